@@ -0,0 +1,70 @@
+//! Compares the `Display` formatting of `unicode_str` with the one of `str`, on equivalent
+//! UTF-8 text, with and without padding.
+
+use std::fmt::Write;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use unicode_string::UnicodeString;
+
+/// Short log lines and longer texts in several scripts.
+const TEXTS: &[(&str, &str, usize)] = &[
+    ("english/short", "GET /index.html 200 OK", 1),
+    ("russian/short", "Съешь же ещё этих булок", 1),
+    (
+        "english/long",
+        "The quick brown fox jumps over the lazy dog while the cat watches from the window. ",
+        64,
+    ),
+    (
+        "chinese/long",
+        "我能吞下玻璃而不伤身体。天地玄黄，宇宙洪荒，日月盈昃，辰宿列张。",
+        64,
+    ),
+];
+
+fn display(c: &mut Criterion) {
+    let mut group = c.benchmark_group("display");
+    for &(name, sentence, repeat) in TEXTS {
+        let text = sentence.repeat(repeat);
+        let unicode_text = UnicodeString::from_string(&text);
+        let mut output = String::with_capacity(text.len() * 2);
+        group.throughput(Throughput::Elements(text.chars().count() as u64));
+        group.bench_function(BenchmarkId::new("unicode_str", name), |b| {
+            b.iter(|| {
+                output.clear();
+                write!(output, "{}", black_box(&unicode_text[..])).unwrap();
+            })
+        });
+        group.bench_function(BenchmarkId::new("str", name), |b| {
+            b.iter(|| {
+                output.clear();
+                write!(output, "{}", black_box(text.as_str())).unwrap();
+            })
+        });
+    }
+    group.finish();
+}
+
+fn padded(c: &mut Criterion) {
+    let mut group = c.benchmark_group("display_padded");
+    for &(name, sentence, _) in TEXTS {
+        let unicode_text = UnicodeString::from_string(sentence);
+        let mut output = String::with_capacity(sentence.len() * 2);
+        group.bench_function(BenchmarkId::new("unicode_str", name), |b| {
+            b.iter(|| {
+                output.clear();
+                write!(output, "{:^40.16}", black_box(&unicode_text[..])).unwrap();
+            })
+        });
+        group.bench_function(BenchmarkId::new("str", name), |b| {
+            b.iter(|| {
+                output.clear();
+                write!(output, "{:^40.16}", black_box(sentence)).unwrap();
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, display, padded);
+criterion_main!(benches);
@@ -0,0 +1,128 @@
+//! Compares `unicode_str::find`, `contains` and `==` with the same operations on `str`, on
+//! equivalent UTF-8 text.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use unicode_string::UnicodeString;
+
+/// Texts of about 64 KiB of chars in several scripts.
+const TEXTS: &[(&str, &str)] = &[
+    (
+        "english",
+        "The quick brown fox jumps over the lazy dog while the cat watches from the window. ",
+    ),
+    (
+        "russian",
+        "Съешь же ещё этих мягких французских булок, да выпей чаю, пока кот спит на окне. ",
+    ),
+    (
+        "chinese",
+        "我能吞下玻璃而不伤身体。天地玄黄，宇宙洪荒，日月盈昃，辰宿列张。",
+    ),
+    (
+        "emoji",
+        "🦀 Rust 🚀 and Unicode 🌍 strings: 👩‍🔬 tests 🎉 pass ✅ today! ",
+    ),
+];
+
+const LEN: usize = 16 * 1024;
+
+fn text(sentence: &str) -> String {
+    sentence.chars().cycle().take(LEN).collect()
+}
+
+fn find(c: &mut Criterion) {
+    let mut group = c.benchmark_group("find");
+    for &(name, sentence) in TEXTS {
+        // The text ends with a needle made of a part of the sentence and a char that does not
+        // appear anywhere else, so it is found after scanning the whole text. The same needle
+        // with another last char is not found at all.
+        let prefix = sentence.chars().take(19).collect::<String>();
+        let found = format!("{}\u{E000}", prefix);
+        let missing = format!("{}\u{E001}", prefix);
+        let mut haystack = text(sentence);
+        haystack.truncate(haystack.char_indices().nth(LEN - 20).unwrap().0);
+        haystack.push_str(&found);
+        let unicode_haystack = UnicodeString::from_string(&haystack);
+        let chars = haystack.chars().collect::<Vec<_>>();
+        group.throughput(Throughput::Elements(LEN as u64));
+        for (kind, needle) in [("found", &found), ("missing", &missing)] {
+            let unicode_needle = UnicodeString::from_string(needle);
+            let id = format!("{}/{}", name, kind);
+            group.bench_with_input(BenchmarkId::new("unicode_str", &id), &(), |b, _| {
+                b.iter(|| black_box(&unicode_haystack).find(black_box(&unicode_needle)))
+            });
+            group.bench_with_input(BenchmarkId::new("str", &id), &(), |b, _| {
+                b.iter(|| black_box(&haystack).find(black_box(needle.as_str())))
+            });
+            group.bench_with_input(BenchmarkId::new("windows", &id), &(), |b, _| {
+                let needle = unicode_needle.chars();
+                b.iter(|| {
+                    black_box(&chars)
+                        .windows(needle.len())
+                        .position(|window| window == black_box(needle))
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+fn single_char(c: &mut Criterion) {
+    let mut group = c.benchmark_group("contains_char");
+    for &(name, sentence) in TEXTS {
+        let haystack = text(sentence);
+        let unicode_haystack = UnicodeString::from_string(&haystack);
+        let unicode_needle = UnicodeString::from_string("\u{E000}");
+        group.throughput(Throughput::Elements(LEN as u64));
+        group.bench_function(BenchmarkId::new("unicode_str", name), |b| {
+            b.iter(|| black_box(&unicode_haystack).contains(black_box(&unicode_needle)))
+        });
+        group.bench_function(BenchmarkId::new("str", name), |b| {
+            b.iter(|| black_box(&haystack).contains(black_box('\u{E000}')))
+        });
+    }
+    group.finish();
+}
+
+fn pathological(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pathological");
+    let haystack = "a".repeat(LEN);
+    let needle = format!("{}b{}", "a".repeat(32), "a".repeat(32));
+    let unicode_haystack = UnicodeString::from_string(&haystack);
+    let unicode_needle = UnicodeString::from_string(&needle);
+    group.throughput(Throughput::Elements(LEN as u64));
+    group.bench_function("unicode_str", |b| {
+        b.iter(|| black_box(&unicode_haystack).find(black_box(&unicode_needle)))
+    });
+    group.bench_function("str", |b| {
+        b.iter(|| black_box(&haystack).find(black_box(needle.as_str())))
+    });
+    group.finish();
+}
+
+fn eq(c: &mut Criterion) {
+    let mut group = c.benchmark_group("eq");
+    for &(name, sentence) in TEXTS {
+        let a = text(sentence);
+        let b = a.clone();
+        let (unicode_a, unicode_b) = (
+            UnicodeString::from_string(&a),
+            UnicodeString::from_string(&b),
+        );
+        group.throughput(Throughput::Elements(LEN as u64));
+        group.bench_function(BenchmarkId::new("unicode_str", name), |bencher| {
+            bencher.iter(|| black_box(&unicode_a[..]) == black_box(&unicode_b[..]))
+        });
+        // The comparison of the slices of chars, which compiles to `memcmp`.
+        group.bench_function(BenchmarkId::new("slice", name), |bencher| {
+            bencher.iter(|| black_box(unicode_a.chars()) == black_box(unicode_b.chars()))
+        });
+        group.bench_function(BenchmarkId::new("str", name), |bencher| {
+            bencher.iter(|| black_box(a.as_str()) == black_box(b.as_str()))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, find, single_char, pathological, eq);
+criterion_main!(benches);
@@ -0,0 +1,236 @@
+use std::collections::VecDeque;
+
+use crate::aho_corasick::{Match, MatchKind};
+use crate::unicode_str;
+
+/// The index of a state of an [`Automaton`].
+pub(crate) type StateId = u32;
+
+/// The start state, which matches the empty prefix.
+pub(crate) const ROOT: StateId = 0;
+
+/// Marks the absence of a state.
+const NONE: StateId = StateId::MAX;
+
+#[derive(Clone, Debug)]
+struct State {
+    /// The transitions of the trie, sorted by char.
+    transitions: Vec<(char, StateId)>,
+    /// The state of the longest proper suffix of this state's prefix that is in the trie.
+    fail: StateId,
+    /// The length of the prefix of this state.
+    depth: usize,
+    /// The patterns equal to the prefix of this state, in increasing order.
+    patterns: Vec<usize>,
+    /// The nearest state on the chain of `fail` links that has patterns, or `NONE`.
+    output: StateId,
+}
+
+impl State {
+    fn new(depth: usize) -> State {
+        State {
+            transitions: Vec::new(),
+            fail: ROOT,
+            depth,
+            patterns: Vec::new(),
+            output: NONE,
+        }
+    }
+
+    #[inline]
+    fn transition(&self, c: char) -> Option<StateId> {
+        self.transitions
+            .binary_search_by_key(&c, |&(other, _)| other)
+            .ok()
+            .map(|index| self.transitions[index].1)
+    }
+}
+
+/// The result of a search for the leftmost match from a position.
+pub(crate) enum Leftmost {
+    /// The leftmost match.
+    Match(Match),
+    /// There is no match, and no match can start before `resume`, even if more text follows.
+    None { resume: usize },
+    /// A match was found, but more text is needed to know if it is the leftmost one.
+    Undecided,
+}
+
+/// An Aho-Corasick automaton: a trie of the patterns with failure links.
+#[derive(Clone, Debug)]
+pub(crate) struct Automaton {
+    states: Vec<State>,
+    pattern_lens: Vec<usize>,
+    kind: MatchKind,
+}
+
+impl Automaton {
+    pub(crate) fn new<P: AsRef<unicode_str>>(
+        patterns: impl IntoIterator<Item = P>,
+        kind: MatchKind,
+    ) -> Automaton {
+        let mut states = vec![State::new(0)];
+        let mut pattern_lens = Vec::new();
+        for (pattern, chars) in patterns.into_iter().enumerate() {
+            let chars = chars.as_ref().chars();
+            let mut state = ROOT;
+            for &c in chars {
+                state = match states[state as usize].transition(c) {
+                    Some(next) => next,
+                    None => {
+                        let next = states.len() as StateId;
+                        states.push(State::new(states[state as usize].depth + 1));
+                        let transitions = &mut states[state as usize].transitions;
+                        let index = transitions.partition_point(|&(other, _)| other < c);
+                        transitions.insert(index, (c, next));
+                        next
+                    }
+                };
+            }
+            states[state as usize].patterns.push(pattern);
+            pattern_lens.push(chars.len());
+        }
+
+        // Set the failure and output links in breadth-first order, so that the links of
+        // shallower states are known.
+        let mut queue = VecDeque::new();
+        for &(_, child) in &states[ROOT as usize].transitions {
+            queue.push_back(child);
+        }
+        while let Some(state) = queue.pop_front() {
+            let fail = states[state as usize].fail;
+            let output = if states[fail as usize].patterns.is_empty() {
+                states[fail as usize].output
+            } else {
+                fail
+            };
+            states[state as usize].output = output;
+            for index in 0..states[state as usize].transitions.len() {
+                let (c, child) = states[state as usize].transitions[index];
+                let mut candidate = fail;
+                let child_fail = loop {
+                    if state == ROOT {
+                        break ROOT;
+                    }
+                    if let Some(next) = states[candidate as usize].transition(c) {
+                        break next;
+                    }
+                    if candidate == ROOT {
+                        break ROOT;
+                    }
+                    candidate = states[candidate as usize].fail;
+                };
+                states[child as usize].fail = child_fail;
+                queue.push_back(child);
+            }
+        }
+        Automaton {
+            states,
+            pattern_lens,
+            kind,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn kind(&self) -> MatchKind {
+        self.kind
+    }
+
+    #[inline]
+    pub(crate) fn patterns_len(&self) -> usize {
+        self.pattern_lens.len()
+    }
+
+    #[inline]
+    pub(crate) fn pattern_len(&self, pattern: usize) -> usize {
+        self.pattern_lens[pattern]
+    }
+
+    /// Returns the state after reading `c` in `state`.
+    #[inline]
+    pub(crate) fn next_state(&self, mut state: StateId, c: char) -> StateId {
+        loop {
+            let current = &self.states[state as usize];
+            if let Some(next) = current.transition(c) {
+                return next;
+            }
+            if state == ROOT {
+                return ROOT;
+            }
+            state = current.fail;
+        }
+    }
+
+    /// Calls `f` with every pattern that ends in `state`, from the longest to the shortest.
+    #[inline]
+    pub(crate) fn for_each_pattern(&self, state: StateId, mut f: impl FnMut(usize)) {
+        let mut current = if self.states[state as usize].patterns.is_empty() {
+            self.states[state as usize].output
+        } else {
+            state
+        };
+        while current != NONE {
+            let state = &self.states[current as usize];
+            state.patterns.iter().for_each(|&pattern| f(pattern));
+            current = state.output;
+        }
+    }
+
+    /// Searches `chars` for the leftmost match that starts at or after `start`, following the
+    /// match kind of the automaton, which must not be [`MatchKind::All`].
+    ///
+    /// If `at_end` is not set, more text may follow `chars`, so the search can be undecided.
+    pub(crate) fn leftmost(&self, chars: &[char], start: usize, at_end: bool) -> Leftmost {
+        debug_assert!(start <= chars.len());
+        let mut best: Option<Match> = None;
+        let mut state = ROOT;
+        for &pattern in &self.states[ROOT as usize].patterns {
+            self.consider(&mut best, Match::new(pattern, start, start));
+        }
+        for (end, &c) in chars
+            .iter()
+            .enumerate()
+            .skip(start)
+            .map(|(i, c)| (i + 1, c))
+        {
+            state = self.next_state(state, c);
+            self.for_each_pattern(state, |pattern| {
+                let len = self.pattern_lens[pattern];
+                self.consider(&mut best, Match::new(pattern, end - len, end))
+            });
+            // Later matches start at or after the start of the prefix of the current state.
+            let earliest_start = end - self.states[state as usize].depth;
+            if let Some(best) = best.filter(|best| best.start() < earliest_start) {
+                return Leftmost::Match(best);
+            }
+        }
+        match best {
+            Some(best) if at_end => Leftmost::Match(best),
+            Some(_) => Leftmost::Undecided,
+            None if at_end => Leftmost::None {
+                resume: chars.len(),
+            },
+            None => Leftmost::None {
+                resume: chars.len() - self.states[state as usize].depth,
+            },
+        }
+    }
+
+    /// Replaces `best` with `candidate` if it is preferred by the match kind.
+    fn consider(&self, best: &mut Option<Match>, candidate: Match) {
+        let better = match best {
+            None => true,
+            Some(best) if candidate.start() != best.start() => candidate.start() < best.start(),
+            Some(best) => match self.kind {
+                MatchKind::LeftmostLongest => {
+                    (candidate.len(), std::cmp::Reverse(candidate.pattern()))
+                        > (best.len(), std::cmp::Reverse(best.pattern()))
+                }
+                _ => candidate.pattern() < best.pattern(),
+            },
+        };
+        if better {
+            *best = Some(candidate);
+        }
+    }
+}
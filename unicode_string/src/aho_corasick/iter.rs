@@ -0,0 +1,99 @@
+use std::iter::FusedIterator;
+
+use crate::aho_corasick::automaton::{Automaton, Leftmost, StateId, ROOT};
+use crate::aho_corasick::{Match, MatchKind};
+use crate::unicode_str;
+
+/// An iterator over the matches of an [`AhoCorasick`] automaton in a `unicode_str`, created
+/// by [`AhoCorasick::find_iter`].
+///
+/// [`AhoCorasick`]: crate::aho_corasick::AhoCorasick
+/// [`AhoCorasick::find_iter`]: crate::aho_corasick::AhoCorasick::find_iter
+#[derive(Clone, Debug)]
+pub struct FindIter<'a, 's> {
+    automaton: &'a Automaton,
+    haystack: &'s unicode_str,
+    /// The index of the next char to read, or for the leftmost kinds, to search from.
+    next: usize,
+    /// The end of the last match, only used for the leftmost kinds.
+    last_end: Option<usize>,
+    /// The current state, only used for [`MatchKind::All`].
+    state: StateId,
+    /// The matches ending at `next` that were not returned yet, in reverse order.
+    pending: Vec<Match>,
+}
+
+impl<'a, 's> FindIter<'a, 's> {
+    pub(crate) fn new(automaton: &'a Automaton, haystack: &'s unicode_str) -> FindIter<'a, 's> {
+        let mut pending = Vec::new();
+        if automaton.kind() == MatchKind::All {
+            automaton.for_each_pattern(ROOT, |pattern| pending.push(Match::new(pattern, 0, 0)));
+            pending.reverse();
+        }
+        FindIter {
+            automaton,
+            haystack,
+            next: 0,
+            last_end: None,
+            state: ROOT,
+            pending,
+        }
+    }
+
+    fn next_overlapping(&mut self) -> Option<Match> {
+        let chars = self.haystack.chars();
+        while self.pending.is_empty() {
+            let &c = chars.get(self.next)?;
+            self.next += 1;
+            self.state = self.automaton.next_state(self.state, c);
+            let (automaton, end, pending) = (self.automaton, self.next, &mut self.pending);
+            automaton.for_each_pattern(self.state, |pattern| {
+                pending.push(Match::new(
+                    pattern,
+                    end - automaton.pattern_len(pattern),
+                    end,
+                ))
+            });
+            pending.reverse();
+        }
+        self.pending.pop()
+    }
+
+    fn next_leftmost(&mut self) -> Option<Match> {
+        let chars = self.haystack.chars();
+        while self.next <= chars.len() {
+            match self.automaton.leftmost(chars, self.next, true) {
+                Leftmost::Match(found) => {
+                    self.next = if found.is_empty() {
+                        found.end() + 1
+                    } else {
+                        found.end()
+                    };
+                    // Like the matches of a regex, an empty match right after the previous
+                    // match is skipped.
+                    if found.is_empty() && self.last_end == Some(found.start()) {
+                        continue;
+                    }
+                    self.last_end = Some(found.end());
+                    return Some(found);
+                }
+                _ => break,
+            }
+        }
+        self.next = chars.len() + 1;
+        None
+    }
+}
+
+impl Iterator for FindIter<'_, '_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        match self.automaton.kind() {
+            MatchKind::All => self.next_overlapping(),
+            MatchKind::LeftmostFirst | MatchKind::LeftmostLongest => self.next_leftmost(),
+        }
+    }
+}
+
+impl FusedIterator for FindIter<'_, '_> {}
@@ -0,0 +1,364 @@
+//! Searching for many patterns at once with the Aho-Corasick algorithm.
+//!
+//! An [`AhoCorasick`] automaton is built from a list of patterns, and finds their matches in
+//! a `unicode_str` in a single pass, in time linear in the length of the text and the number
+//! of matches. Patterns are compared char by char, and positions are char indices, so a
+//! [`Match::range`] can be used to index the searched string.
+//!
+//! The [`MatchKind`] decides which matches are reported when they overlap: by default, all of
+//! them are, but the automaton can also report non-overlapping matches, preferring the one
+//! that starts first and then either the pattern that was given first or the longest one.
+//!
+//! Text that is not available at once, e.g. read from a file, can be searched in chunks with
+//! a [`StreamFinder`].
+
+mod automaton;
+mod iter;
+mod stream;
+
+pub use self::iter::*;
+pub use self::stream::*;
+
+use std::ops::Range;
+
+use self::automaton::Automaton;
+use crate::{unicode_str, UnicodeString};
+
+/// Which matches an [`AhoCorasick`] automaton reports.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::aho_corasick::{AhoCorasickBuilder, MatchKind};
+/// use unicode_string::ustr;
+///
+/// let patterns = [ustr!("Straße"), ustr!("Straßenbahn"), ustr!("bahn")];
+/// let s = ustr!("Straßenbahnhof");
+/// let matches = |kind| -> Vec<_> {
+///     let ac = AhoCorasickBuilder::new().match_kind(kind).build(patterns);
+///     ac.find_iter(s).map(|found| (found.pattern(), found.range())).collect()
+/// };
+///
+/// assert_eq!(matches(MatchKind::All), [(0, 0..6), (1, 0..11), (2, 7..11)]);
+/// assert_eq!(matches(MatchKind::LeftmostFirst), [(0, 0..6), (2, 7..11)]);
+/// assert_eq!(matches(MatchKind::LeftmostLongest), [(1, 0..11)]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum MatchKind {
+    /// All the matches are reported, including overlapping ones. They are ordered by their
+    /// end, then from the longest to the shortest.
+    #[default]
+    All,
+    /// Non-overlapping matches are reported. Of the matches that start first, the one of the
+    /// pattern given first is preferred.
+    LeftmostFirst,
+    /// Non-overlapping matches are reported. Of the matches that start first, the longest one
+    /// is preferred.
+    LeftmostLongest,
+}
+
+/// A match of a pattern of an [`AhoCorasick`] automaton.
+///
+/// Positions are char indices, so [`range`](Match::range) can be used to index the searched
+/// string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Match {
+    pattern: usize,
+    start: usize,
+    end: usize,
+}
+
+impl Match {
+    #[inline]
+    pub(crate) fn new(pattern: usize, start: usize, end: usize) -> Match {
+        Match {
+            pattern,
+            start,
+            end,
+        }
+    }
+
+    /// Returns the index of the matched pattern, in the order the patterns were given.
+    #[inline]
+    pub fn pattern(&self) -> usize {
+        self.pattern
+    }
+
+    /// Returns the index of the first char of the match.
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the index after the last char of the match.
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the range of chars of the match.
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the number of chars of the match.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Checks if the match is empty, which only happens for an empty pattern.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the match shifted by `offset` chars.
+    #[inline]
+    pub(crate) fn offset(self, offset: usize) -> Match {
+        Match::new(self.pattern, self.start + offset, self.end + offset)
+    }
+}
+
+/// An automaton that searches for many patterns at once.
+///
+/// See the [module documentation](self) for details.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::aho_corasick::AhoCorasick;
+/// use unicode_string::ustr;
+///
+/// let ac = AhoCorasick::new([ustr!("кот"), ustr!("от"), ustr!("🐈")]);
+/// let matches: Vec<_> = ac
+///     .find_iter(ustr!("котик 🐈"))
+///     .map(|found| (found.pattern(), found.start(), found.end()))
+///     .collect();
+/// assert_eq!(matches, [(0, 0, 3), (1, 1, 3), (2, 6, 7)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct AhoCorasick {
+    automaton: Automaton,
+}
+
+impl AhoCorasick {
+    /// Builds an automaton that reports all the matches of `patterns`.
+    ///
+    /// Use an [`AhoCorasickBuilder`] to choose another [`MatchKind`].
+    pub fn new<P: AsRef<unicode_str>>(patterns: impl IntoIterator<Item = P>) -> AhoCorasick {
+        AhoCorasickBuilder::new().build(patterns)
+    }
+
+    /// Returns the kind of matches reported by the automaton.
+    #[inline]
+    pub fn match_kind(&self) -> MatchKind {
+        self.automaton.kind()
+    }
+
+    /// Returns the number of patterns of the automaton.
+    #[inline]
+    pub fn patterns_len(&self) -> usize {
+        self.automaton.patterns_len()
+    }
+
+    /// Checks if any of the patterns occurs in `s`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::aho_corasick::AhoCorasick;
+    /// use unicode_string::ustr;
+    ///
+    /// let ac = AhoCorasick::new([ustr!("ü"), ustr!("ö")]);
+    /// assert!(ac.is_match(ustr!("Köln")));
+    /// assert!(!ac.is_match(ustr!("Koeln")));
+    /// ```
+    pub fn is_match(&self, s: &unicode_str) -> bool {
+        let automaton = &self.automaton;
+        if (0..automaton.patterns_len()).any(|pattern| automaton.pattern_len(pattern) == 0) {
+            return true;
+        }
+        let mut state = automaton::ROOT;
+        s.chars().iter().any(|&c| {
+            state = automaton.next_state(state, c);
+            let mut found = false;
+            automaton.for_each_pattern(state, |_| found = true);
+            found
+        })
+    }
+
+    /// Returns the first match in `s`: the leftmost one following the [`MatchKind`], or for
+    /// [`MatchKind::All`], the longest of the matches that end first.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+    /// use unicode_string::ustr;
+    ///
+    /// let patterns = [ustr!("maus"), ustr!("haus"), ustr!("Fledermaus")];
+    /// let s = ustr!("Fledermaushaus");
+    ///
+    /// let ac = AhoCorasick::new(patterns);
+    /// assert_eq!(ac.find(s).map(|found| (found.pattern(), found.range())), Some((2, 0..10)));
+    ///
+    /// let ac = AhoCorasickBuilder::new().match_kind(MatchKind::LeftmostFirst).build(patterns);
+    /// assert_eq!(ac.find(s).map(|found| (found.pattern(), found.range())), Some((2, 0..10)));
+    /// assert_eq!(ac.find(ustr!("Haus")), None);
+    /// ```
+    pub fn find(&self, s: &unicode_str) -> Option<Match> {
+        self.find_iter(s).next()
+    }
+
+    /// Returns an iterator over the matches in `s`, which overlap for [`MatchKind::All`] and
+    /// are successive and non-overlapping otherwise.
+    ///
+    /// For the leftmost kinds, an empty pattern matches at every char index that is not the
+    /// end of the previous match, like an empty match of a regex.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::aho_corasick::{AhoCorasickBuilder, MatchKind};
+    /// use unicode_string::ustr;
+    ///
+    /// let ac = AhoCorasickBuilder::new()
+    ///     .match_kind(MatchKind::LeftmostFirst)
+    ///     .build([ustr!("b"), ustr!("")]);
+    /// let matches: Vec<_> = ac.find_iter(ustr!("abc")).map(|found| found.range()).collect();
+    /// assert_eq!(matches, [0..0, 1..2, 3..3]);
+    /// ```
+    #[inline]
+    pub fn find_iter<'a, 's>(&'a self, s: &'s unicode_str) -> FindIter<'a, 's> {
+        FindIter::new(&self.automaton, s)
+    }
+
+    /// Creates a [`StreamFinder`] that searches text given in chunks.
+    #[inline]
+    pub fn stream_finder(&self) -> StreamFinder<'_> {
+        StreamFinder::new(&self.automaton)
+    }
+
+    /// Replaces the matches in `s` with the replacement of their pattern: the match of pattern
+    /// `i` is replaced with `replacements[i]`.
+    ///
+    /// For [`MatchKind::All`], a match that overlaps a previous one is not replaced. An empty
+    /// match right after a previous one is never replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of replacements is not the number of patterns.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::aho_corasick::{AhoCorasickBuilder, MatchKind};
+    /// use unicode_string::ustr;
+    ///
+    /// let ac = AhoCorasickBuilder::new()
+    ///     .match_kind(MatchKind::LeftmostLongest)
+    ///     .build([ustr!("ß"), ustr!("ä"), ustr!("Straße")]);
+    /// let replaced = ac.replace_all(
+    ///     ustr!("Straßenbäume am Fluß"),
+    ///     &[ustr!("ss"), ustr!("ae"), ustr!("Strasse")],
+    /// );
+    /// assert_eq!(replaced, ustr!("Strassenbaeume am Fluss"));
+    ///
+    /// // An empty pattern is replaced between the other matches, but not right after them.
+    /// let ac = AhoCorasickBuilder::new()
+    ///     .match_kind(MatchKind::LeftmostFirst)
+    ///     .build([ustr!("b"), ustr!("")]);
+    /// let replaced = ac.replace_all(ustr!("abc"), &[ustr!("X"), ustr!("-")]);
+    /// assert_eq!(replaced, ustr!("-aXc-"));
+    /// ```
+    pub fn replace_all<R: AsRef<unicode_str>>(
+        &self,
+        s: &unicode_str,
+        replacements: &[R],
+    ) -> UnicodeString {
+        assert_eq!(
+            replacements.len(),
+            self.patterns_len(),
+            "the number of replacements must be the number of patterns"
+        );
+        let chars = s.chars();
+        let mut result = UnicodeString::with_capacity(chars.len());
+        let mut last_end = None;
+        for found in self.find_iter(s) {
+            let last = last_end.unwrap_or(0);
+            if found.start() < last || (found.is_empty() && last_end == Some(found.start())) {
+                continue;
+            }
+            result.vec.extend_from_slice(&chars[last..found.start()]);
+            result
+                .vec
+                .extend_from_slice(replacements[found.pattern()].as_ref().chars());
+            last_end = Some(found.end());
+        }
+        result
+            .vec
+            .extend_from_slice(&chars[last_end.unwrap_or(0)..]);
+        result
+    }
+}
+
+/// A builder of [`AhoCorasick`] automatons.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::aho_corasick::{AhoCorasickBuilder, MatchKind};
+/// use unicode_string::ustr;
+///
+/// let ac = AhoCorasickBuilder::new()
+///     .match_kind(MatchKind::LeftmostLongest)
+///     .build([ustr!("ein"), ustr!("einhorn")]);
+/// let found = ac.find(ustr!("das einhorn")).unwrap();
+/// assert_eq!((found.pattern(), found.range()), (1, 4..11));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AhoCorasickBuilder {
+    match_kind: MatchKind,
+}
+
+impl AhoCorasickBuilder {
+    /// Creates a builder of automatons that report all the matches.
+    pub const fn new() -> AhoCorasickBuilder {
+        AhoCorasickBuilder {
+            match_kind: MatchKind::All,
+        }
+    }
+
+    /// Sets which matches are reported.
+    #[must_use]
+    pub const fn match_kind(mut self, match_kind: MatchKind) -> AhoCorasickBuilder {
+        self.match_kind = match_kind;
+        self
+    }
+
+    /// Builds an automaton that searches for `patterns`.
+    pub fn build<P: AsRef<unicode_str>>(
+        &self,
+        patterns: impl IntoIterator<Item = P>,
+    ) -> AhoCorasick {
+        AhoCorasick {
+            automaton: Automaton::new(patterns, self.match_kind),
+        }
+    }
+}
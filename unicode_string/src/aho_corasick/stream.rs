@@ -0,0 +1,161 @@
+use crate::aho_corasick::automaton::{Automaton, Leftmost, StateId, ROOT};
+use crate::aho_corasick::{Match, MatchKind};
+use crate::unicode_str;
+
+/// Searches text given in chunks for the patterns of an [`AhoCorasick`] automaton, created by
+/// [`AhoCorasick::stream_finder`].
+///
+/// The matches are the same as those of [`AhoCorasick::find_iter`] on the concatenation of
+/// the chunks, and their positions are char indices in that concatenation. Matches can span
+/// several chunks. For the leftmost match kinds, a match is only reported once the text that
+/// follows it cannot change which match is preferred, so the end of the text that may be part
+/// of a match is kept until the next chunk.
+///
+/// [`AhoCorasick`]: crate::aho_corasick::AhoCorasick
+/// [`AhoCorasick::stream_finder`]: crate::aho_corasick::AhoCorasick::stream_finder
+/// [`AhoCorasick::find_iter`]: crate::aho_corasick::AhoCorasick::find_iter
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::aho_corasick::{AhoCorasickBuilder, MatchKind};
+/// use unicode_string::ustr;
+///
+/// let ac = AhoCorasickBuilder::new()
+///     .match_kind(MatchKind::LeftmostLongest)
+///     .build([ustr!("Rhein"), ustr!("Rheinland"), ustr!("Pfalz")]);
+/// let mut finder = ac.stream_finder();
+///
+/// assert_eq!(finder.push(ustr!("Rhein")), []);
+/// let found = finder.push(ustr!("land-Pf"));
+/// assert_eq!(found.iter().map(|found| found.range()).collect::<Vec<_>>(), [0..9]);
+/// assert_eq!(finder.push(ustr!("alz")), []);
+///
+/// // A longer pattern could still match, until the text ends.
+/// let found = finder.finish();
+/// assert_eq!(found.iter().map(|found| found.range()).collect::<Vec<_>>(), [10..15]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StreamFinder<'a> {
+    automaton: &'a Automaton,
+    /// The number of chars pushed so far.
+    len: usize,
+    /// Whether the matches of empty patterns at the start of the text were reported.
+    started: bool,
+    /// The current state, only used for [`MatchKind::All`].
+    state: StateId,
+    /// The chars that may be part of a match, only used for the leftmost kinds.
+    buffer: Vec<char>,
+    /// The position in the text of the start of `buffer`.
+    buffer_start: usize,
+    /// The position in the text to search from.
+    next: usize,
+    /// The end of the last match, only used for the leftmost kinds.
+    last_end: Option<usize>,
+}
+
+impl<'a> StreamFinder<'a> {
+    pub(crate) fn new(automaton: &'a Automaton) -> StreamFinder<'a> {
+        StreamFinder {
+            automaton,
+            len: 0,
+            started: false,
+            state: ROOT,
+            buffer: Vec::new(),
+            buffer_start: 0,
+            next: 0,
+            last_end: None,
+        }
+    }
+
+    /// Returns the number of chars pushed so far.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.len
+    }
+
+    /// Searches the next chunk of the text, and returns the matches found.
+    pub fn push(&mut self, chunk: &unicode_str) -> Vec<Match> {
+        let mut matches = Vec::new();
+        if self.automaton.kind() == MatchKind::All {
+            self.root_matches(&mut matches);
+            for &c in chunk.chars() {
+                self.len += 1;
+                self.state = self.automaton.next_state(self.state, c);
+                let (automaton, end) = (self.automaton, self.len);
+                automaton.for_each_pattern(self.state, |pattern| {
+                    matches.push(Match::new(
+                        pattern,
+                        end - automaton.pattern_len(pattern),
+                        end,
+                    ))
+                });
+            }
+        } else {
+            self.buffer.extend_from_slice(chunk.chars());
+            self.len += chunk.len();
+            self.search_buffer(false, &mut matches);
+        }
+        matches
+    }
+
+    /// Ends the text, and returns the matches that were waiting for more text.
+    ///
+    /// The finder is reset, so it can then be used to search another text.
+    pub fn finish(&mut self) -> Vec<Match> {
+        let mut matches = Vec::new();
+        if self.automaton.kind() == MatchKind::All {
+            self.root_matches(&mut matches);
+        } else {
+            self.search_buffer(true, &mut matches);
+        }
+        *self = StreamFinder::new(self.automaton);
+        matches
+    }
+
+    /// Adds the matches of empty patterns at the start of the text, if they were not
+    /// reported yet.
+    fn root_matches(&mut self, matches: &mut Vec<Match>) {
+        if !self.started {
+            self.started = true;
+            self.automaton
+                .for_each_pattern(ROOT, |pattern| matches.push(Match::new(pattern, 0, 0)));
+        }
+    }
+
+    /// Searches the buffer for leftmost matches, and drops the chars that cannot be part of a
+    /// later match.
+    fn search_buffer(&mut self, at_end: bool, matches: &mut Vec<Match>) {
+        let offset = self.buffer_start;
+        while self.next <= self.len {
+            match self
+                .automaton
+                .leftmost(&self.buffer, self.next - offset, at_end)
+            {
+                Leftmost::Match(found) => {
+                    let found = found.offset(offset);
+                    self.next = if found.is_empty() {
+                        found.end() + 1
+                    } else {
+                        found.end()
+                    };
+                    if found.is_empty() && self.last_end == Some(found.start()) {
+                        continue;
+                    }
+                    self.last_end = Some(found.end());
+                    matches.push(found);
+                }
+                Leftmost::None { resume } => {
+                    self.next = self.next.max(resume + offset);
+                    break;
+                }
+                Leftmost::Undecided => break,
+            }
+        }
+        let drop = self.next.min(self.len) - offset;
+        self.buffer.drain(..drop);
+        self.buffer_start += drop;
+    }
+}
@@ -0,0 +1,142 @@
+use std::cmp::Ordering;
+
+use crate::tables::bidi::{BIDI_CLASS, BIDI_PAIRED_BRACKETS};
+
+/// The bidirectional character type of a char (the `Bidi_Class` property), as used by the
+/// Unicode Bidirectional Algorithm.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::BidiClass;
+///
+/// assert_eq!(BidiClass::of('a'), BidiClass::L);
+/// assert_eq!(BidiClass::of('א'), BidiClass::R);
+/// assert_eq!(BidiClass::of('ب'), BidiClass::AL);
+/// assert_eq!(BidiClass::of('7'), BidiClass::EN);
+/// assert_eq!(BidiClass::of('\u{2067}'), BidiClass::RLI);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BidiClass {
+    /// Arabic letter.
+    AL,
+    /// Arabic number.
+    AN,
+    /// Paragraph separator.
+    B,
+    /// Boundary neutral.
+    BN,
+    /// Common number separator.
+    CS,
+    /// European number.
+    EN,
+    /// European number separator.
+    ES,
+    /// European number terminator.
+    ET,
+    /// First strong isolate.
+    FSI,
+    /// Left-to-right.
+    L,
+    /// Left-to-right embedding.
+    LRE,
+    /// Left-to-right isolate.
+    LRI,
+    /// Left-to-right override.
+    LRO,
+    /// Nonspacing mark.
+    NSM,
+    /// Other neutral.
+    ON,
+    /// Pop directional format.
+    PDF,
+    /// Pop directional isolate.
+    PDI,
+    /// Right-to-left.
+    R,
+    /// Right-to-left embedding.
+    RLE,
+    /// Right-to-left isolate.
+    RLI,
+    /// Right-to-left override.
+    RLO,
+    /// Segment separator.
+    S,
+    /// Whitespace.
+    WS,
+}
+
+impl BidiClass {
+    /// Returns the bidi class of `c`.
+    pub fn of(c: char) -> BidiClass {
+        if c.is_ascii_alphabetic() {
+            return BidiClass::L;
+        }
+        match BIDI_CLASS.binary_search_by(|&(start, end, _)| {
+            if end < c {
+                Ordering::Less
+            } else if start > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        }) {
+            Ok(index) => BIDI_CLASS[index].2,
+            Err(_) => BidiClass::L,
+        }
+    }
+
+    /// Checks if this is a strong type (`L`, `R` or `AL`).
+    #[inline]
+    pub const fn is_strong(self) -> bool {
+        matches!(self, BidiClass::L | BidiClass::R | BidiClass::AL)
+    }
+
+    /// Checks if this is an isolate initiator (`LRI`, `RLI` or `FSI`).
+    #[inline]
+    pub const fn is_isolate_initiator(self) -> bool {
+        matches!(self, BidiClass::LRI | BidiClass::RLI | BidiClass::FSI)
+    }
+
+    /// Checks if chars of this type are removed by rule X9 of the algorithm (embedding and
+    /// override controls, `PDF` and boundary neutrals).
+    #[inline]
+    pub(crate) const fn is_removed_by_x9(self) -> bool {
+        matches!(
+            self,
+            BidiClass::RLE
+                | BidiClass::LRE
+                | BidiClass::RLO
+                | BidiClass::LRO
+                | BidiClass::PDF
+                | BidiClass::BN
+        )
+    }
+
+    /// Checks if this is a neutral or isolate formatting type, as used by rules N1 and N2.
+    #[inline]
+    pub(crate) const fn is_neutral_or_isolate(self) -> bool {
+        matches!(
+            self,
+            BidiClass::B
+                | BidiClass::S
+                | BidiClass::WS
+                | BidiClass::ON
+                | BidiClass::FSI
+                | BidiClass::LRI
+                | BidiClass::RLI
+                | BidiClass::PDI
+        )
+    }
+}
+
+/// Returns the paired bracket of `c` (the `Bidi_Paired_Bracket` property) and whether `c` is an
+/// opening bracket, or `None` if `c` is not a paired bracket.
+pub(crate) fn paired_bracket(c: char) -> Option<(char, bool)> {
+    BIDI_PAIRED_BRACKETS
+        .binary_search_by_key(&c, |&(bracket, _, _)| bracket)
+        .ok()
+        .map(|index| (BIDI_PAIRED_BRACKETS[index].1, BIDI_PAIRED_BRACKETS[index].2))
+}
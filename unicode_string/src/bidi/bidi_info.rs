@@ -0,0 +1,386 @@
+use std::ops::Range;
+
+use crate::bidi::explicit::{explicit_levels, first_strong_level, isolating_run_sequences};
+use crate::bidi::implicit::resolve_sequence;
+use crate::bidi::{BidiClass, Level};
+use crate::{unicode_str, UnicodeString};
+
+/// A paragraph found by the Unicode Bidirectional Algorithm.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Paragraph {
+    /// The char indices of the paragraph, including its paragraph separator, if any.
+    pub range: Range<usize>,
+    /// The paragraph embedding level.
+    pub level: Level,
+}
+
+/// A run of chars with the same embedding level, as displayed on a line.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VisualRun {
+    /// The char indices of the run. The chars are displayed in reverse order if the level is
+    /// right-to-left.
+    pub range: Range<usize>,
+    /// The embedding level of the run.
+    pub level: Level,
+}
+
+/// The result of running the Unicode Bidirectional Algorithm (UAX #9) on a string.
+///
+/// Every char of the text gets an embedding level, so the level of the char at index `i` is
+/// `levels()[i]`. Even levels are displayed left-to-right and odd levels right-to-left.
+///
+/// The levels returned by [`levels`] do not depend on how the text is broken into lines. To
+/// display a line, use [`reorder_line`], [`visual_runs`] or [`reordered_indices`], which
+/// additionally apply the line-dependent rules (L1 and L2) to the chars of the line.
+///
+/// Explicit embedding and override controls, as well as boundary neutrals, get the level of
+/// the preceding char, and are kept in reordered lines. Mirroring brackets and other glyphs in
+/// right-to-left runs (rule L4) is left to the renderer.
+///
+/// [`levels`]: BidiInfo::levels
+/// [`reorder_line`]: BidiInfo::reorder_line
+/// [`visual_runs`]: BidiInfo::visual_runs
+/// [`reordered_indices`]: BidiInfo::reordered_indices
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::{BidiInfo, Level, ustr};
+///
+/// let text = ustr!("abc אבג 123 דה");
+/// let bidi = BidiInfo::new(text, None);
+///
+/// assert_eq!(bidi.paragraphs()[0].level, Level::LTR);
+/// let levels: Vec<u8> = bidi.levels().iter().map(|level| level.number()).collect();
+/// assert_eq!(levels, [0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 1, 1, 1]);
+/// assert_eq!(bidi.reorder_line(0..text.len()), ustr!("abc הד 123 גבא"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct BidiInfo<'a> {
+    text: &'a unicode_str,
+    classes: Vec<BidiClass>,
+    levels: Vec<Level>,
+    paragraphs: Vec<Paragraph>,
+}
+
+impl<'a> BidiInfo<'a> {
+    /// Runs the algorithm on `text`.
+    ///
+    /// The text is split into paragraphs at paragraph separators. If `default_level` is `None`,
+    /// the level of each paragraph is determined by its first strong char (rules P2 and P3),
+    /// defaulting to left-to-right; otherwise all paragraphs use `default_level`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::{BidiInfo, Level, ustr};
+    ///
+    /// let bidi = BidiInfo::new(ustr!("שלום\nhello"), None);
+    /// assert_eq!(bidi.paragraphs().len(), 2);
+    /// assert_eq!(bidi.paragraphs()[0].range, 0..5);
+    /// assert_eq!(bidi.paragraphs()[0].level, Level::RTL);
+    /// assert_eq!(bidi.paragraphs()[1].level, Level::LTR);
+    ///
+    /// let bidi = BidiInfo::new(ustr!("hello!"), Some(Level::RTL));
+    /// assert_eq!(bidi.reorder_line(0..6), ustr!("!hello"));
+    /// ```
+    pub fn new(text: &'a unicode_str, default_level: Option<Level>) -> BidiInfo<'a> {
+        let chars = text.chars();
+        let classes: Vec<BidiClass> = chars.iter().map(|&c| BidiClass::of(c)).collect();
+        let mut resolved = classes.clone();
+        let mut levels = Vec::with_capacity(chars.len());
+        let mut paragraphs = Vec::new();
+
+        // P1: split the text into paragraphs.
+        let mut start = 0;
+        while start < chars.len() {
+            let end = classes[start..]
+                .iter()
+                .position(|&class| class == BidiClass::B)
+                .map_or(chars.len(), |separator| start + separator + 1);
+            let range = start..end;
+
+            let paragraph_level = match default_level {
+                Some(level) => level.number(),
+                None => first_strong_level(&classes[range.clone()], false).unwrap_or(0),
+            };
+            let paragraph_levels = resolve_paragraph(
+                &chars[range.clone()],
+                &classes[range.clone()],
+                &mut resolved[range.clone()],
+                paragraph_level,
+            );
+            levels.extend(paragraph_levels.into_iter().map(Level::new_unchecked));
+            paragraphs.push(Paragraph {
+                range,
+                level: Level::new_unchecked(paragraph_level),
+            });
+            start = end;
+        }
+
+        BidiInfo {
+            text,
+            classes,
+            levels,
+            paragraphs,
+        }
+    }
+
+    /// Returns the text the algorithm was run on.
+    #[inline]
+    pub fn text(&self) -> &'a unicode_str {
+        self.text
+    }
+
+    /// Returns the original bidi class of every char of the text.
+    #[inline]
+    pub fn classes(&self) -> &[BidiClass] {
+        &self.classes
+    }
+
+    /// Returns the resolved embedding level of every char of the text, before any line-based
+    /// processing.
+    #[inline]
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
+    /// Returns the paragraphs of the text.
+    #[inline]
+    pub fn paragraphs(&self) -> &[Paragraph] {
+        &self.paragraphs
+    }
+
+    /// Checks if any char of the text has a right-to-left level, i.e. if the text needs to be
+    /// reordered at all.
+    #[inline]
+    pub fn has_rtl(&self) -> bool {
+        self.levels.iter().any(|level| level.is_rtl())
+    }
+
+    /// Returns the embedding levels of the chars of `line` after applying rule L1, which
+    /// resets trailing whitespace and separators to the paragraph level.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::{BidiInfo, Level, ustr};
+    ///
+    /// // A right-to-left embedding (RLE) of "abc ", followed by a space.
+    /// let bidi = BidiInfo::new(ustr!("\u{202B}abc "), None);
+    /// assert_eq!(bidi.levels()[4], Level::RTL);
+    /// assert_eq!(bidi.line_levels(0..5)[4], Level::LTR);
+    /// ```
+    pub fn line_levels(&self, line: Range<usize>) -> Vec<Level> {
+        let mut levels = self.levels[line.clone()].to_vec();
+        let classes = &self.classes[line.clone()];
+        for paragraph in &self.paragraphs {
+            let start = paragraph.range.start.max(line.start);
+            let end = paragraph.range.end.min(line.end);
+            if start >= end {
+                continue;
+            }
+            let (start, end) = (start - line.start, end - line.start);
+
+            // L1: segment and paragraph separators, and any whitespace before them or at the
+            // end of the line, are reset to the paragraph level.
+            let mut trailing = true;
+            for index in (start..end).rev() {
+                match classes[index] {
+                    BidiClass::S | BidiClass::B => {
+                        levels[index] = paragraph.level;
+                        trailing = true;
+                    }
+                    BidiClass::WS
+                    | BidiClass::FSI
+                    | BidiClass::LRI
+                    | BidiClass::RLI
+                    | BidiClass::PDI => {
+                        if trailing {
+                            levels[index] = paragraph.level;
+                        }
+                    }
+                    class if class.is_removed_by_x9() => {
+                        if trailing {
+                            levels[index] = paragraph.level;
+                        }
+                    }
+                    _ => trailing = false,
+                }
+            }
+        }
+        levels
+    }
+
+    /// Returns the runs of `line` in visual order, from left to right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::{BidiInfo, Level, VisualRun, ustr};
+    ///
+    /// let bidi = BidiInfo::new(ustr!("אב cd גד"), None);
+    /// let runs = bidi.visual_runs(0..8);
+    /// assert_eq!(runs, [
+    ///     VisualRun { range: 5..8, level: Level::RTL },
+    ///     VisualRun { range: 3..5, level: Level::new(2).unwrap() },
+    ///     VisualRun { range: 0..3, level: Level::RTL },
+    /// ]);
+    /// ```
+    pub fn visual_runs(&self, line: Range<usize>) -> Vec<VisualRun> {
+        let levels = self.line_levels(line.clone());
+        let mut runs = Vec::new();
+        // Paragraphs are always displayed in logical order.
+        for paragraph in &self.paragraphs {
+            let start = paragraph.range.start.max(line.start);
+            let end = paragraph.range.end.min(line.end);
+            if start < end {
+                runs.extend(reorder_runs(
+                    &levels[start - line.start..end - line.start],
+                    start,
+                ));
+            }
+        }
+        runs
+    }
+
+    /// Returns the char indices of `line` in visual order, from left to right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::{BidiInfo, ustr};
+    ///
+    /// let bidi = BidiInfo::new(ustr!("ab גד"), None);
+    /// assert_eq!(bidi.reordered_indices(0..5), [0, 1, 2, 4, 3]);
+    /// ```
+    pub fn reordered_indices(&self, line: Range<usize>) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(line.len());
+        for run in self.visual_runs(line) {
+            if run.level.is_rtl() {
+                indices.extend(run.range.rev());
+            } else {
+                indices.extend(run.range);
+            }
+        }
+        indices
+    }
+
+    /// Returns the chars of `line` in visual order, from left to right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::{BidiInfo, ustr};
+    ///
+    /// let text = ustr!("הספר \u{2066}Rust\u{2069} חדש");
+    /// let bidi = BidiInfo::new(text, None);
+    /// assert_eq!(bidi.reorder_line(0..text.len()), ustr!("שדח \u{2069}Rust\u{2066} רפסה"));
+    /// ```
+    pub fn reorder_line(&self, line: Range<usize>) -> UnicodeString {
+        let chars = self.text.chars();
+        UnicodeString {
+            vec: self
+                .reordered_indices(line)
+                .into_iter()
+                .map(|index| chars[index])
+                .collect(),
+        }
+    }
+}
+
+/// Resolves the levels of a single paragraph.
+fn resolve_paragraph(
+    text: &[char],
+    original: &[BidiClass],
+    classes: &mut [BidiClass],
+    paragraph_level: u8,
+) -> Vec<u8> {
+    // X1 - X8.
+    let mut levels = explicit_levels(classes, paragraph_level);
+
+    // X9 and X10, followed by the weak, neutral and implicit rules on every sequence.
+    for sequence in isolating_run_sequences(original, &levels, paragraph_level) {
+        resolve_sequence(&sequence, text, original, classes, &mut levels);
+    }
+
+    // Chars removed by X9 get the level of the preceding char.
+    let mut previous = paragraph_level;
+    for (level, class) in levels.iter_mut().zip(original) {
+        if class.is_removed_by_x9() {
+            *level = previous;
+        }
+        previous = *level;
+    }
+    levels
+}
+
+/// Applies rule L2 to the levels of a line of a single paragraph, returning its runs in visual
+/// order. `offset` is the index of the first char of `levels` in the text.
+fn reorder_runs(levels: &[Level], offset: usize) -> Vec<VisualRun> {
+    let mut runs: Vec<VisualRun> = Vec::new();
+    for (index, &level) in levels.iter().enumerate() {
+        match runs.last_mut() {
+            Some(run) if run.level == level => run.range.end = offset + index + 1,
+            _ => runs.push(VisualRun {
+                range: offset + index..offset + index + 1,
+                level,
+            }),
+        }
+    }
+
+    let Some(highest) = runs.iter().map(|run| run.level.number()).max() else {
+        return runs;
+    };
+    let lowest_odd = runs
+        .iter()
+        .map(|run| run.level.number())
+        .min()
+        .map_or(1, |lowest| lowest | 1);
+
+    // L2: from the highest level down to the lowest odd level, reverse every sequence of runs
+    // at that level or higher.
+    for level in (lowest_odd..=highest).rev() {
+        let mut index = 0;
+        while index < runs.len() {
+            if runs[index].level.number() < level {
+                index += 1;
+                continue;
+            }
+            let start = index;
+            while index < runs.len() && runs[index].level.number() >= level {
+                index += 1;
+            }
+            runs[start..index].reverse();
+        }
+    }
+    runs
+}
@@ -0,0 +1,268 @@
+use crate::bidi::{BidiClass, Level};
+
+/// Returns the index of the PDI matching each isolate initiator (BD9), indexed like `classes`.
+pub(crate) fn matching_pdis(classes: &[BidiClass]) -> Vec<Option<usize>> {
+    let mut matches = vec![None; classes.len()];
+    let mut open = Vec::new();
+    for (index, &class) in classes.iter().enumerate() {
+        if class.is_isolate_initiator() {
+            open.push(index);
+        } else if class == BidiClass::PDI {
+            if let Some(initiator) = open.pop() {
+                matches[initiator] = Some(index);
+            }
+        }
+    }
+    matches
+}
+
+/// Finds the direction of the first strong char (rules P2 and P3), skipping isolates. When
+/// `stop_at_pdi` is set, stops at a PDI that does not close an isolate started within `classes`.
+pub(crate) fn first_strong_level(classes: &[BidiClass], stop_at_pdi: bool) -> Option<u8> {
+    let mut depth = 0usize;
+    for &class in classes {
+        match class {
+            BidiClass::LRI | BidiClass::RLI | BidiClass::FSI => depth += 1,
+            BidiClass::PDI if depth == 0 && stop_at_pdi => return None,
+            BidiClass::PDI => depth = depth.saturating_sub(1),
+            BidiClass::L if depth == 0 => return Some(0),
+            BidiClass::R | BidiClass::AL if depth == 0 => return Some(1),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[derive(Clone, Copy)]
+struct Status {
+    level: u8,
+    override_class: Option<BidiClass>,
+    isolate: bool,
+}
+
+/// Applies rules X1 to X8 to a paragraph, returning the explicit embedding level of every
+/// char and updating `classes` with directional overrides.
+pub(crate) fn explicit_levels(classes: &mut [BidiClass], paragraph_level: u8) -> Vec<u8> {
+    let original = classes.to_vec();
+    let matches = matching_pdis(&original);
+    let mut levels = vec![paragraph_level; classes.len()];
+
+    let mut stack = vec![Status {
+        level: paragraph_level,
+        override_class: None,
+        isolate: false,
+    }];
+    let mut overflow_isolates = 0usize;
+    let mut overflow_embeddings = 0usize;
+    let mut valid_isolates = 0usize;
+
+    for index in 0..classes.len() {
+        let last = *stack
+            .last()
+            .expect("the directional status stack is never empty");
+        let class = original[index];
+        match class {
+            // X2 - X5: explicit embeddings and overrides.
+            BidiClass::RLE | BidiClass::LRE | BidiClass::RLO | BidiClass::LRO => {
+                levels[index] = last.level;
+                let rtl = matches!(class, BidiClass::RLE | BidiClass::RLO);
+                let level = next_level(last.level, rtl);
+                if level <= Level::MAX_EXPLICIT_DEPTH
+                    && overflow_isolates == 0
+                    && overflow_embeddings == 0
+                {
+                    stack.push(Status {
+                        level,
+                        override_class: match class {
+                            BidiClass::RLO => Some(BidiClass::R),
+                            BidiClass::LRO => Some(BidiClass::L),
+                            _ => None,
+                        },
+                        isolate: false,
+                    });
+                } else if overflow_isolates == 0 {
+                    overflow_embeddings += 1;
+                }
+            }
+            // X5a - X5c: isolates.
+            BidiClass::RLI | BidiClass::LRI | BidiClass::FSI => {
+                levels[index] = last.level;
+                if let Some(override_class) = last.override_class {
+                    classes[index] = override_class;
+                }
+                let rtl = match class {
+                    BidiClass::RLI => true,
+                    BidiClass::LRI => false,
+                    _ => {
+                        let end = matches[index].unwrap_or(original.len());
+                        first_strong_level(&original[index + 1..end], true) == Some(1)
+                    }
+                };
+                let level = next_level(last.level, rtl);
+                if level <= Level::MAX_EXPLICIT_DEPTH
+                    && overflow_isolates == 0
+                    && overflow_embeddings == 0
+                {
+                    valid_isolates += 1;
+                    stack.push(Status {
+                        level,
+                        override_class: None,
+                        isolate: true,
+                    });
+                } else {
+                    overflow_isolates += 1;
+                }
+            }
+            // X6a: terminating isolates.
+            BidiClass::PDI => {
+                if overflow_isolates > 0 {
+                    overflow_isolates -= 1;
+                } else if valid_isolates > 0 {
+                    overflow_embeddings = 0;
+                    while stack.last().is_some_and(|status| !status.isolate) {
+                        stack.pop();
+                    }
+                    stack.pop();
+                    valid_isolates -= 1;
+                }
+                let last = *stack
+                    .last()
+                    .expect("the directional status stack is never empty");
+                levels[index] = last.level;
+                if let Some(override_class) = last.override_class {
+                    classes[index] = override_class;
+                }
+            }
+            // X7: terminating embeddings and overrides.
+            BidiClass::PDF => {
+                levels[index] = last.level;
+                if overflow_isolates > 0 {
+                    // The PDF is inside an overflow isolate and is ignored.
+                } else if overflow_embeddings > 0 {
+                    overflow_embeddings -= 1;
+                } else if !last.isolate && stack.len() >= 2 {
+                    stack.pop();
+                }
+            }
+            // X8: end of paragraph.
+            BidiClass::B => levels[index] = paragraph_level,
+            BidiClass::BN => levels[index] = last.level,
+            // X6: everything else.
+            _ => {
+                levels[index] = last.level;
+                if let Some(override_class) = last.override_class {
+                    classes[index] = override_class;
+                }
+            }
+        }
+    }
+    levels
+}
+
+/// Returns the least odd (`rtl`) or even level greater than `level`.
+#[inline]
+fn next_level(level: u8, rtl: bool) -> u8 {
+    if rtl {
+        (level + 1) | 1
+    } else {
+        (level + 2) & !1
+    }
+}
+
+/// An isolating run sequence (BD13): the indices of its chars, in logical order, and the
+/// direction of its start and end of sequence (`sos` and `eos`).
+pub(crate) struct IsolatingRunSequence {
+    pub(crate) indices: Vec<usize>,
+    pub(crate) level: u8,
+    pub(crate) sos: BidiClass,
+    pub(crate) eos: BidiClass,
+}
+
+/// Splits a paragraph into isolating run sequences (rule X10), ignoring the chars removed by
+/// rule X9.
+pub(crate) fn isolating_run_sequences(
+    original: &[BidiClass],
+    levels: &[u8],
+    paragraph_level: u8,
+) -> Vec<IsolatingRunSequence> {
+    let matches = matching_pdis(original);
+
+    // BD7: level runs.
+    let mut runs: Vec<Vec<usize>> = Vec::new();
+    let mut last_level = None;
+    for index in (0..original.len()).filter(|&index| !original[index].is_removed_by_x9()) {
+        match runs.last_mut() {
+            Some(run) if last_level == Some(levels[index]) => run.push(index),
+            _ => runs.push(vec![index]),
+        }
+        last_level = Some(levels[index]);
+    }
+
+    // BD13: chain the level runs connected by isolate initiators and their matching PDIs.
+    let mut run_starting_at = vec![None; original.len()];
+    for (run_index, run) in runs.iter().enumerate() {
+        run_starting_at[run[0]] = Some(run_index);
+    }
+    let mut is_matching_pdi = vec![false; original.len()];
+    for &pdi in matches.iter().flatten() {
+        is_matching_pdi[pdi] = true;
+    }
+    let mut sequences = Vec::new();
+    for run in &runs {
+        if is_matching_pdi[run[0]] {
+            continue;
+        }
+        let mut indices = run.clone();
+        while let Some(&last) = indices.last() {
+            let next_run = original[last]
+                .is_isolate_initiator()
+                .then(|| matches[last])
+                .flatten()
+                .and_then(|pdi| run_starting_at[pdi]);
+            match next_run {
+                Some(run_index) => indices.extend_from_slice(&runs[run_index]),
+                None => break,
+            }
+        }
+        sequences.push(indices);
+    }
+
+    sequences
+        .into_iter()
+        .map(|indices| {
+            let first = indices[0];
+            let last = *indices
+                .last()
+                .expect("isolating run sequences are never empty");
+            let level = levels[first];
+
+            let before = (0..first)
+                .rev()
+                .find(|&index| !original[index].is_removed_by_x9())
+                .map_or(paragraph_level, |index| levels[index]);
+            let after = if original[last].is_isolate_initiator() {
+                paragraph_level
+            } else {
+                (last + 1..original.len())
+                    .find(|&index| !original[index].is_removed_by_x9())
+                    .map_or(paragraph_level, |index| levels[index])
+            };
+
+            IsolatingRunSequence {
+                indices,
+                level,
+                sos: direction_of(level.max(before)),
+                eos: direction_of(level.max(after)),
+            }
+        })
+        .collect()
+}
+
+#[inline]
+pub(crate) fn direction_of(level: u8) -> BidiClass {
+    if level & 1 == 0 {
+        BidiClass::L
+    } else {
+        BidiClass::R
+    }
+}
@@ -0,0 +1,240 @@
+use crate::bidi::explicit::{direction_of, IsolatingRunSequence};
+use crate::bidi::{paired_bracket, BidiClass};
+use crate::normalization::decompose_canonical;
+
+/// The maximum nesting of bracket pairs tracked by BD16.
+const MAX_BRACKET_DEPTH: usize = 63;
+
+/// Resolves the types and levels of an isolating run sequence: the weak type rules (W1 - W7),
+/// the neutral and isolate rules (N0 - N2) and the implicit levels (I1 - I2).
+pub(crate) fn resolve_sequence(
+    sequence: &IsolatingRunSequence,
+    text: &[char],
+    original: &[BidiClass],
+    classes: &mut [BidiClass],
+    levels: &mut [u8],
+) {
+    let indices = &sequence.indices;
+    let mut types: Vec<BidiClass> = indices.iter().map(|&index| classes[index]).collect();
+
+    resolve_weak_types(&mut types, sequence.sos);
+    resolve_paired_brackets(sequence, text, original, &mut types);
+    resolve_neutral_types(&mut types, sequence);
+
+    // I1 and I2.
+    for (&index, &class) in indices.iter().zip(&types) {
+        classes[index] = class;
+        let level = levels[index];
+        levels[index] += match (level & 1 == 0, class) {
+            (true, BidiClass::R) => 1,
+            (true, BidiClass::AN | BidiClass::EN) => 2,
+            (false, BidiClass::L | BidiClass::EN | BidiClass::AN) => 1,
+            _ => 0,
+        };
+    }
+}
+
+fn resolve_weak_types(types: &mut [BidiClass], sos: BidiClass) {
+    // W1: non-spacing marks take the type of the previous char.
+    let mut previous = sos;
+    for class in types.iter_mut() {
+        if *class == BidiClass::NSM {
+            *class = if previous.is_isolate_initiator() || previous == BidiClass::PDI {
+                BidiClass::ON
+            } else {
+                previous
+            };
+        }
+        previous = *class;
+    }
+
+    // W2: European numbers after Arabic letters become Arabic numbers. W3: AL becomes R.
+    let mut last_strong = sos;
+    for class in types.iter_mut() {
+        match *class {
+            BidiClass::L | BidiClass::R | BidiClass::AL => last_strong = *class,
+            BidiClass::EN if last_strong == BidiClass::AL => *class = BidiClass::AN,
+            _ => {}
+        }
+    }
+    for class in types.iter_mut() {
+        if *class == BidiClass::AL {
+            *class = BidiClass::R;
+        }
+    }
+
+    // W4: a single separator between two numbers of the same type takes their type.
+    for index in 1..types.len().saturating_sub(1) {
+        let (before, after) = (types[index - 1], types[index + 1]);
+        match types[index] {
+            BidiClass::ES if before == BidiClass::EN && after == BidiClass::EN => {
+                types[index] = BidiClass::EN;
+            }
+            BidiClass::CS if before == after && matches!(before, BidiClass::EN | BidiClass::AN) => {
+                types[index] = before;
+            }
+            _ => {}
+        }
+    }
+
+    // W5: terminators adjacent to European numbers become European numbers.
+    let mut index = 0;
+    while index < types.len() {
+        if types[index] != BidiClass::ET {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        while index < types.len() && types[index] == BidiClass::ET {
+            index += 1;
+        }
+        let touches_number = (start > 0 && types[start - 1] == BidiClass::EN)
+            || (index < types.len() && types[index] == BidiClass::EN);
+        if touches_number {
+            types[start..index].fill(BidiClass::EN);
+        }
+    }
+
+    // W6: remaining separators and terminators become neutral.
+    for class in types.iter_mut() {
+        if matches!(*class, BidiClass::ES | BidiClass::ET | BidiClass::CS) {
+            *class = BidiClass::ON;
+        }
+    }
+
+    // W7: European numbers in a left-to-right context become L.
+    let mut last_strong = sos;
+    for class in types.iter_mut() {
+        match *class {
+            BidiClass::L | BidiClass::R => last_strong = *class,
+            BidiClass::EN if last_strong == BidiClass::L => *class = BidiClass::L,
+            _ => {}
+        }
+    }
+}
+
+/// Returns the strong direction a resolved type counts as for rules N0 to N2, where numbers
+/// count as right-to-left.
+#[inline]
+fn strong_direction(class: BidiClass) -> Option<BidiClass> {
+    match class {
+        BidiClass::L => Some(BidiClass::L),
+        BidiClass::R | BidiClass::AN | BidiClass::EN => Some(BidiClass::R),
+        _ => None,
+    }
+}
+
+/// Maps the brackets that are canonically equivalent to another bracket to that bracket, so
+/// that e.g. U+2329 and U+3009 form a pair.
+#[inline]
+fn canonical_bracket(c: char) -> char {
+    let mut decomposition = Vec::with_capacity(1);
+    decompose_canonical(c, &mut decomposition);
+    match decomposition[..] {
+        [single] => single,
+        _ => c,
+    }
+}
+
+/// Identifies the bracket pairs of the sequence (BD16), sorted by the position of their
+/// opening bracket.
+fn bracket_pairs(indices: &[usize], text: &[char], types: &[BidiClass]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    let mut stack: Vec<(char, usize)> = Vec::new();
+    for (position, &index) in indices.iter().enumerate() {
+        if types[position] != BidiClass::ON {
+            continue;
+        }
+        let Some((pair, opening)) = paired_bracket(text[index]) else {
+            continue;
+        };
+        if opening {
+            if stack.len() == MAX_BRACKET_DEPTH {
+                break;
+            }
+            stack.push((canonical_bracket(pair), position));
+        } else {
+            let closing = canonical_bracket(text[index]);
+            if let Some(depth) = stack.iter().rposition(|&(expected, _)| expected == closing) {
+                pairs.push((stack[depth].1, position));
+                stack.truncate(depth);
+            }
+        }
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+/// N0: paired brackets take the direction of the text they enclose or surround.
+fn resolve_paired_brackets(
+    sequence: &IsolatingRunSequence,
+    text: &[char],
+    original: &[BidiClass],
+    types: &mut [BidiClass],
+) {
+    let indices = &sequence.indices;
+    let embedding = direction_of(sequence.level);
+    for (open, close) in bracket_pairs(indices, text, types) {
+        let mut found_embedding = false;
+        let mut found_opposite = false;
+        for &class in &types[open + 1..close] {
+            match strong_direction(class) {
+                Some(direction) if direction == embedding => found_embedding = true,
+                Some(_) => found_opposite = true,
+                None => {}
+            }
+        }
+
+        let direction = if found_embedding {
+            embedding
+        } else if found_opposite {
+            // The brackets take the opposite direction only if the preceding context has it too,
+            // and the embedding direction otherwise, which is the direction of the context.
+            types[..open]
+                .iter()
+                .rev()
+                .find_map(|&class| strong_direction(class))
+                .unwrap_or(sequence.sos)
+        } else {
+            continue;
+        };
+
+        for bracket in [open, close] {
+            types[bracket] = direction;
+            // Non-spacing marks following a bracket take its new type.
+            for position in bracket + 1..types.len() {
+                if original[indices[position]] != BidiClass::NSM {
+                    break;
+                }
+                types[position] = direction;
+            }
+        }
+    }
+}
+
+/// N1 and N2: sequences of neutrals take the direction of the surrounding text if it agrees,
+/// and the embedding direction otherwise.
+fn resolve_neutral_types(types: &mut [BidiClass], sequence: &IsolatingRunSequence) {
+    let embedding = direction_of(sequence.level);
+    let mut index = 0;
+    while index < types.len() {
+        if !types[index].is_neutral_or_isolate() {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        while index < types.len() && types[index].is_neutral_or_isolate() {
+            index += 1;
+        }
+        let before = match start {
+            0 => sequence.sos,
+            _ => strong_direction(types[start - 1]).unwrap_or(embedding),
+        };
+        let after = match types.get(index) {
+            Some(&class) => strong_direction(class).unwrap_or(embedding),
+            None => sequence.eos,
+        };
+        let direction = if before == after { before } else { embedding };
+        types[start..index].fill(direction);
+    }
+}
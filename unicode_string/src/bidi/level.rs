@@ -0,0 +1,65 @@
+/// An embedding level of the Unicode Bidirectional Algorithm.
+///
+/// Even levels are left-to-right and odd levels are right-to-left. Levels range from 0 to
+/// [`Level::MAX_EXPLICIT_DEPTH`] + 1, since the implicit rules may raise the deepest explicit
+/// embedding by one.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::Level;
+///
+/// assert!(Level::LTR.is_ltr());
+/// assert!(Level::RTL.is_rtl());
+/// assert_eq!(Level::new(2).map(Level::number), Some(2));
+/// assert_eq!(Level::new(127), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Level(u8);
+
+impl Level {
+    /// The left-to-right paragraph level.
+    pub const LTR: Level = Level(0);
+
+    /// The right-to-left paragraph level.
+    pub const RTL: Level = Level(1);
+
+    /// The deepest level explicit embeddings and isolates can reach.
+    pub const MAX_EXPLICIT_DEPTH: u8 = 125;
+
+    /// Creates a level, or returns `None` if `number` is greater than
+    /// `Level::MAX_EXPLICIT_DEPTH + 1`.
+    #[inline]
+    pub const fn new(number: u8) -> Option<Level> {
+        if number <= Level::MAX_EXPLICIT_DEPTH + 1 {
+            Some(Level(number))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn new_unchecked(number: u8) -> Level {
+        Level(number)
+    }
+
+    /// Returns the level as a number.
+    #[inline]
+    pub const fn number(self) -> u8 {
+        self.0
+    }
+
+    /// Checks if the level is left-to-right (even).
+    #[inline]
+    pub const fn is_ltr(self) -> bool {
+        self.0 & 1 == 0
+    }
+
+    /// Checks if the level is right-to-left (odd).
+    #[inline]
+    pub const fn is_rtl(self) -> bool {
+        self.0 & 1 == 1
+    }
+}
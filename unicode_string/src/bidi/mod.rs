@@ -0,0 +1,9 @@
+mod bidi_class;
+mod bidi_info;
+mod explicit;
+mod implicit;
+mod level;
+
+pub use self::bidi_class::*;
+pub use self::bidi_info::*;
+pub use self::level::*;
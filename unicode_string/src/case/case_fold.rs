@@ -0,0 +1,80 @@
+use std::iter::FusedIterator;
+use std::slice;
+
+use crate::tables::case_folding::{FULL_CASE_FOLDING, SIMPLE_CASE_FOLDING};
+use crate::unicode_str;
+
+/// Returns the full case folding of `c`, or `None` if `c` folds to itself.
+#[inline]
+pub(crate) fn fold_char(c: char) -> Option<&'static [char]> {
+    if c.is_ascii() {
+        return None;
+    }
+    FULL_CASE_FOLDING
+        .binary_search_by_key(&c, |&(code, _)| code)
+        .ok()
+        .map(|index| FULL_CASE_FOLDING[index].1)
+}
+
+/// Returns the simple case folding of `c`, which maps every char to a single char.
+#[inline]
+pub(crate) fn simple_fold_char(c: char) -> char {
+    if c.is_ascii() {
+        return c.to_ascii_lowercase();
+    }
+    SIMPLE_CASE_FOLDING
+        .binary_search_by_key(&c, |&(code, _)| code)
+        .map_or(c, |index| SIMPLE_CASE_FOLDING[index].1)
+}
+
+/// An iterator over the full case folding of a `unicode_str`.
+///
+/// Folding happens lazily, char by char, so comparing two strings this way never allocates.
+#[derive(Clone, Debug)]
+pub(crate) struct CaseFold<'a> {
+    chars: slice::Iter<'a, char>,
+    pending: &'static [char],
+}
+
+impl<'a> CaseFold<'a> {
+    #[inline]
+    pub(crate) fn new(s: &'a unicode_str) -> CaseFold<'a> {
+        CaseFold {
+            chars: s.chars().iter(),
+            pending: &[],
+        }
+    }
+}
+
+impl Iterator for CaseFold<'_> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        if let Some((&first, rest)) = self.pending.split_first() {
+            self.pending = rest;
+            return Some(first);
+        }
+        let c = *self.chars.next()?;
+        if c.is_ascii() {
+            return Some(c.to_ascii_lowercase());
+        }
+        match fold_char(c) {
+            Some(folded) => {
+                self.pending = &folded[1..];
+                Some(folded[0])
+            }
+            None => Some(c),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.pending.len();
+        let remaining = self.chars.len();
+        // No char folds to more than three chars.
+        (pending + remaining, Some(pending + remaining * 3))
+    }
+}
+
+impl FusedIterator for CaseFold<'_> {}
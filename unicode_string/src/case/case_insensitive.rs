@@ -0,0 +1,117 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use crate::case::CaseFold;
+use crate::{unicode_str, UnicodeString};
+
+/// A wrapper that compares, orders and hashes strings by their full case folding.
+///
+/// This makes it possible to use `UnicodeString`s as case-insensitive keys of a [`HashMap`]
+/// or [`BTreeMap`]. The wrapped value is kept as is, so the original casing is still
+/// available through the public field.
+///
+/// [`HashMap`]: std::collections::HashMap
+/// [`BTreeMap`]: std::collections::BTreeMap
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::collections::HashMap;
+/// use unicode_string::{CaseInsensitive, UnicodeString, ustr};
+///
+/// let mut streets = HashMap::new();
+/// streets.insert(CaseInsensitive(UnicodeString::from_string("Hauptstraße")), 1);
+///
+/// assert_eq!(streets.get(CaseInsensitive::from_ref(ustr!("HAUPTSTRASSE"))), Some(&1));
+/// assert_eq!(streets.get(CaseInsensitive::from_ref(ustr!("hauptstrasse"))), Some(&1));
+/// assert_eq!(streets.get(CaseInsensitive::from_ref(ustr!("Nebenstraße"))), None);
+/// ```
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct CaseInsensitive<T: ?Sized>(pub T);
+
+impl CaseInsensitive<unicode_str> {
+    /// Wraps a string slice reference without copying it.
+    ///
+    /// This is mostly useful for looking up keys in maps keyed by
+    /// `CaseInsensitive<UnicodeString>`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::{CaseInsensitive, ustr};
+    ///
+    /// assert_eq!(CaseInsensitive::from_ref(ustr!("ǅ")), CaseInsensitive::from_ref(ustr!("ǆ")));
+    /// ```
+    #[inline]
+    pub fn from_ref(s: &unicode_str) -> &CaseInsensitive<unicode_str> {
+        // SAFETY: `CaseInsensitive` is a `repr(transparent)` wrapper around `unicode_str`.
+        unsafe { &*(s as *const unicode_str as *const CaseInsensitive<unicode_str>) }
+    }
+}
+
+impl<T: AsRef<unicode_str> + ?Sized> CaseInsensitive<T> {
+    #[inline]
+    fn fold(&self) -> CaseFold<'_> {
+        CaseFold::new(self.0.as_ref())
+    }
+}
+
+impl<T: AsRef<unicode_str> + ?Sized, U: AsRef<unicode_str> + ?Sized> PartialEq<CaseInsensitive<U>>
+    for CaseInsensitive<T>
+{
+    #[inline]
+    fn eq(&self, other: &CaseInsensitive<U>) -> bool {
+        self.0.as_ref().eq_ignore_case(other.0.as_ref())
+    }
+}
+
+impl<T: AsRef<unicode_str> + ?Sized> Eq for CaseInsensitive<T> {}
+
+impl<T: AsRef<unicode_str> + ?Sized> PartialOrd for CaseInsensitive<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &CaseInsensitive<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: AsRef<unicode_str> + ?Sized> Ord for CaseInsensitive<T> {
+    #[inline]
+    fn cmp(&self, other: &CaseInsensitive<T>) -> Ordering {
+        self.0.as_ref().cmp_ignore_case(other.0.as_ref())
+    }
+}
+
+impl<T: AsRef<unicode_str> + ?Sized> Hash for CaseInsensitive<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for c in self.fold() {
+            state.write_u32(c as u32);
+        }
+        // Same trick as `str` uses to keep the hash prefix-free.
+        state.write_u8(0xff);
+    }
+}
+
+impl Borrow<CaseInsensitive<unicode_str>> for CaseInsensitive<UnicodeString> {
+    #[inline]
+    fn borrow(&self) -> &CaseInsensitive<unicode_str> {
+        CaseInsensitive::from_ref(&self.0)
+    }
+}
+
+impl<T: ?Sized + std::fmt::Debug> std::fmt::Debug for CaseInsensitive<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: ?Sized + std::fmt::Display> std::fmt::Display for CaseInsensitive<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,5 @@
+mod case_fold;
+mod case_insensitive;
+
+pub(crate) use self::case_fold::*;
+pub use self::case_insensitive::*;
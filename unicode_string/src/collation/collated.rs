@@ -0,0 +1,94 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::collation::Collator;
+use crate::unicode_str;
+
+/// A string slice that is ordered using a [`Collator`].
+///
+/// The sort key is computed once when the value is created, so sorting a list of `Collated`
+/// values or using them as keys of a [`BTreeMap`] does not recompute it on every comparison.
+///
+/// Two values are only meaningfully compared when they were created by equal collators.
+///
+/// [`BTreeMap`]: std::collections::BTreeMap
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::{Collator, ustr};
+///
+/// let collator = Collator::for_locale("sv").unwrap();
+/// let mut words: Vec<_> = [ustr!("ära"), ustr!("ärlig"), ustr!("ö"), ustr!("aj"), ustr!("zon")]
+///     .into_iter()
+///     .map(|word| collator.collated(word))
+///     .collect();
+///
+/// words.sort();
+/// let words: Vec<_> = words.iter().map(|word| word.as_unicode_str()).collect();
+/// assert_eq!(words, [ustr!("aj"), ustr!("zon"), ustr!("ära"), ustr!("ärlig"), ustr!("ö")]);
+/// ```
+#[derive(Clone)]
+pub struct Collated<'a> {
+    s: &'a unicode_str,
+    key: Vec<u16>,
+}
+
+impl<'a> Collated<'a> {
+    #[inline]
+    pub(crate) fn new(collator: &Collator, s: &'a unicode_str) -> Collated<'a> {
+        Collated {
+            s,
+            key: collator.sort_key(s),
+        }
+    }
+
+    /// Returns the wrapped string slice.
+    #[inline]
+    pub fn as_unicode_str(&self) -> &'a unicode_str {
+        self.s
+    }
+
+    /// Returns the sort key the value is ordered by.
+    #[inline]
+    pub fn sort_key(&self) -> &[u16] {
+        &self.key
+    }
+}
+
+impl PartialEq for Collated<'_> {
+    #[inline]
+    fn eq(&self, other: &Collated<'_>) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Collated<'_> {}
+
+impl PartialOrd for Collated<'_> {
+    #[inline]
+    fn partial_cmp(&self, other: &Collated<'_>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Collated<'_> {
+    #[inline]
+    fn cmp(&self, other: &Collated<'_>) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl fmt::Debug for Collated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.s, f)
+    }
+}
+
+impl fmt::Display for Collated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.s, f)
+    }
+}
@@ -0,0 +1,304 @@
+use std::cmp::Ordering;
+
+use crate::collation::elements::{
+    implicit_elements, is_variable, lookup, primary, secondary, tertiary,
+};
+use crate::collation::{Collated, Tailoring};
+use crate::normalization::{canonical_combining_class, nfd};
+use crate::tables::collation::{DUCET_VERSION, MAX_CONTRACTION_LEN};
+use crate::unicode_str;
+
+/// The number of levels compared by a [`Collator`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Strength {
+    /// Only base letters are compared: `a`, `A` and `á` are equal.
+    Primary,
+    /// Base letters and accents are compared: `a` and `A` are equal, `á` is different.
+    Secondary,
+    /// Base letters, accents and case are compared.
+    #[default]
+    Tertiary,
+    /// Additionally compares the variable (punctuation and whitespace) chars ignored when using
+    /// [`Alternate::Shifted`]. Equivalent to [`Strength::Tertiary`] with
+    /// [`Alternate::NonIgnorable`].
+    Quaternary,
+}
+
+/// How variable collation elements (whitespace, punctuation and most symbols) are handled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Alternate {
+    /// Variable elements are treated like any other: `"de luca"` sorts before `"delta"`
+    /// because the space sorts before letters.
+    #[default]
+    NonIgnorable,
+    /// Variable elements are ignored on the first three levels and only compared on the
+    /// quaternary level: `"de luca"` sorts between `"delta"` and `"dem"`.
+    Shifted,
+}
+
+/// Compares strings according to the Unicode Collation Algorithm (UTS #10).
+///
+/// The [`Ord`] implementation of `unicode_str` compares code points, which is fast but does
+/// not match the order people expect: all uppercase letters sort before lowercase ones, and
+/// accented letters sort after `z`. A `Collator` instead uses the Default Unicode Collation
+/// Element Table (DUCET), optionally [tailored](Tailoring) for a language, and compares base
+/// letters first, then accents, then case.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::{Collator, ustr};
+///
+/// let collator = Collator::new();
+/// let mut words = vec![ustr!("zebra"), ustr!("Äpfel"), ustr!("apple"), ustr!("Zoo")];
+///
+/// words.sort();
+/// assert_eq!(words, [ustr!("Zoo"), ustr!("apple"), ustr!("zebra"), ustr!("Äpfel")]);
+///
+/// words.sort_by(|a, b| collator.compare(a, b));
+/// assert_eq!(words, [ustr!("Äpfel"), ustr!("apple"), ustr!("zebra"), ustr!("Zoo")]);
+/// ```
+///
+/// Tailorings change the order for a particular language:
+///
+/// ```
+/// use unicode_string::{Collator, Tailoring, ustr};
+///
+/// let mut words = vec![ustr!("öl"), ustr!("zon"), ustr!("ost")];
+///
+/// words.sort_by(|a, b| Collator::new().compare(a, b));
+/// assert_eq!(words, [ustr!("öl"), ustr!("ost"), ustr!("zon")]);
+///
+/// let swedish = Collator::with_tailoring(Tailoring::Swedish);
+/// words.sort_by(|a, b| swedish.compare(a, b));
+/// assert_eq!(words, [ustr!("ost"), ustr!("zon"), ustr!("öl")]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Collator {
+    tailoring: Tailoring,
+    strength: Strength,
+    alternate: Alternate,
+}
+
+impl Collator {
+    /// The version of the Default Unicode Collation Element Table used by the collator.
+    pub const DUCET_VERSION: (u8, u8, u8) = DUCET_VERSION;
+
+    /// Creates a collator using the root collation order, tertiary strength and non-ignorable
+    /// variable elements.
+    pub const fn new() -> Collator {
+        Collator::with_tailoring(Tailoring::Root)
+    }
+
+    /// Creates a collator using the given tailoring.
+    pub const fn with_tailoring(tailoring: Tailoring) -> Collator {
+        Collator {
+            tailoring,
+            strength: Strength::Tertiary,
+            alternate: Alternate::NonIgnorable,
+        }
+    }
+
+    /// Creates a collator for a BCP 47 language tag, or returns `None` if there is no tailoring
+    /// for the language. See [`Tailoring::from_locale`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use unicode_string::{Collator, ustr};
+    ///
+    /// let spanish = Collator::for_locale("es").unwrap();
+    /// assert_eq!(spanish.compare(ustr!("ñu"), ustr!("nz")), Ordering::Greater);
+    /// assert_eq!(Collator::new().compare(ustr!("ñu"), ustr!("nz")), Ordering::Less);
+    /// ```
+    pub fn for_locale(locale: &str) -> Option<Collator> {
+        Tailoring::from_locale(locale).map(Collator::with_tailoring)
+    }
+
+    /// Sets the number of levels that are compared.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use unicode_string::{Collator, Strength, ustr};
+    ///
+    /// let primary = Collator::new().strength(Strength::Primary);
+    /// assert_eq!(primary.compare(ustr!("Résumé"), ustr!("resume")), Ordering::Equal);
+    ///
+    /// let secondary = Collator::new().strength(Strength::Secondary);
+    /// assert_eq!(secondary.compare(ustr!("Résumé"), ustr!("résumé")), Ordering::Equal);
+    /// assert_eq!(secondary.compare(ustr!("Résumé"), ustr!("resume")), Ordering::Greater);
+    /// ```
+    #[must_use]
+    pub const fn strength(mut self, strength: Strength) -> Collator {
+        self.strength = strength;
+        self
+    }
+
+    /// Sets how variable collation elements (whitespace and punctuation) are handled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use unicode_string::{Alternate, Collator, ustr};
+    ///
+    /// let collator = Collator::new();
+    /// assert_eq!(collator.compare(ustr!("de luca"), ustr!("delta")), Ordering::Less);
+    ///
+    /// let collator = Collator::new().alternate(Alternate::Shifted);
+    /// assert_eq!(collator.compare(ustr!("de luca"), ustr!("delta")), Ordering::Greater);
+    /// ```
+    #[must_use]
+    pub const fn alternate(mut self, alternate: Alternate) -> Collator {
+        self.alternate = alternate;
+        self
+    }
+
+    /// Compares two strings.
+    ///
+    /// This is equivalent to comparing their [sort keys](Collator::sort_key).
+    #[must_use]
+    pub fn compare(&self, a: &unicode_str, b: &unicode_str) -> Ordering {
+        if a == b {
+            return Ordering::Equal;
+        }
+        self.sort_key(a).cmp(&self.sort_key(b))
+    }
+
+    /// Wraps `s` in a [`Collated`] value that is ordered using this collator.
+    #[inline]
+    pub fn collated<'a>(&self, s: &'a unicode_str) -> Collated<'a> {
+        Collated::new(self, s)
+    }
+
+    /// Returns the sort key of `s`.
+    ///
+    /// Comparing sort keys gives the same result as comparing the strings with [`compare`],
+    /// which makes them useful when the same strings are compared many times, e.g. with
+    /// [`slice::sort_by_cached_key`].
+    ///
+    /// [`compare`]: Collator::compare
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::{Collator, Strength, ustr};
+    ///
+    /// let collator = Collator::new().strength(Strength::Primary);
+    /// assert_eq!(collator.sort_key(ustr!("ab")), [0x1FA2, 0x1FBC]);
+    ///
+    /// let mut names = vec![ustr!("Émile"), ustr!("eve"), ustr!("Ella")];
+    /// names.sort_by_cached_key(|name| Collator::new().sort_key(name));
+    /// assert_eq!(names, [ustr!("Ella"), ustr!("Émile"), ustr!("eve")]);
+    /// ```
+    pub fn sort_key(&self, s: &unicode_str) -> Vec<u16> {
+        let elements = self.collation_elements(s.chars());
+        let shifted = self.alternate == Alternate::Shifted;
+        let levels = match self.strength {
+            Strength::Primary => 1,
+            Strength::Secondary => 2,
+            Strength::Tertiary => 3,
+            Strength::Quaternary if shifted => 4,
+            Strength::Quaternary => 3,
+        };
+
+        let mut key = Vec::with_capacity(elements.len() * levels + levels);
+        for level in 0..levels {
+            if level > 0 {
+                key.push(0);
+            }
+            let mut after_variable = false;
+            for &element in &elements {
+                let weights = if shifted && is_variable(element) {
+                    after_variable = true;
+                    [0, 0, 0, primary(element)]
+                } else if shifted && primary(element) == 0 && after_variable {
+                    [0; 4]
+                } else {
+                    if primary(element) != 0 {
+                        after_variable = false;
+                    }
+                    let quaternary = if element == 0 { 0 } else { 0xFFFF };
+                    [primary(element), secondary(element), tertiary(element), quaternary]
+                };
+                if weights[level] != 0 {
+                    key.push(weights[level]);
+                }
+            }
+        }
+        key
+    }
+
+    /// Looks up `key` in the tailoring, then in the DUCET.
+    fn lookup(&self, key: &[char]) -> Option<&'static [u32]> {
+        let tailored = self.tailoring.entries();
+        match tailored.binary_search_by(|&(entry, _)| entry.cmp(key)) {
+            Ok(index) => Some(tailored[index].1),
+            Err(_) => lookup(key),
+        }
+    }
+
+    /// Produces the collation element array of `chars` (steps S1 and S2 of the algorithm).
+    fn collation_elements(&self, chars: &[char]) -> Vec<u32> {
+        let mut chars = nfd(chars);
+        let mut elements = Vec::with_capacity(chars.len());
+        let mut index = 0;
+        while index < chars.len() {
+            // S2.1: find the longest initial substring that has a match in the table.
+            let longest = MAX_CONTRACTION_LEN.min(chars.len() - index);
+            let mut matched = (1..=longest)
+                .rev()
+                .find_map(|len| Some((len, self.lookup(&chars[index..index + len])?)));
+
+            // S2.1.1 - S2.1.3: extend the match with unblocked non-starters that follow it.
+            if let Some((len, found)) = matched.as_mut() {
+                let mut key = chars[index..index + *len].to_vec();
+                let mut next = index + *len;
+                let mut last_skipped_class = 0;
+                while next < chars.len() {
+                    let class = canonical_combining_class(chars[next]);
+                    if class == 0 {
+                        break;
+                    }
+                    if last_skipped_class < class {
+                        key.push(chars[next]);
+                        if let Some(extended) = self.lookup(&key) {
+                            *found = extended;
+                            chars.remove(next);
+                            continue;
+                        }
+                        key.pop();
+                    }
+                    last_skipped_class = class;
+                    next += 1;
+                }
+            }
+
+            // S2.2 - S2.4: append the collation elements, deriving implicit ones if needed.
+            match matched {
+                Some((len, found)) => {
+                    elements.extend_from_slice(found);
+                    index += len;
+                }
+                None => {
+                    elements.extend_from_slice(&implicit_elements(chars[index]));
+                    index += 1;
+                }
+            }
+        }
+        elements
+    }
+}
@@ -0,0 +1,87 @@
+use crate::tables::collation::{
+    COLLATION_ELEMENTS, CONTRACTIONS, IMPLICIT_WEIGHTS, SINGLES, UNIFIED_IDEOGRAPH,
+};
+
+/// Packs a collation element the same way the generated tables do:
+/// `primary << 16 | secondary << 7 | tertiary << 2 | variable`.
+pub(crate) const fn pack(primary: u16, secondary: u16, tertiary: u8) -> u32 {
+    (primary as u32) << 16 | (secondary as u32) << 7 | (tertiary as u32) << 2
+}
+
+#[inline]
+pub(crate) const fn primary(element: u32) -> u16 {
+    (element >> 16) as u16
+}
+
+#[inline]
+pub(crate) const fn secondary(element: u32) -> u16 {
+    ((element >> 7) & 0x1FF) as u16
+}
+
+#[inline]
+pub(crate) const fn tertiary(element: u32) -> u16 {
+    ((element >> 2) & 0x1F) as u16
+}
+
+#[inline]
+pub(crate) const fn is_variable(element: u32) -> bool {
+    element & 1 != 0
+}
+
+#[inline]
+fn elements_at(index: u32) -> &'static [u32] {
+    let start = (index >> 5) as usize;
+    let len = (index & 0x1F) as usize;
+    &COLLATION_ELEMENTS[start..start + len]
+}
+
+/// Looks up the collation elements of `key` (a single char or a contraction) in the DUCET.
+pub(crate) fn lookup(key: &[char]) -> Option<&'static [u32]> {
+    match key {
+        [] => None,
+        [c] => SINGLES
+            .binary_search_by_key(c, |&(code, _)| code)
+            .ok()
+            .map(|index| elements_at(SINGLES[index].1)),
+        _ => CONTRACTIONS
+            .binary_search_by(|&(entry, _)| entry.cmp(key))
+            .ok()
+            .map(|index| elements_at(CONTRACTIONS[index].1)),
+    }
+}
+
+/// Computes the implicit collation elements of a char that has no entry in the DUCET, as
+/// described in section 10.1 of UTS #10.
+pub(crate) fn implicit_elements(c: char) -> [u32; 2] {
+    let code = c as u32;
+    let (base, offset) = if let Some(&(_, _, base)) = IMPLICIT_WEIGHTS
+        .iter()
+        .find(|&&(start, end, _)| start <= c && c <= end)
+    {
+        // Scripts listed in `@implicitweights` are numbered from the start of their first range.
+        let first = IMPLICIT_WEIGHTS
+            .iter()
+            .filter(|&&(_, _, other)| other == base)
+            .map(|&(start, _, _)| start as u32)
+            .min()
+            .unwrap_or(code);
+        (base, code - first)
+    } else if UNIFIED_IDEOGRAPH
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                std::cmp::Ordering::Less
+            } else if start > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+    {
+        let core = matches!(code, 0x4E00..=0x9FFF | 0xF900..=0xFAFF);
+        ((if core { 0xFB40 } else { 0xFB80 }) + (code >> 15) as u16, code & 0x7FFF)
+    } else {
+        (0xFBC0 + (code >> 15) as u16, code & 0x7FFF)
+    };
+    [pack(base, 0x20, 0x02), pack((offset | 0x8000) as u16, 0, 0)]
+}
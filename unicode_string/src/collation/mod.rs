@@ -0,0 +1,8 @@
+mod collated;
+mod collator;
+mod elements;
+mod tailoring;
+
+pub use self::collated::*;
+pub use self::collator::*;
+pub use self::tailoring::*;
@@ -0,0 +1,180 @@
+use crate::collation::elements::pack;
+use crate::tables::collation::{DENTAL_CLICK_PRIMARY, LATIN_PRIMARIES};
+
+/// A language specific customization of the default collation order.
+///
+/// The tailorings follow the CLDR collation rules for the given language, expressed as
+/// collation elements that fit in the gaps the DUCET leaves between letters.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::Tailoring;
+///
+/// assert_eq!(Tailoring::from_locale("sv-SE"), Some(Tailoring::Swedish));
+/// assert_eq!(Tailoring::from_locale("de-u-co-phonebk"), Some(Tailoring::GermanPhonebook));
+/// assert_eq!(Tailoring::from_locale("sv-u-co-trad"), Some(Tailoring::SwedishTraditional));
+/// assert_eq!(Tailoring::from_locale("tlh"), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Tailoring {
+    /// The root collation order defined by the DUCET.
+    #[default]
+    Root,
+    /// German (`de`). Standard German dictionary order is the root order: umlauts sort as
+    /// their base letters with a secondary difference.
+    German,
+    /// German phonebook order (`de-u-co-phonebk`): `ä`, `ö` and `ü` sort as `ae`, `oe` and `ue`.
+    GermanPhonebook,
+    /// Spanish (`es`): `ñ` is a separate letter between `n` and `o`.
+    Spanish,
+    /// Swedish (`sv`): `å`, `ä` and `ö` are separate letters after `z`.
+    Swedish,
+    /// Traditional Swedish order (`sv-u-co-trad`): like [`Swedish`](Tailoring::Swedish), and
+    /// `w` is a secondary variant of `v`.
+    SwedishTraditional,
+}
+
+impl Tailoring {
+    /// Returns the tailoring for a BCP 47 language tag, or `None` if there is no matching
+    /// tailoring.
+    ///
+    /// Only the language subtag and the `-u-co-phonebk` and `-u-co-trad` collation types are
+    /// taken into account.
+    pub fn from_locale(locale: &str) -> Option<Tailoring> {
+        let locale = locale.to_ascii_lowercase().replace('_', "-");
+        let language = locale.split('-').next().unwrap_or("");
+        let phonebook = locale.contains("-u-co-phonebk");
+        let traditional = locale.contains("-u-co-trad");
+        match language {
+            "" | "und" | "root" => Some(Tailoring::Root),
+            "de" if phonebook => Some(Tailoring::GermanPhonebook),
+            "de" => Some(Tailoring::German),
+            "es" => Some(Tailoring::Spanish),
+            "sv" if traditional => Some(Tailoring::SwedishTraditional),
+            "sv" => Some(Tailoring::Swedish),
+            _ => None,
+        }
+    }
+
+    /// Returns the tailored entries, sorted by key.
+    pub(crate) fn entries(self) -> &'static [(&'static [char], &'static [u32])] {
+        match self {
+            Tailoring::Root | Tailoring::German => &[],
+            Tailoring::GermanPhonebook => GERMAN_PHONEBOOK,
+            Tailoring::Spanish => SPANISH,
+            Tailoring::Swedish => SWEDISH,
+            Tailoring::SwedishTraditional => SWEDISH_TRADITIONAL,
+        }
+    }
+}
+
+const LOWER: u8 = 0x02;
+const UPPER: u8 = 0x08;
+
+/// Returns the primary weight of a Latin letter in the DUCET.
+const fn letter(c: char) -> u16 {
+    LATIN_PRIMARIES[c as usize - 'a' as usize]
+}
+
+const A: u16 = letter('a');
+const D: u16 = letter('d');
+const E: u16 = letter('e');
+const H: u16 = letter('h');
+const N: u16 = letter('n');
+const O: u16 = letter('o');
+const T: u16 = letter('t');
+const U: u16 = letter('u');
+const V: u16 = letter('v');
+const Y: u16 = letter('y');
+
+/// The primary weights in the DUCET gap right before `ǀ` (`&[before 1]ǀ`), after `z` and the
+/// other Latin letters. `unicode_string_gen` checks that they are unused.
+const AFTER_Z: u16 = DENTAL_CLICK_PRIMARY - 3;
+
+// &AE<<ä<<<Ä &OE<<ö<<<Ö &UE<<ü<<<Ü
+static GERMAN_PHONEBOOK: &[(&[char], &[u32])] = &[
+    (&['A', '\u{308}'], &[pack(A, 0x21, UPPER), pack(E, 0x21, UPPER)]),
+    (&['O', '\u{308}'], &[pack(O, 0x21, UPPER), pack(E, 0x21, UPPER)]),
+    (&['U', '\u{308}'], &[pack(U, 0x21, UPPER), pack(E, 0x21, UPPER)]),
+    (&['a', '\u{308}'], &[pack(A, 0x21, LOWER), pack(E, 0x21, LOWER)]),
+    (&['o', '\u{308}'], &[pack(O, 0x21, LOWER), pack(E, 0x21, LOWER)]),
+    (&['u', '\u{308}'], &[pack(U, 0x21, LOWER), pack(E, 0x21, LOWER)]),
+];
+
+// &N<ñ<<<Ñ, where `unicode_string_gen` checks that the primary weight after `n` is unused.
+static SPANISH: &[(&[char], &[u32])] = &[
+    (&['N', '\u{303}'], &[pack(N + 1, 0x20, UPPER)]),
+    (&['n', '\u{303}'], &[pack(N + 1, 0x20, LOWER)]),
+];
+
+// &D<<đ<<<Đ<<ð<<<Ð &t<<<þ/h &T<<<Þ/H &Y<<ü<<<Ü<<ű<<<Ű
+// &[before 1]ǀ<å<<<Å<ä<<<Ä<<æ<<<Æ<<ę<<<Ę<ö<<<Ö<<ø<<<Ø<<ő<<<Ő<<œ<<<Œ<<ô<<<Ô
+static SWEDISH: &[(&[char], &[u32])] = &[
+    (&['A', '\u{308}'], &[pack(AFTER_Z + 1, 0x20, UPPER)]),
+    (&['A', '\u{30A}'], &[pack(AFTER_Z, 0x20, UPPER)]),
+    (&['E', '\u{328}'], &[pack(AFTER_Z + 1, 0x22, UPPER)]),
+    (&['O', '\u{302}'], &[pack(AFTER_Z + 2, 0x24, UPPER)]),
+    (&['O', '\u{308}'], &[pack(AFTER_Z + 2, 0x20, UPPER)]),
+    (&['O', '\u{30B}'], &[pack(AFTER_Z + 2, 0x22, UPPER)]),
+    (&['U', '\u{308}'], &[pack(Y, 0x21, UPPER)]),
+    (&['U', '\u{30B}'], &[pack(Y, 0x22, UPPER)]),
+    (&['a', '\u{308}'], &[pack(AFTER_Z + 1, 0x20, LOWER)]),
+    (&['a', '\u{30A}'], &[pack(AFTER_Z, 0x20, LOWER)]),
+    (&['e', '\u{328}'], &[pack(AFTER_Z + 1, 0x22, LOWER)]),
+    (&['o', '\u{302}'], &[pack(AFTER_Z + 2, 0x24, LOWER)]),
+    (&['o', '\u{308}'], &[pack(AFTER_Z + 2, 0x20, LOWER)]),
+    (&['o', '\u{30B}'], &[pack(AFTER_Z + 2, 0x22, LOWER)]),
+    (&['u', '\u{308}'], &[pack(Y, 0x21, LOWER)]),
+    (&['u', '\u{30B}'], &[pack(Y, 0x22, LOWER)]),
+    (&['\u{C6}'], &[pack(AFTER_Z + 1, 0x21, UPPER)]),
+    (&['\u{D0}'], &[pack(D, 0x22, UPPER)]),
+    (&['\u{D8}'], &[pack(AFTER_Z + 2, 0x21, UPPER)]),
+    (&['\u{DE}'], &[pack(T, 0x20, UPPER + 1), pack(H, 0x20, UPPER + 1)]),
+    (&['\u{E6}'], &[pack(AFTER_Z + 1, 0x21, LOWER)]),
+    (&['\u{F0}'], &[pack(D, 0x22, LOWER)]),
+    (&['\u{F8}'], &[pack(AFTER_Z + 2, 0x21, LOWER)]),
+    (&['\u{FE}'], &[pack(T, 0x20, LOWER + 1), pack(H, 0x20, LOWER + 1)]),
+    (&['\u{110}'], &[pack(D, 0x21, UPPER)]),
+    (&['\u{111}'], &[pack(D, 0x21, LOWER)]),
+    (&['\u{152}'], &[pack(AFTER_Z + 2, 0x23, UPPER)]),
+    (&['\u{153}'], &[pack(AFTER_Z + 2, 0x23, LOWER)]),
+];
+
+// The rules of `SWEDISH`, and &v<<<ᵛ<<w<<<W. The DUCET sorts `ᵛ` after `V`, so it gets a
+// tertiary weight between those of `v` and `V`.
+static SWEDISH_TRADITIONAL: &[(&[char], &[u32])] = &[
+    (&['A', '\u{308}'], &[pack(AFTER_Z + 1, 0x20, UPPER)]),
+    (&['A', '\u{30A}'], &[pack(AFTER_Z, 0x20, UPPER)]),
+    (&['E', '\u{328}'], &[pack(AFTER_Z + 1, 0x22, UPPER)]),
+    (&['O', '\u{302}'], &[pack(AFTER_Z + 2, 0x24, UPPER)]),
+    (&['O', '\u{308}'], &[pack(AFTER_Z + 2, 0x20, UPPER)]),
+    (&['O', '\u{30B}'], &[pack(AFTER_Z + 2, 0x22, UPPER)]),
+    (&['U', '\u{308}'], &[pack(Y, 0x21, UPPER)]),
+    (&['U', '\u{30B}'], &[pack(Y, 0x22, UPPER)]),
+    (&['W'], &[pack(V, 0x21, UPPER)]),
+    (&['a', '\u{308}'], &[pack(AFTER_Z + 1, 0x20, LOWER)]),
+    (&['a', '\u{30A}'], &[pack(AFTER_Z, 0x20, LOWER)]),
+    (&['e', '\u{328}'], &[pack(AFTER_Z + 1, 0x22, LOWER)]),
+    (&['o', '\u{302}'], &[pack(AFTER_Z + 2, 0x24, LOWER)]),
+    (&['o', '\u{308}'], &[pack(AFTER_Z + 2, 0x20, LOWER)]),
+    (&['o', '\u{30B}'], &[pack(AFTER_Z + 2, 0x22, LOWER)]),
+    (&['u', '\u{308}'], &[pack(Y, 0x21, LOWER)]),
+    (&['u', '\u{30B}'], &[pack(Y, 0x22, LOWER)]),
+    (&['w'], &[pack(V, 0x21, LOWER)]),
+    (&['\u{C6}'], &[pack(AFTER_Z + 1, 0x21, UPPER)]),
+    (&['\u{D0}'], &[pack(D, 0x22, UPPER)]),
+    (&['\u{D8}'], &[pack(AFTER_Z + 2, 0x21, UPPER)]),
+    (&['\u{DE}'], &[pack(T, 0x20, UPPER + 1), pack(H, 0x20, UPPER + 1)]),
+    (&['\u{E6}'], &[pack(AFTER_Z + 1, 0x21, LOWER)]),
+    (&['\u{F0}'], &[pack(D, 0x22, LOWER)]),
+    (&['\u{F8}'], &[pack(AFTER_Z + 2, 0x21, LOWER)]),
+    (&['\u{FE}'], &[pack(T, 0x20, LOWER + 1), pack(H, 0x20, LOWER + 1)]),
+    (&['\u{110}'], &[pack(D, 0x21, UPPER)]),
+    (&['\u{111}'], &[pack(D, 0x21, LOWER)]),
+    (&['\u{152}'], &[pack(AFTER_Z + 2, 0x23, UPPER)]),
+    (&['\u{153}'], &[pack(AFTER_Z + 2, 0x23, LOWER)]),
+    (&['\u{1D5B}'], &[pack(V, 0x20, LOWER + 1)]),
+];
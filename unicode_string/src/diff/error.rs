@@ -0,0 +1,22 @@
+use std::error::Error;
+use std::fmt;
+
+/// The reason a [`Patch`](super::Patch) cannot be parsed or applied.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PatchError {
+    /// The line at the position, counted from 1, is not valid in the unified diff format.
+    Syntax(usize),
+    /// The hunk at the position, counted from 0, does not match the string it is applied to.
+    Mismatch(usize),
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatchError::Syntax(line) => write!(f, "invalid patch syntax at line {}", line),
+            PatchError::Mismatch(hunk) => write!(f, "hunk {} does not match", hunk),
+        }
+    }
+}
+
+impl Error for PatchError {}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::diff::{common_prefix_len, common_suffix_len, myers, Blocks};
+
+/// Tokens that occur more often than this in a range are not used to find common regions.
+const MAX_OCCURRENCES: usize = 64;
+
+/// Finds the matching blocks of `old[old_range]` and `new[new_range]` with the histogram
+/// algorithm: the longest common region made of the tokens that occur the least often in
+/// `old` is matched first, then the ranges around it are diffed the same way. Ranges without
+/// such a region are diffed with Myers' algorithm.
+pub(crate) fn diff(
+    old: &[usize],
+    mut old_range: Range<usize>,
+    new: &[usize],
+    mut new_range: Range<usize>,
+    blocks: &mut Blocks,
+) {
+    // The range after a region is diffed in a loop rather than recursively, so that the
+    // recursion stays shallow for texts with many regions.
+    loop {
+        let prefix = common_prefix_len(&old[old_range.clone()], &new[new_range.clone()]);
+        blocks.push(old_range.start, new_range.start, prefix);
+        old_range.start += prefix;
+        new_range.start += prefix;
+
+        let suffix = common_suffix_len(&old[old_range.clone()], &new[new_range.clone()]);
+        old_range.end -= suffix;
+        new_range.end -= suffix;
+        let suffix_block = (old_range.end, new_range.end, suffix);
+
+        if old_range.is_empty() || new_range.is_empty() {
+            blocks.push(suffix_block.0, suffix_block.1, suffix_block.2);
+            return;
+        }
+        match common_region(old, old_range.clone(), new, new_range.clone()) {
+            Some(region) => {
+                diff(
+                    old,
+                    old_range.start..region.old,
+                    new,
+                    new_range.start..region.new,
+                    blocks,
+                );
+                blocks.push(region.old, region.new, region.len);
+                // The suffix is matched again by the next iteration.
+                old_range = region.old + region.len..old_range.end + suffix;
+                new_range = region.new + region.len..new_range.end + suffix;
+            }
+            None => {
+                myers::diff(old, old_range, new, new_range, blocks);
+                blocks.push(suffix_block.0, suffix_block.1, suffix_block.2);
+                return;
+            }
+        }
+    }
+}
+
+/// A common region of both ranges.
+struct Region {
+    old: usize,
+    new: usize,
+    len: usize,
+    /// The smallest number of occurrences in `old` of the tokens of the region.
+    occurrences: usize,
+}
+
+/// Returns the longest common region of the tokens with the fewest occurrences in `old`.
+fn common_region(
+    old: &[usize],
+    old_range: Range<usize>,
+    new: &[usize],
+    new_range: Range<usize>,
+) -> Option<Region> {
+    let mut positions: HashMap<usize, Vec<usize>> = HashMap::new();
+    for index in old_range.clone() {
+        positions.entry(old[index]).or_default().push(index);
+    }
+
+    let mut best: Option<Region> = None;
+    let mut new_index = new_range.start;
+    while new_index < new_range.end {
+        let mut next = new_index + 1;
+        let candidates = match positions.get(&new[new_index]) {
+            Some(candidates) if candidates.len() <= MAX_OCCURRENCES => candidates,
+            _ => {
+                new_index = next;
+                continue;
+            }
+        };
+        for &old_index in candidates {
+            let (mut old_start, mut new_start) = (old_index, new_index);
+            while old_start > old_range.start
+                && new_start > new_range.start
+                && old[old_start - 1] == new[new_start - 1]
+            {
+                old_start -= 1;
+                new_start -= 1;
+            }
+            let (mut old_end, mut new_end) = (old_index + 1, new_index + 1);
+            while old_end < old_range.end && new_end < new_range.end && old[old_end] == new[new_end]
+            {
+                old_end += 1;
+                new_end += 1;
+            }
+            let occurrences = old[old_start..old_end]
+                .iter()
+                .map(|token| positions[token].len())
+                .min()
+                .unwrap_or(usize::MAX);
+            let len = old_end - old_start;
+            let better = match &best {
+                None => true,
+                Some(best) => {
+                    occurrences < best.occurrences
+                        || (occurrences == best.occurrences && len > best.len)
+                }
+            };
+            if better {
+                best = Some(Region {
+                    old: old_start,
+                    new: new_start,
+                    len,
+                    occurrences,
+                });
+            }
+            next = next.max(new_end);
+        }
+        new_index = next;
+    }
+    best
+}
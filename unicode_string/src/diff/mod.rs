@@ -0,0 +1,320 @@
+//! Differences between two `unicode_str`s.
+//!
+//! A [`Diff`] is a short sequence of [`DiffOp`]s that turns one string into another:
+//! ranges of chars that are equal in both, deleted from the old string or inserted in the new
+//! one. Ranges are char indices, so an accented letter or an emoji is never split in the
+//! middle of its UTF-8 encoding. With a [`Granularity`] other than [`Granularity::Char`], the
+//! strings are compared by grapheme clusters or words instead, which gives diffs that are
+//! easier to read, and the ranges still index chars.
+//!
+//! The [`Algorithm`] decides how the sequence is chosen: [`Algorithm::Myers`] quickly finds
+//! one of the shortest, and [`Algorithm::Patience`] and [`Algorithm::Histogram`] first match
+//! the tokens that are rare in the strings, which tends to align changed words and lines the
+//! way a reader would, at the cost of a diff that is sometimes slightly longer.
+//!
+//! A diff can also be turned into a line-based [`Patch`], which can be applied to a string
+//! and written or read in the unified diff format.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use unicode_string::diff::{self, DiffOp};
+//! use unicode_string::ustr;
+//!
+//! let diff = diff::diff(ustr!("Straße"), ustr!("Strasse"));
+//! assert_eq!(
+//!     diff.ops(),
+//!     [
+//!         DiffOp::Equal { old: 0..4, new: 0..4 },
+//!         DiffOp::Delete { old: 4..5, new_index: 4 },
+//!         DiffOp::Insert { old_index: 5, new: 4..6 },
+//!         DiffOp::Equal { old: 5..6, new: 6..7 },
+//!     ]
+//! );
+//! assert_eq!(diff.to_string(), "Stra[-ß-]{+ss+}e");
+//! ```
+
+mod error;
+mod histogram;
+mod myers;
+mod patch;
+mod patience;
+mod tokens;
+
+pub use self::error::*;
+pub use self::patch::*;
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::unicode_str;
+
+/// The algorithm used by a [`Differ`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Algorithm {
+    /// Myers' O(ND) algorithm, in linear space.
+    #[default]
+    Myers,
+    /// Matches the tokens that occur exactly once in both strings first.
+    Patience,
+    /// Matches the longest common regions of the rarest tokens first, like `git diff
+    /// --histogram`.
+    Histogram,
+}
+
+/// The units compared by a [`Differ`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Granularity {
+    /// Chars, i.e. Unicode scalar values.
+    #[default]
+    Char,
+    /// Extended grapheme clusters, as returned by [`unicode_str::graphemes`].
+    Grapheme,
+    /// Words: runs of letters, digits and marks, and runs of whitespace. Ideographs and other
+    /// chars like punctuation are words on their own.
+    Word,
+}
+
+/// An operation of a [`Diff`], with ranges of char indices in the old and new strings.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DiffOp {
+    /// The chars in `old` are the same as those in `new`.
+    Equal {
+        old: Range<usize>,
+        new: Range<usize>,
+    },
+    /// The chars in `old` are deleted, at `new_index` in the new string.
+    Delete { old: Range<usize>, new_index: usize },
+    /// The chars in `new` are inserted, at `old_index` in the old string.
+    Insert { old_index: usize, new: Range<usize> },
+}
+
+impl DiffOp {
+    /// Returns the range of the operation in the old string, which is empty for an insertion.
+    pub fn old_range(&self) -> Range<usize> {
+        match self {
+            DiffOp::Equal { old, .. } | DiffOp::Delete { old, .. } => old.clone(),
+            &DiffOp::Insert { old_index, .. } => old_index..old_index,
+        }
+    }
+
+    /// Returns the range of the operation in the new string, which is empty for a deletion.
+    pub fn new_range(&self) -> Range<usize> {
+        match self {
+            DiffOp::Equal { new, .. } | DiffOp::Insert { new, .. } => new.clone(),
+            &DiffOp::Delete { new_index, .. } => new_index..new_index,
+        }
+    }
+}
+
+/// Computes diffs with custom options.
+///
+/// [`diff`] is a shortcut for `Differ::new().diff`, which compares chars with Myers'
+/// algorithm.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::diff::{Differ, Granularity};
+/// use unicode_string::ustr;
+///
+/// let old = ustr!("la nuit étoilée");
+/// let new = ustr!("la nuit étoilée et calme");
+/// let diff = Differ::new().granularity(Granularity::Word).diff(old, new);
+/// assert_eq!(diff.to_string(), "la nuit étoilée{+ et calme+}");
+///
+/// let diff = Differ::new()
+///     .granularity(Granularity::Word)
+///     .diff(ustr!("le chat noir"), ustr!("le chien noir"));
+/// assert_eq!(diff.to_string(), "le [-chat-]{+chien+} noir");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Differ {
+    algorithm: Algorithm,
+    granularity: Granularity,
+}
+
+impl Differ {
+    /// Creates a differ that compares chars with Myers' algorithm.
+    pub const fn new() -> Differ {
+        Differ {
+            algorithm: Algorithm::Myers,
+            granularity: Granularity::Char,
+        }
+    }
+
+    /// Sets the algorithm.
+    #[must_use]
+    pub const fn algorithm(mut self, algorithm: Algorithm) -> Differ {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Sets the units that are compared.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::diff::{Differ, Granularity};
+    /// use unicode_string::UnicodeString;
+    ///
+    /// // "é" as "e" and a combining acute accent.
+    /// let old = UnicodeString::from_string("cafe\u{301}");
+    /// let new = UnicodeString::from_string("cafe");
+    ///
+    /// let diff = Differ::new().diff(&old, &new);
+    /// assert_eq!(diff.to_string(), "cafe[-\u{301}-]");
+    ///
+    /// let diff = Differ::new().granularity(Granularity::Grapheme).diff(&old, &new);
+    /// assert_eq!(diff.to_string(), "caf[-e\u{301}-]{+e+}");
+    /// ```
+    #[must_use]
+    pub const fn granularity(mut self, granularity: Granularity) -> Differ {
+        self.granularity = granularity;
+        self
+    }
+
+    /// Computes the diff from `old` to `new`.
+    pub fn diff<'a>(&self, old: &'a unicode_str, new: &'a unicode_str) -> Diff<'a> {
+        let (old_tokens, new_tokens) = tokens::tokenize(old, new, self.granularity);
+        let (old_ids, new_ids) = (&old_tokens.ids, &new_tokens.ids);
+        let (old_range, new_range) = (0..old_ids.len(), 0..new_ids.len());
+        let mut blocks = Blocks::default();
+        match self.algorithm {
+            Algorithm::Myers => myers::diff(old_ids, old_range, new_ids, new_range, &mut blocks),
+            Algorithm::Patience => {
+                patience::diff(old_ids, old_range, new_ids, new_range, &mut blocks)
+            }
+            Algorithm::Histogram => {
+                histogram::diff(old_ids, old_range, new_ids, new_range, &mut blocks)
+            }
+        }
+
+        // Turn the matching blocks of tokens into operations on chars.
+        let old_bounds = &old_tokens.bounds;
+        let new_bounds = &new_tokens.bounds;
+        let mut ops = Vec::new();
+        let (mut old_index, mut new_index) = (0, 0);
+        let end = (old_ids.len(), new_ids.len(), 0);
+        for &(old_start, new_start, len) in blocks.blocks.iter().chain([&end]) {
+            let (old_char, new_char) = (old_bounds[old_index], new_bounds[new_index]);
+            if old_index < old_start {
+                ops.push(DiffOp::Delete {
+                    old: old_char..old_bounds[old_start],
+                    new_index: new_char,
+                });
+            }
+            if new_index < new_start {
+                ops.push(DiffOp::Insert {
+                    old_index: old_bounds[old_start],
+                    new: new_char..new_bounds[new_start],
+                });
+            }
+            if len > 0 {
+                ops.push(DiffOp::Equal {
+                    old: old_bounds[old_start]..old_bounds[old_start + len],
+                    new: new_bounds[new_start]..new_bounds[new_start + len],
+                });
+            }
+            old_index = old_start + len;
+            new_index = new_start + len;
+        }
+        Diff { old, new, ops }
+    }
+}
+
+/// Computes the diff from `old` to `new`, comparing chars with Myers' algorithm.
+#[inline]
+pub fn diff<'a>(old: &'a unicode_str, new: &'a unicode_str) -> Diff<'a> {
+    Differ::new().diff(old, new)
+}
+
+/// The differences between two strings, computed by a [`Differ`].
+///
+/// The [`Display`](fmt::Display) implementation shows both strings at once, like `git diff
+/// --word-diff`: deleted chars are written `[-like this-]` and inserted ones `{+like this+}`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Diff<'a> {
+    old: &'a unicode_str,
+    new: &'a unicode_str,
+    ops: Vec<DiffOp>,
+}
+
+impl<'a> Diff<'a> {
+    /// Returns the old string.
+    #[inline]
+    pub fn old_text(&self) -> &'a unicode_str {
+        self.old
+    }
+
+    /// Returns the new string.
+    #[inline]
+    pub fn new_text(&self) -> &'a unicode_str {
+        self.new
+    }
+
+    /// Returns the operations that turn the old string into the new one, in order.
+    ///
+    /// Between two equal ranges, the deletion, if any, comes before the insertion, so the chars
+    /// that were replaced are easy to pair.
+    #[inline]
+    pub fn ops(&self) -> &[DiffOp] {
+        &self.ops
+    }
+
+    /// Checks if the strings differ.
+    pub fn has_changes(&self) -> bool {
+        self.ops
+            .iter()
+            .any(|op| !matches!(op, DiffOp::Equal { .. }))
+    }
+}
+
+impl fmt::Display for Diff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for op in &self.ops {
+            match op {
+                DiffOp::Equal { old, .. } => write!(f, "{}", &self.old[old.clone()])?,
+                DiffOp::Delete { old, .. } => write!(f, "[-{}-]", &self.old[old.clone()])?,
+                DiffOp::Insert { new, .. } => write!(f, "{{+{}+}}", &self.new[new.clone()])?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The matching blocks of two sequences of tokens, as their starts and their length, in
+/// increasing order.
+#[derive(Default)]
+struct Blocks {
+    blocks: Vec<(usize, usize, usize)>,
+}
+
+impl Blocks {
+    /// Adds a matching block, merging it with the previous one if they are adjacent.
+    fn push(&mut self, old: usize, new: usize, len: usize) {
+        match self.blocks.last_mut() {
+            Some(last) if last.0 + last.2 == old && last.1 + last.2 == new => last.2 += len,
+            _ if len > 0 => self.blocks.push((old, new, len)),
+            _ => (),
+        }
+    }
+}
+
+fn common_prefix_len(a: &[usize], b: &[usize]) -> usize {
+    a.iter().zip(b).take_while(|(a, b)| a == b).count()
+}
+
+fn common_suffix_len(a: &[usize], b: &[usize]) -> usize {
+    a.iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count()
+}
@@ -0,0 +1,170 @@
+use std::ops::{Index, IndexMut, Range};
+
+use crate::diff::{common_prefix_len, common_suffix_len, Blocks};
+
+/// Finds the matching blocks of `old[old_range]` and `new[new_range]` with Myers' algorithm,
+/// using the linear space refinement: the middle snake of an optimal edit script is found by
+/// searching from both ends at once, then both halves are diffed recursively.
+pub(crate) fn diff(
+    old: &[usize],
+    old_range: Range<usize>,
+    new: &[usize],
+    new_range: Range<usize>,
+    blocks: &mut Blocks,
+) {
+    let max_d = max_d(old_range.len(), new_range.len());
+    let mut forward = V::new(max_d);
+    let mut backward = V::new(max_d);
+    conquer(
+        old,
+        old_range,
+        new,
+        new_range,
+        &mut forward,
+        &mut backward,
+        blocks,
+    );
+}
+
+/// The furthest reaching x of each diagonal `k`, which can be negative.
+struct V {
+    offset: isize,
+    v: Vec<usize>,
+}
+
+impl V {
+    fn new(max_d: usize) -> V {
+        V {
+            offset: max_d as isize,
+            v: vec![0; 2 * max_d + 1],
+        }
+    }
+}
+
+impl Index<isize> for V {
+    type Output = usize;
+
+    #[inline]
+    fn index(&self, k: isize) -> &usize {
+        &self.v[(k + self.offset) as usize]
+    }
+}
+
+impl IndexMut<isize> for V {
+    #[inline]
+    fn index_mut(&mut self, k: isize) -> &mut usize {
+        &mut self.v[(k + self.offset) as usize]
+    }
+}
+
+fn max_d(old_len: usize, new_len: usize) -> usize {
+    (old_len + new_len).div_ceil(2) + 1
+}
+
+fn conquer(
+    old: &[usize],
+    mut old_range: Range<usize>,
+    new: &[usize],
+    mut new_range: Range<usize>,
+    forward: &mut V,
+    backward: &mut V,
+    blocks: &mut Blocks,
+) {
+    let prefix = common_prefix_len(&old[old_range.clone()], &new[new_range.clone()]);
+    blocks.push(old_range.start, new_range.start, prefix);
+    old_range.start += prefix;
+    new_range.start += prefix;
+
+    let suffix = common_suffix_len(&old[old_range.clone()], &new[new_range.clone()]);
+    old_range.end -= suffix;
+    new_range.end -= suffix;
+
+    if !old_range.is_empty() && !new_range.is_empty() {
+        let (x, y) = middle_snake(
+            old,
+            old_range.clone(),
+            new,
+            new_range.clone(),
+            forward,
+            backward,
+        );
+        conquer(
+            old,
+            old_range.start..x,
+            new,
+            new_range.start..y,
+            forward,
+            backward,
+            blocks,
+        );
+        conquer(
+            old,
+            x..old_range.end,
+            new,
+            y..new_range.end,
+            forward,
+            backward,
+            blocks,
+        );
+    }
+    blocks.push(old_range.end, new_range.end, suffix);
+}
+
+/// Returns the start of the middle snake of an optimal edit script of two non-empty ranges
+/// that do not start or end with the same token.
+fn middle_snake(
+    old: &[usize],
+    old_range: Range<usize>,
+    new: &[usize],
+    new_range: Range<usize>,
+    forward: &mut V,
+    backward: &mut V,
+) -> (usize, usize) {
+    let (n, m) = (old_range.len(), new_range.len());
+    let delta = n as isize - m as isize;
+    let odd = delta & 1 == 1;
+    forward[1] = 0;
+    backward[1] = 0;
+    for d in 0..max_d(n, m) as isize {
+        for k in (-d..=d).rev().step_by(2) {
+            let mut x = if k == -d || (k != d && forward[k - 1] < forward[k + 1]) {
+                forward[k + 1]
+            } else {
+                forward[k - 1] + 1
+            };
+            let y = (x as isize - k) as usize;
+            let (x0, y0) = (x, y);
+            if x < n && y < m {
+                x += common_prefix_len(
+                    &old[old_range.start + x..old_range.end],
+                    &new[new_range.start + y..new_range.end],
+                );
+            }
+            forward[k] = x;
+            if odd && (k - delta).abs() < d && forward[k] + backward[delta - k] >= n {
+                return (old_range.start + x0, new_range.start + y0);
+            }
+        }
+        for k in (-d..=d).rev().step_by(2) {
+            let mut x = if k == -d || (k != d && backward[k - 1] < backward[k + 1]) {
+                backward[k + 1]
+            } else {
+                backward[k - 1] + 1
+            };
+            let mut y = (x as isize - k) as usize;
+            if x < n && y < m {
+                let len = common_suffix_len(
+                    &old[old_range.start..old_range.start + n - x],
+                    &new[new_range.start..new_range.start + m - y],
+                );
+                x += len;
+                y += len;
+            }
+            backward[k] = x;
+            if !odd && (k - delta).abs() <= d && backward[k] + forward[delta - k] >= n {
+                return (old_range.start + n - x, new_range.start + m - y);
+            }
+        }
+    }
+    unreachable!("an edit script has at most n + m edits")
+}
@@ -0,0 +1,427 @@
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::diff::{Diff, DiffOp, PatchError};
+use crate::UnicodeString;
+
+/// A line of a [`Hunk`], including its line feed unless it is the last line of a string that
+/// does not end with one.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PatchLine {
+    /// A line that is the same in both strings.
+    Context(UnicodeString),
+    /// A line of the old string that is deleted.
+    Delete(UnicodeString),
+    /// A line of the new string that is inserted.
+    Insert(UnicodeString),
+}
+
+impl PatchLine {
+    fn prefix(&self) -> char {
+        match self {
+            PatchLine::Context(_) => ' ',
+            PatchLine::Delete(_) => '-',
+            PatchLine::Insert(_) => '+',
+        }
+    }
+
+    /// Returns the content of the line.
+    pub fn content(&self) -> &UnicodeString {
+        match self {
+            PatchLine::Context(line) | PatchLine::Delete(line) | PatchLine::Insert(line) => line,
+        }
+    }
+
+    fn content_mut(&mut self) -> &mut UnicodeString {
+        match self {
+            PatchLine::Context(line) | PatchLine::Delete(line) | PatchLine::Insert(line) => line,
+        }
+    }
+}
+
+/// A group of changed lines of a [`Patch`], with the unchanged lines around them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Hunk {
+    old_start: usize,
+    old_len: usize,
+    new_start: usize,
+    new_len: usize,
+    lines: Vec<PatchLine>,
+}
+
+impl Hunk {
+    /// Returns the range of the lines of the hunk in the old string, counted from 0.
+    #[inline]
+    pub fn old_range(&self) -> Range<usize> {
+        self.old_start..self.old_start + self.old_len
+    }
+
+    /// Returns the range of the lines of the hunk in the new string, counted from 0.
+    #[inline]
+    pub fn new_range(&self) -> Range<usize> {
+        self.new_start..self.new_start + self.new_len
+    }
+
+    /// Returns the lines of the hunk.
+    #[inline]
+    pub fn lines(&self) -> &[PatchLine] {
+        &self.lines
+    }
+}
+
+/// The changed lines between two strings, which can be applied to the old string to get the
+/// new one, created by [`Diff::patch`].
+///
+/// The [`Display`](fmt::Display) and [`FromStr`] implementations use the unified diff format,
+/// without the `---` and `+++` file headers, which are ignored when parsing.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::diff::{self, Patch};
+/// use unicode_string::UnicodeString;
+///
+/// let old = UnicodeString::from_string("α\nβ\nγ\n");
+/// let new = UnicodeString::from_string("α\nβ!\nγ\n");
+/// let patch = diff::diff(&old, &new).patch();
+/// assert_eq!(patch.to_string(), "@@ -1,3 +1,3 @@\n α\n-β\n+β!\n γ\n");
+///
+/// let patch: Patch = patch.to_string().parse().unwrap();
+/// let mut s = old.clone();
+/// patch.apply(&mut s).unwrap();
+/// assert_eq!(s, new);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Patch {
+    hunks: Vec<Hunk>,
+}
+
+impl Patch {
+    /// Returns the hunks of the patch.
+    #[inline]
+    pub fn hunks(&self) -> &[Hunk] {
+        &self.hunks
+    }
+
+    /// Checks if the patch changes nothing.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.hunks.is_empty()
+    }
+
+    /// Applies the patch to `s`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, and leaves `s` unchanged, if the context and deleted lines of a hunk
+    /// are not the lines of `s` at the position of the hunk.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::diff::{self, PatchError};
+    /// use unicode_string::UnicodeString;
+    ///
+    /// let old = UnicodeString::from_string("ein\nzwei\n");
+    /// let new = UnicodeString::from_string("eins\nzwei\n");
+    /// let patch = diff::diff(&old, &new).patch();
+    ///
+    /// let mut s = UnicodeString::from_string("drei\nzwei\n");
+    /// assert_eq!(patch.apply(&mut s), Err(PatchError::Mismatch(0)));
+    /// ```
+    pub fn apply(&self, s: &mut UnicodeString) -> Result<(), PatchError> {
+        let chars = s.chars();
+        let starts = line_starts(chars);
+        let lines_len = starts.len() - 1;
+        let mut result = Vec::with_capacity(chars.len());
+        let mut next_line = 0;
+        for (index, hunk) in self.hunks.iter().enumerate() {
+            if hunk.old_start < next_line || hunk.old_start + hunk.old_len > lines_len {
+                return Err(PatchError::Mismatch(index));
+            }
+            result.extend_from_slice(&chars[starts[next_line]..starts[hunk.old_start]]);
+            next_line = hunk.old_start;
+            for line in &hunk.lines {
+                match line {
+                    PatchLine::Context(content) | PatchLine::Delete(content) => {
+                        if next_line == lines_len
+                            || chars[starts[next_line]..starts[next_line + 1]] != *content.chars()
+                        {
+                            return Err(PatchError::Mismatch(index));
+                        }
+                        if let PatchLine::Context(_) = line {
+                            result.extend_from_slice(content.chars());
+                        }
+                        next_line += 1;
+                    }
+                    PatchLine::Insert(content) => result.extend_from_slice(content.chars()),
+                }
+            }
+        }
+        result.extend_from_slice(&chars[starts[next_line]..]);
+        s.vec = result;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Patch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// Writes a range of lines, counted from 1. An empty range is written as the line
+        /// before it.
+        fn range(f: &mut fmt::Formatter<'_>, start: usize, len: usize) -> fmt::Result {
+            match len {
+                0 => write!(f, "{},0", start),
+                1 => write!(f, "{}", start + 1),
+                _ => write!(f, "{},{}", start + 1, len),
+            }
+        }
+
+        for hunk in &self.hunks {
+            f.write_str("@@ -")?;
+            range(f, hunk.old_start, hunk.old_len)?;
+            f.write_str(" +")?;
+            range(f, hunk.new_start, hunk.new_len)?;
+            f.write_str(" @@\n")?;
+            for line in &hunk.lines {
+                write!(f, "{}{}", line.prefix(), &**line.content())?;
+                if line.content().chars().last() != Some(&'\n') {
+                    f.write_str("\n\\ No newline at end of file\n")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Patch {
+    type Err = PatchError;
+
+    fn from_str(s: &str) -> Result<Patch, PatchError> {
+        let mut lines = s.split_inclusive('\n').enumerate().peekable();
+        let mut hunks = Vec::new();
+        while let Some((number, line)) = lines.next() {
+            if !line.starts_with("@@") {
+                continue;
+            }
+            let (old_start, old_len, new_start, new_len) =
+                parse_header(line).ok_or(PatchError::Syntax(number + 1))?;
+            let mut hunk = Hunk {
+                old_start,
+                old_len,
+                new_start,
+                new_len,
+                lines: Vec::new(),
+            };
+            let (mut old_remaining, mut new_remaining) = (old_len, new_len);
+            while old_remaining > 0 || new_remaining > 0 {
+                let (number, line) = lines
+                    .next()
+                    .ok_or(PatchError::Syntax(s.lines().count() + 1))?;
+                let (prefix, content) = match line.chars().next() {
+                    // Some tools strip the space of empty context lines.
+                    Some('\n') => (' ', line),
+                    Some(prefix) => (prefix, &line[prefix.len_utf8()..]),
+                    None => unreachable!("lines are not empty"),
+                };
+                let content = UnicodeString::from_string(content);
+                let (line, old, new) = match prefix {
+                    ' ' => (PatchLine::Context(content), 1, 1),
+                    '-' => (PatchLine::Delete(content), 1, 0),
+                    '+' => (PatchLine::Insert(content), 0, 1),
+                    _ => return Err(PatchError::Syntax(number + 1)),
+                };
+                if old > old_remaining || new > new_remaining {
+                    return Err(PatchError::Syntax(number + 1));
+                }
+                old_remaining -= old;
+                new_remaining -= new;
+                hunk.lines.push(line);
+                if lines.next_if(|(_, next)| next.starts_with('\\')).is_some() {
+                    let line = hunk.lines.last_mut().expect("a line was just pushed");
+                    if line.content().chars().last() == Some(&'\n') {
+                        line.content_mut().vec.pop();
+                    }
+                }
+            }
+            hunks.push(hunk);
+        }
+        Ok(Patch { hunks })
+    }
+}
+
+/// Parses a hunk header like `@@ -1,3 +1,4 @@`, and returns the starts, counted from 0, and
+/// lengths of the old and new ranges.
+fn parse_header(line: &str) -> Option<(usize, usize, usize, usize)> {
+    fn range(range: &str) -> Option<(usize, usize)> {
+        let (start, len) = match range.split_once(',') {
+            Some((start, len)) => (start.parse().ok()?, len.parse().ok()?),
+            None => (range.parse().ok()?, 1),
+        };
+        match (start, len) {
+            (start, 0) => Some((start, 0)),
+            (0, _) => None,
+            (start, len) => Some((start - 1, len)),
+        }
+    }
+
+    let mut parts = line.strip_prefix("@@ ")?.split(' ');
+    let (old_start, old_len) = range(parts.next()?.strip_prefix('-')?)?;
+    let (new_start, new_len) = range(parts.next()?.strip_prefix('+')?)?;
+    if !parts.next()?.starts_with("@@") {
+        return None;
+    }
+    Some((old_start, old_len, new_start, new_len))
+}
+
+/// Returns the index of the first char of each line, followed by the length of `chars`.
+fn line_starts(chars: &[char]) -> Vec<usize> {
+    let mut starts = vec![0];
+    starts.extend(
+        chars
+            .iter()
+            .enumerate()
+            .filter(|&(index, &c)| c == '\n' && index + 1 < chars.len())
+            .map(|(index, _)| index + 1),
+    );
+    if !chars.is_empty() {
+        starts.push(chars.len());
+    }
+    starts
+}
+
+/// A line of a patch, as its kind and the index of the line in the old and new strings.
+#[derive(Clone, Copy)]
+enum Entry {
+    Context(usize, usize),
+    Delete(usize, usize),
+    Insert(usize, usize),
+}
+
+impl Diff<'_> {
+    /// Returns the patch of the lines that changed, with 3 lines of context around the changes.
+    #[inline]
+    pub fn patch(&self) -> Patch {
+        self.patch_with_context(3)
+    }
+
+    /// Returns the patch of the lines that changed, with `context` lines of context around
+    /// the changes.
+    ///
+    /// A line is changed if any of its chars is. Hunks that are separated by at most twice
+    /// `context` unchanged lines are merged.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::diff;
+    /// use unicode_string::UnicodeString;
+    ///
+    /// let old = UnicodeString::from_string("一\n二\n三\n四\n五\n六");
+    /// let new = UnicodeString::from_string("一\n二\n三\n四\n五\n六\n七");
+    /// let patch = diff::diff(&old, &new).patch_with_context(1);
+    /// assert_eq!(
+    ///     patch.to_string(),
+    ///     "@@ -5,2 +5,3 @@\n 五\n-六\n\\ No newline at end of file\n+六\n+七\n\\ No newline at end of file\n"
+    /// );
+    /// ```
+    pub fn patch_with_context(&self, context: usize) -> Patch {
+        let (old, new) = (self.old.chars(), self.new.chars());
+        let (old_starts, new_starts) = (line_starts(old), line_starts(new));
+
+        // Find the positions where both strings are at the start of a line and everything
+        // before is the same in both, and the lines between them.
+        let mut entries = Vec::new();
+        let (mut old_line, mut new_line) = (0, 0);
+        let mut changed = false;
+        let mut sync = |old_index: usize, new_index: usize, changed: &mut bool| {
+            let old_end = old_starts.binary_search(&old_index);
+            let new_end = new_starts.binary_search(&new_index);
+            if let (Ok(old_end), Ok(new_end)) = (old_end, new_end) {
+                if *changed {
+                    entries.extend((old_line..old_end).map(|line| Entry::Delete(line, new_line)));
+                    entries.extend((new_line..new_end).map(|line| Entry::Insert(old_end, line)));
+                } else {
+                    let lines = (old_line..old_end).zip(new_line..new_end);
+                    entries.extend(lines.map(|(old, new)| Entry::Context(old, new)));
+                }
+                (old_line, new_line) = (old_end, new_end);
+                *changed = false;
+            }
+        };
+        for op in &self.ops {
+            match op {
+                DiffOp::Equal { old, new } => {
+                    for offset in 1..=old.len() {
+                        sync(old.start + offset, new.start + offset, &mut changed);
+                    }
+                }
+                _ => {
+                    changed = true;
+                    sync(op.old_range().end, op.new_range().end, &mut changed);
+                }
+            }
+        }
+
+        // Group the changes with their context.
+        let changes: Vec<usize> = (0..entries.len())
+            .filter(|&index| !matches!(entries[index], Entry::Context(..)))
+            .collect();
+        let mut hunks = Vec::new();
+        let mut index = 0;
+        while index < changes.len() {
+            let start = changes[index].saturating_sub(context);
+            let mut end = changes[index] + 1;
+            index += 1;
+            while index < changes.len() && changes[index] - end <= 2 * context {
+                end = changes[index] + 1;
+                index += 1;
+            }
+            let end = (end + context).min(entries.len());
+
+            let (old_start, new_start) = match entries[start] {
+                Entry::Context(old, new) | Entry::Delete(old, new) | Entry::Insert(old, new) => {
+                    (old, new)
+                }
+            };
+            let mut hunk = Hunk {
+                old_start,
+                old_len: 0,
+                new_start,
+                new_len: 0,
+                lines: Vec::with_capacity(end - start),
+            };
+            let old_line = |line: usize| UnicodeString {
+                vec: old[old_starts[line]..old_starts[line + 1]].to_vec(),
+            };
+            let new_line = |line: usize| UnicodeString {
+                vec: new[new_starts[line]..new_starts[line + 1]].to_vec(),
+            };
+            for &entry in &entries[start..end] {
+                hunk.lines.push(match entry {
+                    Entry::Context(old, _) => {
+                        hunk.old_len += 1;
+                        hunk.new_len += 1;
+                        PatchLine::Context(old_line(old))
+                    }
+                    Entry::Delete(old, _) => {
+                        hunk.old_len += 1;
+                        PatchLine::Delete(old_line(old))
+                    }
+                    Entry::Insert(_, new) => {
+                        hunk.new_len += 1;
+                        PatchLine::Insert(new_line(new))
+                    }
+                });
+            }
+            hunks.push(hunk);
+        }
+        Patch { hunks }
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::diff::{common_prefix_len, common_suffix_len, myers, Blocks};
+
+/// Finds the matching blocks of `old[old_range]` and `new[new_range]` with the patience
+/// algorithm: the tokens that occur exactly once on both sides are matched first, keeping the
+/// longest sequence of them that is in the same order on both sides, and the ranges between
+/// them are diffed recursively. Ranges without such tokens are diffed with Myers' algorithm.
+pub(crate) fn diff(
+    old: &[usize],
+    mut old_range: Range<usize>,
+    new: &[usize],
+    mut new_range: Range<usize>,
+    blocks: &mut Blocks,
+) {
+    let prefix = common_prefix_len(&old[old_range.clone()], &new[new_range.clone()]);
+    blocks.push(old_range.start, new_range.start, prefix);
+    old_range.start += prefix;
+    new_range.start += prefix;
+
+    let suffix = common_suffix_len(&old[old_range.clone()], &new[new_range.clone()]);
+    old_range.end -= suffix;
+    new_range.end -= suffix;
+
+    if !old_range.is_empty() && !new_range.is_empty() {
+        let anchors = unique_anchors(old, old_range.clone(), new, new_range.clone());
+        if anchors.is_empty() {
+            myers::diff(old, old_range.clone(), new, new_range.clone(), blocks);
+        } else {
+            let (mut old_start, mut new_start) = (old_range.start, new_range.start);
+            for (old_index, new_index) in anchors {
+                diff(old, old_start..old_index, new, new_start..new_index, blocks);
+                blocks.push(old_index, new_index, 1);
+                old_start = old_index + 1;
+                new_start = new_index + 1;
+            }
+            diff(
+                old,
+                old_start..old_range.end,
+                new,
+                new_start..new_range.end,
+                blocks,
+            );
+        }
+    }
+    blocks.push(old_range.end, new_range.end, suffix);
+}
+
+/// Returns the longest increasing sequence of the positions of the tokens that occur exactly
+/// once in both ranges.
+fn unique_anchors(
+    old: &[usize],
+    old_range: Range<usize>,
+    new: &[usize],
+    new_range: Range<usize>,
+) -> Vec<(usize, usize)> {
+    // The number of occurrences of each token in `old`, its last position there, and its
+    // number of occurrences in `new`.
+    let mut counts: HashMap<usize, (usize, usize, usize)> = HashMap::new();
+    for index in old_range {
+        let entry = counts.entry(old[index]).or_insert((0, 0, 0));
+        entry.0 += 1;
+        entry.1 = index;
+    }
+    for index in new_range.clone() {
+        if let Some(entry) = counts.get_mut(&new[index]) {
+            entry.2 += 1;
+        }
+    }
+    let pairs: Vec<(usize, usize)> = new_range
+        .filter_map(|index| match counts.get(&new[index]) {
+            Some(&(1, old_index, 1)) => Some((old_index, index)),
+            _ => None,
+        })
+        .collect();
+
+    // Patience sorting: `piles[i]` is the index in `pairs` of the smallest last old position
+    // of an increasing sequence of length `i + 1`.
+    let mut piles: Vec<usize> = Vec::new();
+    let mut previous = vec![usize::MAX; pairs.len()];
+    for (index, &(old_index, _)) in pairs.iter().enumerate() {
+        let pile = piles.partition_point(|&top| pairs[top].0 < old_index);
+        if pile > 0 {
+            previous[index] = piles[pile - 1];
+        }
+        if pile == piles.len() {
+            piles.push(index);
+        } else {
+            piles[pile] = index;
+        }
+    }
+    let mut anchors = Vec::with_capacity(piles.len());
+    let mut index = piles.last().copied().unwrap_or(usize::MAX);
+    while index != usize::MAX {
+        anchors.push(pairs[index]);
+        index = previous[index];
+    }
+    anchors.reverse();
+    anchors
+}
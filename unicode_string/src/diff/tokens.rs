@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use crate::diff::Granularity;
+use crate::ucd::{self, GeneralCategory};
+use crate::unicode_str;
+
+/// The tokens of a string, as ids that are equal for equal tokens.
+pub(crate) struct Tokens {
+    pub(crate) ids: Vec<usize>,
+    /// The index of the first char of each token, followed by the length of the string.
+    pub(crate) bounds: Vec<usize>,
+}
+
+/// Splits both strings into tokens, with the same ids for the same tokens.
+pub(crate) fn tokenize<'a>(
+    old: &'a unicode_str,
+    new: &'a unicode_str,
+    granularity: Granularity,
+) -> (Tokens, Tokens) {
+    match granularity {
+        Granularity::Char => (char_tokens(old), char_tokens(new)),
+        Granularity::Grapheme | Granularity::Word => {
+            let mut ids: HashMap<&'a unicode_str, usize> = HashMap::new();
+            let mut tokens = |s: &'a unicode_str| {
+                let lens: Vec<usize> = match granularity {
+                    Granularity::Grapheme => s.graphemes().map(unicode_str::len).collect(),
+                    _ => word_lens(s.chars()),
+                };
+                let mut tokens = Tokens {
+                    ids: Vec::with_capacity(lens.len()),
+                    bounds: Vec::with_capacity(lens.len() + 1),
+                };
+                let mut start = 0;
+                for len in lens {
+                    let next = ids.len();
+                    tokens
+                        .ids
+                        .push(*ids.entry(&s[start..start + len]).or_insert(next));
+                    tokens.bounds.push(start);
+                    start += len;
+                }
+                tokens.bounds.push(start);
+                tokens
+            };
+            let old = tokens(old);
+            (old, tokens(new))
+        }
+    }
+}
+
+fn char_tokens(s: &unicode_str) -> Tokens {
+    Tokens {
+        ids: s.chars().iter().map(|&c| c as usize).collect(),
+        bounds: (0..=s.len()).collect(),
+    }
+}
+
+/// The kinds of chars that make up words.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum WordClass {
+    WhiteSpace,
+    Word,
+    /// Ideographs and punctuation, which are words on their own.
+    Single,
+}
+
+fn word_class(c: char) -> WordClass {
+    let category = ucd::general_category(c);
+    if ucd::is_white_space(c) {
+        WordClass::WhiteSpace
+    } else if ucd::is_ideographic(c) {
+        WordClass::Single
+    } else if ucd::is_alphabetic(c)
+        || category.is_number()
+        || category.is_mark()
+        || category == GeneralCategory::ConnectorPunctuation
+        || c == '\u{200D}'
+    {
+        WordClass::Word
+    } else {
+        WordClass::Single
+    }
+}
+
+/// Returns the lengths of the words of `chars`: runs of letters, digits and marks, runs of
+/// whitespace, and any other char on its own.
+fn word_lens(chars: &[char]) -> Vec<usize> {
+    let mut lens = Vec::new();
+    let mut start = 0;
+    while start < chars.len() {
+        let class = word_class(chars[start]);
+        let len = match class {
+            WordClass::Single => 1,
+            _ => {
+                1 + chars[start + 1..]
+                    .iter()
+                    .take_while(|&&c| word_class(c) == class)
+                    .count()
+            }
+        };
+        lens.push(len);
+        start += len;
+    }
+    lens
+}
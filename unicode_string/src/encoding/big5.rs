@@ -0,0 +1,66 @@
+use crate::encoding::{code_point, pointer};
+use crate::tables::encoding_chinese_traditional::*;
+
+/// The pointers that decode to two chars, a letter and a combining mark, which have no
+/// precomposed form.
+const TWO_CHARS: &[(usize, [char; 2])] = &[
+    (1133, ['\u{CA}', '\u{304}']),
+    (1135, ['\u{CA}', '\u{30C}']),
+    (1164, ['\u{EA}', '\u{304}']),
+    (1166, ['\u{EA}', '\u{30C}']),
+];
+
+pub(super) fn decode(bytes: &[u8], chars: &mut Vec<char>) -> bool {
+    let mut had_errors = false;
+    let mut index = 0;
+    while let Some(&lead) = bytes.get(index) {
+        if lead.is_ascii() {
+            chars.push(char::from(lead));
+            index += 1;
+            continue;
+        }
+        let trail = match bytes.get(index + 1) {
+            Some(&trail) if (0x81..=0xFE).contains(&lead) => trail,
+            _ => {
+                had_errors = true;
+                chars.push(char::REPLACEMENT_CHARACTER);
+                index += 1;
+                continue;
+            }
+        };
+        let offset = if trail < 0x7F { 0x40 } else { 0x62 };
+        if let 0x40..=0x7E | 0xA1..=0xFE = trail {
+            let pointer = usize::from(lead - 0x81) * 157 + usize::from(trail - offset);
+            if let Some((_, pair)) = TWO_CHARS.iter().find(|&&(other, _)| other == pointer) {
+                chars.extend_from_slice(pair);
+                index += 2;
+                continue;
+            }
+            if let Some(c) = code_point(BIG5, pointer) {
+                chars.push(c);
+                index += 2;
+                continue;
+            }
+        }
+        had_errors = true;
+        chars.push(char::REPLACEMENT_CHARACTER);
+        // An ASCII trail byte is decoded on its own.
+        index += if trail.is_ascii() { 1 } else { 2 };
+    }
+    had_errors
+}
+
+pub(super) fn encode(chars: &[char], bytes: &mut Vec<u8>) -> Result<(), usize> {
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_ascii() {
+            bytes.push(c as u8);
+            continue;
+        }
+        let pointer = pointer(BIG5_POINTERS, c).ok_or(index)?;
+        let trail = pointer % 157;
+        let offset = if trail < 0x3F { 0x40 } else { 0x62 };
+        bytes.push((pointer / 157 + 0x81) as u8);
+        bytes.push((trail + offset) as u8);
+    }
+    Ok(())
+}
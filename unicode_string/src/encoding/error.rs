@@ -0,0 +1,53 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::encoding::Encoding;
+
+/// The error returned by [`encode`](super::encode) for a char that the encoding cannot
+/// represent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UnencodableChar {
+    c: char,
+    index: usize,
+    encoding: Encoding,
+}
+
+impl UnencodableChar {
+    #[inline]
+    pub(crate) fn new(c: char, index: usize, encoding: Encoding) -> UnencodableChar {
+        UnencodableChar { c, index, encoding }
+    }
+
+    /// Returns the char that cannot be encoded.
+    #[inline]
+    pub fn char(&self) -> char {
+        self.c
+    }
+
+    /// Returns the char index of the char in the encoded string.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the encoding that cannot represent the char.
+    #[inline]
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+}
+
+impl fmt::Display for UnencodableChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} (U+{:04X}) at char {} cannot be encoded in {}",
+            self.c,
+            u32::from(self.c),
+            self.index,
+            self.encoding.name()
+        )
+    }
+}
+
+impl Error for UnencodableChar {}
@@ -0,0 +1,56 @@
+use crate::encoding::{code_point, pointer};
+use crate::tables::encoding_korean::*;
+
+pub(super) fn decode(bytes: &[u8], chars: &mut Vec<char>) -> bool {
+    let mut had_errors = false;
+    let mut index = 0;
+    while let Some(&lead) = bytes.get(index) {
+        if lead.is_ascii() {
+            chars.push(char::from(lead));
+            index += 1;
+            continue;
+        }
+        let trail = match bytes.get(index + 1) {
+            Some(&trail) if (0x81..=0xFE).contains(&lead) => trail,
+            _ => {
+                had_errors = true;
+                chars.push(char::REPLACEMENT_CHARACTER);
+                index += 1;
+                continue;
+            }
+        };
+        let c = match trail {
+            0x41..=0xFE => {
+                let pointer = usize::from(lead - 0x81) * 190 + usize::from(trail - 0x41);
+                code_point(EUC_KR, pointer)
+            }
+            _ => None,
+        };
+        match c {
+            Some(c) => {
+                chars.push(c);
+                index += 2;
+            }
+            None => {
+                had_errors = true;
+                chars.push(char::REPLACEMENT_CHARACTER);
+                // An ASCII trail byte is decoded on its own.
+                index += if trail.is_ascii() { 1 } else { 2 };
+            }
+        }
+    }
+    had_errors
+}
+
+pub(super) fn encode(chars: &[char], bytes: &mut Vec<u8>) -> Result<(), usize> {
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_ascii() {
+            bytes.push(c as u8);
+            continue;
+        }
+        let pointer = pointer(EUC_KR_POINTERS, c).ok_or(index)?;
+        bytes.push((pointer / 190 + 0x81) as u8);
+        bytes.push((pointer % 190 + 0x41) as u8);
+    }
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use crate::encoding::{code_point, pointer};
+use crate::tables::encoding_chinese_simplified::*;
+
+/// The pointer of the four-byte sequence that decodes to U+E7C7, which is not part of any
+/// range.
+const E7C7_POINTER: usize = 7457;
+
+/// The pointer of the first four-byte sequence of the chars above the BMP, which are
+/// encoded in order.
+const SUPPLEMENTARY_POINTER: usize = 189000;
+
+/// The Private Use Area chars that earlier versions of gb18030 mapped to two-byte sequences,
+/// which now decode to standard chars but still encode the old way.
+const PRIVATE_USE: &[(char, [u8; 2])] = &[
+    ('\u{E78D}', [0xA6, 0xD9]),
+    ('\u{E78E}', [0xA6, 0xDA]),
+    ('\u{E78F}', [0xA6, 0xDB]),
+    ('\u{E790}', [0xA6, 0xDC]),
+    ('\u{E791}', [0xA6, 0xDD]),
+    ('\u{E792}', [0xA6, 0xDE]),
+    ('\u{E793}', [0xA6, 0xDF]),
+    ('\u{E794}', [0xA6, 0xEC]),
+    ('\u{E795}', [0xA6, 0xED]),
+    ('\u{E796}', [0xA6, 0xF3]),
+    ('\u{E81E}', [0xFE, 0x59]),
+    ('\u{E826}', [0xFE, 0x61]),
+    ('\u{E82B}', [0xFE, 0x66]),
+    ('\u{E82C}', [0xFE, 0x67]),
+    ('\u{E832}', [0xFE, 0x6D]),
+    ('\u{E843}', [0xFE, 0x7E]),
+    ('\u{E854}', [0xFE, 0x90]),
+    ('\u{E864}', [0xFE, 0xA0]),
+];
+
+/// Returns the char of the four-byte sequence with the given pointer.
+fn ranges_code_point(pointer: usize) -> Option<char> {
+    if pointer == E7C7_POINTER {
+        return Some('\u{E7C7}');
+    }
+    if pointer >= SUPPLEMENTARY_POINTER {
+        return char::from_u32(u32::try_from(pointer - SUPPLEMENTARY_POINTER).ok()? + 0x10000);
+    }
+    let last = GB18030_RANGES.last().unwrap();
+    if pointer > usize::from(last.0) + (0xFFFF - usize::from(last.1)) {
+        return None;
+    }
+    let range = GB18030_RANGES.partition_point(|&(start, _)| usize::from(start) <= pointer) - 1;
+    let (start, code) = GB18030_RANGES[range];
+    char::from_u32(u32::from(code) + (pointer - usize::from(start)) as u32)
+}
+
+/// Returns the pointer of the four-byte sequence of `c`, which is not in index gb18030.
+fn ranges_pointer(c: char) -> usize {
+    let code = u32::from(c);
+    if c == '\u{E7C7}' {
+        return E7C7_POINTER;
+    }
+    if code >= 0x10000 {
+        return SUPPLEMENTARY_POINTER + (code - 0x10000) as usize;
+    }
+    let range = GB18030_RANGES.partition_point(|&(_, start)| u32::from(start) <= code) - 1;
+    let (pointer, start) = GB18030_RANGES[range];
+    usize::from(pointer) + (code - u32::from(start)) as usize
+}
+
+/// Decodes gb18030, which is also the decoder of GBK.
+pub(super) fn decode(bytes: &[u8], chars: &mut Vec<char>) -> bool {
+    let mut had_errors = false;
+    let mut index = 0;
+    while let Some(&first) = bytes.get(index) {
+        // The number of bytes consumed by a sequence, or by an error.
+        let (c, len) = match first {
+            0x00..=0x7F => (Some(char::from(first)), 1),
+            0x80 => (Some('\u{20AC}'), 1),
+            0x81..=0xFE => match bytes[index + 1..] {
+                [] => (None, 1),
+                [second @ 0x30..=0x39, ref rest @ ..] => match *rest {
+                    // A truncated sequence at the end is a single error.
+                    [] => (None, 2),
+                    [third @ 0x81..=0xFE, ref rest @ ..] => match *rest {
+                        [] => (None, 3),
+                        [fourth @ 0x30..=0x39, ..] => {
+                            let pointer = ((usize::from(first - 0x81) * 10
+                                + usize::from(second - 0x30))
+                                * 126
+                                + usize::from(third - 0x81))
+                                * 10
+                                + usize::from(fourth - 0x30);
+                            (ranges_code_point(pointer), 4)
+                        }
+                        // The second, third and fourth bytes are decoded again.
+                        _ => (None, 1),
+                    },
+                    _ => (None, 1),
+                },
+                [second, ..] => {
+                    let offset = if second < 0x7F { 0x40 } else { 0x41 };
+                    let c = match second {
+                        0x40..=0x7E | 0x80..=0xFE => {
+                            let pointer =
+                                usize::from(first - 0x81) * 190 + usize::from(second - offset);
+                            code_point(GB18030, pointer)
+                        }
+                        _ => None,
+                    };
+                    // An ASCII second byte is decoded on its own.
+                    (
+                        c,
+                        if c.is_none() && second.is_ascii() {
+                            1
+                        } else {
+                            2
+                        },
+                    )
+                }
+            },
+            0xFF => (None, 1),
+        };
+        match c {
+            Some(c) => chars.push(c),
+            None => {
+                had_errors = true;
+                chars.push(char::REPLACEMENT_CHARACTER);
+            }
+        }
+        index += len;
+    }
+    had_errors
+}
+
+/// Encodes gb18030, or GBK if `four_bytes` is not set.
+pub(super) fn encode(chars: &[char], four_bytes: bool, bytes: &mut Vec<u8>) -> Result<(), usize> {
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_ascii() {
+            bytes.push(c as u8);
+            continue;
+        }
+        if c == '\u{20AC}' && !four_bytes {
+            bytes.push(0x80);
+            continue;
+        }
+        // U+E5E5 has no sequence: A3A0, which it was once mapped to, now decodes to the
+        // ideographic space, and the four-byte sequences skip it.
+        if c == '\u{E5E5}' {
+            return Err(index);
+        }
+        if let Ok(position) = PRIVATE_USE.binary_search_by_key(&c, |&(c, _)| c) {
+            bytes.extend_from_slice(&PRIVATE_USE[position].1);
+            continue;
+        }
+        if let Some(pointer) = pointer(GB18030_POINTERS, c) {
+            let trail = pointer % 190;
+            let offset = if trail < 0x3F { 0x40 } else { 0x41 };
+            bytes.push((pointer / 190 + 0x81) as u8);
+            bytes.push((trail + offset) as u8);
+            continue;
+        }
+        if !four_bytes {
+            return Err(index);
+        }
+        let pointer = ranges_pointer(c);
+        bytes.push((pointer / (10 * 126 * 10) + 0x81) as u8);
+        bytes.push((pointer / (10 * 126) % 10 + 0x30) as u8);
+        bytes.push((pointer / 10 % 126 + 0x81) as u8);
+        bytes.push((pointer % 10 + 0x30) as u8);
+    }
+    Ok(())
+}
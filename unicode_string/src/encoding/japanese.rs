@@ -0,0 +1,336 @@
+use std::mem;
+
+use crate::encoding::{code_point, pointer};
+use crate::tables::encoding_japanese::*;
+
+/// The range of pointers of Shift_JIS that decode to the Private Use Area, after index
+/// jis0208.
+const SHIFT_JIS_PRIVATE_USE: std::ops::RangeInclusive<usize> = 8836..=10715;
+
+/// Returns the halfwidth katakana with the given byte of Shift_JIS and EUC-JP.
+fn halfwidth_katakana(byte: u8) -> char {
+    char::from_u32(0xFF61 - 0xA1 + u32::from(byte)).unwrap()
+}
+
+/// Returns the byte of Shift_JIS and EUC-JP of a halfwidth katakana.
+fn halfwidth_katakana_byte(c: char) -> Option<u8> {
+    matches!(c, '\u{FF61}'..='\u{FF9F}').then(|| (u32::from(c) - 0xFF61 + 0xA1) as u8)
+}
+
+pub(super) fn decode_shift_jis(bytes: &[u8], chars: &mut Vec<char>) -> bool {
+    let mut had_errors = false;
+    let mut index = 0;
+    while let Some(&lead) = bytes.get(index) {
+        match lead {
+            0x00..=0x80 => chars.push(char::from(lead)),
+            0xA1..=0xDF => chars.push(halfwidth_katakana(lead)),
+            0x81..=0x9F | 0xE0..=0xFC if index + 1 < bytes.len() => {
+                let trail = bytes[index + 1];
+                let c = match trail {
+                    0x40..=0x7E | 0x80..=0xFC => {
+                        let lead_offset = if lead < 0xA0 { 0x81 } else { 0xC1 };
+                        let offset = if trail < 0x7F { 0x40 } else { 0x41 };
+                        let pointer =
+                            usize::from(lead - lead_offset) * 188 + usize::from(trail - offset);
+                        if SHIFT_JIS_PRIVATE_USE.contains(&pointer) {
+                            char::from_u32((0xE000 - 8836 + pointer) as u32)
+                        } else {
+                            code_point(JIS0208, pointer)
+                        }
+                    }
+                    _ => None,
+                };
+                match c {
+                    Some(c) => {
+                        chars.push(c);
+                        index += 2;
+                    }
+                    None => {
+                        had_errors = true;
+                        chars.push(char::REPLACEMENT_CHARACTER);
+                        // An ASCII trail byte is decoded on its own.
+                        index += if trail.is_ascii() { 1 } else { 2 };
+                    }
+                }
+                continue;
+            }
+            _ => {
+                had_errors = true;
+                chars.push(char::REPLACEMENT_CHARACTER);
+            }
+        }
+        index += 1;
+    }
+    had_errors
+}
+
+pub(super) fn encode_shift_jis(chars: &[char], bytes: &mut Vec<u8>) -> Result<(), usize> {
+    for (index, &c) in chars.iter().enumerate() {
+        let byte = match c {
+            '\0'..='\u{80}' => Some(c as u8),
+            '\u{A5}' => Some(0x5C),
+            '\u{203E}' => Some(0x7E),
+            _ => halfwidth_katakana_byte(c),
+        };
+        if let Some(byte) = byte {
+            bytes.push(byte);
+            continue;
+        }
+        let c = if c == '\u{2212}' { '\u{FF0D}' } else { c };
+        let pointer = pointer(SHIFT_JIS_POINTERS, c)
+            .or_else(|| pointer(JIS0208_POINTERS, c))
+            .ok_or(index)?;
+        let lead = pointer / 188;
+        let lead_offset = if lead < 0x1F { 0x81 } else { 0xC1 };
+        let trail = pointer % 188;
+        let offset = if trail < 0x3F { 0x40 } else { 0x41 };
+        bytes.push((lead + lead_offset) as u8);
+        bytes.push((trail + offset) as u8);
+    }
+    Ok(())
+}
+
+pub(super) fn decode_euc_jp(bytes: &[u8], chars: &mut Vec<char>) -> bool {
+    let mut had_errors = false;
+    let mut lead = 0;
+    let mut jis0212 = false;
+    let mut index = 0;
+    while let Some(&byte) = bytes.get(index) {
+        index += 1;
+        if lead == 0 {
+            match byte {
+                0x00..=0x7F => chars.push(char::from(byte)),
+                0x8E | 0x8F | 0xA1..=0xFE => lead = byte,
+                _ => {
+                    had_errors = true;
+                    chars.push(char::REPLACEMENT_CHARACTER);
+                }
+            }
+            continue;
+        }
+        match (mem::take(&mut lead), byte) {
+            (0x8E, 0xA1..=0xDF) => chars.push(halfwidth_katakana(byte)),
+            (0x8F, 0xA1..=0xFE) => {
+                jis0212 = true;
+                lead = byte;
+            }
+            (lead, _) => {
+                let table = if mem::take(&mut jis0212) {
+                    JIS0212
+                } else {
+                    JIS0208
+                };
+                let c = match (lead, byte) {
+                    (0xA1..=0xFE, 0xA1..=0xFE) => code_point(
+                        table,
+                        usize::from(lead - 0xA1) * 94 + usize::from(byte - 0xA1),
+                    ),
+                    _ => None,
+                };
+                match c {
+                    Some(c) => chars.push(c),
+                    None => {
+                        had_errors = true;
+                        chars.push(char::REPLACEMENT_CHARACTER);
+                        // An ASCII byte is decoded on its own.
+                        if byte.is_ascii() {
+                            index -= 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    if lead != 0 {
+        had_errors = true;
+        chars.push(char::REPLACEMENT_CHARACTER);
+    }
+    had_errors
+}
+
+pub(super) fn encode_euc_jp(chars: &[char], bytes: &mut Vec<u8>) -> Result<(), usize> {
+    for (index, &c) in chars.iter().enumerate() {
+        match c {
+            '\0'..='\u{7F}' => bytes.push(c as u8),
+            '\u{A5}' => bytes.push(0x5C),
+            '\u{203E}' => bytes.push(0x7E),
+            '\u{FF61}'..='\u{FF9F}' => {
+                bytes.push(0x8E);
+                bytes.push(halfwidth_katakana_byte(c).unwrap());
+            }
+            _ => {
+                let c = if c == '\u{2212}' { '\u{FF0D}' } else { c };
+                let pointer = pointer(JIS0208_POINTERS, c).ok_or(index)?;
+                bytes.push((pointer / 94 + 0xA1) as u8);
+                bytes.push((pointer % 94 + 0xA1) as u8);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The states of the ISO-2022-JP decoder and encoder. Escape sequences select the first
+/// four.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// ASCII, selected by `ESC ( B`.
+    Ascii,
+    /// JIS X 0201 Roman, ASCII with a yen sign and an overline, selected by `ESC ( J`.
+    Roman,
+    /// JIS X 0201 katakana, selected by `ESC ( I`.
+    Katakana,
+    /// JIS X 0208, selected by `ESC $ @` or `ESC $ B`, before its first byte.
+    Lead,
+    /// JIS X 0208, after its first byte.
+    Trail,
+    /// After an escape.
+    EscapeStart,
+    /// After an escape and the first byte of an escape sequence.
+    Escape,
+}
+
+pub(super) fn decode_iso_2022_jp(bytes: &[u8], chars: &mut Vec<char>) -> bool {
+    let mut had_errors = false;
+    let mut error = |chars: &mut Vec<char>| {
+        had_errors = true;
+        chars.push(char::REPLACEMENT_CHARACTER);
+    };
+    let mut state = State::Ascii;
+    // The state selected by the last escape sequence.
+    let mut output = State::Ascii;
+    let mut lead = 0;
+    // Whether nothing was decoded since the last escape sequence: two escape sequences in a
+    // row are an error, since they could hide chars.
+    let mut after_escape = false;
+    // The index of the next byte, which is `bytes.len()` for the end of the input. Bytes are
+    // decoded again by going back.
+    let mut index = 0;
+    loop {
+        let byte = bytes.get(index).copied();
+        index += 1;
+        match (state, byte) {
+            (State::Ascii | State::Roman | State::Katakana | State::Lead, Some(0x1B)) => {
+                state = State::EscapeStart;
+            }
+            (State::Ascii | State::Roman | State::Katakana | State::Lead, Some(byte)) => {
+                after_escape = false;
+                match (state, byte) {
+                    (State::Ascii | State::Roman, 0x0E | 0x0F) => error(chars),
+                    (State::Roman, 0x5C) => chars.push('\u{A5}'),
+                    (State::Roman, 0x7E) => chars.push('\u{203E}'),
+                    (State::Ascii | State::Roman, 0x00..=0x7F) => chars.push(char::from(byte)),
+                    (State::Katakana, 0x21..=0x5F) => {
+                        chars.push(char::from_u32(0xFF61 - 0x21 + u32::from(byte)).unwrap())
+                    }
+                    (State::Lead, 0x21..=0x7E) => {
+                        lead = byte;
+                        state = State::Trail;
+                    }
+                    _ => error(chars),
+                }
+            }
+            (State::Ascii | State::Roman | State::Katakana | State::Lead, None) => break,
+            (State::Trail, Some(0x1B)) => {
+                state = State::EscapeStart;
+                error(chars);
+            }
+            (State::Trail, Some(byte @ 0x21..=0x7E)) => {
+                state = State::Lead;
+                let pointer = usize::from(lead - 0x21) * 94 + usize::from(byte - 0x21);
+                match code_point(JIS0208, pointer) {
+                    Some(c) => chars.push(c),
+                    None => error(chars),
+                }
+            }
+            (State::Trail, _) => {
+                state = State::Lead;
+                if byte.is_none() {
+                    index -= 1;
+                }
+                error(chars);
+            }
+            (State::EscapeStart, Some(byte @ (0x24 | 0x28))) => {
+                lead = byte;
+                state = State::Escape;
+            }
+            (State::EscapeStart, _) => {
+                index -= 1;
+                after_escape = false;
+                state = output;
+                error(chars);
+            }
+            (State::Escape, _) => {
+                let selected = match (mem::take(&mut lead), byte) {
+                    (0x28, Some(0x42)) => Some(State::Ascii),
+                    (0x28, Some(0x4A)) => Some(State::Roman),
+                    (0x28, Some(0x49)) => Some(State::Katakana),
+                    (0x24, Some(0x40 | 0x42)) => Some(State::Lead),
+                    _ => None,
+                };
+                match selected {
+                    Some(selected) => {
+                        state = selected;
+                        output = selected;
+                        if mem::replace(&mut after_escape, true) {
+                            error(chars);
+                        }
+                    }
+                    None => {
+                        // The first byte of the escape sequence is decoded again.
+                        index -= 2;
+                        after_escape = false;
+                        state = output;
+                        error(chars);
+                    }
+                }
+            }
+        }
+    }
+    had_errors
+}
+
+pub(super) fn encode_iso_2022_jp(chars: &[char], bytes: &mut Vec<u8>) -> Result<(), usize> {
+    let mut state = State::Ascii;
+    for (index, &c) in chars.iter().enumerate() {
+        if matches!(c, '\u{0E}' | '\u{0F}' | '\u{1B}') {
+            return Err(index);
+        }
+        if c.is_ascii() && (state == State::Ascii || state == State::Roman && c != '\\' && c != '~')
+        {
+            bytes.push(c as u8);
+            continue;
+        }
+        if c.is_ascii() {
+            bytes.extend_from_slice(b"\x1B(B");
+            state = State::Ascii;
+            bytes.push(c as u8);
+            continue;
+        }
+        if c == '\u{A5}' || c == '\u{203E}' {
+            if state != State::Roman {
+                bytes.extend_from_slice(b"\x1B(J");
+                state = State::Roman;
+            }
+            bytes.push(if c == '\u{A5}' { 0x5C } else { 0x7E });
+            continue;
+        }
+        let c = match c {
+            '\u{2212}' => '\u{FF0D}',
+            '\u{FF61}'..='\u{FF9F}' => {
+                code_point(&ISO_2022_JP_KATAKANA, (u32::from(c) - 0xFF61) as usize).unwrap()
+            }
+            _ => c,
+        };
+        let pointer = pointer(JIS0208_POINTERS, c).ok_or(index)?;
+        if state != State::Lead {
+            bytes.extend_from_slice(b"\x1B$B");
+            state = State::Lead;
+        }
+        bytes.push((pointer / 94 + 0x21) as u8);
+        bytes.push((pointer % 94 + 0x21) as u8);
+    }
+    if state != State::Ascii {
+        bytes.extend_from_slice(b"\x1B(B");
+    }
+    Ok(())
+}
@@ -0,0 +1,854 @@
+//! Decoding and encoding of legacy character encodings.
+//!
+//! [`decode`] turns bytes in an [`Encoding`] into a `UnicodeString`, replacing the bytes that
+//! are not valid in the encoding with U+FFFD REPLACEMENT CHARACTER, and [`encode`] turns a
+//! `unicode_str` back into bytes. Both follow the [WHATWG Encoding
+//! Standard](https://encoding.spec.whatwg.org/), which describes the encodings as browsers
+//! implement them, including the vendor extensions that real documents use: for instance,
+//! Shift_JIS is decoded like Windows code page 932, and GBK and gb18030 share their decoder.
+//!
+//! UTF-8 and ISO-8859-1 are always available. The tables of the other encodings are large, so
+//! each family is behind a cargo feature, and its variants of [`Encoding`] only exist when the
+//! feature is enabled:
+//!
+//! | Feature                        | Encodings                                                  |
+//! |--------------------------------|------------------------------------------------------------|
+//! | `encoding-single-byte`         | IBM866, ISO-8859-x, KOI8-R, KOI8-U, macintosh, windows-125x, windows-874, x-mac-cyrillic |
+//! | `encoding-japanese`            | Shift_JIS, EUC-JP, ISO-2022-JP                             |
+//! | `encoding-chinese-simplified`  | GBK, gb18030                                               |
+//! | `encoding-chinese-traditional` | Big5                                                       |
+//! | `encoding-korean`              | EUC-KR                                                     |
+//! | `encoding-all`                 | All of the above                                           |
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use unicode_string::encoding::{self, Encoding};
+//! use unicode_string::ustr;
+//!
+//! let (s, had_errors) = encoding::decode(b"Caf\xE9", Encoding::Latin1);
+//! assert_eq!(s, ustr!("Café"));
+//! assert!(!had_errors);
+//!
+//! assert_eq!(encoding::encode(ustr!("Café"), Encoding::Latin1).unwrap(), b"Caf\xE9");
+//!
+//! let error = encoding::encode(ustr!("Cafè ☕"), Encoding::Latin1).unwrap_err();
+//! assert_eq!((error.char(), error.index()), ('☕', 5));
+//! ```
+
+#[cfg(feature = "encoding-chinese-traditional")]
+mod big5;
+mod error;
+#[cfg(feature = "encoding-korean")]
+mod euc_kr;
+#[cfg(feature = "encoding-chinese-simplified")]
+mod gb18030;
+#[cfg(feature = "encoding-japanese")]
+mod japanese;
+#[cfg(feature = "encoding-single-byte")]
+mod single_byte;
+
+pub use self::error::*;
+
+use crate::{unicode_str, UnicodeString};
+
+/// A character encoding.
+///
+/// The variants of the encodings behind a cargo feature only exist when the feature is
+/// enabled; see the [module documentation](self).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::encoding::Encoding;
+///
+/// assert_eq!(Encoding::for_label(" UTF8 "), Some(Encoding::Utf8));
+/// assert_eq!(Encoding::Utf8.name(), "UTF-8");
+/// assert_eq!(Encoding::for_label("utf-7"), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Encoding {
+    /// UTF-8.
+    Utf8,
+    /// ISO-8859-1, which maps every byte to the char with the same code point.
+    ///
+    /// The WHATWG Encoding Standard has no such encoding: its labels, like `latin1`, name
+    /// windows-1252, which most documents labeled ISO-8859-1 actually use.
+    Latin1,
+    /// IBM866, the DOS code page for Cyrillic.
+    #[cfg(feature = "encoding-single-byte")]
+    Ibm866,
+    /// ISO-8859-2, Latin 2 (Central European).
+    #[cfg(feature = "encoding-single-byte")]
+    Iso8859_2,
+    /// ISO-8859-3, Latin 3 (South European).
+    #[cfg(feature = "encoding-single-byte")]
+    Iso8859_3,
+    /// ISO-8859-4, Latin 4 (North European).
+    #[cfg(feature = "encoding-single-byte")]
+    Iso8859_4,
+    /// ISO-8859-5, Cyrillic.
+    #[cfg(feature = "encoding-single-byte")]
+    Iso8859_5,
+    /// ISO-8859-6, Arabic.
+    #[cfg(feature = "encoding-single-byte")]
+    Iso8859_6,
+    /// ISO-8859-7, Greek.
+    #[cfg(feature = "encoding-single-byte")]
+    Iso8859_7,
+    /// ISO-8859-8, Hebrew in visual order.
+    #[cfg(feature = "encoding-single-byte")]
+    Iso8859_8,
+    /// ISO-8859-8-I, Hebrew in logical order, which is decoded and encoded like ISO-8859-8.
+    #[cfg(feature = "encoding-single-byte")]
+    Iso8859_8I,
+    /// ISO-8859-10, Latin 6 (Nordic).
+    #[cfg(feature = "encoding-single-byte")]
+    Iso8859_10,
+    /// ISO-8859-13, Latin 7 (Baltic).
+    #[cfg(feature = "encoding-single-byte")]
+    Iso8859_13,
+    /// ISO-8859-14, Latin 8 (Celtic).
+    #[cfg(feature = "encoding-single-byte")]
+    Iso8859_14,
+    /// ISO-8859-15, Latin 9 (Western European with the euro sign).
+    #[cfg(feature = "encoding-single-byte")]
+    Iso8859_15,
+    /// ISO-8859-16, Latin 10 (South-Eastern European).
+    #[cfg(feature = "encoding-single-byte")]
+    Iso8859_16,
+    /// KOI8-R, Russian.
+    #[cfg(feature = "encoding-single-byte")]
+    Koi8R,
+    /// KOI8-U, Ukrainian.
+    #[cfg(feature = "encoding-single-byte")]
+    Koi8U,
+    /// Mac OS Roman.
+    #[cfg(feature = "encoding-single-byte")]
+    Macintosh,
+    /// windows-874, Thai.
+    #[cfg(feature = "encoding-single-byte")]
+    Windows874,
+    /// windows-1250, Central European.
+    #[cfg(feature = "encoding-single-byte")]
+    Windows1250,
+    /// windows-1251, Cyrillic.
+    #[cfg(feature = "encoding-single-byte")]
+    Windows1251,
+    /// windows-1252, Western European.
+    #[cfg(feature = "encoding-single-byte")]
+    Windows1252,
+    /// windows-1253, Greek.
+    #[cfg(feature = "encoding-single-byte")]
+    Windows1253,
+    /// windows-1254, Turkish.
+    #[cfg(feature = "encoding-single-byte")]
+    Windows1254,
+    /// windows-1255, Hebrew.
+    #[cfg(feature = "encoding-single-byte")]
+    Windows1255,
+    /// windows-1256, Arabic.
+    #[cfg(feature = "encoding-single-byte")]
+    Windows1256,
+    /// windows-1257, Baltic.
+    #[cfg(feature = "encoding-single-byte")]
+    Windows1257,
+    /// windows-1258, Vietnamese.
+    #[cfg(feature = "encoding-single-byte")]
+    Windows1258,
+    /// Mac OS Cyrillic.
+    #[cfg(feature = "encoding-single-byte")]
+    XMacCyrillic,
+    /// GBK, Simplified Chinese: gb18030 without its four-byte sequences.
+    #[cfg(feature = "encoding-chinese-simplified")]
+    Gbk,
+    /// gb18030, Simplified Chinese, which can encode every char.
+    #[cfg(feature = "encoding-chinese-simplified")]
+    Gb18030,
+    /// Big5, Traditional Chinese, with the HKSCS extensions.
+    #[cfg(feature = "encoding-chinese-traditional")]
+    Big5,
+    /// EUC-JP, Japanese, with JIS X 0212 when decoding.
+    #[cfg(feature = "encoding-japanese")]
+    EucJp,
+    /// ISO-2022-JP, Japanese, as used in email.
+    #[cfg(feature = "encoding-japanese")]
+    Iso2022Jp,
+    /// Shift_JIS, Japanese, with the extensions of Windows code page 932.
+    #[cfg(feature = "encoding-japanese")]
+    ShiftJis,
+    /// EUC-KR, Korean, with the extensions of Windows code page 949.
+    #[cfg(feature = "encoding-korean")]
+    EucKr,
+}
+
+/// The labels of every encoding, as defined by the WHATWG Encoding Standard.
+static LABELS: &[(&str, Encoding)] = &[
+    ("unicode-1-1-utf-8", Encoding::Utf8),
+    ("unicode11utf8", Encoding::Utf8),
+    ("unicode20utf8", Encoding::Utf8),
+    ("utf-8", Encoding::Utf8),
+    ("utf8", Encoding::Utf8),
+    ("x-unicode20utf8", Encoding::Utf8),
+    #[cfg(feature = "encoding-single-byte")]
+    ("866", Encoding::Ibm866),
+    #[cfg(feature = "encoding-single-byte")]
+    ("cp866", Encoding::Ibm866),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csibm866", Encoding::Ibm866),
+    #[cfg(feature = "encoding-single-byte")]
+    ("ibm866", Encoding::Ibm866),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csisolatin2", Encoding::Iso8859_2),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-2", Encoding::Iso8859_2),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-ir-101", Encoding::Iso8859_2),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso8859-2", Encoding::Iso8859_2),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso88592", Encoding::Iso8859_2),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-2", Encoding::Iso8859_2),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-2:1987", Encoding::Iso8859_2),
+    #[cfg(feature = "encoding-single-byte")]
+    ("l2", Encoding::Iso8859_2),
+    #[cfg(feature = "encoding-single-byte")]
+    ("latin2", Encoding::Iso8859_2),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csisolatin3", Encoding::Iso8859_3),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-3", Encoding::Iso8859_3),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-ir-109", Encoding::Iso8859_3),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso8859-3", Encoding::Iso8859_3),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso88593", Encoding::Iso8859_3),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-3", Encoding::Iso8859_3),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-3:1988", Encoding::Iso8859_3),
+    #[cfg(feature = "encoding-single-byte")]
+    ("l3", Encoding::Iso8859_3),
+    #[cfg(feature = "encoding-single-byte")]
+    ("latin3", Encoding::Iso8859_3),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csisolatin4", Encoding::Iso8859_4),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-4", Encoding::Iso8859_4),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-ir-110", Encoding::Iso8859_4),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso8859-4", Encoding::Iso8859_4),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso88594", Encoding::Iso8859_4),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-4", Encoding::Iso8859_4),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-4:1988", Encoding::Iso8859_4),
+    #[cfg(feature = "encoding-single-byte")]
+    ("l4", Encoding::Iso8859_4),
+    #[cfg(feature = "encoding-single-byte")]
+    ("latin4", Encoding::Iso8859_4),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csisolatincyrillic", Encoding::Iso8859_5),
+    #[cfg(feature = "encoding-single-byte")]
+    ("cyrillic", Encoding::Iso8859_5),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-5", Encoding::Iso8859_5),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-ir-144", Encoding::Iso8859_5),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso8859-5", Encoding::Iso8859_5),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso88595", Encoding::Iso8859_5),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-5", Encoding::Iso8859_5),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-5:1988", Encoding::Iso8859_5),
+    #[cfg(feature = "encoding-single-byte")]
+    ("arabic", Encoding::Iso8859_6),
+    #[cfg(feature = "encoding-single-byte")]
+    ("asmo-708", Encoding::Iso8859_6),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csiso88596e", Encoding::Iso8859_6),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csiso88596i", Encoding::Iso8859_6),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csisolatinarabic", Encoding::Iso8859_6),
+    #[cfg(feature = "encoding-single-byte")]
+    ("ecma-114", Encoding::Iso8859_6),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-6", Encoding::Iso8859_6),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-6-e", Encoding::Iso8859_6),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-6-i", Encoding::Iso8859_6),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-ir-127", Encoding::Iso8859_6),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso8859-6", Encoding::Iso8859_6),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso88596", Encoding::Iso8859_6),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-6", Encoding::Iso8859_6),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-6:1987", Encoding::Iso8859_6),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csisolatingreek", Encoding::Iso8859_7),
+    #[cfg(feature = "encoding-single-byte")]
+    ("ecma-118", Encoding::Iso8859_7),
+    #[cfg(feature = "encoding-single-byte")]
+    ("elot_928", Encoding::Iso8859_7),
+    #[cfg(feature = "encoding-single-byte")]
+    ("greek", Encoding::Iso8859_7),
+    #[cfg(feature = "encoding-single-byte")]
+    ("greek8", Encoding::Iso8859_7),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-7", Encoding::Iso8859_7),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-ir-126", Encoding::Iso8859_7),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso8859-7", Encoding::Iso8859_7),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso88597", Encoding::Iso8859_7),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-7", Encoding::Iso8859_7),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-7:1987", Encoding::Iso8859_7),
+    #[cfg(feature = "encoding-single-byte")]
+    ("sun_eu_greek", Encoding::Iso8859_7),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csiso88598e", Encoding::Iso8859_8),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csisolatinhebrew", Encoding::Iso8859_8),
+    #[cfg(feature = "encoding-single-byte")]
+    ("hebrew", Encoding::Iso8859_8),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-8", Encoding::Iso8859_8),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-8-e", Encoding::Iso8859_8),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-ir-138", Encoding::Iso8859_8),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso8859-8", Encoding::Iso8859_8),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso88598", Encoding::Iso8859_8),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-8", Encoding::Iso8859_8),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-8:1988", Encoding::Iso8859_8),
+    #[cfg(feature = "encoding-single-byte")]
+    ("visual", Encoding::Iso8859_8),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csiso88598i", Encoding::Iso8859_8I),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-8-i", Encoding::Iso8859_8I),
+    #[cfg(feature = "encoding-single-byte")]
+    ("logical", Encoding::Iso8859_8I),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csisolatin6", Encoding::Iso8859_10),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-10", Encoding::Iso8859_10),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-ir-157", Encoding::Iso8859_10),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso8859-10", Encoding::Iso8859_10),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso885910", Encoding::Iso8859_10),
+    #[cfg(feature = "encoding-single-byte")]
+    ("l6", Encoding::Iso8859_10),
+    #[cfg(feature = "encoding-single-byte")]
+    ("latin6", Encoding::Iso8859_10),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-13", Encoding::Iso8859_13),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso8859-13", Encoding::Iso8859_13),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso885913", Encoding::Iso8859_13),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-14", Encoding::Iso8859_14),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso8859-14", Encoding::Iso8859_14),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso885914", Encoding::Iso8859_14),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csisolatin9", Encoding::Iso8859_15),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-15", Encoding::Iso8859_15),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso8859-15", Encoding::Iso8859_15),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso885915", Encoding::Iso8859_15),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-15", Encoding::Iso8859_15),
+    #[cfg(feature = "encoding-single-byte")]
+    ("l9", Encoding::Iso8859_15),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-16", Encoding::Iso8859_16),
+    #[cfg(feature = "encoding-single-byte")]
+    ("cskoi8r", Encoding::Koi8R),
+    #[cfg(feature = "encoding-single-byte")]
+    ("koi", Encoding::Koi8R),
+    #[cfg(feature = "encoding-single-byte")]
+    ("koi8", Encoding::Koi8R),
+    #[cfg(feature = "encoding-single-byte")]
+    ("koi8-r", Encoding::Koi8R),
+    #[cfg(feature = "encoding-single-byte")]
+    ("koi8_r", Encoding::Koi8R),
+    #[cfg(feature = "encoding-single-byte")]
+    ("koi8-ru", Encoding::Koi8U),
+    #[cfg(feature = "encoding-single-byte")]
+    ("koi8-u", Encoding::Koi8U),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csmacintosh", Encoding::Macintosh),
+    #[cfg(feature = "encoding-single-byte")]
+    ("mac", Encoding::Macintosh),
+    #[cfg(feature = "encoding-single-byte")]
+    ("macintosh", Encoding::Macintosh),
+    #[cfg(feature = "encoding-single-byte")]
+    ("x-mac-roman", Encoding::Macintosh),
+    #[cfg(feature = "encoding-single-byte")]
+    ("dos-874", Encoding::Windows874),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-11", Encoding::Windows874),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso8859-11", Encoding::Windows874),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso885911", Encoding::Windows874),
+    #[cfg(feature = "encoding-single-byte")]
+    ("tis-620", Encoding::Windows874),
+    #[cfg(feature = "encoding-single-byte")]
+    ("windows-874", Encoding::Windows874),
+    #[cfg(feature = "encoding-single-byte")]
+    ("cp1250", Encoding::Windows1250),
+    #[cfg(feature = "encoding-single-byte")]
+    ("windows-1250", Encoding::Windows1250),
+    #[cfg(feature = "encoding-single-byte")]
+    ("x-cp1250", Encoding::Windows1250),
+    #[cfg(feature = "encoding-single-byte")]
+    ("cp1251", Encoding::Windows1251),
+    #[cfg(feature = "encoding-single-byte")]
+    ("windows-1251", Encoding::Windows1251),
+    #[cfg(feature = "encoding-single-byte")]
+    ("x-cp1251", Encoding::Windows1251),
+    #[cfg(feature = "encoding-single-byte")]
+    ("ansi_x3.4-1968", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("ascii", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("cp1252", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("cp819", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csisolatin1", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("ibm819", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-1", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-ir-100", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso8859-1", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso88591", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-1", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-1:1987", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("l1", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("latin1", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("us-ascii", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("windows-1252", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("x-cp1252", Encoding::Windows1252),
+    #[cfg(feature = "encoding-single-byte")]
+    ("cp1253", Encoding::Windows1253),
+    #[cfg(feature = "encoding-single-byte")]
+    ("windows-1253", Encoding::Windows1253),
+    #[cfg(feature = "encoding-single-byte")]
+    ("x-cp1253", Encoding::Windows1253),
+    #[cfg(feature = "encoding-single-byte")]
+    ("cp1254", Encoding::Windows1254),
+    #[cfg(feature = "encoding-single-byte")]
+    ("csisolatin5", Encoding::Windows1254),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-8859-9", Encoding::Windows1254),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso-ir-148", Encoding::Windows1254),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso8859-9", Encoding::Windows1254),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso88599", Encoding::Windows1254),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-9", Encoding::Windows1254),
+    #[cfg(feature = "encoding-single-byte")]
+    ("iso_8859-9:1989", Encoding::Windows1254),
+    #[cfg(feature = "encoding-single-byte")]
+    ("l5", Encoding::Windows1254),
+    #[cfg(feature = "encoding-single-byte")]
+    ("latin5", Encoding::Windows1254),
+    #[cfg(feature = "encoding-single-byte")]
+    ("windows-1254", Encoding::Windows1254),
+    #[cfg(feature = "encoding-single-byte")]
+    ("x-cp1254", Encoding::Windows1254),
+    #[cfg(feature = "encoding-single-byte")]
+    ("cp1255", Encoding::Windows1255),
+    #[cfg(feature = "encoding-single-byte")]
+    ("windows-1255", Encoding::Windows1255),
+    #[cfg(feature = "encoding-single-byte")]
+    ("x-cp1255", Encoding::Windows1255),
+    #[cfg(feature = "encoding-single-byte")]
+    ("cp1256", Encoding::Windows1256),
+    #[cfg(feature = "encoding-single-byte")]
+    ("windows-1256", Encoding::Windows1256),
+    #[cfg(feature = "encoding-single-byte")]
+    ("x-cp1256", Encoding::Windows1256),
+    #[cfg(feature = "encoding-single-byte")]
+    ("cp1257", Encoding::Windows1257),
+    #[cfg(feature = "encoding-single-byte")]
+    ("windows-1257", Encoding::Windows1257),
+    #[cfg(feature = "encoding-single-byte")]
+    ("x-cp1257", Encoding::Windows1257),
+    #[cfg(feature = "encoding-single-byte")]
+    ("cp1258", Encoding::Windows1258),
+    #[cfg(feature = "encoding-single-byte")]
+    ("windows-1258", Encoding::Windows1258),
+    #[cfg(feature = "encoding-single-byte")]
+    ("x-cp1258", Encoding::Windows1258),
+    #[cfg(feature = "encoding-single-byte")]
+    ("x-mac-cyrillic", Encoding::XMacCyrillic),
+    #[cfg(feature = "encoding-single-byte")]
+    ("x-mac-ukrainian", Encoding::XMacCyrillic),
+    #[cfg(feature = "encoding-chinese-simplified")]
+    ("chinese", Encoding::Gbk),
+    #[cfg(feature = "encoding-chinese-simplified")]
+    ("csgb2312", Encoding::Gbk),
+    #[cfg(feature = "encoding-chinese-simplified")]
+    ("csiso58gb231280", Encoding::Gbk),
+    #[cfg(feature = "encoding-chinese-simplified")]
+    ("gb2312", Encoding::Gbk),
+    #[cfg(feature = "encoding-chinese-simplified")]
+    ("gb_2312", Encoding::Gbk),
+    #[cfg(feature = "encoding-chinese-simplified")]
+    ("gb_2312-80", Encoding::Gbk),
+    #[cfg(feature = "encoding-chinese-simplified")]
+    ("gbk", Encoding::Gbk),
+    #[cfg(feature = "encoding-chinese-simplified")]
+    ("iso-ir-58", Encoding::Gbk),
+    #[cfg(feature = "encoding-chinese-simplified")]
+    ("x-gbk", Encoding::Gbk),
+    #[cfg(feature = "encoding-chinese-simplified")]
+    ("gb18030", Encoding::Gb18030),
+    #[cfg(feature = "encoding-chinese-traditional")]
+    ("big5", Encoding::Big5),
+    #[cfg(feature = "encoding-chinese-traditional")]
+    ("big5-hkscs", Encoding::Big5),
+    #[cfg(feature = "encoding-chinese-traditional")]
+    ("cn-big5", Encoding::Big5),
+    #[cfg(feature = "encoding-chinese-traditional")]
+    ("csbig5", Encoding::Big5),
+    #[cfg(feature = "encoding-chinese-traditional")]
+    ("x-x-big5", Encoding::Big5),
+    #[cfg(feature = "encoding-japanese")]
+    ("cseucpkdfmtjapanese", Encoding::EucJp),
+    #[cfg(feature = "encoding-japanese")]
+    ("euc-jp", Encoding::EucJp),
+    #[cfg(feature = "encoding-japanese")]
+    ("x-euc-jp", Encoding::EucJp),
+    #[cfg(feature = "encoding-japanese")]
+    ("csiso2022jp", Encoding::Iso2022Jp),
+    #[cfg(feature = "encoding-japanese")]
+    ("iso-2022-jp", Encoding::Iso2022Jp),
+    #[cfg(feature = "encoding-japanese")]
+    ("csshiftjis", Encoding::ShiftJis),
+    #[cfg(feature = "encoding-japanese")]
+    ("ms932", Encoding::ShiftJis),
+    #[cfg(feature = "encoding-japanese")]
+    ("ms_kanji", Encoding::ShiftJis),
+    #[cfg(feature = "encoding-japanese")]
+    ("shift-jis", Encoding::ShiftJis),
+    #[cfg(feature = "encoding-japanese")]
+    ("shift_jis", Encoding::ShiftJis),
+    #[cfg(feature = "encoding-japanese")]
+    ("sjis", Encoding::ShiftJis),
+    #[cfg(feature = "encoding-japanese")]
+    ("windows-31j", Encoding::ShiftJis),
+    #[cfg(feature = "encoding-japanese")]
+    ("x-sjis", Encoding::ShiftJis),
+    #[cfg(feature = "encoding-korean")]
+    ("cseuckr", Encoding::EucKr),
+    #[cfg(feature = "encoding-korean")]
+    ("csksc56011987", Encoding::EucKr),
+    #[cfg(feature = "encoding-korean")]
+    ("euc-kr", Encoding::EucKr),
+    #[cfg(feature = "encoding-korean")]
+    ("iso-ir-149", Encoding::EucKr),
+    #[cfg(feature = "encoding-korean")]
+    ("korean", Encoding::EucKr),
+    #[cfg(feature = "encoding-korean")]
+    ("ks_c_5601-1987", Encoding::EucKr),
+    #[cfg(feature = "encoding-korean")]
+    ("ks_c_5601-1989", Encoding::EucKr),
+    #[cfg(feature = "encoding-korean")]
+    ("ksc5601", Encoding::EucKr),
+    #[cfg(feature = "encoding-korean")]
+    ("ksc_5601", Encoding::EucKr),
+    #[cfg(feature = "encoding-korean")]
+    ("windows-949", Encoding::EucKr),
+];
+
+impl Encoding {
+    /// Returns the encoding with the given label, as defined by the WHATWG Encoding Standard,
+    /// ignoring ASCII case and surrounding ASCII whitespace. Returns `None` for unknown labels
+    /// and for the labels of encodings whose feature is not enabled.
+    ///
+    /// Following the standard, `latin1`, `iso-8859-1` and `ascii` are labels of windows-1252,
+    /// and no label returns [`Encoding::Latin1`].
+    pub fn for_label(label: &str) -> Option<Encoding> {
+        let label = label.trim_matches(|c| matches!(c, '\t' | '\n' | '\x0C' | '\r' | ' '));
+        LABELS
+            .iter()
+            .find(|(other, _)| other.eq_ignore_ascii_case(label))
+            .map(|&(_, encoding)| encoding)
+    }
+
+    /// Returns the name of the encoding, as written by the WHATWG Encoding Standard.
+    pub fn name(self) -> &'static str {
+        match self {
+            Encoding::Utf8 => "UTF-8",
+            Encoding::Latin1 => "ISO-8859-1",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Ibm866 => "IBM866",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Iso8859_2 => "ISO-8859-2",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Iso8859_3 => "ISO-8859-3",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Iso8859_4 => "ISO-8859-4",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Iso8859_5 => "ISO-8859-5",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Iso8859_6 => "ISO-8859-6",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Iso8859_7 => "ISO-8859-7",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Iso8859_8 => "ISO-8859-8",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Iso8859_8I => "ISO-8859-8-I",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Iso8859_10 => "ISO-8859-10",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Iso8859_13 => "ISO-8859-13",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Iso8859_14 => "ISO-8859-14",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Iso8859_15 => "ISO-8859-15",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Iso8859_16 => "ISO-8859-16",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Koi8R => "KOI8-R",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Koi8U => "KOI8-U",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Macintosh => "macintosh",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Windows874 => "windows-874",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Windows1250 => "windows-1250",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Windows1251 => "windows-1251",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Windows1252 => "windows-1252",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Windows1253 => "windows-1253",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Windows1254 => "windows-1254",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Windows1255 => "windows-1255",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Windows1256 => "windows-1256",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Windows1257 => "windows-1257",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::Windows1258 => "windows-1258",
+            #[cfg(feature = "encoding-single-byte")]
+            Encoding::XMacCyrillic => "x-mac-cyrillic",
+            #[cfg(feature = "encoding-chinese-simplified")]
+            Encoding::Gbk => "GBK",
+            #[cfg(feature = "encoding-chinese-simplified")]
+            Encoding::Gb18030 => "gb18030",
+            #[cfg(feature = "encoding-chinese-traditional")]
+            Encoding::Big5 => "Big5",
+            #[cfg(feature = "encoding-japanese")]
+            Encoding::EucJp => "EUC-JP",
+            #[cfg(feature = "encoding-japanese")]
+            Encoding::Iso2022Jp => "ISO-2022-JP",
+            #[cfg(feature = "encoding-japanese")]
+            Encoding::ShiftJis => "Shift_JIS",
+            #[cfg(feature = "encoding-korean")]
+            Encoding::EucKr => "EUC-KR",
+        }
+    }
+}
+
+/// Decodes `bytes` in `encoding`, and returns the decoded string and whether some bytes were
+/// not valid in the encoding.
+///
+/// Invalid bytes are replaced with U+FFFD REPLACEMENT CHARACTER, as many as the WHATWG
+/// Encoding Standard requires, so that the result is the same as a browser's. A byte order
+/// mark is not removed.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::encoding::{self, Encoding};
+/// use unicode_string::ustr;
+///
+/// let (s, had_errors) = encoding::decode(b"\xF0\x9F\xA6\x80 \xF0\x9F", Encoding::Utf8);
+/// assert_eq!(s, ustr!("🦀 \u{FFFD}"));
+/// assert!(had_errors);
+///
+/// #[cfg(feature = "encoding-japanese")]
+/// {
+///     let (s, had_errors) = encoding::decode(b"\x93\xfa\x96\x7b\x8c\xea", Encoding::ShiftJis);
+///     assert_eq!(s, ustr!("日本語"));
+///     assert!(!had_errors);
+/// }
+/// ```
+pub fn decode(bytes: &[u8], encoding: Encoding) -> (UnicodeString, bool) {
+    let mut s = UnicodeString::with_capacity(bytes.len());
+    let had_errors = match encoding {
+        Encoding::Utf8 => {
+            let decoded = String::from_utf8_lossy(bytes);
+            s.vec.extend(decoded.chars());
+            matches!(decoded, std::borrow::Cow::Owned(_))
+        }
+        Encoding::Latin1 => {
+            s.vec.extend(bytes.iter().map(|&byte| char::from(byte)));
+            false
+        }
+        #[cfg(feature = "encoding-chinese-simplified")]
+        Encoding::Gbk | Encoding::Gb18030 => gb18030::decode(bytes, &mut s.vec),
+        #[cfg(feature = "encoding-chinese-traditional")]
+        Encoding::Big5 => big5::decode(bytes, &mut s.vec),
+        #[cfg(feature = "encoding-japanese")]
+        Encoding::EucJp => japanese::decode_euc_jp(bytes, &mut s.vec),
+        #[cfg(feature = "encoding-japanese")]
+        Encoding::Iso2022Jp => japanese::decode_iso_2022_jp(bytes, &mut s.vec),
+        #[cfg(feature = "encoding-japanese")]
+        Encoding::ShiftJis => japanese::decode_shift_jis(bytes, &mut s.vec),
+        #[cfg(feature = "encoding-korean")]
+        Encoding::EucKr => euc_kr::decode(bytes, &mut s.vec),
+        #[cfg(feature = "encoding-single-byte")]
+        _ => single_byte::decode(bytes, single_byte::table(encoding), &mut s.vec),
+    };
+    (s, had_errors)
+}
+
+/// Encodes `s` in `encoding`.
+///
+/// # Errors
+///
+/// Returns an [`UnencodableChar`] error for the first char of `s` that cannot be encoded in
+/// `encoding`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::encoding::{self, Encoding};
+/// use unicode_string::ustr;
+///
+/// assert_eq!(encoding::encode(ustr!("né"), Encoding::Utf8).unwrap(), "né".as_bytes());
+///
+/// #[cfg(feature = "encoding-korean")]
+/// {
+///     let bytes = encoding::encode(ustr!("한국어"), Encoding::EucKr).unwrap();
+///     assert_eq!(bytes, b"\xc7\xd1\xb1\xb9\xbe\xee");
+///
+///     let error = encoding::encode(ustr!("한국어 🇰🇷"), Encoding::EucKr).unwrap_err();
+///     assert_eq!(error.index(), 4);
+///     assert_eq!(error.to_string(), "'🇰' (U+1F1F0) at char 4 cannot be encoded in EUC-KR");
+/// }
+/// ```
+pub fn encode(s: &unicode_str, encoding: Encoding) -> Result<Vec<u8>, UnencodableChar> {
+    let chars = s.chars();
+    let mut bytes = Vec::with_capacity(chars.len());
+    let result = match encoding {
+        Encoding::Utf8 => {
+            bytes.extend(String::from_iter(chars).bytes());
+            Ok(())
+        }
+        Encoding::Latin1 => chars.iter().enumerate().try_for_each(|(index, &c)| {
+            bytes.push(u8::try_from(c).map_err(|_| index)?);
+            Ok(())
+        }),
+        #[cfg(feature = "encoding-chinese-simplified")]
+        Encoding::Gbk => gb18030::encode(chars, false, &mut bytes),
+        #[cfg(feature = "encoding-chinese-simplified")]
+        Encoding::Gb18030 => gb18030::encode(chars, true, &mut bytes),
+        #[cfg(feature = "encoding-chinese-traditional")]
+        Encoding::Big5 => big5::encode(chars, &mut bytes),
+        #[cfg(feature = "encoding-japanese")]
+        Encoding::EucJp => japanese::encode_euc_jp(chars, &mut bytes),
+        #[cfg(feature = "encoding-japanese")]
+        Encoding::Iso2022Jp => japanese::encode_iso_2022_jp(chars, &mut bytes),
+        #[cfg(feature = "encoding-japanese")]
+        Encoding::ShiftJis => japanese::encode_shift_jis(chars, &mut bytes),
+        #[cfg(feature = "encoding-korean")]
+        Encoding::EucKr => euc_kr::encode(chars, &mut bytes),
+        #[cfg(feature = "encoding-single-byte")]
+        _ => single_byte::encode(chars, single_byte::table(encoding), &mut bytes),
+    };
+    match result {
+        Ok(()) => Ok(bytes),
+        Err(index) => Err(UnencodableChar::new(chars[index], index, encoding)),
+    }
+}
+
+/// Returns the char that `table` maps `pointer` to, or `None` if the pointer is not mapped.
+#[cfg(any(
+    feature = "encoding-single-byte",
+    feature = "encoding-japanese",
+    feature = "encoding-chinese-simplified",
+    feature = "encoding-chinese-traditional",
+    feature = "encoding-korean"
+))]
+#[inline]
+fn code_point<T: Copy + Into<u32>>(table: &[T], pointer: usize) -> Option<char> {
+    let code = (*table.get(pointer)?).into();
+    if code == 0 {
+        return None;
+    }
+    char::from_u32(code)
+}
+
+/// Returns the pointer that `table`, which is sorted by code point, maps `c` to.
+#[cfg(any(
+    feature = "encoding-japanese",
+    feature = "encoding-chinese-simplified",
+    feature = "encoding-chinese-traditional",
+    feature = "encoding-korean"
+))]
+#[inline]
+fn pointer<T: Copy + Into<u32>>(table: &[(T, u16)], c: char) -> Option<usize> {
+    let index = table
+        .binary_search_by_key(&u32::from(c), |&(code, _)| code.into())
+        .ok()?;
+    Some(usize::from(table[index].1))
+}
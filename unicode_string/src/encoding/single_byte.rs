@@ -0,0 +1,66 @@
+use crate::encoding::{code_point, Encoding};
+use crate::tables::encoding_single_byte::*;
+
+/// Returns the table of the bytes 0x80 to 0xFF of a single-byte encoding.
+pub(super) fn table(encoding: Encoding) -> &'static [u16; 128] {
+    match encoding {
+        Encoding::Ibm866 => &IBM866,
+        Encoding::Iso8859_2 => &ISO_8859_2,
+        Encoding::Iso8859_3 => &ISO_8859_3,
+        Encoding::Iso8859_4 => &ISO_8859_4,
+        Encoding::Iso8859_5 => &ISO_8859_5,
+        Encoding::Iso8859_6 => &ISO_8859_6,
+        Encoding::Iso8859_7 => &ISO_8859_7,
+        Encoding::Iso8859_8 | Encoding::Iso8859_8I => &ISO_8859_8,
+        Encoding::Iso8859_10 => &ISO_8859_10,
+        Encoding::Iso8859_13 => &ISO_8859_13,
+        Encoding::Iso8859_14 => &ISO_8859_14,
+        Encoding::Iso8859_15 => &ISO_8859_15,
+        Encoding::Iso8859_16 => &ISO_8859_16,
+        Encoding::Koi8R => &KOI8_R,
+        Encoding::Koi8U => &KOI8_U,
+        Encoding::Macintosh => &MACINTOSH,
+        Encoding::Windows874 => &WINDOWS_874,
+        Encoding::Windows1250 => &WINDOWS_1250,
+        Encoding::Windows1251 => &WINDOWS_1251,
+        Encoding::Windows1252 => &WINDOWS_1252,
+        Encoding::Windows1253 => &WINDOWS_1253,
+        Encoding::Windows1254 => &WINDOWS_1254,
+        Encoding::Windows1255 => &WINDOWS_1255,
+        Encoding::Windows1256 => &WINDOWS_1256,
+        Encoding::Windows1257 => &WINDOWS_1257,
+        Encoding::Windows1258 => &WINDOWS_1258,
+        Encoding::XMacCyrillic => &X_MAC_CYRILLIC,
+        _ => unreachable!("{} is not a single-byte encoding", encoding.name()),
+    }
+}
+
+pub(super) fn decode(bytes: &[u8], table: &[u16; 128], chars: &mut Vec<char>) -> bool {
+    let mut had_errors = false;
+    for &byte in bytes {
+        let c = match byte {
+            0x00..=0x7F => char::from(byte),
+            _ => code_point(table, usize::from(byte - 0x80)).unwrap_or_else(|| {
+                had_errors = true;
+                char::REPLACEMENT_CHARACTER
+            }),
+        };
+        chars.push(c);
+    }
+    had_errors
+}
+
+pub(super) fn encode(chars: &[char], table: &[u16; 128], bytes: &mut Vec<u8>) -> Result<(), usize> {
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_ascii() {
+            bytes.push(c as u8);
+            continue;
+        }
+        let position = table
+            .iter()
+            .position(|&code| u32::from(code) == u32::from(c))
+            .ok_or(index)?;
+        bytes.push(0x80 + position as u8);
+    }
+    Ok(())
+}
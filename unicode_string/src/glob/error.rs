@@ -0,0 +1,40 @@
+use std::error::Error;
+use std::fmt;
+
+/// The reason a pattern cannot be compiled by [`Glob::new`](super::Glob::new).
+///
+/// Positions are char indices into the pattern.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GlobError {
+    /// The class starting at the position has no closing `]`.
+    UnclosedClass(usize),
+    /// The range of a class starting at the position is not valid, e.g. `[z-a]`.
+    InvalidRange(usize),
+    /// The alternates starting at the position have no closing `}`.
+    UnclosedAlternates(usize),
+    /// The `}` at the position has no opening `{`.
+    UnopenedAlternates(usize),
+    /// The pattern ends with a `\` that escapes nothing.
+    DanglingEscape(usize),
+    /// The compiled pattern, or set of patterns, would be too large.
+    TooLarge,
+}
+
+impl fmt::Display for GlobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GlobError::UnclosedClass(position) => write!(f, "unclosed class at {}", position),
+            GlobError::InvalidRange(position) => write!(f, "invalid range at {}", position),
+            GlobError::UnclosedAlternates(position) => {
+                write!(f, "unclosed alternates at {}", position)
+            }
+            GlobError::UnopenedAlternates(position) => {
+                write!(f, "unopened alternates at {}", position)
+            }
+            GlobError::DanglingEscape(position) => write!(f, "dangling escape at {}", position),
+            GlobError::TooLarge => f.write_str("compiled pattern is too large"),
+        }
+    }
+}
+
+impl Error for GlobError {}
@@ -0,0 +1,198 @@
+//! Shell-style wildcard patterns that match `unicode_str`s.
+//!
+//! A [`Glob`] matches the chars of a `unicode_str`, so `?` matches exactly one char, whatever
+//! its length in UTF-8. Globs are compiled to the same automaton as
+//! [`UnicodeRegex`](crate::regex::UnicodeRegex), so matching takes time linear in the length
+//! of the text. A [`GlobSet`] matches many globs at once.
+//!
+//! # Syntax
+//!
+//! | Syntax           | Matches                                                         |
+//! |------------------|-----------------------------------------------------------------|
+//! | `?`              | any char                                                        |
+//! | `*`              | any sequence of chars                                           |
+//! | `**`             | as a whole path component, any number of directories            |
+//! | `[abc]`, `[a-z]` | a char in a class                                               |
+//! | `[!a-z]`, `[^a-z]` | a char not in a class                                         |
+//! | `{a,b,c}`        | one of the comma-separated alternatives, which can be nested    |
+//! | `\x`             | the char `x`, even if it has a special meaning                  |
+//!
+//! Any other char matches itself. `**` is a path component if it is only surrounded by `/`,
+//! the bounds of the pattern or those of an alternative: `**/foo` matches `foo` and `a/b/foo`,
+//! and `foo/**` matches everything in `foo`. Elsewhere, it is the same as `*`. A `]` at the
+//! start of a class and a `-` at its start or end match themselves.
+//!
+//! By default, `*`, `?` and negated classes match `/`, so `*.rs` matches `src/lib.rs`. With
+//! [`GlobBuilder::literal_separator`], they do not, and `*.rs` only matches files in the
+//! current directory.
+
+mod error;
+mod parse;
+mod set;
+
+pub use self::error::*;
+pub use self::set::*;
+
+use std::fmt;
+use std::str::FromStr;
+
+use self::parse::Options;
+use crate::regex::class::CharClass;
+use crate::regex::compile::{self, Program};
+use crate::regex::parse::Node;
+use crate::regex::pike;
+use crate::unicode_str;
+
+/// A compiled glob. See the [module documentation](self) for the syntax.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::glob::Glob;
+/// use unicode_string::ustr;
+///
+/// let glob = Glob::new("*.{rs,toml}").unwrap();
+/// assert!(glob.is_match(ustr!("Cargo.toml")));
+/// assert!(glob.is_match(ustr!("src/größe.rs")));
+/// assert!(!glob.is_match(ustr!("README.md")));
+///
+/// // `?` matches one char, not one byte.
+/// let glob = Glob::new("[!α-ω]?.txt").unwrap();
+/// assert!(glob.is_match(ustr!("Ω🦀.txt")));
+/// assert!(!glob.is_match(ustr!("λ🦀.txt")));
+/// ```
+#[derive(Clone)]
+pub struct Glob {
+    glob: String,
+    node: Node,
+    classes: Vec<CharClass>,
+    program: Program,
+}
+
+impl Glob {
+    /// Compiles a glob with the default options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the glob is not valid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::glob::{Glob, GlobError};
+    ///
+    /// assert!(Glob::new("**/*.rs").is_ok());
+    /// assert_eq!(Glob::new("*.{rs,toml").unwrap_err(), GlobError::UnclosedAlternates(2));
+    /// assert_eq!(Glob::new("[z-a]").unwrap_err(), GlobError::InvalidRange(1));
+    /// ```
+    pub fn new(glob: &str) -> Result<Glob, GlobError> {
+        Glob::with_options(glob, Options::default())
+    }
+
+    fn with_options(glob: &str, options: Options) -> Result<Glob, GlobError> {
+        let (node, classes) = parse::parse(glob, options)?;
+        let program = compile::compile_set(std::slice::from_ref(&node), classes.clone())
+            .map_err(|_| GlobError::TooLarge)?;
+        Ok(Glob {
+            glob: glob.to_owned(),
+            node,
+            classes,
+            program,
+        })
+    }
+
+    /// Returns the pattern the glob was compiled from.
+    #[inline]
+    pub fn glob(&self) -> &str {
+        &self.glob
+    }
+
+    /// Checks if the glob matches the whole of `s`.
+    pub fn is_match(&self, s: &unicode_str) -> bool {
+        pike::search(&self.program, s.chars(), 0, &mut [], true)
+    }
+}
+
+impl fmt::Debug for Glob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Glob").field(&self.glob).finish()
+    }
+}
+
+impl fmt::Display for Glob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.glob)
+    }
+}
+
+impl FromStr for Glob {
+    type Err = GlobError;
+
+    fn from_str(glob: &str) -> Result<Glob, GlobError> {
+        Glob::new(glob)
+    }
+}
+
+/// Compiles a [`Glob`] with custom options.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::glob::GlobBuilder;
+/// use unicode_string::ustr;
+///
+/// let glob = GlobBuilder::new("*.JPG")
+///     .case_insensitive(true)
+///     .literal_separator(true)
+///     .build()
+///     .unwrap();
+///
+/// assert!(glob.is_match(ustr!("Ünïcödé.jpg")));
+/// assert!(!glob.is_match(ustr!("photos/Ünïcödé.jpg")));
+/// ```
+#[derive(Clone, Debug)]
+pub struct GlobBuilder {
+    glob: String,
+    options: Options,
+}
+
+impl GlobBuilder {
+    /// Creates a builder for a glob, with the default options.
+    pub fn new(glob: &str) -> GlobBuilder {
+        GlobBuilder {
+            glob: glob.to_owned(),
+            options: Options::default(),
+        }
+    }
+
+    /// Sets whether chars match regardless of case, using simple case folding. Defaults to
+    /// `false`.
+    #[must_use]
+    pub fn case_insensitive(mut self, yes: bool) -> GlobBuilder {
+        self.options.case_insensitive = yes;
+        self
+    }
+
+    /// Sets whether `*`, `?` and negated classes do not match `/`, so that only `**` crosses
+    /// directories. Defaults to `false`.
+    #[must_use]
+    pub fn literal_separator(mut self, yes: bool) -> GlobBuilder {
+        self.options.literal_separator = yes;
+        self
+    }
+
+    /// Compiles the glob.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the glob is not valid.
+    pub fn build(&self) -> Result<Glob, GlobError> {
+        Glob::with_options(&self.glob, self.options)
+    }
+}
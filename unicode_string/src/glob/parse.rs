@@ -0,0 +1,207 @@
+use crate::glob::GlobError;
+use crate::regex::class::CharClass;
+use crate::regex::parse::{Look, Node};
+
+/// The options that change how a glob is parsed.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Options {
+    pub(crate) case_insensitive: bool,
+    pub(crate) literal_separator: bool,
+}
+
+/// Parses a glob to a regex syntax tree that matches whole strings, with the classes it refers
+/// to.
+pub(crate) fn parse(pattern: &str, options: Options) -> Result<(Node, Vec<CharClass>), GlobError> {
+    let mut parser = Parser {
+        pattern: pattern.chars().collect(),
+        position: 0,
+        classes: Vec::new(),
+        options,
+    };
+    let node = parser.parse_sequence(0)?;
+    let node = Node::Concat(vec![Node::Look(Look::Start), node, Node::Look(Look::End)]);
+    Ok((node, parser.classes))
+}
+
+struct Parser {
+    pattern: Vec<char>,
+    position: usize,
+    classes: Vec<CharClass>,
+    options: Options,
+}
+
+impl Parser {
+    #[inline]
+    fn peek(&self) -> Option<char> {
+        self.pattern.get(self.position).copied()
+    }
+
+    /// Parses the glob, or one alternative of alternates nested `depth` deep, until the end of
+    /// the pattern or, in alternates, a `,` or `}`.
+    fn parse_sequence(&mut self, depth: usize) -> Result<Node, GlobError> {
+        let mut nodes = Vec::new();
+        while let Some(c) = self.peek() {
+            let node = match c {
+                '}' if depth == 0 => return Err(GlobError::UnopenedAlternates(self.position)),
+                '}' | ',' if depth > 0 => break,
+                '{' => self.parse_alternates(depth)?,
+                '[' => self.parse_class()?,
+                '*' => self.parse_stars(depth),
+                '?' => {
+                    self.position += 1;
+                    self.any_char()
+                }
+                '\\' => {
+                    let c = self
+                        .pattern
+                        .get(self.position + 1)
+                        .copied()
+                        .ok_or(GlobError::DanglingEscape(self.position))?;
+                    self.position += 2;
+                    self.literal(c)
+                }
+                c => {
+                    self.position += 1;
+                    self.literal(c)
+                }
+            };
+            nodes.push(node);
+        }
+        Ok(Node::Concat(nodes))
+    }
+
+    /// Parses `{a,b,...}`.
+    fn parse_alternates(&mut self, depth: usize) -> Result<Node, GlobError> {
+        let start = self.position;
+        self.position += 1;
+        let mut alternatives = Vec::new();
+        loop {
+            alternatives.push(self.parse_sequence(depth + 1)?);
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some('}') => {
+                    self.position += 1;
+                    return Ok(Node::Alternation(alternatives));
+                }
+                _ => return Err(GlobError::UnclosedAlternates(start)),
+            }
+        }
+    }
+
+    /// Parses `[...]`, `[!...]` or `[^...]`.
+    fn parse_class(&mut self) -> Result<Node, GlobError> {
+        let start = self.position;
+        self.position += 1;
+        let negated = matches!(self.peek(), Some('!' | '^'));
+        if negated {
+            self.position += 1;
+        }
+        let mut ranges = Vec::new();
+        let mut first = true;
+        loop {
+            let c = self.peek().ok_or(GlobError::UnclosedClass(start))?;
+            if c == ']' && !first {
+                self.position += 1;
+                break;
+            }
+            first = false;
+            let range_start = self.position;
+            self.position += 1;
+            match (self.peek(), self.pattern.get(self.position + 1)) {
+                (Some('-'), Some(&end)) if end != ']' => {
+                    if end < c {
+                        return Err(GlobError::InvalidRange(range_start));
+                    }
+                    self.position += 2;
+                    ranges.push((c, end));
+                }
+                _ => ranges.push((c, c)),
+            }
+        }
+        let mut class = CharClass::new(ranges);
+        if self.options.case_insensitive {
+            class = class.case_fold();
+        }
+        if negated {
+            class = class.negate();
+            if self.options.literal_separator {
+                class = class.difference(&CharClass::single('/'));
+            }
+        }
+        Ok(self.class(class))
+    }
+
+    /// Parses a run of `*`. `**` is a whole path component, matching any number of
+    /// directories, if it is surrounded by `/` or the bounds of the pattern or an alternative.
+    fn parse_stars(&mut self, depth: usize) -> Node {
+        let start = self.position;
+        while self.peek() == Some('*') {
+            self.position += 1;
+        }
+        let is_bound = |c: Option<char>| match c {
+            None | Some('/') => true,
+            Some('{' | ',' | '}') => depth > 0,
+            _ => false,
+        };
+        let before = start.checked_sub(1).map(|index| self.pattern[index]);
+        let any = Node::Repetition {
+            node: Box::new(self.class(CharClass::all())),
+            min: 0,
+            max: None,
+            greedy: true,
+        };
+        if self.position - start < 2 || !is_bound(before) || !is_bound(self.peek()) {
+            return Node::Repetition {
+                node: Box::new(self.any_char()),
+                min: 0,
+                max: None,
+                greedy: true,
+            };
+        }
+        if self.peek() == Some('/') {
+            // `**/` matches nothing, or any path that ends with `/`.
+            self.position += 1;
+            Node::Repetition {
+                node: Box::new(Node::Concat(vec![any, Node::Char('/')])),
+                min: 0,
+                max: Some(1),
+                greedy: true,
+            }
+        } else {
+            any
+        }
+    }
+
+    /// The node matched by `?`.
+    fn any_char(&mut self) -> Node {
+        let class = if self.options.literal_separator {
+            CharClass::single('/').negate()
+        } else {
+            CharClass::all()
+        };
+        self.class(class)
+    }
+
+    fn literal(&mut self, c: char) -> Node {
+        if self.options.case_insensitive {
+            self.class(CharClass::single(c).case_fold())
+        } else {
+            Node::Char(c)
+        }
+    }
+
+    /// Returns the node of a class, reusing an equal class that was already added.
+    fn class(&mut self, class: CharClass) -> Node {
+        if let Some(c) = class.as_single() {
+            return Node::Char(c);
+        }
+        let index = match self.classes.iter().position(|other| *other == class) {
+            Some(index) => index,
+            None => {
+                self.classes.push(class);
+                self.classes.len() - 1
+            }
+        };
+        Node::Class(index)
+    }
+}
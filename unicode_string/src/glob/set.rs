@@ -0,0 +1,116 @@
+use crate::glob::{Glob, GlobError};
+use crate::regex::compile::{self, Program};
+use crate::regex::parse::Node;
+use crate::regex::pike;
+use crate::unicode_str;
+
+/// A set of [`Glob`]s that are matched at once.
+///
+/// The globs are combined into one program and the set is matched in one pass over the text,
+/// which takes time proportional to the length of the text times the combined size of the
+/// globs, rather than reading the text again for each glob.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::glob::{Glob, GlobSet};
+/// use unicode_string::ustr;
+///
+/// let set = GlobSet::new([
+///     Glob::new("*.rs").unwrap(),
+///     Glob::new("src/**").unwrap(),
+///     Glob::new("*.toml").unwrap(),
+/// ])
+/// .unwrap();
+///
+/// assert_eq!(set.matches(ustr!("src/größe.rs")), [0, 1]);
+/// assert_eq!(set.matches(ustr!("Cargo.toml")), [2]);
+/// assert!(!set.is_match(ustr!("README.md")));
+/// ```
+#[derive(Clone, Debug)]
+pub struct GlobSet {
+    globs: Vec<String>,
+    program: Program,
+}
+
+impl GlobSet {
+    /// Creates a set of globs. The globs are identified by their index in `globs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GlobError::TooLarge`] if the globs are too large to be compiled together.
+    pub fn new(globs: impl IntoIterator<Item = Glob>) -> Result<GlobSet, GlobError> {
+        let mut patterns = Vec::new();
+        let mut nodes = Vec::new();
+        let mut classes = Vec::new();
+        for glob in globs {
+            nodes.push(offset_classes(&glob.node, classes.len()));
+            classes.extend(glob.classes);
+            patterns.push(glob.glob);
+        }
+        let program = compile::compile_set(&nodes, classes).map_err(|_| GlobError::TooLarge)?;
+        Ok(GlobSet {
+            globs: patterns,
+            program,
+        })
+    }
+
+    /// Returns the number of globs in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.globs.len()
+    }
+
+    /// Checks if the set has no globs.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.globs.is_empty()
+    }
+
+    /// Returns the pattern of the glob with the given index.
+    #[inline]
+    pub fn glob(&self, index: usize) -> Option<&str> {
+        self.globs.get(index).map(String::as_str)
+    }
+
+    /// Checks if any glob of the set matches the whole of `s`.
+    pub fn is_match(&self, s: &unicode_str) -> bool {
+        !self.is_empty() && pike::search(&self.program, s.chars(), 0, &mut [], true)
+    }
+
+    /// Returns the indices of the globs that match the whole of `s`, in increasing order.
+    pub fn matches(&self, s: &unicode_str) -> Vec<usize> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        pike::matching_patterns(&self.program, s.chars())
+    }
+}
+
+/// Shifts the indices of the classes of `node` by `offset`.
+fn offset_classes(node: &Node, offset: usize) -> Node {
+    match node {
+        Node::Class(index) => Node::Class(index + offset),
+        Node::Capture(index, node) => Node::Capture(*index, Box::new(offset_classes(node, offset))),
+        Node::Concat(nodes) => {
+            Node::Concat(nodes.iter().map(|n| offset_classes(n, offset)).collect())
+        }
+        Node::Alternation(nodes) => {
+            Node::Alternation(nodes.iter().map(|n| offset_classes(n, offset)).collect())
+        }
+        Node::Repetition {
+            node,
+            min,
+            max,
+            greedy,
+        } => Node::Repetition {
+            node: Box::new(offset_classes(node, offset)),
+            min: *min,
+            max: *max,
+            greedy: *greedy,
+        },
+        Node::Empty | Node::Char(_) | Node::Look(_) => node.clone(),
+    }
+}
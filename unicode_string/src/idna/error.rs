@@ -0,0 +1,57 @@
+use std::error::Error;
+use std::fmt;
+
+/// The reason a domain name is not valid according to UTS #46.
+///
+/// When a domain name has several problems, the first one found is reported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IdnaError {
+    /// The domain name contains a char that is not allowed in domain names.
+    DisallowedChar(char),
+    /// A label starting with `xn--` is not valid Punycode, or a label cannot be encoded.
+    InvalidPunycode,
+    /// A label encoded with Punycode is not in Normalization Form C.
+    NotNormalized,
+    /// A label has hyphens in its third and fourth positions, which are reserved for
+    /// prefixes like `xn--`.
+    HyphenAt3And4,
+    /// A label starts or ends with a hyphen.
+    LeadingOrTrailingHyphen,
+    /// A label encoded with Punycode contains a full stop.
+    FullStopInLabel,
+    /// A label starts with a combining mark.
+    LeadingCombiningMark,
+    /// A zero width joiner or non-joiner is used outside of the contexts that need it.
+    InvalidJoiner,
+    /// A label of a domain name containing right-to-left text does not follow the bidi rule
+    /// of RFC 5893.
+    Bidi,
+    /// A label is empty or longer than 63 chars once converted to ASCII.
+    InvalidLabelLength,
+    /// The domain name is empty or longer than 253 chars once converted to ASCII.
+    InvalidDomainLength,
+}
+
+impl fmt::Display for IdnaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdnaError::DisallowedChar(c) => {
+                write!(f, "U+{:04X} is not allowed in domain names", *c as u32)
+            }
+            IdnaError::InvalidPunycode => f.write_str("invalid Punycode label"),
+            IdnaError::NotNormalized => f.write_str("label is not in Normalization Form C"),
+            IdnaError::HyphenAt3And4 => {
+                f.write_str("label has hyphens in its third and fourth positions")
+            }
+            IdnaError::LeadingOrTrailingHyphen => f.write_str("label starts or ends with a hyphen"),
+            IdnaError::FullStopInLabel => f.write_str("label contains a full stop"),
+            IdnaError::LeadingCombiningMark => f.write_str("label starts with a combining mark"),
+            IdnaError::InvalidJoiner => f.write_str("invalid zero width joiner or non-joiner"),
+            IdnaError::Bidi => f.write_str("label does not follow the bidi rule"),
+            IdnaError::InvalidLabelLength => f.write_str("label is empty or too long"),
+            IdnaError::InvalidDomainLength => f.write_str("domain name is empty or too long"),
+        }
+    }
+}
+
+impl Error for IdnaError {}
@@ -0,0 +1,31 @@
+use crate::tables::idna::IDNA_MAPPING;
+use crate::ucd::range_value;
+
+/// The status of a char in the IDNA mapping table (UTS #46, section 5), with its mapping.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Mapping {
+    /// The char is allowed in domain names.
+    Valid,
+    /// The char is removed.
+    Ignored,
+    /// The char is replaced with the chars of the mapping.
+    Mapped(&'static [char]),
+    /// The char is valid in nontransitional processing, and replaced with the chars of the
+    /// mapping in transitional processing, as it was in IDNA2003.
+    Deviation(&'static [char]),
+    /// The char is not allowed in domain names.
+    Disallowed,
+    /// The char is disallowed by the STD3 rules, i.e. if only ASCII letters, digits and
+    /// hyphens are allowed, and valid otherwise.
+    DisallowedStd3Valid,
+    /// The char is disallowed by the STD3 rules and mapped otherwise.
+    DisallowedStd3Mapped(&'static [char]),
+}
+
+impl Mapping {
+    /// Returns the mapping of `c`.
+    #[inline]
+    pub(crate) fn of(c: char) -> Mapping {
+        range_value(IDNA_MAPPING, c).unwrap_or(Mapping::Disallowed)
+    }
+}
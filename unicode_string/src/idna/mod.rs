@@ -0,0 +1,323 @@
+//! Internationalized domain names, following
+//! [UTS #46](https://www.unicode.org/reports/tr46/), Unicode IDNA Compatibility Processing.
+//!
+//! [`to_ascii`] converts a domain name to the ASCII form used in DNS lookups, mapping it to
+//! lowercase, normalizing it, validating its labels and encoding the labels that are not ASCII
+//! with [Punycode](crate::punycode). [`to_unicode`] does the opposite, for display.
+//! [`IdnaConfig`] changes the options of the conversions.
+//!
+//! The mapping table is from version 13.0 of UTS #46, so chars added in later versions of
+//! Unicode are not allowed.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use unicode_string::{idna, ustr};
+//!
+//! assert_eq!(idna::to_ascii(ustr!("Bücher.example")).unwrap(), "xn--bcher-kva.example");
+//!
+//! let (domain, result) = idna::to_unicode(ustr!("xn--bcher-kva.example"));
+//! assert_eq!(domain, ustr!("bücher.example"));
+//! assert!(result.is_ok());
+//! ```
+
+mod error;
+mod mapping;
+mod validity;
+
+pub use self::error::*;
+pub(crate) use self::mapping::Mapping;
+
+use self::validity::{check_bidi, is_bidi_domain, validate_label};
+use crate::normalization::nfc;
+use crate::{punycode, unicode_str, UnicodeString};
+
+/// The prefix of labels encoded with Punycode.
+const ACE_PREFIX: &str = "xn--";
+
+/// The options of the UTS #46 conversions.
+///
+/// The defaults are the strictest options, which match IDNA2008 as closely as possible:
+/// nontransitional processing, the STD3 rules, and all the checks. Web browsers follow the
+/// [URL Standard](https://url.spec.whatwg.org/#idna) instead, which does not use the STD3
+/// rules nor check hyphens and lengths.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::idna::{IdnaConfig, IdnaError};
+/// use unicode_string::ustr;
+///
+/// let strict = IdnaConfig::new();
+/// assert_eq!(strict.to_ascii(ustr!("a_b.example")), Err(IdnaError::DisallowedChar('_')));
+///
+/// let lenient = IdnaConfig::new().use_std3_ascii_rules(false);
+/// assert_eq!(lenient.to_ascii(ustr!("a_b.example")).unwrap(), "a_b.example");
+///
+/// // Transitional processing maps the deviation chars like IDNA2003 did.
+/// let transitional = IdnaConfig::new().transitional_processing(true);
+/// assert_eq!(transitional.to_ascii(ustr!("faß.de")).unwrap(), "fass.de");
+/// assert_eq!(strict.to_ascii(ustr!("faß.de")).unwrap(), "xn--fa-hia.de");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IdnaConfig {
+    transitional: bool,
+    use_std3_ascii_rules: bool,
+    check_hyphens: bool,
+    check_bidi: bool,
+    check_joiners: bool,
+    verify_dns_length: bool,
+}
+
+impl Default for IdnaConfig {
+    fn default() -> IdnaConfig {
+        IdnaConfig::new()
+    }
+}
+
+impl IdnaConfig {
+    /// Creates the default options.
+    pub const fn new() -> IdnaConfig {
+        IdnaConfig {
+            transitional: false,
+            use_std3_ascii_rules: true,
+            check_hyphens: true,
+            check_bidi: true,
+            check_joiners: true,
+            verify_dns_length: true,
+        }
+    }
+
+    /// Sets whether the deviation chars (`ß`, `ς` and the zero width joiner and non-joiner)
+    /// are mapped like in IDNA2003 (`Transitional_Processing`). Defaults to `false`.
+    #[must_use]
+    pub const fn transitional_processing(mut self, transitional: bool) -> IdnaConfig {
+        self.transitional = transitional;
+        self
+    }
+
+    /// Sets whether only ASCII letters, digits and hyphens are allowed among the ASCII chars
+    /// (`UseSTD3ASCIIRules`). Defaults to `true`.
+    #[must_use]
+    pub const fn use_std3_ascii_rules(mut self, use_std3_ascii_rules: bool) -> IdnaConfig {
+        self.use_std3_ascii_rules = use_std3_ascii_rules;
+        self
+    }
+
+    /// Sets whether labels are checked for hyphens at their start, their end, and in their
+    /// third and fourth positions (`CheckHyphens`). Defaults to `true`.
+    #[must_use]
+    pub const fn check_hyphens(mut self, check_hyphens: bool) -> IdnaConfig {
+        self.check_hyphens = check_hyphens;
+        self
+    }
+
+    /// Sets whether the labels of domain names with right-to-left text are checked against
+    /// the bidi rule of RFC 5893 (`CheckBidi`). Defaults to `true`.
+    #[must_use]
+    pub const fn check_bidi(mut self, check_bidi: bool) -> IdnaConfig {
+        self.check_bidi = check_bidi;
+        self
+    }
+
+    /// Sets whether the contexts of the zero width joiner and non-joiner are checked
+    /// (`CheckJoiners`). Defaults to `true`.
+    #[must_use]
+    pub const fn check_joiners(mut self, check_joiners: bool) -> IdnaConfig {
+        self.check_joiners = check_joiners;
+        self
+    }
+
+    /// Sets whether [`to_ascii`](IdnaConfig::to_ascii) checks the lengths of the domain name
+    /// and its labels (`VerifyDnsLength`). Defaults to `true`.
+    #[must_use]
+    pub const fn verify_dns_length(mut self, verify_dns_length: bool) -> IdnaConfig {
+        self.verify_dns_length = verify_dns_length;
+        self
+    }
+
+    /// Converts a domain name to ASCII (UTS #46, section 4.2).
+    ///
+    /// # Errors
+    ///
+    /// Returns the first problem found if the domain name is not valid. See [`IdnaError`].
+    pub fn to_ascii(&self, domain: &unicode_str) -> Result<String, IdnaError> {
+        let (labels, mut error) = self.process(domain);
+        let mut output = String::with_capacity(domain.len());
+        for (index, label) in labels.iter().enumerate() {
+            if index > 0 {
+                output.push('.');
+            }
+            if label.iter().all(char::is_ascii) {
+                output.extend(label.iter());
+            } else {
+                match punycode::encode(unicode_str::from_chars(label)) {
+                    Ok(encoded) => {
+                        output.push_str(ACE_PREFIX);
+                        output.push_str(&encoded);
+                    }
+                    Err(_) => {
+                        error.get_or_insert(IdnaError::InvalidPunycode);
+                    }
+                }
+            }
+        }
+        if self.verify_dns_length {
+            // The empty root label at the end of a fully qualified name is not counted.
+            let name = output.strip_suffix('.').unwrap_or(&output);
+            if name
+                .split('.')
+                .any(|label| label.is_empty() || label.len() > 63)
+            {
+                error.get_or_insert(IdnaError::InvalidLabelLength);
+            }
+            if name.is_empty() || name.len() > 253 {
+                error.get_or_insert(IdnaError::InvalidDomainLength);
+            }
+        }
+        match error {
+            Some(error) => Err(error),
+            None => Ok(output),
+        }
+    }
+
+    /// Converts a domain name to Unicode (UTS #46, section 4.3).
+    ///
+    /// The domain name is always converted, even if it is not valid, so it can be displayed
+    /// along with an error.
+    pub fn to_unicode(&self, domain: &unicode_str) -> (UnicodeString, Result<(), IdnaError>) {
+        let (labels, error) = self.process(domain);
+        let mut vec = Vec::with_capacity(domain.len());
+        for (index, label) in labels.iter().enumerate() {
+            if index > 0 {
+                vec.push('.');
+            }
+            vec.extend_from_slice(label);
+        }
+        let result = match error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        };
+        (UnicodeString { vec }, result)
+    }
+
+    /// Maps, normalizes, splits and validates a domain name (UTS #46, section 4), returning
+    /// its labels and the first error found.
+    fn process(&self, domain: &unicode_str) -> (Vec<Vec<char>>, Option<IdnaError>) {
+        let mut error = None;
+        let mut mapped = Vec::with_capacity(domain.len());
+        for &c in domain.chars() {
+            match Mapping::of(c) {
+                Mapping::Valid => mapped.push(c),
+                Mapping::Ignored => {}
+                Mapping::Mapped(mapping) => mapped.extend_from_slice(mapping),
+                Mapping::Deviation(mapping) if self.transitional => {
+                    mapped.extend_from_slice(mapping)
+                }
+                Mapping::Deviation(_) => mapped.push(c),
+                Mapping::DisallowedStd3Valid if !self.use_std3_ascii_rules => mapped.push(c),
+                Mapping::DisallowedStd3Mapped(mapping) if !self.use_std3_ascii_rules => {
+                    mapped.extend_from_slice(mapping)
+                }
+                Mapping::Disallowed
+                | Mapping::DisallowedStd3Valid
+                | Mapping::DisallowedStd3Mapped(_) => {
+                    error.get_or_insert(IdnaError::DisallowedChar(c));
+                    mapped.push(c);
+                }
+            }
+        }
+        let normalized = nfc(&mapped);
+
+        let mut labels = Vec::new();
+        for label in normalized.split(|&c| c == '.') {
+            let encoded: Option<String> = label
+                .starts_with(&['x', 'n', '-', '-'])
+                .then(|| label[ACE_PREFIX.len()..].iter().collect());
+            let result = match encoded {
+                Some(encoded) => match punycode::decode(&encoded) {
+                    Ok(decoded) => {
+                        let result = validate_label(&decoded.vec, self, false, true);
+                        labels.push(decoded.vec);
+                        result
+                    }
+                    Err(_) => {
+                        labels.push(label.to_vec());
+                        Err(IdnaError::InvalidPunycode)
+                    }
+                },
+                None => {
+                    labels.push(label.to_vec());
+                    validate_label(label, self, self.transitional, false)
+                }
+            };
+            if let Err(label_error) = result {
+                error.get_or_insert(label_error);
+            }
+        }
+
+        if self.check_bidi {
+            let slices: Vec<&[char]> = labels.iter().map(Vec::as_slice).collect();
+            if is_bidi_domain(&slices) && !slices.iter().all(|label| check_bidi(label)) {
+                error.get_or_insert(IdnaError::Bidi);
+            }
+        }
+        (labels, error)
+    }
+}
+
+/// Converts a domain name to ASCII with the default [options](IdnaConfig).
+///
+/// # Errors
+///
+/// Returns the first problem found if the domain name is not valid. See [`IdnaError`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::idna::{self, IdnaError};
+/// use unicode_string::ustr;
+///
+/// assert_eq!(idna::to_ascii(ustr!("Example.COM")).unwrap(), "example.com");
+/// assert_eq!(idna::to_ascii(ustr!("☕.example")).unwrap(), "xn--53h.example");
+/// assert_eq!(idna::to_ascii(ustr!("日本語。ＪＰ")).unwrap(), "xn--wgv71a119e.jp");
+///
+/// assert_eq!(idna::to_ascii(ustr!("-example.com")), Err(IdnaError::LeadingOrTrailingHyphen));
+/// assert_eq!(idna::to_ascii(ustr!("a..b")), Err(IdnaError::InvalidLabelLength));
+/// ```
+#[inline]
+pub fn to_ascii(domain: &unicode_str) -> Result<String, IdnaError> {
+    IdnaConfig::new().to_ascii(domain)
+}
+
+/// Converts a domain name to Unicode with the default [options](IdnaConfig).
+///
+/// The domain name is always converted, even if it is not valid, so it can be displayed
+/// along with an error.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::idna::{self, IdnaError};
+/// use unicode_string::ustr;
+///
+/// let (domain, result) = idna::to_unicode(ustr!("xn--53h.EXAMPLE"));
+/// assert_eq!(domain, ustr!("☕.example"));
+/// assert_eq!(result, Ok(()));
+///
+/// let (domain, result) = idna::to_unicode(ustr!("xn--zz.example"));
+/// assert_eq!(domain, ustr!("xn--zz.example"));
+/// assert_eq!(result, Err(IdnaError::InvalidPunycode));
+/// ```
+#[inline]
+pub fn to_unicode(domain: &unicode_str) -> (UnicodeString, Result<(), IdnaError>) {
+    IdnaConfig::new().to_unicode(domain)
+}
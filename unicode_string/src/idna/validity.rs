@@ -0,0 +1,160 @@
+use crate::idna::{IdnaConfig, IdnaError, Mapping};
+use crate::normalization::{canonical_combining_class, nfc};
+use crate::ucd::{self, JoiningType};
+use crate::BidiClass;
+
+/// The canonical combining class of viramas, after which joiners are always allowed.
+const VIRAMA: u8 = 9;
+
+/// Checks a label against the validity criteria of UTS #46 (section 4.1), except the bidi
+/// rule, which depends on the other labels.
+///
+/// `decoded` is set for labels decoded from Punycode, which were not mapped and normalized.
+pub(crate) fn validate_label(
+    label: &[char],
+    config: &IdnaConfig,
+    transitional: bool,
+    decoded: bool,
+) -> Result<(), IdnaError> {
+    if decoded && nfc(label) != label {
+        return Err(IdnaError::NotNormalized);
+    }
+    if config.check_hyphens {
+        if label.get(2) == Some(&'-') && label.get(3) == Some(&'-') {
+            return Err(IdnaError::HyphenAt3And4);
+        }
+        if label.first() == Some(&'-') || label.last() == Some(&'-') {
+            return Err(IdnaError::LeadingOrTrailingHyphen);
+        }
+    }
+    if label.contains(&'.') {
+        return Err(IdnaError::FullStopInLabel);
+    }
+    if let Some(&first) = label.first() {
+        if ucd::general_category(first).is_mark() {
+            return Err(IdnaError::LeadingCombiningMark);
+        }
+    }
+    for &c in label {
+        let valid = match Mapping::of(c) {
+            Mapping::Valid => true,
+            Mapping::Deviation(_) => !transitional,
+            Mapping::DisallowedStd3Valid => !config.use_std3_ascii_rules,
+            _ => false,
+        };
+        if !valid {
+            return Err(IdnaError::DisallowedChar(c));
+        }
+    }
+    if config.check_joiners && !check_joiners(label) {
+        return Err(IdnaError::InvalidJoiner);
+    }
+    Ok(())
+}
+
+/// Checks the contexts of the zero width joiners and non-joiners of a label (the CONTEXTJ
+/// rules of RFC 5892, appendix A).
+fn check_joiners(label: &[char]) -> bool {
+    label.iter().enumerate().all(|(index, &c)| {
+        if c != '\u{200C}' && c != '\u{200D}' {
+            return true;
+        }
+        if index > 0 && canonical_combining_class(label[index - 1]) == VIRAMA {
+            return true;
+        }
+        if c == '\u{200D}' {
+            return false;
+        }
+        // A non-joiner is also allowed between two chars that would otherwise join.
+        let joining_type = |c: &char| ucd::joining_type(*c);
+        let before = label[..index]
+            .iter()
+            .map(joining_type)
+            .rfind(|&joining| joining != JoiningType::Transparent);
+        let after = label[index + 1..]
+            .iter()
+            .map(joining_type)
+            .find(|&joining| joining != JoiningType::Transparent);
+        matches!(
+            before,
+            Some(JoiningType::LeftJoining | JoiningType::DualJoining)
+        ) && matches!(
+            after,
+            Some(JoiningType::RightJoining | JoiningType::DualJoining)
+        )
+    })
+}
+
+/// Checks if a domain name is a bidi domain name, i.e. if it contains right-to-left chars or
+/// Arabic digits.
+pub(crate) fn is_bidi_domain(labels: &[&[char]]) -> bool {
+    labels.iter().any(|label| {
+        label.iter().any(|&c| {
+            matches!(
+                BidiClass::of(c),
+                BidiClass::R | BidiClass::AL | BidiClass::AN
+            )
+        })
+    })
+}
+
+/// Checks a non-empty label of a bidi domain name against the bidi rule of RFC 5893
+/// (section 2).
+pub(crate) fn check_bidi(label: &[char]) -> bool {
+    let mut classes = label.iter().map(|&c| BidiClass::of(c));
+    let Some(first) = classes.next() else {
+        return true;
+    };
+    let last = label
+        .iter()
+        .map(|&c| BidiClass::of(c))
+        .rfind(|&class| class != BidiClass::NSM);
+    match first {
+        BidiClass::R | BidiClass::AL => {
+            let (mut european, mut arabic) = (false, false);
+            let allowed = label.iter().all(|&c| match BidiClass::of(c) {
+                BidiClass::EN => {
+                    european = true;
+                    true
+                }
+                BidiClass::AN => {
+                    arabic = true;
+                    true
+                }
+                class => matches!(
+                    class,
+                    BidiClass::R
+                        | BidiClass::AL
+                        | BidiClass::ES
+                        | BidiClass::CS
+                        | BidiClass::ET
+                        | BidiClass::ON
+                        | BidiClass::BN
+                        | BidiClass::NSM
+                ),
+            });
+            allowed
+                && !(european && arabic)
+                && matches!(
+                    last,
+                    Some(BidiClass::R | BidiClass::AL | BidiClass::EN | BidiClass::AN)
+                )
+        }
+        BidiClass::L => {
+            classes.all(|class| {
+                matches!(
+                    class,
+                    BidiClass::L
+                        | BidiClass::EN
+                        | BidiClass::ES
+                        | BidiClass::CS
+                        | BidiClass::ET
+                        | BidiClass::ON
+                        | BidiClass::BN
+                        | BidiClass::NSM
+                )
+            }) && matches!(last, Some(BidiClass::L | BidiClass::EN))
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,226 @@
+use std::str;
+
+use crate::io::{ErrorMode, Utf8DecodeError};
+use crate::UnicodeString;
+
+/// A decoder of UTF-8 given in chunks, which can split sequences anywhere.
+///
+/// The chars of each chunk are appended by [`decode`], except for an incomplete sequence at
+/// its end, which is kept until it is completed by the next chunk. [`finish`] ends the
+/// stream, which is an error if a sequence is still incomplete, and resets the decoder for a
+/// new stream.
+///
+/// In [`ErrorMode::Strict`], the default, decoding a chunk stops at the first invalid
+/// sequence: the chars before it are appended, and the rest of the chunk is skipped. In
+/// [`ErrorMode::Lossy`], invalid sequences are replaced and decoding never fails.
+///
+/// [`decode`]: Utf8Decoder::decode
+/// [`finish`]: Utf8Decoder::finish
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::io::Utf8Decoder;
+/// use unicode_string::{ustr, UnicodeString};
+///
+/// let mut decoder = Utf8Decoder::new();
+/// let mut s = UnicodeString::new();
+///
+/// // "ö" is encoded as C3 B6, and "€" as E2 82 AC.
+/// decoder.decode(b"K\xc3", &mut s).unwrap();
+/// assert_eq!(s, ustr!("K"));
+/// decoder.decode(b"\xb6ln \xe2\x82", &mut s).unwrap();
+/// decoder.decode(b"\xac", &mut s).unwrap();
+/// decoder.finish(&mut s).unwrap();
+/// assert_eq!(s, ustr!("Köln €"));
+///
+/// decoder.decode(b"\xe2\x82", &mut s).unwrap();
+/// let error = decoder.finish(&mut s).unwrap_err();
+/// assert_eq!((error.position(), error.error_len()), (0, None));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Utf8Decoder {
+    mode: ErrorMode,
+    strip_bom: bool,
+    /// The start of the incomplete sequence at the end of the last chunk.
+    pending: [u8; 3],
+    pending_len: usize,
+    /// The number of bytes given so far.
+    position: u64,
+    /// Whether a char or an error was decoded, after which a BOM is an ordinary char.
+    started: bool,
+}
+
+impl Utf8Decoder {
+    /// Creates a decoder in [`ErrorMode::Strict`] that keeps a byte order mark.
+    pub fn new() -> Utf8Decoder {
+        Utf8Decoder::default()
+    }
+
+    /// Sets what the decoder does with invalid UTF-8. Defaults to [`ErrorMode::Strict`].
+    #[must_use]
+    pub fn error_mode(mut self, mode: ErrorMode) -> Utf8Decoder {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets whether a byte order mark, U+FEFF, is skipped at the start of the stream. Defaults
+    /// to `false`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::io::Utf8Decoder;
+    /// use unicode_string::{ustr, UnicodeString};
+    ///
+    /// let mut decoder = Utf8Decoder::new().strip_bom(true);
+    /// let mut s = UnicodeString::new();
+    ///
+    /// decoder.decode(b"\xef\xbb", &mut s).unwrap();
+    /// decoder.decode(b"\xbfA\xef\xbb\xbf", &mut s).unwrap();
+    /// assert_eq!(s, ustr!("A\u{FEFF}"));
+    /// ```
+    #[must_use]
+    pub fn strip_bom(mut self, yes: bool) -> Utf8Decoder {
+        self.strip_bom = yes;
+        self
+    }
+
+    /// Decodes a chunk of the stream, appending its chars to `s`.
+    ///
+    /// # Errors
+    ///
+    /// In [`ErrorMode::Strict`], returns an error for the first invalid sequence, after
+    /// appending the chars before it. The rest of the chunk is skipped.
+    pub fn decode(&mut self, bytes: &[u8], s: &mut UnicodeString) -> Result<(), Utf8DecodeError> {
+        let start = s.vec.len();
+        let result = self.decode_chunk(bytes, &mut s.vec);
+        self.position += bytes.len() as u64;
+        self.check_bom(&mut s.vec, start, result.is_err());
+        result
+    }
+
+    /// Ends the stream, and resets the decoder so that it can decode a new stream.
+    ///
+    /// # Errors
+    ///
+    /// In [`ErrorMode::Strict`], returns an error if the stream ends in the middle of a
+    /// sequence. In [`ErrorMode::Lossy`], the sequence is replaced.
+    pub fn finish(&mut self, s: &mut UnicodeString) -> Result<(), Utf8DecodeError> {
+        let start = s.vec.len();
+        let result = if self.pending_len > 0 {
+            self.invalid(self.position - self.pending_len as u64, None, &mut s.vec)
+        } else {
+            Ok(())
+        };
+        self.check_bom(&mut s.vec, start, result.is_err());
+        self.pending_len = 0;
+        self.position = 0;
+        self.started = false;
+        result
+    }
+
+    /// Returns whether the last chunk ended in the middle of a sequence.
+    #[inline]
+    pub fn has_pending(&self) -> bool {
+        self.pending_len > 0
+    }
+
+    fn decode_chunk(&mut self, bytes: &[u8], chars: &mut Vec<char>) -> Result<(), Utf8DecodeError> {
+        let mut index = 0;
+        if self.pending_len > 0 {
+            // Completes the pending sequence with the first bytes of the chunk.
+            let pending_len = self.pending_len;
+            let len = usize::min(4, pending_len + bytes.len());
+            let mut buffer = [0; 4];
+            buffer[..pending_len].copy_from_slice(&self.pending[..pending_len]);
+            buffer[pending_len..len].copy_from_slice(&bytes[..len - pending_len]);
+            self.pending_len = 0;
+            let first = match str::from_utf8(&buffer[..len]) {
+                Ok(s) => Ok(s),
+                Err(error) if error.valid_up_to() > 0 => {
+                    Ok(str::from_utf8(&buffer[..error.valid_up_to()]).unwrap())
+                }
+                Err(error) => Err(error.error_len()),
+            };
+            index = match first {
+                Ok(s) => {
+                    let c = s.chars().next().unwrap();
+                    chars.push(c);
+                    c.len_utf8() - pending_len
+                }
+                Err(None) => {
+                    // The chunk is too short to complete the sequence.
+                    self.pending[..len].copy_from_slice(&buffer[..len]);
+                    self.pending_len = len;
+                    return Ok(());
+                }
+                Err(Some(error_len)) => {
+                    let position = self.position - pending_len as u64;
+                    self.invalid(position, Some(error_len), chars)?;
+                    error_len - pending_len
+                }
+            };
+        }
+        while index < bytes.len() {
+            match str::from_utf8(&bytes[index..]) {
+                Ok(s) => {
+                    chars.extend(s.chars());
+                    break;
+                }
+                Err(error) => {
+                    let valid = &bytes[index..index + error.valid_up_to()];
+                    // SAFETY: `from_utf8` just checked that `valid` is valid UTF-8.
+                    chars.extend(unsafe { str::from_utf8_unchecked(valid) }.chars());
+                    index += error.valid_up_to();
+                    match error.error_len() {
+                        Some(error_len) => {
+                            let position = self.position + index as u64;
+                            self.invalid(position, Some(error_len), chars)?;
+                            index += error_len;
+                        }
+                        None => {
+                            let rest = &bytes[index..];
+                            self.pending[..rest.len()].copy_from_slice(rest);
+                            self.pending_len = rest.len();
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Handles an invalid sequence as the error mode says.
+    fn invalid(
+        &self,
+        position: u64,
+        len: Option<usize>,
+        chars: &mut Vec<char>,
+    ) -> Result<(), Utf8DecodeError> {
+        match self.mode {
+            ErrorMode::Strict => Err(Utf8DecodeError::new(position, len)),
+            ErrorMode::Lossy => {
+                chars.push(char::REPLACEMENT_CHARACTER);
+                Ok(())
+            }
+        }
+    }
+
+    /// Removes the byte order mark at the start of the stream, if any, from the chars decoded
+    /// from `start`.
+    fn check_bom(&mut self, chars: &mut Vec<char>, start: usize, had_error: bool) {
+        if self.started || (chars.len() == start && !had_error) {
+            return;
+        }
+        self.started = true;
+        if self.strip_bom && chars.get(start) == Some(&'\u{FEFF}') {
+            chars.remove(start);
+        }
+    }
+}
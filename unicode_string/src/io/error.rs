@@ -0,0 +1,59 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// The error returned by a [`Utf8Decoder`](super::Utf8Decoder) in
+/// [`ErrorMode::Strict`](super::ErrorMode::Strict) for invalid UTF-8.
+///
+/// It is converted to an [`io::Error`] of kind [`InvalidData`](io::ErrorKind::InvalidData) by
+/// a [`UnicodeReader`](super::UnicodeReader).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Utf8DecodeError {
+    position: u64,
+    len: Option<usize>,
+}
+
+impl Utf8DecodeError {
+    #[inline]
+    pub(crate) fn new(position: u64, len: Option<usize>) -> Utf8DecodeError {
+        Utf8DecodeError { position, len }
+    }
+
+    /// Returns the position of the invalid sequence, in bytes from the start of the stream.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the length in bytes of the invalid sequence, or `None` if the stream ended in
+    /// the middle of a sequence, like [`Utf8Error::error_len`](std::str::Utf8Error::error_len).
+    #[inline]
+    pub fn error_len(&self) -> Option<usize> {
+        self.len
+    }
+}
+
+impl fmt::Display for Utf8DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.len {
+            Some(len) => write!(
+                f,
+                "invalid utf-8 sequence of {} bytes at byte {}",
+                len, self.position
+            ),
+            None => write!(
+                f,
+                "incomplete utf-8 byte sequence at byte {}",
+                self.position
+            ),
+        }
+    }
+}
+
+impl Error for Utf8DecodeError {}
+
+impl From<Utf8DecodeError> for io::Error {
+    fn from(error: Utf8DecodeError) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, error)
+    }
+}
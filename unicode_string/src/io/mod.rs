@@ -0,0 +1,73 @@
+//! Reading and writing text as a stream of encoded bytes.
+//!
+//! [`UnicodeString::from_utf8`] needs all the bytes at once. A [`Utf8Decoder`] decodes bytes
+//! given in chunks of any size instead, keeping the start of a sequence that is split between
+//! two chunks until the next one, and a [`UnicodeReader`] uses it to read lines or a whole
+//! text from any [`Read`](std::io::Read) implementation.
+//!
+//! Both can skip a byte order mark at the start of the text, and either stop at the first
+//! invalid sequence or replace invalid sequences with U+FFFD REPLACEMENT CHARACTER, as chosen
+//! by their [`ErrorMode`].
+//!
+//! In the other direction, a [`UnicodeWriter`] encodes strings and chars in UTF-8, UTF-16 or
+//! UTF-32 to any [`Write`](std::io::Write) implementation, and a [`UnicodeStrReader`] is a
+//! `Read` implementation that encodes a `unicode_str` in UTF-8 as it is read. Neither needs
+//! the whole text as a `String`, and neither does [`unicode_str::write_utf8_to`].
+//!
+//! [`UnicodeString::from_utf8`]: crate::UnicodeString::from_utf8
+//! [`unicode_str::write_utf8_to`]: crate::unicode_str::write_utf8_to
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use unicode_string::io::UnicodeReader;
+//! use unicode_string::ustr;
+//!
+//! let text = "\u{FEFF}Grüße\r\naus Köln\n".as_bytes();
+//! let lines = UnicodeReader::new(text).strip_bom(true).lines();
+//!
+//! let lines: Vec<_> = lines.collect::<Result<_, _>>().unwrap();
+//! assert_eq!(lines, [ustr!("Grüße"), ustr!("aus Köln")]);
+//! ```
+
+mod decoder;
+mod error;
+mod reader;
+mod str_reader;
+mod writer;
+
+pub use self::decoder::*;
+pub use self::error::*;
+pub use self::reader::*;
+pub use self::str_reader::*;
+pub use self::writer::*;
+
+/// What a [`Utf8Decoder`] or a [`UnicodeReader`] does with invalid UTF-8.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::io::{ErrorMode, UnicodeReader};
+/// use unicode_string::{ustr, UnicodeString};
+///
+/// let mut s = UnicodeString::new();
+/// let mut reader = UnicodeReader::new(&b"caf\xe9"[..]);
+/// assert!(reader.read_to_ustring(&mut s).is_err());
+///
+/// let mut reader = UnicodeReader::new(&b"caf\xe9"[..]).error_mode(ErrorMode::Lossy);
+/// assert_eq!(reader.read_to_ustring(&mut s).unwrap(), 4);
+/// assert_eq!(s, ustr!("caf\u{FFFD}"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum ErrorMode {
+    /// Decoding stops at the first invalid sequence with an error.
+    #[default]
+    Strict,
+    /// Each maximal invalid sequence is replaced with U+FFFD REPLACEMENT CHARACTER, as
+    /// [`String::from_utf8_lossy`] does.
+    Lossy,
+}
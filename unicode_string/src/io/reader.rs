@@ -0,0 +1,259 @@
+use std::io::{self, Read};
+
+use crate::io::{ErrorMode, Utf8Decoder};
+use crate::UnicodeString;
+
+/// The default capacity of the buffer of a [`UnicodeReader`].
+const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+/// A reader of UTF-8 text from a [`Read`] implementation, which decodes it into
+/// `UnicodeString`s.
+///
+/// The bytes are read into a buffer of its own, like a [`BufReader`](std::io::BufReader), so
+/// the inner reader does not need to be buffered. Invalid UTF-8 is an error of kind
+/// [`InvalidData`](io::ErrorKind::InvalidData) in [`ErrorMode::Strict`], the default, and is
+/// replaced in [`ErrorMode::Lossy`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::io::UnicodeReader;
+/// use unicode_string::{ustr, UnicodeString};
+///
+/// let mut reader = UnicodeReader::new("Ünïcödé\nlines".as_bytes());
+/// let mut line = UnicodeString::new();
+///
+/// assert_eq!(reader.read_line(&mut line).unwrap(), 8);
+/// assert_eq!(line, ustr!("Ünïcödé\n"));
+///
+/// let mut line = UnicodeString::new();
+/// assert_eq!(reader.read_line(&mut line).unwrap(), 5);
+/// assert_eq!(reader.read_line(&mut line).unwrap(), 0);
+/// assert_eq!(line, ustr!("lines"));
+/// ```
+#[derive(Debug)]
+pub struct UnicodeReader<R> {
+    inner: R,
+    buffer: Box<[u8]>,
+    /// The range of `buffer` that was read and not decoded yet.
+    start: usize,
+    end: usize,
+    decoder: Utf8Decoder,
+}
+
+impl<R: Read> UnicodeReader<R> {
+    /// Creates a reader with a buffer of the default capacity, currently 8 KiB.
+    pub fn new(inner: R) -> UnicodeReader<R> {
+        UnicodeReader::with_capacity(DEFAULT_CAPACITY, inner)
+    }
+
+    /// Creates a reader with a buffer of the given capacity in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn with_capacity(capacity: usize, inner: R) -> UnicodeReader<R> {
+        assert!(capacity > 0, "the capacity of a UnicodeReader cannot be 0");
+        UnicodeReader {
+            inner,
+            buffer: vec![0; capacity].into_boxed_slice(),
+            start: 0,
+            end: 0,
+            decoder: Utf8Decoder::new(),
+        }
+    }
+
+    /// Sets what the reader does with invalid UTF-8. Defaults to [`ErrorMode::Strict`].
+    #[must_use]
+    pub fn error_mode(mut self, mode: ErrorMode) -> UnicodeReader<R> {
+        self.decoder = self.decoder.error_mode(mode);
+        self
+    }
+
+    /// Sets whether a byte order mark, U+FEFF, is skipped at the start of the text. Defaults
+    /// to `false`.
+    #[must_use]
+    pub fn strip_bom(mut self, yes: bool) -> UnicodeReader<R> {
+        self.decoder = self.decoder.strip_bom(yes);
+        self
+    }
+
+    /// Reads chars up to and including the next `'\n'`, or to the end of the text, and
+    /// appends them to `line`. Returns the number of chars appended, which is 0 at the end of
+    /// the text.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of the inner reader, other than
+    /// [`Interrupted`](io::ErrorKind::Interrupted), and in [`ErrorMode::Strict`], an error of
+    /// kind [`InvalidData`](io::ErrorKind::InvalidData) for invalid UTF-8. `line` is left
+    /// unchanged on error, but the bytes read are consumed.
+    pub fn read_line(&mut self, line: &mut UnicodeString) -> io::Result<usize> {
+        self.append(line, |reader, line| {
+            loop {
+                if reader.start == reader.end && reader.fill_buffer()? == 0 {
+                    reader.decoder.finish(line)?;
+                    return Ok(());
+                }
+                let bytes = &reader.buffer[reader.start..reader.end];
+                // A newline byte is never part of a longer sequence.
+                let len = match bytes.iter().position(|&byte| byte == b'\n') {
+                    Some(index) => index + 1,
+                    None => bytes.len(),
+                };
+                reader.start += len;
+                reader.decoder.decode(&bytes[..len], line)?;
+                if bytes[len - 1] == b'\n' {
+                    return Ok(());
+                }
+            }
+        })
+    }
+
+    /// Reads all the chars until the end of the text, and appends them to `s`. Returns the
+    /// number of chars appended.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read_line`](UnicodeReader::read_line), and likewise leaves
+    /// `s` unchanged on error.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::io::ErrorKind;
+    ///
+    /// use unicode_string::io::UnicodeReader;
+    /// use unicode_string::{ustr, UnicodeString};
+    ///
+    /// let mut s = UnicodeString::new();
+    /// let mut reader = UnicodeReader::new("¡Olé!".as_bytes());
+    /// assert_eq!(reader.read_to_ustring(&mut s).unwrap(), 5);
+    /// assert_eq!(s, ustr!("¡Olé!"));
+    ///
+    /// let mut reader = UnicodeReader::new(&b"Ol\xc3"[..]);
+    /// let error = reader.read_to_ustring(&mut s).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::InvalidData);
+    /// assert_eq!(error.to_string(), "incomplete utf-8 byte sequence at byte 2");
+    /// ```
+    pub fn read_to_ustring(&mut self, s: &mut UnicodeString) -> io::Result<usize> {
+        self.append(s, |reader, s| loop {
+            if reader.start == reader.end && reader.fill_buffer()? == 0 {
+                reader.decoder.finish(s)?;
+                return Ok(());
+            }
+            let bytes = &reader.buffer[reader.start..reader.end];
+            reader.start = reader.end;
+            reader.decoder.decode(bytes, s)?;
+        })
+    }
+
+    /// Returns an iterator over the lines of the text, without their `"\n"` or `"\r\n"`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::io::{ErrorMode, UnicodeReader};
+    ///
+    /// let text = &b"one\r\ntw\xf6\n\nthree"[..];
+    /// let reader = UnicodeReader::new(text).error_mode(ErrorMode::Lossy);
+    ///
+    /// let lines: Vec<String> = reader
+    ///     .lines()
+    ///     .map(|line| line.unwrap().chars().iter().collect())
+    ///     .collect();
+    /// assert_eq!(lines, ["one", "tw\u{FFFD}", "", "three"]);
+    /// ```
+    pub fn lines(self) -> Lines<R> {
+        Lines { reader: self }
+    }
+
+    /// Returns a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the bytes that are already in the buffer.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader. The bytes in the buffer are lost.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Runs `read`, which appends chars to `s`, and returns the number of chars it appended,
+    /// or removes them on error.
+    fn append(
+        &mut self,
+        s: &mut UnicodeString,
+        read: impl FnOnce(&mut Self, &mut UnicodeString) -> io::Result<()>,
+    ) -> io::Result<usize> {
+        let len = s.vec.len();
+        match read(self, s) {
+            Ok(()) => Ok(s.vec.len() - len),
+            Err(error) => {
+                s.vec.truncate(len);
+                Err(error)
+            }
+        }
+    }
+
+    /// Reads bytes from the inner reader into the empty buffer, and returns their number,
+    /// which is 0 at the end of the text.
+    fn fill_buffer(&mut self) -> io::Result<usize> {
+        loop {
+            match self.inner.read(&mut self.buffer) {
+                Ok(len) => {
+                    self.start = 0;
+                    self.end = len;
+                    return Ok(len);
+                }
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+/// An iterator over the lines of the text of a [`UnicodeReader`], created by
+/// [`UnicodeReader::lines`].
+///
+/// Each line is an `io::Result<UnicodeString>`, without its `"\n"` or `"\r\n"`.
+#[derive(Debug)]
+pub struct Lines<R> {
+    reader: UnicodeReader<R>,
+}
+
+impl<R: Read> Iterator for Lines<R> {
+    type Item = io::Result<UnicodeString>;
+
+    fn next(&mut self) -> Option<io::Result<UnicodeString>> {
+        let mut line = UnicodeString::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) => None,
+            Ok(_) => {
+                if line.vec.last() == Some(&'\n') {
+                    line.vec.pop();
+                    if line.vec.last() == Some(&'\r') {
+                        line.vec.pop();
+                    }
+                }
+                Some(Ok(line))
+            }
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
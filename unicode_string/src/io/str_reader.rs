@@ -0,0 +1,105 @@
+use std::io::{self, Read};
+
+use crate::unicode_str;
+
+/// A reader of the UTF-8 encoding of a `unicode_str`, which encodes the chars as they are
+/// read, without allocating.
+///
+/// It can be given to anything that reads bytes, like [`io::copy`], hashers or compressors,
+/// instead of encoding the whole string to a `String` first. Reads can end in the middle of
+/// the encoding of a char, whose remaining bytes start the next read.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::io::Read;
+///
+/// use unicode_string::io::UnicodeStrReader;
+/// use unicode_string::ustr;
+///
+/// let mut reader = UnicodeStrReader::new(ustr!("añb"));
+/// let mut buffer = [0; 2];
+///
+/// assert_eq!(reader.read(&mut buffer).unwrap(), 2);
+/// assert_eq!(buffer, [b'a', 0xC3]);
+/// assert_eq!(reader.read(&mut buffer).unwrap(), 2);
+/// assert_eq!(buffer, [0xB1, b'b']);
+/// assert_eq!(reader.read(&mut buffer).unwrap(), 0);
+///
+/// let mut bytes = Vec::new();
+/// std::io::copy(&mut UnicodeStrReader::new(ustr!("añb")), &mut bytes).unwrap();
+/// assert_eq!(bytes, "añb".as_bytes());
+/// ```
+#[derive(Clone, Debug)]
+pub struct UnicodeStrReader<'a> {
+    /// The chars that were not read yet.
+    chars: &'a [char],
+    /// The encoding of the char that was partially read.
+    partial: [u8; 4],
+    /// The range of `partial` that was not read yet.
+    partial_start: usize,
+    partial_end: usize,
+}
+
+impl<'a> UnicodeStrReader<'a> {
+    /// Creates a reader of the UTF-8 encoding of `s`.
+    pub fn new(s: &'a unicode_str) -> UnicodeStrReader<'a> {
+        UnicodeStrReader {
+            chars: s.chars(),
+            partial: [0; 4],
+            partial_start: 0,
+            partial_end: 0,
+        }
+    }
+
+    /// Returns the number of bytes that are left to read.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::io::Read;
+    ///
+    /// use unicode_string::io::UnicodeStrReader;
+    /// use unicode_string::ustr;
+    ///
+    /// let mut reader = UnicodeStrReader::new(ustr!("añb"));
+    /// assert_eq!(reader.remaining(), 4);
+    /// reader.read(&mut [0; 2]).unwrap();
+    /// assert_eq!(reader.remaining(), 2);
+    /// ```
+    pub fn remaining(&self) -> usize {
+        let chars: usize = self.chars.iter().map(|c| c.len_utf8()).sum();
+        self.partial_end - self.partial_start + chars
+    }
+}
+
+impl Read for UnicodeStrReader<'_> {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        let partial = &self.partial[self.partial_start..self.partial_end];
+        let mut len = usize::min(partial.len(), buffer.len());
+        buffer[..len].copy_from_slice(&partial[..len]);
+        self.partial_start += len;
+        while let Some((&c, rest)) = self.chars.split_first() {
+            if len == buffer.len() {
+                break;
+            }
+            self.chars = rest;
+            if c.len_utf8() <= buffer.len() - len {
+                len += c.encode_utf8(&mut buffer[len..]).len();
+            } else {
+                // The rest of the encoding of `c` is read next time.
+                let encoded = c.encode_utf8(&mut self.partial);
+                let read = buffer.len() - len;
+                buffer[len..].copy_from_slice(&encoded.as_bytes()[..read]);
+                self.partial_start = read;
+                self.partial_end = c.len_utf8();
+                len += read;
+            }
+        }
+        Ok(len)
+    }
+}
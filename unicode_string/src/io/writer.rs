@@ -0,0 +1,212 @@
+use std::io::{self, Write};
+
+use crate::unicode_str;
+
+/// The default capacity of the buffer of a [`UnicodeWriter`].
+const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+/// The encoding forms of Unicode that a [`UnicodeWriter`] can write.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum UtfEncoding {
+    /// UTF-8.
+    #[default]
+    Utf8,
+    /// UTF-16, little-endian.
+    Utf16Le,
+    /// UTF-16, big-endian.
+    Utf16Be,
+    /// UTF-32, little-endian.
+    Utf32Le,
+    /// UTF-32, big-endian.
+    Utf32Be,
+}
+
+impl UtfEncoding {
+    /// Appends the encoding of `c` to `bytes`.
+    fn encode(self, c: char, bytes: &mut Vec<u8>) {
+        match self {
+            UtfEncoding::Utf8 => bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            UtfEncoding::Utf16Le => {
+                for unit in c.encode_utf16(&mut [0; 2]) {
+                    bytes.extend_from_slice(&unit.to_le_bytes());
+                }
+            }
+            UtfEncoding::Utf16Be => {
+                for unit in c.encode_utf16(&mut [0; 2]) {
+                    bytes.extend_from_slice(&unit.to_be_bytes());
+                }
+            }
+            UtfEncoding::Utf32Le => bytes.extend_from_slice(&u32::from(c).to_le_bytes()),
+            UtfEncoding::Utf32Be => bytes.extend_from_slice(&u32::from(c).to_be_bytes()),
+        }
+    }
+}
+
+/// A writer of `unicode_str`s and chars to an [`io::Write`] implementation, which encodes
+/// them in UTF-8, UTF-16 or UTF-32.
+///
+/// The bytes are written through a buffer of its own, like a
+/// [`BufWriter`](std::io::BufWriter), which is flushed when it is full, by
+/// [`flush`](UnicodeWriter::flush) and [`into_inner`](UnicodeWriter::into_inner), and when the
+/// writer is dropped, ignoring errors. A byte order mark can be written before the first
+/// char.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::io::{UnicodeWriter, UtfEncoding};
+/// use unicode_string::ustr;
+///
+/// let mut writer = UnicodeWriter::new(Vec::new())
+///     .encoding(UtfEncoding::Utf16Le)
+///     .bom(true);
+/// writer.write_str(ustr!("€")).unwrap();
+/// writer.write_char('😀').unwrap();
+///
+/// let bytes = writer.into_inner().unwrap();
+/// assert_eq!(bytes, b"\xff\xfe\xac\x20\x3d\xd8\x00\xde");
+/// ```
+#[derive(Debug)]
+pub struct UnicodeWriter<W: Write> {
+    /// The inner writer, only taken by [`into_inner`](UnicodeWriter::into_inner).
+    inner: Option<W>,
+    buffer: Vec<u8>,
+    encoding: UtfEncoding,
+    /// Whether a byte order mark is written before the first char, until it is.
+    bom: bool,
+}
+
+impl<W: Write> UnicodeWriter<W> {
+    /// Creates a writer of UTF-8 without a byte order mark, with a buffer of the default
+    /// capacity, currently 8 KiB.
+    pub fn new(inner: W) -> UnicodeWriter<W> {
+        UnicodeWriter::with_capacity(DEFAULT_CAPACITY, inner)
+    }
+
+    /// Creates a writer of UTF-8 without a byte order mark, with a buffer of the given
+    /// capacity in bytes.
+    pub fn with_capacity(capacity: usize, inner: W) -> UnicodeWriter<W> {
+        UnicodeWriter {
+            inner: Some(inner),
+            buffer: Vec::with_capacity(capacity),
+            encoding: UtfEncoding::Utf8,
+            bom: false,
+        }
+    }
+
+    /// Sets the encoding of the chars. Defaults to [`UtfEncoding::Utf8`].
+    #[must_use]
+    pub fn encoding(mut self, encoding: UtfEncoding) -> UnicodeWriter<W> {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Sets whether a byte order mark, U+FEFF, is written before the first char. Defaults to
+    /// `false`. Nothing is written for an empty text.
+    #[must_use]
+    pub fn bom(mut self, yes: bool) -> UnicodeWriter<W> {
+        self.bom = yes;
+        self
+    }
+
+    /// Writes the chars of `s`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of the inner writer when the buffer is full and written.
+    pub fn write_str(&mut self, s: &unicode_str) -> io::Result<()> {
+        s.chars().iter().try_for_each(|&c| self.write_char(c))
+    }
+
+    /// Writes a char.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of the inner writer when the buffer is full and written.
+    pub fn write_char(&mut self, c: char) -> io::Result<()> {
+        // Each char takes at most 4 bytes, and the byte order mark 4 more.
+        if self.buffer.len() + 8 > self.buffer.capacity() {
+            self.flush_buffer()?;
+        }
+        if self.bom {
+            self.bom = false;
+            self.encoding.encode('\u{FEFF}', &mut self.buffer);
+        }
+        self.encoding.encode(c, &mut self.buffer);
+        Ok(())
+    }
+
+    /// Writes the buffer and flushes the inner writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of the inner writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.flush_buffer()?;
+        self.get_mut().flush()
+    }
+
+    /// Returns a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        self.inner
+            .as_ref()
+            .expect("the inner writer is only taken by into_inner")
+    }
+
+    /// Returns a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly writes before the bytes that are still in the buffer.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner
+            .as_mut()
+            .expect("the inner writer is only taken by into_inner")
+    }
+
+    /// Writes the buffer and returns the inner writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of the inner writer, in which case the writer is dropped.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.flush_buffer()?;
+        Ok(self
+            .inner
+            .take()
+            .expect("the inner writer is only taken by into_inner"))
+    }
+
+    /// Writes the bytes of the buffer to the inner writer.
+    fn flush_buffer(&mut self) -> io::Result<()> {
+        let inner = self
+            .inner
+            .as_mut()
+            .expect("the inner writer is only taken by into_inner");
+        let mut written = 0;
+        let result = loop {
+            if written == self.buffer.len() {
+                break Ok(());
+            }
+            match inner.write(&self.buffer[written..]) {
+                Ok(0) => break Err(io::Error::from(io::ErrorKind::WriteZero)),
+                Ok(len) => written += len,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => break Err(error),
+            }
+        };
+        self.buffer.drain(..written);
+        result
+    }
+}
+
+impl<W: Write> Drop for UnicodeWriter<W> {
+    fn drop(&mut self) {
+        // The buffer was already written if the inner writer was taken by `into_inner`.
+        if self.inner.is_some() {
+            let _ = self.flush_buffer();
+        }
+    }
+}
@@ -1,10 +1,38 @@
 #![feature(const_mut_refs)]
 #![feature(const_trait_impl)]
+#![feature(min_specialization)]
 #![feature(slice_index_methods)]
 
+pub mod aho_corasick;
+mod bidi;
+mod case;
+mod collation;
+pub mod diff;
+pub mod encoding;
+pub mod glob;
+pub mod idna;
+pub mod io;
+mod natural;
+mod normalization;
+pub mod punycode;
+pub mod regex;
+mod search;
+pub mod security;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod similarity;
+pub mod suffix_array;
+mod tables;
+mod transliteration;
+pub mod ucd;
 mod unicode_str_impl;
 mod unicode_string_impl;
 
+pub use self::bidi::{BidiClass, BidiInfo, Level, Paragraph, VisualRun};
+pub use self::case::CaseInsensitive;
+pub use self::collation::*;
+pub use self::natural::{Natural, NaturalOrder};
+pub use self::transliteration::{to_ascii_approx, Transliteration, Transliterator};
 pub use self::unicode_str_impl::*;
 pub use self::unicode_string_impl::*;
 pub use unicode_string_macros::*;
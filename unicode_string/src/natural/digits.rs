@@ -0,0 +1,16 @@
+use crate::tables::decimal_digits::DECIMAL_DIGIT_ZEROS;
+
+/// Returns the value of `c` if it is a decimal digit (general category `Nd`) of any script.
+#[inline]
+pub(crate) fn decimal_digit_value(c: char) -> Option<u8> {
+    if c.is_ascii() {
+        return c.to_digit(10).map(|value| value as u8);
+    }
+    let index = match DECIMAL_DIGIT_ZEROS.binary_search(&c) {
+        Ok(_) => return Some(0),
+        Err(0) => return None,
+        Err(index) => index - 1,
+    };
+    let value = c as u32 - DECIMAL_DIGIT_ZEROS[index] as u32;
+    (value < 10).then_some(value as u8)
+}
@@ -0,0 +1,7 @@
+mod digits;
+mod natural_order;
+mod wrapper;
+
+pub(crate) use self::digits::*;
+pub use self::natural_order::*;
+pub use self::wrapper::*;
@@ -0,0 +1,189 @@
+use std::cmp::Ordering;
+
+use crate::case::CaseFold;
+use crate::natural::{decimal_digit_value, Natural};
+use crate::unicode_str;
+
+/// Compares strings in natural order: runs of decimal digits are compared by their numeric
+/// value instead of char by char, so `"file2"` sorts before `"file10"`.
+///
+/// Digits of every script are recognised (all chars of the general category `Nd`), and a run
+/// may mix digits of different scripts. A number sorts before any other char, and text between
+/// numbers is compared by code point, or by its full case folding when [`ignore_case`] is set.
+///
+/// By default numbers with the same value are ordered by their number of leading zeros
+/// (`"2"` before `"02"`), which keeps the order consistent with equality. With
+/// [`ignore_leading_zeros`] set they compare equal instead.
+///
+/// [`ignore_case`]: NaturalOrder::ignore_case
+/// [`ignore_leading_zeros`]: NaturalOrder::ignore_leading_zeros
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::{NaturalOrder, ustr};
+///
+/// let mut files = vec![ustr!("file10.txt"), ustr!("File2.txt"), ustr!("file1.txt")];
+///
+/// files.sort_by(|a, b| NaturalOrder::new().compare(a, b));
+/// assert_eq!(files, [ustr!("File2.txt"), ustr!("file1.txt"), ustr!("file10.txt")]);
+///
+/// files.sort_by(|a, b| NaturalOrder::new().ignore_case(true).compare(a, b));
+/// assert_eq!(files, [ustr!("file1.txt"), ustr!("File2.txt"), ustr!("file10.txt")]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct NaturalOrder {
+    ignore_case: bool,
+    ignore_leading_zeros: bool,
+}
+
+impl NaturalOrder {
+    /// Creates a case-sensitive natural order that takes leading zeros into account.
+    pub const fn new() -> NaturalOrder {
+        NaturalOrder {
+            ignore_case: false,
+            ignore_leading_zeros: false,
+        }
+    }
+
+    /// Sets whether text is compared by its full case folding.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use unicode_string::{NaturalOrder, ustr};
+    ///
+    /// let order = NaturalOrder::new().ignore_case(true);
+    /// assert_eq!(order.compare(ustr!("STRASSE 9"), ustr!("Straße 9")), Ordering::Equal);
+    /// assert_eq!(order.compare(ustr!("Straße 9"), ustr!("strasse 10")), Ordering::Less);
+    /// ```
+    #[must_use]
+    pub const fn ignore_case(mut self, ignore_case: bool) -> NaturalOrder {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// Sets whether numbers that only differ in leading zeros compare equal.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use unicode_string::{NaturalOrder, ustr};
+    ///
+    /// let order = NaturalOrder::new();
+    /// assert_eq!(order.compare(ustr!("v007"), ustr!("v7")), Ordering::Greater);
+    /// assert_eq!(order.compare(ustr!("v007"), ustr!("v8")), Ordering::Less);
+    ///
+    /// let order = NaturalOrder::new().ignore_leading_zeros(true);
+    /// assert_eq!(order.compare(ustr!("v007"), ustr!("v7")), Ordering::Equal);
+    /// ```
+    #[must_use]
+    pub const fn ignore_leading_zeros(mut self, ignore_leading_zeros: bool) -> NaturalOrder {
+        self.ignore_leading_zeros = ignore_leading_zeros;
+        self
+    }
+
+    /// Wraps `value` in a [`Natural`] value that is ordered using this order.
+    #[inline]
+    pub fn wrap<T: AsRef<unicode_str>>(self, value: T) -> Natural<T> {
+        Natural::with_order(value, self)
+    }
+
+    /// Compares two strings in natural order.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use unicode_string::{NaturalOrder, ustr};
+    ///
+    /// let order = NaturalOrder::new();
+    /// assert_eq!(order.compare(ustr!("page 9"), ustr!("page 10")), Ordering::Less);
+    /// // Devanagari digits are compared by value too.
+    /// assert_eq!(order.compare(ustr!("पृष्ठ ९"), ustr!("पृष्ठ १०")), Ordering::Less);
+    /// ```
+    #[must_use]
+    pub fn compare(&self, a: &unicode_str, b: &unicode_str) -> Ordering {
+        let (a, b) = (a.chars(), b.chars());
+        let (mut i, mut j) = (0, 0);
+        loop {
+            let (x, y) = match (a.get(i), b.get(j)) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(&x), Some(&y)) => (x, y),
+            };
+            let x_is_digit = decimal_digit_value(x).is_some();
+            let y_is_digit = decimal_digit_value(y).is_some();
+            if x_is_digit != y_is_digit {
+                return if x_is_digit {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                };
+            }
+
+            let a_end = run_end(a, i, x_is_digit);
+            let b_end = run_end(b, j, y_is_digit);
+            let ordering = if x_is_digit {
+                self.compare_numbers(&a[i..a_end], &b[j..b_end])
+            } else {
+                self.compare_text(&a[i..a_end], &b[j..b_end])
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+            (i, j) = (a_end, b_end);
+        }
+    }
+
+    fn compare_numbers(&self, a: &[char], b: &[char]) -> Ordering {
+        let a_significant = strip_leading_zeros(a);
+        let b_significant = strip_leading_zeros(b);
+        let by_value = a_significant.len().cmp(&b_significant.len()).then_with(|| {
+            let a_values = a_significant.iter().map(|&c| decimal_digit_value(c));
+            let b_values = b_significant.iter().map(|&c| decimal_digit_value(c));
+            a_values.cmp(b_values)
+        });
+        // Numbers written with digits of different scripts are only equal if the digits are.
+        if self.ignore_leading_zeros {
+            by_value.then_with(|| a_significant.cmp(b_significant))
+        } else {
+            by_value.then(a.len().cmp(&b.len())).then_with(|| a.cmp(b))
+        }
+    }
+
+    fn compare_text(&self, a: &[char], b: &[char]) -> Ordering {
+        if self.ignore_case {
+            CaseFold::new(unicode_str::from_chars(a)).cmp(CaseFold::new(unicode_str::from_chars(b)))
+        } else {
+            a.cmp(b)
+        }
+    }
+}
+
+/// Returns the end of the run of digits or non-digits starting at `start`.
+fn run_end(chars: &[char], start: usize, digits: bool) -> usize {
+    chars[start..]
+        .iter()
+        .position(|&c| decimal_digit_value(c).is_some() != digits)
+        .map_or(chars.len(), |len| start + len)
+}
+
+fn strip_leading_zeros(digits: &[char]) -> &[char] {
+    let zeros = digits
+        .iter()
+        .take_while(|&&c| decimal_digit_value(c) == Some(0))
+        .count();
+    &digits[zeros..]
+}
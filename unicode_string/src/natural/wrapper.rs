@@ -0,0 +1,97 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::natural::NaturalOrder;
+use crate::unicode_str;
+
+/// A wrapper that orders strings in [natural order](NaturalOrder), comparing runs of digits
+/// by their numeric value.
+///
+/// Two values are only meaningfully compared when they use equal [`NaturalOrder`]s.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::collections::BTreeSet;
+/// use unicode_string::{Natural, NaturalOrder, ustr};
+///
+/// let mut versions = vec![ustr!("v1.10"), ustr!("v1.9"), ustr!("v1.0")];
+/// versions.sort_by_key(|version| Natural::new(*version));
+/// assert_eq!(versions, [ustr!("v1.0"), ustr!("v1.9"), ustr!("v1.10")]);
+///
+/// let order = NaturalOrder::new().ignore_case(true).ignore_leading_zeros(true);
+/// let tracks: BTreeSet<_> = [ustr!("Track 02"), ustr!("track 2"), ustr!("Track 1")]
+///     .into_iter()
+///     .map(|track| order.wrap(track))
+///     .collect();
+/// assert_eq!(tracks.len(), 2);
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct Natural<T> {
+    value: T,
+    order: NaturalOrder,
+}
+
+impl<T: AsRef<unicode_str>> Natural<T> {
+    /// Wraps `value` using the default [`NaturalOrder`].
+    #[inline]
+    pub const fn new(value: T) -> Natural<T> {
+        Natural::with_order(value, NaturalOrder::new())
+    }
+
+    /// Wraps `value` using the given order.
+    #[inline]
+    pub const fn with_order(value: T, order: NaturalOrder) -> Natural<T> {
+        Natural { value, order }
+    }
+
+    /// Returns a reference to the wrapped value.
+    #[inline]
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: AsRef<unicode_str>> PartialEq for Natural<T> {
+    #[inline]
+    fn eq(&self, other: &Natural<T>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: AsRef<unicode_str>> Eq for Natural<T> {}
+
+impl<T: AsRef<unicode_str>> PartialOrd for Natural<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Natural<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: AsRef<unicode_str>> Ord for Natural<T> {
+    #[inline]
+    fn cmp(&self, other: &Natural<T>) -> Ordering {
+        self.order
+            .compare(self.value.as_ref(), other.value.as_ref())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Natural<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Natural<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
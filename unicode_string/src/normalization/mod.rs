@@ -0,0 +1,149 @@
+use crate::tables::normalization::{
+    CANONICAL_COMBINING_CLASS, CANONICAL_COMPOSITION, CANONICAL_DECOMPOSITION,
+};
+
+pub(crate) const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+pub(crate) const HANGUL_T_COUNT: u32 = 28;
+pub(crate) const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+pub(crate) const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+/// Returns the canonical combining class of `c`.
+#[inline]
+pub(crate) fn canonical_combining_class(c: char) -> u8 {
+    if c < '\u{300}' {
+        return 0;
+    }
+    match CANONICAL_COMBINING_CLASS.binary_search_by(|&(start, end, _)| {
+        if end < c {
+            std::cmp::Ordering::Less
+        } else if start > c {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(index) => CANONICAL_COMBINING_CLASS[index].2,
+        Err(_) => 0,
+    }
+}
+
+/// Appends the full canonical decomposition of `c` to `out`.
+pub(crate) fn decompose_canonical(c: char, out: &mut Vec<char>) {
+    let code = c as u32;
+    if code < 0xC0 {
+        out.push(c);
+        return;
+    }
+    if (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&code) {
+        let index = code - HANGUL_S_BASE;
+        let trailing = index % HANGUL_T_COUNT;
+        // SAFETY: the jamo computed from a Hangul syllable are always valid chars.
+        unsafe {
+            out.push(char::from_u32_unchecked(HANGUL_L_BASE + index / HANGUL_N_COUNT));
+            out.push(char::from_u32_unchecked(
+                HANGUL_V_BASE + (index % HANGUL_N_COUNT) / HANGUL_T_COUNT,
+            ));
+            if trailing != 0 {
+                out.push(char::from_u32_unchecked(HANGUL_T_BASE + trailing));
+            }
+        }
+        return;
+    }
+    match CANONICAL_DECOMPOSITION.binary_search_by_key(&c, |&(code, _)| code) {
+        Ok(index) => out.extend_from_slice(CANONICAL_DECOMPOSITION[index].1),
+        Err(_) => out.push(c),
+    }
+}
+
+/// Returns the canonical decomposition (NFD) of `chars`.
+pub(crate) fn nfd(chars: &[char]) -> Vec<char> {
+    let mut out = Vec::with_capacity(chars.len());
+    for &c in chars {
+        decompose_canonical(c, &mut out);
+    }
+    canonical_order(&mut out);
+    out
+}
+
+/// Returns the canonical composition (NFC) of `chars`.
+pub(crate) fn nfc(chars: &[char]) -> Vec<char> {
+    let mut out = nfd(chars);
+    compose_canonical(&mut out);
+    out
+}
+
+/// Returns the primary composite of `first` and `second`, if there is one.
+fn compose_pair(first: char, second: char) -> Option<char> {
+    let (first_code, second_code) = (first as u32, second as u32);
+    if (HANGUL_L_BASE..HANGUL_L_BASE + HANGUL_L_COUNT).contains(&first_code)
+        && (HANGUL_V_BASE..HANGUL_V_BASE + HANGUL_V_COUNT).contains(&second_code)
+    {
+        let index = (first_code - HANGUL_L_BASE) * HANGUL_N_COUNT
+            + (second_code - HANGUL_V_BASE) * HANGUL_T_COUNT;
+        return char::from_u32(HANGUL_S_BASE + index);
+    }
+    if (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&first_code)
+        && (first_code - HANGUL_S_BASE).is_multiple_of(HANGUL_T_COUNT)
+        && (HANGUL_T_BASE + 1..HANGUL_T_BASE + HANGUL_T_COUNT).contains(&second_code)
+    {
+        return char::from_u32(first_code + second_code - HANGUL_T_BASE);
+    }
+    CANONICAL_COMPOSITION
+        .binary_search_by(|&(a, b, _)| (a, b).cmp(&(first, second)))
+        .ok()
+        .map(|index| CANONICAL_COMPOSITION[index].2)
+}
+
+/// Applies the canonical composition algorithm to decomposed and canonically ordered chars:
+/// every char that is not blocked from the last starter is combined with it if they have a
+/// primary composite.
+fn compose_canonical(chars: &mut Vec<char>) {
+    let mut starter: Option<usize> = None;
+    let mut last_class = 0;
+    let mut len = 0;
+    for index in 0..chars.len() {
+        let c = chars[index];
+        let class = canonical_combining_class(c);
+        if let Some(starter) = starter {
+            let blocked = len - 1 != starter && (last_class == 0 || last_class >= class);
+            if !blocked {
+                if let Some(composite) = compose_pair(chars[starter], c) {
+                    chars[starter] = composite;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(len);
+        }
+        last_class = class;
+        chars[len] = c;
+        len += 1;
+    }
+    chars.truncate(len);
+}
+
+/// Applies the canonical ordering algorithm: every run of non-starters is stably sorted by
+/// canonical combining class.
+fn canonical_order(chars: &mut [char]) {
+    let mut start = 0;
+    while start < chars.len() {
+        if canonical_combining_class(chars[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let mut end = start + 1;
+        while end < chars.len() && canonical_combining_class(chars[end]) != 0 {
+            end += 1;
+        }
+        if end - start > 1 {
+            chars[start..end].sort_by_key(|&c| canonical_combining_class(c));
+        }
+        start = end;
+    }
+}
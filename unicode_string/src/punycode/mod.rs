@@ -0,0 +1,239 @@
+//! Punycode, the encoding of Unicode strings as ASCII used by internationalized domain names
+//! ([RFC 3492](https://www.rfc-editor.org/rfc/rfc3492)).
+//!
+//! Punycode encodes code points, so it works directly on the chars of a [`unicode_str`]. Use
+//! the [`idna`](crate::idna) module to convert whole domain names, which also maps, normalizes
+//! and validates their labels and adds the `xn--` prefix.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use unicode_string::{punycode, ustr};
+//!
+//! assert_eq!(punycode::encode(ustr!("bücher")).unwrap(), "bcher-kva");
+//! assert_eq!(punycode::decode("bcher-kva").unwrap(), ustr!("bücher"));
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use crate::{unicode_str, UnicodeString};
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+const DELIMITER: char = '-';
+
+/// An error returned when encoding or decoding Punycode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PunycodeError {
+    /// The encoded string contains a char that is not an ASCII letter, digit or hyphen, or a
+    /// non-ASCII char before the last delimiter.
+    InvalidDigit,
+    /// The encoded string ends in the middle of a code point.
+    UnexpectedEnd,
+    /// The encoded string decodes to a value that is not a char, like a surrogate.
+    InvalidCodePoint,
+    /// A value does not fit in 32 bits. Strings of reasonable length never overflow.
+    Overflow,
+}
+
+impl fmt::Display for PunycodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PunycodeError::InvalidDigit => "invalid Punycode digit",
+            PunycodeError::UnexpectedEnd => "unexpected end of Punycode input",
+            PunycodeError::InvalidCodePoint => "Punycode input decodes to an invalid code point",
+            PunycodeError::Overflow => "Punycode overflow",
+        })
+    }
+}
+
+impl Error for PunycodeError {}
+
+/// Adapts the bias after a delta is encoded or decoded (RFC 3492, section 6.1).
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+}
+
+/// Returns the threshold of the digit at position `k` of a variable-length integer.
+#[inline]
+fn threshold(k: u32, bias: u32) -> u32 {
+    k.saturating_sub(bias).clamp(T_MIN, T_MAX)
+}
+
+/// Returns the lowercase ASCII char of a digit between 0 and 35.
+#[inline]
+fn encode_digit(digit: u32) -> char {
+    match digit {
+        0..=25 => (b'a' + digit as u8) as char,
+        _ => (b'0' + (digit - 26) as u8) as char,
+    }
+}
+
+/// Returns the value of a digit, ignoring case.
+#[inline]
+fn decode_digit(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32),
+        'A'..='Z' => Some(c as u32 - 'A' as u32),
+        '0'..='9' => Some(c as u32 - '0' as u32 + 26),
+        _ => None,
+    }
+}
+
+/// Encodes `input` as Punycode.
+///
+/// The ASCII chars of `input` are copied first, in order and with their case, followed by a
+/// hyphen if there are any, and by the encoding of the other chars.
+///
+/// # Errors
+///
+/// Returns [`PunycodeError::Overflow`] if the input is so long that the encoding overflows.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::{punycode, ustr};
+///
+/// assert_eq!(punycode::encode(ustr!("münchen")).unwrap(), "mnchen-3ya");
+/// assert_eq!(punycode::encode(ustr!("☃")).unwrap(), "n3h");
+/// assert_eq!(punycode::encode(ustr!("abc")).unwrap(), "abc-");
+/// ```
+pub fn encode(input: &unicode_str) -> Result<String, PunycodeError> {
+    let chars = input.chars();
+    let mut output: String = chars.iter().filter(|c| c.is_ascii()).collect();
+    let basic_len = output.len() as u32;
+    if basic_len > 0 {
+        output.push(DELIMITER);
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut handled = basic_len;
+    let total = u32::try_from(chars.len()).map_err(|_| PunycodeError::Overflow)?;
+    while handled < total {
+        // The smallest code point that has not been handled yet.
+        let m = chars
+            .iter()
+            .map(|&c| c as u32)
+            .filter(|&code| code >= n)
+            .min()
+            .expect("an unhandled char is left");
+        delta = (m - n)
+            .checked_mul(handled + 1)
+            .and_then(|increment| delta.checked_add(increment))
+            .ok_or(PunycodeError::Overflow)?;
+        n = m;
+        for &c in chars {
+            let code = c as u32;
+            if code < n {
+                delta = delta.checked_add(1).ok_or(PunycodeError::Overflow)?;
+            }
+            if code == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    output.push(encode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(encode_digit(q));
+                bias = adapt(delta, handled + 1, handled == basic_len);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta = delta.checked_add(1).ok_or(PunycodeError::Overflow)?;
+        n += 1;
+    }
+    Ok(output)
+}
+
+/// Decodes a Punycode string.
+///
+/// # Errors
+///
+/// Returns an error if `input` is not valid Punycode. See [`PunycodeError`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::punycode::{self, PunycodeError};
+/// use unicode_string::ustr;
+///
+/// assert_eq!(punycode::decode("mnchen-3ya").unwrap(), ustr!("münchen"));
+/// assert_eq!(punycode::decode("n3h").unwrap(), ustr!("☃"));
+/// assert_eq!(punycode::decode("abc-").unwrap(), ustr!("abc"));
+///
+/// assert_eq!(punycode::decode("mnchen-3y"), Err(PunycodeError::UnexpectedEnd));
+/// assert_eq!(punycode::decode("mnchen-3y!"), Err(PunycodeError::InvalidDigit));
+/// ```
+pub fn decode(input: &str) -> Result<UnicodeString, PunycodeError> {
+    let (basic, extended) = match input.rfind(DELIMITER) {
+        Some(index) => (&input[..index], &input[index + 1..]),
+        None => ("", input),
+    };
+    if !basic.is_ascii() {
+        return Err(PunycodeError::InvalidDigit);
+    }
+    let mut output: Vec<char> = basic.chars().collect();
+
+    let mut n = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut digits = extended.chars();
+    while digits.as_str() != "" {
+        let old_i = i;
+        let mut weight: u32 = 1;
+        let mut k = BASE;
+        loop {
+            let c = digits.next().ok_or(PunycodeError::UnexpectedEnd)?;
+            let digit = decode_digit(c).ok_or(PunycodeError::InvalidDigit)?;
+            i = digit
+                .checked_mul(weight)
+                .and_then(|value| i.checked_add(value))
+                .ok_or(PunycodeError::Overflow)?;
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            weight = weight
+                .checked_mul(BASE - t)
+                .ok_or(PunycodeError::Overflow)?;
+            k += BASE;
+        }
+        let len = output.len() as u32 + 1;
+        bias = adapt(i - old_i, len, old_i == 0);
+        n = n.checked_add(i / len).ok_or(PunycodeError::Overflow)?;
+        i %= len;
+        let c = char::from_u32(n)
+            .filter(|c| !c.is_ascii())
+            .ok_or(PunycodeError::InvalidCodePoint)?;
+        output.insert(i as usize, c);
+        i += 1;
+    }
+    Ok(UnicodeString { vec: output })
+}
@@ -0,0 +1,335 @@
+use std::iter::FusedIterator;
+use std::ops::{Index, Range};
+use std::sync::Arc;
+
+use crate::regex::UnicodeRegex;
+use crate::{unicode_str, UnicodeString};
+
+/// A match of a [`UnicodeRegex`] in a `unicode_str`.
+///
+/// Positions are char indices, so [`range`](Match::range) can be used to index the searched
+/// string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Match<'s> {
+    haystack: &'s unicode_str,
+    start: usize,
+    end: usize,
+}
+
+impl<'s> Match<'s> {
+    #[inline]
+    pub(crate) fn new(haystack: &'s unicode_str, start: usize, end: usize) -> Match<'s> {
+        Match {
+            haystack,
+            start,
+            end,
+        }
+    }
+
+    /// Returns the index of the first char of the match.
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the index after the last char of the match.
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the range of chars of the match.
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the number of chars of the match.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Checks if the match is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the matched chars.
+    #[inline]
+    pub fn as_unicode_str(&self) -> &'s unicode_str {
+        &self.haystack[self.range()]
+    }
+}
+
+/// The positions of the capture groups of a match of a [`UnicodeRegex`].
+///
+/// Group 0 is the whole match, and the other groups are numbered by the position of their
+/// opening parenthesis in the pattern. Groups that did not participate in the match, like
+/// an alternative that was not taken, have no match.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Captures<'s> {
+    haystack: &'s unicode_str,
+    slots: Vec<Option<usize>>,
+    names: Arc<[Option<String>]>,
+}
+
+impl<'s> Captures<'s> {
+    #[inline]
+    pub(crate) fn new(
+        haystack: &'s unicode_str,
+        slots: Vec<Option<usize>>,
+        names: Arc<[Option<String>]>,
+    ) -> Captures<'s> {
+        Captures {
+            haystack,
+            slots,
+            names,
+        }
+    }
+
+    /// Returns the match of the group with the given index, or `None` if the group did not
+    /// participate in the match or does not exist.
+    pub fn get(&self, index: usize) -> Option<Match<'s>> {
+        let start = (*self.slots.get(index * 2)?)?;
+        let end = (*self.slots.get(index * 2 + 1)?)?;
+        Some(Match::new(self.haystack, start, end))
+    }
+
+    /// Returns the match of the group with the given name, or `None` if the group did not
+    /// participate in the match or does not exist.
+    pub fn name(&self, name: &str) -> Option<Match<'s>> {
+        let index = self
+            .names
+            .iter()
+            .position(|other| other.as_deref() == Some(name))?;
+        self.get(index)
+    }
+
+    /// Returns the number of groups, including group 0.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Always returns `false`, since there is always at least group 0.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Iterates over the matches of all the groups, in order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Option<Match<'s>>> + '_ {
+        (0..self.len()).map(|index| self.get(index))
+    }
+
+    /// Appends `replacement` to `dst`, replacing `$name` and `${name}` with the group with
+    /// that name or index. `$$` is a literal `$`. Groups that did not participate in the
+    /// match are replaced with nothing.
+    ///
+    /// `$name` uses the longest sequence of letters, digits and underscores as the name, so
+    /// use `${1}a` rather than `$1a` when a group is followed by such chars.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::regex::UnicodeRegex;
+    /// use unicode_string::{ustr, UnicodeString};
+    ///
+    /// let regex = UnicodeRegex::new(r"(?<day>\d+)\.(?<month>\d+)\.").unwrap();
+    /// let captures = regex.captures(ustr!("31.12.")).unwrap();
+    ///
+    /// let mut dst = UnicodeString::new();
+    /// captures.expand(ustr!("$month/${day} costs $$1"), &mut dst);
+    /// assert_eq!(dst, ustr!("12/31 costs $1"));
+    /// ```
+    pub fn expand(&self, replacement: &unicode_str, dst: &mut UnicodeString) {
+        let chars = replacement.chars();
+        let mut index = 0;
+        while index < chars.len() {
+            let c = chars[index];
+            index += 1;
+            if c != '$' {
+                dst.vec.push(c);
+                continue;
+            }
+            let (name, next) = if chars.get(index) == Some(&'$') {
+                dst.vec.push('$');
+                index += 1;
+                continue;
+            } else if chars.get(index) == Some(&'{') {
+                match chars[index..].iter().position(|&c| c == '}') {
+                    Some(length) => (&chars[index + 1..index + length], index + length + 1),
+                    None => {
+                        dst.vec.push('$');
+                        continue;
+                    }
+                }
+            } else {
+                let length = chars[index..]
+                    .iter()
+                    .take_while(|&&c| c == '_' || c.is_ascii_alphanumeric())
+                    .count();
+                (&chars[index..index + length], index + length)
+            };
+            if name.is_empty() {
+                dst.vec.push('$');
+                continue;
+            }
+            let name = name.iter().collect::<String>();
+            let group = match name.parse::<usize>() {
+                Ok(group) => self.get(group),
+                Err(_) => self.name(&name),
+            };
+            if let Some(group) = group {
+                dst.vec.extend_from_slice(group.as_unicode_str().chars());
+            }
+            index = next;
+        }
+    }
+}
+
+impl<'s> Index<usize> for Captures<'s> {
+    type Output = unicode_str;
+
+    /// Returns the chars matched by the group with the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the group did not participate in the match or does not exist.
+    fn index(&self, index: usize) -> &unicode_str {
+        self.get(index)
+            .unwrap_or_else(|| panic!("no group at index {}", index))
+            .as_unicode_str()
+    }
+}
+
+impl<'s> Index<&str> for Captures<'s> {
+    type Output = unicode_str;
+
+    /// Returns the chars matched by the group with the given name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the group did not participate in the match or does not exist.
+    fn index(&self, name: &str) -> &unicode_str {
+        self.name(name)
+            .unwrap_or_else(|| panic!("no group named {:?}", name))
+            .as_unicode_str()
+    }
+}
+
+/// Tracks the position of an iteration over successive matches. An empty match right after
+/// the previous match is skipped.
+#[derive(Clone, Debug)]
+struct Position {
+    next: usize,
+    last_end: Option<usize>,
+}
+
+impl Position {
+    fn new() -> Position {
+        Position {
+            next: 0,
+            last_end: None,
+        }
+    }
+
+    /// Finds the next match with `search`, which searches from a position and returns the
+    /// match and its range.
+    fn advance<T>(
+        &mut self,
+        len: usize,
+        mut search: impl FnMut(usize) -> Option<(T, Range<usize>)>,
+    ) -> Option<T> {
+        loop {
+            if self.next > len {
+                return None;
+            }
+            let (value, range) = search(self.next)?;
+            self.next = if range.is_empty() {
+                range.end + 1
+            } else {
+                range.end
+            };
+            if range.is_empty() && self.last_end == Some(range.start) {
+                continue;
+            }
+            self.last_end = Some(range.end);
+            return Some(value);
+        }
+    }
+}
+
+/// An iterator over the successive matches of a [`UnicodeRegex`], created by
+/// [`UnicodeRegex::find_iter`].
+#[derive(Clone, Debug)]
+pub struct Matches<'r, 's> {
+    regex: &'r UnicodeRegex,
+    haystack: &'s unicode_str,
+    position: Position,
+}
+
+impl<'r, 's> Matches<'r, 's> {
+    pub(crate) fn new(regex: &'r UnicodeRegex, haystack: &'s unicode_str) -> Matches<'r, 's> {
+        Matches {
+            regex,
+            haystack,
+            position: Position::new(),
+        }
+    }
+}
+
+impl<'r, 's> Iterator for Matches<'r, 's> {
+    type Item = Match<'s>;
+
+    fn next(&mut self) -> Option<Match<'s>> {
+        let (regex, haystack) = (self.regex, self.haystack);
+        self.position.advance(haystack.len(), |start| {
+            let found = regex.find_at(haystack, start)?;
+            Some((found, found.range()))
+        })
+    }
+}
+
+impl FusedIterator for Matches<'_, '_> {}
+
+/// An iterator over the captures of the successive matches of a [`UnicodeRegex`], created by
+/// [`UnicodeRegex::captures_iter`].
+#[derive(Clone, Debug)]
+pub struct CaptureMatches<'r, 's> {
+    regex: &'r UnicodeRegex,
+    haystack: &'s unicode_str,
+    position: Position,
+}
+
+impl<'r, 's> CaptureMatches<'r, 's> {
+    pub(crate) fn new(
+        regex: &'r UnicodeRegex,
+        haystack: &'s unicode_str,
+    ) -> CaptureMatches<'r, 's> {
+        CaptureMatches {
+            regex,
+            haystack,
+            position: Position::new(),
+        }
+    }
+}
+
+impl<'r, 's> Iterator for CaptureMatches<'r, 's> {
+    type Item = Captures<'s>;
+
+    fn next(&mut self) -> Option<Captures<'s>> {
+        let (regex, haystack) = (self.regex, self.haystack);
+        self.position.advance(haystack.len(), |start| {
+            let captures = regex.captures_at(haystack, start)?;
+            let range = captures.get(0)?.range();
+            Some((captures, range))
+        })
+    }
+}
+
+impl FusedIterator for CaptureMatches<'_, '_> {}
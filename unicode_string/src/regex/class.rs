@@ -0,0 +1,340 @@
+use crate::case::simple_fold_char;
+use crate::tables::case_folding::SIMPLE_CASE_FOLDING;
+use crate::tables::properties::*;
+use crate::tables::scripts::{SCRIPT, SCRIPT_EXTENSIONS};
+use crate::ucd::{self, loose_eq, GeneralCategory, Script};
+
+/// A set of chars, stored as sorted, non-overlapping and non-adjacent ranges.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub(crate) struct CharClass {
+    ranges: Vec<(char, char)>,
+}
+
+impl CharClass {
+    /// Creates a class from ranges in any order.
+    pub(crate) fn new(mut ranges: Vec<(char, char)>) -> CharClass {
+        ranges.sort_unstable();
+        let mut merged: Vec<(char, char)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some((_, last)) if next_char(*last).is_none_or(|next| start <= next) => {
+                    *last = (*last).max(end)
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+        CharClass { ranges: merged }
+    }
+
+    /// Creates a class that only contains `c`.
+    pub(crate) fn single(c: char) -> CharClass {
+        CharClass {
+            ranges: vec![(c, c)],
+        }
+    }
+
+    /// Creates a class that contains every char.
+    pub(crate) fn all() -> CharClass {
+        CharClass {
+            ranges: vec![('\0', char::MAX)],
+        }
+    }
+
+    /// Returns the only char of the class, if it contains exactly one.
+    pub(crate) fn as_single(&self) -> Option<char> {
+        match *self.ranges {
+            [(start, end)] if start == end => Some(start),
+            _ => None,
+        }
+    }
+
+    /// Checks if the class contains every char.
+    pub(crate) fn is_all(&self) -> bool {
+        self.ranges == [('\0', char::MAX)]
+    }
+
+    #[inline]
+    pub(crate) fn contains(&self, c: char) -> bool {
+        ucd::in_ranges(&self.ranges, c)
+    }
+
+    pub(crate) fn union(&self, other: &CharClass) -> CharClass {
+        CharClass::new([&self.ranges[..], &other.ranges[..]].concat())
+    }
+
+    pub(crate) fn negate(&self) -> CharClass {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        let mut start = Some('\0');
+        for &(first, last) in &self.ranges {
+            if let Some(start) = start.filter(|&start| start < first) {
+                ranges.push((start, previous_char(first).unwrap_or(start)));
+            }
+            start = next_char(last);
+        }
+        if let Some(start) = start {
+            ranges.push((start, char::MAX));
+        }
+        CharClass { ranges }
+    }
+
+    pub(crate) fn intersection(&self, other: &CharClass) -> CharClass {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (0, 0);
+        while a < self.ranges.len() && b < other.ranges.len() {
+            let (start_a, end_a) = self.ranges[a];
+            let (start_b, end_b) = other.ranges[b];
+            let start = start_a.max(start_b);
+            let end = end_a.min(end_b);
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if end_a < end_b {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+        CharClass { ranges }
+    }
+
+    pub(crate) fn difference(&self, other: &CharClass) -> CharClass {
+        self.intersection(&other.negate())
+    }
+
+    /// Adds every char that has the same simple case folding as a char of the class.
+    pub(crate) fn case_fold(&self) -> CharClass {
+        let mut folded = Vec::new();
+        for (c, folding) in simple_case_foldings() {
+            if self.contains(c) || self.contains(folding) {
+                folded.push(folding);
+            }
+        }
+        folded.sort_unstable();
+        let mut ranges = self.ranges.clone();
+        for (c, folding) in simple_case_foldings() {
+            if folded.binary_search(&folding).is_ok() {
+                ranges.push((c, c));
+                ranges.push((folding, folding));
+            }
+        }
+        CharClass::new(ranges)
+    }
+
+    /// Returns the class of a Unicode property, written like `Greek`, `L`, `Alphabetic`,
+    /// `gc=Lu`, `sc=Latn` or `scx=Hira`, or `None` if there is no such property.
+    pub(crate) fn property(name: &str) -> Option<CharClass> {
+        if let Some((property, value)) = name.split_once(['=', ':']) {
+            let property = property.trim();
+            return if ["gc", "General_Category"]
+                .iter()
+                .any(|&p| loose_eq(property, p))
+            {
+                general_category(value)
+            } else if ["sc", "Script"].iter().any(|&p| loose_eq(property, p)) {
+                Script::from_name(value).map(script)
+            } else if ["scx", "Script_Extensions"]
+                .iter()
+                .any(|&p| loose_eq(property, p))
+            {
+                Script::from_name(value).map(script_extensions)
+            } else {
+                None
+            };
+        }
+        if loose_eq(name, "Any") {
+            return Some(CharClass::all());
+        }
+        if loose_eq(name, "ASCII") {
+            return Some(CharClass::new(vec![('\0', '\x7F')]));
+        }
+        if loose_eq(name, "Assigned") {
+            return general_category("Cn").map(|class| class.negate());
+        }
+        general_category(name)
+            .or_else(|| Script::from_name(name).map(script))
+            .or_else(|| {
+                BINARY_PROPERTIES
+                    .iter()
+                    .find(|(names, _)| names.iter().any(|&n| loose_eq(name, n)))
+                    .map(|&(_, table)| CharClass::new(table.to_vec()))
+            })
+    }
+
+    /// The chars matched by `\d`: decimal digits (`\p{Nd}`).
+    pub(crate) fn digit() -> CharClass {
+        general_category_matching(|category| category == GeneralCategory::DecimalNumber)
+    }
+
+    /// The chars matched by `\s`: whitespace (`\p{White_Space}`).
+    pub(crate) fn space() -> CharClass {
+        CharClass::new(WHITE_SPACE.to_vec())
+    }
+
+    /// The chars matched by `\w`, as defined by UTS #18: alphabetic chars, marks, decimal
+    /// digits, connector punctuation and the joiners.
+    pub(crate) fn word() -> CharClass {
+        let categories = general_category_matching(|category| {
+            category.is_mark()
+                || matches!(
+                    category,
+                    GeneralCategory::DecimalNumber | GeneralCategory::ConnectorPunctuation
+                )
+        });
+        CharClass::new(ALPHABETIC.to_vec())
+            .union(&categories)
+            .union(&CharClass::new(vec![('\u{200C}', '\u{200D}')]))
+    }
+}
+
+/// Checks if `c` is matched by `\w`, for word boundaries.
+pub(crate) fn is_word_char(c: char) -> bool {
+    if c.is_ascii() {
+        return c.is_ascii_alphanumeric() || c == '_';
+    }
+    let category = ucd::general_category(c);
+    ucd::is_alphabetic(c)
+        || category.is_mark()
+        || matches!(
+            category,
+            GeneralCategory::DecimalNumber | GeneralCategory::ConnectorPunctuation
+        )
+        || matches!(c, '\u{200C}' | '\u{200D}')
+}
+
+/// The names of a property or value: the long name, then the short names.
+type Names = &'static [&'static str];
+
+type CategoryFilter = fn(GeneralCategory) -> bool;
+
+/// The binary properties usable in `\p{...}`.
+static BINARY_PROPERTIES: &[(Names, &[(char, char)])] = &[
+    (&["Alphabetic", "Alpha"], ALPHABETIC),
+    (&["Dash"], DASH),
+    (
+        &["Default_Ignorable_Code_Point", "DI"],
+        DEFAULT_IGNORABLE_CODE_POINT,
+    ),
+    (&["Emoji"], EMOJI),
+    (&["Emoji_Component", "EComp"], EMOJI_COMPONENT),
+    (&["Emoji_Modifier", "EMod"], EMOJI_MODIFIER),
+    (&["Emoji_Modifier_Base", "EBase"], EMOJI_MODIFIER_BASE),
+    (&["Emoji_Presentation", "EPres"], EMOJI_PRESENTATION),
+    (&["Extended_Pictographic", "ExtPict"], EXTENDED_PICTOGRAPHIC),
+    (&["ID_Continue", "IDC"], ID_CONTINUE),
+    (&["ID_Start", "IDS"], ID_START),
+    (&["Ideographic", "Ideo"], IDEOGRAPHIC),
+    (&["Lowercase", "Lower"], LOWERCASE),
+    (&["Math"], MATH),
+    (
+        &["Noncharacter_Code_Point", "NChar"],
+        NONCHARACTER_CODE_POINT,
+    ),
+    (&["Pattern_Syntax", "Pat_Syn"], PATTERN_SYNTAX),
+    (&["Pattern_White_Space", "Pat_WS"], PATTERN_WHITE_SPACE),
+    (&["Quotation_Mark", "QMark"], QUOTATION_MARK),
+    (&["Regional_Indicator", "RI"], REGIONAL_INDICATOR),
+    (&["Uppercase", "Upper"], UPPERCASE),
+    (&["Variation_Selector", "VS"], VARIATION_SELECTOR),
+    (&["White_Space", "WSpace", "space"], WHITE_SPACE),
+    (&["XID_Continue", "XIDC"], XID_CONTINUE),
+    (&["XID_Start", "XIDS"], XID_START),
+];
+
+/// The groups of general categories.
+static GENERAL_CATEGORY_GROUPS: &[(Names, CategoryFilter)] = &[
+    (&["Letter", "L"], GeneralCategory::is_letter),
+    (&["Cased_Letter", "LC"], GeneralCategory::is_cased_letter),
+    (&["Mark", "M", "Combining_Mark"], GeneralCategory::is_mark),
+    (&["Number", "N"], GeneralCategory::is_number),
+    (
+        &["Punctuation", "P", "punct"],
+        GeneralCategory::is_punctuation,
+    ),
+    (&["Symbol", "S"], GeneralCategory::is_symbol),
+    (&["Separator", "Z"], GeneralCategory::is_separator),
+    (&["Other", "C"], GeneralCategory::is_other),
+];
+
+fn general_category(name: &str) -> Option<CharClass> {
+    if let Some(category) = GeneralCategory::from_name(name) {
+        return Some(general_category_matching(|other| other == category));
+    }
+    GENERAL_CATEGORY_GROUPS
+        .iter()
+        .find(|(names, _)| names.iter().any(|&n| loose_eq(name, n)))
+        .map(|&(_, matches)| general_category_matching(matches))
+}
+
+fn general_category_matching(matches: impl Fn(GeneralCategory) -> bool) -> CharClass {
+    let mut class = CharClass::new(
+        GENERAL_CATEGORY
+            .iter()
+            .filter(|&&(_, _, category)| matches(category))
+            .map(|&(start, end, _)| (start, end))
+            .collect(),
+    );
+    if matches(GeneralCategory::Unassigned) {
+        let assigned = GENERAL_CATEGORY
+            .iter()
+            .map(|&(start, end, _)| (start, end))
+            .collect();
+        class = class.union(&CharClass::new(assigned).negate());
+    }
+    class
+}
+
+fn script(script: Script) -> CharClass {
+    let mut class = CharClass::new(
+        SCRIPT
+            .iter()
+            .filter(|&&(_, _, other)| other == script)
+            .map(|&(start, end, _)| (start, end))
+            .collect(),
+    );
+    if script == Script::Unknown {
+        let known = SCRIPT.iter().map(|&(start, end, _)| (start, end)).collect();
+        class = class.union(&CharClass::new(known).negate());
+    }
+    class
+}
+
+fn script_extensions(value: Script) -> CharClass {
+    let extended = CharClass::new(
+        SCRIPT_EXTENSIONS
+            .iter()
+            .map(|&(start, end, _)| (start, end))
+            .collect(),
+    );
+    let with_script = CharClass::new(
+        SCRIPT_EXTENSIONS
+            .iter()
+            .filter(|&&(_, _, scripts)| scripts.contains(&value))
+            .map(|&(start, end, _)| (start, end))
+            .collect(),
+    );
+    script(value).difference(&extended).union(&with_script)
+}
+
+/// Iterates over the simple case foldings of the chars that do not fold to themselves,
+/// including ASCII.
+fn simple_case_foldings() -> impl Iterator<Item = (char, char)> {
+    ('A'..='Z')
+        .map(|c| (c, simple_fold_char(c)))
+        .chain(SIMPLE_CASE_FOLDING.iter().copied())
+}
+
+#[inline]
+fn next_char(c: char) -> Option<char> {
+    match c {
+        '\u{D7FF}' => Some('\u{E000}'),
+        c => char::from_u32(c as u32 + 1),
+    }
+}
+
+#[inline]
+fn previous_char(c: char) -> Option<char> {
+    match c {
+        '\u{E000}' => Some('\u{D7FF}'),
+        c => (c as u32).checked_sub(1).and_then(char::from_u32),
+    }
+}
@@ -0,0 +1,234 @@
+use crate::regex::class::CharClass;
+use crate::regex::parse::{Ast, Look, Node};
+use crate::regex::RegexError;
+
+/// The largest number of instructions of a compiled pattern.
+const MAX_INSTRUCTIONS: usize = 1 << 20;
+
+/// An instruction of the Pike VM.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Inst {
+    /// Matches a single char.
+    Char(char),
+    /// Matches a char of the class with the given index.
+    Class(usize),
+    /// Matches any char.
+    Any,
+    /// Continues if the assertion holds at the current position.
+    Look(Look),
+    /// Continues at both targets, preferring the first.
+    Split(usize, usize),
+    Jump(usize),
+    /// Records the current position in a capture slot.
+    Save(usize),
+    /// Reports a match of the pattern with the given index, which is always 0 except in
+    /// programs made of several patterns.
+    Match(usize),
+}
+
+/// A compiled pattern.
+#[derive(Clone, Debug)]
+pub(crate) struct Program {
+    pub(crate) insts: Vec<Inst>,
+    pub(crate) classes: Vec<CharClass>,
+    /// The number of capture slots, two per group.
+    pub(crate) slots: usize,
+    /// Set if every match has to start at the start of the text.
+    pub(crate) anchored: bool,
+}
+
+pub(crate) fn compile(ast: Ast) -> Result<Program, RegexError> {
+    let mut compiler = Compiler {
+        insts: Vec::new(),
+        classes: &ast.classes,
+    };
+    compiler.push(Inst::Save(0))?;
+    compiler.compile(&ast.node)?;
+    compiler.push(Inst::Save(1))?;
+    compiler.push(Inst::Match(0))?;
+    let insts = compiler.insts;
+    Ok(Program {
+        insts,
+        anchored: is_anchored(&ast.node),
+        slots: ast.names.len() * 2,
+        classes: ast.classes,
+    })
+}
+
+/// Compiles several patterns into a program that matches any of them and reports which ones
+/// matched. The patterns have no capture groups and share `classes`.
+pub(crate) fn compile_set(nodes: &[Node], classes: Vec<CharClass>) -> Result<Program, RegexError> {
+    let mut compiler = Compiler {
+        insts: Vec::new(),
+        classes: &classes,
+    };
+    for (index, node) in nodes.iter().enumerate() {
+        let split = if index + 1 < nodes.len() {
+            Some(compiler.push(Inst::Split(compiler.insts.len() + 1, 0))?)
+        } else {
+            None
+        };
+        compiler.compile(node)?;
+        compiler.push(Inst::Match(index))?;
+        if let Some(split) = split {
+            let next = compiler.insts.len();
+            compiler.patch(split, next);
+        }
+    }
+    let insts = compiler.insts;
+    Ok(Program {
+        insts,
+        classes,
+        slots: 0,
+        anchored: nodes.iter().all(is_anchored),
+    })
+}
+
+/// Checks if every match of `node` has to start at the start of the text.
+fn is_anchored(node: &Node) -> bool {
+    match node {
+        Node::Look(Look::Start) => true,
+        Node::Capture(_, node) => is_anchored(node),
+        Node::Concat(nodes) => nodes.first().is_some_and(is_anchored),
+        Node::Alternation(nodes) => nodes.iter().all(is_anchored),
+        Node::Repetition { node, min, .. } => *min > 0 && is_anchored(node),
+        _ => false,
+    }
+}
+
+struct Compiler<'a> {
+    insts: Vec<Inst>,
+    classes: &'a [CharClass],
+}
+
+impl Compiler<'_> {
+    /// Appends an instruction and returns its index.
+    fn push(&mut self, inst: Inst) -> Result<usize, RegexError> {
+        if self.insts.len() >= MAX_INSTRUCTIONS {
+            return Err(RegexError::TooLarge);
+        }
+        self.insts.push(inst);
+        Ok(self.insts.len() - 1)
+    }
+
+    /// Sets the target of the jump or split at `index` that is still unknown.
+    fn patch(&mut self, index: usize, target: usize) {
+        match &mut self.insts[index] {
+            Inst::Jump(next) => *next = target,
+            Inst::Split(_, second) => *second = target,
+            _ => unreachable!("only jumps and splits are patched"),
+        }
+    }
+
+    fn compile(&mut self, node: &Node) -> Result<(), RegexError> {
+        match node {
+            Node::Empty => {}
+            Node::Char(c) => {
+                self.push(Inst::Char(*c))?;
+            }
+            Node::Class(index) if self.classes[*index].is_all() => {
+                self.push(Inst::Any)?;
+            }
+            Node::Class(index) => {
+                self.push(Inst::Class(*index))?;
+            }
+            Node::Look(look) => {
+                self.push(Inst::Look(*look))?;
+            }
+            Node::Capture(index, node) => {
+                self.push(Inst::Save(index * 2))?;
+                self.compile(node)?;
+                self.push(Inst::Save(index * 2 + 1))?;
+            }
+            Node::Concat(nodes) => {
+                for node in nodes {
+                    self.compile(node)?;
+                }
+            }
+            Node::Alternation(nodes) => {
+                let mut jumps = Vec::new();
+                for (index, node) in nodes.iter().enumerate() {
+                    if index + 1 == nodes.len() {
+                        self.compile(node)?;
+                        break;
+                    }
+                    let split = self.push(Inst::Split(self.insts.len() + 1, 0))?;
+                    self.compile(node)?;
+                    jumps.push(self.push(Inst::Jump(0))?);
+                    let next = self.insts.len();
+                    self.patch(split, next);
+                }
+                let end = self.insts.len();
+                for jump in jumps {
+                    self.patch(jump, end);
+                }
+            }
+            Node::Repetition {
+                node,
+                min,
+                max,
+                greedy,
+            } => {
+                match max {
+                    None if *min == 0 && !node.can_match_empty() => {
+                        // L: split(body, end); body; jump L
+                        let split = self.push(Inst::Split(0, 0))?;
+                        self.compile(node)?;
+                        self.push(Inst::Jump(split))?;
+                        let end = self.insts.len();
+                        self.insts[split] = repetition_split(split + 1, end, *greedy);
+                    }
+                    None => {
+                        // body; ...; L: body; split(L, end), or without a minimum
+                        // split(L, end); L: body; split(L, end)
+                        //
+                        // The loop is `x+`, or `(x+)?`, rather than a jump back to the first
+                        // split, so that an iteration of a body that can match nothing can
+                        // still leave the loop with its own priority instead of being discarded.
+                        for _ in 1..*min {
+                            self.compile(node)?;
+                        }
+                        let split = if *min == 0 {
+                            Some(self.push(Inst::Split(0, 0))?)
+                        } else {
+                            None
+                        };
+                        let body = self.insts.len();
+                        self.compile(node)?;
+                        let repeat = self.push(Inst::Split(0, 0))?;
+                        let end = self.insts.len();
+                        if let Some(split) = split {
+                            self.insts[split] = repetition_split(body, end, *greedy);
+                        }
+                        self.insts[repeat] = repetition_split(body, end, *greedy);
+                    }
+                    Some(max) => {
+                        for _ in 0..*min {
+                            self.compile(node)?;
+                        }
+                        // split(body, end); body; split(body, end); body; ...; end
+                        let mut splits = Vec::new();
+                        for _ in *min..*max {
+                            splits.push(self.push(Inst::Split(0, 0))?);
+                            self.compile(node)?;
+                        }
+                        let end = self.insts.len();
+                        for split in splits {
+                            self.insts[split] = repetition_split(split + 1, end, *greedy);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns a split that prefers `body` if `greedy` and `end` otherwise.
+fn repetition_split(body: usize, end: usize, greedy: bool) -> Inst {
+    if greedy {
+        Inst::Split(body, end)
+    } else {
+        Inst::Split(end, body)
+    }
+}
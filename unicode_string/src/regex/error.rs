@@ -0,0 +1,60 @@
+use std::error::Error;
+use std::fmt;
+
+/// The reason a pattern cannot be compiled by [`UnicodeRegex::new`](super::UnicodeRegex::new).
+///
+/// Positions are char indices into the pattern.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RegexError {
+    /// The group starting at the position has no closing `)`.
+    UnclosedGroup(usize),
+    /// The `)` at the position has no opening `(`.
+    UnopenedGroup(usize),
+    /// The class starting at the position has no closing `]`.
+    UnclosedClass(usize),
+    /// The range of a class starting at the position is not valid, e.g. `z-a`.
+    InvalidClassRange(usize),
+    /// The escape sequence at the position is unknown or not valid.
+    InvalidEscape(usize),
+    /// The repetition at the position has nothing to repeat or is not valid, e.g. `a{2,1}`.
+    InvalidRepetition(usize),
+    /// The flag at the position is unknown.
+    InvalidFlag(usize),
+    /// The name of the group at the position is missing or not valid.
+    InvalidGroupName(usize),
+    /// Several groups have the same name.
+    DuplicateGroupName(String),
+    /// The property of a `\p{...}` class is unknown.
+    UnknownProperty(String),
+    /// The name of a `\N{...}` escape is not the name of a char.
+    UnknownCharName(String),
+    /// The compiled pattern would be too large, usually because of large repetitions.
+    TooLarge,
+}
+
+impl fmt::Display for RegexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegexError::UnclosedGroup(position) => write!(f, "unclosed group at {}", position),
+            RegexError::UnopenedGroup(position) => write!(f, "unopened group at {}", position),
+            RegexError::UnclosedClass(position) => write!(f, "unclosed class at {}", position),
+            RegexError::InvalidClassRange(position) => {
+                write!(f, "invalid class range at {}", position)
+            }
+            RegexError::InvalidEscape(position) => write!(f, "invalid escape at {}", position),
+            RegexError::InvalidRepetition(position) => {
+                write!(f, "invalid repetition at {}", position)
+            }
+            RegexError::InvalidFlag(position) => write!(f, "invalid flag at {}", position),
+            RegexError::InvalidGroupName(position) => {
+                write!(f, "invalid group name at {}", position)
+            }
+            RegexError::DuplicateGroupName(name) => write!(f, "duplicate group name {:?}", name),
+            RegexError::UnknownProperty(name) => write!(f, "unknown property {:?}", name),
+            RegexError::UnknownCharName(name) => write!(f, "unknown character name {:?}", name),
+            RegexError::TooLarge => f.write_str("compiled pattern is too large"),
+        }
+    }
+}
+
+impl Error for RegexError {}
@@ -0,0 +1,336 @@
+//! Regular expressions that search a `unicode_str` directly.
+//!
+//! A [`UnicodeRegex`] matches the chars of a `unicode_str` without converting it to UTF-8, and
+//! reports positions as char indices, so matches can be used to index the searched string.
+//! Patterns are compiled to an NFA that is run by a Pike VM, which follows all the possible
+//! paths at once: a search takes time linear in the length of the text, whatever the
+//! pattern. In exchange, backreferences and lookaround are not supported.
+//!
+//! # Syntax
+//!
+//! The syntax is close to that of the `regex` crate:
+//!
+//! | Syntax                              | Matches                                          |
+//! |-------------------------------------|--------------------------------------------------|
+//! | `.`                                 | any char except `\n` (any char with `s`)          |
+//! | `[a-z]`, `[^a-z]`                   | a char in, or not in, a class                     |
+//! | `[\p{L}&&[^a-z]]`                   | a char in both classes                            |
+//! | `\d`, `\s`, `\w`                    | a decimal digit, whitespace, a word char          |
+//! | `\D`, `\S`, `\W`                    | the negations of the above                        |
+//! | `\pL`, `\p{Greek}`, `\P{Greek}`     | a char with, or without, a Unicode property       |
+//! | `\p{gc=Lu}`, `\p{sc=Grek}`, `\p{scx=Hira}` | a general category, script or script extension |
+//! | `\n`, `\t`, `\x7F`, `\u{1F980}`, `\N{SNOWMAN}` | a char written as an escape            |
+//! | `^`, `$`                            | the start and end of the text (of a line with `m`) |
+//! | `\A`, `\z`                          | the start and end of the text                     |
+//! | `\b`, `\B`                          | a word boundary, not a word boundary              |
+//! | `x*`, `x+`, `x?`                    | zero or more, one or more, zero or one `x`        |
+//! | `x{n}`, `x{n,}`, `x{n,m}`           | `n`, at least `n`, between `n` and `m` `x`        |
+//! | `x*?`, `x+?`, `x??`, `x{n,m}?`      | the same, but preferring fewer repetitions        |
+//! | `xy`, `x\|y`                        | `x` followed by `y`, `x` or `y` (preferring `x`)  |
+//! | `(x)`, `(?<name>x)`, `(?:x)`        | a numbered, named or non-capturing group          |
+//! | `(?flags)`, `(?flags:x)`            | set flags for the rest of the group, or for `x`   |
+//!
+//! The flags are `i` (case-insensitive, using simple case folding), `m` (multi-line), `s`
+//! (`.` matches `\n`) and `x` (whitespace and `#` comments are ignored), and can be cleared
+//! with `-`, as in `(?i-s)`. `\d`, `\s` and `\w` are Unicode aware, as defined by UTS #18.
+//!
+//! When several matches start at the same position, the one preferred by the pattern is
+//! reported, like in Perl and most other engines: `a|ab` matches `a` in `ab`.
+
+mod captures;
+pub(crate) mod class;
+pub(crate) mod compile;
+mod error;
+pub(crate) mod parse;
+pub(crate) mod pike;
+
+pub use self::captures::*;
+pub use self::error::*;
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::regex::compile::Program;
+use crate::{unicode_str, UnicodeString};
+
+/// A compiled regular expression that searches `unicode_str`s. See the
+/// [module documentation](self) for the syntax.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::regex::UnicodeRegex;
+/// use unicode_string::ustr;
+///
+/// let s = ustr!("Καλημέρα, κόσμε!");
+/// let regex = UnicodeRegex::new(r"\p{Greek}+").unwrap();
+///
+/// let words: Vec<_> = regex.find_iter(s).map(|m| m.range()).collect();
+/// assert_eq!(words, [0..8, 10..15]);
+/// assert_eq!(&s[10..15], ustr!("κόσμε"));
+/// ```
+///
+/// Capture groups:
+///
+/// ```
+/// use unicode_string::regex::UnicodeRegex;
+/// use unicode_string::ustr;
+///
+/// let regex = UnicodeRegex::new(r"(?<key>\w+)\s*=\s*(?<value>\w+)").unwrap();
+/// let captures = regex.captures(ustr!("🦀 größe = 42")).unwrap();
+///
+/// assert_eq!(&captures["key"], ustr!("größe"));
+/// assert_eq!(captures.name("value").unwrap().range(), 10..12);
+/// assert_eq!(&captures[0], ustr!("größe = 42"));
+/// ```
+///
+/// Case-insensitive matching:
+///
+/// ```
+/// use unicode_string::regex::UnicodeRegex;
+/// use unicode_string::ustr;
+///
+/// let regex = UnicodeRegex::new(r"(?i)straße|ΣΊΣΥΦΟΣ").unwrap();
+/// assert!(regex.is_match(ustr!("STRAßE")));
+/// assert!(regex.is_match(ustr!("σίσυφος")));
+/// ```
+///
+/// Repetitions of patterns that can match nothing stop like in Perl, when an iteration
+/// prefers to match nothing:
+///
+/// ```
+/// use unicode_string::regex::UnicodeRegex;
+/// use unicode_string::ustr;
+///
+/// let s = ustr!("aa");
+/// for pattern in ["(a*?)*", "(?:a??)*", "(?:a*?)+", "(?:a*?){2,}"] {
+///     let regex = UnicodeRegex::new(pattern).unwrap();
+///     assert_eq!(regex.find(s).unwrap().range(), 0..0, "{}", pattern);
+/// }
+/// assert_eq!(UnicodeRegex::new("(?:a?)*").unwrap().find(s).unwrap().range(), 0..2);
+/// assert_eq!(UnicodeRegex::new("(?:a|)+?b").unwrap().find(ustr!("aab")).unwrap().range(), 0..3);
+/// ```
+#[derive(Clone)]
+pub struct UnicodeRegex {
+    pattern: String,
+    program: Program,
+    names: Arc<[Option<String>]>,
+}
+
+impl UnicodeRegex {
+    /// Compiles a pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is not valid, or if it would compile to a program that
+    /// is too large.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::regex::{RegexError, UnicodeRegex};
+    ///
+    /// assert!(UnicodeRegex::new(r"\p{Cyrillic}+").is_ok());
+    /// assert_eq!(UnicodeRegex::new("(a|b").unwrap_err(), RegexError::UnclosedGroup(0));
+    /// assert_eq!(
+    ///     UnicodeRegex::new(r"\p{Klingon}").unwrap_err(),
+    ///     RegexError::UnknownProperty("Klingon".to_owned())
+    /// );
+    /// ```
+    pub fn new(pattern: &str) -> Result<UnicodeRegex, RegexError> {
+        let ast = parse::parse(pattern)?;
+        let names = Arc::from(ast.names.clone());
+        let program = compile::compile(ast)?;
+        Ok(UnicodeRegex {
+            pattern: pattern.to_owned(),
+            program,
+            names,
+        })
+    }
+
+    /// Returns the pattern the regex was compiled from.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the number of capture groups, including group 0, the whole match.
+    #[inline]
+    pub fn captures_len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Iterates over the names of the capture groups, with `None` for unnamed groups.
+    pub fn capture_names(&self) -> impl ExactSizeIterator<Item = Option<&str>> {
+        self.names.iter().map(Option::as_deref)
+    }
+
+    /// Checks if the regex matches anywhere in `s`.
+    ///
+    /// This is faster than [`find`](UnicodeRegex::find), since the search stops at the first
+    /// match found.
+    pub fn is_match(&self, s: &unicode_str) -> bool {
+        pike::search(&self.program, s.chars(), 0, &mut [], true)
+    }
+
+    /// Returns the leftmost match in `s`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::regex::UnicodeRegex;
+    /// use unicode_string::ustr;
+    ///
+    /// let regex = UnicodeRegex::new(r"\d+").unwrap();
+    /// let found = regex.find(ustr!("Ünïcödé 2024")).unwrap();
+    /// assert_eq!(found.range(), 8..12);
+    /// assert_eq!(found.as_unicode_str(), ustr!("2024"));
+    /// ```
+    pub fn find<'s>(&self, s: &'s unicode_str) -> Option<Match<'s>> {
+        self.find_at(s, 0)
+    }
+
+    /// Returns the leftmost match in `s` that starts at or after the char index `start`.
+    ///
+    /// Unlike searching `&s[start..]`, the chars before `start` are taken into account by
+    /// `^`, `\b` and the other assertions.
+    pub fn find_at<'s>(&self, s: &'s unicode_str, start: usize) -> Option<Match<'s>> {
+        if start > s.len() {
+            return None;
+        }
+        let mut slots = [None; 2];
+        if !pike::search(&self.program, s.chars(), start, &mut slots, false) {
+            return None;
+        }
+        Some(Match::new(s, slots[0]?, slots[1]?))
+    }
+
+    /// Iterates over the successive non-overlapping matches in `s`.
+    pub fn find_iter<'r, 's>(&'r self, s: &'s unicode_str) -> Matches<'r, 's> {
+        Matches::new(self, s)
+    }
+
+    /// Returns the capture groups of the leftmost match in `s`.
+    pub fn captures<'s>(&self, s: &'s unicode_str) -> Option<Captures<'s>> {
+        self.captures_at(s, 0)
+    }
+
+    /// Returns the capture groups of the leftmost match in `s` that starts at or after the char
+    /// index `start`.
+    pub fn captures_at<'s>(&self, s: &'s unicode_str, start: usize) -> Option<Captures<'s>> {
+        if start > s.len() {
+            return None;
+        }
+        let mut slots = vec![None; self.program.slots];
+        if !pike::search(&self.program, s.chars(), start, &mut slots, false) {
+            return None;
+        }
+        Some(Captures::new(s, slots, self.names.clone()))
+    }
+
+    /// Iterates over the capture groups of the successive non-overlapping matches in `s`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::regex::UnicodeRegex;
+    /// use unicode_string::ustr;
+    ///
+    /// let regex = UnicodeRegex::new(r"(\p{Lu})\p{Ll}*").unwrap();
+    /// let initials: Vec<_> = regex
+    ///     .captures_iter(ustr!("Émile Zola, Ёлка"))
+    ///     .map(|captures| captures[1].to_string())
+    ///     .collect();
+    /// assert_eq!(initials, ["É", "Z", "Ё"]);
+    /// ```
+    pub fn captures_iter<'r, 's>(&'r self, s: &'s unicode_str) -> CaptureMatches<'r, 's> {
+        CaptureMatches::new(self, s)
+    }
+
+    /// Replaces the leftmost match in `s` with `replacement`, in which capture groups can be
+    /// referred to as described in [`Captures::expand`].
+    pub fn replace(&self, s: &unicode_str, replacement: &unicode_str) -> UnicodeString {
+        self.replacen(s, 1, replacement)
+    }
+
+    /// Replaces all the matches in `s` with `replacement`, in which capture groups can be
+    /// referred to as described in [`Captures::expand`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::regex::UnicodeRegex;
+    /// use unicode_string::ustr;
+    ///
+    /// let regex = UnicodeRegex::new(r"(\w+)@(\w+)").unwrap();
+    /// let s = ustr!("mail ünal@örnek or jo@example");
+    /// assert_eq!(
+    ///     regex.replace_all(s, ustr!("$2:$1")),
+    ///     ustr!("mail örnek:ünal or example:jo")
+    /// );
+    /// ```
+    pub fn replace_all(&self, s: &unicode_str, replacement: &unicode_str) -> UnicodeString {
+        self.replacen(s, 0, replacement)
+    }
+
+    /// Replaces the first `limit` matches in `s` with `replacement`, or all of them if `limit`
+    /// is 0.
+    pub fn replacen(
+        &self,
+        s: &unicode_str,
+        limit: usize,
+        replacement: &unicode_str,
+    ) -> UnicodeString {
+        let chars = s.chars();
+        let mut result = UnicodeString::new();
+        let mut last = 0;
+        let limit = if limit == 0 { usize::MAX } else { limit };
+        if replacement.chars().contains(&'$') {
+            for captures in self.captures_iter(s).take(limit) {
+                let found = captures.get(0).expect("group 0 always matches");
+                result.vec.extend_from_slice(&chars[last..found.start()]);
+                captures.expand(replacement, &mut result);
+                last = found.end();
+            }
+        } else {
+            for found in self.find_iter(s).take(limit) {
+                result.vec.extend_from_slice(&chars[last..found.start()]);
+                result.vec.extend_from_slice(replacement.chars());
+                last = found.end();
+            }
+        }
+        result.vec.extend_from_slice(&chars[last..]);
+        result
+    }
+}
+
+impl fmt::Debug for UnicodeRegex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("UnicodeRegex").field(&self.pattern).finish()
+    }
+}
+
+impl fmt::Display for UnicodeRegex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+impl FromStr for UnicodeRegex {
+    type Err = RegexError;
+
+    fn from_str(pattern: &str) -> Result<UnicodeRegex, RegexError> {
+        UnicodeRegex::new(pattern)
+    }
+}
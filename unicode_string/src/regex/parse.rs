@@ -0,0 +1,499 @@
+use crate::regex::class::CharClass;
+use crate::regex::RegexError;
+use crate::ucd;
+
+/// A zero-width assertion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Look {
+    /// `\A`, or `^` without the `m` flag.
+    Start,
+    /// `\z`, or `$` without the `m` flag.
+    End,
+    /// `^` with the `m` flag.
+    LineStart,
+    /// `$` with the `m` flag.
+    LineEnd,
+    /// `\b`
+    WordBoundary,
+    /// `\B`
+    NotWordBoundary,
+}
+
+/// The syntax tree of a pattern. Classes are stored in [`Ast::classes`] and referred to by
+/// index, so that repetitions do not copy them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Node {
+    Empty,
+    Char(char),
+    Class(usize),
+    Look(Look),
+    Capture(usize, Box<Node>),
+    Concat(Vec<Node>),
+    Alternation(Vec<Node>),
+    Repetition {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+    },
+}
+
+impl Node {
+    /// Checks if the node can match without consuming any char.
+    pub(crate) fn can_match_empty(&self) -> bool {
+        match self {
+            Node::Empty | Node::Look(_) => true,
+            Node::Char(_) | Node::Class(_) => false,
+            Node::Capture(_, node) => node.can_match_empty(),
+            Node::Concat(nodes) => nodes.iter().all(Node::can_match_empty),
+            Node::Alternation(nodes) => nodes.iter().any(Node::can_match_empty),
+            Node::Repetition { node, min, .. } => *min == 0 || node.can_match_empty(),
+        }
+    }
+}
+
+/// A parsed pattern.
+pub(crate) struct Ast {
+    pub(crate) node: Node,
+    pub(crate) classes: Vec<CharClass>,
+    /// The names of the capture groups, starting with the implicit group 0.
+    pub(crate) names: Vec<Option<String>>,
+}
+
+/// The largest number of repetitions that can be written with `{n,m}`.
+const MAX_REPETITIONS: u32 = 1000;
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Flags {
+    case_insensitive: bool,
+    multi_line: bool,
+    dot_matches_new_line: bool,
+    ignore_whitespace: bool,
+}
+
+pub(crate) fn parse(pattern: &str) -> Result<Ast, RegexError> {
+    let mut parser = Parser {
+        pattern: pattern.chars().collect(),
+        position: 0,
+        classes: Vec::new(),
+        names: vec![None],
+    };
+    let mut flags = Flags::default();
+    let node = parser.parse_alternation(&mut flags)?;
+    if parser.position < parser.pattern.len() {
+        return Err(RegexError::UnopenedGroup(parser.position));
+    }
+    Ok(Ast {
+        node,
+        classes: parser.classes,
+        names: parser.names,
+    })
+}
+
+struct Parser {
+    pattern: Vec<char>,
+    position: usize,
+    classes: Vec<CharClass>,
+    names: Vec<Option<String>>,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.pattern.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += 1;
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Skips whitespace and `#` comments in the `x` mode.
+    fn skip_ignored(&mut self, flags: Flags) {
+        if !flags.ignore_whitespace {
+            return;
+        }
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.position += 1;
+            } else if c == '#' {
+                while !matches!(self.next(), Some('\n') | None) {}
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Parses alternatives until the end of the pattern or a `)`, which is not consumed.
+    fn parse_alternation(&mut self, flags: &mut Flags) -> Result<Node, RegexError> {
+        let mut alternatives = vec![self.parse_concat(flags)?];
+        while self.eat('|') {
+            alternatives.push(self.parse_concat(flags)?);
+        }
+        Ok(if alternatives.len() == 1 {
+            alternatives.pop().unwrap()
+        } else {
+            Node::Alternation(alternatives)
+        })
+    }
+
+    fn parse_concat(&mut self, flags: &mut Flags) -> Result<Node, RegexError> {
+        let mut nodes = Vec::new();
+        loop {
+            self.skip_ignored(*flags);
+            let start = self.position;
+            let node = match self.peek() {
+                None | Some('|' | ')') => break,
+                Some('*' | '+' | '?' | '{') => return Err(RegexError::InvalidRepetition(start)),
+                Some('(') => match self.parse_group(flags)? {
+                    Some(node) => node,
+                    // A flag group like `(?i)` only changes the flags.
+                    None => continue,
+                },
+                Some(_) => self.parse_atom(*flags)?,
+            };
+            let node = self.parse_repetition(node, *flags)?;
+            nodes.push(node);
+        }
+        Ok(match nodes.len() {
+            0 => Node::Empty,
+            1 => nodes.pop().unwrap(),
+            _ => Node::Concat(nodes),
+        })
+    }
+
+    /// Parses the repetition following `node`, if any.
+    fn parse_repetition(&mut self, node: Node, flags: Flags) -> Result<Node, RegexError> {
+        self.skip_ignored(flags);
+        let start = self.position;
+        let (min, max) = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => self.parse_counts(start)?,
+            _ => return Ok(node),
+        };
+        if self.position == start {
+            self.position += 1;
+        }
+        let greedy = !self.eat('?');
+        self.skip_ignored(flags);
+        if matches!(self.peek(), Some('*' | '+' | '?' | '{')) {
+            // Other engines read `a*+` as a possessive repetition, which is not supported, so
+            // a repetition has to be put in a group to be repeated again.
+            return Err(RegexError::InvalidRepetition(self.position));
+        }
+        Ok(Node::Repetition {
+            node: Box::new(node),
+            min,
+            max,
+            greedy,
+        })
+    }
+
+    /// Parses `{n}`, `{n,}` or `{n,m}`.
+    fn parse_counts(&mut self, start: usize) -> Result<(u32, Option<u32>), RegexError> {
+        let error = RegexError::InvalidRepetition(start);
+        self.position += 1;
+        let min = self.parse_number().ok_or(error.clone())?;
+        let max = if self.eat(',') {
+            if self.peek() == Some('}') {
+                None
+            } else {
+                Some(self.parse_number().ok_or(error.clone())?)
+            }
+        } else {
+            Some(min)
+        };
+        if !self.eat('}') || max.is_some_and(|max| max < min) {
+            return Err(error);
+        }
+        if min > MAX_REPETITIONS || max.is_some_and(|max| max > MAX_REPETITIONS) {
+            return Err(RegexError::TooLarge);
+        }
+        Ok((min, max))
+    }
+
+    fn parse_number(&mut self) -> Option<u32> {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+        self.pattern[start..self.position]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .ok()
+    }
+
+    /// Parses a group, returning `None` for a group that only sets flags, like `(?i)`.
+    fn parse_group(&mut self, flags: &mut Flags) -> Result<Option<Node>, RegexError> {
+        let start = self.position;
+        self.position += 1;
+        let mut index = None;
+        let mut group_flags = *flags;
+        if self.eat('?') {
+            if self.eat('P') && !matches!(self.peek(), Some('<')) {
+                return Err(RegexError::InvalidGroupName(self.position));
+            }
+            if self.eat('<') {
+                index = Some(self.parse_group_name()?);
+            } else if !self.eat(':') {
+                let scoped = self.parse_flags(&mut group_flags)?;
+                if !scoped {
+                    *flags = group_flags;
+                    return Ok(None);
+                }
+            }
+        } else {
+            index = Some(self.names.len());
+            self.names.push(None);
+        }
+        let node = self.parse_alternation(&mut group_flags)?;
+        if !self.eat(')') {
+            return Err(RegexError::UnclosedGroup(start));
+        }
+        Ok(Some(match index {
+            Some(index) => Node::Capture(index, Box::new(node)),
+            None => node,
+        }))
+    }
+
+    /// Parses the name of a `(?<name>...)` group after the `<` and registers the group.
+    fn parse_group_name(&mut self) -> Result<usize, RegexError> {
+        let start = self.position;
+        let mut name = String::new();
+        loop {
+            match self.next() {
+                Some('>') => break,
+                Some(c) if c == '_' || c.is_ascii_alphanumeric() => name.push(c),
+                _ => return Err(RegexError::InvalidGroupName(start)),
+            }
+        }
+        if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(RegexError::InvalidGroupName(start));
+        }
+        if self.names.iter().flatten().any(|other| *other == name) {
+            return Err(RegexError::DuplicateGroupName(name));
+        }
+        self.names.push(Some(name));
+        Ok(self.names.len() - 1)
+    }
+
+    /// Parses the flags of `(?flags)` or `(?flags:...)`, returning `true` if they are followed
+    /// by a `:` and only apply to the group.
+    fn parse_flags(&mut self, flags: &mut Flags) -> Result<bool, RegexError> {
+        let mut enable = true;
+        loop {
+            let position = self.position;
+            let flag = match self.next() {
+                Some(':') => return Ok(true),
+                Some(')') => return Ok(false),
+                Some('-') if enable => {
+                    enable = false;
+                    continue;
+                }
+                Some('i') => &mut flags.case_insensitive,
+                Some('m') => &mut flags.multi_line,
+                Some('s') => &mut flags.dot_matches_new_line,
+                Some('x') => &mut flags.ignore_whitespace,
+                Some(_) => return Err(RegexError::InvalidFlag(position)),
+                None => return Err(RegexError::UnclosedGroup(position)),
+            };
+            *flag = enable;
+        }
+    }
+
+    fn parse_atom(&mut self, flags: Flags) -> Result<Node, RegexError> {
+        let start = self.position;
+        let c = self.next().unwrap();
+        let class = match c {
+            '.' if flags.dot_matches_new_line => CharClass::all(),
+            '.' => CharClass::single('\n').negate(),
+            '^' => {
+                return Ok(Node::Look(if flags.multi_line {
+                    Look::LineStart
+                } else {
+                    Look::Start
+                }))
+            }
+            '$' => {
+                return Ok(Node::Look(if flags.multi_line {
+                    Look::LineEnd
+                } else {
+                    Look::End
+                }))
+            }
+            '[' => self.parse_class(start, flags)?,
+            '\\' => match self.parse_escape(start)? {
+                Escape::Char(c) => CharClass::single(c),
+                Escape::Class(class) => class,
+                Escape::Look(look) => return Ok(Node::Look(look)),
+            },
+            c => CharClass::single(c),
+        };
+        Ok(self.class_node(class, flags))
+    }
+
+    /// Creates the node of a class, applying case folding if needed.
+    fn class_node(&mut self, class: CharClass, flags: Flags) -> Node {
+        let class = if flags.case_insensitive {
+            class.case_fold()
+        } else {
+            class
+        };
+        match class.as_single() {
+            Some(c) => Node::Char(c),
+            None => {
+                self.classes.push(class);
+                Node::Class(self.classes.len() - 1)
+            }
+        }
+    }
+
+    /// Parses a bracketed class after the `[`.
+    fn parse_class(&mut self, start: usize, flags: Flags) -> Result<CharClass, RegexError> {
+        let negated = self.eat('^');
+        let mut class = self.parse_class_items(start, flags, true)?;
+        if flags.case_insensitive {
+            class = class.case_fold();
+        }
+        Ok(if negated { class.negate() } else { class })
+    }
+
+    /// Parses the items of a bracketed class up to and including its `]`. A `]` that is the
+    /// first item is a literal `]`.
+    fn parse_class_items(
+        &mut self,
+        start: usize,
+        flags: Flags,
+        mut first: bool,
+    ) -> Result<CharClass, RegexError> {
+        let mut class = CharClass::default();
+        loop {
+            let position = self.position;
+            let item = match self.next() {
+                None => return Err(RegexError::UnclosedClass(start)),
+                Some(']') if !first => return Ok(class),
+                Some('&') if self.eat('&') => {
+                    // `&&` intersects the items before it with the items after it.
+                    let rest = self.parse_class_items(start, flags, false)?;
+                    return Ok(class.intersection(&rest));
+                }
+                Some('[') => Escape::Class(self.parse_class(position, flags)?),
+                Some('\\') => self.parse_escape(position)?,
+                Some(c) => Escape::Char(c),
+            };
+            first = false;
+            let item = match item {
+                Escape::Char(low)
+                    if self.peek() == Some('-')
+                        && !matches!(self.pattern.get(self.position + 1), Some(']') | None) =>
+                {
+                    self.position += 1;
+                    let high_position = self.position;
+                    let high = match self.next() {
+                        Some('\\') => self.parse_escape(high_position)?,
+                        Some(c) => Escape::Char(c),
+                        None => return Err(RegexError::UnclosedClass(start)),
+                    };
+                    match high {
+                        Escape::Char(high) if low <= high => CharClass::new(vec![(low, high)]),
+                        _ => return Err(RegexError::InvalidClassRange(position)),
+                    }
+                }
+                Escape::Char(c) => CharClass::single(c),
+                Escape::Class(class) => class,
+                Escape::Look(_) => return Err(RegexError::InvalidEscape(position)),
+            };
+            class = class.union(&item);
+        }
+    }
+
+    /// Parses an escape after the `\`.
+    fn parse_escape(&mut self, start: usize) -> Result<Escape, RegexError> {
+        let error = RegexError::InvalidEscape(start);
+        let c = self.next().ok_or(error.clone())?;
+        Ok(Escape::Char(match c {
+            'd' => return Ok(Escape::Class(CharClass::digit())),
+            'D' => return Ok(Escape::Class(CharClass::digit().negate())),
+            's' => return Ok(Escape::Class(CharClass::space())),
+            'S' => return Ok(Escape::Class(CharClass::space().negate())),
+            'w' => return Ok(Escape::Class(CharClass::word())),
+            'W' => return Ok(Escape::Class(CharClass::word().negate())),
+            'p' | 'P' => {
+                let name = if self.eat('{') {
+                    self.take_until('}').ok_or(error)?
+                } else {
+                    self.next().ok_or(error)?.to_string()
+                };
+                let class = CharClass::property(&name).ok_or(RegexError::UnknownProperty(name))?;
+                return Ok(Escape::Class(if c == 'P' { class.negate() } else { class }));
+            }
+            'A' => return Ok(Escape::Look(Look::Start)),
+            'z' => return Ok(Escape::Look(Look::End)),
+            'b' => return Ok(Escape::Look(Look::WordBoundary)),
+            'B' => return Ok(Escape::Look(Look::NotWordBoundary)),
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            'f' => '\x0C',
+            'v' => '\x0B',
+            'a' => '\x07',
+            'e' => '\x1B',
+            '0' => '\0',
+            'x' | 'u' => {
+                let digits = if self.eat('{') {
+                    self.take_until('}').ok_or(error.clone())?
+                } else {
+                    let count = if c == 'x' { 2 } else { 4 };
+                    let end = self.position + count;
+                    let digits = self.pattern.get(self.position..end).ok_or(error.clone())?;
+                    self.position = end;
+                    digits.iter().collect()
+                };
+                u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .filter(|_| !digits.starts_with('+'))
+                    .and_then(char::from_u32)
+                    .ok_or(error)?
+            }
+            'N' => {
+                if !self.eat('{') {
+                    return Err(error);
+                }
+                let name = self.take_until('}').ok_or(error)?;
+                ucd::lookup(&name).ok_or(RegexError::UnknownCharName(name))?
+            }
+            c if c.is_ascii_punctuation() || c == ' ' => c,
+            _ => return Err(error),
+        }))
+    }
+
+    /// Returns the chars up to `end`, and consumes them and `end`.
+    fn take_until(&mut self, end: char) -> Option<String> {
+        let length = self.pattern[self.position..]
+            .iter()
+            .position(|&c| c == end)?;
+        let value = self.pattern[self.position..self.position + length]
+            .iter()
+            .collect();
+        self.position += length + 1;
+        Some(value)
+    }
+}
+
+enum Escape {
+    Char(char),
+    Class(CharClass),
+    Look(Look),
+}
@@ -0,0 +1,233 @@
+//! A Pike VM, which runs all the threads of the NFA in lockstep, so a search takes time
+//! proportional to the length of the text times the size of the program.
+
+use crate::regex::class::is_word_char;
+use crate::regex::compile::{Inst, Program};
+use crate::regex::parse::Look;
+
+/// The threads at one position of the text, in priority order, with their capture slots.
+struct Threads {
+    /// The instructions of the threads, in priority order.
+    dense: Vec<usize>,
+    /// The position of each instruction in `dense`, which is only valid if it points back.
+    sparse: Vec<usize>,
+    /// The slots of the thread at each instruction.
+    slots: Vec<Option<usize>>,
+    slots_per_thread: usize,
+}
+
+impl Threads {
+    fn new(len: usize, slots_per_thread: usize) -> Threads {
+        Threads {
+            dense: Vec::with_capacity(len),
+            sparse: vec![0; len],
+            slots: vec![None; len * slots_per_thread],
+            slots_per_thread,
+        }
+    }
+
+    #[inline]
+    fn contains(&self, pc: usize) -> bool {
+        let index = self.sparse[pc];
+        index < self.dense.len() && self.dense[index] == pc
+    }
+
+    #[inline]
+    fn insert(&mut self, pc: usize) {
+        self.sparse[pc] = self.dense.len();
+        self.dense.push(pc);
+    }
+
+    #[inline]
+    fn slots_mut(&mut self, pc: usize) -> &mut [Option<usize>] {
+        &mut self.slots[pc * self.slots_per_thread..(pc + 1) * self.slots_per_thread]
+    }
+}
+
+enum Frame {
+    Explore(usize),
+    Restore(usize, Option<usize>),
+}
+
+/// Searches `chars` for a match starting at or after `start`, filling `slots` with the
+/// positions of the capture groups of the leftmost-first match. Only as many slots as `slots`
+/// has are tracked. If `earliest` is set, the search stops as soon as any match is found.
+pub(crate) fn search(
+    program: &Program,
+    chars: &[char],
+    start: usize,
+    slots: &mut [Option<usize>],
+    earliest: bool,
+) -> bool {
+    let len = program.insts.len();
+    let mut vm = Vm {
+        program,
+        chars,
+        stack: Vec::new(),
+    };
+    let mut current = Threads::new(len, slots.len());
+    let mut next = Threads::new(len, slots.len());
+    let mut scratch = vec![None; slots.len()];
+    let mut matched = false;
+    let mut at = start;
+    loop {
+        if !matched && (at == start || !program.anchored) {
+            scratch.fill(None);
+            vm.add_thread(&mut current, &mut scratch, 0, at);
+        }
+        if current.dense.is_empty() {
+            break;
+        }
+        for index in 0..current.dense.len() {
+            let pc = current.dense[index];
+            let matches = match program.insts[pc] {
+                Inst::Match(_) => {
+                    slots.copy_from_slice(current.slots_mut(pc));
+                    matched = true;
+                    if earliest {
+                        return true;
+                    }
+                    // The remaining threads have a lower priority.
+                    break;
+                }
+                _ => vm.step(pc, at),
+            };
+            if matches {
+                scratch.copy_from_slice(current.slots_mut(pc));
+                vm.add_thread(&mut next, &mut scratch, pc + 1, at + 1);
+            }
+        }
+        if at >= chars.len() {
+            break;
+        }
+        at += 1;
+        std::mem::swap(&mut current, &mut next);
+        next.dense.clear();
+    }
+    matched
+}
+
+/// Returns the indices of the patterns of a program made of several patterns that match the
+/// whole of `chars`, in increasing order.
+pub(crate) fn matching_patterns(program: &Program, chars: &[char]) -> Vec<usize> {
+    let len = program.insts.len();
+    let mut vm = Vm {
+        program,
+        chars,
+        stack: Vec::new(),
+    };
+    let mut current = Threads::new(len, 0);
+    let mut next = Threads::new(len, 0);
+    vm.add_thread(&mut current, &mut [], 0, 0);
+    for at in 0..chars.len() {
+        for index in 0..current.dense.len() {
+            let pc = current.dense[index];
+            if vm.step(pc, at) {
+                vm.add_thread(&mut next, &mut [], pc + 1, at + 1);
+            }
+        }
+        if next.dense.is_empty() {
+            return Vec::new();
+        }
+        std::mem::swap(&mut current, &mut next);
+        next.dense.clear();
+    }
+    let mut patterns = current
+        .dense
+        .iter()
+        .filter_map(|&pc| match program.insts[pc] {
+            Inst::Match(pattern) => Some(pattern),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    patterns.sort_unstable();
+    patterns
+}
+
+struct Vm<'a> {
+    program: &'a Program,
+    chars: &'a [char],
+    stack: Vec<Frame>,
+}
+
+impl Vm<'_> {
+    /// Adds the thread at `pc` and all the threads reachable from it without consuming a char,
+    /// in priority order.
+    fn add_thread(
+        &mut self,
+        threads: &mut Threads,
+        slots: &mut [Option<usize>],
+        pc: usize,
+        at: usize,
+    ) {
+        self.stack.push(Frame::Explore(pc));
+        while let Some(frame) = self.stack.pop() {
+            let mut pc = match frame {
+                Frame::Explore(pc) => pc,
+                Frame::Restore(slot, value) => {
+                    slots[slot] = value;
+                    continue;
+                }
+            };
+            loop {
+                if threads.contains(pc) {
+                    break;
+                }
+                threads.insert(pc);
+                match self.program.insts[pc] {
+                    Inst::Jump(target) => pc = target,
+                    Inst::Split(first, second) => {
+                        self.stack.push(Frame::Explore(second));
+                        pc = first;
+                    }
+                    Inst::Save(slot) => {
+                        if slot < slots.len() {
+                            self.stack.push(Frame::Restore(slot, slots[slot]));
+                            slots[slot] = Some(at);
+                        }
+                        pc += 1;
+                    }
+                    Inst::Look(look) => {
+                        if !self.look(look, at) {
+                            break;
+                        }
+                        pc += 1;
+                    }
+                    Inst::Char(_) | Inst::Class(_) | Inst::Any | Inst::Match(_) => {
+                        threads.slots_mut(pc).copy_from_slice(slots);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Checks if the instruction at `pc` matches the char at `at`.
+    #[inline]
+    fn step(&self, pc: usize, at: usize) -> bool {
+        match self.program.insts[pc] {
+            Inst::Char(c) => self.chars.get(at) == Some(&c),
+            Inst::Class(class) => self
+                .chars
+                .get(at)
+                .is_some_and(|&c| self.program.classes[class].contains(c)),
+            Inst::Any => at < self.chars.len(),
+            _ => false,
+        }
+    }
+
+    fn look(&self, look: Look, at: usize) -> bool {
+        let chars = self.chars;
+        match look {
+            Look::Start => at == 0,
+            Look::End => at == chars.len(),
+            Look::LineStart => at == 0 || chars[at - 1] == '\n',
+            Look::LineEnd => at == chars.len() || chars[at] == '\n',
+            Look::WordBoundary | Look::NotWordBoundary => {
+                let before = at > 0 && is_word_char(chars[at - 1]);
+                let after = at < chars.len() && is_word_char(chars[at]);
+                (before != after) == (look == Look::WordBoundary)
+            }
+        }
+    }
+}
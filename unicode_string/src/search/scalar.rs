@@ -0,0 +1,23 @@
+/// Returns the first position at or after `start` where `first` is found in `haystack` and
+/// `last` is found `gap` chars further.
+#[inline]
+pub(crate) fn find_pair(
+    haystack: &[char],
+    first: char,
+    last: char,
+    gap: usize,
+    start: usize,
+) -> Option<usize> {
+    let end = haystack.len().checked_sub(gap)?;
+    (start..end).find(|&position| haystack[position] == first && haystack[position + gap] == last)
+}
+
+/// Returns the first position at or after `start` where `c` is found in `haystack`.
+#[inline]
+pub(crate) fn find_char(haystack: &[char], c: char, start: usize) -> Option<usize> {
+    haystack
+        .get(start..)?
+        .iter()
+        .position(|&other| other == c)
+        .map(|index| start + index)
+}
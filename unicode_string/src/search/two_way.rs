@@ -0,0 +1,132 @@
+use std::cmp;
+
+/// The Two-Way string matching algorithm of Crochemore and Perrin, which finds a needle in
+/// linear time and constant space, following the implementation of `str::find`.
+///
+/// The needle is split at a critical position into a left part and a right part. The right
+/// part is compared first, from left to right; on a mismatch the needle is shifted past the
+/// mismatched char. If the right part matches, the left part is compared from right to left,
+/// and on a mismatch the needle is shifted by its period.
+#[derive(Clone, Debug)]
+pub(crate) struct TwoWay {
+    /// The critical position, where the needle is split.
+    critical_position: usize,
+    /// The shift after the left part mismatched. It is the period of the needle if the needle
+    /// is periodic, in which case `memory` is used.
+    period: usize,
+    /// A set of the chars of the needle, hashed by their low 6 bits, to shift past a whole
+    /// needle when the char at the end of a window is not in it.
+    char_set: u64,
+    /// Whether the needle has a long period, in which case the position of the last partial
+    /// match is not remembered.
+    long_period: bool,
+}
+
+impl TwoWay {
+    pub(crate) fn new(needle: &[char]) -> TwoWay {
+        let (position_less, period_less) = maximal_suffix(needle, false);
+        let (position_greater, period_greater) = maximal_suffix(needle, true);
+        let (critical_position, period) = if position_less > position_greater {
+            (position_less, period_less)
+        } else {
+            (position_greater, period_greater)
+        };
+        let char_set = needle.iter().fold(0, |set, &c| set | char_bit(c));
+        if needle[..critical_position] == needle[period..period + critical_position] {
+            TwoWay {
+                critical_position,
+                period,
+                char_set,
+                long_period: false,
+            }
+        } else {
+            // The needle has no useful period, so a shift past the longest part is safe.
+            TwoWay {
+                critical_position,
+                period: cmp::max(critical_position, needle.len() - critical_position) + 1,
+                char_set,
+                long_period: true,
+            }
+        }
+    }
+
+    /// Returns the index of the first occurrence of `needle`, which must be the needle this
+    /// searcher was created for, in `haystack` at or after `start`.
+    pub(crate) fn find(&self, haystack: &[char], needle: &[char], start: usize) -> Option<usize> {
+        let last = needle.len() - 1;
+        let mut position = start;
+        // The length of the prefix of the needle known to match at `position`, for periodic
+        // needles.
+        let mut memory = 0;
+        'search: loop {
+            let &tail = haystack.get(position + last)?;
+            if self.char_set & char_bit(tail) == 0 {
+                position += needle.len();
+                memory = 0;
+                continue 'search;
+            }
+
+            let right_start = if self.long_period {
+                self.critical_position
+            } else {
+                cmp::max(self.critical_position, memory)
+            };
+            for i in right_start..needle.len() {
+                if needle[i] != haystack[position + i] {
+                    position += i - self.critical_position + 1;
+                    memory = 0;
+                    continue 'search;
+                }
+            }
+
+            let left_end = if self.long_period { 0 } else { memory };
+            for i in (left_end..self.critical_position).rev() {
+                if needle[i] != haystack[position + i] {
+                    position += self.period;
+                    if !self.long_period {
+                        memory = needle.len() - self.period;
+                    }
+                    continue 'search;
+                }
+            }
+            return Some(position);
+        }
+    }
+}
+
+#[inline]
+fn char_bit(c: char) -> u64 {
+    1 << (c as u32 & 0x3F)
+}
+
+/// Returns the start of the maximal suffix of `needle` for the order of chars, or the
+/// reversed order if `reversed` is set, and the period of that suffix.
+fn maximal_suffix(needle: &[char], reversed: bool) -> (usize, usize) {
+    let mut left = 0;
+    let mut right = 1;
+    let mut offset = 0;
+    let mut period = 1;
+    while let Some(&a) = needle.get(right + offset) {
+        let b = needle[left + offset];
+        if (a < b && !reversed) || (a > b && reversed) {
+            // The suffix is smaller, so the period is the whole prefix so far.
+            right += offset + 1;
+            offset = 0;
+            period = right - left;
+        } else if a == b {
+            if offset + 1 == period {
+                right += offset + 1;
+                offset = 0;
+            } else {
+                offset += 1;
+            }
+        } else {
+            // The suffix is larger, so it starts at the current position.
+            left = right;
+            right += 1;
+            offset = 0;
+            period = 1;
+        }
+    }
+    (left, period)
+}
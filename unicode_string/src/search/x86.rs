@@ -0,0 +1,204 @@
+//! SSE2 and AVX2 versions of the prefilter and of equality, which compare 4 or 8 chars at
+//! once as `u32` lanes.
+
+use std::arch::x86_64::*;
+
+use crate::search::scalar;
+
+/// Like [`scalar::find_pair`], comparing 8 positions at once.
+///
+/// # Safety
+///
+/// The CPU must support AVX2.
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn find_pair_avx2(
+    haystack: &[char],
+    first: char,
+    last: char,
+    gap: usize,
+    start: usize,
+) -> Option<usize> {
+    let end = haystack.len().checked_sub(gap)?;
+    let pointer = haystack.as_ptr();
+    let firsts = _mm256_set1_epi32(first as i32);
+    let lasts = _mm256_set1_epi32(last as i32);
+    let mut position = start;
+    while position + 8 <= end {
+        // SAFETY: `position + gap + 8 <= haystack.len()`, so both loads are in bounds.
+        let (a, b) = unsafe {
+            (
+                _mm256_loadu_si256(pointer.add(position).cast()),
+                _mm256_loadu_si256(pointer.add(position + gap).cast()),
+            )
+        };
+        let matches = _mm256_and_si256(_mm256_cmpeq_epi32(a, firsts), _mm256_cmpeq_epi32(b, lasts));
+        let mask = _mm256_movemask_ps(_mm256_castsi256_ps(matches));
+        if mask != 0 {
+            return Some(position + mask.trailing_zeros() as usize);
+        }
+        position += 8;
+    }
+    scalar::find_pair(haystack, first, last, gap, position)
+}
+
+/// Like [`scalar::find_pair`], comparing 4 positions at once.
+///
+/// # Safety
+///
+/// The CPU must support SSE2, which all x86-64 CPUs do.
+#[target_feature(enable = "sse2")]
+pub(crate) unsafe fn find_pair_sse2(
+    haystack: &[char],
+    first: char,
+    last: char,
+    gap: usize,
+    start: usize,
+) -> Option<usize> {
+    let end = haystack.len().checked_sub(gap)?;
+    let pointer = haystack.as_ptr();
+    let firsts = _mm_set1_epi32(first as i32);
+    let lasts = _mm_set1_epi32(last as i32);
+    let mut position = start;
+    while position + 4 <= end {
+        // SAFETY: `position + gap + 4 <= haystack.len()`, so both loads are in bounds.
+        let (a, b) = unsafe {
+            (
+                _mm_loadu_si128(pointer.add(position).cast()),
+                _mm_loadu_si128(pointer.add(position + gap).cast()),
+            )
+        };
+        let matches = _mm_and_si128(_mm_cmpeq_epi32(a, firsts), _mm_cmpeq_epi32(b, lasts));
+        let mask = _mm_movemask_ps(_mm_castsi128_ps(matches));
+        if mask != 0 {
+            return Some(position + mask.trailing_zeros() as usize);
+        }
+        position += 4;
+    }
+    scalar::find_pair(haystack, first, last, gap, position)
+}
+
+/// Like [`scalar::find_char`], comparing 32 positions at a time.
+///
+/// # Safety
+///
+/// The CPU must support AVX2.
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn find_char_avx2(haystack: &[char], c: char, start: usize) -> Option<usize> {
+    let pointer = haystack.as_ptr();
+    let chars = _mm256_set1_epi32(c as i32);
+    let mut position = start;
+    while position + 32 <= haystack.len() {
+        // SAFETY: `position + 32 <= haystack.len()`, so all the loads are in bounds.
+        let [a, b, c, d] = unsafe {
+            [0, 8, 16, 24].map(|offset| {
+                _mm256_cmpeq_epi32(
+                    _mm256_loadu_si256(pointer.add(position + offset).cast()),
+                    chars,
+                )
+            })
+        };
+        let any = _mm256_or_si256(_mm256_or_si256(a, b), _mm256_or_si256(c, d));
+        if _mm256_testz_si256(any, any) == 0 {
+            let masks =
+                [a, b, c, d].map(|matches| _mm256_movemask_ps(_mm256_castsi256_ps(matches)) as u32);
+            let mask = masks[0] | masks[1] << 8 | masks[2] << 16 | masks[3] << 24;
+            return Some(position + mask.trailing_zeros() as usize);
+        }
+        position += 32;
+    }
+    scalar::find_char(haystack, c, position)
+}
+
+/// Like [`scalar::find_char`], comparing 16 positions at a time.
+///
+/// # Safety
+///
+/// The CPU must support SSE2, which all x86-64 CPUs do.
+#[target_feature(enable = "sse2")]
+pub(crate) unsafe fn find_char_sse2(haystack: &[char], c: char, start: usize) -> Option<usize> {
+    let pointer = haystack.as_ptr();
+    let chars = _mm_set1_epi32(c as i32);
+    let mut position = start;
+    while position + 16 <= haystack.len() {
+        // SAFETY: `position + 16 <= haystack.len()`, so all the loads are in bounds.
+        let [a, b, c, d] = unsafe {
+            [0, 4, 8, 12].map(|offset| {
+                _mm_cmpeq_epi32(
+                    _mm_loadu_si128(pointer.add(position + offset).cast()),
+                    chars,
+                )
+            })
+        };
+        let any = _mm_or_si128(_mm_or_si128(a, b), _mm_or_si128(c, d));
+        if _mm_movemask_epi8(any) != 0 {
+            let masks =
+                [a, b, c, d].map(|matches| _mm_movemask_ps(_mm_castsi128_ps(matches)) as u32);
+            let mask = masks[0] | masks[1] << 4 | masks[2] << 8 | masks[3] << 12;
+            return Some(position + mask.trailing_zeros() as usize);
+        }
+        position += 16;
+    }
+    scalar::find_char(haystack, c, position)
+}
+
+/// Checks if `a` and `b`, which have the same length, are equal, comparing 32 chars at a
+/// time.
+///
+/// # Safety
+///
+/// The CPU must support AVX2.
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn eq_avx2(a: &[char], b: &[char]) -> bool {
+    debug_assert_eq!(a.len(), b.len());
+    let (a_pointer, b_pointer) = (a.as_ptr(), b.as_ptr());
+    let mut position = 0;
+    while position + 32 <= a.len() {
+        // SAFETY: `position + 32 <= a.len() == b.len()`, so all the loads are in bounds.
+        let differences = unsafe {
+            [0, 8, 16, 24].map(|offset| {
+                _mm256_xor_si256(
+                    _mm256_loadu_si256(a_pointer.add(position + offset).cast()),
+                    _mm256_loadu_si256(b_pointer.add(position + offset).cast()),
+                )
+            })
+        };
+        let [w, x, y, z] = differences;
+        let any = _mm256_or_si256(_mm256_or_si256(w, x), _mm256_or_si256(y, z));
+        if _mm256_testz_si256(any, any) == 0 {
+            return false;
+        }
+        position += 32;
+    }
+    a[position..] == b[position..]
+}
+
+/// Checks if `a` and `b`, which have the same length, are equal, comparing 16 chars at a
+/// time.
+///
+/// # Safety
+///
+/// The CPU must support SSE2, which all x86-64 CPUs do.
+#[target_feature(enable = "sse2")]
+pub(crate) unsafe fn eq_sse2(a: &[char], b: &[char]) -> bool {
+    debug_assert_eq!(a.len(), b.len());
+    let (a_pointer, b_pointer) = (a.as_ptr(), b.as_ptr());
+    let mut position = 0;
+    while position + 16 <= a.len() {
+        // SAFETY: `position + 16 <= a.len() == b.len()`, so all the loads are in bounds.
+        let equal = unsafe {
+            [0, 4, 8, 12].map(|offset| {
+                _mm_cmpeq_epi32(
+                    _mm_loadu_si128(a_pointer.add(position + offset).cast()),
+                    _mm_loadu_si128(b_pointer.add(position + offset).cast()),
+                )
+            })
+        };
+        let [w, x, y, z] = equal;
+        let all = _mm_and_si128(_mm_and_si128(w, x), _mm_and_si128(y, z));
+        if _mm_movemask_epi8(all) != 0xFFFF {
+            return false;
+        }
+        position += 16;
+    }
+    a[position..] == b[position..]
+}
@@ -0,0 +1,132 @@
+use crate::normalization::nfd;
+use crate::security::mixed_script::AugmentedScriptSet;
+use crate::tables::confusables::CONFUSABLES;
+use crate::{unicode_str, UnicodeString};
+
+/// Returns the prototype `c` is visually confusable with, or `None` if `c` is its own
+/// prototype.
+#[inline]
+fn prototype(c: char) -> Option<&'static [char]> {
+    CONFUSABLES
+        .binary_search_by_key(&c, |&(code, _)| code)
+        .ok()
+        .map(|index| CONFUSABLES[index].1)
+}
+
+/// Returns the skeleton of `chars`: their canonical decomposition, with every char replaced by
+/// its prototype and decomposed again.
+fn skeleton_chars(chars: &[char]) -> Vec<char> {
+    let mut mapped = Vec::with_capacity(chars.len());
+    for c in nfd(chars) {
+        match prototype(c) {
+            Some(prototype) => mapped.extend_from_slice(prototype),
+            None => mapped.push(c),
+        }
+    }
+    nfd(&mapped)
+}
+
+/// Returns the skeleton of `s` (UTS #39, section 4).
+///
+/// Strings that look the same have the same skeleton, so skeletons can be stored alongside
+/// usernames or identifiers to find existing ones a new one could be confused with. The
+/// skeleton is only meant to be compared: it is not a readable or normalized form of `s`, and
+/// it can mix scripts even if `s` does not.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::security;
+/// use unicode_string::ustr;
+///
+/// assert_eq!(security::skeleton(ustr!("paypal")), ustr!("paypal"));
+///
+/// // CYRILLIC SMALL LETTER ER and CYRILLIC SMALL LETTER A.
+/// assert_eq!(security::skeleton(ustr!("раypal")), ustr!("paypal"));
+///
+/// // The digit one and the capital I both look like a lowercase L.
+/// assert_eq!(security::skeleton(ustr!("1Il")), ustr!("lll"));
+/// ```
+pub fn skeleton(s: &unicode_str) -> UnicodeString {
+    UnicodeString {
+        vec: skeleton_chars(s.chars()),
+    }
+}
+
+/// Checks if `a` and `b` are visually confusable, i.e. if they have the same [`skeleton`].
+///
+/// Note that a string is confusable with itself.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::security;
+/// use unicode_string::ustr;
+///
+/// assert!(security::is_confusable(ustr!("paypal"), ustr!("paypa1")));
+/// assert!(security::is_confusable(ustr!("scope"), ustr!("ѕсоре")));
+/// assert!(!security::is_confusable(ustr!("paypal"), ustr!("ebay")));
+/// ```
+pub fn is_confusable(a: &unicode_str, b: &unicode_str) -> bool {
+    skeleton_chars(a.chars()) == skeleton_chars(b.chars())
+}
+
+/// Checks if `a` and `b` are mixed-script confusables: they are [confusable](is_confusable)
+/// but have no script in common (UTS #39, section 4).
+///
+/// This is the case of a Latin name spoofed with some Cyrillic letters, but not of a name
+/// spoofed with a digit that looks like a letter.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::security;
+/// use unicode_string::ustr;
+///
+/// // The second letter is CYRILLIC SMALL LETTER A.
+/// assert!(security::is_mixed_script_confusable(ustr!("paypal"), ustr!("pаypal")));
+/// assert!(!security::is_mixed_script_confusable(ustr!("paypal"), ustr!("paypa1")));
+/// ```
+pub fn is_mixed_script_confusable(a: &unicode_str, b: &unicode_str) -> bool {
+    is_confusable(a, b)
+        && AugmentedScriptSet::resolve(a.chars())
+            .intersection(AugmentedScriptSet::resolve(b.chars()))
+            .is_empty()
+}
+
+/// Checks if `a` and `b` are whole-script confusables: they are
+/// [mixed-script confusables](is_mixed_script_confusable) and each of them is written in a
+/// single script (UTS #39, section 4).
+///
+/// These are the hardest spoofs to notice, since neither string looks out of place on its own.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::security;
+/// use unicode_string::ustr;
+///
+/// // Only Cyrillic letters.
+/// assert!(security::is_whole_script_confusable(ustr!("scope"), ustr!("ѕсоре")));
+///
+/// // Latin and Cyrillic letters.
+/// assert!(!security::is_whole_script_confusable(ustr!("paypal"), ustr!("pаypal")));
+/// ```
+pub fn is_whole_script_confusable(a: &unicode_str, b: &unicode_str) -> bool {
+    if !is_confusable(a, b) {
+        return false;
+    }
+    let (a, b) = (
+        AugmentedScriptSet::resolve(a.chars()),
+        AugmentedScriptSet::resolve(b.chars()),
+    );
+    !a.is_empty() && !b.is_empty() && a.intersection(b).is_empty()
+}
@@ -0,0 +1,90 @@
+use crate::ucd::script_set::ScriptSet;
+use crate::ucd::{is_shared, script_extensions, Script};
+use crate::unicode_str;
+
+/// An augmented script set (UTS #39, section 5.1): the script extensions of a char, plus the
+/// writing systems that combine several scripts, Han with Bopomofo (`Hanb`), Japanese (`Jpan`)
+/// and Korean (`Kore`).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct AugmentedScriptSet {
+    pub(crate) scripts: ScriptSet,
+    pub(crate) hanb: bool,
+    pub(crate) jpan: bool,
+    pub(crate) kore: bool,
+}
+
+impl AugmentedScriptSet {
+    /// Returns the set containing every script, which is the set of chars used with any script.
+    pub(crate) fn all() -> AugmentedScriptSet {
+        AugmentedScriptSet {
+            scripts: ScriptSet::all(),
+            hanb: true,
+            jpan: true,
+            kore: true,
+        }
+    }
+
+    /// Returns the augmented script set of `c`.
+    pub(crate) fn of(c: char) -> AugmentedScriptSet {
+        let extensions = script_extensions(c);
+        if is_shared(extensions) {
+            return AugmentedScriptSet::all();
+        }
+        let scripts = ScriptSet::new(extensions);
+        let han = scripts.contains(Script::Han);
+        AugmentedScriptSet {
+            scripts,
+            hanb: han || scripts.contains(Script::Bopomofo),
+            jpan: han || scripts.contains(Script::Hiragana) || scripts.contains(Script::Katakana),
+            kore: han || scripts.contains(Script::Hangul),
+        }
+    }
+
+    /// Returns the resolved script set of `chars`, the intersection of the augmented script
+    /// sets of all its chars.
+    pub(crate) fn resolve(chars: &[char]) -> AugmentedScriptSet {
+        chars.iter().fold(AugmentedScriptSet::all(), |set, &c| {
+            set.intersection(AugmentedScriptSet::of(c))
+        })
+    }
+
+    pub(crate) fn intersection(self, other: AugmentedScriptSet) -> AugmentedScriptSet {
+        AugmentedScriptSet {
+            scripts: self.scripts.intersection(other.scripts),
+            hanb: self.hanb && other.hanb,
+            jpan: self.jpan && other.jpan,
+            kore: self.kore && other.kore,
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.scripts.is_empty() && !self.hanb && !self.jpan && !self.kore
+    }
+}
+
+/// Checks if `s` is mixed-script, i.e. if no single script or writing system covers all of its
+/// chars (UTS #39, section 5.1).
+///
+/// Chars used with any script, like digits and punctuation, do not make a string mixed-script,
+/// and neither do chars whose [script extensions](crate::ucd::script_extensions) share a
+/// script with the other chars. Japanese text mixing Han, Hiragana and Katakana and Korean text
+/// mixing Han and Hangul are single-script.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::security;
+/// use unicode_string::ustr;
+///
+/// assert!(!security::is_mixed_script(ustr!("paypal")));
+/// assert!(!security::is_mixed_script(ustr!("東京とトウキョウ")));
+/// assert!(!security::is_mixed_script(ustr!("123-456")));
+///
+/// // The second letter is CYRILLIC SMALL LETTER A.
+/// assert!(security::is_mixed_script(ustr!("pаypal")));
+/// ```
+pub fn is_mixed_script(s: &unicode_str) -> bool {
+    AugmentedScriptSet::resolve(s.chars()).is_empty()
+}
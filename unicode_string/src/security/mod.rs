@@ -0,0 +1,38 @@
+//! Detection of spoofing with visually confusable strings, following
+//! [UTS #39](https://www.unicode.org/reports/tr39/), Unicode Security Mechanisms.
+//!
+//! Two strings are confusable if they have the same [`skeleton`]. Confusables written in
+//! different scripts are the most common spoofing technique, so they can be told apart with
+//! [`is_mixed_script_confusable`] and [`is_whole_script_confusable`], and strings mixing
+//! scripts in unusual ways can be rejected with their [`restriction_level`].
+//!
+//! Text can also be made to look different from what it is with invisible chars and bidi
+//! controls that reorder it, as in Trojan Source attacks. [`scan`] reports these chars, and
+//! [`sanitize`] removes or escapes them.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use unicode_string::security::{self, RestrictionLevel};
+//! use unicode_string::ustr;
+//!
+//! // The first letter is CYRILLIC SMALL LETTER ER, the second CYRILLIC SMALL LETTER A.
+//! let spoof = ustr!("раypal");
+//! assert!(security::is_confusable(spoof, ustr!("paypal")));
+//! assert!(security::is_mixed_script(spoof));
+//! assert_eq!(security::restriction_level(spoof), RestrictionLevel::MinimallyRestrictive);
+//! ```
+
+mod confusables;
+mod mixed_script;
+mod restriction_level;
+mod sanitize;
+mod scan;
+
+pub use self::confusables::*;
+pub use self::mixed_script::*;
+pub use self::restriction_level::*;
+pub use self::sanitize::*;
+pub use self::scan::*;
@@ -0,0 +1,110 @@
+use crate::security::mixed_script::AugmentedScriptSet;
+use crate::ucd::{script_extensions, Script};
+use crate::unicode_str;
+
+/// The scripts recommended for use in identifiers (UAX #31, table 5), other than `Common` and
+/// `Inherited`.
+const RECOMMENDED_SCRIPTS: &[Script] = &[
+    Script::Arabic,
+    Script::Armenian,
+    Script::Bengali,
+    Script::Bopomofo,
+    Script::Cyrillic,
+    Script::Devanagari,
+    Script::Ethiopic,
+    Script::Georgian,
+    Script::Greek,
+    Script::Gujarati,
+    Script::Gurmukhi,
+    Script::Han,
+    Script::Hangul,
+    Script::Hebrew,
+    Script::Hiragana,
+    Script::Kannada,
+    Script::Katakana,
+    Script::Khmer,
+    Script::Lao,
+    Script::Latin,
+    Script::Malayalam,
+    Script::Myanmar,
+    Script::Oriya,
+    Script::Sinhala,
+    Script::Tamil,
+    Script::Telugu,
+    Script::Thaana,
+    Script::Thai,
+    Script::Tibetan,
+];
+
+/// The restriction level of a string (UTS #39, section 5.2), which describes how it mixes
+/// scripts.
+///
+/// Levels are ordered from the most to the least restrictive, so a string can be checked
+/// against a maximum level with `<=`.
+///
+/// Unlike in UTS #39, the chars themselves are not checked against the identifier profile, so
+/// there is no `Unrestricted` level: strings that should only contain identifier chars must be
+/// checked separately, e.g. with [`ucd::is_xid_continue`](crate::ucd::is_xid_continue).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum RestrictionLevel {
+    /// All the chars are ASCII.
+    AsciiOnly,
+    /// All the chars are in a single script, or in a single writing system like Japanese.
+    SingleScript,
+    /// The string only mixes Latin with Japanese (Han, Hiragana and Katakana), with Han and
+    /// Bopomofo, or with Korean (Han and Hangul).
+    HighlyRestrictive,
+    /// The string only mixes Latin with another script recommended for identifiers, except
+    /// Cyrillic and Greek, whose letters are often confusable with Latin ones.
+    ModeratelyRestrictive,
+    /// The string mixes scripts in other ways.
+    MinimallyRestrictive,
+}
+
+/// Returns the [`RestrictionLevel`] of `s`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::security::{self, RestrictionLevel};
+/// use unicode_string::ustr;
+///
+/// assert_eq!(security::restriction_level(ustr!("paypal")), RestrictionLevel::AsciiOnly);
+/// assert_eq!(security::restriction_level(ustr!("café")), RestrictionLevel::SingleScript);
+/// assert_eq!(security::restriction_level(ustr!("東京とトウキョウ")), RestrictionLevel::SingleScript);
+/// assert_eq!(security::restriction_level(ustr!("Tokyo東京")), RestrictionLevel::HighlyRestrictive);
+/// assert_eq!(security::restriction_level(ustr!("Shalomשלום")), RestrictionLevel::ModeratelyRestrictive);
+/// assert_eq!(security::restriction_level(ustr!("Privetпривет")), RestrictionLevel::MinimallyRestrictive);
+///
+/// // Allow at most one script besides Latin, as long as it is not easily confused with Latin.
+/// let level = security::restriction_level(ustr!("pаypal"));
+/// assert!(level > RestrictionLevel::ModeratelyRestrictive);
+/// ```
+pub fn restriction_level(s: &unicode_str) -> RestrictionLevel {
+    let chars = s.chars();
+    if chars.iter().all(char::is_ascii) {
+        return RestrictionLevel::AsciiOnly;
+    }
+    if !AugmentedScriptSet::resolve(chars).is_empty() {
+        return RestrictionLevel::SingleScript;
+    }
+
+    // The scripts covering the chars that cannot be Latin.
+    let others = chars
+        .iter()
+        .filter(|&&c| !script_extensions(c).contains(&Script::Latin))
+        .fold(AugmentedScriptSet::all(), |set, &c| {
+            set.intersection(AugmentedScriptSet::of(c))
+        });
+    if others.hanb || others.jpan || others.kore {
+        RestrictionLevel::HighlyRestrictive
+    } else if others.scripts.iter().any(|script| {
+        RECOMMENDED_SCRIPTS.contains(&script) && !matches!(script, Script::Cyrillic | Script::Greek)
+    }) {
+        RestrictionLevel::ModeratelyRestrictive
+    } else {
+        RestrictionLevel::MinimallyRestrictive
+    }
+}
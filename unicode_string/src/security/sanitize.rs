@@ -0,0 +1,56 @@
+use std::fmt::Write;
+
+use crate::security::scan;
+use crate::{unicode_str, UnicodeString};
+
+/// What [`sanitize`] does with the suspicious chars.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum SanitizeMode {
+    /// The chars are removed.
+    #[default]
+    Strip,
+    /// The chars are replaced with an escape like `\u{202E}`, which makes them visible.
+    Escape,
+}
+
+/// Removes or escapes the suspicious chars of `s`, i.e. every char reported by [`scan`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::security::{self, SanitizeMode};
+/// use unicode_string::ustr;
+///
+/// let s = ustr!("admin\u{200B}\u{202E}txt.exe");
+/// assert_eq!(security::sanitize(s, SanitizeMode::Strip), ustr!("admintxt.exe"));
+/// assert_eq!(
+///     security::sanitize(s, SanitizeMode::Escape),
+///     ustr!(r"admin\u{200B}\u{202E}txt.exe")
+/// );
+///
+/// // Emoji are left alone.
+/// assert_eq!(security::sanitize(ustr!("👍🏽"), SanitizeMode::Strip), ustr!("👍🏽"));
+///
+/// // Tag chars that are not part of a flag are removed.
+/// let s = ustr!("price: 1\u{E0069}\u{E0067}\u{E007F} USD");
+/// assert_eq!(security::sanitize(s, SanitizeMode::Strip), ustr!("price: 1 USD"));
+/// ```
+pub fn sanitize(s: &unicode_str, mode: SanitizeMode) -> UnicodeString {
+    let chars = s.chars();
+    let mut vec = Vec::with_capacity(chars.len());
+    let mut start = 0;
+    let mut escape = String::new();
+    for finding in scan(s) {
+        vec.extend_from_slice(&chars[start..finding.index]);
+        if mode == SanitizeMode::Escape {
+            escape.clear();
+            let _ = write!(escape, "\\u{{{:X}}}", finding.c as u32);
+            vec.extend(escape.chars());
+        }
+        start = finding.index + 1;
+    }
+    vec.extend_from_slice(&chars[start..]);
+    UnicodeString { vec }
+}
@@ -0,0 +1,274 @@
+use crate::ucd::{self, Script};
+use crate::{unicode_str, BidiClass};
+
+/// How dangerous a [`Finding`] is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// An invisible char with legitimate uses, that should still be reviewed in identifiers.
+    Low,
+    /// An invisible char that can make different strings look the same or hide data.
+    Medium,
+    /// A char that changes the order in which the text around it is displayed.
+    High,
+    /// A char that changes the order in which the text is displayed beyond its own span,
+    /// because it is not terminated, or that terminates something that was not started.
+    Critical,
+}
+
+/// The kind of a suspicious char reported by [`scan`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FindingKind {
+    /// An explicit bidi embedding, override or isolate (U+202A to U+202E and U+2066 to
+    /// U+2069), properly terminated.
+    BidiControl,
+    /// An explicit bidi embedding, override or isolate that is not terminated before the end
+    /// of its paragraph, or a terminator with nothing to terminate.
+    UnbalancedBidiControl,
+    /// A zero width space, joiner or non-joiner, a word joiner or a zero width no-break space.
+    ZeroWidth,
+    /// A tag char (U+E0000 to U+E007F) outside of an emoji tag sequence.
+    Tag,
+    /// A variation selector that does not follow a char it could select a variant of.
+    VariationSelector,
+    /// Another char that is ignored by default when rendering (`Default_Ignorable_Code_Point`),
+    /// like U+00AD SOFT HYPHEN or U+3164 HANGUL FILLER.
+    DefaultIgnorable,
+}
+
+impl FindingKind {
+    /// Returns the severity of findings of this kind.
+    pub const fn severity(self) -> Severity {
+        match self {
+            FindingKind::UnbalancedBidiControl => Severity::Critical,
+            FindingKind::BidiControl => Severity::High,
+            FindingKind::ZeroWidth | FindingKind::Tag | FindingKind::VariationSelector => {
+                Severity::Medium
+            }
+            FindingKind::DefaultIgnorable => Severity::Low,
+        }
+    }
+}
+
+/// A suspicious char found by [`scan`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Finding {
+    /// The char index of the char.
+    pub index: usize,
+    /// The char.
+    pub c: char,
+    /// Why the char was reported.
+    pub kind: FindingKind,
+    /// How dangerous the char is.
+    pub severity: Severity,
+}
+
+/// Finds the invisible and reordering chars of `s` that can be used to make text look different
+/// from what it is, like in Trojan Source attacks, and returns them in order.
+///
+/// Chars used as intended in emoji are not reported: zero width joiners between pictographic
+/// emoji, presentation selectors after pictographs and keycap bases, and the tags of the
+/// subdivision flags, like the flag of Scotland. Other zero width joiners and non-joiners are
+/// reported, although some scripts like Arabic or Devanagari need them in some words.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::security::{self, FindingKind, Severity};
+/// use unicode_string::ustr;
+///
+/// // A RIGHT-TO-LEFT OVERRIDE that is never terminated, and a terminated isolate.
+/// let findings = security::scan(ustr!("access = \"user\u{202E} \u{2066}// admin\u{2069}\""));
+/// assert_eq!(findings.len(), 3);
+/// assert_eq!(findings[0].index, 14);
+/// assert_eq!(findings[0].kind, FindingKind::UnbalancedBidiControl);
+/// assert_eq!(findings[0].severity, Severity::Critical);
+/// assert_eq!(findings[1].index, 16);
+/// assert_eq!(findings[1].kind, FindingKind::BidiControl);
+/// assert_eq!(findings[2].index, 25);
+/// assert_eq!(findings[2].severity, Severity::High);
+///
+/// let findings = security::scan(ustr!("admin\u{200B}"));
+/// assert_eq!(findings[0].kind, FindingKind::ZeroWidth);
+///
+/// // Emoji ZWJ sequences and subdivision flags are fine.
+/// assert!(security::scan(ustr!("👩🏽‍💻")).is_empty());
+/// let scotland = ustr!("🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}");
+/// assert!(security::scan(scotland).is_empty());
+/// ```
+///
+/// Digits, `#`, `*`, `©` and `®` are emoji too, but they cannot be joined, and cannot start
+/// tag sequences, so they do not hide the chars after them:
+///
+/// ```
+/// use unicode_string::security::{self, FindingKind};
+/// use unicode_string::ustr;
+///
+/// assert_eq!(security::scan(ustr!("12\u{200D}34"))[0].kind, FindingKind::ZeroWidth);
+/// assert_eq!(security::scan(ustr!("#\u{200D}#"))[0].kind, FindingKind::ZeroWidth);
+///
+/// // "ig" smuggled in tag chars after a digit.
+/// let findings = security::scan(ustr!("price: 1\u{E0069}\u{E0067}\u{E007F} USD"));
+/// assert_eq!(findings.len(), 3);
+/// assert!(findings.iter().all(|finding| finding.kind == FindingKind::Tag));
+///
+/// assert_eq!(security::scan(ustr!("©\u{E0041}\u{E0042}\u{E007F}")).len(), 3);
+/// ```
+pub fn scan(s: &unicode_str) -> Vec<Finding> {
+    let chars = s.chars();
+    let mut kinds: Vec<Option<FindingKind>> = vec![None; chars.len()];
+    scan_bidi_controls(chars, &mut kinds);
+
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        if kinds[index].is_some() {
+            index += 1;
+            continue;
+        }
+        if ('\u{E0020}'..='\u{E007E}').contains(&c) {
+            if let Some(end) = emoji_tag_sequence_end(chars, index) {
+                index = end;
+                continue;
+            }
+        }
+        kinds[index] = match c {
+            '\u{200D}' if joins_emoji(chars, index) => None,
+            '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}' => {
+                Some(FindingKind::ZeroWidth)
+            }
+            '\u{E0000}'..='\u{E007F}' => Some(FindingKind::Tag),
+            _ if ucd::is_variation_selector(c) => {
+                if selects_variant(chars, index) {
+                    None
+                } else {
+                    Some(FindingKind::VariationSelector)
+                }
+            }
+            _ if ucd::is_default_ignorable_code_point(c) => Some(FindingKind::DefaultIgnorable),
+            _ => None,
+        };
+        index += 1;
+    }
+
+    kinds
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, kind)| {
+            let kind = kind?;
+            Some(Finding {
+                index,
+                c: chars[index],
+                kind,
+                severity: kind.severity(),
+            })
+        })
+        .collect()
+}
+
+/// Classifies the explicit bidi controls of `chars`, matching embeddings and overrides with
+/// their `PDF` and isolates with their `PDI` like rules X1 to X8 of the bidi algorithm.
+fn scan_bidi_controls(chars: &[char], kinds: &mut [Option<FindingKind>]) {
+    // The indices of the open embeddings, overrides and isolates, and whether they are isolates.
+    let mut stack: Vec<(usize, bool)> = Vec::new();
+    fn unbalanced(stack: &mut Vec<(usize, bool)>, kinds: &mut [Option<FindingKind>]) {
+        for (index, _) in stack.drain(..) {
+            kinds[index] = Some(FindingKind::UnbalancedBidiControl);
+        }
+    }
+
+    for (index, &c) in chars.iter().enumerate() {
+        match BidiClass::of(c) {
+            BidiClass::LRE | BidiClass::RLE | BidiClass::LRO | BidiClass::RLO => {
+                kinds[index] = Some(FindingKind::BidiControl);
+                stack.push((index, false));
+            }
+            BidiClass::LRI | BidiClass::RLI | BidiClass::FSI => {
+                kinds[index] = Some(FindingKind::BidiControl);
+                stack.push((index, true));
+            }
+            BidiClass::PDF => match stack.last() {
+                Some(&(_, false)) => {
+                    stack.pop();
+                    kinds[index] = Some(FindingKind::BidiControl);
+                }
+                _ => kinds[index] = Some(FindingKind::UnbalancedBidiControl),
+            },
+            BidiClass::PDI => match stack.iter().rposition(|&(_, isolate)| isolate) {
+                Some(position) => {
+                    // The embeddings opened in the isolate are terminated implicitly.
+                    let mut rest = stack.split_off(position + 1);
+                    unbalanced(&mut rest, kinds);
+                    stack.pop();
+                    kinds[index] = Some(FindingKind::BidiControl);
+                }
+                None => kinds[index] = Some(FindingKind::UnbalancedBidiControl),
+            },
+            BidiClass::B => unbalanced(&mut stack, kinds),
+            _ => {}
+        }
+    }
+    unbalanced(&mut stack, kinds);
+}
+
+/// Returns the end of the emoji tag sequence whose tags start at `index`, if there is one: a
+/// WAVING BLACK FLAG followed by the tag letters and digits of a subdivision code, like `gbsct`,
+/// and a CANCEL TAG.
+fn emoji_tag_sequence_end(chars: &[char], index: usize) -> Option<usize> {
+    if chars.get(index.checked_sub(1)?) != Some(&'\u{1F3F4}') {
+        return None;
+    }
+    let tags = chars[index..]
+        .iter()
+        .take_while(|&&c| matches!(c, '\u{E0030}'..='\u{E0039}' | '\u{E0061}'..='\u{E007A}'))
+        .count();
+    // A subdivision code is a region code of 2 letters or 3 digits and 1 to 4 more letters or
+    // digits.
+    match chars.get(index + tags) {
+        Some('\u{E007F}') if (3..=7).contains(&tags) => Some(index + tags + 1),
+        _ => None,
+    }
+}
+
+/// Checks if the zero width joiner at `index` joins two pictographs in an emoji ZWJ sequence.
+///
+/// The pictograph before it can be followed by a presentation selector, a skin tone modifier
+/// or the tags of a flag.
+fn joins_emoji(chars: &[char], index: usize) -> bool {
+    let before = chars[..index].iter().rev().find(|&&c| {
+        c != '\u{FE0F}' && !ucd::is_emoji_modifier(c) && !('\u{E0020}'..='\u{E007F}').contains(&c)
+    });
+    let after = chars.get(index + 1);
+    matches!(
+        (before, after),
+        (Some(&before), Some(&after))
+            if ucd::is_extended_pictographic(before) && ucd::is_extended_pictographic(after)
+    )
+}
+
+/// Checks if the variation selector at `index` follows a char it can select a variant of.
+fn selects_variant(chars: &[char], index: usize) -> bool {
+    let Some(&base) = index.checked_sub(1).and_then(|before| chars.get(before)) else {
+        return false;
+    };
+    match chars[index] {
+        // Text and emoji presentation selectors, after a pictograph or the base of a keycap.
+        '\u{FE0E}' | '\u{FE0F}' => {
+            ucd::is_extended_pictographic(base)
+                || (matches!(base, '0'..='9' | '#' | '*')
+                    && chars.get(index + 1) == Some(&'\u{20E3}'))
+        }
+        // Mongolian free variation selectors.
+        '\u{180B}'..='\u{180F}' => ucd::script_extensions(base).contains(&Script::Mongolian),
+        // Ideographic variation selectors.
+        '\u{E0100}'..='\u{E01EF}' => ucd::is_ideographic(base),
+        _ => {
+            let category = ucd::general_category(base);
+            category.is_letter()
+                || category.is_number()
+                || category.is_punctuation()
+                || category.is_symbol()
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//! Serialization with [serde](https://serde.rs), behind the `serde` feature.
+//!
+//! `unicode_str`, `UnicodeString` and `Box<unicode_str>` are serialized as ordinary strings,
+//! and `UnicodeString`, `Box<unicode_str>` and `Cow<unicode_str>` can be deserialized from
+//! strings or from UTF-8 bytes, so they can replace `String` in serialized structs without
+//! changing the format.
+//!
+//! Binary formats that have no string type, or that should not pay for decoding UTF-8, can
+//! store the chars themselves with the [`code_points`] and [`utf32`] modules, which are meant
+//! for the `#[serde(with = "...")]` attribute.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use unicode_string::{ustr, UnicodeString};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct City {
+//!     name: UnicodeString,
+//!     #[serde(with = "unicode_string::serde::code_points")]
+//!     code: UnicodeString,
+//! }
+//!
+//! let city = City {
+//!     name: UnicodeString::from_string("Zürich"),
+//!     code: UnicodeString::from_string("ZÜ"),
+//! };
+//!
+//! let json = serde_json::to_string(&city).unwrap();
+//! assert_eq!(json, r#"{"name":"Zürich","code":[90,220]}"#);
+//!
+//! let city: City = serde_json::from_str(&json).unwrap();
+//! assert_eq!(city.name, ustr!("Zürich"));
+//! ```
+
+use std::fmt;
+
+use ::serde::de::{self, Deserialize, Deserializer, SeqAccess, Unexpected, Visitor};
+use ::serde::ser::{Serialize, SerializeSeq, Serializer};
+
+use crate::{unicode_str, UnicodeString};
+
+impl Serialize for unicode_str {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&String::from_iter(self.chars()))
+    }
+}
+
+impl Serialize for UnicodeString {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize(serializer)
+    }
+}
+
+/// Deserializes a string, or bytes that are valid UTF-8.
+struct StringVisitor;
+
+impl<'de> Visitor<'de> for StringVisitor {
+    type Value = UnicodeString;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<UnicodeString, E> {
+        Ok(UnicodeString::from_string(v))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<UnicodeString, E> {
+        match std::str::from_utf8(v) {
+            Ok(s) => Ok(UnicodeString::from_string(s)),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for UnicodeString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<UnicodeString, D::Error> {
+        deserializer.deserialize_string(StringVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for Box<unicode_str> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Box<unicode_str>, D::Error> {
+        UnicodeString::deserialize(deserializer).map(UnicodeString::into_boxed_unicode_str)
+    }
+}
+
+/// Returns the char with the given code point, or an error if it is not a Unicode scalar
+/// value.
+fn scalar_value<E: de::Error>(code: u32) -> Result<char, E> {
+    char::from_u32(code).ok_or_else(|| {
+        E::invalid_value(Unexpected::Unsigned(code.into()), &"a Unicode scalar value")
+    })
+}
+
+/// (De)serializes a string as a sequence of its code points, as `u32`s.
+///
+/// The functions of this module accept a `UnicodeString`, a `Box<unicode_str>` or a
+/// `Cow<unicode_str>`, and are meant for `#[serde(with = "unicode_string::serde::code_points")]`.
+/// Deserialization fails on numbers that are not Unicode scalar values, like surrogates.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use unicode_string::{unicode_str, ustr};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Glyph {
+///     #[serde(with = "unicode_string::serde::code_points")]
+///     chars: Box<unicode_str>,
+/// }
+///
+/// let json = r#"{"chars":[128150,33]}"#;
+/// let glyph: Glyph = serde_json::from_str(json).unwrap();
+/// assert_eq!(&*glyph.chars, ustr!("💖!"));
+/// assert_eq!(serde_json::to_string(&glyph).unwrap(), json);
+///
+/// // A surrogate is not a char.
+/// assert!(serde_json::from_str::<Glyph>(r#"{"chars":[55357]}"#).is_err());
+/// ```
+pub mod code_points {
+    use super::*;
+
+    /// Serializes `s` as a sequence of `u32` code points.
+    pub fn serialize<T, S>(s: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<unicode_str> + ?Sized,
+        S: Serializer,
+    {
+        let chars = s.as_ref().chars();
+        let mut seq = serializer.serialize_seq(Some(chars.len()))?;
+        for &c in chars {
+            seq.serialize_element(&u32::from(c))?;
+        }
+        seq.end()
+    }
+
+    /// Deserializes a string from a sequence of `u32` code points.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: From<UnicodeString>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(CodePointsVisitor).map(T::from)
+    }
+
+    struct CodePointsVisitor;
+
+    impl<'de> Visitor<'de> for CodePointsVisitor {
+        type Value = UnicodeString;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a sequence of code points")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<UnicodeString, A::Error> {
+            let mut s = UnicodeString::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+            while let Some(code) = seq.next_element::<u32>()? {
+                s.push(scalar_value(code)?);
+            }
+            Ok(s)
+        }
+    }
+}
+
+/// (De)serializes a string as its UTF-32 encoding, in big-endian order and without a byte
+/// order mark.
+///
+/// The functions of this module accept a `UnicodeString`, a `Box<unicode_str>` or a
+/// `Cow<unicode_str>`, and are meant for `#[serde(with = "unicode_string::serde::utf32")]`.
+/// The string is serialized as bytes, which binary formats store as they are, and
+/// deserialized from bytes or from a sequence of `u8`s. Deserialization fails if the number
+/// of bytes is not a multiple of 4, or on code points that are not Unicode scalar values.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use unicode_string::{ustr, UnicodeString};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Label {
+///     #[serde(with = "unicode_string::serde::utf32")]
+///     text: UnicodeString,
+/// }
+///
+/// let label = Label { text: UnicodeString::from_string("Ö🦀") };
+/// let json = serde_json::to_string(&label).unwrap();
+/// assert_eq!(json, r#"{"text":[0,0,0,214,0,1,249,128]}"#);
+///
+/// let label: Label = serde_json::from_str(&json).unwrap();
+/// assert_eq!(label.text, ustr!("Ö🦀"));
+/// ```
+pub mod utf32 {
+    use super::*;
+
+    /// Serializes `s` as its big-endian UTF-32 bytes.
+    pub fn serialize<T, S>(s: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<unicode_str> + ?Sized,
+        S: Serializer,
+    {
+        let bytes: Vec<u8> = s
+            .as_ref()
+            .chars()
+            .iter()
+            .flat_map(|&c| u32::from(c).to_be_bytes())
+            .collect();
+        serializer.serialize_bytes(&bytes)
+    }
+
+    /// Deserializes a string from its big-endian UTF-32 bytes.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: From<UnicodeString>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_byte_buf(Utf32Visitor).map(T::from)
+    }
+
+    struct Utf32Visitor;
+
+    impl Utf32Visitor {
+        fn decode<E: de::Error>(&self, bytes: &[u8]) -> Result<UnicodeString, E> {
+            if !bytes.len().is_multiple_of(4) {
+                return Err(E::invalid_length(bytes.len(), self));
+            }
+            let mut s = UnicodeString::with_capacity(bytes.len() / 4);
+            for code in bytes.chunks_exact(4) {
+                let code = u32::from_be_bytes([code[0], code[1], code[2], code[3]]);
+                s.push(scalar_value(code)?);
+            }
+            Ok(s)
+        }
+    }
+
+    impl<'de> Visitor<'de> for Utf32Visitor {
+        type Value = UnicodeString;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("UTF-32 bytes")
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<UnicodeString, E> {
+            self.decode(v)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<UnicodeString, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+            while let Some(byte) = seq.next_element::<u8>()? {
+                bytes.push(byte);
+            }
+            self.decode(&bytes)
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Returns the number of insertions, deletions and substitutions, and with `transpositions`
+/// of swaps of adjacent elements that are not edited further, needed to turn `a` into `b`, or
+/// `None` if it is more than `max`.
+///
+/// Only the cells of the matrix that are at most `max` away from its diagonal are computed,
+/// and the computation stops as soon as a whole row is above `max`.
+pub(crate) fn edit_distance<T: Eq>(
+    a: &[T],
+    b: &[T],
+    max: usize,
+    transpositions: bool,
+) -> Option<usize> {
+    let (a, b) = strip_common_affixes(a, b);
+    let (a, b) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    let (n, m) = (a.len(), b.len());
+    if m - n > max {
+        return None;
+    }
+    if n == 0 {
+        return Some(m);
+    }
+    let max = max.min(m);
+    // The value of the cells outside of the band, which cannot lead to a distance within `max`.
+    let outside = max + 1;
+    let initial = (0..=n)
+        .map(|i| if i <= max { i } else { outside })
+        .collect::<Vec<_>>();
+    let mut before_previous = initial.clone();
+    let mut previous = initial;
+    let mut current = vec![outside; n + 1];
+    for j in 1..=m {
+        let low = j.saturating_sub(max).max(1);
+        let high = (j + max).min(n);
+        current[low - 1] = if low == 1 && j <= max { j } else { outside };
+        let mut row_min = current[low - 1];
+        for i in low..=high {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut value = (previous[i - 1] + cost)
+                .min(previous[i] + 1)
+                .min(current[i - 1] + 1);
+            if transpositions && i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                value = value.min(before_previous[i - 2] + 1);
+            }
+            current[i] = value.min(outside);
+            row_min = row_min.min(current[i]);
+        }
+        if high < n {
+            current[high + 1] = outside;
+        }
+        if row_min > max {
+            return None;
+        }
+        std::mem::swap(&mut before_previous, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+    Some(previous[n]).filter(|&distance| distance <= max)
+}
+
+/// Returns the Damerau-Levenshtein distance between `a` and `b`, where transposed elements
+/// can be edited further, with the algorithm of Lowrance and Wagner.
+pub(crate) fn damerau_levenshtein<T: Eq + Hash + Copy>(a: &[T], b: &[T]) -> usize {
+    let (n, m) = (a.len(), b.len());
+    if n == 0 || m == 0 {
+        return n + m;
+    }
+    let infinity = n + m;
+    let width = m + 2;
+    // The matrix has an extra row and column of `infinity` before the usual ones.
+    let mut matrix = vec![0; (n + 2) * width];
+    matrix[0] = infinity;
+    for i in 0..=n {
+        matrix[(i + 1) * width] = infinity;
+        matrix[(i + 1) * width + 1] = i;
+    }
+    for j in 0..=m {
+        matrix[j + 1] = infinity;
+        matrix[width + j + 1] = j;
+    }
+    // The last row in which each element of `a` was seen.
+    let mut last_row = HashMap::new();
+    for i in 1..=n {
+        // The last column of the row in which the element of `a` was seen in `b`.
+        let mut last_match_column = 0;
+        for j in 1..=m {
+            let last_match_row = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let previous_match_column = last_match_column;
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            if cost == 0 {
+                last_match_column = j;
+            }
+            let substitution = matrix[i * width + j] + cost;
+            let insertion = matrix[(i + 1) * width + j] + 1;
+            let deletion = matrix[i * width + j + 1] + 1;
+            let transposition = matrix[last_match_row * width + previous_match_column]
+                + (i - last_match_row - 1)
+                + 1
+                + (j - previous_match_column - 1);
+            matrix[(i + 1) * width + j + 1] =
+                substitution.min(insertion).min(deletion).min(transposition);
+        }
+        last_row.insert(a[i - 1], i);
+    }
+    matrix[(n + 1) * width + m + 1]
+}
+
+/// Returns the length of the longest common subsequence of `a` and `b`.
+pub(crate) fn lcs_len<T: Eq>(a: &[T], b: &[T]) -> usize {
+    let prefix = common_prefix_len(a, b);
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = common_suffix_len(a, b);
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+    let (a, b) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    let mut row = vec![0; a.len() + 1];
+    for y in b {
+        let mut diagonal = 0;
+        for (i, x) in a.iter().enumerate() {
+            let above = row[i + 1];
+            row[i + 1] = if x == y {
+                diagonal + 1
+            } else {
+                above.max(row[i])
+            };
+            diagonal = above;
+        }
+    }
+    prefix + row[a.len()] + suffix
+}
+
+fn strip_common_affixes<'a, T: Eq>(a: &'a [T], b: &'a [T]) -> (&'a [T], &'a [T]) {
+    let prefix = common_prefix_len(a, b);
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = common_suffix_len(a, b);
+    (&a[..a.len() - suffix], &b[..b.len() - suffix])
+}
+
+pub(crate) fn common_prefix_len<T: Eq>(a: &[T], b: &[T]) -> usize {
+    a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
+
+fn common_suffix_len<T: Eq>(a: &[T], b: &[T]) -> usize {
+    a.iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count()
+}
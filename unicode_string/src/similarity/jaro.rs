@@ -0,0 +1,56 @@
+use crate::similarity::edit::common_prefix_len;
+
+/// Returns the Jaro similarity of `a` and `b`.
+pub(crate) fn jaro<T: Eq>(a: &[T], b: &[T]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    // Elements match if they are equal and at most `window` positions apart.
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::new();
+    for (i, x) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *x {
+                b_matched[j] = true;
+                a_matches.push(x);
+                break;
+            }
+        }
+    }
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+    let b_matches = b
+        .iter()
+        .zip(&b_matched)
+        .filter(|&(_, &matched)| matched)
+        .map(|(y, _)| y);
+    let transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|&(&x, y)| x != y)
+        .count()
+        / 2;
+    let matches = a_matches.len() as f64;
+    (matches / a.len() as f64
+        + matches / b.len() as f64
+        + (matches - transpositions as f64) / matches)
+        / 3.0
+}
+
+/// Returns the Jaro-Winkler similarity of `a` and `b`, which boosts the Jaro similarity of
+/// strings that share a prefix of up to 4 elements when it is above 0.7.
+pub(crate) fn jaro_winkler<T: Eq>(a: &[T], b: &[T]) -> f64 {
+    let similarity = jaro(a, b);
+    if similarity <= 0.7 {
+        return similarity;
+    }
+    let prefix = common_prefix_len(a, b).min(4) as f64;
+    similarity + 0.1 * prefix * (1.0 - similarity)
+}
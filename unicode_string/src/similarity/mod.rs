@@ -0,0 +1,410 @@
+//! Edit distances and similarity scores between strings.
+//!
+//! The functions of this module compare the chars of two `unicode_str`s, so an accented
+//! letter or an emoji counts as one char, whatever its length in UTF-8. To compare
+//! user-perceived characters instead, so that a letter with a combining accent or an emoji
+//! sequence counts as one unit, use a [`Similarity`] with [`Unit::Grapheme`].
+//!
+//! | Function                     | Measures                                                        |
+//! |------------------------------|-----------------------------------------------------------------|
+//! | [`levenshtein`]              | insertions, deletions and substitutions                         |
+//! | [`osa_distance`]             | the same, plus transpositions of adjacent chars that are not edited further |
+//! | [`damerau_levenshtein`]      | the same, plus any transpositions of adjacent chars             |
+//! | [`hamming`]                  | substitutions, between strings of the same length               |
+//! | [`lcs_len`]                  | the length of the longest common subsequence                    |
+//! | [`jaro`], [`jaro_winkler`]   | a similarity between 0 and 1 suited to short strings like names |
+//!
+//! The edit distances take time proportional to the product of the lengths of the strings.
+//! When only small distances matter, [`levenshtein_bounded`] and [`osa_distance_bounded`] are
+//! much faster, since they stop as soon as the distance is known to be above a limit.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use unicode_string::similarity;
+//! use unicode_string::ustr;
+//!
+//! assert_eq!(similarity::levenshtein(ustr!("kitten"), ustr!("sitting")), 3);
+//! assert_eq!(similarity::levenshtein(ustr!("Straße"), ustr!("Strasse")), 2);
+//! assert_eq!(similarity::osa_distance(ustr!("Ünïcödé"), ustr!("Ünïcdöé")), 1);
+//! assert!(similarity::jaro_winkler(ustr!("Σωκράτης"), ustr!("Σωκρατης")) > 0.9);
+//! ```
+
+mod edit;
+mod jaro;
+
+use std::collections::HashMap;
+
+use crate::unicode_str;
+
+/// The units compared by a [`Similarity`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Unit {
+    /// Chars, i.e. Unicode scalar values.
+    #[default]
+    Char,
+    /// Extended grapheme clusters, as returned by [`unicode_str::graphemes`].
+    Grapheme,
+}
+
+/// Computes edit distances and similarity scores with custom options.
+///
+/// The functions of the [module](self) are shortcuts for the methods of
+/// `Similarity::new()`, which compares chars.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::similarity::{Similarity, Unit};
+/// use unicode_string::ustr;
+///
+/// // "é" written as "e" and a combining acute accent.
+/// let a = ustr!("cafe\u{301}");
+/// let b = ustr!("cafè");
+///
+/// assert_eq!(Similarity::new().levenshtein(a, b), 2);
+///
+/// let graphemes = Similarity::new().unit(Unit::Grapheme);
+/// assert_eq!(graphemes.levenshtein(a, b), 1);
+/// assert_eq!(graphemes.hamming(ustr!("👩‍🔬👍"), ustr!("👨‍🔬👍")), Some(1));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Similarity {
+    unit: Unit,
+}
+
+/// Evaluates `$body` with `$a` and `$b` bound to slices of the units of the strings.
+/// Graphemes are replaced with numbers, equal graphemes getting equal numbers.
+macro_rules! with_units {
+    ($similarity:expr, $a:ident, $b:ident, $body:expr) => {
+        match $similarity.unit {
+            Unit::Char => {
+                let ($a, $b) = ($a.chars(), $b.chars());
+                $body
+            }
+            Unit::Grapheme => {
+                let (a, b) = grapheme_ids($a, $b);
+                let ($a, $b) = (&a[..], &b[..]);
+                $body
+            }
+        }
+    };
+}
+
+impl Similarity {
+    /// Creates a `Similarity` that compares chars.
+    pub const fn new() -> Similarity {
+        Similarity { unit: Unit::Char }
+    }
+
+    /// Sets the units that are compared.
+    #[must_use]
+    pub const fn unit(mut self, unit: Unit) -> Similarity {
+        self.unit = unit;
+        self
+    }
+
+    /// Returns the Levenshtein distance between `a` and `b`: the smallest number of
+    /// insertions, deletions and substitutions that turn `a` into `b`.
+    pub fn levenshtein(&self, a: &unicode_str, b: &unicode_str) -> usize {
+        with_units!(self, a, b, {
+            edit::edit_distance(a, b, usize::MAX, false).expect("the distance is bounded")
+        })
+    }
+
+    /// Returns the Levenshtein distance between `a` and `b`, or `None` if it is more than
+    /// `max`.
+    ///
+    /// This takes time proportional to the length of the strings times `max`, instead of the
+    /// product of their lengths.
+    pub fn levenshtein_bounded(
+        &self,
+        a: &unicode_str,
+        b: &unicode_str,
+        max: usize,
+    ) -> Option<usize> {
+        with_units!(self, a, b, edit::edit_distance(a, b, max, false))
+    }
+
+    /// Returns the optimal string alignment distance between `a` and `b`: the smallest number
+    /// of insertions, deletions, substitutions and transpositions of adjacent units that turn
+    /// `a` into `b`, where no unit is edited more than once.
+    pub fn osa_distance(&self, a: &unicode_str, b: &unicode_str) -> usize {
+        with_units!(self, a, b, {
+            edit::edit_distance(a, b, usize::MAX, true).expect("the distance is bounded")
+        })
+    }
+
+    /// Returns the optimal string alignment distance between `a` and `b`, or `None` if it is
+    /// more than `max`.
+    pub fn osa_distance_bounded(
+        &self,
+        a: &unicode_str,
+        b: &unicode_str,
+        max: usize,
+    ) -> Option<usize> {
+        with_units!(self, a, b, edit::edit_distance(a, b, max, true))
+    }
+
+    /// Returns the Damerau-Levenshtein distance between `a` and `b`: the smallest number of
+    /// insertions, deletions, substitutions and transpositions of adjacent units that turn
+    /// `a` into `b`.
+    ///
+    /// Unlike [`osa_distance`](Similarity::osa_distance), units can be edited after being
+    /// transposed, so the distance between `ca` and `abc` is 2, not 3.
+    pub fn damerau_levenshtein(&self, a: &unicode_str, b: &unicode_str) -> usize {
+        with_units!(self, a, b, edit::damerau_levenshtein(a, b))
+    }
+
+    /// Returns the Hamming distance between `a` and `b`, the number of positions at which
+    /// their units differ, or `None` if they do not have the same number of units.
+    pub fn hamming(&self, a: &unicode_str, b: &unicode_str) -> Option<usize> {
+        with_units!(self, a, b, {
+            if a.len() != b.len() {
+                return None;
+            }
+            Some(a.iter().zip(b).filter(|(x, y)| x != y).count())
+        })
+    }
+
+    /// Returns the length of the longest common subsequence of `a` and `b`: the largest number
+    /// of units that appear in both strings in the same order, not necessarily contiguously.
+    pub fn lcs_len(&self, a: &unicode_str, b: &unicode_str) -> usize {
+        with_units!(self, a, b, edit::lcs_len(a, b))
+    }
+
+    /// Returns the Jaro similarity of `a` and `b`, between 0 (nothing in common) and 1 (equal
+    /// strings).
+    pub fn jaro(&self, a: &unicode_str, b: &unicode_str) -> f64 {
+        with_units!(self, a, b, jaro::jaro(a, b))
+    }
+
+    /// Returns the Jaro-Winkler similarity of `a` and `b`, between 0 and 1, which gives a
+    /// higher score than [`jaro`](Similarity::jaro) to strings that share a prefix.
+    ///
+    /// The Jaro similarity is increased by a tenth of the distance to 1 for each unit of the
+    /// common prefix, up to 4 units, if it is above 0.7.
+    pub fn jaro_winkler(&self, a: &unicode_str, b: &unicode_str) -> f64 {
+        with_units!(self, a, b, jaro::jaro_winkler(a, b))
+    }
+
+    /// Returns 1 minus the Levenshtein distance divided by the length of the longest string,
+    /// a similarity between 0 and 1. Two empty strings have a similarity of 1.
+    pub fn normalized_levenshtein(&self, a: &unicode_str, b: &unicode_str) -> f64 {
+        with_units!(self, a, b, {
+            let distance =
+                edit::edit_distance(a, b, usize::MAX, false).expect("the distance is bounded");
+            normalize(distance, a.len().max(b.len()))
+        })
+    }
+
+    /// Returns 1 minus the Damerau-Levenshtein distance divided by the length of the longest
+    /// string, a similarity between 0 and 1. Two empty strings have a similarity of 1.
+    pub fn normalized_damerau_levenshtein(&self, a: &unicode_str, b: &unicode_str) -> f64 {
+        with_units!(self, a, b, {
+            normalize(edit::damerau_levenshtein(a, b), a.len().max(b.len()))
+        })
+    }
+}
+
+/// Returns the Levenshtein distance between the chars of `a` and `b`. See
+/// [`Similarity::levenshtein`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::similarity;
+/// use unicode_string::ustr;
+///
+/// assert_eq!(similarity::levenshtein(ustr!("kitten"), ustr!("sitting")), 3);
+/// assert_eq!(similarity::levenshtein(ustr!("🦀"), ustr!("🐍")), 1);
+/// ```
+#[inline]
+pub fn levenshtein(a: &unicode_str, b: &unicode_str) -> usize {
+    Similarity::new().levenshtein(a, b)
+}
+
+/// Returns the Levenshtein distance between the chars of `a` and `b`, or `None` if it is more
+/// than `max`. See [`Similarity::levenshtein_bounded`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::similarity;
+/// use unicode_string::ustr;
+///
+/// let word = ustr!("Ünïcödé");
+/// let candidates = [ustr!("Unicode"), ustr!("Ünïcödè"), ustr!("Ünïcödés")];
+/// let close: Vec<_> = candidates
+///     .iter()
+///     .filter(|c| similarity::levenshtein_bounded(word, c, 1).is_some())
+///     .collect();
+/// assert_eq!(close, [&ustr!("Ünïcödè"), &ustr!("Ünïcödés")]);
+/// ```
+#[inline]
+pub fn levenshtein_bounded(a: &unicode_str, b: &unicode_str, max: usize) -> Option<usize> {
+    Similarity::new().levenshtein_bounded(a, b, max)
+}
+
+/// Returns the optimal string alignment distance between the chars of `a` and `b`. See
+/// [`Similarity::osa_distance`].
+#[inline]
+pub fn osa_distance(a: &unicode_str, b: &unicode_str) -> usize {
+    Similarity::new().osa_distance(a, b)
+}
+
+/// Returns the optimal string alignment distance between the chars of `a` and `b`, or `None`
+/// if it is more than `max`. See [`Similarity::osa_distance_bounded`].
+#[inline]
+pub fn osa_distance_bounded(a: &unicode_str, b: &unicode_str, max: usize) -> Option<usize> {
+    Similarity::new().osa_distance_bounded(a, b, max)
+}
+
+/// Returns the Damerau-Levenshtein distance between the chars of `a` and `b`. See
+/// [`Similarity::damerau_levenshtein`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::similarity;
+/// use unicode_string::ustr;
+///
+/// assert_eq!(similarity::damerau_levenshtein(ustr!("ca"), ustr!("abc")), 2);
+/// assert_eq!(similarity::osa_distance(ustr!("ca"), ustr!("abc")), 3);
+/// ```
+#[inline]
+pub fn damerau_levenshtein(a: &unicode_str, b: &unicode_str) -> usize {
+    Similarity::new().damerau_levenshtein(a, b)
+}
+
+/// Returns the Hamming distance between the chars of `a` and `b`, or `None` if they do not
+/// have the same length. See [`Similarity::hamming`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::similarity;
+/// use unicode_string::ustr;
+///
+/// assert_eq!(similarity::hamming(ustr!("größe"), ustr!("grüße")), Some(1));
+/// assert_eq!(similarity::hamming(ustr!("größe"), ustr!("grösse")), None);
+/// ```
+#[inline]
+pub fn hamming(a: &unicode_str, b: &unicode_str) -> Option<usize> {
+    Similarity::new().hamming(a, b)
+}
+
+/// Returns the length of the longest common subsequence of the chars of `a` and `b`. See
+/// [`Similarity::lcs_len`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::similarity;
+/// use unicode_string::ustr;
+///
+/// assert_eq!(similarity::lcs_len(ustr!("ΑΒΓΔΕ"), ustr!("ΑΓΕΖ")), 3);
+/// ```
+#[inline]
+pub fn lcs_len(a: &unicode_str, b: &unicode_str) -> usize {
+    Similarity::new().lcs_len(a, b)
+}
+
+/// Returns the Jaro similarity of the chars of `a` and `b`. See [`Similarity::jaro`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::similarity;
+/// use unicode_string::ustr;
+///
+/// let similarity = similarity::jaro(ustr!("MARTHA"), ustr!("MARHTA"));
+/// assert!((similarity - 0.944).abs() < 0.001);
+/// ```
+#[inline]
+pub fn jaro(a: &unicode_str, b: &unicode_str) -> f64 {
+    Similarity::new().jaro(a, b)
+}
+
+/// Returns the Jaro-Winkler similarity of the chars of `a` and `b`. See
+/// [`Similarity::jaro_winkler`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::similarity;
+/// use unicode_string::ustr;
+///
+/// let similarity = similarity::jaro_winkler(ustr!("MARTHA"), ustr!("MARHTA"));
+/// assert!((similarity - 0.961).abs() < 0.001);
+/// ```
+#[inline]
+pub fn jaro_winkler(a: &unicode_str, b: &unicode_str) -> f64 {
+    Similarity::new().jaro_winkler(a, b)
+}
+
+/// Returns the Levenshtein distance between the chars of `a` and `b`, normalized to a
+/// similarity between 0 and 1. See [`Similarity::normalized_levenshtein`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::similarity;
+/// use unicode_string::{ustr, UnicodeString};
+///
+/// assert_eq!(similarity::normalized_levenshtein(ustr!("größe"), ustr!("grüße")), 0.8);
+///
+/// let empty = UnicodeString::new();
+/// assert_eq!(similarity::normalized_levenshtein(&empty, &empty), 1.0);
+/// ```
+#[inline]
+pub fn normalized_levenshtein(a: &unicode_str, b: &unicode_str) -> f64 {
+    Similarity::new().normalized_levenshtein(a, b)
+}
+
+/// Returns the Damerau-Levenshtein distance between the chars of `a` and `b`, normalized to a
+/// similarity between 0 and 1. See [`Similarity::normalized_damerau_levenshtein`].
+#[inline]
+pub fn normalized_damerau_levenshtein(a: &unicode_str, b: &unicode_str) -> f64 {
+    Similarity::new().normalized_damerau_levenshtein(a, b)
+}
+
+/// Replaces the graphemes of `a` and `b` with numbers, equal graphemes getting equal numbers.
+fn grapheme_ids<'a>(a: &'a unicode_str, b: &'a unicode_str) -> (Vec<usize>, Vec<usize>) {
+    let mut ids: HashMap<&'a unicode_str, usize> = HashMap::new();
+    let mut id = |grapheme: &'a unicode_str| {
+        let next = ids.len();
+        *ids.entry(grapheme).or_insert(next)
+    };
+    let a = a.graphemes().map(&mut id).collect();
+    let b = b.graphemes().map(&mut id).collect();
+    (a, b)
+}
+
+fn normalize(distance: usize, len: usize) -> f64 {
+    if len == 0 {
+        1.0
+    } else {
+        1.0 - distance as f64 / len as f64
+    }
+}
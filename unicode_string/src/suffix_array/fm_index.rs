@@ -0,0 +1,184 @@
+use crate::suffix_array::sais;
+use crate::suffix_array::wavelet::{BitVector, WaveletMatrix};
+use crate::unicode_str;
+
+/// A compressed full-text index of a `unicode_str`, which counts and locates the occurrences
+/// of patterns without keeping the text or its whole suffix array.
+///
+/// An FM-index stores the Burrows-Wheeler transform of the text in a wavelet matrix, which
+/// takes about `log2(k)` bits per char for a text of `k` distinct chars, and one position of
+/// the suffix array every `sample_rate` chars of the text. A [`SuffixArray`] takes 8 bytes
+/// per char on top of the text, and an FM-index of an English text with the default sample
+/// rate takes less than 1.5.
+///
+/// [`count`](FmIndex::count) takes time proportional to the length of the pattern and to
+/// `log2(k)`, whatever the length of the text. [`locate`](FmIndex::locate) additionally
+/// takes up to `sample_rate` steps per occurrence, so a larger sample rate trades speed for
+/// memory.
+///
+/// [`SuffixArray`]: crate::suffix_array::SuffixArray
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::suffix_array::FmIndex;
+/// use unicode_string::ustr;
+///
+/// let text = ustr!("Fischers Fritz fischt frische Fische");
+/// let index = FmIndex::new(text);
+///
+/// assert_eq!(index.count(ustr!("isch")), 4);
+/// assert_eq!(index.locate(ustr!("Fisch")), [0, 30]);
+/// assert_eq!(&text[30..36], ustr!("Fische"));
+/// assert!(!index.contains(ustr!("Frosch")));
+/// ```
+#[derive(Clone, Debug)]
+pub struct FmIndex {
+    /// The sorted distinct chars of the text. The char at index `i` has the rank `i + 1`, and
+    /// the rank 0 is the end of the text.
+    alphabet: Vec<char>,
+    /// The ranks of the Burrows-Wheeler transform of the text followed by its end.
+    bwt: WaveletMatrix,
+    /// The number of ranks of the text and its end that are smaller than each rank.
+    counts: Vec<usize>,
+    /// The rows of the sorted suffixes whose position is sampled.
+    sampled: BitVector,
+    /// The sampled positions, in the order of their rows.
+    samples: Vec<usize>,
+    sample_rate: usize,
+}
+
+impl FmIndex {
+    /// The number of chars of the text between two sampled positions, by default.
+    pub const DEFAULT_SAMPLE_RATE: usize = 32;
+
+    /// Builds the FM-index of `text`, with the [default sample
+    /// rate](FmIndex::DEFAULT_SAMPLE_RATE).
+    pub fn new(text: &unicode_str) -> FmIndex {
+        FmIndex::with_sample_rate(text, FmIndex::DEFAULT_SAMPLE_RATE)
+    }
+
+    /// Builds the FM-index of `text`, keeping the position of one suffix every `sample_rate`
+    /// chars of the text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sample_rate` is 0.
+    pub fn with_sample_rate(text: &unicode_str, sample_rate: usize) -> FmIndex {
+        assert!(sample_rate > 0, "the sample rate must not be 0");
+        let (mut ranks, alphabet) = sais::ranks(text.chars(), 1);
+        ranks.push(0);
+        let sa = sais::suffix_array(&ranks, alphabet.len() as u32);
+
+        let mut counts = vec![0; alphabet.len() + 2];
+        for &rank in &ranks {
+            counts[rank as usize + 1] += 1;
+        }
+        for rank in 1..counts.len() {
+            counts[rank] += counts[rank - 1];
+        }
+        let bwt = sa
+            .iter()
+            .map(|&i| ranks[i.checked_sub(1).unwrap_or(ranks.len() - 1)])
+            .collect();
+        let sampled = BitVector::new(sa.iter().map(|&i| i % sample_rate == 0));
+        let samples = sa
+            .iter()
+            .copied()
+            .filter(|&i| i % sample_rate == 0)
+            .collect();
+        FmIndex {
+            bwt: WaveletMatrix::new(bwt, alphabet.len() as u32),
+            alphabet,
+            counts,
+            sampled,
+            samples,
+            sample_rate,
+        }
+    }
+
+    /// Returns the number of chars of the indexed text.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.counts[self.counts.len() - 1] - 1
+    }
+
+    /// Checks if the indexed text is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of chars of the text between two sampled positions.
+    #[inline]
+    pub fn sample_rate(&self) -> usize {
+        self.sample_rate
+    }
+
+    /// Returns the number of bytes allocated by the index.
+    pub fn heap_size(&self) -> usize {
+        self.alphabet.len() * std::mem::size_of::<char>()
+            + self.bwt.heap_size()
+            + self.counts.len() * std::mem::size_of::<usize>()
+            + self.sampled.heap_size()
+            + self.samples.len() * std::mem::size_of::<usize>()
+    }
+
+    /// Returns the number of occurrences of `pattern` in the text, which may overlap.
+    ///
+    /// The empty pattern occurs at every char index, including the length of the text.
+    pub fn count(&self, pattern: &unicode_str) -> usize {
+        let rows = self.rows(pattern);
+        rows.end - rows.start
+    }
+
+    /// Checks if `pattern` occurs in the text.
+    pub fn contains(&self, pattern: &unicode_str) -> bool {
+        self.count(pattern) > 0
+    }
+
+    /// Returns the char indices of the occurrences of `pattern` in the text, in increasing
+    /// order.
+    pub fn locate(&self, pattern: &unicode_str) -> Vec<usize> {
+        let mut positions: Vec<usize> = self
+            .rows(pattern)
+            .map(|mut row| {
+                let mut steps = 0;
+                while !self.sampled.get(row) {
+                    row = self.lf(row);
+                    steps += 1;
+                }
+                self.samples[self.sampled.rank1(row)] + steps
+            })
+            .collect();
+        positions.sort_unstable();
+        positions
+    }
+
+    /// Returns the row of the suffix that starts one char before the suffix in `row`, which
+    /// must not be the first suffix.
+    #[inline]
+    fn lf(&self, row: usize) -> usize {
+        let rank = self.bwt.get(row);
+        self.counts[rank as usize] + self.bwt.rank(rank, row)
+    }
+
+    /// Returns the range of rows of the sorted suffixes that start with `pattern`.
+    fn rows(&self, pattern: &unicode_str) -> std::ops::Range<usize> {
+        let (mut start, mut end) = (0, self.len() + 1);
+        for c in pattern.chars().iter().rev() {
+            let rank = match self.alphabet.binary_search(c) {
+                Ok(index) => index as u32 + 1,
+                Err(_) => return 0..0,
+            };
+            start = self.counts[rank as usize] + self.bwt.rank(rank, start);
+            end = self.counts[rank as usize] + self.bwt.rank(rank, end);
+            if start >= end {
+                return 0..0;
+            }
+        }
+        start..end
+    }
+}
@@ -0,0 +1,178 @@
+//! Full-text indexes for answering many substring queries on a fixed text.
+//!
+//! A [`SuffixArray`] sorts the suffixes of a text once, in time linear in its length, so that
+//! the occurrences of any pattern can then be found by binary search, in time proportional to
+//! the length of the pattern times the logarithm of the length of the text. Its longest common
+//! prefix array also finds repeated substrings. An [`FmIndex`] answers the same queries in a
+//! fraction of the memory, without keeping the text.
+//!
+//! Both compare chars, and return char indices, which can be used to index the text.
+
+mod fm_index;
+mod sais;
+mod wavelet;
+
+pub use self::fm_index::*;
+
+use std::ops::Range;
+
+use crate::unicode_str;
+
+/// The suffix array of a `unicode_str`: the starting indices of its suffixes, in sorted
+/// order, with their longest common prefixes.
+///
+/// The suffix array is built with the SA-IS algorithm, in time linear in the length of the
+/// text, and the longest common prefix array with Kasai's algorithm. Both take 8 bytes per
+/// char, and the text is borrowed to answer queries. Use an [`FmIndex`] when memory matters
+/// more than speed.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::suffix_array::SuffixArray;
+/// use unicode_string::ustr;
+///
+/// let text = ustr!("банан");
+/// let sa = SuffixArray::new(text);
+///
+/// assert_eq!(sa.suffix_array(), [3, 1, 0, 4, 2]);
+/// assert_eq!(sa.lcp_array(), [0, 2, 0, 0, 1]);
+/// assert_eq!(sa.count(ustr!("ан")), 2);
+/// assert_eq!(sa.locate(ustr!("ан")), [1, 3]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SuffixArray<'a> {
+    text: &'a unicode_str,
+    sa: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+impl<'a> SuffixArray<'a> {
+    /// Builds the suffix array of `text`.
+    pub fn new(text: &'a unicode_str) -> SuffixArray<'a> {
+        let (ranks, alphabet) = sais::ranks(text.chars(), 0);
+        let upper = alphabet.len().saturating_sub(1) as u32;
+        let sa = sais::suffix_array(&ranks, upper);
+        let lcp = sais::lcp_array(&ranks, &sa);
+        SuffixArray { text, sa, lcp }
+    }
+
+    /// Returns the indexed text.
+    #[inline]
+    pub fn text(&self) -> &'a unicode_str {
+        self.text
+    }
+
+    /// Returns the starting char indices of the suffixes of the text, in sorted order.
+    #[inline]
+    pub fn suffix_array(&self) -> &[usize] {
+        &self.sa
+    }
+
+    /// Returns the length of the longest common prefix of each suffix in the [suffix
+    /// array](SuffixArray::suffix_array) and the one before it, or 0 for the first.
+    #[inline]
+    pub fn lcp_array(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// Returns the number of bytes allocated by the suffix array, without the text.
+    pub fn heap_size(&self) -> usize {
+        (self.sa.len() + self.lcp.len()) * std::mem::size_of::<usize>()
+    }
+
+    /// Returns the number of occurrences of `pattern` in the text, which may overlap.
+    ///
+    /// The empty pattern occurs at every char index, including the length of the text.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::suffix_array::SuffixArray;
+    /// use unicode_string::ustr;
+    ///
+    /// let sa = SuffixArray::new(ustr!("🦀🦀🦀"));
+    /// assert_eq!(sa.count(ustr!("🦀🦀")), 2);
+    /// assert_eq!(sa.count(ustr!("🐍")), 0);
+    /// ```
+    pub fn count(&self, pattern: &unicode_str) -> usize {
+        if pattern.chars().is_empty() {
+            return self.text.len() + 1;
+        }
+        self.rows(pattern).len()
+    }
+
+    /// Checks if `pattern` occurs in the text.
+    pub fn contains(&self, pattern: &unicode_str) -> bool {
+        self.count(pattern) > 0
+    }
+
+    /// Returns the char indices of the occurrences of `pattern` in the text, in increasing
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::suffix_array::SuffixArray;
+    /// use unicode_string::ustr;
+    ///
+    /// let text = ustr!("Ölfass, Fassöl, Ölfässer");
+    /// let sa = SuffixArray::new(text);
+    /// let found = sa.locate(ustr!("Öl"));
+    /// assert_eq!(found, [0, 16]);
+    /// assert_eq!(&text[found[1]..found[1] + 2], ustr!("Öl"));
+    /// ```
+    pub fn locate(&self, pattern: &unicode_str) -> Vec<usize> {
+        if pattern.chars().is_empty() {
+            return (0..=self.text.len()).collect();
+        }
+        let mut positions = self.sa[self.rows(pattern)].to_vec();
+        positions.sort_unstable();
+        positions
+    }
+
+    /// Returns the range of chars of the longest substring that occurs at least twice in the
+    /// text, possibly overlapping, or `None` if no char occurs twice. Of the longest ones, the
+    /// first in sorted order is returned.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::suffix_array::SuffixArray;
+    /// use unicode_string::ustr;
+    ///
+    /// let text = ustr!("to be or not to be, ça");
+    /// let sa = SuffixArray::new(text);
+    /// let range = sa.longest_repeated_substring().unwrap();
+    /// assert_eq!(&text[range], ustr!("to be"));
+    ///
+    /// assert_eq!(SuffixArray::new(ustr!("abc")).longest_repeated_substring(), None);
+    /// ```
+    pub fn longest_repeated_substring(&self) -> Option<Range<usize>> {
+        let (row, &len) = self
+            .lcp
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|&(_, len)| len)?;
+        (len > 0).then(|| self.sa[row]..self.sa[row] + len)
+    }
+
+    /// Returns the range of rows of the sorted suffixes that start with `pattern`.
+    fn rows(&self, pattern: &unicode_str) -> Range<usize> {
+        let chars = self.text.chars();
+        let pattern = pattern.chars();
+        let prefix = |i: usize| &chars[i..chars.len().min(i + pattern.len())];
+        let start = self.sa.partition_point(|&i| prefix(i) < pattern);
+        let end = start + self.sa[start..].partition_point(|&i| prefix(i) == pattern);
+        start..end
+    }
+}
@@ -0,0 +1,159 @@
+/// Marks an empty slot of the suffix array during construction.
+const NONE: usize = usize::MAX;
+
+/// Maps the chars of `chars` to their ranks among the distinct chars, starting from `first`,
+/// and returns the ranks and the sorted distinct chars.
+pub(crate) fn ranks(chars: &[char], first: u32) -> (Vec<u32>, Vec<char>) {
+    let mut alphabet = chars.to_vec();
+    alphabet.sort_unstable();
+    alphabet.dedup();
+    let ranks = chars
+        .iter()
+        .map(|c| {
+            first
+                + alphabet
+                    .binary_search(c)
+                    .expect("the char is in the alphabet") as u32
+        })
+        .collect();
+    (ranks, alphabet)
+}
+
+/// Builds the suffix array of `s`, whose values are at most `upper`, with the SA-IS algorithm
+/// (Nong, Zhang and Chan, 2009), in linear time.
+pub(crate) fn suffix_array(s: &[u32], upper: u32) -> Vec<usize> {
+    let n = s.len();
+    match n {
+        0 => return Vec::new(),
+        1 => return vec![0],
+        2 if s[0] < s[1] => return vec![0, 1],
+        2 => return vec![1, 0],
+        _ => (),
+    }
+    let upper = upper as usize;
+
+    // `is_s[i]` is set if the suffix at `i` is smaller than the one at `i + 1` (S-type), and
+    // not if it is larger (L-type).
+    let mut is_s = vec![false; n];
+    for i in (0..n - 1).rev() {
+        is_s[i] = if s[i] == s[i + 1] {
+            is_s[i + 1]
+        } else {
+            s[i] < s[i + 1]
+        };
+    }
+
+    // The start of the L-type and S-type suffixes of each bucket.
+    let mut sum_l = vec![0; upper + 2];
+    let mut sum_s = vec![0; upper + 2];
+    for i in 0..n {
+        if is_s[i] {
+            sum_l[s[i] as usize + 1] += 1;
+        } else {
+            sum_s[s[i] as usize] += 1;
+        }
+    }
+    for c in 0..=upper {
+        sum_s[c] += sum_l[c];
+        sum_l[c + 1] += sum_s[c];
+    }
+
+    let mut sa = vec![NONE; n];
+    let induce = |sa: &mut Vec<usize>, lms: &[usize]| {
+        sa.fill(NONE);
+        let mut buckets = sum_s.clone();
+        for &i in lms {
+            sa[buckets[s[i] as usize]] = i;
+            buckets[s[i] as usize] += 1;
+        }
+        buckets.copy_from_slice(&sum_l);
+        sa[buckets[s[n - 1] as usize]] = n - 1;
+        buckets[s[n - 1] as usize] += 1;
+        for index in 0..n {
+            let i = sa[index];
+            if i != NONE && i >= 1 && !is_s[i - 1] {
+                sa[buckets[s[i - 1] as usize]] = i - 1;
+                buckets[s[i - 1] as usize] += 1;
+            }
+        }
+        buckets.copy_from_slice(&sum_l);
+        for index in (0..n).rev() {
+            let i = sa[index];
+            if i != NONE && i >= 1 && is_s[i - 1] {
+                buckets[s[i - 1] as usize + 1] -= 1;
+                sa[buckets[s[i - 1] as usize + 1]] = i - 1;
+            }
+        }
+    };
+
+    // The leftmost S-type positions (LMS), which are sorted first.
+    let mut lms_index = vec![NONE; n + 1];
+    let lms: Vec<usize> = (1..n).filter(|&i| !is_s[i - 1] && is_s[i]).collect();
+    for (index, &i) in lms.iter().enumerate() {
+        lms_index[i] = index;
+    }
+    let m = lms.len();
+    induce(&mut sa, &lms);
+
+    if m > 0 {
+        // Name the LMS substrings in sorted order, and sort the LMS suffixes by sorting the
+        // string of their names recursively.
+        let sorted_lms: Vec<usize> = sa
+            .iter()
+            .copied()
+            .filter(|&i| lms_index[i] != NONE)
+            .collect();
+        let mut names = vec![0; m];
+        let mut name = 0;
+        for index in 1..m {
+            let (mut left, mut right) = (sorted_lms[index - 1], sorted_lms[index]);
+            let end = |i: usize| lms.get(lms_index[i] + 1).copied().unwrap_or(n);
+            let (left_end, right_end) = (end(left), end(right));
+            let mut same = left_end - left == right_end - right;
+            if same {
+                while left < left_end && s[left] == s[right] {
+                    left += 1;
+                    right += 1;
+                }
+                if left == n || s[left] != s[right] {
+                    same = false;
+                }
+            }
+            if !same {
+                name += 1;
+            }
+            names[lms_index[sorted_lms[index]]] = name;
+        }
+        let sorted: Vec<usize> = suffix_array(&names, name)
+            .into_iter()
+            .map(|index| lms[index])
+            .collect();
+        induce(&mut sa, &sorted);
+    }
+    sa
+}
+
+/// Builds the longest common prefix array of `s` with Kasai's algorithm: the length of the
+/// longest common prefix of each suffix in `sa` and the one before it, or 0 for the first.
+pub(crate) fn lcp_array<T: PartialEq>(s: &[T], sa: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    let mut rank = vec![0; n];
+    for (index, &i) in sa.iter().enumerate() {
+        rank[i] = index;
+    }
+    let mut lcp = vec![0; n];
+    let mut len: usize = 0;
+    for i in 0..n {
+        if rank[i] == 0 {
+            len = 0;
+            continue;
+        }
+        let j = sa[rank[i] - 1];
+        while i + len < n && j + len < n && s[i + len] == s[j + len] {
+            len += 1;
+        }
+        lcp[rank[i]] = len;
+        len = len.saturating_sub(1);
+    }
+    lcp
+}
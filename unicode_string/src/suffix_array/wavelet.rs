@@ -0,0 +1,132 @@
+/// The number of bits of a block whose rank is stored.
+const BLOCK_BITS: usize = 512;
+const WORDS_PER_BLOCK: usize = BLOCK_BITS / 64;
+
+/// A vector of bits supporting rank queries in constant time.
+#[derive(Clone, Debug)]
+pub(crate) struct BitVector {
+    words: Vec<u64>,
+    /// The number of set bits before each block.
+    blocks: Vec<usize>,
+}
+
+impl BitVector {
+    pub(crate) fn new(bits: impl ExactSizeIterator<Item = bool>) -> BitVector {
+        let mut words = vec![0; bits.len().div_ceil(64)];
+        for (index, bit) in bits.enumerate() {
+            words[index / 64] |= (bit as u64) << (index % 64);
+        }
+        let mut blocks = Vec::with_capacity(words.len() / WORDS_PER_BLOCK + 1);
+        let mut ones = 0;
+        for block in words.chunks(WORDS_PER_BLOCK) {
+            blocks.push(ones);
+            ones += block
+                .iter()
+                .map(|word| word.count_ones() as usize)
+                .sum::<usize>();
+        }
+        blocks.push(ones);
+        BitVector { words, blocks }
+    }
+
+    #[inline]
+    pub(crate) fn get(&self, index: usize) -> bool {
+        self.words[index / 64] >> (index % 64) & 1 == 1
+    }
+
+    /// Returns the number of set bits before `index`.
+    #[inline]
+    pub(crate) fn rank1(&self, index: usize) -> usize {
+        let word = index / 64;
+        let block = index / BLOCK_BITS;
+        let mut rank = self.blocks[block];
+        for &word in &self.words[block * WORDS_PER_BLOCK..word] {
+            rank += word.count_ones() as usize;
+        }
+        if !index.is_multiple_of(64) {
+            rank += (self.words[word] << (64 - index % 64)).count_ones() as usize;
+        }
+        rank
+    }
+
+    /// Returns the number of unset bits before `index`.
+    #[inline]
+    pub(crate) fn rank0(&self, index: usize) -> usize {
+        index - self.rank1(index)
+    }
+
+    pub(crate) fn heap_size(&self) -> usize {
+        self.words.len() * 8 + self.blocks.len() * std::mem::size_of::<usize>()
+    }
+}
+
+/// A wavelet matrix: a sequence of small integers, stored in about one bit per bit of the
+/// largest value, that supports access and rank queries in time proportional to that number
+/// of bits.
+#[derive(Clone, Debug)]
+pub(crate) struct WaveletMatrix {
+    /// The bits of the values at each level, from the most significant one, with the values
+    /// stably sorted by their previous bits.
+    levels: Vec<BitVector>,
+    /// The number of unset bits at each level.
+    zeros: Vec<usize>,
+}
+
+impl WaveletMatrix {
+    pub(crate) fn new(mut values: Vec<u32>, max: u32) -> WaveletMatrix {
+        let bits = (u32::BITS - max.leading_zeros()).max(1);
+        let mut levels = Vec::with_capacity(bits as usize);
+        let mut zeros = Vec::with_capacity(bits as usize);
+        let mut ones = Vec::with_capacity(values.len());
+        for level in (0..bits).rev() {
+            let bit = |value: u32| value >> level & 1 == 1;
+            levels.push(BitVector::new(values.iter().map(|&value| bit(value))));
+            ones.clear();
+            values.retain(|&value| {
+                if bit(value) {
+                    ones.push(value);
+                }
+                !bit(value)
+            });
+            zeros.push(values.len());
+            values.append(&mut ones);
+        }
+        WaveletMatrix { levels, zeros }
+    }
+
+    /// Returns the value at `index`.
+    pub(crate) fn get(&self, mut index: usize) -> u32 {
+        let mut value = 0;
+        for (level, zeros) in self.levels.iter().zip(&self.zeros) {
+            let bit = level.get(index);
+            value = value << 1 | bit as u32;
+            index = if bit {
+                zeros + level.rank1(index)
+            } else {
+                level.rank0(index)
+            };
+        }
+        value
+    }
+
+    /// Returns the number of occurrences of `value` before `index`.
+    pub(crate) fn rank(&self, value: u32, mut index: usize) -> usize {
+        let mut start = 0;
+        let bits = self.levels.len() as u32;
+        for (level, (bit_vector, zeros)) in self.levels.iter().zip(&self.zeros).enumerate() {
+            if value >> (bits - 1 - level as u32) & 1 == 1 {
+                start = zeros + bit_vector.rank1(start);
+                index = zeros + bit_vector.rank1(index);
+            } else {
+                start = bit_vector.rank0(start);
+                index = bit_vector.rank0(index);
+            }
+        }
+        index - start
+    }
+
+    pub(crate) fn heap_size(&self) -> usize {
+        let levels: usize = self.levels.iter().map(BitVector::heap_size).sum();
+        levels + self.zeros.len() * std::mem::size_of::<usize>()
+    }
+}
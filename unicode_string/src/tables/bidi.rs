@@ -0,0 +1,852 @@
+// This file is generated by `unicode_string_gen`. Do not edit it manually.
+
+use crate::bidi::BidiClass;
+
+/// Ranges of chars whose bidi class is not `L`, sorted by code point.
+pub(crate) static BIDI_CLASS: &[(char, char, BidiClass)] = &[
+    ('\u{0}', '\u{8}', BidiClass::BN),
+    ('\u{9}', '\u{9}', BidiClass::S),
+    ('\u{A}', '\u{A}', BidiClass::B),
+    ('\u{B}', '\u{B}', BidiClass::S),
+    ('\u{C}', '\u{C}', BidiClass::WS),
+    ('\u{D}', '\u{D}', BidiClass::B),
+    ('\u{E}', '\u{1B}', BidiClass::BN),
+    ('\u{1C}', '\u{1E}', BidiClass::B),
+    ('\u{1F}', '\u{1F}', BidiClass::S),
+    ('\u{20}', '\u{20}', BidiClass::WS),
+    ('\u{21}', '\u{22}', BidiClass::ON),
+    ('\u{23}', '\u{25}', BidiClass::ET),
+    ('\u{26}', '\u{2A}', BidiClass::ON),
+    ('\u{2B}', '\u{2B}', BidiClass::ES),
+    ('\u{2C}', '\u{2C}', BidiClass::CS),
+    ('\u{2D}', '\u{2D}', BidiClass::ES),
+    ('\u{2E}', '\u{2F}', BidiClass::CS),
+    ('\u{30}', '\u{39}', BidiClass::EN),
+    ('\u{3A}', '\u{3A}', BidiClass::CS),
+    ('\u{3B}', '\u{40}', BidiClass::ON),
+    ('\u{5B}', '\u{60}', BidiClass::ON),
+    ('\u{7B}', '\u{7E}', BidiClass::ON),
+    ('\u{7F}', '\u{84}', BidiClass::BN),
+    ('\u{85}', '\u{85}', BidiClass::B),
+    ('\u{86}', '\u{9F}', BidiClass::BN),
+    ('\u{A0}', '\u{A0}', BidiClass::CS),
+    ('\u{A1}', '\u{A1}', BidiClass::ON),
+    ('\u{A2}', '\u{A5}', BidiClass::ET),
+    ('\u{A6}', '\u{A9}', BidiClass::ON),
+    ('\u{AB}', '\u{AC}', BidiClass::ON),
+    ('\u{AD}', '\u{AD}', BidiClass::BN),
+    ('\u{AE}', '\u{AF}', BidiClass::ON),
+    ('\u{B0}', '\u{B1}', BidiClass::ET),
+    ('\u{B2}', '\u{B3}', BidiClass::EN),
+    ('\u{B4}', '\u{B4}', BidiClass::ON),
+    ('\u{B6}', '\u{B8}', BidiClass::ON),
+    ('\u{B9}', '\u{B9}', BidiClass::EN),
+    ('\u{BB}', '\u{BF}', BidiClass::ON),
+    ('\u{D7}', '\u{D7}', BidiClass::ON),
+    ('\u{F7}', '\u{F7}', BidiClass::ON),
+    ('\u{2B9}', '\u{2BA}', BidiClass::ON),
+    ('\u{2C2}', '\u{2CF}', BidiClass::ON),
+    ('\u{2D2}', '\u{2DF}', BidiClass::ON),
+    ('\u{2E5}', '\u{2ED}', BidiClass::ON),
+    ('\u{2EF}', '\u{2FF}', BidiClass::ON),
+    ('\u{300}', '\u{36F}', BidiClass::NSM),
+    ('\u{374}', '\u{375}', BidiClass::ON),
+    ('\u{37E}', '\u{37E}', BidiClass::ON),
+    ('\u{384}', '\u{385}', BidiClass::ON),
+    ('\u{387}', '\u{387}', BidiClass::ON),
+    ('\u{3F6}', '\u{3F6}', BidiClass::ON),
+    ('\u{483}', '\u{489}', BidiClass::NSM),
+    ('\u{58A}', '\u{58A}', BidiClass::ON),
+    ('\u{58D}', '\u{58E}', BidiClass::ON),
+    ('\u{58F}', '\u{58F}', BidiClass::ET),
+    ('\u{590}', '\u{590}', BidiClass::R),
+    ('\u{591}', '\u{5BD}', BidiClass::NSM),
+    ('\u{5BE}', '\u{5BE}', BidiClass::R),
+    ('\u{5BF}', '\u{5BF}', BidiClass::NSM),
+    ('\u{5C0}', '\u{5C0}', BidiClass::R),
+    ('\u{5C1}', '\u{5C2}', BidiClass::NSM),
+    ('\u{5C3}', '\u{5C3}', BidiClass::R),
+    ('\u{5C4}', '\u{5C5}', BidiClass::NSM),
+    ('\u{5C6}', '\u{5C6}', BidiClass::R),
+    ('\u{5C7}', '\u{5C7}', BidiClass::NSM),
+    ('\u{5C8}', '\u{5FF}', BidiClass::R),
+    ('\u{600}', '\u{605}', BidiClass::AN),
+    ('\u{606}', '\u{607}', BidiClass::ON),
+    ('\u{608}', '\u{608}', BidiClass::AL),
+    ('\u{609}', '\u{60A}', BidiClass::ET),
+    ('\u{60B}', '\u{60B}', BidiClass::AL),
+    ('\u{60C}', '\u{60C}', BidiClass::CS),
+    ('\u{60D}', '\u{60D}', BidiClass::AL),
+    ('\u{60E}', '\u{60F}', BidiClass::ON),
+    ('\u{610}', '\u{61A}', BidiClass::NSM),
+    ('\u{61B}', '\u{64A}', BidiClass::AL),
+    ('\u{64B}', '\u{65F}', BidiClass::NSM),
+    ('\u{660}', '\u{669}', BidiClass::AN),
+    ('\u{66A}', '\u{66A}', BidiClass::ET),
+    ('\u{66B}', '\u{66C}', BidiClass::AN),
+    ('\u{66D}', '\u{66F}', BidiClass::AL),
+    ('\u{670}', '\u{670}', BidiClass::NSM),
+    ('\u{671}', '\u{6D5}', BidiClass::AL),
+    ('\u{6D6}', '\u{6DC}', BidiClass::NSM),
+    ('\u{6DD}', '\u{6DD}', BidiClass::AN),
+    ('\u{6DE}', '\u{6DE}', BidiClass::ON),
+    ('\u{6DF}', '\u{6E4}', BidiClass::NSM),
+    ('\u{6E5}', '\u{6E6}', BidiClass::AL),
+    ('\u{6E7}', '\u{6E8}', BidiClass::NSM),
+    ('\u{6E9}', '\u{6E9}', BidiClass::ON),
+    ('\u{6EA}', '\u{6ED}', BidiClass::NSM),
+    ('\u{6EE}', '\u{6EF}', BidiClass::AL),
+    ('\u{6F0}', '\u{6F9}', BidiClass::EN),
+    ('\u{6FA}', '\u{710}', BidiClass::AL),
+    ('\u{711}', '\u{711}', BidiClass::NSM),
+    ('\u{712}', '\u{72F}', BidiClass::AL),
+    ('\u{730}', '\u{74A}', BidiClass::NSM),
+    ('\u{74B}', '\u{7A5}', BidiClass::AL),
+    ('\u{7A6}', '\u{7B0}', BidiClass::NSM),
+    ('\u{7B1}', '\u{7BF}', BidiClass::AL),
+    ('\u{7C0}', '\u{7EA}', BidiClass::R),
+    ('\u{7EB}', '\u{7F3}', BidiClass::NSM),
+    ('\u{7F4}', '\u{7F5}', BidiClass::R),
+    ('\u{7F6}', '\u{7F9}', BidiClass::ON),
+    ('\u{7FA}', '\u{7FC}', BidiClass::R),
+    ('\u{7FD}', '\u{7FD}', BidiClass::NSM),
+    ('\u{7FE}', '\u{815}', BidiClass::R),
+    ('\u{816}', '\u{819}', BidiClass::NSM),
+    ('\u{81A}', '\u{81A}', BidiClass::R),
+    ('\u{81B}', '\u{823}', BidiClass::NSM),
+    ('\u{824}', '\u{824}', BidiClass::R),
+    ('\u{825}', '\u{827}', BidiClass::NSM),
+    ('\u{828}', '\u{828}', BidiClass::R),
+    ('\u{829}', '\u{82D}', BidiClass::NSM),
+    ('\u{82E}', '\u{858}', BidiClass::R),
+    ('\u{859}', '\u{85B}', BidiClass::NSM),
+    ('\u{85C}', '\u{85F}', BidiClass::R),
+    ('\u{860}', '\u{88F}', BidiClass::AL),
+    ('\u{890}', '\u{891}', BidiClass::AN),
+    ('\u{892}', '\u{897}', BidiClass::AL),
+    ('\u{898}', '\u{89F}', BidiClass::NSM),
+    ('\u{8A0}', '\u{8C9}', BidiClass::AL),
+    ('\u{8CA}', '\u{8E1}', BidiClass::NSM),
+    ('\u{8E2}', '\u{8E2}', BidiClass::AN),
+    ('\u{8E3}', '\u{902}', BidiClass::NSM),
+    ('\u{93A}', '\u{93A}', BidiClass::NSM),
+    ('\u{93C}', '\u{93C}', BidiClass::NSM),
+    ('\u{941}', '\u{948}', BidiClass::NSM),
+    ('\u{94D}', '\u{94D}', BidiClass::NSM),
+    ('\u{951}', '\u{957}', BidiClass::NSM),
+    ('\u{962}', '\u{963}', BidiClass::NSM),
+    ('\u{981}', '\u{981}', BidiClass::NSM),
+    ('\u{9BC}', '\u{9BC}', BidiClass::NSM),
+    ('\u{9C1}', '\u{9C4}', BidiClass::NSM),
+    ('\u{9CD}', '\u{9CD}', BidiClass::NSM),
+    ('\u{9E2}', '\u{9E3}', BidiClass::NSM),
+    ('\u{9F2}', '\u{9F3}', BidiClass::ET),
+    ('\u{9FB}', '\u{9FB}', BidiClass::ET),
+    ('\u{9FE}', '\u{9FE}', BidiClass::NSM),
+    ('\u{A01}', '\u{A02}', BidiClass::NSM),
+    ('\u{A3C}', '\u{A3C}', BidiClass::NSM),
+    ('\u{A41}', '\u{A42}', BidiClass::NSM),
+    ('\u{A47}', '\u{A48}', BidiClass::NSM),
+    ('\u{A4B}', '\u{A4D}', BidiClass::NSM),
+    ('\u{A51}', '\u{A51}', BidiClass::NSM),
+    ('\u{A70}', '\u{A71}', BidiClass::NSM),
+    ('\u{A75}', '\u{A75}', BidiClass::NSM),
+    ('\u{A81}', '\u{A82}', BidiClass::NSM),
+    ('\u{ABC}', '\u{ABC}', BidiClass::NSM),
+    ('\u{AC1}', '\u{AC5}', BidiClass::NSM),
+    ('\u{AC7}', '\u{AC8}', BidiClass::NSM),
+    ('\u{ACD}', '\u{ACD}', BidiClass::NSM),
+    ('\u{AE2}', '\u{AE3}', BidiClass::NSM),
+    ('\u{AF1}', '\u{AF1}', BidiClass::ET),
+    ('\u{AFA}', '\u{AFF}', BidiClass::NSM),
+    ('\u{B01}', '\u{B01}', BidiClass::NSM),
+    ('\u{B3C}', '\u{B3C}', BidiClass::NSM),
+    ('\u{B3F}', '\u{B3F}', BidiClass::NSM),
+    ('\u{B41}', '\u{B44}', BidiClass::NSM),
+    ('\u{B4D}', '\u{B4D}', BidiClass::NSM),
+    ('\u{B55}', '\u{B56}', BidiClass::NSM),
+    ('\u{B62}', '\u{B63}', BidiClass::NSM),
+    ('\u{B82}', '\u{B82}', BidiClass::NSM),
+    ('\u{BC0}', '\u{BC0}', BidiClass::NSM),
+    ('\u{BCD}', '\u{BCD}', BidiClass::NSM),
+    ('\u{BF3}', '\u{BF8}', BidiClass::ON),
+    ('\u{BF9}', '\u{BF9}', BidiClass::ET),
+    ('\u{BFA}', '\u{BFA}', BidiClass::ON),
+    ('\u{C00}', '\u{C00}', BidiClass::NSM),
+    ('\u{C04}', '\u{C04}', BidiClass::NSM),
+    ('\u{C3C}', '\u{C3C}', BidiClass::NSM),
+    ('\u{C3E}', '\u{C40}', BidiClass::NSM),
+    ('\u{C46}', '\u{C48}', BidiClass::NSM),
+    ('\u{C4A}', '\u{C4D}', BidiClass::NSM),
+    ('\u{C55}', '\u{C56}', BidiClass::NSM),
+    ('\u{C62}', '\u{C63}', BidiClass::NSM),
+    ('\u{C78}', '\u{C7E}', BidiClass::ON),
+    ('\u{C81}', '\u{C81}', BidiClass::NSM),
+    ('\u{CBC}', '\u{CBC}', BidiClass::NSM),
+    ('\u{CCC}', '\u{CCD}', BidiClass::NSM),
+    ('\u{CE2}', '\u{CE3}', BidiClass::NSM),
+    ('\u{D00}', '\u{D01}', BidiClass::NSM),
+    ('\u{D3B}', '\u{D3C}', BidiClass::NSM),
+    ('\u{D41}', '\u{D44}', BidiClass::NSM),
+    ('\u{D4D}', '\u{D4D}', BidiClass::NSM),
+    ('\u{D62}', '\u{D63}', BidiClass::NSM),
+    ('\u{D81}', '\u{D81}', BidiClass::NSM),
+    ('\u{DCA}', '\u{DCA}', BidiClass::NSM),
+    ('\u{DD2}', '\u{DD4}', BidiClass::NSM),
+    ('\u{DD6}', '\u{DD6}', BidiClass::NSM),
+    ('\u{E31}', '\u{E31}', BidiClass::NSM),
+    ('\u{E34}', '\u{E3A}', BidiClass::NSM),
+    ('\u{E3F}', '\u{E3F}', BidiClass::ET),
+    ('\u{E47}', '\u{E4E}', BidiClass::NSM),
+    ('\u{EB1}', '\u{EB1}', BidiClass::NSM),
+    ('\u{EB4}', '\u{EBC}', BidiClass::NSM),
+    ('\u{EC8}', '\u{ECD}', BidiClass::NSM),
+    ('\u{F18}', '\u{F19}', BidiClass::NSM),
+    ('\u{F35}', '\u{F35}', BidiClass::NSM),
+    ('\u{F37}', '\u{F37}', BidiClass::NSM),
+    ('\u{F39}', '\u{F39}', BidiClass::NSM),
+    ('\u{F3A}', '\u{F3D}', BidiClass::ON),
+    ('\u{F71}', '\u{F7E}', BidiClass::NSM),
+    ('\u{F80}', '\u{F84}', BidiClass::NSM),
+    ('\u{F86}', '\u{F87}', BidiClass::NSM),
+    ('\u{F8D}', '\u{F97}', BidiClass::NSM),
+    ('\u{F99}', '\u{FBC}', BidiClass::NSM),
+    ('\u{FC6}', '\u{FC6}', BidiClass::NSM),
+    ('\u{102D}', '\u{1030}', BidiClass::NSM),
+    ('\u{1032}', '\u{1037}', BidiClass::NSM),
+    ('\u{1039}', '\u{103A}', BidiClass::NSM),
+    ('\u{103D}', '\u{103E}', BidiClass::NSM),
+    ('\u{1058}', '\u{1059}', BidiClass::NSM),
+    ('\u{105E}', '\u{1060}', BidiClass::NSM),
+    ('\u{1071}', '\u{1074}', BidiClass::NSM),
+    ('\u{1082}', '\u{1082}', BidiClass::NSM),
+    ('\u{1085}', '\u{1086}', BidiClass::NSM),
+    ('\u{108D}', '\u{108D}', BidiClass::NSM),
+    ('\u{109D}', '\u{109D}', BidiClass::NSM),
+    ('\u{135D}', '\u{135F}', BidiClass::NSM),
+    ('\u{1390}', '\u{1399}', BidiClass::ON),
+    ('\u{1400}', '\u{1400}', BidiClass::ON),
+    ('\u{1680}', '\u{1680}', BidiClass::WS),
+    ('\u{169B}', '\u{169C}', BidiClass::ON),
+    ('\u{1712}', '\u{1714}', BidiClass::NSM),
+    ('\u{1732}', '\u{1733}', BidiClass::NSM),
+    ('\u{1752}', '\u{1753}', BidiClass::NSM),
+    ('\u{1772}', '\u{1773}', BidiClass::NSM),
+    ('\u{17B4}', '\u{17B5}', BidiClass::NSM),
+    ('\u{17B7}', '\u{17BD}', BidiClass::NSM),
+    ('\u{17C6}', '\u{17C6}', BidiClass::NSM),
+    ('\u{17C9}', '\u{17D3}', BidiClass::NSM),
+    ('\u{17DB}', '\u{17DB}', BidiClass::ET),
+    ('\u{17DD}', '\u{17DD}', BidiClass::NSM),
+    ('\u{17F0}', '\u{17F9}', BidiClass::ON),
+    ('\u{1800}', '\u{180A}', BidiClass::ON),
+    ('\u{180B}', '\u{180D}', BidiClass::NSM),
+    ('\u{180E}', '\u{180E}', BidiClass::BN),
+    ('\u{180F}', '\u{180F}', BidiClass::NSM),
+    ('\u{1885}', '\u{1886}', BidiClass::NSM),
+    ('\u{18A9}', '\u{18A9}', BidiClass::NSM),
+    ('\u{1920}', '\u{1922}', BidiClass::NSM),
+    ('\u{1927}', '\u{1928}', BidiClass::NSM),
+    ('\u{1932}', '\u{1932}', BidiClass::NSM),
+    ('\u{1939}', '\u{193B}', BidiClass::NSM),
+    ('\u{1940}', '\u{1940}', BidiClass::ON),
+    ('\u{1944}', '\u{1945}', BidiClass::ON),
+    ('\u{19DE}', '\u{19FF}', BidiClass::ON),
+    ('\u{1A17}', '\u{1A18}', BidiClass::NSM),
+    ('\u{1A1B}', '\u{1A1B}', BidiClass::NSM),
+    ('\u{1A56}', '\u{1A56}', BidiClass::NSM),
+    ('\u{1A58}', '\u{1A5E}', BidiClass::NSM),
+    ('\u{1A60}', '\u{1A60}', BidiClass::NSM),
+    ('\u{1A62}', '\u{1A62}', BidiClass::NSM),
+    ('\u{1A65}', '\u{1A6C}', BidiClass::NSM),
+    ('\u{1A73}', '\u{1A7C}', BidiClass::NSM),
+    ('\u{1A7F}', '\u{1A7F}', BidiClass::NSM),
+    ('\u{1AB0}', '\u{1ACE}', BidiClass::NSM),
+    ('\u{1B00}', '\u{1B03}', BidiClass::NSM),
+    ('\u{1B34}', '\u{1B34}', BidiClass::NSM),
+    ('\u{1B36}', '\u{1B3A}', BidiClass::NSM),
+    ('\u{1B3C}', '\u{1B3C}', BidiClass::NSM),
+    ('\u{1B42}', '\u{1B42}', BidiClass::NSM),
+    ('\u{1B6B}', '\u{1B73}', BidiClass::NSM),
+    ('\u{1B80}', '\u{1B81}', BidiClass::NSM),
+    ('\u{1BA2}', '\u{1BA5}', BidiClass::NSM),
+    ('\u{1BA8}', '\u{1BA9}', BidiClass::NSM),
+    ('\u{1BAB}', '\u{1BAD}', BidiClass::NSM),
+    ('\u{1BE6}', '\u{1BE6}', BidiClass::NSM),
+    ('\u{1BE8}', '\u{1BE9}', BidiClass::NSM),
+    ('\u{1BED}', '\u{1BED}', BidiClass::NSM),
+    ('\u{1BEF}', '\u{1BF1}', BidiClass::NSM),
+    ('\u{1C2C}', '\u{1C33}', BidiClass::NSM),
+    ('\u{1C36}', '\u{1C37}', BidiClass::NSM),
+    ('\u{1CD0}', '\u{1CD2}', BidiClass::NSM),
+    ('\u{1CD4}', '\u{1CE0}', BidiClass::NSM),
+    ('\u{1CE2}', '\u{1CE8}', BidiClass::NSM),
+    ('\u{1CED}', '\u{1CED}', BidiClass::NSM),
+    ('\u{1CF4}', '\u{1CF4}', BidiClass::NSM),
+    ('\u{1CF8}', '\u{1CF9}', BidiClass::NSM),
+    ('\u{1DC0}', '\u{1DFF}', BidiClass::NSM),
+    ('\u{1FBD}', '\u{1FBD}', BidiClass::ON),
+    ('\u{1FBF}', '\u{1FC1}', BidiClass::ON),
+    ('\u{1FCD}', '\u{1FCF}', BidiClass::ON),
+    ('\u{1FDD}', '\u{1FDF}', BidiClass::ON),
+    ('\u{1FED}', '\u{1FEF}', BidiClass::ON),
+    ('\u{1FFD}', '\u{1FFE}', BidiClass::ON),
+    ('\u{2000}', '\u{200A}', BidiClass::WS),
+    ('\u{200B}', '\u{200D}', BidiClass::BN),
+    ('\u{200F}', '\u{200F}', BidiClass::R),
+    ('\u{2010}', '\u{2027}', BidiClass::ON),
+    ('\u{2028}', '\u{2028}', BidiClass::WS),
+    ('\u{2029}', '\u{2029}', BidiClass::B),
+    ('\u{202A}', '\u{202A}', BidiClass::LRE),
+    ('\u{202B}', '\u{202B}', BidiClass::RLE),
+    ('\u{202C}', '\u{202C}', BidiClass::PDF),
+    ('\u{202D}', '\u{202D}', BidiClass::LRO),
+    ('\u{202E}', '\u{202E}', BidiClass::RLO),
+    ('\u{202F}', '\u{202F}', BidiClass::CS),
+    ('\u{2030}', '\u{2034}', BidiClass::ET),
+    ('\u{2035}', '\u{2043}', BidiClass::ON),
+    ('\u{2044}', '\u{2044}', BidiClass::CS),
+    ('\u{2045}', '\u{205E}', BidiClass::ON),
+    ('\u{205F}', '\u{205F}', BidiClass::WS),
+    ('\u{2060}', '\u{2065}', BidiClass::BN),
+    ('\u{2066}', '\u{2066}', BidiClass::LRI),
+    ('\u{2067}', '\u{2067}', BidiClass::RLI),
+    ('\u{2068}', '\u{2068}', BidiClass::FSI),
+    ('\u{2069}', '\u{2069}', BidiClass::PDI),
+    ('\u{206A}', '\u{206F}', BidiClass::BN),
+    ('\u{2070}', '\u{2070}', BidiClass::EN),
+    ('\u{2074}', '\u{2079}', BidiClass::EN),
+    ('\u{207A}', '\u{207B}', BidiClass::ES),
+    ('\u{207C}', '\u{207E}', BidiClass::ON),
+    ('\u{2080}', '\u{2089}', BidiClass::EN),
+    ('\u{208A}', '\u{208B}', BidiClass::ES),
+    ('\u{208C}', '\u{208E}', BidiClass::ON),
+    ('\u{20A0}', '\u{20CF}', BidiClass::ET),
+    ('\u{20D0}', '\u{20F0}', BidiClass::NSM),
+    ('\u{2100}', '\u{2101}', BidiClass::ON),
+    ('\u{2103}', '\u{2106}', BidiClass::ON),
+    ('\u{2108}', '\u{2109}', BidiClass::ON),
+    ('\u{2114}', '\u{2114}', BidiClass::ON),
+    ('\u{2116}', '\u{2118}', BidiClass::ON),
+    ('\u{211E}', '\u{2123}', BidiClass::ON),
+    ('\u{2125}', '\u{2125}', BidiClass::ON),
+    ('\u{2127}', '\u{2127}', BidiClass::ON),
+    ('\u{2129}', '\u{2129}', BidiClass::ON),
+    ('\u{212E}', '\u{212E}', BidiClass::ET),
+    ('\u{213A}', '\u{213B}', BidiClass::ON),
+    ('\u{2140}', '\u{2144}', BidiClass::ON),
+    ('\u{214A}', '\u{214D}', BidiClass::ON),
+    ('\u{2150}', '\u{215F}', BidiClass::ON),
+    ('\u{2189}', '\u{218B}', BidiClass::ON),
+    ('\u{2190}', '\u{2211}', BidiClass::ON),
+    ('\u{2212}', '\u{2212}', BidiClass::ES),
+    ('\u{2213}', '\u{2213}', BidiClass::ET),
+    ('\u{2214}', '\u{2335}', BidiClass::ON),
+    ('\u{237B}', '\u{2394}', BidiClass::ON),
+    ('\u{2396}', '\u{2426}', BidiClass::ON),
+    ('\u{2440}', '\u{244A}', BidiClass::ON),
+    ('\u{2460}', '\u{2487}', BidiClass::ON),
+    ('\u{2488}', '\u{249B}', BidiClass::EN),
+    ('\u{24EA}', '\u{26AB}', BidiClass::ON),
+    ('\u{26AD}', '\u{27FF}', BidiClass::ON),
+    ('\u{2900}', '\u{2B73}', BidiClass::ON),
+    ('\u{2B76}', '\u{2B95}', BidiClass::ON),
+    ('\u{2B97}', '\u{2BFF}', BidiClass::ON),
+    ('\u{2CE5}', '\u{2CEA}', BidiClass::ON),
+    ('\u{2CEF}', '\u{2CF1}', BidiClass::NSM),
+    ('\u{2CF9}', '\u{2CFF}', BidiClass::ON),
+    ('\u{2D7F}', '\u{2D7F}', BidiClass::NSM),
+    ('\u{2DE0}', '\u{2DFF}', BidiClass::NSM),
+    ('\u{2E00}', '\u{2E5D}', BidiClass::ON),
+    ('\u{2E80}', '\u{2E99}', BidiClass::ON),
+    ('\u{2E9B}', '\u{2EF3}', BidiClass::ON),
+    ('\u{2F00}', '\u{2FD5}', BidiClass::ON),
+    ('\u{2FF0}', '\u{2FFB}', BidiClass::ON),
+    ('\u{3000}', '\u{3000}', BidiClass::WS),
+    ('\u{3001}', '\u{3004}', BidiClass::ON),
+    ('\u{3008}', '\u{3020}', BidiClass::ON),
+    ('\u{302A}', '\u{302D}', BidiClass::NSM),
+    ('\u{3030}', '\u{3030}', BidiClass::ON),
+    ('\u{3036}', '\u{3037}', BidiClass::ON),
+    ('\u{303D}', '\u{303F}', BidiClass::ON),
+    ('\u{3099}', '\u{309A}', BidiClass::NSM),
+    ('\u{309B}', '\u{309C}', BidiClass::ON),
+    ('\u{30A0}', '\u{30A0}', BidiClass::ON),
+    ('\u{30FB}', '\u{30FB}', BidiClass::ON),
+    ('\u{31C0}', '\u{31E3}', BidiClass::ON),
+    ('\u{321D}', '\u{321E}', BidiClass::ON),
+    ('\u{3250}', '\u{325F}', BidiClass::ON),
+    ('\u{327C}', '\u{327E}', BidiClass::ON),
+    ('\u{32B1}', '\u{32BF}', BidiClass::ON),
+    ('\u{32CC}', '\u{32CF}', BidiClass::ON),
+    ('\u{3377}', '\u{337A}', BidiClass::ON),
+    ('\u{33DE}', '\u{33DF}', BidiClass::ON),
+    ('\u{33FF}', '\u{33FF}', BidiClass::ON),
+    ('\u{4DC0}', '\u{4DFF}', BidiClass::ON),
+    ('\u{A490}', '\u{A4C6}', BidiClass::ON),
+    ('\u{A60D}', '\u{A60F}', BidiClass::ON),
+    ('\u{A66F}', '\u{A672}', BidiClass::NSM),
+    ('\u{A673}', '\u{A673}', BidiClass::ON),
+    ('\u{A674}', '\u{A67D}', BidiClass::NSM),
+    ('\u{A67E}', '\u{A67F}', BidiClass::ON),
+    ('\u{A69E}', '\u{A69F}', BidiClass::NSM),
+    ('\u{A6F0}', '\u{A6F1}', BidiClass::NSM),
+    ('\u{A700}', '\u{A721}', BidiClass::ON),
+    ('\u{A788}', '\u{A788}', BidiClass::ON),
+    ('\u{A802}', '\u{A802}', BidiClass::NSM),
+    ('\u{A806}', '\u{A806}', BidiClass::NSM),
+    ('\u{A80B}', '\u{A80B}', BidiClass::NSM),
+    ('\u{A825}', '\u{A826}', BidiClass::NSM),
+    ('\u{A828}', '\u{A82B}', BidiClass::ON),
+    ('\u{A82C}', '\u{A82C}', BidiClass::NSM),
+    ('\u{A838}', '\u{A839}', BidiClass::ET),
+    ('\u{A874}', '\u{A877}', BidiClass::ON),
+    ('\u{A8C4}', '\u{A8C5}', BidiClass::NSM),
+    ('\u{A8E0}', '\u{A8F1}', BidiClass::NSM),
+    ('\u{A8FF}', '\u{A8FF}', BidiClass::NSM),
+    ('\u{A926}', '\u{A92D}', BidiClass::NSM),
+    ('\u{A947}', '\u{A951}', BidiClass::NSM),
+    ('\u{A980}', '\u{A982}', BidiClass::NSM),
+    ('\u{A9B3}', '\u{A9B3}', BidiClass::NSM),
+    ('\u{A9B6}', '\u{A9B9}', BidiClass::NSM),
+    ('\u{A9BC}', '\u{A9BD}', BidiClass::NSM),
+    ('\u{A9E5}', '\u{A9E5}', BidiClass::NSM),
+    ('\u{AA29}', '\u{AA2E}', BidiClass::NSM),
+    ('\u{AA31}', '\u{AA32}', BidiClass::NSM),
+    ('\u{AA35}', '\u{AA36}', BidiClass::NSM),
+    ('\u{AA43}', '\u{AA43}', BidiClass::NSM),
+    ('\u{AA4C}', '\u{AA4C}', BidiClass::NSM),
+    ('\u{AA7C}', '\u{AA7C}', BidiClass::NSM),
+    ('\u{AAB0}', '\u{AAB0}', BidiClass::NSM),
+    ('\u{AAB2}', '\u{AAB4}', BidiClass::NSM),
+    ('\u{AAB7}', '\u{AAB8}', BidiClass::NSM),
+    ('\u{AABE}', '\u{AABF}', BidiClass::NSM),
+    ('\u{AAC1}', '\u{AAC1}', BidiClass::NSM),
+    ('\u{AAEC}', '\u{AAED}', BidiClass::NSM),
+    ('\u{AAF6}', '\u{AAF6}', BidiClass::NSM),
+    ('\u{AB6A}', '\u{AB6B}', BidiClass::ON),
+    ('\u{ABE5}', '\u{ABE5}', BidiClass::NSM),
+    ('\u{ABE8}', '\u{ABE8}', BidiClass::NSM),
+    ('\u{ABED}', '\u{ABED}', BidiClass::NSM),
+    ('\u{FB1D}', '\u{FB1D}', BidiClass::R),
+    ('\u{FB1E}', '\u{FB1E}', BidiClass::NSM),
+    ('\u{FB1F}', '\u{FB28}', BidiClass::R),
+    ('\u{FB29}', '\u{FB29}', BidiClass::ES),
+    ('\u{FB2A}', '\u{FB4F}', BidiClass::R),
+    ('\u{FB50}', '\u{FD3D}', BidiClass::AL),
+    ('\u{FD3E}', '\u{FD4F}', BidiClass::ON),
+    ('\u{FD50}', '\u{FDCE}', BidiClass::AL),
+    ('\u{FDCF}', '\u{FDCF}', BidiClass::ON),
+    ('\u{FDD0}', '\u{FDEF}', BidiClass::BN),
+    ('\u{FDF0}', '\u{FDFC}', BidiClass::AL),
+    ('\u{FDFD}', '\u{FDFF}', BidiClass::ON),
+    ('\u{FE00}', '\u{FE0F}', BidiClass::NSM),
+    ('\u{FE10}', '\u{FE19}', BidiClass::ON),
+    ('\u{FE20}', '\u{FE2F}', BidiClass::NSM),
+    ('\u{FE30}', '\u{FE4F}', BidiClass::ON),
+    ('\u{FE50}', '\u{FE50}', BidiClass::CS),
+    ('\u{FE51}', '\u{FE51}', BidiClass::ON),
+    ('\u{FE52}', '\u{FE52}', BidiClass::CS),
+    ('\u{FE54}', '\u{FE54}', BidiClass::ON),
+    ('\u{FE55}', '\u{FE55}', BidiClass::CS),
+    ('\u{FE56}', '\u{FE5E}', BidiClass::ON),
+    ('\u{FE5F}', '\u{FE5F}', BidiClass::ET),
+    ('\u{FE60}', '\u{FE61}', BidiClass::ON),
+    ('\u{FE62}', '\u{FE63}', BidiClass::ES),
+    ('\u{FE64}', '\u{FE66}', BidiClass::ON),
+    ('\u{FE68}', '\u{FE68}', BidiClass::ON),
+    ('\u{FE69}', '\u{FE6A}', BidiClass::ET),
+    ('\u{FE6B}', '\u{FE6B}', BidiClass::ON),
+    ('\u{FE70}', '\u{FEFE}', BidiClass::AL),
+    ('\u{FEFF}', '\u{FEFF}', BidiClass::BN),
+    ('\u{FF01}', '\u{FF02}', BidiClass::ON),
+    ('\u{FF03}', '\u{FF05}', BidiClass::ET),
+    ('\u{FF06}', '\u{FF0A}', BidiClass::ON),
+    ('\u{FF0B}', '\u{FF0B}', BidiClass::ES),
+    ('\u{FF0C}', '\u{FF0C}', BidiClass::CS),
+    ('\u{FF0D}', '\u{FF0D}', BidiClass::ES),
+    ('\u{FF0E}', '\u{FF0F}', BidiClass::CS),
+    ('\u{FF10}', '\u{FF19}', BidiClass::EN),
+    ('\u{FF1A}', '\u{FF1A}', BidiClass::CS),
+    ('\u{FF1B}', '\u{FF20}', BidiClass::ON),
+    ('\u{FF3B}', '\u{FF40}', BidiClass::ON),
+    ('\u{FF5B}', '\u{FF65}', BidiClass::ON),
+    ('\u{FFE0}', '\u{FFE1}', BidiClass::ET),
+    ('\u{FFE2}', '\u{FFE4}', BidiClass::ON),
+    ('\u{FFE5}', '\u{FFE6}', BidiClass::ET),
+    ('\u{FFE8}', '\u{FFEE}', BidiClass::ON),
+    ('\u{FFF0}', '\u{FFF8}', BidiClass::BN),
+    ('\u{FFF9}', '\u{FFFD}', BidiClass::ON),
+    ('\u{FFFE}', '\u{FFFF}', BidiClass::BN),
+    ('\u{10101}', '\u{10101}', BidiClass::ON),
+    ('\u{10140}', '\u{1018C}', BidiClass::ON),
+    ('\u{10190}', '\u{1019C}', BidiClass::ON),
+    ('\u{101A0}', '\u{101A0}', BidiClass::ON),
+    ('\u{101FD}', '\u{101FD}', BidiClass::NSM),
+    ('\u{102E0}', '\u{102E0}', BidiClass::NSM),
+    ('\u{102E1}', '\u{102FB}', BidiClass::EN),
+    ('\u{10376}', '\u{1037A}', BidiClass::NSM),
+    ('\u{10800}', '\u{1091E}', BidiClass::R),
+    ('\u{1091F}', '\u{1091F}', BidiClass::ON),
+    ('\u{10920}', '\u{10A00}', BidiClass::R),
+    ('\u{10A01}', '\u{10A03}', BidiClass::NSM),
+    ('\u{10A04}', '\u{10A04}', BidiClass::R),
+    ('\u{10A05}', '\u{10A06}', BidiClass::NSM),
+    ('\u{10A07}', '\u{10A0B}', BidiClass::R),
+    ('\u{10A0C}', '\u{10A0F}', BidiClass::NSM),
+    ('\u{10A10}', '\u{10A37}', BidiClass::R),
+    ('\u{10A38}', '\u{10A3A}', BidiClass::NSM),
+    ('\u{10A3B}', '\u{10A3E}', BidiClass::R),
+    ('\u{10A3F}', '\u{10A3F}', BidiClass::NSM),
+    ('\u{10A40}', '\u{10AE4}', BidiClass::R),
+    ('\u{10AE5}', '\u{10AE6}', BidiClass::NSM),
+    ('\u{10AE7}', '\u{10B38}', BidiClass::R),
+    ('\u{10B39}', '\u{10B3F}', BidiClass::ON),
+    ('\u{10B40}', '\u{10CFF}', BidiClass::R),
+    ('\u{10D00}', '\u{10D23}', BidiClass::AL),
+    ('\u{10D24}', '\u{10D27}', BidiClass::NSM),
+    ('\u{10D28}', '\u{10D2F}', BidiClass::AL),
+    ('\u{10D30}', '\u{10D39}', BidiClass::AN),
+    ('\u{10D3A}', '\u{10D3F}', BidiClass::AL),
+    ('\u{10D40}', '\u{10E5F}', BidiClass::R),
+    ('\u{10E60}', '\u{10E7E}', BidiClass::AN),
+    ('\u{10E7F}', '\u{10EAA}', BidiClass::R),
+    ('\u{10EAB}', '\u{10EAC}', BidiClass::NSM),
+    ('\u{10EAD}', '\u{10F2F}', BidiClass::R),
+    ('\u{10F30}', '\u{10F45}', BidiClass::AL),
+    ('\u{10F46}', '\u{10F50}', BidiClass::NSM),
+    ('\u{10F51}', '\u{10F6F}', BidiClass::AL),
+    ('\u{10F70}', '\u{10F81}', BidiClass::R),
+    ('\u{10F82}', '\u{10F85}', BidiClass::NSM),
+    ('\u{10F86}', '\u{10FFF}', BidiClass::R),
+    ('\u{11001}', '\u{11001}', BidiClass::NSM),
+    ('\u{11038}', '\u{11046}', BidiClass::NSM),
+    ('\u{11052}', '\u{11065}', BidiClass::ON),
+    ('\u{11070}', '\u{11070}', BidiClass::NSM),
+    ('\u{11073}', '\u{11074}', BidiClass::NSM),
+    ('\u{1107F}', '\u{11081}', BidiClass::NSM),
+    ('\u{110B3}', '\u{110B6}', BidiClass::NSM),
+    ('\u{110B9}', '\u{110BA}', BidiClass::NSM),
+    ('\u{110C2}', '\u{110C2}', BidiClass::NSM),
+    ('\u{11100}', '\u{11102}', BidiClass::NSM),
+    ('\u{11127}', '\u{1112B}', BidiClass::NSM),
+    ('\u{1112D}', '\u{11134}', BidiClass::NSM),
+    ('\u{11173}', '\u{11173}', BidiClass::NSM),
+    ('\u{11180}', '\u{11181}', BidiClass::NSM),
+    ('\u{111B6}', '\u{111BE}', BidiClass::NSM),
+    ('\u{111C9}', '\u{111CC}', BidiClass::NSM),
+    ('\u{111CF}', '\u{111CF}', BidiClass::NSM),
+    ('\u{1122F}', '\u{11231}', BidiClass::NSM),
+    ('\u{11234}', '\u{11234}', BidiClass::NSM),
+    ('\u{11236}', '\u{11237}', BidiClass::NSM),
+    ('\u{1123E}', '\u{1123E}', BidiClass::NSM),
+    ('\u{112DF}', '\u{112DF}', BidiClass::NSM),
+    ('\u{112E3}', '\u{112EA}', BidiClass::NSM),
+    ('\u{11300}', '\u{11301}', BidiClass::NSM),
+    ('\u{1133B}', '\u{1133C}', BidiClass::NSM),
+    ('\u{11340}', '\u{11340}', BidiClass::NSM),
+    ('\u{11366}', '\u{1136C}', BidiClass::NSM),
+    ('\u{11370}', '\u{11374}', BidiClass::NSM),
+    ('\u{11438}', '\u{1143F}', BidiClass::NSM),
+    ('\u{11442}', '\u{11444}', BidiClass::NSM),
+    ('\u{11446}', '\u{11446}', BidiClass::NSM),
+    ('\u{1145E}', '\u{1145E}', BidiClass::NSM),
+    ('\u{114B3}', '\u{114B8}', BidiClass::NSM),
+    ('\u{114BA}', '\u{114BA}', BidiClass::NSM),
+    ('\u{114BF}', '\u{114C0}', BidiClass::NSM),
+    ('\u{114C2}', '\u{114C3}', BidiClass::NSM),
+    ('\u{115B2}', '\u{115B5}', BidiClass::NSM),
+    ('\u{115BC}', '\u{115BD}', BidiClass::NSM),
+    ('\u{115BF}', '\u{115C0}', BidiClass::NSM),
+    ('\u{115DC}', '\u{115DD}', BidiClass::NSM),
+    ('\u{11633}', '\u{1163A}', BidiClass::NSM),
+    ('\u{1163D}', '\u{1163D}', BidiClass::NSM),
+    ('\u{1163F}', '\u{11640}', BidiClass::NSM),
+    ('\u{11660}', '\u{1166C}', BidiClass::ON),
+    ('\u{116AB}', '\u{116AB}', BidiClass::NSM),
+    ('\u{116AD}', '\u{116AD}', BidiClass::NSM),
+    ('\u{116B0}', '\u{116B5}', BidiClass::NSM),
+    ('\u{116B7}', '\u{116B7}', BidiClass::NSM),
+    ('\u{1171D}', '\u{1171F}', BidiClass::NSM),
+    ('\u{11722}', '\u{11725}', BidiClass::NSM),
+    ('\u{11727}', '\u{1172B}', BidiClass::NSM),
+    ('\u{1182F}', '\u{11837}', BidiClass::NSM),
+    ('\u{11839}', '\u{1183A}', BidiClass::NSM),
+    ('\u{1193B}', '\u{1193C}', BidiClass::NSM),
+    ('\u{1193E}', '\u{1193E}', BidiClass::NSM),
+    ('\u{11943}', '\u{11943}', BidiClass::NSM),
+    ('\u{119D4}', '\u{119D7}', BidiClass::NSM),
+    ('\u{119DA}', '\u{119DB}', BidiClass::NSM),
+    ('\u{119E0}', '\u{119E0}', BidiClass::NSM),
+    ('\u{11A01}', '\u{11A06}', BidiClass::NSM),
+    ('\u{11A09}', '\u{11A0A}', BidiClass::NSM),
+    ('\u{11A33}', '\u{11A38}', BidiClass::NSM),
+    ('\u{11A3B}', '\u{11A3E}', BidiClass::NSM),
+    ('\u{11A47}', '\u{11A47}', BidiClass::NSM),
+    ('\u{11A51}', '\u{11A56}', BidiClass::NSM),
+    ('\u{11A59}', '\u{11A5B}', BidiClass::NSM),
+    ('\u{11A8A}', '\u{11A96}', BidiClass::NSM),
+    ('\u{11A98}', '\u{11A99}', BidiClass::NSM),
+    ('\u{11C30}', '\u{11C36}', BidiClass::NSM),
+    ('\u{11C38}', '\u{11C3D}', BidiClass::NSM),
+    ('\u{11C92}', '\u{11CA7}', BidiClass::NSM),
+    ('\u{11CAA}', '\u{11CB0}', BidiClass::NSM),
+    ('\u{11CB2}', '\u{11CB3}', BidiClass::NSM),
+    ('\u{11CB5}', '\u{11CB6}', BidiClass::NSM),
+    ('\u{11D31}', '\u{11D36}', BidiClass::NSM),
+    ('\u{11D3A}', '\u{11D3A}', BidiClass::NSM),
+    ('\u{11D3C}', '\u{11D3D}', BidiClass::NSM),
+    ('\u{11D3F}', '\u{11D45}', BidiClass::NSM),
+    ('\u{11D47}', '\u{11D47}', BidiClass::NSM),
+    ('\u{11D90}', '\u{11D91}', BidiClass::NSM),
+    ('\u{11D95}', '\u{11D95}', BidiClass::NSM),
+    ('\u{11D97}', '\u{11D97}', BidiClass::NSM),
+    ('\u{11EF3}', '\u{11EF4}', BidiClass::NSM),
+    ('\u{11FD5}', '\u{11FDC}', BidiClass::ON),
+    ('\u{11FDD}', '\u{11FE0}', BidiClass::ET),
+    ('\u{11FE1}', '\u{11FF1}', BidiClass::ON),
+    ('\u{16AF0}', '\u{16AF4}', BidiClass::NSM),
+    ('\u{16B30}', '\u{16B36}', BidiClass::NSM),
+    ('\u{16F4F}', '\u{16F4F}', BidiClass::NSM),
+    ('\u{16F8F}', '\u{16F92}', BidiClass::NSM),
+    ('\u{16FE2}', '\u{16FE2}', BidiClass::ON),
+    ('\u{16FE4}', '\u{16FE4}', BidiClass::NSM),
+    ('\u{1BC9D}', '\u{1BC9E}', BidiClass::NSM),
+    ('\u{1BCA0}', '\u{1BCA3}', BidiClass::BN),
+    ('\u{1CF00}', '\u{1CF2D}', BidiClass::NSM),
+    ('\u{1CF30}', '\u{1CF46}', BidiClass::NSM),
+    ('\u{1D167}', '\u{1D169}', BidiClass::NSM),
+    ('\u{1D173}', '\u{1D17A}', BidiClass::BN),
+    ('\u{1D17B}', '\u{1D182}', BidiClass::NSM),
+    ('\u{1D185}', '\u{1D18B}', BidiClass::NSM),
+    ('\u{1D1AA}', '\u{1D1AD}', BidiClass::NSM),
+    ('\u{1D1E9}', '\u{1D1EA}', BidiClass::ON),
+    ('\u{1D200}', '\u{1D241}', BidiClass::ON),
+    ('\u{1D242}', '\u{1D244}', BidiClass::NSM),
+    ('\u{1D245}', '\u{1D245}', BidiClass::ON),
+    ('\u{1D300}', '\u{1D356}', BidiClass::ON),
+    ('\u{1D6DB}', '\u{1D6DB}', BidiClass::ON),
+    ('\u{1D715}', '\u{1D715}', BidiClass::ON),
+    ('\u{1D74F}', '\u{1D74F}', BidiClass::ON),
+    ('\u{1D789}', '\u{1D789}', BidiClass::ON),
+    ('\u{1D7C3}', '\u{1D7C3}', BidiClass::ON),
+    ('\u{1D7CE}', '\u{1D7FF}', BidiClass::EN),
+    ('\u{1DA00}', '\u{1DA36}', BidiClass::NSM),
+    ('\u{1DA3B}', '\u{1DA6C}', BidiClass::NSM),
+    ('\u{1DA75}', '\u{1DA75}', BidiClass::NSM),
+    ('\u{1DA84}', '\u{1DA84}', BidiClass::NSM),
+    ('\u{1DA9B}', '\u{1DA9F}', BidiClass::NSM),
+    ('\u{1DAA1}', '\u{1DAAF}', BidiClass::NSM),
+    ('\u{1E000}', '\u{1E006}', BidiClass::NSM),
+    ('\u{1E008}', '\u{1E018}', BidiClass::NSM),
+    ('\u{1E01B}', '\u{1E021}', BidiClass::NSM),
+    ('\u{1E023}', '\u{1E024}', BidiClass::NSM),
+    ('\u{1E026}', '\u{1E02A}', BidiClass::NSM),
+    ('\u{1E130}', '\u{1E136}', BidiClass::NSM),
+    ('\u{1E2AE}', '\u{1E2AE}', BidiClass::NSM),
+    ('\u{1E2EC}', '\u{1E2EF}', BidiClass::NSM),
+    ('\u{1E2FF}', '\u{1E2FF}', BidiClass::ET),
+    ('\u{1E800}', '\u{1E8CF}', BidiClass::R),
+    ('\u{1E8D0}', '\u{1E8D6}', BidiClass::NSM),
+    ('\u{1E8D7}', '\u{1E943}', BidiClass::R),
+    ('\u{1E944}', '\u{1E94A}', BidiClass::NSM),
+    ('\u{1E94B}', '\u{1EC6F}', BidiClass::R),
+    ('\u{1EC70}', '\u{1ECBF}', BidiClass::AL),
+    ('\u{1ECC0}', '\u{1ECFF}', BidiClass::R),
+    ('\u{1ED00}', '\u{1ED4F}', BidiClass::AL),
+    ('\u{1ED50}', '\u{1EDFF}', BidiClass::R),
+    ('\u{1EE00}', '\u{1EEEF}', BidiClass::AL),
+    ('\u{1EEF0}', '\u{1EEF1}', BidiClass::ON),
+    ('\u{1EEF2}', '\u{1EEFF}', BidiClass::AL),
+    ('\u{1EF00}', '\u{1EFFF}', BidiClass::R),
+    ('\u{1F000}', '\u{1F02B}', BidiClass::ON),
+    ('\u{1F030}', '\u{1F093}', BidiClass::ON),
+    ('\u{1F0A0}', '\u{1F0AE}', BidiClass::ON),
+    ('\u{1F0B1}', '\u{1F0BF}', BidiClass::ON),
+    ('\u{1F0C1}', '\u{1F0CF}', BidiClass::ON),
+    ('\u{1F0D1}', '\u{1F0F5}', BidiClass::ON),
+    ('\u{1F100}', '\u{1F10A}', BidiClass::EN),
+    ('\u{1F10B}', '\u{1F10F}', BidiClass::ON),
+    ('\u{1F12F}', '\u{1F12F}', BidiClass::ON),
+    ('\u{1F16A}', '\u{1F16F}', BidiClass::ON),
+    ('\u{1F1AD}', '\u{1F1AD}', BidiClass::ON),
+    ('\u{1F260}', '\u{1F265}', BidiClass::ON),
+    ('\u{1F300}', '\u{1F6D7}', BidiClass::ON),
+    ('\u{1F6DD}', '\u{1F6EC}', BidiClass::ON),
+    ('\u{1F6F0}', '\u{1F6FC}', BidiClass::ON),
+    ('\u{1F700}', '\u{1F773}', BidiClass::ON),
+    ('\u{1F780}', '\u{1F7D8}', BidiClass::ON),
+    ('\u{1F7E0}', '\u{1F7EB}', BidiClass::ON),
+    ('\u{1F7F0}', '\u{1F7F0}', BidiClass::ON),
+    ('\u{1F800}', '\u{1F80B}', BidiClass::ON),
+    ('\u{1F810}', '\u{1F847}', BidiClass::ON),
+    ('\u{1F850}', '\u{1F859}', BidiClass::ON),
+    ('\u{1F860}', '\u{1F887}', BidiClass::ON),
+    ('\u{1F890}', '\u{1F8AD}', BidiClass::ON),
+    ('\u{1F8B0}', '\u{1F8B1}', BidiClass::ON),
+    ('\u{1F900}', '\u{1FA53}', BidiClass::ON),
+    ('\u{1FA60}', '\u{1FA6D}', BidiClass::ON),
+    ('\u{1FA70}', '\u{1FA74}', BidiClass::ON),
+    ('\u{1FA78}', '\u{1FA7C}', BidiClass::ON),
+    ('\u{1FA80}', '\u{1FA86}', BidiClass::ON),
+    ('\u{1FA90}', '\u{1FAAC}', BidiClass::ON),
+    ('\u{1FAB0}', '\u{1FABA}', BidiClass::ON),
+    ('\u{1FAC0}', '\u{1FAC5}', BidiClass::ON),
+    ('\u{1FAD0}', '\u{1FAD9}', BidiClass::ON),
+    ('\u{1FAE0}', '\u{1FAE7}', BidiClass::ON),
+    ('\u{1FAF0}', '\u{1FAF6}', BidiClass::ON),
+    ('\u{1FB00}', '\u{1FB92}', BidiClass::ON),
+    ('\u{1FB94}', '\u{1FBCA}', BidiClass::ON),
+    ('\u{1FBF0}', '\u{1FBF9}', BidiClass::EN),
+    ('\u{1FFFE}', '\u{1FFFF}', BidiClass::BN),
+    ('\u{2FFFE}', '\u{2FFFF}', BidiClass::BN),
+    ('\u{3FFFE}', '\u{3FFFF}', BidiClass::BN),
+    ('\u{4FFFE}', '\u{4FFFF}', BidiClass::BN),
+    ('\u{5FFFE}', '\u{5FFFF}', BidiClass::BN),
+    ('\u{6FFFE}', '\u{6FFFF}', BidiClass::BN),
+    ('\u{7FFFE}', '\u{7FFFF}', BidiClass::BN),
+    ('\u{8FFFE}', '\u{8FFFF}', BidiClass::BN),
+    ('\u{9FFFE}', '\u{9FFFF}', BidiClass::BN),
+    ('\u{AFFFE}', '\u{AFFFF}', BidiClass::BN),
+    ('\u{BFFFE}', '\u{BFFFF}', BidiClass::BN),
+    ('\u{CFFFE}', '\u{CFFFF}', BidiClass::BN),
+    ('\u{DFFFE}', '\u{E00FF}', BidiClass::BN),
+    ('\u{E0100}', '\u{E01EF}', BidiClass::NSM),
+    ('\u{E01F0}', '\u{E0FFF}', BidiClass::BN),
+    ('\u{EFFFE}', '\u{EFFFF}', BidiClass::BN),
+    ('\u{FFFFE}', '\u{FFFFF}', BidiClass::BN),
+    ('\u{10FFFE}', '\u{10FFFF}', BidiClass::BN),
+];
+
+/// Paired brackets: the bracket, its pair and whether it is an opening bracket, sorted
+/// by code point.
+pub(crate) static BIDI_PAIRED_BRACKETS: &[(char, char, bool)] = &[
+    ('\u{28}', '\u{29}', true),
+    ('\u{29}', '\u{28}', false),
+    ('\u{5B}', '\u{5D}', true),
+    ('\u{5D}', '\u{5B}', false),
+    ('\u{7B}', '\u{7D}', true),
+    ('\u{7D}', '\u{7B}', false),
+    ('\u{F3A}', '\u{F3B}', true),
+    ('\u{F3B}', '\u{F3A}', false),
+    ('\u{F3C}', '\u{F3D}', true),
+    ('\u{F3D}', '\u{F3C}', false),
+    ('\u{169B}', '\u{169C}', true),
+    ('\u{169C}', '\u{169B}', false),
+    ('\u{2045}', '\u{2046}', true),
+    ('\u{2046}', '\u{2045}', false),
+    ('\u{207D}', '\u{207E}', true),
+    ('\u{207E}', '\u{207D}', false),
+    ('\u{208D}', '\u{208E}', true),
+    ('\u{208E}', '\u{208D}', false),
+    ('\u{2308}', '\u{2309}', true),
+    ('\u{2309}', '\u{2308}', false),
+    ('\u{230A}', '\u{230B}', true),
+    ('\u{230B}', '\u{230A}', false),
+    ('\u{2329}', '\u{232A}', true),
+    ('\u{232A}', '\u{2329}', false),
+    ('\u{2768}', '\u{2769}', true),
+    ('\u{2769}', '\u{2768}', false),
+    ('\u{276A}', '\u{276B}', true),
+    ('\u{276B}', '\u{276A}', false),
+    ('\u{276C}', '\u{276D}', true),
+    ('\u{276D}', '\u{276C}', false),
+    ('\u{276E}', '\u{276F}', true),
+    ('\u{276F}', '\u{276E}', false),
+    ('\u{2770}', '\u{2771}', true),
+    ('\u{2771}', '\u{2770}', false),
+    ('\u{2772}', '\u{2773}', true),
+    ('\u{2773}', '\u{2772}', false),
+    ('\u{2774}', '\u{2775}', true),
+    ('\u{2775}', '\u{2774}', false),
+    ('\u{27C5}', '\u{27C6}', true),
+    ('\u{27C6}', '\u{27C5}', false),
+    ('\u{27E6}', '\u{27E7}', true),
+    ('\u{27E7}', '\u{27E6}', false),
+    ('\u{27E8}', '\u{27E9}', true),
+    ('\u{27E9}', '\u{27E8}', false),
+    ('\u{27EA}', '\u{27EB}', true),
+    ('\u{27EB}', '\u{27EA}', false),
+    ('\u{27EC}', '\u{27ED}', true),
+    ('\u{27ED}', '\u{27EC}', false),
+    ('\u{27EE}', '\u{27EF}', true),
+    ('\u{27EF}', '\u{27EE}', false),
+    ('\u{2983}', '\u{2984}', true),
+    ('\u{2984}', '\u{2983}', false),
+    ('\u{2985}', '\u{2986}', true),
+    ('\u{2986}', '\u{2985}', false),
+    ('\u{2987}', '\u{2988}', true),
+    ('\u{2988}', '\u{2987}', false),
+    ('\u{2989}', '\u{298A}', true),
+    ('\u{298A}', '\u{2989}', false),
+    ('\u{298B}', '\u{298C}', true),
+    ('\u{298C}', '\u{298B}', false),
+    ('\u{298D}', '\u{2990}', true),
+    ('\u{298E}', '\u{298F}', false),
+    ('\u{298F}', '\u{298E}', true),
+    ('\u{2990}', '\u{298D}', false),
+    ('\u{2991}', '\u{2992}', true),
+    ('\u{2992}', '\u{2991}', false),
+    ('\u{2993}', '\u{2994}', true),
+    ('\u{2994}', '\u{2993}', false),
+    ('\u{2995}', '\u{2996}', true),
+    ('\u{2996}', '\u{2995}', false),
+    ('\u{2997}', '\u{2998}', true),
+    ('\u{2998}', '\u{2997}', false),
+    ('\u{29D8}', '\u{29D9}', true),
+    ('\u{29D9}', '\u{29D8}', false),
+    ('\u{29DA}', '\u{29DB}', true),
+    ('\u{29DB}', '\u{29DA}', false),
+    ('\u{29FC}', '\u{29FD}', true),
+    ('\u{29FD}', '\u{29FC}', false),
+    ('\u{2E22}', '\u{2E23}', true),
+    ('\u{2E23}', '\u{2E22}', false),
+    ('\u{2E24}', '\u{2E25}', true),
+    ('\u{2E25}', '\u{2E24}', false),
+    ('\u{2E26}', '\u{2E27}', true),
+    ('\u{2E27}', '\u{2E26}', false),
+    ('\u{2E28}', '\u{2E29}', true),
+    ('\u{2E29}', '\u{2E28}', false),
+    ('\u{2E55}', '\u{2E56}', true),
+    ('\u{2E56}', '\u{2E55}', false),
+    ('\u{2E57}', '\u{2E58}', true),
+    ('\u{2E58}', '\u{2E57}', false),
+    ('\u{2E59}', '\u{2E5A}', true),
+    ('\u{2E5A}', '\u{2E59}', false),
+    ('\u{2E5B}', '\u{2E5C}', true),
+    ('\u{2E5C}', '\u{2E5B}', false),
+    ('\u{3008}', '\u{3009}', true),
+    ('\u{3009}', '\u{3008}', false),
+    ('\u{300A}', '\u{300B}', true),
+    ('\u{300B}', '\u{300A}', false),
+    ('\u{300C}', '\u{300D}', true),
+    ('\u{300D}', '\u{300C}', false),
+    ('\u{300E}', '\u{300F}', true),
+    ('\u{300F}', '\u{300E}', false),
+    ('\u{3010}', '\u{3011}', true),
+    ('\u{3011}', '\u{3010}', false),
+    ('\u{3014}', '\u{3015}', true),
+    ('\u{3015}', '\u{3014}', false),
+    ('\u{3016}', '\u{3017}', true),
+    ('\u{3017}', '\u{3016}', false),
+    ('\u{3018}', '\u{3019}', true),
+    ('\u{3019}', '\u{3018}', false),
+    ('\u{301A}', '\u{301B}', true),
+    ('\u{301B}', '\u{301A}', false),
+    ('\u{FE59}', '\u{FE5A}', true),
+    ('\u{FE5A}', '\u{FE59}', false),
+    ('\u{FE5B}', '\u{FE5C}', true),
+    ('\u{FE5C}', '\u{FE5B}', false),
+    ('\u{FE5D}', '\u{FE5E}', true),
+    ('\u{FE5E}', '\u{FE5D}', false),
+    ('\u{FF08}', '\u{FF09}', true),
+    ('\u{FF09}', '\u{FF08}', false),
+    ('\u{FF3B}', '\u{FF3D}', true),
+    ('\u{FF3D}', '\u{FF3B}', false),
+    ('\u{FF5B}', '\u{FF5D}', true),
+    ('\u{FF5D}', '\u{FF5B}', false),
+    ('\u{FF5F}', '\u{FF60}', true),
+    ('\u{FF60}', '\u{FF5F}', false),
+    ('\u{FF62}', '\u{FF63}', true),
+    ('\u{FF63}', '\u{FF62}', false),
+];
@@ -0,0 +1,6316 @@
+// This file is generated by `unicode_string_gen`. Do not edit it manually.
+
+/// The prototype of every confusable char, sorted by code point.
+pub(crate) static CONFUSABLES: &[(char, &[char])] = &[
+    ('\u{22}', &['\u{27}', '\u{27}']),
+    ('\u{25}', &['\u{BA}', '\u{2F}', '\u{2080}']),
+    ('\u{30}', &['\u{4F}']),
+    ('\u{31}', &['\u{6C}']),
+    ('\u{49}', &['\u{6C}']),
+    ('\u{60}', &['\u{27}']),
+    ('\u{6D}', &['\u{72}', '\u{6E}']),
+    ('\u{7C}', &['\u{6C}']),
+    ('\u{A0}', &['\u{20}']),
+    ('\u{A2}', &['\u{63}', '\u{338}']),
+    ('\u{A5}', &['\u{59}', '\u{335}']),
+    ('\u{AF}', &['\u{2C9}']),
+    ('\u{B4}', &['\u{27}']),
+    ('\u{B5}', &['\u{3BC}']),
+    ('\u{B8}', &['\u{2C}']),
+    ('\u{C6}', &['\u{41}', '\u{45}']),
+    ('\u{C7}', &['\u{43}', '\u{326}']),
+    ('\u{D0}', &['\u{44}', '\u{335}']),
+    ('\u{D7}', &['\u{78}']),
+    ('\u{D8}', &['\u{4F}', '\u{338}']),
+    ('\u{E6}', &['\u{61}', '\u{65}']),
+    ('\u{E7}', &['\u{63}', '\u{326}']),
+    ('\u{F0}', &['\u{2202}', '\u{335}']),
+    ('\u{F6}', &['\u{629}']),
+    ('\u{F8}', &['\u{6F}', '\u{338}']),
+    ('\u{110}', &['\u{44}', '\u{335}']),
+    ('\u{111}', &['\u{64}', '\u{335}']),
+    ('\u{11A}', &['\u{114}']),
+    ('\u{11B}', &['\u{115}']),
+    ('\u{126}', &['\u{48}', '\u{335}']),
+    ('\u{127}', &['\u{68}', '\u{335}']),
+    ('\u{131}', &['\u{69}']),
+    ('\u{132}', &['\u{6C}', '\u{4A}']),
+    ('\u{133}', &['\u{69}', '\u{6A}']),
+    ('\u{13F}', &['\u{6C}', '\u{B7}']),
+    ('\u{140}', &['\u{6C}', '\u{B7}']),
+    ('\u{141}', &['\u{4C}', '\u{338}']),
+    ('\u{142}', &['\u{6C}', '\u{338}']),
+    ('\u{146}', &['\u{272}']),
+    ('\u{149}', &['\u{27}', '\u{6E}']),
+    ('\u{150}', &['\u{D6}']),
+    ('\u{152}', &['\u{4F}', '\u{45}']),
+    ('\u{153}', &['\u{6F}', '\u{65}']),
+    ('\u{163}', &['\u{1AB}']),
+    ('\u{166}', &['\u{54}', '\u{335}']),
+    ('\u{167}', &['\u{74}', '\u{335}']),
+    ('\u{17F}', &['\u{66}']),
+    ('\u{180}', &['\u{62}', '\u{335}']),
+    ('\u{181}', &['\u{27}', '\u{42}']),
+    ('\u{182}', &['\u{62}', '\u{304}']),
+    ('\u{183}', &['\u{62}', '\u{304}']),
+    ('\u{184}', &['\u{62}']),
+    ('\u{187}', &['\u{43}', '\u{27}']),
+    ('\u{189}', &['\u{44}', '\u{335}']),
+    ('\u{18A}', &['\u{27}', '\u{44}']),
+    ('\u{18C}', &['\u{64}', '\u{304}']),
+    ('\u{18D}', &['\u{67}']),
+    ('\u{191}', &['\u{46}', '\u{326}']),
+    ('\u{192}', &['\u{66}', '\u{326}']),
+    ('\u{193}', &['\u{47}', '\u{27}']),
+    ('\u{196}', &['\u{6C}']),
+    ('\u{197}', &['\u{6C}', '\u{335}']),
+    ('\u{198}', &['\u{4B}', '\u{27}']),
+    ('\u{199}', &['\u{6B}', '\u{314}']),
+    ('\u{19A}', &['\u{6C}', '\u{335}']),
+    ('\u{19D}', &['\u{4E}', '\u{326}']),
+    ('\u{19E}', &['\u{6E}', '\u{329}']),
+    ('\u{19F}', &['\u{4F}', '\u{335}']),
+    ('\u{1A0}', &['\u{4F}', '\u{27}']),
+    ('\u{1A1}', &['\u{6F}', '\u{27}']),
+    ('\u{1A4}', &['\u{27}', '\u{50}']),
+    ('\u{1A5}', &['\u{70}', '\u{314}']),
+    ('\u{1A6}', &['\u{52}']),
+    ('\u{1A7}', &['\u{32}']),
+    ('\u{1AC}', &['\u{27}', '\u{54}']),
+    ('\u{1AD}', &['\u{74}', '\u{314}']),
+    ('\u{1AE}', &['\u{54}', '\u{328}']),
+    ('\u{1B3}', &['\u{27}', '\u{59}']),
+    ('\u{1B4}', &['\u{79}', '\u{314}']),
+    ('\u{1B5}', &['\u{5A}', '\u{335}']),
+    ('\u{1B6}', &['\u{7A}', '\u{335}']),
+    ('\u{1B7}', &['\u{33}']),
+    ('\u{1BB}', &['\u{32}', '\u{335}']),
+    ('\u{1BC}', &['\u{35}']),
+    ('\u{1BD}', &['\u{73}']),
+    ('\u{1BF}', &['\u{FE}']),
+    ('\u{1C0}', &['\u{6C}']),
+    ('\u{1C1}', &['\u{6C}', '\u{6C}']),
+    ('\u{1C3}', &['\u{21}']),
+    ('\u{1C4}', &['\u{44}', '\u{17D}']),
+    ('\u{1C5}', &['\u{44}', '\u{17E}']),
+    ('\u{1C6}', &['\u{64}', '\u{17E}']),
+    ('\u{1C7}', &['\u{4C}', '\u{4A}']),
+    ('\u{1C8}', &['\u{4C}', '\u{6A}']),
+    ('\u{1C9}', &['\u{6C}', '\u{6A}']),
+    ('\u{1CA}', &['\u{4E}', '\u{4A}']),
+    ('\u{1CB}', &['\u{4E}', '\u{6A}']),
+    ('\u{1CC}', &['\u{6E}', '\u{6A}']),
+    ('\u{1CD}', &['\u{102}']),
+    ('\u{1CE}', &['\u{103}']),
+    ('\u{1CF}', &['\u{12C}']),
+    ('\u{1D0}', &['\u{12D}']),
+    ('\u{1D1}', &['\u{14E}']),
+    ('\u{1D2}', &['\u{14F}']),
+    ('\u{1D3}', &['\u{16C}']),
+    ('\u{1D4}', &['\u{16D}']),
+    ('\u{1E4}', &['\u{47}', '\u{335}']),
+    ('\u{1E5}', &['\u{67}', '\u{335}']),
+    ('\u{1E6}', &['\u{11E}']),
+    ('\u{1E7}', &['\u{11F}']),
+    ('\u{1F1}', &['\u{44}', '\u{5A}']),
+    ('\u{1F2}', &['\u{44}', '\u{7A}']),
+    ('\u{1F3}', &['\u{64}', '\u{7A}']),
+    ('\u{1F5}', &['\u{123}']),
+    ('\u{1FE}', &['\u{4F}', '\u{338}', '\u{301}']),
+    ('\u{21A}', &['\u{162}']),
+    ('\u{21B}', &['\u{1AB}']),
+    ('\u{21C}', &['\u{33}']),
+    ('\u{222}', &['\u{38}']),
+    ('\u{223}', &['\u{38}']),
+    ('\u{224}', &['\u{5A}', '\u{326}']),
+    ('\u{225}', &['\u{7A}', '\u{326}']),
+    ('\u{226}', &['\u{C5}']),
+    ('\u{227}', &['\u{E5}']),
+    ('\u{23C}', &['\u{63}', '\u{338}']),
+    ('\u{23E}', &['\u{54}', '\u{338}']),
+    ('\u{241}', &['\u{3F}']),
+    ('\u{244}', &['\u{55}', '\u{335}']),
+    ('\u{246}', &['\u{45}', '\u{338}']),
+    ('\u{247}', &['\u{65}', '\u{338}']),
+    ('\u{248}', &['\u{4A}', '\u{335}']),
+    ('\u{249}', &['\u{6A}', '\u{335}']),
+    ('\u{24D}', &['\u{72}', '\u{335}']),
+    ('\u{24E}', &['\u{59}', '\u{335}']),
+    ('\u{24F}', &['\u{79}', '\u{335}']),
+    ('\u{251}', &['\u{61}']),
+    ('\u{253}', &['\u{62}', '\u{314}']),
+    ('\u{256}', &['\u{64}', '\u{328}']),
+    ('\u{257}', &['\u{64}', '\u{314}']),
+    ('\u{259}', &['\u{1DD}']),
+    ('\u{25A}', &['\u{1DD}', '\u{2DE}']),
+    ('\u{25B}', &['\u{A793}']),
+    ('\u{260}', &['\u{67}', '\u{314}']),
+    ('\u{261}', &['\u{67}']),
+    ('\u{263}', &['\u{79}']),
+    ('\u{266}', &['\u{68}', '\u{314}']),
+    ('\u{268}', &['\u{69}', '\u{335}']),
+    ('\u{269}', &['\u{69}']),
+    ('\u{26A}', &['\u{69}']),
+    ('\u{26B}', &['\u{6C}', '\u{334}']),
+    ('\u{26D}', &['\u{6C}', '\u{328}']),
+    ('\u{26E}', &['\u{6C}', '\u{21D}']),
+    ('\u{26F}', &['\u{77}']),
+    ('\u{271}', &['\u{72}', '\u{6E}', '\u{326}']),
+    ('\u{273}', &['\u{6E}', '\u{328}']),
+    ('\u{275}', &['\u{6F}', '\u{335}']),
+    ('\u{276}', &['\u{6F}', '\u{1D07}']),
+    ('\u{27C}', &['\u{72}', '\u{329}']),
+    ('\u{27D}', &['\u{72}', '\u{328}']),
+    ('\u{282}', &['\u{73}', '\u{328}']),
+    ('\u{28B}', &['\u{75}']),
+    ('\u{28F}', &['\u{79}']),
+    ('\u{290}', &['\u{7A}', '\u{328}']),
+    ('\u{292}', &['\u{21D}']),
+    ('\u{294}', &['\u{3F}']),
+    ('\u{2A0}', &['\u{71}', '\u{314}']),
+    ('\u{2A3}', &['\u{64}', '\u{7A}']),
+    ('\u{2A4}', &['\u{64}', '\u{21D}']),
+    ('\u{2A5}', &['\u{64}', '\u{291}']),
+    ('\u{2A6}', &['\u{74}', '\u{73}']),
+    ('\u{2A7}', &['\u{74}', '\u{283}']),
+    ('\u{2A8}', &['\u{74}', '\u{255}']),
+    ('\u{2A9}', &['\u{66}', '\u{14B}']),
+    ('\u{2AA}', &['\u{6C}', '\u{73}']),
+    ('\u{2AB}', &['\u{6C}', '\u{7A}']),
+    ('\u{2B3}', &['\u{18F4}']),
+    ('\u{2B9}', &['\u{27}']),
+    ('\u{2BA}', &['\u{27}', '\u{27}']),
+    ('\u{2BB}', &['\u{27}']),
+    ('\u{2BC}', &['\u{27}']),
+    ('\u{2BD}', &['\u{27}']),
+    ('\u{2BE}', &['\u{27}']),
+    ('\u{2BF}', &['\u{559}']),
+    ('\u{2C2}', &['\u{3C}']),
+    ('\u{2C3}', &['\u{3E}']),
+    ('\u{2C4}', &['\u{5E}']),
+    ('\u{2C6}', &['\u{5E}']),
+    ('\u{2C8}', &['\u{27}']),
+    ('\u{2CA}', &['\u{27}']),
+    ('\u{2CB}', &['\u{27}']),
+    ('\u{2D0}', &['\u{3A}']),
+    ('\u{2D3}', &['\u{559}']),
+    ('\u{2D7}', &['\u{2D}']),
+    ('\u{2D8}', &['\u{2C7}']),
+    ('\u{2D9}', &['\u{971}']),
+    ('\u{2DA}', &['\u{B0}']),
+    ('\u{2DB}', &['\u{69}']),
+    ('\u{2DC}', &['\u{7E}']),
+    ('\u{2DD}', &['\u{27}', '\u{27}']),
+    ('\u{2E1}', &['\u{18F3}']),
+    ('\u{2E2}', &['\u{18F5}']),
+    ('\u{2E4}', &['\u{2C1}']),
+    ('\u{2EE}', &['\u{27}', '\u{27}']),
+    ('\u{2F4}', &['\u{27}']),
+    ('\u{2F6}', &['\u{27}', '\u{27}']),
+    ('\u{2F8}', &['\u{3A}']),
+    ('\u{2FB}', &['\u{2EA}']),
+    ('\u{305}', &['\u{304}']),
+    ('\u{30C}', &['\u{306}']),
+    ('\u{30D}', &['\u{670}']),
+    ('\u{310}', &['\u{306}', '\u{307}']),
+    ('\u{311}', &['\u{302}']),
+    ('\u{315}', &['\u{313}']),
+    ('\u{317}', &['\u{650}']),
+    ('\u{320}', &['\u{331}']),
+    ('\u{321}', &['\u{326}']),
+    ('\u{322}', &['\u{328}']),
+    ('\u{327}', &['\u{326}']),
+    ('\u{336}', &['\u{335}']),
+    ('\u{337}', &['\u{338}']),
+    ('\u{339}', &['\u{326}']),
+    ('\u{340}', &['\u{300}']),
+    ('\u{341}', &['\u{301}']),
+    ('\u{342}', &['\u{303}']),
+    ('\u{343}', &['\u{313}']),
+    ('\u{345}', &['\u{328}']),
+    ('\u{347}', &['\u{333}']),
+    ('\u{357}', &['\u{350}']),
+    ('\u{358}', &['\u{307}']),
+    ('\u{366}', &['\u{30A}']),
+    ('\u{36E}', &['\u{306}']),
+    ('\u{370}', &['\u{2C75}']),
+    ('\u{374}', &['\u{27}']),
+    ('\u{375}', &['\u{2CF}']),
+    ('\u{376}', &['\u{418}']),
+    ('\u{377}', &['\u{1D0E}']),
+    ('\u{37A}', &['\u{69}']),
+    ('\u{37B}', &['\u{254}']),
+    ('\u{37D}', &['\u{A73F}']),
+    ('\u{37E}', &['\u{3B}']),
+    ('\u{37F}', &['\u{4A}']),
+    ('\u{384}', &['\u{27}']),
+    ('\u{387}', &['\u{B7}']),
+    ('\u{391}', &['\u{41}']),
+    ('\u{392}', &['\u{42}']),
+    ('\u{395}', &['\u{45}']),
+    ('\u{396}', &['\u{5A}']),
+    ('\u{397}', &['\u{48}']),
+    ('\u{398}', &['\u{4F}', '\u{335}']),
+    ('\u{399}', &['\u{6C}']),
+    ('\u{39A}', &['\u{4B}']),
+    ('\u{39B}', &['\u{245}']),
+    ('\u{39C}', &['\u{4D}']),
+    ('\u{39D}', &['\u{4E}']),
+    ('\u{39F}', &['\u{4F}']),
+    ('\u{3A1}', &['\u{50}']),
+    ('\u{3A3}', &['\u{1A9}']),
+    ('\u{3A4}', &['\u{54}']),
+    ('\u{3A5}', &['\u{59}']),
+    ('\u{3A7}', &['\u{58}']),
+    ('\u{3B1}', &['\u{61}']),
+    ('\u{3B2}', &['\u{DF}']),
+    ('\u{3B3}', &['\u{79}']),
+    ('\u{3B4}', &['\u{1E9F}']),
+    ('\u{3B5}', &['\u{A793}']),
+    ('\u{3B7}', &['\u{6E}', '\u{329}']),
+    ('\u{3B8}', &['\u{4F}', '\u{335}']),
+    ('\u{3B9}', &['\u{69}']),
+    ('\u{3BA}', &['\u{138}']),
+    ('\u{3BD}', &['\u{76}']),
+    ('\u{3BF}', &['\u{6F}']),
+    ('\u{3C1}', &['\u{70}']),
+    ('\u{3C3}', &['\u{6F}']),
+    ('\u{3C4}', &['\u{1D1B}']),
+    ('\u{3C5}', &['\u{75}']),
+    ('\u{3C6}', &['\u{278}']),
+    ('\u{3D0}', &['\u{DF}']),
+    ('\u{3D1}', &['\u{4F}', '\u{335}']),
+    ('\u{3D2}', &['\u{59}']),
+    ('\u{3D5}', &['\u{278}']),
+    ('\u{3D6}', &['\u{3C0}']),
+    ('\u{3DB}', &['\u{3C2}']),
+    ('\u{3DC}', &['\u{46}']),
+    ('\u{3E8}', &['\u{32}']),
+    ('\u{3E9}', &['\u{1A8}']),
+    ('\u{3F0}', &['\u{138}']),
+    ('\u{3F1}', &['\u{70}']),
+    ('\u{3F2}', &['\u{63}']),
+    ('\u{3F3}', &['\u{6A}']),
+    ('\u{3F4}', &['\u{4F}', '\u{335}']),
+    ('\u{3F5}', &['\u{A793}']),
+    ('\u{3F7}', &['\u{DE}']),
+    ('\u{3F8}', &['\u{FE}']),
+    ('\u{3F9}', &['\u{43}']),
+    ('\u{3FA}', &['\u{4D}']),
+    ('\u{3FD}', &['\u{186}']),
+    ('\u{3FF}', &['\u{A73E}']),
+    ('\u{404}', &['\u{A792}']),
+    ('\u{405}', &['\u{53}']),
+    ('\u{406}', &['\u{6C}']),
+    ('\u{408}', &['\u{4A}']),
+    ('\u{410}', &['\u{41}']),
+    ('\u{411}', &['\u{62}', '\u{304}']),
+    ('\u{412}', &['\u{42}']),
+    ('\u{413}', &['\u{393}']),
+    ('\u{415}', &['\u{45}']),
+    ('\u{417}', &['\u{33}']),
+    ('\u{419}', &['\u{40D}']),
+    ('\u{41A}', &['\u{4B}']),
+    ('\u{41B}', &['\u{245}']),
+    ('\u{41C}', &['\u{4D}']),
+    ('\u{41D}', &['\u{48}']),
+    ('\u{41E}', &['\u{4F}']),
+    ('\u{41F}', &['\u{3A0}']),
+    ('\u{420}', &['\u{50}']),
+    ('\u{421}', &['\u{43}']),
+    ('\u{422}', &['\u{54}']),
+    ('\u{423}', &['\u{59}']),
+    ('\u{424}', &['\u{3A6}']),
+    ('\u{425}', &['\u{58}']),
+    ('\u{42B}', &['\u{62}', '\u{6C}']),
+    ('\u{42C}', &['\u{62}']),
+    ('\u{42E}', &['\u{6C}', '\u{4F}']),
+    ('\u{430}', &['\u{61}']),
+    ('\u{431}', &['\u{36}']),
+    ('\u{432}', &['\u{299}']),
+    ('\u{433}', &['\u{72}']),
+    ('\u{435}', &['\u{65}']),
+    ('\u{437}', &['\u{25C}']),
+    ('\u{438}', &['\u{1D0E}']),
+    ('\u{43A}', &['\u{138}']),
+    ('\u{43C}', &['\u{28D}']),
+    ('\u{43D}', &['\u{29C}']),
+    ('\u{43E}', &['\u{6F}']),
+    ('\u{43F}', &['\u{3C0}']),
+    ('\u{440}', &['\u{70}']),
+    ('\u{441}', &['\u{63}']),
+    ('\u{442}', &['\u{1D1B}']),
+    ('\u{443}', &['\u{79}']),
+    ('\u{444}', &['\u{278}']),
+    ('\u{445}', &['\u{78}']),
+    ('\u{44A}', &['\u{2C9}', '\u{62}']),
+    ('\u{44B}', &['\u{185}', '\u{69}']),
+    ('\u{44C}', &['\u{185}']),
+    ('\u{44F}', &['\u{1D19}']),
+    ('\u{454}', &['\u{A793}']),
+    ('\u{455}', &['\u{73}']),
+    ('\u{456}', &['\u{69}']),
+    ('\u{458}', &['\u{6A}']),
+    ('\u{45B}', &['\u{68}', '\u{335}']),
+    ('\u{45D}', &['\u{439}']),
+    ('\u{461}', &['\u{77}']),
+    ('\u{462}', &['\u{62}', '\u{335}']),
+    ('\u{463}', &['\u{62}', '\u{335}']),
+    ('\u{470}', &['\u{3A8}']),
+    ('\u{471}', &['\u{3C8}']),
+    ('\u{472}', &['\u{4F}', '\u{335}']),
+    ('\u{473}', &['\u{6F}', '\u{335}']),
+    ('\u{474}', &['\u{56}']),
+    ('\u{475}', &['\u{76}']),
+    ('\u{47C}', &['\u{460}', '\u{486}', '\u{487}']),
+    ('\u{47D}', &['\u{77}', '\u{486}', '\u{487}']),
+    ('\u{48A}', &['\u{40D}', '\u{326}']),
+    ('\u{48B}', &['\u{439}', '\u{326}']),
+    ('\u{48C}', &['\u{62}', '\u{335}']),
+    ('\u{48D}', &['\u{62}', '\u{335}']),
+    ('\u{490}', &['\u{393}', '\u{27}']),
+    ('\u{491}', &['\u{72}', '\u{27}']),
+    ('\u{492}', &['\u{393}', '\u{335}']),
+    ('\u{493}', &['\u{72}', '\u{335}']),
+    ('\u{496}', &['\u{416}', '\u{329}']),
+    ('\u{497}', &['\u{436}', '\u{329}']),
+    ('\u{498}', &['\u{33}', '\u{326}']),
+    ('\u{499}', &['\u{25C}', '\u{326}']),
+    ('\u{49A}', &['\u{4B}', '\u{329}']),
+    ('\u{49B}', &['\u{138}', '\u{329}']),
+    ('\u{49E}', &['\u{4B}', '\u{335}']),
+    ('\u{49F}', &['\u{138}', '\u{335}']),
+    ('\u{4A2}', &['\u{48}', '\u{329}']),
+    ('\u{4A3}', &['\u{29C}', '\u{329}']),
+    ('\u{4AA}', &['\u{43}', '\u{326}']),
+    ('\u{4AB}', &['\u{63}', '\u{326}']),
+    ('\u{4AC}', &['\u{54}', '\u{329}']),
+    ('\u{4AD}', &['\u{1D1B}', '\u{329}']),
+    ('\u{4AE}', &['\u{59}']),
+    ('\u{4AF}', &['\u{79}']),
+    ('\u{4B0}', &['\u{59}', '\u{335}']),
+    ('\u{4B1}', &['\u{79}', '\u{335}']),
+    ('\u{4B2}', &['\u{58}', '\u{329}']),
+    ('\u{4BB}', &['\u{68}']),
+    ('\u{4BD}', &['\u{65}']),
+    ('\u{4BE}', &['\u{4BC}', '\u{328}']),
+    ('\u{4BF}', &['\u{65}', '\u{328}']),
+    ('\u{4C0}', &['\u{6C}']),
+    ('\u{4C5}', &['\u{245}', '\u{326}']),
+    ('\u{4C6}', &['\u{43B}', '\u{326}']),
+    ('\u{4C7}', &['\u{48}', '\u{326}']),
+    ('\u{4C8}', &['\u{29C}', '\u{326}']),
+    ('\u{4C9}', &['\u{48}', '\u{326}']),
+    ('\u{4CA}', &['\u{29C}', '\u{326}']),
+    ('\u{4CB}', &['\u{4B6}']),
+    ('\u{4CC}', &['\u{4B7}']),
+    ('\u{4CD}', &['\u{4D}', '\u{326}']),
+    ('\u{4CE}', &['\u{28D}', '\u{326}']),
+    ('\u{4CF}', &['\u{69}']),
+    ('\u{4D4}', &['\u{41}', '\u{45}']),
+    ('\u{4D5}', &['\u{61}', '\u{65}']),
+    ('\u{4D8}', &['\u{18F}']),
+    ('\u{4D9}', &['\u{1DD}']),
+    ('\u{4E0}', &['\u{33}']),
+    ('\u{4E1}', &['\u{21D}']),
+    ('\u{4E8}', &['\u{4F}', '\u{335}']),
+    ('\u{4E9}', &['\u{6F}', '\u{335}']),
+    ('\u{501}', &['\u{64}']),
+    ('\u{50A}', &['\u{1F6}']),
+    ('\u{50C}', &['\u{47}']),
+    ('\u{50D}', &['\u{262}']),
+    ('\u{510}', &['\u{190}']),
+    ('\u{511}', &['\u{A793}']),
+    ('\u{51B}', &['\u{71}']),
+    ('\u{51C}', &['\u{57}']),
+    ('\u{51D}', &['\u{77}']),
+    ('\u{53B}', &['\u{12AE}']),
+    ('\u{544}', &['\u{1206}']),
+    ('\u{54A}', &['\u{1323}']),
+    ('\u{54C}', &['\u{1261}']),
+    ('\u{54D}', &['\u{55}']),
+    ('\u{54F}', &['\u{53}']),
+    ('\u{553}', &['\u{3A6}']),
+    ('\u{555}', &['\u{4F}']),
+    ('\u{55A}', &['\u{27}']),
+    ('\u{55D}', &['\u{27}']),
+    ('\u{561}', &['\u{77}']),
+    ('\u{563}', &['\u{71}']),
+    ('\u{566}', &['\u{71}']),
+    ('\u{56E}', &['\u{1E9F}']),
+    ('\u{570}', &['\u{68}']),
+    ('\u{575}', &['\u{237}']),
+    ('\u{578}', &['\u{6E}']),
+    ('\u{57A}', &['\u{270}']),
+    ('\u{57C}', &['\u{6E}']),
+    ('\u{57D}', &['\u{75}']),
+    ('\u{581}', &['\u{67}']),
+    ('\u{584}', &['\u{66}']),
+    ('\u{585}', &['\u{6F}']),
+    ('\u{587}', &['\u{565}', '\u{582}']),
+    ('\u{589}', &['\u{3A}']),
+    ('\u{59C}', &['\u{301}']),
+    ('\u{59D}', &['\u{301}']),
+    ('\u{5A4}', &['\u{59A}']),
+    ('\u{5A8}', &['\u{599}']),
+    ('\u{5AD}', &['\u{596}']),
+    ('\u{5AE}', &['\u{598}']),
+    ('\u{5AF}', &['\u{30A}']),
+    ('\u{5B4}', &['\u{323}']),
+    ('\u{5B9}', &['\u{307}']),
+    ('\u{5BA}', &['\u{307}']),
+    ('\u{5C0}', &['\u{6C}']),
+    ('\u{5C1}', &['\u{307}']),
+    ('\u{5C2}', &['\u{307}']),
+    ('\u{5C3}', &['\u{3A}']),
+    ('\u{5C4}', &['\u{307}']),
+    ('\u{5C5}', &['\u{323}']),
+    ('\u{5D5}', &['\u{6C}']),
+    ('\u{5D8}', &['\u{76}']),
+    ('\u{5D9}', &['\u{27}']),
+    ('\u{5DF}', &['\u{6C}']),
+    ('\u{5E1}', &['\u{6F}']),
+    ('\u{5F0}', &['\u{6C}', '\u{6C}']),
+    ('\u{5F1}', &['\u{6C}', '\u{27}']),
+    ('\u{5F2}', &['\u{27}', '\u{27}']),
+    ('\u{5F3}', &['\u{27}']),
+    ('\u{5F4}', &['\u{27}', '\u{27}']),
+    ('\u{609}', &['\u{BA}', '\u{2F}', '\u{2080}', '\u{2080}']),
+    ('\u{60A}', &['\u{BA}', '\u{2F}', '\u{2080}', '\u{2080}', '\u{2080}']),
+    ('\u{60D}', &['\u{2C}']),
+    ('\u{60F}', &['\u{639}']),
+    ('\u{618}', &['\u{301}']),
+    ('\u{619}', &['\u{313}']),
+    ('\u{61A}', &['\u{650}']),
+    ('\u{623}', &['\u{6C}', '\u{674}']),
+    ('\u{624}', &['\u{648}', '\u{674}']),
+    ('\u{625}', &['\u{6C}', '\u{655}']),
+    ('\u{626}', &['\u{649}', '\u{674}']),
+    ('\u{627}', &['\u{6C}']),
+    ('\u{62B}', &['\u{649}', '\u{6DB}']),
+    ('\u{634}', &['\u{633}', '\u{6DB}']),
+    ('\u{63D}', &['\u{649}', '\u{302}']),
+    ('\u{63F}', &['\u{649}', '\u{6DB}']),
+    ('\u{647}', &['\u{6F}']),
+    ('\u{64A}', &['\u{649}']),
+    ('\u{64B}', &['\u{30B}']),
+    ('\u{64E}', &['\u{301}']),
+    ('\u{64F}', &['\u{313}']),
+    ('\u{652}', &['\u{30A}']),
+    ('\u{653}', &['\u{303}']),
+    ('\u{656}', &['\u{329}']),
+    ('\u{657}', &['\u{312}']),
+    ('\u{658}', &['\u{306}']),
+    ('\u{659}', &['\u{304}']),
+    ('\u{65A}', &['\u{306}']),
+    ('\u{65B}', &['\u{302}']),
+    ('\u{65C}', &['\u{323}']),
+    ('\u{65D}', &['\u{314}']),
+    ('\u{65F}', &['\u{655}']),
+    ('\u{660}', &['\u{2E}']),
+    ('\u{661}', &['\u{6C}']),
+    ('\u{665}', &['\u{6F}']),
+    ('\u{667}', &['\u{56}']),
+    ('\u{668}', &['\u{245}']),
+    ('\u{66A}', &['\u{BA}', '\u{2F}', '\u{2080}']),
+    ('\u{66B}', &['\u{2C}']),
+    ('\u{66C}', &['\u{60C}']),
+    ('\u{66D}', &['\u{2A}']),
+    ('\u{66E}', &['\u{649}']),
+    ('\u{66F}', &['\u{6A1}']),
+    ('\u{672}', &['\u{6C}', '\u{674}']),
+    ('\u{673}', &['\u{6C}', '\u{655}']),
+    ('\u{675}', &['\u{6C}', '\u{674}']),
+    ('\u{676}', &['\u{648}', '\u{674}']),
+    ('\u{677}', &['\u{648}', '\u{313}', '\u{674}']),
+    ('\u{678}', &['\u{649}', '\u{674}']),
+    ('\u{679}', &['\u{649}', '\u{615}']),
+    ('\u{67E}', &['\u{649}', '\u{6DB}']),
+    ('\u{681}', &['\u{62D}', '\u{654}']),
+    ('\u{685}', &['\u{62D}', '\u{6DB}']),
+    ('\u{688}', &['\u{62F}', '\u{615}']),
+    ('\u{68B}', &['\u{68A}', '\u{615}']),
+    ('\u{68E}', &['\u{62F}', '\u{6DB}']),
+    ('\u{691}', &['\u{631}', '\u{615}']),
+    ('\u{692}', &['\u{631}', '\u{306}']),
+    ('\u{698}', &['\u{631}', '\u{6DB}']),
+    ('\u{69E}', &['\u{635}', '\u{6DB}']),
+    ('\u{69F}', &['\u{637}', '\u{6DB}']),
+    ('\u{6A4}', &['\u{6A1}', '\u{6DB}']),
+    ('\u{6A7}', &['\u{641}']),
+    ('\u{6A8}', &['\u{6A1}', '\u{6DB}']),
+    ('\u{6A9}', &['\u{643}']),
+    ('\u{6AA}', &['\u{643}']),
+    ('\u{6AD}', &['\u{643}', '\u{6DB}']),
+    ('\u{6B4}', &['\u{6AF}', '\u{6DB}']),
+    ('\u{6B5}', &['\u{644}', '\u{306}']),
+    ('\u{6B7}', &['\u{644}', '\u{6DB}']),
+    ('\u{6BA}', &['\u{649}']),
+    ('\u{6BB}', &['\u{649}', '\u{615}']),
+    ('\u{6BD}', &['\u{649}', '\u{6DB}']),
+    ('\u{6BE}', &['\u{6F}']),
+    ('\u{6C1}', &['\u{6F}']),
+    ('\u{6C2}', &['\u{6C0}']),
+    ('\u{6C3}', &['\u{629}']),
+    ('\u{6C6}', &['\u{648}', '\u{306}']),
+    ('\u{6C7}', &['\u{648}', '\u{313}']),
+    ('\u{6C8}', &['\u{648}', '\u{670}']),
+    ('\u{6C9}', &['\u{648}', '\u{302}']),
+    ('\u{6CB}', &['\u{648}', '\u{6DB}']),
+    ('\u{6CC}', &['\u{649}']),
+    ('\u{6CE}', &['\u{649}', '\u{306}']),
+    ('\u{6D0}', &['\u{67B}']),
+    ('\u{6D1}', &['\u{649}', '\u{6DB}']),
+    ('\u{6D2}', &['\u{649}']),
+    ('\u{6D4}', &['\u{2D}']),
+    ('\u{6D5}', &['\u{6F}']),
+    ('\u{6DF}', &['\u{30A}']),
+    ('\u{6E8}', &['\u{306}', '\u{307}']),
+    ('\u{6EC}', &['\u{307}']),
+    ('\u{6EE}', &['\u{62F}', '\u{302}']),
+    ('\u{6EF}', &['\u{631}', '\u{302}']),
+    ('\u{6F0}', &['\u{2E}']),
+    ('\u{6F1}', &['\u{6C}']),
+    ('\u{6F2}', &['\u{662}']),
+    ('\u{6F3}', &['\u{663}']),
+    ('\u{6F4}', &['\u{664}']),
+    ('\u{6F5}', &['\u{6F}']),
+    ('\u{6F6}', &['\u{666}']),
+    ('\u{6F7}', &['\u{56}']),
+    ('\u{6F8}', &['\u{245}']),
+    ('\u{6F9}', &['\u{669}']),
+    ('\u{6FD}', &['\u{621}', '\u{348}']),
+    ('\u{6FE}', &['\u{645}', '\u{348}']),
+    ('\u{6FF}', &['\u{6F}', '\u{302}']),
+    ('\u{701}', &['\u{2E}']),
+    ('\u{702}', &['\u{2E}']),
+    ('\u{703}', &['\u{3A}']),
+    ('\u{704}', &['\u{3A}']),
+    ('\u{740}', &['\u{307}']),
+    ('\u{741}', &['\u{307}']),
+    ('\u{742}', &['\u{73C}']),
+    ('\u{747}', &['\u{301}']),
+    ('\u{751}', &['\u{628}', '\u{6DB}']),
+    ('\u{756}', &['\u{649}', '\u{306}']),
+    ('\u{762}', &['\u{6AC}']),
+    ('\u{763}', &['\u{643}', '\u{6DB}']),
+    ('\u{767}', &['\u{754}']),
+    ('\u{768}', &['\u{646}', '\u{615}']),
+    ('\u{769}', &['\u{646}', '\u{306}']),
+    ('\u{76C}', &['\u{631}', '\u{654}']),
+    ('\u{771}', &['\u{697}', '\u{615}']),
+    ('\u{772}', &['\u{62D}', '\u{654}']),
+    ('\u{77E}', &['\u{633}', '\u{302}']),
+    ('\u{7C0}', &['\u{4F}']),
+    ('\u{7CA}', &['\u{6C}']),
+    ('\u{7EB}', &['\u{304}']),
+    ('\u{7ED}', &['\u{307}']),
+    ('\u{7EE}', &['\u{302}']),
+    ('\u{7F3}', &['\u{308}']),
+    ('\u{7F4}', &['\u{27}']),
+    ('\u{7F5}', &['\u{27}']),
+    ('\u{7FA}', &['\u{5F}']),
+    ('\u{8A1}', &['\u{628}', '\u{654}']),
+    ('\u{8A4}', &['\u{6A2}', '\u{6DB}']),
+    ('\u{8A7}', &['\u{645}', '\u{6DB}']),
+    ('\u{8A8}', &['\u{649}', '\u{654}']),
+    ('\u{8A9}', &['\u{754}']),
+    ('\u{8AE}', &['\u{62F}', '\u{324}', '\u{323}']),
+    ('\u{8AF}', &['\u{635}', '\u{324}', '\u{323}']),
+    ('\u{8B0}', &['\u{6AF}']),
+    ('\u{8B1}', &['\u{648}']),
+    ('\u{8B2}', &['\u{632}', '\u{302}']),
+    ('\u{8B6}', &['\u{628}', '\u{6E2}']),
+    ('\u{8B7}', &['\u{649}', '\u{6DB}', '\u{6E2}']),
+    ('\u{8B9}', &['\u{631}', '\u{306}', '\u{307}']),
+    ('\u{8BA}', &['\u{649}', '\u{306}', '\u{307}']),
+    ('\u{8BB}', &['\u{6A1}']),
+    ('\u{8BC}', &['\u{6A1}']),
+    ('\u{8BD}', &['\u{649}']),
+    ('\u{8E5}', &['\u{64C}']),
+    ('\u{8E8}', &['\u{64C}']),
+    ('\u{8EA}', &['\u{307}']),
+    ('\u{8EB}', &['\u{308}']),
+    ('\u{8ED}', &['\u{323}']),
+    ('\u{8EE}', &['\u{324}']),
+    ('\u{8F0}', &['\u{30B}']),
+    ('\u{8F1}', &['\u{64C}']),
+    ('\u{8F2}', &['\u{64D}']),
+    ('\u{8F3}', &['\u{313}']),
+    ('\u{8F8}', &['\u{350}']),
+    ('\u{8F9}', &['\u{354}']),
+    ('\u{8FA}', &['\u{355}']),
+    ('\u{8FF}', &['\u{350}']),
+    ('\u{900}', &['\u{352}']),
+    ('\u{901}', &['\u{306}', '\u{307}']),
+    ('\u{902}', &['\u{307}']),
+    ('\u{903}', &['\u{3A}']),
+    ('\u{904}', &['\u{905}', '\u{946}']),
+    ('\u{906}', &['\u{905}', '\u{93E}']),
+    ('\u{908}', &['\u{930}', '\u{94D}', '\u{907}']),
+    ('\u{90D}', &['\u{90F}', '\u{945}']),
+    ('\u{90E}', &['\u{90F}', '\u{946}']),
+    ('\u{910}', &['\u{90F}', '\u{947}']),
+    ('\u{911}', &['\u{905}', '\u{949}']),
+    ('\u{912}', &['\u{905}', '\u{93E}', '\u{946}']),
+    ('\u{913}', &['\u{905}', '\u{93E}', '\u{947}']),
+    ('\u{914}', &['\u{905}', '\u{93E}', '\u{948}']),
+    ('\u{93C}', &['\u{323}']),
+    ('\u{952}', &['\u{331}']),
+    ('\u{953}', &['\u{300}']),
+    ('\u{954}', &['\u{301}']),
+    ('\u{965}', &['\u{964}', '\u{964}']),
+    ('\u{966}', &['\u{6F}']),
+    ('\u{967}', &['\u{669}']),
+    ('\u{97D}', &['\u{3F}']),
+    ('\u{981}', &['\u{306}', '\u{307}']),
+    ('\u{986}', &['\u{985}', '\u{9BE}']),
+    ('\u{9BC}', &['\u{323}']),
+    ('\u{9E0}', &['\u{98B}', '\u{9C3}']),
+    ('\u{9E1}', &['\u{98B}', '\u{9C3}']),
+    ('\u{9E6}', &['\u{4F}']),
+    ('\u{9EA}', &['\u{38}']),
+    ('\u{9ED}', &['\u{39}']),
+    ('\u{A02}', &['\u{307}']),
+    ('\u{A03}', &['\u{983}']),
+    ('\u{A06}', &['\u{A05}', '\u{A3E}']),
+    ('\u{A07}', &['\u{A72}', '\u{A3F}']),
+    ('\u{A08}', &['\u{A72}', '\u{A40}']),
+    ('\u{A09}', &['\u{A73}', '\u{A41}']),
+    ('\u{A0A}', &['\u{A73}', '\u{A42}']),
+    ('\u{A0F}', &['\u{A72}', '\u{A47}']),
+    ('\u{A10}', &['\u{A05}', '\u{A48}']),
+    ('\u{A14}', &['\u{A05}', '\u{A4C}']),
+    ('\u{A3C}', &['\u{323}']),
+    ('\u{A4B}', &['\u{946}']),
+    ('\u{A4D}', &['\u{94D}']),
+    ('\u{A66}', &['\u{6F}']),
+    ('\u{A67}', &['\u{39}']),
+    ('\u{A6A}', &['\u{38}']),
+    ('\u{A81}', &['\u{306}', '\u{307}']),
+    ('\u{A82}', &['\u{307}']),
+    ('\u{A83}', &['\u{3A}']),
+    ('\u{A86}', &['\u{A85}', '\u{ABE}']),
+    ('\u{A8D}', &['\u{A85}', '\u{AC5}']),
+    ('\u{A8F}', &['\u{A85}', '\u{AC7}']),
+    ('\u{A90}', &['\u{A85}', '\u{AC8}']),
+    ('\u{A91}', &['\u{A85}', '\u{ABE}', '\u{AC5}']),
+    ('\u{A93}', &['\u{A85}', '\u{ABE}', '\u{AC7}']),
+    ('\u{A94}', &['\u{A85}', '\u{ABE}', '\u{AC8}']),
+    ('\u{ABC}', &['\u{323}']),
+    ('\u{ABD}', &['\u{93D}']),
+    ('\u{AC1}', &['\u{941}']),
+    ('\u{AC2}', &['\u{942}']),
+    ('\u{ACD}', &['\u{94D}']),
+    ('\u{AE6}', &['\u{6F}']),
+    ('\u{AE8}', &['\u{968}']),
+    ('\u{AE9}', &['\u{969}']),
+    ('\u{AEA}', &['\u{96A}']),
+    ('\u{AEE}', &['\u{96E}']),
+    ('\u{AF0}', &['\u{970}']),
+    ('\u{B01}', &['\u{306}', '\u{307}']),
+    ('\u{B03}', &['\u{38}']),
+    ('\u{B06}', &['\u{B05}', '\u{B3E}']),
+    ('\u{B20}', &['\u{4F}']),
+    ('\u{B3C}', &['\u{323}']),
+    ('\u{B66}', &['\u{4F}']),
+    ('\u{B68}', &['\u{39}']),
+    ('\u{B82}', &['\u{30A}']),
+    ('\u{B8A}', &['\u{B89}', '\u{BB3}']),
+    ('\u{B9C}', &['\u{B90}']),
+    ('\u{BB0}', &['\u{B88}']),
+    ('\u{BBE}', &['\u{B88}']),
+    ('\u{BC8}', &['\u{BA9}']),
+    ('\u{BCA}', &['\u{BC6}', '\u{B88}']),
+    ('\u{BCB}', &['\u{BC7}', '\u{B88}']),
+    ('\u{BCC}', &['\u{BC6}', '\u{BB3}']),
+    ('\u{BCD}', &['\u{307}']),
+    ('\u{BD7}', &['\u{BB3}']),
+    ('\u{BE6}', &['\u{6F}']),
+    ('\u{BE7}', &['\u{B95}']),
+    ('\u{BE8}', &['\u{B89}']),
+    ('\u{BEA}', &['\u{B9A}']),
+    ('\u{BEB}', &['\u{B88}', '\u{BC1}']),
+    ('\u{BEC}', &['\u{B9A}', '\u{BC1}']),
+    ('\u{BED}', &['\u{B8E}']),
+    ('\u{BEE}', &['\u{B85}']),
+    ('\u{BF0}', &['\u{BAF}']),
+    ('\u{BF2}', &['\u{B9A}', '\u{BC2}']),
+    ('\u{BF4}', &['\u{BAE}', '\u{BC0}']),
+    ('\u{BF5}', &['\u{BF3}']),
+    ('\u{BF7}', &['\u{B8E}', '\u{BB5}']),
+    ('\u{BF8}', &['\u{BB7}']),
+    ('\u{BFA}', &['\u{BA8}', '\u{BC0}']),
+    ('\u{C00}', &['\u{306}', '\u{307}']),
+    ('\u{C02}', &['\u{6F}']),
+    ('\u{C03}', &['\u{983}']),
+    ('\u{C13}', &['\u{C12}', '\u{C55}']),
+    ('\u{C14}', &['\u{C12}', '\u{C4C}']),
+    ('\u{C20}', &['\u{C30}', '\u{5BC}']),
+    ('\u{C22}', &['\u{C21}', '\u{323}']),
+    ('\u{C25}', &['\u{C27}', '\u{5BC}']),
+    ('\u{C2D}', &['\u{C2C}', '\u{323}']),
+    ('\u{C2E}', &['\u{C35}', '\u{C41}']),
+    ('\u{C37}', &['\u{C35}', '\u{323}']),
+    ('\u{C39}', &['\u{C35}', '\u{C3E}']),
+    ('\u{C42}', &['\u{C41}', '\u{C3E}']),
+    ('\u{C44}', &['\u{C43}', '\u{C3E}']),
+    ('\u{C60}', &['\u{C0B}', '\u{C3E}']),
+    ('\u{C61}', &['\u{C0C}', '\u{C3E}']),
+    ('\u{C66}', &['\u{6F}']),
+    ('\u{C81}', &['\u{306}', '\u{307}']),
+    ('\u{C82}', &['\u{6F}']),
+    ('\u{C83}', &['\u{983}']),
+    ('\u{C85}', &['\u{C05}']),
+    ('\u{C86}', &['\u{C06}']),
+    ('\u{C87}', &['\u{C07}']),
+    ('\u{C92}', &['\u{C12}']),
+    ('\u{C93}', &['\u{C12}', '\u{C55}']),
+    ('\u{C94}', &['\u{C12}', '\u{C4C}']),
+    ('\u{C9C}', &['\u{C1C}']),
+    ('\u{C9E}', &['\u{C1E}']),
+    ('\u{CA3}', &['\u{C23}']),
+    ('\u{CAF}', &['\u{C2F}']),
+    ('\u{CB1}', &['\u{C31}']),
+    ('\u{CB2}', &['\u{C32}']),
+    ('\u{CE1}', &['\u{C8C}', '\u{CBE}']),
+    ('\u{CE6}', &['\u{6F}']),
+    ('\u{CE7}', &['\u{C67}']),
+    ('\u{CE8}', &['\u{C68}']),
+    ('\u{CEF}', &['\u{C6F}']),
+    ('\u{D01}', &['\u{306}', '\u{307}']),
+    ('\u{D02}', &['\u{6F}']),
+    ('\u{D03}', &['\u{983}']),
+    ('\u{D08}', &['\u{D07}', '\u{D57}']),
+    ('\u{D09}', &['\u{B89}']),
+    ('\u{D0A}', &['\u{B89}', '\u{D57}']),
+    ('\u{D0C}', &['\u{D28}', '\u{D41}']),
+    ('\u{D10}', &['\u{D0E}', '\u{D46}']),
+    ('\u{D13}', &['\u{D12}', '\u{D3E}']),
+    ('\u{D14}', &['\u{D12}', '\u{D57}']),
+    ('\u{D19}', &['\u{D28}', '\u{D41}']),
+    ('\u{D1C}', &['\u{B90}']),
+    ('\u{D20}', &['\u{6F}']),
+    ('\u{D23}', &['\u{BA3}']),
+    ('\u{D31}', &['\u{D30}']),
+    ('\u{D34}', &['\u{BB4}']),
+    ('\u{D36}', &['\u{BB6}']),
+    ('\u{D3A}', &['\u{B9F}', '\u{BBF}']),
+    ('\u{D3F}', &['\u{BBF}']),
+    ('\u{D40}', &['\u{BBF}']),
+    ('\u{D42}', &['\u{D41}']),
+    ('\u{D43}', &['\u{D41}']),
+    ('\u{D48}', &['\u{D46}', '\u{D46}']),
+    ('\u{D4E}', &['\u{971}']),
+    ('\u{D5A}', &['\u{D28}', '\u{D4D}', '\u{D2E}']),
+    ('\u{D5F}', &['\u{6F}', '\u{D30}', '\u{6F}']),
+    ('\u{D61}', &['\u{D1E}']),
+    ('\u{D66}', &['\u{6F}']),
+    ('\u{D6A}', &['\u{D30}', '\u{D4D}']),
+    ('\u{D6B}', &['\u{D26}', '\u{D4D}', '\u{D30}']),
+    ('\u{D6C}', &['\u{D28}', '\u{D4D}', '\u{D28}']),
+    ('\u{D6D}', &['\u{39}']),
+    ('\u{D6E}', &['\u{D35}', '\u{D4D}', '\u{D30}']),
+    ('\u{D6F}', &['\u{D28}', '\u{D4D}']),
+    ('\u{D76}', &['\u{D39}', '\u{D4D}', '\u{D2E}']),
+    ('\u{D79}', &['\u{D28}', '\u{D41}']),
+    ('\u{D7B}', &['\u{D28}', '\u{D4D}']),
+    ('\u{D7C}', &['\u{D30}', '\u{D4D}']),
+    ('\u{D82}', &['\u{6F}']),
+    ('\u{D83}', &['\u{983}']),
+    ('\u{DE9}', &['\u{DE8}', '\u{DCF}']),
+    ('\u{DEA}', &['\u{DA2}']),
+    ('\u{DEB}', &['\u{DAF}']),
+    ('\u{DEF}', &['\u{DE8}', '\u{DD3}']),
+    ('\u{E03}', &['\u{E02}']),
+    ('\u{E0B}', &['\u{E0A}']),
+    ('\u{E0F}', &['\u{E0E}']),
+    ('\u{E14}', &['\u{E04}']),
+    ('\u{E15}', &['\u{E04}']),
+    ('\u{E17}', &['\u{E11}']),
+    ('\u{E21}', &['\u{E06}']),
+    ('\u{E26}', &['\u{E20}']),
+    ('\u{E33}', &['\u{30A}', '\u{E32}']),
+    ('\u{E41}', &['\u{E40}', '\u{E40}']),
+    ('\u{E45}', &['\u{E32}']),
+    ('\u{E4D}', &['\u{30A}']),
+    ('\u{E50}', &['\u{6F}']),
+    ('\u{E88}', &['\u{E08}']),
+    ('\u{E8D}', &['\u{E22}']),
+    ('\u{E9A}', &['\u{E1A}']),
+    ('\u{E9B}', &['\u{E1B}']),
+    ('\u{E9D}', &['\u{E1D}']),
+    ('\u{E9E}', &['\u{E1E}']),
+    ('\u{E9F}', &['\u{E1F}']),
+    ('\u{EB3}', &['\u{30A}', '\u{EB2}']),
+    ('\u{EB8}', &['\u{E38}']),
+    ('\u{EB9}', &['\u{E39}']),
+    ('\u{EC8}', &['\u{E48}']),
+    ('\u{EC9}', &['\u{E49}']),
+    ('\u{ECA}', &['\u{E4A}']),
+    ('\u{ECB}', &['\u{E4B}']),
+    ('\u{ECD}', &['\u{30A}']),
+    ('\u{ED0}', &['\u{6F}']),
+    ('\u{EDC}', &['\u{EAB}', '\u{E99}']),
+    ('\u{EDD}', &['\u{EAB}', '\u{EA1}']),
+    ('\u{F00}', &['\u{F68}', '\u{F7C}', '\u{F7E}']),
+    ('\u{F02}', &['\u{F60}', '\u{F74}', '\u{F82}', '\u{F7F}']),
+    ('\u{F03}', &['\u{F60}', '\u{F74}', '\u{F82}', '\u{F14}']),
+    ('\u{F0C}', &['\u{F0B}']),
+    ('\u{F0E}', &['\u{F0D}', '\u{F0D}']),
+    ('\u{F1B}', &['\u{F1A}', '\u{F1A}']),
+    ('\u{F1E}', &['\u{F1D}', '\u{F1D}']),
+    ('\u{F1F}', &['\u{F1A}', '\u{F1D}']),
+    ('\u{F37}', &['\u{325}']),
+    ('\u{F6A}', &['\u{F62}']),
+    ('\u{F77}', &['\u{FB2}', '\u{F71}', '\u{F80}']),
+    ('\u{F79}', &['\u{FB3}', '\u{F71}', '\u{F80}']),
+    ('\u{FCE}', &['\u{F1D}', '\u{F1A}']),
+    ('\u{FD5}', &['\u{5350}']),
+    ('\u{FD6}', &['\u{534D}']),
+    ('\u{1000}', &['\u{1002}', '\u{102C}']),
+    ('\u{1010}', &['\u{6F}', '\u{102C}']),
+    ('\u{101D}', &['\u{6F}']),
+    ('\u{101F}', &['\u{1015}', '\u{102C}']),
+    ('\u{1029}', &['\u{101E}', '\u{103C}']),
+    ('\u{102A}', &['\u{101E}', '\u{103C}', '\u{1031}', '\u{102C}', '\u{103A}']),
+    ('\u{1036}', &['\u{30A}']),
+    ('\u{1038}', &['\u{983}']),
+    ('\u{1040}', &['\u{6F}']),
+    ('\u{104B}', &['\u{104A}', '\u{104A}']),
+    ('\u{1065}', &['\u{1041}']),
+    ('\u{1066}', &['\u{1015}', '\u{103E}']),
+    ('\u{106F}', &['\u{1015}', '\u{102C}', '\u{103E}']),
+    ('\u{1070}', &['\u{1003}', '\u{103E}']),
+    ('\u{107E}', &['\u{107D}', '\u{103E}']),
+    ('\u{1081}', &['\u{1002}', '\u{103E}']),
+    ('\u{109E}', &['\u{1083}', '\u{30A}']),
+    ('\u{10A0}', &['\u{A786}']),
+    ('\u{10E7}', &['\u{79}']),
+    ('\u{10F3}', &['\u{21D}']),
+    ('\u{10FF}', &['\u{6F}']),
+    ('\u{1101}', &['\u{1100}', '\u{1100}']),
+    ('\u{1104}', &['\u{1103}', '\u{1103}']),
+    ('\u{1108}', &['\u{1107}', '\u{1107}']),
+    ('\u{110A}', &['\u{1109}', '\u{1109}']),
+    ('\u{110D}', &['\u{110C}', '\u{110C}']),
+    ('\u{1113}', &['\u{1102}', '\u{1100}']),
+    ('\u{1114}', &['\u{1102}', '\u{1102}']),
+    ('\u{1115}', &['\u{1102}', '\u{1103}']),
+    ('\u{1116}', &['\u{1102}', '\u{1107}']),
+    ('\u{1117}', &['\u{1103}', '\u{1100}']),
+    ('\u{1118}', &['\u{1105}', '\u{1102}']),
+    ('\u{1119}', &['\u{1105}', '\u{1105}']),
+    ('\u{111A}', &['\u{1105}', '\u{1112}']),
+    ('\u{111B}', &['\u{1105}', '\u{110B}']),
+    ('\u{111C}', &['\u{1106}', '\u{1107}']),
+    ('\u{111D}', &['\u{1106}', '\u{110B}']),
+    ('\u{111E}', &['\u{1107}', '\u{1100}']),
+    ('\u{111F}', &['\u{1107}', '\u{1102}']),
+    ('\u{1120}', &['\u{1107}', '\u{1103}']),
+    ('\u{1121}', &['\u{1107}', '\u{1109}']),
+    ('\u{1122}', &['\u{1107}', '\u{1109}', '\u{1100}']),
+    ('\u{1123}', &['\u{1107}', '\u{1109}', '\u{1103}']),
+    ('\u{1124}', &['\u{1107}', '\u{1109}', '\u{1107}']),
+    ('\u{1125}', &['\u{1107}', '\u{1109}', '\u{1109}']),
+    ('\u{1126}', &['\u{1107}', '\u{1109}', '\u{110C}']),
+    ('\u{1127}', &['\u{1107}', '\u{110C}']),
+    ('\u{1128}', &['\u{1107}', '\u{110E}']),
+    ('\u{1129}', &['\u{1107}', '\u{1110}']),
+    ('\u{112A}', &['\u{1107}', '\u{1111}']),
+    ('\u{112B}', &['\u{1107}', '\u{110B}']),
+    ('\u{112C}', &['\u{1107}', '\u{1107}', '\u{110B}']),
+    ('\u{112D}', &['\u{1109}', '\u{1100}']),
+    ('\u{112E}', &['\u{1109}', '\u{1102}']),
+    ('\u{112F}', &['\u{1109}', '\u{1103}']),
+    ('\u{1130}', &['\u{1109}', '\u{1105}']),
+    ('\u{1131}', &['\u{1109}', '\u{1106}']),
+    ('\u{1132}', &['\u{1109}', '\u{1107}']),
+    ('\u{1133}', &['\u{1109}', '\u{1107}', '\u{1100}']),
+    ('\u{1134}', &['\u{1109}', '\u{1109}', '\u{1109}']),
+    ('\u{1135}', &['\u{1109}', '\u{110B}']),
+    ('\u{1136}', &['\u{1109}', '\u{110C}']),
+    ('\u{1137}', &['\u{1109}', '\u{110E}']),
+    ('\u{1138}', &['\u{1109}', '\u{110F}']),
+    ('\u{1139}', &['\u{1109}', '\u{1110}']),
+    ('\u{113A}', &['\u{1109}', '\u{1111}']),
+    ('\u{113B}', &['\u{1105}', '\u{1112}']),
+    ('\u{113D}', &['\u{113C}', '\u{113C}']),
+    ('\u{113F}', &['\u{113E}', '\u{113E}']),
+    ('\u{1141}', &['\u{110B}', '\u{1100}']),
+    ('\u{1142}', &['\u{110B}', '\u{1103}']),
+    ('\u{1143}', &['\u{110B}', '\u{1106}']),
+    ('\u{1144}', &['\u{110B}', '\u{1107}']),
+    ('\u{1145}', &['\u{110B}', '\u{1109}']),
+    ('\u{1146}', &['\u{110B}', '\u{1140}']),
+    ('\u{1147}', &['\u{110B}', '\u{110B}']),
+    ('\u{1148}', &['\u{110B}', '\u{110C}']),
+    ('\u{1149}', &['\u{110B}', '\u{110E}']),
+    ('\u{114A}', &['\u{110B}', '\u{1110}']),
+    ('\u{114B}', &['\u{110B}', '\u{1111}']),
+    ('\u{114D}', &['\u{110C}', '\u{110B}']),
+    ('\u{114F}', &['\u{114E}', '\u{114E}']),
+    ('\u{1151}', &['\u{1150}', '\u{1150}']),
+    ('\u{1152}', &['\u{110E}', '\u{110F}']),
+    ('\u{1153}', &['\u{110E}', '\u{1112}']),
+    ('\u{1156}', &['\u{1111}', '\u{1107}']),
+    ('\u{1157}', &['\u{1111}', '\u{110B}']),
+    ('\u{1158}', &['\u{1112}', '\u{1112}']),
+    ('\u{115A}', &['\u{1100}', '\u{1103}']),
+    ('\u{115B}', &['\u{1102}', '\u{1109}']),
+    ('\u{115C}', &['\u{1102}', '\u{110C}']),
+    ('\u{115D}', &['\u{1102}', '\u{1112}']),
+    ('\u{115E}', &['\u{1103}', '\u{1105}']),
+    ('\u{1162}', &['\u{1161}', '\u{4E28}']),
+    ('\u{1164}', &['\u{1163}', '\u{4E28}']),
+    ('\u{1166}', &['\u{1165}', '\u{4E28}']),
+    ('\u{1168}', &['\u{1167}', '\u{4E28}']),
+    ('\u{116A}', &['\u{1169}', '\u{1161}']),
+    ('\u{116B}', &['\u{1169}', '\u{1161}', '\u{4E28}']),
+    ('\u{116C}', &['\u{1169}', '\u{4E28}']),
+    ('\u{116F}', &['\u{116E}', '\u{1165}']),
+    ('\u{1170}', &['\u{116E}', '\u{1165}', '\u{4E28}']),
+    ('\u{1171}', &['\u{116E}', '\u{4E28}']),
+    ('\u{1173}', &['\u{30FC}']),
+    ('\u{1174}', &['\u{30FC}', '\u{4E28}']),
+    ('\u{1175}', &['\u{4E28}']),
+    ('\u{1176}', &['\u{1161}', '\u{1169}']),
+    ('\u{1177}', &['\u{1161}', '\u{116E}']),
+    ('\u{1178}', &['\u{1163}', '\u{1169}']),
+    ('\u{1179}', &['\u{1163}', '\u{116D}']),
+    ('\u{117A}', &['\u{1165}', '\u{1169}']),
+    ('\u{117B}', &['\u{1165}', '\u{116E}']),
+    ('\u{117C}', &['\u{1165}', '\u{30FC}']),
+    ('\u{117D}', &['\u{1167}', '\u{1169}']),
+    ('\u{117E}', &['\u{1167}', '\u{116E}']),
+    ('\u{117F}', &['\u{1169}', '\u{1165}']),
+    ('\u{1180}', &['\u{1169}', '\u{1165}', '\u{4E28}']),
+    ('\u{1181}', &['\u{1169}', '\u{1167}', '\u{4E28}']),
+    ('\u{1182}', &['\u{1169}', '\u{1169}']),
+    ('\u{1183}', &['\u{1169}', '\u{116E}']),
+    ('\u{1184}', &['\u{116D}', '\u{1163}']),
+    ('\u{1185}', &['\u{116D}', '\u{1163}', '\u{4E28}']),
+    ('\u{1186}', &['\u{116D}', '\u{1163}']),
+    ('\u{1187}', &['\u{116D}', '\u{1169}']),
+    ('\u{1188}', &['\u{116D}', '\u{4E28}']),
+    ('\u{1189}', &['\u{116E}', '\u{1161}']),
+    ('\u{118A}', &['\u{116E}', '\u{1161}', '\u{4E28}']),
+    ('\u{118B}', &['\u{116E}', '\u{1165}', '\u{30FC}']),
+    ('\u{118C}', &['\u{116E}', '\u{1167}', '\u{4E28}']),
+    ('\u{118D}', &['\u{116E}', '\u{116E}']),
+    ('\u{118E}', &['\u{1172}', '\u{1161}']),
+    ('\u{118F}', &['\u{1172}', '\u{1165}']),
+    ('\u{1190}', &['\u{1172}', '\u{1165}', '\u{4E28}']),
+    ('\u{1191}', &['\u{1172}', '\u{1167}']),
+    ('\u{1192}', &['\u{1172}', '\u{1167}', '\u{4E28}']),
+    ('\u{1193}', &['\u{1172}', '\u{116E}']),
+    ('\u{1194}', &['\u{1172}', '\u{4E28}']),
+    ('\u{1195}', &['\u{30FC}', '\u{116E}']),
+    ('\u{1196}', &['\u{30FC}', '\u{30FC}']),
+    ('\u{1197}', &['\u{30FC}', '\u{4E28}', '\u{116E}']),
+    ('\u{1198}', &['\u{4E28}', '\u{1161}']),
+    ('\u{1199}', &['\u{4E28}', '\u{1163}']),
+    ('\u{119A}', &['\u{4E28}', '\u{1169}']),
+    ('\u{119B}', &['\u{4E28}', '\u{116E}']),
+    ('\u{119C}', &['\u{4E28}', '\u{30FC}']),
+    ('\u{119D}', &['\u{4E28}', '\u{119E}']),
+    ('\u{119F}', &['\u{119E}', '\u{1165}']),
+    ('\u{11A0}', &['\u{119E}', '\u{116E}']),
+    ('\u{11A1}', &['\u{119E}', '\u{4E28}']),
+    ('\u{11A2}', &['\u{119E}', '\u{119E}']),
+    ('\u{11A3}', &['\u{1161}', '\u{30FC}']),
+    ('\u{11A4}', &['\u{1163}', '\u{116E}']),
+    ('\u{11A5}', &['\u{1167}', '\u{1163}']),
+    ('\u{11A6}', &['\u{1169}', '\u{1163}']),
+    ('\u{11A7}', &['\u{1169}', '\u{1163}', '\u{4E28}']),
+    ('\u{11A8}', &['\u{1100}']),
+    ('\u{11A9}', &['\u{1100}', '\u{1100}']),
+    ('\u{11AA}', &['\u{1100}', '\u{1109}']),
+    ('\u{11AB}', &['\u{1102}']),
+    ('\u{11AC}', &['\u{1102}', '\u{110C}']),
+    ('\u{11AD}', &['\u{1102}', '\u{1112}']),
+    ('\u{11AE}', &['\u{1103}']),
+    ('\u{11AF}', &['\u{1105}']),
+    ('\u{11B0}', &['\u{1105}', '\u{1100}']),
+    ('\u{11B1}', &['\u{1105}', '\u{1106}']),
+    ('\u{11B2}', &['\u{1105}', '\u{1107}']),
+    ('\u{11B3}', &['\u{1105}', '\u{1109}']),
+    ('\u{11B4}', &['\u{1105}', '\u{1110}']),
+    ('\u{11B5}', &['\u{1105}', '\u{1111}']),
+    ('\u{11B6}', &['\u{1105}', '\u{1112}']),
+    ('\u{11B7}', &['\u{1106}']),
+    ('\u{11B8}', &['\u{1107}']),
+    ('\u{11B9}', &['\u{1107}', '\u{1109}']),
+    ('\u{11BA}', &['\u{1109}']),
+    ('\u{11BB}', &['\u{1109}', '\u{1109}']),
+    ('\u{11BC}', &['\u{110B}']),
+    ('\u{11BD}', &['\u{110C}']),
+    ('\u{11BE}', &['\u{110E}']),
+    ('\u{11BF}', &['\u{110F}']),
+    ('\u{11C0}', &['\u{1110}']),
+    ('\u{11C1}', &['\u{1111}']),
+    ('\u{11C2}', &['\u{1112}']),
+    ('\u{11C3}', &['\u{1100}', '\u{1105}']),
+    ('\u{11C4}', &['\u{1100}', '\u{1109}', '\u{1100}']),
+    ('\u{11C5}', &['\u{1102}', '\u{1100}']),
+    ('\u{11C6}', &['\u{1102}', '\u{1103}']),
+    ('\u{11C7}', &['\u{1102}', '\u{1109}']),
+    ('\u{11C8}', &['\u{1102}', '\u{1140}']),
+    ('\u{11C9}', &['\u{1102}', '\u{1110}']),
+    ('\u{11CA}', &['\u{1103}', '\u{1100}']),
+    ('\u{11CB}', &['\u{1103}', '\u{1105}']),
+    ('\u{11CC}', &['\u{1105}', '\u{1100}', '\u{1109}']),
+    ('\u{11CD}', &['\u{1105}', '\u{1102}']),
+    ('\u{11CE}', &['\u{1105}', '\u{1103}']),
+    ('\u{11CF}', &['\u{1105}', '\u{1103}', '\u{1112}']),
+    ('\u{11D0}', &['\u{1105}', '\u{1105}']),
+    ('\u{11D1}', &['\u{1105}', '\u{1106}', '\u{1100}']),
+    ('\u{11D2}', &['\u{1105}', '\u{1106}', '\u{1109}']),
+    ('\u{11D3}', &['\u{1105}', '\u{1107}', '\u{1109}']),
+    ('\u{11D4}', &['\u{1105}', '\u{1107}', '\u{1112}']),
+    ('\u{11D5}', &['\u{1105}', '\u{1107}', '\u{110B}']),
+    ('\u{11D6}', &['\u{1105}', '\u{1109}', '\u{1109}']),
+    ('\u{11D7}', &['\u{1105}', '\u{1140}']),
+    ('\u{11D8}', &['\u{1105}', '\u{110F}']),
+    ('\u{11D9}', &['\u{1105}', '\u{1159}']),
+    ('\u{11DA}', &['\u{1106}', '\u{1100}']),
+    ('\u{11DB}', &['\u{1106}', '\u{1105}']),
+    ('\u{11DC}', &['\u{1106}', '\u{1107}']),
+    ('\u{11DD}', &['\u{1106}', '\u{1109}']),
+    ('\u{11DE}', &['\u{1106}', '\u{1109}', '\u{1109}']),
+    ('\u{11DF}', &['\u{1106}', '\u{1140}']),
+    ('\u{11E0}', &['\u{1106}', '\u{110E}']),
+    ('\u{11E1}', &['\u{1106}', '\u{1112}']),
+    ('\u{11E2}', &['\u{1106}', '\u{110B}']),
+    ('\u{11E3}', &['\u{1107}', '\u{1105}']),
+    ('\u{11E4}', &['\u{1107}', '\u{1111}']),
+    ('\u{11E5}', &['\u{1107}', '\u{1112}']),
+    ('\u{11E6}', &['\u{1107}', '\u{110B}']),
+    ('\u{11E7}', &['\u{1109}', '\u{1100}']),
+    ('\u{11E8}', &['\u{1109}', '\u{1103}']),
+    ('\u{11E9}', &['\u{1109}', '\u{1105}']),
+    ('\u{11EA}', &['\u{1109}', '\u{1107}']),
+    ('\u{11EB}', &['\u{1140}']),
+    ('\u{11EC}', &['\u{110B}', '\u{1100}']),
+    ('\u{11ED}', &['\u{110B}', '\u{1100}', '\u{1100}']),
+    ('\u{11EE}', &['\u{110B}', '\u{110B}']),
+    ('\u{11EF}', &['\u{110B}', '\u{110F}']),
+    ('\u{11F0}', &['\u{114C}']),
+    ('\u{11F1}', &['\u{110B}', '\u{1109}']),
+    ('\u{11F2}', &['\u{110B}', '\u{1140}']),
+    ('\u{11F3}', &['\u{1111}', '\u{1107}']),
+    ('\u{11F4}', &['\u{1111}', '\u{110B}']),
+    ('\u{11F5}', &['\u{1112}', '\u{1102}']),
+    ('\u{11F6}', &['\u{1112}', '\u{1105}']),
+    ('\u{11F7}', &['\u{1112}', '\u{1106}']),
+    ('\u{11F8}', &['\u{1112}', '\u{1107}']),
+    ('\u{11F9}', &['\u{1159}']),
+    ('\u{11FA}', &['\u{1100}', '\u{1102}']),
+    ('\u{11FB}', &['\u{1100}', '\u{1107}']),
+    ('\u{11FC}', &['\u{1100}', '\u{110E}']),
+    ('\u{11FD}', &['\u{1100}', '\u{110F}']),
+    ('\u{11FE}', &['\u{1100}', '\u{1112}']),
+    ('\u{11FF}', &['\u{1102}', '\u{1102}']),
+    ('\u{1200}', &['\u{55}']),
+    ('\u{1223}', &['\u{270}']),
+    ('\u{1240}', &['\u{3A6}']),
+    ('\u{1260}', &['\u{548}']),
+    ('\u{1294}', &['\u{571}']),
+    ('\u{12D0}', &['\u{4F}']),
+    ('\u{13A0}', &['\u{44}']),
+    ('\u{13A1}', &['\u{52}']),
+    ('\u{13A2}', &['\u{54}']),
+    ('\u{13A4}', &['\u{4F}', '\u{27}']),
+    ('\u{13A5}', &['\u{69}']),
+    ('\u{13A8}', &['\u{2C75}']),
+    ('\u{13A9}', &['\u{59}']),
+    ('\u{13AA}', &['\u{41}']),
+    ('\u{13AB}', &['\u{4A}']),
+    ('\u{13AC}', &['\u{45}']),
+    ('\u{13AE}', &['\u{3F}']),
+    ('\u{13B0}', &['\u{2C75}']),
+    ('\u{13B1}', &['\u{393}']),
+    ('\u{13B3}', &['\u{57}']),
+    ('\u{13B7}', &['\u{4D}']),
+    ('\u{13BB}', &['\u{48}']),
+    ('\u{13BD}', &['\u{59}']),
+    ('\u{13BE}', &['\u{4F}', '\u{335}']),
+    ('\u{13BF}', &['\u{1AB}']),
+    ('\u{13C0}', &['\u{47}']),
+    ('\u{13C2}', &['\u{68}']),
+    ('\u{13C3}', &['\u{5A}']),
+    ('\u{13C7}', &['\u{460}']),
+    ('\u{13CB}', &['\u{190}']),
+    ('\u{13CC}', &['\u{55}', '\u{335}']),
+    ('\u{13CE}', &['\u{34}']),
+    ('\u{13CF}', &['\u{62}']),
+    ('\u{13D2}', &['\u{52}']),
+    ('\u{13D4}', &['\u{57}']),
+    ('\u{13D5}', &['\u{53}']),
+    ('\u{13D9}', &['\u{56}']),
+    ('\u{13DA}', &['\u{53}']),
+    ('\u{13DE}', &['\u{4C}']),
+    ('\u{13DF}', &['\u{43}']),
+    ('\u{13E2}', &['\u{50}']),
+    ('\u{13E6}', &['\u{4B}']),
+    ('\u{13E7}', &['\u{64}']),
+    ('\u{13EB}', &['\u{4F}', '\u{335}']),
+    ('\u{13EE}', &['\u{36}']),
+    ('\u{13F0}', &['\u{DF}']),
+    ('\u{13F2}', &['\u{68}', '\u{314}']),
+    ('\u{13F3}', &['\u{47}']),
+    ('\u{13F4}', &['\u{42}']),
+    ('\u{13FB}', &['\u{262}']),
+    ('\u{13FC}', &['\u{299}']),
+    ('\u{1400}', &['\u{3D}']),
+    ('\u{1403}', &['\u{394}']),
+    ('\u{140C}', &['\u{B7}', '\u{1401}']),
+    ('\u{140D}', &['\u{1401}', '\u{B7}']),
+    ('\u{140E}', &['\u{B7}', '\u{394}']),
+    ('\u{140F}', &['\u{394}', '\u{B7}']),
+    ('\u{1410}', &['\u{B7}', '\u{1404}']),
+    ('\u{1411}', &['\u{1404}', '\u{B7}']),
+    ('\u{1412}', &['\u{B7}', '\u{1405}']),
+    ('\u{1413}', &['\u{1405}', '\u{B7}']),
+    ('\u{1414}', &['\u{B7}', '\u{1406}']),
+    ('\u{1415}', &['\u{1406}', '\u{B7}']),
+    ('\u{1417}', &['\u{B7}', '\u{140A}']),
+    ('\u{1418}', &['\u{140A}', '\u{B7}']),
+    ('\u{1419}', &['\u{B7}', '\u{140B}']),
+    ('\u{141A}', &['\u{140B}', '\u{B7}']),
+    ('\u{1427}', &['\u{B7}']),
+    ('\u{142B}', &['\u{1401}', '\u{1420}']),
+    ('\u{142C}', &['\u{394}', '\u{1420}']),
+    ('\u{142D}', &['\u{1405}', '\u{1420}']),
+    ('\u{142E}', &['\u{140A}', '\u{1420}']),
+    ('\u{142F}', &['\u{56}']),
+    ('\u{1431}', &['\u{245}']),
+    ('\u{1433}', &['\u{3E}']),
+    ('\u{1437}', &['\u{B7}', '\u{3E}']),
+    ('\u{1438}', &['\u{3C}']),
+    ('\u{143A}', &['\u{B7}', '\u{56}']),
+    ('\u{143B}', &['\u{56}', '\u{B7}']),
+    ('\u{143C}', &['\u{B7}', '\u{245}']),
+    ('\u{143D}', &['\u{245}', '\u{B7}']),
+    ('\u{143E}', &['\u{B7}', '\u{1432}']),
+    ('\u{143F}', &['\u{1432}', '\u{B7}']),
+    ('\u{1440}', &['\u{B7}', '\u{3E}']),
+    ('\u{1441}', &['\u{3E}', '\u{B7}']),
+    ('\u{1442}', &['\u{B7}', '\u{1434}']),
+    ('\u{1443}', &['\u{1434}', '\u{B7}']),
+    ('\u{1444}', &['\u{B7}', '\u{3C}']),
+    ('\u{1445}', &['\u{3C}', '\u{B7}']),
+    ('\u{1446}', &['\u{B7}', '\u{1439}']),
+    ('\u{1447}', &['\u{1439}', '\u{B7}']),
+    ('\u{144A}', &['\u{27}']),
+    ('\u{144C}', &['\u{55}']),
+    ('\u{144E}', &['\u{548}']),
+    ('\u{1454}', &['\u{B7}', '\u{1450}']),
+    ('\u{1457}', &['\u{B7}', '\u{55}']),
+    ('\u{1458}', &['\u{55}', '\u{B7}']),
+    ('\u{1459}', &['\u{B7}', '\u{548}']),
+    ('\u{145A}', &['\u{548}', '\u{B7}']),
+    ('\u{145B}', &['\u{B7}', '\u{144F}']),
+    ('\u{145C}', &['\u{144F}', '\u{B7}']),
+    ('\u{145D}', &['\u{B7}', '\u{1450}']),
+    ('\u{145E}', &['\u{1450}', '\u{B7}']),
+    ('\u{145F}', &['\u{B7}', '\u{1451}']),
+    ('\u{1460}', &['\u{1451}', '\u{B7}']),
+    ('\u{1461}', &['\u{B7}', '\u{1455}']),
+    ('\u{1462}', &['\u{1455}', '\u{B7}']),
+    ('\u{1463}', &['\u{B7}', '\u{1456}']),
+    ('\u{1464}', &['\u{1456}', '\u{B7}']),
+    ('\u{1467}', &['\u{55}', '\u{27}']),
+    ('\u{1468}', &['\u{548}', '\u{27}']),
+    ('\u{1469}', &['\u{1450}', '\u{27}']),
+    ('\u{146A}', &['\u{1455}', '\u{27}']),
+    ('\u{146D}', &['\u{50}']),
+    ('\u{146F}', &['\u{64}']),
+    ('\u{1472}', &['\u{62}']),
+    ('\u{1473}', &['\u{62}', '\u{307}']),
+    ('\u{1474}', &['\u{B7}', '\u{146B}']),
+    ('\u{1475}', &['\u{146B}', '\u{B7}']),
+    ('\u{1476}', &['\u{B7}', '\u{50}']),
+    ('\u{1477}', &['\u{70}', '\u{B7}']),
+    ('\u{1478}', &['\u{B7}', '\u{146E}']),
+    ('\u{1479}', &['\u{146E}', '\u{B7}']),
+    ('\u{147A}', &['\u{B7}', '\u{64}']),
+    ('\u{147B}', &['\u{64}', '\u{B7}']),
+    ('\u{147C}', &['\u{B7}', '\u{1470}']),
+    ('\u{147D}', &['\u{1470}', '\u{B7}']),
+    ('\u{147E}', &['\u{B7}', '\u{62}']),
+    ('\u{147F}', &['\u{62}', '\u{B7}']),
+    ('\u{1480}', &['\u{B7}', '\u{62}', '\u{307}']),
+    ('\u{1481}', &['\u{62}', '\u{307}', '\u{B7}']),
+    ('\u{1485}', &['\u{146B}', '\u{27}']),
+    ('\u{1486}', &['\u{50}', '\u{27}']),
+    ('\u{1487}', &['\u{64}', '\u{27}']),
+    ('\u{1488}', &['\u{62}', '\u{27}']),
+    ('\u{148D}', &['\u{4A}']),
+    ('\u{1492}', &['\u{B7}', '\u{1489}']),
+    ('\u{1493}', &['\u{1489}', '\u{B7}']),
+    ('\u{1494}', &['\u{B7}', '\u{148B}']),
+    ('\u{1495}', &['\u{148B}', '\u{B7}']),
+    ('\u{1496}', &['\u{B7}', '\u{148C}']),
+    ('\u{1497}', &['\u{148C}', '\u{B7}']),
+    ('\u{1498}', &['\u{B7}', '\u{4A}']),
+    ('\u{1499}', &['\u{4A}', '\u{B7}']),
+    ('\u{149A}', &['\u{B7}', '\u{148E}']),
+    ('\u{149B}', &['\u{148E}', '\u{B7}']),
+    ('\u{149C}', &['\u{B7}', '\u{1490}']),
+    ('\u{149D}', &['\u{1490}', '\u{B7}']),
+    ('\u{149E}', &['\u{B7}', '\u{1491}']),
+    ('\u{149F}', &['\u{1491}', '\u{B7}']),
+    ('\u{14A5}', &['\u{393}']),
+    ('\u{14AA}', &['\u{4C}']),
+    ('\u{14AC}', &['\u{B7}', '\u{14A3}']),
+    ('\u{14AD}', &['\u{14A3}', '\u{B7}']),
+    ('\u{14AE}', &['\u{B7}', '\u{393}']),
+    ('\u{14AF}', &['\u{393}', '\u{B7}']),
+    ('\u{14B0}', &['\u{B7}', '\u{14A6}']),
+    ('\u{14B1}', &['\u{14A6}', '\u{B7}']),
+    ('\u{14B2}', &['\u{B7}', '\u{14A7}']),
+    ('\u{14B3}', &['\u{14A7}', '\u{B7}']),
+    ('\u{14B4}', &['\u{B7}', '\u{14A8}']),
+    ('\u{14B5}', &['\u{14A8}', '\u{B7}']),
+    ('\u{14B6}', &['\u{B7}', '\u{4C}']),
+    ('\u{14B7}', &['\u{6C}', '\u{B7}']),
+    ('\u{14B8}', &['\u{B7}', '\u{14AB}']),
+    ('\u{14B9}', &['\u{14AB}', '\u{B7}']),
+    ('\u{14BF}', &['\u{32}']),
+    ('\u{14C9}', &['\u{B7}', '\u{14C0}']),
+    ('\u{14CA}', &['\u{14C0}', '\u{B7}']),
+    ('\u{14CB}', &['\u{B7}', '\u{14C7}']),
+    ('\u{14CC}', &['\u{14C7}', '\u{B7}']),
+    ('\u{14CD}', &['\u{B7}', '\u{14C8}']),
+    ('\u{14CE}', &['\u{14C8}', '\u{B7}']),
+    ('\u{14D1}', &['\u{1421}']),
+    ('\u{14DC}', &['\u{B7}', '\u{14D3}']),
+    ('\u{14DD}', &['\u{14D3}', '\u{B7}']),
+    ('\u{14DE}', &['\u{B7}', '\u{14D5}']),
+    ('\u{14DF}', &['\u{14D5}', '\u{B7}']),
+    ('\u{14E0}', &['\u{B7}', '\u{14D6}']),
+    ('\u{14E1}', &['\u{14D6}', '\u{B7}']),
+    ('\u{14E2}', &['\u{B7}', '\u{14D7}']),
+    ('\u{14E3}', &['\u{14D7}', '\u{B7}']),
+    ('\u{14E4}', &['\u{B7}', '\u{14D8}']),
+    ('\u{14E5}', &['\u{14D8}', '\u{B7}']),
+    ('\u{14E6}', &['\u{B7}', '\u{14DA}']),
+    ('\u{14E7}', &['\u{14DA}', '\u{B7}']),
+    ('\u{14E8}', &['\u{B7}', '\u{14DB}']),
+    ('\u{14E9}', &['\u{14DB}', '\u{B7}']),
+    ('\u{14F6}', &['\u{B7}', '\u{14ED}']),
+    ('\u{14F7}', &['\u{14ED}', '\u{B7}']),
+    ('\u{14F8}', &['\u{B7}', '\u{14EF}']),
+    ('\u{14F9}', &['\u{14EF}', '\u{B7}']),
+    ('\u{14FA}', &['\u{B7}', '\u{14F0}']),
+    ('\u{14FB}', &['\u{14F0}', '\u{B7}']),
+    ('\u{14FC}', &['\u{B7}', '\u{14F1}']),
+    ('\u{14FD}', &['\u{14F1}', '\u{B7}']),
+    ('\u{14FE}', &['\u{B7}', '\u{14F2}']),
+    ('\u{14FF}', &['\u{14F2}', '\u{B7}']),
+    ('\u{1500}', &['\u{B7}', '\u{14F4}']),
+    ('\u{1501}', &['\u{14F4}', '\u{B7}']),
+    ('\u{1502}', &['\u{B7}', '\u{14F5}']),
+    ('\u{1503}', &['\u{14F5}', '\u{B7}']),
+    ('\u{150C}', &['\u{150B}', '\u{3C}']),
+    ('\u{150D}', &['\u{150B}', '\u{1455}']),
+    ('\u{150E}', &['\u{150B}', '\u{62}']),
+    ('\u{150F}', &['\u{150B}', '\u{1490}']),
+    ('\u{1517}', &['\u{B7}', '\u{1510}']),
+    ('\u{1518}', &['\u{1510}', '\u{B7}']),
+    ('\u{1519}', &['\u{B7}', '\u{1511}']),
+    ('\u{151A}', &['\u{1511}', '\u{B7}']),
+    ('\u{151B}', &['\u{B7}', '\u{1512}']),
+    ('\u{151C}', &['\u{1512}', '\u{B7}']),
+    ('\u{151D}', &['\u{B7}', '\u{1513}']),
+    ('\u{151E}', &['\u{1513}', '\u{B7}']),
+    ('\u{151F}', &['\u{B7}', '\u{1514}']),
+    ('\u{1520}', &['\u{1514}', '\u{B7}']),
+    ('\u{1521}', &['\u{B7}', '\u{1515}']),
+    ('\u{1522}', &['\u{1515}', '\u{B7}']),
+    ('\u{1523}', &['\u{B7}', '\u{1516}']),
+    ('\u{1524}', &['\u{1516}', '\u{B7}']),
+    ('\u{152F}', &['\u{B7}', '\u{34}']),
+    ('\u{1530}', &['\u{34}', '\u{B7}']),
+    ('\u{1531}', &['\u{B7}', '\u{1528}']),
+    ('\u{1532}', &['\u{1528}', '\u{B7}']),
+    ('\u{1533}', &['\u{B7}', '\u{1529}']),
+    ('\u{1534}', &['\u{1529}', '\u{B7}']),
+    ('\u{1535}', &['\u{B7}', '\u{152A}']),
+    ('\u{1536}', &['\u{152A}', '\u{B7}']),
+    ('\u{1537}', &['\u{B7}', '\u{152B}']),
+    ('\u{1538}', &['\u{152B}', '\u{B7}']),
+    ('\u{1539}', &['\u{B7}', '\u{152D}']),
+    ('\u{153A}', &['\u{152D}', '\u{B7}']),
+    ('\u{153B}', &['\u{B7}', '\u{152E}']),
+    ('\u{153C}', &['\u{152E}', '\u{B7}']),
+    ('\u{1540}', &['\u{1429}']),
+    ('\u{1541}', &['\u{78}']),
+    ('\u{154E}', &['\u{B7}', '\u{154C}']),
+    ('\u{154F}', &['\u{154C}', '\u{B7}']),
+    ('\u{155B}', &['\u{B7}', '\u{155A}']),
+    ('\u{155C}', &['\u{155A}', '\u{B7}']),
+    ('\u{1568}', &['\u{B7}', '\u{1567}']),
+    ('\u{1569}', &['\u{1567}', '\u{B7}']),
+    ('\u{1577}', &['\u{1E9F}']),
+    ('\u{157C}', &['\u{48}']),
+    ('\u{157D}', &['\u{78}']),
+    ('\u{157E}', &['\u{1550}', '\u{146C}']),
+    ('\u{157F}', &['\u{1550}', '\u{50}']),
+    ('\u{1580}', &['\u{1550}', '\u{146E}']),
+    ('\u{1581}', &['\u{1550}', '\u{64}']),
+    ('\u{1582}', &['\u{1550}', '\u{1470}']),
+    ('\u{1583}', &['\u{1550}', '\u{62}']),
+    ('\u{1584}', &['\u{1550}', '\u{62}', '\u{307}']),
+    ('\u{1585}', &['\u{1550}', '\u{1483}']),
+    ('\u{1587}', &['\u{52}']),
+    ('\u{158E}', &['\u{1595}', '\u{148A}']),
+    ('\u{158F}', &['\u{1595}', '\u{148B}']),
+    ('\u{1590}', &['\u{1595}', '\u{148C}']),
+    ('\u{1591}', &['\u{1595}', '\u{4A}']),
+    ('\u{1592}', &['\u{1595}', '\u{148E}']),
+    ('\u{1593}', &['\u{1595}', '\u{1490}']),
+    ('\u{1594}', &['\u{1595}', '\u{1491}']),
+    ('\u{15AF}', &['\u{62}']),
+    ('\u{15B4}', &['\u{46}']),
+    ('\u{15B5}', &['\u{2132}']),
+    ('\u{15B7}', &['\u{A7FB}']),
+    ('\u{15C4}', &['\u{2C6F}']),
+    ('\u{15C5}', &['\u{41}']),
+    ('\u{15DE}', &['\u{44}']),
+    ('\u{15EA}', &['\u{44}']),
+    ('\u{15EF}', &['\u{460}']),
+    ('\u{15F0}', &['\u{4D}']),
+    ('\u{15F7}', &['\u{42}']),
+    ('\u{1602}', &['\u{1490}']),
+    ('\u{1603}', &['\u{1489}']),
+    ('\u{1604}', &['\u{14D3}']),
+    ('\u{1607}', &['\u{14DA}']),
+    ('\u{1622}', &['\u{1543}']),
+    ('\u{1623}', &['\u{1546}']),
+    ('\u{1624}', &['\u{154A}']),
+    ('\u{162E}', &['\u{1B1}']),
+    ('\u{162F}', &['\u{3A9}']),
+    ('\u{1634}', &['\u{1B1}']),
+    ('\u{1635}', &['\u{3A9}']),
+    ('\u{166D}', &['\u{58}']),
+    ('\u{166E}', &['\u{78}']),
+    ('\u{166F}', &['\u{1550}', '\u{146B}']),
+    ('\u{1670}', &['\u{1595}', '\u{1489}']),
+    ('\u{1671}', &['\u{1596}', '\u{148B}']),
+    ('\u{1672}', &['\u{1596}', '\u{148C}']),
+    ('\u{1673}', &['\u{1596}', '\u{4A}']),
+    ('\u{1674}', &['\u{1596}', '\u{148E}']),
+    ('\u{1675}', &['\u{1596}', '\u{1490}']),
+    ('\u{1676}', &['\u{1596}', '\u{1491}']),
+    ('\u{1677}', &['\u{15A7}', '\u{B7}']),
+    ('\u{1678}', &['\u{15A8}', '\u{B7}']),
+    ('\u{1679}', &['\u{15A9}', '\u{B7}']),
+    ('\u{167A}', &['\u{15AA}', '\u{B7}']),
+    ('\u{167B}', &['\u{15AB}', '\u{B7}']),
+    ('\u{167C}', &['\u{15AC}', '\u{B7}']),
+    ('\u{167D}', &['\u{15AD}', '\u{B7}']),
+    ('\u{1680}', &['\u{20}']),
+    ('\u{16B2}', &['\u{3C}']),
+    ('\u{16B7}', &['\u{58}']),
+    ('\u{16C1}', &['\u{6C}']),
+    ('\u{16C2}', &['\u{16BD}']),
+    ('\u{16CC}', &['\u{27}']),
+    ('\u{16D5}', &['\u{4B}']),
+    ('\u{16D6}', &['\u{4D}']),
+    ('\u{16D8}', &['\u{3A8}']),
+    ('\u{16E1}', &['\u{16BC}']),
+    ('\u{16EB}', &['\u{B7}']),
+    ('\u{16EC}', &['\u{3A}']),
+    ('\u{16ED}', &['\u{2B}']),
+    ('\u{16F0}', &['\u{3A6}']),
+    ('\u{1735}', &['\u{2F}']),
+    ('\u{17A3}', &['\u{17A2}']),
+    ('\u{17B7}', &['\u{E34}']),
+    ('\u{17B8}', &['\u{E35}']),
+    ('\u{17B9}', &['\u{E36}']),
+    ('\u{17BA}', &['\u{E37}']),
+    ('\u{17C6}', &['\u{30A}']),
+    ('\u{17CB}', &['\u{E48}']),
+    ('\u{17D3}', &['\u{30A}']),
+    ('\u{17D4}', &['\u{E2F}']),
+    ('\u{17D5}', &['\u{E5A}']),
+    ('\u{17D9}', &['\u{E4F}']),
+    ('\u{17DA}', &['\u{E5B}']),
+    ('\u{1803}', &['\u{3A}']),
+    ('\u{1809}', &['\u{3A}']),
+    ('\u{1855}', &['\u{1835}']),
+    ('\u{1896}', &['\u{185C}']),
+    ('\u{18B3}', &['\u{B7}', '\u{18B1}']),
+    ('\u{18B6}', &['\u{B7}', '\u{18B4}']),
+    ('\u{18B9}', &['\u{B7}', '\u{18B8}']),
+    ('\u{18C2}', &['\u{B7}', '\u{18C0}']),
+    ('\u{18C6}', &['\u{B7}', '\u{14C2}']),
+    ('\u{18C7}', &['\u{14C2}', '\u{B7}']),
+    ('\u{18C8}', &['\u{B7}', '\u{14C3}']),
+    ('\u{18C9}', &['\u{14C3}', '\u{B7}']),
+    ('\u{18CA}', &['\u{B7}', '\u{14C4}']),
+    ('\u{18CB}', &['\u{14C4}', '\u{B7}']),
+    ('\u{18CC}', &['\u{B7}', '\u{14C5}']),
+    ('\u{18CD}', &['\u{14C5}', '\u{B7}']),
+    ('\u{18CE}', &['\u{B7}', '\u{1543}']),
+    ('\u{18CF}', &['\u{B7}', '\u{1546}']),
+    ('\u{18D0}', &['\u{B7}', '\u{1547}']),
+    ('\u{18D1}', &['\u{B7}', '\u{1548}']),
+    ('\u{18D2}', &['\u{B7}', '\u{1549}']),
+    ('\u{18D3}', &['\u{B7}', '\u{154B}']),
+    ('\u{18DB}', &['\u{18F5}']),
+    ('\u{18DC}', &['\u{18DF}', '\u{141E}']),
+    ('\u{18DD}', &['\u{141E}', '\u{18DF}']),
+    ('\u{18E0}', &['\u{1543}', '\u{B7}']),
+    ('\u{18E3}', &['\u{155E}', '\u{B7}']),
+    ('\u{18E4}', &['\u{1566}', '\u{B7}']),
+    ('\u{18E5}', &['\u{156B}', '\u{B7}']),
+    ('\u{18E8}', &['\u{1586}', '\u{B7}']),
+    ('\u{18EA}', &['\u{1597}', '\u{B7}']),
+    ('\u{18ED}', &['\u{460}', '\u{B7}']),
+    ('\u{18F0}', &['\u{15F4}', '\u{B7}']),
+    ('\u{18F2}', &['\u{161B}', '\u{B7}']),
+    ('\u{19D0}', &['\u{199E}']),
+    ('\u{19D1}', &['\u{19B1}']),
+    ('\u{1A80}', &['\u{1A45}']),
+    ('\u{1A90}', &['\u{1A45}']),
+    ('\u{1AA9}', &['\u{1AA8}', '\u{1AA8}']),
+    ('\u{1AAB}', &['\u{1AAA}', '\u{1AA8}']),
+    ('\u{1AB4}', &['\u{6DB}']),
+    ('\u{1AB7}', &['\u{328}']),
+    ('\u{1B52}', &['\u{1B0D}']),
+    ('\u{1B53}', &['\u{1B11}']),
+    ('\u{1B58}', &['\u{1B28}']),
+    ('\u{1B5C}', &['\u{1B50}']),
+    ('\u{1B5F}', &['\u{1B5E}', '\u{1B5E}']),
+    ('\u{1C3C}', &['\u{1C3B}', '\u{1C3B}']),
+    ('\u{1C7F}', &['\u{1C7E}', '\u{1C7E}']),
+    ('\u{1CD0}', &['\u{302}']),
+    ('\u{1CD2}', &['\u{304}']),
+    ('\u{1CD3}', &['\u{27}', '\u{27}']),
+    ('\u{1CD5}', &['\u{32B}']),
+    ('\u{1CD8}', &['\u{32E}']),
+    ('\u{1CD9}', &['\u{32D}']),
+    ('\u{1CDA}', &['\u{30E}']),
+    ('\u{1CDC}', &['\u{329}']),
+    ('\u{1CDD}', &['\u{323}']),
+    ('\u{1CDE}', &['\u{324}']),
+    ('\u{1CED}', &['\u{316}']),
+    ('\u{1D04}', &['\u{63}']),
+    ('\u{1D08}', &['\u{25C}']),
+    ('\u{1D0B}', &['\u{138}']),
+    ('\u{1D0D}', &['\u{28D}']),
+    ('\u{1D0F}', &['\u{6F}']),
+    ('\u{1D10}', &['\u{254}']),
+    ('\u{1D11}', &['\u{6F}']),
+    ('\u{1D14}', &['\u{1DD}', '\u{6F}']),
+    ('\u{1D1C}', &['\u{75}']),
+    ('\u{1D20}', &['\u{76}']),
+    ('\u{1D21}', &['\u{77}']),
+    ('\u{1D22}', &['\u{7A}']),
+    ('\u{1D24}', &['\u{1A8}']),
+    ('\u{1D26}', &['\u{72}']),
+    ('\u{1D27}', &['\u{28C}']),
+    ('\u{1D28}', &['\u{3C0}']),
+    ('\u{1D29}', &['\u{1D18}']),
+    ('\u{1D2B}', &['\u{43B}']),
+    ('\u{1D3E}', &['\u{18D6}']),
+    ('\u{1D52}', &['\u{BA}']),
+    ('\u{1D6B}', &['\u{75}', '\u{65}']),
+    ('\u{1D6E}', &['\u{66}', '\u{334}']),
+    ('\u{1D6F}', &['\u{72}', '\u{6E}', '\u{334}']),
+    ('\u{1D70}', &['\u{6E}', '\u{334}']),
+    ('\u{1D72}', &['\u{72}', '\u{334}']),
+    ('\u{1D73}', &['\u{27E}', '\u{334}']),
+    ('\u{1D74}', &['\u{73}', '\u{334}']),
+    ('\u{1D75}', &['\u{74}', '\u{334}']),
+    ('\u{1D76}', &['\u{7A}', '\u{334}']),
+    ('\u{1D78}', &['\u{1D34}']),
+    ('\u{1D7B}', &['\u{69}', '\u{335}']),
+    ('\u{1D7C}', &['\u{69}', '\u{335}']),
+    ('\u{1D7D}', &['\u{70}', '\u{335}']),
+    ('\u{1D7E}', &['\u{75}', '\u{335}']),
+    ('\u{1D7F}', &['\u{28A}', '\u{335}']),
+    ('\u{1D83}', &['\u{67}']),
+    ('\u{1D8C}', &['\u{79}']),
+    ('\u{1D90}', &['\u{24B}']),
+    ('\u{1D9F}', &['\u{1D4B}']),
+    ('\u{1DA2}', &['\u{1D4D}']),
+    ('\u{1DBA}', &['\u{18D4}']),
+    ('\u{1DBB}', &['\u{1646}']),
+    ('\u{1DEE}', &['\u{2DEC}']),
+    ('\u{1E43}', &['\u{AB51}']),
+    ('\u{1E9A}', &['\u{1EA3}']),
+    ('\u{1E9D}', &['\u{66}']),
+    ('\u{1EFF}', &['\u{79}']),
+    ('\u{1F7D}', &['\u{1FF4}']),
+    ('\u{1FBD}', &['\u{27}']),
+    ('\u{1FBE}', &['\u{69}']),
+    ('\u{1FBF}', &['\u{27}']),
+    ('\u{1FC0}', &['\u{7E}']),
+    ('\u{1FEF}', &['\u{27}']),
+    ('\u{1FF6}', &['\u{13EF}']),
+    ('\u{1FFD}', &['\u{27}']),
+    ('\u{1FFE}', &['\u{27}']),
+    ('\u{2000}', &['\u{20}']),
+    ('\u{2001}', &['\u{20}']),
+    ('\u{2002}', &['\u{20}']),
+    ('\u{2003}', &['\u{20}']),
+    ('\u{2004}', &['\u{20}']),
+    ('\u{2005}', &['\u{20}']),
+    ('\u{2006}', &['\u{20}']),
+    ('\u{2007}', &['\u{20}']),
+    ('\u{2008}', &['\u{20}']),
+    ('\u{2009}', &['\u{20}']),
+    ('\u{200A}', &['\u{20}']),
+    ('\u{2010}', &['\u{2D}']),
+    ('\u{2011}', &['\u{2D}']),
+    ('\u{2012}', &['\u{2D}']),
+    ('\u{2013}', &['\u{2D}']),
+    ('\u{2014}', &['\u{30FC}']),
+    ('\u{2015}', &['\u{30FC}']),
+    ('\u{2016}', &['\u{6C}', '\u{6C}']),
+    ('\u{2018}', &['\u{27}']),
+    ('\u{2019}', &['\u{27}']),
+    ('\u{201A}', &['\u{2C}']),
+    ('\u{201B}', &['\u{27}']),
+    ('\u{201C}', &['\u{27}', '\u{27}']),
+    ('\u{201D}', &['\u{27}', '\u{27}']),
+    ('\u{201F}', &['\u{27}', '\u{27}']),
+    ('\u{2022}', &['\u{B7}']),
+    ('\u{2024}', &['\u{2E}']),
+    ('\u{2025}', &['\u{2E}', '\u{2E}']),
+    ('\u{2026}', &['\u{2E}', '\u{2E}', '\u{2E}']),
+    ('\u{2027}', &['\u{B7}']),
+    ('\u{2028}', &['\u{20}']),
+    ('\u{2029}', &['\u{20}']),
+    ('\u{202F}', &['\u{20}']),
+    ('\u{2030}', &['\u{BA}', '\u{2F}', '\u{2080}', '\u{2080}']),
+    ('\u{2031}', &['\u{BA}', '\u{2F}', '\u{2080}', '\u{2080}', '\u{2080}']),
+    ('\u{2032}', &['\u{27}']),
+    ('\u{2033}', &['\u{27}', '\u{27}']),
+    ('\u{2034}', &['\u{27}', '\u{27}', '\u{27}']),
+    ('\u{2035}', &['\u{27}']),
+    ('\u{2036}', &['\u{27}', '\u{27}']),
+    ('\u{2037}', &['\u{27}', '\u{27}', '\u{27}']),
+    ('\u{2039}', &['\u{3C}']),
+    ('\u{203A}', &['\u{3E}']),
+    ('\u{203C}', &['\u{21}', '\u{21}']),
+    ('\u{203E}', &['\u{2C9}']),
+    ('\u{2041}', &['\u{2F}']),
+    ('\u{2043}', &['\u{2D}']),
+    ('\u{2044}', &['\u{2F}']),
+    ('\u{2047}', &['\u{3F}', '\u{3F}']),
+    ('\u{2048}', &['\u{3F}', '\u{21}']),
+    ('\u{2049}', &['\u{21}', '\u{3F}']),
+    ('\u{204E}', &['\u{2A}']),
+    ('\u{2052}', &['\u{BA}', '\u{2F}', '\u{2080}']),
+    ('\u{2053}', &['\u{7E}']),
+    ('\u{2057}', &['\u{27}', '\u{27}', '\u{27}', '\u{27}']),
+    ('\u{205A}', &['\u{3A}']),
+    ('\u{205D}', &['\u{2D57}']),
+    ('\u{205E}', &['\u{2D42}']),
+    ('\u{205F}', &['\u{20}']),
+    ('\u{2070}', &['\u{BA}']),
+    ('\u{2079}', &['\u{A770}']),
+    ('\u{20A1}', &['\u{43}', '\u{20EB}']),
+    ('\u{20A4}', &['\u{A3}']),
+    ('\u{20A5}', &['\u{72}', '\u{6E}', '\u{338}']),
+    ('\u{20A8}', &['\u{52}', '\u{73}']),
+    ('\u{20A9}', &['\u{57}', '\u{335}']),
+    ('\u{20AB}', &['\u{64}', '\u{335}', '\u{331}']),
+    ('\u{20AC}', &['\u{A792}']),
+    ('\u{20AD}', &['\u{4B}', '\u{335}']),
+    ('\u{20AE}', &['\u{54}', '\u{20EB}']),
+    ('\u{20B6}', &['\u{6C}', '\u{74}']),
+    ('\u{20BD}', &['\u{554}']),
+    ('\u{20DB}', &['\u{6DB}']),
+    ('\u{2100}', &['\u{61}', '\u{2F}', '\u{63}']),
+    ('\u{2101}', &['\u{61}', '\u{2F}', '\u{73}']),
+    ('\u{2102}', &['\u{43}']),
+    ('\u{2103}', &['\u{B0}', '\u{43}']),
+    ('\u{2105}', &['\u{63}', '\u{2F}', '\u{6F}']),
+    ('\u{2106}', &['\u{63}', '\u{2F}', '\u{75}']),
+    ('\u{2107}', &['\u{190}']),
+    ('\u{2108}', &['\u{42D}']),
+    ('\u{2109}', &['\u{B0}', '\u{46}']),
+    ('\u{210A}', &['\u{67}']),
+    ('\u{210B}', &['\u{48}']),
+    ('\u{210C}', &['\u{48}']),
+    ('\u{210D}', &['\u{48}']),
+    ('\u{210E}', &['\u{68}']),
+    ('\u{210F}', &['\u{68}', '\u{335}']),
+    ('\u{2110}', &['\u{6C}']),
+    ('\u{2111}', &['\u{6C}']),
+    ('\u{2112}', &['\u{4C}']),
+    ('\u{2113}', &['\u{6C}']),
+    ('\u{2115}', &['\u{4E}']),
+    ('\u{2116}', &['\u{4E}', '\u{6F}']),
+    ('\u{2119}', &['\u{50}']),
+    ('\u{211A}', &['\u{51}']),
+    ('\u{211B}', &['\u{52}']),
+    ('\u{211C}', &['\u{52}']),
+    ('\u{211D}', &['\u{52}']),
+    ('\u{2121}', &['\u{54}', '\u{45}', '\u{4C}']),
+    ('\u{2124}', &['\u{5A}']),
+    ('\u{2126}', &['\u{3A9}']),
+    ('\u{2127}', &['\u{1B1}']),
+    ('\u{2128}', &['\u{5A}']),
+    ('\u{2129}', &['\u{27F}']),
+    ('\u{212A}', &['\u{4B}']),
+    ('\u{212C}', &['\u{42}']),
+    ('\u{212D}', &['\u{43}']),
+    ('\u{212E}', &['\u{65}']),
+    ('\u{212F}', &['\u{65}']),
+    ('\u{2130}', &['\u{45}']),
+    ('\u{2131}', &['\u{46}']),
+    ('\u{2133}', &['\u{4D}']),
+    ('\u{2134}', &['\u{6F}']),
+    ('\u{2135}', &['\u{5D0}']),
+    ('\u{2136}', &['\u{5D1}']),
+    ('\u{2137}', &['\u{5D2}']),
+    ('\u{2138}', &['\u{5D3}']),
+    ('\u{2139}', &['\u{69}']),
+    ('\u{213B}', &['\u{46}', '\u{41}', '\u{58}']),
+    ('\u{213C}', &['\u{3C0}']),
+    ('\u{213D}', &['\u{79}']),
+    ('\u{213E}', &['\u{393}']),
+    ('\u{213F}', &['\u{3A0}']),
+    ('\u{2140}', &['\u{1A9}']),
+    ('\u{2141}', &['\u{A4E8}']),
+    ('\u{2142}', &['\u{A4F6}']),
+    ('\u{2143}', &['\u{16F00}']),
+    ('\u{2145}', &['\u{44}']),
+    ('\u{2146}', &['\u{64}']),
+    ('\u{2147}', &['\u{65}']),
+    ('\u{2148}', &['\u{69}']),
+    ('\u{2149}', &['\u{6A}']),
+    ('\u{2160}', &['\u{6C}']),
+    ('\u{2161}', &['\u{6C}', '\u{6C}']),
+    ('\u{2162}', &['\u{6C}', '\u{6C}', '\u{6C}']),
+    ('\u{2163}', &['\u{6C}', '\u{56}']),
+    ('\u{2164}', &['\u{56}']),
+    ('\u{2165}', &['\u{56}', '\u{6C}']),
+    ('\u{2166}', &['\u{56}', '\u{6C}', '\u{6C}']),
+    ('\u{2167}', &['\u{56}', '\u{6C}', '\u{6C}', '\u{6C}']),
+    ('\u{2168}', &['\u{6C}', '\u{58}']),
+    ('\u{2169}', &['\u{58}']),
+    ('\u{216A}', &['\u{58}', '\u{6C}']),
+    ('\u{216B}', &['\u{58}', '\u{6C}', '\u{6C}']),
+    ('\u{216C}', &['\u{4C}']),
+    ('\u{216D}', &['\u{43}']),
+    ('\u{216E}', &['\u{44}']),
+    ('\u{216F}', &['\u{4D}']),
+    ('\u{2170}', &['\u{69}']),
+    ('\u{2171}', &['\u{69}', '\u{69}']),
+    ('\u{2172}', &['\u{69}', '\u{69}', '\u{69}']),
+    ('\u{2173}', &['\u{69}', '\u{76}']),
+    ('\u{2174}', &['\u{76}']),
+    ('\u{2175}', &['\u{76}', '\u{69}']),
+    ('\u{2176}', &['\u{76}', '\u{69}', '\u{69}']),
+    ('\u{2177}', &['\u{76}', '\u{69}', '\u{69}', '\u{69}']),
+    ('\u{2178}', &['\u{69}', '\u{78}']),
+    ('\u{2179}', &['\u{78}']),
+    ('\u{217A}', &['\u{78}', '\u{69}']),
+    ('\u{217B}', &['\u{78}', '\u{69}', '\u{69}']),
+    ('\u{217C}', &['\u{6C}']),
+    ('\u{217D}', &['\u{63}']),
+    ('\u{217E}', &['\u{64}']),
+    ('\u{217F}', &['\u{72}', '\u{6E}']),
+    ('\u{2183}', &['\u{186}']),
+    ('\u{2184}', &['\u{254}']),
+    ('\u{2191}', &['\u{16CF}']),
+    ('\u{2195}', &['\u{16E8}']),
+    ('\u{21B5}', &['\u{21B2}']),
+    ('\u{21BA}', &['\u{1F10E}']),
+    ('\u{21BE}', &['\u{16DA}']),
+    ('\u{21BF}', &['\u{16D0}']),
+    ('\u{2200}', &['\u{2C6F}']),
+    ('\u{2203}', &['\u{18E}']),
+    ('\u{2206}', &['\u{394}']),
+    ('\u{220F}', &['\u{3A0}']),
+    ('\u{2211}', &['\u{1A9}']),
+    ('\u{2212}', &['\u{2D}']),
+    ('\u{2214}', &['\u{2B}', '\u{307}']),
+    ('\u{2215}', &['\u{2F}']),
+    ('\u{2216}', &['\u{5C}']),
+    ('\u{2217}', &['\u{2A}']),
+    ('\u{2218}', &['\u{B0}']),
+    ('\u{2219}', &['\u{B7}']),
+    ('\u{221E}', &['\u{6F}', '\u{6F}']),
+    ('\u{2223}', &['\u{6C}']),
+    ('\u{2225}', &['\u{6C}', '\u{6C}']),
+    ('\u{2228}', &['\u{76}']),
+    ('\u{2229}', &['\u{548}']),
+    ('\u{222A}', &['\u{55}']),
+    ('\u{222B}', &['\u{283}']),
+    ('\u{222C}', &['\u{283}', '\u{283}']),
+    ('\u{222D}', &['\u{283}', '\u{283}', '\u{283}']),
+    ('\u{222F}', &['\u{222E}', '\u{222E}']),
+    ('\u{2230}', &['\u{222E}', '\u{222E}', '\u{222E}']),
+    ('\u{2236}', &['\u{3A}']),
+    ('\u{2238}', &['\u{2D}', '\u{307}']),
+    ('\u{223C}', &['\u{7E}']),
+    ('\u{2250}', &['\u{3D}', '\u{307}']),
+    ('\u{2251}', &['\u{3D}', '\u{307}', '\u{323}']),
+    ('\u{2257}', &['\u{3D}', '\u{30A}']),
+    ('\u{2259}', &['\u{3D}', '\u{302}']),
+    ('\u{225A}', &['\u{3D}', '\u{306}']),
+    ('\u{225E}', &['\u{3D}', '\u{36B}']),
+    ('\u{2263}', &['\u{2261}']),
+    ('\u{226A}', &['\u{3C}', '\u{3C}']),
+    ('\u{226B}', &['\u{3E}', '\u{3E}']),
+    ('\u{2282}', &['\u{1455}']),
+    ('\u{2283}', &['\u{1450}']),
+    ('\u{2295}', &['\u{102A8}']),
+    ('\u{2296}', &['\u{4F}', '\u{335}']),
+    ('\u{2299}', &['\u{298}']),
+    ('\u{229D}', &['\u{4F}', '\u{335}']),
+    ('\u{22A4}', &['\u{54}']),
+    ('\u{22A5}', &['\u{A4D5}']),
+    ('\u{22C0}', &['\u{2227}']),
+    ('\u{22C1}', &['\u{76}']),
+    ('\u{22C2}', &['\u{548}']),
+    ('\u{22C3}', &['\u{55}']),
+    ('\u{22C4}', &['\u{16DC}']),
+    ('\u{22C5}', &['\u{B7}']),
+    ('\u{22C8}', &['\u{16DE}']),
+    ('\u{22D6}', &['\u{3C}', '\u{B7}']),
+    ('\u{22D7}', &['\u{B7}', '\u{3E}']),
+    ('\u{22D8}', &['\u{3C}', '\u{3C}', '\u{3C}']),
+    ('\u{22D9}', &['\u{3E}', '\u{3E}', '\u{3E}']),
+    ('\u{22EE}', &['\u{2D57}']),
+    ('\u{22EF}', &['\u{B7}', '\u{B7}', '\u{B7}']),
+    ('\u{22F4}', &['\u{A793}']),
+    ('\u{22FF}', &['\u{45}']),
+    ('\u{2300}', &['\u{2205}']),
+    ('\u{2325}', &['\u{2324}']),
+    ('\u{2329}', &['\u{276C}']),
+    ('\u{232A}', &['\u{276D}']),
+    ('\u{2341}', &['\u{303C}']),
+    ('\u{2359}', &['\u{394}', '\u{332}']),
+    ('\u{235A}', &['\u{16DC}', '\u{332}']),
+    ('\u{235C}', &['\u{B0}', '\u{332}']),
+    ('\u{235F}', &['\u{229B}']),
+    ('\u{2361}', &['\u{54}', '\u{308}']),
+    ('\u{2362}', &['\u{2207}', '\u{308}']),
+    ('\u{2363}', &['\u{22C6}', '\u{308}']),
+    ('\u{2364}', &['\u{B0}', '\u{308}']),
+    ('\u{2365}', &['\u{629}']),
+    ('\u{2368}', &['\u{7E}', '\u{308}']),
+    ('\u{2369}', &['\u{1435}']),
+    ('\u{236B}', &['\u{2207}', '\u{334}']),
+    ('\u{236C}', &['\u{4F}', '\u{335}']),
+    ('\u{2373}', &['\u{69}']),
+    ('\u{2374}', &['\u{70}']),
+    ('\u{2375}', &['\u{3C9}']),
+    ('\u{2376}', &['\u{61}', '\u{332}']),
+    ('\u{2377}', &['\u{A793}', '\u{332}']),
+    ('\u{2378}', &['\u{69}', '\u{332}']),
+    ('\u{2379}', &['\u{3C9}', '\u{332}']),
+    ('\u{237A}', &['\u{61}']),
+    ('\u{237F}', &['\u{16BD}']),
+    ('\u{239C}', &['\u{4E28}']),
+    ('\u{239F}', &['\u{4E28}']),
+    ('\u{23A2}', &['\u{4E28}']),
+    ('\u{23A5}', &['\u{4E28}']),
+    ('\u{23AA}', &['\u{4E28}']),
+    ('\u{23AE}', &['\u{4E28}']),
+    ('\u{23C1}', &['\u{2355}']),
+    ('\u{23C2}', &['\u{234E}']),
+    ('\u{23C3}', &['\u{234B}']),
+    ('\u{23C6}', &['\u{236D}']),
+    ('\u{23E8}', &['\u{2081}', '\u{2080}']),
+    ('\u{23FC}', &['\u{23FB}']),
+    ('\u{23FD}', &['\u{6C}']),
+    ('\u{23FE}', &['\u{263E}']),
+    ('\u{244A}', &['\u{5C}', '\u{5C}']),
+    ('\u{2460}', &['\u{2780}']),
+    ('\u{2461}', &['\u{2781}']),
+    ('\u{2462}', &['\u{2782}']),
+    ('\u{2463}', &['\u{2783}']),
+    ('\u{2464}', &['\u{2784}']),
+    ('\u{2465}', &['\u{2785}']),
+    ('\u{2466}', &['\u{2786}']),
+    ('\u{2467}', &['\u{2787}']),
+    ('\u{2468}', &['\u{2788}']),
+    ('\u{2469}', &['\u{2789}']),
+    ('\u{2474}', &['\u{28}', '\u{6C}', '\u{29}']),
+    ('\u{2475}', &['\u{28}', '\u{32}', '\u{29}']),
+    ('\u{2476}', &['\u{28}', '\u{33}', '\u{29}']),
+    ('\u{2477}', &['\u{28}', '\u{34}', '\u{29}']),
+    ('\u{2478}', &['\u{28}', '\u{35}', '\u{29}']),
+    ('\u{2479}', &['\u{28}', '\u{36}', '\u{29}']),
+    ('\u{247A}', &['\u{28}', '\u{37}', '\u{29}']),
+    ('\u{247B}', &['\u{28}', '\u{38}', '\u{29}']),
+    ('\u{247C}', &['\u{28}', '\u{39}', '\u{29}']),
+    ('\u{247D}', &['\u{28}', '\u{6C}', '\u{4F}', '\u{29}']),
+    ('\u{247E}', &['\u{28}', '\u{6C}', '\u{6C}', '\u{29}']),
+    ('\u{247F}', &['\u{28}', '\u{6C}', '\u{32}', '\u{29}']),
+    ('\u{2480}', &['\u{28}', '\u{6C}', '\u{33}', '\u{29}']),
+    ('\u{2481}', &['\u{28}', '\u{6C}', '\u{34}', '\u{29}']),
+    ('\u{2482}', &['\u{28}', '\u{6C}', '\u{35}', '\u{29}']),
+    ('\u{2483}', &['\u{28}', '\u{6C}', '\u{36}', '\u{29}']),
+    ('\u{2484}', &['\u{28}', '\u{6C}', '\u{37}', '\u{29}']),
+    ('\u{2485}', &['\u{28}', '\u{6C}', '\u{38}', '\u{29}']),
+    ('\u{2486}', &['\u{28}', '\u{6C}', '\u{39}', '\u{29}']),
+    ('\u{2487}', &['\u{28}', '\u{32}', '\u{4F}', '\u{29}']),
+    ('\u{2488}', &['\u{6C}', '\u{2E}']),
+    ('\u{2489}', &['\u{32}', '\u{2E}']),
+    ('\u{248A}', &['\u{33}', '\u{2E}']),
+    ('\u{248B}', &['\u{34}', '\u{2E}']),
+    ('\u{248C}', &['\u{35}', '\u{2E}']),
+    ('\u{248D}', &['\u{36}', '\u{2E}']),
+    ('\u{248E}', &['\u{37}', '\u{2E}']),
+    ('\u{248F}', &['\u{38}', '\u{2E}']),
+    ('\u{2490}', &['\u{39}', '\u{2E}']),
+    ('\u{2491}', &['\u{6C}', '\u{4F}', '\u{2E}']),
+    ('\u{2492}', &['\u{6C}', '\u{6C}', '\u{2E}']),
+    ('\u{2493}', &['\u{6C}', '\u{32}', '\u{2E}']),
+    ('\u{2494}', &['\u{6C}', '\u{33}', '\u{2E}']),
+    ('\u{2495}', &['\u{6C}', '\u{34}', '\u{2E}']),
+    ('\u{2496}', &['\u{6C}', '\u{35}', '\u{2E}']),
+    ('\u{2497}', &['\u{6C}', '\u{36}', '\u{2E}']),
+    ('\u{2498}', &['\u{6C}', '\u{37}', '\u{2E}']),
+    ('\u{2499}', &['\u{6C}', '\u{38}', '\u{2E}']),
+    ('\u{249A}', &['\u{6C}', '\u{39}', '\u{2E}']),
+    ('\u{249B}', &['\u{32}', '\u{4F}', '\u{2E}']),
+    ('\u{249C}', &['\u{28}', '\u{61}', '\u{29}']),
+    ('\u{249D}', &['\u{28}', '\u{62}', '\u{29}']),
+    ('\u{249E}', &['\u{28}', '\u{63}', '\u{29}']),
+    ('\u{249F}', &['\u{28}', '\u{64}', '\u{29}']),
+    ('\u{24A0}', &['\u{28}', '\u{65}', '\u{29}']),
+    ('\u{24A1}', &['\u{28}', '\u{66}', '\u{29}']),
+    ('\u{24A2}', &['\u{28}', '\u{67}', '\u{29}']),
+    ('\u{24A3}', &['\u{28}', '\u{68}', '\u{29}']),
+    ('\u{24A4}', &['\u{28}', '\u{69}', '\u{29}']),
+    ('\u{24A5}', &['\u{28}', '\u{6A}', '\u{29}']),
+    ('\u{24A6}', &['\u{28}', '\u{6B}', '\u{29}']),
+    ('\u{24A7}', &['\u{28}', '\u{6C}', '\u{29}']),
+    ('\u{24A8}', &['\u{28}', '\u{72}', '\u{6E}', '\u{29}']),
+    ('\u{24A9}', &['\u{28}', '\u{6E}', '\u{29}']),
+    ('\u{24AA}', &['\u{28}', '\u{6F}', '\u{29}']),
+    ('\u{24AB}', &['\u{28}', '\u{70}', '\u{29}']),
+    ('\u{24AC}', &['\u{28}', '\u{71}', '\u{29}']),
+    ('\u{24AD}', &['\u{28}', '\u{72}', '\u{29}']),
+    ('\u{24AE}', &['\u{28}', '\u{73}', '\u{29}']),
+    ('\u{24AF}', &['\u{28}', '\u{74}', '\u{29}']),
+    ('\u{24B0}', &['\u{28}', '\u{75}', '\u{29}']),
+    ('\u{24B1}', &['\u{28}', '\u{76}', '\u{29}']),
+    ('\u{24B2}', &['\u{28}', '\u{77}', '\u{29}']),
+    ('\u{24B3}', &['\u{28}', '\u{78}', '\u{29}']),
+    ('\u{24B4}', &['\u{28}', '\u{79}', '\u{29}']),
+    ('\u{24B5}', &['\u{28}', '\u{7A}', '\u{29}']),
+    ('\u{24B8}', &['\u{A9}']),
+    ('\u{24C5}', &['\u{2117}']),
+    ('\u{24C7}', &['\u{AE}']),
+    ('\u{24DB}', &['\u{24BE}']),
+    ('\u{24EA}', &['\u{1F10D}']),
+    ('\u{2500}', &['\u{30FC}']),
+    ('\u{2501}', &['\u{30FC}']),
+    ('\u{2503}', &['\u{2502}']),
+    ('\u{250F}', &['\u{250C}']),
+    ('\u{2523}', &['\u{251C}']),
+    ('\u{2571}', &['\u{2F}']),
+    ('\u{2573}', &['\u{58}']),
+    ('\u{2588}', &['\u{220E}']),
+    ('\u{2590}', &['\u{258C}']),
+    ('\u{2594}', &['\u{2C9}']),
+    ('\u{2597}', &['\u{2596}']),
+    ('\u{259D}', &['\u{2598}']),
+    ('\u{25A0}', &['\u{220E}']),
+    ('\u{25B1}', &['\u{23E5}']),
+    ('\u{25B3}', &['\u{394}']),
+    ('\u{25B7}', &['\u{22B3}']),
+    ('\u{25B8}', &['\u{25B6}']),
+    ('\u{25BA}', &['\u{25B6}']),
+    ('\u{25BD}', &['\u{102BC}']),
+    ('\u{25C1}', &['\u{22B2}']),
+    ('\u{25C7}', &['\u{16DC}']),
+    ('\u{25CA}', &['\u{16DC}']),
+    ('\u{25CB}', &['\u{B0}']),
+    ('\u{25CE}', &['\u{233E}']),
+    ('\u{25E0}', &['\u{2312}']),
+    ('\u{25E6}', &['\u{B0}']),
+    ('\u{2609}', &['\u{298}']),
+    ('\u{2610}', &['\u{25A1}']),
+    ('\u{2625}', &['\u{1099E}']),
+    ('\u{2630}', &['\u{2CB6}']),
+    ('\u{2638}', &['\u{2388}']),
+    ('\u{264E}', &['\u{224F}']),
+    ('\u{2662}', &['\u{16DC}']),
+    ('\u{2669}', &['\u{1D158}', '\u{1D165}']),
+    ('\u{266A}', &['\u{1D158}', '\u{1D165}', '\u{1D16E}']),
+    ('\u{26AC}', &['\u{970}']),
+    ('\u{2768}', &['\u{28}']),
+    ('\u{2769}', &['\u{29}']),
+    ('\u{276E}', &['\u{3C}']),
+    ('\u{276F}', &['\u{3E}']),
+    ('\u{2772}', &['\u{28}']),
+    ('\u{2773}', &['\u{29}']),
+    ('\u{2774}', &['\u{7B}']),
+    ('\u{2775}', &['\u{7D}']),
+    ('\u{2795}', &['\u{2B}']),
+    ('\u{2796}', &['\u{2D}']),
+    ('\u{2797}', &['\u{F7}']),
+    ('\u{27C2}', &['\u{A4D5}']),
+    ('\u{27C8}', &['\u{5C}', '\u{1455}']),
+    ('\u{27C9}', &['\u{1450}', '\u{2F}']),
+    ('\u{27CB}', &['\u{2F}']),
+    ('\u{27CD}', &['\u{5C}']),
+    ('\u{27D9}', &['\u{54}']),
+    ('\u{27E8}', &['\u{276C}']),
+    ('\u{27E9}', &['\u{276D}']),
+    ('\u{292B}', &['\u{78}']),
+    ('\u{292C}', &['\u{78}']),
+    ('\u{2963}', &['\u{16D0}', '\u{16DA}']),
+    ('\u{2965}', &['\u{21C3}', '\u{21C2}']),
+    ('\u{296E}', &['\u{16D0}', '\u{21C2}']),
+    ('\u{296F}', &['\u{21C3}', '\u{16DA}']),
+    ('\u{2999}', &['\u{2D42}']),
+    ('\u{29B0}', &['\u{2349}']),
+    ('\u{29BE}', &['\u{233E}']),
+    ('\u{29C4}', &['\u{303C}']),
+    ('\u{29C5}', &['\u{2342}']),
+    ('\u{29C7}', &['\u{233B}']),
+    ('\u{29D6}', &['\u{102C0}']),
+    ('\u{29D9}', &['\u{299A}']),
+    ('\u{29F4}', &['\u{3A}', '\u{2192}']),
+    ('\u{29F5}', &['\u{5C}']),
+    ('\u{29F6}', &['\u{2F}', '\u{304}']),
+    ('\u{29F8}', &['\u{2F}']),
+    ('\u{29F9}', &['\u{5C}']),
+    ('\u{2A00}', &['\u{298}']),
+    ('\u{2A01}', &['\u{102A8}']),
+    ('\u{2A02}', &['\u{2297}']),
+    ('\u{2A03}', &['\u{228D}']),
+    ('\u{2A04}', &['\u{228E}']),
+    ('\u{2A05}', &['\u{2293}']),
+    ('\u{2A06}', &['\u{2294}']),
+    ('\u{2A0C}', &['\u{283}', '\u{283}', '\u{283}', '\u{283}']),
+    ('\u{2A1D}', &['\u{16DE}']),
+    ('\u{2A20}', &['\u{3E}', '\u{3E}']),
+    ('\u{2A21}', &['\u{16DA}']),
+    ('\u{2A22}', &['\u{2B}', '\u{30A}']),
+    ('\u{2A23}', &['\u{2B}', '\u{302}']),
+    ('\u{2A24}', &['\u{2B}', '\u{303}']),
+    ('\u{2A25}', &['\u{2B}', '\u{323}']),
+    ('\u{2A26}', &['\u{2B}', '\u{330}']),
+    ('\u{2A27}', &['\u{2B}', '\u{2082}']),
+    ('\u{2A29}', &['\u{2D}', '\u{313}']),
+    ('\u{2A2A}', &['\u{2D}', '\u{323}']),
+    ('\u{2A2F}', &['\u{78}']),
+    ('\u{2A30}', &['\u{78}', '\u{307}']),
+    ('\u{2A3D}', &['\u{2319}']),
+    ('\u{2A3E}', &['\u{2A1F}']),
+    ('\u{2A3F}', &['\u{2210}']),
+    ('\u{2A6A}', &['\u{7E}', '\u{307}']),
+    ('\u{2A6E}', &['\u{3D}', '\u{20F0}']),
+    ('\u{2A74}', &['\u{3A}', '\u{3A}', '\u{3D}']),
+    ('\u{2A75}', &['\u{3D}', '\u{3D}']),
+    ('\u{2A76}', &['\u{3D}', '\u{3D}', '\u{3D}']),
+    ('\u{2AA5}', &['\u{3E}', '\u{3C}']),
+    ('\u{2AAA}', &['\u{15D5}']),
+    ('\u{2AAB}', &['\u{15D2}']),
+    ('\u{2AD7}', &['\u{1450}', '\u{1455}']),
+    ('\u{2AFB}', &['\u{2F}', '\u{2F}', '\u{2F}']),
+    ('\u{2AFD}', &['\u{2F}', '\u{2F}']),
+    ('\u{2BEC}', &['\u{219E}']),
+    ('\u{2BED}', &['\u{219F}']),
+    ('\u{2BEE}', &['\u{21A0}']),
+    ('\u{2BEF}', &['\u{21A1}']),
+    ('\u{2C67}', &['\u{48}', '\u{329}']),
+    ('\u{2C69}', &['\u{4B}', '\u{329}']),
+    ('\u{2C84}', &['\u{393}']),
+    ('\u{2C85}', &['\u{72}']),
+    ('\u{2C86}', &['\u{394}']),
+    ('\u{2C88}', &['\u{A792}']),
+    ('\u{2C89}', &['\u{A793}']),
+    ('\u{2C8E}', &['\u{48}']),
+    ('\u{2C92}', &['\u{6C}']),
+    ('\u{2C94}', &['\u{4B}']),
+    ('\u{2C95}', &['\u{138}']),
+    ('\u{2C96}', &['\u{3BB}']),
+    ('\u{2C98}', &['\u{4D}']),
+    ('\u{2C9A}', &['\u{4E}']),
+    ('\u{2C9E}', &['\u{4F}']),
+    ('\u{2C9F}', &['\u{6F}']),
+    ('\u{2CA0}', &['\u{3A0}']),
+    ('\u{2CA2}', &['\u{50}']),
+    ('\u{2CA3}', &['\u{70}']),
+    ('\u{2CA4}', &['\u{43}']),
+    ('\u{2CA5}', &['\u{63}']),
+    ('\u{2CA6}', &['\u{54}']),
+    ('\u{2CA8}', &['\u{59}']),
+    ('\u{2CAA}', &['\u{3A6}']),
+    ('\u{2CAB}', &['\u{278}']),
+    ('\u{2CAC}', &['\u{58}']),
+    ('\u{2CAD}', &['\u{3C7}']),
+    ('\u{2CAE}', &['\u{3A8}']),
+    ('\u{2CB1}', &['\u{3C9}']),
+    ('\u{2CB4}', &['\u{3C}', '\u{B7}']),
+    ('\u{2CBA}', &['\u{2D}']),
+    ('\u{2CBC}', &['\u{428}']),
+    ('\u{2CBD}', &['\u{448}']),
+    ('\u{2CC6}', &['\u{2F}']),
+    ('\u{2CCA}', &['\u{39}']),
+    ('\u{2CCC}', &['\u{33}']),
+    ('\u{2CCD}', &['\u{21D}']),
+    ('\u{2CD0}', &['\u{4C}']),
+    ('\u{2CD1}', &['\u{29F}']),
+    ('\u{2CD2}', &['\u{36}']),
+    ('\u{2CDC}', &['\u{3EC}']),
+    ('\u{2CE4}', &['\u{3D7}']),
+    ('\u{2CE9}', &['\u{2627}']),
+    ('\u{2CF9}', &['\u{5C}', '\u{5C}']),
+    ('\u{2D31}', &['\u{4F}', '\u{335}']),
+    ('\u{2D37}', &['\u{245}']),
+    ('\u{2D38}', &['\u{56}']),
+    ('\u{2D39}', &['\u{45}']),
+    ('\u{2D3A}', &['\u{18E}']),
+    ('\u{2D41}', &['\u{4F}', '\u{338}']),
+    ('\u{2D48}', &['\u{B7}', '\u{B7}', '\u{B7}']),
+    ('\u{2D49}', &['\u{1A9}']),
+    ('\u{2D4F}', &['\u{6C}']),
+    ('\u{2D51}', &['\u{21}']),
+    ('\u{2D54}', &['\u{4F}']),
+    ('\u{2D55}', &['\u{51}']),
+    ('\u{2D59}', &['\u{298}']),
+    ('\u{2D5D}', &['\u{58}']),
+    ('\u{2D60}', &['\u{394}']),
+    ('\u{2D63}', &['\u{16EF}']),
+    ('\u{2DE8}', &['\u{1DDF}']),
+    ('\u{2DEA}', &['\u{30A}']),
+    ('\u{2DED}', &['\u{368}']),
+    ('\u{2DEF}', &['\u{36F}']),
+    ('\u{2DF6}', &['\u{363}']),
+    ('\u{2DF7}', &['\u{364}']),
+    ('\u{2E1A}', &['\u{2D}', '\u{308}']),
+    ('\u{2E1E}', &['\u{7E}', '\u{307}']),
+    ('\u{2E1F}', &['\u{7E}', '\u{323}']),
+    ('\u{2E26}', &['\u{1455}']),
+    ('\u{2E27}', &['\u{1450}']),
+    ('\u{2E28}', &['\u{28}', '\u{28}']),
+    ('\u{2E29}', &['\u{29}', '\u{29}']),
+    ('\u{2E2A}', &['\u{2235}']),
+    ('\u{2E2B}', &['\u{2234}']),
+    ('\u{2E2C}', &['\u{2237}']),
+    ('\u{2E2E}', &['\u{61F}']),
+    ('\u{2E30}', &['\u{B0}']),
+    ('\u{2E31}', &['\u{B7}']),
+    ('\u{2E32}', &['\u{60C}']),
+    ('\u{2E35}', &['\u{61B}']),
+    ('\u{2E39}', &['\u{1E9F}']),
+    ('\u{2E3D}', &['\u{2D42}']),
+    ('\u{2E3F}', &['\u{B6}']),
+    ('\u{2E40}', &['\u{3D}']),
+    ('\u{2E82}', &['\u{4E5B}']),
+    ('\u{2E83}', &['\u{4E5A}']),
+    ('\u{2E85}', &['\u{4EBB}']),
+    ('\u{2E89}', &['\u{5202}']),
+    ('\u{2E8B}', &['\u{353E}']),
+    ('\u{2E8E}', &['\u{5140}']),
+    ('\u{2E8F}', &['\u{5C23}']),
+    ('\u{2E90}', &['\u{5C22}']),
+    ('\u{2E92}', &['\u{5DF3}']),
+    ('\u{2E93}', &['\u{5E7A}']),
+    ('\u{2E94}', &['\u{5F51}']),
+    ('\u{2E96}', &['\u{5FC4}']),
+    ('\u{2E97}', &['\u{38FA}']),
+    ('\u{2E98}', &['\u{624C}']),
+    ('\u{2E99}', &['\u{6535}']),
+    ('\u{2E9B}', &['\u{65E1}']),
+    ('\u{2E9E}', &['\u{6B7A}']),
+    ('\u{2E9F}', &['\u{6BCD}']),
+    ('\u{2EA0}', &['\u{6C11}']),
+    ('\u{2EA1}', &['\u{6C35}']),
+    ('\u{2EA2}', &['\u{6C3A}']),
+    ('\u{2EA3}', &['\u{706C}']),
+    ('\u{2EA4}', &['\u{722B}']),
+    ('\u{2EA6}', &['\u{4E2C}']),
+    ('\u{2EA8}', &['\u{72AD}']),
+    ('\u{2EAB}', &['\u{7F52}']),
+    ('\u{2EAD}', &['\u{793B}']),
+    ('\u{2EAF}', &['\u{7CF9}']),
+    ('\u{2EB1}', &['\u{7F53}']),
+    ('\u{2EB2}', &['\u{7F52}']),
+    ('\u{2EB9}', &['\u{8002}']),
+    ('\u{2EBA}', &['\u{8080}']),
+    ('\u{2EBE}', &['\u{8279}']),
+    ('\u{2EBF}', &['\u{8279}']),
+    ('\u{2EC0}', &['\u{8279}']),
+    ('\u{2EC1}', &['\u{864E}']),
+    ('\u{2EC2}', &['\u{8864}']),
+    ('\u{2EC3}', &['\u{8980}']),
+    ('\u{2EC4}', &['\u{897F}']),
+    ('\u{2EC5}', &['\u{89C1}']),
+    ('\u{2EC8}', &['\u{8BA0}']),
+    ('\u{2EC9}', &['\u{8D1D}']),
+    ('\u{2ECB}', &['\u{8F66}']),
+    ('\u{2ECC}', &['\u{8FB6}']),
+    ('\u{2ECD}', &['\u{8FB6}']),
+    ('\u{2ECF}', &['\u{961D}']),
+    ('\u{2ED0}', &['\u{9485}']),
+    ('\u{2ED1}', &['\u{9577}']),
+    ('\u{2ED2}', &['\u{9578}']),
+    ('\u{2ED3}', &['\u{957F}']),
+    ('\u{2ED4}', &['\u{95E8}']),
+    ('\u{2ED6}', &['\u{961D}']),
+    ('\u{2ED8}', &['\u{9752}']),
+    ('\u{2ED9}', &['\u{97E6}']),
+    ('\u{2EDA}', &['\u{9875}']),
+    ('\u{2EDB}', &['\u{98CE}']),
+    ('\u{2EDC}', &['\u{98DE}']),
+    ('\u{2EDD}', &['\u{98DF}']),
+    ('\u{2EDF}', &['\u{98E0}']),
+    ('\u{2EE0}', &['\u{9963}']),
+    ('\u{2EE2}', &['\u{9A6C}']),
+    ('\u{2EE4}', &['\u{9B3C}']),
+    ('\u{2EE5}', &['\u{9C7C}']),
+    ('\u{2EE8}', &['\u{9EA6}']),
+    ('\u{2EE9}', &['\u{9EC4}']),
+    ('\u{2EEB}', &['\u{6589}']),
+    ('\u{2EEC}', &['\u{9F50}']),
+    ('\u{2EED}', &['\u{6B6F}']),
+    ('\u{2EEE}', &['\u{9F7F}']),
+    ('\u{2EEF}', &['\u{7ADC}']),
+    ('\u{2EF0}', &['\u{9F99}']),
+    ('\u{2EF2}', &['\u{4E80}']),
+    ('\u{2EF3}', &['\u{9F9F}']),
+    ('\u{2F00}', &['\u{30FC}']),
+    ('\u{2F01}', &['\u{4E28}']),
+    ('\u{2F02}', &['\u{5C}']),
+    ('\u{2F03}', &['\u{2F}']),
+    ('\u{2F04}', &['\u{4E59}']),
+    ('\u{2F05}', &['\u{4E85}']),
+    ('\u{2F06}', &['\u{4E8C}']),
+    ('\u{2F07}', &['\u{4EA0}']),
+    ('\u{2F08}', &['\u{4EBA}']),
+    ('\u{2F09}', &['\u{513F}']),
+    ('\u{2F0A}', &['\u{5165}']),
+    ('\u{2F0B}', &['\u{516B}']),
+    ('\u{2F0C}', &['\u{5182}']),
+    ('\u{2F0D}', &['\u{5196}']),
+    ('\u{2F0E}', &['\u{51AB}']),
+    ('\u{2F0F}', &['\u{51E0}']),
+    ('\u{2F10}', &['\u{51F5}']),
+    ('\u{2F11}', &['\u{5200}']),
+    ('\u{2F12}', &['\u{529B}']),
+    ('\u{2F13}', &['\u{52F9}']),
+    ('\u{2F14}', &['\u{5315}']),
+    ('\u{2F15}', &['\u{531A}']),
+    ('\u{2F16}', &['\u{5338}']),
+    ('\u{2F17}', &['\u{5341}']),
+    ('\u{2F18}', &['\u{535C}']),
+    ('\u{2F19}', &['\u{5369}']),
+    ('\u{2F1A}', &['\u{5382}']),
+    ('\u{2F1B}', &['\u{53B6}']),
+    ('\u{2F1C}', &['\u{53C8}']),
+    ('\u{2F1D}', &['\u{53E3}']),
+    ('\u{2F1E}', &['\u{53E3}']),
+    ('\u{2F1F}', &['\u{571F}']),
+    ('\u{2F20}', &['\u{571F}']),
+    ('\u{2F21}', &['\u{5902}']),
+    ('\u{2F22}', &['\u{590A}']),
+    ('\u{2F23}', &['\u{5915}']),
+    ('\u{2F24}', &['\u{5927}']),
+    ('\u{2F25}', &['\u{5973}']),
+    ('\u{2F26}', &['\u{5B50}']),
+    ('\u{2F27}', &['\u{5B80}']),
+    ('\u{2F28}', &['\u{5BF8}']),
+    ('\u{2F29}', &['\u{5C0F}']),
+    ('\u{2F2A}', &['\u{5C22}']),
+    ('\u{2F2B}', &['\u{5C38}']),
+    ('\u{2F2C}', &['\u{5C6E}']),
+    ('\u{2F2D}', &['\u{5C71}']),
+    ('\u{2F2E}', &['\u{5DDB}']),
+    ('\u{2F2F}', &['\u{5DE5}']),
+    ('\u{2F30}', &['\u{5DF1}']),
+    ('\u{2F31}', &['\u{5DFE}']),
+    ('\u{2F32}', &['\u{5E72}']),
+    ('\u{2F33}', &['\u{5E7A}']),
+    ('\u{2F34}', &['\u{5E7F}']),
+    ('\u{2F35}', &['\u{5EF4}']),
+    ('\u{2F36}', &['\u{5EFE}']),
+    ('\u{2F37}', &['\u{5F0B}']),
+    ('\u{2F38}', &['\u{5F13}']),
+    ('\u{2F39}', &['\u{5F50}']),
+    ('\u{2F3A}', &['\u{5F61}']),
+    ('\u{2F3B}', &['\u{5F73}']),
+    ('\u{2F3C}', &['\u{5FC3}']),
+    ('\u{2F3D}', &['\u{6208}']),
+    ('\u{2F3E}', &['\u{6236}']),
+    ('\u{2F3F}', &['\u{624B}']),
+    ('\u{2F40}', &['\u{652F}']),
+    ('\u{2F41}', &['\u{6534}']),
+    ('\u{2F42}', &['\u{6587}']),
+    ('\u{2F43}', &['\u{6597}']),
+    ('\u{2F44}', &['\u{65A4}']),
+    ('\u{2F45}', &['\u{65B9}']),
+    ('\u{2F46}', &['\u{65E0}']),
+    ('\u{2F47}', &['\u{65E5}']),
+    ('\u{2F48}', &['\u{66F0}']),
+    ('\u{2F49}', &['\u{6708}']),
+    ('\u{2F4A}', &['\u{6728}']),
+    ('\u{2F4B}', &['\u{6B20}']),
+    ('\u{2F4C}', &['\u{6B62}']),
+    ('\u{2F4D}', &['\u{6B79}']),
+    ('\u{2F4E}', &['\u{6BB3}']),
+    ('\u{2F4F}', &['\u{6BCB}']),
+    ('\u{2F50}', &['\u{6BD4}']),
+    ('\u{2F51}', &['\u{6BDB}']),
+    ('\u{2F52}', &['\u{6C0F}']),
+    ('\u{2F53}', &['\u{6C14}']),
+    ('\u{2F54}', &['\u{6C34}']),
+    ('\u{2F55}', &['\u{706B}']),
+    ('\u{2F56}', &['\u{722A}']),
+    ('\u{2F57}', &['\u{7236}']),
+    ('\u{2F58}', &['\u{723B}']),
+    ('\u{2F59}', &['\u{723F}']),
+    ('\u{2F5A}', &['\u{7247}']),
+    ('\u{2F5B}', &['\u{7259}']),
+    ('\u{2F5C}', &['\u{725B}']),
+    ('\u{2F5D}', &['\u{72AC}']),
+    ('\u{2F5E}', &['\u{7384}']),
+    ('\u{2F5F}', &['\u{7389}']),
+    ('\u{2F60}', &['\u{74DC}']),
+    ('\u{2F61}', &['\u{74E6}']),
+    ('\u{2F62}', &['\u{7518}']),
+    ('\u{2F63}', &['\u{751F}']),
+    ('\u{2F64}', &['\u{7528}']),
+    ('\u{2F65}', &['\u{7530}']),
+    ('\u{2F66}', &['\u{758B}']),
+    ('\u{2F67}', &['\u{7592}']),
+    ('\u{2F68}', &['\u{7676}']),
+    ('\u{2F69}', &['\u{767D}']),
+    ('\u{2F6A}', &['\u{76AE}']),
+    ('\u{2F6B}', &['\u{76BF}']),
+    ('\u{2F6C}', &['\u{76EE}']),
+    ('\u{2F6D}', &['\u{77DB}']),
+    ('\u{2F6E}', &['\u{77E2}']),
+    ('\u{2F6F}', &['\u{77F3}']),
+    ('\u{2F70}', &['\u{793A}']),
+    ('\u{2F71}', &['\u{79B8}']),
+    ('\u{2F72}', &['\u{79BE}']),
+    ('\u{2F73}', &['\u{7A74}']),
+    ('\u{2F74}', &['\u{7ACB}']),
+    ('\u{2F75}', &['\u{7AF9}']),
+    ('\u{2F76}', &['\u{7C73}']),
+    ('\u{2F77}', &['\u{7CF8}']),
+    ('\u{2F78}', &['\u{7F36}']),
+    ('\u{2F79}', &['\u{7F51}']),
+    ('\u{2F7A}', &['\u{7F8A}']),
+    ('\u{2F7B}', &['\u{7FBD}']),
+    ('\u{2F7C}', &['\u{8001}']),
+    ('\u{2F7D}', &['\u{800C}']),
+    ('\u{2F7E}', &['\u{8012}']),
+    ('\u{2F7F}', &['\u{8033}']),
+    ('\u{2F80}', &['\u{807F}']),
+    ('\u{2F81}', &['\u{8089}']),
+    ('\u{2F82}', &['\u{81E3}']),
+    ('\u{2F83}', &['\u{81EA}']),
+    ('\u{2F84}', &['\u{81F3}']),
+    ('\u{2F85}', &['\u{81FC}']),
+    ('\u{2F86}', &['\u{820C}']),
+    ('\u{2F87}', &['\u{821B}']),
+    ('\u{2F88}', &['\u{821F}']),
+    ('\u{2F89}', &['\u{826E}']),
+    ('\u{2F8A}', &['\u{8272}']),
+    ('\u{2F8B}', &['\u{8278}']),
+    ('\u{2F8C}', &['\u{864D}']),
+    ('\u{2F8D}', &['\u{866B}']),
+    ('\u{2F8E}', &['\u{8840}']),
+    ('\u{2F8F}', &['\u{884C}']),
+    ('\u{2F90}', &['\u{8863}']),
+    ('\u{2F91}', &['\u{897E}']),
+    ('\u{2F92}', &['\u{898B}']),
+    ('\u{2F93}', &['\u{89D2}']),
+    ('\u{2F94}', &['\u{8A00}']),
+    ('\u{2F95}', &['\u{8C37}']),
+    ('\u{2F96}', &['\u{8C46}']),
+    ('\u{2F97}', &['\u{8C55}']),
+    ('\u{2F98}', &['\u{8C78}']),
+    ('\u{2F99}', &['\u{8C9D}']),
+    ('\u{2F9A}', &['\u{8D64}']),
+    ('\u{2F9B}', &['\u{8D70}']),
+    ('\u{2F9C}', &['\u{8DB3}']),
+    ('\u{2F9D}', &['\u{8EAB}']),
+    ('\u{2F9E}', &['\u{8ECA}']),
+    ('\u{2F9F}', &['\u{8F9B}']),
+    ('\u{2FA0}', &['\u{8FB0}']),
+    ('\u{2FA1}', &['\u{8FB5}']),
+    ('\u{2FA2}', &['\u{9091}']),
+    ('\u{2FA3}', &['\u{9149}']),
+    ('\u{2FA4}', &['\u{91C6}']),
+    ('\u{2FA5}', &['\u{91CC}']),
+    ('\u{2FA6}', &['\u{91D1}']),
+    ('\u{2FA7}', &['\u{9577}']),
+    ('\u{2FA8}', &['\u{9580}']),
+    ('\u{2FA9}', &['\u{961C}']),
+    ('\u{2FAA}', &['\u{96B6}']),
+    ('\u{2FAB}', &['\u{96B9}']),
+    ('\u{2FAC}', &['\u{96E8}']),
+    ('\u{2FAD}', &['\u{9751}']),
+    ('\u{2FAE}', &['\u{975E}']),
+    ('\u{2FAF}', &['\u{9762}']),
+    ('\u{2FB0}', &['\u{9769}']),
+    ('\u{2FB1}', &['\u{97CB}']),
+    ('\u{2FB2}', &['\u{97ED}']),
+    ('\u{2FB3}', &['\u{97F3}']),
+    ('\u{2FB4}', &['\u{9801}']),
+    ('\u{2FB5}', &['\u{98A8}']),
+    ('\u{2FB6}', &['\u{98DB}']),
+    ('\u{2FB7}', &['\u{98DF}']),
+    ('\u{2FB8}', &['\u{9996}']),
+    ('\u{2FB9}', &['\u{9999}']),
+    ('\u{2FBA}', &['\u{99AC}']),
+    ('\u{2FBB}', &['\u{9AA8}']),
+    ('\u{2FBC}', &['\u{9AD8}']),
+    ('\u{2FBD}', &['\u{9ADF}']),
+    ('\u{2FBE}', &['\u{9B25}']),
+    ('\u{2FBF}', &['\u{9B2F}']),
+    ('\u{2FC0}', &['\u{9B32}']),
+    ('\u{2FC1}', &['\u{9B3C}']),
+    ('\u{2FC2}', &['\u{9B5A}']),
+    ('\u{2FC3}', &['\u{9CE5}']),
+    ('\u{2FC4}', &['\u{9E75}']),
+    ('\u{2FC5}', &['\u{9E7F}']),
+    ('\u{2FC6}', &['\u{9EA5}']),
+    ('\u{2FC7}', &['\u{9EBB}']),
+    ('\u{2FC8}', &['\u{9EC3}']),
+    ('\u{2FC9}', &['\u{9ECD}']),
+    ('\u{2FCA}', &['\u{9ED1}']),
+    ('\u{2FCB}', &['\u{9EF9}']),
+    ('\u{2FCC}', &['\u{9EFD}']),
+    ('\u{2FCD}', &['\u{9F0E}']),
+    ('\u{2FCE}', &['\u{9F13}']),
+    ('\u{2FCF}', &['\u{9F20}']),
+    ('\u{2FD0}', &['\u{9F3B}']),
+    ('\u{2FD1}', &['\u{9F4A}']),
+    ('\u{2FD2}', &['\u{9F52}']),
+    ('\u{2FD3}', &['\u{9F8D}']),
+    ('\u{2FD4}', &['\u{9F9C}']),
+    ('\u{2FD5}', &['\u{9FA0}']),
+    ('\u{3002}', &['\u{2F3}']),
+    ('\u{3003}', &['\u{27}', '\u{27}']),
+    ('\u{3007}', &['\u{4F}']),
+    ('\u{3008}', &['\u{276C}']),
+    ('\u{3009}', &['\u{276D}']),
+    ('\u{3012}', &['\u{20B8}']),
+    ('\u{3014}', &['\u{28}']),
+    ('\u{3015}', &['\u{29}']),
+    ('\u{301A}', &['\u{27E6}']),
+    ('\u{301B}', &['\u{27E7}']),
+    ('\u{302C}', &['\u{309}']),
+    ('\u{302D}', &['\u{325}']),
+    ('\u{3033}', &['\u{2F}']),
+    ('\u{3036}', &['\u{20B8}']),
+    ('\u{3038}', &['\u{5341}']),
+    ('\u{3039}', &['\u{5344}']),
+    ('\u{303A}', &['\u{5345}']),
+    ('\u{304F}', &['\u{276C}']),
+    ('\u{309A}', &['\u{30A}']),
+    ('\u{309B}', &['\u{FF9E}']),
+    ('\u{309C}', &['\u{FF9F}']),
+    ('\u{30A0}', &['\u{3D}']),
+    ('\u{30A4}', &['\u{4EBB}']),
+    ('\u{30A8}', &['\u{5DE5}']),
+    ('\u{30AB}', &['\u{529B}']),
+    ('\u{30BF}', &['\u{5915}']),
+    ('\u{30C8}', &['\u{535C}']),
+    ('\u{30CB}', &['\u{4E8C}']),
+    ('\u{30CE}', &['\u{2F}']),
+    ('\u{30CF}', &['\u{516B}']),
+    ('\u{30D8}', &['\u{3078}']),
+    ('\u{30ED}', &['\u{53E3}']),
+    ('\u{30FB}', &['\u{B7}']),
+    ('\u{3131}', &['\u{1100}']),
+    ('\u{3132}', &['\u{1100}', '\u{1100}']),
+    ('\u{3133}', &['\u{1100}', '\u{1109}']),
+    ('\u{3134}', &['\u{1102}']),
+    ('\u{3135}', &['\u{1102}', '\u{110C}']),
+    ('\u{3136}', &['\u{1102}', '\u{1112}']),
+    ('\u{3137}', &['\u{1103}']),
+    ('\u{3138}', &['\u{1103}', '\u{1103}']),
+    ('\u{3139}', &['\u{1105}']),
+    ('\u{313A}', &['\u{1105}', '\u{1100}']),
+    ('\u{313B}', &['\u{1105}', '\u{1106}']),
+    ('\u{313C}', &['\u{1105}', '\u{1107}']),
+    ('\u{313D}', &['\u{1105}', '\u{1109}']),
+    ('\u{313E}', &['\u{1105}', '\u{1110}']),
+    ('\u{313F}', &['\u{1105}', '\u{1111}']),
+    ('\u{3140}', &['\u{1105}', '\u{1112}']),
+    ('\u{3141}', &['\u{1106}']),
+    ('\u{3142}', &['\u{1107}']),
+    ('\u{3143}', &['\u{1107}', '\u{1107}']),
+    ('\u{3144}', &['\u{1107}', '\u{1109}']),
+    ('\u{3145}', &['\u{1109}']),
+    ('\u{3146}', &['\u{1109}', '\u{1109}']),
+    ('\u{3147}', &['\u{110B}']),
+    ('\u{3148}', &['\u{110C}']),
+    ('\u{3149}', &['\u{110C}', '\u{110C}']),
+    ('\u{314A}', &['\u{110E}']),
+    ('\u{314B}', &['\u{110F}']),
+    ('\u{314C}', &['\u{1110}']),
+    ('\u{314D}', &['\u{1111}']),
+    ('\u{314E}', &['\u{1112}']),
+    ('\u{314F}', &['\u{1161}']),
+    ('\u{3150}', &['\u{1161}', '\u{4E28}']),
+    ('\u{3151}', &['\u{1163}']),
+    ('\u{3152}', &['\u{1163}', '\u{4E28}']),
+    ('\u{3153}', &['\u{1165}']),
+    ('\u{3154}', &['\u{1165}', '\u{4E28}']),
+    ('\u{3155}', &['\u{1167}']),
+    ('\u{3156}', &['\u{1167}', '\u{4E28}']),
+    ('\u{3157}', &['\u{1169}']),
+    ('\u{3158}', &['\u{1169}', '\u{1161}']),
+    ('\u{3159}', &['\u{1169}', '\u{1161}', '\u{4E28}']),
+    ('\u{315A}', &['\u{1169}', '\u{4E28}']),
+    ('\u{315B}', &['\u{116D}']),
+    ('\u{315C}', &['\u{116E}']),
+    ('\u{315D}', &['\u{116E}', '\u{1165}']),
+    ('\u{315E}', &['\u{116E}', '\u{1165}', '\u{4E28}']),
+    ('\u{315F}', &['\u{116E}', '\u{4E28}']),
+    ('\u{3160}', &['\u{1172}']),
+    ('\u{3161}', &['\u{30FC}']),
+    ('\u{3162}', &['\u{30FC}', '\u{4E28}']),
+    ('\u{3163}', &['\u{4E28}']),
+    ('\u{3164}', &['\u{1160}']),
+    ('\u{3165}', &['\u{1102}', '\u{1102}']),
+    ('\u{3166}', &['\u{1102}', '\u{1103}']),
+    ('\u{3167}', &['\u{1102}', '\u{1109}']),
+    ('\u{3168}', &['\u{1102}', '\u{1140}']),
+    ('\u{3169}', &['\u{1105}', '\u{1100}', '\u{1109}']),
+    ('\u{316A}', &['\u{1105}', '\u{1103}']),
+    ('\u{316B}', &['\u{1105}', '\u{1107}', '\u{1109}']),
+    ('\u{316C}', &['\u{1105}', '\u{1140}']),
+    ('\u{316D}', &['\u{1105}', '\u{1159}']),
+    ('\u{316E}', &['\u{1106}', '\u{1107}']),
+    ('\u{316F}', &['\u{1106}', '\u{1109}']),
+    ('\u{3170}', &['\u{1106}', '\u{1140}']),
+    ('\u{3171}', &['\u{1106}', '\u{110B}']),
+    ('\u{3172}', &['\u{1107}', '\u{1100}']),
+    ('\u{3173}', &['\u{1107}', '\u{1103}']),
+    ('\u{3174}', &['\u{1107}', '\u{1109}', '\u{1100}']),
+    ('\u{3175}', &['\u{1107}', '\u{1109}', '\u{1103}']),
+    ('\u{3176}', &['\u{1107}', '\u{110C}']),
+    ('\u{3177}', &['\u{1107}', '\u{1110}']),
+    ('\u{3178}', &['\u{1107}', '\u{110B}']),
+    ('\u{3179}', &['\u{1107}', '\u{1107}', '\u{110B}']),
+    ('\u{317A}', &['\u{1109}', '\u{1100}']),
+    ('\u{317B}', &['\u{1109}', '\u{1102}']),
+    ('\u{317C}', &['\u{1109}', '\u{1103}']),
+    ('\u{317D}', &['\u{1109}', '\u{1107}']),
+    ('\u{317E}', &['\u{1109}', '\u{110C}']),
+    ('\u{317F}', &['\u{1140}']),
+    ('\u{3180}', &['\u{110B}', '\u{110B}']),
+    ('\u{3181}', &['\u{114C}']),
+    ('\u{3182}', &['\u{110B}', '\u{1109}']),
+    ('\u{3183}', &['\u{110B}', '\u{1140}']),
+    ('\u{3184}', &['\u{1111}', '\u{110B}']),
+    ('\u{3185}', &['\u{1112}', '\u{1112}']),
+    ('\u{3186}', &['\u{1159}']),
+    ('\u{3187}', &['\u{116D}', '\u{1163}']),
+    ('\u{3188}', &['\u{116D}', '\u{1163}', '\u{4E28}']),
+    ('\u{3189}', &['\u{116D}', '\u{4E28}']),
+    ('\u{318A}', &['\u{1172}', '\u{1167}']),
+    ('\u{318B}', &['\u{1172}', '\u{1167}', '\u{4E28}']),
+    ('\u{318C}', &['\u{1172}', '\u{4E28}']),
+    ('\u{318D}', &['\u{119E}']),
+    ('\u{318E}', &['\u{119E}', '\u{4E28}']),
+    ('\u{31D0}', &['\u{30FC}']),
+    ('\u{31D1}', &['\u{4E28}']),
+    ('\u{31D3}', &['\u{2F}']),
+    ('\u{31D4}', &['\u{5C}']),
+    ('\u{31D6}', &['\u{4E5B}']),
+    ('\u{31DA}', &['\u{4E85}']),
+    ('\u{31DB}', &['\u{276C}']),
+    ('\u{31DF}', &['\u{4E5A}']),
+    ('\u{31E0}', &['\u{4E59}']),
+    ('\u{3200}', &['\u{28}', '\u{1100}', '\u{29}']),
+    ('\u{3201}', &['\u{28}', '\u{1102}', '\u{29}']),
+    ('\u{3202}', &['\u{28}', '\u{1103}', '\u{29}']),
+    ('\u{3203}', &['\u{28}', '\u{1105}', '\u{29}']),
+    ('\u{3204}', &['\u{28}', '\u{1106}', '\u{29}']),
+    ('\u{3205}', &['\u{28}', '\u{1107}', '\u{29}']),
+    ('\u{3206}', &['\u{28}', '\u{1109}', '\u{29}']),
+    ('\u{3207}', &['\u{28}', '\u{110B}', '\u{29}']),
+    ('\u{3208}', &['\u{28}', '\u{110C}', '\u{29}']),
+    ('\u{3209}', &['\u{28}', '\u{110E}', '\u{29}']),
+    ('\u{320A}', &['\u{28}', '\u{110F}', '\u{29}']),
+    ('\u{320B}', &['\u{28}', '\u{1110}', '\u{29}']),
+    ('\u{320C}', &['\u{28}', '\u{1111}', '\u{29}']),
+    ('\u{320D}', &['\u{28}', '\u{1112}', '\u{29}']),
+    ('\u{320E}', &['\u{28}', '\u{AC00}', '\u{29}']),
+    ('\u{320F}', &['\u{28}', '\u{B098}', '\u{29}']),
+    ('\u{3210}', &['\u{28}', '\u{B2E4}', '\u{29}']),
+    ('\u{3211}', &['\u{28}', '\u{B77C}', '\u{29}']),
+    ('\u{3212}', &['\u{28}', '\u{B9C8}', '\u{29}']),
+    ('\u{3213}', &['\u{28}', '\u{BC14}', '\u{29}']),
+    ('\u{3214}', &['\u{28}', '\u{C0AC}', '\u{29}']),
+    ('\u{3215}', &['\u{28}', '\u{C544}', '\u{29}']),
+    ('\u{3216}', &['\u{28}', '\u{C790}', '\u{29}']),
+    ('\u{3217}', &['\u{28}', '\u{CC28}', '\u{29}']),
+    ('\u{3218}', &['\u{28}', '\u{CE74}', '\u{29}']),
+    ('\u{3219}', &['\u{28}', '\u{D0C0}', '\u{29}']),
+    ('\u{321A}', &['\u{28}', '\u{D30C}', '\u{29}']),
+    ('\u{321B}', &['\u{28}', '\u{D558}', '\u{29}']),
+    ('\u{321C}', &['\u{28}', '\u{C8FC}', '\u{29}']),
+    ('\u{321D}', &['\u{28}', '\u{C624}', '\u{C804}', '\u{29}']),
+    ('\u{321E}', &['\u{28}', '\u{C624}', '\u{D6C4}', '\u{29}']),
+    ('\u{3220}', &['\u{28}', '\u{30FC}', '\u{29}']),
+    ('\u{3221}', &['\u{28}', '\u{4E8C}', '\u{29}']),
+    ('\u{3222}', &['\u{28}', '\u{4E09}', '\u{29}']),
+    ('\u{3223}', &['\u{28}', '\u{56DB}', '\u{29}']),
+    ('\u{3224}', &['\u{28}', '\u{4E94}', '\u{29}']),
+    ('\u{3225}', &['\u{28}', '\u{516D}', '\u{29}']),
+    ('\u{3226}', &['\u{28}', '\u{4E03}', '\u{29}']),
+    ('\u{3227}', &['\u{28}', '\u{516B}', '\u{29}']),
+    ('\u{3228}', &['\u{28}', '\u{4E5D}', '\u{29}']),
+    ('\u{3229}', &['\u{28}', '\u{5341}', '\u{29}']),
+    ('\u{322A}', &['\u{28}', '\u{6708}', '\u{29}']),
+    ('\u{322B}', &['\u{28}', '\u{706B}', '\u{29}']),
+    ('\u{322C}', &['\u{28}', '\u{6C34}', '\u{29}']),
+    ('\u{322D}', &['\u{28}', '\u{6728}', '\u{29}']),
+    ('\u{322E}', &['\u{28}', '\u{91D1}', '\u{29}']),
+    ('\u{322F}', &['\u{28}', '\u{571F}', '\u{29}']),
+    ('\u{3230}', &['\u{28}', '\u{65E5}', '\u{29}']),
+    ('\u{3231}', &['\u{28}', '\u{682A}', '\u{29}']),
+    ('\u{3232}', &['\u{28}', '\u{6709}', '\u{29}']),
+    ('\u{3233}', &['\u{28}', '\u{793E}', '\u{29}']),
+    ('\u{3234}', &['\u{28}', '\u{540D}', '\u{29}']),
+    ('\u{3235}', &['\u{28}', '\u{7279}', '\u{29}']),
+    ('\u{3236}', &['\u{28}', '\u{8CA1}', '\u{29}']),
+    ('\u{3237}', &['\u{28}', '\u{795D}', '\u{29}']),
+    ('\u{3238}', &['\u{28}', '\u{52B4}', '\u{29}']),
+    ('\u{3239}', &['\u{28}', '\u{4EE3}', '\u{29}']),
+    ('\u{323A}', &['\u{28}', '\u{547C}', '\u{29}']),
+    ('\u{323B}', &['\u{28}', '\u{5B66}', '\u{29}']),
+    ('\u{323C}', &['\u{28}', '\u{76E3}', '\u{29}']),
+    ('\u{323D}', &['\u{28}', '\u{4F01}', '\u{29}']),
+    ('\u{323E}', &['\u{28}', '\u{8CC7}', '\u{29}']),
+    ('\u{323F}', &['\u{28}', '\u{5354}', '\u{29}']),
+    ('\u{3240}', &['\u{28}', '\u{796D}', '\u{29}']),
+    ('\u{3241}', &['\u{28}', '\u{4F11}', '\u{29}']),
+    ('\u{3242}', &['\u{28}', '\u{81EA}', '\u{29}']),
+    ('\u{3243}', &['\u{28}', '\u{81F3}', '\u{29}']),
+    ('\u{32C0}', &['\u{6C}', '\u{6708}']),
+    ('\u{32C1}', &['\u{32}', '\u{6708}']),
+    ('\u{32C2}', &['\u{33}', '\u{6708}']),
+    ('\u{32C3}', &['\u{34}', '\u{6708}']),
+    ('\u{32C4}', &['\u{35}', '\u{6708}']),
+    ('\u{32C5}', &['\u{36}', '\u{6708}']),
+    ('\u{32C6}', &['\u{37}', '\u{6708}']),
+    ('\u{32C7}', &['\u{38}', '\u{6708}']),
+    ('\u{32C8}', &['\u{39}', '\u{6708}']),
+    ('\u{32C9}', &['\u{6C}', '\u{4F}', '\u{6708}']),
+    ('\u{32CA}', &['\u{6C}', '\u{6C}', '\u{6708}']),
+    ('\u{32CB}', &['\u{6C}', '\u{32}', '\u{6708}']),
+    ('\u{3358}', &['\u{4F}', '\u{70B9}']),
+    ('\u{3359}', &['\u{6C}', '\u{70B9}']),
+    ('\u{335A}', &['\u{32}', '\u{70B9}']),
+    ('\u{335B}', &['\u{33}', '\u{70B9}']),
+    ('\u{335C}', &['\u{34}', '\u{70B9}']),
+    ('\u{335D}', &['\u{35}', '\u{70B9}']),
+    ('\u{335E}', &['\u{36}', '\u{70B9}']),
+    ('\u{335F}', &['\u{37}', '\u{70B9}']),
+    ('\u{3360}', &['\u{38}', '\u{70B9}']),
+    ('\u{3361}', &['\u{39}', '\u{70B9}']),
+    ('\u{3362}', &['\u{6C}', '\u{4F}', '\u{70B9}']),
+    ('\u{3363}', &['\u{6C}', '\u{6C}', '\u{70B9}']),
+    ('\u{3364}', &['\u{6C}', '\u{32}', '\u{70B9}']),
+    ('\u{3365}', &['\u{6C}', '\u{33}', '\u{70B9}']),
+    ('\u{3366}', &['\u{6C}', '\u{34}', '\u{70B9}']),
+    ('\u{3367}', &['\u{6C}', '\u{35}', '\u{70B9}']),
+    ('\u{3368}', &['\u{6C}', '\u{36}', '\u{70B9}']),
+    ('\u{3369}', &['\u{6C}', '\u{37}', '\u{70B9}']),
+    ('\u{336A}', &['\u{6C}', '\u{38}', '\u{70B9}']),
+    ('\u{336B}', &['\u{6C}', '\u{39}', '\u{70B9}']),
+    ('\u{336C}', &['\u{32}', '\u{4F}', '\u{70B9}']),
+    ('\u{336D}', &['\u{32}', '\u{6C}', '\u{70B9}']),
+    ('\u{336E}', &['\u{32}', '\u{32}', '\u{70B9}']),
+    ('\u{336F}', &['\u{32}', '\u{33}', '\u{70B9}']),
+    ('\u{3370}', &['\u{32}', '\u{34}', '\u{70B9}']),
+    ('\u{33E0}', &['\u{6C}', '\u{65E5}']),
+    ('\u{33E1}', &['\u{32}', '\u{65E5}']),
+    ('\u{33E2}', &['\u{33}', '\u{65E5}']),
+    ('\u{33E3}', &['\u{34}', '\u{65E5}']),
+    ('\u{33E4}', &['\u{35}', '\u{65E5}']),
+    ('\u{33E5}', &['\u{36}', '\u{65E5}']),
+    ('\u{33E6}', &['\u{37}', '\u{65E5}']),
+    ('\u{33E7}', &['\u{38}', '\u{65E5}']),
+    ('\u{33E8}', &['\u{39}', '\u{65E5}']),
+    ('\u{33E9}', &['\u{6C}', '\u{4F}', '\u{65E5}']),
+    ('\u{33EA}', &['\u{6C}', '\u{6C}', '\u{65E5}']),
+    ('\u{33EB}', &['\u{6C}', '\u{32}', '\u{65E5}']),
+    ('\u{33EC}', &['\u{6C}', '\u{33}', '\u{65E5}']),
+    ('\u{33ED}', &['\u{6C}', '\u{34}', '\u{65E5}']),
+    ('\u{33EE}', &['\u{6C}', '\u{35}', '\u{65E5}']),
+    ('\u{33EF}', &['\u{6C}', '\u{36}', '\u{65E5}']),
+    ('\u{33F0}', &['\u{6C}', '\u{37}', '\u{65E5}']),
+    ('\u{33F1}', &['\u{6C}', '\u{38}', '\u{65E5}']),
+    ('\u{33F2}', &['\u{6C}', '\u{39}', '\u{65E5}']),
+    ('\u{33F3}', &['\u{32}', '\u{4F}', '\u{65E5}']),
+    ('\u{33F4}', &['\u{32}', '\u{6C}', '\u{65E5}']),
+    ('\u{33F5}', &['\u{32}', '\u{32}', '\u{65E5}']),
+    ('\u{33F6}', &['\u{32}', '\u{33}', '\u{65E5}']),
+    ('\u{33F7}', &['\u{32}', '\u{34}', '\u{65E5}']),
+    ('\u{33F8}', &['\u{32}', '\u{35}', '\u{65E5}']),
+    ('\u{33F9}', &['\u{32}', '\u{36}', '\u{65E5}']),
+    ('\u{33FA}', &['\u{32}', '\u{37}', '\u{65E5}']),
+    ('\u{33FB}', &['\u{32}', '\u{38}', '\u{65E5}']),
+    ('\u{33FC}', &['\u{32}', '\u{39}', '\u{65E5}']),
+    ('\u{33FD}', &['\u{33}', '\u{4F}', '\u{65E5}']),
+    ('\u{33FE}', &['\u{33}', '\u{6C}', '\u{65E5}']),
+    ('\u{39B3}', &['\u{363D}']),
+    ('\u{439B}', &['\u{3588}']),
+    ('\u{4420}', &['\u{3B3B}']),
+    ('\u{4E00}', &['\u{30FC}']),
+    ('\u{4E36}', &['\u{5C}']),
+    ('\u{4E3F}', &['\u{2F}']),
+    ('\u{5002}', &['\u{4F75}']),
+    ('\u{503C}', &['\u{5024}']),
+    ('\u{555F}', &['\u{5553}']),
+    ('\u{56D7}', &['\u{53E3}']),
+    ('\u{586B}', &['\u{5861}']),
+    ('\u{58EB}', &['\u{571F}']),
+    ('\u{58FF}', &['\u{58AB}']),
+    ('\u{5B00}', &['\u{5AAF}']),
+    ('\u{5E32}', &['\u{5E21}']),
+    ('\u{5E50}', &['\u{3B3A}']),
+    ('\u{6238}', &['\u{6236}']),
+    ('\u{6409}', &['\u{3A41}']),
+    ('\u{6663}', &['\u{403F}']),
+    ('\u{6669}', &['\u{665A}']),
+    ('\u{66F6}', &['\u{3ADA}']),
+    ('\u{6726}', &['\u{4443}']),
+    ('\u{67FF}', &['\u{676E}']),
+    ('\u{69E9}', &['\u{3BA3}']),
+    ('\u{6A27}', &['\u{699D}']),
+    ('\u{6F59}', &['\u{6E88}']),
+    ('\u{784F}', &['\u{7814}']),
+    ('\u{7D76}', &['\u{7D55}']),
+    ('\u{80A6}', &['\u{670C}']),
+    ('\u{80CA}', &['\u{6710}']),
+    ('\u{80D0}', &['\u{670F}']),
+    ('\u{80F6}', &['\u{3B35}']),
+    ('\u{8101}', &['\u{6713}']),
+    ('\u{8127}', &['\u{6718}']),
+    ('\u{8141}', &['\u{80FC}']),
+    ('\u{81A7}', &['\u{6723}']),
+    ('\u{853F}', &['\u{848D}']),
+    ('\u{8641}', &['\u{8637}']),
+    ('\u{8A1E}', &['\u{46B6}']),
+    ('\u{8A7D}', &['\u{8A2E}']),
+    ('\u{8B8F}', &['\u{8B86}']),
+    ('\u{8C63}', &['\u{8C5C}']),
+    ('\u{8D86}', &['\u{8D7F}']),
+    ('\u{8DFA}', &['\u{8DE5}']),
+    ('\u{8E9B}', &['\u{8E97}']),
+    ('\u{8F27}', &['\u{8EFF}']),
+    ('\u{90DE}', &['\u{90CE}']),
+    ('\u{93AE}', &['\u{93AD}']),
+    ('\u{96B8}', &['\u{96B7}']),
+    ('\u{9E43}', &['\u{9E42}']),
+    ('\u{9ED2}', &['\u{9ED1}']),
+    ('\u{9FC3}', &['\u{4039}']),
+    ('\u{A494}', &['\u{A2CD}']),
+    ('\u{A49C}', &['\u{A0C0}']),
+    ('\u{A49E}', &['\u{A04A}']),
+    ('\u{A4A7}', &['\u{A458}']),
+    ('\u{A4A8}', &['\u{A132}']),
+    ('\u{A4AC}', &['\u{A050}']),
+    ('\u{A4B0}', &['\u{A3C2}']),
+    ('\u{A4BA}', &['\u{A3BF}']),
+    ('\u{A4BE}', &['\u{A2B1}']),
+    ('\u{A4BF}', &['\u{A259}']),
+    ('\u{A4C0}', &['\u{A3AB}']),
+    ('\u{A4C2}', &['\u{A3B5}']),
+    ('\u{A4D0}', &['\u{42}']),
+    ('\u{A4D1}', &['\u{50}']),
+    ('\u{A4D2}', &['\u{64}']),
+    ('\u{A4D3}', &['\u{44}']),
+    ('\u{A4D4}', &['\u{54}']),
+    ('\u{A4D6}', &['\u{47}']),
+    ('\u{A4D7}', &['\u{4B}']),
+    ('\u{A4D9}', &['\u{4A}']),
+    ('\u{A4DA}', &['\u{43}']),
+    ('\u{A4DB}', &['\u{186}']),
+    ('\u{A4DC}', &['\u{5A}']),
+    ('\u{A4DD}', &['\u{46}']),
+    ('\u{A4DE}', &['\u{2132}']),
+    ('\u{A4DF}', &['\u{4D}']),
+    ('\u{A4E0}', &['\u{4E}']),
+    ('\u{A4E1}', &['\u{4C}']),
+    ('\u{A4E2}', &['\u{53}']),
+    ('\u{A4E3}', &['\u{52}']),
+    ('\u{A4E5}', &['\u{245}']),
+    ('\u{A4E6}', &['\u{56}']),
+    ('\u{A4E7}', &['\u{48}']),
+    ('\u{A4EA}', &['\u{57}']),
+    ('\u{A4EB}', &['\u{58}']),
+    ('\u{A4EC}', &['\u{59}']),
+    ('\u{A4ED}', &['\u{1660}']),
+    ('\u{A4EE}', &['\u{41}']),
+    ('\u{A4EF}', &['\u{2C6F}']),
+    ('\u{A4F0}', &['\u{45}']),
+    ('\u{A4F1}', &['\u{18E}']),
+    ('\u{A4F2}', &['\u{6C}']),
+    ('\u{A4F3}', &['\u{4F}']),
+    ('\u{A4F4}', &['\u{55}']),
+    ('\u{A4F5}', &['\u{548}']),
+    ('\u{A4F7}', &['\u{15E1}']),
+    ('\u{A4F8}', &['\u{2E}']),
+    ('\u{A4F9}', &['\u{2C}']),
+    ('\u{A4FA}', &['\u{2E}', '\u{2E}']),
+    ('\u{A4FB}', &['\u{2E}', '\u{2C}']),
+    ('\u{A4FD}', &['\u{3A}']),
+    ('\u{A4FE}', &['\u{2D}', '\u{2E}']),
+    ('\u{A4FF}', &['\u{3D}']),
+    ('\u{A60E}', &['\u{2E}']),
+    ('\u{A644}', &['\u{32}']),
+    ('\u{A645}', &['\u{1A8}']),
+    ('\u{A647}', &['\u{69}']),
+    ('\u{A64D}', &['\u{3C9}']),
+    ('\u{A650}', &['\u{42A}', '\u{6C}']),
+    ('\u{A651}', &['\u{2C9}', '\u{62}', '\u{69}']),
+    ('\u{A668}', &['\u{298}']),
+    ('\u{A66F}', &['\u{20E9}']),
+    ('\u{A67C}', &['\u{306}']),
+    ('\u{A67E}', &['\u{2C7}']),
+    ('\u{A695}', &['\u{68}', '\u{314}']),
+    ('\u{A698}', &['\u{4F}', '\u{4F}']),
+    ('\u{A699}', &['\u{6F}', '\u{6F}']),
+    ('\u{A69A}', &['\u{102A8}']),
+    ('\u{A6A1}', &['\u{418}']),
+    ('\u{A6B0}', &['\u{16B9}']),
+    ('\u{A6B1}', &['\u{2C75}']),
+    ('\u{A6CD}', &['\u{2A1}']),
+    ('\u{A6CE}', &['\u{245}']),
+    ('\u{A6DB}', &['\u{3A0}']),
+    ('\u{A6DF}', &['\u{56}']),
+    ('\u{A6EB}', &['\u{3F}']),
+    ('\u{A6EF}', &['\u{32}']),
+    ('\u{A6F0}', &['\u{302}']),
+    ('\u{A6F1}', &['\u{304}']),
+    ('\u{A6F4}', &['\u{A6F3}', '\u{A6F3}']),
+    ('\u{A714}', &['\u{2EB}']),
+    ('\u{A716}', &['\u{2EA}']),
+    ('\u{A728}', &['\u{54}', '\u{33}']),
+    ('\u{A729}', &['\u{74}', '\u{21D}']),
+    ('\u{A731}', &['\u{73}']),
+    ('\u{A732}', &['\u{41}', '\u{41}']),
+    ('\u{A733}', &['\u{61}', '\u{61}']),
+    ('\u{A734}', &['\u{41}', '\u{4F}']),
+    ('\u{A735}', &['\u{61}', '\u{6F}']),
+    ('\u{A736}', &['\u{41}', '\u{55}']),
+    ('\u{A737}', &['\u{61}', '\u{75}']),
+    ('\u{A738}', &['\u{41}', '\u{56}']),
+    ('\u{A739}', &['\u{61}', '\u{76}']),
+    ('\u{A73A}', &['\u{41}', '\u{56}']),
+    ('\u{A73B}', &['\u{61}', '\u{76}']),
+    ('\u{A73C}', &['\u{41}', '\u{59}']),
+    ('\u{A73D}', &['\u{61}', '\u{79}']),
+    ('\u{A740}', &['\u{4B}', '\u{335}']),
+    ('\u{A74A}', &['\u{4F}', '\u{335}']),
+    ('\u{A74B}', &['\u{6F}', '\u{335}']),
+    ('\u{A74E}', &['\u{4F}', '\u{4F}']),
+    ('\u{A74F}', &['\u{6F}', '\u{6F}']),
+    ('\u{A75A}', &['\u{32}']),
+    ('\u{A761}', &['\u{77}', '\u{326}']),
+    ('\u{A76A}', &['\u{33}']),
+    ('\u{A76B}', &['\u{21D}']),
+    ('\u{A76E}', &['\u{39}']),
+    ('\u{A777}', &['\u{74}', '\u{66}']),
+    ('\u{A778}', &['\u{26}']),
+    ('\u{A77A}', &['\u{A779}']),
+    ('\u{A789}', &['\u{3A}']),
+    ('\u{A78C}', &['\u{27}']),
+    ('\u{A78F}', &['\u{B7}']),
+    ('\u{A795}', &['\u{A727}']),
+    ('\u{A798}', &['\u{46}']),
+    ('\u{A799}', &['\u{66}']),
+    ('\u{A79A}', &['\u{10412}']),
+    ('\u{A79B}', &['\u{1043A}']),
+    ('\u{A79D}', &['\u{29A}']),
+    ('\u{A79E}', &['\u{A4E4}']),
+    ('\u{A79F}', &['\u{75}']),
+    ('\u{A7AB}', &['\u{33}']),
+    ('\u{A7B1}', &['\u{A4D5}']),
+    ('\u{A7B2}', &['\u{4A}']),
+    ('\u{A7B3}', &['\u{58}']),
+    ('\u{A7B4}', &['\u{42}']),
+    ('\u{A7B5}', &['\u{DF}']),
+    ('\u{A7B6}', &['\u{A64C}']),
+    ('\u{A7B7}', &['\u{3C9}']),
+    ('\u{A7F7}', &['\u{30FC}']),
+    ('\u{A830}', &['\u{964}']),
+    ('\u{A960}', &['\u{1103}', '\u{1106}']),
+    ('\u{A961}', &['\u{1103}', '\u{1107}']),
+    ('\u{A962}', &['\u{1103}', '\u{1109}']),
+    ('\u{A963}', &['\u{1103}', '\u{110C}']),
+    ('\u{A964}', &['\u{1105}', '\u{1100}']),
+    ('\u{A965}', &['\u{1105}', '\u{1100}', '\u{1100}']),
+    ('\u{A966}', &['\u{1105}', '\u{1103}']),
+    ('\u{A967}', &['\u{1105}', '\u{1103}', '\u{1103}']),
+    ('\u{A968}', &['\u{1105}', '\u{1106}']),
+    ('\u{A969}', &['\u{1105}', '\u{1107}']),
+    ('\u{A96A}', &['\u{1105}', '\u{1107}', '\u{1107}']),
+    ('\u{A96B}', &['\u{1105}', '\u{1107}', '\u{110B}']),
+    ('\u{A96C}', &['\u{1105}', '\u{1109}']),
+    ('\u{A96D}', &['\u{1105}', '\u{110C}']),
+    ('\u{A96E}', &['\u{1105}', '\u{110F}']),
+    ('\u{A96F}', &['\u{1106}', '\u{1100}']),
+    ('\u{A970}', &['\u{1106}', '\u{1103}']),
+    ('\u{A971}', &['\u{1106}', '\u{1109}']),
+    ('\u{A972}', &['\u{1107}', '\u{1109}', '\u{1110}']),
+    ('\u{A973}', &['\u{1107}', '\u{110F}']),
+    ('\u{A974}', &['\u{1107}', '\u{1112}']),
+    ('\u{A975}', &['\u{1109}', '\u{1109}', '\u{1107}']),
+    ('\u{A976}', &['\u{110B}', '\u{1105}']),
+    ('\u{A977}', &['\u{110B}', '\u{1112}']),
+    ('\u{A978}', &['\u{110C}', '\u{110C}', '\u{1112}']),
+    ('\u{A979}', &['\u{1110}', '\u{1110}']),
+    ('\u{A97A}', &['\u{1111}', '\u{1112}']),
+    ('\u{A97B}', &['\u{1112}', '\u{1109}']),
+    ('\u{A97C}', &['\u{1159}', '\u{1159}']),
+    ('\u{A992}', &['\u{2C3F}']),
+    ('\u{A9A3}', &['\u{A99D}']),
+    ('\u{A9C6}', &['\u{A9D0}']),
+    ('\u{A9CF}', &['\u{662}']),
+    ('\u{AA53}', &['\u{AA01}']),
+    ('\u{AA56}', &['\u{AA23}']),
+    ('\u{AB32}', &['\u{65}']),
+    ('\u{AB35}', &['\u{66}']),
+    ('\u{AB3D}', &['\u{6F}']),
+    ('\u{AB3E}', &['\u{6F}', '\u{338}']),
+    ('\u{AB3F}', &['\u{254}', '\u{338}']),
+    ('\u{AB41}', &['\u{1DD}', '\u{6F}', '\u{338}']),
+    ('\u{AB42}', &['\u{1DD}', '\u{6F}', '\u{335}']),
+    ('\u{AB47}', &['\u{72}']),
+    ('\u{AB48}', &['\u{72}']),
+    ('\u{AB4D}', &['\u{283}']),
+    ('\u{AB4E}', &['\u{75}']),
+    ('\u{AB52}', &['\u{75}']),
+    ('\u{AB53}', &['\u{3C7}']),
+    ('\u{AB55}', &['\u{3C7}']),
+    ('\u{AB5A}', &['\u{79}']),
+    ('\u{AB60}', &['\u{459}']),
+    ('\u{AB62}', &['\u{254}', '\u{65}']),
+    ('\u{AB63}', &['\u{75}', '\u{6F}']),
+    ('\u{AB70}', &['\u{1D05}']),
+    ('\u{AB71}', &['\u{280}']),
+    ('\u{AB72}', &['\u{1D1B}']),
+    ('\u{AB74}', &['\u{6F}', '\u{31B}']),
+    ('\u{AB75}', &['\u{69}']),
+    ('\u{AB7A}', &['\u{1D00}']),
+    ('\u{AB7B}', &['\u{1D0A}']),
+    ('\u{AB7C}', &['\u{1D07}']),
+    ('\u{AB7E}', &['\u{242}']),
+    ('\u{AB80}', &['\u{2C76}']),
+    ('\u{AB81}', &['\u{72}']),
+    ('\u{AB83}', &['\u{77}']),
+    ('\u{AB87}', &['\u{28D}']),
+    ('\u{AB8B}', &['\u{29C}']),
+    ('\u{AB8E}', &['\u{6F}', '\u{335}']),
+    ('\u{AB90}', &['\u{262}']),
+    ('\u{AB93}', &['\u{7A}']),
+    ('\u{AB9B}', &['\u{A793}']),
+    ('\u{AB9C}', &['\u{75}', '\u{335}']),
+    ('\u{AB9F}', &['\u{185}']),
+    ('\u{ABA2}', &['\u{280}']),
+    ('\u{ABA9}', &['\u{76}']),
+    ('\u{ABAA}', &['\u{73}']),
+    ('\u{ABAE}', &['\u{29F}']),
+    ('\u{ABAF}', &['\u{63}']),
+    ('\u{ABB2}', &['\u{1D18}']),
+    ('\u{ABB6}', &['\u{138}']),
+    ('\u{ABBB}', &['\u{6F}', '\u{335}']),
+    ('\u{D7B0}', &['\u{1169}', '\u{1167}']),
+    ('\u{D7B1}', &['\u{1169}', '\u{1169}', '\u{4E28}']),
+    ('\u{D7B2}', &['\u{116D}', '\u{1161}']),
+    ('\u{D7B3}', &['\u{116D}', '\u{1161}', '\u{4E28}']),
+    ('\u{D7B4}', &['\u{116D}', '\u{1165}']),
+    ('\u{D7B5}', &['\u{116E}', '\u{1167}']),
+    ('\u{D7B6}', &['\u{116E}', '\u{4E28}', '\u{4E28}']),
+    ('\u{D7B7}', &['\u{1172}', '\u{1161}', '\u{4E28}']),
+    ('\u{D7B8}', &['\u{1172}', '\u{1169}']),
+    ('\u{D7B9}', &['\u{30FC}', '\u{1161}']),
+    ('\u{D7BA}', &['\u{30FC}', '\u{1165}']),
+    ('\u{D7BB}', &['\u{30FC}', '\u{1165}', '\u{4E28}']),
+    ('\u{D7BC}', &['\u{30FC}', '\u{1169}']),
+    ('\u{D7BD}', &['\u{4E28}', '\u{1163}', '\u{1169}']),
+    ('\u{D7BE}', &['\u{4E28}', '\u{1163}', '\u{4E28}']),
+    ('\u{D7BF}', &['\u{4E28}', '\u{1167}']),
+    ('\u{D7C0}', &['\u{4E28}', '\u{1167}', '\u{4E28}']),
+    ('\u{D7C1}', &['\u{4E28}', '\u{1169}', '\u{4E28}']),
+    ('\u{D7C2}', &['\u{4E28}', '\u{116D}']),
+    ('\u{D7C3}', &['\u{4E28}', '\u{1172}']),
+    ('\u{D7C4}', &['\u{4E28}', '\u{4E28}']),
+    ('\u{D7C5}', &['\u{119E}', '\u{1161}']),
+    ('\u{D7C6}', &['\u{119E}', '\u{1165}', '\u{4E28}']),
+    ('\u{D7CB}', &['\u{1102}', '\u{1105}']),
+    ('\u{D7CC}', &['\u{1102}', '\u{110E}']),
+    ('\u{D7CD}', &['\u{1103}', '\u{1103}']),
+    ('\u{D7CE}', &['\u{1103}', '\u{1103}', '\u{1107}']),
+    ('\u{D7CF}', &['\u{1103}', '\u{1107}']),
+    ('\u{D7D0}', &['\u{1103}', '\u{1109}']),
+    ('\u{D7D1}', &['\u{1103}', '\u{1109}', '\u{1100}']),
+    ('\u{D7D2}', &['\u{1103}', '\u{110C}']),
+    ('\u{D7D3}', &['\u{1103}', '\u{110E}']),
+    ('\u{D7D4}', &['\u{1103}', '\u{1110}']),
+    ('\u{D7D5}', &['\u{1105}', '\u{1100}', '\u{1100}']),
+    ('\u{D7D6}', &['\u{1105}', '\u{1100}', '\u{1112}']),
+    ('\u{D7D7}', &['\u{1105}', '\u{1105}', '\u{110F}']),
+    ('\u{D7D8}', &['\u{1105}', '\u{1106}', '\u{1112}']),
+    ('\u{D7D9}', &['\u{1105}', '\u{1107}', '\u{1103}']),
+    ('\u{D7DA}', &['\u{1105}', '\u{1107}', '\u{1111}']),
+    ('\u{D7DB}', &['\u{1105}', '\u{114C}']),
+    ('\u{D7DC}', &['\u{1105}', '\u{1159}', '\u{1112}']),
+    ('\u{D7DD}', &['\u{1105}', '\u{110B}']),
+    ('\u{D7DE}', &['\u{1106}', '\u{1102}']),
+    ('\u{D7DF}', &['\u{1106}', '\u{1102}', '\u{1102}']),
+    ('\u{D7E0}', &['\u{1106}', '\u{1106}']),
+    ('\u{D7E1}', &['\u{1106}', '\u{1107}', '\u{1109}']),
+    ('\u{D7E2}', &['\u{1106}', '\u{110C}']),
+    ('\u{D7E3}', &['\u{1107}', '\u{1103}']),
+    ('\u{D7E4}', &['\u{1107}', '\u{1105}', '\u{1111}']),
+    ('\u{D7E5}', &['\u{1107}', '\u{1106}']),
+    ('\u{D7E6}', &['\u{1107}', '\u{1107}']),
+    ('\u{D7E7}', &['\u{1107}', '\u{1109}', '\u{1103}']),
+    ('\u{D7E8}', &['\u{1107}', '\u{110C}']),
+    ('\u{D7E9}', &['\u{1107}', '\u{110E}']),
+    ('\u{D7EA}', &['\u{1109}', '\u{1106}']),
+    ('\u{D7EB}', &['\u{1109}', '\u{1107}', '\u{110B}']),
+    ('\u{D7EC}', &['\u{1109}', '\u{1109}', '\u{1100}']),
+    ('\u{D7ED}', &['\u{1109}', '\u{1109}', '\u{1103}']),
+    ('\u{D7EE}', &['\u{1109}', '\u{1140}']),
+    ('\u{D7EF}', &['\u{1109}', '\u{110C}']),
+    ('\u{D7F0}', &['\u{1109}', '\u{110E}']),
+    ('\u{D7F1}', &['\u{1109}', '\u{1110}']),
+    ('\u{D7F2}', &['\u{1105}', '\u{1112}']),
+    ('\u{D7F3}', &['\u{1140}', '\u{1107}']),
+    ('\u{D7F4}', &['\u{1140}', '\u{1107}', '\u{110B}']),
+    ('\u{D7F5}', &['\u{114C}', '\u{1106}']),
+    ('\u{D7F6}', &['\u{114C}', '\u{1112}']),
+    ('\u{D7F7}', &['\u{110C}', '\u{1107}']),
+    ('\u{D7F8}', &['\u{110C}', '\u{1107}', '\u{1107}']),
+    ('\u{D7F9}', &['\u{110C}', '\u{110C}']),
+    ('\u{D7FA}', &['\u{1111}', '\u{1109}']),
+    ('\u{D7FB}', &['\u{1111}', '\u{1110}']),
+    ('\u{F900}', &['\u{8C48}']),
+    ('\u{F901}', &['\u{66F4}']),
+    ('\u{F902}', &['\u{8ECA}']),
+    ('\u{F903}', &['\u{8CC8}']),
+    ('\u{F904}', &['\u{6ED1}']),
+    ('\u{F905}', &['\u{4E32}']),
+    ('\u{F906}', &['\u{53E5}']),
+    ('\u{F907}', &['\u{9F9C}']),
+    ('\u{F908}', &['\u{9F9C}']),
+    ('\u{F909}', &['\u{5951}']),
+    ('\u{F90A}', &['\u{91D1}']),
+    ('\u{F90B}', &['\u{5587}']),
+    ('\u{F90C}', &['\u{5948}']),
+    ('\u{F90D}', &['\u{61F6}']),
+    ('\u{F90E}', &['\u{7669}']),
+    ('\u{F90F}', &['\u{7F85}']),
+    ('\u{F910}', &['\u{863F}']),
+    ('\u{F911}', &['\u{87BA}']),
+    ('\u{F912}', &['\u{88F8}']),
+    ('\u{F913}', &['\u{908F}']),
+    ('\u{F914}', &['\u{6A02}']),
+    ('\u{F915}', &['\u{6D1B}']),
+    ('\u{F916}', &['\u{70D9}']),
+    ('\u{F917}', &['\u{73DE}']),
+    ('\u{F918}', &['\u{843D}']),
+    ('\u{F919}', &['\u{916A}']),
+    ('\u{F91A}', &['\u{99F1}']),
+    ('\u{F91B}', &['\u{4E82}']),
+    ('\u{F91C}', &['\u{5375}']),
+    ('\u{F91D}', &['\u{6B04}']),
+    ('\u{F91E}', &['\u{721B}']),
+    ('\u{F91F}', &['\u{862D}']),
+    ('\u{F920}', &['\u{9E1E}']),
+    ('\u{F921}', &['\u{5D50}']),
+    ('\u{F922}', &['\u{6FEB}']),
+    ('\u{F923}', &['\u{85CD}']),
+    ('\u{F924}', &['\u{8964}']),
+    ('\u{F925}', &['\u{62C9}']),
+    ('\u{F926}', &['\u{81D8}']),
+    ('\u{F927}', &['\u{881F}']),
+    ('\u{F928}', &['\u{5ECA}']),
+    ('\u{F929}', &['\u{6717}']),
+    ('\u{F92A}', &['\u{6D6A}']),
+    ('\u{F92B}', &['\u{72FC}']),
+    ('\u{F92C}', &['\u{90CE}']),
+    ('\u{F92D}', &['\u{4F86}']),
+    ('\u{F92E}', &['\u{51B7}']),
+    ('\u{F92F}', &['\u{52DE}']),
+    ('\u{F930}', &['\u{64C4}']),
+    ('\u{F931}', &['\u{6AD3}']),
+    ('\u{F932}', &['\u{7210}']),
+    ('\u{F933}', &['\u{76E7}']),
+    ('\u{F934}', &['\u{8001}']),
+    ('\u{F935}', &['\u{8606}']),
+    ('\u{F936}', &['\u{865C}']),
+    ('\u{F937}', &['\u{8DEF}']),
+    ('\u{F938}', &['\u{9732}']),
+    ('\u{F939}', &['\u{9B6F}']),
+    ('\u{F93A}', &['\u{9DFA}']),
+    ('\u{F93B}', &['\u{788C}']),
+    ('\u{F93C}', &['\u{797F}']),
+    ('\u{F93D}', &['\u{7DA0}']),
+    ('\u{F93E}', &['\u{83C9}']),
+    ('\u{F93F}', &['\u{9304}']),
+    ('\u{F940}', &['\u{9E7F}']),
+    ('\u{F941}', &['\u{8AD6}']),
+    ('\u{F942}', &['\u{58DF}']),
+    ('\u{F943}', &['\u{5F04}']),
+    ('\u{F944}', &['\u{7C60}']),
+    ('\u{F945}', &['\u{807E}']),
+    ('\u{F946}', &['\u{7262}']),
+    ('\u{F947}', &['\u{78CA}']),
+    ('\u{F948}', &['\u{8CC2}']),
+    ('\u{F949}', &['\u{96F7}']),
+    ('\u{F94A}', &['\u{58D8}']),
+    ('\u{F94B}', &['\u{5C62}']),
+    ('\u{F94C}', &['\u{6A13}']),
+    ('\u{F94D}', &['\u{6DDA}']),
+    ('\u{F94E}', &['\u{6F0F}']),
+    ('\u{F94F}', &['\u{7D2F}']),
+    ('\u{F950}', &['\u{7E37}']),
+    ('\u{F951}', &['\u{964B}']),
+    ('\u{F952}', &['\u{52D2}']),
+    ('\u{F953}', &['\u{808B}']),
+    ('\u{F954}', &['\u{51DC}']),
+    ('\u{F955}', &['\u{51CC}']),
+    ('\u{F956}', &['\u{7A1C}']),
+    ('\u{F957}', &['\u{7DBE}']),
+    ('\u{F958}', &['\u{83F1}']),
+    ('\u{F959}', &['\u{9675}']),
+    ('\u{F95A}', &['\u{8B80}']),
+    ('\u{F95B}', &['\u{62CF}']),
+    ('\u{F95C}', &['\u{6A02}']),
+    ('\u{F95D}', &['\u{8AFE}']),
+    ('\u{F95E}', &['\u{4E39}']),
+    ('\u{F95F}', &['\u{5BE7}']),
+    ('\u{F960}', &['\u{6012}']),
+    ('\u{F961}', &['\u{7387}']),
+    ('\u{F962}', &['\u{7570}']),
+    ('\u{F963}', &['\u{5317}']),
+    ('\u{F964}', &['\u{78FB}']),
+    ('\u{F965}', &['\u{4FBF}']),
+    ('\u{F966}', &['\u{5FA9}']),
+    ('\u{F967}', &['\u{4E0D}']),
+    ('\u{F968}', &['\u{6CCC}']),
+    ('\u{F969}', &['\u{6578}']),
+    ('\u{F96A}', &['\u{7D22}']),
+    ('\u{F96B}', &['\u{53C3}']),
+    ('\u{F96C}', &['\u{585E}']),
+    ('\u{F96D}', &['\u{7701}']),
+    ('\u{F96E}', &['\u{8449}']),
+    ('\u{F96F}', &['\u{8AAA}']),
+    ('\u{F970}', &['\u{6BBA}']),
+    ('\u{F971}', &['\u{8FB0}']),
+    ('\u{F972}', &['\u{6C88}']),
+    ('\u{F973}', &['\u{62FE}']),
+    ('\u{F974}', &['\u{82E5}']),
+    ('\u{F975}', &['\u{63A0}']),
+    ('\u{F976}', &['\u{7565}']),
+    ('\u{F977}', &['\u{4EAE}']),
+    ('\u{F978}', &['\u{5169}']),
+    ('\u{F979}', &['\u{51C9}']),
+    ('\u{F97A}', &['\u{6881}']),
+    ('\u{F97B}', &['\u{7CE7}']),
+    ('\u{F97C}', &['\u{826F}']),
+    ('\u{F97D}', &['\u{8AD2}']),
+    ('\u{F97E}', &['\u{91CF}']),
+    ('\u{F97F}', &['\u{52F5}']),
+    ('\u{F980}', &['\u{5442}']),
+    ('\u{F981}', &['\u{5973}']),
+    ('\u{F982}', &['\u{5EEC}']),
+    ('\u{F983}', &['\u{65C5}']),
+    ('\u{F984}', &['\u{6FFE}']),
+    ('\u{F985}', &['\u{792A}']),
+    ('\u{F986}', &['\u{95AD}']),
+    ('\u{F987}', &['\u{9A6A}']),
+    ('\u{F988}', &['\u{9E97}']),
+    ('\u{F989}', &['\u{9ECE}']),
+    ('\u{F98A}', &['\u{529B}']),
+    ('\u{F98B}', &['\u{66C6}']),
+    ('\u{F98C}', &['\u{6B77}']),
+    ('\u{F98D}', &['\u{8F62}']),
+    ('\u{F98E}', &['\u{5E74}']),
+    ('\u{F98F}', &['\u{6190}']),
+    ('\u{F990}', &['\u{6200}']),
+    ('\u{F991}', &['\u{649A}']),
+    ('\u{F992}', &['\u{6F23}']),
+    ('\u{F993}', &['\u{7149}']),
+    ('\u{F994}', &['\u{7489}']),
+    ('\u{F995}', &['\u{79CA}']),
+    ('\u{F996}', &['\u{7DF4}']),
+    ('\u{F997}', &['\u{806F}']),
+    ('\u{F998}', &['\u{8F26}']),
+    ('\u{F999}', &['\u{84EE}']),
+    ('\u{F99A}', &['\u{9023}']),
+    ('\u{F99B}', &['\u{934A}']),
+    ('\u{F99C}', &['\u{5217}']),
+    ('\u{F99D}', &['\u{52A3}']),
+    ('\u{F99E}', &['\u{54BD}']),
+    ('\u{F99F}', &['\u{70C8}']),
+    ('\u{F9A0}', &['\u{88C2}']),
+    ('\u{F9A1}', &['\u{8AAA}']),
+    ('\u{F9A2}', &['\u{5EC9}']),
+    ('\u{F9A3}', &['\u{5FF5}']),
+    ('\u{F9A4}', &['\u{637B}']),
+    ('\u{F9A5}', &['\u{6BAE}']),
+    ('\u{F9A6}', &['\u{7C3E}']),
+    ('\u{F9A7}', &['\u{7375}']),
+    ('\u{F9A8}', &['\u{4EE4}']),
+    ('\u{F9A9}', &['\u{56F9}']),
+    ('\u{F9AA}', &['\u{5BE7}']),
+    ('\u{F9AB}', &['\u{5DBA}']),
+    ('\u{F9AC}', &['\u{601C}']),
+    ('\u{F9AD}', &['\u{73B2}']),
+    ('\u{F9AE}', &['\u{7469}']),
+    ('\u{F9AF}', &['\u{7F9A}']),
+    ('\u{F9B0}', &['\u{8046}']),
+    ('\u{F9B1}', &['\u{9234}']),
+    ('\u{F9B2}', &['\u{96F6}']),
+    ('\u{F9B3}', &['\u{9748}']),
+    ('\u{F9B4}', &['\u{9818}']),
+    ('\u{F9B5}', &['\u{4F8B}']),
+    ('\u{F9B6}', &['\u{79AE}']),
+    ('\u{F9B7}', &['\u{91B4}']),
+    ('\u{F9B8}', &['\u{96B7}']),
+    ('\u{F9B9}', &['\u{60E1}']),
+    ('\u{F9BA}', &['\u{4E86}']),
+    ('\u{F9BB}', &['\u{50DA}']),
+    ('\u{F9BC}', &['\u{5BEE}']),
+    ('\u{F9BD}', &['\u{5C3F}']),
+    ('\u{F9BE}', &['\u{6599}']),
+    ('\u{F9BF}', &['\u{6A02}']),
+    ('\u{F9C0}', &['\u{71CE}']),
+    ('\u{F9C1}', &['\u{7642}']),
+    ('\u{F9C2}', &['\u{84FC}']),
+    ('\u{F9C3}', &['\u{907C}']),
+    ('\u{F9C4}', &['\u{9F8D}']),
+    ('\u{F9C5}', &['\u{6688}']),
+    ('\u{F9C6}', &['\u{962E}']),
+    ('\u{F9C7}', &['\u{5289}']),
+    ('\u{F9C8}', &['\u{677B}']),
+    ('\u{F9C9}', &['\u{67F3}']),
+    ('\u{F9CA}', &['\u{6D41}']),
+    ('\u{F9CB}', &['\u{6E9C}']),
+    ('\u{F9CC}', &['\u{7409}']),
+    ('\u{F9CD}', &['\u{7559}']),
+    ('\u{F9CE}', &['\u{786B}']),
+    ('\u{F9CF}', &['\u{7D10}']),
+    ('\u{F9D0}', &['\u{985E}']),
+    ('\u{F9D1}', &['\u{516D}']),
+    ('\u{F9D2}', &['\u{622E}']),
+    ('\u{F9D3}', &['\u{9678}']),
+    ('\u{F9D4}', &['\u{502B}']),
+    ('\u{F9D5}', &['\u{5D19}']),
+    ('\u{F9D6}', &['\u{6DEA}']),
+    ('\u{F9D7}', &['\u{8F2A}']),
+    ('\u{F9D8}', &['\u{5F8B}']),
+    ('\u{F9D9}', &['\u{6144}']),
+    ('\u{F9DA}', &['\u{6817}']),
+    ('\u{F9DB}', &['\u{7387}']),
+    ('\u{F9DC}', &['\u{9686}']),
+    ('\u{F9DD}', &['\u{5229}']),
+    ('\u{F9DE}', &['\u{540F}']),
+    ('\u{F9DF}', &['\u{5C65}']),
+    ('\u{F9E0}', &['\u{6613}']),
+    ('\u{F9E1}', &['\u{674E}']),
+    ('\u{F9E2}', &['\u{68A8}']),
+    ('\u{F9E3}', &['\u{6CE5}']),
+    ('\u{F9E4}', &['\u{7406}']),
+    ('\u{F9E5}', &['\u{75E2}']),
+    ('\u{F9E6}', &['\u{7F79}']),
+    ('\u{F9E7}', &['\u{88CF}']),
+    ('\u{F9E8}', &['\u{88E1}']),
+    ('\u{F9E9}', &['\u{91CC}']),
+    ('\u{F9EA}', &['\u{96E2}']),
+    ('\u{F9EB}', &['\u{533F}']),
+    ('\u{F9EC}', &['\u{6EBA}']),
+    ('\u{F9ED}', &['\u{541D}']),
+    ('\u{F9EE}', &['\u{71D0}']),
+    ('\u{F9EF}', &['\u{7498}']),
+    ('\u{F9F0}', &['\u{85FA}']),
+    ('\u{F9F1}', &['\u{96A3}']),
+    ('\u{F9F2}', &['\u{9C57}']),
+    ('\u{F9F3}', &['\u{9E9F}']),
+    ('\u{F9F4}', &['\u{6797}']),
+    ('\u{F9F5}', &['\u{6DCB}']),
+    ('\u{F9F6}', &['\u{81E8}']),
+    ('\u{F9F7}', &['\u{7ACB}']),
+    ('\u{F9F8}', &['\u{7B20}']),
+    ('\u{F9F9}', &['\u{7C92}']),
+    ('\u{F9FA}', &['\u{72C0}']),
+    ('\u{F9FB}', &['\u{7099}']),
+    ('\u{F9FC}', &['\u{8B58}']),
+    ('\u{F9FD}', &['\u{4EC0}']),
+    ('\u{F9FE}', &['\u{8336}']),
+    ('\u{F9FF}', &['\u{523A}']),
+    ('\u{FA00}', &['\u{5207}']),
+    ('\u{FA01}', &['\u{5EA6}']),
+    ('\u{FA02}', &['\u{62D3}']),
+    ('\u{FA03}', &['\u{7CD6}']),
+    ('\u{FA04}', &['\u{5B85}']),
+    ('\u{FA05}', &['\u{6D1E}']),
+    ('\u{FA06}', &['\u{66B4}']),
+    ('\u{FA07}', &['\u{8F3B}']),
+    ('\u{FA08}', &['\u{884C}']),
+    ('\u{FA09}', &['\u{964D}']),
+    ('\u{FA0A}', &['\u{898B}']),
+    ('\u{FA0B}', &['\u{5ED3}']),
+    ('\u{FA0C}', &['\u{5140}']),
+    ('\u{FA0D}', &['\u{55C0}']),
+    ('\u{FA10}', &['\u{585A}']),
+    ('\u{FA12}', &['\u{6674}']),
+    ('\u{FA15}', &['\u{51DE}']),
+    ('\u{FA16}', &['\u{732A}']),
+    ('\u{FA17}', &['\u{76CA}']),
+    ('\u{FA18}', &['\u{793C}']),
+    ('\u{FA19}', &['\u{795E}']),
+    ('\u{FA1A}', &['\u{7965}']),
+    ('\u{FA1B}', &['\u{798F}']),
+    ('\u{FA1C}', &['\u{9756}']),
+    ('\u{FA1D}', &['\u{7CBE}']),
+    ('\u{FA1E}', &['\u{7FBD}']),
+    ('\u{FA20}', &['\u{8612}']),
+    ('\u{FA22}', &['\u{8AF8}']),
+    ('\u{FA25}', &['\u{9038}']),
+    ('\u{FA26}', &['\u{90FD}']),
+    ('\u{FA2A}', &['\u{98EF}']),
+    ('\u{FA2B}', &['\u{98FC}']),
+    ('\u{FA2C}', &['\u{9928}']),
+    ('\u{FA2D}', &['\u{9DB4}']),
+    ('\u{FA2E}', &['\u{90CE}']),
+    ('\u{FA2F}', &['\u{96B7}']),
+    ('\u{FA30}', &['\u{4FAE}']),
+    ('\u{FA31}', &['\u{50E7}']),
+    ('\u{FA32}', &['\u{514D}']),
+    ('\u{FA33}', &['\u{52C9}']),
+    ('\u{FA34}', &['\u{52E4}']),
+    ('\u{FA35}', &['\u{5351}']),
+    ('\u{FA36}', &['\u{559D}']),
+    ('\u{FA37}', &['\u{5606}']),
+    ('\u{FA38}', &['\u{5668}']),
+    ('\u{FA39}', &['\u{5840}']),
+    ('\u{FA3A}', &['\u{58A8}']),
+    ('\u{FA3B}', &['\u{5C64}']),
+    ('\u{FA3C}', &['\u{5C6E}']),
+    ('\u{FA3D}', &['\u{6094}']),
+    ('\u{FA3E}', &['\u{6168}']),
+    ('\u{FA3F}', &['\u{618E}']),
+    ('\u{FA40}', &['\u{61F2}']),
+    ('\u{FA41}', &['\u{654F}']),
+    ('\u{FA42}', &['\u{65E2}']),
+    ('\u{FA43}', &['\u{6691}']),
+    ('\u{FA44}', &['\u{6885}']),
+    ('\u{FA45}', &['\u{6D77}']),
+    ('\u{FA46}', &['\u{6E1A}']),
+    ('\u{FA47}', &['\u{6F22}']),
+    ('\u{FA48}', &['\u{716E}']),
+    ('\u{FA49}', &['\u{722B}']),
+    ('\u{FA4A}', &['\u{7422}']),
+    ('\u{FA4B}', &['\u{7891}']),
+    ('\u{FA4C}', &['\u{793E}']),
+    ('\u{FA4D}', &['\u{7949}']),
+    ('\u{FA4E}', &['\u{7948}']),
+    ('\u{FA4F}', &['\u{7950}']),
+    ('\u{FA50}', &['\u{7956}']),
+    ('\u{FA51}', &['\u{795D}']),
+    ('\u{FA52}', &['\u{798D}']),
+    ('\u{FA53}', &['\u{798E}']),
+    ('\u{FA54}', &['\u{7A40}']),
+    ('\u{FA55}', &['\u{7A81}']),
+    ('\u{FA56}', &['\u{7BC0}']),
+    ('\u{FA57}', &['\u{7DF4}']),
+    ('\u{FA58}', &['\u{7E09}']),
+    ('\u{FA59}', &['\u{7E41}']),
+    ('\u{FA5A}', &['\u{7F72}']),
+    ('\u{FA5B}', &['\u{8005}']),
+    ('\u{FA5C}', &['\u{81ED}']),
+    ('\u{FA5D}', &['\u{8279}']),
+    ('\u{FA5E}', &['\u{8279}']),
+    ('\u{FA5F}', &['\u{8457}']),
+    ('\u{FA60}', &['\u{8910}']),
+    ('\u{FA61}', &['\u{8996}']),
+    ('\u{FA62}', &['\u{8B01}']),
+    ('\u{FA63}', &['\u{8B39}']),
+    ('\u{FA64}', &['\u{8CD3}']),
+    ('\u{FA65}', &['\u{8D08}']),
+    ('\u{FA66}', &['\u{8FB6}']),
+    ('\u{FA67}', &['\u{9038}']),
+    ('\u{FA68}', &['\u{96E3}']),
+    ('\u{FA69}', &['\u{97FF}']),
+    ('\u{FA6A}', &['\u{983B}']),
+    ('\u{FA6B}', &['\u{6075}']),
+    ('\u{FA6C}', &['\u{242EE}']),
+    ('\u{FA6D}', &['\u{8218}']),
+    ('\u{FA70}', &['\u{4E26}']),
+    ('\u{FA71}', &['\u{51B5}']),
+    ('\u{FA72}', &['\u{5168}']),
+    ('\u{FA73}', &['\u{4F80}']),
+    ('\u{FA74}', &['\u{5145}']),
+    ('\u{FA75}', &['\u{5180}']),
+    ('\u{FA76}', &['\u{52C7}']),
+    ('\u{FA77}', &['\u{52FA}']),
+    ('\u{FA78}', &['\u{559D}']),
+    ('\u{FA79}', &['\u{5555}']),
+    ('\u{FA7A}', &['\u{5599}']),
+    ('\u{FA7B}', &['\u{55E2}']),
+    ('\u{FA7C}', &['\u{585A}']),
+    ('\u{FA7D}', &['\u{58B3}']),
+    ('\u{FA7E}', &['\u{5944}']),
+    ('\u{FA7F}', &['\u{5954}']),
+    ('\u{FA80}', &['\u{5A62}']),
+    ('\u{FA81}', &['\u{5B28}']),
+    ('\u{FA82}', &['\u{5ED2}']),
+    ('\u{FA83}', &['\u{5ED9}']),
+    ('\u{FA84}', &['\u{5F69}']),
+    ('\u{FA85}', &['\u{5FAD}']),
+    ('\u{FA86}', &['\u{60D8}']),
+    ('\u{FA87}', &['\u{614E}']),
+    ('\u{FA88}', &['\u{6108}']),
+    ('\u{FA89}', &['\u{618E}']),
+    ('\u{FA8A}', &['\u{6160}']),
+    ('\u{FA8B}', &['\u{61F2}']),
+    ('\u{FA8C}', &['\u{6234}']),
+    ('\u{FA8D}', &['\u{63C4}']),
+    ('\u{FA8E}', &['\u{641C}']),
+    ('\u{FA8F}', &['\u{6452}']),
+    ('\u{FA90}', &['\u{6556}']),
+    ('\u{FA91}', &['\u{6674}']),
+    ('\u{FA92}', &['\u{6717}']),
+    ('\u{FA93}', &['\u{671B}']),
+    ('\u{FA94}', &['\u{6756}']),
+    ('\u{FA95}', &['\u{6B79}']),
+    ('\u{FA96}', &['\u{6BBA}']),
+    ('\u{FA97}', &['\u{6D41}']),
+    ('\u{FA98}', &['\u{6EDB}']),
+    ('\u{FA99}', &['\u{6ECB}']),
+    ('\u{FA9A}', &['\u{6F22}']),
+    ('\u{FA9B}', &['\u{701E}']),
+    ('\u{FA9C}', &['\u{716E}']),
+    ('\u{FA9D}', &['\u{77A7}']),
+    ('\u{FA9E}', &['\u{7235}']),
+    ('\u{FA9F}', &['\u{72AF}']),
+    ('\u{FAA0}', &['\u{732A}']),
+    ('\u{FAA1}', &['\u{7471}']),
+    ('\u{FAA2}', &['\u{7506}']),
+    ('\u{FAA3}', &['\u{753B}']),
+    ('\u{FAA4}', &['\u{761D}']),
+    ('\u{FAA5}', &['\u{761F}']),
+    ('\u{FAA6}', &['\u{76CA}']),
+    ('\u{FAA7}', &['\u{76DB}']),
+    ('\u{FAA8}', &['\u{76F4}']),
+    ('\u{FAA9}', &['\u{774A}']),
+    ('\u{FAAA}', &['\u{7740}']),
+    ('\u{FAAB}', &['\u{78CC}']),
+    ('\u{FAAC}', &['\u{7AB1}']),
+    ('\u{FAAD}', &['\u{7BC0}']),
+    ('\u{FAAE}', &['\u{7C7B}']),
+    ('\u{FAAF}', &['\u{7D5B}']),
+    ('\u{FAB0}', &['\u{7DF4}']),
+    ('\u{FAB1}', &['\u{7F3E}']),
+    ('\u{FAB2}', &['\u{8005}']),
+    ('\u{FAB3}', &['\u{8352}']),
+    ('\u{FAB4}', &['\u{83EF}']),
+    ('\u{FAB5}', &['\u{8779}']),
+    ('\u{FAB6}', &['\u{8941}']),
+    ('\u{FAB7}', &['\u{8986}']),
+    ('\u{FAB8}', &['\u{8996}']),
+    ('\u{FAB9}', &['\u{8ABF}']),
+    ('\u{FABA}', &['\u{8AF8}']),
+    ('\u{FABB}', &['\u{8ACB}']),
+    ('\u{FABC}', &['\u{8B01}']),
+    ('\u{FABD}', &['\u{8AFE}']),
+    ('\u{FABE}', &['\u{8AED}']),
+    ('\u{FABF}', &['\u{8B39}']),
+    ('\u{FAC0}', &['\u{8B8A}']),
+    ('\u{FAC1}', &['\u{8D08}']),
+    ('\u{FAC2}', &['\u{8F38}']),
+    ('\u{FAC3}', &['\u{9072}']),
+    ('\u{FAC4}', &['\u{9199}']),
+    ('\u{FAC5}', &['\u{9276}']),
+    ('\u{FAC6}', &['\u{967C}']),
+    ('\u{FAC7}', &['\u{96E3}']),
+    ('\u{FAC8}', &['\u{9756}']),
+    ('\u{FAC9}', &['\u{97DB}']),
+    ('\u{FACA}', &['\u{97FF}']),
+    ('\u{FACB}', &['\u{980B}']),
+    ('\u{FACC}', &['\u{983B}']),
+    ('\u{FACD}', &['\u{9B12}']),
+    ('\u{FACE}', &['\u{9F9C}']),
+    ('\u{FACF}', &['\u{2284A}']),
+    ('\u{FAD0}', &['\u{22844}']),
+    ('\u{FAD1}', &['\u{233D5}']),
+    ('\u{FAD2}', &['\u{3B9D}']),
+    ('\u{FAD3}', &['\u{4018}']),
+    ('\u{FAD4}', &['\u{4039}']),
+    ('\u{FAD5}', &['\u{25249}']),
+    ('\u{FAD6}', &['\u{25CD0}']),
+    ('\u{FAD7}', &['\u{27ED3}']),
+    ('\u{FAD8}', &['\u{9F43}']),
+    ('\u{FAD9}', &['\u{9F8E}']),
+    ('\u{FB00}', &['\u{66}', '\u{66}']),
+    ('\u{FB01}', &['\u{66}', '\u{69}']),
+    ('\u{FB02}', &['\u{66}', '\u{6C}']),
+    ('\u{FB03}', &['\u{66}', '\u{66}', '\u{69}']),
+    ('\u{FB04}', &['\u{66}', '\u{66}', '\u{6C}']),
+    ('\u{FB06}', &['\u{73}', '\u{74}']),
+    ('\u{FB13}', &['\u{574}', '\u{576}']),
+    ('\u{FB14}', &['\u{574}', '\u{565}']),
+    ('\u{FB15}', &['\u{574}', '\u{56B}']),
+    ('\u{FB16}', &['\u{57E}', '\u{576}']),
+    ('\u{FB17}', &['\u{574}', '\u{56D}']),
+    ('\u{FB20}', &['\u{5E2}']),
+    ('\u{FB21}', &['\u{5D0}']),
+    ('\u{FB22}', &['\u{5D3}']),
+    ('\u{FB23}', &['\u{5D4}']),
+    ('\u{FB24}', &['\u{5DB}']),
+    ('\u{FB25}', &['\u{5DC}']),
+    ('\u{FB26}', &['\u{5DD}']),
+    ('\u{FB27}', &['\u{5E8}']),
+    ('\u{FB28}', &['\u{5EA}']),
+    ('\u{FB29}', &['\u{2D}', '\u{307}']),
+    ('\u{FB2B}', &['\u{FB2A}']),
+    ('\u{FB2D}', &['\u{FB2C}']),
+    ('\u{FB2F}', &['\u{FB2E}']),
+    ('\u{FB30}', &['\u{FB2E}']),
+    ('\u{FB39}', &['\u{FB1D}']),
+    ('\u{FB49}', &['\u{FB2A}']),
+    ('\u{FB4F}', &['\u{5D0}', '\u{5DC}']),
+    ('\u{FB50}', &['\u{671}']),
+    ('\u{FB51}', &['\u{671}']),
+    ('\u{FB52}', &['\u{67B}']),
+    ('\u{FB53}', &['\u{67B}']),
+    ('\u{FB54}', &['\u{67B}']),
+    ('\u{FB55}', &['\u{67B}']),
+    ('\u{FB56}', &['\u{649}', '\u{6DB}']),
+    ('\u{FB57}', &['\u{649}', '\u{6DB}']),
+    ('\u{FB58}', &['\u{649}', '\u{6DB}']),
+    ('\u{FB59}', &['\u{649}', '\u{6DB}']),
+    ('\u{FB5A}', &['\u{680}']),
+    ('\u{FB5B}', &['\u{680}']),
+    ('\u{FB5C}', &['\u{680}']),
+    ('\u{FB5D}', &['\u{680}']),
+    ('\u{FB5E}', &['\u{67A}']),
+    ('\u{FB5F}', &['\u{67A}']),
+    ('\u{FB60}', &['\u{67A}']),
+    ('\u{FB61}', &['\u{67A}']),
+    ('\u{FB62}', &['\u{67F}']),
+    ('\u{FB63}', &['\u{67F}']),
+    ('\u{FB64}', &['\u{67F}']),
+    ('\u{FB65}', &['\u{67F}']),
+    ('\u{FB66}', &['\u{649}', '\u{615}']),
+    ('\u{FB67}', &['\u{649}', '\u{615}']),
+    ('\u{FB68}', &['\u{649}', '\u{615}']),
+    ('\u{FB69}', &['\u{649}', '\u{615}']),
+    ('\u{FB6A}', &['\u{6A1}', '\u{6DB}']),
+    ('\u{FB6B}', &['\u{6A1}', '\u{6DB}']),
+    ('\u{FB6C}', &['\u{6A1}', '\u{6DB}']),
+    ('\u{FB6D}', &['\u{6A1}', '\u{6DB}']),
+    ('\u{FB6E}', &['\u{6A6}']),
+    ('\u{FB6F}', &['\u{6A6}']),
+    ('\u{FB70}', &['\u{6A6}']),
+    ('\u{FB71}', &['\u{6A6}']),
+    ('\u{FB72}', &['\u{684}']),
+    ('\u{FB73}', &['\u{684}']),
+    ('\u{FB74}', &['\u{684}']),
+    ('\u{FB75}', &['\u{684}']),
+    ('\u{FB76}', &['\u{683}']),
+    ('\u{FB77}', &['\u{683}']),
+    ('\u{FB78}', &['\u{683}']),
+    ('\u{FB79}', &['\u{683}']),
+    ('\u{FB7A}', &['\u{686}']),
+    ('\u{FB7B}', &['\u{686}']),
+    ('\u{FB7C}', &['\u{686}']),
+    ('\u{FB7D}', &['\u{686}']),
+    ('\u{FB7E}', &['\u{687}']),
+    ('\u{FB7F}', &['\u{687}']),
+    ('\u{FB80}', &['\u{687}']),
+    ('\u{FB81}', &['\u{687}']),
+    ('\u{FB82}', &['\u{68D}']),
+    ('\u{FB83}', &['\u{68D}']),
+    ('\u{FB84}', &['\u{68C}']),
+    ('\u{FB85}', &['\u{68C}']),
+    ('\u{FB86}', &['\u{62F}', '\u{6DB}']),
+    ('\u{FB87}', &['\u{62F}', '\u{6DB}']),
+    ('\u{FB88}', &['\u{62F}', '\u{615}']),
+    ('\u{FB89}', &['\u{62F}', '\u{615}']),
+    ('\u{FB8A}', &['\u{631}', '\u{6DB}']),
+    ('\u{FB8B}', &['\u{631}', '\u{6DB}']),
+    ('\u{FB8C}', &['\u{631}', '\u{615}']),
+    ('\u{FB8D}', &['\u{631}', '\u{615}']),
+    ('\u{FB8E}', &['\u{643}']),
+    ('\u{FB8F}', &['\u{643}']),
+    ('\u{FB90}', &['\u{643}']),
+    ('\u{FB91}', &['\u{643}']),
+    ('\u{FB92}', &['\u{6AF}']),
+    ('\u{FB93}', &['\u{6AF}']),
+    ('\u{FB94}', &['\u{6AF}']),
+    ('\u{FB95}', &['\u{6AF}']),
+    ('\u{FB96}', &['\u{6B3}']),
+    ('\u{FB97}', &['\u{6B3}']),
+    ('\u{FB98}', &['\u{6B3}']),
+    ('\u{FB99}', &['\u{6B3}']),
+    ('\u{FB9A}', &['\u{6B1}']),
+    ('\u{FB9B}', &['\u{6B1}']),
+    ('\u{FB9C}', &['\u{6B1}']),
+    ('\u{FB9D}', &['\u{6B1}']),
+    ('\u{FB9E}', &['\u{649}']),
+    ('\u{FB9F}', &['\u{649}']),
+    ('\u{FBA0}', &['\u{649}', '\u{615}']),
+    ('\u{FBA1}', &['\u{649}', '\u{615}']),
+    ('\u{FBA2}', &['\u{649}', '\u{615}']),
+    ('\u{FBA3}', &['\u{649}', '\u{615}']),
+    ('\u{FBA4}', &['\u{6C0}']),
+    ('\u{FBA5}', &['\u{6C0}']),
+    ('\u{FBA6}', &['\u{6F}']),
+    ('\u{FBA7}', &['\u{6F}']),
+    ('\u{FBA8}', &['\u{6F}']),
+    ('\u{FBA9}', &['\u{6F}']),
+    ('\u{FBAA}', &['\u{6F}']),
+    ('\u{FBAB}', &['\u{6F}']),
+    ('\u{FBAC}', &['\u{6F}']),
+    ('\u{FBAD}', &['\u{6F}']),
+    ('\u{FBAE}', &['\u{649}']),
+    ('\u{FBAF}', &['\u{649}']),
+    ('\u{FBB0}', &['\u{6D3}']),
+    ('\u{FBB1}', &['\u{6D3}']),
+    ('\u{FBD3}', &['\u{643}', '\u{6DB}']),
+    ('\u{FBD4}', &['\u{643}', '\u{6DB}']),
+    ('\u{FBD5}', &['\u{643}', '\u{6DB}']),
+    ('\u{FBD6}', &['\u{643}', '\u{6DB}']),
+    ('\u{FBD7}', &['\u{648}', '\u{313}']),
+    ('\u{FBD8}', &['\u{648}', '\u{313}']),
+    ('\u{FBD9}', &['\u{648}', '\u{306}']),
+    ('\u{FBDA}', &['\u{648}', '\u{306}']),
+    ('\u{FBDB}', &['\u{648}', '\u{670}']),
+    ('\u{FBDC}', &['\u{648}', '\u{670}']),
+    ('\u{FBDD}', &['\u{648}', '\u{313}', '\u{674}']),
+    ('\u{FBDE}', &['\u{648}', '\u{6DB}']),
+    ('\u{FBDF}', &['\u{648}', '\u{6DB}']),
+    ('\u{FBE0}', &['\u{6C5}']),
+    ('\u{FBE1}', &['\u{6C5}']),
+    ('\u{FBE2}', &['\u{648}', '\u{302}']),
+    ('\u{FBE3}', &['\u{648}', '\u{302}']),
+    ('\u{FBE4}', &['\u{67B}']),
+    ('\u{FBE5}', &['\u{67B}']),
+    ('\u{FBE6}', &['\u{67B}']),
+    ('\u{FBE7}', &['\u{67B}']),
+    ('\u{FBE8}', &['\u{649}']),
+    ('\u{FBE9}', &['\u{649}']),
+    ('\u{FBEA}', &['\u{649}', '\u{674}', '\u{6C}']),
+    ('\u{FBEB}', &['\u{649}', '\u{674}', '\u{6C}']),
+    ('\u{FBEC}', &['\u{649}', '\u{674}', '\u{6F}']),
+    ('\u{FBED}', &['\u{649}', '\u{674}', '\u{6F}']),
+    ('\u{FBEE}', &['\u{649}', '\u{674}', '\u{648}']),
+    ('\u{FBEF}', &['\u{649}', '\u{674}', '\u{648}']),
+    ('\u{FBF0}', &['\u{649}', '\u{674}', '\u{648}', '\u{313}']),
+    ('\u{FBF1}', &['\u{649}', '\u{674}', '\u{648}', '\u{313}']),
+    ('\u{FBF2}', &['\u{649}', '\u{674}', '\u{648}', '\u{306}']),
+    ('\u{FBF3}', &['\u{649}', '\u{674}', '\u{648}', '\u{306}']),
+    ('\u{FBF4}', &['\u{649}', '\u{674}', '\u{648}', '\u{670}']),
+    ('\u{FBF5}', &['\u{649}', '\u{674}', '\u{648}', '\u{670}']),
+    ('\u{FBF6}', &['\u{649}', '\u{674}', '\u{67B}']),
+    ('\u{FBF7}', &['\u{649}', '\u{674}', '\u{67B}']),
+    ('\u{FBF8}', &['\u{649}', '\u{674}', '\u{67B}']),
+    ('\u{FBF9}', &['\u{649}', '\u{674}', '\u{649}']),
+    ('\u{FBFA}', &['\u{649}', '\u{674}', '\u{649}']),
+    ('\u{FBFB}', &['\u{649}', '\u{674}', '\u{649}']),
+    ('\u{FBFC}', &['\u{649}']),
+    ('\u{FBFD}', &['\u{649}']),
+    ('\u{FBFE}', &['\u{649}']),
+    ('\u{FBFF}', &['\u{649}']),
+    ('\u{FC00}', &['\u{649}', '\u{674}', '\u{62C}']),
+    ('\u{FC01}', &['\u{649}', '\u{674}', '\u{62D}']),
+    ('\u{FC02}', &['\u{649}', '\u{674}', '\u{645}']),
+    ('\u{FC03}', &['\u{649}', '\u{674}', '\u{649}']),
+    ('\u{FC04}', &['\u{649}', '\u{674}', '\u{649}']),
+    ('\u{FC05}', &['\u{628}', '\u{62C}']),
+    ('\u{FC06}', &['\u{628}', '\u{62D}']),
+    ('\u{FC07}', &['\u{628}', '\u{62E}']),
+    ('\u{FC08}', &['\u{628}', '\u{645}']),
+    ('\u{FC09}', &['\u{628}', '\u{649}']),
+    ('\u{FC0A}', &['\u{628}', '\u{649}']),
+    ('\u{FC0B}', &['\u{62A}', '\u{62C}']),
+    ('\u{FC0C}', &['\u{62A}', '\u{62D}']),
+    ('\u{FC0D}', &['\u{62A}', '\u{62E}']),
+    ('\u{FC0E}', &['\u{62A}', '\u{645}']),
+    ('\u{FC0F}', &['\u{62A}', '\u{649}']),
+    ('\u{FC10}', &['\u{62A}', '\u{649}']),
+    ('\u{FC11}', &['\u{649}', '\u{6DB}', '\u{62C}']),
+    ('\u{FC12}', &['\u{649}', '\u{6DB}', '\u{645}']),
+    ('\u{FC13}', &['\u{649}', '\u{6DB}', '\u{649}']),
+    ('\u{FC14}', &['\u{649}', '\u{6DB}', '\u{649}']),
+    ('\u{FC15}', &['\u{62C}', '\u{62D}']),
+    ('\u{FC16}', &['\u{62C}', '\u{645}']),
+    ('\u{FC17}', &['\u{62D}', '\u{62C}']),
+    ('\u{FC18}', &['\u{62D}', '\u{645}']),
+    ('\u{FC19}', &['\u{62E}', '\u{62C}']),
+    ('\u{FC1A}', &['\u{62E}', '\u{62D}']),
+    ('\u{FC1B}', &['\u{62E}', '\u{645}']),
+    ('\u{FC1C}', &['\u{633}', '\u{62C}']),
+    ('\u{FC1D}', &['\u{633}', '\u{62D}']),
+    ('\u{FC1E}', &['\u{633}', '\u{62E}']),
+    ('\u{FC1F}', &['\u{633}', '\u{645}']),
+    ('\u{FC20}', &['\u{635}', '\u{62D}']),
+    ('\u{FC21}', &['\u{635}', '\u{645}']),
+    ('\u{FC22}', &['\u{636}', '\u{62C}']),
+    ('\u{FC23}', &['\u{636}', '\u{62D}']),
+    ('\u{FC24}', &['\u{636}', '\u{62E}']),
+    ('\u{FC25}', &['\u{636}', '\u{645}']),
+    ('\u{FC26}', &['\u{637}', '\u{62D}']),
+    ('\u{FC27}', &['\u{637}', '\u{645}']),
+    ('\u{FC28}', &['\u{638}', '\u{645}']),
+    ('\u{FC29}', &['\u{639}', '\u{62C}']),
+    ('\u{FC2A}', &['\u{639}', '\u{645}']),
+    ('\u{FC2B}', &['\u{63A}', '\u{62C}']),
+    ('\u{FC2C}', &['\u{63A}', '\u{645}']),
+    ('\u{FC2D}', &['\u{641}', '\u{62C}']),
+    ('\u{FC2E}', &['\u{641}', '\u{62D}']),
+    ('\u{FC2F}', &['\u{641}', '\u{62E}']),
+    ('\u{FC30}', &['\u{641}', '\u{645}']),
+    ('\u{FC31}', &['\u{641}', '\u{649}']),
+    ('\u{FC32}', &['\u{641}', '\u{649}']),
+    ('\u{FC33}', &['\u{642}', '\u{62D}']),
+    ('\u{FC34}', &['\u{642}', '\u{645}']),
+    ('\u{FC35}', &['\u{642}', '\u{649}']),
+    ('\u{FC36}', &['\u{642}', '\u{649}']),
+    ('\u{FC37}', &['\u{643}', '\u{6C}']),
+    ('\u{FC38}', &['\u{643}', '\u{62C}']),
+    ('\u{FC39}', &['\u{643}', '\u{62D}']),
+    ('\u{FC3A}', &['\u{643}', '\u{62E}']),
+    ('\u{FC3B}', &['\u{643}', '\u{644}']),
+    ('\u{FC3C}', &['\u{643}', '\u{645}']),
+    ('\u{FC3D}', &['\u{643}', '\u{649}']),
+    ('\u{FC3E}', &['\u{643}', '\u{649}']),
+    ('\u{FC3F}', &['\u{644}', '\u{62C}']),
+    ('\u{FC40}', &['\u{644}', '\u{62D}']),
+    ('\u{FC41}', &['\u{644}', '\u{62E}']),
+    ('\u{FC42}', &['\u{644}', '\u{645}']),
+    ('\u{FC43}', &['\u{644}', '\u{649}']),
+    ('\u{FC44}', &['\u{644}', '\u{649}']),
+    ('\u{FC45}', &['\u{645}', '\u{62C}']),
+    ('\u{FC46}', &['\u{645}', '\u{62D}']),
+    ('\u{FC47}', &['\u{645}', '\u{62E}']),
+    ('\u{FC48}', &['\u{645}', '\u{645}']),
+    ('\u{FC49}', &['\u{645}', '\u{649}']),
+    ('\u{FC4A}', &['\u{645}', '\u{649}']),
+    ('\u{FC4B}', &['\u{628}', '\u{62E}']),
+    ('\u{FC4C}', &['\u{646}', '\u{62D}']),
+    ('\u{FC4D}', &['\u{646}', '\u{62E}']),
+    ('\u{FC4E}', &['\u{646}', '\u{645}']),
+    ('\u{FC4F}', &['\u{646}', '\u{649}']),
+    ('\u{FC50}', &['\u{646}', '\u{649}']),
+    ('\u{FC51}', &['\u{6F}', '\u{62C}']),
+    ('\u{FC52}', &['\u{6F}', '\u{645}']),
+    ('\u{FC53}', &['\u{6F}', '\u{649}']),
+    ('\u{FC54}', &['\u{6F}', '\u{649}']),
+    ('\u{FC55}', &['\u{649}', '\u{62C}']),
+    ('\u{FC56}', &['\u{649}', '\u{62D}']),
+    ('\u{FC57}', &['\u{649}', '\u{62E}']),
+    ('\u{FC58}', &['\u{649}', '\u{645}']),
+    ('\u{FC59}', &['\u{649}', '\u{649}']),
+    ('\u{FC5A}', &['\u{649}', '\u{649}']),
+    ('\u{FC5B}', &['\u{630}', '\u{670}']),
+    ('\u{FC5C}', &['\u{631}', '\u{670}']),
+    ('\u{FC5D}', &['\u{649}', '\u{670}']),
+    ('\u{FC5E}', &['\u{FE72}', '\u{651}']),
+    ('\u{FC5F}', &['\u{FE74}', '\u{651}']),
+    ('\u{FC60}', &['\u{FE76}', '\u{651}']),
+    ('\u{FC61}', &['\u{FE78}', '\u{651}']),
+    ('\u{FC62}', &['\u{FE7A}', '\u{651}']),
+    ('\u{FC63}', &['\u{FE7C}', '\u{670}']),
+    ('\u{FC64}', &['\u{649}', '\u{674}', '\u{631}']),
+    ('\u{FC65}', &['\u{649}', '\u{674}', '\u{632}']),
+    ('\u{FC66}', &['\u{649}', '\u{674}', '\u{645}']),
+    ('\u{FC67}', &['\u{649}', '\u{674}', '\u{646}']),
+    ('\u{FC68}', &['\u{649}', '\u{674}', '\u{649}']),
+    ('\u{FC69}', &['\u{649}', '\u{674}', '\u{649}']),
+    ('\u{FC6A}', &['\u{628}', '\u{631}']),
+    ('\u{FC6B}', &['\u{628}', '\u{632}']),
+    ('\u{FC6C}', &['\u{628}', '\u{645}']),
+    ('\u{FC6D}', &['\u{628}', '\u{646}']),
+    ('\u{FC6E}', &['\u{628}', '\u{649}']),
+    ('\u{FC6F}', &['\u{628}', '\u{649}']),
+    ('\u{FC70}', &['\u{62A}', '\u{631}']),
+    ('\u{FC71}', &['\u{62A}', '\u{632}']),
+    ('\u{FC72}', &['\u{62A}', '\u{645}']),
+    ('\u{FC73}', &['\u{62A}', '\u{646}']),
+    ('\u{FC74}', &['\u{62A}', '\u{649}']),
+    ('\u{FC75}', &['\u{62A}', '\u{649}']),
+    ('\u{FC76}', &['\u{649}', '\u{6DB}', '\u{631}']),
+    ('\u{FC77}', &['\u{649}', '\u{6DB}', '\u{632}']),
+    ('\u{FC78}', &['\u{649}', '\u{6DB}', '\u{645}']),
+    ('\u{FC79}', &['\u{649}', '\u{6DB}', '\u{646}']),
+    ('\u{FC7A}', &['\u{649}', '\u{6DB}', '\u{649}']),
+    ('\u{FC7B}', &['\u{649}', '\u{6DB}', '\u{649}']),
+    ('\u{FC7C}', &['\u{641}', '\u{649}']),
+    ('\u{FC7D}', &['\u{641}', '\u{649}']),
+    ('\u{FC7E}', &['\u{642}', '\u{649}']),
+    ('\u{FC7F}', &['\u{642}', '\u{649}']),
+    ('\u{FC80}', &['\u{643}', '\u{6C}']),
+    ('\u{FC81}', &['\u{643}', '\u{644}']),
+    ('\u{FC82}', &['\u{643}', '\u{645}']),
+    ('\u{FC83}', &['\u{643}', '\u{649}']),
+    ('\u{FC84}', &['\u{643}', '\u{649}']),
+    ('\u{FC85}', &['\u{644}', '\u{645}']),
+    ('\u{FC86}', &['\u{644}', '\u{649}']),
+    ('\u{FC87}', &['\u{644}', '\u{649}']),
+    ('\u{FC88}', &['\u{645}', '\u{6C}']),
+    ('\u{FC89}', &['\u{645}', '\u{645}']),
+    ('\u{FC8A}', &['\u{646}', '\u{631}']),
+    ('\u{FC8B}', &['\u{646}', '\u{632}']),
+    ('\u{FC8C}', &['\u{646}', '\u{645}']),
+    ('\u{FC8D}', &['\u{646}', '\u{646}']),
+    ('\u{FC8E}', &['\u{646}', '\u{649}']),
+    ('\u{FC8F}', &['\u{646}', '\u{649}']),
+    ('\u{FC90}', &['\u{649}', '\u{670}']),
+    ('\u{FC91}', &['\u{649}', '\u{631}']),
+    ('\u{FC92}', &['\u{649}', '\u{632}']),
+    ('\u{FC93}', &['\u{649}', '\u{645}']),
+    ('\u{FC94}', &['\u{649}', '\u{646}']),
+    ('\u{FC95}', &['\u{649}', '\u{649}']),
+    ('\u{FC96}', &['\u{649}', '\u{649}']),
+    ('\u{FC97}', &['\u{649}', '\u{674}', '\u{62C}']),
+    ('\u{FC98}', &['\u{649}', '\u{674}', '\u{62D}']),
+    ('\u{FC99}', &['\u{649}', '\u{674}', '\u{62E}']),
+    ('\u{FC9A}', &['\u{649}', '\u{674}', '\u{645}']),
+    ('\u{FC9B}', &['\u{649}', '\u{674}', '\u{6F}']),
+    ('\u{FC9C}', &['\u{628}', '\u{62C}']),
+    ('\u{FC9D}', &['\u{628}', '\u{62D}']),
+    ('\u{FC9E}', &['\u{628}', '\u{62E}']),
+    ('\u{FC9F}', &['\u{628}', '\u{645}']),
+    ('\u{FCA0}', &['\u{628}', '\u{6F}']),
+    ('\u{FCA1}', &['\u{62A}', '\u{62C}']),
+    ('\u{FCA2}', &['\u{62A}', '\u{62D}']),
+    ('\u{FCA3}', &['\u{62A}', '\u{62E}']),
+    ('\u{FCA4}', &['\u{62A}', '\u{645}']),
+    ('\u{FCA5}', &['\u{62A}', '\u{6F}']),
+    ('\u{FCA6}', &['\u{649}', '\u{6DB}', '\u{645}']),
+    ('\u{FCA7}', &['\u{62C}', '\u{62D}']),
+    ('\u{FCA8}', &['\u{62C}', '\u{645}']),
+    ('\u{FCA9}', &['\u{62D}', '\u{62C}']),
+    ('\u{FCAA}', &['\u{62D}', '\u{645}']),
+    ('\u{FCAB}', &['\u{62E}', '\u{62C}']),
+    ('\u{FCAC}', &['\u{62E}', '\u{645}']),
+    ('\u{FCAD}', &['\u{633}', '\u{62C}']),
+    ('\u{FCAE}', &['\u{633}', '\u{62D}']),
+    ('\u{FCAF}', &['\u{633}', '\u{62E}']),
+    ('\u{FCB0}', &['\u{633}', '\u{645}']),
+    ('\u{FCB1}', &['\u{635}', '\u{62D}']),
+    ('\u{FCB2}', &['\u{635}', '\u{62E}']),
+    ('\u{FCB3}', &['\u{635}', '\u{645}']),
+    ('\u{FCB4}', &['\u{636}', '\u{62C}']),
+    ('\u{FCB5}', &['\u{636}', '\u{62D}']),
+    ('\u{FCB6}', &['\u{636}', '\u{62E}']),
+    ('\u{FCB7}', &['\u{636}', '\u{645}']),
+    ('\u{FCB8}', &['\u{637}', '\u{62D}']),
+    ('\u{FCB9}', &['\u{638}', '\u{645}']),
+    ('\u{FCBA}', &['\u{639}', '\u{62C}']),
+    ('\u{FCBB}', &['\u{639}', '\u{645}']),
+    ('\u{FCBC}', &['\u{63A}', '\u{62C}']),
+    ('\u{FCBD}', &['\u{63A}', '\u{645}']),
+    ('\u{FCBE}', &['\u{641}', '\u{62C}']),
+    ('\u{FCBF}', &['\u{641}', '\u{62D}']),
+    ('\u{FCC0}', &['\u{641}', '\u{62E}']),
+    ('\u{FCC1}', &['\u{641}', '\u{645}']),
+    ('\u{FCC2}', &['\u{642}', '\u{62D}']),
+    ('\u{FCC3}', &['\u{642}', '\u{645}']),
+    ('\u{FCC4}', &['\u{643}', '\u{62C}']),
+    ('\u{FCC5}', &['\u{643}', '\u{62D}']),
+    ('\u{FCC6}', &['\u{643}', '\u{62E}']),
+    ('\u{FCC7}', &['\u{643}', '\u{644}']),
+    ('\u{FCC8}', &['\u{643}', '\u{645}']),
+    ('\u{FCC9}', &['\u{644}', '\u{62C}']),
+    ('\u{FCCA}', &['\u{644}', '\u{62D}']),
+    ('\u{FCCB}', &['\u{644}', '\u{62E}']),
+    ('\u{FCCC}', &['\u{644}', '\u{645}']),
+    ('\u{FCCD}', &['\u{644}', '\u{6F}']),
+    ('\u{FCCE}', &['\u{645}', '\u{62C}']),
+    ('\u{FCCF}', &['\u{645}', '\u{62D}']),
+    ('\u{FCD0}', &['\u{645}', '\u{62E}']),
+    ('\u{FCD1}', &['\u{645}', '\u{645}']),
+    ('\u{FCD2}', &['\u{628}', '\u{62E}']),
+    ('\u{FCD3}', &['\u{646}', '\u{62D}']),
+    ('\u{FCD4}', &['\u{646}', '\u{62E}']),
+    ('\u{FCD5}', &['\u{646}', '\u{645}']),
+    ('\u{FCD6}', &['\u{646}', '\u{6F}']),
+    ('\u{FCD7}', &['\u{6F}', '\u{62C}']),
+    ('\u{FCD8}', &['\u{6F}', '\u{645}']),
+    ('\u{FCD9}', &['\u{6F}', '\u{670}']),
+    ('\u{FCDA}', &['\u{649}', '\u{62C}']),
+    ('\u{FCDB}', &['\u{649}', '\u{62D}']),
+    ('\u{FCDC}', &['\u{649}', '\u{62E}']),
+    ('\u{FCDD}', &['\u{649}', '\u{645}']),
+    ('\u{FCDE}', &['\u{649}', '\u{6F}']),
+    ('\u{FCDF}', &['\u{649}', '\u{674}', '\u{645}']),
+    ('\u{FCE0}', &['\u{649}', '\u{674}', '\u{6F}']),
+    ('\u{FCE1}', &['\u{628}', '\u{645}']),
+    ('\u{FCE2}', &['\u{628}', '\u{6F}']),
+    ('\u{FCE3}', &['\u{62A}', '\u{645}']),
+    ('\u{FCE4}', &['\u{62A}', '\u{6F}']),
+    ('\u{FCE5}', &['\u{649}', '\u{6DB}', '\u{645}']),
+    ('\u{FCE6}', &['\u{649}', '\u{6DB}', '\u{6F}']),
+    ('\u{FCE7}', &['\u{633}', '\u{645}']),
+    ('\u{FCE8}', &['\u{633}', '\u{6F}']),
+    ('\u{FCE9}', &['\u{633}', '\u{6DB}', '\u{645}']),
+    ('\u{FCEA}', &['\u{633}', '\u{6DB}', '\u{6F}']),
+    ('\u{FCEB}', &['\u{643}', '\u{644}']),
+    ('\u{FCEC}', &['\u{643}', '\u{645}']),
+    ('\u{FCED}', &['\u{644}', '\u{645}']),
+    ('\u{FCEE}', &['\u{646}', '\u{645}']),
+    ('\u{FCEF}', &['\u{646}', '\u{6F}']),
+    ('\u{FCF0}', &['\u{649}', '\u{645}']),
+    ('\u{FCF1}', &['\u{649}', '\u{6F}']),
+    ('\u{FCF2}', &['\u{FE77}', '\u{651}']),
+    ('\u{FCF3}', &['\u{FE79}', '\u{651}']),
+    ('\u{FCF4}', &['\u{FE7B}', '\u{651}']),
+    ('\u{FCF5}', &['\u{637}', '\u{649}']),
+    ('\u{FCF6}', &['\u{637}', '\u{649}']),
+    ('\u{FCF7}', &['\u{639}', '\u{649}']),
+    ('\u{FCF8}', &['\u{639}', '\u{649}']),
+    ('\u{FCF9}', &['\u{63A}', '\u{649}']),
+    ('\u{FCFA}', &['\u{63A}', '\u{649}']),
+    ('\u{FCFB}', &['\u{633}', '\u{649}']),
+    ('\u{FCFC}', &['\u{633}', '\u{649}']),
+    ('\u{FCFD}', &['\u{633}', '\u{6DB}', '\u{649}']),
+    ('\u{FCFE}', &['\u{633}', '\u{6DB}', '\u{649}']),
+    ('\u{FCFF}', &['\u{62D}', '\u{649}']),
+    ('\u{FD00}', &['\u{62D}', '\u{649}']),
+    ('\u{FD01}', &['\u{62C}', '\u{649}']),
+    ('\u{FD02}', &['\u{62C}', '\u{649}']),
+    ('\u{FD03}', &['\u{62E}', '\u{649}']),
+    ('\u{FD04}', &['\u{62E}', '\u{649}']),
+    ('\u{FD05}', &['\u{635}', '\u{649}']),
+    ('\u{FD06}', &['\u{635}', '\u{649}']),
+    ('\u{FD07}', &['\u{636}', '\u{649}']),
+    ('\u{FD08}', &['\u{636}', '\u{649}']),
+    ('\u{FD09}', &['\u{633}', '\u{6DB}', '\u{62C}']),
+    ('\u{FD0A}', &['\u{633}', '\u{6DB}', '\u{62D}']),
+    ('\u{FD0B}', &['\u{633}', '\u{6DB}', '\u{62E}']),
+    ('\u{FD0C}', &['\u{633}', '\u{6DB}', '\u{645}']),
+    ('\u{FD0D}', &['\u{633}', '\u{6DB}', '\u{631}']),
+    ('\u{FD0E}', &['\u{633}', '\u{631}']),
+    ('\u{FD0F}', &['\u{635}', '\u{631}']),
+    ('\u{FD10}', &['\u{636}', '\u{631}']),
+    ('\u{FD11}', &['\u{637}', '\u{649}']),
+    ('\u{FD12}', &['\u{637}', '\u{649}']),
+    ('\u{FD13}', &['\u{639}', '\u{649}']),
+    ('\u{FD14}', &['\u{639}', '\u{649}']),
+    ('\u{FD15}', &['\u{63A}', '\u{649}']),
+    ('\u{FD16}', &['\u{63A}', '\u{649}']),
+    ('\u{FD17}', &['\u{633}', '\u{649}']),
+    ('\u{FD18}', &['\u{633}', '\u{649}']),
+    ('\u{FD19}', &['\u{633}', '\u{6DB}', '\u{649}']),
+    ('\u{FD1A}', &['\u{633}', '\u{6DB}', '\u{649}']),
+    ('\u{FD1B}', &['\u{62D}', '\u{649}']),
+    ('\u{FD1C}', &['\u{62D}', '\u{649}']),
+    ('\u{FD1D}', &['\u{62C}', '\u{649}']),
+    ('\u{FD1E}', &['\u{62C}', '\u{649}']),
+    ('\u{FD1F}', &['\u{62E}', '\u{649}']),
+    ('\u{FD20}', &['\u{62E}', '\u{649}']),
+    ('\u{FD21}', &['\u{635}', '\u{649}']),
+    ('\u{FD22}', &['\u{635}', '\u{649}']),
+    ('\u{FD23}', &['\u{636}', '\u{649}']),
+    ('\u{FD24}', &['\u{636}', '\u{649}']),
+    ('\u{FD25}', &['\u{633}', '\u{6DB}', '\u{62C}']),
+    ('\u{FD26}', &['\u{633}', '\u{6DB}', '\u{62D}']),
+    ('\u{FD27}', &['\u{633}', '\u{6DB}', '\u{62E}']),
+    ('\u{FD28}', &['\u{633}', '\u{6DB}', '\u{645}']),
+    ('\u{FD29}', &['\u{633}', '\u{6DB}', '\u{631}']),
+    ('\u{FD2A}', &['\u{633}', '\u{631}']),
+    ('\u{FD2B}', &['\u{635}', '\u{631}']),
+    ('\u{FD2C}', &['\u{636}', '\u{631}']),
+    ('\u{FD2D}', &['\u{633}', '\u{6DB}', '\u{62C}']),
+    ('\u{FD2E}', &['\u{633}', '\u{6DB}', '\u{62D}']),
+    ('\u{FD2F}', &['\u{633}', '\u{6DB}', '\u{62E}']),
+    ('\u{FD30}', &['\u{633}', '\u{6DB}', '\u{645}']),
+    ('\u{FD31}', &['\u{633}', '\u{6F}']),
+    ('\u{FD32}', &['\u{633}', '\u{6DB}', '\u{6F}']),
+    ('\u{FD33}', &['\u{637}', '\u{645}']),
+    ('\u{FD34}', &['\u{633}', '\u{62C}']),
+    ('\u{FD35}', &['\u{633}', '\u{62D}']),
+    ('\u{FD36}', &['\u{633}', '\u{62E}']),
+    ('\u{FD37}', &['\u{633}', '\u{6DB}', '\u{62C}']),
+    ('\u{FD38}', &['\u{633}', '\u{6DB}', '\u{62D}']),
+    ('\u{FD39}', &['\u{633}', '\u{6DB}', '\u{62E}']),
+    ('\u{FD3A}', &['\u{637}', '\u{645}']),
+    ('\u{FD3B}', &['\u{638}', '\u{645}']),
+    ('\u{FD3C}', &['\u{6C}', '\u{30B}']),
+    ('\u{FD3D}', &['\u{6C}', '\u{30B}']),
+    ('\u{FD3E}', &['\u{28}']),
+    ('\u{FD3F}', &['\u{29}']),
+    ('\u{FD50}', &['\u{62A}', '\u{62C}', '\u{645}']),
+    ('\u{FD51}', &['\u{62A}', '\u{62D}', '\u{62C}']),
+    ('\u{FD52}', &['\u{62A}', '\u{62D}', '\u{62C}']),
+    ('\u{FD53}', &['\u{62A}', '\u{62D}', '\u{645}']),
+    ('\u{FD54}', &['\u{62A}', '\u{62E}', '\u{645}']),
+    ('\u{FD55}', &['\u{62A}', '\u{645}', '\u{62C}']),
+    ('\u{FD56}', &['\u{62A}', '\u{645}', '\u{62D}']),
+    ('\u{FD57}', &['\u{62A}', '\u{645}', '\u{62E}']),
+    ('\u{FD58}', &['\u{62C}', '\u{645}', '\u{62D}']),
+    ('\u{FD59}', &['\u{62C}', '\u{645}', '\u{62D}']),
+    ('\u{FD5A}', &['\u{62D}', '\u{645}', '\u{649}']),
+    ('\u{FD5B}', &['\u{62D}', '\u{645}', '\u{649}']),
+    ('\u{FD5C}', &['\u{633}', '\u{62D}', '\u{62C}']),
+    ('\u{FD5D}', &['\u{633}', '\u{62C}', '\u{62D}']),
+    ('\u{FD5E}', &['\u{633}', '\u{62C}', '\u{649}']),
+    ('\u{FD5F}', &['\u{633}', '\u{645}', '\u{62D}']),
+    ('\u{FD60}', &['\u{633}', '\u{645}', '\u{62D}']),
+    ('\u{FD61}', &['\u{633}', '\u{645}', '\u{62C}']),
+    ('\u{FD62}', &['\u{633}', '\u{645}', '\u{645}']),
+    ('\u{FD63}', &['\u{633}', '\u{645}', '\u{645}']),
+    ('\u{FD64}', &['\u{635}', '\u{62D}', '\u{62D}']),
+    ('\u{FD65}', &['\u{635}', '\u{62D}', '\u{62D}']),
+    ('\u{FD66}', &['\u{635}', '\u{645}', '\u{645}']),
+    ('\u{FD67}', &['\u{633}', '\u{6DB}', '\u{62D}', '\u{645}']),
+    ('\u{FD68}', &['\u{633}', '\u{6DB}', '\u{62D}', '\u{645}']),
+    ('\u{FD69}', &['\u{633}', '\u{6DB}', '\u{62C}', '\u{649}']),
+    ('\u{FD6A}', &['\u{633}', '\u{6DB}', '\u{645}', '\u{62E}']),
+    ('\u{FD6B}', &['\u{633}', '\u{6DB}', '\u{645}', '\u{62E}']),
+    ('\u{FD6C}', &['\u{633}', '\u{6DB}', '\u{645}', '\u{645}']),
+    ('\u{FD6D}', &['\u{633}', '\u{6DB}', '\u{645}', '\u{645}']),
+    ('\u{FD6E}', &['\u{636}', '\u{62D}', '\u{649}']),
+    ('\u{FD6F}', &['\u{636}', '\u{62E}', '\u{645}']),
+    ('\u{FD70}', &['\u{636}', '\u{62E}', '\u{645}']),
+    ('\u{FD71}', &['\u{637}', '\u{645}', '\u{62D}']),
+    ('\u{FD72}', &['\u{637}', '\u{645}', '\u{62D}']),
+    ('\u{FD73}', &['\u{637}', '\u{645}', '\u{645}']),
+    ('\u{FD74}', &['\u{637}', '\u{645}', '\u{649}']),
+    ('\u{FD75}', &['\u{639}', '\u{62C}', '\u{645}']),
+    ('\u{FD76}', &['\u{639}', '\u{645}', '\u{645}']),
+    ('\u{FD77}', &['\u{639}', '\u{645}', '\u{645}']),
+    ('\u{FD78}', &['\u{639}', '\u{645}', '\u{649}']),
+    ('\u{FD79}', &['\u{63A}', '\u{645}', '\u{645}']),
+    ('\u{FD7A}', &['\u{63A}', '\u{645}', '\u{649}']),
+    ('\u{FD7B}', &['\u{63A}', '\u{645}', '\u{649}']),
+    ('\u{FD7C}', &['\u{641}', '\u{62E}', '\u{645}']),
+    ('\u{FD7D}', &['\u{641}', '\u{62E}', '\u{645}']),
+    ('\u{FD7E}', &['\u{642}', '\u{645}', '\u{62D}']),
+    ('\u{FD7F}', &['\u{642}', '\u{645}', '\u{645}']),
+    ('\u{FD80}', &['\u{644}', '\u{62D}', '\u{645}']),
+    ('\u{FD81}', &['\u{644}', '\u{62D}', '\u{649}']),
+    ('\u{FD82}', &['\u{644}', '\u{62D}', '\u{649}']),
+    ('\u{FD83}', &['\u{644}', '\u{62C}', '\u{62C}']),
+    ('\u{FD84}', &['\u{644}', '\u{62C}', '\u{62C}']),
+    ('\u{FD85}', &['\u{644}', '\u{62E}', '\u{645}']),
+    ('\u{FD86}', &['\u{644}', '\u{62E}', '\u{645}']),
+    ('\u{FD87}', &['\u{644}', '\u{645}', '\u{62D}']),
+    ('\u{FD88}', &['\u{644}', '\u{645}', '\u{62D}']),
+    ('\u{FD89}', &['\u{645}', '\u{62D}', '\u{62C}']),
+    ('\u{FD8A}', &['\u{645}', '\u{62D}', '\u{645}']),
+    ('\u{FD8B}', &['\u{645}', '\u{62D}', '\u{649}']),
+    ('\u{FD8C}', &['\u{645}', '\u{62C}', '\u{62D}']),
+    ('\u{FD8D}', &['\u{645}', '\u{62C}', '\u{645}']),
+    ('\u{FD8E}', &['\u{645}', '\u{62E}', '\u{62C}']),
+    ('\u{FD8F}', &['\u{645}', '\u{62E}', '\u{645}']),
+    ('\u{FD92}', &['\u{645}', '\u{62C}', '\u{62E}']),
+    ('\u{FD93}', &['\u{6F}', '\u{645}', '\u{62C}']),
+    ('\u{FD94}', &['\u{6F}', '\u{645}', '\u{645}']),
+    ('\u{FD95}', &['\u{646}', '\u{62D}', '\u{645}']),
+    ('\u{FD96}', &['\u{646}', '\u{62D}', '\u{649}']),
+    ('\u{FD97}', &['\u{646}', '\u{62C}', '\u{645}']),
+    ('\u{FD98}', &['\u{646}', '\u{62C}', '\u{645}']),
+    ('\u{FD99}', &['\u{646}', '\u{62C}', '\u{649}']),
+    ('\u{FD9A}', &['\u{646}', '\u{645}', '\u{649}']),
+    ('\u{FD9B}', &['\u{646}', '\u{645}', '\u{649}']),
+    ('\u{FD9C}', &['\u{649}', '\u{645}', '\u{645}']),
+    ('\u{FD9D}', &['\u{649}', '\u{645}', '\u{645}']),
+    ('\u{FD9E}', &['\u{628}', '\u{62E}', '\u{649}']),
+    ('\u{FD9F}', &['\u{62A}', '\u{62C}', '\u{649}']),
+    ('\u{FDA0}', &['\u{62A}', '\u{62C}', '\u{649}']),
+    ('\u{FDA1}', &['\u{62A}', '\u{62E}', '\u{649}']),
+    ('\u{FDA2}', &['\u{62A}', '\u{62E}', '\u{649}']),
+    ('\u{FDA3}', &['\u{62A}', '\u{645}', '\u{649}']),
+    ('\u{FDA4}', &['\u{62A}', '\u{645}', '\u{649}']),
+    ('\u{FDA5}', &['\u{62C}', '\u{645}', '\u{649}']),
+    ('\u{FDA6}', &['\u{62C}', '\u{62D}', '\u{649}']),
+    ('\u{FDA7}', &['\u{62C}', '\u{645}', '\u{649}']),
+    ('\u{FDA8}', &['\u{633}', '\u{62E}', '\u{649}']),
+    ('\u{FDA9}', &['\u{635}', '\u{62D}', '\u{649}']),
+    ('\u{FDAA}', &['\u{633}', '\u{6DB}', '\u{62D}', '\u{649}']),
+    ('\u{FDAB}', &['\u{636}', '\u{62D}', '\u{649}']),
+    ('\u{FDAC}', &['\u{644}', '\u{62C}', '\u{649}']),
+    ('\u{FDAD}', &['\u{644}', '\u{645}', '\u{649}']),
+    ('\u{FDAE}', &['\u{649}', '\u{62D}', '\u{649}']),
+    ('\u{FDAF}', &['\u{649}', '\u{62C}', '\u{649}']),
+    ('\u{FDB0}', &['\u{649}', '\u{645}', '\u{649}']),
+    ('\u{FDB1}', &['\u{645}', '\u{645}', '\u{649}']),
+    ('\u{FDB2}', &['\u{642}', '\u{645}', '\u{649}']),
+    ('\u{FDB3}', &['\u{646}', '\u{62D}', '\u{649}']),
+    ('\u{FDB4}', &['\u{642}', '\u{645}', '\u{62D}']),
+    ('\u{FDB5}', &['\u{644}', '\u{62D}', '\u{645}']),
+    ('\u{FDB6}', &['\u{639}', '\u{645}', '\u{649}']),
+    ('\u{FDB7}', &['\u{643}', '\u{645}', '\u{649}']),
+    ('\u{FDB8}', &['\u{646}', '\u{62C}', '\u{62D}']),
+    ('\u{FDB9}', &['\u{645}', '\u{62E}', '\u{649}']),
+    ('\u{FDBA}', &['\u{644}', '\u{62C}', '\u{645}']),
+    ('\u{FDBB}', &['\u{643}', '\u{645}', '\u{645}']),
+    ('\u{FDBC}', &['\u{644}', '\u{62C}', '\u{645}']),
+    ('\u{FDBD}', &['\u{646}', '\u{62C}', '\u{62D}']),
+    ('\u{FDBE}', &['\u{62C}', '\u{62D}', '\u{649}']),
+    ('\u{FDBF}', &['\u{62D}', '\u{62C}', '\u{649}']),
+    ('\u{FDC0}', &['\u{645}', '\u{62C}', '\u{649}']),
+    ('\u{FDC1}', &['\u{641}', '\u{645}', '\u{649}']),
+    ('\u{FDC2}', &['\u{628}', '\u{62D}', '\u{649}']),
+    ('\u{FDC3}', &['\u{643}', '\u{645}', '\u{645}']),
+    ('\u{FDC4}', &['\u{639}', '\u{62C}', '\u{645}']),
+    ('\u{FDC5}', &['\u{635}', '\u{645}', '\u{645}']),
+    ('\u{FDC6}', &['\u{633}', '\u{62E}', '\u{649}']),
+    ('\u{FDC7}', &['\u{646}', '\u{62C}', '\u{649}']),
+    ('\u{FDF0}', &['\u{635}', '\u{644}', '\u{649}']),
+    ('\u{FDF1}', &['\u{642}', '\u{644}', '\u{649}']),
+    ('\u{FDF2}', &['\u{6C}', '\u{644}', '\u{644}', '\u{651}', '\u{670}', '\u{6F}']),
+    ('\u{FDF3}', &['\u{6C}', '\u{643}', '\u{628}', '\u{631}']),
+    ('\u{FDF4}', &['\u{645}', '\u{62D}', '\u{645}', '\u{62F}']),
+    ('\u{FDF5}', &['\u{635}', '\u{644}', '\u{639}', '\u{645}']),
+    ('\u{FDF6}', &['\u{631}', '\u{633}', '\u{648}', '\u{644}']),
+    ('\u{FDF7}', &['\u{639}', '\u{644}', '\u{649}', '\u{6F}']),
+    ('\u{FDF8}', &['\u{648}', '\u{633}', '\u{644}', '\u{645}']),
+    ('\u{FDF9}', &['\u{635}', '\u{644}', '\u{649}']),
+    ('\u{FDFA}', &['\u{635}', '\u{644}', '\u{649}', '\u{20}', '\u{6C}', '\u{644}', '\u{644}', '\u{6F}', '\u{20}', '\u{639}', '\u{644}', '\u{649}', '\u{6F}', '\u{20}', '\u{648}', '\u{633}', '\u{644}', '\u{645}']),
+    ('\u{FDFB}', &['\u{62C}', '\u{644}', '\u{20}', '\u{62C}', '\u{644}', '\u{6C}', '\u{644}', '\u{6F}']),
+    ('\u{FDFC}', &['\u{631}', '\u{649}', '\u{6C}', '\u{644}']),
+    ('\u{FE19}', &['\u{2D57}']),
+    ('\u{FE30}', &['\u{3A}']),
+    ('\u{FE31}', &['\u{2502}']),
+    ('\u{FE34}', &['\u{2307}']),
+    ('\u{FE35}', &['\u{23DC}']),
+    ('\u{FE36}', &['\u{23DD}']),
+    ('\u{FE37}', &['\u{23DE}']),
+    ('\u{FE38}', &['\u{23DF}']),
+    ('\u{FE39}', &['\u{23E0}']),
+    ('\u{FE3A}', &['\u{23E1}']),
+    ('\u{FE49}', &['\u{2C9}']),
+    ('\u{FE4A}', &['\u{2C9}']),
+    ('\u{FE4B}', &['\u{2C9}']),
+    ('\u{FE4C}', &['\u{2C9}']),
+    ('\u{FE4D}', &['\u{5F}']),
+    ('\u{FE4E}', &['\u{5F}']),
+    ('\u{FE4F}', &['\u{5F}']),
+    ('\u{FE58}', &['\u{2D}']),
+    ('\u{FE68}', &['\u{5C}']),
+    ('\u{FE80}', &['\u{621}']),
+    ('\u{FE81}', &['\u{622}']),
+    ('\u{FE82}', &['\u{622}']),
+    ('\u{FE83}', &['\u{6C}', '\u{674}']),
+    ('\u{FE84}', &['\u{6C}', '\u{674}']),
+    ('\u{FE85}', &['\u{648}', '\u{674}']),
+    ('\u{FE86}', &['\u{648}', '\u{674}']),
+    ('\u{FE87}', &['\u{6C}', '\u{655}']),
+    ('\u{FE88}', &['\u{6C}', '\u{655}']),
+    ('\u{FE89}', &['\u{649}', '\u{674}']),
+    ('\u{FE8A}', &['\u{649}', '\u{674}']),
+    ('\u{FE8B}', &['\u{649}', '\u{674}']),
+    ('\u{FE8C}', &['\u{649}', '\u{674}']),
+    ('\u{FE8D}', &['\u{6C}']),
+    ('\u{FE8E}', &['\u{6C}']),
+    ('\u{FE8F}', &['\u{628}']),
+    ('\u{FE90}', &['\u{628}']),
+    ('\u{FE91}', &['\u{628}']),
+    ('\u{FE92}', &['\u{628}']),
+    ('\u{FE93}', &['\u{629}']),
+    ('\u{FE94}', &['\u{629}']),
+    ('\u{FE95}', &['\u{62A}']),
+    ('\u{FE96}', &['\u{62A}']),
+    ('\u{FE97}', &['\u{62A}']),
+    ('\u{FE98}', &['\u{62A}']),
+    ('\u{FE99}', &['\u{649}', '\u{6DB}']),
+    ('\u{FE9A}', &['\u{649}', '\u{6DB}']),
+    ('\u{FE9B}', &['\u{649}', '\u{6DB}']),
+    ('\u{FE9C}', &['\u{649}', '\u{6DB}']),
+    ('\u{FE9D}', &['\u{62C}']),
+    ('\u{FE9E}', &['\u{62C}']),
+    ('\u{FE9F}', &['\u{62C}']),
+    ('\u{FEA0}', &['\u{62C}']),
+    ('\u{FEA1}', &['\u{62D}']),
+    ('\u{FEA2}', &['\u{62D}']),
+    ('\u{FEA3}', &['\u{62D}']),
+    ('\u{FEA4}', &['\u{62D}']),
+    ('\u{FEA5}', &['\u{62E}']),
+    ('\u{FEA6}', &['\u{62E}']),
+    ('\u{FEA7}', &['\u{62E}']),
+    ('\u{FEA8}', &['\u{62E}']),
+    ('\u{FEA9}', &['\u{62F}']),
+    ('\u{FEAA}', &['\u{62F}']),
+    ('\u{FEAB}', &['\u{630}']),
+    ('\u{FEAC}', &['\u{630}']),
+    ('\u{FEAD}', &['\u{631}']),
+    ('\u{FEAE}', &['\u{631}']),
+    ('\u{FEAF}', &['\u{632}']),
+    ('\u{FEB0}', &['\u{632}']),
+    ('\u{FEB1}', &['\u{633}']),
+    ('\u{FEB2}', &['\u{633}']),
+    ('\u{FEB3}', &['\u{633}']),
+    ('\u{FEB4}', &['\u{633}']),
+    ('\u{FEB5}', &['\u{633}', '\u{6DB}']),
+    ('\u{FEB6}', &['\u{633}', '\u{6DB}']),
+    ('\u{FEB7}', &['\u{633}', '\u{6DB}']),
+    ('\u{FEB8}', &['\u{633}', '\u{6DB}']),
+    ('\u{FEB9}', &['\u{635}']),
+    ('\u{FEBA}', &['\u{635}']),
+    ('\u{FEBB}', &['\u{635}']),
+    ('\u{FEBC}', &['\u{635}']),
+    ('\u{FEBD}', &['\u{636}']),
+    ('\u{FEBE}', &['\u{636}']),
+    ('\u{FEBF}', &['\u{636}']),
+    ('\u{FEC0}', &['\u{636}']),
+    ('\u{FEC1}', &['\u{637}']),
+    ('\u{FEC2}', &['\u{637}']),
+    ('\u{FEC3}', &['\u{637}']),
+    ('\u{FEC4}', &['\u{637}']),
+    ('\u{FEC5}', &['\u{638}']),
+    ('\u{FEC6}', &['\u{638}']),
+    ('\u{FEC7}', &['\u{638}']),
+    ('\u{FEC8}', &['\u{638}']),
+    ('\u{FEC9}', &['\u{639}']),
+    ('\u{FECA}', &['\u{639}']),
+    ('\u{FECB}', &['\u{639}']),
+    ('\u{FECC}', &['\u{639}']),
+    ('\u{FECD}', &['\u{63A}']),
+    ('\u{FECE}', &['\u{63A}']),
+    ('\u{FECF}', &['\u{63A}']),
+    ('\u{FED0}', &['\u{63A}']),
+    ('\u{FED1}', &['\u{641}']),
+    ('\u{FED2}', &['\u{641}']),
+    ('\u{FED3}', &['\u{641}']),
+    ('\u{FED4}', &['\u{641}']),
+    ('\u{FED5}', &['\u{642}']),
+    ('\u{FED6}', &['\u{642}']),
+    ('\u{FED7}', &['\u{642}']),
+    ('\u{FED8}', &['\u{642}']),
+    ('\u{FED9}', &['\u{643}']),
+    ('\u{FEDA}', &['\u{643}']),
+    ('\u{FEDB}', &['\u{643}']),
+    ('\u{FEDC}', &['\u{643}']),
+    ('\u{FEDD}', &['\u{644}']),
+    ('\u{FEDE}', &['\u{644}']),
+    ('\u{FEDF}', &['\u{644}']),
+    ('\u{FEE0}', &['\u{644}']),
+    ('\u{FEE1}', &['\u{645}']),
+    ('\u{FEE2}', &['\u{645}']),
+    ('\u{FEE3}', &['\u{645}']),
+    ('\u{FEE4}', &['\u{645}']),
+    ('\u{FEE5}', &['\u{646}']),
+    ('\u{FEE6}', &['\u{646}']),
+    ('\u{FEE7}', &['\u{646}']),
+    ('\u{FEE8}', &['\u{646}']),
+    ('\u{FEE9}', &['\u{6F}']),
+    ('\u{FEEA}', &['\u{6F}']),
+    ('\u{FEEB}', &['\u{6F}']),
+    ('\u{FEEC}', &['\u{6F}']),
+    ('\u{FEED}', &['\u{648}']),
+    ('\u{FEEE}', &['\u{648}']),
+    ('\u{FEEF}', &['\u{649}']),
+    ('\u{FEF0}', &['\u{649}']),
+    ('\u{FEF1}', &['\u{649}']),
+    ('\u{FEF2}', &['\u{649}']),
+    ('\u{FEF3}', &['\u{649}']),
+    ('\u{FEF4}', &['\u{649}']),
+    ('\u{FEF5}', &['\u{644}', '\u{622}']),
+    ('\u{FEF6}', &['\u{644}', '\u{622}']),
+    ('\u{FEF7}', &['\u{644}', '\u{6C}', '\u{674}']),
+    ('\u{FEF8}', &['\u{644}', '\u{6C}', '\u{674}']),
+    ('\u{FEF9}', &['\u{644}', '\u{6C}', '\u{655}']),
+    ('\u{FEFA}', &['\u{644}', '\u{6C}', '\u{655}']),
+    ('\u{FEFB}', &['\u{644}', '\u{6C}']),
+    ('\u{FEFC}', &['\u{644}', '\u{6C}']),
+    ('\u{FF01}', &['\u{21}']),
+    ('\u{FF02}', &['\u{27}', '\u{27}']),
+    ('\u{FF07}', &['\u{27}']),
+    ('\u{FF0D}', &['\u{30FC}']),
+    ('\u{FF1A}', &['\u{3A}']),
+    ('\u{FF21}', &['\u{41}']),
+    ('\u{FF22}', &['\u{42}']),
+    ('\u{FF23}', &['\u{43}']),
+    ('\u{FF25}', &['\u{45}']),
+    ('\u{FF28}', &['\u{48}']),
+    ('\u{FF29}', &['\u{6C}']),
+    ('\u{FF2A}', &['\u{4A}']),
+    ('\u{FF2B}', &['\u{4B}']),
+    ('\u{FF2D}', &['\u{4D}']),
+    ('\u{FF2E}', &['\u{4E}']),
+    ('\u{FF2F}', &['\u{4F}']),
+    ('\u{FF30}', &['\u{50}']),
+    ('\u{FF33}', &['\u{53}']),
+    ('\u{FF34}', &['\u{54}']),
+    ('\u{FF38}', &['\u{58}']),
+    ('\u{FF39}', &['\u{59}']),
+    ('\u{FF3A}', &['\u{5A}']),
+    ('\u{FF3B}', &['\u{28}']),
+    ('\u{FF3C}', &['\u{5C}']),
+    ('\u{FF3D}', &['\u{29}']),
+    ('\u{FF3E}', &['\u{FE3F}']),
+    ('\u{FF40}', &['\u{27}']),
+    ('\u{FF41}', &['\u{61}']),
+    ('\u{FF43}', &['\u{63}']),
+    ('\u{FF45}', &['\u{65}']),
+    ('\u{FF47}', &['\u{67}']),
+    ('\u{FF48}', &['\u{68}']),
+    ('\u{FF49}', &['\u{69}']),
+    ('\u{FF4A}', &['\u{6A}']),
+    ('\u{FF4C}', &['\u{6C}']),
+    ('\u{FF4F}', &['\u{6F}']),
+    ('\u{FF50}', &['\u{70}']),
+    ('\u{FF53}', &['\u{73}']),
+    ('\u{FF56}', &['\u{76}']),
+    ('\u{FF58}', &['\u{78}']),
+    ('\u{FF59}', &['\u{79}']),
+    ('\u{FF5C}', &['\u{2502}']),
+    ('\u{FF5E}', &['\u{301C}']),
+    ('\u{FF65}', &['\u{B7}']),
+    ('\u{FFE3}', &['\u{2C9}']),
+    ('\u{FFE8}', &['\u{6C}']),
+    ('\u{FFED}', &['\u{25AA}']),
+    ('\u{10101}', &['\u{B7}']),
+    ('\u{1018E}', &['\u{4E}', '\u{30A}']),
+    ('\u{10196}', &['\u{58}', '\u{335}']),
+    ('\u{10197}', &['\u{56}', '\u{335}']),
+    ('\u{10198}', &['\u{6C}', '\u{335}', '\u{6C}', '\u{335}', '\u{53}', '\u{335}']),
+    ('\u{10199}', &['\u{6C}', '\u{335}', '\u{6C}', '\u{335}']),
+    ('\u{101A0}', &['\u{2CE8}']),
+    ('\u{10282}', &['\u{42}']),
+    ('\u{10285}', &['\u{394}']),
+    ('\u{10286}', &['\u{45}']),
+    ('\u{10287}', &['\u{46}']),
+    ('\u{1028A}', &['\u{6C}']),
+    ('\u{1028D}', &['\u{245}']),
+    ('\u{10290}', &['\u{58}']),
+    ('\u{10292}', &['\u{4F}']),
+    ('\u{10294}', &['\u{16DC}']),
+    ('\u{10295}', &['\u{50}']),
+    ('\u{10296}', &['\u{53}']),
+    ('\u{10297}', &['\u{54}']),
+    ('\u{1029B}', &['\u{2B}']),
+    ('\u{102A0}', &['\u{41}']),
+    ('\u{102A1}', &['\u{42}']),
+    ('\u{102A2}', &['\u{43}']),
+    ('\u{102A3}', &['\u{394}']),
+    ('\u{102A5}', &['\u{46}']),
+    ('\u{102AB}', &['\u{4F}']),
+    ('\u{102AD}', &['\u{3D8}']),
+    ('\u{102B0}', &['\u{4D}']),
+    ('\u{102B1}', &['\u{54}']),
+    ('\u{102B2}', &['\u{59}']),
+    ('\u{102B3}', &['\u{3A6}']),
+    ('\u{102B4}', &['\u{58}']),
+    ('\u{102B5}', &['\u{3A8}']),
+    ('\u{102B6}', &['\u{3A9}']),
+    ('\u{102B8}', &['\u{2D40}']),
+    ('\u{102CF}', &['\u{48}']),
+    ('\u{102E1}', &['\u{62F}']),
+    ('\u{102E4}', &['\u{648}']),
+    ('\u{102E8}', &['\u{637}']),
+    ('\u{102F2}', &['\u{635}']),
+    ('\u{102F5}', &['\u{5A}']),
+    ('\u{10301}', &['\u{42}']),
+    ('\u{10302}', &['\u{43}']),
+    ('\u{10309}', &['\u{6C}']),
+    ('\u{10311}', &['\u{4D}']),
+    ('\u{10312}', &['\u{3D8}']),
+    ('\u{10315}', &['\u{54}']),
+    ('\u{10317}', &['\u{58}']),
+    ('\u{1031A}', &['\u{38}']),
+    ('\u{1031F}', &['\u{2A}']),
+    ('\u{10320}', &['\u{6C}']),
+    ('\u{10322}', &['\u{58}']),
+    ('\u{103D1}', &['\u{10382}']),
+    ('\u{103D3}', &['\u{10393}']),
+    ('\u{10401}', &['\u{190}']),
+    ('\u{10404}', &['\u{4F}']),
+    ('\u{10411}', &['\u{A4F6}']),
+    ('\u{10415}', &['\u{43}']),
+    ('\u{1041B}', &['\u{4C}']),
+    ('\u{1041F}', &['\u{2C70}']),
+    ('\u{10420}', &['\u{53}']),
+    ('\u{10423}', &['\u{186}']),
+    ('\u{10425}', &['\u{418}']),
+    ('\u{10429}', &['\u{A793}']),
+    ('\u{1042A}', &['\u{29A}']),
+    ('\u{1042C}', &['\u{6F}']),
+    ('\u{1043D}', &['\u{63}']),
+    ('\u{1043F}', &['\u{277}']),
+    ('\u{10442}', &['\u{25E}']),
+    ('\u{10443}', &['\u{29F}']),
+    ('\u{10448}', &['\u{73}']),
+    ('\u{1044B}', &['\u{254}']),
+    ('\u{1044D}', &['\u{1D0E}']),
+    ('\u{104A0}', &['\u{10486}']),
+    ('\u{104B0}', &['\u{245}']),
+    ('\u{104B4}', &['\u{52}']),
+    ('\u{104BC}', &['\u{4C3}']),
+    ('\u{104C2}', &['\u{4F}']),
+    ('\u{104C3}', &['\u{298}']),
+    ('\u{104C4}', &['\u{DE}']),
+    ('\u{104CD}', &['\u{40B}']),
+    ('\u{104CE}', &['\u{55}']),
+    ('\u{104D0}', &['\u{16E6}']),
+    ('\u{104D1}', &['\u{3A8}']),
+    ('\u{104D2}', &['\u{37}']),
+    ('\u{104D8}', &['\u{28C}']),
+    ('\u{104DB}', &['\u{3BB}']),
+    ('\u{104EA}', &['\u{6F}']),
+    ('\u{104EB}', &['\u{A669}']),
+    ('\u{104F6}', &['\u{75}']),
+    ('\u{104F9}', &['\u{3C8}']),
+    ('\u{10513}', &['\u{4E}']),
+    ('\u{10516}', &['\u{4F}']),
+    ('\u{10518}', &['\u{4B}']),
+    ('\u{1051C}', &['\u{43}']),
+    ('\u{1051D}', &['\u{56}']),
+    ('\u{10525}', &['\u{46}']),
+    ('\u{10526}', &['\u{4C}']),
+    ('\u{10527}', &['\u{58}']),
+    ('\u{10A3A}', &['\u{323}']),
+    ('\u{10A50}', &['\u{2E}']),
+    ('\u{10A57}', &['\u{10A56}', '\u{10A56}']),
+    ('\u{10CFA}', &['\u{10CA5}']),
+    ('\u{10CFC}', &['\u{10C82}']),
+    ('\u{110BB}', &['\u{970}']),
+    ('\u{111C7}', &['\u{970}']),
+    ('\u{111CA}', &['\u{323}']),
+    ('\u{111CB}', &['\u{93A}']),
+    ('\u{111DB}', &['\u{A8FC}']),
+    ('\u{111DC}', &['\u{A8FB}']),
+    ('\u{111DE}', &['\u{2248}']),
+    ('\u{11300}', &['\u{30A}']),
+    ('\u{11413}', &['\u{11434}', '\u{11442}', '\u{11412}']),
+    ('\u{11419}', &['\u{11434}', '\u{11442}', '\u{11418}']),
+    ('\u{11424}', &['\u{11434}', '\u{11442}', '\u{11423}']),
+    ('\u{1142A}', &['\u{11434}', '\u{11442}', '\u{11429}']),
+    ('\u{1142D}', &['\u{11434}', '\u{11442}', '\u{1142C}']),
+    ('\u{1142F}', &['\u{11434}', '\u{11442}', '\u{1142E}']),
+    ('\u{1144C}', &['\u{1144B}', '\u{1144B}']),
+    ('\u{11492}', &['\u{998}']),
+    ('\u{11494}', &['\u{99A}']),
+    ('\u{11496}', &['\u{99C}']),
+    ('\u{11498}', &['\u{99E}']),
+    ('\u{11499}', &['\u{99F}']),
+    ('\u{1149B}', &['\u{9A1}']),
+    ('\u{1149D}', &['\u{9B2}']),
+    ('\u{1149E}', &['\u{9A4}']),
+    ('\u{1149F}', &['\u{9A5}']),
+    ('\u{114A0}', &['\u{9A6}']),
+    ('\u{114A1}', &['\u{9A7}']),
+    ('\u{114A2}', &['\u{9A8}']),
+    ('\u{114A3}', &['\u{9AA}']),
+    ('\u{114A7}', &['\u{9AE}']),
+    ('\u{114A8}', &['\u{9AF}']),
+    ('\u{114A9}', &['\u{9AC}']),
+    ('\u{114AA}', &['\u{9A3}']),
+    ('\u{114AB}', &['\u{9B0}']),
+    ('\u{114AD}', &['\u{9B7}']),
+    ('\u{114AE}', &['\u{9B8}']),
+    ('\u{114B0}', &['\u{9BE}']),
+    ('\u{114B1}', &['\u{9BF}']),
+    ('\u{114B9}', &['\u{9C7}']),
+    ('\u{114BC}', &['\u{9CB}']),
+    ('\u{114BD}', &['\u{9D7}']),
+    ('\u{114BE}', &['\u{9CC}']),
+    ('\u{114BF}', &['\u{306}', '\u{307}']),
+    ('\u{114C1}', &['\u{983}']),
+    ('\u{114C2}', &['\u{9CD}']),
+    ('\u{114C3}', &['\u{323}']),
+    ('\u{114C4}', &['\u{9BD}']),
+    ('\u{114C5}', &['\u{77}', '\u{307}']),
+    ('\u{114D0}', &['\u{4F}']),
+    ('\u{114D1}', &['\u{9E7}']),
+    ('\u{114D2}', &['\u{9E8}']),
+    ('\u{114D6}', &['\u{9EC}']),
+    ('\u{115D8}', &['\u{11582}']),
+    ('\u{115D9}', &['\u{11582}']),
+    ('\u{115DA}', &['\u{11583}']),
+    ('\u{115DB}', &['\u{11584}']),
+    ('\u{115DC}', &['\u{115B2}']),
+    ('\u{115DD}', &['\u{115B3}']),
+    ('\u{11642}', &['\u{11641}', '\u{11641}']),
+    ('\u{11700}', &['\u{72}', '\u{6E}']),
+    ('\u{11706}', &['\u{76}']),
+    ('\u{1170A}', &['\u{77}']),
+    ('\u{1170E}', &['\u{77}']),
+    ('\u{1170F}', &['\u{77}']),
+    ('\u{118A0}', &['\u{56}']),
+    ('\u{118A2}', &['\u{46}']),
+    ('\u{118A3}', &['\u{4C}']),
+    ('\u{118A4}', &['\u{59}']),
+    ('\u{118A6}', &['\u{45}']),
+    ('\u{118A8}', &['\u{2207}']),
+    ('\u{118A9}', &['\u{5A}']),
+    ('\u{118AC}', &['\u{39}']),
+    ('\u{118AE}', &['\u{45}']),
+    ('\u{118AF}', &['\u{34}']),
+    ('\u{118B2}', &['\u{4C}']),
+    ('\u{118B5}', &['\u{4F}']),
+    ('\u{118B7}', &['\u{16DC}']),
+    ('\u{118B8}', &['\u{55}']),
+    ('\u{118BB}', &['\u{35}']),
+    ('\u{118BC}', &['\u{54}']),
+    ('\u{118C0}', &['\u{76}']),
+    ('\u{118C1}', &['\u{73}']),
+    ('\u{118C2}', &['\u{46}']),
+    ('\u{118C3}', &['\u{69}']),
+    ('\u{118C4}', &['\u{7A}']),
+    ('\u{118C6}', &['\u{37}']),
+    ('\u{118C8}', &['\u{6F}']),
+    ('\u{118CA}', &['\u{33}']),
+    ('\u{118CC}', &['\u{39}']),
+    ('\u{118CE}', &['\u{A793}']),
+    ('\u{118D5}', &['\u{36}']),
+    ('\u{118D6}', &['\u{39}']),
+    ('\u{118D7}', &['\u{6F}']),
+    ('\u{118D8}', &['\u{75}']),
+    ('\u{118DC}', &['\u{79}']),
+    ('\u{118E0}', &['\u{4F}']),
+    ('\u{118E3}', &['\u{72}', '\u{6E}']),
+    ('\u{118E4}', &['\u{669}']),
+    ('\u{118E5}', &['\u{5A}']),
+    ('\u{118E6}', &['\u{57}']),
+    ('\u{118E9}', &['\u{43}']),
+    ('\u{118EC}', &['\u{58}']),
+    ('\u{118EF}', &['\u{57}']),
+    ('\u{118F2}', &['\u{43}']),
+    ('\u{11AE6}', &['\u{11AE5}', '\u{11AEF}']),
+    ('\u{11AE7}', &['\u{11AE5}', '\u{11AF0}']),
+    ('\u{11AE8}', &['\u{11AE5}', '\u{11AE5}']),
+    ('\u{11AE9}', &['\u{11AE5}', '\u{11AE5}', '\u{11AEF}']),
+    ('\u{11AEA}', &['\u{11AE5}', '\u{11AE5}', '\u{11AF0}']),
+    ('\u{11AEC}', &['\u{11AEB}', '\u{11AEF}']),
+    ('\u{11AED}', &['\u{11AEB}', '\u{11AEB}']),
+    ('\u{11AEE}', &['\u{11AEB}', '\u{11AEB}', '\u{11AEF}']),
+    ('\u{11AF4}', &['\u{11AF3}', '\u{11AEF}']),
+    ('\u{11AF5}', &['\u{11AF3}', '\u{11AF0}']),
+    ('\u{11AF6}', &['\u{11AF3}', '\u{11AF3}']),
+    ('\u{11AF7}', &['\u{11AF3}', '\u{11AF3}', '\u{11AEF}']),
+    ('\u{11AF8}', &['\u{11AF3}', '\u{11AF3}', '\u{11AF0}']),
+    ('\u{11C42}', &['\u{11C41}', '\u{11C41}']),
+    ('\u{11CB2}', &['\u{11CAA}']),
+    ('\u{12038}', &['\u{1039A}']),
+    ('\u{132F9}', &['\u{1099E}']),
+    ('\u{16F07}', &['\u{393}']),
+    ('\u{16F08}', &['\u{56}']),
+    ('\u{16F0A}', &['\u{54}']),
+    ('\u{16F16}', &['\u{4C}']),
+    ('\u{16F1A}', &['\u{394}']),
+    ('\u{16F1C}', &['\u{A658}']),
+    ('\u{16F26}', &['\u{A4F6}']),
+    ('\u{16F28}', &['\u{6C}']),
+    ('\u{16F2D}', &['\u{190}']),
+    ('\u{16F35}', &['\u{52}']),
+    ('\u{16F3A}', &['\u{53}']),
+    ('\u{16F3B}', &['\u{33}']),
+    ('\u{16F3D}', &['\u{245}']),
+    ('\u{16F3F}', &['\u{3E}']),
+    ('\u{16F40}', &['\u{41}']),
+    ('\u{16F42}', &['\u{55}']),
+    ('\u{16F43}', &['\u{59}']),
+    ('\u{16F51}', &['\u{27}']),
+    ('\u{16F52}', &['\u{27}']),
+    ('\u{1D114}', &['\u{7B}']),
+    ('\u{1D16D}', &['\u{2E}']),
+    ('\u{1D202}', &['\u{4FE}']),
+    ('\u{1D206}', &['\u{33}']),
+    ('\u{1D20B}', &['\u{418}']),
+    ('\u{1D20D}', &['\u{56}']),
+    ('\u{1D20F}', &['\u{5C}']),
+    ('\u{1D212}', &['\u{37}']),
+    ('\u{1D213}', &['\u{46}']),
+    ('\u{1D214}', &['\u{102BC}']),
+    ('\u{1D215}', &['\u{A4F6}']),
+    ('\u{1D216}', &['\u{52}']),
+    ('\u{1D217}', &['\u{2C6F}']),
+    ('\u{1D21A}', &['\u{4F}', '\u{335}']),
+    ('\u{1D21B}', &['\u{2144}']),
+    ('\u{1D21C}', &['\u{A4D5}']),
+    ('\u{1D221}', &['\u{190}']),
+    ('\u{1D222}', &['\u{460}']),
+    ('\u{1D22A}', &['\u{4C}']),
+    ('\u{1D22B}', &['\u{A4F6}']),
+    ('\u{1D230}', &['\u{A7FB}']),
+    ('\u{1D236}', &['\u{3C}']),
+    ('\u{1D237}', &['\u{3E}']),
+    ('\u{1D238}', &['\u{228F}']),
+    ('\u{1D239}', &['\u{2290}']),
+    ('\u{1D23A}', &['\u{2F}']),
+    ('\u{1D23B}', &['\u{5C}']),
+    ('\u{1D23F}', &['\u{16CB}']),
+    ('\u{1D245}', &['\u{548}']),
+    ('\u{1D400}', &['\u{41}']),
+    ('\u{1D401}', &['\u{42}']),
+    ('\u{1D402}', &['\u{43}']),
+    ('\u{1D403}', &['\u{44}']),
+    ('\u{1D404}', &['\u{45}']),
+    ('\u{1D405}', &['\u{46}']),
+    ('\u{1D406}', &['\u{47}']),
+    ('\u{1D407}', &['\u{48}']),
+    ('\u{1D408}', &['\u{6C}']),
+    ('\u{1D409}', &['\u{4A}']),
+    ('\u{1D40A}', &['\u{4B}']),
+    ('\u{1D40B}', &['\u{4C}']),
+    ('\u{1D40C}', &['\u{4D}']),
+    ('\u{1D40D}', &['\u{4E}']),
+    ('\u{1D40E}', &['\u{4F}']),
+    ('\u{1D40F}', &['\u{50}']),
+    ('\u{1D410}', &['\u{51}']),
+    ('\u{1D411}', &['\u{52}']),
+    ('\u{1D412}', &['\u{53}']),
+    ('\u{1D413}', &['\u{54}']),
+    ('\u{1D414}', &['\u{55}']),
+    ('\u{1D415}', &['\u{56}']),
+    ('\u{1D416}', &['\u{57}']),
+    ('\u{1D417}', &['\u{58}']),
+    ('\u{1D418}', &['\u{59}']),
+    ('\u{1D419}', &['\u{5A}']),
+    ('\u{1D41A}', &['\u{61}']),
+    ('\u{1D41B}', &['\u{62}']),
+    ('\u{1D41C}', &['\u{63}']),
+    ('\u{1D41D}', &['\u{64}']),
+    ('\u{1D41E}', &['\u{65}']),
+    ('\u{1D41F}', &['\u{66}']),
+    ('\u{1D420}', &['\u{67}']),
+    ('\u{1D421}', &['\u{68}']),
+    ('\u{1D422}', &['\u{69}']),
+    ('\u{1D423}', &['\u{6A}']),
+    ('\u{1D424}', &['\u{6B}']),
+    ('\u{1D425}', &['\u{6C}']),
+    ('\u{1D426}', &['\u{72}', '\u{6E}']),
+    ('\u{1D427}', &['\u{6E}']),
+    ('\u{1D428}', &['\u{6F}']),
+    ('\u{1D429}', &['\u{70}']),
+    ('\u{1D42A}', &['\u{71}']),
+    ('\u{1D42B}', &['\u{72}']),
+    ('\u{1D42C}', &['\u{73}']),
+    ('\u{1D42D}', &['\u{74}']),
+    ('\u{1D42E}', &['\u{75}']),
+    ('\u{1D42F}', &['\u{76}']),
+    ('\u{1D430}', &['\u{77}']),
+    ('\u{1D431}', &['\u{78}']),
+    ('\u{1D432}', &['\u{79}']),
+    ('\u{1D433}', &['\u{7A}']),
+    ('\u{1D434}', &['\u{41}']),
+    ('\u{1D435}', &['\u{42}']),
+    ('\u{1D436}', &['\u{43}']),
+    ('\u{1D437}', &['\u{44}']),
+    ('\u{1D438}', &['\u{45}']),
+    ('\u{1D439}', &['\u{46}']),
+    ('\u{1D43A}', &['\u{47}']),
+    ('\u{1D43B}', &['\u{48}']),
+    ('\u{1D43C}', &['\u{6C}']),
+    ('\u{1D43D}', &['\u{4A}']),
+    ('\u{1D43E}', &['\u{4B}']),
+    ('\u{1D43F}', &['\u{4C}']),
+    ('\u{1D440}', &['\u{4D}']),
+    ('\u{1D441}', &['\u{4E}']),
+    ('\u{1D442}', &['\u{4F}']),
+    ('\u{1D443}', &['\u{50}']),
+    ('\u{1D444}', &['\u{51}']),
+    ('\u{1D445}', &['\u{52}']),
+    ('\u{1D446}', &['\u{53}']),
+    ('\u{1D447}', &['\u{54}']),
+    ('\u{1D448}', &['\u{55}']),
+    ('\u{1D449}', &['\u{56}']),
+    ('\u{1D44A}', &['\u{57}']),
+    ('\u{1D44B}', &['\u{58}']),
+    ('\u{1D44C}', &['\u{59}']),
+    ('\u{1D44D}', &['\u{5A}']),
+    ('\u{1D44E}', &['\u{61}']),
+    ('\u{1D44F}', &['\u{62}']),
+    ('\u{1D450}', &['\u{63}']),
+    ('\u{1D451}', &['\u{64}']),
+    ('\u{1D452}', &['\u{65}']),
+    ('\u{1D453}', &['\u{66}']),
+    ('\u{1D454}', &['\u{67}']),
+    ('\u{1D456}', &['\u{69}']),
+    ('\u{1D457}', &['\u{6A}']),
+    ('\u{1D458}', &['\u{6B}']),
+    ('\u{1D459}', &['\u{6C}']),
+    ('\u{1D45A}', &['\u{72}', '\u{6E}']),
+    ('\u{1D45B}', &['\u{6E}']),
+    ('\u{1D45C}', &['\u{6F}']),
+    ('\u{1D45D}', &['\u{70}']),
+    ('\u{1D45E}', &['\u{71}']),
+    ('\u{1D45F}', &['\u{72}']),
+    ('\u{1D460}', &['\u{73}']),
+    ('\u{1D461}', &['\u{74}']),
+    ('\u{1D462}', &['\u{75}']),
+    ('\u{1D463}', &['\u{76}']),
+    ('\u{1D464}', &['\u{77}']),
+    ('\u{1D465}', &['\u{78}']),
+    ('\u{1D466}', &['\u{79}']),
+    ('\u{1D467}', &['\u{7A}']),
+    ('\u{1D468}', &['\u{41}']),
+    ('\u{1D469}', &['\u{42}']),
+    ('\u{1D46A}', &['\u{43}']),
+    ('\u{1D46B}', &['\u{44}']),
+    ('\u{1D46C}', &['\u{45}']),
+    ('\u{1D46D}', &['\u{46}']),
+    ('\u{1D46E}', &['\u{47}']),
+    ('\u{1D46F}', &['\u{48}']),
+    ('\u{1D470}', &['\u{6C}']),
+    ('\u{1D471}', &['\u{4A}']),
+    ('\u{1D472}', &['\u{4B}']),
+    ('\u{1D473}', &['\u{4C}']),
+    ('\u{1D474}', &['\u{4D}']),
+    ('\u{1D475}', &['\u{4E}']),
+    ('\u{1D476}', &['\u{4F}']),
+    ('\u{1D477}', &['\u{50}']),
+    ('\u{1D478}', &['\u{51}']),
+    ('\u{1D479}', &['\u{52}']),
+    ('\u{1D47A}', &['\u{53}']),
+    ('\u{1D47B}', &['\u{54}']),
+    ('\u{1D47C}', &['\u{55}']),
+    ('\u{1D47D}', &['\u{56}']),
+    ('\u{1D47E}', &['\u{57}']),
+    ('\u{1D47F}', &['\u{58}']),
+    ('\u{1D480}', &['\u{59}']),
+    ('\u{1D481}', &['\u{5A}']),
+    ('\u{1D482}', &['\u{61}']),
+    ('\u{1D483}', &['\u{62}']),
+    ('\u{1D484}', &['\u{63}']),
+    ('\u{1D485}', &['\u{64}']),
+    ('\u{1D486}', &['\u{65}']),
+    ('\u{1D487}', &['\u{66}']),
+    ('\u{1D488}', &['\u{67}']),
+    ('\u{1D489}', &['\u{68}']),
+    ('\u{1D48A}', &['\u{69}']),
+    ('\u{1D48B}', &['\u{6A}']),
+    ('\u{1D48C}', &['\u{6B}']),
+    ('\u{1D48D}', &['\u{6C}']),
+    ('\u{1D48E}', &['\u{72}', '\u{6E}']),
+    ('\u{1D48F}', &['\u{6E}']),
+    ('\u{1D490}', &['\u{6F}']),
+    ('\u{1D491}', &['\u{70}']),
+    ('\u{1D492}', &['\u{71}']),
+    ('\u{1D493}', &['\u{72}']),
+    ('\u{1D494}', &['\u{73}']),
+    ('\u{1D495}', &['\u{74}']),
+    ('\u{1D496}', &['\u{75}']),
+    ('\u{1D497}', &['\u{76}']),
+    ('\u{1D498}', &['\u{77}']),
+    ('\u{1D499}', &['\u{78}']),
+    ('\u{1D49A}', &['\u{79}']),
+    ('\u{1D49B}', &['\u{7A}']),
+    ('\u{1D49C}', &['\u{41}']),
+    ('\u{1D49E}', &['\u{43}']),
+    ('\u{1D49F}', &['\u{44}']),
+    ('\u{1D4A2}', &['\u{47}']),
+    ('\u{1D4A5}', &['\u{4A}']),
+    ('\u{1D4A6}', &['\u{4B}']),
+    ('\u{1D4A9}', &['\u{4E}']),
+    ('\u{1D4AA}', &['\u{4F}']),
+    ('\u{1D4AB}', &['\u{50}']),
+    ('\u{1D4AC}', &['\u{51}']),
+    ('\u{1D4AE}', &['\u{53}']),
+    ('\u{1D4AF}', &['\u{54}']),
+    ('\u{1D4B0}', &['\u{55}']),
+    ('\u{1D4B1}', &['\u{56}']),
+    ('\u{1D4B2}', &['\u{57}']),
+    ('\u{1D4B3}', &['\u{58}']),
+    ('\u{1D4B4}', &['\u{59}']),
+    ('\u{1D4B5}', &['\u{5A}']),
+    ('\u{1D4B6}', &['\u{61}']),
+    ('\u{1D4B7}', &['\u{62}']),
+    ('\u{1D4B8}', &['\u{63}']),
+    ('\u{1D4B9}', &['\u{64}']),
+    ('\u{1D4BB}', &['\u{66}']),
+    ('\u{1D4BD}', &['\u{68}']),
+    ('\u{1D4BE}', &['\u{69}']),
+    ('\u{1D4BF}', &['\u{6A}']),
+    ('\u{1D4C0}', &['\u{6B}']),
+    ('\u{1D4C1}', &['\u{6C}']),
+    ('\u{1D4C2}', &['\u{72}', '\u{6E}']),
+    ('\u{1D4C3}', &['\u{6E}']),
+    ('\u{1D4C5}', &['\u{70}']),
+    ('\u{1D4C6}', &['\u{71}']),
+    ('\u{1D4C7}', &['\u{72}']),
+    ('\u{1D4C8}', &['\u{73}']),
+    ('\u{1D4C9}', &['\u{74}']),
+    ('\u{1D4CA}', &['\u{75}']),
+    ('\u{1D4CB}', &['\u{76}']),
+    ('\u{1D4CC}', &['\u{77}']),
+    ('\u{1D4CD}', &['\u{78}']),
+    ('\u{1D4CE}', &['\u{79}']),
+    ('\u{1D4CF}', &['\u{7A}']),
+    ('\u{1D4D0}', &['\u{41}']),
+    ('\u{1D4D1}', &['\u{42}']),
+    ('\u{1D4D2}', &['\u{43}']),
+    ('\u{1D4D3}', &['\u{44}']),
+    ('\u{1D4D4}', &['\u{45}']),
+    ('\u{1D4D5}', &['\u{46}']),
+    ('\u{1D4D6}', &['\u{47}']),
+    ('\u{1D4D7}', &['\u{48}']),
+    ('\u{1D4D8}', &['\u{6C}']),
+    ('\u{1D4D9}', &['\u{4A}']),
+    ('\u{1D4DA}', &['\u{4B}']),
+    ('\u{1D4DB}', &['\u{4C}']),
+    ('\u{1D4DC}', &['\u{4D}']),
+    ('\u{1D4DD}', &['\u{4E}']),
+    ('\u{1D4DE}', &['\u{4F}']),
+    ('\u{1D4DF}', &['\u{50}']),
+    ('\u{1D4E0}', &['\u{51}']),
+    ('\u{1D4E1}', &['\u{52}']),
+    ('\u{1D4E2}', &['\u{53}']),
+    ('\u{1D4E3}', &['\u{54}']),
+    ('\u{1D4E4}', &['\u{55}']),
+    ('\u{1D4E5}', &['\u{56}']),
+    ('\u{1D4E6}', &['\u{57}']),
+    ('\u{1D4E7}', &['\u{58}']),
+    ('\u{1D4E8}', &['\u{59}']),
+    ('\u{1D4E9}', &['\u{5A}']),
+    ('\u{1D4EA}', &['\u{61}']),
+    ('\u{1D4EB}', &['\u{62}']),
+    ('\u{1D4EC}', &['\u{63}']),
+    ('\u{1D4ED}', &['\u{64}']),
+    ('\u{1D4EE}', &['\u{65}']),
+    ('\u{1D4EF}', &['\u{66}']),
+    ('\u{1D4F0}', &['\u{67}']),
+    ('\u{1D4F1}', &['\u{68}']),
+    ('\u{1D4F2}', &['\u{69}']),
+    ('\u{1D4F3}', &['\u{6A}']),
+    ('\u{1D4F4}', &['\u{6B}']),
+    ('\u{1D4F5}', &['\u{6C}']),
+    ('\u{1D4F6}', &['\u{72}', '\u{6E}']),
+    ('\u{1D4F7}', &['\u{6E}']),
+    ('\u{1D4F8}', &['\u{6F}']),
+    ('\u{1D4F9}', &['\u{70}']),
+    ('\u{1D4FA}', &['\u{71}']),
+    ('\u{1D4FB}', &['\u{72}']),
+    ('\u{1D4FC}', &['\u{73}']),
+    ('\u{1D4FD}', &['\u{74}']),
+    ('\u{1D4FE}', &['\u{75}']),
+    ('\u{1D4FF}', &['\u{76}']),
+    ('\u{1D500}', &['\u{77}']),
+    ('\u{1D501}', &['\u{78}']),
+    ('\u{1D502}', &['\u{79}']),
+    ('\u{1D503}', &['\u{7A}']),
+    ('\u{1D504}', &['\u{41}']),
+    ('\u{1D505}', &['\u{42}']),
+    ('\u{1D507}', &['\u{44}']),
+    ('\u{1D508}', &['\u{45}']),
+    ('\u{1D509}', &['\u{46}']),
+    ('\u{1D50A}', &['\u{47}']),
+    ('\u{1D50D}', &['\u{4A}']),
+    ('\u{1D50E}', &['\u{4B}']),
+    ('\u{1D50F}', &['\u{4C}']),
+    ('\u{1D510}', &['\u{4D}']),
+    ('\u{1D511}', &['\u{4E}']),
+    ('\u{1D512}', &['\u{4F}']),
+    ('\u{1D513}', &['\u{50}']),
+    ('\u{1D514}', &['\u{51}']),
+    ('\u{1D516}', &['\u{53}']),
+    ('\u{1D517}', &['\u{54}']),
+    ('\u{1D518}', &['\u{55}']),
+    ('\u{1D519}', &['\u{56}']),
+    ('\u{1D51A}', &['\u{57}']),
+    ('\u{1D51B}', &['\u{58}']),
+    ('\u{1D51C}', &['\u{59}']),
+    ('\u{1D51E}', &['\u{61}']),
+    ('\u{1D51F}', &['\u{62}']),
+    ('\u{1D520}', &['\u{63}']),
+    ('\u{1D521}', &['\u{64}']),
+    ('\u{1D522}', &['\u{65}']),
+    ('\u{1D523}', &['\u{66}']),
+    ('\u{1D524}', &['\u{67}']),
+    ('\u{1D525}', &['\u{68}']),
+    ('\u{1D526}', &['\u{69}']),
+    ('\u{1D527}', &['\u{6A}']),
+    ('\u{1D528}', &['\u{6B}']),
+    ('\u{1D529}', &['\u{6C}']),
+    ('\u{1D52A}', &['\u{72}', '\u{6E}']),
+    ('\u{1D52B}', &['\u{6E}']),
+    ('\u{1D52C}', &['\u{6F}']),
+    ('\u{1D52D}', &['\u{70}']),
+    ('\u{1D52E}', &['\u{71}']),
+    ('\u{1D52F}', &['\u{72}']),
+    ('\u{1D530}', &['\u{73}']),
+    ('\u{1D531}', &['\u{74}']),
+    ('\u{1D532}', &['\u{75}']),
+    ('\u{1D533}', &['\u{76}']),
+    ('\u{1D534}', &['\u{77}']),
+    ('\u{1D535}', &['\u{78}']),
+    ('\u{1D536}', &['\u{79}']),
+    ('\u{1D537}', &['\u{7A}']),
+    ('\u{1D538}', &['\u{41}']),
+    ('\u{1D539}', &['\u{42}']),
+    ('\u{1D53B}', &['\u{44}']),
+    ('\u{1D53C}', &['\u{45}']),
+    ('\u{1D53D}', &['\u{46}']),
+    ('\u{1D53E}', &['\u{47}']),
+    ('\u{1D540}', &['\u{6C}']),
+    ('\u{1D541}', &['\u{4A}']),
+    ('\u{1D542}', &['\u{4B}']),
+    ('\u{1D543}', &['\u{4C}']),
+    ('\u{1D544}', &['\u{4D}']),
+    ('\u{1D546}', &['\u{4F}']),
+    ('\u{1D54A}', &['\u{53}']),
+    ('\u{1D54B}', &['\u{54}']),
+    ('\u{1D54C}', &['\u{55}']),
+    ('\u{1D54D}', &['\u{56}']),
+    ('\u{1D54E}', &['\u{57}']),
+    ('\u{1D54F}', &['\u{58}']),
+    ('\u{1D550}', &['\u{59}']),
+    ('\u{1D552}', &['\u{61}']),
+    ('\u{1D553}', &['\u{62}']),
+    ('\u{1D554}', &['\u{63}']),
+    ('\u{1D555}', &['\u{64}']),
+    ('\u{1D556}', &['\u{65}']),
+    ('\u{1D557}', &['\u{66}']),
+    ('\u{1D558}', &['\u{67}']),
+    ('\u{1D559}', &['\u{68}']),
+    ('\u{1D55A}', &['\u{69}']),
+    ('\u{1D55B}', &['\u{6A}']),
+    ('\u{1D55C}', &['\u{6B}']),
+    ('\u{1D55D}', &['\u{6C}']),
+    ('\u{1D55E}', &['\u{72}', '\u{6E}']),
+    ('\u{1D55F}', &['\u{6E}']),
+    ('\u{1D560}', &['\u{6F}']),
+    ('\u{1D561}', &['\u{70}']),
+    ('\u{1D562}', &['\u{71}']),
+    ('\u{1D563}', &['\u{72}']),
+    ('\u{1D564}', &['\u{73}']),
+    ('\u{1D565}', &['\u{74}']),
+    ('\u{1D566}', &['\u{75}']),
+    ('\u{1D567}', &['\u{76}']),
+    ('\u{1D568}', &['\u{77}']),
+    ('\u{1D569}', &['\u{78}']),
+    ('\u{1D56A}', &['\u{79}']),
+    ('\u{1D56B}', &['\u{7A}']),
+    ('\u{1D56C}', &['\u{41}']),
+    ('\u{1D56D}', &['\u{42}']),
+    ('\u{1D56E}', &['\u{43}']),
+    ('\u{1D56F}', &['\u{44}']),
+    ('\u{1D570}', &['\u{45}']),
+    ('\u{1D571}', &['\u{46}']),
+    ('\u{1D572}', &['\u{47}']),
+    ('\u{1D573}', &['\u{48}']),
+    ('\u{1D574}', &['\u{6C}']),
+    ('\u{1D575}', &['\u{4A}']),
+    ('\u{1D576}', &['\u{4B}']),
+    ('\u{1D577}', &['\u{4C}']),
+    ('\u{1D578}', &['\u{4D}']),
+    ('\u{1D579}', &['\u{4E}']),
+    ('\u{1D57A}', &['\u{4F}']),
+    ('\u{1D57B}', &['\u{50}']),
+    ('\u{1D57C}', &['\u{51}']),
+    ('\u{1D57D}', &['\u{52}']),
+    ('\u{1D57E}', &['\u{53}']),
+    ('\u{1D57F}', &['\u{54}']),
+    ('\u{1D580}', &['\u{55}']),
+    ('\u{1D581}', &['\u{56}']),
+    ('\u{1D582}', &['\u{57}']),
+    ('\u{1D583}', &['\u{58}']),
+    ('\u{1D584}', &['\u{59}']),
+    ('\u{1D585}', &['\u{5A}']),
+    ('\u{1D586}', &['\u{61}']),
+    ('\u{1D587}', &['\u{62}']),
+    ('\u{1D588}', &['\u{63}']),
+    ('\u{1D589}', &['\u{64}']),
+    ('\u{1D58A}', &['\u{65}']),
+    ('\u{1D58B}', &['\u{66}']),
+    ('\u{1D58C}', &['\u{67}']),
+    ('\u{1D58D}', &['\u{68}']),
+    ('\u{1D58E}', &['\u{69}']),
+    ('\u{1D58F}', &['\u{6A}']),
+    ('\u{1D590}', &['\u{6B}']),
+    ('\u{1D591}', &['\u{6C}']),
+    ('\u{1D592}', &['\u{72}', '\u{6E}']),
+    ('\u{1D593}', &['\u{6E}']),
+    ('\u{1D594}', &['\u{6F}']),
+    ('\u{1D595}', &['\u{70}']),
+    ('\u{1D596}', &['\u{71}']),
+    ('\u{1D597}', &['\u{72}']),
+    ('\u{1D598}', &['\u{73}']),
+    ('\u{1D599}', &['\u{74}']),
+    ('\u{1D59A}', &['\u{75}']),
+    ('\u{1D59B}', &['\u{76}']),
+    ('\u{1D59C}', &['\u{77}']),
+    ('\u{1D59D}', &['\u{78}']),
+    ('\u{1D59E}', &['\u{79}']),
+    ('\u{1D59F}', &['\u{7A}']),
+    ('\u{1D5A0}', &['\u{41}']),
+    ('\u{1D5A1}', &['\u{42}']),
+    ('\u{1D5A2}', &['\u{43}']),
+    ('\u{1D5A3}', &['\u{44}']),
+    ('\u{1D5A4}', &['\u{45}']),
+    ('\u{1D5A5}', &['\u{46}']),
+    ('\u{1D5A6}', &['\u{47}']),
+    ('\u{1D5A7}', &['\u{48}']),
+    ('\u{1D5A8}', &['\u{6C}']),
+    ('\u{1D5A9}', &['\u{4A}']),
+    ('\u{1D5AA}', &['\u{4B}']),
+    ('\u{1D5AB}', &['\u{4C}']),
+    ('\u{1D5AC}', &['\u{4D}']),
+    ('\u{1D5AD}', &['\u{4E}']),
+    ('\u{1D5AE}', &['\u{4F}']),
+    ('\u{1D5AF}', &['\u{50}']),
+    ('\u{1D5B0}', &['\u{51}']),
+    ('\u{1D5B1}', &['\u{52}']),
+    ('\u{1D5B2}', &['\u{53}']),
+    ('\u{1D5B3}', &['\u{54}']),
+    ('\u{1D5B4}', &['\u{55}']),
+    ('\u{1D5B5}', &['\u{56}']),
+    ('\u{1D5B6}', &['\u{57}']),
+    ('\u{1D5B7}', &['\u{58}']),
+    ('\u{1D5B8}', &['\u{59}']),
+    ('\u{1D5B9}', &['\u{5A}']),
+    ('\u{1D5BA}', &['\u{61}']),
+    ('\u{1D5BB}', &['\u{62}']),
+    ('\u{1D5BC}', &['\u{63}']),
+    ('\u{1D5BD}', &['\u{64}']),
+    ('\u{1D5BE}', &['\u{65}']),
+    ('\u{1D5BF}', &['\u{66}']),
+    ('\u{1D5C0}', &['\u{67}']),
+    ('\u{1D5C1}', &['\u{68}']),
+    ('\u{1D5C2}', &['\u{69}']),
+    ('\u{1D5C3}', &['\u{6A}']),
+    ('\u{1D5C4}', &['\u{6B}']),
+    ('\u{1D5C5}', &['\u{6C}']),
+    ('\u{1D5C6}', &['\u{72}', '\u{6E}']),
+    ('\u{1D5C7}', &['\u{6E}']),
+    ('\u{1D5C8}', &['\u{6F}']),
+    ('\u{1D5C9}', &['\u{70}']),
+    ('\u{1D5CA}', &['\u{71}']),
+    ('\u{1D5CB}', &['\u{72}']),
+    ('\u{1D5CC}', &['\u{73}']),
+    ('\u{1D5CD}', &['\u{74}']),
+    ('\u{1D5CE}', &['\u{75}']),
+    ('\u{1D5CF}', &['\u{76}']),
+    ('\u{1D5D0}', &['\u{77}']),
+    ('\u{1D5D1}', &['\u{78}']),
+    ('\u{1D5D2}', &['\u{79}']),
+    ('\u{1D5D3}', &['\u{7A}']),
+    ('\u{1D5D4}', &['\u{41}']),
+    ('\u{1D5D5}', &['\u{42}']),
+    ('\u{1D5D6}', &['\u{43}']),
+    ('\u{1D5D7}', &['\u{44}']),
+    ('\u{1D5D8}', &['\u{45}']),
+    ('\u{1D5D9}', &['\u{46}']),
+    ('\u{1D5DA}', &['\u{47}']),
+    ('\u{1D5DB}', &['\u{48}']),
+    ('\u{1D5DC}', &['\u{6C}']),
+    ('\u{1D5DD}', &['\u{4A}']),
+    ('\u{1D5DE}', &['\u{4B}']),
+    ('\u{1D5DF}', &['\u{4C}']),
+    ('\u{1D5E0}', &['\u{4D}']),
+    ('\u{1D5E1}', &['\u{4E}']),
+    ('\u{1D5E2}', &['\u{4F}']),
+    ('\u{1D5E3}', &['\u{50}']),
+    ('\u{1D5E4}', &['\u{51}']),
+    ('\u{1D5E5}', &['\u{52}']),
+    ('\u{1D5E6}', &['\u{53}']),
+    ('\u{1D5E7}', &['\u{54}']),
+    ('\u{1D5E8}', &['\u{55}']),
+    ('\u{1D5E9}', &['\u{56}']),
+    ('\u{1D5EA}', &['\u{57}']),
+    ('\u{1D5EB}', &['\u{58}']),
+    ('\u{1D5EC}', &['\u{59}']),
+    ('\u{1D5ED}', &['\u{5A}']),
+    ('\u{1D5EE}', &['\u{61}']),
+    ('\u{1D5EF}', &['\u{62}']),
+    ('\u{1D5F0}', &['\u{63}']),
+    ('\u{1D5F1}', &['\u{64}']),
+    ('\u{1D5F2}', &['\u{65}']),
+    ('\u{1D5F3}', &['\u{66}']),
+    ('\u{1D5F4}', &['\u{67}']),
+    ('\u{1D5F5}', &['\u{68}']),
+    ('\u{1D5F6}', &['\u{69}']),
+    ('\u{1D5F7}', &['\u{6A}']),
+    ('\u{1D5F8}', &['\u{6B}']),
+    ('\u{1D5F9}', &['\u{6C}']),
+    ('\u{1D5FA}', &['\u{72}', '\u{6E}']),
+    ('\u{1D5FB}', &['\u{6E}']),
+    ('\u{1D5FC}', &['\u{6F}']),
+    ('\u{1D5FD}', &['\u{70}']),
+    ('\u{1D5FE}', &['\u{71}']),
+    ('\u{1D5FF}', &['\u{72}']),
+    ('\u{1D600}', &['\u{73}']),
+    ('\u{1D601}', &['\u{74}']),
+    ('\u{1D602}', &['\u{75}']),
+    ('\u{1D603}', &['\u{76}']),
+    ('\u{1D604}', &['\u{77}']),
+    ('\u{1D605}', &['\u{78}']),
+    ('\u{1D606}', &['\u{79}']),
+    ('\u{1D607}', &['\u{7A}']),
+    ('\u{1D608}', &['\u{41}']),
+    ('\u{1D609}', &['\u{42}']),
+    ('\u{1D60A}', &['\u{43}']),
+    ('\u{1D60B}', &['\u{44}']),
+    ('\u{1D60C}', &['\u{45}']),
+    ('\u{1D60D}', &['\u{46}']),
+    ('\u{1D60E}', &['\u{47}']),
+    ('\u{1D60F}', &['\u{48}']),
+    ('\u{1D610}', &['\u{6C}']),
+    ('\u{1D611}', &['\u{4A}']),
+    ('\u{1D612}', &['\u{4B}']),
+    ('\u{1D613}', &['\u{4C}']),
+    ('\u{1D614}', &['\u{4D}']),
+    ('\u{1D615}', &['\u{4E}']),
+    ('\u{1D616}', &['\u{4F}']),
+    ('\u{1D617}', &['\u{50}']),
+    ('\u{1D618}', &['\u{51}']),
+    ('\u{1D619}', &['\u{52}']),
+    ('\u{1D61A}', &['\u{53}']),
+    ('\u{1D61B}', &['\u{54}']),
+    ('\u{1D61C}', &['\u{55}']),
+    ('\u{1D61D}', &['\u{56}']),
+    ('\u{1D61E}', &['\u{57}']),
+    ('\u{1D61F}', &['\u{58}']),
+    ('\u{1D620}', &['\u{59}']),
+    ('\u{1D621}', &['\u{5A}']),
+    ('\u{1D622}', &['\u{61}']),
+    ('\u{1D623}', &['\u{62}']),
+    ('\u{1D624}', &['\u{63}']),
+    ('\u{1D625}', &['\u{64}']),
+    ('\u{1D626}', &['\u{65}']),
+    ('\u{1D627}', &['\u{66}']),
+    ('\u{1D628}', &['\u{67}']),
+    ('\u{1D629}', &['\u{68}']),
+    ('\u{1D62A}', &['\u{69}']),
+    ('\u{1D62B}', &['\u{6A}']),
+    ('\u{1D62C}', &['\u{6B}']),
+    ('\u{1D62D}', &['\u{6C}']),
+    ('\u{1D62E}', &['\u{72}', '\u{6E}']),
+    ('\u{1D62F}', &['\u{6E}']),
+    ('\u{1D630}', &['\u{6F}']),
+    ('\u{1D631}', &['\u{70}']),
+    ('\u{1D632}', &['\u{71}']),
+    ('\u{1D633}', &['\u{72}']),
+    ('\u{1D634}', &['\u{73}']),
+    ('\u{1D635}', &['\u{74}']),
+    ('\u{1D636}', &['\u{75}']),
+    ('\u{1D637}', &['\u{76}']),
+    ('\u{1D638}', &['\u{77}']),
+    ('\u{1D639}', &['\u{78}']),
+    ('\u{1D63A}', &['\u{79}']),
+    ('\u{1D63B}', &['\u{7A}']),
+    ('\u{1D63C}', &['\u{41}']),
+    ('\u{1D63D}', &['\u{42}']),
+    ('\u{1D63E}', &['\u{43}']),
+    ('\u{1D63F}', &['\u{44}']),
+    ('\u{1D640}', &['\u{45}']),
+    ('\u{1D641}', &['\u{46}']),
+    ('\u{1D642}', &['\u{47}']),
+    ('\u{1D643}', &['\u{48}']),
+    ('\u{1D644}', &['\u{6C}']),
+    ('\u{1D645}', &['\u{4A}']),
+    ('\u{1D646}', &['\u{4B}']),
+    ('\u{1D647}', &['\u{4C}']),
+    ('\u{1D648}', &['\u{4D}']),
+    ('\u{1D649}', &['\u{4E}']),
+    ('\u{1D64A}', &['\u{4F}']),
+    ('\u{1D64B}', &['\u{50}']),
+    ('\u{1D64C}', &['\u{51}']),
+    ('\u{1D64D}', &['\u{52}']),
+    ('\u{1D64E}', &['\u{53}']),
+    ('\u{1D64F}', &['\u{54}']),
+    ('\u{1D650}', &['\u{55}']),
+    ('\u{1D651}', &['\u{56}']),
+    ('\u{1D652}', &['\u{57}']),
+    ('\u{1D653}', &['\u{58}']),
+    ('\u{1D654}', &['\u{59}']),
+    ('\u{1D655}', &['\u{5A}']),
+    ('\u{1D656}', &['\u{61}']),
+    ('\u{1D657}', &['\u{62}']),
+    ('\u{1D658}', &['\u{63}']),
+    ('\u{1D659}', &['\u{64}']),
+    ('\u{1D65A}', &['\u{65}']),
+    ('\u{1D65B}', &['\u{66}']),
+    ('\u{1D65C}', &['\u{67}']),
+    ('\u{1D65D}', &['\u{68}']),
+    ('\u{1D65E}', &['\u{69}']),
+    ('\u{1D65F}', &['\u{6A}']),
+    ('\u{1D660}', &['\u{6B}']),
+    ('\u{1D661}', &['\u{6C}']),
+    ('\u{1D662}', &['\u{72}', '\u{6E}']),
+    ('\u{1D663}', &['\u{6E}']),
+    ('\u{1D664}', &['\u{6F}']),
+    ('\u{1D665}', &['\u{70}']),
+    ('\u{1D666}', &['\u{71}']),
+    ('\u{1D667}', &['\u{72}']),
+    ('\u{1D668}', &['\u{73}']),
+    ('\u{1D669}', &['\u{74}']),
+    ('\u{1D66A}', &['\u{75}']),
+    ('\u{1D66B}', &['\u{76}']),
+    ('\u{1D66C}', &['\u{77}']),
+    ('\u{1D66D}', &['\u{78}']),
+    ('\u{1D66E}', &['\u{79}']),
+    ('\u{1D66F}', &['\u{7A}']),
+    ('\u{1D670}', &['\u{41}']),
+    ('\u{1D671}', &['\u{42}']),
+    ('\u{1D672}', &['\u{43}']),
+    ('\u{1D673}', &['\u{44}']),
+    ('\u{1D674}', &['\u{45}']),
+    ('\u{1D675}', &['\u{46}']),
+    ('\u{1D676}', &['\u{47}']),
+    ('\u{1D677}', &['\u{48}']),
+    ('\u{1D678}', &['\u{6C}']),
+    ('\u{1D679}', &['\u{4A}']),
+    ('\u{1D67A}', &['\u{4B}']),
+    ('\u{1D67B}', &['\u{4C}']),
+    ('\u{1D67C}', &['\u{4D}']),
+    ('\u{1D67D}', &['\u{4E}']),
+    ('\u{1D67E}', &['\u{4F}']),
+    ('\u{1D67F}', &['\u{50}']),
+    ('\u{1D680}', &['\u{51}']),
+    ('\u{1D681}', &['\u{52}']),
+    ('\u{1D682}', &['\u{53}']),
+    ('\u{1D683}', &['\u{54}']),
+    ('\u{1D684}', &['\u{55}']),
+    ('\u{1D685}', &['\u{56}']),
+    ('\u{1D686}', &['\u{57}']),
+    ('\u{1D687}', &['\u{58}']),
+    ('\u{1D688}', &['\u{59}']),
+    ('\u{1D689}', &['\u{5A}']),
+    ('\u{1D68A}', &['\u{61}']),
+    ('\u{1D68B}', &['\u{62}']),
+    ('\u{1D68C}', &['\u{63}']),
+    ('\u{1D68D}', &['\u{64}']),
+    ('\u{1D68E}', &['\u{65}']),
+    ('\u{1D68F}', &['\u{66}']),
+    ('\u{1D690}', &['\u{67}']),
+    ('\u{1D691}', &['\u{68}']),
+    ('\u{1D692}', &['\u{69}']),
+    ('\u{1D693}', &['\u{6A}']),
+    ('\u{1D694}', &['\u{6B}']),
+    ('\u{1D695}', &['\u{6C}']),
+    ('\u{1D696}', &['\u{72}', '\u{6E}']),
+    ('\u{1D697}', &['\u{6E}']),
+    ('\u{1D698}', &['\u{6F}']),
+    ('\u{1D699}', &['\u{70}']),
+    ('\u{1D69A}', &['\u{71}']),
+    ('\u{1D69B}', &['\u{72}']),
+    ('\u{1D69C}', &['\u{73}']),
+    ('\u{1D69D}', &['\u{74}']),
+    ('\u{1D69E}', &['\u{75}']),
+    ('\u{1D69F}', &['\u{76}']),
+    ('\u{1D6A0}', &['\u{77}']),
+    ('\u{1D6A1}', &['\u{78}']),
+    ('\u{1D6A2}', &['\u{79}']),
+    ('\u{1D6A3}', &['\u{7A}']),
+    ('\u{1D6A4}', &['\u{69}']),
+    ('\u{1D6A5}', &['\u{237}']),
+    ('\u{1D6A8}', &['\u{41}']),
+    ('\u{1D6A9}', &['\u{42}']),
+    ('\u{1D6AA}', &['\u{393}']),
+    ('\u{1D6AB}', &['\u{394}']),
+    ('\u{1D6AC}', &['\u{45}']),
+    ('\u{1D6AD}', &['\u{5A}']),
+    ('\u{1D6AE}', &['\u{48}']),
+    ('\u{1D6AF}', &['\u{4F}', '\u{335}']),
+    ('\u{1D6B0}', &['\u{6C}']),
+    ('\u{1D6B1}', &['\u{4B}']),
+    ('\u{1D6B2}', &['\u{245}']),
+    ('\u{1D6B3}', &['\u{4D}']),
+    ('\u{1D6B4}', &['\u{4E}']),
+    ('\u{1D6B5}', &['\u{39E}']),
+    ('\u{1D6B6}', &['\u{4F}']),
+    ('\u{1D6B7}', &['\u{3A0}']),
+    ('\u{1D6B8}', &['\u{50}']),
+    ('\u{1D6B9}', &['\u{4F}', '\u{335}']),
+    ('\u{1D6BA}', &['\u{1A9}']),
+    ('\u{1D6BB}', &['\u{54}']),
+    ('\u{1D6BC}', &['\u{59}']),
+    ('\u{1D6BD}', &['\u{3A6}']),
+    ('\u{1D6BE}', &['\u{58}']),
+    ('\u{1D6BF}', &['\u{3A8}']),
+    ('\u{1D6C0}', &['\u{3A9}']),
+    ('\u{1D6C1}', &['\u{2207}']),
+    ('\u{1D6C2}', &['\u{61}']),
+    ('\u{1D6C3}', &['\u{DF}']),
+    ('\u{1D6C4}', &['\u{79}']),
+    ('\u{1D6C5}', &['\u{1E9F}']),
+    ('\u{1D6C6}', &['\u{A793}']),
+    ('\u{1D6C7}', &['\u{3B6}']),
+    ('\u{1D6C8}', &['\u{6E}', '\u{329}']),
+    ('\u{1D6C9}', &['\u{4F}', '\u{335}']),
+    ('\u{1D6CA}', &['\u{69}']),
+    ('\u{1D6CB}', &['\u{138}']),
+    ('\u{1D6CC}', &['\u{3BB}']),
+    ('\u{1D6CD}', &['\u{3BC}']),
+    ('\u{1D6CE}', &['\u{76}']),
+    ('\u{1D6CF}', &['\u{3BE}']),
+    ('\u{1D6D0}', &['\u{6F}']),
+    ('\u{1D6D1}', &['\u{3C0}']),
+    ('\u{1D6D2}', &['\u{70}']),
+    ('\u{1D6D3}', &['\u{3C2}']),
+    ('\u{1D6D4}', &['\u{6F}']),
+    ('\u{1D6D5}', &['\u{1D1B}']),
+    ('\u{1D6D6}', &['\u{75}']),
+    ('\u{1D6D7}', &['\u{278}']),
+    ('\u{1D6D8}', &['\u{3C7}']),
+    ('\u{1D6D9}', &['\u{3C8}']),
+    ('\u{1D6DA}', &['\u{3C9}']),
+    ('\u{1D6DB}', &['\u{2202}']),
+    ('\u{1D6DC}', &['\u{A793}']),
+    ('\u{1D6DD}', &['\u{4F}', '\u{335}']),
+    ('\u{1D6DE}', &['\u{138}']),
+    ('\u{1D6DF}', &['\u{278}']),
+    ('\u{1D6E0}', &['\u{70}']),
+    ('\u{1D6E1}', &['\u{3C0}']),
+    ('\u{1D6E2}', &['\u{41}']),
+    ('\u{1D6E3}', &['\u{42}']),
+    ('\u{1D6E4}', &['\u{393}']),
+    ('\u{1D6E5}', &['\u{394}']),
+    ('\u{1D6E6}', &['\u{45}']),
+    ('\u{1D6E7}', &['\u{5A}']),
+    ('\u{1D6E8}', &['\u{48}']),
+    ('\u{1D6E9}', &['\u{4F}', '\u{335}']),
+    ('\u{1D6EA}', &['\u{6C}']),
+    ('\u{1D6EB}', &['\u{4B}']),
+    ('\u{1D6EC}', &['\u{245}']),
+    ('\u{1D6ED}', &['\u{4D}']),
+    ('\u{1D6EE}', &['\u{4E}']),
+    ('\u{1D6EF}', &['\u{39E}']),
+    ('\u{1D6F0}', &['\u{4F}']),
+    ('\u{1D6F1}', &['\u{3A0}']),
+    ('\u{1D6F2}', &['\u{50}']),
+    ('\u{1D6F3}', &['\u{4F}', '\u{335}']),
+    ('\u{1D6F4}', &['\u{1A9}']),
+    ('\u{1D6F5}', &['\u{54}']),
+    ('\u{1D6F6}', &['\u{59}']),
+    ('\u{1D6F7}', &['\u{3A6}']),
+    ('\u{1D6F8}', &['\u{58}']),
+    ('\u{1D6F9}', &['\u{3A8}']),
+    ('\u{1D6FA}', &['\u{3A9}']),
+    ('\u{1D6FB}', &['\u{2207}']),
+    ('\u{1D6FC}', &['\u{61}']),
+    ('\u{1D6FD}', &['\u{DF}']),
+    ('\u{1D6FE}', &['\u{79}']),
+    ('\u{1D6FF}', &['\u{1E9F}']),
+    ('\u{1D700}', &['\u{A793}']),
+    ('\u{1D701}', &['\u{3B6}']),
+    ('\u{1D702}', &['\u{6E}', '\u{329}']),
+    ('\u{1D703}', &['\u{4F}', '\u{335}']),
+    ('\u{1D704}', &['\u{69}']),
+    ('\u{1D705}', &['\u{138}']),
+    ('\u{1D706}', &['\u{3BB}']),
+    ('\u{1D707}', &['\u{3BC}']),
+    ('\u{1D708}', &['\u{76}']),
+    ('\u{1D709}', &['\u{3BE}']),
+    ('\u{1D70A}', &['\u{6F}']),
+    ('\u{1D70B}', &['\u{3C0}']),
+    ('\u{1D70C}', &['\u{70}']),
+    ('\u{1D70D}', &['\u{3C2}']),
+    ('\u{1D70E}', &['\u{6F}']),
+    ('\u{1D70F}', &['\u{1D1B}']),
+    ('\u{1D710}', &['\u{75}']),
+    ('\u{1D711}', &['\u{278}']),
+    ('\u{1D712}', &['\u{3C7}']),
+    ('\u{1D713}', &['\u{3C8}']),
+    ('\u{1D714}', &['\u{3C9}']),
+    ('\u{1D715}', &['\u{2202}']),
+    ('\u{1D716}', &['\u{A793}']),
+    ('\u{1D717}', &['\u{4F}', '\u{335}']),
+    ('\u{1D718}', &['\u{138}']),
+    ('\u{1D719}', &['\u{278}']),
+    ('\u{1D71A}', &['\u{70}']),
+    ('\u{1D71B}', &['\u{3C0}']),
+    ('\u{1D71C}', &['\u{41}']),
+    ('\u{1D71D}', &['\u{42}']),
+    ('\u{1D71E}', &['\u{393}']),
+    ('\u{1D71F}', &['\u{394}']),
+    ('\u{1D720}', &['\u{45}']),
+    ('\u{1D721}', &['\u{5A}']),
+    ('\u{1D722}', &['\u{48}']),
+    ('\u{1D723}', &['\u{4F}', '\u{335}']),
+    ('\u{1D724}', &['\u{6C}']),
+    ('\u{1D725}', &['\u{4B}']),
+    ('\u{1D726}', &['\u{245}']),
+    ('\u{1D727}', &['\u{4D}']),
+    ('\u{1D728}', &['\u{4E}']),
+    ('\u{1D729}', &['\u{39E}']),
+    ('\u{1D72A}', &['\u{4F}']),
+    ('\u{1D72B}', &['\u{3A0}']),
+    ('\u{1D72C}', &['\u{50}']),
+    ('\u{1D72D}', &['\u{4F}', '\u{335}']),
+    ('\u{1D72E}', &['\u{1A9}']),
+    ('\u{1D72F}', &['\u{54}']),
+    ('\u{1D730}', &['\u{59}']),
+    ('\u{1D731}', &['\u{3A6}']),
+    ('\u{1D732}', &['\u{58}']),
+    ('\u{1D733}', &['\u{3A8}']),
+    ('\u{1D734}', &['\u{3A9}']),
+    ('\u{1D735}', &['\u{2207}']),
+    ('\u{1D736}', &['\u{61}']),
+    ('\u{1D737}', &['\u{DF}']),
+    ('\u{1D738}', &['\u{79}']),
+    ('\u{1D739}', &['\u{1E9F}']),
+    ('\u{1D73A}', &['\u{A793}']),
+    ('\u{1D73B}', &['\u{3B6}']),
+    ('\u{1D73C}', &['\u{6E}', '\u{329}']),
+    ('\u{1D73D}', &['\u{4F}', '\u{335}']),
+    ('\u{1D73E}', &['\u{69}']),
+    ('\u{1D73F}', &['\u{138}']),
+    ('\u{1D740}', &['\u{3BB}']),
+    ('\u{1D741}', &['\u{3BC}']),
+    ('\u{1D742}', &['\u{76}']),
+    ('\u{1D743}', &['\u{3BE}']),
+    ('\u{1D744}', &['\u{6F}']),
+    ('\u{1D745}', &['\u{3C0}']),
+    ('\u{1D746}', &['\u{70}']),
+    ('\u{1D747}', &['\u{3C2}']),
+    ('\u{1D748}', &['\u{6F}']),
+    ('\u{1D749}', &['\u{1D1B}']),
+    ('\u{1D74A}', &['\u{75}']),
+    ('\u{1D74B}', &['\u{278}']),
+    ('\u{1D74C}', &['\u{3C7}']),
+    ('\u{1D74D}', &['\u{3C8}']),
+    ('\u{1D74E}', &['\u{3C9}']),
+    ('\u{1D74F}', &['\u{2202}']),
+    ('\u{1D750}', &['\u{A793}']),
+    ('\u{1D751}', &['\u{4F}', '\u{335}']),
+    ('\u{1D752}', &['\u{138}']),
+    ('\u{1D753}', &['\u{278}']),
+    ('\u{1D754}', &['\u{70}']),
+    ('\u{1D755}', &['\u{3C0}']),
+    ('\u{1D756}', &['\u{41}']),
+    ('\u{1D757}', &['\u{42}']),
+    ('\u{1D758}', &['\u{393}']),
+    ('\u{1D759}', &['\u{394}']),
+    ('\u{1D75A}', &['\u{45}']),
+    ('\u{1D75B}', &['\u{5A}']),
+    ('\u{1D75C}', &['\u{48}']),
+    ('\u{1D75D}', &['\u{4F}', '\u{335}']),
+    ('\u{1D75E}', &['\u{6C}']),
+    ('\u{1D75F}', &['\u{4B}']),
+    ('\u{1D760}', &['\u{245}']),
+    ('\u{1D761}', &['\u{4D}']),
+    ('\u{1D762}', &['\u{4E}']),
+    ('\u{1D763}', &['\u{39E}']),
+    ('\u{1D764}', &['\u{4F}']),
+    ('\u{1D765}', &['\u{3A0}']),
+    ('\u{1D766}', &['\u{50}']),
+    ('\u{1D767}', &['\u{4F}', '\u{335}']),
+    ('\u{1D768}', &['\u{1A9}']),
+    ('\u{1D769}', &['\u{54}']),
+    ('\u{1D76A}', &['\u{59}']),
+    ('\u{1D76B}', &['\u{3A6}']),
+    ('\u{1D76C}', &['\u{58}']),
+    ('\u{1D76D}', &['\u{3A8}']),
+    ('\u{1D76E}', &['\u{3A9}']),
+    ('\u{1D76F}', &['\u{2207}']),
+    ('\u{1D770}', &['\u{61}']),
+    ('\u{1D771}', &['\u{DF}']),
+    ('\u{1D772}', &['\u{79}']),
+    ('\u{1D773}', &['\u{1E9F}']),
+    ('\u{1D774}', &['\u{A793}']),
+    ('\u{1D775}', &['\u{3B6}']),
+    ('\u{1D776}', &['\u{6E}', '\u{329}']),
+    ('\u{1D777}', &['\u{4F}', '\u{335}']),
+    ('\u{1D778}', &['\u{69}']),
+    ('\u{1D779}', &['\u{138}']),
+    ('\u{1D77A}', &['\u{3BB}']),
+    ('\u{1D77B}', &['\u{3BC}']),
+    ('\u{1D77C}', &['\u{76}']),
+    ('\u{1D77D}', &['\u{3BE}']),
+    ('\u{1D77E}', &['\u{6F}']),
+    ('\u{1D77F}', &['\u{3C0}']),
+    ('\u{1D780}', &['\u{70}']),
+    ('\u{1D781}', &['\u{3C2}']),
+    ('\u{1D782}', &['\u{6F}']),
+    ('\u{1D783}', &['\u{1D1B}']),
+    ('\u{1D784}', &['\u{75}']),
+    ('\u{1D785}', &['\u{278}']),
+    ('\u{1D786}', &['\u{3C7}']),
+    ('\u{1D787}', &['\u{3C8}']),
+    ('\u{1D788}', &['\u{3C9}']),
+    ('\u{1D789}', &['\u{2202}']),
+    ('\u{1D78A}', &['\u{A793}']),
+    ('\u{1D78B}', &['\u{4F}', '\u{335}']),
+    ('\u{1D78C}', &['\u{138}']),
+    ('\u{1D78D}', &['\u{278}']),
+    ('\u{1D78E}', &['\u{70}']),
+    ('\u{1D78F}', &['\u{3C0}']),
+    ('\u{1D790}', &['\u{41}']),
+    ('\u{1D791}', &['\u{42}']),
+    ('\u{1D792}', &['\u{393}']),
+    ('\u{1D793}', &['\u{394}']),
+    ('\u{1D794}', &['\u{45}']),
+    ('\u{1D795}', &['\u{5A}']),
+    ('\u{1D796}', &['\u{48}']),
+    ('\u{1D797}', &['\u{4F}', '\u{335}']),
+    ('\u{1D798}', &['\u{6C}']),
+    ('\u{1D799}', &['\u{4B}']),
+    ('\u{1D79A}', &['\u{245}']),
+    ('\u{1D79B}', &['\u{4D}']),
+    ('\u{1D79C}', &['\u{4E}']),
+    ('\u{1D79D}', &['\u{39E}']),
+    ('\u{1D79E}', &['\u{4F}']),
+    ('\u{1D79F}', &['\u{3A0}']),
+    ('\u{1D7A0}', &['\u{50}']),
+    ('\u{1D7A1}', &['\u{4F}', '\u{335}']),
+    ('\u{1D7A2}', &['\u{1A9}']),
+    ('\u{1D7A3}', &['\u{54}']),
+    ('\u{1D7A4}', &['\u{59}']),
+    ('\u{1D7A5}', &['\u{3A6}']),
+    ('\u{1D7A6}', &['\u{58}']),
+    ('\u{1D7A7}', &['\u{3A8}']),
+    ('\u{1D7A8}', &['\u{3A9}']),
+    ('\u{1D7A9}', &['\u{2207}']),
+    ('\u{1D7AA}', &['\u{61}']),
+    ('\u{1D7AB}', &['\u{DF}']),
+    ('\u{1D7AC}', &['\u{79}']),
+    ('\u{1D7AD}', &['\u{1E9F}']),
+    ('\u{1D7AE}', &['\u{A793}']),
+    ('\u{1D7AF}', &['\u{3B6}']),
+    ('\u{1D7B0}', &['\u{6E}', '\u{329}']),
+    ('\u{1D7B1}', &['\u{4F}', '\u{335}']),
+    ('\u{1D7B2}', &['\u{69}']),
+    ('\u{1D7B3}', &['\u{138}']),
+    ('\u{1D7B4}', &['\u{3BB}']),
+    ('\u{1D7B5}', &['\u{3BC}']),
+    ('\u{1D7B6}', &['\u{76}']),
+    ('\u{1D7B7}', &['\u{3BE}']),
+    ('\u{1D7B8}', &['\u{6F}']),
+    ('\u{1D7B9}', &['\u{3C0}']),
+    ('\u{1D7BA}', &['\u{70}']),
+    ('\u{1D7BB}', &['\u{3C2}']),
+    ('\u{1D7BC}', &['\u{6F}']),
+    ('\u{1D7BD}', &['\u{1D1B}']),
+    ('\u{1D7BE}', &['\u{75}']),
+    ('\u{1D7BF}', &['\u{278}']),
+    ('\u{1D7C0}', &['\u{3C7}']),
+    ('\u{1D7C1}', &['\u{3C8}']),
+    ('\u{1D7C2}', &['\u{3C9}']),
+    ('\u{1D7C3}', &['\u{2202}']),
+    ('\u{1D7C4}', &['\u{A793}']),
+    ('\u{1D7C5}', &['\u{4F}', '\u{335}']),
+    ('\u{1D7C6}', &['\u{138}']),
+    ('\u{1D7C7}', &['\u{278}']),
+    ('\u{1D7C8}', &['\u{70}']),
+    ('\u{1D7C9}', &['\u{3C0}']),
+    ('\u{1D7CA}', &['\u{46}']),
+    ('\u{1D7CB}', &['\u{3DD}']),
+    ('\u{1D7CE}', &['\u{4F}']),
+    ('\u{1D7CF}', &['\u{6C}']),
+    ('\u{1D7D0}', &['\u{32}']),
+    ('\u{1D7D1}', &['\u{33}']),
+    ('\u{1D7D2}', &['\u{34}']),
+    ('\u{1D7D3}', &['\u{35}']),
+    ('\u{1D7D4}', &['\u{36}']),
+    ('\u{1D7D5}', &['\u{37}']),
+    ('\u{1D7D6}', &['\u{38}']),
+    ('\u{1D7D7}', &['\u{39}']),
+    ('\u{1D7D8}', &['\u{4F}']),
+    ('\u{1D7D9}', &['\u{6C}']),
+    ('\u{1D7DA}', &['\u{32}']),
+    ('\u{1D7DB}', &['\u{33}']),
+    ('\u{1D7DC}', &['\u{34}']),
+    ('\u{1D7DD}', &['\u{35}']),
+    ('\u{1D7DE}', &['\u{36}']),
+    ('\u{1D7DF}', &['\u{37}']),
+    ('\u{1D7E0}', &['\u{38}']),
+    ('\u{1D7E1}', &['\u{39}']),
+    ('\u{1D7E2}', &['\u{4F}']),
+    ('\u{1D7E3}', &['\u{6C}']),
+    ('\u{1D7E4}', &['\u{32}']),
+    ('\u{1D7E5}', &['\u{33}']),
+    ('\u{1D7E6}', &['\u{34}']),
+    ('\u{1D7E7}', &['\u{35}']),
+    ('\u{1D7E8}', &['\u{36}']),
+    ('\u{1D7E9}', &['\u{37}']),
+    ('\u{1D7EA}', &['\u{38}']),
+    ('\u{1D7EB}', &['\u{39}']),
+    ('\u{1D7EC}', &['\u{4F}']),
+    ('\u{1D7ED}', &['\u{6C}']),
+    ('\u{1D7EE}', &['\u{32}']),
+    ('\u{1D7EF}', &['\u{33}']),
+    ('\u{1D7F0}', &['\u{34}']),
+    ('\u{1D7F1}', &['\u{35}']),
+    ('\u{1D7F2}', &['\u{36}']),
+    ('\u{1D7F3}', &['\u{37}']),
+    ('\u{1D7F4}', &['\u{38}']),
+    ('\u{1D7F5}', &['\u{39}']),
+    ('\u{1D7F6}', &['\u{4F}']),
+    ('\u{1D7F7}', &['\u{6C}']),
+    ('\u{1D7F8}', &['\u{32}']),
+    ('\u{1D7F9}', &['\u{33}']),
+    ('\u{1D7FA}', &['\u{34}']),
+    ('\u{1D7FB}', &['\u{35}']),
+    ('\u{1D7FC}', &['\u{36}']),
+    ('\u{1D7FD}', &['\u{37}']),
+    ('\u{1D7FE}', &['\u{38}']),
+    ('\u{1D7FF}', &['\u{39}']),
+    ('\u{1E8C7}', &['\u{6C}']),
+    ('\u{1E8C8}', &['\u{2220}']),
+    ('\u{1E8C9}', &['\u{663}']),
+    ('\u{1E8CB}', &['\u{38}']),
+    ('\u{1E8CC}', &['\u{2202}']),
+    ('\u{1E8CD}', &['\u{2202}', '\u{335}']),
+    ('\u{1EE00}', &['\u{6C}']),
+    ('\u{1EE01}', &['\u{628}']),
+    ('\u{1EE02}', &['\u{62C}']),
+    ('\u{1EE03}', &['\u{62F}']),
+    ('\u{1EE05}', &['\u{648}']),
+    ('\u{1EE06}', &['\u{632}']),
+    ('\u{1EE07}', &['\u{62D}']),
+    ('\u{1EE08}', &['\u{637}']),
+    ('\u{1EE09}', &['\u{649}']),
+    ('\u{1EE0A}', &['\u{643}']),
+    ('\u{1EE0B}', &['\u{644}']),
+    ('\u{1EE0C}', &['\u{645}']),
+    ('\u{1EE0D}', &['\u{646}']),
+    ('\u{1EE0E}', &['\u{633}']),
+    ('\u{1EE0F}', &['\u{639}']),
+    ('\u{1EE10}', &['\u{641}']),
+    ('\u{1EE11}', &['\u{635}']),
+    ('\u{1EE12}', &['\u{642}']),
+    ('\u{1EE13}', &['\u{631}']),
+    ('\u{1EE14}', &['\u{633}', '\u{6DB}']),
+    ('\u{1EE15}', &['\u{62A}']),
+    ('\u{1EE16}', &['\u{649}', '\u{6DB}']),
+    ('\u{1EE17}', &['\u{62E}']),
+    ('\u{1EE18}', &['\u{630}']),
+    ('\u{1EE19}', &['\u{636}']),
+    ('\u{1EE1A}', &['\u{638}']),
+    ('\u{1EE1B}', &['\u{63A}']),
+    ('\u{1EE1C}', &['\u{649}']),
+    ('\u{1EE1D}', &['\u{649}']),
+    ('\u{1EE1E}', &['\u{6A1}']),
+    ('\u{1EE1F}', &['\u{6A1}']),
+    ('\u{1EE21}', &['\u{628}']),
+    ('\u{1EE22}', &['\u{62C}']),
+    ('\u{1EE24}', &['\u{6F}']),
+    ('\u{1EE27}', &['\u{62D}']),
+    ('\u{1EE29}', &['\u{649}']),
+    ('\u{1EE2A}', &['\u{643}']),
+    ('\u{1EE2B}', &['\u{644}']),
+    ('\u{1EE2C}', &['\u{645}']),
+    ('\u{1EE2D}', &['\u{646}']),
+    ('\u{1EE2E}', &['\u{633}']),
+    ('\u{1EE2F}', &['\u{639}']),
+    ('\u{1EE30}', &['\u{641}']),
+    ('\u{1EE31}', &['\u{635}']),
+    ('\u{1EE32}', &['\u{642}']),
+    ('\u{1EE34}', &['\u{633}', '\u{6DB}']),
+    ('\u{1EE35}', &['\u{62A}']),
+    ('\u{1EE36}', &['\u{649}', '\u{6DB}']),
+    ('\u{1EE37}', &['\u{62E}']),
+    ('\u{1EE39}', &['\u{636}']),
+    ('\u{1EE3B}', &['\u{63A}']),
+    ('\u{1EE42}', &['\u{62C}']),
+    ('\u{1EE47}', &['\u{62D}']),
+    ('\u{1EE49}', &['\u{649}']),
+    ('\u{1EE4B}', &['\u{644}']),
+    ('\u{1EE4D}', &['\u{646}']),
+    ('\u{1EE4E}', &['\u{633}']),
+    ('\u{1EE4F}', &['\u{639}']),
+    ('\u{1EE51}', &['\u{635}']),
+    ('\u{1EE52}', &['\u{642}']),
+    ('\u{1EE54}', &['\u{633}', '\u{6DB}']),
+    ('\u{1EE57}', &['\u{62E}']),
+    ('\u{1EE59}', &['\u{636}']),
+    ('\u{1EE5B}', &['\u{63A}']),
+    ('\u{1EE5D}', &['\u{649}']),
+    ('\u{1EE5F}', &['\u{6A1}']),
+    ('\u{1EE61}', &['\u{628}']),
+    ('\u{1EE62}', &['\u{62C}']),
+    ('\u{1EE64}', &['\u{6F}']),
+    ('\u{1EE67}', &['\u{62D}']),
+    ('\u{1EE68}', &['\u{637}']),
+    ('\u{1EE69}', &['\u{649}']),
+    ('\u{1EE6A}', &['\u{643}']),
+    ('\u{1EE6C}', &['\u{645}']),
+    ('\u{1EE6D}', &['\u{646}']),
+    ('\u{1EE6E}', &['\u{633}']),
+    ('\u{1EE6F}', &['\u{639}']),
+    ('\u{1EE70}', &['\u{641}']),
+    ('\u{1EE71}', &['\u{635}']),
+    ('\u{1EE72}', &['\u{642}']),
+    ('\u{1EE74}', &['\u{633}', '\u{6DB}']),
+    ('\u{1EE75}', &['\u{62A}']),
+    ('\u{1EE76}', &['\u{649}', '\u{6DB}']),
+    ('\u{1EE77}', &['\u{62E}']),
+    ('\u{1EE79}', &['\u{636}']),
+    ('\u{1EE7A}', &['\u{638}']),
+    ('\u{1EE7B}', &['\u{63A}']),
+    ('\u{1EE7C}', &['\u{649}']),
+    ('\u{1EE7E}', &['\u{6A1}']),
+    ('\u{1EE80}', &['\u{6C}']),
+    ('\u{1EE81}', &['\u{628}']),
+    ('\u{1EE82}', &['\u{62C}']),
+    ('\u{1EE83}', &['\u{62F}']),
+    ('\u{1EE84}', &['\u{6F}']),
+    ('\u{1EE85}', &['\u{648}']),
+    ('\u{1EE86}', &['\u{632}']),
+    ('\u{1EE87}', &['\u{62D}']),
+    ('\u{1EE88}', &['\u{637}']),
+    ('\u{1EE89}', &['\u{649}']),
+    ('\u{1EE8B}', &['\u{644}']),
+    ('\u{1EE8C}', &['\u{645}']),
+    ('\u{1EE8D}', &['\u{646}']),
+    ('\u{1EE8E}', &['\u{633}']),
+    ('\u{1EE8F}', &['\u{639}']),
+    ('\u{1EE90}', &['\u{641}']),
+    ('\u{1EE91}', &['\u{635}']),
+    ('\u{1EE92}', &['\u{642}']),
+    ('\u{1EE93}', &['\u{631}']),
+    ('\u{1EE94}', &['\u{633}', '\u{6DB}']),
+    ('\u{1EE95}', &['\u{62A}']),
+    ('\u{1EE96}', &['\u{649}', '\u{6DB}']),
+    ('\u{1EE97}', &['\u{62E}']),
+    ('\u{1EE98}', &['\u{630}']),
+    ('\u{1EE99}', &['\u{636}']),
+    ('\u{1EE9A}', &['\u{638}']),
+    ('\u{1EE9B}', &['\u{63A}']),
+    ('\u{1EEA1}', &['\u{628}']),
+    ('\u{1EEA2}', &['\u{62C}']),
+    ('\u{1EEA3}', &['\u{62F}']),
+    ('\u{1EEA5}', &['\u{648}']),
+    ('\u{1EEA6}', &['\u{632}']),
+    ('\u{1EEA7}', &['\u{62D}']),
+    ('\u{1EEA8}', &['\u{637}']),
+    ('\u{1EEA9}', &['\u{649}']),
+    ('\u{1EEAB}', &['\u{644}']),
+    ('\u{1EEAC}', &['\u{645}']),
+    ('\u{1EEAD}', &['\u{646}']),
+    ('\u{1EEAE}', &['\u{633}']),
+    ('\u{1EEAF}', &['\u{639}']),
+    ('\u{1EEB0}', &['\u{641}']),
+    ('\u{1EEB1}', &['\u{635}']),
+    ('\u{1EEB2}', &['\u{642}']),
+    ('\u{1EEB3}', &['\u{631}']),
+    ('\u{1EEB4}', &['\u{633}', '\u{6DB}']),
+    ('\u{1EEB5}', &['\u{62A}']),
+    ('\u{1EEB6}', &['\u{649}', '\u{6DB}']),
+    ('\u{1EEB7}', &['\u{62E}']),
+    ('\u{1EEB8}', &['\u{630}']),
+    ('\u{1EEB9}', &['\u{636}']),
+    ('\u{1EEBA}', &['\u{638}']),
+    ('\u{1EEBB}', &['\u{63A}']),
+    ('\u{1F100}', &['\u{4F}', '\u{2E}']),
+    ('\u{1F101}', &['\u{4F}', '\u{2C}']),
+    ('\u{1F102}', &['\u{6C}', '\u{2C}']),
+    ('\u{1F103}', &['\u{32}', '\u{2C}']),
+    ('\u{1F104}', &['\u{33}', '\u{2C}']),
+    ('\u{1F105}', &['\u{34}', '\u{2C}']),
+    ('\u{1F106}', &['\u{35}', '\u{2C}']),
+    ('\u{1F107}', &['\u{36}', '\u{2C}']),
+    ('\u{1F108}', &['\u{37}', '\u{2C}']),
+    ('\u{1F109}', &['\u{38}', '\u{2C}']),
+    ('\u{1F10A}', &['\u{39}', '\u{2C}']),
+    ('\u{1F10F}', &['\u{24}', '\u{20E0}']),
+    ('\u{1F110}', &['\u{28}', '\u{41}', '\u{29}']),
+    ('\u{1F111}', &['\u{28}', '\u{42}', '\u{29}']),
+    ('\u{1F112}', &['\u{28}', '\u{43}', '\u{29}']),
+    ('\u{1F113}', &['\u{28}', '\u{44}', '\u{29}']),
+    ('\u{1F114}', &['\u{28}', '\u{45}', '\u{29}']),
+    ('\u{1F115}', &['\u{28}', '\u{46}', '\u{29}']),
+    ('\u{1F116}', &['\u{28}', '\u{47}', '\u{29}']),
+    ('\u{1F117}', &['\u{28}', '\u{48}', '\u{29}']),
+    ('\u{1F118}', &['\u{28}', '\u{6C}', '\u{29}']),
+    ('\u{1F119}', &['\u{28}', '\u{4A}', '\u{29}']),
+    ('\u{1F11A}', &['\u{28}', '\u{4B}', '\u{29}']),
+    ('\u{1F11B}', &['\u{28}', '\u{4C}', '\u{29}']),
+    ('\u{1F11C}', &['\u{28}', '\u{4D}', '\u{29}']),
+    ('\u{1F11D}', &['\u{28}', '\u{4E}', '\u{29}']),
+    ('\u{1F11E}', &['\u{28}', '\u{4F}', '\u{29}']),
+    ('\u{1F11F}', &['\u{28}', '\u{50}', '\u{29}']),
+    ('\u{1F120}', &['\u{28}', '\u{51}', '\u{29}']),
+    ('\u{1F121}', &['\u{28}', '\u{52}', '\u{29}']),
+    ('\u{1F122}', &['\u{28}', '\u{53}', '\u{29}']),
+    ('\u{1F123}', &['\u{28}', '\u{54}', '\u{29}']),
+    ('\u{1F124}', &['\u{28}', '\u{55}', '\u{29}']),
+    ('\u{1F125}', &['\u{28}', '\u{56}', '\u{29}']),
+    ('\u{1F126}', &['\u{28}', '\u{57}', '\u{29}']),
+    ('\u{1F127}', &['\u{28}', '\u{58}', '\u{29}']),
+    ('\u{1F128}', &['\u{28}', '\u{59}', '\u{29}']),
+    ('\u{1F129}', &['\u{28}', '\u{5A}', '\u{29}']),
+    ('\u{1F12A}', &['\u{28}', '\u{53}', '\u{29}']),
+    ('\u{1F16D}', &['\u{33C4}', '\u{9}', '\u{20DD}']),
+    ('\u{1F16E}', &['\u{43}', '\u{20E0}']),
+    ('\u{1F240}', &['\u{28}', '\u{672C}', '\u{29}']),
+    ('\u{1F241}', &['\u{28}', '\u{4E09}', '\u{29}']),
+    ('\u{1F242}', &['\u{28}', '\u{4E8C}', '\u{29}']),
+    ('\u{1F243}', &['\u{28}', '\u{5B89}', '\u{29}']),
+    ('\u{1F244}', &['\u{28}', '\u{70B9}', '\u{29}']),
+    ('\u{1F245}', &['\u{28}', '\u{6253}', '\u{29}']),
+    ('\u{1F246}', &['\u{28}', '\u{76D7}', '\u{29}']),
+    ('\u{1F247}', &['\u{28}', '\u{52DD}', '\u{29}']),
+    ('\u{1F248}', &['\u{28}', '\u{6557}', '\u{29}']),
+    ('\u{1F312}', &['\u{263D}']),
+    ('\u{1F318}', &['\u{263E}']),
+    ('\u{1F319}', &['\u{263D}']),
+    ('\u{1F700}', &['\u{51}', '\u{45}']),
+    ('\u{1F701}', &['\u{A658}']),
+    ('\u{1F702}', &['\u{394}']),
+    ('\u{1F704}', &['\u{102BC}']),
+    ('\u{1F707}', &['\u{41}', '\u{52}']),
+    ('\u{1F708}', &['\u{56}', '\u{1DE4}']),
+    ('\u{1F70A}', &['\u{2629}']),
+    ('\u{1F714}', &['\u{4F}', '\u{335}']),
+    ('\u{1F728}', &['\u{102A8}']),
+    ('\u{1F73A}', &['\u{29DF}']),
+    ('\u{1F74C}', &['\u{43}']),
+    ('\u{1F754}', &['\u{16DC}']),
+    ('\u{1F755}', &['\u{22A1}']),
+    ('\u{1F75C}', &['\u{73}', '\u{73}', '\u{73}']),
+    ('\u{1F75E}', &['\u{224F}']),
+    ('\u{1F768}', &['\u{54}']),
+    ('\u{1F76B}', &['\u{4D}', '\u{42}']),
+    ('\u{1F76C}', &['\u{56}', '\u{42}']),
+    ('\u{1F771}', &['\u{22A0}']),
+    ('\u{1FBF0}', &['\u{4F}']),
+    ('\u{1FBF1}', &['\u{6C}']),
+    ('\u{1FBF2}', &['\u{32}']),
+    ('\u{1FBF3}', &['\u{33}']),
+    ('\u{1FBF4}', &['\u{34}']),
+    ('\u{1FBF5}', &['\u{35}']),
+    ('\u{1FBF6}', &['\u{36}']),
+    ('\u{1FBF7}', &['\u{37}']),
+    ('\u{1FBF8}', &['\u{38}']),
+    ('\u{1FBF9}', &['\u{39}']),
+    ('\u{21FE8}', &['\u{276C}']),
+    ('\u{2F800}', &['\u{4E3D}']),
+    ('\u{2F801}', &['\u{4E38}']),
+    ('\u{2F802}', &['\u{4E41}']),
+    ('\u{2F803}', &['\u{20122}']),
+    ('\u{2F804}', &['\u{4F60}']),
+    ('\u{2F805}', &['\u{4FAE}']),
+    ('\u{2F806}', &['\u{4FBB}']),
+    ('\u{2F807}', &['\u{4F75}']),
+    ('\u{2F808}', &['\u{507A}']),
+    ('\u{2F809}', &['\u{5099}']),
+    ('\u{2F80A}', &['\u{50E7}']),
+    ('\u{2F80B}', &['\u{50CF}']),
+    ('\u{2F80C}', &['\u{349E}']),
+    ('\u{2F80D}', &['\u{2063A}']),
+    ('\u{2F80E}', &['\u{514D}']),
+    ('\u{2F80F}', &['\u{5154}']),
+    ('\u{2F810}', &['\u{5164}']),
+    ('\u{2F811}', &['\u{5177}']),
+    ('\u{2F812}', &['\u{2051C}']),
+    ('\u{2F813}', &['\u{34B9}']),
+    ('\u{2F814}', &['\u{5167}']),
+    ('\u{2F815}', &['\u{518D}']),
+    ('\u{2F816}', &['\u{2054B}']),
+    ('\u{2F817}', &['\u{5197}']),
+    ('\u{2F818}', &['\u{51A4}']),
+    ('\u{2F819}', &['\u{4ECC}']),
+    ('\u{2F81A}', &['\u{51AC}']),
+    ('\u{2F81B}', &['\u{51B5}']),
+    ('\u{2F81C}', &['\u{291DF}']),
+    ('\u{2F81D}', &['\u{51F5}']),
+    ('\u{2F81E}', &['\u{5203}']),
+    ('\u{2F81F}', &['\u{34DF}']),
+    ('\u{2F820}', &['\u{523B}']),
+    ('\u{2F821}', &['\u{5246}']),
+    ('\u{2F822}', &['\u{5272}']),
+    ('\u{2F823}', &['\u{5277}']),
+    ('\u{2F824}', &['\u{3515}']),
+    ('\u{2F825}', &['\u{52C7}']),
+    ('\u{2F826}', &['\u{52C9}']),
+    ('\u{2F827}', &['\u{52E4}']),
+    ('\u{2F828}', &['\u{52FA}']),
+    ('\u{2F829}', &['\u{5305}']),
+    ('\u{2F82A}', &['\u{5306}']),
+    ('\u{2F82B}', &['\u{5317}']),
+    ('\u{2F82C}', &['\u{5349}']),
+    ('\u{2F82D}', &['\u{5351}']),
+    ('\u{2F82E}', &['\u{535A}']),
+    ('\u{2F82F}', &['\u{5373}']),
+    ('\u{2F830}', &['\u{537D}']),
+    ('\u{2F831}', &['\u{537F}']),
+    ('\u{2F832}', &['\u{537F}']),
+    ('\u{2F833}', &['\u{537F}']),
+    ('\u{2F834}', &['\u{20A2C}']),
+    ('\u{2F835}', &['\u{7070}']),
+    ('\u{2F836}', &['\u{53CA}']),
+    ('\u{2F837}', &['\u{53DF}']),
+    ('\u{2F838}', &['\u{20B63}']),
+    ('\u{2F839}', &['\u{53EB}']),
+    ('\u{2F83A}', &['\u{53F1}']),
+    ('\u{2F83B}', &['\u{5406}']),
+    ('\u{2F83C}', &['\u{549E}']),
+    ('\u{2F83D}', &['\u{5438}']),
+    ('\u{2F83E}', &['\u{5448}']),
+    ('\u{2F83F}', &['\u{5468}']),
+    ('\u{2F840}', &['\u{54A2}']),
+    ('\u{2F841}', &['\u{54F6}']),
+    ('\u{2F842}', &['\u{5510}']),
+    ('\u{2F843}', &['\u{5553}']),
+    ('\u{2F844}', &['\u{5563}']),
+    ('\u{2F845}', &['\u{5584}']),
+    ('\u{2F846}', &['\u{5584}']),
+    ('\u{2F847}', &['\u{5599}']),
+    ('\u{2F848}', &['\u{55AB}']),
+    ('\u{2F849}', &['\u{55B3}']),
+    ('\u{2F84A}', &['\u{55C2}']),
+    ('\u{2F84B}', &['\u{5716}']),
+    ('\u{2F84C}', &['\u{5606}']),
+    ('\u{2F84D}', &['\u{5717}']),
+    ('\u{2F84E}', &['\u{5651}']),
+    ('\u{2F84F}', &['\u{5674}']),
+    ('\u{2F850}', &['\u{5207}']),
+    ('\u{2F851}', &['\u{58EE}']),
+    ('\u{2F852}', &['\u{57CE}']),
+    ('\u{2F853}', &['\u{57F4}']),
+    ('\u{2F854}', &['\u{580D}']),
+    ('\u{2F855}', &['\u{578B}']),
+    ('\u{2F856}', &['\u{5832}']),
+    ('\u{2F857}', &['\u{5831}']),
+    ('\u{2F858}', &['\u{58AC}']),
+    ('\u{2F859}', &['\u{214E4}']),
+    ('\u{2F85A}', &['\u{58F2}']),
+    ('\u{2F85B}', &['\u{58F7}']),
+    ('\u{2F85C}', &['\u{5906}']),
+    ('\u{2F85D}', &['\u{591A}']),
+    ('\u{2F85E}', &['\u{5922}']),
+    ('\u{2F85F}', &['\u{5962}']),
+    ('\u{2F860}', &['\u{216A8}']),
+    ('\u{2F861}', &['\u{216EA}']),
+    ('\u{2F862}', &['\u{59EC}']),
+    ('\u{2F863}', &['\u{5A1B}']),
+    ('\u{2F864}', &['\u{5A27}']),
+    ('\u{2F865}', &['\u{59D8}']),
+    ('\u{2F866}', &['\u{5A66}']),
+    ('\u{2F867}', &['\u{36EE}']),
+    ('\u{2F868}', &['\u{36FC}']),
+    ('\u{2F869}', &['\u{5B08}']),
+    ('\u{2F86A}', &['\u{5B3E}']),
+    ('\u{2F86B}', &['\u{5B3E}']),
+    ('\u{2F86C}', &['\u{219C8}']),
+    ('\u{2F86D}', &['\u{5BC3}']),
+    ('\u{2F86E}', &['\u{5BD8}']),
+    ('\u{2F86F}', &['\u{5BE7}']),
+    ('\u{2F870}', &['\u{5BF3}']),
+    ('\u{2F871}', &['\u{21B18}']),
+    ('\u{2F872}', &['\u{5BFF}']),
+    ('\u{2F873}', &['\u{5C06}']),
+    ('\u{2F874}', &['\u{5F53}']),
+    ('\u{2F875}', &['\u{5C22}']),
+    ('\u{2F876}', &['\u{3781}']),
+    ('\u{2F877}', &['\u{5C60}']),
+    ('\u{2F878}', &['\u{5C6E}']),
+    ('\u{2F879}', &['\u{5CC0}']),
+    ('\u{2F87A}', &['\u{5C8D}']),
+    ('\u{2F87B}', &['\u{21DE4}']),
+    ('\u{2F87C}', &['\u{5D43}']),
+    ('\u{2F87D}', &['\u{21DE6}']),
+    ('\u{2F87E}', &['\u{5D6E}']),
+    ('\u{2F87F}', &['\u{5D6B}']),
+    ('\u{2F880}', &['\u{5D7C}']),
+    ('\u{2F881}', &['\u{5DE1}']),
+    ('\u{2F882}', &['\u{5DE2}']),
+    ('\u{2F883}', &['\u{382F}']),
+    ('\u{2F884}', &['\u{5DFD}']),
+    ('\u{2F885}', &['\u{5E28}']),
+    ('\u{2F886}', &['\u{5E3D}']),
+    ('\u{2F887}', &['\u{5E69}']),
+    ('\u{2F888}', &['\u{3862}']),
+    ('\u{2F889}', &['\u{22183}']),
+    ('\u{2F88A}', &['\u{387C}']),
+    ('\u{2F88B}', &['\u{5EB0}']),
+    ('\u{2F88C}', &['\u{5EB3}']),
+    ('\u{2F88D}', &['\u{5EB6}']),
+    ('\u{2F88E}', &['\u{5ECA}']),
+    ('\u{2F88F}', &['\u{2A392}']),
+    ('\u{2F890}', &['\u{5EFE}']),
+    ('\u{2F891}', &['\u{22331}']),
+    ('\u{2F892}', &['\u{22331}']),
+    ('\u{2F893}', &['\u{8201}']),
+    ('\u{2F894}', &['\u{5F22}']),
+    ('\u{2F895}', &['\u{5F22}']),
+    ('\u{2F896}', &['\u{38C7}']),
+    ('\u{2F897}', &['\u{232B8}']),
+    ('\u{2F898}', &['\u{261DA}']),
+    ('\u{2F899}', &['\u{5F62}']),
+    ('\u{2F89A}', &['\u{5F6B}']),
+    ('\u{2F89B}', &['\u{38E3}']),
+    ('\u{2F89C}', &['\u{5F9A}']),
+    ('\u{2F89D}', &['\u{5FCD}']),
+    ('\u{2F89E}', &['\u{5FD7}']),
+    ('\u{2F89F}', &['\u{5FF9}']),
+    ('\u{2F8A0}', &['\u{6081}']),
+    ('\u{2F8A1}', &['\u{393A}']),
+    ('\u{2F8A2}', &['\u{391C}']),
+    ('\u{2F8A3}', &['\u{6094}']),
+    ('\u{2F8A4}', &['\u{226D4}']),
+    ('\u{2F8A5}', &['\u{60C7}']),
+    ('\u{2F8A6}', &['\u{6148}']),
+    ('\u{2F8A7}', &['\u{614C}']),
+    ('\u{2F8A8}', &['\u{614E}']),
+    ('\u{2F8A9}', &['\u{614C}']),
+    ('\u{2F8AA}', &['\u{617A}']),
+    ('\u{2F8AB}', &['\u{618E}']),
+    ('\u{2F8AC}', &['\u{61B2}']),
+    ('\u{2F8AD}', &['\u{61A4}']),
+    ('\u{2F8AE}', &['\u{61AF}']),
+    ('\u{2F8AF}', &['\u{61DE}']),
+    ('\u{2F8B0}', &['\u{61F2}']),
+    ('\u{2F8B1}', &['\u{61F6}']),
+    ('\u{2F8B2}', &['\u{6210}']),
+    ('\u{2F8B3}', &['\u{621B}']),
+    ('\u{2F8B4}', &['\u{625D}']),
+    ('\u{2F8B5}', &['\u{62B1}']),
+    ('\u{2F8B6}', &['\u{62D4}']),
+    ('\u{2F8B7}', &['\u{6350}']),
+    ('\u{2F8B8}', &['\u{22B0C}']),
+    ('\u{2F8B9}', &['\u{633D}']),
+    ('\u{2F8BA}', &['\u{62FC}']),
+    ('\u{2F8BB}', &['\u{6368}']),
+    ('\u{2F8BC}', &['\u{6383}']),
+    ('\u{2F8BD}', &['\u{63E4}']),
+    ('\u{2F8BE}', &['\u{22BF1}']),
+    ('\u{2F8BF}', &['\u{6422}']),
+    ('\u{2F8C0}', &['\u{63C5}']),
+    ('\u{2F8C1}', &['\u{63A9}']),
+    ('\u{2F8C2}', &['\u{3A2E}']),
+    ('\u{2F8C3}', &['\u{6469}']),
+    ('\u{2F8C4}', &['\u{647E}']),
+    ('\u{2F8C5}', &['\u{649D}']),
+    ('\u{2F8C6}', &['\u{6477}']),
+    ('\u{2F8C7}', &['\u{3A6C}']),
+    ('\u{2F8C8}', &['\u{654F}']),
+    ('\u{2F8C9}', &['\u{656C}']),
+    ('\u{2F8CA}', &['\u{2300A}']),
+    ('\u{2F8CB}', &['\u{65E3}']),
+    ('\u{2F8CC}', &['\u{66F8}']),
+    ('\u{2F8CD}', &['\u{6649}']),
+    ('\u{2F8CE}', &['\u{3B19}']),
+    ('\u{2F8CF}', &['\u{6691}']),
+    ('\u{2F8D0}', &['\u{3B08}']),
+    ('\u{2F8D1}', &['\u{3AE4}']),
+    ('\u{2F8D2}', &['\u{5192}']),
+    ('\u{2F8D3}', &['\u{5195}']),
+    ('\u{2F8D4}', &['\u{6700}']),
+    ('\u{2F8D5}', &['\u{669C}']),
+    ('\u{2F8D6}', &['\u{80AD}']),
+    ('\u{2F8D7}', &['\u{43D9}']),
+    ('\u{2F8D8}', &['\u{6717}']),
+    ('\u{2F8D9}', &['\u{671B}']),
+    ('\u{2F8DA}', &['\u{6721}']),
+    ('\u{2F8DB}', &['\u{675E}']),
+    ('\u{2F8DC}', &['\u{6753}']),
+    ('\u{2F8DD}', &['\u{233C3}']),
+    ('\u{2F8DE}', &['\u{3B49}']),
+    ('\u{2F8DF}', &['\u{67FA}']),
+    ('\u{2F8E0}', &['\u{6785}']),
+    ('\u{2F8E1}', &['\u{6852}']),
+    ('\u{2F8E2}', &['\u{6885}']),
+    ('\u{2F8E3}', &['\u{2346D}']),
+    ('\u{2F8E4}', &['\u{688E}']),
+    ('\u{2F8E5}', &['\u{681F}']),
+    ('\u{2F8E6}', &['\u{6914}']),
+    ('\u{2F8E7}', &['\u{3B9D}']),
+    ('\u{2F8E8}', &['\u{6942}']),
+    ('\u{2F8E9}', &['\u{69A3}']),
+    ('\u{2F8EA}', &['\u{69EA}']),
+    ('\u{2F8EB}', &['\u{6AA8}']),
+    ('\u{2F8EC}', &['\u{236A3}']),
+    ('\u{2F8ED}', &['\u{6ADB}']),
+    ('\u{2F8EE}', &['\u{3C18}']),
+    ('\u{2F8EF}', &['\u{6B21}']),
+    ('\u{2F8F0}', &['\u{238A7}']),
+    ('\u{2F8F1}', &['\u{6B54}']),
+    ('\u{2F8F2}', &['\u{3C4E}']),
+    ('\u{2F8F3}', &['\u{6B72}']),
+    ('\u{2F8F4}', &['\u{6B9F}']),
+    ('\u{2F8F5}', &['\u{6BBA}']),
+    ('\u{2F8F6}', &['\u{6BBB}']),
+    ('\u{2F8F7}', &['\u{23A8D}']),
+    ('\u{2F8F8}', &['\u{21D0B}']),
+    ('\u{2F8F9}', &['\u{23AFA}']),
+    ('\u{2F8FA}', &['\u{6C4E}']),
+    ('\u{2F8FB}', &['\u{23CBC}']),
+    ('\u{2F8FC}', &['\u{6CBF}']),
+    ('\u{2F8FD}', &['\u{6CCD}']),
+    ('\u{2F8FE}', &['\u{6C67}']),
+    ('\u{2F8FF}', &['\u{6D16}']),
+    ('\u{2F900}', &['\u{6D3E}']),
+    ('\u{2F901}', &['\u{6D77}']),
+    ('\u{2F902}', &['\u{6D41}']),
+    ('\u{2F903}', &['\u{6D69}']),
+    ('\u{2F904}', &['\u{6D78}']),
+    ('\u{2F905}', &['\u{6D85}']),
+    ('\u{2F906}', &['\u{23D1E}']),
+    ('\u{2F907}', &['\u{6D34}']),
+    ('\u{2F908}', &['\u{6E2F}']),
+    ('\u{2F909}', &['\u{6E6E}']),
+    ('\u{2F90A}', &['\u{3D33}']),
+    ('\u{2F90B}', &['\u{6ECB}']),
+    ('\u{2F90C}', &['\u{6EC7}']),
+    ('\u{2F90D}', &['\u{23ED1}']),
+    ('\u{2F90E}', &['\u{6DF9}']),
+    ('\u{2F90F}', &['\u{6F6E}']),
+    ('\u{2F910}', &['\u{23F5E}']),
+    ('\u{2F911}', &['\u{23F8E}']),
+    ('\u{2F912}', &['\u{6FC6}']),
+    ('\u{2F913}', &['\u{7039}']),
+    ('\u{2F914}', &['\u{701E}']),
+    ('\u{2F915}', &['\u{701B}']),
+    ('\u{2F916}', &['\u{3D96}']),
+    ('\u{2F917}', &['\u{704A}']),
+    ('\u{2F918}', &['\u{707D}']),
+    ('\u{2F919}', &['\u{7077}']),
+    ('\u{2F91A}', &['\u{70AD}']),
+    ('\u{2F91B}', &['\u{20525}']),
+    ('\u{2F91C}', &['\u{7145}']),
+    ('\u{2F91D}', &['\u{24263}']),
+    ('\u{2F91E}', &['\u{719C}']),
+    ('\u{2F91F}', &['\u{243AB}']),
+    ('\u{2F920}', &['\u{7228}']),
+    ('\u{2F921}', &['\u{7235}']),
+    ('\u{2F922}', &['\u{7250}']),
+    ('\u{2F923}', &['\u{24608}']),
+    ('\u{2F924}', &['\u{7280}']),
+    ('\u{2F925}', &['\u{7295}']),
+    ('\u{2F926}', &['\u{24735}']),
+    ('\u{2F927}', &['\u{24814}']),
+    ('\u{2F928}', &['\u{737A}']),
+    ('\u{2F929}', &['\u{738B}']),
+    ('\u{2F92A}', &['\u{3EAC}']),
+    ('\u{2F92B}', &['\u{73A5}']),
+    ('\u{2F92C}', &['\u{3EB8}']),
+    ('\u{2F92D}', &['\u{3EB8}']),
+    ('\u{2F92E}', &['\u{7447}']),
+    ('\u{2F92F}', &['\u{745C}']),
+    ('\u{2F930}', &['\u{7471}']),
+    ('\u{2F931}', &['\u{7485}']),
+    ('\u{2F932}', &['\u{74CA}']),
+    ('\u{2F933}', &['\u{3F1B}']),
+    ('\u{2F934}', &['\u{7524}']),
+    ('\u{2F935}', &['\u{24C36}']),
+    ('\u{2F936}', &['\u{753E}']),
+    ('\u{2F937}', &['\u{24C92}']),
+    ('\u{2F938}', &['\u{7570}']),
+    ('\u{2F939}', &['\u{2219F}']),
+    ('\u{2F93A}', &['\u{7610}']),
+    ('\u{2F93B}', &['\u{24FA1}']),
+    ('\u{2F93C}', &['\u{24FB8}']),
+    ('\u{2F93D}', &['\u{25044}']),
+    ('\u{2F93E}', &['\u{3FFC}']),
+    ('\u{2F93F}', &['\u{4008}']),
+    ('\u{2F940}', &['\u{76F4}']),
+    ('\u{2F941}', &['\u{250F3}']),
+    ('\u{2F942}', &['\u{250F2}']),
+    ('\u{2F943}', &['\u{25119}']),
+    ('\u{2F944}', &['\u{25133}']),
+    ('\u{2F945}', &['\u{771E}']),
+    ('\u{2F946}', &['\u{771F}']),
+    ('\u{2F947}', &['\u{771F}']),
+    ('\u{2F948}', &['\u{774A}']),
+    ('\u{2F949}', &['\u{4039}']),
+    ('\u{2F94A}', &['\u{778B}']),
+    ('\u{2F94B}', &['\u{4046}']),
+    ('\u{2F94C}', &['\u{4096}']),
+    ('\u{2F94D}', &['\u{2541D}']),
+    ('\u{2F94E}', &['\u{784E}']),
+    ('\u{2F94F}', &['\u{788C}']),
+    ('\u{2F950}', &['\u{78CC}']),
+    ('\u{2F951}', &['\u{40E3}']),
+    ('\u{2F952}', &['\u{25626}']),
+    ('\u{2F953}', &['\u{7956}']),
+    ('\u{2F954}', &['\u{2569A}']),
+    ('\u{2F955}', &['\u{256C5}']),
+    ('\u{2F956}', &['\u{798F}']),
+    ('\u{2F957}', &['\u{79EB}']),
+    ('\u{2F958}', &['\u{412F}']),
+    ('\u{2F959}', &['\u{7A40}']),
+    ('\u{2F95A}', &['\u{7A4A}']),
+    ('\u{2F95B}', &['\u{7A4F}']),
+    ('\u{2F95C}', &['\u{2597C}']),
+    ('\u{2F95D}', &['\u{25AA7}']),
+    ('\u{2F95E}', &['\u{25AA7}']),
+    ('\u{2F95F}', &['\u{7AEE}']),
+    ('\u{2F960}', &['\u{4202}']),
+    ('\u{2F961}', &['\u{25BAB}']),
+    ('\u{2F962}', &['\u{7BC6}']),
+    ('\u{2F963}', &['\u{7BC9}']),
+    ('\u{2F964}', &['\u{4227}']),
+    ('\u{2F965}', &['\u{25C80}']),
+    ('\u{2F966}', &['\u{7CD2}']),
+    ('\u{2F967}', &['\u{42A0}']),
+    ('\u{2F968}', &['\u{7CE8}']),
+    ('\u{2F969}', &['\u{7CE3}']),
+    ('\u{2F96A}', &['\u{7D00}']),
+    ('\u{2F96B}', &['\u{25F86}']),
+    ('\u{2F96C}', &['\u{7D63}']),
+    ('\u{2F96D}', &['\u{4301}']),
+    ('\u{2F96E}', &['\u{7DC7}']),
+    ('\u{2F96F}', &['\u{7E02}']),
+    ('\u{2F970}', &['\u{7E45}']),
+    ('\u{2F971}', &['\u{4334}']),
+    ('\u{2F972}', &['\u{26228}']),
+    ('\u{2F973}', &['\u{26247}']),
+    ('\u{2F974}', &['\u{4359}']),
+    ('\u{2F975}', &['\u{262D9}']),
+    ('\u{2F976}', &['\u{7F7A}']),
+    ('\u{2F977}', &['\u{2633E}']),
+    ('\u{2F978}', &['\u{7F95}']),
+    ('\u{2F979}', &['\u{7FFA}']),
+    ('\u{2F97A}', &['\u{8005}']),
+    ('\u{2F97B}', &['\u{264DA}']),
+    ('\u{2F97C}', &['\u{26523}']),
+    ('\u{2F97D}', &['\u{8060}']),
+    ('\u{2F97E}', &['\u{265A8}']),
+    ('\u{2F97F}', &['\u{8070}']),
+    ('\u{2F980}', &['\u{2335F}']),
+    ('\u{2F981}', &['\u{43D5}']),
+    ('\u{2F982}', &['\u{80B2}']),
+    ('\u{2F983}', &['\u{8103}']),
+    ('\u{2F984}', &['\u{440B}']),
+    ('\u{2F985}', &['\u{813E}']),
+    ('\u{2F986}', &['\u{5AB5}']),
+    ('\u{2F987}', &['\u{267A7}']),
+    ('\u{2F988}', &['\u{267B5}']),
+    ('\u{2F989}', &['\u{23393}']),
+    ('\u{2F98A}', &['\u{2339C}']),
+    ('\u{2F98B}', &['\u{8201}']),
+    ('\u{2F98C}', &['\u{8204}']),
+    ('\u{2F98D}', &['\u{8F9E}']),
+    ('\u{2F98E}', &['\u{446B}']),
+    ('\u{2F98F}', &['\u{8291}']),
+    ('\u{2F990}', &['\u{828B}']),
+    ('\u{2F991}', &['\u{829D}']),
+    ('\u{2F992}', &['\u{52B3}']),
+    ('\u{2F993}', &['\u{82B1}']),
+    ('\u{2F994}', &['\u{82B3}']),
+    ('\u{2F995}', &['\u{82BD}']),
+    ('\u{2F996}', &['\u{82E6}']),
+    ('\u{2F997}', &['\u{26B3C}']),
+    ('\u{2F998}', &['\u{82E5}']),
+    ('\u{2F999}', &['\u{831D}']),
+    ('\u{2F99A}', &['\u{8363}']),
+    ('\u{2F99B}', &['\u{83AD}']),
+    ('\u{2F99C}', &['\u{8323}']),
+    ('\u{2F99D}', &['\u{83BD}']),
+    ('\u{2F99E}', &['\u{83E7}']),
+    ('\u{2F99F}', &['\u{8457}']),
+    ('\u{2F9A0}', &['\u{8353}']),
+    ('\u{2F9A1}', &['\u{83CA}']),
+    ('\u{2F9A2}', &['\u{83CC}']),
+    ('\u{2F9A3}', &['\u{83DC}']),
+    ('\u{2F9A4}', &['\u{26C36}']),
+    ('\u{2F9A5}', &['\u{26D6B}']),
+    ('\u{2F9A6}', &['\u{26CD5}']),
+    ('\u{2F9A7}', &['\u{452B}']),
+    ('\u{2F9A8}', &['\u{84F1}']),
+    ('\u{2F9A9}', &['\u{84F3}']),
+    ('\u{2F9AA}', &['\u{8516}']),
+    ('\u{2F9AB}', &['\u{273CA}']),
+    ('\u{2F9AC}', &['\u{8564}']),
+    ('\u{2F9AD}', &['\u{26F2C}']),
+    ('\u{2F9AE}', &['\u{455D}']),
+    ('\u{2F9AF}', &['\u{4561}']),
+    ('\u{2F9B0}', &['\u{26FB1}']),
+    ('\u{2F9B1}', &['\u{270D2}']),
+    ('\u{2F9B2}', &['\u{456B}']),
+    ('\u{2F9B3}', &['\u{8650}']),
+    ('\u{2F9B4}', &['\u{865C}']),
+    ('\u{2F9B5}', &['\u{8667}']),
+    ('\u{2F9B6}', &['\u{8669}']),
+    ('\u{2F9B7}', &['\u{86A9}']),
+    ('\u{2F9B8}', &['\u{8688}']),
+    ('\u{2F9B9}', &['\u{870E}']),
+    ('\u{2F9BA}', &['\u{86E2}']),
+    ('\u{2F9BB}', &['\u{8779}']),
+    ('\u{2F9BC}', &['\u{8728}']),
+    ('\u{2F9BD}', &['\u{876B}']),
+    ('\u{2F9BE}', &['\u{8786}']),
+    ('\u{2F9BF}', &['\u{45D7}']),
+    ('\u{2F9C0}', &['\u{87E1}']),
+    ('\u{2F9C1}', &['\u{8801}']),
+    ('\u{2F9C2}', &['\u{45F9}']),
+    ('\u{2F9C3}', &['\u{8860}']),
+    ('\u{2F9C4}', &['\u{8863}']),
+    ('\u{2F9C5}', &['\u{27667}']),
+    ('\u{2F9C6}', &['\u{88D7}']),
+    ('\u{2F9C7}', &['\u{88DE}']),
+    ('\u{2F9C8}', &['\u{4635}']),
+    ('\u{2F9C9}', &['\u{88FA}']),
+    ('\u{2F9CA}', &['\u{34BB}']),
+    ('\u{2F9CB}', &['\u{278AE}']),
+    ('\u{2F9CC}', &['\u{27966}']),
+    ('\u{2F9CD}', &['\u{46BE}']),
+    ('\u{2F9CE}', &['\u{46C7}']),
+    ('\u{2F9CF}', &['\u{8AA0}']),
+    ('\u{2F9D0}', &['\u{8AED}']),
+    ('\u{2F9D1}', &['\u{8B8A}']),
+    ('\u{2F9D2}', &['\u{8C55}']),
+    ('\u{2F9D3}', &['\u{27CA8}']),
+    ('\u{2F9D4}', &['\u{8CAB}']),
+    ('\u{2F9D5}', &['\u{8CC1}']),
+    ('\u{2F9D6}', &['\u{8D1B}']),
+    ('\u{2F9D7}', &['\u{8D77}']),
+    ('\u{2F9D8}', &['\u{27F2F}']),
+    ('\u{2F9D9}', &['\u{20804}']),
+    ('\u{2F9DA}', &['\u{8DCB}']),
+    ('\u{2F9DB}', &['\u{8DBC}']),
+    ('\u{2F9DC}', &['\u{8DF0}']),
+    ('\u{2F9DD}', &['\u{208DE}']),
+    ('\u{2F9DE}', &['\u{8ED4}']),
+    ('\u{2F9DF}', &['\u{8F38}']),
+    ('\u{2F9E0}', &['\u{285D2}']),
+    ('\u{2F9E1}', &['\u{285ED}']),
+    ('\u{2F9E2}', &['\u{9094}']),
+    ('\u{2F9E3}', &['\u{90F1}']),
+    ('\u{2F9E4}', &['\u{9111}']),
+    ('\u{2F9E5}', &['\u{2872E}']),
+    ('\u{2F9E6}', &['\u{911B}']),
+    ('\u{2F9E7}', &['\u{9238}']),
+    ('\u{2F9E8}', &['\u{92D7}']),
+    ('\u{2F9E9}', &['\u{92D8}']),
+    ('\u{2F9EA}', &['\u{927C}']),
+    ('\u{2F9EB}', &['\u{93F9}']),
+    ('\u{2F9EC}', &['\u{9415}']),
+    ('\u{2F9ED}', &['\u{28BFA}']),
+    ('\u{2F9EE}', &['\u{958B}']),
+    ('\u{2F9EF}', &['\u{4995}']),
+    ('\u{2F9F0}', &['\u{95B7}']),
+    ('\u{2F9F1}', &['\u{28D77}']),
+    ('\u{2F9F2}', &['\u{49E6}']),
+    ('\u{2F9F3}', &['\u{96C3}']),
+    ('\u{2F9F4}', &['\u{5DB2}']),
+    ('\u{2F9F5}', &['\u{9723}']),
+    ('\u{2F9F6}', &['\u{29145}']),
+    ('\u{2F9F7}', &['\u{2921A}']),
+    ('\u{2F9F8}', &['\u{4A6E}']),
+    ('\u{2F9F9}', &['\u{4A76}']),
+    ('\u{2F9FA}', &['\u{97E0}']),
+    ('\u{2F9FB}', &['\u{2940A}']),
+    ('\u{2F9FC}', &['\u{4AB2}']),
+    ('\u{2F9FD}', &['\u{29496}']),
+    ('\u{2F9FE}', &['\u{980B}']),
+    ('\u{2F9FF}', &['\u{980B}']),
+    ('\u{2FA00}', &['\u{9829}']),
+    ('\u{2FA01}', &['\u{295B6}']),
+    ('\u{2FA02}', &['\u{98E2}']),
+    ('\u{2FA03}', &['\u{4B33}']),
+    ('\u{2FA04}', &['\u{9929}']),
+    ('\u{2FA05}', &['\u{99A7}']),
+    ('\u{2FA06}', &['\u{99C2}']),
+    ('\u{2FA07}', &['\u{99FE}']),
+    ('\u{2FA08}', &['\u{4BCE}']),
+    ('\u{2FA09}', &['\u{29B30}']),
+    ('\u{2FA0A}', &['\u{9B12}']),
+    ('\u{2FA0B}', &['\u{9C40}']),
+    ('\u{2FA0C}', &['\u{9CFD}']),
+    ('\u{2FA0D}', &['\u{4CCE}']),
+    ('\u{2FA0E}', &['\u{4CED}']),
+    ('\u{2FA0F}', &['\u{9D67}']),
+    ('\u{2FA10}', &['\u{2A0CE}']),
+    ('\u{2FA11}', &['\u{4CF8}']),
+    ('\u{2FA12}', &['\u{2A105}']),
+    ('\u{2FA13}', &['\u{2A20E}']),
+    ('\u{2FA14}', &['\u{2A291}']),
+    ('\u{2FA15}', &['\u{9EBB}']),
+    ('\u{2FA16}', &['\u{4D56}']),
+    ('\u{2FA17}', &['\u{9EF9}']),
+    ('\u{2FA18}', &['\u{9EFE}']),
+    ('\u{2FA19}', &['\u{9F05}']),
+    ('\u{2FA1A}', &['\u{9F0F}']),
+    ('\u{2FA1B}', &['\u{9F16}']),
+    ('\u{2FA1C}', &['\u{9F3B}']),
+    ('\u{2FA1D}', &['\u{2A600}']),
+];
@@ -0,0 +1,6206 @@
+// This file is generated by `unicode_string_gen`. Do not edit it manually.
+
+/// Index gb18030, or 0 if a pointer is not mapped.
+pub(crate) static GB18030: &[u16] = &[
+    0x4E02, 0x4E04, 0x4E05, 0x4E06, 0x4E0F, 0x4E12, 0x4E17, 0x4E1F, 0x4E20, 0x4E21, 0x4E23, 0x4E26,
+    0x4E29, 0x4E2E, 0x4E2F, 0x4E31, 0x4E33, 0x4E35, 0x4E37, 0x4E3C, 0x4E40, 0x4E41, 0x4E42, 0x4E44,
+    0x4E46, 0x4E4A, 0x4E51, 0x4E55, 0x4E57, 0x4E5A, 0x4E5B, 0x4E62, 0x4E63, 0x4E64, 0x4E65, 0x4E67,
+    0x4E68, 0x4E6A, 0x4E6B, 0x4E6C, 0x4E6D, 0x4E6E, 0x4E6F, 0x4E72, 0x4E74, 0x4E75, 0x4E76, 0x4E77,
+    0x4E78, 0x4E79, 0x4E7A, 0x4E7B, 0x4E7C, 0x4E7D, 0x4E7F, 0x4E80, 0x4E81, 0x4E82, 0x4E83, 0x4E84,
+    0x4E85, 0x4E87, 0x4E8A, 0x4E90, 0x4E96, 0x4E97, 0x4E99, 0x4E9C, 0x4E9D, 0x4E9E, 0x4EA3, 0x4EAA,
+    0x4EAF, 0x4EB0, 0x4EB1, 0x4EB4, 0x4EB6, 0x4EB7, 0x4EB8, 0x4EB9, 0x4EBC, 0x4EBD, 0x4EBE, 0x4EC8,
+    0x4ECC, 0x4ECF, 0x4ED0, 0x4ED2, 0x4EDA, 0x4EDB, 0x4EDC, 0x4EE0, 0x4EE2, 0x4EE6, 0x4EE7, 0x4EE9,
+    0x4EED, 0x4EEE, 0x4EEF, 0x4EF1, 0x4EF4, 0x4EF8, 0x4EF9, 0x4EFA, 0x4EFC, 0x4EFE, 0x4F00, 0x4F02,
+    0x4F03, 0x4F04, 0x4F05, 0x4F06, 0x4F07, 0x4F08, 0x4F0B, 0x4F0C, 0x4F12, 0x4F13, 0x4F14, 0x4F15,
+    0x4F16, 0x4F1C, 0x4F1D, 0x4F21, 0x4F23, 0x4F28, 0x4F29, 0x4F2C, 0x4F2D, 0x4F2E, 0x4F31, 0x4F33,
+    0x4F35, 0x4F37, 0x4F39, 0x4F3B, 0x4F3E, 0x4F3F, 0x4F40, 0x4F41, 0x4F42, 0x4F44, 0x4F45, 0x4F47,
+    0x4F48, 0x4F49, 0x4F4A, 0x4F4B, 0x4F4C, 0x4F52, 0x4F54, 0x4F56, 0x4F61, 0x4F62, 0x4F66, 0x4F68,
+    0x4F6A, 0x4F6B, 0x4F6D, 0x4F6E, 0x4F71, 0x4F72, 0x4F75, 0x4F77, 0x4F78, 0x4F79, 0x4F7A, 0x4F7D,
+    0x4F80, 0x4F81, 0x4F82, 0x4F85, 0x4F86, 0x4F87, 0x4F8A, 0x4F8C, 0x4F8E, 0x4F90, 0x4F92, 0x4F93,
+    0x4F95, 0x4F96, 0x4F98, 0x4F99, 0x4F9A, 0x4F9C, 0x4F9E, 0x4F9F, 0x4FA1, 0x4FA2, 0x4FA4, 0x4FAB,
+    0x4FAD, 0x4FB0, 0x4FB1, 0x4FB2, 0x4FB3, 0x4FB4, 0x4FB6, 0x4FB7, 0x4FB8, 0x4FB9, 0x4FBA, 0x4FBB,
+    0x4FBC, 0x4FBD, 0x4FBE, 0x4FC0, 0x4FC1, 0x4FC2, 0x4FC6, 0x4FC7, 0x4FC8, 0x4FC9, 0x4FCB, 0x4FCC,
+    0x4FCD, 0x4FD2, 0x4FD3, 0x4FD4, 0x4FD5, 0x4FD6, 0x4FD9, 0x4FDB, 0x4FE0, 0x4FE2, 0x4FE4, 0x4FE5,
+    0x4FE7, 0x4FEB, 0x4FEC, 0x4FF0, 0x4FF2, 0x4FF4, 0x4FF5, 0x4FF6, 0x4FF7, 0x4FF9, 0x4FFB, 0x4FFC,
+    0x4FFD, 0x4FFF, 0x5000, 0x5001, 0x5002, 0x5003, 0x5004, 0x5005, 0x5006, 0x5007, 0x5008, 0x5009,
+    0x500A, 0x500B, 0x500E, 0x5010, 0x5011, 0x5013, 0x5015, 0x5016, 0x5017, 0x501B, 0x501D, 0x501E,
+    0x5020, 0x5022, 0x5023, 0x5024, 0x5027, 0x502B, 0x502F, 0x5030, 0x5031, 0x5032, 0x5033, 0x5034,
+    0x5035, 0x5036, 0x5037, 0x5038, 0x5039, 0x503B, 0x503D, 0x503F, 0x5040, 0x5041, 0x5042, 0x5044,
+    0x5045, 0x5046, 0x5049, 0x504A, 0x504B, 0x504D, 0x5050, 0x5051, 0x5052, 0x5053, 0x5054, 0x5056,
+    0x5057, 0x5058, 0x5059, 0x505B, 0x505D, 0x505E, 0x505F, 0x5060, 0x5061, 0x5062, 0x5063, 0x5064,
+    0x5066, 0x5067, 0x5068, 0x5069, 0x506A, 0x506B, 0x506D, 0x506E, 0x506F, 0x5070, 0x5071, 0x5072,
+    0x5073, 0x5074, 0x5075, 0x5078, 0x5079, 0x507A, 0x507C, 0x507D, 0x5081, 0x5082, 0x5083, 0x5084,
+    0x5086, 0x5087, 0x5089, 0x508A, 0x508B, 0x508C, 0x508E, 0x508F, 0x5090, 0x5091, 0x5092, 0x5093,
+    0x5094, 0x5095, 0x5096, 0x5097, 0x5098, 0x5099, 0x509A, 0x509B, 0x509C, 0x509D, 0x509E, 0x509F,
+    0x50A0, 0x50A1, 0x50A2, 0x50A4, 0x50A6, 0x50AA, 0x50AB, 0x50AD, 0x50AE, 0x50AF, 0x50B0, 0x50B1,
+    0x50B3, 0x50B4, 0x50B5, 0x50B6, 0x50B7, 0x50B8, 0x50B9, 0x50BC, 0x50BD, 0x50BE, 0x50BF, 0x50C0,
+    0x50C1, 0x50C2, 0x50C3, 0x50C4, 0x50C5, 0x50C6, 0x50C7, 0x50C8, 0x50C9, 0x50CA, 0x50CB, 0x50CC,
+    0x50CD, 0x50CE, 0x50D0, 0x50D1, 0x50D2, 0x50D3, 0x50D4, 0x50D5, 0x50D7, 0x50D8, 0x50D9, 0x50DB,
+    0x50DC, 0x50DD, 0x50DE, 0x50DF, 0x50E0, 0x50E1, 0x50E2, 0x50E3, 0x50E4, 0x50E5, 0x50E8, 0x50E9,
+    0x50EA, 0x50EB, 0x50EF, 0x50F0, 0x50F1, 0x50F2, 0x50F4, 0x50F6, 0x50F7, 0x50F8, 0x50F9, 0x50FA,
+    0x50FC, 0x50FD, 0x50FE, 0x50FF, 0x5100, 0x5101, 0x5102, 0x5103, 0x5104, 0x5105, 0x5108, 0x5109,
+    0x510A, 0x510C, 0x510D, 0x510E, 0x510F, 0x5110, 0x5111, 0x5113, 0x5114, 0x5115, 0x5116, 0x5117,
+    0x5118, 0x5119, 0x511A, 0x511B, 0x511C, 0x511D, 0x511E, 0x511F, 0x5120, 0x5122, 0x5123, 0x5124,
+    0x5125, 0x5126, 0x5127, 0x5128, 0x5129, 0x512A, 0x512B, 0x512C, 0x512D, 0x512E, 0x512F, 0x5130,
+    0x5131, 0x5132, 0x5133, 0x5134, 0x5135, 0x5136, 0x5137, 0x5138, 0x5139, 0x513A, 0x513B, 0x513C,
+    0x513D, 0x513E, 0x5142, 0x5147, 0x514A, 0x514C, 0x514E, 0x514F, 0x5150, 0x5152, 0x5153, 0x5157,
+    0x5158, 0x5159, 0x515B, 0x515D, 0x515E, 0x515F, 0x5160, 0x5161, 0x5163, 0x5164, 0x5166, 0x5167,
+    0x5169, 0x516A, 0x516F, 0x5172, 0x517A, 0x517E, 0x517F, 0x5183, 0x5184, 0x5186, 0x5187, 0x518A,
+    0x518B, 0x518E, 0x518F, 0x5190, 0x5191, 0x5193, 0x5194, 0x5198, 0x519A, 0x519D, 0x519E, 0x519F,
+    0x51A1, 0x51A3, 0x51A6, 0x51A7, 0x51A8, 0x51A9, 0x51AA, 0x51AD, 0x51AE, 0x51B4, 0x51B8, 0x51B9,
+    0x51BA, 0x51BE, 0x51BF, 0x51C1, 0x51C2, 0x51C3, 0x51C5, 0x51C8, 0x51CA, 0x51CD, 0x51CE, 0x51D0,
+    0x51D2, 0x51D3, 0x51D4, 0x51D5, 0x51D6, 0x51D7, 0x51D8, 0x51D9, 0x51DA, 0x51DC, 0x51DE, 0x51DF,
+    0x51E2, 0x51E3, 0x51E5, 0x51E6, 0x51E7, 0x51E8, 0x51E9, 0x51EA, 0x51EC, 0x51EE, 0x51F1, 0x51F2,
+    0x51F4, 0x51F7, 0x51FE, 0x5204, 0x5205, 0x5209, 0x520B, 0x520C, 0x520F, 0x5210, 0x5213, 0x5214,
+    0x5215, 0x521C, 0x521E, 0x521F, 0x5221, 0x5222, 0x5223, 0x5225, 0x5226, 0x5227, 0x522A, 0x522C,
+    0x522F, 0x5231, 0x5232, 0x5234, 0x5235, 0x523C, 0x523E, 0x5244, 0x5245, 0x5246, 0x5247, 0x5248,
+    0x5249, 0x524B, 0x524E, 0x524F, 0x5252, 0x5253, 0x5255, 0x5257, 0x5258, 0x5259, 0x525A, 0x525B,
+    0x525D, 0x525F, 0x5260, 0x5262, 0x5263, 0x5264, 0x5266, 0x5268, 0x526B, 0x526C, 0x526D, 0x526E,
+    0x5270, 0x5271, 0x5273, 0x5274, 0x5275, 0x5276, 0x5277, 0x5278, 0x5279, 0x527A, 0x527B, 0x527C,
+    0x527E, 0x5280, 0x5283, 0x5284, 0x5285, 0x5286, 0x5287, 0x5289, 0x528A, 0x528B, 0x528C, 0x528D,
+    0x528E, 0x528F, 0x5291, 0x5292, 0x5294, 0x5295, 0x5296, 0x5297, 0x5298, 0x5299, 0x529A, 0x529C,
+    0x52A4, 0x52A5, 0x52A6, 0x52A7, 0x52AE, 0x52AF, 0x52B0, 0x52B4, 0x52B5, 0x52B6, 0x52B7, 0x52B8,
+    0x52B9, 0x52BA, 0x52BB, 0x52BC, 0x52BD, 0x52C0, 0x52C1, 0x52C2, 0x52C4, 0x52C5, 0x52C6, 0x52C8,
+    0x52CA, 0x52CC, 0x52CD, 0x52CE, 0x52CF, 0x52D1, 0x52D3, 0x52D4, 0x52D5, 0x52D7, 0x52D9, 0x52DA,
+    0x52DB, 0x52DC, 0x52DD, 0x52DE, 0x52E0, 0x52E1, 0x52E2, 0x52E3, 0x52E5, 0x52E6, 0x52E7, 0x52E8,
+    0x52E9, 0x52EA, 0x52EB, 0x52EC, 0x52ED, 0x52EE, 0x52EF, 0x52F1, 0x52F2, 0x52F3, 0x52F4, 0x52F5,
+    0x52F6, 0x52F7, 0x52F8, 0x52FB, 0x52FC, 0x52FD, 0x5301, 0x5302, 0x5303, 0x5304, 0x5307, 0x5309,
+    0x530A, 0x530B, 0x530C, 0x530E, 0x5311, 0x5312, 0x5313, 0x5314, 0x5318, 0x531B, 0x531C, 0x531E,
+    0x531F, 0x5322, 0x5324, 0x5325, 0x5327, 0x5328, 0x5329, 0x532B, 0x532C, 0x532D, 0x532F, 0x5330,
+    0x5331, 0x5332, 0x5333, 0x5334, 0x5335, 0x5336, 0x5337, 0x5338, 0x533C, 0x533D, 0x5340, 0x5342,
+    0x5344, 0x5346, 0x534B, 0x534C, 0x534D, 0x5350, 0x5354, 0x5358, 0x5359, 0x535B, 0x535D, 0x5365,
+    0x5368, 0x536A, 0x536C, 0x536D, 0x5372, 0x5376, 0x5379, 0x537B, 0x537C, 0x537D, 0x537E, 0x5380,
+    0x5381, 0x5383, 0x5387, 0x5388, 0x538A, 0x538E, 0x538F, 0x5390, 0x5391, 0x5392, 0x5393, 0x5394,
+    0x5396, 0x5397, 0x5399, 0x539B, 0x539C, 0x539E, 0x53A0, 0x53A1, 0x53A4, 0x53A7, 0x53AA, 0x53AB,
+    0x53AC, 0x53AD, 0x53AF, 0x53B0, 0x53B1, 0x53B2, 0x53B3, 0x53B4, 0x53B5, 0x53B7, 0x53B8, 0x53B9,
+    0x53BA, 0x53BC, 0x53BD, 0x53BE, 0x53C0, 0x53C3, 0x53C4, 0x53C5, 0x53C6, 0x53C7, 0x53CE, 0x53CF,
+    0x53D0, 0x53D2, 0x53D3, 0x53D5, 0x53DA, 0x53DC, 0x53DD, 0x53DE, 0x53E1, 0x53E2, 0x53E7, 0x53F4,
+    0x53FA, 0x53FE, 0x53FF, 0x5400, 0x5402, 0x5405, 0x5407, 0x540B, 0x5414, 0x5418, 0x5419, 0x541A,
+    0x541C, 0x5422, 0x5424, 0x5425, 0x542A, 0x5430, 0x5433, 0x5436, 0x5437, 0x543A, 0x543D, 0x543F,
+    0x5441, 0x5442, 0x5444, 0x5445, 0x5447, 0x5449, 0x544C, 0x544D, 0x544E, 0x544F, 0x5451, 0x545A,
+    0x545D, 0x545E, 0x545F, 0x5460, 0x5461, 0x5463, 0x5465, 0x5467, 0x5469, 0x546A, 0x546B, 0x546C,
+    0x546D, 0x546E, 0x546F, 0x5470, 0x5474, 0x5479, 0x547A, 0x547E, 0x547F, 0x5481, 0x5483, 0x5485,
+    0x5487, 0x5488, 0x5489, 0x548A, 0x548D, 0x5491, 0x5493, 0x5497, 0x5498, 0x549C, 0x549E, 0x549F,
+    0x54A0, 0x54A1, 0x54A2, 0x54A5, 0x54AE, 0x54B0, 0x54B2, 0x54B5, 0x54B6, 0x54B7, 0x54B9, 0x54BA,
+    0x54BC, 0x54BE, 0x54C3, 0x54C5, 0x54CA, 0x54CB, 0x54D6, 0x54D8, 0x54DB, 0x54E0, 0x54E1, 0x54E2,
+    0x54E3, 0x54E4, 0x54EB, 0x54EC, 0x54EF, 0x54F0, 0x54F1, 0x54F4, 0x54F5, 0x54F6, 0x54F7, 0x54F8,
+    0x54F9, 0x54FB, 0x54FE, 0x5500, 0x5502, 0x5503, 0x5504, 0x5505, 0x5508, 0x550A, 0x550B, 0x550C,
+    0x550D, 0x550E, 0x5512, 0x5513, 0x5515, 0x5516, 0x5517, 0x5518, 0x5519, 0x551A, 0x551C, 0x551D,
+    0x551E, 0x551F, 0x5521, 0x5525, 0x5526, 0x5528, 0x5529, 0x552B, 0x552D, 0x5532, 0x5534, 0x5535,
+    0x5536, 0x5538, 0x5539, 0x553A, 0x553B, 0x553D, 0x5540, 0x5542, 0x5545, 0x5547, 0x5548, 0x554B,
+    0x554C, 0x554D, 0x554E, 0x554F, 0x5551, 0x5552, 0x5553, 0x5554, 0x5557, 0x5558, 0x5559, 0x555A,
+    0x555B, 0x555D, 0x555E, 0x555F, 0x5560, 0x5562, 0x5563, 0x5568, 0x5569, 0x556B, 0x556F, 0x5570,
+    0x5571, 0x5572, 0x5573, 0x5574, 0x5579, 0x557A, 0x557D, 0x557F, 0x5585, 0x5586, 0x558C, 0x558D,
+    0x558E, 0x5590, 0x5592, 0x5593, 0x5595, 0x5596, 0x5597, 0x559A, 0x559B, 0x559E, 0x55A0, 0x55A1,
+    0x55A2, 0x55A3, 0x55A4, 0x55A5, 0x55A6, 0x55A8, 0x55A9, 0x55AA, 0x55AB, 0x55AC, 0x55AD, 0x55AE,
+    0x55AF, 0x55B0, 0x55B2, 0x55B4, 0x55B6, 0x55B8, 0x55BA, 0x55BC, 0x55BF, 0x55C0, 0x55C1, 0x55C2,
+    0x55C3, 0x55C6, 0x55C7, 0x55C8, 0x55CA, 0x55CB, 0x55CE, 0x55CF, 0x55D0, 0x55D5, 0x55D7, 0x55D8,
+    0x55D9, 0x55DA, 0x55DB, 0x55DE, 0x55E0, 0x55E2, 0x55E7, 0x55E9, 0x55ED, 0x55EE, 0x55F0, 0x55F1,
+    0x55F4, 0x55F6, 0x55F8, 0x55F9, 0x55FA, 0x55FB, 0x55FC, 0x55FF, 0x5602, 0x5603, 0x5604, 0x5605,
+    0x5606, 0x5607, 0x560A, 0x560B, 0x560D, 0x5610, 0x5611, 0x5612, 0x5613, 0x5614, 0x5615, 0x5616,
+    0x5617, 0x5619, 0x561A, 0x561C, 0x561D, 0x5620, 0x5621, 0x5622, 0x5625, 0x5626, 0x5628, 0x5629,
+    0x562A, 0x562B, 0x562E, 0x562F, 0x5630, 0x5633, 0x5635, 0x5637, 0x5638, 0x563A, 0x563C, 0x563D,
+    0x563E, 0x5640, 0x5641, 0x5642, 0x5643, 0x5644, 0x5645, 0x5646, 0x5647, 0x5648, 0x5649, 0x564A,
+    0x564B, 0x564F, 0x5650, 0x5651, 0x5652, 0x5653, 0x5655, 0x5656, 0x565A, 0x565B, 0x565D, 0x565E,
+    0x565F, 0x5660, 0x5661, 0x5663, 0x5665, 0x5666, 0x5667, 0x566D, 0x566E, 0x566F, 0x5670, 0x5672,
+    0x5673, 0x5674, 0x5675, 0x5677, 0x5678, 0x5679, 0x567A, 0x567D, 0x567E, 0x567F, 0x5680, 0x5681,
+    0x5682, 0x5683, 0x5684, 0x5687, 0x5688, 0x5689, 0x568A, 0x568B, 0x568C, 0x568D, 0x5690, 0x5691,
+    0x5692, 0x5694, 0x5695, 0x5696, 0x5697, 0x5698, 0x5699, 0x569A, 0x569B, 0x569C, 0x569D, 0x569E,
+    0x569F, 0x56A0, 0x56A1, 0x56A2, 0x56A4, 0x56A5, 0x56A6, 0x56A7, 0x56A8, 0x56A9, 0x56AA, 0x56AB,
+    0x56AC, 0x56AD, 0x56AE, 0x56B0, 0x56B1, 0x56B2, 0x56B3, 0x56B4, 0x56B5, 0x56B6, 0x56B8, 0x56B9,
+    0x56BA, 0x56BB, 0x56BD, 0x56BE, 0x56BF, 0x56C0, 0x56C1, 0x56C2, 0x56C3, 0x56C4, 0x56C5, 0x56C6,
+    0x56C7, 0x56C8, 0x56C9, 0x56CB, 0x56CC, 0x56CD, 0x56CE, 0x56CF, 0x56D0, 0x56D1, 0x56D2, 0x56D3,
+    0x56D5, 0x56D6, 0x56D8, 0x56D9, 0x56DC, 0x56E3, 0x56E5, 0x56E6, 0x56E7, 0x56E8, 0x56E9, 0x56EA,
+    0x56EC, 0x56EE, 0x56EF, 0x56F2, 0x56F3, 0x56F6, 0x56F7, 0x56F8, 0x56FB, 0x56FC, 0x5700, 0x5701,
+    0x5702, 0x5705, 0x5707, 0x570B, 0x570C, 0x570D, 0x570E, 0x570F, 0x5710, 0x5711, 0x5712, 0x5713,
+    0x5714, 0x5715, 0x5716, 0x5717, 0x5718, 0x5719, 0x571A, 0x571B, 0x571D, 0x571E, 0x5720, 0x5721,
+    0x5722, 0x5724, 0x5725, 0x5726, 0x5727, 0x572B, 0x5731, 0x5732, 0x5734, 0x5735, 0x5736, 0x5737,
+    0x5738, 0x573C, 0x573D, 0x573F, 0x5741, 0x5743, 0x5744, 0x5745, 0x5746, 0x5748, 0x5749, 0x574B,
+    0x5752, 0x5753, 0x5754, 0x5755, 0x5756, 0x5758, 0x5759, 0x5762, 0x5763, 0x5765, 0x5767, 0x576C,
+    0x576E, 0x5770, 0x5771, 0x5772, 0x5774, 0x5775, 0x5778, 0x5779, 0x577A, 0x577D, 0x577E, 0x577F,
+    0x5780, 0x5781, 0x5787, 0x5788, 0x5789, 0x578A, 0x578D, 0x578E, 0x578F, 0x5790, 0x5791, 0x5794,
+    0x5795, 0x5796, 0x5797, 0x5798, 0x5799, 0x579A, 0x579C, 0x579D, 0x579E, 0x579F, 0x57A5, 0x57A8,
+    0x57AA, 0x57AC, 0x57AF, 0x57B0, 0x57B1, 0x57B3, 0x57B5, 0x57B6, 0x57B7, 0x57B9, 0x57BA, 0x57BB,
+    0x57BC, 0x57BD, 0x57BE, 0x57BF, 0x57C0, 0x57C1, 0x57C4, 0x57C5, 0x57C6, 0x57C7, 0x57C8, 0x57C9,
+    0x57CA, 0x57CC, 0x57CD, 0x57D0, 0x57D1, 0x57D3, 0x57D6, 0x57D7, 0x57DB, 0x57DC, 0x57DE, 0x57E1,
+    0x57E2, 0x57E3, 0x57E5, 0x57E6, 0x57E7, 0x57E8, 0x57E9, 0x57EA, 0x57EB, 0x57EC, 0x57EE, 0x57F0,
+    0x57F1, 0x57F2, 0x57F3, 0x57F5, 0x57F6, 0x57F7, 0x57FB, 0x57FC, 0x57FE, 0x57FF, 0x5801, 0x5803,
+    0x5804, 0x5805, 0x5808, 0x5809, 0x580A, 0x580C, 0x580E, 0x580F, 0x5810, 0x5812, 0x5813, 0x5814,
+    0x5816, 0x5817, 0x5818, 0x581A, 0x581B, 0x581C, 0x581D, 0x581F, 0x5822, 0x5823, 0x5825, 0x5826,
+    0x5827, 0x5828, 0x5829, 0x582B, 0x582C, 0x582D, 0x582E, 0x582F, 0x5831, 0x5832, 0x5833, 0x5834,
+    0x5836, 0x5837, 0x5838, 0x5839, 0x583A, 0x583B, 0x583C, 0x583D, 0x583E, 0x583F, 0x5840, 0x5841,
+    0x5842, 0x5843, 0x5845, 0x5846, 0x5847, 0x5848, 0x5849, 0x584A, 0x584B, 0x584E, 0x584F, 0x5850,
+    0x5852, 0x5853, 0x5855, 0x5856, 0x5857, 0x5859, 0x585A, 0x585B, 0x585C, 0x585D, 0x585F, 0x5860,
+    0x5861, 0x5862, 0x5863, 0x5864, 0x5866, 0x5867, 0x5868, 0x5869, 0x586A, 0x586D, 0x586E, 0x586F,
+    0x5870, 0x5871, 0x5872, 0x5873, 0x5874, 0x5875, 0x5876, 0x5877, 0x5878, 0x5879, 0x587A, 0x587B,
+    0x587C, 0x587D, 0x587F, 0x5882, 0x5884, 0x5886, 0x5887, 0x5888, 0x588A, 0x588B, 0x588C, 0x588D,
+    0x588E, 0x588F, 0x5890, 0x5891, 0x5894, 0x5895, 0x5896, 0x5897, 0x5898, 0x589B, 0x589C, 0x589D,
+    0x58A0, 0x58A1, 0x58A2, 0x58A3, 0x58A4, 0x58A5, 0x58A6, 0x58A7, 0x58AA, 0x58AB, 0x58AC, 0x58AD,
+    0x58AE, 0x58AF, 0x58B0, 0x58B1, 0x58B2, 0x58B3, 0x58B4, 0x58B5, 0x58B6, 0x58B7, 0x58B8, 0x58B9,
+    0x58BA, 0x58BB, 0x58BD, 0x58BE, 0x58BF, 0x58C0, 0x58C2, 0x58C3, 0x58C4, 0x58C6, 0x58C7, 0x58C8,
+    0x58C9, 0x58CA, 0x58CB, 0x58CC, 0x58CD, 0x58CE, 0x58CF, 0x58D0, 0x58D2, 0x58D3, 0x58D4, 0x58D6,
+    0x58D7, 0x58D8, 0x58D9, 0x58DA, 0x58DB, 0x58DC, 0x58DD, 0x58DE, 0x58DF, 0x58E0, 0x58E1, 0x58E2,
+    0x58E3, 0x58E5, 0x58E6, 0x58E7, 0x58E8, 0x58E9, 0x58EA, 0x58ED, 0x58EF, 0x58F1, 0x58F2, 0x58F4,
+    0x58F5, 0x58F7, 0x58F8, 0x58FA, 0x58FB, 0x58FC, 0x58FD, 0x58FE, 0x58FF, 0x5900, 0x5901, 0x5903,
+    0x5905, 0x5906, 0x5908, 0x5909, 0x590A, 0x590B, 0x590C, 0x590E, 0x5910, 0x5911, 0x5912, 0x5913,
+    0x5917, 0x5918, 0x591B, 0x591D, 0x591E, 0x5920, 0x5921, 0x5922, 0x5923, 0x5926, 0x5928, 0x592C,
+    0x5930, 0x5932, 0x5933, 0x5935, 0x5936, 0x593B, 0x593D, 0x593E, 0x593F, 0x5940, 0x5943, 0x5945,
+    0x5946, 0x594A, 0x594C, 0x594D, 0x5950, 0x5952, 0x5953, 0x5959, 0x595B, 0x595C, 0x595D, 0x595E,
+    0x595F, 0x5961, 0x5963, 0x5964, 0x5966, 0x5967, 0x5968, 0x5969, 0x596A, 0x596B, 0x596C, 0x596D,
+    0x596E, 0x596F, 0x5970, 0x5971, 0x5972, 0x5975, 0x5977, 0x597A, 0x597B, 0x597C, 0x597E, 0x597F,
+    0x5980, 0x5985, 0x5989, 0x598B, 0x598C, 0x598E, 0x598F, 0x5990, 0x5991, 0x5994, 0x5995, 0x5998,
+    0x599A, 0x599B, 0x599C, 0x599D, 0x599F, 0x59A0, 0x59A1, 0x59A2, 0x59A6, 0x59A7, 0x59AC, 0x59AD,
+    0x59B0, 0x59B1, 0x59B3, 0x59B4, 0x59B5, 0x59B6, 0x59B7, 0x59B8, 0x59BA, 0x59BC, 0x59BD, 0x59BF,
+    0x59C0, 0x59C1, 0x59C2, 0x59C3, 0x59C4, 0x59C5, 0x59C7, 0x59C8, 0x59C9, 0x59CC, 0x59CD, 0x59CE,
+    0x59CF, 0x59D5, 0x59D6, 0x59D9, 0x59DB, 0x59DE, 0x59DF, 0x59E0, 0x59E1, 0x59E2, 0x59E4, 0x59E6,
+    0x59E7, 0x59E9, 0x59EA, 0x59EB, 0x59ED, 0x59EE, 0x59EF, 0x59F0, 0x59F1, 0x59F2, 0x59F3, 0x59F4,
+    0x59F5, 0x59F6, 0x59F7, 0x59F8, 0x59FA, 0x59FC, 0x59FD, 0x59FE, 0x5A00, 0x5A02, 0x5A0A, 0x5A0B,
+    0x5A0D, 0x5A0E, 0x5A0F, 0x5A10, 0x5A12, 0x5A14, 0x5A15, 0x5A16, 0x5A17, 0x5A19, 0x5A1A, 0x5A1B,
+    0x5A1D, 0x5A1E, 0x5A21, 0x5A22, 0x5A24, 0x5A26, 0x5A27, 0x5A28, 0x5A2A, 0x5A2B, 0x5A2C, 0x5A2D,
+    0x5A2E, 0x5A2F, 0x5A30, 0x5A33, 0x5A35, 0x5A37, 0x5A38, 0x5A39, 0x5A3A, 0x5A3B, 0x5A3D, 0x5A3E,
+    0x5A3F, 0x5A41, 0x5A42, 0x5A43, 0x5A44, 0x5A45, 0x5A47, 0x5A48, 0x5A4B, 0x5A4C, 0x5A4D, 0x5A4E,
+    0x5A4F, 0x5A50, 0x5A51, 0x5A52, 0x5A53, 0x5A54, 0x5A56, 0x5A57, 0x5A58, 0x5A59, 0x5A5B, 0x5A5C,
+    0x5A5D, 0x5A5E, 0x5A5F, 0x5A60, 0x5A61, 0x5A63, 0x5A64, 0x5A65, 0x5A66, 0x5A68, 0x5A69, 0x5A6B,
+    0x5A6C, 0x5A6D, 0x5A6E, 0x5A6F, 0x5A70, 0x5A71, 0x5A72, 0x5A73, 0x5A78, 0x5A79, 0x5A7B, 0x5A7C,
+    0x5A7D, 0x5A7E, 0x5A80, 0x5A81, 0x5A82, 0x5A83, 0x5A84, 0x5A85, 0x5A86, 0x5A87, 0x5A88, 0x5A89,
+    0x5A8A, 0x5A8B, 0x5A8C, 0x5A8D, 0x5A8E, 0x5A8F, 0x5A90, 0x5A91, 0x5A93, 0x5A94, 0x5A95, 0x5A96,
+    0x5A97, 0x5A98, 0x5A99, 0x5A9C, 0x5A9D, 0x5A9E, 0x5A9F, 0x5AA0, 0x5AA1, 0x5AA2, 0x5AA3, 0x5AA4,
+    0x5AA5, 0x5AA6, 0x5AA7, 0x5AA8, 0x5AA9, 0x5AAB, 0x5AAC, 0x5AAD, 0x5AAE, 0x5AAF, 0x5AB0, 0x5AB1,
+    0x5AB4, 0x5AB6, 0x5AB7, 0x5AB9, 0x5ABA, 0x5ABB, 0x5ABC, 0x5ABD, 0x5ABF, 0x5AC0, 0x5AC3, 0x5AC4,
+    0x5AC5, 0x5AC6, 0x5AC7, 0x5AC8, 0x5ACA, 0x5ACB, 0x5ACD, 0x5ACE, 0x5ACF, 0x5AD0, 0x5AD1, 0x5AD3,
+    0x5AD5, 0x5AD7, 0x5AD9, 0x5ADA, 0x5ADB, 0x5ADD, 0x5ADE, 0x5ADF, 0x5AE2, 0x5AE4, 0x5AE5, 0x5AE7,
+    0x5AE8, 0x5AEA, 0x5AEC, 0x5AED, 0x5AEE, 0x5AEF, 0x5AF0, 0x5AF2, 0x5AF3, 0x5AF4, 0x5AF5, 0x5AF6,
+    0x5AF7, 0x5AF8, 0x5AF9, 0x5AFA, 0x5AFB, 0x5AFC, 0x5AFD, 0x5AFE, 0x5AFF, 0x5B00, 0x5B01, 0x5B02,
+    0x5B03, 0x5B04, 0x5B05, 0x5B06, 0x5B07, 0x5B08, 0x5B0A, 0x5B0B, 0x5B0C, 0x5B0D, 0x5B0E, 0x5B0F,
+    0x5B10, 0x5B11, 0x5B12, 0x5B13, 0x5B14, 0x5B15, 0x5B18, 0x5B19, 0x5B1A, 0x5B1B, 0x5B1C, 0x5B1D,
+    0x5B1E, 0x5B1F, 0x5B20, 0x5B21, 0x5B22, 0x5B23, 0x5B24, 0x5B25, 0x5B26, 0x5B27, 0x5B28, 0x5B29,
+    0x5B2A, 0x5B2B, 0x5B2C, 0x5B2D, 0x5B2E, 0x5B2F, 0x5B30, 0x5B31, 0x5B33, 0x5B35, 0x5B36, 0x5B38,
+    0x5B39, 0x5B3A, 0x5B3B, 0x5B3C, 0x5B3D, 0x5B3E, 0x5B3F, 0x5B41, 0x5B42, 0x5B43, 0x5B44, 0x5B45,
+    0x5B46, 0x5B47, 0x5B48, 0x5B49, 0x5B4A, 0x5B4B, 0x5B4C, 0x5B4D, 0x5B4E, 0x5B4F, 0x5B52, 0x5B56,
+    0x5B5E, 0x5B60, 0x5B61, 0x5B67, 0x5B68, 0x5B6B, 0x5B6D, 0x5B6E, 0x5B6F, 0x5B72, 0x5B74, 0x5B76,
+    0x5B77, 0x5B78, 0x5B79, 0x5B7B, 0x5B7C, 0x5B7E, 0x5B7F, 0x5B82, 0x5B86, 0x5B8A, 0x5B8D, 0x5B8E,
+    0x5B90, 0x5B91, 0x5B92, 0x5B94, 0x5B96, 0x5B9F, 0x5BA7, 0x5BA8, 0x5BA9, 0x5BAC, 0x5BAD, 0x5BAE,
+    0x5BAF, 0x5BB1, 0x5BB2, 0x5BB7, 0x5BBA, 0x5BBB, 0x5BBC, 0x5BC0, 0x5BC1, 0x5BC3, 0x5BC8, 0x5BC9,
+    0x5BCA, 0x5BCB, 0x5BCD, 0x5BCE, 0x5BCF, 0x5BD1, 0x5BD4, 0x5BD5, 0x5BD6, 0x5BD7, 0x5BD8, 0x5BD9,
+    0x5BDA, 0x5BDB, 0x5BDC, 0x5BE0, 0x5BE2, 0x5BE3, 0x5BE6, 0x5BE7, 0x5BE9, 0x5BEA, 0x5BEB, 0x5BEC,
+    0x5BED, 0x5BEF, 0x5BF1, 0x5BF2, 0x5BF3, 0x5BF4, 0x5BF5, 0x5BF6, 0x5BF7, 0x5BFD, 0x5BFE, 0x5C00,
+    0x5C02, 0x5C03, 0x5C05, 0x5C07, 0x5C08, 0x5C0B, 0x5C0C, 0x5C0D, 0x5C0E, 0x5C10, 0x5C12, 0x5C13,
+    0x5C17, 0x5C19, 0x5C1B, 0x5C1E, 0x5C1F, 0x5C20, 0x5C21, 0x5C23, 0x5C26, 0x5C28, 0x5C29, 0x5C2A,
+    0x5C2B, 0x5C2D, 0x5C2E, 0x5C2F, 0x5C30, 0x5C32, 0x5C33, 0x5C35, 0x5C36, 0x5C37, 0x5C43, 0x5C44,
+    0x5C46, 0x5C47, 0x5C4C, 0x5C4D, 0x5C52, 0x5C53, 0x5C54, 0x5C56, 0x5C57, 0x5C58, 0x5C5A, 0x5C5B,
+    0x5C5C, 0x5C5D, 0x5C5F, 0x5C62, 0x5C64, 0x5C67, 0x5C68, 0x5C69, 0x5C6A, 0x5C6B, 0x5C6C, 0x5C6D,
+    0x5C70, 0x5C72, 0x5C73, 0x5C74, 0x5C75, 0x5C76, 0x5C77, 0x5C78, 0x5C7B, 0x5C7C, 0x5C7D, 0x5C7E,
+    0x5C80, 0x5C83, 0x5C84, 0x5C85, 0x5C86, 0x5C87, 0x5C89, 0x5C8A, 0x5C8B, 0x5C8E, 0x5C8F, 0x5C92,
+    0x5C93, 0x5C95, 0x5C9D, 0x5C9E, 0x5C9F, 0x5CA0, 0x5CA1, 0x5CA4, 0x5CA5, 0x5CA6, 0x5CA7, 0x5CA8,
+    0x5CAA, 0x5CAE, 0x5CAF, 0x5CB0, 0x5CB2, 0x5CB4, 0x5CB6, 0x5CB9, 0x5CBA, 0x5CBB, 0x5CBC, 0x5CBE,
+    0x5CC0, 0x5CC2, 0x5CC3, 0x5CC5, 0x5CC6, 0x5CC7, 0x5CC8, 0x5CC9, 0x5CCA, 0x5CCC, 0x5CCD, 0x5CCE,
+    0x5CCF, 0x5CD0, 0x5CD1, 0x5CD3, 0x5CD4, 0x5CD5, 0x5CD6, 0x5CD7, 0x5CD8, 0x5CDA, 0x5CDB, 0x5CDC,
+    0x5CDD, 0x5CDE, 0x5CDF, 0x5CE0, 0x5CE2, 0x5CE3, 0x5CE7, 0x5CE9, 0x5CEB, 0x5CEC, 0x5CEE, 0x5CEF,
+    0x5CF1, 0x5CF2, 0x5CF3, 0x5CF4, 0x5CF5, 0x5CF6, 0x5CF7, 0x5CF8, 0x5CF9, 0x5CFA, 0x5CFC, 0x5CFD,
+    0x5CFE, 0x5CFF, 0x5D00, 0x5D01, 0x5D04, 0x5D05, 0x5D08, 0x5D09, 0x5D0A, 0x5D0B, 0x5D0C, 0x5D0D,
+    0x5D0F, 0x5D10, 0x5D11, 0x5D12, 0x5D13, 0x5D15, 0x5D17, 0x5D18, 0x5D19, 0x5D1A, 0x5D1C, 0x5D1D,
+    0x5D1F, 0x5D20, 0x5D21, 0x5D22, 0x5D23, 0x5D25, 0x5D28, 0x5D2A, 0x5D2B, 0x5D2C, 0x5D2F, 0x5D30,
+    0x5D31, 0x5D32, 0x5D33, 0x5D35, 0x5D36, 0x5D37, 0x5D38, 0x5D39, 0x5D3A, 0x5D3B, 0x5D3C, 0x5D3F,
+    0x5D40, 0x5D41, 0x5D42, 0x5D43, 0x5D44, 0x5D45, 0x5D46, 0x5D48, 0x5D49, 0x5D4D, 0x5D4E, 0x5D4F,
+    0x5D50, 0x5D51, 0x5D52, 0x5D53, 0x5D54, 0x5D55, 0x5D56, 0x5D57, 0x5D59, 0x5D5A, 0x5D5C, 0x5D5E,
+    0x5D5F, 0x5D60, 0x5D61, 0x5D62, 0x5D63, 0x5D64, 0x5D65, 0x5D66, 0x5D67, 0x5D68, 0x5D6A, 0x5D6D,
+    0x5D6E, 0x5D70, 0x5D71, 0x5D72, 0x5D73, 0x5D75, 0x5D76, 0x5D77, 0x5D78, 0x5D79, 0x5D7A, 0x5D7B,
+    0x5D7C, 0x5D7D, 0x5D7E, 0x5D7F, 0x5D80, 0x5D81, 0x5D83, 0x5D84, 0x5D85, 0x5D86, 0x5D87, 0x5D88,
+    0x5D89, 0x5D8A, 0x5D8B, 0x5D8C, 0x5D8D, 0x5D8E, 0x5D8F, 0x5D90, 0x5D91, 0x5D92, 0x5D93, 0x5D94,
+    0x5D95, 0x5D96, 0x5D97, 0x5D98, 0x5D9A, 0x5D9B, 0x5D9C, 0x5D9E, 0x5D9F, 0x5DA0, 0x5DA1, 0x5DA2,
+    0x5DA3, 0x5DA4, 0x5DA5, 0x5DA6, 0x5DA7, 0x5DA8, 0x5DA9, 0x5DAA, 0x5DAB, 0x5DAC, 0x5DAD, 0x5DAE,
+    0x5DAF, 0x5DB0, 0x5DB1, 0x5DB2, 0x5DB3, 0x5DB4, 0x5DB5, 0x5DB6, 0x5DB8, 0x5DB9, 0x5DBA, 0x5DBB,
+    0x5DBC, 0x5DBD, 0x5DBE, 0x5DBF, 0x5DC0, 0x5DC1, 0x5DC2, 0x5DC3, 0x5DC4, 0x5DC6, 0x5DC7, 0x5DC8,
+    0x5DC9, 0x5DCA, 0x5DCB, 0x5DCC, 0x5DCE, 0x5DCF, 0x5DD0, 0x5DD1, 0x5DD2, 0x5DD3, 0x5DD4, 0x5DD5,
+    0x5DD6, 0x5DD7, 0x5DD8, 0x5DD9, 0x5DDA, 0x5DDC, 0x5DDF, 0x5DE0, 0x5DE3, 0x5DE4, 0x5DEA, 0x5DEC,
+    0x5DED, 0x5DF0, 0x5DF5, 0x5DF6, 0x5DF8, 0x5DF9, 0x5DFA, 0x5DFB, 0x5DFC, 0x5DFF, 0x5E00, 0x5E04,
+    0x5E07, 0x5E09, 0x5E0A, 0x5E0B, 0x5E0D, 0x5E0E, 0x5E12, 0x5E13, 0x5E17, 0x5E1E, 0x5E1F, 0x5E20,
+    0x5E21, 0x5E22, 0x5E23, 0x5E24, 0x5E25, 0x5E28, 0x5E29, 0x5E2A, 0x5E2B, 0x5E2C, 0x5E2F, 0x5E30,
+    0x5E32, 0x5E33, 0x5E34, 0x5E35, 0x5E36, 0x5E39, 0x5E3A, 0x5E3E, 0x5E3F, 0x5E40, 0x5E41, 0x5E43,
+    0x5E46, 0x5E47, 0x5E48, 0x5E49, 0x5E4A, 0x5E4B, 0x5E4D, 0x5E4E, 0x5E4F, 0x5E50, 0x5E51, 0x5E52,
+    0x5E53, 0x5E56, 0x5E57, 0x5E58, 0x5E59, 0x5E5A, 0x5E5C, 0x5E5D, 0x5E5F, 0x5E60, 0x5E63, 0x5E64,
+    0x5E65, 0x5E66, 0x5E67, 0x5E68, 0x5E69, 0x5E6A, 0x5E6B, 0x5E6C, 0x5E6D, 0x5E6E, 0x5E6F, 0x5E70,
+    0x5E71, 0x5E75, 0x5E77, 0x5E79, 0x5E7E, 0x5E81, 0x5E82, 0x5E83, 0x5E85, 0x5E88, 0x5E89, 0x5E8C,
+    0x5E8D, 0x5E8E, 0x5E92, 0x5E98, 0x5E9B, 0x5E9D, 0x5EA1, 0x5EA2, 0x5EA3, 0x5EA4, 0x5EA8, 0x5EA9,
+    0x5EAA, 0x5EAB, 0x5EAC, 0x5EAE, 0x5EAF, 0x5EB0, 0x5EB1, 0x5EB2, 0x5EB4, 0x5EBA, 0x5EBB, 0x5EBC,
+    0x5EBD, 0x5EBF, 0x5EC0, 0x5EC1, 0x5EC2, 0x5EC3, 0x5EC4, 0x5EC5, 0x5EC6, 0x5EC7, 0x5EC8, 0x5ECB,
+    0x5ECC, 0x5ECD, 0x5ECE, 0x5ECF, 0x5ED0, 0x5ED4, 0x5ED5, 0x5ED7, 0x5ED8, 0x5ED9, 0x5EDA, 0x5EDC,
+    0x5EDD, 0x5EDE, 0x5EDF, 0x5EE0, 0x5EE1, 0x5EE2, 0x5EE3, 0x5EE4, 0x5EE5, 0x5EE6, 0x5EE7, 0x5EE9,
+    0x5EEB, 0x5EEC, 0x5EED, 0x5EEE, 0x5EEF, 0x5EF0, 0x5EF1, 0x5EF2, 0x5EF3, 0x5EF5, 0x5EF8, 0x5EF9,
+    0x5EFB, 0x5EFC, 0x5EFD, 0x5F05, 0x5F06, 0x5F07, 0x5F09, 0x5F0C, 0x5F0D, 0x5F0E, 0x5F10, 0x5F12,
+    0x5F14, 0x5F16, 0x5F19, 0x5F1A, 0x5F1C, 0x5F1D, 0x5F1E, 0x5F21, 0x5F22, 0x5F23, 0x5F24, 0x5F28,
+    0x5F2B, 0x5F2C, 0x5F2E, 0x5F30, 0x5F32, 0x5F33, 0x5F34, 0x5F35, 0x5F36, 0x5F37, 0x5F38, 0x5F3B,
+    0x5F3D, 0x5F3E, 0x5F3F, 0x5F41, 0x5F42, 0x5F43, 0x5F44, 0x5F45, 0x5F46, 0x5F47, 0x5F48, 0x5F49,
+    0x5F4A, 0x5F4B, 0x5F4C, 0x5F4D, 0x5F4E, 0x5F4F, 0x5F51, 0x5F54, 0x5F59, 0x5F5A, 0x5F5B, 0x5F5C,
+    0x5F5E, 0x5F5F, 0x5F60, 0x5F63, 0x5F65, 0x5F67, 0x5F68, 0x5F6B, 0x5F6E, 0x5F6F, 0x5F72, 0x5F74,
+    0x5F75, 0x5F76, 0x5F78, 0x5F7A, 0x5F7D, 0x5F7E, 0x5F7F, 0x5F83, 0x5F86, 0x5F8D, 0x5F8E, 0x5F8F,
+    0x5F91, 0x5F93, 0x5F94, 0x5F96, 0x5F9A, 0x5F9B, 0x5F9D, 0x5F9E, 0x5F9F, 0x5FA0, 0x5FA2, 0x5FA3,
+    0x5FA4, 0x5FA5, 0x5FA6, 0x5FA7, 0x5FA9, 0x5FAB, 0x5FAC, 0x5FAF, 0x5FB0, 0x5FB1, 0x5FB2, 0x5FB3,
+    0x5FB4, 0x5FB6, 0x5FB8, 0x5FB9, 0x5FBA, 0x5FBB, 0x5FBE, 0x5FBF, 0x5FC0, 0x5FC1, 0x5FC2, 0x5FC7,
+    0x5FC8, 0x5FCA, 0x5FCB, 0x5FCE, 0x5FD3, 0x5FD4, 0x5FD5, 0x5FDA, 0x5FDB, 0x5FDC, 0x5FDE, 0x5FDF,
+    0x5FE2, 0x5FE3, 0x5FE5, 0x5FE6, 0x5FE8, 0x5FE9, 0x5FEC, 0x5FEF, 0x5FF0, 0x5FF2, 0x5FF3, 0x5FF4,
+    0x5FF6, 0x5FF7, 0x5FF9, 0x5FFA, 0x5FFC, 0x6007, 0x6008, 0x6009, 0x600B, 0x600C, 0x6010, 0x6011,
+    0x6013, 0x6017, 0x6018, 0x601A, 0x601E, 0x601F, 0x6022, 0x6023, 0x6024, 0x602C, 0x602D, 0x602E,
+    0x6030, 0x6031, 0x6032, 0x6033, 0x6034, 0x6036, 0x6037, 0x6038, 0x6039, 0x603A, 0x603D, 0x603E,
+    0x6040, 0x6044, 0x6045, 0x6046, 0x6047, 0x6048, 0x6049, 0x604A, 0x604C, 0x604E, 0x604F, 0x6051,
+    0x6053, 0x6054, 0x6056, 0x6057, 0x6058, 0x605B, 0x605C, 0x605E, 0x605F, 0x6060, 0x6061, 0x6065,
+    0x6066, 0x606E, 0x6071, 0x6072, 0x6074, 0x6075, 0x6077, 0x607E, 0x6080, 0x6081, 0x6082, 0x6085,
+    0x6086, 0x6087, 0x6088, 0x608A, 0x608B, 0x608E, 0x608F, 0x6090, 0x6091, 0x6093, 0x6095, 0x6097,
+    0x6098, 0x6099, 0x609C, 0x609E, 0x60A1, 0x60A2, 0x60A4, 0x60A5, 0x60A7, 0x60A9, 0x60AA, 0x60AE,
+    0x60B0, 0x60B3, 0x60B5, 0x60B6, 0x60B7, 0x60B9, 0x60BA, 0x60BD, 0x60BE, 0x60BF, 0x60C0, 0x60C1,
+    0x60C2, 0x60C3, 0x60C4, 0x60C7, 0x60C8, 0x60C9, 0x60CC, 0x60CD, 0x60CE, 0x60CF, 0x60D0, 0x60D2,
+    0x60D3, 0x60D4, 0x60D6, 0x60D7, 0x60D9, 0x60DB, 0x60DE, 0x60E1, 0x60E2, 0x60E3, 0x60E4, 0x60E5,
+    0x60EA, 0x60F1, 0x60F2, 0x60F5, 0x60F7, 0x60F8, 0x60FB, 0x60FC, 0x60FD, 0x60FE, 0x60FF, 0x6102,
+    0x6103, 0x6104, 0x6105, 0x6107, 0x610A, 0x610B, 0x610C, 0x6110, 0x6111, 0x6112, 0x6113, 0x6114,
+    0x6116, 0x6117, 0x6118, 0x6119, 0x611B, 0x611C, 0x611D, 0x611E, 0x6121, 0x6122, 0x6125, 0x6128,
+    0x6129, 0x612A, 0x612C, 0x612D, 0x612E, 0x612F, 0x6130, 0x6131, 0x6132, 0x6133, 0x6134, 0x6135,
+    0x6136, 0x6137, 0x6138, 0x6139, 0x613A, 0x613B, 0x613C, 0x613D, 0x613E, 0x6140, 0x6141, 0x6142,
+    0x6143, 0x6144, 0x6145, 0x6146, 0x6147, 0x6149, 0x614B, 0x614D, 0x614F, 0x6150, 0x6152, 0x6153,
+    0x6154, 0x6156, 0x6157, 0x6158, 0x6159, 0x615A, 0x615B, 0x615C, 0x615E, 0x615F, 0x6160, 0x6161,
+    0x6163, 0x6164, 0x6165, 0x6166, 0x6169, 0x616A, 0x616B, 0x616C, 0x616D, 0x616E, 0x616F, 0x6171,
+    0x6172, 0x6173, 0x6174, 0x6176, 0x6178, 0x6179, 0x617A, 0x617B, 0x617C, 0x617D, 0x617E, 0x617F,
+    0x6180, 0x6181, 0x6182, 0x6183, 0x6184, 0x6185, 0x6186, 0x6187, 0x6188, 0x6189, 0x618A, 0x618C,
+    0x618D, 0x618F, 0x6190, 0x6191, 0x6192, 0x6193, 0x6195, 0x6196, 0x6197, 0x6198, 0x6199, 0x619A,
+    0x619B, 0x619C, 0x619E, 0x619F, 0x61A0, 0x61A1, 0x61A2, 0x61A3, 0x61A4, 0x61A5, 0x61A6, 0x61AA,
+    0x61AB, 0x61AD, 0x61AE, 0x61AF, 0x61B0, 0x61B1, 0x61B2, 0x61B3, 0x61B4, 0x61B5, 0x61B6, 0x61B8,
+    0x61B9, 0x61BA, 0x61BB, 0x61BC, 0x61BD, 0x61BF, 0x61C0, 0x61C1, 0x61C3, 0x61C4, 0x61C5, 0x61C6,
+    0x61C7, 0x61C9, 0x61CC, 0x61CD, 0x61CE, 0x61CF, 0x61D0, 0x61D3, 0x61D5, 0x61D6, 0x61D7, 0x61D8,
+    0x61D9, 0x61DA, 0x61DB, 0x61DC, 0x61DD, 0x61DE, 0x61DF, 0x61E0, 0x61E1, 0x61E2, 0x61E3, 0x61E4,
+    0x61E5, 0x61E7, 0x61E8, 0x61E9, 0x61EA, 0x61EB, 0x61EC, 0x61ED, 0x61EE, 0x61EF, 0x61F0, 0x61F1,
+    0x61F2, 0x61F3, 0x61F4, 0x61F6, 0x61F7, 0x61F8, 0x61F9, 0x61FA, 0x61FB, 0x61FC, 0x61FD, 0x61FE,
+    0x6200, 0x6201, 0x6202, 0x6203, 0x6204, 0x6205, 0x6207, 0x6209, 0x6213, 0x6214, 0x6219, 0x621C,
+    0x621D, 0x621E, 0x6220, 0x6223, 0x6226, 0x6227, 0x6228, 0x6229, 0x622B, 0x622D, 0x622F, 0x6230,
+    0x6231, 0x6232, 0x6235, 0x6236, 0x6238, 0x6239, 0x623A, 0x623B, 0x623C, 0x6242, 0x6244, 0x6245,
+    0x6246, 0x624A, 0x624F, 0x6250, 0x6255, 0x6256, 0x6257, 0x6259, 0x625A, 0x625C, 0x625D, 0x625E,
+    0x625F, 0x6260, 0x6261, 0x6262, 0x6264, 0x6265, 0x6268, 0x6271, 0x6272, 0x6274, 0x6275, 0x6277,
+    0x6278, 0x627A, 0x627B, 0x627D, 0x6281, 0x6282, 0x6283, 0x6285, 0x6286, 0x6287, 0x6288, 0x628B,
+    0x628C, 0x628D, 0x628E, 0x628F, 0x6290, 0x6294, 0x6299, 0x629C, 0x629D, 0x629E, 0x62A3, 0x62A6,
+    0x62A7, 0x62A9, 0x62AA, 0x62AD, 0x62AE, 0x62AF, 0x62B0, 0x62B2, 0x62B3, 0x62B4, 0x62B6, 0x62B7,
+    0x62B8, 0x62BA, 0x62BE, 0x62C0, 0x62C1, 0x62C3, 0x62CB, 0x62CF, 0x62D1, 0x62D5, 0x62DD, 0x62DE,
+    0x62E0, 0x62E1, 0x62E4, 0x62EA, 0x62EB, 0x62F0, 0x62F2, 0x62F5, 0x62F8, 0x62F9, 0x62FA, 0x62FB,
+    0x6300, 0x6303, 0x6304, 0x6305, 0x6306, 0x630A, 0x630B, 0x630C, 0x630D, 0x630F, 0x6310, 0x6312,
+    0x6313, 0x6314, 0x6315, 0x6317, 0x6318, 0x6319, 0x631C, 0x6326, 0x6327, 0x6329, 0x632C, 0x632D,
+    0x632E, 0x6330, 0x6331, 0x6333, 0x6334, 0x6335, 0x6336, 0x6337, 0x6338, 0x633B, 0x633C, 0x633E,
+    0x633F, 0x6340, 0x6341, 0x6344, 0x6347, 0x6348, 0x634A, 0x6351, 0x6352, 0x6353, 0x6354, 0x6356,
+    0x6357, 0x6358, 0x6359, 0x635A, 0x635B, 0x635C, 0x635D, 0x6360, 0x6364, 0x6365, 0x6366, 0x6368,
+    0x636A, 0x636B, 0x636C, 0x636F, 0x6370, 0x6372, 0x6373, 0x6374, 0x6375, 0x6378, 0x6379, 0x637C,
+    0x637D, 0x637E, 0x637F, 0x6381, 0x6383, 0x6384, 0x6385, 0x6386, 0x638B, 0x638D, 0x6391, 0x6393,
+    0x6394, 0x6395, 0x6397, 0x6399, 0x639A, 0x639B, 0x639C, 0x639D, 0x639E, 0x639F, 0x63A1, 0x63A4,
+    0x63A6, 0x63AB, 0x63AF, 0x63B1, 0x63B2, 0x63B5, 0x63B6, 0x63B9, 0x63BB, 0x63BD, 0x63BF, 0x63C0,
+    0x63C1, 0x63C2, 0x63C3, 0x63C5, 0x63C7, 0x63C8, 0x63CA, 0x63CB, 0x63CC, 0x63D1, 0x63D3, 0x63D4,
+    0x63D5, 0x63D7, 0x63D8, 0x63D9, 0x63DA, 0x63DB, 0x63DC, 0x63DD, 0x63DF, 0x63E2, 0x63E4, 0x63E5,
+    0x63E6, 0x63E7, 0x63E8, 0x63EB, 0x63EC, 0x63EE, 0x63EF, 0x63F0, 0x63F1, 0x63F3, 0x63F5, 0x63F7,
+    0x63F9, 0x63FA, 0x63FB, 0x63FC, 0x63FE, 0x6403, 0x6404, 0x6406, 0x6407, 0x6408, 0x6409, 0x640A,
+    0x640D, 0x640E, 0x6411, 0x6412, 0x6415, 0x6416, 0x6417, 0x6418, 0x6419, 0x641A, 0x641D, 0x641F,
+    0x6422, 0x6423, 0x6424, 0x6425, 0x6427, 0x6428, 0x6429, 0x642B, 0x642E, 0x642F, 0x6430, 0x6431,
+    0x6432, 0x6433, 0x6435, 0x6436, 0x6437, 0x6438, 0x6439, 0x643B, 0x643C, 0x643E, 0x6440, 0x6442,
+    0x6443, 0x6449, 0x644B, 0x644C, 0x644D, 0x644E, 0x644F, 0x6450, 0x6451, 0x6453, 0x6455, 0x6456,
+    0x6457, 0x6459, 0x645A, 0x645B, 0x645C, 0x645D, 0x645F, 0x6460, 0x6461, 0x6462, 0x6463, 0x6464,
+    0x6465, 0x6466, 0x6468, 0x646A, 0x646B, 0x646C, 0x646E, 0x646F, 0x6470, 0x6471, 0x6472, 0x6473,
+    0x6474, 0x6475, 0x6476, 0x6477, 0x647B, 0x647C, 0x647D, 0x647E, 0x647F, 0x6480, 0x6481, 0x6483,
+    0x6486, 0x6488, 0x6489, 0x648A, 0x648B, 0x648C, 0x648D, 0x648E, 0x648F, 0x6490, 0x6493, 0x6494,
+    0x6497, 0x6498, 0x649A, 0x649B, 0x649C, 0x649D, 0x649F, 0x64A0, 0x64A1, 0x64A2, 0x64A3, 0x64A5,
+    0x64A6, 0x64A7, 0x64A8, 0x64AA, 0x64AB, 0x64AF, 0x64B1, 0x64B2, 0x64B3, 0x64B4, 0x64B6, 0x64B9,
+    0x64BB, 0x64BD, 0x64BE, 0x64BF, 0x64C1, 0x64C3, 0x64C4, 0x64C6, 0x64C7, 0x64C8, 0x64C9, 0x64CA,
+    0x64CB, 0x64CC, 0x64CF, 0x64D1, 0x64D3, 0x64D4, 0x64D5, 0x64D6, 0x64D9, 0x64DA, 0x64DB, 0x64DC,
+    0x64DD, 0x64DF, 0x64E0, 0x64E1, 0x64E3, 0x64E5, 0x64E7, 0x64E8, 0x64E9, 0x64EA, 0x64EB, 0x64EC,
+    0x64ED, 0x64EE, 0x64EF, 0x64F0, 0x64F1, 0x64F2, 0x64F3, 0x64F4, 0x64F5, 0x64F6, 0x64F7, 0x64F8,
+    0x64F9, 0x64FA, 0x64FB, 0x64FC, 0x64FD, 0x64FE, 0x64FF, 0x6501, 0x6502, 0x6503, 0x6504, 0x6505,
+    0x6506, 0x6507, 0x6508, 0x650A, 0x650B, 0x650C, 0x650D, 0x650E, 0x650F, 0x6510, 0x6511, 0x6513,
+    0x6514, 0x6515, 0x6516, 0x6517, 0x6519, 0x651A, 0x651B, 0x651C, 0x651D, 0x651E, 0x651F, 0x6520,
+    0x6521, 0x6522, 0x6523, 0x6524, 0x6526, 0x6527, 0x6528, 0x6529, 0x652A, 0x652C, 0x652D, 0x6530,
+    0x6531, 0x6532, 0x6533, 0x6537, 0x653A, 0x653C, 0x653D, 0x6540, 0x6541, 0x6542, 0x6543, 0x6544,
+    0x6546, 0x6547, 0x654A, 0x654B, 0x654D, 0x654E, 0x6550, 0x6552, 0x6553, 0x6554, 0x6557, 0x6558,
+    0x655A, 0x655C, 0x655F, 0x6560, 0x6561, 0x6564, 0x6565, 0x6567, 0x6568, 0x6569, 0x656A, 0x656D,
+    0x656E, 0x656F, 0x6571, 0x6573, 0x6575, 0x6576, 0x6578, 0x6579, 0x657A, 0x657B, 0x657C, 0x657D,
+    0x657E, 0x657F, 0x6580, 0x6581, 0x6582, 0x6583, 0x6584, 0x6585, 0x6586, 0x6588, 0x6589, 0x658A,
+    0x658D, 0x658E, 0x658F, 0x6592, 0x6594, 0x6595, 0x6596, 0x6598, 0x659A, 0x659D, 0x659E, 0x65A0,
+    0x65A2, 0x65A3, 0x65A6, 0x65A8, 0x65AA, 0x65AC, 0x65AE, 0x65B1, 0x65B2, 0x65B3, 0x65B4, 0x65B5,
+    0x65B6, 0x65B7, 0x65B8, 0x65BA, 0x65BB, 0x65BE, 0x65BF, 0x65C0, 0x65C2, 0x65C7, 0x65C8, 0x65C9,
+    0x65CA, 0x65CD, 0x65D0, 0x65D1, 0x65D3, 0x65D4, 0x65D5, 0x65D8, 0x65D9, 0x65DA, 0x65DB, 0x65DC,
+    0x65DD, 0x65DE, 0x65DF, 0x65E1, 0x65E3, 0x65E4, 0x65EA, 0x65EB, 0x65F2, 0x65F3, 0x65F4, 0x65F5,
+    0x65F8, 0x65F9, 0x65FB, 0x65FC, 0x65FD, 0x65FE, 0x65FF, 0x6601, 0x6604, 0x6605, 0x6607, 0x6608,
+    0x6609, 0x660B, 0x660D, 0x6610, 0x6611, 0x6612, 0x6616, 0x6617, 0x6618, 0x661A, 0x661B, 0x661C,
+    0x661E, 0x6621, 0x6622, 0x6623, 0x6624, 0x6626, 0x6629, 0x662A, 0x662B, 0x662C, 0x662E, 0x6630,
+    0x6632, 0x6633, 0x6637, 0x6638, 0x6639, 0x663A, 0x663B, 0x663D, 0x663F, 0x6640, 0x6642, 0x6644,
+    0x6645, 0x6646, 0x6647, 0x6648, 0x6649, 0x664A, 0x664D, 0x664E, 0x6650, 0x6651, 0x6658, 0x6659,
+    0x665B, 0x665C, 0x665D, 0x665E, 0x6660, 0x6662, 0x6663, 0x6665, 0x6667, 0x6669, 0x666A, 0x666B,
+    0x666C, 0x666D, 0x6671, 0x6672, 0x6673, 0x6675, 0x6678, 0x6679, 0x667B, 0x667C, 0x667D, 0x667F,
+    0x6680, 0x6681, 0x6683, 0x6685, 0x6686, 0x6688, 0x6689, 0x668A, 0x668B, 0x668D, 0x668E, 0x668F,
+    0x6690, 0x6692, 0x6693, 0x6694, 0x6695, 0x6698, 0x6699, 0x669A, 0x669B, 0x669C, 0x669E, 0x669F,
+    0x66A0, 0x66A1, 0x66A2, 0x66A3, 0x66A4, 0x66A5, 0x66A6, 0x66A9, 0x66AA, 0x66AB, 0x66AC, 0x66AD,
+    0x66AF, 0x66B0, 0x66B1, 0x66B2, 0x66B3, 0x66B5, 0x66B6, 0x66B7, 0x66B8, 0x66BA, 0x66BB, 0x66BC,
+    0x66BD, 0x66BF, 0x66C0, 0x66C1, 0x66C2, 0x66C3, 0x66C4, 0x66C5, 0x66C6, 0x66C7, 0x66C8, 0x66C9,
+    0x66CA, 0x66CB, 0x66CC, 0x66CD, 0x66CE, 0x66CF, 0x66D0, 0x66D1, 0x66D2, 0x66D3, 0x66D4, 0x66D5,
+    0x66D6, 0x66D7, 0x66D8, 0x66DA, 0x66DE, 0x66DF, 0x66E0, 0x66E1, 0x66E2, 0x66E3, 0x66E4, 0x66E5,
+    0x66E7, 0x66E8, 0x66EA, 0x66EB, 0x66EC, 0x66ED, 0x66EE, 0x66EF, 0x66F1, 0x66F5, 0x66F6, 0x66F8,
+    0x66FA, 0x66FB, 0x66FD, 0x6701, 0x6702, 0x6703, 0x6704, 0x6705, 0x6706, 0x6707, 0x670C, 0x670E,
+    0x670F, 0x6711, 0x6712, 0x6713, 0x6716, 0x6718, 0x6719, 0x671A, 0x671C, 0x671E, 0x6720, 0x6721,
+    0x6722, 0x6723, 0x6724, 0x6725, 0x6727, 0x6729, 0x672E, 0x6730, 0x6732, 0x6733, 0x6736, 0x6737,
+    0x6738, 0x6739, 0x673B, 0x673C, 0x673E, 0x673F, 0x6741, 0x6744, 0x6745, 0x6747, 0x674A, 0x674B,
+    0x674D, 0x6752, 0x6754, 0x6755, 0x6757, 0x6758, 0x6759, 0x675A, 0x675B, 0x675D, 0x6762, 0x6763,
+    0x6764, 0x6766, 0x6767, 0x676B, 0x676C, 0x676E, 0x6771, 0x6774, 0x6776, 0x6778, 0x6779, 0x677A,
+    0x677B, 0x677D, 0x6780, 0x6782, 0x6783, 0x6785, 0x6786, 0x6788, 0x678A, 0x678C, 0x678D, 0x678E,
+    0x678F, 0x6791, 0x6792, 0x6793, 0x6794, 0x6796, 0x6799, 0x679B, 0x679F, 0x67A0, 0x67A1, 0x67A4,
+    0x67A6, 0x67A9, 0x67AC, 0x67AE, 0x67B1, 0x67B2, 0x67B4, 0x67B9, 0x67BA, 0x67BB, 0x67BC, 0x67BD,
+    0x67BE, 0x67BF, 0x67C0, 0x67C2, 0x67C5, 0x67C6, 0x67C7, 0x67C8, 0x67C9, 0x67CA, 0x67CB, 0x67CC,
+    0x67CD, 0x67CE, 0x67D5, 0x67D6, 0x67D7, 0x67DB, 0x67DF, 0x67E1, 0x67E3, 0x67E4, 0x67E6, 0x67E7,
+    0x67E8, 0x67EA, 0x67EB, 0x67ED, 0x67EE, 0x67F2, 0x67F5, 0x67F6, 0x67F7, 0x67F8, 0x67F9, 0x67FA,
+    0x67FB, 0x67FC, 0x67FE, 0x6801, 0x6802, 0x6803, 0x6804, 0x6806, 0x680D, 0x6810, 0x6812, 0x6814,
+    0x6815, 0x6818, 0x6819, 0x681A, 0x681B, 0x681C, 0x681E, 0x681F, 0x6820, 0x6822, 0x6823, 0x6824,
+    0x6825, 0x6826, 0x6827, 0x6828, 0x682B, 0x682C, 0x682D, 0x682E, 0x682F, 0x6830, 0x6831, 0x6834,
+    0x6835, 0x6836, 0x683A, 0x683B, 0x683F, 0x6847, 0x684B, 0x684D, 0x684F, 0x6852, 0x6856, 0x6857,
+    0x6858, 0x6859, 0x685A, 0x685B, 0x685C, 0x685D, 0x685E, 0x685F, 0x686A, 0x686C, 0x686D, 0x686E,
+    0x686F, 0x6870, 0x6871, 0x6872, 0x6873, 0x6875, 0x6878, 0x6879, 0x687A, 0x687B, 0x687C, 0x687D,
+    0x687E, 0x687F, 0x6880, 0x6882, 0x6884, 0x6887, 0x6888, 0x6889, 0x688A, 0x688B, 0x688C, 0x688D,
+    0x688E, 0x6890, 0x6891, 0x6892, 0x6894, 0x6895, 0x6896, 0x6898, 0x6899, 0x689A, 0x689B, 0x689C,
+    0x689D, 0x689E, 0x689F, 0x68A0, 0x68A1, 0x68A3, 0x68A4, 0x68A5, 0x68A9, 0x68AA, 0x68AB, 0x68AC,
+    0x68AE, 0x68B1, 0x68B2, 0x68B4, 0x68B6, 0x68B7, 0x68B8, 0x68B9, 0x68BA, 0x68BB, 0x68BC, 0x68BD,
+    0x68BE, 0x68BF, 0x68C1, 0x68C3, 0x68C4, 0x68C5, 0x68C6, 0x68C7, 0x68C8, 0x68CA, 0x68CC, 0x68CE,
+    0x68CF, 0x68D0, 0x68D1, 0x68D3, 0x68D4, 0x68D6, 0x68D7, 0x68D9, 0x68DB, 0x68DC, 0x68DD, 0x68DE,
+    0x68DF, 0x68E1, 0x68E2, 0x68E4, 0x68E5, 0x68E6, 0x68E7, 0x68E8, 0x68E9, 0x68EA, 0x68EB, 0x68EC,
+    0x68ED, 0x68EF, 0x68F2, 0x68F3, 0x68F4, 0x68F6, 0x68F7, 0x68F8, 0x68FB, 0x68FD, 0x68FE, 0x68FF,
+    0x6900, 0x6902, 0x6903, 0x6904, 0x6906, 0x6907, 0x6908, 0x6909, 0x690A, 0x690C, 0x690F, 0x6911,
+    0x6913, 0x6914, 0x6915, 0x6916, 0x6917, 0x6918, 0x6919, 0x691A, 0x691B, 0x691C, 0x691D, 0x691E,
+    0x6921, 0x6922, 0x6923, 0x6925, 0x6926, 0x6927, 0x6928, 0x6929, 0x692A, 0x692B, 0x692C, 0x692E,
+    0x692F, 0x6931, 0x6932, 0x6933, 0x6935, 0x6936, 0x6937, 0x6938, 0x693A, 0x693B, 0x693C, 0x693E,
+    0x6940, 0x6941, 0x6943, 0x6944, 0x6945, 0x6946, 0x6947, 0x6948, 0x6949, 0x694A, 0x694B, 0x694C,
+    0x694D, 0x694E, 0x694F, 0x6950, 0x6951, 0x6952, 0x6953, 0x6955, 0x6956, 0x6958, 0x6959, 0x695B,
+    0x695C, 0x695F, 0x6961, 0x6962, 0x6964, 0x6965, 0x6967, 0x6968, 0x6969, 0x696A, 0x696C, 0x696D,
+    0x696F, 0x6970, 0x6972, 0x6973, 0x6974, 0x6975, 0x6976, 0x697A, 0x697B, 0x697D, 0x697E, 0x697F,
+    0x6981, 0x6983, 0x6985, 0x698A, 0x698B, 0x698C, 0x698E, 0x698F, 0x6990, 0x6991, 0x6992, 0x6993,
+    0x6996, 0x6997, 0x6999, 0x699A, 0x699D, 0x699E, 0x699F, 0x69A0, 0x69A1, 0x69A2, 0x69A3, 0x69A4,
+    0x69A5, 0x69A6, 0x69A9, 0x69AA, 0x69AC, 0x69AE, 0x69AF, 0x69B0, 0x69B2, 0x69B3, 0x69B5, 0x69B6,
+    0x69B8, 0x69B9, 0x69BA, 0x69BC, 0x69BD, 0x69BE, 0x69BF, 0x69C0, 0x69C2, 0x69C3, 0x69C4, 0x69C5,
+    0x69C6, 0x69C7, 0x69C8, 0x69C9, 0x69CB, 0x69CD, 0x69CF, 0x69D1, 0x69D2, 0x69D3, 0x69D5, 0x69D6,
+    0x69D7, 0x69D8, 0x69D9, 0x69DA, 0x69DC, 0x69DD, 0x69DE, 0x69E1, 0x69E2, 0x69E3, 0x69E4, 0x69E5,
+    0x69E6, 0x69E7, 0x69E8, 0x69E9, 0x69EA, 0x69EB, 0x69EC, 0x69EE, 0x69EF, 0x69F0, 0x69F1, 0x69F3,
+    0x69F4, 0x69F5, 0x69F6, 0x69F7, 0x69F8, 0x69F9, 0x69FA, 0x69FB, 0x69FC, 0x69FE, 0x6A00, 0x6A01,
+    0x6A02, 0x6A03, 0x6A04, 0x6A05, 0x6A06, 0x6A07, 0x6A08, 0x6A09, 0x6A0B, 0x6A0C, 0x6A0D, 0x6A0E,
+    0x6A0F, 0x6A10, 0x6A11, 0x6A12, 0x6A13, 0x6A14, 0x6A15, 0x6A16, 0x6A19, 0x6A1A, 0x6A1B, 0x6A1C,
+    0x6A1D, 0x6A1E, 0x6A20, 0x6A22, 0x6A23, 0x6A24, 0x6A25, 0x6A26, 0x6A27, 0x6A29, 0x6A2B, 0x6A2C,
+    0x6A2D, 0x6A2E, 0x6A30, 0x6A32, 0x6A33, 0x6A34, 0x6A36, 0x6A37, 0x6A38, 0x6A39, 0x6A3A, 0x6A3B,
+    0x6A3C, 0x6A3F, 0x6A40, 0x6A41, 0x6A42, 0x6A43, 0x6A45, 0x6A46, 0x6A48, 0x6A49, 0x6A4A, 0x6A4B,
+    0x6A4C, 0x6A4D, 0x6A4E, 0x6A4F, 0x6A51, 0x6A52, 0x6A53, 0x6A54, 0x6A55, 0x6A56, 0x6A57, 0x6A5A,
+    0x6A5C, 0x6A5D, 0x6A5E, 0x6A5F, 0x6A60, 0x6A62, 0x6A63, 0x6A64, 0x6A66, 0x6A67, 0x6A68, 0x6A69,
+    0x6A6A, 0x6A6B, 0x6A6C, 0x6A6D, 0x6A6E, 0x6A6F, 0x6A70, 0x6A72, 0x6A73, 0x6A74, 0x6A75, 0x6A76,
+    0x6A77, 0x6A78, 0x6A7A, 0x6A7B, 0x6A7D, 0x6A7E, 0x6A7F, 0x6A81, 0x6A82, 0x6A83, 0x6A85, 0x6A86,
+    0x6A87, 0x6A88, 0x6A89, 0x6A8A, 0x6A8B, 0x6A8C, 0x6A8D, 0x6A8F, 0x6A92, 0x6A93, 0x6A94, 0x6A95,
+    0x6A96, 0x6A98, 0x6A99, 0x6A9A, 0x6A9B, 0x6A9C, 0x6A9D, 0x6A9E, 0x6A9F, 0x6AA1, 0x6AA2, 0x6AA3,
+    0x6AA4, 0x6AA5, 0x6AA6, 0x6AA7, 0x6AA8, 0x6AAA, 0x6AAD, 0x6AAE, 0x6AAF, 0x6AB0, 0x6AB1, 0x6AB2,
+    0x6AB3, 0x6AB4, 0x6AB5, 0x6AB6, 0x6AB7, 0x6AB8, 0x6AB9, 0x6ABA, 0x6ABB, 0x6ABC, 0x6ABD, 0x6ABE,
+    0x6ABF, 0x6AC0, 0x6AC1, 0x6AC2, 0x6AC3, 0x6AC4, 0x6AC5, 0x6AC6, 0x6AC7, 0x6AC8, 0x6AC9, 0x6ACA,
+    0x6ACB, 0x6ACC, 0x6ACD, 0x6ACE, 0x6ACF, 0x6AD0, 0x6AD1, 0x6AD2, 0x6AD3, 0x6AD4, 0x6AD5, 0x6AD6,
+    0x6AD7, 0x6AD8, 0x6AD9, 0x6ADA, 0x6ADB, 0x6ADC, 0x6ADD, 0x6ADE, 0x6ADF, 0x6AE0, 0x6AE1, 0x6AE2,
+    0x6AE3, 0x6AE4, 0x6AE5, 0x6AE6, 0x6AE7, 0x6AE8, 0x6AE9, 0x6AEA, 0x6AEB, 0x6AEC, 0x6AED, 0x6AEE,
+    0x6AEF, 0x6AF0, 0x6AF1, 0x6AF2, 0x6AF3, 0x6AF4, 0x6AF5, 0x6AF6, 0x6AF7, 0x6AF8, 0x6AF9, 0x6AFA,
+    0x6AFB, 0x6AFC, 0x6AFD, 0x6AFE, 0x6AFF, 0x6B00, 0x6B01, 0x6B02, 0x6B03, 0x6B04, 0x6B05, 0x6B06,
+    0x6B07, 0x6B08, 0x6B09, 0x6B0A, 0x6B0B, 0x6B0C, 0x6B0D, 0x6B0E, 0x6B0F, 0x6B10, 0x6B11, 0x6B12,
+    0x6B13, 0x6B14, 0x6B15, 0x6B16, 0x6B17, 0x6B18, 0x6B19, 0x6B1A, 0x6B1B, 0x6B1C, 0x6B1D, 0x6B1E,
+    0x6B1F, 0x6B25, 0x6B26, 0x6B28, 0x6B29, 0x6B2A, 0x6B2B, 0x6B2C, 0x6B2D, 0x6B2E, 0x6B2F, 0x6B30,
+    0x6B31, 0x6B33, 0x6B34, 0x6B35, 0x6B36, 0x6B38, 0x6B3B, 0x6B3C, 0x6B3D, 0x6B3F, 0x6B40, 0x6B41,
+    0x6B42, 0x6B44, 0x6B45, 0x6B48, 0x6B4A, 0x6B4B, 0x6B4D, 0x6B4E, 0x6B4F, 0x6B50, 0x6B51, 0x6B52,
+    0x6B53, 0x6B54, 0x6B55, 0x6B56, 0x6B57, 0x6B58, 0x6B5A, 0x6B5B, 0x6B5C, 0x6B5D, 0x6B5E, 0x6B5F,
+    0x6B60, 0x6B61, 0x6B68, 0x6B69, 0x6B6B, 0x6B6C, 0x6B6D, 0x6B6E, 0x6B6F, 0x6B70, 0x6B71, 0x6B72,
+    0x6B73, 0x6B74, 0x6B75, 0x6B76, 0x6B77, 0x6B78, 0x6B7A, 0x6B7D, 0x6B7E, 0x6B7F, 0x6B80, 0x6B85,
+    0x6B88, 0x6B8C, 0x6B8E, 0x6B8F, 0x6B90, 0x6B91, 0x6B94, 0x6B95, 0x6B97, 0x6B98, 0x6B99, 0x6B9C,
+    0x6B9D, 0x6B9E, 0x6B9F, 0x6BA0, 0x6BA2, 0x6BA3, 0x6BA4, 0x6BA5, 0x6BA6, 0x6BA7, 0x6BA8, 0x6BA9,
+    0x6BAB, 0x6BAC, 0x6BAD, 0x6BAE, 0x6BAF, 0x6BB0, 0x6BB1, 0x6BB2, 0x6BB6, 0x6BB8, 0x6BB9, 0x6BBA,
+    0x6BBB, 0x6BBC, 0x6BBD, 0x6BBE, 0x6BC0, 0x6BC3, 0x6BC4, 0x6BC6, 0x6BC7, 0x6BC8, 0x6BC9, 0x6BCA,
+    0x6BCC, 0x6BCE, 0x6BD0, 0x6BD1, 0x6BD8, 0x6BDA, 0x6BDC, 0x6BDD, 0x6BDE, 0x6BDF, 0x6BE0, 0x6BE2,
+    0x6BE3, 0x6BE4, 0x6BE5, 0x6BE6, 0x6BE7, 0x6BE8, 0x6BE9, 0x6BEC, 0x6BED, 0x6BEE, 0x6BF0, 0x6BF1,
+    0x6BF2, 0x6BF4, 0x6BF6, 0x6BF7, 0x6BF8, 0x6BFA, 0x6BFB, 0x6BFC, 0x6BFE, 0x6BFF, 0x6C00, 0x6C01,
+    0x6C02, 0x6C03, 0x6C04, 0x6C08, 0x6C09, 0x6C0A, 0x6C0B, 0x6C0C, 0x6C0E, 0x6C12, 0x6C17, 0x6C1C,
+    0x6C1D, 0x6C1E, 0x6C20, 0x6C23, 0x6C25, 0x6C2B, 0x6C2C, 0x6C2D, 0x6C31, 0x6C33, 0x6C36, 0x6C37,
+    0x6C39, 0x6C3A, 0x6C3B, 0x6C3C, 0x6C3E, 0x6C3F, 0x6C43, 0x6C44, 0x6C45, 0x6C48, 0x6C4B, 0x6C4C,
+    0x6C4D, 0x6C4E, 0x6C4F, 0x6C51, 0x6C52, 0x6C53, 0x6C56, 0x6C58, 0x6C59, 0x6C5A, 0x6C62, 0x6C63,
+    0x6C65, 0x6C66, 0x6C67, 0x6C6B, 0x6C6C, 0x6C6D, 0x6C6E, 0x6C6F, 0x6C71, 0x6C73, 0x6C75, 0x6C77,
+    0x6C78, 0x6C7A, 0x6C7B, 0x6C7C, 0x6C7F, 0x6C80, 0x6C84, 0x6C87, 0x6C8A, 0x6C8B, 0x6C8D, 0x6C8E,
+    0x6C91, 0x6C92, 0x6C95, 0x6C96, 0x6C97, 0x6C98, 0x6C9A, 0x6C9C, 0x6C9D, 0x6C9E, 0x6CA0, 0x6CA2,
+    0x6CA8, 0x6CAC, 0x6CAF, 0x6CB0, 0x6CB4, 0x6CB5, 0x6CB6, 0x6CB7, 0x6CBA, 0x6CC0, 0x6CC1, 0x6CC2,
+    0x6CC3, 0x6CC6, 0x6CC7, 0x6CC8, 0x6CCB, 0x6CCD, 0x6CCE, 0x6CCF, 0x6CD1, 0x6CD2, 0x6CD8, 0x6CD9,
+    0x6CDA, 0x6CDC, 0x6CDD, 0x6CDF, 0x6CE4, 0x6CE6, 0x6CE7, 0x6CE9, 0x6CEC, 0x6CED, 0x6CF2, 0x6CF4,
+    0x6CF9, 0x6CFF, 0x6D00, 0x6D02, 0x6D03, 0x6D05, 0x6D06, 0x6D08, 0x6D09, 0x6D0A, 0x6D0D, 0x6D0F,
+    0x6D10, 0x6D11, 0x6D13, 0x6D14, 0x6D15, 0x6D16, 0x6D18, 0x6D1C, 0x6D1D, 0x6D1F, 0x6D20, 0x6D21,
+    0x6D22, 0x6D23, 0x6D24, 0x6D26, 0x6D28, 0x6D29, 0x6D2C, 0x6D2D, 0x6D2F, 0x6D30, 0x6D34, 0x6D36,
+    0x6D37, 0x6D38, 0x6D3A, 0x6D3F, 0x6D40, 0x6D42, 0x6D44, 0x6D49, 0x6D4C, 0x6D50, 0x6D55, 0x6D56,
+    0x6D57, 0x6D58, 0x6D5B, 0x6D5D, 0x6D5F, 0x6D61, 0x6D62, 0x6D64, 0x6D65, 0x6D67, 0x6D68, 0x6D6B,
+    0x6D6C, 0x6D6D, 0x6D70, 0x6D71, 0x6D72, 0x6D73, 0x6D75, 0x6D76, 0x6D79, 0x6D7A, 0x6D7B, 0x6D7D,
+    0x6D7E, 0x6D7F, 0x6D80, 0x6D81, 0x6D83, 0x6D84, 0x6D86, 0x6D87, 0x6D8A, 0x6D8B, 0x6D8D, 0x6D8F,
+    0x6D90, 0x6D92, 0x6D96, 0x6D97, 0x6D98, 0x6D99, 0x6D9A, 0x6D9C, 0x6DA2, 0x6DA5, 0x6DAC, 0x6DAD,
+    0x6DB0, 0x6DB1, 0x6DB3, 0x6DB4, 0x6DB6, 0x6DB7, 0x6DB9, 0x6DBA, 0x6DBB, 0x6DBC, 0x6DBD, 0x6DBE,
+    0x6DC1, 0x6DC2, 0x6DC3, 0x6DC8, 0x6DC9, 0x6DCA, 0x6DCD, 0x6DCE, 0x6DCF, 0x6DD0, 0x6DD2, 0x6DD3,
+    0x6DD4, 0x6DD5, 0x6DD7, 0x6DDA, 0x6DDB, 0x6DDC, 0x6DDF, 0x6DE2, 0x6DE3, 0x6DE5, 0x6DE7, 0x6DE8,
+    0x6DE9, 0x6DEA, 0x6DED, 0x6DEF, 0x6DF0, 0x6DF2, 0x6DF4, 0x6DF5, 0x6DF6, 0x6DF8, 0x6DFA, 0x6DFD,
+    0x6DFE, 0x6DFF, 0x6E00, 0x6E01, 0x6E02, 0x6E03, 0x6E04, 0x6E06, 0x6E07, 0x6E08, 0x6E09, 0x6E0B,
+    0x6E0F, 0x6E12, 0x6E13, 0x6E15, 0x6E18, 0x6E19, 0x6E1B, 0x6E1C, 0x6E1E, 0x6E1F, 0x6E22, 0x6E26,
+    0x6E27, 0x6E28, 0x6E2A, 0x6E2C, 0x6E2E, 0x6E30, 0x6E31, 0x6E33, 0x6E35, 0x6E36, 0x6E37, 0x6E39,
+    0x6E3B, 0x6E3C, 0x6E3D, 0x6E3E, 0x6E3F, 0x6E40, 0x6E41, 0x6E42, 0x6E45, 0x6E46, 0x6E47, 0x6E48,
+    0x6E49, 0x6E4A, 0x6E4B, 0x6E4C, 0x6E4F, 0x6E50, 0x6E51, 0x6E52, 0x6E55, 0x6E57, 0x6E59, 0x6E5A,
+    0x6E5C, 0x6E5D, 0x6E5E, 0x6E60, 0x6E61, 0x6E62, 0x6E63, 0x6E64, 0x6E65, 0x6E66, 0x6E67, 0x6E68,
+    0x6E69, 0x6E6A, 0x6E6C, 0x6E6D, 0x6E6F, 0x6E70, 0x6E71, 0x6E72, 0x6E73, 0x6E74, 0x6E75, 0x6E76,
+    0x6E77, 0x6E78, 0x6E79, 0x6E7A, 0x6E7B, 0x6E7C, 0x6E7D, 0x6E80, 0x6E81, 0x6E82, 0x6E84, 0x6E87,
+    0x6E88, 0x6E8A, 0x6E8B, 0x6E8C, 0x6E8D, 0x6E8E, 0x6E91, 0x6E92, 0x6E93, 0x6E94, 0x6E95, 0x6E96,
+    0x6E97, 0x6E99, 0x6E9A, 0x6E9B, 0x6E9D, 0x6E9E, 0x6EA0, 0x6EA1, 0x6EA3, 0x6EA4, 0x6EA6, 0x6EA8,
+    0x6EA9, 0x6EAB, 0x6EAC, 0x6EAD, 0x6EAE, 0x6EB0, 0x6EB3, 0x6EB5, 0x6EB8, 0x6EB9, 0x6EBC, 0x6EBE,
+    0x6EBF, 0x6EC0, 0x6EC3, 0x6EC4, 0x6EC5, 0x6EC6, 0x6EC8, 0x6EC9, 0x6ECA, 0x6ECC, 0x6ECD, 0x6ECE,
+    0x6ED0, 0x6ED2, 0x6ED6, 0x6ED8, 0x6ED9, 0x6EDB, 0x6EDC, 0x6EDD, 0x6EE3, 0x6EE7, 0x6EEA, 0x6EEB,
+    0x6EEC, 0x6EED, 0x6EEE, 0x6EEF, 0x6EF0, 0x6EF1, 0x6EF2, 0x6EF3, 0x6EF5, 0x6EF6, 0x6EF7, 0x6EF8,
+    0x6EFA, 0x6EFB, 0x6EFC, 0x6EFD, 0x6EFE, 0x6EFF, 0x6F00, 0x6F01, 0x6F03, 0x6F04, 0x6F05, 0x6F07,
+    0x6F08, 0x6F0A, 0x6F0B, 0x6F0C, 0x6F0D, 0x6F0E, 0x6F10, 0x6F11, 0x6F12, 0x6F16, 0x6F17, 0x6F18,
+    0x6F19, 0x6F1A, 0x6F1B, 0x6F1C, 0x6F1D, 0x6F1E, 0x6F1F, 0x6F21, 0x6F22, 0x6F23, 0x6F25, 0x6F26,
+    0x6F27, 0x6F28, 0x6F2C, 0x6F2E, 0x6F30, 0x6F32, 0x6F34, 0x6F35, 0x6F37, 0x6F38, 0x6F39, 0x6F3A,
+    0x6F3B, 0x6F3C, 0x6F3D, 0x6F3F, 0x6F40, 0x6F41, 0x6F42, 0x6F43, 0x6F44, 0x6F45, 0x6F48, 0x6F49,
+    0x6F4A, 0x6F4C, 0x6F4E, 0x6F4F, 0x6F50, 0x6F51, 0x6F52, 0x6F53, 0x6F54, 0x6F55, 0x6F56, 0x6F57,
+    0x6F59, 0x6F5A, 0x6F5B, 0x6F5D, 0x6F5F, 0x6F60, 0x6F61, 0x6F63, 0x6F64, 0x6F65, 0x6F67, 0x6F68,
+    0x6F69, 0x6F6A, 0x6F6B, 0x6F6C, 0x6F6F, 0x6F70, 0x6F71, 0x6F73, 0x6F75, 0x6F76, 0x6F77, 0x6F79,
+    0x6F7B, 0x6F7D, 0x6F7E, 0x6F7F, 0x6F80, 0x6F81, 0x6F82, 0x6F83, 0x6F85, 0x6F86, 0x6F87, 0x6F8A,
+    0x6F8B, 0x6F8F, 0x6F90, 0x6F91, 0x6F92, 0x6F93, 0x6F94, 0x6F95, 0x6F96, 0x6F97, 0x6F98, 0x6F99,
+    0x6F9A, 0x6F9B, 0x6F9D, 0x6F9E, 0x6F9F, 0x6FA0, 0x6FA2, 0x6FA3, 0x6FA4, 0x6FA5, 0x6FA6, 0x6FA8,
+    0x6FA9, 0x6FAA, 0x6FAB, 0x6FAC, 0x6FAD, 0x6FAE, 0x6FAF, 0x6FB0, 0x6FB1, 0x6FB2, 0x6FB4, 0x6FB5,
+    0x6FB7, 0x6FB8, 0x6FBA, 0x6FBB, 0x6FBC, 0x6FBD, 0x6FBE, 0x6FBF, 0x6FC1, 0x6FC3, 0x6FC4, 0x6FC5,
+    0x6FC6, 0x6FC7, 0x6FC8, 0x6FCA, 0x6FCB, 0x6FCC, 0x6FCD, 0x6FCE, 0x6FCF, 0x6FD0, 0x6FD3, 0x6FD4,
+    0x6FD5, 0x6FD6, 0x6FD7, 0x6FD8, 0x6FD9, 0x6FDA, 0x6FDB, 0x6FDC, 0x6FDD, 0x6FDF, 0x6FE2, 0x6FE3,
+    0x6FE4, 0x6FE5, 0x6FE6, 0x6FE7, 0x6FE8, 0x6FE9, 0x6FEA, 0x6FEB, 0x6FEC, 0x6FED, 0x6FF0, 0x6FF1,
+    0x6FF2, 0x6FF3, 0x6FF4, 0x6FF5, 0x6FF6, 0x6FF7, 0x6FF8, 0x6FF9, 0x6FFA, 0x6FFB, 0x6FFC, 0x6FFD,
+    0x6FFE, 0x6FFF, 0x7000, 0x7001, 0x7002, 0x7003, 0x7004, 0x7005, 0x7006, 0x7007, 0x7008, 0x7009,
+    0x700A, 0x700B, 0x700C, 0x700D, 0x700E, 0x700F, 0x7010, 0x7012, 0x7013, 0x7014, 0x7015, 0x7016,
+    0x7017, 0x7018, 0x7019, 0x701C, 0x701D, 0x701E, 0x701F, 0x7020, 0x7021, 0x7022, 0x7024, 0x7025,
+    0x7026, 0x7027, 0x7028, 0x7029, 0x702A, 0x702B, 0x702C, 0x702D, 0x702E, 0x702F, 0x7030, 0x7031,
+    0x7032, 0x7033, 0x7034, 0x7036, 0x7037, 0x7038, 0x703A, 0x703B, 0x703C, 0x703D, 0x703E, 0x703F,
+    0x7040, 0x7041, 0x7042, 0x7043, 0x7044, 0x7045, 0x7046, 0x7047, 0x7048, 0x7049, 0x704A, 0x704B,
+    0x704D, 0x704E, 0x7050, 0x7051, 0x7052, 0x7053, 0x7054, 0x7055, 0x7056, 0x7057, 0x7058, 0x7059,
+    0x705A, 0x705B, 0x705C, 0x705D, 0x705F, 0x7060, 0x7061, 0x7062, 0x7063, 0x7064, 0x7065, 0x7066,
+    0x7067, 0x7068, 0x7069, 0x706A, 0x706E, 0x7071, 0x7072, 0x7073, 0x7074, 0x7077, 0x7079, 0x707A,
+    0x707B, 0x707D, 0x7081, 0x7082, 0x7083, 0x7084, 0x7086, 0x7087, 0x7088, 0x708B, 0x708C, 0x708D,
+    0x708F, 0x7090, 0x7091, 0x7093, 0x7097, 0x7098, 0x709A, 0x709B, 0x709E, 0x709F, 0x70A0, 0x70A1,
+    0x70A2, 0x70A3, 0x70A4, 0x70A5, 0x70A6, 0x70A7, 0x70A8, 0x70A9, 0x70AA, 0x70B0, 0x70B2, 0x70B4,
+    0x70B5, 0x70B6, 0x70BA, 0x70BE, 0x70BF, 0x70C4, 0x70C5, 0x70C6, 0x70C7, 0x70C9, 0x70CB, 0x70CC,
+    0x70CD, 0x70CE, 0x70CF, 0x70D0, 0x70D1, 0x70D2, 0x70D3, 0x70D4, 0x70D5, 0x70D6, 0x70D7, 0x70DA,
+    0x70DC, 0x70DD, 0x70DE, 0x70E0, 0x70E1, 0x70E2, 0x70E3, 0x70E5, 0x70EA, 0x70EE, 0x70F0, 0x70F1,
+    0x70F2, 0x70F3, 0x70F4, 0x70F5, 0x70F6, 0x70F8, 0x70FA, 0x70FB, 0x70FC, 0x70FE, 0x70FF, 0x7100,
+    0x7101, 0x7102, 0x7103, 0x7104, 0x7105, 0x7106, 0x7107, 0x7108, 0x710B, 0x710C, 0x710D, 0x710E,
+    0x710F, 0x7111, 0x7112, 0x7114, 0x7117, 0x711B, 0x711C, 0x711D, 0x711E, 0x711F, 0x7120, 0x7121,
+    0x7122, 0x7123, 0x7124, 0x7125, 0x7127, 0x7128, 0x7129, 0x712A, 0x712B, 0x712C, 0x712D, 0x712E,
+    0x7132, 0x7133, 0x7134, 0x7135, 0x7137, 0x7138, 0x7139, 0x713A, 0x713B, 0x713C, 0x713D, 0x713E,
+    0x713F, 0x7140, 0x7141, 0x7142, 0x7143, 0x7144, 0x7146, 0x7147, 0x7148, 0x7149, 0x714B, 0x714D,
+    0x714F, 0x7150, 0x7151, 0x7152, 0x7153, 0x7154, 0x7155, 0x7156, 0x7157, 0x7158, 0x7159, 0x715A,
+    0x715B, 0x715D, 0x715F, 0x7160, 0x7161, 0x7162, 0x7163, 0x7165, 0x7169, 0x716A, 0x716B, 0x716C,
+    0x716D, 0x716F, 0x7170, 0x7171, 0x7174, 0x7175, 0x7176, 0x7177, 0x7179, 0x717B, 0x717C, 0x717E,
+    0x717F, 0x7180, 0x7181, 0x7182, 0x7183, 0x7185, 0x7186, 0x7187, 0x7188, 0x7189, 0x718B, 0x718C,
+    0x718D, 0x718E, 0x7190, 0x7191, 0x7192, 0x7193, 0x7195, 0x7196, 0x7197, 0x719A, 0x719B, 0x719C,
+    0x719D, 0x719E, 0x71A1, 0x71A2, 0x71A3, 0x71A4, 0x71A5, 0x71A6, 0x71A7, 0x71A9, 0x71AA, 0x71AB,
+    0x71AD, 0x71AE, 0x71AF, 0x71B0, 0x71B1, 0x71B2, 0x71B4, 0x71B6, 0x71B7, 0x71B8, 0x71BA, 0x71BB,
+    0x71BC, 0x71BD, 0x71BE, 0x71BF, 0x71C0, 0x71C1, 0x71C2, 0x71C4, 0x71C5, 0x71C6, 0x71C7, 0x71C8,
+    0x71C9, 0x71CA, 0x71CB, 0x71CC, 0x71CD, 0x71CF, 0x71D0, 0x71D1, 0x71D2, 0x71D3, 0x71D6, 0x71D7,
+    0x71D8, 0x71D9, 0x71DA, 0x71DB, 0x71DC, 0x71DD, 0x71DE, 0x71DF, 0x71E1, 0x71E2, 0x71E3, 0x71E4,
+    0x71E6, 0x71E8, 0x71E9, 0x71EA, 0x71EB, 0x71EC, 0x71ED, 0x71EF, 0x71F0, 0x71F1, 0x71F2, 0x71F3,
+    0x71F4, 0x71F5, 0x71F6, 0x71F7, 0x71F8, 0x71FA, 0x71FB, 0x71FC, 0x71FD, 0x71FE, 0x71FF, 0x7200,
+    0x7201, 0x7202, 0x7203, 0x7204, 0x7205, 0x7207, 0x7208, 0x7209, 0x720A, 0x720B, 0x720C, 0x720D,
+    0x720E, 0x720F, 0x7210, 0x7211, 0x7212, 0x7213, 0x7214, 0x7215, 0x7216, 0x7217, 0x7218, 0x7219,
+    0x721A, 0x721B, 0x721C, 0x721E, 0x721F, 0x7220, 0x7221, 0x7222, 0x7223, 0x7224, 0x7225, 0x7226,
+    0x7227, 0x7229, 0x722B, 0x722D, 0x722E, 0x722F, 0x7232, 0x7233, 0x7234, 0x723A, 0x723C, 0x723E,
+    0x7240, 0x7241, 0x7242, 0x7243, 0x7244, 0x7245, 0x7246, 0x7249, 0x724A, 0x724B, 0x724E, 0x724F,
+    0x7250, 0x7251, 0x7253, 0x7254, 0x7255, 0x7257, 0x7258, 0x725A, 0x725C, 0x725E, 0x7260, 0x7263,
+    0x7264, 0x7265, 0x7268, 0x726A, 0x726B, 0x726C, 0x726D, 0x7270, 0x7271, 0x7273, 0x7274, 0x7276,
+    0x7277, 0x7278, 0x727B, 0x727C, 0x727D, 0x7282, 0x7283, 0x7285, 0x7286, 0x7287, 0x7288, 0x7289,
+    0x728C, 0x728E, 0x7290, 0x7291, 0x7293, 0x7294, 0x7295, 0x7296, 0x7297, 0x7298, 0x7299, 0x729A,
+    0x729B, 0x729C, 0x729D, 0x729E, 0x72A0, 0x72A1, 0x72A2, 0x72A3, 0x72A4, 0x72A5, 0x72A6, 0x72A7,
+    0x72A8, 0x72A9, 0x72AA, 0x72AB, 0x72AE, 0x72B1, 0x72B2, 0x72B3, 0x72B5, 0x72BA, 0x72BB, 0x72BC,
+    0x72BD, 0x72BE, 0x72BF, 0x72C0, 0x72C5, 0x72C6, 0x72C7, 0x72C9, 0x72CA, 0x72CB, 0x72CC, 0x72CF,
+    0x72D1, 0x72D3, 0x72D4, 0x72D5, 0x72D6, 0x72D8, 0x72DA, 0x72DB, 0xE4C6, 0xE4C7, 0xE4C8, 0xE4C9,
+    0xE4CA, 0xE4CB, 0xE4CC, 0xE4CD, 0xE4CE, 0xE4CF, 0xE4D0, 0xE4D1, 0xE4D2, 0xE4D3, 0xE4D4, 0xE4D5,
+    0xE4D6, 0xE4D7, 0xE4D8, 0xE4D9, 0xE4DA, 0xE4DB, 0xE4DC, 0xE4DD, 0xE4DE, 0xE4DF, 0xE4E0, 0xE4E1,
+    0xE4E2, 0xE4E3, 0xE4E4, 0xE4E5, 0xE4E6, 0xE4E7, 0xE4E8, 0xE4E9, 0xE4EA, 0xE4EB, 0xE4EC, 0xE4ED,
+    0xE4EE, 0xE4EF, 0xE4F0, 0xE4F1, 0xE4F2, 0xE4F3, 0xE4F4, 0xE4F5, 0xE4F6, 0xE4F7, 0xE4F8, 0xE4F9,
+    0xE4FA, 0xE4FB, 0xE4FC, 0xE4FD, 0xE4FE, 0xE4FF, 0xE500, 0xE501, 0xE502, 0xE503, 0xE504, 0xE505,
+    0xE506, 0xE507, 0xE508, 0xE509, 0xE50A, 0xE50B, 0xE50C, 0xE50D, 0xE50E, 0xE50F, 0xE510, 0xE511,
+    0xE512, 0xE513, 0xE514, 0xE515, 0xE516, 0xE517, 0xE518, 0xE519, 0xE51A, 0xE51B, 0xE51C, 0xE51D,
+    0xE51E, 0xE51F, 0xE520, 0xE521, 0xE522, 0xE523, 0xE524, 0xE525, 0x3000, 0x3001, 0x3002, 0x00B7,
+    0x02C9, 0x02C7, 0x00A8, 0x3003, 0x3005, 0x2014, 0xFF5E, 0x2016, 0x2026, 0x2018, 0x2019, 0x201C,
+    0x201D, 0x3014, 0x3015, 0x3008, 0x3009, 0x300A, 0x300B, 0x300C, 0x300D, 0x300E, 0x300F, 0x3016,
+    0x3017, 0x3010, 0x3011, 0x00B1, 0x00D7, 0x00F7, 0x2236, 0x2227, 0x2228, 0x2211, 0x220F, 0x222A,
+    0x2229, 0x2208, 0x2237, 0x221A, 0x22A5, 0x2225, 0x2220, 0x2312, 0x2299, 0x222B, 0x222E, 0x2261,
+    0x224C, 0x2248, 0x223D, 0x221D, 0x2260, 0x226E, 0x226F, 0x2264, 0x2265, 0x221E, 0x2235, 0x2234,
+    0x2642, 0x2640, 0x00B0, 0x2032, 0x2033, 0x2103, 0xFF04, 0x00A4, 0xFFE0, 0xFFE1, 0x2030, 0x00A7,
+    0x2116, 0x2606, 0x2605, 0x25CB, 0x25CF, 0x25CE, 0x25C7, 0x25C6, 0x25A1, 0x25A0, 0x25B3, 0x25B2,
+    0x203B, 0x2192, 0x2190, 0x2191, 0x2193, 0x3013, 0xE526, 0xE527, 0xE528, 0xE529, 0xE52A, 0xE52B,
+    0xE52C, 0xE52D, 0xE52E, 0xE52F, 0xE530, 0xE531, 0xE532, 0xE533, 0xE534, 0xE535, 0xE536, 0xE537,
+    0xE538, 0xE539, 0xE53A, 0xE53B, 0xE53C, 0xE53D, 0xE53E, 0xE53F, 0xE540, 0xE541, 0xE542, 0xE543,
+    0xE544, 0xE545, 0xE546, 0xE547, 0xE548, 0xE549, 0xE54A, 0xE54B, 0xE54C, 0xE54D, 0xE54E, 0xE54F,
+    0xE550, 0xE551, 0xE552, 0xE553, 0xE554, 0xE555, 0xE556, 0xE557, 0xE558, 0xE559, 0xE55A, 0xE55B,
+    0xE55C, 0xE55D, 0xE55E, 0xE55F, 0xE560, 0xE561, 0xE562, 0xE563, 0xE564, 0xE565, 0xE566, 0xE567,
+    0xE568, 0xE569, 0xE56A, 0xE56B, 0xE56C, 0xE56D, 0xE56E, 0xE56F, 0xE570, 0xE571, 0xE572, 0xE573,
+    0xE574, 0xE575, 0xE576, 0xE577, 0xE578, 0xE579, 0xE57A, 0xE57B, 0xE57C, 0xE57D, 0xE57E, 0xE57F,
+    0xE580, 0xE581, 0xE582, 0xE583, 0xE584, 0xE585, 0x2170, 0x2171, 0x2172, 0x2173, 0x2174, 0x2175,
+    0x2176, 0x2177, 0x2178, 0x2179, 0xE766, 0xE767, 0xE768, 0xE769, 0xE76A, 0xE76B, 0x2488, 0x2489,
+    0x248A, 0x248B, 0x248C, 0x248D, 0x248E, 0x248F, 0x2490, 0x2491, 0x2492, 0x2493, 0x2494, 0x2495,
+    0x2496, 0x2497, 0x2498, 0x2499, 0x249A, 0x249B, 0x2474, 0x2475, 0x2476, 0x2477, 0x2478, 0x2479,
+    0x247A, 0x247B, 0x247C, 0x247D, 0x247E, 0x247F, 0x2480, 0x2481, 0x2482, 0x2483, 0x2484, 0x2485,
+    0x2486, 0x2487, 0x2460, 0x2461, 0x2462, 0x2463, 0x2464, 0x2465, 0x2466, 0x2467, 0x2468, 0x2469,
+    0x20AC, 0xE76D, 0x3220, 0x3221, 0x3222, 0x3223, 0x3224, 0x3225, 0x3226, 0x3227, 0x3228, 0x3229,
+    0xE76E, 0xE76F, 0x2160, 0x2161, 0x2162, 0x2163, 0x2164, 0x2165, 0x2166, 0x2167, 0x2168, 0x2169,
+    0x216A, 0x216B, 0xE770, 0xE771, 0xE586, 0xE587, 0xE588, 0xE589, 0xE58A, 0xE58B, 0xE58C, 0xE58D,
+    0xE58E, 0xE58F, 0xE590, 0xE591, 0xE592, 0xE593, 0xE594, 0xE595, 0xE596, 0xE597, 0xE598, 0xE599,
+    0xE59A, 0xE59B, 0xE59C, 0xE59D, 0xE59E, 0xE59F, 0xE5A0, 0xE5A1, 0xE5A2, 0xE5A3, 0xE5A4, 0xE5A5,
+    0xE5A6, 0xE5A7, 0xE5A8, 0xE5A9, 0xE5AA, 0xE5AB, 0xE5AC, 0xE5AD, 0xE5AE, 0xE5AF, 0xE5B0, 0xE5B1,
+    0xE5B2, 0xE5B3, 0xE5B4, 0xE5B5, 0xE5B6, 0xE5B7, 0xE5B8, 0xE5B9, 0xE5BA, 0xE5BB, 0xE5BC, 0xE5BD,
+    0xE5BE, 0xE5BF, 0xE5C0, 0xE5C1, 0xE5C2, 0xE5C3, 0xE5C4, 0xE5C5, 0xE5C6, 0xE5C7, 0xE5C8, 0xE5C9,
+    0xE5CA, 0xE5CB, 0xE5CC, 0xE5CD, 0xE5CE, 0xE5CF, 0xE5D0, 0xE5D1, 0xE5D2, 0xE5D3, 0xE5D4, 0xE5D5,
+    0xE5D6, 0xE5D7, 0xE5D8, 0xE5D9, 0xE5DA, 0xE5DB, 0xE5DC, 0xE5DD, 0xE5DE, 0xE5DF, 0xE5E0, 0xE5E1,
+    0xE5E2, 0xE5E3, 0xE5E4, 0x3000, 0xFF01, 0xFF02, 0xFF03, 0xFFE5, 0xFF05, 0xFF06, 0xFF07, 0xFF08,
+    0xFF09, 0xFF0A, 0xFF0B, 0xFF0C, 0xFF0D, 0xFF0E, 0xFF0F, 0xFF10, 0xFF11, 0xFF12, 0xFF13, 0xFF14,
+    0xFF15, 0xFF16, 0xFF17, 0xFF18, 0xFF19, 0xFF1A, 0xFF1B, 0xFF1C, 0xFF1D, 0xFF1E, 0xFF1F, 0xFF20,
+    0xFF21, 0xFF22, 0xFF23, 0xFF24, 0xFF25, 0xFF26, 0xFF27, 0xFF28, 0xFF29, 0xFF2A, 0xFF2B, 0xFF2C,
+    0xFF2D, 0xFF2E, 0xFF2F, 0xFF30, 0xFF31, 0xFF32, 0xFF33, 0xFF34, 0xFF35, 0xFF36, 0xFF37, 0xFF38,
+    0xFF39, 0xFF3A, 0xFF3B, 0xFF3C, 0xFF3D, 0xFF3E, 0xFF3F, 0xFF40, 0xFF41, 0xFF42, 0xFF43, 0xFF44,
+    0xFF45, 0xFF46, 0xFF47, 0xFF48, 0xFF49, 0xFF4A, 0xFF4B, 0xFF4C, 0xFF4D, 0xFF4E, 0xFF4F, 0xFF50,
+    0xFF51, 0xFF52, 0xFF53, 0xFF54, 0xFF55, 0xFF56, 0xFF57, 0xFF58, 0xFF59, 0xFF5A, 0xFF5B, 0xFF5C,
+    0xFF5D, 0xFFE3, 0xE5E6, 0xE5E7, 0xE5E8, 0xE5E9, 0xE5EA, 0xE5EB, 0xE5EC, 0xE5ED, 0xE5EE, 0xE5EF,
+    0xE5F0, 0xE5F1, 0xE5F2, 0xE5F3, 0xE5F4, 0xE5F5, 0xE5F6, 0xE5F7, 0xE5F8, 0xE5F9, 0xE5FA, 0xE5FB,
+    0xE5FC, 0xE5FD, 0xE5FE, 0xE5FF, 0xE600, 0xE601, 0xE602, 0xE603, 0xE604, 0xE605, 0xE606, 0xE607,
+    0xE608, 0xE609, 0xE60A, 0xE60B, 0xE60C, 0xE60D, 0xE60E, 0xE60F, 0xE610, 0xE611, 0xE612, 0xE613,
+    0xE614, 0xE615, 0xE616, 0xE617, 0xE618, 0xE619, 0xE61A, 0xE61B, 0xE61C, 0xE61D, 0xE61E, 0xE61F,
+    0xE620, 0xE621, 0xE622, 0xE623, 0xE624, 0xE625, 0xE626, 0xE627, 0xE628, 0xE629, 0xE62A, 0xE62B,
+    0xE62C, 0xE62D, 0xE62E, 0xE62F, 0xE630, 0xE631, 0xE632, 0xE633, 0xE634, 0xE635, 0xE636, 0xE637,
+    0xE638, 0xE639, 0xE63A, 0xE63B, 0xE63C, 0xE63D, 0xE63E, 0xE63F, 0xE640, 0xE641, 0xE642, 0xE643,
+    0xE644, 0xE645, 0x3041, 0x3042, 0x3043, 0x3044, 0x3045, 0x3046, 0x3047, 0x3048, 0x3049, 0x304A,
+    0x304B, 0x304C, 0x304D, 0x304E, 0x304F, 0x3050, 0x3051, 0x3052, 0x3053, 0x3054, 0x3055, 0x3056,
+    0x3057, 0x3058, 0x3059, 0x305A, 0x305B, 0x305C, 0x305D, 0x305E, 0x305F, 0x3060, 0x3061, 0x3062,
+    0x3063, 0x3064, 0x3065, 0x3066, 0x3067, 0x3068, 0x3069, 0x306A, 0x306B, 0x306C, 0x306D, 0x306E,
+    0x306F, 0x3070, 0x3071, 0x3072, 0x3073, 0x3074, 0x3075, 0x3076, 0x3077, 0x3078, 0x3079, 0x307A,
+    0x307B, 0x307C, 0x307D, 0x307E, 0x307F, 0x3080, 0x3081, 0x3082, 0x3083, 0x3084, 0x3085, 0x3086,
+    0x3087, 0x3088, 0x3089, 0x308A, 0x308B, 0x308C, 0x308D, 0x308E, 0x308F, 0x3090, 0x3091, 0x3092,
+    0x3093, 0xE772, 0xE773, 0xE774, 0xE775, 0xE776, 0xE777, 0xE778, 0xE779, 0xE77A, 0xE77B, 0xE77C,
+    0xE646, 0xE647, 0xE648, 0xE649, 0xE64A, 0xE64B, 0xE64C, 0xE64D, 0xE64E, 0xE64F, 0xE650, 0xE651,
+    0xE652, 0xE653, 0xE654, 0xE655, 0xE656, 0xE657, 0xE658, 0xE659, 0xE65A, 0xE65B, 0xE65C, 0xE65D,
+    0xE65E, 0xE65F, 0xE660, 0xE661, 0xE662, 0xE663, 0xE664, 0xE665, 0xE666, 0xE667, 0xE668, 0xE669,
+    0xE66A, 0xE66B, 0xE66C, 0xE66D, 0xE66E, 0xE66F, 0xE670, 0xE671, 0xE672, 0xE673, 0xE674, 0xE675,
+    0xE676, 0xE677, 0xE678, 0xE679, 0xE67A, 0xE67B, 0xE67C, 0xE67D, 0xE67E, 0xE67F, 0xE680, 0xE681,
+    0xE682, 0xE683, 0xE684, 0xE685, 0xE686, 0xE687, 0xE688, 0xE689, 0xE68A, 0xE68B, 0xE68C, 0xE68D,
+    0xE68E, 0xE68F, 0xE690, 0xE691, 0xE692, 0xE693, 0xE694, 0xE695, 0xE696, 0xE697, 0xE698, 0xE699,
+    0xE69A, 0xE69B, 0xE69C, 0xE69D, 0xE69E, 0xE69F, 0xE6A0, 0xE6A1, 0xE6A2, 0xE6A3, 0xE6A4, 0xE6A5,
+    0x30A1, 0x30A2, 0x30A3, 0x30A4, 0x30A5, 0x30A6, 0x30A7, 0x30A8, 0x30A9, 0x30AA, 0x30AB, 0x30AC,
+    0x30AD, 0x30AE, 0x30AF, 0x30B0, 0x30B1, 0x30B2, 0x30B3, 0x30B4, 0x30B5, 0x30B6, 0x30B7, 0x30B8,
+    0x30B9, 0x30BA, 0x30BB, 0x30BC, 0x30BD, 0x30BE, 0x30BF, 0x30C0, 0x30C1, 0x30C2, 0x30C3, 0x30C4,
+    0x30C5, 0x30C6, 0x30C7, 0x30C8, 0x30C9, 0x30CA, 0x30CB, 0x30CC, 0x30CD, 0x30CE, 0x30CF, 0x30D0,
+    0x30D1, 0x30D2, 0x30D3, 0x30D4, 0x30D5, 0x30D6, 0x30D7, 0x30D8, 0x30D9, 0x30DA, 0x30DB, 0x30DC,
+    0x30DD, 0x30DE, 0x30DF, 0x30E0, 0x30E1, 0x30E2, 0x30E3, 0x30E4, 0x30E5, 0x30E6, 0x30E7, 0x30E8,
+    0x30E9, 0x30EA, 0x30EB, 0x30EC, 0x30ED, 0x30EE, 0x30EF, 0x30F0, 0x30F1, 0x30F2, 0x30F3, 0x30F4,
+    0x30F5, 0x30F6, 0xE77D, 0xE77E, 0xE77F, 0xE780, 0xE781, 0xE782, 0xE783, 0xE784, 0xE6A6, 0xE6A7,
+    0xE6A8, 0xE6A9, 0xE6AA, 0xE6AB, 0xE6AC, 0xE6AD, 0xE6AE, 0xE6AF, 0xE6B0, 0xE6B1, 0xE6B2, 0xE6B3,
+    0xE6B4, 0xE6B5, 0xE6B6, 0xE6B7, 0xE6B8, 0xE6B9, 0xE6BA, 0xE6BB, 0xE6BC, 0xE6BD, 0xE6BE, 0xE6BF,
+    0xE6C0, 0xE6C1, 0xE6C2, 0xE6C3, 0xE6C4, 0xE6C5, 0xE6C6, 0xE6C7, 0xE6C8, 0xE6C9, 0xE6CA, 0xE6CB,
+    0xE6CC, 0xE6CD, 0xE6CE, 0xE6CF, 0xE6D0, 0xE6D1, 0xE6D2, 0xE6D3, 0xE6D4, 0xE6D5, 0xE6D6, 0xE6D7,
+    0xE6D8, 0xE6D9, 0xE6DA, 0xE6DB, 0xE6DC, 0xE6DD, 0xE6DE, 0xE6DF, 0xE6E0, 0xE6E1, 0xE6E2, 0xE6E3,
+    0xE6E4, 0xE6E5, 0xE6E6, 0xE6E7, 0xE6E8, 0xE6E9, 0xE6EA, 0xE6EB, 0xE6EC, 0xE6ED, 0xE6EE, 0xE6EF,
+    0xE6F0, 0xE6F1, 0xE6F2, 0xE6F3, 0xE6F4, 0xE6F5, 0xE6F6, 0xE6F7, 0xE6F8, 0xE6F9, 0xE6FA, 0xE6FB,
+    0xE6FC, 0xE6FD, 0xE6FE, 0xE6FF, 0xE700, 0xE701, 0xE702, 0xE703, 0xE704, 0xE705, 0x0391, 0x0392,
+    0x0393, 0x0394, 0x0395, 0x0396, 0x0397, 0x0398, 0x0399, 0x039A, 0x039B, 0x039C, 0x039D, 0x039E,
+    0x039F, 0x03A0, 0x03A1, 0x03A3, 0x03A4, 0x03A5, 0x03A6, 0x03A7, 0x03A8, 0x03A9, 0xE785, 0xE786,
+    0xE787, 0xE788, 0xE789, 0xE78A, 0xE78B, 0xE78C, 0x03B1, 0x03B2, 0x03B3, 0x03B4, 0x03B5, 0x03B6,
+    0x03B7, 0x03B8, 0x03B9, 0x03BA, 0x03BB, 0x03BC, 0x03BD, 0x03BE, 0x03BF, 0x03C0, 0x03C1, 0x03C3,
+    0x03C4, 0x03C5, 0x03C6, 0x03C7, 0x03C8, 0x03C9, 0xFE10, 0xFE12, 0xFE11, 0xFE13, 0xFE14, 0xFE15,
+    0xFE16, 0xFE35, 0xFE36, 0xFE39, 0xFE3A, 0xFE3F, 0xFE40, 0xFE3D, 0xFE3E, 0xFE41, 0xFE42, 0xFE43,
+    0xFE44, 0xFE17, 0xFE18, 0xFE3B, 0xFE3C, 0xFE37, 0xFE38, 0xFE31, 0xFE19, 0xFE33, 0xFE34, 0xE797,
+    0xE798, 0xE799, 0xE79A, 0xE79B, 0xE79C, 0xE79D, 0xE79E, 0xE79F, 0xE706, 0xE707, 0xE708, 0xE709,
+    0xE70A, 0xE70B, 0xE70C, 0xE70D, 0xE70E, 0xE70F, 0xE710, 0xE711, 0xE712, 0xE713, 0xE714, 0xE715,
+    0xE716, 0xE717, 0xE718, 0xE719, 0xE71A, 0xE71B, 0xE71C, 0xE71D, 0xE71E, 0xE71F, 0xE720, 0xE721,
+    0xE722, 0xE723, 0xE724, 0xE725, 0xE726, 0xE727, 0xE728, 0xE729, 0xE72A, 0xE72B, 0xE72C, 0xE72D,
+    0xE72E, 0xE72F, 0xE730, 0xE731, 0xE732, 0xE733, 0xE734, 0xE735, 0xE736, 0xE737, 0xE738, 0xE739,
+    0xE73A, 0xE73B, 0xE73C, 0xE73D, 0xE73E, 0xE73F, 0xE740, 0xE741, 0xE742, 0xE743, 0xE744, 0xE745,
+    0xE746, 0xE747, 0xE748, 0xE749, 0xE74A, 0xE74B, 0xE74C, 0xE74D, 0xE74E, 0xE74F, 0xE750, 0xE751,
+    0xE752, 0xE753, 0xE754, 0xE755, 0xE756, 0xE757, 0xE758, 0xE759, 0xE75A, 0xE75B, 0xE75C, 0xE75D,
+    0xE75E, 0xE75F, 0xE760, 0xE761, 0xE762, 0xE763, 0xE764, 0xE765, 0x0410, 0x0411, 0x0412, 0x0413,
+    0x0414, 0x0415, 0x0401, 0x0416, 0x0417, 0x0418, 0x0419, 0x041A, 0x041B, 0x041C, 0x041D, 0x041E,
+    0x041F, 0x0420, 0x0421, 0x0422, 0x0423, 0x0424, 0x0425, 0x0426, 0x0427, 0x0428, 0x0429, 0x042A,
+    0x042B, 0x042C, 0x042D, 0x042E, 0x042F, 0xE7A0, 0xE7A1, 0xE7A2, 0xE7A3, 0xE7A4, 0xE7A5, 0xE7A6,
+    0xE7A7, 0xE7A8, 0xE7A9, 0xE7AA, 0xE7AB, 0xE7AC, 0xE7AD, 0xE7AE, 0x0430, 0x0431, 0x0432, 0x0433,
+    0x0434, 0x0435, 0x0451, 0x0436, 0x0437, 0x0438, 0x0439, 0x043A, 0x043B, 0x043C, 0x043D, 0x043E,
+    0x043F, 0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447, 0x0448, 0x0449, 0x044A,
+    0x044B, 0x044C, 0x044D, 0x044E, 0x044F, 0xE7AF, 0xE7B0, 0xE7B1, 0xE7B2, 0xE7B3, 0xE7B4, 0xE7B5,
+    0xE7B6, 0xE7B7, 0xE7B8, 0xE7B9, 0xE7BA, 0xE7BB, 0x02CA, 0x02CB, 0x02D9, 0x2013, 0x2015, 0x2025,
+    0x2035, 0x2105, 0x2109, 0x2196, 0x2197, 0x2198, 0x2199, 0x2215, 0x221F, 0x2223, 0x2252, 0x2266,
+    0x2267, 0x22BF, 0x2550, 0x2551, 0x2552, 0x2553, 0x2554, 0x2555, 0x2556, 0x2557, 0x2558, 0x2559,
+    0x255A, 0x255B, 0x255C, 0x255D, 0x255E, 0x255F, 0x2560, 0x2561, 0x2562, 0x2563, 0x2564, 0x2565,
+    0x2566, 0x2567, 0x2568, 0x2569, 0x256A, 0x256B, 0x256C, 0x256D, 0x256E, 0x256F, 0x2570, 0x2571,
+    0x2572, 0x2573, 0x2581, 0x2582, 0x2583, 0x2584, 0x2585, 0x2586, 0x2587, 0x2588, 0x2589, 0x258A,
+    0x258B, 0x258C, 0x258D, 0x258E, 0x258F, 0x2593, 0x2594, 0x2595, 0x25BC, 0x25BD, 0x25E2, 0x25E3,
+    0x25E4, 0x25E5, 0x2609, 0x2295, 0x3012, 0x301D, 0x301E, 0xE7BC, 0xE7BD, 0xE7BE, 0xE7BF, 0xE7C0,
+    0xE7C1, 0xE7C2, 0xE7C3, 0xE7C4, 0xE7C5, 0xE7C6, 0x0101, 0x00E1, 0x01CE, 0x00E0, 0x0113, 0x00E9,
+    0x011B, 0x00E8, 0x012B, 0x00ED, 0x01D0, 0x00EC, 0x014D, 0x00F3, 0x01D2, 0x00F2, 0x016B, 0x00FA,
+    0x01D4, 0x00F9, 0x01D6, 0x01D8, 0x01DA, 0x01DC, 0x00FC, 0x00EA, 0x0251, 0x1E3F, 0x0144, 0x0148,
+    0x01F9, 0x0261, 0xE7C9, 0xE7CA, 0xE7CB, 0xE7CC, 0x3105, 0x3106, 0x3107, 0x3108, 0x3109, 0x310A,
+    0x310B, 0x310C, 0x310D, 0x310E, 0x310F, 0x3110, 0x3111, 0x3112, 0x3113, 0x3114, 0x3115, 0x3116,
+    0x3117, 0x3118, 0x3119, 0x311A, 0x311B, 0x311C, 0x311D, 0x311E, 0x311F, 0x3120, 0x3121, 0x3122,
+    0x3123, 0x3124, 0x3125, 0x3126, 0x3127, 0x3128, 0x3129, 0xE7CD, 0xE7CE, 0xE7CF, 0xE7D0, 0xE7D1,
+    0xE7D2, 0xE7D3, 0xE7D4, 0xE7D5, 0xE7D6, 0xE7D7, 0xE7D8, 0xE7D9, 0xE7DA, 0xE7DB, 0xE7DC, 0xE7DD,
+    0xE7DE, 0xE7DF, 0xE7E0, 0xE7E1, 0x3021, 0x3022, 0x3023, 0x3024, 0x3025, 0x3026, 0x3027, 0x3028,
+    0x3029, 0x32A3, 0x338E, 0x338F, 0x339C, 0x339D, 0x339E, 0x33A1, 0x33C4, 0x33CE, 0x33D1, 0x33D2,
+    0x33D5, 0xFE30, 0xFFE2, 0xFFE4, 0xE7E2, 0x2121, 0x3231, 0xE7E3, 0x2010, 0xE7E4, 0xE7E5, 0xE7E6,
+    0x30FC, 0x309B, 0x309C, 0x30FD, 0x30FE, 0x3006, 0x309D, 0x309E, 0xFE49, 0xFE4A, 0xFE4B, 0xFE4C,
+    0xFE4D, 0xFE4E, 0xFE4F, 0xFE50, 0xFE51, 0xFE52, 0xFE54, 0xFE55, 0xFE56, 0xFE57, 0xFE59, 0xFE5A,
+    0xFE5B, 0xFE5C, 0xFE5D, 0xFE5E, 0xFE5F, 0xFE60, 0xFE61, 0xFE62, 0xFE63, 0xFE64, 0xFE65, 0xFE66,
+    0xFE68, 0xFE69, 0xFE6A, 0xFE6B, 0x303E, 0x2FF0, 0x2FF1, 0x2FF2, 0x2FF3, 0x2FF4, 0x2FF5, 0x2FF6,
+    0x2FF7, 0x2FF8, 0x2FF9, 0x2FFA, 0x2FFB, 0x3007, 0xE7F4, 0xE7F5, 0xE7F6, 0xE7F7, 0xE7F8, 0xE7F9,
+    0xE7FA, 0xE7FB, 0xE7FC, 0xE7FD, 0xE7FE, 0xE7FF, 0xE800, 0x2500, 0x2501, 0x2502, 0x2503, 0x2504,
+    0x2505, 0x2506, 0x2507, 0x2508, 0x2509, 0x250A, 0x250B, 0x250C, 0x250D, 0x250E, 0x250F, 0x2510,
+    0x2511, 0x2512, 0x2513, 0x2514, 0x2515, 0x2516, 0x2517, 0x2518, 0x2519, 0x251A, 0x251B, 0x251C,
+    0x251D, 0x251E, 0x251F, 0x2520, 0x2521, 0x2522, 0x2523, 0x2524, 0x2525, 0x2526, 0x2527, 0x2528,
+    0x2529, 0x252A, 0x252B, 0x252C, 0x252D, 0x252E, 0x252F, 0x2530, 0x2531, 0x2532, 0x2533, 0x2534,
+    0x2535, 0x2536, 0x2537, 0x2538, 0x2539, 0x253A, 0x253B, 0x253C, 0x253D, 0x253E, 0x253F, 0x2540,
+    0x2541, 0x2542, 0x2543, 0x2544, 0x2545, 0x2546, 0x2547, 0x2548, 0x2549, 0x254A, 0x254B, 0xE801,
+    0xE802, 0xE803, 0xE804, 0xE805, 0xE806, 0xE807, 0xE808, 0xE809, 0xE80A, 0xE80B, 0xE80C, 0xE80D,
+    0xE80E, 0xE80F, 0x72DC, 0x72DD, 0x72DF, 0x72E2, 0x72E3, 0x72E4, 0x72E5, 0x72E6, 0x72E7, 0x72EA,
+    0x72EB, 0x72F5, 0x72F6, 0x72F9, 0x72FD, 0x72FE, 0x72FF, 0x7300, 0x7302, 0x7304, 0x7305, 0x7306,
+    0x7307, 0x7308, 0x7309, 0x730B, 0x730C, 0x730D, 0x730F, 0x7310, 0x7311, 0x7312, 0x7314, 0x7318,
+    0x7319, 0x731A, 0x731F, 0x7320, 0x7323, 0x7324, 0x7326, 0x7327, 0x7328, 0x732D, 0x732F, 0x7330,
+    0x7332, 0x7333, 0x7335, 0x7336, 0x733A, 0x733B, 0x733C, 0x733D, 0x7340, 0x7341, 0x7342, 0x7343,
+    0x7344, 0x7345, 0x7346, 0x7347, 0x7348, 0x7349, 0x734A, 0x734B, 0x734C, 0x734E, 0x734F, 0x7351,
+    0x7353, 0x7354, 0x7355, 0x7356, 0x7358, 0x7359, 0x735A, 0x735B, 0x735C, 0x735D, 0x735E, 0x735F,
+    0x7361, 0x7362, 0x7363, 0x7364, 0x7365, 0x7366, 0x7367, 0x7368, 0x7369, 0x736A, 0x736B, 0x736E,
+    0x7370, 0x7371, 0xE000, 0xE001, 0xE002, 0xE003, 0xE004, 0xE005, 0xE006, 0xE007, 0xE008, 0xE009,
+    0xE00A, 0xE00B, 0xE00C, 0xE00D, 0xE00E, 0xE00F, 0xE010, 0xE011, 0xE012, 0xE013, 0xE014, 0xE015,
+    0xE016, 0xE017, 0xE018, 0xE019, 0xE01A, 0xE01B, 0xE01C, 0xE01D, 0xE01E, 0xE01F, 0xE020, 0xE021,
+    0xE022, 0xE023, 0xE024, 0xE025, 0xE026, 0xE027, 0xE028, 0xE029, 0xE02A, 0xE02B, 0xE02C, 0xE02D,
+    0xE02E, 0xE02F, 0xE030, 0xE031, 0xE032, 0xE033, 0xE034, 0xE035, 0xE036, 0xE037, 0xE038, 0xE039,
+    0xE03A, 0xE03B, 0xE03C, 0xE03D, 0xE03E, 0xE03F, 0xE040, 0xE041, 0xE042, 0xE043, 0xE044, 0xE045,
+    0xE046, 0xE047, 0xE048, 0xE049, 0xE04A, 0xE04B, 0xE04C, 0xE04D, 0xE04E, 0xE04F, 0xE050, 0xE051,
+    0xE052, 0xE053, 0xE054, 0xE055, 0xE056, 0xE057, 0xE058, 0xE059, 0xE05A, 0xE05B, 0xE05C, 0xE05D,
+    0x7372, 0x7373, 0x7374, 0x7375, 0x7376, 0x7377, 0x7378, 0x7379, 0x737A, 0x737B, 0x737C, 0x737D,
+    0x737F, 0x7380, 0x7381, 0x7382, 0x7383, 0x7385, 0x7386, 0x7388, 0x738A, 0x738C, 0x738D, 0x738F,
+    0x7390, 0x7392, 0x7393, 0x7394, 0x7395, 0x7397, 0x7398, 0x7399, 0x739A, 0x739C, 0x739D, 0x739E,
+    0x73A0, 0x73A1, 0x73A3, 0x73A4, 0x73A5, 0x73A6, 0x73A7, 0x73A8, 0x73AA, 0x73AC, 0x73AD, 0x73B1,
+    0x73B4, 0x73B5, 0x73B6, 0x73B8, 0x73B9, 0x73BC, 0x73BD, 0x73BE, 0x73BF, 0x73C1, 0x73C3, 0x73C4,
+    0x73C5, 0x73C6, 0x73C7, 0x73CB, 0x73CC, 0x73CE, 0x73D2, 0x73D3, 0x73D4, 0x73D5, 0x73D6, 0x73D7,
+    0x73D8, 0x73DA, 0x73DB, 0x73DC, 0x73DD, 0x73DF, 0x73E1, 0x73E2, 0x73E3, 0x73E4, 0x73E6, 0x73E8,
+    0x73EA, 0x73EB, 0x73EC, 0x73EE, 0x73EF, 0x73F0, 0x73F1, 0x73F3, 0x73F4, 0x73F5, 0x73F6, 0x73F7,
+    0xE05E, 0xE05F, 0xE060, 0xE061, 0xE062, 0xE063, 0xE064, 0xE065, 0xE066, 0xE067, 0xE068, 0xE069,
+    0xE06A, 0xE06B, 0xE06C, 0xE06D, 0xE06E, 0xE06F, 0xE070, 0xE071, 0xE072, 0xE073, 0xE074, 0xE075,
+    0xE076, 0xE077, 0xE078, 0xE079, 0xE07A, 0xE07B, 0xE07C, 0xE07D, 0xE07E, 0xE07F, 0xE080, 0xE081,
+    0xE082, 0xE083, 0xE084, 0xE085, 0xE086, 0xE087, 0xE088, 0xE089, 0xE08A, 0xE08B, 0xE08C, 0xE08D,
+    0xE08E, 0xE08F, 0xE090, 0xE091, 0xE092, 0xE093, 0xE094, 0xE095, 0xE096, 0xE097, 0xE098, 0xE099,
+    0xE09A, 0xE09B, 0xE09C, 0xE09D, 0xE09E, 0xE09F, 0xE0A0, 0xE0A1, 0xE0A2, 0xE0A3, 0xE0A4, 0xE0A5,
+    0xE0A6, 0xE0A7, 0xE0A8, 0xE0A9, 0xE0AA, 0xE0AB, 0xE0AC, 0xE0AD, 0xE0AE, 0xE0AF, 0xE0B0, 0xE0B1,
+    0xE0B2, 0xE0B3, 0xE0B4, 0xE0B5, 0xE0B6, 0xE0B7, 0xE0B8, 0xE0B9, 0xE0BA, 0xE0BB, 0x73F8, 0x73F9,
+    0x73FA, 0x73FB, 0x73FC, 0x73FD, 0x73FE, 0x73FF, 0x7400, 0x7401, 0x7402, 0x7404, 0x7407, 0x7408,
+    0x740B, 0x740C, 0x740D, 0x740E, 0x7411, 0x7412, 0x7413, 0x7414, 0x7415, 0x7416, 0x7417, 0x7418,
+    0x7419, 0x741C, 0x741D, 0x741E, 0x741F, 0x7420, 0x7421, 0x7423, 0x7424, 0x7427, 0x7429, 0x742B,
+    0x742D, 0x742F, 0x7431, 0x7432, 0x7437, 0x7438, 0x7439, 0x743A, 0x743B, 0x743D, 0x743E, 0x743F,
+    0x7440, 0x7442, 0x7443, 0x7444, 0x7445, 0x7446, 0x7447, 0x7448, 0x7449, 0x744A, 0x744B, 0x744C,
+    0x744D, 0x744E, 0x744F, 0x7450, 0x7451, 0x7452, 0x7453, 0x7454, 0x7456, 0x7458, 0x745D, 0x7460,
+    0x7461, 0x7462, 0x7463, 0x7464, 0x7465, 0x7466, 0x7467, 0x7468, 0x7469, 0x746A, 0x746B, 0x746C,
+    0x746E, 0x746F, 0x7471, 0x7472, 0x7473, 0x7474, 0x7475, 0x7478, 0x7479, 0x747A, 0xE0BC, 0xE0BD,
+    0xE0BE, 0xE0BF, 0xE0C0, 0xE0C1, 0xE0C2, 0xE0C3, 0xE0C4, 0xE0C5, 0xE0C6, 0xE0C7, 0xE0C8, 0xE0C9,
+    0xE0CA, 0xE0CB, 0xE0CC, 0xE0CD, 0xE0CE, 0xE0CF, 0xE0D0, 0xE0D1, 0xE0D2, 0xE0D3, 0xE0D4, 0xE0D5,
+    0xE0D6, 0xE0D7, 0xE0D8, 0xE0D9, 0xE0DA, 0xE0DB, 0xE0DC, 0xE0DD, 0xE0DE, 0xE0DF, 0xE0E0, 0xE0E1,
+    0xE0E2, 0xE0E3, 0xE0E4, 0xE0E5, 0xE0E6, 0xE0E7, 0xE0E8, 0xE0E9, 0xE0EA, 0xE0EB, 0xE0EC, 0xE0ED,
+    0xE0EE, 0xE0EF, 0xE0F0, 0xE0F1, 0xE0F2, 0xE0F3, 0xE0F4, 0xE0F5, 0xE0F6, 0xE0F7, 0xE0F8, 0xE0F9,
+    0xE0FA, 0xE0FB, 0xE0FC, 0xE0FD, 0xE0FE, 0xE0FF, 0xE100, 0xE101, 0xE102, 0xE103, 0xE104, 0xE105,
+    0xE106, 0xE107, 0xE108, 0xE109, 0xE10A, 0xE10B, 0xE10C, 0xE10D, 0xE10E, 0xE10F, 0xE110, 0xE111,
+    0xE112, 0xE113, 0xE114, 0xE115, 0xE116, 0xE117, 0xE118, 0xE119, 0x747B, 0x747C, 0x747D, 0x747F,
+    0x7482, 0x7484, 0x7485, 0x7486, 0x7488, 0x7489, 0x748A, 0x748C, 0x748D, 0x748F, 0x7491, 0x7492,
+    0x7493, 0x7494, 0x7495, 0x7496, 0x7497, 0x7498, 0x7499, 0x749A, 0x749B, 0x749D, 0x749F, 0x74A0,
+    0x74A1, 0x74A2, 0x74A3, 0x74A4, 0x74A5, 0x74A6, 0x74AA, 0x74AB, 0x74AC, 0x74AD, 0x74AE, 0x74AF,
+    0x74B0, 0x74B1, 0x74B2, 0x74B3, 0x74B4, 0x74B5, 0x74B6, 0x74B7, 0x74B8, 0x74B9, 0x74BB, 0x74BC,
+    0x74BD, 0x74BE, 0x74BF, 0x74C0, 0x74C1, 0x74C2, 0x74C3, 0x74C4, 0x74C5, 0x74C6, 0x74C7, 0x74C8,
+    0x74C9, 0x74CA, 0x74CB, 0x74CC, 0x74CD, 0x74CE, 0x74CF, 0x74D0, 0x74D1, 0x74D3, 0x74D4, 0x74D5,
+    0x74D6, 0x74D7, 0x74D8, 0x74D9, 0x74DA, 0x74DB, 0x74DD, 0x74DF, 0x74E1, 0x74E5, 0x74E7, 0x74E8,
+    0x74E9, 0x74EA, 0x74EB, 0x74EC, 0x74ED, 0x74F0, 0x74F1, 0x74F2, 0xE11A, 0xE11B, 0xE11C, 0xE11D,
+    0xE11E, 0xE11F, 0xE120, 0xE121, 0xE122, 0xE123, 0xE124, 0xE125, 0xE126, 0xE127, 0xE128, 0xE129,
+    0xE12A, 0xE12B, 0xE12C, 0xE12D, 0xE12E, 0xE12F, 0xE130, 0xE131, 0xE132, 0xE133, 0xE134, 0xE135,
+    0xE136, 0xE137, 0xE138, 0xE139, 0xE13A, 0xE13B, 0xE13C, 0xE13D, 0xE13E, 0xE13F, 0xE140, 0xE141,
+    0xE142, 0xE143, 0xE144, 0xE145, 0xE146, 0xE147, 0xE148, 0xE149, 0xE14A, 0xE14B, 0xE14C, 0xE14D,
+    0xE14E, 0xE14F, 0xE150, 0xE151, 0xE152, 0xE153, 0xE154, 0xE155, 0xE156, 0xE157, 0xE158, 0xE159,
+    0xE15A, 0xE15B, 0xE15C, 0xE15D, 0xE15E, 0xE15F, 0xE160, 0xE161, 0xE162, 0xE163, 0xE164, 0xE165,
+    0xE166, 0xE167, 0xE168, 0xE169, 0xE16A, 0xE16B, 0xE16C, 0xE16D, 0xE16E, 0xE16F, 0xE170, 0xE171,
+    0xE172, 0xE173, 0xE174, 0xE175, 0xE176, 0xE177, 0x74F3, 0x74F5, 0x74F8, 0x74F9, 0x74FA, 0x74FB,
+    0x74FC, 0x74FD, 0x74FE, 0x7500, 0x7501, 0x7502, 0x7503, 0x7505, 0x7506, 0x7507, 0x7508, 0x7509,
+    0x750A, 0x750B, 0x750C, 0x750E, 0x7510, 0x7512, 0x7514, 0x7515, 0x7516, 0x7517, 0x751B, 0x751D,
+    0x751E, 0x7520, 0x7521, 0x7522, 0x7523, 0x7524, 0x7526, 0x7527, 0x752A, 0x752E, 0x7534, 0x7536,
+    0x7539, 0x753C, 0x753D, 0x753F, 0x7541, 0x7542, 0x7543, 0x7544, 0x7546, 0x7547, 0x7549, 0x754A,
+    0x754D, 0x7550, 0x7551, 0x7552, 0x7553, 0x7555, 0x7556, 0x7557, 0x7558, 0x755D, 0x755E, 0x755F,
+    0x7560, 0x7561, 0x7562, 0x7563, 0x7564, 0x7567, 0x7568, 0x7569, 0x756B, 0x756C, 0x756D, 0x756E,
+    0x756F, 0x7570, 0x7571, 0x7573, 0x7575, 0x7576, 0x7577, 0x757A, 0x757B, 0x757C, 0x757D, 0x757E,
+    0x7580, 0x7581, 0x7582, 0x7584, 0x7585, 0x7587, 0xE178, 0xE179, 0xE17A, 0xE17B, 0xE17C, 0xE17D,
+    0xE17E, 0xE17F, 0xE180, 0xE181, 0xE182, 0xE183, 0xE184, 0xE185, 0xE186, 0xE187, 0xE188, 0xE189,
+    0xE18A, 0xE18B, 0xE18C, 0xE18D, 0xE18E, 0xE18F, 0xE190, 0xE191, 0xE192, 0xE193, 0xE194, 0xE195,
+    0xE196, 0xE197, 0xE198, 0xE199, 0xE19A, 0xE19B, 0xE19C, 0xE19D, 0xE19E, 0xE19F, 0xE1A0, 0xE1A1,
+    0xE1A2, 0xE1A3, 0xE1A4, 0xE1A5, 0xE1A6, 0xE1A7, 0xE1A8, 0xE1A9, 0xE1AA, 0xE1AB, 0xE1AC, 0xE1AD,
+    0xE1AE, 0xE1AF, 0xE1B0, 0xE1B1, 0xE1B2, 0xE1B3, 0xE1B4, 0xE1B5, 0xE1B6, 0xE1B7, 0xE1B8, 0xE1B9,
+    0xE1BA, 0xE1BB, 0xE1BC, 0xE1BD, 0xE1BE, 0xE1BF, 0xE1C0, 0xE1C1, 0xE1C2, 0xE1C3, 0xE1C4, 0xE1C5,
+    0xE1C6, 0xE1C7, 0xE1C8, 0xE1C9, 0xE1CA, 0xE1CB, 0xE1CC, 0xE1CD, 0xE1CE, 0xE1CF, 0xE1D0, 0xE1D1,
+    0xE1D2, 0xE1D3, 0xE1D4, 0xE1D5, 0x7588, 0x7589, 0x758A, 0x758C, 0x758D, 0x758E, 0x7590, 0x7593,
+    0x7595, 0x7598, 0x759B, 0x759C, 0x759E, 0x75A2, 0x75A6, 0x75A7, 0x75A8, 0x75A9, 0x75AA, 0x75AD,
+    0x75B6, 0x75B7, 0x75BA, 0x75BB, 0x75BF, 0x75C0, 0x75C1, 0x75C6, 0x75CB, 0x75CC, 0x75CE, 0x75CF,
+    0x75D0, 0x75D1, 0x75D3, 0x75D7, 0x75D9, 0x75DA, 0x75DC, 0x75DD, 0x75DF, 0x75E0, 0x75E1, 0x75E5,
+    0x75E9, 0x75EC, 0x75ED, 0x75EE, 0x75EF, 0x75F2, 0x75F3, 0x75F5, 0x75F6, 0x75F7, 0x75F8, 0x75FA,
+    0x75FB, 0x75FD, 0x75FE, 0x7602, 0x7604, 0x7606, 0x7607, 0x7608, 0x7609, 0x760B, 0x760D, 0x760E,
+    0x760F, 0x7611, 0x7612, 0x7613, 0x7614, 0x7616, 0x761A, 0x761C, 0x761D, 0x761E, 0x7621, 0x7623,
+    0x7627, 0x7628, 0x762C, 0x762E, 0x762F, 0x7631, 0x7632, 0x7636, 0x7637, 0x7639, 0x763A, 0x763B,
+    0x763D, 0x7641, 0x7642, 0x7644, 0xE1D6, 0xE1D7, 0xE1D8, 0xE1D9, 0xE1DA, 0xE1DB, 0xE1DC, 0xE1DD,
+    0xE1DE, 0xE1DF, 0xE1E0, 0xE1E1, 0xE1E2, 0xE1E3, 0xE1E4, 0xE1E5, 0xE1E6, 0xE1E7, 0xE1E8, 0xE1E9,
+    0xE1EA, 0xE1EB, 0xE1EC, 0xE1ED, 0xE1EE, 0xE1EF, 0xE1F0, 0xE1F1, 0xE1F2, 0xE1F3, 0xE1F4, 0xE1F5,
+    0xE1F6, 0xE1F7, 0xE1F8, 0xE1F9, 0xE1FA, 0xE1FB, 0xE1FC, 0xE1FD, 0xE1FE, 0xE1FF, 0xE200, 0xE201,
+    0xE202, 0xE203, 0xE204, 0xE205, 0xE206, 0xE207, 0xE208, 0xE209, 0xE20A, 0xE20B, 0xE20C, 0xE20D,
+    0xE20E, 0xE20F, 0xE210, 0xE211, 0xE212, 0xE213, 0xE214, 0xE215, 0xE216, 0xE217, 0xE218, 0xE219,
+    0xE21A, 0xE21B, 0xE21C, 0xE21D, 0xE21E, 0xE21F, 0xE220, 0xE221, 0xE222, 0xE223, 0xE224, 0xE225,
+    0xE226, 0xE227, 0xE228, 0xE229, 0xE22A, 0xE22B, 0xE22C, 0xE22D, 0xE22E, 0xE22F, 0xE230, 0xE231,
+    0xE232, 0xE233, 0x7645, 0x7646, 0x7647, 0x7648, 0x7649, 0x764A, 0x764B, 0x764E, 0x764F, 0x7650,
+    0x7651, 0x7652, 0x7653, 0x7655, 0x7657, 0x7658, 0x7659, 0x765A, 0x765B, 0x765D, 0x765F, 0x7660,
+    0x7661, 0x7662, 0x7664, 0x7665, 0x7666, 0x7667, 0x7668, 0x7669, 0x766A, 0x766C, 0x766D, 0x766E,
+    0x7670, 0x7671, 0x7672, 0x7673, 0x7674, 0x7675, 0x7676, 0x7677, 0x7679, 0x767A, 0x767C, 0x767F,
+    0x7680, 0x7681, 0x7683, 0x7685, 0x7689, 0x768A, 0x768C, 0x768D, 0x768F, 0x7690, 0x7692, 0x7694,
+    0x7695, 0x7697, 0x7698, 0x769A, 0x769B, 0x769C, 0x769D, 0x769E, 0x769F, 0x76A0, 0x76A1, 0x76A2,
+    0x76A3, 0x76A5, 0x76A6, 0x76A7, 0x76A8, 0x76A9, 0x76AA, 0x76AB, 0x76AC, 0x76AD, 0x76AF, 0x76B0,
+    0x76B3, 0x76B5, 0x76B6, 0x76B7, 0x76B8, 0x76B9, 0x76BA, 0x76BB, 0x76BC, 0x76BD, 0x76BE, 0x76C0,
+    0x76C1, 0x76C3, 0x554A, 0x963F, 0x57C3, 0x6328, 0x54CE, 0x5509, 0x54C0, 0x7691, 0x764C, 0x853C,
+    0x77EE, 0x827E, 0x788D, 0x7231, 0x9698, 0x978D, 0x6C28, 0x5B89, 0x4FFA, 0x6309, 0x6697, 0x5CB8,
+    0x80FA, 0x6848, 0x80AE, 0x6602, 0x76CE, 0x51F9, 0x6556, 0x71AC, 0x7FF1, 0x8884, 0x50B2, 0x5965,
+    0x61CA, 0x6FB3, 0x82AD, 0x634C, 0x6252, 0x53ED, 0x5427, 0x7B06, 0x516B, 0x75A4, 0x5DF4, 0x62D4,
+    0x8DCB, 0x9776, 0x628A, 0x8019, 0x575D, 0x9738, 0x7F62, 0x7238, 0x767D, 0x67CF, 0x767E, 0x6446,
+    0x4F70, 0x8D25, 0x62DC, 0x7A17, 0x6591, 0x73ED, 0x642C, 0x6273, 0x822C, 0x9881, 0x677F, 0x7248,
+    0x626E, 0x62CC, 0x4F34, 0x74E3, 0x534A, 0x529E, 0x7ECA, 0x90A6, 0x5E2E, 0x6886, 0x699C, 0x8180,
+    0x7ED1, 0x68D2, 0x78C5, 0x868C, 0x9551, 0x508D, 0x8C24, 0x82DE, 0x80DE, 0x5305, 0x8912, 0x5265,
+    0x76C4, 0x76C7, 0x76C9, 0x76CB, 0x76CC, 0x76D3, 0x76D5, 0x76D9, 0x76DA, 0x76DC, 0x76DD, 0x76DE,
+    0x76E0, 0x76E1, 0x76E2, 0x76E3, 0x76E4, 0x76E6, 0x76E7, 0x76E8, 0x76E9, 0x76EA, 0x76EB, 0x76EC,
+    0x76ED, 0x76F0, 0x76F3, 0x76F5, 0x76F6, 0x76F7, 0x76FA, 0x76FB, 0x76FD, 0x76FF, 0x7700, 0x7702,
+    0x7703, 0x7705, 0x7706, 0x770A, 0x770C, 0x770E, 0x770F, 0x7710, 0x7711, 0x7712, 0x7713, 0x7714,
+    0x7715, 0x7716, 0x7717, 0x7718, 0x771B, 0x771C, 0x771D, 0x771E, 0x7721, 0x7723, 0x7724, 0x7725,
+    0x7727, 0x772A, 0x772B, 0x772C, 0x772E, 0x7730, 0x7731, 0x7732, 0x7733, 0x7734, 0x7739, 0x773B,
+    0x773D, 0x773E, 0x773F, 0x7742, 0x7744, 0x7745, 0x7746, 0x7748, 0x7749, 0x774A, 0x774B, 0x774C,
+    0x774D, 0x774E, 0x774F, 0x7752, 0x7753, 0x7754, 0x7755, 0x7756, 0x7757, 0x7758, 0x7759, 0x775C,
+    0x8584, 0x96F9, 0x4FDD, 0x5821, 0x9971, 0x5B9D, 0x62B1, 0x62A5, 0x66B4, 0x8C79, 0x9C8D, 0x7206,
+    0x676F, 0x7891, 0x60B2, 0x5351, 0x5317, 0x8F88, 0x80CC, 0x8D1D, 0x94A1, 0x500D, 0x72C8, 0x5907,
+    0x60EB, 0x7119, 0x88AB, 0x5954, 0x82EF, 0x672C, 0x7B28, 0x5D29, 0x7EF7, 0x752D, 0x6CF5, 0x8E66,
+    0x8FF8, 0x903C, 0x9F3B, 0x6BD4, 0x9119, 0x7B14, 0x5F7C, 0x78A7, 0x84D6, 0x853D, 0x6BD5, 0x6BD9,
+    0x6BD6, 0x5E01, 0x5E87, 0x75F9, 0x95ED, 0x655D, 0x5F0A, 0x5FC5, 0x8F9F, 0x58C1, 0x81C2, 0x907F,
+    0x965B, 0x97AD, 0x8FB9, 0x7F16, 0x8D2C, 0x6241, 0x4FBF, 0x53D8, 0x535E, 0x8FA8, 0x8FA9, 0x8FAB,
+    0x904D, 0x6807, 0x5F6A, 0x8198, 0x8868, 0x9CD6, 0x618B, 0x522B, 0x762A, 0x5F6C, 0x658C, 0x6FD2,
+    0x6EE8, 0x5BBE, 0x6448, 0x5175, 0x51B0, 0x67C4, 0x4E19, 0x79C9, 0x997C, 0x70B3, 0x775D, 0x775E,
+    0x775F, 0x7760, 0x7764, 0x7767, 0x7769, 0x776A, 0x776D, 0x776E, 0x776F, 0x7770, 0x7771, 0x7772,
+    0x7773, 0x7774, 0x7775, 0x7776, 0x7777, 0x7778, 0x777A, 0x777B, 0x777C, 0x7781, 0x7782, 0x7783,
+    0x7786, 0x7787, 0x7788, 0x7789, 0x778A, 0x778B, 0x778F, 0x7790, 0x7793, 0x7794, 0x7795, 0x7796,
+    0x7797, 0x7798, 0x7799, 0x779A, 0x779B, 0x779C, 0x779D, 0x779E, 0x77A1, 0x77A3, 0x77A4, 0x77A6,
+    0x77A8, 0x77AB, 0x77AD, 0x77AE, 0x77AF, 0x77B1, 0x77B2, 0x77B4, 0x77B6, 0x77B7, 0x77B8, 0x77B9,
+    0x77BA, 0x77BC, 0x77BE, 0x77C0, 0x77C1, 0x77C2, 0x77C3, 0x77C4, 0x77C5, 0x77C6, 0x77C7, 0x77C8,
+    0x77C9, 0x77CA, 0x77CB, 0x77CC, 0x77CE, 0x77CF, 0x77D0, 0x77D1, 0x77D2, 0x77D3, 0x77D4, 0x77D5,
+    0x77D6, 0x77D8, 0x77D9, 0x77DA, 0x77DD, 0x77DE, 0x77DF, 0x77E0, 0x77E1, 0x77E4, 0x75C5, 0x5E76,
+    0x73BB, 0x83E0, 0x64AD, 0x62E8, 0x94B5, 0x6CE2, 0x535A, 0x52C3, 0x640F, 0x94C2, 0x7B94, 0x4F2F,
+    0x5E1B, 0x8236, 0x8116, 0x818A, 0x6E24, 0x6CCA, 0x9A73, 0x6355, 0x535C, 0x54FA, 0x8865, 0x57E0,
+    0x4E0D, 0x5E03, 0x6B65, 0x7C3F, 0x90E8, 0x6016, 0x64E6, 0x731C, 0x88C1, 0x6750, 0x624D, 0x8D22,
+    0x776C, 0x8E29, 0x91C7, 0x5F69, 0x83DC, 0x8521, 0x9910, 0x53C2, 0x8695, 0x6B8B, 0x60ED, 0x60E8,
+    0x707F, 0x82CD, 0x8231, 0x4ED3, 0x6CA7, 0x85CF, 0x64CD, 0x7CD9, 0x69FD, 0x66F9, 0x8349, 0x5395,
+    0x7B56, 0x4FA7, 0x518C, 0x6D4B, 0x5C42, 0x8E6D, 0x63D2, 0x53C9, 0x832C, 0x8336, 0x67E5, 0x78B4,
+    0x643D, 0x5BDF, 0x5C94, 0x5DEE, 0x8BE7, 0x62C6, 0x67F4, 0x8C7A, 0x6400, 0x63BA, 0x8749, 0x998B,
+    0x8C17, 0x7F20, 0x94F2, 0x4EA7, 0x9610, 0x98A4, 0x660C, 0x7316, 0x77E6, 0x77E8, 0x77EA, 0x77EF,
+    0x77F0, 0x77F1, 0x77F2, 0x77F4, 0x77F5, 0x77F7, 0x77F9, 0x77FA, 0x77FB, 0x77FC, 0x7803, 0x7804,
+    0x7805, 0x7806, 0x7807, 0x7808, 0x780A, 0x780B, 0x780E, 0x780F, 0x7810, 0x7813, 0x7815, 0x7819,
+    0x781B, 0x781E, 0x7820, 0x7821, 0x7822, 0x7824, 0x7828, 0x782A, 0x782B, 0x782E, 0x782F, 0x7831,
+    0x7832, 0x7833, 0x7835, 0x7836, 0x783D, 0x783F, 0x7841, 0x7842, 0x7843, 0x7844, 0x7846, 0x7848,
+    0x7849, 0x784A, 0x784B, 0x784D, 0x784F, 0x7851, 0x7853, 0x7854, 0x7858, 0x7859, 0x785A, 0x785B,
+    0x785C, 0x785E, 0x785F, 0x7860, 0x7861, 0x7862, 0x7863, 0x7864, 0x7865, 0x7866, 0x7867, 0x7868,
+    0x7869, 0x786F, 0x7870, 0x7871, 0x7872, 0x7873, 0x7874, 0x7875, 0x7876, 0x7878, 0x7879, 0x787A,
+    0x787B, 0x787D, 0x787E, 0x787F, 0x7880, 0x7881, 0x7882, 0x7883, 0x573A, 0x5C1D, 0x5E38, 0x957F,
+    0x507F, 0x80A0, 0x5382, 0x655E, 0x7545, 0x5531, 0x5021, 0x8D85, 0x6284, 0x949E, 0x671D, 0x5632,
+    0x6F6E, 0x5DE2, 0x5435, 0x7092, 0x8F66, 0x626F, 0x64A4, 0x63A3, 0x5F7B, 0x6F88, 0x90F4, 0x81E3,
+    0x8FB0, 0x5C18, 0x6668, 0x5FF1, 0x6C89, 0x9648, 0x8D81, 0x886C, 0x6491, 0x79F0, 0x57CE, 0x6A59,
+    0x6210, 0x5448, 0x4E58, 0x7A0B, 0x60E9, 0x6F84, 0x8BDA, 0x627F, 0x901E, 0x9A8B, 0x79E4, 0x5403,
+    0x75F4, 0x6301, 0x5319, 0x6C60, 0x8FDF, 0x5F1B, 0x9A70, 0x803B, 0x9F7F, 0x4F88, 0x5C3A, 0x8D64,
+    0x7FC5, 0x65A5, 0x70BD, 0x5145, 0x51B2, 0x866B, 0x5D07, 0x5BA0, 0x62BD, 0x916C, 0x7574, 0x8E0C,
+    0x7A20, 0x6101, 0x7B79, 0x4EC7, 0x7EF8, 0x7785, 0x4E11, 0x81ED, 0x521D, 0x51FA, 0x6A71, 0x53A8,
+    0x8E87, 0x9504, 0x96CF, 0x6EC1, 0x9664, 0x695A, 0x7884, 0x7885, 0x7886, 0x7888, 0x788A, 0x788B,
+    0x788F, 0x7890, 0x7892, 0x7894, 0x7895, 0x7896, 0x7899, 0x789D, 0x789E, 0x78A0, 0x78A2, 0x78A4,
+    0x78A6, 0x78A8, 0x78A9, 0x78AA, 0x78AB, 0x78AC, 0x78AD, 0x78AE, 0x78AF, 0x78B5, 0x78B6, 0x78B7,
+    0x78B8, 0x78BA, 0x78BB, 0x78BC, 0x78BD, 0x78BF, 0x78C0, 0x78C2, 0x78C3, 0x78C4, 0x78C6, 0x78C7,
+    0x78C8, 0x78CC, 0x78CD, 0x78CE, 0x78CF, 0x78D1, 0x78D2, 0x78D3, 0x78D6, 0x78D7, 0x78D8, 0x78DA,
+    0x78DB, 0x78DC, 0x78DD, 0x78DE, 0x78DF, 0x78E0, 0x78E1, 0x78E2, 0x78E3, 0x78E4, 0x78E5, 0x78E6,
+    0x78E7, 0x78E9, 0x78EA, 0x78EB, 0x78ED, 0x78EE, 0x78EF, 0x78F0, 0x78F1, 0x78F3, 0x78F5, 0x78F6,
+    0x78F8, 0x78F9, 0x78FB, 0x78FC, 0x78FD, 0x78FE, 0x78FF, 0x7900, 0x7902, 0x7903, 0x7904, 0x7906,
+    0x7907, 0x7908, 0x7909, 0x790A, 0x790B, 0x790C, 0x7840, 0x50A8, 0x77D7, 0x6410, 0x89E6, 0x5904,
+    0x63E3, 0x5DDD, 0x7A7F, 0x693D, 0x4F20, 0x8239, 0x5598, 0x4E32, 0x75AE, 0x7A97, 0x5E62, 0x5E8A,
+    0x95EF, 0x521B, 0x5439, 0x708A, 0x6376, 0x9524, 0x5782, 0x6625, 0x693F, 0x9187, 0x5507, 0x6DF3,
+    0x7EAF, 0x8822, 0x6233, 0x7EF0, 0x75B5, 0x8328, 0x78C1, 0x96CC, 0x8F9E, 0x6148, 0x74F7, 0x8BCD,
+    0x6B64, 0x523A, 0x8D50, 0x6B21, 0x806A, 0x8471, 0x56F1, 0x5306, 0x4ECE, 0x4E1B, 0x51D1, 0x7C97,
+    0x918B, 0x7C07, 0x4FC3, 0x8E7F, 0x7BE1, 0x7A9C, 0x6467, 0x5D14, 0x50AC, 0x8106, 0x7601, 0x7CB9,
+    0x6DEC, 0x7FE0, 0x6751, 0x5B58, 0x5BF8, 0x78CB, 0x64AE, 0x6413, 0x63AA, 0x632B, 0x9519, 0x642D,
+    0x8FBE, 0x7B54, 0x7629, 0x6253, 0x5927, 0x5446, 0x6B79, 0x50A3, 0x6234, 0x5E26, 0x6B86, 0x4EE3,
+    0x8D37, 0x888B, 0x5F85, 0x902E, 0x790D, 0x790E, 0x790F, 0x7910, 0x7911, 0x7912, 0x7914, 0x7915,
+    0x7916, 0x7917, 0x7918, 0x7919, 0x791A, 0x791B, 0x791C, 0x791D, 0x791F, 0x7920, 0x7921, 0x7922,
+    0x7923, 0x7925, 0x7926, 0x7927, 0x7928, 0x7929, 0x792A, 0x792B, 0x792C, 0x792D, 0x792E, 0x792F,
+    0x7930, 0x7931, 0x7932, 0x7933, 0x7935, 0x7936, 0x7937, 0x7938, 0x7939, 0x793D, 0x793F, 0x7942,
+    0x7943, 0x7944, 0x7945, 0x7947, 0x794A, 0x794B, 0x794C, 0x794D, 0x794E, 0x794F, 0x7950, 0x7951,
+    0x7952, 0x7954, 0x7955, 0x7958, 0x7959, 0x7961, 0x7963, 0x7964, 0x7966, 0x7969, 0x796A, 0x796B,
+    0x796C, 0x796E, 0x7970, 0x7971, 0x7972, 0x7973, 0x7974, 0x7975, 0x7976, 0x7979, 0x797B, 0x797C,
+    0x797D, 0x797E, 0x797F, 0x7982, 0x7983, 0x7986, 0x7987, 0x7988, 0x7989, 0x798B, 0x798C, 0x798D,
+    0x798E, 0x7990, 0x7991, 0x7992, 0x6020, 0x803D, 0x62C5, 0x4E39, 0x5355, 0x90F8, 0x63B8, 0x80C6,
+    0x65E6, 0x6C2E, 0x4F46, 0x60EE, 0x6DE1, 0x8BDE, 0x5F39, 0x86CB, 0x5F53, 0x6321, 0x515A, 0x8361,
+    0x6863, 0x5200, 0x6363, 0x8E48, 0x5012, 0x5C9B, 0x7977, 0x5BFC, 0x5230, 0x7A3B, 0x60BC, 0x9053,
+    0x76D7, 0x5FB7, 0x5F97, 0x7684, 0x8E6C, 0x706F, 0x767B, 0x7B49, 0x77AA, 0x51F3, 0x9093, 0x5824,
+    0x4F4E, 0x6EF4, 0x8FEA, 0x654C, 0x7B1B, 0x72C4, 0x6DA4, 0x7FDF, 0x5AE1, 0x62B5, 0x5E95, 0x5730,
+    0x8482, 0x7B2C, 0x5E1D, 0x5F1F, 0x9012, 0x7F14, 0x98A0, 0x6382, 0x6EC7, 0x7898, 0x70B9, 0x5178,
+    0x975B, 0x57AB, 0x7535, 0x4F43, 0x7538, 0x5E97, 0x60E6, 0x5960, 0x6DC0, 0x6BBF, 0x7889, 0x53FC,
+    0x96D5, 0x51CB, 0x5201, 0x6389, 0x540A, 0x9493, 0x8C03, 0x8DCC, 0x7239, 0x789F, 0x8776, 0x8FED,
+    0x8C0D, 0x53E0, 0x7993, 0x7994, 0x7995, 0x7996, 0x7997, 0x7998, 0x7999, 0x799B, 0x799C, 0x799D,
+    0x799E, 0x799F, 0x79A0, 0x79A1, 0x79A2, 0x79A3, 0x79A4, 0x79A5, 0x79A6, 0x79A8, 0x79A9, 0x79AA,
+    0x79AB, 0x79AC, 0x79AD, 0x79AE, 0x79AF, 0x79B0, 0x79B1, 0x79B2, 0x79B4, 0x79B5, 0x79B6, 0x79B7,
+    0x79B8, 0x79BC, 0x79BF, 0x79C2, 0x79C4, 0x79C5, 0x79C7, 0x79C8, 0x79CA, 0x79CC, 0x79CE, 0x79CF,
+    0x79D0, 0x79D3, 0x79D4, 0x79D6, 0x79D7, 0x79D9, 0x79DA, 0x79DB, 0x79DC, 0x79DD, 0x79DE, 0x79E0,
+    0x79E1, 0x79E2, 0x79E5, 0x79E8, 0x79EA, 0x79EC, 0x79EE, 0x79F1, 0x79F2, 0x79F3, 0x79F4, 0x79F5,
+    0x79F6, 0x79F7, 0x79F9, 0x79FA, 0x79FC, 0x79FE, 0x79FF, 0x7A01, 0x7A04, 0x7A05, 0x7A07, 0x7A08,
+    0x7A09, 0x7A0A, 0x7A0C, 0x7A0F, 0x7A10, 0x7A11, 0x7A12, 0x7A13, 0x7A15, 0x7A16, 0x7A18, 0x7A19,
+    0x7A1B, 0x7A1C, 0x4E01, 0x76EF, 0x53EE, 0x9489, 0x9876, 0x9F0E, 0x952D, 0x5B9A, 0x8BA2, 0x4E22,
+    0x4E1C, 0x51AC, 0x8463, 0x61C2, 0x52A8, 0x680B, 0x4F97, 0x606B, 0x51BB, 0x6D1E, 0x515C, 0x6296,
+    0x6597, 0x9661, 0x8C46, 0x9017, 0x75D8, 0x90FD, 0x7763, 0x6BD2, 0x728A, 0x72EC, 0x8BFB, 0x5835,
+    0x7779, 0x8D4C, 0x675C, 0x9540, 0x809A, 0x5EA6, 0x6E21, 0x5992, 0x7AEF, 0x77ED, 0x953B, 0x6BB5,
+    0x65AD, 0x7F0E, 0x5806, 0x5151, 0x961F, 0x5BF9, 0x58A9, 0x5428, 0x8E72, 0x6566, 0x987F, 0x56E4,
+    0x949D, 0x76FE, 0x9041, 0x6387, 0x54C6, 0x591A, 0x593A, 0x579B, 0x8EB2, 0x6735, 0x8DFA, 0x8235,
+    0x5241, 0x60F0, 0x5815, 0x86FE, 0x5CE8, 0x9E45, 0x4FC4, 0x989D, 0x8BB9, 0x5A25, 0x6076, 0x5384,
+    0x627C, 0x904F, 0x9102, 0x997F, 0x6069, 0x800C, 0x513F, 0x8033, 0x5C14, 0x9975, 0x6D31, 0x4E8C,
+    0x7A1D, 0x7A1F, 0x7A21, 0x7A22, 0x7A24, 0x7A25, 0x7A26, 0x7A27, 0x7A28, 0x7A29, 0x7A2A, 0x7A2B,
+    0x7A2C, 0x7A2D, 0x7A2E, 0x7A2F, 0x7A30, 0x7A31, 0x7A32, 0x7A34, 0x7A35, 0x7A36, 0x7A38, 0x7A3A,
+    0x7A3E, 0x7A40, 0x7A41, 0x7A42, 0x7A43, 0x7A44, 0x7A45, 0x7A47, 0x7A48, 0x7A49, 0x7A4A, 0x7A4B,
+    0x7A4C, 0x7A4D, 0x7A4E, 0x7A4F, 0x7A50, 0x7A52, 0x7A53, 0x7A54, 0x7A55, 0x7A56, 0x7A58, 0x7A59,
+    0x7A5A, 0x7A5B, 0x7A5C, 0x7A5D, 0x7A5E, 0x7A5F, 0x7A60, 0x7A61, 0x7A62, 0x7A63, 0x7A64, 0x7A65,
+    0x7A66, 0x7A67, 0x7A68, 0x7A69, 0x7A6A, 0x7A6B, 0x7A6C, 0x7A6D, 0x7A6E, 0x7A6F, 0x7A71, 0x7A72,
+    0x7A73, 0x7A75, 0x7A7B, 0x7A7C, 0x7A7D, 0x7A7E, 0x7A82, 0x7A85, 0x7A87, 0x7A89, 0x7A8A, 0x7A8B,
+    0x7A8C, 0x7A8E, 0x7A8F, 0x7A90, 0x7A93, 0x7A94, 0x7A99, 0x7A9A, 0x7A9B, 0x7A9E, 0x7AA1, 0x7AA2,
+    0x8D30, 0x53D1, 0x7F5A, 0x7B4F, 0x4F10, 0x4E4F, 0x9600, 0x6CD5, 0x73D0, 0x85E9, 0x5E06, 0x756A,
+    0x7FFB, 0x6A0A, 0x77FE, 0x9492, 0x7E41, 0x51E1, 0x70E6, 0x53CD, 0x8FD4, 0x8303, 0x8D29, 0x72AF,
+    0x996D, 0x6CDB, 0x574A, 0x82B3, 0x65B9, 0x80AA, 0x623F, 0x9632, 0x59A8, 0x4EFF, 0x8BBF, 0x7EBA,
+    0x653E, 0x83F2, 0x975E, 0x5561, 0x98DE, 0x80A5, 0x532A, 0x8BFD, 0x5420, 0x80BA, 0x5E9F, 0x6CB8,
+    0x8D39, 0x82AC, 0x915A, 0x5429, 0x6C1B, 0x5206, 0x7EB7, 0x575F, 0x711A, 0x6C7E, 0x7C89, 0x594B,
+    0x4EFD, 0x5FFF, 0x6124, 0x7CAA, 0x4E30, 0x5C01, 0x67AB, 0x8702, 0x5CF0, 0x950B, 0x98CE, 0x75AF,
+    0x70FD, 0x9022, 0x51AF, 0x7F1D, 0x8BBD, 0x5949, 0x51E4, 0x4F5B, 0x5426, 0x592B, 0x6577, 0x80A4,
+    0x5B75, 0x6276, 0x62C2, 0x8F90, 0x5E45, 0x6C1F, 0x7B26, 0x4F0F, 0x4FD8, 0x670D, 0x7AA3, 0x7AA4,
+    0x7AA7, 0x7AA9, 0x7AAA, 0x7AAB, 0x7AAE, 0x7AAF, 0x7AB0, 0x7AB1, 0x7AB2, 0x7AB4, 0x7AB5, 0x7AB6,
+    0x7AB7, 0x7AB8, 0x7AB9, 0x7ABA, 0x7ABB, 0x7ABC, 0x7ABD, 0x7ABE, 0x7AC0, 0x7AC1, 0x7AC2, 0x7AC3,
+    0x7AC4, 0x7AC5, 0x7AC6, 0x7AC7, 0x7AC8, 0x7AC9, 0x7ACA, 0x7ACC, 0x7ACD, 0x7ACE, 0x7ACF, 0x7AD0,
+    0x7AD1, 0x7AD2, 0x7AD3, 0x7AD4, 0x7AD5, 0x7AD7, 0x7AD8, 0x7ADA, 0x7ADB, 0x7ADC, 0x7ADD, 0x7AE1,
+    0x7AE2, 0x7AE4, 0x7AE7, 0x7AE8, 0x7AE9, 0x7AEA, 0x7AEB, 0x7AEC, 0x7AEE, 0x7AF0, 0x7AF1, 0x7AF2,
+    0x7AF3, 0x7AF4, 0x7AF5, 0x7AF6, 0x7AF7, 0x7AF8, 0x7AFB, 0x7AFC, 0x7AFE, 0x7B00, 0x7B01, 0x7B02,
+    0x7B05, 0x7B07, 0x7B09, 0x7B0C, 0x7B0D, 0x7B0E, 0x7B10, 0x7B12, 0x7B13, 0x7B16, 0x7B17, 0x7B18,
+    0x7B1A, 0x7B1C, 0x7B1D, 0x7B1F, 0x7B21, 0x7B22, 0x7B23, 0x7B27, 0x7B29, 0x7B2D, 0x6D6E, 0x6DAA,
+    0x798F, 0x88B1, 0x5F17, 0x752B, 0x629A, 0x8F85, 0x4FEF, 0x91DC, 0x65A7, 0x812F, 0x8151, 0x5E9C,
+    0x8150, 0x8D74, 0x526F, 0x8986, 0x8D4B, 0x590D, 0x5085, 0x4ED8, 0x961C, 0x7236, 0x8179, 0x8D1F,
+    0x5BCC, 0x8BA3, 0x9644, 0x5987, 0x7F1A, 0x5490, 0x5676, 0x560E, 0x8BE5, 0x6539, 0x6982, 0x9499,
+    0x76D6, 0x6E89, 0x5E72, 0x7518, 0x6746, 0x67D1, 0x7AFF, 0x809D, 0x8D76, 0x611F, 0x79C6, 0x6562,
+    0x8D63, 0x5188, 0x521A, 0x94A2, 0x7F38, 0x809B, 0x7EB2, 0x5C97, 0x6E2F, 0x6760, 0x7BD9, 0x768B,
+    0x9AD8, 0x818F, 0x7F94, 0x7CD5, 0x641E, 0x9550, 0x7A3F, 0x544A, 0x54E5, 0x6B4C, 0x6401, 0x6208,
+    0x9E3D, 0x80F3, 0x7599, 0x5272, 0x9769, 0x845B, 0x683C, 0x86E4, 0x9601, 0x9694, 0x94EC, 0x4E2A,
+    0x5404, 0x7ED9, 0x6839, 0x8DDF, 0x8015, 0x66F4, 0x5E9A, 0x7FB9, 0x7B2F, 0x7B30, 0x7B32, 0x7B34,
+    0x7B35, 0x7B36, 0x7B37, 0x7B39, 0x7B3B, 0x7B3D, 0x7B3F, 0x7B40, 0x7B41, 0x7B42, 0x7B43, 0x7B44,
+    0x7B46, 0x7B48, 0x7B4A, 0x7B4D, 0x7B4E, 0x7B53, 0x7B55, 0x7B57, 0x7B59, 0x7B5C, 0x7B5E, 0x7B5F,
+    0x7B61, 0x7B63, 0x7B64, 0x7B65, 0x7B66, 0x7B67, 0x7B68, 0x7B69, 0x7B6A, 0x7B6B, 0x7B6C, 0x7B6D,
+    0x7B6F, 0x7B70, 0x7B73, 0x7B74, 0x7B76, 0x7B78, 0x7B7A, 0x7B7C, 0x7B7D, 0x7B7F, 0x7B81, 0x7B82,
+    0x7B83, 0x7B84, 0x7B86, 0x7B87, 0x7B88, 0x7B89, 0x7B8A, 0x7B8B, 0x7B8C, 0x7B8E, 0x7B8F, 0x7B91,
+    0x7B92, 0x7B93, 0x7B96, 0x7B98, 0x7B99, 0x7B9A, 0x7B9B, 0x7B9E, 0x7B9F, 0x7BA0, 0x7BA3, 0x7BA4,
+    0x7BA5, 0x7BAE, 0x7BAF, 0x7BB0, 0x7BB2, 0x7BB3, 0x7BB5, 0x7BB6, 0x7BB7, 0x7BB9, 0x7BBA, 0x7BBB,
+    0x7BBC, 0x7BBD, 0x7BBE, 0x7BBF, 0x7BC0, 0x7BC2, 0x7BC3, 0x7BC4, 0x57C2, 0x803F, 0x6897, 0x5DE5,
+    0x653B, 0x529F, 0x606D, 0x9F9A, 0x4F9B, 0x8EAC, 0x516C, 0x5BAB, 0x5F13, 0x5DE9, 0x6C5E, 0x62F1,
+    0x8D21, 0x5171, 0x94A9, 0x52FE, 0x6C9F, 0x82DF, 0x72D7, 0x57A2, 0x6784, 0x8D2D, 0x591F, 0x8F9C,
+    0x83C7, 0x5495, 0x7B8D, 0x4F30, 0x6CBD, 0x5B64, 0x59D1, 0x9F13, 0x53E4, 0x86CA, 0x9AA8, 0x8C37,
+    0x80A1, 0x6545, 0x987E, 0x56FA, 0x96C7, 0x522E, 0x74DC, 0x5250, 0x5BE1, 0x6302, 0x8902, 0x4E56,
+    0x62D0, 0x602A, 0x68FA, 0x5173, 0x5B98, 0x51A0, 0x89C2, 0x7BA1, 0x9986, 0x7F50, 0x60EF, 0x704C,
+    0x8D2F, 0x5149, 0x5E7F, 0x901B, 0x7470, 0x89C4, 0x572D, 0x7845, 0x5F52, 0x9F9F, 0x95FA, 0x8F68,
+    0x9B3C, 0x8BE1, 0x7678, 0x6842, 0x67DC, 0x8DEA, 0x8D35, 0x523D, 0x8F8A, 0x6EDA, 0x68CD, 0x9505,
+    0x90ED, 0x56FD, 0x679C, 0x88F9, 0x8FC7, 0x54C8, 0x7BC5, 0x7BC8, 0x7BC9, 0x7BCA, 0x7BCB, 0x7BCD,
+    0x7BCE, 0x7BCF, 0x7BD0, 0x7BD2, 0x7BD4, 0x7BD5, 0x7BD6, 0x7BD7, 0x7BD8, 0x7BDB, 0x7BDC, 0x7BDE,
+    0x7BDF, 0x7BE0, 0x7BE2, 0x7BE3, 0x7BE4, 0x7BE7, 0x7BE8, 0x7BE9, 0x7BEB, 0x7BEC, 0x7BED, 0x7BEF,
+    0x7BF0, 0x7BF2, 0x7BF3, 0x7BF4, 0x7BF5, 0x7BF6, 0x7BF8, 0x7BF9, 0x7BFA, 0x7BFB, 0x7BFD, 0x7BFF,
+    0x7C00, 0x7C01, 0x7C02, 0x7C03, 0x7C04, 0x7C05, 0x7C06, 0x7C08, 0x7C09, 0x7C0A, 0x7C0D, 0x7C0E,
+    0x7C10, 0x7C11, 0x7C12, 0x7C13, 0x7C14, 0x7C15, 0x7C17, 0x7C18, 0x7C19, 0x7C1A, 0x7C1B, 0x7C1C,
+    0x7C1D, 0x7C1E, 0x7C20, 0x7C21, 0x7C22, 0x7C23, 0x7C24, 0x7C25, 0x7C28, 0x7C29, 0x7C2B, 0x7C2C,
+    0x7C2D, 0x7C2E, 0x7C2F, 0x7C30, 0x7C31, 0x7C32, 0x7C33, 0x7C34, 0x7C35, 0x7C36, 0x7C37, 0x7C39,
+    0x7C3A, 0x7C3B, 0x7C3C, 0x7C3D, 0x7C3E, 0x7C42, 0x9AB8, 0x5B69, 0x6D77, 0x6C26, 0x4EA5, 0x5BB3,
+    0x9A87, 0x9163, 0x61A8, 0x90AF, 0x97E9, 0x542B, 0x6DB5, 0x5BD2, 0x51FD, 0x558A, 0x7F55, 0x7FF0,
+    0x64BC, 0x634D, 0x65F1, 0x61BE, 0x608D, 0x710A, 0x6C57, 0x6C49, 0x592F, 0x676D, 0x822A, 0x58D5,
+    0x568E, 0x8C6A, 0x6BEB, 0x90DD, 0x597D, 0x8017, 0x53F7, 0x6D69, 0x5475, 0x559D, 0x8377, 0x83CF,
+    0x6838, 0x79BE, 0x548C, 0x4F55, 0x5408, 0x76D2, 0x8C89, 0x9602, 0x6CB3, 0x6DB8, 0x8D6B, 0x8910,
+    0x9E64, 0x8D3A, 0x563F, 0x9ED1, 0x75D5, 0x5F88, 0x72E0, 0x6068, 0x54FC, 0x4EA8, 0x6A2A, 0x8861,
+    0x6052, 0x8F70, 0x54C4, 0x70D8, 0x8679, 0x9E3F, 0x6D2A, 0x5B8F, 0x5F18, 0x7EA2, 0x5589, 0x4FAF,
+    0x7334, 0x543C, 0x539A, 0x5019, 0x540E, 0x547C, 0x4E4E, 0x5FFD, 0x745A, 0x58F6, 0x846B, 0x80E1,
+    0x8774, 0x72D0, 0x7CCA, 0x6E56, 0x7C43, 0x7C44, 0x7C45, 0x7C46, 0x7C47, 0x7C48, 0x7C49, 0x7C4A,
+    0x7C4B, 0x7C4C, 0x7C4E, 0x7C4F, 0x7C50, 0x7C51, 0x7C52, 0x7C53, 0x7C54, 0x7C55, 0x7C56, 0x7C57,
+    0x7C58, 0x7C59, 0x7C5A, 0x7C5B, 0x7C5C, 0x7C5D, 0x7C5E, 0x7C5F, 0x7C60, 0x7C61, 0x7C62, 0x7C63,
+    0x7C64, 0x7C65, 0x7C66, 0x7C67, 0x7C68, 0x7C69, 0x7C6A, 0x7C6B, 0x7C6C, 0x7C6D, 0x7C6E, 0x7C6F,
+    0x7C70, 0x7C71, 0x7C72, 0x7C75, 0x7C76, 0x7C77, 0x7C78, 0x7C79, 0x7C7A, 0x7C7E, 0x7C7F, 0x7C80,
+    0x7C81, 0x7C82, 0x7C83, 0x7C84, 0x7C85, 0x7C86, 0x7C87, 0x7C88, 0x7C8A, 0x7C8B, 0x7C8C, 0x7C8D,
+    0x7C8E, 0x7C8F, 0x7C90, 0x7C93, 0x7C94, 0x7C96, 0x7C99, 0x7C9A, 0x7C9B, 0x7CA0, 0x7CA1, 0x7CA3,
+    0x7CA6, 0x7CA7, 0x7CA8, 0x7CA9, 0x7CAB, 0x7CAC, 0x7CAD, 0x7CAF, 0x7CB0, 0x7CB4, 0x7CB5, 0x7CB6,
+    0x7CB7, 0x7CB8, 0x7CBA, 0x7CBB, 0x5F27, 0x864E, 0x552C, 0x62A4, 0x4E92, 0x6CAA, 0x6237, 0x82B1,
+    0x54D7, 0x534E, 0x733E, 0x6ED1, 0x753B, 0x5212, 0x5316, 0x8BDD, 0x69D0, 0x5F8A, 0x6000, 0x6DEE,
+    0x574F, 0x6B22, 0x73AF, 0x6853, 0x8FD8, 0x7F13, 0x6362, 0x60A3, 0x5524, 0x75EA, 0x8C62, 0x7115,
+    0x6DA3, 0x5BA6, 0x5E7B, 0x8352, 0x614C, 0x9EC4, 0x78FA, 0x8757, 0x7C27, 0x7687, 0x51F0, 0x60F6,
+    0x714C, 0x6643, 0x5E4C, 0x604D, 0x8C0E, 0x7070, 0x6325, 0x8F89, 0x5FBD, 0x6062, 0x86D4, 0x56DE,
+    0x6BC1, 0x6094, 0x6167, 0x5349, 0x60E0, 0x6666, 0x8D3F, 0x79FD, 0x4F1A, 0x70E9, 0x6C47, 0x8BB3,
+    0x8BF2, 0x7ED8, 0x8364, 0x660F, 0x5A5A, 0x9B42, 0x6D51, 0x6DF7, 0x8C41, 0x6D3B, 0x4F19, 0x706B,
+    0x83B7, 0x6216, 0x60D1, 0x970D, 0x8D27, 0x7978, 0x51FB, 0x573E, 0x57FA, 0x673A, 0x7578, 0x7A3D,
+    0x79EF, 0x7B95, 0x7CBF, 0x7CC0, 0x7CC2, 0x7CC3, 0x7CC4, 0x7CC6, 0x7CC9, 0x7CCB, 0x7CCE, 0x7CCF,
+    0x7CD0, 0x7CD1, 0x7CD2, 0x7CD3, 0x7CD4, 0x7CD8, 0x7CDA, 0x7CDB, 0x7CDD, 0x7CDE, 0x7CE1, 0x7CE2,
+    0x7CE3, 0x7CE4, 0x7CE5, 0x7CE6, 0x7CE7, 0x7CE9, 0x7CEA, 0x7CEB, 0x7CEC, 0x7CED, 0x7CEE, 0x7CF0,
+    0x7CF1, 0x7CF2, 0x7CF3, 0x7CF4, 0x7CF5, 0x7CF6, 0x7CF7, 0x7CF9, 0x7CFA, 0x7CFC, 0x7CFD, 0x7CFE,
+    0x7CFF, 0x7D00, 0x7D01, 0x7D02, 0x7D03, 0x7D04, 0x7D05, 0x7D06, 0x7D07, 0x7D08, 0x7D09, 0x7D0B,
+    0x7D0C, 0x7D0D, 0x7D0E, 0x7D0F, 0x7D10, 0x7D11, 0x7D12, 0x7D13, 0x7D14, 0x7D15, 0x7D16, 0x7D17,
+    0x7D18, 0x7D19, 0x7D1A, 0x7D1B, 0x7D1C, 0x7D1D, 0x7D1E, 0x7D1F, 0x7D21, 0x7D23, 0x7D24, 0x7D25,
+    0x7D26, 0x7D28, 0x7D29, 0x7D2A, 0x7D2C, 0x7D2D, 0x7D2E, 0x7D30, 0x7D31, 0x7D32, 0x7D33, 0x7D34,
+    0x7D35, 0x7D36, 0x808C, 0x9965, 0x8FF9, 0x6FC0, 0x8BA5, 0x9E21, 0x59EC, 0x7EE9, 0x7F09, 0x5409,
+    0x6781, 0x68D8, 0x8F91, 0x7C4D, 0x96C6, 0x53CA, 0x6025, 0x75BE, 0x6C72, 0x5373, 0x5AC9, 0x7EA7,
+    0x6324, 0x51E0, 0x810A, 0x5DF1, 0x84DF, 0x6280, 0x5180, 0x5B63, 0x4F0E, 0x796D, 0x5242, 0x60B8,
+    0x6D4E, 0x5BC4, 0x5BC2, 0x8BA1, 0x8BB0, 0x65E2, 0x5FCC, 0x9645, 0x5993, 0x7EE7, 0x7EAA, 0x5609,
+    0x67B7, 0x5939, 0x4F73, 0x5BB6, 0x52A0, 0x835A, 0x988A, 0x8D3E, 0x7532, 0x94BE, 0x5047, 0x7A3C,
+    0x4EF7, 0x67B6, 0x9A7E, 0x5AC1, 0x6B7C, 0x76D1, 0x575A, 0x5C16, 0x7B3A, 0x95F4, 0x714E, 0x517C,
+    0x80A9, 0x8270, 0x5978, 0x7F04, 0x8327, 0x68C0, 0x67EC, 0x78B1, 0x7877, 0x62E3, 0x6361, 0x7B80,
+    0x4FED, 0x526A, 0x51CF, 0x8350, 0x69DB, 0x9274, 0x8DF5, 0x8D31, 0x89C1, 0x952E, 0x7BAD, 0x4EF6,
+    0x7D37, 0x7D38, 0x7D39, 0x7D3A, 0x7D3B, 0x7D3C, 0x7D3D, 0x7D3E, 0x7D3F, 0x7D40, 0x7D41, 0x7D42,
+    0x7D43, 0x7D44, 0x7D45, 0x7D46, 0x7D47, 0x7D48, 0x7D49, 0x7D4A, 0x7D4B, 0x7D4C, 0x7D4D, 0x7D4E,
+    0x7D4F, 0x7D50, 0x7D51, 0x7D52, 0x7D53, 0x7D54, 0x7D55, 0x7D56, 0x7D57, 0x7D58, 0x7D59, 0x7D5A,
+    0x7D5B, 0x7D5C, 0x7D5D, 0x7D5E, 0x7D5F, 0x7D60, 0x7D61, 0x7D62, 0x7D63, 0x7D64, 0x7D65, 0x7D66,
+    0x7D67, 0x7D68, 0x7D69, 0x7D6A, 0x7D6B, 0x7D6C, 0x7D6D, 0x7D6F, 0x7D70, 0x7D71, 0x7D72, 0x7D73,
+    0x7D74, 0x7D75, 0x7D76, 0x7D78, 0x7D79, 0x7D7A, 0x7D7B, 0x7D7C, 0x7D7D, 0x7D7E, 0x7D7F, 0x7D80,
+    0x7D81, 0x7D82, 0x7D83, 0x7D84, 0x7D85, 0x7D86, 0x7D87, 0x7D88, 0x7D89, 0x7D8A, 0x7D8B, 0x7D8C,
+    0x7D8D, 0x7D8E, 0x7D8F, 0x7D90, 0x7D91, 0x7D92, 0x7D93, 0x7D94, 0x7D95, 0x7D96, 0x7D97, 0x7D98,
+    0x5065, 0x8230, 0x5251, 0x996F, 0x6E10, 0x6E85, 0x6DA7, 0x5EFA, 0x50F5, 0x59DC, 0x5C06, 0x6D46,
+    0x6C5F, 0x7586, 0x848B, 0x6868, 0x5956, 0x8BB2, 0x5320, 0x9171, 0x964D, 0x8549, 0x6912, 0x7901,
+    0x7126, 0x80F6, 0x4EA4, 0x90CA, 0x6D47, 0x9A84, 0x5A07, 0x56BC, 0x6405, 0x94F0, 0x77EB, 0x4FA5,
+    0x811A, 0x72E1, 0x89D2, 0x997A, 0x7F34, 0x7EDE, 0x527F, 0x6559, 0x9175, 0x8F7F, 0x8F83, 0x53EB,
+    0x7A96, 0x63ED, 0x63A5, 0x7686, 0x79F8, 0x8857, 0x9636, 0x622A, 0x52AB, 0x8282, 0x6854, 0x6770,
+    0x6377, 0x776B, 0x7AED, 0x6D01, 0x7ED3, 0x89E3, 0x59D0, 0x6212, 0x85C9, 0x82A5, 0x754C, 0x501F,
+    0x4ECB, 0x75A5, 0x8BEB, 0x5C4A, 0x5DFE, 0x7B4B, 0x65A4, 0x91D1, 0x4ECA, 0x6D25, 0x895F, 0x7D27,
+    0x9526, 0x4EC5, 0x8C28, 0x8FDB, 0x9773, 0x664B, 0x7981, 0x8FD1, 0x70EC, 0x6D78, 0x7D99, 0x7D9A,
+    0x7D9B, 0x7D9C, 0x7D9D, 0x7D9E, 0x7D9F, 0x7DA0, 0x7DA1, 0x7DA2, 0x7DA3, 0x7DA4, 0x7DA5, 0x7DA7,
+    0x7DA8, 0x7DA9, 0x7DAA, 0x7DAB, 0x7DAC, 0x7DAD, 0x7DAF, 0x7DB0, 0x7DB1, 0x7DB2, 0x7DB3, 0x7DB4,
+    0x7DB5, 0x7DB6, 0x7DB7, 0x7DB8, 0x7DB9, 0x7DBA, 0x7DBB, 0x7DBC, 0x7DBD, 0x7DBE, 0x7DBF, 0x7DC0,
+    0x7DC1, 0x7DC2, 0x7DC3, 0x7DC4, 0x7DC5, 0x7DC6, 0x7DC7, 0x7DC8, 0x7DC9, 0x7DCA, 0x7DCB, 0x7DCC,
+    0x7DCD, 0x7DCE, 0x7DCF, 0x7DD0, 0x7DD1, 0x7DD2, 0x7DD3, 0x7DD4, 0x7DD5, 0x7DD6, 0x7DD7, 0x7DD8,
+    0x7DD9, 0x7DDA, 0x7DDB, 0x7DDC, 0x7DDD, 0x7DDE, 0x7DDF, 0x7DE0, 0x7DE1, 0x7DE2, 0x7DE3, 0x7DE4,
+    0x7DE5, 0x7DE6, 0x7DE7, 0x7DE8, 0x7DE9, 0x7DEA, 0x7DEB, 0x7DEC, 0x7DED, 0x7DEE, 0x7DEF, 0x7DF0,
+    0x7DF1, 0x7DF2, 0x7DF3, 0x7DF4, 0x7DF5, 0x7DF6, 0x7DF7, 0x7DF8, 0x7DF9, 0x7DFA, 0x5C3D, 0x52B2,
+    0x8346, 0x5162, 0x830E, 0x775B, 0x6676, 0x9CB8, 0x4EAC, 0x60CA, 0x7CBE, 0x7CB3, 0x7ECF, 0x4E95,
+    0x8B66, 0x666F, 0x9888, 0x9759, 0x5883, 0x656C, 0x955C, 0x5F84, 0x75C9, 0x9756, 0x7ADF, 0x7ADE,
+    0x51C0, 0x70AF, 0x7A98, 0x63EA, 0x7A76, 0x7EA0, 0x7396, 0x97ED, 0x4E45, 0x7078, 0x4E5D, 0x9152,
+    0x53A9, 0x6551, 0x65E7, 0x81FC, 0x8205, 0x548E, 0x5C31, 0x759A, 0x97A0, 0x62D8, 0x72D9, 0x75BD,
+    0x5C45, 0x9A79, 0x83CA, 0x5C40, 0x5480, 0x77E9, 0x4E3E, 0x6CAE, 0x805A, 0x62D2, 0x636E, 0x5DE8,
+    0x5177, 0x8DDD, 0x8E1E, 0x952F, 0x4FF1, 0x53E5, 0x60E7, 0x70AC, 0x5267, 0x6350, 0x9E43, 0x5A1F,
+    0x5026, 0x7737, 0x5377, 0x7EE2, 0x6485, 0x652B, 0x6289, 0x6398, 0x5014, 0x7235, 0x89C9, 0x51B3,
+    0x8BC0, 0x7EDD, 0x5747, 0x83CC, 0x94A7, 0x519B, 0x541B, 0x5CFB, 0x7DFB, 0x7DFC, 0x7DFD, 0x7DFE,
+    0x7DFF, 0x7E00, 0x7E01, 0x7E02, 0x7E03, 0x7E04, 0x7E05, 0x7E06, 0x7E07, 0x7E08, 0x7E09, 0x7E0A,
+    0x7E0B, 0x7E0C, 0x7E0D, 0x7E0E, 0x7E0F, 0x7E10, 0x7E11, 0x7E12, 0x7E13, 0x7E14, 0x7E15, 0x7E16,
+    0x7E17, 0x7E18, 0x7E19, 0x7E1A, 0x7E1B, 0x7E1C, 0x7E1D, 0x7E1E, 0x7E1F, 0x7E20, 0x7E21, 0x7E22,
+    0x7E23, 0x7E24, 0x7E25, 0x7E26, 0x7E27, 0x7E28, 0x7E29, 0x7E2A, 0x7E2B, 0x7E2C, 0x7E2D, 0x7E2E,
+    0x7E2F, 0x7E30, 0x7E31, 0x7E32, 0x7E33, 0x7E34, 0x7E35, 0x7E36, 0x7E37, 0x7E38, 0x7E39, 0x7E3A,
+    0x7E3C, 0x7E3D, 0x7E3E, 0x7E3F, 0x7E40, 0x7E42, 0x7E43, 0x7E44, 0x7E45, 0x7E46, 0x7E48, 0x7E49,
+    0x7E4A, 0x7E4B, 0x7E4C, 0x7E4D, 0x7E4E, 0x7E4F, 0x7E50, 0x7E51, 0x7E52, 0x7E53, 0x7E54, 0x7E55,
+    0x7E56, 0x7E57, 0x7E58, 0x7E59, 0x7E5A, 0x7E5B, 0x7E5C, 0x7E5D, 0x4FCA, 0x7AE3, 0x6D5A, 0x90E1,
+    0x9A8F, 0x5580, 0x5496, 0x5361, 0x54AF, 0x5F00, 0x63E9, 0x6977, 0x51EF, 0x6168, 0x520A, 0x582A,
+    0x52D8, 0x574E, 0x780D, 0x770B, 0x5EB7, 0x6177, 0x7CE0, 0x625B, 0x6297, 0x4EA2, 0x7095, 0x8003,
+    0x62F7, 0x70E4, 0x9760, 0x5777, 0x82DB, 0x67EF, 0x68F5, 0x78D5, 0x9897, 0x79D1, 0x58F3, 0x54B3,
+    0x53EF, 0x6E34, 0x514B, 0x523B, 0x5BA2, 0x8BFE, 0x80AF, 0x5543, 0x57A6, 0x6073, 0x5751, 0x542D,
+    0x7A7A, 0x6050, 0x5B54, 0x63A7, 0x62A0, 0x53E3, 0x6263, 0x5BC7, 0x67AF, 0x54ED, 0x7A9F, 0x82E6,
+    0x9177, 0x5E93, 0x88E4, 0x5938, 0x57AE, 0x630E, 0x8DE8, 0x80EF, 0x5757, 0x7B77, 0x4FA9, 0x5FEB,
+    0x5BBD, 0x6B3E, 0x5321, 0x7B50, 0x72C2, 0x6846, 0x77FF, 0x7736, 0x65F7, 0x51B5, 0x4E8F, 0x76D4,
+    0x5CBF, 0x7AA5, 0x8475, 0x594E, 0x9B41, 0x5080, 0x7E5E, 0x7E5F, 0x7E60, 0x7E61, 0x7E62, 0x7E63,
+    0x7E64, 0x7E65, 0x7E66, 0x7E67, 0x7E68, 0x7E69, 0x7E6A, 0x7E6B, 0x7E6C, 0x7E6D, 0x7E6E, 0x7E6F,
+    0x7E70, 0x7E71, 0x7E72, 0x7E73, 0x7E74, 0x7E75, 0x7E76, 0x7E77, 0x7E78, 0x7E79, 0x7E7A, 0x7E7B,
+    0x7E7C, 0x7E7D, 0x7E7E, 0x7E7F, 0x7E80, 0x7E81, 0x7E83, 0x7E84, 0x7E85, 0x7E86, 0x7E87, 0x7E88,
+    0x7E89, 0x7E8A, 0x7E8B, 0x7E8C, 0x7E8D, 0x7E8E, 0x7E8F, 0x7E90, 0x7E91, 0x7E92, 0x7E93, 0x7E94,
+    0x7E95, 0x7E96, 0x7E97, 0x7E98, 0x7E99, 0x7E9A, 0x7E9C, 0x7E9D, 0x7E9E, 0x7EAE, 0x7EB4, 0x7EBB,
+    0x7EBC, 0x7ED6, 0x7EE4, 0x7EEC, 0x7EF9, 0x7F0A, 0x7F10, 0x7F1E, 0x7F37, 0x7F39, 0x7F3B, 0x7F3C,
+    0x7F3D, 0x7F3E, 0x7F3F, 0x7F40, 0x7F41, 0x7F43, 0x7F46, 0x7F47, 0x7F48, 0x7F49, 0x7F4A, 0x7F4B,
+    0x7F4C, 0x7F4D, 0x7F4E, 0x7F4F, 0x7F52, 0x7F53, 0x9988, 0x6127, 0x6E83, 0x5764, 0x6606, 0x6346,
+    0x56F0, 0x62EC, 0x6269, 0x5ED3, 0x9614, 0x5783, 0x62C9, 0x5587, 0x8721, 0x814A, 0x8FA3, 0x5566,
+    0x83B1, 0x6765, 0x8D56, 0x84DD, 0x5A6A, 0x680F, 0x62E6, 0x7BEE, 0x9611, 0x5170, 0x6F9C, 0x8C30,
+    0x63FD, 0x89C8, 0x61D2, 0x7F06, 0x70C2, 0x6EE5, 0x7405, 0x6994, 0x72FC, 0x5ECA, 0x90CE, 0x6717,
+    0x6D6A, 0x635E, 0x52B3, 0x7262, 0x8001, 0x4F6C, 0x59E5, 0x916A, 0x70D9, 0x6D9D, 0x52D2, 0x4E50,
+    0x96F7, 0x956D, 0x857E, 0x78CA, 0x7D2F, 0x5121, 0x5792, 0x64C2, 0x808B, 0x7C7B, 0x6CEA, 0x68F1,
+    0x695E, 0x51B7, 0x5398, 0x68A8, 0x7281, 0x9ECE, 0x7BF1, 0x72F8, 0x79BB, 0x6F13, 0x7406, 0x674E,
+    0x91CC, 0x9CA4, 0x793C, 0x8389, 0x8354, 0x540F, 0x6817, 0x4E3D, 0x5389, 0x52B1, 0x783E, 0x5386,
+    0x5229, 0x5088, 0x4F8B, 0x4FD0, 0x7F56, 0x7F59, 0x7F5B, 0x7F5C, 0x7F5D, 0x7F5E, 0x7F60, 0x7F63,
+    0x7F64, 0x7F65, 0x7F66, 0x7F67, 0x7F6B, 0x7F6C, 0x7F6D, 0x7F6F, 0x7F70, 0x7F73, 0x7F75, 0x7F76,
+    0x7F77, 0x7F78, 0x7F7A, 0x7F7B, 0x7F7C, 0x7F7D, 0x7F7F, 0x7F80, 0x7F82, 0x7F83, 0x7F84, 0x7F85,
+    0x7F86, 0x7F87, 0x7F88, 0x7F89, 0x7F8B, 0x7F8D, 0x7F8F, 0x7F90, 0x7F91, 0x7F92, 0x7F93, 0x7F95,
+    0x7F96, 0x7F97, 0x7F98, 0x7F99, 0x7F9B, 0x7F9C, 0x7FA0, 0x7FA2, 0x7FA3, 0x7FA5, 0x7FA6, 0x7FA8,
+    0x7FA9, 0x7FAA, 0x7FAB, 0x7FAC, 0x7FAD, 0x7FAE, 0x7FB1, 0x7FB3, 0x7FB4, 0x7FB5, 0x7FB6, 0x7FB7,
+    0x7FBA, 0x7FBB, 0x7FBE, 0x7FC0, 0x7FC2, 0x7FC3, 0x7FC4, 0x7FC6, 0x7FC7, 0x7FC8, 0x7FC9, 0x7FCB,
+    0x7FCD, 0x7FCF, 0x7FD0, 0x7FD1, 0x7FD2, 0x7FD3, 0x7FD6, 0x7FD7, 0x7FD9, 0x7FDA, 0x7FDB, 0x7FDC,
+    0x7FDD, 0x7FDE, 0x7FE2, 0x7FE3, 0x75E2, 0x7ACB, 0x7C92, 0x6CA5, 0x96B6, 0x529B, 0x7483, 0x54E9,
+    0x4FE9, 0x8054, 0x83B2, 0x8FDE, 0x9570, 0x5EC9, 0x601C, 0x6D9F, 0x5E18, 0x655B, 0x8138, 0x94FE,
+    0x604B, 0x70BC, 0x7EC3, 0x7CAE, 0x51C9, 0x6881, 0x7CB1, 0x826F, 0x4E24, 0x8F86, 0x91CF, 0x667E,
+    0x4EAE, 0x8C05, 0x64A9, 0x804A, 0x50DA, 0x7597, 0x71CE, 0x5BE5, 0x8FBD, 0x6F66, 0x4E86, 0x6482,
+    0x9563, 0x5ED6, 0x6599, 0x5217, 0x88C2, 0x70C8, 0x52A3, 0x730E, 0x7433, 0x6797, 0x78F7, 0x9716,
+    0x4E34, 0x90BB, 0x9CDE, 0x6DCB, 0x51DB, 0x8D41, 0x541D, 0x62CE, 0x73B2, 0x83F1, 0x96F6, 0x9F84,
+    0x94C3, 0x4F36, 0x7F9A, 0x51CC, 0x7075, 0x9675, 0x5CAD, 0x9886, 0x53E6, 0x4EE4, 0x6E9C, 0x7409,
+    0x69B4, 0x786B, 0x998F, 0x7559, 0x5218, 0x7624, 0x6D41, 0x67F3, 0x516D, 0x9F99, 0x804B, 0x5499,
+    0x7B3C, 0x7ABF, 0x7FE4, 0x7FE7, 0x7FE8, 0x7FEA, 0x7FEB, 0x7FEC, 0x7FED, 0x7FEF, 0x7FF2, 0x7FF4,
+    0x7FF5, 0x7FF6, 0x7FF7, 0x7FF8, 0x7FF9, 0x7FFA, 0x7FFD, 0x7FFE, 0x7FFF, 0x8002, 0x8007, 0x8008,
+    0x8009, 0x800A, 0x800E, 0x800F, 0x8011, 0x8013, 0x801A, 0x801B, 0x801D, 0x801E, 0x801F, 0x8021,
+    0x8023, 0x8024, 0x802B, 0x802C, 0x802D, 0x802E, 0x802F, 0x8030, 0x8032, 0x8034, 0x8039, 0x803A,
+    0x803C, 0x803E, 0x8040, 0x8041, 0x8044, 0x8045, 0x8047, 0x8048, 0x8049, 0x804E, 0x804F, 0x8050,
+    0x8051, 0x8053, 0x8055, 0x8056, 0x8057, 0x8059, 0x805B, 0x805C, 0x805D, 0x805E, 0x805F, 0x8060,
+    0x8061, 0x8062, 0x8063, 0x8064, 0x8065, 0x8066, 0x8067, 0x8068, 0x806B, 0x806C, 0x806D, 0x806E,
+    0x806F, 0x8070, 0x8072, 0x8073, 0x8074, 0x8075, 0x8076, 0x8077, 0x8078, 0x8079, 0x807A, 0x807B,
+    0x807C, 0x807D, 0x9686, 0x5784, 0x62E2, 0x9647, 0x697C, 0x5A04, 0x6402, 0x7BD3, 0x6F0F, 0x964B,
+    0x82A6, 0x5362, 0x9885, 0x5E90, 0x7089, 0x63B3, 0x5364, 0x864F, 0x9C81, 0x9E93, 0x788C, 0x9732,
+    0x8DEF, 0x8D42, 0x9E7F, 0x6F5E, 0x7984, 0x5F55, 0x9646, 0x622E, 0x9A74, 0x5415, 0x94DD, 0x4FA3,
+    0x65C5, 0x5C65, 0x5C61, 0x7F15, 0x8651, 0x6C2F, 0x5F8B, 0x7387, 0x6EE4, 0x7EFF, 0x5CE6, 0x631B,
+    0x5B6A, 0x6EE6, 0x5375, 0x4E71, 0x63A0, 0x7565, 0x62A1, 0x8F6E, 0x4F26, 0x4ED1, 0x6CA6, 0x7EB6,
+    0x8BBA, 0x841D, 0x87BA, 0x7F57, 0x903B, 0x9523, 0x7BA9, 0x9AA1, 0x88F8, 0x843D, 0x6D1B, 0x9A86,
+    0x7EDC, 0x5988, 0x9EBB, 0x739B, 0x7801, 0x8682, 0x9A6C, 0x9A82, 0x561B, 0x5417, 0x57CB, 0x4E70,
+    0x9EA6, 0x5356, 0x8FC8, 0x8109, 0x7792, 0x9992, 0x86EE, 0x6EE1, 0x8513, 0x66FC, 0x6162, 0x6F2B,
+    0x807E, 0x8081, 0x8082, 0x8085, 0x8088, 0x808A, 0x808D, 0x808E, 0x808F, 0x8090, 0x8091, 0x8092,
+    0x8094, 0x8095, 0x8097, 0x8099, 0x809E, 0x80A3, 0x80A6, 0x80A7, 0x80A8, 0x80AC, 0x80B0, 0x80B3,
+    0x80B5, 0x80B6, 0x80B8, 0x80B9, 0x80BB, 0x80C5, 0x80C7, 0x80C8, 0x80C9, 0x80CA, 0x80CB, 0x80CF,
+    0x80D0, 0x80D1, 0x80D2, 0x80D3, 0x80D4, 0x80D5, 0x80D8, 0x80DF, 0x80E0, 0x80E2, 0x80E3, 0x80E6,
+    0x80EE, 0x80F5, 0x80F7, 0x80F9, 0x80FB, 0x80FE, 0x80FF, 0x8100, 0x8101, 0x8103, 0x8104, 0x8105,
+    0x8107, 0x8108, 0x810B, 0x810C, 0x8115, 0x8117, 0x8119, 0x811B, 0x811C, 0x811D, 0x811F, 0x8120,
+    0x8121, 0x8122, 0x8123, 0x8124, 0x8125, 0x8126, 0x8127, 0x8128, 0x8129, 0x812A, 0x812B, 0x812D,
+    0x812E, 0x8130, 0x8133, 0x8134, 0x8135, 0x8137, 0x8139, 0x813A, 0x813B, 0x813C, 0x813D, 0x813F,
+    0x8C29, 0x8292, 0x832B, 0x76F2, 0x6C13, 0x5FD9, 0x83BD, 0x732B, 0x8305, 0x951A, 0x6BDB, 0x77DB,
+    0x94C6, 0x536F, 0x8302, 0x5192, 0x5E3D, 0x8C8C, 0x8D38, 0x4E48, 0x73AB, 0x679A, 0x6885, 0x9176,
+    0x9709, 0x7164, 0x6CA1, 0x7709, 0x5A92, 0x9541, 0x6BCF, 0x7F8E, 0x6627, 0x5BD0, 0x59B9, 0x5A9A,
+    0x95E8, 0x95F7, 0x4EEC, 0x840C, 0x8499, 0x6AAC, 0x76DF, 0x9530, 0x731B, 0x68A6, 0x5B5F, 0x772F,
+    0x919A, 0x9761, 0x7CDC, 0x8FF7, 0x8C1C, 0x5F25, 0x7C73, 0x79D8, 0x89C5, 0x6CCC, 0x871C, 0x5BC6,
+    0x5E42, 0x68C9, 0x7720, 0x7EF5, 0x5195, 0x514D, 0x52C9, 0x5A29, 0x7F05, 0x9762, 0x82D7, 0x63CF,
+    0x7784, 0x85D0, 0x79D2, 0x6E3A, 0x5E99, 0x5999, 0x8511, 0x706D, 0x6C11, 0x62BF, 0x76BF, 0x654F,
+    0x60AF, 0x95FD, 0x660E, 0x879F, 0x9E23, 0x94ED, 0x540D, 0x547D, 0x8C2C, 0x6478, 0x8140, 0x8141,
+    0x8142, 0x8143, 0x8144, 0x8145, 0x8147, 0x8149, 0x814D, 0x814E, 0x814F, 0x8152, 0x8156, 0x8157,
+    0x8158, 0x815B, 0x815C, 0x815D, 0x815E, 0x815F, 0x8161, 0x8162, 0x8163, 0x8164, 0x8166, 0x8168,
+    0x816A, 0x816B, 0x816C, 0x816F, 0x8172, 0x8173, 0x8175, 0x8176, 0x8177, 0x8178, 0x8181, 0x8183,
+    0x8184, 0x8185, 0x8186, 0x8187, 0x8189, 0x818B, 0x818C, 0x818D, 0x818E, 0x8190, 0x8192, 0x8193,
+    0x8194, 0x8195, 0x8196, 0x8197, 0x8199, 0x819A, 0x819E, 0x819F, 0x81A0, 0x81A1, 0x81A2, 0x81A4,
+    0x81A5, 0x81A7, 0x81A9, 0x81AB, 0x81AC, 0x81AD, 0x81AE, 0x81AF, 0x81B0, 0x81B1, 0x81B2, 0x81B4,
+    0x81B5, 0x81B6, 0x81B7, 0x81B8, 0x81B9, 0x81BC, 0x81BD, 0x81BE, 0x81BF, 0x81C4, 0x81C5, 0x81C7,
+    0x81C8, 0x81C9, 0x81CB, 0x81CD, 0x81CE, 0x81CF, 0x81D0, 0x81D1, 0x81D2, 0x81D3, 0x6479, 0x8611,
+    0x6A21, 0x819C, 0x78E8, 0x6469, 0x9B54, 0x62B9, 0x672B, 0x83AB, 0x58A8, 0x9ED8, 0x6CAB, 0x6F20,
+    0x5BDE, 0x964C, 0x8C0B, 0x725F, 0x67D0, 0x62C7, 0x7261, 0x4EA9, 0x59C6, 0x6BCD, 0x5893, 0x66AE,
+    0x5E55, 0x52DF, 0x6155, 0x6728, 0x76EE, 0x7766, 0x7267, 0x7A46, 0x62FF, 0x54EA, 0x5450, 0x94A0,
+    0x90A3, 0x5A1C, 0x7EB3, 0x6C16, 0x4E43, 0x5976, 0x8010, 0x5948, 0x5357, 0x7537, 0x96BE, 0x56CA,
+    0x6320, 0x8111, 0x607C, 0x95F9, 0x6DD6, 0x5462, 0x9981, 0x5185, 0x5AE9, 0x80FD, 0x59AE, 0x9713,
+    0x502A, 0x6CE5, 0x5C3C, 0x62DF, 0x4F60, 0x533F, 0x817B, 0x9006, 0x6EBA, 0x852B, 0x62C8, 0x5E74,
+    0x78BE, 0x64B5, 0x637B, 0x5FF5, 0x5A18, 0x917F, 0x9E1F, 0x5C3F, 0x634F, 0x8042, 0x5B7D, 0x556E,
+    0x954A, 0x954D, 0x6D85, 0x60A8, 0x67E0, 0x72DE, 0x51DD, 0x5B81, 0x81D4, 0x81D5, 0x81D6, 0x81D7,
+    0x81D8, 0x81D9, 0x81DA, 0x81DB, 0x81DC, 0x81DD, 0x81DE, 0x81DF, 0x81E0, 0x81E1, 0x81E2, 0x81E4,
+    0x81E5, 0x81E6, 0x81E8, 0x81E9, 0x81EB, 0x81EE, 0x81EF, 0x81F0, 0x81F1, 0x81F2, 0x81F5, 0x81F6,
+    0x81F7, 0x81F8, 0x81F9, 0x81FA, 0x81FD, 0x81FF, 0x8203, 0x8207, 0x8208, 0x8209, 0x820A, 0x820B,
+    0x820E, 0x820F, 0x8211, 0x8213, 0x8215, 0x8216, 0x8217, 0x8218, 0x8219, 0x821A, 0x821D, 0x8220,
+    0x8224, 0x8225, 0x8226, 0x8227, 0x8229, 0x822E, 0x8232, 0x823A, 0x823C, 0x823D, 0x823F, 0x8240,
+    0x8241, 0x8242, 0x8243, 0x8245, 0x8246, 0x8248, 0x824A, 0x824C, 0x824D, 0x824E, 0x8250, 0x8251,
+    0x8252, 0x8253, 0x8254, 0x8255, 0x8256, 0x8257, 0x8259, 0x825B, 0x825C, 0x825D, 0x825E, 0x8260,
+    0x8261, 0x8262, 0x8263, 0x8264, 0x8265, 0x8266, 0x8267, 0x8269, 0x62E7, 0x6CDE, 0x725B, 0x626D,
+    0x94AE, 0x7EBD, 0x8113, 0x6D53, 0x519C, 0x5F04, 0x5974, 0x52AA, 0x6012, 0x5973, 0x6696, 0x8650,
+    0x759F, 0x632A, 0x61E6, 0x7CEF, 0x8BFA, 0x54E6, 0x6B27, 0x9E25, 0x6BB4, 0x85D5, 0x5455, 0x5076,
+    0x6CA4, 0x556A, 0x8DB4, 0x722C, 0x5E15, 0x6015, 0x7436, 0x62CD, 0x6392, 0x724C, 0x5F98, 0x6E43,
+    0x6D3E, 0x6500, 0x6F58, 0x76D8, 0x78D0, 0x76FC, 0x7554, 0x5224, 0x53DB, 0x4E53, 0x5E9E, 0x65C1,
+    0x802A, 0x80D6, 0x629B, 0x5486, 0x5228, 0x70AE, 0x888D, 0x8DD1, 0x6CE1, 0x5478, 0x80DA, 0x57F9,
+    0x88F4, 0x8D54, 0x966A, 0x914D, 0x4F69, 0x6C9B, 0x55B7, 0x76C6, 0x7830, 0x62A8, 0x70F9, 0x6F8E,
+    0x5F6D, 0x84EC, 0x68DA, 0x787C, 0x7BF7, 0x81A8, 0x670B, 0x9E4F, 0x6367, 0x78B0, 0x576F, 0x7812,
+    0x9739, 0x6279, 0x62AB, 0x5288, 0x7435, 0x6BD7, 0x826A, 0x826B, 0x826C, 0x826D, 0x8271, 0x8275,
+    0x8276, 0x8277, 0x8278, 0x827B, 0x827C, 0x8280, 0x8281, 0x8283, 0x8285, 0x8286, 0x8287, 0x8289,
+    0x828C, 0x8290, 0x8293, 0x8294, 0x8295, 0x8296, 0x829A, 0x829B, 0x829E, 0x82A0, 0x82A2, 0x82A3,
+    0x82A7, 0x82B2, 0x82B5, 0x82B6, 0x82BA, 0x82BB, 0x82BC, 0x82BF, 0x82C0, 0x82C2, 0x82C3, 0x82C5,
+    0x82C6, 0x82C9, 0x82D0, 0x82D6, 0x82D9, 0x82DA, 0x82DD, 0x82E2, 0x82E7, 0x82E8, 0x82E9, 0x82EA,
+    0x82EC, 0x82ED, 0x82EE, 0x82F0, 0x82F2, 0x82F3, 0x82F5, 0x82F6, 0x82F8, 0x82FA, 0x82FC, 0x82FD,
+    0x82FE, 0x82FF, 0x8300, 0x830A, 0x830B, 0x830D, 0x8310, 0x8312, 0x8313, 0x8316, 0x8318, 0x8319,
+    0x831D, 0x831E, 0x831F, 0x8320, 0x8321, 0x8322, 0x8323, 0x8324, 0x8325, 0x8326, 0x8329, 0x832A,
+    0x832E, 0x8330, 0x8332, 0x8337, 0x833B, 0x833D, 0x5564, 0x813E, 0x75B2, 0x76AE, 0x5339, 0x75DE,
+    0x50FB, 0x5C41, 0x8B6C, 0x7BC7, 0x504F, 0x7247, 0x9A97, 0x98D8, 0x6F02, 0x74E2, 0x7968, 0x6487,
+    0x77A5, 0x62FC, 0x9891, 0x8D2B, 0x54C1, 0x8058, 0x4E52, 0x576A, 0x82F9, 0x840D, 0x5E73, 0x51ED,
+    0x74F6, 0x8BC4, 0x5C4F, 0x5761, 0x6CFC, 0x9887, 0x5A46, 0x7834, 0x9B44, 0x8FEB, 0x7C95, 0x5256,
+    0x6251, 0x94FA, 0x4EC6, 0x8386, 0x8461, 0x83E9, 0x84B2, 0x57D4, 0x6734, 0x5703, 0x666E, 0x6D66,
+    0x8C31, 0x66DD, 0x7011, 0x671F, 0x6B3A, 0x6816, 0x621A, 0x59BB, 0x4E03, 0x51C4, 0x6F06, 0x67D2,
+    0x6C8F, 0x5176, 0x68CB, 0x5947, 0x6B67, 0x7566, 0x5D0E, 0x8110, 0x9F50, 0x65D7, 0x7948, 0x7941,
+    0x9A91, 0x8D77, 0x5C82, 0x4E5E, 0x4F01, 0x542F, 0x5951, 0x780C, 0x5668, 0x6C14, 0x8FC4, 0x5F03,
+    0x6C7D, 0x6CE3, 0x8BAB, 0x6390, 0x833E, 0x833F, 0x8341, 0x8342, 0x8344, 0x8345, 0x8348, 0x834A,
+    0x834B, 0x834C, 0x834D, 0x834E, 0x8353, 0x8355, 0x8356, 0x8357, 0x8358, 0x8359, 0x835D, 0x8362,
+    0x8370, 0x8371, 0x8372, 0x8373, 0x8374, 0x8375, 0x8376, 0x8379, 0x837A, 0x837E, 0x837F, 0x8380,
+    0x8381, 0x8382, 0x8383, 0x8384, 0x8387, 0x8388, 0x838A, 0x838B, 0x838C, 0x838D, 0x838F, 0x8390,
+    0x8391, 0x8394, 0x8395, 0x8396, 0x8397, 0x8399, 0x839A, 0x839D, 0x839F, 0x83A1, 0x83A2, 0x83A3,
+    0x83A4, 0x83A5, 0x83A6, 0x83A7, 0x83AC, 0x83AD, 0x83AE, 0x83AF, 0x83B5, 0x83BB, 0x83BE, 0x83BF,
+    0x83C2, 0x83C3, 0x83C4, 0x83C6, 0x83C8, 0x83C9, 0x83CB, 0x83CD, 0x83CE, 0x83D0, 0x83D1, 0x83D2,
+    0x83D3, 0x83D5, 0x83D7, 0x83D9, 0x83DA, 0x83DB, 0x83DE, 0x83E2, 0x83E3, 0x83E4, 0x83E6, 0x83E7,
+    0x83E8, 0x83EB, 0x83EC, 0x83ED, 0x6070, 0x6D3D, 0x7275, 0x6266, 0x948E, 0x94C5, 0x5343, 0x8FC1,
+    0x7B7E, 0x4EDF, 0x8C26, 0x4E7E, 0x9ED4, 0x94B1, 0x94B3, 0x524D, 0x6F5C, 0x9063, 0x6D45, 0x8C34,
+    0x5811, 0x5D4C, 0x6B20, 0x6B49, 0x67AA, 0x545B, 0x8154, 0x7F8C, 0x5899, 0x8537, 0x5F3A, 0x62A2,
+    0x6A47, 0x9539, 0x6572, 0x6084, 0x6865, 0x77A7, 0x4E54, 0x4FA8, 0x5DE7, 0x9798, 0x64AC, 0x7FD8,
+    0x5CED, 0x4FCF, 0x7A8D, 0x5207, 0x8304, 0x4E14, 0x602F, 0x7A83, 0x94A6, 0x4FB5, 0x4EB2, 0x79E6,
+    0x7434, 0x52E4, 0x82B9, 0x64D2, 0x79BD, 0x5BDD, 0x6C81, 0x9752, 0x8F7B, 0x6C22, 0x503E, 0x537F,
+    0x6E05, 0x64CE, 0x6674, 0x6C30, 0x60C5, 0x9877, 0x8BF7, 0x5E86, 0x743C, 0x7A77, 0x79CB, 0x4E18,
+    0x90B1, 0x7403, 0x6C42, 0x56DA, 0x914B, 0x6CC5, 0x8D8B, 0x533A, 0x86C6, 0x66F2, 0x8EAF, 0x5C48,
+    0x9A71, 0x6E20, 0x83EE, 0x83EF, 0x83F3, 0x83F4, 0x83F5, 0x83F6, 0x83F7, 0x83FA, 0x83FB, 0x83FC,
+    0x83FE, 0x83FF, 0x8400, 0x8402, 0x8405, 0x8407, 0x8408, 0x8409, 0x840A, 0x8410, 0x8412, 0x8413,
+    0x8414, 0x8415, 0x8416, 0x8417, 0x8419, 0x841A, 0x841B, 0x841E, 0x841F, 0x8420, 0x8421, 0x8422,
+    0x8423, 0x8429, 0x842A, 0x842B, 0x842C, 0x842D, 0x842E, 0x842F, 0x8430, 0x8432, 0x8433, 0x8434,
+    0x8435, 0x8436, 0x8437, 0x8439, 0x843A, 0x843B, 0x843E, 0x843F, 0x8440, 0x8441, 0x8442, 0x8443,
+    0x8444, 0x8445, 0x8447, 0x8448, 0x8449, 0x844A, 0x844B, 0x844C, 0x844D, 0x844E, 0x844F, 0x8450,
+    0x8452, 0x8453, 0x8454, 0x8455, 0x8456, 0x8458, 0x845D, 0x845E, 0x845F, 0x8460, 0x8462, 0x8464,
+    0x8465, 0x8466, 0x8467, 0x8468, 0x846A, 0x846E, 0x846F, 0x8470, 0x8472, 0x8474, 0x8477, 0x8479,
+    0x847B, 0x847C, 0x53D6, 0x5A36, 0x9F8B, 0x8DA3, 0x53BB, 0x5708, 0x98A7, 0x6743, 0x919B, 0x6CC9,
+    0x5168, 0x75CA, 0x62F3, 0x72AC, 0x5238, 0x529D, 0x7F3A, 0x7094, 0x7638, 0x5374, 0x9E4A, 0x69B7,
+    0x786E, 0x96C0, 0x88D9, 0x7FA4, 0x7136, 0x71C3, 0x5189, 0x67D3, 0x74E4, 0x58E4, 0x6518, 0x56B7,
+    0x8BA9, 0x9976, 0x6270, 0x7ED5, 0x60F9, 0x70ED, 0x58EC, 0x4EC1, 0x4EBA, 0x5FCD, 0x97E7, 0x4EFB,
+    0x8BA4, 0x5203, 0x598A, 0x7EAB, 0x6254, 0x4ECD, 0x65E5, 0x620E, 0x8338, 0x84C9, 0x8363, 0x878D,
+    0x7194, 0x6EB6, 0x5BB9, 0x7ED2, 0x5197, 0x63C9, 0x67D4, 0x8089, 0x8339, 0x8815, 0x5112, 0x5B7A,
+    0x5982, 0x8FB1, 0x4E73, 0x6C5D, 0x5165, 0x8925, 0x8F6F, 0x962E, 0x854A, 0x745E, 0x9510, 0x95F0,
+    0x6DA6, 0x82E5, 0x5F31, 0x6492, 0x6D12, 0x8428, 0x816E, 0x9CC3, 0x585E, 0x8D5B, 0x4E09, 0x53C1,
+    0x847D, 0x847E, 0x847F, 0x8480, 0x8481, 0x8483, 0x8484, 0x8485, 0x8486, 0x848A, 0x848D, 0x848F,
+    0x8490, 0x8491, 0x8492, 0x8493, 0x8494, 0x8495, 0x8496, 0x8498, 0x849A, 0x849B, 0x849D, 0x849E,
+    0x849F, 0x84A0, 0x84A2, 0x84A3, 0x84A4, 0x84A5, 0x84A6, 0x84A7, 0x84A8, 0x84A9, 0x84AA, 0x84AB,
+    0x84AC, 0x84AD, 0x84AE, 0x84B0, 0x84B1, 0x84B3, 0x84B5, 0x84B6, 0x84B7, 0x84BB, 0x84BC, 0x84BE,
+    0x84C0, 0x84C2, 0x84C3, 0x84C5, 0x84C6, 0x84C7, 0x84C8, 0x84CB, 0x84CC, 0x84CE, 0x84CF, 0x84D2,
+    0x84D4, 0x84D5, 0x84D7, 0x84D8, 0x84D9, 0x84DA, 0x84DB, 0x84DC, 0x84DE, 0x84E1, 0x84E2, 0x84E4,
+    0x84E7, 0x84E8, 0x84E9, 0x84EA, 0x84EB, 0x84ED, 0x84EE, 0x84EF, 0x84F1, 0x84F2, 0x84F3, 0x84F4,
+    0x84F5, 0x84F6, 0x84F7, 0x84F8, 0x84F9, 0x84FA, 0x84FB, 0x84FD, 0x84FE, 0x8500, 0x8501, 0x8502,
+    0x4F1E, 0x6563, 0x6851, 0x55D3, 0x4E27, 0x6414, 0x9A9A, 0x626B, 0x5AC2, 0x745F, 0x8272, 0x6DA9,
+    0x68EE, 0x50E7, 0x838E, 0x7802, 0x6740, 0x5239, 0x6C99, 0x7EB1, 0x50BB, 0x5565, 0x715E, 0x7B5B,
+    0x6652, 0x73CA, 0x82EB, 0x6749, 0x5C71, 0x5220, 0x717D, 0x886B, 0x95EA, 0x9655, 0x64C5, 0x8D61,
+    0x81B3, 0x5584, 0x6C55, 0x6247, 0x7F2E, 0x5892, 0x4F24, 0x5546, 0x8D4F, 0x664C, 0x4E0A, 0x5C1A,
+    0x88F3, 0x68A2, 0x634E, 0x7A0D, 0x70E7, 0x828D, 0x52FA, 0x97F6, 0x5C11, 0x54E8, 0x90B5, 0x7ECD,
+    0x5962, 0x8D4A, 0x86C7, 0x820C, 0x820D, 0x8D66, 0x6444, 0x5C04, 0x6151, 0x6D89, 0x793E, 0x8BBE,
+    0x7837, 0x7533, 0x547B, 0x4F38, 0x8EAB, 0x6DF1, 0x5A20, 0x7EC5, 0x795E, 0x6C88, 0x5BA1, 0x5A76,
+    0x751A, 0x80BE, 0x614E, 0x6E17, 0x58F0, 0x751F, 0x7525, 0x7272, 0x5347, 0x7EF3, 0x8503, 0x8504,
+    0x8505, 0x8506, 0x8507, 0x8508, 0x8509, 0x850A, 0x850B, 0x850D, 0x850E, 0x850F, 0x8510, 0x8512,
+    0x8514, 0x8515, 0x8516, 0x8518, 0x8519, 0x851B, 0x851C, 0x851D, 0x851E, 0x8520, 0x8522, 0x8523,
+    0x8524, 0x8525, 0x8526, 0x8527, 0x8528, 0x8529, 0x852A, 0x852D, 0x852E, 0x852F, 0x8530, 0x8531,
+    0x8532, 0x8533, 0x8534, 0x8535, 0x8536, 0x853E, 0x853F, 0x8540, 0x8541, 0x8542, 0x8544, 0x8545,
+    0x8546, 0x8547, 0x854B, 0x854C, 0x854D, 0x854E, 0x854F, 0x8550, 0x8551, 0x8552, 0x8553, 0x8554,
+    0x8555, 0x8557, 0x8558, 0x855A, 0x855B, 0x855C, 0x855D, 0x855F, 0x8560, 0x8561, 0x8562, 0x8563,
+    0x8565, 0x8566, 0x8567, 0x8569, 0x856A, 0x856B, 0x856C, 0x856D, 0x856E, 0x856F, 0x8570, 0x8571,
+    0x8573, 0x8575, 0x8576, 0x8577, 0x8578, 0x857C, 0x857D, 0x857F, 0x8580, 0x8581, 0x7701, 0x76DB,
+    0x5269, 0x80DC, 0x5723, 0x5E08, 0x5931, 0x72EE, 0x65BD, 0x6E7F, 0x8BD7, 0x5C38, 0x8671, 0x5341,
+    0x77F3, 0x62FE, 0x65F6, 0x4EC0, 0x98DF, 0x8680, 0x5B9E, 0x8BC6, 0x53F2, 0x77E2, 0x4F7F, 0x5C4E,
+    0x9A76, 0x59CB, 0x5F0F, 0x793A, 0x58EB, 0x4E16, 0x67FF, 0x4E8B, 0x62ED, 0x8A93, 0x901D, 0x52BF,
+    0x662F, 0x55DC, 0x566C, 0x9002, 0x4ED5, 0x4F8D, 0x91CA, 0x9970, 0x6C0F, 0x5E02, 0x6043, 0x5BA4,
+    0x89C6, 0x8BD5, 0x6536, 0x624B, 0x9996, 0x5B88, 0x5BFF, 0x6388, 0x552E, 0x53D7, 0x7626, 0x517D,
+    0x852C, 0x67A2, 0x68B3, 0x6B8A, 0x6292, 0x8F93, 0x53D4, 0x8212, 0x6DD1, 0x758F, 0x4E66, 0x8D4E,
+    0x5B70, 0x719F, 0x85AF, 0x6691, 0x66D9, 0x7F72, 0x8700, 0x9ECD, 0x9F20, 0x5C5E, 0x672F, 0x8FF0,
+    0x6811, 0x675F, 0x620D, 0x7AD6, 0x5885, 0x5EB6, 0x6570, 0x6F31, 0x8582, 0x8583, 0x8586, 0x8588,
+    0x8589, 0x858A, 0x858B, 0x858C, 0x858D, 0x858E, 0x8590, 0x8591, 0x8592, 0x8593, 0x8594, 0x8595,
+    0x8596, 0x8597, 0x8598, 0x8599, 0x859A, 0x859D, 0x859E, 0x859F, 0x85A0, 0x85A1, 0x85A2, 0x85A3,
+    0x85A5, 0x85A6, 0x85A7, 0x85A9, 0x85AB, 0x85AC, 0x85AD, 0x85B1, 0x85B2, 0x85B3, 0x85B4, 0x85B5,
+    0x85B6, 0x85B8, 0x85BA, 0x85BB, 0x85BC, 0x85BD, 0x85BE, 0x85BF, 0x85C0, 0x85C2, 0x85C3, 0x85C4,
+    0x85C5, 0x85C6, 0x85C7, 0x85C8, 0x85CA, 0x85CB, 0x85CC, 0x85CD, 0x85CE, 0x85D1, 0x85D2, 0x85D4,
+    0x85D6, 0x85D7, 0x85D8, 0x85D9, 0x85DA, 0x85DB, 0x85DD, 0x85DE, 0x85DF, 0x85E0, 0x85E1, 0x85E2,
+    0x85E3, 0x85E5, 0x85E6, 0x85E7, 0x85E8, 0x85EA, 0x85EB, 0x85EC, 0x85ED, 0x85EE, 0x85EF, 0x85F0,
+    0x85F1, 0x85F2, 0x85F3, 0x85F4, 0x85F5, 0x85F6, 0x85F7, 0x85F8, 0x6055, 0x5237, 0x800D, 0x6454,
+    0x8870, 0x7529, 0x5E05, 0x6813, 0x62F4, 0x971C, 0x53CC, 0x723D, 0x8C01, 0x6C34, 0x7761, 0x7A0E,
+    0x542E, 0x77AC, 0x987A, 0x821C, 0x8BF4, 0x7855, 0x6714, 0x70C1, 0x65AF, 0x6495, 0x5636, 0x601D,
+    0x79C1, 0x53F8, 0x4E1D, 0x6B7B, 0x8086, 0x5BFA, 0x55E3, 0x56DB, 0x4F3A, 0x4F3C, 0x9972, 0x5DF3,
+    0x677E, 0x8038, 0x6002, 0x9882, 0x9001, 0x5B8B, 0x8BBC, 0x8BF5, 0x641C, 0x8258, 0x64DE, 0x55FD,
+    0x82CF, 0x9165, 0x4FD7, 0x7D20, 0x901F, 0x7C9F, 0x50F3, 0x5851, 0x6EAF, 0x5BBF, 0x8BC9, 0x8083,
+    0x9178, 0x849C, 0x7B97, 0x867D, 0x968B, 0x968F, 0x7EE5, 0x9AD3, 0x788E, 0x5C81, 0x7A57, 0x9042,
+    0x96A7, 0x795F, 0x5B59, 0x635F, 0x7B0B, 0x84D1, 0x68AD, 0x5506, 0x7F29, 0x7410, 0x7D22, 0x9501,
+    0x6240, 0x584C, 0x4ED6, 0x5B83, 0x5979, 0x5854, 0x85F9, 0x85FA, 0x85FC, 0x85FD, 0x85FE, 0x8600,
+    0x8601, 0x8602, 0x8603, 0x8604, 0x8606, 0x8607, 0x8608, 0x8609, 0x860A, 0x860B, 0x860C, 0x860D,
+    0x860E, 0x860F, 0x8610, 0x8612, 0x8613, 0x8614, 0x8615, 0x8617, 0x8618, 0x8619, 0x861A, 0x861B,
+    0x861C, 0x861D, 0x861E, 0x861F, 0x8620, 0x8621, 0x8622, 0x8623, 0x8624, 0x8625, 0x8626, 0x8628,
+    0x862A, 0x862B, 0x862C, 0x862D, 0x862E, 0x862F, 0x8630, 0x8631, 0x8632, 0x8633, 0x8634, 0x8635,
+    0x8636, 0x8637, 0x8639, 0x863A, 0x863B, 0x863D, 0x863E, 0x863F, 0x8640, 0x8641, 0x8642, 0x8643,
+    0x8644, 0x8645, 0x8646, 0x8647, 0x8648, 0x8649, 0x864A, 0x864B, 0x864C, 0x8652, 0x8653, 0x8655,
+    0x8656, 0x8657, 0x8658, 0x8659, 0x865B, 0x865C, 0x865D, 0x865F, 0x8660, 0x8661, 0x8663, 0x8664,
+    0x8665, 0x8666, 0x8667, 0x8668, 0x8669, 0x866A, 0x736D, 0x631E, 0x8E4B, 0x8E0F, 0x80CE, 0x82D4,
+    0x62AC, 0x53F0, 0x6CF0, 0x915E, 0x592A, 0x6001, 0x6C70, 0x574D, 0x644A, 0x8D2A, 0x762B, 0x6EE9,
+    0x575B, 0x6A80, 0x75F0, 0x6F6D, 0x8C2D, 0x8C08, 0x5766, 0x6BEF, 0x8892, 0x78B3, 0x63A2, 0x53F9,
+    0x70AD, 0x6C64, 0x5858, 0x642A, 0x5802, 0x68E0, 0x819B, 0x5510, 0x7CD6, 0x5018, 0x8EBA, 0x6DCC,
+    0x8D9F, 0x70EB, 0x638F, 0x6D9B, 0x6ED4, 0x7EE6, 0x8404, 0x6843, 0x9003, 0x6DD8, 0x9676, 0x8BA8,
+    0x5957, 0x7279, 0x85E4, 0x817E, 0x75BC, 0x8A8A, 0x68AF, 0x5254, 0x8E22, 0x9511, 0x63D0, 0x9898,
+    0x8E44, 0x557C, 0x4F53, 0x66FF, 0x568F, 0x60D5, 0x6D95, 0x5243, 0x5C49, 0x5929, 0x6DFB, 0x586B,
+    0x7530, 0x751C, 0x606C, 0x8214, 0x8146, 0x6311, 0x6761, 0x8FE2, 0x773A, 0x8DF3, 0x8D34, 0x94C1,
+    0x5E16, 0x5385, 0x542C, 0x70C3, 0x866D, 0x866F, 0x8670, 0x8672, 0x8673, 0x8674, 0x8675, 0x8676,
+    0x8677, 0x8678, 0x8683, 0x8684, 0x8685, 0x8686, 0x8687, 0x8688, 0x8689, 0x868E, 0x868F, 0x8690,
+    0x8691, 0x8692, 0x8694, 0x8696, 0x8697, 0x8698, 0x8699, 0x869A, 0x869B, 0x869E, 0x869F, 0x86A0,
+    0x86A1, 0x86A2, 0x86A5, 0x86A6, 0x86AB, 0x86AD, 0x86AE, 0x86B2, 0x86B3, 0x86B7, 0x86B8, 0x86B9,
+    0x86BB, 0x86BC, 0x86BD, 0x86BE, 0x86BF, 0x86C1, 0x86C2, 0x86C3, 0x86C5, 0x86C8, 0x86CC, 0x86CD,
+    0x86D2, 0x86D3, 0x86D5, 0x86D6, 0x86D7, 0x86DA, 0x86DC, 0x86DD, 0x86E0, 0x86E1, 0x86E2, 0x86E3,
+    0x86E5, 0x86E6, 0x86E7, 0x86E8, 0x86EA, 0x86EB, 0x86EC, 0x86EF, 0x86F5, 0x86F6, 0x86F7, 0x86FA,
+    0x86FB, 0x86FC, 0x86FD, 0x86FF, 0x8701, 0x8704, 0x8705, 0x8706, 0x870B, 0x870C, 0x870E, 0x870F,
+    0x8710, 0x8711, 0x8714, 0x8716, 0x6C40, 0x5EF7, 0x505C, 0x4EAD, 0x5EAD, 0x633A, 0x8247, 0x901A,
+    0x6850, 0x916E, 0x77B3, 0x540C, 0x94DC, 0x5F64, 0x7AE5, 0x6876, 0x6345, 0x7B52, 0x7EDF, 0x75DB,
+    0x5077, 0x6295, 0x5934, 0x900F, 0x51F8, 0x79C3, 0x7A81, 0x56FE, 0x5F92, 0x9014, 0x6D82, 0x5C60,
+    0x571F, 0x5410, 0x5154, 0x6E4D, 0x56E2, 0x63A8, 0x9893, 0x817F, 0x8715, 0x892A, 0x9000, 0x541E,
+    0x5C6F, 0x81C0, 0x62D6, 0x6258, 0x8131, 0x9E35, 0x9640, 0x9A6E, 0x9A7C, 0x692D, 0x59A5, 0x62D3,
+    0x553E, 0x6316, 0x54C7, 0x86D9, 0x6D3C, 0x5A03, 0x74E6, 0x889C, 0x6B6A, 0x5916, 0x8C4C, 0x5F2F,
+    0x6E7E, 0x73A9, 0x987D, 0x4E38, 0x70F7, 0x5B8C, 0x7897, 0x633D, 0x665A, 0x7696, 0x60CB, 0x5B9B,
+    0x5A49, 0x4E07, 0x8155, 0x6C6A, 0x738B, 0x4EA1, 0x6789, 0x7F51, 0x5F80, 0x65FA, 0x671B, 0x5FD8,
+    0x5984, 0x5A01, 0x8719, 0x871B, 0x871D, 0x871F, 0x8720, 0x8724, 0x8726, 0x8727, 0x8728, 0x872A,
+    0x872B, 0x872C, 0x872D, 0x872F, 0x8730, 0x8732, 0x8733, 0x8735, 0x8736, 0x8738, 0x8739, 0x873A,
+    0x873C, 0x873D, 0x8740, 0x8741, 0x8742, 0x8743, 0x8744, 0x8745, 0x8746, 0x874A, 0x874B, 0x874D,
+    0x874F, 0x8750, 0x8751, 0x8752, 0x8754, 0x8755, 0x8756, 0x8758, 0x875A, 0x875B, 0x875C, 0x875D,
+    0x875E, 0x875F, 0x8761, 0x8762, 0x8766, 0x8767, 0x8768, 0x8769, 0x876A, 0x876B, 0x876C, 0x876D,
+    0x876F, 0x8771, 0x8772, 0x8773, 0x8775, 0x8777, 0x8778, 0x8779, 0x877A, 0x877F, 0x8780, 0x8781,
+    0x8784, 0x8786, 0x8787, 0x8789, 0x878A, 0x878C, 0x878E, 0x878F, 0x8790, 0x8791, 0x8792, 0x8794,
+    0x8795, 0x8796, 0x8798, 0x8799, 0x879A, 0x879B, 0x879C, 0x879D, 0x879E, 0x87A0, 0x87A1, 0x87A2,
+    0x87A3, 0x87A4, 0x5DCD, 0x5FAE, 0x5371, 0x97E6, 0x8FDD, 0x6845, 0x56F4, 0x552F, 0x60DF, 0x4E3A,
+    0x6F4D, 0x7EF4, 0x82C7, 0x840E, 0x59D4, 0x4F1F, 0x4F2A, 0x5C3E, 0x7EAC, 0x672A, 0x851A, 0x5473,
+    0x754F, 0x80C3, 0x5582, 0x9B4F, 0x4F4D, 0x6E2D, 0x8C13, 0x5C09, 0x6170, 0x536B, 0x761F, 0x6E29,
+    0x868A, 0x6587, 0x95FB, 0x7EB9, 0x543B, 0x7A33, 0x7D0A, 0x95EE, 0x55E1, 0x7FC1, 0x74EE, 0x631D,
+    0x8717, 0x6DA1, 0x7A9D, 0x6211, 0x65A1, 0x5367, 0x63E1, 0x6C83, 0x5DEB, 0x545C, 0x94A8, 0x4E4C,
+    0x6C61, 0x8BEC, 0x5C4B, 0x65E0, 0x829C, 0x68A7, 0x543E, 0x5434, 0x6BCB, 0x6B66, 0x4E94, 0x6342,
+    0x5348, 0x821E, 0x4F0D, 0x4FAE, 0x575E, 0x620A, 0x96FE, 0x6664, 0x7269, 0x52FF, 0x52A1, 0x609F,
+    0x8BEF, 0x6614, 0x7199, 0x6790, 0x897F, 0x7852, 0x77FD, 0x6670, 0x563B, 0x5438, 0x9521, 0x727A,
+    0x87A5, 0x87A6, 0x87A7, 0x87A9, 0x87AA, 0x87AE, 0x87B0, 0x87B1, 0x87B2, 0x87B4, 0x87B6, 0x87B7,
+    0x87B8, 0x87B9, 0x87BB, 0x87BC, 0x87BE, 0x87BF, 0x87C1, 0x87C2, 0x87C3, 0x87C4, 0x87C5, 0x87C7,
+    0x87C8, 0x87C9, 0x87CC, 0x87CD, 0x87CE, 0x87CF, 0x87D0, 0x87D4, 0x87D5, 0x87D6, 0x87D7, 0x87D8,
+    0x87D9, 0x87DA, 0x87DC, 0x87DD, 0x87DE, 0x87DF, 0x87E1, 0x87E2, 0x87E3, 0x87E4, 0x87E6, 0x87E7,
+    0x87E8, 0x87E9, 0x87EB, 0x87EC, 0x87ED, 0x87EF, 0x87F0, 0x87F1, 0x87F2, 0x87F3, 0x87F4, 0x87F5,
+    0x87F6, 0x87F7, 0x87F8, 0x87FA, 0x87FB, 0x87FC, 0x87FD, 0x87FF, 0x8800, 0x8801, 0x8802, 0x8804,
+    0x8805, 0x8806, 0x8807, 0x8808, 0x8809, 0x880B, 0x880C, 0x880D, 0x880E, 0x880F, 0x8810, 0x8811,
+    0x8812, 0x8814, 0x8817, 0x8818, 0x8819, 0x881A, 0x881C, 0x881D, 0x881E, 0x881F, 0x8820, 0x8823,
+    0x7A00, 0x606F, 0x5E0C, 0x6089, 0x819D, 0x5915, 0x60DC, 0x7184, 0x70EF, 0x6EAA, 0x6C50, 0x7280,
+    0x6A84, 0x88AD, 0x5E2D, 0x4E60, 0x5AB3, 0x559C, 0x94E3, 0x6D17, 0x7CFB, 0x9699, 0x620F, 0x7EC6,
+    0x778E, 0x867E, 0x5323, 0x971E, 0x8F96, 0x6687, 0x5CE1, 0x4FA0, 0x72ED, 0x4E0B, 0x53A6, 0x590F,
+    0x5413, 0x6380, 0x9528, 0x5148, 0x4ED9, 0x9C9C, 0x7EA4, 0x54B8, 0x8D24, 0x8854, 0x8237, 0x95F2,
+    0x6D8E, 0x5F26, 0x5ACC, 0x663E, 0x9669, 0x73B0, 0x732E, 0x53BF, 0x817A, 0x9985, 0x7FA1, 0x5BAA,
+    0x9677, 0x9650, 0x7EBF, 0x76F8, 0x53A2, 0x9576, 0x9999, 0x7BB1, 0x8944, 0x6E58, 0x4E61, 0x7FD4,
+    0x7965, 0x8BE6, 0x60F3, 0x54CD, 0x4EAB, 0x9879, 0x5DF7, 0x6A61, 0x50CF, 0x5411, 0x8C61, 0x8427,
+    0x785D, 0x9704, 0x524A, 0x54EE, 0x56A3, 0x9500, 0x6D88, 0x5BB5, 0x6DC6, 0x6653, 0x8824, 0x8825,
+    0x8826, 0x8827, 0x8828, 0x8829, 0x882A, 0x882B, 0x882C, 0x882D, 0x882E, 0x882F, 0x8830, 0x8831,
+    0x8833, 0x8834, 0x8835, 0x8836, 0x8837, 0x8838, 0x883A, 0x883B, 0x883D, 0x883E, 0x883F, 0x8841,
+    0x8842, 0x8843, 0x8846, 0x8847, 0x8848, 0x8849, 0x884A, 0x884B, 0x884E, 0x884F, 0x8850, 0x8851,
+    0x8852, 0x8853, 0x8855, 0x8856, 0x8858, 0x885A, 0x885B, 0x885C, 0x885D, 0x885E, 0x885F, 0x8860,
+    0x8866, 0x8867, 0x886A, 0x886D, 0x886F, 0x8871, 0x8873, 0x8874, 0x8875, 0x8876, 0x8878, 0x8879,
+    0x887A, 0x887B, 0x887C, 0x8880, 0x8883, 0x8886, 0x8887, 0x8889, 0x888A, 0x888C, 0x888E, 0x888F,
+    0x8890, 0x8891, 0x8893, 0x8894, 0x8895, 0x8897, 0x8898, 0x8899, 0x889A, 0x889B, 0x889D, 0x889E,
+    0x889F, 0x88A0, 0x88A1, 0x88A3, 0x88A5, 0x88A6, 0x88A7, 0x88A8, 0x88A9, 0x88AA, 0x5C0F, 0x5B5D,
+    0x6821, 0x8096, 0x5578, 0x7B11, 0x6548, 0x6954, 0x4E9B, 0x6B47, 0x874E, 0x978B, 0x534F, 0x631F,
+    0x643A, 0x90AA, 0x659C, 0x80C1, 0x8C10, 0x5199, 0x68B0, 0x5378, 0x87F9, 0x61C8, 0x6CC4, 0x6CFB,
+    0x8C22, 0x5C51, 0x85AA, 0x82AF, 0x950C, 0x6B23, 0x8F9B, 0x65B0, 0x5FFB, 0x5FC3, 0x4FE1, 0x8845,
+    0x661F, 0x8165, 0x7329, 0x60FA, 0x5174, 0x5211, 0x578B, 0x5F62, 0x90A2, 0x884C, 0x9192, 0x5E78,
+    0x674F, 0x6027, 0x59D3, 0x5144, 0x51F6, 0x80F8, 0x5308, 0x6C79, 0x96C4, 0x718A, 0x4F11, 0x4FEE,
+    0x7F9E, 0x673D, 0x55C5, 0x9508, 0x79C0, 0x8896, 0x7EE3, 0x589F, 0x620C, 0x9700, 0x865A, 0x5618,
+    0x987B, 0x5F90, 0x8BB8, 0x84C4, 0x9157, 0x53D9, 0x65ED, 0x5E8F, 0x755C, 0x6064, 0x7D6E, 0x5A7F,
+    0x7EEA, 0x7EED, 0x8F69, 0x55A7, 0x5BA3, 0x60AC, 0x65CB, 0x7384, 0x88AC, 0x88AE, 0x88AF, 0x88B0,
+    0x88B2, 0x88B3, 0x88B4, 0x88B5, 0x88B6, 0x88B8, 0x88B9, 0x88BA, 0x88BB, 0x88BD, 0x88BE, 0x88BF,
+    0x88C0, 0x88C3, 0x88C4, 0x88C7, 0x88C8, 0x88CA, 0x88CB, 0x88CC, 0x88CD, 0x88CF, 0x88D0, 0x88D1,
+    0x88D3, 0x88D6, 0x88D7, 0x88DA, 0x88DB, 0x88DC, 0x88DD, 0x88DE, 0x88E0, 0x88E1, 0x88E6, 0x88E7,
+    0x88E9, 0x88EA, 0x88EB, 0x88EC, 0x88ED, 0x88EE, 0x88EF, 0x88F2, 0x88F5, 0x88F6, 0x88F7, 0x88FA,
+    0x88FB, 0x88FD, 0x88FF, 0x8900, 0x8901, 0x8903, 0x8904, 0x8905, 0x8906, 0x8907, 0x8908, 0x8909,
+    0x890B, 0x890C, 0x890D, 0x890E, 0x890F, 0x8911, 0x8914, 0x8915, 0x8916, 0x8917, 0x8918, 0x891C,
+    0x891D, 0x891E, 0x891F, 0x8920, 0x8922, 0x8923, 0x8924, 0x8926, 0x8927, 0x8928, 0x8929, 0x892C,
+    0x892D, 0x892E, 0x892F, 0x8931, 0x8932, 0x8933, 0x8935, 0x8937, 0x9009, 0x7663, 0x7729, 0x7EDA,
+    0x9774, 0x859B, 0x5B66, 0x7A74, 0x96EA, 0x8840, 0x52CB, 0x718F, 0x5FAA, 0x65EC, 0x8BE2, 0x5BFB,
+    0x9A6F, 0x5DE1, 0x6B89, 0x6C5B, 0x8BAD, 0x8BAF, 0x900A, 0x8FC5, 0x538B, 0x62BC, 0x9E26, 0x9E2D,
+    0x5440, 0x4E2B, 0x82BD, 0x7259, 0x869C, 0x5D16, 0x8859, 0x6DAF, 0x96C5, 0x54D1, 0x4E9A, 0x8BB6,
+    0x7109, 0x54BD, 0x9609, 0x70DF, 0x6DF9, 0x76D0, 0x4E25, 0x7814, 0x8712, 0x5CA9, 0x5EF6, 0x8A00,
+    0x989C, 0x960E, 0x708E, 0x6CBF, 0x5944, 0x63A9, 0x773C, 0x884D, 0x6F14, 0x8273, 0x5830, 0x71D5,
+    0x538C, 0x781A, 0x96C1, 0x5501, 0x5F66, 0x7130, 0x5BB4, 0x8C1A, 0x9A8C, 0x6B83, 0x592E, 0x9E2F,
+    0x79E7, 0x6768, 0x626C, 0x4F6F, 0x75A1, 0x7F8A, 0x6D0B, 0x9633, 0x6C27, 0x4EF0, 0x75D2, 0x517B,
+    0x6837, 0x6F3E, 0x9080, 0x8170, 0x5996, 0x7476, 0x8938, 0x8939, 0x893A, 0x893B, 0x893C, 0x893D,
+    0x893E, 0x893F, 0x8940, 0x8942, 0x8943, 0x8945, 0x8946, 0x8947, 0x8948, 0x8949, 0x894A, 0x894B,
+    0x894C, 0x894D, 0x894E, 0x894F, 0x8950, 0x8951, 0x8952, 0x8953, 0x8954, 0x8955, 0x8956, 0x8957,
+    0x8958, 0x8959, 0x895A, 0x895B, 0x895C, 0x895D, 0x8960, 0x8961, 0x8962, 0x8963, 0x8964, 0x8965,
+    0x8967, 0x8968, 0x8969, 0x896A, 0x896B, 0x896C, 0x896D, 0x896E, 0x896F, 0x8970, 0x8971, 0x8972,
+    0x8973, 0x8974, 0x8975, 0x8976, 0x8977, 0x8978, 0x8979, 0x897A, 0x897C, 0x897D, 0x897E, 0x8980,
+    0x8982, 0x8984, 0x8985, 0x8987, 0x8988, 0x8989, 0x898A, 0x898B, 0x898C, 0x898D, 0x898E, 0x898F,
+    0x8990, 0x8991, 0x8992, 0x8993, 0x8994, 0x8995, 0x8996, 0x8997, 0x8998, 0x8999, 0x899A, 0x899B,
+    0x899C, 0x899D, 0x899E, 0x899F, 0x89A0, 0x89A1, 0x6447, 0x5C27, 0x9065, 0x7A91, 0x8C23, 0x59DA,
+    0x54AC, 0x8200, 0x836F, 0x8981, 0x8000, 0x6930, 0x564E, 0x8036, 0x7237, 0x91CE, 0x51B6, 0x4E5F,
+    0x9875, 0x6396, 0x4E1A, 0x53F6, 0x66F3, 0x814B, 0x591C, 0x6DB2, 0x4E00, 0x58F9, 0x533B, 0x63D6,
+    0x94F1, 0x4F9D, 0x4F0A, 0x8863, 0x9890, 0x5937, 0x9057, 0x79FB, 0x4EEA, 0x80F0, 0x7591, 0x6C82,
+    0x5B9C, 0x59E8, 0x5F5D, 0x6905, 0x8681, 0x501A, 0x5DF2, 0x4E59, 0x77E3, 0x4EE5, 0x827A, 0x6291,
+    0x6613, 0x9091, 0x5C79, 0x4EBF, 0x5F79, 0x81C6, 0x9038, 0x8084, 0x75AB, 0x4EA6, 0x88D4, 0x610F,
+    0x6BC5, 0x5FC6, 0x4E49, 0x76CA, 0x6EA2, 0x8BE3, 0x8BAE, 0x8C0A, 0x8BD1, 0x5F02, 0x7FFC, 0x7FCC,
+    0x7ECE, 0x8335, 0x836B, 0x56E0, 0x6BB7, 0x97F3, 0x9634, 0x59FB, 0x541F, 0x94F6, 0x6DEB, 0x5BC5,
+    0x996E, 0x5C39, 0x5F15, 0x9690, 0x89A2, 0x89A3, 0x89A4, 0x89A5, 0x89A6, 0x89A7, 0x89A8, 0x89A9,
+    0x89AA, 0x89AB, 0x89AC, 0x89AD, 0x89AE, 0x89AF, 0x89B0, 0x89B1, 0x89B2, 0x89B3, 0x89B4, 0x89B5,
+    0x89B6, 0x89B7, 0x89B8, 0x89B9, 0x89BA, 0x89BB, 0x89BC, 0x89BD, 0x89BE, 0x89BF, 0x89C0, 0x89C3,
+    0x89CD, 0x89D3, 0x89D4, 0x89D5, 0x89D7, 0x89D8, 0x89D9, 0x89DB, 0x89DD, 0x89DF, 0x89E0, 0x89E1,
+    0x89E2, 0x89E4, 0x89E7, 0x89E8, 0x89E9, 0x89EA, 0x89EC, 0x89ED, 0x89EE, 0x89F0, 0x89F1, 0x89F2,
+    0x89F4, 0x89F5, 0x89F6, 0x89F7, 0x89F8, 0x89F9, 0x89FA, 0x89FB, 0x89FC, 0x89FD, 0x89FE, 0x89FF,
+    0x8A01, 0x8A02, 0x8A03, 0x8A04, 0x8A05, 0x8A06, 0x8A08, 0x8A09, 0x8A0A, 0x8A0B, 0x8A0C, 0x8A0D,
+    0x8A0E, 0x8A0F, 0x8A10, 0x8A11, 0x8A12, 0x8A13, 0x8A14, 0x8A15, 0x8A16, 0x8A17, 0x8A18, 0x8A19,
+    0x8A1A, 0x8A1B, 0x8A1C, 0x8A1D, 0x5370, 0x82F1, 0x6A31, 0x5A74, 0x9E70, 0x5E94, 0x7F28, 0x83B9,
+    0x8424, 0x8425, 0x8367, 0x8747, 0x8FCE, 0x8D62, 0x76C8, 0x5F71, 0x9896, 0x786C, 0x6620, 0x54DF,
+    0x62E5, 0x4F63, 0x81C3, 0x75C8, 0x5EB8, 0x96CD, 0x8E0A, 0x86F9, 0x548F, 0x6CF3, 0x6D8C, 0x6C38,
+    0x607F, 0x52C7, 0x7528, 0x5E7D, 0x4F18, 0x60A0, 0x5FE7, 0x5C24, 0x7531, 0x90AE, 0x94C0, 0x72B9,
+    0x6CB9, 0x6E38, 0x9149, 0x6709, 0x53CB, 0x53F3, 0x4F51, 0x91C9, 0x8BF1, 0x53C8, 0x5E7C, 0x8FC2,
+    0x6DE4, 0x4E8E, 0x76C2, 0x6986, 0x865E, 0x611A, 0x8206, 0x4F59, 0x4FDE, 0x903E, 0x9C7C, 0x6109,
+    0x6E1D, 0x6E14, 0x9685, 0x4E88, 0x5A31, 0x96E8, 0x4E0E, 0x5C7F, 0x79B9, 0x5B87, 0x8BED, 0x7FBD,
+    0x7389, 0x57DF, 0x828B, 0x90C1, 0x5401, 0x9047, 0x55BB, 0x5CEA, 0x5FA1, 0x6108, 0x6B32, 0x72F1,
+    0x80B2, 0x8A89, 0x8A1E, 0x8A1F, 0x8A20, 0x8A21, 0x8A22, 0x8A23, 0x8A24, 0x8A25, 0x8A26, 0x8A27,
+    0x8A28, 0x8A29, 0x8A2A, 0x8A2B, 0x8A2C, 0x8A2D, 0x8A2E, 0x8A2F, 0x8A30, 0x8A31, 0x8A32, 0x8A33,
+    0x8A34, 0x8A35, 0x8A36, 0x8A37, 0x8A38, 0x8A39, 0x8A3A, 0x8A3B, 0x8A3C, 0x8A3D, 0x8A3F, 0x8A40,
+    0x8A41, 0x8A42, 0x8A43, 0x8A44, 0x8A45, 0x8A46, 0x8A47, 0x8A49, 0x8A4A, 0x8A4B, 0x8A4C, 0x8A4D,
+    0x8A4E, 0x8A4F, 0x8A50, 0x8A51, 0x8A52, 0x8A53, 0x8A54, 0x8A55, 0x8A56, 0x8A57, 0x8A58, 0x8A59,
+    0x8A5A, 0x8A5B, 0x8A5C, 0x8A5D, 0x8A5E, 0x8A5F, 0x8A60, 0x8A61, 0x8A62, 0x8A63, 0x8A64, 0x8A65,
+    0x8A66, 0x8A67, 0x8A68, 0x8A69, 0x8A6A, 0x8A6B, 0x8A6C, 0x8A6D, 0x8A6E, 0x8A6F, 0x8A70, 0x8A71,
+    0x8A72, 0x8A73, 0x8A74, 0x8A75, 0x8A76, 0x8A77, 0x8A78, 0x8A7A, 0x8A7B, 0x8A7C, 0x8A7D, 0x8A7E,
+    0x8A7F, 0x8A80, 0x6D74, 0x5BD3, 0x88D5, 0x9884, 0x8C6B, 0x9A6D, 0x9E33, 0x6E0A, 0x51A4, 0x5143,
+    0x57A3, 0x8881, 0x539F, 0x63F4, 0x8F95, 0x56ED, 0x5458, 0x5706, 0x733F, 0x6E90, 0x7F18, 0x8FDC,
+    0x82D1, 0x613F, 0x6028, 0x9662, 0x66F0, 0x7EA6, 0x8D8A, 0x8DC3, 0x94A5, 0x5CB3, 0x7CA4, 0x6708,
+    0x60A6, 0x9605, 0x8018, 0x4E91, 0x90E7, 0x5300, 0x9668, 0x5141, 0x8FD0, 0x8574, 0x915D, 0x6655,
+    0x97F5, 0x5B55, 0x531D, 0x7838, 0x6742, 0x683D, 0x54C9, 0x707E, 0x5BB0, 0x8F7D, 0x518D, 0x5728,
+    0x54B1, 0x6512, 0x6682, 0x8D5E, 0x8D43, 0x810F, 0x846C, 0x906D, 0x7CDF, 0x51FF, 0x85FB, 0x67A3,
+    0x65E9, 0x6FA1, 0x86A4, 0x8E81, 0x566A, 0x9020, 0x7682, 0x7076, 0x71E5, 0x8D23, 0x62E9, 0x5219,
+    0x6CFD, 0x8D3C, 0x600E, 0x589E, 0x618E, 0x66FE, 0x8D60, 0x624E, 0x55B3, 0x6E23, 0x672D, 0x8F67,
+    0x8A81, 0x8A82, 0x8A83, 0x8A84, 0x8A85, 0x8A86, 0x8A87, 0x8A88, 0x8A8B, 0x8A8C, 0x8A8D, 0x8A8E,
+    0x8A8F, 0x8A90, 0x8A91, 0x8A92, 0x8A94, 0x8A95, 0x8A96, 0x8A97, 0x8A98, 0x8A99, 0x8A9A, 0x8A9B,
+    0x8A9C, 0x8A9D, 0x8A9E, 0x8A9F, 0x8AA0, 0x8AA1, 0x8AA2, 0x8AA3, 0x8AA4, 0x8AA5, 0x8AA6, 0x8AA7,
+    0x8AA8, 0x8AA9, 0x8AAA, 0x8AAB, 0x8AAC, 0x8AAD, 0x8AAE, 0x8AAF, 0x8AB0, 0x8AB1, 0x8AB2, 0x8AB3,
+    0x8AB4, 0x8AB5, 0x8AB6, 0x8AB7, 0x8AB8, 0x8AB9, 0x8ABA, 0x8ABB, 0x8ABC, 0x8ABD, 0x8ABE, 0x8ABF,
+    0x8AC0, 0x8AC1, 0x8AC2, 0x8AC3, 0x8AC4, 0x8AC5, 0x8AC6, 0x8AC7, 0x8AC8, 0x8AC9, 0x8ACA, 0x8ACB,
+    0x8ACC, 0x8ACD, 0x8ACE, 0x8ACF, 0x8AD0, 0x8AD1, 0x8AD2, 0x8AD3, 0x8AD4, 0x8AD5, 0x8AD6, 0x8AD7,
+    0x8AD8, 0x8AD9, 0x8ADA, 0x8ADB, 0x8ADC, 0x8ADD, 0x8ADE, 0x8ADF, 0x8AE0, 0x8AE1, 0x8AE2, 0x8AE3,
+    0x94E1, 0x95F8, 0x7728, 0x6805, 0x69A8, 0x548B, 0x4E4D, 0x70B8, 0x8BC8, 0x6458, 0x658B, 0x5B85,
+    0x7A84, 0x503A, 0x5BE8, 0x77BB, 0x6BE1, 0x8A79, 0x7C98, 0x6CBE, 0x76CF, 0x65A9, 0x8F97, 0x5D2D,
+    0x5C55, 0x8638, 0x6808, 0x5360, 0x6218, 0x7AD9, 0x6E5B, 0x7EFD, 0x6A1F, 0x7AE0, 0x5F70, 0x6F33,
+    0x5F20, 0x638C, 0x6DA8, 0x6756, 0x4E08, 0x5E10, 0x8D26, 0x4ED7, 0x80C0, 0x7634, 0x969C, 0x62DB,
+    0x662D, 0x627E, 0x6CBC, 0x8D75, 0x7167, 0x7F69, 0x5146, 0x8087, 0x53EC, 0x906E, 0x6298, 0x54F2,
+    0x86F0, 0x8F99, 0x8005, 0x9517, 0x8517, 0x8FD9, 0x6D59, 0x73CD, 0x659F, 0x771F, 0x7504, 0x7827,
+    0x81FB, 0x8D1E, 0x9488, 0x4FA6, 0x6795, 0x75B9, 0x8BCA, 0x9707, 0x632F, 0x9547, 0x9635, 0x84B8,
+    0x6323, 0x7741, 0x5F81, 0x72F0, 0x4E89, 0x6014, 0x6574, 0x62EF, 0x6B63, 0x653F, 0x8AE4, 0x8AE5,
+    0x8AE6, 0x8AE7, 0x8AE8, 0x8AE9, 0x8AEA, 0x8AEB, 0x8AEC, 0x8AED, 0x8AEE, 0x8AEF, 0x8AF0, 0x8AF1,
+    0x8AF2, 0x8AF3, 0x8AF4, 0x8AF5, 0x8AF6, 0x8AF7, 0x8AF8, 0x8AF9, 0x8AFA, 0x8AFB, 0x8AFC, 0x8AFD,
+    0x8AFE, 0x8AFF, 0x8B00, 0x8B01, 0x8B02, 0x8B03, 0x8B04, 0x8B05, 0x8B06, 0x8B08, 0x8B09, 0x8B0A,
+    0x8B0B, 0x8B0C, 0x8B0D, 0x8B0E, 0x8B0F, 0x8B10, 0x8B11, 0x8B12, 0x8B13, 0x8B14, 0x8B15, 0x8B16,
+    0x8B17, 0x8B18, 0x8B19, 0x8B1A, 0x8B1B, 0x8B1C, 0x8B1D, 0x8B1E, 0x8B1F, 0x8B20, 0x8B21, 0x8B22,
+    0x8B23, 0x8B24, 0x8B25, 0x8B27, 0x8B28, 0x8B29, 0x8B2A, 0x8B2B, 0x8B2C, 0x8B2D, 0x8B2E, 0x8B2F,
+    0x8B30, 0x8B31, 0x8B32, 0x8B33, 0x8B34, 0x8B35, 0x8B36, 0x8B37, 0x8B38, 0x8B39, 0x8B3A, 0x8B3B,
+    0x8B3C, 0x8B3D, 0x8B3E, 0x8B3F, 0x8B40, 0x8B41, 0x8B42, 0x8B43, 0x8B44, 0x8B45, 0x5E27, 0x75C7,
+    0x90D1, 0x8BC1, 0x829D, 0x679D, 0x652F, 0x5431, 0x8718, 0x77E5, 0x80A2, 0x8102, 0x6C41, 0x4E4B,
+    0x7EC7, 0x804C, 0x76F4, 0x690D, 0x6B96, 0x6267, 0x503C, 0x4F84, 0x5740, 0x6307, 0x6B62, 0x8DBE,
+    0x53EA, 0x65E8, 0x7EB8, 0x5FD7, 0x631A, 0x63B7, 0x81F3, 0x81F4, 0x7F6E, 0x5E1C, 0x5CD9, 0x5236,
+    0x667A, 0x79E9, 0x7A1A, 0x8D28, 0x7099, 0x75D4, 0x6EDE, 0x6CBB, 0x7A92, 0x4E2D, 0x76C5, 0x5FE0,
+    0x949F, 0x8877, 0x7EC8, 0x79CD, 0x80BF, 0x91CD, 0x4EF2, 0x4F17, 0x821F, 0x5468, 0x5DDE, 0x6D32,
+    0x8BCC, 0x7CA5, 0x8F74, 0x8098, 0x5E1A, 0x5492, 0x76B1, 0x5B99, 0x663C, 0x9AA4, 0x73E0, 0x682A,
+    0x86DB, 0x6731, 0x732A, 0x8BF8, 0x8BDB, 0x9010, 0x7AF9, 0x70DB, 0x716E, 0x62C4, 0x77A9, 0x5631,
+    0x4E3B, 0x8457, 0x67F1, 0x52A9, 0x86C0, 0x8D2E, 0x94F8, 0x7B51, 0x8B46, 0x8B47, 0x8B48, 0x8B49,
+    0x8B4A, 0x8B4B, 0x8B4C, 0x8B4D, 0x8B4E, 0x8B4F, 0x8B50, 0x8B51, 0x8B52, 0x8B53, 0x8B54, 0x8B55,
+    0x8B56, 0x8B57, 0x8B58, 0x8B59, 0x8B5A, 0x8B5B, 0x8B5C, 0x8B5D, 0x8B5E, 0x8B5F, 0x8B60, 0x8B61,
+    0x8B62, 0x8B63, 0x8B64, 0x8B65, 0x8B67, 0x8B68, 0x8B69, 0x8B6A, 0x8B6B, 0x8B6D, 0x8B6E, 0x8B6F,
+    0x8B70, 0x8B71, 0x8B72, 0x8B73, 0x8B74, 0x8B75, 0x8B76, 0x8B77, 0x8B78, 0x8B79, 0x8B7A, 0x8B7B,
+    0x8B7C, 0x8B7D, 0x8B7E, 0x8B7F, 0x8B80, 0x8B81, 0x8B82, 0x8B83, 0x8B84, 0x8B85, 0x8B86, 0x8B87,
+    0x8B88, 0x8B89, 0x8B8A, 0x8B8B, 0x8B8C, 0x8B8D, 0x8B8E, 0x8B8F, 0x8B90, 0x8B91, 0x8B92, 0x8B93,
+    0x8B94, 0x8B95, 0x8B96, 0x8B97, 0x8B98, 0x8B99, 0x8B9A, 0x8B9B, 0x8B9C, 0x8B9D, 0x8B9E, 0x8B9F,
+    0x8BAC, 0x8BB1, 0x8BBB, 0x8BC7, 0x8BD0, 0x8BEA, 0x8C09, 0x8C1E, 0x4F4F, 0x6CE8, 0x795D, 0x9A7B,
+    0x6293, 0x722A, 0x62FD, 0x4E13, 0x7816, 0x8F6C, 0x64B0, 0x8D5A, 0x7BC6, 0x6869, 0x5E84, 0x88C5,
+    0x5986, 0x649E, 0x58EE, 0x72B6, 0x690E, 0x9525, 0x8FFD, 0x8D58, 0x5760, 0x7F00, 0x8C06, 0x51C6,
+    0x6349, 0x62D9, 0x5353, 0x684C, 0x7422, 0x8301, 0x914C, 0x5544, 0x7740, 0x707C, 0x6D4A, 0x5179,
+    0x54A8, 0x8D44, 0x59FF, 0x6ECB, 0x6DC4, 0x5B5C, 0x7D2B, 0x4ED4, 0x7C7D, 0x6ED3, 0x5B50, 0x81EA,
+    0x6E0D, 0x5B57, 0x9B03, 0x68D5, 0x8E2A, 0x5B97, 0x7EFC, 0x603B, 0x7EB5, 0x90B9, 0x8D70, 0x594F,
+    0x63CD, 0x79DF, 0x8DB3, 0x5352, 0x65CF, 0x7956, 0x8BC5, 0x963B, 0x7EC4, 0x94BB, 0x7E82, 0x5634,
+    0x9189, 0x6700, 0x7F6A, 0x5C0A, 0x9075, 0x6628, 0x5DE6, 0x4F50, 0x67DE, 0x505A, 0x4F5C, 0x5750,
+    0x5EA7, 0xE810, 0xE811, 0xE812, 0xE813, 0xE814, 0x8C38, 0x8C39, 0x8C3A, 0x8C3B, 0x8C3C, 0x8C3D,
+    0x8C3E, 0x8C3F, 0x8C40, 0x8C42, 0x8C43, 0x8C44, 0x8C45, 0x8C48, 0x8C4A, 0x8C4B, 0x8C4D, 0x8C4E,
+    0x8C4F, 0x8C50, 0x8C51, 0x8C52, 0x8C53, 0x8C54, 0x8C56, 0x8C57, 0x8C58, 0x8C59, 0x8C5B, 0x8C5C,
+    0x8C5D, 0x8C5E, 0x8C5F, 0x8C60, 0x8C63, 0x8C64, 0x8C65, 0x8C66, 0x8C67, 0x8C68, 0x8C69, 0x8C6C,
+    0x8C6D, 0x8C6E, 0x8C6F, 0x8C70, 0x8C71, 0x8C72, 0x8C74, 0x8C75, 0x8C76, 0x8C77, 0x8C7B, 0x8C7C,
+    0x8C7D, 0x8C7E, 0x8C7F, 0x8C80, 0x8C81, 0x8C83, 0x8C84, 0x8C86, 0x8C87, 0x8C88, 0x8C8B, 0x8C8D,
+    0x8C8E, 0x8C8F, 0x8C90, 0x8C91, 0x8C92, 0x8C93, 0x8C95, 0x8C96, 0x8C97, 0x8C99, 0x8C9A, 0x8C9B,
+    0x8C9C, 0x8C9D, 0x8C9E, 0x8C9F, 0x8CA0, 0x8CA1, 0x8CA2, 0x8CA3, 0x8CA4, 0x8CA5, 0x8CA6, 0x8CA7,
+    0x8CA8, 0x8CA9, 0x8CAA, 0x8CAB, 0x8CAC, 0x8CAD, 0x4E8D, 0x4E0C, 0x5140, 0x4E10, 0x5EFF, 0x5345,
+    0x4E15, 0x4E98, 0x4E1E, 0x9B32, 0x5B6C, 0x5669, 0x4E28, 0x79BA, 0x4E3F, 0x5315, 0x4E47, 0x592D,
+    0x723B, 0x536E, 0x6C10, 0x56DF, 0x80E4, 0x9997, 0x6BD3, 0x777E, 0x9F17, 0x4E36, 0x4E9F, 0x9F10,
+    0x4E5C, 0x4E69, 0x4E93, 0x8288, 0x5B5B, 0x556C, 0x560F, 0x4EC4, 0x538D, 0x539D, 0x53A3, 0x53A5,
+    0x53AE, 0x9765, 0x8D5D, 0x531A, 0x53F5, 0x5326, 0x532E, 0x533E, 0x8D5C, 0x5366, 0x5363, 0x5202,
+    0x5208, 0x520E, 0x522D, 0x5233, 0x523F, 0x5240, 0x524C, 0x525E, 0x5261, 0x525C, 0x84AF, 0x527D,
+    0x5282, 0x5281, 0x5290, 0x5293, 0x5182, 0x7F54, 0x4EBB, 0x4EC3, 0x4EC9, 0x4EC2, 0x4EE8, 0x4EE1,
+    0x4EEB, 0x4EDE, 0x4F1B, 0x4EF3, 0x4F22, 0x4F64, 0x4EF5, 0x4F25, 0x4F27, 0x4F09, 0x4F2B, 0x4F5E,
+    0x4F67, 0x6538, 0x4F5A, 0x4F5D, 0x8CAE, 0x8CAF, 0x8CB0, 0x8CB1, 0x8CB2, 0x8CB3, 0x8CB4, 0x8CB5,
+    0x8CB6, 0x8CB7, 0x8CB8, 0x8CB9, 0x8CBA, 0x8CBB, 0x8CBC, 0x8CBD, 0x8CBE, 0x8CBF, 0x8CC0, 0x8CC1,
+    0x8CC2, 0x8CC3, 0x8CC4, 0x8CC5, 0x8CC6, 0x8CC7, 0x8CC8, 0x8CC9, 0x8CCA, 0x8CCB, 0x8CCC, 0x8CCD,
+    0x8CCE, 0x8CCF, 0x8CD0, 0x8CD1, 0x8CD2, 0x8CD3, 0x8CD4, 0x8CD5, 0x8CD6, 0x8CD7, 0x8CD8, 0x8CD9,
+    0x8CDA, 0x8CDB, 0x8CDC, 0x8CDD, 0x8CDE, 0x8CDF, 0x8CE0, 0x8CE1, 0x8CE2, 0x8CE3, 0x8CE4, 0x8CE5,
+    0x8CE6, 0x8CE7, 0x8CE8, 0x8CE9, 0x8CEA, 0x8CEB, 0x8CEC, 0x8CED, 0x8CEE, 0x8CEF, 0x8CF0, 0x8CF1,
+    0x8CF2, 0x8CF3, 0x8CF4, 0x8CF5, 0x8CF6, 0x8CF7, 0x8CF8, 0x8CF9, 0x8CFA, 0x8CFB, 0x8CFC, 0x8CFD,
+    0x8CFE, 0x8CFF, 0x8D00, 0x8D01, 0x8D02, 0x8D03, 0x8D04, 0x8D05, 0x8D06, 0x8D07, 0x8D08, 0x8D09,
+    0x8D0A, 0x8D0B, 0x8D0C, 0x8D0D, 0x4F5F, 0x4F57, 0x4F32, 0x4F3D, 0x4F76, 0x4F74, 0x4F91, 0x4F89,
+    0x4F83, 0x4F8F, 0x4F7E, 0x4F7B, 0x4FAA, 0x4F7C, 0x4FAC, 0x4F94, 0x4FE6, 0x4FE8, 0x4FEA, 0x4FC5,
+    0x4FDA, 0x4FE3, 0x4FDC, 0x4FD1, 0x4FDF, 0x4FF8, 0x5029, 0x504C, 0x4FF3, 0x502C, 0x500F, 0x502E,
+    0x502D, 0x4FFE, 0x501C, 0x500C, 0x5025, 0x5028, 0x507E, 0x5043, 0x5055, 0x5048, 0x504E, 0x506C,
+    0x507B, 0x50A5, 0x50A7, 0x50A9, 0x50BA, 0x50D6, 0x5106, 0x50ED, 0x50EC, 0x50E6, 0x50EE, 0x5107,
+    0x510B, 0x4EDD, 0x6C3D, 0x4F58, 0x4F65, 0x4FCE, 0x9FA0, 0x6C46, 0x7C74, 0x516E, 0x5DFD, 0x9EC9,
+    0x9998, 0x5181, 0x5914, 0x52F9, 0x530D, 0x8A07, 0x5310, 0x51EB, 0x5919, 0x5155, 0x4EA0, 0x5156,
+    0x4EB3, 0x886E, 0x88A4, 0x4EB5, 0x8114, 0x88D2, 0x7980, 0x5B34, 0x8803, 0x7FB8, 0x51AB, 0x51B1,
+    0x51BD, 0x51BC, 0x8D0E, 0x8D0F, 0x8D10, 0x8D11, 0x8D12, 0x8D13, 0x8D14, 0x8D15, 0x8D16, 0x8D17,
+    0x8D18, 0x8D19, 0x8D1A, 0x8D1B, 0x8D1C, 0x8D20, 0x8D51, 0x8D52, 0x8D57, 0x8D5F, 0x8D65, 0x8D68,
+    0x8D69, 0x8D6A, 0x8D6C, 0x8D6E, 0x8D6F, 0x8D71, 0x8D72, 0x8D78, 0x8D79, 0x8D7A, 0x8D7B, 0x8D7C,
+    0x8D7D, 0x8D7E, 0x8D7F, 0x8D80, 0x8D82, 0x8D83, 0x8D86, 0x8D87, 0x8D88, 0x8D89, 0x8D8C, 0x8D8D,
+    0x8D8E, 0x8D8F, 0x8D90, 0x8D92, 0x8D93, 0x8D95, 0x8D96, 0x8D97, 0x8D98, 0x8D99, 0x8D9A, 0x8D9B,
+    0x8D9C, 0x8D9D, 0x8D9E, 0x8DA0, 0x8DA1, 0x8DA2, 0x8DA4, 0x8DA5, 0x8DA6, 0x8DA7, 0x8DA8, 0x8DA9,
+    0x8DAA, 0x8DAB, 0x8DAC, 0x8DAD, 0x8DAE, 0x8DAF, 0x8DB0, 0x8DB2, 0x8DB6, 0x8DB7, 0x8DB9, 0x8DBB,
+    0x8DBD, 0x8DC0, 0x8DC1, 0x8DC2, 0x8DC5, 0x8DC7, 0x8DC8, 0x8DC9, 0x8DCA, 0x8DCD, 0x8DD0, 0x8DD2,
+    0x8DD3, 0x8DD4, 0x51C7, 0x5196, 0x51A2, 0x51A5, 0x8BA0, 0x8BA6, 0x8BA7, 0x8BAA, 0x8BB4, 0x8BB5,
+    0x8BB7, 0x8BC2, 0x8BC3, 0x8BCB, 0x8BCF, 0x8BCE, 0x8BD2, 0x8BD3, 0x8BD4, 0x8BD6, 0x8BD8, 0x8BD9,
+    0x8BDC, 0x8BDF, 0x8BE0, 0x8BE4, 0x8BE8, 0x8BE9, 0x8BEE, 0x8BF0, 0x8BF3, 0x8BF6, 0x8BF9, 0x8BFC,
+    0x8BFF, 0x8C00, 0x8C02, 0x8C04, 0x8C07, 0x8C0C, 0x8C0F, 0x8C11, 0x8C12, 0x8C14, 0x8C15, 0x8C16,
+    0x8C19, 0x8C1B, 0x8C18, 0x8C1D, 0x8C1F, 0x8C20, 0x8C21, 0x8C25, 0x8C27, 0x8C2A, 0x8C2B, 0x8C2E,
+    0x8C2F, 0x8C32, 0x8C33, 0x8C35, 0x8C36, 0x5369, 0x537A, 0x961D, 0x9622, 0x9621, 0x9631, 0x962A,
+    0x963D, 0x963C, 0x9642, 0x9649, 0x9654, 0x965F, 0x9667, 0x966C, 0x9672, 0x9674, 0x9688, 0x968D,
+    0x9697, 0x96B0, 0x9097, 0x909B, 0x909D, 0x9099, 0x90AC, 0x90A1, 0x90B4, 0x90B3, 0x90B6, 0x90BA,
+    0x8DD5, 0x8DD8, 0x8DD9, 0x8DDC, 0x8DE0, 0x8DE1, 0x8DE2, 0x8DE5, 0x8DE6, 0x8DE7, 0x8DE9, 0x8DED,
+    0x8DEE, 0x8DF0, 0x8DF1, 0x8DF2, 0x8DF4, 0x8DF6, 0x8DFC, 0x8DFE, 0x8DFF, 0x8E00, 0x8E01, 0x8E02,
+    0x8E03, 0x8E04, 0x8E06, 0x8E07, 0x8E08, 0x8E0B, 0x8E0D, 0x8E0E, 0x8E10, 0x8E11, 0x8E12, 0x8E13,
+    0x8E15, 0x8E16, 0x8E17, 0x8E18, 0x8E19, 0x8E1A, 0x8E1B, 0x8E1C, 0x8E20, 0x8E21, 0x8E24, 0x8E25,
+    0x8E26, 0x8E27, 0x8E28, 0x8E2B, 0x8E2D, 0x8E30, 0x8E32, 0x8E33, 0x8E34, 0x8E36, 0x8E37, 0x8E38,
+    0x8E3B, 0x8E3C, 0x8E3E, 0x8E3F, 0x8E43, 0x8E45, 0x8E46, 0x8E4C, 0x8E4D, 0x8E4E, 0x8E4F, 0x8E50,
+    0x8E53, 0x8E54, 0x8E55, 0x8E56, 0x8E57, 0x8E58, 0x8E5A, 0x8E5B, 0x8E5C, 0x8E5D, 0x8E5E, 0x8E5F,
+    0x8E60, 0x8E61, 0x8E62, 0x8E63, 0x8E64, 0x8E65, 0x8E67, 0x8E68, 0x8E6A, 0x8E6B, 0x8E6E, 0x8E71,
+    0x90B8, 0x90B0, 0x90CF, 0x90C5, 0x90BE, 0x90D0, 0x90C4, 0x90C7, 0x90D3, 0x90E6, 0x90E2, 0x90DC,
+    0x90D7, 0x90DB, 0x90EB, 0x90EF, 0x90FE, 0x9104, 0x9122, 0x911E, 0x9123, 0x9131, 0x912F, 0x9139,
+    0x9143, 0x9146, 0x520D, 0x5942, 0x52A2, 0x52AC, 0x52AD, 0x52BE, 0x54FF, 0x52D0, 0x52D6, 0x52F0,
+    0x53DF, 0x71EE, 0x77CD, 0x5EF4, 0x51F5, 0x51FC, 0x9B2F, 0x53B6, 0x5F01, 0x755A, 0x5DEF, 0x574C,
+    0x57A9, 0x57A1, 0x587E, 0x58BC, 0x58C5, 0x58D1, 0x5729, 0x572C, 0x572A, 0x5733, 0x5739, 0x572E,
+    0x572F, 0x575C, 0x573B, 0x5742, 0x5769, 0x5785, 0x576B, 0x5786, 0x577C, 0x577B, 0x5768, 0x576D,
+    0x5776, 0x5773, 0x57AD, 0x57A4, 0x578C, 0x57B2, 0x57CF, 0x57A7, 0x57B4, 0x5793, 0x57A0, 0x57D5,
+    0x57D8, 0x57DA, 0x57D9, 0x57D2, 0x57B8, 0x57F4, 0x57EF, 0x57F8, 0x57E4, 0x57DD, 0x8E73, 0x8E75,
+    0x8E77, 0x8E78, 0x8E79, 0x8E7A, 0x8E7B, 0x8E7D, 0x8E7E, 0x8E80, 0x8E82, 0x8E83, 0x8E84, 0x8E86,
+    0x8E88, 0x8E89, 0x8E8A, 0x8E8B, 0x8E8C, 0x8E8D, 0x8E8E, 0x8E91, 0x8E92, 0x8E93, 0x8E95, 0x8E96,
+    0x8E97, 0x8E98, 0x8E99, 0x8E9A, 0x8E9B, 0x8E9D, 0x8E9F, 0x8EA0, 0x8EA1, 0x8EA2, 0x8EA3, 0x8EA4,
+    0x8EA5, 0x8EA6, 0x8EA7, 0x8EA8, 0x8EA9, 0x8EAA, 0x8EAD, 0x8EAE, 0x8EB0, 0x8EB1, 0x8EB3, 0x8EB4,
+    0x8EB5, 0x8EB6, 0x8EB7, 0x8EB8, 0x8EB9, 0x8EBB, 0x8EBC, 0x8EBD, 0x8EBE, 0x8EBF, 0x8EC0, 0x8EC1,
+    0x8EC2, 0x8EC3, 0x8EC4, 0x8EC5, 0x8EC6, 0x8EC7, 0x8EC8, 0x8EC9, 0x8ECA, 0x8ECB, 0x8ECC, 0x8ECD,
+    0x8ECF, 0x8ED0, 0x8ED1, 0x8ED2, 0x8ED3, 0x8ED4, 0x8ED5, 0x8ED6, 0x8ED7, 0x8ED8, 0x8ED9, 0x8EDA,
+    0x8EDB, 0x8EDC, 0x8EDD, 0x8EDE, 0x8EDF, 0x8EE0, 0x8EE1, 0x8EE2, 0x8EE3, 0x8EE4, 0x580B, 0x580D,
+    0x57FD, 0x57ED, 0x5800, 0x581E, 0x5819, 0x5844, 0x5820, 0x5865, 0x586C, 0x5881, 0x5889, 0x589A,
+    0x5880, 0x99A8, 0x9F19, 0x61FF, 0x8279, 0x827D, 0x827F, 0x828F, 0x828A, 0x82A8, 0x8284, 0x828E,
+    0x8291, 0x8297, 0x8299, 0x82AB, 0x82B8, 0x82BE, 0x82B0, 0x82C8, 0x82CA, 0x82E3, 0x8298, 0x82B7,
+    0x82AE, 0x82CB, 0x82CC, 0x82C1, 0x82A9, 0x82B4, 0x82A1, 0x82AA, 0x829F, 0x82C4, 0x82CE, 0x82A4,
+    0x82E1, 0x8309, 0x82F7, 0x82E4, 0x830F, 0x8307, 0x82DC, 0x82F4, 0x82D2, 0x82D8, 0x830C, 0x82FB,
+    0x82D3, 0x8311, 0x831A, 0x8306, 0x8314, 0x8315, 0x82E0, 0x82D5, 0x831C, 0x8351, 0x835B, 0x835C,
+    0x8308, 0x8392, 0x833C, 0x8334, 0x8331, 0x839B, 0x835E, 0x832F, 0x834F, 0x8347, 0x8343, 0x835F,
+    0x8340, 0x8317, 0x8360, 0x832D, 0x833A, 0x8333, 0x8366, 0x8365, 0x8EE5, 0x8EE6, 0x8EE7, 0x8EE8,
+    0x8EE9, 0x8EEA, 0x8EEB, 0x8EEC, 0x8EED, 0x8EEE, 0x8EEF, 0x8EF0, 0x8EF1, 0x8EF2, 0x8EF3, 0x8EF4,
+    0x8EF5, 0x8EF6, 0x8EF7, 0x8EF8, 0x8EF9, 0x8EFA, 0x8EFB, 0x8EFC, 0x8EFD, 0x8EFE, 0x8EFF, 0x8F00,
+    0x8F01, 0x8F02, 0x8F03, 0x8F04, 0x8F05, 0x8F06, 0x8F07, 0x8F08, 0x8F09, 0x8F0A, 0x8F0B, 0x8F0C,
+    0x8F0D, 0x8F0E, 0x8F0F, 0x8F10, 0x8F11, 0x8F12, 0x8F13, 0x8F14, 0x8F15, 0x8F16, 0x8F17, 0x8F18,
+    0x8F19, 0x8F1A, 0x8F1B, 0x8F1C, 0x8F1D, 0x8F1E, 0x8F1F, 0x8F20, 0x8F21, 0x8F22, 0x8F23, 0x8F24,
+    0x8F25, 0x8F26, 0x8F27, 0x8F28, 0x8F29, 0x8F2A, 0x8F2B, 0x8F2C, 0x8F2D, 0x8F2E, 0x8F2F, 0x8F30,
+    0x8F31, 0x8F32, 0x8F33, 0x8F34, 0x8F35, 0x8F36, 0x8F37, 0x8F38, 0x8F39, 0x8F3A, 0x8F3B, 0x8F3C,
+    0x8F3D, 0x8F3E, 0x8F3F, 0x8F40, 0x8F41, 0x8F42, 0x8F43, 0x8F44, 0x8368, 0x831B, 0x8369, 0x836C,
+    0x836A, 0x836D, 0x836E, 0x83B0, 0x8378, 0x83B3, 0x83B4, 0x83A0, 0x83AA, 0x8393, 0x839C, 0x8385,
+    0x837C, 0x83B6, 0x83A9, 0x837D, 0x83B8, 0x837B, 0x8398, 0x839E, 0x83A8, 0x83BA, 0x83BC, 0x83C1,
+    0x8401, 0x83E5, 0x83D8, 0x5807, 0x8418, 0x840B, 0x83DD, 0x83FD, 0x83D6, 0x841C, 0x8438, 0x8411,
+    0x8406, 0x83D4, 0x83DF, 0x840F, 0x8403, 0x83F8, 0x83F9, 0x83EA, 0x83C5, 0x83C0, 0x8426, 0x83F0,
+    0x83E1, 0x845C, 0x8451, 0x845A, 0x8459, 0x8473, 0x8487, 0x8488, 0x847A, 0x8489, 0x8478, 0x843C,
+    0x8446, 0x8469, 0x8476, 0x848C, 0x848E, 0x8431, 0x846D, 0x84C1, 0x84CD, 0x84D0, 0x84E6, 0x84BD,
+    0x84D3, 0x84CA, 0x84BF, 0x84BA, 0x84E0, 0x84A1, 0x84B9, 0x84B4, 0x8497, 0x84E5, 0x84E3, 0x850C,
+    0x750D, 0x8538, 0x84F0, 0x8539, 0x851F, 0x853A, 0x8F45, 0x8F46, 0x8F47, 0x8F48, 0x8F49, 0x8F4A,
+    0x8F4B, 0x8F4C, 0x8F4D, 0x8F4E, 0x8F4F, 0x8F50, 0x8F51, 0x8F52, 0x8F53, 0x8F54, 0x8F55, 0x8F56,
+    0x8F57, 0x8F58, 0x8F59, 0x8F5A, 0x8F5B, 0x8F5C, 0x8F5D, 0x8F5E, 0x8F5F, 0x8F60, 0x8F61, 0x8F62,
+    0x8F63, 0x8F64, 0x8F65, 0x8F6A, 0x8F80, 0x8F8C, 0x8F92, 0x8F9D, 0x8FA0, 0x8FA1, 0x8FA2, 0x8FA4,
+    0x8FA5, 0x8FA6, 0x8FA7, 0x8FAA, 0x8FAC, 0x8FAD, 0x8FAE, 0x8FAF, 0x8FB2, 0x8FB3, 0x8FB4, 0x8FB5,
+    0x8FB7, 0x8FB8, 0x8FBA, 0x8FBB, 0x8FBC, 0x8FBF, 0x8FC0, 0x8FC3, 0x8FC6, 0x8FC9, 0x8FCA, 0x8FCB,
+    0x8FCC, 0x8FCD, 0x8FCF, 0x8FD2, 0x8FD6, 0x8FD7, 0x8FDA, 0x8FE0, 0x8FE1, 0x8FE3, 0x8FE7, 0x8FEC,
+    0x8FEF, 0x8FF1, 0x8FF2, 0x8FF4, 0x8FF5, 0x8FF6, 0x8FFA, 0x8FFB, 0x8FFC, 0x8FFE, 0x8FFF, 0x9007,
+    0x9008, 0x900C, 0x900E, 0x9013, 0x9015, 0x9018, 0x8556, 0x853B, 0x84FF, 0x84FC, 0x8559, 0x8548,
+    0x8568, 0x8564, 0x855E, 0x857A, 0x77A2, 0x8543, 0x8572, 0x857B, 0x85A4, 0x85A8, 0x8587, 0x858F,
+    0x8579, 0x85AE, 0x859C, 0x8585, 0x85B9, 0x85B7, 0x85B0, 0x85D3, 0x85C1, 0x85DC, 0x85FF, 0x8627,
+    0x8605, 0x8629, 0x8616, 0x863C, 0x5EFE, 0x5F08, 0x593C, 0x5941, 0x8037, 0x5955, 0x595A, 0x5958,
+    0x530F, 0x5C22, 0x5C25, 0x5C2C, 0x5C34, 0x624C, 0x626A, 0x629F, 0x62BB, 0x62CA, 0x62DA, 0x62D7,
+    0x62EE, 0x6322, 0x62F6, 0x6339, 0x634B, 0x6343, 0x63AD, 0x63F6, 0x6371, 0x637A, 0x638E, 0x63B4,
+    0x636D, 0x63AC, 0x638A, 0x6369, 0x63AE, 0x63BC, 0x63F2, 0x63F8, 0x63E0, 0x63FF, 0x63C4, 0x63DE,
+    0x63CE, 0x6452, 0x63C6, 0x63BE, 0x6445, 0x6441, 0x640B, 0x641B, 0x6420, 0x640C, 0x6426, 0x6421,
+    0x645E, 0x6484, 0x646D, 0x6496, 0x9019, 0x901C, 0x9023, 0x9024, 0x9025, 0x9027, 0x9028, 0x9029,
+    0x902A, 0x902B, 0x902C, 0x9030, 0x9031, 0x9032, 0x9033, 0x9034, 0x9037, 0x9039, 0x903A, 0x903D,
+    0x903F, 0x9040, 0x9043, 0x9045, 0x9046, 0x9048, 0x9049, 0x904A, 0x904B, 0x904C, 0x904E, 0x9054,
+    0x9055, 0x9056, 0x9059, 0x905A, 0x905C, 0x905D, 0x905E, 0x905F, 0x9060, 0x9061, 0x9064, 0x9066,
+    0x9067, 0x9069, 0x906A, 0x906B, 0x906C, 0x906F, 0x9070, 0x9071, 0x9072, 0x9073, 0x9076, 0x9077,
+    0x9078, 0x9079, 0x907A, 0x907B, 0x907C, 0x907E, 0x9081, 0x9084, 0x9085, 0x9086, 0x9087, 0x9089,
+    0x908A, 0x908C, 0x908D, 0x908E, 0x908F, 0x9090, 0x9092, 0x9094, 0x9096, 0x9098, 0x909A, 0x909C,
+    0x909E, 0x909F, 0x90A0, 0x90A4, 0x90A5, 0x90A7, 0x90A8, 0x90A9, 0x90AB, 0x90AD, 0x90B2, 0x90B7,
+    0x90BC, 0x90BD, 0x90BF, 0x90C0, 0x647A, 0x64B7, 0x64B8, 0x6499, 0x64BA, 0x64C0, 0x64D0, 0x64D7,
+    0x64E4, 0x64E2, 0x6509, 0x6525, 0x652E, 0x5F0B, 0x5FD2, 0x7519, 0x5F11, 0x535F, 0x53F1, 0x53FD,
+    0x53E9, 0x53E8, 0x53FB, 0x5412, 0x5416, 0x5406, 0x544B, 0x5452, 0x5453, 0x5454, 0x5456, 0x5443,
+    0x5421, 0x5457, 0x5459, 0x5423, 0x5432, 0x5482, 0x5494, 0x5477, 0x5471, 0x5464, 0x549A, 0x549B,
+    0x5484, 0x5476, 0x5466, 0x549D, 0x54D0, 0x54AD, 0x54C2, 0x54B4, 0x54D2, 0x54A7, 0x54A6, 0x54D3,
+    0x54D4, 0x5472, 0x54A3, 0x54D5, 0x54BB, 0x54BF, 0x54CC, 0x54D9, 0x54DA, 0x54DC, 0x54A9, 0x54AA,
+    0x54A4, 0x54DD, 0x54CF, 0x54DE, 0x551B, 0x54E7, 0x5520, 0x54FD, 0x5514, 0x54F3, 0x5522, 0x5523,
+    0x550F, 0x5511, 0x5527, 0x552A, 0x5567, 0x558F, 0x55B5, 0x5549, 0x556D, 0x5541, 0x5555, 0x553F,
+    0x5550, 0x553C, 0x90C2, 0x90C3, 0x90C6, 0x90C8, 0x90C9, 0x90CB, 0x90CC, 0x90CD, 0x90D2, 0x90D4,
+    0x90D5, 0x90D6, 0x90D8, 0x90D9, 0x90DA, 0x90DE, 0x90DF, 0x90E0, 0x90E3, 0x90E4, 0x90E5, 0x90E9,
+    0x90EA, 0x90EC, 0x90EE, 0x90F0, 0x90F1, 0x90F2, 0x90F3, 0x90F5, 0x90F6, 0x90F7, 0x90F9, 0x90FA,
+    0x90FB, 0x90FC, 0x90FF, 0x9100, 0x9101, 0x9103, 0x9105, 0x9106, 0x9107, 0x9108, 0x9109, 0x910A,
+    0x910B, 0x910C, 0x910D, 0x910E, 0x910F, 0x9110, 0x9111, 0x9112, 0x9113, 0x9114, 0x9115, 0x9116,
+    0x9117, 0x9118, 0x911A, 0x911B, 0x911C, 0x911D, 0x911F, 0x9120, 0x9121, 0x9124, 0x9125, 0x9126,
+    0x9127, 0x9128, 0x9129, 0x912A, 0x912B, 0x912C, 0x912D, 0x912E, 0x9130, 0x9132, 0x9133, 0x9134,
+    0x9135, 0x9136, 0x9137, 0x9138, 0x913A, 0x913B, 0x913C, 0x913D, 0x913E, 0x913F, 0x9140, 0x9141,
+    0x9142, 0x9144, 0x5537, 0x5556, 0x5575, 0x5576, 0x5577, 0x5533, 0x5530, 0x555C, 0x558B, 0x55D2,
+    0x5583, 0x55B1, 0x55B9, 0x5588, 0x5581, 0x559F, 0x557E, 0x55D6, 0x5591, 0x557B, 0x55DF, 0x55BD,
+    0x55BE, 0x5594, 0x5599, 0x55EA, 0x55F7, 0x55C9, 0x561F, 0x55D1, 0x55EB, 0x55EC, 0x55D4, 0x55E6,
+    0x55DD, 0x55C4, 0x55EF, 0x55E5, 0x55F2, 0x55F3, 0x55CC, 0x55CD, 0x55E8, 0x55F5, 0x55E4, 0x8F94,
+    0x561E, 0x5608, 0x560C, 0x5601, 0x5624, 0x5623, 0x55FE, 0x5600, 0x5627, 0x562D, 0x5658, 0x5639,
+    0x5657, 0x562C, 0x564D, 0x5662, 0x5659, 0x565C, 0x564C, 0x5654, 0x5686, 0x5664, 0x5671, 0x566B,
+    0x567B, 0x567C, 0x5685, 0x5693, 0x56AF, 0x56D4, 0x56D7, 0x56DD, 0x56E1, 0x56F5, 0x56EB, 0x56F9,
+    0x56FF, 0x5704, 0x570A, 0x5709, 0x571C, 0x5E0F, 0x5E19, 0x5E14, 0x5E11, 0x5E31, 0x5E3B, 0x5E3C,
+    0x9145, 0x9147, 0x9148, 0x9151, 0x9153, 0x9154, 0x9155, 0x9156, 0x9158, 0x9159, 0x915B, 0x915C,
+    0x915F, 0x9160, 0x9166, 0x9167, 0x9168, 0x916B, 0x916D, 0x9173, 0x917A, 0x917B, 0x917C, 0x9180,
+    0x9181, 0x9182, 0x9183, 0x9184, 0x9186, 0x9188, 0x918A, 0x918E, 0x918F, 0x9193, 0x9194, 0x9195,
+    0x9196, 0x9197, 0x9198, 0x9199, 0x919C, 0x919D, 0x919E, 0x919F, 0x91A0, 0x91A1, 0x91A4, 0x91A5,
+    0x91A6, 0x91A7, 0x91A8, 0x91A9, 0x91AB, 0x91AC, 0x91B0, 0x91B1, 0x91B2, 0x91B3, 0x91B6, 0x91B7,
+    0x91B8, 0x91B9, 0x91BB, 0x91BC, 0x91BD, 0x91BE, 0x91BF, 0x91C0, 0x91C1, 0x91C2, 0x91C3, 0x91C4,
+    0x91C5, 0x91C6, 0x91C8, 0x91CB, 0x91D0, 0x91D2, 0x91D3, 0x91D4, 0x91D5, 0x91D6, 0x91D7, 0x91D8,
+    0x91D9, 0x91DA, 0x91DB, 0x91DD, 0x91DE, 0x91DF, 0x91E0, 0x91E1, 0x91E2, 0x91E3, 0x91E4, 0x91E5,
+    0x5E37, 0x5E44, 0x5E54, 0x5E5B, 0x5E5E, 0x5E61, 0x5C8C, 0x5C7A, 0x5C8D, 0x5C90, 0x5C96, 0x5C88,
+    0x5C98, 0x5C99, 0x5C91, 0x5C9A, 0x5C9C, 0x5CB5, 0x5CA2, 0x5CBD, 0x5CAC, 0x5CAB, 0x5CB1, 0x5CA3,
+    0x5CC1, 0x5CB7, 0x5CC4, 0x5CD2, 0x5CE4, 0x5CCB, 0x5CE5, 0x5D02, 0x5D03, 0x5D27, 0x5D26, 0x5D2E,
+    0x5D24, 0x5D1E, 0x5D06, 0x5D1B, 0x5D58, 0x5D3E, 0x5D34, 0x5D3D, 0x5D6C, 0x5D5B, 0x5D6F, 0x5D5D,
+    0x5D6B, 0x5D4B, 0x5D4A, 0x5D69, 0x5D74, 0x5D82, 0x5D99, 0x5D9D, 0x8C73, 0x5DB7, 0x5DC5, 0x5F73,
+    0x5F77, 0x5F82, 0x5F87, 0x5F89, 0x5F8C, 0x5F95, 0x5F99, 0x5F9C, 0x5FA8, 0x5FAD, 0x5FB5, 0x5FBC,
+    0x8862, 0x5F61, 0x72AD, 0x72B0, 0x72B4, 0x72B7, 0x72B8, 0x72C3, 0x72C1, 0x72CE, 0x72CD, 0x72D2,
+    0x72E8, 0x72EF, 0x72E9, 0x72F2, 0x72F4, 0x72F7, 0x7301, 0x72F3, 0x7303, 0x72FA, 0x91E6, 0x91E7,
+    0x91E8, 0x91E9, 0x91EA, 0x91EB, 0x91EC, 0x91ED, 0x91EE, 0x91EF, 0x91F0, 0x91F1, 0x91F2, 0x91F3,
+    0x91F4, 0x91F5, 0x91F6, 0x91F7, 0x91F8, 0x91F9, 0x91FA, 0x91FB, 0x91FC, 0x91FD, 0x91FE, 0x91FF,
+    0x9200, 0x9201, 0x9202, 0x9203, 0x9204, 0x9205, 0x9206, 0x9207, 0x9208, 0x9209, 0x920A, 0x920B,
+    0x920C, 0x920D, 0x920E, 0x920F, 0x9210, 0x9211, 0x9212, 0x9213, 0x9214, 0x9215, 0x9216, 0x9217,
+    0x9218, 0x9219, 0x921A, 0x921B, 0x921C, 0x921D, 0x921E, 0x921F, 0x9220, 0x9221, 0x9222, 0x9223,
+    0x9224, 0x9225, 0x9226, 0x9227, 0x9228, 0x9229, 0x922A, 0x922B, 0x922C, 0x922D, 0x922E, 0x922F,
+    0x9230, 0x9231, 0x9232, 0x9233, 0x9234, 0x9235, 0x9236, 0x9237, 0x9238, 0x9239, 0x923A, 0x923B,
+    0x923C, 0x923D, 0x923E, 0x923F, 0x9240, 0x9241, 0x9242, 0x9243, 0x9244, 0x9245, 0x72FB, 0x7317,
+    0x7313, 0x7321, 0x730A, 0x731E, 0x731D, 0x7315, 0x7322, 0x7339, 0x7325, 0x732C, 0x7338, 0x7331,
+    0x7350, 0x734D, 0x7357, 0x7360, 0x736C, 0x736F, 0x737E, 0x821B, 0x5925, 0x98E7, 0x5924, 0x5902,
+    0x9963, 0x9967, 0x9968, 0x9969, 0x996A, 0x996B, 0x996C, 0x9974, 0x9977, 0x997D, 0x9980, 0x9984,
+    0x9987, 0x998A, 0x998D, 0x9990, 0x9991, 0x9993, 0x9994, 0x9995, 0x5E80, 0x5E91, 0x5E8B, 0x5E96,
+    0x5EA5, 0x5EA0, 0x5EB9, 0x5EB5, 0x5EBE, 0x5EB3, 0x8D53, 0x5ED2, 0x5ED1, 0x5EDB, 0x5EE8, 0x5EEA,
+    0x81BA, 0x5FC4, 0x5FC9, 0x5FD6, 0x5FCF, 0x6003, 0x5FEE, 0x6004, 0x5FE1, 0x5FE4, 0x5FFE, 0x6005,
+    0x6006, 0x5FEA, 0x5FED, 0x5FF8, 0x6019, 0x6035, 0x6026, 0x601B, 0x600F, 0x600D, 0x6029, 0x602B,
+    0x600A, 0x603F, 0x6021, 0x6078, 0x6079, 0x607B, 0x607A, 0x6042, 0x9246, 0x9247, 0x9248, 0x9249,
+    0x924A, 0x924B, 0x924C, 0x924D, 0x924E, 0x924F, 0x9250, 0x9251, 0x9252, 0x9253, 0x9254, 0x9255,
+    0x9256, 0x9257, 0x9258, 0x9259, 0x925A, 0x925B, 0x925C, 0x925D, 0x925E, 0x925F, 0x9260, 0x9261,
+    0x9262, 0x9263, 0x9264, 0x9265, 0x9266, 0x9267, 0x9268, 0x9269, 0x926A, 0x926B, 0x926C, 0x926D,
+    0x926E, 0x926F, 0x9270, 0x9271, 0x9272, 0x9273, 0x9275, 0x9276, 0x9277, 0x9278, 0x9279, 0x927A,
+    0x927B, 0x927C, 0x927D, 0x927E, 0x927F, 0x9280, 0x9281, 0x9282, 0x9283, 0x9284, 0x9285, 0x9286,
+    0x9287, 0x9288, 0x9289, 0x928A, 0x928B, 0x928C, 0x928D, 0x928F, 0x9290, 0x9291, 0x9292, 0x9293,
+    0x9294, 0x9295, 0x9296, 0x9297, 0x9298, 0x9299, 0x929A, 0x929B, 0x929C, 0x929D, 0x929E, 0x929F,
+    0x92A0, 0x92A1, 0x92A2, 0x92A3, 0x92A4, 0x92A5, 0x92A6, 0x92A7, 0x606A, 0x607D, 0x6096, 0x609A,
+    0x60AD, 0x609D, 0x6083, 0x6092, 0x608C, 0x609B, 0x60EC, 0x60BB, 0x60B1, 0x60DD, 0x60D8, 0x60C6,
+    0x60DA, 0x60B4, 0x6120, 0x6126, 0x6115, 0x6123, 0x60F4, 0x6100, 0x610E, 0x612B, 0x614A, 0x6175,
+    0x61AC, 0x6194, 0x61A7, 0x61B7, 0x61D4, 0x61F5, 0x5FDD, 0x96B3, 0x95E9, 0x95EB, 0x95F1, 0x95F3,
+    0x95F5, 0x95F6, 0x95FC, 0x95FE, 0x9603, 0x9604, 0x9606, 0x9608, 0x960A, 0x960B, 0x960C, 0x960D,
+    0x960F, 0x9612, 0x9615, 0x9616, 0x9617, 0x9619, 0x961A, 0x4E2C, 0x723F, 0x6215, 0x6C35, 0x6C54,
+    0x6C5C, 0x6C4A, 0x6CA3, 0x6C85, 0x6C90, 0x6C94, 0x6C8C, 0x6C68, 0x6C69, 0x6C74, 0x6C76, 0x6C86,
+    0x6CA9, 0x6CD0, 0x6CD4, 0x6CAD, 0x6CF7, 0x6CF8, 0x6CF1, 0x6CD7, 0x6CB2, 0x6CE0, 0x6CD6, 0x6CFA,
+    0x6CEB, 0x6CEE, 0x6CB1, 0x6CD3, 0x6CEF, 0x6CFE, 0x92A8, 0x92A9, 0x92AA, 0x92AB, 0x92AC, 0x92AD,
+    0x92AF, 0x92B0, 0x92B1, 0x92B2, 0x92B3, 0x92B4, 0x92B5, 0x92B6, 0x92B7, 0x92B8, 0x92B9, 0x92BA,
+    0x92BB, 0x92BC, 0x92BD, 0x92BE, 0x92BF, 0x92C0, 0x92C1, 0x92C2, 0x92C3, 0x92C4, 0x92C5, 0x92C6,
+    0x92C7, 0x92C9, 0x92CA, 0x92CB, 0x92CC, 0x92CD, 0x92CE, 0x92CF, 0x92D0, 0x92D1, 0x92D2, 0x92D3,
+    0x92D4, 0x92D5, 0x92D6, 0x92D7, 0x92D8, 0x92D9, 0x92DA, 0x92DB, 0x92DC, 0x92DD, 0x92DE, 0x92DF,
+    0x92E0, 0x92E1, 0x92E2, 0x92E3, 0x92E4, 0x92E5, 0x92E6, 0x92E7, 0x92E8, 0x92E9, 0x92EA, 0x92EB,
+    0x92EC, 0x92ED, 0x92EE, 0x92EF, 0x92F0, 0x92F1, 0x92F2, 0x92F3, 0x92F4, 0x92F5, 0x92F6, 0x92F7,
+    0x92F8, 0x92F9, 0x92FA, 0x92FB, 0x92FC, 0x92FD, 0x92FE, 0x92FF, 0x9300, 0x9301, 0x9302, 0x9303,
+    0x9304, 0x9305, 0x9306, 0x9307, 0x9308, 0x9309, 0x6D39, 0x6D27, 0x6D0C, 0x6D43, 0x6D48, 0x6D07,
+    0x6D04, 0x6D19, 0x6D0E, 0x6D2B, 0x6D4D, 0x6D2E, 0x6D35, 0x6D1A, 0x6D4F, 0x6D52, 0x6D54, 0x6D33,
+    0x6D91, 0x6D6F, 0x6D9E, 0x6DA0, 0x6D5E, 0x6D93, 0x6D94, 0x6D5C, 0x6D60, 0x6D7C, 0x6D63, 0x6E1A,
+    0x6DC7, 0x6DC5, 0x6DDE, 0x6E0E, 0x6DBF, 0x6DE0, 0x6E11, 0x6DE6, 0x6DDD, 0x6DD9, 0x6E16, 0x6DAB,
+    0x6E0C, 0x6DAE, 0x6E2B, 0x6E6E, 0x6E4E, 0x6E6B, 0x6EB2, 0x6E5F, 0x6E86, 0x6E53, 0x6E54, 0x6E32,
+    0x6E25, 0x6E44, 0x6EDF, 0x6EB1, 0x6E98, 0x6EE0, 0x6F2D, 0x6EE2, 0x6EA5, 0x6EA7, 0x6EBD, 0x6EBB,
+    0x6EB7, 0x6ED7, 0x6EB4, 0x6ECF, 0x6E8F, 0x6EC2, 0x6E9F, 0x6F62, 0x6F46, 0x6F47, 0x6F24, 0x6F15,
+    0x6EF9, 0x6F2F, 0x6F36, 0x6F4B, 0x6F74, 0x6F2A, 0x6F09, 0x6F29, 0x6F89, 0x6F8D, 0x6F8C, 0x6F78,
+    0x6F72, 0x6F7C, 0x6F7A, 0x6FD1, 0x930A, 0x930B, 0x930C, 0x930D, 0x930E, 0x930F, 0x9310, 0x9311,
+    0x9312, 0x9313, 0x9314, 0x9315, 0x9316, 0x9317, 0x9318, 0x9319, 0x931A, 0x931B, 0x931C, 0x931D,
+    0x931E, 0x931F, 0x9320, 0x9321, 0x9322, 0x9323, 0x9324, 0x9325, 0x9326, 0x9327, 0x9328, 0x9329,
+    0x932A, 0x932B, 0x932C, 0x932D, 0x932E, 0x932F, 0x9330, 0x9331, 0x9332, 0x9333, 0x9334, 0x9335,
+    0x9336, 0x9337, 0x9338, 0x9339, 0x933A, 0x933B, 0x933C, 0x933D, 0x933F, 0x9340, 0x9341, 0x9342,
+    0x9343, 0x9344, 0x9345, 0x9346, 0x9347, 0x9348, 0x9349, 0x934A, 0x934B, 0x934C, 0x934D, 0x934E,
+    0x934F, 0x9350, 0x9351, 0x9352, 0x9353, 0x9354, 0x9355, 0x9356, 0x9357, 0x9358, 0x9359, 0x935A,
+    0x935B, 0x935C, 0x935D, 0x935E, 0x935F, 0x9360, 0x9361, 0x9362, 0x9363, 0x9364, 0x9365, 0x9366,
+    0x9367, 0x9368, 0x9369, 0x936B, 0x6FC9, 0x6FA7, 0x6FB9, 0x6FB6, 0x6FC2, 0x6FE1, 0x6FEE, 0x6FDE,
+    0x6FE0, 0x6FEF, 0x701A, 0x7023, 0x701B, 0x7039, 0x7035, 0x704F, 0x705E, 0x5B80, 0x5B84, 0x5B95,
+    0x5B93, 0x5BA5, 0x5BB8, 0x752F, 0x9A9E, 0x6434, 0x5BE4, 0x5BEE, 0x8930, 0x5BF0, 0x8E47, 0x8B07,
+    0x8FB6, 0x8FD3, 0x8FD5, 0x8FE5, 0x8FEE, 0x8FE4, 0x8FE9, 0x8FE6, 0x8FF3, 0x8FE8, 0x9005, 0x9004,
+    0x900B, 0x9026, 0x9011, 0x900D, 0x9016, 0x9021, 0x9035, 0x9036, 0x902D, 0x902F, 0x9044, 0x9051,
+    0x9052, 0x9050, 0x9068, 0x9058, 0x9062, 0x905B, 0x66B9, 0x9074, 0x907D, 0x9082, 0x9088, 0x9083,
+    0x908B, 0x5F50, 0x5F57, 0x5F56, 0x5F58, 0x5C3B, 0x54AB, 0x5C50, 0x5C59, 0x5B71, 0x5C63, 0x5C66,
+    0x7FBC, 0x5F2A, 0x5F29, 0x5F2D, 0x8274, 0x5F3C, 0x9B3B, 0x5C6E, 0x5981, 0x5983, 0x598D, 0x59A9,
+    0x59AA, 0x59A3, 0x936C, 0x936D, 0x936E, 0x936F, 0x9370, 0x9371, 0x9372, 0x9373, 0x9374, 0x9375,
+    0x9376, 0x9377, 0x9378, 0x9379, 0x937A, 0x937B, 0x937C, 0x937D, 0x937E, 0x937F, 0x9380, 0x9381,
+    0x9382, 0x9383, 0x9384, 0x9385, 0x9386, 0x9387, 0x9388, 0x9389, 0x938A, 0x938B, 0x938C, 0x938D,
+    0x938E, 0x9390, 0x9391, 0x9392, 0x9393, 0x9394, 0x9395, 0x9396, 0x9397, 0x9398, 0x9399, 0x939A,
+    0x939B, 0x939C, 0x939D, 0x939E, 0x939F, 0x93A0, 0x93A1, 0x93A2, 0x93A3, 0x93A4, 0x93A5, 0x93A6,
+    0x93A7, 0x93A8, 0x93A9, 0x93AA, 0x93AB, 0x93AC, 0x93AD, 0x93AE, 0x93AF, 0x93B0, 0x93B1, 0x93B2,
+    0x93B3, 0x93B4, 0x93B5, 0x93B6, 0x93B7, 0x93B8, 0x93B9, 0x93BA, 0x93BB, 0x93BC, 0x93BD, 0x93BE,
+    0x93BF, 0x93C0, 0x93C1, 0x93C2, 0x93C3, 0x93C4, 0x93C5, 0x93C6, 0x93C7, 0x93C8, 0x93C9, 0x93CB,
+    0x93CC, 0x93CD, 0x5997, 0x59CA, 0x59AB, 0x599E, 0x59A4, 0x59D2, 0x59B2, 0x59AF, 0x59D7, 0x59BE,
+    0x5A05, 0x5A06, 0x59DD, 0x5A08, 0x59E3, 0x59D8, 0x59F9, 0x5A0C, 0x5A09, 0x5A32, 0x5A34, 0x5A11,
+    0x5A23, 0x5A13, 0x5A40, 0x5A67, 0x5A4A, 0x5A55, 0x5A3C, 0x5A62, 0x5A75, 0x80EC, 0x5AAA, 0x5A9B,
+    0x5A77, 0x5A7A, 0x5ABE, 0x5AEB, 0x5AB2, 0x5AD2, 0x5AD4, 0x5AB8, 0x5AE0, 0x5AE3, 0x5AF1, 0x5AD6,
+    0x5AE6, 0x5AD8, 0x5ADC, 0x5B09, 0x5B17, 0x5B16, 0x5B32, 0x5B37, 0x5B40, 0x5C15, 0x5C1C, 0x5B5A,
+    0x5B65, 0x5B73, 0x5B51, 0x5B53, 0x5B62, 0x9A75, 0x9A77, 0x9A78, 0x9A7A, 0x9A7F, 0x9A7D, 0x9A80,
+    0x9A81, 0x9A85, 0x9A88, 0x9A8A, 0x9A90, 0x9A92, 0x9A93, 0x9A96, 0x9A98, 0x9A9B, 0x9A9C, 0x9A9D,
+    0x9A9F, 0x9AA0, 0x9AA2, 0x9AA3, 0x9AA5, 0x9AA7, 0x7E9F, 0x7EA1, 0x7EA3, 0x7EA5, 0x7EA8, 0x7EA9,
+    0x93CE, 0x93CF, 0x93D0, 0x93D1, 0x93D2, 0x93D3, 0x93D4, 0x93D5, 0x93D7, 0x93D8, 0x93D9, 0x93DA,
+    0x93DB, 0x93DC, 0x93DD, 0x93DE, 0x93DF, 0x93E0, 0x93E1, 0x93E2, 0x93E3, 0x93E4, 0x93E5, 0x93E6,
+    0x93E7, 0x93E8, 0x93E9, 0x93EA, 0x93EB, 0x93EC, 0x93ED, 0x93EE, 0x93EF, 0x93F0, 0x93F1, 0x93F2,
+    0x93F3, 0x93F4, 0x93F5, 0x93F6, 0x93F7, 0x93F8, 0x93F9, 0x93FA, 0x93FB, 0x93FC, 0x93FD, 0x93FE,
+    0x93FF, 0x9400, 0x9401, 0x9402, 0x9403, 0x9404, 0x9405, 0x9406, 0x9407, 0x9408, 0x9409, 0x940A,
+    0x940B, 0x940C, 0x940D, 0x940E, 0x940F, 0x9410, 0x9411, 0x9412, 0x9413, 0x9414, 0x9415, 0x9416,
+    0x9417, 0x9418, 0x9419, 0x941A, 0x941B, 0x941C, 0x941D, 0x941E, 0x941F, 0x9420, 0x9421, 0x9422,
+    0x9423, 0x9424, 0x9425, 0x9426, 0x9427, 0x9428, 0x9429, 0x942A, 0x942B, 0x942C, 0x942D, 0x942E,
+    0x7EAD, 0x7EB0, 0x7EBE, 0x7EC0, 0x7EC1, 0x7EC2, 0x7EC9, 0x7ECB, 0x7ECC, 0x7ED0, 0x7ED4, 0x7ED7,
+    0x7EDB, 0x7EE0, 0x7EE1, 0x7EE8, 0x7EEB, 0x7EEE, 0x7EEF, 0x7EF1, 0x7EF2, 0x7F0D, 0x7EF6, 0x7EFA,
+    0x7EFB, 0x7EFE, 0x7F01, 0x7F02, 0x7F03, 0x7F07, 0x7F08, 0x7F0B, 0x7F0C, 0x7F0F, 0x7F11, 0x7F12,
+    0x7F17, 0x7F19, 0x7F1C, 0x7F1B, 0x7F1F, 0x7F21, 0x7F22, 0x7F23, 0x7F24, 0x7F25, 0x7F26, 0x7F27,
+    0x7F2A, 0x7F2B, 0x7F2C, 0x7F2D, 0x7F2F, 0x7F30, 0x7F31, 0x7F32, 0x7F33, 0x7F35, 0x5E7A, 0x757F,
+    0x5DDB, 0x753E, 0x9095, 0x738E, 0x7391, 0x73AE, 0x73A2, 0x739F, 0x73CF, 0x73C2, 0x73D1, 0x73B7,
+    0x73B3, 0x73C0, 0x73C9, 0x73C8, 0x73E5, 0x73D9, 0x987C, 0x740A, 0x73E9, 0x73E7, 0x73DE, 0x73BA,
+    0x73F2, 0x740F, 0x742A, 0x745B, 0x7426, 0x7425, 0x7428, 0x7430, 0x742E, 0x742C, 0x942F, 0x9430,
+    0x9431, 0x9432, 0x9433, 0x9434, 0x9435, 0x9436, 0x9437, 0x9438, 0x9439, 0x943A, 0x943B, 0x943C,
+    0x943D, 0x943F, 0x9440, 0x9441, 0x9442, 0x9443, 0x9444, 0x9445, 0x9446, 0x9447, 0x9448, 0x9449,
+    0x944A, 0x944B, 0x944C, 0x944D, 0x944E, 0x944F, 0x9450, 0x9451, 0x9452, 0x9453, 0x9454, 0x9455,
+    0x9456, 0x9457, 0x9458, 0x9459, 0x945A, 0x945B, 0x945C, 0x945D, 0x945E, 0x945F, 0x9460, 0x9461,
+    0x9462, 0x9463, 0x9464, 0x9465, 0x9466, 0x9467, 0x9468, 0x9469, 0x946A, 0x946C, 0x946D, 0x946E,
+    0x946F, 0x9470, 0x9471, 0x9472, 0x9473, 0x9474, 0x9475, 0x9476, 0x9477, 0x9478, 0x9479, 0x947A,
+    0x947B, 0x947C, 0x947D, 0x947E, 0x947F, 0x9480, 0x9481, 0x9482, 0x9483, 0x9484, 0x9491, 0x9496,
+    0x9498, 0x94C7, 0x94CF, 0x94D3, 0x94D4, 0x94DA, 0x94E6, 0x94FB, 0x951C, 0x9520, 0x741B, 0x741A,
+    0x7441, 0x745C, 0x7457, 0x7455, 0x7459, 0x7477, 0x746D, 0x747E, 0x749C, 0x748E, 0x7480, 0x7481,
+    0x7487, 0x748B, 0x749E, 0x74A8, 0x74A9, 0x7490, 0x74A7, 0x74D2, 0x74BA, 0x97EA, 0x97EB, 0x97EC,
+    0x674C, 0x6753, 0x675E, 0x6748, 0x6769, 0x67A5, 0x6787, 0x676A, 0x6773, 0x6798, 0x67A7, 0x6775,
+    0x67A8, 0x679E, 0x67AD, 0x678B, 0x6777, 0x677C, 0x67F0, 0x6809, 0x67D8, 0x680A, 0x67E9, 0x67B0,
+    0x680C, 0x67D9, 0x67B5, 0x67DA, 0x67B3, 0x67DD, 0x6800, 0x67C3, 0x67B8, 0x67E2, 0x680E, 0x67C1,
+    0x67FD, 0x6832, 0x6833, 0x6860, 0x6861, 0x684E, 0x6862, 0x6844, 0x6864, 0x6883, 0x681D, 0x6855,
+    0x6866, 0x6841, 0x6867, 0x6840, 0x683E, 0x684A, 0x6849, 0x6829, 0x68B5, 0x688F, 0x6874, 0x6877,
+    0x6893, 0x686B, 0x68C2, 0x696E, 0x68FC, 0x691F, 0x6920, 0x68F9, 0x9527, 0x9533, 0x953D, 0x9543,
+    0x9548, 0x954B, 0x9555, 0x955A, 0x9560, 0x956E, 0x9574, 0x9575, 0x9577, 0x9578, 0x9579, 0x957A,
+    0x957B, 0x957C, 0x957D, 0x957E, 0x9580, 0x9581, 0x9582, 0x9583, 0x9584, 0x9585, 0x9586, 0x9587,
+    0x9588, 0x9589, 0x958A, 0x958B, 0x958C, 0x958D, 0x958E, 0x958F, 0x9590, 0x9591, 0x9592, 0x9593,
+    0x9594, 0x9595, 0x9596, 0x9597, 0x9598, 0x9599, 0x959A, 0x959B, 0x959C, 0x959D, 0x959E, 0x959F,
+    0x95A0, 0x95A1, 0x95A2, 0x95A3, 0x95A4, 0x95A5, 0x95A6, 0x95A7, 0x95A8, 0x95A9, 0x95AA, 0x95AB,
+    0x95AC, 0x95AD, 0x95AE, 0x95AF, 0x95B0, 0x95B1, 0x95B2, 0x95B3, 0x95B4, 0x95B5, 0x95B6, 0x95B7,
+    0x95B8, 0x95B9, 0x95BA, 0x95BB, 0x95BC, 0x95BD, 0x95BE, 0x95BF, 0x95C0, 0x95C1, 0x95C2, 0x95C3,
+    0x95C4, 0x95C5, 0x95C6, 0x95C7, 0x95C8, 0x95C9, 0x95CA, 0x95CB, 0x6924, 0x68F0, 0x690B, 0x6901,
+    0x6957, 0x68E3, 0x6910, 0x6971, 0x6939, 0x6960, 0x6942, 0x695D, 0x6984, 0x696B, 0x6980, 0x6998,
+    0x6978, 0x6934, 0x69CC, 0x6987, 0x6988, 0x69CE, 0x6989, 0x6966, 0x6963, 0x6979, 0x699B, 0x69A7,
+    0x69BB, 0x69AB, 0x69AD, 0x69D4, 0x69B1, 0x69C1, 0x69CA, 0x69DF, 0x6995, 0x69E0, 0x698D, 0x69FF,
+    0x6A2F, 0x69ED, 0x6A17, 0x6A18, 0x6A65, 0x69F2, 0x6A44, 0x6A3E, 0x6AA0, 0x6A50, 0x6A5B, 0x6A35,
+    0x6A8E, 0x6A79, 0x6A3D, 0x6A28, 0x6A58, 0x6A7C, 0x6A91, 0x6A90, 0x6AA9, 0x6A97, 0x6AAB, 0x7337,
+    0x7352, 0x6B81, 0x6B82, 0x6B87, 0x6B84, 0x6B92, 0x6B93, 0x6B8D, 0x6B9A, 0x6B9B, 0x6BA1, 0x6BAA,
+    0x8F6B, 0x8F6D, 0x8F71, 0x8F72, 0x8F73, 0x8F75, 0x8F76, 0x8F78, 0x8F77, 0x8F79, 0x8F7A, 0x8F7C,
+    0x8F7E, 0x8F81, 0x8F82, 0x8F84, 0x8F87, 0x8F8B, 0x95CC, 0x95CD, 0x95CE, 0x95CF, 0x95D0, 0x95D1,
+    0x95D2, 0x95D3, 0x95D4, 0x95D5, 0x95D6, 0x95D7, 0x95D8, 0x95D9, 0x95DA, 0x95DB, 0x95DC, 0x95DD,
+    0x95DE, 0x95DF, 0x95E0, 0x95E1, 0x95E2, 0x95E3, 0x95E4, 0x95E5, 0x95E6, 0x95E7, 0x95EC, 0x95FF,
+    0x9607, 0x9613, 0x9618, 0x961B, 0x961E, 0x9620, 0x9623, 0x9624, 0x9625, 0x9626, 0x9627, 0x9628,
+    0x9629, 0x962B, 0x962C, 0x962D, 0x962F, 0x9630, 0x9637, 0x9638, 0x9639, 0x963A, 0x963E, 0x9641,
+    0x9643, 0x964A, 0x964E, 0x964F, 0x9651, 0x9652, 0x9653, 0x9656, 0x9657, 0x9658, 0x9659, 0x965A,
+    0x965C, 0x965D, 0x965E, 0x9660, 0x9663, 0x9665, 0x9666, 0x966B, 0x966D, 0x966E, 0x966F, 0x9670,
+    0x9671, 0x9673, 0x9678, 0x9679, 0x967A, 0x967B, 0x967C, 0x967D, 0x967E, 0x967F, 0x9680, 0x9681,
+    0x9682, 0x9683, 0x9684, 0x9687, 0x9689, 0x968A, 0x8F8D, 0x8F8E, 0x8F8F, 0x8F98, 0x8F9A, 0x8ECE,
+    0x620B, 0x6217, 0x621B, 0x621F, 0x6222, 0x6221, 0x6225, 0x6224, 0x622C, 0x81E7, 0x74EF, 0x74F4,
+    0x74FF, 0x750F, 0x7511, 0x7513, 0x6534, 0x65EE, 0x65EF, 0x65F0, 0x660A, 0x6619, 0x6772, 0x6603,
+    0x6615, 0x6600, 0x7085, 0x66F7, 0x661D, 0x6634, 0x6631, 0x6636, 0x6635, 0x8006, 0x665F, 0x6654,
+    0x6641, 0x664F, 0x6656, 0x6661, 0x6657, 0x6677, 0x6684, 0x668C, 0x66A7, 0x669D, 0x66BE, 0x66DB,
+    0x66DC, 0x66E6, 0x66E9, 0x8D32, 0x8D33, 0x8D36, 0x8D3B, 0x8D3D, 0x8D40, 0x8D45, 0x8D46, 0x8D48,
+    0x8D49, 0x8D47, 0x8D4D, 0x8D55, 0x8D59, 0x89C7, 0x89CA, 0x89CB, 0x89CC, 0x89CE, 0x89CF, 0x89D0,
+    0x89D1, 0x726E, 0x729F, 0x725D, 0x7266, 0x726F, 0x727E, 0x727F, 0x7284, 0x728B, 0x728D, 0x728F,
+    0x7292, 0x6308, 0x6332, 0x63B0, 0x968C, 0x968E, 0x9691, 0x9692, 0x9693, 0x9695, 0x9696, 0x969A,
+    0x969B, 0x969D, 0x969E, 0x969F, 0x96A0, 0x96A1, 0x96A2, 0x96A3, 0x96A4, 0x96A5, 0x96A6, 0x96A8,
+    0x96A9, 0x96AA, 0x96AB, 0x96AC, 0x96AD, 0x96AE, 0x96AF, 0x96B1, 0x96B2, 0x96B4, 0x96B5, 0x96B7,
+    0x96B8, 0x96BA, 0x96BB, 0x96BF, 0x96C2, 0x96C3, 0x96C8, 0x96CA, 0x96CB, 0x96D0, 0x96D1, 0x96D3,
+    0x96D4, 0x96D6, 0x96D7, 0x96D8, 0x96D9, 0x96DA, 0x96DB, 0x96DC, 0x96DD, 0x96DE, 0x96DF, 0x96E1,
+    0x96E2, 0x96E3, 0x96E4, 0x96E5, 0x96E6, 0x96E7, 0x96EB, 0x96EC, 0x96ED, 0x96EE, 0x96F0, 0x96F1,
+    0x96F2, 0x96F4, 0x96F5, 0x96F8, 0x96FA, 0x96FB, 0x96FC, 0x96FD, 0x96FF, 0x9702, 0x9703, 0x9705,
+    0x970A, 0x970B, 0x970C, 0x9710, 0x9711, 0x9712, 0x9714, 0x9715, 0x9717, 0x9718, 0x9719, 0x971A,
+    0x971B, 0x971D, 0x971F, 0x9720, 0x643F, 0x64D8, 0x8004, 0x6BEA, 0x6BF3, 0x6BFD, 0x6BF5, 0x6BF9,
+    0x6C05, 0x6C07, 0x6C06, 0x6C0D, 0x6C15, 0x6C18, 0x6C19, 0x6C1A, 0x6C21, 0x6C29, 0x6C24, 0x6C2A,
+    0x6C32, 0x6535, 0x6555, 0x656B, 0x724D, 0x7252, 0x7256, 0x7230, 0x8662, 0x5216, 0x809F, 0x809C,
+    0x8093, 0x80BC, 0x670A, 0x80BD, 0x80B1, 0x80AB, 0x80AD, 0x80B4, 0x80B7, 0x80E7, 0x80E8, 0x80E9,
+    0x80EA, 0x80DB, 0x80C2, 0x80C4, 0x80D9, 0x80CD, 0x80D7, 0x6710, 0x80DD, 0x80EB, 0x80F1, 0x80F4,
+    0x80ED, 0x810D, 0x810E, 0x80F2, 0x80FC, 0x6715, 0x8112, 0x8C5A, 0x8136, 0x811E, 0x812C, 0x8118,
+    0x8132, 0x8148, 0x814C, 0x8153, 0x8174, 0x8159, 0x815A, 0x8171, 0x8160, 0x8169, 0x817C, 0x817D,
+    0x816D, 0x8167, 0x584D, 0x5AB5, 0x8188, 0x8182, 0x8191, 0x6ED5, 0x81A3, 0x81AA, 0x81CC, 0x6726,
+    0x81CA, 0x81BB, 0x9721, 0x9722, 0x9723, 0x9724, 0x9725, 0x9726, 0x9727, 0x9728, 0x9729, 0x972B,
+    0x972C, 0x972E, 0x972F, 0x9731, 0x9733, 0x9734, 0x9735, 0x9736, 0x9737, 0x973A, 0x973B, 0x973C,
+    0x973D, 0x973F, 0x9740, 0x9741, 0x9742, 0x9743, 0x9744, 0x9745, 0x9746, 0x9747, 0x9748, 0x9749,
+    0x974A, 0x974B, 0x974C, 0x974D, 0x974E, 0x974F, 0x9750, 0x9751, 0x9754, 0x9755, 0x9757, 0x9758,
+    0x975A, 0x975C, 0x975D, 0x975F, 0x9763, 0x9764, 0x9766, 0x9767, 0x9768, 0x976A, 0x976B, 0x976C,
+    0x976D, 0x976E, 0x976F, 0x9770, 0x9771, 0x9772, 0x9775, 0x9777, 0x9778, 0x9779, 0x977A, 0x977B,
+    0x977D, 0x977E, 0x977F, 0x9780, 0x9781, 0x9782, 0x9783, 0x9784, 0x9786, 0x9787, 0x9788, 0x9789,
+    0x978A, 0x978C, 0x978E, 0x978F, 0x9790, 0x9793, 0x9795, 0x9796, 0x9797, 0x9799, 0x979A, 0x979B,
+    0x979C, 0x979D, 0x81C1, 0x81A6, 0x6B24, 0x6B37, 0x6B39, 0x6B43, 0x6B46, 0x6B59, 0x98D1, 0x98D2,
+    0x98D3, 0x98D5, 0x98D9, 0x98DA, 0x6BB3, 0x5F40, 0x6BC2, 0x89F3, 0x6590, 0x9F51, 0x6593, 0x65BC,
+    0x65C6, 0x65C4, 0x65C3, 0x65CC, 0x65CE, 0x65D2, 0x65D6, 0x7080, 0x709C, 0x7096, 0x709D, 0x70BB,
+    0x70C0, 0x70B7, 0x70AB, 0x70B1, 0x70E8, 0x70CA, 0x7110, 0x7113, 0x7116, 0x712F, 0x7131, 0x7173,
+    0x715C, 0x7168, 0x7145, 0x7172, 0x714A, 0x7178, 0x717A, 0x7198, 0x71B3, 0x71B5, 0x71A8, 0x71A0,
+    0x71E0, 0x71D4, 0x71E7, 0x71F9, 0x721D, 0x7228, 0x706C, 0x7118, 0x7166, 0x71B9, 0x623E, 0x623D,
+    0x6243, 0x6248, 0x6249, 0x793B, 0x7940, 0x7946, 0x7949, 0x795B, 0x795C, 0x7953, 0x795A, 0x7962,
+    0x7957, 0x7960, 0x796F, 0x7967, 0x797A, 0x7985, 0x798A, 0x799A, 0x79A7, 0x79B3, 0x5FD1, 0x5FD0,
+    0x979E, 0x979F, 0x97A1, 0x97A2, 0x97A4, 0x97A5, 0x97A6, 0x97A7, 0x97A8, 0x97A9, 0x97AA, 0x97AC,
+    0x97AE, 0x97B0, 0x97B1, 0x97B3, 0x97B5, 0x97B6, 0x97B7, 0x97B8, 0x97B9, 0x97BA, 0x97BB, 0x97BC,
+    0x97BD, 0x97BE, 0x97BF, 0x97C0, 0x97C1, 0x97C2, 0x97C3, 0x97C4, 0x97C5, 0x97C6, 0x97C7, 0x97C8,
+    0x97C9, 0x97CA, 0x97CB, 0x97CC, 0x97CD, 0x97CE, 0x97CF, 0x97D0, 0x97D1, 0x97D2, 0x97D3, 0x97D4,
+    0x97D5, 0x97D6, 0x97D7, 0x97D8, 0x97D9, 0x97DA, 0x97DB, 0x97DC, 0x97DD, 0x97DE, 0x97DF, 0x97E0,
+    0x97E1, 0x97E2, 0x97E3, 0x97E4, 0x97E5, 0x97E8, 0x97EE, 0x97EF, 0x97F0, 0x97F1, 0x97F2, 0x97F4,
+    0x97F7, 0x97F8, 0x97F9, 0x97FA, 0x97FB, 0x97FC, 0x97FD, 0x97FE, 0x97FF, 0x9800, 0x9801, 0x9802,
+    0x9803, 0x9804, 0x9805, 0x9806, 0x9807, 0x9808, 0x9809, 0x980A, 0x980B, 0x980C, 0x980D, 0x980E,
+    0x603C, 0x605D, 0x605A, 0x6067, 0x6041, 0x6059, 0x6063, 0x60AB, 0x6106, 0x610D, 0x615D, 0x61A9,
+    0x619D, 0x61CB, 0x61D1, 0x6206, 0x8080, 0x807F, 0x6C93, 0x6CF6, 0x6DFC, 0x77F6, 0x77F8, 0x7800,
+    0x7809, 0x7817, 0x7818, 0x7811, 0x65AB, 0x782D, 0x781C, 0x781D, 0x7839, 0x783A, 0x783B, 0x781F,
+    0x783C, 0x7825, 0x782C, 0x7823, 0x7829, 0x784E, 0x786D, 0x7856, 0x7857, 0x7826, 0x7850, 0x7847,
+    0x784C, 0x786A, 0x789B, 0x7893, 0x789A, 0x7887, 0x789C, 0x78A1, 0x78A3, 0x78B2, 0x78B9, 0x78A5,
+    0x78D4, 0x78D9, 0x78C9, 0x78EC, 0x78F2, 0x7905, 0x78F4, 0x7913, 0x7924, 0x791E, 0x7934, 0x9F9B,
+    0x9EF9, 0x9EFB, 0x9EFC, 0x76F1, 0x7704, 0x770D, 0x76F9, 0x7707, 0x7708, 0x771A, 0x7722, 0x7719,
+    0x772D, 0x7726, 0x7735, 0x7738, 0x7750, 0x7751, 0x7747, 0x7743, 0x775A, 0x7768, 0x980F, 0x9810,
+    0x9811, 0x9812, 0x9813, 0x9814, 0x9815, 0x9816, 0x9817, 0x9818, 0x9819, 0x981A, 0x981B, 0x981C,
+    0x981D, 0x981E, 0x981F, 0x9820, 0x9821, 0x9822, 0x9823, 0x9824, 0x9825, 0x9826, 0x9827, 0x9828,
+    0x9829, 0x982A, 0x982B, 0x982C, 0x982D, 0x982E, 0x982F, 0x9830, 0x9831, 0x9832, 0x9833, 0x9834,
+    0x9835, 0x9836, 0x9837, 0x9838, 0x9839, 0x983A, 0x983B, 0x983C, 0x983D, 0x983E, 0x983F, 0x9840,
+    0x9841, 0x9842, 0x9843, 0x9844, 0x9845, 0x9846, 0x9847, 0x9848, 0x9849, 0x984A, 0x984B, 0x984C,
+    0x984D, 0x984E, 0x984F, 0x9850, 0x9851, 0x9852, 0x9853, 0x9854, 0x9855, 0x9856, 0x9857, 0x9858,
+    0x9859, 0x985A, 0x985B, 0x985C, 0x985D, 0x985E, 0x985F, 0x9860, 0x9861, 0x9862, 0x9863, 0x9864,
+    0x9865, 0x9866, 0x9867, 0x9868, 0x9869, 0x986A, 0x986B, 0x986C, 0x986D, 0x986E, 0x7762, 0x7765,
+    0x777F, 0x778D, 0x777D, 0x7780, 0x778C, 0x7791, 0x779F, 0x77A0, 0x77B0, 0x77B5, 0x77BD, 0x753A,
+    0x7540, 0x754E, 0x754B, 0x7548, 0x755B, 0x7572, 0x7579, 0x7583, 0x7F58, 0x7F61, 0x7F5F, 0x8A48,
+    0x7F68, 0x7F74, 0x7F71, 0x7F79, 0x7F81, 0x7F7E, 0x76CD, 0x76E5, 0x8832, 0x9485, 0x9486, 0x9487,
+    0x948B, 0x948A, 0x948C, 0x948D, 0x948F, 0x9490, 0x9494, 0x9497, 0x9495, 0x949A, 0x949B, 0x949C,
+    0x94A3, 0x94A4, 0x94AB, 0x94AA, 0x94AD, 0x94AC, 0x94AF, 0x94B0, 0x94B2, 0x94B4, 0x94B6, 0x94B7,
+    0x94B8, 0x94B9, 0x94BA, 0x94BC, 0x94BD, 0x94BF, 0x94C4, 0x94C8, 0x94C9, 0x94CA, 0x94CB, 0x94CC,
+    0x94CD, 0x94CE, 0x94D0, 0x94D1, 0x94D2, 0x94D5, 0x94D6, 0x94D7, 0x94D9, 0x94D8, 0x94DB, 0x94DE,
+    0x94DF, 0x94E0, 0x94E2, 0x94E4, 0x94E5, 0x94E7, 0x94E8, 0x94EA, 0x986F, 0x9870, 0x9871, 0x9872,
+    0x9873, 0x9874, 0x988B, 0x988E, 0x9892, 0x9895, 0x9899, 0x98A3, 0x98A8, 0x98A9, 0x98AA, 0x98AB,
+    0x98AC, 0x98AD, 0x98AE, 0x98AF, 0x98B0, 0x98B1, 0x98B2, 0x98B3, 0x98B4, 0x98B5, 0x98B6, 0x98B7,
+    0x98B8, 0x98B9, 0x98BA, 0x98BB, 0x98BC, 0x98BD, 0x98BE, 0x98BF, 0x98C0, 0x98C1, 0x98C2, 0x98C3,
+    0x98C4, 0x98C5, 0x98C6, 0x98C7, 0x98C8, 0x98C9, 0x98CA, 0x98CB, 0x98CC, 0x98CD, 0x98CF, 0x98D0,
+    0x98D4, 0x98D6, 0x98D7, 0x98DB, 0x98DC, 0x98DD, 0x98E0, 0x98E1, 0x98E2, 0x98E3, 0x98E4, 0x98E5,
+    0x98E6, 0x98E9, 0x98EA, 0x98EB, 0x98EC, 0x98ED, 0x98EE, 0x98EF, 0x98F0, 0x98F1, 0x98F2, 0x98F3,
+    0x98F4, 0x98F5, 0x98F6, 0x98F7, 0x98F8, 0x98F9, 0x98FA, 0x98FB, 0x98FC, 0x98FD, 0x98FE, 0x98FF,
+    0x9900, 0x9901, 0x9902, 0x9903, 0x9904, 0x9905, 0x9906, 0x9907, 0x94E9, 0x94EB, 0x94EE, 0x94EF,
+    0x94F3, 0x94F4, 0x94F5, 0x94F7, 0x94F9, 0x94FC, 0x94FD, 0x94FF, 0x9503, 0x9502, 0x9506, 0x9507,
+    0x9509, 0x950A, 0x950D, 0x950E, 0x950F, 0x9512, 0x9513, 0x9514, 0x9515, 0x9516, 0x9518, 0x951B,
+    0x951D, 0x951E, 0x951F, 0x9522, 0x952A, 0x952B, 0x9529, 0x952C, 0x9531, 0x9532, 0x9534, 0x9536,
+    0x9537, 0x9538, 0x953C, 0x953E, 0x953F, 0x9542, 0x9535, 0x9544, 0x9545, 0x9546, 0x9549, 0x954C,
+    0x954E, 0x954F, 0x9552, 0x9553, 0x9554, 0x9556, 0x9557, 0x9558, 0x9559, 0x955B, 0x955E, 0x955F,
+    0x955D, 0x9561, 0x9562, 0x9564, 0x9565, 0x9566, 0x9567, 0x9568, 0x9569, 0x956A, 0x956B, 0x956C,
+    0x956F, 0x9571, 0x9572, 0x9573, 0x953A, 0x77E7, 0x77EC, 0x96C9, 0x79D5, 0x79ED, 0x79E3, 0x79EB,
+    0x7A06, 0x5D47, 0x7A03, 0x7A02, 0x7A1E, 0x7A14, 0x9908, 0x9909, 0x990A, 0x990B, 0x990C, 0x990E,
+    0x990F, 0x9911, 0x9912, 0x9913, 0x9914, 0x9915, 0x9916, 0x9917, 0x9918, 0x9919, 0x991A, 0x991B,
+    0x991C, 0x991D, 0x991E, 0x991F, 0x9920, 0x9921, 0x9922, 0x9923, 0x9924, 0x9925, 0x9926, 0x9927,
+    0x9928, 0x9929, 0x992A, 0x992B, 0x992C, 0x992D, 0x992F, 0x9930, 0x9931, 0x9932, 0x9933, 0x9934,
+    0x9935, 0x9936, 0x9937, 0x9938, 0x9939, 0x993A, 0x993B, 0x993C, 0x993D, 0x993E, 0x993F, 0x9940,
+    0x9941, 0x9942, 0x9943, 0x9944, 0x9945, 0x9946, 0x9947, 0x9948, 0x9949, 0x994A, 0x994B, 0x994C,
+    0x994D, 0x994E, 0x994F, 0x9950, 0x9951, 0x9952, 0x9953, 0x9956, 0x9957, 0x9958, 0x9959, 0x995A,
+    0x995B, 0x995C, 0x995D, 0x995E, 0x995F, 0x9960, 0x9961, 0x9962, 0x9964, 0x9966, 0x9973, 0x9978,
+    0x9979, 0x997B, 0x997E, 0x9982, 0x9983, 0x9989, 0x7A39, 0x7A37, 0x7A51, 0x9ECF, 0x99A5, 0x7A70,
+    0x7688, 0x768E, 0x7693, 0x7699, 0x76A4, 0x74DE, 0x74E0, 0x752C, 0x9E20, 0x9E22, 0x9E28, 0x9E29,
+    0x9E2A, 0x9E2B, 0x9E2C, 0x9E32, 0x9E31, 0x9E36, 0x9E38, 0x9E37, 0x9E39, 0x9E3A, 0x9E3E, 0x9E41,
+    0x9E42, 0x9E44, 0x9E46, 0x9E47, 0x9E48, 0x9E49, 0x9E4B, 0x9E4C, 0x9E4E, 0x9E51, 0x9E55, 0x9E57,
+    0x9E5A, 0x9E5B, 0x9E5C, 0x9E5E, 0x9E63, 0x9E66, 0x9E67, 0x9E68, 0x9E69, 0x9E6A, 0x9E6B, 0x9E6C,
+    0x9E71, 0x9E6D, 0x9E73, 0x7592, 0x7594, 0x7596, 0x75A0, 0x759D, 0x75AC, 0x75A3, 0x75B3, 0x75B4,
+    0x75B8, 0x75C4, 0x75B1, 0x75B0, 0x75C3, 0x75C2, 0x75D6, 0x75CD, 0x75E3, 0x75E8, 0x75E6, 0x75E4,
+    0x75EB, 0x75E7, 0x7603, 0x75F1, 0x75FC, 0x75FF, 0x7610, 0x7600, 0x7605, 0x760C, 0x7617, 0x760A,
+    0x7625, 0x7618, 0x7615, 0x7619, 0x998C, 0x998E, 0x999A, 0x999B, 0x999C, 0x999D, 0x999E, 0x999F,
+    0x99A0, 0x99A1, 0x99A2, 0x99A3, 0x99A4, 0x99A6, 0x99A7, 0x99A9, 0x99AA, 0x99AB, 0x99AC, 0x99AD,
+    0x99AE, 0x99AF, 0x99B0, 0x99B1, 0x99B2, 0x99B3, 0x99B4, 0x99B5, 0x99B6, 0x99B7, 0x99B8, 0x99B9,
+    0x99BA, 0x99BB, 0x99BC, 0x99BD, 0x99BE, 0x99BF, 0x99C0, 0x99C1, 0x99C2, 0x99C3, 0x99C4, 0x99C5,
+    0x99C6, 0x99C7, 0x99C8, 0x99C9, 0x99CA, 0x99CB, 0x99CC, 0x99CD, 0x99CE, 0x99CF, 0x99D0, 0x99D1,
+    0x99D2, 0x99D3, 0x99D4, 0x99D5, 0x99D6, 0x99D7, 0x99D8, 0x99D9, 0x99DA, 0x99DB, 0x99DC, 0x99DD,
+    0x99DE, 0x99DF, 0x99E0, 0x99E1, 0x99E2, 0x99E3, 0x99E4, 0x99E5, 0x99E6, 0x99E7, 0x99E8, 0x99E9,
+    0x99EA, 0x99EB, 0x99EC, 0x99ED, 0x99EE, 0x99EF, 0x99F0, 0x99F1, 0x99F2, 0x99F3, 0x99F4, 0x99F5,
+    0x99F6, 0x99F7, 0x99F8, 0x99F9, 0x761B, 0x763C, 0x7622, 0x7620, 0x7640, 0x762D, 0x7630, 0x763F,
+    0x7635, 0x7643, 0x763E, 0x7633, 0x764D, 0x765E, 0x7654, 0x765C, 0x7656, 0x766B, 0x766F, 0x7FCA,
+    0x7AE6, 0x7A78, 0x7A79, 0x7A80, 0x7A86, 0x7A88, 0x7A95, 0x7AA6, 0x7AA0, 0x7AAC, 0x7AA8, 0x7AAD,
+    0x7AB3, 0x8864, 0x8869, 0x8872, 0x887D, 0x887F, 0x8882, 0x88A2, 0x88C6, 0x88B7, 0x88BC, 0x88C9,
+    0x88E2, 0x88CE, 0x88E3, 0x88E5, 0x88F1, 0x891A, 0x88FC, 0x88E8, 0x88FE, 0x88F0, 0x8921, 0x8919,
+    0x8913, 0x891B, 0x890A, 0x8934, 0x892B, 0x8936, 0x8941, 0x8966, 0x897B, 0x758B, 0x80E5, 0x76B2,
+    0x76B4, 0x77DC, 0x8012, 0x8014, 0x8016, 0x801C, 0x8020, 0x8022, 0x8025, 0x8026, 0x8027, 0x8029,
+    0x8028, 0x8031, 0x800B, 0x8035, 0x8043, 0x8046, 0x804D, 0x8052, 0x8069, 0x8071, 0x8983, 0x9878,
+    0x9880, 0x9883, 0x99FA, 0x99FB, 0x99FC, 0x99FD, 0x99FE, 0x99FF, 0x9A00, 0x9A01, 0x9A02, 0x9A03,
+    0x9A04, 0x9A05, 0x9A06, 0x9A07, 0x9A08, 0x9A09, 0x9A0A, 0x9A0B, 0x9A0C, 0x9A0D, 0x9A0E, 0x9A0F,
+    0x9A10, 0x9A11, 0x9A12, 0x9A13, 0x9A14, 0x9A15, 0x9A16, 0x9A17, 0x9A18, 0x9A19, 0x9A1A, 0x9A1B,
+    0x9A1C, 0x9A1D, 0x9A1E, 0x9A1F, 0x9A20, 0x9A21, 0x9A22, 0x9A23, 0x9A24, 0x9A25, 0x9A26, 0x9A27,
+    0x9A28, 0x9A29, 0x9A2A, 0x9A2B, 0x9A2C, 0x9A2D, 0x9A2E, 0x9A2F, 0x9A30, 0x9A31, 0x9A32, 0x9A33,
+    0x9A34, 0x9A35, 0x9A36, 0x9A37, 0x9A38, 0x9A39, 0x9A3A, 0x9A3B, 0x9A3C, 0x9A3D, 0x9A3E, 0x9A3F,
+    0x9A40, 0x9A41, 0x9A42, 0x9A43, 0x9A44, 0x9A45, 0x9A46, 0x9A47, 0x9A48, 0x9A49, 0x9A4A, 0x9A4B,
+    0x9A4C, 0x9A4D, 0x9A4E, 0x9A4F, 0x9A50, 0x9A51, 0x9A52, 0x9A53, 0x9A54, 0x9A55, 0x9A56, 0x9A57,
+    0x9A58, 0x9A59, 0x9889, 0x988C, 0x988D, 0x988F, 0x9894, 0x989A, 0x989B, 0x989E, 0x989F, 0x98A1,
+    0x98A2, 0x98A5, 0x98A6, 0x864D, 0x8654, 0x866C, 0x866E, 0x867F, 0x867A, 0x867C, 0x867B, 0x86A8,
+    0x868D, 0x868B, 0x86AC, 0x869D, 0x86A7, 0x86A3, 0x86AA, 0x8693, 0x86A9, 0x86B6, 0x86C4, 0x86B5,
+    0x86CE, 0x86B0, 0x86BA, 0x86B1, 0x86AF, 0x86C9, 0x86CF, 0x86B4, 0x86E9, 0x86F1, 0x86F2, 0x86ED,
+    0x86F3, 0x86D0, 0x8713, 0x86DE, 0x86F4, 0x86DF, 0x86D8, 0x86D1, 0x8703, 0x8707, 0x86F8, 0x8708,
+    0x870A, 0x870D, 0x8709, 0x8723, 0x873B, 0x871E, 0x8725, 0x872E, 0x871A, 0x873E, 0x8748, 0x8734,
+    0x8731, 0x8729, 0x8737, 0x873F, 0x8782, 0x8722, 0x877D, 0x877E, 0x877B, 0x8760, 0x8770, 0x874C,
+    0x876E, 0x878B, 0x8753, 0x8763, 0x877C, 0x8764, 0x8759, 0x8765, 0x8793, 0x87AF, 0x87A8, 0x87D2,
+    0x9A5A, 0x9A5B, 0x9A5C, 0x9A5D, 0x9A5E, 0x9A5F, 0x9A60, 0x9A61, 0x9A62, 0x9A63, 0x9A64, 0x9A65,
+    0x9A66, 0x9A67, 0x9A68, 0x9A69, 0x9A6A, 0x9A6B, 0x9A72, 0x9A83, 0x9A89, 0x9A8D, 0x9A8E, 0x9A94,
+    0x9A95, 0x9A99, 0x9AA6, 0x9AA9, 0x9AAA, 0x9AAB, 0x9AAC, 0x9AAD, 0x9AAE, 0x9AAF, 0x9AB2, 0x9AB3,
+    0x9AB4, 0x9AB5, 0x9AB9, 0x9ABB, 0x9ABD, 0x9ABE, 0x9ABF, 0x9AC3, 0x9AC4, 0x9AC6, 0x9AC7, 0x9AC8,
+    0x9AC9, 0x9ACA, 0x9ACD, 0x9ACE, 0x9ACF, 0x9AD0, 0x9AD2, 0x9AD4, 0x9AD5, 0x9AD6, 0x9AD7, 0x9AD9,
+    0x9ADA, 0x9ADB, 0x9ADC, 0x9ADD, 0x9ADE, 0x9AE0, 0x9AE2, 0x9AE3, 0x9AE4, 0x9AE5, 0x9AE7, 0x9AE8,
+    0x9AE9, 0x9AEA, 0x9AEC, 0x9AEE, 0x9AF0, 0x9AF1, 0x9AF2, 0x9AF3, 0x9AF4, 0x9AF5, 0x9AF6, 0x9AF7,
+    0x9AF8, 0x9AFA, 0x9AFC, 0x9AFD, 0x9AFE, 0x9AFF, 0x9B00, 0x9B01, 0x9B02, 0x9B04, 0x9B05, 0x9B06,
+    0x87C6, 0x8788, 0x8785, 0x87AD, 0x8797, 0x8783, 0x87AB, 0x87E5, 0x87AC, 0x87B5, 0x87B3, 0x87CB,
+    0x87D3, 0x87BD, 0x87D1, 0x87C0, 0x87CA, 0x87DB, 0x87EA, 0x87E0, 0x87EE, 0x8816, 0x8813, 0x87FE,
+    0x880A, 0x881B, 0x8821, 0x8839, 0x883C, 0x7F36, 0x7F42, 0x7F44, 0x7F45, 0x8210, 0x7AFA, 0x7AFD,
+    0x7B08, 0x7B03, 0x7B04, 0x7B15, 0x7B0A, 0x7B2B, 0x7B0F, 0x7B47, 0x7B38, 0x7B2A, 0x7B19, 0x7B2E,
+    0x7B31, 0x7B20, 0x7B25, 0x7B24, 0x7B33, 0x7B3E, 0x7B1E, 0x7B58, 0x7B5A, 0x7B45, 0x7B75, 0x7B4C,
+    0x7B5D, 0x7B60, 0x7B6E, 0x7B7B, 0x7B62, 0x7B72, 0x7B71, 0x7B90, 0x7BA6, 0x7BA7, 0x7BB8, 0x7BAC,
+    0x7B9D, 0x7BA8, 0x7B85, 0x7BAA, 0x7B9C, 0x7BA2, 0x7BAB, 0x7BB4, 0x7BD1, 0x7BC1, 0x7BCC, 0x7BDD,
+    0x7BDA, 0x7BE5, 0x7BE6, 0x7BEA, 0x7C0C, 0x7BFE, 0x7BFC, 0x7C0F, 0x7C16, 0x7C0B, 0x9B07, 0x9B09,
+    0x9B0A, 0x9B0B, 0x9B0C, 0x9B0D, 0x9B0E, 0x9B10, 0x9B11, 0x9B12, 0x9B14, 0x9B15, 0x9B16, 0x9B17,
+    0x9B18, 0x9B19, 0x9B1A, 0x9B1B, 0x9B1C, 0x9B1D, 0x9B1E, 0x9B20, 0x9B21, 0x9B22, 0x9B24, 0x9B25,
+    0x9B26, 0x9B27, 0x9B28, 0x9B29, 0x9B2A, 0x9B2B, 0x9B2C, 0x9B2D, 0x9B2E, 0x9B30, 0x9B31, 0x9B33,
+    0x9B34, 0x9B35, 0x9B36, 0x9B37, 0x9B38, 0x9B39, 0x9B3A, 0x9B3D, 0x9B3E, 0x9B3F, 0x9B40, 0x9B46,
+    0x9B4A, 0x9B4B, 0x9B4C, 0x9B4E, 0x9B50, 0x9B52, 0x9B53, 0x9B55, 0x9B56, 0x9B57, 0x9B58, 0x9B59,
+    0x9B5A, 0x9B5B, 0x9B5C, 0x9B5D, 0x9B5E, 0x9B5F, 0x9B60, 0x9B61, 0x9B62, 0x9B63, 0x9B64, 0x9B65,
+    0x9B66, 0x9B67, 0x9B68, 0x9B69, 0x9B6A, 0x9B6B, 0x9B6C, 0x9B6D, 0x9B6E, 0x9B6F, 0x9B70, 0x9B71,
+    0x9B72, 0x9B73, 0x9B74, 0x9B75, 0x9B76, 0x9B77, 0x9B78, 0x9B79, 0x9B7A, 0x9B7B, 0x7C1F, 0x7C2A,
+    0x7C26, 0x7C38, 0x7C41, 0x7C40, 0x81FE, 0x8201, 0x8202, 0x8204, 0x81EC, 0x8844, 0x8221, 0x8222,
+    0x8223, 0x822D, 0x822F, 0x8228, 0x822B, 0x8238, 0x823B, 0x8233, 0x8234, 0x823E, 0x8244, 0x8249,
+    0x824B, 0x824F, 0x825A, 0x825F, 0x8268, 0x887E, 0x8885, 0x8888, 0x88D8, 0x88DF, 0x895E, 0x7F9D,
+    0x7F9F, 0x7FA7, 0x7FAF, 0x7FB0, 0x7FB2, 0x7C7C, 0x6549, 0x7C91, 0x7C9D, 0x7C9C, 0x7C9E, 0x7CA2,
+    0x7CB2, 0x7CBC, 0x7CBD, 0x7CC1, 0x7CC7, 0x7CCC, 0x7CCD, 0x7CC8, 0x7CC5, 0x7CD7, 0x7CE8, 0x826E,
+    0x66A8, 0x7FBF, 0x7FCE, 0x7FD5, 0x7FE5, 0x7FE1, 0x7FE6, 0x7FE9, 0x7FEE, 0x7FF3, 0x7CF8, 0x7D77,
+    0x7DA6, 0x7DAE, 0x7E47, 0x7E9B, 0x9EB8, 0x9EB4, 0x8D73, 0x8D84, 0x8D94, 0x8D91, 0x8DB1, 0x8D67,
+    0x8D6D, 0x8C47, 0x8C49, 0x914A, 0x9150, 0x914E, 0x914F, 0x9164, 0x9B7C, 0x9B7D, 0x9B7E, 0x9B7F,
+    0x9B80, 0x9B81, 0x9B82, 0x9B83, 0x9B84, 0x9B85, 0x9B86, 0x9B87, 0x9B88, 0x9B89, 0x9B8A, 0x9B8B,
+    0x9B8C, 0x9B8D, 0x9B8E, 0x9B8F, 0x9B90, 0x9B91, 0x9B92, 0x9B93, 0x9B94, 0x9B95, 0x9B96, 0x9B97,
+    0x9B98, 0x9B99, 0x9B9A, 0x9B9B, 0x9B9C, 0x9B9D, 0x9B9E, 0x9B9F, 0x9BA0, 0x9BA1, 0x9BA2, 0x9BA3,
+    0x9BA4, 0x9BA5, 0x9BA6, 0x9BA7, 0x9BA8, 0x9BA9, 0x9BAA, 0x9BAB, 0x9BAC, 0x9BAD, 0x9BAE, 0x9BAF,
+    0x9BB0, 0x9BB1, 0x9BB2, 0x9BB3, 0x9BB4, 0x9BB5, 0x9BB6, 0x9BB7, 0x9BB8, 0x9BB9, 0x9BBA, 0x9BBB,
+    0x9BBC, 0x9BBD, 0x9BBE, 0x9BBF, 0x9BC0, 0x9BC1, 0x9BC2, 0x9BC3, 0x9BC4, 0x9BC5, 0x9BC6, 0x9BC7,
+    0x9BC8, 0x9BC9, 0x9BCA, 0x9BCB, 0x9BCC, 0x9BCD, 0x9BCE, 0x9BCF, 0x9BD0, 0x9BD1, 0x9BD2, 0x9BD3,
+    0x9BD4, 0x9BD5, 0x9BD6, 0x9BD7, 0x9BD8, 0x9BD9, 0x9BDA, 0x9BDB, 0x9162, 0x9161, 0x9170, 0x9169,
+    0x916F, 0x917D, 0x917E, 0x9172, 0x9174, 0x9179, 0x918C, 0x9185, 0x9190, 0x918D, 0x9191, 0x91A2,
+    0x91A3, 0x91AA, 0x91AD, 0x91AE, 0x91AF, 0x91B5, 0x91B4, 0x91BA, 0x8C55, 0x9E7E, 0x8DB8, 0x8DEB,
+    0x8E05, 0x8E59, 0x8E69, 0x8DB5, 0x8DBF, 0x8DBC, 0x8DBA, 0x8DC4, 0x8DD6, 0x8DD7, 0x8DDA, 0x8DDE,
+    0x8DCE, 0x8DCF, 0x8DDB, 0x8DC6, 0x8DEC, 0x8DF7, 0x8DF8, 0x8DE3, 0x8DF9, 0x8DFB, 0x8DE4, 0x8E09,
+    0x8DFD, 0x8E14, 0x8E1D, 0x8E1F, 0x8E2C, 0x8E2E, 0x8E23, 0x8E2F, 0x8E3A, 0x8E40, 0x8E39, 0x8E35,
+    0x8E3D, 0x8E31, 0x8E49, 0x8E41, 0x8E42, 0x8E51, 0x8E52, 0x8E4A, 0x8E70, 0x8E76, 0x8E7C, 0x8E6F,
+    0x8E74, 0x8E85, 0x8E8F, 0x8E94, 0x8E90, 0x8E9C, 0x8E9E, 0x8C78, 0x8C82, 0x8C8A, 0x8C85, 0x8C98,
+    0x8C94, 0x659B, 0x89D6, 0x89DE, 0x89DA, 0x89DC, 0x9BDC, 0x9BDD, 0x9BDE, 0x9BDF, 0x9BE0, 0x9BE1,
+    0x9BE2, 0x9BE3, 0x9BE4, 0x9BE5, 0x9BE6, 0x9BE7, 0x9BE8, 0x9BE9, 0x9BEA, 0x9BEB, 0x9BEC, 0x9BED,
+    0x9BEE, 0x9BEF, 0x9BF0, 0x9BF1, 0x9BF2, 0x9BF3, 0x9BF4, 0x9BF5, 0x9BF6, 0x9BF7, 0x9BF8, 0x9BF9,
+    0x9BFA, 0x9BFB, 0x9BFC, 0x9BFD, 0x9BFE, 0x9BFF, 0x9C00, 0x9C01, 0x9C02, 0x9C03, 0x9C04, 0x9C05,
+    0x9C06, 0x9C07, 0x9C08, 0x9C09, 0x9C0A, 0x9C0B, 0x9C0C, 0x9C0D, 0x9C0E, 0x9C0F, 0x9C10, 0x9C11,
+    0x9C12, 0x9C13, 0x9C14, 0x9C15, 0x9C16, 0x9C17, 0x9C18, 0x9C19, 0x9C1A, 0x9C1B, 0x9C1C, 0x9C1D,
+    0x9C1E, 0x9C1F, 0x9C20, 0x9C21, 0x9C22, 0x9C23, 0x9C24, 0x9C25, 0x9C26, 0x9C27, 0x9C28, 0x9C29,
+    0x9C2A, 0x9C2B, 0x9C2C, 0x9C2D, 0x9C2E, 0x9C2F, 0x9C30, 0x9C31, 0x9C32, 0x9C33, 0x9C34, 0x9C35,
+    0x9C36, 0x9C37, 0x9C38, 0x9C39, 0x9C3A, 0x9C3B, 0x89E5, 0x89EB, 0x89EF, 0x8A3E, 0x8B26, 0x9753,
+    0x96E9, 0x96F3, 0x96EF, 0x9706, 0x9701, 0x9708, 0x970F, 0x970E, 0x972A, 0x972D, 0x9730, 0x973E,
+    0x9F80, 0x9F83, 0x9F85, 0x9F86, 0x9F87, 0x9F88, 0x9F89, 0x9F8A, 0x9F8C, 0x9EFE, 0x9F0B, 0x9F0D,
+    0x96B9, 0x96BC, 0x96BD, 0x96CE, 0x96D2, 0x77BF, 0x96E0, 0x928E, 0x92AE, 0x92C8, 0x933E, 0x936A,
+    0x93CA, 0x938F, 0x943E, 0x946B, 0x9C7F, 0x9C82, 0x9C85, 0x9C86, 0x9C87, 0x9C88, 0x7A23, 0x9C8B,
+    0x9C8E, 0x9C90, 0x9C91, 0x9C92, 0x9C94, 0x9C95, 0x9C9A, 0x9C9B, 0x9C9E, 0x9C9F, 0x9CA0, 0x9CA1,
+    0x9CA2, 0x9CA3, 0x9CA5, 0x9CA6, 0x9CA7, 0x9CA8, 0x9CA9, 0x9CAB, 0x9CAD, 0x9CAE, 0x9CB0, 0x9CB1,
+    0x9CB2, 0x9CB3, 0x9CB4, 0x9CB5, 0x9CB6, 0x9CB7, 0x9CBA, 0x9CBB, 0x9CBC, 0x9CBD, 0x9CC4, 0x9CC5,
+    0x9CC6, 0x9CC7, 0x9CCA, 0x9CCB, 0x9C3C, 0x9C3D, 0x9C3E, 0x9C3F, 0x9C40, 0x9C41, 0x9C42, 0x9C43,
+    0x9C44, 0x9C45, 0x9C46, 0x9C47, 0x9C48, 0x9C49, 0x9C4A, 0x9C4B, 0x9C4C, 0x9C4D, 0x9C4E, 0x9C4F,
+    0x9C50, 0x9C51, 0x9C52, 0x9C53, 0x9C54, 0x9C55, 0x9C56, 0x9C57, 0x9C58, 0x9C59, 0x9C5A, 0x9C5B,
+    0x9C5C, 0x9C5D, 0x9C5E, 0x9C5F, 0x9C60, 0x9C61, 0x9C62, 0x9C63, 0x9C64, 0x9C65, 0x9C66, 0x9C67,
+    0x9C68, 0x9C69, 0x9C6A, 0x9C6B, 0x9C6C, 0x9C6D, 0x9C6E, 0x9C6F, 0x9C70, 0x9C71, 0x9C72, 0x9C73,
+    0x9C74, 0x9C75, 0x9C76, 0x9C77, 0x9C78, 0x9C79, 0x9C7A, 0x9C7B, 0x9C7D, 0x9C7E, 0x9C80, 0x9C83,
+    0x9C84, 0x9C89, 0x9C8A, 0x9C8C, 0x9C8F, 0x9C93, 0x9C96, 0x9C97, 0x9C98, 0x9C99, 0x9C9D, 0x9CAA,
+    0x9CAC, 0x9CAF, 0x9CB9, 0x9CBE, 0x9CBF, 0x9CC0, 0x9CC1, 0x9CC2, 0x9CC8, 0x9CC9, 0x9CD1, 0x9CD2,
+    0x9CDA, 0x9CDB, 0x9CE0, 0x9CE1, 0x9CCC, 0x9CCD, 0x9CCE, 0x9CCF, 0x9CD0, 0x9CD3, 0x9CD4, 0x9CD5,
+    0x9CD7, 0x9CD8, 0x9CD9, 0x9CDC, 0x9CDD, 0x9CDF, 0x9CE2, 0x977C, 0x9785, 0x9791, 0x9792, 0x9794,
+    0x97AF, 0x97AB, 0x97A3, 0x97B2, 0x97B4, 0x9AB1, 0x9AB0, 0x9AB7, 0x9E58, 0x9AB6, 0x9ABA, 0x9ABC,
+    0x9AC1, 0x9AC0, 0x9AC5, 0x9AC2, 0x9ACB, 0x9ACC, 0x9AD1, 0x9B45, 0x9B43, 0x9B47, 0x9B49, 0x9B48,
+    0x9B4D, 0x9B51, 0x98E8, 0x990D, 0x992E, 0x9955, 0x9954, 0x9ADF, 0x9AE1, 0x9AE6, 0x9AEF, 0x9AEB,
+    0x9AFB, 0x9AED, 0x9AF9, 0x9B08, 0x9B0F, 0x9B13, 0x9B1F, 0x9B23, 0x9EBD, 0x9EBE, 0x7E3B, 0x9E82,
+    0x9E87, 0x9E88, 0x9E8B, 0x9E92, 0x93D6, 0x9E9D, 0x9E9F, 0x9EDB, 0x9EDC, 0x9EDD, 0x9EE0, 0x9EDF,
+    0x9EE2, 0x9EE9, 0x9EE7, 0x9EE5, 0x9EEA, 0x9EEF, 0x9F22, 0x9F2C, 0x9F2F, 0x9F39, 0x9F37, 0x9F3D,
+    0x9F3E, 0x9F44, 0x9CE3, 0x9CE4, 0x9CE5, 0x9CE6, 0x9CE7, 0x9CE8, 0x9CE9, 0x9CEA, 0x9CEB, 0x9CEC,
+    0x9CED, 0x9CEE, 0x9CEF, 0x9CF0, 0x9CF1, 0x9CF2, 0x9CF3, 0x9CF4, 0x9CF5, 0x9CF6, 0x9CF7, 0x9CF8,
+    0x9CF9, 0x9CFA, 0x9CFB, 0x9CFC, 0x9CFD, 0x9CFE, 0x9CFF, 0x9D00, 0x9D01, 0x9D02, 0x9D03, 0x9D04,
+    0x9D05, 0x9D06, 0x9D07, 0x9D08, 0x9D09, 0x9D0A, 0x9D0B, 0x9D0C, 0x9D0D, 0x9D0E, 0x9D0F, 0x9D10,
+    0x9D11, 0x9D12, 0x9D13, 0x9D14, 0x9D15, 0x9D16, 0x9D17, 0x9D18, 0x9D19, 0x9D1A, 0x9D1B, 0x9D1C,
+    0x9D1D, 0x9D1E, 0x9D1F, 0x9D20, 0x9D21, 0x9D22, 0x9D23, 0x9D24, 0x9D25, 0x9D26, 0x9D27, 0x9D28,
+    0x9D29, 0x9D2A, 0x9D2B, 0x9D2C, 0x9D2D, 0x9D2E, 0x9D2F, 0x9D30, 0x9D31, 0x9D32, 0x9D33, 0x9D34,
+    0x9D35, 0x9D36, 0x9D37, 0x9D38, 0x9D39, 0x9D3A, 0x9D3B, 0x9D3C, 0x9D3D, 0x9D3E, 0x9D3F, 0x9D40,
+    0x9D41, 0x9D42, 0xE234, 0xE235, 0xE236, 0xE237, 0xE238, 0xE239, 0xE23A, 0xE23B, 0xE23C, 0xE23D,
+    0xE23E, 0xE23F, 0xE240, 0xE241, 0xE242, 0xE243, 0xE244, 0xE245, 0xE246, 0xE247, 0xE248, 0xE249,
+    0xE24A, 0xE24B, 0xE24C, 0xE24D, 0xE24E, 0xE24F, 0xE250, 0xE251, 0xE252, 0xE253, 0xE254, 0xE255,
+    0xE256, 0xE257, 0xE258, 0xE259, 0xE25A, 0xE25B, 0xE25C, 0xE25D, 0xE25E, 0xE25F, 0xE260, 0xE261,
+    0xE262, 0xE263, 0xE264, 0xE265, 0xE266, 0xE267, 0xE268, 0xE269, 0xE26A, 0xE26B, 0xE26C, 0xE26D,
+    0xE26E, 0xE26F, 0xE270, 0xE271, 0xE272, 0xE273, 0xE274, 0xE275, 0xE276, 0xE277, 0xE278, 0xE279,
+    0xE27A, 0xE27B, 0xE27C, 0xE27D, 0xE27E, 0xE27F, 0xE280, 0xE281, 0xE282, 0xE283, 0xE284, 0xE285,
+    0xE286, 0xE287, 0xE288, 0xE289, 0xE28A, 0xE28B, 0xE28C, 0xE28D, 0xE28E, 0xE28F, 0xE290, 0xE291,
+    0x9D43, 0x9D44, 0x9D45, 0x9D46, 0x9D47, 0x9D48, 0x9D49, 0x9D4A, 0x9D4B, 0x9D4C, 0x9D4D, 0x9D4E,
+    0x9D4F, 0x9D50, 0x9D51, 0x9D52, 0x9D53, 0x9D54, 0x9D55, 0x9D56, 0x9D57, 0x9D58, 0x9D59, 0x9D5A,
+    0x9D5B, 0x9D5C, 0x9D5D, 0x9D5E, 0x9D5F, 0x9D60, 0x9D61, 0x9D62, 0x9D63, 0x9D64, 0x9D65, 0x9D66,
+    0x9D67, 0x9D68, 0x9D69, 0x9D6A, 0x9D6B, 0x9D6C, 0x9D6D, 0x9D6E, 0x9D6F, 0x9D70, 0x9D71, 0x9D72,
+    0x9D73, 0x9D74, 0x9D75, 0x9D76, 0x9D77, 0x9D78, 0x9D79, 0x9D7A, 0x9D7B, 0x9D7C, 0x9D7D, 0x9D7E,
+    0x9D7F, 0x9D80, 0x9D81, 0x9D82, 0x9D83, 0x9D84, 0x9D85, 0x9D86, 0x9D87, 0x9D88, 0x9D89, 0x9D8A,
+    0x9D8B, 0x9D8C, 0x9D8D, 0x9D8E, 0x9D8F, 0x9D90, 0x9D91, 0x9D92, 0x9D93, 0x9D94, 0x9D95, 0x9D96,
+    0x9D97, 0x9D98, 0x9D99, 0x9D9A, 0x9D9B, 0x9D9C, 0x9D9D, 0x9D9E, 0x9D9F, 0x9DA0, 0x9DA1, 0x9DA2,
+    0xE292, 0xE293, 0xE294, 0xE295, 0xE296, 0xE297, 0xE298, 0xE299, 0xE29A, 0xE29B, 0xE29C, 0xE29D,
+    0xE29E, 0xE29F, 0xE2A0, 0xE2A1, 0xE2A2, 0xE2A3, 0xE2A4, 0xE2A5, 0xE2A6, 0xE2A7, 0xE2A8, 0xE2A9,
+    0xE2AA, 0xE2AB, 0xE2AC, 0xE2AD, 0xE2AE, 0xE2AF, 0xE2B0, 0xE2B1, 0xE2B2, 0xE2B3, 0xE2B4, 0xE2B5,
+    0xE2B6, 0xE2B7, 0xE2B8, 0xE2B9, 0xE2BA, 0xE2BB, 0xE2BC, 0xE2BD, 0xE2BE, 0xE2BF, 0xE2C0, 0xE2C1,
+    0xE2C2, 0xE2C3, 0xE2C4, 0xE2C5, 0xE2C6, 0xE2C7, 0xE2C8, 0xE2C9, 0xE2CA, 0xE2CB, 0xE2CC, 0xE2CD,
+    0xE2CE, 0xE2CF, 0xE2D0, 0xE2D1, 0xE2D2, 0xE2D3, 0xE2D4, 0xE2D5, 0xE2D6, 0xE2D7, 0xE2D8, 0xE2D9,
+    0xE2DA, 0xE2DB, 0xE2DC, 0xE2DD, 0xE2DE, 0xE2DF, 0xE2E0, 0xE2E1, 0xE2E2, 0xE2E3, 0xE2E4, 0xE2E5,
+    0xE2E6, 0xE2E7, 0xE2E8, 0xE2E9, 0xE2EA, 0xE2EB, 0xE2EC, 0xE2ED, 0xE2EE, 0xE2EF, 0x9DA3, 0x9DA4,
+    0x9DA5, 0x9DA6, 0x9DA7, 0x9DA8, 0x9DA9, 0x9DAA, 0x9DAB, 0x9DAC, 0x9DAD, 0x9DAE, 0x9DAF, 0x9DB0,
+    0x9DB1, 0x9DB2, 0x9DB3, 0x9DB4, 0x9DB5, 0x9DB6, 0x9DB7, 0x9DB8, 0x9DB9, 0x9DBA, 0x9DBB, 0x9DBC,
+    0x9DBD, 0x9DBE, 0x9DBF, 0x9DC0, 0x9DC1, 0x9DC2, 0x9DC3, 0x9DC4, 0x9DC5, 0x9DC6, 0x9DC7, 0x9DC8,
+    0x9DC9, 0x9DCA, 0x9DCB, 0x9DCC, 0x9DCD, 0x9DCE, 0x9DCF, 0x9DD0, 0x9DD1, 0x9DD2, 0x9DD3, 0x9DD4,
+    0x9DD5, 0x9DD6, 0x9DD7, 0x9DD8, 0x9DD9, 0x9DDA, 0x9DDB, 0x9DDC, 0x9DDD, 0x9DDE, 0x9DDF, 0x9DE0,
+    0x9DE1, 0x9DE2, 0x9DE3, 0x9DE4, 0x9DE5, 0x9DE6, 0x9DE7, 0x9DE8, 0x9DE9, 0x9DEA, 0x9DEB, 0x9DEC,
+    0x9DED, 0x9DEE, 0x9DEF, 0x9DF0, 0x9DF1, 0x9DF2, 0x9DF3, 0x9DF4, 0x9DF5, 0x9DF6, 0x9DF7, 0x9DF8,
+    0x9DF9, 0x9DFA, 0x9DFB, 0x9DFC, 0x9DFD, 0x9DFE, 0x9DFF, 0x9E00, 0x9E01, 0x9E02, 0xE2F0, 0xE2F1,
+    0xE2F2, 0xE2F3, 0xE2F4, 0xE2F5, 0xE2F6, 0xE2F7, 0xE2F8, 0xE2F9, 0xE2FA, 0xE2FB, 0xE2FC, 0xE2FD,
+    0xE2FE, 0xE2FF, 0xE300, 0xE301, 0xE302, 0xE303, 0xE304, 0xE305, 0xE306, 0xE307, 0xE308, 0xE309,
+    0xE30A, 0xE30B, 0xE30C, 0xE30D, 0xE30E, 0xE30F, 0xE310, 0xE311, 0xE312, 0xE313, 0xE314, 0xE315,
+    0xE316, 0xE317, 0xE318, 0xE319, 0xE31A, 0xE31B, 0xE31C, 0xE31D, 0xE31E, 0xE31F, 0xE320, 0xE321,
+    0xE322, 0xE323, 0xE324, 0xE325, 0xE326, 0xE327, 0xE328, 0xE329, 0xE32A, 0xE32B, 0xE32C, 0xE32D,
+    0xE32E, 0xE32F, 0xE330, 0xE331, 0xE332, 0xE333, 0xE334, 0xE335, 0xE336, 0xE337, 0xE338, 0xE339,
+    0xE33A, 0xE33B, 0xE33C, 0xE33D, 0xE33E, 0xE33F, 0xE340, 0xE341, 0xE342, 0xE343, 0xE344, 0xE345,
+    0xE346, 0xE347, 0xE348, 0xE349, 0xE34A, 0xE34B, 0xE34C, 0xE34D, 0x9E03, 0x9E04, 0x9E05, 0x9E06,
+    0x9E07, 0x9E08, 0x9E09, 0x9E0A, 0x9E0B, 0x9E0C, 0x9E0D, 0x9E0E, 0x9E0F, 0x9E10, 0x9E11, 0x9E12,
+    0x9E13, 0x9E14, 0x9E15, 0x9E16, 0x9E17, 0x9E18, 0x9E19, 0x9E1A, 0x9E1B, 0x9E1C, 0x9E1D, 0x9E1E,
+    0x9E24, 0x9E27, 0x9E2E, 0x9E30, 0x9E34, 0x9E3B, 0x9E3C, 0x9E40, 0x9E4D, 0x9E50, 0x9E52, 0x9E53,
+    0x9E54, 0x9E56, 0x9E59, 0x9E5D, 0x9E5F, 0x9E60, 0x9E61, 0x9E62, 0x9E65, 0x9E6E, 0x9E6F, 0x9E72,
+    0x9E74, 0x9E75, 0x9E76, 0x9E77, 0x9E78, 0x9E79, 0x9E7A, 0x9E7B, 0x9E7C, 0x9E7D, 0x9E80, 0x9E81,
+    0x9E83, 0x9E84, 0x9E85, 0x9E86, 0x9E89, 0x9E8A, 0x9E8C, 0x9E8D, 0x9E8E, 0x9E8F, 0x9E90, 0x9E91,
+    0x9E94, 0x9E95, 0x9E96, 0x9E97, 0x9E98, 0x9E99, 0x9E9A, 0x9E9B, 0x9E9C, 0x9E9E, 0x9EA0, 0x9EA1,
+    0x9EA2, 0x9EA3, 0x9EA4, 0x9EA5, 0x9EA7, 0x9EA8, 0x9EA9, 0x9EAA, 0xE34E, 0xE34F, 0xE350, 0xE351,
+    0xE352, 0xE353, 0xE354, 0xE355, 0xE356, 0xE357, 0xE358, 0xE359, 0xE35A, 0xE35B, 0xE35C, 0xE35D,
+    0xE35E, 0xE35F, 0xE360, 0xE361, 0xE362, 0xE363, 0xE364, 0xE365, 0xE366, 0xE367, 0xE368, 0xE369,
+    0xE36A, 0xE36B, 0xE36C, 0xE36D, 0xE36E, 0xE36F, 0xE370, 0xE371, 0xE372, 0xE373, 0xE374, 0xE375,
+    0xE376, 0xE377, 0xE378, 0xE379, 0xE37A, 0xE37B, 0xE37C, 0xE37D, 0xE37E, 0xE37F, 0xE380, 0xE381,
+    0xE382, 0xE383, 0xE384, 0xE385, 0xE386, 0xE387, 0xE388, 0xE389, 0xE38A, 0xE38B, 0xE38C, 0xE38D,
+    0xE38E, 0xE38F, 0xE390, 0xE391, 0xE392, 0xE393, 0xE394, 0xE395, 0xE396, 0xE397, 0xE398, 0xE399,
+    0xE39A, 0xE39B, 0xE39C, 0xE39D, 0xE39E, 0xE39F, 0xE3A0, 0xE3A1, 0xE3A2, 0xE3A3, 0xE3A4, 0xE3A5,
+    0xE3A6, 0xE3A7, 0xE3A8, 0xE3A9, 0xE3AA, 0xE3AB, 0x9EAB, 0x9EAC, 0x9EAD, 0x9EAE, 0x9EAF, 0x9EB0,
+    0x9EB1, 0x9EB2, 0x9EB3, 0x9EB5, 0x9EB6, 0x9EB7, 0x9EB9, 0x9EBA, 0x9EBC, 0x9EBF, 0x9EC0, 0x9EC1,
+    0x9EC2, 0x9EC3, 0x9EC5, 0x9EC6, 0x9EC7, 0x9EC8, 0x9ECA, 0x9ECB, 0x9ECC, 0x9ED0, 0x9ED2, 0x9ED3,
+    0x9ED5, 0x9ED6, 0x9ED7, 0x9ED9, 0x9EDA, 0x9EDE, 0x9EE1, 0x9EE3, 0x9EE4, 0x9EE6, 0x9EE8, 0x9EEB,
+    0x9EEC, 0x9EED, 0x9EEE, 0x9EF0, 0x9EF1, 0x9EF2, 0x9EF3, 0x9EF4, 0x9EF5, 0x9EF6, 0x9EF7, 0x9EF8,
+    0x9EFA, 0x9EFD, 0x9EFF, 0x9F00, 0x9F01, 0x9F02, 0x9F03, 0x9F04, 0x9F05, 0x9F06, 0x9F07, 0x9F08,
+    0x9F09, 0x9F0A, 0x9F0C, 0x9F0F, 0x9F11, 0x9F12, 0x9F14, 0x9F15, 0x9F16, 0x9F18, 0x9F1A, 0x9F1B,
+    0x9F1C, 0x9F1D, 0x9F1E, 0x9F1F, 0x9F21, 0x9F23, 0x9F24, 0x9F25, 0x9F26, 0x9F27, 0x9F28, 0x9F29,
+    0x9F2A, 0x9F2B, 0x9F2D, 0x9F2E, 0x9F30, 0x9F31, 0xE3AC, 0xE3AD, 0xE3AE, 0xE3AF, 0xE3B0, 0xE3B1,
+    0xE3B2, 0xE3B3, 0xE3B4, 0xE3B5, 0xE3B6, 0xE3B7, 0xE3B8, 0xE3B9, 0xE3BA, 0xE3BB, 0xE3BC, 0xE3BD,
+    0xE3BE, 0xE3BF, 0xE3C0, 0xE3C1, 0xE3C2, 0xE3C3, 0xE3C4, 0xE3C5, 0xE3C6, 0xE3C7, 0xE3C8, 0xE3C9,
+    0xE3CA, 0xE3CB, 0xE3CC, 0xE3CD, 0xE3CE, 0xE3CF, 0xE3D0, 0xE3D1, 0xE3D2, 0xE3D3, 0xE3D4, 0xE3D5,
+    0xE3D6, 0xE3D7, 0xE3D8, 0xE3D9, 0xE3DA, 0xE3DB, 0xE3DC, 0xE3DD, 0xE3DE, 0xE3DF, 0xE3E0, 0xE3E1,
+    0xE3E2, 0xE3E3, 0xE3E4, 0xE3E5, 0xE3E6, 0xE3E7, 0xE3E8, 0xE3E9, 0xE3EA, 0xE3EB, 0xE3EC, 0xE3ED,
+    0xE3EE, 0xE3EF, 0xE3F0, 0xE3F1, 0xE3F2, 0xE3F3, 0xE3F4, 0xE3F5, 0xE3F6, 0xE3F7, 0xE3F8, 0xE3F9,
+    0xE3FA, 0xE3FB, 0xE3FC, 0xE3FD, 0xE3FE, 0xE3FF, 0xE400, 0xE401, 0xE402, 0xE403, 0xE404, 0xE405,
+    0xE406, 0xE407, 0xE408, 0xE409, 0x9F32, 0x9F33, 0x9F34, 0x9F35, 0x9F36, 0x9F38, 0x9F3A, 0x9F3C,
+    0x9F3F, 0x9F40, 0x9F41, 0x9F42, 0x9F43, 0x9F45, 0x9F46, 0x9F47, 0x9F48, 0x9F49, 0x9F4A, 0x9F4B,
+    0x9F4C, 0x9F4D, 0x9F4E, 0x9F4F, 0x9F52, 0x9F53, 0x9F54, 0x9F55, 0x9F56, 0x9F57, 0x9F58, 0x9F59,
+    0x9F5A, 0x9F5B, 0x9F5C, 0x9F5D, 0x9F5E, 0x9F5F, 0x9F60, 0x9F61, 0x9F62, 0x9F63, 0x9F64, 0x9F65,
+    0x9F66, 0x9F67, 0x9F68, 0x9F69, 0x9F6A, 0x9F6B, 0x9F6C, 0x9F6D, 0x9F6E, 0x9F6F, 0x9F70, 0x9F71,
+    0x9F72, 0x9F73, 0x9F74, 0x9F75, 0x9F76, 0x9F77, 0x9F78, 0x9F79, 0x9F7A, 0x9F7B, 0x9F7C, 0x9F7D,
+    0x9F7E, 0x9F81, 0x9F82, 0x9F8D, 0x9F8E, 0x9F8F, 0x9F90, 0x9F91, 0x9F92, 0x9F93, 0x9F94, 0x9F95,
+    0x9F96, 0x9F97, 0x9F98, 0x9F9C, 0x9F9D, 0x9F9E, 0x9FA1, 0x9FA2, 0x9FA3, 0x9FA4, 0x9FA5, 0xF92C,
+    0xF979, 0xF995, 0xF9E7, 0xF9F1, 0xE40A, 0xE40B, 0xE40C, 0xE40D, 0xE40E, 0xE40F, 0xE410, 0xE411,
+    0xE412, 0xE413, 0xE414, 0xE415, 0xE416, 0xE417, 0xE418, 0xE419, 0xE41A, 0xE41B, 0xE41C, 0xE41D,
+    0xE41E, 0xE41F, 0xE420, 0xE421, 0xE422, 0xE423, 0xE424, 0xE425, 0xE426, 0xE427, 0xE428, 0xE429,
+    0xE42A, 0xE42B, 0xE42C, 0xE42D, 0xE42E, 0xE42F, 0xE430, 0xE431, 0xE432, 0xE433, 0xE434, 0xE435,
+    0xE436, 0xE437, 0xE438, 0xE439, 0xE43A, 0xE43B, 0xE43C, 0xE43D, 0xE43E, 0xE43F, 0xE440, 0xE441,
+    0xE442, 0xE443, 0xE444, 0xE445, 0xE446, 0xE447, 0xE448, 0xE449, 0xE44A, 0xE44B, 0xE44C, 0xE44D,
+    0xE44E, 0xE44F, 0xE450, 0xE451, 0xE452, 0xE453, 0xE454, 0xE455, 0xE456, 0xE457, 0xE458, 0xE459,
+    0xE45A, 0xE45B, 0xE45C, 0xE45D, 0xE45E, 0xE45F, 0xE460, 0xE461, 0xE462, 0xE463, 0xE464, 0xE465,
+    0xE466, 0xE467, 0xFA0C, 0xFA0D, 0xFA0E, 0xFA0F, 0xFA11, 0xFA13, 0xFA14, 0xFA18, 0xFA1F, 0xFA20,
+    0xFA21, 0xFA23, 0xFA24, 0xFA27, 0xFA28, 0xFA29, 0x2E81, 0xE816, 0xE817, 0xE818, 0x2E84, 0x3473,
+    0x3447, 0x2E88, 0x2E8B, 0x9FB4, 0x359E, 0x361A, 0x360E, 0x2E8C, 0x2E97, 0x396E, 0x3918, 0x9FB5,
+    0x39CF, 0x39DF, 0x3A73, 0x39D0, 0x9FB6, 0x9FB7, 0x3B4E, 0x3C6E, 0x3CE0, 0x2EA7, 0xE831, 0x9FB8,
+    0x2EAA, 0x4056, 0x415F, 0x2EAE, 0x4337, 0x2EB3, 0x2EB6, 0x2EB7, 0xE83B, 0x43B1, 0x43AC, 0x2EBB,
+    0x43DD, 0x44D6, 0x4661, 0x464C, 0x9FB9, 0x4723, 0x4729, 0x477C, 0x478D, 0x2ECA, 0x4947, 0x497A,
+    0x497D, 0x4982, 0x4983, 0x4985, 0x4986, 0x499F, 0x499B, 0x49B7, 0x49B6, 0x9FBA, 0xE855, 0x4CA3,
+    0x4C9F, 0x4CA0, 0x4CA1, 0x4C77, 0x4CA2, 0x4D13, 0x4D14, 0x4D15, 0x4D16, 0x4D17, 0x4D18, 0x4D19,
+    0x4DAE, 0x9FBB, 0xE468, 0xE469, 0xE46A, 0xE46B, 0xE46C, 0xE46D, 0xE46E, 0xE46F, 0xE470, 0xE471,
+    0xE472, 0xE473, 0xE474, 0xE475, 0xE476, 0xE477, 0xE478, 0xE479, 0xE47A, 0xE47B, 0xE47C, 0xE47D,
+    0xE47E, 0xE47F, 0xE480, 0xE481, 0xE482, 0xE483, 0xE484, 0xE485, 0xE486, 0xE487, 0xE488, 0xE489,
+    0xE48A, 0xE48B, 0xE48C, 0xE48D, 0xE48E, 0xE48F, 0xE490, 0xE491, 0xE492, 0xE493, 0xE494, 0xE495,
+    0xE496, 0xE497, 0xE498, 0xE499, 0xE49A, 0xE49B, 0xE49C, 0xE49D, 0xE49E, 0xE49F, 0xE4A0, 0xE4A1,
+    0xE4A2, 0xE4A3, 0xE4A4, 0xE4A5, 0xE4A6, 0xE4A7, 0xE4A8, 0xE4A9, 0xE4AA, 0xE4AB, 0xE4AC, 0xE4AD,
+    0xE4AE, 0xE4AF, 0xE4B0, 0xE4B1, 0xE4B2, 0xE4B3, 0xE4B4, 0xE4B5, 0xE4B6, 0xE4B7, 0xE4B8, 0xE4B9,
+    0xE4BA, 0xE4BB, 0xE4BC, 0xE4BD, 0xE4BE, 0xE4BF, 0xE4C0, 0xE4C1, 0xE4C2, 0xE4C3, 0xE4C4, 0xE4C5,
+];
+
+/// The first pointer of every code point of index gb18030, sorted by code point.
+pub(crate) static GB18030_POINTERS: &[(u16, u16)] = &[
+    (0x00A4, 6247), (0x00A7, 6251), (0x00A8, 6182), (0x00B0, 6242), (0x00B1, 6207), (0x00B7, 6179),
+    (0x00D7, 6208), (0x00E0, 7509), (0x00E1, 7507), (0x00E8, 7513), (0x00E9, 7511), (0x00EA, 7531),
+    (0x00EC, 7517), (0x00ED, 7515), (0x00F2, 7521), (0x00F3, 7519), (0x00F7, 6209), (0x00F9, 7525),
+    (0x00FA, 7523), (0x00FC, 7530), (0x0101, 7506), (0x0113, 7510), (0x011B, 7512), (0x012B, 7514),
+    (0x0144, 7534), (0x0148, 7535), (0x014D, 7518), (0x016B, 7522), (0x01CE, 7508), (0x01D0, 7516),
+    (0x01D2, 7520), (0x01D4, 7524), (0x01D6, 7526), (0x01D8, 7527), (0x01DA, 7528), (0x01DC, 7529),
+    (0x01F9, 7536), (0x0251, 7532), (0x0261, 7537), (0x02C7, 6181), (0x02C9, 6180), (0x02CA, 7410),
+    (0x02CB, 7411), (0x02D9, 7412), (0x0391, 7126), (0x0392, 7127), (0x0393, 7128), (0x0394, 7129),
+    (0x0395, 7130), (0x0396, 7131), (0x0397, 7132), (0x0398, 7133), (0x0399, 7134), (0x039A, 7135),
+    (0x039B, 7136), (0x039C, 7137), (0x039D, 7138), (0x039E, 7139), (0x039F, 7140), (0x03A0, 7141),
+    (0x03A1, 7142), (0x03A3, 7143), (0x03A4, 7144), (0x03A5, 7145), (0x03A6, 7146), (0x03A7, 7147),
+    (0x03A8, 7148), (0x03A9, 7149), (0x03B1, 7158), (0x03B2, 7159), (0x03B3, 7160), (0x03B4, 7161),
+    (0x03B5, 7162), (0x03B6, 7163), (0x03B7, 7164), (0x03B8, 7165), (0x03B9, 7166), (0x03BA, 7167),
+    (0x03BB, 7168), (0x03BC, 7169), (0x03BD, 7170), (0x03BE, 7171), (0x03BF, 7172), (0x03C0, 7173),
+    (0x03C1, 7174), (0x03C3, 7175), (0x03C4, 7176), (0x03C5, 7177), (0x03C6, 7178), (0x03C7, 7179),
+    (0x03C8, 7180), (0x03C9, 7181), (0x0401, 7322), (0x0410, 7316), (0x0411, 7317), (0x0412, 7318),
+    (0x0413, 7319), (0x0414, 7320), (0x0415, 7321), (0x0416, 7323), (0x0417, 7324), (0x0418, 7325),
+    (0x0419, 7326), (0x041A, 7327), (0x041B, 7328), (0x041C, 7329), (0x041D, 7330), (0x041E, 7331),
+    (0x041F, 7332), (0x0420, 7333), (0x0421, 7334), (0x0422, 7335), (0x0423, 7336), (0x0424, 7337),
+    (0x0425, 7338), (0x0426, 7339), (0x0427, 7340), (0x0428, 7341), (0x0429, 7342), (0x042A, 7343),
+    (0x042B, 7344), (0x042C, 7345), (0x042D, 7346), (0x042E, 7347), (0x042F, 7348), (0x0430, 7364),
+    (0x0431, 7365), (0x0432, 7366), (0x0433, 7367), (0x0434, 7368), (0x0435, 7369), (0x0436, 7371),
+    (0x0437, 7372), (0x0438, 7373), (0x0439, 7374), (0x043A, 7375), (0x043B, 7376), (0x043C, 7377),
+    (0x043D, 7378), (0x043E, 7379), (0x043F, 7380), (0x0440, 7381), (0x0441, 7382), (0x0442, 7383),
+    (0x0443, 7384), (0x0444, 7385), (0x0445, 7386), (0x0446, 7387), (0x0447, 7388), (0x0448, 7389),
+    (0x0449, 7390), (0x044A, 7391), (0x044B, 7392), (0x044C, 7393), (0x044D, 7394), (0x044E, 7395),
+    (0x044F, 7396), (0x0451, 7370), (0x1E3F, 7533), (0x2010, 7628), (0x2013, 7413), (0x2014, 6185),
+    (0x2015, 7414), (0x2016, 6187), (0x2018, 6189), (0x2019, 6190), (0x201C, 6191), (0x201D, 6192),
+    (0x2025, 7415), (0x2026, 6188), (0x2030, 6250), (0x2032, 6243), (0x2033, 6244), (0x2035, 7416),
+    (0x203B, 6264), (0x20AC, 6432), (0x2103, 6245), (0x2105, 7417), (0x2109, 7418), (0x2116, 6252),
+    (0x2121, 7625), (0x2160, 6446), (0x2161, 6447), (0x2162, 6448), (0x2163, 6449), (0x2164, 6450),
+    (0x2165, 6451), (0x2166, 6452), (0x2167, 6453), (0x2168, 6454), (0x2169, 6455), (0x216A, 6456),
+    (0x216B, 6457), (0x2170, 6366), (0x2171, 6367), (0x2172, 6368), (0x2173, 6369), (0x2174, 6370),
+    (0x2175, 6371), (0x2176, 6372), (0x2177, 6373), (0x2178, 6374), (0x2179, 6375), (0x2190, 6266),
+    (0x2191, 6267), (0x2192, 6265), (0x2193, 6268), (0x2196, 7419), (0x2197, 7420), (0x2198, 7421),
+    (0x2199, 7422), (0x2208, 6217), (0x220F, 6214), (0x2211, 6213), (0x2215, 7423), (0x221A, 6219),
+    (0x221D, 6231), (0x221E, 6237), (0x221F, 7424), (0x2220, 6222), (0x2223, 7425), (0x2225, 6221),
+    (0x2227, 6211), (0x2228, 6212), (0x2229, 6216), (0x222A, 6215), (0x222B, 6225), (0x222E, 6226),
+    (0x2234, 6239), (0x2235, 6238), (0x2236, 6210), (0x2237, 6218), (0x223D, 6230), (0x2248, 6229),
+    (0x224C, 6228), (0x2252, 7426), (0x2260, 6232), (0x2261, 6227), (0x2264, 6235), (0x2265, 6236),
+    (0x2266, 7427), (0x2267, 7428), (0x226E, 6233), (0x226F, 6234), (0x2295, 7491), (0x2299, 6224),
+    (0x22A5, 6220), (0x22BF, 7429), (0x2312, 6223), (0x2460, 6422), (0x2461, 6423), (0x2462, 6424),
+    (0x2463, 6425), (0x2464, 6426), (0x2465, 6427), (0x2466, 6428), (0x2467, 6429), (0x2468, 6430),
+    (0x2469, 6431), (0x2474, 6402), (0x2475, 6403), (0x2476, 6404), (0x2477, 6405), (0x2478, 6406),
+    (0x2479, 6407), (0x247A, 6408), (0x247B, 6409), (0x247C, 6410), (0x247D, 6411), (0x247E, 6412),
+    (0x247F, 6413), (0x2480, 6414), (0x2481, 6415), (0x2482, 6416), (0x2483, 6417), (0x2484, 6418),
+    (0x2485, 6419), (0x2486, 6420), (0x2487, 6421), (0x2488, 6382), (0x2489, 6383), (0x248A, 6384),
+    (0x248B, 6385), (0x248C, 6386), (0x248D, 6387), (0x248E, 6388), (0x248F, 6389), (0x2490, 6390),
+    (0x2491, 6391), (0x2492, 6392), (0x2493, 6393), (0x2494, 6394), (0x2495, 6395), (0x2496, 6396),
+    (0x2497, 6397), (0x2498, 6398), (0x2499, 6399), (0x249A, 6400), (0x249B, 6401), (0x2500, 7699),
+    (0x2501, 7700), (0x2502, 7701), (0x2503, 7702), (0x2504, 7703), (0x2505, 7704), (0x2506, 7705),
+    (0x2507, 7706), (0x2508, 7707), (0x2509, 7708), (0x250A, 7709), (0x250B, 7710), (0x250C, 7711),
+    (0x250D, 7712), (0x250E, 7713), (0x250F, 7714), (0x2510, 7715), (0x2511, 7716), (0x2512, 7717),
+    (0x2513, 7718), (0x2514, 7719), (0x2515, 7720), (0x2516, 7721), (0x2517, 7722), (0x2518, 7723),
+    (0x2519, 7724), (0x251A, 7725), (0x251B, 7726), (0x251C, 7727), (0x251D, 7728), (0x251E, 7729),
+    (0x251F, 7730), (0x2520, 7731), (0x2521, 7732), (0x2522, 7733), (0x2523, 7734), (0x2524, 7735),
+    (0x2525, 7736), (0x2526, 7737), (0x2527, 7738), (0x2528, 7739), (0x2529, 7740), (0x252A, 7741),
+    (0x252B, 7742), (0x252C, 7743), (0x252D, 7744), (0x252E, 7745), (0x252F, 7746), (0x2530, 7747),
+    (0x2531, 7748), (0x2532, 7749), (0x2533, 7750), (0x2534, 7751), (0x2535, 7752), (0x2536, 7753),
+    (0x2537, 7754), (0x2538, 7755), (0x2539, 7756), (0x253A, 7757), (0x253B, 7758), (0x253C, 7759),
+    (0x253D, 7760), (0x253E, 7761), (0x253F, 7762), (0x2540, 7763), (0x2541, 7764), (0x2542, 7765),
+    (0x2543, 7766), (0x2544, 7767), (0x2545, 7768), (0x2546, 7769), (0x2547, 7770), (0x2548, 7771),
+    (0x2549, 7772), (0x254A, 7773), (0x254B, 7774), (0x2550, 7430), (0x2551, 7431), (0x2552, 7432),
+    (0x2553, 7433), (0x2554, 7434), (0x2555, 7435), (0x2556, 7436), (0x2557, 7437), (0x2558, 7438),
+    (0x2559, 7439), (0x255A, 7440), (0x255B, 7441), (0x255C, 7442), (0x255D, 7443), (0x255E, 7444),
+    (0x255F, 7445), (0x2560, 7446), (0x2561, 7447), (0x2562, 7448), (0x2563, 7449), (0x2564, 7450),
+    (0x2565, 7451), (0x2566, 7452), (0x2567, 7453), (0x2568, 7454), (0x2569, 7455), (0x256A, 7456),
+    (0x256B, 7457), (0x256C, 7458), (0x256D, 7459), (0x256E, 7460), (0x256F, 7461), (0x2570, 7462),
+    (0x2571, 7463), (0x2572, 7464), (0x2573, 7465), (0x2581, 7466), (0x2582, 7467), (0x2583, 7468),
+    (0x2584, 7469), (0x2585, 7470), (0x2586, 7471), (0x2587, 7472), (0x2588, 7473), (0x2589, 7474),
+    (0x258A, 7475), (0x258B, 7476), (0x258C, 7477), (0x258D, 7478), (0x258E, 7479), (0x258F, 7480),
+    (0x2593, 7481), (0x2594, 7482), (0x2595, 7483), (0x25A0, 6261), (0x25A1, 6260), (0x25B2, 6263),
+    (0x25B3, 6262), (0x25BC, 7484), (0x25BD, 7485), (0x25C6, 6259), (0x25C7, 6258), (0x25CB, 6255),
+    (0x25CE, 6257), (0x25CF, 6256), (0x25E2, 7486), (0x25E3, 7487), (0x25E4, 7488), (0x25E5, 7489),
+    (0x2605, 6254), (0x2606, 6253), (0x2609, 7490), (0x2640, 6241), (0x2642, 6240), (0x2E81, 23766),
+    (0x2E84, 23770), (0x2E88, 23773), (0x2E8B, 23774), (0x2E8C, 23779), (0x2E97, 23780), (0x2EA7, 23793),
+    (0x2EAA, 23796), (0x2EAE, 23799), (0x2EB3, 23801), (0x2EB6, 23802), (0x2EB7, 23803), (0x2EBB, 23807),
+    (0x2ECA, 23817), (0x2FF0, 7673), (0x2FF1, 7674), (0x2FF2, 7675), (0x2FF3, 7676), (0x2FF4, 7677),
+    (0x2FF5, 7678), (0x2FF6, 7679), (0x2FF7, 7680), (0x2FF8, 7681), (0x2FF9, 7682), (0x2FFA, 7683),
+    (0x2FFB, 7684), (0x3000, 6176), (0x3001, 6177), (0x3002, 6178), (0x3003, 6183), (0x3005, 6184),
+    (0x3006, 7637), (0x3007, 7685), (0x3008, 6195), (0x3009, 6196), (0x300A, 6197), (0x300B, 6198),
+    (0x300C, 6199), (0x300D, 6200), (0x300E, 6201), (0x300F, 6202), (0x3010, 6205), (0x3011, 6206),
+    (0x3012, 7492), (0x3013, 6269), (0x3014, 6193), (0x3015, 6194), (0x3016, 6203), (0x3017, 6204),
+    (0x301D, 7493), (0x301E, 7494), (0x3021, 7600), (0x3022, 7601), (0x3023, 7602), (0x3024, 7603),
+    (0x3025, 7604), (0x3026, 7605), (0x3027, 7606), (0x3028, 7607), (0x3029, 7608), (0x303E, 7672),
+    (0x3041, 6746), (0x3042, 6747), (0x3043, 6748), (0x3044, 6749), (0x3045, 6750), (0x3046, 6751),
+    (0x3047, 6752), (0x3048, 6753), (0x3049, 6754), (0x304A, 6755), (0x304B, 6756), (0x304C, 6757),
+    (0x304D, 6758), (0x304E, 6759), (0x304F, 6760), (0x3050, 6761), (0x3051, 6762), (0x3052, 6763),
+    (0x3053, 6764), (0x3054, 6765), (0x3055, 6766), (0x3056, 6767), (0x3057, 6768), (0x3058, 6769),
+    (0x3059, 6770), (0x305A, 6771), (0x305B, 6772), (0x305C, 6773), (0x305D, 6774), (0x305E, 6775),
+    (0x305F, 6776), (0x3060, 6777), (0x3061, 6778), (0x3062, 6779), (0x3063, 6780), (0x3064, 6781),
+    (0x3065, 6782), (0x3066, 6783), (0x3067, 6784), (0x3068, 6785), (0x3069, 6786), (0x306A, 6787),
+    (0x306B, 6788), (0x306C, 6789), (0x306D, 6790), (0x306E, 6791), (0x306F, 6792), (0x3070, 6793),
+    (0x3071, 6794), (0x3072, 6795), (0x3073, 6796), (0x3074, 6797), (0x3075, 6798), (0x3076, 6799),
+    (0x3077, 6800), (0x3078, 6801), (0x3079, 6802), (0x307A, 6803), (0x307B, 6804), (0x307C, 6805),
+    (0x307D, 6806), (0x307E, 6807), (0x307F, 6808), (0x3080, 6809), (0x3081, 6810), (0x3082, 6811),
+    (0x3083, 6812), (0x3084, 6813), (0x3085, 6814), (0x3086, 6815), (0x3087, 6816), (0x3088, 6817),
+    (0x3089, 6818), (0x308A, 6819), (0x308B, 6820), (0x308C, 6821), (0x308D, 6822), (0x308E, 6823),
+    (0x308F, 6824), (0x3090, 6825), (0x3091, 6826), (0x3092, 6827), (0x3093, 6828), (0x309B, 7633),
+    (0x309C, 7634), (0x309D, 7638), (0x309E, 7639), (0x30A1, 6936), (0x30A2, 6937), (0x30A3, 6938),
+    (0x30A4, 6939), (0x30A5, 6940), (0x30A6, 6941), (0x30A7, 6942), (0x30A8, 6943), (0x30A9, 6944),
+    (0x30AA, 6945), (0x30AB, 6946), (0x30AC, 6947), (0x30AD, 6948), (0x30AE, 6949), (0x30AF, 6950),
+    (0x30B0, 6951), (0x30B1, 6952), (0x30B2, 6953), (0x30B3, 6954), (0x30B4, 6955), (0x30B5, 6956),
+    (0x30B6, 6957), (0x30B7, 6958), (0x30B8, 6959), (0x30B9, 6960), (0x30BA, 6961), (0x30BB, 6962),
+    (0x30BC, 6963), (0x30BD, 6964), (0x30BE, 6965), (0x30BF, 6966), (0x30C0, 6967), (0x30C1, 6968),
+    (0x30C2, 6969), (0x30C3, 6970), (0x30C4, 6971), (0x30C5, 6972), (0x30C6, 6973), (0x30C7, 6974),
+    (0x30C8, 6975), (0x30C9, 6976), (0x30CA, 6977), (0x30CB, 6978), (0x30CC, 6979), (0x30CD, 6980),
+    (0x30CE, 6981), (0x30CF, 6982), (0x30D0, 6983), (0x30D1, 6984), (0x30D2, 6985), (0x30D3, 6986),
+    (0x30D4, 6987), (0x30D5, 6988), (0x30D6, 6989), (0x30D7, 6990), (0x30D8, 6991), (0x30D9, 6992),
+    (0x30DA, 6993), (0x30DB, 6994), (0x30DC, 6995), (0x30DD, 6996), (0x30DE, 6997), (0x30DF, 6998),
+    (0x30E0, 6999), (0x30E1, 7000), (0x30E2, 7001), (0x30E3, 7002), (0x30E4, 7003), (0x30E5, 7004),
+    (0x30E6, 7005), (0x30E7, 7006), (0x30E8, 7007), (0x30E9, 7008), (0x30EA, 7009), (0x30EB, 7010),
+    (0x30EC, 7011), (0x30ED, 7012), (0x30EE, 7013), (0x30EF, 7014), (0x30F0, 7015), (0x30F1, 7016),
+    (0x30F2, 7017), (0x30F3, 7018), (0x30F4, 7019), (0x30F5, 7020), (0x30F6, 7021), (0x30FC, 7632),
+    (0x30FD, 7635), (0x30FE, 7636), (0x3105, 7542), (0x3106, 7543), (0x3107, 7544), (0x3108, 7545),
+    (0x3109, 7546), (0x310A, 7547), (0x310B, 7548), (0x310C, 7549), (0x310D, 7550), (0x310E, 7551),
+    (0x310F, 7552), (0x3110, 7553), (0x3111, 7554), (0x3112, 7555), (0x3113, 7556), (0x3114, 7557),
+    (0x3115, 7558), (0x3116, 7559), (0x3117, 7560), (0x3118, 7561), (0x3119, 7562), (0x311A, 7563),
+    (0x311B, 7564), (0x311C, 7565), (0x311D, 7566), (0x311E, 7567), (0x311F, 7568), (0x3120, 7569),
+    (0x3121, 7570), (0x3122, 7571), (0x3123, 7572), (0x3124, 7573), (0x3125, 7574), (0x3126, 7575),
+    (0x3127, 7576), (0x3128, 7577), (0x3129, 7578), (0x3220, 6434), (0x3221, 6435), (0x3222, 6436),
+    (0x3223, 6437), (0x3224, 6438), (0x3225, 6439), (0x3226, 6440), (0x3227, 6441), (0x3228, 6442),
+    (0x3229, 6443), (0x3231, 7626), (0x32A3, 7609), (0x338E, 7610), (0x338F, 7611), (0x339C, 7612),
+    (0x339D, 7613), (0x339E, 7614), (0x33A1, 7615), (0x33C4, 7616), (0x33CE, 7617), (0x33D1, 7618),
+    (0x33D2, 7619), (0x33D5, 7620), (0x3447, 23772), (0x3473, 23771), (0x359E, 23776), (0x360E, 23778),
+    (0x361A, 23777), (0x3918, 23782), (0x396E, 23781), (0x39CF, 23784), (0x39D0, 23787), (0x39DF, 23785),
+    (0x3A73, 23786), (0x3B4E, 23790), (0x3C6E, 23791), (0x3CE0, 23792), (0x4056, 23797), (0x415F, 23798),
+    (0x4337, 23800), (0x43AC, 23806), (0x43B1, 23805), (0x43DD, 23808), (0x44D6, 23809), (0x464C, 23811),
+    (0x4661, 23810), (0x4723, 23813), (0x4729, 23814), (0x477C, 23815), (0x478D, 23816), (0x4947, 23818),
+    (0x497A, 23819), (0x497D, 23820), (0x4982, 23821), (0x4983, 23822), (0x4985, 23823), (0x4986, 23824),
+    (0x499B, 23826), (0x499F, 23825), (0x49B6, 23828), (0x49B7, 23827), (0x4C77, 23835), (0x4C9F, 23832),
+    (0x4CA0, 23833), (0x4CA1, 23834), (0x4CA2, 23836), (0x4CA3, 23831), (0x4D13, 23837), (0x4D14, 23838),
+    (0x4D15, 23839), (0x4D16, 23840), (0x4D17, 23841), (0x4D18, 23842), (0x4D19, 23843), (0x4DAE, 23844),
+    (0x4E00, 15512), (0x4E01, 10166), (0x4E02, 0), (0x4E03, 13268), (0x4E04, 1), (0x4E05, 2),
+    (0x4E06, 3), (0x4E07, 14617), (0x4E08, 16096), (0x4E09, 13678), (0x4E0A, 13822), (0x4E0B, 14949),
+    (0x4E0C, 16627), (0x4E0D, 9432), (0x4E0E, 15750), (0x4E0F, 4), (0x4E10, 16629), (0x4E11, 9678),
+    (0x4E12, 5), (0x4E13, 16443), (0x4E14, 13445), (0x4E15, 16632), (0x4E16, 13997), (0x4E17, 6),
+    (0x4E18, 13475), (0x4E19, 9306), (0x4E1A, 15506), (0x4E1B, 9837), (0x4E1C, 10176), (0x4E1D, 14186),
+    (0x4E1E, 16634), (0x4E1F, 7), (0x4E20, 8), (0x4E21, 9), (0x4E22, 10175), (0x4E23, 10),
+    (0x4E24, 12284), (0x4E25, 15342), (0x4E26, 11), (0x4E27, 13780), (0x4E28, 16638), (0x4E29, 12),
+    (0x4E2A, 10631), (0x4E2B, 15325), (0x4E2C, 18775), (0x4E2D, 16293), (0x4E2E, 13), (0x4E2F, 14),
+    (0x4E30, 10420), (0x4E31, 15), (0x4E32, 9799), (0x4E33, 16), (0x4E34, 12312), (0x4E35, 17),
+    (0x4E36, 16653), (0x4E37, 18), (0x4E38, 14607), (0x4E39, 9979), (0x4E3A, 14735), (0x4E3B, 16332),
+    (0x4E3C, 19), (0x4E3D, 12151), (0x4E3E, 11742), (0x4E3F, 16640), (0x4E40, 20), (0x4E41, 21),
+    (0x4E42, 22), (0x4E43, 12868), (0x4E44, 23), (0x4E45, 11720), (0x4E46, 24), (0x4E47, 16642),
+    (0x4E48, 12655), (0x4E49, 15554), (0x4E4A, 25), (0x4E4B, 16259), (0x4E4C, 14783), (0x4E4D, 16062),
+    (0x4E4E, 11010), (0x4E4F, 10361), (0x4E50, 12119), (0x4E51, 26), (0x4E52, 13230), (0x4E53, 13065),
+    (0x4E54, 13434), (0x4E55, 27), (0x4E56, 10787), (0x4E57, 28), (0x4E58, 9638), (0x4E59, 15535),
+    (0x4E5A, 29), (0x4E5B, 30), (0x4E5C, 16656), (0x4E5D, 11722), (0x4E5E, 13287), (0x4E5F, 15503),
+    (0x4E60, 14931), (0x4E61, 14986), (0x4E62, 31), (0x4E63, 32), (0x4E64, 33), (0x4E65, 34),
+    (0x4E66, 14038), (0x4E67, 35), (0x4E68, 36), (0x4E69, 16657), (0x4E6A, 37), (0x4E6B, 38),
+    (0x4E6C, 39), (0x4E6D, 40), (0x4E6E, 41), (0x4E6F, 42), (0x4E70, 12527), (0x4E71, 12495),
+    (0x4E72, 43), (0x4E73, 13658), (0x4E74, 44), (0x4E75, 45), (0x4E76, 46), (0x4E77, 47),
+    (0x4E78, 48), (0x4E79, 49), (0x4E7A, 50), (0x4E7B, 51), (0x4E7C, 52), (0x4E7D, 53),
+    (0x4E7E, 13407), (0x4E7F, 54), (0x4E80, 55), (0x4E81, 56), (0x4E82, 57), (0x4E83, 58),
+    (0x4E84, 59), (0x4E85, 60), (0x4E86, 12298), (0x4E87, 61), (0x4E88, 15747), (0x4E89, 16144),
+    (0x4E8A, 62), (0x4E8B, 13999), (0x4E8C, 10259), (0x4E8D, 16626), (0x4E8E, 15733), (0x4E8F, 11962),
+    (0x4E90, 63), (0x4E91, 15903), (0x4E92, 11120), (0x4E93, 16658), (0x4E94, 14794), (0x4E95, 11699),
+    (0x4E96, 64), (0x4E97, 65), (0x4E98, 16633), (0x4E99, 66), (0x4E9A, 15334), (0x4E9B, 15114),
+    (0x4E9C, 67), (0x4E9D, 68), (0x4E9E, 69), (0x4E9F, 16654), (0x4EA0, 16894), (0x4EA1, 14621),
+    (0x4EA2, 11901), (0x4EA3, 70), (0x4EA4, 11522), (0x4EA5, 10930), (0x4EA6, 15549), (0x4EA7, 9495),
+    (0x4EA8, 10989), (0x4EA9, 12847), (0x4EAA, 71), (0x4EAB, 14992), (0x4EAC, 11694), (0x4EAD, 14539),
+    (0x4EAE, 12288), (0x4EAF, 72), (0x4EB0, 73), (0x4EB1, 74), (0x4EB2, 13450), (0x4EB3, 16896),
+    (0x4EB4, 75), (0x4EB5, 16899), (0x4EB6, 76), (0x4EB7, 77), (0x4EB8, 78), (0x4EB9, 79),
+    (0x4EBA, 13628), (0x4EBB, 16698), (0x4EBC, 80), (0x4EBD, 81), (0x4EBE, 82), (0x4EBF, 15543),
+    (0x4EC0, 13983), (0x4EC1, 13627), (0x4EC2, 16701), (0x4EC3, 16699), (0x4EC4, 16663), (0x4EC5, 11581),
+    (0x4EC6, 13250), (0x4EC7, 9675), (0x4EC8, 83), (0x4EC9, 16700), (0x4ECA, 11576), (0x4ECB, 11568),
+    (0x4ECC, 84), (0x4ECD, 13637), (0x4ECE, 9836), (0x4ECF, 85), (0x4ED0, 86), (0x4ED1, 12501),
+    (0x4ED2, 87), (0x4ED3, 9459), (0x4ED4, 16483), (0x4ED5, 14008), (0x4ED6, 14246), (0x4ED7, 16099),
+    (0x4ED8, 10567), (0x4ED9, 14956), (0x4EDA, 88), (0x4EDB, 89), (0x4EDC, 90), (0x4EDD, 16873),
+    (0x4EDE, 16705), (0x4EDF, 13405), (0x4EE0, 91), (0x4EE1, 16703), (0x4EE2, 92), (0x4EE3, 9875),
+    (0x4EE4, 12333), (0x4EE5, 15537), (0x4EE6, 93), (0x4EE7, 94), (0x4EE8, 16702), (0x4EE9, 95),
+    (0x4EEA, 15524), (0x4EEB, 16704), (0x4EEC, 12674), (0x4EED, 96), (0x4EEE, 97), (0x4EEF, 98),
+    (0x4EF0, 15381), (0x4EF1, 99), (0x4EF2, 16302), (0x4EF3, 16707), (0x4EF4, 100), (0x4EF5, 16710),
+    (0x4EF6, 11399), (0x4EF7, 11364), (0x4EF8, 101), (0x4EF9, 102), (0x4EFA, 103), (0x4EFB, 13631),
+    (0x4EFC, 104), (0x4EFD, 10416), (0x4EFE, 105), (0x4EFF, 10389), (0x4F00, 106), (0x4F01, 13288),
+    (0x4F02, 107), (0x4F03, 108), (0x4F04, 109), (0x4F05, 110), (0x4F06, 111), (0x4F07, 112),
+    (0x4F08, 113), (0x4F09, 16713), (0x4F0A, 15518), (0x4F0B, 114), (0x4F0C, 115), (0x4F0D, 14798),
+    (0x4F0E, 11336), (0x4F0F, 10447), (0x4F10, 10360), (0x4F11, 15166), (0x4F12, 116), (0x4F13, 117),
+    (0x4F14, 118), (0x4F15, 119), (0x4F16, 120), (0x4F17, 16303), (0x4F18, 15712), (0x4F19, 11194),
+    (0x4F1A, 11180), (0x4F1B, 16706), (0x4F1C, 121), (0x4F1D, 122), (0x4F1E, 13776), (0x4F1F, 14741),
+    (0x4F20, 9796), (0x4F21, 123), (0x4F22, 16708), (0x4F23, 124), (0x4F24, 13818), (0x4F25, 16711),
+    (0x4F26, 12500), (0x4F27, 16712), (0x4F28, 125), (0x4F29, 126), (0x4F2A, 14742), (0x4F2B, 16714),
+    (0x4F2C, 127), (0x4F2D, 128), (0x4F2E, 129), (0x4F2F, 9419), (0x4F30, 10767), (0x4F31, 130),
+    (0x4F32, 16818), (0x4F33, 131), (0x4F34, 9098), (0x4F35, 132), (0x4F36, 12325), (0x4F37, 133),
+    (0x4F38, 13851), (0x4F39, 134), (0x4F3A, 14192), (0x4F3B, 135), (0x4F3C, 14193), (0x4F3D, 16819),
+    (0x4F3E, 136), (0x4F3F, 137), (0x4F40, 138), (0x4F41, 139), (0x4F42, 140), (0x4F43, 10047),
+    (0x4F44, 141), (0x4F45, 142), (0x4F46, 9986), (0x4F47, 143), (0x4F48, 144), (0x4F49, 145),
+    (0x4F4A, 146), (0x4F4B, 147), (0x4F4C, 148), (0x4F4D, 14752), (0x4F4E, 10020), (0x4F4F, 16436),
+    (0x4F50, 16519), (0x4F51, 15726), (0x4F52, 149), (0x4F53, 14414), (0x4F54, 150), (0x4F55, 10971),
+    (0x4F56, 151), (0x4F57, 16817), (0x4F58, 16875), (0x4F59, 15739), (0x4F5A, 16718), (0x4F5B, 10435),
+    (0x4F5C, 16522), (0x4F5D, 16719), (0x4F5E, 16715), (0x4F5F, 16816), (0x4F60, 12892), (0x4F61, 152),
+    (0x4F62, 153), (0x4F63, 15697), (0x4F64, 16709), (0x4F65, 16876), (0x4F66, 154), (0x4F67, 16716),
+    (0x4F68, 155), (0x4F69, 13084), (0x4F6A, 156), (0x4F6B, 157), (0x4F6C, 12113), (0x4F6D, 158),
+    (0x4F6E, 159), (0x4F6F, 15375), (0x4F70, 9084), (0x4F71, 160), (0x4F72, 161), (0x4F73, 11354),
+    (0x4F74, 16821), (0x4F75, 162), (0x4F76, 16820), (0x4F77, 163), (0x4F78, 164), (0x4F79, 165),
+    (0x4F7A, 166), (0x4F7B, 16827), (0x4F7C, 16829), (0x4F7D, 167), (0x4F7E, 16826), (0x4F7F, 13990),
+    (0x4F80, 168), (0x4F81, 169), (0x4F82, 170), (0x4F83, 16824), (0x4F84, 16267), (0x4F85, 171),
+    (0x4F86, 172), (0x4F87, 173), (0x4F88, 9657), (0x4F89, 16823), (0x4F8A, 174), (0x4F8B, 12158),
+    (0x4F8C, 175), (0x4F8D, 14009), (0x4F8E, 176), (0x4F8F, 16825), (0x4F90, 177), (0x4F91, 16822),
+    (0x4F92, 178), (0x4F93, 179), (0x4F94, 16831), (0x4F95, 180), (0x4F96, 181), (0x4F97, 10182),
+    (0x4F98, 182), (0x4F99, 183), (0x4F9A, 184), (0x4F9B, 10744), (0x4F9C, 185), (0x4F9D, 15517),
+    (0x4F9E, 186), (0x4F9F, 187), (0x4FA0, 14947), (0x4FA1, 188), (0x4FA2, 189), (0x4FA3, 12479),
+    (0x4FA4, 190), (0x4FA5, 11531), (0x4FA6, 16131), (0x4FA7, 9469), (0x4FA8, 13435), (0x4FA9, 11950),
+    (0x4FAA, 16828), (0x4FAB, 191), (0x4FAC, 16830), (0x4FAD, 192), (0x4FAE, 14799), (0x4FAF, 11003),
+    (0x4FB0, 193), (0x4FB1, 194), (0x4FB2, 195), (0x4FB3, 196), (0x4FB4, 197), (0x4FB5, 13449),
+    (0x4FB6, 198), (0x4FB7, 199), (0x4FB8, 200), (0x4FB9, 201), (0x4FBA, 202), (0x4FBB, 203),
+    (0x4FBC, 204), (0x4FBD, 205), (0x4FBE, 206), (0x4FBF, 9282), (0x4FC0, 207), (0x4FC1, 208),
+    (0x4FC2, 209), (0x4FC3, 9842), (0x4FC4, 10242), (0x4FC5, 16835), (0x4FC6, 210), (0x4FC7, 211),
+    (0x4FC8, 212), (0x4FC9, 213), (0x4FCA, 11876), (0x4FCB, 214), (0x4FCC, 215), (0x4FCD, 216),
+    (0x4FCE, 16877), (0x4FCF, 13441), (0x4FD0, 12159), (0x4FD1, 16839), (0x4FD2, 217), (0x4FD3, 218),
+    (0x4FD4, 219), (0x4FD5, 220), (0x4FD6, 221), (0x4FD7, 14210), (0x4FD8, 10448), (0x4FD9, 222),
+    (0x4FDA, 16836), (0x4FDB, 223), (0x4FDC, 16838), (0x4FDD, 9218), (0x4FDE, 15740), (0x4FDF, 16840),
+    (0x4FE0, 224), (0x4FE1, 15142), (0x4FE2, 225), (0x4FE3, 16837), (0x4FE4, 226), (0x4FE5, 227),
+    (0x4FE6, 16832), (0x4FE7, 228), (0x4FE8, 16833), (0x4FE9, 12264), (0x4FEA, 16834), (0x4FEB, 229),
+    (0x4FEC, 230), (0x4FED, 11388), (0x4FEE, 15167), (0x4FEF, 10554), (0x4FF0, 231), (0x4FF1, 11752),
+    (0x4FF2, 232), (0x4FF3, 16844), (0x4FF4, 233), (0x4FF5, 234), (0x4FF6, 235), (0x4FF7, 236),
+    (0x4FF8, 16841), (0x4FF9, 237), (0x4FFA, 9044), (0x4FFB, 238), (0x4FFC, 239), (0x4FFD, 240),
+    (0x4FFE, 16849), (0x4FFF, 241), (0x5000, 242), (0x5001, 243), (0x5002, 244), (0x5003, 245),
+    (0x5004, 246), (0x5005, 247), (0x5006, 248), (0x5007, 249), (0x5008, 250), (0x5009, 251),
+    (0x500A, 252), (0x500B, 253), (0x500C, 16851), (0x500D, 9237), (0x500E, 254), (0x500F, 16846),
+    (0x5010, 255), (0x5011, 256), (0x5012, 10000), (0x5013, 257), (0x5014, 11768), (0x5015, 258),
+    (0x5016, 259), (0x5017, 260), (0x5018, 14385), (0x5019, 11007), (0x501A, 15533), (0x501B, 261),
+    (0x501C, 16850), (0x501D, 262), (0x501E, 263), (0x501F, 11567), (0x5020, 264), (0x5021, 9606),
+    (0x5022, 265), (0x5023, 266), (0x5024, 267), (0x5025, 16852), (0x5026, 11760), (0x5027, 268),
+    (0x5028, 16853), (0x5029, 16842), (0x502A, 12888), (0x502B, 269), (0x502C, 16845), (0x502D, 16848),
+    (0x502E, 16847), (0x502F, 270), (0x5030, 271), (0x5031, 272), (0x5032, 273), (0x5033, 274),
+    (0x5034, 275), (0x5035, 276), (0x5036, 277), (0x5037, 278), (0x5038, 279), (0x5039, 280),
+    (0x503A, 16069), (0x503B, 281), (0x503C, 16266), (0x503D, 282), (0x503E, 13462), (0x503F, 283),
+    (0x5040, 284), (0x5041, 285), (0x5042, 286), (0x5043, 16855), (0x5044, 287), (0x5045, 288),
+    (0x5046, 289), (0x5047, 11362), (0x5048, 16857), (0x5049, 290), (0x504A, 291), (0x504B, 292),
+    (0x504C, 16843), (0x504D, 293), (0x504E, 16858), (0x504F, 13216), (0x5050, 294), (0x5051, 295),
+    (0x5052, 296), (0x5053, 297), (0x5054, 298), (0x5055, 16856), (0x5056, 299), (0x5057, 300),
+    (0x5058, 301), (0x5059, 302), (0x505A, 16521), (0x505B, 303), (0x505C, 14538), (0x505D, 304),
+    (0x505E, 305), (0x505F, 306), (0x5060, 307), (0x5061, 308), (0x5062, 309), (0x5063, 310),
+    (0x5064, 311), (0x5065, 11496), (0x5066, 312), (0x5067, 313), (0x5068, 314), (0x5069, 315),
+    (0x506A, 316), (0x506B, 317), (0x506C, 16859), (0x506D, 318), (0x506E, 319), (0x506F, 320),
+    (0x5070, 321), (0x5071, 322), (0x5072, 323), (0x5073, 324), (0x5074, 325), (0x5075, 326),
+    (0x5076, 13043), (0x5077, 14556), (0x5078, 327), (0x5079, 328), (0x507A, 329), (0x507B, 16860),
+    (0x507C, 330), (0x507D, 331), (0x507E, 16854), (0x507F, 9600), (0x5080, 11969), (0x5081, 332),
+    (0x5082, 333), (0x5083, 334), (0x5084, 335), (0x5085, 10566), (0x5086, 336), (0x5087, 337),
+    (0x5088, 12157), (0x5089, 338), (0x508A, 339), (0x508B, 340), (0x508C, 341), (0x508D, 9113),
+    (0x508E, 342), (0x508F, 343), (0x5090, 344), (0x5091, 345), (0x5092, 346), (0x5093, 347),
+    (0x5094, 348), (0x5095, 349), (0x5096, 350), (0x5097, 351), (0x5098, 352), (0x5099, 353),
+    (0x509A, 354), (0x509B, 355), (0x509C, 356), (0x509D, 357), (0x509E, 358), (0x509F, 359),
+    (0x50A0, 360), (0x50A1, 361), (0x50A2, 362), (0x50A3, 9871), (0x50A4, 363), (0x50A5, 16861),
+    (0x50A6, 364), (0x50A7, 16862), (0x50A8, 9787), (0x50A9, 16863), (0x50AA, 365), (0x50AB, 366),
+    (0x50AC, 9848), (0x50AD, 367), (0x50AE, 368), (0x50AF, 369), (0x50B0, 370), (0x50B1, 371),
+    (0x50B2, 9058), (0x50B3, 372), (0x50B4, 373), (0x50B5, 374), (0x50B6, 375), (0x50B7, 376),
+    (0x50B8, 377), (0x50B9, 378), (0x50BA, 16864), (0x50BB, 13796), (0x50BC, 379), (0x50BD, 380),
+    (0x50BE, 381), (0x50BF, 382), (0x50C0, 383), (0x50C1, 384), (0x50C2, 385), (0x50C3, 386),
+    (0x50C4, 387), (0x50C5, 388), (0x50C6, 389), (0x50C7, 390), (0x50C8, 391), (0x50C9, 392),
+    (0x50CA, 393), (0x50CB, 394), (0x50CC, 395), (0x50CD, 396), (0x50CE, 397), (0x50CF, 14996),
+    (0x50D0, 398), (0x50D1, 399), (0x50D2, 400), (0x50D3, 401), (0x50D4, 402), (0x50D5, 403),
+    (0x50D6, 16865), (0x50D7, 404), (0x50D8, 405), (0x50D9, 406), (0x50DA, 12292), (0x50DB, 407),
+    (0x50DC, 408), (0x50DD, 409), (0x50DE, 410), (0x50DF, 411), (0x50E0, 412), (0x50E1, 413),
+    (0x50E2, 414), (0x50E3, 415), (0x50E4, 416), (0x50E5, 417), (0x50E6, 16869), (0x50E7, 13789),
+    (0x50E8, 418), (0x50E9, 419), (0x50EA, 420), (0x50EB, 421), (0x50EC, 16868), (0x50ED, 16867),
+    (0x50EE, 16870), (0x50EF, 422), (0x50F0, 423), (0x50F1, 424), (0x50F2, 425), (0x50F3, 14214),
+    (0x50F4, 426), (0x50F5, 11504), (0x50F6, 427), (0x50F7, 428), (0x50F8, 429), (0x50F9, 430),
+    (0x50FA, 431), (0x50FB, 13212), (0x50FC, 432), (0x50FD, 433), (0x50FE, 434), (0x50FF, 435),
+    (0x5100, 436), (0x5101, 437), (0x5102, 438), (0x5103, 439), (0x5104, 440), (0x5105, 441),
+    (0x5106, 16866), (0x5107, 16871), (0x5108, 442), (0x5109, 443), (0x510A, 444), (0x510B, 16872),
+    (0x510C, 445), (0x510D, 446), (0x510E, 447), (0x510F, 448), (0x5110, 449), (0x5111, 450),
+    (0x5112, 13654), (0x5113, 451), (0x5114, 452), (0x5115, 453), (0x5116, 454), (0x5117, 455),
+    (0x5118, 456), (0x5119, 457), (0x511A, 458), (0x511B, 459), (0x511C, 460), (0x511D, 461),
+    (0x511E, 462), (0x511F, 463), (0x5120, 464), (0x5121, 12125), (0x5122, 465), (0x5123, 466),
+    (0x5124, 467), (0x5125, 468), (0x5126, 469), (0x5127, 470), (0x5128, 471), (0x5129, 472),
+    (0x512A, 473), (0x512B, 474), (0x512C, 475), (0x512D, 476), (0x512E, 477), (0x512F, 478),
+    (0x5130, 479), (0x5131, 480), (0x5132, 481), (0x5133, 482), (0x5134, 483), (0x5135, 484),
+    (0x5136, 485), (0x5137, 486), (0x5138, 487), (0x5139, 488), (0x513A, 489), (0x513B, 490),
+    (0x513C, 491), (0x513D, 492), (0x513E, 493), (0x513F, 10254), (0x5140, 16628), (0x5141, 15907),
+    (0x5142, 494), (0x5143, 15875), (0x5144, 15159), (0x5145, 9663), (0x5146, 16110), (0x5147, 495),
+    (0x5148, 14955), (0x5149, 10801), (0x514A, 496), (0x514B, 11918), (0x514C, 497), (0x514D, 12701),
+    (0x514E, 498), (0x514F, 499), (0x5150, 500), (0x5151, 10215), (0x5152, 501), (0x5153, 502),
+    (0x5154, 14570), (0x5155, 16893), (0x5156, 16895), (0x5157, 503), (0x5158, 504), (0x5159, 505),
+    (0x515A, 9994), (0x515B, 506), (0x515C, 10186), (0x515D, 507), (0x515E, 508), (0x515F, 509),
+    (0x5160, 510), (0x5161, 511), (0x5162, 11689), (0x5163, 512), (0x5164, 513), (0x5165, 13660),
+    (0x5166, 514), (0x5167, 515), (0x5168, 13596), (0x5169, 516), (0x516A, 517), (0x516B, 9068),
+    (0x516C, 10746), (0x516D, 12344), (0x516E, 16881), (0x516F, 518), (0x5170, 12093), (0x5171, 10753),
+    (0x5172, 519), (0x5173, 10791), (0x5174, 15148), (0x5175, 9303), (0x5176, 13273), (0x5177, 11748),
+    (0x5178, 10043), (0x5179, 16475), (0x517A, 520), (0x517B, 15383), (0x517C, 11375), (0x517D, 14027),
+    (0x517E, 521), (0x517F, 522), (0x5180, 11334), (0x5181, 16885), (0x5182, 16696), (0x5183, 523),
+    (0x5184, 524), (0x5185, 12883), (0x5186, 525), (0x5187, 526), (0x5188, 10597), (0x5189, 13614),
+    (0x518A, 527), (0x518B, 528), (0x518C, 9470), (0x518D, 15922), (0x518E, 529), (0x518F, 530),
+    (0x5190, 531), (0x5191, 532), (0x5192, 12651), (0x5193, 533), (0x5194, 534), (0x5195, 12700),
+    (0x5196, 17007), (0x5197, 13648), (0x5198, 535), (0x5199, 15125), (0x519A, 536), (0x519B, 11777),
+    (0x519C, 13024), (0x519D, 537), (0x519E, 538), (0x519F, 539), (0x51A0, 10793), (0x51A1, 540),
+    (0x51A2, 17008), (0x51A3, 541), (0x51A4, 15874), (0x51A5, 17009), (0x51A6, 542), (0x51A7, 543),
+    (0x51A8, 544), (0x51A9, 545), (0x51AA, 546), (0x51AB, 16906), (0x51AC, 10177), (0x51AD, 547),
+    (0x51AE, 548), (0x51AF, 10430), (0x51B0, 9304), (0x51B1, 16907), (0x51B2, 9664), (0x51B3, 11771),
+    (0x51B4, 549), (0x51B5, 11961), (0x51B6, 15502), (0x51B7, 12133), (0x51B8, 550), (0x51B9, 551),
+    (0x51BA, 552), (0x51BB, 10184), (0x51BC, 16909), (0x51BD, 16908), (0x51BE, 553), (0x51BF, 554),
+    (0x51C0, 11712), (0x51C1, 555), (0x51C2, 556), (0x51C3, 557), (0x51C4, 13269), (0x51C5, 558),
+    (0x51C6, 16463), (0x51C7, 17006), (0x51C8, 559), (0x51C9, 12280), (0x51CA, 560), (0x51CB, 10057),
+    (0x51CC, 12327), (0x51CD, 561), (0x51CE, 562), (0x51CF, 11390), (0x51D0, 563), (0x51D1, 9838),
+    (0x51D2, 564), (0x51D3, 565), (0x51D4, 566), (0x51D5, 567), (0x51D6, 568), (0x51D7, 569),
+    (0x51D8, 570), (0x51D9, 571), (0x51DA, 572), (0x51DB, 12316), (0x51DC, 573), (0x51DD, 12918),
+    (0x51DE, 574), (0x51DF, 575), (0x51E0, 11329), (0x51E1, 10373), (0x51E2, 576), (0x51E3, 577),
+    (0x51E4, 10434), (0x51E5, 578), (0x51E6, 579), (0x51E7, 580), (0x51E8, 581), (0x51E9, 582),
+    (0x51EA, 583), (0x51EB, 16891), (0x51EC, 584), (0x51ED, 13235), (0x51EE, 585), (0x51EF, 11888),
+    (0x51F0, 11158), (0x51F1, 586), (0x51F2, 587), (0x51F3, 10017), (0x51F4, 588), (0x51F5, 17236),
+    (0x51F6, 15160), (0x51F7, 589), (0x51F8, 14560), (0x51F9, 9053), (0x51FA, 9681), (0x51FB, 11202),
+    (0x51FC, 17237), (0x51FD, 10940), (0x51FE, 590), (0x51FF, 15933), (0x5200, 9997), (0x5201, 10058),
+    (0x5202, 16679), (0x5203, 13633), (0x5204, 591), (0x5205, 592), (0x5206, 10409), (0x5207, 13443),
+    (0x5208, 16680), (0x5209, 593), (0x520A, 11890), (0x520B, 594), (0x520C, 595), (0x520D, 17222),
+    (0x520E, 16681), (0x520F, 596), (0x5210, 597), (0x5211, 15149), (0x5212, 11129), (0x5213, 598),
+    (0x5214, 599), (0x5215, 600), (0x5216, 20265), (0x5217, 12303), (0x5218, 12340), (0x5219, 15947),
+    (0x521A, 10598), (0x521B, 9805), (0x521C, 601), (0x521D, 9680), (0x521E, 602), (0x521F, 603),
+    (0x5220, 13805), (0x5221, 604), (0x5222, 605), (0x5223, 606), (0x5224, 13063), (0x5225, 607),
+    (0x5226, 608), (0x5227, 609), (0x5228, 13072), (0x5229, 12156), (0x522A, 610), (0x522B, 9295),
+    (0x522C, 611), (0x522D, 16682), (0x522E, 10781), (0x522F, 612), (0x5230, 10004), (0x5231, 613),
+    (0x5232, 614), (0x5233, 16683), (0x5234, 615), (0x5235, 616), (0x5236, 16283), (0x5237, 14157),
+    (0x5238, 13600), (0x5239, 13793), (0x523A, 9829), (0x523B, 11919), (0x523C, 617), (0x523D, 10819),
+    (0x523E, 618), (0x523F, 16684), (0x5240, 16685), (0x5241, 10236), (0x5242, 11338), (0x5243, 14419),
+    (0x5244, 619), (0x5245, 620), (0x5246, 621), (0x5247, 622), (0x5248, 623), (0x5249, 624),
+    (0x524A, 15002), (0x524B, 625), (0x524C, 16686), (0x524D, 13411), (0x524E, 626), (0x524F, 627),
+    (0x5250, 10783), (0x5251, 11498), (0x5252, 628), (0x5253, 629), (0x5254, 14407), (0x5255, 630),
+    (0x5256, 13247), (0x5257, 631), (0x5258, 632), (0x5259, 633), (0x525A, 634), (0x525B, 635),
+    (0x525C, 16689), (0x525D, 636), (0x525E, 16687), (0x525F, 637), (0x5260, 638), (0x5261, 16688),
+    (0x5262, 639), (0x5263, 640), (0x5264, 641), (0x5265, 9119), (0x5266, 642), (0x5267, 11756),
+    (0x5268, 643), (0x5269, 13968), (0x526A, 11389), (0x526B, 644), (0x526C, 645), (0x526D, 646),
+    (0x526E, 647), (0x526F, 10562), (0x5270, 648), (0x5271, 649), (0x5272, 10623), (0x5273, 650),
+    (0x5274, 651), (0x5275, 652), (0x5276, 653), (0x5277, 654), (0x5278, 655), (0x5279, 656),
+    (0x527A, 657), (0x527B, 658), (0x527C, 659), (0x527D, 16691), (0x527E, 660), (0x527F, 11538),
+    (0x5280, 661), (0x5281, 16693), (0x5282, 16692), (0x5283, 662), (0x5284, 663), (0x5285, 664),
+    (0x5286, 665), (0x5287, 666), (0x5288, 13107), (0x5289, 667), (0x528A, 668), (0x528B, 669),
+    (0x528C, 670), (0x528D, 671), (0x528E, 672), (0x528F, 673), (0x5290, 16694), (0x5291, 674),
+    (0x5292, 675), (0x5293, 16695), (0x5294, 676), (0x5295, 677), (0x5296, 678), (0x5297, 679),
+    (0x5298, 680), (0x5299, 681), (0x529A, 682), (0x529B, 12261), (0x529C, 683), (0x529D, 13601),
+    (0x529E, 9101), (0x529F, 10741), (0x52A0, 11356), (0x52A1, 14806), (0x52A2, 17224), (0x52A3, 12306),
+    (0x52A4, 684), (0x52A5, 685), (0x52A6, 686), (0x52A7, 687), (0x52A8, 10180), (0x52A9, 16335),
+    (0x52AA, 13027), (0x52AB, 11552), (0x52AC, 17225), (0x52AD, 17226), (0x52AE, 688), (0x52AF, 689),
+    (0x52B0, 690), (0x52B1, 12153), (0x52B2, 11687), (0x52B3, 12110), (0x52B4, 691), (0x52B5, 692),
+    (0x52B6, 693), (0x52B7, 694), (0x52B8, 695), (0x52B9, 696), (0x52BA, 697), (0x52BB, 698),
+    (0x52BC, 699), (0x52BD, 700), (0x52BE, 17227), (0x52BF, 14003), (0x52C0, 701), (0x52C1, 702),
+    (0x52C2, 703), (0x52C3, 9415), (0x52C4, 704), (0x52C5, 705), (0x52C6, 706), (0x52C7, 15709),
+    (0x52C8, 707), (0x52C9, 12702), (0x52CA, 708), (0x52CB, 15306), (0x52CC, 709), (0x52CD, 710),
+    (0x52CE, 711), (0x52CF, 712), (0x52D0, 17229), (0x52D1, 713), (0x52D2, 12118), (0x52D3, 714),
+    (0x52D4, 715), (0x52D5, 716), (0x52D6, 17230), (0x52D7, 717), (0x52D8, 11892), (0x52D9, 718),
+    (0x52DA, 719), (0x52DB, 720), (0x52DC, 721), (0x52DD, 722), (0x52DE, 723), (0x52DF, 12853),
+    (0x52E0, 724), (0x52E1, 725), (0x52E2, 726), (0x52E3, 727), (0x52E4, 13453), (0x52E5, 728),
+    (0x52E6, 729), (0x52E7, 730), (0x52E8, 731), (0x52E9, 732), (0x52EA, 733), (0x52EB, 734),
+    (0x52EC, 735), (0x52ED, 736), (0x52EE, 737), (0x52EF, 738), (0x52F0, 17231), (0x52F1, 739),
+    (0x52F2, 740), (0x52F3, 741), (0x52F4, 742), (0x52F5, 743), (0x52F6, 744), (0x52F7, 745),
+    (0x52F8, 746), (0x52F9, 16887), (0x52FA, 13830), (0x52FB, 747), (0x52FC, 748), (0x52FD, 749),
+    (0x52FE, 10755), (0x52FF, 14805), (0x5300, 15905), (0x5301, 750), (0x5302, 751), (0x5303, 752),
+    (0x5304, 753), (0x5305, 9117), (0x5306, 9835), (0x5307, 754), (0x5308, 15162), (0x5309, 755),
+    (0x530A, 756), (0x530B, 757), (0x530C, 758), (0x530D, 16888), (0x530E, 759), (0x530F, 17808),
+    (0x5310, 16890), (0x5311, 760), (0x5312, 761), (0x5313, 762), (0x5314, 763), (0x5315, 16641),
+    (0x5316, 11130), (0x5317, 9232), (0x5318, 764), (0x5319, 9650), (0x531A, 16671), (0x531B, 765),
+    (0x531C, 766), (0x531D, 15914), (0x531E, 767), (0x531F, 768), (0x5320, 11514), (0x5321, 11954),
+    (0x5322, 769), (0x5323, 14942), (0x5324, 770), (0x5325, 771), (0x5326, 16673), (0x5327, 772),
+    (0x5328, 773), (0x5329, 774), (0x532A, 10398), (0x532B, 775), (0x532C, 776), (0x532D, 777),
+    (0x532E, 16674), (0x532F, 778), (0x5330, 779), (0x5331, 780), (0x5332, 781), (0x5333, 782),
+    (0x5334, 783), (0x5335, 784), (0x5336, 785), (0x5337, 786), (0x5338, 787), (0x5339, 13210),
+    (0x533A, 13483), (0x533B, 15514), (0x533C, 788), (0x533D, 789), (0x533E, 16675), (0x533F, 12893),
+    (0x5340, 790), (0x5341, 13979), (0x5342, 791), (0x5343, 13402), (0x5344, 792), (0x5345, 16631),
+    (0x5346, 793), (0x5347, 13868), (0x5348, 14796), (0x5349, 11175), (0x534A, 9100), (0x534B, 794),
+    (0x534C, 795), (0x534D, 796), (0x534E, 11125), (0x534F, 15118), (0x5350, 797), (0x5351, 9231),
+    (0x5352, 16503), (0x5353, 16466), (0x5354, 798), (0x5355, 9980), (0x5356, 12529), (0x5357, 12872),
+    (0x5358, 799), (0x5359, 800), (0x535A, 9414), (0x535B, 801), (0x535C, 9428), (0x535D, 802),
+    (0x535E, 9284), (0x535F, 17973), (0x5360, 16083), (0x5361, 11883), (0x5362, 12457), (0x5363, 16678),
+    (0x5364, 12462), (0x5365, 803), (0x5366, 16677), (0x5367, 14777), (0x5368, 804), (0x5369, 17069),
+    (0x536A, 805), (0x536B, 14757), (0x536C, 806), (0x536D, 807), (0x536E, 16645), (0x536F, 12649),
+    (0x5370, 15676), (0x5371, 14728), (0x5372, 808), (0x5373, 11325), (0x5374, 13605), (0x5375, 12494),
+    (0x5376, 809), (0x5377, 11762), (0x5378, 15127), (0x5379, 810), (0x537A, 17070), (0x537B, 811),
+    (0x537C, 812), (0x537D, 813), (0x537E, 814), (0x537F, 13463), (0x5380, 815), (0x5381, 816),
+    (0x5382, 9602), (0x5383, 817), (0x5384, 10247), (0x5385, 14437), (0x5386, 12155), (0x5387, 818),
+    (0x5388, 819), (0x5389, 12152), (0x538A, 820), (0x538B, 15320), (0x538C, 15360), (0x538D, 16664),
+    (0x538E, 821), (0x538F, 822), (0x5390, 823), (0x5391, 824), (0x5392, 825), (0x5393, 826),
+    (0x5394, 827), (0x5395, 9467), (0x5396, 828), (0x5397, 829), (0x5398, 12134), (0x5399, 830),
+    (0x539A, 11006), (0x539B, 831), (0x539C, 832), (0x539D, 16665), (0x539E, 833), (0x539F, 15878),
+    (0x53A0, 834), (0x53A1, 835), (0x53A2, 14980), (0x53A3, 16666), (0x53A4, 836), (0x53A5, 16667),
+    (0x53A6, 14950), (0x53A7, 837), (0x53A8, 9683), (0x53A9, 11724), (0x53AA, 838), (0x53AB, 839),
+    (0x53AC, 840), (0x53AD, 841), (0x53AE, 16668), (0x53AF, 842), (0x53B0, 843), (0x53B1, 844),
+    (0x53B2, 845), (0x53B3, 846), (0x53B4, 847), (0x53B5, 848), (0x53B6, 17239), (0x53B7, 849),
+    (0x53B8, 850), (0x53B9, 851), (0x53BA, 852), (0x53BB, 13590), (0x53BC, 853), (0x53BD, 854),
+    (0x53BE, 855), (0x53BF, 14971), (0x53C0, 856), (0x53C1, 13679), (0x53C2, 9451), (0x53C3, 857),
+    (0x53C4, 858), (0x53C5, 859), (0x53C6, 860), (0x53C7, 861), (0x53C8, 15729), (0x53C9, 9475),
+    (0x53CA, 11321), (0x53CB, 15724), (0x53CC, 14166), (0x53CD, 10375), (0x53CE, 862), (0x53CF, 863),
+    (0x53D0, 864), (0x53D1, 10357), (0x53D2, 865), (0x53D3, 866), (0x53D4, 14034), (0x53D5, 867),
+    (0x53D6, 13586), (0x53D7, 14025), (0x53D8, 9283), (0x53D9, 15185), (0x53DA, 868), (0x53DB, 13064),
+    (0x53DC, 869), (0x53DD, 870), (0x53DE, 871), (0x53DF, 17232), (0x53E0, 10069), (0x53E1, 872),
+    (0x53E2, 873), (0x53E3, 11933), (0x53E4, 10772), (0x53E5, 11753), (0x53E6, 12332), (0x53E7, 874),
+    (0x53E8, 17977), (0x53E9, 17976), (0x53EA, 16272), (0x53EB, 11543), (0x53EC, 16112), (0x53ED, 9065),
+    (0x53EE, 10168), (0x53EF, 11916), (0x53F0, 14353), (0x53F1, 17974), (0x53F2, 13988), (0x53F3, 15725),
+    (0x53F4, 875), (0x53F5, 16672), (0x53F6, 15507), (0x53F7, 10962), (0x53F8, 14185), (0x53F9, 14375),
+    (0x53FA, 876), (0x53FB, 17978), (0x53FC, 10055), (0x53FD, 17975), (0x53FE, 877), (0x53FF, 878),
+    (0x5400, 879), (0x5401, 15760), (0x5402, 880), (0x5403, 9647), (0x5404, 10632), (0x5405, 881),
+    (0x5406, 17981), (0x5407, 882), (0x5408, 10972), (0x5409, 11315), (0x540A, 10060), (0x540B, 883),
+    (0x540C, 14547), (0x540D, 12726), (0x540E, 11008), (0x540F, 12149), (0x5410, 14569), (0x5411, 14997),
+    (0x5412, 17979), (0x5413, 14952), (0x5414, 884), (0x5415, 12477), (0x5416, 17980), (0x5417, 12525),
+    (0x5418, 885), (0x5419, 886), (0x541A, 887), (0x541B, 11778), (0x541C, 888), (0x541D, 12318),
+    (0x541E, 14579), (0x541F, 15572), (0x5420, 10400), (0x5421, 17988), (0x5422, 889), (0x5423, 17991),
+    (0x5424, 890), (0x5425, 891), (0x5426, 10436), (0x5427, 9066), (0x5428, 10219), (0x5429, 10407),
+    (0x542A, 892), (0x542B, 10937), (0x542C, 14438), (0x542D, 11927), (0x542E, 14172), (0x542F, 13289),
+    (0x5430, 893), (0x5431, 16253), (0x5432, 17992), (0x5433, 894), (0x5434, 14791), (0x5435, 9614),
+    (0x5436, 895), (0x5437, 896), (0x5438, 14817), (0x5439, 9806), (0x543A, 897), (0x543B, 14764),
+    (0x543C, 11005), (0x543D, 898), (0x543E, 14790), (0x543F, 899), (0x5440, 15324), (0x5441, 900),
+    (0x5442, 901), (0x5443, 17987), (0x5444, 902), (0x5445, 903), (0x5446, 9869), (0x5447, 904),
+    (0x5448, 9637), (0x5449, 905), (0x544A, 10615), (0x544B, 17982), (0x544C, 906), (0x544D, 907),
+    (0x544E, 908), (0x544F, 909), (0x5450, 12862), (0x5451, 910), (0x5452, 17983), (0x5453, 17984),
+    (0x5454, 17985), (0x5455, 13042), (0x5456, 17986), (0x5457, 17989), (0x5458, 15882), (0x5459, 17990),
+    (0x545A, 911), (0x545B, 13421), (0x545C, 14781), (0x545D, 912), (0x545E, 913), (0x545F, 914),
+    (0x5460, 915), (0x5461, 916), (0x5462, 12881), (0x5463, 917), (0x5464, 17997), (0x5465, 918),
+    (0x5466, 18002), (0x5467, 919), (0x5468, 16305), (0x5469, 920), (0x546A, 921), (0x546B, 922),
+    (0x546C, 923), (0x546D, 924), (0x546E, 925), (0x546F, 926), (0x5470, 927), (0x5471, 17996),
+    (0x5472, 18013), (0x5473, 14747), (0x5474, 928), (0x5475, 10964), (0x5476, 18001), (0x5477, 17995),
+    (0x5478, 13077), (0x5479, 929), (0x547A, 930), (0x547B, 13850), (0x547C, 11009), (0x547D, 12727),
+    (0x547E, 931), (0x547F, 932), (0x5480, 11740), (0x5481, 933), (0x5482, 17993), (0x5483, 934),
+    (0x5484, 18000), (0x5485, 935), (0x5486, 13071), (0x5487, 936), (0x5488, 937), (0x5489, 938),
+    (0x548A, 939), (0x548B, 16061), (0x548C, 10970), (0x548D, 940), (0x548E, 11729), (0x548F, 15704),
+    (0x5490, 10577), (0x5491, 941), (0x5492, 16313), (0x5493, 942), (0x5494, 17994), (0x5495, 10765),
+    (0x5496, 11882), (0x5497, 943), (0x5498, 944), (0x5499, 12347), (0x549A, 17998), (0x549B, 17999),
+    (0x549C, 945), (0x549D, 18003), (0x549E, 946), (0x549F, 947), (0x54A0, 948), (0x54A1, 949),
+    (0x54A2, 950), (0x54A3, 18014), (0x54A4, 18024), (0x54A5, 951), (0x54A6, 18010), (0x54A7, 18009),
+    (0x54A8, 16476), (0x54A9, 18022), (0x54AA, 18023), (0x54AB, 19170), (0x54AC, 15492), (0x54AD, 18005),
+    (0x54AE, 952), (0x54AF, 11884), (0x54B0, 953), (0x54B1, 15924), (0x54B2, 954), (0x54B3, 11915),
+    (0x54B4, 18007), (0x54B5, 955), (0x54B6, 956), (0x54B7, 957), (0x54B8, 14959), (0x54B9, 958),
+    (0x54BA, 959), (0x54BB, 18016), (0x54BC, 960), (0x54BD, 15337), (0x54BE, 961), (0x54BF, 18017),
+    (0x54C0, 9032), (0x54C1, 13228), (0x54C2, 18006), (0x54C3, 962), (0x54C4, 10994), (0x54C5, 963),
+    (0x54C6, 10228), (0x54C7, 14594), (0x54C8, 10829), (0x54C9, 15918), (0x54CA, 964), (0x54CB, 965),
+    (0x54CC, 18018), (0x54CD, 14991), (0x54CE, 9030), (0x54CF, 18026), (0x54D0, 18004), (0x54D1, 15333),
+    (0x54D2, 18008), (0x54D3, 18011), (0x54D4, 18012), (0x54D5, 18015), (0x54D6, 966), (0x54D7, 11124),
+    (0x54D8, 967), (0x54D9, 18019), (0x54DA, 18020), (0x54DB, 968), (0x54DC, 18021), (0x54DD, 18025),
+    (0x54DE, 18027), (0x54DF, 15695), (0x54E0, 969), (0x54E1, 970), (0x54E2, 971), (0x54E3, 972),
+    (0x54E4, 973), (0x54E5, 10616), (0x54E6, 13037), (0x54E7, 18029), (0x54E8, 13833), (0x54E9, 12263),
+    (0x54EA, 12861), (0x54EB, 974), (0x54EC, 975), (0x54ED, 11937), (0x54EE, 15003), (0x54EF, 976),
+    (0x54F0, 977), (0x54F1, 978), (0x54F2, 16115), (0x54F3, 18033), (0x54F4, 979), (0x54F5, 980),
+    (0x54F6, 981), (0x54F7, 982), (0x54F8, 983), (0x54F9, 984), (0x54FA, 9429), (0x54FB, 985),
+    (0x54FC, 10988), (0x54FD, 18031), (0x54FE, 986), (0x54FF, 17228), (0x5500, 987), (0x5501, 15363),
+    (0x5502, 988), (0x5503, 989), (0x5504, 990), (0x5505, 991), (0x5506, 14239), (0x5507, 9814),
+    (0x5508, 992), (0x5509, 9031), (0x550A, 993), (0x550B, 994), (0x550C, 995), (0x550D, 996),
+    (0x550E, 997), (0x550F, 18036), (0x5510, 14383), (0x5511, 18037), (0x5512, 998), (0x5513, 999),
+    (0x5514, 18032), (0x5515, 1000), (0x5516, 1001), (0x5517, 1002), (0x5518, 1003), (0x5519, 1004),
+    (0x551A, 1005), (0x551B, 18028), (0x551C, 1006), (0x551D, 1007), (0x551E, 1008), (0x551F, 1009),
+    (0x5520, 18030), (0x5521, 1010), (0x5522, 18034), (0x5523, 18035), (0x5524, 11144), (0x5525, 1011),
+    (0x5526, 1012), (0x5527, 18038), (0x5528, 1013), (0x5529, 1014), (0x552A, 18039), (0x552B, 1015),
+    (0x552C, 11118), (0x552D, 1016), (0x552E, 14024), (0x552F, 14733), (0x5530, 18152), (0x5531, 9605),
+    (0x5532, 1017), (0x5533, 18151), (0x5534, 1018), (0x5535, 1019), (0x5536, 1020), (0x5537, 18146),
+    (0x5538, 1021), (0x5539, 1022), (0x553A, 1023), (0x553B, 1024), (0x553C, 18049), (0x553D, 1025),
+    (0x553E, 14592), (0x553F, 18047), (0x5540, 1026), (0x5541, 18045), (0x5542, 1027), (0x5543, 11923),
+    (0x5544, 16471), (0x5545, 1028), (0x5546, 13819), (0x5547, 1029), (0x5548, 1030), (0x5549, 18043),
+    (0x554A, 9026), (0x554B, 1031), (0x554C, 1032), (0x554D, 1033), (0x554E, 1034), (0x554F, 1035),
+    (0x5550, 18048), (0x5551, 1036), (0x5552, 1037), (0x5553, 1038), (0x5554, 1039), (0x5555, 18046),
+    (0x5556, 18147), (0x5557, 1040), (0x5558, 1041), (0x5559, 1042), (0x555A, 1043), (0x555B, 1044),
+    (0x555C, 18153), (0x555D, 1045), (0x555E, 1046), (0x555F, 1047), (0x5560, 1048), (0x5561, 10395),
+    (0x5562, 1049), (0x5563, 1050), (0x5564, 13206), (0x5565, 13797), (0x5566, 12083), (0x5567, 18040),
+    (0x5568, 1051), (0x5569, 1052), (0x556A, 13045), (0x556B, 1053), (0x556C, 16661), (0x556D, 18044),
+    (0x556E, 12911), (0x556F, 1054), (0x5570, 1055), (0x5571, 1056), (0x5572, 1057), (0x5573, 1058),
+    (0x5574, 1059), (0x5575, 18148), (0x5576, 18149), (0x5577, 18150), (0x5578, 15110), (0x5579, 1060),
+    (0x557A, 1061), (0x557B, 18165), (0x557C, 14413), (0x557D, 1062), (0x557E, 18162), (0x557F, 1063),
+    (0x5580, 11881), (0x5581, 18160), (0x5582, 14750), (0x5583, 18156), (0x5584, 13813), (0x5585, 1064),
+    (0x5586, 1065), (0x5587, 12079), (0x5588, 18159), (0x5589, 11002), (0x558A, 10941), (0x558B, 18154),
+    (0x558C, 1066), (0x558D, 1067), (0x558E, 1068), (0x558F, 18041), (0x5590, 1069), (0x5591, 18164),
+    (0x5592, 1070), (0x5593, 1071), (0x5594, 18169), (0x5595, 1072), (0x5596, 1073), (0x5597, 1074),
+    (0x5598, 9798), (0x5599, 18170), (0x559A, 1075), (0x559B, 1076), (0x559C, 14933), (0x559D, 10965),
+    (0x559E, 1077), (0x559F, 18161), (0x55A0, 1078), (0x55A1, 1079), (0x55A2, 1080), (0x55A3, 1081),
+    (0x55A4, 1082), (0x55A5, 1083), (0x55A6, 1084), (0x55A7, 15195), (0x55A8, 1085), (0x55A9, 1086),
+    (0x55AA, 1087), (0x55AB, 1088), (0x55AC, 1089), (0x55AD, 1090), (0x55AE, 1091), (0x55AF, 1092),
+    (0x55B0, 1093), (0x55B1, 18157), (0x55B2, 1094), (0x55B3, 15956), (0x55B4, 1095), (0x55B5, 18042),
+    (0x55B6, 1096), (0x55B7, 13086), (0x55B8, 1097), (0x55B9, 18158), (0x55BA, 1098), (0x55BB, 15762),
+    (0x55BC, 1099), (0x55BD, 18167), (0x55BE, 18168), (0x55BF, 1100), (0x55C0, 1101), (0x55C1, 1102),
+    (0x55C2, 1103), (0x55C3, 1104), (0x55C4, 18181), (0x55C5, 15170), (0x55C6, 1105), (0x55C7, 1106),
+    (0x55C8, 1107), (0x55C9, 18173), (0x55CA, 1108), (0x55CB, 1109), (0x55CC, 18186), (0x55CD, 18187),
+    (0x55CE, 1110), (0x55CF, 1111), (0x55D0, 1112), (0x55D1, 18175), (0x55D2, 18155), (0x55D3, 13779),
+    (0x55D4, 18178), (0x55D5, 1113), (0x55D6, 18163), (0x55D7, 1114), (0x55D8, 1115), (0x55D9, 1116),
+    (0x55DA, 1117), (0x55DB, 1118), (0x55DC, 14005), (0x55DD, 18180), (0x55DE, 1119), (0x55DF, 18166),
+    (0x55E0, 1120), (0x55E1, 14768), (0x55E2, 1121), (0x55E3, 14190), (0x55E4, 18190), (0x55E5, 18183),
+    (0x55E6, 18179), (0x55E7, 1122), (0x55E8, 18188), (0x55E9, 1123), (0x55EA, 18171), (0x55EB, 18176),
+    (0x55EC, 18177), (0x55ED, 1124), (0x55EE, 1125), (0x55EF, 18182), (0x55F0, 1126), (0x55F1, 1127),
+    (0x55F2, 18184), (0x55F3, 18185), (0x55F4, 1128), (0x55F5, 18189), (0x55F6, 1129), (0x55F7, 18172),
+    (0x55F8, 1130), (0x55F9, 1131), (0x55FA, 1132), (0x55FB, 1133), (0x55FC, 1134), (0x55FD, 14207),
+    (0x55FE, 18198), (0x55FF, 1135), (0x5600, 18199), (0x5601, 18195), (0x5602, 1136), (0x5603, 1137),
+    (0x5604, 1138), (0x5605, 1139), (0x5606, 1140), (0x5607, 1141), (0x5608, 18193), (0x5609, 11351),
+    (0x560A, 1142), (0x560B, 1143), (0x560C, 18194), (0x560D, 1144), (0x560E, 10579), (0x560F, 16662),
+    (0x5610, 1145), (0x5611, 1146), (0x5612, 1147), (0x5613, 1148), (0x5614, 1149), (0x5615, 1150),
+    (0x5616, 1151), (0x5617, 1152), (0x5618, 15179), (0x5619, 1153), (0x561A, 1154), (0x561B, 12524),
+    (0x561C, 1155), (0x561D, 1156), (0x561E, 18192), (0x561F, 18174), (0x5620, 1157), (0x5621, 1158),
+    (0x5622, 1159), (0x5623, 18197), (0x5624, 18196), (0x5625, 1160), (0x5626, 1161), (0x5627, 18200),
+    (0x5628, 1162), (0x5629, 1163), (0x562A, 1164), (0x562B, 1165), (0x562C, 18205), (0x562D, 18201),
+    (0x562E, 1166), (0x562F, 1167), (0x5630, 1168), (0x5631, 16331), (0x5632, 9611), (0x5633, 1169),
+    (0x5634, 16511), (0x5635, 1170), (0x5636, 14182), (0x5637, 1171), (0x5638, 1172), (0x5639, 18203),
+    (0x563A, 1173), (0x563B, 14816), (0x563C, 1174), (0x563D, 1175), (0x563E, 1176), (0x563F, 10982),
+    (0x5640, 1177), (0x5641, 1178), (0x5642, 1179), (0x5643, 1180), (0x5644, 1181), (0x5645, 1182),
+    (0x5646, 1183), (0x5647, 1184), (0x5648, 1185), (0x5649, 1186), (0x564A, 1187), (0x564B, 1188),
+    (0x564C, 18210), (0x564D, 18206), (0x564E, 15498), (0x564F, 1189), (0x5650, 1190), (0x5651, 1191),
+    (0x5652, 1192), (0x5653, 1193), (0x5654, 18211), (0x5655, 1194), (0x5656, 1195), (0x5657, 18204),
+    (0x5658, 18202), (0x5659, 18208), (0x565A, 1196), (0x565B, 1197), (0x565C, 18209), (0x565D, 1198),
+    (0x565E, 1199), (0x565F, 1200), (0x5660, 1201), (0x5661, 1202), (0x5662, 18207), (0x5663, 1203),
+    (0x5664, 18213), (0x5665, 1204), (0x5666, 1205), (0x5667, 1206), (0x5668, 13292), (0x5669, 16637),
+    (0x566A, 15940), (0x566B, 18215), (0x566C, 14006), (0x566D, 1207), (0x566E, 1208), (0x566F, 1209),
+    (0x5670, 1210), (0x5671, 18214), (0x5672, 1211), (0x5673, 1212), (0x5674, 1213), (0x5675, 1214),
+    (0x5676, 10578), (0x5677, 1215), (0x5678, 1216), (0x5679, 1217), (0x567A, 1218), (0x567B, 18216),
+    (0x567C, 18217), (0x567D, 1219), (0x567E, 1220), (0x567F, 1221), (0x5680, 1222), (0x5681, 1223),
+    (0x5682, 1224), (0x5683, 1225), (0x5684, 1226), (0x5685, 18218), (0x5686, 18212), (0x5687, 1227),
+    (0x5688, 1228), (0x5689, 1229), (0x568A, 1230), (0x568B, 1231), (0x568C, 1232), (0x568D, 1233),
+    (0x568E, 10956), (0x568F, 14416), (0x5690, 1234), (0x5691, 1235), (0x5692, 1236), (0x5693, 18219),
+    (0x5694, 1237), (0x5695, 1238), (0x5696, 1239), (0x5697, 1240), (0x5698, 1241), (0x5699, 1242),
+    (0x569A, 1243), (0x569B, 1244), (0x569C, 1245), (0x569D, 1246), (0x569E, 1247), (0x569F, 1248),
+    (0x56A0, 1249), (0x56A1, 1250), (0x56A2, 1251), (0x56A3, 15004), (0x56A4, 1252), (0x56A5, 1253),
+    (0x56A6, 1254), (0x56A7, 1255), (0x56A8, 1256), (0x56A9, 1257), (0x56AA, 1258), (0x56AB, 1259),
+    (0x56AC, 1260), (0x56AD, 1261), (0x56AE, 1262), (0x56AF, 18220), (0x56B0, 1263), (0x56B1, 1264),
+    (0x56B2, 1265), (0x56B3, 1266), (0x56B4, 1267), (0x56B5, 1268), (0x56B6, 1269), (0x56B7, 13619),
+    (0x56B8, 1270), (0x56B9, 1271), (0x56BA, 1272), (0x56BB, 1273), (0x56BC, 11527), (0x56BD, 1274),
+    (0x56BE, 1275), (0x56BF, 1276), (0x56C0, 1277), (0x56C1, 1278), (0x56C2, 1279), (0x56C3, 1280),
+    (0x56C4, 1281), (0x56C5, 1282), (0x56C6, 1283), (0x56C7, 1284), (0x56C8, 1285), (0x56C9, 1286),
+    (0x56CA, 12875), (0x56CB, 1287), (0x56CC, 1288), (0x56CD, 1289), (0x56CE, 1290), (0x56CF, 1291),
+    (0x56D0, 1292), (0x56D1, 1293), (0x56D2, 1294), (0x56D3, 1295), (0x56D4, 18221), (0x56D5, 1296),
+    (0x56D6, 1297), (0x56D7, 18222), (0x56D8, 1298), (0x56D9, 1299), (0x56DA, 13479), (0x56DB, 14191),
+    (0x56DC, 1300), (0x56DD, 18223), (0x56DE, 11171), (0x56DF, 16647), (0x56E0, 15567), (0x56E1, 18224),
+    (0x56E2, 14572), (0x56E3, 1301), (0x56E4, 10223), (0x56E5, 1302), (0x56E6, 1303), (0x56E7, 1304),
+    (0x56E8, 1305), (0x56E9, 1306), (0x56EA, 1307), (0x56EB, 18226), (0x56EC, 1308), (0x56ED, 15881),
+    (0x56EE, 1309), (0x56EF, 1310), (0x56F0, 12072), (0x56F1, 9834), (0x56F2, 1311), (0x56F3, 1312),
+    (0x56F4, 14732), (0x56F5, 18225), (0x56F6, 1313), (0x56F7, 1314), (0x56F8, 1315), (0x56F9, 18227),
+    (0x56FA, 10779), (0x56FB, 1316), (0x56FC, 1317), (0x56FD, 10825), (0x56FE, 14563), (0x56FF, 18228),
+    (0x5700, 1318), (0x5701, 1319), (0x5702, 1320), (0x5703, 13257), (0x5704, 18229), (0x5705, 1321),
+    (0x5706, 15883), (0x5707, 1322), (0x5708, 13591), (0x5709, 18231), (0x570A, 18230), (0x570B, 1323),
+    (0x570C, 1324), (0x570D, 1325), (0x570E, 1326), (0x570F, 1327), (0x5710, 1328), (0x5711, 1329),
+    (0x5712, 1330), (0x5713, 1331), (0x5714, 1332), (0x5715, 1333), (0x5716, 1334), (0x5717, 1335),
+    (0x5718, 1336), (0x5719, 1337), (0x571A, 1338), (0x571B, 1339), (0x571C, 18232), (0x571D, 1340),
+    (0x571E, 1341), (0x571F, 14568), (0x5720, 1342), (0x5721, 1343), (0x5722, 1344), (0x5723, 13970),
+    (0x5724, 1345), (0x5725, 1346), (0x5726, 1347), (0x5727, 1348), (0x5728, 15923), (0x5729, 17250),
+    (0x572A, 17252), (0x572B, 1349), (0x572C, 17251), (0x572D, 10806), (0x572E, 17255), (0x572F, 17256),
+    (0x5730, 10031), (0x5731, 1350), (0x5732, 1351), (0x5733, 17253), (0x5734, 1352), (0x5735, 1353),
+    (0x5736, 1354), (0x5737, 1355), (0x5738, 1356), (0x5739, 17254), (0x573A, 9596), (0x573B, 17258),
+    (0x573C, 1357), (0x573D, 1358), (0x573E, 11203), (0x573F, 1359), (0x5740, 16268), (0x5741, 1360),
+    (0x5742, 17259), (0x5743, 1361), (0x5744, 1362), (0x5745, 1363), (0x5746, 1364), (0x5747, 11774),
+    (0x5748, 1365), (0x5749, 1366), (0x574A, 10382), (0x574B, 1367), (0x574C, 17243), (0x574D, 14359),
+    (0x574E, 11893), (0x574F, 11136), (0x5750, 16523), (0x5751, 11926), (0x5752, 1368), (0x5753, 1369),
+    (0x5754, 1370), (0x5755, 1371), (0x5756, 1372), (0x5757, 11948), (0x5758, 1373), (0x5759, 1374),
+    (0x575A, 11370), (0x575B, 14364), (0x575C, 17257), (0x575D, 9076), (0x575E, 14800), (0x575F, 10411),
+    (0x5760, 16460), (0x5761, 13239), (0x5762, 1375), (0x5763, 1376), (0x5764, 12069), (0x5765, 1377),
+    (0x5766, 14370), (0x5767, 1378), (0x5768, 17266), (0x5769, 17260), (0x576A, 13231), (0x576B, 17262),
+    (0x576C, 1379), (0x576D, 17267), (0x576E, 1380), (0x576F, 13102), (0x5770, 1381), (0x5771, 1382),
+    (0x5772, 1383), (0x5773, 17269), (0x5774, 1384), (0x5775, 1385), (0x5776, 17268), (0x5777, 11907),
+    (0x5778, 1386), (0x5779, 1387), (0x577A, 1388), (0x577B, 17265), (0x577C, 17264), (0x577D, 1389),
+    (0x577E, 1390), (0x577F, 1391), (0x5780, 1392), (0x5781, 1393), (0x5782, 9810), (0x5783, 12077),
+    (0x5784, 12447), (0x5785, 17261), (0x5786, 17263), (0x5787, 1394), (0x5788, 1395), (0x5789, 1396),
+    (0x578A, 1397), (0x578B, 15150), (0x578C, 17272), (0x578D, 1398), (0x578E, 1399), (0x578F, 1400),
+    (0x5790, 1401), (0x5791, 1402), (0x5792, 12126), (0x5793, 17277), (0x5794, 1403), (0x5795, 1404),
+    (0x5796, 1405), (0x5797, 1406), (0x5798, 1407), (0x5799, 1408), (0x579A, 1409), (0x579B, 10231),
+    (0x579C, 1410), (0x579D, 1411), (0x579E, 1412), (0x579F, 1413), (0x57A0, 17278), (0x57A1, 17245),
+    (0x57A2, 10759), (0x57A3, 15876), (0x57A4, 17271), (0x57A5, 1414), (0x57A6, 11924), (0x57A7, 17275),
+    (0x57A8, 1415), (0x57A9, 17244), (0x57AA, 1416), (0x57AB, 10045), (0x57AC, 1417), (0x57AD, 17270),
+    (0x57AE, 11944), (0x57AF, 1418), (0x57B0, 1419), (0x57B1, 1420), (0x57B2, 17273), (0x57B3, 1421),
+    (0x57B4, 17276), (0x57B5, 1422), (0x57B6, 1423), (0x57B7, 1424), (0x57B8, 17284), (0x57B9, 1425),
+    (0x57BA, 1426), (0x57BB, 1427), (0x57BC, 1428), (0x57BD, 1429), (0x57BE, 1430), (0x57BF, 1431),
+    (0x57C0, 1432), (0x57C1, 1433), (0x57C2, 10736), (0x57C3, 9028), (0x57C4, 1434), (0x57C5, 1435),
+    (0x57C6, 1436), (0x57C7, 1437), (0x57C8, 1438), (0x57C9, 1439), (0x57CA, 1440), (0x57CB, 12526),
+    (0x57CC, 1441), (0x57CD, 1442), (0x57CE, 9634), (0x57CF, 17274), (0x57D0, 1443), (0x57D1, 1444),
+    (0x57D2, 17283), (0x57D3, 1445), (0x57D4, 13255), (0x57D5, 17279), (0x57D6, 1446), (0x57D7, 1447),
+    (0x57D8, 17280), (0x57D9, 17282), (0x57DA, 17281), (0x57DB, 1448), (0x57DC, 1449), (0x57DD, 17289),
+    (0x57DE, 1450), (0x57DF, 15757), (0x57E0, 9431), (0x57E1, 1451), (0x57E2, 1452), (0x57E3, 1453),
+    (0x57E4, 17288), (0x57E5, 1454), (0x57E6, 1455), (0x57E7, 1456), (0x57E8, 1457), (0x57E9, 1458),
+    (0x57EA, 1459), (0x57EB, 1460), (0x57EC, 1461), (0x57ED, 17389), (0x57EE, 1462), (0x57EF, 17286),
+    (0x57F0, 1463), (0x57F1, 1464), (0x57F2, 1465), (0x57F3, 1466), (0x57F4, 17285), (0x57F5, 1467),
+    (0x57F6, 1468), (0x57F7, 1469), (0x57F8, 17287), (0x57F9, 13079), (0x57FA, 11204), (0x57FB, 1470),
+    (0x57FC, 1471), (0x57FD, 17388), (0x57FE, 1472), (0x57FF, 1473), (0x5800, 17390), (0x5801, 1474),
+    (0x5802, 14380), (0x5803, 1475), (0x5804, 1476), (0x5805, 1477), (0x5806, 10214), (0x5807, 17607),
+    (0x5808, 1478), (0x5809, 1479), (0x580A, 1480), (0x580B, 17386), (0x580C, 1481), (0x580D, 17387),
+    (0x580E, 1482), (0x580F, 1483), (0x5810, 1484), (0x5811, 13416), (0x5812, 1485), (0x5813, 1486),
+    (0x5814, 1487), (0x5815, 10238), (0x5816, 1488), (0x5817, 1489), (0x5818, 1490), (0x5819, 17392),
+    (0x581A, 1491), (0x581B, 1492), (0x581C, 1493), (0x581D, 1494), (0x581E, 17391), (0x581F, 1495),
+    (0x5820, 17394), (0x5821, 9219), (0x5822, 1496), (0x5823, 1497), (0x5824, 10019), (0x5825, 1498),
+    (0x5826, 1499), (0x5827, 1500), (0x5828, 1501), (0x5829, 1502), (0x582A, 11891), (0x582B, 1503),
+    (0x582C, 1504), (0x582D, 1505), (0x582E, 1506), (0x582F, 1507), (0x5830, 15358), (0x5831, 1508),
+    (0x5832, 1509), (0x5833, 1510), (0x5834, 1511), (0x5835, 10199), (0x5836, 1512), (0x5837, 1513),
+    (0x5838, 1514), (0x5839, 1515), (0x583A, 1516), (0x583B, 1517), (0x583C, 1518), (0x583D, 1519),
+    (0x583E, 1520), (0x583F, 1521), (0x5840, 1522), (0x5841, 1523), (0x5842, 1524), (0x5843, 1525),
+    (0x5844, 17393), (0x5845, 1526), (0x5846, 1527), (0x5847, 1528), (0x5848, 1529), (0x5849, 1530),
+    (0x584A, 1531), (0x584B, 1532), (0x584C, 14245), (0x584D, 20318), (0x584E, 1533), (0x584F, 1534),
+    (0x5850, 1535), (0x5851, 14215), (0x5852, 1536), (0x5853, 1537), (0x5854, 14249), (0x5855, 1538),
+    (0x5856, 1539), (0x5857, 1540), (0x5858, 14378), (0x5859, 1541), (0x585A, 1542), (0x585B, 1543),
+    (0x585C, 1544), (0x585D, 1545), (0x585E, 13676), (0x585F, 1546), (0x5860, 1547), (0x5861, 1548),
+    (0x5862, 1549), (0x5863, 1550), (0x5864, 1551), (0x5865, 17395), (0x5866, 1552), (0x5867, 1553),
+    (0x5868, 1554), (0x5869, 1555), (0x586A, 1556), (0x586B, 14423), (0x586C, 17396), (0x586D, 1557),
+    (0x586E, 1558), (0x586F, 1559), (0x5870, 1560), (0x5871, 1561), (0x5872, 1562), (0x5873, 1563),
+    (0x5874, 1564), (0x5875, 1565), (0x5876, 1566), (0x5877, 1567), (0x5878, 1568), (0x5879, 1569),
+    (0x587A, 1570), (0x587B, 1571), (0x587C, 1572), (0x587D, 1573), (0x587E, 17246), (0x587F, 1574),
+    (0x5880, 17400), (0x5881, 17397), (0x5882, 1575), (0x5883, 11704), (0x5884, 1576), (0x5885, 14056),
+    (0x5886, 1577), (0x5887, 1578), (0x5888, 1579), (0x5889, 17398), (0x588A, 1580), (0x588B, 1581),
+    (0x588C, 1582), (0x588D, 1583), (0x588E, 1584), (0x588F, 1585), (0x5890, 1586), (0x5891, 1587),
+    (0x5892, 13817), (0x5893, 12850), (0x5894, 1588), (0x5895, 1589), (0x5896, 1590), (0x5897, 1591),
+    (0x5898, 1592), (0x5899, 13424), (0x589A, 17399), (0x589B, 1593), (0x589C, 1594), (0x589D, 1595),
+    (0x589E, 15951), (0x589F, 15175), (0x58A0, 1596), (0x58A1, 1597), (0x58A2, 1598), (0x58A3, 1599),
+    (0x58A4, 1600), (0x58A5, 1601), (0x58A6, 1602), (0x58A7, 1603), (0x58A8, 12836), (0x58A9, 10218),
+    (0x58AA, 1604), (0x58AB, 1605), (0x58AC, 1606), (0x58AD, 1607), (0x58AE, 1608), (0x58AF, 1609),
+    (0x58B0, 1610), (0x58B1, 1611), (0x58B2, 1612), (0x58B3, 1613), (0x58B4, 1614), (0x58B5, 1615),
+    (0x58B6, 1616), (0x58B7, 1617), (0x58B8, 1618), (0x58B9, 1619), (0x58BA, 1620), (0x58BB, 1621),
+    (0x58BC, 17247), (0x58BD, 1622), (0x58BE, 1623), (0x58BF, 1624), (0x58C0, 1625), (0x58C1, 9273),
+    (0x58C2, 1626), (0x58C3, 1627), (0x58C4, 1628), (0x58C5, 17248), (0x58C6, 1629), (0x58C7, 1630),
+    (0x58C8, 1631), (0x58C9, 1632), (0x58CA, 1633), (0x58CB, 1634), (0x58CC, 1635), (0x58CD, 1636),
+    (0x58CE, 1637), (0x58CF, 1638), (0x58D0, 1639), (0x58D1, 17249), (0x58D2, 1640), (0x58D3, 1641),
+    (0x58D4, 1642), (0x58D5, 10955), (0x58D6, 1643), (0x58D7, 1644), (0x58D8, 1645), (0x58D9, 1646),
+    (0x58DA, 1647), (0x58DB, 1648), (0x58DC, 1649), (0x58DD, 1650), (0x58DE, 1651), (0x58DF, 1652),
+    (0x58E0, 1653), (0x58E1, 1654), (0x58E2, 1655), (0x58E3, 1656), (0x58E4, 13617), (0x58E5, 1657),
+    (0x58E6, 1658), (0x58E7, 1659), (0x58E8, 1660), (0x58E9, 1661), (0x58EA, 1662), (0x58EB, 13996),
+    (0x58EC, 13626), (0x58ED, 1663), (0x58EE, 16454), (0x58EF, 1664), (0x58F0, 13864), (0x58F1, 1665),
+    (0x58F2, 1666), (0x58F3, 11914), (0x58F4, 1667), (0x58F5, 1668), (0x58F6, 11013), (0x58F7, 1669),
+    (0x58F8, 1670), (0x58F9, 15513), (0x58FA, 1671), (0x58FB, 1672), (0x58FC, 1673), (0x58FD, 1674),
+    (0x58FE, 1675), (0x58FF, 1676), (0x5900, 1677), (0x5901, 1678), (0x5902, 18551), (0x5903, 1679),
+    (0x5904, 9791), (0x5905, 1680), (0x5906, 1681), (0x5907, 9239), (0x5908, 1682), (0x5909, 1683),
+    (0x590A, 1684), (0x590B, 1685), (0x590C, 1686), (0x590D, 10565), (0x590E, 1687), (0x590F, 14951),
+    (0x5910, 1688), (0x5911, 1689), (0x5912, 1690), (0x5913, 1691), (0x5914, 16886), (0x5915, 14921),
+    (0x5916, 14601), (0x5917, 1692), (0x5918, 1693), (0x5919, 16892), (0x591A, 10229), (0x591B, 1694),
+    (0x591C, 15510), (0x591D, 1695), (0x591E, 1696), (0x591F, 10762), (0x5920, 1697), (0x5921, 1698),
+    (0x5922, 1699), (0x5923, 1700), (0x5924, 18550), (0x5925, 18548), (0x5926, 1701), (0x5927, 9868),
+    (0x5928, 1702), (0x5929, 14421), (0x592A, 14356), (0x592B, 10437), (0x592C, 1703), (0x592D, 16643),
+    (0x592E, 15370), (0x592F, 10952), (0x5930, 1704), (0x5931, 13972), (0x5932, 1705), (0x5933, 1706),
+    (0x5934, 14558), (0x5935, 1707), (0x5936, 1708), (0x5937, 15521), (0x5938, 11943), (0x5939, 11353),
+    (0x593A, 10230), (0x593B, 1709), (0x593C, 17802), (0x593D, 1710), (0x593E, 1711), (0x593F, 1712),
+    (0x5940, 1713), (0x5941, 17803), (0x5942, 17223), (0x5943, 1714), (0x5944, 15352), (0x5945, 1715),
+    (0x5946, 1716), (0x5947, 13275), (0x5948, 12871), (0x5949, 10433), (0x594A, 1717), (0x594B, 10415),
+    (0x594C, 1718), (0x594D, 1719), (0x594E, 11967), (0x594F, 16499), (0x5950, 1720), (0x5951, 13290),
+    (0x5952, 1721), (0x5953, 1722), (0x5954, 9243), (0x5955, 17805), (0x5956, 11512), (0x5957, 14400),
+    (0x5958, 17807), (0x5959, 1723), (0x595A, 17806), (0x595B, 1724), (0x595C, 1725), (0x595D, 1726),
+    (0x595E, 1727), (0x595F, 1728), (0x5960, 10051), (0x5961, 1729), (0x5962, 13836), (0x5963, 1730),
+    (0x5964, 1731), (0x5965, 9059), (0x5966, 1732), (0x5967, 1733), (0x5968, 1734), (0x5969, 1735),
+    (0x596A, 1736), (0x596B, 1737), (0x596C, 1738), (0x596D, 1739), (0x596E, 1740), (0x596F, 1741),
+    (0x5970, 1742), (0x5971, 1743), (0x5972, 1744), (0x5973, 13029), (0x5974, 13026), (0x5975, 1745),
+    (0x5976, 12869), (0x5977, 1746), (0x5978, 11378), (0x5979, 14248), (0x597A, 1747), (0x597B, 1748),
+    (0x597C, 1749), (0x597D, 10960), (0x597E, 1750), (0x597F, 1751), (0x5980, 1752), (0x5981, 19184),
+    (0x5982, 13656), (0x5983, 19185), (0x5984, 14628), (0x5985, 1753), (0x5986, 16452), (0x5987, 10575),
+    (0x5988, 12517), (0x5989, 1754), (0x598A, 13634), (0x598B, 1755), (0x598C, 1756), (0x598D, 19186),
+    (0x598E, 1757), (0x598F, 1758), (0x5990, 1759), (0x5991, 1760), (0x5992, 10207), (0x5993, 11348),
+    (0x5994, 1761), (0x5995, 1762), (0x5996, 15388), (0x5997, 19286), (0x5998, 1763), (0x5999, 12713),
+    (0x599A, 1764), (0x599B, 1765), (0x599C, 1766), (0x599D, 1767), (0x599E, 19289), (0x599F, 1768),
+    (0x59A0, 1769), (0x59A1, 1770), (0x59A2, 1771), (0x59A3, 19189), (0x59A4, 19290), (0x59A5, 14590),
+    (0x59A6, 1772), (0x59A7, 1773), (0x59A8, 10388), (0x59A9, 19187), (0x59AA, 19188), (0x59AB, 19288),
+    (0x59AC, 1774), (0x59AD, 1775), (0x59AE, 12886), (0x59AF, 19293), (0x59B0, 1776), (0x59B1, 1777),
+    (0x59B2, 19292), (0x59B3, 1778), (0x59B4, 1779), (0x59B5, 1780), (0x59B6, 1781), (0x59B7, 1782),
+    (0x59B8, 1783), (0x59B9, 12670), (0x59BA, 1784), (0x59BB, 13267), (0x59BC, 1785), (0x59BD, 1786),
+    (0x59BE, 19295), (0x59BF, 1787), (0x59C0, 1788), (0x59C1, 1789), (0x59C2, 1790), (0x59C3, 1791),
+    (0x59C4, 1792), (0x59C5, 1793), (0x59C6, 12848), (0x59C7, 1794), (0x59C8, 1795), (0x59C9, 1796),
+    (0x59CA, 19287), (0x59CB, 13993), (0x59CC, 1797), (0x59CD, 1798), (0x59CE, 1799), (0x59CF, 1800),
+    (0x59D0, 11562), (0x59D1, 10770), (0x59D2, 19291), (0x59D3, 15158), (0x59D4, 14740), (0x59D5, 1801),
+    (0x59D6, 1802), (0x59D7, 19294), (0x59D8, 19301), (0x59D9, 1803), (0x59DA, 15491), (0x59DB, 1804),
+    (0x59DC, 11505), (0x59DD, 19298), (0x59DE, 1805), (0x59DF, 1806), (0x59E0, 1807), (0x59E1, 1808),
+    (0x59E2, 1809), (0x59E3, 19300), (0x59E4, 1810), (0x59E5, 12114), (0x59E6, 1811), (0x59E7, 1812),
+    (0x59E8, 15529), (0x59E9, 1813), (0x59EA, 1814), (0x59EB, 1815), (0x59EC, 11312), (0x59ED, 1816),
+    (0x59EE, 1817), (0x59EF, 1818), (0x59F0, 1819), (0x59F1, 1820), (0x59F2, 1821), (0x59F3, 1822),
+    (0x59F4, 1823), (0x59F5, 1824), (0x59F6, 1825), (0x59F7, 1826), (0x59F8, 1827), (0x59F9, 19302),
+    (0x59FA, 1828), (0x59FB, 15571), (0x59FC, 1829), (0x59FD, 1830), (0x59FE, 1831), (0x59FF, 16478),
+    (0x5A00, 1832), (0x5A01, 14629), (0x5A02, 1833), (0x5A03, 14597), (0x5A04, 12451), (0x5A05, 19296),
+    (0x5A06, 19297), (0x5A07, 11526), (0x5A08, 19299), (0x5A09, 19304), (0x5A0A, 1834), (0x5A0B, 1835),
+    (0x5A0C, 19303), (0x5A0D, 1836), (0x5A0E, 1837), (0x5A0F, 1838), (0x5A10, 1839), (0x5A11, 19307),
+    (0x5A12, 1840), (0x5A13, 19309), (0x5A14, 1841), (0x5A15, 1842), (0x5A16, 1843), (0x5A17, 1844),
+    (0x5A18, 12904), (0x5A19, 1845), (0x5A1A, 1846), (0x5A1B, 1847), (0x5A1C, 12865), (0x5A1D, 1848),
+    (0x5A1E, 1849), (0x5A1F, 11759), (0x5A20, 13854), (0x5A21, 1850), (0x5A22, 1851), (0x5A23, 19308),
+    (0x5A24, 1852), (0x5A25, 10245), (0x5A26, 1853), (0x5A27, 1854), (0x5A28, 1855), (0x5A29, 12703),
+    (0x5A2A, 1856), (0x5A2B, 1857), (0x5A2C, 1858), (0x5A2D, 1859), (0x5A2E, 1860), (0x5A2F, 1861),
+    (0x5A30, 1862), (0x5A31, 15748), (0x5A32, 19305), (0x5A33, 1863), (0x5A34, 19306), (0x5A35, 1864),
+    (0x5A36, 13587), (0x5A37, 1865), (0x5A38, 1866), (0x5A39, 1867), (0x5A3A, 1868), (0x5A3B, 1869),
+    (0x5A3C, 19314), (0x5A3D, 1870), (0x5A3E, 1871), (0x5A3F, 1872), (0x5A40, 19310), (0x5A41, 1873),
+    (0x5A42, 1874), (0x5A43, 1875), (0x5A44, 1876), (0x5A45, 1877), (0x5A46, 13242), (0x5A47, 1878),
+    (0x5A48, 1879), (0x5A49, 14616), (0x5A4A, 19312), (0x5A4B, 1880), (0x5A4C, 1881), (0x5A4D, 1882),
+    (0x5A4E, 1883), (0x5A4F, 1884), (0x5A50, 1885), (0x5A51, 1886), (0x5A52, 1887), (0x5A53, 1888),
+    (0x5A54, 1889), (0x5A55, 19313), (0x5A56, 1890), (0x5A57, 1891), (0x5A58, 1892), (0x5A59, 1893),
+    (0x5A5A, 11188), (0x5A5B, 1894), (0x5A5C, 1895), (0x5A5D, 1896), (0x5A5E, 1897), (0x5A5F, 1898),
+    (0x5A60, 1899), (0x5A61, 1900), (0x5A62, 19315), (0x5A63, 1901), (0x5A64, 1902), (0x5A65, 1903),
+    (0x5A66, 1904), (0x5A67, 19311), (0x5A68, 1905), (0x5A69, 1906), (0x5A6A, 12088), (0x5A6B, 1907),
+    (0x5A6C, 1908), (0x5A6D, 1909), (0x5A6E, 1910), (0x5A6F, 1911), (0x5A70, 1912), (0x5A71, 1913),
+    (0x5A72, 1914), (0x5A73, 1915), (0x5A74, 15679), (0x5A75, 19316), (0x5A76, 13859), (0x5A77, 19320),
+    (0x5A78, 1916), (0x5A79, 1917), (0x5A7A, 19321), (0x5A7B, 1918), (0x5A7C, 1919), (0x5A7D, 1920),
+    (0x5A7E, 1921), (0x5A7F, 15191), (0x5A80, 1922), (0x5A81, 1923), (0x5A82, 1924), (0x5A83, 1925),
+    (0x5A84, 1926), (0x5A85, 1927), (0x5A86, 1928), (0x5A87, 1929), (0x5A88, 1930), (0x5A89, 1931),
+    (0x5A8A, 1932), (0x5A8B, 1933), (0x5A8C, 1934), (0x5A8D, 1935), (0x5A8E, 1936), (0x5A8F, 1937),
+    (0x5A90, 1938), (0x5A91, 1939), (0x5A92, 12664), (0x5A93, 1940), (0x5A94, 1941), (0x5A95, 1942),
+    (0x5A96, 1943), (0x5A97, 1944), (0x5A98, 1945), (0x5A99, 1946), (0x5A9A, 12671), (0x5A9B, 19319),
+    (0x5A9C, 1947), (0x5A9D, 1948), (0x5A9E, 1949), (0x5A9F, 1950), (0x5AA0, 1951), (0x5AA1, 1952),
+    (0x5AA2, 1953), (0x5AA3, 1954), (0x5AA4, 1955), (0x5AA5, 1956), (0x5AA6, 1957), (0x5AA7, 1958),
+    (0x5AA8, 1959), (0x5AA9, 1960), (0x5AAA, 19318), (0x5AAB, 1961), (0x5AAC, 1962), (0x5AAD, 1963),
+    (0x5AAE, 1964), (0x5AAF, 1965), (0x5AB0, 1966), (0x5AB1, 1967), (0x5AB2, 19324), (0x5AB3, 14932),
+    (0x5AB4, 1968), (0x5AB5, 20319), (0x5AB6, 1969), (0x5AB7, 1970), (0x5AB8, 19327), (0x5AB9, 1971),
+    (0x5ABA, 1972), (0x5ABB, 1973), (0x5ABC, 1974), (0x5ABD, 1975), (0x5ABE, 19322), (0x5ABF, 1976),
+    (0x5AC0, 1977), (0x5AC1, 11367), (0x5AC2, 13784), (0x5AC3, 1978), (0x5AC4, 1979), (0x5AC5, 1980),
+    (0x5AC6, 1981), (0x5AC7, 1982), (0x5AC8, 1983), (0x5AC9, 11326), (0x5ACA, 1984), (0x5ACB, 1985),
+    (0x5ACC, 14966), (0x5ACD, 1986), (0x5ACE, 1987), (0x5ACF, 1988), (0x5AD0, 1989), (0x5AD1, 1990),
+    (0x5AD2, 19325), (0x5AD3, 1991), (0x5AD4, 19326), (0x5AD5, 1992), (0x5AD6, 19331), (0x5AD7, 1993),
+    (0x5AD8, 19333), (0x5AD9, 1994), (0x5ADA, 1995), (0x5ADB, 1996), (0x5ADC, 19334), (0x5ADD, 1997),
+    (0x5ADE, 1998), (0x5ADF, 1999), (0x5AE0, 19328), (0x5AE1, 10028), (0x5AE2, 2000), (0x5AE3, 19329),
+    (0x5AE4, 2001), (0x5AE5, 2002), (0x5AE6, 19332), (0x5AE7, 2003), (0x5AE8, 2004), (0x5AE9, 12884),
+    (0x5AEA, 2005), (0x5AEB, 19323), (0x5AEC, 2006), (0x5AED, 2007), (0x5AEE, 2008), (0x5AEF, 2009),
+    (0x5AF0, 2010), (0x5AF1, 19330), (0x5AF2, 2011), (0x5AF3, 2012), (0x5AF4, 2013), (0x5AF5, 2014),
+    (0x5AF6, 2015), (0x5AF7, 2016), (0x5AF8, 2017), (0x5AF9, 2018), (0x5AFA, 2019), (0x5AFB, 2020),
+    (0x5AFC, 2021), (0x5AFD, 2022), (0x5AFE, 2023), (0x5AFF, 2024), (0x5B00, 2025), (0x5B01, 2026),
+    (0x5B02, 2027), (0x5B03, 2028), (0x5B04, 2029), (0x5B05, 2030), (0x5B06, 2031), (0x5B07, 2032),
+    (0x5B08, 2033), (0x5B09, 19335), (0x5B0A, 2034), (0x5B0B, 2035), (0x5B0C, 2036), (0x5B0D, 2037),
+    (0x5B0E, 2038), (0x5B0F, 2039), (0x5B10, 2040), (0x5B11, 2041), (0x5B12, 2042), (0x5B13, 2043),
+    (0x5B14, 2044), (0x5B15, 2045), (0x5B16, 19337), (0x5B17, 19336), (0x5B18, 2046), (0x5B19, 2047),
+    (0x5B1A, 2048), (0x5B1B, 2049), (0x5B1C, 2050), (0x5B1D, 2051), (0x5B1E, 2052), (0x5B1F, 2053),
+    (0x5B20, 2054), (0x5B21, 2055), (0x5B22, 2056), (0x5B23, 2057), (0x5B24, 2058), (0x5B25, 2059),
+    (0x5B26, 2060), (0x5B27, 2061), (0x5B28, 2062), (0x5B29, 2063), (0x5B2A, 2064), (0x5B2B, 2065),
+    (0x5B2C, 2066), (0x5B2D, 2067), (0x5B2E, 2068), (0x5B2F, 2069), (0x5B30, 2070), (0x5B31, 2071),
+    (0x5B32, 19338), (0x5B33, 2072), (0x5B34, 16903), (0x5B35, 2073), (0x5B36, 2074), (0x5B37, 19339),
+    (0x5B38, 2075), (0x5B39, 2076), (0x5B3A, 2077), (0x5B3B, 2078), (0x5B3C, 2079), (0x5B3D, 2080),
+    (0x5B3E, 2081), (0x5B3F, 2082), (0x5B40, 19340), (0x5B41, 2083), (0x5B42, 2084), (0x5B43, 2085),
+    (0x5B44, 2086), (0x5B45, 2087), (0x5B46, 2088), (0x5B47, 2089), (0x5B48, 2090), (0x5B49, 2091),
+    (0x5B4A, 2092), (0x5B4B, 2093), (0x5B4C, 2094), (0x5B4D, 2095), (0x5B4E, 2096), (0x5B4F, 2097),
+    (0x5B50, 16486), (0x5B51, 19346), (0x5B52, 2098), (0x5B53, 19347), (0x5B54, 11930), (0x5B55, 15913),
+    (0x5B56, 2099), (0x5B57, 16489), (0x5B58, 9855), (0x5B59, 14234), (0x5B5A, 19343), (0x5B5B, 16660),
+    (0x5B5C, 16481), (0x5B5D, 15107), (0x5B5E, 2100), (0x5B5F, 12682), (0x5B60, 2101), (0x5B61, 2102),
+    (0x5B62, 19348), (0x5B63, 11335), (0x5B64, 10769), (0x5B65, 19344), (0x5B66, 15302), (0x5B67, 2103),
+    (0x5B68, 2104), (0x5B69, 10927), (0x5B6A, 12492), (0x5B6B, 2105), (0x5B6C, 16636), (0x5B6D, 2106),
+    (0x5B6E, 2107), (0x5B6F, 2108), (0x5B70, 14040), (0x5B71, 19173), (0x5B72, 2109), (0x5B73, 19345),
+    (0x5B74, 2110), (0x5B75, 10440), (0x5B76, 2111), (0x5B77, 2112), (0x5B78, 2113), (0x5B79, 2114),
+    (0x5B7A, 13655), (0x5B7B, 2115), (0x5B7C, 2116), (0x5B7D, 12910), (0x5B7E, 2117), (0x5B7F, 2118),
+    (0x5B80, 19113), (0x5B81, 12919), (0x5B82, 2119), (0x5B83, 14247), (0x5B84, 19114), (0x5B85, 16067),
+    (0x5B86, 2120), (0x5B87, 15753), (0x5B88, 14021), (0x5B89, 9043), (0x5B8A, 2121), (0x5B8B, 14201),
+    (0x5B8C, 14609), (0x5B8D, 2122), (0x5B8E, 2123), (0x5B8F, 10999), (0x5B90, 2124), (0x5B91, 2125),
+    (0x5B92, 2126), (0x5B93, 19116), (0x5B94, 2127), (0x5B95, 19115), (0x5B96, 2128), (0x5B97, 16493),
+    (0x5B98, 10792), (0x5B99, 16315), (0x5B9A, 10173), (0x5B9B, 14615), (0x5B9C, 15528), (0x5B9D, 9221),
+    (0x5B9E, 13986), (0x5B9F, 2129), (0x5BA0, 9667), (0x5BA1, 13858), (0x5BA2, 11920), (0x5BA3, 15196),
+    (0x5BA4, 14015), (0x5BA5, 19117), (0x5BA6, 11149), (0x5BA7, 2130), (0x5BA8, 2131), (0x5BA9, 2132),
+    (0x5BAA, 14975), (0x5BAB, 10747), (0x5BAC, 2133), (0x5BAD, 2134), (0x5BAE, 2135), (0x5BAF, 2136),
+    (0x5BB0, 15920), (0x5BB1, 2137), (0x5BB2, 2138), (0x5BB3, 10931), (0x5BB4, 15366), (0x5BB5, 15007),
+    (0x5BB6, 11355), (0x5BB7, 2139), (0x5BB8, 19118), (0x5BB9, 13646), (0x5BBA, 2140), (0x5BBB, 2141),
+    (0x5BBC, 2142), (0x5BBD, 11952), (0x5BBE, 9301), (0x5BBF, 14217), (0x5BC0, 2143), (0x5BC1, 2144),
+    (0x5BC2, 11342), (0x5BC3, 2145), (0x5BC4, 11341), (0x5BC5, 15575), (0x5BC6, 12695), (0x5BC7, 11935),
+    (0x5BC8, 2146), (0x5BC9, 2147), (0x5BCA, 2148), (0x5BCB, 2149), (0x5BCC, 10572), (0x5BCD, 2150),
+    (0x5BCE, 2151), (0x5BCF, 2152), (0x5BD0, 12669), (0x5BD1, 2153), (0x5BD2, 10939), (0x5BD3, 15867),
+    (0x5BD4, 2154), (0x5BD5, 2155), (0x5BD6, 2156), (0x5BD7, 2157), (0x5BD8, 2158), (0x5BD9, 2159),
+    (0x5BDA, 2160), (0x5BDB, 2161), (0x5BDC, 2162), (0x5BDD, 13457), (0x5BDE, 12840), (0x5BDF, 9481),
+    (0x5BE0, 2163), (0x5BE1, 10784), (0x5BE2, 2164), (0x5BE3, 2165), (0x5BE4, 19122), (0x5BE5, 12295),
+    (0x5BE6, 2166), (0x5BE7, 2167), (0x5BE8, 16070), (0x5BE9, 2168), (0x5BEA, 2169), (0x5BEB, 2170),
+    (0x5BEC, 2171), (0x5BED, 2172), (0x5BEE, 19123), (0x5BEF, 2173), (0x5BF0, 19125), (0x5BF1, 2174),
+    (0x5BF2, 2175), (0x5BF3, 2176), (0x5BF4, 2177), (0x5BF5, 2178), (0x5BF6, 2179), (0x5BF7, 2180),
+    (0x5BF8, 9856), (0x5BF9, 10217), (0x5BFA, 14189), (0x5BFB, 15311), (0x5BFC, 10003), (0x5BFD, 2181),
+    (0x5BFE, 2182), (0x5BFF, 14022), (0x5C00, 2183), (0x5C01, 10421), (0x5C02, 2184), (0x5C03, 2185),
+    (0x5C04, 13843), (0x5C05, 2186), (0x5C06, 11506), (0x5C07, 2187), (0x5C08, 2188), (0x5C09, 14755),
+    (0x5C0A, 16515), (0x5C0B, 2189), (0x5C0C, 2190), (0x5C0D, 2191), (0x5C0E, 2192), (0x5C0F, 15106),
+    (0x5C10, 2193), (0x5C11, 13832), (0x5C12, 2194), (0x5C13, 2195), (0x5C14, 10256), (0x5C15, 19341),
+    (0x5C16, 11371), (0x5C17, 2196), (0x5C18, 9625), (0x5C19, 2197), (0x5C1A, 13823), (0x5C1B, 2198),
+    (0x5C1C, 19342), (0x5C1D, 9597), (0x5C1E, 2199), (0x5C1F, 2200), (0x5C20, 2201), (0x5C21, 2202),
+    (0x5C22, 17809), (0x5C23, 2203), (0x5C24, 15715), (0x5C25, 17810), (0x5C26, 2204), (0x5C27, 15487),
+    (0x5C28, 2205), (0x5C29, 2206), (0x5C2A, 2207), (0x5C2B, 2208), (0x5C2C, 17811), (0x5C2D, 2209),
+    (0x5C2E, 2210), (0x5C2F, 2211), (0x5C30, 2212), (0x5C31, 11730), (0x5C32, 2213), (0x5C33, 2214),
+    (0x5C34, 17812), (0x5C35, 2215), (0x5C36, 2216), (0x5C37, 2217), (0x5C38, 13977), (0x5C39, 15577),
+    (0x5C3A, 9658), (0x5C3B, 19169), (0x5C3C, 12890), (0x5C3D, 11686), (0x5C3E, 14743), (0x5C3F, 12907),
+    (0x5C40, 11739), (0x5C41, 13213), (0x5C42, 9472), (0x5C43, 2218), (0x5C44, 2219), (0x5C45, 11736),
+    (0x5C46, 2220), (0x5C47, 2221), (0x5C48, 13487), (0x5C49, 14420), (0x5C4A, 11571), (0x5C4B, 14786),
+    (0x5C4C, 2222), (0x5C4D, 2223), (0x5C4E, 13991), (0x5C4F, 13238), (0x5C50, 19171), (0x5C51, 15133),
+    (0x5C52, 2224), (0x5C53, 2225), (0x5C54, 2226), (0x5C55, 16080), (0x5C56, 2227), (0x5C57, 2228),
+    (0x5C58, 2229), (0x5C59, 19172), (0x5C5A, 2230), (0x5C5B, 2231), (0x5C5C, 2232), (0x5C5D, 2233),
+    (0x5C5E, 14049), (0x5C5F, 2234), (0x5C60, 14567), (0x5C61, 12482), (0x5C62, 2235), (0x5C63, 19174),
+    (0x5C64, 2236), (0x5C65, 12481), (0x5C66, 19175), (0x5C67, 2237), (0x5C68, 2238), (0x5C69, 2239),
+    (0x5C6A, 2240), (0x5C6B, 2241), (0x5C6C, 2242), (0x5C6D, 2243), (0x5C6E, 19183), (0x5C6F, 14580),
+    (0x5C70, 2244), (0x5C71, 13804), (0x5C72, 2245), (0x5C73, 2246), (0x5C74, 2247), (0x5C75, 2248),
+    (0x5C76, 2249), (0x5C77, 2250), (0x5C78, 2251), (0x5C79, 15542), (0x5C7A, 18343), (0x5C7B, 2252),
+    (0x5C7C, 2253), (0x5C7D, 2254), (0x5C7E, 2255), (0x5C7F, 15751), (0x5C80, 2256), (0x5C81, 14229),
+    (0x5C82, 13286), (0x5C83, 2257), (0x5C84, 2258), (0x5C85, 2259), (0x5C86, 2260), (0x5C87, 2261),
+    (0x5C88, 18347), (0x5C89, 2262), (0x5C8A, 2263), (0x5C8B, 2264), (0x5C8C, 18342), (0x5C8D, 18344),
+    (0x5C8E, 2265), (0x5C8F, 2266), (0x5C90, 18345), (0x5C91, 18350), (0x5C92, 2267), (0x5C93, 2268),
+    (0x5C94, 9482), (0x5C95, 2269), (0x5C96, 18346), (0x5C97, 10603), (0x5C98, 18348), (0x5C99, 18349),
+    (0x5C9A, 18351), (0x5C9B, 10001), (0x5C9C, 18352), (0x5C9D, 2270), (0x5C9E, 2271), (0x5C9F, 2272),
+    (0x5CA0, 2273), (0x5CA1, 2274), (0x5CA2, 18354), (0x5CA3, 18359), (0x5CA4, 2275), (0x5CA5, 2276),
+    (0x5CA6, 2277), (0x5CA7, 2278), (0x5CA8, 2279), (0x5CA9, 15345), (0x5CAA, 2280), (0x5CAB, 18357),
+    (0x5CAC, 18356), (0x5CAD, 12330), (0x5CAE, 2281), (0x5CAF, 2282), (0x5CB0, 2283), (0x5CB1, 18358),
+    (0x5CB2, 2284), (0x5CB3, 15897), (0x5CB4, 2285), (0x5CB5, 18353), (0x5CB6, 2286), (0x5CB7, 18361),
+    (0x5CB8, 9047), (0x5CB9, 2287), (0x5CBA, 2288), (0x5CBB, 2289), (0x5CBC, 2290), (0x5CBD, 18355),
+    (0x5CBE, 2291), (0x5CBF, 11964), (0x5CC0, 2292), (0x5CC1, 18360), (0x5CC2, 2293), (0x5CC3, 2294),
+    (0x5CC4, 18362), (0x5CC5, 2295), (0x5CC6, 2296), (0x5CC7, 2297), (0x5CC8, 2298), (0x5CC9, 2299),
+    (0x5CCA, 2300), (0x5CCB, 18365), (0x5CCC, 2301), (0x5CCD, 2302), (0x5CCE, 2303), (0x5CCF, 2304),
+    (0x5CD0, 2305), (0x5CD1, 2306), (0x5CD2, 18363), (0x5CD3, 2307), (0x5CD4, 2308), (0x5CD5, 2309),
+    (0x5CD6, 2310), (0x5CD7, 2311), (0x5CD8, 2312), (0x5CD9, 16282), (0x5CDA, 2313), (0x5CDB, 2314),
+    (0x5CDC, 2315), (0x5CDD, 2316), (0x5CDE, 2317), (0x5CDF, 2318), (0x5CE0, 2319), (0x5CE1, 14946),
+    (0x5CE2, 2320), (0x5CE3, 2321), (0x5CE4, 18364), (0x5CE5, 18366), (0x5CE6, 12490), (0x5CE7, 2322),
+    (0x5CE8, 10240), (0x5CE9, 2323), (0x5CEA, 15763), (0x5CEB, 2324), (0x5CEC, 2325), (0x5CED, 13440),
+    (0x5CEE, 2326), (0x5CEF, 2327), (0x5CF0, 10424), (0x5CF1, 2328), (0x5CF2, 2329), (0x5CF3, 2330),
+    (0x5CF4, 2331), (0x5CF5, 2332), (0x5CF6, 2333), (0x5CF7, 2334), (0x5CF8, 2335), (0x5CF9, 2336),
+    (0x5CFA, 2337), (0x5CFB, 11779), (0x5CFC, 2338), (0x5CFD, 2339), (0x5CFE, 2340), (0x5CFF, 2341),
+    (0x5D00, 2342), (0x5D01, 2343), (0x5D02, 18367), (0x5D03, 18368), (0x5D04, 2344), (0x5D05, 2345),
+    (0x5D06, 18374), (0x5D07, 9666), (0x5D08, 2346), (0x5D09, 2347), (0x5D0A, 2348), (0x5D0B, 2349),
+    (0x5D0C, 2350), (0x5D0D, 2351), (0x5D0E, 13278), (0x5D0F, 2352), (0x5D10, 2353), (0x5D11, 2354),
+    (0x5D12, 2355), (0x5D13, 2356), (0x5D14, 9847), (0x5D15, 2357), (0x5D16, 15329), (0x5D17, 2358),
+    (0x5D18, 2359), (0x5D19, 2360), (0x5D1A, 2361), (0x5D1B, 18375), (0x5D1C, 2362), (0x5D1D, 2363),
+    (0x5D1E, 18373), (0x5D1F, 2364), (0x5D20, 2365), (0x5D21, 2366), (0x5D22, 2367), (0x5D23, 2368),
+    (0x5D24, 18372), (0x5D25, 2369), (0x5D26, 18370), (0x5D27, 18369), (0x5D28, 2370), (0x5D29, 9247),
+    (0x5D2A, 2371), (0x5D2B, 2372), (0x5D2C, 2373), (0x5D2D, 16079), (0x5D2E, 18371), (0x5D2F, 2374),
+    (0x5D30, 2375), (0x5D31, 2376), (0x5D32, 2377), (0x5D33, 2378), (0x5D34, 18378), (0x5D35, 2379),
+    (0x5D36, 2380), (0x5D37, 2381), (0x5D38, 2382), (0x5D39, 2383), (0x5D3A, 2384), (0x5D3B, 2385),
+    (0x5D3C, 2386), (0x5D3D, 18379), (0x5D3E, 18377), (0x5D3F, 2387), (0x5D40, 2388), (0x5D41, 2389),
+    (0x5D42, 2390), (0x5D43, 2391), (0x5D44, 2392), (0x5D45, 2393), (0x5D46, 2394), (0x5D47, 21085),
+    (0x5D48, 2395), (0x5D49, 2396), (0x5D4A, 18386), (0x5D4B, 18385), (0x5D4C, 13417), (0x5D4D, 2397),
+    (0x5D4E, 2398), (0x5D4F, 2399), (0x5D50, 2400), (0x5D51, 2401), (0x5D52, 2402), (0x5D53, 2403),
+    (0x5D54, 2404), (0x5D55, 2405), (0x5D56, 2406), (0x5D57, 2407), (0x5D58, 18376), (0x5D59, 2408),
+    (0x5D5A, 2409), (0x5D5B, 18381), (0x5D5C, 2410), (0x5D5D, 18383), (0x5D5E, 2411), (0x5D5F, 2412),
+    (0x5D60, 2413), (0x5D61, 2414), (0x5D62, 2415), (0x5D63, 2416), (0x5D64, 2417), (0x5D65, 2418),
+    (0x5D66, 2419), (0x5D67, 2420), (0x5D68, 2421), (0x5D69, 18387), (0x5D6A, 2422), (0x5D6B, 18384),
+    (0x5D6C, 18380), (0x5D6D, 2423), (0x5D6E, 2424), (0x5D6F, 18382), (0x5D70, 2425), (0x5D71, 2426),
+    (0x5D72, 2427), (0x5D73, 2428), (0x5D74, 18388), (0x5D75, 2429), (0x5D76, 2430), (0x5D77, 2431),
+    (0x5D78, 2432), (0x5D79, 2433), (0x5D7A, 2434), (0x5D7B, 2435), (0x5D7C, 2436), (0x5D7D, 2437),
+    (0x5D7E, 2438), (0x5D7F, 2439), (0x5D80, 2440), (0x5D81, 2441), (0x5D82, 18389), (0x5D83, 2442),
+    (0x5D84, 2443), (0x5D85, 2444), (0x5D86, 2445), (0x5D87, 2446), (0x5D88, 2447), (0x5D89, 2448),
+    (0x5D8A, 2449), (0x5D8B, 2450), (0x5D8C, 2451), (0x5D8D, 2452), (0x5D8E, 2453), (0x5D8F, 2454),
+    (0x5D90, 2455), (0x5D91, 2456), (0x5D92, 2457), (0x5D93, 2458), (0x5D94, 2459), (0x5D95, 2460),
+    (0x5D96, 2461), (0x5D97, 2462), (0x5D98, 2463), (0x5D99, 18390), (0x5D9A, 2464), (0x5D9B, 2465),
+    (0x5D9C, 2466), (0x5D9D, 18391), (0x5D9E, 2467), (0x5D9F, 2468), (0x5DA0, 2469), (0x5DA1, 2470),
+    (0x5DA2, 2471), (0x5DA3, 2472), (0x5DA4, 2473), (0x5DA5, 2474), (0x5DA6, 2475), (0x5DA7, 2476),
+    (0x5DA8, 2477), (0x5DA9, 2478), (0x5DAA, 2479), (0x5DAB, 2480), (0x5DAC, 2481), (0x5DAD, 2482),
+    (0x5DAE, 2483), (0x5DAF, 2484), (0x5DB0, 2485), (0x5DB1, 2486), (0x5DB2, 2487), (0x5DB3, 2488),
+    (0x5DB4, 2489), (0x5DB5, 2490), (0x5DB6, 2491), (0x5DB7, 18393), (0x5DB8, 2492), (0x5DB9, 2493),
+    (0x5DBA, 2494), (0x5DBB, 2495), (0x5DBC, 2496), (0x5DBD, 2497), (0x5DBE, 2498), (0x5DBF, 2499),
+    (0x5DC0, 2500), (0x5DC1, 2501), (0x5DC2, 2502), (0x5DC3, 2503), (0x5DC4, 2504), (0x5DC5, 18394),
+    (0x5DC6, 2505), (0x5DC7, 2506), (0x5DC8, 2507), (0x5DC9, 2508), (0x5DCA, 2509), (0x5DCB, 2510),
+    (0x5DCC, 2511), (0x5DCD, 14726), (0x5DCE, 2512), (0x5DCF, 2513), (0x5DD0, 2514), (0x5DD1, 2515),
+    (0x5DD2, 2516), (0x5DD3, 2517), (0x5DD4, 2518), (0x5DD5, 2519), (0x5DD6, 2520), (0x5DD7, 2521),
+    (0x5DD8, 2522), (0x5DD9, 2523), (0x5DDA, 2524), (0x5DDB, 19536), (0x5DDC, 2525), (0x5DDD, 9793),
+    (0x5DDE, 16306), (0x5DDF, 2526), (0x5DE0, 2527), (0x5DE1, 15313), (0x5DE2, 9613), (0x5DE3, 2528),
+    (0x5DE4, 2529), (0x5DE5, 10739), (0x5DE6, 16518), (0x5DE7, 13436), (0x5DE8, 11747), (0x5DE9, 10749),
+    (0x5DEA, 2530), (0x5DEB, 14780), (0x5DEC, 2531), (0x5DED, 2532), (0x5DEE, 9483), (0x5DEF, 17242),
+    (0x5DF0, 2533), (0x5DF1, 11331), (0x5DF2, 15534), (0x5DF3, 14195), (0x5DF4, 9070), (0x5DF5, 2534),
+    (0x5DF6, 2535), (0x5DF7, 14994), (0x5DF8, 2536), (0x5DF9, 2537), (0x5DFA, 2538), (0x5DFB, 2539),
+    (0x5DFC, 2540), (0x5DFD, 16882), (0x5DFE, 11572), (0x5DFF, 2541), (0x5E00, 2542), (0x5E01, 9265),
+    (0x5E02, 14013), (0x5E03, 9433), (0x5E04, 2543), (0x5E05, 14162), (0x5E06, 10366), (0x5E07, 2544),
+    (0x5E08, 13971), (0x5E09, 2545), (0x5E0A, 2546), (0x5E0B, 2547), (0x5E0C, 14918), (0x5E0D, 2548),
+    (0x5E0E, 2549), (0x5E0F, 18233), (0x5E10, 16097), (0x5E11, 18236), (0x5E12, 2550), (0x5E13, 2551),
+    (0x5E14, 18235), (0x5E15, 13048), (0x5E16, 14436), (0x5E17, 2552), (0x5E18, 12272), (0x5E19, 18234),
+    (0x5E1A, 16312), (0x5E1B, 9420), (0x5E1C, 16281), (0x5E1D, 10034), (0x5E1E, 2553), (0x5E1F, 2554),
+    (0x5E20, 2555), (0x5E21, 2556), (0x5E22, 2557), (0x5E23, 2558), (0x5E24, 2559), (0x5E25, 2560),
+    (0x5E26, 9873), (0x5E27, 16246), (0x5E28, 2561), (0x5E29, 2562), (0x5E2A, 2563), (0x5E2B, 2564),
+    (0x5E2C, 2565), (0x5E2D, 14930), (0x5E2E, 9104), (0x5E2F, 2566), (0x5E30, 2567), (0x5E31, 18237),
+    (0x5E32, 2568), (0x5E33, 2569), (0x5E34, 2570), (0x5E35, 2571), (0x5E36, 2572), (0x5E37, 18336),
+    (0x5E38, 9598), (0x5E39, 2573), (0x5E3A, 2574), (0x5E3B, 18238), (0x5E3C, 18239), (0x5E3D, 12652),
+    (0x5E3E, 2575), (0x5E3F, 2576), (0x5E40, 2577), (0x5E41, 2578), (0x5E42, 12696), (0x5E43, 2579),
+    (0x5E44, 18337), (0x5E45, 10444), (0x5E46, 2580), (0x5E47, 2581), (0x5E48, 2582), (0x5E49, 2583),
+    (0x5E4A, 2584), (0x5E4B, 2585), (0x5E4C, 11162), (0x5E4D, 2586), (0x5E4E, 2587), (0x5E4F, 2588),
+    (0x5E50, 2589), (0x5E51, 2590), (0x5E52, 2591), (0x5E53, 2592), (0x5E54, 18338), (0x5E55, 12852),
+    (0x5E56, 2593), (0x5E57, 2594), (0x5E58, 2595), (0x5E59, 2596), (0x5E5A, 2597), (0x5E5B, 18339),
+    (0x5E5C, 2598), (0x5E5D, 2599), (0x5E5E, 18340), (0x5E5F, 2600), (0x5E60, 2601), (0x5E61, 18341),
+    (0x5E62, 9802), (0x5E63, 2602), (0x5E64, 2603), (0x5E65, 2604), (0x5E66, 2605), (0x5E67, 2606),
+    (0x5E68, 2607), (0x5E69, 2608), (0x5E6A, 2609), (0x5E6B, 2610), (0x5E6C, 2611), (0x5E6D, 2612),
+    (0x5E6E, 2613), (0x5E6F, 2614), (0x5E70, 2615), (0x5E71, 2616), (0x5E72, 10586), (0x5E73, 13234),
+    (0x5E74, 12899), (0x5E75, 2617), (0x5E76, 9407), (0x5E77, 2618), (0x5E78, 15155), (0x5E79, 2619),
+    (0x5E7A, 19534), (0x5E7B, 11150), (0x5E7C, 15730), (0x5E7D, 15711), (0x5E7E, 2620), (0x5E7F, 10802),
+    (0x5E80, 18572), (0x5E81, 2621), (0x5E82, 2622), (0x5E83, 2623), (0x5E84, 16450), (0x5E85, 2624),
+    (0x5E86, 13471), (0x5E87, 9266), (0x5E88, 2625), (0x5E89, 2626), (0x5E8A, 9803), (0x5E8B, 18574),
+    (0x5E8C, 2627), (0x5E8D, 2628), (0x5E8E, 2629), (0x5E8F, 15187), (0x5E90, 12459), (0x5E91, 18573),
+    (0x5E92, 2630), (0x5E93, 11941), (0x5E94, 15681), (0x5E95, 10030), (0x5E96, 18575), (0x5E97, 10049),
+    (0x5E98, 2631), (0x5E99, 12712), (0x5E9A, 10638), (0x5E9B, 2632), (0x5E9C, 10559), (0x5E9D, 2633),
+    (0x5E9E, 13066), (0x5E9F, 10402), (0x5EA0, 18577), (0x5EA1, 2634), (0x5EA2, 2635), (0x5EA3, 2636),
+    (0x5EA4, 2637), (0x5EA5, 18576), (0x5EA6, 10205), (0x5EA7, 16524), (0x5EA8, 2638), (0x5EA9, 2639),
+    (0x5EAA, 2640), (0x5EAB, 2641), (0x5EAC, 2642), (0x5EAD, 14540), (0x5EAE, 2643), (0x5EAF, 2644),
+    (0x5EB0, 2645), (0x5EB1, 2646), (0x5EB2, 2647), (0x5EB3, 18581), (0x5EB4, 2648), (0x5EB5, 18579),
+    (0x5EB6, 14057), (0x5EB7, 11896), (0x5EB8, 15700), (0x5EB9, 18578), (0x5EBA, 2649), (0x5EBB, 2650),
+    (0x5EBC, 2651), (0x5EBD, 2652), (0x5EBE, 18580), (0x5EBF, 2653), (0x5EC0, 2654), (0x5EC1, 2655),
+    (0x5EC2, 2656), (0x5EC3, 2657), (0x5EC4, 2658), (0x5EC5, 2659), (0x5EC6, 2660), (0x5EC7, 2661),
+    (0x5EC8, 2662), (0x5EC9, 12269), (0x5ECA, 12105), (0x5ECB, 2663), (0x5ECC, 2664), (0x5ECD, 2665),
+    (0x5ECE, 2666), (0x5ECF, 2667), (0x5ED0, 2668), (0x5ED1, 18584), (0x5ED2, 18583), (0x5ED3, 12075),
+    (0x5ED4, 2669), (0x5ED5, 2670), (0x5ED6, 12301), (0x5ED7, 2671), (0x5ED8, 2672), (0x5ED9, 2673),
+    (0x5EDA, 2674), (0x5EDB, 18585), (0x5EDC, 2675), (0x5EDD, 2676), (0x5EDE, 2677), (0x5EDF, 2678),
+    (0x5EE0, 2679), (0x5EE1, 2680), (0x5EE2, 2681), (0x5EE3, 2682), (0x5EE4, 2683), (0x5EE5, 2684),
+    (0x5EE6, 2685), (0x5EE7, 2686), (0x5EE8, 18586), (0x5EE9, 2687), (0x5EEA, 18587), (0x5EEB, 2688),
+    (0x5EEC, 2689), (0x5EED, 2690), (0x5EEE, 2691), (0x5EEF, 2692), (0x5EF0, 2693), (0x5EF1, 2694),
+    (0x5EF2, 2695), (0x5EF3, 2696), (0x5EF4, 17235), (0x5EF5, 2697), (0x5EF6, 15346), (0x5EF7, 14537),
+    (0x5EF8, 2698), (0x5EF9, 2699), (0x5EFA, 11503), (0x5EFB, 2700), (0x5EFC, 2701), (0x5EFD, 2702),
+    (0x5EFE, 17800), (0x5EFF, 16630), (0x5F00, 11885), (0x5F01, 17240), (0x5F02, 15561), (0x5F03, 13295),
+    (0x5F04, 13025), (0x5F05, 2703), (0x5F06, 2704), (0x5F07, 2705), (0x5F08, 17801), (0x5F09, 2706),
+    (0x5F0A, 9270), (0x5F0B, 17969), (0x5F0C, 2707), (0x5F0D, 2708), (0x5F0E, 2709), (0x5F0F, 13994),
+    (0x5F10, 2710), (0x5F11, 17972), (0x5F12, 2711), (0x5F13, 10748), (0x5F14, 2712), (0x5F15, 15578),
+    (0x5F16, 2713), (0x5F17, 10550), (0x5F18, 11000), (0x5F19, 2714), (0x5F1A, 2715), (0x5F1B, 9653),
+    (0x5F1C, 2716), (0x5F1D, 2717), (0x5F1E, 2718), (0x5F1F, 10035), (0x5F20, 16092), (0x5F21, 2719),
+    (0x5F22, 2720), (0x5F23, 2721), (0x5F24, 2722), (0x5F25, 12689), (0x5F26, 14965), (0x5F27, 11116),
+    (0x5F28, 2723), (0x5F29, 19178), (0x5F2A, 19177), (0x5F2B, 2724), (0x5F2C, 2725), (0x5F2D, 19179),
+    (0x5F2E, 2726), (0x5F2F, 14603), (0x5F30, 2727), (0x5F31, 13670), (0x5F32, 2728), (0x5F33, 2729),
+    (0x5F34, 2730), (0x5F35, 2731), (0x5F36, 2732), (0x5F37, 2733), (0x5F38, 2734), (0x5F39, 9990),
+    (0x5F3A, 13426), (0x5F3B, 2735), (0x5F3C, 19181), (0x5F3D, 2736), (0x5F3E, 2737), (0x5F3F, 2738),
+    (0x5F40, 20441), (0x5F41, 2739), (0x5F42, 2740), (0x5F43, 2741), (0x5F44, 2742), (0x5F45, 2743),
+    (0x5F46, 2744), (0x5F47, 2745), (0x5F48, 2746), (0x5F49, 2747), (0x5F4A, 2748), (0x5F4B, 2749),
+    (0x5F4C, 2750), (0x5F4D, 2751), (0x5F4E, 2752), (0x5F4F, 2753), (0x5F50, 19165), (0x5F51, 2754),
+    (0x5F52, 10808), (0x5F53, 9992), (0x5F54, 2755), (0x5F55, 12473), (0x5F56, 19167), (0x5F57, 19166),
+    (0x5F58, 19168), (0x5F59, 2756), (0x5F5A, 2757), (0x5F5B, 2758), (0x5F5C, 2759), (0x5F5D, 15530),
+    (0x5F5E, 2760), (0x5F5F, 2761), (0x5F60, 2762), (0x5F61, 18409), (0x5F62, 15151), (0x5F63, 2763),
+    (0x5F64, 14549), (0x5F65, 2764), (0x5F66, 15364), (0x5F67, 2765), (0x5F68, 2766), (0x5F69, 9447),
+    (0x5F6A, 9290), (0x5F6B, 2767), (0x5F6C, 9297), (0x5F6D, 13092), (0x5F6E, 2768), (0x5F6F, 2769),
+    (0x5F70, 16090), (0x5F71, 15691), (0x5F72, 2770), (0x5F73, 18395), (0x5F74, 2771), (0x5F75, 2772),
+    (0x5F76, 2773), (0x5F77, 18396), (0x5F78, 2774), (0x5F79, 15544), (0x5F7A, 2775), (0x5F7B, 9620),
+    (0x5F7C, 9258), (0x5F7D, 2776), (0x5F7E, 2777), (0x5F7F, 2778), (0x5F80, 14624), (0x5F81, 16142),
+    (0x5F82, 18397), (0x5F83, 2779), (0x5F84, 11707), (0x5F85, 9878), (0x5F86, 2780), (0x5F87, 18398),
+    (0x5F88, 10985), (0x5F89, 18399), (0x5F8A, 11133), (0x5F8B, 12486), (0x5F8C, 18400), (0x5F8D, 2781),
+    (0x5F8E, 2782), (0x5F8F, 2783), (0x5F90, 15181), (0x5F91, 2784), (0x5F92, 14564), (0x5F93, 2785),
+    (0x5F94, 2786), (0x5F95, 18401), (0x5F96, 2787), (0x5F97, 10010), (0x5F98, 13054), (0x5F99, 18402),
+    (0x5F9A, 2788), (0x5F9B, 2789), (0x5F9C, 18403), (0x5F9D, 2790), (0x5F9E, 2791), (0x5F9F, 2792),
+    (0x5FA0, 2793), (0x5FA1, 15764), (0x5FA2, 2794), (0x5FA3, 2795), (0x5FA4, 2796), (0x5FA5, 2797),
+    (0x5FA6, 2798), (0x5FA7, 2799), (0x5FA8, 18404), (0x5FA9, 2800), (0x5FAA, 15308), (0x5FAB, 2801),
+    (0x5FAC, 2802), (0x5FAD, 18405), (0x5FAE, 14727), (0x5FAF, 2803), (0x5FB0, 2804), (0x5FB1, 2805),
+    (0x5FB2, 2806), (0x5FB3, 2807), (0x5FB4, 2808), (0x5FB5, 18406), (0x5FB6, 2809), (0x5FB7, 10009),
+    (0x5FB8, 2810), (0x5FB9, 2811), (0x5FBA, 2812), (0x5FBB, 2813), (0x5FBC, 18407), (0x5FBD, 11168),
+    (0x5FBE, 2814), (0x5FBF, 2815), (0x5FC0, 2816), (0x5FC1, 2817), (0x5FC2, 2818), (0x5FC3, 15141),
+    (0x5FC4, 18589), (0x5FC5, 9271), (0x5FC6, 15553), (0x5FC7, 2819), (0x5FC8, 2820), (0x5FC9, 18590),
+    (0x5FCA, 2821), (0x5FCB, 2822), (0x5FCC, 11346), (0x5FCD, 13629), (0x5FCE, 2823), (0x5FCF, 18592),
+    (0x5FD0, 20519), (0x5FD1, 20518), (0x5FD2, 17970), (0x5FD3, 2824), (0x5FD4, 2825), (0x5FD5, 2826),
+    (0x5FD6, 18591), (0x5FD7, 16275), (0x5FD8, 14627), (0x5FD9, 12641), (0x5FDA, 2827), (0x5FDB, 2828),
+    (0x5FDC, 2829), (0x5FDD, 18750), (0x5FDE, 2830), (0x5FDF, 2831), (0x5FE0, 16295), (0x5FE1, 18596),
+    (0x5FE2, 2832), (0x5FE3, 2833), (0x5FE4, 18597), (0x5FE5, 2834), (0x5FE6, 2835), (0x5FE7, 15714),
+    (0x5FE8, 2836), (0x5FE9, 2837), (0x5FEA, 18601), (0x5FEB, 11951), (0x5FEC, 2838), (0x5FED, 18602),
+    (0x5FEE, 18594), (0x5FEF, 2839), (0x5FF0, 2840), (0x5FF1, 9627), (0x5FF2, 2841), (0x5FF3, 2842),
+    (0x5FF4, 2843), (0x5FF5, 12903), (0x5FF6, 2844), (0x5FF7, 2845), (0x5FF8, 18603), (0x5FF9, 2846),
+    (0x5FFA, 2847), (0x5FFB, 15140), (0x5FFC, 2848), (0x5FFD, 11011), (0x5FFE, 18598), (0x5FFF, 10417),
+    (0x6000, 11134), (0x6001, 14357), (0x6002, 14198), (0x6003, 18593), (0x6004, 18595), (0x6005, 18599),
+    (0x6006, 18600), (0x6007, 2849), (0x6008, 2850), (0x6009, 2851), (0x600A, 18612), (0x600B, 2852),
+    (0x600C, 2853), (0x600D, 18609), (0x600E, 15950), (0x600F, 18608), (0x6010, 2854), (0x6011, 2855),
+    (0x6012, 13028), (0x6013, 2856), (0x6014, 16145), (0x6015, 13049), (0x6016, 9437), (0x6017, 2857),
+    (0x6018, 2858), (0x6019, 18604), (0x601A, 2859), (0x601B, 18607), (0x601C, 12270), (0x601D, 14183),
+    (0x601E, 2860), (0x601F, 2861), (0x6020, 9976), (0x6021, 18614), (0x6022, 2862), (0x6023, 2863),
+    (0x6024, 2864), (0x6025, 11322), (0x6026, 18606), (0x6027, 15157), (0x6028, 15890), (0x6029, 18610),
+    (0x602A, 10789), (0x602B, 18611), (0x602C, 2865), (0x602D, 2866), (0x602E, 2867), (0x602F, 13446),
+    (0x6030, 2868), (0x6031, 2869), (0x6032, 2870), (0x6033, 2871), (0x6034, 2872), (0x6035, 18605),
+    (0x6036, 2873), (0x6037, 2874), (0x6038, 2875), (0x6039, 2876), (0x603A, 2877), (0x603B, 16495),
+    (0x603C, 20616), (0x603D, 2878), (0x603E, 2879), (0x603F, 18613), (0x6040, 2880), (0x6041, 20620),
+    (0x6042, 18619), (0x6043, 14014), (0x6044, 2881), (0x6045, 2882), (0x6046, 2883), (0x6047, 2884),
+    (0x6048, 2885), (0x6049, 2886), (0x604A, 2887), (0x604B, 12276), (0x604C, 2888), (0x604D, 11163),
+    (0x604E, 2889), (0x604F, 2890), (0x6050, 11929), (0x6051, 2891), (0x6052, 10992), (0x6053, 2892),
+    (0x6054, 2893), (0x6055, 14156), (0x6056, 2894), (0x6057, 2895), (0x6058, 2896), (0x6059, 20621),
+    (0x605A, 20618), (0x605B, 2897), (0x605C, 2898), (0x605D, 20617), (0x605E, 2899), (0x605F, 2900),
+    (0x6060, 2901), (0x6061, 2902), (0x6062, 11169), (0x6063, 20622), (0x6064, 15189), (0x6065, 2903),
+    (0x6066, 2904), (0x6067, 20619), (0x6068, 10987), (0x6069, 10252), (0x606A, 18716), (0x606B, 10183),
+    (0x606C, 14426), (0x606D, 10742), (0x606E, 2905), (0x606F, 14917), (0x6070, 13396), (0x6071, 2906),
+    (0x6072, 2907), (0x6073, 11925), (0x6074, 2908), (0x6075, 2909), (0x6076, 10246), (0x6077, 2910),
+    (0x6078, 18615), (0x6079, 18616), (0x607A, 18618), (0x607B, 18617), (0x607C, 12878), (0x607D, 18717),
+    (0x607E, 2911), (0x607F, 15708), (0x6080, 2912), (0x6081, 2913), (0x6082, 2914), (0x6083, 18722),
+    (0x6084, 13431), (0x6085, 2915), (0x6086, 2916), (0x6087, 2917), (0x6088, 2918), (0x6089, 14919),
+    (0x608A, 2919), (0x608B, 2920), (0x608C, 18724), (0x608D, 10948), (0x608E, 2921), (0x608F, 2922),
+    (0x6090, 2923), (0x6091, 2924), (0x6092, 18723), (0x6093, 2925), (0x6094, 11173), (0x6095, 2926),
+    (0x6096, 18718), (0x6097, 2927), (0x6098, 2928), (0x6099, 2929), (0x609A, 18719), (0x609B, 18725),
+    (0x609C, 2930), (0x609D, 18721), (0x609E, 2931), (0x609F, 14807), (0x60A0, 15713), (0x60A1, 2932),
+    (0x60A2, 2933), (0x60A3, 11143), (0x60A4, 2934), (0x60A5, 2935), (0x60A6, 15900), (0x60A7, 2936),
+    (0x60A8, 12915), (0x60A9, 2937), (0x60AA, 2938), (0x60AB, 20623), (0x60AC, 15197), (0x60AD, 18720),
+    (0x60AE, 2939), (0x60AF, 12720), (0x60B0, 2940), (0x60B1, 18728), (0x60B2, 9230), (0x60B3, 2941),
+    (0x60B4, 18733), (0x60B5, 2942), (0x60B6, 2943), (0x60B7, 2944), (0x60B8, 11339), (0x60B9, 2945),
+    (0x60BA, 2946), (0x60BB, 18727), (0x60BC, 10006), (0x60BD, 2947), (0x60BE, 2948), (0x60BF, 2949),
+    (0x60C0, 2950), (0x60C1, 2951), (0x60C2, 2952), (0x60C3, 2953), (0x60C4, 2954), (0x60C5, 13468),
+    (0x60C6, 18731), (0x60C7, 2955), (0x60C8, 2956), (0x60C9, 2957), (0x60CA, 11695), (0x60CB, 14614),
+    (0x60CC, 2958), (0x60CD, 2959), (0x60CE, 2960), (0x60CF, 2961), (0x60D0, 2962), (0x60D1, 11198),
+    (0x60D2, 2963), (0x60D3, 2964), (0x60D4, 2965), (0x60D5, 14417), (0x60D6, 2966), (0x60D7, 2967),
+    (0x60D8, 18730), (0x60D9, 2968), (0x60DA, 18732), (0x60DB, 2969), (0x60DC, 14922), (0x60DD, 18729),
+    (0x60DE, 2970), (0x60DF, 14734), (0x60E0, 11176), (0x60E1, 2971), (0x60E2, 2972), (0x60E3, 2973),
+    (0x60E4, 2974), (0x60E5, 2975), (0x60E6, 10050), (0x60E7, 11754), (0x60E8, 9455), (0x60E9, 9640),
+    (0x60EA, 2976), (0x60EB, 9240), (0x60EC, 18726), (0x60ED, 9454), (0x60EE, 9987), (0x60EF, 10798),
+    (0x60F0, 10237), (0x60F1, 2977), (0x60F2, 2978), (0x60F3, 14990), (0x60F4, 18738), (0x60F5, 2979),
+    (0x60F6, 11159), (0x60F7, 2980), (0x60F8, 2981), (0x60F9, 13624), (0x60FA, 15147), (0x60FB, 2982),
+    (0x60FC, 2983), (0x60FD, 2984), (0x60FE, 2985), (0x60FF, 2986), (0x6100, 18739), (0x6101, 9673),
+    (0x6102, 2987), (0x6103, 2988), (0x6104, 2989), (0x6105, 2990), (0x6106, 20624), (0x6107, 2991),
+    (0x6108, 15765), (0x6109, 15743), (0x610A, 2992), (0x610B, 2993), (0x610C, 2994), (0x610D, 20625),
+    (0x610E, 18740), (0x610F, 15551), (0x6110, 2995), (0x6111, 2996), (0x6112, 2997), (0x6113, 2998),
+    (0x6114, 2999), (0x6115, 18736), (0x6116, 3000), (0x6117, 3001), (0x6118, 3002), (0x6119, 3003),
+    (0x611A, 15737), (0x611B, 3004), (0x611C, 3005), (0x611D, 3006), (0x611E, 3007), (0x611F, 10593),
+    (0x6120, 18734), (0x6121, 3008), (0x6122, 3009), (0x6123, 18737), (0x6124, 10418), (0x6125, 3010),
+    (0x6126, 18735), (0x6127, 12067), (0x6128, 3011), (0x6129, 3012), (0x612A, 3013), (0x612B, 18741),
+    (0x612C, 3014), (0x612D, 3015), (0x612E, 3016), (0x612F, 3017), (0x6130, 3018), (0x6131, 3019),
+    (0x6132, 3020), (0x6133, 3021), (0x6134, 3022), (0x6135, 3023), (0x6136, 3024), (0x6137, 3025),
+    (0x6138, 3026), (0x6139, 3027), (0x613A, 3028), (0x613B, 3029), (0x613C, 3030), (0x613D, 3031),
+    (0x613E, 3032), (0x613F, 15889), (0x6140, 3033), (0x6141, 3034), (0x6142, 3035), (0x6143, 3036),
+    (0x6144, 3037), (0x6145, 3038), (0x6146, 3039), (0x6147, 3040), (0x6148, 9825), (0x6149, 3041),
+    (0x614A, 18742), (0x614B, 3042), (0x614C, 11152), (0x614D, 3043), (0x614E, 13862), (0x614F, 3044),
+    (0x6150, 3045), (0x6151, 13844), (0x6152, 3046), (0x6153, 3047), (0x6154, 3048), (0x6155, 12854),
+    (0x6156, 3049), (0x6157, 3050), (0x6158, 3051), (0x6159, 3052), (0x615A, 3053), (0x615B, 3054),
+    (0x615C, 3055), (0x615D, 20626), (0x615E, 3056), (0x615F, 3057), (0x6160, 3058), (0x6161, 3059),
+    (0x6162, 12538), (0x6163, 3060), (0x6164, 3061), (0x6165, 3062), (0x6166, 3063), (0x6167, 11174),
+    (0x6168, 11889), (0x6169, 3064), (0x616A, 3065), (0x616B, 3066), (0x616C, 3067), (0x616D, 3068),
+    (0x616E, 3069), (0x616F, 3070), (0x6170, 14756), (0x6171, 3071), (0x6172, 3072), (0x6173, 3073),
+    (0x6174, 3074), (0x6175, 18743), (0x6176, 3075), (0x6177, 11897), (0x6178, 3076), (0x6179, 3077),
+    (0x617A, 3078), (0x617B, 3079), (0x617C, 3080), (0x617D, 3081), (0x617E, 3082), (0x617F, 3083),
+    (0x6180, 3084), (0x6181, 3085), (0x6182, 3086), (0x6183, 3087), (0x6184, 3088), (0x6185, 3089),
+    (0x6186, 3090), (0x6187, 3091), (0x6188, 3092), (0x6189, 3093), (0x618A, 3094), (0x618B, 9294),
+    (0x618C, 3095), (0x618D, 3096), (0x618E, 15952), (0x618F, 3097), (0x6190, 3098), (0x6191, 3099),
+    (0x6192, 3100), (0x6193, 3101), (0x6194, 18745), (0x6195, 3102), (0x6196, 3103), (0x6197, 3104),
+    (0x6198, 3105), (0x6199, 3106), (0x619A, 3107), (0x619B, 3108), (0x619C, 3109), (0x619D, 20628),
+    (0x619E, 3110), (0x619F, 3111), (0x61A0, 3112), (0x61A1, 3113), (0x61A2, 3114), (0x61A3, 3115),
+    (0x61A4, 3116), (0x61A5, 3117), (0x61A6, 3118), (0x61A7, 18746), (0x61A8, 10934), (0x61A9, 20627),
+    (0x61AA, 3119), (0x61AB, 3120), (0x61AC, 18744), (0x61AD, 3121), (0x61AE, 3122), (0x61AF, 3123),
+    (0x61B0, 3124), (0x61B1, 3125), (0x61B2, 3126), (0x61B3, 3127), (0x61B4, 3128), (0x61B5, 3129),
+    (0x61B6, 3130), (0x61B7, 18747), (0x61B8, 3131), (0x61B9, 3132), (0x61BA, 3133), (0x61BB, 3134),
+    (0x61BC, 3135), (0x61BD, 3136), (0x61BE, 10947), (0x61BF, 3137), (0x61C0, 3138), (0x61C1, 3139),
+    (0x61C2, 10179), (0x61C3, 3140), (0x61C4, 3141), (0x61C5, 3142), (0x61C6, 3143), (0x61C7, 3144),
+    (0x61C8, 15129), (0x61C9, 3145), (0x61CA, 9060), (0x61CB, 20629), (0x61CC, 3146), (0x61CD, 3147),
+    (0x61CE, 3148), (0x61CF, 3149), (0x61D0, 3150), (0x61D1, 20630), (0x61D2, 12098), (0x61D3, 3151),
+    (0x61D4, 18748), (0x61D5, 3152), (0x61D6, 3153), (0x61D7, 3154), (0x61D8, 3155), (0x61D9, 3156),
+    (0x61DA, 3157), (0x61DB, 3158), (0x61DC, 3159), (0x61DD, 3160), (0x61DE, 3161), (0x61DF, 3162),
+    (0x61E0, 3163), (0x61E1, 3164), (0x61E2, 3165), (0x61E3, 3166), (0x61E4, 3167), (0x61E5, 3168),
+    (0x61E6, 13034), (0x61E7, 3169), (0x61E8, 3170), (0x61E9, 3171), (0x61EA, 3172), (0x61EB, 3173),
+    (0x61EC, 3174), (0x61ED, 3175), (0x61EE, 3176), (0x61EF, 3177), (0x61F0, 3178), (0x61F1, 3179),
+    (0x61F2, 3180), (0x61F3, 3181), (0x61F4, 3182), (0x61F5, 18749), (0x61F6, 3183), (0x61F7, 3184),
+    (0x61F8, 3185), (0x61F9, 3186), (0x61FA, 3187), (0x61FB, 3188), (0x61FC, 3189), (0x61FD, 3190),
+    (0x61FE, 3191), (0x61FF, 17403), (0x6200, 3192), (0x6201, 3193), (0x6202, 3194), (0x6203, 3195),
+    (0x6204, 3196), (0x6205, 3197), (0x6206, 20631), (0x6207, 3198), (0x6208, 10619), (0x6209, 3199),
+    (0x620A, 14801), (0x620B, 20052), (0x620C, 15176), (0x620D, 14054), (0x620E, 13639), (0x620F, 14938),
+    (0x6210, 9636), (0x6211, 14775), (0x6212, 11563), (0x6213, 3200), (0x6214, 3201), (0x6215, 18777),
+    (0x6216, 11197), (0x6217, 20053), (0x6218, 16084), (0x6219, 3202), (0x621A, 13266), (0x621B, 20054),
+    (0x621C, 3203), (0x621D, 3204), (0x621E, 3205), (0x621F, 20055), (0x6220, 3206), (0x6221, 20057),
+    (0x6222, 20056), (0x6223, 3207), (0x6224, 20059), (0x6225, 20058), (0x6226, 3208), (0x6227, 3209),
+    (0x6228, 3210), (0x6229, 3211), (0x622A, 11551), (0x622B, 3212), (0x622C, 20060), (0x622D, 3213),
+    (0x622E, 12475), (0x622F, 3214), (0x6230, 3215), (0x6231, 3216), (0x6232, 3217), (0x6233, 9818),
+    (0x6234, 9872), (0x6235, 3218), (0x6236, 3219), (0x6237, 11122), (0x6238, 3220), (0x6239, 3221),
+    (0x623A, 3222), (0x623B, 3223), (0x623C, 3224), (0x623D, 20495), (0x623E, 20494), (0x623F, 10386),
+    (0x6240, 14244), (0x6241, 9281), (0x6242, 3225), (0x6243, 20496), (0x6244, 3226), (0x6245, 3227),
+    (0x6246, 3228), (0x6247, 13815), (0x6248, 20497), (0x6249, 20498), (0x624A, 3229), (0x624B, 14019),
+    (0x624C, 17813), (0x624D, 9442), (0x624E, 15955), (0x624F, 3230), (0x6250, 3231), (0x6251, 13248),
+    (0x6252, 9064), (0x6253, 9867), (0x6254, 13636), (0x6255, 3232), (0x6256, 3233), (0x6257, 3234),
+    (0x6258, 14583), (0x6259, 3235), (0x625A, 3236), (0x625B, 11899), (0x625C, 3237), (0x625D, 3238),
+    (0x625E, 3239), (0x625F, 3240), (0x6260, 3241), (0x6261, 3242), (0x6262, 3243), (0x6263, 11934),
+    (0x6264, 3244), (0x6265, 3245), (0x6266, 13399), (0x6267, 16265), (0x6268, 3246), (0x6269, 12074),
+    (0x626A, 17814), (0x626B, 13783), (0x626C, 15374), (0x626D, 13019), (0x626E, 9096), (0x626F, 9617),
+    (0x6270, 13622), (0x6271, 3247), (0x6272, 3248), (0x6273, 9091), (0x6274, 3249), (0x6275, 3250),
+    (0x6276, 10441), (0x6277, 3251), (0x6278, 3252), (0x6279, 13105), (0x627A, 3253), (0x627B, 3254),
+    (0x627C, 10248), (0x627D, 3255), (0x627E, 16105), (0x627F, 9643), (0x6280, 11333), (0x6281, 3256),
+    (0x6282, 3257), (0x6283, 3258), (0x6284, 9608), (0x6285, 3259), (0x6286, 3260), (0x6287, 3261),
+    (0x6288, 3262), (0x6289, 11766), (0x628A, 9074), (0x628B, 3263), (0x628C, 3264), (0x628D, 3265),
+    (0x628E, 3266), (0x628F, 3267), (0x6290, 3268), (0x6291, 15539), (0x6292, 14032), (0x6293, 16440),
+    (0x6294, 3269), (0x6295, 14557), (0x6296, 10187), (0x6297, 11900), (0x6298, 16114), (0x6299, 3270),
+    (0x629A, 10552), (0x629B, 13070), (0x629C, 3271), (0x629D, 3272), (0x629E, 3273), (0x629F, 17815),
+    (0x62A0, 11932), (0x62A1, 12498), (0x62A2, 13427), (0x62A3, 3274), (0x62A4, 11119), (0x62A5, 9223),
+    (0x62A6, 3275), (0x62A7, 3276), (0x62A8, 13089), (0x62A9, 3277), (0x62AA, 3278), (0x62AB, 13106),
+    (0x62AC, 14352), (0x62AD, 3279), (0x62AE, 3280), (0x62AF, 3281), (0x62B0, 3282), (0x62B1, 9222),
+    (0x62B2, 3283), (0x62B3, 3284), (0x62B4, 3285), (0x62B5, 10029), (0x62B6, 3286), (0x62B7, 3287),
+    (0x62B8, 3288), (0x62B9, 12833), (0x62BA, 3289), (0x62BB, 17816), (0x62BC, 15321), (0x62BD, 9668),
+    (0x62BE, 3290), (0x62BF, 12717), (0x62C0, 3291), (0x62C1, 3292), (0x62C2, 10442), (0x62C3, 3293),
+    (0x62C4, 16329), (0x62C5, 9978), (0x62C6, 9485), (0x62C7, 12845), (0x62C8, 12898), (0x62C9, 12078),
+    (0x62CA, 17817), (0x62CB, 3294), (0x62CC, 9097), (0x62CD, 13051), (0x62CE, 12319), (0x62CF, 3295),
+    (0x62D0, 10788), (0x62D1, 3296), (0x62D2, 11745), (0x62D3, 14591), (0x62D4, 9071), (0x62D5, 3297),
+    (0x62D6, 14582), (0x62D7, 17819), (0x62D8, 11733), (0x62D9, 16465), (0x62DA, 17818), (0x62DB, 16103),
+    (0x62DC, 9086), (0x62DD, 3298), (0x62DE, 3299), (0x62DF, 12891), (0x62E0, 3300), (0x62E1, 3301),
+    (0x62E2, 12448), (0x62E3, 11385), (0x62E4, 3302), (0x62E5, 15696), (0x62E6, 12090), (0x62E7, 13016),
+    (0x62E8, 9411), (0x62E9, 15946), (0x62EA, 3303), (0x62EB, 3304), (0x62EC, 12073), (0x62ED, 14000),
+    (0x62EE, 17820), (0x62EF, 16147), (0x62F0, 3305), (0x62F1, 10751), (0x62F2, 3306), (0x62F3, 13598),
+    (0x62F4, 14164), (0x62F5, 3307), (0x62F6, 17822), (0x62F7, 11904), (0x62F8, 3308), (0x62F9, 3309),
+    (0x62FA, 3310), (0x62FB, 3311), (0x62FC, 13225), (0x62FD, 16442), (0x62FE, 13981), (0x62FF, 12860),
+    (0x6300, 3312), (0x6301, 9649), (0x6302, 10785), (0x6303, 3313), (0x6304, 3314), (0x6305, 3315),
+    (0x6306, 3316), (0x6307, 16269), (0x6308, 20137), (0x6309, 9045), (0x630A, 3317), (0x630B, 3318),
+    (0x630C, 3319), (0x630D, 3320), (0x630E, 11945), (0x630F, 3321), (0x6310, 3322), (0x6311, 14429),
+    (0x6312, 3323), (0x6313, 3324), (0x6314, 3325), (0x6315, 3326), (0x6316, 14593), (0x6317, 3327),
+    (0x6318, 3328), (0x6319, 3329), (0x631A, 16276), (0x631B, 12491), (0x631C, 3330), (0x631D, 14771),
+    (0x631E, 14347), (0x631F, 15119), (0x6320, 12876), (0x6321, 9993), (0x6322, 17821), (0x6323, 16140),
+    (0x6324, 11328), (0x6325, 11166), (0x6326, 3331), (0x6327, 3332), (0x6328, 9029), (0x6329, 3333),
+    (0x632A, 13033), (0x632B, 9861), (0x632C, 3334), (0x632D, 3335), (0x632E, 3336), (0x632F, 16136),
+    (0x6330, 3337), (0x6331, 3338), (0x6332, 20138), (0x6333, 3339), (0x6334, 3340), (0x6335, 3341),
+    (0x6336, 3342), (0x6337, 3343), (0x6338, 3344), (0x6339, 17823), (0x633A, 14541), (0x633B, 3345),
+    (0x633C, 3346), (0x633D, 14611), (0x633E, 3347), (0x633F, 3348), (0x6340, 3349), (0x6341, 3350),
+    (0x6342, 14795), (0x6343, 17825), (0x6344, 3351), (0x6345, 14552), (0x6346, 12071), (0x6347, 3352),
+    (0x6348, 3353), (0x6349, 16464), (0x634A, 3354), (0x634B, 17824), (0x634C, 9063), (0x634D, 10945),
+    (0x634E, 13826), (0x634F, 12908), (0x6350, 11757), (0x6351, 3355), (0x6352, 3356), (0x6353, 3357),
+    (0x6354, 3358), (0x6355, 9427), (0x6356, 3359), (0x6357, 3360), (0x6358, 3361), (0x6359, 3362),
+    (0x635A, 3363), (0x635B, 3364), (0x635C, 3365), (0x635D, 3366), (0x635E, 12109), (0x635F, 14235),
+    (0x6360, 3367), (0x6361, 11386), (0x6362, 11142), (0x6363, 9998), (0x6364, 3368), (0x6365, 3369),
+    (0x6366, 3370), (0x6367, 13100), (0x6368, 3371), (0x6369, 17835), (0x636A, 3372), (0x636B, 3373),
+    (0x636C, 3374), (0x636D, 17832), (0x636E, 11746), (0x636F, 3375), (0x6370, 3376), (0x6371, 17828),
+    (0x6372, 3377), (0x6373, 3378), (0x6374, 3379), (0x6375, 3380), (0x6376, 9808), (0x6377, 11556),
+    (0x6378, 3381), (0x6379, 3382), (0x637A, 17829), (0x637B, 12902), (0x637C, 3383), (0x637D, 3384),
+    (0x637E, 3385), (0x637F, 3386), (0x6380, 14953), (0x6381, 3387), (0x6382, 10039), (0x6383, 3388),
+    (0x6384, 3389), (0x6385, 3390), (0x6386, 3391), (0x6387, 10227), (0x6388, 14023), (0x6389, 10059),
+    (0x638A, 17834), (0x638B, 3392), (0x638C, 16093), (0x638D, 3393), (0x638E, 17830), (0x638F, 14390),
+    (0x6390, 13299), (0x6391, 3394), (0x6392, 13052), (0x6393, 3395), (0x6394, 3396), (0x6395, 3397),
+    (0x6396, 15505), (0x6397, 3398), (0x6398, 11767), (0x6399, 3399), (0x639A, 3400), (0x639B, 3401),
+    (0x639C, 3402), (0x639D, 3403), (0x639E, 3404), (0x639F, 3405), (0x63A0, 12496), (0x63A1, 3406),
+    (0x63A2, 14374), (0x63A3, 9619), (0x63A4, 3407), (0x63A5, 11546), (0x63A6, 3408), (0x63A7, 11931),
+    (0x63A8, 14573), (0x63A9, 15353), (0x63AA, 9860), (0x63AB, 3409), (0x63AC, 17833), (0x63AD, 17826),
+    (0x63AE, 17836), (0x63AF, 3410), (0x63B0, 20139), (0x63B1, 3411), (0x63B2, 3412), (0x63B3, 12461),
+    (0x63B4, 17831), (0x63B5, 3413), (0x63B6, 3414), (0x63B7, 16277), (0x63B8, 9982), (0x63B9, 3415),
+    (0x63BA, 9489), (0x63BB, 3416), (0x63BC, 17837), (0x63BD, 3417), (0x63BE, 17847), (0x63BF, 3418),
+    (0x63C0, 3419), (0x63C1, 3420), (0x63C2, 3421), (0x63C3, 3422), (0x63C4, 17842), (0x63C5, 3423),
+    (0x63C6, 17846), (0x63C7, 3424), (0x63C8, 3425), (0x63C9, 13649), (0x63CA, 3426), (0x63CB, 3427),
+    (0x63CC, 3428), (0x63CD, 16500), (0x63CE, 17844), (0x63CF, 12707), (0x63D0, 14410), (0x63D1, 3429),
+    (0x63D2, 9474), (0x63D3, 3430), (0x63D4, 3431), (0x63D5, 3432), (0x63D6, 15515), (0x63D7, 3433),
+    (0x63D8, 3434), (0x63D9, 3435), (0x63DA, 3436), (0x63DB, 3437), (0x63DC, 3438), (0x63DD, 3439),
+    (0x63DE, 17843), (0x63DF, 3440), (0x63E0, 17840), (0x63E1, 14778), (0x63E2, 3441), (0x63E3, 9792),
+    (0x63E4, 3442), (0x63E5, 3443), (0x63E6, 3444), (0x63E7, 3445), (0x63E8, 3446), (0x63E9, 11886),
+    (0x63EA, 11715), (0x63EB, 3447), (0x63EC, 3448), (0x63ED, 11545), (0x63EE, 3449), (0x63EF, 3450),
+    (0x63F0, 3451), (0x63F1, 3452), (0x63F2, 17838), (0x63F3, 3453), (0x63F4, 15879), (0x63F5, 3454),
+    (0x63F6, 17827), (0x63F7, 3455), (0x63F8, 17839), (0x63F9, 3456), (0x63FA, 3457), (0x63FB, 3458),
+    (0x63FC, 3459), (0x63FD, 12096), (0x63FE, 3460), (0x63FF, 17841), (0x6400, 9488), (0x6401, 10618),
+    (0x6402, 12452), (0x6403, 3461), (0x6404, 3462), (0x6405, 11528), (0x6406, 3463), (0x6407, 3464),
+    (0x6408, 3465), (0x6409, 3466), (0x640A, 3467), (0x640B, 17850), (0x640C, 17853), (0x640D, 3468),
+    (0x640E, 3469), (0x640F, 9416), (0x6410, 9789), (0x6411, 3470), (0x6412, 3471), (0x6413, 9859),
+    (0x6414, 13781), (0x6415, 3472), (0x6416, 3473), (0x6417, 3474), (0x6418, 3475), (0x6419, 3476),
+    (0x641A, 3477), (0x641B, 17851), (0x641C, 14204), (0x641D, 3478), (0x641E, 10612), (0x641F, 3479),
+    (0x6420, 17852), (0x6421, 17855), (0x6422, 3480), (0x6423, 3481), (0x6424, 3482), (0x6425, 3483),
+    (0x6426, 17854), (0x6427, 3484), (0x6428, 3485), (0x6429, 3486), (0x642A, 14379), (0x642B, 3487),
+    (0x642C, 9090), (0x642D, 9863), (0x642E, 3488), (0x642F, 3489), (0x6430, 3490), (0x6431, 3491),
+    (0x6432, 3492), (0x6433, 3493), (0x6434, 19121), (0x6435, 3494), (0x6436, 3495), (0x6437, 3496),
+    (0x6438, 3497), (0x6439, 3498), (0x643A, 15120), (0x643B, 3499), (0x643C, 3500), (0x643D, 9480),
+    (0x643E, 3501), (0x643F, 20236), (0x6440, 3502), (0x6441, 17849), (0x6442, 3503), (0x6443, 3504),
+    (0x6444, 13842), (0x6445, 17848), (0x6446, 9083), (0x6447, 15486), (0x6448, 9302), (0x6449, 3505),
+    (0x644A, 14360), (0x644B, 3506), (0x644C, 3507), (0x644D, 3508), (0x644E, 3509), (0x644F, 3510),
+    (0x6450, 3511), (0x6451, 3512), (0x6452, 17845), (0x6453, 3513), (0x6454, 14159), (0x6455, 3514),
+    (0x6456, 3515), (0x6457, 3516), (0x6458, 16065), (0x6459, 3517), (0x645A, 3518), (0x645B, 3519),
+    (0x645C, 3520), (0x645D, 3521), (0x645E, 17856), (0x645F, 3522), (0x6460, 3523), (0x6461, 3524),
+    (0x6462, 3525), (0x6463, 3526), (0x6464, 3527), (0x6465, 3528), (0x6466, 3529), (0x6467, 9846),
+    (0x6468, 3530), (0x6469, 12831), (0x646A, 3531), (0x646B, 3532), (0x646C, 3533), (0x646D, 17858),
+    (0x646E, 3534), (0x646F, 3535), (0x6470, 3536), (0x6471, 3537), (0x6472, 3538), (0x6473, 3539),
+    (0x6474, 3540), (0x6475, 3541), (0x6476, 3542), (0x6477, 3543), (0x6478, 12729), (0x6479, 12826),
+    (0x647A, 17956), (0x647B, 3544), (0x647C, 3545), (0x647D, 3546), (0x647E, 3547), (0x647F, 3548),
+    (0x6480, 3549), (0x6481, 3550), (0x6482, 12299), (0x6483, 3551), (0x6484, 17857), (0x6485, 11764),
+    (0x6486, 3552), (0x6487, 13223), (0x6488, 3553), (0x6489, 3554), (0x648A, 3555), (0x648B, 3556),
+    (0x648C, 3557), (0x648D, 3558), (0x648E, 3559), (0x648F, 3560), (0x6490, 3561), (0x6491, 9632),
+    (0x6492, 13671), (0x6493, 3562), (0x6494, 3563), (0x6495, 14181), (0x6496, 17859), (0x6497, 3564),
+    (0x6498, 3565), (0x6499, 17959), (0x649A, 3566), (0x649B, 3567), (0x649C, 3568), (0x649D, 3569),
+    (0x649E, 16453), (0x649F, 3570), (0x64A0, 3571), (0x64A1, 3572), (0x64A2, 3573), (0x64A3, 3574),
+    (0x64A4, 9618), (0x64A5, 3575), (0x64A6, 3576), (0x64A7, 3577), (0x64A8, 3578), (0x64A9, 12290),
+    (0x64AA, 3579), (0x64AB, 3580), (0x64AC, 13438), (0x64AD, 9410), (0x64AE, 9858), (0x64AF, 3581),
+    (0x64B0, 16446), (0x64B1, 3582), (0x64B2, 3583), (0x64B3, 3584), (0x64B4, 3585), (0x64B5, 12901),
+    (0x64B6, 3586), (0x64B7, 17957), (0x64B8, 17958), (0x64B9, 3587), (0x64BA, 17960), (0x64BB, 3588),
+    (0x64BC, 10944), (0x64BD, 3589), (0x64BE, 3590), (0x64BF, 3591), (0x64C0, 17961), (0x64C1, 3592),
+    (0x64C2, 12127), (0x64C3, 3593), (0x64C4, 3594), (0x64C5, 13810), (0x64C6, 3595), (0x64C7, 3596),
+    (0x64C8, 3597), (0x64C9, 3598), (0x64CA, 3599), (0x64CB, 3600), (0x64CC, 3601), (0x64CD, 9462),
+    (0x64CE, 13465), (0x64CF, 3602), (0x64D0, 17962), (0x64D1, 3603), (0x64D2, 13455), (0x64D3, 3604),
+    (0x64D4, 3605), (0x64D5, 3606), (0x64D6, 3607), (0x64D7, 17963), (0x64D8, 20237), (0x64D9, 3608),
+    (0x64DA, 3609), (0x64DB, 3610), (0x64DC, 3611), (0x64DD, 3612), (0x64DE, 14206), (0x64DF, 3613),
+    (0x64E0, 3614), (0x64E1, 3615), (0x64E2, 17965), (0x64E3, 3616), (0x64E4, 17964), (0x64E5, 3617),
+    (0x64E6, 9438), (0x64E7, 3618), (0x64E8, 3619), (0x64E9, 3620), (0x64EA, 3621), (0x64EB, 3622),
+    (0x64EC, 3623), (0x64ED, 3624), (0x64EE, 3625), (0x64EF, 3626), (0x64F0, 3627), (0x64F1, 3628),
+    (0x64F2, 3629), (0x64F3, 3630), (0x64F4, 3631), (0x64F5, 3632), (0x64F6, 3633), (0x64F7, 3634),
+    (0x64F8, 3635), (0x64F9, 3636), (0x64FA, 3637), (0x64FB, 3638), (0x64FC, 3639), (0x64FD, 3640),
+    (0x64FE, 3641), (0x64FF, 3642), (0x6500, 13057), (0x6501, 3643), (0x6502, 3644), (0x6503, 3645),
+    (0x6504, 3646), (0x6505, 3647), (0x6506, 3648), (0x6507, 3649), (0x6508, 3650), (0x6509, 17966),
+    (0x650A, 3651), (0x650B, 3652), (0x650C, 3653), (0x650D, 3654), (0x650E, 3655), (0x650F, 3656),
+    (0x6510, 3657), (0x6511, 3658), (0x6512, 15925), (0x6513, 3659), (0x6514, 3660), (0x6515, 3661),
+    (0x6516, 3662), (0x6517, 3663), (0x6518, 13618), (0x6519, 3664), (0x651A, 3665), (0x651B, 3666),
+    (0x651C, 3667), (0x651D, 3668), (0x651E, 3669), (0x651F, 3670), (0x6520, 3671), (0x6521, 3672),
+    (0x6522, 3673), (0x6523, 3674), (0x6524, 3675), (0x6525, 17967), (0x6526, 3676), (0x6527, 3677),
+    (0x6528, 3678), (0x6529, 3679), (0x652A, 3680), (0x652B, 11765), (0x652C, 3681), (0x652D, 3682),
+    (0x652E, 17968), (0x652F, 16252), (0x6530, 3683), (0x6531, 3684), (0x6532, 3685), (0x6533, 3686),
+    (0x6534, 20068), (0x6535, 20257), (0x6536, 14018), (0x6537, 3687), (0x6538, 16717), (0x6539, 10581),
+    (0x653A, 3688), (0x653B, 10740), (0x653C, 3689), (0x653D, 3690), (0x653E, 10392), (0x653F, 16149),
+    (0x6540, 3691), (0x6541, 3692), (0x6542, 3693), (0x6543, 3694), (0x6544, 3695), (0x6545, 10777),
+    (0x6546, 3696), (0x6547, 3697), (0x6548, 15112), (0x6549, 21990), (0x654A, 3698), (0x654B, 3699),
+    (0x654C, 10023), (0x654D, 3700), (0x654E, 3701), (0x654F, 12719), (0x6550, 3702), (0x6551, 11725),
+    (0x6552, 3703), (0x6553, 3704), (0x6554, 3705), (0x6555, 20258), (0x6556, 9054), (0x6557, 3706),
+    (0x6558, 3707), (0x6559, 11539), (0x655A, 3708), (0x655B, 12273), (0x655C, 3709), (0x655D, 9269),
+    (0x655E, 9603), (0x655F, 3710), (0x6560, 3711), (0x6561, 3712), (0x6562, 10595), (0x6563, 13777),
+    (0x6564, 3713), (0x6565, 3714), (0x6566, 10221), (0x6567, 3715), (0x6568, 3716), (0x6569, 3717),
+    (0x656A, 3718), (0x656B, 20259), (0x656C, 11705), (0x656D, 3719), (0x656E, 3720), (0x656F, 3721),
+    (0x6570, 14058), (0x6571, 3722), (0x6572, 13430), (0x6573, 3723), (0x6574, 16146), (0x6575, 3724),
+    (0x6576, 3725), (0x6577, 10438), (0x6578, 3726), (0x6579, 3727), (0x657A, 3728), (0x657B, 3729),
+    (0x657C, 3730), (0x657D, 3731), (0x657E, 3732), (0x657F, 3733), (0x6580, 3734), (0x6581, 3735),
+    (0x6582, 3736), (0x6583, 3737), (0x6584, 3738), (0x6585, 3739), (0x6586, 3740), (0x6587, 14761),
+    (0x6588, 3741), (0x6589, 3742), (0x658A, 3743), (0x658B, 16066), (0x658C, 9298), (0x658D, 3744),
+    (0x658E, 3745), (0x658F, 3746), (0x6590, 20444), (0x6591, 9088), (0x6592, 3747), (0x6593, 20446),
+    (0x6594, 3748), (0x6595, 3749), (0x6596, 3750), (0x6597, 10188), (0x6598, 3751), (0x6599, 12302),
+    (0x659A, 3752), (0x659B, 22225), (0x659C, 15122), (0x659D, 3753), (0x659E, 3754), (0x659F, 16124),
+    (0x65A0, 3755), (0x65A1, 14776), (0x65A2, 3756), (0x65A3, 3757), (0x65A4, 11574), (0x65A5, 9661),
+    (0x65A6, 3758), (0x65A7, 10556), (0x65A8, 3759), (0x65A9, 16077), (0x65AA, 3760), (0x65AB, 20644),
+    (0x65AC, 3761), (0x65AD, 10212), (0x65AE, 3762), (0x65AF, 14180), (0x65B0, 15139), (0x65B1, 3763),
+    (0x65B2, 3764), (0x65B3, 3765), (0x65B4, 3766), (0x65B5, 3767), (0x65B6, 3768), (0x65B7, 3769),
+    (0x65B8, 3770), (0x65B9, 10384), (0x65BA, 3771), (0x65BB, 3772), (0x65BC, 20447), (0x65BD, 13974),
+    (0x65BE, 3773), (0x65BF, 3774), (0x65C0, 3775), (0x65C1, 13067), (0x65C2, 3776), (0x65C3, 20450),
+    (0x65C4, 20449), (0x65C5, 12480), (0x65C6, 20448), (0x65C7, 3777), (0x65C8, 3778), (0x65C9, 3779),
+    (0x65CA, 3780), (0x65CB, 15198), (0x65CC, 20451), (0x65CD, 3781), (0x65CE, 20452), (0x65CF, 16504),
+    (0x65D0, 3782), (0x65D1, 3783), (0x65D2, 20453), (0x65D3, 3784), (0x65D4, 3785), (0x65D5, 3786),
+    (0x65D6, 20454), (0x65D7, 13281), (0x65D8, 3787), (0x65D9, 3788), (0x65DA, 3789), (0x65DB, 3790),
+    (0x65DC, 3791), (0x65DD, 3792), (0x65DE, 3793), (0x65DF, 3794), (0x65E0, 14787), (0x65E1, 3795),
+    (0x65E2, 11345), (0x65E3, 3796), (0x65E4, 3797), (0x65E5, 13638), (0x65E6, 9984), (0x65E7, 11726),
+    (0x65E8, 16273), (0x65E9, 15936), (0x65EA, 3798), (0x65EB, 3799), (0x65EC, 15309), (0x65ED, 15186),
+    (0x65EE, 20069), (0x65EF, 20070), (0x65F0, 20071), (0x65F1, 10946), (0x65F2, 3800), (0x65F3, 3801),
+    (0x65F4, 3802), (0x65F5, 3803), (0x65F6, 13982), (0x65F7, 11960), (0x65F8, 3804), (0x65F9, 3805),
+    (0x65FA, 14625), (0x65FB, 3806), (0x65FC, 3807), (0x65FD, 3808), (0x65FE, 3809), (0x65FF, 3810),
+    (0x6600, 20077), (0x6601, 3811), (0x6602, 9051), (0x6603, 20075), (0x6604, 3812), (0x6605, 3813),
+    (0x6606, 12070), (0x6607, 3814), (0x6608, 3815), (0x6609, 3816), (0x660A, 20072), (0x660B, 3817),
+    (0x660C, 9498), (0x660D, 3818), (0x660E, 12722), (0x660F, 11187), (0x6610, 3819), (0x6611, 3820),
+    (0x6612, 3821), (0x6613, 15540), (0x6614, 14809), (0x6615, 20076), (0x6616, 3822), (0x6617, 3823),
+    (0x6618, 3824), (0x6619, 20073), (0x661A, 3825), (0x661B, 3826), (0x661C, 3827), (0x661D, 20080),
+    (0x661E, 3828), (0x661F, 15144), (0x6620, 15694), (0x6621, 3829), (0x6622, 3830), (0x6623, 3831),
+    (0x6624, 3832), (0x6625, 9811), (0x6626, 3833), (0x6627, 12668), (0x6628, 16517), (0x6629, 3834),
+    (0x662A, 3835), (0x662B, 3836), (0x662C, 3837), (0x662D, 16104), (0x662E, 3838), (0x662F, 14004),
+    (0x6630, 3839), (0x6631, 20082), (0x6632, 3840), (0x6633, 3841), (0x6634, 20081), (0x6635, 20084),
+    (0x6636, 20083), (0x6637, 3842), (0x6638, 3843), (0x6639, 3844), (0x663A, 3845), (0x663B, 3846),
+    (0x663C, 16316), (0x663D, 3847), (0x663E, 14967), (0x663F, 3848), (0x6640, 3849), (0x6641, 20088),
+    (0x6642, 3850), (0x6643, 11161), (0x6644, 3851), (0x6645, 3852), (0x6646, 3853), (0x6647, 3854),
+    (0x6648, 3855), (0x6649, 3856), (0x664A, 3857), (0x664B, 11585), (0x664C, 13821), (0x664D, 3858),
+    (0x664E, 3859), (0x664F, 20089), (0x6650, 3860), (0x6651, 3861), (0x6652, 13800), (0x6653, 15009),
+    (0x6654, 20087), (0x6655, 15911), (0x6656, 20090), (0x6657, 20092), (0x6658, 3862), (0x6659, 3863),
+    (0x665A, 14612), (0x665B, 3864), (0x665C, 3865), (0x665D, 3866), (0x665E, 3867), (0x665F, 20086),
+    (0x6660, 3868), (0x6661, 20091), (0x6662, 3869), (0x6663, 3870), (0x6664, 14803), (0x6665, 3871),
+    (0x6666, 11177), (0x6667, 3872), (0x6668, 9626), (0x6669, 3873), (0x666A, 3874), (0x666B, 3875),
+    (0x666C, 3876), (0x666D, 3877), (0x666E, 13258), (0x666F, 11701), (0x6670, 14815), (0x6671, 3878),
+    (0x6672, 3879), (0x6673, 3880), (0x6674, 13466), (0x6675, 3881), (0x6676, 11692), (0x6677, 20093),
+    (0x6678, 3882), (0x6679, 3883), (0x667A, 16284), (0x667B, 3884), (0x667C, 3885), (0x667D, 3886),
+    (0x667E, 12287), (0x667F, 3887), (0x6680, 3888), (0x6681, 3889), (0x6682, 15926), (0x6683, 3890),
+    (0x6684, 20094), (0x6685, 3891), (0x6686, 3892), (0x6687, 14945), (0x6688, 3893), (0x6689, 3894),
+    (0x668A, 3895), (0x668B, 3896), (0x668C, 20095), (0x668D, 3897), (0x668E, 3898), (0x668F, 3899),
+    (0x6690, 3900), (0x6691, 14043), (0x6692, 3901), (0x6693, 3902), (0x6694, 3903), (0x6695, 3904),
+    (0x6696, 13030), (0x6697, 9046), (0x6698, 3905), (0x6699, 3906), (0x669A, 3907), (0x669B, 3908),
+    (0x669C, 3909), (0x669D, 20097), (0x669E, 3910), (0x669F, 3911), (0x66A0, 3912), (0x66A1, 3913),
+    (0x66A2, 3914), (0x66A3, 3915), (0x66A4, 3916), (0x66A5, 3917), (0x66A6, 3918), (0x66A7, 20096),
+    (0x66A8, 22008), (0x66A9, 3919), (0x66AA, 3920), (0x66AB, 3921), (0x66AC, 3922), (0x66AD, 3923),
+    (0x66AE, 12851), (0x66AF, 3924), (0x66B0, 3925), (0x66B1, 3926), (0x66B2, 3927), (0x66B3, 3928),
+    (0x66B4, 9224), (0x66B5, 3929), (0x66B6, 3930), (0x66B7, 3931), (0x66B8, 3932), (0x66B9, 19158),
+    (0x66BA, 3933), (0x66BB, 3934), (0x66BC, 3935), (0x66BD, 3936), (0x66BE, 20098), (0x66BF, 3937),
+    (0x66C0, 3938), (0x66C1, 3939), (0x66C2, 3940), (0x66C3, 3941), (0x66C4, 3942), (0x66C5, 3943),
+    (0x66C6, 3944), (0x66C7, 3945), (0x66C8, 3946), (0x66C9, 3947), (0x66CA, 3948), (0x66CB, 3949),
+    (0x66CC, 3950), (0x66CD, 3951), (0x66CE, 3952), (0x66CF, 3953), (0x66D0, 3954), (0x66D1, 3955),
+    (0x66D2, 3956), (0x66D3, 3957), (0x66D4, 3958), (0x66D5, 3959), (0x66D6, 3960), (0x66D7, 3961),
+    (0x66D8, 3962), (0x66D9, 14044), (0x66DA, 3963), (0x66DB, 20099), (0x66DC, 20100), (0x66DD, 13261),
+    (0x66DE, 3964), (0x66DF, 3965), (0x66E0, 3966), (0x66E1, 3967), (0x66E2, 3968), (0x66E3, 3969),
+    (0x66E4, 3970), (0x66E5, 3971), (0x66E6, 20101), (0x66E7, 3972), (0x66E8, 3973), (0x66E9, 20102),
+    (0x66EA, 3974), (0x66EB, 3975), (0x66EC, 3976), (0x66ED, 3977), (0x66EE, 3978), (0x66EF, 3979),
+    (0x66F0, 15892), (0x66F1, 3980), (0x66F2, 13485), (0x66F3, 15508), (0x66F4, 10637), (0x66F5, 3981),
+    (0x66F6, 3982), (0x66F7, 20079), (0x66F8, 3983), (0x66F9, 9465), (0x66FA, 3984), (0x66FB, 3985),
+    (0x66FC, 12537), (0x66FD, 3986), (0x66FE, 15953), (0x66FF, 14415), (0x6700, 16513), (0x6701, 3987),
+    (0x6702, 3988), (0x6703, 3989), (0x6704, 3990), (0x6705, 3991), (0x6706, 3992), (0x6707, 3993),
+    (0x6708, 15899), (0x6709, 15723), (0x670A, 20270), (0x670B, 13098), (0x670C, 3994), (0x670D, 10449),
+    (0x670E, 3995), (0x670F, 3996), (0x6710, 20287), (0x6711, 3997), (0x6712, 3998), (0x6713, 3999),
+    (0x6714, 14178), (0x6715, 20297), (0x6716, 4000), (0x6717, 12107), (0x6718, 4001), (0x6719, 4002),
+    (0x671A, 4003), (0x671B, 14626), (0x671C, 4004), (0x671D, 9610), (0x671E, 4005), (0x671F, 13263),
+    (0x6720, 4006), (0x6721, 4007), (0x6722, 4008), (0x6723, 4009), (0x6724, 4010), (0x6725, 4011),
+    (0x6726, 20327), (0x6727, 4012), (0x6728, 12855), (0x6729, 4013), (0x672A, 14745), (0x672B, 12834),
+    (0x672C, 9245), (0x672D, 15958), (0x672E, 4014), (0x672F, 14050), (0x6730, 4015), (0x6731, 16321),
+    (0x6732, 4016), (0x6733, 4017), (0x6734, 13256), (0x6735, 10233), (0x6736, 4018), (0x6737, 4019),
+    (0x6738, 4020), (0x6739, 4021), (0x673A, 11205), (0x673B, 4022), (0x673C, 4023), (0x673D, 15169),
+    (0x673E, 4024), (0x673F, 4025), (0x6740, 13792), (0x6741, 4026), (0x6742, 15916), (0x6743, 13593),
+    (0x6744, 4027), (0x6745, 4028), (0x6746, 10588), (0x6747, 4029), (0x6748, 19695), (0x6749, 13803),
+    (0x674A, 4030), (0x674B, 4031), (0x674C, 19692), (0x674D, 4032), (0x674E, 12143), (0x674F, 15156),
+    (0x6750, 9441), (0x6751, 9854), (0x6752, 4033), (0x6753, 19693), (0x6754, 4034), (0x6755, 4035),
+    (0x6756, 16095), (0x6757, 4036), (0x6758, 4037), (0x6759, 4038), (0x675A, 4039), (0x675B, 4040),
+    (0x675C, 10202), (0x675D, 4041), (0x675E, 19694), (0x675F, 14053), (0x6760, 10605), (0x6761, 14430),
+    (0x6762, 4042), (0x6763, 4043), (0x6764, 4044), (0x6765, 12085), (0x6766, 4045), (0x6767, 4046),
+    (0x6768, 15373), (0x6769, 19696), (0x676A, 19699), (0x676B, 4047), (0x676C, 4048), (0x676D, 10953),
+    (0x676E, 4049), (0x676F, 9228), (0x6770, 11555), (0x6771, 4050), (0x6772, 20074), (0x6773, 19700),
+    (0x6774, 4051), (0x6775, 19703), (0x6776, 4052), (0x6777, 19708), (0x6778, 4053), (0x6779, 4054),
+    (0x677A, 4055), (0x677B, 4056), (0x677C, 19709), (0x677D, 4057), (0x677E, 14196), (0x677F, 9094),
+    (0x6780, 4058), (0x6781, 11316), (0x6782, 4059), (0x6783, 4060), (0x6784, 10760), (0x6785, 4061),
+    (0x6786, 4062), (0x6787, 19698), (0x6788, 4063), (0x6789, 14622), (0x678A, 4064), (0x678B, 19707),
+    (0x678C, 4065), (0x678D, 4066), (0x678E, 4067), (0x678F, 4068), (0x6790, 14811), (0x6791, 4069),
+    (0x6792, 4070), (0x6793, 4071), (0x6794, 4072), (0x6795, 16132), (0x6796, 4073), (0x6797, 12309),
+    (0x6798, 19701), (0x6799, 4074), (0x679A, 12657), (0x679B, 4075), (0x679C, 10826), (0x679D, 16251),
+    (0x679E, 19705), (0x679F, 4076), (0x67A0, 4077), (0x67A1, 4078), (0x67A2, 14029), (0x67A3, 15935),
+    (0x67A4, 4079), (0x67A5, 19697), (0x67A6, 4080), (0x67A7, 19702), (0x67A8, 19704), (0x67A9, 4081),
+    (0x67AA, 13420), (0x67AB, 10422), (0x67AC, 4082), (0x67AD, 19706), (0x67AE, 4083), (0x67AF, 11936),
+    (0x67B0, 19715), (0x67B1, 4084), (0x67B2, 4085), (0x67B3, 19720), (0x67B4, 4086), (0x67B5, 19718),
+    (0x67B6, 11365), (0x67B7, 11352), (0x67B8, 19724), (0x67B9, 4087), (0x67BA, 4088), (0x67BB, 4089),
+    (0x67BC, 4090), (0x67BD, 4091), (0x67BE, 4092), (0x67BF, 4093), (0x67C0, 4094), (0x67C1, 19727),
+    (0x67C2, 4095), (0x67C3, 19723), (0x67C4, 9305), (0x67C5, 4096), (0x67C6, 4097), (0x67C7, 4098),
+    (0x67C8, 4099), (0x67C9, 4100), (0x67CA, 4101), (0x67CB, 4102), (0x67CC, 4103), (0x67CD, 4104),
+    (0x67CE, 4105), (0x67CF, 9081), (0x67D0, 12844), (0x67D1, 10589), (0x67D2, 13271), (0x67D3, 13615),
+    (0x67D4, 13650), (0x67D5, 4106), (0x67D6, 4107), (0x67D7, 4108), (0x67D8, 19712), (0x67D9, 19717),
+    (0x67DA, 19719), (0x67DB, 4109), (0x67DC, 10816), (0x67DD, 19721), (0x67DE, 16520), (0x67DF, 4110),
+    (0x67E0, 12916), (0x67E1, 4111), (0x67E2, 19725), (0x67E3, 4112), (0x67E4, 4113), (0x67E5, 9478),
+    (0x67E6, 4114), (0x67E7, 4115), (0x67E8, 4116), (0x67E9, 19714), (0x67EA, 4117), (0x67EB, 4118),
+    (0x67EC, 11382), (0x67ED, 4119), (0x67EE, 4120), (0x67EF, 11909), (0x67F0, 19710), (0x67F1, 16334),
+    (0x67F2, 4121), (0x67F3, 12343), (0x67F4, 9486), (0x67F5, 4122), (0x67F6, 4123), (0x67F7, 4124),
+    (0x67F8, 4125), (0x67F9, 4126), (0x67FA, 4127), (0x67FB, 4128), (0x67FC, 4129), (0x67FD, 19728),
+    (0x67FE, 4130), (0x67FF, 13998), (0x6800, 19722), (0x6801, 4131), (0x6802, 4132), (0x6803, 4133),
+    (0x6804, 4134), (0x6805, 16059), (0x6806, 4135), (0x6807, 9289), (0x6808, 16082), (0x6809, 19711),
+    (0x680A, 19713), (0x680B, 10181), (0x680C, 19716), (0x680D, 4136), (0x680E, 19726), (0x680F, 12089),
+    (0x6810, 4137), (0x6811, 14052), (0x6812, 4138), (0x6813, 14163), (0x6814, 4139), (0x6815, 4140),
+    (0x6816, 13265), (0x6817, 12150), (0x6818, 4141), (0x6819, 4142), (0x681A, 4143), (0x681B, 4144),
+    (0x681C, 4145), (0x681D, 19738), (0x681E, 4146), (0x681F, 4147), (0x6820, 4148), (0x6821, 15108),
+    (0x6822, 4149), (0x6823, 4150), (0x6824, 4151), (0x6825, 4152), (0x6826, 4153), (0x6827, 4154),
+    (0x6828, 4155), (0x6829, 19747), (0x682A, 16319), (0x682B, 4156), (0x682C, 4157), (0x682D, 4158),
+    (0x682E, 4159), (0x682F, 4160), (0x6830, 4161), (0x6831, 4162), (0x6832, 19729), (0x6833, 19730),
+    (0x6834, 4163), (0x6835, 4164), (0x6836, 4165), (0x6837, 15384), (0x6838, 10968), (0x6839, 10634),
+    (0x683A, 4166), (0x683B, 4167), (0x683C, 10626), (0x683D, 15917), (0x683E, 19744), (0x683F, 4168),
+    (0x6840, 19743), (0x6841, 19741), (0x6842, 10815), (0x6843, 14395), (0x6844, 19735), (0x6845, 14731),
+    (0x6846, 11957), (0x6847, 4169), (0x6848, 9049), (0x6849, 19746), (0x684A, 19745), (0x684B, 4170),
+    (0x684C, 16467), (0x684D, 4171), (0x684E, 19733), (0x684F, 4172), (0x6850, 14544), (0x6851, 13778),
+    (0x6852, 4173), (0x6853, 11139), (0x6854, 11554), (0x6855, 19739), (0x6856, 4174), (0x6857, 4175),
+    (0x6858, 4176), (0x6859, 4177), (0x685A, 4178), (0x685B, 4179), (0x685C, 4180), (0x685D, 4181),
+    (0x685E, 4182), (0x685F, 4183), (0x6860, 19731), (0x6861, 19732), (0x6862, 19734), (0x6863, 9996),
+    (0x6864, 19736), (0x6865, 13432), (0x6866, 19740), (0x6867, 19742), (0x6868, 11511), (0x6869, 16449),
+    (0x686A, 4184), (0x686B, 19753), (0x686C, 4185), (0x686D, 4186), (0x686E, 4187), (0x686F, 4188),
+    (0x6870, 4189), (0x6871, 4190), (0x6872, 4191), (0x6873, 4192), (0x6874, 19750), (0x6875, 4193),
+    (0x6876, 14551), (0x6877, 19751), (0x6878, 4194), (0x6879, 4195), (0x687A, 4196), (0x687B, 4197),
+    (0x687C, 4198), (0x687D, 4199), (0x687E, 4200), (0x687F, 4201), (0x6880, 4202), (0x6881, 12281),
+    (0x6882, 4203), (0x6883, 19737), (0x6884, 4204), (0x6885, 12658), (0x6886, 9105), (0x6887, 4205),
+    (0x6888, 4206), (0x6889, 4207), (0x688A, 4208), (0x688B, 4209), (0x688C, 4210), (0x688D, 4211),
+    (0x688E, 4212), (0x688F, 19749), (0x6890, 4213), (0x6891, 4214), (0x6892, 4215), (0x6893, 19752),
+    (0x6894, 4216), (0x6895, 4217), (0x6896, 4218), (0x6897, 10738), (0x6898, 4219), (0x6899, 4220),
+    (0x689A, 4221), (0x689B, 4222), (0x689C, 4223), (0x689D, 4224), (0x689E, 4225), (0x689F, 4226),
+    (0x68A0, 4227), (0x68A1, 4228), (0x68A2, 13825), (0x68A3, 4229), (0x68A4, 4230), (0x68A5, 4231),
+    (0x68A6, 12681), (0x68A7, 14789), (0x68A8, 12135), (0x68A9, 4232), (0x68AA, 4233), (0x68AB, 4234),
+    (0x68AC, 4235), (0x68AD, 14238), (0x68AE, 4236), (0x68AF, 14406), (0x68B0, 15126), (0x68B1, 4237),
+    (0x68B2, 4238), (0x68B3, 14030), (0x68B4, 4239), (0x68B5, 19748), (0x68B6, 4240), (0x68B7, 4241),
+    (0x68B8, 4242), (0x68B9, 4243), (0x68BA, 4244), (0x68BB, 4245), (0x68BC, 4246), (0x68BD, 4247),
+    (0x68BE, 4248), (0x68BF, 4249), (0x68C0, 11381), (0x68C1, 4250), (0x68C2, 19754), (0x68C3, 4251),
+    (0x68C4, 4252), (0x68C5, 4253), (0x68C6, 4254), (0x68C7, 4255), (0x68C8, 4256), (0x68C9, 12697),
+    (0x68CA, 4257), (0x68CB, 13274), (0x68CC, 4258), (0x68CD, 10822), (0x68CE, 4259), (0x68CF, 4260),
+    (0x68D0, 4261), (0x68D1, 4262), (0x68D2, 9109), (0x68D3, 4263), (0x68D4, 4264), (0x68D5, 16491),
+    (0x68D6, 4265), (0x68D7, 4266), (0x68D8, 11317), (0x68D9, 4267), (0x68DA, 13094), (0x68DB, 4268),
+    (0x68DC, 4269), (0x68DD, 4270), (0x68DE, 4271), (0x68DF, 4272), (0x68E0, 14381), (0x68E1, 4273),
+    (0x68E2, 4274), (0x68E3, 19861), (0x68E4, 4275), (0x68E5, 4276), (0x68E6, 4277), (0x68E7, 4278),
+    (0x68E8, 4279), (0x68E9, 4280), (0x68EA, 4281), (0x68EB, 4282), (0x68EC, 4283), (0x68ED, 4284),
+    (0x68EE, 13788), (0x68EF, 4285), (0x68F0, 19857), (0x68F1, 12131), (0x68F2, 4286), (0x68F3, 4287),
+    (0x68F4, 4288), (0x68F5, 11910), (0x68F6, 4289), (0x68F7, 4290), (0x68F8, 4291), (0x68F9, 19759),
+    (0x68FA, 10790), (0x68FB, 4292), (0x68FC, 19756), (0x68FD, 4293), (0x68FE, 4294), (0x68FF, 4295),
+    (0x6900, 4296), (0x6901, 19859), (0x6902, 4297), (0x6903, 4298), (0x6904, 4299), (0x6905, 15531),
+    (0x6906, 4300), (0x6907, 4301), (0x6908, 4302), (0x6909, 4303), (0x690A, 4304), (0x690B, 19858),
+    (0x690C, 4305), (0x690D, 16263), (0x690E, 16456), (0x690F, 4306), (0x6910, 19862), (0x6911, 4307),
+    (0x6912, 11518), (0x6913, 4308), (0x6914, 4309), (0x6915, 4310), (0x6916, 4311), (0x6917, 4312),
+    (0x6918, 4313), (0x6919, 4314), (0x691A, 4315), (0x691B, 4316), (0x691C, 4317), (0x691D, 4318),
+    (0x691E, 4319), (0x691F, 19757), (0x6920, 19758), (0x6921, 4320), (0x6922, 4321), (0x6923, 4322),
+    (0x6924, 19856), (0x6925, 4323), (0x6926, 4324), (0x6927, 4325), (0x6928, 4326), (0x6929, 4327),
+    (0x692A, 4328), (0x692B, 4329), (0x692C, 4330), (0x692D, 14589), (0x692E, 4331), (0x692F, 4332),
+    (0x6930, 15497), (0x6931, 4333), (0x6932, 4334), (0x6933, 4335), (0x6934, 19873), (0x6935, 4336),
+    (0x6936, 4337), (0x6937, 4338), (0x6938, 4339), (0x6939, 19864), (0x693A, 4340), (0x693B, 4341),
+    (0x693C, 4342), (0x693D, 9795), (0x693E, 4343), (0x693F, 9812), (0x6940, 4344), (0x6941, 4345),
+    (0x6942, 19866), (0x6943, 4346), (0x6944, 4347), (0x6945, 4348), (0x6946, 4349), (0x6947, 4350),
+    (0x6948, 4351), (0x6949, 4352), (0x694A, 4353), (0x694B, 4354), (0x694C, 4355), (0x694D, 4356),
+    (0x694E, 4357), (0x694F, 4358), (0x6950, 4359), (0x6951, 4360), (0x6952, 4361), (0x6953, 4362),
+    (0x6954, 15113), (0x6955, 4363), (0x6956, 4364), (0x6957, 19860), (0x6958, 4365), (0x6959, 4366),
+    (0x695A, 9689), (0x695B, 4367), (0x695C, 4368), (0x695D, 19867), (0x695E, 12132), (0x695F, 4369),
+    (0x6960, 19865), (0x6961, 4370), (0x6962, 4371), (0x6963, 19880), (0x6964, 4372), (0x6965, 4373),
+    (0x6966, 19879), (0x6967, 4374), (0x6968, 4375), (0x6969, 4376), (0x696A, 4377), (0x696B, 19869),
+    (0x696C, 4378), (0x696D, 4379), (0x696E, 19755), (0x696F, 4380), (0x6970, 4381), (0x6971, 19863),
+    (0x6972, 4382), (0x6973, 4383), (0x6974, 4384), (0x6975, 4385), (0x6976, 4386), (0x6977, 11887),
+    (0x6978, 19872), (0x6979, 19881), (0x697A, 4387), (0x697B, 4388), (0x697C, 12450), (0x697D, 4389),
+    (0x697E, 4390), (0x697F, 4391), (0x6980, 19870), (0x6981, 4392), (0x6982, 10582), (0x6983, 4393),
+    (0x6984, 19868), (0x6985, 4394), (0x6986, 15735), (0x6987, 19875), (0x6988, 19876), (0x6989, 19878),
+    (0x698A, 4395), (0x698B, 4396), (0x698C, 4397), (0x698D, 19894), (0x698E, 4398), (0x698F, 4399),
+    (0x6990, 4400), (0x6991, 4401), (0x6992, 4402), (0x6993, 4403), (0x6994, 12103), (0x6995, 19892),
+    (0x6996, 4404), (0x6997, 4405), (0x6998, 19871), (0x6999, 4406), (0x699A, 4407), (0x699B, 19882),
+    (0x699C, 9106), (0x699D, 4408), (0x699E, 4409), (0x699F, 4410), (0x69A0, 4411), (0x69A1, 4412),
+    (0x69A2, 4413), (0x69A3, 4414), (0x69A4, 4415), (0x69A5, 4416), (0x69A6, 4417), (0x69A7, 19883),
+    (0x69A8, 16060), (0x69A9, 4418), (0x69AA, 4419), (0x69AB, 19885), (0x69AC, 4420), (0x69AD, 19886),
+    (0x69AE, 4421), (0x69AF, 4422), (0x69B0, 4423), (0x69B1, 19888), (0x69B2, 4424), (0x69B3, 4425),
+    (0x69B4, 12336), (0x69B5, 4426), (0x69B6, 4427), (0x69B7, 13607), (0x69B8, 4428), (0x69B9, 4429),
+    (0x69BA, 4430), (0x69BB, 19884), (0x69BC, 4431), (0x69BD, 4432), (0x69BE, 4433), (0x69BF, 4434),
+    (0x69C0, 4435), (0x69C1, 19889), (0x69C2, 4436), (0x69C3, 4437), (0x69C4, 4438), (0x69C5, 4439),
+    (0x69C6, 4440), (0x69C7, 4441), (0x69C8, 4442), (0x69C9, 4443), (0x69CA, 19890), (0x69CB, 4444),
+    (0x69CC, 19874), (0x69CD, 4445), (0x69CE, 19877), (0x69CF, 4446), (0x69D0, 11132), (0x69D1, 4447),
+    (0x69D2, 4448), (0x69D3, 4449), (0x69D4, 19887), (0x69D5, 4450), (0x69D6, 4451), (0x69D7, 4452),
+    (0x69D8, 4453), (0x69D9, 4454), (0x69DA, 4455), (0x69DB, 11392), (0x69DC, 4456), (0x69DD, 4457),
+    (0x69DE, 4458), (0x69DF, 19891), (0x69E0, 19893), (0x69E1, 4459), (0x69E2, 4460), (0x69E3, 4461),
+    (0x69E4, 4462), (0x69E5, 4463), (0x69E6, 4464), (0x69E7, 4465), (0x69E8, 4466), (0x69E9, 4467),
+    (0x69EA, 4468), (0x69EB, 4469), (0x69EC, 4470), (0x69ED, 19897), (0x69EE, 4471), (0x69EF, 4472),
+    (0x69F0, 4473), (0x69F1, 4474), (0x69F2, 19901), (0x69F3, 4475), (0x69F4, 4476), (0x69F5, 4477),
+    (0x69F6, 4478), (0x69F7, 4479), (0x69F8, 4480), (0x69F9, 4481), (0x69FA, 4482), (0x69FB, 4483),
+    (0x69FC, 4484), (0x69FD, 9464), (0x69FE, 4485), (0x69FF, 19895), (0x6A00, 4486), (0x6A01, 4487),
+    (0x6A02, 4488), (0x6A03, 4489), (0x6A04, 4490), (0x6A05, 4491), (0x6A06, 4492), (0x6A07, 4493),
+    (0x6A08, 4494), (0x6A09, 4495), (0x6A0A, 10369), (0x6A0B, 4496), (0x6A0C, 4497), (0x6A0D, 4498),
+    (0x6A0E, 4499), (0x6A0F, 4500), (0x6A10, 4501), (0x6A11, 4502), (0x6A12, 4503), (0x6A13, 4504),
+    (0x6A14, 4505), (0x6A15, 4506), (0x6A16, 4507), (0x6A17, 19898), (0x6A18, 19899), (0x6A19, 4508),
+    (0x6A1A, 4509), (0x6A1B, 4510), (0x6A1C, 4511), (0x6A1D, 4512), (0x6A1E, 4513), (0x6A1F, 16088),
+    (0x6A20, 4514), (0x6A21, 12828), (0x6A22, 4515), (0x6A23, 4516), (0x6A24, 4517), (0x6A25, 4518),
+    (0x6A26, 4519), (0x6A27, 4520), (0x6A28, 19911), (0x6A29, 4521), (0x6A2A, 10990), (0x6A2B, 4522),
+    (0x6A2C, 4523), (0x6A2D, 4524), (0x6A2E, 4525), (0x6A2F, 19896), (0x6A30, 4526), (0x6A31, 15678),
+    (0x6A32, 4527), (0x6A33, 4528), (0x6A34, 4529), (0x6A35, 19907), (0x6A36, 4530), (0x6A37, 4531),
+    (0x6A38, 4532), (0x6A39, 4533), (0x6A3A, 4534), (0x6A3B, 4535), (0x6A3C, 4536), (0x6A3D, 19910),
+    (0x6A3E, 19903), (0x6A3F, 4537), (0x6A40, 4538), (0x6A41, 4539), (0x6A42, 4540), (0x6A43, 4541),
+    (0x6A44, 19902), (0x6A45, 4542), (0x6A46, 4543), (0x6A47, 13428), (0x6A48, 4544), (0x6A49, 4545),
+    (0x6A4A, 4546), (0x6A4B, 4547), (0x6A4C, 4548), (0x6A4D, 4549), (0x6A4E, 4550), (0x6A4F, 4551),
+    (0x6A50, 19905), (0x6A51, 4552), (0x6A52, 4553), (0x6A53, 4554), (0x6A54, 4555), (0x6A55, 4556),
+    (0x6A56, 4557), (0x6A57, 4558), (0x6A58, 19912), (0x6A59, 9635), (0x6A5A, 4559), (0x6A5B, 19906),
+    (0x6A5C, 4560), (0x6A5D, 4561), (0x6A5E, 4562), (0x6A5F, 4563), (0x6A60, 4564), (0x6A61, 14995),
+    (0x6A62, 4565), (0x6A63, 4566), (0x6A64, 4567), (0x6A65, 19900), (0x6A66, 4568), (0x6A67, 4569),
+    (0x6A68, 4570), (0x6A69, 4571), (0x6A6A, 4572), (0x6A6B, 4573), (0x6A6C, 4574), (0x6A6D, 4575),
+    (0x6A6E, 4576), (0x6A6F, 4577), (0x6A70, 4578), (0x6A71, 9682), (0x6A72, 4579), (0x6A73, 4580),
+    (0x6A74, 4581), (0x6A75, 4582), (0x6A76, 4583), (0x6A77, 4584), (0x6A78, 4585), (0x6A79, 19909),
+    (0x6A7A, 4586), (0x6A7B, 4587), (0x6A7C, 19913), (0x6A7D, 4588), (0x6A7E, 4589), (0x6A7F, 4590),
+    (0x6A80, 14365), (0x6A81, 4591), (0x6A82, 4592), (0x6A83, 4593), (0x6A84, 14928), (0x6A85, 4594),
+    (0x6A86, 4595), (0x6A87, 4596), (0x6A88, 4597), (0x6A89, 4598), (0x6A8A, 4599), (0x6A8B, 4600),
+    (0x6A8C, 4601), (0x6A8D, 4602), (0x6A8E, 19908), (0x6A8F, 4603), (0x6A90, 19915), (0x6A91, 19914),
+    (0x6A92, 4604), (0x6A93, 4605), (0x6A94, 4606), (0x6A95, 4607), (0x6A96, 4608), (0x6A97, 19917),
+    (0x6A98, 4609), (0x6A99, 4610), (0x6A9A, 4611), (0x6A9B, 4612), (0x6A9C, 4613), (0x6A9D, 4614),
+    (0x6A9E, 4615), (0x6A9F, 4616), (0x6AA0, 19904), (0x6AA1, 4617), (0x6AA2, 4618), (0x6AA3, 4619),
+    (0x6AA4, 4620), (0x6AA5, 4621), (0x6AA6, 4622), (0x6AA7, 4623), (0x6AA8, 4624), (0x6AA9, 19916),
+    (0x6AAA, 4625), (0x6AAB, 19918), (0x6AAC, 12677), (0x6AAD, 4626), (0x6AAE, 4627), (0x6AAF, 4628),
+    (0x6AB0, 4629), (0x6AB1, 4630), (0x6AB2, 4631), (0x6AB3, 4632), (0x6AB4, 4633), (0x6AB5, 4634),
+    (0x6AB6, 4635), (0x6AB7, 4636), (0x6AB8, 4637), (0x6AB9, 4638), (0x6ABA, 4639), (0x6ABB, 4640),
+    (0x6ABC, 4641), (0x6ABD, 4642), (0x6ABE, 4643), (0x6ABF, 4644), (0x6AC0, 4645), (0x6AC1, 4646),
+    (0x6AC2, 4647), (0x6AC3, 4648), (0x6AC4, 4649), (0x6AC5, 4650), (0x6AC6, 4651), (0x6AC7, 4652),
+    (0x6AC8, 4653), (0x6AC9, 4654), (0x6ACA, 4655), (0x6ACB, 4656), (0x6ACC, 4657), (0x6ACD, 4658),
+    (0x6ACE, 4659), (0x6ACF, 4660), (0x6AD0, 4661), (0x6AD1, 4662), (0x6AD2, 4663), (0x6AD3, 4664),
+    (0x6AD4, 4665), (0x6AD5, 4666), (0x6AD6, 4667), (0x6AD7, 4668), (0x6AD8, 4669), (0x6AD9, 4670),
+    (0x6ADA, 4671), (0x6ADB, 4672), (0x6ADC, 4673), (0x6ADD, 4674), (0x6ADE, 4675), (0x6ADF, 4676),
+    (0x6AE0, 4677), (0x6AE1, 4678), (0x6AE2, 4679), (0x6AE3, 4680), (0x6AE4, 4681), (0x6AE5, 4682),
+    (0x6AE6, 4683), (0x6AE7, 4684), (0x6AE8, 4685), (0x6AE9, 4686), (0x6AEA, 4687), (0x6AEB, 4688),
+    (0x6AEC, 4689), (0x6AED, 4690), (0x6AEE, 4691), (0x6AEF, 4692), (0x6AF0, 4693), (0x6AF1, 4694),
+    (0x6AF2, 4695), (0x6AF3, 4696), (0x6AF4, 4697), (0x6AF5, 4698), (0x6AF6, 4699), (0x6AF7, 4700),
+    (0x6AF8, 4701), (0x6AF9, 4702), (0x6AFA, 4703), (0x6AFB, 4704), (0x6AFC, 4705), (0x6AFD, 4706),
+    (0x6AFE, 4707), (0x6AFF, 4708), (0x6B00, 4709), (0x6B01, 4710), (0x6B02, 4711), (0x6B03, 4712),
+    (0x6B04, 4713), (0x6B05, 4714), (0x6B06, 4715), (0x6B07, 4716), (0x6B08, 4717), (0x6B09, 4718),
+    (0x6B0A, 4719), (0x6B0B, 4720), (0x6B0C, 4721), (0x6B0D, 4722), (0x6B0E, 4723), (0x6B0F, 4724),
+    (0x6B10, 4725), (0x6B11, 4726), (0x6B12, 4727), (0x6B13, 4728), (0x6B14, 4729), (0x6B15, 4730),
+    (0x6B16, 4731), (0x6B17, 4732), (0x6B18, 4733), (0x6B19, 4734), (0x6B1A, 4735), (0x6B1B, 4736),
+    (0x6B1C, 4737), (0x6B1D, 4738), (0x6B1E, 4739), (0x6B1F, 4740), (0x6B20, 13418), (0x6B21, 9831),
+    (0x6B22, 11137), (0x6B23, 15137), (0x6B24, 20428), (0x6B25, 4741), (0x6B26, 4742), (0x6B27, 13038),
+    (0x6B28, 4743), (0x6B29, 4744), (0x6B2A, 4745), (0x6B2B, 4746), (0x6B2C, 4747), (0x6B2D, 4748),
+    (0x6B2E, 4749), (0x6B2F, 4750), (0x6B30, 4751), (0x6B31, 4752), (0x6B32, 15766), (0x6B33, 4753),
+    (0x6B34, 4754), (0x6B35, 4755), (0x6B36, 4756), (0x6B37, 20429), (0x6B38, 4757), (0x6B39, 20430),
+    (0x6B3A, 13264), (0x6B3B, 4758), (0x6B3C, 4759), (0x6B3D, 4760), (0x6B3E, 11953), (0x6B3F, 4761),
+    (0x6B40, 4762), (0x6B41, 4763), (0x6B42, 4764), (0x6B43, 20431), (0x6B44, 4765), (0x6B45, 4766),
+    (0x6B46, 20432), (0x6B47, 15115), (0x6B48, 4767), (0x6B49, 13419), (0x6B4A, 4768), (0x6B4B, 4769),
+    (0x6B4C, 10617), (0x6B4D, 4770), (0x6B4E, 4771), (0x6B4F, 4772), (0x6B50, 4773), (0x6B51, 4774),
+    (0x6B52, 4775), (0x6B53, 4776), (0x6B54, 4777), (0x6B55, 4778), (0x6B56, 4779), (0x6B57, 4780),
+    (0x6B58, 4781), (0x6B59, 20433), (0x6B5A, 4782), (0x6B5B, 4783), (0x6B5C, 4784), (0x6B5D, 4785),
+    (0x6B5E, 4786), (0x6B5F, 4787), (0x6B60, 4788), (0x6B61, 4789), (0x6B62, 16270), (0x6B63, 16148),
+    (0x6B64, 9828), (0x6B65, 9434), (0x6B66, 14793), (0x6B67, 13276), (0x6B68, 4790), (0x6B69, 4791),
+    (0x6B6A, 14600), (0x6B6B, 4792), (0x6B6C, 4793), (0x6B6D, 4794), (0x6B6E, 4795), (0x6B6F, 4796),
+    (0x6B70, 4797), (0x6B71, 4798), (0x6B72, 4799), (0x6B73, 4800), (0x6B74, 4801), (0x6B75, 4802),
+    (0x6B76, 4803), (0x6B77, 4804), (0x6B78, 4805), (0x6B79, 9870), (0x6B7A, 4806), (0x6B7B, 14187),
+    (0x6B7C, 11368), (0x6B7D, 4807), (0x6B7E, 4808), (0x6B7F, 4809), (0x6B80, 4810), (0x6B81, 19921),
+    (0x6B82, 19922), (0x6B83, 15369), (0x6B84, 19924), (0x6B85, 4811), (0x6B86, 9874), (0x6B87, 19923),
+    (0x6B88, 4812), (0x6B89, 15314), (0x6B8A, 14031), (0x6B8B, 9453), (0x6B8C, 4813), (0x6B8D, 19927),
+    (0x6B8E, 4814), (0x6B8F, 4815), (0x6B90, 4816), (0x6B91, 4817), (0x6B92, 19925), (0x6B93, 19926),
+    (0x6B94, 4818), (0x6B95, 4819), (0x6B96, 16264), (0x6B97, 4820), (0x6B98, 4821), (0x6B99, 4822),
+    (0x6B9A, 19928), (0x6B9B, 19929), (0x6B9C, 4823), (0x6B9D, 4824), (0x6B9E, 4825), (0x6B9F, 4826),
+    (0x6BA0, 4827), (0x6BA1, 19930), (0x6BA2, 4828), (0x6BA3, 4829), (0x6BA4, 4830), (0x6BA5, 4831),
+    (0x6BA6, 4832), (0x6BA7, 4833), (0x6BA8, 4834), (0x6BA9, 4835), (0x6BAA, 19931), (0x6BAB, 4836),
+    (0x6BAC, 4837), (0x6BAD, 4838), (0x6BAE, 4839), (0x6BAF, 4840), (0x6BB0, 4841), (0x6BB1, 4842),
+    (0x6BB2, 4843), (0x6BB3, 20440), (0x6BB4, 13040), (0x6BB5, 10211), (0x6BB6, 4844), (0x6BB7, 15568),
+    (0x6BB8, 4845), (0x6BB9, 4846), (0x6BBA, 4847), (0x6BBB, 4848), (0x6BBC, 4849), (0x6BBD, 4850),
+    (0x6BBE, 4851), (0x6BBF, 10053), (0x6BC0, 4852), (0x6BC1, 11172), (0x6BC2, 20442), (0x6BC3, 4853),
+    (0x6BC4, 4854), (0x6BC5, 15552), (0x6BC6, 4855), (0x6BC7, 4856), (0x6BC8, 4857), (0x6BC9, 4858),
+    (0x6BCA, 4859), (0x6BCB, 14792), (0x6BCC, 4860), (0x6BCD, 12849), (0x6BCE, 4861), (0x6BCF, 12666),
+    (0x6BD0, 4862), (0x6BD1, 4863), (0x6BD2, 10195), (0x6BD3, 16650), (0x6BD4, 9255), (0x6BD5, 9262),
+    (0x6BD6, 9264), (0x6BD7, 13109), (0x6BD8, 4864), (0x6BD9, 9263), (0x6BDA, 4865), (0x6BDB, 12646),
+    (0x6BDC, 4866), (0x6BDD, 4867), (0x6BDE, 4868), (0x6BDF, 4869), (0x6BE0, 4870), (0x6BE1, 16072),
+    (0x6BE2, 4871), (0x6BE3, 4872), (0x6BE4, 4873), (0x6BE5, 4874), (0x6BE6, 4875), (0x6BE7, 4876),
+    (0x6BE8, 4877), (0x6BE9, 4878), (0x6BEA, 20239), (0x6BEB, 10958), (0x6BEC, 4879), (0x6BED, 4880),
+    (0x6BEE, 4881), (0x6BEF, 14371), (0x6BF0, 4882), (0x6BF1, 4883), (0x6BF2, 4884), (0x6BF3, 20240),
+    (0x6BF4, 4885), (0x6BF5, 20242), (0x6BF6, 4886), (0x6BF7, 4887), (0x6BF8, 4888), (0x6BF9, 20243),
+    (0x6BFA, 4889), (0x6BFB, 4890), (0x6BFC, 4891), (0x6BFD, 20241), (0x6BFE, 4892), (0x6BFF, 4893),
+    (0x6C00, 4894), (0x6C01, 4895), (0x6C02, 4896), (0x6C03, 4897), (0x6C04, 4898), (0x6C05, 20244),
+    (0x6C06, 20246), (0x6C07, 20245), (0x6C08, 4899), (0x6C09, 4900), (0x6C0A, 4901), (0x6C0B, 4902),
+    (0x6C0C, 4903), (0x6C0D, 20247), (0x6C0E, 4904), (0x6C0F, 14012), (0x6C10, 16646), (0x6C11, 12716),
+    (0x6C12, 4905), (0x6C13, 12640), (0x6C14, 13293), (0x6C15, 20248), (0x6C16, 12867), (0x6C17, 4906),
+    (0x6C18, 20249), (0x6C19, 20250), (0x6C1A, 20251), (0x6C1B, 10408), (0x6C1C, 4907), (0x6C1D, 4908),
+    (0x6C1E, 4909), (0x6C1F, 10445), (0x6C20, 4910), (0x6C21, 20252), (0x6C22, 13461), (0x6C23, 4911),
+    (0x6C24, 20254), (0x6C25, 4912), (0x6C26, 10929), (0x6C27, 15380), (0x6C28, 9042), (0x6C29, 20253),
+    (0x6C2A, 20255), (0x6C2B, 4913), (0x6C2C, 4914), (0x6C2D, 4915), (0x6C2E, 9985), (0x6C2F, 12485),
+    (0x6C30, 13467), (0x6C31, 4916), (0x6C32, 20256), (0x6C33, 4917), (0x6C34, 14169), (0x6C35, 18778),
+    (0x6C36, 4918), (0x6C37, 4919), (0x6C38, 15707), (0x6C39, 4920), (0x6C3A, 4921), (0x6C3B, 4922),
+    (0x6C3C, 4923), (0x6C3D, 16874), (0x6C3E, 4924), (0x6C3F, 4925), (0x6C40, 14536), (0x6C41, 16258),
+    (0x6C42, 13478), (0x6C43, 4926), (0x6C44, 4927), (0x6C45, 4928), (0x6C46, 16879), (0x6C47, 11182),
+    (0x6C48, 4929), (0x6C49, 10951), (0x6C4A, 18781), (0x6C4B, 4930), (0x6C4C, 4931), (0x6C4D, 4932),
+    (0x6C4E, 4933), (0x6C4F, 4934), (0x6C50, 14926), (0x6C51, 4935), (0x6C52, 4936), (0x6C53, 4937),
+    (0x6C54, 18779), (0x6C55, 13814), (0x6C56, 4938), (0x6C57, 10950), (0x6C58, 4939), (0x6C59, 4940),
+    (0x6C5A, 4941), (0x6C5B, 15315), (0x6C5C, 18780), (0x6C5D, 13659), (0x6C5E, 10750), (0x6C5F, 11508),
+    (0x6C60, 9651), (0x6C61, 14784), (0x6C62, 4942), (0x6C63, 4943), (0x6C64, 14377), (0x6C65, 4944),
+    (0x6C66, 4945), (0x6C67, 4946), (0x6C68, 18787), (0x6C69, 18788), (0x6C6A, 14619), (0x6C6B, 4947),
+    (0x6C6C, 4948), (0x6C6D, 4949), (0x6C6E, 4950), (0x6C6F, 4951), (0x6C70, 14358), (0x6C71, 4952),
+    (0x6C72, 11324), (0x6C73, 4953), (0x6C74, 18789), (0x6C75, 4954), (0x6C76, 18790), (0x6C77, 4955),
+    (0x6C78, 4956), (0x6C79, 15163), (0x6C7A, 4957), (0x6C7B, 4958), (0x6C7C, 4959), (0x6C7D, 13296),
+    (0x6C7E, 10413), (0x6C7F, 4960), (0x6C80, 4961), (0x6C81, 13458), (0x6C82, 15527), (0x6C83, 14779),
+    (0x6C84, 4962), (0x6C85, 18783), (0x6C86, 18791), (0x6C87, 4963), (0x6C88, 13857), (0x6C89, 9628),
+    (0x6C8A, 4964), (0x6C8B, 4965), (0x6C8C, 18786), (0x6C8D, 4966), (0x6C8E, 4967), (0x6C8F, 13272),
+    (0x6C90, 18784), (0x6C91, 4968), (0x6C92, 4969), (0x6C93, 20634), (0x6C94, 18785), (0x6C95, 4970),
+    (0x6C96, 4971), (0x6C97, 4972), (0x6C98, 4973), (0x6C99, 13794), (0x6C9A, 4974), (0x6C9B, 13085),
+    (0x6C9C, 4975), (0x6C9D, 4976), (0x6C9E, 4977), (0x6C9F, 10756), (0x6CA0, 4978), (0x6CA1, 12662),
+    (0x6CA2, 4979), (0x6CA3, 18782), (0x6CA4, 13044), (0x6CA5, 12259), (0x6CA6, 12502), (0x6CA7, 9460),
+    (0x6CA8, 4980), (0x6CA9, 18792), (0x6CAA, 11121), (0x6CAB, 12838), (0x6CAC, 4981), (0x6CAD, 18795),
+    (0x6CAE, 11743), (0x6CAF, 4982), (0x6CB0, 4983), (0x6CB1, 18806), (0x6CB2, 18800), (0x6CB3, 10976),
+    (0x6CB4, 4984), (0x6CB5, 4985), (0x6CB6, 4986), (0x6CB7, 4987), (0x6CB8, 10403), (0x6CB9, 15720),
+    (0x6CBA, 4988), (0x6CBB, 16291), (0x6CBC, 16106), (0x6CBD, 10768), (0x6CBE, 16075), (0x6CBF, 15351),
+    (0x6CC0, 4989), (0x6CC1, 4990), (0x6CC2, 4991), (0x6CC3, 4992), (0x6CC4, 15130), (0x6CC5, 13481),
+    (0x6CC6, 4993), (0x6CC7, 4994), (0x6CC8, 4995), (0x6CC9, 13595), (0x6CCA, 9425), (0x6CCB, 4996),
+    (0x6CCC, 12693), (0x6CCD, 4997), (0x6CCE, 4998), (0x6CCF, 4999), (0x6CD0, 18793), (0x6CD1, 5000),
+    (0x6CD2, 5001), (0x6CD3, 18807), (0x6CD4, 18794), (0x6CD5, 10363), (0x6CD6, 18802), (0x6CD7, 18799),
+    (0x6CD8, 5002), (0x6CD9, 5003), (0x6CDA, 5004), (0x6CDB, 10381), (0x6CDC, 5005), (0x6CDD, 5006),
+    (0x6CDE, 13017), (0x6CDF, 5007), (0x6CE0, 18801), (0x6CE1, 13076), (0x6CE2, 9413), (0x6CE3, 13297),
+    (0x6CE4, 5008), (0x6CE5, 12889), (0x6CE6, 5009), (0x6CE7, 5010), (0x6CE8, 16437), (0x6CE9, 5011),
+    (0x6CEA, 12130), (0x6CEB, 18804), (0x6CEC, 5012), (0x6CED, 5013), (0x6CEE, 18805), (0x6CEF, 18808),
+    (0x6CF0, 14354), (0x6CF1, 18798), (0x6CF2, 5014), (0x6CF3, 15705), (0x6CF4, 5015), (0x6CF5, 9250),
+    (0x6CF6, 20635), (0x6CF7, 18796), (0x6CF8, 18797), (0x6CF9, 5016), (0x6CFA, 18803), (0x6CFB, 15131),
+    (0x6CFC, 13240), (0x6CFD, 15948), (0x6CFE, 18809), (0x6CFF, 5017), (0x6D00, 5018), (0x6D01, 11559),
+    (0x6D02, 5019), (0x6D03, 5020), (0x6D04, 18912), (0x6D05, 5021), (0x6D06, 5022), (0x6D07, 18911),
+    (0x6D08, 5023), (0x6D09, 5024), (0x6D0A, 5025), (0x6D0B, 15378), (0x6D0C, 18908), (0x6D0D, 5026),
+    (0x6D0E, 18914), (0x6D0F, 5027), (0x6D10, 5028), (0x6D11, 5029), (0x6D12, 13672), (0x6D13, 5030),
+    (0x6D14, 5031), (0x6D15, 5032), (0x6D16, 5033), (0x6D17, 14935), (0x6D18, 5034), (0x6D19, 18913),
+    (0x6D1A, 18919), (0x6D1B, 12514), (0x6D1C, 5035), (0x6D1D, 5036), (0x6D1E, 10185), (0x6D1F, 5037),
+    (0x6D20, 5038), (0x6D21, 5039), (0x6D22, 5040), (0x6D23, 5041), (0x6D24, 5042), (0x6D25, 11577),
+    (0x6D26, 5043), (0x6D27, 18907), (0x6D28, 5044), (0x6D29, 5045), (0x6D2A, 10998), (0x6D2B, 18915),
+    (0x6D2C, 5046), (0x6D2D, 5047), (0x6D2E, 18917), (0x6D2F, 5048), (0x6D30, 5049), (0x6D31, 10258),
+    (0x6D32, 16307), (0x6D33, 18923), (0x6D34, 5050), (0x6D35, 18918), (0x6D36, 5051), (0x6D37, 5052),
+    (0x6D38, 5053), (0x6D39, 18906), (0x6D3A, 5054), (0x6D3B, 11193), (0x6D3C, 14596), (0x6D3D, 13397),
+    (0x6D3E, 13056), (0x6D3F, 5055), (0x6D40, 5056), (0x6D41, 12342), (0x6D42, 5057), (0x6D43, 18909),
+    (0x6D44, 5058), (0x6D45, 13414), (0x6D46, 11507), (0x6D47, 11524), (0x6D48, 18910), (0x6D49, 5059),
+    (0x6D4A, 16474), (0x6D4B, 9471), (0x6D4C, 5060), (0x6D4D, 18916), (0x6D4E, 11340), (0x6D4F, 18920),
+    (0x6D50, 5061), (0x6D51, 11190), (0x6D52, 18921), (0x6D53, 13023), (0x6D54, 18922), (0x6D55, 5062),
+    (0x6D56, 5063), (0x6D57, 5064), (0x6D58, 5065), (0x6D59, 16122), (0x6D5A, 11878), (0x6D5B, 5066),
+    (0x6D5C, 18931), (0x6D5D, 5067), (0x6D5E, 18928), (0x6D5F, 5068), (0x6D60, 18932), (0x6D61, 5069),
+    (0x6D62, 5070), (0x6D63, 18934), (0x6D64, 5071), (0x6D65, 5072), (0x6D66, 13259), (0x6D67, 5073),
+    (0x6D68, 5074), (0x6D69, 10963), (0x6D6A, 12108), (0x6D6B, 5075), (0x6D6C, 5076), (0x6D6D, 5077),
+    (0x6D6E, 10546), (0x6D6F, 18925), (0x6D70, 5078), (0x6D71, 5079), (0x6D72, 5080), (0x6D73, 5081),
+    (0x6D74, 15866), (0x6D75, 5082), (0x6D76, 5083), (0x6D77, 10928), (0x6D78, 11589), (0x6D79, 5084),
+    (0x6D7A, 5085), (0x6D7B, 5086), (0x6D7C, 18933), (0x6D7D, 5087), (0x6D7E, 5088), (0x6D7F, 5089),
+    (0x6D80, 5090), (0x6D81, 5091), (0x6D82, 14566), (0x6D83, 5092), (0x6D84, 5093), (0x6D85, 12914),
+    (0x6D86, 5094), (0x6D87, 5095), (0x6D88, 15006), (0x6D89, 13845), (0x6D8A, 5096), (0x6D8B, 5097),
+    (0x6D8C, 15706), (0x6D8D, 5098), (0x6D8E, 14964), (0x6D8F, 5099), (0x6D90, 5100), (0x6D91, 18924),
+    (0x6D92, 5101), (0x6D93, 18929), (0x6D94, 18930), (0x6D95, 14418), (0x6D96, 5102), (0x6D97, 5103),
+    (0x6D98, 5104), (0x6D99, 5105), (0x6D9A, 5106), (0x6D9B, 14391), (0x6D9C, 5107), (0x6D9D, 12117),
+    (0x6D9E, 18926), (0x6D9F, 12271), (0x6DA0, 18927), (0x6DA1, 14773), (0x6DA2, 5108), (0x6DA3, 11148),
+    (0x6DA4, 10026), (0x6DA5, 5109), (0x6DA6, 13668), (0x6DA7, 11502), (0x6DA8, 16094), (0x6DA9, 13787),
+    (0x6DAA, 10547), (0x6DAB, 18947), (0x6DAC, 5110), (0x6DAD, 5111), (0x6DAE, 18949), (0x6DAF, 15331),
+    (0x6DB0, 5112), (0x6DB1, 5113), (0x6DB2, 15511), (0x6DB3, 5114), (0x6DB4, 5115), (0x6DB5, 10938),
+    (0x6DB6, 5116), (0x6DB7, 5117), (0x6DB8, 10977), (0x6DB9, 5118), (0x6DBA, 5119), (0x6DBB, 5120),
+    (0x6DBC, 5121), (0x6DBD, 5122), (0x6DBE, 5123), (0x6DBF, 18940), (0x6DC0, 10052), (0x6DC1, 5124),
+    (0x6DC2, 5125), (0x6DC3, 5126), (0x6DC4, 16480), (0x6DC5, 18937), (0x6DC6, 15008), (0x6DC7, 18936),
+    (0x6DC8, 5127), (0x6DC9, 5128), (0x6DCA, 5129), (0x6DCB, 12315), (0x6DCC, 14387), (0x6DCD, 5130),
+    (0x6DCE, 5131), (0x6DCF, 5132), (0x6DD0, 5133), (0x6DD1, 14036), (0x6DD2, 5134), (0x6DD3, 5135),
+    (0x6DD4, 5136), (0x6DD5, 5137), (0x6DD6, 12880), (0x6DD7, 5138), (0x6DD8, 14397), (0x6DD9, 18945),
+    (0x6DDA, 5139), (0x6DDB, 5140), (0x6DDC, 5141), (0x6DDD, 18944), (0x6DDE, 18938), (0x6DDF, 5142),
+    (0x6DE0, 18941), (0x6DE1, 9988), (0x6DE2, 5143), (0x6DE3, 5144), (0x6DE4, 15732), (0x6DE5, 5145),
+    (0x6DE6, 18943), (0x6DE7, 5146), (0x6DE8, 5147), (0x6DE9, 5148), (0x6DEA, 5149), (0x6DEB, 15574),
+    (0x6DEC, 9852), (0x6DED, 5150), (0x6DEE, 11135), (0x6DEF, 5151), (0x6DF0, 5152), (0x6DF1, 13853),
+    (0x6DF2, 5153), (0x6DF3, 9815), (0x6DF4, 5154), (0x6DF5, 5155), (0x6DF6, 5156), (0x6DF7, 11191),
+    (0x6DF8, 5157), (0x6DF9, 15340), (0x6DFA, 5158), (0x6DFB, 14422), (0x6DFC, 20636), (0x6DFD, 5159),
+    (0x6DFE, 5160), (0x6DFF, 5161), (0x6E00, 5162), (0x6E01, 5163), (0x6E02, 5164), (0x6E03, 5165),
+    (0x6E04, 5166), (0x6E05, 13464), (0x6E06, 5167), (0x6E07, 5168), (0x6E08, 5169), (0x6E09, 5170),
+    (0x6E0A, 15873), (0x6E0B, 5171), (0x6E0C, 18948), (0x6E0D, 16488), (0x6E0E, 18939), (0x6E0F, 5172),
+    (0x6E10, 11500), (0x6E11, 18942), (0x6E12, 5173), (0x6E13, 5174), (0x6E14, 15745), (0x6E15, 5175),
+    (0x6E16, 18946), (0x6E17, 13863), (0x6E18, 5176), (0x6E19, 5177), (0x6E1A, 18935), (0x6E1B, 5178),
+    (0x6E1C, 5179), (0x6E1D, 15744), (0x6E1E, 5180), (0x6E1F, 5181), (0x6E20, 13489), (0x6E21, 10206),
+    (0x6E22, 5182), (0x6E23, 15957), (0x6E24, 9424), (0x6E25, 18960), (0x6E26, 5183), (0x6E27, 5184),
+    (0x6E28, 5185), (0x6E29, 14759), (0x6E2A, 5186), (0x6E2B, 18950), (0x6E2C, 5187), (0x6E2D, 14753),
+    (0x6E2E, 5188), (0x6E2F, 10604), (0x6E30, 5189), (0x6E31, 5190), (0x6E32, 18959), (0x6E33, 5191),
+    (0x6E34, 11917), (0x6E35, 5192), (0x6E36, 5193), (0x6E37, 5194), (0x6E38, 15721), (0x6E39, 5195),
+    (0x6E3A, 12711), (0x6E3B, 5196), (0x6E3C, 5197), (0x6E3D, 5198), (0x6E3E, 5199), (0x6E3F, 5200),
+    (0x6E40, 5201), (0x6E41, 5202), (0x6E42, 5203), (0x6E43, 13055), (0x6E44, 18961), (0x6E45, 5204),
+    (0x6E46, 5205), (0x6E47, 5206), (0x6E48, 5207), (0x6E49, 5208), (0x6E4A, 5209), (0x6E4B, 5210),
+    (0x6E4C, 5211), (0x6E4D, 14571), (0x6E4E, 18952), (0x6E4F, 5212), (0x6E50, 5213), (0x6E51, 5214),
+    (0x6E52, 5215), (0x6E53, 18957), (0x6E54, 18958), (0x6E55, 5216), (0x6E56, 11019), (0x6E57, 5217),
+    (0x6E58, 14985), (0x6E59, 5218), (0x6E5A, 5219), (0x6E5B, 16086), (0x6E5C, 5220), (0x6E5D, 5221),
+    (0x6E5E, 5222), (0x6E5F, 18955), (0x6E60, 5223), (0x6E61, 5224), (0x6E62, 5225), (0x6E63, 5226),
+    (0x6E64, 5227), (0x6E65, 5228), (0x6E66, 5229), (0x6E67, 5230), (0x6E68, 5231), (0x6E69, 5232),
+    (0x6E6A, 5233), (0x6E6B, 18953), (0x6E6C, 5234), (0x6E6D, 5235), (0x6E6E, 18951), (0x6E6F, 5236),
+    (0x6E70, 5237), (0x6E71, 5238), (0x6E72, 5239), (0x6E73, 5240), (0x6E74, 5241), (0x6E75, 5242),
+    (0x6E76, 5243), (0x6E77, 5244), (0x6E78, 5245), (0x6E79, 5246), (0x6E7A, 5247), (0x6E7B, 5248),
+    (0x6E7C, 5249), (0x6E7D, 5250), (0x6E7E, 14604), (0x6E7F, 13975), (0x6E80, 5251), (0x6E81, 5252),
+    (0x6E82, 5253), (0x6E83, 12068), (0x6E84, 5254), (0x6E85, 11501), (0x6E86, 18956), (0x6E87, 5255),
+    (0x6E88, 5256), (0x6E89, 10585), (0x6E8A, 5257), (0x6E8B, 5258), (0x6E8C, 5259), (0x6E8D, 5260),
+    (0x6E8E, 5261), (0x6E8F, 18976), (0x6E90, 15885), (0x6E91, 5262), (0x6E92, 5263), (0x6E93, 5264),
+    (0x6E94, 5265), (0x6E95, 5266), (0x6E96, 5267), (0x6E97, 5268), (0x6E98, 18964), (0x6E99, 5269),
+    (0x6E9A, 5270), (0x6E9B, 5271), (0x6E9C, 12334), (0x6E9D, 5272), (0x6E9E, 5273), (0x6E9F, 18978),
+    (0x6EA0, 5274), (0x6EA1, 5275), (0x6EA2, 15556), (0x6EA3, 5276), (0x6EA4, 5277), (0x6EA5, 18968),
+    (0x6EA6, 5278), (0x6EA7, 18969), (0x6EA8, 5279), (0x6EA9, 5280), (0x6EAA, 14925), (0x6EAB, 5281),
+    (0x6EAC, 5282), (0x6EAD, 5283), (0x6EAE, 5284), (0x6EAF, 14216), (0x6EB0, 5285), (0x6EB1, 18963),
+    (0x6EB2, 18954), (0x6EB3, 5286), (0x6EB4, 18974), (0x6EB5, 5287), (0x6EB6, 13645), (0x6EB7, 18972),
+    (0x6EB8, 5288), (0x6EB9, 5289), (0x6EBA, 12896), (0x6EBB, 18971), (0x6EBC, 5290), (0x6EBD, 18970),
+    (0x6EBE, 5291), (0x6EBF, 5292), (0x6EC0, 5293), (0x6EC1, 9687), (0x6EC2, 18977), (0x6EC3, 5294),
+    (0x6EC4, 5295), (0x6EC5, 5296), (0x6EC6, 5297), (0x6EC7, 10040), (0x6EC8, 5298), (0x6EC9, 5299),
+    (0x6ECA, 5300), (0x6ECB, 16479), (0x6ECC, 5301), (0x6ECD, 5302), (0x6ECE, 5303), (0x6ECF, 18975),
+    (0x6ED0, 5304), (0x6ED1, 11127), (0x6ED2, 5305), (0x6ED3, 16485), (0x6ED4, 14392), (0x6ED5, 20323),
+    (0x6ED6, 5306), (0x6ED7, 18973), (0x6ED8, 5307), (0x6ED9, 5308), (0x6EDA, 10821), (0x6EDB, 5309),
+    (0x6EDC, 5310), (0x6EDD, 5311), (0x6EDE, 16290), (0x6EDF, 18962), (0x6EE0, 18965), (0x6EE1, 12535),
+    (0x6EE2, 18967), (0x6EE3, 5312), (0x6EE4, 12488), (0x6EE5, 12101), (0x6EE6, 12493), (0x6EE7, 5313),
+    (0x6EE8, 9300), (0x6EE9, 14363), (0x6EEA, 5314), (0x6EEB, 5315), (0x6EEC, 5316), (0x6EED, 5317),
+    (0x6EEE, 5318), (0x6EEF, 5319), (0x6EF0, 5320), (0x6EF1, 5321), (0x6EF2, 5322), (0x6EF3, 5323),
+    (0x6EF4, 10021), (0x6EF5, 5324), (0x6EF6, 5325), (0x6EF7, 5326), (0x6EF8, 5327), (0x6EF9, 18984),
+    (0x6EFA, 5328), (0x6EFB, 5329), (0x6EFC, 5330), (0x6EFD, 5331), (0x6EFE, 5332), (0x6EFF, 5333),
+    (0x6F00, 5334), (0x6F01, 5335), (0x6F02, 13220), (0x6F03, 5336), (0x6F04, 5337), (0x6F05, 5338),
+    (0x6F06, 13270), (0x6F07, 5339), (0x6F08, 5340), (0x6F09, 18990), (0x6F0A, 5341), (0x6F0B, 5342),
+    (0x6F0C, 5343), (0x6F0D, 5344), (0x6F0E, 5345), (0x6F0F, 12454), (0x6F10, 5346), (0x6F11, 5347),
+    (0x6F12, 5348), (0x6F13, 12141), (0x6F14, 15356), (0x6F15, 18983), (0x6F16, 5349), (0x6F17, 5350),
+    (0x6F18, 5351), (0x6F19, 5352), (0x6F1A, 5353), (0x6F1B, 5354), (0x6F1C, 5355), (0x6F1D, 5356),
+    (0x6F1E, 5357), (0x6F1F, 5358), (0x6F20, 12839), (0x6F21, 5359), (0x6F22, 5360), (0x6F23, 5361),
+    (0x6F24, 18982), (0x6F25, 5362), (0x6F26, 5363), (0x6F27, 5364), (0x6F28, 5365), (0x6F29, 18991),
+    (0x6F2A, 18989), (0x6F2B, 12539), (0x6F2C, 5366), (0x6F2D, 18966), (0x6F2E, 5367), (0x6F2F, 18985),
+    (0x6F30, 5368), (0x6F31, 14059), (0x6F32, 5369), (0x6F33, 16091), (0x6F34, 5370), (0x6F35, 5371),
+    (0x6F36, 18986), (0x6F37, 5372), (0x6F38, 5373), (0x6F39, 5374), (0x6F3A, 5375), (0x6F3B, 5376),
+    (0x6F3C, 5377), (0x6F3D, 5378), (0x6F3E, 15385), (0x6F3F, 5379), (0x6F40, 5380), (0x6F41, 5381),
+    (0x6F42, 5382), (0x6F43, 5383), (0x6F44, 5384), (0x6F45, 5385), (0x6F46, 18980), (0x6F47, 18981),
+    (0x6F48, 5386), (0x6F49, 5387), (0x6F4A, 5388), (0x6F4B, 18987), (0x6F4C, 5389), (0x6F4D, 14736),
+    (0x6F4E, 5390), (0x6F4F, 5391), (0x6F50, 5392), (0x6F51, 5393), (0x6F52, 5394), (0x6F53, 5395),
+    (0x6F54, 5396), (0x6F55, 5397), (0x6F56, 5398), (0x6F57, 5399), (0x6F58, 13058), (0x6F59, 5400),
+    (0x6F5A, 5401), (0x6F5B, 5402), (0x6F5C, 13412), (0x6F5D, 5403), (0x6F5E, 12471), (0x6F5F, 5404),
+    (0x6F60, 5405), (0x6F61, 5406), (0x6F62, 18979), (0x6F63, 5407), (0x6F64, 5408), (0x6F65, 5409),
+    (0x6F66, 12297), (0x6F67, 5410), (0x6F68, 5411), (0x6F69, 5412), (0x6F6A, 5413), (0x6F6B, 5414),
+    (0x6F6C, 5415), (0x6F6D, 14367), (0x6F6E, 9612), (0x6F6F, 5416), (0x6F70, 5417), (0x6F71, 5418),
+    (0x6F72, 18996), (0x6F73, 5419), (0x6F74, 18988), (0x6F75, 5420), (0x6F76, 5421), (0x6F77, 5422),
+    (0x6F78, 18995), (0x6F79, 5423), (0x6F7A, 18998), (0x6F7B, 5424), (0x6F7C, 18997), (0x6F7D, 5425),
+    (0x6F7E, 5426), (0x6F7F, 5427), (0x6F80, 5428), (0x6F81, 5429), (0x6F82, 5430), (0x6F83, 5431),
+    (0x6F84, 9641), (0x6F85, 5432), (0x6F86, 5433), (0x6F87, 5434), (0x6F88, 9621), (0x6F89, 18992),
+    (0x6F8A, 5435), (0x6F8B, 5436), (0x6F8C, 18994), (0x6F8D, 18993), (0x6F8E, 13091), (0x6F8F, 5437),
+    (0x6F90, 5438), (0x6F91, 5439), (0x6F92, 5440), (0x6F93, 5441), (0x6F94, 5442), (0x6F95, 5443),
+    (0x6F96, 5444), (0x6F97, 5445), (0x6F98, 5446), (0x6F99, 5447), (0x6F9A, 5448), (0x6F9B, 5449),
+    (0x6F9C, 12094), (0x6F9D, 5450), (0x6F9E, 5451), (0x6F9F, 5452), (0x6FA0, 5453), (0x6FA1, 15937),
+    (0x6FA2, 5454), (0x6FA3, 5455), (0x6FA4, 5456), (0x6FA5, 5457), (0x6FA6, 5458), (0x6FA7, 19097),
+    (0x6FA8, 5459), (0x6FA9, 5460), (0x6FAA, 5461), (0x6FAB, 5462), (0x6FAC, 5463), (0x6FAD, 5464),
+    (0x6FAE, 5465), (0x6FAF, 5466), (0x6FB0, 5467), (0x6FB1, 5468), (0x6FB2, 5469), (0x6FB3, 9061),
+    (0x6FB4, 5470), (0x6FB5, 5471), (0x6FB6, 19099), (0x6FB7, 5472), (0x6FB8, 5473), (0x6FB9, 19098),
+    (0x6FBA, 5474), (0x6FBB, 5475), (0x6FBC, 5476), (0x6FBD, 5477), (0x6FBE, 5478), (0x6FBF, 5479),
+    (0x6FC0, 11309), (0x6FC1, 5480), (0x6FC2, 19100), (0x6FC3, 5481), (0x6FC4, 5482), (0x6FC5, 5483),
+    (0x6FC6, 5484), (0x6FC7, 5485), (0x6FC8, 5486), (0x6FC9, 19096), (0x6FCA, 5487), (0x6FCB, 5488),
+    (0x6FCC, 5489), (0x6FCD, 5490), (0x6FCE, 5491), (0x6FCF, 5492), (0x6FD0, 5493), (0x6FD1, 18999),
+    (0x6FD2, 9299), (0x6FD3, 5494), (0x6FD4, 5495), (0x6FD5, 5496), (0x6FD6, 5497), (0x6FD7, 5498),
+    (0x6FD8, 5499), (0x6FD9, 5500), (0x6FDA, 5501), (0x6FDB, 5502), (0x6FDC, 5503), (0x6FDD, 5504),
+    (0x6FDE, 19103), (0x6FDF, 5505), (0x6FE0, 19104), (0x6FE1, 19101), (0x6FE2, 5506), (0x6FE3, 5507),
+    (0x6FE4, 5508), (0x6FE5, 5509), (0x6FE6, 5510), (0x6FE7, 5511), (0x6FE8, 5512), (0x6FE9, 5513),
+    (0x6FEA, 5514), (0x6FEB, 5515), (0x6FEC, 5516), (0x6FED, 5517), (0x6FEE, 19102), (0x6FEF, 19105),
+    (0x6FF0, 5518), (0x6FF1, 5519), (0x6FF2, 5520), (0x6FF3, 5521), (0x6FF4, 5522), (0x6FF5, 5523),
+    (0x6FF6, 5524), (0x6FF7, 5525), (0x6FF8, 5526), (0x6FF9, 5527), (0x6FFA, 5528), (0x6FFB, 5529),
+    (0x6FFC, 5530), (0x6FFD, 5531), (0x6FFE, 5532), (0x6FFF, 5533), (0x7000, 5534), (0x7001, 5535),
+    (0x7002, 5536), (0x7003, 5537), (0x7004, 5538), (0x7005, 5539), (0x7006, 5540), (0x7007, 5541),
+    (0x7008, 5542), (0x7009, 5543), (0x700A, 5544), (0x700B, 5545), (0x700C, 5546), (0x700D, 5547),
+    (0x700E, 5548), (0x700F, 5549), (0x7010, 5550), (0x7011, 13262), (0x7012, 5551), (0x7013, 5552),
+    (0x7014, 5553), (0x7015, 5554), (0x7016, 5555), (0x7017, 5556), (0x7018, 5557), (0x7019, 5558),
+    (0x701A, 19106), (0x701B, 19108), (0x701C, 5559), (0x701D, 5560), (0x701E, 5561), (0x701F, 5562),
+    (0x7020, 5563), (0x7021, 5564), (0x7022, 5565), (0x7023, 19107), (0x7024, 5566), (0x7025, 5567),
+    (0x7026, 5568), (0x7027, 5569), (0x7028, 5570), (0x7029, 5571), (0x702A, 5572), (0x702B, 5573),
+    (0x702C, 5574), (0x702D, 5575), (0x702E, 5576), (0x702F, 5577), (0x7030, 5578), (0x7031, 5579),
+    (0x7032, 5580), (0x7033, 5581), (0x7034, 5582), (0x7035, 19110), (0x7036, 5583), (0x7037, 5584),
+    (0x7038, 5585), (0x7039, 19109), (0x703A, 5586), (0x703B, 5587), (0x703C, 5588), (0x703D, 5589),
+    (0x703E, 5590), (0x703F, 5591), (0x7040, 5592), (0x7041, 5593), (0x7042, 5594), (0x7043, 5595),
+    (0x7044, 5596), (0x7045, 5597), (0x7046, 5598), (0x7047, 5599), (0x7048, 5600), (0x7049, 5601),
+    (0x704A, 5602), (0x704B, 5603), (0x704C, 10799), (0x704D, 5604), (0x704E, 5605), (0x704F, 19111),
+    (0x7050, 5606), (0x7051, 5607), (0x7052, 5608), (0x7053, 5609), (0x7054, 5610), (0x7055, 5611),
+    (0x7056, 5612), (0x7057, 5613), (0x7058, 5614), (0x7059, 5615), (0x705A, 5616), (0x705B, 5617),
+    (0x705C, 5618), (0x705D, 5619), (0x705E, 19112), (0x705F, 5620), (0x7060, 5621), (0x7061, 5622),
+    (0x7062, 5623), (0x7063, 5624), (0x7064, 5625), (0x7065, 5626), (0x7066, 5627), (0x7067, 5628),
+    (0x7068, 5629), (0x7069, 5630), (0x706A, 5631), (0x706B, 11195), (0x706C, 20490), (0x706D, 12715),
+    (0x706E, 5632), (0x706F, 10013), (0x7070, 11165), (0x7071, 5633), (0x7072, 5634), (0x7073, 5635),
+    (0x7074, 5636), (0x7075, 12328), (0x7076, 15943), (0x7077, 5637), (0x7078, 11721), (0x7079, 5638),
+    (0x707A, 5639), (0x707B, 5640), (0x707C, 16473), (0x707D, 5641), (0x707E, 15919), (0x707F, 9456),
+    (0x7080, 20455), (0x7081, 5642), (0x7082, 5643), (0x7083, 5644), (0x7084, 5645), (0x7085, 20078),
+    (0x7086, 5646), (0x7087, 5647), (0x7088, 5648), (0x7089, 12460), (0x708A, 9807), (0x708B, 5649),
+    (0x708C, 5650), (0x708D, 5651), (0x708E, 15350), (0x708F, 5652), (0x7090, 5653), (0x7091, 5654),
+    (0x7092, 9615), (0x7093, 5655), (0x7094, 13603), (0x7095, 11902), (0x7096, 20457), (0x7097, 5656),
+    (0x7098, 5657), (0x7099, 16288), (0x709A, 5658), (0x709B, 5659), (0x709C, 20456), (0x709D, 20458),
+    (0x709E, 5660), (0x709F, 5661), (0x70A0, 5662), (0x70A1, 5663), (0x70A2, 5664), (0x70A3, 5665),
+    (0x70A4, 5666), (0x70A5, 5667), (0x70A6, 5668), (0x70A7, 5669), (0x70A8, 5670), (0x70A9, 5671),
+    (0x70AA, 5672), (0x70AB, 20462), (0x70AC, 11755), (0x70AD, 14376), (0x70AE, 13073), (0x70AF, 11713),
+    (0x70B0, 5673), (0x70B1, 20463), (0x70B2, 5674), (0x70B3, 9309), (0x70B4, 5675), (0x70B5, 5676),
+    (0x70B6, 5677), (0x70B7, 20461), (0x70B8, 16063), (0x70B9, 10042), (0x70BA, 5678), (0x70BB, 20459),
+    (0x70BC, 12277), (0x70BD, 9662), (0x70BE, 5679), (0x70BF, 5680), (0x70C0, 20460), (0x70C1, 14179),
+    (0x70C2, 12100), (0x70C3, 14439), (0x70C4, 5681), (0x70C5, 5682), (0x70C6, 5683), (0x70C7, 5684),
+    (0x70C8, 12305), (0x70C9, 5685), (0x70CA, 20465), (0x70CB, 5686), (0x70CC, 5687), (0x70CD, 5688),
+    (0x70CE, 5689), (0x70CF, 5690), (0x70D0, 5691), (0x70D1, 5692), (0x70D2, 5693), (0x70D3, 5694),
+    (0x70D4, 5695), (0x70D5, 5696), (0x70D6, 5697), (0x70D7, 5698), (0x70D8, 10995), (0x70D9, 12116),
+    (0x70DA, 5699), (0x70DB, 16327), (0x70DC, 5700), (0x70DD, 5701), (0x70DE, 5702), (0x70DF, 15339),
+    (0x70E0, 5703), (0x70E1, 5704), (0x70E2, 5705), (0x70E3, 5706), (0x70E4, 11905), (0x70E5, 5707),
+    (0x70E6, 10374), (0x70E7, 13828), (0x70E8, 20464), (0x70E9, 11181), (0x70EA, 5708), (0x70EB, 14389),
+    (0x70EC, 11588), (0x70ED, 13625), (0x70EE, 5709), (0x70EF, 14924), (0x70F0, 5710), (0x70F1, 5711),
+    (0x70F2, 5712), (0x70F3, 5713), (0x70F4, 5714), (0x70F5, 5715), (0x70F6, 5716), (0x70F7, 14608),
+    (0x70F8, 5717), (0x70F9, 13090), (0x70FA, 5718), (0x70FB, 5719), (0x70FC, 5720), (0x70FD, 10428),
+    (0x70FE, 5721), (0x70FF, 5722), (0x7100, 5723), (0x7101, 5724), (0x7102, 5725), (0x7103, 5726),
+    (0x7104, 5727), (0x7105, 5728), (0x7106, 5729), (0x7107, 5730), (0x7108, 5731), (0x7109, 15336),
+    (0x710A, 10949), (0x710B, 5732), (0x710C, 5733), (0x710D, 5734), (0x710E, 5735), (0x710F, 5736),
+    (0x7110, 20466), (0x7111, 5737), (0x7112, 5738), (0x7113, 20467), (0x7114, 5739), (0x7115, 11147),
+    (0x7116, 20468), (0x7117, 5740), (0x7118, 20491), (0x7119, 9241), (0x711A, 10412), (0x711B, 5741),
+    (0x711C, 5742), (0x711D, 5743), (0x711E, 5744), (0x711F, 5745), (0x7120, 5746), (0x7121, 5747),
+    (0x7122, 5748), (0x7123, 5749), (0x7124, 5750), (0x7125, 5751), (0x7126, 11520), (0x7127, 5752),
+    (0x7128, 5753), (0x7129, 5754), (0x712A, 5755), (0x712B, 5756), (0x712C, 5757), (0x712D, 5758),
+    (0x712E, 5759), (0x712F, 20469), (0x7130, 15365), (0x7131, 20470), (0x7132, 5760), (0x7133, 5761),
+    (0x7134, 5762), (0x7135, 5763), (0x7136, 13612), (0x7137, 5764), (0x7138, 5765), (0x7139, 5766),
+    (0x713A, 5767), (0x713B, 5768), (0x713C, 5769), (0x713D, 5770), (0x713E, 5771), (0x713F, 5772),
+    (0x7140, 5773), (0x7141, 5774), (0x7142, 5775), (0x7143, 5776), (0x7144, 5777), (0x7145, 20474),
+    (0x7146, 5778), (0x7147, 5779), (0x7148, 5780), (0x7149, 5781), (0x714A, 20476), (0x714B, 5782),
+    (0x714C, 11160), (0x714D, 5783), (0x714E, 11374), (0x714F, 5784), (0x7150, 5785), (0x7151, 5786),
+    (0x7152, 5787), (0x7153, 5788), (0x7154, 5789), (0x7155, 5790), (0x7156, 5791), (0x7157, 5792),
+    (0x7158, 5793), (0x7159, 5794), (0x715A, 5795), (0x715B, 5796), (0x715C, 20472), (0x715D, 5797),
+    (0x715E, 13798), (0x715F, 5798), (0x7160, 5799), (0x7161, 5800), (0x7162, 5801), (0x7163, 5802),
+    (0x7164, 12661), (0x7165, 5803), (0x7166, 20492), (0x7167, 16108), (0x7168, 20473), (0x7169, 5804),
+    (0x716A, 5805), (0x716B, 5806), (0x716C, 5807), (0x716D, 5808), (0x716E, 16328), (0x716F, 5809),
+    (0x7170, 5810), (0x7171, 5811), (0x7172, 20475), (0x7173, 20471), (0x7174, 5812), (0x7175, 5813),
+    (0x7176, 5814), (0x7177, 5815), (0x7178, 20477), (0x7179, 5816), (0x717A, 20478), (0x717B, 5817),
+    (0x717C, 5818), (0x717D, 13806), (0x717E, 5819), (0x717F, 5820), (0x7180, 5821), (0x7181, 5822),
+    (0x7182, 5823), (0x7183, 5824), (0x7184, 14923), (0x7185, 5825), (0x7186, 5826), (0x7187, 5827),
+    (0x7188, 5828), (0x7189, 5829), (0x718A, 15165), (0x718B, 5830), (0x718C, 5831), (0x718D, 5832),
+    (0x718E, 5833), (0x718F, 15307), (0x7190, 5834), (0x7191, 5835), (0x7192, 5836), (0x7193, 5837),
+    (0x7194, 13644), (0x7195, 5838), (0x7196, 5839), (0x7197, 5840), (0x7198, 20479), (0x7199, 14810),
+    (0x719A, 5841), (0x719B, 5842), (0x719C, 5843), (0x719D, 5844), (0x719E, 5845), (0x719F, 14041),
+    (0x71A0, 20483), (0x71A1, 5846), (0x71A2, 5847), (0x71A3, 5848), (0x71A4, 5849), (0x71A5, 5850),
+    (0x71A6, 5851), (0x71A7, 5852), (0x71A8, 20482), (0x71A9, 5853), (0x71AA, 5854), (0x71AB, 5855),
+    (0x71AC, 9055), (0x71AD, 5856), (0x71AE, 5857), (0x71AF, 5858), (0x71B0, 5859), (0x71B1, 5860),
+    (0x71B2, 5861), (0x71B3, 20480), (0x71B4, 5862), (0x71B5, 20481), (0x71B6, 5863), (0x71B7, 5864),
+    (0x71B8, 5865), (0x71B9, 20493), (0x71BA, 5866), (0x71BB, 5867), (0x71BC, 5868), (0x71BD, 5869),
+    (0x71BE, 5870), (0x71BF, 5871), (0x71C0, 5872), (0x71C1, 5873), (0x71C2, 5874), (0x71C3, 13613),
+    (0x71C4, 5875), (0x71C5, 5876), (0x71C6, 5877), (0x71C7, 5878), (0x71C8, 5879), (0x71C9, 5880),
+    (0x71CA, 5881), (0x71CB, 5882), (0x71CC, 5883), (0x71CD, 5884), (0x71CE, 12294), (0x71CF, 5885),
+    (0x71D0, 5886), (0x71D1, 5887), (0x71D2, 5888), (0x71D3, 5889), (0x71D4, 20485), (0x71D5, 15359),
+    (0x71D6, 5890), (0x71D7, 5891), (0x71D8, 5892), (0x71D9, 5893), (0x71DA, 5894), (0x71DB, 5895),
+    (0x71DC, 5896), (0x71DD, 5897), (0x71DE, 5898), (0x71DF, 5899), (0x71E0, 20484), (0x71E1, 5900),
+    (0x71E2, 5901), (0x71E3, 5902), (0x71E4, 5903), (0x71E5, 15944), (0x71E6, 5904), (0x71E7, 20486),
+    (0x71E8, 5905), (0x71E9, 5906), (0x71EA, 5907), (0x71EB, 5908), (0x71EC, 5909), (0x71ED, 5910),
+    (0x71EE, 17233), (0x71EF, 5911), (0x71F0, 5912), (0x71F1, 5913), (0x71F2, 5914), (0x71F3, 5915),
+    (0x71F4, 5916), (0x71F5, 5917), (0x71F6, 5918), (0x71F7, 5919), (0x71F8, 5920), (0x71F9, 20487),
+    (0x71FA, 5921), (0x71FB, 5922), (0x71FC, 5923), (0x71FD, 5924), (0x71FE, 5925), (0x71FF, 5926),
+    (0x7200, 5927), (0x7201, 5928), (0x7202, 5929), (0x7203, 5930), (0x7204, 5931), (0x7205, 5932),
+    (0x7206, 9227), (0x7207, 5933), (0x7208, 5934), (0x7209, 5935), (0x720A, 5936), (0x720B, 5937),
+    (0x720C, 5938), (0x720D, 5939), (0x720E, 5940), (0x720F, 5941), (0x7210, 5942), (0x7211, 5943),
+    (0x7212, 5944), (0x7213, 5945), (0x7214, 5946), (0x7215, 5947), (0x7216, 5948), (0x7217, 5949),
+    (0x7218, 5950), (0x7219, 5951), (0x721A, 5952), (0x721B, 5953), (0x721C, 5954), (0x721D, 20488),
+    (0x721E, 5955), (0x721F, 5956), (0x7220, 5957), (0x7221, 5958), (0x7222, 5959), (0x7223, 5960),
+    (0x7224, 5961), (0x7225, 5962), (0x7226, 5963), (0x7227, 5964), (0x7228, 20489), (0x7229, 5965),
+    (0x722A, 16441), (0x722B, 5966), (0x722C, 13047), (0x722D, 5967), (0x722E, 5968), (0x722F, 5969),
+    (0x7230, 20263), (0x7231, 9039), (0x7232, 5970), (0x7233, 5971), (0x7234, 5972), (0x7235, 11769),
+    (0x7236, 10569), (0x7237, 15500), (0x7238, 9079), (0x7239, 10064), (0x723A, 5973), (0x723B, 16644),
+    (0x723C, 5974), (0x723D, 14167), (0x723E, 5975), (0x723F, 18776), (0x7240, 5976), (0x7241, 5977),
+    (0x7242, 5978), (0x7243, 5979), (0x7244, 5980), (0x7245, 5981), (0x7246, 5982), (0x7247, 13217),
+    (0x7248, 9095), (0x7249, 5983), (0x724A, 5984), (0x724B, 5985), (0x724C, 13053), (0x724D, 20260),
+    (0x724E, 5986), (0x724F, 5987), (0x7250, 5988), (0x7251, 5989), (0x7252, 20261), (0x7253, 5990),
+    (0x7254, 5991), (0x7255, 5992), (0x7256, 20262), (0x7257, 5993), (0x7258, 5994), (0x7259, 15327),
+    (0x725A, 5995), (0x725B, 13018), (0x725C, 5996), (0x725D, 20127), (0x725E, 5997), (0x725F, 12843),
+    (0x7260, 5998), (0x7261, 12846), (0x7262, 12111), (0x7263, 5999), (0x7264, 6000), (0x7265, 6001),
+    (0x7266, 20128), (0x7267, 12858), (0x7268, 6002), (0x7269, 14804), (0x726A, 6003), (0x726B, 6004),
+    (0x726C, 6005), (0x726D, 6006), (0x726E, 20125), (0x726F, 20129), (0x7270, 6007), (0x7271, 6008),
+    (0x7272, 13867), (0x7273, 6009), (0x7274, 6010), (0x7275, 13398), (0x7276, 6011), (0x7277, 6012),
+    (0x7278, 6013), (0x7279, 14401), (0x727A, 14819), (0x727B, 6014), (0x727C, 6015), (0x727D, 6016),
+    (0x727E, 20130), (0x727F, 20131), (0x7280, 14927), (0x7281, 12136), (0x7282, 6017), (0x7283, 6018),
+    (0x7284, 20132), (0x7285, 6019), (0x7286, 6020), (0x7287, 6021), (0x7288, 6022), (0x7289, 6023),
+    (0x728A, 10196), (0x728B, 20133), (0x728C, 6024), (0x728D, 20134), (0x728E, 6025), (0x728F, 20135),
+    (0x7290, 6026), (0x7291, 6027), (0x7292, 20136), (0x7293, 6028), (0x7294, 6029), (0x7295, 6030),
+    (0x7296, 6031), (0x7297, 6032), (0x7298, 6033), (0x7299, 6034), (0x729A, 6035), (0x729B, 6036),
+    (0x729C, 6037), (0x729D, 6038), (0x729E, 6039), (0x729F, 20126), (0x72A0, 6040), (0x72A1, 6041),
+    (0x72A2, 6042), (0x72A3, 6043), (0x72A4, 6044), (0x72A5, 6045), (0x72A6, 6046), (0x72A7, 6047),
+    (0x72A8, 6048), (0x72A9, 6049), (0x72AA, 6050), (0x72AB, 6051), (0x72AC, 13599), (0x72AD, 18410),
+    (0x72AE, 6052), (0x72AF, 10379), (0x72B0, 18411), (0x72B1, 6053), (0x72B2, 6054), (0x72B3, 6055),
+    (0x72B4, 18412), (0x72B5, 6056), (0x72B6, 16455), (0x72B7, 18413), (0x72B8, 18414), (0x72B9, 15719),
+    (0x72BA, 6057), (0x72BB, 6058), (0x72BC, 6059), (0x72BD, 6060), (0x72BE, 6061), (0x72BF, 6062),
+    (0x72C0, 6063), (0x72C1, 18416), (0x72C2, 11956), (0x72C3, 18415), (0x72C4, 10025), (0x72C5, 6064),
+    (0x72C6, 6065), (0x72C7, 6066), (0x72C8, 9238), (0x72C9, 6067), (0x72CA, 6068), (0x72CB, 6069),
+    (0x72CC, 6070), (0x72CD, 18418), (0x72CE, 18417), (0x72CF, 6071), (0x72D0, 11017), (0x72D1, 6072),
+    (0x72D2, 18419), (0x72D3, 6073), (0x72D4, 6074), (0x72D5, 6075), (0x72D6, 6076), (0x72D7, 10758),
+    (0x72D8, 6077), (0x72D9, 11734), (0x72DA, 6078), (0x72DB, 6079), (0x72DC, 7790), (0x72DD, 7791),
+    (0x72DE, 12917), (0x72DF, 7792), (0x72E0, 10986), (0x72E1, 11533), (0x72E2, 7793), (0x72E3, 7794),
+    (0x72E4, 7795), (0x72E5, 7796), (0x72E6, 7797), (0x72E7, 7798), (0x72E8, 18420), (0x72E9, 18422),
+    (0x72EA, 7799), (0x72EB, 7800), (0x72EC, 10197), (0x72ED, 14948), (0x72EE, 13973), (0x72EF, 18421),
+    (0x72F0, 16143), (0x72F1, 15767), (0x72F2, 18423), (0x72F3, 18427), (0x72F4, 18424), (0x72F5, 7801),
+    (0x72F6, 7802), (0x72F7, 18425), (0x72F8, 12139), (0x72F9, 7803), (0x72FA, 18429), (0x72FB, 18526),
+    (0x72FC, 12104), (0x72FD, 7804), (0x72FE, 7805), (0x72FF, 7806), (0x7300, 7807), (0x7301, 18426),
+    (0x7302, 7808), (0x7303, 18428), (0x7304, 7809), (0x7305, 7810), (0x7306, 7811), (0x7307, 7812),
+    (0x7308, 7813), (0x7309, 7814), (0x730A, 18530), (0x730B, 7815), (0x730C, 7816), (0x730D, 7817),
+    (0x730E, 12307), (0x730F, 7818), (0x7310, 7819), (0x7311, 7820), (0x7312, 7821), (0x7313, 18528),
+    (0x7314, 7822), (0x7315, 18533), (0x7316, 9499), (0x7317, 18527), (0x7318, 7823), (0x7319, 7824),
+    (0x731A, 7825), (0x731B, 12680), (0x731C, 9439), (0x731D, 18532), (0x731E, 18531), (0x731F, 7826),
+    (0x7320, 7827), (0x7321, 18529), (0x7322, 18534), (0x7323, 7828), (0x7324, 7829), (0x7325, 18536),
+    (0x7326, 7830), (0x7327, 7831), (0x7328, 7832), (0x7329, 15146), (0x732A, 16322), (0x732B, 12643),
+    (0x732C, 18537), (0x732D, 7833), (0x732E, 14970), (0x732F, 7834), (0x7330, 7835), (0x7331, 18539),
+    (0x7332, 7836), (0x7333, 7837), (0x7334, 11004), (0x7335, 7838), (0x7336, 7839), (0x7337, 19919),
+    (0x7338, 18538), (0x7339, 18535), (0x733A, 7840), (0x733B, 7841), (0x733C, 7842), (0x733D, 7843),
+    (0x733E, 11126), (0x733F, 15884), (0x7340, 7844), (0x7341, 7845), (0x7342, 7846), (0x7343, 7847),
+    (0x7344, 7848), (0x7345, 7849), (0x7346, 7850), (0x7347, 7851), (0x7348, 7852), (0x7349, 7853),
+    (0x734A, 7854), (0x734B, 7855), (0x734C, 7856), (0x734D, 18541), (0x734E, 7857), (0x734F, 7858),
+    (0x7350, 18540), (0x7351, 7859), (0x7352, 19920), (0x7353, 7860), (0x7354, 7861), (0x7355, 7862),
+    (0x7356, 7863), (0x7357, 18542), (0x7358, 7864), (0x7359, 7865), (0x735A, 7866), (0x735B, 7867),
+    (0x735C, 7868), (0x735D, 7869), (0x735E, 7870), (0x735F, 7871), (0x7360, 18543), (0x7361, 7872),
+    (0x7362, 7873), (0x7363, 7874), (0x7364, 7875), (0x7365, 7876), (0x7366, 7877), (0x7367, 7878),
+    (0x7368, 7879), (0x7369, 7880), (0x736A, 7881), (0x736B, 7882), (0x736C, 18544), (0x736D, 14346),
+    (0x736E, 7883), (0x736F, 18545), (0x7370, 7884), (0x7371, 7885), (0x7372, 7980), (0x7373, 7981),
+    (0x7374, 7982), (0x7375, 7983), (0x7376, 7984), (0x7377, 7985), (0x7378, 7986), (0x7379, 7987),
+    (0x737A, 7988), (0x737B, 7989), (0x737C, 7990), (0x737D, 7991), (0x737E, 18546), (0x737F, 7992),
+    (0x7380, 7993), (0x7381, 7994), (0x7382, 7995), (0x7383, 7996), (0x7384, 15199), (0x7385, 7997),
+    (0x7386, 7998), (0x7387, 12487), (0x7388, 7999), (0x7389, 15756), (0x738A, 8000), (0x738B, 14620),
+    (0x738C, 8001), (0x738D, 8002), (0x738E, 19539), (0x738F, 8003), (0x7390, 8004), (0x7391, 19540),
+    (0x7392, 8005), (0x7393, 8006), (0x7394, 8007), (0x7395, 8008), (0x7396, 11718), (0x7397, 8009),
+    (0x7398, 8010), (0x7399, 8011), (0x739A, 8012), (0x739B, 12519), (0x739C, 8013), (0x739D, 8014),
+    (0x739E, 8015), (0x739F, 19543), (0x73A0, 8016), (0x73A1, 8017), (0x73A2, 19542), (0x73A3, 8018),
+    (0x73A4, 8019), (0x73A5, 8020), (0x73A6, 8021), (0x73A7, 8022), (0x73A8, 8023), (0x73A9, 14605),
+    (0x73AA, 8024), (0x73AB, 12656), (0x73AC, 8025), (0x73AD, 8026), (0x73AE, 19541), (0x73AF, 11138),
+    (0x73B0, 14969), (0x73B1, 8027), (0x73B2, 12320), (0x73B3, 19548), (0x73B4, 8028), (0x73B5, 8029),
+    (0x73B6, 8030), (0x73B7, 19547), (0x73B8, 8031), (0x73B9, 8032), (0x73BA, 19559), (0x73BB, 9408),
+    (0x73BC, 8033), (0x73BD, 8034), (0x73BE, 8035), (0x73BF, 8036), (0x73C0, 19549), (0x73C1, 8037),
+    (0x73C2, 19545), (0x73C3, 8038), (0x73C4, 8039), (0x73C5, 8040), (0x73C6, 8041), (0x73C7, 8042),
+    (0x73C8, 19551), (0x73C9, 19550), (0x73CA, 13801), (0x73CB, 8043), (0x73CC, 8044), (0x73CD, 16123),
+    (0x73CE, 8045), (0x73CF, 19544), (0x73D0, 10364), (0x73D1, 19546), (0x73D2, 8046), (0x73D3, 8047),
+    (0x73D4, 8048), (0x73D5, 8049), (0x73D6, 8050), (0x73D7, 8051), (0x73D8, 8052), (0x73D9, 19553),
+    (0x73DA, 8053), (0x73DB, 8054), (0x73DC, 8055), (0x73DD, 8056), (0x73DE, 19558), (0x73DF, 8057),
+    (0x73E0, 16318), (0x73E1, 8058), (0x73E2, 8059), (0x73E3, 8060), (0x73E4, 8061), (0x73E5, 19552),
+    (0x73E6, 8062), (0x73E7, 19557), (0x73E8, 8063), (0x73E9, 19556), (0x73EA, 8064), (0x73EB, 8065),
+    (0x73EC, 8066), (0x73ED, 9089), (0x73EE, 8067), (0x73EF, 8068), (0x73F0, 8069), (0x73F1, 8070),
+    (0x73F2, 19560), (0x73F3, 8071), (0x73F4, 8072), (0x73F5, 8073), (0x73F6, 8074), (0x73F7, 8075),
+    (0x73F8, 8170), (0x73F9, 8171), (0x73FA, 8172), (0x73FB, 8173), (0x73FC, 8174), (0x73FD, 8175),
+    (0x73FE, 8176), (0x73FF, 8177), (0x7400, 8178), (0x7401, 8179), (0x7402, 8180), (0x7403, 13477),
+    (0x7404, 8181), (0x7405, 12102), (0x7406, 12142), (0x7407, 8182), (0x7408, 8183), (0x7409, 12335),
+    (0x740A, 19555), (0x740B, 8184), (0x740C, 8185), (0x740D, 8186), (0x740E, 8187), (0x740F, 19561),
+    (0x7410, 14241), (0x7411, 8188), (0x7412, 8189), (0x7413, 8190), (0x7414, 8191), (0x7415, 8192),
+    (0x7416, 8193), (0x7417, 8194), (0x7418, 8195), (0x7419, 8196), (0x741A, 19667), (0x741B, 19666),
+    (0x741C, 8197), (0x741D, 8198), (0x741E, 8199), (0x741F, 8200), (0x7420, 8201), (0x7421, 8202),
+    (0x7422, 16468), (0x7423, 8203), (0x7424, 8204), (0x7425, 19565), (0x7426, 19564), (0x7427, 8205),
+    (0x7428, 19566), (0x7429, 8206), (0x742A, 19562), (0x742B, 8207), (0x742C, 19569), (0x742D, 8208),
+    (0x742E, 19568), (0x742F, 8209), (0x7430, 19567), (0x7431, 8210), (0x7432, 8211), (0x7433, 12308),
+    (0x7434, 13452), (0x7435, 13108), (0x7436, 13050), (0x7437, 8212), (0x7438, 8213), (0x7439, 8214),
+    (0x743A, 8215), (0x743B, 8216), (0x743C, 13472), (0x743D, 8217), (0x743E, 8218), (0x743F, 8219),
+    (0x7440, 8220), (0x7441, 19668), (0x7442, 8221), (0x7443, 8222), (0x7444, 8223), (0x7445, 8224),
+    (0x7446, 8225), (0x7447, 8226), (0x7448, 8227), (0x7449, 8228), (0x744A, 8229), (0x744B, 8230),
+    (0x744C, 8231), (0x744D, 8232), (0x744E, 8233), (0x744F, 8234), (0x7450, 8235), (0x7451, 8236),
+    (0x7452, 8237), (0x7453, 8238), (0x7454, 8239), (0x7455, 19671), (0x7456, 8240), (0x7457, 19670),
+    (0x7458, 8241), (0x7459, 19672), (0x745A, 11012), (0x745B, 19563), (0x745C, 19669), (0x745D, 8242),
+    (0x745E, 13665), (0x745F, 13785), (0x7460, 8243), (0x7461, 8244), (0x7462, 8245), (0x7463, 8246),
+    (0x7464, 8247), (0x7465, 8248), (0x7466, 8249), (0x7467, 8250), (0x7468, 8251), (0x7469, 8252),
+    (0x746A, 8253), (0x746B, 8254), (0x746C, 8255), (0x746D, 19674), (0x746E, 8256), (0x746F, 8257),
+    (0x7470, 10804), (0x7471, 8258), (0x7472, 8259), (0x7473, 8260), (0x7474, 8261), (0x7475, 8262),
+    (0x7476, 15389), (0x7477, 19673), (0x7478, 8263), (0x7479, 8264), (0x747A, 8265), (0x747B, 8360),
+    (0x747C, 8361), (0x747D, 8362), (0x747E, 19675), (0x747F, 8363), (0x7480, 19678), (0x7481, 19679),
+    (0x7482, 8364), (0x7483, 12262), (0x7484, 8365), (0x7485, 8366), (0x7486, 8367), (0x7487, 19680),
+    (0x7488, 8368), (0x7489, 8369), (0x748A, 8370), (0x748B, 19681), (0x748C, 8371), (0x748D, 8372),
+    (0x748E, 19677), (0x748F, 8373), (0x7490, 19685), (0x7491, 8374), (0x7492, 8375), (0x7493, 8376),
+    (0x7494, 8377), (0x7495, 8378), (0x7496, 8379), (0x7497, 8380), (0x7498, 8381), (0x7499, 8382),
+    (0x749A, 8383), (0x749B, 8384), (0x749C, 19676), (0x749D, 8385), (0x749E, 19682), (0x749F, 8386),
+    (0x74A0, 8387), (0x74A1, 8388), (0x74A2, 8389), (0x74A3, 8390), (0x74A4, 8391), (0x74A5, 8392),
+    (0x74A6, 8393), (0x74A7, 19686), (0x74A8, 19683), (0x74A9, 19684), (0x74AA, 8394), (0x74AB, 8395),
+    (0x74AC, 8396), (0x74AD, 8397), (0x74AE, 8398), (0x74AF, 8399), (0x74B0, 8400), (0x74B1, 8401),
+    (0x74B2, 8402), (0x74B3, 8403), (0x74B4, 8404), (0x74B5, 8405), (0x74B6, 8406), (0x74B7, 8407),
+    (0x74B8, 8408), (0x74B9, 8409), (0x74BA, 19688), (0x74BB, 8410), (0x74BC, 8411), (0x74BD, 8412),
+    (0x74BE, 8413), (0x74BF, 8414), (0x74C0, 8415), (0x74C1, 8416), (0x74C2, 8417), (0x74C3, 8418),
+    (0x74C4, 8419), (0x74C5, 8420), (0x74C6, 8421), (0x74C7, 8422), (0x74C8, 8423), (0x74C9, 8424),
+    (0x74CA, 8425), (0x74CB, 8426), (0x74CC, 8427), (0x74CD, 8428), (0x74CE, 8429), (0x74CF, 8430),
+    (0x74D0, 8431), (0x74D1, 8432), (0x74D2, 19687), (0x74D3, 8433), (0x74D4, 8434), (0x74D5, 8435),
+    (0x74D6, 8436), (0x74D7, 8437), (0x74D8, 8438), (0x74D9, 8439), (0x74DA, 8440), (0x74DB, 8441),
+    (0x74DC, 10782), (0x74DD, 8442), (0x74DE, 21197), (0x74DF, 8443), (0x74E0, 21198), (0x74E1, 8444),
+    (0x74E2, 13221), (0x74E3, 9099), (0x74E4, 13616), (0x74E5, 8445), (0x74E6, 14598), (0x74E7, 8446),
+    (0x74E8, 8447), (0x74E9, 8448), (0x74EA, 8449), (0x74EB, 8450), (0x74EC, 8451), (0x74ED, 8452),
+    (0x74EE, 14770), (0x74EF, 20062), (0x74F0, 8453), (0x74F1, 8454), (0x74F2, 8455), (0x74F3, 8550),
+    (0x74F4, 20063), (0x74F5, 8551), (0x74F6, 13236), (0x74F7, 9826), (0x74F8, 8552), (0x74F9, 8553),
+    (0x74FA, 8554), (0x74FB, 8555), (0x74FC, 8556), (0x74FD, 8557), (0x74FE, 8558), (0x74FF, 20064),
+    (0x7500, 8559), (0x7501, 8560), (0x7502, 8561), (0x7503, 8562), (0x7504, 16126), (0x7505, 8563),
+    (0x7506, 8564), (0x7507, 8565), (0x7508, 8566), (0x7509, 8567), (0x750A, 8568), (0x750B, 8569),
+    (0x750C, 8570), (0x750D, 17664), (0x750E, 8571), (0x750F, 20065), (0x7510, 8572), (0x7511, 20066),
+    (0x7512, 8573), (0x7513, 20067), (0x7514, 8574), (0x7515, 8575), (0x7516, 8576), (0x7517, 8577),
+    (0x7518, 10587), (0x7519, 17971), (0x751A, 13860), (0x751B, 8578), (0x751C, 14425), (0x751D, 8579),
+    (0x751E, 8580), (0x751F, 13865), (0x7520, 8581), (0x7521, 8582), (0x7522, 8583), (0x7523, 8584),
+    (0x7524, 8585), (0x7525, 13866), (0x7526, 8586), (0x7527, 8587), (0x7528, 15710), (0x7529, 14161),
+    (0x752A, 8588), (0x752B, 10551), (0x752C, 21199), (0x752D, 9249), (0x752E, 8589), (0x752F, 19119),
+    (0x7530, 14424), (0x7531, 15716), (0x7532, 11360), (0x7533, 13849), (0x7534, 8590), (0x7535, 10046),
+    (0x7536, 8591), (0x7537, 12873), (0x7538, 10048), (0x7539, 8592), (0x753A, 20819), (0x753B, 11128),
+    (0x753C, 8593), (0x753D, 8594), (0x753E, 19537), (0x753F, 8595), (0x7540, 20820), (0x7541, 8596),
+    (0x7542, 8597), (0x7543, 8598), (0x7544, 8599), (0x7545, 9604), (0x7546, 8600), (0x7547, 8601),
+    (0x7548, 20823), (0x7549, 8602), (0x754A, 8603), (0x754B, 20822), (0x754C, 11566), (0x754D, 8604),
+    (0x754E, 20821), (0x754F, 14748), (0x7550, 8605), (0x7551, 8606), (0x7552, 8607), (0x7553, 8608),
+    (0x7554, 13062), (0x7555, 8609), (0x7556, 8610), (0x7557, 8611), (0x7558, 8612), (0x7559, 12339),
+    (0x755A, 17241), (0x755B, 20824), (0x755C, 15188), (0x755D, 8613), (0x755E, 8614), (0x755F, 8615),
+    (0x7560, 8616), (0x7561, 8617), (0x7562, 8618), (0x7563, 8619), (0x7564, 8620), (0x7565, 12497),
+    (0x7566, 13277), (0x7567, 8621), (0x7568, 8622), (0x7569, 8623), (0x756A, 10367), (0x756B, 8624),
+    (0x756C, 8625), (0x756D, 8626), (0x756E, 8627), (0x756F, 8628), (0x7570, 8629), (0x7571, 8630),
+    (0x7572, 20825), (0x7573, 8631), (0x7574, 9670), (0x7575, 8632), (0x7576, 8633), (0x7577, 8634),
+    (0x7578, 11206), (0x7579, 20826), (0x757A, 8635), (0x757B, 8636), (0x757C, 8637), (0x757D, 8638),
+    (0x757E, 8639), (0x757F, 19535), (0x7580, 8640), (0x7581, 8641), (0x7582, 8642), (0x7583, 20827),
+    (0x7584, 8643), (0x7585, 8644), (0x7586, 11509), (0x7587, 8645), (0x7588, 8740), (0x7589, 8741),
+    (0x758A, 8742), (0x758B, 21441), (0x758C, 8743), (0x758D, 8744), (0x758E, 8745), (0x758F, 14037),
+    (0x7590, 8746), (0x7591, 15526), (0x7592, 21243), (0x7593, 8747), (0x7594, 21244), (0x7595, 8748),
+    (0x7596, 21245), (0x7597, 12293), (0x7598, 8749), (0x7599, 10622), (0x759A, 11731), (0x759B, 8750),
+    (0x759C, 8751), (0x759D, 21247), (0x759E, 8752), (0x759F, 13032), (0x75A0, 21246), (0x75A1, 15376),
+    (0x75A2, 8753), (0x75A3, 21249), (0x75A4, 9069), (0x75A5, 11569), (0x75A6, 8754), (0x75A7, 8755),
+    (0x75A8, 8756), (0x75A9, 8757), (0x75AA, 8758), (0x75AB, 15548), (0x75AC, 21248), (0x75AD, 8759),
+    (0x75AE, 9800), (0x75AF, 10427), (0x75B0, 21255), (0x75B1, 21254), (0x75B2, 13208), (0x75B3, 21250),
+    (0x75B4, 21251), (0x75B5, 9820), (0x75B6, 8760), (0x75B7, 8761), (0x75B8, 21252), (0x75B9, 16133),
+    (0x75BA, 8762), (0x75BB, 8763), (0x75BC, 14404), (0x75BD, 11735), (0x75BE, 11323), (0x75BF, 8764),
+    (0x75C0, 8765), (0x75C1, 8766), (0x75C2, 21257), (0x75C3, 21256), (0x75C4, 21253), (0x75C5, 9406),
+    (0x75C6, 8767), (0x75C7, 16247), (0x75C8, 15699), (0x75C9, 11708), (0x75CA, 13597), (0x75CB, 8768),
+    (0x75CC, 8769), (0x75CD, 21259), (0x75CE, 8770), (0x75CF, 8771), (0x75D0, 8772), (0x75D1, 8773),
+    (0x75D2, 15382), (0x75D3, 8774), (0x75D4, 16289), (0x75D5, 10984), (0x75D6, 21258), (0x75D7, 8775),
+    (0x75D8, 10192), (0x75D9, 8776), (0x75DA, 8777), (0x75DB, 14555), (0x75DC, 8778), (0x75DD, 8779),
+    (0x75DE, 13211), (0x75DF, 8780), (0x75E0, 8781), (0x75E1, 8782), (0x75E2, 12256), (0x75E3, 21260),
+    (0x75E4, 21263), (0x75E5, 8783), (0x75E6, 21262), (0x75E7, 21265), (0x75E8, 21261), (0x75E9, 8784),
+    (0x75EA, 11145), (0x75EB, 21264), (0x75EC, 8785), (0x75ED, 8786), (0x75EE, 8787), (0x75EF, 8788),
+    (0x75F0, 14366), (0x75F1, 21267), (0x75F2, 8789), (0x75F3, 8790), (0x75F4, 9648), (0x75F5, 8791),
+    (0x75F6, 8792), (0x75F7, 8793), (0x75F8, 8794), (0x75F9, 9267), (0x75FA, 8795), (0x75FB, 8796),
+    (0x75FC, 21268), (0x75FD, 8797), (0x75FE, 8798), (0x75FF, 21269), (0x7600, 21271), (0x7601, 9850),
+    (0x7602, 8799), (0x7603, 21266), (0x7604, 8800), (0x7605, 21272), (0x7606, 8801), (0x7607, 8802),
+    (0x7608, 8803), (0x7609, 8804), (0x760A, 21275), (0x760B, 8805), (0x760C, 21273), (0x760D, 8806),
+    (0x760E, 8807), (0x760F, 8808), (0x7610, 21270), (0x7611, 8809), (0x7612, 8810), (0x7613, 8811),
+    (0x7614, 8812), (0x7615, 21278), (0x7616, 8813), (0x7617, 21274), (0x7618, 21277), (0x7619, 21279),
+    (0x761A, 8814), (0x761B, 21376), (0x761C, 8815), (0x761D, 8816), (0x761E, 8817), (0x761F, 14758),
+    (0x7620, 21379), (0x7621, 8818), (0x7622, 21378), (0x7623, 8819), (0x7624, 12341), (0x7625, 21276),
+    (0x7626, 14026), (0x7627, 8820), (0x7628, 8821), (0x7629, 9866), (0x762A, 9296), (0x762B, 14362),
+    (0x762C, 8822), (0x762D, 21381), (0x762E, 8823), (0x762F, 8824), (0x7630, 21382), (0x7631, 8825),
+    (0x7632, 8826), (0x7633, 21387), (0x7634, 16101), (0x7635, 21384), (0x7636, 8827), (0x7637, 8828),
+    (0x7638, 13604), (0x7639, 8829), (0x763A, 8830), (0x763B, 8831), (0x763C, 21377), (0x763D, 8832),
+    (0x763E, 21386), (0x763F, 21383), (0x7640, 21380), (0x7641, 8833), (0x7642, 8834), (0x7643, 21385),
+    (0x7644, 8835), (0x7645, 8930), (0x7646, 8931), (0x7647, 8932), (0x7648, 8933), (0x7649, 8934),
+    (0x764A, 8935), (0x764B, 8936), (0x764C, 9034), (0x764D, 21388), (0x764E, 8937), (0x764F, 8938),
+    (0x7650, 8939), (0x7651, 8940), (0x7652, 8941), (0x7653, 8942), (0x7654, 21390), (0x7655, 8943),
+    (0x7656, 21392), (0x7657, 8944), (0x7658, 8945), (0x7659, 8946), (0x765A, 8947), (0x765B, 8948),
+    (0x765C, 21391), (0x765D, 8949), (0x765E, 21389), (0x765F, 8950), (0x7660, 8951), (0x7661, 8952),
+    (0x7662, 8953), (0x7663, 15297), (0x7664, 8954), (0x7665, 8955), (0x7666, 8956), (0x7667, 8957),
+    (0x7668, 8958), (0x7669, 8959), (0x766A, 8960), (0x766B, 21393), (0x766C, 8961), (0x766D, 8962),
+    (0x766E, 8963), (0x766F, 21394), (0x7670, 8964), (0x7671, 8965), (0x7672, 8966), (0x7673, 8967),
+    (0x7674, 8968), (0x7675, 8969), (0x7676, 8970), (0x7677, 8971), (0x7678, 10814), (0x7679, 8972),
+    (0x767A, 8973), (0x767B, 10014), (0x767C, 8974), (0x767D, 9080), (0x767E, 9082), (0x767F, 8975),
+    (0x7680, 8976), (0x7681, 8977), (0x7682, 15942), (0x7683, 8978), (0x7684, 10011), (0x7685, 8979),
+    (0x7686, 11547), (0x7687, 11157), (0x7688, 21192), (0x7689, 8980), (0x768A, 8981), (0x768B, 10607),
+    (0x768C, 8982), (0x768D, 8983), (0x768E, 21193), (0x768F, 8984), (0x7690, 8985), (0x7691, 9033),
+    (0x7692, 8986), (0x7693, 21194), (0x7694, 8987), (0x7695, 8988), (0x7696, 14613), (0x7697, 8989),
+    (0x7698, 8990), (0x7699, 21195), (0x769A, 8991), (0x769B, 8992), (0x769C, 8993), (0x769D, 8994),
+    (0x769E, 8995), (0x769F, 8996), (0x76A0, 8997), (0x76A1, 8998), (0x76A2, 8999), (0x76A3, 9000),
+    (0x76A4, 21196), (0x76A5, 9001), (0x76A6, 9002), (0x76A7, 9003), (0x76A8, 9004), (0x76A9, 9005),
+    (0x76AA, 9006), (0x76AB, 9007), (0x76AC, 9008), (0x76AD, 9009), (0x76AE, 13209), (0x76AF, 9010),
+    (0x76B0, 9011), (0x76B1, 16314), (0x76B2, 21443), (0x76B3, 9012), (0x76B4, 21444), (0x76B5, 9013),
+    (0x76B6, 9014), (0x76B7, 9015), (0x76B8, 9016), (0x76B9, 9017), (0x76BA, 9018), (0x76BB, 9019),
+    (0x76BC, 9020), (0x76BD, 9021), (0x76BE, 9022), (0x76BF, 12718), (0x76C0, 9023), (0x76C1, 9024),
+    (0x76C2, 15734), (0x76C3, 9025), (0x76C4, 9120), (0x76C5, 16294), (0x76C6, 13087), (0x76C7, 9121),
+    (0x76C8, 15690), (0x76C9, 9122), (0x76CA, 15555), (0x76CB, 9123), (0x76CC, 9124), (0x76CD, 20838),
+    (0x76CE, 9052), (0x76CF, 16076), (0x76D0, 15341), (0x76D1, 11369), (0x76D2, 10973), (0x76D3, 9125),
+    (0x76D4, 11963), (0x76D5, 9126), (0x76D6, 10584), (0x76D7, 10008), (0x76D8, 13059), (0x76D9, 9127),
+    (0x76DA, 9128), (0x76DB, 13967), (0x76DC, 9129), (0x76DD, 9130), (0x76DE, 9131), (0x76DF, 12678),
+    (0x76E0, 9132), (0x76E1, 9133), (0x76E2, 9134), (0x76E3, 9135), (0x76E4, 9136), (0x76E5, 20839),
+    (0x76E6, 9137), (0x76E7, 9138), (0x76E8, 9139), (0x76E9, 9140), (0x76EA, 9141), (0x76EB, 9142),
+    (0x76EC, 9143), (0x76ED, 9144), (0x76EE, 12856), (0x76EF, 10167), (0x76F0, 9145), (0x76F1, 20691),
+    (0x76F2, 12639), (0x76F3, 9146), (0x76F4, 16262), (0x76F5, 9147), (0x76F6, 9148), (0x76F7, 9149),
+    (0x76F8, 14979), (0x76F9, 20694), (0x76FA, 9150), (0x76FB, 9151), (0x76FC, 13061), (0x76FD, 9152),
+    (0x76FE, 10225), (0x76FF, 9153), (0x7700, 9154), (0x7701, 13966), (0x7702, 9155), (0x7703, 9156),
+    (0x7704, 20692), (0x7705, 9157), (0x7706, 9158), (0x7707, 20695), (0x7708, 20696), (0x7709, 12663),
+    (0x770A, 9159), (0x770B, 11895), (0x770C, 9160), (0x770D, 20693), (0x770E, 9161), (0x770F, 9162),
+    (0x7710, 9163), (0x7711, 9164), (0x7712, 9165), (0x7713, 9166), (0x7714, 9167), (0x7715, 9168),
+    (0x7716, 9169), (0x7717, 9170), (0x7718, 9171), (0x7719, 20699), (0x771A, 20697), (0x771B, 9172),
+    (0x771C, 9173), (0x771D, 9174), (0x771E, 9175), (0x771F, 16125), (0x7720, 12698), (0x7721, 9176),
+    (0x7722, 20698), (0x7723, 9177), (0x7724, 9178), (0x7725, 9179), (0x7726, 20701), (0x7727, 9180),
+    (0x7728, 16058), (0x7729, 15298), (0x772A, 9181), (0x772B, 9182), (0x772C, 9183), (0x772D, 20700),
+    (0x772E, 9184), (0x772F, 12683), (0x7730, 9185), (0x7731, 9186), (0x7732, 9187), (0x7733, 9188),
+    (0x7734, 9189), (0x7735, 20702), (0x7736, 11959), (0x7737, 11761), (0x7738, 20703), (0x7739, 9190),
+    (0x773A, 14432), (0x773B, 9191), (0x773C, 15354), (0x773D, 9192), (0x773E, 9193), (0x773F, 9194),
+    (0x7740, 16472), (0x7741, 16141), (0x7742, 9195), (0x7743, 20707), (0x7744, 9196), (0x7745, 9197),
+    (0x7746, 9198), (0x7747, 20706), (0x7748, 9199), (0x7749, 9200), (0x774A, 9201), (0x774B, 9202),
+    (0x774C, 9203), (0x774D, 9204), (0x774E, 9205), (0x774F, 9206), (0x7750, 20704), (0x7751, 20705),
+    (0x7752, 9207), (0x7753, 9208), (0x7754, 9209), (0x7755, 9210), (0x7756, 9211), (0x7757, 9212),
+    (0x7758, 9213), (0x7759, 9214), (0x775A, 20708), (0x775B, 11691), (0x775C, 9215), (0x775D, 9310),
+    (0x775E, 9311), (0x775F, 9312), (0x7760, 9313), (0x7761, 14170), (0x7762, 20806), (0x7763, 10194),
+    (0x7764, 9314), (0x7765, 20807), (0x7766, 12857), (0x7767, 9315), (0x7768, 20709), (0x7769, 9316),
+    (0x776A, 9317), (0x776B, 11557), (0x776C, 9444), (0x776D, 9318), (0x776E, 9319), (0x776F, 9320),
+    (0x7770, 9321), (0x7771, 9322), (0x7772, 9323), (0x7773, 9324), (0x7774, 9325), (0x7775, 9326),
+    (0x7776, 9327), (0x7777, 9328), (0x7778, 9329), (0x7779, 10200), (0x777A, 9330), (0x777B, 9331),
+    (0x777C, 9332), (0x777D, 20810), (0x777E, 16651), (0x777F, 20808), (0x7780, 20811), (0x7781, 9333),
+    (0x7782, 9334), (0x7783, 9335), (0x7784, 12708), (0x7785, 9677), (0x7786, 9336), (0x7787, 9337),
+    (0x7788, 9338), (0x7789, 9339), (0x778A, 9340), (0x778B, 9341), (0x778C, 20812), (0x778D, 20809),
+    (0x778E, 14940), (0x778F, 9342), (0x7790, 9343), (0x7791, 20813), (0x7792, 12532), (0x7793, 9344),
+    (0x7794, 9345), (0x7795, 9346), (0x7796, 9347), (0x7797, 9348), (0x7798, 9349), (0x7799, 9350),
+    (0x779A, 9351), (0x779B, 9352), (0x779C, 9353), (0x779D, 9354), (0x779E, 9355), (0x779F, 20814),
+    (0x77A0, 20815), (0x77A1, 9356), (0x77A2, 17776), (0x77A3, 9357), (0x77A4, 9358), (0x77A5, 13224),
+    (0x77A6, 9359), (0x77A7, 13433), (0x77A8, 9360), (0x77A9, 16330), (0x77AA, 10016), (0x77AB, 9361),
+    (0x77AC, 14173), (0x77AD, 9362), (0x77AE, 9363), (0x77AF, 9364), (0x77B0, 20816), (0x77B1, 9365),
+    (0x77B2, 9366), (0x77B3, 14546), (0x77B4, 9367), (0x77B5, 20817), (0x77B6, 9368), (0x77B7, 9369),
+    (0x77B8, 9370), (0x77B9, 9371), (0x77BA, 9372), (0x77BB, 16071), (0x77BC, 9373), (0x77BD, 20818),
+    (0x77BE, 9374), (0x77BF, 22361), (0x77C0, 9375), (0x77C1, 9376), (0x77C2, 9377), (0x77C3, 9378),
+    (0x77C4, 9379), (0x77C5, 9380), (0x77C6, 9381), (0x77C7, 9382), (0x77C8, 9383), (0x77C9, 9384),
+    (0x77CA, 9385), (0x77CB, 9386), (0x77CC, 9387), (0x77CD, 17234), (0x77CE, 9388), (0x77CF, 9389),
+    (0x77D0, 9390), (0x77D1, 9391), (0x77D2, 9392), (0x77D3, 9393), (0x77D4, 9394), (0x77D5, 9395),
+    (0x77D6, 9396), (0x77D7, 9788), (0x77D8, 9397), (0x77D9, 9398), (0x77DA, 9399), (0x77DB, 12647),
+    (0x77DC, 21445), (0x77DD, 9400), (0x77DE, 9401), (0x77DF, 9402), (0x77E0, 9403), (0x77E1, 9404),
+    (0x77E2, 13989), (0x77E3, 15536), (0x77E4, 9405), (0x77E5, 16255), (0x77E6, 9500), (0x77E7, 21077),
+    (0x77E8, 9501), (0x77E9, 11741), (0x77EA, 9502), (0x77EB, 11530), (0x77EC, 21078), (0x77ED, 10209),
+    (0x77EE, 9036), (0x77EF, 9503), (0x77F0, 9504), (0x77F1, 9505), (0x77F2, 9506), (0x77F3, 13980),
+    (0x77F4, 9507), (0x77F5, 9508), (0x77F6, 20637), (0x77F7, 9509), (0x77F8, 20638), (0x77F9, 9510),
+    (0x77FA, 9511), (0x77FB, 9512), (0x77FC, 9513), (0x77FD, 14814), (0x77FE, 10370), (0x77FF, 11958),
+    (0x7800, 20639), (0x7801, 12520), (0x7802, 13791), (0x7803, 9514), (0x7804, 9515), (0x7805, 9516),
+    (0x7806, 9517), (0x7807, 9518), (0x7808, 9519), (0x7809, 20640), (0x780A, 9520), (0x780B, 9521),
+    (0x780C, 13291), (0x780D, 11894), (0x780E, 9522), (0x780F, 9523), (0x7810, 9524), (0x7811, 20643),
+    (0x7812, 13103), (0x7813, 9525), (0x7814, 15343), (0x7815, 9526), (0x7816, 16444), (0x7817, 20641),
+    (0x7818, 20642), (0x7819, 9527), (0x781A, 15361), (0x781B, 9528), (0x781C, 20646), (0x781D, 20647),
+    (0x781E, 9529), (0x781F, 20651), (0x7820, 9530), (0x7821, 9531), (0x7822, 9532), (0x7823, 20655),
+    (0x7824, 9533), (0x7825, 20653), (0x7826, 20661), (0x7827, 16127), (0x7828, 9534), (0x7829, 20656),
+    (0x782A, 9535), (0x782B, 9536), (0x782C, 20654), (0x782D, 20645), (0x782E, 9537), (0x782F, 9538),
+    (0x7830, 13088), (0x7831, 9539), (0x7832, 9540), (0x7833, 9541), (0x7834, 13243), (0x7835, 9542),
+    (0x7836, 9543), (0x7837, 13848), (0x7838, 15915), (0x7839, 20648), (0x783A, 20649), (0x783B, 20650),
+    (0x783C, 20652), (0x783D, 9544), (0x783E, 12154), (0x783F, 9545), (0x7840, 9786), (0x7841, 9546),
+    (0x7842, 9547), (0x7843, 9548), (0x7844, 9549), (0x7845, 10807), (0x7846, 9550), (0x7847, 20663),
+    (0x7848, 9551), (0x7849, 9552), (0x784A, 9553), (0x784B, 9554), (0x784C, 20664), (0x784D, 9555),
+    (0x784E, 20657), (0x784F, 9556), (0x7850, 20662), (0x7851, 9557), (0x7852, 14813), (0x7853, 9558),
+    (0x7854, 9559), (0x7855, 14177), (0x7856, 20659), (0x7857, 20660), (0x7858, 9560), (0x7859, 9561),
+    (0x785A, 9562), (0x785B, 9563), (0x785C, 9564), (0x785D, 15000), (0x785E, 9565), (0x785F, 9566),
+    (0x7860, 9567), (0x7861, 9568), (0x7862, 9569), (0x7863, 9570), (0x7864, 9571), (0x7865, 9572),
+    (0x7866, 9573), (0x7867, 9574), (0x7868, 9575), (0x7869, 9576), (0x786A, 20665), (0x786B, 12337),
+    (0x786C, 15693), (0x786D, 20658), (0x786E, 13608), (0x786F, 9577), (0x7870, 9578), (0x7871, 9579),
+    (0x7872, 9580), (0x7873, 9581), (0x7874, 9582), (0x7875, 9583), (0x7876, 9584), (0x7877, 11384),
+    (0x7878, 9585), (0x7879, 9586), (0x787A, 9587), (0x787B, 9588), (0x787C, 13095), (0x787D, 9589),
+    (0x787E, 9590), (0x787F, 9591), (0x7880, 9592), (0x7881, 9593), (0x7882, 9594), (0x7883, 9595),
+    (0x7884, 9690), (0x7885, 9691), (0x7886, 9692), (0x7887, 20669), (0x7888, 9693), (0x7889, 10054),
+    (0x788A, 9694), (0x788B, 9695), (0x788C, 12466), (0x788D, 9038), (0x788E, 14228), (0x788F, 9696),
+    (0x7890, 9697), (0x7891, 9229), (0x7892, 9698), (0x7893, 20667), (0x7894, 9699), (0x7895, 9700),
+    (0x7896, 9701), (0x7897, 14610), (0x7898, 10041), (0x7899, 9702), (0x789A, 20668), (0x789B, 20666),
+    (0x789C, 20670), (0x789D, 9703), (0x789E, 9704), (0x789F, 10065), (0x78A0, 9705), (0x78A1, 20671),
+    (0x78A2, 9706), (0x78A3, 20672), (0x78A4, 9707), (0x78A5, 20675), (0x78A6, 9708), (0x78A7, 9259),
+    (0x78A8, 9709), (0x78A9, 9710), (0x78AA, 9711), (0x78AB, 9712), (0x78AC, 9713), (0x78AD, 9714),
+    (0x78AE, 9715), (0x78AF, 9716), (0x78B0, 13101), (0x78B1, 11383), (0x78B2, 20673), (0x78B3, 14373),
+    (0x78B4, 9479), (0x78B5, 9717), (0x78B6, 9718), (0x78B7, 9719), (0x78B8, 9720), (0x78B9, 20674),
+    (0x78BA, 9721), (0x78BB, 9722), (0x78BC, 9723), (0x78BD, 9724), (0x78BE, 12900), (0x78BF, 9725),
+    (0x78C0, 9726), (0x78C1, 9822), (0x78C2, 9727), (0x78C3, 9728), (0x78C4, 9729), (0x78C5, 9110),
+    (0x78C6, 9730), (0x78C7, 9731), (0x78C8, 9732), (0x78C9, 20678), (0x78CA, 12123), (0x78CB, 9857),
+    (0x78CC, 9733), (0x78CD, 9734), (0x78CE, 9735), (0x78CF, 9736), (0x78D0, 13060), (0x78D1, 9737),
+    (0x78D2, 9738), (0x78D3, 9739), (0x78D4, 20676), (0x78D5, 11911), (0x78D6, 9740), (0x78D7, 9741),
+    (0x78D8, 9742), (0x78D9, 20677), (0x78DA, 9743), (0x78DB, 9744), (0x78DC, 9745), (0x78DD, 9746),
+    (0x78DE, 9747), (0x78DF, 9748), (0x78E0, 9749), (0x78E1, 9750), (0x78E2, 9751), (0x78E3, 9752),
+    (0x78E4, 9753), (0x78E5, 9754), (0x78E6, 9755), (0x78E7, 9756), (0x78E8, 12830), (0x78E9, 9757),
+    (0x78EA, 9758), (0x78EB, 9759), (0x78EC, 20679), (0x78ED, 9760), (0x78EE, 9761), (0x78EF, 9762),
+    (0x78F0, 9763), (0x78F1, 9764), (0x78F2, 20680), (0x78F3, 9765), (0x78F4, 20682), (0x78F5, 9766),
+    (0x78F6, 9767), (0x78F7, 12310), (0x78F8, 9768), (0x78F9, 9769), (0x78FA, 11154), (0x78FB, 9770),
+    (0x78FC, 9771), (0x78FD, 9772), (0x78FE, 9773), (0x78FF, 9774), (0x7900, 9775), (0x7901, 11519),
+    (0x7902, 9776), (0x7903, 9777), (0x7904, 9778), (0x7905, 20681), (0x7906, 9779), (0x7907, 9780),
+    (0x7908, 9781), (0x7909, 9782), (0x790A, 9783), (0x790B, 9784), (0x790C, 9785), (0x790D, 9880),
+    (0x790E, 9881), (0x790F, 9882), (0x7910, 9883), (0x7911, 9884), (0x7912, 9885), (0x7913, 20683),
+    (0x7914, 9886), (0x7915, 9887), (0x7916, 9888), (0x7917, 9889), (0x7918, 9890), (0x7919, 9891),
+    (0x791A, 9892), (0x791B, 9893), (0x791C, 9894), (0x791D, 9895), (0x791E, 20685), (0x791F, 9896),
+    (0x7920, 9897), (0x7921, 9898), (0x7922, 9899), (0x7923, 9900), (0x7924, 20684), (0x7925, 9901),
+    (0x7926, 9902), (0x7927, 9903), (0x7928, 9904), (0x7929, 9905), (0x792A, 9906), (0x792B, 9907),
+    (0x792C, 9908), (0x792D, 9909), (0x792E, 9910), (0x792F, 9911), (0x7930, 9912), (0x7931, 9913),
+    (0x7932, 9914), (0x7933, 9915), (0x7934, 20686), (0x7935, 9916), (0x7936, 9917), (0x7937, 9918),
+    (0x7938, 9919), (0x7939, 9920), (0x793A, 13995), (0x793B, 20499), (0x793C, 12146), (0x793D, 9921),
+    (0x793E, 13846), (0x793F, 9922), (0x7940, 20500), (0x7941, 13283), (0x7942, 9923), (0x7943, 9924),
+    (0x7944, 9925), (0x7945, 9926), (0x7946, 20501), (0x7947, 9927), (0x7948, 13282), (0x7949, 20502),
+    (0x794A, 9928), (0x794B, 9929), (0x794C, 9930), (0x794D, 9931), (0x794E, 9932), (0x794F, 9933),
+    (0x7950, 9934), (0x7951, 9935), (0x7952, 9936), (0x7953, 20505), (0x7954, 9937), (0x7955, 9938),
+    (0x7956, 16505), (0x7957, 20508), (0x7958, 9939), (0x7959, 9940), (0x795A, 20506), (0x795B, 20503),
+    (0x795C, 20504), (0x795D, 16438), (0x795E, 13856), (0x795F, 14233), (0x7960, 20509), (0x7961, 9941),
+    (0x7962, 20507), (0x7963, 9942), (0x7964, 9943), (0x7965, 14988), (0x7966, 9944), (0x7967, 20511),
+    (0x7968, 13222), (0x7969, 9945), (0x796A, 9946), (0x796B, 9947), (0x796C, 9948), (0x796D, 11337),
+    (0x796E, 9949), (0x796F, 20510), (0x7970, 9950), (0x7971, 9951), (0x7972, 9952), (0x7973, 9953),
+    (0x7974, 9954), (0x7975, 9955), (0x7976, 9956), (0x7977, 10002), (0x7978, 11201), (0x7979, 9957),
+    (0x797A, 20512), (0x797B, 9958), (0x797C, 9959), (0x797D, 9960), (0x797E, 9961), (0x797F, 9962),
+    (0x7980, 16902), (0x7981, 11586), (0x7982, 9963), (0x7983, 9964), (0x7984, 12472), (0x7985, 20513),
+    (0x7986, 9965), (0x7987, 9966), (0x7988, 9967), (0x7989, 9968), (0x798A, 20514), (0x798B, 9969),
+    (0x798C, 9970), (0x798D, 9971), (0x798E, 9972), (0x798F, 10548), (0x7990, 9973), (0x7991, 9974),
+    (0x7992, 9975), (0x7993, 10070), (0x7994, 10071), (0x7995, 10072), (0x7996, 10073), (0x7997, 10074),
+    (0x7998, 10075), (0x7999, 10076), (0x799A, 20515), (0x799B, 10077), (0x799C, 10078), (0x799D, 10079),
+    (0x799E, 10080), (0x799F, 10081), (0x79A0, 10082), (0x79A1, 10083), (0x79A2, 10084), (0x79A3, 10085),
+    (0x79A4, 10086), (0x79A5, 10087), (0x79A6, 10088), (0x79A7, 20516), (0x79A8, 10089), (0x79A9, 10090),
+    (0x79AA, 10091), (0x79AB, 10092), (0x79AC, 10093), (0x79AD, 10094), (0x79AE, 10095), (0x79AF, 10096),
+    (0x79B0, 10097), (0x79B1, 10098), (0x79B2, 10099), (0x79B3, 20517), (0x79B4, 10100), (0x79B5, 10101),
+    (0x79B6, 10102), (0x79B7, 10103), (0x79B8, 10104), (0x79B9, 15752), (0x79BA, 16639), (0x79BB, 12140),
+    (0x79BC, 10105), (0x79BD, 13456), (0x79BE, 10969), (0x79BF, 10106), (0x79C0, 15172), (0x79C1, 14184),
+    (0x79C2, 10107), (0x79C3, 14561), (0x79C4, 10108), (0x79C5, 10109), (0x79C6, 10594), (0x79C7, 10110),
+    (0x79C8, 10111), (0x79C9, 9307), (0x79CA, 10112), (0x79CB, 13474), (0x79CC, 10113), (0x79CD, 16299),
+    (0x79CE, 10114), (0x79CF, 10115), (0x79D0, 10116), (0x79D1, 11913), (0x79D2, 12710), (0x79D3, 10117),
+    (0x79D4, 10118), (0x79D5, 21080), (0x79D6, 10119), (0x79D7, 10120), (0x79D8, 12691), (0x79D9, 10121),
+    (0x79DA, 10122), (0x79DB, 10123), (0x79DC, 10124), (0x79DD, 10125), (0x79DE, 10126), (0x79DF, 16501),
+    (0x79E0, 10127), (0x79E1, 10128), (0x79E2, 10129), (0x79E3, 21082), (0x79E4, 9646), (0x79E5, 10130),
+    (0x79E6, 13451), (0x79E7, 15372), (0x79E8, 10131), (0x79E9, 16285), (0x79EA, 10132), (0x79EB, 21083),
+    (0x79EC, 10133), (0x79ED, 21081), (0x79EE, 10134), (0x79EF, 11208), (0x79F0, 9633), (0x79F1, 10135),
+    (0x79F2, 10136), (0x79F3, 10137), (0x79F4, 10138), (0x79F5, 10139), (0x79F6, 10140), (0x79F7, 10141),
+    (0x79F8, 11548), (0x79F9, 10142), (0x79FA, 10143), (0x79FB, 15523), (0x79FC, 10144), (0x79FD, 11179),
+    (0x79FE, 10145), (0x79FF, 10146), (0x7A00, 14916), (0x7A01, 10147), (0x7A02, 21087), (0x7A03, 21086),
+    (0x7A04, 10148), (0x7A05, 10149), (0x7A06, 21084), (0x7A07, 10150), (0x7A08, 10151), (0x7A09, 10152),
+    (0x7A0A, 10153), (0x7A0B, 9639), (0x7A0C, 10154), (0x7A0D, 13827), (0x7A0E, 14171), (0x7A0F, 10155),
+    (0x7A10, 10156), (0x7A11, 10157), (0x7A12, 10158), (0x7A13, 10159), (0x7A14, 21089), (0x7A15, 10160),
+    (0x7A16, 10161), (0x7A17, 9087), (0x7A18, 10162), (0x7A19, 10163), (0x7A1A, 16286), (0x7A1B, 10164),
+    (0x7A1C, 10165), (0x7A1D, 10260), (0x7A1E, 21088), (0x7A1F, 10261), (0x7A20, 9672), (0x7A21, 10262),
+    (0x7A22, 10263), (0x7A23, 22378), (0x7A24, 10264), (0x7A25, 10265), (0x7A26, 10266), (0x7A27, 10267),
+    (0x7A28, 10268), (0x7A29, 10269), (0x7A2A, 10270), (0x7A2B, 10271), (0x7A2C, 10272), (0x7A2D, 10273),
+    (0x7A2E, 10274), (0x7A2F, 10275), (0x7A30, 10276), (0x7A31, 10277), (0x7A32, 10278), (0x7A33, 14765),
+    (0x7A34, 10279), (0x7A35, 10280), (0x7A36, 10281), (0x7A37, 21187), (0x7A38, 10282), (0x7A39, 21186),
+    (0x7A3A, 10283), (0x7A3B, 10005), (0x7A3C, 11363), (0x7A3D, 11207), (0x7A3E, 10284), (0x7A3F, 10614),
+    (0x7A40, 10285), (0x7A41, 10286), (0x7A42, 10287), (0x7A43, 10288), (0x7A44, 10289), (0x7A45, 10290),
+    (0x7A46, 12859), (0x7A47, 10291), (0x7A48, 10292), (0x7A49, 10293), (0x7A4A, 10294), (0x7A4B, 10295),
+    (0x7A4C, 10296), (0x7A4D, 10297), (0x7A4E, 10298), (0x7A4F, 10299), (0x7A50, 10300), (0x7A51, 21188),
+    (0x7A52, 10301), (0x7A53, 10302), (0x7A54, 10303), (0x7A55, 10304), (0x7A56, 10305), (0x7A57, 14230),
+    (0x7A58, 10306), (0x7A59, 10307), (0x7A5A, 10308), (0x7A5B, 10309), (0x7A5C, 10310), (0x7A5D, 10311),
+    (0x7A5E, 10312), (0x7A5F, 10313), (0x7A60, 10314), (0x7A61, 10315), (0x7A62, 10316), (0x7A63, 10317),
+    (0x7A64, 10318), (0x7A65, 10319), (0x7A66, 10320), (0x7A67, 10321), (0x7A68, 10322), (0x7A69, 10323),
+    (0x7A6A, 10324), (0x7A6B, 10325), (0x7A6C, 10326), (0x7A6D, 10327), (0x7A6E, 10328), (0x7A6F, 10329),
+    (0x7A70, 21191), (0x7A71, 10330), (0x7A72, 10331), (0x7A73, 10332), (0x7A74, 15303), (0x7A75, 10333),
+    (0x7A76, 11716), (0x7A77, 13473), (0x7A78, 21397), (0x7A79, 21398), (0x7A7A, 11928), (0x7A7B, 10334),
+    (0x7A7C, 10335), (0x7A7D, 10336), (0x7A7E, 10337), (0x7A7F, 9794), (0x7A80, 21399), (0x7A81, 14562),
+    (0x7A82, 10338), (0x7A83, 13447), (0x7A84, 16068), (0x7A85, 10339), (0x7A86, 21400), (0x7A87, 10340),
+    (0x7A88, 21401), (0x7A89, 10341), (0x7A8A, 10342), (0x7A8B, 10343), (0x7A8C, 10344), (0x7A8D, 13442),
+    (0x7A8E, 10345), (0x7A8F, 10346), (0x7A90, 10347), (0x7A91, 15489), (0x7A92, 16292), (0x7A93, 10348),
+    (0x7A94, 10349), (0x7A95, 21402), (0x7A96, 11544), (0x7A97, 9801), (0x7A98, 11714), (0x7A99, 10350),
+    (0x7A9A, 10351), (0x7A9B, 10352), (0x7A9C, 9845), (0x7A9D, 14774), (0x7A9E, 10353), (0x7A9F, 11938),
+    (0x7AA0, 21404), (0x7AA1, 10354), (0x7AA2, 10355), (0x7AA3, 10450), (0x7AA4, 10451), (0x7AA5, 11965),
+    (0x7AA6, 21403), (0x7AA7, 10452), (0x7AA8, 21406), (0x7AA9, 10453), (0x7AAA, 10454), (0x7AAB, 10455),
+    (0x7AAC, 21405), (0x7AAD, 21407), (0x7AAE, 10456), (0x7AAF, 10457), (0x7AB0, 10458), (0x7AB1, 10459),
+    (0x7AB2, 10460), (0x7AB3, 21408), (0x7AB4, 10461), (0x7AB5, 10462), (0x7AB6, 10463), (0x7AB7, 10464),
+    (0x7AB8, 10465), (0x7AB9, 10466), (0x7ABA, 10467), (0x7ABB, 10468), (0x7ABC, 10469), (0x7ABD, 10470),
+    (0x7ABE, 10471), (0x7ABF, 12349), (0x7AC0, 10472), (0x7AC1, 10473), (0x7AC2, 10474), (0x7AC3, 10475),
+    (0x7AC4, 10476), (0x7AC5, 10477), (0x7AC6, 10478), (0x7AC7, 10479), (0x7AC8, 10480), (0x7AC9, 10481),
+    (0x7ACA, 10482), (0x7ACB, 12257), (0x7ACC, 10483), (0x7ACD, 10484), (0x7ACE, 10485), (0x7ACF, 10486),
+    (0x7AD0, 10487), (0x7AD1, 10488), (0x7AD2, 10489), (0x7AD3, 10490), (0x7AD4, 10491), (0x7AD5, 10492),
+    (0x7AD6, 14055), (0x7AD7, 10493), (0x7AD8, 10494), (0x7AD9, 16085), (0x7ADA, 10495), (0x7ADB, 10496),
+    (0x7ADC, 10497), (0x7ADD, 10498), (0x7ADE, 11711), (0x7ADF, 11710), (0x7AE0, 16089), (0x7AE1, 10499),
+    (0x7AE2, 10500), (0x7AE3, 11877), (0x7AE4, 10501), (0x7AE5, 14550), (0x7AE6, 21396), (0x7AE7, 10502),
+    (0x7AE8, 10503), (0x7AE9, 10504), (0x7AEA, 10505), (0x7AEB, 10506), (0x7AEC, 10507), (0x7AED, 11558),
+    (0x7AEE, 10508), (0x7AEF, 10208), (0x7AF0, 10509), (0x7AF1, 10510), (0x7AF2, 10511), (0x7AF3, 10512),
+    (0x7AF4, 10513), (0x7AF5, 10514), (0x7AF6, 10515), (0x7AF7, 10516), (0x7AF8, 10517), (0x7AF9, 16326),
+    (0x7AFA, 21790), (0x7AFB, 10518), (0x7AFC, 10519), (0x7AFD, 21791), (0x7AFE, 10520), (0x7AFF, 10590),
+    (0x7B00, 10521), (0x7B01, 10522), (0x7B02, 10523), (0x7B03, 21793), (0x7B04, 21794), (0x7B05, 10524),
+    (0x7B06, 9067), (0x7B07, 10525), (0x7B08, 21792), (0x7B09, 10526), (0x7B0A, 21796), (0x7B0B, 14236),
+    (0x7B0C, 10527), (0x7B0D, 10528), (0x7B0E, 10529), (0x7B0F, 21798), (0x7B10, 10530), (0x7B11, 15111),
+    (0x7B12, 10531), (0x7B13, 10532), (0x7B14, 9257), (0x7B15, 21795), (0x7B16, 10533), (0x7B17, 10534),
+    (0x7B18, 10535), (0x7B19, 21802), (0x7B1A, 10536), (0x7B1B, 10024), (0x7B1C, 10537), (0x7B1D, 10538),
+    (0x7B1E, 21810), (0x7B1F, 10539), (0x7B20, 21805), (0x7B21, 10540), (0x7B22, 10541), (0x7B23, 10542),
+    (0x7B24, 21807), (0x7B25, 21806), (0x7B26, 10446), (0x7B27, 10543), (0x7B28, 9246), (0x7B29, 10544),
+    (0x7B2A, 21801), (0x7B2B, 21797), (0x7B2C, 10033), (0x7B2D, 10545), (0x7B2E, 21803), (0x7B2F, 10640),
+    (0x7B30, 10641), (0x7B31, 21804), (0x7B32, 10642), (0x7B33, 21808), (0x7B34, 10643), (0x7B35, 10644),
+    (0x7B36, 10645), (0x7B37, 10646), (0x7B38, 21800), (0x7B39, 10647), (0x7B3A, 11372), (0x7B3B, 10648),
+    (0x7B3C, 12348), (0x7B3D, 10649), (0x7B3E, 21809), (0x7B3F, 10650), (0x7B40, 10651), (0x7B41, 10652),
+    (0x7B42, 10653), (0x7B43, 10654), (0x7B44, 10655), (0x7B45, 21813), (0x7B46, 10656), (0x7B47, 21799),
+    (0x7B48, 10657), (0x7B49, 10015), (0x7B4A, 10658), (0x7B4B, 11573), (0x7B4C, 21815), (0x7B4D, 10659),
+    (0x7B4E, 10660), (0x7B4F, 10359), (0x7B50, 11955), (0x7B51, 16339), (0x7B52, 14553), (0x7B53, 10661),
+    (0x7B54, 9865), (0x7B55, 10662), (0x7B56, 9468), (0x7B57, 10663), (0x7B58, 21811), (0x7B59, 10664),
+    (0x7B5A, 21812), (0x7B5B, 13799), (0x7B5C, 10665), (0x7B5D, 21816), (0x7B5E, 10666), (0x7B5F, 10667),
+    (0x7B60, 21817), (0x7B61, 10668), (0x7B62, 21820), (0x7B63, 10669), (0x7B64, 10670), (0x7B65, 10671),
+    (0x7B66, 10672), (0x7B67, 10673), (0x7B68, 10674), (0x7B69, 10675), (0x7B6A, 10676), (0x7B6B, 10677),
+    (0x7B6C, 10678), (0x7B6D, 10679), (0x7B6E, 21818), (0x7B6F, 10680), (0x7B70, 10681), (0x7B71, 21822),
+    (0x7B72, 21821), (0x7B73, 10682), (0x7B74, 10683), (0x7B75, 21814), (0x7B76, 10684), (0x7B77, 11949),
+    (0x7B78, 10685), (0x7B79, 9674), (0x7B7A, 10686), (0x7B7B, 21819), (0x7B7C, 10687), (0x7B7D, 10688),
+    (0x7B7E, 13404), (0x7B7F, 10689), (0x7B80, 11387), (0x7B81, 10690), (0x7B82, 10691), (0x7B83, 10692),
+    (0x7B84, 10693), (0x7B85, 21830), (0x7B86, 10694), (0x7B87, 10695), (0x7B88, 10696), (0x7B89, 10697),
+    (0x7B8A, 10698), (0x7B8B, 10699), (0x7B8C, 10700), (0x7B8D, 10766), (0x7B8E, 10701), (0x7B8F, 10702),
+    (0x7B90, 21823), (0x7B91, 10703), (0x7B92, 10704), (0x7B93, 10705), (0x7B94, 9418), (0x7B95, 11209),
+    (0x7B96, 10706), (0x7B97, 14222), (0x7B98, 10707), (0x7B99, 10708), (0x7B9A, 10709), (0x7B9B, 10710),
+    (0x7B9C, 21832), (0x7B9D, 21828), (0x7B9E, 10711), (0x7B9F, 10712), (0x7BA0, 10713), (0x7BA1, 10795),
+    (0x7BA2, 21833), (0x7BA3, 10714), (0x7BA4, 10715), (0x7BA5, 10716), (0x7BA6, 21824), (0x7BA7, 21825),
+    (0x7BA8, 21829), (0x7BA9, 12510), (0x7BAA, 21831), (0x7BAB, 21834), (0x7BAC, 21827), (0x7BAD, 11398),
+    (0x7BAE, 10717), (0x7BAF, 10718), (0x7BB0, 10719), (0x7BB1, 14983), (0x7BB2, 10720), (0x7BB3, 10721),
+    (0x7BB4, 21835), (0x7BB5, 10722), (0x7BB6, 10723), (0x7BB7, 10724), (0x7BB8, 21826), (0x7BB9, 10725),
+    (0x7BBA, 10726), (0x7BBB, 10727), (0x7BBC, 10728), (0x7BBD, 10729), (0x7BBE, 10730), (0x7BBF, 10731),
+    (0x7BC0, 10732), (0x7BC1, 21837), (0x7BC2, 10733), (0x7BC3, 10734), (0x7BC4, 10735), (0x7BC5, 10830),
+    (0x7BC6, 16448), (0x7BC7, 13215), (0x7BC8, 10831), (0x7BC9, 10832), (0x7BCA, 10833), (0x7BCB, 10834),
+    (0x7BCC, 21838), (0x7BCD, 10835), (0x7BCE, 10836), (0x7BCF, 10837), (0x7BD0, 10838), (0x7BD1, 21836),
+    (0x7BD2, 10839), (0x7BD3, 12453), (0x7BD4, 10840), (0x7BD5, 10841), (0x7BD6, 10842), (0x7BD7, 10843),
+    (0x7BD8, 10844), (0x7BD9, 10606), (0x7BDA, 21840), (0x7BDB, 10845), (0x7BDC, 10846), (0x7BDD, 21839),
+    (0x7BDE, 10847), (0x7BDF, 10848), (0x7BE0, 10849), (0x7BE1, 9844), (0x7BE2, 10850), (0x7BE3, 10851),
+    (0x7BE4, 10852), (0x7BE5, 21841), (0x7BE6, 21842), (0x7BE7, 10853), (0x7BE8, 10854), (0x7BE9, 10855),
+    (0x7BEA, 21843), (0x7BEB, 10856), (0x7BEC, 10857), (0x7BED, 10858), (0x7BEE, 12091), (0x7BEF, 10859),
+    (0x7BF0, 10860), (0x7BF1, 12138), (0x7BF2, 10861), (0x7BF3, 10862), (0x7BF4, 10863), (0x7BF5, 10864),
+    (0x7BF6, 10865), (0x7BF7, 13096), (0x7BF8, 10866), (0x7BF9, 10867), (0x7BFA, 10868), (0x7BFB, 10869),
+    (0x7BFC, 21846), (0x7BFD, 10870), (0x7BFE, 21845), (0x7BFF, 10871), (0x7C00, 10872), (0x7C01, 10873),
+    (0x7C02, 10874), (0x7C03, 10875), (0x7C04, 10876), (0x7C05, 10877), (0x7C06, 10878), (0x7C07, 9841),
+    (0x7C08, 10879), (0x7C09, 10880), (0x7C0A, 10881), (0x7C0B, 21849), (0x7C0C, 21844), (0x7C0D, 10882),
+    (0x7C0E, 10883), (0x7C0F, 21847), (0x7C10, 10884), (0x7C11, 10885), (0x7C12, 10886), (0x7C13, 10887),
+    (0x7C14, 10888), (0x7C15, 10889), (0x7C16, 21848), (0x7C17, 10890), (0x7C18, 10891), (0x7C19, 10892),
+    (0x7C1A, 10893), (0x7C1B, 10894), (0x7C1C, 10895), (0x7C1D, 10896), (0x7C1E, 10897), (0x7C1F, 21946),
+    (0x7C20, 10898), (0x7C21, 10899), (0x7C22, 10900), (0x7C23, 10901), (0x7C24, 10902), (0x7C25, 10903),
+    (0x7C26, 21948), (0x7C27, 11156), (0x7C28, 10904), (0x7C29, 10905), (0x7C2A, 21947), (0x7C2B, 10906),
+    (0x7C2C, 10907), (0x7C2D, 10908), (0x7C2E, 10909), (0x7C2F, 10910), (0x7C30, 10911), (0x7C31, 10912),
+    (0x7C32, 10913), (0x7C33, 10914), (0x7C34, 10915), (0x7C35, 10916), (0x7C36, 10917), (0x7C37, 10918),
+    (0x7C38, 21949), (0x7C39, 10919), (0x7C3A, 10920), (0x7C3B, 10921), (0x7C3C, 10922), (0x7C3D, 10923),
+    (0x7C3E, 10924), (0x7C3F, 9435), (0x7C40, 21951), (0x7C41, 21950), (0x7C42, 10925), (0x7C43, 11020),
+    (0x7C44, 11021), (0x7C45, 11022), (0x7C46, 11023), (0x7C47, 11024), (0x7C48, 11025), (0x7C49, 11026),
+    (0x7C4A, 11027), (0x7C4B, 11028), (0x7C4C, 11029), (0x7C4D, 11319), (0x7C4E, 11030), (0x7C4F, 11031),
+    (0x7C50, 11032), (0x7C51, 11033), (0x7C52, 11034), (0x7C53, 11035), (0x7C54, 11036), (0x7C55, 11037),
+    (0x7C56, 11038), (0x7C57, 11039), (0x7C58, 11040), (0x7C59, 11041), (0x7C5A, 11042), (0x7C5B, 11043),
+    (0x7C5C, 11044), (0x7C5D, 11045), (0x7C5E, 11046), (0x7C5F, 11047), (0x7C60, 11048), (0x7C61, 11049),
+    (0x7C62, 11050), (0x7C63, 11051), (0x7C64, 11052), (0x7C65, 11053), (0x7C66, 11054), (0x7C67, 11055),
+    (0x7C68, 11056), (0x7C69, 11057), (0x7C6A, 11058), (0x7C6B, 11059), (0x7C6C, 11060), (0x7C6D, 11061),
+    (0x7C6E, 11062), (0x7C6F, 11063), (0x7C70, 11064), (0x7C71, 11065), (0x7C72, 11066), (0x7C73, 12690),
+    (0x7C74, 16880), (0x7C75, 11067), (0x7C76, 11068), (0x7C77, 11069), (0x7C78, 11070), (0x7C79, 11071),
+    (0x7C7A, 11072), (0x7C7B, 12129), (0x7C7C, 21989), (0x7C7D, 16484), (0x7C7E, 11073), (0x7C7F, 11074),
+    (0x7C80, 11075), (0x7C81, 11076), (0x7C82, 11077), (0x7C83, 11078), (0x7C84, 11079), (0x7C85, 11080),
+    (0x7C86, 11081), (0x7C87, 11082), (0x7C88, 11083), (0x7C89, 10414), (0x7C8A, 11084), (0x7C8B, 11085),
+    (0x7C8C, 11086), (0x7C8D, 11087), (0x7C8E, 11088), (0x7C8F, 11089), (0x7C90, 11090), (0x7C91, 21991),
+    (0x7C92, 12258), (0x7C93, 11091), (0x7C94, 11092), (0x7C95, 13246), (0x7C96, 11093), (0x7C97, 9839),
+    (0x7C98, 16074), (0x7C99, 11094), (0x7C9A, 11095), (0x7C9B, 11096), (0x7C9C, 21993), (0x7C9D, 21992),
+    (0x7C9E, 21994), (0x7C9F, 14213), (0x7CA0, 11097), (0x7CA1, 11098), (0x7CA2, 21995), (0x7CA3, 11099),
+    (0x7CA4, 15898), (0x7CA5, 16309), (0x7CA6, 11100), (0x7CA7, 11101), (0x7CA8, 11102), (0x7CA9, 11103),
+    (0x7CAA, 10419), (0x7CAB, 11104), (0x7CAC, 11105), (0x7CAD, 11106), (0x7CAE, 12279), (0x7CAF, 11107),
+    (0x7CB0, 11108), (0x7CB1, 12282), (0x7CB2, 21996), (0x7CB3, 11697), (0x7CB4, 11109), (0x7CB5, 11110),
+    (0x7CB6, 11111), (0x7CB7, 11112), (0x7CB8, 11113), (0x7CB9, 9851), (0x7CBA, 11114), (0x7CBB, 11115),
+    (0x7CBC, 21997), (0x7CBD, 21998), (0x7CBE, 11696), (0x7CBF, 11210), (0x7CC0, 11211), (0x7CC1, 21999),
+    (0x7CC2, 11212), (0x7CC3, 11213), (0x7CC4, 11214), (0x7CC5, 22004), (0x7CC6, 11215), (0x7CC7, 22000),
+    (0x7CC8, 22003), (0x7CC9, 11216), (0x7CCA, 11018), (0x7CCB, 11217), (0x7CCC, 22001), (0x7CCD, 22002),
+    (0x7CCE, 11218), (0x7CCF, 11219), (0x7CD0, 11220), (0x7CD1, 11221), (0x7CD2, 11222), (0x7CD3, 11223),
+    (0x7CD4, 11224), (0x7CD5, 10611), (0x7CD6, 14384), (0x7CD7, 22005), (0x7CD8, 11225), (0x7CD9, 9463),
+    (0x7CDA, 11226), (0x7CDB, 11227), (0x7CDC, 12686), (0x7CDD, 11228), (0x7CDE, 11229), (0x7CDF, 15932),
+    (0x7CE0, 11898), (0x7CE1, 11230), (0x7CE2, 11231), (0x7CE3, 11232), (0x7CE4, 11233), (0x7CE5, 11234),
+    (0x7CE6, 11235), (0x7CE7, 11236), (0x7CE8, 22006), (0x7CE9, 11237), (0x7CEA, 11238), (0x7CEB, 11239),
+    (0x7CEC, 11240), (0x7CED, 11241), (0x7CEE, 11242), (0x7CEF, 13035), (0x7CF0, 11243), (0x7CF1, 11244),
+    (0x7CF2, 11245), (0x7CF3, 11246), (0x7CF4, 11247), (0x7CF5, 11248), (0x7CF6, 11249), (0x7CF7, 11250),
+    (0x7CF8, 22018), (0x7CF9, 11251), (0x7CFA, 11252), (0x7CFB, 14936), (0x7CFC, 11253), (0x7CFD, 11254),
+    (0x7CFE, 11255), (0x7CFF, 11256), (0x7D00, 11257), (0x7D01, 11258), (0x7D02, 11259), (0x7D03, 11260),
+    (0x7D04, 11261), (0x7D05, 11262), (0x7D06, 11263), (0x7D07, 11264), (0x7D08, 11265), (0x7D09, 11266),
+    (0x7D0A, 14766), (0x7D0B, 11267), (0x7D0C, 11268), (0x7D0D, 11269), (0x7D0E, 11270), (0x7D0F, 11271),
+    (0x7D10, 11272), (0x7D11, 11273), (0x7D12, 11274), (0x7D13, 11275), (0x7D14, 11276), (0x7D15, 11277),
+    (0x7D16, 11278), (0x7D17, 11279), (0x7D18, 11280), (0x7D19, 11281), (0x7D1A, 11282), (0x7D1B, 11283),
+    (0x7D1C, 11284), (0x7D1D, 11285), (0x7D1E, 11286), (0x7D1F, 11287), (0x7D20, 14211), (0x7D21, 11288),
+    (0x7D22, 14242), (0x7D23, 11289), (0x7D24, 11290), (0x7D25, 11291), (0x7D26, 11292), (0x7D27, 11579),
+    (0x7D28, 11293), (0x7D29, 11294), (0x7D2A, 11295), (0x7D2B, 16482), (0x7D2C, 11296), (0x7D2D, 11297),
+    (0x7D2E, 11298), (0x7D2F, 12124), (0x7D30, 11299), (0x7D31, 11300), (0x7D32, 11301), (0x7D33, 11302),
+    (0x7D34, 11303), (0x7D35, 11304), (0x7D36, 11305), (0x7D37, 11400), (0x7D38, 11401), (0x7D39, 11402),
+    (0x7D3A, 11403), (0x7D3B, 11404), (0x7D3C, 11405), (0x7D3D, 11406), (0x7D3E, 11407), (0x7D3F, 11408),
+    (0x7D40, 11409), (0x7D41, 11410), (0x7D42, 11411), (0x7D43, 11412), (0x7D44, 11413), (0x7D45, 11414),
+    (0x7D46, 11415), (0x7D47, 11416), (0x7D48, 11417), (0x7D49, 11418), (0x7D4A, 11419), (0x7D4B, 11420),
+    (0x7D4C, 11421), (0x7D4D, 11422), (0x7D4E, 11423), (0x7D4F, 11424), (0x7D50, 11425), (0x7D51, 11426),
+    (0x7D52, 11427), (0x7D53, 11428), (0x7D54, 11429), (0x7D55, 11430), (0x7D56, 11431), (0x7D57, 11432),
+    (0x7D58, 11433), (0x7D59, 11434), (0x7D5A, 11435), (0x7D5B, 11436), (0x7D5C, 11437), (0x7D5D, 11438),
+    (0x7D5E, 11439), (0x7D5F, 11440), (0x7D60, 11441), (0x7D61, 11442), (0x7D62, 11443), (0x7D63, 11444),
+    (0x7D64, 11445), (0x7D65, 11446), (0x7D66, 11447), (0x7D67, 11448), (0x7D68, 11449), (0x7D69, 11450),
+    (0x7D6A, 11451), (0x7D6B, 11452), (0x7D6C, 11453), (0x7D6D, 11454), (0x7D6E, 15190), (0x7D6F, 11455),
+    (0x7D70, 11456), (0x7D71, 11457), (0x7D72, 11458), (0x7D73, 11459), (0x7D74, 11460), (0x7D75, 11461),
+    (0x7D76, 11462), (0x7D77, 22019), (0x7D78, 11463), (0x7D79, 11464), (0x7D7A, 11465), (0x7D7B, 11466),
+    (0x7D7C, 11467), (0x7D7D, 11468), (0x7D7E, 11469), (0x7D7F, 11470), (0x7D80, 11471), (0x7D81, 11472),
+    (0x7D82, 11473), (0x7D83, 11474), (0x7D84, 11475), (0x7D85, 11476), (0x7D86, 11477), (0x7D87, 11478),
+    (0x7D88, 11479), (0x7D89, 11480), (0x7D8A, 11481), (0x7D8B, 11482), (0x7D8C, 11483), (0x7D8D, 11484),
+    (0x7D8E, 11485), (0x7D8F, 11486), (0x7D90, 11487), (0x7D91, 11488), (0x7D92, 11489), (0x7D93, 11490),
+    (0x7D94, 11491), (0x7D95, 11492), (0x7D96, 11493), (0x7D97, 11494), (0x7D98, 11495), (0x7D99, 11590),
+    (0x7D9A, 11591), (0x7D9B, 11592), (0x7D9C, 11593), (0x7D9D, 11594), (0x7D9E, 11595), (0x7D9F, 11596),
+    (0x7DA0, 11597), (0x7DA1, 11598), (0x7DA2, 11599), (0x7DA3, 11600), (0x7DA4, 11601), (0x7DA5, 11602),
+    (0x7DA6, 22020), (0x7DA7, 11603), (0x7DA8, 11604), (0x7DA9, 11605), (0x7DAA, 11606), (0x7DAB, 11607),
+    (0x7DAC, 11608), (0x7DAD, 11609), (0x7DAE, 22021), (0x7DAF, 11610), (0x7DB0, 11611), (0x7DB1, 11612),
+    (0x7DB2, 11613), (0x7DB3, 11614), (0x7DB4, 11615), (0x7DB5, 11616), (0x7DB6, 11617), (0x7DB7, 11618),
+    (0x7DB8, 11619), (0x7DB9, 11620), (0x7DBA, 11621), (0x7DBB, 11622), (0x7DBC, 11623), (0x7DBD, 11624),
+    (0x7DBE, 11625), (0x7DBF, 11626), (0x7DC0, 11627), (0x7DC1, 11628), (0x7DC2, 11629), (0x7DC3, 11630),
+    (0x7DC4, 11631), (0x7DC5, 11632), (0x7DC6, 11633), (0x7DC7, 11634), (0x7DC8, 11635), (0x7DC9, 11636),
+    (0x7DCA, 11637), (0x7DCB, 11638), (0x7DCC, 11639), (0x7DCD, 11640), (0x7DCE, 11641), (0x7DCF, 11642),
+    (0x7DD0, 11643), (0x7DD1, 11644), (0x7DD2, 11645), (0x7DD3, 11646), (0x7DD4, 11647), (0x7DD5, 11648),
+    (0x7DD6, 11649), (0x7DD7, 11650), (0x7DD8, 11651), (0x7DD9, 11652), (0x7DDA, 11653), (0x7DDB, 11654),
+    (0x7DDC, 11655), (0x7DDD, 11656), (0x7DDE, 11657), (0x7DDF, 11658), (0x7DE0, 11659), (0x7DE1, 11660),
+    (0x7DE2, 11661), (0x7DE3, 11662), (0x7DE4, 11663), (0x7DE5, 11664), (0x7DE6, 11665), (0x7DE7, 11666),
+    (0x7DE8, 11667), (0x7DE9, 11668), (0x7DEA, 11669), (0x7DEB, 11670), (0x7DEC, 11671), (0x7DED, 11672),
+    (0x7DEE, 11673), (0x7DEF, 11674), (0x7DF0, 11675), (0x7DF1, 11676), (0x7DF2, 11677), (0x7DF3, 11678),
+    (0x7DF4, 11679), (0x7DF5, 11680), (0x7DF6, 11681), (0x7DF7, 11682), (0x7DF8, 11683), (0x7DF9, 11684),
+    (0x7DFA, 11685), (0x7DFB, 11780), (0x7DFC, 11781), (0x7DFD, 11782), (0x7DFE, 11783), (0x7DFF, 11784),
+    (0x7E00, 11785), (0x7E01, 11786), (0x7E02, 11787), (0x7E03, 11788), (0x7E04, 11789), (0x7E05, 11790),
+    (0x7E06, 11791), (0x7E07, 11792), (0x7E08, 11793), (0x7E09, 11794), (0x7E0A, 11795), (0x7E0B, 11796),
+    (0x7E0C, 11797), (0x7E0D, 11798), (0x7E0E, 11799), (0x7E0F, 11800), (0x7E10, 11801), (0x7E11, 11802),
+    (0x7E12, 11803), (0x7E13, 11804), (0x7E14, 11805), (0x7E15, 11806), (0x7E16, 11807), (0x7E17, 11808),
+    (0x7E18, 11809), (0x7E19, 11810), (0x7E1A, 11811), (0x7E1B, 11812), (0x7E1C, 11813), (0x7E1D, 11814),
+    (0x7E1E, 11815), (0x7E1F, 11816), (0x7E20, 11817), (0x7E21, 11818), (0x7E22, 11819), (0x7E23, 11820),
+    (0x7E24, 11821), (0x7E25, 11822), (0x7E26, 11823), (0x7E27, 11824), (0x7E28, 11825), (0x7E29, 11826),
+    (0x7E2A, 11827), (0x7E2B, 11828), (0x7E2C, 11829), (0x7E2D, 11830), (0x7E2E, 11831), (0x7E2F, 11832),
+    (0x7E30, 11833), (0x7E31, 11834), (0x7E32, 11835), (0x7E33, 11836), (0x7E34, 11837), (0x7E35, 11838),
+    (0x7E36, 11839), (0x7E37, 11840), (0x7E38, 11841), (0x7E39, 11842), (0x7E3A, 11843), (0x7E3B, 22582),
+    (0x7E3C, 11844), (0x7E3D, 11845), (0x7E3E, 11846), (0x7E3F, 11847), (0x7E40, 11848), (0x7E41, 10372),
+    (0x7E42, 11849), (0x7E43, 11850), (0x7E44, 11851), (0x7E45, 11852), (0x7E46, 11853), (0x7E47, 22022),
+    (0x7E48, 11854), (0x7E49, 11855), (0x7E4A, 11856), (0x7E4B, 11857), (0x7E4C, 11858), (0x7E4D, 11859),
+    (0x7E4E, 11860), (0x7E4F, 11861), (0x7E50, 11862), (0x7E51, 11863), (0x7E52, 11864), (0x7E53, 11865),
+    (0x7E54, 11866), (0x7E55, 11867), (0x7E56, 11868), (0x7E57, 11869), (0x7E58, 11870), (0x7E59, 11871),
+    (0x7E5A, 11872), (0x7E5B, 11873), (0x7E5C, 11874), (0x7E5D, 11875), (0x7E5E, 11970), (0x7E5F, 11971),
+    (0x7E60, 11972), (0x7E61, 11973), (0x7E62, 11974), (0x7E63, 11975), (0x7E64, 11976), (0x7E65, 11977),
+    (0x7E66, 11978), (0x7E67, 11979), (0x7E68, 11980), (0x7E69, 11981), (0x7E6A, 11982), (0x7E6B, 11983),
+    (0x7E6C, 11984), (0x7E6D, 11985), (0x7E6E, 11986), (0x7E6F, 11987), (0x7E70, 11988), (0x7E71, 11989),
+    (0x7E72, 11990), (0x7E73, 11991), (0x7E74, 11992), (0x7E75, 11993), (0x7E76, 11994), (0x7E77, 11995),
+    (0x7E78, 11996), (0x7E79, 11997), (0x7E7A, 11998), (0x7E7B, 11999), (0x7E7C, 12000), (0x7E7D, 12001),
+    (0x7E7E, 12002), (0x7E7F, 12003), (0x7E80, 12004), (0x7E81, 12005), (0x7E82, 16510), (0x7E83, 12006),
+    (0x7E84, 12007), (0x7E85, 12008), (0x7E86, 12009), (0x7E87, 12010), (0x7E88, 12011), (0x7E89, 12012),
+    (0x7E8A, 12013), (0x7E8B, 12014), (0x7E8C, 12015), (0x7E8D, 12016), (0x7E8E, 12017), (0x7E8F, 12018),
+    (0x7E90, 12019), (0x7E91, 12020), (0x7E92, 12021), (0x7E93, 12022), (0x7E94, 12023), (0x7E95, 12024),
+    (0x7E96, 12025), (0x7E97, 12026), (0x7E98, 12027), (0x7E99, 12028), (0x7E9A, 12029), (0x7E9B, 22023),
+    (0x7E9C, 12030), (0x7E9D, 12031), (0x7E9E, 12032), (0x7E9F, 19374), (0x7EA0, 11717), (0x7EA1, 19375),
+    (0x7EA2, 11001), (0x7EA3, 19376), (0x7EA4, 14958), (0x7EA5, 19377), (0x7EA6, 15893), (0x7EA7, 11327),
+    (0x7EA8, 19378), (0x7EA9, 19379), (0x7EAA, 11350), (0x7EAB, 13635), (0x7EAC, 14744), (0x7EAD, 19476),
+    (0x7EAE, 12033), (0x7EAF, 9816), (0x7EB0, 19477), (0x7EB1, 13795), (0x7EB2, 10602), (0x7EB3, 12866),
+    (0x7EB4, 12034), (0x7EB5, 16496), (0x7EB6, 12503), (0x7EB7, 10410), (0x7EB8, 16274), (0x7EB9, 14763),
+    (0x7EBA, 10391), (0x7EBB, 12035), (0x7EBC, 12036), (0x7EBD, 13021), (0x7EBE, 19478), (0x7EBF, 14978),
+    (0x7EC0, 19479), (0x7EC1, 19480), (0x7EC2, 19481), (0x7EC3, 12278), (0x7EC4, 16508), (0x7EC5, 13855),
+    (0x7EC6, 14939), (0x7EC7, 16260), (0x7EC8, 16298), (0x7EC9, 19482), (0x7ECA, 9102), (0x7ECB, 19483),
+    (0x7ECC, 19484), (0x7ECD, 13835), (0x7ECE, 15564), (0x7ECF, 11698), (0x7ED0, 19485), (0x7ED1, 9108),
+    (0x7ED2, 13647), (0x7ED3, 11560), (0x7ED4, 19486), (0x7ED5, 13623), (0x7ED6, 12037), (0x7ED7, 19487),
+    (0x7ED8, 11185), (0x7ED9, 10633), (0x7EDA, 15299), (0x7EDB, 19488), (0x7EDC, 12516), (0x7EDD, 11773),
+    (0x7EDE, 11537), (0x7EDF, 14554), (0x7EE0, 19489), (0x7EE1, 19490), (0x7EE2, 11763), (0x7EE3, 15174),
+    (0x7EE4, 12038), (0x7EE5, 14226), (0x7EE6, 14393), (0x7EE7, 11349), (0x7EE8, 19491), (0x7EE9, 11313),
+    (0x7EEA, 15192), (0x7EEB, 19492), (0x7EEC, 12039), (0x7EED, 15193), (0x7EEE, 19493), (0x7EEF, 19494),
+    (0x7EF0, 9819), (0x7EF1, 19495), (0x7EF2, 19496), (0x7EF3, 13869), (0x7EF4, 14737), (0x7EF5, 12699),
+    (0x7EF6, 19498), (0x7EF7, 9248), (0x7EF8, 9676), (0x7EF9, 12040), (0x7EFA, 19499), (0x7EFB, 19500),
+    (0x7EFC, 16494), (0x7EFD, 16087), (0x7EFE, 19501), (0x7EFF, 12489), (0x7F00, 16461), (0x7F01, 19502),
+    (0x7F02, 19503), (0x7F03, 19504), (0x7F04, 11379), (0x7F05, 12704), (0x7F06, 12099), (0x7F07, 19505),
+    (0x7F08, 19506), (0x7F09, 11314), (0x7F0A, 12041), (0x7F0B, 19507), (0x7F0C, 19508), (0x7F0D, 19497),
+    (0x7F0E, 10213), (0x7F0F, 19509), (0x7F10, 12042), (0x7F11, 19510), (0x7F12, 19511), (0x7F13, 11141),
+    (0x7F14, 10037), (0x7F15, 12483), (0x7F16, 9279), (0x7F17, 19512), (0x7F18, 15886), (0x7F19, 19513),
+    (0x7F1A, 10576), (0x7F1B, 19515), (0x7F1C, 19514), (0x7F1D, 10431), (0x7F1E, 12043), (0x7F1F, 19516),
+    (0x7F20, 9493), (0x7F21, 19517), (0x7F22, 19518), (0x7F23, 19519), (0x7F24, 19520), (0x7F25, 19521),
+    (0x7F26, 19522), (0x7F27, 19523), (0x7F28, 15682), (0x7F29, 14240), (0x7F2A, 19524), (0x7F2B, 19525),
+    (0x7F2C, 19526), (0x7F2D, 19527), (0x7F2E, 13816), (0x7F2F, 19528), (0x7F30, 19529), (0x7F31, 19530),
+    (0x7F32, 19531), (0x7F33, 19532), (0x7F34, 11536), (0x7F35, 19533), (0x7F36, 21785), (0x7F37, 12044),
+    (0x7F38, 10600), (0x7F39, 12045), (0x7F3A, 13602), (0x7F3B, 12046), (0x7F3C, 12047), (0x7F3D, 12048),
+    (0x7F3E, 12049), (0x7F3F, 12050), (0x7F40, 12051), (0x7F41, 12052), (0x7F42, 21786), (0x7F43, 12053),
+    (0x7F44, 21787), (0x7F45, 21788), (0x7F46, 12054), (0x7F47, 12055), (0x7F48, 12056), (0x7F49, 12057),
+    (0x7F4A, 12058), (0x7F4B, 12059), (0x7F4C, 12060), (0x7F4D, 12061), (0x7F4E, 12062), (0x7F4F, 12063),
+    (0x7F50, 10797), (0x7F51, 14623), (0x7F52, 12064), (0x7F53, 12065), (0x7F54, 16697), (0x7F55, 10942),
+    (0x7F56, 12160), (0x7F57, 12507), (0x7F58, 20828), (0x7F59, 12161), (0x7F5A, 10358), (0x7F5B, 12162),
+    (0x7F5C, 12163), (0x7F5D, 12164), (0x7F5E, 12165), (0x7F5F, 20830), (0x7F60, 12166), (0x7F61, 20829),
+    (0x7F62, 9078), (0x7F63, 12167), (0x7F64, 12168), (0x7F65, 12169), (0x7F66, 12170), (0x7F67, 12171),
+    (0x7F68, 20832), (0x7F69, 16109), (0x7F6A, 16514), (0x7F6B, 12172), (0x7F6C, 12173), (0x7F6D, 12174),
+    (0x7F6E, 16280), (0x7F6F, 12175), (0x7F70, 12176), (0x7F71, 20834), (0x7F72, 14045), (0x7F73, 12177),
+    (0x7F74, 20833), (0x7F75, 12178), (0x7F76, 12179), (0x7F77, 12180), (0x7F78, 12181), (0x7F79, 20835),
+    (0x7F7A, 12182), (0x7F7B, 12183), (0x7F7C, 12184), (0x7F7D, 12185), (0x7F7E, 20837), (0x7F7F, 12186),
+    (0x7F80, 12187), (0x7F81, 20836), (0x7F82, 12188), (0x7F83, 12189), (0x7F84, 12190), (0x7F85, 12191),
+    (0x7F86, 12192), (0x7F87, 12193), (0x7F88, 12194), (0x7F89, 12195), (0x7F8A, 15377), (0x7F8B, 12196),
+    (0x7F8C, 13423), (0x7F8D, 12197), (0x7F8E, 12667), (0x7F8F, 12198), (0x7F90, 12199), (0x7F91, 12200),
+    (0x7F92, 12201), (0x7F93, 12202), (0x7F94, 10610), (0x7F95, 12203), (0x7F96, 12204), (0x7F97, 12205),
+    (0x7F98, 12206), (0x7F99, 12207), (0x7F9A, 12326), (0x7F9B, 12208), (0x7F9C, 12209), (0x7F9D, 21983),
+    (0x7F9E, 15168), (0x7F9F, 21984), (0x7FA0, 12210), (0x7FA1, 14974), (0x7FA2, 12211), (0x7FA3, 12212),
+    (0x7FA4, 13611), (0x7FA5, 12213), (0x7FA6, 12214), (0x7FA7, 21985), (0x7FA8, 12215), (0x7FA9, 12216),
+    (0x7FAA, 12217), (0x7FAB, 12218), (0x7FAC, 12219), (0x7FAD, 12220), (0x7FAE, 12221), (0x7FAF, 21986),
+    (0x7FB0, 21987), (0x7FB1, 12222), (0x7FB2, 21988), (0x7FB3, 12223), (0x7FB4, 12224), (0x7FB5, 12225),
+    (0x7FB6, 12226), (0x7FB7, 12227), (0x7FB8, 16905), (0x7FB9, 10639), (0x7FBA, 12228), (0x7FBB, 12229),
+    (0x7FBC, 19176), (0x7FBD, 15755), (0x7FBE, 12230), (0x7FBF, 22009), (0x7FC0, 12231), (0x7FC1, 14769),
+    (0x7FC2, 12232), (0x7FC3, 12233), (0x7FC4, 12234), (0x7FC5, 9660), (0x7FC6, 12235), (0x7FC7, 12236),
+    (0x7FC8, 12237), (0x7FC9, 12238), (0x7FCA, 21395), (0x7FCB, 12239), (0x7FCC, 15563), (0x7FCD, 12240),
+    (0x7FCE, 22010), (0x7FCF, 12241), (0x7FD0, 12242), (0x7FD1, 12243), (0x7FD2, 12244), (0x7FD3, 12245),
+    (0x7FD4, 14987), (0x7FD5, 22011), (0x7FD6, 12246), (0x7FD7, 12247), (0x7FD8, 13439), (0x7FD9, 12248),
+    (0x7FDA, 12249), (0x7FDB, 12250), (0x7FDC, 12251), (0x7FDD, 12252), (0x7FDE, 12253), (0x7FDF, 10027),
+    (0x7FE0, 9853), (0x7FE1, 22013), (0x7FE2, 12254), (0x7FE3, 12255), (0x7FE4, 12350), (0x7FE5, 22012),
+    (0x7FE6, 22014), (0x7FE7, 12351), (0x7FE8, 12352), (0x7FE9, 22015), (0x7FEA, 12353), (0x7FEB, 12354),
+    (0x7FEC, 12355), (0x7FED, 12356), (0x7FEE, 22016), (0x7FEF, 12357), (0x7FF0, 10943), (0x7FF1, 9056),
+    (0x7FF2, 12358), (0x7FF3, 22017), (0x7FF4, 12359), (0x7FF5, 12360), (0x7FF6, 12361), (0x7FF7, 12362),
+    (0x7FF8, 12363), (0x7FF9, 12364), (0x7FFA, 12365), (0x7FFB, 10368), (0x7FFC, 15562), (0x7FFD, 12366),
+    (0x7FFE, 12367), (0x7FFF, 12368), (0x8000, 15496), (0x8001, 12112), (0x8002, 12369), (0x8003, 11903),
+    (0x8004, 20238), (0x8005, 16118), (0x8006, 20085), (0x8007, 12370), (0x8008, 12371), (0x8009, 12372),
+    (0x800A, 12373), (0x800B, 21458), (0x800C, 10253), (0x800D, 14158), (0x800E, 12374), (0x800F, 12375),
+    (0x8010, 12870), (0x8011, 12376), (0x8012, 21446), (0x8013, 12377), (0x8014, 21447), (0x8015, 10636),
+    (0x8016, 21448), (0x8017, 10961), (0x8018, 15902), (0x8019, 9075), (0x801A, 12378), (0x801B, 12379),
+    (0x801C, 21449), (0x801D, 12380), (0x801E, 12381), (0x801F, 12382), (0x8020, 21450), (0x8021, 12383),
+    (0x8022, 21451), (0x8023, 12384), (0x8024, 12385), (0x8025, 21452), (0x8026, 21453), (0x8027, 21454),
+    (0x8028, 21456), (0x8029, 21455), (0x802A, 13068), (0x802B, 12386), (0x802C, 12387), (0x802D, 12388),
+    (0x802E, 12389), (0x802F, 12390), (0x8030, 12391), (0x8031, 21457), (0x8032, 12392), (0x8033, 10255),
+    (0x8034, 12393), (0x8035, 21459), (0x8036, 15499), (0x8037, 17804), (0x8038, 14197), (0x8039, 12394),
+    (0x803A, 12395), (0x803B, 9655), (0x803C, 12396), (0x803D, 9977), (0x803E, 12397), (0x803F, 10737),
+    (0x8040, 12398), (0x8041, 12399), (0x8042, 12909), (0x8043, 21460), (0x8044, 12400), (0x8045, 12401),
+    (0x8046, 21461), (0x8047, 12402), (0x8048, 12403), (0x8049, 12404), (0x804A, 12291), (0x804B, 12346),
+    (0x804C, 16261), (0x804D, 21462), (0x804E, 12405), (0x804F, 12406), (0x8050, 12407), (0x8051, 12408),
+    (0x8052, 21463), (0x8053, 12409), (0x8054, 12265), (0x8055, 12410), (0x8056, 12411), (0x8057, 12412),
+    (0x8058, 13229), (0x8059, 12413), (0x805A, 11744), (0x805B, 12414), (0x805C, 12415), (0x805D, 12416),
+    (0x805E, 12417), (0x805F, 12418), (0x8060, 12419), (0x8061, 12420), (0x8062, 12421), (0x8063, 12422),
+    (0x8064, 12423), (0x8065, 12424), (0x8066, 12425), (0x8067, 12426), (0x8068, 12427), (0x8069, 21464),
+    (0x806A, 9832), (0x806B, 12428), (0x806C, 12429), (0x806D, 12430), (0x806E, 12431), (0x806F, 12432),
+    (0x8070, 12433), (0x8071, 21465), (0x8072, 12434), (0x8073, 12435), (0x8074, 12436), (0x8075, 12437),
+    (0x8076, 12438), (0x8077, 12439), (0x8078, 12440), (0x8079, 12441), (0x807A, 12442), (0x807B, 12443),
+    (0x807C, 12444), (0x807D, 12445), (0x807E, 12540), (0x807F, 20633), (0x8080, 20632), (0x8081, 12541),
+    (0x8082, 12542), (0x8083, 14219), (0x8084, 15547), (0x8085, 12543), (0x8086, 14188), (0x8087, 16111),
+    (0x8088, 12544), (0x8089, 13651), (0x808A, 12545), (0x808B, 12128), (0x808C, 11306), (0x808D, 12546),
+    (0x808E, 12547), (0x808F, 12548), (0x8090, 12549), (0x8091, 12550), (0x8092, 12551), (0x8093, 20268),
+    (0x8094, 12552), (0x8095, 12553), (0x8096, 15109), (0x8097, 12554), (0x8098, 16311), (0x8099, 12555),
+    (0x809A, 10204), (0x809B, 10601), (0x809C, 20267), (0x809D, 10591), (0x809E, 12556), (0x809F, 20266),
+    (0x80A0, 9601), (0x80A1, 10776), (0x80A2, 16256), (0x80A3, 12557), (0x80A4, 10439), (0x80A5, 10397),
+    (0x80A6, 12558), (0x80A7, 12559), (0x80A8, 12560), (0x80A9, 11376), (0x80AA, 10385), (0x80AB, 20273),
+    (0x80AC, 12561), (0x80AD, 20274), (0x80AE, 9050), (0x80AF, 11922), (0x80B0, 12562), (0x80B1, 20272),
+    (0x80B2, 15768), (0x80B3, 12563), (0x80B4, 20275), (0x80B5, 12564), (0x80B6, 12565), (0x80B7, 20276),
+    (0x80B8, 12566), (0x80B9, 12567), (0x80BA, 10401), (0x80BB, 12568), (0x80BC, 20269), (0x80BD, 20271),
+    (0x80BE, 13861), (0x80BF, 16300), (0x80C0, 16100), (0x80C1, 15123), (0x80C2, 20282), (0x80C3, 14749),
+    (0x80C4, 20283), (0x80C5, 12569), (0x80C6, 9983), (0x80C7, 12570), (0x80C8, 12571), (0x80C9, 12572),
+    (0x80CA, 12573), (0x80CB, 12574), (0x80CC, 9234), (0x80CD, 20285), (0x80CE, 14350), (0x80CF, 12575),
+    (0x80D0, 12576), (0x80D1, 12577), (0x80D2, 12578), (0x80D3, 12579), (0x80D4, 12580), (0x80D5, 12581),
+    (0x80D6, 13069), (0x80D7, 20286), (0x80D8, 12582), (0x80D9, 20284), (0x80DA, 13078), (0x80DB, 20281),
+    (0x80DC, 13969), (0x80DD, 20288), (0x80DE, 9116), (0x80DF, 12583), (0x80E0, 12584), (0x80E1, 11015),
+    (0x80E2, 12585), (0x80E3, 12586), (0x80E4, 16648), (0x80E5, 21442), (0x80E6, 12587), (0x80E7, 20277),
+    (0x80E8, 20278), (0x80E9, 20279), (0x80EA, 20280), (0x80EB, 20289), (0x80EC, 19317), (0x80ED, 20292),
+    (0x80EE, 12588), (0x80EF, 11947), (0x80F0, 15525), (0x80F1, 20290), (0x80F2, 20295), (0x80F3, 10621),
+    (0x80F4, 20291), (0x80F5, 12589), (0x80F6, 11521), (0x80F7, 12590), (0x80F8, 15161), (0x80F9, 12591),
+    (0x80FA, 9048), (0x80FB, 12592), (0x80FC, 20296), (0x80FD, 12885), (0x80FE, 12593), (0x80FF, 12594),
+    (0x8100, 12595), (0x8101, 12596), (0x8102, 16257), (0x8103, 12597), (0x8104, 12598), (0x8105, 12599),
+    (0x8106, 9849), (0x8107, 12600), (0x8108, 12601), (0x8109, 12531), (0x810A, 11330), (0x810B, 12602),
+    (0x810C, 12603), (0x810D, 20293), (0x810E, 20294), (0x810F, 15929), (0x8110, 13279), (0x8111, 12877),
+    (0x8112, 20298), (0x8113, 13022), (0x8114, 16900), (0x8115, 12604), (0x8116, 9422), (0x8117, 12605),
+    (0x8118, 20303), (0x8119, 12606), (0x811A, 11532), (0x811B, 12607), (0x811C, 12608), (0x811D, 12609),
+    (0x811E, 20301), (0x811F, 12610), (0x8120, 12611), (0x8121, 12612), (0x8122, 12613), (0x8123, 12614),
+    (0x8124, 12615), (0x8125, 12616), (0x8126, 12617), (0x8127, 12618), (0x8128, 12619), (0x8129, 12620),
+    (0x812A, 12621), (0x812B, 12622), (0x812C, 20302), (0x812D, 12623), (0x812E, 12624), (0x812F, 10557),
+    (0x8130, 12625), (0x8131, 14584), (0x8132, 20304), (0x8133, 12626), (0x8134, 12627), (0x8135, 12628),
+    (0x8136, 20300), (0x8137, 12629), (0x8138, 12274), (0x8139, 12630), (0x813A, 12631), (0x813B, 12632),
+    (0x813C, 12633), (0x813D, 12634), (0x813E, 13207), (0x813F, 12635), (0x8140, 12730), (0x8141, 12731),
+    (0x8142, 12732), (0x8143, 12733), (0x8144, 12734), (0x8145, 12735), (0x8146, 14428), (0x8147, 12736),
+    (0x8148, 20305), (0x8149, 12737), (0x814A, 12081), (0x814B, 15509), (0x814C, 20306), (0x814D, 12738),
+    (0x814E, 12739), (0x814F, 12740), (0x8150, 10560), (0x8151, 10558), (0x8152, 12741), (0x8153, 20307),
+    (0x8154, 13422), (0x8155, 14618), (0x8156, 12742), (0x8157, 12743), (0x8158, 12744), (0x8159, 20309),
+    (0x815A, 20310), (0x815B, 12745), (0x815C, 12746), (0x815D, 12747), (0x815E, 12748), (0x815F, 12749),
+    (0x8160, 20312), (0x8161, 12750), (0x8162, 12751), (0x8163, 12752), (0x8164, 12753), (0x8165, 15145),
+    (0x8166, 12754), (0x8167, 20317), (0x8168, 12755), (0x8169, 20313), (0x816A, 12756), (0x816B, 12757),
+    (0x816C, 12758), (0x816D, 20316), (0x816E, 13674), (0x816F, 12759), (0x8170, 15387), (0x8171, 20311),
+    (0x8172, 12760), (0x8173, 12761), (0x8174, 20308), (0x8175, 12762), (0x8176, 12763), (0x8177, 12764),
+    (0x8178, 12765), (0x8179, 10570), (0x817A, 14972), (0x817B, 12894), (0x817C, 20314), (0x817D, 20315),
+    (0x817E, 14403), (0x817F, 14575), (0x8180, 9107), (0x8181, 12766), (0x8182, 20321), (0x8183, 12767),
+    (0x8184, 12768), (0x8185, 12769), (0x8186, 12770), (0x8187, 12771), (0x8188, 20320), (0x8189, 12772),
+    (0x818A, 9423), (0x818B, 12773), (0x818C, 12774), (0x818D, 12775), (0x818E, 12776), (0x818F, 10609),
+    (0x8190, 12777), (0x8191, 20322), (0x8192, 12778), (0x8193, 12779), (0x8194, 12780), (0x8195, 12781),
+    (0x8196, 12782), (0x8197, 12783), (0x8198, 9291), (0x8199, 12784), (0x819A, 12785), (0x819B, 14382),
+    (0x819C, 12829), (0x819D, 14920), (0x819E, 12786), (0x819F, 12787), (0x81A0, 12788), (0x81A1, 12789),
+    (0x81A2, 12790), (0x81A3, 20324), (0x81A4, 12791), (0x81A5, 12792), (0x81A6, 20427), (0x81A7, 12793),
+    (0x81A8, 13097), (0x81A9, 12794), (0x81AA, 20325), (0x81AB, 12795), (0x81AC, 12796), (0x81AD, 12797),
+    (0x81AE, 12798), (0x81AF, 12799), (0x81B0, 12800), (0x81B1, 12801), (0x81B2, 12802), (0x81B3, 13812),
+    (0x81B4, 12803), (0x81B5, 12804), (0x81B6, 12805), (0x81B7, 12806), (0x81B8, 12807), (0x81B9, 12808),
+    (0x81BA, 18588), (0x81BB, 20329), (0x81BC, 12809), (0x81BD, 12810), (0x81BE, 12811), (0x81BF, 12812),
+    (0x81C0, 14581), (0x81C1, 20426), (0x81C2, 9274), (0x81C3, 15698), (0x81C4, 12813), (0x81C5, 12814),
+    (0x81C6, 15545), (0x81C7, 12815), (0x81C8, 12816), (0x81C9, 12817), (0x81CA, 20328), (0x81CB, 12818),
+    (0x81CC, 20326), (0x81CD, 12819), (0x81CE, 12820), (0x81CF, 12821), (0x81D0, 12822), (0x81D1, 12823),
+    (0x81D2, 12824), (0x81D3, 12825), (0x81D4, 12920), (0x81D5, 12921), (0x81D6, 12922), (0x81D7, 12923),
+    (0x81D8, 12924), (0x81D9, 12925), (0x81DA, 12926), (0x81DB, 12927), (0x81DC, 12928), (0x81DD, 12929),
+    (0x81DE, 12930), (0x81DF, 12931), (0x81E0, 12932), (0x81E1, 12933), (0x81E2, 12934), (0x81E3, 9623),
+    (0x81E4, 12935), (0x81E5, 12936), (0x81E6, 12937), (0x81E7, 20061), (0x81E8, 12938), (0x81E9, 12939),
+    (0x81EA, 16487), (0x81EB, 12940), (0x81EC, 21956), (0x81ED, 9679), (0x81EE, 12941), (0x81EF, 12942),
+    (0x81F0, 12943), (0x81F1, 12944), (0x81F2, 12945), (0x81F3, 16278), (0x81F4, 16279), (0x81F5, 12946),
+    (0x81F6, 12947), (0x81F7, 12948), (0x81F8, 12949), (0x81F9, 12950), (0x81FA, 12951), (0x81FB, 16128),
+    (0x81FC, 11727), (0x81FD, 12952), (0x81FE, 21952), (0x81FF, 12953), (0x8200, 15493), (0x8201, 21953),
+    (0x8202, 21954), (0x8203, 12954), (0x8204, 21955), (0x8205, 11728), (0x8206, 15738), (0x8207, 12955),
+    (0x8208, 12956), (0x8209, 12957), (0x820A, 12958), (0x820B, 12959), (0x820C, 13839), (0x820D, 13840),
+    (0x820E, 12960), (0x820F, 12961), (0x8210, 21789), (0x8211, 12962), (0x8212, 14035), (0x8213, 12963),
+    (0x8214, 14427), (0x8215, 12964), (0x8216, 12965), (0x8217, 12966), (0x8218, 12967), (0x8219, 12968),
+    (0x821A, 12969), (0x821B, 18547), (0x821C, 14175), (0x821D, 12970), (0x821E, 14797), (0x821F, 16304),
+    (0x8220, 12971), (0x8221, 21958), (0x8222, 21959), (0x8223, 21960), (0x8224, 12972), (0x8225, 12973),
+    (0x8226, 12974), (0x8227, 12975), (0x8228, 21963), (0x8229, 12976), (0x822A, 10954), (0x822B, 21964),
+    (0x822C, 9092), (0x822D, 21961), (0x822E, 12977), (0x822F, 21962), (0x8230, 11497), (0x8231, 9458),
+    (0x8232, 12978), (0x8233, 21967), (0x8234, 21968), (0x8235, 10235), (0x8236, 9421), (0x8237, 14962),
+    (0x8238, 21965), (0x8239, 9797), (0x823A, 12979), (0x823B, 21966), (0x823C, 12980), (0x823D, 12981),
+    (0x823E, 21969), (0x823F, 12982), (0x8240, 12983), (0x8241, 12984), (0x8242, 12985), (0x8243, 12986),
+    (0x8244, 21970), (0x8245, 12987), (0x8246, 12988), (0x8247, 14542), (0x8248, 12989), (0x8249, 21971),
+    (0x824A, 12990), (0x824B, 21972), (0x824C, 12991), (0x824D, 12992), (0x824E, 12993), (0x824F, 21973),
+    (0x8250, 12994), (0x8251, 12995), (0x8252, 12996), (0x8253, 12997), (0x8254, 12998), (0x8255, 12999),
+    (0x8256, 13000), (0x8257, 13001), (0x8258, 14205), (0x8259, 13002), (0x825A, 21974), (0x825B, 13003),
+    (0x825C, 13004), (0x825D, 13005), (0x825E, 13006), (0x825F, 21975), (0x8260, 13007), (0x8261, 13008),
+    (0x8262, 13009), (0x8263, 13010), (0x8264, 13011), (0x8265, 13012), (0x8266, 13013), (0x8267, 13014),
+    (0x8268, 21976), (0x8269, 13015), (0x826A, 13110), (0x826B, 13111), (0x826C, 13112), (0x826D, 13113),
+    (0x826E, 22007), (0x826F, 12283), (0x8270, 11377), (0x8271, 13114), (0x8272, 13786), (0x8273, 15357),
+    (0x8274, 19180), (0x8275, 13115), (0x8276, 13116), (0x8277, 13117), (0x8278, 13118), (0x8279, 17404),
+    (0x827A, 15538), (0x827B, 13119), (0x827C, 13120), (0x827D, 17405), (0x827E, 9037), (0x827F, 17406),
+    (0x8280, 13121), (0x8281, 13122), (0x8282, 11553), (0x8283, 13123), (0x8284, 17410), (0x8285, 13124),
+    (0x8286, 13125), (0x8287, 13126), (0x8288, 16659), (0x8289, 13127), (0x828A, 17408), (0x828B, 15758),
+    (0x828C, 13128), (0x828D, 13829), (0x828E, 17411), (0x828F, 17407), (0x8290, 13129), (0x8291, 17412),
+    (0x8292, 12637), (0x8293, 13130), (0x8294, 13131), (0x8295, 13132), (0x8296, 13133), (0x8297, 17413),
+    (0x8298, 17422), (0x8299, 17414), (0x829A, 13134), (0x829B, 13135), (0x829C, 14788), (0x829D, 16250),
+    (0x829E, 13136), (0x829F, 17432), (0x82A0, 13137), (0x82A1, 17430), (0x82A2, 13138), (0x82A3, 13139),
+    (0x82A4, 17435), (0x82A5, 11565), (0x82A6, 12456), (0x82A7, 13140), (0x82A8, 17409), (0x82A9, 17428),
+    (0x82AA, 17431), (0x82AB, 17415), (0x82AC, 10405), (0x82AD, 9062), (0x82AE, 17424), (0x82AF, 15135),
+    (0x82B0, 17418), (0x82B1, 11123), (0x82B2, 13141), (0x82B3, 10383), (0x82B4, 17429), (0x82B5, 13142),
+    (0x82B6, 13143), (0x82B7, 17423), (0x82B8, 17416), (0x82B9, 13454), (0x82BA, 13144), (0x82BB, 13145),
+    (0x82BC, 13146), (0x82BD, 15326), (0x82BE, 17417), (0x82BF, 13147), (0x82C0, 13148), (0x82C1, 17427),
+    (0x82C2, 13149), (0x82C3, 13150), (0x82C4, 17433), (0x82C5, 13151), (0x82C6, 13152), (0x82C7, 14738),
+    (0x82C8, 17419), (0x82C9, 13153), (0x82CA, 17420), (0x82CB, 17425), (0x82CC, 17426), (0x82CD, 9457),
+    (0x82CE, 17434), (0x82CF, 14208), (0x82D0, 13154), (0x82D1, 15888), (0x82D2, 17444), (0x82D3, 17448),
+    (0x82D4, 14351), (0x82D5, 17455), (0x82D6, 13155), (0x82D7, 12706), (0x82D8, 17445), (0x82D9, 13156),
+    (0x82DA, 13157), (0x82DB, 11908), (0x82DC, 17442), (0x82DD, 13158), (0x82DE, 9115), (0x82DF, 10757),
+    (0x82E0, 17454), (0x82E1, 17436), (0x82E2, 13159), (0x82E3, 17421), (0x82E4, 17439), (0x82E5, 13669),
+    (0x82E6, 11939), (0x82E7, 13160), (0x82E8, 13161), (0x82E9, 13162), (0x82EA, 13163), (0x82EB, 13802),
+    (0x82EC, 13164), (0x82ED, 13165), (0x82EE, 13166), (0x82EF, 9244), (0x82F0, 13167), (0x82F1, 15677),
+    (0x82F2, 13168), (0x82F3, 13169), (0x82F4, 17443), (0x82F5, 13170), (0x82F6, 13171), (0x82F7, 17438),
+    (0x82F8, 13172), (0x82F9, 13232), (0x82FA, 13173), (0x82FB, 17447), (0x82FC, 13174), (0x82FD, 13175),
+    (0x82FE, 13176), (0x82FF, 13177), (0x8300, 13178), (0x8301, 16469), (0x8302, 12650), (0x8303, 10377),
+    (0x8304, 13444), (0x8305, 12644), (0x8306, 17451), (0x8307, 17441), (0x8308, 17460), (0x8309, 17437),
+    (0x830A, 13179), (0x830B, 13180), (0x830C, 17446), (0x830D, 13181), (0x830E, 11690), (0x830F, 17440),
+    (0x8310, 13182), (0x8311, 17449), (0x8312, 13183), (0x8313, 13184), (0x8314, 17452), (0x8315, 17453),
+    (0x8316, 13185), (0x8317, 17473), (0x8318, 13186), (0x8319, 13187), (0x831A, 17450), (0x831B, 17577),
+    (0x831C, 17456), (0x831D, 13188), (0x831E, 13189), (0x831F, 13190), (0x8320, 13191), (0x8321, 13192),
+    (0x8322, 13193), (0x8323, 13194), (0x8324, 13195), (0x8325, 13196), (0x8326, 13197), (0x8327, 11380),
+    (0x8328, 9821), (0x8329, 13198), (0x832A, 13199), (0x832B, 12638), (0x832C, 9476), (0x832D, 17475),
+    (0x832E, 13200), (0x832F, 17467), (0x8330, 13201), (0x8331, 17464), (0x8332, 13202), (0x8333, 17477),
+    (0x8334, 17463), (0x8335, 15565), (0x8336, 9477), (0x8337, 13203), (0x8338, 13640), (0x8339, 13652),
+    (0x833A, 17476), (0x833B, 13204), (0x833C, 17462), (0x833D, 13205), (0x833E, 13300), (0x833F, 13301),
+    (0x8340, 17472), (0x8341, 13302), (0x8342, 13303), (0x8343, 17470), (0x8344, 13304), (0x8345, 13305),
+    (0x8346, 11688), (0x8347, 17469), (0x8348, 13306), (0x8349, 9466), (0x834A, 13307), (0x834B, 13308),
+    (0x834C, 13309), (0x834D, 13310), (0x834E, 13311), (0x834F, 17468), (0x8350, 11391), (0x8351, 17457),
+    (0x8352, 11151), (0x8353, 13312), (0x8354, 12148), (0x8355, 13313), (0x8356, 13314), (0x8357, 13315),
+    (0x8358, 13316), (0x8359, 13317), (0x835A, 11357), (0x835B, 17458), (0x835C, 17459), (0x835D, 13318),
+    (0x835E, 17466), (0x835F, 17471), (0x8360, 17474), (0x8361, 9995), (0x8362, 13319), (0x8363, 13642),
+    (0x8364, 11186), (0x8365, 17479), (0x8366, 17478), (0x8367, 15686), (0x8368, 17576), (0x8369, 17578),
+    (0x836A, 17580), (0x836B, 15566), (0x836C, 17579), (0x836D, 17581), (0x836E, 17582), (0x836F, 15494),
+    (0x8370, 13320), (0x8371, 13321), (0x8372, 13322), (0x8373, 13323), (0x8374, 13324), (0x8375, 13325),
+    (0x8376, 13326), (0x8377, 10966), (0x8378, 17584), (0x8379, 13327), (0x837A, 13328), (0x837B, 17597),
+    (0x837C, 17592), (0x837D, 17595), (0x837E, 13329), (0x837F, 13330), (0x8380, 13331), (0x8381, 13332),
+    (0x8382, 13333), (0x8383, 13334), (0x8384, 13335), (0x8385, 17591), (0x8386, 13251), (0x8387, 13336),
+    (0x8388, 13337), (0x8389, 12147), (0x838A, 13338), (0x838B, 13339), (0x838C, 13340), (0x838D, 13341),
+    (0x838E, 13790), (0x838F, 13342), (0x8390, 13343), (0x8391, 13344), (0x8392, 17461), (0x8393, 17589),
+    (0x8394, 13345), (0x8395, 13346), (0x8396, 13347), (0x8397, 13348), (0x8398, 17598), (0x8399, 13349),
+    (0x839A, 13350), (0x839B, 17465), (0x839C, 17590), (0x839D, 13351), (0x839E, 17599), (0x839F, 13352),
+    (0x83A0, 17587), (0x83A1, 13353), (0x83A2, 13354), (0x83A3, 13355), (0x83A4, 13356), (0x83A5, 13357),
+    (0x83A6, 13358), (0x83A7, 13359), (0x83A8, 17600), (0x83A9, 17594), (0x83AA, 17588), (0x83AB, 12835),
+    (0x83AC, 13360), (0x83AD, 13361), (0x83AE, 13362), (0x83AF, 13363), (0x83B0, 17583), (0x83B1, 12084),
+    (0x83B2, 12266), (0x83B3, 17585), (0x83B4, 17586), (0x83B5, 13364), (0x83B6, 17593), (0x83B7, 11196),
+    (0x83B8, 17596), (0x83B9, 15683), (0x83BA, 17601), (0x83BB, 13365), (0x83BC, 17602), (0x83BD, 12642),
+    (0x83BE, 13366), (0x83BF, 13367), (0x83C0, 17625), (0x83C1, 17603), (0x83C2, 13368), (0x83C3, 13369),
+    (0x83C4, 13370), (0x83C5, 17624), (0x83C6, 13371), (0x83C7, 10764), (0x83C8, 13372), (0x83C9, 13373),
+    (0x83CA, 11738), (0x83CB, 13374), (0x83CC, 11775), (0x83CD, 13375), (0x83CE, 13376), (0x83CF, 10967),
+    (0x83D0, 13377), (0x83D1, 13378), (0x83D2, 13379), (0x83D3, 13380), (0x83D4, 17617), (0x83D5, 13381),
+    (0x83D6, 17612), (0x83D7, 13382), (0x83D8, 17606), (0x83D9, 13383), (0x83DA, 13384), (0x83DB, 13385),
+    (0x83DC, 9448), (0x83DD, 17610), (0x83DE, 13386), (0x83DF, 17618), (0x83E0, 9409), (0x83E1, 17628),
+    (0x83E2, 13387), (0x83E3, 13388), (0x83E4, 13389), (0x83E5, 17605), (0x83E6, 13390), (0x83E7, 13391),
+    (0x83E8, 13392), (0x83E9, 13253), (0x83EA, 17623), (0x83EB, 13393), (0x83EC, 13394), (0x83ED, 13395),
+    (0x83EE, 13490), (0x83EF, 13491), (0x83F0, 17627), (0x83F1, 12321), (0x83F2, 10393), (0x83F3, 13492),
+    (0x83F4, 13493), (0x83F5, 13494), (0x83F6, 13495), (0x83F7, 13496), (0x83F8, 17621), (0x83F9, 17622),
+    (0x83FA, 13497), (0x83FB, 13498), (0x83FC, 13499), (0x83FD, 17611), (0x83FE, 13500), (0x83FF, 13501),
+    (0x8400, 13502), (0x8401, 17604), (0x8402, 13503), (0x8403, 17620), (0x8404, 14394), (0x8405, 13504),
+    (0x8406, 17616), (0x8407, 13505), (0x8408, 13506), (0x8409, 13507), (0x840A, 13508), (0x840B, 17609),
+    (0x840C, 12675), (0x840D, 13233), (0x840E, 14739), (0x840F, 17619), (0x8410, 13509), (0x8411, 17615),
+    (0x8412, 13510), (0x8413, 13511), (0x8414, 13512), (0x8415, 13513), (0x8416, 13514), (0x8417, 13515),
+    (0x8418, 17608), (0x8419, 13516), (0x841A, 13517), (0x841B, 13518), (0x841C, 17613), (0x841D, 12505),
+    (0x841E, 13519), (0x841F, 13520), (0x8420, 13521), (0x8421, 13522), (0x8422, 13523), (0x8423, 13524),
+    (0x8424, 15684), (0x8425, 15685), (0x8426, 17626), (0x8427, 14999), (0x8428, 13673), (0x8429, 13525),
+    (0x842A, 13526), (0x842B, 13527), (0x842C, 13528), (0x842D, 13529), (0x842E, 13530), (0x842F, 13531),
+    (0x8430, 13532), (0x8431, 17645), (0x8432, 13533), (0x8433, 13534), (0x8434, 13535), (0x8435, 13536),
+    (0x8436, 13537), (0x8437, 13538), (0x8438, 17614), (0x8439, 13539), (0x843A, 13540), (0x843B, 13541),
+    (0x843C, 17639), (0x843D, 12513), (0x843E, 13542), (0x843F, 13543), (0x8440, 13544), (0x8441, 13545),
+    (0x8442, 13546), (0x8443, 13547), (0x8444, 13548), (0x8445, 13549), (0x8446, 17640), (0x8447, 13550),
+    (0x8448, 13551), (0x8449, 13552), (0x844A, 13553), (0x844B, 13554), (0x844C, 13555), (0x844D, 13556),
+    (0x844E, 13557), (0x844F, 13558), (0x8450, 13559), (0x8451, 17630), (0x8452, 13560), (0x8453, 13561),
+    (0x8454, 13562), (0x8455, 13563), (0x8456, 13564), (0x8457, 16333), (0x8458, 13565), (0x8459, 17632),
+    (0x845A, 17631), (0x845B, 10625), (0x845C, 17629), (0x845D, 13566), (0x845E, 13567), (0x845F, 13568),
+    (0x8460, 13569), (0x8461, 13252), (0x8462, 13570), (0x8463, 10178), (0x8464, 13571), (0x8465, 13572),
+    (0x8466, 13573), (0x8467, 13574), (0x8468, 13575), (0x8469, 17641), (0x846A, 13576), (0x846B, 11014),
+    (0x846C, 15930), (0x846D, 17646), (0x846E, 13577), (0x846F, 13578), (0x8470, 13579), (0x8471, 9833),
+    (0x8472, 13580), (0x8473, 17633), (0x8474, 13581), (0x8475, 11966), (0x8476, 17642), (0x8477, 13582),
+    (0x8478, 17638), (0x8479, 13583), (0x847A, 17636), (0x847B, 13584), (0x847C, 13585), (0x847D, 13680),
+    (0x847E, 13681), (0x847F, 13682), (0x8480, 13683), (0x8481, 13684), (0x8482, 10032), (0x8483, 13685),
+    (0x8484, 13686), (0x8485, 13687), (0x8486, 13688), (0x8487, 17634), (0x8488, 17635), (0x8489, 17637),
+    (0x848A, 13689), (0x848B, 11510), (0x848C, 17643), (0x848D, 13690), (0x848E, 17644), (0x848F, 13691),
+    (0x8490, 13692), (0x8491, 13693), (0x8492, 13694), (0x8493, 13695), (0x8494, 13696), (0x8495, 13697),
+    (0x8496, 13698), (0x8497, 17660), (0x8498, 13699), (0x8499, 12676), (0x849A, 13700), (0x849B, 13701),
+    (0x849C, 14221), (0x849D, 13702), (0x849E, 13703), (0x849F, 13704), (0x84A0, 13705), (0x84A1, 17657),
+    (0x84A2, 13706), (0x84A3, 13707), (0x84A4, 13708), (0x84A5, 13709), (0x84A6, 13710), (0x84A7, 13711),
+    (0x84A8, 13712), (0x84A9, 13713), (0x84AA, 13714), (0x84AB, 13715), (0x84AC, 13716), (0x84AD, 13717),
+    (0x84AE, 13718), (0x84AF, 16690), (0x84B0, 13719), (0x84B1, 13720), (0x84B2, 13254), (0x84B3, 13721),
+    (0x84B4, 17659), (0x84B5, 13722), (0x84B6, 13723), (0x84B7, 13724), (0x84B8, 16139), (0x84B9, 17658),
+    (0x84BA, 17655), (0x84BB, 13725), (0x84BC, 13726), (0x84BD, 17651), (0x84BE, 13727), (0x84BF, 17654),
+    (0x84C0, 13728), (0x84C1, 17647), (0x84C2, 13729), (0x84C3, 13730), (0x84C4, 15183), (0x84C5, 13731),
+    (0x84C6, 13732), (0x84C7, 13733), (0x84C8, 13734), (0x84C9, 13641), (0x84CA, 17653), (0x84CB, 13735),
+    (0x84CC, 13736), (0x84CD, 17648), (0x84CE, 13737), (0x84CF, 13738), (0x84D0, 17649), (0x84D1, 14237),
+    (0x84D2, 13739), (0x84D3, 17652), (0x84D4, 13740), (0x84D5, 13741), (0x84D6, 9260), (0x84D7, 13742),
+    (0x84D8, 13743), (0x84D9, 13744), (0x84DA, 13745), (0x84DB, 13746), (0x84DC, 13747), (0x84DD, 12087),
+    (0x84DE, 13748), (0x84DF, 11332), (0x84E0, 17656), (0x84E1, 13749), (0x84E2, 13750), (0x84E3, 17662),
+    (0x84E4, 13751), (0x84E5, 17661), (0x84E6, 17650), (0x84E7, 13752), (0x84E8, 13753), (0x84E9, 13754),
+    (0x84EA, 13755), (0x84EB, 13756), (0x84EC, 13093), (0x84ED, 13757), (0x84EE, 13758), (0x84EF, 13759),
+    (0x84F0, 17666), (0x84F1, 13760), (0x84F2, 13761), (0x84F3, 13762), (0x84F4, 13763), (0x84F5, 13764),
+    (0x84F6, 13765), (0x84F7, 13766), (0x84F8, 13767), (0x84F9, 13768), (0x84FA, 13769), (0x84FB, 13770),
+    (0x84FC, 17769), (0x84FD, 13771), (0x84FE, 13772), (0x84FF, 17768), (0x8500, 13773), (0x8501, 13774),
+    (0x8502, 13775), (0x8503, 13870), (0x8504, 13871), (0x8505, 13872), (0x8506, 13873), (0x8507, 13874),
+    (0x8508, 13875), (0x8509, 13876), (0x850A, 13877), (0x850B, 13878), (0x850C, 17663), (0x850D, 13879),
+    (0x850E, 13880), (0x850F, 13881), (0x8510, 13882), (0x8511, 12714), (0x8512, 13883), (0x8513, 12536),
+    (0x8514, 13884), (0x8515, 13885), (0x8516, 13886), (0x8517, 16120), (0x8518, 13887), (0x8519, 13888),
+    (0x851A, 14746), (0x851B, 13889), (0x851C, 13890), (0x851D, 13891), (0x851E, 13892), (0x851F, 17668),
+    (0x8520, 13893), (0x8521, 9449), (0x8522, 13894), (0x8523, 13895), (0x8524, 13896), (0x8525, 13897),
+    (0x8526, 13898), (0x8527, 13899), (0x8528, 13900), (0x8529, 13901), (0x852A, 13902), (0x852B, 12897),
+    (0x852C, 14028), (0x852D, 13903), (0x852E, 13904), (0x852F, 13905), (0x8530, 13906), (0x8531, 13907),
+    (0x8532, 13908), (0x8533, 13909), (0x8534, 13910), (0x8535, 13911), (0x8536, 13912), (0x8537, 13425),
+    (0x8538, 17665), (0x8539, 17667), (0x853A, 17669), (0x853B, 17767), (0x853C, 9035), (0x853D, 9261),
+    (0x853E, 13913), (0x853F, 13914), (0x8540, 13915), (0x8541, 13916), (0x8542, 13917), (0x8543, 17777),
+    (0x8544, 13918), (0x8545, 13919), (0x8546, 13920), (0x8547, 13921), (0x8548, 17771), (0x8549, 11517),
+    (0x854A, 13664), (0x854B, 13922), (0x854C, 13923), (0x854D, 13924), (0x854E, 13925), (0x854F, 13926),
+    (0x8550, 13927), (0x8551, 13928), (0x8552, 13929), (0x8553, 13930), (0x8554, 13931), (0x8555, 13932),
+    (0x8556, 17766), (0x8557, 13933), (0x8558, 13934), (0x8559, 17770), (0x855A, 13935), (0x855B, 13936),
+    (0x855C, 13937), (0x855D, 13938), (0x855E, 17774), (0x855F, 13939), (0x8560, 13940), (0x8561, 13941),
+    (0x8562, 13942), (0x8563, 13943), (0x8564, 17773), (0x8565, 13944), (0x8566, 13945), (0x8567, 13946),
+    (0x8568, 17772), (0x8569, 13947), (0x856A, 13948), (0x856B, 13949), (0x856C, 13950), (0x856D, 13951),
+    (0x856E, 13952), (0x856F, 13953), (0x8570, 13954), (0x8571, 13955), (0x8572, 17778), (0x8573, 13956),
+    (0x8574, 15909), (0x8575, 13957), (0x8576, 13958), (0x8577, 13959), (0x8578, 13960), (0x8579, 17784),
+    (0x857A, 17775), (0x857B, 17779), (0x857C, 13961), (0x857D, 13962), (0x857E, 12122), (0x857F, 13963),
+    (0x8580, 13964), (0x8581, 13965), (0x8582, 14060), (0x8583, 14061), (0x8584, 9216), (0x8585, 17787),
+    (0x8586, 14062), (0x8587, 17782), (0x8588, 14063), (0x8589, 14064), (0x858A, 14065), (0x858B, 14066),
+    (0x858C, 14067), (0x858D, 14068), (0x858E, 14069), (0x858F, 17783), (0x8590, 14070), (0x8591, 14071),
+    (0x8592, 14072), (0x8593, 14073), (0x8594, 14074), (0x8595, 14075), (0x8596, 14076), (0x8597, 14077),
+    (0x8598, 14078), (0x8599, 14079), (0x859A, 14080), (0x859B, 15301), (0x859C, 17786), (0x859D, 14081),
+    (0x859E, 14082), (0x859F, 14083), (0x85A0, 14084), (0x85A1, 14085), (0x85A2, 14086), (0x85A3, 14087),
+    (0x85A4, 17780), (0x85A5, 14088), (0x85A6, 14089), (0x85A7, 14090), (0x85A8, 17781), (0x85A9, 14091),
+    (0x85AA, 15134), (0x85AB, 14092), (0x85AC, 14093), (0x85AD, 14094), (0x85AE, 17785), (0x85AF, 14042),
+    (0x85B0, 17790), (0x85B1, 14095), (0x85B2, 14096), (0x85B3, 14097), (0x85B4, 14098), (0x85B5, 14099),
+    (0x85B6, 14100), (0x85B7, 17789), (0x85B8, 14101), (0x85B9, 17788), (0x85BA, 14102), (0x85BB, 14103),
+    (0x85BC, 14104), (0x85BD, 14105), (0x85BE, 14106), (0x85BF, 14107), (0x85C0, 14108), (0x85C1, 17792),
+    (0x85C2, 14109), (0x85C3, 14110), (0x85C4, 14111), (0x85C5, 14112), (0x85C6, 14113), (0x85C7, 14114),
+    (0x85C8, 14115), (0x85C9, 11564), (0x85CA, 14116), (0x85CB, 14117), (0x85CC, 14118), (0x85CD, 14119),
+    (0x85CE, 14120), (0x85CF, 9461), (0x85D0, 12709), (0x85D1, 14121), (0x85D2, 14122), (0x85D3, 17791),
+    (0x85D4, 14123), (0x85D5, 13041), (0x85D6, 14124), (0x85D7, 14125), (0x85D8, 14126), (0x85D9, 14127),
+    (0x85DA, 14128), (0x85DB, 14129), (0x85DC, 17793), (0x85DD, 14130), (0x85DE, 14131), (0x85DF, 14132),
+    (0x85E0, 14133), (0x85E1, 14134), (0x85E2, 14135), (0x85E3, 14136), (0x85E4, 14402), (0x85E5, 14137),
+    (0x85E6, 14138), (0x85E7, 14139), (0x85E8, 14140), (0x85E9, 10365), (0x85EA, 14141), (0x85EB, 14142),
+    (0x85EC, 14143), (0x85ED, 14144), (0x85EE, 14145), (0x85EF, 14146), (0x85F0, 14147), (0x85F1, 14148),
+    (0x85F2, 14149), (0x85F3, 14150), (0x85F4, 14151), (0x85F5, 14152), (0x85F6, 14153), (0x85F7, 14154),
+    (0x85F8, 14155), (0x85F9, 14250), (0x85FA, 14251), (0x85FB, 15934), (0x85FC, 14252), (0x85FD, 14253),
+    (0x85FE, 14254), (0x85FF, 17794), (0x8600, 14255), (0x8601, 14256), (0x8602, 14257), (0x8603, 14258),
+    (0x8604, 14259), (0x8605, 17796), (0x8606, 14260), (0x8607, 14261), (0x8608, 14262), (0x8609, 14263),
+    (0x860A, 14264), (0x860B, 14265), (0x860C, 14266), (0x860D, 14267), (0x860E, 14268), (0x860F, 14269),
+    (0x8610, 14270), (0x8611, 12827), (0x8612, 14271), (0x8613, 14272), (0x8614, 14273), (0x8615, 14274),
+    (0x8616, 17798), (0x8617, 14275), (0x8618, 14276), (0x8619, 14277), (0x861A, 14278), (0x861B, 14279),
+    (0x861C, 14280), (0x861D, 14281), (0x861E, 14282), (0x861F, 14283), (0x8620, 14284), (0x8621, 14285),
+    (0x8622, 14286), (0x8623, 14287), (0x8624, 14288), (0x8625, 14289), (0x8626, 14290), (0x8627, 17795),
+    (0x8628, 14291), (0x8629, 17797), (0x862A, 14292), (0x862B, 14293), (0x862C, 14294), (0x862D, 14295),
+    (0x862E, 14296), (0x862F, 14297), (0x8630, 14298), (0x8631, 14299), (0x8632, 14300), (0x8633, 14301),
+    (0x8634, 14302), (0x8635, 14303), (0x8636, 14304), (0x8637, 14305), (0x8638, 16081), (0x8639, 14306),
+    (0x863A, 14307), (0x863B, 14308), (0x863C, 17799), (0x863D, 14309), (0x863E, 14310), (0x863F, 14311),
+    (0x8640, 14312), (0x8641, 14313), (0x8642, 14314), (0x8643, 14315), (0x8644, 14316), (0x8645, 14317),
+    (0x8646, 14318), (0x8647, 14319), (0x8648, 14320), (0x8649, 14321), (0x864A, 14322), (0x864B, 14323),
+    (0x864C, 14324), (0x864D, 21579), (0x864E, 11117), (0x864F, 12463), (0x8650, 13031), (0x8651, 12484),
+    (0x8652, 14325), (0x8653, 14326), (0x8654, 21580), (0x8655, 14327), (0x8656, 14328), (0x8657, 14329),
+    (0x8658, 14330), (0x8659, 14331), (0x865A, 15178), (0x865B, 14332), (0x865C, 14333), (0x865D, 14334),
+    (0x865E, 15736), (0x865F, 14335), (0x8660, 14336), (0x8661, 14337), (0x8662, 20264), (0x8663, 14338),
+    (0x8664, 14339), (0x8665, 14340), (0x8666, 14341), (0x8667, 14342), (0x8668, 14343), (0x8669, 14344),
+    (0x866A, 14345), (0x866B, 9665), (0x866C, 21581), (0x866D, 14440), (0x866E, 21582), (0x866F, 14441),
+    (0x8670, 14442), (0x8671, 13978), (0x8672, 14443), (0x8673, 14444), (0x8674, 14445), (0x8675, 14446),
+    (0x8676, 14447), (0x8677, 14448), (0x8678, 14449), (0x8679, 10996), (0x867A, 21584), (0x867B, 21586),
+    (0x867C, 21585), (0x867D, 14223), (0x867E, 14941), (0x867F, 21583), (0x8680, 13985), (0x8681, 15532),
+    (0x8682, 12521), (0x8683, 14450), (0x8684, 14451), (0x8685, 14452), (0x8686, 14453), (0x8687, 14454),
+    (0x8688, 14455), (0x8689, 14456), (0x868A, 14760), (0x868B, 21589), (0x868C, 9111), (0x868D, 21588),
+    (0x868E, 14457), (0x868F, 14458), (0x8690, 14459), (0x8691, 14460), (0x8692, 14461), (0x8693, 21595),
+    (0x8694, 14462), (0x8695, 9452), (0x8696, 14463), (0x8697, 14464), (0x8698, 14465), (0x8699, 14466),
+    (0x869A, 14467), (0x869B, 14468), (0x869C, 15328), (0x869D, 21591), (0x869E, 14469), (0x869F, 14470),
+    (0x86A0, 14471), (0x86A1, 14472), (0x86A2, 14473), (0x86A3, 21593), (0x86A4, 15938), (0x86A5, 14474),
+    (0x86A6, 14475), (0x86A7, 21592), (0x86A8, 21587), (0x86A9, 21596), (0x86AA, 21594), (0x86AB, 14476),
+    (0x86AC, 21590), (0x86AD, 14477), (0x86AE, 14478), (0x86AF, 21604), (0x86B0, 21601), (0x86B1, 21603),
+    (0x86B2, 14479), (0x86B3, 14480), (0x86B4, 21607), (0x86B5, 21599), (0x86B6, 21597), (0x86B7, 14481),
+    (0x86B8, 14482), (0x86B9, 14483), (0x86BA, 21602), (0x86BB, 14484), (0x86BC, 14485), (0x86BD, 14486),
+    (0x86BE, 14487), (0x86BF, 14488), (0x86C0, 16336), (0x86C1, 14489), (0x86C2, 14490), (0x86C3, 14491),
+    (0x86C4, 21598), (0x86C5, 14492), (0x86C6, 13484), (0x86C7, 13838), (0x86C8, 14493), (0x86C9, 21605),
+    (0x86CA, 10773), (0x86CB, 9991), (0x86CC, 14494), (0x86CD, 14495), (0x86CE, 21600), (0x86CF, 21606),
+    (0x86D0, 21613), (0x86D1, 21619), (0x86D2, 14496), (0x86D3, 14497), (0x86D4, 11170), (0x86D5, 14498),
+    (0x86D6, 14499), (0x86D7, 14500), (0x86D8, 21618), (0x86D9, 14595), (0x86DA, 14501), (0x86DB, 16320),
+    (0x86DC, 14502), (0x86DD, 14503), (0x86DE, 21615), (0x86DF, 21617), (0x86E0, 14504), (0x86E1, 14505),
+    (0x86E2, 14506), (0x86E3, 14507), (0x86E4, 10627), (0x86E5, 14508), (0x86E6, 14509), (0x86E7, 14510),
+    (0x86E8, 14511), (0x86E9, 21608), (0x86EA, 14512), (0x86EB, 14513), (0x86EC, 14514), (0x86ED, 21611),
+    (0x86EE, 12534), (0x86EF, 14515), (0x86F0, 16116), (0x86F1, 21609), (0x86F2, 21610), (0x86F3, 21612),
+    (0x86F4, 21616), (0x86F5, 14516), (0x86F6, 14517), (0x86F7, 14518), (0x86F8, 21622), (0x86F9, 15703),
+    (0x86FA, 14519), (0x86FB, 14520), (0x86FC, 14521), (0x86FD, 14522), (0x86FE, 10239), (0x86FF, 14523),
+    (0x8700, 14046), (0x8701, 14524), (0x8702, 10423), (0x8703, 21620), (0x8704, 14525), (0x8705, 14526),
+    (0x8706, 14527), (0x8707, 21621), (0x8708, 21623), (0x8709, 21626), (0x870A, 21624), (0x870B, 14528),
+    (0x870C, 14529), (0x870D, 21625), (0x870E, 14530), (0x870F, 14531), (0x8710, 14532), (0x8711, 14533),
+    (0x8712, 15344), (0x8713, 21614), (0x8714, 14534), (0x8715, 14576), (0x8716, 14535), (0x8717, 14772),
+    (0x8718, 16254), (0x8719, 14630), (0x871A, 21632), (0x871B, 14631), (0x871C, 12694), (0x871D, 14632),
+    (0x871E, 21629), (0x871F, 14633), (0x8720, 14634), (0x8721, 12080), (0x8722, 21641), (0x8723, 21627),
+    (0x8724, 14635), (0x8725, 21630), (0x8726, 14636), (0x8727, 14637), (0x8728, 14638), (0x8729, 21637),
+    (0x872A, 14639), (0x872B, 14640), (0x872C, 14641), (0x872D, 14642), (0x872E, 21631), (0x872F, 14643),
+    (0x8730, 14644), (0x8731, 21636), (0x8732, 14645), (0x8733, 14646), (0x8734, 21635), (0x8735, 14647),
+    (0x8736, 14648), (0x8737, 21638), (0x8738, 14649), (0x8739, 14650), (0x873A, 14651), (0x873B, 21628),
+    (0x873C, 14652), (0x873D, 14653), (0x873E, 21633), (0x873F, 21639), (0x8740, 14654), (0x8741, 14655),
+    (0x8742, 14656), (0x8743, 14657), (0x8744, 14658), (0x8745, 14659), (0x8746, 14660), (0x8747, 15687),
+    (0x8748, 21634), (0x8749, 9490), (0x874A, 14661), (0x874B, 14662), (0x874C, 21647), (0x874D, 14663),
+    (0x874E, 15116), (0x874F, 14664), (0x8750, 14665), (0x8751, 14666), (0x8752, 14667), (0x8753, 21650),
+    (0x8754, 14668), (0x8755, 14669), (0x8756, 14670), (0x8757, 11155), (0x8758, 14671), (0x8759, 21654),
+    (0x875A, 14672), (0x875B, 14673), (0x875C, 14674), (0x875D, 14675), (0x875E, 14676), (0x875F, 14677),
+    (0x8760, 21645), (0x8761, 14678), (0x8762, 14679), (0x8763, 21651), (0x8764, 21653), (0x8765, 21655),
+    (0x8766, 14680), (0x8767, 14681), (0x8768, 14682), (0x8769, 14683), (0x876A, 14684), (0x876B, 14685),
+    (0x876C, 14686), (0x876D, 14687), (0x876E, 21648), (0x876F, 14688), (0x8770, 21646), (0x8771, 14689),
+    (0x8772, 14690), (0x8773, 14691), (0x8774, 11016), (0x8775, 14692), (0x8776, 10066), (0x8777, 14693),
+    (0x8778, 14694), (0x8779, 14695), (0x877A, 14696), (0x877B, 21644), (0x877C, 21652), (0x877D, 21642),
+    (0x877E, 21643), (0x877F, 14697), (0x8780, 14698), (0x8781, 14699), (0x8782, 21640), (0x8783, 21761),
+    (0x8784, 14700), (0x8785, 21758), (0x8786, 14701), (0x8787, 14702), (0x8788, 21757), (0x8789, 14703),
+    (0x878A, 14704), (0x878B, 21649), (0x878C, 14705), (0x878D, 13643), (0x878E, 14706), (0x878F, 14707),
+    (0x8790, 14708), (0x8791, 14709), (0x8792, 14710), (0x8793, 21656), (0x8794, 14711), (0x8795, 14712),
+    (0x8796, 14713), (0x8797, 21760), (0x8798, 14714), (0x8799, 14715), (0x879A, 14716), (0x879B, 14717),
+    (0x879C, 14718), (0x879D, 14719), (0x879E, 14720), (0x879F, 12723), (0x87A0, 14721), (0x87A1, 14722),
+    (0x87A2, 14723), (0x87A3, 14724), (0x87A4, 14725), (0x87A5, 14820), (0x87A6, 14821), (0x87A7, 14822),
+    (0x87A8, 21658), (0x87A9, 14823), (0x87AA, 14824), (0x87AB, 21762), (0x87AC, 21764), (0x87AD, 21759),
+    (0x87AE, 14825), (0x87AF, 21657), (0x87B0, 14826), (0x87B1, 14827), (0x87B2, 14828), (0x87B3, 21766),
+    (0x87B4, 14829), (0x87B5, 21765), (0x87B6, 14830), (0x87B7, 14831), (0x87B8, 14832), (0x87B9, 14833),
+    (0x87BA, 12506), (0x87BB, 14834), (0x87BC, 14835), (0x87BD, 21769), (0x87BE, 14836), (0x87BF, 14837),
+    (0x87C0, 21771), (0x87C1, 14838), (0x87C2, 14839), (0x87C3, 14840), (0x87C4, 14841), (0x87C5, 14842),
+    (0x87C6, 21756), (0x87C7, 14843), (0x87C8, 14844), (0x87C9, 14845), (0x87CA, 21772), (0x87CB, 21767),
+    (0x87CC, 14846), (0x87CD, 14847), (0x87CE, 14848), (0x87CF, 14849), (0x87D0, 14850), (0x87D1, 21770),
+    (0x87D2, 21659), (0x87D3, 21768), (0x87D4, 14851), (0x87D5, 14852), (0x87D6, 14853), (0x87D7, 14854),
+    (0x87D8, 14855), (0x87D9, 14856), (0x87DA, 14857), (0x87DB, 21773), (0x87DC, 14858), (0x87DD, 14859),
+    (0x87DE, 14860), (0x87DF, 14861), (0x87E0, 21775), (0x87E1, 14862), (0x87E2, 14863), (0x87E3, 14864),
+    (0x87E4, 14865), (0x87E5, 21763), (0x87E6, 14866), (0x87E7, 14867), (0x87E8, 14868), (0x87E9, 14869),
+    (0x87EA, 21774), (0x87EB, 14870), (0x87EC, 14871), (0x87ED, 14872), (0x87EE, 21776), (0x87EF, 14873),
+    (0x87F0, 14874), (0x87F1, 14875), (0x87F2, 14876), (0x87F3, 14877), (0x87F4, 14878), (0x87F5, 14879),
+    (0x87F6, 14880), (0x87F7, 14881), (0x87F8, 14882), (0x87F9, 15128), (0x87FA, 14883), (0x87FB, 14884),
+    (0x87FC, 14885), (0x87FD, 14886), (0x87FE, 21779), (0x87FF, 14887), (0x8800, 14888), (0x8801, 14889),
+    (0x8802, 14890), (0x8803, 16904), (0x8804, 14891), (0x8805, 14892), (0x8806, 14893), (0x8807, 14894),
+    (0x8808, 14895), (0x8809, 14896), (0x880A, 21780), (0x880B, 14897), (0x880C, 14898), (0x880D, 14899),
+    (0x880E, 14900), (0x880F, 14901), (0x8810, 14902), (0x8811, 14903), (0x8812, 14904), (0x8813, 21778),
+    (0x8814, 14905), (0x8815, 13653), (0x8816, 21777), (0x8817, 14906), (0x8818, 14907), (0x8819, 14908),
+    (0x881A, 14909), (0x881B, 21781), (0x881C, 14910), (0x881D, 14911), (0x881E, 14912), (0x881F, 14913),
+    (0x8820, 14914), (0x8821, 21782), (0x8822, 9817), (0x8823, 14915), (0x8824, 15010), (0x8825, 15011),
+    (0x8826, 15012), (0x8827, 15013), (0x8828, 15014), (0x8829, 15015), (0x882A, 15016), (0x882B, 15017),
+    (0x882C, 15018), (0x882D, 15019), (0x882E, 15020), (0x882F, 15021), (0x8830, 15022), (0x8831, 15023),
+    (0x8832, 20840), (0x8833, 15024), (0x8834, 15025), (0x8835, 15026), (0x8836, 15027), (0x8837, 15028),
+    (0x8838, 15029), (0x8839, 21783), (0x883A, 15030), (0x883B, 15031), (0x883C, 21784), (0x883D, 15032),
+    (0x883E, 15033), (0x883F, 15034), (0x8840, 15305), (0x8841, 15035), (0x8842, 15036), (0x8843, 15037),
+    (0x8844, 21957), (0x8845, 15143), (0x8846, 15038), (0x8847, 15039), (0x8848, 15040), (0x8849, 15041),
+    (0x884A, 15042), (0x884B, 15043), (0x884C, 15153), (0x884D, 15355), (0x884E, 15044), (0x884F, 15045),
+    (0x8850, 15046), (0x8851, 15047), (0x8852, 15048), (0x8853, 15049), (0x8854, 14961), (0x8855, 15050),
+    (0x8856, 15051), (0x8857, 11549), (0x8858, 15052), (0x8859, 15330), (0x885A, 15053), (0x885B, 15054),
+    (0x885C, 15055), (0x885D, 15056), (0x885E, 15057), (0x885F, 15058), (0x8860, 15059), (0x8861, 10991),
+    (0x8862, 18408), (0x8863, 15519), (0x8864, 21409), (0x8865, 9430), (0x8866, 15060), (0x8867, 15061),
+    (0x8868, 9292), (0x8869, 21410), (0x886A, 15062), (0x886B, 13807), (0x886C, 9631), (0x886D, 15063),
+    (0x886E, 16897), (0x886F, 15064), (0x8870, 14160), (0x8871, 15065), (0x8872, 21411), (0x8873, 15066),
+    (0x8874, 15067), (0x8875, 15068), (0x8876, 15069), (0x8877, 16297), (0x8878, 15070), (0x8879, 15071),
+    (0x887A, 15072), (0x887B, 15073), (0x887C, 15074), (0x887D, 21412), (0x887E, 21977), (0x887F, 21413),
+    (0x8880, 15075), (0x8881, 15877), (0x8882, 21414), (0x8883, 15076), (0x8884, 9057), (0x8885, 21978),
+    (0x8886, 15077), (0x8887, 15078), (0x8888, 21979), (0x8889, 15079), (0x888A, 15080), (0x888B, 9877),
+    (0x888C, 15081), (0x888D, 13074), (0x888E, 15082), (0x888F, 15083), (0x8890, 15084), (0x8891, 15085),
+    (0x8892, 14372), (0x8893, 15086), (0x8894, 15087), (0x8895, 15088), (0x8896, 15173), (0x8897, 15089),
+    (0x8898, 15090), (0x8899, 15091), (0x889A, 15092), (0x889B, 15093), (0x889C, 14599), (0x889D, 15094),
+    (0x889E, 15095), (0x889F, 15096), (0x88A0, 15097), (0x88A1, 15098), (0x88A2, 21415), (0x88A3, 15099),
+    (0x88A4, 16898), (0x88A5, 15100), (0x88A6, 15101), (0x88A7, 15102), (0x88A8, 15103), (0x88A9, 15104),
+    (0x88AA, 15105), (0x88AB, 9242), (0x88AC, 15200), (0x88AD, 14929), (0x88AE, 15201), (0x88AF, 15202),
+    (0x88B0, 15203), (0x88B1, 10549), (0x88B2, 15204), (0x88B3, 15205), (0x88B4, 15206), (0x88B5, 15207),
+    (0x88B6, 15208), (0x88B7, 21417), (0x88B8, 15209), (0x88B9, 15210), (0x88BA, 15211), (0x88BB, 15212),
+    (0x88BC, 21418), (0x88BD, 15213), (0x88BE, 15214), (0x88BF, 15215), (0x88C0, 15216), (0x88C1, 9440),
+    (0x88C2, 12304), (0x88C3, 15217), (0x88C4, 15218), (0x88C5, 16451), (0x88C6, 21416), (0x88C7, 15219),
+    (0x88C8, 15220), (0x88C9, 21419), (0x88CA, 15221), (0x88CB, 15222), (0x88CC, 15223), (0x88CD, 15224),
+    (0x88CE, 21421), (0x88CF, 15225), (0x88D0, 15226), (0x88D1, 15227), (0x88D2, 16901), (0x88D3, 15228),
+    (0x88D4, 15550), (0x88D5, 15868), (0x88D6, 15229), (0x88D7, 15230), (0x88D8, 21980), (0x88D9, 13610),
+    (0x88DA, 15231), (0x88DB, 15232), (0x88DC, 15233), (0x88DD, 15234), (0x88DE, 15235), (0x88DF, 21981),
+    (0x88E0, 15236), (0x88E1, 15237), (0x88E2, 21420), (0x88E3, 21422), (0x88E4, 11942), (0x88E5, 21423),
+    (0x88E6, 15238), (0x88E7, 15239), (0x88E8, 21427), (0x88E9, 15240), (0x88EA, 15241), (0x88EB, 15242),
+    (0x88EC, 15243), (0x88ED, 15244), (0x88EE, 15245), (0x88EF, 15246), (0x88F0, 21429), (0x88F1, 21424),
+    (0x88F2, 15247), (0x88F3, 13824), (0x88F4, 13080), (0x88F5, 15248), (0x88F6, 15249), (0x88F7, 15250),
+    (0x88F8, 12512), (0x88F9, 10827), (0x88FA, 15251), (0x88FB, 15252), (0x88FC, 21426), (0x88FD, 15253),
+    (0x88FE, 21428), (0x88FF, 15254), (0x8900, 15255), (0x8901, 15256), (0x8902, 10786), (0x8903, 15257),
+    (0x8904, 15258), (0x8905, 15259), (0x8906, 15260), (0x8907, 15261), (0x8908, 15262), (0x8909, 15263),
+    (0x890A, 21434), (0x890B, 15264), (0x890C, 15265), (0x890D, 15266), (0x890E, 15267), (0x890F, 15268),
+    (0x8910, 10979), (0x8911, 15269), (0x8912, 9118), (0x8913, 21432), (0x8914, 15270), (0x8915, 15271),
+    (0x8916, 15272), (0x8917, 15273), (0x8918, 15274), (0x8919, 21431), (0x891A, 21425), (0x891B, 21433),
+    (0x891C, 15275), (0x891D, 15276), (0x891E, 15277), (0x891F, 15278), (0x8920, 15279), (0x8921, 21430),
+    (0x8922, 15280), (0x8923, 15281), (0x8924, 15282), (0x8925, 13661), (0x8926, 15283), (0x8927, 15284),
+    (0x8928, 15285), (0x8929, 15286), (0x892A, 14577), (0x892B, 21436), (0x892C, 15287), (0x892D, 15288),
+    (0x892E, 15289), (0x892F, 15290), (0x8930, 19124), (0x8931, 15291), (0x8932, 15292), (0x8933, 15293),
+    (0x8934, 21435), (0x8935, 15294), (0x8936, 21437), (0x8937, 15295), (0x8938, 15390), (0x8939, 15391),
+    (0x893A, 15392), (0x893B, 15393), (0x893C, 15394), (0x893D, 15395), (0x893E, 15396), (0x893F, 15397),
+    (0x8940, 15398), (0x8941, 21438), (0x8942, 15399), (0x8943, 15400), (0x8944, 14984), (0x8945, 15401),
+    (0x8946, 15402), (0x8947, 15403), (0x8948, 15404), (0x8949, 15405), (0x894A, 15406), (0x894B, 15407),
+    (0x894C, 15408), (0x894D, 15409), (0x894E, 15410), (0x894F, 15411), (0x8950, 15412), (0x8951, 15413),
+    (0x8952, 15414), (0x8953, 15415), (0x8954, 15416), (0x8955, 15417), (0x8956, 15418), (0x8957, 15419),
+    (0x8958, 15420), (0x8959, 15421), (0x895A, 15422), (0x895B, 15423), (0x895C, 15424), (0x895D, 15425),
+    (0x895E, 21982), (0x895F, 11578), (0x8960, 15426), (0x8961, 15427), (0x8962, 15428), (0x8963, 15429),
+    (0x8964, 15430), (0x8965, 15431), (0x8966, 21439), (0x8967, 15432), (0x8968, 15433), (0x8969, 15434),
+    (0x896A, 15435), (0x896B, 15436), (0x896C, 15437), (0x896D, 15438), (0x896E, 15439), (0x896F, 15440),
+    (0x8970, 15441), (0x8971, 15442), (0x8972, 15443), (0x8973, 15444), (0x8974, 15445), (0x8975, 15446),
+    (0x8976, 15447), (0x8977, 15448), (0x8978, 15449), (0x8979, 15450), (0x897A, 15451), (0x897B, 21440),
+    (0x897C, 15452), (0x897D, 15453), (0x897E, 15454), (0x897F, 14812), (0x8980, 15455), (0x8981, 15495),
+    (0x8982, 15456), (0x8983, 21466), (0x8984, 15457), (0x8985, 15458), (0x8986, 10563), (0x8987, 15459),
+    (0x8988, 15460), (0x8989, 15461), (0x898A, 15462), (0x898B, 15463), (0x898C, 15464), (0x898D, 15465),
+    (0x898E, 15466), (0x898F, 15467), (0x8990, 15468), (0x8991, 15469), (0x8992, 15470), (0x8993, 15471),
+    (0x8994, 15472), (0x8995, 15473), (0x8996, 15474), (0x8997, 15475), (0x8998, 15476), (0x8999, 15477),
+    (0x899A, 15478), (0x899B, 15479), (0x899C, 15480), (0x899D, 15481), (0x899E, 15482), (0x899F, 15483),
+    (0x89A0, 15484), (0x89A1, 15485), (0x89A2, 15580), (0x89A3, 15581), (0x89A4, 15582), (0x89A5, 15583),
+    (0x89A6, 15584), (0x89A7, 15585), (0x89A8, 15586), (0x89A9, 15587), (0x89AA, 15588), (0x89AB, 15589),
+    (0x89AC, 15590), (0x89AD, 15591), (0x89AE, 15592), (0x89AF, 15593), (0x89B0, 15594), (0x89B1, 15595),
+    (0x89B2, 15596), (0x89B3, 15597), (0x89B4, 15598), (0x89B5, 15599), (0x89B6, 15600), (0x89B7, 15601),
+    (0x89B8, 15602), (0x89B9, 15603), (0x89BA, 15604), (0x89BB, 15605), (0x89BC, 15606), (0x89BD, 15607),
+    (0x89BE, 15608), (0x89BF, 15609), (0x89C0, 15610), (0x89C1, 11396), (0x89C2, 10794), (0x89C3, 15611),
+    (0x89C4, 10805), (0x89C5, 12692), (0x89C6, 14016), (0x89C7, 20117), (0x89C8, 12097), (0x89C9, 11770),
+    (0x89CA, 20118), (0x89CB, 20119), (0x89CC, 20120), (0x89CD, 15612), (0x89CE, 20121), (0x89CF, 20122),
+    (0x89D0, 20123), (0x89D1, 20124), (0x89D2, 11534), (0x89D3, 15613), (0x89D4, 15614), (0x89D5, 15615),
+    (0x89D6, 22226), (0x89D7, 15616), (0x89D8, 15617), (0x89D9, 15618), (0x89DA, 22228), (0x89DB, 15619),
+    (0x89DC, 22229), (0x89DD, 15620), (0x89DE, 22227), (0x89DF, 15621), (0x89E0, 15622), (0x89E1, 15623),
+    (0x89E2, 15624), (0x89E3, 11561), (0x89E4, 15625), (0x89E5, 22326), (0x89E6, 9790), (0x89E7, 15626),
+    (0x89E8, 15627), (0x89E9, 15628), (0x89EA, 15629), (0x89EB, 22327), (0x89EC, 15630), (0x89ED, 15631),
+    (0x89EE, 15632), (0x89EF, 22328), (0x89F0, 15633), (0x89F1, 15634), (0x89F2, 15635), (0x89F3, 20443),
+    (0x89F4, 15636), (0x89F5, 15637), (0x89F6, 15638), (0x89F7, 15639), (0x89F8, 15640), (0x89F9, 15641),
+    (0x89FA, 15642), (0x89FB, 15643), (0x89FC, 15644), (0x89FD, 15645), (0x89FE, 15646), (0x89FF, 15647),
+    (0x8A00, 15347), (0x8A01, 15648), (0x8A02, 15649), (0x8A03, 15650), (0x8A04, 15651), (0x8A05, 15652),
+    (0x8A06, 15653), (0x8A07, 16889), (0x8A08, 15654), (0x8A09, 15655), (0x8A0A, 15656), (0x8A0B, 15657),
+    (0x8A0C, 15658), (0x8A0D, 15659), (0x8A0E, 15660), (0x8A0F, 15661), (0x8A10, 15662), (0x8A11, 15663),
+    (0x8A12, 15664), (0x8A13, 15665), (0x8A14, 15666), (0x8A15, 15667), (0x8A16, 15668), (0x8A17, 15669),
+    (0x8A18, 15670), (0x8A19, 15671), (0x8A1A, 15672), (0x8A1B, 15673), (0x8A1C, 15674), (0x8A1D, 15675),
+    (0x8A1E, 15770), (0x8A1F, 15771), (0x8A20, 15772), (0x8A21, 15773), (0x8A22, 15774), (0x8A23, 15775),
+    (0x8A24, 15776), (0x8A25, 15777), (0x8A26, 15778), (0x8A27, 15779), (0x8A28, 15780), (0x8A29, 15781),
+    (0x8A2A, 15782), (0x8A2B, 15783), (0x8A2C, 15784), (0x8A2D, 15785), (0x8A2E, 15786), (0x8A2F, 15787),
+    (0x8A30, 15788), (0x8A31, 15789), (0x8A32, 15790), (0x8A33, 15791), (0x8A34, 15792), (0x8A35, 15793),
+    (0x8A36, 15794), (0x8A37, 15795), (0x8A38, 15796), (0x8A39, 15797), (0x8A3A, 15798), (0x8A3B, 15799),
+    (0x8A3C, 15800), (0x8A3D, 15801), (0x8A3E, 22329), (0x8A3F, 15802), (0x8A40, 15803), (0x8A41, 15804),
+    (0x8A42, 15805), (0x8A43, 15806), (0x8A44, 15807), (0x8A45, 15808), (0x8A46, 15809), (0x8A47, 15810),
+    (0x8A48, 20831), (0x8A49, 15811), (0x8A4A, 15812), (0x8A4B, 15813), (0x8A4C, 15814), (0x8A4D, 15815),
+    (0x8A4E, 15816), (0x8A4F, 15817), (0x8A50, 15818), (0x8A51, 15819), (0x8A52, 15820), (0x8A53, 15821),
+    (0x8A54, 15822), (0x8A55, 15823), (0x8A56, 15824), (0x8A57, 15825), (0x8A58, 15826), (0x8A59, 15827),
+    (0x8A5A, 15828), (0x8A5B, 15829), (0x8A5C, 15830), (0x8A5D, 15831), (0x8A5E, 15832), (0x8A5F, 15833),
+    (0x8A60, 15834), (0x8A61, 15835), (0x8A62, 15836), (0x8A63, 15837), (0x8A64, 15838), (0x8A65, 15839),
+    (0x8A66, 15840), (0x8A67, 15841), (0x8A68, 15842), (0x8A69, 15843), (0x8A6A, 15844), (0x8A6B, 15845),
+    (0x8A6C, 15846), (0x8A6D, 15847), (0x8A6E, 15848), (0x8A6F, 15849), (0x8A70, 15850), (0x8A71, 15851),
+    (0x8A72, 15852), (0x8A73, 15853), (0x8A74, 15854), (0x8A75, 15855), (0x8A76, 15856), (0x8A77, 15857),
+    (0x8A78, 15858), (0x8A79, 16073), (0x8A7A, 15859), (0x8A7B, 15860), (0x8A7C, 15861), (0x8A7D, 15862),
+    (0x8A7E, 15863), (0x8A7F, 15864), (0x8A80, 15865), (0x8A81, 15960), (0x8A82, 15961), (0x8A83, 15962),
+    (0x8A84, 15963), (0x8A85, 15964), (0x8A86, 15965), (0x8A87, 15966), (0x8A88, 15967), (0x8A89, 15769),
+    (0x8A8A, 14405), (0x8A8B, 15968), (0x8A8C, 15969), (0x8A8D, 15970), (0x8A8E, 15971), (0x8A8F, 15972),
+    (0x8A90, 15973), (0x8A91, 15974), (0x8A92, 15975), (0x8A93, 14001), (0x8A94, 15976), (0x8A95, 15977),
+    (0x8A96, 15978), (0x8A97, 15979), (0x8A98, 15980), (0x8A99, 15981), (0x8A9A, 15982), (0x8A9B, 15983),
+    (0x8A9C, 15984), (0x8A9D, 15985), (0x8A9E, 15986), (0x8A9F, 15987), (0x8AA0, 15988), (0x8AA1, 15989),
+    (0x8AA2, 15990), (0x8AA3, 15991), (0x8AA4, 15992), (0x8AA5, 15993), (0x8AA6, 15994), (0x8AA7, 15995),
+    (0x8AA8, 15996), (0x8AA9, 15997), (0x8AAA, 15998), (0x8AAB, 15999), (0x8AAC, 16000), (0x8AAD, 16001),
+    (0x8AAE, 16002), (0x8AAF, 16003), (0x8AB0, 16004), (0x8AB1, 16005), (0x8AB2, 16006), (0x8AB3, 16007),
+    (0x8AB4, 16008), (0x8AB5, 16009), (0x8AB6, 16010), (0x8AB7, 16011), (0x8AB8, 16012), (0x8AB9, 16013),
+    (0x8ABA, 16014), (0x8ABB, 16015), (0x8ABC, 16016), (0x8ABD, 16017), (0x8ABE, 16018), (0x8ABF, 16019),
+    (0x8AC0, 16020), (0x8AC1, 16021), (0x8AC2, 16022), (0x8AC3, 16023), (0x8AC4, 16024), (0x8AC5, 16025),
+    (0x8AC6, 16026), (0x8AC7, 16027), (0x8AC8, 16028), (0x8AC9, 16029), (0x8ACA, 16030), (0x8ACB, 16031),
+    (0x8ACC, 16032), (0x8ACD, 16033), (0x8ACE, 16034), (0x8ACF, 16035), (0x8AD0, 16036), (0x8AD1, 16037),
+    (0x8AD2, 16038), (0x8AD3, 16039), (0x8AD4, 16040), (0x8AD5, 16041), (0x8AD6, 16042), (0x8AD7, 16043),
+    (0x8AD8, 16044), (0x8AD9, 16045), (0x8ADA, 16046), (0x8ADB, 16047), (0x8ADC, 16048), (0x8ADD, 16049),
+    (0x8ADE, 16050), (0x8ADF, 16051), (0x8AE0, 16052), (0x8AE1, 16053), (0x8AE2, 16054), (0x8AE3, 16055),
+    (0x8AE4, 16150), (0x8AE5, 16151), (0x8AE6, 16152), (0x8AE7, 16153), (0x8AE8, 16154), (0x8AE9, 16155),
+    (0x8AEA, 16156), (0x8AEB, 16157), (0x8AEC, 16158), (0x8AED, 16159), (0x8AEE, 16160), (0x8AEF, 16161),
+    (0x8AF0, 16162), (0x8AF1, 16163), (0x8AF2, 16164), (0x8AF3, 16165), (0x8AF4, 16166), (0x8AF5, 16167),
+    (0x8AF6, 16168), (0x8AF7, 16169), (0x8AF8, 16170), (0x8AF9, 16171), (0x8AFA, 16172), (0x8AFB, 16173),
+    (0x8AFC, 16174), (0x8AFD, 16175), (0x8AFE, 16176), (0x8AFF, 16177), (0x8B00, 16178), (0x8B01, 16179),
+    (0x8B02, 16180), (0x8B03, 16181), (0x8B04, 16182), (0x8B05, 16183), (0x8B06, 16184), (0x8B07, 19127),
+    (0x8B08, 16185), (0x8B09, 16186), (0x8B0A, 16187), (0x8B0B, 16188), (0x8B0C, 16189), (0x8B0D, 16190),
+    (0x8B0E, 16191), (0x8B0F, 16192), (0x8B10, 16193), (0x8B11, 16194), (0x8B12, 16195), (0x8B13, 16196),
+    (0x8B14, 16197), (0x8B15, 16198), (0x8B16, 16199), (0x8B17, 16200), (0x8B18, 16201), (0x8B19, 16202),
+    (0x8B1A, 16203), (0x8B1B, 16204), (0x8B1C, 16205), (0x8B1D, 16206), (0x8B1E, 16207), (0x8B1F, 16208),
+    (0x8B20, 16209), (0x8B21, 16210), (0x8B22, 16211), (0x8B23, 16212), (0x8B24, 16213), (0x8B25, 16214),
+    (0x8B26, 22330), (0x8B27, 16215), (0x8B28, 16216), (0x8B29, 16217), (0x8B2A, 16218), (0x8B2B, 16219),
+    (0x8B2C, 16220), (0x8B2D, 16221), (0x8B2E, 16222), (0x8B2F, 16223), (0x8B30, 16224), (0x8B31, 16225),
+    (0x8B32, 16226), (0x8B33, 16227), (0x8B34, 16228), (0x8B35, 16229), (0x8B36, 16230), (0x8B37, 16231),
+    (0x8B38, 16232), (0x8B39, 16233), (0x8B3A, 16234), (0x8B3B, 16235), (0x8B3C, 16236), (0x8B3D, 16237),
+    (0x8B3E, 16238), (0x8B3F, 16239), (0x8B40, 16240), (0x8B41, 16241), (0x8B42, 16242), (0x8B43, 16243),
+    (0x8B44, 16244), (0x8B45, 16245), (0x8B46, 16340), (0x8B47, 16341), (0x8B48, 16342), (0x8B49, 16343),
+    (0x8B4A, 16344), (0x8B4B, 16345), (0x8B4C, 16346), (0x8B4D, 16347), (0x8B4E, 16348), (0x8B4F, 16349),
+    (0x8B50, 16350), (0x8B51, 16351), (0x8B52, 16352), (0x8B53, 16353), (0x8B54, 16354), (0x8B55, 16355),
+    (0x8B56, 16356), (0x8B57, 16357), (0x8B58, 16358), (0x8B59, 16359), (0x8B5A, 16360), (0x8B5B, 16361),
+    (0x8B5C, 16362), (0x8B5D, 16363), (0x8B5E, 16364), (0x8B5F, 16365), (0x8B60, 16366), (0x8B61, 16367),
+    (0x8B62, 16368), (0x8B63, 16369), (0x8B64, 16370), (0x8B65, 16371), (0x8B66, 11700), (0x8B67, 16372),
+    (0x8B68, 16373), (0x8B69, 16374), (0x8B6A, 16375), (0x8B6B, 16376), (0x8B6C, 13214), (0x8B6D, 16377),
+    (0x8B6E, 16378), (0x8B6F, 16379), (0x8B70, 16380), (0x8B71, 16381), (0x8B72, 16382), (0x8B73, 16383),
+    (0x8B74, 16384), (0x8B75, 16385), (0x8B76, 16386), (0x8B77, 16387), (0x8B78, 16388), (0x8B79, 16389),
+    (0x8B7A, 16390), (0x8B7B, 16391), (0x8B7C, 16392), (0x8B7D, 16393), (0x8B7E, 16394), (0x8B7F, 16395),
+    (0x8B80, 16396), (0x8B81, 16397), (0x8B82, 16398), (0x8B83, 16399), (0x8B84, 16400), (0x8B85, 16401),
+    (0x8B86, 16402), (0x8B87, 16403), (0x8B88, 16404), (0x8B89, 16405), (0x8B8A, 16406), (0x8B8B, 16407),
+    (0x8B8C, 16408), (0x8B8D, 16409), (0x8B8E, 16410), (0x8B8F, 16411), (0x8B90, 16412), (0x8B91, 16413),
+    (0x8B92, 16414), (0x8B93, 16415), (0x8B94, 16416), (0x8B95, 16417), (0x8B96, 16418), (0x8B97, 16419),
+    (0x8B98, 16420), (0x8B99, 16421), (0x8B9A, 16422), (0x8B9B, 16423), (0x8B9C, 16424), (0x8B9D, 16425),
+    (0x8B9E, 16426), (0x8B9F, 16427), (0x8BA0, 17010), (0x8BA1, 11343), (0x8BA2, 10174), (0x8BA3, 10573),
+    (0x8BA4, 13632), (0x8BA5, 11310), (0x8BA6, 17011), (0x8BA7, 17012), (0x8BA8, 14399), (0x8BA9, 13620),
+    (0x8BAA, 17013), (0x8BAB, 13298), (0x8BAC, 16428), (0x8BAD, 15316), (0x8BAE, 15558), (0x8BAF, 15317),
+    (0x8BB0, 11344), (0x8BB1, 16429), (0x8BB2, 11513), (0x8BB3, 11183), (0x8BB4, 17014), (0x8BB5, 17015),
+    (0x8BB6, 15335), (0x8BB7, 17016), (0x8BB8, 15182), (0x8BB9, 10244), (0x8BBA, 12504), (0x8BBB, 16430),
+    (0x8BBC, 14202), (0x8BBD, 10432), (0x8BBE, 13847), (0x8BBF, 10390), (0x8BC0, 11772), (0x8BC1, 16249),
+    (0x8BC2, 17017), (0x8BC3, 17018), (0x8BC4, 13237), (0x8BC5, 16506), (0x8BC6, 13987), (0x8BC7, 16431),
+    (0x8BC8, 16064), (0x8BC9, 14218), (0x8BCA, 16134), (0x8BCB, 17019), (0x8BCC, 16308), (0x8BCD, 9827),
+    (0x8BCE, 17021), (0x8BCF, 17020), (0x8BD0, 16432), (0x8BD1, 15560), (0x8BD2, 17022), (0x8BD3, 17023),
+    (0x8BD4, 17024), (0x8BD5, 14017), (0x8BD6, 17025), (0x8BD7, 13976), (0x8BD8, 17026), (0x8BD9, 17027),
+    (0x8BDA, 9642), (0x8BDB, 16324), (0x8BDC, 17028), (0x8BDD, 11131), (0x8BDE, 9989), (0x8BDF, 17029),
+    (0x8BE0, 17030), (0x8BE1, 10813), (0x8BE2, 15310), (0x8BE3, 15557), (0x8BE4, 17031), (0x8BE5, 10580),
+    (0x8BE6, 14989), (0x8BE7, 9484), (0x8BE8, 17032), (0x8BE9, 17033), (0x8BEA, 16433), (0x8BEB, 11570),
+    (0x8BEC, 14785), (0x8BED, 15754), (0x8BEE, 17034), (0x8BEF, 14808), (0x8BF0, 17035), (0x8BF1, 15728),
+    (0x8BF2, 11184), (0x8BF3, 17036), (0x8BF4, 14176), (0x8BF5, 14203), (0x8BF6, 17037), (0x8BF7, 13470),
+    (0x8BF8, 16323), (0x8BF9, 17038), (0x8BFA, 13036), (0x8BFB, 10198), (0x8BFC, 17039), (0x8BFD, 10399),
+    (0x8BFE, 11921), (0x8BFF, 17040), (0x8C00, 17041), (0x8C01, 14168), (0x8C02, 17042), (0x8C03, 10062),
+    (0x8C04, 17043), (0x8C05, 12289), (0x8C06, 16462), (0x8C07, 17044), (0x8C08, 14369), (0x8C09, 16434),
+    (0x8C0A, 15559), (0x8C0B, 12842), (0x8C0C, 17045), (0x8C0D, 10068), (0x8C0E, 11164), (0x8C0F, 17046),
+    (0x8C10, 15124), (0x8C11, 17047), (0x8C12, 17048), (0x8C13, 14754), (0x8C14, 17049), (0x8C15, 17050),
+    (0x8C16, 17051), (0x8C17, 9492), (0x8C18, 17054), (0x8C19, 17052), (0x8C1A, 15367), (0x8C1B, 17053),
+    (0x8C1C, 12688), (0x8C1D, 17055), (0x8C1E, 16435), (0x8C1F, 17056), (0x8C20, 17057), (0x8C21, 17058),
+    (0x8C22, 15132), (0x8C23, 15490), (0x8C24, 9114), (0x8C25, 17059), (0x8C26, 13406), (0x8C27, 17060),
+    (0x8C28, 11582), (0x8C29, 12636), (0x8C2A, 17061), (0x8C2B, 17062), (0x8C2C, 12728), (0x8C2D, 14368),
+    (0x8C2E, 17063), (0x8C2F, 17064), (0x8C30, 12095), (0x8C31, 13260), (0x8C32, 17065), (0x8C33, 17066),
+    (0x8C34, 13415), (0x8C35, 17067), (0x8C36, 17068), (0x8C37, 10775), (0x8C38, 16530), (0x8C39, 16531),
+    (0x8C3A, 16532), (0x8C3B, 16533), (0x8C3C, 16534), (0x8C3D, 16535), (0x8C3E, 16536), (0x8C3F, 16537),
+    (0x8C40, 16538), (0x8C41, 11192), (0x8C42, 16539), (0x8C43, 16540), (0x8C44, 16541), (0x8C45, 16542),
+    (0x8C46, 10190), (0x8C47, 22033), (0x8C48, 16543), (0x8C49, 22034), (0x8C4A, 16544), (0x8C4B, 16545),
+    (0x8C4C, 14602), (0x8C4D, 16546), (0x8C4E, 16547), (0x8C4F, 16548), (0x8C50, 16549), (0x8C51, 16550),
+    (0x8C52, 16551), (0x8C53, 16552), (0x8C54, 16553), (0x8C55, 22160), (0x8C56, 16554), (0x8C57, 16555),
+    (0x8C58, 16556), (0x8C59, 16557), (0x8C5A, 20299), (0x8C5B, 16558), (0x8C5C, 16559), (0x8C5D, 16560),
+    (0x8C5E, 16561), (0x8C5F, 16562), (0x8C60, 16563), (0x8C61, 14998), (0x8C62, 11146), (0x8C63, 16564),
+    (0x8C64, 16565), (0x8C65, 16566), (0x8C66, 16567), (0x8C67, 16568), (0x8C68, 16569), (0x8C69, 16570),
+    (0x8C6A, 10957), (0x8C6B, 15870), (0x8C6C, 16571), (0x8C6D, 16572), (0x8C6E, 16573), (0x8C6F, 16574),
+    (0x8C70, 16575), (0x8C71, 16576), (0x8C72, 16577), (0x8C73, 18392), (0x8C74, 16578), (0x8C75, 16579),
+    (0x8C76, 16580), (0x8C77, 16581), (0x8C78, 22219), (0x8C79, 9225), (0x8C7A, 9487), (0x8C7B, 16582),
+    (0x8C7C, 16583), (0x8C7D, 16584), (0x8C7E, 16585), (0x8C7F, 16586), (0x8C80, 16587), (0x8C81, 16588),
+    (0x8C82, 22220), (0x8C83, 16589), (0x8C84, 16590), (0x8C85, 22222), (0x8C86, 16591), (0x8C87, 16592),
+    (0x8C88, 16593), (0x8C89, 10974), (0x8C8A, 22221), (0x8C8B, 16594), (0x8C8C, 12653), (0x8C8D, 16595),
+    (0x8C8E, 16596), (0x8C8F, 16597), (0x8C90, 16598), (0x8C91, 16599), (0x8C92, 16600), (0x8C93, 16601),
+    (0x8C94, 22224), (0x8C95, 16602), (0x8C96, 16603), (0x8C97, 16604), (0x8C98, 22223), (0x8C99, 16605),
+    (0x8C9A, 16606), (0x8C9B, 16607), (0x8C9C, 16608), (0x8C9D, 16609), (0x8C9E, 16610), (0x8C9F, 16611),
+    (0x8CA0, 16612), (0x8CA1, 16613), (0x8CA2, 16614), (0x8CA3, 16615), (0x8CA4, 16616), (0x8CA5, 16617),
+    (0x8CA6, 16618), (0x8CA7, 16619), (0x8CA8, 16620), (0x8CA9, 16621), (0x8CAA, 16622), (0x8CAB, 16623),
+    (0x8CAC, 16624), (0x8CAD, 16625), (0x8CAE, 16720), (0x8CAF, 16721), (0x8CB0, 16722), (0x8CB1, 16723),
+    (0x8CB2, 16724), (0x8CB3, 16725), (0x8CB4, 16726), (0x8CB5, 16727), (0x8CB6, 16728), (0x8CB7, 16729),
+    (0x8CB8, 16730), (0x8CB9, 16731), (0x8CBA, 16732), (0x8CBB, 16733), (0x8CBC, 16734), (0x8CBD, 16735),
+    (0x8CBE, 16736), (0x8CBF, 16737), (0x8CC0, 16738), (0x8CC1, 16739), (0x8CC2, 16740), (0x8CC3, 16741),
+    (0x8CC4, 16742), (0x8CC5, 16743), (0x8CC6, 16744), (0x8CC7, 16745), (0x8CC8, 16746), (0x8CC9, 16747),
+    (0x8CCA, 16748), (0x8CCB, 16749), (0x8CCC, 16750), (0x8CCD, 16751), (0x8CCE, 16752), (0x8CCF, 16753),
+    (0x8CD0, 16754), (0x8CD1, 16755), (0x8CD2, 16756), (0x8CD3, 16757), (0x8CD4, 16758), (0x8CD5, 16759),
+    (0x8CD6, 16760), (0x8CD7, 16761), (0x8CD8, 16762), (0x8CD9, 16763), (0x8CDA, 16764), (0x8CDB, 16765),
+    (0x8CDC, 16766), (0x8CDD, 16767), (0x8CDE, 16768), (0x8CDF, 16769), (0x8CE0, 16770), (0x8CE1, 16771),
+    (0x8CE2, 16772), (0x8CE3, 16773), (0x8CE4, 16774), (0x8CE5, 16775), (0x8CE6, 16776), (0x8CE7, 16777),
+    (0x8CE8, 16778), (0x8CE9, 16779), (0x8CEA, 16780), (0x8CEB, 16781), (0x8CEC, 16782), (0x8CED, 16783),
+    (0x8CEE, 16784), (0x8CEF, 16785), (0x8CF0, 16786), (0x8CF1, 16787), (0x8CF2, 16788), (0x8CF3, 16789),
+    (0x8CF4, 16790), (0x8CF5, 16791), (0x8CF6, 16792), (0x8CF7, 16793), (0x8CF8, 16794), (0x8CF9, 16795),
+    (0x8CFA, 16796), (0x8CFB, 16797), (0x8CFC, 16798), (0x8CFD, 16799), (0x8CFE, 16800), (0x8CFF, 16801),
+    (0x8D00, 16802), (0x8D01, 16803), (0x8D02, 16804), (0x8D03, 16805), (0x8D04, 16806), (0x8D05, 16807),
+    (0x8D06, 16808), (0x8D07, 16809), (0x8D08, 16810), (0x8D09, 16811), (0x8D0A, 16812), (0x8D0B, 16813),
+    (0x8D0C, 16814), (0x8D0D, 16815), (0x8D0E, 16910), (0x8D0F, 16911), (0x8D10, 16912), (0x8D11, 16913),
+    (0x8D12, 16914), (0x8D13, 16915), (0x8D14, 16916), (0x8D15, 16917), (0x8D16, 16918), (0x8D17, 16919),
+    (0x8D18, 16920), (0x8D19, 16921), (0x8D1A, 16922), (0x8D1B, 16923), (0x8D1C, 16924), (0x8D1D, 9235),
+    (0x8D1E, 16129), (0x8D1F, 10571), (0x8D20, 16925), (0x8D21, 10752), (0x8D22, 9443), (0x8D23, 15945),
+    (0x8D24, 14960), (0x8D25, 9085), (0x8D26, 16098), (0x8D27, 11200), (0x8D28, 16287), (0x8D29, 10378),
+    (0x8D2A, 14361), (0x8D2B, 13227), (0x8D2C, 9280), (0x8D2D, 10761), (0x8D2E, 16337), (0x8D2F, 10800),
+    (0x8D30, 10356), (0x8D31, 11395), (0x8D32, 20103), (0x8D33, 20104), (0x8D34, 14434), (0x8D35, 10818),
+    (0x8D36, 20105), (0x8D37, 9876), (0x8D38, 12654), (0x8D39, 10404), (0x8D3A, 10981), (0x8D3B, 20106),
+    (0x8D3C, 15949), (0x8D3D, 20107), (0x8D3E, 11359), (0x8D3F, 11178), (0x8D40, 20108), (0x8D41, 12317),
+    (0x8D42, 12469), (0x8D43, 15928), (0x8D44, 16477), (0x8D45, 20109), (0x8D46, 20110), (0x8D47, 20113),
+    (0x8D48, 20111), (0x8D49, 20112), (0x8D4A, 13837), (0x8D4B, 10564), (0x8D4C, 10201), (0x8D4D, 20114),
+    (0x8D4E, 14039), (0x8D4F, 13820), (0x8D50, 9830), (0x8D51, 16926), (0x8D52, 16927), (0x8D53, 18582),
+    (0x8D54, 13081), (0x8D55, 20115), (0x8D56, 12086), (0x8D57, 16928), (0x8D58, 16459), (0x8D59, 20116),
+    (0x8D5A, 16447), (0x8D5B, 13677), (0x8D5C, 16676), (0x8D5D, 16670), (0x8D5E, 15927), (0x8D5F, 16929),
+    (0x8D60, 15954), (0x8D61, 13811), (0x8D62, 15689), (0x8D63, 10596), (0x8D64, 9659), (0x8D65, 16930),
+    (0x8D66, 13841), (0x8D67, 22031), (0x8D68, 16931), (0x8D69, 16932), (0x8D6A, 16933), (0x8D6B, 10978),
+    (0x8D6C, 16934), (0x8D6D, 22032), (0x8D6E, 16935), (0x8D6F, 16936), (0x8D70, 16498), (0x8D71, 16937),
+    (0x8D72, 16938), (0x8D73, 22026), (0x8D74, 10561), (0x8D75, 16107), (0x8D76, 10592), (0x8D77, 13285),
+    (0x8D78, 16939), (0x8D79, 16940), (0x8D7A, 16941), (0x8D7B, 16942), (0x8D7C, 16943), (0x8D7D, 16944),
+    (0x8D7E, 16945), (0x8D7F, 16946), (0x8D80, 16947), (0x8D81, 9630), (0x8D82, 16948), (0x8D83, 16949),
+    (0x8D84, 22027), (0x8D85, 9607), (0x8D86, 16950), (0x8D87, 16951), (0x8D88, 16952), (0x8D89, 16953),
+    (0x8D8A, 15894), (0x8D8B, 13482), (0x8D8C, 16954), (0x8D8D, 16955), (0x8D8E, 16956), (0x8D8F, 16957),
+    (0x8D90, 16958), (0x8D91, 22029), (0x8D92, 16959), (0x8D93, 16960), (0x8D94, 22028), (0x8D95, 16961),
+    (0x8D96, 16962), (0x8D97, 16963), (0x8D98, 16964), (0x8D99, 16965), (0x8D9A, 16966), (0x8D9B, 16967),
+    (0x8D9C, 16968), (0x8D9D, 16969), (0x8D9E, 16970), (0x8D9F, 14388), (0x8DA0, 16971), (0x8DA1, 16972),
+    (0x8DA2, 16973), (0x8DA3, 13589), (0x8DA4, 16974), (0x8DA5, 16975), (0x8DA6, 16976), (0x8DA7, 16977),
+    (0x8DA8, 16978), (0x8DA9, 16979), (0x8DAA, 16980), (0x8DAB, 16981), (0x8DAC, 16982), (0x8DAD, 16983),
+    (0x8DAE, 16984), (0x8DAF, 16985), (0x8DB0, 16986), (0x8DB1, 22030), (0x8DB2, 16987), (0x8DB3, 16502),
+    (0x8DB4, 13046), (0x8DB5, 22167), (0x8DB6, 16988), (0x8DB7, 16989), (0x8DB8, 22162), (0x8DB9, 16990),
+    (0x8DBA, 22170), (0x8DBB, 16991), (0x8DBC, 22169), (0x8DBD, 16992), (0x8DBE, 16271), (0x8DBF, 22168),
+    (0x8DC0, 16993), (0x8DC1, 16994), (0x8DC2, 16995), (0x8DC3, 15895), (0x8DC4, 22171), (0x8DC5, 16996),
+    (0x8DC6, 22179), (0x8DC7, 16997), (0x8DC8, 16998), (0x8DC9, 16999), (0x8DCA, 17000), (0x8DCB, 9072),
+    (0x8DCC, 10063), (0x8DCD, 17001), (0x8DCE, 22176), (0x8DCF, 22177), (0x8DD0, 17002), (0x8DD1, 13075),
+    (0x8DD2, 17003), (0x8DD3, 17004), (0x8DD4, 17005), (0x8DD5, 17100), (0x8DD6, 22172), (0x8DD7, 22173),
+    (0x8DD8, 17101), (0x8DD9, 17102), (0x8DDA, 22174), (0x8DDB, 22178), (0x8DDC, 17103), (0x8DDD, 11749),
+    (0x8DDE, 22175), (0x8DDF, 10635), (0x8DE0, 17104), (0x8DE1, 17105), (0x8DE2, 17106), (0x8DE3, 22183),
+    (0x8DE4, 22186), (0x8DE5, 17107), (0x8DE6, 17108), (0x8DE7, 17109), (0x8DE8, 11946), (0x8DE9, 17110),
+    (0x8DEA, 10817), (0x8DEB, 22163), (0x8DEC, 22180), (0x8DED, 17111), (0x8DEE, 17112), (0x8DEF, 12468),
+    (0x8DF0, 17113), (0x8DF1, 17114), (0x8DF2, 17115), (0x8DF3, 14433), (0x8DF4, 17116), (0x8DF5, 11394),
+    (0x8DF6, 17117), (0x8DF7, 22181), (0x8DF8, 22182), (0x8DF9, 22184), (0x8DFA, 10234), (0x8DFB, 22185),
+    (0x8DFC, 17118), (0x8DFD, 22188), (0x8DFE, 17119), (0x8DFF, 17120), (0x8E00, 17121), (0x8E01, 17122),
+    (0x8E02, 17123), (0x8E03, 17124), (0x8E04, 17125), (0x8E05, 22164), (0x8E06, 17126), (0x8E07, 17127),
+    (0x8E08, 17128), (0x8E09, 22187), (0x8E0A, 15702), (0x8E0B, 17129), (0x8E0C, 9671), (0x8E0D, 17130),
+    (0x8E0E, 17131), (0x8E0F, 14349), (0x8E10, 17132), (0x8E11, 17133), (0x8E12, 17134), (0x8E13, 17135),
+    (0x8E14, 22189), (0x8E15, 17136), (0x8E16, 17137), (0x8E17, 17138), (0x8E18, 17139), (0x8E19, 17140),
+    (0x8E1A, 17141), (0x8E1B, 17142), (0x8E1C, 17143), (0x8E1D, 22190), (0x8E1E, 11750), (0x8E1F, 22191),
+    (0x8E20, 17144), (0x8E21, 17145), (0x8E22, 14408), (0x8E23, 22194), (0x8E24, 17146), (0x8E25, 17147),
+    (0x8E26, 17148), (0x8E27, 17149), (0x8E28, 17150), (0x8E29, 9445), (0x8E2A, 16492), (0x8E2B, 17151),
+    (0x8E2C, 22192), (0x8E2D, 17152), (0x8E2E, 22193), (0x8E2F, 22195), (0x8E30, 17153), (0x8E31, 22201),
+    (0x8E32, 17154), (0x8E33, 17155), (0x8E34, 17156), (0x8E35, 22199), (0x8E36, 17157), (0x8E37, 17158),
+    (0x8E38, 17159), (0x8E39, 22198), (0x8E3A, 22196), (0x8E3B, 17160), (0x8E3C, 17161), (0x8E3D, 22200),
+    (0x8E3E, 17162), (0x8E3F, 17163), (0x8E40, 22197), (0x8E41, 22203), (0x8E42, 22204), (0x8E43, 17164),
+    (0x8E44, 14412), (0x8E45, 17165), (0x8E46, 17166), (0x8E47, 19126), (0x8E48, 9999), (0x8E49, 22202),
+    (0x8E4A, 22207), (0x8E4B, 14348), (0x8E4C, 17167), (0x8E4D, 17168), (0x8E4E, 17169), (0x8E4F, 17170),
+    (0x8E50, 17171), (0x8E51, 22205), (0x8E52, 22206), (0x8E53, 17172), (0x8E54, 17173), (0x8E55, 17174),
+    (0x8E56, 17175), (0x8E57, 17176), (0x8E58, 17177), (0x8E59, 22165), (0x8E5A, 17178), (0x8E5B, 17179),
+    (0x8E5C, 17180), (0x8E5D, 17181), (0x8E5E, 17182), (0x8E5F, 17183), (0x8E60, 17184), (0x8E61, 17185),
+    (0x8E62, 17186), (0x8E63, 17187), (0x8E64, 17188), (0x8E65, 17189), (0x8E66, 9251), (0x8E67, 17190),
+    (0x8E68, 17191), (0x8E69, 22166), (0x8E6A, 17192), (0x8E6B, 17193), (0x8E6C, 10012), (0x8E6D, 9473),
+    (0x8E6E, 17194), (0x8E6F, 22211), (0x8E70, 22208), (0x8E71, 17195), (0x8E72, 10220), (0x8E73, 17290),
+    (0x8E74, 22212), (0x8E75, 17291), (0x8E76, 22209), (0x8E77, 17292), (0x8E78, 17293), (0x8E79, 17294),
+    (0x8E7A, 17295), (0x8E7B, 17296), (0x8E7C, 22210), (0x8E7D, 17297), (0x8E7E, 17298), (0x8E7F, 9843),
+    (0x8E80, 17299), (0x8E81, 15939), (0x8E82, 17300), (0x8E83, 17301), (0x8E84, 17302), (0x8E85, 22213),
+    (0x8E86, 17303), (0x8E87, 9684), (0x8E88, 17304), (0x8E89, 17305), (0x8E8A, 17306), (0x8E8B, 17307),
+    (0x8E8C, 17308), (0x8E8D, 17309), (0x8E8E, 17310), (0x8E8F, 22214), (0x8E90, 22216), (0x8E91, 17311),
+    (0x8E92, 17312), (0x8E93, 17313), (0x8E94, 22215), (0x8E95, 17314), (0x8E96, 17315), (0x8E97, 17316),
+    (0x8E98, 17317), (0x8E99, 17318), (0x8E9A, 17319), (0x8E9B, 17320), (0x8E9C, 22217), (0x8E9D, 17321),
+    (0x8E9E, 22218), (0x8E9F, 17322), (0x8EA0, 17323), (0x8EA1, 17324), (0x8EA2, 17325), (0x8EA3, 17326),
+    (0x8EA4, 17327), (0x8EA5, 17328), (0x8EA6, 17329), (0x8EA7, 17330), (0x8EA8, 17331), (0x8EA9, 17332),
+    (0x8EAA, 17333), (0x8EAB, 13852), (0x8EAC, 10745), (0x8EAD, 17334), (0x8EAE, 17335), (0x8EAF, 13486),
+    (0x8EB0, 17336), (0x8EB1, 17337), (0x8EB2, 10232), (0x8EB3, 17338), (0x8EB4, 17339), (0x8EB5, 17340),
+    (0x8EB6, 17341), (0x8EB7, 17342), (0x8EB8, 17343), (0x8EB9, 17344), (0x8EBA, 14386), (0x8EBB, 17345),
+    (0x8EBC, 17346), (0x8EBD, 17347), (0x8EBE, 17348), (0x8EBF, 17349), (0x8EC0, 17350), (0x8EC1, 17351),
+    (0x8EC2, 17352), (0x8EC3, 17353), (0x8EC4, 17354), (0x8EC5, 17355), (0x8EC6, 17356), (0x8EC7, 17357),
+    (0x8EC8, 17358), (0x8EC9, 17359), (0x8ECA, 17360), (0x8ECB, 17361), (0x8ECC, 17362), (0x8ECD, 17363),
+    (0x8ECE, 20051), (0x8ECF, 17364), (0x8ED0, 17365), (0x8ED1, 17366), (0x8ED2, 17367), (0x8ED3, 17368),
+    (0x8ED4, 17369), (0x8ED5, 17370), (0x8ED6, 17371), (0x8ED7, 17372), (0x8ED8, 17373), (0x8ED9, 17374),
+    (0x8EDA, 17375), (0x8EDB, 17376), (0x8EDC, 17377), (0x8EDD, 17378), (0x8EDE, 17379), (0x8EDF, 17380),
+    (0x8EE0, 17381), (0x8EE1, 17382), (0x8EE2, 17383), (0x8EE3, 17384), (0x8EE4, 17385), (0x8EE5, 17480),
+    (0x8EE6, 17481), (0x8EE7, 17482), (0x8EE8, 17483), (0x8EE9, 17484), (0x8EEA, 17485), (0x8EEB, 17486),
+    (0x8EEC, 17487), (0x8EED, 17488), (0x8EEE, 17489), (0x8EEF, 17490), (0x8EF0, 17491), (0x8EF1, 17492),
+    (0x8EF2, 17493), (0x8EF3, 17494), (0x8EF4, 17495), (0x8EF5, 17496), (0x8EF6, 17497), (0x8EF7, 17498),
+    (0x8EF8, 17499), (0x8EF9, 17500), (0x8EFA, 17501), (0x8EFB, 17502), (0x8EFC, 17503), (0x8EFD, 17504),
+    (0x8EFE, 17505), (0x8EFF, 17506), (0x8F00, 17507), (0x8F01, 17508), (0x8F02, 17509), (0x8F03, 17510),
+    (0x8F04, 17511), (0x8F05, 17512), (0x8F06, 17513), (0x8F07, 17514), (0x8F08, 17515), (0x8F09, 17516),
+    (0x8F0A, 17517), (0x8F0B, 17518), (0x8F0C, 17519), (0x8F0D, 17520), (0x8F0E, 17521), (0x8F0F, 17522),
+    (0x8F10, 17523), (0x8F11, 17524), (0x8F12, 17525), (0x8F13, 17526), (0x8F14, 17527), (0x8F15, 17528),
+    (0x8F16, 17529), (0x8F17, 17530), (0x8F18, 17531), (0x8F19, 17532), (0x8F1A, 17533), (0x8F1B, 17534),
+    (0x8F1C, 17535), (0x8F1D, 17536), (0x8F1E, 17537), (0x8F1F, 17538), (0x8F20, 17539), (0x8F21, 17540),
+    (0x8F22, 17541), (0x8F23, 17542), (0x8F24, 17543), (0x8F25, 17544), (0x8F26, 17545), (0x8F27, 17546),
+    (0x8F28, 17547), (0x8F29, 17548), (0x8F2A, 17549), (0x8F2B, 17550), (0x8F2C, 17551), (0x8F2D, 17552),
+    (0x8F2E, 17553), (0x8F2F, 17554), (0x8F30, 17555), (0x8F31, 17556), (0x8F32, 17557), (0x8F33, 17558),
+    (0x8F34, 17559), (0x8F35, 17560), (0x8F36, 17561), (0x8F37, 17562), (0x8F38, 17563), (0x8F39, 17564),
+    (0x8F3A, 17565), (0x8F3B, 17566), (0x8F3C, 17567), (0x8F3D, 17568), (0x8F3E, 17569), (0x8F3F, 17570),
+    (0x8F40, 17571), (0x8F41, 17572), (0x8F42, 17573), (0x8F43, 17574), (0x8F44, 17575), (0x8F45, 17670),
+    (0x8F46, 17671), (0x8F47, 17672), (0x8F48, 17673), (0x8F49, 17674), (0x8F4A, 17675), (0x8F4B, 17676),
+    (0x8F4C, 17677), (0x8F4D, 17678), (0x8F4E, 17679), (0x8F4F, 17680), (0x8F50, 17681), (0x8F51, 17682),
+    (0x8F52, 17683), (0x8F53, 17684), (0x8F54, 17685), (0x8F55, 17686), (0x8F56, 17687), (0x8F57, 17688),
+    (0x8F58, 17689), (0x8F59, 17690), (0x8F5A, 17691), (0x8F5B, 17692), (0x8F5C, 17693), (0x8F5D, 17694),
+    (0x8F5E, 17695), (0x8F5F, 17696), (0x8F60, 17697), (0x8F61, 17698), (0x8F62, 17699), (0x8F63, 17700),
+    (0x8F64, 17701), (0x8F65, 17702), (0x8F66, 9616), (0x8F67, 15959), (0x8F68, 10811), (0x8F69, 15194),
+    (0x8F6A, 17703), (0x8F6B, 19932), (0x8F6C, 16445), (0x8F6D, 19933), (0x8F6E, 12499), (0x8F6F, 13662),
+    (0x8F70, 10993), (0x8F71, 19934), (0x8F72, 19935), (0x8F73, 19936), (0x8F74, 16310), (0x8F75, 19937),
+    (0x8F76, 19938), (0x8F77, 19940), (0x8F78, 19939), (0x8F79, 19941), (0x8F7A, 19942), (0x8F7B, 13460),
+    (0x8F7C, 19943), (0x8F7D, 15921), (0x8F7E, 19944), (0x8F7F, 11541), (0x8F80, 17704), (0x8F81, 19945),
+    (0x8F82, 19946), (0x8F83, 11542), (0x8F84, 19947), (0x8F85, 10553), (0x8F86, 12285), (0x8F87, 19948),
+    (0x8F88, 9233), (0x8F89, 11167), (0x8F8A, 10820), (0x8F8B, 19949), (0x8F8C, 17705), (0x8F8D, 20046),
+    (0x8F8E, 20047), (0x8F8F, 20048), (0x8F90, 10443), (0x8F91, 11318), (0x8F92, 17706), (0x8F93, 14033),
+    (0x8F94, 18191), (0x8F95, 15880), (0x8F96, 14944), (0x8F97, 16078), (0x8F98, 20049), (0x8F99, 16117),
+    (0x8F9A, 20050), (0x8F9B, 15138), (0x8F9C, 10763), (0x8F9D, 17707), (0x8F9E, 9824), (0x8F9F, 9272),
+    (0x8FA0, 17708), (0x8FA1, 17709), (0x8FA2, 17710), (0x8FA3, 12082), (0x8FA4, 17711), (0x8FA5, 17712),
+    (0x8FA6, 17713), (0x8FA7, 17714), (0x8FA8, 9285), (0x8FA9, 9286), (0x8FAA, 17715), (0x8FAB, 9287),
+    (0x8FAC, 17716), (0x8FAD, 17717), (0x8FAE, 17718), (0x8FAF, 17719), (0x8FB0, 9624), (0x8FB1, 13657),
+    (0x8FB2, 17720), (0x8FB3, 17721), (0x8FB4, 17722), (0x8FB5, 17723), (0x8FB6, 19128), (0x8FB7, 17724),
+    (0x8FB8, 17725), (0x8FB9, 9278), (0x8FBA, 17726), (0x8FBB, 17727), (0x8FBC, 17728), (0x8FBD, 12296),
+    (0x8FBE, 9864), (0x8FBF, 17729), (0x8FC0, 17730), (0x8FC1, 13403), (0x8FC2, 15731), (0x8FC3, 17731),
+    (0x8FC4, 13294), (0x8FC5, 15319), (0x8FC6, 17732), (0x8FC7, 10828), (0x8FC8, 12530), (0x8FC9, 17733),
+    (0x8FCA, 17734), (0x8FCB, 17735), (0x8FCC, 17736), (0x8FCD, 17737), (0x8FCE, 15688), (0x8FCF, 17738),
+    (0x8FD0, 15908), (0x8FD1, 11587), (0x8FD2, 17739), (0x8FD3, 19129), (0x8FD4, 10376), (0x8FD5, 19130),
+    (0x8FD6, 17740), (0x8FD7, 17741), (0x8FD8, 11140), (0x8FD9, 16121), (0x8FDA, 17742), (0x8FDB, 11583),
+    (0x8FDC, 15887), (0x8FDD, 14730), (0x8FDE, 12267), (0x8FDF, 9652), (0x8FE0, 17743), (0x8FE1, 17744),
+    (0x8FE2, 14431), (0x8FE3, 17745), (0x8FE4, 19133), (0x8FE5, 19131), (0x8FE6, 19135), (0x8FE7, 17746),
+    (0x8FE8, 19137), (0x8FE9, 19134), (0x8FEA, 10022), (0x8FEB, 13245), (0x8FEC, 17747), (0x8FED, 10067),
+    (0x8FEE, 19132), (0x8FEF, 17748), (0x8FF0, 14051), (0x8FF1, 17749), (0x8FF2, 17750), (0x8FF3, 19136),
+    (0x8FF4, 17751), (0x8FF5, 17752), (0x8FF6, 17753), (0x8FF7, 12687), (0x8FF8, 9252), (0x8FF9, 11308),
+    (0x8FFA, 17754), (0x8FFB, 17755), (0x8FFC, 17756), (0x8FFD, 16458), (0x8FFE, 17757), (0x8FFF, 17758),
+    (0x9000, 14578), (0x9001, 14200), (0x9002, 14007), (0x9003, 14396), (0x9004, 19139), (0x9005, 19138),
+    (0x9006, 12895), (0x9007, 17759), (0x9008, 17760), (0x9009, 15296), (0x900A, 15318), (0x900B, 19140),
+    (0x900C, 17761), (0x900D, 19143), (0x900E, 17762), (0x900F, 14559), (0x9010, 16325), (0x9011, 19142),
+    (0x9012, 10036), (0x9013, 17763), (0x9014, 14565), (0x9015, 17764), (0x9016, 19144), (0x9017, 10191),
+    (0x9018, 17765), (0x9019, 17860), (0x901A, 14543), (0x901B, 10803), (0x901C, 17861), (0x901D, 14002),
+    (0x901E, 9644), (0x901F, 14212), (0x9020, 15941), (0x9021, 19145), (0x9022, 10429), (0x9023, 17862),
+    (0x9024, 17863), (0x9025, 17864), (0x9026, 19141), (0x9027, 17865), (0x9028, 17866), (0x9029, 17867),
+    (0x902A, 17868), (0x902B, 17869), (0x902C, 17870), (0x902D, 19148), (0x902E, 9879), (0x902F, 19149),
+    (0x9030, 17871), (0x9031, 17872), (0x9032, 17873), (0x9033, 17874), (0x9034, 17875), (0x9035, 19146),
+    (0x9036, 19147), (0x9037, 17876), (0x9038, 15546), (0x9039, 17877), (0x903A, 17878), (0x903B, 12508),
+    (0x903C, 9253), (0x903D, 17879), (0x903E, 15741), (0x903F, 17880), (0x9040, 17881), (0x9041, 10226),
+    (0x9042, 14231), (0x9043, 17882), (0x9044, 19150), (0x9045, 17883), (0x9046, 17884), (0x9047, 15761),
+    (0x9048, 17885), (0x9049, 17886), (0x904A, 17887), (0x904B, 17888), (0x904C, 17889), (0x904D, 9288),
+    (0x904E, 17890), (0x904F, 10249), (0x9050, 19153), (0x9051, 19151), (0x9052, 19152), (0x9053, 10007),
+    (0x9054, 17891), (0x9055, 17892), (0x9056, 17893), (0x9057, 15522), (0x9058, 19155), (0x9059, 17894),
+    (0x905A, 17895), (0x905B, 19157), (0x905C, 17896), (0x905D, 17897), (0x905E, 17898), (0x905F, 17899),
+    (0x9060, 17900), (0x9061, 17901), (0x9062, 19156), (0x9063, 13413), (0x9064, 17902), (0x9065, 15488),
+    (0x9066, 17903), (0x9067, 17904), (0x9068, 19154), (0x9069, 17905), (0x906A, 17906), (0x906B, 17907),
+    (0x906C, 17908), (0x906D, 15931), (0x906E, 16113), (0x906F, 17909), (0x9070, 17910), (0x9071, 17911),
+    (0x9072, 17912), (0x9073, 17913), (0x9074, 19159), (0x9075, 16516), (0x9076, 17914), (0x9077, 17915),
+    (0x9078, 17916), (0x9079, 17917), (0x907A, 17918), (0x907B, 17919), (0x907C, 17920), (0x907D, 19160),
+    (0x907E, 17921), (0x907F, 9275), (0x9080, 15386), (0x9081, 17922), (0x9082, 19161), (0x9083, 19163),
+    (0x9084, 17923), (0x9085, 17924), (0x9086, 17925), (0x9087, 17926), (0x9088, 19162), (0x9089, 17927),
+    (0x908A, 17928), (0x908B, 19164), (0x908C, 17929), (0x908D, 17930), (0x908E, 17931), (0x908F, 17932),
+    (0x9090, 17933), (0x9091, 15541), (0x9092, 17934), (0x9093, 10018), (0x9094, 17935), (0x9095, 19538),
+    (0x9096, 17936), (0x9097, 17090), (0x9098, 17937), (0x9099, 17093), (0x909A, 17938), (0x909B, 17091),
+    (0x909C, 17939), (0x909D, 17092), (0x909E, 17940), (0x909F, 17941), (0x90A0, 17942), (0x90A1, 17095),
+    (0x90A2, 15152), (0x90A3, 12864), (0x90A4, 17943), (0x90A5, 17944), (0x90A6, 9103), (0x90A7, 17945),
+    (0x90A8, 17946), (0x90A9, 17947), (0x90AA, 15121), (0x90AB, 17948), (0x90AC, 17094), (0x90AD, 17949),
+    (0x90AE, 15717), (0x90AF, 10935), (0x90B0, 17197), (0x90B1, 13476), (0x90B2, 17950), (0x90B3, 17097),
+    (0x90B4, 17096), (0x90B5, 13834), (0x90B6, 17098), (0x90B7, 17951), (0x90B8, 17196), (0x90B9, 16497),
+    (0x90BA, 17099), (0x90BB, 12313), (0x90BC, 17952), (0x90BD, 17953), (0x90BE, 17200), (0x90BF, 17954),
+    (0x90C0, 17955), (0x90C1, 15759), (0x90C2, 18050), (0x90C3, 18051), (0x90C4, 17202), (0x90C5, 17199),
+    (0x90C6, 18052), (0x90C7, 17203), (0x90C8, 18053), (0x90C9, 18054), (0x90CA, 11523), (0x90CB, 18055),
+    (0x90CC, 18056), (0x90CD, 18057), (0x90CE, 12106), (0x90CF, 17198), (0x90D0, 17201), (0x90D1, 16248),
+    (0x90D2, 18058), (0x90D3, 17204), (0x90D4, 18059), (0x90D5, 18060), (0x90D6, 18061), (0x90D7, 17208),
+    (0x90D8, 18062), (0x90D9, 18063), (0x90DA, 18064), (0x90DB, 17209), (0x90DC, 17207), (0x90DD, 10959),
+    (0x90DE, 18065), (0x90DF, 18066), (0x90E0, 18067), (0x90E1, 11879), (0x90E2, 17206), (0x90E3, 18068),
+    (0x90E4, 18069), (0x90E5, 18070), (0x90E6, 17205), (0x90E7, 15904), (0x90E8, 9436), (0x90E9, 18071),
+    (0x90EA, 18072), (0x90EB, 17210), (0x90EC, 18073), (0x90ED, 10824), (0x90EE, 18074), (0x90EF, 17211),
+    (0x90F0, 18075), (0x90F1, 18076), (0x90F2, 18077), (0x90F3, 18078), (0x90F4, 9622), (0x90F5, 18079),
+    (0x90F6, 18080), (0x90F7, 18081), (0x90F8, 9981), (0x90F9, 18082), (0x90FA, 18083), (0x90FB, 18084),
+    (0x90FC, 18085), (0x90FD, 10193), (0x90FE, 17212), (0x90FF, 18086), (0x9100, 18087), (0x9101, 18088),
+    (0x9102, 10250), (0x9103, 18089), (0x9104, 17213), (0x9105, 18090), (0x9106, 18091), (0x9107, 18092),
+    (0x9108, 18093), (0x9109, 18094), (0x910A, 18095), (0x910B, 18096), (0x910C, 18097), (0x910D, 18098),
+    (0x910E, 18099), (0x910F, 18100), (0x9110, 18101), (0x9111, 18102), (0x9112, 18103), (0x9113, 18104),
+    (0x9114, 18105), (0x9115, 18106), (0x9116, 18107), (0x9117, 18108), (0x9118, 18109), (0x9119, 9256),
+    (0x911A, 18110), (0x911B, 18111), (0x911C, 18112), (0x911D, 18113), (0x911E, 17215), (0x911F, 18114),
+    (0x9120, 18115), (0x9121, 18116), (0x9122, 17214), (0x9123, 17216), (0x9124, 18117), (0x9125, 18118),
+    (0x9126, 18119), (0x9127, 18120), (0x9128, 18121), (0x9129, 18122), (0x912A, 18123), (0x912B, 18124),
+    (0x912C, 18125), (0x912D, 18126), (0x912E, 18127), (0x912F, 17218), (0x9130, 18128), (0x9131, 17217),
+    (0x9132, 18129), (0x9133, 18130), (0x9134, 18131), (0x9135, 18132), (0x9136, 18133), (0x9137, 18134),
+    (0x9138, 18135), (0x9139, 17219), (0x913A, 18136), (0x913B, 18137), (0x913C, 18138), (0x913D, 18139),
+    (0x913E, 18140), (0x913F, 18141), (0x9140, 18142), (0x9141, 18143), (0x9142, 18144), (0x9143, 17220),
+    (0x9144, 18145), (0x9145, 18240), (0x9146, 17221), (0x9147, 18241), (0x9148, 18242), (0x9149, 15722),
+    (0x914A, 22035), (0x914B, 13480), (0x914C, 16470), (0x914D, 13083), (0x914E, 22037), (0x914F, 22038),
+    (0x9150, 22036), (0x9151, 18243), (0x9152, 11723), (0x9153, 18244), (0x9154, 18245), (0x9155, 18246),
+    (0x9156, 18247), (0x9157, 15184), (0x9158, 18248), (0x9159, 18249), (0x915A, 10406), (0x915B, 18250),
+    (0x915C, 18251), (0x915D, 15910), (0x915E, 14355), (0x915F, 18252), (0x9160, 18253), (0x9161, 22137),
+    (0x9162, 22136), (0x9163, 10933), (0x9164, 22039), (0x9165, 14209), (0x9166, 18254), (0x9167, 18255),
+    (0x9168, 18256), (0x9169, 22139), (0x916A, 12115), (0x916B, 18257), (0x916C, 9669), (0x916D, 18258),
+    (0x916E, 14545), (0x916F, 22140), (0x9170, 22138), (0x9171, 11515), (0x9172, 22143), (0x9173, 18259),
+    (0x9174, 22144), (0x9175, 11540), (0x9176, 12659), (0x9177, 11940), (0x9178, 14220), (0x9179, 22145),
+    (0x917A, 18260), (0x917B, 18261), (0x917C, 18262), (0x917D, 22141), (0x917E, 22142), (0x917F, 12905),
+    (0x9180, 18263), (0x9181, 18264), (0x9182, 18265), (0x9183, 18266), (0x9184, 18267), (0x9185, 22147),
+    (0x9186, 18268), (0x9187, 9813), (0x9188, 18269), (0x9189, 16512), (0x918A, 18270), (0x918B, 9840),
+    (0x918C, 22146), (0x918D, 22149), (0x918E, 18271), (0x918F, 18272), (0x9190, 22148), (0x9191, 22150),
+    (0x9192, 15154), (0x9193, 18273), (0x9194, 18274), (0x9195, 18275), (0x9196, 18276), (0x9197, 18277),
+    (0x9198, 18278), (0x9199, 18279), (0x919A, 12684), (0x919B, 13594), (0x919C, 18280), (0x919D, 18281),
+    (0x919E, 18282), (0x919F, 18283), (0x91A0, 18284), (0x91A1, 18285), (0x91A2, 22151), (0x91A3, 22152),
+    (0x91A4, 18286), (0x91A5, 18287), (0x91A6, 18288), (0x91A7, 18289), (0x91A8, 18290), (0x91A9, 18291),
+    (0x91AA, 22153), (0x91AB, 18292), (0x91AC, 18293), (0x91AD, 22154), (0x91AE, 22155), (0x91AF, 22156),
+    (0x91B0, 18294), (0x91B1, 18295), (0x91B2, 18296), (0x91B3, 18297), (0x91B4, 22158), (0x91B5, 22157),
+    (0x91B6, 18298), (0x91B7, 18299), (0x91B8, 18300), (0x91B9, 18301), (0x91BA, 22159), (0x91BB, 18302),
+    (0x91BC, 18303), (0x91BD, 18304), (0x91BE, 18305), (0x91BF, 18306), (0x91C0, 18307), (0x91C1, 18308),
+    (0x91C2, 18309), (0x91C3, 18310), (0x91C4, 18311), (0x91C5, 18312), (0x91C6, 18313), (0x91C7, 9446),
+    (0x91C8, 18314), (0x91C9, 15727), (0x91CA, 14010), (0x91CB, 18315), (0x91CC, 12144), (0x91CD, 16301),
+    (0x91CE, 15501), (0x91CF, 12286), (0x91D0, 18316), (0x91D1, 11575), (0x91D2, 18317), (0x91D3, 18318),
+    (0x91D4, 18319), (0x91D5, 18320), (0x91D6, 18321), (0x91D7, 18322), (0x91D8, 18323), (0x91D9, 18324),
+    (0x91DA, 18325), (0x91DB, 18326), (0x91DC, 10555), (0x91DD, 18327), (0x91DE, 18328), (0x91DF, 18329),
+    (0x91E0, 18330), (0x91E1, 18331), (0x91E2, 18332), (0x91E3, 18333), (0x91E4, 18334), (0x91E5, 18335),
+    (0x91E6, 18430), (0x91E7, 18431), (0x91E8, 18432), (0x91E9, 18433), (0x91EA, 18434), (0x91EB, 18435),
+    (0x91EC, 18436), (0x91ED, 18437), (0x91EE, 18438), (0x91EF, 18439), (0x91F0, 18440), (0x91F1, 18441),
+    (0x91F2, 18442), (0x91F3, 18443), (0x91F4, 18444), (0x91F5, 18445), (0x91F6, 18446), (0x91F7, 18447),
+    (0x91F8, 18448), (0x91F9, 18449), (0x91FA, 18450), (0x91FB, 18451), (0x91FC, 18452), (0x91FD, 18453),
+    (0x91FE, 18454), (0x91FF, 18455), (0x9200, 18456), (0x9201, 18457), (0x9202, 18458), (0x9203, 18459),
+    (0x9204, 18460), (0x9205, 18461), (0x9206, 18462), (0x9207, 18463), (0x9208, 18464), (0x9209, 18465),
+    (0x920A, 18466), (0x920B, 18467), (0x920C, 18468), (0x920D, 18469), (0x920E, 18470), (0x920F, 18471),
+    (0x9210, 18472), (0x9211, 18473), (0x9212, 18474), (0x9213, 18475), (0x9214, 18476), (0x9215, 18477),
+    (0x9216, 18478), (0x9217, 18479), (0x9218, 18480), (0x9219, 18481), (0x921A, 18482), (0x921B, 18483),
+    (0x921C, 18484), (0x921D, 18485), (0x921E, 18486), (0x921F, 18487), (0x9220, 18488), (0x9221, 18489),
+    (0x9222, 18490), (0x9223, 18491), (0x9224, 18492), (0x9225, 18493), (0x9226, 18494), (0x9227, 18495),
+    (0x9228, 18496), (0x9229, 18497), (0x922A, 18498), (0x922B, 18499), (0x922C, 18500), (0x922D, 18501),
+    (0x922E, 18502), (0x922F, 18503), (0x9230, 18504), (0x9231, 18505), (0x9232, 18506), (0x9233, 18507),
+    (0x9234, 18508), (0x9235, 18509), (0x9236, 18510), (0x9237, 18511), (0x9238, 18512), (0x9239, 18513),
+    (0x923A, 18514), (0x923B, 18515), (0x923C, 18516), (0x923D, 18517), (0x923E, 18518), (0x923F, 18519),
+    (0x9240, 18520), (0x9241, 18521), (0x9242, 18522), (0x9243, 18523), (0x9244, 18524), (0x9245, 18525),
+    (0x9246, 18620), (0x9247, 18621), (0x9248, 18622), (0x9249, 18623), (0x924A, 18624), (0x924B, 18625),
+    (0x924C, 18626), (0x924D, 18627), (0x924E, 18628), (0x924F, 18629), (0x9250, 18630), (0x9251, 18631),
+    (0x9252, 18632), (0x9253, 18633), (0x9254, 18634), (0x9255, 18635), (0x9256, 18636), (0x9257, 18637),
+    (0x9258, 18638), (0x9259, 18639), (0x925A, 18640), (0x925B, 18641), (0x925C, 18642), (0x925D, 18643),
+    (0x925E, 18644), (0x925F, 18645), (0x9260, 18646), (0x9261, 18647), (0x9262, 18648), (0x9263, 18649),
+    (0x9264, 18650), (0x9265, 18651), (0x9266, 18652), (0x9267, 18653), (0x9268, 18654), (0x9269, 18655),
+    (0x926A, 18656), (0x926B, 18657), (0x926C, 18658), (0x926D, 18659), (0x926E, 18660), (0x926F, 18661),
+    (0x9270, 18662), (0x9271, 18663), (0x9272, 18664), (0x9273, 18665), (0x9274, 11393), (0x9275, 18666),
+    (0x9276, 18667), (0x9277, 18668), (0x9278, 18669), (0x9279, 18670), (0x927A, 18671), (0x927B, 18672),
+    (0x927C, 18673), (0x927D, 18674), (0x927E, 18675), (0x927F, 18676), (0x9280, 18677), (0x9281, 18678),
+    (0x9282, 18679), (0x9283, 18680), (0x9284, 18681), (0x9285, 18682), (0x9286, 18683), (0x9287, 18684),
+    (0x9288, 18685), (0x9289, 18686), (0x928A, 18687), (0x928B, 18688), (0x928C, 18689), (0x928D, 18690),
+    (0x928E, 22363), (0x928F, 18691), (0x9290, 18692), (0x9291, 18693), (0x9292, 18694), (0x9293, 18695),
+    (0x9294, 18696), (0x9295, 18697), (0x9296, 18698), (0x9297, 18699), (0x9298, 18700), (0x9299, 18701),
+    (0x929A, 18702), (0x929B, 18703), (0x929C, 18704), (0x929D, 18705), (0x929E, 18706), (0x929F, 18707),
+    (0x92A0, 18708), (0x92A1, 18709), (0x92A2, 18710), (0x92A3, 18711), (0x92A4, 18712), (0x92A5, 18713),
+    (0x92A6, 18714), (0x92A7, 18715), (0x92A8, 18810), (0x92A9, 18811), (0x92AA, 18812), (0x92AB, 18813),
+    (0x92AC, 18814), (0x92AD, 18815), (0x92AE, 22364), (0x92AF, 18816), (0x92B0, 18817), (0x92B1, 18818),
+    (0x92B2, 18819), (0x92B3, 18820), (0x92B4, 18821), (0x92B5, 18822), (0x92B6, 18823), (0x92B7, 18824),
+    (0x92B8, 18825), (0x92B9, 18826), (0x92BA, 18827), (0x92BB, 18828), (0x92BC, 18829), (0x92BD, 18830),
+    (0x92BE, 18831), (0x92BF, 18832), (0x92C0, 18833), (0x92C1, 18834), (0x92C2, 18835), (0x92C3, 18836),
+    (0x92C4, 18837), (0x92C5, 18838), (0x92C6, 18839), (0x92C7, 18840), (0x92C8, 22365), (0x92C9, 18841),
+    (0x92CA, 18842), (0x92CB, 18843), (0x92CC, 18844), (0x92CD, 18845), (0x92CE, 18846), (0x92CF, 18847),
+    (0x92D0, 18848), (0x92D1, 18849), (0x92D2, 18850), (0x92D3, 18851), (0x92D4, 18852), (0x92D5, 18853),
+    (0x92D6, 18854), (0x92D7, 18855), (0x92D8, 18856), (0x92D9, 18857), (0x92DA, 18858), (0x92DB, 18859),
+    (0x92DC, 18860), (0x92DD, 18861), (0x92DE, 18862), (0x92DF, 18863), (0x92E0, 18864), (0x92E1, 18865),
+    (0x92E2, 18866), (0x92E3, 18867), (0x92E4, 18868), (0x92E5, 18869), (0x92E6, 18870), (0x92E7, 18871),
+    (0x92E8, 18872), (0x92E9, 18873), (0x92EA, 18874), (0x92EB, 18875), (0x92EC, 18876), (0x92ED, 18877),
+    (0x92EE, 18878), (0x92EF, 18879), (0x92F0, 18880), (0x92F1, 18881), (0x92F2, 18882), (0x92F3, 18883),
+    (0x92F4, 18884), (0x92F5, 18885), (0x92F6, 18886), (0x92F7, 18887), (0x92F8, 18888), (0x92F9, 18889),
+    (0x92FA, 18890), (0x92FB, 18891), (0x92FC, 18892), (0x92FD, 18893), (0x92FE, 18894), (0x92FF, 18895),
+    (0x9300, 18896), (0x9301, 18897), (0x9302, 18898), (0x9303, 18899), (0x9304, 18900), (0x9305, 18901),
+    (0x9306, 18902), (0x9307, 18903), (0x9308, 18904), (0x9309, 18905), (0x930A, 19000), (0x930B, 19001),
+    (0x930C, 19002), (0x930D, 19003), (0x930E, 19004), (0x930F, 19005), (0x9310, 19006), (0x9311, 19007),
+    (0x9312, 19008), (0x9313, 19009), (0x9314, 19010), (0x9315, 19011), (0x9316, 19012), (0x9317, 19013),
+    (0x9318, 19014), (0x9319, 19015), (0x931A, 19016), (0x931B, 19017), (0x931C, 19018), (0x931D, 19019),
+    (0x931E, 19020), (0x931F, 19021), (0x9320, 19022), (0x9321, 19023), (0x9322, 19024), (0x9323, 19025),
+    (0x9324, 19026), (0x9325, 19027), (0x9326, 19028), (0x9327, 19029), (0x9328, 19030), (0x9329, 19031),
+    (0x932A, 19032), (0x932B, 19033), (0x932C, 19034), (0x932D, 19035), (0x932E, 19036), (0x932F, 19037),
+    (0x9330, 19038), (0x9331, 19039), (0x9332, 19040), (0x9333, 19041), (0x9334, 19042), (0x9335, 19043),
+    (0x9336, 19044), (0x9337, 19045), (0x9338, 19046), (0x9339, 19047), (0x933A, 19048), (0x933B, 19049),
+    (0x933C, 19050), (0x933D, 19051), (0x933E, 22366), (0x933F, 19052), (0x9340, 19053), (0x9341, 19054),
+    (0x9342, 19055), (0x9343, 19056), (0x9344, 19057), (0x9345, 19058), (0x9346, 19059), (0x9347, 19060),
+    (0x9348, 19061), (0x9349, 19062), (0x934A, 19063), (0x934B, 19064), (0x934C, 19065), (0x934D, 19066),
+    (0x934E, 19067), (0x934F, 19068), (0x9350, 19069), (0x9351, 19070), (0x9352, 19071), (0x9353, 19072),
+    (0x9354, 19073), (0x9355, 19074), (0x9356, 19075), (0x9357, 19076), (0x9358, 19077), (0x9359, 19078),
+    (0x935A, 19079), (0x935B, 19080), (0x935C, 19081), (0x935D, 19082), (0x935E, 19083), (0x935F, 19084),
+    (0x9360, 19085), (0x9361, 19086), (0x9362, 19087), (0x9363, 19088), (0x9364, 19089), (0x9365, 19090),
+    (0x9366, 19091), (0x9367, 19092), (0x9368, 19093), (0x9369, 19094), (0x936A, 22367), (0x936B, 19095),
+    (0x936C, 19190), (0x936D, 19191), (0x936E, 19192), (0x936F, 19193), (0x9370, 19194), (0x9371, 19195),
+    (0x9372, 19196), (0x9373, 19197), (0x9374, 19198), (0x9375, 19199), (0x9376, 19200), (0x9377, 19201),
+    (0x9378, 19202), (0x9379, 19203), (0x937A, 19204), (0x937B, 19205), (0x937C, 19206), (0x937D, 19207),
+    (0x937E, 19208), (0x937F, 19209), (0x9380, 19210), (0x9381, 19211), (0x9382, 19212), (0x9383, 19213),
+    (0x9384, 19214), (0x9385, 19215), (0x9386, 19216), (0x9387, 19217), (0x9388, 19218), (0x9389, 19219),
+    (0x938A, 19220), (0x938B, 19221), (0x938C, 19222), (0x938D, 19223), (0x938E, 19224), (0x938F, 22369),
+    (0x9390, 19225), (0x9391, 19226), (0x9392, 19227), (0x9393, 19228), (0x9394, 19229), (0x9395, 19230),
+    (0x9396, 19231), (0x9397, 19232), (0x9398, 19233), (0x9399, 19234), (0x939A, 19235), (0x939B, 19236),
+    (0x939C, 19237), (0x939D, 19238), (0x939E, 19239), (0x939F, 19240), (0x93A0, 19241), (0x93A1, 19242),
+    (0x93A2, 19243), (0x93A3, 19244), (0x93A4, 19245), (0x93A5, 19246), (0x93A6, 19247), (0x93A7, 19248),
+    (0x93A8, 19249), (0x93A9, 19250), (0x93AA, 19251), (0x93AB, 19252), (0x93AC, 19253), (0x93AD, 19254),
+    (0x93AE, 19255), (0x93AF, 19256), (0x93B0, 19257), (0x93B1, 19258), (0x93B2, 19259), (0x93B3, 19260),
+    (0x93B4, 19261), (0x93B5, 19262), (0x93B6, 19263), (0x93B7, 19264), (0x93B8, 19265), (0x93B9, 19266),
+    (0x93BA, 19267), (0x93BB, 19268), (0x93BC, 19269), (0x93BD, 19270), (0x93BE, 19271), (0x93BF, 19272),
+    (0x93C0, 19273), (0x93C1, 19274), (0x93C2, 19275), (0x93C3, 19276), (0x93C4, 19277), (0x93C5, 19278),
+    (0x93C6, 19279), (0x93C7, 19280), (0x93C8, 19281), (0x93C9, 19282), (0x93CA, 22368), (0x93CB, 19283),
+    (0x93CC, 19284), (0x93CD, 19285), (0x93CE, 19380), (0x93CF, 19381), (0x93D0, 19382), (0x93D1, 19383),
+    (0x93D2, 19384), (0x93D3, 19385), (0x93D4, 19386), (0x93D5, 19387), (0x93D6, 22588), (0x93D7, 19388),
+    (0x93D8, 19389), (0x93D9, 19390), (0x93DA, 19391), (0x93DB, 19392), (0x93DC, 19393), (0x93DD, 19394),
+    (0x93DE, 19395), (0x93DF, 19396), (0x93E0, 19397), (0x93E1, 19398), (0x93E2, 19399), (0x93E3, 19400),
+    (0x93E4, 19401), (0x93E5, 19402), (0x93E6, 19403), (0x93E7, 19404), (0x93E8, 19405), (0x93E9, 19406),
+    (0x93EA, 19407), (0x93EB, 19408), (0x93EC, 19409), (0x93ED, 19410), (0x93EE, 19411), (0x93EF, 19412),
+    (0x93F0, 19413), (0x93F1, 19414), (0x93F2, 19415), (0x93F3, 19416), (0x93F4, 19417), (0x93F5, 19418),
+    (0x93F6, 19419), (0x93F7, 19420), (0x93F8, 19421), (0x93F9, 19422), (0x93FA, 19423), (0x93FB, 19424),
+    (0x93FC, 19425), (0x93FD, 19426), (0x93FE, 19427), (0x93FF, 19428), (0x9400, 19429), (0x9401, 19430),
+    (0x9402, 19431), (0x9403, 19432), (0x9404, 19433), (0x9405, 19434), (0x9406, 19435), (0x9407, 19436),
+    (0x9408, 19437), (0x9409, 19438), (0x940A, 19439), (0x940B, 19440), (0x940C, 19441), (0x940D, 19442),
+    (0x940E, 19443), (0x940F, 19444), (0x9410, 19445), (0x9411, 19446), (0x9412, 19447), (0x9413, 19448),
+    (0x9414, 19449), (0x9415, 19450), (0x9416, 19451), (0x9417, 19452), (0x9418, 19453), (0x9419, 19454),
+    (0x941A, 19455), (0x941B, 19456), (0x941C, 19457), (0x941D, 19458), (0x941E, 19459), (0x941F, 19460),
+    (0x9420, 19461), (0x9421, 19462), (0x9422, 19463), (0x9423, 19464), (0x9424, 19465), (0x9425, 19466),
+    (0x9426, 19467), (0x9427, 19468), (0x9428, 19469), (0x9429, 19470), (0x942A, 19471), (0x942B, 19472),
+    (0x942C, 19473), (0x942D, 19474), (0x942E, 19475), (0x942F, 19570), (0x9430, 19571), (0x9431, 19572),
+    (0x9432, 19573), (0x9433, 19574), (0x9434, 19575), (0x9435, 19576), (0x9436, 19577), (0x9437, 19578),
+    (0x9438, 19579), (0x9439, 19580), (0x943A, 19581), (0x943B, 19582), (0x943C, 19583), (0x943D, 19584),
+    (0x943E, 22370), (0x943F, 19585), (0x9440, 19586), (0x9441, 19587), (0x9442, 19588), (0x9443, 19589),
+    (0x9444, 19590), (0x9445, 19591), (0x9446, 19592), (0x9447, 19593), (0x9448, 19594), (0x9449, 19595),
+    (0x944A, 19596), (0x944B, 19597), (0x944C, 19598), (0x944D, 19599), (0x944E, 19600), (0x944F, 19601),
+    (0x9450, 19602), (0x9451, 19603), (0x9452, 19604), (0x9453, 19605), (0x9454, 19606), (0x9455, 19607),
+    (0x9456, 19608), (0x9457, 19609), (0x9458, 19610), (0x9459, 19611), (0x945A, 19612), (0x945B, 19613),
+    (0x945C, 19614), (0x945D, 19615), (0x945E, 19616), (0x945F, 19617), (0x9460, 19618), (0x9461, 19619),
+    (0x9462, 19620), (0x9463, 19621), (0x9464, 19622), (0x9465, 19623), (0x9466, 19624), (0x9467, 19625),
+    (0x9468, 19626), (0x9469, 19627), (0x946A, 19628), (0x946B, 22371), (0x946C, 19629), (0x946D, 19630),
+    (0x946E, 19631), (0x946F, 19632), (0x9470, 19633), (0x9471, 19634), (0x9472, 19635), (0x9473, 19636),
+    (0x9474, 19637), (0x9475, 19638), (0x9476, 19639), (0x9477, 19640), (0x9478, 19641), (0x9479, 19642),
+    (0x947A, 19643), (0x947B, 19644), (0x947C, 19645), (0x947D, 19646), (0x947E, 19647), (0x947F, 19648),
+    (0x9480, 19649), (0x9481, 19650), (0x9482, 19651), (0x9483, 19652), (0x9484, 19653), (0x9485, 20841),
+    (0x9486, 20842), (0x9487, 20843), (0x9488, 16130), (0x9489, 10169), (0x948A, 20845), (0x948B, 20844),
+    (0x948C, 20846), (0x948D, 20847), (0x948E, 13400), (0x948F, 20848), (0x9490, 20849), (0x9491, 19654),
+    (0x9492, 10371), (0x9493, 10061), (0x9494, 20850), (0x9495, 20852), (0x9496, 19655), (0x9497, 20851),
+    (0x9498, 19656), (0x9499, 10583), (0x949A, 20853), (0x949B, 20854), (0x949C, 20855), (0x949D, 10224),
+    (0x949E, 9609), (0x949F, 16296), (0x94A0, 12863), (0x94A1, 9236), (0x94A2, 10599), (0x94A3, 20856),
+    (0x94A4, 20857), (0x94A5, 15896), (0x94A6, 13448), (0x94A7, 11776), (0x94A8, 14782), (0x94A9, 10754),
+    (0x94AA, 20859), (0x94AB, 20858), (0x94AC, 20861), (0x94AD, 20860), (0x94AE, 13020), (0x94AF, 20862),
+    (0x94B0, 20863), (0x94B1, 13409), (0x94B2, 20864), (0x94B3, 13410), (0x94B4, 20865), (0x94B5, 9412),
+    (0x94B6, 20866), (0x94B7, 20867), (0x94B8, 20868), (0x94B9, 20869), (0x94BA, 20870), (0x94BB, 16509),
+    (0x94BC, 20871), (0x94BD, 20872), (0x94BE, 11361), (0x94BF, 20873), (0x94C0, 15718), (0x94C1, 14435),
+    (0x94C2, 9417), (0x94C3, 12324), (0x94C4, 20874), (0x94C5, 13401), (0x94C6, 12648), (0x94C7, 19657),
+    (0x94C8, 20875), (0x94C9, 20876), (0x94CA, 20877), (0x94CB, 20878), (0x94CC, 20879), (0x94CD, 20880),
+    (0x94CE, 20881), (0x94CF, 19658), (0x94D0, 20882), (0x94D1, 20883), (0x94D2, 20884), (0x94D3, 19659),
+    (0x94D4, 19660), (0x94D5, 20885), (0x94D6, 20886), (0x94D7, 20887), (0x94D8, 20889), (0x94D9, 20888),
+    (0x94DA, 19661), (0x94DB, 20890), (0x94DC, 14548), (0x94DD, 12478), (0x94DE, 20891), (0x94DF, 20892),
+    (0x94E0, 20893), (0x94E1, 16056), (0x94E2, 20894), (0x94E3, 14934), (0x94E4, 20895), (0x94E5, 20896),
+    (0x94E6, 19662), (0x94E7, 20897), (0x94E8, 20898), (0x94E9, 20996), (0x94EA, 20899), (0x94EB, 20997),
+    (0x94EC, 10630), (0x94ED, 12725), (0x94EE, 20998), (0x94EF, 20999), (0x94F0, 11529), (0x94F1, 15516),
+    (0x94F2, 9494), (0x94F3, 21000), (0x94F4, 21001), (0x94F5, 21002), (0x94F6, 15573), (0x94F7, 21003),
+    (0x94F8, 16338), (0x94F9, 21004), (0x94FA, 13249), (0x94FB, 19663), (0x94FC, 21005), (0x94FD, 21006),
+    (0x94FE, 12275), (0x94FF, 21007), (0x9500, 15005), (0x9501, 14243), (0x9502, 21009), (0x9503, 21008),
+    (0x9504, 9685), (0x9505, 10823), (0x9506, 21010), (0x9507, 21011), (0x9508, 15171), (0x9509, 21012),
+    (0x950A, 21013), (0x950B, 10425), (0x950C, 15136), (0x950D, 21014), (0x950E, 21015), (0x950F, 21016),
+    (0x9510, 13666), (0x9511, 14409), (0x9512, 21017), (0x9513, 21018), (0x9514, 21019), (0x9515, 21020),
+    (0x9516, 21021), (0x9517, 16119), (0x9518, 21022), (0x9519, 9862), (0x951A, 12645), (0x951B, 21023),
+    (0x951C, 19664), (0x951D, 21024), (0x951E, 21025), (0x951F, 21026), (0x9520, 19665), (0x9521, 14818),
+    (0x9522, 21027), (0x9523, 12509), (0x9524, 9809), (0x9525, 16457), (0x9526, 11580), (0x9527, 19760),
+    (0x9528, 14954), (0x9529, 21030), (0x952A, 21028), (0x952B, 21029), (0x952C, 21031), (0x952D, 10172),
+    (0x952E, 11397), (0x952F, 11751), (0x9530, 12679), (0x9531, 21032), (0x9532, 21033), (0x9533, 19761),
+    (0x9534, 21034), (0x9535, 21042), (0x9536, 21035), (0x9537, 21036), (0x9538, 21037), (0x9539, 13429),
+    (0x953A, 21076), (0x953B, 10210), (0x953C, 21038), (0x953D, 19762), (0x953E, 21039), (0x953F, 21040),
+    (0x9540, 10203), (0x9541, 12665), (0x9542, 21041), (0x9543, 19763), (0x9544, 21043), (0x9545, 21044),
+    (0x9546, 21045), (0x9547, 16137), (0x9548, 19764), (0x9549, 21046), (0x954A, 12912), (0x954B, 19765),
+    (0x954C, 21047), (0x954D, 12913), (0x954E, 21048), (0x954F, 21049), (0x9550, 10613), (0x9551, 9112),
+    (0x9552, 21050), (0x9553, 21051), (0x9554, 21052), (0x9555, 19766), (0x9556, 21053), (0x9557, 21054),
+    (0x9558, 21055), (0x9559, 21056), (0x955A, 19767), (0x955B, 21057), (0x955C, 11706), (0x955D, 21060),
+    (0x955E, 21058), (0x955F, 21059), (0x9560, 19768), (0x9561, 21061), (0x9562, 21062), (0x9563, 12300),
+    (0x9564, 21063), (0x9565, 21064), (0x9566, 21065), (0x9567, 21066), (0x9568, 21067), (0x9569, 21068),
+    (0x956A, 21069), (0x956B, 21070), (0x956C, 21071), (0x956D, 12121), (0x956E, 19769), (0x956F, 21072),
+    (0x9570, 12268), (0x9571, 21073), (0x9572, 21074), (0x9573, 21075), (0x9574, 19770), (0x9575, 19771),
+    (0x9576, 14981), (0x9577, 19772), (0x9578, 19773), (0x9579, 19774), (0x957A, 19775), (0x957B, 19776),
+    (0x957C, 19777), (0x957D, 19778), (0x957E, 19779), (0x957F, 9599), (0x9580, 19780), (0x9581, 19781),
+    (0x9582, 19782), (0x9583, 19783), (0x9584, 19784), (0x9585, 19785), (0x9586, 19786), (0x9587, 19787),
+    (0x9588, 19788), (0x9589, 19789), (0x958A, 19790), (0x958B, 19791), (0x958C, 19792), (0x958D, 19793),
+    (0x958E, 19794), (0x958F, 19795), (0x9590, 19796), (0x9591, 19797), (0x9592, 19798), (0x9593, 19799),
+    (0x9594, 19800), (0x9595, 19801), (0x9596, 19802), (0x9597, 19803), (0x9598, 19804), (0x9599, 19805),
+    (0x959A, 19806), (0x959B, 19807), (0x959C, 19808), (0x959D, 19809), (0x959E, 19810), (0x959F, 19811),
+    (0x95A0, 19812), (0x95A1, 19813), (0x95A2, 19814), (0x95A3, 19815), (0x95A4, 19816), (0x95A5, 19817),
+    (0x95A6, 19818), (0x95A7, 19819), (0x95A8, 19820), (0x95A9, 19821), (0x95AA, 19822), (0x95AB, 19823),
+    (0x95AC, 19824), (0x95AD, 19825), (0x95AE, 19826), (0x95AF, 19827), (0x95B0, 19828), (0x95B1, 19829),
+    (0x95B2, 19830), (0x95B3, 19831), (0x95B4, 19832), (0x95B5, 19833), (0x95B6, 19834), (0x95B7, 19835),
+    (0x95B8, 19836), (0x95B9, 19837), (0x95BA, 19838), (0x95BB, 19839), (0x95BC, 19840), (0x95BD, 19841),
+    (0x95BE, 19842), (0x95BF, 19843), (0x95C0, 19844), (0x95C1, 19845), (0x95C2, 19846), (0x95C3, 19847),
+    (0x95C4, 19848), (0x95C5, 19849), (0x95C6, 19850), (0x95C7, 19851), (0x95C8, 19852), (0x95C9, 19853),
+    (0x95CA, 19854), (0x95CB, 19855), (0x95CC, 19950), (0x95CD, 19951), (0x95CE, 19952), (0x95CF, 19953),
+    (0x95D0, 19954), (0x95D1, 19955), (0x95D2, 19956), (0x95D3, 19957), (0x95D4, 19958), (0x95D5, 19959),
+    (0x95D6, 19960), (0x95D7, 19961), (0x95D8, 19962), (0x95D9, 19963), (0x95DA, 19964), (0x95DB, 19965),
+    (0x95DC, 19966), (0x95DD, 19967), (0x95DE, 19968), (0x95DF, 19969), (0x95E0, 19970), (0x95E1, 19971),
+    (0x95E2, 19972), (0x95E3, 19973), (0x95E4, 19974), (0x95E5, 19975), (0x95E6, 19976), (0x95E7, 19977),
+    (0x95E8, 12672), (0x95E9, 18752), (0x95EA, 13808), (0x95EB, 18753), (0x95EC, 19978), (0x95ED, 9268),
+    (0x95EE, 14767), (0x95EF, 9804), (0x95F0, 13667), (0x95F1, 18754), (0x95F2, 14963), (0x95F3, 18755),
+    (0x95F4, 11373), (0x95F5, 18756), (0x95F6, 18757), (0x95F7, 12673), (0x95F8, 16057), (0x95F9, 12879),
+    (0x95FA, 10810), (0x95FB, 14762), (0x95FC, 18758), (0x95FD, 12721), (0x95FE, 18759), (0x95FF, 19979),
+    (0x9600, 10362), (0x9601, 10628), (0x9602, 10975), (0x9603, 18760), (0x9604, 18761), (0x9605, 15901),
+    (0x9606, 18762), (0x9607, 19980), (0x9608, 18763), (0x9609, 15338), (0x960A, 18764), (0x960B, 18765),
+    (0x960C, 18766), (0x960D, 18767), (0x960E, 15349), (0x960F, 18768), (0x9610, 9496), (0x9611, 12092),
+    (0x9612, 18769), (0x9613, 19981), (0x9614, 12076), (0x9615, 18770), (0x9616, 18771), (0x9617, 18772),
+    (0x9618, 19982), (0x9619, 18773), (0x961A, 18774), (0x961B, 19983), (0x961C, 10568), (0x961D, 17071),
+    (0x961E, 19984), (0x961F, 10216), (0x9620, 19985), (0x9621, 17073), (0x9622, 17072), (0x9623, 19986),
+    (0x9624, 19987), (0x9625, 19988), (0x9626, 19989), (0x9627, 19990), (0x9628, 19991), (0x9629, 19992),
+    (0x962A, 17075), (0x962B, 19993), (0x962C, 19994), (0x962D, 19995), (0x962E, 13663), (0x962F, 19996),
+    (0x9630, 19997), (0x9631, 17074), (0x9632, 10387), (0x9633, 15379), (0x9634, 15570), (0x9635, 16138),
+    (0x9636, 11550), (0x9637, 19998), (0x9638, 19999), (0x9639, 20000), (0x963A, 20001), (0x963B, 16507),
+    (0x963C, 17077), (0x963D, 17076), (0x963E, 20002), (0x963F, 9027), (0x9640, 14586), (0x9641, 20003),
+    (0x9642, 17078), (0x9643, 20004), (0x9644, 10574), (0x9645, 11347), (0x9646, 12474), (0x9647, 12449),
+    (0x9648, 9629), (0x9649, 17079), (0x964A, 20005), (0x964B, 12455), (0x964C, 12841), (0x964D, 11516),
+    (0x964E, 20006), (0x964F, 20007), (0x9650, 14977), (0x9651, 20008), (0x9652, 20009), (0x9653, 20010),
+    (0x9654, 17080), (0x9655, 13809), (0x9656, 20011), (0x9657, 20012), (0x9658, 20013), (0x9659, 20014),
+    (0x965A, 20015), (0x965B, 9276), (0x965C, 20016), (0x965D, 20017), (0x965E, 20018), (0x965F, 17081),
+    (0x9660, 20019), (0x9661, 10189), (0x9662, 15891), (0x9663, 20020), (0x9664, 9688), (0x9665, 20021),
+    (0x9666, 20022), (0x9667, 17082), (0x9668, 15906), (0x9669, 14968), (0x966A, 13082), (0x966B, 20023),
+    (0x966C, 17083), (0x966D, 20024), (0x966E, 20025), (0x966F, 20026), (0x9670, 20027), (0x9671, 20028),
+    (0x9672, 17084), (0x9673, 20029), (0x9674, 17085), (0x9675, 12329), (0x9676, 14398), (0x9677, 14976),
+    (0x9678, 20030), (0x9679, 20031), (0x967A, 20032), (0x967B, 20033), (0x967C, 20034), (0x967D, 20035),
+    (0x967E, 20036), (0x967F, 20037), (0x9680, 20038), (0x9681, 20039), (0x9682, 20040), (0x9683, 20041),
+    (0x9684, 20042), (0x9685, 15746), (0x9686, 12446), (0x9687, 20043), (0x9688, 17086), (0x9689, 20044),
+    (0x968A, 20045), (0x968B, 14224), (0x968C, 20140), (0x968D, 17087), (0x968E, 20141), (0x968F, 14225),
+    (0x9690, 15579), (0x9691, 20142), (0x9692, 20143), (0x9693, 20144), (0x9694, 10629), (0x9695, 20145),
+    (0x9696, 20146), (0x9697, 17088), (0x9698, 9040), (0x9699, 14937), (0x969A, 20147), (0x969B, 20148),
+    (0x969C, 16102), (0x969D, 20149), (0x969E, 20150), (0x969F, 20151), (0x96A0, 20152), (0x96A1, 20153),
+    (0x96A2, 20154), (0x96A3, 20155), (0x96A4, 20156), (0x96A5, 20157), (0x96A6, 20158), (0x96A7, 14232),
+    (0x96A8, 20159), (0x96A9, 20160), (0x96AA, 20161), (0x96AB, 20162), (0x96AC, 20163), (0x96AD, 20164),
+    (0x96AE, 20165), (0x96AF, 20166), (0x96B0, 17089), (0x96B1, 20167), (0x96B2, 20168), (0x96B3, 18751),
+    (0x96B4, 20169), (0x96B5, 20170), (0x96B6, 12260), (0x96B7, 20171), (0x96B8, 20172), (0x96B9, 22356),
+    (0x96BA, 20173), (0x96BB, 20174), (0x96BC, 22357), (0x96BD, 22358), (0x96BE, 12874), (0x96BF, 20175),
+    (0x96C0, 13609), (0x96C1, 15362), (0x96C2, 20176), (0x96C3, 20177), (0x96C4, 15164), (0x96C5, 15332),
+    (0x96C6, 11320), (0x96C7, 10780), (0x96C8, 20178), (0x96C9, 21079), (0x96CA, 20179), (0x96CB, 20180),
+    (0x96CC, 9823), (0x96CD, 15701), (0x96CE, 22359), (0x96CF, 9686), (0x96D0, 20181), (0x96D1, 20182),
+    (0x96D2, 22360), (0x96D3, 20183), (0x96D4, 20184), (0x96D5, 10056), (0x96D6, 20185), (0x96D7, 20186),
+    (0x96D8, 20187), (0x96D9, 20188), (0x96DA, 20189), (0x96DB, 20190), (0x96DC, 20191), (0x96DD, 20192),
+    (0x96DE, 20193), (0x96DF, 20194), (0x96E0, 22362), (0x96E1, 20195), (0x96E2, 20196), (0x96E3, 20197),
+    (0x96E4, 20198), (0x96E5, 20199), (0x96E6, 20200), (0x96E7, 20201), (0x96E8, 15749), (0x96E9, 22332),
+    (0x96EA, 15304), (0x96EB, 20202), (0x96EC, 20203), (0x96ED, 20204), (0x96EE, 20205), (0x96EF, 22334),
+    (0x96F0, 20206), (0x96F1, 20207), (0x96F2, 20208), (0x96F3, 22333), (0x96F4, 20209), (0x96F5, 20210),
+    (0x96F6, 12322), (0x96F7, 12120), (0x96F8, 20211), (0x96F9, 9217), (0x96FA, 20212), (0x96FB, 20213),
+    (0x96FC, 20214), (0x96FD, 20215), (0x96FE, 14802), (0x96FF, 20216), (0x9700, 15177), (0x9701, 22336),
+    (0x9702, 20217), (0x9703, 20218), (0x9704, 15001), (0x9705, 20219), (0x9706, 22335), (0x9707, 16135),
+    (0x9708, 22337), (0x9709, 12660), (0x970A, 20220), (0x970B, 20221), (0x970C, 20222), (0x970D, 11199),
+    (0x970E, 22339), (0x970F, 22338), (0x9710, 20223), (0x9711, 20224), (0x9712, 20225), (0x9713, 12887),
+    (0x9714, 20226), (0x9715, 20227), (0x9716, 12311), (0x9717, 20228), (0x9718, 20229), (0x9719, 20230),
+    (0x971A, 20231), (0x971B, 20232), (0x971C, 14165), (0x971D, 20233), (0x971E, 14943), (0x971F, 20234),
+    (0x9720, 20235), (0x9721, 20330), (0x9722, 20331), (0x9723, 20332), (0x9724, 20333), (0x9725, 20334),
+    (0x9726, 20335), (0x9727, 20336), (0x9728, 20337), (0x9729, 20338), (0x972A, 22340), (0x972B, 20339),
+    (0x972C, 20340), (0x972D, 22341), (0x972E, 20341), (0x972F, 20342), (0x9730, 22342), (0x9731, 20343),
+    (0x9732, 12467), (0x9733, 20344), (0x9734, 20345), (0x9735, 20346), (0x9736, 20347), (0x9737, 20348),
+    (0x9738, 9077), (0x9739, 13104), (0x973A, 20349), (0x973B, 20350), (0x973C, 20351), (0x973D, 20352),
+    (0x973E, 22343), (0x973F, 20353), (0x9740, 20354), (0x9741, 20355), (0x9742, 20356), (0x9743, 20357),
+    (0x9744, 20358), (0x9745, 20359), (0x9746, 20360), (0x9747, 20361), (0x9748, 20362), (0x9749, 20363),
+    (0x974A, 20364), (0x974B, 20365), (0x974C, 20366), (0x974D, 20367), (0x974E, 20368), (0x974F, 20369),
+    (0x9750, 20370), (0x9751, 20371), (0x9752, 13459), (0x9753, 22331), (0x9754, 20372), (0x9755, 20373),
+    (0x9756, 11709), (0x9757, 20374), (0x9758, 20375), (0x9759, 11703), (0x975A, 20376), (0x975B, 10044),
+    (0x975C, 20377), (0x975D, 20378), (0x975E, 10394), (0x975F, 20379), (0x9760, 11906), (0x9761, 12685),
+    (0x9762, 12705), (0x9763, 20380), (0x9764, 20381), (0x9765, 16669), (0x9766, 20382), (0x9767, 20383),
+    (0x9768, 20384), (0x9769, 10624), (0x976A, 20385), (0x976B, 20386), (0x976C, 20387), (0x976D, 20388),
+    (0x976E, 20389), (0x976F, 20390), (0x9770, 20391), (0x9771, 20392), (0x9772, 20393), (0x9773, 11584),
+    (0x9774, 15300), (0x9775, 20394), (0x9776, 9073), (0x9777, 20395), (0x9778, 20396), (0x9779, 20397),
+    (0x977A, 20398), (0x977B, 20399), (0x977C, 22531), (0x977D, 20400), (0x977E, 20401), (0x977F, 20402),
+    (0x9780, 20403), (0x9781, 20404), (0x9782, 20405), (0x9783, 20406), (0x9784, 20407), (0x9785, 22532),
+    (0x9786, 20408), (0x9787, 20409), (0x9788, 20410), (0x9789, 20411), (0x978A, 20412), (0x978B, 15117),
+    (0x978C, 20413), (0x978D, 9041), (0x978E, 20414), (0x978F, 20415), (0x9790, 20416), (0x9791, 22533),
+    (0x9792, 22534), (0x9793, 20417), (0x9794, 22535), (0x9795, 20418), (0x9796, 20419), (0x9797, 20420),
+    (0x9798, 13437), (0x9799, 20421), (0x979A, 20422), (0x979B, 20423), (0x979C, 20424), (0x979D, 20425),
+    (0x979E, 20520), (0x979F, 20521), (0x97A0, 11732), (0x97A1, 20522), (0x97A2, 20523), (0x97A3, 22538),
+    (0x97A4, 20524), (0x97A5, 20525), (0x97A6, 20526), (0x97A7, 20527), (0x97A8, 20528), (0x97A9, 20529),
+    (0x97AA, 20530), (0x97AB, 22537), (0x97AC, 20531), (0x97AD, 9277), (0x97AE, 20532), (0x97AF, 22536),
+    (0x97B0, 20533), (0x97B1, 20534), (0x97B2, 22539), (0x97B3, 20535), (0x97B4, 22540), (0x97B5, 20536),
+    (0x97B6, 20537), (0x97B7, 20538), (0x97B8, 20539), (0x97B9, 20540), (0x97BA, 20541), (0x97BB, 20542),
+    (0x97BC, 20543), (0x97BD, 20544), (0x97BE, 20545), (0x97BF, 20546), (0x97C0, 20547), (0x97C1, 20548),
+    (0x97C2, 20549), (0x97C3, 20550), (0x97C4, 20551), (0x97C5, 20552), (0x97C6, 20553), (0x97C7, 20554),
+    (0x97C8, 20555), (0x97C9, 20556), (0x97CA, 20557), (0x97CB, 20558), (0x97CC, 20559), (0x97CD, 20560),
+    (0x97CE, 20561), (0x97CF, 20562), (0x97D0, 20563), (0x97D1, 20564), (0x97D2, 20565), (0x97D3, 20566),
+    (0x97D4, 20567), (0x97D5, 20568), (0x97D6, 20569), (0x97D7, 20570), (0x97D8, 20571), (0x97D9, 20572),
+    (0x97DA, 20573), (0x97DB, 20574), (0x97DC, 20575), (0x97DD, 20576), (0x97DE, 20577), (0x97DF, 20578),
+    (0x97E0, 20579), (0x97E1, 20580), (0x97E2, 20581), (0x97E3, 20582), (0x97E4, 20583), (0x97E5, 20584),
+    (0x97E6, 14729), (0x97E7, 13630), (0x97E8, 20585), (0x97E9, 10936), (0x97EA, 19689), (0x97EB, 19690),
+    (0x97EC, 19691), (0x97ED, 11719), (0x97EE, 20586), (0x97EF, 20587), (0x97F0, 20588), (0x97F1, 20589),
+    (0x97F2, 20590), (0x97F3, 15569), (0x97F4, 20591), (0x97F5, 15912), (0x97F6, 13831), (0x97F7, 20592),
+    (0x97F8, 20593), (0x97F9, 20594), (0x97FA, 20595), (0x97FB, 20596), (0x97FC, 20597), (0x97FD, 20598),
+    (0x97FE, 20599), (0x97FF, 20600), (0x9800, 20601), (0x9801, 20602), (0x9802, 20603), (0x9803, 20604),
+    (0x9804, 20605), (0x9805, 20606), (0x9806, 20607), (0x9807, 20608), (0x9808, 20609), (0x9809, 20610),
+    (0x980A, 20611), (0x980B, 20612), (0x980C, 20613), (0x980D, 20614), (0x980E, 20615), (0x980F, 20710),
+    (0x9810, 20711), (0x9811, 20712), (0x9812, 20713), (0x9813, 20714), (0x9814, 20715), (0x9815, 20716),
+    (0x9816, 20717), (0x9817, 20718), (0x9818, 20719), (0x9819, 20720), (0x981A, 20721), (0x981B, 20722),
+    (0x981C, 20723), (0x981D, 20724), (0x981E, 20725), (0x981F, 20726), (0x9820, 20727), (0x9821, 20728),
+    (0x9822, 20729), (0x9823, 20730), (0x9824, 20731), (0x9825, 20732), (0x9826, 20733), (0x9827, 20734),
+    (0x9828, 20735), (0x9829, 20736), (0x982A, 20737), (0x982B, 20738), (0x982C, 20739), (0x982D, 20740),
+    (0x982E, 20741), (0x982F, 20742), (0x9830, 20743), (0x9831, 20744), (0x9832, 20745), (0x9833, 20746),
+    (0x9834, 20747), (0x9835, 20748), (0x9836, 20749), (0x9837, 20750), (0x9838, 20751), (0x9839, 20752),
+    (0x983A, 20753), (0x983B, 20754), (0x983C, 20755), (0x983D, 20756), (0x983E, 20757), (0x983F, 20758),
+    (0x9840, 20759), (0x9841, 20760), (0x9842, 20761), (0x9843, 20762), (0x9844, 20763), (0x9845, 20764),
+    (0x9846, 20765), (0x9847, 20766), (0x9848, 20767), (0x9849, 20768), (0x984A, 20769), (0x984B, 20770),
+    (0x984C, 20771), (0x984D, 20772), (0x984E, 20773), (0x984F, 20774), (0x9850, 20775), (0x9851, 20776),
+    (0x9852, 20777), (0x9853, 20778), (0x9854, 20779), (0x9855, 20780), (0x9856, 20781), (0x9857, 20782),
+    (0x9858, 20783), (0x9859, 20784), (0x985A, 20785), (0x985B, 20786), (0x985C, 20787), (0x985D, 20788),
+    (0x985E, 20789), (0x985F, 20790), (0x9860, 20791), (0x9861, 20792), (0x9862, 20793), (0x9863, 20794),
+    (0x9864, 20795), (0x9865, 20796), (0x9866, 20797), (0x9867, 20798), (0x9868, 20799), (0x9869, 20800),
+    (0x986A, 20801), (0x986B, 20802), (0x986C, 20803), (0x986D, 20804), (0x986E, 20805), (0x986F, 20900),
+    (0x9870, 20901), (0x9871, 20902), (0x9872, 20903), (0x9873, 20904), (0x9874, 20905), (0x9875, 15504),
+    (0x9876, 10170), (0x9877, 13469), (0x9878, 21467), (0x9879, 14993), (0x987A, 14174), (0x987B, 15180),
+    (0x987C, 19554), (0x987D, 14606), (0x987E, 10778), (0x987F, 10222), (0x9880, 21468), (0x9881, 9093),
+    (0x9882, 14199), (0x9883, 21469), (0x9884, 15869), (0x9885, 12458), (0x9886, 12331), (0x9887, 13241),
+    (0x9888, 11702), (0x9889, 21566), (0x988A, 11358), (0x988B, 20906), (0x988C, 21567), (0x988D, 21568),
+    (0x988E, 20907), (0x988F, 21569), (0x9890, 15520), (0x9891, 13226), (0x9892, 20908), (0x9893, 14574),
+    (0x9894, 21570), (0x9895, 20909), (0x9896, 15692), (0x9897, 11912), (0x9898, 14411), (0x9899, 20910),
+    (0x989A, 21571), (0x989B, 21572), (0x989C, 15348), (0x989D, 10243), (0x989E, 21573), (0x989F, 21574),
+    (0x98A0, 10038), (0x98A1, 21575), (0x98A2, 21576), (0x98A3, 20911), (0x98A4, 9497), (0x98A5, 21577),
+    (0x98A6, 21578), (0x98A7, 13592), (0x98A8, 20912), (0x98A9, 20913), (0x98AA, 20914), (0x98AB, 20915),
+    (0x98AC, 20916), (0x98AD, 20917), (0x98AE, 20918), (0x98AF, 20919), (0x98B0, 20920), (0x98B1, 20921),
+    (0x98B2, 20922), (0x98B3, 20923), (0x98B4, 20924), (0x98B5, 20925), (0x98B6, 20926), (0x98B7, 20927),
+    (0x98B8, 20928), (0x98B9, 20929), (0x98BA, 20930), (0x98BB, 20931), (0x98BC, 20932), (0x98BD, 20933),
+    (0x98BE, 20934), (0x98BF, 20935), (0x98C0, 20936), (0x98C1, 20937), (0x98C2, 20938), (0x98C3, 20939),
+    (0x98C4, 20940), (0x98C5, 20941), (0x98C6, 20942), (0x98C7, 20943), (0x98C8, 20944), (0x98C9, 20945),
+    (0x98CA, 20946), (0x98CB, 20947), (0x98CC, 20948), (0x98CD, 20949), (0x98CE, 10426), (0x98CF, 20950),
+    (0x98D0, 20951), (0x98D1, 20434), (0x98D2, 20435), (0x98D3, 20436), (0x98D4, 20952), (0x98D5, 20437),
+    (0x98D6, 20953), (0x98D7, 20954), (0x98D8, 13219), (0x98D9, 20438), (0x98DA, 20439), (0x98DB, 20955),
+    (0x98DC, 20956), (0x98DD, 20957), (0x98DE, 10396), (0x98DF, 13984), (0x98E0, 20958), (0x98E1, 20959),
+    (0x98E2, 20960), (0x98E3, 20961), (0x98E4, 20962), (0x98E5, 20963), (0x98E6, 20964), (0x98E7, 18549),
+    (0x98E8, 22562), (0x98E9, 20965), (0x98EA, 20966), (0x98EB, 20967), (0x98EC, 20968), (0x98ED, 20969),
+    (0x98EE, 20970), (0x98EF, 20971), (0x98F0, 20972), (0x98F1, 20973), (0x98F2, 20974), (0x98F3, 20975),
+    (0x98F4, 20976), (0x98F5, 20977), (0x98F6, 20978), (0x98F7, 20979), (0x98F8, 20980), (0x98F9, 20981),
+    (0x98FA, 20982), (0x98FB, 20983), (0x98FC, 20984), (0x98FD, 20985), (0x98FE, 20986), (0x98FF, 20987),
+    (0x9900, 20988), (0x9901, 20989), (0x9902, 20990), (0x9903, 20991), (0x9904, 20992), (0x9905, 20993),
+    (0x9906, 20994), (0x9907, 20995), (0x9908, 21090), (0x9909, 21091), (0x990A, 21092), (0x990B, 21093),
+    (0x990C, 21094), (0x990D, 22563), (0x990E, 21095), (0x990F, 21096), (0x9910, 9450), (0x9911, 21097),
+    (0x9912, 21098), (0x9913, 21099), (0x9914, 21100), (0x9915, 21101), (0x9916, 21102), (0x9917, 21103),
+    (0x9918, 21104), (0x9919, 21105), (0x991A, 21106), (0x991B, 21107), (0x991C, 21108), (0x991D, 21109),
+    (0x991E, 21110), (0x991F, 21111), (0x9920, 21112), (0x9921, 21113), (0x9922, 21114), (0x9923, 21115),
+    (0x9924, 21116), (0x9925, 21117), (0x9926, 21118), (0x9927, 21119), (0x9928, 21120), (0x9929, 21121),
+    (0x992A, 21122), (0x992B, 21123), (0x992C, 21124), (0x992D, 21125), (0x992E, 22564), (0x992F, 21126),
+    (0x9930, 21127), (0x9931, 21128), (0x9932, 21129), (0x9933, 21130), (0x9934, 21131), (0x9935, 21132),
+    (0x9936, 21133), (0x9937, 21134), (0x9938, 21135), (0x9939, 21136), (0x993A, 21137), (0x993B, 21138),
+    (0x993C, 21139), (0x993D, 21140), (0x993E, 21141), (0x993F, 21142), (0x9940, 21143), (0x9941, 21144),
+    (0x9942, 21145), (0x9943, 21146), (0x9944, 21147), (0x9945, 21148), (0x9946, 21149), (0x9947, 21150),
+    (0x9948, 21151), (0x9949, 21152), (0x994A, 21153), (0x994B, 21154), (0x994C, 21155), (0x994D, 21156),
+    (0x994E, 21157), (0x994F, 21158), (0x9950, 21159), (0x9951, 21160), (0x9952, 21161), (0x9953, 21162),
+    (0x9954, 22566), (0x9955, 22565), (0x9956, 21163), (0x9957, 21164), (0x9958, 21165), (0x9959, 21166),
+    (0x995A, 21167), (0x995B, 21168), (0x995C, 21169), (0x995D, 21170), (0x995E, 21171), (0x995F, 21172),
+    (0x9960, 21173), (0x9961, 21174), (0x9962, 21175), (0x9963, 18552), (0x9964, 21176), (0x9965, 11307),
+    (0x9966, 21177), (0x9967, 18553), (0x9968, 18554), (0x9969, 18555), (0x996A, 18556), (0x996B, 18557),
+    (0x996C, 18558), (0x996D, 10380), (0x996E, 15576), (0x996F, 11499), (0x9970, 14011), (0x9971, 9220),
+    (0x9972, 14194), (0x9973, 21178), (0x9974, 18559), (0x9975, 10257), (0x9976, 13621), (0x9977, 18560),
+    (0x9978, 21179), (0x9979, 21180), (0x997A, 11535), (0x997B, 21181), (0x997C, 9308), (0x997D, 18561),
+    (0x997E, 21182), (0x997F, 10251), (0x9980, 18562), (0x9981, 12882), (0x9982, 21183), (0x9983, 21184),
+    (0x9984, 18563), (0x9985, 14973), (0x9986, 10796), (0x9987, 18564), (0x9988, 12066), (0x9989, 21185),
+    (0x998A, 18565), (0x998B, 9491), (0x998C, 21280), (0x998D, 18566), (0x998E, 21281), (0x998F, 12338),
+    (0x9990, 18567), (0x9991, 18568), (0x9992, 12533), (0x9993, 18569), (0x9994, 18570), (0x9995, 18571),
+    (0x9996, 14020), (0x9997, 16649), (0x9998, 16884), (0x9999, 14982), (0x999A, 21282), (0x999B, 21283),
+    (0x999C, 21284), (0x999D, 21285), (0x999E, 21286), (0x999F, 21287), (0x99A0, 21288), (0x99A1, 21289),
+    (0x99A2, 21290), (0x99A3, 21291), (0x99A4, 21292), (0x99A5, 21190), (0x99A6, 21293), (0x99A7, 21294),
+    (0x99A8, 17401), (0x99A9, 21295), (0x99AA, 21296), (0x99AB, 21297), (0x99AC, 21298), (0x99AD, 21299),
+    (0x99AE, 21300), (0x99AF, 21301), (0x99B0, 21302), (0x99B1, 21303), (0x99B2, 21304), (0x99B3, 21305),
+    (0x99B4, 21306), (0x99B5, 21307), (0x99B6, 21308), (0x99B7, 21309), (0x99B8, 21310), (0x99B9, 21311),
+    (0x99BA, 21312), (0x99BB, 21313), (0x99BC, 21314), (0x99BD, 21315), (0x99BE, 21316), (0x99BF, 21317),
+    (0x99C0, 21318), (0x99C1, 21319), (0x99C2, 21320), (0x99C3, 21321), (0x99C4, 21322), (0x99C5, 21323),
+    (0x99C6, 21324), (0x99C7, 21325), (0x99C8, 21326), (0x99C9, 21327), (0x99CA, 21328), (0x99CB, 21329),
+    (0x99CC, 21330), (0x99CD, 21331), (0x99CE, 21332), (0x99CF, 21333), (0x99D0, 21334), (0x99D1, 21335),
+    (0x99D2, 21336), (0x99D3, 21337), (0x99D4, 21338), (0x99D5, 21339), (0x99D6, 21340), (0x99D7, 21341),
+    (0x99D8, 21342), (0x99D9, 21343), (0x99DA, 21344), (0x99DB, 21345), (0x99DC, 21346), (0x99DD, 21347),
+    (0x99DE, 21348), (0x99DF, 21349), (0x99E0, 21350), (0x99E1, 21351), (0x99E2, 21352), (0x99E3, 21353),
+    (0x99E4, 21354), (0x99E5, 21355), (0x99E6, 21356), (0x99E7, 21357), (0x99E8, 21358), (0x99E9, 21359),
+    (0x99EA, 21360), (0x99EB, 21361), (0x99EC, 21362), (0x99ED, 21363), (0x99EE, 21364), (0x99EF, 21365),
+    (0x99F0, 21366), (0x99F1, 21367), (0x99F2, 21368), (0x99F3, 21369), (0x99F4, 21370), (0x99F5, 21371),
+    (0x99F6, 21372), (0x99F7, 21373), (0x99F8, 21374), (0x99F9, 21375), (0x99FA, 21470), (0x99FB, 21471),
+    (0x99FC, 21472), (0x99FD, 21473), (0x99FE, 21474), (0x99FF, 21475), (0x9A00, 21476), (0x9A01, 21477),
+    (0x9A02, 21478), (0x9A03, 21479), (0x9A04, 21480), (0x9A05, 21481), (0x9A06, 21482), (0x9A07, 21483),
+    (0x9A08, 21484), (0x9A09, 21485), (0x9A0A, 21486), (0x9A0B, 21487), (0x9A0C, 21488), (0x9A0D, 21489),
+    (0x9A0E, 21490), (0x9A0F, 21491), (0x9A10, 21492), (0x9A11, 21493), (0x9A12, 21494), (0x9A13, 21495),
+    (0x9A14, 21496), (0x9A15, 21497), (0x9A16, 21498), (0x9A17, 21499), (0x9A18, 21500), (0x9A19, 21501),
+    (0x9A1A, 21502), (0x9A1B, 21503), (0x9A1C, 21504), (0x9A1D, 21505), (0x9A1E, 21506), (0x9A1F, 21507),
+    (0x9A20, 21508), (0x9A21, 21509), (0x9A22, 21510), (0x9A23, 21511), (0x9A24, 21512), (0x9A25, 21513),
+    (0x9A26, 21514), (0x9A27, 21515), (0x9A28, 21516), (0x9A29, 21517), (0x9A2A, 21518), (0x9A2B, 21519),
+    (0x9A2C, 21520), (0x9A2D, 21521), (0x9A2E, 21522), (0x9A2F, 21523), (0x9A30, 21524), (0x9A31, 21525),
+    (0x9A32, 21526), (0x9A33, 21527), (0x9A34, 21528), (0x9A35, 21529), (0x9A36, 21530), (0x9A37, 21531),
+    (0x9A38, 21532), (0x9A39, 21533), (0x9A3A, 21534), (0x9A3B, 21535), (0x9A3C, 21536), (0x9A3D, 21537),
+    (0x9A3E, 21538), (0x9A3F, 21539), (0x9A40, 21540), (0x9A41, 21541), (0x9A42, 21542), (0x9A43, 21543),
+    (0x9A44, 21544), (0x9A45, 21545), (0x9A46, 21546), (0x9A47, 21547), (0x9A48, 21548), (0x9A49, 21549),
+    (0x9A4A, 21550), (0x9A4B, 21551), (0x9A4C, 21552), (0x9A4D, 21553), (0x9A4E, 21554), (0x9A4F, 21555),
+    (0x9A50, 21556), (0x9A51, 21557), (0x9A52, 21558), (0x9A53, 21559), (0x9A54, 21560), (0x9A55, 21561),
+    (0x9A56, 21562), (0x9A57, 21563), (0x9A58, 21564), (0x9A59, 21565), (0x9A5A, 21660), (0x9A5B, 21661),
+    (0x9A5C, 21662), (0x9A5D, 21663), (0x9A5E, 21664), (0x9A5F, 21665), (0x9A60, 21666), (0x9A61, 21667),
+    (0x9A62, 21668), (0x9A63, 21669), (0x9A64, 21670), (0x9A65, 21671), (0x9A66, 21672), (0x9A67, 21673),
+    (0x9A68, 21674), (0x9A69, 21675), (0x9A6A, 21676), (0x9A6B, 21677), (0x9A6C, 12522), (0x9A6D, 15871),
+    (0x9A6E, 14587), (0x9A6F, 15312), (0x9A70, 9654), (0x9A71, 13488), (0x9A72, 21678), (0x9A73, 9426),
+    (0x9A74, 12476), (0x9A75, 19349), (0x9A76, 13992), (0x9A77, 19350), (0x9A78, 19351), (0x9A79, 11737),
+    (0x9A7A, 19352), (0x9A7B, 16439), (0x9A7C, 14588), (0x9A7D, 19354), (0x9A7E, 11366), (0x9A7F, 19353),
+    (0x9A80, 19355), (0x9A81, 19356), (0x9A82, 12523), (0x9A83, 21679), (0x9A84, 11525), (0x9A85, 19357),
+    (0x9A86, 12515), (0x9A87, 10932), (0x9A88, 19358), (0x9A89, 21680), (0x9A8A, 19359), (0x9A8B, 9645),
+    (0x9A8C, 15368), (0x9A8D, 21681), (0x9A8E, 21682), (0x9A8F, 11880), (0x9A90, 19360), (0x9A91, 13284),
+    (0x9A92, 19361), (0x9A93, 19362), (0x9A94, 21683), (0x9A95, 21684), (0x9A96, 19363), (0x9A97, 13218),
+    (0x9A98, 19364), (0x9A99, 21685), (0x9A9A, 13782), (0x9A9B, 19365), (0x9A9C, 19366), (0x9A9D, 19367),
+    (0x9A9E, 19120), (0x9A9F, 19368), (0x9AA0, 19369), (0x9AA1, 12511), (0x9AA2, 19370), (0x9AA3, 19371),
+    (0x9AA4, 16317), (0x9AA5, 19372), (0x9AA6, 21686), (0x9AA7, 19373), (0x9AA8, 10774), (0x9AA9, 21687),
+    (0x9AAA, 21688), (0x9AAB, 21689), (0x9AAC, 21690), (0x9AAD, 21691), (0x9AAE, 21692), (0x9AAF, 21693),
+    (0x9AB0, 22542), (0x9AB1, 22541), (0x9AB2, 21694), (0x9AB3, 21695), (0x9AB4, 21696), (0x9AB5, 21697),
+    (0x9AB6, 22545), (0x9AB7, 22543), (0x9AB8, 10926), (0x9AB9, 21698), (0x9ABA, 22546), (0x9ABB, 21699),
+    (0x9ABC, 22547), (0x9ABD, 21700), (0x9ABE, 21701), (0x9ABF, 21702), (0x9AC0, 22549), (0x9AC1, 22548),
+    (0x9AC2, 22551), (0x9AC3, 21703), (0x9AC4, 21704), (0x9AC5, 22550), (0x9AC6, 21705), (0x9AC7, 21706),
+    (0x9AC8, 21707), (0x9AC9, 21708), (0x9ACA, 21709), (0x9ACB, 22552), (0x9ACC, 22553), (0x9ACD, 21710),
+    (0x9ACE, 21711), (0x9ACF, 21712), (0x9AD0, 21713), (0x9AD1, 22554), (0x9AD2, 21714), (0x9AD3, 14227),
+    (0x9AD4, 21715), (0x9AD5, 21716), (0x9AD6, 21717), (0x9AD7, 21718), (0x9AD8, 10608), (0x9AD9, 21719),
+    (0x9ADA, 21720), (0x9ADB, 21721), (0x9ADC, 21722), (0x9ADD, 21723), (0x9ADE, 21724), (0x9ADF, 22567),
+    (0x9AE0, 21725), (0x9AE1, 22568), (0x9AE2, 21726), (0x9AE3, 21727), (0x9AE4, 21728), (0x9AE5, 21729),
+    (0x9AE6, 22569), (0x9AE7, 21730), (0x9AE8, 21731), (0x9AE9, 21732), (0x9AEA, 21733), (0x9AEB, 22571),
+    (0x9AEC, 21734), (0x9AED, 22573), (0x9AEE, 21735), (0x9AEF, 22570), (0x9AF0, 21736), (0x9AF1, 21737),
+    (0x9AF2, 21738), (0x9AF3, 21739), (0x9AF4, 21740), (0x9AF5, 21741), (0x9AF6, 21742), (0x9AF7, 21743),
+    (0x9AF8, 21744), (0x9AF9, 22574), (0x9AFA, 21745), (0x9AFB, 22572), (0x9AFC, 21746), (0x9AFD, 21747),
+    (0x9AFE, 21748), (0x9AFF, 21749), (0x9B00, 21750), (0x9B01, 21751), (0x9B02, 21752), (0x9B03, 16490),
+    (0x9B04, 21753), (0x9B05, 21754), (0x9B06, 21755), (0x9B07, 21850), (0x9B08, 22575), (0x9B09, 21851),
+    (0x9B0A, 21852), (0x9B0B, 21853), (0x9B0C, 21854), (0x9B0D, 21855), (0x9B0E, 21856), (0x9B0F, 22576),
+    (0x9B10, 21857), (0x9B11, 21858), (0x9B12, 21859), (0x9B13, 22577), (0x9B14, 21860), (0x9B15, 21861),
+    (0x9B16, 21862), (0x9B17, 21863), (0x9B18, 21864), (0x9B19, 21865), (0x9B1A, 21866), (0x9B1B, 21867),
+    (0x9B1C, 21868), (0x9B1D, 21869), (0x9B1E, 21870), (0x9B1F, 22578), (0x9B20, 21871), (0x9B21, 21872),
+    (0x9B22, 21873), (0x9B23, 22579), (0x9B24, 21874), (0x9B25, 21875), (0x9B26, 21876), (0x9B27, 21877),
+    (0x9B28, 21878), (0x9B29, 21879), (0x9B2A, 21880), (0x9B2B, 21881), (0x9B2C, 21882), (0x9B2D, 21883),
+    (0x9B2E, 21884), (0x9B2F, 17238), (0x9B30, 21885), (0x9B31, 21886), (0x9B32, 16635), (0x9B33, 21887),
+    (0x9B34, 21888), (0x9B35, 21889), (0x9B36, 21890), (0x9B37, 21891), (0x9B38, 21892), (0x9B39, 21893),
+    (0x9B3A, 21894), (0x9B3B, 19182), (0x9B3C, 10812), (0x9B3D, 21895), (0x9B3E, 21896), (0x9B3F, 21897),
+    (0x9B40, 21898), (0x9B41, 11968), (0x9B42, 11189), (0x9B43, 22556), (0x9B44, 13244), (0x9B45, 22555),
+    (0x9B46, 21899), (0x9B47, 22557), (0x9B48, 22559), (0x9B49, 22558), (0x9B4A, 21900), (0x9B4B, 21901),
+    (0x9B4C, 21902), (0x9B4D, 22560), (0x9B4E, 21903), (0x9B4F, 14751), (0x9B50, 21904), (0x9B51, 22561),
+    (0x9B52, 21905), (0x9B53, 21906), (0x9B54, 12832), (0x9B55, 21907), (0x9B56, 21908), (0x9B57, 21909),
+    (0x9B58, 21910), (0x9B59, 21911), (0x9B5A, 21912), (0x9B5B, 21913), (0x9B5C, 21914), (0x9B5D, 21915),
+    (0x9B5E, 21916), (0x9B5F, 21917), (0x9B60, 21918), (0x9B61, 21919), (0x9B62, 21920), (0x9B63, 21921),
+    (0x9B64, 21922), (0x9B65, 21923), (0x9B66, 21924), (0x9B67, 21925), (0x9B68, 21926), (0x9B69, 21927),
+    (0x9B6A, 21928), (0x9B6B, 21929), (0x9B6C, 21930), (0x9B6D, 21931), (0x9B6E, 21932), (0x9B6F, 21933),
+    (0x9B70, 21934), (0x9B71, 21935), (0x9B72, 21936), (0x9B73, 21937), (0x9B74, 21938), (0x9B75, 21939),
+    (0x9B76, 21940), (0x9B77, 21941), (0x9B78, 21942), (0x9B79, 21943), (0x9B7A, 21944), (0x9B7B, 21945),
+    (0x9B7C, 22040), (0x9B7D, 22041), (0x9B7E, 22042), (0x9B7F, 22043), (0x9B80, 22044), (0x9B81, 22045),
+    (0x9B82, 22046), (0x9B83, 22047), (0x9B84, 22048), (0x9B85, 22049), (0x9B86, 22050), (0x9B87, 22051),
+    (0x9B88, 22052), (0x9B89, 22053), (0x9B8A, 22054), (0x9B8B, 22055), (0x9B8C, 22056), (0x9B8D, 22057),
+    (0x9B8E, 22058), (0x9B8F, 22059), (0x9B90, 22060), (0x9B91, 22061), (0x9B92, 22062), (0x9B93, 22063),
+    (0x9B94, 22064), (0x9B95, 22065), (0x9B96, 22066), (0x9B97, 22067), (0x9B98, 22068), (0x9B99, 22069),
+    (0x9B9A, 22070), (0x9B9B, 22071), (0x9B9C, 22072), (0x9B9D, 22073), (0x9B9E, 22074), (0x9B9F, 22075),
+    (0x9BA0, 22076), (0x9BA1, 22077), (0x9BA2, 22078), (0x9BA3, 22079), (0x9BA4, 22080), (0x9BA5, 22081),
+    (0x9BA6, 22082), (0x9BA7, 22083), (0x9BA8, 22084), (0x9BA9, 22085), (0x9BAA, 22086), (0x9BAB, 22087),
+    (0x9BAC, 22088), (0x9BAD, 22089), (0x9BAE, 22090), (0x9BAF, 22091), (0x9BB0, 22092), (0x9BB1, 22093),
+    (0x9BB2, 22094), (0x9BB3, 22095), (0x9BB4, 22096), (0x9BB5, 22097), (0x9BB6, 22098), (0x9BB7, 22099),
+    (0x9BB8, 22100), (0x9BB9, 22101), (0x9BBA, 22102), (0x9BBB, 22103), (0x9BBC, 22104), (0x9BBD, 22105),
+    (0x9BBE, 22106), (0x9BBF, 22107), (0x9BC0, 22108), (0x9BC1, 22109), (0x9BC2, 22110), (0x9BC3, 22111),
+    (0x9BC4, 22112), (0x9BC5, 22113), (0x9BC6, 22114), (0x9BC7, 22115), (0x9BC8, 22116), (0x9BC9, 22117),
+    (0x9BCA, 22118), (0x9BCB, 22119), (0x9BCC, 22120), (0x9BCD, 22121), (0x9BCE, 22122), (0x9BCF, 22123),
+    (0x9BD0, 22124), (0x9BD1, 22125), (0x9BD2, 22126), (0x9BD3, 22127), (0x9BD4, 22128), (0x9BD5, 22129),
+    (0x9BD6, 22130), (0x9BD7, 22131), (0x9BD8, 22132), (0x9BD9, 22133), (0x9BDA, 22134), (0x9BDB, 22135),
+    (0x9BDC, 22230), (0x9BDD, 22231), (0x9BDE, 22232), (0x9BDF, 22233), (0x9BE0, 22234), (0x9BE1, 22235),
+    (0x9BE2, 22236), (0x9BE3, 22237), (0x9BE4, 22238), (0x9BE5, 22239), (0x9BE6, 22240), (0x9BE7, 22241),
+    (0x9BE8, 22242), (0x9BE9, 22243), (0x9BEA, 22244), (0x9BEB, 22245), (0x9BEC, 22246), (0x9BED, 22247),
+    (0x9BEE, 22248), (0x9BEF, 22249), (0x9BF0, 22250), (0x9BF1, 22251), (0x9BF2, 22252), (0x9BF3, 22253),
+    (0x9BF4, 22254), (0x9BF5, 22255), (0x9BF6, 22256), (0x9BF7, 22257), (0x9BF8, 22258), (0x9BF9, 22259),
+    (0x9BFA, 22260), (0x9BFB, 22261), (0x9BFC, 22262), (0x9BFD, 22263), (0x9BFE, 22264), (0x9BFF, 22265),
+    (0x9C00, 22266), (0x9C01, 22267), (0x9C02, 22268), (0x9C03, 22269), (0x9C04, 22270), (0x9C05, 22271),
+    (0x9C06, 22272), (0x9C07, 22273), (0x9C08, 22274), (0x9C09, 22275), (0x9C0A, 22276), (0x9C0B, 22277),
+    (0x9C0C, 22278), (0x9C0D, 22279), (0x9C0E, 22280), (0x9C0F, 22281), (0x9C10, 22282), (0x9C11, 22283),
+    (0x9C12, 22284), (0x9C13, 22285), (0x9C14, 22286), (0x9C15, 22287), (0x9C16, 22288), (0x9C17, 22289),
+    (0x9C18, 22290), (0x9C19, 22291), (0x9C1A, 22292), (0x9C1B, 22293), (0x9C1C, 22294), (0x9C1D, 22295),
+    (0x9C1E, 22296), (0x9C1F, 22297), (0x9C20, 22298), (0x9C21, 22299), (0x9C22, 22300), (0x9C23, 22301),
+    (0x9C24, 22302), (0x9C25, 22303), (0x9C26, 22304), (0x9C27, 22305), (0x9C28, 22306), (0x9C29, 22307),
+    (0x9C2A, 22308), (0x9C2B, 22309), (0x9C2C, 22310), (0x9C2D, 22311), (0x9C2E, 22312), (0x9C2F, 22313),
+    (0x9C30, 22314), (0x9C31, 22315), (0x9C32, 22316), (0x9C33, 22317), (0x9C34, 22318), (0x9C35, 22319),
+    (0x9C36, 22320), (0x9C37, 22321), (0x9C38, 22322), (0x9C39, 22323), (0x9C3A, 22324), (0x9C3B, 22325),
+    (0x9C3C, 22420), (0x9C3D, 22421), (0x9C3E, 22422), (0x9C3F, 22423), (0x9C40, 22424), (0x9C41, 22425),
+    (0x9C42, 22426), (0x9C43, 22427), (0x9C44, 22428), (0x9C45, 22429), (0x9C46, 22430), (0x9C47, 22431),
+    (0x9C48, 22432), (0x9C49, 22433), (0x9C4A, 22434), (0x9C4B, 22435), (0x9C4C, 22436), (0x9C4D, 22437),
+    (0x9C4E, 22438), (0x9C4F, 22439), (0x9C50, 22440), (0x9C51, 22441), (0x9C52, 22442), (0x9C53, 22443),
+    (0x9C54, 22444), (0x9C55, 22445), (0x9C56, 22446), (0x9C57, 22447), (0x9C58, 22448), (0x9C59, 22449),
+    (0x9C5A, 22450), (0x9C5B, 22451), (0x9C5C, 22452), (0x9C5D, 22453), (0x9C5E, 22454), (0x9C5F, 22455),
+    (0x9C60, 22456), (0x9C61, 22457), (0x9C62, 22458), (0x9C63, 22459), (0x9C64, 22460), (0x9C65, 22461),
+    (0x9C66, 22462), (0x9C67, 22463), (0x9C68, 22464), (0x9C69, 22465), (0x9C6A, 22466), (0x9C6B, 22467),
+    (0x9C6C, 22468), (0x9C6D, 22469), (0x9C6E, 22470), (0x9C6F, 22471), (0x9C70, 22472), (0x9C71, 22473),
+    (0x9C72, 22474), (0x9C73, 22475), (0x9C74, 22476), (0x9C75, 22477), (0x9C76, 22478), (0x9C77, 22479),
+    (0x9C78, 22480), (0x9C79, 22481), (0x9C7A, 22482), (0x9C7B, 22483), (0x9C7C, 15742), (0x9C7D, 22484),
+    (0x9C7E, 22485), (0x9C7F, 22372), (0x9C80, 22486), (0x9C81, 12464), (0x9C82, 22373), (0x9C83, 22487),
+    (0x9C84, 22488), (0x9C85, 22374), (0x9C86, 22375), (0x9C87, 22376), (0x9C88, 22377), (0x9C89, 22489),
+    (0x9C8A, 22490), (0x9C8B, 22379), (0x9C8C, 22491), (0x9C8D, 9226), (0x9C8E, 22380), (0x9C8F, 22492),
+    (0x9C90, 22381), (0x9C91, 22382), (0x9C92, 22383), (0x9C93, 22493), (0x9C94, 22384), (0x9C95, 22385),
+    (0x9C96, 22494), (0x9C97, 22495), (0x9C98, 22496), (0x9C99, 22497), (0x9C9A, 22386), (0x9C9B, 22387),
+    (0x9C9C, 14957), (0x9C9D, 22498), (0x9C9E, 22388), (0x9C9F, 22389), (0x9CA0, 22390), (0x9CA1, 22391),
+    (0x9CA2, 22392), (0x9CA3, 22393), (0x9CA4, 12145), (0x9CA5, 22394), (0x9CA6, 22395), (0x9CA7, 22396),
+    (0x9CA8, 22397), (0x9CA9, 22398), (0x9CAA, 22499), (0x9CAB, 22399), (0x9CAC, 22500), (0x9CAD, 22400),
+    (0x9CAE, 22401), (0x9CAF, 22501), (0x9CB0, 22402), (0x9CB1, 22403), (0x9CB2, 22404), (0x9CB3, 22405),
+    (0x9CB4, 22406), (0x9CB5, 22407), (0x9CB6, 22408), (0x9CB7, 22409), (0x9CB8, 11693), (0x9CB9, 22502),
+    (0x9CBA, 22410), (0x9CBB, 22411), (0x9CBC, 22412), (0x9CBD, 22413), (0x9CBE, 22503), (0x9CBF, 22504),
+    (0x9CC0, 22505), (0x9CC1, 22506), (0x9CC2, 22507), (0x9CC3, 13675), (0x9CC4, 22414), (0x9CC5, 22415),
+    (0x9CC6, 22416), (0x9CC7, 22417), (0x9CC8, 22508), (0x9CC9, 22509), (0x9CCA, 22418), (0x9CCB, 22419),
+    (0x9CCC, 22516), (0x9CCD, 22517), (0x9CCE, 22518), (0x9CCF, 22519), (0x9CD0, 22520), (0x9CD1, 22510),
+    (0x9CD2, 22511), (0x9CD3, 22521), (0x9CD4, 22522), (0x9CD5, 22523), (0x9CD6, 9293), (0x9CD7, 22524),
+    (0x9CD8, 22525), (0x9CD9, 22526), (0x9CDA, 22512), (0x9CDB, 22513), (0x9CDC, 22527), (0x9CDD, 22528),
+    (0x9CDE, 12314), (0x9CDF, 22529), (0x9CE0, 22514), (0x9CE1, 22515), (0x9CE2, 22530), (0x9CE3, 22610),
+    (0x9CE4, 22611), (0x9CE5, 22612), (0x9CE6, 22613), (0x9CE7, 22614), (0x9CE8, 22615), (0x9CE9, 22616),
+    (0x9CEA, 22617), (0x9CEB, 22618), (0x9CEC, 22619), (0x9CED, 22620), (0x9CEE, 22621), (0x9CEF, 22622),
+    (0x9CF0, 22623), (0x9CF1, 22624), (0x9CF2, 22625), (0x9CF3, 22626), (0x9CF4, 22627), (0x9CF5, 22628),
+    (0x9CF6, 22629), (0x9CF7, 22630), (0x9CF8, 22631), (0x9CF9, 22632), (0x9CFA, 22633), (0x9CFB, 22634),
+    (0x9CFC, 22635), (0x9CFD, 22636), (0x9CFE, 22637), (0x9CFF, 22638), (0x9D00, 22639), (0x9D01, 22640),
+    (0x9D02, 22641), (0x9D03, 22642), (0x9D04, 22643), (0x9D05, 22644), (0x9D06, 22645), (0x9D07, 22646),
+    (0x9D08, 22647), (0x9D09, 22648), (0x9D0A, 22649), (0x9D0B, 22650), (0x9D0C, 22651), (0x9D0D, 22652),
+    (0x9D0E, 22653), (0x9D0F, 22654), (0x9D10, 22655), (0x9D11, 22656), (0x9D12, 22657), (0x9D13, 22658),
+    (0x9D14, 22659), (0x9D15, 22660), (0x9D16, 22661), (0x9D17, 22662), (0x9D18, 22663), (0x9D19, 22664),
+    (0x9D1A, 22665), (0x9D1B, 22666), (0x9D1C, 22667), (0x9D1D, 22668), (0x9D1E, 22669), (0x9D1F, 22670),
+    (0x9D20, 22671), (0x9D21, 22672), (0x9D22, 22673), (0x9D23, 22674), (0x9D24, 22675), (0x9D25, 22676),
+    (0x9D26, 22677), (0x9D27, 22678), (0x9D28, 22679), (0x9D29, 22680), (0x9D2A, 22681), (0x9D2B, 22682),
+    (0x9D2C, 22683), (0x9D2D, 22684), (0x9D2E, 22685), (0x9D2F, 22686), (0x9D30, 22687), (0x9D31, 22688),
+    (0x9D32, 22689), (0x9D33, 22690), (0x9D34, 22691), (0x9D35, 22692), (0x9D36, 22693), (0x9D37, 22694),
+    (0x9D38, 22695), (0x9D39, 22696), (0x9D3A, 22697), (0x9D3B, 22698), (0x9D3C, 22699), (0x9D3D, 22700),
+    (0x9D3E, 22701), (0x9D3F, 22702), (0x9D40, 22703), (0x9D41, 22704), (0x9D42, 22705), (0x9D43, 22800),
+    (0x9D44, 22801), (0x9D45, 22802), (0x9D46, 22803), (0x9D47, 22804), (0x9D48, 22805), (0x9D49, 22806),
+    (0x9D4A, 22807), (0x9D4B, 22808), (0x9D4C, 22809), (0x9D4D, 22810), (0x9D4E, 22811), (0x9D4F, 22812),
+    (0x9D50, 22813), (0x9D51, 22814), (0x9D52, 22815), (0x9D53, 22816), (0x9D54, 22817), (0x9D55, 22818),
+    (0x9D56, 22819), (0x9D57, 22820), (0x9D58, 22821), (0x9D59, 22822), (0x9D5A, 22823), (0x9D5B, 22824),
+    (0x9D5C, 22825), (0x9D5D, 22826), (0x9D5E, 22827), (0x9D5F, 22828), (0x9D60, 22829), (0x9D61, 22830),
+    (0x9D62, 22831), (0x9D63, 22832), (0x9D64, 22833), (0x9D65, 22834), (0x9D66, 22835), (0x9D67, 22836),
+    (0x9D68, 22837), (0x9D69, 22838), (0x9D6A, 22839), (0x9D6B, 22840), (0x9D6C, 22841), (0x9D6D, 22842),
+    (0x9D6E, 22843), (0x9D6F, 22844), (0x9D70, 22845), (0x9D71, 22846), (0x9D72, 22847), (0x9D73, 22848),
+    (0x9D74, 22849), (0x9D75, 22850), (0x9D76, 22851), (0x9D77, 22852), (0x9D78, 22853), (0x9D79, 22854),
+    (0x9D7A, 22855), (0x9D7B, 22856), (0x9D7C, 22857), (0x9D7D, 22858), (0x9D7E, 22859), (0x9D7F, 22860),
+    (0x9D80, 22861), (0x9D81, 22862), (0x9D82, 22863), (0x9D83, 22864), (0x9D84, 22865), (0x9D85, 22866),
+    (0x9D86, 22867), (0x9D87, 22868), (0x9D88, 22869), (0x9D89, 22870), (0x9D8A, 22871), (0x9D8B, 22872),
+    (0x9D8C, 22873), (0x9D8D, 22874), (0x9D8E, 22875), (0x9D8F, 22876), (0x9D90, 22877), (0x9D91, 22878),
+    (0x9D92, 22879), (0x9D93, 22880), (0x9D94, 22881), (0x9D95, 22882), (0x9D96, 22883), (0x9D97, 22884),
+    (0x9D98, 22885), (0x9D99, 22886), (0x9D9A, 22887), (0x9D9B, 22888), (0x9D9C, 22889), (0x9D9D, 22890),
+    (0x9D9E, 22891), (0x9D9F, 22892), (0x9DA0, 22893), (0x9DA1, 22894), (0x9DA2, 22895), (0x9DA3, 22990),
+    (0x9DA4, 22991), (0x9DA5, 22992), (0x9DA6, 22993), (0x9DA7, 22994), (0x9DA8, 22995), (0x9DA9, 22996),
+    (0x9DAA, 22997), (0x9DAB, 22998), (0x9DAC, 22999), (0x9DAD, 23000), (0x9DAE, 23001), (0x9DAF, 23002),
+    (0x9DB0, 23003), (0x9DB1, 23004), (0x9DB2, 23005), (0x9DB3, 23006), (0x9DB4, 23007), (0x9DB5, 23008),
+    (0x9DB6, 23009), (0x9DB7, 23010), (0x9DB8, 23011), (0x9DB9, 23012), (0x9DBA, 23013), (0x9DBB, 23014),
+    (0x9DBC, 23015), (0x9DBD, 23016), (0x9DBE, 23017), (0x9DBF, 23018), (0x9DC0, 23019), (0x9DC1, 23020),
+    (0x9DC2, 23021), (0x9DC3, 23022), (0x9DC4, 23023), (0x9DC5, 23024), (0x9DC6, 23025), (0x9DC7, 23026),
+    (0x9DC8, 23027), (0x9DC9, 23028), (0x9DCA, 23029), (0x9DCB, 23030), (0x9DCC, 23031), (0x9DCD, 23032),
+    (0x9DCE, 23033), (0x9DCF, 23034), (0x9DD0, 23035), (0x9DD1, 23036), (0x9DD2, 23037), (0x9DD3, 23038),
+    (0x9DD4, 23039), (0x9DD5, 23040), (0x9DD6, 23041), (0x9DD7, 23042), (0x9DD8, 23043), (0x9DD9, 23044),
+    (0x9DDA, 23045), (0x9DDB, 23046), (0x9DDC, 23047), (0x9DDD, 23048), (0x9DDE, 23049), (0x9DDF, 23050),
+    (0x9DE0, 23051), (0x9DE1, 23052), (0x9DE2, 23053), (0x9DE3, 23054), (0x9DE4, 23055), (0x9DE5, 23056),
+    (0x9DE6, 23057), (0x9DE7, 23058), (0x9DE8, 23059), (0x9DE9, 23060), (0x9DEA, 23061), (0x9DEB, 23062),
+    (0x9DEC, 23063), (0x9DED, 23064), (0x9DEE, 23065), (0x9DEF, 23066), (0x9DF0, 23067), (0x9DF1, 23068),
+    (0x9DF2, 23069), (0x9DF3, 23070), (0x9DF4, 23071), (0x9DF5, 23072), (0x9DF6, 23073), (0x9DF7, 23074),
+    (0x9DF8, 23075), (0x9DF9, 23076), (0x9DFA, 23077), (0x9DFB, 23078), (0x9DFC, 23079), (0x9DFD, 23080),
+    (0x9DFE, 23081), (0x9DFF, 23082), (0x9E00, 23083), (0x9E01, 23084), (0x9E02, 23085), (0x9E03, 23180),
+    (0x9E04, 23181), (0x9E05, 23182), (0x9E06, 23183), (0x9E07, 23184), (0x9E08, 23185), (0x9E09, 23186),
+    (0x9E0A, 23187), (0x9E0B, 23188), (0x9E0C, 23189), (0x9E0D, 23190), (0x9E0E, 23191), (0x9E0F, 23192),
+    (0x9E10, 23193), (0x9E11, 23194), (0x9E12, 23195), (0x9E13, 23196), (0x9E14, 23197), (0x9E15, 23198),
+    (0x9E16, 23199), (0x9E17, 23200), (0x9E18, 23201), (0x9E19, 23202), (0x9E1A, 23203), (0x9E1B, 23204),
+    (0x9E1C, 23205), (0x9E1D, 23206), (0x9E1E, 23207), (0x9E1F, 12906), (0x9E20, 21200), (0x9E21, 11311),
+    (0x9E22, 21201), (0x9E23, 12724), (0x9E24, 23208), (0x9E25, 13039), (0x9E26, 15322), (0x9E27, 23209),
+    (0x9E28, 21202), (0x9E29, 21203), (0x9E2A, 21204), (0x9E2B, 21205), (0x9E2C, 21206), (0x9E2D, 15323),
+    (0x9E2E, 23210), (0x9E2F, 15371), (0x9E30, 23211), (0x9E31, 21208), (0x9E32, 21207), (0x9E33, 15872),
+    (0x9E34, 23212), (0x9E35, 14585), (0x9E36, 21209), (0x9E37, 21211), (0x9E38, 21210), (0x9E39, 21212),
+    (0x9E3A, 21213), (0x9E3B, 23213), (0x9E3C, 23214), (0x9E3D, 10620), (0x9E3E, 21214), (0x9E3F, 10997),
+    (0x9E40, 23215), (0x9E41, 21215), (0x9E42, 21216), (0x9E43, 11758), (0x9E44, 21217), (0x9E45, 10241),
+    (0x9E46, 21218), (0x9E47, 21219), (0x9E48, 21220), (0x9E49, 21221), (0x9E4A, 13606), (0x9E4B, 21222),
+    (0x9E4C, 21223), (0x9E4D, 23216), (0x9E4E, 21224), (0x9E4F, 13099), (0x9E50, 23217), (0x9E51, 21225),
+    (0x9E52, 23218), (0x9E53, 23219), (0x9E54, 23220), (0x9E55, 21226), (0x9E56, 23221), (0x9E57, 21227),
+    (0x9E58, 22544), (0x9E59, 23222), (0x9E5A, 21228), (0x9E5B, 21229), (0x9E5C, 21230), (0x9E5D, 23223),
+    (0x9E5E, 21231), (0x9E5F, 23224), (0x9E60, 23225), (0x9E61, 23226), (0x9E62, 23227), (0x9E63, 21232),
+    (0x9E64, 10980), (0x9E65, 23228), (0x9E66, 21233), (0x9E67, 21234), (0x9E68, 21235), (0x9E69, 21236),
+    (0x9E6A, 21237), (0x9E6B, 21238), (0x9E6C, 21239), (0x9E6D, 21241), (0x9E6E, 23229), (0x9E6F, 23230),
+    (0x9E70, 15680), (0x9E71, 21240), (0x9E72, 23231), (0x9E73, 21242), (0x9E74, 23232), (0x9E75, 23233),
+    (0x9E76, 23234), (0x9E77, 23235), (0x9E78, 23236), (0x9E79, 23237), (0x9E7A, 23238), (0x9E7B, 23239),
+    (0x9E7C, 23240), (0x9E7D, 23241), (0x9E7E, 22161), (0x9E7F, 12470), (0x9E80, 23242), (0x9E81, 23243),
+    (0x9E82, 22583), (0x9E83, 23244), (0x9E84, 23245), (0x9E85, 23246), (0x9E86, 23247), (0x9E87, 22584),
+    (0x9E88, 22585), (0x9E89, 23248), (0x9E8A, 23249), (0x9E8B, 22586), (0x9E8C, 23250), (0x9E8D, 23251),
+    (0x9E8E, 23252), (0x9E8F, 23253), (0x9E90, 23254), (0x9E91, 23255), (0x9E92, 22587), (0x9E93, 12465),
+    (0x9E94, 23256), (0x9E95, 23257), (0x9E96, 23258), (0x9E97, 23259), (0x9E98, 23260), (0x9E99, 23261),
+    (0x9E9A, 23262), (0x9E9B, 23263), (0x9E9C, 23264), (0x9E9D, 22589), (0x9E9E, 23265), (0x9E9F, 22590),
+    (0x9EA0, 23266), (0x9EA1, 23267), (0x9EA2, 23268), (0x9EA3, 23269), (0x9EA4, 23270), (0x9EA5, 23271),
+    (0x9EA6, 12528), (0x9EA7, 23272), (0x9EA8, 23273), (0x9EA9, 23274), (0x9EAA, 23275), (0x9EAB, 23370),
+    (0x9EAC, 23371), (0x9EAD, 23372), (0x9EAE, 23373), (0x9EAF, 23374), (0x9EB0, 23375), (0x9EB1, 23376),
+    (0x9EB2, 23377), (0x9EB3, 23378), (0x9EB4, 22025), (0x9EB5, 23379), (0x9EB6, 23380), (0x9EB7, 23381),
+    (0x9EB8, 22024), (0x9EB9, 23382), (0x9EBA, 23383), (0x9EBB, 12518), (0x9EBC, 23384), (0x9EBD, 22580),
+    (0x9EBE, 22581), (0x9EBF, 23385), (0x9EC0, 23386), (0x9EC1, 23387), (0x9EC2, 23388), (0x9EC3, 23389),
+    (0x9EC4, 11153), (0x9EC5, 23390), (0x9EC6, 23391), (0x9EC7, 23392), (0x9EC8, 23393), (0x9EC9, 16883),
+    (0x9ECA, 23394), (0x9ECB, 23395), (0x9ECC, 23396), (0x9ECD, 14047), (0x9ECE, 12137), (0x9ECF, 21189),
+    (0x9ED0, 23397), (0x9ED1, 10983), (0x9ED2, 23398), (0x9ED3, 23399), (0x9ED4, 13408), (0x9ED5, 23400),
+    (0x9ED6, 23401), (0x9ED7, 23402), (0x9ED8, 12837), (0x9ED9, 23403), (0x9EDA, 23404), (0x9EDB, 22591),
+    (0x9EDC, 22592), (0x9EDD, 22593), (0x9EDE, 23405), (0x9EDF, 22595), (0x9EE0, 22594), (0x9EE1, 23406),
+    (0x9EE2, 22596), (0x9EE3, 23407), (0x9EE4, 23408), (0x9EE5, 22599), (0x9EE6, 23409), (0x9EE7, 22598),
+    (0x9EE8, 23410), (0x9EE9, 22597), (0x9EEA, 22600), (0x9EEB, 23411), (0x9EEC, 23412), (0x9EED, 23413),
+    (0x9EEE, 23414), (0x9EEF, 22601), (0x9EF0, 23415), (0x9EF1, 23416), (0x9EF2, 23417), (0x9EF3, 23418),
+    (0x9EF4, 23419), (0x9EF5, 23420), (0x9EF6, 23421), (0x9EF7, 23422), (0x9EF8, 23423), (0x9EF9, 20688),
+    (0x9EFA, 23424), (0x9EFB, 20689), (0x9EFC, 20690), (0x9EFD, 23425), (0x9EFE, 22353), (0x9EFF, 23426),
+    (0x9F00, 23427), (0x9F01, 23428), (0x9F02, 23429), (0x9F03, 23430), (0x9F04, 23431), (0x9F05, 23432),
+    (0x9F06, 23433), (0x9F07, 23434), (0x9F08, 23435), (0x9F09, 23436), (0x9F0A, 23437), (0x9F0B, 22354),
+    (0x9F0C, 23438), (0x9F0D, 22355), (0x9F0E, 10171), (0x9F0F, 23439), (0x9F10, 16655), (0x9F11, 23440),
+    (0x9F12, 23441), (0x9F13, 10771), (0x9F14, 23442), (0x9F15, 23443), (0x9F16, 23444), (0x9F17, 16652),
+    (0x9F18, 23445), (0x9F19, 17402), (0x9F1A, 23446), (0x9F1B, 23447), (0x9F1C, 23448), (0x9F1D, 23449),
+    (0x9F1E, 23450), (0x9F1F, 23451), (0x9F20, 14048), (0x9F21, 23452), (0x9F22, 22602), (0x9F23, 23453),
+    (0x9F24, 23454), (0x9F25, 23455), (0x9F26, 23456), (0x9F27, 23457), (0x9F28, 23458), (0x9F29, 23459),
+    (0x9F2A, 23460), (0x9F2B, 23461), (0x9F2C, 22603), (0x9F2D, 23462), (0x9F2E, 23463), (0x9F2F, 22604),
+    (0x9F30, 23464), (0x9F31, 23465), (0x9F32, 23560), (0x9F33, 23561), (0x9F34, 23562), (0x9F35, 23563),
+    (0x9F36, 23564), (0x9F37, 22606), (0x9F38, 23565), (0x9F39, 22605), (0x9F3A, 23566), (0x9F3B, 9254),
+    (0x9F3C, 23567), (0x9F3D, 22607), (0x9F3E, 22608), (0x9F3F, 23568), (0x9F40, 23569), (0x9F41, 23570),
+    (0x9F42, 23571), (0x9F43, 23572), (0x9F44, 22609), (0x9F45, 23573), (0x9F46, 23574), (0x9F47, 23575),
+    (0x9F48, 23576), (0x9F49, 23577), (0x9F4A, 23578), (0x9F4B, 23579), (0x9F4C, 23580), (0x9F4D, 23581),
+    (0x9F4E, 23582), (0x9F4F, 23583), (0x9F50, 13280), (0x9F51, 20445), (0x9F52, 23584), (0x9F53, 23585),
+    (0x9F54, 23586), (0x9F55, 23587), (0x9F56, 23588), (0x9F57, 23589), (0x9F58, 23590), (0x9F59, 23591),
+    (0x9F5A, 23592), (0x9F5B, 23593), (0x9F5C, 23594), (0x9F5D, 23595), (0x9F5E, 23596), (0x9F5F, 23597),
+    (0x9F60, 23598), (0x9F61, 23599), (0x9F62, 23600), (0x9F63, 23601), (0x9F64, 23602), (0x9F65, 23603),
+    (0x9F66, 23604), (0x9F67, 23605), (0x9F68, 23606), (0x9F69, 23607), (0x9F6A, 23608), (0x9F6B, 23609),
+    (0x9F6C, 23610), (0x9F6D, 23611), (0x9F6E, 23612), (0x9F6F, 23613), (0x9F70, 23614), (0x9F71, 23615),
+    (0x9F72, 23616), (0x9F73, 23617), (0x9F74, 23618), (0x9F75, 23619), (0x9F76, 23620), (0x9F77, 23621),
+    (0x9F78, 23622), (0x9F79, 23623), (0x9F7A, 23624), (0x9F7B, 23625), (0x9F7C, 23626), (0x9F7D, 23627),
+    (0x9F7E, 23628), (0x9F7F, 9656), (0x9F80, 22344), (0x9F81, 23629), (0x9F82, 23630), (0x9F83, 22345),
+    (0x9F84, 12323), (0x9F85, 22346), (0x9F86, 22347), (0x9F87, 22348), (0x9F88, 22349), (0x9F89, 22350),
+    (0x9F8A, 22351), (0x9F8B, 13588), (0x9F8C, 22352), (0x9F8D, 23631), (0x9F8E, 23632), (0x9F8F, 23633),
+    (0x9F90, 23634), (0x9F91, 23635), (0x9F92, 23636), (0x9F93, 23637), (0x9F94, 23638), (0x9F95, 23639),
+    (0x9F96, 23640), (0x9F97, 23641), (0x9F98, 23642), (0x9F99, 12345), (0x9F9A, 10743), (0x9F9B, 20687),
+    (0x9F9C, 23643), (0x9F9D, 23644), (0x9F9E, 23645), (0x9F9F, 10809), (0x9FA0, 16878), (0x9FA1, 23646),
+    (0x9FA2, 23647), (0x9FA3, 23648), (0x9FA4, 23649), (0x9FA5, 23650), (0x9FB4, 23775), (0x9FB5, 23783),
+    (0x9FB6, 23788), (0x9FB7, 23789), (0x9FB8, 23795), (0x9FB9, 23812), (0x9FBA, 23829), (0x9FBB, 23845),
+    (0xE000, 7886), (0xE001, 7887), (0xE002, 7888), (0xE003, 7889), (0xE004, 7890), (0xE005, 7891),
+    (0xE006, 7892), (0xE007, 7893), (0xE008, 7894), (0xE009, 7895), (0xE00A, 7896), (0xE00B, 7897),
+    (0xE00C, 7898), (0xE00D, 7899), (0xE00E, 7900), (0xE00F, 7901), (0xE010, 7902), (0xE011, 7903),
+    (0xE012, 7904), (0xE013, 7905), (0xE014, 7906), (0xE015, 7907), (0xE016, 7908), (0xE017, 7909),
+    (0xE018, 7910), (0xE019, 7911), (0xE01A, 7912), (0xE01B, 7913), (0xE01C, 7914), (0xE01D, 7915),
+    (0xE01E, 7916), (0xE01F, 7917), (0xE020, 7918), (0xE021, 7919), (0xE022, 7920), (0xE023, 7921),
+    (0xE024, 7922), (0xE025, 7923), (0xE026, 7924), (0xE027, 7925), (0xE028, 7926), (0xE029, 7927),
+    (0xE02A, 7928), (0xE02B, 7929), (0xE02C, 7930), (0xE02D, 7931), (0xE02E, 7932), (0xE02F, 7933),
+    (0xE030, 7934), (0xE031, 7935), (0xE032, 7936), (0xE033, 7937), (0xE034, 7938), (0xE035, 7939),
+    (0xE036, 7940), (0xE037, 7941), (0xE038, 7942), (0xE039, 7943), (0xE03A, 7944), (0xE03B, 7945),
+    (0xE03C, 7946), (0xE03D, 7947), (0xE03E, 7948), (0xE03F, 7949), (0xE040, 7950), (0xE041, 7951),
+    (0xE042, 7952), (0xE043, 7953), (0xE044, 7954), (0xE045, 7955), (0xE046, 7956), (0xE047, 7957),
+    (0xE048, 7958), (0xE049, 7959), (0xE04A, 7960), (0xE04B, 7961), (0xE04C, 7962), (0xE04D, 7963),
+    (0xE04E, 7964), (0xE04F, 7965), (0xE050, 7966), (0xE051, 7967), (0xE052, 7968), (0xE053, 7969),
+    (0xE054, 7970), (0xE055, 7971), (0xE056, 7972), (0xE057, 7973), (0xE058, 7974), (0xE059, 7975),
+    (0xE05A, 7976), (0xE05B, 7977), (0xE05C, 7978), (0xE05D, 7979), (0xE05E, 8076), (0xE05F, 8077),
+    (0xE060, 8078), (0xE061, 8079), (0xE062, 8080), (0xE063, 8081), (0xE064, 8082), (0xE065, 8083),
+    (0xE066, 8084), (0xE067, 8085), (0xE068, 8086), (0xE069, 8087), (0xE06A, 8088), (0xE06B, 8089),
+    (0xE06C, 8090), (0xE06D, 8091), (0xE06E, 8092), (0xE06F, 8093), (0xE070, 8094), (0xE071, 8095),
+    (0xE072, 8096), (0xE073, 8097), (0xE074, 8098), (0xE075, 8099), (0xE076, 8100), (0xE077, 8101),
+    (0xE078, 8102), (0xE079, 8103), (0xE07A, 8104), (0xE07B, 8105), (0xE07C, 8106), (0xE07D, 8107),
+    (0xE07E, 8108), (0xE07F, 8109), (0xE080, 8110), (0xE081, 8111), (0xE082, 8112), (0xE083, 8113),
+    (0xE084, 8114), (0xE085, 8115), (0xE086, 8116), (0xE087, 8117), (0xE088, 8118), (0xE089, 8119),
+    (0xE08A, 8120), (0xE08B, 8121), (0xE08C, 8122), (0xE08D, 8123), (0xE08E, 8124), (0xE08F, 8125),
+    (0xE090, 8126), (0xE091, 8127), (0xE092, 8128), (0xE093, 8129), (0xE094, 8130), (0xE095, 8131),
+    (0xE096, 8132), (0xE097, 8133), (0xE098, 8134), (0xE099, 8135), (0xE09A, 8136), (0xE09B, 8137),
+    (0xE09C, 8138), (0xE09D, 8139), (0xE09E, 8140), (0xE09F, 8141), (0xE0A0, 8142), (0xE0A1, 8143),
+    (0xE0A2, 8144), (0xE0A3, 8145), (0xE0A4, 8146), (0xE0A5, 8147), (0xE0A6, 8148), (0xE0A7, 8149),
+    (0xE0A8, 8150), (0xE0A9, 8151), (0xE0AA, 8152), (0xE0AB, 8153), (0xE0AC, 8154), (0xE0AD, 8155),
+    (0xE0AE, 8156), (0xE0AF, 8157), (0xE0B0, 8158), (0xE0B1, 8159), (0xE0B2, 8160), (0xE0B3, 8161),
+    (0xE0B4, 8162), (0xE0B5, 8163), (0xE0B6, 8164), (0xE0B7, 8165), (0xE0B8, 8166), (0xE0B9, 8167),
+    (0xE0BA, 8168), (0xE0BB, 8169), (0xE0BC, 8266), (0xE0BD, 8267), (0xE0BE, 8268), (0xE0BF, 8269),
+    (0xE0C0, 8270), (0xE0C1, 8271), (0xE0C2, 8272), (0xE0C3, 8273), (0xE0C4, 8274), (0xE0C5, 8275),
+    (0xE0C6, 8276), (0xE0C7, 8277), (0xE0C8, 8278), (0xE0C9, 8279), (0xE0CA, 8280), (0xE0CB, 8281),
+    (0xE0CC, 8282), (0xE0CD, 8283), (0xE0CE, 8284), (0xE0CF, 8285), (0xE0D0, 8286), (0xE0D1, 8287),
+    (0xE0D2, 8288), (0xE0D3, 8289), (0xE0D4, 8290), (0xE0D5, 8291), (0xE0D6, 8292), (0xE0D7, 8293),
+    (0xE0D8, 8294), (0xE0D9, 8295), (0xE0DA, 8296), (0xE0DB, 8297), (0xE0DC, 8298), (0xE0DD, 8299),
+    (0xE0DE, 8300), (0xE0DF, 8301), (0xE0E0, 8302), (0xE0E1, 8303), (0xE0E2, 8304), (0xE0E3, 8305),
+    (0xE0E4, 8306), (0xE0E5, 8307), (0xE0E6, 8308), (0xE0E7, 8309), (0xE0E8, 8310), (0xE0E9, 8311),
+    (0xE0EA, 8312), (0xE0EB, 8313), (0xE0EC, 8314), (0xE0ED, 8315), (0xE0EE, 8316), (0xE0EF, 8317),
+    (0xE0F0, 8318), (0xE0F1, 8319), (0xE0F2, 8320), (0xE0F3, 8321), (0xE0F4, 8322), (0xE0F5, 8323),
+    (0xE0F6, 8324), (0xE0F7, 8325), (0xE0F8, 8326), (0xE0F9, 8327), (0xE0FA, 8328), (0xE0FB, 8329),
+    (0xE0FC, 8330), (0xE0FD, 8331), (0xE0FE, 8332), (0xE0FF, 8333), (0xE100, 8334), (0xE101, 8335),
+    (0xE102, 8336), (0xE103, 8337), (0xE104, 8338), (0xE105, 8339), (0xE106, 8340), (0xE107, 8341),
+    (0xE108, 8342), (0xE109, 8343), (0xE10A, 8344), (0xE10B, 8345), (0xE10C, 8346), (0xE10D, 8347),
+    (0xE10E, 8348), (0xE10F, 8349), (0xE110, 8350), (0xE111, 8351), (0xE112, 8352), (0xE113, 8353),
+    (0xE114, 8354), (0xE115, 8355), (0xE116, 8356), (0xE117, 8357), (0xE118, 8358), (0xE119, 8359),
+    (0xE11A, 8456), (0xE11B, 8457), (0xE11C, 8458), (0xE11D, 8459), (0xE11E, 8460), (0xE11F, 8461),
+    (0xE120, 8462), (0xE121, 8463), (0xE122, 8464), (0xE123, 8465), (0xE124, 8466), (0xE125, 8467),
+    (0xE126, 8468), (0xE127, 8469), (0xE128, 8470), (0xE129, 8471), (0xE12A, 8472), (0xE12B, 8473),
+    (0xE12C, 8474), (0xE12D, 8475), (0xE12E, 8476), (0xE12F, 8477), (0xE130, 8478), (0xE131, 8479),
+    (0xE132, 8480), (0xE133, 8481), (0xE134, 8482), (0xE135, 8483), (0xE136, 8484), (0xE137, 8485),
+    (0xE138, 8486), (0xE139, 8487), (0xE13A, 8488), (0xE13B, 8489), (0xE13C, 8490), (0xE13D, 8491),
+    (0xE13E, 8492), (0xE13F, 8493), (0xE140, 8494), (0xE141, 8495), (0xE142, 8496), (0xE143, 8497),
+    (0xE144, 8498), (0xE145, 8499), (0xE146, 8500), (0xE147, 8501), (0xE148, 8502), (0xE149, 8503),
+    (0xE14A, 8504), (0xE14B, 8505), (0xE14C, 8506), (0xE14D, 8507), (0xE14E, 8508), (0xE14F, 8509),
+    (0xE150, 8510), (0xE151, 8511), (0xE152, 8512), (0xE153, 8513), (0xE154, 8514), (0xE155, 8515),
+    (0xE156, 8516), (0xE157, 8517), (0xE158, 8518), (0xE159, 8519), (0xE15A, 8520), (0xE15B, 8521),
+    (0xE15C, 8522), (0xE15D, 8523), (0xE15E, 8524), (0xE15F, 8525), (0xE160, 8526), (0xE161, 8527),
+    (0xE162, 8528), (0xE163, 8529), (0xE164, 8530), (0xE165, 8531), (0xE166, 8532), (0xE167, 8533),
+    (0xE168, 8534), (0xE169, 8535), (0xE16A, 8536), (0xE16B, 8537), (0xE16C, 8538), (0xE16D, 8539),
+    (0xE16E, 8540), (0xE16F, 8541), (0xE170, 8542), (0xE171, 8543), (0xE172, 8544), (0xE173, 8545),
+    (0xE174, 8546), (0xE175, 8547), (0xE176, 8548), (0xE177, 8549), (0xE178, 8646), (0xE179, 8647),
+    (0xE17A, 8648), (0xE17B, 8649), (0xE17C, 8650), (0xE17D, 8651), (0xE17E, 8652), (0xE17F, 8653),
+    (0xE180, 8654), (0xE181, 8655), (0xE182, 8656), (0xE183, 8657), (0xE184, 8658), (0xE185, 8659),
+    (0xE186, 8660), (0xE187, 8661), (0xE188, 8662), (0xE189, 8663), (0xE18A, 8664), (0xE18B, 8665),
+    (0xE18C, 8666), (0xE18D, 8667), (0xE18E, 8668), (0xE18F, 8669), (0xE190, 8670), (0xE191, 8671),
+    (0xE192, 8672), (0xE193, 8673), (0xE194, 8674), (0xE195, 8675), (0xE196, 8676), (0xE197, 8677),
+    (0xE198, 8678), (0xE199, 8679), (0xE19A, 8680), (0xE19B, 8681), (0xE19C, 8682), (0xE19D, 8683),
+    (0xE19E, 8684), (0xE19F, 8685), (0xE1A0, 8686), (0xE1A1, 8687), (0xE1A2, 8688), (0xE1A3, 8689),
+    (0xE1A4, 8690), (0xE1A5, 8691), (0xE1A6, 8692), (0xE1A7, 8693), (0xE1A8, 8694), (0xE1A9, 8695),
+    (0xE1AA, 8696), (0xE1AB, 8697), (0xE1AC, 8698), (0xE1AD, 8699), (0xE1AE, 8700), (0xE1AF, 8701),
+    (0xE1B0, 8702), (0xE1B1, 8703), (0xE1B2, 8704), (0xE1B3, 8705), (0xE1B4, 8706), (0xE1B5, 8707),
+    (0xE1B6, 8708), (0xE1B7, 8709), (0xE1B8, 8710), (0xE1B9, 8711), (0xE1BA, 8712), (0xE1BB, 8713),
+    (0xE1BC, 8714), (0xE1BD, 8715), (0xE1BE, 8716), (0xE1BF, 8717), (0xE1C0, 8718), (0xE1C1, 8719),
+    (0xE1C2, 8720), (0xE1C3, 8721), (0xE1C4, 8722), (0xE1C5, 8723), (0xE1C6, 8724), (0xE1C7, 8725),
+    (0xE1C8, 8726), (0xE1C9, 8727), (0xE1CA, 8728), (0xE1CB, 8729), (0xE1CC, 8730), (0xE1CD, 8731),
+    (0xE1CE, 8732), (0xE1CF, 8733), (0xE1D0, 8734), (0xE1D1, 8735), (0xE1D2, 8736), (0xE1D3, 8737),
+    (0xE1D4, 8738), (0xE1D5, 8739), (0xE1D6, 8836), (0xE1D7, 8837), (0xE1D8, 8838), (0xE1D9, 8839),
+    (0xE1DA, 8840), (0xE1DB, 8841), (0xE1DC, 8842), (0xE1DD, 8843), (0xE1DE, 8844), (0xE1DF, 8845),
+    (0xE1E0, 8846), (0xE1E1, 8847), (0xE1E2, 8848), (0xE1E3, 8849), (0xE1E4, 8850), (0xE1E5, 8851),
+    (0xE1E6, 8852), (0xE1E7, 8853), (0xE1E8, 8854), (0xE1E9, 8855), (0xE1EA, 8856), (0xE1EB, 8857),
+    (0xE1EC, 8858), (0xE1ED, 8859), (0xE1EE, 8860), (0xE1EF, 8861), (0xE1F0, 8862), (0xE1F1, 8863),
+    (0xE1F2, 8864), (0xE1F3, 8865), (0xE1F4, 8866), (0xE1F5, 8867), (0xE1F6, 8868), (0xE1F7, 8869),
+    (0xE1F8, 8870), (0xE1F9, 8871), (0xE1FA, 8872), (0xE1FB, 8873), (0xE1FC, 8874), (0xE1FD, 8875),
+    (0xE1FE, 8876), (0xE1FF, 8877), (0xE200, 8878), (0xE201, 8879), (0xE202, 8880), (0xE203, 8881),
+    (0xE204, 8882), (0xE205, 8883), (0xE206, 8884), (0xE207, 8885), (0xE208, 8886), (0xE209, 8887),
+    (0xE20A, 8888), (0xE20B, 8889), (0xE20C, 8890), (0xE20D, 8891), (0xE20E, 8892), (0xE20F, 8893),
+    (0xE210, 8894), (0xE211, 8895), (0xE212, 8896), (0xE213, 8897), (0xE214, 8898), (0xE215, 8899),
+    (0xE216, 8900), (0xE217, 8901), (0xE218, 8902), (0xE219, 8903), (0xE21A, 8904), (0xE21B, 8905),
+    (0xE21C, 8906), (0xE21D, 8907), (0xE21E, 8908), (0xE21F, 8909), (0xE220, 8910), (0xE221, 8911),
+    (0xE222, 8912), (0xE223, 8913), (0xE224, 8914), (0xE225, 8915), (0xE226, 8916), (0xE227, 8917),
+    (0xE228, 8918), (0xE229, 8919), (0xE22A, 8920), (0xE22B, 8921), (0xE22C, 8922), (0xE22D, 8923),
+    (0xE22E, 8924), (0xE22F, 8925), (0xE230, 8926), (0xE231, 8927), (0xE232, 8928), (0xE233, 8929),
+    (0xE234, 22706), (0xE235, 22707), (0xE236, 22708), (0xE237, 22709), (0xE238, 22710), (0xE239, 22711),
+    (0xE23A, 22712), (0xE23B, 22713), (0xE23C, 22714), (0xE23D, 22715), (0xE23E, 22716), (0xE23F, 22717),
+    (0xE240, 22718), (0xE241, 22719), (0xE242, 22720), (0xE243, 22721), (0xE244, 22722), (0xE245, 22723),
+    (0xE246, 22724), (0xE247, 22725), (0xE248, 22726), (0xE249, 22727), (0xE24A, 22728), (0xE24B, 22729),
+    (0xE24C, 22730), (0xE24D, 22731), (0xE24E, 22732), (0xE24F, 22733), (0xE250, 22734), (0xE251, 22735),
+    (0xE252, 22736), (0xE253, 22737), (0xE254, 22738), (0xE255, 22739), (0xE256, 22740), (0xE257, 22741),
+    (0xE258, 22742), (0xE259, 22743), (0xE25A, 22744), (0xE25B, 22745), (0xE25C, 22746), (0xE25D, 22747),
+    (0xE25E, 22748), (0xE25F, 22749), (0xE260, 22750), (0xE261, 22751), (0xE262, 22752), (0xE263, 22753),
+    (0xE264, 22754), (0xE265, 22755), (0xE266, 22756), (0xE267, 22757), (0xE268, 22758), (0xE269, 22759),
+    (0xE26A, 22760), (0xE26B, 22761), (0xE26C, 22762), (0xE26D, 22763), (0xE26E, 22764), (0xE26F, 22765),
+    (0xE270, 22766), (0xE271, 22767), (0xE272, 22768), (0xE273, 22769), (0xE274, 22770), (0xE275, 22771),
+    (0xE276, 22772), (0xE277, 22773), (0xE278, 22774), (0xE279, 22775), (0xE27A, 22776), (0xE27B, 22777),
+    (0xE27C, 22778), (0xE27D, 22779), (0xE27E, 22780), (0xE27F, 22781), (0xE280, 22782), (0xE281, 22783),
+    (0xE282, 22784), (0xE283, 22785), (0xE284, 22786), (0xE285, 22787), (0xE286, 22788), (0xE287, 22789),
+    (0xE288, 22790), (0xE289, 22791), (0xE28A, 22792), (0xE28B, 22793), (0xE28C, 22794), (0xE28D, 22795),
+    (0xE28E, 22796), (0xE28F, 22797), (0xE290, 22798), (0xE291, 22799), (0xE292, 22896), (0xE293, 22897),
+    (0xE294, 22898), (0xE295, 22899), (0xE296, 22900), (0xE297, 22901), (0xE298, 22902), (0xE299, 22903),
+    (0xE29A, 22904), (0xE29B, 22905), (0xE29C, 22906), (0xE29D, 22907), (0xE29E, 22908), (0xE29F, 22909),
+    (0xE2A0, 22910), (0xE2A1, 22911), (0xE2A2, 22912), (0xE2A3, 22913), (0xE2A4, 22914), (0xE2A5, 22915),
+    (0xE2A6, 22916), (0xE2A7, 22917), (0xE2A8, 22918), (0xE2A9, 22919), (0xE2AA, 22920), (0xE2AB, 22921),
+    (0xE2AC, 22922), (0xE2AD, 22923), (0xE2AE, 22924), (0xE2AF, 22925), (0xE2B0, 22926), (0xE2B1, 22927),
+    (0xE2B2, 22928), (0xE2B3, 22929), (0xE2B4, 22930), (0xE2B5, 22931), (0xE2B6, 22932), (0xE2B7, 22933),
+    (0xE2B8, 22934), (0xE2B9, 22935), (0xE2BA, 22936), (0xE2BB, 22937), (0xE2BC, 22938), (0xE2BD, 22939),
+    (0xE2BE, 22940), (0xE2BF, 22941), (0xE2C0, 22942), (0xE2C1, 22943), (0xE2C2, 22944), (0xE2C3, 22945),
+    (0xE2C4, 22946), (0xE2C5, 22947), (0xE2C6, 22948), (0xE2C7, 22949), (0xE2C8, 22950), (0xE2C9, 22951),
+    (0xE2CA, 22952), (0xE2CB, 22953), (0xE2CC, 22954), (0xE2CD, 22955), (0xE2CE, 22956), (0xE2CF, 22957),
+    (0xE2D0, 22958), (0xE2D1, 22959), (0xE2D2, 22960), (0xE2D3, 22961), (0xE2D4, 22962), (0xE2D5, 22963),
+    (0xE2D6, 22964), (0xE2D7, 22965), (0xE2D8, 22966), (0xE2D9, 22967), (0xE2DA, 22968), (0xE2DB, 22969),
+    (0xE2DC, 22970), (0xE2DD, 22971), (0xE2DE, 22972), (0xE2DF, 22973), (0xE2E0, 22974), (0xE2E1, 22975),
+    (0xE2E2, 22976), (0xE2E3, 22977), (0xE2E4, 22978), (0xE2E5, 22979), (0xE2E6, 22980), (0xE2E7, 22981),
+    (0xE2E8, 22982), (0xE2E9, 22983), (0xE2EA, 22984), (0xE2EB, 22985), (0xE2EC, 22986), (0xE2ED, 22987),
+    (0xE2EE, 22988), (0xE2EF, 22989), (0xE2F0, 23086), (0xE2F1, 23087), (0xE2F2, 23088), (0xE2F3, 23089),
+    (0xE2F4, 23090), (0xE2F5, 23091), (0xE2F6, 23092), (0xE2F7, 23093), (0xE2F8, 23094), (0xE2F9, 23095),
+    (0xE2FA, 23096), (0xE2FB, 23097), (0xE2FC, 23098), (0xE2FD, 23099), (0xE2FE, 23100), (0xE2FF, 23101),
+    (0xE300, 23102), (0xE301, 23103), (0xE302, 23104), (0xE303, 23105), (0xE304, 23106), (0xE305, 23107),
+    (0xE306, 23108), (0xE307, 23109), (0xE308, 23110), (0xE309, 23111), (0xE30A, 23112), (0xE30B, 23113),
+    (0xE30C, 23114), (0xE30D, 23115), (0xE30E, 23116), (0xE30F, 23117), (0xE310, 23118), (0xE311, 23119),
+    (0xE312, 23120), (0xE313, 23121), (0xE314, 23122), (0xE315, 23123), (0xE316, 23124), (0xE317, 23125),
+    (0xE318, 23126), (0xE319, 23127), (0xE31A, 23128), (0xE31B, 23129), (0xE31C, 23130), (0xE31D, 23131),
+    (0xE31E, 23132), (0xE31F, 23133), (0xE320, 23134), (0xE321, 23135), (0xE322, 23136), (0xE323, 23137),
+    (0xE324, 23138), (0xE325, 23139), (0xE326, 23140), (0xE327, 23141), (0xE328, 23142), (0xE329, 23143),
+    (0xE32A, 23144), (0xE32B, 23145), (0xE32C, 23146), (0xE32D, 23147), (0xE32E, 23148), (0xE32F, 23149),
+    (0xE330, 23150), (0xE331, 23151), (0xE332, 23152), (0xE333, 23153), (0xE334, 23154), (0xE335, 23155),
+    (0xE336, 23156), (0xE337, 23157), (0xE338, 23158), (0xE339, 23159), (0xE33A, 23160), (0xE33B, 23161),
+    (0xE33C, 23162), (0xE33D, 23163), (0xE33E, 23164), (0xE33F, 23165), (0xE340, 23166), (0xE341, 23167),
+    (0xE342, 23168), (0xE343, 23169), (0xE344, 23170), (0xE345, 23171), (0xE346, 23172), (0xE347, 23173),
+    (0xE348, 23174), (0xE349, 23175), (0xE34A, 23176), (0xE34B, 23177), (0xE34C, 23178), (0xE34D, 23179),
+    (0xE34E, 23276), (0xE34F, 23277), (0xE350, 23278), (0xE351, 23279), (0xE352, 23280), (0xE353, 23281),
+    (0xE354, 23282), (0xE355, 23283), (0xE356, 23284), (0xE357, 23285), (0xE358, 23286), (0xE359, 23287),
+    (0xE35A, 23288), (0xE35B, 23289), (0xE35C, 23290), (0xE35D, 23291), (0xE35E, 23292), (0xE35F, 23293),
+    (0xE360, 23294), (0xE361, 23295), (0xE362, 23296), (0xE363, 23297), (0xE364, 23298), (0xE365, 23299),
+    (0xE366, 23300), (0xE367, 23301), (0xE368, 23302), (0xE369, 23303), (0xE36A, 23304), (0xE36B, 23305),
+    (0xE36C, 23306), (0xE36D, 23307), (0xE36E, 23308), (0xE36F, 23309), (0xE370, 23310), (0xE371, 23311),
+    (0xE372, 23312), (0xE373, 23313), (0xE374, 23314), (0xE375, 23315), (0xE376, 23316), (0xE377, 23317),
+    (0xE378, 23318), (0xE379, 23319), (0xE37A, 23320), (0xE37B, 23321), (0xE37C, 23322), (0xE37D, 23323),
+    (0xE37E, 23324), (0xE37F, 23325), (0xE380, 23326), (0xE381, 23327), (0xE382, 23328), (0xE383, 23329),
+    (0xE384, 23330), (0xE385, 23331), (0xE386, 23332), (0xE387, 23333), (0xE388, 23334), (0xE389, 23335),
+    (0xE38A, 23336), (0xE38B, 23337), (0xE38C, 23338), (0xE38D, 23339), (0xE38E, 23340), (0xE38F, 23341),
+    (0xE390, 23342), (0xE391, 23343), (0xE392, 23344), (0xE393, 23345), (0xE394, 23346), (0xE395, 23347),
+    (0xE396, 23348), (0xE397, 23349), (0xE398, 23350), (0xE399, 23351), (0xE39A, 23352), (0xE39B, 23353),
+    (0xE39C, 23354), (0xE39D, 23355), (0xE39E, 23356), (0xE39F, 23357), (0xE3A0, 23358), (0xE3A1, 23359),
+    (0xE3A2, 23360), (0xE3A3, 23361), (0xE3A4, 23362), (0xE3A5, 23363), (0xE3A6, 23364), (0xE3A7, 23365),
+    (0xE3A8, 23366), (0xE3A9, 23367), (0xE3AA, 23368), (0xE3AB, 23369), (0xE3AC, 23466), (0xE3AD, 23467),
+    (0xE3AE, 23468), (0xE3AF, 23469), (0xE3B0, 23470), (0xE3B1, 23471), (0xE3B2, 23472), (0xE3B3, 23473),
+    (0xE3B4, 23474), (0xE3B5, 23475), (0xE3B6, 23476), (0xE3B7, 23477), (0xE3B8, 23478), (0xE3B9, 23479),
+    (0xE3BA, 23480), (0xE3BB, 23481), (0xE3BC, 23482), (0xE3BD, 23483), (0xE3BE, 23484), (0xE3BF, 23485),
+    (0xE3C0, 23486), (0xE3C1, 23487), (0xE3C2, 23488), (0xE3C3, 23489), (0xE3C4, 23490), (0xE3C5, 23491),
+    (0xE3C6, 23492), (0xE3C7, 23493), (0xE3C8, 23494), (0xE3C9, 23495), (0xE3CA, 23496), (0xE3CB, 23497),
+    (0xE3CC, 23498), (0xE3CD, 23499), (0xE3CE, 23500), (0xE3CF, 23501), (0xE3D0, 23502), (0xE3D1, 23503),
+    (0xE3D2, 23504), (0xE3D3, 23505), (0xE3D4, 23506), (0xE3D5, 23507), (0xE3D6, 23508), (0xE3D7, 23509),
+    (0xE3D8, 23510), (0xE3D9, 23511), (0xE3DA, 23512), (0xE3DB, 23513), (0xE3DC, 23514), (0xE3DD, 23515),
+    (0xE3DE, 23516), (0xE3DF, 23517), (0xE3E0, 23518), (0xE3E1, 23519), (0xE3E2, 23520), (0xE3E3, 23521),
+    (0xE3E4, 23522), (0xE3E5, 23523), (0xE3E6, 23524), (0xE3E7, 23525), (0xE3E8, 23526), (0xE3E9, 23527),
+    (0xE3EA, 23528), (0xE3EB, 23529), (0xE3EC, 23530), (0xE3ED, 23531), (0xE3EE, 23532), (0xE3EF, 23533),
+    (0xE3F0, 23534), (0xE3F1, 23535), (0xE3F2, 23536), (0xE3F3, 23537), (0xE3F4, 23538), (0xE3F5, 23539),
+    (0xE3F6, 23540), (0xE3F7, 23541), (0xE3F8, 23542), (0xE3F9, 23543), (0xE3FA, 23544), (0xE3FB, 23545),
+    (0xE3FC, 23546), (0xE3FD, 23547), (0xE3FE, 23548), (0xE3FF, 23549), (0xE400, 23550), (0xE401, 23551),
+    (0xE402, 23552), (0xE403, 23553), (0xE404, 23554), (0xE405, 23555), (0xE406, 23556), (0xE407, 23557),
+    (0xE408, 23558), (0xE409, 23559), (0xE40A, 23656), (0xE40B, 23657), (0xE40C, 23658), (0xE40D, 23659),
+    (0xE40E, 23660), (0xE40F, 23661), (0xE410, 23662), (0xE411, 23663), (0xE412, 23664), (0xE413, 23665),
+    (0xE414, 23666), (0xE415, 23667), (0xE416, 23668), (0xE417, 23669), (0xE418, 23670), (0xE419, 23671),
+    (0xE41A, 23672), (0xE41B, 23673), (0xE41C, 23674), (0xE41D, 23675), (0xE41E, 23676), (0xE41F, 23677),
+    (0xE420, 23678), (0xE421, 23679), (0xE422, 23680), (0xE423, 23681), (0xE424, 23682), (0xE425, 23683),
+    (0xE426, 23684), (0xE427, 23685), (0xE428, 23686), (0xE429, 23687), (0xE42A, 23688), (0xE42B, 23689),
+    (0xE42C, 23690), (0xE42D, 23691), (0xE42E, 23692), (0xE42F, 23693), (0xE430, 23694), (0xE431, 23695),
+    (0xE432, 23696), (0xE433, 23697), (0xE434, 23698), (0xE435, 23699), (0xE436, 23700), (0xE437, 23701),
+    (0xE438, 23702), (0xE439, 23703), (0xE43A, 23704), (0xE43B, 23705), (0xE43C, 23706), (0xE43D, 23707),
+    (0xE43E, 23708), (0xE43F, 23709), (0xE440, 23710), (0xE441, 23711), (0xE442, 23712), (0xE443, 23713),
+    (0xE444, 23714), (0xE445, 23715), (0xE446, 23716), (0xE447, 23717), (0xE448, 23718), (0xE449, 23719),
+    (0xE44A, 23720), (0xE44B, 23721), (0xE44C, 23722), (0xE44D, 23723), (0xE44E, 23724), (0xE44F, 23725),
+    (0xE450, 23726), (0xE451, 23727), (0xE452, 23728), (0xE453, 23729), (0xE454, 23730), (0xE455, 23731),
+    (0xE456, 23732), (0xE457, 23733), (0xE458, 23734), (0xE459, 23735), (0xE45A, 23736), (0xE45B, 23737),
+    (0xE45C, 23738), (0xE45D, 23739), (0xE45E, 23740), (0xE45F, 23741), (0xE460, 23742), (0xE461, 23743),
+    (0xE462, 23744), (0xE463, 23745), (0xE464, 23746), (0xE465, 23747), (0xE466, 23748), (0xE467, 23749),
+    (0xE468, 23846), (0xE469, 23847), (0xE46A, 23848), (0xE46B, 23849), (0xE46C, 23850), (0xE46D, 23851),
+    (0xE46E, 23852), (0xE46F, 23853), (0xE470, 23854), (0xE471, 23855), (0xE472, 23856), (0xE473, 23857),
+    (0xE474, 23858), (0xE475, 23859), (0xE476, 23860), (0xE477, 23861), (0xE478, 23862), (0xE479, 23863),
+    (0xE47A, 23864), (0xE47B, 23865), (0xE47C, 23866), (0xE47D, 23867), (0xE47E, 23868), (0xE47F, 23869),
+    (0xE480, 23870), (0xE481, 23871), (0xE482, 23872), (0xE483, 23873), (0xE484, 23874), (0xE485, 23875),
+    (0xE486, 23876), (0xE487, 23877), (0xE488, 23878), (0xE489, 23879), (0xE48A, 23880), (0xE48B, 23881),
+    (0xE48C, 23882), (0xE48D, 23883), (0xE48E, 23884), (0xE48F, 23885), (0xE490, 23886), (0xE491, 23887),
+    (0xE492, 23888), (0xE493, 23889), (0xE494, 23890), (0xE495, 23891), (0xE496, 23892), (0xE497, 23893),
+    (0xE498, 23894), (0xE499, 23895), (0xE49A, 23896), (0xE49B, 23897), (0xE49C, 23898), (0xE49D, 23899),
+    (0xE49E, 23900), (0xE49F, 23901), (0xE4A0, 23902), (0xE4A1, 23903), (0xE4A2, 23904), (0xE4A3, 23905),
+    (0xE4A4, 23906), (0xE4A5, 23907), (0xE4A6, 23908), (0xE4A7, 23909), (0xE4A8, 23910), (0xE4A9, 23911),
+    (0xE4AA, 23912), (0xE4AB, 23913), (0xE4AC, 23914), (0xE4AD, 23915), (0xE4AE, 23916), (0xE4AF, 23917),
+    (0xE4B0, 23918), (0xE4B1, 23919), (0xE4B2, 23920), (0xE4B3, 23921), (0xE4B4, 23922), (0xE4B5, 23923),
+    (0xE4B6, 23924), (0xE4B7, 23925), (0xE4B8, 23926), (0xE4B9, 23927), (0xE4BA, 23928), (0xE4BB, 23929),
+    (0xE4BC, 23930), (0xE4BD, 23931), (0xE4BE, 23932), (0xE4BF, 23933), (0xE4C0, 23934), (0xE4C1, 23935),
+    (0xE4C2, 23936), (0xE4C3, 23937), (0xE4C4, 23938), (0xE4C5, 23939), (0xE4C6, 6080), (0xE4C7, 6081),
+    (0xE4C8, 6082), (0xE4C9, 6083), (0xE4CA, 6084), (0xE4CB, 6085), (0xE4CC, 6086), (0xE4CD, 6087),
+    (0xE4CE, 6088), (0xE4CF, 6089), (0xE4D0, 6090), (0xE4D1, 6091), (0xE4D2, 6092), (0xE4D3, 6093),
+    (0xE4D4, 6094), (0xE4D5, 6095), (0xE4D6, 6096), (0xE4D7, 6097), (0xE4D8, 6098), (0xE4D9, 6099),
+    (0xE4DA, 6100), (0xE4DB, 6101), (0xE4DC, 6102), (0xE4DD, 6103), (0xE4DE, 6104), (0xE4DF, 6105),
+    (0xE4E0, 6106), (0xE4E1, 6107), (0xE4E2, 6108), (0xE4E3, 6109), (0xE4E4, 6110), (0xE4E5, 6111),
+    (0xE4E6, 6112), (0xE4E7, 6113), (0xE4E8, 6114), (0xE4E9, 6115), (0xE4EA, 6116), (0xE4EB, 6117),
+    (0xE4EC, 6118), (0xE4ED, 6119), (0xE4EE, 6120), (0xE4EF, 6121), (0xE4F0, 6122), (0xE4F1, 6123),
+    (0xE4F2, 6124), (0xE4F3, 6125), (0xE4F4, 6126), (0xE4F5, 6127), (0xE4F6, 6128), (0xE4F7, 6129),
+    (0xE4F8, 6130), (0xE4F9, 6131), (0xE4FA, 6132), (0xE4FB, 6133), (0xE4FC, 6134), (0xE4FD, 6135),
+    (0xE4FE, 6136), (0xE4FF, 6137), (0xE500, 6138), (0xE501, 6139), (0xE502, 6140), (0xE503, 6141),
+    (0xE504, 6142), (0xE505, 6143), (0xE506, 6144), (0xE507, 6145), (0xE508, 6146), (0xE509, 6147),
+    (0xE50A, 6148), (0xE50B, 6149), (0xE50C, 6150), (0xE50D, 6151), (0xE50E, 6152), (0xE50F, 6153),
+    (0xE510, 6154), (0xE511, 6155), (0xE512, 6156), (0xE513, 6157), (0xE514, 6158), (0xE515, 6159),
+    (0xE516, 6160), (0xE517, 6161), (0xE518, 6162), (0xE519, 6163), (0xE51A, 6164), (0xE51B, 6165),
+    (0xE51C, 6166), (0xE51D, 6167), (0xE51E, 6168), (0xE51F, 6169), (0xE520, 6170), (0xE521, 6171),
+    (0xE522, 6172), (0xE523, 6173), (0xE524, 6174), (0xE525, 6175), (0xE526, 6270), (0xE527, 6271),
+    (0xE528, 6272), (0xE529, 6273), (0xE52A, 6274), (0xE52B, 6275), (0xE52C, 6276), (0xE52D, 6277),
+    (0xE52E, 6278), (0xE52F, 6279), (0xE530, 6280), (0xE531, 6281), (0xE532, 6282), (0xE533, 6283),
+    (0xE534, 6284), (0xE535, 6285), (0xE536, 6286), (0xE537, 6287), (0xE538, 6288), (0xE539, 6289),
+    (0xE53A, 6290), (0xE53B, 6291), (0xE53C, 6292), (0xE53D, 6293), (0xE53E, 6294), (0xE53F, 6295),
+    (0xE540, 6296), (0xE541, 6297), (0xE542, 6298), (0xE543, 6299), (0xE544, 6300), (0xE545, 6301),
+    (0xE546, 6302), (0xE547, 6303), (0xE548, 6304), (0xE549, 6305), (0xE54A, 6306), (0xE54B, 6307),
+    (0xE54C, 6308), (0xE54D, 6309), (0xE54E, 6310), (0xE54F, 6311), (0xE550, 6312), (0xE551, 6313),
+    (0xE552, 6314), (0xE553, 6315), (0xE554, 6316), (0xE555, 6317), (0xE556, 6318), (0xE557, 6319),
+    (0xE558, 6320), (0xE559, 6321), (0xE55A, 6322), (0xE55B, 6323), (0xE55C, 6324), (0xE55D, 6325),
+    (0xE55E, 6326), (0xE55F, 6327), (0xE560, 6328), (0xE561, 6329), (0xE562, 6330), (0xE563, 6331),
+    (0xE564, 6332), (0xE565, 6333), (0xE566, 6334), (0xE567, 6335), (0xE568, 6336), (0xE569, 6337),
+    (0xE56A, 6338), (0xE56B, 6339), (0xE56C, 6340), (0xE56D, 6341), (0xE56E, 6342), (0xE56F, 6343),
+    (0xE570, 6344), (0xE571, 6345), (0xE572, 6346), (0xE573, 6347), (0xE574, 6348), (0xE575, 6349),
+    (0xE576, 6350), (0xE577, 6351), (0xE578, 6352), (0xE579, 6353), (0xE57A, 6354), (0xE57B, 6355),
+    (0xE57C, 6356), (0xE57D, 6357), (0xE57E, 6358), (0xE57F, 6359), (0xE580, 6360), (0xE581, 6361),
+    (0xE582, 6362), (0xE583, 6363), (0xE584, 6364), (0xE585, 6365), (0xE586, 6460), (0xE587, 6461),
+    (0xE588, 6462), (0xE589, 6463), (0xE58A, 6464), (0xE58B, 6465), (0xE58C, 6466), (0xE58D, 6467),
+    (0xE58E, 6468), (0xE58F, 6469), (0xE590, 6470), (0xE591, 6471), (0xE592, 6472), (0xE593, 6473),
+    (0xE594, 6474), (0xE595, 6475), (0xE596, 6476), (0xE597, 6477), (0xE598, 6478), (0xE599, 6479),
+    (0xE59A, 6480), (0xE59B, 6481), (0xE59C, 6482), (0xE59D, 6483), (0xE59E, 6484), (0xE59F, 6485),
+    (0xE5A0, 6486), (0xE5A1, 6487), (0xE5A2, 6488), (0xE5A3, 6489), (0xE5A4, 6490), (0xE5A5, 6491),
+    (0xE5A6, 6492), (0xE5A7, 6493), (0xE5A8, 6494), (0xE5A9, 6495), (0xE5AA, 6496), (0xE5AB, 6497),
+    (0xE5AC, 6498), (0xE5AD, 6499), (0xE5AE, 6500), (0xE5AF, 6501), (0xE5B0, 6502), (0xE5B1, 6503),
+    (0xE5B2, 6504), (0xE5B3, 6505), (0xE5B4, 6506), (0xE5B5, 6507), (0xE5B6, 6508), (0xE5B7, 6509),
+    (0xE5B8, 6510), (0xE5B9, 6511), (0xE5BA, 6512), (0xE5BB, 6513), (0xE5BC, 6514), (0xE5BD, 6515),
+    (0xE5BE, 6516), (0xE5BF, 6517), (0xE5C0, 6518), (0xE5C1, 6519), (0xE5C2, 6520), (0xE5C3, 6521),
+    (0xE5C4, 6522), (0xE5C5, 6523), (0xE5C6, 6524), (0xE5C7, 6525), (0xE5C8, 6526), (0xE5C9, 6527),
+    (0xE5CA, 6528), (0xE5CB, 6529), (0xE5CC, 6530), (0xE5CD, 6531), (0xE5CE, 6532), (0xE5CF, 6533),
+    (0xE5D0, 6534), (0xE5D1, 6535), (0xE5D2, 6536), (0xE5D3, 6537), (0xE5D4, 6538), (0xE5D5, 6539),
+    (0xE5D6, 6540), (0xE5D7, 6541), (0xE5D8, 6542), (0xE5D9, 6543), (0xE5DA, 6544), (0xE5DB, 6545),
+    (0xE5DC, 6546), (0xE5DD, 6547), (0xE5DE, 6548), (0xE5DF, 6549), (0xE5E0, 6550), (0xE5E1, 6551),
+    (0xE5E2, 6552), (0xE5E3, 6553), (0xE5E4, 6554), (0xE5E6, 6650), (0xE5E7, 6651), (0xE5E8, 6652),
+    (0xE5E9, 6653), (0xE5EA, 6654), (0xE5EB, 6655), (0xE5EC, 6656), (0xE5ED, 6657), (0xE5EE, 6658),
+    (0xE5EF, 6659), (0xE5F0, 6660), (0xE5F1, 6661), (0xE5F2, 6662), (0xE5F3, 6663), (0xE5F4, 6664),
+    (0xE5F5, 6665), (0xE5F6, 6666), (0xE5F7, 6667), (0xE5F8, 6668), (0xE5F9, 6669), (0xE5FA, 6670),
+    (0xE5FB, 6671), (0xE5FC, 6672), (0xE5FD, 6673), (0xE5FE, 6674), (0xE5FF, 6675), (0xE600, 6676),
+    (0xE601, 6677), (0xE602, 6678), (0xE603, 6679), (0xE604, 6680), (0xE605, 6681), (0xE606, 6682),
+    (0xE607, 6683), (0xE608, 6684), (0xE609, 6685), (0xE60A, 6686), (0xE60B, 6687), (0xE60C, 6688),
+    (0xE60D, 6689), (0xE60E, 6690), (0xE60F, 6691), (0xE610, 6692), (0xE611, 6693), (0xE612, 6694),
+    (0xE613, 6695), (0xE614, 6696), (0xE615, 6697), (0xE616, 6698), (0xE617, 6699), (0xE618, 6700),
+    (0xE619, 6701), (0xE61A, 6702), (0xE61B, 6703), (0xE61C, 6704), (0xE61D, 6705), (0xE61E, 6706),
+    (0xE61F, 6707), (0xE620, 6708), (0xE621, 6709), (0xE622, 6710), (0xE623, 6711), (0xE624, 6712),
+    (0xE625, 6713), (0xE626, 6714), (0xE627, 6715), (0xE628, 6716), (0xE629, 6717), (0xE62A, 6718),
+    (0xE62B, 6719), (0xE62C, 6720), (0xE62D, 6721), (0xE62E, 6722), (0xE62F, 6723), (0xE630, 6724),
+    (0xE631, 6725), (0xE632, 6726), (0xE633, 6727), (0xE634, 6728), (0xE635, 6729), (0xE636, 6730),
+    (0xE637, 6731), (0xE638, 6732), (0xE639, 6733), (0xE63A, 6734), (0xE63B, 6735), (0xE63C, 6736),
+    (0xE63D, 6737), (0xE63E, 6738), (0xE63F, 6739), (0xE640, 6740), (0xE641, 6741), (0xE642, 6742),
+    (0xE643, 6743), (0xE644, 6744), (0xE645, 6745), (0xE646, 6840), (0xE647, 6841), (0xE648, 6842),
+    (0xE649, 6843), (0xE64A, 6844), (0xE64B, 6845), (0xE64C, 6846), (0xE64D, 6847), (0xE64E, 6848),
+    (0xE64F, 6849), (0xE650, 6850), (0xE651, 6851), (0xE652, 6852), (0xE653, 6853), (0xE654, 6854),
+    (0xE655, 6855), (0xE656, 6856), (0xE657, 6857), (0xE658, 6858), (0xE659, 6859), (0xE65A, 6860),
+    (0xE65B, 6861), (0xE65C, 6862), (0xE65D, 6863), (0xE65E, 6864), (0xE65F, 6865), (0xE660, 6866),
+    (0xE661, 6867), (0xE662, 6868), (0xE663, 6869), (0xE664, 6870), (0xE665, 6871), (0xE666, 6872),
+    (0xE667, 6873), (0xE668, 6874), (0xE669, 6875), (0xE66A, 6876), (0xE66B, 6877), (0xE66C, 6878),
+    (0xE66D, 6879), (0xE66E, 6880), (0xE66F, 6881), (0xE670, 6882), (0xE671, 6883), (0xE672, 6884),
+    (0xE673, 6885), (0xE674, 6886), (0xE675, 6887), (0xE676, 6888), (0xE677, 6889), (0xE678, 6890),
+    (0xE679, 6891), (0xE67A, 6892), (0xE67B, 6893), (0xE67C, 6894), (0xE67D, 6895), (0xE67E, 6896),
+    (0xE67F, 6897), (0xE680, 6898), (0xE681, 6899), (0xE682, 6900), (0xE683, 6901), (0xE684, 6902),
+    (0xE685, 6903), (0xE686, 6904), (0xE687, 6905), (0xE688, 6906), (0xE689, 6907), (0xE68A, 6908),
+    (0xE68B, 6909), (0xE68C, 6910), (0xE68D, 6911), (0xE68E, 6912), (0xE68F, 6913), (0xE690, 6914),
+    (0xE691, 6915), (0xE692, 6916), (0xE693, 6917), (0xE694, 6918), (0xE695, 6919), (0xE696, 6920),
+    (0xE697, 6921), (0xE698, 6922), (0xE699, 6923), (0xE69A, 6924), (0xE69B, 6925), (0xE69C, 6926),
+    (0xE69D, 6927), (0xE69E, 6928), (0xE69F, 6929), (0xE6A0, 6930), (0xE6A1, 6931), (0xE6A2, 6932),
+    (0xE6A3, 6933), (0xE6A4, 6934), (0xE6A5, 6935), (0xE6A6, 7030), (0xE6A7, 7031), (0xE6A8, 7032),
+    (0xE6A9, 7033), (0xE6AA, 7034), (0xE6AB, 7035), (0xE6AC, 7036), (0xE6AD, 7037), (0xE6AE, 7038),
+    (0xE6AF, 7039), (0xE6B0, 7040), (0xE6B1, 7041), (0xE6B2, 7042), (0xE6B3, 7043), (0xE6B4, 7044),
+    (0xE6B5, 7045), (0xE6B6, 7046), (0xE6B7, 7047), (0xE6B8, 7048), (0xE6B9, 7049), (0xE6BA, 7050),
+    (0xE6BB, 7051), (0xE6BC, 7052), (0xE6BD, 7053), (0xE6BE, 7054), (0xE6BF, 7055), (0xE6C0, 7056),
+    (0xE6C1, 7057), (0xE6C2, 7058), (0xE6C3, 7059), (0xE6C4, 7060), (0xE6C5, 7061), (0xE6C6, 7062),
+    (0xE6C7, 7063), (0xE6C8, 7064), (0xE6C9, 7065), (0xE6CA, 7066), (0xE6CB, 7067), (0xE6CC, 7068),
+    (0xE6CD, 7069), (0xE6CE, 7070), (0xE6CF, 7071), (0xE6D0, 7072), (0xE6D1, 7073), (0xE6D2, 7074),
+    (0xE6D3, 7075), (0xE6D4, 7076), (0xE6D5, 7077), (0xE6D6, 7078), (0xE6D7, 7079), (0xE6D8, 7080),
+    (0xE6D9, 7081), (0xE6DA, 7082), (0xE6DB, 7083), (0xE6DC, 7084), (0xE6DD, 7085), (0xE6DE, 7086),
+    (0xE6DF, 7087), (0xE6E0, 7088), (0xE6E1, 7089), (0xE6E2, 7090), (0xE6E3, 7091), (0xE6E4, 7092),
+    (0xE6E5, 7093), (0xE6E6, 7094), (0xE6E7, 7095), (0xE6E8, 7096), (0xE6E9, 7097), (0xE6EA, 7098),
+    (0xE6EB, 7099), (0xE6EC, 7100), (0xE6ED, 7101), (0xE6EE, 7102), (0xE6EF, 7103), (0xE6F0, 7104),
+    (0xE6F1, 7105), (0xE6F2, 7106), (0xE6F3, 7107), (0xE6F4, 7108), (0xE6F5, 7109), (0xE6F6, 7110),
+    (0xE6F7, 7111), (0xE6F8, 7112), (0xE6F9, 7113), (0xE6FA, 7114), (0xE6FB, 7115), (0xE6FC, 7116),
+    (0xE6FD, 7117), (0xE6FE, 7118), (0xE6FF, 7119), (0xE700, 7120), (0xE701, 7121), (0xE702, 7122),
+    (0xE703, 7123), (0xE704, 7124), (0xE705, 7125), (0xE706, 7220), (0xE707, 7221), (0xE708, 7222),
+    (0xE709, 7223), (0xE70A, 7224), (0xE70B, 7225), (0xE70C, 7226), (0xE70D, 7227), (0xE70E, 7228),
+    (0xE70F, 7229), (0xE710, 7230), (0xE711, 7231), (0xE712, 7232), (0xE713, 7233), (0xE714, 7234),
+    (0xE715, 7235), (0xE716, 7236), (0xE717, 7237), (0xE718, 7238), (0xE719, 7239), (0xE71A, 7240),
+    (0xE71B, 7241), (0xE71C, 7242), (0xE71D, 7243), (0xE71E, 7244), (0xE71F, 7245), (0xE720, 7246),
+    (0xE721, 7247), (0xE722, 7248), (0xE723, 7249), (0xE724, 7250), (0xE725, 7251), (0xE726, 7252),
+    (0xE727, 7253), (0xE728, 7254), (0xE729, 7255), (0xE72A, 7256), (0xE72B, 7257), (0xE72C, 7258),
+    (0xE72D, 7259), (0xE72E, 7260), (0xE72F, 7261), (0xE730, 7262), (0xE731, 7263), (0xE732, 7264),
+    (0xE733, 7265), (0xE734, 7266), (0xE735, 7267), (0xE736, 7268), (0xE737, 7269), (0xE738, 7270),
+    (0xE739, 7271), (0xE73A, 7272), (0xE73B, 7273), (0xE73C, 7274), (0xE73D, 7275), (0xE73E, 7276),
+    (0xE73F, 7277), (0xE740, 7278), (0xE741, 7279), (0xE742, 7280), (0xE743, 7281), (0xE744, 7282),
+    (0xE745, 7283), (0xE746, 7284), (0xE747, 7285), (0xE748, 7286), (0xE749, 7287), (0xE74A, 7288),
+    (0xE74B, 7289), (0xE74C, 7290), (0xE74D, 7291), (0xE74E, 7292), (0xE74F, 7293), (0xE750, 7294),
+    (0xE751, 7295), (0xE752, 7296), (0xE753, 7297), (0xE754, 7298), (0xE755, 7299), (0xE756, 7300),
+    (0xE757, 7301), (0xE758, 7302), (0xE759, 7303), (0xE75A, 7304), (0xE75B, 7305), (0xE75C, 7306),
+    (0xE75D, 7307), (0xE75E, 7308), (0xE75F, 7309), (0xE760, 7310), (0xE761, 7311), (0xE762, 7312),
+    (0xE763, 7313), (0xE764, 7314), (0xE765, 7315), (0xE766, 6376), (0xE767, 6377), (0xE768, 6378),
+    (0xE769, 6379), (0xE76A, 6380), (0xE76B, 6381), (0xE76D, 6433), (0xE76E, 6444), (0xE76F, 6445),
+    (0xE770, 6458), (0xE771, 6459), (0xE772, 6829), (0xE773, 6830), (0xE774, 6831), (0xE775, 6832),
+    (0xE776, 6833), (0xE777, 6834), (0xE778, 6835), (0xE779, 6836), (0xE77A, 6837), (0xE77B, 6838),
+    (0xE77C, 6839), (0xE77D, 7022), (0xE77E, 7023), (0xE77F, 7024), (0xE780, 7025), (0xE781, 7026),
+    (0xE782, 7027), (0xE783, 7028), (0xE784, 7029), (0xE785, 7150), (0xE786, 7151), (0xE787, 7152),
+    (0xE788, 7153), (0xE789, 7154), (0xE78A, 7155), (0xE78B, 7156), (0xE78C, 7157), (0xE797, 7211),
+    (0xE798, 7212), (0xE799, 7213), (0xE79A, 7214), (0xE79B, 7215), (0xE79C, 7216), (0xE79D, 7217),
+    (0xE79E, 7218), (0xE79F, 7219), (0xE7A0, 7349), (0xE7A1, 7350), (0xE7A2, 7351), (0xE7A3, 7352),
+    (0xE7A4, 7353), (0xE7A5, 7354), (0xE7A6, 7355), (0xE7A7, 7356), (0xE7A8, 7357), (0xE7A9, 7358),
+    (0xE7AA, 7359), (0xE7AB, 7360), (0xE7AC, 7361), (0xE7AD, 7362), (0xE7AE, 7363), (0xE7AF, 7397),
+    (0xE7B0, 7398), (0xE7B1, 7399), (0xE7B2, 7400), (0xE7B3, 7401), (0xE7B4, 7402), (0xE7B5, 7403),
+    (0xE7B6, 7404), (0xE7B7, 7405), (0xE7B8, 7406), (0xE7B9, 7407), (0xE7BA, 7408), (0xE7BB, 7409),
+    (0xE7BC, 7495), (0xE7BD, 7496), (0xE7BE, 7497), (0xE7BF, 7498), (0xE7C0, 7499), (0xE7C1, 7500),
+    (0xE7C2, 7501), (0xE7C3, 7502), (0xE7C4, 7503), (0xE7C5, 7504), (0xE7C6, 7505), (0xE7C9, 7538),
+    (0xE7CA, 7539), (0xE7CB, 7540), (0xE7CC, 7541), (0xE7CD, 7579), (0xE7CE, 7580), (0xE7CF, 7581),
+    (0xE7D0, 7582), (0xE7D1, 7583), (0xE7D2, 7584), (0xE7D3, 7585), (0xE7D4, 7586), (0xE7D5, 7587),
+    (0xE7D6, 7588), (0xE7D7, 7589), (0xE7D8, 7590), (0xE7D9, 7591), (0xE7DA, 7592), (0xE7DB, 7593),
+    (0xE7DC, 7594), (0xE7DD, 7595), (0xE7DE, 7596), (0xE7DF, 7597), (0xE7E0, 7598), (0xE7E1, 7599),
+    (0xE7E2, 7624), (0xE7E3, 7627), (0xE7E4, 7629), (0xE7E5, 7630), (0xE7E6, 7631), (0xE7F4, 7686),
+    (0xE7F5, 7687), (0xE7F6, 7688), (0xE7F7, 7689), (0xE7F8, 7690), (0xE7F9, 7691), (0xE7FA, 7692),
+    (0xE7FB, 7693), (0xE7FC, 7694), (0xE7FD, 7695), (0xE7FE, 7696), (0xE7FF, 7697), (0xE800, 7698),
+    (0xE801, 7775), (0xE802, 7776), (0xE803, 7777), (0xE804, 7778), (0xE805, 7779), (0xE806, 7780),
+    (0xE807, 7781), (0xE808, 7782), (0xE809, 7783), (0xE80A, 7784), (0xE80B, 7785), (0xE80C, 7786),
+    (0xE80D, 7787), (0xE80E, 7788), (0xE80F, 7789), (0xE810, 16525), (0xE811, 16526), (0xE812, 16527),
+    (0xE813, 16528), (0xE814, 16529), (0xE816, 23767), (0xE817, 23768), (0xE818, 23769), (0xE831, 23794),
+    (0xE83B, 23804), (0xE855, 23830), (0xF92C, 23651), (0xF979, 23652), (0xF995, 23653), (0xF9E7, 23654),
+    (0xF9F1, 23655), (0xFA0C, 23750), (0xFA0D, 23751), (0xFA0E, 23752), (0xFA0F, 23753), (0xFA11, 23754),
+    (0xFA13, 23755), (0xFA14, 23756), (0xFA18, 23757), (0xFA1F, 23758), (0xFA20, 23759), (0xFA21, 23760),
+    (0xFA23, 23761), (0xFA24, 23762), (0xFA27, 23763), (0xFA28, 23764), (0xFA29, 23765), (0xFE10, 7182),
+    (0xFE11, 7184), (0xFE12, 7183), (0xFE13, 7185), (0xFE14, 7186), (0xFE15, 7187), (0xFE16, 7188),
+    (0xFE17, 7201), (0xFE18, 7202), (0xFE19, 7208), (0xFE30, 7621), (0xFE31, 7207), (0xFE33, 7209),
+    (0xFE34, 7210), (0xFE35, 7189), (0xFE36, 7190), (0xFE37, 7205), (0xFE38, 7206), (0xFE39, 7191),
+    (0xFE3A, 7192), (0xFE3B, 7203), (0xFE3C, 7204), (0xFE3D, 7195), (0xFE3E, 7196), (0xFE3F, 7193),
+    (0xFE40, 7194), (0xFE41, 7197), (0xFE42, 7198), (0xFE43, 7199), (0xFE44, 7200), (0xFE49, 7640),
+    (0xFE4A, 7641), (0xFE4B, 7642), (0xFE4C, 7643), (0xFE4D, 7644), (0xFE4E, 7645), (0xFE4F, 7646),
+    (0xFE50, 7647), (0xFE51, 7648), (0xFE52, 7649), (0xFE54, 7650), (0xFE55, 7651), (0xFE56, 7652),
+    (0xFE57, 7653), (0xFE59, 7654), (0xFE5A, 7655), (0xFE5B, 7656), (0xFE5C, 7657), (0xFE5D, 7658),
+    (0xFE5E, 7659), (0xFE5F, 7660), (0xFE60, 7661), (0xFE61, 7662), (0xFE62, 7663), (0xFE63, 7664),
+    (0xFE64, 7665), (0xFE65, 7666), (0xFE66, 7667), (0xFE68, 7668), (0xFE69, 7669), (0xFE6A, 7670),
+    (0xFE6B, 7671), (0xFF01, 6556), (0xFF02, 6557), (0xFF03, 6558), (0xFF04, 6246), (0xFF05, 6560),
+    (0xFF06, 6561), (0xFF07, 6562), (0xFF08, 6563), (0xFF09, 6564), (0xFF0A, 6565), (0xFF0B, 6566),
+    (0xFF0C, 6567), (0xFF0D, 6568), (0xFF0E, 6569), (0xFF0F, 6570), (0xFF10, 6571), (0xFF11, 6572),
+    (0xFF12, 6573), (0xFF13, 6574), (0xFF14, 6575), (0xFF15, 6576), (0xFF16, 6577), (0xFF17, 6578),
+    (0xFF18, 6579), (0xFF19, 6580), (0xFF1A, 6581), (0xFF1B, 6582), (0xFF1C, 6583), (0xFF1D, 6584),
+    (0xFF1E, 6585), (0xFF1F, 6586), (0xFF20, 6587), (0xFF21, 6588), (0xFF22, 6589), (0xFF23, 6590),
+    (0xFF24, 6591), (0xFF25, 6592), (0xFF26, 6593), (0xFF27, 6594), (0xFF28, 6595), (0xFF29, 6596),
+    (0xFF2A, 6597), (0xFF2B, 6598), (0xFF2C, 6599), (0xFF2D, 6600), (0xFF2E, 6601), (0xFF2F, 6602),
+    (0xFF30, 6603), (0xFF31, 6604), (0xFF32, 6605), (0xFF33, 6606), (0xFF34, 6607), (0xFF35, 6608),
+    (0xFF36, 6609), (0xFF37, 6610), (0xFF38, 6611), (0xFF39, 6612), (0xFF3A, 6613), (0xFF3B, 6614),
+    (0xFF3C, 6615), (0xFF3D, 6616), (0xFF3E, 6617), (0xFF3F, 6618), (0xFF40, 6619), (0xFF41, 6620),
+    (0xFF42, 6621), (0xFF43, 6622), (0xFF44, 6623), (0xFF45, 6624), (0xFF46, 6625), (0xFF47, 6626),
+    (0xFF48, 6627), (0xFF49, 6628), (0xFF4A, 6629), (0xFF4B, 6630), (0xFF4C, 6631), (0xFF4D, 6632),
+    (0xFF4E, 6633), (0xFF4F, 6634), (0xFF50, 6635), (0xFF51, 6636), (0xFF52, 6637), (0xFF53, 6638),
+    (0xFF54, 6639), (0xFF55, 6640), (0xFF56, 6641), (0xFF57, 6642), (0xFF58, 6643), (0xFF59, 6644),
+    (0xFF5A, 6645), (0xFF5B, 6646), (0xFF5C, 6647), (0xFF5D, 6648), (0xFF5E, 6186), (0xFFE0, 6248),
+    (0xFFE1, 6249), (0xFFE2, 7622), (0xFFE3, 6649), (0xFFE4, 7623), (0xFFE5, 6559),
+];
+
+/// Index gb18030 ranges: the first pointer and code point of every range of four-byte
+/// sequences, sorted by pointer and by code point.
+pub(crate) static GB18030_RANGES: &[(u16, u16)] = &[
+    (0, 0x0080),
+    (36, 0x00A5),
+    (38, 0x00A9),
+    (45, 0x00B2),
+    (50, 0x00B8),
+    (81, 0x00D8),
+    (89, 0x00E2),
+    (95, 0x00EB),
+    (96, 0x00EE),
+    (100, 0x00F4),
+    (103, 0x00F8),
+    (104, 0x00FB),
+    (105, 0x00FD),
+    (109, 0x0102),
+    (126, 0x0114),
+    (133, 0x011C),
+    (148, 0x012C),
+    (172, 0x0145),
+    (175, 0x0149),
+    (179, 0x014E),
+    (208, 0x016C),
+    (306, 0x01CF),
+    (307, 0x01D1),
+    (308, 0x01D3),
+    (309, 0x01D5),
+    (310, 0x01D7),
+    (311, 0x01D9),
+    (312, 0x01DB),
+    (313, 0x01DD),
+    (341, 0x01FA),
+    (428, 0x0252),
+    (443, 0x0262),
+    (544, 0x02C8),
+    (545, 0x02CC),
+    (558, 0x02DA),
+    (741, 0x03A2),
+    (742, 0x03AA),
+    (749, 0x03C2),
+    (750, 0x03CA),
+    (805, 0x0402),
+    (819, 0x0450),
+    (820, 0x0452),
+    (7458, 0x1E40),
+    (7922, 0x2011),
+    (7924, 0x2017),
+    (7925, 0x201A),
+    (7927, 0x201E),
+    (7934, 0x2027),
+    (7943, 0x2031),
+    (7944, 0x2034),
+    (7945, 0x2036),
+    (7950, 0x203C),
+    (8062, 0x20AD),
+    (8148, 0x2104),
+    (8149, 0x2106),
+    (8152, 0x210A),
+    (8164, 0x2117),
+    (8174, 0x2122),
+    (8236, 0x216C),
+    (8240, 0x217A),
+    (8262, 0x2194),
+    (8264, 0x219A),
+    (8374, 0x2209),
+    (8380, 0x2210),
+    (8381, 0x2212),
+    (8384, 0x2216),
+    (8388, 0x221B),
+    (8390, 0x2221),
+    (8392, 0x2224),
+    (8393, 0x2226),
+    (8394, 0x222C),
+    (8396, 0x222F),
+    (8401, 0x2238),
+    (8406, 0x223E),
+    (8416, 0x2249),
+    (8419, 0x224D),
+    (8424, 0x2253),
+    (8437, 0x2262),
+    (8439, 0x2268),
+    (8445, 0x2270),
+    (8482, 0x2296),
+    (8485, 0x229A),
+    (8496, 0x22A6),
+    (8521, 0x22C0),
+    (8603, 0x2313),
+    (8936, 0x246A),
+    (8946, 0x249C),
+    (9046, 0x254C),
+    (9050, 0x2574),
+    (9063, 0x2590),
+    (9066, 0x2596),
+    (9076, 0x25A2),
+    (9092, 0x25B4),
+    (9100, 0x25BE),
+    (9108, 0x25C8),
+    (9111, 0x25CC),
+    (9113, 0x25D0),
+    (9131, 0x25E6),
+    (9162, 0x2607),
+    (9164, 0x260A),
+    (9218, 0x2641),
+    (9219, 0x2643),
+    (11329, 0x2E82),
+    (11331, 0x2E85),
+    (11334, 0x2E89),
+    (11336, 0x2E8D),
+    (11346, 0x2E98),
+    (11361, 0x2EA8),
+    (11363, 0x2EAB),
+    (11366, 0x2EAF),
+    (11370, 0x2EB4),
+    (11372, 0x2EB8),
+    (11375, 0x2EBC),
+    (11389, 0x2ECB),
+    (11682, 0x2FFC),
+    (11686, 0x3004),
+    (11687, 0x3018),
+    (11692, 0x301F),
+    (11694, 0x302A),
+    (11714, 0x303F),
+    (11716, 0x3094),
+    (11723, 0x309F),
+    (11725, 0x30F7),
+    (11730, 0x30FF),
+    (11736, 0x312A),
+    (11982, 0x322A),
+    (11989, 0x3232),
+    (12102, 0x32A4),
+    (12336, 0x3390),
+    (12348, 0x339F),
+    (12350, 0x33A2),
+    (12384, 0x33C5),
+    (12393, 0x33CF),
+    (12395, 0x33D3),
+    (12397, 0x33D6),
+    (12510, 0x3448),
+    (12553, 0x3474),
+    (12851, 0x359F),
+    (12962, 0x360F),
+    (12973, 0x361B),
+    (13738, 0x3919),
+    (13823, 0x396F),
+    (13919, 0x39D1),
+    (13933, 0x39E0),
+    (14080, 0x3A74),
+    (14298, 0x3B4F),
+    (14585, 0x3C6F),
+    (14698, 0x3CE1),
+    (15583, 0x4057),
+    (15847, 0x4160),
+    (16318, 0x4338),
+    (16434, 0x43AD),
+    (16438, 0x43B2),
+    (16481, 0x43DE),
+    (16729, 0x44D7),
+    (17102, 0x464D),
+    (17122, 0x4662),
+    (17315, 0x4724),
+    (17320, 0x472A),
+    (17402, 0x477D),
+    (17418, 0x478E),
+    (17859, 0x4948),
+    (17909, 0x497B),
+    (17911, 0x497E),
+    (17915, 0x4984),
+    (17916, 0x4987),
+    (17936, 0x499C),
+    (17939, 0x49A0),
+    (17961, 0x49B8),
+    (18664, 0x4C78),
+    (18703, 0x4CA4),
+    (18814, 0x4D1A),
+    (18962, 0x4DAF),
+    (19043, 0x9FA6),
+    (33469, 0xE76C),
+    (33470, 0xE7C8),
+    (33471, 0xE7E7),
+    (33484, 0xE815),
+    (33485, 0xE819),
+    (33490, 0xE81F),
+    (33497, 0xE827),
+    (33501, 0xE82D),
+    (33505, 0xE833),
+    (33513, 0xE83C),
+    (33520, 0xE844),
+    (33536, 0xE856),
+    (33550, 0xE865),
+    (37845, 0xF92D),
+    (37921, 0xF97A),
+    (37948, 0xF996),
+    (38029, 0xF9E8),
+    (38038, 0xF9F2),
+    (38064, 0xFA10),
+    (38065, 0xFA12),
+    (38066, 0xFA15),
+    (38069, 0xFA19),
+    (38075, 0xFA22),
+    (38076, 0xFA25),
+    (38078, 0xFA2A),
+    (39108, 0xFE32),
+    (39109, 0xFE45),
+    (39113, 0xFE53),
+    (39114, 0xFE58),
+    (39115, 0xFE67),
+    (39116, 0xFE6C),
+    (39265, 0xFF5F),
+    (39394, 0xFFE6),
+];
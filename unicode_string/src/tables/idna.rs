@@ -0,0 +1,7792 @@
+// This file is generated by `unicode_string_gen`. Do not edit it manually.
+
+use crate::idna::Mapping;
+
+/// The IDNA mapping of every char, sorted by code point. All the chars of a range have the
+/// same mapping.
+pub(crate) static IDNA_MAPPING: &[(char, char, Mapping)] = &[
+    ('\u{0}', '\u{2C}', Mapping::DisallowedStd3Valid),
+    ('\u{2D}', '\u{2E}', Mapping::Valid),
+    ('\u{2F}', '\u{2F}', Mapping::DisallowedStd3Valid),
+    ('\u{30}', '\u{39}', Mapping::Valid),
+    ('\u{3A}', '\u{40}', Mapping::DisallowedStd3Valid),
+    ('\u{41}', '\u{41}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{42}', '\u{42}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{43}', '\u{43}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{44}', '\u{44}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{45}', '\u{45}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{46}', '\u{46}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{47}', '\u{47}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{48}', '\u{48}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{49}', '\u{49}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{4A}', '\u{4A}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{4B}', '\u{4B}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{4C}', '\u{4C}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{4D}', '\u{4D}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{4E}', '\u{4E}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{4F}', '\u{4F}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{50}', '\u{50}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{51}', '\u{51}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{52}', '\u{52}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{53}', '\u{53}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{54}', '\u{54}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{55}', '\u{55}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{56}', '\u{56}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{57}', '\u{57}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{58}', '\u{58}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{59}', '\u{59}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{5A}', '\u{5A}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{5B}', '\u{60}', Mapping::DisallowedStd3Valid),
+    ('\u{61}', '\u{7A}', Mapping::Valid),
+    ('\u{7B}', '\u{7F}', Mapping::DisallowedStd3Valid),
+    ('\u{80}', '\u{9F}', Mapping::Disallowed),
+    ('\u{A0}', '\u{A0}', Mapping::DisallowedStd3Mapped(&['\u{20}'])),
+    ('\u{A1}', '\u{A7}', Mapping::Valid),
+    ('\u{A8}', '\u{A8}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{308}'])),
+    ('\u{A9}', '\u{A9}', Mapping::Valid),
+    ('\u{AA}', '\u{AA}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{AB}', '\u{AC}', Mapping::Valid),
+    ('\u{AD}', '\u{AD}', Mapping::Ignored),
+    ('\u{AE}', '\u{AE}', Mapping::Valid),
+    ('\u{AF}', '\u{AF}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{304}'])),
+    ('\u{B0}', '\u{B1}', Mapping::Valid),
+    ('\u{B2}', '\u{B2}', Mapping::Mapped(&['\u{32}'])),
+    ('\u{B3}', '\u{B3}', Mapping::Mapped(&['\u{33}'])),
+    ('\u{B4}', '\u{B4}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{301}'])),
+    ('\u{B5}', '\u{B5}', Mapping::Mapped(&['\u{3BC}'])),
+    ('\u{B6}', '\u{B7}', Mapping::Valid),
+    ('\u{B8}', '\u{B8}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{327}'])),
+    ('\u{B9}', '\u{B9}', Mapping::Mapped(&['\u{31}'])),
+    ('\u{BA}', '\u{BA}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{BB}', '\u{BB}', Mapping::Valid),
+    ('\u{BC}', '\u{BC}', Mapping::Mapped(&['\u{31}', '\u{2044}', '\u{34}'])),
+    ('\u{BD}', '\u{BD}', Mapping::Mapped(&['\u{31}', '\u{2044}', '\u{32}'])),
+    ('\u{BE}', '\u{BE}', Mapping::Mapped(&['\u{33}', '\u{2044}', '\u{34}'])),
+    ('\u{BF}', '\u{BF}', Mapping::Valid),
+    ('\u{C0}', '\u{C0}', Mapping::Mapped(&['\u{E0}'])),
+    ('\u{C1}', '\u{C1}', Mapping::Mapped(&['\u{E1}'])),
+    ('\u{C2}', '\u{C2}', Mapping::Mapped(&['\u{E2}'])),
+    ('\u{C3}', '\u{C3}', Mapping::Mapped(&['\u{E3}'])),
+    ('\u{C4}', '\u{C4}', Mapping::Mapped(&['\u{E4}'])),
+    ('\u{C5}', '\u{C5}', Mapping::Mapped(&['\u{E5}'])),
+    ('\u{C6}', '\u{C6}', Mapping::Mapped(&['\u{E6}'])),
+    ('\u{C7}', '\u{C7}', Mapping::Mapped(&['\u{E7}'])),
+    ('\u{C8}', '\u{C8}', Mapping::Mapped(&['\u{E8}'])),
+    ('\u{C9}', '\u{C9}', Mapping::Mapped(&['\u{E9}'])),
+    ('\u{CA}', '\u{CA}', Mapping::Mapped(&['\u{EA}'])),
+    ('\u{CB}', '\u{CB}', Mapping::Mapped(&['\u{EB}'])),
+    ('\u{CC}', '\u{CC}', Mapping::Mapped(&['\u{EC}'])),
+    ('\u{CD}', '\u{CD}', Mapping::Mapped(&['\u{ED}'])),
+    ('\u{CE}', '\u{CE}', Mapping::Mapped(&['\u{EE}'])),
+    ('\u{CF}', '\u{CF}', Mapping::Mapped(&['\u{EF}'])),
+    ('\u{D0}', '\u{D0}', Mapping::Mapped(&['\u{F0}'])),
+    ('\u{D1}', '\u{D1}', Mapping::Mapped(&['\u{F1}'])),
+    ('\u{D2}', '\u{D2}', Mapping::Mapped(&['\u{F2}'])),
+    ('\u{D3}', '\u{D3}', Mapping::Mapped(&['\u{F3}'])),
+    ('\u{D4}', '\u{D4}', Mapping::Mapped(&['\u{F4}'])),
+    ('\u{D5}', '\u{D5}', Mapping::Mapped(&['\u{F5}'])),
+    ('\u{D6}', '\u{D6}', Mapping::Mapped(&['\u{F6}'])),
+    ('\u{D7}', '\u{D7}', Mapping::Valid),
+    ('\u{D8}', '\u{D8}', Mapping::Mapped(&['\u{F8}'])),
+    ('\u{D9}', '\u{D9}', Mapping::Mapped(&['\u{F9}'])),
+    ('\u{DA}', '\u{DA}', Mapping::Mapped(&['\u{FA}'])),
+    ('\u{DB}', '\u{DB}', Mapping::Mapped(&['\u{FB}'])),
+    ('\u{DC}', '\u{DC}', Mapping::Mapped(&['\u{FC}'])),
+    ('\u{DD}', '\u{DD}', Mapping::Mapped(&['\u{FD}'])),
+    ('\u{DE}', '\u{DE}', Mapping::Mapped(&['\u{FE}'])),
+    ('\u{DF}', '\u{DF}', Mapping::Deviation(&['\u{73}', '\u{73}'])),
+    ('\u{E0}', '\u{FF}', Mapping::Valid),
+    ('\u{100}', '\u{100}', Mapping::Mapped(&['\u{101}'])),
+    ('\u{101}', '\u{101}', Mapping::Valid),
+    ('\u{102}', '\u{102}', Mapping::Mapped(&['\u{103}'])),
+    ('\u{103}', '\u{103}', Mapping::Valid),
+    ('\u{104}', '\u{104}', Mapping::Mapped(&['\u{105}'])),
+    ('\u{105}', '\u{105}', Mapping::Valid),
+    ('\u{106}', '\u{106}', Mapping::Mapped(&['\u{107}'])),
+    ('\u{107}', '\u{107}', Mapping::Valid),
+    ('\u{108}', '\u{108}', Mapping::Mapped(&['\u{109}'])),
+    ('\u{109}', '\u{109}', Mapping::Valid),
+    ('\u{10A}', '\u{10A}', Mapping::Mapped(&['\u{10B}'])),
+    ('\u{10B}', '\u{10B}', Mapping::Valid),
+    ('\u{10C}', '\u{10C}', Mapping::Mapped(&['\u{10D}'])),
+    ('\u{10D}', '\u{10D}', Mapping::Valid),
+    ('\u{10E}', '\u{10E}', Mapping::Mapped(&['\u{10F}'])),
+    ('\u{10F}', '\u{10F}', Mapping::Valid),
+    ('\u{110}', '\u{110}', Mapping::Mapped(&['\u{111}'])),
+    ('\u{111}', '\u{111}', Mapping::Valid),
+    ('\u{112}', '\u{112}', Mapping::Mapped(&['\u{113}'])),
+    ('\u{113}', '\u{113}', Mapping::Valid),
+    ('\u{114}', '\u{114}', Mapping::Mapped(&['\u{115}'])),
+    ('\u{115}', '\u{115}', Mapping::Valid),
+    ('\u{116}', '\u{116}', Mapping::Mapped(&['\u{117}'])),
+    ('\u{117}', '\u{117}', Mapping::Valid),
+    ('\u{118}', '\u{118}', Mapping::Mapped(&['\u{119}'])),
+    ('\u{119}', '\u{119}', Mapping::Valid),
+    ('\u{11A}', '\u{11A}', Mapping::Mapped(&['\u{11B}'])),
+    ('\u{11B}', '\u{11B}', Mapping::Valid),
+    ('\u{11C}', '\u{11C}', Mapping::Mapped(&['\u{11D}'])),
+    ('\u{11D}', '\u{11D}', Mapping::Valid),
+    ('\u{11E}', '\u{11E}', Mapping::Mapped(&['\u{11F}'])),
+    ('\u{11F}', '\u{11F}', Mapping::Valid),
+    ('\u{120}', '\u{120}', Mapping::Mapped(&['\u{121}'])),
+    ('\u{121}', '\u{121}', Mapping::Valid),
+    ('\u{122}', '\u{122}', Mapping::Mapped(&['\u{123}'])),
+    ('\u{123}', '\u{123}', Mapping::Valid),
+    ('\u{124}', '\u{124}', Mapping::Mapped(&['\u{125}'])),
+    ('\u{125}', '\u{125}', Mapping::Valid),
+    ('\u{126}', '\u{126}', Mapping::Mapped(&['\u{127}'])),
+    ('\u{127}', '\u{127}', Mapping::Valid),
+    ('\u{128}', '\u{128}', Mapping::Mapped(&['\u{129}'])),
+    ('\u{129}', '\u{129}', Mapping::Valid),
+    ('\u{12A}', '\u{12A}', Mapping::Mapped(&['\u{12B}'])),
+    ('\u{12B}', '\u{12B}', Mapping::Valid),
+    ('\u{12C}', '\u{12C}', Mapping::Mapped(&['\u{12D}'])),
+    ('\u{12D}', '\u{12D}', Mapping::Valid),
+    ('\u{12E}', '\u{12E}', Mapping::Mapped(&['\u{12F}'])),
+    ('\u{12F}', '\u{12F}', Mapping::Valid),
+    ('\u{130}', '\u{130}', Mapping::Mapped(&['\u{69}', '\u{307}'])),
+    ('\u{131}', '\u{131}', Mapping::Valid),
+    ('\u{132}', '\u{133}', Mapping::Mapped(&['\u{69}', '\u{6A}'])),
+    ('\u{134}', '\u{134}', Mapping::Mapped(&['\u{135}'])),
+    ('\u{135}', '\u{135}', Mapping::Valid),
+    ('\u{136}', '\u{136}', Mapping::Mapped(&['\u{137}'])),
+    ('\u{137}', '\u{138}', Mapping::Valid),
+    ('\u{139}', '\u{139}', Mapping::Mapped(&['\u{13A}'])),
+    ('\u{13A}', '\u{13A}', Mapping::Valid),
+    ('\u{13B}', '\u{13B}', Mapping::Mapped(&['\u{13C}'])),
+    ('\u{13C}', '\u{13C}', Mapping::Valid),
+    ('\u{13D}', '\u{13D}', Mapping::Mapped(&['\u{13E}'])),
+    ('\u{13E}', '\u{13E}', Mapping::Valid),
+    ('\u{13F}', '\u{140}', Mapping::Mapped(&['\u{6C}', '\u{B7}'])),
+    ('\u{141}', '\u{141}', Mapping::Mapped(&['\u{142}'])),
+    ('\u{142}', '\u{142}', Mapping::Valid),
+    ('\u{143}', '\u{143}', Mapping::Mapped(&['\u{144}'])),
+    ('\u{144}', '\u{144}', Mapping::Valid),
+    ('\u{145}', '\u{145}', Mapping::Mapped(&['\u{146}'])),
+    ('\u{146}', '\u{146}', Mapping::Valid),
+    ('\u{147}', '\u{147}', Mapping::Mapped(&['\u{148}'])),
+    ('\u{148}', '\u{148}', Mapping::Valid),
+    ('\u{149}', '\u{149}', Mapping::Mapped(&['\u{2BC}', '\u{6E}'])),
+    ('\u{14A}', '\u{14A}', Mapping::Mapped(&['\u{14B}'])),
+    ('\u{14B}', '\u{14B}', Mapping::Valid),
+    ('\u{14C}', '\u{14C}', Mapping::Mapped(&['\u{14D}'])),
+    ('\u{14D}', '\u{14D}', Mapping::Valid),
+    ('\u{14E}', '\u{14E}', Mapping::Mapped(&['\u{14F}'])),
+    ('\u{14F}', '\u{14F}', Mapping::Valid),
+    ('\u{150}', '\u{150}', Mapping::Mapped(&['\u{151}'])),
+    ('\u{151}', '\u{151}', Mapping::Valid),
+    ('\u{152}', '\u{152}', Mapping::Mapped(&['\u{153}'])),
+    ('\u{153}', '\u{153}', Mapping::Valid),
+    ('\u{154}', '\u{154}', Mapping::Mapped(&['\u{155}'])),
+    ('\u{155}', '\u{155}', Mapping::Valid),
+    ('\u{156}', '\u{156}', Mapping::Mapped(&['\u{157}'])),
+    ('\u{157}', '\u{157}', Mapping::Valid),
+    ('\u{158}', '\u{158}', Mapping::Mapped(&['\u{159}'])),
+    ('\u{159}', '\u{159}', Mapping::Valid),
+    ('\u{15A}', '\u{15A}', Mapping::Mapped(&['\u{15B}'])),
+    ('\u{15B}', '\u{15B}', Mapping::Valid),
+    ('\u{15C}', '\u{15C}', Mapping::Mapped(&['\u{15D}'])),
+    ('\u{15D}', '\u{15D}', Mapping::Valid),
+    ('\u{15E}', '\u{15E}', Mapping::Mapped(&['\u{15F}'])),
+    ('\u{15F}', '\u{15F}', Mapping::Valid),
+    ('\u{160}', '\u{160}', Mapping::Mapped(&['\u{161}'])),
+    ('\u{161}', '\u{161}', Mapping::Valid),
+    ('\u{162}', '\u{162}', Mapping::Mapped(&['\u{163}'])),
+    ('\u{163}', '\u{163}', Mapping::Valid),
+    ('\u{164}', '\u{164}', Mapping::Mapped(&['\u{165}'])),
+    ('\u{165}', '\u{165}', Mapping::Valid),
+    ('\u{166}', '\u{166}', Mapping::Mapped(&['\u{167}'])),
+    ('\u{167}', '\u{167}', Mapping::Valid),
+    ('\u{168}', '\u{168}', Mapping::Mapped(&['\u{169}'])),
+    ('\u{169}', '\u{169}', Mapping::Valid),
+    ('\u{16A}', '\u{16A}', Mapping::Mapped(&['\u{16B}'])),
+    ('\u{16B}', '\u{16B}', Mapping::Valid),
+    ('\u{16C}', '\u{16C}', Mapping::Mapped(&['\u{16D}'])),
+    ('\u{16D}', '\u{16D}', Mapping::Valid),
+    ('\u{16E}', '\u{16E}', Mapping::Mapped(&['\u{16F}'])),
+    ('\u{16F}', '\u{16F}', Mapping::Valid),
+    ('\u{170}', '\u{170}', Mapping::Mapped(&['\u{171}'])),
+    ('\u{171}', '\u{171}', Mapping::Valid),
+    ('\u{172}', '\u{172}', Mapping::Mapped(&['\u{173}'])),
+    ('\u{173}', '\u{173}', Mapping::Valid),
+    ('\u{174}', '\u{174}', Mapping::Mapped(&['\u{175}'])),
+    ('\u{175}', '\u{175}', Mapping::Valid),
+    ('\u{176}', '\u{176}', Mapping::Mapped(&['\u{177}'])),
+    ('\u{177}', '\u{177}', Mapping::Valid),
+    ('\u{178}', '\u{178}', Mapping::Mapped(&['\u{FF}'])),
+    ('\u{179}', '\u{179}', Mapping::Mapped(&['\u{17A}'])),
+    ('\u{17A}', '\u{17A}', Mapping::Valid),
+    ('\u{17B}', '\u{17B}', Mapping::Mapped(&['\u{17C}'])),
+    ('\u{17C}', '\u{17C}', Mapping::Valid),
+    ('\u{17D}', '\u{17D}', Mapping::Mapped(&['\u{17E}'])),
+    ('\u{17E}', '\u{17E}', Mapping::Valid),
+    ('\u{17F}', '\u{17F}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{180}', '\u{180}', Mapping::Valid),
+    ('\u{181}', '\u{181}', Mapping::Mapped(&['\u{253}'])),
+    ('\u{182}', '\u{182}', Mapping::Mapped(&['\u{183}'])),
+    ('\u{183}', '\u{183}', Mapping::Valid),
+    ('\u{184}', '\u{184}', Mapping::Mapped(&['\u{185}'])),
+    ('\u{185}', '\u{185}', Mapping::Valid),
+    ('\u{186}', '\u{186}', Mapping::Mapped(&['\u{254}'])),
+    ('\u{187}', '\u{187}', Mapping::Mapped(&['\u{188}'])),
+    ('\u{188}', '\u{188}', Mapping::Valid),
+    ('\u{189}', '\u{189}', Mapping::Mapped(&['\u{256}'])),
+    ('\u{18A}', '\u{18A}', Mapping::Mapped(&['\u{257}'])),
+    ('\u{18B}', '\u{18B}', Mapping::Mapped(&['\u{18C}'])),
+    ('\u{18C}', '\u{18D}', Mapping::Valid),
+    ('\u{18E}', '\u{18E}', Mapping::Mapped(&['\u{1DD}'])),
+    ('\u{18F}', '\u{18F}', Mapping::Mapped(&['\u{259}'])),
+    ('\u{190}', '\u{190}', Mapping::Mapped(&['\u{25B}'])),
+    ('\u{191}', '\u{191}', Mapping::Mapped(&['\u{192}'])),
+    ('\u{192}', '\u{192}', Mapping::Valid),
+    ('\u{193}', '\u{193}', Mapping::Mapped(&['\u{260}'])),
+    ('\u{194}', '\u{194}', Mapping::Mapped(&['\u{263}'])),
+    ('\u{195}', '\u{195}', Mapping::Valid),
+    ('\u{196}', '\u{196}', Mapping::Mapped(&['\u{269}'])),
+    ('\u{197}', '\u{197}', Mapping::Mapped(&['\u{268}'])),
+    ('\u{198}', '\u{198}', Mapping::Mapped(&['\u{199}'])),
+    ('\u{199}', '\u{19B}', Mapping::Valid),
+    ('\u{19C}', '\u{19C}', Mapping::Mapped(&['\u{26F}'])),
+    ('\u{19D}', '\u{19D}', Mapping::Mapped(&['\u{272}'])),
+    ('\u{19E}', '\u{19E}', Mapping::Valid),
+    ('\u{19F}', '\u{19F}', Mapping::Mapped(&['\u{275}'])),
+    ('\u{1A0}', '\u{1A0}', Mapping::Mapped(&['\u{1A1}'])),
+    ('\u{1A1}', '\u{1A1}', Mapping::Valid),
+    ('\u{1A2}', '\u{1A2}', Mapping::Mapped(&['\u{1A3}'])),
+    ('\u{1A3}', '\u{1A3}', Mapping::Valid),
+    ('\u{1A4}', '\u{1A4}', Mapping::Mapped(&['\u{1A5}'])),
+    ('\u{1A5}', '\u{1A5}', Mapping::Valid),
+    ('\u{1A6}', '\u{1A6}', Mapping::Mapped(&['\u{280}'])),
+    ('\u{1A7}', '\u{1A7}', Mapping::Mapped(&['\u{1A8}'])),
+    ('\u{1A8}', '\u{1A8}', Mapping::Valid),
+    ('\u{1A9}', '\u{1A9}', Mapping::Mapped(&['\u{283}'])),
+    ('\u{1AA}', '\u{1AB}', Mapping::Valid),
+    ('\u{1AC}', '\u{1AC}', Mapping::Mapped(&['\u{1AD}'])),
+    ('\u{1AD}', '\u{1AD}', Mapping::Valid),
+    ('\u{1AE}', '\u{1AE}', Mapping::Mapped(&['\u{288}'])),
+    ('\u{1AF}', '\u{1AF}', Mapping::Mapped(&['\u{1B0}'])),
+    ('\u{1B0}', '\u{1B0}', Mapping::Valid),
+    ('\u{1B1}', '\u{1B1}', Mapping::Mapped(&['\u{28A}'])),
+    ('\u{1B2}', '\u{1B2}', Mapping::Mapped(&['\u{28B}'])),
+    ('\u{1B3}', '\u{1B3}', Mapping::Mapped(&['\u{1B4}'])),
+    ('\u{1B4}', '\u{1B4}', Mapping::Valid),
+    ('\u{1B5}', '\u{1B5}', Mapping::Mapped(&['\u{1B6}'])),
+    ('\u{1B6}', '\u{1B6}', Mapping::Valid),
+    ('\u{1B7}', '\u{1B7}', Mapping::Mapped(&['\u{292}'])),
+    ('\u{1B8}', '\u{1B8}', Mapping::Mapped(&['\u{1B9}'])),
+    ('\u{1B9}', '\u{1BB}', Mapping::Valid),
+    ('\u{1BC}', '\u{1BC}', Mapping::Mapped(&['\u{1BD}'])),
+    ('\u{1BD}', '\u{1C3}', Mapping::Valid),
+    ('\u{1C4}', '\u{1C6}', Mapping::Mapped(&['\u{64}', '\u{17E}'])),
+    ('\u{1C7}', '\u{1C9}', Mapping::Mapped(&['\u{6C}', '\u{6A}'])),
+    ('\u{1CA}', '\u{1CC}', Mapping::Mapped(&['\u{6E}', '\u{6A}'])),
+    ('\u{1CD}', '\u{1CD}', Mapping::Mapped(&['\u{1CE}'])),
+    ('\u{1CE}', '\u{1CE}', Mapping::Valid),
+    ('\u{1CF}', '\u{1CF}', Mapping::Mapped(&['\u{1D0}'])),
+    ('\u{1D0}', '\u{1D0}', Mapping::Valid),
+    ('\u{1D1}', '\u{1D1}', Mapping::Mapped(&['\u{1D2}'])),
+    ('\u{1D2}', '\u{1D2}', Mapping::Valid),
+    ('\u{1D3}', '\u{1D3}', Mapping::Mapped(&['\u{1D4}'])),
+    ('\u{1D4}', '\u{1D4}', Mapping::Valid),
+    ('\u{1D5}', '\u{1D5}', Mapping::Mapped(&['\u{1D6}'])),
+    ('\u{1D6}', '\u{1D6}', Mapping::Valid),
+    ('\u{1D7}', '\u{1D7}', Mapping::Mapped(&['\u{1D8}'])),
+    ('\u{1D8}', '\u{1D8}', Mapping::Valid),
+    ('\u{1D9}', '\u{1D9}', Mapping::Mapped(&['\u{1DA}'])),
+    ('\u{1DA}', '\u{1DA}', Mapping::Valid),
+    ('\u{1DB}', '\u{1DB}', Mapping::Mapped(&['\u{1DC}'])),
+    ('\u{1DC}', '\u{1DD}', Mapping::Valid),
+    ('\u{1DE}', '\u{1DE}', Mapping::Mapped(&['\u{1DF}'])),
+    ('\u{1DF}', '\u{1DF}', Mapping::Valid),
+    ('\u{1E0}', '\u{1E0}', Mapping::Mapped(&['\u{1E1}'])),
+    ('\u{1E1}', '\u{1E1}', Mapping::Valid),
+    ('\u{1E2}', '\u{1E2}', Mapping::Mapped(&['\u{1E3}'])),
+    ('\u{1E3}', '\u{1E3}', Mapping::Valid),
+    ('\u{1E4}', '\u{1E4}', Mapping::Mapped(&['\u{1E5}'])),
+    ('\u{1E5}', '\u{1E5}', Mapping::Valid),
+    ('\u{1E6}', '\u{1E6}', Mapping::Mapped(&['\u{1E7}'])),
+    ('\u{1E7}', '\u{1E7}', Mapping::Valid),
+    ('\u{1E8}', '\u{1E8}', Mapping::Mapped(&['\u{1E9}'])),
+    ('\u{1E9}', '\u{1E9}', Mapping::Valid),
+    ('\u{1EA}', '\u{1EA}', Mapping::Mapped(&['\u{1EB}'])),
+    ('\u{1EB}', '\u{1EB}', Mapping::Valid),
+    ('\u{1EC}', '\u{1EC}', Mapping::Mapped(&['\u{1ED}'])),
+    ('\u{1ED}', '\u{1ED}', Mapping::Valid),
+    ('\u{1EE}', '\u{1EE}', Mapping::Mapped(&['\u{1EF}'])),
+    ('\u{1EF}', '\u{1F0}', Mapping::Valid),
+    ('\u{1F1}', '\u{1F3}', Mapping::Mapped(&['\u{64}', '\u{7A}'])),
+    ('\u{1F4}', '\u{1F4}', Mapping::Mapped(&['\u{1F5}'])),
+    ('\u{1F5}', '\u{1F5}', Mapping::Valid),
+    ('\u{1F6}', '\u{1F6}', Mapping::Mapped(&['\u{195}'])),
+    ('\u{1F7}', '\u{1F7}', Mapping::Mapped(&['\u{1BF}'])),
+    ('\u{1F8}', '\u{1F8}', Mapping::Mapped(&['\u{1F9}'])),
+    ('\u{1F9}', '\u{1F9}', Mapping::Valid),
+    ('\u{1FA}', '\u{1FA}', Mapping::Mapped(&['\u{1FB}'])),
+    ('\u{1FB}', '\u{1FB}', Mapping::Valid),
+    ('\u{1FC}', '\u{1FC}', Mapping::Mapped(&['\u{1FD}'])),
+    ('\u{1FD}', '\u{1FD}', Mapping::Valid),
+    ('\u{1FE}', '\u{1FE}', Mapping::Mapped(&['\u{1FF}'])),
+    ('\u{1FF}', '\u{1FF}', Mapping::Valid),
+    ('\u{200}', '\u{200}', Mapping::Mapped(&['\u{201}'])),
+    ('\u{201}', '\u{201}', Mapping::Valid),
+    ('\u{202}', '\u{202}', Mapping::Mapped(&['\u{203}'])),
+    ('\u{203}', '\u{203}', Mapping::Valid),
+    ('\u{204}', '\u{204}', Mapping::Mapped(&['\u{205}'])),
+    ('\u{205}', '\u{205}', Mapping::Valid),
+    ('\u{206}', '\u{206}', Mapping::Mapped(&['\u{207}'])),
+    ('\u{207}', '\u{207}', Mapping::Valid),
+    ('\u{208}', '\u{208}', Mapping::Mapped(&['\u{209}'])),
+    ('\u{209}', '\u{209}', Mapping::Valid),
+    ('\u{20A}', '\u{20A}', Mapping::Mapped(&['\u{20B}'])),
+    ('\u{20B}', '\u{20B}', Mapping::Valid),
+    ('\u{20C}', '\u{20C}', Mapping::Mapped(&['\u{20D}'])),
+    ('\u{20D}', '\u{20D}', Mapping::Valid),
+    ('\u{20E}', '\u{20E}', Mapping::Mapped(&['\u{20F}'])),
+    ('\u{20F}', '\u{20F}', Mapping::Valid),
+    ('\u{210}', '\u{210}', Mapping::Mapped(&['\u{211}'])),
+    ('\u{211}', '\u{211}', Mapping::Valid),
+    ('\u{212}', '\u{212}', Mapping::Mapped(&['\u{213}'])),
+    ('\u{213}', '\u{213}', Mapping::Valid),
+    ('\u{214}', '\u{214}', Mapping::Mapped(&['\u{215}'])),
+    ('\u{215}', '\u{215}', Mapping::Valid),
+    ('\u{216}', '\u{216}', Mapping::Mapped(&['\u{217}'])),
+    ('\u{217}', '\u{217}', Mapping::Valid),
+    ('\u{218}', '\u{218}', Mapping::Mapped(&['\u{219}'])),
+    ('\u{219}', '\u{219}', Mapping::Valid),
+    ('\u{21A}', '\u{21A}', Mapping::Mapped(&['\u{21B}'])),
+    ('\u{21B}', '\u{21B}', Mapping::Valid),
+    ('\u{21C}', '\u{21C}', Mapping::Mapped(&['\u{21D}'])),
+    ('\u{21D}', '\u{21D}', Mapping::Valid),
+    ('\u{21E}', '\u{21E}', Mapping::Mapped(&['\u{21F}'])),
+    ('\u{21F}', '\u{21F}', Mapping::Valid),
+    ('\u{220}', '\u{220}', Mapping::Mapped(&['\u{19E}'])),
+    ('\u{221}', '\u{221}', Mapping::Valid),
+    ('\u{222}', '\u{222}', Mapping::Mapped(&['\u{223}'])),
+    ('\u{223}', '\u{223}', Mapping::Valid),
+    ('\u{224}', '\u{224}', Mapping::Mapped(&['\u{225}'])),
+    ('\u{225}', '\u{225}', Mapping::Valid),
+    ('\u{226}', '\u{226}', Mapping::Mapped(&['\u{227}'])),
+    ('\u{227}', '\u{227}', Mapping::Valid),
+    ('\u{228}', '\u{228}', Mapping::Mapped(&['\u{229}'])),
+    ('\u{229}', '\u{229}', Mapping::Valid),
+    ('\u{22A}', '\u{22A}', Mapping::Mapped(&['\u{22B}'])),
+    ('\u{22B}', '\u{22B}', Mapping::Valid),
+    ('\u{22C}', '\u{22C}', Mapping::Mapped(&['\u{22D}'])),
+    ('\u{22D}', '\u{22D}', Mapping::Valid),
+    ('\u{22E}', '\u{22E}', Mapping::Mapped(&['\u{22F}'])),
+    ('\u{22F}', '\u{22F}', Mapping::Valid),
+    ('\u{230}', '\u{230}', Mapping::Mapped(&['\u{231}'])),
+    ('\u{231}', '\u{231}', Mapping::Valid),
+    ('\u{232}', '\u{232}', Mapping::Mapped(&['\u{233}'])),
+    ('\u{233}', '\u{239}', Mapping::Valid),
+    ('\u{23A}', '\u{23A}', Mapping::Mapped(&['\u{2C65}'])),
+    ('\u{23B}', '\u{23B}', Mapping::Mapped(&['\u{23C}'])),
+    ('\u{23C}', '\u{23C}', Mapping::Valid),
+    ('\u{23D}', '\u{23D}', Mapping::Mapped(&['\u{19A}'])),
+    ('\u{23E}', '\u{23E}', Mapping::Mapped(&['\u{2C66}'])),
+    ('\u{23F}', '\u{240}', Mapping::Valid),
+    ('\u{241}', '\u{241}', Mapping::Mapped(&['\u{242}'])),
+    ('\u{242}', '\u{242}', Mapping::Valid),
+    ('\u{243}', '\u{243}', Mapping::Mapped(&['\u{180}'])),
+    ('\u{244}', '\u{244}', Mapping::Mapped(&['\u{289}'])),
+    ('\u{245}', '\u{245}', Mapping::Mapped(&['\u{28C}'])),
+    ('\u{246}', '\u{246}', Mapping::Mapped(&['\u{247}'])),
+    ('\u{247}', '\u{247}', Mapping::Valid),
+    ('\u{248}', '\u{248}', Mapping::Mapped(&['\u{249}'])),
+    ('\u{249}', '\u{249}', Mapping::Valid),
+    ('\u{24A}', '\u{24A}', Mapping::Mapped(&['\u{24B}'])),
+    ('\u{24B}', '\u{24B}', Mapping::Valid),
+    ('\u{24C}', '\u{24C}', Mapping::Mapped(&['\u{24D}'])),
+    ('\u{24D}', '\u{24D}', Mapping::Valid),
+    ('\u{24E}', '\u{24E}', Mapping::Mapped(&['\u{24F}'])),
+    ('\u{24F}', '\u{2AF}', Mapping::Valid),
+    ('\u{2B0}', '\u{2B0}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{2B1}', '\u{2B1}', Mapping::Mapped(&['\u{266}'])),
+    ('\u{2B2}', '\u{2B2}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{2B3}', '\u{2B3}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{2B4}', '\u{2B4}', Mapping::Mapped(&['\u{279}'])),
+    ('\u{2B5}', '\u{2B5}', Mapping::Mapped(&['\u{27B}'])),
+    ('\u{2B6}', '\u{2B6}', Mapping::Mapped(&['\u{281}'])),
+    ('\u{2B7}', '\u{2B7}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{2B8}', '\u{2B8}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{2B9}', '\u{2D7}', Mapping::Valid),
+    ('\u{2D8}', '\u{2D8}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{306}'])),
+    ('\u{2D9}', '\u{2D9}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{307}'])),
+    ('\u{2DA}', '\u{2DA}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{30A}'])),
+    ('\u{2DB}', '\u{2DB}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{328}'])),
+    ('\u{2DC}', '\u{2DC}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{303}'])),
+    ('\u{2DD}', '\u{2DD}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{30B}'])),
+    ('\u{2DE}', '\u{2DF}', Mapping::Valid),
+    ('\u{2E0}', '\u{2E0}', Mapping::Mapped(&['\u{263}'])),
+    ('\u{2E1}', '\u{2E1}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{2E2}', '\u{2E2}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{2E3}', '\u{2E3}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{2E4}', '\u{2E4}', Mapping::Mapped(&['\u{295}'])),
+    ('\u{2E5}', '\u{33F}', Mapping::Valid),
+    ('\u{340}', '\u{340}', Mapping::Mapped(&['\u{300}'])),
+    ('\u{341}', '\u{341}', Mapping::Mapped(&['\u{301}'])),
+    ('\u{342}', '\u{342}', Mapping::Valid),
+    ('\u{343}', '\u{343}', Mapping::Mapped(&['\u{313}'])),
+    ('\u{344}', '\u{344}', Mapping::Mapped(&['\u{308}', '\u{301}'])),
+    ('\u{345}', '\u{345}', Mapping::Mapped(&['\u{3B9}'])),
+    ('\u{346}', '\u{34E}', Mapping::Valid),
+    ('\u{34F}', '\u{34F}', Mapping::Ignored),
+    ('\u{350}', '\u{36F}', Mapping::Valid),
+    ('\u{370}', '\u{370}', Mapping::Mapped(&['\u{371}'])),
+    ('\u{371}', '\u{371}', Mapping::Valid),
+    ('\u{372}', '\u{372}', Mapping::Mapped(&['\u{373}'])),
+    ('\u{373}', '\u{373}', Mapping::Valid),
+    ('\u{374}', '\u{374}', Mapping::Mapped(&['\u{2B9}'])),
+    ('\u{375}', '\u{375}', Mapping::Valid),
+    ('\u{376}', '\u{376}', Mapping::Mapped(&['\u{377}'])),
+    ('\u{377}', '\u{377}', Mapping::Valid),
+    ('\u{378}', '\u{379}', Mapping::Disallowed),
+    ('\u{37A}', '\u{37A}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{3B9}'])),
+    ('\u{37B}', '\u{37D}', Mapping::Valid),
+    ('\u{37E}', '\u{37E}', Mapping::DisallowedStd3Mapped(&['\u{3B}'])),
+    ('\u{37F}', '\u{37F}', Mapping::Mapped(&['\u{3F3}'])),
+    ('\u{380}', '\u{383}', Mapping::Disallowed),
+    ('\u{384}', '\u{384}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{301}'])),
+    ('\u{385}', '\u{385}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{308}', '\u{301}'])),
+    ('\u{386}', '\u{386}', Mapping::Mapped(&['\u{3AC}'])),
+    ('\u{387}', '\u{387}', Mapping::Mapped(&['\u{B7}'])),
+    ('\u{388}', '\u{388}', Mapping::Mapped(&['\u{3AD}'])),
+    ('\u{389}', '\u{389}', Mapping::Mapped(&['\u{3AE}'])),
+    ('\u{38A}', '\u{38A}', Mapping::Mapped(&['\u{3AF}'])),
+    ('\u{38B}', '\u{38B}', Mapping::Disallowed),
+    ('\u{38C}', '\u{38C}', Mapping::Mapped(&['\u{3CC}'])),
+    ('\u{38D}', '\u{38D}', Mapping::Disallowed),
+    ('\u{38E}', '\u{38E}', Mapping::Mapped(&['\u{3CD}'])),
+    ('\u{38F}', '\u{38F}', Mapping::Mapped(&['\u{3CE}'])),
+    ('\u{390}', '\u{390}', Mapping::Valid),
+    ('\u{391}', '\u{391}', Mapping::Mapped(&['\u{3B1}'])),
+    ('\u{392}', '\u{392}', Mapping::Mapped(&['\u{3B2}'])),
+    ('\u{393}', '\u{393}', Mapping::Mapped(&['\u{3B3}'])),
+    ('\u{394}', '\u{394}', Mapping::Mapped(&['\u{3B4}'])),
+    ('\u{395}', '\u{395}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{396}', '\u{396}', Mapping::Mapped(&['\u{3B6}'])),
+    ('\u{397}', '\u{397}', Mapping::Mapped(&['\u{3B7}'])),
+    ('\u{398}', '\u{398}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{399}', '\u{399}', Mapping::Mapped(&['\u{3B9}'])),
+    ('\u{39A}', '\u{39A}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{39B}', '\u{39B}', Mapping::Mapped(&['\u{3BB}'])),
+    ('\u{39C}', '\u{39C}', Mapping::Mapped(&['\u{3BC}'])),
+    ('\u{39D}', '\u{39D}', Mapping::Mapped(&['\u{3BD}'])),
+    ('\u{39E}', '\u{39E}', Mapping::Mapped(&['\u{3BE}'])),
+    ('\u{39F}', '\u{39F}', Mapping::Mapped(&['\u{3BF}'])),
+    ('\u{3A0}', '\u{3A0}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{3A1}', '\u{3A1}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{3A2}', '\u{3A2}', Mapping::Disallowed),
+    ('\u{3A3}', '\u{3A3}', Mapping::Mapped(&['\u{3C3}'])),
+    ('\u{3A4}', '\u{3A4}', Mapping::Mapped(&['\u{3C4}'])),
+    ('\u{3A5}', '\u{3A5}', Mapping::Mapped(&['\u{3C5}'])),
+    ('\u{3A6}', '\u{3A6}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{3A7}', '\u{3A7}', Mapping::Mapped(&['\u{3C7}'])),
+    ('\u{3A8}', '\u{3A8}', Mapping::Mapped(&['\u{3C8}'])),
+    ('\u{3A9}', '\u{3A9}', Mapping::Mapped(&['\u{3C9}'])),
+    ('\u{3AA}', '\u{3AA}', Mapping::Mapped(&['\u{3CA}'])),
+    ('\u{3AB}', '\u{3AB}', Mapping::Mapped(&['\u{3CB}'])),
+    ('\u{3AC}', '\u{3C1}', Mapping::Valid),
+    ('\u{3C2}', '\u{3C2}', Mapping::Deviation(&['\u{3C3}'])),
+    ('\u{3C3}', '\u{3CE}', Mapping::Valid),
+    ('\u{3CF}', '\u{3CF}', Mapping::Mapped(&['\u{3D7}'])),
+    ('\u{3D0}', '\u{3D0}', Mapping::Mapped(&['\u{3B2}'])),
+    ('\u{3D1}', '\u{3D1}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{3D2}', '\u{3D2}', Mapping::Mapped(&['\u{3C5}'])),
+    ('\u{3D3}', '\u{3D3}', Mapping::Mapped(&['\u{3CD}'])),
+    ('\u{3D4}', '\u{3D4}', Mapping::Mapped(&['\u{3CB}'])),
+    ('\u{3D5}', '\u{3D5}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{3D6}', '\u{3D6}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{3D7}', '\u{3D7}', Mapping::Valid),
+    ('\u{3D8}', '\u{3D8}', Mapping::Mapped(&['\u{3D9}'])),
+    ('\u{3D9}', '\u{3D9}', Mapping::Valid),
+    ('\u{3DA}', '\u{3DA}', Mapping::Mapped(&['\u{3DB}'])),
+    ('\u{3DB}', '\u{3DB}', Mapping::Valid),
+    ('\u{3DC}', '\u{3DC}', Mapping::Mapped(&['\u{3DD}'])),
+    ('\u{3DD}', '\u{3DD}', Mapping::Valid),
+    ('\u{3DE}', '\u{3DE}', Mapping::Mapped(&['\u{3DF}'])),
+    ('\u{3DF}', '\u{3DF}', Mapping::Valid),
+    ('\u{3E0}', '\u{3E0}', Mapping::Mapped(&['\u{3E1}'])),
+    ('\u{3E1}', '\u{3E1}', Mapping::Valid),
+    ('\u{3E2}', '\u{3E2}', Mapping::Mapped(&['\u{3E3}'])),
+    ('\u{3E3}', '\u{3E3}', Mapping::Valid),
+    ('\u{3E4}', '\u{3E4}', Mapping::Mapped(&['\u{3E5}'])),
+    ('\u{3E5}', '\u{3E5}', Mapping::Valid),
+    ('\u{3E6}', '\u{3E6}', Mapping::Mapped(&['\u{3E7}'])),
+    ('\u{3E7}', '\u{3E7}', Mapping::Valid),
+    ('\u{3E8}', '\u{3E8}', Mapping::Mapped(&['\u{3E9}'])),
+    ('\u{3E9}', '\u{3E9}', Mapping::Valid),
+    ('\u{3EA}', '\u{3EA}', Mapping::Mapped(&['\u{3EB}'])),
+    ('\u{3EB}', '\u{3EB}', Mapping::Valid),
+    ('\u{3EC}', '\u{3EC}', Mapping::Mapped(&['\u{3ED}'])),
+    ('\u{3ED}', '\u{3ED}', Mapping::Valid),
+    ('\u{3EE}', '\u{3EE}', Mapping::Mapped(&['\u{3EF}'])),
+    ('\u{3EF}', '\u{3EF}', Mapping::Valid),
+    ('\u{3F0}', '\u{3F0}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{3F1}', '\u{3F1}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{3F2}', '\u{3F2}', Mapping::Mapped(&['\u{3C3}'])),
+    ('\u{3F3}', '\u{3F3}', Mapping::Valid),
+    ('\u{3F4}', '\u{3F4}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{3F5}', '\u{3F5}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{3F6}', '\u{3F6}', Mapping::Valid),
+    ('\u{3F7}', '\u{3F7}', Mapping::Mapped(&['\u{3F8}'])),
+    ('\u{3F8}', '\u{3F8}', Mapping::Valid),
+    ('\u{3F9}', '\u{3F9}', Mapping::Mapped(&['\u{3C3}'])),
+    ('\u{3FA}', '\u{3FA}', Mapping::Mapped(&['\u{3FB}'])),
+    ('\u{3FB}', '\u{3FC}', Mapping::Valid),
+    ('\u{3FD}', '\u{3FD}', Mapping::Mapped(&['\u{37B}'])),
+    ('\u{3FE}', '\u{3FE}', Mapping::Mapped(&['\u{37C}'])),
+    ('\u{3FF}', '\u{3FF}', Mapping::Mapped(&['\u{37D}'])),
+    ('\u{400}', '\u{400}', Mapping::Mapped(&['\u{450}'])),
+    ('\u{401}', '\u{401}', Mapping::Mapped(&['\u{451}'])),
+    ('\u{402}', '\u{402}', Mapping::Mapped(&['\u{452}'])),
+    ('\u{403}', '\u{403}', Mapping::Mapped(&['\u{453}'])),
+    ('\u{404}', '\u{404}', Mapping::Mapped(&['\u{454}'])),
+    ('\u{405}', '\u{405}', Mapping::Mapped(&['\u{455}'])),
+    ('\u{406}', '\u{406}', Mapping::Mapped(&['\u{456}'])),
+    ('\u{407}', '\u{407}', Mapping::Mapped(&['\u{457}'])),
+    ('\u{408}', '\u{408}', Mapping::Mapped(&['\u{458}'])),
+    ('\u{409}', '\u{409}', Mapping::Mapped(&['\u{459}'])),
+    ('\u{40A}', '\u{40A}', Mapping::Mapped(&['\u{45A}'])),
+    ('\u{40B}', '\u{40B}', Mapping::Mapped(&['\u{45B}'])),
+    ('\u{40C}', '\u{40C}', Mapping::Mapped(&['\u{45C}'])),
+    ('\u{40D}', '\u{40D}', Mapping::Mapped(&['\u{45D}'])),
+    ('\u{40E}', '\u{40E}', Mapping::Mapped(&['\u{45E}'])),
+    ('\u{40F}', '\u{40F}', Mapping::Mapped(&['\u{45F}'])),
+    ('\u{410}', '\u{410}', Mapping::Mapped(&['\u{430}'])),
+    ('\u{411}', '\u{411}', Mapping::Mapped(&['\u{431}'])),
+    ('\u{412}', '\u{412}', Mapping::Mapped(&['\u{432}'])),
+    ('\u{413}', '\u{413}', Mapping::Mapped(&['\u{433}'])),
+    ('\u{414}', '\u{414}', Mapping::Mapped(&['\u{434}'])),
+    ('\u{415}', '\u{415}', Mapping::Mapped(&['\u{435}'])),
+    ('\u{416}', '\u{416}', Mapping::Mapped(&['\u{436}'])),
+    ('\u{417}', '\u{417}', Mapping::Mapped(&['\u{437}'])),
+    ('\u{418}', '\u{418}', Mapping::Mapped(&['\u{438}'])),
+    ('\u{419}', '\u{419}', Mapping::Mapped(&['\u{439}'])),
+    ('\u{41A}', '\u{41A}', Mapping::Mapped(&['\u{43A}'])),
+    ('\u{41B}', '\u{41B}', Mapping::Mapped(&['\u{43B}'])),
+    ('\u{41C}', '\u{41C}', Mapping::Mapped(&['\u{43C}'])),
+    ('\u{41D}', '\u{41D}', Mapping::Mapped(&['\u{43D}'])),
+    ('\u{41E}', '\u{41E}', Mapping::Mapped(&['\u{43E}'])),
+    ('\u{41F}', '\u{41F}', Mapping::Mapped(&['\u{43F}'])),
+    ('\u{420}', '\u{420}', Mapping::Mapped(&['\u{440}'])),
+    ('\u{421}', '\u{421}', Mapping::Mapped(&['\u{441}'])),
+    ('\u{422}', '\u{422}', Mapping::Mapped(&['\u{442}'])),
+    ('\u{423}', '\u{423}', Mapping::Mapped(&['\u{443}'])),
+    ('\u{424}', '\u{424}', Mapping::Mapped(&['\u{444}'])),
+    ('\u{425}', '\u{425}', Mapping::Mapped(&['\u{445}'])),
+    ('\u{426}', '\u{426}', Mapping::Mapped(&['\u{446}'])),
+    ('\u{427}', '\u{427}', Mapping::Mapped(&['\u{447}'])),
+    ('\u{428}', '\u{428}', Mapping::Mapped(&['\u{448}'])),
+    ('\u{429}', '\u{429}', Mapping::Mapped(&['\u{449}'])),
+    ('\u{42A}', '\u{42A}', Mapping::Mapped(&['\u{44A}'])),
+    ('\u{42B}', '\u{42B}', Mapping::Mapped(&['\u{44B}'])),
+    ('\u{42C}', '\u{42C}', Mapping::Mapped(&['\u{44C}'])),
+    ('\u{42D}', '\u{42D}', Mapping::Mapped(&['\u{44D}'])),
+    ('\u{42E}', '\u{42E}', Mapping::Mapped(&['\u{44E}'])),
+    ('\u{42F}', '\u{42F}', Mapping::Mapped(&['\u{44F}'])),
+    ('\u{430}', '\u{45F}', Mapping::Valid),
+    ('\u{460}', '\u{460}', Mapping::Mapped(&['\u{461}'])),
+    ('\u{461}', '\u{461}', Mapping::Valid),
+    ('\u{462}', '\u{462}', Mapping::Mapped(&['\u{463}'])),
+    ('\u{463}', '\u{463}', Mapping::Valid),
+    ('\u{464}', '\u{464}', Mapping::Mapped(&['\u{465}'])),
+    ('\u{465}', '\u{465}', Mapping::Valid),
+    ('\u{466}', '\u{466}', Mapping::Mapped(&['\u{467}'])),
+    ('\u{467}', '\u{467}', Mapping::Valid),
+    ('\u{468}', '\u{468}', Mapping::Mapped(&['\u{469}'])),
+    ('\u{469}', '\u{469}', Mapping::Valid),
+    ('\u{46A}', '\u{46A}', Mapping::Mapped(&['\u{46B}'])),
+    ('\u{46B}', '\u{46B}', Mapping::Valid),
+    ('\u{46C}', '\u{46C}', Mapping::Mapped(&['\u{46D}'])),
+    ('\u{46D}', '\u{46D}', Mapping::Valid),
+    ('\u{46E}', '\u{46E}', Mapping::Mapped(&['\u{46F}'])),
+    ('\u{46F}', '\u{46F}', Mapping::Valid),
+    ('\u{470}', '\u{470}', Mapping::Mapped(&['\u{471}'])),
+    ('\u{471}', '\u{471}', Mapping::Valid),
+    ('\u{472}', '\u{472}', Mapping::Mapped(&['\u{473}'])),
+    ('\u{473}', '\u{473}', Mapping::Valid),
+    ('\u{474}', '\u{474}', Mapping::Mapped(&['\u{475}'])),
+    ('\u{475}', '\u{475}', Mapping::Valid),
+    ('\u{476}', '\u{476}', Mapping::Mapped(&['\u{477}'])),
+    ('\u{477}', '\u{477}', Mapping::Valid),
+    ('\u{478}', '\u{478}', Mapping::Mapped(&['\u{479}'])),
+    ('\u{479}', '\u{479}', Mapping::Valid),
+    ('\u{47A}', '\u{47A}', Mapping::Mapped(&['\u{47B}'])),
+    ('\u{47B}', '\u{47B}', Mapping::Valid),
+    ('\u{47C}', '\u{47C}', Mapping::Mapped(&['\u{47D}'])),
+    ('\u{47D}', '\u{47D}', Mapping::Valid),
+    ('\u{47E}', '\u{47E}', Mapping::Mapped(&['\u{47F}'])),
+    ('\u{47F}', '\u{47F}', Mapping::Valid),
+    ('\u{480}', '\u{480}', Mapping::Mapped(&['\u{481}'])),
+    ('\u{481}', '\u{489}', Mapping::Valid),
+    ('\u{48A}', '\u{48A}', Mapping::Mapped(&['\u{48B}'])),
+    ('\u{48B}', '\u{48B}', Mapping::Valid),
+    ('\u{48C}', '\u{48C}', Mapping::Mapped(&['\u{48D}'])),
+    ('\u{48D}', '\u{48D}', Mapping::Valid),
+    ('\u{48E}', '\u{48E}', Mapping::Mapped(&['\u{48F}'])),
+    ('\u{48F}', '\u{48F}', Mapping::Valid),
+    ('\u{490}', '\u{490}', Mapping::Mapped(&['\u{491}'])),
+    ('\u{491}', '\u{491}', Mapping::Valid),
+    ('\u{492}', '\u{492}', Mapping::Mapped(&['\u{493}'])),
+    ('\u{493}', '\u{493}', Mapping::Valid),
+    ('\u{494}', '\u{494}', Mapping::Mapped(&['\u{495}'])),
+    ('\u{495}', '\u{495}', Mapping::Valid),
+    ('\u{496}', '\u{496}', Mapping::Mapped(&['\u{497}'])),
+    ('\u{497}', '\u{497}', Mapping::Valid),
+    ('\u{498}', '\u{498}', Mapping::Mapped(&['\u{499}'])),
+    ('\u{499}', '\u{499}', Mapping::Valid),
+    ('\u{49A}', '\u{49A}', Mapping::Mapped(&['\u{49B}'])),
+    ('\u{49B}', '\u{49B}', Mapping::Valid),
+    ('\u{49C}', '\u{49C}', Mapping::Mapped(&['\u{49D}'])),
+    ('\u{49D}', '\u{49D}', Mapping::Valid),
+    ('\u{49E}', '\u{49E}', Mapping::Mapped(&['\u{49F}'])),
+    ('\u{49F}', '\u{49F}', Mapping::Valid),
+    ('\u{4A0}', '\u{4A0}', Mapping::Mapped(&['\u{4A1}'])),
+    ('\u{4A1}', '\u{4A1}', Mapping::Valid),
+    ('\u{4A2}', '\u{4A2}', Mapping::Mapped(&['\u{4A3}'])),
+    ('\u{4A3}', '\u{4A3}', Mapping::Valid),
+    ('\u{4A4}', '\u{4A4}', Mapping::Mapped(&['\u{4A5}'])),
+    ('\u{4A5}', '\u{4A5}', Mapping::Valid),
+    ('\u{4A6}', '\u{4A6}', Mapping::Mapped(&['\u{4A7}'])),
+    ('\u{4A7}', '\u{4A7}', Mapping::Valid),
+    ('\u{4A8}', '\u{4A8}', Mapping::Mapped(&['\u{4A9}'])),
+    ('\u{4A9}', '\u{4A9}', Mapping::Valid),
+    ('\u{4AA}', '\u{4AA}', Mapping::Mapped(&['\u{4AB}'])),
+    ('\u{4AB}', '\u{4AB}', Mapping::Valid),
+    ('\u{4AC}', '\u{4AC}', Mapping::Mapped(&['\u{4AD}'])),
+    ('\u{4AD}', '\u{4AD}', Mapping::Valid),
+    ('\u{4AE}', '\u{4AE}', Mapping::Mapped(&['\u{4AF}'])),
+    ('\u{4AF}', '\u{4AF}', Mapping::Valid),
+    ('\u{4B0}', '\u{4B0}', Mapping::Mapped(&['\u{4B1}'])),
+    ('\u{4B1}', '\u{4B1}', Mapping::Valid),
+    ('\u{4B2}', '\u{4B2}', Mapping::Mapped(&['\u{4B3}'])),
+    ('\u{4B3}', '\u{4B3}', Mapping::Valid),
+    ('\u{4B4}', '\u{4B4}', Mapping::Mapped(&['\u{4B5}'])),
+    ('\u{4B5}', '\u{4B5}', Mapping::Valid),
+    ('\u{4B6}', '\u{4B6}', Mapping::Mapped(&['\u{4B7}'])),
+    ('\u{4B7}', '\u{4B7}', Mapping::Valid),
+    ('\u{4B8}', '\u{4B8}', Mapping::Mapped(&['\u{4B9}'])),
+    ('\u{4B9}', '\u{4B9}', Mapping::Valid),
+    ('\u{4BA}', '\u{4BA}', Mapping::Mapped(&['\u{4BB}'])),
+    ('\u{4BB}', '\u{4BB}', Mapping::Valid),
+    ('\u{4BC}', '\u{4BC}', Mapping::Mapped(&['\u{4BD}'])),
+    ('\u{4BD}', '\u{4BD}', Mapping::Valid),
+    ('\u{4BE}', '\u{4BE}', Mapping::Mapped(&['\u{4BF}'])),
+    ('\u{4BF}', '\u{4BF}', Mapping::Valid),
+    ('\u{4C0}', '\u{4C0}', Mapping::Disallowed),
+    ('\u{4C1}', '\u{4C1}', Mapping::Mapped(&['\u{4C2}'])),
+    ('\u{4C2}', '\u{4C2}', Mapping::Valid),
+    ('\u{4C3}', '\u{4C3}', Mapping::Mapped(&['\u{4C4}'])),
+    ('\u{4C4}', '\u{4C4}', Mapping::Valid),
+    ('\u{4C5}', '\u{4C5}', Mapping::Mapped(&['\u{4C6}'])),
+    ('\u{4C6}', '\u{4C6}', Mapping::Valid),
+    ('\u{4C7}', '\u{4C7}', Mapping::Mapped(&['\u{4C8}'])),
+    ('\u{4C8}', '\u{4C8}', Mapping::Valid),
+    ('\u{4C9}', '\u{4C9}', Mapping::Mapped(&['\u{4CA}'])),
+    ('\u{4CA}', '\u{4CA}', Mapping::Valid),
+    ('\u{4CB}', '\u{4CB}', Mapping::Mapped(&['\u{4CC}'])),
+    ('\u{4CC}', '\u{4CC}', Mapping::Valid),
+    ('\u{4CD}', '\u{4CD}', Mapping::Mapped(&['\u{4CE}'])),
+    ('\u{4CE}', '\u{4CF}', Mapping::Valid),
+    ('\u{4D0}', '\u{4D0}', Mapping::Mapped(&['\u{4D1}'])),
+    ('\u{4D1}', '\u{4D1}', Mapping::Valid),
+    ('\u{4D2}', '\u{4D2}', Mapping::Mapped(&['\u{4D3}'])),
+    ('\u{4D3}', '\u{4D3}', Mapping::Valid),
+    ('\u{4D4}', '\u{4D4}', Mapping::Mapped(&['\u{4D5}'])),
+    ('\u{4D5}', '\u{4D5}', Mapping::Valid),
+    ('\u{4D6}', '\u{4D6}', Mapping::Mapped(&['\u{4D7}'])),
+    ('\u{4D7}', '\u{4D7}', Mapping::Valid),
+    ('\u{4D8}', '\u{4D8}', Mapping::Mapped(&['\u{4D9}'])),
+    ('\u{4D9}', '\u{4D9}', Mapping::Valid),
+    ('\u{4DA}', '\u{4DA}', Mapping::Mapped(&['\u{4DB}'])),
+    ('\u{4DB}', '\u{4DB}', Mapping::Valid),
+    ('\u{4DC}', '\u{4DC}', Mapping::Mapped(&['\u{4DD}'])),
+    ('\u{4DD}', '\u{4DD}', Mapping::Valid),
+    ('\u{4DE}', '\u{4DE}', Mapping::Mapped(&['\u{4DF}'])),
+    ('\u{4DF}', '\u{4DF}', Mapping::Valid),
+    ('\u{4E0}', '\u{4E0}', Mapping::Mapped(&['\u{4E1}'])),
+    ('\u{4E1}', '\u{4E1}', Mapping::Valid),
+    ('\u{4E2}', '\u{4E2}', Mapping::Mapped(&['\u{4E3}'])),
+    ('\u{4E3}', '\u{4E3}', Mapping::Valid),
+    ('\u{4E4}', '\u{4E4}', Mapping::Mapped(&['\u{4E5}'])),
+    ('\u{4E5}', '\u{4E5}', Mapping::Valid),
+    ('\u{4E6}', '\u{4E6}', Mapping::Mapped(&['\u{4E7}'])),
+    ('\u{4E7}', '\u{4E7}', Mapping::Valid),
+    ('\u{4E8}', '\u{4E8}', Mapping::Mapped(&['\u{4E9}'])),
+    ('\u{4E9}', '\u{4E9}', Mapping::Valid),
+    ('\u{4EA}', '\u{4EA}', Mapping::Mapped(&['\u{4EB}'])),
+    ('\u{4EB}', '\u{4EB}', Mapping::Valid),
+    ('\u{4EC}', '\u{4EC}', Mapping::Mapped(&['\u{4ED}'])),
+    ('\u{4ED}', '\u{4ED}', Mapping::Valid),
+    ('\u{4EE}', '\u{4EE}', Mapping::Mapped(&['\u{4EF}'])),
+    ('\u{4EF}', '\u{4EF}', Mapping::Valid),
+    ('\u{4F0}', '\u{4F0}', Mapping::Mapped(&['\u{4F1}'])),
+    ('\u{4F1}', '\u{4F1}', Mapping::Valid),
+    ('\u{4F2}', '\u{4F2}', Mapping::Mapped(&['\u{4F3}'])),
+    ('\u{4F3}', '\u{4F3}', Mapping::Valid),
+    ('\u{4F4}', '\u{4F4}', Mapping::Mapped(&['\u{4F5}'])),
+    ('\u{4F5}', '\u{4F5}', Mapping::Valid),
+    ('\u{4F6}', '\u{4F6}', Mapping::Mapped(&['\u{4F7}'])),
+    ('\u{4F7}', '\u{4F7}', Mapping::Valid),
+    ('\u{4F8}', '\u{4F8}', Mapping::Mapped(&['\u{4F9}'])),
+    ('\u{4F9}', '\u{4F9}', Mapping::Valid),
+    ('\u{4FA}', '\u{4FA}', Mapping::Mapped(&['\u{4FB}'])),
+    ('\u{4FB}', '\u{4FB}', Mapping::Valid),
+    ('\u{4FC}', '\u{4FC}', Mapping::Mapped(&['\u{4FD}'])),
+    ('\u{4FD}', '\u{4FD}', Mapping::Valid),
+    ('\u{4FE}', '\u{4FE}', Mapping::Mapped(&['\u{4FF}'])),
+    ('\u{4FF}', '\u{4FF}', Mapping::Valid),
+    ('\u{500}', '\u{500}', Mapping::Mapped(&['\u{501}'])),
+    ('\u{501}', '\u{501}', Mapping::Valid),
+    ('\u{502}', '\u{502}', Mapping::Mapped(&['\u{503}'])),
+    ('\u{503}', '\u{503}', Mapping::Valid),
+    ('\u{504}', '\u{504}', Mapping::Mapped(&['\u{505}'])),
+    ('\u{505}', '\u{505}', Mapping::Valid),
+    ('\u{506}', '\u{506}', Mapping::Mapped(&['\u{507}'])),
+    ('\u{507}', '\u{507}', Mapping::Valid),
+    ('\u{508}', '\u{508}', Mapping::Mapped(&['\u{509}'])),
+    ('\u{509}', '\u{509}', Mapping::Valid),
+    ('\u{50A}', '\u{50A}', Mapping::Mapped(&['\u{50B}'])),
+    ('\u{50B}', '\u{50B}', Mapping::Valid),
+    ('\u{50C}', '\u{50C}', Mapping::Mapped(&['\u{50D}'])),
+    ('\u{50D}', '\u{50D}', Mapping::Valid),
+    ('\u{50E}', '\u{50E}', Mapping::Mapped(&['\u{50F}'])),
+    ('\u{50F}', '\u{50F}', Mapping::Valid),
+    ('\u{510}', '\u{510}', Mapping::Mapped(&['\u{511}'])),
+    ('\u{511}', '\u{511}', Mapping::Valid),
+    ('\u{512}', '\u{512}', Mapping::Mapped(&['\u{513}'])),
+    ('\u{513}', '\u{513}', Mapping::Valid),
+    ('\u{514}', '\u{514}', Mapping::Mapped(&['\u{515}'])),
+    ('\u{515}', '\u{515}', Mapping::Valid),
+    ('\u{516}', '\u{516}', Mapping::Mapped(&['\u{517}'])),
+    ('\u{517}', '\u{517}', Mapping::Valid),
+    ('\u{518}', '\u{518}', Mapping::Mapped(&['\u{519}'])),
+    ('\u{519}', '\u{519}', Mapping::Valid),
+    ('\u{51A}', '\u{51A}', Mapping::Mapped(&['\u{51B}'])),
+    ('\u{51B}', '\u{51B}', Mapping::Valid),
+    ('\u{51C}', '\u{51C}', Mapping::Mapped(&['\u{51D}'])),
+    ('\u{51D}', '\u{51D}', Mapping::Valid),
+    ('\u{51E}', '\u{51E}', Mapping::Mapped(&['\u{51F}'])),
+    ('\u{51F}', '\u{51F}', Mapping::Valid),
+    ('\u{520}', '\u{520}', Mapping::Mapped(&['\u{521}'])),
+    ('\u{521}', '\u{521}', Mapping::Valid),
+    ('\u{522}', '\u{522}', Mapping::Mapped(&['\u{523}'])),
+    ('\u{523}', '\u{523}', Mapping::Valid),
+    ('\u{524}', '\u{524}', Mapping::Mapped(&['\u{525}'])),
+    ('\u{525}', '\u{525}', Mapping::Valid),
+    ('\u{526}', '\u{526}', Mapping::Mapped(&['\u{527}'])),
+    ('\u{527}', '\u{527}', Mapping::Valid),
+    ('\u{528}', '\u{528}', Mapping::Mapped(&['\u{529}'])),
+    ('\u{529}', '\u{529}', Mapping::Valid),
+    ('\u{52A}', '\u{52A}', Mapping::Mapped(&['\u{52B}'])),
+    ('\u{52B}', '\u{52B}', Mapping::Valid),
+    ('\u{52C}', '\u{52C}', Mapping::Mapped(&['\u{52D}'])),
+    ('\u{52D}', '\u{52D}', Mapping::Valid),
+    ('\u{52E}', '\u{52E}', Mapping::Mapped(&['\u{52F}'])),
+    ('\u{52F}', '\u{52F}', Mapping::Valid),
+    ('\u{530}', '\u{530}', Mapping::Disallowed),
+    ('\u{531}', '\u{531}', Mapping::Mapped(&['\u{561}'])),
+    ('\u{532}', '\u{532}', Mapping::Mapped(&['\u{562}'])),
+    ('\u{533}', '\u{533}', Mapping::Mapped(&['\u{563}'])),
+    ('\u{534}', '\u{534}', Mapping::Mapped(&['\u{564}'])),
+    ('\u{535}', '\u{535}', Mapping::Mapped(&['\u{565}'])),
+    ('\u{536}', '\u{536}', Mapping::Mapped(&['\u{566}'])),
+    ('\u{537}', '\u{537}', Mapping::Mapped(&['\u{567}'])),
+    ('\u{538}', '\u{538}', Mapping::Mapped(&['\u{568}'])),
+    ('\u{539}', '\u{539}', Mapping::Mapped(&['\u{569}'])),
+    ('\u{53A}', '\u{53A}', Mapping::Mapped(&['\u{56A}'])),
+    ('\u{53B}', '\u{53B}', Mapping::Mapped(&['\u{56B}'])),
+    ('\u{53C}', '\u{53C}', Mapping::Mapped(&['\u{56C}'])),
+    ('\u{53D}', '\u{53D}', Mapping::Mapped(&['\u{56D}'])),
+    ('\u{53E}', '\u{53E}', Mapping::Mapped(&['\u{56E}'])),
+    ('\u{53F}', '\u{53F}', Mapping::Mapped(&['\u{56F}'])),
+    ('\u{540}', '\u{540}', Mapping::Mapped(&['\u{570}'])),
+    ('\u{541}', '\u{541}', Mapping::Mapped(&['\u{571}'])),
+    ('\u{542}', '\u{542}', Mapping::Mapped(&['\u{572}'])),
+    ('\u{543}', '\u{543}', Mapping::Mapped(&['\u{573}'])),
+    ('\u{544}', '\u{544}', Mapping::Mapped(&['\u{574}'])),
+    ('\u{545}', '\u{545}', Mapping::Mapped(&['\u{575}'])),
+    ('\u{546}', '\u{546}', Mapping::Mapped(&['\u{576}'])),
+    ('\u{547}', '\u{547}', Mapping::Mapped(&['\u{577}'])),
+    ('\u{548}', '\u{548}', Mapping::Mapped(&['\u{578}'])),
+    ('\u{549}', '\u{549}', Mapping::Mapped(&['\u{579}'])),
+    ('\u{54A}', '\u{54A}', Mapping::Mapped(&['\u{57A}'])),
+    ('\u{54B}', '\u{54B}', Mapping::Mapped(&['\u{57B}'])),
+    ('\u{54C}', '\u{54C}', Mapping::Mapped(&['\u{57C}'])),
+    ('\u{54D}', '\u{54D}', Mapping::Mapped(&['\u{57D}'])),
+    ('\u{54E}', '\u{54E}', Mapping::Mapped(&['\u{57E}'])),
+    ('\u{54F}', '\u{54F}', Mapping::Mapped(&['\u{57F}'])),
+    ('\u{550}', '\u{550}', Mapping::Mapped(&['\u{580}'])),
+    ('\u{551}', '\u{551}', Mapping::Mapped(&['\u{581}'])),
+    ('\u{552}', '\u{552}', Mapping::Mapped(&['\u{582}'])),
+    ('\u{553}', '\u{553}', Mapping::Mapped(&['\u{583}'])),
+    ('\u{554}', '\u{554}', Mapping::Mapped(&['\u{584}'])),
+    ('\u{555}', '\u{555}', Mapping::Mapped(&['\u{585}'])),
+    ('\u{556}', '\u{556}', Mapping::Mapped(&['\u{586}'])),
+    ('\u{557}', '\u{558}', Mapping::Disallowed),
+    ('\u{559}', '\u{586}', Mapping::Valid),
+    ('\u{587}', '\u{587}', Mapping::Mapped(&['\u{565}', '\u{582}'])),
+    ('\u{588}', '\u{58A}', Mapping::Valid),
+    ('\u{58B}', '\u{58C}', Mapping::Disallowed),
+    ('\u{58D}', '\u{58F}', Mapping::Valid),
+    ('\u{590}', '\u{590}', Mapping::Disallowed),
+    ('\u{591}', '\u{5C7}', Mapping::Valid),
+    ('\u{5C8}', '\u{5CF}', Mapping::Disallowed),
+    ('\u{5D0}', '\u{5EA}', Mapping::Valid),
+    ('\u{5EB}', '\u{5EE}', Mapping::Disallowed),
+    ('\u{5EF}', '\u{5F4}', Mapping::Valid),
+    ('\u{5F5}', '\u{605}', Mapping::Disallowed),
+    ('\u{606}', '\u{61B}', Mapping::Valid),
+    ('\u{61C}', '\u{61D}', Mapping::Disallowed),
+    ('\u{61E}', '\u{674}', Mapping::Valid),
+    ('\u{675}', '\u{675}', Mapping::Mapped(&['\u{627}', '\u{674}'])),
+    ('\u{676}', '\u{676}', Mapping::Mapped(&['\u{648}', '\u{674}'])),
+    ('\u{677}', '\u{677}', Mapping::Mapped(&['\u{6C7}', '\u{674}'])),
+    ('\u{678}', '\u{678}', Mapping::Mapped(&['\u{64A}', '\u{674}'])),
+    ('\u{679}', '\u{6DC}', Mapping::Valid),
+    ('\u{6DD}', '\u{6DD}', Mapping::Disallowed),
+    ('\u{6DE}', '\u{70D}', Mapping::Valid),
+    ('\u{70E}', '\u{70F}', Mapping::Disallowed),
+    ('\u{710}', '\u{74A}', Mapping::Valid),
+    ('\u{74B}', '\u{74C}', Mapping::Disallowed),
+    ('\u{74D}', '\u{7B1}', Mapping::Valid),
+    ('\u{7B2}', '\u{7BF}', Mapping::Disallowed),
+    ('\u{7C0}', '\u{7FA}', Mapping::Valid),
+    ('\u{7FB}', '\u{7FC}', Mapping::Disallowed),
+    ('\u{7FD}', '\u{82D}', Mapping::Valid),
+    ('\u{82E}', '\u{82F}', Mapping::Disallowed),
+    ('\u{830}', '\u{83E}', Mapping::Valid),
+    ('\u{83F}', '\u{83F}', Mapping::Disallowed),
+    ('\u{840}', '\u{85B}', Mapping::Valid),
+    ('\u{85C}', '\u{85D}', Mapping::Disallowed),
+    ('\u{85E}', '\u{85E}', Mapping::Valid),
+    ('\u{85F}', '\u{85F}', Mapping::Disallowed),
+    ('\u{860}', '\u{86A}', Mapping::Valid),
+    ('\u{86B}', '\u{89F}', Mapping::Disallowed),
+    ('\u{8A0}', '\u{8B4}', Mapping::Valid),
+    ('\u{8B5}', '\u{8B5}', Mapping::Disallowed),
+    ('\u{8B6}', '\u{8C7}', Mapping::Valid),
+    ('\u{8C8}', '\u{8D2}', Mapping::Disallowed),
+    ('\u{8D3}', '\u{8E1}', Mapping::Valid),
+    ('\u{8E2}', '\u{8E2}', Mapping::Disallowed),
+    ('\u{8E3}', '\u{957}', Mapping::Valid),
+    ('\u{958}', '\u{958}', Mapping::Mapped(&['\u{915}', '\u{93C}'])),
+    ('\u{959}', '\u{959}', Mapping::Mapped(&['\u{916}', '\u{93C}'])),
+    ('\u{95A}', '\u{95A}', Mapping::Mapped(&['\u{917}', '\u{93C}'])),
+    ('\u{95B}', '\u{95B}', Mapping::Mapped(&['\u{91C}', '\u{93C}'])),
+    ('\u{95C}', '\u{95C}', Mapping::Mapped(&['\u{921}', '\u{93C}'])),
+    ('\u{95D}', '\u{95D}', Mapping::Mapped(&['\u{922}', '\u{93C}'])),
+    ('\u{95E}', '\u{95E}', Mapping::Mapped(&['\u{92B}', '\u{93C}'])),
+    ('\u{95F}', '\u{95F}', Mapping::Mapped(&['\u{92F}', '\u{93C}'])),
+    ('\u{960}', '\u{983}', Mapping::Valid),
+    ('\u{984}', '\u{984}', Mapping::Disallowed),
+    ('\u{985}', '\u{98C}', Mapping::Valid),
+    ('\u{98D}', '\u{98E}', Mapping::Disallowed),
+    ('\u{98F}', '\u{990}', Mapping::Valid),
+    ('\u{991}', '\u{992}', Mapping::Disallowed),
+    ('\u{993}', '\u{9A8}', Mapping::Valid),
+    ('\u{9A9}', '\u{9A9}', Mapping::Disallowed),
+    ('\u{9AA}', '\u{9B0}', Mapping::Valid),
+    ('\u{9B1}', '\u{9B1}', Mapping::Disallowed),
+    ('\u{9B2}', '\u{9B2}', Mapping::Valid),
+    ('\u{9B3}', '\u{9B5}', Mapping::Disallowed),
+    ('\u{9B6}', '\u{9B9}', Mapping::Valid),
+    ('\u{9BA}', '\u{9BB}', Mapping::Disallowed),
+    ('\u{9BC}', '\u{9C4}', Mapping::Valid),
+    ('\u{9C5}', '\u{9C6}', Mapping::Disallowed),
+    ('\u{9C7}', '\u{9C8}', Mapping::Valid),
+    ('\u{9C9}', '\u{9CA}', Mapping::Disallowed),
+    ('\u{9CB}', '\u{9CE}', Mapping::Valid),
+    ('\u{9CF}', '\u{9D6}', Mapping::Disallowed),
+    ('\u{9D7}', '\u{9D7}', Mapping::Valid),
+    ('\u{9D8}', '\u{9DB}', Mapping::Disallowed),
+    ('\u{9DC}', '\u{9DC}', Mapping::Mapped(&['\u{9A1}', '\u{9BC}'])),
+    ('\u{9DD}', '\u{9DD}', Mapping::Mapped(&['\u{9A2}', '\u{9BC}'])),
+    ('\u{9DE}', '\u{9DE}', Mapping::Disallowed),
+    ('\u{9DF}', '\u{9DF}', Mapping::Mapped(&['\u{9AF}', '\u{9BC}'])),
+    ('\u{9E0}', '\u{9E3}', Mapping::Valid),
+    ('\u{9E4}', '\u{9E5}', Mapping::Disallowed),
+    ('\u{9E6}', '\u{9FE}', Mapping::Valid),
+    ('\u{9FF}', '\u{A00}', Mapping::Disallowed),
+    ('\u{A01}', '\u{A03}', Mapping::Valid),
+    ('\u{A04}', '\u{A04}', Mapping::Disallowed),
+    ('\u{A05}', '\u{A0A}', Mapping::Valid),
+    ('\u{A0B}', '\u{A0E}', Mapping::Disallowed),
+    ('\u{A0F}', '\u{A10}', Mapping::Valid),
+    ('\u{A11}', '\u{A12}', Mapping::Disallowed),
+    ('\u{A13}', '\u{A28}', Mapping::Valid),
+    ('\u{A29}', '\u{A29}', Mapping::Disallowed),
+    ('\u{A2A}', '\u{A30}', Mapping::Valid),
+    ('\u{A31}', '\u{A31}', Mapping::Disallowed),
+    ('\u{A32}', '\u{A32}', Mapping::Valid),
+    ('\u{A33}', '\u{A33}', Mapping::Mapped(&['\u{A32}', '\u{A3C}'])),
+    ('\u{A34}', '\u{A34}', Mapping::Disallowed),
+    ('\u{A35}', '\u{A35}', Mapping::Valid),
+    ('\u{A36}', '\u{A36}', Mapping::Mapped(&['\u{A38}', '\u{A3C}'])),
+    ('\u{A37}', '\u{A37}', Mapping::Disallowed),
+    ('\u{A38}', '\u{A39}', Mapping::Valid),
+    ('\u{A3A}', '\u{A3B}', Mapping::Disallowed),
+    ('\u{A3C}', '\u{A3C}', Mapping::Valid),
+    ('\u{A3D}', '\u{A3D}', Mapping::Disallowed),
+    ('\u{A3E}', '\u{A42}', Mapping::Valid),
+    ('\u{A43}', '\u{A46}', Mapping::Disallowed),
+    ('\u{A47}', '\u{A48}', Mapping::Valid),
+    ('\u{A49}', '\u{A4A}', Mapping::Disallowed),
+    ('\u{A4B}', '\u{A4D}', Mapping::Valid),
+    ('\u{A4E}', '\u{A50}', Mapping::Disallowed),
+    ('\u{A51}', '\u{A51}', Mapping::Valid),
+    ('\u{A52}', '\u{A58}', Mapping::Disallowed),
+    ('\u{A59}', '\u{A59}', Mapping::Mapped(&['\u{A16}', '\u{A3C}'])),
+    ('\u{A5A}', '\u{A5A}', Mapping::Mapped(&['\u{A17}', '\u{A3C}'])),
+    ('\u{A5B}', '\u{A5B}', Mapping::Mapped(&['\u{A1C}', '\u{A3C}'])),
+    ('\u{A5C}', '\u{A5C}', Mapping::Valid),
+    ('\u{A5D}', '\u{A5D}', Mapping::Disallowed),
+    ('\u{A5E}', '\u{A5E}', Mapping::Mapped(&['\u{A2B}', '\u{A3C}'])),
+    ('\u{A5F}', '\u{A65}', Mapping::Disallowed),
+    ('\u{A66}', '\u{A76}', Mapping::Valid),
+    ('\u{A77}', '\u{A80}', Mapping::Disallowed),
+    ('\u{A81}', '\u{A83}', Mapping::Valid),
+    ('\u{A84}', '\u{A84}', Mapping::Disallowed),
+    ('\u{A85}', '\u{A8D}', Mapping::Valid),
+    ('\u{A8E}', '\u{A8E}', Mapping::Disallowed),
+    ('\u{A8F}', '\u{A91}', Mapping::Valid),
+    ('\u{A92}', '\u{A92}', Mapping::Disallowed),
+    ('\u{A93}', '\u{AA8}', Mapping::Valid),
+    ('\u{AA9}', '\u{AA9}', Mapping::Disallowed),
+    ('\u{AAA}', '\u{AB0}', Mapping::Valid),
+    ('\u{AB1}', '\u{AB1}', Mapping::Disallowed),
+    ('\u{AB2}', '\u{AB3}', Mapping::Valid),
+    ('\u{AB4}', '\u{AB4}', Mapping::Disallowed),
+    ('\u{AB5}', '\u{AB9}', Mapping::Valid),
+    ('\u{ABA}', '\u{ABB}', Mapping::Disallowed),
+    ('\u{ABC}', '\u{AC5}', Mapping::Valid),
+    ('\u{AC6}', '\u{AC6}', Mapping::Disallowed),
+    ('\u{AC7}', '\u{AC9}', Mapping::Valid),
+    ('\u{ACA}', '\u{ACA}', Mapping::Disallowed),
+    ('\u{ACB}', '\u{ACD}', Mapping::Valid),
+    ('\u{ACE}', '\u{ACF}', Mapping::Disallowed),
+    ('\u{AD0}', '\u{AD0}', Mapping::Valid),
+    ('\u{AD1}', '\u{ADF}', Mapping::Disallowed),
+    ('\u{AE0}', '\u{AE3}', Mapping::Valid),
+    ('\u{AE4}', '\u{AE5}', Mapping::Disallowed),
+    ('\u{AE6}', '\u{AF1}', Mapping::Valid),
+    ('\u{AF2}', '\u{AF8}', Mapping::Disallowed),
+    ('\u{AF9}', '\u{AFF}', Mapping::Valid),
+    ('\u{B00}', '\u{B00}', Mapping::Disallowed),
+    ('\u{B01}', '\u{B03}', Mapping::Valid),
+    ('\u{B04}', '\u{B04}', Mapping::Disallowed),
+    ('\u{B05}', '\u{B0C}', Mapping::Valid),
+    ('\u{B0D}', '\u{B0E}', Mapping::Disallowed),
+    ('\u{B0F}', '\u{B10}', Mapping::Valid),
+    ('\u{B11}', '\u{B12}', Mapping::Disallowed),
+    ('\u{B13}', '\u{B28}', Mapping::Valid),
+    ('\u{B29}', '\u{B29}', Mapping::Disallowed),
+    ('\u{B2A}', '\u{B30}', Mapping::Valid),
+    ('\u{B31}', '\u{B31}', Mapping::Disallowed),
+    ('\u{B32}', '\u{B33}', Mapping::Valid),
+    ('\u{B34}', '\u{B34}', Mapping::Disallowed),
+    ('\u{B35}', '\u{B39}', Mapping::Valid),
+    ('\u{B3A}', '\u{B3B}', Mapping::Disallowed),
+    ('\u{B3C}', '\u{B44}', Mapping::Valid),
+    ('\u{B45}', '\u{B46}', Mapping::Disallowed),
+    ('\u{B47}', '\u{B48}', Mapping::Valid),
+    ('\u{B49}', '\u{B4A}', Mapping::Disallowed),
+    ('\u{B4B}', '\u{B4D}', Mapping::Valid),
+    ('\u{B4E}', '\u{B54}', Mapping::Disallowed),
+    ('\u{B55}', '\u{B57}', Mapping::Valid),
+    ('\u{B58}', '\u{B5B}', Mapping::Disallowed),
+    ('\u{B5C}', '\u{B5C}', Mapping::Mapped(&['\u{B21}', '\u{B3C}'])),
+    ('\u{B5D}', '\u{B5D}', Mapping::Mapped(&['\u{B22}', '\u{B3C}'])),
+    ('\u{B5E}', '\u{B5E}', Mapping::Disallowed),
+    ('\u{B5F}', '\u{B63}', Mapping::Valid),
+    ('\u{B64}', '\u{B65}', Mapping::Disallowed),
+    ('\u{B66}', '\u{B77}', Mapping::Valid),
+    ('\u{B78}', '\u{B81}', Mapping::Disallowed),
+    ('\u{B82}', '\u{B83}', Mapping::Valid),
+    ('\u{B84}', '\u{B84}', Mapping::Disallowed),
+    ('\u{B85}', '\u{B8A}', Mapping::Valid),
+    ('\u{B8B}', '\u{B8D}', Mapping::Disallowed),
+    ('\u{B8E}', '\u{B90}', Mapping::Valid),
+    ('\u{B91}', '\u{B91}', Mapping::Disallowed),
+    ('\u{B92}', '\u{B95}', Mapping::Valid),
+    ('\u{B96}', '\u{B98}', Mapping::Disallowed),
+    ('\u{B99}', '\u{B9A}', Mapping::Valid),
+    ('\u{B9B}', '\u{B9B}', Mapping::Disallowed),
+    ('\u{B9C}', '\u{B9C}', Mapping::Valid),
+    ('\u{B9D}', '\u{B9D}', Mapping::Disallowed),
+    ('\u{B9E}', '\u{B9F}', Mapping::Valid),
+    ('\u{BA0}', '\u{BA2}', Mapping::Disallowed),
+    ('\u{BA3}', '\u{BA4}', Mapping::Valid),
+    ('\u{BA5}', '\u{BA7}', Mapping::Disallowed),
+    ('\u{BA8}', '\u{BAA}', Mapping::Valid),
+    ('\u{BAB}', '\u{BAD}', Mapping::Disallowed),
+    ('\u{BAE}', '\u{BB9}', Mapping::Valid),
+    ('\u{BBA}', '\u{BBD}', Mapping::Disallowed),
+    ('\u{BBE}', '\u{BC2}', Mapping::Valid),
+    ('\u{BC3}', '\u{BC5}', Mapping::Disallowed),
+    ('\u{BC6}', '\u{BC8}', Mapping::Valid),
+    ('\u{BC9}', '\u{BC9}', Mapping::Disallowed),
+    ('\u{BCA}', '\u{BCD}', Mapping::Valid),
+    ('\u{BCE}', '\u{BCF}', Mapping::Disallowed),
+    ('\u{BD0}', '\u{BD0}', Mapping::Valid),
+    ('\u{BD1}', '\u{BD6}', Mapping::Disallowed),
+    ('\u{BD7}', '\u{BD7}', Mapping::Valid),
+    ('\u{BD8}', '\u{BE5}', Mapping::Disallowed),
+    ('\u{BE6}', '\u{BFA}', Mapping::Valid),
+    ('\u{BFB}', '\u{BFF}', Mapping::Disallowed),
+    ('\u{C00}', '\u{C0C}', Mapping::Valid),
+    ('\u{C0D}', '\u{C0D}', Mapping::Disallowed),
+    ('\u{C0E}', '\u{C10}', Mapping::Valid),
+    ('\u{C11}', '\u{C11}', Mapping::Disallowed),
+    ('\u{C12}', '\u{C28}', Mapping::Valid),
+    ('\u{C29}', '\u{C29}', Mapping::Disallowed),
+    ('\u{C2A}', '\u{C39}', Mapping::Valid),
+    ('\u{C3A}', '\u{C3C}', Mapping::Disallowed),
+    ('\u{C3D}', '\u{C44}', Mapping::Valid),
+    ('\u{C45}', '\u{C45}', Mapping::Disallowed),
+    ('\u{C46}', '\u{C48}', Mapping::Valid),
+    ('\u{C49}', '\u{C49}', Mapping::Disallowed),
+    ('\u{C4A}', '\u{C4D}', Mapping::Valid),
+    ('\u{C4E}', '\u{C54}', Mapping::Disallowed),
+    ('\u{C55}', '\u{C56}', Mapping::Valid),
+    ('\u{C57}', '\u{C57}', Mapping::Disallowed),
+    ('\u{C58}', '\u{C5A}', Mapping::Valid),
+    ('\u{C5B}', '\u{C5F}', Mapping::Disallowed),
+    ('\u{C60}', '\u{C63}', Mapping::Valid),
+    ('\u{C64}', '\u{C65}', Mapping::Disallowed),
+    ('\u{C66}', '\u{C6F}', Mapping::Valid),
+    ('\u{C70}', '\u{C76}', Mapping::Disallowed),
+    ('\u{C77}', '\u{C8C}', Mapping::Valid),
+    ('\u{C8D}', '\u{C8D}', Mapping::Disallowed),
+    ('\u{C8E}', '\u{C90}', Mapping::Valid),
+    ('\u{C91}', '\u{C91}', Mapping::Disallowed),
+    ('\u{C92}', '\u{CA8}', Mapping::Valid),
+    ('\u{CA9}', '\u{CA9}', Mapping::Disallowed),
+    ('\u{CAA}', '\u{CB3}', Mapping::Valid),
+    ('\u{CB4}', '\u{CB4}', Mapping::Disallowed),
+    ('\u{CB5}', '\u{CB9}', Mapping::Valid),
+    ('\u{CBA}', '\u{CBB}', Mapping::Disallowed),
+    ('\u{CBC}', '\u{CC4}', Mapping::Valid),
+    ('\u{CC5}', '\u{CC5}', Mapping::Disallowed),
+    ('\u{CC6}', '\u{CC8}', Mapping::Valid),
+    ('\u{CC9}', '\u{CC9}', Mapping::Disallowed),
+    ('\u{CCA}', '\u{CCD}', Mapping::Valid),
+    ('\u{CCE}', '\u{CD4}', Mapping::Disallowed),
+    ('\u{CD5}', '\u{CD6}', Mapping::Valid),
+    ('\u{CD7}', '\u{CDD}', Mapping::Disallowed),
+    ('\u{CDE}', '\u{CDE}', Mapping::Valid),
+    ('\u{CDF}', '\u{CDF}', Mapping::Disallowed),
+    ('\u{CE0}', '\u{CE3}', Mapping::Valid),
+    ('\u{CE4}', '\u{CE5}', Mapping::Disallowed),
+    ('\u{CE6}', '\u{CEF}', Mapping::Valid),
+    ('\u{CF0}', '\u{CF0}', Mapping::Disallowed),
+    ('\u{CF1}', '\u{CF2}', Mapping::Valid),
+    ('\u{CF3}', '\u{CFF}', Mapping::Disallowed),
+    ('\u{D00}', '\u{D0C}', Mapping::Valid),
+    ('\u{D0D}', '\u{D0D}', Mapping::Disallowed),
+    ('\u{D0E}', '\u{D10}', Mapping::Valid),
+    ('\u{D11}', '\u{D11}', Mapping::Disallowed),
+    ('\u{D12}', '\u{D44}', Mapping::Valid),
+    ('\u{D45}', '\u{D45}', Mapping::Disallowed),
+    ('\u{D46}', '\u{D48}', Mapping::Valid),
+    ('\u{D49}', '\u{D49}', Mapping::Disallowed),
+    ('\u{D4A}', '\u{D4F}', Mapping::Valid),
+    ('\u{D50}', '\u{D53}', Mapping::Disallowed),
+    ('\u{D54}', '\u{D63}', Mapping::Valid),
+    ('\u{D64}', '\u{D65}', Mapping::Disallowed),
+    ('\u{D66}', '\u{D7F}', Mapping::Valid),
+    ('\u{D80}', '\u{D80}', Mapping::Disallowed),
+    ('\u{D81}', '\u{D83}', Mapping::Valid),
+    ('\u{D84}', '\u{D84}', Mapping::Disallowed),
+    ('\u{D85}', '\u{D96}', Mapping::Valid),
+    ('\u{D97}', '\u{D99}', Mapping::Disallowed),
+    ('\u{D9A}', '\u{DB1}', Mapping::Valid),
+    ('\u{DB2}', '\u{DB2}', Mapping::Disallowed),
+    ('\u{DB3}', '\u{DBB}', Mapping::Valid),
+    ('\u{DBC}', '\u{DBC}', Mapping::Disallowed),
+    ('\u{DBD}', '\u{DBD}', Mapping::Valid),
+    ('\u{DBE}', '\u{DBF}', Mapping::Disallowed),
+    ('\u{DC0}', '\u{DC6}', Mapping::Valid),
+    ('\u{DC7}', '\u{DC9}', Mapping::Disallowed),
+    ('\u{DCA}', '\u{DCA}', Mapping::Valid),
+    ('\u{DCB}', '\u{DCE}', Mapping::Disallowed),
+    ('\u{DCF}', '\u{DD4}', Mapping::Valid),
+    ('\u{DD5}', '\u{DD5}', Mapping::Disallowed),
+    ('\u{DD6}', '\u{DD6}', Mapping::Valid),
+    ('\u{DD7}', '\u{DD7}', Mapping::Disallowed),
+    ('\u{DD8}', '\u{DDF}', Mapping::Valid),
+    ('\u{DE0}', '\u{DE5}', Mapping::Disallowed),
+    ('\u{DE6}', '\u{DEF}', Mapping::Valid),
+    ('\u{DF0}', '\u{DF1}', Mapping::Disallowed),
+    ('\u{DF2}', '\u{DF4}', Mapping::Valid),
+    ('\u{DF5}', '\u{E00}', Mapping::Disallowed),
+    ('\u{E01}', '\u{E32}', Mapping::Valid),
+    ('\u{E33}', '\u{E33}', Mapping::Mapped(&['\u{E4D}', '\u{E32}'])),
+    ('\u{E34}', '\u{E3A}', Mapping::Valid),
+    ('\u{E3B}', '\u{E3E}', Mapping::Disallowed),
+    ('\u{E3F}', '\u{E5B}', Mapping::Valid),
+    ('\u{E5C}', '\u{E80}', Mapping::Disallowed),
+    ('\u{E81}', '\u{E82}', Mapping::Valid),
+    ('\u{E83}', '\u{E83}', Mapping::Disallowed),
+    ('\u{E84}', '\u{E84}', Mapping::Valid),
+    ('\u{E85}', '\u{E85}', Mapping::Disallowed),
+    ('\u{E86}', '\u{E8A}', Mapping::Valid),
+    ('\u{E8B}', '\u{E8B}', Mapping::Disallowed),
+    ('\u{E8C}', '\u{EA3}', Mapping::Valid),
+    ('\u{EA4}', '\u{EA4}', Mapping::Disallowed),
+    ('\u{EA5}', '\u{EA5}', Mapping::Valid),
+    ('\u{EA6}', '\u{EA6}', Mapping::Disallowed),
+    ('\u{EA7}', '\u{EB2}', Mapping::Valid),
+    ('\u{EB3}', '\u{EB3}', Mapping::Mapped(&['\u{ECD}', '\u{EB2}'])),
+    ('\u{EB4}', '\u{EBD}', Mapping::Valid),
+    ('\u{EBE}', '\u{EBF}', Mapping::Disallowed),
+    ('\u{EC0}', '\u{EC4}', Mapping::Valid),
+    ('\u{EC5}', '\u{EC5}', Mapping::Disallowed),
+    ('\u{EC6}', '\u{EC6}', Mapping::Valid),
+    ('\u{EC7}', '\u{EC7}', Mapping::Disallowed),
+    ('\u{EC8}', '\u{ECD}', Mapping::Valid),
+    ('\u{ECE}', '\u{ECF}', Mapping::Disallowed),
+    ('\u{ED0}', '\u{ED9}', Mapping::Valid),
+    ('\u{EDA}', '\u{EDB}', Mapping::Disallowed),
+    ('\u{EDC}', '\u{EDC}', Mapping::Mapped(&['\u{EAB}', '\u{E99}'])),
+    ('\u{EDD}', '\u{EDD}', Mapping::Mapped(&['\u{EAB}', '\u{EA1}'])),
+    ('\u{EDE}', '\u{EDF}', Mapping::Valid),
+    ('\u{EE0}', '\u{EFF}', Mapping::Disallowed),
+    ('\u{F00}', '\u{F0B}', Mapping::Valid),
+    ('\u{F0C}', '\u{F0C}', Mapping::Mapped(&['\u{F0B}'])),
+    ('\u{F0D}', '\u{F42}', Mapping::Valid),
+    ('\u{F43}', '\u{F43}', Mapping::Mapped(&['\u{F42}', '\u{FB7}'])),
+    ('\u{F44}', '\u{F47}', Mapping::Valid),
+    ('\u{F48}', '\u{F48}', Mapping::Disallowed),
+    ('\u{F49}', '\u{F4C}', Mapping::Valid),
+    ('\u{F4D}', '\u{F4D}', Mapping::Mapped(&['\u{F4C}', '\u{FB7}'])),
+    ('\u{F4E}', '\u{F51}', Mapping::Valid),
+    ('\u{F52}', '\u{F52}', Mapping::Mapped(&['\u{F51}', '\u{FB7}'])),
+    ('\u{F53}', '\u{F56}', Mapping::Valid),
+    ('\u{F57}', '\u{F57}', Mapping::Mapped(&['\u{F56}', '\u{FB7}'])),
+    ('\u{F58}', '\u{F5B}', Mapping::Valid),
+    ('\u{F5C}', '\u{F5C}', Mapping::Mapped(&['\u{F5B}', '\u{FB7}'])),
+    ('\u{F5D}', '\u{F68}', Mapping::Valid),
+    ('\u{F69}', '\u{F69}', Mapping::Mapped(&['\u{F40}', '\u{FB5}'])),
+    ('\u{F6A}', '\u{F6C}', Mapping::Valid),
+    ('\u{F6D}', '\u{F70}', Mapping::Disallowed),
+    ('\u{F71}', '\u{F72}', Mapping::Valid),
+    ('\u{F73}', '\u{F73}', Mapping::Mapped(&['\u{F71}', '\u{F72}'])),
+    ('\u{F74}', '\u{F74}', Mapping::Valid),
+    ('\u{F75}', '\u{F75}', Mapping::Mapped(&['\u{F71}', '\u{F74}'])),
+    ('\u{F76}', '\u{F76}', Mapping::Mapped(&['\u{FB2}', '\u{F80}'])),
+    ('\u{F77}', '\u{F77}', Mapping::Mapped(&['\u{FB2}', '\u{F71}', '\u{F80}'])),
+    ('\u{F78}', '\u{F78}', Mapping::Mapped(&['\u{FB3}', '\u{F80}'])),
+    ('\u{F79}', '\u{F79}', Mapping::Mapped(&['\u{FB3}', '\u{F71}', '\u{F80}'])),
+    ('\u{F7A}', '\u{F80}', Mapping::Valid),
+    ('\u{F81}', '\u{F81}', Mapping::Mapped(&['\u{F71}', '\u{F80}'])),
+    ('\u{F82}', '\u{F92}', Mapping::Valid),
+    ('\u{F93}', '\u{F93}', Mapping::Mapped(&['\u{F92}', '\u{FB7}'])),
+    ('\u{F94}', '\u{F97}', Mapping::Valid),
+    ('\u{F98}', '\u{F98}', Mapping::Disallowed),
+    ('\u{F99}', '\u{F9C}', Mapping::Valid),
+    ('\u{F9D}', '\u{F9D}', Mapping::Mapped(&['\u{F9C}', '\u{FB7}'])),
+    ('\u{F9E}', '\u{FA1}', Mapping::Valid),
+    ('\u{FA2}', '\u{FA2}', Mapping::Mapped(&['\u{FA1}', '\u{FB7}'])),
+    ('\u{FA3}', '\u{FA6}', Mapping::Valid),
+    ('\u{FA7}', '\u{FA7}', Mapping::Mapped(&['\u{FA6}', '\u{FB7}'])),
+    ('\u{FA8}', '\u{FAB}', Mapping::Valid),
+    ('\u{FAC}', '\u{FAC}', Mapping::Mapped(&['\u{FAB}', '\u{FB7}'])),
+    ('\u{FAD}', '\u{FB8}', Mapping::Valid),
+    ('\u{FB9}', '\u{FB9}', Mapping::Mapped(&['\u{F90}', '\u{FB5}'])),
+    ('\u{FBA}', '\u{FBC}', Mapping::Valid),
+    ('\u{FBD}', '\u{FBD}', Mapping::Disallowed),
+    ('\u{FBE}', '\u{FCC}', Mapping::Valid),
+    ('\u{FCD}', '\u{FCD}', Mapping::Disallowed),
+    ('\u{FCE}', '\u{FDA}', Mapping::Valid),
+    ('\u{FDB}', '\u{FFF}', Mapping::Disallowed),
+    ('\u{1000}', '\u{109F}', Mapping::Valid),
+    ('\u{10A0}', '\u{10C6}', Mapping::Disallowed),
+    ('\u{10C7}', '\u{10C7}', Mapping::Mapped(&['\u{2D27}'])),
+    ('\u{10C8}', '\u{10CC}', Mapping::Disallowed),
+    ('\u{10CD}', '\u{10CD}', Mapping::Mapped(&['\u{2D2D}'])),
+    ('\u{10CE}', '\u{10CF}', Mapping::Disallowed),
+    ('\u{10D0}', '\u{10FB}', Mapping::Valid),
+    ('\u{10FC}', '\u{10FC}', Mapping::Mapped(&['\u{10DC}'])),
+    ('\u{10FD}', '\u{115E}', Mapping::Valid),
+    ('\u{115F}', '\u{1160}', Mapping::Disallowed),
+    ('\u{1161}', '\u{1248}', Mapping::Valid),
+    ('\u{1249}', '\u{1249}', Mapping::Disallowed),
+    ('\u{124A}', '\u{124D}', Mapping::Valid),
+    ('\u{124E}', '\u{124F}', Mapping::Disallowed),
+    ('\u{1250}', '\u{1256}', Mapping::Valid),
+    ('\u{1257}', '\u{1257}', Mapping::Disallowed),
+    ('\u{1258}', '\u{1258}', Mapping::Valid),
+    ('\u{1259}', '\u{1259}', Mapping::Disallowed),
+    ('\u{125A}', '\u{125D}', Mapping::Valid),
+    ('\u{125E}', '\u{125F}', Mapping::Disallowed),
+    ('\u{1260}', '\u{1288}', Mapping::Valid),
+    ('\u{1289}', '\u{1289}', Mapping::Disallowed),
+    ('\u{128A}', '\u{128D}', Mapping::Valid),
+    ('\u{128E}', '\u{128F}', Mapping::Disallowed),
+    ('\u{1290}', '\u{12B0}', Mapping::Valid),
+    ('\u{12B1}', '\u{12B1}', Mapping::Disallowed),
+    ('\u{12B2}', '\u{12B5}', Mapping::Valid),
+    ('\u{12B6}', '\u{12B7}', Mapping::Disallowed),
+    ('\u{12B8}', '\u{12BE}', Mapping::Valid),
+    ('\u{12BF}', '\u{12BF}', Mapping::Disallowed),
+    ('\u{12C0}', '\u{12C0}', Mapping::Valid),
+    ('\u{12C1}', '\u{12C1}', Mapping::Disallowed),
+    ('\u{12C2}', '\u{12C5}', Mapping::Valid),
+    ('\u{12C6}', '\u{12C7}', Mapping::Disallowed),
+    ('\u{12C8}', '\u{12D6}', Mapping::Valid),
+    ('\u{12D7}', '\u{12D7}', Mapping::Disallowed),
+    ('\u{12D8}', '\u{1310}', Mapping::Valid),
+    ('\u{1311}', '\u{1311}', Mapping::Disallowed),
+    ('\u{1312}', '\u{1315}', Mapping::Valid),
+    ('\u{1316}', '\u{1317}', Mapping::Disallowed),
+    ('\u{1318}', '\u{135A}', Mapping::Valid),
+    ('\u{135B}', '\u{135C}', Mapping::Disallowed),
+    ('\u{135D}', '\u{137C}', Mapping::Valid),
+    ('\u{137D}', '\u{137F}', Mapping::Disallowed),
+    ('\u{1380}', '\u{1399}', Mapping::Valid),
+    ('\u{139A}', '\u{139F}', Mapping::Disallowed),
+    ('\u{13A0}', '\u{13F5}', Mapping::Valid),
+    ('\u{13F6}', '\u{13F7}', Mapping::Disallowed),
+    ('\u{13F8}', '\u{13F8}', Mapping::Mapped(&['\u{13F0}'])),
+    ('\u{13F9}', '\u{13F9}', Mapping::Mapped(&['\u{13F1}'])),
+    ('\u{13FA}', '\u{13FA}', Mapping::Mapped(&['\u{13F2}'])),
+    ('\u{13FB}', '\u{13FB}', Mapping::Mapped(&['\u{13F3}'])),
+    ('\u{13FC}', '\u{13FC}', Mapping::Mapped(&['\u{13F4}'])),
+    ('\u{13FD}', '\u{13FD}', Mapping::Mapped(&['\u{13F5}'])),
+    ('\u{13FE}', '\u{13FF}', Mapping::Disallowed),
+    ('\u{1400}', '\u{167F}', Mapping::Valid),
+    ('\u{1680}', '\u{1680}', Mapping::Disallowed),
+    ('\u{1681}', '\u{169C}', Mapping::Valid),
+    ('\u{169D}', '\u{169F}', Mapping::Disallowed),
+    ('\u{16A0}', '\u{16F8}', Mapping::Valid),
+    ('\u{16F9}', '\u{16FF}', Mapping::Disallowed),
+    ('\u{1700}', '\u{170C}', Mapping::Valid),
+    ('\u{170D}', '\u{170D}', Mapping::Disallowed),
+    ('\u{170E}', '\u{1714}', Mapping::Valid),
+    ('\u{1715}', '\u{171F}', Mapping::Disallowed),
+    ('\u{1720}', '\u{1736}', Mapping::Valid),
+    ('\u{1737}', '\u{173F}', Mapping::Disallowed),
+    ('\u{1740}', '\u{1753}', Mapping::Valid),
+    ('\u{1754}', '\u{175F}', Mapping::Disallowed),
+    ('\u{1760}', '\u{176C}', Mapping::Valid),
+    ('\u{176D}', '\u{176D}', Mapping::Disallowed),
+    ('\u{176E}', '\u{1770}', Mapping::Valid),
+    ('\u{1771}', '\u{1771}', Mapping::Disallowed),
+    ('\u{1772}', '\u{1773}', Mapping::Valid),
+    ('\u{1774}', '\u{177F}', Mapping::Disallowed),
+    ('\u{1780}', '\u{17B3}', Mapping::Valid),
+    ('\u{17B4}', '\u{17B5}', Mapping::Disallowed),
+    ('\u{17B6}', '\u{17DD}', Mapping::Valid),
+    ('\u{17DE}', '\u{17DF}', Mapping::Disallowed),
+    ('\u{17E0}', '\u{17E9}', Mapping::Valid),
+    ('\u{17EA}', '\u{17EF}', Mapping::Disallowed),
+    ('\u{17F0}', '\u{17F9}', Mapping::Valid),
+    ('\u{17FA}', '\u{17FF}', Mapping::Disallowed),
+    ('\u{1800}', '\u{1805}', Mapping::Valid),
+    ('\u{1806}', '\u{1806}', Mapping::Disallowed),
+    ('\u{1807}', '\u{180A}', Mapping::Valid),
+    ('\u{180B}', '\u{180D}', Mapping::Ignored),
+    ('\u{180E}', '\u{180F}', Mapping::Disallowed),
+    ('\u{1810}', '\u{1819}', Mapping::Valid),
+    ('\u{181A}', '\u{181F}', Mapping::Disallowed),
+    ('\u{1820}', '\u{1878}', Mapping::Valid),
+    ('\u{1879}', '\u{187F}', Mapping::Disallowed),
+    ('\u{1880}', '\u{18AA}', Mapping::Valid),
+    ('\u{18AB}', '\u{18AF}', Mapping::Disallowed),
+    ('\u{18B0}', '\u{18F5}', Mapping::Valid),
+    ('\u{18F6}', '\u{18FF}', Mapping::Disallowed),
+    ('\u{1900}', '\u{191E}', Mapping::Valid),
+    ('\u{191F}', '\u{191F}', Mapping::Disallowed),
+    ('\u{1920}', '\u{192B}', Mapping::Valid),
+    ('\u{192C}', '\u{192F}', Mapping::Disallowed),
+    ('\u{1930}', '\u{193B}', Mapping::Valid),
+    ('\u{193C}', '\u{193F}', Mapping::Disallowed),
+    ('\u{1940}', '\u{1940}', Mapping::Valid),
+    ('\u{1941}', '\u{1943}', Mapping::Disallowed),
+    ('\u{1944}', '\u{196D}', Mapping::Valid),
+    ('\u{196E}', '\u{196F}', Mapping::Disallowed),
+    ('\u{1970}', '\u{1974}', Mapping::Valid),
+    ('\u{1975}', '\u{197F}', Mapping::Disallowed),
+    ('\u{1980}', '\u{19AB}', Mapping::Valid),
+    ('\u{19AC}', '\u{19AF}', Mapping::Disallowed),
+    ('\u{19B0}', '\u{19C9}', Mapping::Valid),
+    ('\u{19CA}', '\u{19CF}', Mapping::Disallowed),
+    ('\u{19D0}', '\u{19DA}', Mapping::Valid),
+    ('\u{19DB}', '\u{19DD}', Mapping::Disallowed),
+    ('\u{19DE}', '\u{1A1B}', Mapping::Valid),
+    ('\u{1A1C}', '\u{1A1D}', Mapping::Disallowed),
+    ('\u{1A1E}', '\u{1A5E}', Mapping::Valid),
+    ('\u{1A5F}', '\u{1A5F}', Mapping::Disallowed),
+    ('\u{1A60}', '\u{1A7C}', Mapping::Valid),
+    ('\u{1A7D}', '\u{1A7E}', Mapping::Disallowed),
+    ('\u{1A7F}', '\u{1A89}', Mapping::Valid),
+    ('\u{1A8A}', '\u{1A8F}', Mapping::Disallowed),
+    ('\u{1A90}', '\u{1A99}', Mapping::Valid),
+    ('\u{1A9A}', '\u{1A9F}', Mapping::Disallowed),
+    ('\u{1AA0}', '\u{1AAD}', Mapping::Valid),
+    ('\u{1AAE}', '\u{1AAF}', Mapping::Disallowed),
+    ('\u{1AB0}', '\u{1AC0}', Mapping::Valid),
+    ('\u{1AC1}', '\u{1AFF}', Mapping::Disallowed),
+    ('\u{1B00}', '\u{1B4B}', Mapping::Valid),
+    ('\u{1B4C}', '\u{1B4F}', Mapping::Disallowed),
+    ('\u{1B50}', '\u{1B7C}', Mapping::Valid),
+    ('\u{1B7D}', '\u{1B7F}', Mapping::Disallowed),
+    ('\u{1B80}', '\u{1BF3}', Mapping::Valid),
+    ('\u{1BF4}', '\u{1BFB}', Mapping::Disallowed),
+    ('\u{1BFC}', '\u{1C37}', Mapping::Valid),
+    ('\u{1C38}', '\u{1C3A}', Mapping::Disallowed),
+    ('\u{1C3B}', '\u{1C49}', Mapping::Valid),
+    ('\u{1C4A}', '\u{1C4C}', Mapping::Disallowed),
+    ('\u{1C4D}', '\u{1C7F}', Mapping::Valid),
+    ('\u{1C80}', '\u{1C80}', Mapping::Mapped(&['\u{432}'])),
+    ('\u{1C81}', '\u{1C81}', Mapping::Mapped(&['\u{434}'])),
+    ('\u{1C82}', '\u{1C82}', Mapping::Mapped(&['\u{43E}'])),
+    ('\u{1C83}', '\u{1C83}', Mapping::Mapped(&['\u{441}'])),
+    ('\u{1C84}', '\u{1C85}', Mapping::Mapped(&['\u{442}'])),
+    ('\u{1C86}', '\u{1C86}', Mapping::Mapped(&['\u{44A}'])),
+    ('\u{1C87}', '\u{1C87}', Mapping::Mapped(&['\u{463}'])),
+    ('\u{1C88}', '\u{1C88}', Mapping::Mapped(&['\u{A64B}'])),
+    ('\u{1C89}', '\u{1C8F}', Mapping::Disallowed),
+    ('\u{1C90}', '\u{1C90}', Mapping::Mapped(&['\u{10D0}'])),
+    ('\u{1C91}', '\u{1C91}', Mapping::Mapped(&['\u{10D1}'])),
+    ('\u{1C92}', '\u{1C92}', Mapping::Mapped(&['\u{10D2}'])),
+    ('\u{1C93}', '\u{1C93}', Mapping::Mapped(&['\u{10D3}'])),
+    ('\u{1C94}', '\u{1C94}', Mapping::Mapped(&['\u{10D4}'])),
+    ('\u{1C95}', '\u{1C95}', Mapping::Mapped(&['\u{10D5}'])),
+    ('\u{1C96}', '\u{1C96}', Mapping::Mapped(&['\u{10D6}'])),
+    ('\u{1C97}', '\u{1C97}', Mapping::Mapped(&['\u{10D7}'])),
+    ('\u{1C98}', '\u{1C98}', Mapping::Mapped(&['\u{10D8}'])),
+    ('\u{1C99}', '\u{1C99}', Mapping::Mapped(&['\u{10D9}'])),
+    ('\u{1C9A}', '\u{1C9A}', Mapping::Mapped(&['\u{10DA}'])),
+    ('\u{1C9B}', '\u{1C9B}', Mapping::Mapped(&['\u{10DB}'])),
+    ('\u{1C9C}', '\u{1C9C}', Mapping::Mapped(&['\u{10DC}'])),
+    ('\u{1C9D}', '\u{1C9D}', Mapping::Mapped(&['\u{10DD}'])),
+    ('\u{1C9E}', '\u{1C9E}', Mapping::Mapped(&['\u{10DE}'])),
+    ('\u{1C9F}', '\u{1C9F}', Mapping::Mapped(&['\u{10DF}'])),
+    ('\u{1CA0}', '\u{1CA0}', Mapping::Mapped(&['\u{10E0}'])),
+    ('\u{1CA1}', '\u{1CA1}', Mapping::Mapped(&['\u{10E1}'])),
+    ('\u{1CA2}', '\u{1CA2}', Mapping::Mapped(&['\u{10E2}'])),
+    ('\u{1CA3}', '\u{1CA3}', Mapping::Mapped(&['\u{10E3}'])),
+    ('\u{1CA4}', '\u{1CA4}', Mapping::Mapped(&['\u{10E4}'])),
+    ('\u{1CA5}', '\u{1CA5}', Mapping::Mapped(&['\u{10E5}'])),
+    ('\u{1CA6}', '\u{1CA6}', Mapping::Mapped(&['\u{10E6}'])),
+    ('\u{1CA7}', '\u{1CA7}', Mapping::Mapped(&['\u{10E7}'])),
+    ('\u{1CA8}', '\u{1CA8}', Mapping::Mapped(&['\u{10E8}'])),
+    ('\u{1CA9}', '\u{1CA9}', Mapping::Mapped(&['\u{10E9}'])),
+    ('\u{1CAA}', '\u{1CAA}', Mapping::Mapped(&['\u{10EA}'])),
+    ('\u{1CAB}', '\u{1CAB}', Mapping::Mapped(&['\u{10EB}'])),
+    ('\u{1CAC}', '\u{1CAC}', Mapping::Mapped(&['\u{10EC}'])),
+    ('\u{1CAD}', '\u{1CAD}', Mapping::Mapped(&['\u{10ED}'])),
+    ('\u{1CAE}', '\u{1CAE}', Mapping::Mapped(&['\u{10EE}'])),
+    ('\u{1CAF}', '\u{1CAF}', Mapping::Mapped(&['\u{10EF}'])),
+    ('\u{1CB0}', '\u{1CB0}', Mapping::Mapped(&['\u{10F0}'])),
+    ('\u{1CB1}', '\u{1CB1}', Mapping::Mapped(&['\u{10F1}'])),
+    ('\u{1CB2}', '\u{1CB2}', Mapping::Mapped(&['\u{10F2}'])),
+    ('\u{1CB3}', '\u{1CB3}', Mapping::Mapped(&['\u{10F3}'])),
+    ('\u{1CB4}', '\u{1CB4}', Mapping::Mapped(&['\u{10F4}'])),
+    ('\u{1CB5}', '\u{1CB5}', Mapping::Mapped(&['\u{10F5}'])),
+    ('\u{1CB6}', '\u{1CB6}', Mapping::Mapped(&['\u{10F6}'])),
+    ('\u{1CB7}', '\u{1CB7}', Mapping::Mapped(&['\u{10F7}'])),
+    ('\u{1CB8}', '\u{1CB8}', Mapping::Mapped(&['\u{10F8}'])),
+    ('\u{1CB9}', '\u{1CB9}', Mapping::Mapped(&['\u{10F9}'])),
+    ('\u{1CBA}', '\u{1CBA}', Mapping::Mapped(&['\u{10FA}'])),
+    ('\u{1CBB}', '\u{1CBC}', Mapping::Disallowed),
+    ('\u{1CBD}', '\u{1CBD}', Mapping::Mapped(&['\u{10FD}'])),
+    ('\u{1CBE}', '\u{1CBE}', Mapping::Mapped(&['\u{10FE}'])),
+    ('\u{1CBF}', '\u{1CBF}', Mapping::Mapped(&['\u{10FF}'])),
+    ('\u{1CC0}', '\u{1CC7}', Mapping::Valid),
+    ('\u{1CC8}', '\u{1CCF}', Mapping::Disallowed),
+    ('\u{1CD0}', '\u{1CFA}', Mapping::Valid),
+    ('\u{1CFB}', '\u{1CFF}', Mapping::Disallowed),
+    ('\u{1D00}', '\u{1D2B}', Mapping::Valid),
+    ('\u{1D2C}', '\u{1D2C}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D2D}', '\u{1D2D}', Mapping::Mapped(&['\u{E6}'])),
+    ('\u{1D2E}', '\u{1D2E}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D2F}', '\u{1D2F}', Mapping::Valid),
+    ('\u{1D30}', '\u{1D30}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D31}', '\u{1D31}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D32}', '\u{1D32}', Mapping::Mapped(&['\u{1DD}'])),
+    ('\u{1D33}', '\u{1D33}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D34}', '\u{1D34}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D35}', '\u{1D35}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D36}', '\u{1D36}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D37}', '\u{1D37}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D38}', '\u{1D38}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D39}', '\u{1D39}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D3A}', '\u{1D3A}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D3B}', '\u{1D3B}', Mapping::Valid),
+    ('\u{1D3C}', '\u{1D3C}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D3D}', '\u{1D3D}', Mapping::Mapped(&['\u{223}'])),
+    ('\u{1D3E}', '\u{1D3E}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D3F}', '\u{1D3F}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D40}', '\u{1D40}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D41}', '\u{1D41}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D42}', '\u{1D42}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D43}', '\u{1D43}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D44}', '\u{1D44}', Mapping::Mapped(&['\u{250}'])),
+    ('\u{1D45}', '\u{1D45}', Mapping::Mapped(&['\u{251}'])),
+    ('\u{1D46}', '\u{1D46}', Mapping::Mapped(&['\u{1D02}'])),
+    ('\u{1D47}', '\u{1D47}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D48}', '\u{1D48}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D49}', '\u{1D49}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D4A}', '\u{1D4A}', Mapping::Mapped(&['\u{259}'])),
+    ('\u{1D4B}', '\u{1D4B}', Mapping::Mapped(&['\u{25B}'])),
+    ('\u{1D4C}', '\u{1D4C}', Mapping::Mapped(&['\u{25C}'])),
+    ('\u{1D4D}', '\u{1D4D}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D4E}', '\u{1D4E}', Mapping::Valid),
+    ('\u{1D4F}', '\u{1D4F}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D50}', '\u{1D50}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D51}', '\u{1D51}', Mapping::Mapped(&['\u{14B}'])),
+    ('\u{1D52}', '\u{1D52}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D53}', '\u{1D53}', Mapping::Mapped(&['\u{254}'])),
+    ('\u{1D54}', '\u{1D54}', Mapping::Mapped(&['\u{1D16}'])),
+    ('\u{1D55}', '\u{1D55}', Mapping::Mapped(&['\u{1D17}'])),
+    ('\u{1D56}', '\u{1D56}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D57}', '\u{1D57}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D58}', '\u{1D58}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D59}', '\u{1D59}', Mapping::Mapped(&['\u{1D1D}'])),
+    ('\u{1D5A}', '\u{1D5A}', Mapping::Mapped(&['\u{26F}'])),
+    ('\u{1D5B}', '\u{1D5B}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D5C}', '\u{1D5C}', Mapping::Mapped(&['\u{1D25}'])),
+    ('\u{1D5D}', '\u{1D5D}', Mapping::Mapped(&['\u{3B2}'])),
+    ('\u{1D5E}', '\u{1D5E}', Mapping::Mapped(&['\u{3B3}'])),
+    ('\u{1D5F}', '\u{1D5F}', Mapping::Mapped(&['\u{3B4}'])),
+    ('\u{1D60}', '\u{1D60}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D61}', '\u{1D61}', Mapping::Mapped(&['\u{3C7}'])),
+    ('\u{1D62}', '\u{1D62}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D63}', '\u{1D63}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D64}', '\u{1D64}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D65}', '\u{1D65}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D66}', '\u{1D66}', Mapping::Mapped(&['\u{3B2}'])),
+    ('\u{1D67}', '\u{1D67}', Mapping::Mapped(&['\u{3B3}'])),
+    ('\u{1D68}', '\u{1D68}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D69}', '\u{1D69}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D6A}', '\u{1D6A}', Mapping::Mapped(&['\u{3C7}'])),
+    ('\u{1D6B}', '\u{1D77}', Mapping::Valid),
+    ('\u{1D78}', '\u{1D78}', Mapping::Mapped(&['\u{43D}'])),
+    ('\u{1D79}', '\u{1D9A}', Mapping::Valid),
+    ('\u{1D9B}', '\u{1D9B}', Mapping::Mapped(&['\u{252}'])),
+    ('\u{1D9C}', '\u{1D9C}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D9D}', '\u{1D9D}', Mapping::Mapped(&['\u{255}'])),
+    ('\u{1D9E}', '\u{1D9E}', Mapping::Mapped(&['\u{F0}'])),
+    ('\u{1D9F}', '\u{1D9F}', Mapping::Mapped(&['\u{25C}'])),
+    ('\u{1DA0}', '\u{1DA0}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1DA1}', '\u{1DA1}', Mapping::Mapped(&['\u{25F}'])),
+    ('\u{1DA2}', '\u{1DA2}', Mapping::Mapped(&['\u{261}'])),
+    ('\u{1DA3}', '\u{1DA3}', Mapping::Mapped(&['\u{265}'])),
+    ('\u{1DA4}', '\u{1DA4}', Mapping::Mapped(&['\u{268}'])),
+    ('\u{1DA5}', '\u{1DA5}', Mapping::Mapped(&['\u{269}'])),
+    ('\u{1DA6}', '\u{1DA6}', Mapping::Mapped(&['\u{26A}'])),
+    ('\u{1DA7}', '\u{1DA7}', Mapping::Mapped(&['\u{1D7B}'])),
+    ('\u{1DA8}', '\u{1DA8}', Mapping::Mapped(&['\u{29D}'])),
+    ('\u{1DA9}', '\u{1DA9}', Mapping::Mapped(&['\u{26D}'])),
+    ('\u{1DAA}', '\u{1DAA}', Mapping::Mapped(&['\u{1D85}'])),
+    ('\u{1DAB}', '\u{1DAB}', Mapping::Mapped(&['\u{29F}'])),
+    ('\u{1DAC}', '\u{1DAC}', Mapping::Mapped(&['\u{271}'])),
+    ('\u{1DAD}', '\u{1DAD}', Mapping::Mapped(&['\u{270}'])),
+    ('\u{1DAE}', '\u{1DAE}', Mapping::Mapped(&['\u{272}'])),
+    ('\u{1DAF}', '\u{1DAF}', Mapping::Mapped(&['\u{273}'])),
+    ('\u{1DB0}', '\u{1DB0}', Mapping::Mapped(&['\u{274}'])),
+    ('\u{1DB1}', '\u{1DB1}', Mapping::Mapped(&['\u{275}'])),
+    ('\u{1DB2}', '\u{1DB2}', Mapping::Mapped(&['\u{278}'])),
+    ('\u{1DB3}', '\u{1DB3}', Mapping::Mapped(&['\u{282}'])),
+    ('\u{1DB4}', '\u{1DB4}', Mapping::Mapped(&['\u{283}'])),
+    ('\u{1DB5}', '\u{1DB5}', Mapping::Mapped(&['\u{1AB}'])),
+    ('\u{1DB6}', '\u{1DB6}', Mapping::Mapped(&['\u{289}'])),
+    ('\u{1DB7}', '\u{1DB7}', Mapping::Mapped(&['\u{28A}'])),
+    ('\u{1DB8}', '\u{1DB8}', Mapping::Mapped(&['\u{1D1C}'])),
+    ('\u{1DB9}', '\u{1DB9}', Mapping::Mapped(&['\u{28B}'])),
+    ('\u{1DBA}', '\u{1DBA}', Mapping::Mapped(&['\u{28C}'])),
+    ('\u{1DBB}', '\u{1DBB}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1DBC}', '\u{1DBC}', Mapping::Mapped(&['\u{290}'])),
+    ('\u{1DBD}', '\u{1DBD}', Mapping::Mapped(&['\u{291}'])),
+    ('\u{1DBE}', '\u{1DBE}', Mapping::Mapped(&['\u{292}'])),
+    ('\u{1DBF}', '\u{1DBF}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1DC0}', '\u{1DF9}', Mapping::Valid),
+    ('\u{1DFA}', '\u{1DFA}', Mapping::Disallowed),
+    ('\u{1DFB}', '\u{1DFF}', Mapping::Valid),
+    ('\u{1E00}', '\u{1E00}', Mapping::Mapped(&['\u{1E01}'])),
+    ('\u{1E01}', '\u{1E01}', Mapping::Valid),
+    ('\u{1E02}', '\u{1E02}', Mapping::Mapped(&['\u{1E03}'])),
+    ('\u{1E03}', '\u{1E03}', Mapping::Valid),
+    ('\u{1E04}', '\u{1E04}', Mapping::Mapped(&['\u{1E05}'])),
+    ('\u{1E05}', '\u{1E05}', Mapping::Valid),
+    ('\u{1E06}', '\u{1E06}', Mapping::Mapped(&['\u{1E07}'])),
+    ('\u{1E07}', '\u{1E07}', Mapping::Valid),
+    ('\u{1E08}', '\u{1E08}', Mapping::Mapped(&['\u{1E09}'])),
+    ('\u{1E09}', '\u{1E09}', Mapping::Valid),
+    ('\u{1E0A}', '\u{1E0A}', Mapping::Mapped(&['\u{1E0B}'])),
+    ('\u{1E0B}', '\u{1E0B}', Mapping::Valid),
+    ('\u{1E0C}', '\u{1E0C}', Mapping::Mapped(&['\u{1E0D}'])),
+    ('\u{1E0D}', '\u{1E0D}', Mapping::Valid),
+    ('\u{1E0E}', '\u{1E0E}', Mapping::Mapped(&['\u{1E0F}'])),
+    ('\u{1E0F}', '\u{1E0F}', Mapping::Valid),
+    ('\u{1E10}', '\u{1E10}', Mapping::Mapped(&['\u{1E11}'])),
+    ('\u{1E11}', '\u{1E11}', Mapping::Valid),
+    ('\u{1E12}', '\u{1E12}', Mapping::Mapped(&['\u{1E13}'])),
+    ('\u{1E13}', '\u{1E13}', Mapping::Valid),
+    ('\u{1E14}', '\u{1E14}', Mapping::Mapped(&['\u{1E15}'])),
+    ('\u{1E15}', '\u{1E15}', Mapping::Valid),
+    ('\u{1E16}', '\u{1E16}', Mapping::Mapped(&['\u{1E17}'])),
+    ('\u{1E17}', '\u{1E17}', Mapping::Valid),
+    ('\u{1E18}', '\u{1E18}', Mapping::Mapped(&['\u{1E19}'])),
+    ('\u{1E19}', '\u{1E19}', Mapping::Valid),
+    ('\u{1E1A}', '\u{1E1A}', Mapping::Mapped(&['\u{1E1B}'])),
+    ('\u{1E1B}', '\u{1E1B}', Mapping::Valid),
+    ('\u{1E1C}', '\u{1E1C}', Mapping::Mapped(&['\u{1E1D}'])),
+    ('\u{1E1D}', '\u{1E1D}', Mapping::Valid),
+    ('\u{1E1E}', '\u{1E1E}', Mapping::Mapped(&['\u{1E1F}'])),
+    ('\u{1E1F}', '\u{1E1F}', Mapping::Valid),
+    ('\u{1E20}', '\u{1E20}', Mapping::Mapped(&['\u{1E21}'])),
+    ('\u{1E21}', '\u{1E21}', Mapping::Valid),
+    ('\u{1E22}', '\u{1E22}', Mapping::Mapped(&['\u{1E23}'])),
+    ('\u{1E23}', '\u{1E23}', Mapping::Valid),
+    ('\u{1E24}', '\u{1E24}', Mapping::Mapped(&['\u{1E25}'])),
+    ('\u{1E25}', '\u{1E25}', Mapping::Valid),
+    ('\u{1E26}', '\u{1E26}', Mapping::Mapped(&['\u{1E27}'])),
+    ('\u{1E27}', '\u{1E27}', Mapping::Valid),
+    ('\u{1E28}', '\u{1E28}', Mapping::Mapped(&['\u{1E29}'])),
+    ('\u{1E29}', '\u{1E29}', Mapping::Valid),
+    ('\u{1E2A}', '\u{1E2A}', Mapping::Mapped(&['\u{1E2B}'])),
+    ('\u{1E2B}', '\u{1E2B}', Mapping::Valid),
+    ('\u{1E2C}', '\u{1E2C}', Mapping::Mapped(&['\u{1E2D}'])),
+    ('\u{1E2D}', '\u{1E2D}', Mapping::Valid),
+    ('\u{1E2E}', '\u{1E2E}', Mapping::Mapped(&['\u{1E2F}'])),
+    ('\u{1E2F}', '\u{1E2F}', Mapping::Valid),
+    ('\u{1E30}', '\u{1E30}', Mapping::Mapped(&['\u{1E31}'])),
+    ('\u{1E31}', '\u{1E31}', Mapping::Valid),
+    ('\u{1E32}', '\u{1E32}', Mapping::Mapped(&['\u{1E33}'])),
+    ('\u{1E33}', '\u{1E33}', Mapping::Valid),
+    ('\u{1E34}', '\u{1E34}', Mapping::Mapped(&['\u{1E35}'])),
+    ('\u{1E35}', '\u{1E35}', Mapping::Valid),
+    ('\u{1E36}', '\u{1E36}', Mapping::Mapped(&['\u{1E37}'])),
+    ('\u{1E37}', '\u{1E37}', Mapping::Valid),
+    ('\u{1E38}', '\u{1E38}', Mapping::Mapped(&['\u{1E39}'])),
+    ('\u{1E39}', '\u{1E39}', Mapping::Valid),
+    ('\u{1E3A}', '\u{1E3A}', Mapping::Mapped(&['\u{1E3B}'])),
+    ('\u{1E3B}', '\u{1E3B}', Mapping::Valid),
+    ('\u{1E3C}', '\u{1E3C}', Mapping::Mapped(&['\u{1E3D}'])),
+    ('\u{1E3D}', '\u{1E3D}', Mapping::Valid),
+    ('\u{1E3E}', '\u{1E3E}', Mapping::Mapped(&['\u{1E3F}'])),
+    ('\u{1E3F}', '\u{1E3F}', Mapping::Valid),
+    ('\u{1E40}', '\u{1E40}', Mapping::Mapped(&['\u{1E41}'])),
+    ('\u{1E41}', '\u{1E41}', Mapping::Valid),
+    ('\u{1E42}', '\u{1E42}', Mapping::Mapped(&['\u{1E43}'])),
+    ('\u{1E43}', '\u{1E43}', Mapping::Valid),
+    ('\u{1E44}', '\u{1E44}', Mapping::Mapped(&['\u{1E45}'])),
+    ('\u{1E45}', '\u{1E45}', Mapping::Valid),
+    ('\u{1E46}', '\u{1E46}', Mapping::Mapped(&['\u{1E47}'])),
+    ('\u{1E47}', '\u{1E47}', Mapping::Valid),
+    ('\u{1E48}', '\u{1E48}', Mapping::Mapped(&['\u{1E49}'])),
+    ('\u{1E49}', '\u{1E49}', Mapping::Valid),
+    ('\u{1E4A}', '\u{1E4A}', Mapping::Mapped(&['\u{1E4B}'])),
+    ('\u{1E4B}', '\u{1E4B}', Mapping::Valid),
+    ('\u{1E4C}', '\u{1E4C}', Mapping::Mapped(&['\u{1E4D}'])),
+    ('\u{1E4D}', '\u{1E4D}', Mapping::Valid),
+    ('\u{1E4E}', '\u{1E4E}', Mapping::Mapped(&['\u{1E4F}'])),
+    ('\u{1E4F}', '\u{1E4F}', Mapping::Valid),
+    ('\u{1E50}', '\u{1E50}', Mapping::Mapped(&['\u{1E51}'])),
+    ('\u{1E51}', '\u{1E51}', Mapping::Valid),
+    ('\u{1E52}', '\u{1E52}', Mapping::Mapped(&['\u{1E53}'])),
+    ('\u{1E53}', '\u{1E53}', Mapping::Valid),
+    ('\u{1E54}', '\u{1E54}', Mapping::Mapped(&['\u{1E55}'])),
+    ('\u{1E55}', '\u{1E55}', Mapping::Valid),
+    ('\u{1E56}', '\u{1E56}', Mapping::Mapped(&['\u{1E57}'])),
+    ('\u{1E57}', '\u{1E57}', Mapping::Valid),
+    ('\u{1E58}', '\u{1E58}', Mapping::Mapped(&['\u{1E59}'])),
+    ('\u{1E59}', '\u{1E59}', Mapping::Valid),
+    ('\u{1E5A}', '\u{1E5A}', Mapping::Mapped(&['\u{1E5B}'])),
+    ('\u{1E5B}', '\u{1E5B}', Mapping::Valid),
+    ('\u{1E5C}', '\u{1E5C}', Mapping::Mapped(&['\u{1E5D}'])),
+    ('\u{1E5D}', '\u{1E5D}', Mapping::Valid),
+    ('\u{1E5E}', '\u{1E5E}', Mapping::Mapped(&['\u{1E5F}'])),
+    ('\u{1E5F}', '\u{1E5F}', Mapping::Valid),
+    ('\u{1E60}', '\u{1E60}', Mapping::Mapped(&['\u{1E61}'])),
+    ('\u{1E61}', '\u{1E61}', Mapping::Valid),
+    ('\u{1E62}', '\u{1E62}', Mapping::Mapped(&['\u{1E63}'])),
+    ('\u{1E63}', '\u{1E63}', Mapping::Valid),
+    ('\u{1E64}', '\u{1E64}', Mapping::Mapped(&['\u{1E65}'])),
+    ('\u{1E65}', '\u{1E65}', Mapping::Valid),
+    ('\u{1E66}', '\u{1E66}', Mapping::Mapped(&['\u{1E67}'])),
+    ('\u{1E67}', '\u{1E67}', Mapping::Valid),
+    ('\u{1E68}', '\u{1E68}', Mapping::Mapped(&['\u{1E69}'])),
+    ('\u{1E69}', '\u{1E69}', Mapping::Valid),
+    ('\u{1E6A}', '\u{1E6A}', Mapping::Mapped(&['\u{1E6B}'])),
+    ('\u{1E6B}', '\u{1E6B}', Mapping::Valid),
+    ('\u{1E6C}', '\u{1E6C}', Mapping::Mapped(&['\u{1E6D}'])),
+    ('\u{1E6D}', '\u{1E6D}', Mapping::Valid),
+    ('\u{1E6E}', '\u{1E6E}', Mapping::Mapped(&['\u{1E6F}'])),
+    ('\u{1E6F}', '\u{1E6F}', Mapping::Valid),
+    ('\u{1E70}', '\u{1E70}', Mapping::Mapped(&['\u{1E71}'])),
+    ('\u{1E71}', '\u{1E71}', Mapping::Valid),
+    ('\u{1E72}', '\u{1E72}', Mapping::Mapped(&['\u{1E73}'])),
+    ('\u{1E73}', '\u{1E73}', Mapping::Valid),
+    ('\u{1E74}', '\u{1E74}', Mapping::Mapped(&['\u{1E75}'])),
+    ('\u{1E75}', '\u{1E75}', Mapping::Valid),
+    ('\u{1E76}', '\u{1E76}', Mapping::Mapped(&['\u{1E77}'])),
+    ('\u{1E77}', '\u{1E77}', Mapping::Valid),
+    ('\u{1E78}', '\u{1E78}', Mapping::Mapped(&['\u{1E79}'])),
+    ('\u{1E79}', '\u{1E79}', Mapping::Valid),
+    ('\u{1E7A}', '\u{1E7A}', Mapping::Mapped(&['\u{1E7B}'])),
+    ('\u{1E7B}', '\u{1E7B}', Mapping::Valid),
+    ('\u{1E7C}', '\u{1E7C}', Mapping::Mapped(&['\u{1E7D}'])),
+    ('\u{1E7D}', '\u{1E7D}', Mapping::Valid),
+    ('\u{1E7E}', '\u{1E7E}', Mapping::Mapped(&['\u{1E7F}'])),
+    ('\u{1E7F}', '\u{1E7F}', Mapping::Valid),
+    ('\u{1E80}', '\u{1E80}', Mapping::Mapped(&['\u{1E81}'])),
+    ('\u{1E81}', '\u{1E81}', Mapping::Valid),
+    ('\u{1E82}', '\u{1E82}', Mapping::Mapped(&['\u{1E83}'])),
+    ('\u{1E83}', '\u{1E83}', Mapping::Valid),
+    ('\u{1E84}', '\u{1E84}', Mapping::Mapped(&['\u{1E85}'])),
+    ('\u{1E85}', '\u{1E85}', Mapping::Valid),
+    ('\u{1E86}', '\u{1E86}', Mapping::Mapped(&['\u{1E87}'])),
+    ('\u{1E87}', '\u{1E87}', Mapping::Valid),
+    ('\u{1E88}', '\u{1E88}', Mapping::Mapped(&['\u{1E89}'])),
+    ('\u{1E89}', '\u{1E89}', Mapping::Valid),
+    ('\u{1E8A}', '\u{1E8A}', Mapping::Mapped(&['\u{1E8B}'])),
+    ('\u{1E8B}', '\u{1E8B}', Mapping::Valid),
+    ('\u{1E8C}', '\u{1E8C}', Mapping::Mapped(&['\u{1E8D}'])),
+    ('\u{1E8D}', '\u{1E8D}', Mapping::Valid),
+    ('\u{1E8E}', '\u{1E8E}', Mapping::Mapped(&['\u{1E8F}'])),
+    ('\u{1E8F}', '\u{1E8F}', Mapping::Valid),
+    ('\u{1E90}', '\u{1E90}', Mapping::Mapped(&['\u{1E91}'])),
+    ('\u{1E91}', '\u{1E91}', Mapping::Valid),
+    ('\u{1E92}', '\u{1E92}', Mapping::Mapped(&['\u{1E93}'])),
+    ('\u{1E93}', '\u{1E93}', Mapping::Valid),
+    ('\u{1E94}', '\u{1E94}', Mapping::Mapped(&['\u{1E95}'])),
+    ('\u{1E95}', '\u{1E99}', Mapping::Valid),
+    ('\u{1E9A}', '\u{1E9A}', Mapping::Mapped(&['\u{61}', '\u{2BE}'])),
+    ('\u{1E9B}', '\u{1E9B}', Mapping::Mapped(&['\u{1E61}'])),
+    ('\u{1E9C}', '\u{1E9D}', Mapping::Valid),
+    ('\u{1E9E}', '\u{1E9E}', Mapping::Mapped(&['\u{73}', '\u{73}'])),
+    ('\u{1E9F}', '\u{1E9F}', Mapping::Valid),
+    ('\u{1EA0}', '\u{1EA0}', Mapping::Mapped(&['\u{1EA1}'])),
+    ('\u{1EA1}', '\u{1EA1}', Mapping::Valid),
+    ('\u{1EA2}', '\u{1EA2}', Mapping::Mapped(&['\u{1EA3}'])),
+    ('\u{1EA3}', '\u{1EA3}', Mapping::Valid),
+    ('\u{1EA4}', '\u{1EA4}', Mapping::Mapped(&['\u{1EA5}'])),
+    ('\u{1EA5}', '\u{1EA5}', Mapping::Valid),
+    ('\u{1EA6}', '\u{1EA6}', Mapping::Mapped(&['\u{1EA7}'])),
+    ('\u{1EA7}', '\u{1EA7}', Mapping::Valid),
+    ('\u{1EA8}', '\u{1EA8}', Mapping::Mapped(&['\u{1EA9}'])),
+    ('\u{1EA9}', '\u{1EA9}', Mapping::Valid),
+    ('\u{1EAA}', '\u{1EAA}', Mapping::Mapped(&['\u{1EAB}'])),
+    ('\u{1EAB}', '\u{1EAB}', Mapping::Valid),
+    ('\u{1EAC}', '\u{1EAC}', Mapping::Mapped(&['\u{1EAD}'])),
+    ('\u{1EAD}', '\u{1EAD}', Mapping::Valid),
+    ('\u{1EAE}', '\u{1EAE}', Mapping::Mapped(&['\u{1EAF}'])),
+    ('\u{1EAF}', '\u{1EAF}', Mapping::Valid),
+    ('\u{1EB0}', '\u{1EB0}', Mapping::Mapped(&['\u{1EB1}'])),
+    ('\u{1EB1}', '\u{1EB1}', Mapping::Valid),
+    ('\u{1EB2}', '\u{1EB2}', Mapping::Mapped(&['\u{1EB3}'])),
+    ('\u{1EB3}', '\u{1EB3}', Mapping::Valid),
+    ('\u{1EB4}', '\u{1EB4}', Mapping::Mapped(&['\u{1EB5}'])),
+    ('\u{1EB5}', '\u{1EB5}', Mapping::Valid),
+    ('\u{1EB6}', '\u{1EB6}', Mapping::Mapped(&['\u{1EB7}'])),
+    ('\u{1EB7}', '\u{1EB7}', Mapping::Valid),
+    ('\u{1EB8}', '\u{1EB8}', Mapping::Mapped(&['\u{1EB9}'])),
+    ('\u{1EB9}', '\u{1EB9}', Mapping::Valid),
+    ('\u{1EBA}', '\u{1EBA}', Mapping::Mapped(&['\u{1EBB}'])),
+    ('\u{1EBB}', '\u{1EBB}', Mapping::Valid),
+    ('\u{1EBC}', '\u{1EBC}', Mapping::Mapped(&['\u{1EBD}'])),
+    ('\u{1EBD}', '\u{1EBD}', Mapping::Valid),
+    ('\u{1EBE}', '\u{1EBE}', Mapping::Mapped(&['\u{1EBF}'])),
+    ('\u{1EBF}', '\u{1EBF}', Mapping::Valid),
+    ('\u{1EC0}', '\u{1EC0}', Mapping::Mapped(&['\u{1EC1}'])),
+    ('\u{1EC1}', '\u{1EC1}', Mapping::Valid),
+    ('\u{1EC2}', '\u{1EC2}', Mapping::Mapped(&['\u{1EC3}'])),
+    ('\u{1EC3}', '\u{1EC3}', Mapping::Valid),
+    ('\u{1EC4}', '\u{1EC4}', Mapping::Mapped(&['\u{1EC5}'])),
+    ('\u{1EC5}', '\u{1EC5}', Mapping::Valid),
+    ('\u{1EC6}', '\u{1EC6}', Mapping::Mapped(&['\u{1EC7}'])),
+    ('\u{1EC7}', '\u{1EC7}', Mapping::Valid),
+    ('\u{1EC8}', '\u{1EC8}', Mapping::Mapped(&['\u{1EC9}'])),
+    ('\u{1EC9}', '\u{1EC9}', Mapping::Valid),
+    ('\u{1ECA}', '\u{1ECA}', Mapping::Mapped(&['\u{1ECB}'])),
+    ('\u{1ECB}', '\u{1ECB}', Mapping::Valid),
+    ('\u{1ECC}', '\u{1ECC}', Mapping::Mapped(&['\u{1ECD}'])),
+    ('\u{1ECD}', '\u{1ECD}', Mapping::Valid),
+    ('\u{1ECE}', '\u{1ECE}', Mapping::Mapped(&['\u{1ECF}'])),
+    ('\u{1ECF}', '\u{1ECF}', Mapping::Valid),
+    ('\u{1ED0}', '\u{1ED0}', Mapping::Mapped(&['\u{1ED1}'])),
+    ('\u{1ED1}', '\u{1ED1}', Mapping::Valid),
+    ('\u{1ED2}', '\u{1ED2}', Mapping::Mapped(&['\u{1ED3}'])),
+    ('\u{1ED3}', '\u{1ED3}', Mapping::Valid),
+    ('\u{1ED4}', '\u{1ED4}', Mapping::Mapped(&['\u{1ED5}'])),
+    ('\u{1ED5}', '\u{1ED5}', Mapping::Valid),
+    ('\u{1ED6}', '\u{1ED6}', Mapping::Mapped(&['\u{1ED7}'])),
+    ('\u{1ED7}', '\u{1ED7}', Mapping::Valid),
+    ('\u{1ED8}', '\u{1ED8}', Mapping::Mapped(&['\u{1ED9}'])),
+    ('\u{1ED9}', '\u{1ED9}', Mapping::Valid),
+    ('\u{1EDA}', '\u{1EDA}', Mapping::Mapped(&['\u{1EDB}'])),
+    ('\u{1EDB}', '\u{1EDB}', Mapping::Valid),
+    ('\u{1EDC}', '\u{1EDC}', Mapping::Mapped(&['\u{1EDD}'])),
+    ('\u{1EDD}', '\u{1EDD}', Mapping::Valid),
+    ('\u{1EDE}', '\u{1EDE}', Mapping::Mapped(&['\u{1EDF}'])),
+    ('\u{1EDF}', '\u{1EDF}', Mapping::Valid),
+    ('\u{1EE0}', '\u{1EE0}', Mapping::Mapped(&['\u{1EE1}'])),
+    ('\u{1EE1}', '\u{1EE1}', Mapping::Valid),
+    ('\u{1EE2}', '\u{1EE2}', Mapping::Mapped(&['\u{1EE3}'])),
+    ('\u{1EE3}', '\u{1EE3}', Mapping::Valid),
+    ('\u{1EE4}', '\u{1EE4}', Mapping::Mapped(&['\u{1EE5}'])),
+    ('\u{1EE5}', '\u{1EE5}', Mapping::Valid),
+    ('\u{1EE6}', '\u{1EE6}', Mapping::Mapped(&['\u{1EE7}'])),
+    ('\u{1EE7}', '\u{1EE7}', Mapping::Valid),
+    ('\u{1EE8}', '\u{1EE8}', Mapping::Mapped(&['\u{1EE9}'])),
+    ('\u{1EE9}', '\u{1EE9}', Mapping::Valid),
+    ('\u{1EEA}', '\u{1EEA}', Mapping::Mapped(&['\u{1EEB}'])),
+    ('\u{1EEB}', '\u{1EEB}', Mapping::Valid),
+    ('\u{1EEC}', '\u{1EEC}', Mapping::Mapped(&['\u{1EED}'])),
+    ('\u{1EED}', '\u{1EED}', Mapping::Valid),
+    ('\u{1EEE}', '\u{1EEE}', Mapping::Mapped(&['\u{1EEF}'])),
+    ('\u{1EEF}', '\u{1EEF}', Mapping::Valid),
+    ('\u{1EF0}', '\u{1EF0}', Mapping::Mapped(&['\u{1EF1}'])),
+    ('\u{1EF1}', '\u{1EF1}', Mapping::Valid),
+    ('\u{1EF2}', '\u{1EF2}', Mapping::Mapped(&['\u{1EF3}'])),
+    ('\u{1EF3}', '\u{1EF3}', Mapping::Valid),
+    ('\u{1EF4}', '\u{1EF4}', Mapping::Mapped(&['\u{1EF5}'])),
+    ('\u{1EF5}', '\u{1EF5}', Mapping::Valid),
+    ('\u{1EF6}', '\u{1EF6}', Mapping::Mapped(&['\u{1EF7}'])),
+    ('\u{1EF7}', '\u{1EF7}', Mapping::Valid),
+    ('\u{1EF8}', '\u{1EF8}', Mapping::Mapped(&['\u{1EF9}'])),
+    ('\u{1EF9}', '\u{1EF9}', Mapping::Valid),
+    ('\u{1EFA}', '\u{1EFA}', Mapping::Mapped(&['\u{1EFB}'])),
+    ('\u{1EFB}', '\u{1EFB}', Mapping::Valid),
+    ('\u{1EFC}', '\u{1EFC}', Mapping::Mapped(&['\u{1EFD}'])),
+    ('\u{1EFD}', '\u{1EFD}', Mapping::Valid),
+    ('\u{1EFE}', '\u{1EFE}', Mapping::Mapped(&['\u{1EFF}'])),
+    ('\u{1EFF}', '\u{1F07}', Mapping::Valid),
+    ('\u{1F08}', '\u{1F08}', Mapping::Mapped(&['\u{1F00}'])),
+    ('\u{1F09}', '\u{1F09}', Mapping::Mapped(&['\u{1F01}'])),
+    ('\u{1F0A}', '\u{1F0A}', Mapping::Mapped(&['\u{1F02}'])),
+    ('\u{1F0B}', '\u{1F0B}', Mapping::Mapped(&['\u{1F03}'])),
+    ('\u{1F0C}', '\u{1F0C}', Mapping::Mapped(&['\u{1F04}'])),
+    ('\u{1F0D}', '\u{1F0D}', Mapping::Mapped(&['\u{1F05}'])),
+    ('\u{1F0E}', '\u{1F0E}', Mapping::Mapped(&['\u{1F06}'])),
+    ('\u{1F0F}', '\u{1F0F}', Mapping::Mapped(&['\u{1F07}'])),
+    ('\u{1F10}', '\u{1F15}', Mapping::Valid),
+    ('\u{1F16}', '\u{1F17}', Mapping::Disallowed),
+    ('\u{1F18}', '\u{1F18}', Mapping::Mapped(&['\u{1F10}'])),
+    ('\u{1F19}', '\u{1F19}', Mapping::Mapped(&['\u{1F11}'])),
+    ('\u{1F1A}', '\u{1F1A}', Mapping::Mapped(&['\u{1F12}'])),
+    ('\u{1F1B}', '\u{1F1B}', Mapping::Mapped(&['\u{1F13}'])),
+    ('\u{1F1C}', '\u{1F1C}', Mapping::Mapped(&['\u{1F14}'])),
+    ('\u{1F1D}', '\u{1F1D}', Mapping::Mapped(&['\u{1F15}'])),
+    ('\u{1F1E}', '\u{1F1F}', Mapping::Disallowed),
+    ('\u{1F20}', '\u{1F27}', Mapping::Valid),
+    ('\u{1F28}', '\u{1F28}', Mapping::Mapped(&['\u{1F20}'])),
+    ('\u{1F29}', '\u{1F29}', Mapping::Mapped(&['\u{1F21}'])),
+    ('\u{1F2A}', '\u{1F2A}', Mapping::Mapped(&['\u{1F22}'])),
+    ('\u{1F2B}', '\u{1F2B}', Mapping::Mapped(&['\u{1F23}'])),
+    ('\u{1F2C}', '\u{1F2C}', Mapping::Mapped(&['\u{1F24}'])),
+    ('\u{1F2D}', '\u{1F2D}', Mapping::Mapped(&['\u{1F25}'])),
+    ('\u{1F2E}', '\u{1F2E}', Mapping::Mapped(&['\u{1F26}'])),
+    ('\u{1F2F}', '\u{1F2F}', Mapping::Mapped(&['\u{1F27}'])),
+    ('\u{1F30}', '\u{1F37}', Mapping::Valid),
+    ('\u{1F38}', '\u{1F38}', Mapping::Mapped(&['\u{1F30}'])),
+    ('\u{1F39}', '\u{1F39}', Mapping::Mapped(&['\u{1F31}'])),
+    ('\u{1F3A}', '\u{1F3A}', Mapping::Mapped(&['\u{1F32}'])),
+    ('\u{1F3B}', '\u{1F3B}', Mapping::Mapped(&['\u{1F33}'])),
+    ('\u{1F3C}', '\u{1F3C}', Mapping::Mapped(&['\u{1F34}'])),
+    ('\u{1F3D}', '\u{1F3D}', Mapping::Mapped(&['\u{1F35}'])),
+    ('\u{1F3E}', '\u{1F3E}', Mapping::Mapped(&['\u{1F36}'])),
+    ('\u{1F3F}', '\u{1F3F}', Mapping::Mapped(&['\u{1F37}'])),
+    ('\u{1F40}', '\u{1F45}', Mapping::Valid),
+    ('\u{1F46}', '\u{1F47}', Mapping::Disallowed),
+    ('\u{1F48}', '\u{1F48}', Mapping::Mapped(&['\u{1F40}'])),
+    ('\u{1F49}', '\u{1F49}', Mapping::Mapped(&['\u{1F41}'])),
+    ('\u{1F4A}', '\u{1F4A}', Mapping::Mapped(&['\u{1F42}'])),
+    ('\u{1F4B}', '\u{1F4B}', Mapping::Mapped(&['\u{1F43}'])),
+    ('\u{1F4C}', '\u{1F4C}', Mapping::Mapped(&['\u{1F44}'])),
+    ('\u{1F4D}', '\u{1F4D}', Mapping::Mapped(&['\u{1F45}'])),
+    ('\u{1F4E}', '\u{1F4F}', Mapping::Disallowed),
+    ('\u{1F50}', '\u{1F57}', Mapping::Valid),
+    ('\u{1F58}', '\u{1F58}', Mapping::Disallowed),
+    ('\u{1F59}', '\u{1F59}', Mapping::Mapped(&['\u{1F51}'])),
+    ('\u{1F5A}', '\u{1F5A}', Mapping::Disallowed),
+    ('\u{1F5B}', '\u{1F5B}', Mapping::Mapped(&['\u{1F53}'])),
+    ('\u{1F5C}', '\u{1F5C}', Mapping::Disallowed),
+    ('\u{1F5D}', '\u{1F5D}', Mapping::Mapped(&['\u{1F55}'])),
+    ('\u{1F5E}', '\u{1F5E}', Mapping::Disallowed),
+    ('\u{1F5F}', '\u{1F5F}', Mapping::Mapped(&['\u{1F57}'])),
+    ('\u{1F60}', '\u{1F67}', Mapping::Valid),
+    ('\u{1F68}', '\u{1F68}', Mapping::Mapped(&['\u{1F60}'])),
+    ('\u{1F69}', '\u{1F69}', Mapping::Mapped(&['\u{1F61}'])),
+    ('\u{1F6A}', '\u{1F6A}', Mapping::Mapped(&['\u{1F62}'])),
+    ('\u{1F6B}', '\u{1F6B}', Mapping::Mapped(&['\u{1F63}'])),
+    ('\u{1F6C}', '\u{1F6C}', Mapping::Mapped(&['\u{1F64}'])),
+    ('\u{1F6D}', '\u{1F6D}', Mapping::Mapped(&['\u{1F65}'])),
+    ('\u{1F6E}', '\u{1F6E}', Mapping::Mapped(&['\u{1F66}'])),
+    ('\u{1F6F}', '\u{1F6F}', Mapping::Mapped(&['\u{1F67}'])),
+    ('\u{1F70}', '\u{1F70}', Mapping::Valid),
+    ('\u{1F71}', '\u{1F71}', Mapping::Mapped(&['\u{3AC}'])),
+    ('\u{1F72}', '\u{1F72}', Mapping::Valid),
+    ('\u{1F73}', '\u{1F73}', Mapping::Mapped(&['\u{3AD}'])),
+    ('\u{1F74}', '\u{1F74}', Mapping::Valid),
+    ('\u{1F75}', '\u{1F75}', Mapping::Mapped(&['\u{3AE}'])),
+    ('\u{1F76}', '\u{1F76}', Mapping::Valid),
+    ('\u{1F77}', '\u{1F77}', Mapping::Mapped(&['\u{3AF}'])),
+    ('\u{1F78}', '\u{1F78}', Mapping::Valid),
+    ('\u{1F79}', '\u{1F79}', Mapping::Mapped(&['\u{3CC}'])),
+    ('\u{1F7A}', '\u{1F7A}', Mapping::Valid),
+    ('\u{1F7B}', '\u{1F7B}', Mapping::Mapped(&['\u{3CD}'])),
+    ('\u{1F7C}', '\u{1F7C}', Mapping::Valid),
+    ('\u{1F7D}', '\u{1F7D}', Mapping::Mapped(&['\u{3CE}'])),
+    ('\u{1F7E}', '\u{1F7F}', Mapping::Disallowed),
+    ('\u{1F80}', '\u{1F80}', Mapping::Mapped(&['\u{1F00}', '\u{3B9}'])),
+    ('\u{1F81}', '\u{1F81}', Mapping::Mapped(&['\u{1F01}', '\u{3B9}'])),
+    ('\u{1F82}', '\u{1F82}', Mapping::Mapped(&['\u{1F02}', '\u{3B9}'])),
+    ('\u{1F83}', '\u{1F83}', Mapping::Mapped(&['\u{1F03}', '\u{3B9}'])),
+    ('\u{1F84}', '\u{1F84}', Mapping::Mapped(&['\u{1F04}', '\u{3B9}'])),
+    ('\u{1F85}', '\u{1F85}', Mapping::Mapped(&['\u{1F05}', '\u{3B9}'])),
+    ('\u{1F86}', '\u{1F86}', Mapping::Mapped(&['\u{1F06}', '\u{3B9}'])),
+    ('\u{1F87}', '\u{1F87}', Mapping::Mapped(&['\u{1F07}', '\u{3B9}'])),
+    ('\u{1F88}', '\u{1F88}', Mapping::Mapped(&['\u{1F00}', '\u{3B9}'])),
+    ('\u{1F89}', '\u{1F89}', Mapping::Mapped(&['\u{1F01}', '\u{3B9}'])),
+    ('\u{1F8A}', '\u{1F8A}', Mapping::Mapped(&['\u{1F02}', '\u{3B9}'])),
+    ('\u{1F8B}', '\u{1F8B}', Mapping::Mapped(&['\u{1F03}', '\u{3B9}'])),
+    ('\u{1F8C}', '\u{1F8C}', Mapping::Mapped(&['\u{1F04}', '\u{3B9}'])),
+    ('\u{1F8D}', '\u{1F8D}', Mapping::Mapped(&['\u{1F05}', '\u{3B9}'])),
+    ('\u{1F8E}', '\u{1F8E}', Mapping::Mapped(&['\u{1F06}', '\u{3B9}'])),
+    ('\u{1F8F}', '\u{1F8F}', Mapping::Mapped(&['\u{1F07}', '\u{3B9}'])),
+    ('\u{1F90}', '\u{1F90}', Mapping::Mapped(&['\u{1F20}', '\u{3B9}'])),
+    ('\u{1F91}', '\u{1F91}', Mapping::Mapped(&['\u{1F21}', '\u{3B9}'])),
+    ('\u{1F92}', '\u{1F92}', Mapping::Mapped(&['\u{1F22}', '\u{3B9}'])),
+    ('\u{1F93}', '\u{1F93}', Mapping::Mapped(&['\u{1F23}', '\u{3B9}'])),
+    ('\u{1F94}', '\u{1F94}', Mapping::Mapped(&['\u{1F24}', '\u{3B9}'])),
+    ('\u{1F95}', '\u{1F95}', Mapping::Mapped(&['\u{1F25}', '\u{3B9}'])),
+    ('\u{1F96}', '\u{1F96}', Mapping::Mapped(&['\u{1F26}', '\u{3B9}'])),
+    ('\u{1F97}', '\u{1F97}', Mapping::Mapped(&['\u{1F27}', '\u{3B9}'])),
+    ('\u{1F98}', '\u{1F98}', Mapping::Mapped(&['\u{1F20}', '\u{3B9}'])),
+    ('\u{1F99}', '\u{1F99}', Mapping::Mapped(&['\u{1F21}', '\u{3B9}'])),
+    ('\u{1F9A}', '\u{1F9A}', Mapping::Mapped(&['\u{1F22}', '\u{3B9}'])),
+    ('\u{1F9B}', '\u{1F9B}', Mapping::Mapped(&['\u{1F23}', '\u{3B9}'])),
+    ('\u{1F9C}', '\u{1F9C}', Mapping::Mapped(&['\u{1F24}', '\u{3B9}'])),
+    ('\u{1F9D}', '\u{1F9D}', Mapping::Mapped(&['\u{1F25}', '\u{3B9}'])),
+    ('\u{1F9E}', '\u{1F9E}', Mapping::Mapped(&['\u{1F26}', '\u{3B9}'])),
+    ('\u{1F9F}', '\u{1F9F}', Mapping::Mapped(&['\u{1F27}', '\u{3B9}'])),
+    ('\u{1FA0}', '\u{1FA0}', Mapping::Mapped(&['\u{1F60}', '\u{3B9}'])),
+    ('\u{1FA1}', '\u{1FA1}', Mapping::Mapped(&['\u{1F61}', '\u{3B9}'])),
+    ('\u{1FA2}', '\u{1FA2}', Mapping::Mapped(&['\u{1F62}', '\u{3B9}'])),
+    ('\u{1FA3}', '\u{1FA3}', Mapping::Mapped(&['\u{1F63}', '\u{3B9}'])),
+    ('\u{1FA4}', '\u{1FA4}', Mapping::Mapped(&['\u{1F64}', '\u{3B9}'])),
+    ('\u{1FA5}', '\u{1FA5}', Mapping::Mapped(&['\u{1F65}', '\u{3B9}'])),
+    ('\u{1FA6}', '\u{1FA6}', Mapping::Mapped(&['\u{1F66}', '\u{3B9}'])),
+    ('\u{1FA7}', '\u{1FA7}', Mapping::Mapped(&['\u{1F67}', '\u{3B9}'])),
+    ('\u{1FA8}', '\u{1FA8}', Mapping::Mapped(&['\u{1F60}', '\u{3B9}'])),
+    ('\u{1FA9}', '\u{1FA9}', Mapping::Mapped(&['\u{1F61}', '\u{3B9}'])),
+    ('\u{1FAA}', '\u{1FAA}', Mapping::Mapped(&['\u{1F62}', '\u{3B9}'])),
+    ('\u{1FAB}', '\u{1FAB}', Mapping::Mapped(&['\u{1F63}', '\u{3B9}'])),
+    ('\u{1FAC}', '\u{1FAC}', Mapping::Mapped(&['\u{1F64}', '\u{3B9}'])),
+    ('\u{1FAD}', '\u{1FAD}', Mapping::Mapped(&['\u{1F65}', '\u{3B9}'])),
+    ('\u{1FAE}', '\u{1FAE}', Mapping::Mapped(&['\u{1F66}', '\u{3B9}'])),
+    ('\u{1FAF}', '\u{1FAF}', Mapping::Mapped(&['\u{1F67}', '\u{3B9}'])),
+    ('\u{1FB0}', '\u{1FB1}', Mapping::Valid),
+    ('\u{1FB2}', '\u{1FB2}', Mapping::Mapped(&['\u{1F70}', '\u{3B9}'])),
+    ('\u{1FB3}', '\u{1FB3}', Mapping::Mapped(&['\u{3B1}', '\u{3B9}'])),
+    ('\u{1FB4}', '\u{1FB4}', Mapping::Mapped(&['\u{3AC}', '\u{3B9}'])),
+    ('\u{1FB5}', '\u{1FB5}', Mapping::Disallowed),
+    ('\u{1FB6}', '\u{1FB6}', Mapping::Valid),
+    ('\u{1FB7}', '\u{1FB7}', Mapping::Mapped(&['\u{1FB6}', '\u{3B9}'])),
+    ('\u{1FB8}', '\u{1FB8}', Mapping::Mapped(&['\u{1FB0}'])),
+    ('\u{1FB9}', '\u{1FB9}', Mapping::Mapped(&['\u{1FB1}'])),
+    ('\u{1FBA}', '\u{1FBA}', Mapping::Mapped(&['\u{1F70}'])),
+    ('\u{1FBB}', '\u{1FBB}', Mapping::Mapped(&['\u{3AC}'])),
+    ('\u{1FBC}', '\u{1FBC}', Mapping::Mapped(&['\u{3B1}', '\u{3B9}'])),
+    ('\u{1FBD}', '\u{1FBD}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{313}'])),
+    ('\u{1FBE}', '\u{1FBE}', Mapping::Mapped(&['\u{3B9}'])),
+    ('\u{1FBF}', '\u{1FBF}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{313}'])),
+    ('\u{1FC0}', '\u{1FC0}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{342}'])),
+    ('\u{1FC1}', '\u{1FC1}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{308}', '\u{342}'])),
+    ('\u{1FC2}', '\u{1FC2}', Mapping::Mapped(&['\u{1F74}', '\u{3B9}'])),
+    ('\u{1FC3}', '\u{1FC3}', Mapping::Mapped(&['\u{3B7}', '\u{3B9}'])),
+    ('\u{1FC4}', '\u{1FC4}', Mapping::Mapped(&['\u{3AE}', '\u{3B9}'])),
+    ('\u{1FC5}', '\u{1FC5}', Mapping::Disallowed),
+    ('\u{1FC6}', '\u{1FC6}', Mapping::Valid),
+    ('\u{1FC7}', '\u{1FC7}', Mapping::Mapped(&['\u{1FC6}', '\u{3B9}'])),
+    ('\u{1FC8}', '\u{1FC8}', Mapping::Mapped(&['\u{1F72}'])),
+    ('\u{1FC9}', '\u{1FC9}', Mapping::Mapped(&['\u{3AD}'])),
+    ('\u{1FCA}', '\u{1FCA}', Mapping::Mapped(&['\u{1F74}'])),
+    ('\u{1FCB}', '\u{1FCB}', Mapping::Mapped(&['\u{3AE}'])),
+    ('\u{1FCC}', '\u{1FCC}', Mapping::Mapped(&['\u{3B7}', '\u{3B9}'])),
+    ('\u{1FCD}', '\u{1FCD}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{313}', '\u{300}'])),
+    ('\u{1FCE}', '\u{1FCE}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{313}', '\u{301}'])),
+    ('\u{1FCF}', '\u{1FCF}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{313}', '\u{342}'])),
+    ('\u{1FD0}', '\u{1FD2}', Mapping::Valid),
+    ('\u{1FD3}', '\u{1FD3}', Mapping::Mapped(&['\u{390}'])),
+    ('\u{1FD4}', '\u{1FD5}', Mapping::Disallowed),
+    ('\u{1FD6}', '\u{1FD7}', Mapping::Valid),
+    ('\u{1FD8}', '\u{1FD8}', Mapping::Mapped(&['\u{1FD0}'])),
+    ('\u{1FD9}', '\u{1FD9}', Mapping::Mapped(&['\u{1FD1}'])),
+    ('\u{1FDA}', '\u{1FDA}', Mapping::Mapped(&['\u{1F76}'])),
+    ('\u{1FDB}', '\u{1FDB}', Mapping::Mapped(&['\u{3AF}'])),
+    ('\u{1FDC}', '\u{1FDC}', Mapping::Disallowed),
+    ('\u{1FDD}', '\u{1FDD}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{314}', '\u{300}'])),
+    ('\u{1FDE}', '\u{1FDE}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{314}', '\u{301}'])),
+    ('\u{1FDF}', '\u{1FDF}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{314}', '\u{342}'])),
+    ('\u{1FE0}', '\u{1FE2}', Mapping::Valid),
+    ('\u{1FE3}', '\u{1FE3}', Mapping::Mapped(&['\u{3B0}'])),
+    ('\u{1FE4}', '\u{1FE7}', Mapping::Valid),
+    ('\u{1FE8}', '\u{1FE8}', Mapping::Mapped(&['\u{1FE0}'])),
+    ('\u{1FE9}', '\u{1FE9}', Mapping::Mapped(&['\u{1FE1}'])),
+    ('\u{1FEA}', '\u{1FEA}', Mapping::Mapped(&['\u{1F7A}'])),
+    ('\u{1FEB}', '\u{1FEB}', Mapping::Mapped(&['\u{3CD}'])),
+    ('\u{1FEC}', '\u{1FEC}', Mapping::Mapped(&['\u{1FE5}'])),
+    ('\u{1FED}', '\u{1FED}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{308}', '\u{300}'])),
+    ('\u{1FEE}', '\u{1FEE}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{308}', '\u{301}'])),
+    ('\u{1FEF}', '\u{1FEF}', Mapping::DisallowedStd3Mapped(&['\u{60}'])),
+    ('\u{1FF0}', '\u{1FF1}', Mapping::Disallowed),
+    ('\u{1FF2}', '\u{1FF2}', Mapping::Mapped(&['\u{1F7C}', '\u{3B9}'])),
+    ('\u{1FF3}', '\u{1FF3}', Mapping::Mapped(&['\u{3C9}', '\u{3B9}'])),
+    ('\u{1FF4}', '\u{1FF4}', Mapping::Mapped(&['\u{3CE}', '\u{3B9}'])),
+    ('\u{1FF5}', '\u{1FF5}', Mapping::Disallowed),
+    ('\u{1FF6}', '\u{1FF6}', Mapping::Valid),
+    ('\u{1FF7}', '\u{1FF7}', Mapping::Mapped(&['\u{1FF6}', '\u{3B9}'])),
+    ('\u{1FF8}', '\u{1FF8}', Mapping::Mapped(&['\u{1F78}'])),
+    ('\u{1FF9}', '\u{1FF9}', Mapping::Mapped(&['\u{3CC}'])),
+    ('\u{1FFA}', '\u{1FFA}', Mapping::Mapped(&['\u{1F7C}'])),
+    ('\u{1FFB}', '\u{1FFB}', Mapping::Mapped(&['\u{3CE}'])),
+    ('\u{1FFC}', '\u{1FFC}', Mapping::Mapped(&['\u{3C9}', '\u{3B9}'])),
+    ('\u{1FFD}', '\u{1FFD}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{301}'])),
+    ('\u{1FFE}', '\u{1FFE}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{314}'])),
+    ('\u{1FFF}', '\u{1FFF}', Mapping::Disallowed),
+    ('\u{2000}', '\u{200A}', Mapping::DisallowedStd3Mapped(&['\u{20}'])),
+    ('\u{200B}', '\u{200B}', Mapping::Ignored),
+    ('\u{200C}', '\u{200D}', Mapping::Deviation(&[])),
+    ('\u{200E}', '\u{200F}', Mapping::Disallowed),
+    ('\u{2010}', '\u{2010}', Mapping::Valid),
+    ('\u{2011}', '\u{2011}', Mapping::Mapped(&['\u{2010}'])),
+    ('\u{2012}', '\u{2016}', Mapping::Valid),
+    ('\u{2017}', '\u{2017}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{333}'])),
+    ('\u{2018}', '\u{2023}', Mapping::Valid),
+    ('\u{2024}', '\u{2026}', Mapping::Disallowed),
+    ('\u{2027}', '\u{2027}', Mapping::Valid),
+    ('\u{2028}', '\u{202E}', Mapping::Disallowed),
+    ('\u{202F}', '\u{202F}', Mapping::DisallowedStd3Mapped(&['\u{20}'])),
+    ('\u{2030}', '\u{2032}', Mapping::Valid),
+    ('\u{2033}', '\u{2033}', Mapping::Mapped(&['\u{2032}', '\u{2032}'])),
+    ('\u{2034}', '\u{2034}', Mapping::Mapped(&['\u{2032}', '\u{2032}', '\u{2032}'])),
+    ('\u{2035}', '\u{2035}', Mapping::Valid),
+    ('\u{2036}', '\u{2036}', Mapping::Mapped(&['\u{2035}', '\u{2035}'])),
+    ('\u{2037}', '\u{2037}', Mapping::Mapped(&['\u{2035}', '\u{2035}', '\u{2035}'])),
+    ('\u{2038}', '\u{203B}', Mapping::Valid),
+    ('\u{203C}', '\u{203C}', Mapping::DisallowedStd3Mapped(&['\u{21}', '\u{21}'])),
+    ('\u{203D}', '\u{203D}', Mapping::Valid),
+    ('\u{203E}', '\u{203E}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{305}'])),
+    ('\u{203F}', '\u{2046}', Mapping::Valid),
+    ('\u{2047}', '\u{2047}', Mapping::DisallowedStd3Mapped(&['\u{3F}', '\u{3F}'])),
+    ('\u{2048}', '\u{2048}', Mapping::DisallowedStd3Mapped(&['\u{3F}', '\u{21}'])),
+    ('\u{2049}', '\u{2049}', Mapping::DisallowedStd3Mapped(&['\u{21}', '\u{3F}'])),
+    ('\u{204A}', '\u{2056}', Mapping::Valid),
+    ('\u{2057}', '\u{2057}', Mapping::Mapped(&['\u{2032}', '\u{2032}', '\u{2032}', '\u{2032}'])),
+    ('\u{2058}', '\u{205E}', Mapping::Valid),
+    ('\u{205F}', '\u{205F}', Mapping::DisallowedStd3Mapped(&['\u{20}'])),
+    ('\u{2060}', '\u{2060}', Mapping::Ignored),
+    ('\u{2061}', '\u{2063}', Mapping::Disallowed),
+    ('\u{2064}', '\u{2064}', Mapping::Ignored),
+    ('\u{2065}', '\u{206F}', Mapping::Disallowed),
+    ('\u{2070}', '\u{2070}', Mapping::Mapped(&['\u{30}'])),
+    ('\u{2071}', '\u{2071}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{2072}', '\u{2073}', Mapping::Disallowed),
+    ('\u{2074}', '\u{2074}', Mapping::Mapped(&['\u{34}'])),
+    ('\u{2075}', '\u{2075}', Mapping::Mapped(&['\u{35}'])),
+    ('\u{2076}', '\u{2076}', Mapping::Mapped(&['\u{36}'])),
+    ('\u{2077}', '\u{2077}', Mapping::Mapped(&['\u{37}'])),
+    ('\u{2078}', '\u{2078}', Mapping::Mapped(&['\u{38}'])),
+    ('\u{2079}', '\u{2079}', Mapping::Mapped(&['\u{39}'])),
+    ('\u{207A}', '\u{207A}', Mapping::DisallowedStd3Mapped(&['\u{2B}'])),
+    ('\u{207B}', '\u{207B}', Mapping::Mapped(&['\u{2212}'])),
+    ('\u{207C}', '\u{207C}', Mapping::DisallowedStd3Mapped(&['\u{3D}'])),
+    ('\u{207D}', '\u{207D}', Mapping::DisallowedStd3Mapped(&['\u{28}'])),
+    ('\u{207E}', '\u{207E}', Mapping::DisallowedStd3Mapped(&['\u{29}'])),
+    ('\u{207F}', '\u{207F}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{2080}', '\u{2080}', Mapping::Mapped(&['\u{30}'])),
+    ('\u{2081}', '\u{2081}', Mapping::Mapped(&['\u{31}'])),
+    ('\u{2082}', '\u{2082}', Mapping::Mapped(&['\u{32}'])),
+    ('\u{2083}', '\u{2083}', Mapping::Mapped(&['\u{33}'])),
+    ('\u{2084}', '\u{2084}', Mapping::Mapped(&['\u{34}'])),
+    ('\u{2085}', '\u{2085}', Mapping::Mapped(&['\u{35}'])),
+    ('\u{2086}', '\u{2086}', Mapping::Mapped(&['\u{36}'])),
+    ('\u{2087}', '\u{2087}', Mapping::Mapped(&['\u{37}'])),
+    ('\u{2088}', '\u{2088}', Mapping::Mapped(&['\u{38}'])),
+    ('\u{2089}', '\u{2089}', Mapping::Mapped(&['\u{39}'])),
+    ('\u{208A}', '\u{208A}', Mapping::DisallowedStd3Mapped(&['\u{2B}'])),
+    ('\u{208B}', '\u{208B}', Mapping::Mapped(&['\u{2212}'])),
+    ('\u{208C}', '\u{208C}', Mapping::DisallowedStd3Mapped(&['\u{3D}'])),
+    ('\u{208D}', '\u{208D}', Mapping::DisallowedStd3Mapped(&['\u{28}'])),
+    ('\u{208E}', '\u{208E}', Mapping::DisallowedStd3Mapped(&['\u{29}'])),
+    ('\u{208F}', '\u{208F}', Mapping::Disallowed),
+    ('\u{2090}', '\u{2090}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{2091}', '\u{2091}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{2092}', '\u{2092}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{2093}', '\u{2093}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{2094}', '\u{2094}', Mapping::Mapped(&['\u{259}'])),
+    ('\u{2095}', '\u{2095}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{2096}', '\u{2096}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{2097}', '\u{2097}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{2098}', '\u{2098}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{2099}', '\u{2099}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{209A}', '\u{209A}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{209B}', '\u{209B}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{209C}', '\u{209C}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{209D}', '\u{209F}', Mapping::Disallowed),
+    ('\u{20A0}', '\u{20A7}', Mapping::Valid),
+    ('\u{20A8}', '\u{20A8}', Mapping::Mapped(&['\u{72}', '\u{73}'])),
+    ('\u{20A9}', '\u{20BF}', Mapping::Valid),
+    ('\u{20C0}', '\u{20CF}', Mapping::Disallowed),
+    ('\u{20D0}', '\u{20F0}', Mapping::Valid),
+    ('\u{20F1}', '\u{20FF}', Mapping::Disallowed),
+    ('\u{2100}', '\u{2100}', Mapping::DisallowedStd3Mapped(&['\u{61}', '\u{2F}', '\u{63}'])),
+    ('\u{2101}', '\u{2101}', Mapping::DisallowedStd3Mapped(&['\u{61}', '\u{2F}', '\u{73}'])),
+    ('\u{2102}', '\u{2102}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{2103}', '\u{2103}', Mapping::Mapped(&['\u{B0}', '\u{63}'])),
+    ('\u{2104}', '\u{2104}', Mapping::Valid),
+    ('\u{2105}', '\u{2105}', Mapping::DisallowedStd3Mapped(&['\u{63}', '\u{2F}', '\u{6F}'])),
+    ('\u{2106}', '\u{2106}', Mapping::DisallowedStd3Mapped(&['\u{63}', '\u{2F}', '\u{75}'])),
+    ('\u{2107}', '\u{2107}', Mapping::Mapped(&['\u{25B}'])),
+    ('\u{2108}', '\u{2108}', Mapping::Valid),
+    ('\u{2109}', '\u{2109}', Mapping::Mapped(&['\u{B0}', '\u{66}'])),
+    ('\u{210A}', '\u{210A}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{210B}', '\u{210E}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{210F}', '\u{210F}', Mapping::Mapped(&['\u{127}'])),
+    ('\u{2110}', '\u{2111}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{2112}', '\u{2113}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{2114}', '\u{2114}', Mapping::Valid),
+    ('\u{2115}', '\u{2115}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{2116}', '\u{2116}', Mapping::Mapped(&['\u{6E}', '\u{6F}'])),
+    ('\u{2117}', '\u{2118}', Mapping::Valid),
+    ('\u{2119}', '\u{2119}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{211A}', '\u{211A}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{211B}', '\u{211D}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{211E}', '\u{211F}', Mapping::Valid),
+    ('\u{2120}', '\u{2120}', Mapping::Mapped(&['\u{73}', '\u{6D}'])),
+    ('\u{2121}', '\u{2121}', Mapping::Mapped(&['\u{74}', '\u{65}', '\u{6C}'])),
+    ('\u{2122}', '\u{2122}', Mapping::Mapped(&['\u{74}', '\u{6D}'])),
+    ('\u{2123}', '\u{2123}', Mapping::Valid),
+    ('\u{2124}', '\u{2124}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{2125}', '\u{2125}', Mapping::Valid),
+    ('\u{2126}', '\u{2126}', Mapping::Mapped(&['\u{3C9}'])),
+    ('\u{2127}', '\u{2127}', Mapping::Valid),
+    ('\u{2128}', '\u{2128}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{2129}', '\u{2129}', Mapping::Valid),
+    ('\u{212A}', '\u{212A}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{212B}', '\u{212B}', Mapping::Mapped(&['\u{E5}'])),
+    ('\u{212C}', '\u{212C}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{212D}', '\u{212D}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{212E}', '\u{212E}', Mapping::Valid),
+    ('\u{212F}', '\u{2130}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{2131}', '\u{2131}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{2132}', '\u{2132}', Mapping::Disallowed),
+    ('\u{2133}', '\u{2133}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{2134}', '\u{2134}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{2135}', '\u{2135}', Mapping::Mapped(&['\u{5D0}'])),
+    ('\u{2136}', '\u{2136}', Mapping::Mapped(&['\u{5D1}'])),
+    ('\u{2137}', '\u{2137}', Mapping::Mapped(&['\u{5D2}'])),
+    ('\u{2138}', '\u{2138}', Mapping::Mapped(&['\u{5D3}'])),
+    ('\u{2139}', '\u{2139}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{213A}', '\u{213A}', Mapping::Valid),
+    ('\u{213B}', '\u{213B}', Mapping::Mapped(&['\u{66}', '\u{61}', '\u{78}'])),
+    ('\u{213C}', '\u{213C}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{213D}', '\u{213E}', Mapping::Mapped(&['\u{3B3}'])),
+    ('\u{213F}', '\u{213F}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{2140}', '\u{2140}', Mapping::Mapped(&['\u{2211}'])),
+    ('\u{2141}', '\u{2144}', Mapping::Valid),
+    ('\u{2145}', '\u{2146}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{2147}', '\u{2147}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{2148}', '\u{2148}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{2149}', '\u{2149}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{214A}', '\u{214F}', Mapping::Valid),
+    ('\u{2150}', '\u{2150}', Mapping::Mapped(&['\u{31}', '\u{2044}', '\u{37}'])),
+    ('\u{2151}', '\u{2151}', Mapping::Mapped(&['\u{31}', '\u{2044}', '\u{39}'])),
+    ('\u{2152}', '\u{2152}', Mapping::Mapped(&['\u{31}', '\u{2044}', '\u{31}', '\u{30}'])),
+    ('\u{2153}', '\u{2153}', Mapping::Mapped(&['\u{31}', '\u{2044}', '\u{33}'])),
+    ('\u{2154}', '\u{2154}', Mapping::Mapped(&['\u{32}', '\u{2044}', '\u{33}'])),
+    ('\u{2155}', '\u{2155}', Mapping::Mapped(&['\u{31}', '\u{2044}', '\u{35}'])),
+    ('\u{2156}', '\u{2156}', Mapping::Mapped(&['\u{32}', '\u{2044}', '\u{35}'])),
+    ('\u{2157}', '\u{2157}', Mapping::Mapped(&['\u{33}', '\u{2044}', '\u{35}'])),
+    ('\u{2158}', '\u{2158}', Mapping::Mapped(&['\u{34}', '\u{2044}', '\u{35}'])),
+    ('\u{2159}', '\u{2159}', Mapping::Mapped(&['\u{31}', '\u{2044}', '\u{36}'])),
+    ('\u{215A}', '\u{215A}', Mapping::Mapped(&['\u{35}', '\u{2044}', '\u{36}'])),
+    ('\u{215B}', '\u{215B}', Mapping::Mapped(&['\u{31}', '\u{2044}', '\u{38}'])),
+    ('\u{215C}', '\u{215C}', Mapping::Mapped(&['\u{33}', '\u{2044}', '\u{38}'])),
+    ('\u{215D}', '\u{215D}', Mapping::Mapped(&['\u{35}', '\u{2044}', '\u{38}'])),
+    ('\u{215E}', '\u{215E}', Mapping::Mapped(&['\u{37}', '\u{2044}', '\u{38}'])),
+    ('\u{215F}', '\u{215F}', Mapping::Mapped(&['\u{31}', '\u{2044}'])),
+    ('\u{2160}', '\u{2160}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{2161}', '\u{2161}', Mapping::Mapped(&['\u{69}', '\u{69}'])),
+    ('\u{2162}', '\u{2162}', Mapping::Mapped(&['\u{69}', '\u{69}', '\u{69}'])),
+    ('\u{2163}', '\u{2163}', Mapping::Mapped(&['\u{69}', '\u{76}'])),
+    ('\u{2164}', '\u{2164}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{2165}', '\u{2165}', Mapping::Mapped(&['\u{76}', '\u{69}'])),
+    ('\u{2166}', '\u{2166}', Mapping::Mapped(&['\u{76}', '\u{69}', '\u{69}'])),
+    ('\u{2167}', '\u{2167}', Mapping::Mapped(&['\u{76}', '\u{69}', '\u{69}', '\u{69}'])),
+    ('\u{2168}', '\u{2168}', Mapping::Mapped(&['\u{69}', '\u{78}'])),
+    ('\u{2169}', '\u{2169}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{216A}', '\u{216A}', Mapping::Mapped(&['\u{78}', '\u{69}'])),
+    ('\u{216B}', '\u{216B}', Mapping::Mapped(&['\u{78}', '\u{69}', '\u{69}'])),
+    ('\u{216C}', '\u{216C}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{216D}', '\u{216D}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{216E}', '\u{216E}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{216F}', '\u{216F}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{2170}', '\u{2170}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{2171}', '\u{2171}', Mapping::Mapped(&['\u{69}', '\u{69}'])),
+    ('\u{2172}', '\u{2172}', Mapping::Mapped(&['\u{69}', '\u{69}', '\u{69}'])),
+    ('\u{2173}', '\u{2173}', Mapping::Mapped(&['\u{69}', '\u{76}'])),
+    ('\u{2174}', '\u{2174}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{2175}', '\u{2175}', Mapping::Mapped(&['\u{76}', '\u{69}'])),
+    ('\u{2176}', '\u{2176}', Mapping::Mapped(&['\u{76}', '\u{69}', '\u{69}'])),
+    ('\u{2177}', '\u{2177}', Mapping::Mapped(&['\u{76}', '\u{69}', '\u{69}', '\u{69}'])),
+    ('\u{2178}', '\u{2178}', Mapping::Mapped(&['\u{69}', '\u{78}'])),
+    ('\u{2179}', '\u{2179}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{217A}', '\u{217A}', Mapping::Mapped(&['\u{78}', '\u{69}'])),
+    ('\u{217B}', '\u{217B}', Mapping::Mapped(&['\u{78}', '\u{69}', '\u{69}'])),
+    ('\u{217C}', '\u{217C}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{217D}', '\u{217D}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{217E}', '\u{217E}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{217F}', '\u{217F}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{2180}', '\u{2182}', Mapping::Valid),
+    ('\u{2183}', '\u{2183}', Mapping::Disallowed),
+    ('\u{2184}', '\u{2188}', Mapping::Valid),
+    ('\u{2189}', '\u{2189}', Mapping::Mapped(&['\u{30}', '\u{2044}', '\u{33}'])),
+    ('\u{218A}', '\u{218B}', Mapping::Valid),
+    ('\u{218C}', '\u{218F}', Mapping::Disallowed),
+    ('\u{2190}', '\u{222B}', Mapping::Valid),
+    ('\u{222C}', '\u{222C}', Mapping::Mapped(&['\u{222B}', '\u{222B}'])),
+    ('\u{222D}', '\u{222D}', Mapping::Mapped(&['\u{222B}', '\u{222B}', '\u{222B}'])),
+    ('\u{222E}', '\u{222E}', Mapping::Valid),
+    ('\u{222F}', '\u{222F}', Mapping::Mapped(&['\u{222E}', '\u{222E}'])),
+    ('\u{2230}', '\u{2230}', Mapping::Mapped(&['\u{222E}', '\u{222E}', '\u{222E}'])),
+    ('\u{2231}', '\u{225F}', Mapping::Valid),
+    ('\u{2260}', '\u{2260}', Mapping::DisallowedStd3Valid),
+    ('\u{2261}', '\u{226D}', Mapping::Valid),
+    ('\u{226E}', '\u{226F}', Mapping::DisallowedStd3Valid),
+    ('\u{2270}', '\u{2328}', Mapping::Valid),
+    ('\u{2329}', '\u{2329}', Mapping::Mapped(&['\u{3008}'])),
+    ('\u{232A}', '\u{232A}', Mapping::Mapped(&['\u{3009}'])),
+    ('\u{232B}', '\u{2426}', Mapping::Valid),
+    ('\u{2427}', '\u{243F}', Mapping::Disallowed),
+    ('\u{2440}', '\u{244A}', Mapping::Valid),
+    ('\u{244B}', '\u{245F}', Mapping::Disallowed),
+    ('\u{2460}', '\u{2460}', Mapping::Mapped(&['\u{31}'])),
+    ('\u{2461}', '\u{2461}', Mapping::Mapped(&['\u{32}'])),
+    ('\u{2462}', '\u{2462}', Mapping::Mapped(&['\u{33}'])),
+    ('\u{2463}', '\u{2463}', Mapping::Mapped(&['\u{34}'])),
+    ('\u{2464}', '\u{2464}', Mapping::Mapped(&['\u{35}'])),
+    ('\u{2465}', '\u{2465}', Mapping::Mapped(&['\u{36}'])),
+    ('\u{2466}', '\u{2466}', Mapping::Mapped(&['\u{37}'])),
+    ('\u{2467}', '\u{2467}', Mapping::Mapped(&['\u{38}'])),
+    ('\u{2468}', '\u{2468}', Mapping::Mapped(&['\u{39}'])),
+    ('\u{2469}', '\u{2469}', Mapping::Mapped(&['\u{31}', '\u{30}'])),
+    ('\u{246A}', '\u{246A}', Mapping::Mapped(&['\u{31}', '\u{31}'])),
+    ('\u{246B}', '\u{246B}', Mapping::Mapped(&['\u{31}', '\u{32}'])),
+    ('\u{246C}', '\u{246C}', Mapping::Mapped(&['\u{31}', '\u{33}'])),
+    ('\u{246D}', '\u{246D}', Mapping::Mapped(&['\u{31}', '\u{34}'])),
+    ('\u{246E}', '\u{246E}', Mapping::Mapped(&['\u{31}', '\u{35}'])),
+    ('\u{246F}', '\u{246F}', Mapping::Mapped(&['\u{31}', '\u{36}'])),
+    ('\u{2470}', '\u{2470}', Mapping::Mapped(&['\u{31}', '\u{37}'])),
+    ('\u{2471}', '\u{2471}', Mapping::Mapped(&['\u{31}', '\u{38}'])),
+    ('\u{2472}', '\u{2472}', Mapping::Mapped(&['\u{31}', '\u{39}'])),
+    ('\u{2473}', '\u{2473}', Mapping::Mapped(&['\u{32}', '\u{30}'])),
+    ('\u{2474}', '\u{2474}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{31}', '\u{29}'])),
+    ('\u{2475}', '\u{2475}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{32}', '\u{29}'])),
+    ('\u{2476}', '\u{2476}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{33}', '\u{29}'])),
+    ('\u{2477}', '\u{2477}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{34}', '\u{29}'])),
+    ('\u{2478}', '\u{2478}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{35}', '\u{29}'])),
+    ('\u{2479}', '\u{2479}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{36}', '\u{29}'])),
+    ('\u{247A}', '\u{247A}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{37}', '\u{29}'])),
+    ('\u{247B}', '\u{247B}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{38}', '\u{29}'])),
+    ('\u{247C}', '\u{247C}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{39}', '\u{29}'])),
+    ('\u{247D}', '\u{247D}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{31}', '\u{30}', '\u{29}'])),
+    ('\u{247E}', '\u{247E}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{31}', '\u{31}', '\u{29}'])),
+    ('\u{247F}', '\u{247F}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{31}', '\u{32}', '\u{29}'])),
+    ('\u{2480}', '\u{2480}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{31}', '\u{33}', '\u{29}'])),
+    ('\u{2481}', '\u{2481}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{31}', '\u{34}', '\u{29}'])),
+    ('\u{2482}', '\u{2482}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{31}', '\u{35}', '\u{29}'])),
+    ('\u{2483}', '\u{2483}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{31}', '\u{36}', '\u{29}'])),
+    ('\u{2484}', '\u{2484}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{31}', '\u{37}', '\u{29}'])),
+    ('\u{2485}', '\u{2485}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{31}', '\u{38}', '\u{29}'])),
+    ('\u{2486}', '\u{2486}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{31}', '\u{39}', '\u{29}'])),
+    ('\u{2487}', '\u{2487}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{32}', '\u{30}', '\u{29}'])),
+    ('\u{2488}', '\u{249B}', Mapping::Disallowed),
+    ('\u{249C}', '\u{249C}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{61}', '\u{29}'])),
+    ('\u{249D}', '\u{249D}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{62}', '\u{29}'])),
+    ('\u{249E}', '\u{249E}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{63}', '\u{29}'])),
+    ('\u{249F}', '\u{249F}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{64}', '\u{29}'])),
+    ('\u{24A0}', '\u{24A0}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{65}', '\u{29}'])),
+    ('\u{24A1}', '\u{24A1}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{66}', '\u{29}'])),
+    ('\u{24A2}', '\u{24A2}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{67}', '\u{29}'])),
+    ('\u{24A3}', '\u{24A3}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{68}', '\u{29}'])),
+    ('\u{24A4}', '\u{24A4}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{69}', '\u{29}'])),
+    ('\u{24A5}', '\u{24A5}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6A}', '\u{29}'])),
+    ('\u{24A6}', '\u{24A6}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6B}', '\u{29}'])),
+    ('\u{24A7}', '\u{24A7}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6C}', '\u{29}'])),
+    ('\u{24A8}', '\u{24A8}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6D}', '\u{29}'])),
+    ('\u{24A9}', '\u{24A9}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6E}', '\u{29}'])),
+    ('\u{24AA}', '\u{24AA}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6F}', '\u{29}'])),
+    ('\u{24AB}', '\u{24AB}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{70}', '\u{29}'])),
+    ('\u{24AC}', '\u{24AC}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{71}', '\u{29}'])),
+    ('\u{24AD}', '\u{24AD}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{72}', '\u{29}'])),
+    ('\u{24AE}', '\u{24AE}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{73}', '\u{29}'])),
+    ('\u{24AF}', '\u{24AF}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{74}', '\u{29}'])),
+    ('\u{24B0}', '\u{24B0}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{75}', '\u{29}'])),
+    ('\u{24B1}', '\u{24B1}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{76}', '\u{29}'])),
+    ('\u{24B2}', '\u{24B2}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{77}', '\u{29}'])),
+    ('\u{24B3}', '\u{24B3}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{78}', '\u{29}'])),
+    ('\u{24B4}', '\u{24B4}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{79}', '\u{29}'])),
+    ('\u{24B5}', '\u{24B5}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{7A}', '\u{29}'])),
+    ('\u{24B6}', '\u{24B6}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{24B7}', '\u{24B7}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{24B8}', '\u{24B8}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{24B9}', '\u{24B9}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{24BA}', '\u{24BA}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{24BB}', '\u{24BB}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{24BC}', '\u{24BC}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{24BD}', '\u{24BD}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{24BE}', '\u{24BE}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{24BF}', '\u{24BF}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{24C0}', '\u{24C0}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{24C1}', '\u{24C1}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{24C2}', '\u{24C2}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{24C3}', '\u{24C3}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{24C4}', '\u{24C4}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{24C5}', '\u{24C5}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{24C6}', '\u{24C6}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{24C7}', '\u{24C7}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{24C8}', '\u{24C8}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{24C9}', '\u{24C9}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{24CA}', '\u{24CA}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{24CB}', '\u{24CB}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{24CC}', '\u{24CC}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{24CD}', '\u{24CD}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{24CE}', '\u{24CE}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{24CF}', '\u{24CF}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{24D0}', '\u{24D0}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{24D1}', '\u{24D1}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{24D2}', '\u{24D2}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{24D3}', '\u{24D3}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{24D4}', '\u{24D4}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{24D5}', '\u{24D5}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{24D6}', '\u{24D6}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{24D7}', '\u{24D7}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{24D8}', '\u{24D8}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{24D9}', '\u{24D9}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{24DA}', '\u{24DA}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{24DB}', '\u{24DB}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{24DC}', '\u{24DC}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{24DD}', '\u{24DD}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{24DE}', '\u{24DE}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{24DF}', '\u{24DF}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{24E0}', '\u{24E0}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{24E1}', '\u{24E1}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{24E2}', '\u{24E2}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{24E3}', '\u{24E3}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{24E4}', '\u{24E4}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{24E5}', '\u{24E5}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{24E6}', '\u{24E6}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{24E7}', '\u{24E7}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{24E8}', '\u{24E8}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{24E9}', '\u{24E9}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{24EA}', '\u{24EA}', Mapping::Mapped(&['\u{30}'])),
+    ('\u{24EB}', '\u{2A0B}', Mapping::Valid),
+    ('\u{2A0C}', '\u{2A0C}', Mapping::Mapped(&['\u{222B}', '\u{222B}', '\u{222B}', '\u{222B}'])),
+    ('\u{2A0D}', '\u{2A73}', Mapping::Valid),
+    ('\u{2A74}', '\u{2A74}', Mapping::DisallowedStd3Mapped(&['\u{3A}', '\u{3A}', '\u{3D}'])),
+    ('\u{2A75}', '\u{2A75}', Mapping::DisallowedStd3Mapped(&['\u{3D}', '\u{3D}'])),
+    ('\u{2A76}', '\u{2A76}', Mapping::DisallowedStd3Mapped(&['\u{3D}', '\u{3D}', '\u{3D}'])),
+    ('\u{2A77}', '\u{2ADB}', Mapping::Valid),
+    ('\u{2ADC}', '\u{2ADC}', Mapping::Mapped(&['\u{2ADD}', '\u{338}'])),
+    ('\u{2ADD}', '\u{2B73}', Mapping::Valid),
+    ('\u{2B74}', '\u{2B75}', Mapping::Disallowed),
+    ('\u{2B76}', '\u{2B95}', Mapping::Valid),
+    ('\u{2B96}', '\u{2B96}', Mapping::Disallowed),
+    ('\u{2B97}', '\u{2BFF}', Mapping::Valid),
+    ('\u{2C00}', '\u{2C00}', Mapping::Mapped(&['\u{2C30}'])),
+    ('\u{2C01}', '\u{2C01}', Mapping::Mapped(&['\u{2C31}'])),
+    ('\u{2C02}', '\u{2C02}', Mapping::Mapped(&['\u{2C32}'])),
+    ('\u{2C03}', '\u{2C03}', Mapping::Mapped(&['\u{2C33}'])),
+    ('\u{2C04}', '\u{2C04}', Mapping::Mapped(&['\u{2C34}'])),
+    ('\u{2C05}', '\u{2C05}', Mapping::Mapped(&['\u{2C35}'])),
+    ('\u{2C06}', '\u{2C06}', Mapping::Mapped(&['\u{2C36}'])),
+    ('\u{2C07}', '\u{2C07}', Mapping::Mapped(&['\u{2C37}'])),
+    ('\u{2C08}', '\u{2C08}', Mapping::Mapped(&['\u{2C38}'])),
+    ('\u{2C09}', '\u{2C09}', Mapping::Mapped(&['\u{2C39}'])),
+    ('\u{2C0A}', '\u{2C0A}', Mapping::Mapped(&['\u{2C3A}'])),
+    ('\u{2C0B}', '\u{2C0B}', Mapping::Mapped(&['\u{2C3B}'])),
+    ('\u{2C0C}', '\u{2C0C}', Mapping::Mapped(&['\u{2C3C}'])),
+    ('\u{2C0D}', '\u{2C0D}', Mapping::Mapped(&['\u{2C3D}'])),
+    ('\u{2C0E}', '\u{2C0E}', Mapping::Mapped(&['\u{2C3E}'])),
+    ('\u{2C0F}', '\u{2C0F}', Mapping::Mapped(&['\u{2C3F}'])),
+    ('\u{2C10}', '\u{2C10}', Mapping::Mapped(&['\u{2C40}'])),
+    ('\u{2C11}', '\u{2C11}', Mapping::Mapped(&['\u{2C41}'])),
+    ('\u{2C12}', '\u{2C12}', Mapping::Mapped(&['\u{2C42}'])),
+    ('\u{2C13}', '\u{2C13}', Mapping::Mapped(&['\u{2C43}'])),
+    ('\u{2C14}', '\u{2C14}', Mapping::Mapped(&['\u{2C44}'])),
+    ('\u{2C15}', '\u{2C15}', Mapping::Mapped(&['\u{2C45}'])),
+    ('\u{2C16}', '\u{2C16}', Mapping::Mapped(&['\u{2C46}'])),
+    ('\u{2C17}', '\u{2C17}', Mapping::Mapped(&['\u{2C47}'])),
+    ('\u{2C18}', '\u{2C18}', Mapping::Mapped(&['\u{2C48}'])),
+    ('\u{2C19}', '\u{2C19}', Mapping::Mapped(&['\u{2C49}'])),
+    ('\u{2C1A}', '\u{2C1A}', Mapping::Mapped(&['\u{2C4A}'])),
+    ('\u{2C1B}', '\u{2C1B}', Mapping::Mapped(&['\u{2C4B}'])),
+    ('\u{2C1C}', '\u{2C1C}', Mapping::Mapped(&['\u{2C4C}'])),
+    ('\u{2C1D}', '\u{2C1D}', Mapping::Mapped(&['\u{2C4D}'])),
+    ('\u{2C1E}', '\u{2C1E}', Mapping::Mapped(&['\u{2C4E}'])),
+    ('\u{2C1F}', '\u{2C1F}', Mapping::Mapped(&['\u{2C4F}'])),
+    ('\u{2C20}', '\u{2C20}', Mapping::Mapped(&['\u{2C50}'])),
+    ('\u{2C21}', '\u{2C21}', Mapping::Mapped(&['\u{2C51}'])),
+    ('\u{2C22}', '\u{2C22}', Mapping::Mapped(&['\u{2C52}'])),
+    ('\u{2C23}', '\u{2C23}', Mapping::Mapped(&['\u{2C53}'])),
+    ('\u{2C24}', '\u{2C24}', Mapping::Mapped(&['\u{2C54}'])),
+    ('\u{2C25}', '\u{2C25}', Mapping::Mapped(&['\u{2C55}'])),
+    ('\u{2C26}', '\u{2C26}', Mapping::Mapped(&['\u{2C56}'])),
+    ('\u{2C27}', '\u{2C27}', Mapping::Mapped(&['\u{2C57}'])),
+    ('\u{2C28}', '\u{2C28}', Mapping::Mapped(&['\u{2C58}'])),
+    ('\u{2C29}', '\u{2C29}', Mapping::Mapped(&['\u{2C59}'])),
+    ('\u{2C2A}', '\u{2C2A}', Mapping::Mapped(&['\u{2C5A}'])),
+    ('\u{2C2B}', '\u{2C2B}', Mapping::Mapped(&['\u{2C5B}'])),
+    ('\u{2C2C}', '\u{2C2C}', Mapping::Mapped(&['\u{2C5C}'])),
+    ('\u{2C2D}', '\u{2C2D}', Mapping::Mapped(&['\u{2C5D}'])),
+    ('\u{2C2E}', '\u{2C2E}', Mapping::Mapped(&['\u{2C5E}'])),
+    ('\u{2C2F}', '\u{2C2F}', Mapping::Disallowed),
+    ('\u{2C30}', '\u{2C5E}', Mapping::Valid),
+    ('\u{2C5F}', '\u{2C5F}', Mapping::Disallowed),
+    ('\u{2C60}', '\u{2C60}', Mapping::Mapped(&['\u{2C61}'])),
+    ('\u{2C61}', '\u{2C61}', Mapping::Valid),
+    ('\u{2C62}', '\u{2C62}', Mapping::Mapped(&['\u{26B}'])),
+    ('\u{2C63}', '\u{2C63}', Mapping::Mapped(&['\u{1D7D}'])),
+    ('\u{2C64}', '\u{2C64}', Mapping::Mapped(&['\u{27D}'])),
+    ('\u{2C65}', '\u{2C66}', Mapping::Valid),
+    ('\u{2C67}', '\u{2C67}', Mapping::Mapped(&['\u{2C68}'])),
+    ('\u{2C68}', '\u{2C68}', Mapping::Valid),
+    ('\u{2C69}', '\u{2C69}', Mapping::Mapped(&['\u{2C6A}'])),
+    ('\u{2C6A}', '\u{2C6A}', Mapping::Valid),
+    ('\u{2C6B}', '\u{2C6B}', Mapping::Mapped(&['\u{2C6C}'])),
+    ('\u{2C6C}', '\u{2C6C}', Mapping::Valid),
+    ('\u{2C6D}', '\u{2C6D}', Mapping::Mapped(&['\u{251}'])),
+    ('\u{2C6E}', '\u{2C6E}', Mapping::Mapped(&['\u{271}'])),
+    ('\u{2C6F}', '\u{2C6F}', Mapping::Mapped(&['\u{250}'])),
+    ('\u{2C70}', '\u{2C70}', Mapping::Mapped(&['\u{252}'])),
+    ('\u{2C71}', '\u{2C71}', Mapping::Valid),
+    ('\u{2C72}', '\u{2C72}', Mapping::Mapped(&['\u{2C73}'])),
+    ('\u{2C73}', '\u{2C74}', Mapping::Valid),
+    ('\u{2C75}', '\u{2C75}', Mapping::Mapped(&['\u{2C76}'])),
+    ('\u{2C76}', '\u{2C7B}', Mapping::Valid),
+    ('\u{2C7C}', '\u{2C7C}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{2C7D}', '\u{2C7D}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{2C7E}', '\u{2C7E}', Mapping::Mapped(&['\u{23F}'])),
+    ('\u{2C7F}', '\u{2C7F}', Mapping::Mapped(&['\u{240}'])),
+    ('\u{2C80}', '\u{2C80}', Mapping::Mapped(&['\u{2C81}'])),
+    ('\u{2C81}', '\u{2C81}', Mapping::Valid),
+    ('\u{2C82}', '\u{2C82}', Mapping::Mapped(&['\u{2C83}'])),
+    ('\u{2C83}', '\u{2C83}', Mapping::Valid),
+    ('\u{2C84}', '\u{2C84}', Mapping::Mapped(&['\u{2C85}'])),
+    ('\u{2C85}', '\u{2C85}', Mapping::Valid),
+    ('\u{2C86}', '\u{2C86}', Mapping::Mapped(&['\u{2C87}'])),
+    ('\u{2C87}', '\u{2C87}', Mapping::Valid),
+    ('\u{2C88}', '\u{2C88}', Mapping::Mapped(&['\u{2C89}'])),
+    ('\u{2C89}', '\u{2C89}', Mapping::Valid),
+    ('\u{2C8A}', '\u{2C8A}', Mapping::Mapped(&['\u{2C8B}'])),
+    ('\u{2C8B}', '\u{2C8B}', Mapping::Valid),
+    ('\u{2C8C}', '\u{2C8C}', Mapping::Mapped(&['\u{2C8D}'])),
+    ('\u{2C8D}', '\u{2C8D}', Mapping::Valid),
+    ('\u{2C8E}', '\u{2C8E}', Mapping::Mapped(&['\u{2C8F}'])),
+    ('\u{2C8F}', '\u{2C8F}', Mapping::Valid),
+    ('\u{2C90}', '\u{2C90}', Mapping::Mapped(&['\u{2C91}'])),
+    ('\u{2C91}', '\u{2C91}', Mapping::Valid),
+    ('\u{2C92}', '\u{2C92}', Mapping::Mapped(&['\u{2C93}'])),
+    ('\u{2C93}', '\u{2C93}', Mapping::Valid),
+    ('\u{2C94}', '\u{2C94}', Mapping::Mapped(&['\u{2C95}'])),
+    ('\u{2C95}', '\u{2C95}', Mapping::Valid),
+    ('\u{2C96}', '\u{2C96}', Mapping::Mapped(&['\u{2C97}'])),
+    ('\u{2C97}', '\u{2C97}', Mapping::Valid),
+    ('\u{2C98}', '\u{2C98}', Mapping::Mapped(&['\u{2C99}'])),
+    ('\u{2C99}', '\u{2C99}', Mapping::Valid),
+    ('\u{2C9A}', '\u{2C9A}', Mapping::Mapped(&['\u{2C9B}'])),
+    ('\u{2C9B}', '\u{2C9B}', Mapping::Valid),
+    ('\u{2C9C}', '\u{2C9C}', Mapping::Mapped(&['\u{2C9D}'])),
+    ('\u{2C9D}', '\u{2C9D}', Mapping::Valid),
+    ('\u{2C9E}', '\u{2C9E}', Mapping::Mapped(&['\u{2C9F}'])),
+    ('\u{2C9F}', '\u{2C9F}', Mapping::Valid),
+    ('\u{2CA0}', '\u{2CA0}', Mapping::Mapped(&['\u{2CA1}'])),
+    ('\u{2CA1}', '\u{2CA1}', Mapping::Valid),
+    ('\u{2CA2}', '\u{2CA2}', Mapping::Mapped(&['\u{2CA3}'])),
+    ('\u{2CA3}', '\u{2CA3}', Mapping::Valid),
+    ('\u{2CA4}', '\u{2CA4}', Mapping::Mapped(&['\u{2CA5}'])),
+    ('\u{2CA5}', '\u{2CA5}', Mapping::Valid),
+    ('\u{2CA6}', '\u{2CA6}', Mapping::Mapped(&['\u{2CA7}'])),
+    ('\u{2CA7}', '\u{2CA7}', Mapping::Valid),
+    ('\u{2CA8}', '\u{2CA8}', Mapping::Mapped(&['\u{2CA9}'])),
+    ('\u{2CA9}', '\u{2CA9}', Mapping::Valid),
+    ('\u{2CAA}', '\u{2CAA}', Mapping::Mapped(&['\u{2CAB}'])),
+    ('\u{2CAB}', '\u{2CAB}', Mapping::Valid),
+    ('\u{2CAC}', '\u{2CAC}', Mapping::Mapped(&['\u{2CAD}'])),
+    ('\u{2CAD}', '\u{2CAD}', Mapping::Valid),
+    ('\u{2CAE}', '\u{2CAE}', Mapping::Mapped(&['\u{2CAF}'])),
+    ('\u{2CAF}', '\u{2CAF}', Mapping::Valid),
+    ('\u{2CB0}', '\u{2CB0}', Mapping::Mapped(&['\u{2CB1}'])),
+    ('\u{2CB1}', '\u{2CB1}', Mapping::Valid),
+    ('\u{2CB2}', '\u{2CB2}', Mapping::Mapped(&['\u{2CB3}'])),
+    ('\u{2CB3}', '\u{2CB3}', Mapping::Valid),
+    ('\u{2CB4}', '\u{2CB4}', Mapping::Mapped(&['\u{2CB5}'])),
+    ('\u{2CB5}', '\u{2CB5}', Mapping::Valid),
+    ('\u{2CB6}', '\u{2CB6}', Mapping::Mapped(&['\u{2CB7}'])),
+    ('\u{2CB7}', '\u{2CB7}', Mapping::Valid),
+    ('\u{2CB8}', '\u{2CB8}', Mapping::Mapped(&['\u{2CB9}'])),
+    ('\u{2CB9}', '\u{2CB9}', Mapping::Valid),
+    ('\u{2CBA}', '\u{2CBA}', Mapping::Mapped(&['\u{2CBB}'])),
+    ('\u{2CBB}', '\u{2CBB}', Mapping::Valid),
+    ('\u{2CBC}', '\u{2CBC}', Mapping::Mapped(&['\u{2CBD}'])),
+    ('\u{2CBD}', '\u{2CBD}', Mapping::Valid),
+    ('\u{2CBE}', '\u{2CBE}', Mapping::Mapped(&['\u{2CBF}'])),
+    ('\u{2CBF}', '\u{2CBF}', Mapping::Valid),
+    ('\u{2CC0}', '\u{2CC0}', Mapping::Mapped(&['\u{2CC1}'])),
+    ('\u{2CC1}', '\u{2CC1}', Mapping::Valid),
+    ('\u{2CC2}', '\u{2CC2}', Mapping::Mapped(&['\u{2CC3}'])),
+    ('\u{2CC3}', '\u{2CC3}', Mapping::Valid),
+    ('\u{2CC4}', '\u{2CC4}', Mapping::Mapped(&['\u{2CC5}'])),
+    ('\u{2CC5}', '\u{2CC5}', Mapping::Valid),
+    ('\u{2CC6}', '\u{2CC6}', Mapping::Mapped(&['\u{2CC7}'])),
+    ('\u{2CC7}', '\u{2CC7}', Mapping::Valid),
+    ('\u{2CC8}', '\u{2CC8}', Mapping::Mapped(&['\u{2CC9}'])),
+    ('\u{2CC9}', '\u{2CC9}', Mapping::Valid),
+    ('\u{2CCA}', '\u{2CCA}', Mapping::Mapped(&['\u{2CCB}'])),
+    ('\u{2CCB}', '\u{2CCB}', Mapping::Valid),
+    ('\u{2CCC}', '\u{2CCC}', Mapping::Mapped(&['\u{2CCD}'])),
+    ('\u{2CCD}', '\u{2CCD}', Mapping::Valid),
+    ('\u{2CCE}', '\u{2CCE}', Mapping::Mapped(&['\u{2CCF}'])),
+    ('\u{2CCF}', '\u{2CCF}', Mapping::Valid),
+    ('\u{2CD0}', '\u{2CD0}', Mapping::Mapped(&['\u{2CD1}'])),
+    ('\u{2CD1}', '\u{2CD1}', Mapping::Valid),
+    ('\u{2CD2}', '\u{2CD2}', Mapping::Mapped(&['\u{2CD3}'])),
+    ('\u{2CD3}', '\u{2CD3}', Mapping::Valid),
+    ('\u{2CD4}', '\u{2CD4}', Mapping::Mapped(&['\u{2CD5}'])),
+    ('\u{2CD5}', '\u{2CD5}', Mapping::Valid),
+    ('\u{2CD6}', '\u{2CD6}', Mapping::Mapped(&['\u{2CD7}'])),
+    ('\u{2CD7}', '\u{2CD7}', Mapping::Valid),
+    ('\u{2CD8}', '\u{2CD8}', Mapping::Mapped(&['\u{2CD9}'])),
+    ('\u{2CD9}', '\u{2CD9}', Mapping::Valid),
+    ('\u{2CDA}', '\u{2CDA}', Mapping::Mapped(&['\u{2CDB}'])),
+    ('\u{2CDB}', '\u{2CDB}', Mapping::Valid),
+    ('\u{2CDC}', '\u{2CDC}', Mapping::Mapped(&['\u{2CDD}'])),
+    ('\u{2CDD}', '\u{2CDD}', Mapping::Valid),
+    ('\u{2CDE}', '\u{2CDE}', Mapping::Mapped(&['\u{2CDF}'])),
+    ('\u{2CDF}', '\u{2CDF}', Mapping::Valid),
+    ('\u{2CE0}', '\u{2CE0}', Mapping::Mapped(&['\u{2CE1}'])),
+    ('\u{2CE1}', '\u{2CE1}', Mapping::Valid),
+    ('\u{2CE2}', '\u{2CE2}', Mapping::Mapped(&['\u{2CE3}'])),
+    ('\u{2CE3}', '\u{2CEA}', Mapping::Valid),
+    ('\u{2CEB}', '\u{2CEB}', Mapping::Mapped(&['\u{2CEC}'])),
+    ('\u{2CEC}', '\u{2CEC}', Mapping::Valid),
+    ('\u{2CED}', '\u{2CED}', Mapping::Mapped(&['\u{2CEE}'])),
+    ('\u{2CEE}', '\u{2CF1}', Mapping::Valid),
+    ('\u{2CF2}', '\u{2CF2}', Mapping::Mapped(&['\u{2CF3}'])),
+    ('\u{2CF3}', '\u{2CF3}', Mapping::Valid),
+    ('\u{2CF4}', '\u{2CF8}', Mapping::Disallowed),
+    ('\u{2CF9}', '\u{2D25}', Mapping::Valid),
+    ('\u{2D26}', '\u{2D26}', Mapping::Disallowed),
+    ('\u{2D27}', '\u{2D27}', Mapping::Valid),
+    ('\u{2D28}', '\u{2D2C}', Mapping::Disallowed),
+    ('\u{2D2D}', '\u{2D2D}', Mapping::Valid),
+    ('\u{2D2E}', '\u{2D2F}', Mapping::Disallowed),
+    ('\u{2D30}', '\u{2D67}', Mapping::Valid),
+    ('\u{2D68}', '\u{2D6E}', Mapping::Disallowed),
+    ('\u{2D6F}', '\u{2D6F}', Mapping::Mapped(&['\u{2D61}'])),
+    ('\u{2D70}', '\u{2D70}', Mapping::Valid),
+    ('\u{2D71}', '\u{2D7E}', Mapping::Disallowed),
+    ('\u{2D7F}', '\u{2D96}', Mapping::Valid),
+    ('\u{2D97}', '\u{2D9F}', Mapping::Disallowed),
+    ('\u{2DA0}', '\u{2DA6}', Mapping::Valid),
+    ('\u{2DA7}', '\u{2DA7}', Mapping::Disallowed),
+    ('\u{2DA8}', '\u{2DAE}', Mapping::Valid),
+    ('\u{2DAF}', '\u{2DAF}', Mapping::Disallowed),
+    ('\u{2DB0}', '\u{2DB6}', Mapping::Valid),
+    ('\u{2DB7}', '\u{2DB7}', Mapping::Disallowed),
+    ('\u{2DB8}', '\u{2DBE}', Mapping::Valid),
+    ('\u{2DBF}', '\u{2DBF}', Mapping::Disallowed),
+    ('\u{2DC0}', '\u{2DC6}', Mapping::Valid),
+    ('\u{2DC7}', '\u{2DC7}', Mapping::Disallowed),
+    ('\u{2DC8}', '\u{2DCE}', Mapping::Valid),
+    ('\u{2DCF}', '\u{2DCF}', Mapping::Disallowed),
+    ('\u{2DD0}', '\u{2DD6}', Mapping::Valid),
+    ('\u{2DD7}', '\u{2DD7}', Mapping::Disallowed),
+    ('\u{2DD8}', '\u{2DDE}', Mapping::Valid),
+    ('\u{2DDF}', '\u{2DDF}', Mapping::Disallowed),
+    ('\u{2DE0}', '\u{2E52}', Mapping::Valid),
+    ('\u{2E53}', '\u{2E7F}', Mapping::Disallowed),
+    ('\u{2E80}', '\u{2E99}', Mapping::Valid),
+    ('\u{2E9A}', '\u{2E9A}', Mapping::Disallowed),
+    ('\u{2E9B}', '\u{2E9E}', Mapping::Valid),
+    ('\u{2E9F}', '\u{2E9F}', Mapping::Mapped(&['\u{6BCD}'])),
+    ('\u{2EA0}', '\u{2EF2}', Mapping::Valid),
+    ('\u{2EF3}', '\u{2EF3}', Mapping::Mapped(&['\u{9F9F}'])),
+    ('\u{2EF4}', '\u{2EFF}', Mapping::Disallowed),
+    ('\u{2F00}', '\u{2F00}', Mapping::Mapped(&['\u{4E00}'])),
+    ('\u{2F01}', '\u{2F01}', Mapping::Mapped(&['\u{4E28}'])),
+    ('\u{2F02}', '\u{2F02}', Mapping::Mapped(&['\u{4E36}'])),
+    ('\u{2F03}', '\u{2F03}', Mapping::Mapped(&['\u{4E3F}'])),
+    ('\u{2F04}', '\u{2F04}', Mapping::Mapped(&['\u{4E59}'])),
+    ('\u{2F05}', '\u{2F05}', Mapping::Mapped(&['\u{4E85}'])),
+    ('\u{2F06}', '\u{2F06}', Mapping::Mapped(&['\u{4E8C}'])),
+    ('\u{2F07}', '\u{2F07}', Mapping::Mapped(&['\u{4EA0}'])),
+    ('\u{2F08}', '\u{2F08}', Mapping::Mapped(&['\u{4EBA}'])),
+    ('\u{2F09}', '\u{2F09}', Mapping::Mapped(&['\u{513F}'])),
+    ('\u{2F0A}', '\u{2F0A}', Mapping::Mapped(&['\u{5165}'])),
+    ('\u{2F0B}', '\u{2F0B}', Mapping::Mapped(&['\u{516B}'])),
+    ('\u{2F0C}', '\u{2F0C}', Mapping::Mapped(&['\u{5182}'])),
+    ('\u{2F0D}', '\u{2F0D}', Mapping::Mapped(&['\u{5196}'])),
+    ('\u{2F0E}', '\u{2F0E}', Mapping::Mapped(&['\u{51AB}'])),
+    ('\u{2F0F}', '\u{2F0F}', Mapping::Mapped(&['\u{51E0}'])),
+    ('\u{2F10}', '\u{2F10}', Mapping::Mapped(&['\u{51F5}'])),
+    ('\u{2F11}', '\u{2F11}', Mapping::Mapped(&['\u{5200}'])),
+    ('\u{2F12}', '\u{2F12}', Mapping::Mapped(&['\u{529B}'])),
+    ('\u{2F13}', '\u{2F13}', Mapping::Mapped(&['\u{52F9}'])),
+    ('\u{2F14}', '\u{2F14}', Mapping::Mapped(&['\u{5315}'])),
+    ('\u{2F15}', '\u{2F15}', Mapping::Mapped(&['\u{531A}'])),
+    ('\u{2F16}', '\u{2F16}', Mapping::Mapped(&['\u{5338}'])),
+    ('\u{2F17}', '\u{2F17}', Mapping::Mapped(&['\u{5341}'])),
+    ('\u{2F18}', '\u{2F18}', Mapping::Mapped(&['\u{535C}'])),
+    ('\u{2F19}', '\u{2F19}', Mapping::Mapped(&['\u{5369}'])),
+    ('\u{2F1A}', '\u{2F1A}', Mapping::Mapped(&['\u{5382}'])),
+    ('\u{2F1B}', '\u{2F1B}', Mapping::Mapped(&['\u{53B6}'])),
+    ('\u{2F1C}', '\u{2F1C}', Mapping::Mapped(&['\u{53C8}'])),
+    ('\u{2F1D}', '\u{2F1D}', Mapping::Mapped(&['\u{53E3}'])),
+    ('\u{2F1E}', '\u{2F1E}', Mapping::Mapped(&['\u{56D7}'])),
+    ('\u{2F1F}', '\u{2F1F}', Mapping::Mapped(&['\u{571F}'])),
+    ('\u{2F20}', '\u{2F20}', Mapping::Mapped(&['\u{58EB}'])),
+    ('\u{2F21}', '\u{2F21}', Mapping::Mapped(&['\u{5902}'])),
+    ('\u{2F22}', '\u{2F22}', Mapping::Mapped(&['\u{590A}'])),
+    ('\u{2F23}', '\u{2F23}', Mapping::Mapped(&['\u{5915}'])),
+    ('\u{2F24}', '\u{2F24}', Mapping::Mapped(&['\u{5927}'])),
+    ('\u{2F25}', '\u{2F25}', Mapping::Mapped(&['\u{5973}'])),
+    ('\u{2F26}', '\u{2F26}', Mapping::Mapped(&['\u{5B50}'])),
+    ('\u{2F27}', '\u{2F27}', Mapping::Mapped(&['\u{5B80}'])),
+    ('\u{2F28}', '\u{2F28}', Mapping::Mapped(&['\u{5BF8}'])),
+    ('\u{2F29}', '\u{2F29}', Mapping::Mapped(&['\u{5C0F}'])),
+    ('\u{2F2A}', '\u{2F2A}', Mapping::Mapped(&['\u{5C22}'])),
+    ('\u{2F2B}', '\u{2F2B}', Mapping::Mapped(&['\u{5C38}'])),
+    ('\u{2F2C}', '\u{2F2C}', Mapping::Mapped(&['\u{5C6E}'])),
+    ('\u{2F2D}', '\u{2F2D}', Mapping::Mapped(&['\u{5C71}'])),
+    ('\u{2F2E}', '\u{2F2E}', Mapping::Mapped(&['\u{5DDB}'])),
+    ('\u{2F2F}', '\u{2F2F}', Mapping::Mapped(&['\u{5DE5}'])),
+    ('\u{2F30}', '\u{2F30}', Mapping::Mapped(&['\u{5DF1}'])),
+    ('\u{2F31}', '\u{2F31}', Mapping::Mapped(&['\u{5DFE}'])),
+    ('\u{2F32}', '\u{2F32}', Mapping::Mapped(&['\u{5E72}'])),
+    ('\u{2F33}', '\u{2F33}', Mapping::Mapped(&['\u{5E7A}'])),
+    ('\u{2F34}', '\u{2F34}', Mapping::Mapped(&['\u{5E7F}'])),
+    ('\u{2F35}', '\u{2F35}', Mapping::Mapped(&['\u{5EF4}'])),
+    ('\u{2F36}', '\u{2F36}', Mapping::Mapped(&['\u{5EFE}'])),
+    ('\u{2F37}', '\u{2F37}', Mapping::Mapped(&['\u{5F0B}'])),
+    ('\u{2F38}', '\u{2F38}', Mapping::Mapped(&['\u{5F13}'])),
+    ('\u{2F39}', '\u{2F39}', Mapping::Mapped(&['\u{5F50}'])),
+    ('\u{2F3A}', '\u{2F3A}', Mapping::Mapped(&['\u{5F61}'])),
+    ('\u{2F3B}', '\u{2F3B}', Mapping::Mapped(&['\u{5F73}'])),
+    ('\u{2F3C}', '\u{2F3C}', Mapping::Mapped(&['\u{5FC3}'])),
+    ('\u{2F3D}', '\u{2F3D}', Mapping::Mapped(&['\u{6208}'])),
+    ('\u{2F3E}', '\u{2F3E}', Mapping::Mapped(&['\u{6236}'])),
+    ('\u{2F3F}', '\u{2F3F}', Mapping::Mapped(&['\u{624B}'])),
+    ('\u{2F40}', '\u{2F40}', Mapping::Mapped(&['\u{652F}'])),
+    ('\u{2F41}', '\u{2F41}', Mapping::Mapped(&['\u{6534}'])),
+    ('\u{2F42}', '\u{2F42}', Mapping::Mapped(&['\u{6587}'])),
+    ('\u{2F43}', '\u{2F43}', Mapping::Mapped(&['\u{6597}'])),
+    ('\u{2F44}', '\u{2F44}', Mapping::Mapped(&['\u{65A4}'])),
+    ('\u{2F45}', '\u{2F45}', Mapping::Mapped(&['\u{65B9}'])),
+    ('\u{2F46}', '\u{2F46}', Mapping::Mapped(&['\u{65E0}'])),
+    ('\u{2F47}', '\u{2F47}', Mapping::Mapped(&['\u{65E5}'])),
+    ('\u{2F48}', '\u{2F48}', Mapping::Mapped(&['\u{66F0}'])),
+    ('\u{2F49}', '\u{2F49}', Mapping::Mapped(&['\u{6708}'])),
+    ('\u{2F4A}', '\u{2F4A}', Mapping::Mapped(&['\u{6728}'])),
+    ('\u{2F4B}', '\u{2F4B}', Mapping::Mapped(&['\u{6B20}'])),
+    ('\u{2F4C}', '\u{2F4C}', Mapping::Mapped(&['\u{6B62}'])),
+    ('\u{2F4D}', '\u{2F4D}', Mapping::Mapped(&['\u{6B79}'])),
+    ('\u{2F4E}', '\u{2F4E}', Mapping::Mapped(&['\u{6BB3}'])),
+    ('\u{2F4F}', '\u{2F4F}', Mapping::Mapped(&['\u{6BCB}'])),
+    ('\u{2F50}', '\u{2F50}', Mapping::Mapped(&['\u{6BD4}'])),
+    ('\u{2F51}', '\u{2F51}', Mapping::Mapped(&['\u{6BDB}'])),
+    ('\u{2F52}', '\u{2F52}', Mapping::Mapped(&['\u{6C0F}'])),
+    ('\u{2F53}', '\u{2F53}', Mapping::Mapped(&['\u{6C14}'])),
+    ('\u{2F54}', '\u{2F54}', Mapping::Mapped(&['\u{6C34}'])),
+    ('\u{2F55}', '\u{2F55}', Mapping::Mapped(&['\u{706B}'])),
+    ('\u{2F56}', '\u{2F56}', Mapping::Mapped(&['\u{722A}'])),
+    ('\u{2F57}', '\u{2F57}', Mapping::Mapped(&['\u{7236}'])),
+    ('\u{2F58}', '\u{2F58}', Mapping::Mapped(&['\u{723B}'])),
+    ('\u{2F59}', '\u{2F59}', Mapping::Mapped(&['\u{723F}'])),
+    ('\u{2F5A}', '\u{2F5A}', Mapping::Mapped(&['\u{7247}'])),
+    ('\u{2F5B}', '\u{2F5B}', Mapping::Mapped(&['\u{7259}'])),
+    ('\u{2F5C}', '\u{2F5C}', Mapping::Mapped(&['\u{725B}'])),
+    ('\u{2F5D}', '\u{2F5D}', Mapping::Mapped(&['\u{72AC}'])),
+    ('\u{2F5E}', '\u{2F5E}', Mapping::Mapped(&['\u{7384}'])),
+    ('\u{2F5F}', '\u{2F5F}', Mapping::Mapped(&['\u{7389}'])),
+    ('\u{2F60}', '\u{2F60}', Mapping::Mapped(&['\u{74DC}'])),
+    ('\u{2F61}', '\u{2F61}', Mapping::Mapped(&['\u{74E6}'])),
+    ('\u{2F62}', '\u{2F62}', Mapping::Mapped(&['\u{7518}'])),
+    ('\u{2F63}', '\u{2F63}', Mapping::Mapped(&['\u{751F}'])),
+    ('\u{2F64}', '\u{2F64}', Mapping::Mapped(&['\u{7528}'])),
+    ('\u{2F65}', '\u{2F65}', Mapping::Mapped(&['\u{7530}'])),
+    ('\u{2F66}', '\u{2F66}', Mapping::Mapped(&['\u{758B}'])),
+    ('\u{2F67}', '\u{2F67}', Mapping::Mapped(&['\u{7592}'])),
+    ('\u{2F68}', '\u{2F68}', Mapping::Mapped(&['\u{7676}'])),
+    ('\u{2F69}', '\u{2F69}', Mapping::Mapped(&['\u{767D}'])),
+    ('\u{2F6A}', '\u{2F6A}', Mapping::Mapped(&['\u{76AE}'])),
+    ('\u{2F6B}', '\u{2F6B}', Mapping::Mapped(&['\u{76BF}'])),
+    ('\u{2F6C}', '\u{2F6C}', Mapping::Mapped(&['\u{76EE}'])),
+    ('\u{2F6D}', '\u{2F6D}', Mapping::Mapped(&['\u{77DB}'])),
+    ('\u{2F6E}', '\u{2F6E}', Mapping::Mapped(&['\u{77E2}'])),
+    ('\u{2F6F}', '\u{2F6F}', Mapping::Mapped(&['\u{77F3}'])),
+    ('\u{2F70}', '\u{2F70}', Mapping::Mapped(&['\u{793A}'])),
+    ('\u{2F71}', '\u{2F71}', Mapping::Mapped(&['\u{79B8}'])),
+    ('\u{2F72}', '\u{2F72}', Mapping::Mapped(&['\u{79BE}'])),
+    ('\u{2F73}', '\u{2F73}', Mapping::Mapped(&['\u{7A74}'])),
+    ('\u{2F74}', '\u{2F74}', Mapping::Mapped(&['\u{7ACB}'])),
+    ('\u{2F75}', '\u{2F75}', Mapping::Mapped(&['\u{7AF9}'])),
+    ('\u{2F76}', '\u{2F76}', Mapping::Mapped(&['\u{7C73}'])),
+    ('\u{2F77}', '\u{2F77}', Mapping::Mapped(&['\u{7CF8}'])),
+    ('\u{2F78}', '\u{2F78}', Mapping::Mapped(&['\u{7F36}'])),
+    ('\u{2F79}', '\u{2F79}', Mapping::Mapped(&['\u{7F51}'])),
+    ('\u{2F7A}', '\u{2F7A}', Mapping::Mapped(&['\u{7F8A}'])),
+    ('\u{2F7B}', '\u{2F7B}', Mapping::Mapped(&['\u{7FBD}'])),
+    ('\u{2F7C}', '\u{2F7C}', Mapping::Mapped(&['\u{8001}'])),
+    ('\u{2F7D}', '\u{2F7D}', Mapping::Mapped(&['\u{800C}'])),
+    ('\u{2F7E}', '\u{2F7E}', Mapping::Mapped(&['\u{8012}'])),
+    ('\u{2F7F}', '\u{2F7F}', Mapping::Mapped(&['\u{8033}'])),
+    ('\u{2F80}', '\u{2F80}', Mapping::Mapped(&['\u{807F}'])),
+    ('\u{2F81}', '\u{2F81}', Mapping::Mapped(&['\u{8089}'])),
+    ('\u{2F82}', '\u{2F82}', Mapping::Mapped(&['\u{81E3}'])),
+    ('\u{2F83}', '\u{2F83}', Mapping::Mapped(&['\u{81EA}'])),
+    ('\u{2F84}', '\u{2F84}', Mapping::Mapped(&['\u{81F3}'])),
+    ('\u{2F85}', '\u{2F85}', Mapping::Mapped(&['\u{81FC}'])),
+    ('\u{2F86}', '\u{2F86}', Mapping::Mapped(&['\u{820C}'])),
+    ('\u{2F87}', '\u{2F87}', Mapping::Mapped(&['\u{821B}'])),
+    ('\u{2F88}', '\u{2F88}', Mapping::Mapped(&['\u{821F}'])),
+    ('\u{2F89}', '\u{2F89}', Mapping::Mapped(&['\u{826E}'])),
+    ('\u{2F8A}', '\u{2F8A}', Mapping::Mapped(&['\u{8272}'])),
+    ('\u{2F8B}', '\u{2F8B}', Mapping::Mapped(&['\u{8278}'])),
+    ('\u{2F8C}', '\u{2F8C}', Mapping::Mapped(&['\u{864D}'])),
+    ('\u{2F8D}', '\u{2F8D}', Mapping::Mapped(&['\u{866B}'])),
+    ('\u{2F8E}', '\u{2F8E}', Mapping::Mapped(&['\u{8840}'])),
+    ('\u{2F8F}', '\u{2F8F}', Mapping::Mapped(&['\u{884C}'])),
+    ('\u{2F90}', '\u{2F90}', Mapping::Mapped(&['\u{8863}'])),
+    ('\u{2F91}', '\u{2F91}', Mapping::Mapped(&['\u{897E}'])),
+    ('\u{2F92}', '\u{2F92}', Mapping::Mapped(&['\u{898B}'])),
+    ('\u{2F93}', '\u{2F93}', Mapping::Mapped(&['\u{89D2}'])),
+    ('\u{2F94}', '\u{2F94}', Mapping::Mapped(&['\u{8A00}'])),
+    ('\u{2F95}', '\u{2F95}', Mapping::Mapped(&['\u{8C37}'])),
+    ('\u{2F96}', '\u{2F96}', Mapping::Mapped(&['\u{8C46}'])),
+    ('\u{2F97}', '\u{2F97}', Mapping::Mapped(&['\u{8C55}'])),
+    ('\u{2F98}', '\u{2F98}', Mapping::Mapped(&['\u{8C78}'])),
+    ('\u{2F99}', '\u{2F99}', Mapping::Mapped(&['\u{8C9D}'])),
+    ('\u{2F9A}', '\u{2F9A}', Mapping::Mapped(&['\u{8D64}'])),
+    ('\u{2F9B}', '\u{2F9B}', Mapping::Mapped(&['\u{8D70}'])),
+    ('\u{2F9C}', '\u{2F9C}', Mapping::Mapped(&['\u{8DB3}'])),
+    ('\u{2F9D}', '\u{2F9D}', Mapping::Mapped(&['\u{8EAB}'])),
+    ('\u{2F9E}', '\u{2F9E}', Mapping::Mapped(&['\u{8ECA}'])),
+    ('\u{2F9F}', '\u{2F9F}', Mapping::Mapped(&['\u{8F9B}'])),
+    ('\u{2FA0}', '\u{2FA0}', Mapping::Mapped(&['\u{8FB0}'])),
+    ('\u{2FA1}', '\u{2FA1}', Mapping::Mapped(&['\u{8FB5}'])),
+    ('\u{2FA2}', '\u{2FA2}', Mapping::Mapped(&['\u{9091}'])),
+    ('\u{2FA3}', '\u{2FA3}', Mapping::Mapped(&['\u{9149}'])),
+    ('\u{2FA4}', '\u{2FA4}', Mapping::Mapped(&['\u{91C6}'])),
+    ('\u{2FA5}', '\u{2FA5}', Mapping::Mapped(&['\u{91CC}'])),
+    ('\u{2FA6}', '\u{2FA6}', Mapping::Mapped(&['\u{91D1}'])),
+    ('\u{2FA7}', '\u{2FA7}', Mapping::Mapped(&['\u{9577}'])),
+    ('\u{2FA8}', '\u{2FA8}', Mapping::Mapped(&['\u{9580}'])),
+    ('\u{2FA9}', '\u{2FA9}', Mapping::Mapped(&['\u{961C}'])),
+    ('\u{2FAA}', '\u{2FAA}', Mapping::Mapped(&['\u{96B6}'])),
+    ('\u{2FAB}', '\u{2FAB}', Mapping::Mapped(&['\u{96B9}'])),
+    ('\u{2FAC}', '\u{2FAC}', Mapping::Mapped(&['\u{96E8}'])),
+    ('\u{2FAD}', '\u{2FAD}', Mapping::Mapped(&['\u{9751}'])),
+    ('\u{2FAE}', '\u{2FAE}', Mapping::Mapped(&['\u{975E}'])),
+    ('\u{2FAF}', '\u{2FAF}', Mapping::Mapped(&['\u{9762}'])),
+    ('\u{2FB0}', '\u{2FB0}', Mapping::Mapped(&['\u{9769}'])),
+    ('\u{2FB1}', '\u{2FB1}', Mapping::Mapped(&['\u{97CB}'])),
+    ('\u{2FB2}', '\u{2FB2}', Mapping::Mapped(&['\u{97ED}'])),
+    ('\u{2FB3}', '\u{2FB3}', Mapping::Mapped(&['\u{97F3}'])),
+    ('\u{2FB4}', '\u{2FB4}', Mapping::Mapped(&['\u{9801}'])),
+    ('\u{2FB5}', '\u{2FB5}', Mapping::Mapped(&['\u{98A8}'])),
+    ('\u{2FB6}', '\u{2FB6}', Mapping::Mapped(&['\u{98DB}'])),
+    ('\u{2FB7}', '\u{2FB7}', Mapping::Mapped(&['\u{98DF}'])),
+    ('\u{2FB8}', '\u{2FB8}', Mapping::Mapped(&['\u{9996}'])),
+    ('\u{2FB9}', '\u{2FB9}', Mapping::Mapped(&['\u{9999}'])),
+    ('\u{2FBA}', '\u{2FBA}', Mapping::Mapped(&['\u{99AC}'])),
+    ('\u{2FBB}', '\u{2FBB}', Mapping::Mapped(&['\u{9AA8}'])),
+    ('\u{2FBC}', '\u{2FBC}', Mapping::Mapped(&['\u{9AD8}'])),
+    ('\u{2FBD}', '\u{2FBD}', Mapping::Mapped(&['\u{9ADF}'])),
+    ('\u{2FBE}', '\u{2FBE}', Mapping::Mapped(&['\u{9B25}'])),
+    ('\u{2FBF}', '\u{2FBF}', Mapping::Mapped(&['\u{9B2F}'])),
+    ('\u{2FC0}', '\u{2FC0}', Mapping::Mapped(&['\u{9B32}'])),
+    ('\u{2FC1}', '\u{2FC1}', Mapping::Mapped(&['\u{9B3C}'])),
+    ('\u{2FC2}', '\u{2FC2}', Mapping::Mapped(&['\u{9B5A}'])),
+    ('\u{2FC3}', '\u{2FC3}', Mapping::Mapped(&['\u{9CE5}'])),
+    ('\u{2FC4}', '\u{2FC4}', Mapping::Mapped(&['\u{9E75}'])),
+    ('\u{2FC5}', '\u{2FC5}', Mapping::Mapped(&['\u{9E7F}'])),
+    ('\u{2FC6}', '\u{2FC6}', Mapping::Mapped(&['\u{9EA5}'])),
+    ('\u{2FC7}', '\u{2FC7}', Mapping::Mapped(&['\u{9EBB}'])),
+    ('\u{2FC8}', '\u{2FC8}', Mapping::Mapped(&['\u{9EC3}'])),
+    ('\u{2FC9}', '\u{2FC9}', Mapping::Mapped(&['\u{9ECD}'])),
+    ('\u{2FCA}', '\u{2FCA}', Mapping::Mapped(&['\u{9ED1}'])),
+    ('\u{2FCB}', '\u{2FCB}', Mapping::Mapped(&['\u{9EF9}'])),
+    ('\u{2FCC}', '\u{2FCC}', Mapping::Mapped(&['\u{9EFD}'])),
+    ('\u{2FCD}', '\u{2FCD}', Mapping::Mapped(&['\u{9F0E}'])),
+    ('\u{2FCE}', '\u{2FCE}', Mapping::Mapped(&['\u{9F13}'])),
+    ('\u{2FCF}', '\u{2FCF}', Mapping::Mapped(&['\u{9F20}'])),
+    ('\u{2FD0}', '\u{2FD0}', Mapping::Mapped(&['\u{9F3B}'])),
+    ('\u{2FD1}', '\u{2FD1}', Mapping::Mapped(&['\u{9F4A}'])),
+    ('\u{2FD2}', '\u{2FD2}', Mapping::Mapped(&['\u{9F52}'])),
+    ('\u{2FD3}', '\u{2FD3}', Mapping::Mapped(&['\u{9F8D}'])),
+    ('\u{2FD4}', '\u{2FD4}', Mapping::Mapped(&['\u{9F9C}'])),
+    ('\u{2FD5}', '\u{2FD5}', Mapping::Mapped(&['\u{9FA0}'])),
+    ('\u{2FD6}', '\u{2FFF}', Mapping::Disallowed),
+    ('\u{3000}', '\u{3000}', Mapping::DisallowedStd3Mapped(&['\u{20}'])),
+    ('\u{3001}', '\u{3001}', Mapping::Valid),
+    ('\u{3002}', '\u{3002}', Mapping::Mapped(&['\u{2E}'])),
+    ('\u{3003}', '\u{3035}', Mapping::Valid),
+    ('\u{3036}', '\u{3036}', Mapping::Mapped(&['\u{3012}'])),
+    ('\u{3037}', '\u{3037}', Mapping::Valid),
+    ('\u{3038}', '\u{3038}', Mapping::Mapped(&['\u{5341}'])),
+    ('\u{3039}', '\u{3039}', Mapping::Mapped(&['\u{5344}'])),
+    ('\u{303A}', '\u{303A}', Mapping::Mapped(&['\u{5345}'])),
+    ('\u{303B}', '\u{303F}', Mapping::Valid),
+    ('\u{3040}', '\u{3040}', Mapping::Disallowed),
+    ('\u{3041}', '\u{3096}', Mapping::Valid),
+    ('\u{3097}', '\u{3098}', Mapping::Disallowed),
+    ('\u{3099}', '\u{309A}', Mapping::Valid),
+    ('\u{309B}', '\u{309B}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{3099}'])),
+    ('\u{309C}', '\u{309C}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{309A}'])),
+    ('\u{309D}', '\u{309E}', Mapping::Valid),
+    ('\u{309F}', '\u{309F}', Mapping::Mapped(&['\u{3088}', '\u{308A}'])),
+    ('\u{30A0}', '\u{30FE}', Mapping::Valid),
+    ('\u{30FF}', '\u{30FF}', Mapping::Mapped(&['\u{30B3}', '\u{30C8}'])),
+    ('\u{3100}', '\u{3104}', Mapping::Disallowed),
+    ('\u{3105}', '\u{312F}', Mapping::Valid),
+    ('\u{3130}', '\u{3130}', Mapping::Disallowed),
+    ('\u{3131}', '\u{3131}', Mapping::Mapped(&['\u{1100}'])),
+    ('\u{3132}', '\u{3132}', Mapping::Mapped(&['\u{1101}'])),
+    ('\u{3133}', '\u{3133}', Mapping::Mapped(&['\u{11AA}'])),
+    ('\u{3134}', '\u{3134}', Mapping::Mapped(&['\u{1102}'])),
+    ('\u{3135}', '\u{3135}', Mapping::Mapped(&['\u{11AC}'])),
+    ('\u{3136}', '\u{3136}', Mapping::Mapped(&['\u{11AD}'])),
+    ('\u{3137}', '\u{3137}', Mapping::Mapped(&['\u{1103}'])),
+    ('\u{3138}', '\u{3138}', Mapping::Mapped(&['\u{1104}'])),
+    ('\u{3139}', '\u{3139}', Mapping::Mapped(&['\u{1105}'])),
+    ('\u{313A}', '\u{313A}', Mapping::Mapped(&['\u{11B0}'])),
+    ('\u{313B}', '\u{313B}', Mapping::Mapped(&['\u{11B1}'])),
+    ('\u{313C}', '\u{313C}', Mapping::Mapped(&['\u{11B2}'])),
+    ('\u{313D}', '\u{313D}', Mapping::Mapped(&['\u{11B3}'])),
+    ('\u{313E}', '\u{313E}', Mapping::Mapped(&['\u{11B4}'])),
+    ('\u{313F}', '\u{313F}', Mapping::Mapped(&['\u{11B5}'])),
+    ('\u{3140}', '\u{3140}', Mapping::Mapped(&['\u{111A}'])),
+    ('\u{3141}', '\u{3141}', Mapping::Mapped(&['\u{1106}'])),
+    ('\u{3142}', '\u{3142}', Mapping::Mapped(&['\u{1107}'])),
+    ('\u{3143}', '\u{3143}', Mapping::Mapped(&['\u{1108}'])),
+    ('\u{3144}', '\u{3144}', Mapping::Mapped(&['\u{1121}'])),
+    ('\u{3145}', '\u{3145}', Mapping::Mapped(&['\u{1109}'])),
+    ('\u{3146}', '\u{3146}', Mapping::Mapped(&['\u{110A}'])),
+    ('\u{3147}', '\u{3147}', Mapping::Mapped(&['\u{110B}'])),
+    ('\u{3148}', '\u{3148}', Mapping::Mapped(&['\u{110C}'])),
+    ('\u{3149}', '\u{3149}', Mapping::Mapped(&['\u{110D}'])),
+    ('\u{314A}', '\u{314A}', Mapping::Mapped(&['\u{110E}'])),
+    ('\u{314B}', '\u{314B}', Mapping::Mapped(&['\u{110F}'])),
+    ('\u{314C}', '\u{314C}', Mapping::Mapped(&['\u{1110}'])),
+    ('\u{314D}', '\u{314D}', Mapping::Mapped(&['\u{1111}'])),
+    ('\u{314E}', '\u{314E}', Mapping::Mapped(&['\u{1112}'])),
+    ('\u{314F}', '\u{314F}', Mapping::Mapped(&['\u{1161}'])),
+    ('\u{3150}', '\u{3150}', Mapping::Mapped(&['\u{1162}'])),
+    ('\u{3151}', '\u{3151}', Mapping::Mapped(&['\u{1163}'])),
+    ('\u{3152}', '\u{3152}', Mapping::Mapped(&['\u{1164}'])),
+    ('\u{3153}', '\u{3153}', Mapping::Mapped(&['\u{1165}'])),
+    ('\u{3154}', '\u{3154}', Mapping::Mapped(&['\u{1166}'])),
+    ('\u{3155}', '\u{3155}', Mapping::Mapped(&['\u{1167}'])),
+    ('\u{3156}', '\u{3156}', Mapping::Mapped(&['\u{1168}'])),
+    ('\u{3157}', '\u{3157}', Mapping::Mapped(&['\u{1169}'])),
+    ('\u{3158}', '\u{3158}', Mapping::Mapped(&['\u{116A}'])),
+    ('\u{3159}', '\u{3159}', Mapping::Mapped(&['\u{116B}'])),
+    ('\u{315A}', '\u{315A}', Mapping::Mapped(&['\u{116C}'])),
+    ('\u{315B}', '\u{315B}', Mapping::Mapped(&['\u{116D}'])),
+    ('\u{315C}', '\u{315C}', Mapping::Mapped(&['\u{116E}'])),
+    ('\u{315D}', '\u{315D}', Mapping::Mapped(&['\u{116F}'])),
+    ('\u{315E}', '\u{315E}', Mapping::Mapped(&['\u{1170}'])),
+    ('\u{315F}', '\u{315F}', Mapping::Mapped(&['\u{1171}'])),
+    ('\u{3160}', '\u{3160}', Mapping::Mapped(&['\u{1172}'])),
+    ('\u{3161}', '\u{3161}', Mapping::Mapped(&['\u{1173}'])),
+    ('\u{3162}', '\u{3162}', Mapping::Mapped(&['\u{1174}'])),
+    ('\u{3163}', '\u{3163}', Mapping::Mapped(&['\u{1175}'])),
+    ('\u{3164}', '\u{3164}', Mapping::Disallowed),
+    ('\u{3165}', '\u{3165}', Mapping::Mapped(&['\u{1114}'])),
+    ('\u{3166}', '\u{3166}', Mapping::Mapped(&['\u{1115}'])),
+    ('\u{3167}', '\u{3167}', Mapping::Mapped(&['\u{11C7}'])),
+    ('\u{3168}', '\u{3168}', Mapping::Mapped(&['\u{11C8}'])),
+    ('\u{3169}', '\u{3169}', Mapping::Mapped(&['\u{11CC}'])),
+    ('\u{316A}', '\u{316A}', Mapping::Mapped(&['\u{11CE}'])),
+    ('\u{316B}', '\u{316B}', Mapping::Mapped(&['\u{11D3}'])),
+    ('\u{316C}', '\u{316C}', Mapping::Mapped(&['\u{11D7}'])),
+    ('\u{316D}', '\u{316D}', Mapping::Mapped(&['\u{11D9}'])),
+    ('\u{316E}', '\u{316E}', Mapping::Mapped(&['\u{111C}'])),
+    ('\u{316F}', '\u{316F}', Mapping::Mapped(&['\u{11DD}'])),
+    ('\u{3170}', '\u{3170}', Mapping::Mapped(&['\u{11DF}'])),
+    ('\u{3171}', '\u{3171}', Mapping::Mapped(&['\u{111D}'])),
+    ('\u{3172}', '\u{3172}', Mapping::Mapped(&['\u{111E}'])),
+    ('\u{3173}', '\u{3173}', Mapping::Mapped(&['\u{1120}'])),
+    ('\u{3174}', '\u{3174}', Mapping::Mapped(&['\u{1122}'])),
+    ('\u{3175}', '\u{3175}', Mapping::Mapped(&['\u{1123}'])),
+    ('\u{3176}', '\u{3176}', Mapping::Mapped(&['\u{1127}'])),
+    ('\u{3177}', '\u{3177}', Mapping::Mapped(&['\u{1129}'])),
+    ('\u{3178}', '\u{3178}', Mapping::Mapped(&['\u{112B}'])),
+    ('\u{3179}', '\u{3179}', Mapping::Mapped(&['\u{112C}'])),
+    ('\u{317A}', '\u{317A}', Mapping::Mapped(&['\u{112D}'])),
+    ('\u{317B}', '\u{317B}', Mapping::Mapped(&['\u{112E}'])),
+    ('\u{317C}', '\u{317C}', Mapping::Mapped(&['\u{112F}'])),
+    ('\u{317D}', '\u{317D}', Mapping::Mapped(&['\u{1132}'])),
+    ('\u{317E}', '\u{317E}', Mapping::Mapped(&['\u{1136}'])),
+    ('\u{317F}', '\u{317F}', Mapping::Mapped(&['\u{1140}'])),
+    ('\u{3180}', '\u{3180}', Mapping::Mapped(&['\u{1147}'])),
+    ('\u{3181}', '\u{3181}', Mapping::Mapped(&['\u{114C}'])),
+    ('\u{3182}', '\u{3182}', Mapping::Mapped(&['\u{11F1}'])),
+    ('\u{3183}', '\u{3183}', Mapping::Mapped(&['\u{11F2}'])),
+    ('\u{3184}', '\u{3184}', Mapping::Mapped(&['\u{1157}'])),
+    ('\u{3185}', '\u{3185}', Mapping::Mapped(&['\u{1158}'])),
+    ('\u{3186}', '\u{3186}', Mapping::Mapped(&['\u{1159}'])),
+    ('\u{3187}', '\u{3187}', Mapping::Mapped(&['\u{1184}'])),
+    ('\u{3188}', '\u{3188}', Mapping::Mapped(&['\u{1185}'])),
+    ('\u{3189}', '\u{3189}', Mapping::Mapped(&['\u{1188}'])),
+    ('\u{318A}', '\u{318A}', Mapping::Mapped(&['\u{1191}'])),
+    ('\u{318B}', '\u{318B}', Mapping::Mapped(&['\u{1192}'])),
+    ('\u{318C}', '\u{318C}', Mapping::Mapped(&['\u{1194}'])),
+    ('\u{318D}', '\u{318D}', Mapping::Mapped(&['\u{119E}'])),
+    ('\u{318E}', '\u{318E}', Mapping::Mapped(&['\u{11A1}'])),
+    ('\u{318F}', '\u{318F}', Mapping::Disallowed),
+    ('\u{3190}', '\u{3191}', Mapping::Valid),
+    ('\u{3192}', '\u{3192}', Mapping::Mapped(&['\u{4E00}'])),
+    ('\u{3193}', '\u{3193}', Mapping::Mapped(&['\u{4E8C}'])),
+    ('\u{3194}', '\u{3194}', Mapping::Mapped(&['\u{4E09}'])),
+    ('\u{3195}', '\u{3195}', Mapping::Mapped(&['\u{56DB}'])),
+    ('\u{3196}', '\u{3196}', Mapping::Mapped(&['\u{4E0A}'])),
+    ('\u{3197}', '\u{3197}', Mapping::Mapped(&['\u{4E2D}'])),
+    ('\u{3198}', '\u{3198}', Mapping::Mapped(&['\u{4E0B}'])),
+    ('\u{3199}', '\u{3199}', Mapping::Mapped(&['\u{7532}'])),
+    ('\u{319A}', '\u{319A}', Mapping::Mapped(&['\u{4E59}'])),
+    ('\u{319B}', '\u{319B}', Mapping::Mapped(&['\u{4E19}'])),
+    ('\u{319C}', '\u{319C}', Mapping::Mapped(&['\u{4E01}'])),
+    ('\u{319D}', '\u{319D}', Mapping::Mapped(&['\u{5929}'])),
+    ('\u{319E}', '\u{319E}', Mapping::Mapped(&['\u{5730}'])),
+    ('\u{319F}', '\u{319F}', Mapping::Mapped(&['\u{4EBA}'])),
+    ('\u{31A0}', '\u{31E3}', Mapping::Valid),
+    ('\u{31E4}', '\u{31EF}', Mapping::Disallowed),
+    ('\u{31F0}', '\u{31FF}', Mapping::Valid),
+    ('\u{3200}', '\u{3200}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{1100}', '\u{29}'])),
+    ('\u{3201}', '\u{3201}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{1102}', '\u{29}'])),
+    ('\u{3202}', '\u{3202}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{1103}', '\u{29}'])),
+    ('\u{3203}', '\u{3203}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{1105}', '\u{29}'])),
+    ('\u{3204}', '\u{3204}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{1106}', '\u{29}'])),
+    ('\u{3205}', '\u{3205}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{1107}', '\u{29}'])),
+    ('\u{3206}', '\u{3206}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{1109}', '\u{29}'])),
+    ('\u{3207}', '\u{3207}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{110B}', '\u{29}'])),
+    ('\u{3208}', '\u{3208}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{110C}', '\u{29}'])),
+    ('\u{3209}', '\u{3209}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{110E}', '\u{29}'])),
+    ('\u{320A}', '\u{320A}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{110F}', '\u{29}'])),
+    ('\u{320B}', '\u{320B}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{1110}', '\u{29}'])),
+    ('\u{320C}', '\u{320C}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{1111}', '\u{29}'])),
+    ('\u{320D}', '\u{320D}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{1112}', '\u{29}'])),
+    ('\u{320E}', '\u{320E}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{AC00}', '\u{29}'])),
+    ('\u{320F}', '\u{320F}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{B098}', '\u{29}'])),
+    ('\u{3210}', '\u{3210}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{B2E4}', '\u{29}'])),
+    ('\u{3211}', '\u{3211}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{B77C}', '\u{29}'])),
+    ('\u{3212}', '\u{3212}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{B9C8}', '\u{29}'])),
+    ('\u{3213}', '\u{3213}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{BC14}', '\u{29}'])),
+    ('\u{3214}', '\u{3214}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{C0AC}', '\u{29}'])),
+    ('\u{3215}', '\u{3215}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{C544}', '\u{29}'])),
+    ('\u{3216}', '\u{3216}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{C790}', '\u{29}'])),
+    ('\u{3217}', '\u{3217}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{CC28}', '\u{29}'])),
+    ('\u{3218}', '\u{3218}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{CE74}', '\u{29}'])),
+    ('\u{3219}', '\u{3219}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{D0C0}', '\u{29}'])),
+    ('\u{321A}', '\u{321A}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{D30C}', '\u{29}'])),
+    ('\u{321B}', '\u{321B}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{D558}', '\u{29}'])),
+    ('\u{321C}', '\u{321C}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{C8FC}', '\u{29}'])),
+    ('\u{321D}', '\u{321D}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{C624}', '\u{C804}', '\u{29}'])),
+    ('\u{321E}', '\u{321E}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{C624}', '\u{D6C4}', '\u{29}'])),
+    ('\u{321F}', '\u{321F}', Mapping::Disallowed),
+    ('\u{3220}', '\u{3220}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{4E00}', '\u{29}'])),
+    ('\u{3221}', '\u{3221}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{4E8C}', '\u{29}'])),
+    ('\u{3222}', '\u{3222}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{4E09}', '\u{29}'])),
+    ('\u{3223}', '\u{3223}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{56DB}', '\u{29}'])),
+    ('\u{3224}', '\u{3224}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{4E94}', '\u{29}'])),
+    ('\u{3225}', '\u{3225}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{516D}', '\u{29}'])),
+    ('\u{3226}', '\u{3226}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{4E03}', '\u{29}'])),
+    ('\u{3227}', '\u{3227}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{516B}', '\u{29}'])),
+    ('\u{3228}', '\u{3228}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{4E5D}', '\u{29}'])),
+    ('\u{3229}', '\u{3229}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{5341}', '\u{29}'])),
+    ('\u{322A}', '\u{322A}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6708}', '\u{29}'])),
+    ('\u{322B}', '\u{322B}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{706B}', '\u{29}'])),
+    ('\u{322C}', '\u{322C}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6C34}', '\u{29}'])),
+    ('\u{322D}', '\u{322D}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6728}', '\u{29}'])),
+    ('\u{322E}', '\u{322E}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{91D1}', '\u{29}'])),
+    ('\u{322F}', '\u{322F}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{571F}', '\u{29}'])),
+    ('\u{3230}', '\u{3230}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{65E5}', '\u{29}'])),
+    ('\u{3231}', '\u{3231}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{682A}', '\u{29}'])),
+    ('\u{3232}', '\u{3232}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6709}', '\u{29}'])),
+    ('\u{3233}', '\u{3233}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{793E}', '\u{29}'])),
+    ('\u{3234}', '\u{3234}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{540D}', '\u{29}'])),
+    ('\u{3235}', '\u{3235}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{7279}', '\u{29}'])),
+    ('\u{3236}', '\u{3236}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{8CA1}', '\u{29}'])),
+    ('\u{3237}', '\u{3237}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{795D}', '\u{29}'])),
+    ('\u{3238}', '\u{3238}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{52B4}', '\u{29}'])),
+    ('\u{3239}', '\u{3239}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{4EE3}', '\u{29}'])),
+    ('\u{323A}', '\u{323A}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{547C}', '\u{29}'])),
+    ('\u{323B}', '\u{323B}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{5B66}', '\u{29}'])),
+    ('\u{323C}', '\u{323C}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{76E3}', '\u{29}'])),
+    ('\u{323D}', '\u{323D}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{4F01}', '\u{29}'])),
+    ('\u{323E}', '\u{323E}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{8CC7}', '\u{29}'])),
+    ('\u{323F}', '\u{323F}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{5354}', '\u{29}'])),
+    ('\u{3240}', '\u{3240}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{796D}', '\u{29}'])),
+    ('\u{3241}', '\u{3241}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{4F11}', '\u{29}'])),
+    ('\u{3242}', '\u{3242}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{81EA}', '\u{29}'])),
+    ('\u{3243}', '\u{3243}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{81F3}', '\u{29}'])),
+    ('\u{3244}', '\u{3244}', Mapping::Mapped(&['\u{554F}'])),
+    ('\u{3245}', '\u{3245}', Mapping::Mapped(&['\u{5E7C}'])),
+    ('\u{3246}', '\u{3246}', Mapping::Mapped(&['\u{6587}'])),
+    ('\u{3247}', '\u{3247}', Mapping::Mapped(&['\u{7B8F}'])),
+    ('\u{3248}', '\u{324F}', Mapping::Valid),
+    ('\u{3250}', '\u{3250}', Mapping::Mapped(&['\u{70}', '\u{74}', '\u{65}'])),
+    ('\u{3251}', '\u{3251}', Mapping::Mapped(&['\u{32}', '\u{31}'])),
+    ('\u{3252}', '\u{3252}', Mapping::Mapped(&['\u{32}', '\u{32}'])),
+    ('\u{3253}', '\u{3253}', Mapping::Mapped(&['\u{32}', '\u{33}'])),
+    ('\u{3254}', '\u{3254}', Mapping::Mapped(&['\u{32}', '\u{34}'])),
+    ('\u{3255}', '\u{3255}', Mapping::Mapped(&['\u{32}', '\u{35}'])),
+    ('\u{3256}', '\u{3256}', Mapping::Mapped(&['\u{32}', '\u{36}'])),
+    ('\u{3257}', '\u{3257}', Mapping::Mapped(&['\u{32}', '\u{37}'])),
+    ('\u{3258}', '\u{3258}', Mapping::Mapped(&['\u{32}', '\u{38}'])),
+    ('\u{3259}', '\u{3259}', Mapping::Mapped(&['\u{32}', '\u{39}'])),
+    ('\u{325A}', '\u{325A}', Mapping::Mapped(&['\u{33}', '\u{30}'])),
+    ('\u{325B}', '\u{325B}', Mapping::Mapped(&['\u{33}', '\u{31}'])),
+    ('\u{325C}', '\u{325C}', Mapping::Mapped(&['\u{33}', '\u{32}'])),
+    ('\u{325D}', '\u{325D}', Mapping::Mapped(&['\u{33}', '\u{33}'])),
+    ('\u{325E}', '\u{325E}', Mapping::Mapped(&['\u{33}', '\u{34}'])),
+    ('\u{325F}', '\u{325F}', Mapping::Mapped(&['\u{33}', '\u{35}'])),
+    ('\u{3260}', '\u{3260}', Mapping::Mapped(&['\u{1100}'])),
+    ('\u{3261}', '\u{3261}', Mapping::Mapped(&['\u{1102}'])),
+    ('\u{3262}', '\u{3262}', Mapping::Mapped(&['\u{1103}'])),
+    ('\u{3263}', '\u{3263}', Mapping::Mapped(&['\u{1105}'])),
+    ('\u{3264}', '\u{3264}', Mapping::Mapped(&['\u{1106}'])),
+    ('\u{3265}', '\u{3265}', Mapping::Mapped(&['\u{1107}'])),
+    ('\u{3266}', '\u{3266}', Mapping::Mapped(&['\u{1109}'])),
+    ('\u{3267}', '\u{3267}', Mapping::Mapped(&['\u{110B}'])),
+    ('\u{3268}', '\u{3268}', Mapping::Mapped(&['\u{110C}'])),
+    ('\u{3269}', '\u{3269}', Mapping::Mapped(&['\u{110E}'])),
+    ('\u{326A}', '\u{326A}', Mapping::Mapped(&['\u{110F}'])),
+    ('\u{326B}', '\u{326B}', Mapping::Mapped(&['\u{1110}'])),
+    ('\u{326C}', '\u{326C}', Mapping::Mapped(&['\u{1111}'])),
+    ('\u{326D}', '\u{326D}', Mapping::Mapped(&['\u{1112}'])),
+    ('\u{326E}', '\u{326E}', Mapping::Mapped(&['\u{AC00}'])),
+    ('\u{326F}', '\u{326F}', Mapping::Mapped(&['\u{B098}'])),
+    ('\u{3270}', '\u{3270}', Mapping::Mapped(&['\u{B2E4}'])),
+    ('\u{3271}', '\u{3271}', Mapping::Mapped(&['\u{B77C}'])),
+    ('\u{3272}', '\u{3272}', Mapping::Mapped(&['\u{B9C8}'])),
+    ('\u{3273}', '\u{3273}', Mapping::Mapped(&['\u{BC14}'])),
+    ('\u{3274}', '\u{3274}', Mapping::Mapped(&['\u{C0AC}'])),
+    ('\u{3275}', '\u{3275}', Mapping::Mapped(&['\u{C544}'])),
+    ('\u{3276}', '\u{3276}', Mapping::Mapped(&['\u{C790}'])),
+    ('\u{3277}', '\u{3277}', Mapping::Mapped(&['\u{CC28}'])),
+    ('\u{3278}', '\u{3278}', Mapping::Mapped(&['\u{CE74}'])),
+    ('\u{3279}', '\u{3279}', Mapping::Mapped(&['\u{D0C0}'])),
+    ('\u{327A}', '\u{327A}', Mapping::Mapped(&['\u{D30C}'])),
+    ('\u{327B}', '\u{327B}', Mapping::Mapped(&['\u{D558}'])),
+    ('\u{327C}', '\u{327C}', Mapping::Mapped(&['\u{CC38}', '\u{ACE0}'])),
+    ('\u{327D}', '\u{327D}', Mapping::Mapped(&['\u{C8FC}', '\u{C758}'])),
+    ('\u{327E}', '\u{327E}', Mapping::Mapped(&['\u{C6B0}'])),
+    ('\u{327F}', '\u{327F}', Mapping::Valid),
+    ('\u{3280}', '\u{3280}', Mapping::Mapped(&['\u{4E00}'])),
+    ('\u{3281}', '\u{3281}', Mapping::Mapped(&['\u{4E8C}'])),
+    ('\u{3282}', '\u{3282}', Mapping::Mapped(&['\u{4E09}'])),
+    ('\u{3283}', '\u{3283}', Mapping::Mapped(&['\u{56DB}'])),
+    ('\u{3284}', '\u{3284}', Mapping::Mapped(&['\u{4E94}'])),
+    ('\u{3285}', '\u{3285}', Mapping::Mapped(&['\u{516D}'])),
+    ('\u{3286}', '\u{3286}', Mapping::Mapped(&['\u{4E03}'])),
+    ('\u{3287}', '\u{3287}', Mapping::Mapped(&['\u{516B}'])),
+    ('\u{3288}', '\u{3288}', Mapping::Mapped(&['\u{4E5D}'])),
+    ('\u{3289}', '\u{3289}', Mapping::Mapped(&['\u{5341}'])),
+    ('\u{328A}', '\u{328A}', Mapping::Mapped(&['\u{6708}'])),
+    ('\u{328B}', '\u{328B}', Mapping::Mapped(&['\u{706B}'])),
+    ('\u{328C}', '\u{328C}', Mapping::Mapped(&['\u{6C34}'])),
+    ('\u{328D}', '\u{328D}', Mapping::Mapped(&['\u{6728}'])),
+    ('\u{328E}', '\u{328E}', Mapping::Mapped(&['\u{91D1}'])),
+    ('\u{328F}', '\u{328F}', Mapping::Mapped(&['\u{571F}'])),
+    ('\u{3290}', '\u{3290}', Mapping::Mapped(&['\u{65E5}'])),
+    ('\u{3291}', '\u{3291}', Mapping::Mapped(&['\u{682A}'])),
+    ('\u{3292}', '\u{3292}', Mapping::Mapped(&['\u{6709}'])),
+    ('\u{3293}', '\u{3293}', Mapping::Mapped(&['\u{793E}'])),
+    ('\u{3294}', '\u{3294}', Mapping::Mapped(&['\u{540D}'])),
+    ('\u{3295}', '\u{3295}', Mapping::Mapped(&['\u{7279}'])),
+    ('\u{3296}', '\u{3296}', Mapping::Mapped(&['\u{8CA1}'])),
+    ('\u{3297}', '\u{3297}', Mapping::Mapped(&['\u{795D}'])),
+    ('\u{3298}', '\u{3298}', Mapping::Mapped(&['\u{52B4}'])),
+    ('\u{3299}', '\u{3299}', Mapping::Mapped(&['\u{79D8}'])),
+    ('\u{329A}', '\u{329A}', Mapping::Mapped(&['\u{7537}'])),
+    ('\u{329B}', '\u{329B}', Mapping::Mapped(&['\u{5973}'])),
+    ('\u{329C}', '\u{329C}', Mapping::Mapped(&['\u{9069}'])),
+    ('\u{329D}', '\u{329D}', Mapping::Mapped(&['\u{512A}'])),
+    ('\u{329E}', '\u{329E}', Mapping::Mapped(&['\u{5370}'])),
+    ('\u{329F}', '\u{329F}', Mapping::Mapped(&['\u{6CE8}'])),
+    ('\u{32A0}', '\u{32A0}', Mapping::Mapped(&['\u{9805}'])),
+    ('\u{32A1}', '\u{32A1}', Mapping::Mapped(&['\u{4F11}'])),
+    ('\u{32A2}', '\u{32A2}', Mapping::Mapped(&['\u{5199}'])),
+    ('\u{32A3}', '\u{32A3}', Mapping::Mapped(&['\u{6B63}'])),
+    ('\u{32A4}', '\u{32A4}', Mapping::Mapped(&['\u{4E0A}'])),
+    ('\u{32A5}', '\u{32A5}', Mapping::Mapped(&['\u{4E2D}'])),
+    ('\u{32A6}', '\u{32A6}', Mapping::Mapped(&['\u{4E0B}'])),
+    ('\u{32A7}', '\u{32A7}', Mapping::Mapped(&['\u{5DE6}'])),
+    ('\u{32A8}', '\u{32A8}', Mapping::Mapped(&['\u{53F3}'])),
+    ('\u{32A9}', '\u{32A9}', Mapping::Mapped(&['\u{533B}'])),
+    ('\u{32AA}', '\u{32AA}', Mapping::Mapped(&['\u{5B97}'])),
+    ('\u{32AB}', '\u{32AB}', Mapping::Mapped(&['\u{5B66}'])),
+    ('\u{32AC}', '\u{32AC}', Mapping::Mapped(&['\u{76E3}'])),
+    ('\u{32AD}', '\u{32AD}', Mapping::Mapped(&['\u{4F01}'])),
+    ('\u{32AE}', '\u{32AE}', Mapping::Mapped(&['\u{8CC7}'])),
+    ('\u{32AF}', '\u{32AF}', Mapping::Mapped(&['\u{5354}'])),
+    ('\u{32B0}', '\u{32B0}', Mapping::Mapped(&['\u{591C}'])),
+    ('\u{32B1}', '\u{32B1}', Mapping::Mapped(&['\u{33}', '\u{36}'])),
+    ('\u{32B2}', '\u{32B2}', Mapping::Mapped(&['\u{33}', '\u{37}'])),
+    ('\u{32B3}', '\u{32B3}', Mapping::Mapped(&['\u{33}', '\u{38}'])),
+    ('\u{32B4}', '\u{32B4}', Mapping::Mapped(&['\u{33}', '\u{39}'])),
+    ('\u{32B5}', '\u{32B5}', Mapping::Mapped(&['\u{34}', '\u{30}'])),
+    ('\u{32B6}', '\u{32B6}', Mapping::Mapped(&['\u{34}', '\u{31}'])),
+    ('\u{32B7}', '\u{32B7}', Mapping::Mapped(&['\u{34}', '\u{32}'])),
+    ('\u{32B8}', '\u{32B8}', Mapping::Mapped(&['\u{34}', '\u{33}'])),
+    ('\u{32B9}', '\u{32B9}', Mapping::Mapped(&['\u{34}', '\u{34}'])),
+    ('\u{32BA}', '\u{32BA}', Mapping::Mapped(&['\u{34}', '\u{35}'])),
+    ('\u{32BB}', '\u{32BB}', Mapping::Mapped(&['\u{34}', '\u{36}'])),
+    ('\u{32BC}', '\u{32BC}', Mapping::Mapped(&['\u{34}', '\u{37}'])),
+    ('\u{32BD}', '\u{32BD}', Mapping::Mapped(&['\u{34}', '\u{38}'])),
+    ('\u{32BE}', '\u{32BE}', Mapping::Mapped(&['\u{34}', '\u{39}'])),
+    ('\u{32BF}', '\u{32BF}', Mapping::Mapped(&['\u{35}', '\u{30}'])),
+    ('\u{32C0}', '\u{32C0}', Mapping::Mapped(&['\u{31}', '\u{6708}'])),
+    ('\u{32C1}', '\u{32C1}', Mapping::Mapped(&['\u{32}', '\u{6708}'])),
+    ('\u{32C2}', '\u{32C2}', Mapping::Mapped(&['\u{33}', '\u{6708}'])),
+    ('\u{32C3}', '\u{32C3}', Mapping::Mapped(&['\u{34}', '\u{6708}'])),
+    ('\u{32C4}', '\u{32C4}', Mapping::Mapped(&['\u{35}', '\u{6708}'])),
+    ('\u{32C5}', '\u{32C5}', Mapping::Mapped(&['\u{36}', '\u{6708}'])),
+    ('\u{32C6}', '\u{32C6}', Mapping::Mapped(&['\u{37}', '\u{6708}'])),
+    ('\u{32C7}', '\u{32C7}', Mapping::Mapped(&['\u{38}', '\u{6708}'])),
+    ('\u{32C8}', '\u{32C8}', Mapping::Mapped(&['\u{39}', '\u{6708}'])),
+    ('\u{32C9}', '\u{32C9}', Mapping::Mapped(&['\u{31}', '\u{30}', '\u{6708}'])),
+    ('\u{32CA}', '\u{32CA}', Mapping::Mapped(&['\u{31}', '\u{31}', '\u{6708}'])),
+    ('\u{32CB}', '\u{32CB}', Mapping::Mapped(&['\u{31}', '\u{32}', '\u{6708}'])),
+    ('\u{32CC}', '\u{32CC}', Mapping::Mapped(&['\u{68}', '\u{67}'])),
+    ('\u{32CD}', '\u{32CD}', Mapping::Mapped(&['\u{65}', '\u{72}', '\u{67}'])),
+    ('\u{32CE}', '\u{32CE}', Mapping::Mapped(&['\u{65}', '\u{76}'])),
+    ('\u{32CF}', '\u{32CF}', Mapping::Mapped(&['\u{6C}', '\u{74}', '\u{64}'])),
+    ('\u{32D0}', '\u{32D0}', Mapping::Mapped(&['\u{30A2}'])),
+    ('\u{32D1}', '\u{32D1}', Mapping::Mapped(&['\u{30A4}'])),
+    ('\u{32D2}', '\u{32D2}', Mapping::Mapped(&['\u{30A6}'])),
+    ('\u{32D3}', '\u{32D3}', Mapping::Mapped(&['\u{30A8}'])),
+    ('\u{32D4}', '\u{32D4}', Mapping::Mapped(&['\u{30AA}'])),
+    ('\u{32D5}', '\u{32D5}', Mapping::Mapped(&['\u{30AB}'])),
+    ('\u{32D6}', '\u{32D6}', Mapping::Mapped(&['\u{30AD}'])),
+    ('\u{32D7}', '\u{32D7}', Mapping::Mapped(&['\u{30AF}'])),
+    ('\u{32D8}', '\u{32D8}', Mapping::Mapped(&['\u{30B1}'])),
+    ('\u{32D9}', '\u{32D9}', Mapping::Mapped(&['\u{30B3}'])),
+    ('\u{32DA}', '\u{32DA}', Mapping::Mapped(&['\u{30B5}'])),
+    ('\u{32DB}', '\u{32DB}', Mapping::Mapped(&['\u{30B7}'])),
+    ('\u{32DC}', '\u{32DC}', Mapping::Mapped(&['\u{30B9}'])),
+    ('\u{32DD}', '\u{32DD}', Mapping::Mapped(&['\u{30BB}'])),
+    ('\u{32DE}', '\u{32DE}', Mapping::Mapped(&['\u{30BD}'])),
+    ('\u{32DF}', '\u{32DF}', Mapping::Mapped(&['\u{30BF}'])),
+    ('\u{32E0}', '\u{32E0}', Mapping::Mapped(&['\u{30C1}'])),
+    ('\u{32E1}', '\u{32E1}', Mapping::Mapped(&['\u{30C4}'])),
+    ('\u{32E2}', '\u{32E2}', Mapping::Mapped(&['\u{30C6}'])),
+    ('\u{32E3}', '\u{32E3}', Mapping::Mapped(&['\u{30C8}'])),
+    ('\u{32E4}', '\u{32E4}', Mapping::Mapped(&['\u{30CA}'])),
+    ('\u{32E5}', '\u{32E5}', Mapping::Mapped(&['\u{30CB}'])),
+    ('\u{32E6}', '\u{32E6}', Mapping::Mapped(&['\u{30CC}'])),
+    ('\u{32E7}', '\u{32E7}', Mapping::Mapped(&['\u{30CD}'])),
+    ('\u{32E8}', '\u{32E8}', Mapping::Mapped(&['\u{30CE}'])),
+    ('\u{32E9}', '\u{32E9}', Mapping::Mapped(&['\u{30CF}'])),
+    ('\u{32EA}', '\u{32EA}', Mapping::Mapped(&['\u{30D2}'])),
+    ('\u{32EB}', '\u{32EB}', Mapping::Mapped(&['\u{30D5}'])),
+    ('\u{32EC}', '\u{32EC}', Mapping::Mapped(&['\u{30D8}'])),
+    ('\u{32ED}', '\u{32ED}', Mapping::Mapped(&['\u{30DB}'])),
+    ('\u{32EE}', '\u{32EE}', Mapping::Mapped(&['\u{30DE}'])),
+    ('\u{32EF}', '\u{32EF}', Mapping::Mapped(&['\u{30DF}'])),
+    ('\u{32F0}', '\u{32F0}', Mapping::Mapped(&['\u{30E0}'])),
+    ('\u{32F1}', '\u{32F1}', Mapping::Mapped(&['\u{30E1}'])),
+    ('\u{32F2}', '\u{32F2}', Mapping::Mapped(&['\u{30E2}'])),
+    ('\u{32F3}', '\u{32F3}', Mapping::Mapped(&['\u{30E4}'])),
+    ('\u{32F4}', '\u{32F4}', Mapping::Mapped(&['\u{30E6}'])),
+    ('\u{32F5}', '\u{32F5}', Mapping::Mapped(&['\u{30E8}'])),
+    ('\u{32F6}', '\u{32F6}', Mapping::Mapped(&['\u{30E9}'])),
+    ('\u{32F7}', '\u{32F7}', Mapping::Mapped(&['\u{30EA}'])),
+    ('\u{32F8}', '\u{32F8}', Mapping::Mapped(&['\u{30EB}'])),
+    ('\u{32F9}', '\u{32F9}', Mapping::Mapped(&['\u{30EC}'])),
+    ('\u{32FA}', '\u{32FA}', Mapping::Mapped(&['\u{30ED}'])),
+    ('\u{32FB}', '\u{32FB}', Mapping::Mapped(&['\u{30EF}'])),
+    ('\u{32FC}', '\u{32FC}', Mapping::Mapped(&['\u{30F0}'])),
+    ('\u{32FD}', '\u{32FD}', Mapping::Mapped(&['\u{30F1}'])),
+    ('\u{32FE}', '\u{32FE}', Mapping::Mapped(&['\u{30F2}'])),
+    ('\u{32FF}', '\u{32FF}', Mapping::Mapped(&['\u{4EE4}', '\u{548C}'])),
+    ('\u{3300}', '\u{3300}', Mapping::Mapped(&['\u{30A2}', '\u{30D1}', '\u{30FC}', '\u{30C8}'])),
+    ('\u{3301}', '\u{3301}', Mapping::Mapped(&['\u{30A2}', '\u{30EB}', '\u{30D5}', '\u{30A1}'])),
+    ('\u{3302}', '\u{3302}', Mapping::Mapped(&['\u{30A2}', '\u{30F3}', '\u{30DA}', '\u{30A2}'])),
+    ('\u{3303}', '\u{3303}', Mapping::Mapped(&['\u{30A2}', '\u{30FC}', '\u{30EB}'])),
+    ('\u{3304}', '\u{3304}', Mapping::Mapped(&['\u{30A4}', '\u{30CB}', '\u{30F3}', '\u{30B0}'])),
+    ('\u{3305}', '\u{3305}', Mapping::Mapped(&['\u{30A4}', '\u{30F3}', '\u{30C1}'])),
+    ('\u{3306}', '\u{3306}', Mapping::Mapped(&['\u{30A6}', '\u{30A9}', '\u{30F3}'])),
+    ('\u{3307}', '\u{3307}', Mapping::Mapped(&['\u{30A8}', '\u{30B9}', '\u{30AF}', '\u{30FC}', '\u{30C9}'])),
+    ('\u{3308}', '\u{3308}', Mapping::Mapped(&['\u{30A8}', '\u{30FC}', '\u{30AB}', '\u{30FC}'])),
+    ('\u{3309}', '\u{3309}', Mapping::Mapped(&['\u{30AA}', '\u{30F3}', '\u{30B9}'])),
+    ('\u{330A}', '\u{330A}', Mapping::Mapped(&['\u{30AA}', '\u{30FC}', '\u{30E0}'])),
+    ('\u{330B}', '\u{330B}', Mapping::Mapped(&['\u{30AB}', '\u{30A4}', '\u{30EA}'])),
+    ('\u{330C}', '\u{330C}', Mapping::Mapped(&['\u{30AB}', '\u{30E9}', '\u{30C3}', '\u{30C8}'])),
+    ('\u{330D}', '\u{330D}', Mapping::Mapped(&['\u{30AB}', '\u{30ED}', '\u{30EA}', '\u{30FC}'])),
+    ('\u{330E}', '\u{330E}', Mapping::Mapped(&['\u{30AC}', '\u{30ED}', '\u{30F3}'])),
+    ('\u{330F}', '\u{330F}', Mapping::Mapped(&['\u{30AC}', '\u{30F3}', '\u{30DE}'])),
+    ('\u{3310}', '\u{3310}', Mapping::Mapped(&['\u{30AE}', '\u{30AC}'])),
+    ('\u{3311}', '\u{3311}', Mapping::Mapped(&['\u{30AE}', '\u{30CB}', '\u{30FC}'])),
+    ('\u{3312}', '\u{3312}', Mapping::Mapped(&['\u{30AD}', '\u{30E5}', '\u{30EA}', '\u{30FC}'])),
+    ('\u{3313}', '\u{3313}', Mapping::Mapped(&['\u{30AE}', '\u{30EB}', '\u{30C0}', '\u{30FC}'])),
+    ('\u{3314}', '\u{3314}', Mapping::Mapped(&['\u{30AD}', '\u{30ED}'])),
+    ('\u{3315}', '\u{3315}', Mapping::Mapped(&['\u{30AD}', '\u{30ED}', '\u{30B0}', '\u{30E9}', '\u{30E0}'])),
+    ('\u{3316}', '\u{3316}', Mapping::Mapped(&['\u{30AD}', '\u{30ED}', '\u{30E1}', '\u{30FC}', '\u{30C8}', '\u{30EB}'])),
+    ('\u{3317}', '\u{3317}', Mapping::Mapped(&['\u{30AD}', '\u{30ED}', '\u{30EF}', '\u{30C3}', '\u{30C8}'])),
+    ('\u{3318}', '\u{3318}', Mapping::Mapped(&['\u{30B0}', '\u{30E9}', '\u{30E0}'])),
+    ('\u{3319}', '\u{3319}', Mapping::Mapped(&['\u{30B0}', '\u{30E9}', '\u{30E0}', '\u{30C8}', '\u{30F3}'])),
+    ('\u{331A}', '\u{331A}', Mapping::Mapped(&['\u{30AF}', '\u{30EB}', '\u{30BC}', '\u{30A4}', '\u{30ED}'])),
+    ('\u{331B}', '\u{331B}', Mapping::Mapped(&['\u{30AF}', '\u{30ED}', '\u{30FC}', '\u{30CD}'])),
+    ('\u{331C}', '\u{331C}', Mapping::Mapped(&['\u{30B1}', '\u{30FC}', '\u{30B9}'])),
+    ('\u{331D}', '\u{331D}', Mapping::Mapped(&['\u{30B3}', '\u{30EB}', '\u{30CA}'])),
+    ('\u{331E}', '\u{331E}', Mapping::Mapped(&['\u{30B3}', '\u{30FC}', '\u{30DD}'])),
+    ('\u{331F}', '\u{331F}', Mapping::Mapped(&['\u{30B5}', '\u{30A4}', '\u{30AF}', '\u{30EB}'])),
+    ('\u{3320}', '\u{3320}', Mapping::Mapped(&['\u{30B5}', '\u{30F3}', '\u{30C1}', '\u{30FC}', '\u{30E0}'])),
+    ('\u{3321}', '\u{3321}', Mapping::Mapped(&['\u{30B7}', '\u{30EA}', '\u{30F3}', '\u{30B0}'])),
+    ('\u{3322}', '\u{3322}', Mapping::Mapped(&['\u{30BB}', '\u{30F3}', '\u{30C1}'])),
+    ('\u{3323}', '\u{3323}', Mapping::Mapped(&['\u{30BB}', '\u{30F3}', '\u{30C8}'])),
+    ('\u{3324}', '\u{3324}', Mapping::Mapped(&['\u{30C0}', '\u{30FC}', '\u{30B9}'])),
+    ('\u{3325}', '\u{3325}', Mapping::Mapped(&['\u{30C7}', '\u{30B7}'])),
+    ('\u{3326}', '\u{3326}', Mapping::Mapped(&['\u{30C9}', '\u{30EB}'])),
+    ('\u{3327}', '\u{3327}', Mapping::Mapped(&['\u{30C8}', '\u{30F3}'])),
+    ('\u{3328}', '\u{3328}', Mapping::Mapped(&['\u{30CA}', '\u{30CE}'])),
+    ('\u{3329}', '\u{3329}', Mapping::Mapped(&['\u{30CE}', '\u{30C3}', '\u{30C8}'])),
+    ('\u{332A}', '\u{332A}', Mapping::Mapped(&['\u{30CF}', '\u{30A4}', '\u{30C4}'])),
+    ('\u{332B}', '\u{332B}', Mapping::Mapped(&['\u{30D1}', '\u{30FC}', '\u{30BB}', '\u{30F3}', '\u{30C8}'])),
+    ('\u{332C}', '\u{332C}', Mapping::Mapped(&['\u{30D1}', '\u{30FC}', '\u{30C4}'])),
+    ('\u{332D}', '\u{332D}', Mapping::Mapped(&['\u{30D0}', '\u{30FC}', '\u{30EC}', '\u{30EB}'])),
+    ('\u{332E}', '\u{332E}', Mapping::Mapped(&['\u{30D4}', '\u{30A2}', '\u{30B9}', '\u{30C8}', '\u{30EB}'])),
+    ('\u{332F}', '\u{332F}', Mapping::Mapped(&['\u{30D4}', '\u{30AF}', '\u{30EB}'])),
+    ('\u{3330}', '\u{3330}', Mapping::Mapped(&['\u{30D4}', '\u{30B3}'])),
+    ('\u{3331}', '\u{3331}', Mapping::Mapped(&['\u{30D3}', '\u{30EB}'])),
+    ('\u{3332}', '\u{3332}', Mapping::Mapped(&['\u{30D5}', '\u{30A1}', '\u{30E9}', '\u{30C3}', '\u{30C9}'])),
+    ('\u{3333}', '\u{3333}', Mapping::Mapped(&['\u{30D5}', '\u{30A3}', '\u{30FC}', '\u{30C8}'])),
+    ('\u{3334}', '\u{3334}', Mapping::Mapped(&['\u{30D6}', '\u{30C3}', '\u{30B7}', '\u{30A7}', '\u{30EB}'])),
+    ('\u{3335}', '\u{3335}', Mapping::Mapped(&['\u{30D5}', '\u{30E9}', '\u{30F3}'])),
+    ('\u{3336}', '\u{3336}', Mapping::Mapped(&['\u{30D8}', '\u{30AF}', '\u{30BF}', '\u{30FC}', '\u{30EB}'])),
+    ('\u{3337}', '\u{3337}', Mapping::Mapped(&['\u{30DA}', '\u{30BD}'])),
+    ('\u{3338}', '\u{3338}', Mapping::Mapped(&['\u{30DA}', '\u{30CB}', '\u{30D2}'])),
+    ('\u{3339}', '\u{3339}', Mapping::Mapped(&['\u{30D8}', '\u{30EB}', '\u{30C4}'])),
+    ('\u{333A}', '\u{333A}', Mapping::Mapped(&['\u{30DA}', '\u{30F3}', '\u{30B9}'])),
+    ('\u{333B}', '\u{333B}', Mapping::Mapped(&['\u{30DA}', '\u{30FC}', '\u{30B8}'])),
+    ('\u{333C}', '\u{333C}', Mapping::Mapped(&['\u{30D9}', '\u{30FC}', '\u{30BF}'])),
+    ('\u{333D}', '\u{333D}', Mapping::Mapped(&['\u{30DD}', '\u{30A4}', '\u{30F3}', '\u{30C8}'])),
+    ('\u{333E}', '\u{333E}', Mapping::Mapped(&['\u{30DC}', '\u{30EB}', '\u{30C8}'])),
+    ('\u{333F}', '\u{333F}', Mapping::Mapped(&['\u{30DB}', '\u{30F3}'])),
+    ('\u{3340}', '\u{3340}', Mapping::Mapped(&['\u{30DD}', '\u{30F3}', '\u{30C9}'])),
+    ('\u{3341}', '\u{3341}', Mapping::Mapped(&['\u{30DB}', '\u{30FC}', '\u{30EB}'])),
+    ('\u{3342}', '\u{3342}', Mapping::Mapped(&['\u{30DB}', '\u{30FC}', '\u{30F3}'])),
+    ('\u{3343}', '\u{3343}', Mapping::Mapped(&['\u{30DE}', '\u{30A4}', '\u{30AF}', '\u{30ED}'])),
+    ('\u{3344}', '\u{3344}', Mapping::Mapped(&['\u{30DE}', '\u{30A4}', '\u{30EB}'])),
+    ('\u{3345}', '\u{3345}', Mapping::Mapped(&['\u{30DE}', '\u{30C3}', '\u{30CF}'])),
+    ('\u{3346}', '\u{3346}', Mapping::Mapped(&['\u{30DE}', '\u{30EB}', '\u{30AF}'])),
+    ('\u{3347}', '\u{3347}', Mapping::Mapped(&['\u{30DE}', '\u{30F3}', '\u{30B7}', '\u{30E7}', '\u{30F3}'])),
+    ('\u{3348}', '\u{3348}', Mapping::Mapped(&['\u{30DF}', '\u{30AF}', '\u{30ED}', '\u{30F3}'])),
+    ('\u{3349}', '\u{3349}', Mapping::Mapped(&['\u{30DF}', '\u{30EA}'])),
+    ('\u{334A}', '\u{334A}', Mapping::Mapped(&['\u{30DF}', '\u{30EA}', '\u{30D0}', '\u{30FC}', '\u{30EB}'])),
+    ('\u{334B}', '\u{334B}', Mapping::Mapped(&['\u{30E1}', '\u{30AC}'])),
+    ('\u{334C}', '\u{334C}', Mapping::Mapped(&['\u{30E1}', '\u{30AC}', '\u{30C8}', '\u{30F3}'])),
+    ('\u{334D}', '\u{334D}', Mapping::Mapped(&['\u{30E1}', '\u{30FC}', '\u{30C8}', '\u{30EB}'])),
+    ('\u{334E}', '\u{334E}', Mapping::Mapped(&['\u{30E4}', '\u{30FC}', '\u{30C9}'])),
+    ('\u{334F}', '\u{334F}', Mapping::Mapped(&['\u{30E4}', '\u{30FC}', '\u{30EB}'])),
+    ('\u{3350}', '\u{3350}', Mapping::Mapped(&['\u{30E6}', '\u{30A2}', '\u{30F3}'])),
+    ('\u{3351}', '\u{3351}', Mapping::Mapped(&['\u{30EA}', '\u{30C3}', '\u{30C8}', '\u{30EB}'])),
+    ('\u{3352}', '\u{3352}', Mapping::Mapped(&['\u{30EA}', '\u{30E9}'])),
+    ('\u{3353}', '\u{3353}', Mapping::Mapped(&['\u{30EB}', '\u{30D4}', '\u{30FC}'])),
+    ('\u{3354}', '\u{3354}', Mapping::Mapped(&['\u{30EB}', '\u{30FC}', '\u{30D6}', '\u{30EB}'])),
+    ('\u{3355}', '\u{3355}', Mapping::Mapped(&['\u{30EC}', '\u{30E0}'])),
+    ('\u{3356}', '\u{3356}', Mapping::Mapped(&['\u{30EC}', '\u{30F3}', '\u{30C8}', '\u{30B2}', '\u{30F3}'])),
+    ('\u{3357}', '\u{3357}', Mapping::Mapped(&['\u{30EF}', '\u{30C3}', '\u{30C8}'])),
+    ('\u{3358}', '\u{3358}', Mapping::Mapped(&['\u{30}', '\u{70B9}'])),
+    ('\u{3359}', '\u{3359}', Mapping::Mapped(&['\u{31}', '\u{70B9}'])),
+    ('\u{335A}', '\u{335A}', Mapping::Mapped(&['\u{32}', '\u{70B9}'])),
+    ('\u{335B}', '\u{335B}', Mapping::Mapped(&['\u{33}', '\u{70B9}'])),
+    ('\u{335C}', '\u{335C}', Mapping::Mapped(&['\u{34}', '\u{70B9}'])),
+    ('\u{335D}', '\u{335D}', Mapping::Mapped(&['\u{35}', '\u{70B9}'])),
+    ('\u{335E}', '\u{335E}', Mapping::Mapped(&['\u{36}', '\u{70B9}'])),
+    ('\u{335F}', '\u{335F}', Mapping::Mapped(&['\u{37}', '\u{70B9}'])),
+    ('\u{3360}', '\u{3360}', Mapping::Mapped(&['\u{38}', '\u{70B9}'])),
+    ('\u{3361}', '\u{3361}', Mapping::Mapped(&['\u{39}', '\u{70B9}'])),
+    ('\u{3362}', '\u{3362}', Mapping::Mapped(&['\u{31}', '\u{30}', '\u{70B9}'])),
+    ('\u{3363}', '\u{3363}', Mapping::Mapped(&['\u{31}', '\u{31}', '\u{70B9}'])),
+    ('\u{3364}', '\u{3364}', Mapping::Mapped(&['\u{31}', '\u{32}', '\u{70B9}'])),
+    ('\u{3365}', '\u{3365}', Mapping::Mapped(&['\u{31}', '\u{33}', '\u{70B9}'])),
+    ('\u{3366}', '\u{3366}', Mapping::Mapped(&['\u{31}', '\u{34}', '\u{70B9}'])),
+    ('\u{3367}', '\u{3367}', Mapping::Mapped(&['\u{31}', '\u{35}', '\u{70B9}'])),
+    ('\u{3368}', '\u{3368}', Mapping::Mapped(&['\u{31}', '\u{36}', '\u{70B9}'])),
+    ('\u{3369}', '\u{3369}', Mapping::Mapped(&['\u{31}', '\u{37}', '\u{70B9}'])),
+    ('\u{336A}', '\u{336A}', Mapping::Mapped(&['\u{31}', '\u{38}', '\u{70B9}'])),
+    ('\u{336B}', '\u{336B}', Mapping::Mapped(&['\u{31}', '\u{39}', '\u{70B9}'])),
+    ('\u{336C}', '\u{336C}', Mapping::Mapped(&['\u{32}', '\u{30}', '\u{70B9}'])),
+    ('\u{336D}', '\u{336D}', Mapping::Mapped(&['\u{32}', '\u{31}', '\u{70B9}'])),
+    ('\u{336E}', '\u{336E}', Mapping::Mapped(&['\u{32}', '\u{32}', '\u{70B9}'])),
+    ('\u{336F}', '\u{336F}', Mapping::Mapped(&['\u{32}', '\u{33}', '\u{70B9}'])),
+    ('\u{3370}', '\u{3370}', Mapping::Mapped(&['\u{32}', '\u{34}', '\u{70B9}'])),
+    ('\u{3371}', '\u{3371}', Mapping::Mapped(&['\u{68}', '\u{70}', '\u{61}'])),
+    ('\u{3372}', '\u{3372}', Mapping::Mapped(&['\u{64}', '\u{61}'])),
+    ('\u{3373}', '\u{3373}', Mapping::Mapped(&['\u{61}', '\u{75}'])),
+    ('\u{3374}', '\u{3374}', Mapping::Mapped(&['\u{62}', '\u{61}', '\u{72}'])),
+    ('\u{3375}', '\u{3375}', Mapping::Mapped(&['\u{6F}', '\u{76}'])),
+    ('\u{3376}', '\u{3376}', Mapping::Mapped(&['\u{70}', '\u{63}'])),
+    ('\u{3377}', '\u{3377}', Mapping::Mapped(&['\u{64}', '\u{6D}'])),
+    ('\u{3378}', '\u{3378}', Mapping::Mapped(&['\u{64}', '\u{6D}', '\u{32}'])),
+    ('\u{3379}', '\u{3379}', Mapping::Mapped(&['\u{64}', '\u{6D}', '\u{33}'])),
+    ('\u{337A}', '\u{337A}', Mapping::Mapped(&['\u{69}', '\u{75}'])),
+    ('\u{337B}', '\u{337B}', Mapping::Mapped(&['\u{5E73}', '\u{6210}'])),
+    ('\u{337C}', '\u{337C}', Mapping::Mapped(&['\u{662D}', '\u{548C}'])),
+    ('\u{337D}', '\u{337D}', Mapping::Mapped(&['\u{5927}', '\u{6B63}'])),
+    ('\u{337E}', '\u{337E}', Mapping::Mapped(&['\u{660E}', '\u{6CBB}'])),
+    ('\u{337F}', '\u{337F}', Mapping::Mapped(&['\u{682A}', '\u{5F0F}', '\u{4F1A}', '\u{793E}'])),
+    ('\u{3380}', '\u{3380}', Mapping::Mapped(&['\u{70}', '\u{61}'])),
+    ('\u{3381}', '\u{3381}', Mapping::Mapped(&['\u{6E}', '\u{61}'])),
+    ('\u{3382}', '\u{3382}', Mapping::Mapped(&['\u{3BC}', '\u{61}'])),
+    ('\u{3383}', '\u{3383}', Mapping::Mapped(&['\u{6D}', '\u{61}'])),
+    ('\u{3384}', '\u{3384}', Mapping::Mapped(&['\u{6B}', '\u{61}'])),
+    ('\u{3385}', '\u{3385}', Mapping::Mapped(&['\u{6B}', '\u{62}'])),
+    ('\u{3386}', '\u{3386}', Mapping::Mapped(&['\u{6D}', '\u{62}'])),
+    ('\u{3387}', '\u{3387}', Mapping::Mapped(&['\u{67}', '\u{62}'])),
+    ('\u{3388}', '\u{3388}', Mapping::Mapped(&['\u{63}', '\u{61}', '\u{6C}'])),
+    ('\u{3389}', '\u{3389}', Mapping::Mapped(&['\u{6B}', '\u{63}', '\u{61}', '\u{6C}'])),
+    ('\u{338A}', '\u{338A}', Mapping::Mapped(&['\u{70}', '\u{66}'])),
+    ('\u{338B}', '\u{338B}', Mapping::Mapped(&['\u{6E}', '\u{66}'])),
+    ('\u{338C}', '\u{338C}', Mapping::Mapped(&['\u{3BC}', '\u{66}'])),
+    ('\u{338D}', '\u{338D}', Mapping::Mapped(&['\u{3BC}', '\u{67}'])),
+    ('\u{338E}', '\u{338E}', Mapping::Mapped(&['\u{6D}', '\u{67}'])),
+    ('\u{338F}', '\u{338F}', Mapping::Mapped(&['\u{6B}', '\u{67}'])),
+    ('\u{3390}', '\u{3390}', Mapping::Mapped(&['\u{68}', '\u{7A}'])),
+    ('\u{3391}', '\u{3391}', Mapping::Mapped(&['\u{6B}', '\u{68}', '\u{7A}'])),
+    ('\u{3392}', '\u{3392}', Mapping::Mapped(&['\u{6D}', '\u{68}', '\u{7A}'])),
+    ('\u{3393}', '\u{3393}', Mapping::Mapped(&['\u{67}', '\u{68}', '\u{7A}'])),
+    ('\u{3394}', '\u{3394}', Mapping::Mapped(&['\u{74}', '\u{68}', '\u{7A}'])),
+    ('\u{3395}', '\u{3395}', Mapping::Mapped(&['\u{3BC}', '\u{6C}'])),
+    ('\u{3396}', '\u{3396}', Mapping::Mapped(&['\u{6D}', '\u{6C}'])),
+    ('\u{3397}', '\u{3397}', Mapping::Mapped(&['\u{64}', '\u{6C}'])),
+    ('\u{3398}', '\u{3398}', Mapping::Mapped(&['\u{6B}', '\u{6C}'])),
+    ('\u{3399}', '\u{3399}', Mapping::Mapped(&['\u{66}', '\u{6D}'])),
+    ('\u{339A}', '\u{339A}', Mapping::Mapped(&['\u{6E}', '\u{6D}'])),
+    ('\u{339B}', '\u{339B}', Mapping::Mapped(&['\u{3BC}', '\u{6D}'])),
+    ('\u{339C}', '\u{339C}', Mapping::Mapped(&['\u{6D}', '\u{6D}'])),
+    ('\u{339D}', '\u{339D}', Mapping::Mapped(&['\u{63}', '\u{6D}'])),
+    ('\u{339E}', '\u{339E}', Mapping::Mapped(&['\u{6B}', '\u{6D}'])),
+    ('\u{339F}', '\u{339F}', Mapping::Mapped(&['\u{6D}', '\u{6D}', '\u{32}'])),
+    ('\u{33A0}', '\u{33A0}', Mapping::Mapped(&['\u{63}', '\u{6D}', '\u{32}'])),
+    ('\u{33A1}', '\u{33A1}', Mapping::Mapped(&['\u{6D}', '\u{32}'])),
+    ('\u{33A2}', '\u{33A2}', Mapping::Mapped(&['\u{6B}', '\u{6D}', '\u{32}'])),
+    ('\u{33A3}', '\u{33A3}', Mapping::Mapped(&['\u{6D}', '\u{6D}', '\u{33}'])),
+    ('\u{33A4}', '\u{33A4}', Mapping::Mapped(&['\u{63}', '\u{6D}', '\u{33}'])),
+    ('\u{33A5}', '\u{33A5}', Mapping::Mapped(&['\u{6D}', '\u{33}'])),
+    ('\u{33A6}', '\u{33A6}', Mapping::Mapped(&['\u{6B}', '\u{6D}', '\u{33}'])),
+    ('\u{33A7}', '\u{33A7}', Mapping::Mapped(&['\u{6D}', '\u{2215}', '\u{73}'])),
+    ('\u{33A8}', '\u{33A8}', Mapping::Mapped(&['\u{6D}', '\u{2215}', '\u{73}', '\u{32}'])),
+    ('\u{33A9}', '\u{33A9}', Mapping::Mapped(&['\u{70}', '\u{61}'])),
+    ('\u{33AA}', '\u{33AA}', Mapping::Mapped(&['\u{6B}', '\u{70}', '\u{61}'])),
+    ('\u{33AB}', '\u{33AB}', Mapping::Mapped(&['\u{6D}', '\u{70}', '\u{61}'])),
+    ('\u{33AC}', '\u{33AC}', Mapping::Mapped(&['\u{67}', '\u{70}', '\u{61}'])),
+    ('\u{33AD}', '\u{33AD}', Mapping::Mapped(&['\u{72}', '\u{61}', '\u{64}'])),
+    ('\u{33AE}', '\u{33AE}', Mapping::Mapped(&['\u{72}', '\u{61}', '\u{64}', '\u{2215}', '\u{73}'])),
+    ('\u{33AF}', '\u{33AF}', Mapping::Mapped(&['\u{72}', '\u{61}', '\u{64}', '\u{2215}', '\u{73}', '\u{32}'])),
+    ('\u{33B0}', '\u{33B0}', Mapping::Mapped(&['\u{70}', '\u{73}'])),
+    ('\u{33B1}', '\u{33B1}', Mapping::Mapped(&['\u{6E}', '\u{73}'])),
+    ('\u{33B2}', '\u{33B2}', Mapping::Mapped(&['\u{3BC}', '\u{73}'])),
+    ('\u{33B3}', '\u{33B3}', Mapping::Mapped(&['\u{6D}', '\u{73}'])),
+    ('\u{33B4}', '\u{33B4}', Mapping::Mapped(&['\u{70}', '\u{76}'])),
+    ('\u{33B5}', '\u{33B5}', Mapping::Mapped(&['\u{6E}', '\u{76}'])),
+    ('\u{33B6}', '\u{33B6}', Mapping::Mapped(&['\u{3BC}', '\u{76}'])),
+    ('\u{33B7}', '\u{33B7}', Mapping::Mapped(&['\u{6D}', '\u{76}'])),
+    ('\u{33B8}', '\u{33B8}', Mapping::Mapped(&['\u{6B}', '\u{76}'])),
+    ('\u{33B9}', '\u{33B9}', Mapping::Mapped(&['\u{6D}', '\u{76}'])),
+    ('\u{33BA}', '\u{33BA}', Mapping::Mapped(&['\u{70}', '\u{77}'])),
+    ('\u{33BB}', '\u{33BB}', Mapping::Mapped(&['\u{6E}', '\u{77}'])),
+    ('\u{33BC}', '\u{33BC}', Mapping::Mapped(&['\u{3BC}', '\u{77}'])),
+    ('\u{33BD}', '\u{33BD}', Mapping::Mapped(&['\u{6D}', '\u{77}'])),
+    ('\u{33BE}', '\u{33BE}', Mapping::Mapped(&['\u{6B}', '\u{77}'])),
+    ('\u{33BF}', '\u{33BF}', Mapping::Mapped(&['\u{6D}', '\u{77}'])),
+    ('\u{33C0}', '\u{33C0}', Mapping::Mapped(&['\u{6B}', '\u{3C9}'])),
+    ('\u{33C1}', '\u{33C1}', Mapping::Mapped(&['\u{6D}', '\u{3C9}'])),
+    ('\u{33C2}', '\u{33C2}', Mapping::Disallowed),
+    ('\u{33C3}', '\u{33C3}', Mapping::Mapped(&['\u{62}', '\u{71}'])),
+    ('\u{33C4}', '\u{33C4}', Mapping::Mapped(&['\u{63}', '\u{63}'])),
+    ('\u{33C5}', '\u{33C5}', Mapping::Mapped(&['\u{63}', '\u{64}'])),
+    ('\u{33C6}', '\u{33C6}', Mapping::Mapped(&['\u{63}', '\u{2215}', '\u{6B}', '\u{67}'])),
+    ('\u{33C7}', '\u{33C7}', Mapping::Disallowed),
+    ('\u{33C8}', '\u{33C8}', Mapping::Mapped(&['\u{64}', '\u{62}'])),
+    ('\u{33C9}', '\u{33C9}', Mapping::Mapped(&['\u{67}', '\u{79}'])),
+    ('\u{33CA}', '\u{33CA}', Mapping::Mapped(&['\u{68}', '\u{61}'])),
+    ('\u{33CB}', '\u{33CB}', Mapping::Mapped(&['\u{68}', '\u{70}'])),
+    ('\u{33CC}', '\u{33CC}', Mapping::Mapped(&['\u{69}', '\u{6E}'])),
+    ('\u{33CD}', '\u{33CD}', Mapping::Mapped(&['\u{6B}', '\u{6B}'])),
+    ('\u{33CE}', '\u{33CE}', Mapping::Mapped(&['\u{6B}', '\u{6D}'])),
+    ('\u{33CF}', '\u{33CF}', Mapping::Mapped(&['\u{6B}', '\u{74}'])),
+    ('\u{33D0}', '\u{33D0}', Mapping::Mapped(&['\u{6C}', '\u{6D}'])),
+    ('\u{33D1}', '\u{33D1}', Mapping::Mapped(&['\u{6C}', '\u{6E}'])),
+    ('\u{33D2}', '\u{33D2}', Mapping::Mapped(&['\u{6C}', '\u{6F}', '\u{67}'])),
+    ('\u{33D3}', '\u{33D3}', Mapping::Mapped(&['\u{6C}', '\u{78}'])),
+    ('\u{33D4}', '\u{33D4}', Mapping::Mapped(&['\u{6D}', '\u{62}'])),
+    ('\u{33D5}', '\u{33D5}', Mapping::Mapped(&['\u{6D}', '\u{69}', '\u{6C}'])),
+    ('\u{33D6}', '\u{33D6}', Mapping::Mapped(&['\u{6D}', '\u{6F}', '\u{6C}'])),
+    ('\u{33D7}', '\u{33D7}', Mapping::Mapped(&['\u{70}', '\u{68}'])),
+    ('\u{33D8}', '\u{33D8}', Mapping::Disallowed),
+    ('\u{33D9}', '\u{33D9}', Mapping::Mapped(&['\u{70}', '\u{70}', '\u{6D}'])),
+    ('\u{33DA}', '\u{33DA}', Mapping::Mapped(&['\u{70}', '\u{72}'])),
+    ('\u{33DB}', '\u{33DB}', Mapping::Mapped(&['\u{73}', '\u{72}'])),
+    ('\u{33DC}', '\u{33DC}', Mapping::Mapped(&['\u{73}', '\u{76}'])),
+    ('\u{33DD}', '\u{33DD}', Mapping::Mapped(&['\u{77}', '\u{62}'])),
+    ('\u{33DE}', '\u{33DE}', Mapping::Mapped(&['\u{76}', '\u{2215}', '\u{6D}'])),
+    ('\u{33DF}', '\u{33DF}', Mapping::Mapped(&['\u{61}', '\u{2215}', '\u{6D}'])),
+    ('\u{33E0}', '\u{33E0}', Mapping::Mapped(&['\u{31}', '\u{65E5}'])),
+    ('\u{33E1}', '\u{33E1}', Mapping::Mapped(&['\u{32}', '\u{65E5}'])),
+    ('\u{33E2}', '\u{33E2}', Mapping::Mapped(&['\u{33}', '\u{65E5}'])),
+    ('\u{33E3}', '\u{33E3}', Mapping::Mapped(&['\u{34}', '\u{65E5}'])),
+    ('\u{33E4}', '\u{33E4}', Mapping::Mapped(&['\u{35}', '\u{65E5}'])),
+    ('\u{33E5}', '\u{33E5}', Mapping::Mapped(&['\u{36}', '\u{65E5}'])),
+    ('\u{33E6}', '\u{33E6}', Mapping::Mapped(&['\u{37}', '\u{65E5}'])),
+    ('\u{33E7}', '\u{33E7}', Mapping::Mapped(&['\u{38}', '\u{65E5}'])),
+    ('\u{33E8}', '\u{33E8}', Mapping::Mapped(&['\u{39}', '\u{65E5}'])),
+    ('\u{33E9}', '\u{33E9}', Mapping::Mapped(&['\u{31}', '\u{30}', '\u{65E5}'])),
+    ('\u{33EA}', '\u{33EA}', Mapping::Mapped(&['\u{31}', '\u{31}', '\u{65E5}'])),
+    ('\u{33EB}', '\u{33EB}', Mapping::Mapped(&['\u{31}', '\u{32}', '\u{65E5}'])),
+    ('\u{33EC}', '\u{33EC}', Mapping::Mapped(&['\u{31}', '\u{33}', '\u{65E5}'])),
+    ('\u{33ED}', '\u{33ED}', Mapping::Mapped(&['\u{31}', '\u{34}', '\u{65E5}'])),
+    ('\u{33EE}', '\u{33EE}', Mapping::Mapped(&['\u{31}', '\u{35}', '\u{65E5}'])),
+    ('\u{33EF}', '\u{33EF}', Mapping::Mapped(&['\u{31}', '\u{36}', '\u{65E5}'])),
+    ('\u{33F0}', '\u{33F0}', Mapping::Mapped(&['\u{31}', '\u{37}', '\u{65E5}'])),
+    ('\u{33F1}', '\u{33F1}', Mapping::Mapped(&['\u{31}', '\u{38}', '\u{65E5}'])),
+    ('\u{33F2}', '\u{33F2}', Mapping::Mapped(&['\u{31}', '\u{39}', '\u{65E5}'])),
+    ('\u{33F3}', '\u{33F3}', Mapping::Mapped(&['\u{32}', '\u{30}', '\u{65E5}'])),
+    ('\u{33F4}', '\u{33F4}', Mapping::Mapped(&['\u{32}', '\u{31}', '\u{65E5}'])),
+    ('\u{33F5}', '\u{33F5}', Mapping::Mapped(&['\u{32}', '\u{32}', '\u{65E5}'])),
+    ('\u{33F6}', '\u{33F6}', Mapping::Mapped(&['\u{32}', '\u{33}', '\u{65E5}'])),
+    ('\u{33F7}', '\u{33F7}', Mapping::Mapped(&['\u{32}', '\u{34}', '\u{65E5}'])),
+    ('\u{33F8}', '\u{33F8}', Mapping::Mapped(&['\u{32}', '\u{35}', '\u{65E5}'])),
+    ('\u{33F9}', '\u{33F9}', Mapping::Mapped(&['\u{32}', '\u{36}', '\u{65E5}'])),
+    ('\u{33FA}', '\u{33FA}', Mapping::Mapped(&['\u{32}', '\u{37}', '\u{65E5}'])),
+    ('\u{33FB}', '\u{33FB}', Mapping::Mapped(&['\u{32}', '\u{38}', '\u{65E5}'])),
+    ('\u{33FC}', '\u{33FC}', Mapping::Mapped(&['\u{32}', '\u{39}', '\u{65E5}'])),
+    ('\u{33FD}', '\u{33FD}', Mapping::Mapped(&['\u{33}', '\u{30}', '\u{65E5}'])),
+    ('\u{33FE}', '\u{33FE}', Mapping::Mapped(&['\u{33}', '\u{31}', '\u{65E5}'])),
+    ('\u{33FF}', '\u{33FF}', Mapping::Mapped(&['\u{67}', '\u{61}', '\u{6C}'])),
+    ('\u{3400}', '\u{9FFC}', Mapping::Valid),
+    ('\u{9FFD}', '\u{9FFF}', Mapping::Disallowed),
+    ('\u{A000}', '\u{A48C}', Mapping::Valid),
+    ('\u{A48D}', '\u{A48F}', Mapping::Disallowed),
+    ('\u{A490}', '\u{A4C6}', Mapping::Valid),
+    ('\u{A4C7}', '\u{A4CF}', Mapping::Disallowed),
+    ('\u{A4D0}', '\u{A62B}', Mapping::Valid),
+    ('\u{A62C}', '\u{A63F}', Mapping::Disallowed),
+    ('\u{A640}', '\u{A640}', Mapping::Mapped(&['\u{A641}'])),
+    ('\u{A641}', '\u{A641}', Mapping::Valid),
+    ('\u{A642}', '\u{A642}', Mapping::Mapped(&['\u{A643}'])),
+    ('\u{A643}', '\u{A643}', Mapping::Valid),
+    ('\u{A644}', '\u{A644}', Mapping::Mapped(&['\u{A645}'])),
+    ('\u{A645}', '\u{A645}', Mapping::Valid),
+    ('\u{A646}', '\u{A646}', Mapping::Mapped(&['\u{A647}'])),
+    ('\u{A647}', '\u{A647}', Mapping::Valid),
+    ('\u{A648}', '\u{A648}', Mapping::Mapped(&['\u{A649}'])),
+    ('\u{A649}', '\u{A649}', Mapping::Valid),
+    ('\u{A64A}', '\u{A64A}', Mapping::Mapped(&['\u{A64B}'])),
+    ('\u{A64B}', '\u{A64B}', Mapping::Valid),
+    ('\u{A64C}', '\u{A64C}', Mapping::Mapped(&['\u{A64D}'])),
+    ('\u{A64D}', '\u{A64D}', Mapping::Valid),
+    ('\u{A64E}', '\u{A64E}', Mapping::Mapped(&['\u{A64F}'])),
+    ('\u{A64F}', '\u{A64F}', Mapping::Valid),
+    ('\u{A650}', '\u{A650}', Mapping::Mapped(&['\u{A651}'])),
+    ('\u{A651}', '\u{A651}', Mapping::Valid),
+    ('\u{A652}', '\u{A652}', Mapping::Mapped(&['\u{A653}'])),
+    ('\u{A653}', '\u{A653}', Mapping::Valid),
+    ('\u{A654}', '\u{A654}', Mapping::Mapped(&['\u{A655}'])),
+    ('\u{A655}', '\u{A655}', Mapping::Valid),
+    ('\u{A656}', '\u{A656}', Mapping::Mapped(&['\u{A657}'])),
+    ('\u{A657}', '\u{A657}', Mapping::Valid),
+    ('\u{A658}', '\u{A658}', Mapping::Mapped(&['\u{A659}'])),
+    ('\u{A659}', '\u{A659}', Mapping::Valid),
+    ('\u{A65A}', '\u{A65A}', Mapping::Mapped(&['\u{A65B}'])),
+    ('\u{A65B}', '\u{A65B}', Mapping::Valid),
+    ('\u{A65C}', '\u{A65C}', Mapping::Mapped(&['\u{A65D}'])),
+    ('\u{A65D}', '\u{A65D}', Mapping::Valid),
+    ('\u{A65E}', '\u{A65E}', Mapping::Mapped(&['\u{A65F}'])),
+    ('\u{A65F}', '\u{A65F}', Mapping::Valid),
+    ('\u{A660}', '\u{A660}', Mapping::Mapped(&['\u{A661}'])),
+    ('\u{A661}', '\u{A661}', Mapping::Valid),
+    ('\u{A662}', '\u{A662}', Mapping::Mapped(&['\u{A663}'])),
+    ('\u{A663}', '\u{A663}', Mapping::Valid),
+    ('\u{A664}', '\u{A664}', Mapping::Mapped(&['\u{A665}'])),
+    ('\u{A665}', '\u{A665}', Mapping::Valid),
+    ('\u{A666}', '\u{A666}', Mapping::Mapped(&['\u{A667}'])),
+    ('\u{A667}', '\u{A667}', Mapping::Valid),
+    ('\u{A668}', '\u{A668}', Mapping::Mapped(&['\u{A669}'])),
+    ('\u{A669}', '\u{A669}', Mapping::Valid),
+    ('\u{A66A}', '\u{A66A}', Mapping::Mapped(&['\u{A66B}'])),
+    ('\u{A66B}', '\u{A66B}', Mapping::Valid),
+    ('\u{A66C}', '\u{A66C}', Mapping::Mapped(&['\u{A66D}'])),
+    ('\u{A66D}', '\u{A67F}', Mapping::Valid),
+    ('\u{A680}', '\u{A680}', Mapping::Mapped(&['\u{A681}'])),
+    ('\u{A681}', '\u{A681}', Mapping::Valid),
+    ('\u{A682}', '\u{A682}', Mapping::Mapped(&['\u{A683}'])),
+    ('\u{A683}', '\u{A683}', Mapping::Valid),
+    ('\u{A684}', '\u{A684}', Mapping::Mapped(&['\u{A685}'])),
+    ('\u{A685}', '\u{A685}', Mapping::Valid),
+    ('\u{A686}', '\u{A686}', Mapping::Mapped(&['\u{A687}'])),
+    ('\u{A687}', '\u{A687}', Mapping::Valid),
+    ('\u{A688}', '\u{A688}', Mapping::Mapped(&['\u{A689}'])),
+    ('\u{A689}', '\u{A689}', Mapping::Valid),
+    ('\u{A68A}', '\u{A68A}', Mapping::Mapped(&['\u{A68B}'])),
+    ('\u{A68B}', '\u{A68B}', Mapping::Valid),
+    ('\u{A68C}', '\u{A68C}', Mapping::Mapped(&['\u{A68D}'])),
+    ('\u{A68D}', '\u{A68D}', Mapping::Valid),
+    ('\u{A68E}', '\u{A68E}', Mapping::Mapped(&['\u{A68F}'])),
+    ('\u{A68F}', '\u{A68F}', Mapping::Valid),
+    ('\u{A690}', '\u{A690}', Mapping::Mapped(&['\u{A691}'])),
+    ('\u{A691}', '\u{A691}', Mapping::Valid),
+    ('\u{A692}', '\u{A692}', Mapping::Mapped(&['\u{A693}'])),
+    ('\u{A693}', '\u{A693}', Mapping::Valid),
+    ('\u{A694}', '\u{A694}', Mapping::Mapped(&['\u{A695}'])),
+    ('\u{A695}', '\u{A695}', Mapping::Valid),
+    ('\u{A696}', '\u{A696}', Mapping::Mapped(&['\u{A697}'])),
+    ('\u{A697}', '\u{A697}', Mapping::Valid),
+    ('\u{A698}', '\u{A698}', Mapping::Mapped(&['\u{A699}'])),
+    ('\u{A699}', '\u{A699}', Mapping::Valid),
+    ('\u{A69A}', '\u{A69A}', Mapping::Mapped(&['\u{A69B}'])),
+    ('\u{A69B}', '\u{A69B}', Mapping::Valid),
+    ('\u{A69C}', '\u{A69C}', Mapping::Mapped(&['\u{44A}'])),
+    ('\u{A69D}', '\u{A69D}', Mapping::Mapped(&['\u{44C}'])),
+    ('\u{A69E}', '\u{A6F7}', Mapping::Valid),
+    ('\u{A6F8}', '\u{A6FF}', Mapping::Disallowed),
+    ('\u{A700}', '\u{A721}', Mapping::Valid),
+    ('\u{A722}', '\u{A722}', Mapping::Mapped(&['\u{A723}'])),
+    ('\u{A723}', '\u{A723}', Mapping::Valid),
+    ('\u{A724}', '\u{A724}', Mapping::Mapped(&['\u{A725}'])),
+    ('\u{A725}', '\u{A725}', Mapping::Valid),
+    ('\u{A726}', '\u{A726}', Mapping::Mapped(&['\u{A727}'])),
+    ('\u{A727}', '\u{A727}', Mapping::Valid),
+    ('\u{A728}', '\u{A728}', Mapping::Mapped(&['\u{A729}'])),
+    ('\u{A729}', '\u{A729}', Mapping::Valid),
+    ('\u{A72A}', '\u{A72A}', Mapping::Mapped(&['\u{A72B}'])),
+    ('\u{A72B}', '\u{A72B}', Mapping::Valid),
+    ('\u{A72C}', '\u{A72C}', Mapping::Mapped(&['\u{A72D}'])),
+    ('\u{A72D}', '\u{A72D}', Mapping::Valid),
+    ('\u{A72E}', '\u{A72E}', Mapping::Mapped(&['\u{A72F}'])),
+    ('\u{A72F}', '\u{A731}', Mapping::Valid),
+    ('\u{A732}', '\u{A732}', Mapping::Mapped(&['\u{A733}'])),
+    ('\u{A733}', '\u{A733}', Mapping::Valid),
+    ('\u{A734}', '\u{A734}', Mapping::Mapped(&['\u{A735}'])),
+    ('\u{A735}', '\u{A735}', Mapping::Valid),
+    ('\u{A736}', '\u{A736}', Mapping::Mapped(&['\u{A737}'])),
+    ('\u{A737}', '\u{A737}', Mapping::Valid),
+    ('\u{A738}', '\u{A738}', Mapping::Mapped(&['\u{A739}'])),
+    ('\u{A739}', '\u{A739}', Mapping::Valid),
+    ('\u{A73A}', '\u{A73A}', Mapping::Mapped(&['\u{A73B}'])),
+    ('\u{A73B}', '\u{A73B}', Mapping::Valid),
+    ('\u{A73C}', '\u{A73C}', Mapping::Mapped(&['\u{A73D}'])),
+    ('\u{A73D}', '\u{A73D}', Mapping::Valid),
+    ('\u{A73E}', '\u{A73E}', Mapping::Mapped(&['\u{A73F}'])),
+    ('\u{A73F}', '\u{A73F}', Mapping::Valid),
+    ('\u{A740}', '\u{A740}', Mapping::Mapped(&['\u{A741}'])),
+    ('\u{A741}', '\u{A741}', Mapping::Valid),
+    ('\u{A742}', '\u{A742}', Mapping::Mapped(&['\u{A743}'])),
+    ('\u{A743}', '\u{A743}', Mapping::Valid),
+    ('\u{A744}', '\u{A744}', Mapping::Mapped(&['\u{A745}'])),
+    ('\u{A745}', '\u{A745}', Mapping::Valid),
+    ('\u{A746}', '\u{A746}', Mapping::Mapped(&['\u{A747}'])),
+    ('\u{A747}', '\u{A747}', Mapping::Valid),
+    ('\u{A748}', '\u{A748}', Mapping::Mapped(&['\u{A749}'])),
+    ('\u{A749}', '\u{A749}', Mapping::Valid),
+    ('\u{A74A}', '\u{A74A}', Mapping::Mapped(&['\u{A74B}'])),
+    ('\u{A74B}', '\u{A74B}', Mapping::Valid),
+    ('\u{A74C}', '\u{A74C}', Mapping::Mapped(&['\u{A74D}'])),
+    ('\u{A74D}', '\u{A74D}', Mapping::Valid),
+    ('\u{A74E}', '\u{A74E}', Mapping::Mapped(&['\u{A74F}'])),
+    ('\u{A74F}', '\u{A74F}', Mapping::Valid),
+    ('\u{A750}', '\u{A750}', Mapping::Mapped(&['\u{A751}'])),
+    ('\u{A751}', '\u{A751}', Mapping::Valid),
+    ('\u{A752}', '\u{A752}', Mapping::Mapped(&['\u{A753}'])),
+    ('\u{A753}', '\u{A753}', Mapping::Valid),
+    ('\u{A754}', '\u{A754}', Mapping::Mapped(&['\u{A755}'])),
+    ('\u{A755}', '\u{A755}', Mapping::Valid),
+    ('\u{A756}', '\u{A756}', Mapping::Mapped(&['\u{A757}'])),
+    ('\u{A757}', '\u{A757}', Mapping::Valid),
+    ('\u{A758}', '\u{A758}', Mapping::Mapped(&['\u{A759}'])),
+    ('\u{A759}', '\u{A759}', Mapping::Valid),
+    ('\u{A75A}', '\u{A75A}', Mapping::Mapped(&['\u{A75B}'])),
+    ('\u{A75B}', '\u{A75B}', Mapping::Valid),
+    ('\u{A75C}', '\u{A75C}', Mapping::Mapped(&['\u{A75D}'])),
+    ('\u{A75D}', '\u{A75D}', Mapping::Valid),
+    ('\u{A75E}', '\u{A75E}', Mapping::Mapped(&['\u{A75F}'])),
+    ('\u{A75F}', '\u{A75F}', Mapping::Valid),
+    ('\u{A760}', '\u{A760}', Mapping::Mapped(&['\u{A761}'])),
+    ('\u{A761}', '\u{A761}', Mapping::Valid),
+    ('\u{A762}', '\u{A762}', Mapping::Mapped(&['\u{A763}'])),
+    ('\u{A763}', '\u{A763}', Mapping::Valid),
+    ('\u{A764}', '\u{A764}', Mapping::Mapped(&['\u{A765}'])),
+    ('\u{A765}', '\u{A765}', Mapping::Valid),
+    ('\u{A766}', '\u{A766}', Mapping::Mapped(&['\u{A767}'])),
+    ('\u{A767}', '\u{A767}', Mapping::Valid),
+    ('\u{A768}', '\u{A768}', Mapping::Mapped(&['\u{A769}'])),
+    ('\u{A769}', '\u{A769}', Mapping::Valid),
+    ('\u{A76A}', '\u{A76A}', Mapping::Mapped(&['\u{A76B}'])),
+    ('\u{A76B}', '\u{A76B}', Mapping::Valid),
+    ('\u{A76C}', '\u{A76C}', Mapping::Mapped(&['\u{A76D}'])),
+    ('\u{A76D}', '\u{A76D}', Mapping::Valid),
+    ('\u{A76E}', '\u{A76E}', Mapping::Mapped(&['\u{A76F}'])),
+    ('\u{A76F}', '\u{A76F}', Mapping::Valid),
+    ('\u{A770}', '\u{A770}', Mapping::Mapped(&['\u{A76F}'])),
+    ('\u{A771}', '\u{A778}', Mapping::Valid),
+    ('\u{A779}', '\u{A779}', Mapping::Mapped(&['\u{A77A}'])),
+    ('\u{A77A}', '\u{A77A}', Mapping::Valid),
+    ('\u{A77B}', '\u{A77B}', Mapping::Mapped(&['\u{A77C}'])),
+    ('\u{A77C}', '\u{A77C}', Mapping::Valid),
+    ('\u{A77D}', '\u{A77D}', Mapping::Mapped(&['\u{1D79}'])),
+    ('\u{A77E}', '\u{A77E}', Mapping::Mapped(&['\u{A77F}'])),
+    ('\u{A77F}', '\u{A77F}', Mapping::Valid),
+    ('\u{A780}', '\u{A780}', Mapping::Mapped(&['\u{A781}'])),
+    ('\u{A781}', '\u{A781}', Mapping::Valid),
+    ('\u{A782}', '\u{A782}', Mapping::Mapped(&['\u{A783}'])),
+    ('\u{A783}', '\u{A783}', Mapping::Valid),
+    ('\u{A784}', '\u{A784}', Mapping::Mapped(&['\u{A785}'])),
+    ('\u{A785}', '\u{A785}', Mapping::Valid),
+    ('\u{A786}', '\u{A786}', Mapping::Mapped(&['\u{A787}'])),
+    ('\u{A787}', '\u{A78A}', Mapping::Valid),
+    ('\u{A78B}', '\u{A78B}', Mapping::Mapped(&['\u{A78C}'])),
+    ('\u{A78C}', '\u{A78C}', Mapping::Valid),
+    ('\u{A78D}', '\u{A78D}', Mapping::Mapped(&['\u{265}'])),
+    ('\u{A78E}', '\u{A78F}', Mapping::Valid),
+    ('\u{A790}', '\u{A790}', Mapping::Mapped(&['\u{A791}'])),
+    ('\u{A791}', '\u{A791}', Mapping::Valid),
+    ('\u{A792}', '\u{A792}', Mapping::Mapped(&['\u{A793}'])),
+    ('\u{A793}', '\u{A795}', Mapping::Valid),
+    ('\u{A796}', '\u{A796}', Mapping::Mapped(&['\u{A797}'])),
+    ('\u{A797}', '\u{A797}', Mapping::Valid),
+    ('\u{A798}', '\u{A798}', Mapping::Mapped(&['\u{A799}'])),
+    ('\u{A799}', '\u{A799}', Mapping::Valid),
+    ('\u{A79A}', '\u{A79A}', Mapping::Mapped(&['\u{A79B}'])),
+    ('\u{A79B}', '\u{A79B}', Mapping::Valid),
+    ('\u{A79C}', '\u{A79C}', Mapping::Mapped(&['\u{A79D}'])),
+    ('\u{A79D}', '\u{A79D}', Mapping::Valid),
+    ('\u{A79E}', '\u{A79E}', Mapping::Mapped(&['\u{A79F}'])),
+    ('\u{A79F}', '\u{A79F}', Mapping::Valid),
+    ('\u{A7A0}', '\u{A7A0}', Mapping::Mapped(&['\u{A7A1}'])),
+    ('\u{A7A1}', '\u{A7A1}', Mapping::Valid),
+    ('\u{A7A2}', '\u{A7A2}', Mapping::Mapped(&['\u{A7A3}'])),
+    ('\u{A7A3}', '\u{A7A3}', Mapping::Valid),
+    ('\u{A7A4}', '\u{A7A4}', Mapping::Mapped(&['\u{A7A5}'])),
+    ('\u{A7A5}', '\u{A7A5}', Mapping::Valid),
+    ('\u{A7A6}', '\u{A7A6}', Mapping::Mapped(&['\u{A7A7}'])),
+    ('\u{A7A7}', '\u{A7A7}', Mapping::Valid),
+    ('\u{A7A8}', '\u{A7A8}', Mapping::Mapped(&['\u{A7A9}'])),
+    ('\u{A7A9}', '\u{A7A9}', Mapping::Valid),
+    ('\u{A7AA}', '\u{A7AA}', Mapping::Mapped(&['\u{266}'])),
+    ('\u{A7AB}', '\u{A7AB}', Mapping::Mapped(&['\u{25C}'])),
+    ('\u{A7AC}', '\u{A7AC}', Mapping::Mapped(&['\u{261}'])),
+    ('\u{A7AD}', '\u{A7AD}', Mapping::Mapped(&['\u{26C}'])),
+    ('\u{A7AE}', '\u{A7AE}', Mapping::Mapped(&['\u{26A}'])),
+    ('\u{A7AF}', '\u{A7AF}', Mapping::Valid),
+    ('\u{A7B0}', '\u{A7B0}', Mapping::Mapped(&['\u{29E}'])),
+    ('\u{A7B1}', '\u{A7B1}', Mapping::Mapped(&['\u{287}'])),
+    ('\u{A7B2}', '\u{A7B2}', Mapping::Mapped(&['\u{29D}'])),
+    ('\u{A7B3}', '\u{A7B3}', Mapping::Mapped(&['\u{AB53}'])),
+    ('\u{A7B4}', '\u{A7B4}', Mapping::Mapped(&['\u{A7B5}'])),
+    ('\u{A7B5}', '\u{A7B5}', Mapping::Valid),
+    ('\u{A7B6}', '\u{A7B6}', Mapping::Mapped(&['\u{A7B7}'])),
+    ('\u{A7B7}', '\u{A7B7}', Mapping::Valid),
+    ('\u{A7B8}', '\u{A7B8}', Mapping::Mapped(&['\u{A7B9}'])),
+    ('\u{A7B9}', '\u{A7B9}', Mapping::Valid),
+    ('\u{A7BA}', '\u{A7BA}', Mapping::Mapped(&['\u{A7BB}'])),
+    ('\u{A7BB}', '\u{A7BB}', Mapping::Valid),
+    ('\u{A7BC}', '\u{A7BC}', Mapping::Mapped(&['\u{A7BD}'])),
+    ('\u{A7BD}', '\u{A7BD}', Mapping::Valid),
+    ('\u{A7BE}', '\u{A7BE}', Mapping::Mapped(&['\u{A7BF}'])),
+    ('\u{A7BF}', '\u{A7BF}', Mapping::Valid),
+    ('\u{A7C0}', '\u{A7C1}', Mapping::Disallowed),
+    ('\u{A7C2}', '\u{A7C2}', Mapping::Mapped(&['\u{A7C3}'])),
+    ('\u{A7C3}', '\u{A7C3}', Mapping::Valid),
+    ('\u{A7C4}', '\u{A7C4}', Mapping::Mapped(&['\u{A794}'])),
+    ('\u{A7C5}', '\u{A7C5}', Mapping::Mapped(&['\u{282}'])),
+    ('\u{A7C6}', '\u{A7C6}', Mapping::Mapped(&['\u{1D8E}'])),
+    ('\u{A7C7}', '\u{A7C7}', Mapping::Mapped(&['\u{A7C8}'])),
+    ('\u{A7C8}', '\u{A7C8}', Mapping::Valid),
+    ('\u{A7C9}', '\u{A7C9}', Mapping::Mapped(&['\u{A7CA}'])),
+    ('\u{A7CA}', '\u{A7CA}', Mapping::Valid),
+    ('\u{A7CB}', '\u{A7F4}', Mapping::Disallowed),
+    ('\u{A7F5}', '\u{A7F5}', Mapping::Mapped(&['\u{A7F6}'])),
+    ('\u{A7F6}', '\u{A7F7}', Mapping::Valid),
+    ('\u{A7F8}', '\u{A7F8}', Mapping::Mapped(&['\u{127}'])),
+    ('\u{A7F9}', '\u{A7F9}', Mapping::Mapped(&['\u{153}'])),
+    ('\u{A7FA}', '\u{A82C}', Mapping::Valid),
+    ('\u{A82D}', '\u{A82F}', Mapping::Disallowed),
+    ('\u{A830}', '\u{A839}', Mapping::Valid),
+    ('\u{A83A}', '\u{A83F}', Mapping::Disallowed),
+    ('\u{A840}', '\u{A877}', Mapping::Valid),
+    ('\u{A878}', '\u{A87F}', Mapping::Disallowed),
+    ('\u{A880}', '\u{A8C5}', Mapping::Valid),
+    ('\u{A8C6}', '\u{A8CD}', Mapping::Disallowed),
+    ('\u{A8CE}', '\u{A8D9}', Mapping::Valid),
+    ('\u{A8DA}', '\u{A8DF}', Mapping::Disallowed),
+    ('\u{A8E0}', '\u{A953}', Mapping::Valid),
+    ('\u{A954}', '\u{A95E}', Mapping::Disallowed),
+    ('\u{A95F}', '\u{A97C}', Mapping::Valid),
+    ('\u{A97D}', '\u{A97F}', Mapping::Disallowed),
+    ('\u{A980}', '\u{A9CD}', Mapping::Valid),
+    ('\u{A9CE}', '\u{A9CE}', Mapping::Disallowed),
+    ('\u{A9CF}', '\u{A9D9}', Mapping::Valid),
+    ('\u{A9DA}', '\u{A9DD}', Mapping::Disallowed),
+    ('\u{A9DE}', '\u{A9FE}', Mapping::Valid),
+    ('\u{A9FF}', '\u{A9FF}', Mapping::Disallowed),
+    ('\u{AA00}', '\u{AA36}', Mapping::Valid),
+    ('\u{AA37}', '\u{AA3F}', Mapping::Disallowed),
+    ('\u{AA40}', '\u{AA4D}', Mapping::Valid),
+    ('\u{AA4E}', '\u{AA4F}', Mapping::Disallowed),
+    ('\u{AA50}', '\u{AA59}', Mapping::Valid),
+    ('\u{AA5A}', '\u{AA5B}', Mapping::Disallowed),
+    ('\u{AA5C}', '\u{AAC2}', Mapping::Valid),
+    ('\u{AAC3}', '\u{AADA}', Mapping::Disallowed),
+    ('\u{AADB}', '\u{AAF6}', Mapping::Valid),
+    ('\u{AAF7}', '\u{AB00}', Mapping::Disallowed),
+    ('\u{AB01}', '\u{AB06}', Mapping::Valid),
+    ('\u{AB07}', '\u{AB08}', Mapping::Disallowed),
+    ('\u{AB09}', '\u{AB0E}', Mapping::Valid),
+    ('\u{AB0F}', '\u{AB10}', Mapping::Disallowed),
+    ('\u{AB11}', '\u{AB16}', Mapping::Valid),
+    ('\u{AB17}', '\u{AB1F}', Mapping::Disallowed),
+    ('\u{AB20}', '\u{AB26}', Mapping::Valid),
+    ('\u{AB27}', '\u{AB27}', Mapping::Disallowed),
+    ('\u{AB28}', '\u{AB2E}', Mapping::Valid),
+    ('\u{AB2F}', '\u{AB2F}', Mapping::Disallowed),
+    ('\u{AB30}', '\u{AB5B}', Mapping::Valid),
+    ('\u{AB5C}', '\u{AB5C}', Mapping::Mapped(&['\u{A727}'])),
+    ('\u{AB5D}', '\u{AB5D}', Mapping::Mapped(&['\u{AB37}'])),
+    ('\u{AB5E}', '\u{AB5E}', Mapping::Mapped(&['\u{26B}'])),
+    ('\u{AB5F}', '\u{AB5F}', Mapping::Mapped(&['\u{AB52}'])),
+    ('\u{AB60}', '\u{AB68}', Mapping::Valid),
+    ('\u{AB69}', '\u{AB69}', Mapping::Mapped(&['\u{28D}'])),
+    ('\u{AB6A}', '\u{AB6B}', Mapping::Valid),
+    ('\u{AB6C}', '\u{AB6F}', Mapping::Disallowed),
+    ('\u{AB70}', '\u{AB70}', Mapping::Mapped(&['\u{13A0}'])),
+    ('\u{AB71}', '\u{AB71}', Mapping::Mapped(&['\u{13A1}'])),
+    ('\u{AB72}', '\u{AB72}', Mapping::Mapped(&['\u{13A2}'])),
+    ('\u{AB73}', '\u{AB73}', Mapping::Mapped(&['\u{13A3}'])),
+    ('\u{AB74}', '\u{AB74}', Mapping::Mapped(&['\u{13A4}'])),
+    ('\u{AB75}', '\u{AB75}', Mapping::Mapped(&['\u{13A5}'])),
+    ('\u{AB76}', '\u{AB76}', Mapping::Mapped(&['\u{13A6}'])),
+    ('\u{AB77}', '\u{AB77}', Mapping::Mapped(&['\u{13A7}'])),
+    ('\u{AB78}', '\u{AB78}', Mapping::Mapped(&['\u{13A8}'])),
+    ('\u{AB79}', '\u{AB79}', Mapping::Mapped(&['\u{13A9}'])),
+    ('\u{AB7A}', '\u{AB7A}', Mapping::Mapped(&['\u{13AA}'])),
+    ('\u{AB7B}', '\u{AB7B}', Mapping::Mapped(&['\u{13AB}'])),
+    ('\u{AB7C}', '\u{AB7C}', Mapping::Mapped(&['\u{13AC}'])),
+    ('\u{AB7D}', '\u{AB7D}', Mapping::Mapped(&['\u{13AD}'])),
+    ('\u{AB7E}', '\u{AB7E}', Mapping::Mapped(&['\u{13AE}'])),
+    ('\u{AB7F}', '\u{AB7F}', Mapping::Mapped(&['\u{13AF}'])),
+    ('\u{AB80}', '\u{AB80}', Mapping::Mapped(&['\u{13B0}'])),
+    ('\u{AB81}', '\u{AB81}', Mapping::Mapped(&['\u{13B1}'])),
+    ('\u{AB82}', '\u{AB82}', Mapping::Mapped(&['\u{13B2}'])),
+    ('\u{AB83}', '\u{AB83}', Mapping::Mapped(&['\u{13B3}'])),
+    ('\u{AB84}', '\u{AB84}', Mapping::Mapped(&['\u{13B4}'])),
+    ('\u{AB85}', '\u{AB85}', Mapping::Mapped(&['\u{13B5}'])),
+    ('\u{AB86}', '\u{AB86}', Mapping::Mapped(&['\u{13B6}'])),
+    ('\u{AB87}', '\u{AB87}', Mapping::Mapped(&['\u{13B7}'])),
+    ('\u{AB88}', '\u{AB88}', Mapping::Mapped(&['\u{13B8}'])),
+    ('\u{AB89}', '\u{AB89}', Mapping::Mapped(&['\u{13B9}'])),
+    ('\u{AB8A}', '\u{AB8A}', Mapping::Mapped(&['\u{13BA}'])),
+    ('\u{AB8B}', '\u{AB8B}', Mapping::Mapped(&['\u{13BB}'])),
+    ('\u{AB8C}', '\u{AB8C}', Mapping::Mapped(&['\u{13BC}'])),
+    ('\u{AB8D}', '\u{AB8D}', Mapping::Mapped(&['\u{13BD}'])),
+    ('\u{AB8E}', '\u{AB8E}', Mapping::Mapped(&['\u{13BE}'])),
+    ('\u{AB8F}', '\u{AB8F}', Mapping::Mapped(&['\u{13BF}'])),
+    ('\u{AB90}', '\u{AB90}', Mapping::Mapped(&['\u{13C0}'])),
+    ('\u{AB91}', '\u{AB91}', Mapping::Mapped(&['\u{13C1}'])),
+    ('\u{AB92}', '\u{AB92}', Mapping::Mapped(&['\u{13C2}'])),
+    ('\u{AB93}', '\u{AB93}', Mapping::Mapped(&['\u{13C3}'])),
+    ('\u{AB94}', '\u{AB94}', Mapping::Mapped(&['\u{13C4}'])),
+    ('\u{AB95}', '\u{AB95}', Mapping::Mapped(&['\u{13C5}'])),
+    ('\u{AB96}', '\u{AB96}', Mapping::Mapped(&['\u{13C6}'])),
+    ('\u{AB97}', '\u{AB97}', Mapping::Mapped(&['\u{13C7}'])),
+    ('\u{AB98}', '\u{AB98}', Mapping::Mapped(&['\u{13C8}'])),
+    ('\u{AB99}', '\u{AB99}', Mapping::Mapped(&['\u{13C9}'])),
+    ('\u{AB9A}', '\u{AB9A}', Mapping::Mapped(&['\u{13CA}'])),
+    ('\u{AB9B}', '\u{AB9B}', Mapping::Mapped(&['\u{13CB}'])),
+    ('\u{AB9C}', '\u{AB9C}', Mapping::Mapped(&['\u{13CC}'])),
+    ('\u{AB9D}', '\u{AB9D}', Mapping::Mapped(&['\u{13CD}'])),
+    ('\u{AB9E}', '\u{AB9E}', Mapping::Mapped(&['\u{13CE}'])),
+    ('\u{AB9F}', '\u{AB9F}', Mapping::Mapped(&['\u{13CF}'])),
+    ('\u{ABA0}', '\u{ABA0}', Mapping::Mapped(&['\u{13D0}'])),
+    ('\u{ABA1}', '\u{ABA1}', Mapping::Mapped(&['\u{13D1}'])),
+    ('\u{ABA2}', '\u{ABA2}', Mapping::Mapped(&['\u{13D2}'])),
+    ('\u{ABA3}', '\u{ABA3}', Mapping::Mapped(&['\u{13D3}'])),
+    ('\u{ABA4}', '\u{ABA4}', Mapping::Mapped(&['\u{13D4}'])),
+    ('\u{ABA5}', '\u{ABA5}', Mapping::Mapped(&['\u{13D5}'])),
+    ('\u{ABA6}', '\u{ABA6}', Mapping::Mapped(&['\u{13D6}'])),
+    ('\u{ABA7}', '\u{ABA7}', Mapping::Mapped(&['\u{13D7}'])),
+    ('\u{ABA8}', '\u{ABA8}', Mapping::Mapped(&['\u{13D8}'])),
+    ('\u{ABA9}', '\u{ABA9}', Mapping::Mapped(&['\u{13D9}'])),
+    ('\u{ABAA}', '\u{ABAA}', Mapping::Mapped(&['\u{13DA}'])),
+    ('\u{ABAB}', '\u{ABAB}', Mapping::Mapped(&['\u{13DB}'])),
+    ('\u{ABAC}', '\u{ABAC}', Mapping::Mapped(&['\u{13DC}'])),
+    ('\u{ABAD}', '\u{ABAD}', Mapping::Mapped(&['\u{13DD}'])),
+    ('\u{ABAE}', '\u{ABAE}', Mapping::Mapped(&['\u{13DE}'])),
+    ('\u{ABAF}', '\u{ABAF}', Mapping::Mapped(&['\u{13DF}'])),
+    ('\u{ABB0}', '\u{ABB0}', Mapping::Mapped(&['\u{13E0}'])),
+    ('\u{ABB1}', '\u{ABB1}', Mapping::Mapped(&['\u{13E1}'])),
+    ('\u{ABB2}', '\u{ABB2}', Mapping::Mapped(&['\u{13E2}'])),
+    ('\u{ABB3}', '\u{ABB3}', Mapping::Mapped(&['\u{13E3}'])),
+    ('\u{ABB4}', '\u{ABB4}', Mapping::Mapped(&['\u{13E4}'])),
+    ('\u{ABB5}', '\u{ABB5}', Mapping::Mapped(&['\u{13E5}'])),
+    ('\u{ABB6}', '\u{ABB6}', Mapping::Mapped(&['\u{13E6}'])),
+    ('\u{ABB7}', '\u{ABB7}', Mapping::Mapped(&['\u{13E7}'])),
+    ('\u{ABB8}', '\u{ABB8}', Mapping::Mapped(&['\u{13E8}'])),
+    ('\u{ABB9}', '\u{ABB9}', Mapping::Mapped(&['\u{13E9}'])),
+    ('\u{ABBA}', '\u{ABBA}', Mapping::Mapped(&['\u{13EA}'])),
+    ('\u{ABBB}', '\u{ABBB}', Mapping::Mapped(&['\u{13EB}'])),
+    ('\u{ABBC}', '\u{ABBC}', Mapping::Mapped(&['\u{13EC}'])),
+    ('\u{ABBD}', '\u{ABBD}', Mapping::Mapped(&['\u{13ED}'])),
+    ('\u{ABBE}', '\u{ABBE}', Mapping::Mapped(&['\u{13EE}'])),
+    ('\u{ABBF}', '\u{ABBF}', Mapping::Mapped(&['\u{13EF}'])),
+    ('\u{ABC0}', '\u{ABED}', Mapping::Valid),
+    ('\u{ABEE}', '\u{ABEF}', Mapping::Disallowed),
+    ('\u{ABF0}', '\u{ABF9}', Mapping::Valid),
+    ('\u{ABFA}', '\u{ABFF}', Mapping::Disallowed),
+    ('\u{AC00}', '\u{D7A3}', Mapping::Valid),
+    ('\u{D7A4}', '\u{D7AF}', Mapping::Disallowed),
+    ('\u{D7B0}', '\u{D7C6}', Mapping::Valid),
+    ('\u{D7C7}', '\u{D7CA}', Mapping::Disallowed),
+    ('\u{D7CB}', '\u{D7FB}', Mapping::Valid),
+    ('\u{D7FC}', '\u{D7FF}', Mapping::Disallowed),
+    ('\u{E000}', '\u{F8FF}', Mapping::Disallowed),
+    ('\u{F900}', '\u{F900}', Mapping::Mapped(&['\u{8C48}'])),
+    ('\u{F901}', '\u{F901}', Mapping::Mapped(&['\u{66F4}'])),
+    ('\u{F902}', '\u{F902}', Mapping::Mapped(&['\u{8ECA}'])),
+    ('\u{F903}', '\u{F903}', Mapping::Mapped(&['\u{8CC8}'])),
+    ('\u{F904}', '\u{F904}', Mapping::Mapped(&['\u{6ED1}'])),
+    ('\u{F905}', '\u{F905}', Mapping::Mapped(&['\u{4E32}'])),
+    ('\u{F906}', '\u{F906}', Mapping::Mapped(&['\u{53E5}'])),
+    ('\u{F907}', '\u{F908}', Mapping::Mapped(&['\u{9F9C}'])),
+    ('\u{F909}', '\u{F909}', Mapping::Mapped(&['\u{5951}'])),
+    ('\u{F90A}', '\u{F90A}', Mapping::Mapped(&['\u{91D1}'])),
+    ('\u{F90B}', '\u{F90B}', Mapping::Mapped(&['\u{5587}'])),
+    ('\u{F90C}', '\u{F90C}', Mapping::Mapped(&['\u{5948}'])),
+    ('\u{F90D}', '\u{F90D}', Mapping::Mapped(&['\u{61F6}'])),
+    ('\u{F90E}', '\u{F90E}', Mapping::Mapped(&['\u{7669}'])),
+    ('\u{F90F}', '\u{F90F}', Mapping::Mapped(&['\u{7F85}'])),
+    ('\u{F910}', '\u{F910}', Mapping::Mapped(&['\u{863F}'])),
+    ('\u{F911}', '\u{F911}', Mapping::Mapped(&['\u{87BA}'])),
+    ('\u{F912}', '\u{F912}', Mapping::Mapped(&['\u{88F8}'])),
+    ('\u{F913}', '\u{F913}', Mapping::Mapped(&['\u{908F}'])),
+    ('\u{F914}', '\u{F914}', Mapping::Mapped(&['\u{6A02}'])),
+    ('\u{F915}', '\u{F915}', Mapping::Mapped(&['\u{6D1B}'])),
+    ('\u{F916}', '\u{F916}', Mapping::Mapped(&['\u{70D9}'])),
+    ('\u{F917}', '\u{F917}', Mapping::Mapped(&['\u{73DE}'])),
+    ('\u{F918}', '\u{F918}', Mapping::Mapped(&['\u{843D}'])),
+    ('\u{F919}', '\u{F919}', Mapping::Mapped(&['\u{916A}'])),
+    ('\u{F91A}', '\u{F91A}', Mapping::Mapped(&['\u{99F1}'])),
+    ('\u{F91B}', '\u{F91B}', Mapping::Mapped(&['\u{4E82}'])),
+    ('\u{F91C}', '\u{F91C}', Mapping::Mapped(&['\u{5375}'])),
+    ('\u{F91D}', '\u{F91D}', Mapping::Mapped(&['\u{6B04}'])),
+    ('\u{F91E}', '\u{F91E}', Mapping::Mapped(&['\u{721B}'])),
+    ('\u{F91F}', '\u{F91F}', Mapping::Mapped(&['\u{862D}'])),
+    ('\u{F920}', '\u{F920}', Mapping::Mapped(&['\u{9E1E}'])),
+    ('\u{F921}', '\u{F921}', Mapping::Mapped(&['\u{5D50}'])),
+    ('\u{F922}', '\u{F922}', Mapping::Mapped(&['\u{6FEB}'])),
+    ('\u{F923}', '\u{F923}', Mapping::Mapped(&['\u{85CD}'])),
+    ('\u{F924}', '\u{F924}', Mapping::Mapped(&['\u{8964}'])),
+    ('\u{F925}', '\u{F925}', Mapping::Mapped(&['\u{62C9}'])),
+    ('\u{F926}', '\u{F926}', Mapping::Mapped(&['\u{81D8}'])),
+    ('\u{F927}', '\u{F927}', Mapping::Mapped(&['\u{881F}'])),
+    ('\u{F928}', '\u{F928}', Mapping::Mapped(&['\u{5ECA}'])),
+    ('\u{F929}', '\u{F929}', Mapping::Mapped(&['\u{6717}'])),
+    ('\u{F92A}', '\u{F92A}', Mapping::Mapped(&['\u{6D6A}'])),
+    ('\u{F92B}', '\u{F92B}', Mapping::Mapped(&['\u{72FC}'])),
+    ('\u{F92C}', '\u{F92C}', Mapping::Mapped(&['\u{90CE}'])),
+    ('\u{F92D}', '\u{F92D}', Mapping::Mapped(&['\u{4F86}'])),
+    ('\u{F92E}', '\u{F92E}', Mapping::Mapped(&['\u{51B7}'])),
+    ('\u{F92F}', '\u{F92F}', Mapping::Mapped(&['\u{52DE}'])),
+    ('\u{F930}', '\u{F930}', Mapping::Mapped(&['\u{64C4}'])),
+    ('\u{F931}', '\u{F931}', Mapping::Mapped(&['\u{6AD3}'])),
+    ('\u{F932}', '\u{F932}', Mapping::Mapped(&['\u{7210}'])),
+    ('\u{F933}', '\u{F933}', Mapping::Mapped(&['\u{76E7}'])),
+    ('\u{F934}', '\u{F934}', Mapping::Mapped(&['\u{8001}'])),
+    ('\u{F935}', '\u{F935}', Mapping::Mapped(&['\u{8606}'])),
+    ('\u{F936}', '\u{F936}', Mapping::Mapped(&['\u{865C}'])),
+    ('\u{F937}', '\u{F937}', Mapping::Mapped(&['\u{8DEF}'])),
+    ('\u{F938}', '\u{F938}', Mapping::Mapped(&['\u{9732}'])),
+    ('\u{F939}', '\u{F939}', Mapping::Mapped(&['\u{9B6F}'])),
+    ('\u{F93A}', '\u{F93A}', Mapping::Mapped(&['\u{9DFA}'])),
+    ('\u{F93B}', '\u{F93B}', Mapping::Mapped(&['\u{788C}'])),
+    ('\u{F93C}', '\u{F93C}', Mapping::Mapped(&['\u{797F}'])),
+    ('\u{F93D}', '\u{F93D}', Mapping::Mapped(&['\u{7DA0}'])),
+    ('\u{F93E}', '\u{F93E}', Mapping::Mapped(&['\u{83C9}'])),
+    ('\u{F93F}', '\u{F93F}', Mapping::Mapped(&['\u{9304}'])),
+    ('\u{F940}', '\u{F940}', Mapping::Mapped(&['\u{9E7F}'])),
+    ('\u{F941}', '\u{F941}', Mapping::Mapped(&['\u{8AD6}'])),
+    ('\u{F942}', '\u{F942}', Mapping::Mapped(&['\u{58DF}'])),
+    ('\u{F943}', '\u{F943}', Mapping::Mapped(&['\u{5F04}'])),
+    ('\u{F944}', '\u{F944}', Mapping::Mapped(&['\u{7C60}'])),
+    ('\u{F945}', '\u{F945}', Mapping::Mapped(&['\u{807E}'])),
+    ('\u{F946}', '\u{F946}', Mapping::Mapped(&['\u{7262}'])),
+    ('\u{F947}', '\u{F947}', Mapping::Mapped(&['\u{78CA}'])),
+    ('\u{F948}', '\u{F948}', Mapping::Mapped(&['\u{8CC2}'])),
+    ('\u{F949}', '\u{F949}', Mapping::Mapped(&['\u{96F7}'])),
+    ('\u{F94A}', '\u{F94A}', Mapping::Mapped(&['\u{58D8}'])),
+    ('\u{F94B}', '\u{F94B}', Mapping::Mapped(&['\u{5C62}'])),
+    ('\u{F94C}', '\u{F94C}', Mapping::Mapped(&['\u{6A13}'])),
+    ('\u{F94D}', '\u{F94D}', Mapping::Mapped(&['\u{6DDA}'])),
+    ('\u{F94E}', '\u{F94E}', Mapping::Mapped(&['\u{6F0F}'])),
+    ('\u{F94F}', '\u{F94F}', Mapping::Mapped(&['\u{7D2F}'])),
+    ('\u{F950}', '\u{F950}', Mapping::Mapped(&['\u{7E37}'])),
+    ('\u{F951}', '\u{F951}', Mapping::Mapped(&['\u{964B}'])),
+    ('\u{F952}', '\u{F952}', Mapping::Mapped(&['\u{52D2}'])),
+    ('\u{F953}', '\u{F953}', Mapping::Mapped(&['\u{808B}'])),
+    ('\u{F954}', '\u{F954}', Mapping::Mapped(&['\u{51DC}'])),
+    ('\u{F955}', '\u{F955}', Mapping::Mapped(&['\u{51CC}'])),
+    ('\u{F956}', '\u{F956}', Mapping::Mapped(&['\u{7A1C}'])),
+    ('\u{F957}', '\u{F957}', Mapping::Mapped(&['\u{7DBE}'])),
+    ('\u{F958}', '\u{F958}', Mapping::Mapped(&['\u{83F1}'])),
+    ('\u{F959}', '\u{F959}', Mapping::Mapped(&['\u{9675}'])),
+    ('\u{F95A}', '\u{F95A}', Mapping::Mapped(&['\u{8B80}'])),
+    ('\u{F95B}', '\u{F95B}', Mapping::Mapped(&['\u{62CF}'])),
+    ('\u{F95C}', '\u{F95C}', Mapping::Mapped(&['\u{6A02}'])),
+    ('\u{F95D}', '\u{F95D}', Mapping::Mapped(&['\u{8AFE}'])),
+    ('\u{F95E}', '\u{F95E}', Mapping::Mapped(&['\u{4E39}'])),
+    ('\u{F95F}', '\u{F95F}', Mapping::Mapped(&['\u{5BE7}'])),
+    ('\u{F960}', '\u{F960}', Mapping::Mapped(&['\u{6012}'])),
+    ('\u{F961}', '\u{F961}', Mapping::Mapped(&['\u{7387}'])),
+    ('\u{F962}', '\u{F962}', Mapping::Mapped(&['\u{7570}'])),
+    ('\u{F963}', '\u{F963}', Mapping::Mapped(&['\u{5317}'])),
+    ('\u{F964}', '\u{F964}', Mapping::Mapped(&['\u{78FB}'])),
+    ('\u{F965}', '\u{F965}', Mapping::Mapped(&['\u{4FBF}'])),
+    ('\u{F966}', '\u{F966}', Mapping::Mapped(&['\u{5FA9}'])),
+    ('\u{F967}', '\u{F967}', Mapping::Mapped(&['\u{4E0D}'])),
+    ('\u{F968}', '\u{F968}', Mapping::Mapped(&['\u{6CCC}'])),
+    ('\u{F969}', '\u{F969}', Mapping::Mapped(&['\u{6578}'])),
+    ('\u{F96A}', '\u{F96A}', Mapping::Mapped(&['\u{7D22}'])),
+    ('\u{F96B}', '\u{F96B}', Mapping::Mapped(&['\u{53C3}'])),
+    ('\u{F96C}', '\u{F96C}', Mapping::Mapped(&['\u{585E}'])),
+    ('\u{F96D}', '\u{F96D}', Mapping::Mapped(&['\u{7701}'])),
+    ('\u{F96E}', '\u{F96E}', Mapping::Mapped(&['\u{8449}'])),
+    ('\u{F96F}', '\u{F96F}', Mapping::Mapped(&['\u{8AAA}'])),
+    ('\u{F970}', '\u{F970}', Mapping::Mapped(&['\u{6BBA}'])),
+    ('\u{F971}', '\u{F971}', Mapping::Mapped(&['\u{8FB0}'])),
+    ('\u{F972}', '\u{F972}', Mapping::Mapped(&['\u{6C88}'])),
+    ('\u{F973}', '\u{F973}', Mapping::Mapped(&['\u{62FE}'])),
+    ('\u{F974}', '\u{F974}', Mapping::Mapped(&['\u{82E5}'])),
+    ('\u{F975}', '\u{F975}', Mapping::Mapped(&['\u{63A0}'])),
+    ('\u{F976}', '\u{F976}', Mapping::Mapped(&['\u{7565}'])),
+    ('\u{F977}', '\u{F977}', Mapping::Mapped(&['\u{4EAE}'])),
+    ('\u{F978}', '\u{F978}', Mapping::Mapped(&['\u{5169}'])),
+    ('\u{F979}', '\u{F979}', Mapping::Mapped(&['\u{51C9}'])),
+    ('\u{F97A}', '\u{F97A}', Mapping::Mapped(&['\u{6881}'])),
+    ('\u{F97B}', '\u{F97B}', Mapping::Mapped(&['\u{7CE7}'])),
+    ('\u{F97C}', '\u{F97C}', Mapping::Mapped(&['\u{826F}'])),
+    ('\u{F97D}', '\u{F97D}', Mapping::Mapped(&['\u{8AD2}'])),
+    ('\u{F97E}', '\u{F97E}', Mapping::Mapped(&['\u{91CF}'])),
+    ('\u{F97F}', '\u{F97F}', Mapping::Mapped(&['\u{52F5}'])),
+    ('\u{F980}', '\u{F980}', Mapping::Mapped(&['\u{5442}'])),
+    ('\u{F981}', '\u{F981}', Mapping::Mapped(&['\u{5973}'])),
+    ('\u{F982}', '\u{F982}', Mapping::Mapped(&['\u{5EEC}'])),
+    ('\u{F983}', '\u{F983}', Mapping::Mapped(&['\u{65C5}'])),
+    ('\u{F984}', '\u{F984}', Mapping::Mapped(&['\u{6FFE}'])),
+    ('\u{F985}', '\u{F985}', Mapping::Mapped(&['\u{792A}'])),
+    ('\u{F986}', '\u{F986}', Mapping::Mapped(&['\u{95AD}'])),
+    ('\u{F987}', '\u{F987}', Mapping::Mapped(&['\u{9A6A}'])),
+    ('\u{F988}', '\u{F988}', Mapping::Mapped(&['\u{9E97}'])),
+    ('\u{F989}', '\u{F989}', Mapping::Mapped(&['\u{9ECE}'])),
+    ('\u{F98A}', '\u{F98A}', Mapping::Mapped(&['\u{529B}'])),
+    ('\u{F98B}', '\u{F98B}', Mapping::Mapped(&['\u{66C6}'])),
+    ('\u{F98C}', '\u{F98C}', Mapping::Mapped(&['\u{6B77}'])),
+    ('\u{F98D}', '\u{F98D}', Mapping::Mapped(&['\u{8F62}'])),
+    ('\u{F98E}', '\u{F98E}', Mapping::Mapped(&['\u{5E74}'])),
+    ('\u{F98F}', '\u{F98F}', Mapping::Mapped(&['\u{6190}'])),
+    ('\u{F990}', '\u{F990}', Mapping::Mapped(&['\u{6200}'])),
+    ('\u{F991}', '\u{F991}', Mapping::Mapped(&['\u{649A}'])),
+    ('\u{F992}', '\u{F992}', Mapping::Mapped(&['\u{6F23}'])),
+    ('\u{F993}', '\u{F993}', Mapping::Mapped(&['\u{7149}'])),
+    ('\u{F994}', '\u{F994}', Mapping::Mapped(&['\u{7489}'])),
+    ('\u{F995}', '\u{F995}', Mapping::Mapped(&['\u{79CA}'])),
+    ('\u{F996}', '\u{F996}', Mapping::Mapped(&['\u{7DF4}'])),
+    ('\u{F997}', '\u{F997}', Mapping::Mapped(&['\u{806F}'])),
+    ('\u{F998}', '\u{F998}', Mapping::Mapped(&['\u{8F26}'])),
+    ('\u{F999}', '\u{F999}', Mapping::Mapped(&['\u{84EE}'])),
+    ('\u{F99A}', '\u{F99A}', Mapping::Mapped(&['\u{9023}'])),
+    ('\u{F99B}', '\u{F99B}', Mapping::Mapped(&['\u{934A}'])),
+    ('\u{F99C}', '\u{F99C}', Mapping::Mapped(&['\u{5217}'])),
+    ('\u{F99D}', '\u{F99D}', Mapping::Mapped(&['\u{52A3}'])),
+    ('\u{F99E}', '\u{F99E}', Mapping::Mapped(&['\u{54BD}'])),
+    ('\u{F99F}', '\u{F99F}', Mapping::Mapped(&['\u{70C8}'])),
+    ('\u{F9A0}', '\u{F9A0}', Mapping::Mapped(&['\u{88C2}'])),
+    ('\u{F9A1}', '\u{F9A1}', Mapping::Mapped(&['\u{8AAA}'])),
+    ('\u{F9A2}', '\u{F9A2}', Mapping::Mapped(&['\u{5EC9}'])),
+    ('\u{F9A3}', '\u{F9A3}', Mapping::Mapped(&['\u{5FF5}'])),
+    ('\u{F9A4}', '\u{F9A4}', Mapping::Mapped(&['\u{637B}'])),
+    ('\u{F9A5}', '\u{F9A5}', Mapping::Mapped(&['\u{6BAE}'])),
+    ('\u{F9A6}', '\u{F9A6}', Mapping::Mapped(&['\u{7C3E}'])),
+    ('\u{F9A7}', '\u{F9A7}', Mapping::Mapped(&['\u{7375}'])),
+    ('\u{F9A8}', '\u{F9A8}', Mapping::Mapped(&['\u{4EE4}'])),
+    ('\u{F9A9}', '\u{F9A9}', Mapping::Mapped(&['\u{56F9}'])),
+    ('\u{F9AA}', '\u{F9AA}', Mapping::Mapped(&['\u{5BE7}'])),
+    ('\u{F9AB}', '\u{F9AB}', Mapping::Mapped(&['\u{5DBA}'])),
+    ('\u{F9AC}', '\u{F9AC}', Mapping::Mapped(&['\u{601C}'])),
+    ('\u{F9AD}', '\u{F9AD}', Mapping::Mapped(&['\u{73B2}'])),
+    ('\u{F9AE}', '\u{F9AE}', Mapping::Mapped(&['\u{7469}'])),
+    ('\u{F9AF}', '\u{F9AF}', Mapping::Mapped(&['\u{7F9A}'])),
+    ('\u{F9B0}', '\u{F9B0}', Mapping::Mapped(&['\u{8046}'])),
+    ('\u{F9B1}', '\u{F9B1}', Mapping::Mapped(&['\u{9234}'])),
+    ('\u{F9B2}', '\u{F9B2}', Mapping::Mapped(&['\u{96F6}'])),
+    ('\u{F9B3}', '\u{F9B3}', Mapping::Mapped(&['\u{9748}'])),
+    ('\u{F9B4}', '\u{F9B4}', Mapping::Mapped(&['\u{9818}'])),
+    ('\u{F9B5}', '\u{F9B5}', Mapping::Mapped(&['\u{4F8B}'])),
+    ('\u{F9B6}', '\u{F9B6}', Mapping::Mapped(&['\u{79AE}'])),
+    ('\u{F9B7}', '\u{F9B7}', Mapping::Mapped(&['\u{91B4}'])),
+    ('\u{F9B8}', '\u{F9B8}', Mapping::Mapped(&['\u{96B8}'])),
+    ('\u{F9B9}', '\u{F9B9}', Mapping::Mapped(&['\u{60E1}'])),
+    ('\u{F9BA}', '\u{F9BA}', Mapping::Mapped(&['\u{4E86}'])),
+    ('\u{F9BB}', '\u{F9BB}', Mapping::Mapped(&['\u{50DA}'])),
+    ('\u{F9BC}', '\u{F9BC}', Mapping::Mapped(&['\u{5BEE}'])),
+    ('\u{F9BD}', '\u{F9BD}', Mapping::Mapped(&['\u{5C3F}'])),
+    ('\u{F9BE}', '\u{F9BE}', Mapping::Mapped(&['\u{6599}'])),
+    ('\u{F9BF}', '\u{F9BF}', Mapping::Mapped(&['\u{6A02}'])),
+    ('\u{F9C0}', '\u{F9C0}', Mapping::Mapped(&['\u{71CE}'])),
+    ('\u{F9C1}', '\u{F9C1}', Mapping::Mapped(&['\u{7642}'])),
+    ('\u{F9C2}', '\u{F9C2}', Mapping::Mapped(&['\u{84FC}'])),
+    ('\u{F9C3}', '\u{F9C3}', Mapping::Mapped(&['\u{907C}'])),
+    ('\u{F9C4}', '\u{F9C4}', Mapping::Mapped(&['\u{9F8D}'])),
+    ('\u{F9C5}', '\u{F9C5}', Mapping::Mapped(&['\u{6688}'])),
+    ('\u{F9C6}', '\u{F9C6}', Mapping::Mapped(&['\u{962E}'])),
+    ('\u{F9C7}', '\u{F9C7}', Mapping::Mapped(&['\u{5289}'])),
+    ('\u{F9C8}', '\u{F9C8}', Mapping::Mapped(&['\u{677B}'])),
+    ('\u{F9C9}', '\u{F9C9}', Mapping::Mapped(&['\u{67F3}'])),
+    ('\u{F9CA}', '\u{F9CA}', Mapping::Mapped(&['\u{6D41}'])),
+    ('\u{F9CB}', '\u{F9CB}', Mapping::Mapped(&['\u{6E9C}'])),
+    ('\u{F9CC}', '\u{F9CC}', Mapping::Mapped(&['\u{7409}'])),
+    ('\u{F9CD}', '\u{F9CD}', Mapping::Mapped(&['\u{7559}'])),
+    ('\u{F9CE}', '\u{F9CE}', Mapping::Mapped(&['\u{786B}'])),
+    ('\u{F9CF}', '\u{F9CF}', Mapping::Mapped(&['\u{7D10}'])),
+    ('\u{F9D0}', '\u{F9D0}', Mapping::Mapped(&['\u{985E}'])),
+    ('\u{F9D1}', '\u{F9D1}', Mapping::Mapped(&['\u{516D}'])),
+    ('\u{F9D2}', '\u{F9D2}', Mapping::Mapped(&['\u{622E}'])),
+    ('\u{F9D3}', '\u{F9D3}', Mapping::Mapped(&['\u{9678}'])),
+    ('\u{F9D4}', '\u{F9D4}', Mapping::Mapped(&['\u{502B}'])),
+    ('\u{F9D5}', '\u{F9D5}', Mapping::Mapped(&['\u{5D19}'])),
+    ('\u{F9D6}', '\u{F9D6}', Mapping::Mapped(&['\u{6DEA}'])),
+    ('\u{F9D7}', '\u{F9D7}', Mapping::Mapped(&['\u{8F2A}'])),
+    ('\u{F9D8}', '\u{F9D8}', Mapping::Mapped(&['\u{5F8B}'])),
+    ('\u{F9D9}', '\u{F9D9}', Mapping::Mapped(&['\u{6144}'])),
+    ('\u{F9DA}', '\u{F9DA}', Mapping::Mapped(&['\u{6817}'])),
+    ('\u{F9DB}', '\u{F9DB}', Mapping::Mapped(&['\u{7387}'])),
+    ('\u{F9DC}', '\u{F9DC}', Mapping::Mapped(&['\u{9686}'])),
+    ('\u{F9DD}', '\u{F9DD}', Mapping::Mapped(&['\u{5229}'])),
+    ('\u{F9DE}', '\u{F9DE}', Mapping::Mapped(&['\u{540F}'])),
+    ('\u{F9DF}', '\u{F9DF}', Mapping::Mapped(&['\u{5C65}'])),
+    ('\u{F9E0}', '\u{F9E0}', Mapping::Mapped(&['\u{6613}'])),
+    ('\u{F9E1}', '\u{F9E1}', Mapping::Mapped(&['\u{674E}'])),
+    ('\u{F9E2}', '\u{F9E2}', Mapping::Mapped(&['\u{68A8}'])),
+    ('\u{F9E3}', '\u{F9E3}', Mapping::Mapped(&['\u{6CE5}'])),
+    ('\u{F9E4}', '\u{F9E4}', Mapping::Mapped(&['\u{7406}'])),
+    ('\u{F9E5}', '\u{F9E5}', Mapping::Mapped(&['\u{75E2}'])),
+    ('\u{F9E6}', '\u{F9E6}', Mapping::Mapped(&['\u{7F79}'])),
+    ('\u{F9E7}', '\u{F9E7}', Mapping::Mapped(&['\u{88CF}'])),
+    ('\u{F9E8}', '\u{F9E8}', Mapping::Mapped(&['\u{88E1}'])),
+    ('\u{F9E9}', '\u{F9E9}', Mapping::Mapped(&['\u{91CC}'])),
+    ('\u{F9EA}', '\u{F9EA}', Mapping::Mapped(&['\u{96E2}'])),
+    ('\u{F9EB}', '\u{F9EB}', Mapping::Mapped(&['\u{533F}'])),
+    ('\u{F9EC}', '\u{F9EC}', Mapping::Mapped(&['\u{6EBA}'])),
+    ('\u{F9ED}', '\u{F9ED}', Mapping::Mapped(&['\u{541D}'])),
+    ('\u{F9EE}', '\u{F9EE}', Mapping::Mapped(&['\u{71D0}'])),
+    ('\u{F9EF}', '\u{F9EF}', Mapping::Mapped(&['\u{7498}'])),
+    ('\u{F9F0}', '\u{F9F0}', Mapping::Mapped(&['\u{85FA}'])),
+    ('\u{F9F1}', '\u{F9F1}', Mapping::Mapped(&['\u{96A3}'])),
+    ('\u{F9F2}', '\u{F9F2}', Mapping::Mapped(&['\u{9C57}'])),
+    ('\u{F9F3}', '\u{F9F3}', Mapping::Mapped(&['\u{9E9F}'])),
+    ('\u{F9F4}', '\u{F9F4}', Mapping::Mapped(&['\u{6797}'])),
+    ('\u{F9F5}', '\u{F9F5}', Mapping::Mapped(&['\u{6DCB}'])),
+    ('\u{F9F6}', '\u{F9F6}', Mapping::Mapped(&['\u{81E8}'])),
+    ('\u{F9F7}', '\u{F9F7}', Mapping::Mapped(&['\u{7ACB}'])),
+    ('\u{F9F8}', '\u{F9F8}', Mapping::Mapped(&['\u{7B20}'])),
+    ('\u{F9F9}', '\u{F9F9}', Mapping::Mapped(&['\u{7C92}'])),
+    ('\u{F9FA}', '\u{F9FA}', Mapping::Mapped(&['\u{72C0}'])),
+    ('\u{F9FB}', '\u{F9FB}', Mapping::Mapped(&['\u{7099}'])),
+    ('\u{F9FC}', '\u{F9FC}', Mapping::Mapped(&['\u{8B58}'])),
+    ('\u{F9FD}', '\u{F9FD}', Mapping::Mapped(&['\u{4EC0}'])),
+    ('\u{F9FE}', '\u{F9FE}', Mapping::Mapped(&['\u{8336}'])),
+    ('\u{F9FF}', '\u{F9FF}', Mapping::Mapped(&['\u{523A}'])),
+    ('\u{FA00}', '\u{FA00}', Mapping::Mapped(&['\u{5207}'])),
+    ('\u{FA01}', '\u{FA01}', Mapping::Mapped(&['\u{5EA6}'])),
+    ('\u{FA02}', '\u{FA02}', Mapping::Mapped(&['\u{62D3}'])),
+    ('\u{FA03}', '\u{FA03}', Mapping::Mapped(&['\u{7CD6}'])),
+    ('\u{FA04}', '\u{FA04}', Mapping::Mapped(&['\u{5B85}'])),
+    ('\u{FA05}', '\u{FA05}', Mapping::Mapped(&['\u{6D1E}'])),
+    ('\u{FA06}', '\u{FA06}', Mapping::Mapped(&['\u{66B4}'])),
+    ('\u{FA07}', '\u{FA07}', Mapping::Mapped(&['\u{8F3B}'])),
+    ('\u{FA08}', '\u{FA08}', Mapping::Mapped(&['\u{884C}'])),
+    ('\u{FA09}', '\u{FA09}', Mapping::Mapped(&['\u{964D}'])),
+    ('\u{FA0A}', '\u{FA0A}', Mapping::Mapped(&['\u{898B}'])),
+    ('\u{FA0B}', '\u{FA0B}', Mapping::Mapped(&['\u{5ED3}'])),
+    ('\u{FA0C}', '\u{FA0C}', Mapping::Mapped(&['\u{5140}'])),
+    ('\u{FA0D}', '\u{FA0D}', Mapping::Mapped(&['\u{55C0}'])),
+    ('\u{FA0E}', '\u{FA0F}', Mapping::Valid),
+    ('\u{FA10}', '\u{FA10}', Mapping::Mapped(&['\u{585A}'])),
+    ('\u{FA11}', '\u{FA11}', Mapping::Valid),
+    ('\u{FA12}', '\u{FA12}', Mapping::Mapped(&['\u{6674}'])),
+    ('\u{FA13}', '\u{FA14}', Mapping::Valid),
+    ('\u{FA15}', '\u{FA15}', Mapping::Mapped(&['\u{51DE}'])),
+    ('\u{FA16}', '\u{FA16}', Mapping::Mapped(&['\u{732A}'])),
+    ('\u{FA17}', '\u{FA17}', Mapping::Mapped(&['\u{76CA}'])),
+    ('\u{FA18}', '\u{FA18}', Mapping::Mapped(&['\u{793C}'])),
+    ('\u{FA19}', '\u{FA19}', Mapping::Mapped(&['\u{795E}'])),
+    ('\u{FA1A}', '\u{FA1A}', Mapping::Mapped(&['\u{7965}'])),
+    ('\u{FA1B}', '\u{FA1B}', Mapping::Mapped(&['\u{798F}'])),
+    ('\u{FA1C}', '\u{FA1C}', Mapping::Mapped(&['\u{9756}'])),
+    ('\u{FA1D}', '\u{FA1D}', Mapping::Mapped(&['\u{7CBE}'])),
+    ('\u{FA1E}', '\u{FA1E}', Mapping::Mapped(&['\u{7FBD}'])),
+    ('\u{FA1F}', '\u{FA1F}', Mapping::Valid),
+    ('\u{FA20}', '\u{FA20}', Mapping::Mapped(&['\u{8612}'])),
+    ('\u{FA21}', '\u{FA21}', Mapping::Valid),
+    ('\u{FA22}', '\u{FA22}', Mapping::Mapped(&['\u{8AF8}'])),
+    ('\u{FA23}', '\u{FA24}', Mapping::Valid),
+    ('\u{FA25}', '\u{FA25}', Mapping::Mapped(&['\u{9038}'])),
+    ('\u{FA26}', '\u{FA26}', Mapping::Mapped(&['\u{90FD}'])),
+    ('\u{FA27}', '\u{FA29}', Mapping::Valid),
+    ('\u{FA2A}', '\u{FA2A}', Mapping::Mapped(&['\u{98EF}'])),
+    ('\u{FA2B}', '\u{FA2B}', Mapping::Mapped(&['\u{98FC}'])),
+    ('\u{FA2C}', '\u{FA2C}', Mapping::Mapped(&['\u{9928}'])),
+    ('\u{FA2D}', '\u{FA2D}', Mapping::Mapped(&['\u{9DB4}'])),
+    ('\u{FA2E}', '\u{FA2E}', Mapping::Mapped(&['\u{90DE}'])),
+    ('\u{FA2F}', '\u{FA2F}', Mapping::Mapped(&['\u{96B7}'])),
+    ('\u{FA30}', '\u{FA30}', Mapping::Mapped(&['\u{4FAE}'])),
+    ('\u{FA31}', '\u{FA31}', Mapping::Mapped(&['\u{50E7}'])),
+    ('\u{FA32}', '\u{FA32}', Mapping::Mapped(&['\u{514D}'])),
+    ('\u{FA33}', '\u{FA33}', Mapping::Mapped(&['\u{52C9}'])),
+    ('\u{FA34}', '\u{FA34}', Mapping::Mapped(&['\u{52E4}'])),
+    ('\u{FA35}', '\u{FA35}', Mapping::Mapped(&['\u{5351}'])),
+    ('\u{FA36}', '\u{FA36}', Mapping::Mapped(&['\u{559D}'])),
+    ('\u{FA37}', '\u{FA37}', Mapping::Mapped(&['\u{5606}'])),
+    ('\u{FA38}', '\u{FA38}', Mapping::Mapped(&['\u{5668}'])),
+    ('\u{FA39}', '\u{FA39}', Mapping::Mapped(&['\u{5840}'])),
+    ('\u{FA3A}', '\u{FA3A}', Mapping::Mapped(&['\u{58A8}'])),
+    ('\u{FA3B}', '\u{FA3B}', Mapping::Mapped(&['\u{5C64}'])),
+    ('\u{FA3C}', '\u{FA3C}', Mapping::Mapped(&['\u{5C6E}'])),
+    ('\u{FA3D}', '\u{FA3D}', Mapping::Mapped(&['\u{6094}'])),
+    ('\u{FA3E}', '\u{FA3E}', Mapping::Mapped(&['\u{6168}'])),
+    ('\u{FA3F}', '\u{FA3F}', Mapping::Mapped(&['\u{618E}'])),
+    ('\u{FA40}', '\u{FA40}', Mapping::Mapped(&['\u{61F2}'])),
+    ('\u{FA41}', '\u{FA41}', Mapping::Mapped(&['\u{654F}'])),
+    ('\u{FA42}', '\u{FA42}', Mapping::Mapped(&['\u{65E2}'])),
+    ('\u{FA43}', '\u{FA43}', Mapping::Mapped(&['\u{6691}'])),
+    ('\u{FA44}', '\u{FA44}', Mapping::Mapped(&['\u{6885}'])),
+    ('\u{FA45}', '\u{FA45}', Mapping::Mapped(&['\u{6D77}'])),
+    ('\u{FA46}', '\u{FA46}', Mapping::Mapped(&['\u{6E1A}'])),
+    ('\u{FA47}', '\u{FA47}', Mapping::Mapped(&['\u{6F22}'])),
+    ('\u{FA48}', '\u{FA48}', Mapping::Mapped(&['\u{716E}'])),
+    ('\u{FA49}', '\u{FA49}', Mapping::Mapped(&['\u{722B}'])),
+    ('\u{FA4A}', '\u{FA4A}', Mapping::Mapped(&['\u{7422}'])),
+    ('\u{FA4B}', '\u{FA4B}', Mapping::Mapped(&['\u{7891}'])),
+    ('\u{FA4C}', '\u{FA4C}', Mapping::Mapped(&['\u{793E}'])),
+    ('\u{FA4D}', '\u{FA4D}', Mapping::Mapped(&['\u{7949}'])),
+    ('\u{FA4E}', '\u{FA4E}', Mapping::Mapped(&['\u{7948}'])),
+    ('\u{FA4F}', '\u{FA4F}', Mapping::Mapped(&['\u{7950}'])),
+    ('\u{FA50}', '\u{FA50}', Mapping::Mapped(&['\u{7956}'])),
+    ('\u{FA51}', '\u{FA51}', Mapping::Mapped(&['\u{795D}'])),
+    ('\u{FA52}', '\u{FA52}', Mapping::Mapped(&['\u{798D}'])),
+    ('\u{FA53}', '\u{FA53}', Mapping::Mapped(&['\u{798E}'])),
+    ('\u{FA54}', '\u{FA54}', Mapping::Mapped(&['\u{7A40}'])),
+    ('\u{FA55}', '\u{FA55}', Mapping::Mapped(&['\u{7A81}'])),
+    ('\u{FA56}', '\u{FA56}', Mapping::Mapped(&['\u{7BC0}'])),
+    ('\u{FA57}', '\u{FA57}', Mapping::Mapped(&['\u{7DF4}'])),
+    ('\u{FA58}', '\u{FA58}', Mapping::Mapped(&['\u{7E09}'])),
+    ('\u{FA59}', '\u{FA59}', Mapping::Mapped(&['\u{7E41}'])),
+    ('\u{FA5A}', '\u{FA5A}', Mapping::Mapped(&['\u{7F72}'])),
+    ('\u{FA5B}', '\u{FA5B}', Mapping::Mapped(&['\u{8005}'])),
+    ('\u{FA5C}', '\u{FA5C}', Mapping::Mapped(&['\u{81ED}'])),
+    ('\u{FA5D}', '\u{FA5E}', Mapping::Mapped(&['\u{8279}'])),
+    ('\u{FA5F}', '\u{FA5F}', Mapping::Mapped(&['\u{8457}'])),
+    ('\u{FA60}', '\u{FA60}', Mapping::Mapped(&['\u{8910}'])),
+    ('\u{FA61}', '\u{FA61}', Mapping::Mapped(&['\u{8996}'])),
+    ('\u{FA62}', '\u{FA62}', Mapping::Mapped(&['\u{8B01}'])),
+    ('\u{FA63}', '\u{FA63}', Mapping::Mapped(&['\u{8B39}'])),
+    ('\u{FA64}', '\u{FA64}', Mapping::Mapped(&['\u{8CD3}'])),
+    ('\u{FA65}', '\u{FA65}', Mapping::Mapped(&['\u{8D08}'])),
+    ('\u{FA66}', '\u{FA66}', Mapping::Mapped(&['\u{8FB6}'])),
+    ('\u{FA67}', '\u{FA67}', Mapping::Mapped(&['\u{9038}'])),
+    ('\u{FA68}', '\u{FA68}', Mapping::Mapped(&['\u{96E3}'])),
+    ('\u{FA69}', '\u{FA69}', Mapping::Mapped(&['\u{97FF}'])),
+    ('\u{FA6A}', '\u{FA6A}', Mapping::Mapped(&['\u{983B}'])),
+    ('\u{FA6B}', '\u{FA6B}', Mapping::Mapped(&['\u{6075}'])),
+    ('\u{FA6C}', '\u{FA6C}', Mapping::Mapped(&['\u{242EE}'])),
+    ('\u{FA6D}', '\u{FA6D}', Mapping::Mapped(&['\u{8218}'])),
+    ('\u{FA6E}', '\u{FA6F}', Mapping::Disallowed),
+    ('\u{FA70}', '\u{FA70}', Mapping::Mapped(&['\u{4E26}'])),
+    ('\u{FA71}', '\u{FA71}', Mapping::Mapped(&['\u{51B5}'])),
+    ('\u{FA72}', '\u{FA72}', Mapping::Mapped(&['\u{5168}'])),
+    ('\u{FA73}', '\u{FA73}', Mapping::Mapped(&['\u{4F80}'])),
+    ('\u{FA74}', '\u{FA74}', Mapping::Mapped(&['\u{5145}'])),
+    ('\u{FA75}', '\u{FA75}', Mapping::Mapped(&['\u{5180}'])),
+    ('\u{FA76}', '\u{FA76}', Mapping::Mapped(&['\u{52C7}'])),
+    ('\u{FA77}', '\u{FA77}', Mapping::Mapped(&['\u{52FA}'])),
+    ('\u{FA78}', '\u{FA78}', Mapping::Mapped(&['\u{559D}'])),
+    ('\u{FA79}', '\u{FA79}', Mapping::Mapped(&['\u{5555}'])),
+    ('\u{FA7A}', '\u{FA7A}', Mapping::Mapped(&['\u{5599}'])),
+    ('\u{FA7B}', '\u{FA7B}', Mapping::Mapped(&['\u{55E2}'])),
+    ('\u{FA7C}', '\u{FA7C}', Mapping::Mapped(&['\u{585A}'])),
+    ('\u{FA7D}', '\u{FA7D}', Mapping::Mapped(&['\u{58B3}'])),
+    ('\u{FA7E}', '\u{FA7E}', Mapping::Mapped(&['\u{5944}'])),
+    ('\u{FA7F}', '\u{FA7F}', Mapping::Mapped(&['\u{5954}'])),
+    ('\u{FA80}', '\u{FA80}', Mapping::Mapped(&['\u{5A62}'])),
+    ('\u{FA81}', '\u{FA81}', Mapping::Mapped(&['\u{5B28}'])),
+    ('\u{FA82}', '\u{FA82}', Mapping::Mapped(&['\u{5ED2}'])),
+    ('\u{FA83}', '\u{FA83}', Mapping::Mapped(&['\u{5ED9}'])),
+    ('\u{FA84}', '\u{FA84}', Mapping::Mapped(&['\u{5F69}'])),
+    ('\u{FA85}', '\u{FA85}', Mapping::Mapped(&['\u{5FAD}'])),
+    ('\u{FA86}', '\u{FA86}', Mapping::Mapped(&['\u{60D8}'])),
+    ('\u{FA87}', '\u{FA87}', Mapping::Mapped(&['\u{614E}'])),
+    ('\u{FA88}', '\u{FA88}', Mapping::Mapped(&['\u{6108}'])),
+    ('\u{FA89}', '\u{FA89}', Mapping::Mapped(&['\u{618E}'])),
+    ('\u{FA8A}', '\u{FA8A}', Mapping::Mapped(&['\u{6160}'])),
+    ('\u{FA8B}', '\u{FA8B}', Mapping::Mapped(&['\u{61F2}'])),
+    ('\u{FA8C}', '\u{FA8C}', Mapping::Mapped(&['\u{6234}'])),
+    ('\u{FA8D}', '\u{FA8D}', Mapping::Mapped(&['\u{63C4}'])),
+    ('\u{FA8E}', '\u{FA8E}', Mapping::Mapped(&['\u{641C}'])),
+    ('\u{FA8F}', '\u{FA8F}', Mapping::Mapped(&['\u{6452}'])),
+    ('\u{FA90}', '\u{FA90}', Mapping::Mapped(&['\u{6556}'])),
+    ('\u{FA91}', '\u{FA91}', Mapping::Mapped(&['\u{6674}'])),
+    ('\u{FA92}', '\u{FA92}', Mapping::Mapped(&['\u{6717}'])),
+    ('\u{FA93}', '\u{FA93}', Mapping::Mapped(&['\u{671B}'])),
+    ('\u{FA94}', '\u{FA94}', Mapping::Mapped(&['\u{6756}'])),
+    ('\u{FA95}', '\u{FA95}', Mapping::Mapped(&['\u{6B79}'])),
+    ('\u{FA96}', '\u{FA96}', Mapping::Mapped(&['\u{6BBA}'])),
+    ('\u{FA97}', '\u{FA97}', Mapping::Mapped(&['\u{6D41}'])),
+    ('\u{FA98}', '\u{FA98}', Mapping::Mapped(&['\u{6EDB}'])),
+    ('\u{FA99}', '\u{FA99}', Mapping::Mapped(&['\u{6ECB}'])),
+    ('\u{FA9A}', '\u{FA9A}', Mapping::Mapped(&['\u{6F22}'])),
+    ('\u{FA9B}', '\u{FA9B}', Mapping::Mapped(&['\u{701E}'])),
+    ('\u{FA9C}', '\u{FA9C}', Mapping::Mapped(&['\u{716E}'])),
+    ('\u{FA9D}', '\u{FA9D}', Mapping::Mapped(&['\u{77A7}'])),
+    ('\u{FA9E}', '\u{FA9E}', Mapping::Mapped(&['\u{7235}'])),
+    ('\u{FA9F}', '\u{FA9F}', Mapping::Mapped(&['\u{72AF}'])),
+    ('\u{FAA0}', '\u{FAA0}', Mapping::Mapped(&['\u{732A}'])),
+    ('\u{FAA1}', '\u{FAA1}', Mapping::Mapped(&['\u{7471}'])),
+    ('\u{FAA2}', '\u{FAA2}', Mapping::Mapped(&['\u{7506}'])),
+    ('\u{FAA3}', '\u{FAA3}', Mapping::Mapped(&['\u{753B}'])),
+    ('\u{FAA4}', '\u{FAA4}', Mapping::Mapped(&['\u{761D}'])),
+    ('\u{FAA5}', '\u{FAA5}', Mapping::Mapped(&['\u{761F}'])),
+    ('\u{FAA6}', '\u{FAA6}', Mapping::Mapped(&['\u{76CA}'])),
+    ('\u{FAA7}', '\u{FAA7}', Mapping::Mapped(&['\u{76DB}'])),
+    ('\u{FAA8}', '\u{FAA8}', Mapping::Mapped(&['\u{76F4}'])),
+    ('\u{FAA9}', '\u{FAA9}', Mapping::Mapped(&['\u{774A}'])),
+    ('\u{FAAA}', '\u{FAAA}', Mapping::Mapped(&['\u{7740}'])),
+    ('\u{FAAB}', '\u{FAAB}', Mapping::Mapped(&['\u{78CC}'])),
+    ('\u{FAAC}', '\u{FAAC}', Mapping::Mapped(&['\u{7AB1}'])),
+    ('\u{FAAD}', '\u{FAAD}', Mapping::Mapped(&['\u{7BC0}'])),
+    ('\u{FAAE}', '\u{FAAE}', Mapping::Mapped(&['\u{7C7B}'])),
+    ('\u{FAAF}', '\u{FAAF}', Mapping::Mapped(&['\u{7D5B}'])),
+    ('\u{FAB0}', '\u{FAB0}', Mapping::Mapped(&['\u{7DF4}'])),
+    ('\u{FAB1}', '\u{FAB1}', Mapping::Mapped(&['\u{7F3E}'])),
+    ('\u{FAB2}', '\u{FAB2}', Mapping::Mapped(&['\u{8005}'])),
+    ('\u{FAB3}', '\u{FAB3}', Mapping::Mapped(&['\u{8352}'])),
+    ('\u{FAB4}', '\u{FAB4}', Mapping::Mapped(&['\u{83EF}'])),
+    ('\u{FAB5}', '\u{FAB5}', Mapping::Mapped(&['\u{8779}'])),
+    ('\u{FAB6}', '\u{FAB6}', Mapping::Mapped(&['\u{8941}'])),
+    ('\u{FAB7}', '\u{FAB7}', Mapping::Mapped(&['\u{8986}'])),
+    ('\u{FAB8}', '\u{FAB8}', Mapping::Mapped(&['\u{8996}'])),
+    ('\u{FAB9}', '\u{FAB9}', Mapping::Mapped(&['\u{8ABF}'])),
+    ('\u{FABA}', '\u{FABA}', Mapping::Mapped(&['\u{8AF8}'])),
+    ('\u{FABB}', '\u{FABB}', Mapping::Mapped(&['\u{8ACB}'])),
+    ('\u{FABC}', '\u{FABC}', Mapping::Mapped(&['\u{8B01}'])),
+    ('\u{FABD}', '\u{FABD}', Mapping::Mapped(&['\u{8AFE}'])),
+    ('\u{FABE}', '\u{FABE}', Mapping::Mapped(&['\u{8AED}'])),
+    ('\u{FABF}', '\u{FABF}', Mapping::Mapped(&['\u{8B39}'])),
+    ('\u{FAC0}', '\u{FAC0}', Mapping::Mapped(&['\u{8B8A}'])),
+    ('\u{FAC1}', '\u{FAC1}', Mapping::Mapped(&['\u{8D08}'])),
+    ('\u{FAC2}', '\u{FAC2}', Mapping::Mapped(&['\u{8F38}'])),
+    ('\u{FAC3}', '\u{FAC3}', Mapping::Mapped(&['\u{9072}'])),
+    ('\u{FAC4}', '\u{FAC4}', Mapping::Mapped(&['\u{9199}'])),
+    ('\u{FAC5}', '\u{FAC5}', Mapping::Mapped(&['\u{9276}'])),
+    ('\u{FAC6}', '\u{FAC6}', Mapping::Mapped(&['\u{967C}'])),
+    ('\u{FAC7}', '\u{FAC7}', Mapping::Mapped(&['\u{96E3}'])),
+    ('\u{FAC8}', '\u{FAC8}', Mapping::Mapped(&['\u{9756}'])),
+    ('\u{FAC9}', '\u{FAC9}', Mapping::Mapped(&['\u{97DB}'])),
+    ('\u{FACA}', '\u{FACA}', Mapping::Mapped(&['\u{97FF}'])),
+    ('\u{FACB}', '\u{FACB}', Mapping::Mapped(&['\u{980B}'])),
+    ('\u{FACC}', '\u{FACC}', Mapping::Mapped(&['\u{983B}'])),
+    ('\u{FACD}', '\u{FACD}', Mapping::Mapped(&['\u{9B12}'])),
+    ('\u{FACE}', '\u{FACE}', Mapping::Mapped(&['\u{9F9C}'])),
+    ('\u{FACF}', '\u{FACF}', Mapping::Mapped(&['\u{2284A}'])),
+    ('\u{FAD0}', '\u{FAD0}', Mapping::Mapped(&['\u{22844}'])),
+    ('\u{FAD1}', '\u{FAD1}', Mapping::Mapped(&['\u{233D5}'])),
+    ('\u{FAD2}', '\u{FAD2}', Mapping::Mapped(&['\u{3B9D}'])),
+    ('\u{FAD3}', '\u{FAD3}', Mapping::Mapped(&['\u{4018}'])),
+    ('\u{FAD4}', '\u{FAD4}', Mapping::Mapped(&['\u{4039}'])),
+    ('\u{FAD5}', '\u{FAD5}', Mapping::Mapped(&['\u{25249}'])),
+    ('\u{FAD6}', '\u{FAD6}', Mapping::Mapped(&['\u{25CD0}'])),
+    ('\u{FAD7}', '\u{FAD7}', Mapping::Mapped(&['\u{27ED3}'])),
+    ('\u{FAD8}', '\u{FAD8}', Mapping::Mapped(&['\u{9F43}'])),
+    ('\u{FAD9}', '\u{FAD9}', Mapping::Mapped(&['\u{9F8E}'])),
+    ('\u{FADA}', '\u{FAFF}', Mapping::Disallowed),
+    ('\u{FB00}', '\u{FB00}', Mapping::Mapped(&['\u{66}', '\u{66}'])),
+    ('\u{FB01}', '\u{FB01}', Mapping::Mapped(&['\u{66}', '\u{69}'])),
+    ('\u{FB02}', '\u{FB02}', Mapping::Mapped(&['\u{66}', '\u{6C}'])),
+    ('\u{FB03}', '\u{FB03}', Mapping::Mapped(&['\u{66}', '\u{66}', '\u{69}'])),
+    ('\u{FB04}', '\u{FB04}', Mapping::Mapped(&['\u{66}', '\u{66}', '\u{6C}'])),
+    ('\u{FB05}', '\u{FB06}', Mapping::Mapped(&['\u{73}', '\u{74}'])),
+    ('\u{FB07}', '\u{FB12}', Mapping::Disallowed),
+    ('\u{FB13}', '\u{FB13}', Mapping::Mapped(&['\u{574}', '\u{576}'])),
+    ('\u{FB14}', '\u{FB14}', Mapping::Mapped(&['\u{574}', '\u{565}'])),
+    ('\u{FB15}', '\u{FB15}', Mapping::Mapped(&['\u{574}', '\u{56B}'])),
+    ('\u{FB16}', '\u{FB16}', Mapping::Mapped(&['\u{57E}', '\u{576}'])),
+    ('\u{FB17}', '\u{FB17}', Mapping::Mapped(&['\u{574}', '\u{56D}'])),
+    ('\u{FB18}', '\u{FB1C}', Mapping::Disallowed),
+    ('\u{FB1D}', '\u{FB1D}', Mapping::Mapped(&['\u{5D9}', '\u{5B4}'])),
+    ('\u{FB1E}', '\u{FB1E}', Mapping::Valid),
+    ('\u{FB1F}', '\u{FB1F}', Mapping::Mapped(&['\u{5F2}', '\u{5B7}'])),
+    ('\u{FB20}', '\u{FB20}', Mapping::Mapped(&['\u{5E2}'])),
+    ('\u{FB21}', '\u{FB21}', Mapping::Mapped(&['\u{5D0}'])),
+    ('\u{FB22}', '\u{FB22}', Mapping::Mapped(&['\u{5D3}'])),
+    ('\u{FB23}', '\u{FB23}', Mapping::Mapped(&['\u{5D4}'])),
+    ('\u{FB24}', '\u{FB24}', Mapping::Mapped(&['\u{5DB}'])),
+    ('\u{FB25}', '\u{FB25}', Mapping::Mapped(&['\u{5DC}'])),
+    ('\u{FB26}', '\u{FB26}', Mapping::Mapped(&['\u{5DD}'])),
+    ('\u{FB27}', '\u{FB27}', Mapping::Mapped(&['\u{5E8}'])),
+    ('\u{FB28}', '\u{FB28}', Mapping::Mapped(&['\u{5EA}'])),
+    ('\u{FB29}', '\u{FB29}', Mapping::DisallowedStd3Mapped(&['\u{2B}'])),
+    ('\u{FB2A}', '\u{FB2A}', Mapping::Mapped(&['\u{5E9}', '\u{5C1}'])),
+    ('\u{FB2B}', '\u{FB2B}', Mapping::Mapped(&['\u{5E9}', '\u{5C2}'])),
+    ('\u{FB2C}', '\u{FB2C}', Mapping::Mapped(&['\u{5E9}', '\u{5BC}', '\u{5C1}'])),
+    ('\u{FB2D}', '\u{FB2D}', Mapping::Mapped(&['\u{5E9}', '\u{5BC}', '\u{5C2}'])),
+    ('\u{FB2E}', '\u{FB2E}', Mapping::Mapped(&['\u{5D0}', '\u{5B7}'])),
+    ('\u{FB2F}', '\u{FB2F}', Mapping::Mapped(&['\u{5D0}', '\u{5B8}'])),
+    ('\u{FB30}', '\u{FB30}', Mapping::Mapped(&['\u{5D0}', '\u{5BC}'])),
+    ('\u{FB31}', '\u{FB31}', Mapping::Mapped(&['\u{5D1}', '\u{5BC}'])),
+    ('\u{FB32}', '\u{FB32}', Mapping::Mapped(&['\u{5D2}', '\u{5BC}'])),
+    ('\u{FB33}', '\u{FB33}', Mapping::Mapped(&['\u{5D3}', '\u{5BC}'])),
+    ('\u{FB34}', '\u{FB34}', Mapping::Mapped(&['\u{5D4}', '\u{5BC}'])),
+    ('\u{FB35}', '\u{FB35}', Mapping::Mapped(&['\u{5D5}', '\u{5BC}'])),
+    ('\u{FB36}', '\u{FB36}', Mapping::Mapped(&['\u{5D6}', '\u{5BC}'])),
+    ('\u{FB37}', '\u{FB37}', Mapping::Disallowed),
+    ('\u{FB38}', '\u{FB38}', Mapping::Mapped(&['\u{5D8}', '\u{5BC}'])),
+    ('\u{FB39}', '\u{FB39}', Mapping::Mapped(&['\u{5D9}', '\u{5BC}'])),
+    ('\u{FB3A}', '\u{FB3A}', Mapping::Mapped(&['\u{5DA}', '\u{5BC}'])),
+    ('\u{FB3B}', '\u{FB3B}', Mapping::Mapped(&['\u{5DB}', '\u{5BC}'])),
+    ('\u{FB3C}', '\u{FB3C}', Mapping::Mapped(&['\u{5DC}', '\u{5BC}'])),
+    ('\u{FB3D}', '\u{FB3D}', Mapping::Disallowed),
+    ('\u{FB3E}', '\u{FB3E}', Mapping::Mapped(&['\u{5DE}', '\u{5BC}'])),
+    ('\u{FB3F}', '\u{FB3F}', Mapping::Disallowed),
+    ('\u{FB40}', '\u{FB40}', Mapping::Mapped(&['\u{5E0}', '\u{5BC}'])),
+    ('\u{FB41}', '\u{FB41}', Mapping::Mapped(&['\u{5E1}', '\u{5BC}'])),
+    ('\u{FB42}', '\u{FB42}', Mapping::Disallowed),
+    ('\u{FB43}', '\u{FB43}', Mapping::Mapped(&['\u{5E3}', '\u{5BC}'])),
+    ('\u{FB44}', '\u{FB44}', Mapping::Mapped(&['\u{5E4}', '\u{5BC}'])),
+    ('\u{FB45}', '\u{FB45}', Mapping::Disallowed),
+    ('\u{FB46}', '\u{FB46}', Mapping::Mapped(&['\u{5E6}', '\u{5BC}'])),
+    ('\u{FB47}', '\u{FB47}', Mapping::Mapped(&['\u{5E7}', '\u{5BC}'])),
+    ('\u{FB48}', '\u{FB48}', Mapping::Mapped(&['\u{5E8}', '\u{5BC}'])),
+    ('\u{FB49}', '\u{FB49}', Mapping::Mapped(&['\u{5E9}', '\u{5BC}'])),
+    ('\u{FB4A}', '\u{FB4A}', Mapping::Mapped(&['\u{5EA}', '\u{5BC}'])),
+    ('\u{FB4B}', '\u{FB4B}', Mapping::Mapped(&['\u{5D5}', '\u{5B9}'])),
+    ('\u{FB4C}', '\u{FB4C}', Mapping::Mapped(&['\u{5D1}', '\u{5BF}'])),
+    ('\u{FB4D}', '\u{FB4D}', Mapping::Mapped(&['\u{5DB}', '\u{5BF}'])),
+    ('\u{FB4E}', '\u{FB4E}', Mapping::Mapped(&['\u{5E4}', '\u{5BF}'])),
+    ('\u{FB4F}', '\u{FB4F}', Mapping::Mapped(&['\u{5D0}', '\u{5DC}'])),
+    ('\u{FB50}', '\u{FB51}', Mapping::Mapped(&['\u{671}'])),
+    ('\u{FB52}', '\u{FB55}', Mapping::Mapped(&['\u{67B}'])),
+    ('\u{FB56}', '\u{FB59}', Mapping::Mapped(&['\u{67E}'])),
+    ('\u{FB5A}', '\u{FB5D}', Mapping::Mapped(&['\u{680}'])),
+    ('\u{FB5E}', '\u{FB61}', Mapping::Mapped(&['\u{67A}'])),
+    ('\u{FB62}', '\u{FB65}', Mapping::Mapped(&['\u{67F}'])),
+    ('\u{FB66}', '\u{FB69}', Mapping::Mapped(&['\u{679}'])),
+    ('\u{FB6A}', '\u{FB6D}', Mapping::Mapped(&['\u{6A4}'])),
+    ('\u{FB6E}', '\u{FB71}', Mapping::Mapped(&['\u{6A6}'])),
+    ('\u{FB72}', '\u{FB75}', Mapping::Mapped(&['\u{684}'])),
+    ('\u{FB76}', '\u{FB79}', Mapping::Mapped(&['\u{683}'])),
+    ('\u{FB7A}', '\u{FB7D}', Mapping::Mapped(&['\u{686}'])),
+    ('\u{FB7E}', '\u{FB81}', Mapping::Mapped(&['\u{687}'])),
+    ('\u{FB82}', '\u{FB83}', Mapping::Mapped(&['\u{68D}'])),
+    ('\u{FB84}', '\u{FB85}', Mapping::Mapped(&['\u{68C}'])),
+    ('\u{FB86}', '\u{FB87}', Mapping::Mapped(&['\u{68E}'])),
+    ('\u{FB88}', '\u{FB89}', Mapping::Mapped(&['\u{688}'])),
+    ('\u{FB8A}', '\u{FB8B}', Mapping::Mapped(&['\u{698}'])),
+    ('\u{FB8C}', '\u{FB8D}', Mapping::Mapped(&['\u{691}'])),
+    ('\u{FB8E}', '\u{FB91}', Mapping::Mapped(&['\u{6A9}'])),
+    ('\u{FB92}', '\u{FB95}', Mapping::Mapped(&['\u{6AF}'])),
+    ('\u{FB96}', '\u{FB99}', Mapping::Mapped(&['\u{6B3}'])),
+    ('\u{FB9A}', '\u{FB9D}', Mapping::Mapped(&['\u{6B1}'])),
+    ('\u{FB9E}', '\u{FB9F}', Mapping::Mapped(&['\u{6BA}'])),
+    ('\u{FBA0}', '\u{FBA3}', Mapping::Mapped(&['\u{6BB}'])),
+    ('\u{FBA4}', '\u{FBA5}', Mapping::Mapped(&['\u{6C0}'])),
+    ('\u{FBA6}', '\u{FBA9}', Mapping::Mapped(&['\u{6C1}'])),
+    ('\u{FBAA}', '\u{FBAD}', Mapping::Mapped(&['\u{6BE}'])),
+    ('\u{FBAE}', '\u{FBAF}', Mapping::Mapped(&['\u{6D2}'])),
+    ('\u{FBB0}', '\u{FBB1}', Mapping::Mapped(&['\u{6D3}'])),
+    ('\u{FBB2}', '\u{FBC1}', Mapping::Valid),
+    ('\u{FBC2}', '\u{FBD2}', Mapping::Disallowed),
+    ('\u{FBD3}', '\u{FBD6}', Mapping::Mapped(&['\u{6AD}'])),
+    ('\u{FBD7}', '\u{FBD8}', Mapping::Mapped(&['\u{6C7}'])),
+    ('\u{FBD9}', '\u{FBDA}', Mapping::Mapped(&['\u{6C6}'])),
+    ('\u{FBDB}', '\u{FBDC}', Mapping::Mapped(&['\u{6C8}'])),
+    ('\u{FBDD}', '\u{FBDD}', Mapping::Mapped(&['\u{6C7}', '\u{674}'])),
+    ('\u{FBDE}', '\u{FBDF}', Mapping::Mapped(&['\u{6CB}'])),
+    ('\u{FBE0}', '\u{FBE1}', Mapping::Mapped(&['\u{6C5}'])),
+    ('\u{FBE2}', '\u{FBE3}', Mapping::Mapped(&['\u{6C9}'])),
+    ('\u{FBE4}', '\u{FBE7}', Mapping::Mapped(&['\u{6D0}'])),
+    ('\u{FBE8}', '\u{FBE9}', Mapping::Mapped(&['\u{649}'])),
+    ('\u{FBEA}', '\u{FBEB}', Mapping::Mapped(&['\u{626}', '\u{627}'])),
+    ('\u{FBEC}', '\u{FBED}', Mapping::Mapped(&['\u{626}', '\u{6D5}'])),
+    ('\u{FBEE}', '\u{FBEF}', Mapping::Mapped(&['\u{626}', '\u{648}'])),
+    ('\u{FBF0}', '\u{FBF1}', Mapping::Mapped(&['\u{626}', '\u{6C7}'])),
+    ('\u{FBF2}', '\u{FBF3}', Mapping::Mapped(&['\u{626}', '\u{6C6}'])),
+    ('\u{FBF4}', '\u{FBF5}', Mapping::Mapped(&['\u{626}', '\u{6C8}'])),
+    ('\u{FBF6}', '\u{FBF8}', Mapping::Mapped(&['\u{626}', '\u{6D0}'])),
+    ('\u{FBF9}', '\u{FBFB}', Mapping::Mapped(&['\u{626}', '\u{649}'])),
+    ('\u{FBFC}', '\u{FBFF}', Mapping::Mapped(&['\u{6CC}'])),
+    ('\u{FC00}', '\u{FC00}', Mapping::Mapped(&['\u{626}', '\u{62C}'])),
+    ('\u{FC01}', '\u{FC01}', Mapping::Mapped(&['\u{626}', '\u{62D}'])),
+    ('\u{FC02}', '\u{FC02}', Mapping::Mapped(&['\u{626}', '\u{645}'])),
+    ('\u{FC03}', '\u{FC03}', Mapping::Mapped(&['\u{626}', '\u{649}'])),
+    ('\u{FC04}', '\u{FC04}', Mapping::Mapped(&['\u{626}', '\u{64A}'])),
+    ('\u{FC05}', '\u{FC05}', Mapping::Mapped(&['\u{628}', '\u{62C}'])),
+    ('\u{FC06}', '\u{FC06}', Mapping::Mapped(&['\u{628}', '\u{62D}'])),
+    ('\u{FC07}', '\u{FC07}', Mapping::Mapped(&['\u{628}', '\u{62E}'])),
+    ('\u{FC08}', '\u{FC08}', Mapping::Mapped(&['\u{628}', '\u{645}'])),
+    ('\u{FC09}', '\u{FC09}', Mapping::Mapped(&['\u{628}', '\u{649}'])),
+    ('\u{FC0A}', '\u{FC0A}', Mapping::Mapped(&['\u{628}', '\u{64A}'])),
+    ('\u{FC0B}', '\u{FC0B}', Mapping::Mapped(&['\u{62A}', '\u{62C}'])),
+    ('\u{FC0C}', '\u{FC0C}', Mapping::Mapped(&['\u{62A}', '\u{62D}'])),
+    ('\u{FC0D}', '\u{FC0D}', Mapping::Mapped(&['\u{62A}', '\u{62E}'])),
+    ('\u{FC0E}', '\u{FC0E}', Mapping::Mapped(&['\u{62A}', '\u{645}'])),
+    ('\u{FC0F}', '\u{FC0F}', Mapping::Mapped(&['\u{62A}', '\u{649}'])),
+    ('\u{FC10}', '\u{FC10}', Mapping::Mapped(&['\u{62A}', '\u{64A}'])),
+    ('\u{FC11}', '\u{FC11}', Mapping::Mapped(&['\u{62B}', '\u{62C}'])),
+    ('\u{FC12}', '\u{FC12}', Mapping::Mapped(&['\u{62B}', '\u{645}'])),
+    ('\u{FC13}', '\u{FC13}', Mapping::Mapped(&['\u{62B}', '\u{649}'])),
+    ('\u{FC14}', '\u{FC14}', Mapping::Mapped(&['\u{62B}', '\u{64A}'])),
+    ('\u{FC15}', '\u{FC15}', Mapping::Mapped(&['\u{62C}', '\u{62D}'])),
+    ('\u{FC16}', '\u{FC16}', Mapping::Mapped(&['\u{62C}', '\u{645}'])),
+    ('\u{FC17}', '\u{FC17}', Mapping::Mapped(&['\u{62D}', '\u{62C}'])),
+    ('\u{FC18}', '\u{FC18}', Mapping::Mapped(&['\u{62D}', '\u{645}'])),
+    ('\u{FC19}', '\u{FC19}', Mapping::Mapped(&['\u{62E}', '\u{62C}'])),
+    ('\u{FC1A}', '\u{FC1A}', Mapping::Mapped(&['\u{62E}', '\u{62D}'])),
+    ('\u{FC1B}', '\u{FC1B}', Mapping::Mapped(&['\u{62E}', '\u{645}'])),
+    ('\u{FC1C}', '\u{FC1C}', Mapping::Mapped(&['\u{633}', '\u{62C}'])),
+    ('\u{FC1D}', '\u{FC1D}', Mapping::Mapped(&['\u{633}', '\u{62D}'])),
+    ('\u{FC1E}', '\u{FC1E}', Mapping::Mapped(&['\u{633}', '\u{62E}'])),
+    ('\u{FC1F}', '\u{FC1F}', Mapping::Mapped(&['\u{633}', '\u{645}'])),
+    ('\u{FC20}', '\u{FC20}', Mapping::Mapped(&['\u{635}', '\u{62D}'])),
+    ('\u{FC21}', '\u{FC21}', Mapping::Mapped(&['\u{635}', '\u{645}'])),
+    ('\u{FC22}', '\u{FC22}', Mapping::Mapped(&['\u{636}', '\u{62C}'])),
+    ('\u{FC23}', '\u{FC23}', Mapping::Mapped(&['\u{636}', '\u{62D}'])),
+    ('\u{FC24}', '\u{FC24}', Mapping::Mapped(&['\u{636}', '\u{62E}'])),
+    ('\u{FC25}', '\u{FC25}', Mapping::Mapped(&['\u{636}', '\u{645}'])),
+    ('\u{FC26}', '\u{FC26}', Mapping::Mapped(&['\u{637}', '\u{62D}'])),
+    ('\u{FC27}', '\u{FC27}', Mapping::Mapped(&['\u{637}', '\u{645}'])),
+    ('\u{FC28}', '\u{FC28}', Mapping::Mapped(&['\u{638}', '\u{645}'])),
+    ('\u{FC29}', '\u{FC29}', Mapping::Mapped(&['\u{639}', '\u{62C}'])),
+    ('\u{FC2A}', '\u{FC2A}', Mapping::Mapped(&['\u{639}', '\u{645}'])),
+    ('\u{FC2B}', '\u{FC2B}', Mapping::Mapped(&['\u{63A}', '\u{62C}'])),
+    ('\u{FC2C}', '\u{FC2C}', Mapping::Mapped(&['\u{63A}', '\u{645}'])),
+    ('\u{FC2D}', '\u{FC2D}', Mapping::Mapped(&['\u{641}', '\u{62C}'])),
+    ('\u{FC2E}', '\u{FC2E}', Mapping::Mapped(&['\u{641}', '\u{62D}'])),
+    ('\u{FC2F}', '\u{FC2F}', Mapping::Mapped(&['\u{641}', '\u{62E}'])),
+    ('\u{FC30}', '\u{FC30}', Mapping::Mapped(&['\u{641}', '\u{645}'])),
+    ('\u{FC31}', '\u{FC31}', Mapping::Mapped(&['\u{641}', '\u{649}'])),
+    ('\u{FC32}', '\u{FC32}', Mapping::Mapped(&['\u{641}', '\u{64A}'])),
+    ('\u{FC33}', '\u{FC33}', Mapping::Mapped(&['\u{642}', '\u{62D}'])),
+    ('\u{FC34}', '\u{FC34}', Mapping::Mapped(&['\u{642}', '\u{645}'])),
+    ('\u{FC35}', '\u{FC35}', Mapping::Mapped(&['\u{642}', '\u{649}'])),
+    ('\u{FC36}', '\u{FC36}', Mapping::Mapped(&['\u{642}', '\u{64A}'])),
+    ('\u{FC37}', '\u{FC37}', Mapping::Mapped(&['\u{643}', '\u{627}'])),
+    ('\u{FC38}', '\u{FC38}', Mapping::Mapped(&['\u{643}', '\u{62C}'])),
+    ('\u{FC39}', '\u{FC39}', Mapping::Mapped(&['\u{643}', '\u{62D}'])),
+    ('\u{FC3A}', '\u{FC3A}', Mapping::Mapped(&['\u{643}', '\u{62E}'])),
+    ('\u{FC3B}', '\u{FC3B}', Mapping::Mapped(&['\u{643}', '\u{644}'])),
+    ('\u{FC3C}', '\u{FC3C}', Mapping::Mapped(&['\u{643}', '\u{645}'])),
+    ('\u{FC3D}', '\u{FC3D}', Mapping::Mapped(&['\u{643}', '\u{649}'])),
+    ('\u{FC3E}', '\u{FC3E}', Mapping::Mapped(&['\u{643}', '\u{64A}'])),
+    ('\u{FC3F}', '\u{FC3F}', Mapping::Mapped(&['\u{644}', '\u{62C}'])),
+    ('\u{FC40}', '\u{FC40}', Mapping::Mapped(&['\u{644}', '\u{62D}'])),
+    ('\u{FC41}', '\u{FC41}', Mapping::Mapped(&['\u{644}', '\u{62E}'])),
+    ('\u{FC42}', '\u{FC42}', Mapping::Mapped(&['\u{644}', '\u{645}'])),
+    ('\u{FC43}', '\u{FC43}', Mapping::Mapped(&['\u{644}', '\u{649}'])),
+    ('\u{FC44}', '\u{FC44}', Mapping::Mapped(&['\u{644}', '\u{64A}'])),
+    ('\u{FC45}', '\u{FC45}', Mapping::Mapped(&['\u{645}', '\u{62C}'])),
+    ('\u{FC46}', '\u{FC46}', Mapping::Mapped(&['\u{645}', '\u{62D}'])),
+    ('\u{FC47}', '\u{FC47}', Mapping::Mapped(&['\u{645}', '\u{62E}'])),
+    ('\u{FC48}', '\u{FC48}', Mapping::Mapped(&['\u{645}', '\u{645}'])),
+    ('\u{FC49}', '\u{FC49}', Mapping::Mapped(&['\u{645}', '\u{649}'])),
+    ('\u{FC4A}', '\u{FC4A}', Mapping::Mapped(&['\u{645}', '\u{64A}'])),
+    ('\u{FC4B}', '\u{FC4B}', Mapping::Mapped(&['\u{646}', '\u{62C}'])),
+    ('\u{FC4C}', '\u{FC4C}', Mapping::Mapped(&['\u{646}', '\u{62D}'])),
+    ('\u{FC4D}', '\u{FC4D}', Mapping::Mapped(&['\u{646}', '\u{62E}'])),
+    ('\u{FC4E}', '\u{FC4E}', Mapping::Mapped(&['\u{646}', '\u{645}'])),
+    ('\u{FC4F}', '\u{FC4F}', Mapping::Mapped(&['\u{646}', '\u{649}'])),
+    ('\u{FC50}', '\u{FC50}', Mapping::Mapped(&['\u{646}', '\u{64A}'])),
+    ('\u{FC51}', '\u{FC51}', Mapping::Mapped(&['\u{647}', '\u{62C}'])),
+    ('\u{FC52}', '\u{FC52}', Mapping::Mapped(&['\u{647}', '\u{645}'])),
+    ('\u{FC53}', '\u{FC53}', Mapping::Mapped(&['\u{647}', '\u{649}'])),
+    ('\u{FC54}', '\u{FC54}', Mapping::Mapped(&['\u{647}', '\u{64A}'])),
+    ('\u{FC55}', '\u{FC55}', Mapping::Mapped(&['\u{64A}', '\u{62C}'])),
+    ('\u{FC56}', '\u{FC56}', Mapping::Mapped(&['\u{64A}', '\u{62D}'])),
+    ('\u{FC57}', '\u{FC57}', Mapping::Mapped(&['\u{64A}', '\u{62E}'])),
+    ('\u{FC58}', '\u{FC58}', Mapping::Mapped(&['\u{64A}', '\u{645}'])),
+    ('\u{FC59}', '\u{FC59}', Mapping::Mapped(&['\u{64A}', '\u{649}'])),
+    ('\u{FC5A}', '\u{FC5A}', Mapping::Mapped(&['\u{64A}', '\u{64A}'])),
+    ('\u{FC5B}', '\u{FC5B}', Mapping::Mapped(&['\u{630}', '\u{670}'])),
+    ('\u{FC5C}', '\u{FC5C}', Mapping::Mapped(&['\u{631}', '\u{670}'])),
+    ('\u{FC5D}', '\u{FC5D}', Mapping::Mapped(&['\u{649}', '\u{670}'])),
+    ('\u{FC5E}', '\u{FC5E}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{64C}', '\u{651}'])),
+    ('\u{FC5F}', '\u{FC5F}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{64D}', '\u{651}'])),
+    ('\u{FC60}', '\u{FC60}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{64E}', '\u{651}'])),
+    ('\u{FC61}', '\u{FC61}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{64F}', '\u{651}'])),
+    ('\u{FC62}', '\u{FC62}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{650}', '\u{651}'])),
+    ('\u{FC63}', '\u{FC63}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{651}', '\u{670}'])),
+    ('\u{FC64}', '\u{FC64}', Mapping::Mapped(&['\u{626}', '\u{631}'])),
+    ('\u{FC65}', '\u{FC65}', Mapping::Mapped(&['\u{626}', '\u{632}'])),
+    ('\u{FC66}', '\u{FC66}', Mapping::Mapped(&['\u{626}', '\u{645}'])),
+    ('\u{FC67}', '\u{FC67}', Mapping::Mapped(&['\u{626}', '\u{646}'])),
+    ('\u{FC68}', '\u{FC68}', Mapping::Mapped(&['\u{626}', '\u{649}'])),
+    ('\u{FC69}', '\u{FC69}', Mapping::Mapped(&['\u{626}', '\u{64A}'])),
+    ('\u{FC6A}', '\u{FC6A}', Mapping::Mapped(&['\u{628}', '\u{631}'])),
+    ('\u{FC6B}', '\u{FC6B}', Mapping::Mapped(&['\u{628}', '\u{632}'])),
+    ('\u{FC6C}', '\u{FC6C}', Mapping::Mapped(&['\u{628}', '\u{645}'])),
+    ('\u{FC6D}', '\u{FC6D}', Mapping::Mapped(&['\u{628}', '\u{646}'])),
+    ('\u{FC6E}', '\u{FC6E}', Mapping::Mapped(&['\u{628}', '\u{649}'])),
+    ('\u{FC6F}', '\u{FC6F}', Mapping::Mapped(&['\u{628}', '\u{64A}'])),
+    ('\u{FC70}', '\u{FC70}', Mapping::Mapped(&['\u{62A}', '\u{631}'])),
+    ('\u{FC71}', '\u{FC71}', Mapping::Mapped(&['\u{62A}', '\u{632}'])),
+    ('\u{FC72}', '\u{FC72}', Mapping::Mapped(&['\u{62A}', '\u{645}'])),
+    ('\u{FC73}', '\u{FC73}', Mapping::Mapped(&['\u{62A}', '\u{646}'])),
+    ('\u{FC74}', '\u{FC74}', Mapping::Mapped(&['\u{62A}', '\u{649}'])),
+    ('\u{FC75}', '\u{FC75}', Mapping::Mapped(&['\u{62A}', '\u{64A}'])),
+    ('\u{FC76}', '\u{FC76}', Mapping::Mapped(&['\u{62B}', '\u{631}'])),
+    ('\u{FC77}', '\u{FC77}', Mapping::Mapped(&['\u{62B}', '\u{632}'])),
+    ('\u{FC78}', '\u{FC78}', Mapping::Mapped(&['\u{62B}', '\u{645}'])),
+    ('\u{FC79}', '\u{FC79}', Mapping::Mapped(&['\u{62B}', '\u{646}'])),
+    ('\u{FC7A}', '\u{FC7A}', Mapping::Mapped(&['\u{62B}', '\u{649}'])),
+    ('\u{FC7B}', '\u{FC7B}', Mapping::Mapped(&['\u{62B}', '\u{64A}'])),
+    ('\u{FC7C}', '\u{FC7C}', Mapping::Mapped(&['\u{641}', '\u{649}'])),
+    ('\u{FC7D}', '\u{FC7D}', Mapping::Mapped(&['\u{641}', '\u{64A}'])),
+    ('\u{FC7E}', '\u{FC7E}', Mapping::Mapped(&['\u{642}', '\u{649}'])),
+    ('\u{FC7F}', '\u{FC7F}', Mapping::Mapped(&['\u{642}', '\u{64A}'])),
+    ('\u{FC80}', '\u{FC80}', Mapping::Mapped(&['\u{643}', '\u{627}'])),
+    ('\u{FC81}', '\u{FC81}', Mapping::Mapped(&['\u{643}', '\u{644}'])),
+    ('\u{FC82}', '\u{FC82}', Mapping::Mapped(&['\u{643}', '\u{645}'])),
+    ('\u{FC83}', '\u{FC83}', Mapping::Mapped(&['\u{643}', '\u{649}'])),
+    ('\u{FC84}', '\u{FC84}', Mapping::Mapped(&['\u{643}', '\u{64A}'])),
+    ('\u{FC85}', '\u{FC85}', Mapping::Mapped(&['\u{644}', '\u{645}'])),
+    ('\u{FC86}', '\u{FC86}', Mapping::Mapped(&['\u{644}', '\u{649}'])),
+    ('\u{FC87}', '\u{FC87}', Mapping::Mapped(&['\u{644}', '\u{64A}'])),
+    ('\u{FC88}', '\u{FC88}', Mapping::Mapped(&['\u{645}', '\u{627}'])),
+    ('\u{FC89}', '\u{FC89}', Mapping::Mapped(&['\u{645}', '\u{645}'])),
+    ('\u{FC8A}', '\u{FC8A}', Mapping::Mapped(&['\u{646}', '\u{631}'])),
+    ('\u{FC8B}', '\u{FC8B}', Mapping::Mapped(&['\u{646}', '\u{632}'])),
+    ('\u{FC8C}', '\u{FC8C}', Mapping::Mapped(&['\u{646}', '\u{645}'])),
+    ('\u{FC8D}', '\u{FC8D}', Mapping::Mapped(&['\u{646}', '\u{646}'])),
+    ('\u{FC8E}', '\u{FC8E}', Mapping::Mapped(&['\u{646}', '\u{649}'])),
+    ('\u{FC8F}', '\u{FC8F}', Mapping::Mapped(&['\u{646}', '\u{64A}'])),
+    ('\u{FC90}', '\u{FC90}', Mapping::Mapped(&['\u{649}', '\u{670}'])),
+    ('\u{FC91}', '\u{FC91}', Mapping::Mapped(&['\u{64A}', '\u{631}'])),
+    ('\u{FC92}', '\u{FC92}', Mapping::Mapped(&['\u{64A}', '\u{632}'])),
+    ('\u{FC93}', '\u{FC93}', Mapping::Mapped(&['\u{64A}', '\u{645}'])),
+    ('\u{FC94}', '\u{FC94}', Mapping::Mapped(&['\u{64A}', '\u{646}'])),
+    ('\u{FC95}', '\u{FC95}', Mapping::Mapped(&['\u{64A}', '\u{649}'])),
+    ('\u{FC96}', '\u{FC96}', Mapping::Mapped(&['\u{64A}', '\u{64A}'])),
+    ('\u{FC97}', '\u{FC97}', Mapping::Mapped(&['\u{626}', '\u{62C}'])),
+    ('\u{FC98}', '\u{FC98}', Mapping::Mapped(&['\u{626}', '\u{62D}'])),
+    ('\u{FC99}', '\u{FC99}', Mapping::Mapped(&['\u{626}', '\u{62E}'])),
+    ('\u{FC9A}', '\u{FC9A}', Mapping::Mapped(&['\u{626}', '\u{645}'])),
+    ('\u{FC9B}', '\u{FC9B}', Mapping::Mapped(&['\u{626}', '\u{647}'])),
+    ('\u{FC9C}', '\u{FC9C}', Mapping::Mapped(&['\u{628}', '\u{62C}'])),
+    ('\u{FC9D}', '\u{FC9D}', Mapping::Mapped(&['\u{628}', '\u{62D}'])),
+    ('\u{FC9E}', '\u{FC9E}', Mapping::Mapped(&['\u{628}', '\u{62E}'])),
+    ('\u{FC9F}', '\u{FC9F}', Mapping::Mapped(&['\u{628}', '\u{645}'])),
+    ('\u{FCA0}', '\u{FCA0}', Mapping::Mapped(&['\u{628}', '\u{647}'])),
+    ('\u{FCA1}', '\u{FCA1}', Mapping::Mapped(&['\u{62A}', '\u{62C}'])),
+    ('\u{FCA2}', '\u{FCA2}', Mapping::Mapped(&['\u{62A}', '\u{62D}'])),
+    ('\u{FCA3}', '\u{FCA3}', Mapping::Mapped(&['\u{62A}', '\u{62E}'])),
+    ('\u{FCA4}', '\u{FCA4}', Mapping::Mapped(&['\u{62A}', '\u{645}'])),
+    ('\u{FCA5}', '\u{FCA5}', Mapping::Mapped(&['\u{62A}', '\u{647}'])),
+    ('\u{FCA6}', '\u{FCA6}', Mapping::Mapped(&['\u{62B}', '\u{645}'])),
+    ('\u{FCA7}', '\u{FCA7}', Mapping::Mapped(&['\u{62C}', '\u{62D}'])),
+    ('\u{FCA8}', '\u{FCA8}', Mapping::Mapped(&['\u{62C}', '\u{645}'])),
+    ('\u{FCA9}', '\u{FCA9}', Mapping::Mapped(&['\u{62D}', '\u{62C}'])),
+    ('\u{FCAA}', '\u{FCAA}', Mapping::Mapped(&['\u{62D}', '\u{645}'])),
+    ('\u{FCAB}', '\u{FCAB}', Mapping::Mapped(&['\u{62E}', '\u{62C}'])),
+    ('\u{FCAC}', '\u{FCAC}', Mapping::Mapped(&['\u{62E}', '\u{645}'])),
+    ('\u{FCAD}', '\u{FCAD}', Mapping::Mapped(&['\u{633}', '\u{62C}'])),
+    ('\u{FCAE}', '\u{FCAE}', Mapping::Mapped(&['\u{633}', '\u{62D}'])),
+    ('\u{FCAF}', '\u{FCAF}', Mapping::Mapped(&['\u{633}', '\u{62E}'])),
+    ('\u{FCB0}', '\u{FCB0}', Mapping::Mapped(&['\u{633}', '\u{645}'])),
+    ('\u{FCB1}', '\u{FCB1}', Mapping::Mapped(&['\u{635}', '\u{62D}'])),
+    ('\u{FCB2}', '\u{FCB2}', Mapping::Mapped(&['\u{635}', '\u{62E}'])),
+    ('\u{FCB3}', '\u{FCB3}', Mapping::Mapped(&['\u{635}', '\u{645}'])),
+    ('\u{FCB4}', '\u{FCB4}', Mapping::Mapped(&['\u{636}', '\u{62C}'])),
+    ('\u{FCB5}', '\u{FCB5}', Mapping::Mapped(&['\u{636}', '\u{62D}'])),
+    ('\u{FCB6}', '\u{FCB6}', Mapping::Mapped(&['\u{636}', '\u{62E}'])),
+    ('\u{FCB7}', '\u{FCB7}', Mapping::Mapped(&['\u{636}', '\u{645}'])),
+    ('\u{FCB8}', '\u{FCB8}', Mapping::Mapped(&['\u{637}', '\u{62D}'])),
+    ('\u{FCB9}', '\u{FCB9}', Mapping::Mapped(&['\u{638}', '\u{645}'])),
+    ('\u{FCBA}', '\u{FCBA}', Mapping::Mapped(&['\u{639}', '\u{62C}'])),
+    ('\u{FCBB}', '\u{FCBB}', Mapping::Mapped(&['\u{639}', '\u{645}'])),
+    ('\u{FCBC}', '\u{FCBC}', Mapping::Mapped(&['\u{63A}', '\u{62C}'])),
+    ('\u{FCBD}', '\u{FCBD}', Mapping::Mapped(&['\u{63A}', '\u{645}'])),
+    ('\u{FCBE}', '\u{FCBE}', Mapping::Mapped(&['\u{641}', '\u{62C}'])),
+    ('\u{FCBF}', '\u{FCBF}', Mapping::Mapped(&['\u{641}', '\u{62D}'])),
+    ('\u{FCC0}', '\u{FCC0}', Mapping::Mapped(&['\u{641}', '\u{62E}'])),
+    ('\u{FCC1}', '\u{FCC1}', Mapping::Mapped(&['\u{641}', '\u{645}'])),
+    ('\u{FCC2}', '\u{FCC2}', Mapping::Mapped(&['\u{642}', '\u{62D}'])),
+    ('\u{FCC3}', '\u{FCC3}', Mapping::Mapped(&['\u{642}', '\u{645}'])),
+    ('\u{FCC4}', '\u{FCC4}', Mapping::Mapped(&['\u{643}', '\u{62C}'])),
+    ('\u{FCC5}', '\u{FCC5}', Mapping::Mapped(&['\u{643}', '\u{62D}'])),
+    ('\u{FCC6}', '\u{FCC6}', Mapping::Mapped(&['\u{643}', '\u{62E}'])),
+    ('\u{FCC7}', '\u{FCC7}', Mapping::Mapped(&['\u{643}', '\u{644}'])),
+    ('\u{FCC8}', '\u{FCC8}', Mapping::Mapped(&['\u{643}', '\u{645}'])),
+    ('\u{FCC9}', '\u{FCC9}', Mapping::Mapped(&['\u{644}', '\u{62C}'])),
+    ('\u{FCCA}', '\u{FCCA}', Mapping::Mapped(&['\u{644}', '\u{62D}'])),
+    ('\u{FCCB}', '\u{FCCB}', Mapping::Mapped(&['\u{644}', '\u{62E}'])),
+    ('\u{FCCC}', '\u{FCCC}', Mapping::Mapped(&['\u{644}', '\u{645}'])),
+    ('\u{FCCD}', '\u{FCCD}', Mapping::Mapped(&['\u{644}', '\u{647}'])),
+    ('\u{FCCE}', '\u{FCCE}', Mapping::Mapped(&['\u{645}', '\u{62C}'])),
+    ('\u{FCCF}', '\u{FCCF}', Mapping::Mapped(&['\u{645}', '\u{62D}'])),
+    ('\u{FCD0}', '\u{FCD0}', Mapping::Mapped(&['\u{645}', '\u{62E}'])),
+    ('\u{FCD1}', '\u{FCD1}', Mapping::Mapped(&['\u{645}', '\u{645}'])),
+    ('\u{FCD2}', '\u{FCD2}', Mapping::Mapped(&['\u{646}', '\u{62C}'])),
+    ('\u{FCD3}', '\u{FCD3}', Mapping::Mapped(&['\u{646}', '\u{62D}'])),
+    ('\u{FCD4}', '\u{FCD4}', Mapping::Mapped(&['\u{646}', '\u{62E}'])),
+    ('\u{FCD5}', '\u{FCD5}', Mapping::Mapped(&['\u{646}', '\u{645}'])),
+    ('\u{FCD6}', '\u{FCD6}', Mapping::Mapped(&['\u{646}', '\u{647}'])),
+    ('\u{FCD7}', '\u{FCD7}', Mapping::Mapped(&['\u{647}', '\u{62C}'])),
+    ('\u{FCD8}', '\u{FCD8}', Mapping::Mapped(&['\u{647}', '\u{645}'])),
+    ('\u{FCD9}', '\u{FCD9}', Mapping::Mapped(&['\u{647}', '\u{670}'])),
+    ('\u{FCDA}', '\u{FCDA}', Mapping::Mapped(&['\u{64A}', '\u{62C}'])),
+    ('\u{FCDB}', '\u{FCDB}', Mapping::Mapped(&['\u{64A}', '\u{62D}'])),
+    ('\u{FCDC}', '\u{FCDC}', Mapping::Mapped(&['\u{64A}', '\u{62E}'])),
+    ('\u{FCDD}', '\u{FCDD}', Mapping::Mapped(&['\u{64A}', '\u{645}'])),
+    ('\u{FCDE}', '\u{FCDE}', Mapping::Mapped(&['\u{64A}', '\u{647}'])),
+    ('\u{FCDF}', '\u{FCDF}', Mapping::Mapped(&['\u{626}', '\u{645}'])),
+    ('\u{FCE0}', '\u{FCE0}', Mapping::Mapped(&['\u{626}', '\u{647}'])),
+    ('\u{FCE1}', '\u{FCE1}', Mapping::Mapped(&['\u{628}', '\u{645}'])),
+    ('\u{FCE2}', '\u{FCE2}', Mapping::Mapped(&['\u{628}', '\u{647}'])),
+    ('\u{FCE3}', '\u{FCE3}', Mapping::Mapped(&['\u{62A}', '\u{645}'])),
+    ('\u{FCE4}', '\u{FCE4}', Mapping::Mapped(&['\u{62A}', '\u{647}'])),
+    ('\u{FCE5}', '\u{FCE5}', Mapping::Mapped(&['\u{62B}', '\u{645}'])),
+    ('\u{FCE6}', '\u{FCE6}', Mapping::Mapped(&['\u{62B}', '\u{647}'])),
+    ('\u{FCE7}', '\u{FCE7}', Mapping::Mapped(&['\u{633}', '\u{645}'])),
+    ('\u{FCE8}', '\u{FCE8}', Mapping::Mapped(&['\u{633}', '\u{647}'])),
+    ('\u{FCE9}', '\u{FCE9}', Mapping::Mapped(&['\u{634}', '\u{645}'])),
+    ('\u{FCEA}', '\u{FCEA}', Mapping::Mapped(&['\u{634}', '\u{647}'])),
+    ('\u{FCEB}', '\u{FCEB}', Mapping::Mapped(&['\u{643}', '\u{644}'])),
+    ('\u{FCEC}', '\u{FCEC}', Mapping::Mapped(&['\u{643}', '\u{645}'])),
+    ('\u{FCED}', '\u{FCED}', Mapping::Mapped(&['\u{644}', '\u{645}'])),
+    ('\u{FCEE}', '\u{FCEE}', Mapping::Mapped(&['\u{646}', '\u{645}'])),
+    ('\u{FCEF}', '\u{FCEF}', Mapping::Mapped(&['\u{646}', '\u{647}'])),
+    ('\u{FCF0}', '\u{FCF0}', Mapping::Mapped(&['\u{64A}', '\u{645}'])),
+    ('\u{FCF1}', '\u{FCF1}', Mapping::Mapped(&['\u{64A}', '\u{647}'])),
+    ('\u{FCF2}', '\u{FCF2}', Mapping::Mapped(&['\u{640}', '\u{64E}', '\u{651}'])),
+    ('\u{FCF3}', '\u{FCF3}', Mapping::Mapped(&['\u{640}', '\u{64F}', '\u{651}'])),
+    ('\u{FCF4}', '\u{FCF4}', Mapping::Mapped(&['\u{640}', '\u{650}', '\u{651}'])),
+    ('\u{FCF5}', '\u{FCF5}', Mapping::Mapped(&['\u{637}', '\u{649}'])),
+    ('\u{FCF6}', '\u{FCF6}', Mapping::Mapped(&['\u{637}', '\u{64A}'])),
+    ('\u{FCF7}', '\u{FCF7}', Mapping::Mapped(&['\u{639}', '\u{649}'])),
+    ('\u{FCF8}', '\u{FCF8}', Mapping::Mapped(&['\u{639}', '\u{64A}'])),
+    ('\u{FCF9}', '\u{FCF9}', Mapping::Mapped(&['\u{63A}', '\u{649}'])),
+    ('\u{FCFA}', '\u{FCFA}', Mapping::Mapped(&['\u{63A}', '\u{64A}'])),
+    ('\u{FCFB}', '\u{FCFB}', Mapping::Mapped(&['\u{633}', '\u{649}'])),
+    ('\u{FCFC}', '\u{FCFC}', Mapping::Mapped(&['\u{633}', '\u{64A}'])),
+    ('\u{FCFD}', '\u{FCFD}', Mapping::Mapped(&['\u{634}', '\u{649}'])),
+    ('\u{FCFE}', '\u{FCFE}', Mapping::Mapped(&['\u{634}', '\u{64A}'])),
+    ('\u{FCFF}', '\u{FCFF}', Mapping::Mapped(&['\u{62D}', '\u{649}'])),
+    ('\u{FD00}', '\u{FD00}', Mapping::Mapped(&['\u{62D}', '\u{64A}'])),
+    ('\u{FD01}', '\u{FD01}', Mapping::Mapped(&['\u{62C}', '\u{649}'])),
+    ('\u{FD02}', '\u{FD02}', Mapping::Mapped(&['\u{62C}', '\u{64A}'])),
+    ('\u{FD03}', '\u{FD03}', Mapping::Mapped(&['\u{62E}', '\u{649}'])),
+    ('\u{FD04}', '\u{FD04}', Mapping::Mapped(&['\u{62E}', '\u{64A}'])),
+    ('\u{FD05}', '\u{FD05}', Mapping::Mapped(&['\u{635}', '\u{649}'])),
+    ('\u{FD06}', '\u{FD06}', Mapping::Mapped(&['\u{635}', '\u{64A}'])),
+    ('\u{FD07}', '\u{FD07}', Mapping::Mapped(&['\u{636}', '\u{649}'])),
+    ('\u{FD08}', '\u{FD08}', Mapping::Mapped(&['\u{636}', '\u{64A}'])),
+    ('\u{FD09}', '\u{FD09}', Mapping::Mapped(&['\u{634}', '\u{62C}'])),
+    ('\u{FD0A}', '\u{FD0A}', Mapping::Mapped(&['\u{634}', '\u{62D}'])),
+    ('\u{FD0B}', '\u{FD0B}', Mapping::Mapped(&['\u{634}', '\u{62E}'])),
+    ('\u{FD0C}', '\u{FD0C}', Mapping::Mapped(&['\u{634}', '\u{645}'])),
+    ('\u{FD0D}', '\u{FD0D}', Mapping::Mapped(&['\u{634}', '\u{631}'])),
+    ('\u{FD0E}', '\u{FD0E}', Mapping::Mapped(&['\u{633}', '\u{631}'])),
+    ('\u{FD0F}', '\u{FD0F}', Mapping::Mapped(&['\u{635}', '\u{631}'])),
+    ('\u{FD10}', '\u{FD10}', Mapping::Mapped(&['\u{636}', '\u{631}'])),
+    ('\u{FD11}', '\u{FD11}', Mapping::Mapped(&['\u{637}', '\u{649}'])),
+    ('\u{FD12}', '\u{FD12}', Mapping::Mapped(&['\u{637}', '\u{64A}'])),
+    ('\u{FD13}', '\u{FD13}', Mapping::Mapped(&['\u{639}', '\u{649}'])),
+    ('\u{FD14}', '\u{FD14}', Mapping::Mapped(&['\u{639}', '\u{64A}'])),
+    ('\u{FD15}', '\u{FD15}', Mapping::Mapped(&['\u{63A}', '\u{649}'])),
+    ('\u{FD16}', '\u{FD16}', Mapping::Mapped(&['\u{63A}', '\u{64A}'])),
+    ('\u{FD17}', '\u{FD17}', Mapping::Mapped(&['\u{633}', '\u{649}'])),
+    ('\u{FD18}', '\u{FD18}', Mapping::Mapped(&['\u{633}', '\u{64A}'])),
+    ('\u{FD19}', '\u{FD19}', Mapping::Mapped(&['\u{634}', '\u{649}'])),
+    ('\u{FD1A}', '\u{FD1A}', Mapping::Mapped(&['\u{634}', '\u{64A}'])),
+    ('\u{FD1B}', '\u{FD1B}', Mapping::Mapped(&['\u{62D}', '\u{649}'])),
+    ('\u{FD1C}', '\u{FD1C}', Mapping::Mapped(&['\u{62D}', '\u{64A}'])),
+    ('\u{FD1D}', '\u{FD1D}', Mapping::Mapped(&['\u{62C}', '\u{649}'])),
+    ('\u{FD1E}', '\u{FD1E}', Mapping::Mapped(&['\u{62C}', '\u{64A}'])),
+    ('\u{FD1F}', '\u{FD1F}', Mapping::Mapped(&['\u{62E}', '\u{649}'])),
+    ('\u{FD20}', '\u{FD20}', Mapping::Mapped(&['\u{62E}', '\u{64A}'])),
+    ('\u{FD21}', '\u{FD21}', Mapping::Mapped(&['\u{635}', '\u{649}'])),
+    ('\u{FD22}', '\u{FD22}', Mapping::Mapped(&['\u{635}', '\u{64A}'])),
+    ('\u{FD23}', '\u{FD23}', Mapping::Mapped(&['\u{636}', '\u{649}'])),
+    ('\u{FD24}', '\u{FD24}', Mapping::Mapped(&['\u{636}', '\u{64A}'])),
+    ('\u{FD25}', '\u{FD25}', Mapping::Mapped(&['\u{634}', '\u{62C}'])),
+    ('\u{FD26}', '\u{FD26}', Mapping::Mapped(&['\u{634}', '\u{62D}'])),
+    ('\u{FD27}', '\u{FD27}', Mapping::Mapped(&['\u{634}', '\u{62E}'])),
+    ('\u{FD28}', '\u{FD28}', Mapping::Mapped(&['\u{634}', '\u{645}'])),
+    ('\u{FD29}', '\u{FD29}', Mapping::Mapped(&['\u{634}', '\u{631}'])),
+    ('\u{FD2A}', '\u{FD2A}', Mapping::Mapped(&['\u{633}', '\u{631}'])),
+    ('\u{FD2B}', '\u{FD2B}', Mapping::Mapped(&['\u{635}', '\u{631}'])),
+    ('\u{FD2C}', '\u{FD2C}', Mapping::Mapped(&['\u{636}', '\u{631}'])),
+    ('\u{FD2D}', '\u{FD2D}', Mapping::Mapped(&['\u{634}', '\u{62C}'])),
+    ('\u{FD2E}', '\u{FD2E}', Mapping::Mapped(&['\u{634}', '\u{62D}'])),
+    ('\u{FD2F}', '\u{FD2F}', Mapping::Mapped(&['\u{634}', '\u{62E}'])),
+    ('\u{FD30}', '\u{FD30}', Mapping::Mapped(&['\u{634}', '\u{645}'])),
+    ('\u{FD31}', '\u{FD31}', Mapping::Mapped(&['\u{633}', '\u{647}'])),
+    ('\u{FD32}', '\u{FD32}', Mapping::Mapped(&['\u{634}', '\u{647}'])),
+    ('\u{FD33}', '\u{FD33}', Mapping::Mapped(&['\u{637}', '\u{645}'])),
+    ('\u{FD34}', '\u{FD34}', Mapping::Mapped(&['\u{633}', '\u{62C}'])),
+    ('\u{FD35}', '\u{FD35}', Mapping::Mapped(&['\u{633}', '\u{62D}'])),
+    ('\u{FD36}', '\u{FD36}', Mapping::Mapped(&['\u{633}', '\u{62E}'])),
+    ('\u{FD37}', '\u{FD37}', Mapping::Mapped(&['\u{634}', '\u{62C}'])),
+    ('\u{FD38}', '\u{FD38}', Mapping::Mapped(&['\u{634}', '\u{62D}'])),
+    ('\u{FD39}', '\u{FD39}', Mapping::Mapped(&['\u{634}', '\u{62E}'])),
+    ('\u{FD3A}', '\u{FD3A}', Mapping::Mapped(&['\u{637}', '\u{645}'])),
+    ('\u{FD3B}', '\u{FD3B}', Mapping::Mapped(&['\u{638}', '\u{645}'])),
+    ('\u{FD3C}', '\u{FD3D}', Mapping::Mapped(&['\u{627}', '\u{64B}'])),
+    ('\u{FD3E}', '\u{FD3F}', Mapping::Valid),
+    ('\u{FD40}', '\u{FD4F}', Mapping::Disallowed),
+    ('\u{FD50}', '\u{FD50}', Mapping::Mapped(&['\u{62A}', '\u{62C}', '\u{645}'])),
+    ('\u{FD51}', '\u{FD52}', Mapping::Mapped(&['\u{62A}', '\u{62D}', '\u{62C}'])),
+    ('\u{FD53}', '\u{FD53}', Mapping::Mapped(&['\u{62A}', '\u{62D}', '\u{645}'])),
+    ('\u{FD54}', '\u{FD54}', Mapping::Mapped(&['\u{62A}', '\u{62E}', '\u{645}'])),
+    ('\u{FD55}', '\u{FD55}', Mapping::Mapped(&['\u{62A}', '\u{645}', '\u{62C}'])),
+    ('\u{FD56}', '\u{FD56}', Mapping::Mapped(&['\u{62A}', '\u{645}', '\u{62D}'])),
+    ('\u{FD57}', '\u{FD57}', Mapping::Mapped(&['\u{62A}', '\u{645}', '\u{62E}'])),
+    ('\u{FD58}', '\u{FD59}', Mapping::Mapped(&['\u{62C}', '\u{645}', '\u{62D}'])),
+    ('\u{FD5A}', '\u{FD5A}', Mapping::Mapped(&['\u{62D}', '\u{645}', '\u{64A}'])),
+    ('\u{FD5B}', '\u{FD5B}', Mapping::Mapped(&['\u{62D}', '\u{645}', '\u{649}'])),
+    ('\u{FD5C}', '\u{FD5C}', Mapping::Mapped(&['\u{633}', '\u{62D}', '\u{62C}'])),
+    ('\u{FD5D}', '\u{FD5D}', Mapping::Mapped(&['\u{633}', '\u{62C}', '\u{62D}'])),
+    ('\u{FD5E}', '\u{FD5E}', Mapping::Mapped(&['\u{633}', '\u{62C}', '\u{649}'])),
+    ('\u{FD5F}', '\u{FD60}', Mapping::Mapped(&['\u{633}', '\u{645}', '\u{62D}'])),
+    ('\u{FD61}', '\u{FD61}', Mapping::Mapped(&['\u{633}', '\u{645}', '\u{62C}'])),
+    ('\u{FD62}', '\u{FD63}', Mapping::Mapped(&['\u{633}', '\u{645}', '\u{645}'])),
+    ('\u{FD64}', '\u{FD65}', Mapping::Mapped(&['\u{635}', '\u{62D}', '\u{62D}'])),
+    ('\u{FD66}', '\u{FD66}', Mapping::Mapped(&['\u{635}', '\u{645}', '\u{645}'])),
+    ('\u{FD67}', '\u{FD68}', Mapping::Mapped(&['\u{634}', '\u{62D}', '\u{645}'])),
+    ('\u{FD69}', '\u{FD69}', Mapping::Mapped(&['\u{634}', '\u{62C}', '\u{64A}'])),
+    ('\u{FD6A}', '\u{FD6B}', Mapping::Mapped(&['\u{634}', '\u{645}', '\u{62E}'])),
+    ('\u{FD6C}', '\u{FD6D}', Mapping::Mapped(&['\u{634}', '\u{645}', '\u{645}'])),
+    ('\u{FD6E}', '\u{FD6E}', Mapping::Mapped(&['\u{636}', '\u{62D}', '\u{649}'])),
+    ('\u{FD6F}', '\u{FD70}', Mapping::Mapped(&['\u{636}', '\u{62E}', '\u{645}'])),
+    ('\u{FD71}', '\u{FD72}', Mapping::Mapped(&['\u{637}', '\u{645}', '\u{62D}'])),
+    ('\u{FD73}', '\u{FD73}', Mapping::Mapped(&['\u{637}', '\u{645}', '\u{645}'])),
+    ('\u{FD74}', '\u{FD74}', Mapping::Mapped(&['\u{637}', '\u{645}', '\u{64A}'])),
+    ('\u{FD75}', '\u{FD75}', Mapping::Mapped(&['\u{639}', '\u{62C}', '\u{645}'])),
+    ('\u{FD76}', '\u{FD77}', Mapping::Mapped(&['\u{639}', '\u{645}', '\u{645}'])),
+    ('\u{FD78}', '\u{FD78}', Mapping::Mapped(&['\u{639}', '\u{645}', '\u{649}'])),
+    ('\u{FD79}', '\u{FD79}', Mapping::Mapped(&['\u{63A}', '\u{645}', '\u{645}'])),
+    ('\u{FD7A}', '\u{FD7A}', Mapping::Mapped(&['\u{63A}', '\u{645}', '\u{64A}'])),
+    ('\u{FD7B}', '\u{FD7B}', Mapping::Mapped(&['\u{63A}', '\u{645}', '\u{649}'])),
+    ('\u{FD7C}', '\u{FD7D}', Mapping::Mapped(&['\u{641}', '\u{62E}', '\u{645}'])),
+    ('\u{FD7E}', '\u{FD7E}', Mapping::Mapped(&['\u{642}', '\u{645}', '\u{62D}'])),
+    ('\u{FD7F}', '\u{FD7F}', Mapping::Mapped(&['\u{642}', '\u{645}', '\u{645}'])),
+    ('\u{FD80}', '\u{FD80}', Mapping::Mapped(&['\u{644}', '\u{62D}', '\u{645}'])),
+    ('\u{FD81}', '\u{FD81}', Mapping::Mapped(&['\u{644}', '\u{62D}', '\u{64A}'])),
+    ('\u{FD82}', '\u{FD82}', Mapping::Mapped(&['\u{644}', '\u{62D}', '\u{649}'])),
+    ('\u{FD83}', '\u{FD84}', Mapping::Mapped(&['\u{644}', '\u{62C}', '\u{62C}'])),
+    ('\u{FD85}', '\u{FD86}', Mapping::Mapped(&['\u{644}', '\u{62E}', '\u{645}'])),
+    ('\u{FD87}', '\u{FD88}', Mapping::Mapped(&['\u{644}', '\u{645}', '\u{62D}'])),
+    ('\u{FD89}', '\u{FD89}', Mapping::Mapped(&['\u{645}', '\u{62D}', '\u{62C}'])),
+    ('\u{FD8A}', '\u{FD8A}', Mapping::Mapped(&['\u{645}', '\u{62D}', '\u{645}'])),
+    ('\u{FD8B}', '\u{FD8B}', Mapping::Mapped(&['\u{645}', '\u{62D}', '\u{64A}'])),
+    ('\u{FD8C}', '\u{FD8C}', Mapping::Mapped(&['\u{645}', '\u{62C}', '\u{62D}'])),
+    ('\u{FD8D}', '\u{FD8D}', Mapping::Mapped(&['\u{645}', '\u{62C}', '\u{645}'])),
+    ('\u{FD8E}', '\u{FD8E}', Mapping::Mapped(&['\u{645}', '\u{62E}', '\u{62C}'])),
+    ('\u{FD8F}', '\u{FD8F}', Mapping::Mapped(&['\u{645}', '\u{62E}', '\u{645}'])),
+    ('\u{FD90}', '\u{FD91}', Mapping::Disallowed),
+    ('\u{FD92}', '\u{FD92}', Mapping::Mapped(&['\u{645}', '\u{62C}', '\u{62E}'])),
+    ('\u{FD93}', '\u{FD93}', Mapping::Mapped(&['\u{647}', '\u{645}', '\u{62C}'])),
+    ('\u{FD94}', '\u{FD94}', Mapping::Mapped(&['\u{647}', '\u{645}', '\u{645}'])),
+    ('\u{FD95}', '\u{FD95}', Mapping::Mapped(&['\u{646}', '\u{62D}', '\u{645}'])),
+    ('\u{FD96}', '\u{FD96}', Mapping::Mapped(&['\u{646}', '\u{62D}', '\u{649}'])),
+    ('\u{FD97}', '\u{FD98}', Mapping::Mapped(&['\u{646}', '\u{62C}', '\u{645}'])),
+    ('\u{FD99}', '\u{FD99}', Mapping::Mapped(&['\u{646}', '\u{62C}', '\u{649}'])),
+    ('\u{FD9A}', '\u{FD9A}', Mapping::Mapped(&['\u{646}', '\u{645}', '\u{64A}'])),
+    ('\u{FD9B}', '\u{FD9B}', Mapping::Mapped(&['\u{646}', '\u{645}', '\u{649}'])),
+    ('\u{FD9C}', '\u{FD9D}', Mapping::Mapped(&['\u{64A}', '\u{645}', '\u{645}'])),
+    ('\u{FD9E}', '\u{FD9E}', Mapping::Mapped(&['\u{628}', '\u{62E}', '\u{64A}'])),
+    ('\u{FD9F}', '\u{FD9F}', Mapping::Mapped(&['\u{62A}', '\u{62C}', '\u{64A}'])),
+    ('\u{FDA0}', '\u{FDA0}', Mapping::Mapped(&['\u{62A}', '\u{62C}', '\u{649}'])),
+    ('\u{FDA1}', '\u{FDA1}', Mapping::Mapped(&['\u{62A}', '\u{62E}', '\u{64A}'])),
+    ('\u{FDA2}', '\u{FDA2}', Mapping::Mapped(&['\u{62A}', '\u{62E}', '\u{649}'])),
+    ('\u{FDA3}', '\u{FDA3}', Mapping::Mapped(&['\u{62A}', '\u{645}', '\u{64A}'])),
+    ('\u{FDA4}', '\u{FDA4}', Mapping::Mapped(&['\u{62A}', '\u{645}', '\u{649}'])),
+    ('\u{FDA5}', '\u{FDA5}', Mapping::Mapped(&['\u{62C}', '\u{645}', '\u{64A}'])),
+    ('\u{FDA6}', '\u{FDA6}', Mapping::Mapped(&['\u{62C}', '\u{62D}', '\u{649}'])),
+    ('\u{FDA7}', '\u{FDA7}', Mapping::Mapped(&['\u{62C}', '\u{645}', '\u{649}'])),
+    ('\u{FDA8}', '\u{FDA8}', Mapping::Mapped(&['\u{633}', '\u{62E}', '\u{649}'])),
+    ('\u{FDA9}', '\u{FDA9}', Mapping::Mapped(&['\u{635}', '\u{62D}', '\u{64A}'])),
+    ('\u{FDAA}', '\u{FDAA}', Mapping::Mapped(&['\u{634}', '\u{62D}', '\u{64A}'])),
+    ('\u{FDAB}', '\u{FDAB}', Mapping::Mapped(&['\u{636}', '\u{62D}', '\u{64A}'])),
+    ('\u{FDAC}', '\u{FDAC}', Mapping::Mapped(&['\u{644}', '\u{62C}', '\u{64A}'])),
+    ('\u{FDAD}', '\u{FDAD}', Mapping::Mapped(&['\u{644}', '\u{645}', '\u{64A}'])),
+    ('\u{FDAE}', '\u{FDAE}', Mapping::Mapped(&['\u{64A}', '\u{62D}', '\u{64A}'])),
+    ('\u{FDAF}', '\u{FDAF}', Mapping::Mapped(&['\u{64A}', '\u{62C}', '\u{64A}'])),
+    ('\u{FDB0}', '\u{FDB0}', Mapping::Mapped(&['\u{64A}', '\u{645}', '\u{64A}'])),
+    ('\u{FDB1}', '\u{FDB1}', Mapping::Mapped(&['\u{645}', '\u{645}', '\u{64A}'])),
+    ('\u{FDB2}', '\u{FDB2}', Mapping::Mapped(&['\u{642}', '\u{645}', '\u{64A}'])),
+    ('\u{FDB3}', '\u{FDB3}', Mapping::Mapped(&['\u{646}', '\u{62D}', '\u{64A}'])),
+    ('\u{FDB4}', '\u{FDB4}', Mapping::Mapped(&['\u{642}', '\u{645}', '\u{62D}'])),
+    ('\u{FDB5}', '\u{FDB5}', Mapping::Mapped(&['\u{644}', '\u{62D}', '\u{645}'])),
+    ('\u{FDB6}', '\u{FDB6}', Mapping::Mapped(&['\u{639}', '\u{645}', '\u{64A}'])),
+    ('\u{FDB7}', '\u{FDB7}', Mapping::Mapped(&['\u{643}', '\u{645}', '\u{64A}'])),
+    ('\u{FDB8}', '\u{FDB8}', Mapping::Mapped(&['\u{646}', '\u{62C}', '\u{62D}'])),
+    ('\u{FDB9}', '\u{FDB9}', Mapping::Mapped(&['\u{645}', '\u{62E}', '\u{64A}'])),
+    ('\u{FDBA}', '\u{FDBA}', Mapping::Mapped(&['\u{644}', '\u{62C}', '\u{645}'])),
+    ('\u{FDBB}', '\u{FDBB}', Mapping::Mapped(&['\u{643}', '\u{645}', '\u{645}'])),
+    ('\u{FDBC}', '\u{FDBC}', Mapping::Mapped(&['\u{644}', '\u{62C}', '\u{645}'])),
+    ('\u{FDBD}', '\u{FDBD}', Mapping::Mapped(&['\u{646}', '\u{62C}', '\u{62D}'])),
+    ('\u{FDBE}', '\u{FDBE}', Mapping::Mapped(&['\u{62C}', '\u{62D}', '\u{64A}'])),
+    ('\u{FDBF}', '\u{FDBF}', Mapping::Mapped(&['\u{62D}', '\u{62C}', '\u{64A}'])),
+    ('\u{FDC0}', '\u{FDC0}', Mapping::Mapped(&['\u{645}', '\u{62C}', '\u{64A}'])),
+    ('\u{FDC1}', '\u{FDC1}', Mapping::Mapped(&['\u{641}', '\u{645}', '\u{64A}'])),
+    ('\u{FDC2}', '\u{FDC2}', Mapping::Mapped(&['\u{628}', '\u{62D}', '\u{64A}'])),
+    ('\u{FDC3}', '\u{FDC3}', Mapping::Mapped(&['\u{643}', '\u{645}', '\u{645}'])),
+    ('\u{FDC4}', '\u{FDC4}', Mapping::Mapped(&['\u{639}', '\u{62C}', '\u{645}'])),
+    ('\u{FDC5}', '\u{FDC5}', Mapping::Mapped(&['\u{635}', '\u{645}', '\u{645}'])),
+    ('\u{FDC6}', '\u{FDC6}', Mapping::Mapped(&['\u{633}', '\u{62E}', '\u{64A}'])),
+    ('\u{FDC7}', '\u{FDC7}', Mapping::Mapped(&['\u{646}', '\u{62C}', '\u{64A}'])),
+    ('\u{FDC8}', '\u{FDEF}', Mapping::Disallowed),
+    ('\u{FDF0}', '\u{FDF0}', Mapping::Mapped(&['\u{635}', '\u{644}', '\u{6D2}'])),
+    ('\u{FDF1}', '\u{FDF1}', Mapping::Mapped(&['\u{642}', '\u{644}', '\u{6D2}'])),
+    ('\u{FDF2}', '\u{FDF2}', Mapping::Mapped(&['\u{627}', '\u{644}', '\u{644}', '\u{647}'])),
+    ('\u{FDF3}', '\u{FDF3}', Mapping::Mapped(&['\u{627}', '\u{643}', '\u{628}', '\u{631}'])),
+    ('\u{FDF4}', '\u{FDF4}', Mapping::Mapped(&['\u{645}', '\u{62D}', '\u{645}', '\u{62F}'])),
+    ('\u{FDF5}', '\u{FDF5}', Mapping::Mapped(&['\u{635}', '\u{644}', '\u{639}', '\u{645}'])),
+    ('\u{FDF6}', '\u{FDF6}', Mapping::Mapped(&['\u{631}', '\u{633}', '\u{648}', '\u{644}'])),
+    ('\u{FDF7}', '\u{FDF7}', Mapping::Mapped(&['\u{639}', '\u{644}', '\u{64A}', '\u{647}'])),
+    ('\u{FDF8}', '\u{FDF8}', Mapping::Mapped(&['\u{648}', '\u{633}', '\u{644}', '\u{645}'])),
+    ('\u{FDF9}', '\u{FDF9}', Mapping::Mapped(&['\u{635}', '\u{644}', '\u{649}'])),
+    ('\u{FDFA}', '\u{FDFA}', Mapping::DisallowedStd3Mapped(&['\u{635}', '\u{644}', '\u{649}', '\u{20}', '\u{627}', '\u{644}', '\u{644}', '\u{647}', '\u{20}', '\u{639}', '\u{644}', '\u{64A}', '\u{647}', '\u{20}', '\u{648}', '\u{633}', '\u{644}', '\u{645}'])),
+    ('\u{FDFB}', '\u{FDFB}', Mapping::DisallowedStd3Mapped(&['\u{62C}', '\u{644}', '\u{20}', '\u{62C}', '\u{644}', '\u{627}', '\u{644}', '\u{647}'])),
+    ('\u{FDFC}', '\u{FDFC}', Mapping::Mapped(&['\u{631}', '\u{6CC}', '\u{627}', '\u{644}'])),
+    ('\u{FDFD}', '\u{FDFD}', Mapping::Valid),
+    ('\u{FDFE}', '\u{FDFF}', Mapping::Disallowed),
+    ('\u{FE00}', '\u{FE0F}', Mapping::Ignored),
+    ('\u{FE10}', '\u{FE10}', Mapping::DisallowedStd3Mapped(&['\u{2C}'])),
+    ('\u{FE11}', '\u{FE11}', Mapping::Mapped(&['\u{3001}'])),
+    ('\u{FE12}', '\u{FE12}', Mapping::Disallowed),
+    ('\u{FE13}', '\u{FE13}', Mapping::DisallowedStd3Mapped(&['\u{3A}'])),
+    ('\u{FE14}', '\u{FE14}', Mapping::DisallowedStd3Mapped(&['\u{3B}'])),
+    ('\u{FE15}', '\u{FE15}', Mapping::DisallowedStd3Mapped(&['\u{21}'])),
+    ('\u{FE16}', '\u{FE16}', Mapping::DisallowedStd3Mapped(&['\u{3F}'])),
+    ('\u{FE17}', '\u{FE17}', Mapping::Mapped(&['\u{3016}'])),
+    ('\u{FE18}', '\u{FE18}', Mapping::Mapped(&['\u{3017}'])),
+    ('\u{FE19}', '\u{FE1F}', Mapping::Disallowed),
+    ('\u{FE20}', '\u{FE2F}', Mapping::Valid),
+    ('\u{FE30}', '\u{FE30}', Mapping::Disallowed),
+    ('\u{FE31}', '\u{FE31}', Mapping::Mapped(&['\u{2014}'])),
+    ('\u{FE32}', '\u{FE32}', Mapping::Mapped(&['\u{2013}'])),
+    ('\u{FE33}', '\u{FE34}', Mapping::DisallowedStd3Mapped(&['\u{5F}'])),
+    ('\u{FE35}', '\u{FE35}', Mapping::DisallowedStd3Mapped(&['\u{28}'])),
+    ('\u{FE36}', '\u{FE36}', Mapping::DisallowedStd3Mapped(&['\u{29}'])),
+    ('\u{FE37}', '\u{FE37}', Mapping::DisallowedStd3Mapped(&['\u{7B}'])),
+    ('\u{FE38}', '\u{FE38}', Mapping::DisallowedStd3Mapped(&['\u{7D}'])),
+    ('\u{FE39}', '\u{FE39}', Mapping::Mapped(&['\u{3014}'])),
+    ('\u{FE3A}', '\u{FE3A}', Mapping::Mapped(&['\u{3015}'])),
+    ('\u{FE3B}', '\u{FE3B}', Mapping::Mapped(&['\u{3010}'])),
+    ('\u{FE3C}', '\u{FE3C}', Mapping::Mapped(&['\u{3011}'])),
+    ('\u{FE3D}', '\u{FE3D}', Mapping::Mapped(&['\u{300A}'])),
+    ('\u{FE3E}', '\u{FE3E}', Mapping::Mapped(&['\u{300B}'])),
+    ('\u{FE3F}', '\u{FE3F}', Mapping::Mapped(&['\u{3008}'])),
+    ('\u{FE40}', '\u{FE40}', Mapping::Mapped(&['\u{3009}'])),
+    ('\u{FE41}', '\u{FE41}', Mapping::Mapped(&['\u{300C}'])),
+    ('\u{FE42}', '\u{FE42}', Mapping::Mapped(&['\u{300D}'])),
+    ('\u{FE43}', '\u{FE43}', Mapping::Mapped(&['\u{300E}'])),
+    ('\u{FE44}', '\u{FE44}', Mapping::Mapped(&['\u{300F}'])),
+    ('\u{FE45}', '\u{FE46}', Mapping::Valid),
+    ('\u{FE47}', '\u{FE47}', Mapping::DisallowedStd3Mapped(&['\u{5B}'])),
+    ('\u{FE48}', '\u{FE48}', Mapping::DisallowedStd3Mapped(&['\u{5D}'])),
+    ('\u{FE49}', '\u{FE4C}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{305}'])),
+    ('\u{FE4D}', '\u{FE4F}', Mapping::DisallowedStd3Mapped(&['\u{5F}'])),
+    ('\u{FE50}', '\u{FE50}', Mapping::DisallowedStd3Mapped(&['\u{2C}'])),
+    ('\u{FE51}', '\u{FE51}', Mapping::Mapped(&['\u{3001}'])),
+    ('\u{FE52}', '\u{FE53}', Mapping::Disallowed),
+    ('\u{FE54}', '\u{FE54}', Mapping::DisallowedStd3Mapped(&['\u{3B}'])),
+    ('\u{FE55}', '\u{FE55}', Mapping::DisallowedStd3Mapped(&['\u{3A}'])),
+    ('\u{FE56}', '\u{FE56}', Mapping::DisallowedStd3Mapped(&['\u{3F}'])),
+    ('\u{FE57}', '\u{FE57}', Mapping::DisallowedStd3Mapped(&['\u{21}'])),
+    ('\u{FE58}', '\u{FE58}', Mapping::Mapped(&['\u{2014}'])),
+    ('\u{FE59}', '\u{FE59}', Mapping::DisallowedStd3Mapped(&['\u{28}'])),
+    ('\u{FE5A}', '\u{FE5A}', Mapping::DisallowedStd3Mapped(&['\u{29}'])),
+    ('\u{FE5B}', '\u{FE5B}', Mapping::DisallowedStd3Mapped(&['\u{7B}'])),
+    ('\u{FE5C}', '\u{FE5C}', Mapping::DisallowedStd3Mapped(&['\u{7D}'])),
+    ('\u{FE5D}', '\u{FE5D}', Mapping::Mapped(&['\u{3014}'])),
+    ('\u{FE5E}', '\u{FE5E}', Mapping::Mapped(&['\u{3015}'])),
+    ('\u{FE5F}', '\u{FE5F}', Mapping::DisallowedStd3Mapped(&['\u{23}'])),
+    ('\u{FE60}', '\u{FE60}', Mapping::DisallowedStd3Mapped(&['\u{26}'])),
+    ('\u{FE61}', '\u{FE61}', Mapping::DisallowedStd3Mapped(&['\u{2A}'])),
+    ('\u{FE62}', '\u{FE62}', Mapping::DisallowedStd3Mapped(&['\u{2B}'])),
+    ('\u{FE63}', '\u{FE63}', Mapping::Mapped(&['\u{2D}'])),
+    ('\u{FE64}', '\u{FE64}', Mapping::DisallowedStd3Mapped(&['\u{3C}'])),
+    ('\u{FE65}', '\u{FE65}', Mapping::DisallowedStd3Mapped(&['\u{3E}'])),
+    ('\u{FE66}', '\u{FE66}', Mapping::DisallowedStd3Mapped(&['\u{3D}'])),
+    ('\u{FE67}', '\u{FE67}', Mapping::Disallowed),
+    ('\u{FE68}', '\u{FE68}', Mapping::DisallowedStd3Mapped(&['\u{5C}'])),
+    ('\u{FE69}', '\u{FE69}', Mapping::DisallowedStd3Mapped(&['\u{24}'])),
+    ('\u{FE6A}', '\u{FE6A}', Mapping::DisallowedStd3Mapped(&['\u{25}'])),
+    ('\u{FE6B}', '\u{FE6B}', Mapping::DisallowedStd3Mapped(&['\u{40}'])),
+    ('\u{FE6C}', '\u{FE6F}', Mapping::Disallowed),
+    ('\u{FE70}', '\u{FE70}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{64B}'])),
+    ('\u{FE71}', '\u{FE71}', Mapping::Mapped(&['\u{640}', '\u{64B}'])),
+    ('\u{FE72}', '\u{FE72}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{64C}'])),
+    ('\u{FE73}', '\u{FE73}', Mapping::Valid),
+    ('\u{FE74}', '\u{FE74}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{64D}'])),
+    ('\u{FE75}', '\u{FE75}', Mapping::Disallowed),
+    ('\u{FE76}', '\u{FE76}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{64E}'])),
+    ('\u{FE77}', '\u{FE77}', Mapping::Mapped(&['\u{640}', '\u{64E}'])),
+    ('\u{FE78}', '\u{FE78}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{64F}'])),
+    ('\u{FE79}', '\u{FE79}', Mapping::Mapped(&['\u{640}', '\u{64F}'])),
+    ('\u{FE7A}', '\u{FE7A}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{650}'])),
+    ('\u{FE7B}', '\u{FE7B}', Mapping::Mapped(&['\u{640}', '\u{650}'])),
+    ('\u{FE7C}', '\u{FE7C}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{651}'])),
+    ('\u{FE7D}', '\u{FE7D}', Mapping::Mapped(&['\u{640}', '\u{651}'])),
+    ('\u{FE7E}', '\u{FE7E}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{652}'])),
+    ('\u{FE7F}', '\u{FE7F}', Mapping::Mapped(&['\u{640}', '\u{652}'])),
+    ('\u{FE80}', '\u{FE80}', Mapping::Mapped(&['\u{621}'])),
+    ('\u{FE81}', '\u{FE82}', Mapping::Mapped(&['\u{622}'])),
+    ('\u{FE83}', '\u{FE84}', Mapping::Mapped(&['\u{623}'])),
+    ('\u{FE85}', '\u{FE86}', Mapping::Mapped(&['\u{624}'])),
+    ('\u{FE87}', '\u{FE88}', Mapping::Mapped(&['\u{625}'])),
+    ('\u{FE89}', '\u{FE8C}', Mapping::Mapped(&['\u{626}'])),
+    ('\u{FE8D}', '\u{FE8E}', Mapping::Mapped(&['\u{627}'])),
+    ('\u{FE8F}', '\u{FE92}', Mapping::Mapped(&['\u{628}'])),
+    ('\u{FE93}', '\u{FE94}', Mapping::Mapped(&['\u{629}'])),
+    ('\u{FE95}', '\u{FE98}', Mapping::Mapped(&['\u{62A}'])),
+    ('\u{FE99}', '\u{FE9C}', Mapping::Mapped(&['\u{62B}'])),
+    ('\u{FE9D}', '\u{FEA0}', Mapping::Mapped(&['\u{62C}'])),
+    ('\u{FEA1}', '\u{FEA4}', Mapping::Mapped(&['\u{62D}'])),
+    ('\u{FEA5}', '\u{FEA8}', Mapping::Mapped(&['\u{62E}'])),
+    ('\u{FEA9}', '\u{FEAA}', Mapping::Mapped(&['\u{62F}'])),
+    ('\u{FEAB}', '\u{FEAC}', Mapping::Mapped(&['\u{630}'])),
+    ('\u{FEAD}', '\u{FEAE}', Mapping::Mapped(&['\u{631}'])),
+    ('\u{FEAF}', '\u{FEB0}', Mapping::Mapped(&['\u{632}'])),
+    ('\u{FEB1}', '\u{FEB4}', Mapping::Mapped(&['\u{633}'])),
+    ('\u{FEB5}', '\u{FEB8}', Mapping::Mapped(&['\u{634}'])),
+    ('\u{FEB9}', '\u{FEBC}', Mapping::Mapped(&['\u{635}'])),
+    ('\u{FEBD}', '\u{FEC0}', Mapping::Mapped(&['\u{636}'])),
+    ('\u{FEC1}', '\u{FEC4}', Mapping::Mapped(&['\u{637}'])),
+    ('\u{FEC5}', '\u{FEC8}', Mapping::Mapped(&['\u{638}'])),
+    ('\u{FEC9}', '\u{FECC}', Mapping::Mapped(&['\u{639}'])),
+    ('\u{FECD}', '\u{FED0}', Mapping::Mapped(&['\u{63A}'])),
+    ('\u{FED1}', '\u{FED4}', Mapping::Mapped(&['\u{641}'])),
+    ('\u{FED5}', '\u{FED8}', Mapping::Mapped(&['\u{642}'])),
+    ('\u{FED9}', '\u{FEDC}', Mapping::Mapped(&['\u{643}'])),
+    ('\u{FEDD}', '\u{FEE0}', Mapping::Mapped(&['\u{644}'])),
+    ('\u{FEE1}', '\u{FEE4}', Mapping::Mapped(&['\u{645}'])),
+    ('\u{FEE5}', '\u{FEE8}', Mapping::Mapped(&['\u{646}'])),
+    ('\u{FEE9}', '\u{FEEC}', Mapping::Mapped(&['\u{647}'])),
+    ('\u{FEED}', '\u{FEEE}', Mapping::Mapped(&['\u{648}'])),
+    ('\u{FEEF}', '\u{FEF0}', Mapping::Mapped(&['\u{649}'])),
+    ('\u{FEF1}', '\u{FEF4}', Mapping::Mapped(&['\u{64A}'])),
+    ('\u{FEF5}', '\u{FEF6}', Mapping::Mapped(&['\u{644}', '\u{622}'])),
+    ('\u{FEF7}', '\u{FEF8}', Mapping::Mapped(&['\u{644}', '\u{623}'])),
+    ('\u{FEF9}', '\u{FEFA}', Mapping::Mapped(&['\u{644}', '\u{625}'])),
+    ('\u{FEFB}', '\u{FEFC}', Mapping::Mapped(&['\u{644}', '\u{627}'])),
+    ('\u{FEFD}', '\u{FEFE}', Mapping::Disallowed),
+    ('\u{FEFF}', '\u{FEFF}', Mapping::Ignored),
+    ('\u{FF00}', '\u{FF00}', Mapping::Disallowed),
+    ('\u{FF01}', '\u{FF01}', Mapping::DisallowedStd3Mapped(&['\u{21}'])),
+    ('\u{FF02}', '\u{FF02}', Mapping::DisallowedStd3Mapped(&['\u{22}'])),
+    ('\u{FF03}', '\u{FF03}', Mapping::DisallowedStd3Mapped(&['\u{23}'])),
+    ('\u{FF04}', '\u{FF04}', Mapping::DisallowedStd3Mapped(&['\u{24}'])),
+    ('\u{FF05}', '\u{FF05}', Mapping::DisallowedStd3Mapped(&['\u{25}'])),
+    ('\u{FF06}', '\u{FF06}', Mapping::DisallowedStd3Mapped(&['\u{26}'])),
+    ('\u{FF07}', '\u{FF07}', Mapping::DisallowedStd3Mapped(&['\u{27}'])),
+    ('\u{FF08}', '\u{FF08}', Mapping::DisallowedStd3Mapped(&['\u{28}'])),
+    ('\u{FF09}', '\u{FF09}', Mapping::DisallowedStd3Mapped(&['\u{29}'])),
+    ('\u{FF0A}', '\u{FF0A}', Mapping::DisallowedStd3Mapped(&['\u{2A}'])),
+    ('\u{FF0B}', '\u{FF0B}', Mapping::DisallowedStd3Mapped(&['\u{2B}'])),
+    ('\u{FF0C}', '\u{FF0C}', Mapping::DisallowedStd3Mapped(&['\u{2C}'])),
+    ('\u{FF0D}', '\u{FF0D}', Mapping::Mapped(&['\u{2D}'])),
+    ('\u{FF0E}', '\u{FF0E}', Mapping::Mapped(&['\u{2E}'])),
+    ('\u{FF0F}', '\u{FF0F}', Mapping::DisallowedStd3Mapped(&['\u{2F}'])),
+    ('\u{FF10}', '\u{FF10}', Mapping::Mapped(&['\u{30}'])),
+    ('\u{FF11}', '\u{FF11}', Mapping::Mapped(&['\u{31}'])),
+    ('\u{FF12}', '\u{FF12}', Mapping::Mapped(&['\u{32}'])),
+    ('\u{FF13}', '\u{FF13}', Mapping::Mapped(&['\u{33}'])),
+    ('\u{FF14}', '\u{FF14}', Mapping::Mapped(&['\u{34}'])),
+    ('\u{FF15}', '\u{FF15}', Mapping::Mapped(&['\u{35}'])),
+    ('\u{FF16}', '\u{FF16}', Mapping::Mapped(&['\u{36}'])),
+    ('\u{FF17}', '\u{FF17}', Mapping::Mapped(&['\u{37}'])),
+    ('\u{FF18}', '\u{FF18}', Mapping::Mapped(&['\u{38}'])),
+    ('\u{FF19}', '\u{FF19}', Mapping::Mapped(&['\u{39}'])),
+    ('\u{FF1A}', '\u{FF1A}', Mapping::DisallowedStd3Mapped(&['\u{3A}'])),
+    ('\u{FF1B}', '\u{FF1B}', Mapping::DisallowedStd3Mapped(&['\u{3B}'])),
+    ('\u{FF1C}', '\u{FF1C}', Mapping::DisallowedStd3Mapped(&['\u{3C}'])),
+    ('\u{FF1D}', '\u{FF1D}', Mapping::DisallowedStd3Mapped(&['\u{3D}'])),
+    ('\u{FF1E}', '\u{FF1E}', Mapping::DisallowedStd3Mapped(&['\u{3E}'])),
+    ('\u{FF1F}', '\u{FF1F}', Mapping::DisallowedStd3Mapped(&['\u{3F}'])),
+    ('\u{FF20}', '\u{FF20}', Mapping::DisallowedStd3Mapped(&['\u{40}'])),
+    ('\u{FF21}', '\u{FF21}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{FF22}', '\u{FF22}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{FF23}', '\u{FF23}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{FF24}', '\u{FF24}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{FF25}', '\u{FF25}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{FF26}', '\u{FF26}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{FF27}', '\u{FF27}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{FF28}', '\u{FF28}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{FF29}', '\u{FF29}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{FF2A}', '\u{FF2A}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{FF2B}', '\u{FF2B}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{FF2C}', '\u{FF2C}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{FF2D}', '\u{FF2D}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{FF2E}', '\u{FF2E}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{FF2F}', '\u{FF2F}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{FF30}', '\u{FF30}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{FF31}', '\u{FF31}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{FF32}', '\u{FF32}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{FF33}', '\u{FF33}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{FF34}', '\u{FF34}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{FF35}', '\u{FF35}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{FF36}', '\u{FF36}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{FF37}', '\u{FF37}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{FF38}', '\u{FF38}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{FF39}', '\u{FF39}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{FF3A}', '\u{FF3A}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{FF3B}', '\u{FF3B}', Mapping::DisallowedStd3Mapped(&['\u{5B}'])),
+    ('\u{FF3C}', '\u{FF3C}', Mapping::DisallowedStd3Mapped(&['\u{5C}'])),
+    ('\u{FF3D}', '\u{FF3D}', Mapping::DisallowedStd3Mapped(&['\u{5D}'])),
+    ('\u{FF3E}', '\u{FF3E}', Mapping::DisallowedStd3Mapped(&['\u{5E}'])),
+    ('\u{FF3F}', '\u{FF3F}', Mapping::DisallowedStd3Mapped(&['\u{5F}'])),
+    ('\u{FF40}', '\u{FF40}', Mapping::DisallowedStd3Mapped(&['\u{60}'])),
+    ('\u{FF41}', '\u{FF41}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{FF42}', '\u{FF42}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{FF43}', '\u{FF43}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{FF44}', '\u{FF44}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{FF45}', '\u{FF45}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{FF46}', '\u{FF46}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{FF47}', '\u{FF47}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{FF48}', '\u{FF48}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{FF49}', '\u{FF49}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{FF4A}', '\u{FF4A}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{FF4B}', '\u{FF4B}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{FF4C}', '\u{FF4C}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{FF4D}', '\u{FF4D}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{FF4E}', '\u{FF4E}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{FF4F}', '\u{FF4F}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{FF50}', '\u{FF50}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{FF51}', '\u{FF51}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{FF52}', '\u{FF52}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{FF53}', '\u{FF53}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{FF54}', '\u{FF54}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{FF55}', '\u{FF55}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{FF56}', '\u{FF56}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{FF57}', '\u{FF57}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{FF58}', '\u{FF58}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{FF59}', '\u{FF59}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{FF5A}', '\u{FF5A}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{FF5B}', '\u{FF5B}', Mapping::DisallowedStd3Mapped(&['\u{7B}'])),
+    ('\u{FF5C}', '\u{FF5C}', Mapping::DisallowedStd3Mapped(&['\u{7C}'])),
+    ('\u{FF5D}', '\u{FF5D}', Mapping::DisallowedStd3Mapped(&['\u{7D}'])),
+    ('\u{FF5E}', '\u{FF5E}', Mapping::DisallowedStd3Mapped(&['\u{7E}'])),
+    ('\u{FF5F}', '\u{FF5F}', Mapping::Mapped(&['\u{2985}'])),
+    ('\u{FF60}', '\u{FF60}', Mapping::Mapped(&['\u{2986}'])),
+    ('\u{FF61}', '\u{FF61}', Mapping::Mapped(&['\u{2E}'])),
+    ('\u{FF62}', '\u{FF62}', Mapping::Mapped(&['\u{300C}'])),
+    ('\u{FF63}', '\u{FF63}', Mapping::Mapped(&['\u{300D}'])),
+    ('\u{FF64}', '\u{FF64}', Mapping::Mapped(&['\u{3001}'])),
+    ('\u{FF65}', '\u{FF65}', Mapping::Mapped(&['\u{30FB}'])),
+    ('\u{FF66}', '\u{FF66}', Mapping::Mapped(&['\u{30F2}'])),
+    ('\u{FF67}', '\u{FF67}', Mapping::Mapped(&['\u{30A1}'])),
+    ('\u{FF68}', '\u{FF68}', Mapping::Mapped(&['\u{30A3}'])),
+    ('\u{FF69}', '\u{FF69}', Mapping::Mapped(&['\u{30A5}'])),
+    ('\u{FF6A}', '\u{FF6A}', Mapping::Mapped(&['\u{30A7}'])),
+    ('\u{FF6B}', '\u{FF6B}', Mapping::Mapped(&['\u{30A9}'])),
+    ('\u{FF6C}', '\u{FF6C}', Mapping::Mapped(&['\u{30E3}'])),
+    ('\u{FF6D}', '\u{FF6D}', Mapping::Mapped(&['\u{30E5}'])),
+    ('\u{FF6E}', '\u{FF6E}', Mapping::Mapped(&['\u{30E7}'])),
+    ('\u{FF6F}', '\u{FF6F}', Mapping::Mapped(&['\u{30C3}'])),
+    ('\u{FF70}', '\u{FF70}', Mapping::Mapped(&['\u{30FC}'])),
+    ('\u{FF71}', '\u{FF71}', Mapping::Mapped(&['\u{30A2}'])),
+    ('\u{FF72}', '\u{FF72}', Mapping::Mapped(&['\u{30A4}'])),
+    ('\u{FF73}', '\u{FF73}', Mapping::Mapped(&['\u{30A6}'])),
+    ('\u{FF74}', '\u{FF74}', Mapping::Mapped(&['\u{30A8}'])),
+    ('\u{FF75}', '\u{FF75}', Mapping::Mapped(&['\u{30AA}'])),
+    ('\u{FF76}', '\u{FF76}', Mapping::Mapped(&['\u{30AB}'])),
+    ('\u{FF77}', '\u{FF77}', Mapping::Mapped(&['\u{30AD}'])),
+    ('\u{FF78}', '\u{FF78}', Mapping::Mapped(&['\u{30AF}'])),
+    ('\u{FF79}', '\u{FF79}', Mapping::Mapped(&['\u{30B1}'])),
+    ('\u{FF7A}', '\u{FF7A}', Mapping::Mapped(&['\u{30B3}'])),
+    ('\u{FF7B}', '\u{FF7B}', Mapping::Mapped(&['\u{30B5}'])),
+    ('\u{FF7C}', '\u{FF7C}', Mapping::Mapped(&['\u{30B7}'])),
+    ('\u{FF7D}', '\u{FF7D}', Mapping::Mapped(&['\u{30B9}'])),
+    ('\u{FF7E}', '\u{FF7E}', Mapping::Mapped(&['\u{30BB}'])),
+    ('\u{FF7F}', '\u{FF7F}', Mapping::Mapped(&['\u{30BD}'])),
+    ('\u{FF80}', '\u{FF80}', Mapping::Mapped(&['\u{30BF}'])),
+    ('\u{FF81}', '\u{FF81}', Mapping::Mapped(&['\u{30C1}'])),
+    ('\u{FF82}', '\u{FF82}', Mapping::Mapped(&['\u{30C4}'])),
+    ('\u{FF83}', '\u{FF83}', Mapping::Mapped(&['\u{30C6}'])),
+    ('\u{FF84}', '\u{FF84}', Mapping::Mapped(&['\u{30C8}'])),
+    ('\u{FF85}', '\u{FF85}', Mapping::Mapped(&['\u{30CA}'])),
+    ('\u{FF86}', '\u{FF86}', Mapping::Mapped(&['\u{30CB}'])),
+    ('\u{FF87}', '\u{FF87}', Mapping::Mapped(&['\u{30CC}'])),
+    ('\u{FF88}', '\u{FF88}', Mapping::Mapped(&['\u{30CD}'])),
+    ('\u{FF89}', '\u{FF89}', Mapping::Mapped(&['\u{30CE}'])),
+    ('\u{FF8A}', '\u{FF8A}', Mapping::Mapped(&['\u{30CF}'])),
+    ('\u{FF8B}', '\u{FF8B}', Mapping::Mapped(&['\u{30D2}'])),
+    ('\u{FF8C}', '\u{FF8C}', Mapping::Mapped(&['\u{30D5}'])),
+    ('\u{FF8D}', '\u{FF8D}', Mapping::Mapped(&['\u{30D8}'])),
+    ('\u{FF8E}', '\u{FF8E}', Mapping::Mapped(&['\u{30DB}'])),
+    ('\u{FF8F}', '\u{FF8F}', Mapping::Mapped(&['\u{30DE}'])),
+    ('\u{FF90}', '\u{FF90}', Mapping::Mapped(&['\u{30DF}'])),
+    ('\u{FF91}', '\u{FF91}', Mapping::Mapped(&['\u{30E0}'])),
+    ('\u{FF92}', '\u{FF92}', Mapping::Mapped(&['\u{30E1}'])),
+    ('\u{FF93}', '\u{FF93}', Mapping::Mapped(&['\u{30E2}'])),
+    ('\u{FF94}', '\u{FF94}', Mapping::Mapped(&['\u{30E4}'])),
+    ('\u{FF95}', '\u{FF95}', Mapping::Mapped(&['\u{30E6}'])),
+    ('\u{FF96}', '\u{FF96}', Mapping::Mapped(&['\u{30E8}'])),
+    ('\u{FF97}', '\u{FF97}', Mapping::Mapped(&['\u{30E9}'])),
+    ('\u{FF98}', '\u{FF98}', Mapping::Mapped(&['\u{30EA}'])),
+    ('\u{FF99}', '\u{FF99}', Mapping::Mapped(&['\u{30EB}'])),
+    ('\u{FF9A}', '\u{FF9A}', Mapping::Mapped(&['\u{30EC}'])),
+    ('\u{FF9B}', '\u{FF9B}', Mapping::Mapped(&['\u{30ED}'])),
+    ('\u{FF9C}', '\u{FF9C}', Mapping::Mapped(&['\u{30EF}'])),
+    ('\u{FF9D}', '\u{FF9D}', Mapping::Mapped(&['\u{30F3}'])),
+    ('\u{FF9E}', '\u{FF9E}', Mapping::Mapped(&['\u{3099}'])),
+    ('\u{FF9F}', '\u{FF9F}', Mapping::Mapped(&['\u{309A}'])),
+    ('\u{FFA0}', '\u{FFA0}', Mapping::Disallowed),
+    ('\u{FFA1}', '\u{FFA1}', Mapping::Mapped(&['\u{1100}'])),
+    ('\u{FFA2}', '\u{FFA2}', Mapping::Mapped(&['\u{1101}'])),
+    ('\u{FFA3}', '\u{FFA3}', Mapping::Mapped(&['\u{11AA}'])),
+    ('\u{FFA4}', '\u{FFA4}', Mapping::Mapped(&['\u{1102}'])),
+    ('\u{FFA5}', '\u{FFA5}', Mapping::Mapped(&['\u{11AC}'])),
+    ('\u{FFA6}', '\u{FFA6}', Mapping::Mapped(&['\u{11AD}'])),
+    ('\u{FFA7}', '\u{FFA7}', Mapping::Mapped(&['\u{1103}'])),
+    ('\u{FFA8}', '\u{FFA8}', Mapping::Mapped(&['\u{1104}'])),
+    ('\u{FFA9}', '\u{FFA9}', Mapping::Mapped(&['\u{1105}'])),
+    ('\u{FFAA}', '\u{FFAA}', Mapping::Mapped(&['\u{11B0}'])),
+    ('\u{FFAB}', '\u{FFAB}', Mapping::Mapped(&['\u{11B1}'])),
+    ('\u{FFAC}', '\u{FFAC}', Mapping::Mapped(&['\u{11B2}'])),
+    ('\u{FFAD}', '\u{FFAD}', Mapping::Mapped(&['\u{11B3}'])),
+    ('\u{FFAE}', '\u{FFAE}', Mapping::Mapped(&['\u{11B4}'])),
+    ('\u{FFAF}', '\u{FFAF}', Mapping::Mapped(&['\u{11B5}'])),
+    ('\u{FFB0}', '\u{FFB0}', Mapping::Mapped(&['\u{111A}'])),
+    ('\u{FFB1}', '\u{FFB1}', Mapping::Mapped(&['\u{1106}'])),
+    ('\u{FFB2}', '\u{FFB2}', Mapping::Mapped(&['\u{1107}'])),
+    ('\u{FFB3}', '\u{FFB3}', Mapping::Mapped(&['\u{1108}'])),
+    ('\u{FFB4}', '\u{FFB4}', Mapping::Mapped(&['\u{1121}'])),
+    ('\u{FFB5}', '\u{FFB5}', Mapping::Mapped(&['\u{1109}'])),
+    ('\u{FFB6}', '\u{FFB6}', Mapping::Mapped(&['\u{110A}'])),
+    ('\u{FFB7}', '\u{FFB7}', Mapping::Mapped(&['\u{110B}'])),
+    ('\u{FFB8}', '\u{FFB8}', Mapping::Mapped(&['\u{110C}'])),
+    ('\u{FFB9}', '\u{FFB9}', Mapping::Mapped(&['\u{110D}'])),
+    ('\u{FFBA}', '\u{FFBA}', Mapping::Mapped(&['\u{110E}'])),
+    ('\u{FFBB}', '\u{FFBB}', Mapping::Mapped(&['\u{110F}'])),
+    ('\u{FFBC}', '\u{FFBC}', Mapping::Mapped(&['\u{1110}'])),
+    ('\u{FFBD}', '\u{FFBD}', Mapping::Mapped(&['\u{1111}'])),
+    ('\u{FFBE}', '\u{FFBE}', Mapping::Mapped(&['\u{1112}'])),
+    ('\u{FFBF}', '\u{FFC1}', Mapping::Disallowed),
+    ('\u{FFC2}', '\u{FFC2}', Mapping::Mapped(&['\u{1161}'])),
+    ('\u{FFC3}', '\u{FFC3}', Mapping::Mapped(&['\u{1162}'])),
+    ('\u{FFC4}', '\u{FFC4}', Mapping::Mapped(&['\u{1163}'])),
+    ('\u{FFC5}', '\u{FFC5}', Mapping::Mapped(&['\u{1164}'])),
+    ('\u{FFC6}', '\u{FFC6}', Mapping::Mapped(&['\u{1165}'])),
+    ('\u{FFC7}', '\u{FFC7}', Mapping::Mapped(&['\u{1166}'])),
+    ('\u{FFC8}', '\u{FFC9}', Mapping::Disallowed),
+    ('\u{FFCA}', '\u{FFCA}', Mapping::Mapped(&['\u{1167}'])),
+    ('\u{FFCB}', '\u{FFCB}', Mapping::Mapped(&['\u{1168}'])),
+    ('\u{FFCC}', '\u{FFCC}', Mapping::Mapped(&['\u{1169}'])),
+    ('\u{FFCD}', '\u{FFCD}', Mapping::Mapped(&['\u{116A}'])),
+    ('\u{FFCE}', '\u{FFCE}', Mapping::Mapped(&['\u{116B}'])),
+    ('\u{FFCF}', '\u{FFCF}', Mapping::Mapped(&['\u{116C}'])),
+    ('\u{FFD0}', '\u{FFD1}', Mapping::Disallowed),
+    ('\u{FFD2}', '\u{FFD2}', Mapping::Mapped(&['\u{116D}'])),
+    ('\u{FFD3}', '\u{FFD3}', Mapping::Mapped(&['\u{116E}'])),
+    ('\u{FFD4}', '\u{FFD4}', Mapping::Mapped(&['\u{116F}'])),
+    ('\u{FFD5}', '\u{FFD5}', Mapping::Mapped(&['\u{1170}'])),
+    ('\u{FFD6}', '\u{FFD6}', Mapping::Mapped(&['\u{1171}'])),
+    ('\u{FFD7}', '\u{FFD7}', Mapping::Mapped(&['\u{1172}'])),
+    ('\u{FFD8}', '\u{FFD9}', Mapping::Disallowed),
+    ('\u{FFDA}', '\u{FFDA}', Mapping::Mapped(&['\u{1173}'])),
+    ('\u{FFDB}', '\u{FFDB}', Mapping::Mapped(&['\u{1174}'])),
+    ('\u{FFDC}', '\u{FFDC}', Mapping::Mapped(&['\u{1175}'])),
+    ('\u{FFDD}', '\u{FFDF}', Mapping::Disallowed),
+    ('\u{FFE0}', '\u{FFE0}', Mapping::Mapped(&['\u{A2}'])),
+    ('\u{FFE1}', '\u{FFE1}', Mapping::Mapped(&['\u{A3}'])),
+    ('\u{FFE2}', '\u{FFE2}', Mapping::Mapped(&['\u{AC}'])),
+    ('\u{FFE3}', '\u{FFE3}', Mapping::DisallowedStd3Mapped(&['\u{20}', '\u{304}'])),
+    ('\u{FFE4}', '\u{FFE4}', Mapping::Mapped(&['\u{A6}'])),
+    ('\u{FFE5}', '\u{FFE5}', Mapping::Mapped(&['\u{A5}'])),
+    ('\u{FFE6}', '\u{FFE6}', Mapping::Mapped(&['\u{20A9}'])),
+    ('\u{FFE7}', '\u{FFE7}', Mapping::Disallowed),
+    ('\u{FFE8}', '\u{FFE8}', Mapping::Mapped(&['\u{2502}'])),
+    ('\u{FFE9}', '\u{FFE9}', Mapping::Mapped(&['\u{2190}'])),
+    ('\u{FFEA}', '\u{FFEA}', Mapping::Mapped(&['\u{2191}'])),
+    ('\u{FFEB}', '\u{FFEB}', Mapping::Mapped(&['\u{2192}'])),
+    ('\u{FFEC}', '\u{FFEC}', Mapping::Mapped(&['\u{2193}'])),
+    ('\u{FFED}', '\u{FFED}', Mapping::Mapped(&['\u{25A0}'])),
+    ('\u{FFEE}', '\u{FFEE}', Mapping::Mapped(&['\u{25CB}'])),
+    ('\u{FFEF}', '\u{FFFF}', Mapping::Disallowed),
+    ('\u{10000}', '\u{1000B}', Mapping::Valid),
+    ('\u{1000C}', '\u{1000C}', Mapping::Disallowed),
+    ('\u{1000D}', '\u{10026}', Mapping::Valid),
+    ('\u{10027}', '\u{10027}', Mapping::Disallowed),
+    ('\u{10028}', '\u{1003A}', Mapping::Valid),
+    ('\u{1003B}', '\u{1003B}', Mapping::Disallowed),
+    ('\u{1003C}', '\u{1003D}', Mapping::Valid),
+    ('\u{1003E}', '\u{1003E}', Mapping::Disallowed),
+    ('\u{1003F}', '\u{1004D}', Mapping::Valid),
+    ('\u{1004E}', '\u{1004F}', Mapping::Disallowed),
+    ('\u{10050}', '\u{1005D}', Mapping::Valid),
+    ('\u{1005E}', '\u{1007F}', Mapping::Disallowed),
+    ('\u{10080}', '\u{100FA}', Mapping::Valid),
+    ('\u{100FB}', '\u{100FF}', Mapping::Disallowed),
+    ('\u{10100}', '\u{10102}', Mapping::Valid),
+    ('\u{10103}', '\u{10106}', Mapping::Disallowed),
+    ('\u{10107}', '\u{10133}', Mapping::Valid),
+    ('\u{10134}', '\u{10136}', Mapping::Disallowed),
+    ('\u{10137}', '\u{1018E}', Mapping::Valid),
+    ('\u{1018F}', '\u{1018F}', Mapping::Disallowed),
+    ('\u{10190}', '\u{1019C}', Mapping::Valid),
+    ('\u{1019D}', '\u{1019F}', Mapping::Disallowed),
+    ('\u{101A0}', '\u{101A0}', Mapping::Valid),
+    ('\u{101A1}', '\u{101CF}', Mapping::Disallowed),
+    ('\u{101D0}', '\u{101FD}', Mapping::Valid),
+    ('\u{101FE}', '\u{1027F}', Mapping::Disallowed),
+    ('\u{10280}', '\u{1029C}', Mapping::Valid),
+    ('\u{1029D}', '\u{1029F}', Mapping::Disallowed),
+    ('\u{102A0}', '\u{102D0}', Mapping::Valid),
+    ('\u{102D1}', '\u{102DF}', Mapping::Disallowed),
+    ('\u{102E0}', '\u{102FB}', Mapping::Valid),
+    ('\u{102FC}', '\u{102FF}', Mapping::Disallowed),
+    ('\u{10300}', '\u{10323}', Mapping::Valid),
+    ('\u{10324}', '\u{1032C}', Mapping::Disallowed),
+    ('\u{1032D}', '\u{1034A}', Mapping::Valid),
+    ('\u{1034B}', '\u{1034F}', Mapping::Disallowed),
+    ('\u{10350}', '\u{1037A}', Mapping::Valid),
+    ('\u{1037B}', '\u{1037F}', Mapping::Disallowed),
+    ('\u{10380}', '\u{1039D}', Mapping::Valid),
+    ('\u{1039E}', '\u{1039E}', Mapping::Disallowed),
+    ('\u{1039F}', '\u{103C3}', Mapping::Valid),
+    ('\u{103C4}', '\u{103C7}', Mapping::Disallowed),
+    ('\u{103C8}', '\u{103D5}', Mapping::Valid),
+    ('\u{103D6}', '\u{103FF}', Mapping::Disallowed),
+    ('\u{10400}', '\u{10400}', Mapping::Mapped(&['\u{10428}'])),
+    ('\u{10401}', '\u{10401}', Mapping::Mapped(&['\u{10429}'])),
+    ('\u{10402}', '\u{10402}', Mapping::Mapped(&['\u{1042A}'])),
+    ('\u{10403}', '\u{10403}', Mapping::Mapped(&['\u{1042B}'])),
+    ('\u{10404}', '\u{10404}', Mapping::Mapped(&['\u{1042C}'])),
+    ('\u{10405}', '\u{10405}', Mapping::Mapped(&['\u{1042D}'])),
+    ('\u{10406}', '\u{10406}', Mapping::Mapped(&['\u{1042E}'])),
+    ('\u{10407}', '\u{10407}', Mapping::Mapped(&['\u{1042F}'])),
+    ('\u{10408}', '\u{10408}', Mapping::Mapped(&['\u{10430}'])),
+    ('\u{10409}', '\u{10409}', Mapping::Mapped(&['\u{10431}'])),
+    ('\u{1040A}', '\u{1040A}', Mapping::Mapped(&['\u{10432}'])),
+    ('\u{1040B}', '\u{1040B}', Mapping::Mapped(&['\u{10433}'])),
+    ('\u{1040C}', '\u{1040C}', Mapping::Mapped(&['\u{10434}'])),
+    ('\u{1040D}', '\u{1040D}', Mapping::Mapped(&['\u{10435}'])),
+    ('\u{1040E}', '\u{1040E}', Mapping::Mapped(&['\u{10436}'])),
+    ('\u{1040F}', '\u{1040F}', Mapping::Mapped(&['\u{10437}'])),
+    ('\u{10410}', '\u{10410}', Mapping::Mapped(&['\u{10438}'])),
+    ('\u{10411}', '\u{10411}', Mapping::Mapped(&['\u{10439}'])),
+    ('\u{10412}', '\u{10412}', Mapping::Mapped(&['\u{1043A}'])),
+    ('\u{10413}', '\u{10413}', Mapping::Mapped(&['\u{1043B}'])),
+    ('\u{10414}', '\u{10414}', Mapping::Mapped(&['\u{1043C}'])),
+    ('\u{10415}', '\u{10415}', Mapping::Mapped(&['\u{1043D}'])),
+    ('\u{10416}', '\u{10416}', Mapping::Mapped(&['\u{1043E}'])),
+    ('\u{10417}', '\u{10417}', Mapping::Mapped(&['\u{1043F}'])),
+    ('\u{10418}', '\u{10418}', Mapping::Mapped(&['\u{10440}'])),
+    ('\u{10419}', '\u{10419}', Mapping::Mapped(&['\u{10441}'])),
+    ('\u{1041A}', '\u{1041A}', Mapping::Mapped(&['\u{10442}'])),
+    ('\u{1041B}', '\u{1041B}', Mapping::Mapped(&['\u{10443}'])),
+    ('\u{1041C}', '\u{1041C}', Mapping::Mapped(&['\u{10444}'])),
+    ('\u{1041D}', '\u{1041D}', Mapping::Mapped(&['\u{10445}'])),
+    ('\u{1041E}', '\u{1041E}', Mapping::Mapped(&['\u{10446}'])),
+    ('\u{1041F}', '\u{1041F}', Mapping::Mapped(&['\u{10447}'])),
+    ('\u{10420}', '\u{10420}', Mapping::Mapped(&['\u{10448}'])),
+    ('\u{10421}', '\u{10421}', Mapping::Mapped(&['\u{10449}'])),
+    ('\u{10422}', '\u{10422}', Mapping::Mapped(&['\u{1044A}'])),
+    ('\u{10423}', '\u{10423}', Mapping::Mapped(&['\u{1044B}'])),
+    ('\u{10424}', '\u{10424}', Mapping::Mapped(&['\u{1044C}'])),
+    ('\u{10425}', '\u{10425}', Mapping::Mapped(&['\u{1044D}'])),
+    ('\u{10426}', '\u{10426}', Mapping::Mapped(&['\u{1044E}'])),
+    ('\u{10427}', '\u{10427}', Mapping::Mapped(&['\u{1044F}'])),
+    ('\u{10428}', '\u{1049D}', Mapping::Valid),
+    ('\u{1049E}', '\u{1049F}', Mapping::Disallowed),
+    ('\u{104A0}', '\u{104A9}', Mapping::Valid),
+    ('\u{104AA}', '\u{104AF}', Mapping::Disallowed),
+    ('\u{104B0}', '\u{104B0}', Mapping::Mapped(&['\u{104D8}'])),
+    ('\u{104B1}', '\u{104B1}', Mapping::Mapped(&['\u{104D9}'])),
+    ('\u{104B2}', '\u{104B2}', Mapping::Mapped(&['\u{104DA}'])),
+    ('\u{104B3}', '\u{104B3}', Mapping::Mapped(&['\u{104DB}'])),
+    ('\u{104B4}', '\u{104B4}', Mapping::Mapped(&['\u{104DC}'])),
+    ('\u{104B5}', '\u{104B5}', Mapping::Mapped(&['\u{104DD}'])),
+    ('\u{104B6}', '\u{104B6}', Mapping::Mapped(&['\u{104DE}'])),
+    ('\u{104B7}', '\u{104B7}', Mapping::Mapped(&['\u{104DF}'])),
+    ('\u{104B8}', '\u{104B8}', Mapping::Mapped(&['\u{104E0}'])),
+    ('\u{104B9}', '\u{104B9}', Mapping::Mapped(&['\u{104E1}'])),
+    ('\u{104BA}', '\u{104BA}', Mapping::Mapped(&['\u{104E2}'])),
+    ('\u{104BB}', '\u{104BB}', Mapping::Mapped(&['\u{104E3}'])),
+    ('\u{104BC}', '\u{104BC}', Mapping::Mapped(&['\u{104E4}'])),
+    ('\u{104BD}', '\u{104BD}', Mapping::Mapped(&['\u{104E5}'])),
+    ('\u{104BE}', '\u{104BE}', Mapping::Mapped(&['\u{104E6}'])),
+    ('\u{104BF}', '\u{104BF}', Mapping::Mapped(&['\u{104E7}'])),
+    ('\u{104C0}', '\u{104C0}', Mapping::Mapped(&['\u{104E8}'])),
+    ('\u{104C1}', '\u{104C1}', Mapping::Mapped(&['\u{104E9}'])),
+    ('\u{104C2}', '\u{104C2}', Mapping::Mapped(&['\u{104EA}'])),
+    ('\u{104C3}', '\u{104C3}', Mapping::Mapped(&['\u{104EB}'])),
+    ('\u{104C4}', '\u{104C4}', Mapping::Mapped(&['\u{104EC}'])),
+    ('\u{104C5}', '\u{104C5}', Mapping::Mapped(&['\u{104ED}'])),
+    ('\u{104C6}', '\u{104C6}', Mapping::Mapped(&['\u{104EE}'])),
+    ('\u{104C7}', '\u{104C7}', Mapping::Mapped(&['\u{104EF}'])),
+    ('\u{104C8}', '\u{104C8}', Mapping::Mapped(&['\u{104F0}'])),
+    ('\u{104C9}', '\u{104C9}', Mapping::Mapped(&['\u{104F1}'])),
+    ('\u{104CA}', '\u{104CA}', Mapping::Mapped(&['\u{104F2}'])),
+    ('\u{104CB}', '\u{104CB}', Mapping::Mapped(&['\u{104F3}'])),
+    ('\u{104CC}', '\u{104CC}', Mapping::Mapped(&['\u{104F4}'])),
+    ('\u{104CD}', '\u{104CD}', Mapping::Mapped(&['\u{104F5}'])),
+    ('\u{104CE}', '\u{104CE}', Mapping::Mapped(&['\u{104F6}'])),
+    ('\u{104CF}', '\u{104CF}', Mapping::Mapped(&['\u{104F7}'])),
+    ('\u{104D0}', '\u{104D0}', Mapping::Mapped(&['\u{104F8}'])),
+    ('\u{104D1}', '\u{104D1}', Mapping::Mapped(&['\u{104F9}'])),
+    ('\u{104D2}', '\u{104D2}', Mapping::Mapped(&['\u{104FA}'])),
+    ('\u{104D3}', '\u{104D3}', Mapping::Mapped(&['\u{104FB}'])),
+    ('\u{104D4}', '\u{104D7}', Mapping::Disallowed),
+    ('\u{104D8}', '\u{104FB}', Mapping::Valid),
+    ('\u{104FC}', '\u{104FF}', Mapping::Disallowed),
+    ('\u{10500}', '\u{10527}', Mapping::Valid),
+    ('\u{10528}', '\u{1052F}', Mapping::Disallowed),
+    ('\u{10530}', '\u{10563}', Mapping::Valid),
+    ('\u{10564}', '\u{1056E}', Mapping::Disallowed),
+    ('\u{1056F}', '\u{1056F}', Mapping::Valid),
+    ('\u{10570}', '\u{105FF}', Mapping::Disallowed),
+    ('\u{10600}', '\u{10736}', Mapping::Valid),
+    ('\u{10737}', '\u{1073F}', Mapping::Disallowed),
+    ('\u{10740}', '\u{10755}', Mapping::Valid),
+    ('\u{10756}', '\u{1075F}', Mapping::Disallowed),
+    ('\u{10760}', '\u{10767}', Mapping::Valid),
+    ('\u{10768}', '\u{107FF}', Mapping::Disallowed),
+    ('\u{10800}', '\u{10805}', Mapping::Valid),
+    ('\u{10806}', '\u{10807}', Mapping::Disallowed),
+    ('\u{10808}', '\u{10808}', Mapping::Valid),
+    ('\u{10809}', '\u{10809}', Mapping::Disallowed),
+    ('\u{1080A}', '\u{10835}', Mapping::Valid),
+    ('\u{10836}', '\u{10836}', Mapping::Disallowed),
+    ('\u{10837}', '\u{10838}', Mapping::Valid),
+    ('\u{10839}', '\u{1083B}', Mapping::Disallowed),
+    ('\u{1083C}', '\u{1083C}', Mapping::Valid),
+    ('\u{1083D}', '\u{1083E}', Mapping::Disallowed),
+    ('\u{1083F}', '\u{10855}', Mapping::Valid),
+    ('\u{10856}', '\u{10856}', Mapping::Disallowed),
+    ('\u{10857}', '\u{1089E}', Mapping::Valid),
+    ('\u{1089F}', '\u{108A6}', Mapping::Disallowed),
+    ('\u{108A7}', '\u{108AF}', Mapping::Valid),
+    ('\u{108B0}', '\u{108DF}', Mapping::Disallowed),
+    ('\u{108E0}', '\u{108F2}', Mapping::Valid),
+    ('\u{108F3}', '\u{108F3}', Mapping::Disallowed),
+    ('\u{108F4}', '\u{108F5}', Mapping::Valid),
+    ('\u{108F6}', '\u{108FA}', Mapping::Disallowed),
+    ('\u{108FB}', '\u{1091B}', Mapping::Valid),
+    ('\u{1091C}', '\u{1091E}', Mapping::Disallowed),
+    ('\u{1091F}', '\u{10939}', Mapping::Valid),
+    ('\u{1093A}', '\u{1093E}', Mapping::Disallowed),
+    ('\u{1093F}', '\u{1093F}', Mapping::Valid),
+    ('\u{10940}', '\u{1097F}', Mapping::Disallowed),
+    ('\u{10980}', '\u{109B7}', Mapping::Valid),
+    ('\u{109B8}', '\u{109BB}', Mapping::Disallowed),
+    ('\u{109BC}', '\u{109CF}', Mapping::Valid),
+    ('\u{109D0}', '\u{109D1}', Mapping::Disallowed),
+    ('\u{109D2}', '\u{10A03}', Mapping::Valid),
+    ('\u{10A04}', '\u{10A04}', Mapping::Disallowed),
+    ('\u{10A05}', '\u{10A06}', Mapping::Valid),
+    ('\u{10A07}', '\u{10A0B}', Mapping::Disallowed),
+    ('\u{10A0C}', '\u{10A13}', Mapping::Valid),
+    ('\u{10A14}', '\u{10A14}', Mapping::Disallowed),
+    ('\u{10A15}', '\u{10A17}', Mapping::Valid),
+    ('\u{10A18}', '\u{10A18}', Mapping::Disallowed),
+    ('\u{10A19}', '\u{10A35}', Mapping::Valid),
+    ('\u{10A36}', '\u{10A37}', Mapping::Disallowed),
+    ('\u{10A38}', '\u{10A3A}', Mapping::Valid),
+    ('\u{10A3B}', '\u{10A3E}', Mapping::Disallowed),
+    ('\u{10A3F}', '\u{10A48}', Mapping::Valid),
+    ('\u{10A49}', '\u{10A4F}', Mapping::Disallowed),
+    ('\u{10A50}', '\u{10A58}', Mapping::Valid),
+    ('\u{10A59}', '\u{10A5F}', Mapping::Disallowed),
+    ('\u{10A60}', '\u{10A9F}', Mapping::Valid),
+    ('\u{10AA0}', '\u{10ABF}', Mapping::Disallowed),
+    ('\u{10AC0}', '\u{10AE6}', Mapping::Valid),
+    ('\u{10AE7}', '\u{10AEA}', Mapping::Disallowed),
+    ('\u{10AEB}', '\u{10AF6}', Mapping::Valid),
+    ('\u{10AF7}', '\u{10AFF}', Mapping::Disallowed),
+    ('\u{10B00}', '\u{10B35}', Mapping::Valid),
+    ('\u{10B36}', '\u{10B38}', Mapping::Disallowed),
+    ('\u{10B39}', '\u{10B55}', Mapping::Valid),
+    ('\u{10B56}', '\u{10B57}', Mapping::Disallowed),
+    ('\u{10B58}', '\u{10B72}', Mapping::Valid),
+    ('\u{10B73}', '\u{10B77}', Mapping::Disallowed),
+    ('\u{10B78}', '\u{10B91}', Mapping::Valid),
+    ('\u{10B92}', '\u{10B98}', Mapping::Disallowed),
+    ('\u{10B99}', '\u{10B9C}', Mapping::Valid),
+    ('\u{10B9D}', '\u{10BA8}', Mapping::Disallowed),
+    ('\u{10BA9}', '\u{10BAF}', Mapping::Valid),
+    ('\u{10BB0}', '\u{10BFF}', Mapping::Disallowed),
+    ('\u{10C00}', '\u{10C48}', Mapping::Valid),
+    ('\u{10C49}', '\u{10C7F}', Mapping::Disallowed),
+    ('\u{10C80}', '\u{10C80}', Mapping::Mapped(&['\u{10CC0}'])),
+    ('\u{10C81}', '\u{10C81}', Mapping::Mapped(&['\u{10CC1}'])),
+    ('\u{10C82}', '\u{10C82}', Mapping::Mapped(&['\u{10CC2}'])),
+    ('\u{10C83}', '\u{10C83}', Mapping::Mapped(&['\u{10CC3}'])),
+    ('\u{10C84}', '\u{10C84}', Mapping::Mapped(&['\u{10CC4}'])),
+    ('\u{10C85}', '\u{10C85}', Mapping::Mapped(&['\u{10CC5}'])),
+    ('\u{10C86}', '\u{10C86}', Mapping::Mapped(&['\u{10CC6}'])),
+    ('\u{10C87}', '\u{10C87}', Mapping::Mapped(&['\u{10CC7}'])),
+    ('\u{10C88}', '\u{10C88}', Mapping::Mapped(&['\u{10CC8}'])),
+    ('\u{10C89}', '\u{10C89}', Mapping::Mapped(&['\u{10CC9}'])),
+    ('\u{10C8A}', '\u{10C8A}', Mapping::Mapped(&['\u{10CCA}'])),
+    ('\u{10C8B}', '\u{10C8B}', Mapping::Mapped(&['\u{10CCB}'])),
+    ('\u{10C8C}', '\u{10C8C}', Mapping::Mapped(&['\u{10CCC}'])),
+    ('\u{10C8D}', '\u{10C8D}', Mapping::Mapped(&['\u{10CCD}'])),
+    ('\u{10C8E}', '\u{10C8E}', Mapping::Mapped(&['\u{10CCE}'])),
+    ('\u{10C8F}', '\u{10C8F}', Mapping::Mapped(&['\u{10CCF}'])),
+    ('\u{10C90}', '\u{10C90}', Mapping::Mapped(&['\u{10CD0}'])),
+    ('\u{10C91}', '\u{10C91}', Mapping::Mapped(&['\u{10CD1}'])),
+    ('\u{10C92}', '\u{10C92}', Mapping::Mapped(&['\u{10CD2}'])),
+    ('\u{10C93}', '\u{10C93}', Mapping::Mapped(&['\u{10CD3}'])),
+    ('\u{10C94}', '\u{10C94}', Mapping::Mapped(&['\u{10CD4}'])),
+    ('\u{10C95}', '\u{10C95}', Mapping::Mapped(&['\u{10CD5}'])),
+    ('\u{10C96}', '\u{10C96}', Mapping::Mapped(&['\u{10CD6}'])),
+    ('\u{10C97}', '\u{10C97}', Mapping::Mapped(&['\u{10CD7}'])),
+    ('\u{10C98}', '\u{10C98}', Mapping::Mapped(&['\u{10CD8}'])),
+    ('\u{10C99}', '\u{10C99}', Mapping::Mapped(&['\u{10CD9}'])),
+    ('\u{10C9A}', '\u{10C9A}', Mapping::Mapped(&['\u{10CDA}'])),
+    ('\u{10C9B}', '\u{10C9B}', Mapping::Mapped(&['\u{10CDB}'])),
+    ('\u{10C9C}', '\u{10C9C}', Mapping::Mapped(&['\u{10CDC}'])),
+    ('\u{10C9D}', '\u{10C9D}', Mapping::Mapped(&['\u{10CDD}'])),
+    ('\u{10C9E}', '\u{10C9E}', Mapping::Mapped(&['\u{10CDE}'])),
+    ('\u{10C9F}', '\u{10C9F}', Mapping::Mapped(&['\u{10CDF}'])),
+    ('\u{10CA0}', '\u{10CA0}', Mapping::Mapped(&['\u{10CE0}'])),
+    ('\u{10CA1}', '\u{10CA1}', Mapping::Mapped(&['\u{10CE1}'])),
+    ('\u{10CA2}', '\u{10CA2}', Mapping::Mapped(&['\u{10CE2}'])),
+    ('\u{10CA3}', '\u{10CA3}', Mapping::Mapped(&['\u{10CE3}'])),
+    ('\u{10CA4}', '\u{10CA4}', Mapping::Mapped(&['\u{10CE4}'])),
+    ('\u{10CA5}', '\u{10CA5}', Mapping::Mapped(&['\u{10CE5}'])),
+    ('\u{10CA6}', '\u{10CA6}', Mapping::Mapped(&['\u{10CE6}'])),
+    ('\u{10CA7}', '\u{10CA7}', Mapping::Mapped(&['\u{10CE7}'])),
+    ('\u{10CA8}', '\u{10CA8}', Mapping::Mapped(&['\u{10CE8}'])),
+    ('\u{10CA9}', '\u{10CA9}', Mapping::Mapped(&['\u{10CE9}'])),
+    ('\u{10CAA}', '\u{10CAA}', Mapping::Mapped(&['\u{10CEA}'])),
+    ('\u{10CAB}', '\u{10CAB}', Mapping::Mapped(&['\u{10CEB}'])),
+    ('\u{10CAC}', '\u{10CAC}', Mapping::Mapped(&['\u{10CEC}'])),
+    ('\u{10CAD}', '\u{10CAD}', Mapping::Mapped(&['\u{10CED}'])),
+    ('\u{10CAE}', '\u{10CAE}', Mapping::Mapped(&['\u{10CEE}'])),
+    ('\u{10CAF}', '\u{10CAF}', Mapping::Mapped(&['\u{10CEF}'])),
+    ('\u{10CB0}', '\u{10CB0}', Mapping::Mapped(&['\u{10CF0}'])),
+    ('\u{10CB1}', '\u{10CB1}', Mapping::Mapped(&['\u{10CF1}'])),
+    ('\u{10CB2}', '\u{10CB2}', Mapping::Mapped(&['\u{10CF2}'])),
+    ('\u{10CB3}', '\u{10CBF}', Mapping::Disallowed),
+    ('\u{10CC0}', '\u{10CF2}', Mapping::Valid),
+    ('\u{10CF3}', '\u{10CF9}', Mapping::Disallowed),
+    ('\u{10CFA}', '\u{10D27}', Mapping::Valid),
+    ('\u{10D28}', '\u{10D2F}', Mapping::Disallowed),
+    ('\u{10D30}', '\u{10D39}', Mapping::Valid),
+    ('\u{10D3A}', '\u{10E5F}', Mapping::Disallowed),
+    ('\u{10E60}', '\u{10E7E}', Mapping::Valid),
+    ('\u{10E7F}', '\u{10E7F}', Mapping::Disallowed),
+    ('\u{10E80}', '\u{10EA9}', Mapping::Valid),
+    ('\u{10EAA}', '\u{10EAA}', Mapping::Disallowed),
+    ('\u{10EAB}', '\u{10EAD}', Mapping::Valid),
+    ('\u{10EAE}', '\u{10EAF}', Mapping::Disallowed),
+    ('\u{10EB0}', '\u{10EB1}', Mapping::Valid),
+    ('\u{10EB2}', '\u{10EFF}', Mapping::Disallowed),
+    ('\u{10F00}', '\u{10F27}', Mapping::Valid),
+    ('\u{10F28}', '\u{10F2F}', Mapping::Disallowed),
+    ('\u{10F30}', '\u{10F59}', Mapping::Valid),
+    ('\u{10F5A}', '\u{10FAF}', Mapping::Disallowed),
+    ('\u{10FB0}', '\u{10FCB}', Mapping::Valid),
+    ('\u{10FCC}', '\u{10FDF}', Mapping::Disallowed),
+    ('\u{10FE0}', '\u{10FF6}', Mapping::Valid),
+    ('\u{10FF7}', '\u{10FFF}', Mapping::Disallowed),
+    ('\u{11000}', '\u{1104D}', Mapping::Valid),
+    ('\u{1104E}', '\u{11051}', Mapping::Disallowed),
+    ('\u{11052}', '\u{1106F}', Mapping::Valid),
+    ('\u{11070}', '\u{1107E}', Mapping::Disallowed),
+    ('\u{1107F}', '\u{110BC}', Mapping::Valid),
+    ('\u{110BD}', '\u{110BD}', Mapping::Disallowed),
+    ('\u{110BE}', '\u{110C1}', Mapping::Valid),
+    ('\u{110C2}', '\u{110CF}', Mapping::Disallowed),
+    ('\u{110D0}', '\u{110E8}', Mapping::Valid),
+    ('\u{110E9}', '\u{110EF}', Mapping::Disallowed),
+    ('\u{110F0}', '\u{110F9}', Mapping::Valid),
+    ('\u{110FA}', '\u{110FF}', Mapping::Disallowed),
+    ('\u{11100}', '\u{11134}', Mapping::Valid),
+    ('\u{11135}', '\u{11135}', Mapping::Disallowed),
+    ('\u{11136}', '\u{11147}', Mapping::Valid),
+    ('\u{11148}', '\u{1114F}', Mapping::Disallowed),
+    ('\u{11150}', '\u{11176}', Mapping::Valid),
+    ('\u{11177}', '\u{1117F}', Mapping::Disallowed),
+    ('\u{11180}', '\u{111DF}', Mapping::Valid),
+    ('\u{111E0}', '\u{111E0}', Mapping::Disallowed),
+    ('\u{111E1}', '\u{111F4}', Mapping::Valid),
+    ('\u{111F5}', '\u{111FF}', Mapping::Disallowed),
+    ('\u{11200}', '\u{11211}', Mapping::Valid),
+    ('\u{11212}', '\u{11212}', Mapping::Disallowed),
+    ('\u{11213}', '\u{1123E}', Mapping::Valid),
+    ('\u{1123F}', '\u{1127F}', Mapping::Disallowed),
+    ('\u{11280}', '\u{11286}', Mapping::Valid),
+    ('\u{11287}', '\u{11287}', Mapping::Disallowed),
+    ('\u{11288}', '\u{11288}', Mapping::Valid),
+    ('\u{11289}', '\u{11289}', Mapping::Disallowed),
+    ('\u{1128A}', '\u{1128D}', Mapping::Valid),
+    ('\u{1128E}', '\u{1128E}', Mapping::Disallowed),
+    ('\u{1128F}', '\u{1129D}', Mapping::Valid),
+    ('\u{1129E}', '\u{1129E}', Mapping::Disallowed),
+    ('\u{1129F}', '\u{112A9}', Mapping::Valid),
+    ('\u{112AA}', '\u{112AF}', Mapping::Disallowed),
+    ('\u{112B0}', '\u{112EA}', Mapping::Valid),
+    ('\u{112EB}', '\u{112EF}', Mapping::Disallowed),
+    ('\u{112F0}', '\u{112F9}', Mapping::Valid),
+    ('\u{112FA}', '\u{112FF}', Mapping::Disallowed),
+    ('\u{11300}', '\u{11303}', Mapping::Valid),
+    ('\u{11304}', '\u{11304}', Mapping::Disallowed),
+    ('\u{11305}', '\u{1130C}', Mapping::Valid),
+    ('\u{1130D}', '\u{1130E}', Mapping::Disallowed),
+    ('\u{1130F}', '\u{11310}', Mapping::Valid),
+    ('\u{11311}', '\u{11312}', Mapping::Disallowed),
+    ('\u{11313}', '\u{11328}', Mapping::Valid),
+    ('\u{11329}', '\u{11329}', Mapping::Disallowed),
+    ('\u{1132A}', '\u{11330}', Mapping::Valid),
+    ('\u{11331}', '\u{11331}', Mapping::Disallowed),
+    ('\u{11332}', '\u{11333}', Mapping::Valid),
+    ('\u{11334}', '\u{11334}', Mapping::Disallowed),
+    ('\u{11335}', '\u{11339}', Mapping::Valid),
+    ('\u{1133A}', '\u{1133A}', Mapping::Disallowed),
+    ('\u{1133B}', '\u{11344}', Mapping::Valid),
+    ('\u{11345}', '\u{11346}', Mapping::Disallowed),
+    ('\u{11347}', '\u{11348}', Mapping::Valid),
+    ('\u{11349}', '\u{1134A}', Mapping::Disallowed),
+    ('\u{1134B}', '\u{1134D}', Mapping::Valid),
+    ('\u{1134E}', '\u{1134F}', Mapping::Disallowed),
+    ('\u{11350}', '\u{11350}', Mapping::Valid),
+    ('\u{11351}', '\u{11356}', Mapping::Disallowed),
+    ('\u{11357}', '\u{11357}', Mapping::Valid),
+    ('\u{11358}', '\u{1135C}', Mapping::Disallowed),
+    ('\u{1135D}', '\u{11363}', Mapping::Valid),
+    ('\u{11364}', '\u{11365}', Mapping::Disallowed),
+    ('\u{11366}', '\u{1136C}', Mapping::Valid),
+    ('\u{1136D}', '\u{1136F}', Mapping::Disallowed),
+    ('\u{11370}', '\u{11374}', Mapping::Valid),
+    ('\u{11375}', '\u{113FF}', Mapping::Disallowed),
+    ('\u{11400}', '\u{1145B}', Mapping::Valid),
+    ('\u{1145C}', '\u{1145C}', Mapping::Disallowed),
+    ('\u{1145D}', '\u{11461}', Mapping::Valid),
+    ('\u{11462}', '\u{1147F}', Mapping::Disallowed),
+    ('\u{11480}', '\u{114C7}', Mapping::Valid),
+    ('\u{114C8}', '\u{114CF}', Mapping::Disallowed),
+    ('\u{114D0}', '\u{114D9}', Mapping::Valid),
+    ('\u{114DA}', '\u{1157F}', Mapping::Disallowed),
+    ('\u{11580}', '\u{115B5}', Mapping::Valid),
+    ('\u{115B6}', '\u{115B7}', Mapping::Disallowed),
+    ('\u{115B8}', '\u{115DD}', Mapping::Valid),
+    ('\u{115DE}', '\u{115FF}', Mapping::Disallowed),
+    ('\u{11600}', '\u{11644}', Mapping::Valid),
+    ('\u{11645}', '\u{1164F}', Mapping::Disallowed),
+    ('\u{11650}', '\u{11659}', Mapping::Valid),
+    ('\u{1165A}', '\u{1165F}', Mapping::Disallowed),
+    ('\u{11660}', '\u{1166C}', Mapping::Valid),
+    ('\u{1166D}', '\u{1167F}', Mapping::Disallowed),
+    ('\u{11680}', '\u{116B8}', Mapping::Valid),
+    ('\u{116B9}', '\u{116BF}', Mapping::Disallowed),
+    ('\u{116C0}', '\u{116C9}', Mapping::Valid),
+    ('\u{116CA}', '\u{116FF}', Mapping::Disallowed),
+    ('\u{11700}', '\u{1171A}', Mapping::Valid),
+    ('\u{1171B}', '\u{1171C}', Mapping::Disallowed),
+    ('\u{1171D}', '\u{1172B}', Mapping::Valid),
+    ('\u{1172C}', '\u{1172F}', Mapping::Disallowed),
+    ('\u{11730}', '\u{1173F}', Mapping::Valid),
+    ('\u{11740}', '\u{117FF}', Mapping::Disallowed),
+    ('\u{11800}', '\u{1183B}', Mapping::Valid),
+    ('\u{1183C}', '\u{1189F}', Mapping::Disallowed),
+    ('\u{118A0}', '\u{118A0}', Mapping::Mapped(&['\u{118C0}'])),
+    ('\u{118A1}', '\u{118A1}', Mapping::Mapped(&['\u{118C1}'])),
+    ('\u{118A2}', '\u{118A2}', Mapping::Mapped(&['\u{118C2}'])),
+    ('\u{118A3}', '\u{118A3}', Mapping::Mapped(&['\u{118C3}'])),
+    ('\u{118A4}', '\u{118A4}', Mapping::Mapped(&['\u{118C4}'])),
+    ('\u{118A5}', '\u{118A5}', Mapping::Mapped(&['\u{118C5}'])),
+    ('\u{118A6}', '\u{118A6}', Mapping::Mapped(&['\u{118C6}'])),
+    ('\u{118A7}', '\u{118A7}', Mapping::Mapped(&['\u{118C7}'])),
+    ('\u{118A8}', '\u{118A8}', Mapping::Mapped(&['\u{118C8}'])),
+    ('\u{118A9}', '\u{118A9}', Mapping::Mapped(&['\u{118C9}'])),
+    ('\u{118AA}', '\u{118AA}', Mapping::Mapped(&['\u{118CA}'])),
+    ('\u{118AB}', '\u{118AB}', Mapping::Mapped(&['\u{118CB}'])),
+    ('\u{118AC}', '\u{118AC}', Mapping::Mapped(&['\u{118CC}'])),
+    ('\u{118AD}', '\u{118AD}', Mapping::Mapped(&['\u{118CD}'])),
+    ('\u{118AE}', '\u{118AE}', Mapping::Mapped(&['\u{118CE}'])),
+    ('\u{118AF}', '\u{118AF}', Mapping::Mapped(&['\u{118CF}'])),
+    ('\u{118B0}', '\u{118B0}', Mapping::Mapped(&['\u{118D0}'])),
+    ('\u{118B1}', '\u{118B1}', Mapping::Mapped(&['\u{118D1}'])),
+    ('\u{118B2}', '\u{118B2}', Mapping::Mapped(&['\u{118D2}'])),
+    ('\u{118B3}', '\u{118B3}', Mapping::Mapped(&['\u{118D3}'])),
+    ('\u{118B4}', '\u{118B4}', Mapping::Mapped(&['\u{118D4}'])),
+    ('\u{118B5}', '\u{118B5}', Mapping::Mapped(&['\u{118D5}'])),
+    ('\u{118B6}', '\u{118B6}', Mapping::Mapped(&['\u{118D6}'])),
+    ('\u{118B7}', '\u{118B7}', Mapping::Mapped(&['\u{118D7}'])),
+    ('\u{118B8}', '\u{118B8}', Mapping::Mapped(&['\u{118D8}'])),
+    ('\u{118B9}', '\u{118B9}', Mapping::Mapped(&['\u{118D9}'])),
+    ('\u{118BA}', '\u{118BA}', Mapping::Mapped(&['\u{118DA}'])),
+    ('\u{118BB}', '\u{118BB}', Mapping::Mapped(&['\u{118DB}'])),
+    ('\u{118BC}', '\u{118BC}', Mapping::Mapped(&['\u{118DC}'])),
+    ('\u{118BD}', '\u{118BD}', Mapping::Mapped(&['\u{118DD}'])),
+    ('\u{118BE}', '\u{118BE}', Mapping::Mapped(&['\u{118DE}'])),
+    ('\u{118BF}', '\u{118BF}', Mapping::Mapped(&['\u{118DF}'])),
+    ('\u{118C0}', '\u{118F2}', Mapping::Valid),
+    ('\u{118F3}', '\u{118FE}', Mapping::Disallowed),
+    ('\u{118FF}', '\u{11906}', Mapping::Valid),
+    ('\u{11907}', '\u{11908}', Mapping::Disallowed),
+    ('\u{11909}', '\u{11909}', Mapping::Valid),
+    ('\u{1190A}', '\u{1190B}', Mapping::Disallowed),
+    ('\u{1190C}', '\u{11913}', Mapping::Valid),
+    ('\u{11914}', '\u{11914}', Mapping::Disallowed),
+    ('\u{11915}', '\u{11916}', Mapping::Valid),
+    ('\u{11917}', '\u{11917}', Mapping::Disallowed),
+    ('\u{11918}', '\u{11935}', Mapping::Valid),
+    ('\u{11936}', '\u{11936}', Mapping::Disallowed),
+    ('\u{11937}', '\u{11938}', Mapping::Valid),
+    ('\u{11939}', '\u{1193A}', Mapping::Disallowed),
+    ('\u{1193B}', '\u{11946}', Mapping::Valid),
+    ('\u{11947}', '\u{1194F}', Mapping::Disallowed),
+    ('\u{11950}', '\u{11959}', Mapping::Valid),
+    ('\u{1195A}', '\u{1199F}', Mapping::Disallowed),
+    ('\u{119A0}', '\u{119A7}', Mapping::Valid),
+    ('\u{119A8}', '\u{119A9}', Mapping::Disallowed),
+    ('\u{119AA}', '\u{119D7}', Mapping::Valid),
+    ('\u{119D8}', '\u{119D9}', Mapping::Disallowed),
+    ('\u{119DA}', '\u{119E4}', Mapping::Valid),
+    ('\u{119E5}', '\u{119FF}', Mapping::Disallowed),
+    ('\u{11A00}', '\u{11A47}', Mapping::Valid),
+    ('\u{11A48}', '\u{11A4F}', Mapping::Disallowed),
+    ('\u{11A50}', '\u{11AA2}', Mapping::Valid),
+    ('\u{11AA3}', '\u{11ABF}', Mapping::Disallowed),
+    ('\u{11AC0}', '\u{11AF8}', Mapping::Valid),
+    ('\u{11AF9}', '\u{11BFF}', Mapping::Disallowed),
+    ('\u{11C00}', '\u{11C08}', Mapping::Valid),
+    ('\u{11C09}', '\u{11C09}', Mapping::Disallowed),
+    ('\u{11C0A}', '\u{11C36}', Mapping::Valid),
+    ('\u{11C37}', '\u{11C37}', Mapping::Disallowed),
+    ('\u{11C38}', '\u{11C45}', Mapping::Valid),
+    ('\u{11C46}', '\u{11C4F}', Mapping::Disallowed),
+    ('\u{11C50}', '\u{11C6C}', Mapping::Valid),
+    ('\u{11C6D}', '\u{11C6F}', Mapping::Disallowed),
+    ('\u{11C70}', '\u{11C8F}', Mapping::Valid),
+    ('\u{11C90}', '\u{11C91}', Mapping::Disallowed),
+    ('\u{11C92}', '\u{11CA7}', Mapping::Valid),
+    ('\u{11CA8}', '\u{11CA8}', Mapping::Disallowed),
+    ('\u{11CA9}', '\u{11CB6}', Mapping::Valid),
+    ('\u{11CB7}', '\u{11CFF}', Mapping::Disallowed),
+    ('\u{11D00}', '\u{11D06}', Mapping::Valid),
+    ('\u{11D07}', '\u{11D07}', Mapping::Disallowed),
+    ('\u{11D08}', '\u{11D09}', Mapping::Valid),
+    ('\u{11D0A}', '\u{11D0A}', Mapping::Disallowed),
+    ('\u{11D0B}', '\u{11D36}', Mapping::Valid),
+    ('\u{11D37}', '\u{11D39}', Mapping::Disallowed),
+    ('\u{11D3A}', '\u{11D3A}', Mapping::Valid),
+    ('\u{11D3B}', '\u{11D3B}', Mapping::Disallowed),
+    ('\u{11D3C}', '\u{11D3D}', Mapping::Valid),
+    ('\u{11D3E}', '\u{11D3E}', Mapping::Disallowed),
+    ('\u{11D3F}', '\u{11D47}', Mapping::Valid),
+    ('\u{11D48}', '\u{11D4F}', Mapping::Disallowed),
+    ('\u{11D50}', '\u{11D59}', Mapping::Valid),
+    ('\u{11D5A}', '\u{11D5F}', Mapping::Disallowed),
+    ('\u{11D60}', '\u{11D65}', Mapping::Valid),
+    ('\u{11D66}', '\u{11D66}', Mapping::Disallowed),
+    ('\u{11D67}', '\u{11D68}', Mapping::Valid),
+    ('\u{11D69}', '\u{11D69}', Mapping::Disallowed),
+    ('\u{11D6A}', '\u{11D8E}', Mapping::Valid),
+    ('\u{11D8F}', '\u{11D8F}', Mapping::Disallowed),
+    ('\u{11D90}', '\u{11D91}', Mapping::Valid),
+    ('\u{11D92}', '\u{11D92}', Mapping::Disallowed),
+    ('\u{11D93}', '\u{11D98}', Mapping::Valid),
+    ('\u{11D99}', '\u{11D9F}', Mapping::Disallowed),
+    ('\u{11DA0}', '\u{11DA9}', Mapping::Valid),
+    ('\u{11DAA}', '\u{11EDF}', Mapping::Disallowed),
+    ('\u{11EE0}', '\u{11EF8}', Mapping::Valid),
+    ('\u{11EF9}', '\u{11FAF}', Mapping::Disallowed),
+    ('\u{11FB0}', '\u{11FB0}', Mapping::Valid),
+    ('\u{11FB1}', '\u{11FBF}', Mapping::Disallowed),
+    ('\u{11FC0}', '\u{11FF1}', Mapping::Valid),
+    ('\u{11FF2}', '\u{11FFE}', Mapping::Disallowed),
+    ('\u{11FFF}', '\u{12399}', Mapping::Valid),
+    ('\u{1239A}', '\u{123FF}', Mapping::Disallowed),
+    ('\u{12400}', '\u{1246E}', Mapping::Valid),
+    ('\u{1246F}', '\u{1246F}', Mapping::Disallowed),
+    ('\u{12470}', '\u{12474}', Mapping::Valid),
+    ('\u{12475}', '\u{1247F}', Mapping::Disallowed),
+    ('\u{12480}', '\u{12543}', Mapping::Valid),
+    ('\u{12544}', '\u{12FFF}', Mapping::Disallowed),
+    ('\u{13000}', '\u{1342E}', Mapping::Valid),
+    ('\u{1342F}', '\u{143FF}', Mapping::Disallowed),
+    ('\u{14400}', '\u{14646}', Mapping::Valid),
+    ('\u{14647}', '\u{167FF}', Mapping::Disallowed),
+    ('\u{16800}', '\u{16A38}', Mapping::Valid),
+    ('\u{16A39}', '\u{16A3F}', Mapping::Disallowed),
+    ('\u{16A40}', '\u{16A5E}', Mapping::Valid),
+    ('\u{16A5F}', '\u{16A5F}', Mapping::Disallowed),
+    ('\u{16A60}', '\u{16A69}', Mapping::Valid),
+    ('\u{16A6A}', '\u{16A6D}', Mapping::Disallowed),
+    ('\u{16A6E}', '\u{16A6F}', Mapping::Valid),
+    ('\u{16A70}', '\u{16ACF}', Mapping::Disallowed),
+    ('\u{16AD0}', '\u{16AED}', Mapping::Valid),
+    ('\u{16AEE}', '\u{16AEF}', Mapping::Disallowed),
+    ('\u{16AF0}', '\u{16AF5}', Mapping::Valid),
+    ('\u{16AF6}', '\u{16AFF}', Mapping::Disallowed),
+    ('\u{16B00}', '\u{16B45}', Mapping::Valid),
+    ('\u{16B46}', '\u{16B4F}', Mapping::Disallowed),
+    ('\u{16B50}', '\u{16B59}', Mapping::Valid),
+    ('\u{16B5A}', '\u{16B5A}', Mapping::Disallowed),
+    ('\u{16B5B}', '\u{16B61}', Mapping::Valid),
+    ('\u{16B62}', '\u{16B62}', Mapping::Disallowed),
+    ('\u{16B63}', '\u{16B77}', Mapping::Valid),
+    ('\u{16B78}', '\u{16B7C}', Mapping::Disallowed),
+    ('\u{16B7D}', '\u{16B8F}', Mapping::Valid),
+    ('\u{16B90}', '\u{16E3F}', Mapping::Disallowed),
+    ('\u{16E40}', '\u{16E40}', Mapping::Mapped(&['\u{16E60}'])),
+    ('\u{16E41}', '\u{16E41}', Mapping::Mapped(&['\u{16E61}'])),
+    ('\u{16E42}', '\u{16E42}', Mapping::Mapped(&['\u{16E62}'])),
+    ('\u{16E43}', '\u{16E43}', Mapping::Mapped(&['\u{16E63}'])),
+    ('\u{16E44}', '\u{16E44}', Mapping::Mapped(&['\u{16E64}'])),
+    ('\u{16E45}', '\u{16E45}', Mapping::Mapped(&['\u{16E65}'])),
+    ('\u{16E46}', '\u{16E46}', Mapping::Mapped(&['\u{16E66}'])),
+    ('\u{16E47}', '\u{16E47}', Mapping::Mapped(&['\u{16E67}'])),
+    ('\u{16E48}', '\u{16E48}', Mapping::Mapped(&['\u{16E68}'])),
+    ('\u{16E49}', '\u{16E49}', Mapping::Mapped(&['\u{16E69}'])),
+    ('\u{16E4A}', '\u{16E4A}', Mapping::Mapped(&['\u{16E6A}'])),
+    ('\u{16E4B}', '\u{16E4B}', Mapping::Mapped(&['\u{16E6B}'])),
+    ('\u{16E4C}', '\u{16E4C}', Mapping::Mapped(&['\u{16E6C}'])),
+    ('\u{16E4D}', '\u{16E4D}', Mapping::Mapped(&['\u{16E6D}'])),
+    ('\u{16E4E}', '\u{16E4E}', Mapping::Mapped(&['\u{16E6E}'])),
+    ('\u{16E4F}', '\u{16E4F}', Mapping::Mapped(&['\u{16E6F}'])),
+    ('\u{16E50}', '\u{16E50}', Mapping::Mapped(&['\u{16E70}'])),
+    ('\u{16E51}', '\u{16E51}', Mapping::Mapped(&['\u{16E71}'])),
+    ('\u{16E52}', '\u{16E52}', Mapping::Mapped(&['\u{16E72}'])),
+    ('\u{16E53}', '\u{16E53}', Mapping::Mapped(&['\u{16E73}'])),
+    ('\u{16E54}', '\u{16E54}', Mapping::Mapped(&['\u{16E74}'])),
+    ('\u{16E55}', '\u{16E55}', Mapping::Mapped(&['\u{16E75}'])),
+    ('\u{16E56}', '\u{16E56}', Mapping::Mapped(&['\u{16E76}'])),
+    ('\u{16E57}', '\u{16E57}', Mapping::Mapped(&['\u{16E77}'])),
+    ('\u{16E58}', '\u{16E58}', Mapping::Mapped(&['\u{16E78}'])),
+    ('\u{16E59}', '\u{16E59}', Mapping::Mapped(&['\u{16E79}'])),
+    ('\u{16E5A}', '\u{16E5A}', Mapping::Mapped(&['\u{16E7A}'])),
+    ('\u{16E5B}', '\u{16E5B}', Mapping::Mapped(&['\u{16E7B}'])),
+    ('\u{16E5C}', '\u{16E5C}', Mapping::Mapped(&['\u{16E7C}'])),
+    ('\u{16E5D}', '\u{16E5D}', Mapping::Mapped(&['\u{16E7D}'])),
+    ('\u{16E5E}', '\u{16E5E}', Mapping::Mapped(&['\u{16E7E}'])),
+    ('\u{16E5F}', '\u{16E5F}', Mapping::Mapped(&['\u{16E7F}'])),
+    ('\u{16E60}', '\u{16E9A}', Mapping::Valid),
+    ('\u{16E9B}', '\u{16EFF}', Mapping::Disallowed),
+    ('\u{16F00}', '\u{16F4A}', Mapping::Valid),
+    ('\u{16F4B}', '\u{16F4E}', Mapping::Disallowed),
+    ('\u{16F4F}', '\u{16F87}', Mapping::Valid),
+    ('\u{16F88}', '\u{16F8E}', Mapping::Disallowed),
+    ('\u{16F8F}', '\u{16F9F}', Mapping::Valid),
+    ('\u{16FA0}', '\u{16FDF}', Mapping::Disallowed),
+    ('\u{16FE0}', '\u{16FE4}', Mapping::Valid),
+    ('\u{16FE5}', '\u{16FEF}', Mapping::Disallowed),
+    ('\u{16FF0}', '\u{16FF1}', Mapping::Valid),
+    ('\u{16FF2}', '\u{16FFF}', Mapping::Disallowed),
+    ('\u{17000}', '\u{187F7}', Mapping::Valid),
+    ('\u{187F8}', '\u{187FF}', Mapping::Disallowed),
+    ('\u{18800}', '\u{18CD5}', Mapping::Valid),
+    ('\u{18CD6}', '\u{18CFF}', Mapping::Disallowed),
+    ('\u{18D00}', '\u{18D08}', Mapping::Valid),
+    ('\u{18D09}', '\u{1AFFF}', Mapping::Disallowed),
+    ('\u{1B000}', '\u{1B11E}', Mapping::Valid),
+    ('\u{1B11F}', '\u{1B14F}', Mapping::Disallowed),
+    ('\u{1B150}', '\u{1B152}', Mapping::Valid),
+    ('\u{1B153}', '\u{1B163}', Mapping::Disallowed),
+    ('\u{1B164}', '\u{1B167}', Mapping::Valid),
+    ('\u{1B168}', '\u{1B16F}', Mapping::Disallowed),
+    ('\u{1B170}', '\u{1B2FB}', Mapping::Valid),
+    ('\u{1B2FC}', '\u{1BBFF}', Mapping::Disallowed),
+    ('\u{1BC00}', '\u{1BC6A}', Mapping::Valid),
+    ('\u{1BC6B}', '\u{1BC6F}', Mapping::Disallowed),
+    ('\u{1BC70}', '\u{1BC7C}', Mapping::Valid),
+    ('\u{1BC7D}', '\u{1BC7F}', Mapping::Disallowed),
+    ('\u{1BC80}', '\u{1BC88}', Mapping::Valid),
+    ('\u{1BC89}', '\u{1BC8F}', Mapping::Disallowed),
+    ('\u{1BC90}', '\u{1BC99}', Mapping::Valid),
+    ('\u{1BC9A}', '\u{1BC9B}', Mapping::Disallowed),
+    ('\u{1BC9C}', '\u{1BC9F}', Mapping::Valid),
+    ('\u{1BCA0}', '\u{1BCA3}', Mapping::Ignored),
+    ('\u{1BCA4}', '\u{1CFFF}', Mapping::Disallowed),
+    ('\u{1D000}', '\u{1D0F5}', Mapping::Valid),
+    ('\u{1D0F6}', '\u{1D0FF}', Mapping::Disallowed),
+    ('\u{1D100}', '\u{1D126}', Mapping::Valid),
+    ('\u{1D127}', '\u{1D128}', Mapping::Disallowed),
+    ('\u{1D129}', '\u{1D15D}', Mapping::Valid),
+    ('\u{1D15E}', '\u{1D15E}', Mapping::Mapped(&['\u{1D157}', '\u{1D165}'])),
+    ('\u{1D15F}', '\u{1D15F}', Mapping::Mapped(&['\u{1D158}', '\u{1D165}'])),
+    ('\u{1D160}', '\u{1D160}', Mapping::Mapped(&['\u{1D158}', '\u{1D165}', '\u{1D16E}'])),
+    ('\u{1D161}', '\u{1D161}', Mapping::Mapped(&['\u{1D158}', '\u{1D165}', '\u{1D16F}'])),
+    ('\u{1D162}', '\u{1D162}', Mapping::Mapped(&['\u{1D158}', '\u{1D165}', '\u{1D170}'])),
+    ('\u{1D163}', '\u{1D163}', Mapping::Mapped(&['\u{1D158}', '\u{1D165}', '\u{1D171}'])),
+    ('\u{1D164}', '\u{1D164}', Mapping::Mapped(&['\u{1D158}', '\u{1D165}', '\u{1D172}'])),
+    ('\u{1D165}', '\u{1D172}', Mapping::Valid),
+    ('\u{1D173}', '\u{1D17A}', Mapping::Disallowed),
+    ('\u{1D17B}', '\u{1D1BA}', Mapping::Valid),
+    ('\u{1D1BB}', '\u{1D1BB}', Mapping::Mapped(&['\u{1D1B9}', '\u{1D165}'])),
+    ('\u{1D1BC}', '\u{1D1BC}', Mapping::Mapped(&['\u{1D1BA}', '\u{1D165}'])),
+    ('\u{1D1BD}', '\u{1D1BD}', Mapping::Mapped(&['\u{1D1B9}', '\u{1D165}', '\u{1D16E}'])),
+    ('\u{1D1BE}', '\u{1D1BE}', Mapping::Mapped(&['\u{1D1BA}', '\u{1D165}', '\u{1D16E}'])),
+    ('\u{1D1BF}', '\u{1D1BF}', Mapping::Mapped(&['\u{1D1B9}', '\u{1D165}', '\u{1D16F}'])),
+    ('\u{1D1C0}', '\u{1D1C0}', Mapping::Mapped(&['\u{1D1BA}', '\u{1D165}', '\u{1D16F}'])),
+    ('\u{1D1C1}', '\u{1D1E8}', Mapping::Valid),
+    ('\u{1D1E9}', '\u{1D1FF}', Mapping::Disallowed),
+    ('\u{1D200}', '\u{1D245}', Mapping::Valid),
+    ('\u{1D246}', '\u{1D2DF}', Mapping::Disallowed),
+    ('\u{1D2E0}', '\u{1D2F3}', Mapping::Valid),
+    ('\u{1D2F4}', '\u{1D2FF}', Mapping::Disallowed),
+    ('\u{1D300}', '\u{1D356}', Mapping::Valid),
+    ('\u{1D357}', '\u{1D35F}', Mapping::Disallowed),
+    ('\u{1D360}', '\u{1D378}', Mapping::Valid),
+    ('\u{1D379}', '\u{1D3FF}', Mapping::Disallowed),
+    ('\u{1D400}', '\u{1D400}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D401}', '\u{1D401}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D402}', '\u{1D402}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D403}', '\u{1D403}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D404}', '\u{1D404}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D405}', '\u{1D405}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D406}', '\u{1D406}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D407}', '\u{1D407}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D408}', '\u{1D408}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D409}', '\u{1D409}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D40A}', '\u{1D40A}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D40B}', '\u{1D40B}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D40C}', '\u{1D40C}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D40D}', '\u{1D40D}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D40E}', '\u{1D40E}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D40F}', '\u{1D40F}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D410}', '\u{1D410}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D411}', '\u{1D411}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D412}', '\u{1D412}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D413}', '\u{1D413}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D414}', '\u{1D414}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D415}', '\u{1D415}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D416}', '\u{1D416}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D417}', '\u{1D417}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D418}', '\u{1D418}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D419}', '\u{1D419}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D41A}', '\u{1D41A}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D41B}', '\u{1D41B}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D41C}', '\u{1D41C}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D41D}', '\u{1D41D}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D41E}', '\u{1D41E}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D41F}', '\u{1D41F}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D420}', '\u{1D420}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D421}', '\u{1D421}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D422}', '\u{1D422}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D423}', '\u{1D423}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D424}', '\u{1D424}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D425}', '\u{1D425}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D426}', '\u{1D426}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D427}', '\u{1D427}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D428}', '\u{1D428}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D429}', '\u{1D429}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D42A}', '\u{1D42A}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D42B}', '\u{1D42B}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D42C}', '\u{1D42C}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D42D}', '\u{1D42D}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D42E}', '\u{1D42E}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D42F}', '\u{1D42F}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D430}', '\u{1D430}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D431}', '\u{1D431}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D432}', '\u{1D432}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D433}', '\u{1D433}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D434}', '\u{1D434}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D435}', '\u{1D435}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D436}', '\u{1D436}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D437}', '\u{1D437}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D438}', '\u{1D438}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D439}', '\u{1D439}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D43A}', '\u{1D43A}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D43B}', '\u{1D43B}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D43C}', '\u{1D43C}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D43D}', '\u{1D43D}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D43E}', '\u{1D43E}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D43F}', '\u{1D43F}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D440}', '\u{1D440}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D441}', '\u{1D441}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D442}', '\u{1D442}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D443}', '\u{1D443}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D444}', '\u{1D444}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D445}', '\u{1D445}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D446}', '\u{1D446}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D447}', '\u{1D447}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D448}', '\u{1D448}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D449}', '\u{1D449}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D44A}', '\u{1D44A}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D44B}', '\u{1D44B}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D44C}', '\u{1D44C}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D44D}', '\u{1D44D}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D44E}', '\u{1D44E}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D44F}', '\u{1D44F}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D450}', '\u{1D450}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D451}', '\u{1D451}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D452}', '\u{1D452}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D453}', '\u{1D453}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D454}', '\u{1D454}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D455}', '\u{1D455}', Mapping::Disallowed),
+    ('\u{1D456}', '\u{1D456}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D457}', '\u{1D457}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D458}', '\u{1D458}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D459}', '\u{1D459}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D45A}', '\u{1D45A}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D45B}', '\u{1D45B}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D45C}', '\u{1D45C}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D45D}', '\u{1D45D}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D45E}', '\u{1D45E}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D45F}', '\u{1D45F}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D460}', '\u{1D460}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D461}', '\u{1D461}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D462}', '\u{1D462}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D463}', '\u{1D463}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D464}', '\u{1D464}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D465}', '\u{1D465}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D466}', '\u{1D466}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D467}', '\u{1D467}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D468}', '\u{1D468}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D469}', '\u{1D469}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D46A}', '\u{1D46A}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D46B}', '\u{1D46B}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D46C}', '\u{1D46C}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D46D}', '\u{1D46D}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D46E}', '\u{1D46E}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D46F}', '\u{1D46F}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D470}', '\u{1D470}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D471}', '\u{1D471}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D472}', '\u{1D472}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D473}', '\u{1D473}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D474}', '\u{1D474}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D475}', '\u{1D475}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D476}', '\u{1D476}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D477}', '\u{1D477}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D478}', '\u{1D478}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D479}', '\u{1D479}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D47A}', '\u{1D47A}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D47B}', '\u{1D47B}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D47C}', '\u{1D47C}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D47D}', '\u{1D47D}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D47E}', '\u{1D47E}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D47F}', '\u{1D47F}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D480}', '\u{1D480}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D481}', '\u{1D481}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D482}', '\u{1D482}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D483}', '\u{1D483}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D484}', '\u{1D484}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D485}', '\u{1D485}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D486}', '\u{1D486}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D487}', '\u{1D487}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D488}', '\u{1D488}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D489}', '\u{1D489}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D48A}', '\u{1D48A}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D48B}', '\u{1D48B}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D48C}', '\u{1D48C}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D48D}', '\u{1D48D}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D48E}', '\u{1D48E}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D48F}', '\u{1D48F}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D490}', '\u{1D490}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D491}', '\u{1D491}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D492}', '\u{1D492}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D493}', '\u{1D493}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D494}', '\u{1D494}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D495}', '\u{1D495}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D496}', '\u{1D496}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D497}', '\u{1D497}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D498}', '\u{1D498}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D499}', '\u{1D499}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D49A}', '\u{1D49A}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D49B}', '\u{1D49B}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D49C}', '\u{1D49C}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D49D}', '\u{1D49D}', Mapping::Disallowed),
+    ('\u{1D49E}', '\u{1D49E}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D49F}', '\u{1D49F}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D4A0}', '\u{1D4A1}', Mapping::Disallowed),
+    ('\u{1D4A2}', '\u{1D4A2}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D4A3}', '\u{1D4A4}', Mapping::Disallowed),
+    ('\u{1D4A5}', '\u{1D4A5}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D4A6}', '\u{1D4A6}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D4A7}', '\u{1D4A8}', Mapping::Disallowed),
+    ('\u{1D4A9}', '\u{1D4A9}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D4AA}', '\u{1D4AA}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D4AB}', '\u{1D4AB}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D4AC}', '\u{1D4AC}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D4AD}', '\u{1D4AD}', Mapping::Disallowed),
+    ('\u{1D4AE}', '\u{1D4AE}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D4AF}', '\u{1D4AF}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D4B0}', '\u{1D4B0}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D4B1}', '\u{1D4B1}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D4B2}', '\u{1D4B2}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D4B3}', '\u{1D4B3}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D4B4}', '\u{1D4B4}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D4B5}', '\u{1D4B5}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D4B6}', '\u{1D4B6}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D4B7}', '\u{1D4B7}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D4B8}', '\u{1D4B8}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D4B9}', '\u{1D4B9}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D4BA}', '\u{1D4BA}', Mapping::Disallowed),
+    ('\u{1D4BB}', '\u{1D4BB}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D4BC}', '\u{1D4BC}', Mapping::Disallowed),
+    ('\u{1D4BD}', '\u{1D4BD}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D4BE}', '\u{1D4BE}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D4BF}', '\u{1D4BF}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D4C0}', '\u{1D4C0}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D4C1}', '\u{1D4C1}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D4C2}', '\u{1D4C2}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D4C3}', '\u{1D4C3}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D4C4}', '\u{1D4C4}', Mapping::Disallowed),
+    ('\u{1D4C5}', '\u{1D4C5}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D4C6}', '\u{1D4C6}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D4C7}', '\u{1D4C7}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D4C8}', '\u{1D4C8}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D4C9}', '\u{1D4C9}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D4CA}', '\u{1D4CA}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D4CB}', '\u{1D4CB}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D4CC}', '\u{1D4CC}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D4CD}', '\u{1D4CD}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D4CE}', '\u{1D4CE}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D4CF}', '\u{1D4CF}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D4D0}', '\u{1D4D0}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D4D1}', '\u{1D4D1}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D4D2}', '\u{1D4D2}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D4D3}', '\u{1D4D3}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D4D4}', '\u{1D4D4}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D4D5}', '\u{1D4D5}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D4D6}', '\u{1D4D6}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D4D7}', '\u{1D4D7}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D4D8}', '\u{1D4D8}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D4D9}', '\u{1D4D9}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D4DA}', '\u{1D4DA}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D4DB}', '\u{1D4DB}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D4DC}', '\u{1D4DC}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D4DD}', '\u{1D4DD}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D4DE}', '\u{1D4DE}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D4DF}', '\u{1D4DF}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D4E0}', '\u{1D4E0}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D4E1}', '\u{1D4E1}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D4E2}', '\u{1D4E2}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D4E3}', '\u{1D4E3}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D4E4}', '\u{1D4E4}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D4E5}', '\u{1D4E5}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D4E6}', '\u{1D4E6}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D4E7}', '\u{1D4E7}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D4E8}', '\u{1D4E8}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D4E9}', '\u{1D4E9}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D4EA}', '\u{1D4EA}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D4EB}', '\u{1D4EB}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D4EC}', '\u{1D4EC}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D4ED}', '\u{1D4ED}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D4EE}', '\u{1D4EE}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D4EF}', '\u{1D4EF}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D4F0}', '\u{1D4F0}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D4F1}', '\u{1D4F1}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D4F2}', '\u{1D4F2}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D4F3}', '\u{1D4F3}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D4F4}', '\u{1D4F4}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D4F5}', '\u{1D4F5}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D4F6}', '\u{1D4F6}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D4F7}', '\u{1D4F7}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D4F8}', '\u{1D4F8}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D4F9}', '\u{1D4F9}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D4FA}', '\u{1D4FA}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D4FB}', '\u{1D4FB}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D4FC}', '\u{1D4FC}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D4FD}', '\u{1D4FD}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D4FE}', '\u{1D4FE}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D4FF}', '\u{1D4FF}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D500}', '\u{1D500}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D501}', '\u{1D501}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D502}', '\u{1D502}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D503}', '\u{1D503}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D504}', '\u{1D504}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D505}', '\u{1D505}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D506}', '\u{1D506}', Mapping::Disallowed),
+    ('\u{1D507}', '\u{1D507}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D508}', '\u{1D508}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D509}', '\u{1D509}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D50A}', '\u{1D50A}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D50B}', '\u{1D50C}', Mapping::Disallowed),
+    ('\u{1D50D}', '\u{1D50D}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D50E}', '\u{1D50E}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D50F}', '\u{1D50F}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D510}', '\u{1D510}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D511}', '\u{1D511}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D512}', '\u{1D512}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D513}', '\u{1D513}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D514}', '\u{1D514}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D515}', '\u{1D515}', Mapping::Disallowed),
+    ('\u{1D516}', '\u{1D516}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D517}', '\u{1D517}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D518}', '\u{1D518}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D519}', '\u{1D519}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D51A}', '\u{1D51A}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D51B}', '\u{1D51B}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D51C}', '\u{1D51C}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D51D}', '\u{1D51D}', Mapping::Disallowed),
+    ('\u{1D51E}', '\u{1D51E}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D51F}', '\u{1D51F}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D520}', '\u{1D520}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D521}', '\u{1D521}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D522}', '\u{1D522}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D523}', '\u{1D523}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D524}', '\u{1D524}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D525}', '\u{1D525}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D526}', '\u{1D526}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D527}', '\u{1D527}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D528}', '\u{1D528}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D529}', '\u{1D529}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D52A}', '\u{1D52A}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D52B}', '\u{1D52B}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D52C}', '\u{1D52C}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D52D}', '\u{1D52D}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D52E}', '\u{1D52E}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D52F}', '\u{1D52F}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D530}', '\u{1D530}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D531}', '\u{1D531}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D532}', '\u{1D532}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D533}', '\u{1D533}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D534}', '\u{1D534}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D535}', '\u{1D535}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D536}', '\u{1D536}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D537}', '\u{1D537}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D538}', '\u{1D538}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D539}', '\u{1D539}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D53A}', '\u{1D53A}', Mapping::Disallowed),
+    ('\u{1D53B}', '\u{1D53B}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D53C}', '\u{1D53C}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D53D}', '\u{1D53D}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D53E}', '\u{1D53E}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D53F}', '\u{1D53F}', Mapping::Disallowed),
+    ('\u{1D540}', '\u{1D540}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D541}', '\u{1D541}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D542}', '\u{1D542}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D543}', '\u{1D543}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D544}', '\u{1D544}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D545}', '\u{1D545}', Mapping::Disallowed),
+    ('\u{1D546}', '\u{1D546}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D547}', '\u{1D549}', Mapping::Disallowed),
+    ('\u{1D54A}', '\u{1D54A}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D54B}', '\u{1D54B}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D54C}', '\u{1D54C}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D54D}', '\u{1D54D}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D54E}', '\u{1D54E}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D54F}', '\u{1D54F}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D550}', '\u{1D550}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D551}', '\u{1D551}', Mapping::Disallowed),
+    ('\u{1D552}', '\u{1D552}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D553}', '\u{1D553}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D554}', '\u{1D554}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D555}', '\u{1D555}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D556}', '\u{1D556}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D557}', '\u{1D557}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D558}', '\u{1D558}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D559}', '\u{1D559}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D55A}', '\u{1D55A}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D55B}', '\u{1D55B}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D55C}', '\u{1D55C}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D55D}', '\u{1D55D}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D55E}', '\u{1D55E}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D55F}', '\u{1D55F}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D560}', '\u{1D560}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D561}', '\u{1D561}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D562}', '\u{1D562}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D563}', '\u{1D563}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D564}', '\u{1D564}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D565}', '\u{1D565}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D566}', '\u{1D566}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D567}', '\u{1D567}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D568}', '\u{1D568}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D569}', '\u{1D569}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D56A}', '\u{1D56A}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D56B}', '\u{1D56B}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D56C}', '\u{1D56C}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D56D}', '\u{1D56D}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D56E}', '\u{1D56E}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D56F}', '\u{1D56F}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D570}', '\u{1D570}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D571}', '\u{1D571}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D572}', '\u{1D572}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D573}', '\u{1D573}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D574}', '\u{1D574}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D575}', '\u{1D575}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D576}', '\u{1D576}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D577}', '\u{1D577}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D578}', '\u{1D578}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D579}', '\u{1D579}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D57A}', '\u{1D57A}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D57B}', '\u{1D57B}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D57C}', '\u{1D57C}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D57D}', '\u{1D57D}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D57E}', '\u{1D57E}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D57F}', '\u{1D57F}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D580}', '\u{1D580}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D581}', '\u{1D581}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D582}', '\u{1D582}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D583}', '\u{1D583}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D584}', '\u{1D584}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D585}', '\u{1D585}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D586}', '\u{1D586}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D587}', '\u{1D587}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D588}', '\u{1D588}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D589}', '\u{1D589}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D58A}', '\u{1D58A}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D58B}', '\u{1D58B}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D58C}', '\u{1D58C}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D58D}', '\u{1D58D}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D58E}', '\u{1D58E}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D58F}', '\u{1D58F}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D590}', '\u{1D590}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D591}', '\u{1D591}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D592}', '\u{1D592}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D593}', '\u{1D593}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D594}', '\u{1D594}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D595}', '\u{1D595}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D596}', '\u{1D596}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D597}', '\u{1D597}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D598}', '\u{1D598}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D599}', '\u{1D599}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D59A}', '\u{1D59A}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D59B}', '\u{1D59B}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D59C}', '\u{1D59C}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D59D}', '\u{1D59D}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D59E}', '\u{1D59E}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D59F}', '\u{1D59F}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D5A0}', '\u{1D5A0}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D5A1}', '\u{1D5A1}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D5A2}', '\u{1D5A2}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D5A3}', '\u{1D5A3}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D5A4}', '\u{1D5A4}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D5A5}', '\u{1D5A5}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D5A6}', '\u{1D5A6}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D5A7}', '\u{1D5A7}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D5A8}', '\u{1D5A8}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D5A9}', '\u{1D5A9}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D5AA}', '\u{1D5AA}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D5AB}', '\u{1D5AB}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D5AC}', '\u{1D5AC}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D5AD}', '\u{1D5AD}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D5AE}', '\u{1D5AE}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D5AF}', '\u{1D5AF}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D5B0}', '\u{1D5B0}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D5B1}', '\u{1D5B1}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D5B2}', '\u{1D5B2}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D5B3}', '\u{1D5B3}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D5B4}', '\u{1D5B4}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D5B5}', '\u{1D5B5}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D5B6}', '\u{1D5B6}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D5B7}', '\u{1D5B7}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D5B8}', '\u{1D5B8}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D5B9}', '\u{1D5B9}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D5BA}', '\u{1D5BA}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D5BB}', '\u{1D5BB}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D5BC}', '\u{1D5BC}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D5BD}', '\u{1D5BD}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D5BE}', '\u{1D5BE}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D5BF}', '\u{1D5BF}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D5C0}', '\u{1D5C0}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D5C1}', '\u{1D5C1}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D5C2}', '\u{1D5C2}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D5C3}', '\u{1D5C3}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D5C4}', '\u{1D5C4}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D5C5}', '\u{1D5C5}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D5C6}', '\u{1D5C6}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D5C7}', '\u{1D5C7}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D5C8}', '\u{1D5C8}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D5C9}', '\u{1D5C9}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D5CA}', '\u{1D5CA}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D5CB}', '\u{1D5CB}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D5CC}', '\u{1D5CC}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D5CD}', '\u{1D5CD}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D5CE}', '\u{1D5CE}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D5CF}', '\u{1D5CF}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D5D0}', '\u{1D5D0}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D5D1}', '\u{1D5D1}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D5D2}', '\u{1D5D2}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D5D3}', '\u{1D5D3}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D5D4}', '\u{1D5D4}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D5D5}', '\u{1D5D5}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D5D6}', '\u{1D5D6}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D5D7}', '\u{1D5D7}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D5D8}', '\u{1D5D8}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D5D9}', '\u{1D5D9}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D5DA}', '\u{1D5DA}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D5DB}', '\u{1D5DB}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D5DC}', '\u{1D5DC}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D5DD}', '\u{1D5DD}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D5DE}', '\u{1D5DE}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D5DF}', '\u{1D5DF}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D5E0}', '\u{1D5E0}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D5E1}', '\u{1D5E1}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D5E2}', '\u{1D5E2}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D5E3}', '\u{1D5E3}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D5E4}', '\u{1D5E4}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D5E5}', '\u{1D5E5}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D5E6}', '\u{1D5E6}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D5E7}', '\u{1D5E7}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D5E8}', '\u{1D5E8}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D5E9}', '\u{1D5E9}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D5EA}', '\u{1D5EA}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D5EB}', '\u{1D5EB}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D5EC}', '\u{1D5EC}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D5ED}', '\u{1D5ED}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D5EE}', '\u{1D5EE}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D5EF}', '\u{1D5EF}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D5F0}', '\u{1D5F0}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D5F1}', '\u{1D5F1}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D5F2}', '\u{1D5F2}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D5F3}', '\u{1D5F3}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D5F4}', '\u{1D5F4}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D5F5}', '\u{1D5F5}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D5F6}', '\u{1D5F6}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D5F7}', '\u{1D5F7}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D5F8}', '\u{1D5F8}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D5F9}', '\u{1D5F9}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D5FA}', '\u{1D5FA}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D5FB}', '\u{1D5FB}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D5FC}', '\u{1D5FC}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D5FD}', '\u{1D5FD}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D5FE}', '\u{1D5FE}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D5FF}', '\u{1D5FF}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D600}', '\u{1D600}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D601}', '\u{1D601}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D602}', '\u{1D602}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D603}', '\u{1D603}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D604}', '\u{1D604}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D605}', '\u{1D605}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D606}', '\u{1D606}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D607}', '\u{1D607}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D608}', '\u{1D608}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D609}', '\u{1D609}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D60A}', '\u{1D60A}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D60B}', '\u{1D60B}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D60C}', '\u{1D60C}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D60D}', '\u{1D60D}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D60E}', '\u{1D60E}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D60F}', '\u{1D60F}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D610}', '\u{1D610}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D611}', '\u{1D611}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D612}', '\u{1D612}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D613}', '\u{1D613}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D614}', '\u{1D614}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D615}', '\u{1D615}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D616}', '\u{1D616}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D617}', '\u{1D617}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D618}', '\u{1D618}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D619}', '\u{1D619}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D61A}', '\u{1D61A}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D61B}', '\u{1D61B}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D61C}', '\u{1D61C}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D61D}', '\u{1D61D}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D61E}', '\u{1D61E}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D61F}', '\u{1D61F}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D620}', '\u{1D620}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D621}', '\u{1D621}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D622}', '\u{1D622}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D623}', '\u{1D623}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D624}', '\u{1D624}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D625}', '\u{1D625}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D626}', '\u{1D626}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D627}', '\u{1D627}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D628}', '\u{1D628}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D629}', '\u{1D629}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D62A}', '\u{1D62A}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D62B}', '\u{1D62B}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D62C}', '\u{1D62C}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D62D}', '\u{1D62D}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D62E}', '\u{1D62E}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D62F}', '\u{1D62F}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D630}', '\u{1D630}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D631}', '\u{1D631}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D632}', '\u{1D632}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D633}', '\u{1D633}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D634}', '\u{1D634}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D635}', '\u{1D635}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D636}', '\u{1D636}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D637}', '\u{1D637}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D638}', '\u{1D638}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D639}', '\u{1D639}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D63A}', '\u{1D63A}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D63B}', '\u{1D63B}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D63C}', '\u{1D63C}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D63D}', '\u{1D63D}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D63E}', '\u{1D63E}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D63F}', '\u{1D63F}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D640}', '\u{1D640}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D641}', '\u{1D641}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D642}', '\u{1D642}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D643}', '\u{1D643}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D644}', '\u{1D644}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D645}', '\u{1D645}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D646}', '\u{1D646}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D647}', '\u{1D647}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D648}', '\u{1D648}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D649}', '\u{1D649}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D64A}', '\u{1D64A}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D64B}', '\u{1D64B}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D64C}', '\u{1D64C}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D64D}', '\u{1D64D}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D64E}', '\u{1D64E}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D64F}', '\u{1D64F}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D650}', '\u{1D650}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D651}', '\u{1D651}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D652}', '\u{1D652}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D653}', '\u{1D653}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D654}', '\u{1D654}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D655}', '\u{1D655}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D656}', '\u{1D656}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D657}', '\u{1D657}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D658}', '\u{1D658}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D659}', '\u{1D659}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D65A}', '\u{1D65A}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D65B}', '\u{1D65B}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D65C}', '\u{1D65C}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D65D}', '\u{1D65D}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D65E}', '\u{1D65E}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D65F}', '\u{1D65F}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D660}', '\u{1D660}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D661}', '\u{1D661}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D662}', '\u{1D662}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D663}', '\u{1D663}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D664}', '\u{1D664}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D665}', '\u{1D665}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D666}', '\u{1D666}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D667}', '\u{1D667}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D668}', '\u{1D668}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D669}', '\u{1D669}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D66A}', '\u{1D66A}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D66B}', '\u{1D66B}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D66C}', '\u{1D66C}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D66D}', '\u{1D66D}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D66E}', '\u{1D66E}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D66F}', '\u{1D66F}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D670}', '\u{1D670}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D671}', '\u{1D671}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D672}', '\u{1D672}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D673}', '\u{1D673}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D674}', '\u{1D674}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D675}', '\u{1D675}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D676}', '\u{1D676}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D677}', '\u{1D677}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D678}', '\u{1D678}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D679}', '\u{1D679}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D67A}', '\u{1D67A}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D67B}', '\u{1D67B}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D67C}', '\u{1D67C}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D67D}', '\u{1D67D}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D67E}', '\u{1D67E}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D67F}', '\u{1D67F}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D680}', '\u{1D680}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D681}', '\u{1D681}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D682}', '\u{1D682}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D683}', '\u{1D683}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D684}', '\u{1D684}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D685}', '\u{1D685}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D686}', '\u{1D686}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D687}', '\u{1D687}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D688}', '\u{1D688}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D689}', '\u{1D689}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D68A}', '\u{1D68A}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1D68B}', '\u{1D68B}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1D68C}', '\u{1D68C}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1D68D}', '\u{1D68D}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1D68E}', '\u{1D68E}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1D68F}', '\u{1D68F}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1D690}', '\u{1D690}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1D691}', '\u{1D691}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1D692}', '\u{1D692}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1D693}', '\u{1D693}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1D694}', '\u{1D694}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1D695}', '\u{1D695}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1D696}', '\u{1D696}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1D697}', '\u{1D697}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1D698}', '\u{1D698}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1D699}', '\u{1D699}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1D69A}', '\u{1D69A}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1D69B}', '\u{1D69B}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1D69C}', '\u{1D69C}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1D69D}', '\u{1D69D}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1D69E}', '\u{1D69E}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1D69F}', '\u{1D69F}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1D6A0}', '\u{1D6A0}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1D6A1}', '\u{1D6A1}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1D6A2}', '\u{1D6A2}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1D6A3}', '\u{1D6A3}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1D6A4}', '\u{1D6A4}', Mapping::Mapped(&['\u{131}'])),
+    ('\u{1D6A5}', '\u{1D6A5}', Mapping::Mapped(&['\u{237}'])),
+    ('\u{1D6A6}', '\u{1D6A7}', Mapping::Disallowed),
+    ('\u{1D6A8}', '\u{1D6A8}', Mapping::Mapped(&['\u{3B1}'])),
+    ('\u{1D6A9}', '\u{1D6A9}', Mapping::Mapped(&['\u{3B2}'])),
+    ('\u{1D6AA}', '\u{1D6AA}', Mapping::Mapped(&['\u{3B3}'])),
+    ('\u{1D6AB}', '\u{1D6AB}', Mapping::Mapped(&['\u{3B4}'])),
+    ('\u{1D6AC}', '\u{1D6AC}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D6AD}', '\u{1D6AD}', Mapping::Mapped(&['\u{3B6}'])),
+    ('\u{1D6AE}', '\u{1D6AE}', Mapping::Mapped(&['\u{3B7}'])),
+    ('\u{1D6AF}', '\u{1D6AF}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D6B0}', '\u{1D6B0}', Mapping::Mapped(&['\u{3B9}'])),
+    ('\u{1D6B1}', '\u{1D6B1}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D6B2}', '\u{1D6B2}', Mapping::Mapped(&['\u{3BB}'])),
+    ('\u{1D6B3}', '\u{1D6B3}', Mapping::Mapped(&['\u{3BC}'])),
+    ('\u{1D6B4}', '\u{1D6B4}', Mapping::Mapped(&['\u{3BD}'])),
+    ('\u{1D6B5}', '\u{1D6B5}', Mapping::Mapped(&['\u{3BE}'])),
+    ('\u{1D6B6}', '\u{1D6B6}', Mapping::Mapped(&['\u{3BF}'])),
+    ('\u{1D6B7}', '\u{1D6B7}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D6B8}', '\u{1D6B8}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D6B9}', '\u{1D6B9}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D6BA}', '\u{1D6BA}', Mapping::Mapped(&['\u{3C3}'])),
+    ('\u{1D6BB}', '\u{1D6BB}', Mapping::Mapped(&['\u{3C4}'])),
+    ('\u{1D6BC}', '\u{1D6BC}', Mapping::Mapped(&['\u{3C5}'])),
+    ('\u{1D6BD}', '\u{1D6BD}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D6BE}', '\u{1D6BE}', Mapping::Mapped(&['\u{3C7}'])),
+    ('\u{1D6BF}', '\u{1D6BF}', Mapping::Mapped(&['\u{3C8}'])),
+    ('\u{1D6C0}', '\u{1D6C0}', Mapping::Mapped(&['\u{3C9}'])),
+    ('\u{1D6C1}', '\u{1D6C1}', Mapping::Mapped(&['\u{2207}'])),
+    ('\u{1D6C2}', '\u{1D6C2}', Mapping::Mapped(&['\u{3B1}'])),
+    ('\u{1D6C3}', '\u{1D6C3}', Mapping::Mapped(&['\u{3B2}'])),
+    ('\u{1D6C4}', '\u{1D6C4}', Mapping::Mapped(&['\u{3B3}'])),
+    ('\u{1D6C5}', '\u{1D6C5}', Mapping::Mapped(&['\u{3B4}'])),
+    ('\u{1D6C6}', '\u{1D6C6}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D6C7}', '\u{1D6C7}', Mapping::Mapped(&['\u{3B6}'])),
+    ('\u{1D6C8}', '\u{1D6C8}', Mapping::Mapped(&['\u{3B7}'])),
+    ('\u{1D6C9}', '\u{1D6C9}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D6CA}', '\u{1D6CA}', Mapping::Mapped(&['\u{3B9}'])),
+    ('\u{1D6CB}', '\u{1D6CB}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D6CC}', '\u{1D6CC}', Mapping::Mapped(&['\u{3BB}'])),
+    ('\u{1D6CD}', '\u{1D6CD}', Mapping::Mapped(&['\u{3BC}'])),
+    ('\u{1D6CE}', '\u{1D6CE}', Mapping::Mapped(&['\u{3BD}'])),
+    ('\u{1D6CF}', '\u{1D6CF}', Mapping::Mapped(&['\u{3BE}'])),
+    ('\u{1D6D0}', '\u{1D6D0}', Mapping::Mapped(&['\u{3BF}'])),
+    ('\u{1D6D1}', '\u{1D6D1}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D6D2}', '\u{1D6D2}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D6D3}', '\u{1D6D4}', Mapping::Mapped(&['\u{3C3}'])),
+    ('\u{1D6D5}', '\u{1D6D5}', Mapping::Mapped(&['\u{3C4}'])),
+    ('\u{1D6D6}', '\u{1D6D6}', Mapping::Mapped(&['\u{3C5}'])),
+    ('\u{1D6D7}', '\u{1D6D7}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D6D8}', '\u{1D6D8}', Mapping::Mapped(&['\u{3C7}'])),
+    ('\u{1D6D9}', '\u{1D6D9}', Mapping::Mapped(&['\u{3C8}'])),
+    ('\u{1D6DA}', '\u{1D6DA}', Mapping::Mapped(&['\u{3C9}'])),
+    ('\u{1D6DB}', '\u{1D6DB}', Mapping::Mapped(&['\u{2202}'])),
+    ('\u{1D6DC}', '\u{1D6DC}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D6DD}', '\u{1D6DD}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D6DE}', '\u{1D6DE}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D6DF}', '\u{1D6DF}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D6E0}', '\u{1D6E0}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D6E1}', '\u{1D6E1}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D6E2}', '\u{1D6E2}', Mapping::Mapped(&['\u{3B1}'])),
+    ('\u{1D6E3}', '\u{1D6E3}', Mapping::Mapped(&['\u{3B2}'])),
+    ('\u{1D6E4}', '\u{1D6E4}', Mapping::Mapped(&['\u{3B3}'])),
+    ('\u{1D6E5}', '\u{1D6E5}', Mapping::Mapped(&['\u{3B4}'])),
+    ('\u{1D6E6}', '\u{1D6E6}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D6E7}', '\u{1D6E7}', Mapping::Mapped(&['\u{3B6}'])),
+    ('\u{1D6E8}', '\u{1D6E8}', Mapping::Mapped(&['\u{3B7}'])),
+    ('\u{1D6E9}', '\u{1D6E9}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D6EA}', '\u{1D6EA}', Mapping::Mapped(&['\u{3B9}'])),
+    ('\u{1D6EB}', '\u{1D6EB}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D6EC}', '\u{1D6EC}', Mapping::Mapped(&['\u{3BB}'])),
+    ('\u{1D6ED}', '\u{1D6ED}', Mapping::Mapped(&['\u{3BC}'])),
+    ('\u{1D6EE}', '\u{1D6EE}', Mapping::Mapped(&['\u{3BD}'])),
+    ('\u{1D6EF}', '\u{1D6EF}', Mapping::Mapped(&['\u{3BE}'])),
+    ('\u{1D6F0}', '\u{1D6F0}', Mapping::Mapped(&['\u{3BF}'])),
+    ('\u{1D6F1}', '\u{1D6F1}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D6F2}', '\u{1D6F2}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D6F3}', '\u{1D6F3}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D6F4}', '\u{1D6F4}', Mapping::Mapped(&['\u{3C3}'])),
+    ('\u{1D6F5}', '\u{1D6F5}', Mapping::Mapped(&['\u{3C4}'])),
+    ('\u{1D6F6}', '\u{1D6F6}', Mapping::Mapped(&['\u{3C5}'])),
+    ('\u{1D6F7}', '\u{1D6F7}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D6F8}', '\u{1D6F8}', Mapping::Mapped(&['\u{3C7}'])),
+    ('\u{1D6F9}', '\u{1D6F9}', Mapping::Mapped(&['\u{3C8}'])),
+    ('\u{1D6FA}', '\u{1D6FA}', Mapping::Mapped(&['\u{3C9}'])),
+    ('\u{1D6FB}', '\u{1D6FB}', Mapping::Mapped(&['\u{2207}'])),
+    ('\u{1D6FC}', '\u{1D6FC}', Mapping::Mapped(&['\u{3B1}'])),
+    ('\u{1D6FD}', '\u{1D6FD}', Mapping::Mapped(&['\u{3B2}'])),
+    ('\u{1D6FE}', '\u{1D6FE}', Mapping::Mapped(&['\u{3B3}'])),
+    ('\u{1D6FF}', '\u{1D6FF}', Mapping::Mapped(&['\u{3B4}'])),
+    ('\u{1D700}', '\u{1D700}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D701}', '\u{1D701}', Mapping::Mapped(&['\u{3B6}'])),
+    ('\u{1D702}', '\u{1D702}', Mapping::Mapped(&['\u{3B7}'])),
+    ('\u{1D703}', '\u{1D703}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D704}', '\u{1D704}', Mapping::Mapped(&['\u{3B9}'])),
+    ('\u{1D705}', '\u{1D705}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D706}', '\u{1D706}', Mapping::Mapped(&['\u{3BB}'])),
+    ('\u{1D707}', '\u{1D707}', Mapping::Mapped(&['\u{3BC}'])),
+    ('\u{1D708}', '\u{1D708}', Mapping::Mapped(&['\u{3BD}'])),
+    ('\u{1D709}', '\u{1D709}', Mapping::Mapped(&['\u{3BE}'])),
+    ('\u{1D70A}', '\u{1D70A}', Mapping::Mapped(&['\u{3BF}'])),
+    ('\u{1D70B}', '\u{1D70B}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D70C}', '\u{1D70C}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D70D}', '\u{1D70E}', Mapping::Mapped(&['\u{3C3}'])),
+    ('\u{1D70F}', '\u{1D70F}', Mapping::Mapped(&['\u{3C4}'])),
+    ('\u{1D710}', '\u{1D710}', Mapping::Mapped(&['\u{3C5}'])),
+    ('\u{1D711}', '\u{1D711}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D712}', '\u{1D712}', Mapping::Mapped(&['\u{3C7}'])),
+    ('\u{1D713}', '\u{1D713}', Mapping::Mapped(&['\u{3C8}'])),
+    ('\u{1D714}', '\u{1D714}', Mapping::Mapped(&['\u{3C9}'])),
+    ('\u{1D715}', '\u{1D715}', Mapping::Mapped(&['\u{2202}'])),
+    ('\u{1D716}', '\u{1D716}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D717}', '\u{1D717}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D718}', '\u{1D718}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D719}', '\u{1D719}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D71A}', '\u{1D71A}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D71B}', '\u{1D71B}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D71C}', '\u{1D71C}', Mapping::Mapped(&['\u{3B1}'])),
+    ('\u{1D71D}', '\u{1D71D}', Mapping::Mapped(&['\u{3B2}'])),
+    ('\u{1D71E}', '\u{1D71E}', Mapping::Mapped(&['\u{3B3}'])),
+    ('\u{1D71F}', '\u{1D71F}', Mapping::Mapped(&['\u{3B4}'])),
+    ('\u{1D720}', '\u{1D720}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D721}', '\u{1D721}', Mapping::Mapped(&['\u{3B6}'])),
+    ('\u{1D722}', '\u{1D722}', Mapping::Mapped(&['\u{3B7}'])),
+    ('\u{1D723}', '\u{1D723}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D724}', '\u{1D724}', Mapping::Mapped(&['\u{3B9}'])),
+    ('\u{1D725}', '\u{1D725}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D726}', '\u{1D726}', Mapping::Mapped(&['\u{3BB}'])),
+    ('\u{1D727}', '\u{1D727}', Mapping::Mapped(&['\u{3BC}'])),
+    ('\u{1D728}', '\u{1D728}', Mapping::Mapped(&['\u{3BD}'])),
+    ('\u{1D729}', '\u{1D729}', Mapping::Mapped(&['\u{3BE}'])),
+    ('\u{1D72A}', '\u{1D72A}', Mapping::Mapped(&['\u{3BF}'])),
+    ('\u{1D72B}', '\u{1D72B}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D72C}', '\u{1D72C}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D72D}', '\u{1D72D}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D72E}', '\u{1D72E}', Mapping::Mapped(&['\u{3C3}'])),
+    ('\u{1D72F}', '\u{1D72F}', Mapping::Mapped(&['\u{3C4}'])),
+    ('\u{1D730}', '\u{1D730}', Mapping::Mapped(&['\u{3C5}'])),
+    ('\u{1D731}', '\u{1D731}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D732}', '\u{1D732}', Mapping::Mapped(&['\u{3C7}'])),
+    ('\u{1D733}', '\u{1D733}', Mapping::Mapped(&['\u{3C8}'])),
+    ('\u{1D734}', '\u{1D734}', Mapping::Mapped(&['\u{3C9}'])),
+    ('\u{1D735}', '\u{1D735}', Mapping::Mapped(&['\u{2207}'])),
+    ('\u{1D736}', '\u{1D736}', Mapping::Mapped(&['\u{3B1}'])),
+    ('\u{1D737}', '\u{1D737}', Mapping::Mapped(&['\u{3B2}'])),
+    ('\u{1D738}', '\u{1D738}', Mapping::Mapped(&['\u{3B3}'])),
+    ('\u{1D739}', '\u{1D739}', Mapping::Mapped(&['\u{3B4}'])),
+    ('\u{1D73A}', '\u{1D73A}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D73B}', '\u{1D73B}', Mapping::Mapped(&['\u{3B6}'])),
+    ('\u{1D73C}', '\u{1D73C}', Mapping::Mapped(&['\u{3B7}'])),
+    ('\u{1D73D}', '\u{1D73D}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D73E}', '\u{1D73E}', Mapping::Mapped(&['\u{3B9}'])),
+    ('\u{1D73F}', '\u{1D73F}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D740}', '\u{1D740}', Mapping::Mapped(&['\u{3BB}'])),
+    ('\u{1D741}', '\u{1D741}', Mapping::Mapped(&['\u{3BC}'])),
+    ('\u{1D742}', '\u{1D742}', Mapping::Mapped(&['\u{3BD}'])),
+    ('\u{1D743}', '\u{1D743}', Mapping::Mapped(&['\u{3BE}'])),
+    ('\u{1D744}', '\u{1D744}', Mapping::Mapped(&['\u{3BF}'])),
+    ('\u{1D745}', '\u{1D745}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D746}', '\u{1D746}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D747}', '\u{1D748}', Mapping::Mapped(&['\u{3C3}'])),
+    ('\u{1D749}', '\u{1D749}', Mapping::Mapped(&['\u{3C4}'])),
+    ('\u{1D74A}', '\u{1D74A}', Mapping::Mapped(&['\u{3C5}'])),
+    ('\u{1D74B}', '\u{1D74B}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D74C}', '\u{1D74C}', Mapping::Mapped(&['\u{3C7}'])),
+    ('\u{1D74D}', '\u{1D74D}', Mapping::Mapped(&['\u{3C8}'])),
+    ('\u{1D74E}', '\u{1D74E}', Mapping::Mapped(&['\u{3C9}'])),
+    ('\u{1D74F}', '\u{1D74F}', Mapping::Mapped(&['\u{2202}'])),
+    ('\u{1D750}', '\u{1D750}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D751}', '\u{1D751}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D752}', '\u{1D752}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D753}', '\u{1D753}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D754}', '\u{1D754}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D755}', '\u{1D755}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D756}', '\u{1D756}', Mapping::Mapped(&['\u{3B1}'])),
+    ('\u{1D757}', '\u{1D757}', Mapping::Mapped(&['\u{3B2}'])),
+    ('\u{1D758}', '\u{1D758}', Mapping::Mapped(&['\u{3B3}'])),
+    ('\u{1D759}', '\u{1D759}', Mapping::Mapped(&['\u{3B4}'])),
+    ('\u{1D75A}', '\u{1D75A}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D75B}', '\u{1D75B}', Mapping::Mapped(&['\u{3B6}'])),
+    ('\u{1D75C}', '\u{1D75C}', Mapping::Mapped(&['\u{3B7}'])),
+    ('\u{1D75D}', '\u{1D75D}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D75E}', '\u{1D75E}', Mapping::Mapped(&['\u{3B9}'])),
+    ('\u{1D75F}', '\u{1D75F}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D760}', '\u{1D760}', Mapping::Mapped(&['\u{3BB}'])),
+    ('\u{1D761}', '\u{1D761}', Mapping::Mapped(&['\u{3BC}'])),
+    ('\u{1D762}', '\u{1D762}', Mapping::Mapped(&['\u{3BD}'])),
+    ('\u{1D763}', '\u{1D763}', Mapping::Mapped(&['\u{3BE}'])),
+    ('\u{1D764}', '\u{1D764}', Mapping::Mapped(&['\u{3BF}'])),
+    ('\u{1D765}', '\u{1D765}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D766}', '\u{1D766}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D767}', '\u{1D767}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D768}', '\u{1D768}', Mapping::Mapped(&['\u{3C3}'])),
+    ('\u{1D769}', '\u{1D769}', Mapping::Mapped(&['\u{3C4}'])),
+    ('\u{1D76A}', '\u{1D76A}', Mapping::Mapped(&['\u{3C5}'])),
+    ('\u{1D76B}', '\u{1D76B}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D76C}', '\u{1D76C}', Mapping::Mapped(&['\u{3C7}'])),
+    ('\u{1D76D}', '\u{1D76D}', Mapping::Mapped(&['\u{3C8}'])),
+    ('\u{1D76E}', '\u{1D76E}', Mapping::Mapped(&['\u{3C9}'])),
+    ('\u{1D76F}', '\u{1D76F}', Mapping::Mapped(&['\u{2207}'])),
+    ('\u{1D770}', '\u{1D770}', Mapping::Mapped(&['\u{3B1}'])),
+    ('\u{1D771}', '\u{1D771}', Mapping::Mapped(&['\u{3B2}'])),
+    ('\u{1D772}', '\u{1D772}', Mapping::Mapped(&['\u{3B3}'])),
+    ('\u{1D773}', '\u{1D773}', Mapping::Mapped(&['\u{3B4}'])),
+    ('\u{1D774}', '\u{1D774}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D775}', '\u{1D775}', Mapping::Mapped(&['\u{3B6}'])),
+    ('\u{1D776}', '\u{1D776}', Mapping::Mapped(&['\u{3B7}'])),
+    ('\u{1D777}', '\u{1D777}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D778}', '\u{1D778}', Mapping::Mapped(&['\u{3B9}'])),
+    ('\u{1D779}', '\u{1D779}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D77A}', '\u{1D77A}', Mapping::Mapped(&['\u{3BB}'])),
+    ('\u{1D77B}', '\u{1D77B}', Mapping::Mapped(&['\u{3BC}'])),
+    ('\u{1D77C}', '\u{1D77C}', Mapping::Mapped(&['\u{3BD}'])),
+    ('\u{1D77D}', '\u{1D77D}', Mapping::Mapped(&['\u{3BE}'])),
+    ('\u{1D77E}', '\u{1D77E}', Mapping::Mapped(&['\u{3BF}'])),
+    ('\u{1D77F}', '\u{1D77F}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D780}', '\u{1D780}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D781}', '\u{1D782}', Mapping::Mapped(&['\u{3C3}'])),
+    ('\u{1D783}', '\u{1D783}', Mapping::Mapped(&['\u{3C4}'])),
+    ('\u{1D784}', '\u{1D784}', Mapping::Mapped(&['\u{3C5}'])),
+    ('\u{1D785}', '\u{1D785}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D786}', '\u{1D786}', Mapping::Mapped(&['\u{3C7}'])),
+    ('\u{1D787}', '\u{1D787}', Mapping::Mapped(&['\u{3C8}'])),
+    ('\u{1D788}', '\u{1D788}', Mapping::Mapped(&['\u{3C9}'])),
+    ('\u{1D789}', '\u{1D789}', Mapping::Mapped(&['\u{2202}'])),
+    ('\u{1D78A}', '\u{1D78A}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D78B}', '\u{1D78B}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D78C}', '\u{1D78C}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D78D}', '\u{1D78D}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D78E}', '\u{1D78E}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D78F}', '\u{1D78F}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D790}', '\u{1D790}', Mapping::Mapped(&['\u{3B1}'])),
+    ('\u{1D791}', '\u{1D791}', Mapping::Mapped(&['\u{3B2}'])),
+    ('\u{1D792}', '\u{1D792}', Mapping::Mapped(&['\u{3B3}'])),
+    ('\u{1D793}', '\u{1D793}', Mapping::Mapped(&['\u{3B4}'])),
+    ('\u{1D794}', '\u{1D794}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D795}', '\u{1D795}', Mapping::Mapped(&['\u{3B6}'])),
+    ('\u{1D796}', '\u{1D796}', Mapping::Mapped(&['\u{3B7}'])),
+    ('\u{1D797}', '\u{1D797}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D798}', '\u{1D798}', Mapping::Mapped(&['\u{3B9}'])),
+    ('\u{1D799}', '\u{1D799}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D79A}', '\u{1D79A}', Mapping::Mapped(&['\u{3BB}'])),
+    ('\u{1D79B}', '\u{1D79B}', Mapping::Mapped(&['\u{3BC}'])),
+    ('\u{1D79C}', '\u{1D79C}', Mapping::Mapped(&['\u{3BD}'])),
+    ('\u{1D79D}', '\u{1D79D}', Mapping::Mapped(&['\u{3BE}'])),
+    ('\u{1D79E}', '\u{1D79E}', Mapping::Mapped(&['\u{3BF}'])),
+    ('\u{1D79F}', '\u{1D79F}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D7A0}', '\u{1D7A0}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D7A1}', '\u{1D7A1}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D7A2}', '\u{1D7A2}', Mapping::Mapped(&['\u{3C3}'])),
+    ('\u{1D7A3}', '\u{1D7A3}', Mapping::Mapped(&['\u{3C4}'])),
+    ('\u{1D7A4}', '\u{1D7A4}', Mapping::Mapped(&['\u{3C5}'])),
+    ('\u{1D7A5}', '\u{1D7A5}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D7A6}', '\u{1D7A6}', Mapping::Mapped(&['\u{3C7}'])),
+    ('\u{1D7A7}', '\u{1D7A7}', Mapping::Mapped(&['\u{3C8}'])),
+    ('\u{1D7A8}', '\u{1D7A8}', Mapping::Mapped(&['\u{3C9}'])),
+    ('\u{1D7A9}', '\u{1D7A9}', Mapping::Mapped(&['\u{2207}'])),
+    ('\u{1D7AA}', '\u{1D7AA}', Mapping::Mapped(&['\u{3B1}'])),
+    ('\u{1D7AB}', '\u{1D7AB}', Mapping::Mapped(&['\u{3B2}'])),
+    ('\u{1D7AC}', '\u{1D7AC}', Mapping::Mapped(&['\u{3B3}'])),
+    ('\u{1D7AD}', '\u{1D7AD}', Mapping::Mapped(&['\u{3B4}'])),
+    ('\u{1D7AE}', '\u{1D7AE}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D7AF}', '\u{1D7AF}', Mapping::Mapped(&['\u{3B6}'])),
+    ('\u{1D7B0}', '\u{1D7B0}', Mapping::Mapped(&['\u{3B7}'])),
+    ('\u{1D7B1}', '\u{1D7B1}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D7B2}', '\u{1D7B2}', Mapping::Mapped(&['\u{3B9}'])),
+    ('\u{1D7B3}', '\u{1D7B3}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D7B4}', '\u{1D7B4}', Mapping::Mapped(&['\u{3BB}'])),
+    ('\u{1D7B5}', '\u{1D7B5}', Mapping::Mapped(&['\u{3BC}'])),
+    ('\u{1D7B6}', '\u{1D7B6}', Mapping::Mapped(&['\u{3BD}'])),
+    ('\u{1D7B7}', '\u{1D7B7}', Mapping::Mapped(&['\u{3BE}'])),
+    ('\u{1D7B8}', '\u{1D7B8}', Mapping::Mapped(&['\u{3BF}'])),
+    ('\u{1D7B9}', '\u{1D7B9}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D7BA}', '\u{1D7BA}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D7BB}', '\u{1D7BC}', Mapping::Mapped(&['\u{3C3}'])),
+    ('\u{1D7BD}', '\u{1D7BD}', Mapping::Mapped(&['\u{3C4}'])),
+    ('\u{1D7BE}', '\u{1D7BE}', Mapping::Mapped(&['\u{3C5}'])),
+    ('\u{1D7BF}', '\u{1D7BF}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D7C0}', '\u{1D7C0}', Mapping::Mapped(&['\u{3C7}'])),
+    ('\u{1D7C1}', '\u{1D7C1}', Mapping::Mapped(&['\u{3C8}'])),
+    ('\u{1D7C2}', '\u{1D7C2}', Mapping::Mapped(&['\u{3C9}'])),
+    ('\u{1D7C3}', '\u{1D7C3}', Mapping::Mapped(&['\u{2202}'])),
+    ('\u{1D7C4}', '\u{1D7C4}', Mapping::Mapped(&['\u{3B5}'])),
+    ('\u{1D7C5}', '\u{1D7C5}', Mapping::Mapped(&['\u{3B8}'])),
+    ('\u{1D7C6}', '\u{1D7C6}', Mapping::Mapped(&['\u{3BA}'])),
+    ('\u{1D7C7}', '\u{1D7C7}', Mapping::Mapped(&['\u{3C6}'])),
+    ('\u{1D7C8}', '\u{1D7C8}', Mapping::Mapped(&['\u{3C1}'])),
+    ('\u{1D7C9}', '\u{1D7C9}', Mapping::Mapped(&['\u{3C0}'])),
+    ('\u{1D7CA}', '\u{1D7CB}', Mapping::Mapped(&['\u{3DD}'])),
+    ('\u{1D7CC}', '\u{1D7CD}', Mapping::Disallowed),
+    ('\u{1D7CE}', '\u{1D7CE}', Mapping::Mapped(&['\u{30}'])),
+    ('\u{1D7CF}', '\u{1D7CF}', Mapping::Mapped(&['\u{31}'])),
+    ('\u{1D7D0}', '\u{1D7D0}', Mapping::Mapped(&['\u{32}'])),
+    ('\u{1D7D1}', '\u{1D7D1}', Mapping::Mapped(&['\u{33}'])),
+    ('\u{1D7D2}', '\u{1D7D2}', Mapping::Mapped(&['\u{34}'])),
+    ('\u{1D7D3}', '\u{1D7D3}', Mapping::Mapped(&['\u{35}'])),
+    ('\u{1D7D4}', '\u{1D7D4}', Mapping::Mapped(&['\u{36}'])),
+    ('\u{1D7D5}', '\u{1D7D5}', Mapping::Mapped(&['\u{37}'])),
+    ('\u{1D7D6}', '\u{1D7D6}', Mapping::Mapped(&['\u{38}'])),
+    ('\u{1D7D7}', '\u{1D7D7}', Mapping::Mapped(&['\u{39}'])),
+    ('\u{1D7D8}', '\u{1D7D8}', Mapping::Mapped(&['\u{30}'])),
+    ('\u{1D7D9}', '\u{1D7D9}', Mapping::Mapped(&['\u{31}'])),
+    ('\u{1D7DA}', '\u{1D7DA}', Mapping::Mapped(&['\u{32}'])),
+    ('\u{1D7DB}', '\u{1D7DB}', Mapping::Mapped(&['\u{33}'])),
+    ('\u{1D7DC}', '\u{1D7DC}', Mapping::Mapped(&['\u{34}'])),
+    ('\u{1D7DD}', '\u{1D7DD}', Mapping::Mapped(&['\u{35}'])),
+    ('\u{1D7DE}', '\u{1D7DE}', Mapping::Mapped(&['\u{36}'])),
+    ('\u{1D7DF}', '\u{1D7DF}', Mapping::Mapped(&['\u{37}'])),
+    ('\u{1D7E0}', '\u{1D7E0}', Mapping::Mapped(&['\u{38}'])),
+    ('\u{1D7E1}', '\u{1D7E1}', Mapping::Mapped(&['\u{39}'])),
+    ('\u{1D7E2}', '\u{1D7E2}', Mapping::Mapped(&['\u{30}'])),
+    ('\u{1D7E3}', '\u{1D7E3}', Mapping::Mapped(&['\u{31}'])),
+    ('\u{1D7E4}', '\u{1D7E4}', Mapping::Mapped(&['\u{32}'])),
+    ('\u{1D7E5}', '\u{1D7E5}', Mapping::Mapped(&['\u{33}'])),
+    ('\u{1D7E6}', '\u{1D7E6}', Mapping::Mapped(&['\u{34}'])),
+    ('\u{1D7E7}', '\u{1D7E7}', Mapping::Mapped(&['\u{35}'])),
+    ('\u{1D7E8}', '\u{1D7E8}', Mapping::Mapped(&['\u{36}'])),
+    ('\u{1D7E9}', '\u{1D7E9}', Mapping::Mapped(&['\u{37}'])),
+    ('\u{1D7EA}', '\u{1D7EA}', Mapping::Mapped(&['\u{38}'])),
+    ('\u{1D7EB}', '\u{1D7EB}', Mapping::Mapped(&['\u{39}'])),
+    ('\u{1D7EC}', '\u{1D7EC}', Mapping::Mapped(&['\u{30}'])),
+    ('\u{1D7ED}', '\u{1D7ED}', Mapping::Mapped(&['\u{31}'])),
+    ('\u{1D7EE}', '\u{1D7EE}', Mapping::Mapped(&['\u{32}'])),
+    ('\u{1D7EF}', '\u{1D7EF}', Mapping::Mapped(&['\u{33}'])),
+    ('\u{1D7F0}', '\u{1D7F0}', Mapping::Mapped(&['\u{34}'])),
+    ('\u{1D7F1}', '\u{1D7F1}', Mapping::Mapped(&['\u{35}'])),
+    ('\u{1D7F2}', '\u{1D7F2}', Mapping::Mapped(&['\u{36}'])),
+    ('\u{1D7F3}', '\u{1D7F3}', Mapping::Mapped(&['\u{37}'])),
+    ('\u{1D7F4}', '\u{1D7F4}', Mapping::Mapped(&['\u{38}'])),
+    ('\u{1D7F5}', '\u{1D7F5}', Mapping::Mapped(&['\u{39}'])),
+    ('\u{1D7F6}', '\u{1D7F6}', Mapping::Mapped(&['\u{30}'])),
+    ('\u{1D7F7}', '\u{1D7F7}', Mapping::Mapped(&['\u{31}'])),
+    ('\u{1D7F8}', '\u{1D7F8}', Mapping::Mapped(&['\u{32}'])),
+    ('\u{1D7F9}', '\u{1D7F9}', Mapping::Mapped(&['\u{33}'])),
+    ('\u{1D7FA}', '\u{1D7FA}', Mapping::Mapped(&['\u{34}'])),
+    ('\u{1D7FB}', '\u{1D7FB}', Mapping::Mapped(&['\u{35}'])),
+    ('\u{1D7FC}', '\u{1D7FC}', Mapping::Mapped(&['\u{36}'])),
+    ('\u{1D7FD}', '\u{1D7FD}', Mapping::Mapped(&['\u{37}'])),
+    ('\u{1D7FE}', '\u{1D7FE}', Mapping::Mapped(&['\u{38}'])),
+    ('\u{1D7FF}', '\u{1D7FF}', Mapping::Mapped(&['\u{39}'])),
+    ('\u{1D800}', '\u{1DA8B}', Mapping::Valid),
+    ('\u{1DA8C}', '\u{1DA9A}', Mapping::Disallowed),
+    ('\u{1DA9B}', '\u{1DA9F}', Mapping::Valid),
+    ('\u{1DAA0}', '\u{1DAA0}', Mapping::Disallowed),
+    ('\u{1DAA1}', '\u{1DAAF}', Mapping::Valid),
+    ('\u{1DAB0}', '\u{1DFFF}', Mapping::Disallowed),
+    ('\u{1E000}', '\u{1E006}', Mapping::Valid),
+    ('\u{1E007}', '\u{1E007}', Mapping::Disallowed),
+    ('\u{1E008}', '\u{1E018}', Mapping::Valid),
+    ('\u{1E019}', '\u{1E01A}', Mapping::Disallowed),
+    ('\u{1E01B}', '\u{1E021}', Mapping::Valid),
+    ('\u{1E022}', '\u{1E022}', Mapping::Disallowed),
+    ('\u{1E023}', '\u{1E024}', Mapping::Valid),
+    ('\u{1E025}', '\u{1E025}', Mapping::Disallowed),
+    ('\u{1E026}', '\u{1E02A}', Mapping::Valid),
+    ('\u{1E02B}', '\u{1E0FF}', Mapping::Disallowed),
+    ('\u{1E100}', '\u{1E12C}', Mapping::Valid),
+    ('\u{1E12D}', '\u{1E12F}', Mapping::Disallowed),
+    ('\u{1E130}', '\u{1E13D}', Mapping::Valid),
+    ('\u{1E13E}', '\u{1E13F}', Mapping::Disallowed),
+    ('\u{1E140}', '\u{1E149}', Mapping::Valid),
+    ('\u{1E14A}', '\u{1E14D}', Mapping::Disallowed),
+    ('\u{1E14E}', '\u{1E14F}', Mapping::Valid),
+    ('\u{1E150}', '\u{1E2BF}', Mapping::Disallowed),
+    ('\u{1E2C0}', '\u{1E2F9}', Mapping::Valid),
+    ('\u{1E2FA}', '\u{1E2FE}', Mapping::Disallowed),
+    ('\u{1E2FF}', '\u{1E2FF}', Mapping::Valid),
+    ('\u{1E300}', '\u{1E7FF}', Mapping::Disallowed),
+    ('\u{1E800}', '\u{1E8C4}', Mapping::Valid),
+    ('\u{1E8C5}', '\u{1E8C6}', Mapping::Disallowed),
+    ('\u{1E8C7}', '\u{1E8D6}', Mapping::Valid),
+    ('\u{1E8D7}', '\u{1E8FF}', Mapping::Disallowed),
+    ('\u{1E900}', '\u{1E900}', Mapping::Mapped(&['\u{1E922}'])),
+    ('\u{1E901}', '\u{1E901}', Mapping::Mapped(&['\u{1E923}'])),
+    ('\u{1E902}', '\u{1E902}', Mapping::Mapped(&['\u{1E924}'])),
+    ('\u{1E903}', '\u{1E903}', Mapping::Mapped(&['\u{1E925}'])),
+    ('\u{1E904}', '\u{1E904}', Mapping::Mapped(&['\u{1E926}'])),
+    ('\u{1E905}', '\u{1E905}', Mapping::Mapped(&['\u{1E927}'])),
+    ('\u{1E906}', '\u{1E906}', Mapping::Mapped(&['\u{1E928}'])),
+    ('\u{1E907}', '\u{1E907}', Mapping::Mapped(&['\u{1E929}'])),
+    ('\u{1E908}', '\u{1E908}', Mapping::Mapped(&['\u{1E92A}'])),
+    ('\u{1E909}', '\u{1E909}', Mapping::Mapped(&['\u{1E92B}'])),
+    ('\u{1E90A}', '\u{1E90A}', Mapping::Mapped(&['\u{1E92C}'])),
+    ('\u{1E90B}', '\u{1E90B}', Mapping::Mapped(&['\u{1E92D}'])),
+    ('\u{1E90C}', '\u{1E90C}', Mapping::Mapped(&['\u{1E92E}'])),
+    ('\u{1E90D}', '\u{1E90D}', Mapping::Mapped(&['\u{1E92F}'])),
+    ('\u{1E90E}', '\u{1E90E}', Mapping::Mapped(&['\u{1E930}'])),
+    ('\u{1E90F}', '\u{1E90F}', Mapping::Mapped(&['\u{1E931}'])),
+    ('\u{1E910}', '\u{1E910}', Mapping::Mapped(&['\u{1E932}'])),
+    ('\u{1E911}', '\u{1E911}', Mapping::Mapped(&['\u{1E933}'])),
+    ('\u{1E912}', '\u{1E912}', Mapping::Mapped(&['\u{1E934}'])),
+    ('\u{1E913}', '\u{1E913}', Mapping::Mapped(&['\u{1E935}'])),
+    ('\u{1E914}', '\u{1E914}', Mapping::Mapped(&['\u{1E936}'])),
+    ('\u{1E915}', '\u{1E915}', Mapping::Mapped(&['\u{1E937}'])),
+    ('\u{1E916}', '\u{1E916}', Mapping::Mapped(&['\u{1E938}'])),
+    ('\u{1E917}', '\u{1E917}', Mapping::Mapped(&['\u{1E939}'])),
+    ('\u{1E918}', '\u{1E918}', Mapping::Mapped(&['\u{1E93A}'])),
+    ('\u{1E919}', '\u{1E919}', Mapping::Mapped(&['\u{1E93B}'])),
+    ('\u{1E91A}', '\u{1E91A}', Mapping::Mapped(&['\u{1E93C}'])),
+    ('\u{1E91B}', '\u{1E91B}', Mapping::Mapped(&['\u{1E93D}'])),
+    ('\u{1E91C}', '\u{1E91C}', Mapping::Mapped(&['\u{1E93E}'])),
+    ('\u{1E91D}', '\u{1E91D}', Mapping::Mapped(&['\u{1E93F}'])),
+    ('\u{1E91E}', '\u{1E91E}', Mapping::Mapped(&['\u{1E940}'])),
+    ('\u{1E91F}', '\u{1E91F}', Mapping::Mapped(&['\u{1E941}'])),
+    ('\u{1E920}', '\u{1E920}', Mapping::Mapped(&['\u{1E942}'])),
+    ('\u{1E921}', '\u{1E921}', Mapping::Mapped(&['\u{1E943}'])),
+    ('\u{1E922}', '\u{1E94B}', Mapping::Valid),
+    ('\u{1E94C}', '\u{1E94F}', Mapping::Disallowed),
+    ('\u{1E950}', '\u{1E959}', Mapping::Valid),
+    ('\u{1E95A}', '\u{1E95D}', Mapping::Disallowed),
+    ('\u{1E95E}', '\u{1E95F}', Mapping::Valid),
+    ('\u{1E960}', '\u{1EC70}', Mapping::Disallowed),
+    ('\u{1EC71}', '\u{1ECB4}', Mapping::Valid),
+    ('\u{1ECB5}', '\u{1ED00}', Mapping::Disallowed),
+    ('\u{1ED01}', '\u{1ED3D}', Mapping::Valid),
+    ('\u{1ED3E}', '\u{1EDFF}', Mapping::Disallowed),
+    ('\u{1EE00}', '\u{1EE00}', Mapping::Mapped(&['\u{627}'])),
+    ('\u{1EE01}', '\u{1EE01}', Mapping::Mapped(&['\u{628}'])),
+    ('\u{1EE02}', '\u{1EE02}', Mapping::Mapped(&['\u{62C}'])),
+    ('\u{1EE03}', '\u{1EE03}', Mapping::Mapped(&['\u{62F}'])),
+    ('\u{1EE04}', '\u{1EE04}', Mapping::Disallowed),
+    ('\u{1EE05}', '\u{1EE05}', Mapping::Mapped(&['\u{648}'])),
+    ('\u{1EE06}', '\u{1EE06}', Mapping::Mapped(&['\u{632}'])),
+    ('\u{1EE07}', '\u{1EE07}', Mapping::Mapped(&['\u{62D}'])),
+    ('\u{1EE08}', '\u{1EE08}', Mapping::Mapped(&['\u{637}'])),
+    ('\u{1EE09}', '\u{1EE09}', Mapping::Mapped(&['\u{64A}'])),
+    ('\u{1EE0A}', '\u{1EE0A}', Mapping::Mapped(&['\u{643}'])),
+    ('\u{1EE0B}', '\u{1EE0B}', Mapping::Mapped(&['\u{644}'])),
+    ('\u{1EE0C}', '\u{1EE0C}', Mapping::Mapped(&['\u{645}'])),
+    ('\u{1EE0D}', '\u{1EE0D}', Mapping::Mapped(&['\u{646}'])),
+    ('\u{1EE0E}', '\u{1EE0E}', Mapping::Mapped(&['\u{633}'])),
+    ('\u{1EE0F}', '\u{1EE0F}', Mapping::Mapped(&['\u{639}'])),
+    ('\u{1EE10}', '\u{1EE10}', Mapping::Mapped(&['\u{641}'])),
+    ('\u{1EE11}', '\u{1EE11}', Mapping::Mapped(&['\u{635}'])),
+    ('\u{1EE12}', '\u{1EE12}', Mapping::Mapped(&['\u{642}'])),
+    ('\u{1EE13}', '\u{1EE13}', Mapping::Mapped(&['\u{631}'])),
+    ('\u{1EE14}', '\u{1EE14}', Mapping::Mapped(&['\u{634}'])),
+    ('\u{1EE15}', '\u{1EE15}', Mapping::Mapped(&['\u{62A}'])),
+    ('\u{1EE16}', '\u{1EE16}', Mapping::Mapped(&['\u{62B}'])),
+    ('\u{1EE17}', '\u{1EE17}', Mapping::Mapped(&['\u{62E}'])),
+    ('\u{1EE18}', '\u{1EE18}', Mapping::Mapped(&['\u{630}'])),
+    ('\u{1EE19}', '\u{1EE19}', Mapping::Mapped(&['\u{636}'])),
+    ('\u{1EE1A}', '\u{1EE1A}', Mapping::Mapped(&['\u{638}'])),
+    ('\u{1EE1B}', '\u{1EE1B}', Mapping::Mapped(&['\u{63A}'])),
+    ('\u{1EE1C}', '\u{1EE1C}', Mapping::Mapped(&['\u{66E}'])),
+    ('\u{1EE1D}', '\u{1EE1D}', Mapping::Mapped(&['\u{6BA}'])),
+    ('\u{1EE1E}', '\u{1EE1E}', Mapping::Mapped(&['\u{6A1}'])),
+    ('\u{1EE1F}', '\u{1EE1F}', Mapping::Mapped(&['\u{66F}'])),
+    ('\u{1EE20}', '\u{1EE20}', Mapping::Disallowed),
+    ('\u{1EE21}', '\u{1EE21}', Mapping::Mapped(&['\u{628}'])),
+    ('\u{1EE22}', '\u{1EE22}', Mapping::Mapped(&['\u{62C}'])),
+    ('\u{1EE23}', '\u{1EE23}', Mapping::Disallowed),
+    ('\u{1EE24}', '\u{1EE24}', Mapping::Mapped(&['\u{647}'])),
+    ('\u{1EE25}', '\u{1EE26}', Mapping::Disallowed),
+    ('\u{1EE27}', '\u{1EE27}', Mapping::Mapped(&['\u{62D}'])),
+    ('\u{1EE28}', '\u{1EE28}', Mapping::Disallowed),
+    ('\u{1EE29}', '\u{1EE29}', Mapping::Mapped(&['\u{64A}'])),
+    ('\u{1EE2A}', '\u{1EE2A}', Mapping::Mapped(&['\u{643}'])),
+    ('\u{1EE2B}', '\u{1EE2B}', Mapping::Mapped(&['\u{644}'])),
+    ('\u{1EE2C}', '\u{1EE2C}', Mapping::Mapped(&['\u{645}'])),
+    ('\u{1EE2D}', '\u{1EE2D}', Mapping::Mapped(&['\u{646}'])),
+    ('\u{1EE2E}', '\u{1EE2E}', Mapping::Mapped(&['\u{633}'])),
+    ('\u{1EE2F}', '\u{1EE2F}', Mapping::Mapped(&['\u{639}'])),
+    ('\u{1EE30}', '\u{1EE30}', Mapping::Mapped(&['\u{641}'])),
+    ('\u{1EE31}', '\u{1EE31}', Mapping::Mapped(&['\u{635}'])),
+    ('\u{1EE32}', '\u{1EE32}', Mapping::Mapped(&['\u{642}'])),
+    ('\u{1EE33}', '\u{1EE33}', Mapping::Disallowed),
+    ('\u{1EE34}', '\u{1EE34}', Mapping::Mapped(&['\u{634}'])),
+    ('\u{1EE35}', '\u{1EE35}', Mapping::Mapped(&['\u{62A}'])),
+    ('\u{1EE36}', '\u{1EE36}', Mapping::Mapped(&['\u{62B}'])),
+    ('\u{1EE37}', '\u{1EE37}', Mapping::Mapped(&['\u{62E}'])),
+    ('\u{1EE38}', '\u{1EE38}', Mapping::Disallowed),
+    ('\u{1EE39}', '\u{1EE39}', Mapping::Mapped(&['\u{636}'])),
+    ('\u{1EE3A}', '\u{1EE3A}', Mapping::Disallowed),
+    ('\u{1EE3B}', '\u{1EE3B}', Mapping::Mapped(&['\u{63A}'])),
+    ('\u{1EE3C}', '\u{1EE41}', Mapping::Disallowed),
+    ('\u{1EE42}', '\u{1EE42}', Mapping::Mapped(&['\u{62C}'])),
+    ('\u{1EE43}', '\u{1EE46}', Mapping::Disallowed),
+    ('\u{1EE47}', '\u{1EE47}', Mapping::Mapped(&['\u{62D}'])),
+    ('\u{1EE48}', '\u{1EE48}', Mapping::Disallowed),
+    ('\u{1EE49}', '\u{1EE49}', Mapping::Mapped(&['\u{64A}'])),
+    ('\u{1EE4A}', '\u{1EE4A}', Mapping::Disallowed),
+    ('\u{1EE4B}', '\u{1EE4B}', Mapping::Mapped(&['\u{644}'])),
+    ('\u{1EE4C}', '\u{1EE4C}', Mapping::Disallowed),
+    ('\u{1EE4D}', '\u{1EE4D}', Mapping::Mapped(&['\u{646}'])),
+    ('\u{1EE4E}', '\u{1EE4E}', Mapping::Mapped(&['\u{633}'])),
+    ('\u{1EE4F}', '\u{1EE4F}', Mapping::Mapped(&['\u{639}'])),
+    ('\u{1EE50}', '\u{1EE50}', Mapping::Disallowed),
+    ('\u{1EE51}', '\u{1EE51}', Mapping::Mapped(&['\u{635}'])),
+    ('\u{1EE52}', '\u{1EE52}', Mapping::Mapped(&['\u{642}'])),
+    ('\u{1EE53}', '\u{1EE53}', Mapping::Disallowed),
+    ('\u{1EE54}', '\u{1EE54}', Mapping::Mapped(&['\u{634}'])),
+    ('\u{1EE55}', '\u{1EE56}', Mapping::Disallowed),
+    ('\u{1EE57}', '\u{1EE57}', Mapping::Mapped(&['\u{62E}'])),
+    ('\u{1EE58}', '\u{1EE58}', Mapping::Disallowed),
+    ('\u{1EE59}', '\u{1EE59}', Mapping::Mapped(&['\u{636}'])),
+    ('\u{1EE5A}', '\u{1EE5A}', Mapping::Disallowed),
+    ('\u{1EE5B}', '\u{1EE5B}', Mapping::Mapped(&['\u{63A}'])),
+    ('\u{1EE5C}', '\u{1EE5C}', Mapping::Disallowed),
+    ('\u{1EE5D}', '\u{1EE5D}', Mapping::Mapped(&['\u{6BA}'])),
+    ('\u{1EE5E}', '\u{1EE5E}', Mapping::Disallowed),
+    ('\u{1EE5F}', '\u{1EE5F}', Mapping::Mapped(&['\u{66F}'])),
+    ('\u{1EE60}', '\u{1EE60}', Mapping::Disallowed),
+    ('\u{1EE61}', '\u{1EE61}', Mapping::Mapped(&['\u{628}'])),
+    ('\u{1EE62}', '\u{1EE62}', Mapping::Mapped(&['\u{62C}'])),
+    ('\u{1EE63}', '\u{1EE63}', Mapping::Disallowed),
+    ('\u{1EE64}', '\u{1EE64}', Mapping::Mapped(&['\u{647}'])),
+    ('\u{1EE65}', '\u{1EE66}', Mapping::Disallowed),
+    ('\u{1EE67}', '\u{1EE67}', Mapping::Mapped(&['\u{62D}'])),
+    ('\u{1EE68}', '\u{1EE68}', Mapping::Mapped(&['\u{637}'])),
+    ('\u{1EE69}', '\u{1EE69}', Mapping::Mapped(&['\u{64A}'])),
+    ('\u{1EE6A}', '\u{1EE6A}', Mapping::Mapped(&['\u{643}'])),
+    ('\u{1EE6B}', '\u{1EE6B}', Mapping::Disallowed),
+    ('\u{1EE6C}', '\u{1EE6C}', Mapping::Mapped(&['\u{645}'])),
+    ('\u{1EE6D}', '\u{1EE6D}', Mapping::Mapped(&['\u{646}'])),
+    ('\u{1EE6E}', '\u{1EE6E}', Mapping::Mapped(&['\u{633}'])),
+    ('\u{1EE6F}', '\u{1EE6F}', Mapping::Mapped(&['\u{639}'])),
+    ('\u{1EE70}', '\u{1EE70}', Mapping::Mapped(&['\u{641}'])),
+    ('\u{1EE71}', '\u{1EE71}', Mapping::Mapped(&['\u{635}'])),
+    ('\u{1EE72}', '\u{1EE72}', Mapping::Mapped(&['\u{642}'])),
+    ('\u{1EE73}', '\u{1EE73}', Mapping::Disallowed),
+    ('\u{1EE74}', '\u{1EE74}', Mapping::Mapped(&['\u{634}'])),
+    ('\u{1EE75}', '\u{1EE75}', Mapping::Mapped(&['\u{62A}'])),
+    ('\u{1EE76}', '\u{1EE76}', Mapping::Mapped(&['\u{62B}'])),
+    ('\u{1EE77}', '\u{1EE77}', Mapping::Mapped(&['\u{62E}'])),
+    ('\u{1EE78}', '\u{1EE78}', Mapping::Disallowed),
+    ('\u{1EE79}', '\u{1EE79}', Mapping::Mapped(&['\u{636}'])),
+    ('\u{1EE7A}', '\u{1EE7A}', Mapping::Mapped(&['\u{638}'])),
+    ('\u{1EE7B}', '\u{1EE7B}', Mapping::Mapped(&['\u{63A}'])),
+    ('\u{1EE7C}', '\u{1EE7C}', Mapping::Mapped(&['\u{66E}'])),
+    ('\u{1EE7D}', '\u{1EE7D}', Mapping::Disallowed),
+    ('\u{1EE7E}', '\u{1EE7E}', Mapping::Mapped(&['\u{6A1}'])),
+    ('\u{1EE7F}', '\u{1EE7F}', Mapping::Disallowed),
+    ('\u{1EE80}', '\u{1EE80}', Mapping::Mapped(&['\u{627}'])),
+    ('\u{1EE81}', '\u{1EE81}', Mapping::Mapped(&['\u{628}'])),
+    ('\u{1EE82}', '\u{1EE82}', Mapping::Mapped(&['\u{62C}'])),
+    ('\u{1EE83}', '\u{1EE83}', Mapping::Mapped(&['\u{62F}'])),
+    ('\u{1EE84}', '\u{1EE84}', Mapping::Mapped(&['\u{647}'])),
+    ('\u{1EE85}', '\u{1EE85}', Mapping::Mapped(&['\u{648}'])),
+    ('\u{1EE86}', '\u{1EE86}', Mapping::Mapped(&['\u{632}'])),
+    ('\u{1EE87}', '\u{1EE87}', Mapping::Mapped(&['\u{62D}'])),
+    ('\u{1EE88}', '\u{1EE88}', Mapping::Mapped(&['\u{637}'])),
+    ('\u{1EE89}', '\u{1EE89}', Mapping::Mapped(&['\u{64A}'])),
+    ('\u{1EE8A}', '\u{1EE8A}', Mapping::Disallowed),
+    ('\u{1EE8B}', '\u{1EE8B}', Mapping::Mapped(&['\u{644}'])),
+    ('\u{1EE8C}', '\u{1EE8C}', Mapping::Mapped(&['\u{645}'])),
+    ('\u{1EE8D}', '\u{1EE8D}', Mapping::Mapped(&['\u{646}'])),
+    ('\u{1EE8E}', '\u{1EE8E}', Mapping::Mapped(&['\u{633}'])),
+    ('\u{1EE8F}', '\u{1EE8F}', Mapping::Mapped(&['\u{639}'])),
+    ('\u{1EE90}', '\u{1EE90}', Mapping::Mapped(&['\u{641}'])),
+    ('\u{1EE91}', '\u{1EE91}', Mapping::Mapped(&['\u{635}'])),
+    ('\u{1EE92}', '\u{1EE92}', Mapping::Mapped(&['\u{642}'])),
+    ('\u{1EE93}', '\u{1EE93}', Mapping::Mapped(&['\u{631}'])),
+    ('\u{1EE94}', '\u{1EE94}', Mapping::Mapped(&['\u{634}'])),
+    ('\u{1EE95}', '\u{1EE95}', Mapping::Mapped(&['\u{62A}'])),
+    ('\u{1EE96}', '\u{1EE96}', Mapping::Mapped(&['\u{62B}'])),
+    ('\u{1EE97}', '\u{1EE97}', Mapping::Mapped(&['\u{62E}'])),
+    ('\u{1EE98}', '\u{1EE98}', Mapping::Mapped(&['\u{630}'])),
+    ('\u{1EE99}', '\u{1EE99}', Mapping::Mapped(&['\u{636}'])),
+    ('\u{1EE9A}', '\u{1EE9A}', Mapping::Mapped(&['\u{638}'])),
+    ('\u{1EE9B}', '\u{1EE9B}', Mapping::Mapped(&['\u{63A}'])),
+    ('\u{1EE9C}', '\u{1EEA0}', Mapping::Disallowed),
+    ('\u{1EEA1}', '\u{1EEA1}', Mapping::Mapped(&['\u{628}'])),
+    ('\u{1EEA2}', '\u{1EEA2}', Mapping::Mapped(&['\u{62C}'])),
+    ('\u{1EEA3}', '\u{1EEA3}', Mapping::Mapped(&['\u{62F}'])),
+    ('\u{1EEA4}', '\u{1EEA4}', Mapping::Disallowed),
+    ('\u{1EEA5}', '\u{1EEA5}', Mapping::Mapped(&['\u{648}'])),
+    ('\u{1EEA6}', '\u{1EEA6}', Mapping::Mapped(&['\u{632}'])),
+    ('\u{1EEA7}', '\u{1EEA7}', Mapping::Mapped(&['\u{62D}'])),
+    ('\u{1EEA8}', '\u{1EEA8}', Mapping::Mapped(&['\u{637}'])),
+    ('\u{1EEA9}', '\u{1EEA9}', Mapping::Mapped(&['\u{64A}'])),
+    ('\u{1EEAA}', '\u{1EEAA}', Mapping::Disallowed),
+    ('\u{1EEAB}', '\u{1EEAB}', Mapping::Mapped(&['\u{644}'])),
+    ('\u{1EEAC}', '\u{1EEAC}', Mapping::Mapped(&['\u{645}'])),
+    ('\u{1EEAD}', '\u{1EEAD}', Mapping::Mapped(&['\u{646}'])),
+    ('\u{1EEAE}', '\u{1EEAE}', Mapping::Mapped(&['\u{633}'])),
+    ('\u{1EEAF}', '\u{1EEAF}', Mapping::Mapped(&['\u{639}'])),
+    ('\u{1EEB0}', '\u{1EEB0}', Mapping::Mapped(&['\u{641}'])),
+    ('\u{1EEB1}', '\u{1EEB1}', Mapping::Mapped(&['\u{635}'])),
+    ('\u{1EEB2}', '\u{1EEB2}', Mapping::Mapped(&['\u{642}'])),
+    ('\u{1EEB3}', '\u{1EEB3}', Mapping::Mapped(&['\u{631}'])),
+    ('\u{1EEB4}', '\u{1EEB4}', Mapping::Mapped(&['\u{634}'])),
+    ('\u{1EEB5}', '\u{1EEB5}', Mapping::Mapped(&['\u{62A}'])),
+    ('\u{1EEB6}', '\u{1EEB6}', Mapping::Mapped(&['\u{62B}'])),
+    ('\u{1EEB7}', '\u{1EEB7}', Mapping::Mapped(&['\u{62E}'])),
+    ('\u{1EEB8}', '\u{1EEB8}', Mapping::Mapped(&['\u{630}'])),
+    ('\u{1EEB9}', '\u{1EEB9}', Mapping::Mapped(&['\u{636}'])),
+    ('\u{1EEBA}', '\u{1EEBA}', Mapping::Mapped(&['\u{638}'])),
+    ('\u{1EEBB}', '\u{1EEBB}', Mapping::Mapped(&['\u{63A}'])),
+    ('\u{1EEBC}', '\u{1EEEF}', Mapping::Disallowed),
+    ('\u{1EEF0}', '\u{1EEF1}', Mapping::Valid),
+    ('\u{1EEF2}', '\u{1EFFF}', Mapping::Disallowed),
+    ('\u{1F000}', '\u{1F02B}', Mapping::Valid),
+    ('\u{1F02C}', '\u{1F02F}', Mapping::Disallowed),
+    ('\u{1F030}', '\u{1F093}', Mapping::Valid),
+    ('\u{1F094}', '\u{1F09F}', Mapping::Disallowed),
+    ('\u{1F0A0}', '\u{1F0AE}', Mapping::Valid),
+    ('\u{1F0AF}', '\u{1F0B0}', Mapping::Disallowed),
+    ('\u{1F0B1}', '\u{1F0BF}', Mapping::Valid),
+    ('\u{1F0C0}', '\u{1F0C0}', Mapping::Disallowed),
+    ('\u{1F0C1}', '\u{1F0CF}', Mapping::Valid),
+    ('\u{1F0D0}', '\u{1F0D0}', Mapping::Disallowed),
+    ('\u{1F0D1}', '\u{1F0F5}', Mapping::Valid),
+    ('\u{1F0F6}', '\u{1F100}', Mapping::Disallowed),
+    ('\u{1F101}', '\u{1F101}', Mapping::DisallowedStd3Mapped(&['\u{30}', '\u{2C}'])),
+    ('\u{1F102}', '\u{1F102}', Mapping::DisallowedStd3Mapped(&['\u{31}', '\u{2C}'])),
+    ('\u{1F103}', '\u{1F103}', Mapping::DisallowedStd3Mapped(&['\u{32}', '\u{2C}'])),
+    ('\u{1F104}', '\u{1F104}', Mapping::DisallowedStd3Mapped(&['\u{33}', '\u{2C}'])),
+    ('\u{1F105}', '\u{1F105}', Mapping::DisallowedStd3Mapped(&['\u{34}', '\u{2C}'])),
+    ('\u{1F106}', '\u{1F106}', Mapping::DisallowedStd3Mapped(&['\u{35}', '\u{2C}'])),
+    ('\u{1F107}', '\u{1F107}', Mapping::DisallowedStd3Mapped(&['\u{36}', '\u{2C}'])),
+    ('\u{1F108}', '\u{1F108}', Mapping::DisallowedStd3Mapped(&['\u{37}', '\u{2C}'])),
+    ('\u{1F109}', '\u{1F109}', Mapping::DisallowedStd3Mapped(&['\u{38}', '\u{2C}'])),
+    ('\u{1F10A}', '\u{1F10A}', Mapping::DisallowedStd3Mapped(&['\u{39}', '\u{2C}'])),
+    ('\u{1F10B}', '\u{1F10F}', Mapping::Valid),
+    ('\u{1F110}', '\u{1F110}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{61}', '\u{29}'])),
+    ('\u{1F111}', '\u{1F111}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{62}', '\u{29}'])),
+    ('\u{1F112}', '\u{1F112}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{63}', '\u{29}'])),
+    ('\u{1F113}', '\u{1F113}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{64}', '\u{29}'])),
+    ('\u{1F114}', '\u{1F114}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{65}', '\u{29}'])),
+    ('\u{1F115}', '\u{1F115}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{66}', '\u{29}'])),
+    ('\u{1F116}', '\u{1F116}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{67}', '\u{29}'])),
+    ('\u{1F117}', '\u{1F117}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{68}', '\u{29}'])),
+    ('\u{1F118}', '\u{1F118}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{69}', '\u{29}'])),
+    ('\u{1F119}', '\u{1F119}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6A}', '\u{29}'])),
+    ('\u{1F11A}', '\u{1F11A}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6B}', '\u{29}'])),
+    ('\u{1F11B}', '\u{1F11B}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6C}', '\u{29}'])),
+    ('\u{1F11C}', '\u{1F11C}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6D}', '\u{29}'])),
+    ('\u{1F11D}', '\u{1F11D}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6E}', '\u{29}'])),
+    ('\u{1F11E}', '\u{1F11E}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{6F}', '\u{29}'])),
+    ('\u{1F11F}', '\u{1F11F}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{70}', '\u{29}'])),
+    ('\u{1F120}', '\u{1F120}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{71}', '\u{29}'])),
+    ('\u{1F121}', '\u{1F121}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{72}', '\u{29}'])),
+    ('\u{1F122}', '\u{1F122}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{73}', '\u{29}'])),
+    ('\u{1F123}', '\u{1F123}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{74}', '\u{29}'])),
+    ('\u{1F124}', '\u{1F124}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{75}', '\u{29}'])),
+    ('\u{1F125}', '\u{1F125}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{76}', '\u{29}'])),
+    ('\u{1F126}', '\u{1F126}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{77}', '\u{29}'])),
+    ('\u{1F127}', '\u{1F127}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{78}', '\u{29}'])),
+    ('\u{1F128}', '\u{1F128}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{79}', '\u{29}'])),
+    ('\u{1F129}', '\u{1F129}', Mapping::DisallowedStd3Mapped(&['\u{28}', '\u{7A}', '\u{29}'])),
+    ('\u{1F12A}', '\u{1F12A}', Mapping::Mapped(&['\u{3014}', '\u{73}', '\u{3015}'])),
+    ('\u{1F12B}', '\u{1F12B}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1F12C}', '\u{1F12C}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1F12D}', '\u{1F12D}', Mapping::Mapped(&['\u{63}', '\u{64}'])),
+    ('\u{1F12E}', '\u{1F12E}', Mapping::Mapped(&['\u{77}', '\u{7A}'])),
+    ('\u{1F12F}', '\u{1F12F}', Mapping::Valid),
+    ('\u{1F130}', '\u{1F130}', Mapping::Mapped(&['\u{61}'])),
+    ('\u{1F131}', '\u{1F131}', Mapping::Mapped(&['\u{62}'])),
+    ('\u{1F132}', '\u{1F132}', Mapping::Mapped(&['\u{63}'])),
+    ('\u{1F133}', '\u{1F133}', Mapping::Mapped(&['\u{64}'])),
+    ('\u{1F134}', '\u{1F134}', Mapping::Mapped(&['\u{65}'])),
+    ('\u{1F135}', '\u{1F135}', Mapping::Mapped(&['\u{66}'])),
+    ('\u{1F136}', '\u{1F136}', Mapping::Mapped(&['\u{67}'])),
+    ('\u{1F137}', '\u{1F137}', Mapping::Mapped(&['\u{68}'])),
+    ('\u{1F138}', '\u{1F138}', Mapping::Mapped(&['\u{69}'])),
+    ('\u{1F139}', '\u{1F139}', Mapping::Mapped(&['\u{6A}'])),
+    ('\u{1F13A}', '\u{1F13A}', Mapping::Mapped(&['\u{6B}'])),
+    ('\u{1F13B}', '\u{1F13B}', Mapping::Mapped(&['\u{6C}'])),
+    ('\u{1F13C}', '\u{1F13C}', Mapping::Mapped(&['\u{6D}'])),
+    ('\u{1F13D}', '\u{1F13D}', Mapping::Mapped(&['\u{6E}'])),
+    ('\u{1F13E}', '\u{1F13E}', Mapping::Mapped(&['\u{6F}'])),
+    ('\u{1F13F}', '\u{1F13F}', Mapping::Mapped(&['\u{70}'])),
+    ('\u{1F140}', '\u{1F140}', Mapping::Mapped(&['\u{71}'])),
+    ('\u{1F141}', '\u{1F141}', Mapping::Mapped(&['\u{72}'])),
+    ('\u{1F142}', '\u{1F142}', Mapping::Mapped(&['\u{73}'])),
+    ('\u{1F143}', '\u{1F143}', Mapping::Mapped(&['\u{74}'])),
+    ('\u{1F144}', '\u{1F144}', Mapping::Mapped(&['\u{75}'])),
+    ('\u{1F145}', '\u{1F145}', Mapping::Mapped(&['\u{76}'])),
+    ('\u{1F146}', '\u{1F146}', Mapping::Mapped(&['\u{77}'])),
+    ('\u{1F147}', '\u{1F147}', Mapping::Mapped(&['\u{78}'])),
+    ('\u{1F148}', '\u{1F148}', Mapping::Mapped(&['\u{79}'])),
+    ('\u{1F149}', '\u{1F149}', Mapping::Mapped(&['\u{7A}'])),
+    ('\u{1F14A}', '\u{1F14A}', Mapping::Mapped(&['\u{68}', '\u{76}'])),
+    ('\u{1F14B}', '\u{1F14B}', Mapping::Mapped(&['\u{6D}', '\u{76}'])),
+    ('\u{1F14C}', '\u{1F14C}', Mapping::Mapped(&['\u{73}', '\u{64}'])),
+    ('\u{1F14D}', '\u{1F14D}', Mapping::Mapped(&['\u{73}', '\u{73}'])),
+    ('\u{1F14E}', '\u{1F14E}', Mapping::Mapped(&['\u{70}', '\u{70}', '\u{76}'])),
+    ('\u{1F14F}', '\u{1F14F}', Mapping::Mapped(&['\u{77}', '\u{63}'])),
+    ('\u{1F150}', '\u{1F169}', Mapping::Valid),
+    ('\u{1F16A}', '\u{1F16A}', Mapping::Mapped(&['\u{6D}', '\u{63}'])),
+    ('\u{1F16B}', '\u{1F16B}', Mapping::Mapped(&['\u{6D}', '\u{64}'])),
+    ('\u{1F16C}', '\u{1F16C}', Mapping::Mapped(&['\u{6D}', '\u{72}'])),
+    ('\u{1F16D}', '\u{1F18F}', Mapping::Valid),
+    ('\u{1F190}', '\u{1F190}', Mapping::Mapped(&['\u{64}', '\u{6A}'])),
+    ('\u{1F191}', '\u{1F1AD}', Mapping::Valid),
+    ('\u{1F1AE}', '\u{1F1E5}', Mapping::Disallowed),
+    ('\u{1F1E6}', '\u{1F1FF}', Mapping::Valid),
+    ('\u{1F200}', '\u{1F200}', Mapping::Mapped(&['\u{307B}', '\u{304B}'])),
+    ('\u{1F201}', '\u{1F201}', Mapping::Mapped(&['\u{30B3}', '\u{30B3}'])),
+    ('\u{1F202}', '\u{1F202}', Mapping::Mapped(&['\u{30B5}'])),
+    ('\u{1F203}', '\u{1F20F}', Mapping::Disallowed),
+    ('\u{1F210}', '\u{1F210}', Mapping::Mapped(&['\u{624B}'])),
+    ('\u{1F211}', '\u{1F211}', Mapping::Mapped(&['\u{5B57}'])),
+    ('\u{1F212}', '\u{1F212}', Mapping::Mapped(&['\u{53CC}'])),
+    ('\u{1F213}', '\u{1F213}', Mapping::Mapped(&['\u{30C7}'])),
+    ('\u{1F214}', '\u{1F214}', Mapping::Mapped(&['\u{4E8C}'])),
+    ('\u{1F215}', '\u{1F215}', Mapping::Mapped(&['\u{591A}'])),
+    ('\u{1F216}', '\u{1F216}', Mapping::Mapped(&['\u{89E3}'])),
+    ('\u{1F217}', '\u{1F217}', Mapping::Mapped(&['\u{5929}'])),
+    ('\u{1F218}', '\u{1F218}', Mapping::Mapped(&['\u{4EA4}'])),
+    ('\u{1F219}', '\u{1F219}', Mapping::Mapped(&['\u{6620}'])),
+    ('\u{1F21A}', '\u{1F21A}', Mapping::Mapped(&['\u{7121}'])),
+    ('\u{1F21B}', '\u{1F21B}', Mapping::Mapped(&['\u{6599}'])),
+    ('\u{1F21C}', '\u{1F21C}', Mapping::Mapped(&['\u{524D}'])),
+    ('\u{1F21D}', '\u{1F21D}', Mapping::Mapped(&['\u{5F8C}'])),
+    ('\u{1F21E}', '\u{1F21E}', Mapping::Mapped(&['\u{518D}'])),
+    ('\u{1F21F}', '\u{1F21F}', Mapping::Mapped(&['\u{65B0}'])),
+    ('\u{1F220}', '\u{1F220}', Mapping::Mapped(&['\u{521D}'])),
+    ('\u{1F221}', '\u{1F221}', Mapping::Mapped(&['\u{7D42}'])),
+    ('\u{1F222}', '\u{1F222}', Mapping::Mapped(&['\u{751F}'])),
+    ('\u{1F223}', '\u{1F223}', Mapping::Mapped(&['\u{8CA9}'])),
+    ('\u{1F224}', '\u{1F224}', Mapping::Mapped(&['\u{58F0}'])),
+    ('\u{1F225}', '\u{1F225}', Mapping::Mapped(&['\u{5439}'])),
+    ('\u{1F226}', '\u{1F226}', Mapping::Mapped(&['\u{6F14}'])),
+    ('\u{1F227}', '\u{1F227}', Mapping::Mapped(&['\u{6295}'])),
+    ('\u{1F228}', '\u{1F228}', Mapping::Mapped(&['\u{6355}'])),
+    ('\u{1F229}', '\u{1F229}', Mapping::Mapped(&['\u{4E00}'])),
+    ('\u{1F22A}', '\u{1F22A}', Mapping::Mapped(&['\u{4E09}'])),
+    ('\u{1F22B}', '\u{1F22B}', Mapping::Mapped(&['\u{904A}'])),
+    ('\u{1F22C}', '\u{1F22C}', Mapping::Mapped(&['\u{5DE6}'])),
+    ('\u{1F22D}', '\u{1F22D}', Mapping::Mapped(&['\u{4E2D}'])),
+    ('\u{1F22E}', '\u{1F22E}', Mapping::Mapped(&['\u{53F3}'])),
+    ('\u{1F22F}', '\u{1F22F}', Mapping::Mapped(&['\u{6307}'])),
+    ('\u{1F230}', '\u{1F230}', Mapping::Mapped(&['\u{8D70}'])),
+    ('\u{1F231}', '\u{1F231}', Mapping::Mapped(&['\u{6253}'])),
+    ('\u{1F232}', '\u{1F232}', Mapping::Mapped(&['\u{7981}'])),
+    ('\u{1F233}', '\u{1F233}', Mapping::Mapped(&['\u{7A7A}'])),
+    ('\u{1F234}', '\u{1F234}', Mapping::Mapped(&['\u{5408}'])),
+    ('\u{1F235}', '\u{1F235}', Mapping::Mapped(&['\u{6E80}'])),
+    ('\u{1F236}', '\u{1F236}', Mapping::Mapped(&['\u{6709}'])),
+    ('\u{1F237}', '\u{1F237}', Mapping::Mapped(&['\u{6708}'])),
+    ('\u{1F238}', '\u{1F238}', Mapping::Mapped(&['\u{7533}'])),
+    ('\u{1F239}', '\u{1F239}', Mapping::Mapped(&['\u{5272}'])),
+    ('\u{1F23A}', '\u{1F23A}', Mapping::Mapped(&['\u{55B6}'])),
+    ('\u{1F23B}', '\u{1F23B}', Mapping::Mapped(&['\u{914D}'])),
+    ('\u{1F23C}', '\u{1F23F}', Mapping::Disallowed),
+    ('\u{1F240}', '\u{1F240}', Mapping::Mapped(&['\u{3014}', '\u{672C}', '\u{3015}'])),
+    ('\u{1F241}', '\u{1F241}', Mapping::Mapped(&['\u{3014}', '\u{4E09}', '\u{3015}'])),
+    ('\u{1F242}', '\u{1F242}', Mapping::Mapped(&['\u{3014}', '\u{4E8C}', '\u{3015}'])),
+    ('\u{1F243}', '\u{1F243}', Mapping::Mapped(&['\u{3014}', '\u{5B89}', '\u{3015}'])),
+    ('\u{1F244}', '\u{1F244}', Mapping::Mapped(&['\u{3014}', '\u{70B9}', '\u{3015}'])),
+    ('\u{1F245}', '\u{1F245}', Mapping::Mapped(&['\u{3014}', '\u{6253}', '\u{3015}'])),
+    ('\u{1F246}', '\u{1F246}', Mapping::Mapped(&['\u{3014}', '\u{76D7}', '\u{3015}'])),
+    ('\u{1F247}', '\u{1F247}', Mapping::Mapped(&['\u{3014}', '\u{52DD}', '\u{3015}'])),
+    ('\u{1F248}', '\u{1F248}', Mapping::Mapped(&['\u{3014}', '\u{6557}', '\u{3015}'])),
+    ('\u{1F249}', '\u{1F24F}', Mapping::Disallowed),
+    ('\u{1F250}', '\u{1F250}', Mapping::Mapped(&['\u{5F97}'])),
+    ('\u{1F251}', '\u{1F251}', Mapping::Mapped(&['\u{53EF}'])),
+    ('\u{1F252}', '\u{1F25F}', Mapping::Disallowed),
+    ('\u{1F260}', '\u{1F265}', Mapping::Valid),
+    ('\u{1F266}', '\u{1F2FF}', Mapping::Disallowed),
+    ('\u{1F300}', '\u{1F6D7}', Mapping::Valid),
+    ('\u{1F6D8}', '\u{1F6DF}', Mapping::Disallowed),
+    ('\u{1F6E0}', '\u{1F6EC}', Mapping::Valid),
+    ('\u{1F6ED}', '\u{1F6EF}', Mapping::Disallowed),
+    ('\u{1F6F0}', '\u{1F6FC}', Mapping::Valid),
+    ('\u{1F6FD}', '\u{1F6FF}', Mapping::Disallowed),
+    ('\u{1F700}', '\u{1F773}', Mapping::Valid),
+    ('\u{1F774}', '\u{1F77F}', Mapping::Disallowed),
+    ('\u{1F780}', '\u{1F7D8}', Mapping::Valid),
+    ('\u{1F7D9}', '\u{1F7DF}', Mapping::Disallowed),
+    ('\u{1F7E0}', '\u{1F7EB}', Mapping::Valid),
+    ('\u{1F7EC}', '\u{1F7FF}', Mapping::Disallowed),
+    ('\u{1F800}', '\u{1F80B}', Mapping::Valid),
+    ('\u{1F80C}', '\u{1F80F}', Mapping::Disallowed),
+    ('\u{1F810}', '\u{1F847}', Mapping::Valid),
+    ('\u{1F848}', '\u{1F84F}', Mapping::Disallowed),
+    ('\u{1F850}', '\u{1F859}', Mapping::Valid),
+    ('\u{1F85A}', '\u{1F85F}', Mapping::Disallowed),
+    ('\u{1F860}', '\u{1F887}', Mapping::Valid),
+    ('\u{1F888}', '\u{1F88F}', Mapping::Disallowed),
+    ('\u{1F890}', '\u{1F8AD}', Mapping::Valid),
+    ('\u{1F8AE}', '\u{1F8AF}', Mapping::Disallowed),
+    ('\u{1F8B0}', '\u{1F8B1}', Mapping::Valid),
+    ('\u{1F8B2}', '\u{1F8FF}', Mapping::Disallowed),
+    ('\u{1F900}', '\u{1F978}', Mapping::Valid),
+    ('\u{1F979}', '\u{1F979}', Mapping::Disallowed),
+    ('\u{1F97A}', '\u{1F9CB}', Mapping::Valid),
+    ('\u{1F9CC}', '\u{1F9CC}', Mapping::Disallowed),
+    ('\u{1F9CD}', '\u{1FA53}', Mapping::Valid),
+    ('\u{1FA54}', '\u{1FA5F}', Mapping::Disallowed),
+    ('\u{1FA60}', '\u{1FA6D}', Mapping::Valid),
+    ('\u{1FA6E}', '\u{1FA6F}', Mapping::Disallowed),
+    ('\u{1FA70}', '\u{1FA74}', Mapping::Valid),
+    ('\u{1FA75}', '\u{1FA77}', Mapping::Disallowed),
+    ('\u{1FA78}', '\u{1FA7A}', Mapping::Valid),
+    ('\u{1FA7B}', '\u{1FA7F}', Mapping::Disallowed),
+    ('\u{1FA80}', '\u{1FA86}', Mapping::Valid),
+    ('\u{1FA87}', '\u{1FA8F}', Mapping::Disallowed),
+    ('\u{1FA90}', '\u{1FAA8}', Mapping::Valid),
+    ('\u{1FAA9}', '\u{1FAAF}', Mapping::Disallowed),
+    ('\u{1FAB0}', '\u{1FAB6}', Mapping::Valid),
+    ('\u{1FAB7}', '\u{1FABF}', Mapping::Disallowed),
+    ('\u{1FAC0}', '\u{1FAC2}', Mapping::Valid),
+    ('\u{1FAC3}', '\u{1FACF}', Mapping::Disallowed),
+    ('\u{1FAD0}', '\u{1FAD6}', Mapping::Valid),
+    ('\u{1FAD7}', '\u{1FAFF}', Mapping::Disallowed),
+    ('\u{1FB00}', '\u{1FB92}', Mapping::Valid),
+    ('\u{1FB93}', '\u{1FB93}', Mapping::Disallowed),
+    ('\u{1FB94}', '\u{1FBCA}', Mapping::Valid),
+    ('\u{1FBCB}', '\u{1FBEF}', Mapping::Disallowed),
+    ('\u{1FBF0}', '\u{1FBF0}', Mapping::Mapped(&['\u{30}'])),
+    ('\u{1FBF1}', '\u{1FBF1}', Mapping::Mapped(&['\u{31}'])),
+    ('\u{1FBF2}', '\u{1FBF2}', Mapping::Mapped(&['\u{32}'])),
+    ('\u{1FBF3}', '\u{1FBF3}', Mapping::Mapped(&['\u{33}'])),
+    ('\u{1FBF4}', '\u{1FBF4}', Mapping::Mapped(&['\u{34}'])),
+    ('\u{1FBF5}', '\u{1FBF5}', Mapping::Mapped(&['\u{35}'])),
+    ('\u{1FBF6}', '\u{1FBF6}', Mapping::Mapped(&['\u{36}'])),
+    ('\u{1FBF7}', '\u{1FBF7}', Mapping::Mapped(&['\u{37}'])),
+    ('\u{1FBF8}', '\u{1FBF8}', Mapping::Mapped(&['\u{38}'])),
+    ('\u{1FBF9}', '\u{1FBF9}', Mapping::Mapped(&['\u{39}'])),
+    ('\u{1FBFA}', '\u{1FFFF}', Mapping::Disallowed),
+    ('\u{20000}', '\u{2A6DD}', Mapping::Valid),
+    ('\u{2A6DE}', '\u{2A6FF}', Mapping::Disallowed),
+    ('\u{2A700}', '\u{2B734}', Mapping::Valid),
+    ('\u{2B735}', '\u{2B73F}', Mapping::Disallowed),
+    ('\u{2B740}', '\u{2B81D}', Mapping::Valid),
+    ('\u{2B81E}', '\u{2B81F}', Mapping::Disallowed),
+    ('\u{2B820}', '\u{2CEA1}', Mapping::Valid),
+    ('\u{2CEA2}', '\u{2CEAF}', Mapping::Disallowed),
+    ('\u{2CEB0}', '\u{2EBE0}', Mapping::Valid),
+    ('\u{2EBE1}', '\u{2F7FF}', Mapping::Disallowed),
+    ('\u{2F800}', '\u{2F800}', Mapping::Mapped(&['\u{4E3D}'])),
+    ('\u{2F801}', '\u{2F801}', Mapping::Mapped(&['\u{4E38}'])),
+    ('\u{2F802}', '\u{2F802}', Mapping::Mapped(&['\u{4E41}'])),
+    ('\u{2F803}', '\u{2F803}', Mapping::Mapped(&['\u{20122}'])),
+    ('\u{2F804}', '\u{2F804}', Mapping::Mapped(&['\u{4F60}'])),
+    ('\u{2F805}', '\u{2F805}', Mapping::Mapped(&['\u{4FAE}'])),
+    ('\u{2F806}', '\u{2F806}', Mapping::Mapped(&['\u{4FBB}'])),
+    ('\u{2F807}', '\u{2F807}', Mapping::Mapped(&['\u{5002}'])),
+    ('\u{2F808}', '\u{2F808}', Mapping::Mapped(&['\u{507A}'])),
+    ('\u{2F809}', '\u{2F809}', Mapping::Mapped(&['\u{5099}'])),
+    ('\u{2F80A}', '\u{2F80A}', Mapping::Mapped(&['\u{50E7}'])),
+    ('\u{2F80B}', '\u{2F80B}', Mapping::Mapped(&['\u{50CF}'])),
+    ('\u{2F80C}', '\u{2F80C}', Mapping::Mapped(&['\u{349E}'])),
+    ('\u{2F80D}', '\u{2F80D}', Mapping::Mapped(&['\u{2063A}'])),
+    ('\u{2F80E}', '\u{2F80E}', Mapping::Mapped(&['\u{514D}'])),
+    ('\u{2F80F}', '\u{2F80F}', Mapping::Mapped(&['\u{5154}'])),
+    ('\u{2F810}', '\u{2F810}', Mapping::Mapped(&['\u{5164}'])),
+    ('\u{2F811}', '\u{2F811}', Mapping::Mapped(&['\u{5177}'])),
+    ('\u{2F812}', '\u{2F812}', Mapping::Mapped(&['\u{2051C}'])),
+    ('\u{2F813}', '\u{2F813}', Mapping::Mapped(&['\u{34B9}'])),
+    ('\u{2F814}', '\u{2F814}', Mapping::Mapped(&['\u{5167}'])),
+    ('\u{2F815}', '\u{2F815}', Mapping::Mapped(&['\u{518D}'])),
+    ('\u{2F816}', '\u{2F816}', Mapping::Mapped(&['\u{2054B}'])),
+    ('\u{2F817}', '\u{2F817}', Mapping::Mapped(&['\u{5197}'])),
+    ('\u{2F818}', '\u{2F818}', Mapping::Mapped(&['\u{51A4}'])),
+    ('\u{2F819}', '\u{2F819}', Mapping::Mapped(&['\u{4ECC}'])),
+    ('\u{2F81A}', '\u{2F81A}', Mapping::Mapped(&['\u{51AC}'])),
+    ('\u{2F81B}', '\u{2F81B}', Mapping::Mapped(&['\u{51B5}'])),
+    ('\u{2F81C}', '\u{2F81C}', Mapping::Mapped(&['\u{291DF}'])),
+    ('\u{2F81D}', '\u{2F81D}', Mapping::Mapped(&['\u{51F5}'])),
+    ('\u{2F81E}', '\u{2F81E}', Mapping::Mapped(&['\u{5203}'])),
+    ('\u{2F81F}', '\u{2F81F}', Mapping::Mapped(&['\u{34DF}'])),
+    ('\u{2F820}', '\u{2F820}', Mapping::Mapped(&['\u{523B}'])),
+    ('\u{2F821}', '\u{2F821}', Mapping::Mapped(&['\u{5246}'])),
+    ('\u{2F822}', '\u{2F822}', Mapping::Mapped(&['\u{5272}'])),
+    ('\u{2F823}', '\u{2F823}', Mapping::Mapped(&['\u{5277}'])),
+    ('\u{2F824}', '\u{2F824}', Mapping::Mapped(&['\u{3515}'])),
+    ('\u{2F825}', '\u{2F825}', Mapping::Mapped(&['\u{52C7}'])),
+    ('\u{2F826}', '\u{2F826}', Mapping::Mapped(&['\u{52C9}'])),
+    ('\u{2F827}', '\u{2F827}', Mapping::Mapped(&['\u{52E4}'])),
+    ('\u{2F828}', '\u{2F828}', Mapping::Mapped(&['\u{52FA}'])),
+    ('\u{2F829}', '\u{2F829}', Mapping::Mapped(&['\u{5305}'])),
+    ('\u{2F82A}', '\u{2F82A}', Mapping::Mapped(&['\u{5306}'])),
+    ('\u{2F82B}', '\u{2F82B}', Mapping::Mapped(&['\u{5317}'])),
+    ('\u{2F82C}', '\u{2F82C}', Mapping::Mapped(&['\u{5349}'])),
+    ('\u{2F82D}', '\u{2F82D}', Mapping::Mapped(&['\u{5351}'])),
+    ('\u{2F82E}', '\u{2F82E}', Mapping::Mapped(&['\u{535A}'])),
+    ('\u{2F82F}', '\u{2F82F}', Mapping::Mapped(&['\u{5373}'])),
+    ('\u{2F830}', '\u{2F830}', Mapping::Mapped(&['\u{537D}'])),
+    ('\u{2F831}', '\u{2F833}', Mapping::Mapped(&['\u{537F}'])),
+    ('\u{2F834}', '\u{2F834}', Mapping::Mapped(&['\u{20A2C}'])),
+    ('\u{2F835}', '\u{2F835}', Mapping::Mapped(&['\u{7070}'])),
+    ('\u{2F836}', '\u{2F836}', Mapping::Mapped(&['\u{53CA}'])),
+    ('\u{2F837}', '\u{2F837}', Mapping::Mapped(&['\u{53DF}'])),
+    ('\u{2F838}', '\u{2F838}', Mapping::Mapped(&['\u{20B63}'])),
+    ('\u{2F839}', '\u{2F839}', Mapping::Mapped(&['\u{53EB}'])),
+    ('\u{2F83A}', '\u{2F83A}', Mapping::Mapped(&['\u{53F1}'])),
+    ('\u{2F83B}', '\u{2F83B}', Mapping::Mapped(&['\u{5406}'])),
+    ('\u{2F83C}', '\u{2F83C}', Mapping::Mapped(&['\u{549E}'])),
+    ('\u{2F83D}', '\u{2F83D}', Mapping::Mapped(&['\u{5438}'])),
+    ('\u{2F83E}', '\u{2F83E}', Mapping::Mapped(&['\u{5448}'])),
+    ('\u{2F83F}', '\u{2F83F}', Mapping::Mapped(&['\u{5468}'])),
+    ('\u{2F840}', '\u{2F840}', Mapping::Mapped(&['\u{54A2}'])),
+    ('\u{2F841}', '\u{2F841}', Mapping::Mapped(&['\u{54F6}'])),
+    ('\u{2F842}', '\u{2F842}', Mapping::Mapped(&['\u{5510}'])),
+    ('\u{2F843}', '\u{2F843}', Mapping::Mapped(&['\u{5553}'])),
+    ('\u{2F844}', '\u{2F844}', Mapping::Mapped(&['\u{5563}'])),
+    ('\u{2F845}', '\u{2F846}', Mapping::Mapped(&['\u{5584}'])),
+    ('\u{2F847}', '\u{2F847}', Mapping::Mapped(&['\u{5599}'])),
+    ('\u{2F848}', '\u{2F848}', Mapping::Mapped(&['\u{55AB}'])),
+    ('\u{2F849}', '\u{2F849}', Mapping::Mapped(&['\u{55B3}'])),
+    ('\u{2F84A}', '\u{2F84A}', Mapping::Mapped(&['\u{55C2}'])),
+    ('\u{2F84B}', '\u{2F84B}', Mapping::Mapped(&['\u{5716}'])),
+    ('\u{2F84C}', '\u{2F84C}', Mapping::Mapped(&['\u{5606}'])),
+    ('\u{2F84D}', '\u{2F84D}', Mapping::Mapped(&['\u{5717}'])),
+    ('\u{2F84E}', '\u{2F84E}', Mapping::Mapped(&['\u{5651}'])),
+    ('\u{2F84F}', '\u{2F84F}', Mapping::Mapped(&['\u{5674}'])),
+    ('\u{2F850}', '\u{2F850}', Mapping::Mapped(&['\u{5207}'])),
+    ('\u{2F851}', '\u{2F851}', Mapping::Mapped(&['\u{58EE}'])),
+    ('\u{2F852}', '\u{2F852}', Mapping::Mapped(&['\u{57CE}'])),
+    ('\u{2F853}', '\u{2F853}', Mapping::Mapped(&['\u{57F4}'])),
+    ('\u{2F854}', '\u{2F854}', Mapping::Mapped(&['\u{580D}'])),
+    ('\u{2F855}', '\u{2F855}', Mapping::Mapped(&['\u{578B}'])),
+    ('\u{2F856}', '\u{2F856}', Mapping::Mapped(&['\u{5832}'])),
+    ('\u{2F857}', '\u{2F857}', Mapping::Mapped(&['\u{5831}'])),
+    ('\u{2F858}', '\u{2F858}', Mapping::Mapped(&['\u{58AC}'])),
+    ('\u{2F859}', '\u{2F859}', Mapping::Mapped(&['\u{214E4}'])),
+    ('\u{2F85A}', '\u{2F85A}', Mapping::Mapped(&['\u{58F2}'])),
+    ('\u{2F85B}', '\u{2F85B}', Mapping::Mapped(&['\u{58F7}'])),
+    ('\u{2F85C}', '\u{2F85C}', Mapping::Mapped(&['\u{5906}'])),
+    ('\u{2F85D}', '\u{2F85D}', Mapping::Mapped(&['\u{591A}'])),
+    ('\u{2F85E}', '\u{2F85E}', Mapping::Mapped(&['\u{5922}'])),
+    ('\u{2F85F}', '\u{2F85F}', Mapping::Mapped(&['\u{5962}'])),
+    ('\u{2F860}', '\u{2F860}', Mapping::Mapped(&['\u{216A8}'])),
+    ('\u{2F861}', '\u{2F861}', Mapping::Mapped(&['\u{216EA}'])),
+    ('\u{2F862}', '\u{2F862}', Mapping::Mapped(&['\u{59EC}'])),
+    ('\u{2F863}', '\u{2F863}', Mapping::Mapped(&['\u{5A1B}'])),
+    ('\u{2F864}', '\u{2F864}', Mapping::Mapped(&['\u{5A27}'])),
+    ('\u{2F865}', '\u{2F865}', Mapping::Mapped(&['\u{59D8}'])),
+    ('\u{2F866}', '\u{2F866}', Mapping::Mapped(&['\u{5A66}'])),
+    ('\u{2F867}', '\u{2F867}', Mapping::Mapped(&['\u{36EE}'])),
+    ('\u{2F868}', '\u{2F868}', Mapping::Disallowed),
+    ('\u{2F869}', '\u{2F869}', Mapping::Mapped(&['\u{5B08}'])),
+    ('\u{2F86A}', '\u{2F86B}', Mapping::Mapped(&['\u{5B3E}'])),
+    ('\u{2F86C}', '\u{2F86C}', Mapping::Mapped(&['\u{219C8}'])),
+    ('\u{2F86D}', '\u{2F86D}', Mapping::Mapped(&['\u{5BC3}'])),
+    ('\u{2F86E}', '\u{2F86E}', Mapping::Mapped(&['\u{5BD8}'])),
+    ('\u{2F86F}', '\u{2F86F}', Mapping::Mapped(&['\u{5BE7}'])),
+    ('\u{2F870}', '\u{2F870}', Mapping::Mapped(&['\u{5BF3}'])),
+    ('\u{2F871}', '\u{2F871}', Mapping::Mapped(&['\u{21B18}'])),
+    ('\u{2F872}', '\u{2F872}', Mapping::Mapped(&['\u{5BFF}'])),
+    ('\u{2F873}', '\u{2F873}', Mapping::Mapped(&['\u{5C06}'])),
+    ('\u{2F874}', '\u{2F874}', Mapping::Disallowed),
+    ('\u{2F875}', '\u{2F875}', Mapping::Mapped(&['\u{5C22}'])),
+    ('\u{2F876}', '\u{2F876}', Mapping::Mapped(&['\u{3781}'])),
+    ('\u{2F877}', '\u{2F877}', Mapping::Mapped(&['\u{5C60}'])),
+    ('\u{2F878}', '\u{2F878}', Mapping::Mapped(&['\u{5C6E}'])),
+    ('\u{2F879}', '\u{2F879}', Mapping::Mapped(&['\u{5CC0}'])),
+    ('\u{2F87A}', '\u{2F87A}', Mapping::Mapped(&['\u{5C8D}'])),
+    ('\u{2F87B}', '\u{2F87B}', Mapping::Mapped(&['\u{21DE4}'])),
+    ('\u{2F87C}', '\u{2F87C}', Mapping::Mapped(&['\u{5D43}'])),
+    ('\u{2F87D}', '\u{2F87D}', Mapping::Mapped(&['\u{21DE6}'])),
+    ('\u{2F87E}', '\u{2F87E}', Mapping::Mapped(&['\u{5D6E}'])),
+    ('\u{2F87F}', '\u{2F87F}', Mapping::Mapped(&['\u{5D6B}'])),
+    ('\u{2F880}', '\u{2F880}', Mapping::Mapped(&['\u{5D7C}'])),
+    ('\u{2F881}', '\u{2F881}', Mapping::Mapped(&['\u{5DE1}'])),
+    ('\u{2F882}', '\u{2F882}', Mapping::Mapped(&['\u{5DE2}'])),
+    ('\u{2F883}', '\u{2F883}', Mapping::Mapped(&['\u{382F}'])),
+    ('\u{2F884}', '\u{2F884}', Mapping::Mapped(&['\u{5DFD}'])),
+    ('\u{2F885}', '\u{2F885}', Mapping::Mapped(&['\u{5E28}'])),
+    ('\u{2F886}', '\u{2F886}', Mapping::Mapped(&['\u{5E3D}'])),
+    ('\u{2F887}', '\u{2F887}', Mapping::Mapped(&['\u{5E69}'])),
+    ('\u{2F888}', '\u{2F888}', Mapping::Mapped(&['\u{3862}'])),
+    ('\u{2F889}', '\u{2F889}', Mapping::Mapped(&['\u{22183}'])),
+    ('\u{2F88A}', '\u{2F88A}', Mapping::Mapped(&['\u{387C}'])),
+    ('\u{2F88B}', '\u{2F88B}', Mapping::Mapped(&['\u{5EB0}'])),
+    ('\u{2F88C}', '\u{2F88C}', Mapping::Mapped(&['\u{5EB3}'])),
+    ('\u{2F88D}', '\u{2F88D}', Mapping::Mapped(&['\u{5EB6}'])),
+    ('\u{2F88E}', '\u{2F88E}', Mapping::Mapped(&['\u{5ECA}'])),
+    ('\u{2F88F}', '\u{2F88F}', Mapping::Mapped(&['\u{2A392}'])),
+    ('\u{2F890}', '\u{2F890}', Mapping::Mapped(&['\u{5EFE}'])),
+    ('\u{2F891}', '\u{2F892}', Mapping::Mapped(&['\u{22331}'])),
+    ('\u{2F893}', '\u{2F893}', Mapping::Mapped(&['\u{8201}'])),
+    ('\u{2F894}', '\u{2F895}', Mapping::Mapped(&['\u{5F22}'])),
+    ('\u{2F896}', '\u{2F896}', Mapping::Mapped(&['\u{38C7}'])),
+    ('\u{2F897}', '\u{2F897}', Mapping::Mapped(&['\u{232B8}'])),
+    ('\u{2F898}', '\u{2F898}', Mapping::Mapped(&['\u{261DA}'])),
+    ('\u{2F899}', '\u{2F899}', Mapping::Mapped(&['\u{5F62}'])),
+    ('\u{2F89A}', '\u{2F89A}', Mapping::Mapped(&['\u{5F6B}'])),
+    ('\u{2F89B}', '\u{2F89B}', Mapping::Mapped(&['\u{38E3}'])),
+    ('\u{2F89C}', '\u{2F89C}', Mapping::Mapped(&['\u{5F9A}'])),
+    ('\u{2F89D}', '\u{2F89D}', Mapping::Mapped(&['\u{5FCD}'])),
+    ('\u{2F89E}', '\u{2F89E}', Mapping::Mapped(&['\u{5FD7}'])),
+    ('\u{2F89F}', '\u{2F89F}', Mapping::Mapped(&['\u{5FF9}'])),
+    ('\u{2F8A0}', '\u{2F8A0}', Mapping::Mapped(&['\u{6081}'])),
+    ('\u{2F8A1}', '\u{2F8A1}', Mapping::Mapped(&['\u{393A}'])),
+    ('\u{2F8A2}', '\u{2F8A2}', Mapping::Mapped(&['\u{391C}'])),
+    ('\u{2F8A3}', '\u{2F8A3}', Mapping::Mapped(&['\u{6094}'])),
+    ('\u{2F8A4}', '\u{2F8A4}', Mapping::Mapped(&['\u{226D4}'])),
+    ('\u{2F8A5}', '\u{2F8A5}', Mapping::Mapped(&['\u{60C7}'])),
+    ('\u{2F8A6}', '\u{2F8A6}', Mapping::Mapped(&['\u{6148}'])),
+    ('\u{2F8A7}', '\u{2F8A7}', Mapping::Mapped(&['\u{614C}'])),
+    ('\u{2F8A8}', '\u{2F8A8}', Mapping::Mapped(&['\u{614E}'])),
+    ('\u{2F8A9}', '\u{2F8A9}', Mapping::Mapped(&['\u{614C}'])),
+    ('\u{2F8AA}', '\u{2F8AA}', Mapping::Mapped(&['\u{617A}'])),
+    ('\u{2F8AB}', '\u{2F8AB}', Mapping::Mapped(&['\u{618E}'])),
+    ('\u{2F8AC}', '\u{2F8AC}', Mapping::Mapped(&['\u{61B2}'])),
+    ('\u{2F8AD}', '\u{2F8AD}', Mapping::Mapped(&['\u{61A4}'])),
+    ('\u{2F8AE}', '\u{2F8AE}', Mapping::Mapped(&['\u{61AF}'])),
+    ('\u{2F8AF}', '\u{2F8AF}', Mapping::Mapped(&['\u{61DE}'])),
+    ('\u{2F8B0}', '\u{2F8B0}', Mapping::Mapped(&['\u{61F2}'])),
+    ('\u{2F8B1}', '\u{2F8B1}', Mapping::Mapped(&['\u{61F6}'])),
+    ('\u{2F8B2}', '\u{2F8B2}', Mapping::Mapped(&['\u{6210}'])),
+    ('\u{2F8B3}', '\u{2F8B3}', Mapping::Mapped(&['\u{621B}'])),
+    ('\u{2F8B4}', '\u{2F8B4}', Mapping::Mapped(&['\u{625D}'])),
+    ('\u{2F8B5}', '\u{2F8B5}', Mapping::Mapped(&['\u{62B1}'])),
+    ('\u{2F8B6}', '\u{2F8B6}', Mapping::Mapped(&['\u{62D4}'])),
+    ('\u{2F8B7}', '\u{2F8B7}', Mapping::Mapped(&['\u{6350}'])),
+    ('\u{2F8B8}', '\u{2F8B8}', Mapping::Mapped(&['\u{22B0C}'])),
+    ('\u{2F8B9}', '\u{2F8B9}', Mapping::Mapped(&['\u{633D}'])),
+    ('\u{2F8BA}', '\u{2F8BA}', Mapping::Mapped(&['\u{62FC}'])),
+    ('\u{2F8BB}', '\u{2F8BB}', Mapping::Mapped(&['\u{6368}'])),
+    ('\u{2F8BC}', '\u{2F8BC}', Mapping::Mapped(&['\u{6383}'])),
+    ('\u{2F8BD}', '\u{2F8BD}', Mapping::Mapped(&['\u{63E4}'])),
+    ('\u{2F8BE}', '\u{2F8BE}', Mapping::Mapped(&['\u{22BF1}'])),
+    ('\u{2F8BF}', '\u{2F8BF}', Mapping::Mapped(&['\u{6422}'])),
+    ('\u{2F8C0}', '\u{2F8C0}', Mapping::Mapped(&['\u{63C5}'])),
+    ('\u{2F8C1}', '\u{2F8C1}', Mapping::Mapped(&['\u{63A9}'])),
+    ('\u{2F8C2}', '\u{2F8C2}', Mapping::Mapped(&['\u{3A2E}'])),
+    ('\u{2F8C3}', '\u{2F8C3}', Mapping::Mapped(&['\u{6469}'])),
+    ('\u{2F8C4}', '\u{2F8C4}', Mapping::Mapped(&['\u{647E}'])),
+    ('\u{2F8C5}', '\u{2F8C5}', Mapping::Mapped(&['\u{649D}'])),
+    ('\u{2F8C6}', '\u{2F8C6}', Mapping::Mapped(&['\u{6477}'])),
+    ('\u{2F8C7}', '\u{2F8C7}', Mapping::Mapped(&['\u{3A6C}'])),
+    ('\u{2F8C8}', '\u{2F8C8}', Mapping::Mapped(&['\u{654F}'])),
+    ('\u{2F8C9}', '\u{2F8C9}', Mapping::Mapped(&['\u{656C}'])),
+    ('\u{2F8CA}', '\u{2F8CA}', Mapping::Mapped(&['\u{2300A}'])),
+    ('\u{2F8CB}', '\u{2F8CB}', Mapping::Mapped(&['\u{65E3}'])),
+    ('\u{2F8CC}', '\u{2F8CC}', Mapping::Mapped(&['\u{66F8}'])),
+    ('\u{2F8CD}', '\u{2F8CD}', Mapping::Mapped(&['\u{6649}'])),
+    ('\u{2F8CE}', '\u{2F8CE}', Mapping::Mapped(&['\u{3B19}'])),
+    ('\u{2F8CF}', '\u{2F8CF}', Mapping::Mapped(&['\u{6691}'])),
+    ('\u{2F8D0}', '\u{2F8D0}', Mapping::Mapped(&['\u{3B08}'])),
+    ('\u{2F8D1}', '\u{2F8D1}', Mapping::Mapped(&['\u{3AE4}'])),
+    ('\u{2F8D2}', '\u{2F8D2}', Mapping::Mapped(&['\u{5192}'])),
+    ('\u{2F8D3}', '\u{2F8D3}', Mapping::Mapped(&['\u{5195}'])),
+    ('\u{2F8D4}', '\u{2F8D4}', Mapping::Mapped(&['\u{6700}'])),
+    ('\u{2F8D5}', '\u{2F8D5}', Mapping::Mapped(&['\u{669C}'])),
+    ('\u{2F8D6}', '\u{2F8D6}', Mapping::Mapped(&['\u{80AD}'])),
+    ('\u{2F8D7}', '\u{2F8D7}', Mapping::Mapped(&['\u{43D9}'])),
+    ('\u{2F8D8}', '\u{2F8D8}', Mapping::Mapped(&['\u{6717}'])),
+    ('\u{2F8D9}', '\u{2F8D9}', Mapping::Mapped(&['\u{671B}'])),
+    ('\u{2F8DA}', '\u{2F8DA}', Mapping::Mapped(&['\u{6721}'])),
+    ('\u{2F8DB}', '\u{2F8DB}', Mapping::Mapped(&['\u{675E}'])),
+    ('\u{2F8DC}', '\u{2F8DC}', Mapping::Mapped(&['\u{6753}'])),
+    ('\u{2F8DD}', '\u{2F8DD}', Mapping::Mapped(&['\u{233C3}'])),
+    ('\u{2F8DE}', '\u{2F8DE}', Mapping::Mapped(&['\u{3B49}'])),
+    ('\u{2F8DF}', '\u{2F8DF}', Mapping::Mapped(&['\u{67FA}'])),
+    ('\u{2F8E0}', '\u{2F8E0}', Mapping::Mapped(&['\u{6785}'])),
+    ('\u{2F8E1}', '\u{2F8E1}', Mapping::Mapped(&['\u{6852}'])),
+    ('\u{2F8E2}', '\u{2F8E2}', Mapping::Mapped(&['\u{6885}'])),
+    ('\u{2F8E3}', '\u{2F8E3}', Mapping::Mapped(&['\u{2346D}'])),
+    ('\u{2F8E4}', '\u{2F8E4}', Mapping::Mapped(&['\u{688E}'])),
+    ('\u{2F8E5}', '\u{2F8E5}', Mapping::Mapped(&['\u{681F}'])),
+    ('\u{2F8E6}', '\u{2F8E6}', Mapping::Mapped(&['\u{6914}'])),
+    ('\u{2F8E7}', '\u{2F8E7}', Mapping::Mapped(&['\u{3B9D}'])),
+    ('\u{2F8E8}', '\u{2F8E8}', Mapping::Mapped(&['\u{6942}'])),
+    ('\u{2F8E9}', '\u{2F8E9}', Mapping::Mapped(&['\u{69A3}'])),
+    ('\u{2F8EA}', '\u{2F8EA}', Mapping::Mapped(&['\u{69EA}'])),
+    ('\u{2F8EB}', '\u{2F8EB}', Mapping::Mapped(&['\u{6AA8}'])),
+    ('\u{2F8EC}', '\u{2F8EC}', Mapping::Mapped(&['\u{236A3}'])),
+    ('\u{2F8ED}', '\u{2F8ED}', Mapping::Mapped(&['\u{6ADB}'])),
+    ('\u{2F8EE}', '\u{2F8EE}', Mapping::Mapped(&['\u{3C18}'])),
+    ('\u{2F8EF}', '\u{2F8EF}', Mapping::Mapped(&['\u{6B21}'])),
+    ('\u{2F8F0}', '\u{2F8F0}', Mapping::Mapped(&['\u{238A7}'])),
+    ('\u{2F8F1}', '\u{2F8F1}', Mapping::Mapped(&['\u{6B54}'])),
+    ('\u{2F8F2}', '\u{2F8F2}', Mapping::Mapped(&['\u{3C4E}'])),
+    ('\u{2F8F3}', '\u{2F8F3}', Mapping::Mapped(&['\u{6B72}'])),
+    ('\u{2F8F4}', '\u{2F8F4}', Mapping::Mapped(&['\u{6B9F}'])),
+    ('\u{2F8F5}', '\u{2F8F5}', Mapping::Mapped(&['\u{6BBA}'])),
+    ('\u{2F8F6}', '\u{2F8F6}', Mapping::Mapped(&['\u{6BBB}'])),
+    ('\u{2F8F7}', '\u{2F8F7}', Mapping::Mapped(&['\u{23A8D}'])),
+    ('\u{2F8F8}', '\u{2F8F8}', Mapping::Mapped(&['\u{21D0B}'])),
+    ('\u{2F8F9}', '\u{2F8F9}', Mapping::Mapped(&['\u{23AFA}'])),
+    ('\u{2F8FA}', '\u{2F8FA}', Mapping::Mapped(&['\u{6C4E}'])),
+    ('\u{2F8FB}', '\u{2F8FB}', Mapping::Mapped(&['\u{23CBC}'])),
+    ('\u{2F8FC}', '\u{2F8FC}', Mapping::Mapped(&['\u{6CBF}'])),
+    ('\u{2F8FD}', '\u{2F8FD}', Mapping::Mapped(&['\u{6CCD}'])),
+    ('\u{2F8FE}', '\u{2F8FE}', Mapping::Mapped(&['\u{6C67}'])),
+    ('\u{2F8FF}', '\u{2F8FF}', Mapping::Mapped(&['\u{6D16}'])),
+    ('\u{2F900}', '\u{2F900}', Mapping::Mapped(&['\u{6D3E}'])),
+    ('\u{2F901}', '\u{2F901}', Mapping::Mapped(&['\u{6D77}'])),
+    ('\u{2F902}', '\u{2F902}', Mapping::Mapped(&['\u{6D41}'])),
+    ('\u{2F903}', '\u{2F903}', Mapping::Mapped(&['\u{6D69}'])),
+    ('\u{2F904}', '\u{2F904}', Mapping::Mapped(&['\u{6D78}'])),
+    ('\u{2F905}', '\u{2F905}', Mapping::Mapped(&['\u{6D85}'])),
+    ('\u{2F906}', '\u{2F906}', Mapping::Mapped(&['\u{23D1E}'])),
+    ('\u{2F907}', '\u{2F907}', Mapping::Mapped(&['\u{6D34}'])),
+    ('\u{2F908}', '\u{2F908}', Mapping::Mapped(&['\u{6E2F}'])),
+    ('\u{2F909}', '\u{2F909}', Mapping::Mapped(&['\u{6E6E}'])),
+    ('\u{2F90A}', '\u{2F90A}', Mapping::Mapped(&['\u{3D33}'])),
+    ('\u{2F90B}', '\u{2F90B}', Mapping::Mapped(&['\u{6ECB}'])),
+    ('\u{2F90C}', '\u{2F90C}', Mapping::Mapped(&['\u{6EC7}'])),
+    ('\u{2F90D}', '\u{2F90D}', Mapping::Mapped(&['\u{23ED1}'])),
+    ('\u{2F90E}', '\u{2F90E}', Mapping::Mapped(&['\u{6DF9}'])),
+    ('\u{2F90F}', '\u{2F90F}', Mapping::Mapped(&['\u{6F6E}'])),
+    ('\u{2F910}', '\u{2F910}', Mapping::Mapped(&['\u{23F5E}'])),
+    ('\u{2F911}', '\u{2F911}', Mapping::Mapped(&['\u{23F8E}'])),
+    ('\u{2F912}', '\u{2F912}', Mapping::Mapped(&['\u{6FC6}'])),
+    ('\u{2F913}', '\u{2F913}', Mapping::Mapped(&['\u{7039}'])),
+    ('\u{2F914}', '\u{2F914}', Mapping::Mapped(&['\u{701E}'])),
+    ('\u{2F915}', '\u{2F915}', Mapping::Mapped(&['\u{701B}'])),
+    ('\u{2F916}', '\u{2F916}', Mapping::Mapped(&['\u{3D96}'])),
+    ('\u{2F917}', '\u{2F917}', Mapping::Mapped(&['\u{704A}'])),
+    ('\u{2F918}', '\u{2F918}', Mapping::Mapped(&['\u{707D}'])),
+    ('\u{2F919}', '\u{2F919}', Mapping::Mapped(&['\u{7077}'])),
+    ('\u{2F91A}', '\u{2F91A}', Mapping::Mapped(&['\u{70AD}'])),
+    ('\u{2F91B}', '\u{2F91B}', Mapping::Mapped(&['\u{20525}'])),
+    ('\u{2F91C}', '\u{2F91C}', Mapping::Mapped(&['\u{7145}'])),
+    ('\u{2F91D}', '\u{2F91D}', Mapping::Mapped(&['\u{24263}'])),
+    ('\u{2F91E}', '\u{2F91E}', Mapping::Mapped(&['\u{719C}'])),
+    ('\u{2F91F}', '\u{2F91F}', Mapping::Disallowed),
+    ('\u{2F920}', '\u{2F920}', Mapping::Mapped(&['\u{7228}'])),
+    ('\u{2F921}', '\u{2F921}', Mapping::Mapped(&['\u{7235}'])),
+    ('\u{2F922}', '\u{2F922}', Mapping::Mapped(&['\u{7250}'])),
+    ('\u{2F923}', '\u{2F923}', Mapping::Mapped(&['\u{24608}'])),
+    ('\u{2F924}', '\u{2F924}', Mapping::Mapped(&['\u{7280}'])),
+    ('\u{2F925}', '\u{2F925}', Mapping::Mapped(&['\u{7295}'])),
+    ('\u{2F926}', '\u{2F926}', Mapping::Mapped(&['\u{24735}'])),
+    ('\u{2F927}', '\u{2F927}', Mapping::Mapped(&['\u{24814}'])),
+    ('\u{2F928}', '\u{2F928}', Mapping::Mapped(&['\u{737A}'])),
+    ('\u{2F929}', '\u{2F929}', Mapping::Mapped(&['\u{738B}'])),
+    ('\u{2F92A}', '\u{2F92A}', Mapping::Mapped(&['\u{3EAC}'])),
+    ('\u{2F92B}', '\u{2F92B}', Mapping::Mapped(&['\u{73A5}'])),
+    ('\u{2F92C}', '\u{2F92D}', Mapping::Mapped(&['\u{3EB8}'])),
+    ('\u{2F92E}', '\u{2F92E}', Mapping::Mapped(&['\u{7447}'])),
+    ('\u{2F92F}', '\u{2F92F}', Mapping::Mapped(&['\u{745C}'])),
+    ('\u{2F930}', '\u{2F930}', Mapping::Mapped(&['\u{7471}'])),
+    ('\u{2F931}', '\u{2F931}', Mapping::Mapped(&['\u{7485}'])),
+    ('\u{2F932}', '\u{2F932}', Mapping::Mapped(&['\u{74CA}'])),
+    ('\u{2F933}', '\u{2F933}', Mapping::Mapped(&['\u{3F1B}'])),
+    ('\u{2F934}', '\u{2F934}', Mapping::Mapped(&['\u{7524}'])),
+    ('\u{2F935}', '\u{2F935}', Mapping::Mapped(&['\u{24C36}'])),
+    ('\u{2F936}', '\u{2F936}', Mapping::Mapped(&['\u{753E}'])),
+    ('\u{2F937}', '\u{2F937}', Mapping::Mapped(&['\u{24C92}'])),
+    ('\u{2F938}', '\u{2F938}', Mapping::Mapped(&['\u{7570}'])),
+    ('\u{2F939}', '\u{2F939}', Mapping::Mapped(&['\u{2219F}'])),
+    ('\u{2F93A}', '\u{2F93A}', Mapping::Mapped(&['\u{7610}'])),
+    ('\u{2F93B}', '\u{2F93B}', Mapping::Mapped(&['\u{24FA1}'])),
+    ('\u{2F93C}', '\u{2F93C}', Mapping::Mapped(&['\u{24FB8}'])),
+    ('\u{2F93D}', '\u{2F93D}', Mapping::Mapped(&['\u{25044}'])),
+    ('\u{2F93E}', '\u{2F93E}', Mapping::Mapped(&['\u{3FFC}'])),
+    ('\u{2F93F}', '\u{2F93F}', Mapping::Mapped(&['\u{4008}'])),
+    ('\u{2F940}', '\u{2F940}', Mapping::Mapped(&['\u{76F4}'])),
+    ('\u{2F941}', '\u{2F941}', Mapping::Mapped(&['\u{250F3}'])),
+    ('\u{2F942}', '\u{2F942}', Mapping::Mapped(&['\u{250F2}'])),
+    ('\u{2F943}', '\u{2F943}', Mapping::Mapped(&['\u{25119}'])),
+    ('\u{2F944}', '\u{2F944}', Mapping::Mapped(&['\u{25133}'])),
+    ('\u{2F945}', '\u{2F945}', Mapping::Mapped(&['\u{771E}'])),
+    ('\u{2F946}', '\u{2F947}', Mapping::Mapped(&['\u{771F}'])),
+    ('\u{2F948}', '\u{2F948}', Mapping::Mapped(&['\u{774A}'])),
+    ('\u{2F949}', '\u{2F949}', Mapping::Mapped(&['\u{4039}'])),
+    ('\u{2F94A}', '\u{2F94A}', Mapping::Mapped(&['\u{778B}'])),
+    ('\u{2F94B}', '\u{2F94B}', Mapping::Mapped(&['\u{4046}'])),
+    ('\u{2F94C}', '\u{2F94C}', Mapping::Mapped(&['\u{4096}'])),
+    ('\u{2F94D}', '\u{2F94D}', Mapping::Mapped(&['\u{2541D}'])),
+    ('\u{2F94E}', '\u{2F94E}', Mapping::Mapped(&['\u{784E}'])),
+    ('\u{2F94F}', '\u{2F94F}', Mapping::Mapped(&['\u{788C}'])),
+    ('\u{2F950}', '\u{2F950}', Mapping::Mapped(&['\u{78CC}'])),
+    ('\u{2F951}', '\u{2F951}', Mapping::Mapped(&['\u{40E3}'])),
+    ('\u{2F952}', '\u{2F952}', Mapping::Mapped(&['\u{25626}'])),
+    ('\u{2F953}', '\u{2F953}', Mapping::Mapped(&['\u{7956}'])),
+    ('\u{2F954}', '\u{2F954}', Mapping::Mapped(&['\u{2569A}'])),
+    ('\u{2F955}', '\u{2F955}', Mapping::Mapped(&['\u{256C5}'])),
+    ('\u{2F956}', '\u{2F956}', Mapping::Mapped(&['\u{798F}'])),
+    ('\u{2F957}', '\u{2F957}', Mapping::Mapped(&['\u{79EB}'])),
+    ('\u{2F958}', '\u{2F958}', Mapping::Mapped(&['\u{412F}'])),
+    ('\u{2F959}', '\u{2F959}', Mapping::Mapped(&['\u{7A40}'])),
+    ('\u{2F95A}', '\u{2F95A}', Mapping::Mapped(&['\u{7A4A}'])),
+    ('\u{2F95B}', '\u{2F95B}', Mapping::Mapped(&['\u{7A4F}'])),
+    ('\u{2F95C}', '\u{2F95C}', Mapping::Mapped(&['\u{2597C}'])),
+    ('\u{2F95D}', '\u{2F95E}', Mapping::Mapped(&['\u{25AA7}'])),
+    ('\u{2F95F}', '\u{2F95F}', Mapping::Disallowed),
+    ('\u{2F960}', '\u{2F960}', Mapping::Mapped(&['\u{4202}'])),
+    ('\u{2F961}', '\u{2F961}', Mapping::Mapped(&['\u{25BAB}'])),
+    ('\u{2F962}', '\u{2F962}', Mapping::Mapped(&['\u{7BC6}'])),
+    ('\u{2F963}', '\u{2F963}', Mapping::Mapped(&['\u{7BC9}'])),
+    ('\u{2F964}', '\u{2F964}', Mapping::Mapped(&['\u{4227}'])),
+    ('\u{2F965}', '\u{2F965}', Mapping::Mapped(&['\u{25C80}'])),
+    ('\u{2F966}', '\u{2F966}', Mapping::Mapped(&['\u{7CD2}'])),
+    ('\u{2F967}', '\u{2F967}', Mapping::Mapped(&['\u{42A0}'])),
+    ('\u{2F968}', '\u{2F968}', Mapping::Mapped(&['\u{7CE8}'])),
+    ('\u{2F969}', '\u{2F969}', Mapping::Mapped(&['\u{7CE3}'])),
+    ('\u{2F96A}', '\u{2F96A}', Mapping::Mapped(&['\u{7D00}'])),
+    ('\u{2F96B}', '\u{2F96B}', Mapping::Mapped(&['\u{25F86}'])),
+    ('\u{2F96C}', '\u{2F96C}', Mapping::Mapped(&['\u{7D63}'])),
+    ('\u{2F96D}', '\u{2F96D}', Mapping::Mapped(&['\u{4301}'])),
+    ('\u{2F96E}', '\u{2F96E}', Mapping::Mapped(&['\u{7DC7}'])),
+    ('\u{2F96F}', '\u{2F96F}', Mapping::Mapped(&['\u{7E02}'])),
+    ('\u{2F970}', '\u{2F970}', Mapping::Mapped(&['\u{7E45}'])),
+    ('\u{2F971}', '\u{2F971}', Mapping::Mapped(&['\u{4334}'])),
+    ('\u{2F972}', '\u{2F972}', Mapping::Mapped(&['\u{26228}'])),
+    ('\u{2F973}', '\u{2F973}', Mapping::Mapped(&['\u{26247}'])),
+    ('\u{2F974}', '\u{2F974}', Mapping::Mapped(&['\u{4359}'])),
+    ('\u{2F975}', '\u{2F975}', Mapping::Mapped(&['\u{262D9}'])),
+    ('\u{2F976}', '\u{2F976}', Mapping::Mapped(&['\u{7F7A}'])),
+    ('\u{2F977}', '\u{2F977}', Mapping::Mapped(&['\u{2633E}'])),
+    ('\u{2F978}', '\u{2F978}', Mapping::Mapped(&['\u{7F95}'])),
+    ('\u{2F979}', '\u{2F979}', Mapping::Mapped(&['\u{7FFA}'])),
+    ('\u{2F97A}', '\u{2F97A}', Mapping::Mapped(&['\u{8005}'])),
+    ('\u{2F97B}', '\u{2F97B}', Mapping::Mapped(&['\u{264DA}'])),
+    ('\u{2F97C}', '\u{2F97C}', Mapping::Mapped(&['\u{26523}'])),
+    ('\u{2F97D}', '\u{2F97D}', Mapping::Mapped(&['\u{8060}'])),
+    ('\u{2F97E}', '\u{2F97E}', Mapping::Mapped(&['\u{265A8}'])),
+    ('\u{2F97F}', '\u{2F97F}', Mapping::Mapped(&['\u{8070}'])),
+    ('\u{2F980}', '\u{2F980}', Mapping::Mapped(&['\u{2335F}'])),
+    ('\u{2F981}', '\u{2F981}', Mapping::Mapped(&['\u{43D5}'])),
+    ('\u{2F982}', '\u{2F982}', Mapping::Mapped(&['\u{80B2}'])),
+    ('\u{2F983}', '\u{2F983}', Mapping::Mapped(&['\u{8103}'])),
+    ('\u{2F984}', '\u{2F984}', Mapping::Mapped(&['\u{440B}'])),
+    ('\u{2F985}', '\u{2F985}', Mapping::Mapped(&['\u{813E}'])),
+    ('\u{2F986}', '\u{2F986}', Mapping::Mapped(&['\u{5AB5}'])),
+    ('\u{2F987}', '\u{2F987}', Mapping::Mapped(&['\u{267A7}'])),
+    ('\u{2F988}', '\u{2F988}', Mapping::Mapped(&['\u{267B5}'])),
+    ('\u{2F989}', '\u{2F989}', Mapping::Mapped(&['\u{23393}'])),
+    ('\u{2F98A}', '\u{2F98A}', Mapping::Mapped(&['\u{2339C}'])),
+    ('\u{2F98B}', '\u{2F98B}', Mapping::Mapped(&['\u{8201}'])),
+    ('\u{2F98C}', '\u{2F98C}', Mapping::Mapped(&['\u{8204}'])),
+    ('\u{2F98D}', '\u{2F98D}', Mapping::Mapped(&['\u{8F9E}'])),
+    ('\u{2F98E}', '\u{2F98E}', Mapping::Mapped(&['\u{446B}'])),
+    ('\u{2F98F}', '\u{2F98F}', Mapping::Mapped(&['\u{8291}'])),
+    ('\u{2F990}', '\u{2F990}', Mapping::Mapped(&['\u{828B}'])),
+    ('\u{2F991}', '\u{2F991}', Mapping::Mapped(&['\u{829D}'])),
+    ('\u{2F992}', '\u{2F992}', Mapping::Mapped(&['\u{52B3}'])),
+    ('\u{2F993}', '\u{2F993}', Mapping::Mapped(&['\u{82B1}'])),
+    ('\u{2F994}', '\u{2F994}', Mapping::Mapped(&['\u{82B3}'])),
+    ('\u{2F995}', '\u{2F995}', Mapping::Mapped(&['\u{82BD}'])),
+    ('\u{2F996}', '\u{2F996}', Mapping::Mapped(&['\u{82E6}'])),
+    ('\u{2F997}', '\u{2F997}', Mapping::Mapped(&['\u{26B3C}'])),
+    ('\u{2F998}', '\u{2F998}', Mapping::Mapped(&['\u{82E5}'])),
+    ('\u{2F999}', '\u{2F999}', Mapping::Mapped(&['\u{831D}'])),
+    ('\u{2F99A}', '\u{2F99A}', Mapping::Mapped(&['\u{8363}'])),
+    ('\u{2F99B}', '\u{2F99B}', Mapping::Mapped(&['\u{83AD}'])),
+    ('\u{2F99C}', '\u{2F99C}', Mapping::Mapped(&['\u{8323}'])),
+    ('\u{2F99D}', '\u{2F99D}', Mapping::Mapped(&['\u{83BD}'])),
+    ('\u{2F99E}', '\u{2F99E}', Mapping::Mapped(&['\u{83E7}'])),
+    ('\u{2F99F}', '\u{2F99F}', Mapping::Mapped(&['\u{8457}'])),
+    ('\u{2F9A0}', '\u{2F9A0}', Mapping::Mapped(&['\u{8353}'])),
+    ('\u{2F9A1}', '\u{2F9A1}', Mapping::Mapped(&['\u{83CA}'])),
+    ('\u{2F9A2}', '\u{2F9A2}', Mapping::Mapped(&['\u{83CC}'])),
+    ('\u{2F9A3}', '\u{2F9A3}', Mapping::Mapped(&['\u{83DC}'])),
+    ('\u{2F9A4}', '\u{2F9A4}', Mapping::Mapped(&['\u{26C36}'])),
+    ('\u{2F9A5}', '\u{2F9A5}', Mapping::Mapped(&['\u{26D6B}'])),
+    ('\u{2F9A6}', '\u{2F9A6}', Mapping::Mapped(&['\u{26CD5}'])),
+    ('\u{2F9A7}', '\u{2F9A7}', Mapping::Mapped(&['\u{452B}'])),
+    ('\u{2F9A8}', '\u{2F9A8}', Mapping::Mapped(&['\u{84F1}'])),
+    ('\u{2F9A9}', '\u{2F9A9}', Mapping::Mapped(&['\u{84F3}'])),
+    ('\u{2F9AA}', '\u{2F9AA}', Mapping::Mapped(&['\u{8516}'])),
+    ('\u{2F9AB}', '\u{2F9AB}', Mapping::Mapped(&['\u{273CA}'])),
+    ('\u{2F9AC}', '\u{2F9AC}', Mapping::Mapped(&['\u{8564}'])),
+    ('\u{2F9AD}', '\u{2F9AD}', Mapping::Mapped(&['\u{26F2C}'])),
+    ('\u{2F9AE}', '\u{2F9AE}', Mapping::Mapped(&['\u{455D}'])),
+    ('\u{2F9AF}', '\u{2F9AF}', Mapping::Mapped(&['\u{4561}'])),
+    ('\u{2F9B0}', '\u{2F9B0}', Mapping::Mapped(&['\u{26FB1}'])),
+    ('\u{2F9B1}', '\u{2F9B1}', Mapping::Mapped(&['\u{270D2}'])),
+    ('\u{2F9B2}', '\u{2F9B2}', Mapping::Mapped(&['\u{456B}'])),
+    ('\u{2F9B3}', '\u{2F9B3}', Mapping::Mapped(&['\u{8650}'])),
+    ('\u{2F9B4}', '\u{2F9B4}', Mapping::Mapped(&['\u{865C}'])),
+    ('\u{2F9B5}', '\u{2F9B5}', Mapping::Mapped(&['\u{8667}'])),
+    ('\u{2F9B6}', '\u{2F9B6}', Mapping::Mapped(&['\u{8669}'])),
+    ('\u{2F9B7}', '\u{2F9B7}', Mapping::Mapped(&['\u{86A9}'])),
+    ('\u{2F9B8}', '\u{2F9B8}', Mapping::Mapped(&['\u{8688}'])),
+    ('\u{2F9B9}', '\u{2F9B9}', Mapping::Mapped(&['\u{870E}'])),
+    ('\u{2F9BA}', '\u{2F9BA}', Mapping::Mapped(&['\u{86E2}'])),
+    ('\u{2F9BB}', '\u{2F9BB}', Mapping::Mapped(&['\u{8779}'])),
+    ('\u{2F9BC}', '\u{2F9BC}', Mapping::Mapped(&['\u{8728}'])),
+    ('\u{2F9BD}', '\u{2F9BD}', Mapping::Mapped(&['\u{876B}'])),
+    ('\u{2F9BE}', '\u{2F9BE}', Mapping::Mapped(&['\u{8786}'])),
+    ('\u{2F9BF}', '\u{2F9BF}', Mapping::Disallowed),
+    ('\u{2F9C0}', '\u{2F9C0}', Mapping::Mapped(&['\u{87E1}'])),
+    ('\u{2F9C1}', '\u{2F9C1}', Mapping::Mapped(&['\u{8801}'])),
+    ('\u{2F9C2}', '\u{2F9C2}', Mapping::Mapped(&['\u{45F9}'])),
+    ('\u{2F9C3}', '\u{2F9C3}', Mapping::Mapped(&['\u{8860}'])),
+    ('\u{2F9C4}', '\u{2F9C4}', Mapping::Mapped(&['\u{8863}'])),
+    ('\u{2F9C5}', '\u{2F9C5}', Mapping::Mapped(&['\u{27667}'])),
+    ('\u{2F9C6}', '\u{2F9C6}', Mapping::Mapped(&['\u{88D7}'])),
+    ('\u{2F9C7}', '\u{2F9C7}', Mapping::Mapped(&['\u{88DE}'])),
+    ('\u{2F9C8}', '\u{2F9C8}', Mapping::Mapped(&['\u{4635}'])),
+    ('\u{2F9C9}', '\u{2F9C9}', Mapping::Mapped(&['\u{88FA}'])),
+    ('\u{2F9CA}', '\u{2F9CA}', Mapping::Mapped(&['\u{34BB}'])),
+    ('\u{2F9CB}', '\u{2F9CB}', Mapping::Mapped(&['\u{278AE}'])),
+    ('\u{2F9CC}', '\u{2F9CC}', Mapping::Mapped(&['\u{27966}'])),
+    ('\u{2F9CD}', '\u{2F9CD}', Mapping::Mapped(&['\u{46BE}'])),
+    ('\u{2F9CE}', '\u{2F9CE}', Mapping::Mapped(&['\u{46C7}'])),
+    ('\u{2F9CF}', '\u{2F9CF}', Mapping::Mapped(&['\u{8AA0}'])),
+    ('\u{2F9D0}', '\u{2F9D0}', Mapping::Mapped(&['\u{8AED}'])),
+    ('\u{2F9D1}', '\u{2F9D1}', Mapping::Mapped(&['\u{8B8A}'])),
+    ('\u{2F9D2}', '\u{2F9D2}', Mapping::Mapped(&['\u{8C55}'])),
+    ('\u{2F9D3}', '\u{2F9D3}', Mapping::Mapped(&['\u{27CA8}'])),
+    ('\u{2F9D4}', '\u{2F9D4}', Mapping::Mapped(&['\u{8CAB}'])),
+    ('\u{2F9D5}', '\u{2F9D5}', Mapping::Mapped(&['\u{8CC1}'])),
+    ('\u{2F9D6}', '\u{2F9D6}', Mapping::Mapped(&['\u{8D1B}'])),
+    ('\u{2F9D7}', '\u{2F9D7}', Mapping::Mapped(&['\u{8D77}'])),
+    ('\u{2F9D8}', '\u{2F9D8}', Mapping::Mapped(&['\u{27F2F}'])),
+    ('\u{2F9D9}', '\u{2F9D9}', Mapping::Mapped(&['\u{20804}'])),
+    ('\u{2F9DA}', '\u{2F9DA}', Mapping::Mapped(&['\u{8DCB}'])),
+    ('\u{2F9DB}', '\u{2F9DB}', Mapping::Mapped(&['\u{8DBC}'])),
+    ('\u{2F9DC}', '\u{2F9DC}', Mapping::Mapped(&['\u{8DF0}'])),
+    ('\u{2F9DD}', '\u{2F9DD}', Mapping::Mapped(&['\u{208DE}'])),
+    ('\u{2F9DE}', '\u{2F9DE}', Mapping::Mapped(&['\u{8ED4}'])),
+    ('\u{2F9DF}', '\u{2F9DF}', Mapping::Mapped(&['\u{8F38}'])),
+    ('\u{2F9E0}', '\u{2F9E0}', Mapping::Mapped(&['\u{285D2}'])),
+    ('\u{2F9E1}', '\u{2F9E1}', Mapping::Mapped(&['\u{285ED}'])),
+    ('\u{2F9E2}', '\u{2F9E2}', Mapping::Mapped(&['\u{9094}'])),
+    ('\u{2F9E3}', '\u{2F9E3}', Mapping::Mapped(&['\u{90F1}'])),
+    ('\u{2F9E4}', '\u{2F9E4}', Mapping::Mapped(&['\u{9111}'])),
+    ('\u{2F9E5}', '\u{2F9E5}', Mapping::Mapped(&['\u{2872E}'])),
+    ('\u{2F9E6}', '\u{2F9E6}', Mapping::Mapped(&['\u{911B}'])),
+    ('\u{2F9E7}', '\u{2F9E7}', Mapping::Mapped(&['\u{9238}'])),
+    ('\u{2F9E8}', '\u{2F9E8}', Mapping::Mapped(&['\u{92D7}'])),
+    ('\u{2F9E9}', '\u{2F9E9}', Mapping::Mapped(&['\u{92D8}'])),
+    ('\u{2F9EA}', '\u{2F9EA}', Mapping::Mapped(&['\u{927C}'])),
+    ('\u{2F9EB}', '\u{2F9EB}', Mapping::Mapped(&['\u{93F9}'])),
+    ('\u{2F9EC}', '\u{2F9EC}', Mapping::Mapped(&['\u{9415}'])),
+    ('\u{2F9ED}', '\u{2F9ED}', Mapping::Mapped(&['\u{28BFA}'])),
+    ('\u{2F9EE}', '\u{2F9EE}', Mapping::Mapped(&['\u{958B}'])),
+    ('\u{2F9EF}', '\u{2F9EF}', Mapping::Mapped(&['\u{4995}'])),
+    ('\u{2F9F0}', '\u{2F9F0}', Mapping::Mapped(&['\u{95B7}'])),
+    ('\u{2F9F1}', '\u{2F9F1}', Mapping::Mapped(&['\u{28D77}'])),
+    ('\u{2F9F2}', '\u{2F9F2}', Mapping::Mapped(&['\u{49E6}'])),
+    ('\u{2F9F3}', '\u{2F9F3}', Mapping::Mapped(&['\u{96C3}'])),
+    ('\u{2F9F4}', '\u{2F9F4}', Mapping::Mapped(&['\u{5DB2}'])),
+    ('\u{2F9F5}', '\u{2F9F5}', Mapping::Mapped(&['\u{9723}'])),
+    ('\u{2F9F6}', '\u{2F9F6}', Mapping::Mapped(&['\u{29145}'])),
+    ('\u{2F9F7}', '\u{2F9F7}', Mapping::Mapped(&['\u{2921A}'])),
+    ('\u{2F9F8}', '\u{2F9F8}', Mapping::Mapped(&['\u{4A6E}'])),
+    ('\u{2F9F9}', '\u{2F9F9}', Mapping::Mapped(&['\u{4A76}'])),
+    ('\u{2F9FA}', '\u{2F9FA}', Mapping::Mapped(&['\u{97E0}'])),
+    ('\u{2F9FB}', '\u{2F9FB}', Mapping::Mapped(&['\u{2940A}'])),
+    ('\u{2F9FC}', '\u{2F9FC}', Mapping::Mapped(&['\u{4AB2}'])),
+    ('\u{2F9FD}', '\u{2F9FD}', Mapping::Mapped(&['\u{29496}'])),
+    ('\u{2F9FE}', '\u{2F9FF}', Mapping::Mapped(&['\u{980B}'])),
+    ('\u{2FA00}', '\u{2FA00}', Mapping::Mapped(&['\u{9829}'])),
+    ('\u{2FA01}', '\u{2FA01}', Mapping::Mapped(&['\u{295B6}'])),
+    ('\u{2FA02}', '\u{2FA02}', Mapping::Mapped(&['\u{98E2}'])),
+    ('\u{2FA03}', '\u{2FA03}', Mapping::Mapped(&['\u{4B33}'])),
+    ('\u{2FA04}', '\u{2FA04}', Mapping::Mapped(&['\u{9929}'])),
+    ('\u{2FA05}', '\u{2FA05}', Mapping::Mapped(&['\u{99A7}'])),
+    ('\u{2FA06}', '\u{2FA06}', Mapping::Mapped(&['\u{99C2}'])),
+    ('\u{2FA07}', '\u{2FA07}', Mapping::Mapped(&['\u{99FE}'])),
+    ('\u{2FA08}', '\u{2FA08}', Mapping::Mapped(&['\u{4BCE}'])),
+    ('\u{2FA09}', '\u{2FA09}', Mapping::Mapped(&['\u{29B30}'])),
+    ('\u{2FA0A}', '\u{2FA0A}', Mapping::Mapped(&['\u{9B12}'])),
+    ('\u{2FA0B}', '\u{2FA0B}', Mapping::Mapped(&['\u{9C40}'])),
+    ('\u{2FA0C}', '\u{2FA0C}', Mapping::Mapped(&['\u{9CFD}'])),
+    ('\u{2FA0D}', '\u{2FA0D}', Mapping::Mapped(&['\u{4CCE}'])),
+    ('\u{2FA0E}', '\u{2FA0E}', Mapping::Mapped(&['\u{4CED}'])),
+    ('\u{2FA0F}', '\u{2FA0F}', Mapping::Mapped(&['\u{9D67}'])),
+    ('\u{2FA10}', '\u{2FA10}', Mapping::Mapped(&['\u{2A0CE}'])),
+    ('\u{2FA11}', '\u{2FA11}', Mapping::Mapped(&['\u{4CF8}'])),
+    ('\u{2FA12}', '\u{2FA12}', Mapping::Mapped(&['\u{2A105}'])),
+    ('\u{2FA13}', '\u{2FA13}', Mapping::Mapped(&['\u{2A20E}'])),
+    ('\u{2FA14}', '\u{2FA14}', Mapping::Mapped(&['\u{2A291}'])),
+    ('\u{2FA15}', '\u{2FA15}', Mapping::Mapped(&['\u{9EBB}'])),
+    ('\u{2FA16}', '\u{2FA16}', Mapping::Mapped(&['\u{4D56}'])),
+    ('\u{2FA17}', '\u{2FA17}', Mapping::Mapped(&['\u{9EF9}'])),
+    ('\u{2FA18}', '\u{2FA18}', Mapping::Mapped(&['\u{9EFE}'])),
+    ('\u{2FA19}', '\u{2FA19}', Mapping::Mapped(&['\u{9F05}'])),
+    ('\u{2FA1A}', '\u{2FA1A}', Mapping::Mapped(&['\u{9F0F}'])),
+    ('\u{2FA1B}', '\u{2FA1B}', Mapping::Mapped(&['\u{9F16}'])),
+    ('\u{2FA1C}', '\u{2FA1C}', Mapping::Mapped(&['\u{9F3B}'])),
+    ('\u{2FA1D}', '\u{2FA1D}', Mapping::Mapped(&['\u{2A600}'])),
+    ('\u{2FA1E}', '\u{2FFFF}', Mapping::Disallowed),
+    ('\u{30000}', '\u{3134A}', Mapping::Valid),
+    ('\u{3134B}', '\u{E00FF}', Mapping::Disallowed),
+    ('\u{E0100}', '\u{E01EF}', Mapping::Ignored),
+    ('\u{E01F0}', '\u{10FFFF}', Mapping::Disallowed),
+];
@@ -0,0 +1,21 @@
+pub(crate) mod bidi;
+pub(crate) mod case_folding;
+pub(crate) mod collation;
+pub(crate) mod confusables;
+pub(crate) mod decimal_digits;
+#[cfg(feature = "encoding-chinese-simplified")]
+pub(crate) mod encoding_chinese_simplified;
+#[cfg(feature = "encoding-chinese-traditional")]
+pub(crate) mod encoding_chinese_traditional;
+#[cfg(feature = "encoding-japanese")]
+pub(crate) mod encoding_japanese;
+#[cfg(feature = "encoding-korean")]
+pub(crate) mod encoding_korean;
+#[cfg(feature = "encoding-single-byte")]
+pub(crate) mod encoding_single_byte;
+pub(crate) mod idna;
+pub(crate) mod names;
+pub(crate) mod normalization;
+pub(crate) mod properties;
+pub(crate) mod scripts;
+pub(crate) mod transliteration;
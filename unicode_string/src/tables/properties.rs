@@ -0,0 +1,12723 @@
+// This file is generated by `unicode_string_gen`. Do not edit it manually.
+
+use crate::ucd::{GeneralCategory, GraphemeClusterBreak, JoiningType};
+
+/// The version of the Unicode Character Database these tables were built from.
+pub(crate) const UNICODE_VERSION: (u8, u8, u8) = (14, 0, 0);
+
+/// Ranges of assigned chars and their general category, sorted by code point.
+pub(crate) static GENERAL_CATEGORY: &[(char, char, GeneralCategory)] = &[
+    ('\u{0}', '\u{1F}', GeneralCategory::Control),
+    ('\u{20}', '\u{20}', GeneralCategory::SpaceSeparator),
+    ('\u{21}', '\u{23}', GeneralCategory::OtherPunctuation),
+    ('\u{24}', '\u{24}', GeneralCategory::CurrencySymbol),
+    ('\u{25}', '\u{27}', GeneralCategory::OtherPunctuation),
+    ('\u{28}', '\u{28}', GeneralCategory::OpenPunctuation),
+    ('\u{29}', '\u{29}', GeneralCategory::ClosePunctuation),
+    ('\u{2A}', '\u{2A}', GeneralCategory::OtherPunctuation),
+    ('\u{2B}', '\u{2B}', GeneralCategory::MathSymbol),
+    ('\u{2C}', '\u{2C}', GeneralCategory::OtherPunctuation),
+    ('\u{2D}', '\u{2D}', GeneralCategory::DashPunctuation),
+    ('\u{2E}', '\u{2F}', GeneralCategory::OtherPunctuation),
+    ('\u{30}', '\u{39}', GeneralCategory::DecimalNumber),
+    ('\u{3A}', '\u{3B}', GeneralCategory::OtherPunctuation),
+    ('\u{3C}', '\u{3E}', GeneralCategory::MathSymbol),
+    ('\u{3F}', '\u{40}', GeneralCategory::OtherPunctuation),
+    ('\u{41}', '\u{5A}', GeneralCategory::UppercaseLetter),
+    ('\u{5B}', '\u{5B}', GeneralCategory::OpenPunctuation),
+    ('\u{5C}', '\u{5C}', GeneralCategory::OtherPunctuation),
+    ('\u{5D}', '\u{5D}', GeneralCategory::ClosePunctuation),
+    ('\u{5E}', '\u{5E}', GeneralCategory::ModifierSymbol),
+    ('\u{5F}', '\u{5F}', GeneralCategory::ConnectorPunctuation),
+    ('\u{60}', '\u{60}', GeneralCategory::ModifierSymbol),
+    ('\u{61}', '\u{7A}', GeneralCategory::LowercaseLetter),
+    ('\u{7B}', '\u{7B}', GeneralCategory::OpenPunctuation),
+    ('\u{7C}', '\u{7C}', GeneralCategory::MathSymbol),
+    ('\u{7D}', '\u{7D}', GeneralCategory::ClosePunctuation),
+    ('\u{7E}', '\u{7E}', GeneralCategory::MathSymbol),
+    ('\u{7F}', '\u{9F}', GeneralCategory::Control),
+    ('\u{A0}', '\u{A0}', GeneralCategory::SpaceSeparator),
+    ('\u{A1}', '\u{A1}', GeneralCategory::OtherPunctuation),
+    ('\u{A2}', '\u{A5}', GeneralCategory::CurrencySymbol),
+    ('\u{A6}', '\u{A6}', GeneralCategory::OtherSymbol),
+    ('\u{A7}', '\u{A7}', GeneralCategory::OtherPunctuation),
+    ('\u{A8}', '\u{A8}', GeneralCategory::ModifierSymbol),
+    ('\u{A9}', '\u{A9}', GeneralCategory::OtherSymbol),
+    ('\u{AA}', '\u{AA}', GeneralCategory::OtherLetter),
+    ('\u{AB}', '\u{AB}', GeneralCategory::InitialPunctuation),
+    ('\u{AC}', '\u{AC}', GeneralCategory::MathSymbol),
+    ('\u{AD}', '\u{AD}', GeneralCategory::Format),
+    ('\u{AE}', '\u{AE}', GeneralCategory::OtherSymbol),
+    ('\u{AF}', '\u{AF}', GeneralCategory::ModifierSymbol),
+    ('\u{B0}', '\u{B0}', GeneralCategory::OtherSymbol),
+    ('\u{B1}', '\u{B1}', GeneralCategory::MathSymbol),
+    ('\u{B2}', '\u{B3}', GeneralCategory::OtherNumber),
+    ('\u{B4}', '\u{B4}', GeneralCategory::ModifierSymbol),
+    ('\u{B5}', '\u{B5}', GeneralCategory::LowercaseLetter),
+    ('\u{B6}', '\u{B7}', GeneralCategory::OtherPunctuation),
+    ('\u{B8}', '\u{B8}', GeneralCategory::ModifierSymbol),
+    ('\u{B9}', '\u{B9}', GeneralCategory::OtherNumber),
+    ('\u{BA}', '\u{BA}', GeneralCategory::OtherLetter),
+    ('\u{BB}', '\u{BB}', GeneralCategory::FinalPunctuation),
+    ('\u{BC}', '\u{BE}', GeneralCategory::OtherNumber),
+    ('\u{BF}', '\u{BF}', GeneralCategory::OtherPunctuation),
+    ('\u{C0}', '\u{D6}', GeneralCategory::UppercaseLetter),
+    ('\u{D7}', '\u{D7}', GeneralCategory::MathSymbol),
+    ('\u{D8}', '\u{DE}', GeneralCategory::UppercaseLetter),
+    ('\u{DF}', '\u{F6}', GeneralCategory::LowercaseLetter),
+    ('\u{F7}', '\u{F7}', GeneralCategory::MathSymbol),
+    ('\u{F8}', '\u{FF}', GeneralCategory::LowercaseLetter),
+    ('\u{100}', '\u{100}', GeneralCategory::UppercaseLetter),
+    ('\u{101}', '\u{101}', GeneralCategory::LowercaseLetter),
+    ('\u{102}', '\u{102}', GeneralCategory::UppercaseLetter),
+    ('\u{103}', '\u{103}', GeneralCategory::LowercaseLetter),
+    ('\u{104}', '\u{104}', GeneralCategory::UppercaseLetter),
+    ('\u{105}', '\u{105}', GeneralCategory::LowercaseLetter),
+    ('\u{106}', '\u{106}', GeneralCategory::UppercaseLetter),
+    ('\u{107}', '\u{107}', GeneralCategory::LowercaseLetter),
+    ('\u{108}', '\u{108}', GeneralCategory::UppercaseLetter),
+    ('\u{109}', '\u{109}', GeneralCategory::LowercaseLetter),
+    ('\u{10A}', '\u{10A}', GeneralCategory::UppercaseLetter),
+    ('\u{10B}', '\u{10B}', GeneralCategory::LowercaseLetter),
+    ('\u{10C}', '\u{10C}', GeneralCategory::UppercaseLetter),
+    ('\u{10D}', '\u{10D}', GeneralCategory::LowercaseLetter),
+    ('\u{10E}', '\u{10E}', GeneralCategory::UppercaseLetter),
+    ('\u{10F}', '\u{10F}', GeneralCategory::LowercaseLetter),
+    ('\u{110}', '\u{110}', GeneralCategory::UppercaseLetter),
+    ('\u{111}', '\u{111}', GeneralCategory::LowercaseLetter),
+    ('\u{112}', '\u{112}', GeneralCategory::UppercaseLetter),
+    ('\u{113}', '\u{113}', GeneralCategory::LowercaseLetter),
+    ('\u{114}', '\u{114}', GeneralCategory::UppercaseLetter),
+    ('\u{115}', '\u{115}', GeneralCategory::LowercaseLetter),
+    ('\u{116}', '\u{116}', GeneralCategory::UppercaseLetter),
+    ('\u{117}', '\u{117}', GeneralCategory::LowercaseLetter),
+    ('\u{118}', '\u{118}', GeneralCategory::UppercaseLetter),
+    ('\u{119}', '\u{119}', GeneralCategory::LowercaseLetter),
+    ('\u{11A}', '\u{11A}', GeneralCategory::UppercaseLetter),
+    ('\u{11B}', '\u{11B}', GeneralCategory::LowercaseLetter),
+    ('\u{11C}', '\u{11C}', GeneralCategory::UppercaseLetter),
+    ('\u{11D}', '\u{11D}', GeneralCategory::LowercaseLetter),
+    ('\u{11E}', '\u{11E}', GeneralCategory::UppercaseLetter),
+    ('\u{11F}', '\u{11F}', GeneralCategory::LowercaseLetter),
+    ('\u{120}', '\u{120}', GeneralCategory::UppercaseLetter),
+    ('\u{121}', '\u{121}', GeneralCategory::LowercaseLetter),
+    ('\u{122}', '\u{122}', GeneralCategory::UppercaseLetter),
+    ('\u{123}', '\u{123}', GeneralCategory::LowercaseLetter),
+    ('\u{124}', '\u{124}', GeneralCategory::UppercaseLetter),
+    ('\u{125}', '\u{125}', GeneralCategory::LowercaseLetter),
+    ('\u{126}', '\u{126}', GeneralCategory::UppercaseLetter),
+    ('\u{127}', '\u{127}', GeneralCategory::LowercaseLetter),
+    ('\u{128}', '\u{128}', GeneralCategory::UppercaseLetter),
+    ('\u{129}', '\u{129}', GeneralCategory::LowercaseLetter),
+    ('\u{12A}', '\u{12A}', GeneralCategory::UppercaseLetter),
+    ('\u{12B}', '\u{12B}', GeneralCategory::LowercaseLetter),
+    ('\u{12C}', '\u{12C}', GeneralCategory::UppercaseLetter),
+    ('\u{12D}', '\u{12D}', GeneralCategory::LowercaseLetter),
+    ('\u{12E}', '\u{12E}', GeneralCategory::UppercaseLetter),
+    ('\u{12F}', '\u{12F}', GeneralCategory::LowercaseLetter),
+    ('\u{130}', '\u{130}', GeneralCategory::UppercaseLetter),
+    ('\u{131}', '\u{131}', GeneralCategory::LowercaseLetter),
+    ('\u{132}', '\u{132}', GeneralCategory::UppercaseLetter),
+    ('\u{133}', '\u{133}', GeneralCategory::LowercaseLetter),
+    ('\u{134}', '\u{134}', GeneralCategory::UppercaseLetter),
+    ('\u{135}', '\u{135}', GeneralCategory::LowercaseLetter),
+    ('\u{136}', '\u{136}', GeneralCategory::UppercaseLetter),
+    ('\u{137}', '\u{138}', GeneralCategory::LowercaseLetter),
+    ('\u{139}', '\u{139}', GeneralCategory::UppercaseLetter),
+    ('\u{13A}', '\u{13A}', GeneralCategory::LowercaseLetter),
+    ('\u{13B}', '\u{13B}', GeneralCategory::UppercaseLetter),
+    ('\u{13C}', '\u{13C}', GeneralCategory::LowercaseLetter),
+    ('\u{13D}', '\u{13D}', GeneralCategory::UppercaseLetter),
+    ('\u{13E}', '\u{13E}', GeneralCategory::LowercaseLetter),
+    ('\u{13F}', '\u{13F}', GeneralCategory::UppercaseLetter),
+    ('\u{140}', '\u{140}', GeneralCategory::LowercaseLetter),
+    ('\u{141}', '\u{141}', GeneralCategory::UppercaseLetter),
+    ('\u{142}', '\u{142}', GeneralCategory::LowercaseLetter),
+    ('\u{143}', '\u{143}', GeneralCategory::UppercaseLetter),
+    ('\u{144}', '\u{144}', GeneralCategory::LowercaseLetter),
+    ('\u{145}', '\u{145}', GeneralCategory::UppercaseLetter),
+    ('\u{146}', '\u{146}', GeneralCategory::LowercaseLetter),
+    ('\u{147}', '\u{147}', GeneralCategory::UppercaseLetter),
+    ('\u{148}', '\u{149}', GeneralCategory::LowercaseLetter),
+    ('\u{14A}', '\u{14A}', GeneralCategory::UppercaseLetter),
+    ('\u{14B}', '\u{14B}', GeneralCategory::LowercaseLetter),
+    ('\u{14C}', '\u{14C}', GeneralCategory::UppercaseLetter),
+    ('\u{14D}', '\u{14D}', GeneralCategory::LowercaseLetter),
+    ('\u{14E}', '\u{14E}', GeneralCategory::UppercaseLetter),
+    ('\u{14F}', '\u{14F}', GeneralCategory::LowercaseLetter),
+    ('\u{150}', '\u{150}', GeneralCategory::UppercaseLetter),
+    ('\u{151}', '\u{151}', GeneralCategory::LowercaseLetter),
+    ('\u{152}', '\u{152}', GeneralCategory::UppercaseLetter),
+    ('\u{153}', '\u{153}', GeneralCategory::LowercaseLetter),
+    ('\u{154}', '\u{154}', GeneralCategory::UppercaseLetter),
+    ('\u{155}', '\u{155}', GeneralCategory::LowercaseLetter),
+    ('\u{156}', '\u{156}', GeneralCategory::UppercaseLetter),
+    ('\u{157}', '\u{157}', GeneralCategory::LowercaseLetter),
+    ('\u{158}', '\u{158}', GeneralCategory::UppercaseLetter),
+    ('\u{159}', '\u{159}', GeneralCategory::LowercaseLetter),
+    ('\u{15A}', '\u{15A}', GeneralCategory::UppercaseLetter),
+    ('\u{15B}', '\u{15B}', GeneralCategory::LowercaseLetter),
+    ('\u{15C}', '\u{15C}', GeneralCategory::UppercaseLetter),
+    ('\u{15D}', '\u{15D}', GeneralCategory::LowercaseLetter),
+    ('\u{15E}', '\u{15E}', GeneralCategory::UppercaseLetter),
+    ('\u{15F}', '\u{15F}', GeneralCategory::LowercaseLetter),
+    ('\u{160}', '\u{160}', GeneralCategory::UppercaseLetter),
+    ('\u{161}', '\u{161}', GeneralCategory::LowercaseLetter),
+    ('\u{162}', '\u{162}', GeneralCategory::UppercaseLetter),
+    ('\u{163}', '\u{163}', GeneralCategory::LowercaseLetter),
+    ('\u{164}', '\u{164}', GeneralCategory::UppercaseLetter),
+    ('\u{165}', '\u{165}', GeneralCategory::LowercaseLetter),
+    ('\u{166}', '\u{166}', GeneralCategory::UppercaseLetter),
+    ('\u{167}', '\u{167}', GeneralCategory::LowercaseLetter),
+    ('\u{168}', '\u{168}', GeneralCategory::UppercaseLetter),
+    ('\u{169}', '\u{169}', GeneralCategory::LowercaseLetter),
+    ('\u{16A}', '\u{16A}', GeneralCategory::UppercaseLetter),
+    ('\u{16B}', '\u{16B}', GeneralCategory::LowercaseLetter),
+    ('\u{16C}', '\u{16C}', GeneralCategory::UppercaseLetter),
+    ('\u{16D}', '\u{16D}', GeneralCategory::LowercaseLetter),
+    ('\u{16E}', '\u{16E}', GeneralCategory::UppercaseLetter),
+    ('\u{16F}', '\u{16F}', GeneralCategory::LowercaseLetter),
+    ('\u{170}', '\u{170}', GeneralCategory::UppercaseLetter),
+    ('\u{171}', '\u{171}', GeneralCategory::LowercaseLetter),
+    ('\u{172}', '\u{172}', GeneralCategory::UppercaseLetter),
+    ('\u{173}', '\u{173}', GeneralCategory::LowercaseLetter),
+    ('\u{174}', '\u{174}', GeneralCategory::UppercaseLetter),
+    ('\u{175}', '\u{175}', GeneralCategory::LowercaseLetter),
+    ('\u{176}', '\u{176}', GeneralCategory::UppercaseLetter),
+    ('\u{177}', '\u{177}', GeneralCategory::LowercaseLetter),
+    ('\u{178}', '\u{179}', GeneralCategory::UppercaseLetter),
+    ('\u{17A}', '\u{17A}', GeneralCategory::LowercaseLetter),
+    ('\u{17B}', '\u{17B}', GeneralCategory::UppercaseLetter),
+    ('\u{17C}', '\u{17C}', GeneralCategory::LowercaseLetter),
+    ('\u{17D}', '\u{17D}', GeneralCategory::UppercaseLetter),
+    ('\u{17E}', '\u{180}', GeneralCategory::LowercaseLetter),
+    ('\u{181}', '\u{182}', GeneralCategory::UppercaseLetter),
+    ('\u{183}', '\u{183}', GeneralCategory::LowercaseLetter),
+    ('\u{184}', '\u{184}', GeneralCategory::UppercaseLetter),
+    ('\u{185}', '\u{185}', GeneralCategory::LowercaseLetter),
+    ('\u{186}', '\u{187}', GeneralCategory::UppercaseLetter),
+    ('\u{188}', '\u{188}', GeneralCategory::LowercaseLetter),
+    ('\u{189}', '\u{18B}', GeneralCategory::UppercaseLetter),
+    ('\u{18C}', '\u{18D}', GeneralCategory::LowercaseLetter),
+    ('\u{18E}', '\u{191}', GeneralCategory::UppercaseLetter),
+    ('\u{192}', '\u{192}', GeneralCategory::LowercaseLetter),
+    ('\u{193}', '\u{194}', GeneralCategory::UppercaseLetter),
+    ('\u{195}', '\u{195}', GeneralCategory::LowercaseLetter),
+    ('\u{196}', '\u{198}', GeneralCategory::UppercaseLetter),
+    ('\u{199}', '\u{19B}', GeneralCategory::LowercaseLetter),
+    ('\u{19C}', '\u{19D}', GeneralCategory::UppercaseLetter),
+    ('\u{19E}', '\u{19E}', GeneralCategory::LowercaseLetter),
+    ('\u{19F}', '\u{1A0}', GeneralCategory::UppercaseLetter),
+    ('\u{1A1}', '\u{1A1}', GeneralCategory::LowercaseLetter),
+    ('\u{1A2}', '\u{1A2}', GeneralCategory::UppercaseLetter),
+    ('\u{1A3}', '\u{1A3}', GeneralCategory::LowercaseLetter),
+    ('\u{1A4}', '\u{1A4}', GeneralCategory::UppercaseLetter),
+    ('\u{1A5}', '\u{1A5}', GeneralCategory::LowercaseLetter),
+    ('\u{1A6}', '\u{1A7}', GeneralCategory::UppercaseLetter),
+    ('\u{1A8}', '\u{1A8}', GeneralCategory::LowercaseLetter),
+    ('\u{1A9}', '\u{1A9}', GeneralCategory::UppercaseLetter),
+    ('\u{1AA}', '\u{1AB}', GeneralCategory::LowercaseLetter),
+    ('\u{1AC}', '\u{1AC}', GeneralCategory::UppercaseLetter),
+    ('\u{1AD}', '\u{1AD}', GeneralCategory::LowercaseLetter),
+    ('\u{1AE}', '\u{1AF}', GeneralCategory::UppercaseLetter),
+    ('\u{1B0}', '\u{1B0}', GeneralCategory::LowercaseLetter),
+    ('\u{1B1}', '\u{1B3}', GeneralCategory::UppercaseLetter),
+    ('\u{1B4}', '\u{1B4}', GeneralCategory::LowercaseLetter),
+    ('\u{1B5}', '\u{1B5}', GeneralCategory::UppercaseLetter),
+    ('\u{1B6}', '\u{1B6}', GeneralCategory::LowercaseLetter),
+    ('\u{1B7}', '\u{1B8}', GeneralCategory::UppercaseLetter),
+    ('\u{1B9}', '\u{1BA}', GeneralCategory::LowercaseLetter),
+    ('\u{1BB}', '\u{1BB}', GeneralCategory::OtherLetter),
+    ('\u{1BC}', '\u{1BC}', GeneralCategory::UppercaseLetter),
+    ('\u{1BD}', '\u{1BF}', GeneralCategory::LowercaseLetter),
+    ('\u{1C0}', '\u{1C3}', GeneralCategory::OtherLetter),
+    ('\u{1C4}', '\u{1C4}', GeneralCategory::UppercaseLetter),
+    ('\u{1C5}', '\u{1C5}', GeneralCategory::TitlecaseLetter),
+    ('\u{1C6}', '\u{1C6}', GeneralCategory::LowercaseLetter),
+    ('\u{1C7}', '\u{1C7}', GeneralCategory::UppercaseLetter),
+    ('\u{1C8}', '\u{1C8}', GeneralCategory::TitlecaseLetter),
+    ('\u{1C9}', '\u{1C9}', GeneralCategory::LowercaseLetter),
+    ('\u{1CA}', '\u{1CA}', GeneralCategory::UppercaseLetter),
+    ('\u{1CB}', '\u{1CB}', GeneralCategory::TitlecaseLetter),
+    ('\u{1CC}', '\u{1CC}', GeneralCategory::LowercaseLetter),
+    ('\u{1CD}', '\u{1CD}', GeneralCategory::UppercaseLetter),
+    ('\u{1CE}', '\u{1CE}', GeneralCategory::LowercaseLetter),
+    ('\u{1CF}', '\u{1CF}', GeneralCategory::UppercaseLetter),
+    ('\u{1D0}', '\u{1D0}', GeneralCategory::LowercaseLetter),
+    ('\u{1D1}', '\u{1D1}', GeneralCategory::UppercaseLetter),
+    ('\u{1D2}', '\u{1D2}', GeneralCategory::LowercaseLetter),
+    ('\u{1D3}', '\u{1D3}', GeneralCategory::UppercaseLetter),
+    ('\u{1D4}', '\u{1D4}', GeneralCategory::LowercaseLetter),
+    ('\u{1D5}', '\u{1D5}', GeneralCategory::UppercaseLetter),
+    ('\u{1D6}', '\u{1D6}', GeneralCategory::LowercaseLetter),
+    ('\u{1D7}', '\u{1D7}', GeneralCategory::UppercaseLetter),
+    ('\u{1D8}', '\u{1D8}', GeneralCategory::LowercaseLetter),
+    ('\u{1D9}', '\u{1D9}', GeneralCategory::UppercaseLetter),
+    ('\u{1DA}', '\u{1DA}', GeneralCategory::LowercaseLetter),
+    ('\u{1DB}', '\u{1DB}', GeneralCategory::UppercaseLetter),
+    ('\u{1DC}', '\u{1DD}', GeneralCategory::LowercaseLetter),
+    ('\u{1DE}', '\u{1DE}', GeneralCategory::UppercaseLetter),
+    ('\u{1DF}', '\u{1DF}', GeneralCategory::LowercaseLetter),
+    ('\u{1E0}', '\u{1E0}', GeneralCategory::UppercaseLetter),
+    ('\u{1E1}', '\u{1E1}', GeneralCategory::LowercaseLetter),
+    ('\u{1E2}', '\u{1E2}', GeneralCategory::UppercaseLetter),
+    ('\u{1E3}', '\u{1E3}', GeneralCategory::LowercaseLetter),
+    ('\u{1E4}', '\u{1E4}', GeneralCategory::UppercaseLetter),
+    ('\u{1E5}', '\u{1E5}', GeneralCategory::LowercaseLetter),
+    ('\u{1E6}', '\u{1E6}', GeneralCategory::UppercaseLetter),
+    ('\u{1E7}', '\u{1E7}', GeneralCategory::LowercaseLetter),
+    ('\u{1E8}', '\u{1E8}', GeneralCategory::UppercaseLetter),
+    ('\u{1E9}', '\u{1E9}', GeneralCategory::LowercaseLetter),
+    ('\u{1EA}', '\u{1EA}', GeneralCategory::UppercaseLetter),
+    ('\u{1EB}', '\u{1EB}', GeneralCategory::LowercaseLetter),
+    ('\u{1EC}', '\u{1EC}', GeneralCategory::UppercaseLetter),
+    ('\u{1ED}', '\u{1ED}', GeneralCategory::LowercaseLetter),
+    ('\u{1EE}', '\u{1EE}', GeneralCategory::UppercaseLetter),
+    ('\u{1EF}', '\u{1F0}', GeneralCategory::LowercaseLetter),
+    ('\u{1F1}', '\u{1F1}', GeneralCategory::UppercaseLetter),
+    ('\u{1F2}', '\u{1F2}', GeneralCategory::TitlecaseLetter),
+    ('\u{1F3}', '\u{1F3}', GeneralCategory::LowercaseLetter),
+    ('\u{1F4}', '\u{1F4}', GeneralCategory::UppercaseLetter),
+    ('\u{1F5}', '\u{1F5}', GeneralCategory::LowercaseLetter),
+    ('\u{1F6}', '\u{1F8}', GeneralCategory::UppercaseLetter),
+    ('\u{1F9}', '\u{1F9}', GeneralCategory::LowercaseLetter),
+    ('\u{1FA}', '\u{1FA}', GeneralCategory::UppercaseLetter),
+    ('\u{1FB}', '\u{1FB}', GeneralCategory::LowercaseLetter),
+    ('\u{1FC}', '\u{1FC}', GeneralCategory::UppercaseLetter),
+    ('\u{1FD}', '\u{1FD}', GeneralCategory::LowercaseLetter),
+    ('\u{1FE}', '\u{1FE}', GeneralCategory::UppercaseLetter),
+    ('\u{1FF}', '\u{1FF}', GeneralCategory::LowercaseLetter),
+    ('\u{200}', '\u{200}', GeneralCategory::UppercaseLetter),
+    ('\u{201}', '\u{201}', GeneralCategory::LowercaseLetter),
+    ('\u{202}', '\u{202}', GeneralCategory::UppercaseLetter),
+    ('\u{203}', '\u{203}', GeneralCategory::LowercaseLetter),
+    ('\u{204}', '\u{204}', GeneralCategory::UppercaseLetter),
+    ('\u{205}', '\u{205}', GeneralCategory::LowercaseLetter),
+    ('\u{206}', '\u{206}', GeneralCategory::UppercaseLetter),
+    ('\u{207}', '\u{207}', GeneralCategory::LowercaseLetter),
+    ('\u{208}', '\u{208}', GeneralCategory::UppercaseLetter),
+    ('\u{209}', '\u{209}', GeneralCategory::LowercaseLetter),
+    ('\u{20A}', '\u{20A}', GeneralCategory::UppercaseLetter),
+    ('\u{20B}', '\u{20B}', GeneralCategory::LowercaseLetter),
+    ('\u{20C}', '\u{20C}', GeneralCategory::UppercaseLetter),
+    ('\u{20D}', '\u{20D}', GeneralCategory::LowercaseLetter),
+    ('\u{20E}', '\u{20E}', GeneralCategory::UppercaseLetter),
+    ('\u{20F}', '\u{20F}', GeneralCategory::LowercaseLetter),
+    ('\u{210}', '\u{210}', GeneralCategory::UppercaseLetter),
+    ('\u{211}', '\u{211}', GeneralCategory::LowercaseLetter),
+    ('\u{212}', '\u{212}', GeneralCategory::UppercaseLetter),
+    ('\u{213}', '\u{213}', GeneralCategory::LowercaseLetter),
+    ('\u{214}', '\u{214}', GeneralCategory::UppercaseLetter),
+    ('\u{215}', '\u{215}', GeneralCategory::LowercaseLetter),
+    ('\u{216}', '\u{216}', GeneralCategory::UppercaseLetter),
+    ('\u{217}', '\u{217}', GeneralCategory::LowercaseLetter),
+    ('\u{218}', '\u{218}', GeneralCategory::UppercaseLetter),
+    ('\u{219}', '\u{219}', GeneralCategory::LowercaseLetter),
+    ('\u{21A}', '\u{21A}', GeneralCategory::UppercaseLetter),
+    ('\u{21B}', '\u{21B}', GeneralCategory::LowercaseLetter),
+    ('\u{21C}', '\u{21C}', GeneralCategory::UppercaseLetter),
+    ('\u{21D}', '\u{21D}', GeneralCategory::LowercaseLetter),
+    ('\u{21E}', '\u{21E}', GeneralCategory::UppercaseLetter),
+    ('\u{21F}', '\u{21F}', GeneralCategory::LowercaseLetter),
+    ('\u{220}', '\u{220}', GeneralCategory::UppercaseLetter),
+    ('\u{221}', '\u{221}', GeneralCategory::LowercaseLetter),
+    ('\u{222}', '\u{222}', GeneralCategory::UppercaseLetter),
+    ('\u{223}', '\u{223}', GeneralCategory::LowercaseLetter),
+    ('\u{224}', '\u{224}', GeneralCategory::UppercaseLetter),
+    ('\u{225}', '\u{225}', GeneralCategory::LowercaseLetter),
+    ('\u{226}', '\u{226}', GeneralCategory::UppercaseLetter),
+    ('\u{227}', '\u{227}', GeneralCategory::LowercaseLetter),
+    ('\u{228}', '\u{228}', GeneralCategory::UppercaseLetter),
+    ('\u{229}', '\u{229}', GeneralCategory::LowercaseLetter),
+    ('\u{22A}', '\u{22A}', GeneralCategory::UppercaseLetter),
+    ('\u{22B}', '\u{22B}', GeneralCategory::LowercaseLetter),
+    ('\u{22C}', '\u{22C}', GeneralCategory::UppercaseLetter),
+    ('\u{22D}', '\u{22D}', GeneralCategory::LowercaseLetter),
+    ('\u{22E}', '\u{22E}', GeneralCategory::UppercaseLetter),
+    ('\u{22F}', '\u{22F}', GeneralCategory::LowercaseLetter),
+    ('\u{230}', '\u{230}', GeneralCategory::UppercaseLetter),
+    ('\u{231}', '\u{231}', GeneralCategory::LowercaseLetter),
+    ('\u{232}', '\u{232}', GeneralCategory::UppercaseLetter),
+    ('\u{233}', '\u{239}', GeneralCategory::LowercaseLetter),
+    ('\u{23A}', '\u{23B}', GeneralCategory::UppercaseLetter),
+    ('\u{23C}', '\u{23C}', GeneralCategory::LowercaseLetter),
+    ('\u{23D}', '\u{23E}', GeneralCategory::UppercaseLetter),
+    ('\u{23F}', '\u{240}', GeneralCategory::LowercaseLetter),
+    ('\u{241}', '\u{241}', GeneralCategory::UppercaseLetter),
+    ('\u{242}', '\u{242}', GeneralCategory::LowercaseLetter),
+    ('\u{243}', '\u{246}', GeneralCategory::UppercaseLetter),
+    ('\u{247}', '\u{247}', GeneralCategory::LowercaseLetter),
+    ('\u{248}', '\u{248}', GeneralCategory::UppercaseLetter),
+    ('\u{249}', '\u{249}', GeneralCategory::LowercaseLetter),
+    ('\u{24A}', '\u{24A}', GeneralCategory::UppercaseLetter),
+    ('\u{24B}', '\u{24B}', GeneralCategory::LowercaseLetter),
+    ('\u{24C}', '\u{24C}', GeneralCategory::UppercaseLetter),
+    ('\u{24D}', '\u{24D}', GeneralCategory::LowercaseLetter),
+    ('\u{24E}', '\u{24E}', GeneralCategory::UppercaseLetter),
+    ('\u{24F}', '\u{293}', GeneralCategory::LowercaseLetter),
+    ('\u{294}', '\u{294}', GeneralCategory::OtherLetter),
+    ('\u{295}', '\u{2AF}', GeneralCategory::LowercaseLetter),
+    ('\u{2B0}', '\u{2C1}', GeneralCategory::ModifierLetter),
+    ('\u{2C2}', '\u{2C5}', GeneralCategory::ModifierSymbol),
+    ('\u{2C6}', '\u{2D1}', GeneralCategory::ModifierLetter),
+    ('\u{2D2}', '\u{2DF}', GeneralCategory::ModifierSymbol),
+    ('\u{2E0}', '\u{2E4}', GeneralCategory::ModifierLetter),
+    ('\u{2E5}', '\u{2EB}', GeneralCategory::ModifierSymbol),
+    ('\u{2EC}', '\u{2EC}', GeneralCategory::ModifierLetter),
+    ('\u{2ED}', '\u{2ED}', GeneralCategory::ModifierSymbol),
+    ('\u{2EE}', '\u{2EE}', GeneralCategory::ModifierLetter),
+    ('\u{2EF}', '\u{2FF}', GeneralCategory::ModifierSymbol),
+    ('\u{300}', '\u{36F}', GeneralCategory::NonspacingMark),
+    ('\u{370}', '\u{370}', GeneralCategory::UppercaseLetter),
+    ('\u{371}', '\u{371}', GeneralCategory::LowercaseLetter),
+    ('\u{372}', '\u{372}', GeneralCategory::UppercaseLetter),
+    ('\u{373}', '\u{373}', GeneralCategory::LowercaseLetter),
+    ('\u{374}', '\u{374}', GeneralCategory::ModifierLetter),
+    ('\u{375}', '\u{375}', GeneralCategory::ModifierSymbol),
+    ('\u{376}', '\u{376}', GeneralCategory::UppercaseLetter),
+    ('\u{377}', '\u{377}', GeneralCategory::LowercaseLetter),
+    ('\u{37A}', '\u{37A}', GeneralCategory::ModifierLetter),
+    ('\u{37B}', '\u{37D}', GeneralCategory::LowercaseLetter),
+    ('\u{37E}', '\u{37E}', GeneralCategory::OtherPunctuation),
+    ('\u{37F}', '\u{37F}', GeneralCategory::UppercaseLetter),
+    ('\u{384}', '\u{385}', GeneralCategory::ModifierSymbol),
+    ('\u{386}', '\u{386}', GeneralCategory::UppercaseLetter),
+    ('\u{387}', '\u{387}', GeneralCategory::OtherPunctuation),
+    ('\u{388}', '\u{38A}', GeneralCategory::UppercaseLetter),
+    ('\u{38C}', '\u{38C}', GeneralCategory::UppercaseLetter),
+    ('\u{38E}', '\u{38F}', GeneralCategory::UppercaseLetter),
+    ('\u{390}', '\u{390}', GeneralCategory::LowercaseLetter),
+    ('\u{391}', '\u{3A1}', GeneralCategory::UppercaseLetter),
+    ('\u{3A3}', '\u{3AB}', GeneralCategory::UppercaseLetter),
+    ('\u{3AC}', '\u{3CE}', GeneralCategory::LowercaseLetter),
+    ('\u{3CF}', '\u{3CF}', GeneralCategory::UppercaseLetter),
+    ('\u{3D0}', '\u{3D1}', GeneralCategory::LowercaseLetter),
+    ('\u{3D2}', '\u{3D4}', GeneralCategory::UppercaseLetter),
+    ('\u{3D5}', '\u{3D7}', GeneralCategory::LowercaseLetter),
+    ('\u{3D8}', '\u{3D8}', GeneralCategory::UppercaseLetter),
+    ('\u{3D9}', '\u{3D9}', GeneralCategory::LowercaseLetter),
+    ('\u{3DA}', '\u{3DA}', GeneralCategory::UppercaseLetter),
+    ('\u{3DB}', '\u{3DB}', GeneralCategory::LowercaseLetter),
+    ('\u{3DC}', '\u{3DC}', GeneralCategory::UppercaseLetter),
+    ('\u{3DD}', '\u{3DD}', GeneralCategory::LowercaseLetter),
+    ('\u{3DE}', '\u{3DE}', GeneralCategory::UppercaseLetter),
+    ('\u{3DF}', '\u{3DF}', GeneralCategory::LowercaseLetter),
+    ('\u{3E0}', '\u{3E0}', GeneralCategory::UppercaseLetter),
+    ('\u{3E1}', '\u{3E1}', GeneralCategory::LowercaseLetter),
+    ('\u{3E2}', '\u{3E2}', GeneralCategory::UppercaseLetter),
+    ('\u{3E3}', '\u{3E3}', GeneralCategory::LowercaseLetter),
+    ('\u{3E4}', '\u{3E4}', GeneralCategory::UppercaseLetter),
+    ('\u{3E5}', '\u{3E5}', GeneralCategory::LowercaseLetter),
+    ('\u{3E6}', '\u{3E6}', GeneralCategory::UppercaseLetter),
+    ('\u{3E7}', '\u{3E7}', GeneralCategory::LowercaseLetter),
+    ('\u{3E8}', '\u{3E8}', GeneralCategory::UppercaseLetter),
+    ('\u{3E9}', '\u{3E9}', GeneralCategory::LowercaseLetter),
+    ('\u{3EA}', '\u{3EA}', GeneralCategory::UppercaseLetter),
+    ('\u{3EB}', '\u{3EB}', GeneralCategory::LowercaseLetter),
+    ('\u{3EC}', '\u{3EC}', GeneralCategory::UppercaseLetter),
+    ('\u{3ED}', '\u{3ED}', GeneralCategory::LowercaseLetter),
+    ('\u{3EE}', '\u{3EE}', GeneralCategory::UppercaseLetter),
+    ('\u{3EF}', '\u{3F3}', GeneralCategory::LowercaseLetter),
+    ('\u{3F4}', '\u{3F4}', GeneralCategory::UppercaseLetter),
+    ('\u{3F5}', '\u{3F5}', GeneralCategory::LowercaseLetter),
+    ('\u{3F6}', '\u{3F6}', GeneralCategory::MathSymbol),
+    ('\u{3F7}', '\u{3F7}', GeneralCategory::UppercaseLetter),
+    ('\u{3F8}', '\u{3F8}', GeneralCategory::LowercaseLetter),
+    ('\u{3F9}', '\u{3FA}', GeneralCategory::UppercaseLetter),
+    ('\u{3FB}', '\u{3FC}', GeneralCategory::LowercaseLetter),
+    ('\u{3FD}', '\u{42F}', GeneralCategory::UppercaseLetter),
+    ('\u{430}', '\u{45F}', GeneralCategory::LowercaseLetter),
+    ('\u{460}', '\u{460}', GeneralCategory::UppercaseLetter),
+    ('\u{461}', '\u{461}', GeneralCategory::LowercaseLetter),
+    ('\u{462}', '\u{462}', GeneralCategory::UppercaseLetter),
+    ('\u{463}', '\u{463}', GeneralCategory::LowercaseLetter),
+    ('\u{464}', '\u{464}', GeneralCategory::UppercaseLetter),
+    ('\u{465}', '\u{465}', GeneralCategory::LowercaseLetter),
+    ('\u{466}', '\u{466}', GeneralCategory::UppercaseLetter),
+    ('\u{467}', '\u{467}', GeneralCategory::LowercaseLetter),
+    ('\u{468}', '\u{468}', GeneralCategory::UppercaseLetter),
+    ('\u{469}', '\u{469}', GeneralCategory::LowercaseLetter),
+    ('\u{46A}', '\u{46A}', GeneralCategory::UppercaseLetter),
+    ('\u{46B}', '\u{46B}', GeneralCategory::LowercaseLetter),
+    ('\u{46C}', '\u{46C}', GeneralCategory::UppercaseLetter),
+    ('\u{46D}', '\u{46D}', GeneralCategory::LowercaseLetter),
+    ('\u{46E}', '\u{46E}', GeneralCategory::UppercaseLetter),
+    ('\u{46F}', '\u{46F}', GeneralCategory::LowercaseLetter),
+    ('\u{470}', '\u{470}', GeneralCategory::UppercaseLetter),
+    ('\u{471}', '\u{471}', GeneralCategory::LowercaseLetter),
+    ('\u{472}', '\u{472}', GeneralCategory::UppercaseLetter),
+    ('\u{473}', '\u{473}', GeneralCategory::LowercaseLetter),
+    ('\u{474}', '\u{474}', GeneralCategory::UppercaseLetter),
+    ('\u{475}', '\u{475}', GeneralCategory::LowercaseLetter),
+    ('\u{476}', '\u{476}', GeneralCategory::UppercaseLetter),
+    ('\u{477}', '\u{477}', GeneralCategory::LowercaseLetter),
+    ('\u{478}', '\u{478}', GeneralCategory::UppercaseLetter),
+    ('\u{479}', '\u{479}', GeneralCategory::LowercaseLetter),
+    ('\u{47A}', '\u{47A}', GeneralCategory::UppercaseLetter),
+    ('\u{47B}', '\u{47B}', GeneralCategory::LowercaseLetter),
+    ('\u{47C}', '\u{47C}', GeneralCategory::UppercaseLetter),
+    ('\u{47D}', '\u{47D}', GeneralCategory::LowercaseLetter),
+    ('\u{47E}', '\u{47E}', GeneralCategory::UppercaseLetter),
+    ('\u{47F}', '\u{47F}', GeneralCategory::LowercaseLetter),
+    ('\u{480}', '\u{480}', GeneralCategory::UppercaseLetter),
+    ('\u{481}', '\u{481}', GeneralCategory::LowercaseLetter),
+    ('\u{482}', '\u{482}', GeneralCategory::OtherSymbol),
+    ('\u{483}', '\u{487}', GeneralCategory::NonspacingMark),
+    ('\u{488}', '\u{489}', GeneralCategory::EnclosingMark),
+    ('\u{48A}', '\u{48A}', GeneralCategory::UppercaseLetter),
+    ('\u{48B}', '\u{48B}', GeneralCategory::LowercaseLetter),
+    ('\u{48C}', '\u{48C}', GeneralCategory::UppercaseLetter),
+    ('\u{48D}', '\u{48D}', GeneralCategory::LowercaseLetter),
+    ('\u{48E}', '\u{48E}', GeneralCategory::UppercaseLetter),
+    ('\u{48F}', '\u{48F}', GeneralCategory::LowercaseLetter),
+    ('\u{490}', '\u{490}', GeneralCategory::UppercaseLetter),
+    ('\u{491}', '\u{491}', GeneralCategory::LowercaseLetter),
+    ('\u{492}', '\u{492}', GeneralCategory::UppercaseLetter),
+    ('\u{493}', '\u{493}', GeneralCategory::LowercaseLetter),
+    ('\u{494}', '\u{494}', GeneralCategory::UppercaseLetter),
+    ('\u{495}', '\u{495}', GeneralCategory::LowercaseLetter),
+    ('\u{496}', '\u{496}', GeneralCategory::UppercaseLetter),
+    ('\u{497}', '\u{497}', GeneralCategory::LowercaseLetter),
+    ('\u{498}', '\u{498}', GeneralCategory::UppercaseLetter),
+    ('\u{499}', '\u{499}', GeneralCategory::LowercaseLetter),
+    ('\u{49A}', '\u{49A}', GeneralCategory::UppercaseLetter),
+    ('\u{49B}', '\u{49B}', GeneralCategory::LowercaseLetter),
+    ('\u{49C}', '\u{49C}', GeneralCategory::UppercaseLetter),
+    ('\u{49D}', '\u{49D}', GeneralCategory::LowercaseLetter),
+    ('\u{49E}', '\u{49E}', GeneralCategory::UppercaseLetter),
+    ('\u{49F}', '\u{49F}', GeneralCategory::LowercaseLetter),
+    ('\u{4A0}', '\u{4A0}', GeneralCategory::UppercaseLetter),
+    ('\u{4A1}', '\u{4A1}', GeneralCategory::LowercaseLetter),
+    ('\u{4A2}', '\u{4A2}', GeneralCategory::UppercaseLetter),
+    ('\u{4A3}', '\u{4A3}', GeneralCategory::LowercaseLetter),
+    ('\u{4A4}', '\u{4A4}', GeneralCategory::UppercaseLetter),
+    ('\u{4A5}', '\u{4A5}', GeneralCategory::LowercaseLetter),
+    ('\u{4A6}', '\u{4A6}', GeneralCategory::UppercaseLetter),
+    ('\u{4A7}', '\u{4A7}', GeneralCategory::LowercaseLetter),
+    ('\u{4A8}', '\u{4A8}', GeneralCategory::UppercaseLetter),
+    ('\u{4A9}', '\u{4A9}', GeneralCategory::LowercaseLetter),
+    ('\u{4AA}', '\u{4AA}', GeneralCategory::UppercaseLetter),
+    ('\u{4AB}', '\u{4AB}', GeneralCategory::LowercaseLetter),
+    ('\u{4AC}', '\u{4AC}', GeneralCategory::UppercaseLetter),
+    ('\u{4AD}', '\u{4AD}', GeneralCategory::LowercaseLetter),
+    ('\u{4AE}', '\u{4AE}', GeneralCategory::UppercaseLetter),
+    ('\u{4AF}', '\u{4AF}', GeneralCategory::LowercaseLetter),
+    ('\u{4B0}', '\u{4B0}', GeneralCategory::UppercaseLetter),
+    ('\u{4B1}', '\u{4B1}', GeneralCategory::LowercaseLetter),
+    ('\u{4B2}', '\u{4B2}', GeneralCategory::UppercaseLetter),
+    ('\u{4B3}', '\u{4B3}', GeneralCategory::LowercaseLetter),
+    ('\u{4B4}', '\u{4B4}', GeneralCategory::UppercaseLetter),
+    ('\u{4B5}', '\u{4B5}', GeneralCategory::LowercaseLetter),
+    ('\u{4B6}', '\u{4B6}', GeneralCategory::UppercaseLetter),
+    ('\u{4B7}', '\u{4B7}', GeneralCategory::LowercaseLetter),
+    ('\u{4B8}', '\u{4B8}', GeneralCategory::UppercaseLetter),
+    ('\u{4B9}', '\u{4B9}', GeneralCategory::LowercaseLetter),
+    ('\u{4BA}', '\u{4BA}', GeneralCategory::UppercaseLetter),
+    ('\u{4BB}', '\u{4BB}', GeneralCategory::LowercaseLetter),
+    ('\u{4BC}', '\u{4BC}', GeneralCategory::UppercaseLetter),
+    ('\u{4BD}', '\u{4BD}', GeneralCategory::LowercaseLetter),
+    ('\u{4BE}', '\u{4BE}', GeneralCategory::UppercaseLetter),
+    ('\u{4BF}', '\u{4BF}', GeneralCategory::LowercaseLetter),
+    ('\u{4C0}', '\u{4C1}', GeneralCategory::UppercaseLetter),
+    ('\u{4C2}', '\u{4C2}', GeneralCategory::LowercaseLetter),
+    ('\u{4C3}', '\u{4C3}', GeneralCategory::UppercaseLetter),
+    ('\u{4C4}', '\u{4C4}', GeneralCategory::LowercaseLetter),
+    ('\u{4C5}', '\u{4C5}', GeneralCategory::UppercaseLetter),
+    ('\u{4C6}', '\u{4C6}', GeneralCategory::LowercaseLetter),
+    ('\u{4C7}', '\u{4C7}', GeneralCategory::UppercaseLetter),
+    ('\u{4C8}', '\u{4C8}', GeneralCategory::LowercaseLetter),
+    ('\u{4C9}', '\u{4C9}', GeneralCategory::UppercaseLetter),
+    ('\u{4CA}', '\u{4CA}', GeneralCategory::LowercaseLetter),
+    ('\u{4CB}', '\u{4CB}', GeneralCategory::UppercaseLetter),
+    ('\u{4CC}', '\u{4CC}', GeneralCategory::LowercaseLetter),
+    ('\u{4CD}', '\u{4CD}', GeneralCategory::UppercaseLetter),
+    ('\u{4CE}', '\u{4CF}', GeneralCategory::LowercaseLetter),
+    ('\u{4D0}', '\u{4D0}', GeneralCategory::UppercaseLetter),
+    ('\u{4D1}', '\u{4D1}', GeneralCategory::LowercaseLetter),
+    ('\u{4D2}', '\u{4D2}', GeneralCategory::UppercaseLetter),
+    ('\u{4D3}', '\u{4D3}', GeneralCategory::LowercaseLetter),
+    ('\u{4D4}', '\u{4D4}', GeneralCategory::UppercaseLetter),
+    ('\u{4D5}', '\u{4D5}', GeneralCategory::LowercaseLetter),
+    ('\u{4D6}', '\u{4D6}', GeneralCategory::UppercaseLetter),
+    ('\u{4D7}', '\u{4D7}', GeneralCategory::LowercaseLetter),
+    ('\u{4D8}', '\u{4D8}', GeneralCategory::UppercaseLetter),
+    ('\u{4D9}', '\u{4D9}', GeneralCategory::LowercaseLetter),
+    ('\u{4DA}', '\u{4DA}', GeneralCategory::UppercaseLetter),
+    ('\u{4DB}', '\u{4DB}', GeneralCategory::LowercaseLetter),
+    ('\u{4DC}', '\u{4DC}', GeneralCategory::UppercaseLetter),
+    ('\u{4DD}', '\u{4DD}', GeneralCategory::LowercaseLetter),
+    ('\u{4DE}', '\u{4DE}', GeneralCategory::UppercaseLetter),
+    ('\u{4DF}', '\u{4DF}', GeneralCategory::LowercaseLetter),
+    ('\u{4E0}', '\u{4E0}', GeneralCategory::UppercaseLetter),
+    ('\u{4E1}', '\u{4E1}', GeneralCategory::LowercaseLetter),
+    ('\u{4E2}', '\u{4E2}', GeneralCategory::UppercaseLetter),
+    ('\u{4E3}', '\u{4E3}', GeneralCategory::LowercaseLetter),
+    ('\u{4E4}', '\u{4E4}', GeneralCategory::UppercaseLetter),
+    ('\u{4E5}', '\u{4E5}', GeneralCategory::LowercaseLetter),
+    ('\u{4E6}', '\u{4E6}', GeneralCategory::UppercaseLetter),
+    ('\u{4E7}', '\u{4E7}', GeneralCategory::LowercaseLetter),
+    ('\u{4E8}', '\u{4E8}', GeneralCategory::UppercaseLetter),
+    ('\u{4E9}', '\u{4E9}', GeneralCategory::LowercaseLetter),
+    ('\u{4EA}', '\u{4EA}', GeneralCategory::UppercaseLetter),
+    ('\u{4EB}', '\u{4EB}', GeneralCategory::LowercaseLetter),
+    ('\u{4EC}', '\u{4EC}', GeneralCategory::UppercaseLetter),
+    ('\u{4ED}', '\u{4ED}', GeneralCategory::LowercaseLetter),
+    ('\u{4EE}', '\u{4EE}', GeneralCategory::UppercaseLetter),
+    ('\u{4EF}', '\u{4EF}', GeneralCategory::LowercaseLetter),
+    ('\u{4F0}', '\u{4F0}', GeneralCategory::UppercaseLetter),
+    ('\u{4F1}', '\u{4F1}', GeneralCategory::LowercaseLetter),
+    ('\u{4F2}', '\u{4F2}', GeneralCategory::UppercaseLetter),
+    ('\u{4F3}', '\u{4F3}', GeneralCategory::LowercaseLetter),
+    ('\u{4F4}', '\u{4F4}', GeneralCategory::UppercaseLetter),
+    ('\u{4F5}', '\u{4F5}', GeneralCategory::LowercaseLetter),
+    ('\u{4F6}', '\u{4F6}', GeneralCategory::UppercaseLetter),
+    ('\u{4F7}', '\u{4F7}', GeneralCategory::LowercaseLetter),
+    ('\u{4F8}', '\u{4F8}', GeneralCategory::UppercaseLetter),
+    ('\u{4F9}', '\u{4F9}', GeneralCategory::LowercaseLetter),
+    ('\u{4FA}', '\u{4FA}', GeneralCategory::UppercaseLetter),
+    ('\u{4FB}', '\u{4FB}', GeneralCategory::LowercaseLetter),
+    ('\u{4FC}', '\u{4FC}', GeneralCategory::UppercaseLetter),
+    ('\u{4FD}', '\u{4FD}', GeneralCategory::LowercaseLetter),
+    ('\u{4FE}', '\u{4FE}', GeneralCategory::UppercaseLetter),
+    ('\u{4FF}', '\u{4FF}', GeneralCategory::LowercaseLetter),
+    ('\u{500}', '\u{500}', GeneralCategory::UppercaseLetter),
+    ('\u{501}', '\u{501}', GeneralCategory::LowercaseLetter),
+    ('\u{502}', '\u{502}', GeneralCategory::UppercaseLetter),
+    ('\u{503}', '\u{503}', GeneralCategory::LowercaseLetter),
+    ('\u{504}', '\u{504}', GeneralCategory::UppercaseLetter),
+    ('\u{505}', '\u{505}', GeneralCategory::LowercaseLetter),
+    ('\u{506}', '\u{506}', GeneralCategory::UppercaseLetter),
+    ('\u{507}', '\u{507}', GeneralCategory::LowercaseLetter),
+    ('\u{508}', '\u{508}', GeneralCategory::UppercaseLetter),
+    ('\u{509}', '\u{509}', GeneralCategory::LowercaseLetter),
+    ('\u{50A}', '\u{50A}', GeneralCategory::UppercaseLetter),
+    ('\u{50B}', '\u{50B}', GeneralCategory::LowercaseLetter),
+    ('\u{50C}', '\u{50C}', GeneralCategory::UppercaseLetter),
+    ('\u{50D}', '\u{50D}', GeneralCategory::LowercaseLetter),
+    ('\u{50E}', '\u{50E}', GeneralCategory::UppercaseLetter),
+    ('\u{50F}', '\u{50F}', GeneralCategory::LowercaseLetter),
+    ('\u{510}', '\u{510}', GeneralCategory::UppercaseLetter),
+    ('\u{511}', '\u{511}', GeneralCategory::LowercaseLetter),
+    ('\u{512}', '\u{512}', GeneralCategory::UppercaseLetter),
+    ('\u{513}', '\u{513}', GeneralCategory::LowercaseLetter),
+    ('\u{514}', '\u{514}', GeneralCategory::UppercaseLetter),
+    ('\u{515}', '\u{515}', GeneralCategory::LowercaseLetter),
+    ('\u{516}', '\u{516}', GeneralCategory::UppercaseLetter),
+    ('\u{517}', '\u{517}', GeneralCategory::LowercaseLetter),
+    ('\u{518}', '\u{518}', GeneralCategory::UppercaseLetter),
+    ('\u{519}', '\u{519}', GeneralCategory::LowercaseLetter),
+    ('\u{51A}', '\u{51A}', GeneralCategory::UppercaseLetter),
+    ('\u{51B}', '\u{51B}', GeneralCategory::LowercaseLetter),
+    ('\u{51C}', '\u{51C}', GeneralCategory::UppercaseLetter),
+    ('\u{51D}', '\u{51D}', GeneralCategory::LowercaseLetter),
+    ('\u{51E}', '\u{51E}', GeneralCategory::UppercaseLetter),
+    ('\u{51F}', '\u{51F}', GeneralCategory::LowercaseLetter),
+    ('\u{520}', '\u{520}', GeneralCategory::UppercaseLetter),
+    ('\u{521}', '\u{521}', GeneralCategory::LowercaseLetter),
+    ('\u{522}', '\u{522}', GeneralCategory::UppercaseLetter),
+    ('\u{523}', '\u{523}', GeneralCategory::LowercaseLetter),
+    ('\u{524}', '\u{524}', GeneralCategory::UppercaseLetter),
+    ('\u{525}', '\u{525}', GeneralCategory::LowercaseLetter),
+    ('\u{526}', '\u{526}', GeneralCategory::UppercaseLetter),
+    ('\u{527}', '\u{527}', GeneralCategory::LowercaseLetter),
+    ('\u{528}', '\u{528}', GeneralCategory::UppercaseLetter),
+    ('\u{529}', '\u{529}', GeneralCategory::LowercaseLetter),
+    ('\u{52A}', '\u{52A}', GeneralCategory::UppercaseLetter),
+    ('\u{52B}', '\u{52B}', GeneralCategory::LowercaseLetter),
+    ('\u{52C}', '\u{52C}', GeneralCategory::UppercaseLetter),
+    ('\u{52D}', '\u{52D}', GeneralCategory::LowercaseLetter),
+    ('\u{52E}', '\u{52E}', GeneralCategory::UppercaseLetter),
+    ('\u{52F}', '\u{52F}', GeneralCategory::LowercaseLetter),
+    ('\u{531}', '\u{556}', GeneralCategory::UppercaseLetter),
+    ('\u{559}', '\u{559}', GeneralCategory::ModifierLetter),
+    ('\u{55A}', '\u{55F}', GeneralCategory::OtherPunctuation),
+    ('\u{560}', '\u{588}', GeneralCategory::LowercaseLetter),
+    ('\u{589}', '\u{589}', GeneralCategory::OtherPunctuation),
+    ('\u{58A}', '\u{58A}', GeneralCategory::DashPunctuation),
+    ('\u{58D}', '\u{58E}', GeneralCategory::OtherSymbol),
+    ('\u{58F}', '\u{58F}', GeneralCategory::CurrencySymbol),
+    ('\u{591}', '\u{5BD}', GeneralCategory::NonspacingMark),
+    ('\u{5BE}', '\u{5BE}', GeneralCategory::DashPunctuation),
+    ('\u{5BF}', '\u{5BF}', GeneralCategory::NonspacingMark),
+    ('\u{5C0}', '\u{5C0}', GeneralCategory::OtherPunctuation),
+    ('\u{5C1}', '\u{5C2}', GeneralCategory::NonspacingMark),
+    ('\u{5C3}', '\u{5C3}', GeneralCategory::OtherPunctuation),
+    ('\u{5C4}', '\u{5C5}', GeneralCategory::NonspacingMark),
+    ('\u{5C6}', '\u{5C6}', GeneralCategory::OtherPunctuation),
+    ('\u{5C7}', '\u{5C7}', GeneralCategory::NonspacingMark),
+    ('\u{5D0}', '\u{5EA}', GeneralCategory::OtherLetter),
+    ('\u{5EF}', '\u{5F2}', GeneralCategory::OtherLetter),
+    ('\u{5F3}', '\u{5F4}', GeneralCategory::OtherPunctuation),
+    ('\u{600}', '\u{605}', GeneralCategory::Format),
+    ('\u{606}', '\u{608}', GeneralCategory::MathSymbol),
+    ('\u{609}', '\u{60A}', GeneralCategory::OtherPunctuation),
+    ('\u{60B}', '\u{60B}', GeneralCategory::CurrencySymbol),
+    ('\u{60C}', '\u{60D}', GeneralCategory::OtherPunctuation),
+    ('\u{60E}', '\u{60F}', GeneralCategory::OtherSymbol),
+    ('\u{610}', '\u{61A}', GeneralCategory::NonspacingMark),
+    ('\u{61B}', '\u{61B}', GeneralCategory::OtherPunctuation),
+    ('\u{61C}', '\u{61C}', GeneralCategory::Format),
+    ('\u{61D}', '\u{61F}', GeneralCategory::OtherPunctuation),
+    ('\u{620}', '\u{63F}', GeneralCategory::OtherLetter),
+    ('\u{640}', '\u{640}', GeneralCategory::ModifierLetter),
+    ('\u{641}', '\u{64A}', GeneralCategory::OtherLetter),
+    ('\u{64B}', '\u{65F}', GeneralCategory::NonspacingMark),
+    ('\u{660}', '\u{669}', GeneralCategory::DecimalNumber),
+    ('\u{66A}', '\u{66D}', GeneralCategory::OtherPunctuation),
+    ('\u{66E}', '\u{66F}', GeneralCategory::OtherLetter),
+    ('\u{670}', '\u{670}', GeneralCategory::NonspacingMark),
+    ('\u{671}', '\u{6D3}', GeneralCategory::OtherLetter),
+    ('\u{6D4}', '\u{6D4}', GeneralCategory::OtherPunctuation),
+    ('\u{6D5}', '\u{6D5}', GeneralCategory::OtherLetter),
+    ('\u{6D6}', '\u{6DC}', GeneralCategory::NonspacingMark),
+    ('\u{6DD}', '\u{6DD}', GeneralCategory::Format),
+    ('\u{6DE}', '\u{6DE}', GeneralCategory::OtherSymbol),
+    ('\u{6DF}', '\u{6E4}', GeneralCategory::NonspacingMark),
+    ('\u{6E5}', '\u{6E6}', GeneralCategory::ModifierLetter),
+    ('\u{6E7}', '\u{6E8}', GeneralCategory::NonspacingMark),
+    ('\u{6E9}', '\u{6E9}', GeneralCategory::OtherSymbol),
+    ('\u{6EA}', '\u{6ED}', GeneralCategory::NonspacingMark),
+    ('\u{6EE}', '\u{6EF}', GeneralCategory::OtherLetter),
+    ('\u{6F0}', '\u{6F9}', GeneralCategory::DecimalNumber),
+    ('\u{6FA}', '\u{6FC}', GeneralCategory::OtherLetter),
+    ('\u{6FD}', '\u{6FE}', GeneralCategory::OtherSymbol),
+    ('\u{6FF}', '\u{6FF}', GeneralCategory::OtherLetter),
+    ('\u{700}', '\u{70D}', GeneralCategory::OtherPunctuation),
+    ('\u{70F}', '\u{70F}', GeneralCategory::Format),
+    ('\u{710}', '\u{710}', GeneralCategory::OtherLetter),
+    ('\u{711}', '\u{711}', GeneralCategory::NonspacingMark),
+    ('\u{712}', '\u{72F}', GeneralCategory::OtherLetter),
+    ('\u{730}', '\u{74A}', GeneralCategory::NonspacingMark),
+    ('\u{74D}', '\u{7A5}', GeneralCategory::OtherLetter),
+    ('\u{7A6}', '\u{7B0}', GeneralCategory::NonspacingMark),
+    ('\u{7B1}', '\u{7B1}', GeneralCategory::OtherLetter),
+    ('\u{7C0}', '\u{7C9}', GeneralCategory::DecimalNumber),
+    ('\u{7CA}', '\u{7EA}', GeneralCategory::OtherLetter),
+    ('\u{7EB}', '\u{7F3}', GeneralCategory::NonspacingMark),
+    ('\u{7F4}', '\u{7F5}', GeneralCategory::ModifierLetter),
+    ('\u{7F6}', '\u{7F6}', GeneralCategory::OtherSymbol),
+    ('\u{7F7}', '\u{7F9}', GeneralCategory::OtherPunctuation),
+    ('\u{7FA}', '\u{7FA}', GeneralCategory::ModifierLetter),
+    ('\u{7FD}', '\u{7FD}', GeneralCategory::NonspacingMark),
+    ('\u{7FE}', '\u{7FF}', GeneralCategory::CurrencySymbol),
+    ('\u{800}', '\u{815}', GeneralCategory::OtherLetter),
+    ('\u{816}', '\u{819}', GeneralCategory::NonspacingMark),
+    ('\u{81A}', '\u{81A}', GeneralCategory::ModifierLetter),
+    ('\u{81B}', '\u{823}', GeneralCategory::NonspacingMark),
+    ('\u{824}', '\u{824}', GeneralCategory::ModifierLetter),
+    ('\u{825}', '\u{827}', GeneralCategory::NonspacingMark),
+    ('\u{828}', '\u{828}', GeneralCategory::ModifierLetter),
+    ('\u{829}', '\u{82D}', GeneralCategory::NonspacingMark),
+    ('\u{830}', '\u{83E}', GeneralCategory::OtherPunctuation),
+    ('\u{840}', '\u{858}', GeneralCategory::OtherLetter),
+    ('\u{859}', '\u{85B}', GeneralCategory::NonspacingMark),
+    ('\u{85E}', '\u{85E}', GeneralCategory::OtherPunctuation),
+    ('\u{860}', '\u{86A}', GeneralCategory::OtherLetter),
+    ('\u{870}', '\u{887}', GeneralCategory::OtherLetter),
+    ('\u{888}', '\u{888}', GeneralCategory::ModifierSymbol),
+    ('\u{889}', '\u{88E}', GeneralCategory::OtherLetter),
+    ('\u{890}', '\u{891}', GeneralCategory::Format),
+    ('\u{898}', '\u{89F}', GeneralCategory::NonspacingMark),
+    ('\u{8A0}', '\u{8C8}', GeneralCategory::OtherLetter),
+    ('\u{8C9}', '\u{8C9}', GeneralCategory::ModifierLetter),
+    ('\u{8CA}', '\u{8E1}', GeneralCategory::NonspacingMark),
+    ('\u{8E2}', '\u{8E2}', GeneralCategory::Format),
+    ('\u{8E3}', '\u{902}', GeneralCategory::NonspacingMark),
+    ('\u{903}', '\u{903}', GeneralCategory::SpacingMark),
+    ('\u{904}', '\u{939}', GeneralCategory::OtherLetter),
+    ('\u{93A}', '\u{93A}', GeneralCategory::NonspacingMark),
+    ('\u{93B}', '\u{93B}', GeneralCategory::SpacingMark),
+    ('\u{93C}', '\u{93C}', GeneralCategory::NonspacingMark),
+    ('\u{93D}', '\u{93D}', GeneralCategory::OtherLetter),
+    ('\u{93E}', '\u{940}', GeneralCategory::SpacingMark),
+    ('\u{941}', '\u{948}', GeneralCategory::NonspacingMark),
+    ('\u{949}', '\u{94C}', GeneralCategory::SpacingMark),
+    ('\u{94D}', '\u{94D}', GeneralCategory::NonspacingMark),
+    ('\u{94E}', '\u{94F}', GeneralCategory::SpacingMark),
+    ('\u{950}', '\u{950}', GeneralCategory::OtherLetter),
+    ('\u{951}', '\u{957}', GeneralCategory::NonspacingMark),
+    ('\u{958}', '\u{961}', GeneralCategory::OtherLetter),
+    ('\u{962}', '\u{963}', GeneralCategory::NonspacingMark),
+    ('\u{964}', '\u{965}', GeneralCategory::OtherPunctuation),
+    ('\u{966}', '\u{96F}', GeneralCategory::DecimalNumber),
+    ('\u{970}', '\u{970}', GeneralCategory::OtherPunctuation),
+    ('\u{971}', '\u{971}', GeneralCategory::ModifierLetter),
+    ('\u{972}', '\u{980}', GeneralCategory::OtherLetter),
+    ('\u{981}', '\u{981}', GeneralCategory::NonspacingMark),
+    ('\u{982}', '\u{983}', GeneralCategory::SpacingMark),
+    ('\u{985}', '\u{98C}', GeneralCategory::OtherLetter),
+    ('\u{98F}', '\u{990}', GeneralCategory::OtherLetter),
+    ('\u{993}', '\u{9A8}', GeneralCategory::OtherLetter),
+    ('\u{9AA}', '\u{9B0}', GeneralCategory::OtherLetter),
+    ('\u{9B2}', '\u{9B2}', GeneralCategory::OtherLetter),
+    ('\u{9B6}', '\u{9B9}', GeneralCategory::OtherLetter),
+    ('\u{9BC}', '\u{9BC}', GeneralCategory::NonspacingMark),
+    ('\u{9BD}', '\u{9BD}', GeneralCategory::OtherLetter),
+    ('\u{9BE}', '\u{9C0}', GeneralCategory::SpacingMark),
+    ('\u{9C1}', '\u{9C4}', GeneralCategory::NonspacingMark),
+    ('\u{9C7}', '\u{9C8}', GeneralCategory::SpacingMark),
+    ('\u{9CB}', '\u{9CC}', GeneralCategory::SpacingMark),
+    ('\u{9CD}', '\u{9CD}', GeneralCategory::NonspacingMark),
+    ('\u{9CE}', '\u{9CE}', GeneralCategory::OtherLetter),
+    ('\u{9D7}', '\u{9D7}', GeneralCategory::SpacingMark),
+    ('\u{9DC}', '\u{9DD}', GeneralCategory::OtherLetter),
+    ('\u{9DF}', '\u{9E1}', GeneralCategory::OtherLetter),
+    ('\u{9E2}', '\u{9E3}', GeneralCategory::NonspacingMark),
+    ('\u{9E6}', '\u{9EF}', GeneralCategory::DecimalNumber),
+    ('\u{9F0}', '\u{9F1}', GeneralCategory::OtherLetter),
+    ('\u{9F2}', '\u{9F3}', GeneralCategory::CurrencySymbol),
+    ('\u{9F4}', '\u{9F9}', GeneralCategory::OtherNumber),
+    ('\u{9FA}', '\u{9FA}', GeneralCategory::OtherSymbol),
+    ('\u{9FB}', '\u{9FB}', GeneralCategory::CurrencySymbol),
+    ('\u{9FC}', '\u{9FC}', GeneralCategory::OtherLetter),
+    ('\u{9FD}', '\u{9FD}', GeneralCategory::OtherPunctuation),
+    ('\u{9FE}', '\u{9FE}', GeneralCategory::NonspacingMark),
+    ('\u{A01}', '\u{A02}', GeneralCategory::NonspacingMark),
+    ('\u{A03}', '\u{A03}', GeneralCategory::SpacingMark),
+    ('\u{A05}', '\u{A0A}', GeneralCategory::OtherLetter),
+    ('\u{A0F}', '\u{A10}', GeneralCategory::OtherLetter),
+    ('\u{A13}', '\u{A28}', GeneralCategory::OtherLetter),
+    ('\u{A2A}', '\u{A30}', GeneralCategory::OtherLetter),
+    ('\u{A32}', '\u{A33}', GeneralCategory::OtherLetter),
+    ('\u{A35}', '\u{A36}', GeneralCategory::OtherLetter),
+    ('\u{A38}', '\u{A39}', GeneralCategory::OtherLetter),
+    ('\u{A3C}', '\u{A3C}', GeneralCategory::NonspacingMark),
+    ('\u{A3E}', '\u{A40}', GeneralCategory::SpacingMark),
+    ('\u{A41}', '\u{A42}', GeneralCategory::NonspacingMark),
+    ('\u{A47}', '\u{A48}', GeneralCategory::NonspacingMark),
+    ('\u{A4B}', '\u{A4D}', GeneralCategory::NonspacingMark),
+    ('\u{A51}', '\u{A51}', GeneralCategory::NonspacingMark),
+    ('\u{A59}', '\u{A5C}', GeneralCategory::OtherLetter),
+    ('\u{A5E}', '\u{A5E}', GeneralCategory::OtherLetter),
+    ('\u{A66}', '\u{A6F}', GeneralCategory::DecimalNumber),
+    ('\u{A70}', '\u{A71}', GeneralCategory::NonspacingMark),
+    ('\u{A72}', '\u{A74}', GeneralCategory::OtherLetter),
+    ('\u{A75}', '\u{A75}', GeneralCategory::NonspacingMark),
+    ('\u{A76}', '\u{A76}', GeneralCategory::OtherPunctuation),
+    ('\u{A81}', '\u{A82}', GeneralCategory::NonspacingMark),
+    ('\u{A83}', '\u{A83}', GeneralCategory::SpacingMark),
+    ('\u{A85}', '\u{A8D}', GeneralCategory::OtherLetter),
+    ('\u{A8F}', '\u{A91}', GeneralCategory::OtherLetter),
+    ('\u{A93}', '\u{AA8}', GeneralCategory::OtherLetter),
+    ('\u{AAA}', '\u{AB0}', GeneralCategory::OtherLetter),
+    ('\u{AB2}', '\u{AB3}', GeneralCategory::OtherLetter),
+    ('\u{AB5}', '\u{AB9}', GeneralCategory::OtherLetter),
+    ('\u{ABC}', '\u{ABC}', GeneralCategory::NonspacingMark),
+    ('\u{ABD}', '\u{ABD}', GeneralCategory::OtherLetter),
+    ('\u{ABE}', '\u{AC0}', GeneralCategory::SpacingMark),
+    ('\u{AC1}', '\u{AC5}', GeneralCategory::NonspacingMark),
+    ('\u{AC7}', '\u{AC8}', GeneralCategory::NonspacingMark),
+    ('\u{AC9}', '\u{AC9}', GeneralCategory::SpacingMark),
+    ('\u{ACB}', '\u{ACC}', GeneralCategory::SpacingMark),
+    ('\u{ACD}', '\u{ACD}', GeneralCategory::NonspacingMark),
+    ('\u{AD0}', '\u{AD0}', GeneralCategory::OtherLetter),
+    ('\u{AE0}', '\u{AE1}', GeneralCategory::OtherLetter),
+    ('\u{AE2}', '\u{AE3}', GeneralCategory::NonspacingMark),
+    ('\u{AE6}', '\u{AEF}', GeneralCategory::DecimalNumber),
+    ('\u{AF0}', '\u{AF0}', GeneralCategory::OtherPunctuation),
+    ('\u{AF1}', '\u{AF1}', GeneralCategory::CurrencySymbol),
+    ('\u{AF9}', '\u{AF9}', GeneralCategory::OtherLetter),
+    ('\u{AFA}', '\u{AFF}', GeneralCategory::NonspacingMark),
+    ('\u{B01}', '\u{B01}', GeneralCategory::NonspacingMark),
+    ('\u{B02}', '\u{B03}', GeneralCategory::SpacingMark),
+    ('\u{B05}', '\u{B0C}', GeneralCategory::OtherLetter),
+    ('\u{B0F}', '\u{B10}', GeneralCategory::OtherLetter),
+    ('\u{B13}', '\u{B28}', GeneralCategory::OtherLetter),
+    ('\u{B2A}', '\u{B30}', GeneralCategory::OtherLetter),
+    ('\u{B32}', '\u{B33}', GeneralCategory::OtherLetter),
+    ('\u{B35}', '\u{B39}', GeneralCategory::OtherLetter),
+    ('\u{B3C}', '\u{B3C}', GeneralCategory::NonspacingMark),
+    ('\u{B3D}', '\u{B3D}', GeneralCategory::OtherLetter),
+    ('\u{B3E}', '\u{B3E}', GeneralCategory::SpacingMark),
+    ('\u{B3F}', '\u{B3F}', GeneralCategory::NonspacingMark),
+    ('\u{B40}', '\u{B40}', GeneralCategory::SpacingMark),
+    ('\u{B41}', '\u{B44}', GeneralCategory::NonspacingMark),
+    ('\u{B47}', '\u{B48}', GeneralCategory::SpacingMark),
+    ('\u{B4B}', '\u{B4C}', GeneralCategory::SpacingMark),
+    ('\u{B4D}', '\u{B4D}', GeneralCategory::NonspacingMark),
+    ('\u{B55}', '\u{B56}', GeneralCategory::NonspacingMark),
+    ('\u{B57}', '\u{B57}', GeneralCategory::SpacingMark),
+    ('\u{B5C}', '\u{B5D}', GeneralCategory::OtherLetter),
+    ('\u{B5F}', '\u{B61}', GeneralCategory::OtherLetter),
+    ('\u{B62}', '\u{B63}', GeneralCategory::NonspacingMark),
+    ('\u{B66}', '\u{B6F}', GeneralCategory::DecimalNumber),
+    ('\u{B70}', '\u{B70}', GeneralCategory::OtherSymbol),
+    ('\u{B71}', '\u{B71}', GeneralCategory::OtherLetter),
+    ('\u{B72}', '\u{B77}', GeneralCategory::OtherNumber),
+    ('\u{B82}', '\u{B82}', GeneralCategory::NonspacingMark),
+    ('\u{B83}', '\u{B83}', GeneralCategory::OtherLetter),
+    ('\u{B85}', '\u{B8A}', GeneralCategory::OtherLetter),
+    ('\u{B8E}', '\u{B90}', GeneralCategory::OtherLetter),
+    ('\u{B92}', '\u{B95}', GeneralCategory::OtherLetter),
+    ('\u{B99}', '\u{B9A}', GeneralCategory::OtherLetter),
+    ('\u{B9C}', '\u{B9C}', GeneralCategory::OtherLetter),
+    ('\u{B9E}', '\u{B9F}', GeneralCategory::OtherLetter),
+    ('\u{BA3}', '\u{BA4}', GeneralCategory::OtherLetter),
+    ('\u{BA8}', '\u{BAA}', GeneralCategory::OtherLetter),
+    ('\u{BAE}', '\u{BB9}', GeneralCategory::OtherLetter),
+    ('\u{BBE}', '\u{BBF}', GeneralCategory::SpacingMark),
+    ('\u{BC0}', '\u{BC0}', GeneralCategory::NonspacingMark),
+    ('\u{BC1}', '\u{BC2}', GeneralCategory::SpacingMark),
+    ('\u{BC6}', '\u{BC8}', GeneralCategory::SpacingMark),
+    ('\u{BCA}', '\u{BCC}', GeneralCategory::SpacingMark),
+    ('\u{BCD}', '\u{BCD}', GeneralCategory::NonspacingMark),
+    ('\u{BD0}', '\u{BD0}', GeneralCategory::OtherLetter),
+    ('\u{BD7}', '\u{BD7}', GeneralCategory::SpacingMark),
+    ('\u{BE6}', '\u{BEF}', GeneralCategory::DecimalNumber),
+    ('\u{BF0}', '\u{BF2}', GeneralCategory::OtherNumber),
+    ('\u{BF3}', '\u{BF8}', GeneralCategory::OtherSymbol),
+    ('\u{BF9}', '\u{BF9}', GeneralCategory::CurrencySymbol),
+    ('\u{BFA}', '\u{BFA}', GeneralCategory::OtherSymbol),
+    ('\u{C00}', '\u{C00}', GeneralCategory::NonspacingMark),
+    ('\u{C01}', '\u{C03}', GeneralCategory::SpacingMark),
+    ('\u{C04}', '\u{C04}', GeneralCategory::NonspacingMark),
+    ('\u{C05}', '\u{C0C}', GeneralCategory::OtherLetter),
+    ('\u{C0E}', '\u{C10}', GeneralCategory::OtherLetter),
+    ('\u{C12}', '\u{C28}', GeneralCategory::OtherLetter),
+    ('\u{C2A}', '\u{C39}', GeneralCategory::OtherLetter),
+    ('\u{C3C}', '\u{C3C}', GeneralCategory::NonspacingMark),
+    ('\u{C3D}', '\u{C3D}', GeneralCategory::OtherLetter),
+    ('\u{C3E}', '\u{C40}', GeneralCategory::NonspacingMark),
+    ('\u{C41}', '\u{C44}', GeneralCategory::SpacingMark),
+    ('\u{C46}', '\u{C48}', GeneralCategory::NonspacingMark),
+    ('\u{C4A}', '\u{C4D}', GeneralCategory::NonspacingMark),
+    ('\u{C55}', '\u{C56}', GeneralCategory::NonspacingMark),
+    ('\u{C58}', '\u{C5A}', GeneralCategory::OtherLetter),
+    ('\u{C5D}', '\u{C5D}', GeneralCategory::OtherLetter),
+    ('\u{C60}', '\u{C61}', GeneralCategory::OtherLetter),
+    ('\u{C62}', '\u{C63}', GeneralCategory::NonspacingMark),
+    ('\u{C66}', '\u{C6F}', GeneralCategory::DecimalNumber),
+    ('\u{C77}', '\u{C77}', GeneralCategory::OtherPunctuation),
+    ('\u{C78}', '\u{C7E}', GeneralCategory::OtherNumber),
+    ('\u{C7F}', '\u{C7F}', GeneralCategory::OtherSymbol),
+    ('\u{C80}', '\u{C80}', GeneralCategory::OtherLetter),
+    ('\u{C81}', '\u{C81}', GeneralCategory::NonspacingMark),
+    ('\u{C82}', '\u{C83}', GeneralCategory::SpacingMark),
+    ('\u{C84}', '\u{C84}', GeneralCategory::OtherPunctuation),
+    ('\u{C85}', '\u{C8C}', GeneralCategory::OtherLetter),
+    ('\u{C8E}', '\u{C90}', GeneralCategory::OtherLetter),
+    ('\u{C92}', '\u{CA8}', GeneralCategory::OtherLetter),
+    ('\u{CAA}', '\u{CB3}', GeneralCategory::OtherLetter),
+    ('\u{CB5}', '\u{CB9}', GeneralCategory::OtherLetter),
+    ('\u{CBC}', '\u{CBC}', GeneralCategory::NonspacingMark),
+    ('\u{CBD}', '\u{CBD}', GeneralCategory::OtherLetter),
+    ('\u{CBE}', '\u{CBE}', GeneralCategory::SpacingMark),
+    ('\u{CBF}', '\u{CBF}', GeneralCategory::NonspacingMark),
+    ('\u{CC0}', '\u{CC4}', GeneralCategory::SpacingMark),
+    ('\u{CC6}', '\u{CC6}', GeneralCategory::NonspacingMark),
+    ('\u{CC7}', '\u{CC8}', GeneralCategory::SpacingMark),
+    ('\u{CCA}', '\u{CCB}', GeneralCategory::SpacingMark),
+    ('\u{CCC}', '\u{CCD}', GeneralCategory::NonspacingMark),
+    ('\u{CD5}', '\u{CD6}', GeneralCategory::SpacingMark),
+    ('\u{CDD}', '\u{CDE}', GeneralCategory::OtherLetter),
+    ('\u{CE0}', '\u{CE1}', GeneralCategory::OtherLetter),
+    ('\u{CE2}', '\u{CE3}', GeneralCategory::NonspacingMark),
+    ('\u{CE6}', '\u{CEF}', GeneralCategory::DecimalNumber),
+    ('\u{CF1}', '\u{CF2}', GeneralCategory::OtherLetter),
+    ('\u{D00}', '\u{D01}', GeneralCategory::NonspacingMark),
+    ('\u{D02}', '\u{D03}', GeneralCategory::SpacingMark),
+    ('\u{D04}', '\u{D0C}', GeneralCategory::OtherLetter),
+    ('\u{D0E}', '\u{D10}', GeneralCategory::OtherLetter),
+    ('\u{D12}', '\u{D3A}', GeneralCategory::OtherLetter),
+    ('\u{D3B}', '\u{D3C}', GeneralCategory::NonspacingMark),
+    ('\u{D3D}', '\u{D3D}', GeneralCategory::OtherLetter),
+    ('\u{D3E}', '\u{D40}', GeneralCategory::SpacingMark),
+    ('\u{D41}', '\u{D44}', GeneralCategory::NonspacingMark),
+    ('\u{D46}', '\u{D48}', GeneralCategory::SpacingMark),
+    ('\u{D4A}', '\u{D4C}', GeneralCategory::SpacingMark),
+    ('\u{D4D}', '\u{D4D}', GeneralCategory::NonspacingMark),
+    ('\u{D4E}', '\u{D4E}', GeneralCategory::OtherLetter),
+    ('\u{D4F}', '\u{D4F}', GeneralCategory::OtherSymbol),
+    ('\u{D54}', '\u{D56}', GeneralCategory::OtherLetter),
+    ('\u{D57}', '\u{D57}', GeneralCategory::SpacingMark),
+    ('\u{D58}', '\u{D5E}', GeneralCategory::OtherNumber),
+    ('\u{D5F}', '\u{D61}', GeneralCategory::OtherLetter),
+    ('\u{D62}', '\u{D63}', GeneralCategory::NonspacingMark),
+    ('\u{D66}', '\u{D6F}', GeneralCategory::DecimalNumber),
+    ('\u{D70}', '\u{D78}', GeneralCategory::OtherNumber),
+    ('\u{D79}', '\u{D79}', GeneralCategory::OtherSymbol),
+    ('\u{D7A}', '\u{D7F}', GeneralCategory::OtherLetter),
+    ('\u{D81}', '\u{D81}', GeneralCategory::NonspacingMark),
+    ('\u{D82}', '\u{D83}', GeneralCategory::SpacingMark),
+    ('\u{D85}', '\u{D96}', GeneralCategory::OtherLetter),
+    ('\u{D9A}', '\u{DB1}', GeneralCategory::OtherLetter),
+    ('\u{DB3}', '\u{DBB}', GeneralCategory::OtherLetter),
+    ('\u{DBD}', '\u{DBD}', GeneralCategory::OtherLetter),
+    ('\u{DC0}', '\u{DC6}', GeneralCategory::OtherLetter),
+    ('\u{DCA}', '\u{DCA}', GeneralCategory::NonspacingMark),
+    ('\u{DCF}', '\u{DD1}', GeneralCategory::SpacingMark),
+    ('\u{DD2}', '\u{DD4}', GeneralCategory::NonspacingMark),
+    ('\u{DD6}', '\u{DD6}', GeneralCategory::NonspacingMark),
+    ('\u{DD8}', '\u{DDF}', GeneralCategory::SpacingMark),
+    ('\u{DE6}', '\u{DEF}', GeneralCategory::DecimalNumber),
+    ('\u{DF2}', '\u{DF3}', GeneralCategory::SpacingMark),
+    ('\u{DF4}', '\u{DF4}', GeneralCategory::OtherPunctuation),
+    ('\u{E01}', '\u{E30}', GeneralCategory::OtherLetter),
+    ('\u{E31}', '\u{E31}', GeneralCategory::NonspacingMark),
+    ('\u{E32}', '\u{E33}', GeneralCategory::OtherLetter),
+    ('\u{E34}', '\u{E3A}', GeneralCategory::NonspacingMark),
+    ('\u{E3F}', '\u{E3F}', GeneralCategory::CurrencySymbol),
+    ('\u{E40}', '\u{E45}', GeneralCategory::OtherLetter),
+    ('\u{E46}', '\u{E46}', GeneralCategory::ModifierLetter),
+    ('\u{E47}', '\u{E4E}', GeneralCategory::NonspacingMark),
+    ('\u{E4F}', '\u{E4F}', GeneralCategory::OtherPunctuation),
+    ('\u{E50}', '\u{E59}', GeneralCategory::DecimalNumber),
+    ('\u{E5A}', '\u{E5B}', GeneralCategory::OtherPunctuation),
+    ('\u{E81}', '\u{E82}', GeneralCategory::OtherLetter),
+    ('\u{E84}', '\u{E84}', GeneralCategory::OtherLetter),
+    ('\u{E86}', '\u{E8A}', GeneralCategory::OtherLetter),
+    ('\u{E8C}', '\u{EA3}', GeneralCategory::OtherLetter),
+    ('\u{EA5}', '\u{EA5}', GeneralCategory::OtherLetter),
+    ('\u{EA7}', '\u{EB0}', GeneralCategory::OtherLetter),
+    ('\u{EB1}', '\u{EB1}', GeneralCategory::NonspacingMark),
+    ('\u{EB2}', '\u{EB3}', GeneralCategory::OtherLetter),
+    ('\u{EB4}', '\u{EBC}', GeneralCategory::NonspacingMark),
+    ('\u{EBD}', '\u{EBD}', GeneralCategory::OtherLetter),
+    ('\u{EC0}', '\u{EC4}', GeneralCategory::OtherLetter),
+    ('\u{EC6}', '\u{EC6}', GeneralCategory::ModifierLetter),
+    ('\u{EC8}', '\u{ECD}', GeneralCategory::NonspacingMark),
+    ('\u{ED0}', '\u{ED9}', GeneralCategory::DecimalNumber),
+    ('\u{EDC}', '\u{EDF}', GeneralCategory::OtherLetter),
+    ('\u{F00}', '\u{F00}', GeneralCategory::OtherLetter),
+    ('\u{F01}', '\u{F03}', GeneralCategory::OtherSymbol),
+    ('\u{F04}', '\u{F12}', GeneralCategory::OtherPunctuation),
+    ('\u{F13}', '\u{F13}', GeneralCategory::OtherSymbol),
+    ('\u{F14}', '\u{F14}', GeneralCategory::OtherPunctuation),
+    ('\u{F15}', '\u{F17}', GeneralCategory::OtherSymbol),
+    ('\u{F18}', '\u{F19}', GeneralCategory::NonspacingMark),
+    ('\u{F1A}', '\u{F1F}', GeneralCategory::OtherSymbol),
+    ('\u{F20}', '\u{F29}', GeneralCategory::DecimalNumber),
+    ('\u{F2A}', '\u{F33}', GeneralCategory::OtherNumber),
+    ('\u{F34}', '\u{F34}', GeneralCategory::OtherSymbol),
+    ('\u{F35}', '\u{F35}', GeneralCategory::NonspacingMark),
+    ('\u{F36}', '\u{F36}', GeneralCategory::OtherSymbol),
+    ('\u{F37}', '\u{F37}', GeneralCategory::NonspacingMark),
+    ('\u{F38}', '\u{F38}', GeneralCategory::OtherSymbol),
+    ('\u{F39}', '\u{F39}', GeneralCategory::NonspacingMark),
+    ('\u{F3A}', '\u{F3A}', GeneralCategory::OpenPunctuation),
+    ('\u{F3B}', '\u{F3B}', GeneralCategory::ClosePunctuation),
+    ('\u{F3C}', '\u{F3C}', GeneralCategory::OpenPunctuation),
+    ('\u{F3D}', '\u{F3D}', GeneralCategory::ClosePunctuation),
+    ('\u{F3E}', '\u{F3F}', GeneralCategory::SpacingMark),
+    ('\u{F40}', '\u{F47}', GeneralCategory::OtherLetter),
+    ('\u{F49}', '\u{F6C}', GeneralCategory::OtherLetter),
+    ('\u{F71}', '\u{F7E}', GeneralCategory::NonspacingMark),
+    ('\u{F7F}', '\u{F7F}', GeneralCategory::SpacingMark),
+    ('\u{F80}', '\u{F84}', GeneralCategory::NonspacingMark),
+    ('\u{F85}', '\u{F85}', GeneralCategory::OtherPunctuation),
+    ('\u{F86}', '\u{F87}', GeneralCategory::NonspacingMark),
+    ('\u{F88}', '\u{F8C}', GeneralCategory::OtherLetter),
+    ('\u{F8D}', '\u{F97}', GeneralCategory::NonspacingMark),
+    ('\u{F99}', '\u{FBC}', GeneralCategory::NonspacingMark),
+    ('\u{FBE}', '\u{FC5}', GeneralCategory::OtherSymbol),
+    ('\u{FC6}', '\u{FC6}', GeneralCategory::NonspacingMark),
+    ('\u{FC7}', '\u{FCC}', GeneralCategory::OtherSymbol),
+    ('\u{FCE}', '\u{FCF}', GeneralCategory::OtherSymbol),
+    ('\u{FD0}', '\u{FD4}', GeneralCategory::OtherPunctuation),
+    ('\u{FD5}', '\u{FD8}', GeneralCategory::OtherSymbol),
+    ('\u{FD9}', '\u{FDA}', GeneralCategory::OtherPunctuation),
+    ('\u{1000}', '\u{102A}', GeneralCategory::OtherLetter),
+    ('\u{102B}', '\u{102C}', GeneralCategory::SpacingMark),
+    ('\u{102D}', '\u{1030}', GeneralCategory::NonspacingMark),
+    ('\u{1031}', '\u{1031}', GeneralCategory::SpacingMark),
+    ('\u{1032}', '\u{1037}', GeneralCategory::NonspacingMark),
+    ('\u{1038}', '\u{1038}', GeneralCategory::SpacingMark),
+    ('\u{1039}', '\u{103A}', GeneralCategory::NonspacingMark),
+    ('\u{103B}', '\u{103C}', GeneralCategory::SpacingMark),
+    ('\u{103D}', '\u{103E}', GeneralCategory::NonspacingMark),
+    ('\u{103F}', '\u{103F}', GeneralCategory::OtherLetter),
+    ('\u{1040}', '\u{1049}', GeneralCategory::DecimalNumber),
+    ('\u{104A}', '\u{104F}', GeneralCategory::OtherPunctuation),
+    ('\u{1050}', '\u{1055}', GeneralCategory::OtherLetter),
+    ('\u{1056}', '\u{1057}', GeneralCategory::SpacingMark),
+    ('\u{1058}', '\u{1059}', GeneralCategory::NonspacingMark),
+    ('\u{105A}', '\u{105D}', GeneralCategory::OtherLetter),
+    ('\u{105E}', '\u{1060}', GeneralCategory::NonspacingMark),
+    ('\u{1061}', '\u{1061}', GeneralCategory::OtherLetter),
+    ('\u{1062}', '\u{1064}', GeneralCategory::SpacingMark),
+    ('\u{1065}', '\u{1066}', GeneralCategory::OtherLetter),
+    ('\u{1067}', '\u{106D}', GeneralCategory::SpacingMark),
+    ('\u{106E}', '\u{1070}', GeneralCategory::OtherLetter),
+    ('\u{1071}', '\u{1074}', GeneralCategory::NonspacingMark),
+    ('\u{1075}', '\u{1081}', GeneralCategory::OtherLetter),
+    ('\u{1082}', '\u{1082}', GeneralCategory::NonspacingMark),
+    ('\u{1083}', '\u{1084}', GeneralCategory::SpacingMark),
+    ('\u{1085}', '\u{1086}', GeneralCategory::NonspacingMark),
+    ('\u{1087}', '\u{108C}', GeneralCategory::SpacingMark),
+    ('\u{108D}', '\u{108D}', GeneralCategory::NonspacingMark),
+    ('\u{108E}', '\u{108E}', GeneralCategory::OtherLetter),
+    ('\u{108F}', '\u{108F}', GeneralCategory::SpacingMark),
+    ('\u{1090}', '\u{1099}', GeneralCategory::DecimalNumber),
+    ('\u{109A}', '\u{109C}', GeneralCategory::SpacingMark),
+    ('\u{109D}', '\u{109D}', GeneralCategory::NonspacingMark),
+    ('\u{109E}', '\u{109F}', GeneralCategory::OtherSymbol),
+    ('\u{10A0}', '\u{10C5}', GeneralCategory::UppercaseLetter),
+    ('\u{10C7}', '\u{10C7}', GeneralCategory::UppercaseLetter),
+    ('\u{10CD}', '\u{10CD}', GeneralCategory::UppercaseLetter),
+    ('\u{10D0}', '\u{10FA}', GeneralCategory::LowercaseLetter),
+    ('\u{10FB}', '\u{10FB}', GeneralCategory::OtherPunctuation),
+    ('\u{10FC}', '\u{10FC}', GeneralCategory::ModifierLetter),
+    ('\u{10FD}', '\u{10FF}', GeneralCategory::LowercaseLetter),
+    ('\u{1100}', '\u{1248}', GeneralCategory::OtherLetter),
+    ('\u{124A}', '\u{124D}', GeneralCategory::OtherLetter),
+    ('\u{1250}', '\u{1256}', GeneralCategory::OtherLetter),
+    ('\u{1258}', '\u{1258}', GeneralCategory::OtherLetter),
+    ('\u{125A}', '\u{125D}', GeneralCategory::OtherLetter),
+    ('\u{1260}', '\u{1288}', GeneralCategory::OtherLetter),
+    ('\u{128A}', '\u{128D}', GeneralCategory::OtherLetter),
+    ('\u{1290}', '\u{12B0}', GeneralCategory::OtherLetter),
+    ('\u{12B2}', '\u{12B5}', GeneralCategory::OtherLetter),
+    ('\u{12B8}', '\u{12BE}', GeneralCategory::OtherLetter),
+    ('\u{12C0}', '\u{12C0}', GeneralCategory::OtherLetter),
+    ('\u{12C2}', '\u{12C5}', GeneralCategory::OtherLetter),
+    ('\u{12C8}', '\u{12D6}', GeneralCategory::OtherLetter),
+    ('\u{12D8}', '\u{1310}', GeneralCategory::OtherLetter),
+    ('\u{1312}', '\u{1315}', GeneralCategory::OtherLetter),
+    ('\u{1318}', '\u{135A}', GeneralCategory::OtherLetter),
+    ('\u{135D}', '\u{135F}', GeneralCategory::NonspacingMark),
+    ('\u{1360}', '\u{1368}', GeneralCategory::OtherPunctuation),
+    ('\u{1369}', '\u{137C}', GeneralCategory::OtherNumber),
+    ('\u{1380}', '\u{138F}', GeneralCategory::OtherLetter),
+    ('\u{1390}', '\u{1399}', GeneralCategory::OtherSymbol),
+    ('\u{13A0}', '\u{13F5}', GeneralCategory::UppercaseLetter),
+    ('\u{13F8}', '\u{13FD}', GeneralCategory::LowercaseLetter),
+    ('\u{1400}', '\u{1400}', GeneralCategory::DashPunctuation),
+    ('\u{1401}', '\u{166C}', GeneralCategory::OtherLetter),
+    ('\u{166D}', '\u{166D}', GeneralCategory::OtherSymbol),
+    ('\u{166E}', '\u{166E}', GeneralCategory::OtherPunctuation),
+    ('\u{166F}', '\u{167F}', GeneralCategory::OtherLetter),
+    ('\u{1680}', '\u{1680}', GeneralCategory::SpaceSeparator),
+    ('\u{1681}', '\u{169A}', GeneralCategory::OtherLetter),
+    ('\u{169B}', '\u{169B}', GeneralCategory::OpenPunctuation),
+    ('\u{169C}', '\u{169C}', GeneralCategory::ClosePunctuation),
+    ('\u{16A0}', '\u{16EA}', GeneralCategory::OtherLetter),
+    ('\u{16EB}', '\u{16ED}', GeneralCategory::OtherPunctuation),
+    ('\u{16EE}', '\u{16F0}', GeneralCategory::LetterNumber),
+    ('\u{16F1}', '\u{16F8}', GeneralCategory::OtherLetter),
+    ('\u{1700}', '\u{1711}', GeneralCategory::OtherLetter),
+    ('\u{1712}', '\u{1714}', GeneralCategory::NonspacingMark),
+    ('\u{1715}', '\u{1715}', GeneralCategory::SpacingMark),
+    ('\u{171F}', '\u{1731}', GeneralCategory::OtherLetter),
+    ('\u{1732}', '\u{1733}', GeneralCategory::NonspacingMark),
+    ('\u{1734}', '\u{1734}', GeneralCategory::SpacingMark),
+    ('\u{1735}', '\u{1736}', GeneralCategory::OtherPunctuation),
+    ('\u{1740}', '\u{1751}', GeneralCategory::OtherLetter),
+    ('\u{1752}', '\u{1753}', GeneralCategory::NonspacingMark),
+    ('\u{1760}', '\u{176C}', GeneralCategory::OtherLetter),
+    ('\u{176E}', '\u{1770}', GeneralCategory::OtherLetter),
+    ('\u{1772}', '\u{1773}', GeneralCategory::NonspacingMark),
+    ('\u{1780}', '\u{17B3}', GeneralCategory::OtherLetter),
+    ('\u{17B4}', '\u{17B5}', GeneralCategory::NonspacingMark),
+    ('\u{17B6}', '\u{17B6}', GeneralCategory::SpacingMark),
+    ('\u{17B7}', '\u{17BD}', GeneralCategory::NonspacingMark),
+    ('\u{17BE}', '\u{17C5}', GeneralCategory::SpacingMark),
+    ('\u{17C6}', '\u{17C6}', GeneralCategory::NonspacingMark),
+    ('\u{17C7}', '\u{17C8}', GeneralCategory::SpacingMark),
+    ('\u{17C9}', '\u{17D3}', GeneralCategory::NonspacingMark),
+    ('\u{17D4}', '\u{17D6}', GeneralCategory::OtherPunctuation),
+    ('\u{17D7}', '\u{17D7}', GeneralCategory::ModifierLetter),
+    ('\u{17D8}', '\u{17DA}', GeneralCategory::OtherPunctuation),
+    ('\u{17DB}', '\u{17DB}', GeneralCategory::CurrencySymbol),
+    ('\u{17DC}', '\u{17DC}', GeneralCategory::OtherLetter),
+    ('\u{17DD}', '\u{17DD}', GeneralCategory::NonspacingMark),
+    ('\u{17E0}', '\u{17E9}', GeneralCategory::DecimalNumber),
+    ('\u{17F0}', '\u{17F9}', GeneralCategory::OtherNumber),
+    ('\u{1800}', '\u{1805}', GeneralCategory::OtherPunctuation),
+    ('\u{1806}', '\u{1806}', GeneralCategory::DashPunctuation),
+    ('\u{1807}', '\u{180A}', GeneralCategory::OtherPunctuation),
+    ('\u{180B}', '\u{180D}', GeneralCategory::NonspacingMark),
+    ('\u{180E}', '\u{180E}', GeneralCategory::Format),
+    ('\u{180F}', '\u{180F}', GeneralCategory::NonspacingMark),
+    ('\u{1810}', '\u{1819}', GeneralCategory::DecimalNumber),
+    ('\u{1820}', '\u{1842}', GeneralCategory::OtherLetter),
+    ('\u{1843}', '\u{1843}', GeneralCategory::ModifierLetter),
+    ('\u{1844}', '\u{1878}', GeneralCategory::OtherLetter),
+    ('\u{1880}', '\u{1884}', GeneralCategory::OtherLetter),
+    ('\u{1885}', '\u{1886}', GeneralCategory::NonspacingMark),
+    ('\u{1887}', '\u{18A8}', GeneralCategory::OtherLetter),
+    ('\u{18A9}', '\u{18A9}', GeneralCategory::NonspacingMark),
+    ('\u{18AA}', '\u{18AA}', GeneralCategory::OtherLetter),
+    ('\u{18B0}', '\u{18F5}', GeneralCategory::OtherLetter),
+    ('\u{1900}', '\u{191E}', GeneralCategory::OtherLetter),
+    ('\u{1920}', '\u{1922}', GeneralCategory::NonspacingMark),
+    ('\u{1923}', '\u{1926}', GeneralCategory::SpacingMark),
+    ('\u{1927}', '\u{1928}', GeneralCategory::NonspacingMark),
+    ('\u{1929}', '\u{192B}', GeneralCategory::SpacingMark),
+    ('\u{1930}', '\u{1931}', GeneralCategory::SpacingMark),
+    ('\u{1932}', '\u{1932}', GeneralCategory::NonspacingMark),
+    ('\u{1933}', '\u{1938}', GeneralCategory::SpacingMark),
+    ('\u{1939}', '\u{193B}', GeneralCategory::NonspacingMark),
+    ('\u{1940}', '\u{1940}', GeneralCategory::OtherSymbol),
+    ('\u{1944}', '\u{1945}', GeneralCategory::OtherPunctuation),
+    ('\u{1946}', '\u{194F}', GeneralCategory::DecimalNumber),
+    ('\u{1950}', '\u{196D}', GeneralCategory::OtherLetter),
+    ('\u{1970}', '\u{1974}', GeneralCategory::OtherLetter),
+    ('\u{1980}', '\u{19AB}', GeneralCategory::OtherLetter),
+    ('\u{19B0}', '\u{19C9}', GeneralCategory::OtherLetter),
+    ('\u{19D0}', '\u{19D9}', GeneralCategory::DecimalNumber),
+    ('\u{19DA}', '\u{19DA}', GeneralCategory::OtherNumber),
+    ('\u{19DE}', '\u{19FF}', GeneralCategory::OtherSymbol),
+    ('\u{1A00}', '\u{1A16}', GeneralCategory::OtherLetter),
+    ('\u{1A17}', '\u{1A18}', GeneralCategory::NonspacingMark),
+    ('\u{1A19}', '\u{1A1A}', GeneralCategory::SpacingMark),
+    ('\u{1A1B}', '\u{1A1B}', GeneralCategory::NonspacingMark),
+    ('\u{1A1E}', '\u{1A1F}', GeneralCategory::OtherPunctuation),
+    ('\u{1A20}', '\u{1A54}', GeneralCategory::OtherLetter),
+    ('\u{1A55}', '\u{1A55}', GeneralCategory::SpacingMark),
+    ('\u{1A56}', '\u{1A56}', GeneralCategory::NonspacingMark),
+    ('\u{1A57}', '\u{1A57}', GeneralCategory::SpacingMark),
+    ('\u{1A58}', '\u{1A5E}', GeneralCategory::NonspacingMark),
+    ('\u{1A60}', '\u{1A60}', GeneralCategory::NonspacingMark),
+    ('\u{1A61}', '\u{1A61}', GeneralCategory::SpacingMark),
+    ('\u{1A62}', '\u{1A62}', GeneralCategory::NonspacingMark),
+    ('\u{1A63}', '\u{1A64}', GeneralCategory::SpacingMark),
+    ('\u{1A65}', '\u{1A6C}', GeneralCategory::NonspacingMark),
+    ('\u{1A6D}', '\u{1A72}', GeneralCategory::SpacingMark),
+    ('\u{1A73}', '\u{1A7C}', GeneralCategory::NonspacingMark),
+    ('\u{1A7F}', '\u{1A7F}', GeneralCategory::NonspacingMark),
+    ('\u{1A80}', '\u{1A89}', GeneralCategory::DecimalNumber),
+    ('\u{1A90}', '\u{1A99}', GeneralCategory::DecimalNumber),
+    ('\u{1AA0}', '\u{1AA6}', GeneralCategory::OtherPunctuation),
+    ('\u{1AA7}', '\u{1AA7}', GeneralCategory::ModifierLetter),
+    ('\u{1AA8}', '\u{1AAD}', GeneralCategory::OtherPunctuation),
+    ('\u{1AB0}', '\u{1ABD}', GeneralCategory::NonspacingMark),
+    ('\u{1ABE}', '\u{1ABE}', GeneralCategory::EnclosingMark),
+    ('\u{1ABF}', '\u{1ACE}', GeneralCategory::NonspacingMark),
+    ('\u{1B00}', '\u{1B03}', GeneralCategory::NonspacingMark),
+    ('\u{1B04}', '\u{1B04}', GeneralCategory::SpacingMark),
+    ('\u{1B05}', '\u{1B33}', GeneralCategory::OtherLetter),
+    ('\u{1B34}', '\u{1B34}', GeneralCategory::NonspacingMark),
+    ('\u{1B35}', '\u{1B35}', GeneralCategory::SpacingMark),
+    ('\u{1B36}', '\u{1B3A}', GeneralCategory::NonspacingMark),
+    ('\u{1B3B}', '\u{1B3B}', GeneralCategory::SpacingMark),
+    ('\u{1B3C}', '\u{1B3C}', GeneralCategory::NonspacingMark),
+    ('\u{1B3D}', '\u{1B41}', GeneralCategory::SpacingMark),
+    ('\u{1B42}', '\u{1B42}', GeneralCategory::NonspacingMark),
+    ('\u{1B43}', '\u{1B44}', GeneralCategory::SpacingMark),
+    ('\u{1B45}', '\u{1B4C}', GeneralCategory::OtherLetter),
+    ('\u{1B50}', '\u{1B59}', GeneralCategory::DecimalNumber),
+    ('\u{1B5A}', '\u{1B60}', GeneralCategory::OtherPunctuation),
+    ('\u{1B61}', '\u{1B6A}', GeneralCategory::OtherSymbol),
+    ('\u{1B6B}', '\u{1B73}', GeneralCategory::NonspacingMark),
+    ('\u{1B74}', '\u{1B7C}', GeneralCategory::OtherSymbol),
+    ('\u{1B7D}', '\u{1B7E}', GeneralCategory::OtherPunctuation),
+    ('\u{1B80}', '\u{1B81}', GeneralCategory::NonspacingMark),
+    ('\u{1B82}', '\u{1B82}', GeneralCategory::SpacingMark),
+    ('\u{1B83}', '\u{1BA0}', GeneralCategory::OtherLetter),
+    ('\u{1BA1}', '\u{1BA1}', GeneralCategory::SpacingMark),
+    ('\u{1BA2}', '\u{1BA5}', GeneralCategory::NonspacingMark),
+    ('\u{1BA6}', '\u{1BA7}', GeneralCategory::SpacingMark),
+    ('\u{1BA8}', '\u{1BA9}', GeneralCategory::NonspacingMark),
+    ('\u{1BAA}', '\u{1BAA}', GeneralCategory::SpacingMark),
+    ('\u{1BAB}', '\u{1BAD}', GeneralCategory::NonspacingMark),
+    ('\u{1BAE}', '\u{1BAF}', GeneralCategory::OtherLetter),
+    ('\u{1BB0}', '\u{1BB9}', GeneralCategory::DecimalNumber),
+    ('\u{1BBA}', '\u{1BE5}', GeneralCategory::OtherLetter),
+    ('\u{1BE6}', '\u{1BE6}', GeneralCategory::NonspacingMark),
+    ('\u{1BE7}', '\u{1BE7}', GeneralCategory::SpacingMark),
+    ('\u{1BE8}', '\u{1BE9}', GeneralCategory::NonspacingMark),
+    ('\u{1BEA}', '\u{1BEC}', GeneralCategory::SpacingMark),
+    ('\u{1BED}', '\u{1BED}', GeneralCategory::NonspacingMark),
+    ('\u{1BEE}', '\u{1BEE}', GeneralCategory::SpacingMark),
+    ('\u{1BEF}', '\u{1BF1}', GeneralCategory::NonspacingMark),
+    ('\u{1BF2}', '\u{1BF3}', GeneralCategory::SpacingMark),
+    ('\u{1BFC}', '\u{1BFF}', GeneralCategory::OtherPunctuation),
+    ('\u{1C00}', '\u{1C23}', GeneralCategory::OtherLetter),
+    ('\u{1C24}', '\u{1C2B}', GeneralCategory::SpacingMark),
+    ('\u{1C2C}', '\u{1C33}', GeneralCategory::NonspacingMark),
+    ('\u{1C34}', '\u{1C35}', GeneralCategory::SpacingMark),
+    ('\u{1C36}', '\u{1C37}', GeneralCategory::NonspacingMark),
+    ('\u{1C3B}', '\u{1C3F}', GeneralCategory::OtherPunctuation),
+    ('\u{1C40}', '\u{1C49}', GeneralCategory::DecimalNumber),
+    ('\u{1C4D}', '\u{1C4F}', GeneralCategory::OtherLetter),
+    ('\u{1C50}', '\u{1C59}', GeneralCategory::DecimalNumber),
+    ('\u{1C5A}', '\u{1C77}', GeneralCategory::OtherLetter),
+    ('\u{1C78}', '\u{1C7D}', GeneralCategory::ModifierLetter),
+    ('\u{1C7E}', '\u{1C7F}', GeneralCategory::OtherPunctuation),
+    ('\u{1C80}', '\u{1C88}', GeneralCategory::LowercaseLetter),
+    ('\u{1C90}', '\u{1CBA}', GeneralCategory::UppercaseLetter),
+    ('\u{1CBD}', '\u{1CBF}', GeneralCategory::UppercaseLetter),
+    ('\u{1CC0}', '\u{1CC7}', GeneralCategory::OtherPunctuation),
+    ('\u{1CD0}', '\u{1CD2}', GeneralCategory::NonspacingMark),
+    ('\u{1CD3}', '\u{1CD3}', GeneralCategory::OtherPunctuation),
+    ('\u{1CD4}', '\u{1CE0}', GeneralCategory::NonspacingMark),
+    ('\u{1CE1}', '\u{1CE1}', GeneralCategory::SpacingMark),
+    ('\u{1CE2}', '\u{1CE8}', GeneralCategory::NonspacingMark),
+    ('\u{1CE9}', '\u{1CEC}', GeneralCategory::OtherLetter),
+    ('\u{1CED}', '\u{1CED}', GeneralCategory::NonspacingMark),
+    ('\u{1CEE}', '\u{1CF3}', GeneralCategory::OtherLetter),
+    ('\u{1CF4}', '\u{1CF4}', GeneralCategory::NonspacingMark),
+    ('\u{1CF5}', '\u{1CF6}', GeneralCategory::OtherLetter),
+    ('\u{1CF7}', '\u{1CF7}', GeneralCategory::SpacingMark),
+    ('\u{1CF8}', '\u{1CF9}', GeneralCategory::NonspacingMark),
+    ('\u{1CFA}', '\u{1CFA}', GeneralCategory::OtherLetter),
+    ('\u{1D00}', '\u{1D2B}', GeneralCategory::LowercaseLetter),
+    ('\u{1D2C}', '\u{1D6A}', GeneralCategory::ModifierLetter),
+    ('\u{1D6B}', '\u{1D77}', GeneralCategory::LowercaseLetter),
+    ('\u{1D78}', '\u{1D78}', GeneralCategory::ModifierLetter),
+    ('\u{1D79}', '\u{1D9A}', GeneralCategory::LowercaseLetter),
+    ('\u{1D9B}', '\u{1DBF}', GeneralCategory::ModifierLetter),
+    ('\u{1DC0}', '\u{1DFF}', GeneralCategory::NonspacingMark),
+    ('\u{1E00}', '\u{1E00}', GeneralCategory::UppercaseLetter),
+    ('\u{1E01}', '\u{1E01}', GeneralCategory::LowercaseLetter),
+    ('\u{1E02}', '\u{1E02}', GeneralCategory::UppercaseLetter),
+    ('\u{1E03}', '\u{1E03}', GeneralCategory::LowercaseLetter),
+    ('\u{1E04}', '\u{1E04}', GeneralCategory::UppercaseLetter),
+    ('\u{1E05}', '\u{1E05}', GeneralCategory::LowercaseLetter),
+    ('\u{1E06}', '\u{1E06}', GeneralCategory::UppercaseLetter),
+    ('\u{1E07}', '\u{1E07}', GeneralCategory::LowercaseLetter),
+    ('\u{1E08}', '\u{1E08}', GeneralCategory::UppercaseLetter),
+    ('\u{1E09}', '\u{1E09}', GeneralCategory::LowercaseLetter),
+    ('\u{1E0A}', '\u{1E0A}', GeneralCategory::UppercaseLetter),
+    ('\u{1E0B}', '\u{1E0B}', GeneralCategory::LowercaseLetter),
+    ('\u{1E0C}', '\u{1E0C}', GeneralCategory::UppercaseLetter),
+    ('\u{1E0D}', '\u{1E0D}', GeneralCategory::LowercaseLetter),
+    ('\u{1E0E}', '\u{1E0E}', GeneralCategory::UppercaseLetter),
+    ('\u{1E0F}', '\u{1E0F}', GeneralCategory::LowercaseLetter),
+    ('\u{1E10}', '\u{1E10}', GeneralCategory::UppercaseLetter),
+    ('\u{1E11}', '\u{1E11}', GeneralCategory::LowercaseLetter),
+    ('\u{1E12}', '\u{1E12}', GeneralCategory::UppercaseLetter),
+    ('\u{1E13}', '\u{1E13}', GeneralCategory::LowercaseLetter),
+    ('\u{1E14}', '\u{1E14}', GeneralCategory::UppercaseLetter),
+    ('\u{1E15}', '\u{1E15}', GeneralCategory::LowercaseLetter),
+    ('\u{1E16}', '\u{1E16}', GeneralCategory::UppercaseLetter),
+    ('\u{1E17}', '\u{1E17}', GeneralCategory::LowercaseLetter),
+    ('\u{1E18}', '\u{1E18}', GeneralCategory::UppercaseLetter),
+    ('\u{1E19}', '\u{1E19}', GeneralCategory::LowercaseLetter),
+    ('\u{1E1A}', '\u{1E1A}', GeneralCategory::UppercaseLetter),
+    ('\u{1E1B}', '\u{1E1B}', GeneralCategory::LowercaseLetter),
+    ('\u{1E1C}', '\u{1E1C}', GeneralCategory::UppercaseLetter),
+    ('\u{1E1D}', '\u{1E1D}', GeneralCategory::LowercaseLetter),
+    ('\u{1E1E}', '\u{1E1E}', GeneralCategory::UppercaseLetter),
+    ('\u{1E1F}', '\u{1E1F}', GeneralCategory::LowercaseLetter),
+    ('\u{1E20}', '\u{1E20}', GeneralCategory::UppercaseLetter),
+    ('\u{1E21}', '\u{1E21}', GeneralCategory::LowercaseLetter),
+    ('\u{1E22}', '\u{1E22}', GeneralCategory::UppercaseLetter),
+    ('\u{1E23}', '\u{1E23}', GeneralCategory::LowercaseLetter),
+    ('\u{1E24}', '\u{1E24}', GeneralCategory::UppercaseLetter),
+    ('\u{1E25}', '\u{1E25}', GeneralCategory::LowercaseLetter),
+    ('\u{1E26}', '\u{1E26}', GeneralCategory::UppercaseLetter),
+    ('\u{1E27}', '\u{1E27}', GeneralCategory::LowercaseLetter),
+    ('\u{1E28}', '\u{1E28}', GeneralCategory::UppercaseLetter),
+    ('\u{1E29}', '\u{1E29}', GeneralCategory::LowercaseLetter),
+    ('\u{1E2A}', '\u{1E2A}', GeneralCategory::UppercaseLetter),
+    ('\u{1E2B}', '\u{1E2B}', GeneralCategory::LowercaseLetter),
+    ('\u{1E2C}', '\u{1E2C}', GeneralCategory::UppercaseLetter),
+    ('\u{1E2D}', '\u{1E2D}', GeneralCategory::LowercaseLetter),
+    ('\u{1E2E}', '\u{1E2E}', GeneralCategory::UppercaseLetter),
+    ('\u{1E2F}', '\u{1E2F}', GeneralCategory::LowercaseLetter),
+    ('\u{1E30}', '\u{1E30}', GeneralCategory::UppercaseLetter),
+    ('\u{1E31}', '\u{1E31}', GeneralCategory::LowercaseLetter),
+    ('\u{1E32}', '\u{1E32}', GeneralCategory::UppercaseLetter),
+    ('\u{1E33}', '\u{1E33}', GeneralCategory::LowercaseLetter),
+    ('\u{1E34}', '\u{1E34}', GeneralCategory::UppercaseLetter),
+    ('\u{1E35}', '\u{1E35}', GeneralCategory::LowercaseLetter),
+    ('\u{1E36}', '\u{1E36}', GeneralCategory::UppercaseLetter),
+    ('\u{1E37}', '\u{1E37}', GeneralCategory::LowercaseLetter),
+    ('\u{1E38}', '\u{1E38}', GeneralCategory::UppercaseLetter),
+    ('\u{1E39}', '\u{1E39}', GeneralCategory::LowercaseLetter),
+    ('\u{1E3A}', '\u{1E3A}', GeneralCategory::UppercaseLetter),
+    ('\u{1E3B}', '\u{1E3B}', GeneralCategory::LowercaseLetter),
+    ('\u{1E3C}', '\u{1E3C}', GeneralCategory::UppercaseLetter),
+    ('\u{1E3D}', '\u{1E3D}', GeneralCategory::LowercaseLetter),
+    ('\u{1E3E}', '\u{1E3E}', GeneralCategory::UppercaseLetter),
+    ('\u{1E3F}', '\u{1E3F}', GeneralCategory::LowercaseLetter),
+    ('\u{1E40}', '\u{1E40}', GeneralCategory::UppercaseLetter),
+    ('\u{1E41}', '\u{1E41}', GeneralCategory::LowercaseLetter),
+    ('\u{1E42}', '\u{1E42}', GeneralCategory::UppercaseLetter),
+    ('\u{1E43}', '\u{1E43}', GeneralCategory::LowercaseLetter),
+    ('\u{1E44}', '\u{1E44}', GeneralCategory::UppercaseLetter),
+    ('\u{1E45}', '\u{1E45}', GeneralCategory::LowercaseLetter),
+    ('\u{1E46}', '\u{1E46}', GeneralCategory::UppercaseLetter),
+    ('\u{1E47}', '\u{1E47}', GeneralCategory::LowercaseLetter),
+    ('\u{1E48}', '\u{1E48}', GeneralCategory::UppercaseLetter),
+    ('\u{1E49}', '\u{1E49}', GeneralCategory::LowercaseLetter),
+    ('\u{1E4A}', '\u{1E4A}', GeneralCategory::UppercaseLetter),
+    ('\u{1E4B}', '\u{1E4B}', GeneralCategory::LowercaseLetter),
+    ('\u{1E4C}', '\u{1E4C}', GeneralCategory::UppercaseLetter),
+    ('\u{1E4D}', '\u{1E4D}', GeneralCategory::LowercaseLetter),
+    ('\u{1E4E}', '\u{1E4E}', GeneralCategory::UppercaseLetter),
+    ('\u{1E4F}', '\u{1E4F}', GeneralCategory::LowercaseLetter),
+    ('\u{1E50}', '\u{1E50}', GeneralCategory::UppercaseLetter),
+    ('\u{1E51}', '\u{1E51}', GeneralCategory::LowercaseLetter),
+    ('\u{1E52}', '\u{1E52}', GeneralCategory::UppercaseLetter),
+    ('\u{1E53}', '\u{1E53}', GeneralCategory::LowercaseLetter),
+    ('\u{1E54}', '\u{1E54}', GeneralCategory::UppercaseLetter),
+    ('\u{1E55}', '\u{1E55}', GeneralCategory::LowercaseLetter),
+    ('\u{1E56}', '\u{1E56}', GeneralCategory::UppercaseLetter),
+    ('\u{1E57}', '\u{1E57}', GeneralCategory::LowercaseLetter),
+    ('\u{1E58}', '\u{1E58}', GeneralCategory::UppercaseLetter),
+    ('\u{1E59}', '\u{1E59}', GeneralCategory::LowercaseLetter),
+    ('\u{1E5A}', '\u{1E5A}', GeneralCategory::UppercaseLetter),
+    ('\u{1E5B}', '\u{1E5B}', GeneralCategory::LowercaseLetter),
+    ('\u{1E5C}', '\u{1E5C}', GeneralCategory::UppercaseLetter),
+    ('\u{1E5D}', '\u{1E5D}', GeneralCategory::LowercaseLetter),
+    ('\u{1E5E}', '\u{1E5E}', GeneralCategory::UppercaseLetter),
+    ('\u{1E5F}', '\u{1E5F}', GeneralCategory::LowercaseLetter),
+    ('\u{1E60}', '\u{1E60}', GeneralCategory::UppercaseLetter),
+    ('\u{1E61}', '\u{1E61}', GeneralCategory::LowercaseLetter),
+    ('\u{1E62}', '\u{1E62}', GeneralCategory::UppercaseLetter),
+    ('\u{1E63}', '\u{1E63}', GeneralCategory::LowercaseLetter),
+    ('\u{1E64}', '\u{1E64}', GeneralCategory::UppercaseLetter),
+    ('\u{1E65}', '\u{1E65}', GeneralCategory::LowercaseLetter),
+    ('\u{1E66}', '\u{1E66}', GeneralCategory::UppercaseLetter),
+    ('\u{1E67}', '\u{1E67}', GeneralCategory::LowercaseLetter),
+    ('\u{1E68}', '\u{1E68}', GeneralCategory::UppercaseLetter),
+    ('\u{1E69}', '\u{1E69}', GeneralCategory::LowercaseLetter),
+    ('\u{1E6A}', '\u{1E6A}', GeneralCategory::UppercaseLetter),
+    ('\u{1E6B}', '\u{1E6B}', GeneralCategory::LowercaseLetter),
+    ('\u{1E6C}', '\u{1E6C}', GeneralCategory::UppercaseLetter),
+    ('\u{1E6D}', '\u{1E6D}', GeneralCategory::LowercaseLetter),
+    ('\u{1E6E}', '\u{1E6E}', GeneralCategory::UppercaseLetter),
+    ('\u{1E6F}', '\u{1E6F}', GeneralCategory::LowercaseLetter),
+    ('\u{1E70}', '\u{1E70}', GeneralCategory::UppercaseLetter),
+    ('\u{1E71}', '\u{1E71}', GeneralCategory::LowercaseLetter),
+    ('\u{1E72}', '\u{1E72}', GeneralCategory::UppercaseLetter),
+    ('\u{1E73}', '\u{1E73}', GeneralCategory::LowercaseLetter),
+    ('\u{1E74}', '\u{1E74}', GeneralCategory::UppercaseLetter),
+    ('\u{1E75}', '\u{1E75}', GeneralCategory::LowercaseLetter),
+    ('\u{1E76}', '\u{1E76}', GeneralCategory::UppercaseLetter),
+    ('\u{1E77}', '\u{1E77}', GeneralCategory::LowercaseLetter),
+    ('\u{1E78}', '\u{1E78}', GeneralCategory::UppercaseLetter),
+    ('\u{1E79}', '\u{1E79}', GeneralCategory::LowercaseLetter),
+    ('\u{1E7A}', '\u{1E7A}', GeneralCategory::UppercaseLetter),
+    ('\u{1E7B}', '\u{1E7B}', GeneralCategory::LowercaseLetter),
+    ('\u{1E7C}', '\u{1E7C}', GeneralCategory::UppercaseLetter),
+    ('\u{1E7D}', '\u{1E7D}', GeneralCategory::LowercaseLetter),
+    ('\u{1E7E}', '\u{1E7E}', GeneralCategory::UppercaseLetter),
+    ('\u{1E7F}', '\u{1E7F}', GeneralCategory::LowercaseLetter),
+    ('\u{1E80}', '\u{1E80}', GeneralCategory::UppercaseLetter),
+    ('\u{1E81}', '\u{1E81}', GeneralCategory::LowercaseLetter),
+    ('\u{1E82}', '\u{1E82}', GeneralCategory::UppercaseLetter),
+    ('\u{1E83}', '\u{1E83}', GeneralCategory::LowercaseLetter),
+    ('\u{1E84}', '\u{1E84}', GeneralCategory::UppercaseLetter),
+    ('\u{1E85}', '\u{1E85}', GeneralCategory::LowercaseLetter),
+    ('\u{1E86}', '\u{1E86}', GeneralCategory::UppercaseLetter),
+    ('\u{1E87}', '\u{1E87}', GeneralCategory::LowercaseLetter),
+    ('\u{1E88}', '\u{1E88}', GeneralCategory::UppercaseLetter),
+    ('\u{1E89}', '\u{1E89}', GeneralCategory::LowercaseLetter),
+    ('\u{1E8A}', '\u{1E8A}', GeneralCategory::UppercaseLetter),
+    ('\u{1E8B}', '\u{1E8B}', GeneralCategory::LowercaseLetter),
+    ('\u{1E8C}', '\u{1E8C}', GeneralCategory::UppercaseLetter),
+    ('\u{1E8D}', '\u{1E8D}', GeneralCategory::LowercaseLetter),
+    ('\u{1E8E}', '\u{1E8E}', GeneralCategory::UppercaseLetter),
+    ('\u{1E8F}', '\u{1E8F}', GeneralCategory::LowercaseLetter),
+    ('\u{1E90}', '\u{1E90}', GeneralCategory::UppercaseLetter),
+    ('\u{1E91}', '\u{1E91}', GeneralCategory::LowercaseLetter),
+    ('\u{1E92}', '\u{1E92}', GeneralCategory::UppercaseLetter),
+    ('\u{1E93}', '\u{1E93}', GeneralCategory::LowercaseLetter),
+    ('\u{1E94}', '\u{1E94}', GeneralCategory::UppercaseLetter),
+    ('\u{1E95}', '\u{1E9D}', GeneralCategory::LowercaseLetter),
+    ('\u{1E9E}', '\u{1E9E}', GeneralCategory::UppercaseLetter),
+    ('\u{1E9F}', '\u{1E9F}', GeneralCategory::LowercaseLetter),
+    ('\u{1EA0}', '\u{1EA0}', GeneralCategory::UppercaseLetter),
+    ('\u{1EA1}', '\u{1EA1}', GeneralCategory::LowercaseLetter),
+    ('\u{1EA2}', '\u{1EA2}', GeneralCategory::UppercaseLetter),
+    ('\u{1EA3}', '\u{1EA3}', GeneralCategory::LowercaseLetter),
+    ('\u{1EA4}', '\u{1EA4}', GeneralCategory::UppercaseLetter),
+    ('\u{1EA5}', '\u{1EA5}', GeneralCategory::LowercaseLetter),
+    ('\u{1EA6}', '\u{1EA6}', GeneralCategory::UppercaseLetter),
+    ('\u{1EA7}', '\u{1EA7}', GeneralCategory::LowercaseLetter),
+    ('\u{1EA8}', '\u{1EA8}', GeneralCategory::UppercaseLetter),
+    ('\u{1EA9}', '\u{1EA9}', GeneralCategory::LowercaseLetter),
+    ('\u{1EAA}', '\u{1EAA}', GeneralCategory::UppercaseLetter),
+    ('\u{1EAB}', '\u{1EAB}', GeneralCategory::LowercaseLetter),
+    ('\u{1EAC}', '\u{1EAC}', GeneralCategory::UppercaseLetter),
+    ('\u{1EAD}', '\u{1EAD}', GeneralCategory::LowercaseLetter),
+    ('\u{1EAE}', '\u{1EAE}', GeneralCategory::UppercaseLetter),
+    ('\u{1EAF}', '\u{1EAF}', GeneralCategory::LowercaseLetter),
+    ('\u{1EB0}', '\u{1EB0}', GeneralCategory::UppercaseLetter),
+    ('\u{1EB1}', '\u{1EB1}', GeneralCategory::LowercaseLetter),
+    ('\u{1EB2}', '\u{1EB2}', GeneralCategory::UppercaseLetter),
+    ('\u{1EB3}', '\u{1EB3}', GeneralCategory::LowercaseLetter),
+    ('\u{1EB4}', '\u{1EB4}', GeneralCategory::UppercaseLetter),
+    ('\u{1EB5}', '\u{1EB5}', GeneralCategory::LowercaseLetter),
+    ('\u{1EB6}', '\u{1EB6}', GeneralCategory::UppercaseLetter),
+    ('\u{1EB7}', '\u{1EB7}', GeneralCategory::LowercaseLetter),
+    ('\u{1EB8}', '\u{1EB8}', GeneralCategory::UppercaseLetter),
+    ('\u{1EB9}', '\u{1EB9}', GeneralCategory::LowercaseLetter),
+    ('\u{1EBA}', '\u{1EBA}', GeneralCategory::UppercaseLetter),
+    ('\u{1EBB}', '\u{1EBB}', GeneralCategory::LowercaseLetter),
+    ('\u{1EBC}', '\u{1EBC}', GeneralCategory::UppercaseLetter),
+    ('\u{1EBD}', '\u{1EBD}', GeneralCategory::LowercaseLetter),
+    ('\u{1EBE}', '\u{1EBE}', GeneralCategory::UppercaseLetter),
+    ('\u{1EBF}', '\u{1EBF}', GeneralCategory::LowercaseLetter),
+    ('\u{1EC0}', '\u{1EC0}', GeneralCategory::UppercaseLetter),
+    ('\u{1EC1}', '\u{1EC1}', GeneralCategory::LowercaseLetter),
+    ('\u{1EC2}', '\u{1EC2}', GeneralCategory::UppercaseLetter),
+    ('\u{1EC3}', '\u{1EC3}', GeneralCategory::LowercaseLetter),
+    ('\u{1EC4}', '\u{1EC4}', GeneralCategory::UppercaseLetter),
+    ('\u{1EC5}', '\u{1EC5}', GeneralCategory::LowercaseLetter),
+    ('\u{1EC6}', '\u{1EC6}', GeneralCategory::UppercaseLetter),
+    ('\u{1EC7}', '\u{1EC7}', GeneralCategory::LowercaseLetter),
+    ('\u{1EC8}', '\u{1EC8}', GeneralCategory::UppercaseLetter),
+    ('\u{1EC9}', '\u{1EC9}', GeneralCategory::LowercaseLetter),
+    ('\u{1ECA}', '\u{1ECA}', GeneralCategory::UppercaseLetter),
+    ('\u{1ECB}', '\u{1ECB}', GeneralCategory::LowercaseLetter),
+    ('\u{1ECC}', '\u{1ECC}', GeneralCategory::UppercaseLetter),
+    ('\u{1ECD}', '\u{1ECD}', GeneralCategory::LowercaseLetter),
+    ('\u{1ECE}', '\u{1ECE}', GeneralCategory::UppercaseLetter),
+    ('\u{1ECF}', '\u{1ECF}', GeneralCategory::LowercaseLetter),
+    ('\u{1ED0}', '\u{1ED0}', GeneralCategory::UppercaseLetter),
+    ('\u{1ED1}', '\u{1ED1}', GeneralCategory::LowercaseLetter),
+    ('\u{1ED2}', '\u{1ED2}', GeneralCategory::UppercaseLetter),
+    ('\u{1ED3}', '\u{1ED3}', GeneralCategory::LowercaseLetter),
+    ('\u{1ED4}', '\u{1ED4}', GeneralCategory::UppercaseLetter),
+    ('\u{1ED5}', '\u{1ED5}', GeneralCategory::LowercaseLetter),
+    ('\u{1ED6}', '\u{1ED6}', GeneralCategory::UppercaseLetter),
+    ('\u{1ED7}', '\u{1ED7}', GeneralCategory::LowercaseLetter),
+    ('\u{1ED8}', '\u{1ED8}', GeneralCategory::UppercaseLetter),
+    ('\u{1ED9}', '\u{1ED9}', GeneralCategory::LowercaseLetter),
+    ('\u{1EDA}', '\u{1EDA}', GeneralCategory::UppercaseLetter),
+    ('\u{1EDB}', '\u{1EDB}', GeneralCategory::LowercaseLetter),
+    ('\u{1EDC}', '\u{1EDC}', GeneralCategory::UppercaseLetter),
+    ('\u{1EDD}', '\u{1EDD}', GeneralCategory::LowercaseLetter),
+    ('\u{1EDE}', '\u{1EDE}', GeneralCategory::UppercaseLetter),
+    ('\u{1EDF}', '\u{1EDF}', GeneralCategory::LowercaseLetter),
+    ('\u{1EE0}', '\u{1EE0}', GeneralCategory::UppercaseLetter),
+    ('\u{1EE1}', '\u{1EE1}', GeneralCategory::LowercaseLetter),
+    ('\u{1EE2}', '\u{1EE2}', GeneralCategory::UppercaseLetter),
+    ('\u{1EE3}', '\u{1EE3}', GeneralCategory::LowercaseLetter),
+    ('\u{1EE4}', '\u{1EE4}', GeneralCategory::UppercaseLetter),
+    ('\u{1EE5}', '\u{1EE5}', GeneralCategory::LowercaseLetter),
+    ('\u{1EE6}', '\u{1EE6}', GeneralCategory::UppercaseLetter),
+    ('\u{1EE7}', '\u{1EE7}', GeneralCategory::LowercaseLetter),
+    ('\u{1EE8}', '\u{1EE8}', GeneralCategory::UppercaseLetter),
+    ('\u{1EE9}', '\u{1EE9}', GeneralCategory::LowercaseLetter),
+    ('\u{1EEA}', '\u{1EEA}', GeneralCategory::UppercaseLetter),
+    ('\u{1EEB}', '\u{1EEB}', GeneralCategory::LowercaseLetter),
+    ('\u{1EEC}', '\u{1EEC}', GeneralCategory::UppercaseLetter),
+    ('\u{1EED}', '\u{1EED}', GeneralCategory::LowercaseLetter),
+    ('\u{1EEE}', '\u{1EEE}', GeneralCategory::UppercaseLetter),
+    ('\u{1EEF}', '\u{1EEF}', GeneralCategory::LowercaseLetter),
+    ('\u{1EF0}', '\u{1EF0}', GeneralCategory::UppercaseLetter),
+    ('\u{1EF1}', '\u{1EF1}', GeneralCategory::LowercaseLetter),
+    ('\u{1EF2}', '\u{1EF2}', GeneralCategory::UppercaseLetter),
+    ('\u{1EF3}', '\u{1EF3}', GeneralCategory::LowercaseLetter),
+    ('\u{1EF4}', '\u{1EF4}', GeneralCategory::UppercaseLetter),
+    ('\u{1EF5}', '\u{1EF5}', GeneralCategory::LowercaseLetter),
+    ('\u{1EF6}', '\u{1EF6}', GeneralCategory::UppercaseLetter),
+    ('\u{1EF7}', '\u{1EF7}', GeneralCategory::LowercaseLetter),
+    ('\u{1EF8}', '\u{1EF8}', GeneralCategory::UppercaseLetter),
+    ('\u{1EF9}', '\u{1EF9}', GeneralCategory::LowercaseLetter),
+    ('\u{1EFA}', '\u{1EFA}', GeneralCategory::UppercaseLetter),
+    ('\u{1EFB}', '\u{1EFB}', GeneralCategory::LowercaseLetter),
+    ('\u{1EFC}', '\u{1EFC}', GeneralCategory::UppercaseLetter),
+    ('\u{1EFD}', '\u{1EFD}', GeneralCategory::LowercaseLetter),
+    ('\u{1EFE}', '\u{1EFE}', GeneralCategory::UppercaseLetter),
+    ('\u{1EFF}', '\u{1F07}', GeneralCategory::LowercaseLetter),
+    ('\u{1F08}', '\u{1F0F}', GeneralCategory::UppercaseLetter),
+    ('\u{1F10}', '\u{1F15}', GeneralCategory::LowercaseLetter),
+    ('\u{1F18}', '\u{1F1D}', GeneralCategory::UppercaseLetter),
+    ('\u{1F20}', '\u{1F27}', GeneralCategory::LowercaseLetter),
+    ('\u{1F28}', '\u{1F2F}', GeneralCategory::UppercaseLetter),
+    ('\u{1F30}', '\u{1F37}', GeneralCategory::LowercaseLetter),
+    ('\u{1F38}', '\u{1F3F}', GeneralCategory::UppercaseLetter),
+    ('\u{1F40}', '\u{1F45}', GeneralCategory::LowercaseLetter),
+    ('\u{1F48}', '\u{1F4D}', GeneralCategory::UppercaseLetter),
+    ('\u{1F50}', '\u{1F57}', GeneralCategory::LowercaseLetter),
+    ('\u{1F59}', '\u{1F59}', GeneralCategory::UppercaseLetter),
+    ('\u{1F5B}', '\u{1F5B}', GeneralCategory::UppercaseLetter),
+    ('\u{1F5D}', '\u{1F5D}', GeneralCategory::UppercaseLetter),
+    ('\u{1F5F}', '\u{1F5F}', GeneralCategory::UppercaseLetter),
+    ('\u{1F60}', '\u{1F67}', GeneralCategory::LowercaseLetter),
+    ('\u{1F68}', '\u{1F6F}', GeneralCategory::UppercaseLetter),
+    ('\u{1F70}', '\u{1F7D}', GeneralCategory::LowercaseLetter),
+    ('\u{1F80}', '\u{1F87}', GeneralCategory::LowercaseLetter),
+    ('\u{1F88}', '\u{1F8F}', GeneralCategory::TitlecaseLetter),
+    ('\u{1F90}', '\u{1F97}', GeneralCategory::LowercaseLetter),
+    ('\u{1F98}', '\u{1F9F}', GeneralCategory::TitlecaseLetter),
+    ('\u{1FA0}', '\u{1FA7}', GeneralCategory::LowercaseLetter),
+    ('\u{1FA8}', '\u{1FAF}', GeneralCategory::TitlecaseLetter),
+    ('\u{1FB0}', '\u{1FB4}', GeneralCategory::LowercaseLetter),
+    ('\u{1FB6}', '\u{1FB7}', GeneralCategory::LowercaseLetter),
+    ('\u{1FB8}', '\u{1FBB}', GeneralCategory::UppercaseLetter),
+    ('\u{1FBC}', '\u{1FBC}', GeneralCategory::TitlecaseLetter),
+    ('\u{1FBD}', '\u{1FBD}', GeneralCategory::ModifierSymbol),
+    ('\u{1FBE}', '\u{1FBE}', GeneralCategory::LowercaseLetter),
+    ('\u{1FBF}', '\u{1FC1}', GeneralCategory::ModifierSymbol),
+    ('\u{1FC2}', '\u{1FC4}', GeneralCategory::LowercaseLetter),
+    ('\u{1FC6}', '\u{1FC7}', GeneralCategory::LowercaseLetter),
+    ('\u{1FC8}', '\u{1FCB}', GeneralCategory::UppercaseLetter),
+    ('\u{1FCC}', '\u{1FCC}', GeneralCategory::TitlecaseLetter),
+    ('\u{1FCD}', '\u{1FCF}', GeneralCategory::ModifierSymbol),
+    ('\u{1FD0}', '\u{1FD3}', GeneralCategory::LowercaseLetter),
+    ('\u{1FD6}', '\u{1FD7}', GeneralCategory::LowercaseLetter),
+    ('\u{1FD8}', '\u{1FDB}', GeneralCategory::UppercaseLetter),
+    ('\u{1FDD}', '\u{1FDF}', GeneralCategory::ModifierSymbol),
+    ('\u{1FE0}', '\u{1FE7}', GeneralCategory::LowercaseLetter),
+    ('\u{1FE8}', '\u{1FEC}', GeneralCategory::UppercaseLetter),
+    ('\u{1FED}', '\u{1FEF}', GeneralCategory::ModifierSymbol),
+    ('\u{1FF2}', '\u{1FF4}', GeneralCategory::LowercaseLetter),
+    ('\u{1FF6}', '\u{1FF7}', GeneralCategory::LowercaseLetter),
+    ('\u{1FF8}', '\u{1FFB}', GeneralCategory::UppercaseLetter),
+    ('\u{1FFC}', '\u{1FFC}', GeneralCategory::TitlecaseLetter),
+    ('\u{1FFD}', '\u{1FFE}', GeneralCategory::ModifierSymbol),
+    ('\u{2000}', '\u{200A}', GeneralCategory::SpaceSeparator),
+    ('\u{200B}', '\u{200F}', GeneralCategory::Format),
+    ('\u{2010}', '\u{2015}', GeneralCategory::DashPunctuation),
+    ('\u{2016}', '\u{2017}', GeneralCategory::OtherPunctuation),
+    ('\u{2018}', '\u{2018}', GeneralCategory::InitialPunctuation),
+    ('\u{2019}', '\u{2019}', GeneralCategory::FinalPunctuation),
+    ('\u{201A}', '\u{201A}', GeneralCategory::OpenPunctuation),
+    ('\u{201B}', '\u{201C}', GeneralCategory::InitialPunctuation),
+    ('\u{201D}', '\u{201D}', GeneralCategory::FinalPunctuation),
+    ('\u{201E}', '\u{201E}', GeneralCategory::OpenPunctuation),
+    ('\u{201F}', '\u{201F}', GeneralCategory::InitialPunctuation),
+    ('\u{2020}', '\u{2027}', GeneralCategory::OtherPunctuation),
+    ('\u{2028}', '\u{2028}', GeneralCategory::LineSeparator),
+    ('\u{2029}', '\u{2029}', GeneralCategory::ParagraphSeparator),
+    ('\u{202A}', '\u{202E}', GeneralCategory::Format),
+    ('\u{202F}', '\u{202F}', GeneralCategory::SpaceSeparator),
+    ('\u{2030}', '\u{2038}', GeneralCategory::OtherPunctuation),
+    ('\u{2039}', '\u{2039}', GeneralCategory::InitialPunctuation),
+    ('\u{203A}', '\u{203A}', GeneralCategory::FinalPunctuation),
+    ('\u{203B}', '\u{203E}', GeneralCategory::OtherPunctuation),
+    ('\u{203F}', '\u{2040}', GeneralCategory::ConnectorPunctuation),
+    ('\u{2041}', '\u{2043}', GeneralCategory::OtherPunctuation),
+    ('\u{2044}', '\u{2044}', GeneralCategory::MathSymbol),
+    ('\u{2045}', '\u{2045}', GeneralCategory::OpenPunctuation),
+    ('\u{2046}', '\u{2046}', GeneralCategory::ClosePunctuation),
+    ('\u{2047}', '\u{2051}', GeneralCategory::OtherPunctuation),
+    ('\u{2052}', '\u{2052}', GeneralCategory::MathSymbol),
+    ('\u{2053}', '\u{2053}', GeneralCategory::OtherPunctuation),
+    ('\u{2054}', '\u{2054}', GeneralCategory::ConnectorPunctuation),
+    ('\u{2055}', '\u{205E}', GeneralCategory::OtherPunctuation),
+    ('\u{205F}', '\u{205F}', GeneralCategory::SpaceSeparator),
+    ('\u{2060}', '\u{2064}', GeneralCategory::Format),
+    ('\u{2066}', '\u{206F}', GeneralCategory::Format),
+    ('\u{2070}', '\u{2070}', GeneralCategory::OtherNumber),
+    ('\u{2071}', '\u{2071}', GeneralCategory::ModifierLetter),
+    ('\u{2074}', '\u{2079}', GeneralCategory::OtherNumber),
+    ('\u{207A}', '\u{207C}', GeneralCategory::MathSymbol),
+    ('\u{207D}', '\u{207D}', GeneralCategory::OpenPunctuation),
+    ('\u{207E}', '\u{207E}', GeneralCategory::ClosePunctuation),
+    ('\u{207F}', '\u{207F}', GeneralCategory::ModifierLetter),
+    ('\u{2080}', '\u{2089}', GeneralCategory::OtherNumber),
+    ('\u{208A}', '\u{208C}', GeneralCategory::MathSymbol),
+    ('\u{208D}', '\u{208D}', GeneralCategory::OpenPunctuation),
+    ('\u{208E}', '\u{208E}', GeneralCategory::ClosePunctuation),
+    ('\u{2090}', '\u{209C}', GeneralCategory::ModifierLetter),
+    ('\u{20A0}', '\u{20C0}', GeneralCategory::CurrencySymbol),
+    ('\u{20D0}', '\u{20DC}', GeneralCategory::NonspacingMark),
+    ('\u{20DD}', '\u{20E0}', GeneralCategory::EnclosingMark),
+    ('\u{20E1}', '\u{20E1}', GeneralCategory::NonspacingMark),
+    ('\u{20E2}', '\u{20E4}', GeneralCategory::EnclosingMark),
+    ('\u{20E5}', '\u{20F0}', GeneralCategory::NonspacingMark),
+    ('\u{2100}', '\u{2101}', GeneralCategory::OtherSymbol),
+    ('\u{2102}', '\u{2102}', GeneralCategory::UppercaseLetter),
+    ('\u{2103}', '\u{2106}', GeneralCategory::OtherSymbol),
+    ('\u{2107}', '\u{2107}', GeneralCategory::UppercaseLetter),
+    ('\u{2108}', '\u{2109}', GeneralCategory::OtherSymbol),
+    ('\u{210A}', '\u{210A}', GeneralCategory::LowercaseLetter),
+    ('\u{210B}', '\u{210D}', GeneralCategory::UppercaseLetter),
+    ('\u{210E}', '\u{210F}', GeneralCategory::LowercaseLetter),
+    ('\u{2110}', '\u{2112}', GeneralCategory::UppercaseLetter),
+    ('\u{2113}', '\u{2113}', GeneralCategory::LowercaseLetter),
+    ('\u{2114}', '\u{2114}', GeneralCategory::OtherSymbol),
+    ('\u{2115}', '\u{2115}', GeneralCategory::UppercaseLetter),
+    ('\u{2116}', '\u{2117}', GeneralCategory::OtherSymbol),
+    ('\u{2118}', '\u{2118}', GeneralCategory::MathSymbol),
+    ('\u{2119}', '\u{211D}', GeneralCategory::UppercaseLetter),
+    ('\u{211E}', '\u{2123}', GeneralCategory::OtherSymbol),
+    ('\u{2124}', '\u{2124}', GeneralCategory::UppercaseLetter),
+    ('\u{2125}', '\u{2125}', GeneralCategory::OtherSymbol),
+    ('\u{2126}', '\u{2126}', GeneralCategory::UppercaseLetter),
+    ('\u{2127}', '\u{2127}', GeneralCategory::OtherSymbol),
+    ('\u{2128}', '\u{2128}', GeneralCategory::UppercaseLetter),
+    ('\u{2129}', '\u{2129}', GeneralCategory::OtherSymbol),
+    ('\u{212A}', '\u{212D}', GeneralCategory::UppercaseLetter),
+    ('\u{212E}', '\u{212E}', GeneralCategory::OtherSymbol),
+    ('\u{212F}', '\u{212F}', GeneralCategory::LowercaseLetter),
+    ('\u{2130}', '\u{2133}', GeneralCategory::UppercaseLetter),
+    ('\u{2134}', '\u{2134}', GeneralCategory::LowercaseLetter),
+    ('\u{2135}', '\u{2138}', GeneralCategory::OtherLetter),
+    ('\u{2139}', '\u{2139}', GeneralCategory::LowercaseLetter),
+    ('\u{213A}', '\u{213B}', GeneralCategory::OtherSymbol),
+    ('\u{213C}', '\u{213D}', GeneralCategory::LowercaseLetter),
+    ('\u{213E}', '\u{213F}', GeneralCategory::UppercaseLetter),
+    ('\u{2140}', '\u{2144}', GeneralCategory::MathSymbol),
+    ('\u{2145}', '\u{2145}', GeneralCategory::UppercaseLetter),
+    ('\u{2146}', '\u{2149}', GeneralCategory::LowercaseLetter),
+    ('\u{214A}', '\u{214A}', GeneralCategory::OtherSymbol),
+    ('\u{214B}', '\u{214B}', GeneralCategory::MathSymbol),
+    ('\u{214C}', '\u{214D}', GeneralCategory::OtherSymbol),
+    ('\u{214E}', '\u{214E}', GeneralCategory::LowercaseLetter),
+    ('\u{214F}', '\u{214F}', GeneralCategory::OtherSymbol),
+    ('\u{2150}', '\u{215F}', GeneralCategory::OtherNumber),
+    ('\u{2160}', '\u{2182}', GeneralCategory::LetterNumber),
+    ('\u{2183}', '\u{2183}', GeneralCategory::UppercaseLetter),
+    ('\u{2184}', '\u{2184}', GeneralCategory::LowercaseLetter),
+    ('\u{2185}', '\u{2188}', GeneralCategory::LetterNumber),
+    ('\u{2189}', '\u{2189}', GeneralCategory::OtherNumber),
+    ('\u{218A}', '\u{218B}', GeneralCategory::OtherSymbol),
+    ('\u{2190}', '\u{2194}', GeneralCategory::MathSymbol),
+    ('\u{2195}', '\u{2199}', GeneralCategory::OtherSymbol),
+    ('\u{219A}', '\u{219B}', GeneralCategory::MathSymbol),
+    ('\u{219C}', '\u{219F}', GeneralCategory::OtherSymbol),
+    ('\u{21A0}', '\u{21A0}', GeneralCategory::MathSymbol),
+    ('\u{21A1}', '\u{21A2}', GeneralCategory::OtherSymbol),
+    ('\u{21A3}', '\u{21A3}', GeneralCategory::MathSymbol),
+    ('\u{21A4}', '\u{21A5}', GeneralCategory::OtherSymbol),
+    ('\u{21A6}', '\u{21A6}', GeneralCategory::MathSymbol),
+    ('\u{21A7}', '\u{21AD}', GeneralCategory::OtherSymbol),
+    ('\u{21AE}', '\u{21AE}', GeneralCategory::MathSymbol),
+    ('\u{21AF}', '\u{21CD}', GeneralCategory::OtherSymbol),
+    ('\u{21CE}', '\u{21CF}', GeneralCategory::MathSymbol),
+    ('\u{21D0}', '\u{21D1}', GeneralCategory::OtherSymbol),
+    ('\u{21D2}', '\u{21D2}', GeneralCategory::MathSymbol),
+    ('\u{21D3}', '\u{21D3}', GeneralCategory::OtherSymbol),
+    ('\u{21D4}', '\u{21D4}', GeneralCategory::MathSymbol),
+    ('\u{21D5}', '\u{21F3}', GeneralCategory::OtherSymbol),
+    ('\u{21F4}', '\u{22FF}', GeneralCategory::MathSymbol),
+    ('\u{2300}', '\u{2307}', GeneralCategory::OtherSymbol),
+    ('\u{2308}', '\u{2308}', GeneralCategory::OpenPunctuation),
+    ('\u{2309}', '\u{2309}', GeneralCategory::ClosePunctuation),
+    ('\u{230A}', '\u{230A}', GeneralCategory::OpenPunctuation),
+    ('\u{230B}', '\u{230B}', GeneralCategory::ClosePunctuation),
+    ('\u{230C}', '\u{231F}', GeneralCategory::OtherSymbol),
+    ('\u{2320}', '\u{2321}', GeneralCategory::MathSymbol),
+    ('\u{2322}', '\u{2328}', GeneralCategory::OtherSymbol),
+    ('\u{2329}', '\u{2329}', GeneralCategory::OpenPunctuation),
+    ('\u{232A}', '\u{232A}', GeneralCategory::ClosePunctuation),
+    ('\u{232B}', '\u{237B}', GeneralCategory::OtherSymbol),
+    ('\u{237C}', '\u{237C}', GeneralCategory::MathSymbol),
+    ('\u{237D}', '\u{239A}', GeneralCategory::OtherSymbol),
+    ('\u{239B}', '\u{23B3}', GeneralCategory::MathSymbol),
+    ('\u{23B4}', '\u{23DB}', GeneralCategory::OtherSymbol),
+    ('\u{23DC}', '\u{23E1}', GeneralCategory::MathSymbol),
+    ('\u{23E2}', '\u{2426}', GeneralCategory::OtherSymbol),
+    ('\u{2440}', '\u{244A}', GeneralCategory::OtherSymbol),
+    ('\u{2460}', '\u{249B}', GeneralCategory::OtherNumber),
+    ('\u{249C}', '\u{24E9}', GeneralCategory::OtherSymbol),
+    ('\u{24EA}', '\u{24FF}', GeneralCategory::OtherNumber),
+    ('\u{2500}', '\u{25B6}', GeneralCategory::OtherSymbol),
+    ('\u{25B7}', '\u{25B7}', GeneralCategory::MathSymbol),
+    ('\u{25B8}', '\u{25C0}', GeneralCategory::OtherSymbol),
+    ('\u{25C1}', '\u{25C1}', GeneralCategory::MathSymbol),
+    ('\u{25C2}', '\u{25F7}', GeneralCategory::OtherSymbol),
+    ('\u{25F8}', '\u{25FF}', GeneralCategory::MathSymbol),
+    ('\u{2600}', '\u{266E}', GeneralCategory::OtherSymbol),
+    ('\u{266F}', '\u{266F}', GeneralCategory::MathSymbol),
+    ('\u{2670}', '\u{2767}', GeneralCategory::OtherSymbol),
+    ('\u{2768}', '\u{2768}', GeneralCategory::OpenPunctuation),
+    ('\u{2769}', '\u{2769}', GeneralCategory::ClosePunctuation),
+    ('\u{276A}', '\u{276A}', GeneralCategory::OpenPunctuation),
+    ('\u{276B}', '\u{276B}', GeneralCategory::ClosePunctuation),
+    ('\u{276C}', '\u{276C}', GeneralCategory::OpenPunctuation),
+    ('\u{276D}', '\u{276D}', GeneralCategory::ClosePunctuation),
+    ('\u{276E}', '\u{276E}', GeneralCategory::OpenPunctuation),
+    ('\u{276F}', '\u{276F}', GeneralCategory::ClosePunctuation),
+    ('\u{2770}', '\u{2770}', GeneralCategory::OpenPunctuation),
+    ('\u{2771}', '\u{2771}', GeneralCategory::ClosePunctuation),
+    ('\u{2772}', '\u{2772}', GeneralCategory::OpenPunctuation),
+    ('\u{2773}', '\u{2773}', GeneralCategory::ClosePunctuation),
+    ('\u{2774}', '\u{2774}', GeneralCategory::OpenPunctuation),
+    ('\u{2775}', '\u{2775}', GeneralCategory::ClosePunctuation),
+    ('\u{2776}', '\u{2793}', GeneralCategory::OtherNumber),
+    ('\u{2794}', '\u{27BF}', GeneralCategory::OtherSymbol),
+    ('\u{27C0}', '\u{27C4}', GeneralCategory::MathSymbol),
+    ('\u{27C5}', '\u{27C5}', GeneralCategory::OpenPunctuation),
+    ('\u{27C6}', '\u{27C6}', GeneralCategory::ClosePunctuation),
+    ('\u{27C7}', '\u{27E5}', GeneralCategory::MathSymbol),
+    ('\u{27E6}', '\u{27E6}', GeneralCategory::OpenPunctuation),
+    ('\u{27E7}', '\u{27E7}', GeneralCategory::ClosePunctuation),
+    ('\u{27E8}', '\u{27E8}', GeneralCategory::OpenPunctuation),
+    ('\u{27E9}', '\u{27E9}', GeneralCategory::ClosePunctuation),
+    ('\u{27EA}', '\u{27EA}', GeneralCategory::OpenPunctuation),
+    ('\u{27EB}', '\u{27EB}', GeneralCategory::ClosePunctuation),
+    ('\u{27EC}', '\u{27EC}', GeneralCategory::OpenPunctuation),
+    ('\u{27ED}', '\u{27ED}', GeneralCategory::ClosePunctuation),
+    ('\u{27EE}', '\u{27EE}', GeneralCategory::OpenPunctuation),
+    ('\u{27EF}', '\u{27EF}', GeneralCategory::ClosePunctuation),
+    ('\u{27F0}', '\u{27FF}', GeneralCategory::MathSymbol),
+    ('\u{2800}', '\u{28FF}', GeneralCategory::OtherSymbol),
+    ('\u{2900}', '\u{2982}', GeneralCategory::MathSymbol),
+    ('\u{2983}', '\u{2983}', GeneralCategory::OpenPunctuation),
+    ('\u{2984}', '\u{2984}', GeneralCategory::ClosePunctuation),
+    ('\u{2985}', '\u{2985}', GeneralCategory::OpenPunctuation),
+    ('\u{2986}', '\u{2986}', GeneralCategory::ClosePunctuation),
+    ('\u{2987}', '\u{2987}', GeneralCategory::OpenPunctuation),
+    ('\u{2988}', '\u{2988}', GeneralCategory::ClosePunctuation),
+    ('\u{2989}', '\u{2989}', GeneralCategory::OpenPunctuation),
+    ('\u{298A}', '\u{298A}', GeneralCategory::ClosePunctuation),
+    ('\u{298B}', '\u{298B}', GeneralCategory::OpenPunctuation),
+    ('\u{298C}', '\u{298C}', GeneralCategory::ClosePunctuation),
+    ('\u{298D}', '\u{298D}', GeneralCategory::OpenPunctuation),
+    ('\u{298E}', '\u{298E}', GeneralCategory::ClosePunctuation),
+    ('\u{298F}', '\u{298F}', GeneralCategory::OpenPunctuation),
+    ('\u{2990}', '\u{2990}', GeneralCategory::ClosePunctuation),
+    ('\u{2991}', '\u{2991}', GeneralCategory::OpenPunctuation),
+    ('\u{2992}', '\u{2992}', GeneralCategory::ClosePunctuation),
+    ('\u{2993}', '\u{2993}', GeneralCategory::OpenPunctuation),
+    ('\u{2994}', '\u{2994}', GeneralCategory::ClosePunctuation),
+    ('\u{2995}', '\u{2995}', GeneralCategory::OpenPunctuation),
+    ('\u{2996}', '\u{2996}', GeneralCategory::ClosePunctuation),
+    ('\u{2997}', '\u{2997}', GeneralCategory::OpenPunctuation),
+    ('\u{2998}', '\u{2998}', GeneralCategory::ClosePunctuation),
+    ('\u{2999}', '\u{29D7}', GeneralCategory::MathSymbol),
+    ('\u{29D8}', '\u{29D8}', GeneralCategory::OpenPunctuation),
+    ('\u{29D9}', '\u{29D9}', GeneralCategory::ClosePunctuation),
+    ('\u{29DA}', '\u{29DA}', GeneralCategory::OpenPunctuation),
+    ('\u{29DB}', '\u{29DB}', GeneralCategory::ClosePunctuation),
+    ('\u{29DC}', '\u{29FB}', GeneralCategory::MathSymbol),
+    ('\u{29FC}', '\u{29FC}', GeneralCategory::OpenPunctuation),
+    ('\u{29FD}', '\u{29FD}', GeneralCategory::ClosePunctuation),
+    ('\u{29FE}', '\u{2AFF}', GeneralCategory::MathSymbol),
+    ('\u{2B00}', '\u{2B2F}', GeneralCategory::OtherSymbol),
+    ('\u{2B30}', '\u{2B44}', GeneralCategory::MathSymbol),
+    ('\u{2B45}', '\u{2B46}', GeneralCategory::OtherSymbol),
+    ('\u{2B47}', '\u{2B4C}', GeneralCategory::MathSymbol),
+    ('\u{2B4D}', '\u{2B73}', GeneralCategory::OtherSymbol),
+    ('\u{2B76}', '\u{2B95}', GeneralCategory::OtherSymbol),
+    ('\u{2B97}', '\u{2BFF}', GeneralCategory::OtherSymbol),
+    ('\u{2C00}', '\u{2C2F}', GeneralCategory::UppercaseLetter),
+    ('\u{2C30}', '\u{2C5F}', GeneralCategory::LowercaseLetter),
+    ('\u{2C60}', '\u{2C60}', GeneralCategory::UppercaseLetter),
+    ('\u{2C61}', '\u{2C61}', GeneralCategory::LowercaseLetter),
+    ('\u{2C62}', '\u{2C64}', GeneralCategory::UppercaseLetter),
+    ('\u{2C65}', '\u{2C66}', GeneralCategory::LowercaseLetter),
+    ('\u{2C67}', '\u{2C67}', GeneralCategory::UppercaseLetter),
+    ('\u{2C68}', '\u{2C68}', GeneralCategory::LowercaseLetter),
+    ('\u{2C69}', '\u{2C69}', GeneralCategory::UppercaseLetter),
+    ('\u{2C6A}', '\u{2C6A}', GeneralCategory::LowercaseLetter),
+    ('\u{2C6B}', '\u{2C6B}', GeneralCategory::UppercaseLetter),
+    ('\u{2C6C}', '\u{2C6C}', GeneralCategory::LowercaseLetter),
+    ('\u{2C6D}', '\u{2C70}', GeneralCategory::UppercaseLetter),
+    ('\u{2C71}', '\u{2C71}', GeneralCategory::LowercaseLetter),
+    ('\u{2C72}', '\u{2C72}', GeneralCategory::UppercaseLetter),
+    ('\u{2C73}', '\u{2C74}', GeneralCategory::LowercaseLetter),
+    ('\u{2C75}', '\u{2C75}', GeneralCategory::UppercaseLetter),
+    ('\u{2C76}', '\u{2C7B}', GeneralCategory::LowercaseLetter),
+    ('\u{2C7C}', '\u{2C7D}', GeneralCategory::ModifierLetter),
+    ('\u{2C7E}', '\u{2C80}', GeneralCategory::UppercaseLetter),
+    ('\u{2C81}', '\u{2C81}', GeneralCategory::LowercaseLetter),
+    ('\u{2C82}', '\u{2C82}', GeneralCategory::UppercaseLetter),
+    ('\u{2C83}', '\u{2C83}', GeneralCategory::LowercaseLetter),
+    ('\u{2C84}', '\u{2C84}', GeneralCategory::UppercaseLetter),
+    ('\u{2C85}', '\u{2C85}', GeneralCategory::LowercaseLetter),
+    ('\u{2C86}', '\u{2C86}', GeneralCategory::UppercaseLetter),
+    ('\u{2C87}', '\u{2C87}', GeneralCategory::LowercaseLetter),
+    ('\u{2C88}', '\u{2C88}', GeneralCategory::UppercaseLetter),
+    ('\u{2C89}', '\u{2C89}', GeneralCategory::LowercaseLetter),
+    ('\u{2C8A}', '\u{2C8A}', GeneralCategory::UppercaseLetter),
+    ('\u{2C8B}', '\u{2C8B}', GeneralCategory::LowercaseLetter),
+    ('\u{2C8C}', '\u{2C8C}', GeneralCategory::UppercaseLetter),
+    ('\u{2C8D}', '\u{2C8D}', GeneralCategory::LowercaseLetter),
+    ('\u{2C8E}', '\u{2C8E}', GeneralCategory::UppercaseLetter),
+    ('\u{2C8F}', '\u{2C8F}', GeneralCategory::LowercaseLetter),
+    ('\u{2C90}', '\u{2C90}', GeneralCategory::UppercaseLetter),
+    ('\u{2C91}', '\u{2C91}', GeneralCategory::LowercaseLetter),
+    ('\u{2C92}', '\u{2C92}', GeneralCategory::UppercaseLetter),
+    ('\u{2C93}', '\u{2C93}', GeneralCategory::LowercaseLetter),
+    ('\u{2C94}', '\u{2C94}', GeneralCategory::UppercaseLetter),
+    ('\u{2C95}', '\u{2C95}', GeneralCategory::LowercaseLetter),
+    ('\u{2C96}', '\u{2C96}', GeneralCategory::UppercaseLetter),
+    ('\u{2C97}', '\u{2C97}', GeneralCategory::LowercaseLetter),
+    ('\u{2C98}', '\u{2C98}', GeneralCategory::UppercaseLetter),
+    ('\u{2C99}', '\u{2C99}', GeneralCategory::LowercaseLetter),
+    ('\u{2C9A}', '\u{2C9A}', GeneralCategory::UppercaseLetter),
+    ('\u{2C9B}', '\u{2C9B}', GeneralCategory::LowercaseLetter),
+    ('\u{2C9C}', '\u{2C9C}', GeneralCategory::UppercaseLetter),
+    ('\u{2C9D}', '\u{2C9D}', GeneralCategory::LowercaseLetter),
+    ('\u{2C9E}', '\u{2C9E}', GeneralCategory::UppercaseLetter),
+    ('\u{2C9F}', '\u{2C9F}', GeneralCategory::LowercaseLetter),
+    ('\u{2CA0}', '\u{2CA0}', GeneralCategory::UppercaseLetter),
+    ('\u{2CA1}', '\u{2CA1}', GeneralCategory::LowercaseLetter),
+    ('\u{2CA2}', '\u{2CA2}', GeneralCategory::UppercaseLetter),
+    ('\u{2CA3}', '\u{2CA3}', GeneralCategory::LowercaseLetter),
+    ('\u{2CA4}', '\u{2CA4}', GeneralCategory::UppercaseLetter),
+    ('\u{2CA5}', '\u{2CA5}', GeneralCategory::LowercaseLetter),
+    ('\u{2CA6}', '\u{2CA6}', GeneralCategory::UppercaseLetter),
+    ('\u{2CA7}', '\u{2CA7}', GeneralCategory::LowercaseLetter),
+    ('\u{2CA8}', '\u{2CA8}', GeneralCategory::UppercaseLetter),
+    ('\u{2CA9}', '\u{2CA9}', GeneralCategory::LowercaseLetter),
+    ('\u{2CAA}', '\u{2CAA}', GeneralCategory::UppercaseLetter),
+    ('\u{2CAB}', '\u{2CAB}', GeneralCategory::LowercaseLetter),
+    ('\u{2CAC}', '\u{2CAC}', GeneralCategory::UppercaseLetter),
+    ('\u{2CAD}', '\u{2CAD}', GeneralCategory::LowercaseLetter),
+    ('\u{2CAE}', '\u{2CAE}', GeneralCategory::UppercaseLetter),
+    ('\u{2CAF}', '\u{2CAF}', GeneralCategory::LowercaseLetter),
+    ('\u{2CB0}', '\u{2CB0}', GeneralCategory::UppercaseLetter),
+    ('\u{2CB1}', '\u{2CB1}', GeneralCategory::LowercaseLetter),
+    ('\u{2CB2}', '\u{2CB2}', GeneralCategory::UppercaseLetter),
+    ('\u{2CB3}', '\u{2CB3}', GeneralCategory::LowercaseLetter),
+    ('\u{2CB4}', '\u{2CB4}', GeneralCategory::UppercaseLetter),
+    ('\u{2CB5}', '\u{2CB5}', GeneralCategory::LowercaseLetter),
+    ('\u{2CB6}', '\u{2CB6}', GeneralCategory::UppercaseLetter),
+    ('\u{2CB7}', '\u{2CB7}', GeneralCategory::LowercaseLetter),
+    ('\u{2CB8}', '\u{2CB8}', GeneralCategory::UppercaseLetter),
+    ('\u{2CB9}', '\u{2CB9}', GeneralCategory::LowercaseLetter),
+    ('\u{2CBA}', '\u{2CBA}', GeneralCategory::UppercaseLetter),
+    ('\u{2CBB}', '\u{2CBB}', GeneralCategory::LowercaseLetter),
+    ('\u{2CBC}', '\u{2CBC}', GeneralCategory::UppercaseLetter),
+    ('\u{2CBD}', '\u{2CBD}', GeneralCategory::LowercaseLetter),
+    ('\u{2CBE}', '\u{2CBE}', GeneralCategory::UppercaseLetter),
+    ('\u{2CBF}', '\u{2CBF}', GeneralCategory::LowercaseLetter),
+    ('\u{2CC0}', '\u{2CC0}', GeneralCategory::UppercaseLetter),
+    ('\u{2CC1}', '\u{2CC1}', GeneralCategory::LowercaseLetter),
+    ('\u{2CC2}', '\u{2CC2}', GeneralCategory::UppercaseLetter),
+    ('\u{2CC3}', '\u{2CC3}', GeneralCategory::LowercaseLetter),
+    ('\u{2CC4}', '\u{2CC4}', GeneralCategory::UppercaseLetter),
+    ('\u{2CC5}', '\u{2CC5}', GeneralCategory::LowercaseLetter),
+    ('\u{2CC6}', '\u{2CC6}', GeneralCategory::UppercaseLetter),
+    ('\u{2CC7}', '\u{2CC7}', GeneralCategory::LowercaseLetter),
+    ('\u{2CC8}', '\u{2CC8}', GeneralCategory::UppercaseLetter),
+    ('\u{2CC9}', '\u{2CC9}', GeneralCategory::LowercaseLetter),
+    ('\u{2CCA}', '\u{2CCA}', GeneralCategory::UppercaseLetter),
+    ('\u{2CCB}', '\u{2CCB}', GeneralCategory::LowercaseLetter),
+    ('\u{2CCC}', '\u{2CCC}', GeneralCategory::UppercaseLetter),
+    ('\u{2CCD}', '\u{2CCD}', GeneralCategory::LowercaseLetter),
+    ('\u{2CCE}', '\u{2CCE}', GeneralCategory::UppercaseLetter),
+    ('\u{2CCF}', '\u{2CCF}', GeneralCategory::LowercaseLetter),
+    ('\u{2CD0}', '\u{2CD0}', GeneralCategory::UppercaseLetter),
+    ('\u{2CD1}', '\u{2CD1}', GeneralCategory::LowercaseLetter),
+    ('\u{2CD2}', '\u{2CD2}', GeneralCategory::UppercaseLetter),
+    ('\u{2CD3}', '\u{2CD3}', GeneralCategory::LowercaseLetter),
+    ('\u{2CD4}', '\u{2CD4}', GeneralCategory::UppercaseLetter),
+    ('\u{2CD5}', '\u{2CD5}', GeneralCategory::LowercaseLetter),
+    ('\u{2CD6}', '\u{2CD6}', GeneralCategory::UppercaseLetter),
+    ('\u{2CD7}', '\u{2CD7}', GeneralCategory::LowercaseLetter),
+    ('\u{2CD8}', '\u{2CD8}', GeneralCategory::UppercaseLetter),
+    ('\u{2CD9}', '\u{2CD9}', GeneralCategory::LowercaseLetter),
+    ('\u{2CDA}', '\u{2CDA}', GeneralCategory::UppercaseLetter),
+    ('\u{2CDB}', '\u{2CDB}', GeneralCategory::LowercaseLetter),
+    ('\u{2CDC}', '\u{2CDC}', GeneralCategory::UppercaseLetter),
+    ('\u{2CDD}', '\u{2CDD}', GeneralCategory::LowercaseLetter),
+    ('\u{2CDE}', '\u{2CDE}', GeneralCategory::UppercaseLetter),
+    ('\u{2CDF}', '\u{2CDF}', GeneralCategory::LowercaseLetter),
+    ('\u{2CE0}', '\u{2CE0}', GeneralCategory::UppercaseLetter),
+    ('\u{2CE1}', '\u{2CE1}', GeneralCategory::LowercaseLetter),
+    ('\u{2CE2}', '\u{2CE2}', GeneralCategory::UppercaseLetter),
+    ('\u{2CE3}', '\u{2CE4}', GeneralCategory::LowercaseLetter),
+    ('\u{2CE5}', '\u{2CEA}', GeneralCategory::OtherSymbol),
+    ('\u{2CEB}', '\u{2CEB}', GeneralCategory::UppercaseLetter),
+    ('\u{2CEC}', '\u{2CEC}', GeneralCategory::LowercaseLetter),
+    ('\u{2CED}', '\u{2CED}', GeneralCategory::UppercaseLetter),
+    ('\u{2CEE}', '\u{2CEE}', GeneralCategory::LowercaseLetter),
+    ('\u{2CEF}', '\u{2CF1}', GeneralCategory::NonspacingMark),
+    ('\u{2CF2}', '\u{2CF2}', GeneralCategory::UppercaseLetter),
+    ('\u{2CF3}', '\u{2CF3}', GeneralCategory::LowercaseLetter),
+    ('\u{2CF9}', '\u{2CFC}', GeneralCategory::OtherPunctuation),
+    ('\u{2CFD}', '\u{2CFD}', GeneralCategory::OtherNumber),
+    ('\u{2CFE}', '\u{2CFF}', GeneralCategory::OtherPunctuation),
+    ('\u{2D00}', '\u{2D25}', GeneralCategory::LowercaseLetter),
+    ('\u{2D27}', '\u{2D27}', GeneralCategory::LowercaseLetter),
+    ('\u{2D2D}', '\u{2D2D}', GeneralCategory::LowercaseLetter),
+    ('\u{2D30}', '\u{2D67}', GeneralCategory::OtherLetter),
+    ('\u{2D6F}', '\u{2D6F}', GeneralCategory::ModifierLetter),
+    ('\u{2D70}', '\u{2D70}', GeneralCategory::OtherPunctuation),
+    ('\u{2D7F}', '\u{2D7F}', GeneralCategory::NonspacingMark),
+    ('\u{2D80}', '\u{2D96}', GeneralCategory::OtherLetter),
+    ('\u{2DA0}', '\u{2DA6}', GeneralCategory::OtherLetter),
+    ('\u{2DA8}', '\u{2DAE}', GeneralCategory::OtherLetter),
+    ('\u{2DB0}', '\u{2DB6}', GeneralCategory::OtherLetter),
+    ('\u{2DB8}', '\u{2DBE}', GeneralCategory::OtherLetter),
+    ('\u{2DC0}', '\u{2DC6}', GeneralCategory::OtherLetter),
+    ('\u{2DC8}', '\u{2DCE}', GeneralCategory::OtherLetter),
+    ('\u{2DD0}', '\u{2DD6}', GeneralCategory::OtherLetter),
+    ('\u{2DD8}', '\u{2DDE}', GeneralCategory::OtherLetter),
+    ('\u{2DE0}', '\u{2DFF}', GeneralCategory::NonspacingMark),
+    ('\u{2E00}', '\u{2E01}', GeneralCategory::OtherPunctuation),
+    ('\u{2E02}', '\u{2E02}', GeneralCategory::InitialPunctuation),
+    ('\u{2E03}', '\u{2E03}', GeneralCategory::FinalPunctuation),
+    ('\u{2E04}', '\u{2E04}', GeneralCategory::InitialPunctuation),
+    ('\u{2E05}', '\u{2E05}', GeneralCategory::FinalPunctuation),
+    ('\u{2E06}', '\u{2E08}', GeneralCategory::OtherPunctuation),
+    ('\u{2E09}', '\u{2E09}', GeneralCategory::InitialPunctuation),
+    ('\u{2E0A}', '\u{2E0A}', GeneralCategory::FinalPunctuation),
+    ('\u{2E0B}', '\u{2E0B}', GeneralCategory::OtherPunctuation),
+    ('\u{2E0C}', '\u{2E0C}', GeneralCategory::InitialPunctuation),
+    ('\u{2E0D}', '\u{2E0D}', GeneralCategory::FinalPunctuation),
+    ('\u{2E0E}', '\u{2E16}', GeneralCategory::OtherPunctuation),
+    ('\u{2E17}', '\u{2E17}', GeneralCategory::DashPunctuation),
+    ('\u{2E18}', '\u{2E19}', GeneralCategory::OtherPunctuation),
+    ('\u{2E1A}', '\u{2E1A}', GeneralCategory::DashPunctuation),
+    ('\u{2E1B}', '\u{2E1B}', GeneralCategory::OtherPunctuation),
+    ('\u{2E1C}', '\u{2E1C}', GeneralCategory::InitialPunctuation),
+    ('\u{2E1D}', '\u{2E1D}', GeneralCategory::FinalPunctuation),
+    ('\u{2E1E}', '\u{2E1F}', GeneralCategory::OtherPunctuation),
+    ('\u{2E20}', '\u{2E20}', GeneralCategory::InitialPunctuation),
+    ('\u{2E21}', '\u{2E21}', GeneralCategory::FinalPunctuation),
+    ('\u{2E22}', '\u{2E22}', GeneralCategory::OpenPunctuation),
+    ('\u{2E23}', '\u{2E23}', GeneralCategory::ClosePunctuation),
+    ('\u{2E24}', '\u{2E24}', GeneralCategory::OpenPunctuation),
+    ('\u{2E25}', '\u{2E25}', GeneralCategory::ClosePunctuation),
+    ('\u{2E26}', '\u{2E26}', GeneralCategory::OpenPunctuation),
+    ('\u{2E27}', '\u{2E27}', GeneralCategory::ClosePunctuation),
+    ('\u{2E28}', '\u{2E28}', GeneralCategory::OpenPunctuation),
+    ('\u{2E29}', '\u{2E29}', GeneralCategory::ClosePunctuation),
+    ('\u{2E2A}', '\u{2E2E}', GeneralCategory::OtherPunctuation),
+    ('\u{2E2F}', '\u{2E2F}', GeneralCategory::ModifierLetter),
+    ('\u{2E30}', '\u{2E39}', GeneralCategory::OtherPunctuation),
+    ('\u{2E3A}', '\u{2E3B}', GeneralCategory::DashPunctuation),
+    ('\u{2E3C}', '\u{2E3F}', GeneralCategory::OtherPunctuation),
+    ('\u{2E40}', '\u{2E40}', GeneralCategory::DashPunctuation),
+    ('\u{2E41}', '\u{2E41}', GeneralCategory::OtherPunctuation),
+    ('\u{2E42}', '\u{2E42}', GeneralCategory::OpenPunctuation),
+    ('\u{2E43}', '\u{2E4F}', GeneralCategory::OtherPunctuation),
+    ('\u{2E50}', '\u{2E51}', GeneralCategory::OtherSymbol),
+    ('\u{2E52}', '\u{2E54}', GeneralCategory::OtherPunctuation),
+    ('\u{2E55}', '\u{2E55}', GeneralCategory::OpenPunctuation),
+    ('\u{2E56}', '\u{2E56}', GeneralCategory::ClosePunctuation),
+    ('\u{2E57}', '\u{2E57}', GeneralCategory::OpenPunctuation),
+    ('\u{2E58}', '\u{2E58}', GeneralCategory::ClosePunctuation),
+    ('\u{2E59}', '\u{2E59}', GeneralCategory::OpenPunctuation),
+    ('\u{2E5A}', '\u{2E5A}', GeneralCategory::ClosePunctuation),
+    ('\u{2E5B}', '\u{2E5B}', GeneralCategory::OpenPunctuation),
+    ('\u{2E5C}', '\u{2E5C}', GeneralCategory::ClosePunctuation),
+    ('\u{2E5D}', '\u{2E5D}', GeneralCategory::DashPunctuation),
+    ('\u{2E80}', '\u{2E99}', GeneralCategory::OtherSymbol),
+    ('\u{2E9B}', '\u{2EF3}', GeneralCategory::OtherSymbol),
+    ('\u{2F00}', '\u{2FD5}', GeneralCategory::OtherSymbol),
+    ('\u{2FF0}', '\u{2FFB}', GeneralCategory::OtherSymbol),
+    ('\u{3000}', '\u{3000}', GeneralCategory::SpaceSeparator),
+    ('\u{3001}', '\u{3003}', GeneralCategory::OtherPunctuation),
+    ('\u{3004}', '\u{3004}', GeneralCategory::OtherSymbol),
+    ('\u{3005}', '\u{3005}', GeneralCategory::ModifierLetter),
+    ('\u{3006}', '\u{3006}', GeneralCategory::OtherLetter),
+    ('\u{3007}', '\u{3007}', GeneralCategory::LetterNumber),
+    ('\u{3008}', '\u{3008}', GeneralCategory::OpenPunctuation),
+    ('\u{3009}', '\u{3009}', GeneralCategory::ClosePunctuation),
+    ('\u{300A}', '\u{300A}', GeneralCategory::OpenPunctuation),
+    ('\u{300B}', '\u{300B}', GeneralCategory::ClosePunctuation),
+    ('\u{300C}', '\u{300C}', GeneralCategory::OpenPunctuation),
+    ('\u{300D}', '\u{300D}', GeneralCategory::ClosePunctuation),
+    ('\u{300E}', '\u{300E}', GeneralCategory::OpenPunctuation),
+    ('\u{300F}', '\u{300F}', GeneralCategory::ClosePunctuation),
+    ('\u{3010}', '\u{3010}', GeneralCategory::OpenPunctuation),
+    ('\u{3011}', '\u{3011}', GeneralCategory::ClosePunctuation),
+    ('\u{3012}', '\u{3013}', GeneralCategory::OtherSymbol),
+    ('\u{3014}', '\u{3014}', GeneralCategory::OpenPunctuation),
+    ('\u{3015}', '\u{3015}', GeneralCategory::ClosePunctuation),
+    ('\u{3016}', '\u{3016}', GeneralCategory::OpenPunctuation),
+    ('\u{3017}', '\u{3017}', GeneralCategory::ClosePunctuation),
+    ('\u{3018}', '\u{3018}', GeneralCategory::OpenPunctuation),
+    ('\u{3019}', '\u{3019}', GeneralCategory::ClosePunctuation),
+    ('\u{301A}', '\u{301A}', GeneralCategory::OpenPunctuation),
+    ('\u{301B}', '\u{301B}', GeneralCategory::ClosePunctuation),
+    ('\u{301C}', '\u{301C}', GeneralCategory::DashPunctuation),
+    ('\u{301D}', '\u{301D}', GeneralCategory::OpenPunctuation),
+    ('\u{301E}', '\u{301F}', GeneralCategory::ClosePunctuation),
+    ('\u{3020}', '\u{3020}', GeneralCategory::OtherSymbol),
+    ('\u{3021}', '\u{3029}', GeneralCategory::LetterNumber),
+    ('\u{302A}', '\u{302D}', GeneralCategory::NonspacingMark),
+    ('\u{302E}', '\u{302F}', GeneralCategory::SpacingMark),
+    ('\u{3030}', '\u{3030}', GeneralCategory::DashPunctuation),
+    ('\u{3031}', '\u{3035}', GeneralCategory::ModifierLetter),
+    ('\u{3036}', '\u{3037}', GeneralCategory::OtherSymbol),
+    ('\u{3038}', '\u{303A}', GeneralCategory::LetterNumber),
+    ('\u{303B}', '\u{303B}', GeneralCategory::ModifierLetter),
+    ('\u{303C}', '\u{303C}', GeneralCategory::OtherLetter),
+    ('\u{303D}', '\u{303D}', GeneralCategory::OtherPunctuation),
+    ('\u{303E}', '\u{303F}', GeneralCategory::OtherSymbol),
+    ('\u{3041}', '\u{3096}', GeneralCategory::OtherLetter),
+    ('\u{3099}', '\u{309A}', GeneralCategory::NonspacingMark),
+    ('\u{309B}', '\u{309C}', GeneralCategory::ModifierSymbol),
+    ('\u{309D}', '\u{309E}', GeneralCategory::ModifierLetter),
+    ('\u{309F}', '\u{309F}', GeneralCategory::OtherLetter),
+    ('\u{30A0}', '\u{30A0}', GeneralCategory::DashPunctuation),
+    ('\u{30A1}', '\u{30FA}', GeneralCategory::OtherLetter),
+    ('\u{30FB}', '\u{30FB}', GeneralCategory::OtherPunctuation),
+    ('\u{30FC}', '\u{30FE}', GeneralCategory::ModifierLetter),
+    ('\u{30FF}', '\u{30FF}', GeneralCategory::OtherLetter),
+    ('\u{3105}', '\u{312F}', GeneralCategory::OtherLetter),
+    ('\u{3131}', '\u{318E}', GeneralCategory::OtherLetter),
+    ('\u{3190}', '\u{3191}', GeneralCategory::OtherSymbol),
+    ('\u{3192}', '\u{3195}', GeneralCategory::OtherNumber),
+    ('\u{3196}', '\u{319F}', GeneralCategory::OtherSymbol),
+    ('\u{31A0}', '\u{31BF}', GeneralCategory::OtherLetter),
+    ('\u{31C0}', '\u{31E3}', GeneralCategory::OtherSymbol),
+    ('\u{31F0}', '\u{31FF}', GeneralCategory::OtherLetter),
+    ('\u{3200}', '\u{321E}', GeneralCategory::OtherSymbol),
+    ('\u{3220}', '\u{3229}', GeneralCategory::OtherNumber),
+    ('\u{322A}', '\u{3247}', GeneralCategory::OtherSymbol),
+    ('\u{3248}', '\u{324F}', GeneralCategory::OtherNumber),
+    ('\u{3250}', '\u{3250}', GeneralCategory::OtherSymbol),
+    ('\u{3251}', '\u{325F}', GeneralCategory::OtherNumber),
+    ('\u{3260}', '\u{327F}', GeneralCategory::OtherSymbol),
+    ('\u{3280}', '\u{3289}', GeneralCategory::OtherNumber),
+    ('\u{328A}', '\u{32B0}', GeneralCategory::OtherSymbol),
+    ('\u{32B1}', '\u{32BF}', GeneralCategory::OtherNumber),
+    ('\u{32C0}', '\u{33FF}', GeneralCategory::OtherSymbol),
+    ('\u{3400}', '\u{4DBF}', GeneralCategory::OtherLetter),
+    ('\u{4DC0}', '\u{4DFF}', GeneralCategory::OtherSymbol),
+    ('\u{4E00}', '\u{A014}', GeneralCategory::OtherLetter),
+    ('\u{A015}', '\u{A015}', GeneralCategory::ModifierLetter),
+    ('\u{A016}', '\u{A48C}', GeneralCategory::OtherLetter),
+    ('\u{A490}', '\u{A4C6}', GeneralCategory::OtherSymbol),
+    ('\u{A4D0}', '\u{A4F7}', GeneralCategory::OtherLetter),
+    ('\u{A4F8}', '\u{A4FD}', GeneralCategory::ModifierLetter),
+    ('\u{A4FE}', '\u{A4FF}', GeneralCategory::OtherPunctuation),
+    ('\u{A500}', '\u{A60B}', GeneralCategory::OtherLetter),
+    ('\u{A60C}', '\u{A60C}', GeneralCategory::ModifierLetter),
+    ('\u{A60D}', '\u{A60F}', GeneralCategory::OtherPunctuation),
+    ('\u{A610}', '\u{A61F}', GeneralCategory::OtherLetter),
+    ('\u{A620}', '\u{A629}', GeneralCategory::DecimalNumber),
+    ('\u{A62A}', '\u{A62B}', GeneralCategory::OtherLetter),
+    ('\u{A640}', '\u{A640}', GeneralCategory::UppercaseLetter),
+    ('\u{A641}', '\u{A641}', GeneralCategory::LowercaseLetter),
+    ('\u{A642}', '\u{A642}', GeneralCategory::UppercaseLetter),
+    ('\u{A643}', '\u{A643}', GeneralCategory::LowercaseLetter),
+    ('\u{A644}', '\u{A644}', GeneralCategory::UppercaseLetter),
+    ('\u{A645}', '\u{A645}', GeneralCategory::LowercaseLetter),
+    ('\u{A646}', '\u{A646}', GeneralCategory::UppercaseLetter),
+    ('\u{A647}', '\u{A647}', GeneralCategory::LowercaseLetter),
+    ('\u{A648}', '\u{A648}', GeneralCategory::UppercaseLetter),
+    ('\u{A649}', '\u{A649}', GeneralCategory::LowercaseLetter),
+    ('\u{A64A}', '\u{A64A}', GeneralCategory::UppercaseLetter),
+    ('\u{A64B}', '\u{A64B}', GeneralCategory::LowercaseLetter),
+    ('\u{A64C}', '\u{A64C}', GeneralCategory::UppercaseLetter),
+    ('\u{A64D}', '\u{A64D}', GeneralCategory::LowercaseLetter),
+    ('\u{A64E}', '\u{A64E}', GeneralCategory::UppercaseLetter),
+    ('\u{A64F}', '\u{A64F}', GeneralCategory::LowercaseLetter),
+    ('\u{A650}', '\u{A650}', GeneralCategory::UppercaseLetter),
+    ('\u{A651}', '\u{A651}', GeneralCategory::LowercaseLetter),
+    ('\u{A652}', '\u{A652}', GeneralCategory::UppercaseLetter),
+    ('\u{A653}', '\u{A653}', GeneralCategory::LowercaseLetter),
+    ('\u{A654}', '\u{A654}', GeneralCategory::UppercaseLetter),
+    ('\u{A655}', '\u{A655}', GeneralCategory::LowercaseLetter),
+    ('\u{A656}', '\u{A656}', GeneralCategory::UppercaseLetter),
+    ('\u{A657}', '\u{A657}', GeneralCategory::LowercaseLetter),
+    ('\u{A658}', '\u{A658}', GeneralCategory::UppercaseLetter),
+    ('\u{A659}', '\u{A659}', GeneralCategory::LowercaseLetter),
+    ('\u{A65A}', '\u{A65A}', GeneralCategory::UppercaseLetter),
+    ('\u{A65B}', '\u{A65B}', GeneralCategory::LowercaseLetter),
+    ('\u{A65C}', '\u{A65C}', GeneralCategory::UppercaseLetter),
+    ('\u{A65D}', '\u{A65D}', GeneralCategory::LowercaseLetter),
+    ('\u{A65E}', '\u{A65E}', GeneralCategory::UppercaseLetter),
+    ('\u{A65F}', '\u{A65F}', GeneralCategory::LowercaseLetter),
+    ('\u{A660}', '\u{A660}', GeneralCategory::UppercaseLetter),
+    ('\u{A661}', '\u{A661}', GeneralCategory::LowercaseLetter),
+    ('\u{A662}', '\u{A662}', GeneralCategory::UppercaseLetter),
+    ('\u{A663}', '\u{A663}', GeneralCategory::LowercaseLetter),
+    ('\u{A664}', '\u{A664}', GeneralCategory::UppercaseLetter),
+    ('\u{A665}', '\u{A665}', GeneralCategory::LowercaseLetter),
+    ('\u{A666}', '\u{A666}', GeneralCategory::UppercaseLetter),
+    ('\u{A667}', '\u{A667}', GeneralCategory::LowercaseLetter),
+    ('\u{A668}', '\u{A668}', GeneralCategory::UppercaseLetter),
+    ('\u{A669}', '\u{A669}', GeneralCategory::LowercaseLetter),
+    ('\u{A66A}', '\u{A66A}', GeneralCategory::UppercaseLetter),
+    ('\u{A66B}', '\u{A66B}', GeneralCategory::LowercaseLetter),
+    ('\u{A66C}', '\u{A66C}', GeneralCategory::UppercaseLetter),
+    ('\u{A66D}', '\u{A66D}', GeneralCategory::LowercaseLetter),
+    ('\u{A66E}', '\u{A66E}', GeneralCategory::OtherLetter),
+    ('\u{A66F}', '\u{A66F}', GeneralCategory::NonspacingMark),
+    ('\u{A670}', '\u{A672}', GeneralCategory::EnclosingMark),
+    ('\u{A673}', '\u{A673}', GeneralCategory::OtherPunctuation),
+    ('\u{A674}', '\u{A67D}', GeneralCategory::NonspacingMark),
+    ('\u{A67E}', '\u{A67E}', GeneralCategory::OtherPunctuation),
+    ('\u{A67F}', '\u{A67F}', GeneralCategory::ModifierLetter),
+    ('\u{A680}', '\u{A680}', GeneralCategory::UppercaseLetter),
+    ('\u{A681}', '\u{A681}', GeneralCategory::LowercaseLetter),
+    ('\u{A682}', '\u{A682}', GeneralCategory::UppercaseLetter),
+    ('\u{A683}', '\u{A683}', GeneralCategory::LowercaseLetter),
+    ('\u{A684}', '\u{A684}', GeneralCategory::UppercaseLetter),
+    ('\u{A685}', '\u{A685}', GeneralCategory::LowercaseLetter),
+    ('\u{A686}', '\u{A686}', GeneralCategory::UppercaseLetter),
+    ('\u{A687}', '\u{A687}', GeneralCategory::LowercaseLetter),
+    ('\u{A688}', '\u{A688}', GeneralCategory::UppercaseLetter),
+    ('\u{A689}', '\u{A689}', GeneralCategory::LowercaseLetter),
+    ('\u{A68A}', '\u{A68A}', GeneralCategory::UppercaseLetter),
+    ('\u{A68B}', '\u{A68B}', GeneralCategory::LowercaseLetter),
+    ('\u{A68C}', '\u{A68C}', GeneralCategory::UppercaseLetter),
+    ('\u{A68D}', '\u{A68D}', GeneralCategory::LowercaseLetter),
+    ('\u{A68E}', '\u{A68E}', GeneralCategory::UppercaseLetter),
+    ('\u{A68F}', '\u{A68F}', GeneralCategory::LowercaseLetter),
+    ('\u{A690}', '\u{A690}', GeneralCategory::UppercaseLetter),
+    ('\u{A691}', '\u{A691}', GeneralCategory::LowercaseLetter),
+    ('\u{A692}', '\u{A692}', GeneralCategory::UppercaseLetter),
+    ('\u{A693}', '\u{A693}', GeneralCategory::LowercaseLetter),
+    ('\u{A694}', '\u{A694}', GeneralCategory::UppercaseLetter),
+    ('\u{A695}', '\u{A695}', GeneralCategory::LowercaseLetter),
+    ('\u{A696}', '\u{A696}', GeneralCategory::UppercaseLetter),
+    ('\u{A697}', '\u{A697}', GeneralCategory::LowercaseLetter),
+    ('\u{A698}', '\u{A698}', GeneralCategory::UppercaseLetter),
+    ('\u{A699}', '\u{A699}', GeneralCategory::LowercaseLetter),
+    ('\u{A69A}', '\u{A69A}', GeneralCategory::UppercaseLetter),
+    ('\u{A69B}', '\u{A69B}', GeneralCategory::LowercaseLetter),
+    ('\u{A69C}', '\u{A69D}', GeneralCategory::ModifierLetter),
+    ('\u{A69E}', '\u{A69F}', GeneralCategory::NonspacingMark),
+    ('\u{A6A0}', '\u{A6E5}', GeneralCategory::OtherLetter),
+    ('\u{A6E6}', '\u{A6EF}', GeneralCategory::LetterNumber),
+    ('\u{A6F0}', '\u{A6F1}', GeneralCategory::NonspacingMark),
+    ('\u{A6F2}', '\u{A6F7}', GeneralCategory::OtherPunctuation),
+    ('\u{A700}', '\u{A716}', GeneralCategory::ModifierSymbol),
+    ('\u{A717}', '\u{A71F}', GeneralCategory::ModifierLetter),
+    ('\u{A720}', '\u{A721}', GeneralCategory::ModifierSymbol),
+    ('\u{A722}', '\u{A722}', GeneralCategory::UppercaseLetter),
+    ('\u{A723}', '\u{A723}', GeneralCategory::LowercaseLetter),
+    ('\u{A724}', '\u{A724}', GeneralCategory::UppercaseLetter),
+    ('\u{A725}', '\u{A725}', GeneralCategory::LowercaseLetter),
+    ('\u{A726}', '\u{A726}', GeneralCategory::UppercaseLetter),
+    ('\u{A727}', '\u{A727}', GeneralCategory::LowercaseLetter),
+    ('\u{A728}', '\u{A728}', GeneralCategory::UppercaseLetter),
+    ('\u{A729}', '\u{A729}', GeneralCategory::LowercaseLetter),
+    ('\u{A72A}', '\u{A72A}', GeneralCategory::UppercaseLetter),
+    ('\u{A72B}', '\u{A72B}', GeneralCategory::LowercaseLetter),
+    ('\u{A72C}', '\u{A72C}', GeneralCategory::UppercaseLetter),
+    ('\u{A72D}', '\u{A72D}', GeneralCategory::LowercaseLetter),
+    ('\u{A72E}', '\u{A72E}', GeneralCategory::UppercaseLetter),
+    ('\u{A72F}', '\u{A731}', GeneralCategory::LowercaseLetter),
+    ('\u{A732}', '\u{A732}', GeneralCategory::UppercaseLetter),
+    ('\u{A733}', '\u{A733}', GeneralCategory::LowercaseLetter),
+    ('\u{A734}', '\u{A734}', GeneralCategory::UppercaseLetter),
+    ('\u{A735}', '\u{A735}', GeneralCategory::LowercaseLetter),
+    ('\u{A736}', '\u{A736}', GeneralCategory::UppercaseLetter),
+    ('\u{A737}', '\u{A737}', GeneralCategory::LowercaseLetter),
+    ('\u{A738}', '\u{A738}', GeneralCategory::UppercaseLetter),
+    ('\u{A739}', '\u{A739}', GeneralCategory::LowercaseLetter),
+    ('\u{A73A}', '\u{A73A}', GeneralCategory::UppercaseLetter),
+    ('\u{A73B}', '\u{A73B}', GeneralCategory::LowercaseLetter),
+    ('\u{A73C}', '\u{A73C}', GeneralCategory::UppercaseLetter),
+    ('\u{A73D}', '\u{A73D}', GeneralCategory::LowercaseLetter),
+    ('\u{A73E}', '\u{A73E}', GeneralCategory::UppercaseLetter),
+    ('\u{A73F}', '\u{A73F}', GeneralCategory::LowercaseLetter),
+    ('\u{A740}', '\u{A740}', GeneralCategory::UppercaseLetter),
+    ('\u{A741}', '\u{A741}', GeneralCategory::LowercaseLetter),
+    ('\u{A742}', '\u{A742}', GeneralCategory::UppercaseLetter),
+    ('\u{A743}', '\u{A743}', GeneralCategory::LowercaseLetter),
+    ('\u{A744}', '\u{A744}', GeneralCategory::UppercaseLetter),
+    ('\u{A745}', '\u{A745}', GeneralCategory::LowercaseLetter),
+    ('\u{A746}', '\u{A746}', GeneralCategory::UppercaseLetter),
+    ('\u{A747}', '\u{A747}', GeneralCategory::LowercaseLetter),
+    ('\u{A748}', '\u{A748}', GeneralCategory::UppercaseLetter),
+    ('\u{A749}', '\u{A749}', GeneralCategory::LowercaseLetter),
+    ('\u{A74A}', '\u{A74A}', GeneralCategory::UppercaseLetter),
+    ('\u{A74B}', '\u{A74B}', GeneralCategory::LowercaseLetter),
+    ('\u{A74C}', '\u{A74C}', GeneralCategory::UppercaseLetter),
+    ('\u{A74D}', '\u{A74D}', GeneralCategory::LowercaseLetter),
+    ('\u{A74E}', '\u{A74E}', GeneralCategory::UppercaseLetter),
+    ('\u{A74F}', '\u{A74F}', GeneralCategory::LowercaseLetter),
+    ('\u{A750}', '\u{A750}', GeneralCategory::UppercaseLetter),
+    ('\u{A751}', '\u{A751}', GeneralCategory::LowercaseLetter),
+    ('\u{A752}', '\u{A752}', GeneralCategory::UppercaseLetter),
+    ('\u{A753}', '\u{A753}', GeneralCategory::LowercaseLetter),
+    ('\u{A754}', '\u{A754}', GeneralCategory::UppercaseLetter),
+    ('\u{A755}', '\u{A755}', GeneralCategory::LowercaseLetter),
+    ('\u{A756}', '\u{A756}', GeneralCategory::UppercaseLetter),
+    ('\u{A757}', '\u{A757}', GeneralCategory::LowercaseLetter),
+    ('\u{A758}', '\u{A758}', GeneralCategory::UppercaseLetter),
+    ('\u{A759}', '\u{A759}', GeneralCategory::LowercaseLetter),
+    ('\u{A75A}', '\u{A75A}', GeneralCategory::UppercaseLetter),
+    ('\u{A75B}', '\u{A75B}', GeneralCategory::LowercaseLetter),
+    ('\u{A75C}', '\u{A75C}', GeneralCategory::UppercaseLetter),
+    ('\u{A75D}', '\u{A75D}', GeneralCategory::LowercaseLetter),
+    ('\u{A75E}', '\u{A75E}', GeneralCategory::UppercaseLetter),
+    ('\u{A75F}', '\u{A75F}', GeneralCategory::LowercaseLetter),
+    ('\u{A760}', '\u{A760}', GeneralCategory::UppercaseLetter),
+    ('\u{A761}', '\u{A761}', GeneralCategory::LowercaseLetter),
+    ('\u{A762}', '\u{A762}', GeneralCategory::UppercaseLetter),
+    ('\u{A763}', '\u{A763}', GeneralCategory::LowercaseLetter),
+    ('\u{A764}', '\u{A764}', GeneralCategory::UppercaseLetter),
+    ('\u{A765}', '\u{A765}', GeneralCategory::LowercaseLetter),
+    ('\u{A766}', '\u{A766}', GeneralCategory::UppercaseLetter),
+    ('\u{A767}', '\u{A767}', GeneralCategory::LowercaseLetter),
+    ('\u{A768}', '\u{A768}', GeneralCategory::UppercaseLetter),
+    ('\u{A769}', '\u{A769}', GeneralCategory::LowercaseLetter),
+    ('\u{A76A}', '\u{A76A}', GeneralCategory::UppercaseLetter),
+    ('\u{A76B}', '\u{A76B}', GeneralCategory::LowercaseLetter),
+    ('\u{A76C}', '\u{A76C}', GeneralCategory::UppercaseLetter),
+    ('\u{A76D}', '\u{A76D}', GeneralCategory::LowercaseLetter),
+    ('\u{A76E}', '\u{A76E}', GeneralCategory::UppercaseLetter),
+    ('\u{A76F}', '\u{A76F}', GeneralCategory::LowercaseLetter),
+    ('\u{A770}', '\u{A770}', GeneralCategory::ModifierLetter),
+    ('\u{A771}', '\u{A778}', GeneralCategory::LowercaseLetter),
+    ('\u{A779}', '\u{A779}', GeneralCategory::UppercaseLetter),
+    ('\u{A77A}', '\u{A77A}', GeneralCategory::LowercaseLetter),
+    ('\u{A77B}', '\u{A77B}', GeneralCategory::UppercaseLetter),
+    ('\u{A77C}', '\u{A77C}', GeneralCategory::LowercaseLetter),
+    ('\u{A77D}', '\u{A77E}', GeneralCategory::UppercaseLetter),
+    ('\u{A77F}', '\u{A77F}', GeneralCategory::LowercaseLetter),
+    ('\u{A780}', '\u{A780}', GeneralCategory::UppercaseLetter),
+    ('\u{A781}', '\u{A781}', GeneralCategory::LowercaseLetter),
+    ('\u{A782}', '\u{A782}', GeneralCategory::UppercaseLetter),
+    ('\u{A783}', '\u{A783}', GeneralCategory::LowercaseLetter),
+    ('\u{A784}', '\u{A784}', GeneralCategory::UppercaseLetter),
+    ('\u{A785}', '\u{A785}', GeneralCategory::LowercaseLetter),
+    ('\u{A786}', '\u{A786}', GeneralCategory::UppercaseLetter),
+    ('\u{A787}', '\u{A787}', GeneralCategory::LowercaseLetter),
+    ('\u{A788}', '\u{A788}', GeneralCategory::ModifierLetter),
+    ('\u{A789}', '\u{A78A}', GeneralCategory::ModifierSymbol),
+    ('\u{A78B}', '\u{A78B}', GeneralCategory::UppercaseLetter),
+    ('\u{A78C}', '\u{A78C}', GeneralCategory::LowercaseLetter),
+    ('\u{A78D}', '\u{A78D}', GeneralCategory::UppercaseLetter),
+    ('\u{A78E}', '\u{A78E}', GeneralCategory::LowercaseLetter),
+    ('\u{A78F}', '\u{A78F}', GeneralCategory::OtherLetter),
+    ('\u{A790}', '\u{A790}', GeneralCategory::UppercaseLetter),
+    ('\u{A791}', '\u{A791}', GeneralCategory::LowercaseLetter),
+    ('\u{A792}', '\u{A792}', GeneralCategory::UppercaseLetter),
+    ('\u{A793}', '\u{A795}', GeneralCategory::LowercaseLetter),
+    ('\u{A796}', '\u{A796}', GeneralCategory::UppercaseLetter),
+    ('\u{A797}', '\u{A797}', GeneralCategory::LowercaseLetter),
+    ('\u{A798}', '\u{A798}', GeneralCategory::UppercaseLetter),
+    ('\u{A799}', '\u{A799}', GeneralCategory::LowercaseLetter),
+    ('\u{A79A}', '\u{A79A}', GeneralCategory::UppercaseLetter),
+    ('\u{A79B}', '\u{A79B}', GeneralCategory::LowercaseLetter),
+    ('\u{A79C}', '\u{A79C}', GeneralCategory::UppercaseLetter),
+    ('\u{A79D}', '\u{A79D}', GeneralCategory::LowercaseLetter),
+    ('\u{A79E}', '\u{A79E}', GeneralCategory::UppercaseLetter),
+    ('\u{A79F}', '\u{A79F}', GeneralCategory::LowercaseLetter),
+    ('\u{A7A0}', '\u{A7A0}', GeneralCategory::UppercaseLetter),
+    ('\u{A7A1}', '\u{A7A1}', GeneralCategory::LowercaseLetter),
+    ('\u{A7A2}', '\u{A7A2}', GeneralCategory::UppercaseLetter),
+    ('\u{A7A3}', '\u{A7A3}', GeneralCategory::LowercaseLetter),
+    ('\u{A7A4}', '\u{A7A4}', GeneralCategory::UppercaseLetter),
+    ('\u{A7A5}', '\u{A7A5}', GeneralCategory::LowercaseLetter),
+    ('\u{A7A6}', '\u{A7A6}', GeneralCategory::UppercaseLetter),
+    ('\u{A7A7}', '\u{A7A7}', GeneralCategory::LowercaseLetter),
+    ('\u{A7A8}', '\u{A7A8}', GeneralCategory::UppercaseLetter),
+    ('\u{A7A9}', '\u{A7A9}', GeneralCategory::LowercaseLetter),
+    ('\u{A7AA}', '\u{A7AE}', GeneralCategory::UppercaseLetter),
+    ('\u{A7AF}', '\u{A7AF}', GeneralCategory::LowercaseLetter),
+    ('\u{A7B0}', '\u{A7B4}', GeneralCategory::UppercaseLetter),
+    ('\u{A7B5}', '\u{A7B5}', GeneralCategory::LowercaseLetter),
+    ('\u{A7B6}', '\u{A7B6}', GeneralCategory::UppercaseLetter),
+    ('\u{A7B7}', '\u{A7B7}', GeneralCategory::LowercaseLetter),
+    ('\u{A7B8}', '\u{A7B8}', GeneralCategory::UppercaseLetter),
+    ('\u{A7B9}', '\u{A7B9}', GeneralCategory::LowercaseLetter),
+    ('\u{A7BA}', '\u{A7BA}', GeneralCategory::UppercaseLetter),
+    ('\u{A7BB}', '\u{A7BB}', GeneralCategory::LowercaseLetter),
+    ('\u{A7BC}', '\u{A7BC}', GeneralCategory::UppercaseLetter),
+    ('\u{A7BD}', '\u{A7BD}', GeneralCategory::LowercaseLetter),
+    ('\u{A7BE}', '\u{A7BE}', GeneralCategory::UppercaseLetter),
+    ('\u{A7BF}', '\u{A7BF}', GeneralCategory::LowercaseLetter),
+    ('\u{A7C0}', '\u{A7C0}', GeneralCategory::UppercaseLetter),
+    ('\u{A7C1}', '\u{A7C1}', GeneralCategory::LowercaseLetter),
+    ('\u{A7C2}', '\u{A7C2}', GeneralCategory::UppercaseLetter),
+    ('\u{A7C3}', '\u{A7C3}', GeneralCategory::LowercaseLetter),
+    ('\u{A7C4}', '\u{A7C7}', GeneralCategory::UppercaseLetter),
+    ('\u{A7C8}', '\u{A7C8}', GeneralCategory::LowercaseLetter),
+    ('\u{A7C9}', '\u{A7C9}', GeneralCategory::UppercaseLetter),
+    ('\u{A7CA}', '\u{A7CA}', GeneralCategory::LowercaseLetter),
+    ('\u{A7D0}', '\u{A7D0}', GeneralCategory::UppercaseLetter),
+    ('\u{A7D1}', '\u{A7D1}', GeneralCategory::LowercaseLetter),
+    ('\u{A7D3}', '\u{A7D3}', GeneralCategory::LowercaseLetter),
+    ('\u{A7D5}', '\u{A7D5}', GeneralCategory::LowercaseLetter),
+    ('\u{A7D6}', '\u{A7D6}', GeneralCategory::UppercaseLetter),
+    ('\u{A7D7}', '\u{A7D7}', GeneralCategory::LowercaseLetter),
+    ('\u{A7D8}', '\u{A7D8}', GeneralCategory::UppercaseLetter),
+    ('\u{A7D9}', '\u{A7D9}', GeneralCategory::LowercaseLetter),
+    ('\u{A7F2}', '\u{A7F4}', GeneralCategory::ModifierLetter),
+    ('\u{A7F5}', '\u{A7F5}', GeneralCategory::UppercaseLetter),
+    ('\u{A7F6}', '\u{A7F6}', GeneralCategory::LowercaseLetter),
+    ('\u{A7F7}', '\u{A7F7}', GeneralCategory::OtherLetter),
+    ('\u{A7F8}', '\u{A7F9}', GeneralCategory::ModifierLetter),
+    ('\u{A7FA}', '\u{A7FA}', GeneralCategory::LowercaseLetter),
+    ('\u{A7FB}', '\u{A801}', GeneralCategory::OtherLetter),
+    ('\u{A802}', '\u{A802}', GeneralCategory::NonspacingMark),
+    ('\u{A803}', '\u{A805}', GeneralCategory::OtherLetter),
+    ('\u{A806}', '\u{A806}', GeneralCategory::NonspacingMark),
+    ('\u{A807}', '\u{A80A}', GeneralCategory::OtherLetter),
+    ('\u{A80B}', '\u{A80B}', GeneralCategory::NonspacingMark),
+    ('\u{A80C}', '\u{A822}', GeneralCategory::OtherLetter),
+    ('\u{A823}', '\u{A824}', GeneralCategory::SpacingMark),
+    ('\u{A825}', '\u{A826}', GeneralCategory::NonspacingMark),
+    ('\u{A827}', '\u{A827}', GeneralCategory::SpacingMark),
+    ('\u{A828}', '\u{A82B}', GeneralCategory::OtherSymbol),
+    ('\u{A82C}', '\u{A82C}', GeneralCategory::NonspacingMark),
+    ('\u{A830}', '\u{A835}', GeneralCategory::OtherNumber),
+    ('\u{A836}', '\u{A837}', GeneralCategory::OtherSymbol),
+    ('\u{A838}', '\u{A838}', GeneralCategory::CurrencySymbol),
+    ('\u{A839}', '\u{A839}', GeneralCategory::OtherSymbol),
+    ('\u{A840}', '\u{A873}', GeneralCategory::OtherLetter),
+    ('\u{A874}', '\u{A877}', GeneralCategory::OtherPunctuation),
+    ('\u{A880}', '\u{A881}', GeneralCategory::SpacingMark),
+    ('\u{A882}', '\u{A8B3}', GeneralCategory::OtherLetter),
+    ('\u{A8B4}', '\u{A8C3}', GeneralCategory::SpacingMark),
+    ('\u{A8C4}', '\u{A8C5}', GeneralCategory::NonspacingMark),
+    ('\u{A8CE}', '\u{A8CF}', GeneralCategory::OtherPunctuation),
+    ('\u{A8D0}', '\u{A8D9}', GeneralCategory::DecimalNumber),
+    ('\u{A8E0}', '\u{A8F1}', GeneralCategory::NonspacingMark),
+    ('\u{A8F2}', '\u{A8F7}', GeneralCategory::OtherLetter),
+    ('\u{A8F8}', '\u{A8FA}', GeneralCategory::OtherPunctuation),
+    ('\u{A8FB}', '\u{A8FB}', GeneralCategory::OtherLetter),
+    ('\u{A8FC}', '\u{A8FC}', GeneralCategory::OtherPunctuation),
+    ('\u{A8FD}', '\u{A8FE}', GeneralCategory::OtherLetter),
+    ('\u{A8FF}', '\u{A8FF}', GeneralCategory::NonspacingMark),
+    ('\u{A900}', '\u{A909}', GeneralCategory::DecimalNumber),
+    ('\u{A90A}', '\u{A925}', GeneralCategory::OtherLetter),
+    ('\u{A926}', '\u{A92D}', GeneralCategory::NonspacingMark),
+    ('\u{A92E}', '\u{A92F}', GeneralCategory::OtherPunctuation),
+    ('\u{A930}', '\u{A946}', GeneralCategory::OtherLetter),
+    ('\u{A947}', '\u{A951}', GeneralCategory::NonspacingMark),
+    ('\u{A952}', '\u{A953}', GeneralCategory::SpacingMark),
+    ('\u{A95F}', '\u{A95F}', GeneralCategory::OtherPunctuation),
+    ('\u{A960}', '\u{A97C}', GeneralCategory::OtherLetter),
+    ('\u{A980}', '\u{A982}', GeneralCategory::NonspacingMark),
+    ('\u{A983}', '\u{A983}', GeneralCategory::SpacingMark),
+    ('\u{A984}', '\u{A9B2}', GeneralCategory::OtherLetter),
+    ('\u{A9B3}', '\u{A9B3}', GeneralCategory::NonspacingMark),
+    ('\u{A9B4}', '\u{A9B5}', GeneralCategory::SpacingMark),
+    ('\u{A9B6}', '\u{A9B9}', GeneralCategory::NonspacingMark),
+    ('\u{A9BA}', '\u{A9BB}', GeneralCategory::SpacingMark),
+    ('\u{A9BC}', '\u{A9BD}', GeneralCategory::NonspacingMark),
+    ('\u{A9BE}', '\u{A9C0}', GeneralCategory::SpacingMark),
+    ('\u{A9C1}', '\u{A9CD}', GeneralCategory::OtherPunctuation),
+    ('\u{A9CF}', '\u{A9CF}', GeneralCategory::ModifierLetter),
+    ('\u{A9D0}', '\u{A9D9}', GeneralCategory::DecimalNumber),
+    ('\u{A9DE}', '\u{A9DF}', GeneralCategory::OtherPunctuation),
+    ('\u{A9E0}', '\u{A9E4}', GeneralCategory::OtherLetter),
+    ('\u{A9E5}', '\u{A9E5}', GeneralCategory::NonspacingMark),
+    ('\u{A9E6}', '\u{A9E6}', GeneralCategory::ModifierLetter),
+    ('\u{A9E7}', '\u{A9EF}', GeneralCategory::OtherLetter),
+    ('\u{A9F0}', '\u{A9F9}', GeneralCategory::DecimalNumber),
+    ('\u{A9FA}', '\u{A9FE}', GeneralCategory::OtherLetter),
+    ('\u{AA00}', '\u{AA28}', GeneralCategory::OtherLetter),
+    ('\u{AA29}', '\u{AA2E}', GeneralCategory::NonspacingMark),
+    ('\u{AA2F}', '\u{AA30}', GeneralCategory::SpacingMark),
+    ('\u{AA31}', '\u{AA32}', GeneralCategory::NonspacingMark),
+    ('\u{AA33}', '\u{AA34}', GeneralCategory::SpacingMark),
+    ('\u{AA35}', '\u{AA36}', GeneralCategory::NonspacingMark),
+    ('\u{AA40}', '\u{AA42}', GeneralCategory::OtherLetter),
+    ('\u{AA43}', '\u{AA43}', GeneralCategory::NonspacingMark),
+    ('\u{AA44}', '\u{AA4B}', GeneralCategory::OtherLetter),
+    ('\u{AA4C}', '\u{AA4C}', GeneralCategory::NonspacingMark),
+    ('\u{AA4D}', '\u{AA4D}', GeneralCategory::SpacingMark),
+    ('\u{AA50}', '\u{AA59}', GeneralCategory::DecimalNumber),
+    ('\u{AA5C}', '\u{AA5F}', GeneralCategory::OtherPunctuation),
+    ('\u{AA60}', '\u{AA6F}', GeneralCategory::OtherLetter),
+    ('\u{AA70}', '\u{AA70}', GeneralCategory::ModifierLetter),
+    ('\u{AA71}', '\u{AA76}', GeneralCategory::OtherLetter),
+    ('\u{AA77}', '\u{AA79}', GeneralCategory::OtherSymbol),
+    ('\u{AA7A}', '\u{AA7A}', GeneralCategory::OtherLetter),
+    ('\u{AA7B}', '\u{AA7B}', GeneralCategory::SpacingMark),
+    ('\u{AA7C}', '\u{AA7C}', GeneralCategory::NonspacingMark),
+    ('\u{AA7D}', '\u{AA7D}', GeneralCategory::SpacingMark),
+    ('\u{AA7E}', '\u{AAAF}', GeneralCategory::OtherLetter),
+    ('\u{AAB0}', '\u{AAB0}', GeneralCategory::NonspacingMark),
+    ('\u{AAB1}', '\u{AAB1}', GeneralCategory::OtherLetter),
+    ('\u{AAB2}', '\u{AAB4}', GeneralCategory::NonspacingMark),
+    ('\u{AAB5}', '\u{AAB6}', GeneralCategory::OtherLetter),
+    ('\u{AAB7}', '\u{AAB8}', GeneralCategory::NonspacingMark),
+    ('\u{AAB9}', '\u{AABD}', GeneralCategory::OtherLetter),
+    ('\u{AABE}', '\u{AABF}', GeneralCategory::NonspacingMark),
+    ('\u{AAC0}', '\u{AAC0}', GeneralCategory::OtherLetter),
+    ('\u{AAC1}', '\u{AAC1}', GeneralCategory::NonspacingMark),
+    ('\u{AAC2}', '\u{AAC2}', GeneralCategory::OtherLetter),
+    ('\u{AADB}', '\u{AADC}', GeneralCategory::OtherLetter),
+    ('\u{AADD}', '\u{AADD}', GeneralCategory::ModifierLetter),
+    ('\u{AADE}', '\u{AADF}', GeneralCategory::OtherPunctuation),
+    ('\u{AAE0}', '\u{AAEA}', GeneralCategory::OtherLetter),
+    ('\u{AAEB}', '\u{AAEB}', GeneralCategory::SpacingMark),
+    ('\u{AAEC}', '\u{AAED}', GeneralCategory::NonspacingMark),
+    ('\u{AAEE}', '\u{AAEF}', GeneralCategory::SpacingMark),
+    ('\u{AAF0}', '\u{AAF1}', GeneralCategory::OtherPunctuation),
+    ('\u{AAF2}', '\u{AAF2}', GeneralCategory::OtherLetter),
+    ('\u{AAF3}', '\u{AAF4}', GeneralCategory::ModifierLetter),
+    ('\u{AAF5}', '\u{AAF5}', GeneralCategory::SpacingMark),
+    ('\u{AAF6}', '\u{AAF6}', GeneralCategory::NonspacingMark),
+    ('\u{AB01}', '\u{AB06}', GeneralCategory::OtherLetter),
+    ('\u{AB09}', '\u{AB0E}', GeneralCategory::OtherLetter),
+    ('\u{AB11}', '\u{AB16}', GeneralCategory::OtherLetter),
+    ('\u{AB20}', '\u{AB26}', GeneralCategory::OtherLetter),
+    ('\u{AB28}', '\u{AB2E}', GeneralCategory::OtherLetter),
+    ('\u{AB30}', '\u{AB5A}', GeneralCategory::LowercaseLetter),
+    ('\u{AB5B}', '\u{AB5B}', GeneralCategory::ModifierSymbol),
+    ('\u{AB5C}', '\u{AB5F}', GeneralCategory::ModifierLetter),
+    ('\u{AB60}', '\u{AB68}', GeneralCategory::LowercaseLetter),
+    ('\u{AB69}', '\u{AB69}', GeneralCategory::ModifierLetter),
+    ('\u{AB6A}', '\u{AB6B}', GeneralCategory::ModifierSymbol),
+    ('\u{AB70}', '\u{ABBF}', GeneralCategory::LowercaseLetter),
+    ('\u{ABC0}', '\u{ABE2}', GeneralCategory::OtherLetter),
+    ('\u{ABE3}', '\u{ABE4}', GeneralCategory::SpacingMark),
+    ('\u{ABE5}', '\u{ABE5}', GeneralCategory::NonspacingMark),
+    ('\u{ABE6}', '\u{ABE7}', GeneralCategory::SpacingMark),
+    ('\u{ABE8}', '\u{ABE8}', GeneralCategory::NonspacingMark),
+    ('\u{ABE9}', '\u{ABEA}', GeneralCategory::SpacingMark),
+    ('\u{ABEB}', '\u{ABEB}', GeneralCategory::OtherPunctuation),
+    ('\u{ABEC}', '\u{ABEC}', GeneralCategory::SpacingMark),
+    ('\u{ABED}', '\u{ABED}', GeneralCategory::NonspacingMark),
+    ('\u{ABF0}', '\u{ABF9}', GeneralCategory::DecimalNumber),
+    ('\u{AC00}', '\u{D7A3}', GeneralCategory::OtherLetter),
+    ('\u{D7B0}', '\u{D7C6}', GeneralCategory::OtherLetter),
+    ('\u{D7CB}', '\u{D7FB}', GeneralCategory::OtherLetter),
+    ('\u{E000}', '\u{F8FF}', GeneralCategory::PrivateUse),
+    ('\u{F900}', '\u{FA6D}', GeneralCategory::OtherLetter),
+    ('\u{FA70}', '\u{FAD9}', GeneralCategory::OtherLetter),
+    ('\u{FB00}', '\u{FB06}', GeneralCategory::LowercaseLetter),
+    ('\u{FB13}', '\u{FB17}', GeneralCategory::LowercaseLetter),
+    ('\u{FB1D}', '\u{FB1D}', GeneralCategory::OtherLetter),
+    ('\u{FB1E}', '\u{FB1E}', GeneralCategory::NonspacingMark),
+    ('\u{FB1F}', '\u{FB28}', GeneralCategory::OtherLetter),
+    ('\u{FB29}', '\u{FB29}', GeneralCategory::MathSymbol),
+    ('\u{FB2A}', '\u{FB36}', GeneralCategory::OtherLetter),
+    ('\u{FB38}', '\u{FB3C}', GeneralCategory::OtherLetter),
+    ('\u{FB3E}', '\u{FB3E}', GeneralCategory::OtherLetter),
+    ('\u{FB40}', '\u{FB41}', GeneralCategory::OtherLetter),
+    ('\u{FB43}', '\u{FB44}', GeneralCategory::OtherLetter),
+    ('\u{FB46}', '\u{FBB1}', GeneralCategory::OtherLetter),
+    ('\u{FBB2}', '\u{FBC2}', GeneralCategory::ModifierSymbol),
+    ('\u{FBD3}', '\u{FD3D}', GeneralCategory::OtherLetter),
+    ('\u{FD3E}', '\u{FD3E}', GeneralCategory::ClosePunctuation),
+    ('\u{FD3F}', '\u{FD3F}', GeneralCategory::OpenPunctuation),
+    ('\u{FD40}', '\u{FD4F}', GeneralCategory::OtherSymbol),
+    ('\u{FD50}', '\u{FD8F}', GeneralCategory::OtherLetter),
+    ('\u{FD92}', '\u{FDC7}', GeneralCategory::OtherLetter),
+    ('\u{FDCF}', '\u{FDCF}', GeneralCategory::OtherSymbol),
+    ('\u{FDF0}', '\u{FDFB}', GeneralCategory::OtherLetter),
+    ('\u{FDFC}', '\u{FDFC}', GeneralCategory::CurrencySymbol),
+    ('\u{FDFD}', '\u{FDFF}', GeneralCategory::OtherSymbol),
+    ('\u{FE00}', '\u{FE0F}', GeneralCategory::NonspacingMark),
+    ('\u{FE10}', '\u{FE16}', GeneralCategory::OtherPunctuation),
+    ('\u{FE17}', '\u{FE17}', GeneralCategory::OpenPunctuation),
+    ('\u{FE18}', '\u{FE18}', GeneralCategory::ClosePunctuation),
+    ('\u{FE19}', '\u{FE19}', GeneralCategory::OtherPunctuation),
+    ('\u{FE20}', '\u{FE2F}', GeneralCategory::NonspacingMark),
+    ('\u{FE30}', '\u{FE30}', GeneralCategory::OtherPunctuation),
+    ('\u{FE31}', '\u{FE32}', GeneralCategory::DashPunctuation),
+    ('\u{FE33}', '\u{FE34}', GeneralCategory::ConnectorPunctuation),
+    ('\u{FE35}', '\u{FE35}', GeneralCategory::OpenPunctuation),
+    ('\u{FE36}', '\u{FE36}', GeneralCategory::ClosePunctuation),
+    ('\u{FE37}', '\u{FE37}', GeneralCategory::OpenPunctuation),
+    ('\u{FE38}', '\u{FE38}', GeneralCategory::ClosePunctuation),
+    ('\u{FE39}', '\u{FE39}', GeneralCategory::OpenPunctuation),
+    ('\u{FE3A}', '\u{FE3A}', GeneralCategory::ClosePunctuation),
+    ('\u{FE3B}', '\u{FE3B}', GeneralCategory::OpenPunctuation),
+    ('\u{FE3C}', '\u{FE3C}', GeneralCategory::ClosePunctuation),
+    ('\u{FE3D}', '\u{FE3D}', GeneralCategory::OpenPunctuation),
+    ('\u{FE3E}', '\u{FE3E}', GeneralCategory::ClosePunctuation),
+    ('\u{FE3F}', '\u{FE3F}', GeneralCategory::OpenPunctuation),
+    ('\u{FE40}', '\u{FE40}', GeneralCategory::ClosePunctuation),
+    ('\u{FE41}', '\u{FE41}', GeneralCategory::OpenPunctuation),
+    ('\u{FE42}', '\u{FE42}', GeneralCategory::ClosePunctuation),
+    ('\u{FE43}', '\u{FE43}', GeneralCategory::OpenPunctuation),
+    ('\u{FE44}', '\u{FE44}', GeneralCategory::ClosePunctuation),
+    ('\u{FE45}', '\u{FE46}', GeneralCategory::OtherPunctuation),
+    ('\u{FE47}', '\u{FE47}', GeneralCategory::OpenPunctuation),
+    ('\u{FE48}', '\u{FE48}', GeneralCategory::ClosePunctuation),
+    ('\u{FE49}', '\u{FE4C}', GeneralCategory::OtherPunctuation),
+    ('\u{FE4D}', '\u{FE4F}', GeneralCategory::ConnectorPunctuation),
+    ('\u{FE50}', '\u{FE52}', GeneralCategory::OtherPunctuation),
+    ('\u{FE54}', '\u{FE57}', GeneralCategory::OtherPunctuation),
+    ('\u{FE58}', '\u{FE58}', GeneralCategory::DashPunctuation),
+    ('\u{FE59}', '\u{FE59}', GeneralCategory::OpenPunctuation),
+    ('\u{FE5A}', '\u{FE5A}', GeneralCategory::ClosePunctuation),
+    ('\u{FE5B}', '\u{FE5B}', GeneralCategory::OpenPunctuation),
+    ('\u{FE5C}', '\u{FE5C}', GeneralCategory::ClosePunctuation),
+    ('\u{FE5D}', '\u{FE5D}', GeneralCategory::OpenPunctuation),
+    ('\u{FE5E}', '\u{FE5E}', GeneralCategory::ClosePunctuation),
+    ('\u{FE5F}', '\u{FE61}', GeneralCategory::OtherPunctuation),
+    ('\u{FE62}', '\u{FE62}', GeneralCategory::MathSymbol),
+    ('\u{FE63}', '\u{FE63}', GeneralCategory::DashPunctuation),
+    ('\u{FE64}', '\u{FE66}', GeneralCategory::MathSymbol),
+    ('\u{FE68}', '\u{FE68}', GeneralCategory::OtherPunctuation),
+    ('\u{FE69}', '\u{FE69}', GeneralCategory::CurrencySymbol),
+    ('\u{FE6A}', '\u{FE6B}', GeneralCategory::OtherPunctuation),
+    ('\u{FE70}', '\u{FE74}', GeneralCategory::OtherLetter),
+    ('\u{FE76}', '\u{FEFC}', GeneralCategory::OtherLetter),
+    ('\u{FEFF}', '\u{FEFF}', GeneralCategory::Format),
+    ('\u{FF01}', '\u{FF03}', GeneralCategory::OtherPunctuation),
+    ('\u{FF04}', '\u{FF04}', GeneralCategory::CurrencySymbol),
+    ('\u{FF05}', '\u{FF07}', GeneralCategory::OtherPunctuation),
+    ('\u{FF08}', '\u{FF08}', GeneralCategory::OpenPunctuation),
+    ('\u{FF09}', '\u{FF09}', GeneralCategory::ClosePunctuation),
+    ('\u{FF0A}', '\u{FF0A}', GeneralCategory::OtherPunctuation),
+    ('\u{FF0B}', '\u{FF0B}', GeneralCategory::MathSymbol),
+    ('\u{FF0C}', '\u{FF0C}', GeneralCategory::OtherPunctuation),
+    ('\u{FF0D}', '\u{FF0D}', GeneralCategory::DashPunctuation),
+    ('\u{FF0E}', '\u{FF0F}', GeneralCategory::OtherPunctuation),
+    ('\u{FF10}', '\u{FF19}', GeneralCategory::DecimalNumber),
+    ('\u{FF1A}', '\u{FF1B}', GeneralCategory::OtherPunctuation),
+    ('\u{FF1C}', '\u{FF1E}', GeneralCategory::MathSymbol),
+    ('\u{FF1F}', '\u{FF20}', GeneralCategory::OtherPunctuation),
+    ('\u{FF21}', '\u{FF3A}', GeneralCategory::UppercaseLetter),
+    ('\u{FF3B}', '\u{FF3B}', GeneralCategory::OpenPunctuation),
+    ('\u{FF3C}', '\u{FF3C}', GeneralCategory::OtherPunctuation),
+    ('\u{FF3D}', '\u{FF3D}', GeneralCategory::ClosePunctuation),
+    ('\u{FF3E}', '\u{FF3E}', GeneralCategory::ModifierSymbol),
+    ('\u{FF3F}', '\u{FF3F}', GeneralCategory::ConnectorPunctuation),
+    ('\u{FF40}', '\u{FF40}', GeneralCategory::ModifierSymbol),
+    ('\u{FF41}', '\u{FF5A}', GeneralCategory::LowercaseLetter),
+    ('\u{FF5B}', '\u{FF5B}', GeneralCategory::OpenPunctuation),
+    ('\u{FF5C}', '\u{FF5C}', GeneralCategory::MathSymbol),
+    ('\u{FF5D}', '\u{FF5D}', GeneralCategory::ClosePunctuation),
+    ('\u{FF5E}', '\u{FF5E}', GeneralCategory::MathSymbol),
+    ('\u{FF5F}', '\u{FF5F}', GeneralCategory::OpenPunctuation),
+    ('\u{FF60}', '\u{FF60}', GeneralCategory::ClosePunctuation),
+    ('\u{FF61}', '\u{FF61}', GeneralCategory::OtherPunctuation),
+    ('\u{FF62}', '\u{FF62}', GeneralCategory::OpenPunctuation),
+    ('\u{FF63}', '\u{FF63}', GeneralCategory::ClosePunctuation),
+    ('\u{FF64}', '\u{FF65}', GeneralCategory::OtherPunctuation),
+    ('\u{FF66}', '\u{FF6F}', GeneralCategory::OtherLetter),
+    ('\u{FF70}', '\u{FF70}', GeneralCategory::ModifierLetter),
+    ('\u{FF71}', '\u{FF9D}', GeneralCategory::OtherLetter),
+    ('\u{FF9E}', '\u{FF9F}', GeneralCategory::ModifierLetter),
+    ('\u{FFA0}', '\u{FFBE}', GeneralCategory::OtherLetter),
+    ('\u{FFC2}', '\u{FFC7}', GeneralCategory::OtherLetter),
+    ('\u{FFCA}', '\u{FFCF}', GeneralCategory::OtherLetter),
+    ('\u{FFD2}', '\u{FFD7}', GeneralCategory::OtherLetter),
+    ('\u{FFDA}', '\u{FFDC}', GeneralCategory::OtherLetter),
+    ('\u{FFE0}', '\u{FFE1}', GeneralCategory::CurrencySymbol),
+    ('\u{FFE2}', '\u{FFE2}', GeneralCategory::MathSymbol),
+    ('\u{FFE3}', '\u{FFE3}', GeneralCategory::ModifierSymbol),
+    ('\u{FFE4}', '\u{FFE4}', GeneralCategory::OtherSymbol),
+    ('\u{FFE5}', '\u{FFE6}', GeneralCategory::CurrencySymbol),
+    ('\u{FFE8}', '\u{FFE8}', GeneralCategory::OtherSymbol),
+    ('\u{FFE9}', '\u{FFEC}', GeneralCategory::MathSymbol),
+    ('\u{FFED}', '\u{FFEE}', GeneralCategory::OtherSymbol),
+    ('\u{FFF9}', '\u{FFFB}', GeneralCategory::Format),
+    ('\u{FFFC}', '\u{FFFD}', GeneralCategory::OtherSymbol),
+    ('\u{10000}', '\u{1000B}', GeneralCategory::OtherLetter),
+    ('\u{1000D}', '\u{10026}', GeneralCategory::OtherLetter),
+    ('\u{10028}', '\u{1003A}', GeneralCategory::OtherLetter),
+    ('\u{1003C}', '\u{1003D}', GeneralCategory::OtherLetter),
+    ('\u{1003F}', '\u{1004D}', GeneralCategory::OtherLetter),
+    ('\u{10050}', '\u{1005D}', GeneralCategory::OtherLetter),
+    ('\u{10080}', '\u{100FA}', GeneralCategory::OtherLetter),
+    ('\u{10100}', '\u{10102}', GeneralCategory::OtherPunctuation),
+    ('\u{10107}', '\u{10133}', GeneralCategory::OtherNumber),
+    ('\u{10137}', '\u{1013F}', GeneralCategory::OtherSymbol),
+    ('\u{10140}', '\u{10174}', GeneralCategory::LetterNumber),
+    ('\u{10175}', '\u{10178}', GeneralCategory::OtherNumber),
+    ('\u{10179}', '\u{10189}', GeneralCategory::OtherSymbol),
+    ('\u{1018A}', '\u{1018B}', GeneralCategory::OtherNumber),
+    ('\u{1018C}', '\u{1018E}', GeneralCategory::OtherSymbol),
+    ('\u{10190}', '\u{1019C}', GeneralCategory::OtherSymbol),
+    ('\u{101A0}', '\u{101A0}', GeneralCategory::OtherSymbol),
+    ('\u{101D0}', '\u{101FC}', GeneralCategory::OtherSymbol),
+    ('\u{101FD}', '\u{101FD}', GeneralCategory::NonspacingMark),
+    ('\u{10280}', '\u{1029C}', GeneralCategory::OtherLetter),
+    ('\u{102A0}', '\u{102D0}', GeneralCategory::OtherLetter),
+    ('\u{102E0}', '\u{102E0}', GeneralCategory::NonspacingMark),
+    ('\u{102E1}', '\u{102FB}', GeneralCategory::OtherNumber),
+    ('\u{10300}', '\u{1031F}', GeneralCategory::OtherLetter),
+    ('\u{10320}', '\u{10323}', GeneralCategory::OtherNumber),
+    ('\u{1032D}', '\u{10340}', GeneralCategory::OtherLetter),
+    ('\u{10341}', '\u{10341}', GeneralCategory::LetterNumber),
+    ('\u{10342}', '\u{10349}', GeneralCategory::OtherLetter),
+    ('\u{1034A}', '\u{1034A}', GeneralCategory::LetterNumber),
+    ('\u{10350}', '\u{10375}', GeneralCategory::OtherLetter),
+    ('\u{10376}', '\u{1037A}', GeneralCategory::NonspacingMark),
+    ('\u{10380}', '\u{1039D}', GeneralCategory::OtherLetter),
+    ('\u{1039F}', '\u{1039F}', GeneralCategory::OtherPunctuation),
+    ('\u{103A0}', '\u{103C3}', GeneralCategory::OtherLetter),
+    ('\u{103C8}', '\u{103CF}', GeneralCategory::OtherLetter),
+    ('\u{103D0}', '\u{103D0}', GeneralCategory::OtherPunctuation),
+    ('\u{103D1}', '\u{103D5}', GeneralCategory::LetterNumber),
+    ('\u{10400}', '\u{10427}', GeneralCategory::UppercaseLetter),
+    ('\u{10428}', '\u{1044F}', GeneralCategory::LowercaseLetter),
+    ('\u{10450}', '\u{1049D}', GeneralCategory::OtherLetter),
+    ('\u{104A0}', '\u{104A9}', GeneralCategory::DecimalNumber),
+    ('\u{104B0}', '\u{104D3}', GeneralCategory::UppercaseLetter),
+    ('\u{104D8}', '\u{104FB}', GeneralCategory::LowercaseLetter),
+    ('\u{10500}', '\u{10527}', GeneralCategory::OtherLetter),
+    ('\u{10530}', '\u{10563}', GeneralCategory::OtherLetter),
+    ('\u{1056F}', '\u{1056F}', GeneralCategory::OtherPunctuation),
+    ('\u{10570}', '\u{1057A}', GeneralCategory::UppercaseLetter),
+    ('\u{1057C}', '\u{1058A}', GeneralCategory::UppercaseLetter),
+    ('\u{1058C}', '\u{10592}', GeneralCategory::UppercaseLetter),
+    ('\u{10594}', '\u{10595}', GeneralCategory::UppercaseLetter),
+    ('\u{10597}', '\u{105A1}', GeneralCategory::LowercaseLetter),
+    ('\u{105A3}', '\u{105B1}', GeneralCategory::LowercaseLetter),
+    ('\u{105B3}', '\u{105B9}', GeneralCategory::LowercaseLetter),
+    ('\u{105BB}', '\u{105BC}', GeneralCategory::LowercaseLetter),
+    ('\u{10600}', '\u{10736}', GeneralCategory::OtherLetter),
+    ('\u{10740}', '\u{10755}', GeneralCategory::OtherLetter),
+    ('\u{10760}', '\u{10767}', GeneralCategory::OtherLetter),
+    ('\u{10780}', '\u{10785}', GeneralCategory::ModifierLetter),
+    ('\u{10787}', '\u{107B0}', GeneralCategory::ModifierLetter),
+    ('\u{107B2}', '\u{107BA}', GeneralCategory::ModifierLetter),
+    ('\u{10800}', '\u{10805}', GeneralCategory::OtherLetter),
+    ('\u{10808}', '\u{10808}', GeneralCategory::OtherLetter),
+    ('\u{1080A}', '\u{10835}', GeneralCategory::OtherLetter),
+    ('\u{10837}', '\u{10838}', GeneralCategory::OtherLetter),
+    ('\u{1083C}', '\u{1083C}', GeneralCategory::OtherLetter),
+    ('\u{1083F}', '\u{10855}', GeneralCategory::OtherLetter),
+    ('\u{10857}', '\u{10857}', GeneralCategory::OtherPunctuation),
+    ('\u{10858}', '\u{1085F}', GeneralCategory::OtherNumber),
+    ('\u{10860}', '\u{10876}', GeneralCategory::OtherLetter),
+    ('\u{10877}', '\u{10878}', GeneralCategory::OtherSymbol),
+    ('\u{10879}', '\u{1087F}', GeneralCategory::OtherNumber),
+    ('\u{10880}', '\u{1089E}', GeneralCategory::OtherLetter),
+    ('\u{108A7}', '\u{108AF}', GeneralCategory::OtherNumber),
+    ('\u{108E0}', '\u{108F2}', GeneralCategory::OtherLetter),
+    ('\u{108F4}', '\u{108F5}', GeneralCategory::OtherLetter),
+    ('\u{108FB}', '\u{108FF}', GeneralCategory::OtherNumber),
+    ('\u{10900}', '\u{10915}', GeneralCategory::OtherLetter),
+    ('\u{10916}', '\u{1091B}', GeneralCategory::OtherNumber),
+    ('\u{1091F}', '\u{1091F}', GeneralCategory::OtherPunctuation),
+    ('\u{10920}', '\u{10939}', GeneralCategory::OtherLetter),
+    ('\u{1093F}', '\u{1093F}', GeneralCategory::OtherPunctuation),
+    ('\u{10980}', '\u{109B7}', GeneralCategory::OtherLetter),
+    ('\u{109BC}', '\u{109BD}', GeneralCategory::OtherNumber),
+    ('\u{109BE}', '\u{109BF}', GeneralCategory::OtherLetter),
+    ('\u{109C0}', '\u{109CF}', GeneralCategory::OtherNumber),
+    ('\u{109D2}', '\u{109FF}', GeneralCategory::OtherNumber),
+    ('\u{10A00}', '\u{10A00}', GeneralCategory::OtherLetter),
+    ('\u{10A01}', '\u{10A03}', GeneralCategory::NonspacingMark),
+    ('\u{10A05}', '\u{10A06}', GeneralCategory::NonspacingMark),
+    ('\u{10A0C}', '\u{10A0F}', GeneralCategory::NonspacingMark),
+    ('\u{10A10}', '\u{10A13}', GeneralCategory::OtherLetter),
+    ('\u{10A15}', '\u{10A17}', GeneralCategory::OtherLetter),
+    ('\u{10A19}', '\u{10A35}', GeneralCategory::OtherLetter),
+    ('\u{10A38}', '\u{10A3A}', GeneralCategory::NonspacingMark),
+    ('\u{10A3F}', '\u{10A3F}', GeneralCategory::NonspacingMark),
+    ('\u{10A40}', '\u{10A48}', GeneralCategory::OtherNumber),
+    ('\u{10A50}', '\u{10A58}', GeneralCategory::OtherPunctuation),
+    ('\u{10A60}', '\u{10A7C}', GeneralCategory::OtherLetter),
+    ('\u{10A7D}', '\u{10A7E}', GeneralCategory::OtherNumber),
+    ('\u{10A7F}', '\u{10A7F}', GeneralCategory::OtherPunctuation),
+    ('\u{10A80}', '\u{10A9C}', GeneralCategory::OtherLetter),
+    ('\u{10A9D}', '\u{10A9F}', GeneralCategory::OtherNumber),
+    ('\u{10AC0}', '\u{10AC7}', GeneralCategory::OtherLetter),
+    ('\u{10AC8}', '\u{10AC8}', GeneralCategory::OtherSymbol),
+    ('\u{10AC9}', '\u{10AE4}', GeneralCategory::OtherLetter),
+    ('\u{10AE5}', '\u{10AE6}', GeneralCategory::NonspacingMark),
+    ('\u{10AEB}', '\u{10AEF}', GeneralCategory::OtherNumber),
+    ('\u{10AF0}', '\u{10AF6}', GeneralCategory::OtherPunctuation),
+    ('\u{10B00}', '\u{10B35}', GeneralCategory::OtherLetter),
+    ('\u{10B39}', '\u{10B3F}', GeneralCategory::OtherPunctuation),
+    ('\u{10B40}', '\u{10B55}', GeneralCategory::OtherLetter),
+    ('\u{10B58}', '\u{10B5F}', GeneralCategory::OtherNumber),
+    ('\u{10B60}', '\u{10B72}', GeneralCategory::OtherLetter),
+    ('\u{10B78}', '\u{10B7F}', GeneralCategory::OtherNumber),
+    ('\u{10B80}', '\u{10B91}', GeneralCategory::OtherLetter),
+    ('\u{10B99}', '\u{10B9C}', GeneralCategory::OtherPunctuation),
+    ('\u{10BA9}', '\u{10BAF}', GeneralCategory::OtherNumber),
+    ('\u{10C00}', '\u{10C48}', GeneralCategory::OtherLetter),
+    ('\u{10C80}', '\u{10CB2}', GeneralCategory::UppercaseLetter),
+    ('\u{10CC0}', '\u{10CF2}', GeneralCategory::LowercaseLetter),
+    ('\u{10CFA}', '\u{10CFF}', GeneralCategory::OtherNumber),
+    ('\u{10D00}', '\u{10D23}', GeneralCategory::OtherLetter),
+    ('\u{10D24}', '\u{10D27}', GeneralCategory::NonspacingMark),
+    ('\u{10D30}', '\u{10D39}', GeneralCategory::DecimalNumber),
+    ('\u{10E60}', '\u{10E7E}', GeneralCategory::OtherNumber),
+    ('\u{10E80}', '\u{10EA9}', GeneralCategory::OtherLetter),
+    ('\u{10EAB}', '\u{10EAC}', GeneralCategory::NonspacingMark),
+    ('\u{10EAD}', '\u{10EAD}', GeneralCategory::DashPunctuation),
+    ('\u{10EB0}', '\u{10EB1}', GeneralCategory::OtherLetter),
+    ('\u{10F00}', '\u{10F1C}', GeneralCategory::OtherLetter),
+    ('\u{10F1D}', '\u{10F26}', GeneralCategory::OtherNumber),
+    ('\u{10F27}', '\u{10F27}', GeneralCategory::OtherLetter),
+    ('\u{10F30}', '\u{10F45}', GeneralCategory::OtherLetter),
+    ('\u{10F46}', '\u{10F50}', GeneralCategory::NonspacingMark),
+    ('\u{10F51}', '\u{10F54}', GeneralCategory::OtherNumber),
+    ('\u{10F55}', '\u{10F59}', GeneralCategory::OtherPunctuation),
+    ('\u{10F70}', '\u{10F81}', GeneralCategory::OtherLetter),
+    ('\u{10F82}', '\u{10F85}', GeneralCategory::NonspacingMark),
+    ('\u{10F86}', '\u{10F89}', GeneralCategory::OtherPunctuation),
+    ('\u{10FB0}', '\u{10FC4}', GeneralCategory::OtherLetter),
+    ('\u{10FC5}', '\u{10FCB}', GeneralCategory::OtherNumber),
+    ('\u{10FE0}', '\u{10FF6}', GeneralCategory::OtherLetter),
+    ('\u{11000}', '\u{11000}', GeneralCategory::SpacingMark),
+    ('\u{11001}', '\u{11001}', GeneralCategory::NonspacingMark),
+    ('\u{11002}', '\u{11002}', GeneralCategory::SpacingMark),
+    ('\u{11003}', '\u{11037}', GeneralCategory::OtherLetter),
+    ('\u{11038}', '\u{11046}', GeneralCategory::NonspacingMark),
+    ('\u{11047}', '\u{1104D}', GeneralCategory::OtherPunctuation),
+    ('\u{11052}', '\u{11065}', GeneralCategory::OtherNumber),
+    ('\u{11066}', '\u{1106F}', GeneralCategory::DecimalNumber),
+    ('\u{11070}', '\u{11070}', GeneralCategory::NonspacingMark),
+    ('\u{11071}', '\u{11072}', GeneralCategory::OtherLetter),
+    ('\u{11073}', '\u{11074}', GeneralCategory::NonspacingMark),
+    ('\u{11075}', '\u{11075}', GeneralCategory::OtherLetter),
+    ('\u{1107F}', '\u{11081}', GeneralCategory::NonspacingMark),
+    ('\u{11082}', '\u{11082}', GeneralCategory::SpacingMark),
+    ('\u{11083}', '\u{110AF}', GeneralCategory::OtherLetter),
+    ('\u{110B0}', '\u{110B2}', GeneralCategory::SpacingMark),
+    ('\u{110B3}', '\u{110B6}', GeneralCategory::NonspacingMark),
+    ('\u{110B7}', '\u{110B8}', GeneralCategory::SpacingMark),
+    ('\u{110B9}', '\u{110BA}', GeneralCategory::NonspacingMark),
+    ('\u{110BB}', '\u{110BC}', GeneralCategory::OtherPunctuation),
+    ('\u{110BD}', '\u{110BD}', GeneralCategory::Format),
+    ('\u{110BE}', '\u{110C1}', GeneralCategory::OtherPunctuation),
+    ('\u{110C2}', '\u{110C2}', GeneralCategory::NonspacingMark),
+    ('\u{110CD}', '\u{110CD}', GeneralCategory::Format),
+    ('\u{110D0}', '\u{110E8}', GeneralCategory::OtherLetter),
+    ('\u{110F0}', '\u{110F9}', GeneralCategory::DecimalNumber),
+    ('\u{11100}', '\u{11102}', GeneralCategory::NonspacingMark),
+    ('\u{11103}', '\u{11126}', GeneralCategory::OtherLetter),
+    ('\u{11127}', '\u{1112B}', GeneralCategory::NonspacingMark),
+    ('\u{1112C}', '\u{1112C}', GeneralCategory::SpacingMark),
+    ('\u{1112D}', '\u{11134}', GeneralCategory::NonspacingMark),
+    ('\u{11136}', '\u{1113F}', GeneralCategory::DecimalNumber),
+    ('\u{11140}', '\u{11143}', GeneralCategory::OtherPunctuation),
+    ('\u{11144}', '\u{11144}', GeneralCategory::OtherLetter),
+    ('\u{11145}', '\u{11146}', GeneralCategory::SpacingMark),
+    ('\u{11147}', '\u{11147}', GeneralCategory::OtherLetter),
+    ('\u{11150}', '\u{11172}', GeneralCategory::OtherLetter),
+    ('\u{11173}', '\u{11173}', GeneralCategory::NonspacingMark),
+    ('\u{11174}', '\u{11175}', GeneralCategory::OtherPunctuation),
+    ('\u{11176}', '\u{11176}', GeneralCategory::OtherLetter),
+    ('\u{11180}', '\u{11181}', GeneralCategory::NonspacingMark),
+    ('\u{11182}', '\u{11182}', GeneralCategory::SpacingMark),
+    ('\u{11183}', '\u{111B2}', GeneralCategory::OtherLetter),
+    ('\u{111B3}', '\u{111B5}', GeneralCategory::SpacingMark),
+    ('\u{111B6}', '\u{111BE}', GeneralCategory::NonspacingMark),
+    ('\u{111BF}', '\u{111C0}', GeneralCategory::SpacingMark),
+    ('\u{111C1}', '\u{111C4}', GeneralCategory::OtherLetter),
+    ('\u{111C5}', '\u{111C8}', GeneralCategory::OtherPunctuation),
+    ('\u{111C9}', '\u{111CC}', GeneralCategory::NonspacingMark),
+    ('\u{111CD}', '\u{111CD}', GeneralCategory::OtherPunctuation),
+    ('\u{111CE}', '\u{111CE}', GeneralCategory::SpacingMark),
+    ('\u{111CF}', '\u{111CF}', GeneralCategory::NonspacingMark),
+    ('\u{111D0}', '\u{111D9}', GeneralCategory::DecimalNumber),
+    ('\u{111DA}', '\u{111DA}', GeneralCategory::OtherLetter),
+    ('\u{111DB}', '\u{111DB}', GeneralCategory::OtherPunctuation),
+    ('\u{111DC}', '\u{111DC}', GeneralCategory::OtherLetter),
+    ('\u{111DD}', '\u{111DF}', GeneralCategory::OtherPunctuation),
+    ('\u{111E1}', '\u{111F4}', GeneralCategory::OtherNumber),
+    ('\u{11200}', '\u{11211}', GeneralCategory::OtherLetter),
+    ('\u{11213}', '\u{1122B}', GeneralCategory::OtherLetter),
+    ('\u{1122C}', '\u{1122E}', GeneralCategory::SpacingMark),
+    ('\u{1122F}', '\u{11231}', GeneralCategory::NonspacingMark),
+    ('\u{11232}', '\u{11233}', GeneralCategory::SpacingMark),
+    ('\u{11234}', '\u{11234}', GeneralCategory::NonspacingMark),
+    ('\u{11235}', '\u{11235}', GeneralCategory::SpacingMark),
+    ('\u{11236}', '\u{11237}', GeneralCategory::NonspacingMark),
+    ('\u{11238}', '\u{1123D}', GeneralCategory::OtherPunctuation),
+    ('\u{1123E}', '\u{1123E}', GeneralCategory::NonspacingMark),
+    ('\u{11280}', '\u{11286}', GeneralCategory::OtherLetter),
+    ('\u{11288}', '\u{11288}', GeneralCategory::OtherLetter),
+    ('\u{1128A}', '\u{1128D}', GeneralCategory::OtherLetter),
+    ('\u{1128F}', '\u{1129D}', GeneralCategory::OtherLetter),
+    ('\u{1129F}', '\u{112A8}', GeneralCategory::OtherLetter),
+    ('\u{112A9}', '\u{112A9}', GeneralCategory::OtherPunctuation),
+    ('\u{112B0}', '\u{112DE}', GeneralCategory::OtherLetter),
+    ('\u{112DF}', '\u{112DF}', GeneralCategory::NonspacingMark),
+    ('\u{112E0}', '\u{112E2}', GeneralCategory::SpacingMark),
+    ('\u{112E3}', '\u{112EA}', GeneralCategory::NonspacingMark),
+    ('\u{112F0}', '\u{112F9}', GeneralCategory::DecimalNumber),
+    ('\u{11300}', '\u{11301}', GeneralCategory::NonspacingMark),
+    ('\u{11302}', '\u{11303}', GeneralCategory::SpacingMark),
+    ('\u{11305}', '\u{1130C}', GeneralCategory::OtherLetter),
+    ('\u{1130F}', '\u{11310}', GeneralCategory::OtherLetter),
+    ('\u{11313}', '\u{11328}', GeneralCategory::OtherLetter),
+    ('\u{1132A}', '\u{11330}', GeneralCategory::OtherLetter),
+    ('\u{11332}', '\u{11333}', GeneralCategory::OtherLetter),
+    ('\u{11335}', '\u{11339}', GeneralCategory::OtherLetter),
+    ('\u{1133B}', '\u{1133C}', GeneralCategory::NonspacingMark),
+    ('\u{1133D}', '\u{1133D}', GeneralCategory::OtherLetter),
+    ('\u{1133E}', '\u{1133F}', GeneralCategory::SpacingMark),
+    ('\u{11340}', '\u{11340}', GeneralCategory::NonspacingMark),
+    ('\u{11341}', '\u{11344}', GeneralCategory::SpacingMark),
+    ('\u{11347}', '\u{11348}', GeneralCategory::SpacingMark),
+    ('\u{1134B}', '\u{1134D}', GeneralCategory::SpacingMark),
+    ('\u{11350}', '\u{11350}', GeneralCategory::OtherLetter),
+    ('\u{11357}', '\u{11357}', GeneralCategory::SpacingMark),
+    ('\u{1135D}', '\u{11361}', GeneralCategory::OtherLetter),
+    ('\u{11362}', '\u{11363}', GeneralCategory::SpacingMark),
+    ('\u{11366}', '\u{1136C}', GeneralCategory::NonspacingMark),
+    ('\u{11370}', '\u{11374}', GeneralCategory::NonspacingMark),
+    ('\u{11400}', '\u{11434}', GeneralCategory::OtherLetter),
+    ('\u{11435}', '\u{11437}', GeneralCategory::SpacingMark),
+    ('\u{11438}', '\u{1143F}', GeneralCategory::NonspacingMark),
+    ('\u{11440}', '\u{11441}', GeneralCategory::SpacingMark),
+    ('\u{11442}', '\u{11444}', GeneralCategory::NonspacingMark),
+    ('\u{11445}', '\u{11445}', GeneralCategory::SpacingMark),
+    ('\u{11446}', '\u{11446}', GeneralCategory::NonspacingMark),
+    ('\u{11447}', '\u{1144A}', GeneralCategory::OtherLetter),
+    ('\u{1144B}', '\u{1144F}', GeneralCategory::OtherPunctuation),
+    ('\u{11450}', '\u{11459}', GeneralCategory::DecimalNumber),
+    ('\u{1145A}', '\u{1145B}', GeneralCategory::OtherPunctuation),
+    ('\u{1145D}', '\u{1145D}', GeneralCategory::OtherPunctuation),
+    ('\u{1145E}', '\u{1145E}', GeneralCategory::NonspacingMark),
+    ('\u{1145F}', '\u{11461}', GeneralCategory::OtherLetter),
+    ('\u{11480}', '\u{114AF}', GeneralCategory::OtherLetter),
+    ('\u{114B0}', '\u{114B2}', GeneralCategory::SpacingMark),
+    ('\u{114B3}', '\u{114B8}', GeneralCategory::NonspacingMark),
+    ('\u{114B9}', '\u{114B9}', GeneralCategory::SpacingMark),
+    ('\u{114BA}', '\u{114BA}', GeneralCategory::NonspacingMark),
+    ('\u{114BB}', '\u{114BE}', GeneralCategory::SpacingMark),
+    ('\u{114BF}', '\u{114C0}', GeneralCategory::NonspacingMark),
+    ('\u{114C1}', '\u{114C1}', GeneralCategory::SpacingMark),
+    ('\u{114C2}', '\u{114C3}', GeneralCategory::NonspacingMark),
+    ('\u{114C4}', '\u{114C5}', GeneralCategory::OtherLetter),
+    ('\u{114C6}', '\u{114C6}', GeneralCategory::OtherPunctuation),
+    ('\u{114C7}', '\u{114C7}', GeneralCategory::OtherLetter),
+    ('\u{114D0}', '\u{114D9}', GeneralCategory::DecimalNumber),
+    ('\u{11580}', '\u{115AE}', GeneralCategory::OtherLetter),
+    ('\u{115AF}', '\u{115B1}', GeneralCategory::SpacingMark),
+    ('\u{115B2}', '\u{115B5}', GeneralCategory::NonspacingMark),
+    ('\u{115B8}', '\u{115BB}', GeneralCategory::SpacingMark),
+    ('\u{115BC}', '\u{115BD}', GeneralCategory::NonspacingMark),
+    ('\u{115BE}', '\u{115BE}', GeneralCategory::SpacingMark),
+    ('\u{115BF}', '\u{115C0}', GeneralCategory::NonspacingMark),
+    ('\u{115C1}', '\u{115D7}', GeneralCategory::OtherPunctuation),
+    ('\u{115D8}', '\u{115DB}', GeneralCategory::OtherLetter),
+    ('\u{115DC}', '\u{115DD}', GeneralCategory::NonspacingMark),
+    ('\u{11600}', '\u{1162F}', GeneralCategory::OtherLetter),
+    ('\u{11630}', '\u{11632}', GeneralCategory::SpacingMark),
+    ('\u{11633}', '\u{1163A}', GeneralCategory::NonspacingMark),
+    ('\u{1163B}', '\u{1163C}', GeneralCategory::SpacingMark),
+    ('\u{1163D}', '\u{1163D}', GeneralCategory::NonspacingMark),
+    ('\u{1163E}', '\u{1163E}', GeneralCategory::SpacingMark),
+    ('\u{1163F}', '\u{11640}', GeneralCategory::NonspacingMark),
+    ('\u{11641}', '\u{11643}', GeneralCategory::OtherPunctuation),
+    ('\u{11644}', '\u{11644}', GeneralCategory::OtherLetter),
+    ('\u{11650}', '\u{11659}', GeneralCategory::DecimalNumber),
+    ('\u{11660}', '\u{1166C}', GeneralCategory::OtherPunctuation),
+    ('\u{11680}', '\u{116AA}', GeneralCategory::OtherLetter),
+    ('\u{116AB}', '\u{116AB}', GeneralCategory::NonspacingMark),
+    ('\u{116AC}', '\u{116AC}', GeneralCategory::SpacingMark),
+    ('\u{116AD}', '\u{116AD}', GeneralCategory::NonspacingMark),
+    ('\u{116AE}', '\u{116AF}', GeneralCategory::SpacingMark),
+    ('\u{116B0}', '\u{116B5}', GeneralCategory::NonspacingMark),
+    ('\u{116B6}', '\u{116B6}', GeneralCategory::SpacingMark),
+    ('\u{116B7}', '\u{116B7}', GeneralCategory::NonspacingMark),
+    ('\u{116B8}', '\u{116B8}', GeneralCategory::OtherLetter),
+    ('\u{116B9}', '\u{116B9}', GeneralCategory::OtherPunctuation),
+    ('\u{116C0}', '\u{116C9}', GeneralCategory::DecimalNumber),
+    ('\u{11700}', '\u{1171A}', GeneralCategory::OtherLetter),
+    ('\u{1171D}', '\u{1171F}', GeneralCategory::NonspacingMark),
+    ('\u{11720}', '\u{11721}', GeneralCategory::SpacingMark),
+    ('\u{11722}', '\u{11725}', GeneralCategory::NonspacingMark),
+    ('\u{11726}', '\u{11726}', GeneralCategory::SpacingMark),
+    ('\u{11727}', '\u{1172B}', GeneralCategory::NonspacingMark),
+    ('\u{11730}', '\u{11739}', GeneralCategory::DecimalNumber),
+    ('\u{1173A}', '\u{1173B}', GeneralCategory::OtherNumber),
+    ('\u{1173C}', '\u{1173E}', GeneralCategory::OtherPunctuation),
+    ('\u{1173F}', '\u{1173F}', GeneralCategory::OtherSymbol),
+    ('\u{11740}', '\u{11746}', GeneralCategory::OtherLetter),
+    ('\u{11800}', '\u{1182B}', GeneralCategory::OtherLetter),
+    ('\u{1182C}', '\u{1182E}', GeneralCategory::SpacingMark),
+    ('\u{1182F}', '\u{11837}', GeneralCategory::NonspacingMark),
+    ('\u{11838}', '\u{11838}', GeneralCategory::SpacingMark),
+    ('\u{11839}', '\u{1183A}', GeneralCategory::NonspacingMark),
+    ('\u{1183B}', '\u{1183B}', GeneralCategory::OtherPunctuation),
+    ('\u{118A0}', '\u{118BF}', GeneralCategory::UppercaseLetter),
+    ('\u{118C0}', '\u{118DF}', GeneralCategory::LowercaseLetter),
+    ('\u{118E0}', '\u{118E9}', GeneralCategory::DecimalNumber),
+    ('\u{118EA}', '\u{118F2}', GeneralCategory::OtherNumber),
+    ('\u{118FF}', '\u{11906}', GeneralCategory::OtherLetter),
+    ('\u{11909}', '\u{11909}', GeneralCategory::OtherLetter),
+    ('\u{1190C}', '\u{11913}', GeneralCategory::OtherLetter),
+    ('\u{11915}', '\u{11916}', GeneralCategory::OtherLetter),
+    ('\u{11918}', '\u{1192F}', GeneralCategory::OtherLetter),
+    ('\u{11930}', '\u{11935}', GeneralCategory::SpacingMark),
+    ('\u{11937}', '\u{11938}', GeneralCategory::SpacingMark),
+    ('\u{1193B}', '\u{1193C}', GeneralCategory::NonspacingMark),
+    ('\u{1193D}', '\u{1193D}', GeneralCategory::SpacingMark),
+    ('\u{1193E}', '\u{1193E}', GeneralCategory::NonspacingMark),
+    ('\u{1193F}', '\u{1193F}', GeneralCategory::OtherLetter),
+    ('\u{11940}', '\u{11940}', GeneralCategory::SpacingMark),
+    ('\u{11941}', '\u{11941}', GeneralCategory::OtherLetter),
+    ('\u{11942}', '\u{11942}', GeneralCategory::SpacingMark),
+    ('\u{11943}', '\u{11943}', GeneralCategory::NonspacingMark),
+    ('\u{11944}', '\u{11946}', GeneralCategory::OtherPunctuation),
+    ('\u{11950}', '\u{11959}', GeneralCategory::DecimalNumber),
+    ('\u{119A0}', '\u{119A7}', GeneralCategory::OtherLetter),
+    ('\u{119AA}', '\u{119D0}', GeneralCategory::OtherLetter),
+    ('\u{119D1}', '\u{119D3}', GeneralCategory::SpacingMark),
+    ('\u{119D4}', '\u{119D7}', GeneralCategory::NonspacingMark),
+    ('\u{119DA}', '\u{119DB}', GeneralCategory::NonspacingMark),
+    ('\u{119DC}', '\u{119DF}', GeneralCategory::SpacingMark),
+    ('\u{119E0}', '\u{119E0}', GeneralCategory::NonspacingMark),
+    ('\u{119E1}', '\u{119E1}', GeneralCategory::OtherLetter),
+    ('\u{119E2}', '\u{119E2}', GeneralCategory::OtherPunctuation),
+    ('\u{119E3}', '\u{119E3}', GeneralCategory::OtherLetter),
+    ('\u{119E4}', '\u{119E4}', GeneralCategory::SpacingMark),
+    ('\u{11A00}', '\u{11A00}', GeneralCategory::OtherLetter),
+    ('\u{11A01}', '\u{11A0A}', GeneralCategory::NonspacingMark),
+    ('\u{11A0B}', '\u{11A32}', GeneralCategory::OtherLetter),
+    ('\u{11A33}', '\u{11A38}', GeneralCategory::NonspacingMark),
+    ('\u{11A39}', '\u{11A39}', GeneralCategory::SpacingMark),
+    ('\u{11A3A}', '\u{11A3A}', GeneralCategory::OtherLetter),
+    ('\u{11A3B}', '\u{11A3E}', GeneralCategory::NonspacingMark),
+    ('\u{11A3F}', '\u{11A46}', GeneralCategory::OtherPunctuation),
+    ('\u{11A47}', '\u{11A47}', GeneralCategory::NonspacingMark),
+    ('\u{11A50}', '\u{11A50}', GeneralCategory::OtherLetter),
+    ('\u{11A51}', '\u{11A56}', GeneralCategory::NonspacingMark),
+    ('\u{11A57}', '\u{11A58}', GeneralCategory::SpacingMark),
+    ('\u{11A59}', '\u{11A5B}', GeneralCategory::NonspacingMark),
+    ('\u{11A5C}', '\u{11A89}', GeneralCategory::OtherLetter),
+    ('\u{11A8A}', '\u{11A96}', GeneralCategory::NonspacingMark),
+    ('\u{11A97}', '\u{11A97}', GeneralCategory::SpacingMark),
+    ('\u{11A98}', '\u{11A99}', GeneralCategory::NonspacingMark),
+    ('\u{11A9A}', '\u{11A9C}', GeneralCategory::OtherPunctuation),
+    ('\u{11A9D}', '\u{11A9D}', GeneralCategory::OtherLetter),
+    ('\u{11A9E}', '\u{11AA2}', GeneralCategory::OtherPunctuation),
+    ('\u{11AB0}', '\u{11AF8}', GeneralCategory::OtherLetter),
+    ('\u{11C00}', '\u{11C08}', GeneralCategory::OtherLetter),
+    ('\u{11C0A}', '\u{11C2E}', GeneralCategory::OtherLetter),
+    ('\u{11C2F}', '\u{11C2F}', GeneralCategory::SpacingMark),
+    ('\u{11C30}', '\u{11C36}', GeneralCategory::NonspacingMark),
+    ('\u{11C38}', '\u{11C3D}', GeneralCategory::NonspacingMark),
+    ('\u{11C3E}', '\u{11C3E}', GeneralCategory::SpacingMark),
+    ('\u{11C3F}', '\u{11C3F}', GeneralCategory::NonspacingMark),
+    ('\u{11C40}', '\u{11C40}', GeneralCategory::OtherLetter),
+    ('\u{11C41}', '\u{11C45}', GeneralCategory::OtherPunctuation),
+    ('\u{11C50}', '\u{11C59}', GeneralCategory::DecimalNumber),
+    ('\u{11C5A}', '\u{11C6C}', GeneralCategory::OtherNumber),
+    ('\u{11C70}', '\u{11C71}', GeneralCategory::OtherPunctuation),
+    ('\u{11C72}', '\u{11C8F}', GeneralCategory::OtherLetter),
+    ('\u{11C92}', '\u{11CA7}', GeneralCategory::NonspacingMark),
+    ('\u{11CA9}', '\u{11CA9}', GeneralCategory::SpacingMark),
+    ('\u{11CAA}', '\u{11CB0}', GeneralCategory::NonspacingMark),
+    ('\u{11CB1}', '\u{11CB1}', GeneralCategory::SpacingMark),
+    ('\u{11CB2}', '\u{11CB3}', GeneralCategory::NonspacingMark),
+    ('\u{11CB4}', '\u{11CB4}', GeneralCategory::SpacingMark),
+    ('\u{11CB5}', '\u{11CB6}', GeneralCategory::NonspacingMark),
+    ('\u{11D00}', '\u{11D06}', GeneralCategory::OtherLetter),
+    ('\u{11D08}', '\u{11D09}', GeneralCategory::OtherLetter),
+    ('\u{11D0B}', '\u{11D30}', GeneralCategory::OtherLetter),
+    ('\u{11D31}', '\u{11D36}', GeneralCategory::NonspacingMark),
+    ('\u{11D3A}', '\u{11D3A}', GeneralCategory::NonspacingMark),
+    ('\u{11D3C}', '\u{11D3D}', GeneralCategory::NonspacingMark),
+    ('\u{11D3F}', '\u{11D45}', GeneralCategory::NonspacingMark),
+    ('\u{11D46}', '\u{11D46}', GeneralCategory::OtherLetter),
+    ('\u{11D47}', '\u{11D47}', GeneralCategory::NonspacingMark),
+    ('\u{11D50}', '\u{11D59}', GeneralCategory::DecimalNumber),
+    ('\u{11D60}', '\u{11D65}', GeneralCategory::OtherLetter),
+    ('\u{11D67}', '\u{11D68}', GeneralCategory::OtherLetter),
+    ('\u{11D6A}', '\u{11D89}', GeneralCategory::OtherLetter),
+    ('\u{11D8A}', '\u{11D8E}', GeneralCategory::SpacingMark),
+    ('\u{11D90}', '\u{11D91}', GeneralCategory::NonspacingMark),
+    ('\u{11D93}', '\u{11D94}', GeneralCategory::SpacingMark),
+    ('\u{11D95}', '\u{11D95}', GeneralCategory::NonspacingMark),
+    ('\u{11D96}', '\u{11D96}', GeneralCategory::SpacingMark),
+    ('\u{11D97}', '\u{11D97}', GeneralCategory::NonspacingMark),
+    ('\u{11D98}', '\u{11D98}', GeneralCategory::OtherLetter),
+    ('\u{11DA0}', '\u{11DA9}', GeneralCategory::DecimalNumber),
+    ('\u{11EE0}', '\u{11EF2}', GeneralCategory::OtherLetter),
+    ('\u{11EF3}', '\u{11EF4}', GeneralCategory::NonspacingMark),
+    ('\u{11EF5}', '\u{11EF6}', GeneralCategory::SpacingMark),
+    ('\u{11EF7}', '\u{11EF8}', GeneralCategory::OtherPunctuation),
+    ('\u{11FB0}', '\u{11FB0}', GeneralCategory::OtherLetter),
+    ('\u{11FC0}', '\u{11FD4}', GeneralCategory::OtherNumber),
+    ('\u{11FD5}', '\u{11FDC}', GeneralCategory::OtherSymbol),
+    ('\u{11FDD}', '\u{11FE0}', GeneralCategory::CurrencySymbol),
+    ('\u{11FE1}', '\u{11FF1}', GeneralCategory::OtherSymbol),
+    ('\u{11FFF}', '\u{11FFF}', GeneralCategory::OtherPunctuation),
+    ('\u{12000}', '\u{12399}', GeneralCategory::OtherLetter),
+    ('\u{12400}', '\u{1246E}', GeneralCategory::LetterNumber),
+    ('\u{12470}', '\u{12474}', GeneralCategory::OtherPunctuation),
+    ('\u{12480}', '\u{12543}', GeneralCategory::OtherLetter),
+    ('\u{12F90}', '\u{12FF0}', GeneralCategory::OtherLetter),
+    ('\u{12FF1}', '\u{12FF2}', GeneralCategory::OtherPunctuation),
+    ('\u{13000}', '\u{1342E}', GeneralCategory::OtherLetter),
+    ('\u{13430}', '\u{13438}', GeneralCategory::Format),
+    ('\u{14400}', '\u{14646}', GeneralCategory::OtherLetter),
+    ('\u{16800}', '\u{16A38}', GeneralCategory::OtherLetter),
+    ('\u{16A40}', '\u{16A5E}', GeneralCategory::OtherLetter),
+    ('\u{16A60}', '\u{16A69}', GeneralCategory::DecimalNumber),
+    ('\u{16A6E}', '\u{16A6F}', GeneralCategory::OtherPunctuation),
+    ('\u{16A70}', '\u{16ABE}', GeneralCategory::OtherLetter),
+    ('\u{16AC0}', '\u{16AC9}', GeneralCategory::DecimalNumber),
+    ('\u{16AD0}', '\u{16AED}', GeneralCategory::OtherLetter),
+    ('\u{16AF0}', '\u{16AF4}', GeneralCategory::NonspacingMark),
+    ('\u{16AF5}', '\u{16AF5}', GeneralCategory::OtherPunctuation),
+    ('\u{16B00}', '\u{16B2F}', GeneralCategory::OtherLetter),
+    ('\u{16B30}', '\u{16B36}', GeneralCategory::NonspacingMark),
+    ('\u{16B37}', '\u{16B3B}', GeneralCategory::OtherPunctuation),
+    ('\u{16B3C}', '\u{16B3F}', GeneralCategory::OtherSymbol),
+    ('\u{16B40}', '\u{16B43}', GeneralCategory::ModifierLetter),
+    ('\u{16B44}', '\u{16B44}', GeneralCategory::OtherPunctuation),
+    ('\u{16B45}', '\u{16B45}', GeneralCategory::OtherSymbol),
+    ('\u{16B50}', '\u{16B59}', GeneralCategory::DecimalNumber),
+    ('\u{16B5B}', '\u{16B61}', GeneralCategory::OtherNumber),
+    ('\u{16B63}', '\u{16B77}', GeneralCategory::OtherLetter),
+    ('\u{16B7D}', '\u{16B8F}', GeneralCategory::OtherLetter),
+    ('\u{16E40}', '\u{16E5F}', GeneralCategory::UppercaseLetter),
+    ('\u{16E60}', '\u{16E7F}', GeneralCategory::LowercaseLetter),
+    ('\u{16E80}', '\u{16E96}', GeneralCategory::OtherNumber),
+    ('\u{16E97}', '\u{16E9A}', GeneralCategory::OtherPunctuation),
+    ('\u{16F00}', '\u{16F4A}', GeneralCategory::OtherLetter),
+    ('\u{16F4F}', '\u{16F4F}', GeneralCategory::NonspacingMark),
+    ('\u{16F50}', '\u{16F50}', GeneralCategory::OtherLetter),
+    ('\u{16F51}', '\u{16F87}', GeneralCategory::SpacingMark),
+    ('\u{16F8F}', '\u{16F92}', GeneralCategory::NonspacingMark),
+    ('\u{16F93}', '\u{16F9F}', GeneralCategory::ModifierLetter),
+    ('\u{16FE0}', '\u{16FE1}', GeneralCategory::ModifierLetter),
+    ('\u{16FE2}', '\u{16FE2}', GeneralCategory::OtherPunctuation),
+    ('\u{16FE3}', '\u{16FE3}', GeneralCategory::ModifierLetter),
+    ('\u{16FE4}', '\u{16FE4}', GeneralCategory::NonspacingMark),
+    ('\u{16FF0}', '\u{16FF1}', GeneralCategory::SpacingMark),
+    ('\u{17000}', '\u{187F7}', GeneralCategory::OtherLetter),
+    ('\u{18800}', '\u{18CD5}', GeneralCategory::OtherLetter),
+    ('\u{18D00}', '\u{18D08}', GeneralCategory::OtherLetter),
+    ('\u{1AFF0}', '\u{1AFF3}', GeneralCategory::ModifierLetter),
+    ('\u{1AFF5}', '\u{1AFFB}', GeneralCategory::ModifierLetter),
+    ('\u{1AFFD}', '\u{1AFFE}', GeneralCategory::ModifierLetter),
+    ('\u{1B000}', '\u{1B122}', GeneralCategory::OtherLetter),
+    ('\u{1B150}', '\u{1B152}', GeneralCategory::OtherLetter),
+    ('\u{1B164}', '\u{1B167}', GeneralCategory::OtherLetter),
+    ('\u{1B170}', '\u{1B2FB}', GeneralCategory::OtherLetter),
+    ('\u{1BC00}', '\u{1BC6A}', GeneralCategory::OtherLetter),
+    ('\u{1BC70}', '\u{1BC7C}', GeneralCategory::OtherLetter),
+    ('\u{1BC80}', '\u{1BC88}', GeneralCategory::OtherLetter),
+    ('\u{1BC90}', '\u{1BC99}', GeneralCategory::OtherLetter),
+    ('\u{1BC9C}', '\u{1BC9C}', GeneralCategory::OtherSymbol),
+    ('\u{1BC9D}', '\u{1BC9E}', GeneralCategory::NonspacingMark),
+    ('\u{1BC9F}', '\u{1BC9F}', GeneralCategory::OtherPunctuation),
+    ('\u{1BCA0}', '\u{1BCA3}', GeneralCategory::Format),
+    ('\u{1CF00}', '\u{1CF2D}', GeneralCategory::NonspacingMark),
+    ('\u{1CF30}', '\u{1CF46}', GeneralCategory::NonspacingMark),
+    ('\u{1CF50}', '\u{1CFC3}', GeneralCategory::OtherSymbol),
+    ('\u{1D000}', '\u{1D0F5}', GeneralCategory::OtherSymbol),
+    ('\u{1D100}', '\u{1D126}', GeneralCategory::OtherSymbol),
+    ('\u{1D129}', '\u{1D164}', GeneralCategory::OtherSymbol),
+    ('\u{1D165}', '\u{1D166}', GeneralCategory::SpacingMark),
+    ('\u{1D167}', '\u{1D169}', GeneralCategory::NonspacingMark),
+    ('\u{1D16A}', '\u{1D16C}', GeneralCategory::OtherSymbol),
+    ('\u{1D16D}', '\u{1D172}', GeneralCategory::SpacingMark),
+    ('\u{1D173}', '\u{1D17A}', GeneralCategory::Format),
+    ('\u{1D17B}', '\u{1D182}', GeneralCategory::NonspacingMark),
+    ('\u{1D183}', '\u{1D184}', GeneralCategory::OtherSymbol),
+    ('\u{1D185}', '\u{1D18B}', GeneralCategory::NonspacingMark),
+    ('\u{1D18C}', '\u{1D1A9}', GeneralCategory::OtherSymbol),
+    ('\u{1D1AA}', '\u{1D1AD}', GeneralCategory::NonspacingMark),
+    ('\u{1D1AE}', '\u{1D1EA}', GeneralCategory::OtherSymbol),
+    ('\u{1D200}', '\u{1D241}', GeneralCategory::OtherSymbol),
+    ('\u{1D242}', '\u{1D244}', GeneralCategory::NonspacingMark),
+    ('\u{1D245}', '\u{1D245}', GeneralCategory::OtherSymbol),
+    ('\u{1D2E0}', '\u{1D2F3}', GeneralCategory::OtherNumber),
+    ('\u{1D300}', '\u{1D356}', GeneralCategory::OtherSymbol),
+    ('\u{1D360}', '\u{1D378}', GeneralCategory::OtherNumber),
+    ('\u{1D400}', '\u{1D419}', GeneralCategory::UppercaseLetter),
+    ('\u{1D41A}', '\u{1D433}', GeneralCategory::LowercaseLetter),
+    ('\u{1D434}', '\u{1D44D}', GeneralCategory::UppercaseLetter),
+    ('\u{1D44E}', '\u{1D454}', GeneralCategory::LowercaseLetter),
+    ('\u{1D456}', '\u{1D467}', GeneralCategory::LowercaseLetter),
+    ('\u{1D468}', '\u{1D481}', GeneralCategory::UppercaseLetter),
+    ('\u{1D482}', '\u{1D49B}', GeneralCategory::LowercaseLetter),
+    ('\u{1D49C}', '\u{1D49C}', GeneralCategory::UppercaseLetter),
+    ('\u{1D49E}', '\u{1D49F}', GeneralCategory::UppercaseLetter),
+    ('\u{1D4A2}', '\u{1D4A2}', GeneralCategory::UppercaseLetter),
+    ('\u{1D4A5}', '\u{1D4A6}', GeneralCategory::UppercaseLetter),
+    ('\u{1D4A9}', '\u{1D4AC}', GeneralCategory::UppercaseLetter),
+    ('\u{1D4AE}', '\u{1D4B5}', GeneralCategory::UppercaseLetter),
+    ('\u{1D4B6}', '\u{1D4B9}', GeneralCategory::LowercaseLetter),
+    ('\u{1D4BB}', '\u{1D4BB}', GeneralCategory::LowercaseLetter),
+    ('\u{1D4BD}', '\u{1D4C3}', GeneralCategory::LowercaseLetter),
+    ('\u{1D4C5}', '\u{1D4CF}', GeneralCategory::LowercaseLetter),
+    ('\u{1D4D0}', '\u{1D4E9}', GeneralCategory::UppercaseLetter),
+    ('\u{1D4EA}', '\u{1D503}', GeneralCategory::LowercaseLetter),
+    ('\u{1D504}', '\u{1D505}', GeneralCategory::UppercaseLetter),
+    ('\u{1D507}', '\u{1D50A}', GeneralCategory::UppercaseLetter),
+    ('\u{1D50D}', '\u{1D514}', GeneralCategory::UppercaseLetter),
+    ('\u{1D516}', '\u{1D51C}', GeneralCategory::UppercaseLetter),
+    ('\u{1D51E}', '\u{1D537}', GeneralCategory::LowercaseLetter),
+    ('\u{1D538}', '\u{1D539}', GeneralCategory::UppercaseLetter),
+    ('\u{1D53B}', '\u{1D53E}', GeneralCategory::UppercaseLetter),
+    ('\u{1D540}', '\u{1D544}', GeneralCategory::UppercaseLetter),
+    ('\u{1D546}', '\u{1D546}', GeneralCategory::UppercaseLetter),
+    ('\u{1D54A}', '\u{1D550}', GeneralCategory::UppercaseLetter),
+    ('\u{1D552}', '\u{1D56B}', GeneralCategory::LowercaseLetter),
+    ('\u{1D56C}', '\u{1D585}', GeneralCategory::UppercaseLetter),
+    ('\u{1D586}', '\u{1D59F}', GeneralCategory::LowercaseLetter),
+    ('\u{1D5A0}', '\u{1D5B9}', GeneralCategory::UppercaseLetter),
+    ('\u{1D5BA}', '\u{1D5D3}', GeneralCategory::LowercaseLetter),
+    ('\u{1D5D4}', '\u{1D5ED}', GeneralCategory::UppercaseLetter),
+    ('\u{1D5EE}', '\u{1D607}', GeneralCategory::LowercaseLetter),
+    ('\u{1D608}', '\u{1D621}', GeneralCategory::UppercaseLetter),
+    ('\u{1D622}', '\u{1D63B}', GeneralCategory::LowercaseLetter),
+    ('\u{1D63C}', '\u{1D655}', GeneralCategory::UppercaseLetter),
+    ('\u{1D656}', '\u{1D66F}', GeneralCategory::LowercaseLetter),
+    ('\u{1D670}', '\u{1D689}', GeneralCategory::UppercaseLetter),
+    ('\u{1D68A}', '\u{1D6A5}', GeneralCategory::LowercaseLetter),
+    ('\u{1D6A8}', '\u{1D6C0}', GeneralCategory::UppercaseLetter),
+    ('\u{1D6C1}', '\u{1D6C1}', GeneralCategory::MathSymbol),
+    ('\u{1D6C2}', '\u{1D6DA}', GeneralCategory::LowercaseLetter),
+    ('\u{1D6DB}', '\u{1D6DB}', GeneralCategory::MathSymbol),
+    ('\u{1D6DC}', '\u{1D6E1}', GeneralCategory::LowercaseLetter),
+    ('\u{1D6E2}', '\u{1D6FA}', GeneralCategory::UppercaseLetter),
+    ('\u{1D6FB}', '\u{1D6FB}', GeneralCategory::MathSymbol),
+    ('\u{1D6FC}', '\u{1D714}', GeneralCategory::LowercaseLetter),
+    ('\u{1D715}', '\u{1D715}', GeneralCategory::MathSymbol),
+    ('\u{1D716}', '\u{1D71B}', GeneralCategory::LowercaseLetter),
+    ('\u{1D71C}', '\u{1D734}', GeneralCategory::UppercaseLetter),
+    ('\u{1D735}', '\u{1D735}', GeneralCategory::MathSymbol),
+    ('\u{1D736}', '\u{1D74E}', GeneralCategory::LowercaseLetter),
+    ('\u{1D74F}', '\u{1D74F}', GeneralCategory::MathSymbol),
+    ('\u{1D750}', '\u{1D755}', GeneralCategory::LowercaseLetter),
+    ('\u{1D756}', '\u{1D76E}', GeneralCategory::UppercaseLetter),
+    ('\u{1D76F}', '\u{1D76F}', GeneralCategory::MathSymbol),
+    ('\u{1D770}', '\u{1D788}', GeneralCategory::LowercaseLetter),
+    ('\u{1D789}', '\u{1D789}', GeneralCategory::MathSymbol),
+    ('\u{1D78A}', '\u{1D78F}', GeneralCategory::LowercaseLetter),
+    ('\u{1D790}', '\u{1D7A8}', GeneralCategory::UppercaseLetter),
+    ('\u{1D7A9}', '\u{1D7A9}', GeneralCategory::MathSymbol),
+    ('\u{1D7AA}', '\u{1D7C2}', GeneralCategory::LowercaseLetter),
+    ('\u{1D7C3}', '\u{1D7C3}', GeneralCategory::MathSymbol),
+    ('\u{1D7C4}', '\u{1D7C9}', GeneralCategory::LowercaseLetter),
+    ('\u{1D7CA}', '\u{1D7CA}', GeneralCategory::UppercaseLetter),
+    ('\u{1D7CB}', '\u{1D7CB}', GeneralCategory::LowercaseLetter),
+    ('\u{1D7CE}', '\u{1D7FF}', GeneralCategory::DecimalNumber),
+    ('\u{1D800}', '\u{1D9FF}', GeneralCategory::OtherSymbol),
+    ('\u{1DA00}', '\u{1DA36}', GeneralCategory::NonspacingMark),
+    ('\u{1DA37}', '\u{1DA3A}', GeneralCategory::OtherSymbol),
+    ('\u{1DA3B}', '\u{1DA6C}', GeneralCategory::NonspacingMark),
+    ('\u{1DA6D}', '\u{1DA74}', GeneralCategory::OtherSymbol),
+    ('\u{1DA75}', '\u{1DA75}', GeneralCategory::NonspacingMark),
+    ('\u{1DA76}', '\u{1DA83}', GeneralCategory::OtherSymbol),
+    ('\u{1DA84}', '\u{1DA84}', GeneralCategory::NonspacingMark),
+    ('\u{1DA85}', '\u{1DA86}', GeneralCategory::OtherSymbol),
+    ('\u{1DA87}', '\u{1DA8B}', GeneralCategory::OtherPunctuation),
+    ('\u{1DA9B}', '\u{1DA9F}', GeneralCategory::NonspacingMark),
+    ('\u{1DAA1}', '\u{1DAAF}', GeneralCategory::NonspacingMark),
+    ('\u{1DF00}', '\u{1DF09}', GeneralCategory::LowercaseLetter),
+    ('\u{1DF0A}', '\u{1DF0A}', GeneralCategory::OtherLetter),
+    ('\u{1DF0B}', '\u{1DF1E}', GeneralCategory::LowercaseLetter),
+    ('\u{1E000}', '\u{1E006}', GeneralCategory::NonspacingMark),
+    ('\u{1E008}', '\u{1E018}', GeneralCategory::NonspacingMark),
+    ('\u{1E01B}', '\u{1E021}', GeneralCategory::NonspacingMark),
+    ('\u{1E023}', '\u{1E024}', GeneralCategory::NonspacingMark),
+    ('\u{1E026}', '\u{1E02A}', GeneralCategory::NonspacingMark),
+    ('\u{1E100}', '\u{1E12C}', GeneralCategory::OtherLetter),
+    ('\u{1E130}', '\u{1E136}', GeneralCategory::NonspacingMark),
+    ('\u{1E137}', '\u{1E13D}', GeneralCategory::ModifierLetter),
+    ('\u{1E140}', '\u{1E149}', GeneralCategory::DecimalNumber),
+    ('\u{1E14E}', '\u{1E14E}', GeneralCategory::OtherLetter),
+    ('\u{1E14F}', '\u{1E14F}', GeneralCategory::OtherSymbol),
+    ('\u{1E290}', '\u{1E2AD}', GeneralCategory::OtherLetter),
+    ('\u{1E2AE}', '\u{1E2AE}', GeneralCategory::NonspacingMark),
+    ('\u{1E2C0}', '\u{1E2EB}', GeneralCategory::OtherLetter),
+    ('\u{1E2EC}', '\u{1E2EF}', GeneralCategory::NonspacingMark),
+    ('\u{1E2F0}', '\u{1E2F9}', GeneralCategory::DecimalNumber),
+    ('\u{1E2FF}', '\u{1E2FF}', GeneralCategory::CurrencySymbol),
+    ('\u{1E7E0}', '\u{1E7E6}', GeneralCategory::OtherLetter),
+    ('\u{1E7E8}', '\u{1E7EB}', GeneralCategory::OtherLetter),
+    ('\u{1E7ED}', '\u{1E7EE}', GeneralCategory::OtherLetter),
+    ('\u{1E7F0}', '\u{1E7FE}', GeneralCategory::OtherLetter),
+    ('\u{1E800}', '\u{1E8C4}', GeneralCategory::OtherLetter),
+    ('\u{1E8C7}', '\u{1E8CF}', GeneralCategory::OtherNumber),
+    ('\u{1E8D0}', '\u{1E8D6}', GeneralCategory::NonspacingMark),
+    ('\u{1E900}', '\u{1E921}', GeneralCategory::UppercaseLetter),
+    ('\u{1E922}', '\u{1E943}', GeneralCategory::LowercaseLetter),
+    ('\u{1E944}', '\u{1E94A}', GeneralCategory::NonspacingMark),
+    ('\u{1E94B}', '\u{1E94B}', GeneralCategory::ModifierLetter),
+    ('\u{1E950}', '\u{1E959}', GeneralCategory::DecimalNumber),
+    ('\u{1E95E}', '\u{1E95F}', GeneralCategory::OtherPunctuation),
+    ('\u{1EC71}', '\u{1ECAB}', GeneralCategory::OtherNumber),
+    ('\u{1ECAC}', '\u{1ECAC}', GeneralCategory::OtherSymbol),
+    ('\u{1ECAD}', '\u{1ECAF}', GeneralCategory::OtherNumber),
+    ('\u{1ECB0}', '\u{1ECB0}', GeneralCategory::CurrencySymbol),
+    ('\u{1ECB1}', '\u{1ECB4}', GeneralCategory::OtherNumber),
+    ('\u{1ED01}', '\u{1ED2D}', GeneralCategory::OtherNumber),
+    ('\u{1ED2E}', '\u{1ED2E}', GeneralCategory::OtherSymbol),
+    ('\u{1ED2F}', '\u{1ED3D}', GeneralCategory::OtherNumber),
+    ('\u{1EE00}', '\u{1EE03}', GeneralCategory::OtherLetter),
+    ('\u{1EE05}', '\u{1EE1F}', GeneralCategory::OtherLetter),
+    ('\u{1EE21}', '\u{1EE22}', GeneralCategory::OtherLetter),
+    ('\u{1EE24}', '\u{1EE24}', GeneralCategory::OtherLetter),
+    ('\u{1EE27}', '\u{1EE27}', GeneralCategory::OtherLetter),
+    ('\u{1EE29}', '\u{1EE32}', GeneralCategory::OtherLetter),
+    ('\u{1EE34}', '\u{1EE37}', GeneralCategory::OtherLetter),
+    ('\u{1EE39}', '\u{1EE39}', GeneralCategory::OtherLetter),
+    ('\u{1EE3B}', '\u{1EE3B}', GeneralCategory::OtherLetter),
+    ('\u{1EE42}', '\u{1EE42}', GeneralCategory::OtherLetter),
+    ('\u{1EE47}', '\u{1EE47}', GeneralCategory::OtherLetter),
+    ('\u{1EE49}', '\u{1EE49}', GeneralCategory::OtherLetter),
+    ('\u{1EE4B}', '\u{1EE4B}', GeneralCategory::OtherLetter),
+    ('\u{1EE4D}', '\u{1EE4F}', GeneralCategory::OtherLetter),
+    ('\u{1EE51}', '\u{1EE52}', GeneralCategory::OtherLetter),
+    ('\u{1EE54}', '\u{1EE54}', GeneralCategory::OtherLetter),
+    ('\u{1EE57}', '\u{1EE57}', GeneralCategory::OtherLetter),
+    ('\u{1EE59}', '\u{1EE59}', GeneralCategory::OtherLetter),
+    ('\u{1EE5B}', '\u{1EE5B}', GeneralCategory::OtherLetter),
+    ('\u{1EE5D}', '\u{1EE5D}', GeneralCategory::OtherLetter),
+    ('\u{1EE5F}', '\u{1EE5F}', GeneralCategory::OtherLetter),
+    ('\u{1EE61}', '\u{1EE62}', GeneralCategory::OtherLetter),
+    ('\u{1EE64}', '\u{1EE64}', GeneralCategory::OtherLetter),
+    ('\u{1EE67}', '\u{1EE6A}', GeneralCategory::OtherLetter),
+    ('\u{1EE6C}', '\u{1EE72}', GeneralCategory::OtherLetter),
+    ('\u{1EE74}', '\u{1EE77}', GeneralCategory::OtherLetter),
+    ('\u{1EE79}', '\u{1EE7C}', GeneralCategory::OtherLetter),
+    ('\u{1EE7E}', '\u{1EE7E}', GeneralCategory::OtherLetter),
+    ('\u{1EE80}', '\u{1EE89}', GeneralCategory::OtherLetter),
+    ('\u{1EE8B}', '\u{1EE9B}', GeneralCategory::OtherLetter),
+    ('\u{1EEA1}', '\u{1EEA3}', GeneralCategory::OtherLetter),
+    ('\u{1EEA5}', '\u{1EEA9}', GeneralCategory::OtherLetter),
+    ('\u{1EEAB}', '\u{1EEBB}', GeneralCategory::OtherLetter),
+    ('\u{1EEF0}', '\u{1EEF1}', GeneralCategory::MathSymbol),
+    ('\u{1F000}', '\u{1F02B}', GeneralCategory::OtherSymbol),
+    ('\u{1F030}', '\u{1F093}', GeneralCategory::OtherSymbol),
+    ('\u{1F0A0}', '\u{1F0AE}', GeneralCategory::OtherSymbol),
+    ('\u{1F0B1}', '\u{1F0BF}', GeneralCategory::OtherSymbol),
+    ('\u{1F0C1}', '\u{1F0CF}', GeneralCategory::OtherSymbol),
+    ('\u{1F0D1}', '\u{1F0F5}', GeneralCategory::OtherSymbol),
+    ('\u{1F100}', '\u{1F10C}', GeneralCategory::OtherNumber),
+    ('\u{1F10D}', '\u{1F1AD}', GeneralCategory::OtherSymbol),
+    ('\u{1F1E6}', '\u{1F202}', GeneralCategory::OtherSymbol),
+    ('\u{1F210}', '\u{1F23B}', GeneralCategory::OtherSymbol),
+    ('\u{1F240}', '\u{1F248}', GeneralCategory::OtherSymbol),
+    ('\u{1F250}', '\u{1F251}', GeneralCategory::OtherSymbol),
+    ('\u{1F260}', '\u{1F265}', GeneralCategory::OtherSymbol),
+    ('\u{1F300}', '\u{1F3FA}', GeneralCategory::OtherSymbol),
+    ('\u{1F3FB}', '\u{1F3FF}', GeneralCategory::ModifierSymbol),
+    ('\u{1F400}', '\u{1F6D7}', GeneralCategory::OtherSymbol),
+    ('\u{1F6DD}', '\u{1F6EC}', GeneralCategory::OtherSymbol),
+    ('\u{1F6F0}', '\u{1F6FC}', GeneralCategory::OtherSymbol),
+    ('\u{1F700}', '\u{1F773}', GeneralCategory::OtherSymbol),
+    ('\u{1F780}', '\u{1F7D8}', GeneralCategory::OtherSymbol),
+    ('\u{1F7E0}', '\u{1F7EB}', GeneralCategory::OtherSymbol),
+    ('\u{1F7F0}', '\u{1F7F0}', GeneralCategory::OtherSymbol),
+    ('\u{1F800}', '\u{1F80B}', GeneralCategory::OtherSymbol),
+    ('\u{1F810}', '\u{1F847}', GeneralCategory::OtherSymbol),
+    ('\u{1F850}', '\u{1F859}', GeneralCategory::OtherSymbol),
+    ('\u{1F860}', '\u{1F887}', GeneralCategory::OtherSymbol),
+    ('\u{1F890}', '\u{1F8AD}', GeneralCategory::OtherSymbol),
+    ('\u{1F8B0}', '\u{1F8B1}', GeneralCategory::OtherSymbol),
+    ('\u{1F900}', '\u{1FA53}', GeneralCategory::OtherSymbol),
+    ('\u{1FA60}', '\u{1FA6D}', GeneralCategory::OtherSymbol),
+    ('\u{1FA70}', '\u{1FA74}', GeneralCategory::OtherSymbol),
+    ('\u{1FA78}', '\u{1FA7C}', GeneralCategory::OtherSymbol),
+    ('\u{1FA80}', '\u{1FA86}', GeneralCategory::OtherSymbol),
+    ('\u{1FA90}', '\u{1FAAC}', GeneralCategory::OtherSymbol),
+    ('\u{1FAB0}', '\u{1FABA}', GeneralCategory::OtherSymbol),
+    ('\u{1FAC0}', '\u{1FAC5}', GeneralCategory::OtherSymbol),
+    ('\u{1FAD0}', '\u{1FAD9}', GeneralCategory::OtherSymbol),
+    ('\u{1FAE0}', '\u{1FAE7}', GeneralCategory::OtherSymbol),
+    ('\u{1FAF0}', '\u{1FAF6}', GeneralCategory::OtherSymbol),
+    ('\u{1FB00}', '\u{1FB92}', GeneralCategory::OtherSymbol),
+    ('\u{1FB94}', '\u{1FBCA}', GeneralCategory::OtherSymbol),
+    ('\u{1FBF0}', '\u{1FBF9}', GeneralCategory::DecimalNumber),
+    ('\u{20000}', '\u{2A6DF}', GeneralCategory::OtherLetter),
+    ('\u{2A700}', '\u{2B738}', GeneralCategory::OtherLetter),
+    ('\u{2B740}', '\u{2B81D}', GeneralCategory::OtherLetter),
+    ('\u{2B820}', '\u{2CEA1}', GeneralCategory::OtherLetter),
+    ('\u{2CEB0}', '\u{2EBE0}', GeneralCategory::OtherLetter),
+    ('\u{2F800}', '\u{2FA1D}', GeneralCategory::OtherLetter),
+    ('\u{30000}', '\u{3134A}', GeneralCategory::OtherLetter),
+    ('\u{E0001}', '\u{E0001}', GeneralCategory::Format),
+    ('\u{E0020}', '\u{E007F}', GeneralCategory::Format),
+    ('\u{E0100}', '\u{E01EF}', GeneralCategory::NonspacingMark),
+    ('\u{F0000}', '\u{FFFFD}', GeneralCategory::PrivateUse),
+    ('\u{100000}', '\u{10FFFD}', GeneralCategory::PrivateUse),
+];
+
+/// Ranges of assigned chars and the version that assigned them, sorted by code point.
+pub(crate) static AGE: &[(char, char, (u8, u8))] = &[
+    ('\u{0}', '\u{1F5}', (1, 1)),
+    ('\u{1F6}', '\u{1F9}', (3, 0)),
+    ('\u{1FA}', '\u{217}', (1, 1)),
+    ('\u{218}', '\u{21F}', (3, 0)),
+    ('\u{220}', '\u{220}', (3, 2)),
+    ('\u{221}', '\u{221}', (4, 0)),
+    ('\u{222}', '\u{233}', (3, 0)),
+    ('\u{234}', '\u{236}', (4, 0)),
+    ('\u{237}', '\u{241}', (4, 1)),
+    ('\u{242}', '\u{24F}', (5, 0)),
+    ('\u{250}', '\u{2A8}', (1, 1)),
+    ('\u{2A9}', '\u{2AD}', (3, 0)),
+    ('\u{2AE}', '\u{2AF}', (4, 0)),
+    ('\u{2B0}', '\u{2DE}', (1, 1)),
+    ('\u{2DF}', '\u{2DF}', (3, 0)),
+    ('\u{2E0}', '\u{2E9}', (1, 1)),
+    ('\u{2EA}', '\u{2EE}', (3, 0)),
+    ('\u{2EF}', '\u{2FF}', (4, 0)),
+    ('\u{300}', '\u{345}', (1, 1)),
+    ('\u{346}', '\u{34E}', (3, 0)),
+    ('\u{34F}', '\u{34F}', (3, 2)),
+    ('\u{350}', '\u{357}', (4, 0)),
+    ('\u{358}', '\u{35C}', (4, 1)),
+    ('\u{35D}', '\u{35F}', (4, 0)),
+    ('\u{360}', '\u{361}', (1, 1)),
+    ('\u{362}', '\u{362}', (3, 0)),
+    ('\u{363}', '\u{36F}', (3, 2)),
+    ('\u{370}', '\u{373}', (5, 1)),
+    ('\u{374}', '\u{375}', (1, 1)),
+    ('\u{376}', '\u{377}', (5, 1)),
+    ('\u{37A}', '\u{37A}', (1, 1)),
+    ('\u{37B}', '\u{37D}', (5, 0)),
+    ('\u{37E}', '\u{37E}', (1, 1)),
+    ('\u{37F}', '\u{37F}', (7, 0)),
+    ('\u{384}', '\u{38A}', (1, 1)),
+    ('\u{38C}', '\u{38C}', (1, 1)),
+    ('\u{38E}', '\u{3A1}', (1, 1)),
+    ('\u{3A3}', '\u{3CE}', (1, 1)),
+    ('\u{3CF}', '\u{3CF}', (5, 1)),
+    ('\u{3D0}', '\u{3D6}', (1, 1)),
+    ('\u{3D7}', '\u{3D7}', (3, 0)),
+    ('\u{3D8}', '\u{3D9}', (3, 2)),
+    ('\u{3DA}', '\u{3DA}', (1, 1)),
+    ('\u{3DB}', '\u{3DB}', (3, 0)),
+    ('\u{3DC}', '\u{3DC}', (1, 1)),
+    ('\u{3DD}', '\u{3DD}', (3, 0)),
+    ('\u{3DE}', '\u{3DE}', (1, 1)),
+    ('\u{3DF}', '\u{3DF}', (3, 0)),
+    ('\u{3E0}', '\u{3E0}', (1, 1)),
+    ('\u{3E1}', '\u{3E1}', (3, 0)),
+    ('\u{3E2}', '\u{3F3}', (1, 1)),
+    ('\u{3F4}', '\u{3F5}', (3, 1)),
+    ('\u{3F6}', '\u{3F6}', (3, 2)),
+    ('\u{3F7}', '\u{3FB}', (4, 0)),
+    ('\u{3FC}', '\u{3FF}', (4, 1)),
+    ('\u{400}', '\u{400}', (3, 0)),
+    ('\u{401}', '\u{40C}', (1, 1)),
+    ('\u{40D}', '\u{40D}', (3, 0)),
+    ('\u{40E}', '\u{44F}', (1, 1)),
+    ('\u{450}', '\u{450}', (3, 0)),
+    ('\u{451}', '\u{45C}', (1, 1)),
+    ('\u{45D}', '\u{45D}', (3, 0)),
+    ('\u{45E}', '\u{486}', (1, 1)),
+    ('\u{487}', '\u{487}', (5, 1)),
+    ('\u{488}', '\u{489}', (3, 0)),
+    ('\u{48A}', '\u{48B}', (3, 2)),
+    ('\u{48C}', '\u{48F}', (3, 0)),
+    ('\u{490}', '\u{4C4}', (1, 1)),
+    ('\u{4C5}', '\u{4C6}', (3, 2)),
+    ('\u{4C7}', '\u{4C8}', (1, 1)),
+    ('\u{4C9}', '\u{4CA}', (3, 2)),
+    ('\u{4CB}', '\u{4CC}', (1, 1)),
+    ('\u{4CD}', '\u{4CE}', (3, 2)),
+    ('\u{4CF}', '\u{4CF}', (5, 0)),
+    ('\u{4D0}', '\u{4EB}', (1, 1)),
+    ('\u{4EC}', '\u{4ED}', (3, 0)),
+    ('\u{4EE}', '\u{4F5}', (1, 1)),
+    ('\u{4F6}', '\u{4F7}', (4, 1)),
+    ('\u{4F8}', '\u{4F9}', (1, 1)),
+    ('\u{4FA}', '\u{4FF}', (5, 0)),
+    ('\u{500}', '\u{50F}', (3, 2)),
+    ('\u{510}', '\u{513}', (5, 0)),
+    ('\u{514}', '\u{523}', (5, 1)),
+    ('\u{524}', '\u{525}', (5, 2)),
+    ('\u{526}', '\u{527}', (6, 0)),
+    ('\u{528}', '\u{52F}', (7, 0)),
+    ('\u{531}', '\u{556}', (1, 1)),
+    ('\u{559}', '\u{55F}', (1, 1)),
+    ('\u{560}', '\u{560}', (11, 0)),
+    ('\u{561}', '\u{587}', (1, 1)),
+    ('\u{588}', '\u{588}', (11, 0)),
+    ('\u{589}', '\u{589}', (1, 1)),
+    ('\u{58A}', '\u{58A}', (3, 0)),
+    ('\u{58D}', '\u{58E}', (7, 0)),
+    ('\u{58F}', '\u{58F}', (6, 1)),
+    ('\u{591}', '\u{5A1}', (2, 0)),
+    ('\u{5A2}', '\u{5A2}', (4, 1)),
+    ('\u{5A3}', '\u{5AF}', (2, 0)),
+    ('\u{5B0}', '\u{5B9}', (1, 1)),
+    ('\u{5BA}', '\u{5BA}', (5, 0)),
+    ('\u{5BB}', '\u{5C3}', (1, 1)),
+    ('\u{5C4}', '\u{5C4}', (2, 0)),
+    ('\u{5C5}', '\u{5C7}', (4, 1)),
+    ('\u{5D0}', '\u{5EA}', (1, 1)),
+    ('\u{5EF}', '\u{5EF}', (11, 0)),
+    ('\u{5F0}', '\u{5F4}', (1, 1)),
+    ('\u{600}', '\u{603}', (4, 0)),
+    ('\u{604}', '\u{604}', (6, 1)),
+    ('\u{605}', '\u{605}', (7, 0)),
+    ('\u{606}', '\u{60A}', (5, 1)),
+    ('\u{60B}', '\u{60B}', (4, 1)),
+    ('\u{60C}', '\u{60C}', (1, 1)),
+    ('\u{60D}', '\u{615}', (4, 0)),
+    ('\u{616}', '\u{61A}', (5, 1)),
+    ('\u{61B}', '\u{61B}', (1, 1)),
+    ('\u{61C}', '\u{61C}', (6, 3)),
+    ('\u{61D}', '\u{61D}', (14, 0)),
+    ('\u{61E}', '\u{61E}', (4, 1)),
+    ('\u{61F}', '\u{61F}', (1, 1)),
+    ('\u{620}', '\u{620}', (6, 0)),
+    ('\u{621}', '\u{63A}', (1, 1)),
+    ('\u{63B}', '\u{63F}', (5, 1)),
+    ('\u{640}', '\u{652}', (1, 1)),
+    ('\u{653}', '\u{655}', (3, 0)),
+    ('\u{656}', '\u{658}', (4, 0)),
+    ('\u{659}', '\u{65E}', (4, 1)),
+    ('\u{65F}', '\u{65F}', (6, 0)),
+    ('\u{660}', '\u{66D}', (1, 1)),
+    ('\u{66E}', '\u{66F}', (3, 2)),
+    ('\u{670}', '\u{6B7}', (1, 1)),
+    ('\u{6B8}', '\u{6B9}', (3, 0)),
+    ('\u{6BA}', '\u{6BE}', (1, 1)),
+    ('\u{6BF}', '\u{6BF}', (3, 0)),
+    ('\u{6C0}', '\u{6CE}', (1, 1)),
+    ('\u{6CF}', '\u{6CF}', (3, 0)),
+    ('\u{6D0}', '\u{6ED}', (1, 1)),
+    ('\u{6EE}', '\u{6EF}', (4, 0)),
+    ('\u{6F0}', '\u{6F9}', (1, 1)),
+    ('\u{6FA}', '\u{6FE}', (3, 0)),
+    ('\u{6FF}', '\u{6FF}', (4, 0)),
+    ('\u{700}', '\u{70D}', (3, 0)),
+    ('\u{70F}', '\u{72C}', (3, 0)),
+    ('\u{72D}', '\u{72F}', (4, 0)),
+    ('\u{730}', '\u{74A}', (3, 0)),
+    ('\u{74D}', '\u{74F}', (4, 0)),
+    ('\u{750}', '\u{76D}', (4, 1)),
+    ('\u{76E}', '\u{77F}', (5, 1)),
+    ('\u{780}', '\u{7B0}', (3, 0)),
+    ('\u{7B1}', '\u{7B1}', (3, 2)),
+    ('\u{7C0}', '\u{7FA}', (5, 0)),
+    ('\u{7FD}', '\u{7FF}', (11, 0)),
+    ('\u{800}', '\u{82D}', (5, 2)),
+    ('\u{830}', '\u{83E}', (5, 2)),
+    ('\u{840}', '\u{85B}', (6, 0)),
+    ('\u{85E}', '\u{85E}', (6, 0)),
+    ('\u{860}', '\u{86A}', (10, 0)),
+    ('\u{870}', '\u{88E}', (14, 0)),
+    ('\u{890}', '\u{891}', (14, 0)),
+    ('\u{898}', '\u{89F}', (14, 0)),
+    ('\u{8A0}', '\u{8A0}', (6, 1)),
+    ('\u{8A1}', '\u{8A1}', (7, 0)),
+    ('\u{8A2}', '\u{8AC}', (6, 1)),
+    ('\u{8AD}', '\u{8B2}', (7, 0)),
+    ('\u{8B3}', '\u{8B4}', (8, 0)),
+    ('\u{8B5}', '\u{8B5}', (14, 0)),
+    ('\u{8B6}', '\u{8BD}', (9, 0)),
+    ('\u{8BE}', '\u{8C7}', (13, 0)),
+    ('\u{8C8}', '\u{8D2}', (14, 0)),
+    ('\u{8D3}', '\u{8D3}', (11, 0)),
+    ('\u{8D4}', '\u{8E2}', (9, 0)),
+    ('\u{8E3}', '\u{8E3}', (8, 0)),
+    ('\u{8E4}', '\u{8FE}', (6, 1)),
+    ('\u{8FF}', '\u{8FF}', (7, 0)),
+    ('\u{900}', '\u{900}', (5, 2)),
+    ('\u{901}', '\u{903}', (1, 1)),
+    ('\u{904}', '\u{904}', (4, 0)),
+    ('\u{905}', '\u{939}', (1, 1)),
+    ('\u{93A}', '\u{93B}', (6, 0)),
+    ('\u{93C}', '\u{94D}', (1, 1)),
+    ('\u{94E}', '\u{94E}', (5, 2)),
+    ('\u{94F}', '\u{94F}', (6, 0)),
+    ('\u{950}', '\u{954}', (1, 1)),
+    ('\u{955}', '\u{955}', (5, 2)),
+    ('\u{956}', '\u{957}', (6, 0)),
+    ('\u{958}', '\u{970}', (1, 1)),
+    ('\u{971}', '\u{972}', (5, 1)),
+    ('\u{973}', '\u{977}', (6, 0)),
+    ('\u{978}', '\u{978}', (7, 0)),
+    ('\u{979}', '\u{97A}', (5, 2)),
+    ('\u{97B}', '\u{97C}', (5, 0)),
+    ('\u{97D}', '\u{97D}', (4, 1)),
+    ('\u{97E}', '\u{97F}', (5, 0)),
+    ('\u{980}', '\u{980}', (7, 0)),
+    ('\u{981}', '\u{983}', (1, 1)),
+    ('\u{985}', '\u{98C}', (1, 1)),
+    ('\u{98F}', '\u{990}', (1, 1)),
+    ('\u{993}', '\u{9A8}', (1, 1)),
+    ('\u{9AA}', '\u{9B0}', (1, 1)),
+    ('\u{9B2}', '\u{9B2}', (1, 1)),
+    ('\u{9B6}', '\u{9B9}', (1, 1)),
+    ('\u{9BC}', '\u{9BC}', (1, 1)),
+    ('\u{9BD}', '\u{9BD}', (4, 0)),
+    ('\u{9BE}', '\u{9C4}', (1, 1)),
+    ('\u{9C7}', '\u{9C8}', (1, 1)),
+    ('\u{9CB}', '\u{9CD}', (1, 1)),
+    ('\u{9CE}', '\u{9CE}', (4, 1)),
+    ('\u{9D7}', '\u{9D7}', (1, 1)),
+    ('\u{9DC}', '\u{9DD}', (1, 1)),
+    ('\u{9DF}', '\u{9E3}', (1, 1)),
+    ('\u{9E6}', '\u{9FA}', (1, 1)),
+    ('\u{9FB}', '\u{9FB}', (5, 2)),
+    ('\u{9FC}', '\u{9FD}', (10, 0)),
+    ('\u{9FE}', '\u{9FE}', (11, 0)),
+    ('\u{A01}', '\u{A01}', (4, 0)),
+    ('\u{A02}', '\u{A02}', (1, 1)),
+    ('\u{A03}', '\u{A03}', (4, 0)),
+    ('\u{A05}', '\u{A0A}', (1, 1)),
+    ('\u{A0F}', '\u{A10}', (1, 1)),
+    ('\u{A13}', '\u{A28}', (1, 1)),
+    ('\u{A2A}', '\u{A30}', (1, 1)),
+    ('\u{A32}', '\u{A33}', (1, 1)),
+    ('\u{A35}', '\u{A36}', (1, 1)),
+    ('\u{A38}', '\u{A39}', (1, 1)),
+    ('\u{A3C}', '\u{A3C}', (1, 1)),
+    ('\u{A3E}', '\u{A42}', (1, 1)),
+    ('\u{A47}', '\u{A48}', (1, 1)),
+    ('\u{A4B}', '\u{A4D}', (1, 1)),
+    ('\u{A51}', '\u{A51}', (5, 1)),
+    ('\u{A59}', '\u{A5C}', (1, 1)),
+    ('\u{A5E}', '\u{A5E}', (1, 1)),
+    ('\u{A66}', '\u{A74}', (1, 1)),
+    ('\u{A75}', '\u{A75}', (5, 1)),
+    ('\u{A76}', '\u{A76}', (11, 0)),
+    ('\u{A81}', '\u{A83}', (1, 1)),
+    ('\u{A85}', '\u{A8B}', (1, 1)),
+    ('\u{A8C}', '\u{A8C}', (4, 0)),
+    ('\u{A8D}', '\u{A8D}', (1, 1)),
+    ('\u{A8F}', '\u{A91}', (1, 1)),
+    ('\u{A93}', '\u{AA8}', (1, 1)),
+    ('\u{AAA}', '\u{AB0}', (1, 1)),
+    ('\u{AB2}', '\u{AB3}', (1, 1)),
+    ('\u{AB5}', '\u{AB9}', (1, 1)),
+    ('\u{ABC}', '\u{AC5}', (1, 1)),
+    ('\u{AC7}', '\u{AC9}', (1, 1)),
+    ('\u{ACB}', '\u{ACD}', (1, 1)),
+    ('\u{AD0}', '\u{AD0}', (1, 1)),
+    ('\u{AE0}', '\u{AE0}', (1, 1)),
+    ('\u{AE1}', '\u{AE3}', (4, 0)),
+    ('\u{AE6}', '\u{AEF}', (1, 1)),
+    ('\u{AF0}', '\u{AF0}', (6, 1)),
+    ('\u{AF1}', '\u{AF1}', (4, 0)),
+    ('\u{AF9}', '\u{AF9}', (8, 0)),
+    ('\u{AFA}', '\u{AFF}', (10, 0)),
+    ('\u{B01}', '\u{B03}', (1, 1)),
+    ('\u{B05}', '\u{B0C}', (1, 1)),
+    ('\u{B0F}', '\u{B10}', (1, 1)),
+    ('\u{B13}', '\u{B28}', (1, 1)),
+    ('\u{B2A}', '\u{B30}', (1, 1)),
+    ('\u{B32}', '\u{B33}', (1, 1)),
+    ('\u{B35}', '\u{B35}', (4, 0)),
+    ('\u{B36}', '\u{B39}', (1, 1)),
+    ('\u{B3C}', '\u{B43}', (1, 1)),
+    ('\u{B44}', '\u{B44}', (5, 1)),
+    ('\u{B47}', '\u{B48}', (1, 1)),
+    ('\u{B4B}', '\u{B4D}', (1, 1)),
+    ('\u{B55}', '\u{B55}', (13, 0)),
+    ('\u{B56}', '\u{B57}', (1, 1)),
+    ('\u{B5C}', '\u{B5D}', (1, 1)),
+    ('\u{B5F}', '\u{B61}', (1, 1)),
+    ('\u{B62}', '\u{B63}', (5, 1)),
+    ('\u{B66}', '\u{B70}', (1, 1)),
+    ('\u{B71}', '\u{B71}', (4, 0)),
+    ('\u{B72}', '\u{B77}', (6, 0)),
+    ('\u{B82}', '\u{B83}', (1, 1)),
+    ('\u{B85}', '\u{B8A}', (1, 1)),
+    ('\u{B8E}', '\u{B90}', (1, 1)),
+    ('\u{B92}', '\u{B95}', (1, 1)),
+    ('\u{B99}', '\u{B9A}', (1, 1)),
+    ('\u{B9C}', '\u{B9C}', (1, 1)),
+    ('\u{B9E}', '\u{B9F}', (1, 1)),
+    ('\u{BA3}', '\u{BA4}', (1, 1)),
+    ('\u{BA8}', '\u{BAA}', (1, 1)),
+    ('\u{BAE}', '\u{BB5}', (1, 1)),
+    ('\u{BB6}', '\u{BB6}', (4, 1)),
+    ('\u{BB7}', '\u{BB9}', (1, 1)),
+    ('\u{BBE}', '\u{BC2}', (1, 1)),
+    ('\u{BC6}', '\u{BC8}', (1, 1)),
+    ('\u{BCA}', '\u{BCD}', (1, 1)),
+    ('\u{BD0}', '\u{BD0}', (5, 1)),
+    ('\u{BD7}', '\u{BD7}', (1, 1)),
+    ('\u{BE6}', '\u{BE6}', (4, 1)),
+    ('\u{BE7}', '\u{BF2}', (1, 1)),
+    ('\u{BF3}', '\u{BFA}', (4, 0)),
+    ('\u{C00}', '\u{C00}', (7, 0)),
+    ('\u{C01}', '\u{C03}', (1, 1)),
+    ('\u{C04}', '\u{C04}', (11, 0)),
+    ('\u{C05}', '\u{C0C}', (1, 1)),
+    ('\u{C0E}', '\u{C10}', (1, 1)),
+    ('\u{C12}', '\u{C28}', (1, 1)),
+    ('\u{C2A}', '\u{C33}', (1, 1)),
+    ('\u{C34}', '\u{C34}', (7, 0)),
+    ('\u{C35}', '\u{C39}', (1, 1)),
+    ('\u{C3C}', '\u{C3C}', (14, 0)),
+    ('\u{C3D}', '\u{C3D}', (5, 1)),
+    ('\u{C3E}', '\u{C44}', (1, 1)),
+    ('\u{C46}', '\u{C48}', (1, 1)),
+    ('\u{C4A}', '\u{C4D}', (1, 1)),
+    ('\u{C55}', '\u{C56}', (1, 1)),
+    ('\u{C58}', '\u{C59}', (5, 1)),
+    ('\u{C5A}', '\u{C5A}', (8, 0)),
+    ('\u{C5D}', '\u{C5D}', (14, 0)),
+    ('\u{C60}', '\u{C61}', (1, 1)),
+    ('\u{C62}', '\u{C63}', (5, 1)),
+    ('\u{C66}', '\u{C6F}', (1, 1)),
+    ('\u{C77}', '\u{C77}', (12, 0)),
+    ('\u{C78}', '\u{C7F}', (5, 1)),
+    ('\u{C80}', '\u{C80}', (9, 0)),
+    ('\u{C81}', '\u{C81}', (7, 0)),
+    ('\u{C82}', '\u{C83}', (1, 1)),
+    ('\u{C84}', '\u{C84}', (11, 0)),
+    ('\u{C85}', '\u{C8C}', (1, 1)),
+    ('\u{C8E}', '\u{C90}', (1, 1)),
+    ('\u{C92}', '\u{CA8}', (1, 1)),
+    ('\u{CAA}', '\u{CB3}', (1, 1)),
+    ('\u{CB5}', '\u{CB9}', (1, 1)),
+    ('\u{CBC}', '\u{CBD}', (4, 0)),
+    ('\u{CBE}', '\u{CC4}', (1, 1)),
+    ('\u{CC6}', '\u{CC8}', (1, 1)),
+    ('\u{CCA}', '\u{CCD}', (1, 1)),
+    ('\u{CD5}', '\u{CD6}', (1, 1)),
+    ('\u{CDD}', '\u{CDD}', (14, 0)),
+    ('\u{CDE}', '\u{CDE}', (1, 1)),
+    ('\u{CE0}', '\u{CE1}', (1, 1)),
+    ('\u{CE2}', '\u{CE3}', (5, 0)),
+    ('\u{CE6}', '\u{CEF}', (1, 1)),
+    ('\u{CF1}', '\u{CF2}', (5, 0)),
+    ('\u{D00}', '\u{D00}', (10, 0)),
+    ('\u{D01}', '\u{D01}', (7, 0)),
+    ('\u{D02}', '\u{D03}', (1, 1)),
+    ('\u{D04}', '\u{D04}', (13, 0)),
+    ('\u{D05}', '\u{D0C}', (1, 1)),
+    ('\u{D0E}', '\u{D10}', (1, 1)),
+    ('\u{D12}', '\u{D28}', (1, 1)),
+    ('\u{D29}', '\u{D29}', (6, 0)),
+    ('\u{D2A}', '\u{D39}', (1, 1)),
+    ('\u{D3A}', '\u{D3A}', (6, 0)),
+    ('\u{D3B}', '\u{D3C}', (10, 0)),
+    ('\u{D3D}', '\u{D3D}', (5, 1)),
+    ('\u{D3E}', '\u{D43}', (1, 1)),
+    ('\u{D44}', '\u{D44}', (5, 1)),
+    ('\u{D46}', '\u{D48}', (1, 1)),
+    ('\u{D4A}', '\u{D4D}', (1, 1)),
+    ('\u{D4E}', '\u{D4E}', (6, 0)),
+    ('\u{D4F}', '\u{D4F}', (9, 0)),
+    ('\u{D54}', '\u{D56}', (9, 0)),
+    ('\u{D57}', '\u{D57}', (1, 1)),
+    ('\u{D58}', '\u{D5E}', (9, 0)),
+    ('\u{D5F}', '\u{D5F}', (8, 0)),
+    ('\u{D60}', '\u{D61}', (1, 1)),
+    ('\u{D62}', '\u{D63}', (5, 1)),
+    ('\u{D66}', '\u{D6F}', (1, 1)),
+    ('\u{D70}', '\u{D75}', (5, 1)),
+    ('\u{D76}', '\u{D78}', (9, 0)),
+    ('\u{D79}', '\u{D7F}', (5, 1)),
+    ('\u{D81}', '\u{D81}', (13, 0)),
+    ('\u{D82}', '\u{D83}', (3, 0)),
+    ('\u{D85}', '\u{D96}', (3, 0)),
+    ('\u{D9A}', '\u{DB1}', (3, 0)),
+    ('\u{DB3}', '\u{DBB}', (3, 0)),
+    ('\u{DBD}', '\u{DBD}', (3, 0)),
+    ('\u{DC0}', '\u{DC6}', (3, 0)),
+    ('\u{DCA}', '\u{DCA}', (3, 0)),
+    ('\u{DCF}', '\u{DD4}', (3, 0)),
+    ('\u{DD6}', '\u{DD6}', (3, 0)),
+    ('\u{DD8}', '\u{DDF}', (3, 0)),
+    ('\u{DE6}', '\u{DEF}', (7, 0)),
+    ('\u{DF2}', '\u{DF4}', (3, 0)),
+    ('\u{E01}', '\u{E3A}', (1, 1)),
+    ('\u{E3F}', '\u{E5B}', (1, 1)),
+    ('\u{E81}', '\u{E82}', (1, 1)),
+    ('\u{E84}', '\u{E84}', (1, 1)),
+    ('\u{E86}', '\u{E86}', (12, 0)),
+    ('\u{E87}', '\u{E88}', (1, 1)),
+    ('\u{E89}', '\u{E89}', (12, 0)),
+    ('\u{E8A}', '\u{E8A}', (1, 1)),
+    ('\u{E8C}', '\u{E8C}', (12, 0)),
+    ('\u{E8D}', '\u{E8D}', (1, 1)),
+    ('\u{E8E}', '\u{E93}', (12, 0)),
+    ('\u{E94}', '\u{E97}', (1, 1)),
+    ('\u{E98}', '\u{E98}', (12, 0)),
+    ('\u{E99}', '\u{E9F}', (1, 1)),
+    ('\u{EA0}', '\u{EA0}', (12, 0)),
+    ('\u{EA1}', '\u{EA3}', (1, 1)),
+    ('\u{EA5}', '\u{EA5}', (1, 1)),
+    ('\u{EA7}', '\u{EA7}', (1, 1)),
+    ('\u{EA8}', '\u{EA9}', (12, 0)),
+    ('\u{EAA}', '\u{EAB}', (1, 1)),
+    ('\u{EAC}', '\u{EAC}', (12, 0)),
+    ('\u{EAD}', '\u{EB9}', (1, 1)),
+    ('\u{EBA}', '\u{EBA}', (12, 0)),
+    ('\u{EBB}', '\u{EBD}', (1, 1)),
+    ('\u{EC0}', '\u{EC4}', (1, 1)),
+    ('\u{EC6}', '\u{EC6}', (1, 1)),
+    ('\u{EC8}', '\u{ECD}', (1, 1)),
+    ('\u{ED0}', '\u{ED9}', (1, 1)),
+    ('\u{EDC}', '\u{EDD}', (1, 1)),
+    ('\u{EDE}', '\u{EDF}', (6, 1)),
+    ('\u{F00}', '\u{F47}', (2, 0)),
+    ('\u{F49}', '\u{F69}', (2, 0)),
+    ('\u{F6A}', '\u{F6A}', (3, 0)),
+    ('\u{F6B}', '\u{F6C}', (5, 1)),
+    ('\u{F71}', '\u{F8B}', (2, 0)),
+    ('\u{F8C}', '\u{F8F}', (6, 0)),
+    ('\u{F90}', '\u{F95}', (2, 0)),
+    ('\u{F96}', '\u{F96}', (3, 0)),
+    ('\u{F97}', '\u{F97}', (2, 0)),
+    ('\u{F99}', '\u{FAD}', (2, 0)),
+    ('\u{FAE}', '\u{FB0}', (3, 0)),
+    ('\u{FB1}', '\u{FB7}', (2, 0)),
+    ('\u{FB8}', '\u{FB8}', (3, 0)),
+    ('\u{FB9}', '\u{FB9}', (2, 0)),
+    ('\u{FBA}', '\u{FBC}', (3, 0)),
+    ('\u{FBE}', '\u{FCC}', (3, 0)),
+    ('\u{FCE}', '\u{FCE}', (5, 1)),
+    ('\u{FCF}', '\u{FCF}', (3, 0)),
+    ('\u{FD0}', '\u{FD1}', (4, 1)),
+    ('\u{FD2}', '\u{FD4}', (5, 1)),
+    ('\u{FD5}', '\u{FD8}', (5, 2)),
+    ('\u{FD9}', '\u{FDA}', (6, 0)),
+    ('\u{1000}', '\u{1021}', (3, 0)),
+    ('\u{1022}', '\u{1022}', (5, 1)),
+    ('\u{1023}', '\u{1027}', (3, 0)),
+    ('\u{1028}', '\u{1028}', (5, 1)),
+    ('\u{1029}', '\u{102A}', (3, 0)),
+    ('\u{102B}', '\u{102B}', (5, 1)),
+    ('\u{102C}', '\u{1032}', (3, 0)),
+    ('\u{1033}', '\u{1035}', (5, 1)),
+    ('\u{1036}', '\u{1039}', (3, 0)),
+    ('\u{103A}', '\u{103F}', (5, 1)),
+    ('\u{1040}', '\u{1059}', (3, 0)),
+    ('\u{105A}', '\u{1099}', (5, 1)),
+    ('\u{109A}', '\u{109D}', (5, 2)),
+    ('\u{109E}', '\u{109F}', (5, 1)),
+    ('\u{10A0}', '\u{10C5}', (1, 1)),
+    ('\u{10C7}', '\u{10C7}', (6, 1)),
+    ('\u{10CD}', '\u{10CD}', (6, 1)),
+    ('\u{10D0}', '\u{10F6}', (1, 1)),
+    ('\u{10F7}', '\u{10F8}', (3, 2)),
+    ('\u{10F9}', '\u{10FA}', (4, 1)),
+    ('\u{10FB}', '\u{10FB}', (1, 1)),
+    ('\u{10FC}', '\u{10FC}', (4, 1)),
+    ('\u{10FD}', '\u{10FF}', (6, 1)),
+    ('\u{1100}', '\u{1159}', (1, 1)),
+    ('\u{115A}', '\u{115E}', (5, 2)),
+    ('\u{115F}', '\u{11A2}', (1, 1)),
+    ('\u{11A3}', '\u{11A7}', (5, 2)),
+    ('\u{11A8}', '\u{11F9}', (1, 1)),
+    ('\u{11FA}', '\u{11FF}', (5, 2)),
+    ('\u{1200}', '\u{1206}', (3, 0)),
+    ('\u{1207}', '\u{1207}', (4, 1)),
+    ('\u{1208}', '\u{1246}', (3, 0)),
+    ('\u{1247}', '\u{1247}', (4, 1)),
+    ('\u{1248}', '\u{1248}', (3, 0)),
+    ('\u{124A}', '\u{124D}', (3, 0)),
+    ('\u{1250}', '\u{1256}', (3, 0)),
+    ('\u{1258}', '\u{1258}', (3, 0)),
+    ('\u{125A}', '\u{125D}', (3, 0)),
+    ('\u{1260}', '\u{1286}', (3, 0)),
+    ('\u{1287}', '\u{1287}', (4, 1)),
+    ('\u{1288}', '\u{1288}', (3, 0)),
+    ('\u{128A}', '\u{128D}', (3, 0)),
+    ('\u{1290}', '\u{12AE}', (3, 0)),
+    ('\u{12AF}', '\u{12AF}', (4, 1)),
+    ('\u{12B0}', '\u{12B0}', (3, 0)),
+    ('\u{12B2}', '\u{12B5}', (3, 0)),
+    ('\u{12B8}', '\u{12BE}', (3, 0)),
+    ('\u{12C0}', '\u{12C0}', (3, 0)),
+    ('\u{12C2}', '\u{12C5}', (3, 0)),
+    ('\u{12C8}', '\u{12CE}', (3, 0)),
+    ('\u{12CF}', '\u{12CF}', (4, 1)),
+    ('\u{12D0}', '\u{12D6}', (3, 0)),
+    ('\u{12D8}', '\u{12EE}', (3, 0)),
+    ('\u{12EF}', '\u{12EF}', (4, 1)),
+    ('\u{12F0}', '\u{130E}', (3, 0)),
+    ('\u{130F}', '\u{130F}', (4, 1)),
+    ('\u{1310}', '\u{1310}', (3, 0)),
+    ('\u{1312}', '\u{1315}', (3, 0)),
+    ('\u{1318}', '\u{131E}', (3, 0)),
+    ('\u{131F}', '\u{131F}', (4, 1)),
+    ('\u{1320}', '\u{1346}', (3, 0)),
+    ('\u{1347}', '\u{1347}', (4, 1)),
+    ('\u{1348}', '\u{135A}', (3, 0)),
+    ('\u{135D}', '\u{135E}', (6, 0)),
+    ('\u{135F}', '\u{1360}', (4, 1)),
+    ('\u{1361}', '\u{137C}', (3, 0)),
+    ('\u{1380}', '\u{1399}', (4, 1)),
+    ('\u{13A0}', '\u{13F4}', (3, 0)),
+    ('\u{13F5}', '\u{13F5}', (8, 0)),
+    ('\u{13F8}', '\u{13FD}', (8, 0)),
+    ('\u{1400}', '\u{1400}', (5, 2)),
+    ('\u{1401}', '\u{1676}', (3, 0)),
+    ('\u{1677}', '\u{167F}', (5, 2)),
+    ('\u{1680}', '\u{169C}', (3, 0)),
+    ('\u{16A0}', '\u{16F0}', (3, 0)),
+    ('\u{16F1}', '\u{16F8}', (7, 0)),
+    ('\u{1700}', '\u{170C}', (3, 2)),
+    ('\u{170D}', '\u{170D}', (14, 0)),
+    ('\u{170E}', '\u{1714}', (3, 2)),
+    ('\u{1715}', '\u{1715}', (14, 0)),
+    ('\u{171F}', '\u{171F}', (14, 0)),
+    ('\u{1720}', '\u{1736}', (3, 2)),
+    ('\u{1740}', '\u{1753}', (3, 2)),
+    ('\u{1760}', '\u{176C}', (3, 2)),
+    ('\u{176E}', '\u{1770}', (3, 2)),
+    ('\u{1772}', '\u{1773}', (3, 2)),
+    ('\u{1780}', '\u{17DC}', (3, 0)),
+    ('\u{17DD}', '\u{17DD}', (4, 0)),
+    ('\u{17E0}', '\u{17E9}', (3, 0)),
+    ('\u{17F0}', '\u{17F9}', (4, 0)),
+    ('\u{1800}', '\u{180E}', (3, 0)),
+    ('\u{180F}', '\u{180F}', (14, 0)),
+    ('\u{1810}', '\u{1819}', (3, 0)),
+    ('\u{1820}', '\u{1877}', (3, 0)),
+    ('\u{1878}', '\u{1878}', (11, 0)),
+    ('\u{1880}', '\u{18A9}', (3, 0)),
+    ('\u{18AA}', '\u{18AA}', (5, 1)),
+    ('\u{18B0}', '\u{18F5}', (5, 2)),
+    ('\u{1900}', '\u{191C}', (4, 0)),
+    ('\u{191D}', '\u{191E}', (7, 0)),
+    ('\u{1920}', '\u{192B}', (4, 0)),
+    ('\u{1930}', '\u{193B}', (4, 0)),
+    ('\u{1940}', '\u{1940}', (4, 0)),
+    ('\u{1944}', '\u{196D}', (4, 0)),
+    ('\u{1970}', '\u{1974}', (4, 0)),
+    ('\u{1980}', '\u{19A9}', (4, 1)),
+    ('\u{19AA}', '\u{19AB}', (5, 2)),
+    ('\u{19B0}', '\u{19C9}', (4, 1)),
+    ('\u{19D0}', '\u{19D9}', (4, 1)),
+    ('\u{19DA}', '\u{19DA}', (5, 2)),
+    ('\u{19DE}', '\u{19DF}', (4, 1)),
+    ('\u{19E0}', '\u{19FF}', (4, 0)),
+    ('\u{1A00}', '\u{1A1B}', (4, 1)),
+    ('\u{1A1E}', '\u{1A1F}', (4, 1)),
+    ('\u{1A20}', '\u{1A5E}', (5, 2)),
+    ('\u{1A60}', '\u{1A7C}', (5, 2)),
+    ('\u{1A7F}', '\u{1A89}', (5, 2)),
+    ('\u{1A90}', '\u{1A99}', (5, 2)),
+    ('\u{1AA0}', '\u{1AAD}', (5, 2)),
+    ('\u{1AB0}', '\u{1ABE}', (7, 0)),
+    ('\u{1ABF}', '\u{1AC0}', (13, 0)),
+    ('\u{1AC1}', '\u{1ACE}', (14, 0)),
+    ('\u{1B00}', '\u{1B4B}', (5, 0)),
+    ('\u{1B4C}', '\u{1B4C}', (14, 0)),
+    ('\u{1B50}', '\u{1B7C}', (5, 0)),
+    ('\u{1B7D}', '\u{1B7E}', (14, 0)),
+    ('\u{1B80}', '\u{1BAA}', (5, 1)),
+    ('\u{1BAB}', '\u{1BAD}', (6, 1)),
+    ('\u{1BAE}', '\u{1BB9}', (5, 1)),
+    ('\u{1BBA}', '\u{1BBF}', (6, 1)),
+    ('\u{1BC0}', '\u{1BF3}', (6, 0)),
+    ('\u{1BFC}', '\u{1BFF}', (6, 0)),
+    ('\u{1C00}', '\u{1C37}', (5, 1)),
+    ('\u{1C3B}', '\u{1C49}', (5, 1)),
+    ('\u{1C4D}', '\u{1C7F}', (5, 1)),
+    ('\u{1C80}', '\u{1C88}', (9, 0)),
+    ('\u{1C90}', '\u{1CBA}', (11, 0)),
+    ('\u{1CBD}', '\u{1CBF}', (11, 0)),
+    ('\u{1CC0}', '\u{1CC7}', (6, 1)),
+    ('\u{1CD0}', '\u{1CF2}', (5, 2)),
+    ('\u{1CF3}', '\u{1CF6}', (6, 1)),
+    ('\u{1CF7}', '\u{1CF7}', (10, 0)),
+    ('\u{1CF8}', '\u{1CF9}', (7, 0)),
+    ('\u{1CFA}', '\u{1CFA}', (12, 0)),
+    ('\u{1D00}', '\u{1D6B}', (4, 0)),
+    ('\u{1D6C}', '\u{1DC3}', (4, 1)),
+    ('\u{1DC4}', '\u{1DCA}', (5, 0)),
+    ('\u{1DCB}', '\u{1DE6}', (5, 1)),
+    ('\u{1DE7}', '\u{1DF5}', (7, 0)),
+    ('\u{1DF6}', '\u{1DF9}', (10, 0)),
+    ('\u{1DFA}', '\u{1DFA}', (14, 0)),
+    ('\u{1DFB}', '\u{1DFB}', (9, 0)),
+    ('\u{1DFC}', '\u{1DFC}', (6, 0)),
+    ('\u{1DFD}', '\u{1DFD}', (5, 2)),
+    ('\u{1DFE}', '\u{1DFF}', (5, 0)),
+    ('\u{1E00}', '\u{1E9A}', (1, 1)),
+    ('\u{1E9B}', '\u{1E9B}', (2, 0)),
+    ('\u{1E9C}', '\u{1E9F}', (5, 1)),
+    ('\u{1EA0}', '\u{1EF9}', (1, 1)),
+    ('\u{1EFA}', '\u{1EFF}', (5, 1)),
+    ('\u{1F00}', '\u{1F15}', (1, 1)),
+    ('\u{1F18}', '\u{1F1D}', (1, 1)),
+    ('\u{1F20}', '\u{1F45}', (1, 1)),
+    ('\u{1F48}', '\u{1F4D}', (1, 1)),
+    ('\u{1F50}', '\u{1F57}', (1, 1)),
+    ('\u{1F59}', '\u{1F59}', (1, 1)),
+    ('\u{1F5B}', '\u{1F5B}', (1, 1)),
+    ('\u{1F5D}', '\u{1F5D}', (1, 1)),
+    ('\u{1F5F}', '\u{1F7D}', (1, 1)),
+    ('\u{1F80}', '\u{1FB4}', (1, 1)),
+    ('\u{1FB6}', '\u{1FC4}', (1, 1)),
+    ('\u{1FC6}', '\u{1FD3}', (1, 1)),
+    ('\u{1FD6}', '\u{1FDB}', (1, 1)),
+    ('\u{1FDD}', '\u{1FEF}', (1, 1)),
+    ('\u{1FF2}', '\u{1FF4}', (1, 1)),
+    ('\u{1FF6}', '\u{1FFE}', (1, 1)),
+    ('\u{2000}', '\u{202E}', (1, 1)),
+    ('\u{202F}', '\u{202F}', (3, 0)),
+    ('\u{2030}', '\u{2046}', (1, 1)),
+    ('\u{2047}', '\u{2047}', (3, 2)),
+    ('\u{2048}', '\u{204D}', (3, 0)),
+    ('\u{204E}', '\u{2052}', (3, 2)),
+    ('\u{2053}', '\u{2054}', (4, 0)),
+    ('\u{2055}', '\u{2056}', (4, 1)),
+    ('\u{2057}', '\u{2057}', (3, 2)),
+    ('\u{2058}', '\u{205E}', (4, 1)),
+    ('\u{205F}', '\u{2063}', (3, 2)),
+    ('\u{2064}', '\u{2064}', (5, 1)),
+    ('\u{2066}', '\u{2069}', (6, 3)),
+    ('\u{206A}', '\u{2070}', (1, 1)),
+    ('\u{2071}', '\u{2071}', (3, 2)),
+    ('\u{2074}', '\u{208E}', (1, 1)),
+    ('\u{2090}', '\u{2094}', (4, 1)),
+    ('\u{2095}', '\u{209C}', (6, 0)),
+    ('\u{20A0}', '\u{20AA}', (1, 1)),
+    ('\u{20AB}', '\u{20AB}', (2, 0)),
+    ('\u{20AC}', '\u{20AC}', (2, 1)),
+    ('\u{20AD}', '\u{20AF}', (3, 0)),
+    ('\u{20B0}', '\u{20B1}', (3, 2)),
+    ('\u{20B2}', '\u{20B5}', (4, 1)),
+    ('\u{20B6}', '\u{20B8}', (5, 2)),
+    ('\u{20B9}', '\u{20B9}', (6, 0)),
+    ('\u{20BA}', '\u{20BA}', (6, 2)),
+    ('\u{20BB}', '\u{20BD}', (7, 0)),
+    ('\u{20BE}', '\u{20BE}', (8, 0)),
+    ('\u{20BF}', '\u{20BF}', (10, 0)),
+    ('\u{20C0}', '\u{20C0}', (14, 0)),
+    ('\u{20D0}', '\u{20E1}', (1, 1)),
+    ('\u{20E2}', '\u{20E3}', (3, 0)),
+    ('\u{20E4}', '\u{20EA}', (3, 2)),
+    ('\u{20EB}', '\u{20EB}', (4, 1)),
+    ('\u{20EC}', '\u{20EF}', (5, 0)),
+    ('\u{20F0}', '\u{20F0}', (5, 1)),
+    ('\u{2100}', '\u{2138}', (1, 1)),
+    ('\u{2139}', '\u{213A}', (3, 0)),
+    ('\u{213B}', '\u{213B}', (4, 0)),
+    ('\u{213C}', '\u{213C}', (4, 1)),
+    ('\u{213D}', '\u{214B}', (3, 2)),
+    ('\u{214C}', '\u{214C}', (4, 1)),
+    ('\u{214D}', '\u{214E}', (5, 0)),
+    ('\u{214F}', '\u{214F}', (5, 1)),
+    ('\u{2150}', '\u{2152}', (5, 2)),
+    ('\u{2153}', '\u{2182}', (1, 1)),
+    ('\u{2183}', '\u{2183}', (3, 0)),
+    ('\u{2184}', '\u{2184}', (5, 0)),
+    ('\u{2185}', '\u{2188}', (5, 1)),
+    ('\u{2189}', '\u{2189}', (5, 2)),
+    ('\u{218A}', '\u{218B}', (8, 0)),
+    ('\u{2190}', '\u{21EA}', (1, 1)),
+    ('\u{21EB}', '\u{21F3}', (3, 0)),
+    ('\u{21F4}', '\u{21FF}', (3, 2)),
+    ('\u{2200}', '\u{22F1}', (1, 1)),
+    ('\u{22F2}', '\u{22FF}', (3, 2)),
+    ('\u{2300}', '\u{2300}', (1, 1)),
+    ('\u{2301}', '\u{2301}', (3, 0)),
+    ('\u{2302}', '\u{237A}', (1, 1)),
+    ('\u{237B}', '\u{237B}', (3, 0)),
+    ('\u{237C}', '\u{237C}', (3, 2)),
+    ('\u{237D}', '\u{239A}', (3, 0)),
+    ('\u{239B}', '\u{23CE}', (3, 2)),
+    ('\u{23CF}', '\u{23D0}', (4, 0)),
+    ('\u{23D1}', '\u{23DB}', (4, 1)),
+    ('\u{23DC}', '\u{23E7}', (5, 0)),
+    ('\u{23E8}', '\u{23E8}', (5, 2)),
+    ('\u{23E9}', '\u{23F3}', (6, 0)),
+    ('\u{23F4}', '\u{23FA}', (7, 0)),
+    ('\u{23FB}', '\u{23FE}', (9, 0)),
+    ('\u{23FF}', '\u{23FF}', (10, 0)),
+    ('\u{2400}', '\u{2424}', (1, 1)),
+    ('\u{2425}', '\u{2426}', (3, 0)),
+    ('\u{2440}', '\u{244A}', (1, 1)),
+    ('\u{2460}', '\u{24EA}', (1, 1)),
+    ('\u{24EB}', '\u{24FE}', (3, 2)),
+    ('\u{24FF}', '\u{24FF}', (4, 0)),
+    ('\u{2500}', '\u{2595}', (1, 1)),
+    ('\u{2596}', '\u{259F}', (3, 2)),
+    ('\u{25A0}', '\u{25EF}', (1, 1)),
+    ('\u{25F0}', '\u{25F7}', (3, 0)),
+    ('\u{25F8}', '\u{25FF}', (3, 2)),
+    ('\u{2600}', '\u{2613}', (1, 1)),
+    ('\u{2614}', '\u{2615}', (4, 0)),
+    ('\u{2616}', '\u{2617}', (3, 2)),
+    ('\u{2618}', '\u{2618}', (4, 1)),
+    ('\u{2619}', '\u{2619}', (3, 0)),
+    ('\u{261A}', '\u{266F}', (1, 1)),
+    ('\u{2670}', '\u{2671}', (3, 0)),
+    ('\u{2672}', '\u{267D}', (3, 2)),
+    ('\u{267E}', '\u{267F}', (4, 1)),
+    ('\u{2680}', '\u{2689}', (3, 2)),
+    ('\u{268A}', '\u{2691}', (4, 0)),
+    ('\u{2692}', '\u{269C}', (4, 1)),
+    ('\u{269D}', '\u{269D}', (5, 1)),
+    ('\u{269E}', '\u{269F}', (5, 2)),
+    ('\u{26A0}', '\u{26A1}', (4, 0)),
+    ('\u{26A2}', '\u{26B1}', (4, 1)),
+    ('\u{26B2}', '\u{26B2}', (5, 0)),
+    ('\u{26B3}', '\u{26BC}', (5, 1)),
+    ('\u{26BD}', '\u{26BF}', (5, 2)),
+    ('\u{26C0}', '\u{26C3}', (5, 1)),
+    ('\u{26C4}', '\u{26CD}', (5, 2)),
+    ('\u{26CE}', '\u{26CE}', (6, 0)),
+    ('\u{26CF}', '\u{26E1}', (5, 2)),
+    ('\u{26E2}', '\u{26E2}', (6, 0)),
+    ('\u{26E3}', '\u{26E3}', (5, 2)),
+    ('\u{26E4}', '\u{26E7}', (6, 0)),
+    ('\u{26E8}', '\u{26FF}', (5, 2)),
+    ('\u{2700}', '\u{2700}', (7, 0)),
+    ('\u{2701}', '\u{2704}', (1, 1)),
+    ('\u{2705}', '\u{2705}', (6, 0)),
+    ('\u{2706}', '\u{2709}', (1, 1)),
+    ('\u{270A}', '\u{270B}', (6, 0)),
+    ('\u{270C}', '\u{2727}', (1, 1)),
+    ('\u{2728}', '\u{2728}', (6, 0)),
+    ('\u{2729}', '\u{274B}', (1, 1)),
+    ('\u{274C}', '\u{274C}', (6, 0)),
+    ('\u{274D}', '\u{274D}', (1, 1)),
+    ('\u{274E}', '\u{274E}', (6, 0)),
+    ('\u{274F}', '\u{2752}', (1, 1)),
+    ('\u{2753}', '\u{2755}', (6, 0)),
+    ('\u{2756}', '\u{2756}', (1, 1)),
+    ('\u{2757}', '\u{2757}', (5, 2)),
+    ('\u{2758}', '\u{275E}', (1, 1)),
+    ('\u{275F}', '\u{2760}', (6, 0)),
+    ('\u{2761}', '\u{2767}', (1, 1)),
+    ('\u{2768}', '\u{2775}', (3, 2)),
+    ('\u{2776}', '\u{2794}', (1, 1)),
+    ('\u{2795}', '\u{2797}', (6, 0)),
+    ('\u{2798}', '\u{27AF}', (1, 1)),
+    ('\u{27B0}', '\u{27B0}', (6, 0)),
+    ('\u{27B1}', '\u{27BE}', (1, 1)),
+    ('\u{27BF}', '\u{27BF}', (6, 0)),
+    ('\u{27C0}', '\u{27C6}', (4, 1)),
+    ('\u{27C7}', '\u{27CA}', (5, 0)),
+    ('\u{27CB}', '\u{27CB}', (6, 1)),
+    ('\u{27CC}', '\u{27CC}', (5, 1)),
+    ('\u{27CD}', '\u{27CD}', (6, 1)),
+    ('\u{27CE}', '\u{27CF}', (6, 0)),
+    ('\u{27D0}', '\u{27EB}', (3, 2)),
+    ('\u{27EC}', '\u{27EF}', (5, 1)),
+    ('\u{27F0}', '\u{27FF}', (3, 2)),
+    ('\u{2800}', '\u{28FF}', (3, 0)),
+    ('\u{2900}', '\u{2AFF}', (3, 2)),
+    ('\u{2B00}', '\u{2B0D}', (4, 0)),
+    ('\u{2B0E}', '\u{2B13}', (4, 1)),
+    ('\u{2B14}', '\u{2B1A}', (5, 0)),
+    ('\u{2B1B}', '\u{2B1F}', (5, 1)),
+    ('\u{2B20}', '\u{2B23}', (5, 0)),
+    ('\u{2B24}', '\u{2B4C}', (5, 1)),
+    ('\u{2B4D}', '\u{2B4F}', (7, 0)),
+    ('\u{2B50}', '\u{2B54}', (5, 1)),
+    ('\u{2B55}', '\u{2B59}', (5, 2)),
+    ('\u{2B5A}', '\u{2B73}', (7, 0)),
+    ('\u{2B76}', '\u{2B95}', (7, 0)),
+    ('\u{2B97}', '\u{2B97}', (13, 0)),
+    ('\u{2B98}', '\u{2BB9}', (7, 0)),
+    ('\u{2BBA}', '\u{2BBC}', (11, 0)),
+    ('\u{2BBD}', '\u{2BC8}', (7, 0)),
+    ('\u{2BC9}', '\u{2BC9}', (12, 0)),
+    ('\u{2BCA}', '\u{2BD1}', (7, 0)),
+    ('\u{2BD2}', '\u{2BD2}', (10, 0)),
+    ('\u{2BD3}', '\u{2BEB}', (11, 0)),
+    ('\u{2BEC}', '\u{2BEF}', (8, 0)),
+    ('\u{2BF0}', '\u{2BFE}', (11, 0)),
+    ('\u{2BFF}', '\u{2BFF}', (12, 0)),
+    ('\u{2C00}', '\u{2C2E}', (4, 1)),
+    ('\u{2C2F}', '\u{2C2F}', (14, 0)),
+    ('\u{2C30}', '\u{2C5E}', (4, 1)),
+    ('\u{2C5F}', '\u{2C5F}', (14, 0)),
+    ('\u{2C60}', '\u{2C6C}', (5, 0)),
+    ('\u{2C6D}', '\u{2C6F}', (5, 1)),
+    ('\u{2C70}', '\u{2C70}', (5, 2)),
+    ('\u{2C71}', '\u{2C73}', (5, 1)),
+    ('\u{2C74}', '\u{2C77}', (5, 0)),
+    ('\u{2C78}', '\u{2C7D}', (5, 1)),
+    ('\u{2C7E}', '\u{2C7F}', (5, 2)),
+    ('\u{2C80}', '\u{2CEA}', (4, 1)),
+    ('\u{2CEB}', '\u{2CF1}', (5, 2)),
+    ('\u{2CF2}', '\u{2CF3}', (6, 1)),
+    ('\u{2CF9}', '\u{2D25}', (4, 1)),
+    ('\u{2D27}', '\u{2D27}', (6, 1)),
+    ('\u{2D2D}', '\u{2D2D}', (6, 1)),
+    ('\u{2D30}', '\u{2D65}', (4, 1)),
+    ('\u{2D66}', '\u{2D67}', (6, 1)),
+    ('\u{2D6F}', '\u{2D6F}', (4, 1)),
+    ('\u{2D70}', '\u{2D70}', (6, 0)),
+    ('\u{2D7F}', '\u{2D7F}', (6, 0)),
+    ('\u{2D80}', '\u{2D96}', (4, 1)),
+    ('\u{2DA0}', '\u{2DA6}', (4, 1)),
+    ('\u{2DA8}', '\u{2DAE}', (4, 1)),
+    ('\u{2DB0}', '\u{2DB6}', (4, 1)),
+    ('\u{2DB8}', '\u{2DBE}', (4, 1)),
+    ('\u{2DC0}', '\u{2DC6}', (4, 1)),
+    ('\u{2DC8}', '\u{2DCE}', (4, 1)),
+    ('\u{2DD0}', '\u{2DD6}', (4, 1)),
+    ('\u{2DD8}', '\u{2DDE}', (4, 1)),
+    ('\u{2DE0}', '\u{2DFF}', (5, 1)),
+    ('\u{2E00}', '\u{2E17}', (4, 1)),
+    ('\u{2E18}', '\u{2E1B}', (5, 1)),
+    ('\u{2E1C}', '\u{2E1D}', (4, 1)),
+    ('\u{2E1E}', '\u{2E30}', (5, 1)),
+    ('\u{2E31}', '\u{2E31}', (5, 2)),
+    ('\u{2E32}', '\u{2E3B}', (6, 1)),
+    ('\u{2E3C}', '\u{2E42}', (7, 0)),
+    ('\u{2E43}', '\u{2E44}', (9, 0)),
+    ('\u{2E45}', '\u{2E49}', (10, 0)),
+    ('\u{2E4A}', '\u{2E4E}', (11, 0)),
+    ('\u{2E4F}', '\u{2E4F}', (12, 0)),
+    ('\u{2E50}', '\u{2E52}', (13, 0)),
+    ('\u{2E53}', '\u{2E5D}', (14, 0)),
+    ('\u{2E80}', '\u{2E99}', (3, 0)),
+    ('\u{2E9B}', '\u{2EF3}', (3, 0)),
+    ('\u{2F00}', '\u{2FD5}', (3, 0)),
+    ('\u{2FF0}', '\u{2FFB}', (3, 0)),
+    ('\u{3000}', '\u{3037}', (1, 1)),
+    ('\u{3038}', '\u{303A}', (3, 0)),
+    ('\u{303B}', '\u{303D}', (3, 2)),
+    ('\u{303E}', '\u{303E}', (3, 0)),
+    ('\u{303F}', '\u{303F}', (1, 1)),
+    ('\u{3041}', '\u{3094}', (1, 1)),
+    ('\u{3095}', '\u{3096}', (3, 2)),
+    ('\u{3099}', '\u{309E}', (1, 1)),
+    ('\u{309F}', '\u{30A0}', (3, 2)),
+    ('\u{30A1}', '\u{30FE}', (1, 1)),
+    ('\u{30FF}', '\u{30FF}', (3, 2)),
+    ('\u{3105}', '\u{312C}', (1, 1)),
+    ('\u{312D}', '\u{312D}', (5, 1)),
+    ('\u{312E}', '\u{312E}', (10, 0)),
+    ('\u{312F}', '\u{312F}', (11, 0)),
+    ('\u{3131}', '\u{318E}', (1, 1)),
+    ('\u{3190}', '\u{319F}', (1, 1)),
+    ('\u{31A0}', '\u{31B7}', (3, 0)),
+    ('\u{31B8}', '\u{31BA}', (6, 0)),
+    ('\u{31BB}', '\u{31BF}', (13, 0)),
+    ('\u{31C0}', '\u{31CF}', (4, 1)),
+    ('\u{31D0}', '\u{31E3}', (5, 1)),
+    ('\u{31F0}', '\u{31FF}', (3, 2)),
+    ('\u{3200}', '\u{321C}', (1, 1)),
+    ('\u{321D}', '\u{321E}', (4, 0)),
+    ('\u{3220}', '\u{3243}', (1, 1)),
+    ('\u{3244}', '\u{324F}', (5, 2)),
+    ('\u{3250}', '\u{3250}', (4, 0)),
+    ('\u{3251}', '\u{325F}', (3, 2)),
+    ('\u{3260}', '\u{327B}', (1, 1)),
+    ('\u{327C}', '\u{327D}', (4, 0)),
+    ('\u{327E}', '\u{327E}', (4, 1)),
+    ('\u{327F}', '\u{32B0}', (1, 1)),
+    ('\u{32B1}', '\u{32BF}', (3, 2)),
+    ('\u{32C0}', '\u{32CB}', (1, 1)),
+    ('\u{32CC}', '\u{32CF}', (4, 0)),
+    ('\u{32D0}', '\u{32FE}', (1, 1)),
+    ('\u{32FF}', '\u{32FF}', (12, 1)),
+    ('\u{3300}', '\u{3376}', (1, 1)),
+    ('\u{3377}', '\u{337A}', (4, 0)),
+    ('\u{337B}', '\u{33DD}', (1, 1)),
+    ('\u{33DE}', '\u{33DF}', (4, 0)),
+    ('\u{33E0}', '\u{33FE}', (1, 1)),
+    ('\u{33FF}', '\u{33FF}', (4, 0)),
+    ('\u{3400}', '\u{4DB5}', (3, 0)),
+    ('\u{4DB6}', '\u{4DBF}', (13, 0)),
+    ('\u{4DC0}', '\u{4DFF}', (4, 0)),
+    ('\u{4E00}', '\u{9FA5}', (1, 1)),
+    ('\u{9FA6}', '\u{9FBB}', (4, 1)),
+    ('\u{9FBC}', '\u{9FC3}', (5, 1)),
+    ('\u{9FC4}', '\u{9FCB}', (5, 2)),
+    ('\u{9FCC}', '\u{9FCC}', (6, 1)),
+    ('\u{9FCD}', '\u{9FD5}', (8, 0)),
+    ('\u{9FD6}', '\u{9FEA}', (10, 0)),
+    ('\u{9FEB}', '\u{9FEF}', (11, 0)),
+    ('\u{9FF0}', '\u{9FFC}', (13, 0)),
+    ('\u{9FFD}', '\u{9FFF}', (14, 0)),
+    ('\u{A000}', '\u{A48C}', (3, 0)),
+    ('\u{A490}', '\u{A4A1}', (3, 0)),
+    ('\u{A4A2}', '\u{A4A3}', (3, 2)),
+    ('\u{A4A4}', '\u{A4B3}', (3, 0)),
+    ('\u{A4B4}', '\u{A4B4}', (3, 2)),
+    ('\u{A4B5}', '\u{A4C0}', (3, 0)),
+    ('\u{A4C1}', '\u{A4C1}', (3, 2)),
+    ('\u{A4C2}', '\u{A4C4}', (3, 0)),
+    ('\u{A4C5}', '\u{A4C5}', (3, 2)),
+    ('\u{A4C6}', '\u{A4C6}', (3, 0)),
+    ('\u{A4D0}', '\u{A4FF}', (5, 2)),
+    ('\u{A500}', '\u{A62B}', (5, 1)),
+    ('\u{A640}', '\u{A65F}', (5, 1)),
+    ('\u{A660}', '\u{A661}', (6, 0)),
+    ('\u{A662}', '\u{A673}', (5, 1)),
+    ('\u{A674}', '\u{A67B}', (6, 1)),
+    ('\u{A67C}', '\u{A697}', (5, 1)),
+    ('\u{A698}', '\u{A69D}', (7, 0)),
+    ('\u{A69E}', '\u{A69E}', (8, 0)),
+    ('\u{A69F}', '\u{A69F}', (6, 1)),
+    ('\u{A6A0}', '\u{A6F7}', (5, 2)),
+    ('\u{A700}', '\u{A716}', (4, 1)),
+    ('\u{A717}', '\u{A71A}', (5, 0)),
+    ('\u{A71B}', '\u{A71F}', (5, 1)),
+    ('\u{A720}', '\u{A721}', (5, 0)),
+    ('\u{A722}', '\u{A78C}', (5, 1)),
+    ('\u{A78D}', '\u{A78E}', (6, 0)),
+    ('\u{A78F}', '\u{A78F}', (8, 0)),
+    ('\u{A790}', '\u{A791}', (6, 0)),
+    ('\u{A792}', '\u{A793}', (6, 1)),
+    ('\u{A794}', '\u{A79F}', (7, 0)),
+    ('\u{A7A0}', '\u{A7A9}', (6, 0)),
+    ('\u{A7AA}', '\u{A7AA}', (6, 1)),
+    ('\u{A7AB}', '\u{A7AD}', (7, 0)),
+    ('\u{A7AE}', '\u{A7AE}', (9, 0)),
+    ('\u{A7AF}', '\u{A7AF}', (11, 0)),
+    ('\u{A7B0}', '\u{A7B1}', (7, 0)),
+    ('\u{A7B2}', '\u{A7B7}', (8, 0)),
+    ('\u{A7B8}', '\u{A7B9}', (11, 0)),
+    ('\u{A7BA}', '\u{A7BF}', (12, 0)),
+    ('\u{A7C0}', '\u{A7C1}', (14, 0)),
+    ('\u{A7C2}', '\u{A7C6}', (12, 0)),
+    ('\u{A7C7}', '\u{A7CA}', (13, 0)),
+    ('\u{A7D0}', '\u{A7D1}', (14, 0)),
+    ('\u{A7D3}', '\u{A7D3}', (14, 0)),
+    ('\u{A7D5}', '\u{A7D9}', (14, 0)),
+    ('\u{A7F2}', '\u{A7F4}', (14, 0)),
+    ('\u{A7F5}', '\u{A7F6}', (13, 0)),
+    ('\u{A7F7}', '\u{A7F7}', (7, 0)),
+    ('\u{A7F8}', '\u{A7F9}', (6, 1)),
+    ('\u{A7FA}', '\u{A7FA}', (6, 0)),
+    ('\u{A7FB}', '\u{A7FF}', (5, 1)),
+    ('\u{A800}', '\u{A82B}', (4, 1)),
+    ('\u{A82C}', '\u{A82C}', (13, 0)),
+    ('\u{A830}', '\u{A839}', (5, 2)),
+    ('\u{A840}', '\u{A877}', (5, 0)),
+    ('\u{A880}', '\u{A8C4}', (5, 1)),
+    ('\u{A8C5}', '\u{A8C5}', (9, 0)),
+    ('\u{A8CE}', '\u{A8D9}', (5, 1)),
+    ('\u{A8E0}', '\u{A8FB}', (5, 2)),
+    ('\u{A8FC}', '\u{A8FD}', (8, 0)),
+    ('\u{A8FE}', '\u{A8FF}', (11, 0)),
+    ('\u{A900}', '\u{A953}', (5, 1)),
+    ('\u{A95F}', '\u{A95F}', (5, 1)),
+    ('\u{A960}', '\u{A97C}', (5, 2)),
+    ('\u{A980}', '\u{A9CD}', (5, 2)),
+    ('\u{A9CF}', '\u{A9D9}', (5, 2)),
+    ('\u{A9DE}', '\u{A9DF}', (5, 2)),
+    ('\u{A9E0}', '\u{A9FE}', (7, 0)),
+    ('\u{AA00}', '\u{AA36}', (5, 1)),
+    ('\u{AA40}', '\u{AA4D}', (5, 1)),
+    ('\u{AA50}', '\u{AA59}', (5, 1)),
+    ('\u{AA5C}', '\u{AA5F}', (5, 1)),
+    ('\u{AA60}', '\u{AA7B}', (5, 2)),
+    ('\u{AA7C}', '\u{AA7F}', (7, 0)),
+    ('\u{AA80}', '\u{AAC2}', (5, 2)),
+    ('\u{AADB}', '\u{AADF}', (5, 2)),
+    ('\u{AAE0}', '\u{AAF6}', (6, 1)),
+    ('\u{AB01}', '\u{AB06}', (6, 0)),
+    ('\u{AB09}', '\u{AB0E}', (6, 0)),
+    ('\u{AB11}', '\u{AB16}', (6, 0)),
+    ('\u{AB20}', '\u{AB26}', (6, 0)),
+    ('\u{AB28}', '\u{AB2E}', (6, 0)),
+    ('\u{AB30}', '\u{AB5F}', (7, 0)),
+    ('\u{AB60}', '\u{AB63}', (8, 0)),
+    ('\u{AB64}', '\u{AB65}', (7, 0)),
+    ('\u{AB66}', '\u{AB67}', (12, 0)),
+    ('\u{AB68}', '\u{AB6B}', (13, 0)),
+    ('\u{AB70}', '\u{ABBF}', (8, 0)),
+    ('\u{ABC0}', '\u{ABED}', (5, 2)),
+    ('\u{ABF0}', '\u{ABF9}', (5, 2)),
+    ('\u{AC00}', '\u{D7A3}', (2, 0)),
+    ('\u{D7B0}', '\u{D7C6}', (5, 2)),
+    ('\u{D7CB}', '\u{D7FB}', (5, 2)),
+    ('\u{E000}', '\u{FA2D}', (1, 1)),
+    ('\u{FA2E}', '\u{FA2F}', (6, 1)),
+    ('\u{FA30}', '\u{FA6A}', (3, 2)),
+    ('\u{FA6B}', '\u{FA6D}', (5, 2)),
+    ('\u{FA70}', '\u{FAD9}', (4, 1)),
+    ('\u{FB00}', '\u{FB06}', (1, 1)),
+    ('\u{FB13}', '\u{FB17}', (1, 1)),
+    ('\u{FB1D}', '\u{FB1D}', (3, 0)),
+    ('\u{FB1E}', '\u{FB36}', (1, 1)),
+    ('\u{FB38}', '\u{FB3C}', (1, 1)),
+    ('\u{FB3E}', '\u{FB3E}', (1, 1)),
+    ('\u{FB40}', '\u{FB41}', (1, 1)),
+    ('\u{FB43}', '\u{FB44}', (1, 1)),
+    ('\u{FB46}', '\u{FBB1}', (1, 1)),
+    ('\u{FBB2}', '\u{FBC1}', (6, 0)),
+    ('\u{FBC2}', '\u{FBC2}', (14, 0)),
+    ('\u{FBD3}', '\u{FD3F}', (1, 1)),
+    ('\u{FD40}', '\u{FD4F}', (14, 0)),
+    ('\u{FD50}', '\u{FD8F}', (1, 1)),
+    ('\u{FD92}', '\u{FDC7}', (1, 1)),
+    ('\u{FDCF}', '\u{FDCF}', (14, 0)),
+    ('\u{FDD0}', '\u{FDEF}', (3, 1)),
+    ('\u{FDF0}', '\u{FDFB}', (1, 1)),
+    ('\u{FDFC}', '\u{FDFC}', (3, 2)),
+    ('\u{FDFD}', '\u{FDFD}', (4, 0)),
+    ('\u{FDFE}', '\u{FDFF}', (14, 0)),
+    ('\u{FE00}', '\u{FE0F}', (3, 2)),
+    ('\u{FE10}', '\u{FE19}', (4, 1)),
+    ('\u{FE20}', '\u{FE23}', (1, 1)),
+    ('\u{FE24}', '\u{FE26}', (5, 1)),
+    ('\u{FE27}', '\u{FE2D}', (7, 0)),
+    ('\u{FE2E}', '\u{FE2F}', (8, 0)),
+    ('\u{FE30}', '\u{FE44}', (1, 1)),
+    ('\u{FE45}', '\u{FE46}', (3, 2)),
+    ('\u{FE47}', '\u{FE48}', (4, 0)),
+    ('\u{FE49}', '\u{FE52}', (1, 1)),
+    ('\u{FE54}', '\u{FE66}', (1, 1)),
+    ('\u{FE68}', '\u{FE6B}', (1, 1)),
+    ('\u{FE70}', '\u{FE72}', (1, 1)),
+    ('\u{FE73}', '\u{FE73}', (3, 2)),
+    ('\u{FE74}', '\u{FE74}', (1, 1)),
+    ('\u{FE76}', '\u{FEFC}', (1, 1)),
+    ('\u{FEFF}', '\u{FEFF}', (1, 1)),
+    ('\u{FF01}', '\u{FF5E}', (1, 1)),
+    ('\u{FF5F}', '\u{FF60}', (3, 2)),
+    ('\u{FF61}', '\u{FFBE}', (1, 1)),
+    ('\u{FFC2}', '\u{FFC7}', (1, 1)),
+    ('\u{FFCA}', '\u{FFCF}', (1, 1)),
+    ('\u{FFD2}', '\u{FFD7}', (1, 1)),
+    ('\u{FFDA}', '\u{FFDC}', (1, 1)),
+    ('\u{FFE0}', '\u{FFE6}', (1, 1)),
+    ('\u{FFE8}', '\u{FFEE}', (1, 1)),
+    ('\u{FFF9}', '\u{FFFB}', (3, 0)),
+    ('\u{FFFC}', '\u{FFFC}', (2, 1)),
+    ('\u{FFFD}', '\u{FFFF}', (1, 1)),
+    ('\u{10000}', '\u{1000B}', (4, 0)),
+    ('\u{1000D}', '\u{10026}', (4, 0)),
+    ('\u{10028}', '\u{1003A}', (4, 0)),
+    ('\u{1003C}', '\u{1003D}', (4, 0)),
+    ('\u{1003F}', '\u{1004D}', (4, 0)),
+    ('\u{10050}', '\u{1005D}', (4, 0)),
+    ('\u{10080}', '\u{100FA}', (4, 0)),
+    ('\u{10100}', '\u{10102}', (4, 0)),
+    ('\u{10107}', '\u{10133}', (4, 0)),
+    ('\u{10137}', '\u{1013F}', (4, 0)),
+    ('\u{10140}', '\u{1018A}', (4, 1)),
+    ('\u{1018B}', '\u{1018C}', (7, 0)),
+    ('\u{1018D}', '\u{1018E}', (9, 0)),
+    ('\u{10190}', '\u{1019B}', (5, 1)),
+    ('\u{1019C}', '\u{1019C}', (13, 0)),
+    ('\u{101A0}', '\u{101A0}', (7, 0)),
+    ('\u{101D0}', '\u{101FD}', (5, 1)),
+    ('\u{10280}', '\u{1029C}', (5, 1)),
+    ('\u{102A0}', '\u{102D0}', (5, 1)),
+    ('\u{102E0}', '\u{102FB}', (7, 0)),
+    ('\u{10300}', '\u{1031E}', (3, 1)),
+    ('\u{1031F}', '\u{1031F}', (7, 0)),
+    ('\u{10320}', '\u{10323}', (3, 1)),
+    ('\u{1032D}', '\u{1032F}', (10, 0)),
+    ('\u{10330}', '\u{1034A}', (3, 1)),
+    ('\u{10350}', '\u{1037A}', (7, 0)),
+    ('\u{10380}', '\u{1039D}', (4, 0)),
+    ('\u{1039F}', '\u{1039F}', (4, 0)),
+    ('\u{103A0}', '\u{103C3}', (4, 1)),
+    ('\u{103C8}', '\u{103D5}', (4, 1)),
+    ('\u{10400}', '\u{10425}', (3, 1)),
+    ('\u{10426}', '\u{10427}', (4, 0)),
+    ('\u{10428}', '\u{1044D}', (3, 1)),
+    ('\u{1044E}', '\u{1049D}', (4, 0)),
+    ('\u{104A0}', '\u{104A9}', (4, 0)),
+    ('\u{104B0}', '\u{104D3}', (9, 0)),
+    ('\u{104D8}', '\u{104FB}', (9, 0)),
+    ('\u{10500}', '\u{10527}', (7, 0)),
+    ('\u{10530}', '\u{10563}', (7, 0)),
+    ('\u{1056F}', '\u{1056F}', (7, 0)),
+    ('\u{10570}', '\u{1057A}', (14, 0)),
+    ('\u{1057C}', '\u{1058A}', (14, 0)),
+    ('\u{1058C}', '\u{10592}', (14, 0)),
+    ('\u{10594}', '\u{10595}', (14, 0)),
+    ('\u{10597}', '\u{105A1}', (14, 0)),
+    ('\u{105A3}', '\u{105B1}', (14, 0)),
+    ('\u{105B3}', '\u{105B9}', (14, 0)),
+    ('\u{105BB}', '\u{105BC}', (14, 0)),
+    ('\u{10600}', '\u{10736}', (7, 0)),
+    ('\u{10740}', '\u{10755}', (7, 0)),
+    ('\u{10760}', '\u{10767}', (7, 0)),
+    ('\u{10780}', '\u{10785}', (14, 0)),
+    ('\u{10787}', '\u{107B0}', (14, 0)),
+    ('\u{107B2}', '\u{107BA}', (14, 0)),
+    ('\u{10800}', '\u{10805}', (4, 0)),
+    ('\u{10808}', '\u{10808}', (4, 0)),
+    ('\u{1080A}', '\u{10835}', (4, 0)),
+    ('\u{10837}', '\u{10838}', (4, 0)),
+    ('\u{1083C}', '\u{1083C}', (4, 0)),
+    ('\u{1083F}', '\u{1083F}', (4, 0)),
+    ('\u{10840}', '\u{10855}', (5, 2)),
+    ('\u{10857}', '\u{1085F}', (5, 2)),
+    ('\u{10860}', '\u{1089E}', (7, 0)),
+    ('\u{108A7}', '\u{108AF}', (7, 0)),
+    ('\u{108E0}', '\u{108F2}', (8, 0)),
+    ('\u{108F4}', '\u{108F5}', (8, 0)),
+    ('\u{108FB}', '\u{108FF}', (8, 0)),
+    ('\u{10900}', '\u{10919}', (5, 0)),
+    ('\u{1091A}', '\u{1091B}', (5, 2)),
+    ('\u{1091F}', '\u{1091F}', (5, 0)),
+    ('\u{10920}', '\u{10939}', (5, 1)),
+    ('\u{1093F}', '\u{1093F}', (5, 1)),
+    ('\u{10980}', '\u{109B7}', (6, 1)),
+    ('\u{109BC}', '\u{109BD}', (8, 0)),
+    ('\u{109BE}', '\u{109BF}', (6, 1)),
+    ('\u{109C0}', '\u{109CF}', (8, 0)),
+    ('\u{109D2}', '\u{109FF}', (8, 0)),
+    ('\u{10A00}', '\u{10A03}', (4, 1)),
+    ('\u{10A05}', '\u{10A06}', (4, 1)),
+    ('\u{10A0C}', '\u{10A13}', (4, 1)),
+    ('\u{10A15}', '\u{10A17}', (4, 1)),
+    ('\u{10A19}', '\u{10A33}', (4, 1)),
+    ('\u{10A34}', '\u{10A35}', (11, 0)),
+    ('\u{10A38}', '\u{10A3A}', (4, 1)),
+    ('\u{10A3F}', '\u{10A47}', (4, 1)),
+    ('\u{10A48}', '\u{10A48}', (11, 0)),
+    ('\u{10A50}', '\u{10A58}', (4, 1)),
+    ('\u{10A60}', '\u{10A7F}', (5, 2)),
+    ('\u{10A80}', '\u{10A9F}', (7, 0)),
+    ('\u{10AC0}', '\u{10AE6}', (7, 0)),
+    ('\u{10AEB}', '\u{10AF6}', (7, 0)),
+    ('\u{10B00}', '\u{10B35}', (5, 2)),
+    ('\u{10B39}', '\u{10B55}', (5, 2)),
+    ('\u{10B58}', '\u{10B72}', (5, 2)),
+    ('\u{10B78}', '\u{10B7F}', (5, 2)),
+    ('\u{10B80}', '\u{10B91}', (7, 0)),
+    ('\u{10B99}', '\u{10B9C}', (7, 0)),
+    ('\u{10BA9}', '\u{10BAF}', (7, 0)),
+    ('\u{10C00}', '\u{10C48}', (5, 2)),
+    ('\u{10C80}', '\u{10CB2}', (8, 0)),
+    ('\u{10CC0}', '\u{10CF2}', (8, 0)),
+    ('\u{10CFA}', '\u{10CFF}', (8, 0)),
+    ('\u{10D00}', '\u{10D27}', (11, 0)),
+    ('\u{10D30}', '\u{10D39}', (11, 0)),
+    ('\u{10E60}', '\u{10E7E}', (5, 2)),
+    ('\u{10E80}', '\u{10EA9}', (13, 0)),
+    ('\u{10EAB}', '\u{10EAD}', (13, 0)),
+    ('\u{10EB0}', '\u{10EB1}', (13, 0)),
+    ('\u{10F00}', '\u{10F27}', (11, 0)),
+    ('\u{10F30}', '\u{10F59}', (11, 0)),
+    ('\u{10F70}', '\u{10F89}', (14, 0)),
+    ('\u{10FB0}', '\u{10FCB}', (13, 0)),
+    ('\u{10FE0}', '\u{10FF6}', (12, 0)),
+    ('\u{11000}', '\u{1104D}', (6, 0)),
+    ('\u{11052}', '\u{1106F}', (6, 0)),
+    ('\u{11070}', '\u{11075}', (14, 0)),
+    ('\u{1107F}', '\u{1107F}', (7, 0)),
+    ('\u{11080}', '\u{110C1}', (5, 2)),
+    ('\u{110C2}', '\u{110C2}', (14, 0)),
+    ('\u{110CD}', '\u{110CD}', (11, 0)),
+    ('\u{110D0}', '\u{110E8}', (6, 1)),
+    ('\u{110F0}', '\u{110F9}', (6, 1)),
+    ('\u{11100}', '\u{11134}', (6, 1)),
+    ('\u{11136}', '\u{11143}', (6, 1)),
+    ('\u{11144}', '\u{11146}', (11, 0)),
+    ('\u{11147}', '\u{11147}', (13, 0)),
+    ('\u{11150}', '\u{11176}', (7, 0)),
+    ('\u{11180}', '\u{111C8}', (6, 1)),
+    ('\u{111C9}', '\u{111CC}', (8, 0)),
+    ('\u{111CD}', '\u{111CD}', (7, 0)),
+    ('\u{111CE}', '\u{111CF}', (13, 0)),
+    ('\u{111D0}', '\u{111D9}', (6, 1)),
+    ('\u{111DA}', '\u{111DA}', (7, 0)),
+    ('\u{111DB}', '\u{111DF}', (8, 0)),
+    ('\u{111E1}', '\u{111F4}', (7, 0)),
+    ('\u{11200}', '\u{11211}', (7, 0)),
+    ('\u{11213}', '\u{1123D}', (7, 0)),
+    ('\u{1123E}', '\u{1123E}', (9, 0)),
+    ('\u{11280}', '\u{11286}', (8, 0)),
+    ('\u{11288}', '\u{11288}', (8, 0)),
+    ('\u{1128A}', '\u{1128D}', (8, 0)),
+    ('\u{1128F}', '\u{1129D}', (8, 0)),
+    ('\u{1129F}', '\u{112A9}', (8, 0)),
+    ('\u{112B0}', '\u{112EA}', (7, 0)),
+    ('\u{112F0}', '\u{112F9}', (7, 0)),
+    ('\u{11300}', '\u{11300}', (8, 0)),
+    ('\u{11301}', '\u{11303}', (7, 0)),
+    ('\u{11305}', '\u{1130C}', (7, 0)),
+    ('\u{1130F}', '\u{11310}', (7, 0)),
+    ('\u{11313}', '\u{11328}', (7, 0)),
+    ('\u{1132A}', '\u{11330}', (7, 0)),
+    ('\u{11332}', '\u{11333}', (7, 0)),
+    ('\u{11335}', '\u{11339}', (7, 0)),
+    ('\u{1133B}', '\u{1133B}', (11, 0)),
+    ('\u{1133C}', '\u{11344}', (7, 0)),
+    ('\u{11347}', '\u{11348}', (7, 0)),
+    ('\u{1134B}', '\u{1134D}', (7, 0)),
+    ('\u{11350}', '\u{11350}', (8, 0)),
+    ('\u{11357}', '\u{11357}', (7, 0)),
+    ('\u{1135D}', '\u{11363}', (7, 0)),
+    ('\u{11366}', '\u{1136C}', (7, 0)),
+    ('\u{11370}', '\u{11374}', (7, 0)),
+    ('\u{11400}', '\u{11459}', (9, 0)),
+    ('\u{1145A}', '\u{1145A}', (13, 0)),
+    ('\u{1145B}', '\u{1145B}', (9, 0)),
+    ('\u{1145D}', '\u{1145D}', (9, 0)),
+    ('\u{1145E}', '\u{1145E}', (11, 0)),
+    ('\u{1145F}', '\u{1145F}', (12, 0)),
+    ('\u{11460}', '\u{11461}', (13, 0)),
+    ('\u{11480}', '\u{114C7}', (7, 0)),
+    ('\u{114D0}', '\u{114D9}', (7, 0)),
+    ('\u{11580}', '\u{115B5}', (7, 0)),
+    ('\u{115B8}', '\u{115C9}', (7, 0)),
+    ('\u{115CA}', '\u{115DD}', (8, 0)),
+    ('\u{11600}', '\u{11644}', (7, 0)),
+    ('\u{11650}', '\u{11659}', (7, 0)),
+    ('\u{11660}', '\u{1166C}', (9, 0)),
+    ('\u{11680}', '\u{116B7}', (6, 1)),
+    ('\u{116B8}', '\u{116B8}', (12, 0)),
+    ('\u{116B9}', '\u{116B9}', (14, 0)),
+    ('\u{116C0}', '\u{116C9}', (6, 1)),
+    ('\u{11700}', '\u{11719}', (8, 0)),
+    ('\u{1171A}', '\u{1171A}', (11, 0)),
+    ('\u{1171D}', '\u{1172B}', (8, 0)),
+    ('\u{11730}', '\u{1173F}', (8, 0)),
+    ('\u{11740}', '\u{11746}', (14, 0)),
+    ('\u{11800}', '\u{1183B}', (11, 0)),
+    ('\u{118A0}', '\u{118F2}', (7, 0)),
+    ('\u{118FF}', '\u{118FF}', (7, 0)),
+    ('\u{11900}', '\u{11906}', (13, 0)),
+    ('\u{11909}', '\u{11909}', (13, 0)),
+    ('\u{1190C}', '\u{11913}', (13, 0)),
+    ('\u{11915}', '\u{11916}', (13, 0)),
+    ('\u{11918}', '\u{11935}', (13, 0)),
+    ('\u{11937}', '\u{11938}', (13, 0)),
+    ('\u{1193B}', '\u{11946}', (13, 0)),
+    ('\u{11950}', '\u{11959}', (13, 0)),
+    ('\u{119A0}', '\u{119A7}', (12, 0)),
+    ('\u{119AA}', '\u{119D7}', (12, 0)),
+    ('\u{119DA}', '\u{119E4}', (12, 0)),
+    ('\u{11A00}', '\u{11A47}', (10, 0)),
+    ('\u{11A50}', '\u{11A83}', (10, 0)),
+    ('\u{11A84}', '\u{11A85}', (12, 0)),
+    ('\u{11A86}', '\u{11A9C}', (10, 0)),
+    ('\u{11A9D}', '\u{11A9D}', (11, 0)),
+    ('\u{11A9E}', '\u{11AA2}', (10, 0)),
+    ('\u{11AB0}', '\u{11ABF}', (14, 0)),
+    ('\u{11AC0}', '\u{11AF8}', (7, 0)),
+    ('\u{11C00}', '\u{11C08}', (9, 0)),
+    ('\u{11C0A}', '\u{11C36}', (9, 0)),
+    ('\u{11C38}', '\u{11C45}', (9, 0)),
+    ('\u{11C50}', '\u{11C6C}', (9, 0)),
+    ('\u{11C70}', '\u{11C8F}', (9, 0)),
+    ('\u{11C92}', '\u{11CA7}', (9, 0)),
+    ('\u{11CA9}', '\u{11CB6}', (9, 0)),
+    ('\u{11D00}', '\u{11D06}', (10, 0)),
+    ('\u{11D08}', '\u{11D09}', (10, 0)),
+    ('\u{11D0B}', '\u{11D36}', (10, 0)),
+    ('\u{11D3A}', '\u{11D3A}', (10, 0)),
+    ('\u{11D3C}', '\u{11D3D}', (10, 0)),
+    ('\u{11D3F}', '\u{11D47}', (10, 0)),
+    ('\u{11D50}', '\u{11D59}', (10, 0)),
+    ('\u{11D60}', '\u{11D65}', (11, 0)),
+    ('\u{11D67}', '\u{11D68}', (11, 0)),
+    ('\u{11D6A}', '\u{11D8E}', (11, 0)),
+    ('\u{11D90}', '\u{11D91}', (11, 0)),
+    ('\u{11D93}', '\u{11D98}', (11, 0)),
+    ('\u{11DA0}', '\u{11DA9}', (11, 0)),
+    ('\u{11EE0}', '\u{11EF8}', (11, 0)),
+    ('\u{11FB0}', '\u{11FB0}', (13, 0)),
+    ('\u{11FC0}', '\u{11FF1}', (12, 0)),
+    ('\u{11FFF}', '\u{11FFF}', (12, 0)),
+    ('\u{12000}', '\u{1236E}', (5, 0)),
+    ('\u{1236F}', '\u{12398}', (7, 0)),
+    ('\u{12399}', '\u{12399}', (8, 0)),
+    ('\u{12400}', '\u{12462}', (5, 0)),
+    ('\u{12463}', '\u{1246E}', (7, 0)),
+    ('\u{12470}', '\u{12473}', (5, 0)),
+    ('\u{12474}', '\u{12474}', (7, 0)),
+    ('\u{12480}', '\u{12543}', (8, 0)),
+    ('\u{12F90}', '\u{12FF2}', (14, 0)),
+    ('\u{13000}', '\u{1342E}', (5, 2)),
+    ('\u{13430}', '\u{13438}', (12, 0)),
+    ('\u{14400}', '\u{14646}', (8, 0)),
+    ('\u{16800}', '\u{16A38}', (6, 0)),
+    ('\u{16A40}', '\u{16A5E}', (7, 0)),
+    ('\u{16A60}', '\u{16A69}', (7, 0)),
+    ('\u{16A6E}', '\u{16A6F}', (7, 0)),
+    ('\u{16A70}', '\u{16ABE}', (14, 0)),
+    ('\u{16AC0}', '\u{16AC9}', (14, 0)),
+    ('\u{16AD0}', '\u{16AED}', (7, 0)),
+    ('\u{16AF0}', '\u{16AF5}', (7, 0)),
+    ('\u{16B00}', '\u{16B45}', (7, 0)),
+    ('\u{16B50}', '\u{16B59}', (7, 0)),
+    ('\u{16B5B}', '\u{16B61}', (7, 0)),
+    ('\u{16B63}', '\u{16B77}', (7, 0)),
+    ('\u{16B7D}', '\u{16B8F}', (7, 0)),
+    ('\u{16E40}', '\u{16E9A}', (11, 0)),
+    ('\u{16F00}', '\u{16F44}', (6, 1)),
+    ('\u{16F45}', '\u{16F4A}', (12, 0)),
+    ('\u{16F4F}', '\u{16F4F}', (12, 0)),
+    ('\u{16F50}', '\u{16F7E}', (6, 1)),
+    ('\u{16F7F}', '\u{16F87}', (12, 0)),
+    ('\u{16F8F}', '\u{16F9F}', (6, 1)),
+    ('\u{16FE0}', '\u{16FE0}', (9, 0)),
+    ('\u{16FE1}', '\u{16FE1}', (10, 0)),
+    ('\u{16FE2}', '\u{16FE3}', (12, 0)),
+    ('\u{16FE4}', '\u{16FE4}', (13, 0)),
+    ('\u{16FF0}', '\u{16FF1}', (13, 0)),
+    ('\u{17000}', '\u{187EC}', (9, 0)),
+    ('\u{187ED}', '\u{187F1}', (11, 0)),
+    ('\u{187F2}', '\u{187F7}', (12, 0)),
+    ('\u{18800}', '\u{18AF2}', (9, 0)),
+    ('\u{18AF3}', '\u{18CD5}', (13, 0)),
+    ('\u{18D00}', '\u{18D08}', (13, 0)),
+    ('\u{1AFF0}', '\u{1AFF3}', (14, 0)),
+    ('\u{1AFF5}', '\u{1AFFB}', (14, 0)),
+    ('\u{1AFFD}', '\u{1AFFE}', (14, 0)),
+    ('\u{1B000}', '\u{1B001}', (6, 0)),
+    ('\u{1B002}', '\u{1B11E}', (10, 0)),
+    ('\u{1B11F}', '\u{1B122}', (14, 0)),
+    ('\u{1B150}', '\u{1B152}', (12, 0)),
+    ('\u{1B164}', '\u{1B167}', (12, 0)),
+    ('\u{1B170}', '\u{1B2FB}', (10, 0)),
+    ('\u{1BC00}', '\u{1BC6A}', (7, 0)),
+    ('\u{1BC70}', '\u{1BC7C}', (7, 0)),
+    ('\u{1BC80}', '\u{1BC88}', (7, 0)),
+    ('\u{1BC90}', '\u{1BC99}', (7, 0)),
+    ('\u{1BC9C}', '\u{1BCA3}', (7, 0)),
+    ('\u{1CF00}', '\u{1CF2D}', (14, 0)),
+    ('\u{1CF30}', '\u{1CF46}', (14, 0)),
+    ('\u{1CF50}', '\u{1CFC3}', (14, 0)),
+    ('\u{1D000}', '\u{1D0F5}', (3, 1)),
+    ('\u{1D100}', '\u{1D126}', (3, 1)),
+    ('\u{1D129}', '\u{1D129}', (5, 1)),
+    ('\u{1D12A}', '\u{1D1DD}', (3, 1)),
+    ('\u{1D1DE}', '\u{1D1E8}', (8, 0)),
+    ('\u{1D1E9}', '\u{1D1EA}', (14, 0)),
+    ('\u{1D200}', '\u{1D245}', (4, 1)),
+    ('\u{1D2E0}', '\u{1D2F3}', (11, 0)),
+    ('\u{1D300}', '\u{1D356}', (4, 0)),
+    ('\u{1D360}', '\u{1D371}', (5, 0)),
+    ('\u{1D372}', '\u{1D378}', (11, 0)),
+    ('\u{1D400}', '\u{1D454}', (3, 1)),
+    ('\u{1D456}', '\u{1D49C}', (3, 1)),
+    ('\u{1D49E}', '\u{1D49F}', (3, 1)),
+    ('\u{1D4A2}', '\u{1D4A2}', (3, 1)),
+    ('\u{1D4A5}', '\u{1D4A6}', (3, 1)),
+    ('\u{1D4A9}', '\u{1D4AC}', (3, 1)),
+    ('\u{1D4AE}', '\u{1D4B9}', (3, 1)),
+    ('\u{1D4BB}', '\u{1D4BB}', (3, 1)),
+    ('\u{1D4BD}', '\u{1D4C0}', (3, 1)),
+    ('\u{1D4C1}', '\u{1D4C1}', (4, 0)),
+    ('\u{1D4C2}', '\u{1D4C3}', (3, 1)),
+    ('\u{1D4C5}', '\u{1D505}', (3, 1)),
+    ('\u{1D507}', '\u{1D50A}', (3, 1)),
+    ('\u{1D50D}', '\u{1D514}', (3, 1)),
+    ('\u{1D516}', '\u{1D51C}', (3, 1)),
+    ('\u{1D51E}', '\u{1D539}', (3, 1)),
+    ('\u{1D53B}', '\u{1D53E}', (3, 1)),
+    ('\u{1D540}', '\u{1D544}', (3, 1)),
+    ('\u{1D546}', '\u{1D546}', (3, 1)),
+    ('\u{1D54A}', '\u{1D550}', (3, 1)),
+    ('\u{1D552}', '\u{1D6A3}', (3, 1)),
+    ('\u{1D6A4}', '\u{1D6A5}', (4, 1)),
+    ('\u{1D6A8}', '\u{1D7C9}', (3, 1)),
+    ('\u{1D7CA}', '\u{1D7CB}', (5, 0)),
+    ('\u{1D7CE}', '\u{1D7FF}', (3, 1)),
+    ('\u{1D800}', '\u{1DA8B}', (8, 0)),
+    ('\u{1DA9B}', '\u{1DA9F}', (8, 0)),
+    ('\u{1DAA1}', '\u{1DAAF}', (8, 0)),
+    ('\u{1DF00}', '\u{1DF1E}', (14, 0)),
+    ('\u{1E000}', '\u{1E006}', (9, 0)),
+    ('\u{1E008}', '\u{1E018}', (9, 0)),
+    ('\u{1E01B}', '\u{1E021}', (9, 0)),
+    ('\u{1E023}', '\u{1E024}', (9, 0)),
+    ('\u{1E026}', '\u{1E02A}', (9, 0)),
+    ('\u{1E100}', '\u{1E12C}', (12, 0)),
+    ('\u{1E130}', '\u{1E13D}', (12, 0)),
+    ('\u{1E140}', '\u{1E149}', (12, 0)),
+    ('\u{1E14E}', '\u{1E14F}', (12, 0)),
+    ('\u{1E290}', '\u{1E2AE}', (14, 0)),
+    ('\u{1E2C0}', '\u{1E2F9}', (12, 0)),
+    ('\u{1E2FF}', '\u{1E2FF}', (12, 0)),
+    ('\u{1E7E0}', '\u{1E7E6}', (14, 0)),
+    ('\u{1E7E8}', '\u{1E7EB}', (14, 0)),
+    ('\u{1E7ED}', '\u{1E7EE}', (14, 0)),
+    ('\u{1E7F0}', '\u{1E7FE}', (14, 0)),
+    ('\u{1E800}', '\u{1E8C4}', (7, 0)),
+    ('\u{1E8C7}', '\u{1E8D6}', (7, 0)),
+    ('\u{1E900}', '\u{1E94A}', (9, 0)),
+    ('\u{1E94B}', '\u{1E94B}', (12, 0)),
+    ('\u{1E950}', '\u{1E959}', (9, 0)),
+    ('\u{1E95E}', '\u{1E95F}', (9, 0)),
+    ('\u{1EC71}', '\u{1ECB4}', (11, 0)),
+    ('\u{1ED01}', '\u{1ED3D}', (12, 0)),
+    ('\u{1EE00}', '\u{1EE03}', (6, 1)),
+    ('\u{1EE05}', '\u{1EE1F}', (6, 1)),
+    ('\u{1EE21}', '\u{1EE22}', (6, 1)),
+    ('\u{1EE24}', '\u{1EE24}', (6, 1)),
+    ('\u{1EE27}', '\u{1EE27}', (6, 1)),
+    ('\u{1EE29}', '\u{1EE32}', (6, 1)),
+    ('\u{1EE34}', '\u{1EE37}', (6, 1)),
+    ('\u{1EE39}', '\u{1EE39}', (6, 1)),
+    ('\u{1EE3B}', '\u{1EE3B}', (6, 1)),
+    ('\u{1EE42}', '\u{1EE42}', (6, 1)),
+    ('\u{1EE47}', '\u{1EE47}', (6, 1)),
+    ('\u{1EE49}', '\u{1EE49}', (6, 1)),
+    ('\u{1EE4B}', '\u{1EE4B}', (6, 1)),
+    ('\u{1EE4D}', '\u{1EE4F}', (6, 1)),
+    ('\u{1EE51}', '\u{1EE52}', (6, 1)),
+    ('\u{1EE54}', '\u{1EE54}', (6, 1)),
+    ('\u{1EE57}', '\u{1EE57}', (6, 1)),
+    ('\u{1EE59}', '\u{1EE59}', (6, 1)),
+    ('\u{1EE5B}', '\u{1EE5B}', (6, 1)),
+    ('\u{1EE5D}', '\u{1EE5D}', (6, 1)),
+    ('\u{1EE5F}', '\u{1EE5F}', (6, 1)),
+    ('\u{1EE61}', '\u{1EE62}', (6, 1)),
+    ('\u{1EE64}', '\u{1EE64}', (6, 1)),
+    ('\u{1EE67}', '\u{1EE6A}', (6, 1)),
+    ('\u{1EE6C}', '\u{1EE72}', (6, 1)),
+    ('\u{1EE74}', '\u{1EE77}', (6, 1)),
+    ('\u{1EE79}', '\u{1EE7C}', (6, 1)),
+    ('\u{1EE7E}', '\u{1EE7E}', (6, 1)),
+    ('\u{1EE80}', '\u{1EE89}', (6, 1)),
+    ('\u{1EE8B}', '\u{1EE9B}', (6, 1)),
+    ('\u{1EEA1}', '\u{1EEA3}', (6, 1)),
+    ('\u{1EEA5}', '\u{1EEA9}', (6, 1)),
+    ('\u{1EEAB}', '\u{1EEBB}', (6, 1)),
+    ('\u{1EEF0}', '\u{1EEF1}', (6, 1)),
+    ('\u{1F000}', '\u{1F02B}', (5, 1)),
+    ('\u{1F030}', '\u{1F093}', (5, 1)),
+    ('\u{1F0A0}', '\u{1F0AE}', (6, 0)),
+    ('\u{1F0B1}', '\u{1F0BE}', (6, 0)),
+    ('\u{1F0BF}', '\u{1F0BF}', (7, 0)),
+    ('\u{1F0C1}', '\u{1F0CF}', (6, 0)),
+    ('\u{1F0D1}', '\u{1F0DF}', (6, 0)),
+    ('\u{1F0E0}', '\u{1F0F5}', (7, 0)),
+    ('\u{1F100}', '\u{1F10A}', (5, 2)),
+    ('\u{1F10B}', '\u{1F10C}', (7, 0)),
+    ('\u{1F10D}', '\u{1F10F}', (13, 0)),
+    ('\u{1F110}', '\u{1F12E}', (5, 2)),
+    ('\u{1F12F}', '\u{1F12F}', (11, 0)),
+    ('\u{1F130}', '\u{1F130}', (6, 0)),
+    ('\u{1F131}', '\u{1F131}', (5, 2)),
+    ('\u{1F132}', '\u{1F13C}', (6, 0)),
+    ('\u{1F13D}', '\u{1F13D}', (5, 2)),
+    ('\u{1F13E}', '\u{1F13E}', (6, 0)),
+    ('\u{1F13F}', '\u{1F13F}', (5, 2)),
+    ('\u{1F140}', '\u{1F141}', (6, 0)),
+    ('\u{1F142}', '\u{1F142}', (5, 2)),
+    ('\u{1F143}', '\u{1F145}', (6, 0)),
+    ('\u{1F146}', '\u{1F146}', (5, 2)),
+    ('\u{1F147}', '\u{1F149}', (6, 0)),
+    ('\u{1F14A}', '\u{1F14E}', (5, 2)),
+    ('\u{1F14F}', '\u{1F156}', (6, 0)),
+    ('\u{1F157}', '\u{1F157}', (5, 2)),
+    ('\u{1F158}', '\u{1F15E}', (6, 0)),
+    ('\u{1F15F}', '\u{1F15F}', (5, 2)),
+    ('\u{1F160}', '\u{1F169}', (6, 0)),
+    ('\u{1F16A}', '\u{1F16B}', (6, 1)),
+    ('\u{1F16C}', '\u{1F16C}', (12, 0)),
+    ('\u{1F16D}', '\u{1F16F}', (13, 0)),
+    ('\u{1F170}', '\u{1F178}', (6, 0)),
+    ('\u{1F179}', '\u{1F179}', (5, 2)),
+    ('\u{1F17A}', '\u{1F17A}', (6, 0)),
+    ('\u{1F17B}', '\u{1F17C}', (5, 2)),
+    ('\u{1F17D}', '\u{1F17E}', (6, 0)),
+    ('\u{1F17F}', '\u{1F17F}', (5, 2)),
+    ('\u{1F180}', '\u{1F189}', (6, 0)),
+    ('\u{1F18A}', '\u{1F18D}', (5, 2)),
+    ('\u{1F18E}', '\u{1F18F}', (6, 0)),
+    ('\u{1F190}', '\u{1F190}', (5, 2)),
+    ('\u{1F191}', '\u{1F19A}', (6, 0)),
+    ('\u{1F19B}', '\u{1F1AC}', (9, 0)),
+    ('\u{1F1AD}', '\u{1F1AD}', (13, 0)),
+    ('\u{1F1E6}', '\u{1F1FF}', (6, 0)),
+    ('\u{1F200}', '\u{1F200}', (5, 2)),
+    ('\u{1F201}', '\u{1F202}', (6, 0)),
+    ('\u{1F210}', '\u{1F231}', (5, 2)),
+    ('\u{1F232}', '\u{1F23A}', (6, 0)),
+    ('\u{1F23B}', '\u{1F23B}', (9, 0)),
+    ('\u{1F240}', '\u{1F248}', (5, 2)),
+    ('\u{1F250}', '\u{1F251}', (6, 0)),
+    ('\u{1F260}', '\u{1F265}', (10, 0)),
+    ('\u{1F300}', '\u{1F320}', (6, 0)),
+    ('\u{1F321}', '\u{1F32C}', (7, 0)),
+    ('\u{1F32D}', '\u{1F32F}', (8, 0)),
+    ('\u{1F330}', '\u{1F335}', (6, 0)),
+    ('\u{1F336}', '\u{1F336}', (7, 0)),
+    ('\u{1F337}', '\u{1F37C}', (6, 0)),
+    ('\u{1F37D}', '\u{1F37D}', (7, 0)),
+    ('\u{1F37E}', '\u{1F37F}', (8, 0)),
+    ('\u{1F380}', '\u{1F393}', (6, 0)),
+    ('\u{1F394}', '\u{1F39F}', (7, 0)),
+    ('\u{1F3A0}', '\u{1F3C4}', (6, 0)),
+    ('\u{1F3C5}', '\u{1F3C5}', (7, 0)),
+    ('\u{1F3C6}', '\u{1F3CA}', (6, 0)),
+    ('\u{1F3CB}', '\u{1F3CE}', (7, 0)),
+    ('\u{1F3CF}', '\u{1F3D3}', (8, 0)),
+    ('\u{1F3D4}', '\u{1F3DF}', (7, 0)),
+    ('\u{1F3E0}', '\u{1F3F0}', (6, 0)),
+    ('\u{1F3F1}', '\u{1F3F7}', (7, 0)),
+    ('\u{1F3F8}', '\u{1F3FF}', (8, 0)),
+    ('\u{1F400}', '\u{1F43E}', (6, 0)),
+    ('\u{1F43F}', '\u{1F43F}', (7, 0)),
+    ('\u{1F440}', '\u{1F440}', (6, 0)),
+    ('\u{1F441}', '\u{1F441}', (7, 0)),
+    ('\u{1F442}', '\u{1F4F7}', (6, 0)),
+    ('\u{1F4F8}', '\u{1F4F8}', (7, 0)),
+    ('\u{1F4F9}', '\u{1F4FC}', (6, 0)),
+    ('\u{1F4FD}', '\u{1F4FE}', (7, 0)),
+    ('\u{1F4FF}', '\u{1F4FF}', (8, 0)),
+    ('\u{1F500}', '\u{1F53D}', (6, 0)),
+    ('\u{1F53E}', '\u{1F53F}', (7, 0)),
+    ('\u{1F540}', '\u{1F543}', (6, 1)),
+    ('\u{1F544}', '\u{1F54A}', (7, 0)),
+    ('\u{1F54B}', '\u{1F54F}', (8, 0)),
+    ('\u{1F550}', '\u{1F567}', (6, 0)),
+    ('\u{1F568}', '\u{1F579}', (7, 0)),
+    ('\u{1F57A}', '\u{1F57A}', (9, 0)),
+    ('\u{1F57B}', '\u{1F5A3}', (7, 0)),
+    ('\u{1F5A4}', '\u{1F5A4}', (9, 0)),
+    ('\u{1F5A5}', '\u{1F5FA}', (7, 0)),
+    ('\u{1F5FB}', '\u{1F5FF}', (6, 0)),
+    ('\u{1F600}', '\u{1F600}', (6, 1)),
+    ('\u{1F601}', '\u{1F610}', (6, 0)),
+    ('\u{1F611}', '\u{1F611}', (6, 1)),
+    ('\u{1F612}', '\u{1F614}', (6, 0)),
+    ('\u{1F615}', '\u{1F615}', (6, 1)),
+    ('\u{1F616}', '\u{1F616}', (6, 0)),
+    ('\u{1F617}', '\u{1F617}', (6, 1)),
+    ('\u{1F618}', '\u{1F618}', (6, 0)),
+    ('\u{1F619}', '\u{1F619}', (6, 1)),
+    ('\u{1F61A}', '\u{1F61A}', (6, 0)),
+    ('\u{1F61B}', '\u{1F61B}', (6, 1)),
+    ('\u{1F61C}', '\u{1F61E}', (6, 0)),
+    ('\u{1F61F}', '\u{1F61F}', (6, 1)),
+    ('\u{1F620}', '\u{1F625}', (6, 0)),
+    ('\u{1F626}', '\u{1F627}', (6, 1)),
+    ('\u{1F628}', '\u{1F62B}', (6, 0)),
+    ('\u{1F62C}', '\u{1F62C}', (6, 1)),
+    ('\u{1F62D}', '\u{1F62D}', (6, 0)),
+    ('\u{1F62E}', '\u{1F62F}', (6, 1)),
+    ('\u{1F630}', '\u{1F633}', (6, 0)),
+    ('\u{1F634}', '\u{1F634}', (6, 1)),
+    ('\u{1F635}', '\u{1F640}', (6, 0)),
+    ('\u{1F641}', '\u{1F642}', (7, 0)),
+    ('\u{1F643}', '\u{1F644}', (8, 0)),
+    ('\u{1F645}', '\u{1F64F}', (6, 0)),
+    ('\u{1F650}', '\u{1F67F}', (7, 0)),
+    ('\u{1F680}', '\u{1F6C5}', (6, 0)),
+    ('\u{1F6C6}', '\u{1F6CF}', (7, 0)),
+    ('\u{1F6D0}', '\u{1F6D0}', (8, 0)),
+    ('\u{1F6D1}', '\u{1F6D2}', (9, 0)),
+    ('\u{1F6D3}', '\u{1F6D4}', (10, 0)),
+    ('\u{1F6D5}', '\u{1F6D5}', (12, 0)),
+    ('\u{1F6D6}', '\u{1F6D7}', (13, 0)),
+    ('\u{1F6DD}', '\u{1F6DF}', (14, 0)),
+    ('\u{1F6E0}', '\u{1F6EC}', (7, 0)),
+    ('\u{1F6F0}', '\u{1F6F3}', (7, 0)),
+    ('\u{1F6F4}', '\u{1F6F6}', (9, 0)),
+    ('\u{1F6F7}', '\u{1F6F8}', (10, 0)),
+    ('\u{1F6F9}', '\u{1F6F9}', (11, 0)),
+    ('\u{1F6FA}', '\u{1F6FA}', (12, 0)),
+    ('\u{1F6FB}', '\u{1F6FC}', (13, 0)),
+    ('\u{1F700}', '\u{1F773}', (6, 0)),
+    ('\u{1F780}', '\u{1F7D4}', (7, 0)),
+    ('\u{1F7D5}', '\u{1F7D8}', (11, 0)),
+    ('\u{1F7E0}', '\u{1F7EB}', (12, 0)),
+    ('\u{1F7F0}', '\u{1F7F0}', (14, 0)),
+    ('\u{1F800}', '\u{1F80B}', (7, 0)),
+    ('\u{1F810}', '\u{1F847}', (7, 0)),
+    ('\u{1F850}', '\u{1F859}', (7, 0)),
+    ('\u{1F860}', '\u{1F887}', (7, 0)),
+    ('\u{1F890}', '\u{1F8AD}', (7, 0)),
+    ('\u{1F8B0}', '\u{1F8B1}', (13, 0)),
+    ('\u{1F900}', '\u{1F90B}', (10, 0)),
+    ('\u{1F90C}', '\u{1F90C}', (13, 0)),
+    ('\u{1F90D}', '\u{1F90F}', (12, 0)),
+    ('\u{1F910}', '\u{1F918}', (8, 0)),
+    ('\u{1F919}', '\u{1F91E}', (9, 0)),
+    ('\u{1F91F}', '\u{1F91F}', (10, 0)),
+    ('\u{1F920}', '\u{1F927}', (9, 0)),
+    ('\u{1F928}', '\u{1F92F}', (10, 0)),
+    ('\u{1F930}', '\u{1F930}', (9, 0)),
+    ('\u{1F931}', '\u{1F932}', (10, 0)),
+    ('\u{1F933}', '\u{1F93E}', (9, 0)),
+    ('\u{1F93F}', '\u{1F93F}', (12, 0)),
+    ('\u{1F940}', '\u{1F94B}', (9, 0)),
+    ('\u{1F94C}', '\u{1F94C}', (10, 0)),
+    ('\u{1F94D}', '\u{1F94F}', (11, 0)),
+    ('\u{1F950}', '\u{1F95E}', (9, 0)),
+    ('\u{1F95F}', '\u{1F96B}', (10, 0)),
+    ('\u{1F96C}', '\u{1F970}', (11, 0)),
+    ('\u{1F971}', '\u{1F971}', (12, 0)),
+    ('\u{1F972}', '\u{1F972}', (13, 0)),
+    ('\u{1F973}', '\u{1F976}', (11, 0)),
+    ('\u{1F977}', '\u{1F978}', (13, 0)),
+    ('\u{1F979}', '\u{1F979}', (14, 0)),
+    ('\u{1F97A}', '\u{1F97A}', (11, 0)),
+    ('\u{1F97B}', '\u{1F97B}', (12, 0)),
+    ('\u{1F97C}', '\u{1F97F}', (11, 0)),
+    ('\u{1F980}', '\u{1F984}', (8, 0)),
+    ('\u{1F985}', '\u{1F991}', (9, 0)),
+    ('\u{1F992}', '\u{1F997}', (10, 0)),
+    ('\u{1F998}', '\u{1F9A2}', (11, 0)),
+    ('\u{1F9A3}', '\u{1F9A4}', (13, 0)),
+    ('\u{1F9A5}', '\u{1F9AA}', (12, 0)),
+    ('\u{1F9AB}', '\u{1F9AD}', (13, 0)),
+    ('\u{1F9AE}', '\u{1F9AF}', (12, 0)),
+    ('\u{1F9B0}', '\u{1F9B9}', (11, 0)),
+    ('\u{1F9BA}', '\u{1F9BF}', (12, 0)),
+    ('\u{1F9C0}', '\u{1F9C0}', (8, 0)),
+    ('\u{1F9C1}', '\u{1F9C2}', (11, 0)),
+    ('\u{1F9C3}', '\u{1F9CA}', (12, 0)),
+    ('\u{1F9CB}', '\u{1F9CB}', (13, 0)),
+    ('\u{1F9CC}', '\u{1F9CC}', (14, 0)),
+    ('\u{1F9CD}', '\u{1F9CF}', (12, 0)),
+    ('\u{1F9D0}', '\u{1F9E6}', (10, 0)),
+    ('\u{1F9E7}', '\u{1F9FF}', (11, 0)),
+    ('\u{1FA00}', '\u{1FA53}', (12, 0)),
+    ('\u{1FA60}', '\u{1FA6D}', (11, 0)),
+    ('\u{1FA70}', '\u{1FA73}', (12, 0)),
+    ('\u{1FA74}', '\u{1FA74}', (13, 0)),
+    ('\u{1FA78}', '\u{1FA7A}', (12, 0)),
+    ('\u{1FA7B}', '\u{1FA7C}', (14, 0)),
+    ('\u{1FA80}', '\u{1FA82}', (12, 0)),
+    ('\u{1FA83}', '\u{1FA86}', (13, 0)),
+    ('\u{1FA90}', '\u{1FA95}', (12, 0)),
+    ('\u{1FA96}', '\u{1FAA8}', (13, 0)),
+    ('\u{1FAA9}', '\u{1FAAC}', (14, 0)),
+    ('\u{1FAB0}', '\u{1FAB6}', (13, 0)),
+    ('\u{1FAB7}', '\u{1FABA}', (14, 0)),
+    ('\u{1FAC0}', '\u{1FAC2}', (13, 0)),
+    ('\u{1FAC3}', '\u{1FAC5}', (14, 0)),
+    ('\u{1FAD0}', '\u{1FAD6}', (13, 0)),
+    ('\u{1FAD7}', '\u{1FAD9}', (14, 0)),
+    ('\u{1FAE0}', '\u{1FAE7}', (14, 0)),
+    ('\u{1FAF0}', '\u{1FAF6}', (14, 0)),
+    ('\u{1FB00}', '\u{1FB92}', (13, 0)),
+    ('\u{1FB94}', '\u{1FBCA}', (13, 0)),
+    ('\u{1FBF0}', '\u{1FBF9}', (13, 0)),
+    ('\u{1FFFE}', '\u{1FFFF}', (2, 0)),
+    ('\u{20000}', '\u{2A6D6}', (3, 1)),
+    ('\u{2A6D7}', '\u{2A6DD}', (13, 0)),
+    ('\u{2A6DE}', '\u{2A6DF}', (14, 0)),
+    ('\u{2A700}', '\u{2B734}', (5, 2)),
+    ('\u{2B735}', '\u{2B738}', (14, 0)),
+    ('\u{2B740}', '\u{2B81D}', (6, 0)),
+    ('\u{2B820}', '\u{2CEA1}', (8, 0)),
+    ('\u{2CEB0}', '\u{2EBE0}', (10, 0)),
+    ('\u{2F800}', '\u{2FA1D}', (3, 1)),
+    ('\u{2FFFE}', '\u{2FFFF}', (2, 0)),
+    ('\u{30000}', '\u{3134A}', (13, 0)),
+    ('\u{3FFFE}', '\u{3FFFF}', (2, 0)),
+    ('\u{4FFFE}', '\u{4FFFF}', (2, 0)),
+    ('\u{5FFFE}', '\u{5FFFF}', (2, 0)),
+    ('\u{6FFFE}', '\u{6FFFF}', (2, 0)),
+    ('\u{7FFFE}', '\u{7FFFF}', (2, 0)),
+    ('\u{8FFFE}', '\u{8FFFF}', (2, 0)),
+    ('\u{9FFFE}', '\u{9FFFF}', (2, 0)),
+    ('\u{AFFFE}', '\u{AFFFF}', (2, 0)),
+    ('\u{BFFFE}', '\u{BFFFF}', (2, 0)),
+    ('\u{CFFFE}', '\u{CFFFF}', (2, 0)),
+    ('\u{DFFFE}', '\u{DFFFF}', (2, 0)),
+    ('\u{E0001}', '\u{E0001}', (3, 1)),
+    ('\u{E0020}', '\u{E007F}', (3, 1)),
+    ('\u{E0100}', '\u{E01EF}', (4, 0)),
+    ('\u{EFFFE}', '\u{10FFFF}', (2, 0)),
+];
+
+/// Ranges of chars that are not non-joining and their joining type, sorted by code point.
+pub(crate) static JOINING_TYPE: &[(char, char, JoiningType)] = &[
+    ('\u{AD}', '\u{AD}', JoiningType::Transparent),
+    ('\u{300}', '\u{36F}', JoiningType::Transparent),
+    ('\u{483}', '\u{489}', JoiningType::Transparent),
+    ('\u{591}', '\u{5BD}', JoiningType::Transparent),
+    ('\u{5BF}', '\u{5BF}', JoiningType::Transparent),
+    ('\u{5C1}', '\u{5C2}', JoiningType::Transparent),
+    ('\u{5C4}', '\u{5C5}', JoiningType::Transparent),
+    ('\u{5C7}', '\u{5C7}', JoiningType::Transparent),
+    ('\u{610}', '\u{61A}', JoiningType::Transparent),
+    ('\u{61C}', '\u{61C}', JoiningType::Transparent),
+    ('\u{620}', '\u{620}', JoiningType::DualJoining),
+    ('\u{622}', '\u{625}', JoiningType::RightJoining),
+    ('\u{626}', '\u{626}', JoiningType::DualJoining),
+    ('\u{627}', '\u{627}', JoiningType::RightJoining),
+    ('\u{628}', '\u{628}', JoiningType::DualJoining),
+    ('\u{629}', '\u{629}', JoiningType::RightJoining),
+    ('\u{62A}', '\u{62E}', JoiningType::DualJoining),
+    ('\u{62F}', '\u{632}', JoiningType::RightJoining),
+    ('\u{633}', '\u{63F}', JoiningType::DualJoining),
+    ('\u{640}', '\u{640}', JoiningType::JoinCausing),
+    ('\u{641}', '\u{647}', JoiningType::DualJoining),
+    ('\u{648}', '\u{648}', JoiningType::RightJoining),
+    ('\u{649}', '\u{64A}', JoiningType::DualJoining),
+    ('\u{64B}', '\u{65F}', JoiningType::Transparent),
+    ('\u{66E}', '\u{66F}', JoiningType::DualJoining),
+    ('\u{670}', '\u{670}', JoiningType::Transparent),
+    ('\u{671}', '\u{673}', JoiningType::RightJoining),
+    ('\u{675}', '\u{677}', JoiningType::RightJoining),
+    ('\u{678}', '\u{687}', JoiningType::DualJoining),
+    ('\u{688}', '\u{699}', JoiningType::RightJoining),
+    ('\u{69A}', '\u{6BF}', JoiningType::DualJoining),
+    ('\u{6C0}', '\u{6C0}', JoiningType::RightJoining),
+    ('\u{6C1}', '\u{6C2}', JoiningType::DualJoining),
+    ('\u{6C3}', '\u{6CB}', JoiningType::RightJoining),
+    ('\u{6CC}', '\u{6CC}', JoiningType::DualJoining),
+    ('\u{6CD}', '\u{6CD}', JoiningType::RightJoining),
+    ('\u{6CE}', '\u{6CE}', JoiningType::DualJoining),
+    ('\u{6CF}', '\u{6CF}', JoiningType::RightJoining),
+    ('\u{6D0}', '\u{6D1}', JoiningType::DualJoining),
+    ('\u{6D2}', '\u{6D3}', JoiningType::RightJoining),
+    ('\u{6D5}', '\u{6D5}', JoiningType::RightJoining),
+    ('\u{6D6}', '\u{6DC}', JoiningType::Transparent),
+    ('\u{6DF}', '\u{6E4}', JoiningType::Transparent),
+    ('\u{6E7}', '\u{6E8}', JoiningType::Transparent),
+    ('\u{6EA}', '\u{6ED}', JoiningType::Transparent),
+    ('\u{6EE}', '\u{6EF}', JoiningType::RightJoining),
+    ('\u{6FA}', '\u{6FC}', JoiningType::DualJoining),
+    ('\u{6FF}', '\u{6FF}', JoiningType::DualJoining),
+    ('\u{70F}', '\u{70F}', JoiningType::Transparent),
+    ('\u{710}', '\u{710}', JoiningType::RightJoining),
+    ('\u{711}', '\u{711}', JoiningType::Transparent),
+    ('\u{712}', '\u{714}', JoiningType::DualJoining),
+    ('\u{715}', '\u{719}', JoiningType::RightJoining),
+    ('\u{71A}', '\u{71D}', JoiningType::DualJoining),
+    ('\u{71E}', '\u{71E}', JoiningType::RightJoining),
+    ('\u{71F}', '\u{727}', JoiningType::DualJoining),
+    ('\u{728}', '\u{728}', JoiningType::RightJoining),
+    ('\u{729}', '\u{729}', JoiningType::DualJoining),
+    ('\u{72A}', '\u{72A}', JoiningType::RightJoining),
+    ('\u{72B}', '\u{72B}', JoiningType::DualJoining),
+    ('\u{72C}', '\u{72C}', JoiningType::RightJoining),
+    ('\u{72D}', '\u{72E}', JoiningType::DualJoining),
+    ('\u{72F}', '\u{72F}', JoiningType::RightJoining),
+    ('\u{730}', '\u{74A}', JoiningType::Transparent),
+    ('\u{74D}', '\u{74D}', JoiningType::RightJoining),
+    ('\u{74E}', '\u{758}', JoiningType::DualJoining),
+    ('\u{759}', '\u{75B}', JoiningType::RightJoining),
+    ('\u{75C}', '\u{76A}', JoiningType::DualJoining),
+    ('\u{76B}', '\u{76C}', JoiningType::RightJoining),
+    ('\u{76D}', '\u{770}', JoiningType::DualJoining),
+    ('\u{771}', '\u{771}', JoiningType::RightJoining),
+    ('\u{772}', '\u{772}', JoiningType::DualJoining),
+    ('\u{773}', '\u{774}', JoiningType::RightJoining),
+    ('\u{775}', '\u{777}', JoiningType::DualJoining),
+    ('\u{778}', '\u{779}', JoiningType::RightJoining),
+    ('\u{77A}', '\u{77F}', JoiningType::DualJoining),
+    ('\u{7A6}', '\u{7B0}', JoiningType::Transparent),
+    ('\u{7CA}', '\u{7EA}', JoiningType::DualJoining),
+    ('\u{7EB}', '\u{7F3}', JoiningType::Transparent),
+    ('\u{7FA}', '\u{7FA}', JoiningType::JoinCausing),
+    ('\u{7FD}', '\u{7FD}', JoiningType::Transparent),
+    ('\u{816}', '\u{819}', JoiningType::Transparent),
+    ('\u{81B}', '\u{823}', JoiningType::Transparent),
+    ('\u{825}', '\u{827}', JoiningType::Transparent),
+    ('\u{829}', '\u{82D}', JoiningType::Transparent),
+    ('\u{840}', '\u{840}', JoiningType::RightJoining),
+    ('\u{841}', '\u{845}', JoiningType::DualJoining),
+    ('\u{846}', '\u{847}', JoiningType::RightJoining),
+    ('\u{848}', '\u{848}', JoiningType::DualJoining),
+    ('\u{849}', '\u{849}', JoiningType::RightJoining),
+    ('\u{84A}', '\u{853}', JoiningType::DualJoining),
+    ('\u{854}', '\u{854}', JoiningType::RightJoining),
+    ('\u{855}', '\u{855}', JoiningType::DualJoining),
+    ('\u{856}', '\u{858}', JoiningType::RightJoining),
+    ('\u{859}', '\u{85B}', JoiningType::Transparent),
+    ('\u{860}', '\u{860}', JoiningType::DualJoining),
+    ('\u{862}', '\u{865}', JoiningType::DualJoining),
+    ('\u{867}', '\u{867}', JoiningType::RightJoining),
+    ('\u{868}', '\u{868}', JoiningType::DualJoining),
+    ('\u{869}', '\u{86A}', JoiningType::RightJoining),
+    ('\u{870}', '\u{882}', JoiningType::RightJoining),
+    ('\u{883}', '\u{885}', JoiningType::JoinCausing),
+    ('\u{886}', '\u{886}', JoiningType::DualJoining),
+    ('\u{889}', '\u{88D}', JoiningType::DualJoining),
+    ('\u{88E}', '\u{88E}', JoiningType::RightJoining),
+    ('\u{898}', '\u{89F}', JoiningType::Transparent),
+    ('\u{8A0}', '\u{8A9}', JoiningType::DualJoining),
+    ('\u{8AA}', '\u{8AC}', JoiningType::RightJoining),
+    ('\u{8AE}', '\u{8AE}', JoiningType::RightJoining),
+    ('\u{8AF}', '\u{8B0}', JoiningType::DualJoining),
+    ('\u{8B1}', '\u{8B2}', JoiningType::RightJoining),
+    ('\u{8B3}', '\u{8B8}', JoiningType::DualJoining),
+    ('\u{8B9}', '\u{8B9}', JoiningType::RightJoining),
+    ('\u{8BA}', '\u{8C8}', JoiningType::DualJoining),
+    ('\u{8CA}', '\u{8E1}', JoiningType::Transparent),
+    ('\u{8E3}', '\u{902}', JoiningType::Transparent),
+    ('\u{93A}', '\u{93A}', JoiningType::Transparent),
+    ('\u{93C}', '\u{93C}', JoiningType::Transparent),
+    ('\u{941}', '\u{948}', JoiningType::Transparent),
+    ('\u{94D}', '\u{94D}', JoiningType::Transparent),
+    ('\u{951}', '\u{957}', JoiningType::Transparent),
+    ('\u{962}', '\u{963}', JoiningType::Transparent),
+    ('\u{981}', '\u{981}', JoiningType::Transparent),
+    ('\u{9BC}', '\u{9BC}', JoiningType::Transparent),
+    ('\u{9C1}', '\u{9C4}', JoiningType::Transparent),
+    ('\u{9CD}', '\u{9CD}', JoiningType::Transparent),
+    ('\u{9E2}', '\u{9E3}', JoiningType::Transparent),
+    ('\u{9FE}', '\u{9FE}', JoiningType::Transparent),
+    ('\u{A01}', '\u{A02}', JoiningType::Transparent),
+    ('\u{A3C}', '\u{A3C}', JoiningType::Transparent),
+    ('\u{A41}', '\u{A42}', JoiningType::Transparent),
+    ('\u{A47}', '\u{A48}', JoiningType::Transparent),
+    ('\u{A4B}', '\u{A4D}', JoiningType::Transparent),
+    ('\u{A51}', '\u{A51}', JoiningType::Transparent),
+    ('\u{A70}', '\u{A71}', JoiningType::Transparent),
+    ('\u{A75}', '\u{A75}', JoiningType::Transparent),
+    ('\u{A81}', '\u{A82}', JoiningType::Transparent),
+    ('\u{ABC}', '\u{ABC}', JoiningType::Transparent),
+    ('\u{AC1}', '\u{AC5}', JoiningType::Transparent),
+    ('\u{AC7}', '\u{AC8}', JoiningType::Transparent),
+    ('\u{ACD}', '\u{ACD}', JoiningType::Transparent),
+    ('\u{AE2}', '\u{AE3}', JoiningType::Transparent),
+    ('\u{AFA}', '\u{AFF}', JoiningType::Transparent),
+    ('\u{B01}', '\u{B01}', JoiningType::Transparent),
+    ('\u{B3C}', '\u{B3C}', JoiningType::Transparent),
+    ('\u{B3F}', '\u{B3F}', JoiningType::Transparent),
+    ('\u{B41}', '\u{B44}', JoiningType::Transparent),
+    ('\u{B4D}', '\u{B4D}', JoiningType::Transparent),
+    ('\u{B55}', '\u{B56}', JoiningType::Transparent),
+    ('\u{B62}', '\u{B63}', JoiningType::Transparent),
+    ('\u{B82}', '\u{B82}', JoiningType::Transparent),
+    ('\u{BC0}', '\u{BC0}', JoiningType::Transparent),
+    ('\u{BCD}', '\u{BCD}', JoiningType::Transparent),
+    ('\u{C00}', '\u{C00}', JoiningType::Transparent),
+    ('\u{C04}', '\u{C04}', JoiningType::Transparent),
+    ('\u{C3C}', '\u{C3C}', JoiningType::Transparent),
+    ('\u{C3E}', '\u{C40}', JoiningType::Transparent),
+    ('\u{C46}', '\u{C48}', JoiningType::Transparent),
+    ('\u{C4A}', '\u{C4D}', JoiningType::Transparent),
+    ('\u{C55}', '\u{C56}', JoiningType::Transparent),
+    ('\u{C62}', '\u{C63}', JoiningType::Transparent),
+    ('\u{C81}', '\u{C81}', JoiningType::Transparent),
+    ('\u{CBC}', '\u{CBC}', JoiningType::Transparent),
+    ('\u{CBF}', '\u{CBF}', JoiningType::Transparent),
+    ('\u{CC6}', '\u{CC6}', JoiningType::Transparent),
+    ('\u{CCC}', '\u{CCD}', JoiningType::Transparent),
+    ('\u{CE2}', '\u{CE3}', JoiningType::Transparent),
+    ('\u{D00}', '\u{D01}', JoiningType::Transparent),
+    ('\u{D3B}', '\u{D3C}', JoiningType::Transparent),
+    ('\u{D41}', '\u{D44}', JoiningType::Transparent),
+    ('\u{D4D}', '\u{D4D}', JoiningType::Transparent),
+    ('\u{D62}', '\u{D63}', JoiningType::Transparent),
+    ('\u{D81}', '\u{D81}', JoiningType::Transparent),
+    ('\u{DCA}', '\u{DCA}', JoiningType::Transparent),
+    ('\u{DD2}', '\u{DD4}', JoiningType::Transparent),
+    ('\u{DD6}', '\u{DD6}', JoiningType::Transparent),
+    ('\u{E31}', '\u{E31}', JoiningType::Transparent),
+    ('\u{E34}', '\u{E3A}', JoiningType::Transparent),
+    ('\u{E47}', '\u{E4E}', JoiningType::Transparent),
+    ('\u{EB1}', '\u{EB1}', JoiningType::Transparent),
+    ('\u{EB4}', '\u{EBC}', JoiningType::Transparent),
+    ('\u{EC8}', '\u{ECD}', JoiningType::Transparent),
+    ('\u{F18}', '\u{F19}', JoiningType::Transparent),
+    ('\u{F35}', '\u{F35}', JoiningType::Transparent),
+    ('\u{F37}', '\u{F37}', JoiningType::Transparent),
+    ('\u{F39}', '\u{F39}', JoiningType::Transparent),
+    ('\u{F71}', '\u{F7E}', JoiningType::Transparent),
+    ('\u{F80}', '\u{F84}', JoiningType::Transparent),
+    ('\u{F86}', '\u{F87}', JoiningType::Transparent),
+    ('\u{F8D}', '\u{F97}', JoiningType::Transparent),
+    ('\u{F99}', '\u{FBC}', JoiningType::Transparent),
+    ('\u{FC6}', '\u{FC6}', JoiningType::Transparent),
+    ('\u{102D}', '\u{1030}', JoiningType::Transparent),
+    ('\u{1032}', '\u{1037}', JoiningType::Transparent),
+    ('\u{1039}', '\u{103A}', JoiningType::Transparent),
+    ('\u{103D}', '\u{103E}', JoiningType::Transparent),
+    ('\u{1058}', '\u{1059}', JoiningType::Transparent),
+    ('\u{105E}', '\u{1060}', JoiningType::Transparent),
+    ('\u{1071}', '\u{1074}', JoiningType::Transparent),
+    ('\u{1082}', '\u{1082}', JoiningType::Transparent),
+    ('\u{1085}', '\u{1086}', JoiningType::Transparent),
+    ('\u{108D}', '\u{108D}', JoiningType::Transparent),
+    ('\u{109D}', '\u{109D}', JoiningType::Transparent),
+    ('\u{135D}', '\u{135F}', JoiningType::Transparent),
+    ('\u{1712}', '\u{1714}', JoiningType::Transparent),
+    ('\u{1732}', '\u{1733}', JoiningType::Transparent),
+    ('\u{1752}', '\u{1753}', JoiningType::Transparent),
+    ('\u{1772}', '\u{1773}', JoiningType::Transparent),
+    ('\u{17B4}', '\u{17B5}', JoiningType::Transparent),
+    ('\u{17B7}', '\u{17BD}', JoiningType::Transparent),
+    ('\u{17C6}', '\u{17C6}', JoiningType::Transparent),
+    ('\u{17C9}', '\u{17D3}', JoiningType::Transparent),
+    ('\u{17DD}', '\u{17DD}', JoiningType::Transparent),
+    ('\u{1807}', '\u{1807}', JoiningType::DualJoining),
+    ('\u{180A}', '\u{180A}', JoiningType::JoinCausing),
+    ('\u{180B}', '\u{180D}', JoiningType::Transparent),
+    ('\u{180F}', '\u{180F}', JoiningType::Transparent),
+    ('\u{1820}', '\u{1878}', JoiningType::DualJoining),
+    ('\u{1885}', '\u{1886}', JoiningType::Transparent),
+    ('\u{1887}', '\u{18A8}', JoiningType::DualJoining),
+    ('\u{18A9}', '\u{18A9}', JoiningType::Transparent),
+    ('\u{18AA}', '\u{18AA}', JoiningType::DualJoining),
+    ('\u{1920}', '\u{1922}', JoiningType::Transparent),
+    ('\u{1927}', '\u{1928}', JoiningType::Transparent),
+    ('\u{1932}', '\u{1932}', JoiningType::Transparent),
+    ('\u{1939}', '\u{193B}', JoiningType::Transparent),
+    ('\u{1A17}', '\u{1A18}', JoiningType::Transparent),
+    ('\u{1A1B}', '\u{1A1B}', JoiningType::Transparent),
+    ('\u{1A56}', '\u{1A56}', JoiningType::Transparent),
+    ('\u{1A58}', '\u{1A5E}', JoiningType::Transparent),
+    ('\u{1A60}', '\u{1A60}', JoiningType::Transparent),
+    ('\u{1A62}', '\u{1A62}', JoiningType::Transparent),
+    ('\u{1A65}', '\u{1A6C}', JoiningType::Transparent),
+    ('\u{1A73}', '\u{1A7C}', JoiningType::Transparent),
+    ('\u{1A7F}', '\u{1A7F}', JoiningType::Transparent),
+    ('\u{1AB0}', '\u{1ACE}', JoiningType::Transparent),
+    ('\u{1B00}', '\u{1B03}', JoiningType::Transparent),
+    ('\u{1B34}', '\u{1B34}', JoiningType::Transparent),
+    ('\u{1B36}', '\u{1B3A}', JoiningType::Transparent),
+    ('\u{1B3C}', '\u{1B3C}', JoiningType::Transparent),
+    ('\u{1B42}', '\u{1B42}', JoiningType::Transparent),
+    ('\u{1B6B}', '\u{1B73}', JoiningType::Transparent),
+    ('\u{1B80}', '\u{1B81}', JoiningType::Transparent),
+    ('\u{1BA2}', '\u{1BA5}', JoiningType::Transparent),
+    ('\u{1BA8}', '\u{1BA9}', JoiningType::Transparent),
+    ('\u{1BAB}', '\u{1BAD}', JoiningType::Transparent),
+    ('\u{1BE6}', '\u{1BE6}', JoiningType::Transparent),
+    ('\u{1BE8}', '\u{1BE9}', JoiningType::Transparent),
+    ('\u{1BED}', '\u{1BED}', JoiningType::Transparent),
+    ('\u{1BEF}', '\u{1BF1}', JoiningType::Transparent),
+    ('\u{1C2C}', '\u{1C33}', JoiningType::Transparent),
+    ('\u{1C36}', '\u{1C37}', JoiningType::Transparent),
+    ('\u{1CD0}', '\u{1CD2}', JoiningType::Transparent),
+    ('\u{1CD4}', '\u{1CE0}', JoiningType::Transparent),
+    ('\u{1CE2}', '\u{1CE8}', JoiningType::Transparent),
+    ('\u{1CED}', '\u{1CED}', JoiningType::Transparent),
+    ('\u{1CF4}', '\u{1CF4}', JoiningType::Transparent),
+    ('\u{1CF8}', '\u{1CF9}', JoiningType::Transparent),
+    ('\u{1DC0}', '\u{1DFF}', JoiningType::Transparent),
+    ('\u{200B}', '\u{200B}', JoiningType::Transparent),
+    ('\u{200D}', '\u{200D}', JoiningType::JoinCausing),
+    ('\u{200E}', '\u{200F}', JoiningType::Transparent),
+    ('\u{202A}', '\u{202E}', JoiningType::Transparent),
+    ('\u{2060}', '\u{2064}', JoiningType::Transparent),
+    ('\u{206A}', '\u{206F}', JoiningType::Transparent),
+    ('\u{20D0}', '\u{20F0}', JoiningType::Transparent),
+    ('\u{2CEF}', '\u{2CF1}', JoiningType::Transparent),
+    ('\u{2D7F}', '\u{2D7F}', JoiningType::Transparent),
+    ('\u{2DE0}', '\u{2DFF}', JoiningType::Transparent),
+    ('\u{302A}', '\u{302D}', JoiningType::Transparent),
+    ('\u{3099}', '\u{309A}', JoiningType::Transparent),
+    ('\u{A66F}', '\u{A672}', JoiningType::Transparent),
+    ('\u{A674}', '\u{A67D}', JoiningType::Transparent),
+    ('\u{A69E}', '\u{A69F}', JoiningType::Transparent),
+    ('\u{A6F0}', '\u{A6F1}', JoiningType::Transparent),
+    ('\u{A802}', '\u{A802}', JoiningType::Transparent),
+    ('\u{A806}', '\u{A806}', JoiningType::Transparent),
+    ('\u{A80B}', '\u{A80B}', JoiningType::Transparent),
+    ('\u{A825}', '\u{A826}', JoiningType::Transparent),
+    ('\u{A82C}', '\u{A82C}', JoiningType::Transparent),
+    ('\u{A840}', '\u{A871}', JoiningType::DualJoining),
+    ('\u{A872}', '\u{A872}', JoiningType::LeftJoining),
+    ('\u{A8C4}', '\u{A8C5}', JoiningType::Transparent),
+    ('\u{A8E0}', '\u{A8F1}', JoiningType::Transparent),
+    ('\u{A8FF}', '\u{A8FF}', JoiningType::Transparent),
+    ('\u{A926}', '\u{A92D}', JoiningType::Transparent),
+    ('\u{A947}', '\u{A951}', JoiningType::Transparent),
+    ('\u{A980}', '\u{A982}', JoiningType::Transparent),
+    ('\u{A9B3}', '\u{A9B3}', JoiningType::Transparent),
+    ('\u{A9B6}', '\u{A9B9}', JoiningType::Transparent),
+    ('\u{A9BC}', '\u{A9BD}', JoiningType::Transparent),
+    ('\u{A9E5}', '\u{A9E5}', JoiningType::Transparent),
+    ('\u{AA29}', '\u{AA2E}', JoiningType::Transparent),
+    ('\u{AA31}', '\u{AA32}', JoiningType::Transparent),
+    ('\u{AA35}', '\u{AA36}', JoiningType::Transparent),
+    ('\u{AA43}', '\u{AA43}', JoiningType::Transparent),
+    ('\u{AA4C}', '\u{AA4C}', JoiningType::Transparent),
+    ('\u{AA7C}', '\u{AA7C}', JoiningType::Transparent),
+    ('\u{AAB0}', '\u{AAB0}', JoiningType::Transparent),
+    ('\u{AAB2}', '\u{AAB4}', JoiningType::Transparent),
+    ('\u{AAB7}', '\u{AAB8}', JoiningType::Transparent),
+    ('\u{AABE}', '\u{AABF}', JoiningType::Transparent),
+    ('\u{AAC1}', '\u{AAC1}', JoiningType::Transparent),
+    ('\u{AAEC}', '\u{AAED}', JoiningType::Transparent),
+    ('\u{AAF6}', '\u{AAF6}', JoiningType::Transparent),
+    ('\u{ABE5}', '\u{ABE5}', JoiningType::Transparent),
+    ('\u{ABE8}', '\u{ABE8}', JoiningType::Transparent),
+    ('\u{ABED}', '\u{ABED}', JoiningType::Transparent),
+    ('\u{FB1E}', '\u{FB1E}', JoiningType::Transparent),
+    ('\u{FE00}', '\u{FE0F}', JoiningType::Transparent),
+    ('\u{FE20}', '\u{FE2F}', JoiningType::Transparent),
+    ('\u{FEFF}', '\u{FEFF}', JoiningType::Transparent),
+    ('\u{FFF9}', '\u{FFFB}', JoiningType::Transparent),
+    ('\u{101FD}', '\u{101FD}', JoiningType::Transparent),
+    ('\u{102E0}', '\u{102E0}', JoiningType::Transparent),
+    ('\u{10376}', '\u{1037A}', JoiningType::Transparent),
+    ('\u{10A01}', '\u{10A03}', JoiningType::Transparent),
+    ('\u{10A05}', '\u{10A06}', JoiningType::Transparent),
+    ('\u{10A0C}', '\u{10A0F}', JoiningType::Transparent),
+    ('\u{10A38}', '\u{10A3A}', JoiningType::Transparent),
+    ('\u{10A3F}', '\u{10A3F}', JoiningType::Transparent),
+    ('\u{10AC0}', '\u{10AC4}', JoiningType::DualJoining),
+    ('\u{10AC5}', '\u{10AC5}', JoiningType::RightJoining),
+    ('\u{10AC7}', '\u{10AC7}', JoiningType::RightJoining),
+    ('\u{10AC9}', '\u{10ACA}', JoiningType::RightJoining),
+    ('\u{10ACD}', '\u{10ACD}', JoiningType::LeftJoining),
+    ('\u{10ACE}', '\u{10AD2}', JoiningType::RightJoining),
+    ('\u{10AD3}', '\u{10AD6}', JoiningType::DualJoining),
+    ('\u{10AD7}', '\u{10AD7}', JoiningType::LeftJoining),
+    ('\u{10AD8}', '\u{10ADC}', JoiningType::DualJoining),
+    ('\u{10ADD}', '\u{10ADD}', JoiningType::RightJoining),
+    ('\u{10ADE}', '\u{10AE0}', JoiningType::DualJoining),
+    ('\u{10AE1}', '\u{10AE1}', JoiningType::RightJoining),
+    ('\u{10AE4}', '\u{10AE4}', JoiningType::RightJoining),
+    ('\u{10AE5}', '\u{10AE6}', JoiningType::Transparent),
+    ('\u{10AEB}', '\u{10AEE}', JoiningType::DualJoining),
+    ('\u{10AEF}', '\u{10AEF}', JoiningType::RightJoining),
+    ('\u{10B80}', '\u{10B80}', JoiningType::DualJoining),
+    ('\u{10B81}', '\u{10B81}', JoiningType::RightJoining),
+    ('\u{10B82}', '\u{10B82}', JoiningType::DualJoining),
+    ('\u{10B83}', '\u{10B85}', JoiningType::RightJoining),
+    ('\u{10B86}', '\u{10B88}', JoiningType::DualJoining),
+    ('\u{10B89}', '\u{10B89}', JoiningType::RightJoining),
+    ('\u{10B8A}', '\u{10B8B}', JoiningType::DualJoining),
+    ('\u{10B8C}', '\u{10B8C}', JoiningType::RightJoining),
+    ('\u{10B8D}', '\u{10B8D}', JoiningType::DualJoining),
+    ('\u{10B8E}', '\u{10B8F}', JoiningType::RightJoining),
+    ('\u{10B90}', '\u{10B90}', JoiningType::DualJoining),
+    ('\u{10B91}', '\u{10B91}', JoiningType::RightJoining),
+    ('\u{10BA9}', '\u{10BAC}', JoiningType::RightJoining),
+    ('\u{10BAD}', '\u{10BAE}', JoiningType::DualJoining),
+    ('\u{10D00}', '\u{10D00}', JoiningType::LeftJoining),
+    ('\u{10D01}', '\u{10D21}', JoiningType::DualJoining),
+    ('\u{10D22}', '\u{10D22}', JoiningType::RightJoining),
+    ('\u{10D23}', '\u{10D23}', JoiningType::DualJoining),
+    ('\u{10D24}', '\u{10D27}', JoiningType::Transparent),
+    ('\u{10EAB}', '\u{10EAC}', JoiningType::Transparent),
+    ('\u{10F30}', '\u{10F32}', JoiningType::DualJoining),
+    ('\u{10F33}', '\u{10F33}', JoiningType::RightJoining),
+    ('\u{10F34}', '\u{10F44}', JoiningType::DualJoining),
+    ('\u{10F46}', '\u{10F50}', JoiningType::Transparent),
+    ('\u{10F51}', '\u{10F53}', JoiningType::DualJoining),
+    ('\u{10F54}', '\u{10F54}', JoiningType::RightJoining),
+    ('\u{10F70}', '\u{10F73}', JoiningType::DualJoining),
+    ('\u{10F74}', '\u{10F75}', JoiningType::RightJoining),
+    ('\u{10F76}', '\u{10F81}', JoiningType::DualJoining),
+    ('\u{10F82}', '\u{10F85}', JoiningType::Transparent),
+    ('\u{10FB0}', '\u{10FB0}', JoiningType::DualJoining),
+    ('\u{10FB2}', '\u{10FB3}', JoiningType::DualJoining),
+    ('\u{10FB4}', '\u{10FB6}', JoiningType::RightJoining),
+    ('\u{10FB8}', '\u{10FB8}', JoiningType::DualJoining),
+    ('\u{10FB9}', '\u{10FBA}', JoiningType::RightJoining),
+    ('\u{10FBB}', '\u{10FBC}', JoiningType::DualJoining),
+    ('\u{10FBD}', '\u{10FBD}', JoiningType::RightJoining),
+    ('\u{10FBE}', '\u{10FBF}', JoiningType::DualJoining),
+    ('\u{10FC1}', '\u{10FC1}', JoiningType::DualJoining),
+    ('\u{10FC2}', '\u{10FC3}', JoiningType::RightJoining),
+    ('\u{10FC4}', '\u{10FC4}', JoiningType::DualJoining),
+    ('\u{10FC9}', '\u{10FC9}', JoiningType::RightJoining),
+    ('\u{10FCA}', '\u{10FCA}', JoiningType::DualJoining),
+    ('\u{10FCB}', '\u{10FCB}', JoiningType::LeftJoining),
+    ('\u{11001}', '\u{11001}', JoiningType::Transparent),
+    ('\u{11038}', '\u{11046}', JoiningType::Transparent),
+    ('\u{11070}', '\u{11070}', JoiningType::Transparent),
+    ('\u{11073}', '\u{11074}', JoiningType::Transparent),
+    ('\u{1107F}', '\u{11081}', JoiningType::Transparent),
+    ('\u{110B3}', '\u{110B6}', JoiningType::Transparent),
+    ('\u{110B9}', '\u{110BA}', JoiningType::Transparent),
+    ('\u{110C2}', '\u{110C2}', JoiningType::Transparent),
+    ('\u{11100}', '\u{11102}', JoiningType::Transparent),
+    ('\u{11127}', '\u{1112B}', JoiningType::Transparent),
+    ('\u{1112D}', '\u{11134}', JoiningType::Transparent),
+    ('\u{11173}', '\u{11173}', JoiningType::Transparent),
+    ('\u{11180}', '\u{11181}', JoiningType::Transparent),
+    ('\u{111B6}', '\u{111BE}', JoiningType::Transparent),
+    ('\u{111C9}', '\u{111CC}', JoiningType::Transparent),
+    ('\u{111CF}', '\u{111CF}', JoiningType::Transparent),
+    ('\u{1122F}', '\u{11231}', JoiningType::Transparent),
+    ('\u{11234}', '\u{11234}', JoiningType::Transparent),
+    ('\u{11236}', '\u{11237}', JoiningType::Transparent),
+    ('\u{1123E}', '\u{1123E}', JoiningType::Transparent),
+    ('\u{112DF}', '\u{112DF}', JoiningType::Transparent),
+    ('\u{112E3}', '\u{112EA}', JoiningType::Transparent),
+    ('\u{11300}', '\u{11301}', JoiningType::Transparent),
+    ('\u{1133B}', '\u{1133C}', JoiningType::Transparent),
+    ('\u{11340}', '\u{11340}', JoiningType::Transparent),
+    ('\u{11366}', '\u{1136C}', JoiningType::Transparent),
+    ('\u{11370}', '\u{11374}', JoiningType::Transparent),
+    ('\u{11438}', '\u{1143F}', JoiningType::Transparent),
+    ('\u{11442}', '\u{11444}', JoiningType::Transparent),
+    ('\u{11446}', '\u{11446}', JoiningType::Transparent),
+    ('\u{1145E}', '\u{1145E}', JoiningType::Transparent),
+    ('\u{114B3}', '\u{114B8}', JoiningType::Transparent),
+    ('\u{114BA}', '\u{114BA}', JoiningType::Transparent),
+    ('\u{114BF}', '\u{114C0}', JoiningType::Transparent),
+    ('\u{114C2}', '\u{114C3}', JoiningType::Transparent),
+    ('\u{115B2}', '\u{115B5}', JoiningType::Transparent),
+    ('\u{115BC}', '\u{115BD}', JoiningType::Transparent),
+    ('\u{115BF}', '\u{115C0}', JoiningType::Transparent),
+    ('\u{115DC}', '\u{115DD}', JoiningType::Transparent),
+    ('\u{11633}', '\u{1163A}', JoiningType::Transparent),
+    ('\u{1163D}', '\u{1163D}', JoiningType::Transparent),
+    ('\u{1163F}', '\u{11640}', JoiningType::Transparent),
+    ('\u{116AB}', '\u{116AB}', JoiningType::Transparent),
+    ('\u{116AD}', '\u{116AD}', JoiningType::Transparent),
+    ('\u{116B0}', '\u{116B5}', JoiningType::Transparent),
+    ('\u{116B7}', '\u{116B7}', JoiningType::Transparent),
+    ('\u{1171D}', '\u{1171F}', JoiningType::Transparent),
+    ('\u{11722}', '\u{11725}', JoiningType::Transparent),
+    ('\u{11727}', '\u{1172B}', JoiningType::Transparent),
+    ('\u{1182F}', '\u{11837}', JoiningType::Transparent),
+    ('\u{11839}', '\u{1183A}', JoiningType::Transparent),
+    ('\u{1193B}', '\u{1193C}', JoiningType::Transparent),
+    ('\u{1193E}', '\u{1193E}', JoiningType::Transparent),
+    ('\u{11943}', '\u{11943}', JoiningType::Transparent),
+    ('\u{119D4}', '\u{119D7}', JoiningType::Transparent),
+    ('\u{119DA}', '\u{119DB}', JoiningType::Transparent),
+    ('\u{119E0}', '\u{119E0}', JoiningType::Transparent),
+    ('\u{11A01}', '\u{11A0A}', JoiningType::Transparent),
+    ('\u{11A33}', '\u{11A38}', JoiningType::Transparent),
+    ('\u{11A3B}', '\u{11A3E}', JoiningType::Transparent),
+    ('\u{11A47}', '\u{11A47}', JoiningType::Transparent),
+    ('\u{11A51}', '\u{11A56}', JoiningType::Transparent),
+    ('\u{11A59}', '\u{11A5B}', JoiningType::Transparent),
+    ('\u{11A8A}', '\u{11A96}', JoiningType::Transparent),
+    ('\u{11A98}', '\u{11A99}', JoiningType::Transparent),
+    ('\u{11C30}', '\u{11C36}', JoiningType::Transparent),
+    ('\u{11C38}', '\u{11C3D}', JoiningType::Transparent),
+    ('\u{11C3F}', '\u{11C3F}', JoiningType::Transparent),
+    ('\u{11C92}', '\u{11CA7}', JoiningType::Transparent),
+    ('\u{11CAA}', '\u{11CB0}', JoiningType::Transparent),
+    ('\u{11CB2}', '\u{11CB3}', JoiningType::Transparent),
+    ('\u{11CB5}', '\u{11CB6}', JoiningType::Transparent),
+    ('\u{11D31}', '\u{11D36}', JoiningType::Transparent),
+    ('\u{11D3A}', '\u{11D3A}', JoiningType::Transparent),
+    ('\u{11D3C}', '\u{11D3D}', JoiningType::Transparent),
+    ('\u{11D3F}', '\u{11D45}', JoiningType::Transparent),
+    ('\u{11D47}', '\u{11D47}', JoiningType::Transparent),
+    ('\u{11D90}', '\u{11D91}', JoiningType::Transparent),
+    ('\u{11D95}', '\u{11D95}', JoiningType::Transparent),
+    ('\u{11D97}', '\u{11D97}', JoiningType::Transparent),
+    ('\u{11EF3}', '\u{11EF4}', JoiningType::Transparent),
+    ('\u{13430}', '\u{13438}', JoiningType::Transparent),
+    ('\u{16AF0}', '\u{16AF4}', JoiningType::Transparent),
+    ('\u{16B30}', '\u{16B36}', JoiningType::Transparent),
+    ('\u{16F4F}', '\u{16F4F}', JoiningType::Transparent),
+    ('\u{16F8F}', '\u{16F92}', JoiningType::Transparent),
+    ('\u{16FE4}', '\u{16FE4}', JoiningType::Transparent),
+    ('\u{1BC9D}', '\u{1BC9E}', JoiningType::Transparent),
+    ('\u{1BCA0}', '\u{1BCA3}', JoiningType::Transparent),
+    ('\u{1CF00}', '\u{1CF2D}', JoiningType::Transparent),
+    ('\u{1CF30}', '\u{1CF46}', JoiningType::Transparent),
+    ('\u{1D167}', '\u{1D169}', JoiningType::Transparent),
+    ('\u{1D173}', '\u{1D182}', JoiningType::Transparent),
+    ('\u{1D185}', '\u{1D18B}', JoiningType::Transparent),
+    ('\u{1D1AA}', '\u{1D1AD}', JoiningType::Transparent),
+    ('\u{1D242}', '\u{1D244}', JoiningType::Transparent),
+    ('\u{1DA00}', '\u{1DA36}', JoiningType::Transparent),
+    ('\u{1DA3B}', '\u{1DA6C}', JoiningType::Transparent),
+    ('\u{1DA75}', '\u{1DA75}', JoiningType::Transparent),
+    ('\u{1DA84}', '\u{1DA84}', JoiningType::Transparent),
+    ('\u{1DA9B}', '\u{1DA9F}', JoiningType::Transparent),
+    ('\u{1DAA1}', '\u{1DAAF}', JoiningType::Transparent),
+    ('\u{1E000}', '\u{1E006}', JoiningType::Transparent),
+    ('\u{1E008}', '\u{1E018}', JoiningType::Transparent),
+    ('\u{1E01B}', '\u{1E021}', JoiningType::Transparent),
+    ('\u{1E023}', '\u{1E024}', JoiningType::Transparent),
+    ('\u{1E026}', '\u{1E02A}', JoiningType::Transparent),
+    ('\u{1E130}', '\u{1E136}', JoiningType::Transparent),
+    ('\u{1E2AE}', '\u{1E2AE}', JoiningType::Transparent),
+    ('\u{1E2EC}', '\u{1E2EF}', JoiningType::Transparent),
+    ('\u{1E8D0}', '\u{1E8D6}', JoiningType::Transparent),
+    ('\u{1E900}', '\u{1E943}', JoiningType::DualJoining),
+    ('\u{1E944}', '\u{1E94B}', JoiningType::Transparent),
+    ('\u{E0001}', '\u{E0001}', JoiningType::Transparent),
+    ('\u{E0020}', '\u{E007F}', JoiningType::Transparent),
+    ('\u{E0100}', '\u{E01EF}', JoiningType::Transparent),
+];
+
+/// Ranges of chars whose grapheme cluster break is not `Other` and their value, sorted by
+/// code point.
+pub(crate) static GRAPHEME_CLUSTER_BREAK: &[(char, char, GraphemeClusterBreak)] = &[
+    ('\u{0}', '\u{9}', GraphemeClusterBreak::Control),
+    ('\u{A}', '\u{A}', GraphemeClusterBreak::LF),
+    ('\u{B}', '\u{C}', GraphemeClusterBreak::Control),
+    ('\u{D}', '\u{D}', GraphemeClusterBreak::CR),
+    ('\u{E}', '\u{1F}', GraphemeClusterBreak::Control),
+    ('\u{7F}', '\u{9F}', GraphemeClusterBreak::Control),
+    ('\u{AD}', '\u{AD}', GraphemeClusterBreak::Control),
+    ('\u{300}', '\u{36F}', GraphemeClusterBreak::Extend),
+    ('\u{483}', '\u{489}', GraphemeClusterBreak::Extend),
+    ('\u{591}', '\u{5BD}', GraphemeClusterBreak::Extend),
+    ('\u{5BF}', '\u{5BF}', GraphemeClusterBreak::Extend),
+    ('\u{5C1}', '\u{5C2}', GraphemeClusterBreak::Extend),
+    ('\u{5C4}', '\u{5C5}', GraphemeClusterBreak::Extend),
+    ('\u{5C7}', '\u{5C7}', GraphemeClusterBreak::Extend),
+    ('\u{600}', '\u{605}', GraphemeClusterBreak::Prepend),
+    ('\u{610}', '\u{61A}', GraphemeClusterBreak::Extend),
+    ('\u{61C}', '\u{61C}', GraphemeClusterBreak::Control),
+    ('\u{64B}', '\u{65F}', GraphemeClusterBreak::Extend),
+    ('\u{670}', '\u{670}', GraphemeClusterBreak::Extend),
+    ('\u{6D6}', '\u{6DC}', GraphemeClusterBreak::Extend),
+    ('\u{6DD}', '\u{6DD}', GraphemeClusterBreak::Prepend),
+    ('\u{6DF}', '\u{6E4}', GraphemeClusterBreak::Extend),
+    ('\u{6E7}', '\u{6E8}', GraphemeClusterBreak::Extend),
+    ('\u{6EA}', '\u{6ED}', GraphemeClusterBreak::Extend),
+    ('\u{70F}', '\u{70F}', GraphemeClusterBreak::Prepend),
+    ('\u{711}', '\u{711}', GraphemeClusterBreak::Extend),
+    ('\u{730}', '\u{74A}', GraphemeClusterBreak::Extend),
+    ('\u{7A6}', '\u{7B0}', GraphemeClusterBreak::Extend),
+    ('\u{7EB}', '\u{7F3}', GraphemeClusterBreak::Extend),
+    ('\u{7FD}', '\u{7FD}', GraphemeClusterBreak::Extend),
+    ('\u{816}', '\u{819}', GraphemeClusterBreak::Extend),
+    ('\u{81B}', '\u{823}', GraphemeClusterBreak::Extend),
+    ('\u{825}', '\u{827}', GraphemeClusterBreak::Extend),
+    ('\u{829}', '\u{82D}', GraphemeClusterBreak::Extend),
+    ('\u{859}', '\u{85B}', GraphemeClusterBreak::Extend),
+    ('\u{890}', '\u{891}', GraphemeClusterBreak::Prepend),
+    ('\u{898}', '\u{89F}', GraphemeClusterBreak::Extend),
+    ('\u{8CA}', '\u{8E1}', GraphemeClusterBreak::Extend),
+    ('\u{8E2}', '\u{8E2}', GraphemeClusterBreak::Prepend),
+    ('\u{8E3}', '\u{902}', GraphemeClusterBreak::Extend),
+    ('\u{903}', '\u{903}', GraphemeClusterBreak::SpacingMark),
+    ('\u{93A}', '\u{93A}', GraphemeClusterBreak::Extend),
+    ('\u{93B}', '\u{93B}', GraphemeClusterBreak::SpacingMark),
+    ('\u{93C}', '\u{93C}', GraphemeClusterBreak::Extend),
+    ('\u{93E}', '\u{940}', GraphemeClusterBreak::SpacingMark),
+    ('\u{941}', '\u{948}', GraphemeClusterBreak::Extend),
+    ('\u{949}', '\u{94C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{94D}', '\u{94D}', GraphemeClusterBreak::Extend),
+    ('\u{94E}', '\u{94F}', GraphemeClusterBreak::SpacingMark),
+    ('\u{951}', '\u{957}', GraphemeClusterBreak::Extend),
+    ('\u{962}', '\u{963}', GraphemeClusterBreak::Extend),
+    ('\u{981}', '\u{981}', GraphemeClusterBreak::Extend),
+    ('\u{982}', '\u{983}', GraphemeClusterBreak::SpacingMark),
+    ('\u{9BC}', '\u{9BC}', GraphemeClusterBreak::Extend),
+    ('\u{9BE}', '\u{9BE}', GraphemeClusterBreak::Extend),
+    ('\u{9BF}', '\u{9C0}', GraphemeClusterBreak::SpacingMark),
+    ('\u{9C1}', '\u{9C4}', GraphemeClusterBreak::Extend),
+    ('\u{9C7}', '\u{9C8}', GraphemeClusterBreak::SpacingMark),
+    ('\u{9CB}', '\u{9CC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{9CD}', '\u{9CD}', GraphemeClusterBreak::Extend),
+    ('\u{9D7}', '\u{9D7}', GraphemeClusterBreak::Extend),
+    ('\u{9E2}', '\u{9E3}', GraphemeClusterBreak::Extend),
+    ('\u{9FE}', '\u{9FE}', GraphemeClusterBreak::Extend),
+    ('\u{A01}', '\u{A02}', GraphemeClusterBreak::Extend),
+    ('\u{A03}', '\u{A03}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A3C}', '\u{A3C}', GraphemeClusterBreak::Extend),
+    ('\u{A3E}', '\u{A40}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A41}', '\u{A42}', GraphemeClusterBreak::Extend),
+    ('\u{A47}', '\u{A48}', GraphemeClusterBreak::Extend),
+    ('\u{A4B}', '\u{A4D}', GraphemeClusterBreak::Extend),
+    ('\u{A51}', '\u{A51}', GraphemeClusterBreak::Extend),
+    ('\u{A70}', '\u{A71}', GraphemeClusterBreak::Extend),
+    ('\u{A75}', '\u{A75}', GraphemeClusterBreak::Extend),
+    ('\u{A81}', '\u{A82}', GraphemeClusterBreak::Extend),
+    ('\u{A83}', '\u{A83}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ABC}', '\u{ABC}', GraphemeClusterBreak::Extend),
+    ('\u{ABE}', '\u{AC0}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AC1}', '\u{AC5}', GraphemeClusterBreak::Extend),
+    ('\u{AC7}', '\u{AC8}', GraphemeClusterBreak::Extend),
+    ('\u{AC9}', '\u{AC9}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ACB}', '\u{ACC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ACD}', '\u{ACD}', GraphemeClusterBreak::Extend),
+    ('\u{AE2}', '\u{AE3}', GraphemeClusterBreak::Extend),
+    ('\u{AFA}', '\u{AFF}', GraphemeClusterBreak::Extend),
+    ('\u{B01}', '\u{B01}', GraphemeClusterBreak::Extend),
+    ('\u{B02}', '\u{B03}', GraphemeClusterBreak::SpacingMark),
+    ('\u{B3C}', '\u{B3C}', GraphemeClusterBreak::Extend),
+    ('\u{B3E}', '\u{B3F}', GraphemeClusterBreak::Extend),
+    ('\u{B40}', '\u{B40}', GraphemeClusterBreak::SpacingMark),
+    ('\u{B41}', '\u{B44}', GraphemeClusterBreak::Extend),
+    ('\u{B47}', '\u{B48}', GraphemeClusterBreak::SpacingMark),
+    ('\u{B4B}', '\u{B4C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{B4D}', '\u{B4D}', GraphemeClusterBreak::Extend),
+    ('\u{B55}', '\u{B57}', GraphemeClusterBreak::Extend),
+    ('\u{B62}', '\u{B63}', GraphemeClusterBreak::Extend),
+    ('\u{B82}', '\u{B82}', GraphemeClusterBreak::Extend),
+    ('\u{BBE}', '\u{BBE}', GraphemeClusterBreak::Extend),
+    ('\u{BBF}', '\u{BBF}', GraphemeClusterBreak::SpacingMark),
+    ('\u{BC0}', '\u{BC0}', GraphemeClusterBreak::Extend),
+    ('\u{BC1}', '\u{BC2}', GraphemeClusterBreak::SpacingMark),
+    ('\u{BC6}', '\u{BC8}', GraphemeClusterBreak::SpacingMark),
+    ('\u{BCA}', '\u{BCC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{BCD}', '\u{BCD}', GraphemeClusterBreak::Extend),
+    ('\u{BD7}', '\u{BD7}', GraphemeClusterBreak::Extend),
+    ('\u{C00}', '\u{C00}', GraphemeClusterBreak::Extend),
+    ('\u{C01}', '\u{C03}', GraphemeClusterBreak::SpacingMark),
+    ('\u{C04}', '\u{C04}', GraphemeClusterBreak::Extend),
+    ('\u{C3C}', '\u{C3C}', GraphemeClusterBreak::Extend),
+    ('\u{C3E}', '\u{C40}', GraphemeClusterBreak::Extend),
+    ('\u{C41}', '\u{C44}', GraphemeClusterBreak::SpacingMark),
+    ('\u{C46}', '\u{C48}', GraphemeClusterBreak::Extend),
+    ('\u{C4A}', '\u{C4D}', GraphemeClusterBreak::Extend),
+    ('\u{C55}', '\u{C56}', GraphemeClusterBreak::Extend),
+    ('\u{C62}', '\u{C63}', GraphemeClusterBreak::Extend),
+    ('\u{C81}', '\u{C81}', GraphemeClusterBreak::Extend),
+    ('\u{C82}', '\u{C83}', GraphemeClusterBreak::SpacingMark),
+    ('\u{CBC}', '\u{CBC}', GraphemeClusterBreak::Extend),
+    ('\u{CBE}', '\u{CBE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{CBF}', '\u{CBF}', GraphemeClusterBreak::Extend),
+    ('\u{CC0}', '\u{CC1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{CC2}', '\u{CC2}', GraphemeClusterBreak::Extend),
+    ('\u{CC3}', '\u{CC4}', GraphemeClusterBreak::SpacingMark),
+    ('\u{CC6}', '\u{CC6}', GraphemeClusterBreak::Extend),
+    ('\u{CC7}', '\u{CC8}', GraphemeClusterBreak::SpacingMark),
+    ('\u{CCA}', '\u{CCB}', GraphemeClusterBreak::SpacingMark),
+    ('\u{CCC}', '\u{CCD}', GraphemeClusterBreak::Extend),
+    ('\u{CD5}', '\u{CD6}', GraphemeClusterBreak::Extend),
+    ('\u{CE2}', '\u{CE3}', GraphemeClusterBreak::Extend),
+    ('\u{D00}', '\u{D01}', GraphemeClusterBreak::Extend),
+    ('\u{D02}', '\u{D03}', GraphemeClusterBreak::SpacingMark),
+    ('\u{D3B}', '\u{D3C}', GraphemeClusterBreak::Extend),
+    ('\u{D3E}', '\u{D3E}', GraphemeClusterBreak::Extend),
+    ('\u{D3F}', '\u{D40}', GraphemeClusterBreak::SpacingMark),
+    ('\u{D41}', '\u{D44}', GraphemeClusterBreak::Extend),
+    ('\u{D46}', '\u{D48}', GraphemeClusterBreak::SpacingMark),
+    ('\u{D4A}', '\u{D4C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{D4D}', '\u{D4D}', GraphemeClusterBreak::Extend),
+    ('\u{D4E}', '\u{D4E}', GraphemeClusterBreak::Prepend),
+    ('\u{D57}', '\u{D57}', GraphemeClusterBreak::Extend),
+    ('\u{D62}', '\u{D63}', GraphemeClusterBreak::Extend),
+    ('\u{D81}', '\u{D81}', GraphemeClusterBreak::Extend),
+    ('\u{D82}', '\u{D83}', GraphemeClusterBreak::SpacingMark),
+    ('\u{DCA}', '\u{DCA}', GraphemeClusterBreak::Extend),
+    ('\u{DCF}', '\u{DCF}', GraphemeClusterBreak::Extend),
+    ('\u{DD0}', '\u{DD1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{DD2}', '\u{DD4}', GraphemeClusterBreak::Extend),
+    ('\u{DD6}', '\u{DD6}', GraphemeClusterBreak::Extend),
+    ('\u{DD8}', '\u{DDE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{DDF}', '\u{DDF}', GraphemeClusterBreak::Extend),
+    ('\u{DF2}', '\u{DF3}', GraphemeClusterBreak::SpacingMark),
+    ('\u{E31}', '\u{E31}', GraphemeClusterBreak::Extend),
+    ('\u{E33}', '\u{E33}', GraphemeClusterBreak::SpacingMark),
+    ('\u{E34}', '\u{E3A}', GraphemeClusterBreak::Extend),
+    ('\u{E47}', '\u{E4E}', GraphemeClusterBreak::Extend),
+    ('\u{EB1}', '\u{EB1}', GraphemeClusterBreak::Extend),
+    ('\u{EB3}', '\u{EB3}', GraphemeClusterBreak::SpacingMark),
+    ('\u{EB4}', '\u{EBC}', GraphemeClusterBreak::Extend),
+    ('\u{EC8}', '\u{ECD}', GraphemeClusterBreak::Extend),
+    ('\u{F18}', '\u{F19}', GraphemeClusterBreak::Extend),
+    ('\u{F35}', '\u{F35}', GraphemeClusterBreak::Extend),
+    ('\u{F37}', '\u{F37}', GraphemeClusterBreak::Extend),
+    ('\u{F39}', '\u{F39}', GraphemeClusterBreak::Extend),
+    ('\u{F3E}', '\u{F3F}', GraphemeClusterBreak::SpacingMark),
+    ('\u{F71}', '\u{F7E}', GraphemeClusterBreak::Extend),
+    ('\u{F7F}', '\u{F7F}', GraphemeClusterBreak::SpacingMark),
+    ('\u{F80}', '\u{F84}', GraphemeClusterBreak::Extend),
+    ('\u{F86}', '\u{F87}', GraphemeClusterBreak::Extend),
+    ('\u{F8D}', '\u{F97}', GraphemeClusterBreak::Extend),
+    ('\u{F99}', '\u{FBC}', GraphemeClusterBreak::Extend),
+    ('\u{FC6}', '\u{FC6}', GraphemeClusterBreak::Extend),
+    ('\u{102D}', '\u{1030}', GraphemeClusterBreak::Extend),
+    ('\u{1031}', '\u{1031}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1032}', '\u{1037}', GraphemeClusterBreak::Extend),
+    ('\u{1039}', '\u{103A}', GraphemeClusterBreak::Extend),
+    ('\u{103B}', '\u{103C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{103D}', '\u{103E}', GraphemeClusterBreak::Extend),
+    ('\u{1056}', '\u{1057}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1058}', '\u{1059}', GraphemeClusterBreak::Extend),
+    ('\u{105E}', '\u{1060}', GraphemeClusterBreak::Extend),
+    ('\u{1071}', '\u{1074}', GraphemeClusterBreak::Extend),
+    ('\u{1082}', '\u{1082}', GraphemeClusterBreak::Extend),
+    ('\u{1084}', '\u{1084}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1085}', '\u{1086}', GraphemeClusterBreak::Extend),
+    ('\u{108D}', '\u{108D}', GraphemeClusterBreak::Extend),
+    ('\u{109D}', '\u{109D}', GraphemeClusterBreak::Extend),
+    ('\u{1100}', '\u{115F}', GraphemeClusterBreak::L),
+    ('\u{1160}', '\u{11A7}', GraphemeClusterBreak::V),
+    ('\u{11A8}', '\u{11FF}', GraphemeClusterBreak::T),
+    ('\u{135D}', '\u{135F}', GraphemeClusterBreak::Extend),
+    ('\u{1712}', '\u{1714}', GraphemeClusterBreak::Extend),
+    ('\u{1715}', '\u{1715}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1732}', '\u{1733}', GraphemeClusterBreak::Extend),
+    ('\u{1734}', '\u{1734}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1752}', '\u{1753}', GraphemeClusterBreak::Extend),
+    ('\u{1772}', '\u{1773}', GraphemeClusterBreak::Extend),
+    ('\u{17B4}', '\u{17B5}', GraphemeClusterBreak::Extend),
+    ('\u{17B6}', '\u{17B6}', GraphemeClusterBreak::SpacingMark),
+    ('\u{17B7}', '\u{17BD}', GraphemeClusterBreak::Extend),
+    ('\u{17BE}', '\u{17C5}', GraphemeClusterBreak::SpacingMark),
+    ('\u{17C6}', '\u{17C6}', GraphemeClusterBreak::Extend),
+    ('\u{17C7}', '\u{17C8}', GraphemeClusterBreak::SpacingMark),
+    ('\u{17C9}', '\u{17D3}', GraphemeClusterBreak::Extend),
+    ('\u{17DD}', '\u{17DD}', GraphemeClusterBreak::Extend),
+    ('\u{180B}', '\u{180D}', GraphemeClusterBreak::Extend),
+    ('\u{180E}', '\u{180E}', GraphemeClusterBreak::Control),
+    ('\u{180F}', '\u{180F}', GraphemeClusterBreak::Extend),
+    ('\u{1885}', '\u{1886}', GraphemeClusterBreak::Extend),
+    ('\u{18A9}', '\u{18A9}', GraphemeClusterBreak::Extend),
+    ('\u{1920}', '\u{1922}', GraphemeClusterBreak::Extend),
+    ('\u{1923}', '\u{1926}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1927}', '\u{1928}', GraphemeClusterBreak::Extend),
+    ('\u{1929}', '\u{192B}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1930}', '\u{1931}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1932}', '\u{1932}', GraphemeClusterBreak::Extend),
+    ('\u{1933}', '\u{1938}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1939}', '\u{193B}', GraphemeClusterBreak::Extend),
+    ('\u{1A17}', '\u{1A18}', GraphemeClusterBreak::Extend),
+    ('\u{1A19}', '\u{1A1A}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1A1B}', '\u{1A1B}', GraphemeClusterBreak::Extend),
+    ('\u{1A55}', '\u{1A55}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1A56}', '\u{1A56}', GraphemeClusterBreak::Extend),
+    ('\u{1A57}', '\u{1A57}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1A58}', '\u{1A5E}', GraphemeClusterBreak::Extend),
+    ('\u{1A60}', '\u{1A60}', GraphemeClusterBreak::Extend),
+    ('\u{1A62}', '\u{1A62}', GraphemeClusterBreak::Extend),
+    ('\u{1A65}', '\u{1A6C}', GraphemeClusterBreak::Extend),
+    ('\u{1A6D}', '\u{1A72}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1A73}', '\u{1A7C}', GraphemeClusterBreak::Extend),
+    ('\u{1A7F}', '\u{1A7F}', GraphemeClusterBreak::Extend),
+    ('\u{1AB0}', '\u{1ACE}', GraphemeClusterBreak::Extend),
+    ('\u{1B00}', '\u{1B03}', GraphemeClusterBreak::Extend),
+    ('\u{1B04}', '\u{1B04}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1B34}', '\u{1B3A}', GraphemeClusterBreak::Extend),
+    ('\u{1B3B}', '\u{1B3B}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1B3C}', '\u{1B3C}', GraphemeClusterBreak::Extend),
+    ('\u{1B3D}', '\u{1B41}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1B42}', '\u{1B42}', GraphemeClusterBreak::Extend),
+    ('\u{1B43}', '\u{1B44}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1B6B}', '\u{1B73}', GraphemeClusterBreak::Extend),
+    ('\u{1B80}', '\u{1B81}', GraphemeClusterBreak::Extend),
+    ('\u{1B82}', '\u{1B82}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BA1}', '\u{1BA1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BA2}', '\u{1BA5}', GraphemeClusterBreak::Extend),
+    ('\u{1BA6}', '\u{1BA7}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BA8}', '\u{1BA9}', GraphemeClusterBreak::Extend),
+    ('\u{1BAA}', '\u{1BAA}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BAB}', '\u{1BAD}', GraphemeClusterBreak::Extend),
+    ('\u{1BE6}', '\u{1BE6}', GraphemeClusterBreak::Extend),
+    ('\u{1BE7}', '\u{1BE7}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BE8}', '\u{1BE9}', GraphemeClusterBreak::Extend),
+    ('\u{1BEA}', '\u{1BEC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BED}', '\u{1BED}', GraphemeClusterBreak::Extend),
+    ('\u{1BEE}', '\u{1BEE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BEF}', '\u{1BF1}', GraphemeClusterBreak::Extend),
+    ('\u{1BF2}', '\u{1BF3}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1C24}', '\u{1C2B}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1C2C}', '\u{1C33}', GraphemeClusterBreak::Extend),
+    ('\u{1C34}', '\u{1C35}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1C36}', '\u{1C37}', GraphemeClusterBreak::Extend),
+    ('\u{1CD0}', '\u{1CD2}', GraphemeClusterBreak::Extend),
+    ('\u{1CD4}', '\u{1CE0}', GraphemeClusterBreak::Extend),
+    ('\u{1CE1}', '\u{1CE1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1CE2}', '\u{1CE8}', GraphemeClusterBreak::Extend),
+    ('\u{1CED}', '\u{1CED}', GraphemeClusterBreak::Extend),
+    ('\u{1CF4}', '\u{1CF4}', GraphemeClusterBreak::Extend),
+    ('\u{1CF7}', '\u{1CF7}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1CF8}', '\u{1CF9}', GraphemeClusterBreak::Extend),
+    ('\u{1DC0}', '\u{1DFF}', GraphemeClusterBreak::Extend),
+    ('\u{200B}', '\u{200B}', GraphemeClusterBreak::Control),
+    ('\u{200C}', '\u{200C}', GraphemeClusterBreak::Extend),
+    ('\u{200D}', '\u{200D}', GraphemeClusterBreak::ZWJ),
+    ('\u{200E}', '\u{200F}', GraphemeClusterBreak::Control),
+    ('\u{2028}', '\u{202E}', GraphemeClusterBreak::Control),
+    ('\u{2060}', '\u{206F}', GraphemeClusterBreak::Control),
+    ('\u{20D0}', '\u{20F0}', GraphemeClusterBreak::Extend),
+    ('\u{2CEF}', '\u{2CF1}', GraphemeClusterBreak::Extend),
+    ('\u{2D7F}', '\u{2D7F}', GraphemeClusterBreak::Extend),
+    ('\u{2DE0}', '\u{2DFF}', GraphemeClusterBreak::Extend),
+    ('\u{302A}', '\u{302F}', GraphemeClusterBreak::Extend),
+    ('\u{3099}', '\u{309A}', GraphemeClusterBreak::Extend),
+    ('\u{A66F}', '\u{A672}', GraphemeClusterBreak::Extend),
+    ('\u{A674}', '\u{A67D}', GraphemeClusterBreak::Extend),
+    ('\u{A69E}', '\u{A69F}', GraphemeClusterBreak::Extend),
+    ('\u{A6F0}', '\u{A6F1}', GraphemeClusterBreak::Extend),
+    ('\u{A802}', '\u{A802}', GraphemeClusterBreak::Extend),
+    ('\u{A806}', '\u{A806}', GraphemeClusterBreak::Extend),
+    ('\u{A80B}', '\u{A80B}', GraphemeClusterBreak::Extend),
+    ('\u{A823}', '\u{A824}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A825}', '\u{A826}', GraphemeClusterBreak::Extend),
+    ('\u{A827}', '\u{A827}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A82C}', '\u{A82C}', GraphemeClusterBreak::Extend),
+    ('\u{A880}', '\u{A881}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A8B4}', '\u{A8C3}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A8C4}', '\u{A8C5}', GraphemeClusterBreak::Extend),
+    ('\u{A8E0}', '\u{A8F1}', GraphemeClusterBreak::Extend),
+    ('\u{A8FF}', '\u{A8FF}', GraphemeClusterBreak::Extend),
+    ('\u{A926}', '\u{A92D}', GraphemeClusterBreak::Extend),
+    ('\u{A947}', '\u{A951}', GraphemeClusterBreak::Extend),
+    ('\u{A952}', '\u{A953}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A960}', '\u{A97C}', GraphemeClusterBreak::L),
+    ('\u{A980}', '\u{A982}', GraphemeClusterBreak::Extend),
+    ('\u{A983}', '\u{A983}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A9B3}', '\u{A9B3}', GraphemeClusterBreak::Extend),
+    ('\u{A9B4}', '\u{A9B5}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A9B6}', '\u{A9B9}', GraphemeClusterBreak::Extend),
+    ('\u{A9BA}', '\u{A9BB}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A9BC}', '\u{A9BD}', GraphemeClusterBreak::Extend),
+    ('\u{A9BE}', '\u{A9C0}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A9E5}', '\u{A9E5}', GraphemeClusterBreak::Extend),
+    ('\u{AA29}', '\u{AA2E}', GraphemeClusterBreak::Extend),
+    ('\u{AA2F}', '\u{AA30}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AA31}', '\u{AA32}', GraphemeClusterBreak::Extend),
+    ('\u{AA33}', '\u{AA34}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AA35}', '\u{AA36}', GraphemeClusterBreak::Extend),
+    ('\u{AA43}', '\u{AA43}', GraphemeClusterBreak::Extend),
+    ('\u{AA4C}', '\u{AA4C}', GraphemeClusterBreak::Extend),
+    ('\u{AA4D}', '\u{AA4D}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AA7C}', '\u{AA7C}', GraphemeClusterBreak::Extend),
+    ('\u{AAB0}', '\u{AAB0}', GraphemeClusterBreak::Extend),
+    ('\u{AAB2}', '\u{AAB4}', GraphemeClusterBreak::Extend),
+    ('\u{AAB7}', '\u{AAB8}', GraphemeClusterBreak::Extend),
+    ('\u{AABE}', '\u{AABF}', GraphemeClusterBreak::Extend),
+    ('\u{AAC1}', '\u{AAC1}', GraphemeClusterBreak::Extend),
+    ('\u{AAEB}', '\u{AAEB}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AAEC}', '\u{AAED}', GraphemeClusterBreak::Extend),
+    ('\u{AAEE}', '\u{AAEF}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AAF5}', '\u{AAF5}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AAF6}', '\u{AAF6}', GraphemeClusterBreak::Extend),
+    ('\u{ABE3}', '\u{ABE4}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ABE5}', '\u{ABE5}', GraphemeClusterBreak::Extend),
+    ('\u{ABE6}', '\u{ABE7}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ABE8}', '\u{ABE8}', GraphemeClusterBreak::Extend),
+    ('\u{ABE9}', '\u{ABEA}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ABEC}', '\u{ABEC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ABED}', '\u{ABED}', GraphemeClusterBreak::Extend),
+    ('\u{AC00}', '\u{AC00}', GraphemeClusterBreak::LV),
+    ('\u{AC01}', '\u{AC1B}', GraphemeClusterBreak::LVT),
+    ('\u{AC1C}', '\u{AC1C}', GraphemeClusterBreak::LV),
+    ('\u{AC1D}', '\u{AC37}', GraphemeClusterBreak::LVT),
+    ('\u{AC38}', '\u{AC38}', GraphemeClusterBreak::LV),
+    ('\u{AC39}', '\u{AC53}', GraphemeClusterBreak::LVT),
+    ('\u{AC54}', '\u{AC54}', GraphemeClusterBreak::LV),
+    ('\u{AC55}', '\u{AC6F}', GraphemeClusterBreak::LVT),
+    ('\u{AC70}', '\u{AC70}', GraphemeClusterBreak::LV),
+    ('\u{AC71}', '\u{AC8B}', GraphemeClusterBreak::LVT),
+    ('\u{AC8C}', '\u{AC8C}', GraphemeClusterBreak::LV),
+    ('\u{AC8D}', '\u{ACA7}', GraphemeClusterBreak::LVT),
+    ('\u{ACA8}', '\u{ACA8}', GraphemeClusterBreak::LV),
+    ('\u{ACA9}', '\u{ACC3}', GraphemeClusterBreak::LVT),
+    ('\u{ACC4}', '\u{ACC4}', GraphemeClusterBreak::LV),
+    ('\u{ACC5}', '\u{ACDF}', GraphemeClusterBreak::LVT),
+    ('\u{ACE0}', '\u{ACE0}', GraphemeClusterBreak::LV),
+    ('\u{ACE1}', '\u{ACFB}', GraphemeClusterBreak::LVT),
+    ('\u{ACFC}', '\u{ACFC}', GraphemeClusterBreak::LV),
+    ('\u{ACFD}', '\u{AD17}', GraphemeClusterBreak::LVT),
+    ('\u{AD18}', '\u{AD18}', GraphemeClusterBreak::LV),
+    ('\u{AD19}', '\u{AD33}', GraphemeClusterBreak::LVT),
+    ('\u{AD34}', '\u{AD34}', GraphemeClusterBreak::LV),
+    ('\u{AD35}', '\u{AD4F}', GraphemeClusterBreak::LVT),
+    ('\u{AD50}', '\u{AD50}', GraphemeClusterBreak::LV),
+    ('\u{AD51}', '\u{AD6B}', GraphemeClusterBreak::LVT),
+    ('\u{AD6C}', '\u{AD6C}', GraphemeClusterBreak::LV),
+    ('\u{AD6D}', '\u{AD87}', GraphemeClusterBreak::LVT),
+    ('\u{AD88}', '\u{AD88}', GraphemeClusterBreak::LV),
+    ('\u{AD89}', '\u{ADA3}', GraphemeClusterBreak::LVT),
+    ('\u{ADA4}', '\u{ADA4}', GraphemeClusterBreak::LV),
+    ('\u{ADA5}', '\u{ADBF}', GraphemeClusterBreak::LVT),
+    ('\u{ADC0}', '\u{ADC0}', GraphemeClusterBreak::LV),
+    ('\u{ADC1}', '\u{ADDB}', GraphemeClusterBreak::LVT),
+    ('\u{ADDC}', '\u{ADDC}', GraphemeClusterBreak::LV),
+    ('\u{ADDD}', '\u{ADF7}', GraphemeClusterBreak::LVT),
+    ('\u{ADF8}', '\u{ADF8}', GraphemeClusterBreak::LV),
+    ('\u{ADF9}', '\u{AE13}', GraphemeClusterBreak::LVT),
+    ('\u{AE14}', '\u{AE14}', GraphemeClusterBreak::LV),
+    ('\u{AE15}', '\u{AE2F}', GraphemeClusterBreak::LVT),
+    ('\u{AE30}', '\u{AE30}', GraphemeClusterBreak::LV),
+    ('\u{AE31}', '\u{AE4B}', GraphemeClusterBreak::LVT),
+    ('\u{AE4C}', '\u{AE4C}', GraphemeClusterBreak::LV),
+    ('\u{AE4D}', '\u{AE67}', GraphemeClusterBreak::LVT),
+    ('\u{AE68}', '\u{AE68}', GraphemeClusterBreak::LV),
+    ('\u{AE69}', '\u{AE83}', GraphemeClusterBreak::LVT),
+    ('\u{AE84}', '\u{AE84}', GraphemeClusterBreak::LV),
+    ('\u{AE85}', '\u{AE9F}', GraphemeClusterBreak::LVT),
+    ('\u{AEA0}', '\u{AEA0}', GraphemeClusterBreak::LV),
+    ('\u{AEA1}', '\u{AEBB}', GraphemeClusterBreak::LVT),
+    ('\u{AEBC}', '\u{AEBC}', GraphemeClusterBreak::LV),
+    ('\u{AEBD}', '\u{AED7}', GraphemeClusterBreak::LVT),
+    ('\u{AED8}', '\u{AED8}', GraphemeClusterBreak::LV),
+    ('\u{AED9}', '\u{AEF3}', GraphemeClusterBreak::LVT),
+    ('\u{AEF4}', '\u{AEF4}', GraphemeClusterBreak::LV),
+    ('\u{AEF5}', '\u{AF0F}', GraphemeClusterBreak::LVT),
+    ('\u{AF10}', '\u{AF10}', GraphemeClusterBreak::LV),
+    ('\u{AF11}', '\u{AF2B}', GraphemeClusterBreak::LVT),
+    ('\u{AF2C}', '\u{AF2C}', GraphemeClusterBreak::LV),
+    ('\u{AF2D}', '\u{AF47}', GraphemeClusterBreak::LVT),
+    ('\u{AF48}', '\u{AF48}', GraphemeClusterBreak::LV),
+    ('\u{AF49}', '\u{AF63}', GraphemeClusterBreak::LVT),
+    ('\u{AF64}', '\u{AF64}', GraphemeClusterBreak::LV),
+    ('\u{AF65}', '\u{AF7F}', GraphemeClusterBreak::LVT),
+    ('\u{AF80}', '\u{AF80}', GraphemeClusterBreak::LV),
+    ('\u{AF81}', '\u{AF9B}', GraphemeClusterBreak::LVT),
+    ('\u{AF9C}', '\u{AF9C}', GraphemeClusterBreak::LV),
+    ('\u{AF9D}', '\u{AFB7}', GraphemeClusterBreak::LVT),
+    ('\u{AFB8}', '\u{AFB8}', GraphemeClusterBreak::LV),
+    ('\u{AFB9}', '\u{AFD3}', GraphemeClusterBreak::LVT),
+    ('\u{AFD4}', '\u{AFD4}', GraphemeClusterBreak::LV),
+    ('\u{AFD5}', '\u{AFEF}', GraphemeClusterBreak::LVT),
+    ('\u{AFF0}', '\u{AFF0}', GraphemeClusterBreak::LV),
+    ('\u{AFF1}', '\u{B00B}', GraphemeClusterBreak::LVT),
+    ('\u{B00C}', '\u{B00C}', GraphemeClusterBreak::LV),
+    ('\u{B00D}', '\u{B027}', GraphemeClusterBreak::LVT),
+    ('\u{B028}', '\u{B028}', GraphemeClusterBreak::LV),
+    ('\u{B029}', '\u{B043}', GraphemeClusterBreak::LVT),
+    ('\u{B044}', '\u{B044}', GraphemeClusterBreak::LV),
+    ('\u{B045}', '\u{B05F}', GraphemeClusterBreak::LVT),
+    ('\u{B060}', '\u{B060}', GraphemeClusterBreak::LV),
+    ('\u{B061}', '\u{B07B}', GraphemeClusterBreak::LVT),
+    ('\u{B07C}', '\u{B07C}', GraphemeClusterBreak::LV),
+    ('\u{B07D}', '\u{B097}', GraphemeClusterBreak::LVT),
+    ('\u{B098}', '\u{B098}', GraphemeClusterBreak::LV),
+    ('\u{B099}', '\u{B0B3}', GraphemeClusterBreak::LVT),
+    ('\u{B0B4}', '\u{B0B4}', GraphemeClusterBreak::LV),
+    ('\u{B0B5}', '\u{B0CF}', GraphemeClusterBreak::LVT),
+    ('\u{B0D0}', '\u{B0D0}', GraphemeClusterBreak::LV),
+    ('\u{B0D1}', '\u{B0EB}', GraphemeClusterBreak::LVT),
+    ('\u{B0EC}', '\u{B0EC}', GraphemeClusterBreak::LV),
+    ('\u{B0ED}', '\u{B107}', GraphemeClusterBreak::LVT),
+    ('\u{B108}', '\u{B108}', GraphemeClusterBreak::LV),
+    ('\u{B109}', '\u{B123}', GraphemeClusterBreak::LVT),
+    ('\u{B124}', '\u{B124}', GraphemeClusterBreak::LV),
+    ('\u{B125}', '\u{B13F}', GraphemeClusterBreak::LVT),
+    ('\u{B140}', '\u{B140}', GraphemeClusterBreak::LV),
+    ('\u{B141}', '\u{B15B}', GraphemeClusterBreak::LVT),
+    ('\u{B15C}', '\u{B15C}', GraphemeClusterBreak::LV),
+    ('\u{B15D}', '\u{B177}', GraphemeClusterBreak::LVT),
+    ('\u{B178}', '\u{B178}', GraphemeClusterBreak::LV),
+    ('\u{B179}', '\u{B193}', GraphemeClusterBreak::LVT),
+    ('\u{B194}', '\u{B194}', GraphemeClusterBreak::LV),
+    ('\u{B195}', '\u{B1AF}', GraphemeClusterBreak::LVT),
+    ('\u{B1B0}', '\u{B1B0}', GraphemeClusterBreak::LV),
+    ('\u{B1B1}', '\u{B1CB}', GraphemeClusterBreak::LVT),
+    ('\u{B1CC}', '\u{B1CC}', GraphemeClusterBreak::LV),
+    ('\u{B1CD}', '\u{B1E7}', GraphemeClusterBreak::LVT),
+    ('\u{B1E8}', '\u{B1E8}', GraphemeClusterBreak::LV),
+    ('\u{B1E9}', '\u{B203}', GraphemeClusterBreak::LVT),
+    ('\u{B204}', '\u{B204}', GraphemeClusterBreak::LV),
+    ('\u{B205}', '\u{B21F}', GraphemeClusterBreak::LVT),
+    ('\u{B220}', '\u{B220}', GraphemeClusterBreak::LV),
+    ('\u{B221}', '\u{B23B}', GraphemeClusterBreak::LVT),
+    ('\u{B23C}', '\u{B23C}', GraphemeClusterBreak::LV),
+    ('\u{B23D}', '\u{B257}', GraphemeClusterBreak::LVT),
+    ('\u{B258}', '\u{B258}', GraphemeClusterBreak::LV),
+    ('\u{B259}', '\u{B273}', GraphemeClusterBreak::LVT),
+    ('\u{B274}', '\u{B274}', GraphemeClusterBreak::LV),
+    ('\u{B275}', '\u{B28F}', GraphemeClusterBreak::LVT),
+    ('\u{B290}', '\u{B290}', GraphemeClusterBreak::LV),
+    ('\u{B291}', '\u{B2AB}', GraphemeClusterBreak::LVT),
+    ('\u{B2AC}', '\u{B2AC}', GraphemeClusterBreak::LV),
+    ('\u{B2AD}', '\u{B2C7}', GraphemeClusterBreak::LVT),
+    ('\u{B2C8}', '\u{B2C8}', GraphemeClusterBreak::LV),
+    ('\u{B2C9}', '\u{B2E3}', GraphemeClusterBreak::LVT),
+    ('\u{B2E4}', '\u{B2E4}', GraphemeClusterBreak::LV),
+    ('\u{B2E5}', '\u{B2FF}', GraphemeClusterBreak::LVT),
+    ('\u{B300}', '\u{B300}', GraphemeClusterBreak::LV),
+    ('\u{B301}', '\u{B31B}', GraphemeClusterBreak::LVT),
+    ('\u{B31C}', '\u{B31C}', GraphemeClusterBreak::LV),
+    ('\u{B31D}', '\u{B337}', GraphemeClusterBreak::LVT),
+    ('\u{B338}', '\u{B338}', GraphemeClusterBreak::LV),
+    ('\u{B339}', '\u{B353}', GraphemeClusterBreak::LVT),
+    ('\u{B354}', '\u{B354}', GraphemeClusterBreak::LV),
+    ('\u{B355}', '\u{B36F}', GraphemeClusterBreak::LVT),
+    ('\u{B370}', '\u{B370}', GraphemeClusterBreak::LV),
+    ('\u{B371}', '\u{B38B}', GraphemeClusterBreak::LVT),
+    ('\u{B38C}', '\u{B38C}', GraphemeClusterBreak::LV),
+    ('\u{B38D}', '\u{B3A7}', GraphemeClusterBreak::LVT),
+    ('\u{B3A8}', '\u{B3A8}', GraphemeClusterBreak::LV),
+    ('\u{B3A9}', '\u{B3C3}', GraphemeClusterBreak::LVT),
+    ('\u{B3C4}', '\u{B3C4}', GraphemeClusterBreak::LV),
+    ('\u{B3C5}', '\u{B3DF}', GraphemeClusterBreak::LVT),
+    ('\u{B3E0}', '\u{B3E0}', GraphemeClusterBreak::LV),
+    ('\u{B3E1}', '\u{B3FB}', GraphemeClusterBreak::LVT),
+    ('\u{B3FC}', '\u{B3FC}', GraphemeClusterBreak::LV),
+    ('\u{B3FD}', '\u{B417}', GraphemeClusterBreak::LVT),
+    ('\u{B418}', '\u{B418}', GraphemeClusterBreak::LV),
+    ('\u{B419}', '\u{B433}', GraphemeClusterBreak::LVT),
+    ('\u{B434}', '\u{B434}', GraphemeClusterBreak::LV),
+    ('\u{B435}', '\u{B44F}', GraphemeClusterBreak::LVT),
+    ('\u{B450}', '\u{B450}', GraphemeClusterBreak::LV),
+    ('\u{B451}', '\u{B46B}', GraphemeClusterBreak::LVT),
+    ('\u{B46C}', '\u{B46C}', GraphemeClusterBreak::LV),
+    ('\u{B46D}', '\u{B487}', GraphemeClusterBreak::LVT),
+    ('\u{B488}', '\u{B488}', GraphemeClusterBreak::LV),
+    ('\u{B489}', '\u{B4A3}', GraphemeClusterBreak::LVT),
+    ('\u{B4A4}', '\u{B4A4}', GraphemeClusterBreak::LV),
+    ('\u{B4A5}', '\u{B4BF}', GraphemeClusterBreak::LVT),
+    ('\u{B4C0}', '\u{B4C0}', GraphemeClusterBreak::LV),
+    ('\u{B4C1}', '\u{B4DB}', GraphemeClusterBreak::LVT),
+    ('\u{B4DC}', '\u{B4DC}', GraphemeClusterBreak::LV),
+    ('\u{B4DD}', '\u{B4F7}', GraphemeClusterBreak::LVT),
+    ('\u{B4F8}', '\u{B4F8}', GraphemeClusterBreak::LV),
+    ('\u{B4F9}', '\u{B513}', GraphemeClusterBreak::LVT),
+    ('\u{B514}', '\u{B514}', GraphemeClusterBreak::LV),
+    ('\u{B515}', '\u{B52F}', GraphemeClusterBreak::LVT),
+    ('\u{B530}', '\u{B530}', GraphemeClusterBreak::LV),
+    ('\u{B531}', '\u{B54B}', GraphemeClusterBreak::LVT),
+    ('\u{B54C}', '\u{B54C}', GraphemeClusterBreak::LV),
+    ('\u{B54D}', '\u{B567}', GraphemeClusterBreak::LVT),
+    ('\u{B568}', '\u{B568}', GraphemeClusterBreak::LV),
+    ('\u{B569}', '\u{B583}', GraphemeClusterBreak::LVT),
+    ('\u{B584}', '\u{B584}', GraphemeClusterBreak::LV),
+    ('\u{B585}', '\u{B59F}', GraphemeClusterBreak::LVT),
+    ('\u{B5A0}', '\u{B5A0}', GraphemeClusterBreak::LV),
+    ('\u{B5A1}', '\u{B5BB}', GraphemeClusterBreak::LVT),
+    ('\u{B5BC}', '\u{B5BC}', GraphemeClusterBreak::LV),
+    ('\u{B5BD}', '\u{B5D7}', GraphemeClusterBreak::LVT),
+    ('\u{B5D8}', '\u{B5D8}', GraphemeClusterBreak::LV),
+    ('\u{B5D9}', '\u{B5F3}', GraphemeClusterBreak::LVT),
+    ('\u{B5F4}', '\u{B5F4}', GraphemeClusterBreak::LV),
+    ('\u{B5F5}', '\u{B60F}', GraphemeClusterBreak::LVT),
+    ('\u{B610}', '\u{B610}', GraphemeClusterBreak::LV),
+    ('\u{B611}', '\u{B62B}', GraphemeClusterBreak::LVT),
+    ('\u{B62C}', '\u{B62C}', GraphemeClusterBreak::LV),
+    ('\u{B62D}', '\u{B647}', GraphemeClusterBreak::LVT),
+    ('\u{B648}', '\u{B648}', GraphemeClusterBreak::LV),
+    ('\u{B649}', '\u{B663}', GraphemeClusterBreak::LVT),
+    ('\u{B664}', '\u{B664}', GraphemeClusterBreak::LV),
+    ('\u{B665}', '\u{B67F}', GraphemeClusterBreak::LVT),
+    ('\u{B680}', '\u{B680}', GraphemeClusterBreak::LV),
+    ('\u{B681}', '\u{B69B}', GraphemeClusterBreak::LVT),
+    ('\u{B69C}', '\u{B69C}', GraphemeClusterBreak::LV),
+    ('\u{B69D}', '\u{B6B7}', GraphemeClusterBreak::LVT),
+    ('\u{B6B8}', '\u{B6B8}', GraphemeClusterBreak::LV),
+    ('\u{B6B9}', '\u{B6D3}', GraphemeClusterBreak::LVT),
+    ('\u{B6D4}', '\u{B6D4}', GraphemeClusterBreak::LV),
+    ('\u{B6D5}', '\u{B6EF}', GraphemeClusterBreak::LVT),
+    ('\u{B6F0}', '\u{B6F0}', GraphemeClusterBreak::LV),
+    ('\u{B6F1}', '\u{B70B}', GraphemeClusterBreak::LVT),
+    ('\u{B70C}', '\u{B70C}', GraphemeClusterBreak::LV),
+    ('\u{B70D}', '\u{B727}', GraphemeClusterBreak::LVT),
+    ('\u{B728}', '\u{B728}', GraphemeClusterBreak::LV),
+    ('\u{B729}', '\u{B743}', GraphemeClusterBreak::LVT),
+    ('\u{B744}', '\u{B744}', GraphemeClusterBreak::LV),
+    ('\u{B745}', '\u{B75F}', GraphemeClusterBreak::LVT),
+    ('\u{B760}', '\u{B760}', GraphemeClusterBreak::LV),
+    ('\u{B761}', '\u{B77B}', GraphemeClusterBreak::LVT),
+    ('\u{B77C}', '\u{B77C}', GraphemeClusterBreak::LV),
+    ('\u{B77D}', '\u{B797}', GraphemeClusterBreak::LVT),
+    ('\u{B798}', '\u{B798}', GraphemeClusterBreak::LV),
+    ('\u{B799}', '\u{B7B3}', GraphemeClusterBreak::LVT),
+    ('\u{B7B4}', '\u{B7B4}', GraphemeClusterBreak::LV),
+    ('\u{B7B5}', '\u{B7CF}', GraphemeClusterBreak::LVT),
+    ('\u{B7D0}', '\u{B7D0}', GraphemeClusterBreak::LV),
+    ('\u{B7D1}', '\u{B7EB}', GraphemeClusterBreak::LVT),
+    ('\u{B7EC}', '\u{B7EC}', GraphemeClusterBreak::LV),
+    ('\u{B7ED}', '\u{B807}', GraphemeClusterBreak::LVT),
+    ('\u{B808}', '\u{B808}', GraphemeClusterBreak::LV),
+    ('\u{B809}', '\u{B823}', GraphemeClusterBreak::LVT),
+    ('\u{B824}', '\u{B824}', GraphemeClusterBreak::LV),
+    ('\u{B825}', '\u{B83F}', GraphemeClusterBreak::LVT),
+    ('\u{B840}', '\u{B840}', GraphemeClusterBreak::LV),
+    ('\u{B841}', '\u{B85B}', GraphemeClusterBreak::LVT),
+    ('\u{B85C}', '\u{B85C}', GraphemeClusterBreak::LV),
+    ('\u{B85D}', '\u{B877}', GraphemeClusterBreak::LVT),
+    ('\u{B878}', '\u{B878}', GraphemeClusterBreak::LV),
+    ('\u{B879}', '\u{B893}', GraphemeClusterBreak::LVT),
+    ('\u{B894}', '\u{B894}', GraphemeClusterBreak::LV),
+    ('\u{B895}', '\u{B8AF}', GraphemeClusterBreak::LVT),
+    ('\u{B8B0}', '\u{B8B0}', GraphemeClusterBreak::LV),
+    ('\u{B8B1}', '\u{B8CB}', GraphemeClusterBreak::LVT),
+    ('\u{B8CC}', '\u{B8CC}', GraphemeClusterBreak::LV),
+    ('\u{B8CD}', '\u{B8E7}', GraphemeClusterBreak::LVT),
+    ('\u{B8E8}', '\u{B8E8}', GraphemeClusterBreak::LV),
+    ('\u{B8E9}', '\u{B903}', GraphemeClusterBreak::LVT),
+    ('\u{B904}', '\u{B904}', GraphemeClusterBreak::LV),
+    ('\u{B905}', '\u{B91F}', GraphemeClusterBreak::LVT),
+    ('\u{B920}', '\u{B920}', GraphemeClusterBreak::LV),
+    ('\u{B921}', '\u{B93B}', GraphemeClusterBreak::LVT),
+    ('\u{B93C}', '\u{B93C}', GraphemeClusterBreak::LV),
+    ('\u{B93D}', '\u{B957}', GraphemeClusterBreak::LVT),
+    ('\u{B958}', '\u{B958}', GraphemeClusterBreak::LV),
+    ('\u{B959}', '\u{B973}', GraphemeClusterBreak::LVT),
+    ('\u{B974}', '\u{B974}', GraphemeClusterBreak::LV),
+    ('\u{B975}', '\u{B98F}', GraphemeClusterBreak::LVT),
+    ('\u{B990}', '\u{B990}', GraphemeClusterBreak::LV),
+    ('\u{B991}', '\u{B9AB}', GraphemeClusterBreak::LVT),
+    ('\u{B9AC}', '\u{B9AC}', GraphemeClusterBreak::LV),
+    ('\u{B9AD}', '\u{B9C7}', GraphemeClusterBreak::LVT),
+    ('\u{B9C8}', '\u{B9C8}', GraphemeClusterBreak::LV),
+    ('\u{B9C9}', '\u{B9E3}', GraphemeClusterBreak::LVT),
+    ('\u{B9E4}', '\u{B9E4}', GraphemeClusterBreak::LV),
+    ('\u{B9E5}', '\u{B9FF}', GraphemeClusterBreak::LVT),
+    ('\u{BA00}', '\u{BA00}', GraphemeClusterBreak::LV),
+    ('\u{BA01}', '\u{BA1B}', GraphemeClusterBreak::LVT),
+    ('\u{BA1C}', '\u{BA1C}', GraphemeClusterBreak::LV),
+    ('\u{BA1D}', '\u{BA37}', GraphemeClusterBreak::LVT),
+    ('\u{BA38}', '\u{BA38}', GraphemeClusterBreak::LV),
+    ('\u{BA39}', '\u{BA53}', GraphemeClusterBreak::LVT),
+    ('\u{BA54}', '\u{BA54}', GraphemeClusterBreak::LV),
+    ('\u{BA55}', '\u{BA6F}', GraphemeClusterBreak::LVT),
+    ('\u{BA70}', '\u{BA70}', GraphemeClusterBreak::LV),
+    ('\u{BA71}', '\u{BA8B}', GraphemeClusterBreak::LVT),
+    ('\u{BA8C}', '\u{BA8C}', GraphemeClusterBreak::LV),
+    ('\u{BA8D}', '\u{BAA7}', GraphemeClusterBreak::LVT),
+    ('\u{BAA8}', '\u{BAA8}', GraphemeClusterBreak::LV),
+    ('\u{BAA9}', '\u{BAC3}', GraphemeClusterBreak::LVT),
+    ('\u{BAC4}', '\u{BAC4}', GraphemeClusterBreak::LV),
+    ('\u{BAC5}', '\u{BADF}', GraphemeClusterBreak::LVT),
+    ('\u{BAE0}', '\u{BAE0}', GraphemeClusterBreak::LV),
+    ('\u{BAE1}', '\u{BAFB}', GraphemeClusterBreak::LVT),
+    ('\u{BAFC}', '\u{BAFC}', GraphemeClusterBreak::LV),
+    ('\u{BAFD}', '\u{BB17}', GraphemeClusterBreak::LVT),
+    ('\u{BB18}', '\u{BB18}', GraphemeClusterBreak::LV),
+    ('\u{BB19}', '\u{BB33}', GraphemeClusterBreak::LVT),
+    ('\u{BB34}', '\u{BB34}', GraphemeClusterBreak::LV),
+    ('\u{BB35}', '\u{BB4F}', GraphemeClusterBreak::LVT),
+    ('\u{BB50}', '\u{BB50}', GraphemeClusterBreak::LV),
+    ('\u{BB51}', '\u{BB6B}', GraphemeClusterBreak::LVT),
+    ('\u{BB6C}', '\u{BB6C}', GraphemeClusterBreak::LV),
+    ('\u{BB6D}', '\u{BB87}', GraphemeClusterBreak::LVT),
+    ('\u{BB88}', '\u{BB88}', GraphemeClusterBreak::LV),
+    ('\u{BB89}', '\u{BBA3}', GraphemeClusterBreak::LVT),
+    ('\u{BBA4}', '\u{BBA4}', GraphemeClusterBreak::LV),
+    ('\u{BBA5}', '\u{BBBF}', GraphemeClusterBreak::LVT),
+    ('\u{BBC0}', '\u{BBC0}', GraphemeClusterBreak::LV),
+    ('\u{BBC1}', '\u{BBDB}', GraphemeClusterBreak::LVT),
+    ('\u{BBDC}', '\u{BBDC}', GraphemeClusterBreak::LV),
+    ('\u{BBDD}', '\u{BBF7}', GraphemeClusterBreak::LVT),
+    ('\u{BBF8}', '\u{BBF8}', GraphemeClusterBreak::LV),
+    ('\u{BBF9}', '\u{BC13}', GraphemeClusterBreak::LVT),
+    ('\u{BC14}', '\u{BC14}', GraphemeClusterBreak::LV),
+    ('\u{BC15}', '\u{BC2F}', GraphemeClusterBreak::LVT),
+    ('\u{BC30}', '\u{BC30}', GraphemeClusterBreak::LV),
+    ('\u{BC31}', '\u{BC4B}', GraphemeClusterBreak::LVT),
+    ('\u{BC4C}', '\u{BC4C}', GraphemeClusterBreak::LV),
+    ('\u{BC4D}', '\u{BC67}', GraphemeClusterBreak::LVT),
+    ('\u{BC68}', '\u{BC68}', GraphemeClusterBreak::LV),
+    ('\u{BC69}', '\u{BC83}', GraphemeClusterBreak::LVT),
+    ('\u{BC84}', '\u{BC84}', GraphemeClusterBreak::LV),
+    ('\u{BC85}', '\u{BC9F}', GraphemeClusterBreak::LVT),
+    ('\u{BCA0}', '\u{BCA0}', GraphemeClusterBreak::LV),
+    ('\u{BCA1}', '\u{BCBB}', GraphemeClusterBreak::LVT),
+    ('\u{BCBC}', '\u{BCBC}', GraphemeClusterBreak::LV),
+    ('\u{BCBD}', '\u{BCD7}', GraphemeClusterBreak::LVT),
+    ('\u{BCD8}', '\u{BCD8}', GraphemeClusterBreak::LV),
+    ('\u{BCD9}', '\u{BCF3}', GraphemeClusterBreak::LVT),
+    ('\u{BCF4}', '\u{BCF4}', GraphemeClusterBreak::LV),
+    ('\u{BCF5}', '\u{BD0F}', GraphemeClusterBreak::LVT),
+    ('\u{BD10}', '\u{BD10}', GraphemeClusterBreak::LV),
+    ('\u{BD11}', '\u{BD2B}', GraphemeClusterBreak::LVT),
+    ('\u{BD2C}', '\u{BD2C}', GraphemeClusterBreak::LV),
+    ('\u{BD2D}', '\u{BD47}', GraphemeClusterBreak::LVT),
+    ('\u{BD48}', '\u{BD48}', GraphemeClusterBreak::LV),
+    ('\u{BD49}', '\u{BD63}', GraphemeClusterBreak::LVT),
+    ('\u{BD64}', '\u{BD64}', GraphemeClusterBreak::LV),
+    ('\u{BD65}', '\u{BD7F}', GraphemeClusterBreak::LVT),
+    ('\u{BD80}', '\u{BD80}', GraphemeClusterBreak::LV),
+    ('\u{BD81}', '\u{BD9B}', GraphemeClusterBreak::LVT),
+    ('\u{BD9C}', '\u{BD9C}', GraphemeClusterBreak::LV),
+    ('\u{BD9D}', '\u{BDB7}', GraphemeClusterBreak::LVT),
+    ('\u{BDB8}', '\u{BDB8}', GraphemeClusterBreak::LV),
+    ('\u{BDB9}', '\u{BDD3}', GraphemeClusterBreak::LVT),
+    ('\u{BDD4}', '\u{BDD4}', GraphemeClusterBreak::LV),
+    ('\u{BDD5}', '\u{BDEF}', GraphemeClusterBreak::LVT),
+    ('\u{BDF0}', '\u{BDF0}', GraphemeClusterBreak::LV),
+    ('\u{BDF1}', '\u{BE0B}', GraphemeClusterBreak::LVT),
+    ('\u{BE0C}', '\u{BE0C}', GraphemeClusterBreak::LV),
+    ('\u{BE0D}', '\u{BE27}', GraphemeClusterBreak::LVT),
+    ('\u{BE28}', '\u{BE28}', GraphemeClusterBreak::LV),
+    ('\u{BE29}', '\u{BE43}', GraphemeClusterBreak::LVT),
+    ('\u{BE44}', '\u{BE44}', GraphemeClusterBreak::LV),
+    ('\u{BE45}', '\u{BE5F}', GraphemeClusterBreak::LVT),
+    ('\u{BE60}', '\u{BE60}', GraphemeClusterBreak::LV),
+    ('\u{BE61}', '\u{BE7B}', GraphemeClusterBreak::LVT),
+    ('\u{BE7C}', '\u{BE7C}', GraphemeClusterBreak::LV),
+    ('\u{BE7D}', '\u{BE97}', GraphemeClusterBreak::LVT),
+    ('\u{BE98}', '\u{BE98}', GraphemeClusterBreak::LV),
+    ('\u{BE99}', '\u{BEB3}', GraphemeClusterBreak::LVT),
+    ('\u{BEB4}', '\u{BEB4}', GraphemeClusterBreak::LV),
+    ('\u{BEB5}', '\u{BECF}', GraphemeClusterBreak::LVT),
+    ('\u{BED0}', '\u{BED0}', GraphemeClusterBreak::LV),
+    ('\u{BED1}', '\u{BEEB}', GraphemeClusterBreak::LVT),
+    ('\u{BEEC}', '\u{BEEC}', GraphemeClusterBreak::LV),
+    ('\u{BEED}', '\u{BF07}', GraphemeClusterBreak::LVT),
+    ('\u{BF08}', '\u{BF08}', GraphemeClusterBreak::LV),
+    ('\u{BF09}', '\u{BF23}', GraphemeClusterBreak::LVT),
+    ('\u{BF24}', '\u{BF24}', GraphemeClusterBreak::LV),
+    ('\u{BF25}', '\u{BF3F}', GraphemeClusterBreak::LVT),
+    ('\u{BF40}', '\u{BF40}', GraphemeClusterBreak::LV),
+    ('\u{BF41}', '\u{BF5B}', GraphemeClusterBreak::LVT),
+    ('\u{BF5C}', '\u{BF5C}', GraphemeClusterBreak::LV),
+    ('\u{BF5D}', '\u{BF77}', GraphemeClusterBreak::LVT),
+    ('\u{BF78}', '\u{BF78}', GraphemeClusterBreak::LV),
+    ('\u{BF79}', '\u{BF93}', GraphemeClusterBreak::LVT),
+    ('\u{BF94}', '\u{BF94}', GraphemeClusterBreak::LV),
+    ('\u{BF95}', '\u{BFAF}', GraphemeClusterBreak::LVT),
+    ('\u{BFB0}', '\u{BFB0}', GraphemeClusterBreak::LV),
+    ('\u{BFB1}', '\u{BFCB}', GraphemeClusterBreak::LVT),
+    ('\u{BFCC}', '\u{BFCC}', GraphemeClusterBreak::LV),
+    ('\u{BFCD}', '\u{BFE7}', GraphemeClusterBreak::LVT),
+    ('\u{BFE8}', '\u{BFE8}', GraphemeClusterBreak::LV),
+    ('\u{BFE9}', '\u{C003}', GraphemeClusterBreak::LVT),
+    ('\u{C004}', '\u{C004}', GraphemeClusterBreak::LV),
+    ('\u{C005}', '\u{C01F}', GraphemeClusterBreak::LVT),
+    ('\u{C020}', '\u{C020}', GraphemeClusterBreak::LV),
+    ('\u{C021}', '\u{C03B}', GraphemeClusterBreak::LVT),
+    ('\u{C03C}', '\u{C03C}', GraphemeClusterBreak::LV),
+    ('\u{C03D}', '\u{C057}', GraphemeClusterBreak::LVT),
+    ('\u{C058}', '\u{C058}', GraphemeClusterBreak::LV),
+    ('\u{C059}', '\u{C073}', GraphemeClusterBreak::LVT),
+    ('\u{C074}', '\u{C074}', GraphemeClusterBreak::LV),
+    ('\u{C075}', '\u{C08F}', GraphemeClusterBreak::LVT),
+    ('\u{C090}', '\u{C090}', GraphemeClusterBreak::LV),
+    ('\u{C091}', '\u{C0AB}', GraphemeClusterBreak::LVT),
+    ('\u{C0AC}', '\u{C0AC}', GraphemeClusterBreak::LV),
+    ('\u{C0AD}', '\u{C0C7}', GraphemeClusterBreak::LVT),
+    ('\u{C0C8}', '\u{C0C8}', GraphemeClusterBreak::LV),
+    ('\u{C0C9}', '\u{C0E3}', GraphemeClusterBreak::LVT),
+    ('\u{C0E4}', '\u{C0E4}', GraphemeClusterBreak::LV),
+    ('\u{C0E5}', '\u{C0FF}', GraphemeClusterBreak::LVT),
+    ('\u{C100}', '\u{C100}', GraphemeClusterBreak::LV),
+    ('\u{C101}', '\u{C11B}', GraphemeClusterBreak::LVT),
+    ('\u{C11C}', '\u{C11C}', GraphemeClusterBreak::LV),
+    ('\u{C11D}', '\u{C137}', GraphemeClusterBreak::LVT),
+    ('\u{C138}', '\u{C138}', GraphemeClusterBreak::LV),
+    ('\u{C139}', '\u{C153}', GraphemeClusterBreak::LVT),
+    ('\u{C154}', '\u{C154}', GraphemeClusterBreak::LV),
+    ('\u{C155}', '\u{C16F}', GraphemeClusterBreak::LVT),
+    ('\u{C170}', '\u{C170}', GraphemeClusterBreak::LV),
+    ('\u{C171}', '\u{C18B}', GraphemeClusterBreak::LVT),
+    ('\u{C18C}', '\u{C18C}', GraphemeClusterBreak::LV),
+    ('\u{C18D}', '\u{C1A7}', GraphemeClusterBreak::LVT),
+    ('\u{C1A8}', '\u{C1A8}', GraphemeClusterBreak::LV),
+    ('\u{C1A9}', '\u{C1C3}', GraphemeClusterBreak::LVT),
+    ('\u{C1C4}', '\u{C1C4}', GraphemeClusterBreak::LV),
+    ('\u{C1C5}', '\u{C1DF}', GraphemeClusterBreak::LVT),
+    ('\u{C1E0}', '\u{C1E0}', GraphemeClusterBreak::LV),
+    ('\u{C1E1}', '\u{C1FB}', GraphemeClusterBreak::LVT),
+    ('\u{C1FC}', '\u{C1FC}', GraphemeClusterBreak::LV),
+    ('\u{C1FD}', '\u{C217}', GraphemeClusterBreak::LVT),
+    ('\u{C218}', '\u{C218}', GraphemeClusterBreak::LV),
+    ('\u{C219}', '\u{C233}', GraphemeClusterBreak::LVT),
+    ('\u{C234}', '\u{C234}', GraphemeClusterBreak::LV),
+    ('\u{C235}', '\u{C24F}', GraphemeClusterBreak::LVT),
+    ('\u{C250}', '\u{C250}', GraphemeClusterBreak::LV),
+    ('\u{C251}', '\u{C26B}', GraphemeClusterBreak::LVT),
+    ('\u{C26C}', '\u{C26C}', GraphemeClusterBreak::LV),
+    ('\u{C26D}', '\u{C287}', GraphemeClusterBreak::LVT),
+    ('\u{C288}', '\u{C288}', GraphemeClusterBreak::LV),
+    ('\u{C289}', '\u{C2A3}', GraphemeClusterBreak::LVT),
+    ('\u{C2A4}', '\u{C2A4}', GraphemeClusterBreak::LV),
+    ('\u{C2A5}', '\u{C2BF}', GraphemeClusterBreak::LVT),
+    ('\u{C2C0}', '\u{C2C0}', GraphemeClusterBreak::LV),
+    ('\u{C2C1}', '\u{C2DB}', GraphemeClusterBreak::LVT),
+    ('\u{C2DC}', '\u{C2DC}', GraphemeClusterBreak::LV),
+    ('\u{C2DD}', '\u{C2F7}', GraphemeClusterBreak::LVT),
+    ('\u{C2F8}', '\u{C2F8}', GraphemeClusterBreak::LV),
+    ('\u{C2F9}', '\u{C313}', GraphemeClusterBreak::LVT),
+    ('\u{C314}', '\u{C314}', GraphemeClusterBreak::LV),
+    ('\u{C315}', '\u{C32F}', GraphemeClusterBreak::LVT),
+    ('\u{C330}', '\u{C330}', GraphemeClusterBreak::LV),
+    ('\u{C331}', '\u{C34B}', GraphemeClusterBreak::LVT),
+    ('\u{C34C}', '\u{C34C}', GraphemeClusterBreak::LV),
+    ('\u{C34D}', '\u{C367}', GraphemeClusterBreak::LVT),
+    ('\u{C368}', '\u{C368}', GraphemeClusterBreak::LV),
+    ('\u{C369}', '\u{C383}', GraphemeClusterBreak::LVT),
+    ('\u{C384}', '\u{C384}', GraphemeClusterBreak::LV),
+    ('\u{C385}', '\u{C39F}', GraphemeClusterBreak::LVT),
+    ('\u{C3A0}', '\u{C3A0}', GraphemeClusterBreak::LV),
+    ('\u{C3A1}', '\u{C3BB}', GraphemeClusterBreak::LVT),
+    ('\u{C3BC}', '\u{C3BC}', GraphemeClusterBreak::LV),
+    ('\u{C3BD}', '\u{C3D7}', GraphemeClusterBreak::LVT),
+    ('\u{C3D8}', '\u{C3D8}', GraphemeClusterBreak::LV),
+    ('\u{C3D9}', '\u{C3F3}', GraphemeClusterBreak::LVT),
+    ('\u{C3F4}', '\u{C3F4}', GraphemeClusterBreak::LV),
+    ('\u{C3F5}', '\u{C40F}', GraphemeClusterBreak::LVT),
+    ('\u{C410}', '\u{C410}', GraphemeClusterBreak::LV),
+    ('\u{C411}', '\u{C42B}', GraphemeClusterBreak::LVT),
+    ('\u{C42C}', '\u{C42C}', GraphemeClusterBreak::LV),
+    ('\u{C42D}', '\u{C447}', GraphemeClusterBreak::LVT),
+    ('\u{C448}', '\u{C448}', GraphemeClusterBreak::LV),
+    ('\u{C449}', '\u{C463}', GraphemeClusterBreak::LVT),
+    ('\u{C464}', '\u{C464}', GraphemeClusterBreak::LV),
+    ('\u{C465}', '\u{C47F}', GraphemeClusterBreak::LVT),
+    ('\u{C480}', '\u{C480}', GraphemeClusterBreak::LV),
+    ('\u{C481}', '\u{C49B}', GraphemeClusterBreak::LVT),
+    ('\u{C49C}', '\u{C49C}', GraphemeClusterBreak::LV),
+    ('\u{C49D}', '\u{C4B7}', GraphemeClusterBreak::LVT),
+    ('\u{C4B8}', '\u{C4B8}', GraphemeClusterBreak::LV),
+    ('\u{C4B9}', '\u{C4D3}', GraphemeClusterBreak::LVT),
+    ('\u{C4D4}', '\u{C4D4}', GraphemeClusterBreak::LV),
+    ('\u{C4D5}', '\u{C4EF}', GraphemeClusterBreak::LVT),
+    ('\u{C4F0}', '\u{C4F0}', GraphemeClusterBreak::LV),
+    ('\u{C4F1}', '\u{C50B}', GraphemeClusterBreak::LVT),
+    ('\u{C50C}', '\u{C50C}', GraphemeClusterBreak::LV),
+    ('\u{C50D}', '\u{C527}', GraphemeClusterBreak::LVT),
+    ('\u{C528}', '\u{C528}', GraphemeClusterBreak::LV),
+    ('\u{C529}', '\u{C543}', GraphemeClusterBreak::LVT),
+    ('\u{C544}', '\u{C544}', GraphemeClusterBreak::LV),
+    ('\u{C545}', '\u{C55F}', GraphemeClusterBreak::LVT),
+    ('\u{C560}', '\u{C560}', GraphemeClusterBreak::LV),
+    ('\u{C561}', '\u{C57B}', GraphemeClusterBreak::LVT),
+    ('\u{C57C}', '\u{C57C}', GraphemeClusterBreak::LV),
+    ('\u{C57D}', '\u{C597}', GraphemeClusterBreak::LVT),
+    ('\u{C598}', '\u{C598}', GraphemeClusterBreak::LV),
+    ('\u{C599}', '\u{C5B3}', GraphemeClusterBreak::LVT),
+    ('\u{C5B4}', '\u{C5B4}', GraphemeClusterBreak::LV),
+    ('\u{C5B5}', '\u{C5CF}', GraphemeClusterBreak::LVT),
+    ('\u{C5D0}', '\u{C5D0}', GraphemeClusterBreak::LV),
+    ('\u{C5D1}', '\u{C5EB}', GraphemeClusterBreak::LVT),
+    ('\u{C5EC}', '\u{C5EC}', GraphemeClusterBreak::LV),
+    ('\u{C5ED}', '\u{C607}', GraphemeClusterBreak::LVT),
+    ('\u{C608}', '\u{C608}', GraphemeClusterBreak::LV),
+    ('\u{C609}', '\u{C623}', GraphemeClusterBreak::LVT),
+    ('\u{C624}', '\u{C624}', GraphemeClusterBreak::LV),
+    ('\u{C625}', '\u{C63F}', GraphemeClusterBreak::LVT),
+    ('\u{C640}', '\u{C640}', GraphemeClusterBreak::LV),
+    ('\u{C641}', '\u{C65B}', GraphemeClusterBreak::LVT),
+    ('\u{C65C}', '\u{C65C}', GraphemeClusterBreak::LV),
+    ('\u{C65D}', '\u{C677}', GraphemeClusterBreak::LVT),
+    ('\u{C678}', '\u{C678}', GraphemeClusterBreak::LV),
+    ('\u{C679}', '\u{C693}', GraphemeClusterBreak::LVT),
+    ('\u{C694}', '\u{C694}', GraphemeClusterBreak::LV),
+    ('\u{C695}', '\u{C6AF}', GraphemeClusterBreak::LVT),
+    ('\u{C6B0}', '\u{C6B0}', GraphemeClusterBreak::LV),
+    ('\u{C6B1}', '\u{C6CB}', GraphemeClusterBreak::LVT),
+    ('\u{C6CC}', '\u{C6CC}', GraphemeClusterBreak::LV),
+    ('\u{C6CD}', '\u{C6E7}', GraphemeClusterBreak::LVT),
+    ('\u{C6E8}', '\u{C6E8}', GraphemeClusterBreak::LV),
+    ('\u{C6E9}', '\u{C703}', GraphemeClusterBreak::LVT),
+    ('\u{C704}', '\u{C704}', GraphemeClusterBreak::LV),
+    ('\u{C705}', '\u{C71F}', GraphemeClusterBreak::LVT),
+    ('\u{C720}', '\u{C720}', GraphemeClusterBreak::LV),
+    ('\u{C721}', '\u{C73B}', GraphemeClusterBreak::LVT),
+    ('\u{C73C}', '\u{C73C}', GraphemeClusterBreak::LV),
+    ('\u{C73D}', '\u{C757}', GraphemeClusterBreak::LVT),
+    ('\u{C758}', '\u{C758}', GraphemeClusterBreak::LV),
+    ('\u{C759}', '\u{C773}', GraphemeClusterBreak::LVT),
+    ('\u{C774}', '\u{C774}', GraphemeClusterBreak::LV),
+    ('\u{C775}', '\u{C78F}', GraphemeClusterBreak::LVT),
+    ('\u{C790}', '\u{C790}', GraphemeClusterBreak::LV),
+    ('\u{C791}', '\u{C7AB}', GraphemeClusterBreak::LVT),
+    ('\u{C7AC}', '\u{C7AC}', GraphemeClusterBreak::LV),
+    ('\u{C7AD}', '\u{C7C7}', GraphemeClusterBreak::LVT),
+    ('\u{C7C8}', '\u{C7C8}', GraphemeClusterBreak::LV),
+    ('\u{C7C9}', '\u{C7E3}', GraphemeClusterBreak::LVT),
+    ('\u{C7E4}', '\u{C7E4}', GraphemeClusterBreak::LV),
+    ('\u{C7E5}', '\u{C7FF}', GraphemeClusterBreak::LVT),
+    ('\u{C800}', '\u{C800}', GraphemeClusterBreak::LV),
+    ('\u{C801}', '\u{C81B}', GraphemeClusterBreak::LVT),
+    ('\u{C81C}', '\u{C81C}', GraphemeClusterBreak::LV),
+    ('\u{C81D}', '\u{C837}', GraphemeClusterBreak::LVT),
+    ('\u{C838}', '\u{C838}', GraphemeClusterBreak::LV),
+    ('\u{C839}', '\u{C853}', GraphemeClusterBreak::LVT),
+    ('\u{C854}', '\u{C854}', GraphemeClusterBreak::LV),
+    ('\u{C855}', '\u{C86F}', GraphemeClusterBreak::LVT),
+    ('\u{C870}', '\u{C870}', GraphemeClusterBreak::LV),
+    ('\u{C871}', '\u{C88B}', GraphemeClusterBreak::LVT),
+    ('\u{C88C}', '\u{C88C}', GraphemeClusterBreak::LV),
+    ('\u{C88D}', '\u{C8A7}', GraphemeClusterBreak::LVT),
+    ('\u{C8A8}', '\u{C8A8}', GraphemeClusterBreak::LV),
+    ('\u{C8A9}', '\u{C8C3}', GraphemeClusterBreak::LVT),
+    ('\u{C8C4}', '\u{C8C4}', GraphemeClusterBreak::LV),
+    ('\u{C8C5}', '\u{C8DF}', GraphemeClusterBreak::LVT),
+    ('\u{C8E0}', '\u{C8E0}', GraphemeClusterBreak::LV),
+    ('\u{C8E1}', '\u{C8FB}', GraphemeClusterBreak::LVT),
+    ('\u{C8FC}', '\u{C8FC}', GraphemeClusterBreak::LV),
+    ('\u{C8FD}', '\u{C917}', GraphemeClusterBreak::LVT),
+    ('\u{C918}', '\u{C918}', GraphemeClusterBreak::LV),
+    ('\u{C919}', '\u{C933}', GraphemeClusterBreak::LVT),
+    ('\u{C934}', '\u{C934}', GraphemeClusterBreak::LV),
+    ('\u{C935}', '\u{C94F}', GraphemeClusterBreak::LVT),
+    ('\u{C950}', '\u{C950}', GraphemeClusterBreak::LV),
+    ('\u{C951}', '\u{C96B}', GraphemeClusterBreak::LVT),
+    ('\u{C96C}', '\u{C96C}', GraphemeClusterBreak::LV),
+    ('\u{C96D}', '\u{C987}', GraphemeClusterBreak::LVT),
+    ('\u{C988}', '\u{C988}', GraphemeClusterBreak::LV),
+    ('\u{C989}', '\u{C9A3}', GraphemeClusterBreak::LVT),
+    ('\u{C9A4}', '\u{C9A4}', GraphemeClusterBreak::LV),
+    ('\u{C9A5}', '\u{C9BF}', GraphemeClusterBreak::LVT),
+    ('\u{C9C0}', '\u{C9C0}', GraphemeClusterBreak::LV),
+    ('\u{C9C1}', '\u{C9DB}', GraphemeClusterBreak::LVT),
+    ('\u{C9DC}', '\u{C9DC}', GraphemeClusterBreak::LV),
+    ('\u{C9DD}', '\u{C9F7}', GraphemeClusterBreak::LVT),
+    ('\u{C9F8}', '\u{C9F8}', GraphemeClusterBreak::LV),
+    ('\u{C9F9}', '\u{CA13}', GraphemeClusterBreak::LVT),
+    ('\u{CA14}', '\u{CA14}', GraphemeClusterBreak::LV),
+    ('\u{CA15}', '\u{CA2F}', GraphemeClusterBreak::LVT),
+    ('\u{CA30}', '\u{CA30}', GraphemeClusterBreak::LV),
+    ('\u{CA31}', '\u{CA4B}', GraphemeClusterBreak::LVT),
+    ('\u{CA4C}', '\u{CA4C}', GraphemeClusterBreak::LV),
+    ('\u{CA4D}', '\u{CA67}', GraphemeClusterBreak::LVT),
+    ('\u{CA68}', '\u{CA68}', GraphemeClusterBreak::LV),
+    ('\u{CA69}', '\u{CA83}', GraphemeClusterBreak::LVT),
+    ('\u{CA84}', '\u{CA84}', GraphemeClusterBreak::LV),
+    ('\u{CA85}', '\u{CA9F}', GraphemeClusterBreak::LVT),
+    ('\u{CAA0}', '\u{CAA0}', GraphemeClusterBreak::LV),
+    ('\u{CAA1}', '\u{CABB}', GraphemeClusterBreak::LVT),
+    ('\u{CABC}', '\u{CABC}', GraphemeClusterBreak::LV),
+    ('\u{CABD}', '\u{CAD7}', GraphemeClusterBreak::LVT),
+    ('\u{CAD8}', '\u{CAD8}', GraphemeClusterBreak::LV),
+    ('\u{CAD9}', '\u{CAF3}', GraphemeClusterBreak::LVT),
+    ('\u{CAF4}', '\u{CAF4}', GraphemeClusterBreak::LV),
+    ('\u{CAF5}', '\u{CB0F}', GraphemeClusterBreak::LVT),
+    ('\u{CB10}', '\u{CB10}', GraphemeClusterBreak::LV),
+    ('\u{CB11}', '\u{CB2B}', GraphemeClusterBreak::LVT),
+    ('\u{CB2C}', '\u{CB2C}', GraphemeClusterBreak::LV),
+    ('\u{CB2D}', '\u{CB47}', GraphemeClusterBreak::LVT),
+    ('\u{CB48}', '\u{CB48}', GraphemeClusterBreak::LV),
+    ('\u{CB49}', '\u{CB63}', GraphemeClusterBreak::LVT),
+    ('\u{CB64}', '\u{CB64}', GraphemeClusterBreak::LV),
+    ('\u{CB65}', '\u{CB7F}', GraphemeClusterBreak::LVT),
+    ('\u{CB80}', '\u{CB80}', GraphemeClusterBreak::LV),
+    ('\u{CB81}', '\u{CB9B}', GraphemeClusterBreak::LVT),
+    ('\u{CB9C}', '\u{CB9C}', GraphemeClusterBreak::LV),
+    ('\u{CB9D}', '\u{CBB7}', GraphemeClusterBreak::LVT),
+    ('\u{CBB8}', '\u{CBB8}', GraphemeClusterBreak::LV),
+    ('\u{CBB9}', '\u{CBD3}', GraphemeClusterBreak::LVT),
+    ('\u{CBD4}', '\u{CBD4}', GraphemeClusterBreak::LV),
+    ('\u{CBD5}', '\u{CBEF}', GraphemeClusterBreak::LVT),
+    ('\u{CBF0}', '\u{CBF0}', GraphemeClusterBreak::LV),
+    ('\u{CBF1}', '\u{CC0B}', GraphemeClusterBreak::LVT),
+    ('\u{CC0C}', '\u{CC0C}', GraphemeClusterBreak::LV),
+    ('\u{CC0D}', '\u{CC27}', GraphemeClusterBreak::LVT),
+    ('\u{CC28}', '\u{CC28}', GraphemeClusterBreak::LV),
+    ('\u{CC29}', '\u{CC43}', GraphemeClusterBreak::LVT),
+    ('\u{CC44}', '\u{CC44}', GraphemeClusterBreak::LV),
+    ('\u{CC45}', '\u{CC5F}', GraphemeClusterBreak::LVT),
+    ('\u{CC60}', '\u{CC60}', GraphemeClusterBreak::LV),
+    ('\u{CC61}', '\u{CC7B}', GraphemeClusterBreak::LVT),
+    ('\u{CC7C}', '\u{CC7C}', GraphemeClusterBreak::LV),
+    ('\u{CC7D}', '\u{CC97}', GraphemeClusterBreak::LVT),
+    ('\u{CC98}', '\u{CC98}', GraphemeClusterBreak::LV),
+    ('\u{CC99}', '\u{CCB3}', GraphemeClusterBreak::LVT),
+    ('\u{CCB4}', '\u{CCB4}', GraphemeClusterBreak::LV),
+    ('\u{CCB5}', '\u{CCCF}', GraphemeClusterBreak::LVT),
+    ('\u{CCD0}', '\u{CCD0}', GraphemeClusterBreak::LV),
+    ('\u{CCD1}', '\u{CCEB}', GraphemeClusterBreak::LVT),
+    ('\u{CCEC}', '\u{CCEC}', GraphemeClusterBreak::LV),
+    ('\u{CCED}', '\u{CD07}', GraphemeClusterBreak::LVT),
+    ('\u{CD08}', '\u{CD08}', GraphemeClusterBreak::LV),
+    ('\u{CD09}', '\u{CD23}', GraphemeClusterBreak::LVT),
+    ('\u{CD24}', '\u{CD24}', GraphemeClusterBreak::LV),
+    ('\u{CD25}', '\u{CD3F}', GraphemeClusterBreak::LVT),
+    ('\u{CD40}', '\u{CD40}', GraphemeClusterBreak::LV),
+    ('\u{CD41}', '\u{CD5B}', GraphemeClusterBreak::LVT),
+    ('\u{CD5C}', '\u{CD5C}', GraphemeClusterBreak::LV),
+    ('\u{CD5D}', '\u{CD77}', GraphemeClusterBreak::LVT),
+    ('\u{CD78}', '\u{CD78}', GraphemeClusterBreak::LV),
+    ('\u{CD79}', '\u{CD93}', GraphemeClusterBreak::LVT),
+    ('\u{CD94}', '\u{CD94}', GraphemeClusterBreak::LV),
+    ('\u{CD95}', '\u{CDAF}', GraphemeClusterBreak::LVT),
+    ('\u{CDB0}', '\u{CDB0}', GraphemeClusterBreak::LV),
+    ('\u{CDB1}', '\u{CDCB}', GraphemeClusterBreak::LVT),
+    ('\u{CDCC}', '\u{CDCC}', GraphemeClusterBreak::LV),
+    ('\u{CDCD}', '\u{CDE7}', GraphemeClusterBreak::LVT),
+    ('\u{CDE8}', '\u{CDE8}', GraphemeClusterBreak::LV),
+    ('\u{CDE9}', '\u{CE03}', GraphemeClusterBreak::LVT),
+    ('\u{CE04}', '\u{CE04}', GraphemeClusterBreak::LV),
+    ('\u{CE05}', '\u{CE1F}', GraphemeClusterBreak::LVT),
+    ('\u{CE20}', '\u{CE20}', GraphemeClusterBreak::LV),
+    ('\u{CE21}', '\u{CE3B}', GraphemeClusterBreak::LVT),
+    ('\u{CE3C}', '\u{CE3C}', GraphemeClusterBreak::LV),
+    ('\u{CE3D}', '\u{CE57}', GraphemeClusterBreak::LVT),
+    ('\u{CE58}', '\u{CE58}', GraphemeClusterBreak::LV),
+    ('\u{CE59}', '\u{CE73}', GraphemeClusterBreak::LVT),
+    ('\u{CE74}', '\u{CE74}', GraphemeClusterBreak::LV),
+    ('\u{CE75}', '\u{CE8F}', GraphemeClusterBreak::LVT),
+    ('\u{CE90}', '\u{CE90}', GraphemeClusterBreak::LV),
+    ('\u{CE91}', '\u{CEAB}', GraphemeClusterBreak::LVT),
+    ('\u{CEAC}', '\u{CEAC}', GraphemeClusterBreak::LV),
+    ('\u{CEAD}', '\u{CEC7}', GraphemeClusterBreak::LVT),
+    ('\u{CEC8}', '\u{CEC8}', GraphemeClusterBreak::LV),
+    ('\u{CEC9}', '\u{CEE3}', GraphemeClusterBreak::LVT),
+    ('\u{CEE4}', '\u{CEE4}', GraphemeClusterBreak::LV),
+    ('\u{CEE5}', '\u{CEFF}', GraphemeClusterBreak::LVT),
+    ('\u{CF00}', '\u{CF00}', GraphemeClusterBreak::LV),
+    ('\u{CF01}', '\u{CF1B}', GraphemeClusterBreak::LVT),
+    ('\u{CF1C}', '\u{CF1C}', GraphemeClusterBreak::LV),
+    ('\u{CF1D}', '\u{CF37}', GraphemeClusterBreak::LVT),
+    ('\u{CF38}', '\u{CF38}', GraphemeClusterBreak::LV),
+    ('\u{CF39}', '\u{CF53}', GraphemeClusterBreak::LVT),
+    ('\u{CF54}', '\u{CF54}', GraphemeClusterBreak::LV),
+    ('\u{CF55}', '\u{CF6F}', GraphemeClusterBreak::LVT),
+    ('\u{CF70}', '\u{CF70}', GraphemeClusterBreak::LV),
+    ('\u{CF71}', '\u{CF8B}', GraphemeClusterBreak::LVT),
+    ('\u{CF8C}', '\u{CF8C}', GraphemeClusterBreak::LV),
+    ('\u{CF8D}', '\u{CFA7}', GraphemeClusterBreak::LVT),
+    ('\u{CFA8}', '\u{CFA8}', GraphemeClusterBreak::LV),
+    ('\u{CFA9}', '\u{CFC3}', GraphemeClusterBreak::LVT),
+    ('\u{CFC4}', '\u{CFC4}', GraphemeClusterBreak::LV),
+    ('\u{CFC5}', '\u{CFDF}', GraphemeClusterBreak::LVT),
+    ('\u{CFE0}', '\u{CFE0}', GraphemeClusterBreak::LV),
+    ('\u{CFE1}', '\u{CFFB}', GraphemeClusterBreak::LVT),
+    ('\u{CFFC}', '\u{CFFC}', GraphemeClusterBreak::LV),
+    ('\u{CFFD}', '\u{D017}', GraphemeClusterBreak::LVT),
+    ('\u{D018}', '\u{D018}', GraphemeClusterBreak::LV),
+    ('\u{D019}', '\u{D033}', GraphemeClusterBreak::LVT),
+    ('\u{D034}', '\u{D034}', GraphemeClusterBreak::LV),
+    ('\u{D035}', '\u{D04F}', GraphemeClusterBreak::LVT),
+    ('\u{D050}', '\u{D050}', GraphemeClusterBreak::LV),
+    ('\u{D051}', '\u{D06B}', GraphemeClusterBreak::LVT),
+    ('\u{D06C}', '\u{D06C}', GraphemeClusterBreak::LV),
+    ('\u{D06D}', '\u{D087}', GraphemeClusterBreak::LVT),
+    ('\u{D088}', '\u{D088}', GraphemeClusterBreak::LV),
+    ('\u{D089}', '\u{D0A3}', GraphemeClusterBreak::LVT),
+    ('\u{D0A4}', '\u{D0A4}', GraphemeClusterBreak::LV),
+    ('\u{D0A5}', '\u{D0BF}', GraphemeClusterBreak::LVT),
+    ('\u{D0C0}', '\u{D0C0}', GraphemeClusterBreak::LV),
+    ('\u{D0C1}', '\u{D0DB}', GraphemeClusterBreak::LVT),
+    ('\u{D0DC}', '\u{D0DC}', GraphemeClusterBreak::LV),
+    ('\u{D0DD}', '\u{D0F7}', GraphemeClusterBreak::LVT),
+    ('\u{D0F8}', '\u{D0F8}', GraphemeClusterBreak::LV),
+    ('\u{D0F9}', '\u{D113}', GraphemeClusterBreak::LVT),
+    ('\u{D114}', '\u{D114}', GraphemeClusterBreak::LV),
+    ('\u{D115}', '\u{D12F}', GraphemeClusterBreak::LVT),
+    ('\u{D130}', '\u{D130}', GraphemeClusterBreak::LV),
+    ('\u{D131}', '\u{D14B}', GraphemeClusterBreak::LVT),
+    ('\u{D14C}', '\u{D14C}', GraphemeClusterBreak::LV),
+    ('\u{D14D}', '\u{D167}', GraphemeClusterBreak::LVT),
+    ('\u{D168}', '\u{D168}', GraphemeClusterBreak::LV),
+    ('\u{D169}', '\u{D183}', GraphemeClusterBreak::LVT),
+    ('\u{D184}', '\u{D184}', GraphemeClusterBreak::LV),
+    ('\u{D185}', '\u{D19F}', GraphemeClusterBreak::LVT),
+    ('\u{D1A0}', '\u{D1A0}', GraphemeClusterBreak::LV),
+    ('\u{D1A1}', '\u{D1BB}', GraphemeClusterBreak::LVT),
+    ('\u{D1BC}', '\u{D1BC}', GraphemeClusterBreak::LV),
+    ('\u{D1BD}', '\u{D1D7}', GraphemeClusterBreak::LVT),
+    ('\u{D1D8}', '\u{D1D8}', GraphemeClusterBreak::LV),
+    ('\u{D1D9}', '\u{D1F3}', GraphemeClusterBreak::LVT),
+    ('\u{D1F4}', '\u{D1F4}', GraphemeClusterBreak::LV),
+    ('\u{D1F5}', '\u{D20F}', GraphemeClusterBreak::LVT),
+    ('\u{D210}', '\u{D210}', GraphemeClusterBreak::LV),
+    ('\u{D211}', '\u{D22B}', GraphemeClusterBreak::LVT),
+    ('\u{D22C}', '\u{D22C}', GraphemeClusterBreak::LV),
+    ('\u{D22D}', '\u{D247}', GraphemeClusterBreak::LVT),
+    ('\u{D248}', '\u{D248}', GraphemeClusterBreak::LV),
+    ('\u{D249}', '\u{D263}', GraphemeClusterBreak::LVT),
+    ('\u{D264}', '\u{D264}', GraphemeClusterBreak::LV),
+    ('\u{D265}', '\u{D27F}', GraphemeClusterBreak::LVT),
+    ('\u{D280}', '\u{D280}', GraphemeClusterBreak::LV),
+    ('\u{D281}', '\u{D29B}', GraphemeClusterBreak::LVT),
+    ('\u{D29C}', '\u{D29C}', GraphemeClusterBreak::LV),
+    ('\u{D29D}', '\u{D2B7}', GraphemeClusterBreak::LVT),
+    ('\u{D2B8}', '\u{D2B8}', GraphemeClusterBreak::LV),
+    ('\u{D2B9}', '\u{D2D3}', GraphemeClusterBreak::LVT),
+    ('\u{D2D4}', '\u{D2D4}', GraphemeClusterBreak::LV),
+    ('\u{D2D5}', '\u{D2EF}', GraphemeClusterBreak::LVT),
+    ('\u{D2F0}', '\u{D2F0}', GraphemeClusterBreak::LV),
+    ('\u{D2F1}', '\u{D30B}', GraphemeClusterBreak::LVT),
+    ('\u{D30C}', '\u{D30C}', GraphemeClusterBreak::LV),
+    ('\u{D30D}', '\u{D327}', GraphemeClusterBreak::LVT),
+    ('\u{D328}', '\u{D328}', GraphemeClusterBreak::LV),
+    ('\u{D329}', '\u{D343}', GraphemeClusterBreak::LVT),
+    ('\u{D344}', '\u{D344}', GraphemeClusterBreak::LV),
+    ('\u{D345}', '\u{D35F}', GraphemeClusterBreak::LVT),
+    ('\u{D360}', '\u{D360}', GraphemeClusterBreak::LV),
+    ('\u{D361}', '\u{D37B}', GraphemeClusterBreak::LVT),
+    ('\u{D37C}', '\u{D37C}', GraphemeClusterBreak::LV),
+    ('\u{D37D}', '\u{D397}', GraphemeClusterBreak::LVT),
+    ('\u{D398}', '\u{D398}', GraphemeClusterBreak::LV),
+    ('\u{D399}', '\u{D3B3}', GraphemeClusterBreak::LVT),
+    ('\u{D3B4}', '\u{D3B4}', GraphemeClusterBreak::LV),
+    ('\u{D3B5}', '\u{D3CF}', GraphemeClusterBreak::LVT),
+    ('\u{D3D0}', '\u{D3D0}', GraphemeClusterBreak::LV),
+    ('\u{D3D1}', '\u{D3EB}', GraphemeClusterBreak::LVT),
+    ('\u{D3EC}', '\u{D3EC}', GraphemeClusterBreak::LV),
+    ('\u{D3ED}', '\u{D407}', GraphemeClusterBreak::LVT),
+    ('\u{D408}', '\u{D408}', GraphemeClusterBreak::LV),
+    ('\u{D409}', '\u{D423}', GraphemeClusterBreak::LVT),
+    ('\u{D424}', '\u{D424}', GraphemeClusterBreak::LV),
+    ('\u{D425}', '\u{D43F}', GraphemeClusterBreak::LVT),
+    ('\u{D440}', '\u{D440}', GraphemeClusterBreak::LV),
+    ('\u{D441}', '\u{D45B}', GraphemeClusterBreak::LVT),
+    ('\u{D45C}', '\u{D45C}', GraphemeClusterBreak::LV),
+    ('\u{D45D}', '\u{D477}', GraphemeClusterBreak::LVT),
+    ('\u{D478}', '\u{D478}', GraphemeClusterBreak::LV),
+    ('\u{D479}', '\u{D493}', GraphemeClusterBreak::LVT),
+    ('\u{D494}', '\u{D494}', GraphemeClusterBreak::LV),
+    ('\u{D495}', '\u{D4AF}', GraphemeClusterBreak::LVT),
+    ('\u{D4B0}', '\u{D4B0}', GraphemeClusterBreak::LV),
+    ('\u{D4B1}', '\u{D4CB}', GraphemeClusterBreak::LVT),
+    ('\u{D4CC}', '\u{D4CC}', GraphemeClusterBreak::LV),
+    ('\u{D4CD}', '\u{D4E7}', GraphemeClusterBreak::LVT),
+    ('\u{D4E8}', '\u{D4E8}', GraphemeClusterBreak::LV),
+    ('\u{D4E9}', '\u{D503}', GraphemeClusterBreak::LVT),
+    ('\u{D504}', '\u{D504}', GraphemeClusterBreak::LV),
+    ('\u{D505}', '\u{D51F}', GraphemeClusterBreak::LVT),
+    ('\u{D520}', '\u{D520}', GraphemeClusterBreak::LV),
+    ('\u{D521}', '\u{D53B}', GraphemeClusterBreak::LVT),
+    ('\u{D53C}', '\u{D53C}', GraphemeClusterBreak::LV),
+    ('\u{D53D}', '\u{D557}', GraphemeClusterBreak::LVT),
+    ('\u{D558}', '\u{D558}', GraphemeClusterBreak::LV),
+    ('\u{D559}', '\u{D573}', GraphemeClusterBreak::LVT),
+    ('\u{D574}', '\u{D574}', GraphemeClusterBreak::LV),
+    ('\u{D575}', '\u{D58F}', GraphemeClusterBreak::LVT),
+    ('\u{D590}', '\u{D590}', GraphemeClusterBreak::LV),
+    ('\u{D591}', '\u{D5AB}', GraphemeClusterBreak::LVT),
+    ('\u{D5AC}', '\u{D5AC}', GraphemeClusterBreak::LV),
+    ('\u{D5AD}', '\u{D5C7}', GraphemeClusterBreak::LVT),
+    ('\u{D5C8}', '\u{D5C8}', GraphemeClusterBreak::LV),
+    ('\u{D5C9}', '\u{D5E3}', GraphemeClusterBreak::LVT),
+    ('\u{D5E4}', '\u{D5E4}', GraphemeClusterBreak::LV),
+    ('\u{D5E5}', '\u{D5FF}', GraphemeClusterBreak::LVT),
+    ('\u{D600}', '\u{D600}', GraphemeClusterBreak::LV),
+    ('\u{D601}', '\u{D61B}', GraphemeClusterBreak::LVT),
+    ('\u{D61C}', '\u{D61C}', GraphemeClusterBreak::LV),
+    ('\u{D61D}', '\u{D637}', GraphemeClusterBreak::LVT),
+    ('\u{D638}', '\u{D638}', GraphemeClusterBreak::LV),
+    ('\u{D639}', '\u{D653}', GraphemeClusterBreak::LVT),
+    ('\u{D654}', '\u{D654}', GraphemeClusterBreak::LV),
+    ('\u{D655}', '\u{D66F}', GraphemeClusterBreak::LVT),
+    ('\u{D670}', '\u{D670}', GraphemeClusterBreak::LV),
+    ('\u{D671}', '\u{D68B}', GraphemeClusterBreak::LVT),
+    ('\u{D68C}', '\u{D68C}', GraphemeClusterBreak::LV),
+    ('\u{D68D}', '\u{D6A7}', GraphemeClusterBreak::LVT),
+    ('\u{D6A8}', '\u{D6A8}', GraphemeClusterBreak::LV),
+    ('\u{D6A9}', '\u{D6C3}', GraphemeClusterBreak::LVT),
+    ('\u{D6C4}', '\u{D6C4}', GraphemeClusterBreak::LV),
+    ('\u{D6C5}', '\u{D6DF}', GraphemeClusterBreak::LVT),
+    ('\u{D6E0}', '\u{D6E0}', GraphemeClusterBreak::LV),
+    ('\u{D6E1}', '\u{D6FB}', GraphemeClusterBreak::LVT),
+    ('\u{D6FC}', '\u{D6FC}', GraphemeClusterBreak::LV),
+    ('\u{D6FD}', '\u{D717}', GraphemeClusterBreak::LVT),
+    ('\u{D718}', '\u{D718}', GraphemeClusterBreak::LV),
+    ('\u{D719}', '\u{D733}', GraphemeClusterBreak::LVT),
+    ('\u{D734}', '\u{D734}', GraphemeClusterBreak::LV),
+    ('\u{D735}', '\u{D74F}', GraphemeClusterBreak::LVT),
+    ('\u{D750}', '\u{D750}', GraphemeClusterBreak::LV),
+    ('\u{D751}', '\u{D76B}', GraphemeClusterBreak::LVT),
+    ('\u{D76C}', '\u{D76C}', GraphemeClusterBreak::LV),
+    ('\u{D76D}', '\u{D787}', GraphemeClusterBreak::LVT),
+    ('\u{D788}', '\u{D788}', GraphemeClusterBreak::LV),
+    ('\u{D789}', '\u{D7A3}', GraphemeClusterBreak::LVT),
+    ('\u{D7B0}', '\u{D7C6}', GraphemeClusterBreak::V),
+    ('\u{D7CB}', '\u{D7FB}', GraphemeClusterBreak::T),
+    ('\u{FB1E}', '\u{FB1E}', GraphemeClusterBreak::Extend),
+    ('\u{FE00}', '\u{FE0F}', GraphemeClusterBreak::Extend),
+    ('\u{FE20}', '\u{FE2F}', GraphemeClusterBreak::Extend),
+    ('\u{FEFF}', '\u{FEFF}', GraphemeClusterBreak::Control),
+    ('\u{FF9E}', '\u{FF9F}', GraphemeClusterBreak::Extend),
+    ('\u{FFF0}', '\u{FFFB}', GraphemeClusterBreak::Control),
+    ('\u{101FD}', '\u{101FD}', GraphemeClusterBreak::Extend),
+    ('\u{102E0}', '\u{102E0}', GraphemeClusterBreak::Extend),
+    ('\u{10376}', '\u{1037A}', GraphemeClusterBreak::Extend),
+    ('\u{10A01}', '\u{10A03}', GraphemeClusterBreak::Extend),
+    ('\u{10A05}', '\u{10A06}', GraphemeClusterBreak::Extend),
+    ('\u{10A0C}', '\u{10A0F}', GraphemeClusterBreak::Extend),
+    ('\u{10A38}', '\u{10A3A}', GraphemeClusterBreak::Extend),
+    ('\u{10A3F}', '\u{10A3F}', GraphemeClusterBreak::Extend),
+    ('\u{10AE5}', '\u{10AE6}', GraphemeClusterBreak::Extend),
+    ('\u{10D24}', '\u{10D27}', GraphemeClusterBreak::Extend),
+    ('\u{10EAB}', '\u{10EAC}', GraphemeClusterBreak::Extend),
+    ('\u{10F46}', '\u{10F50}', GraphemeClusterBreak::Extend),
+    ('\u{10F82}', '\u{10F85}', GraphemeClusterBreak::Extend),
+    ('\u{11000}', '\u{11000}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11001}', '\u{11001}', GraphemeClusterBreak::Extend),
+    ('\u{11002}', '\u{11002}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11038}', '\u{11046}', GraphemeClusterBreak::Extend),
+    ('\u{11070}', '\u{11070}', GraphemeClusterBreak::Extend),
+    ('\u{11073}', '\u{11074}', GraphemeClusterBreak::Extend),
+    ('\u{1107F}', '\u{11081}', GraphemeClusterBreak::Extend),
+    ('\u{11082}', '\u{11082}', GraphemeClusterBreak::SpacingMark),
+    ('\u{110B0}', '\u{110B2}', GraphemeClusterBreak::SpacingMark),
+    ('\u{110B3}', '\u{110B6}', GraphemeClusterBreak::Extend),
+    ('\u{110B7}', '\u{110B8}', GraphemeClusterBreak::SpacingMark),
+    ('\u{110B9}', '\u{110BA}', GraphemeClusterBreak::Extend),
+    ('\u{110BD}', '\u{110BD}', GraphemeClusterBreak::Prepend),
+    ('\u{110C2}', '\u{110C2}', GraphemeClusterBreak::Extend),
+    ('\u{110CD}', '\u{110CD}', GraphemeClusterBreak::Prepend),
+    ('\u{11100}', '\u{11102}', GraphemeClusterBreak::Extend),
+    ('\u{11127}', '\u{1112B}', GraphemeClusterBreak::Extend),
+    ('\u{1112C}', '\u{1112C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1112D}', '\u{11134}', GraphemeClusterBreak::Extend),
+    ('\u{11145}', '\u{11146}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11173}', '\u{11173}', GraphemeClusterBreak::Extend),
+    ('\u{11180}', '\u{11181}', GraphemeClusterBreak::Extend),
+    ('\u{11182}', '\u{11182}', GraphemeClusterBreak::SpacingMark),
+    ('\u{111B3}', '\u{111B5}', GraphemeClusterBreak::SpacingMark),
+    ('\u{111B6}', '\u{111BE}', GraphemeClusterBreak::Extend),
+    ('\u{111BF}', '\u{111C0}', GraphemeClusterBreak::SpacingMark),
+    ('\u{111C2}', '\u{111C3}', GraphemeClusterBreak::Prepend),
+    ('\u{111C9}', '\u{111CC}', GraphemeClusterBreak::Extend),
+    ('\u{111CE}', '\u{111CE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{111CF}', '\u{111CF}', GraphemeClusterBreak::Extend),
+    ('\u{1122C}', '\u{1122E}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1122F}', '\u{11231}', GraphemeClusterBreak::Extend),
+    ('\u{11232}', '\u{11233}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11234}', '\u{11234}', GraphemeClusterBreak::Extend),
+    ('\u{11235}', '\u{11235}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11236}', '\u{11237}', GraphemeClusterBreak::Extend),
+    ('\u{1123E}', '\u{1123E}', GraphemeClusterBreak::Extend),
+    ('\u{112DF}', '\u{112DF}', GraphemeClusterBreak::Extend),
+    ('\u{112E0}', '\u{112E2}', GraphemeClusterBreak::SpacingMark),
+    ('\u{112E3}', '\u{112EA}', GraphemeClusterBreak::Extend),
+    ('\u{11300}', '\u{11301}', GraphemeClusterBreak::Extend),
+    ('\u{11302}', '\u{11303}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1133B}', '\u{1133C}', GraphemeClusterBreak::Extend),
+    ('\u{1133E}', '\u{1133E}', GraphemeClusterBreak::Extend),
+    ('\u{1133F}', '\u{1133F}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11340}', '\u{11340}', GraphemeClusterBreak::Extend),
+    ('\u{11341}', '\u{11344}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11347}', '\u{11348}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1134B}', '\u{1134D}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11357}', '\u{11357}', GraphemeClusterBreak::Extend),
+    ('\u{11362}', '\u{11363}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11366}', '\u{1136C}', GraphemeClusterBreak::Extend),
+    ('\u{11370}', '\u{11374}', GraphemeClusterBreak::Extend),
+    ('\u{11435}', '\u{11437}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11438}', '\u{1143F}', GraphemeClusterBreak::Extend),
+    ('\u{11440}', '\u{11441}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11442}', '\u{11444}', GraphemeClusterBreak::Extend),
+    ('\u{11445}', '\u{11445}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11446}', '\u{11446}', GraphemeClusterBreak::Extend),
+    ('\u{1145E}', '\u{1145E}', GraphemeClusterBreak::Extend),
+    ('\u{114B0}', '\u{114B0}', GraphemeClusterBreak::Extend),
+    ('\u{114B1}', '\u{114B2}', GraphemeClusterBreak::SpacingMark),
+    ('\u{114B3}', '\u{114B8}', GraphemeClusterBreak::Extend),
+    ('\u{114B9}', '\u{114B9}', GraphemeClusterBreak::SpacingMark),
+    ('\u{114BA}', '\u{114BA}', GraphemeClusterBreak::Extend),
+    ('\u{114BB}', '\u{114BC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{114BD}', '\u{114BD}', GraphemeClusterBreak::Extend),
+    ('\u{114BE}', '\u{114BE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{114BF}', '\u{114C0}', GraphemeClusterBreak::Extend),
+    ('\u{114C1}', '\u{114C1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{114C2}', '\u{114C3}', GraphemeClusterBreak::Extend),
+    ('\u{115AF}', '\u{115AF}', GraphemeClusterBreak::Extend),
+    ('\u{115B0}', '\u{115B1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{115B2}', '\u{115B5}', GraphemeClusterBreak::Extend),
+    ('\u{115B8}', '\u{115BB}', GraphemeClusterBreak::SpacingMark),
+    ('\u{115BC}', '\u{115BD}', GraphemeClusterBreak::Extend),
+    ('\u{115BE}', '\u{115BE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{115BF}', '\u{115C0}', GraphemeClusterBreak::Extend),
+    ('\u{115DC}', '\u{115DD}', GraphemeClusterBreak::Extend),
+    ('\u{11630}', '\u{11632}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11633}', '\u{1163A}', GraphemeClusterBreak::Extend),
+    ('\u{1163B}', '\u{1163C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1163D}', '\u{1163D}', GraphemeClusterBreak::Extend),
+    ('\u{1163E}', '\u{1163E}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1163F}', '\u{11640}', GraphemeClusterBreak::Extend),
+    ('\u{116AB}', '\u{116AB}', GraphemeClusterBreak::Extend),
+    ('\u{116AC}', '\u{116AC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{116AD}', '\u{116AD}', GraphemeClusterBreak::Extend),
+    ('\u{116AE}', '\u{116AF}', GraphemeClusterBreak::SpacingMark),
+    ('\u{116B0}', '\u{116B5}', GraphemeClusterBreak::Extend),
+    ('\u{116B6}', '\u{116B6}', GraphemeClusterBreak::SpacingMark),
+    ('\u{116B7}', '\u{116B7}', GraphemeClusterBreak::Extend),
+    ('\u{1171D}', '\u{1171F}', GraphemeClusterBreak::Extend),
+    ('\u{11722}', '\u{11725}', GraphemeClusterBreak::Extend),
+    ('\u{11726}', '\u{11726}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11727}', '\u{1172B}', GraphemeClusterBreak::Extend),
+    ('\u{1182C}', '\u{1182E}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1182F}', '\u{11837}', GraphemeClusterBreak::Extend),
+    ('\u{11838}', '\u{11838}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11839}', '\u{1183A}', GraphemeClusterBreak::Extend),
+    ('\u{11930}', '\u{11930}', GraphemeClusterBreak::Extend),
+    ('\u{11931}', '\u{11935}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11937}', '\u{11938}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1193B}', '\u{1193C}', GraphemeClusterBreak::Extend),
+    ('\u{1193D}', '\u{1193D}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1193E}', '\u{1193E}', GraphemeClusterBreak::Extend),
+    ('\u{1193F}', '\u{1193F}', GraphemeClusterBreak::Prepend),
+    ('\u{11940}', '\u{11940}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11941}', '\u{11941}', GraphemeClusterBreak::Prepend),
+    ('\u{11942}', '\u{11942}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11943}', '\u{11943}', GraphemeClusterBreak::Extend),
+    ('\u{119D1}', '\u{119D3}', GraphemeClusterBreak::SpacingMark),
+    ('\u{119D4}', '\u{119D7}', GraphemeClusterBreak::Extend),
+    ('\u{119DA}', '\u{119DB}', GraphemeClusterBreak::Extend),
+    ('\u{119DC}', '\u{119DF}', GraphemeClusterBreak::SpacingMark),
+    ('\u{119E0}', '\u{119E0}', GraphemeClusterBreak::Extend),
+    ('\u{119E4}', '\u{119E4}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11A01}', '\u{11A0A}', GraphemeClusterBreak::Extend),
+    ('\u{11A33}', '\u{11A38}', GraphemeClusterBreak::Extend),
+    ('\u{11A39}', '\u{11A39}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11A3A}', '\u{11A3A}', GraphemeClusterBreak::Prepend),
+    ('\u{11A3B}', '\u{11A3E}', GraphemeClusterBreak::Extend),
+    ('\u{11A47}', '\u{11A47}', GraphemeClusterBreak::Extend),
+    ('\u{11A51}', '\u{11A56}', GraphemeClusterBreak::Extend),
+    ('\u{11A57}', '\u{11A58}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11A59}', '\u{11A5B}', GraphemeClusterBreak::Extend),
+    ('\u{11A84}', '\u{11A89}', GraphemeClusterBreak::Prepend),
+    ('\u{11A8A}', '\u{11A96}', GraphemeClusterBreak::Extend),
+    ('\u{11A97}', '\u{11A97}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11A98}', '\u{11A99}', GraphemeClusterBreak::Extend),
+    ('\u{11C2F}', '\u{11C2F}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11C30}', '\u{11C36}', GraphemeClusterBreak::Extend),
+    ('\u{11C38}', '\u{11C3D}', GraphemeClusterBreak::Extend),
+    ('\u{11C3E}', '\u{11C3E}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11C3F}', '\u{11C3F}', GraphemeClusterBreak::Extend),
+    ('\u{11C92}', '\u{11CA7}', GraphemeClusterBreak::Extend),
+    ('\u{11CA9}', '\u{11CA9}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11CAA}', '\u{11CB0}', GraphemeClusterBreak::Extend),
+    ('\u{11CB1}', '\u{11CB1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11CB2}', '\u{11CB3}', GraphemeClusterBreak::Extend),
+    ('\u{11CB4}', '\u{11CB4}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11CB5}', '\u{11CB6}', GraphemeClusterBreak::Extend),
+    ('\u{11D31}', '\u{11D36}', GraphemeClusterBreak::Extend),
+    ('\u{11D3A}', '\u{11D3A}', GraphemeClusterBreak::Extend),
+    ('\u{11D3C}', '\u{11D3D}', GraphemeClusterBreak::Extend),
+    ('\u{11D3F}', '\u{11D45}', GraphemeClusterBreak::Extend),
+    ('\u{11D46}', '\u{11D46}', GraphemeClusterBreak::Prepend),
+    ('\u{11D47}', '\u{11D47}', GraphemeClusterBreak::Extend),
+    ('\u{11D8A}', '\u{11D8E}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11D90}', '\u{11D91}', GraphemeClusterBreak::Extend),
+    ('\u{11D93}', '\u{11D94}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11D95}', '\u{11D95}', GraphemeClusterBreak::Extend),
+    ('\u{11D96}', '\u{11D96}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11D97}', '\u{11D97}', GraphemeClusterBreak::Extend),
+    ('\u{11EF3}', '\u{11EF4}', GraphemeClusterBreak::Extend),
+    ('\u{11EF5}', '\u{11EF6}', GraphemeClusterBreak::SpacingMark),
+    ('\u{13430}', '\u{13438}', GraphemeClusterBreak::Control),
+    ('\u{16AF0}', '\u{16AF4}', GraphemeClusterBreak::Extend),
+    ('\u{16B30}', '\u{16B36}', GraphemeClusterBreak::Extend),
+    ('\u{16F4F}', '\u{16F4F}', GraphemeClusterBreak::Extend),
+    ('\u{16F51}', '\u{16F87}', GraphemeClusterBreak::SpacingMark),
+    ('\u{16F8F}', '\u{16F92}', GraphemeClusterBreak::Extend),
+    ('\u{16FE4}', '\u{16FE4}', GraphemeClusterBreak::Extend),
+    ('\u{16FF0}', '\u{16FF1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BC9D}', '\u{1BC9E}', GraphemeClusterBreak::Extend),
+    ('\u{1BCA0}', '\u{1BCA3}', GraphemeClusterBreak::Control),
+    ('\u{1CF00}', '\u{1CF2D}', GraphemeClusterBreak::Extend),
+    ('\u{1CF30}', '\u{1CF46}', GraphemeClusterBreak::Extend),
+    ('\u{1D165}', '\u{1D165}', GraphemeClusterBreak::Extend),
+    ('\u{1D166}', '\u{1D166}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1D167}', '\u{1D169}', GraphemeClusterBreak::Extend),
+    ('\u{1D16D}', '\u{1D16D}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1D16E}', '\u{1D172}', GraphemeClusterBreak::Extend),
+    ('\u{1D173}', '\u{1D17A}', GraphemeClusterBreak::Control),
+    ('\u{1D17B}', '\u{1D182}', GraphemeClusterBreak::Extend),
+    ('\u{1D185}', '\u{1D18B}', GraphemeClusterBreak::Extend),
+    ('\u{1D1AA}', '\u{1D1AD}', GraphemeClusterBreak::Extend),
+    ('\u{1D242}', '\u{1D244}', GraphemeClusterBreak::Extend),
+    ('\u{1DA00}', '\u{1DA36}', GraphemeClusterBreak::Extend),
+    ('\u{1DA3B}', '\u{1DA6C}', GraphemeClusterBreak::Extend),
+    ('\u{1DA75}', '\u{1DA75}', GraphemeClusterBreak::Extend),
+    ('\u{1DA84}', '\u{1DA84}', GraphemeClusterBreak::Extend),
+    ('\u{1DA9B}', '\u{1DA9F}', GraphemeClusterBreak::Extend),
+    ('\u{1DAA1}', '\u{1DAAF}', GraphemeClusterBreak::Extend),
+    ('\u{1E000}', '\u{1E006}', GraphemeClusterBreak::Extend),
+    ('\u{1E008}', '\u{1E018}', GraphemeClusterBreak::Extend),
+    ('\u{1E01B}', '\u{1E021}', GraphemeClusterBreak::Extend),
+    ('\u{1E023}', '\u{1E024}', GraphemeClusterBreak::Extend),
+    ('\u{1E026}', '\u{1E02A}', GraphemeClusterBreak::Extend),
+    ('\u{1E130}', '\u{1E136}', GraphemeClusterBreak::Extend),
+    ('\u{1E2AE}', '\u{1E2AE}', GraphemeClusterBreak::Extend),
+    ('\u{1E2EC}', '\u{1E2EF}', GraphemeClusterBreak::Extend),
+    ('\u{1E8D0}', '\u{1E8D6}', GraphemeClusterBreak::Extend),
+    ('\u{1E944}', '\u{1E94A}', GraphemeClusterBreak::Extend),
+    ('\u{1F1E6}', '\u{1F1FF}', GraphemeClusterBreak::RegionalIndicator),
+    ('\u{1F3FB}', '\u{1F3FF}', GraphemeClusterBreak::Extend),
+    ('\u{E0000}', '\u{E001F}', GraphemeClusterBreak::Control),
+    ('\u{E0020}', '\u{E007F}', GraphemeClusterBreak::Extend),
+    ('\u{E0080}', '\u{E00FF}', GraphemeClusterBreak::Control),
+    ('\u{E0100}', '\u{E01EF}', GraphemeClusterBreak::Extend),
+    ('\u{E01F0}', '\u{E0FFF}', GraphemeClusterBreak::Control),
+];
+
+/// The range and name of every block, sorted by code point.
+pub(crate) static BLOCKS: &[(char, char, &str)] = &[
+    ('\u{0}', '\u{7F}', "Basic Latin"),
+    ('\u{80}', '\u{FF}', "Latin-1 Supplement"),
+    ('\u{100}', '\u{17F}', "Latin Extended-A"),
+    ('\u{180}', '\u{24F}', "Latin Extended-B"),
+    ('\u{250}', '\u{2AF}', "IPA Extensions"),
+    ('\u{2B0}', '\u{2FF}', "Spacing Modifier Letters"),
+    ('\u{300}', '\u{36F}', "Combining Diacritical Marks"),
+    ('\u{370}', '\u{3FF}', "Greek and Coptic"),
+    ('\u{400}', '\u{4FF}', "Cyrillic"),
+    ('\u{500}', '\u{52F}', "Cyrillic Supplement"),
+    ('\u{530}', '\u{58F}', "Armenian"),
+    ('\u{590}', '\u{5FF}', "Hebrew"),
+    ('\u{600}', '\u{6FF}', "Arabic"),
+    ('\u{700}', '\u{74F}', "Syriac"),
+    ('\u{750}', '\u{77F}', "Arabic Supplement"),
+    ('\u{780}', '\u{7BF}', "Thaana"),
+    ('\u{7C0}', '\u{7FF}', "NKo"),
+    ('\u{800}', '\u{83F}', "Samaritan"),
+    ('\u{840}', '\u{85F}', "Mandaic"),
+    ('\u{860}', '\u{86F}', "Syriac Supplement"),
+    ('\u{870}', '\u{89F}', "Arabic Extended-B"),
+    ('\u{8A0}', '\u{8FF}', "Arabic Extended-A"),
+    ('\u{900}', '\u{97F}', "Devanagari"),
+    ('\u{980}', '\u{9FF}', "Bengali"),
+    ('\u{A00}', '\u{A7F}', "Gurmukhi"),
+    ('\u{A80}', '\u{AFF}', "Gujarati"),
+    ('\u{B00}', '\u{B7F}', "Oriya"),
+    ('\u{B80}', '\u{BFF}', "Tamil"),
+    ('\u{C00}', '\u{C7F}', "Telugu"),
+    ('\u{C80}', '\u{CFF}', "Kannada"),
+    ('\u{D00}', '\u{D7F}', "Malayalam"),
+    ('\u{D80}', '\u{DFF}', "Sinhala"),
+    ('\u{E00}', '\u{E7F}', "Thai"),
+    ('\u{E80}', '\u{EFF}', "Lao"),
+    ('\u{F00}', '\u{FFF}', "Tibetan"),
+    ('\u{1000}', '\u{109F}', "Myanmar"),
+    ('\u{10A0}', '\u{10FF}', "Georgian"),
+    ('\u{1100}', '\u{11FF}', "Hangul Jamo"),
+    ('\u{1200}', '\u{137F}', "Ethiopic"),
+    ('\u{1380}', '\u{139F}', "Ethiopic Supplement"),
+    ('\u{13A0}', '\u{13FF}', "Cherokee"),
+    ('\u{1400}', '\u{167F}', "Unified Canadian Aboriginal Syllabics"),
+    ('\u{1680}', '\u{169F}', "Ogham"),
+    ('\u{16A0}', '\u{16FF}', "Runic"),
+    ('\u{1700}', '\u{171F}', "Tagalog"),
+    ('\u{1720}', '\u{173F}', "Hanunoo"),
+    ('\u{1740}', '\u{175F}', "Buhid"),
+    ('\u{1760}', '\u{177F}', "Tagbanwa"),
+    ('\u{1780}', '\u{17FF}', "Khmer"),
+    ('\u{1800}', '\u{18AF}', "Mongolian"),
+    ('\u{18B0}', '\u{18FF}', "Unified Canadian Aboriginal Syllabics Extended"),
+    ('\u{1900}', '\u{194F}', "Limbu"),
+    ('\u{1950}', '\u{197F}', "Tai Le"),
+    ('\u{1980}', '\u{19DF}', "New Tai Lue"),
+    ('\u{19E0}', '\u{19FF}', "Khmer Symbols"),
+    ('\u{1A00}', '\u{1A1F}', "Buginese"),
+    ('\u{1A20}', '\u{1AAF}', "Tai Tham"),
+    ('\u{1AB0}', '\u{1AFF}', "Combining Diacritical Marks Extended"),
+    ('\u{1B00}', '\u{1B7F}', "Balinese"),
+    ('\u{1B80}', '\u{1BBF}', "Sundanese"),
+    ('\u{1BC0}', '\u{1BFF}', "Batak"),
+    ('\u{1C00}', '\u{1C4F}', "Lepcha"),
+    ('\u{1C50}', '\u{1C7F}', "Ol Chiki"),
+    ('\u{1C80}', '\u{1C8F}', "Cyrillic Extended-C"),
+    ('\u{1C90}', '\u{1CBF}', "Georgian Extended"),
+    ('\u{1CC0}', '\u{1CCF}', "Sundanese Supplement"),
+    ('\u{1CD0}', '\u{1CFF}', "Vedic Extensions"),
+    ('\u{1D00}', '\u{1D7F}', "Phonetic Extensions"),
+    ('\u{1D80}', '\u{1DBF}', "Phonetic Extensions Supplement"),
+    ('\u{1DC0}', '\u{1DFF}', "Combining Diacritical Marks Supplement"),
+    ('\u{1E00}', '\u{1EFF}', "Latin Extended Additional"),
+    ('\u{1F00}', '\u{1FFF}', "Greek Extended"),
+    ('\u{2000}', '\u{206F}', "General Punctuation"),
+    ('\u{2070}', '\u{209F}', "Superscripts and Subscripts"),
+    ('\u{20A0}', '\u{20CF}', "Currency Symbols"),
+    ('\u{20D0}', '\u{20FF}', "Combining Diacritical Marks for Symbols"),
+    ('\u{2100}', '\u{214F}', "Letterlike Symbols"),
+    ('\u{2150}', '\u{218F}', "Number Forms"),
+    ('\u{2190}', '\u{21FF}', "Arrows"),
+    ('\u{2200}', '\u{22FF}', "Mathematical Operators"),
+    ('\u{2300}', '\u{23FF}', "Miscellaneous Technical"),
+    ('\u{2400}', '\u{243F}', "Control Pictures"),
+    ('\u{2440}', '\u{245F}', "Optical Character Recognition"),
+    ('\u{2460}', '\u{24FF}', "Enclosed Alphanumerics"),
+    ('\u{2500}', '\u{257F}', "Box Drawing"),
+    ('\u{2580}', '\u{259F}', "Block Elements"),
+    ('\u{25A0}', '\u{25FF}', "Geometric Shapes"),
+    ('\u{2600}', '\u{26FF}', "Miscellaneous Symbols"),
+    ('\u{2700}', '\u{27BF}', "Dingbats"),
+    ('\u{27C0}', '\u{27EF}', "Miscellaneous Mathematical Symbols-A"),
+    ('\u{27F0}', '\u{27FF}', "Supplemental Arrows-A"),
+    ('\u{2800}', '\u{28FF}', "Braille Patterns"),
+    ('\u{2900}', '\u{297F}', "Supplemental Arrows-B"),
+    ('\u{2980}', '\u{29FF}', "Miscellaneous Mathematical Symbols-B"),
+    ('\u{2A00}', '\u{2AFF}', "Supplemental Mathematical Operators"),
+    ('\u{2B00}', '\u{2BFF}', "Miscellaneous Symbols and Arrows"),
+    ('\u{2C00}', '\u{2C5F}', "Glagolitic"),
+    ('\u{2C60}', '\u{2C7F}', "Latin Extended-C"),
+    ('\u{2C80}', '\u{2CFF}', "Coptic"),
+    ('\u{2D00}', '\u{2D2F}', "Georgian Supplement"),
+    ('\u{2D30}', '\u{2D7F}', "Tifinagh"),
+    ('\u{2D80}', '\u{2DDF}', "Ethiopic Extended"),
+    ('\u{2DE0}', '\u{2DFF}', "Cyrillic Extended-A"),
+    ('\u{2E00}', '\u{2E7F}', "Supplemental Punctuation"),
+    ('\u{2E80}', '\u{2EFF}', "CJK Radicals Supplement"),
+    ('\u{2F00}', '\u{2FDF}', "Kangxi Radicals"),
+    ('\u{2FF0}', '\u{2FFF}', "Ideographic Description Characters"),
+    ('\u{3000}', '\u{303F}', "CJK Symbols and Punctuation"),
+    ('\u{3040}', '\u{309F}', "Hiragana"),
+    ('\u{30A0}', '\u{30FF}', "Katakana"),
+    ('\u{3100}', '\u{312F}', "Bopomofo"),
+    ('\u{3130}', '\u{318F}', "Hangul Compatibility Jamo"),
+    ('\u{3190}', '\u{319F}', "Kanbun"),
+    ('\u{31A0}', '\u{31BF}', "Bopomofo Extended"),
+    ('\u{31C0}', '\u{31EF}', "CJK Strokes"),
+    ('\u{31F0}', '\u{31FF}', "Katakana Phonetic Extensions"),
+    ('\u{3200}', '\u{32FF}', "Enclosed CJK Letters and Months"),
+    ('\u{3300}', '\u{33FF}', "CJK Compatibility"),
+    ('\u{3400}', '\u{4DBF}', "CJK Unified Ideographs Extension A"),
+    ('\u{4DC0}', '\u{4DFF}', "Yijing Hexagram Symbols"),
+    ('\u{4E00}', '\u{9FFF}', "CJK Unified Ideographs"),
+    ('\u{A000}', '\u{A48F}', "Yi Syllables"),
+    ('\u{A490}', '\u{A4CF}', "Yi Radicals"),
+    ('\u{A4D0}', '\u{A4FF}', "Lisu"),
+    ('\u{A500}', '\u{A63F}', "Vai"),
+    ('\u{A640}', '\u{A69F}', "Cyrillic Extended-B"),
+    ('\u{A6A0}', '\u{A6FF}', "Bamum"),
+    ('\u{A700}', '\u{A71F}', "Modifier Tone Letters"),
+    ('\u{A720}', '\u{A7FF}', "Latin Extended-D"),
+    ('\u{A800}', '\u{A82F}', "Syloti Nagri"),
+    ('\u{A830}', '\u{A83F}', "Common Indic Number Forms"),
+    ('\u{A840}', '\u{A87F}', "Phags-pa"),
+    ('\u{A880}', '\u{A8DF}', "Saurashtra"),
+    ('\u{A8E0}', '\u{A8FF}', "Devanagari Extended"),
+    ('\u{A900}', '\u{A92F}', "Kayah Li"),
+    ('\u{A930}', '\u{A95F}', "Rejang"),
+    ('\u{A960}', '\u{A97F}', "Hangul Jamo Extended-A"),
+    ('\u{A980}', '\u{A9DF}', "Javanese"),
+    ('\u{A9E0}', '\u{A9FF}', "Myanmar Extended-B"),
+    ('\u{AA00}', '\u{AA5F}', "Cham"),
+    ('\u{AA60}', '\u{AA7F}', "Myanmar Extended-A"),
+    ('\u{AA80}', '\u{AADF}', "Tai Viet"),
+    ('\u{AAE0}', '\u{AAFF}', "Meetei Mayek Extensions"),
+    ('\u{AB00}', '\u{AB2F}', "Ethiopic Extended-A"),
+    ('\u{AB30}', '\u{AB6F}', "Latin Extended-E"),
+    ('\u{AB70}', '\u{ABBF}', "Cherokee Supplement"),
+    ('\u{ABC0}', '\u{ABFF}', "Meetei Mayek"),
+    ('\u{AC00}', '\u{D7AF}', "Hangul Syllables"),
+    ('\u{D7B0}', '\u{D7FF}', "Hangul Jamo Extended-B"),
+    ('\u{E000}', '\u{F8FF}', "Private Use Area"),
+    ('\u{F900}', '\u{FAFF}', "CJK Compatibility Ideographs"),
+    ('\u{FB00}', '\u{FB4F}', "Alphabetic Presentation Forms"),
+    ('\u{FB50}', '\u{FDFF}', "Arabic Presentation Forms-A"),
+    ('\u{FE00}', '\u{FE0F}', "Variation Selectors"),
+    ('\u{FE10}', '\u{FE1F}', "Vertical Forms"),
+    ('\u{FE20}', '\u{FE2F}', "Combining Half Marks"),
+    ('\u{FE30}', '\u{FE4F}', "CJK Compatibility Forms"),
+    ('\u{FE50}', '\u{FE6F}', "Small Form Variants"),
+    ('\u{FE70}', '\u{FEFF}', "Arabic Presentation Forms-B"),
+    ('\u{FF00}', '\u{FFEF}', "Halfwidth and Fullwidth Forms"),
+    ('\u{FFF0}', '\u{FFFF}', "Specials"),
+    ('\u{10000}', '\u{1007F}', "Linear B Syllabary"),
+    ('\u{10080}', '\u{100FF}', "Linear B Ideograms"),
+    ('\u{10100}', '\u{1013F}', "Aegean Numbers"),
+    ('\u{10140}', '\u{1018F}', "Ancient Greek Numbers"),
+    ('\u{10190}', '\u{101CF}', "Ancient Symbols"),
+    ('\u{101D0}', '\u{101FF}', "Phaistos Disc"),
+    ('\u{10280}', '\u{1029F}', "Lycian"),
+    ('\u{102A0}', '\u{102DF}', "Carian"),
+    ('\u{102E0}', '\u{102FF}', "Coptic Epact Numbers"),
+    ('\u{10300}', '\u{1032F}', "Old Italic"),
+    ('\u{10330}', '\u{1034F}', "Gothic"),
+    ('\u{10350}', '\u{1037F}', "Old Permic"),
+    ('\u{10380}', '\u{1039F}', "Ugaritic"),
+    ('\u{103A0}', '\u{103DF}', "Old Persian"),
+    ('\u{10400}', '\u{1044F}', "Deseret"),
+    ('\u{10450}', '\u{1047F}', "Shavian"),
+    ('\u{10480}', '\u{104AF}', "Osmanya"),
+    ('\u{104B0}', '\u{104FF}', "Osage"),
+    ('\u{10500}', '\u{1052F}', "Elbasan"),
+    ('\u{10530}', '\u{1056F}', "Caucasian Albanian"),
+    ('\u{10570}', '\u{105BF}', "Vithkuqi"),
+    ('\u{10600}', '\u{1077F}', "Linear A"),
+    ('\u{10780}', '\u{107BF}', "Latin Extended-F"),
+    ('\u{10800}', '\u{1083F}', "Cypriot Syllabary"),
+    ('\u{10840}', '\u{1085F}', "Imperial Aramaic"),
+    ('\u{10860}', '\u{1087F}', "Palmyrene"),
+    ('\u{10880}', '\u{108AF}', "Nabataean"),
+    ('\u{108E0}', '\u{108FF}', "Hatran"),
+    ('\u{10900}', '\u{1091F}', "Phoenician"),
+    ('\u{10920}', '\u{1093F}', "Lydian"),
+    ('\u{10980}', '\u{1099F}', "Meroitic Hieroglyphs"),
+    ('\u{109A0}', '\u{109FF}', "Meroitic Cursive"),
+    ('\u{10A00}', '\u{10A5F}', "Kharoshthi"),
+    ('\u{10A60}', '\u{10A7F}', "Old South Arabian"),
+    ('\u{10A80}', '\u{10A9F}', "Old North Arabian"),
+    ('\u{10AC0}', '\u{10AFF}', "Manichaean"),
+    ('\u{10B00}', '\u{10B3F}', "Avestan"),
+    ('\u{10B40}', '\u{10B5F}', "Inscriptional Parthian"),
+    ('\u{10B60}', '\u{10B7F}', "Inscriptional Pahlavi"),
+    ('\u{10B80}', '\u{10BAF}', "Psalter Pahlavi"),
+    ('\u{10C00}', '\u{10C4F}', "Old Turkic"),
+    ('\u{10C80}', '\u{10CFF}', "Old Hungarian"),
+    ('\u{10D00}', '\u{10D3F}', "Hanifi Rohingya"),
+    ('\u{10E60}', '\u{10E7F}', "Rumi Numeral Symbols"),
+    ('\u{10E80}', '\u{10EBF}', "Yezidi"),
+    ('\u{10F00}', '\u{10F2F}', "Old Sogdian"),
+    ('\u{10F30}', '\u{10F6F}', "Sogdian"),
+    ('\u{10F70}', '\u{10FAF}', "Old Uyghur"),
+    ('\u{10FB0}', '\u{10FDF}', "Chorasmian"),
+    ('\u{10FE0}', '\u{10FFF}', "Elymaic"),
+    ('\u{11000}', '\u{1107F}', "Brahmi"),
+    ('\u{11080}', '\u{110CF}', "Kaithi"),
+    ('\u{110D0}', '\u{110FF}', "Sora Sompeng"),
+    ('\u{11100}', '\u{1114F}', "Chakma"),
+    ('\u{11150}', '\u{1117F}', "Mahajani"),
+    ('\u{11180}', '\u{111DF}', "Sharada"),
+    ('\u{111E0}', '\u{111FF}', "Sinhala Archaic Numbers"),
+    ('\u{11200}', '\u{1124F}', "Khojki"),
+    ('\u{11280}', '\u{112AF}', "Multani"),
+    ('\u{112B0}', '\u{112FF}', "Khudawadi"),
+    ('\u{11300}', '\u{1137F}', "Grantha"),
+    ('\u{11400}', '\u{1147F}', "Newa"),
+    ('\u{11480}', '\u{114DF}', "Tirhuta"),
+    ('\u{11580}', '\u{115FF}', "Siddham"),
+    ('\u{11600}', '\u{1165F}', "Modi"),
+    ('\u{11660}', '\u{1167F}', "Mongolian Supplement"),
+    ('\u{11680}', '\u{116CF}', "Takri"),
+    ('\u{11700}', '\u{1174F}', "Ahom"),
+    ('\u{11800}', '\u{1184F}', "Dogra"),
+    ('\u{118A0}', '\u{118FF}', "Warang Citi"),
+    ('\u{11900}', '\u{1195F}', "Dives Akuru"),
+    ('\u{119A0}', '\u{119FF}', "Nandinagari"),
+    ('\u{11A00}', '\u{11A4F}', "Zanabazar Square"),
+    ('\u{11A50}', '\u{11AAF}', "Soyombo"),
+    ('\u{11AB0}', '\u{11ABF}', "Unified Canadian Aboriginal Syllabics Extended-A"),
+    ('\u{11AC0}', '\u{11AFF}', "Pau Cin Hau"),
+    ('\u{11C00}', '\u{11C6F}', "Bhaiksuki"),
+    ('\u{11C70}', '\u{11CBF}', "Marchen"),
+    ('\u{11D00}', '\u{11D5F}', "Masaram Gondi"),
+    ('\u{11D60}', '\u{11DAF}', "Gunjala Gondi"),
+    ('\u{11EE0}', '\u{11EFF}', "Makasar"),
+    ('\u{11FB0}', '\u{11FBF}', "Lisu Supplement"),
+    ('\u{11FC0}', '\u{11FFF}', "Tamil Supplement"),
+    ('\u{12000}', '\u{123FF}', "Cuneiform"),
+    ('\u{12400}', '\u{1247F}', "Cuneiform Numbers and Punctuation"),
+    ('\u{12480}', '\u{1254F}', "Early Dynastic Cuneiform"),
+    ('\u{12F90}', '\u{12FFF}', "Cypro-Minoan"),
+    ('\u{13000}', '\u{1342F}', "Egyptian Hieroglyphs"),
+    ('\u{13430}', '\u{1343F}', "Egyptian Hieroglyph Format Controls"),
+    ('\u{14400}', '\u{1467F}', "Anatolian Hieroglyphs"),
+    ('\u{16800}', '\u{16A3F}', "Bamum Supplement"),
+    ('\u{16A40}', '\u{16A6F}', "Mro"),
+    ('\u{16A70}', '\u{16ACF}', "Tangsa"),
+    ('\u{16AD0}', '\u{16AFF}', "Bassa Vah"),
+    ('\u{16B00}', '\u{16B8F}', "Pahawh Hmong"),
+    ('\u{16E40}', '\u{16E9F}', "Medefaidrin"),
+    ('\u{16F00}', '\u{16F9F}', "Miao"),
+    ('\u{16FE0}', '\u{16FFF}', "Ideographic Symbols and Punctuation"),
+    ('\u{17000}', '\u{187FF}', "Tangut"),
+    ('\u{18800}', '\u{18AFF}', "Tangut Components"),
+    ('\u{18B00}', '\u{18CFF}', "Khitan Small Script"),
+    ('\u{18D00}', '\u{18D7F}', "Tangut Supplement"),
+    ('\u{1AFF0}', '\u{1AFFF}', "Kana Extended-B"),
+    ('\u{1B000}', '\u{1B0FF}', "Kana Supplement"),
+    ('\u{1B100}', '\u{1B12F}', "Kana Extended-A"),
+    ('\u{1B130}', '\u{1B16F}', "Small Kana Extension"),
+    ('\u{1B170}', '\u{1B2FF}', "Nushu"),
+    ('\u{1BC00}', '\u{1BC9F}', "Duployan"),
+    ('\u{1BCA0}', '\u{1BCAF}', "Shorthand Format Controls"),
+    ('\u{1CF00}', '\u{1CFCF}', "Znamenny Musical Notation"),
+    ('\u{1D000}', '\u{1D0FF}', "Byzantine Musical Symbols"),
+    ('\u{1D100}', '\u{1D1FF}', "Musical Symbols"),
+    ('\u{1D200}', '\u{1D24F}', "Ancient Greek Musical Notation"),
+    ('\u{1D2E0}', '\u{1D2FF}', "Mayan Numerals"),
+    ('\u{1D300}', '\u{1D35F}', "Tai Xuan Jing Symbols"),
+    ('\u{1D360}', '\u{1D37F}', "Counting Rod Numerals"),
+    ('\u{1D400}', '\u{1D7FF}', "Mathematical Alphanumeric Symbols"),
+    ('\u{1D800}', '\u{1DAAF}', "Sutton SignWriting"),
+    ('\u{1DF00}', '\u{1DFFF}', "Latin Extended-G"),
+    ('\u{1E000}', '\u{1E02F}', "Glagolitic Supplement"),
+    ('\u{1E100}', '\u{1E14F}', "Nyiakeng Puachue Hmong"),
+    ('\u{1E290}', '\u{1E2BF}', "Toto"),
+    ('\u{1E2C0}', '\u{1E2FF}', "Wancho"),
+    ('\u{1E7E0}', '\u{1E7FF}', "Ethiopic Extended-B"),
+    ('\u{1E800}', '\u{1E8DF}', "Mende Kikakui"),
+    ('\u{1E900}', '\u{1E95F}', "Adlam"),
+    ('\u{1EC70}', '\u{1ECBF}', "Indic Siyaq Numbers"),
+    ('\u{1ED00}', '\u{1ED4F}', "Ottoman Siyaq Numbers"),
+    ('\u{1EE00}', '\u{1EEFF}', "Arabic Mathematical Alphabetic Symbols"),
+    ('\u{1F000}', '\u{1F02F}', "Mahjong Tiles"),
+    ('\u{1F030}', '\u{1F09F}', "Domino Tiles"),
+    ('\u{1F0A0}', '\u{1F0FF}', "Playing Cards"),
+    ('\u{1F100}', '\u{1F1FF}', "Enclosed Alphanumeric Supplement"),
+    ('\u{1F200}', '\u{1F2FF}', "Enclosed Ideographic Supplement"),
+    ('\u{1F300}', '\u{1F5FF}', "Miscellaneous Symbols and Pictographs"),
+    ('\u{1F600}', '\u{1F64F}', "Emoticons"),
+    ('\u{1F650}', '\u{1F67F}', "Ornamental Dingbats"),
+    ('\u{1F680}', '\u{1F6FF}', "Transport and Map Symbols"),
+    ('\u{1F700}', '\u{1F77F}', "Alchemical Symbols"),
+    ('\u{1F780}', '\u{1F7FF}', "Geometric Shapes Extended"),
+    ('\u{1F800}', '\u{1F8FF}', "Supplemental Arrows-C"),
+    ('\u{1F900}', '\u{1F9FF}', "Supplemental Symbols and Pictographs"),
+    ('\u{1FA00}', '\u{1FA6F}', "Chess Symbols"),
+    ('\u{1FA70}', '\u{1FAFF}', "Symbols and Pictographs Extended-A"),
+    ('\u{1FB00}', '\u{1FBFF}', "Symbols for Legacy Computing"),
+    ('\u{20000}', '\u{2A6DF}', "CJK Unified Ideographs Extension B"),
+    ('\u{2A700}', '\u{2B73F}', "CJK Unified Ideographs Extension C"),
+    ('\u{2B740}', '\u{2B81F}', "CJK Unified Ideographs Extension D"),
+    ('\u{2B820}', '\u{2CEAF}', "CJK Unified Ideographs Extension E"),
+    ('\u{2CEB0}', '\u{2EBEF}', "CJK Unified Ideographs Extension F"),
+    ('\u{2F800}', '\u{2FA1F}', "CJK Compatibility Ideographs Supplement"),
+    ('\u{30000}', '\u{3134F}', "CJK Unified Ideographs Extension G"),
+    ('\u{E0000}', '\u{E007F}', "Tags"),
+    ('\u{E0100}', '\u{E01EF}', "Variation Selectors Supplement"),
+    ('\u{F0000}', '\u{FFFFF}', "Supplementary Private Use Area-A"),
+    ('\u{100000}', '\u{10FFFF}', "Supplementary Private Use Area-B"),
+];
+
+/// Ranges of chars with the `Alphabetic` property, sorted by code point.
+pub(crate) static ALPHABETIC: &[(char, char)] = &[
+    ('\u{41}', '\u{5A}'),
+    ('\u{61}', '\u{7A}'),
+    ('\u{AA}', '\u{AA}'),
+    ('\u{B5}', '\u{B5}'),
+    ('\u{BA}', '\u{BA}'),
+    ('\u{C0}', '\u{D6}'),
+    ('\u{D8}', '\u{F6}'),
+    ('\u{F8}', '\u{2C1}'),
+    ('\u{2C6}', '\u{2D1}'),
+    ('\u{2E0}', '\u{2E4}'),
+    ('\u{2EC}', '\u{2EC}'),
+    ('\u{2EE}', '\u{2EE}'),
+    ('\u{345}', '\u{345}'),
+    ('\u{370}', '\u{374}'),
+    ('\u{376}', '\u{377}'),
+    ('\u{37A}', '\u{37D}'),
+    ('\u{37F}', '\u{37F}'),
+    ('\u{386}', '\u{386}'),
+    ('\u{388}', '\u{38A}'),
+    ('\u{38C}', '\u{38C}'),
+    ('\u{38E}', '\u{3A1}'),
+    ('\u{3A3}', '\u{3F5}'),
+    ('\u{3F7}', '\u{481}'),
+    ('\u{48A}', '\u{52F}'),
+    ('\u{531}', '\u{556}'),
+    ('\u{559}', '\u{559}'),
+    ('\u{560}', '\u{588}'),
+    ('\u{5B0}', '\u{5BD}'),
+    ('\u{5BF}', '\u{5BF}'),
+    ('\u{5C1}', '\u{5C2}'),
+    ('\u{5C4}', '\u{5C5}'),
+    ('\u{5C7}', '\u{5C7}'),
+    ('\u{5D0}', '\u{5EA}'),
+    ('\u{5EF}', '\u{5F2}'),
+    ('\u{610}', '\u{61A}'),
+    ('\u{620}', '\u{657}'),
+    ('\u{659}', '\u{65F}'),
+    ('\u{66E}', '\u{6D3}'),
+    ('\u{6D5}', '\u{6DC}'),
+    ('\u{6E1}', '\u{6E8}'),
+    ('\u{6ED}', '\u{6EF}'),
+    ('\u{6FA}', '\u{6FC}'),
+    ('\u{6FF}', '\u{6FF}'),
+    ('\u{710}', '\u{73F}'),
+    ('\u{74D}', '\u{7B1}'),
+    ('\u{7CA}', '\u{7EA}'),
+    ('\u{7F4}', '\u{7F5}'),
+    ('\u{7FA}', '\u{7FA}'),
+    ('\u{800}', '\u{817}'),
+    ('\u{81A}', '\u{82C}'),
+    ('\u{840}', '\u{858}'),
+    ('\u{860}', '\u{86A}'),
+    ('\u{870}', '\u{887}'),
+    ('\u{889}', '\u{88E}'),
+    ('\u{8A0}', '\u{8C9}'),
+    ('\u{8D4}', '\u{8DF}'),
+    ('\u{8E3}', '\u{8E9}'),
+    ('\u{8F0}', '\u{93B}'),
+    ('\u{93D}', '\u{94C}'),
+    ('\u{94E}', '\u{950}'),
+    ('\u{955}', '\u{963}'),
+    ('\u{971}', '\u{983}'),
+    ('\u{985}', '\u{98C}'),
+    ('\u{98F}', '\u{990}'),
+    ('\u{993}', '\u{9A8}'),
+    ('\u{9AA}', '\u{9B0}'),
+    ('\u{9B2}', '\u{9B2}'),
+    ('\u{9B6}', '\u{9B9}'),
+    ('\u{9BD}', '\u{9C4}'),
+    ('\u{9C7}', '\u{9C8}'),
+    ('\u{9CB}', '\u{9CC}'),
+    ('\u{9CE}', '\u{9CE}'),
+    ('\u{9D7}', '\u{9D7}'),
+    ('\u{9DC}', '\u{9DD}'),
+    ('\u{9DF}', '\u{9E3}'),
+    ('\u{9F0}', '\u{9F1}'),
+    ('\u{9FC}', '\u{9FC}'),
+    ('\u{A01}', '\u{A03}'),
+    ('\u{A05}', '\u{A0A}'),
+    ('\u{A0F}', '\u{A10}'),
+    ('\u{A13}', '\u{A28}'),
+    ('\u{A2A}', '\u{A30}'),
+    ('\u{A32}', '\u{A33}'),
+    ('\u{A35}', '\u{A36}'),
+    ('\u{A38}', '\u{A39}'),
+    ('\u{A3E}', '\u{A42}'),
+    ('\u{A47}', '\u{A48}'),
+    ('\u{A4B}', '\u{A4C}'),
+    ('\u{A51}', '\u{A51}'),
+    ('\u{A59}', '\u{A5C}'),
+    ('\u{A5E}', '\u{A5E}'),
+    ('\u{A70}', '\u{A75}'),
+    ('\u{A81}', '\u{A83}'),
+    ('\u{A85}', '\u{A8D}'),
+    ('\u{A8F}', '\u{A91}'),
+    ('\u{A93}', '\u{AA8}'),
+    ('\u{AAA}', '\u{AB0}'),
+    ('\u{AB2}', '\u{AB3}'),
+    ('\u{AB5}', '\u{AB9}'),
+    ('\u{ABD}', '\u{AC5}'),
+    ('\u{AC7}', '\u{AC9}'),
+    ('\u{ACB}', '\u{ACC}'),
+    ('\u{AD0}', '\u{AD0}'),
+    ('\u{AE0}', '\u{AE3}'),
+    ('\u{AF9}', '\u{AFC}'),
+    ('\u{B01}', '\u{B03}'),
+    ('\u{B05}', '\u{B0C}'),
+    ('\u{B0F}', '\u{B10}'),
+    ('\u{B13}', '\u{B28}'),
+    ('\u{B2A}', '\u{B30}'),
+    ('\u{B32}', '\u{B33}'),
+    ('\u{B35}', '\u{B39}'),
+    ('\u{B3D}', '\u{B44}'),
+    ('\u{B47}', '\u{B48}'),
+    ('\u{B4B}', '\u{B4C}'),
+    ('\u{B56}', '\u{B57}'),
+    ('\u{B5C}', '\u{B5D}'),
+    ('\u{B5F}', '\u{B63}'),
+    ('\u{B71}', '\u{B71}'),
+    ('\u{B82}', '\u{B83}'),
+    ('\u{B85}', '\u{B8A}'),
+    ('\u{B8E}', '\u{B90}'),
+    ('\u{B92}', '\u{B95}'),
+    ('\u{B99}', '\u{B9A}'),
+    ('\u{B9C}', '\u{B9C}'),
+    ('\u{B9E}', '\u{B9F}'),
+    ('\u{BA3}', '\u{BA4}'),
+    ('\u{BA8}', '\u{BAA}'),
+    ('\u{BAE}', '\u{BB9}'),
+    ('\u{BBE}', '\u{BC2}'),
+    ('\u{BC6}', '\u{BC8}'),
+    ('\u{BCA}', '\u{BCC}'),
+    ('\u{BD0}', '\u{BD0}'),
+    ('\u{BD7}', '\u{BD7}'),
+    ('\u{C00}', '\u{C03}'),
+    ('\u{C05}', '\u{C0C}'),
+    ('\u{C0E}', '\u{C10}'),
+    ('\u{C12}', '\u{C28}'),
+    ('\u{C2A}', '\u{C39}'),
+    ('\u{C3D}', '\u{C44}'),
+    ('\u{C46}', '\u{C48}'),
+    ('\u{C4A}', '\u{C4C}'),
+    ('\u{C55}', '\u{C56}'),
+    ('\u{C58}', '\u{C5A}'),
+    ('\u{C5D}', '\u{C5D}'),
+    ('\u{C60}', '\u{C63}'),
+    ('\u{C80}', '\u{C83}'),
+    ('\u{C85}', '\u{C8C}'),
+    ('\u{C8E}', '\u{C90}'),
+    ('\u{C92}', '\u{CA8}'),
+    ('\u{CAA}', '\u{CB3}'),
+    ('\u{CB5}', '\u{CB9}'),
+    ('\u{CBD}', '\u{CC4}'),
+    ('\u{CC6}', '\u{CC8}'),
+    ('\u{CCA}', '\u{CCC}'),
+    ('\u{CD5}', '\u{CD6}'),
+    ('\u{CDD}', '\u{CDE}'),
+    ('\u{CE0}', '\u{CE3}'),
+    ('\u{CF1}', '\u{CF2}'),
+    ('\u{D00}', '\u{D0C}'),
+    ('\u{D0E}', '\u{D10}'),
+    ('\u{D12}', '\u{D3A}'),
+    ('\u{D3D}', '\u{D44}'),
+    ('\u{D46}', '\u{D48}'),
+    ('\u{D4A}', '\u{D4C}'),
+    ('\u{D4E}', '\u{D4E}'),
+    ('\u{D54}', '\u{D57}'),
+    ('\u{D5F}', '\u{D63}'),
+    ('\u{D7A}', '\u{D7F}'),
+    ('\u{D81}', '\u{D83}'),
+    ('\u{D85}', '\u{D96}'),
+    ('\u{D9A}', '\u{DB1}'),
+    ('\u{DB3}', '\u{DBB}'),
+    ('\u{DBD}', '\u{DBD}'),
+    ('\u{DC0}', '\u{DC6}'),
+    ('\u{DCF}', '\u{DD4}'),
+    ('\u{DD6}', '\u{DD6}'),
+    ('\u{DD8}', '\u{DDF}'),
+    ('\u{DF2}', '\u{DF3}'),
+    ('\u{E01}', '\u{E3A}'),
+    ('\u{E40}', '\u{E46}'),
+    ('\u{E4D}', '\u{E4D}'),
+    ('\u{E81}', '\u{E82}'),
+    ('\u{E84}', '\u{E84}'),
+    ('\u{E86}', '\u{E8A}'),
+    ('\u{E8C}', '\u{EA3}'),
+    ('\u{EA5}', '\u{EA5}'),
+    ('\u{EA7}', '\u{EB9}'),
+    ('\u{EBB}', '\u{EBD}'),
+    ('\u{EC0}', '\u{EC4}'),
+    ('\u{EC6}', '\u{EC6}'),
+    ('\u{ECD}', '\u{ECD}'),
+    ('\u{EDC}', '\u{EDF}'),
+    ('\u{F00}', '\u{F00}'),
+    ('\u{F40}', '\u{F47}'),
+    ('\u{F49}', '\u{F6C}'),
+    ('\u{F71}', '\u{F81}'),
+    ('\u{F88}', '\u{F97}'),
+    ('\u{F99}', '\u{FBC}'),
+    ('\u{1000}', '\u{1036}'),
+    ('\u{1038}', '\u{1038}'),
+    ('\u{103B}', '\u{103F}'),
+    ('\u{1050}', '\u{108F}'),
+    ('\u{109A}', '\u{109D}'),
+    ('\u{10A0}', '\u{10C5}'),
+    ('\u{10C7}', '\u{10C7}'),
+    ('\u{10CD}', '\u{10CD}'),
+    ('\u{10D0}', '\u{10FA}'),
+    ('\u{10FC}', '\u{1248}'),
+    ('\u{124A}', '\u{124D}'),
+    ('\u{1250}', '\u{1256}'),
+    ('\u{1258}', '\u{1258}'),
+    ('\u{125A}', '\u{125D}'),
+    ('\u{1260}', '\u{1288}'),
+    ('\u{128A}', '\u{128D}'),
+    ('\u{1290}', '\u{12B0}'),
+    ('\u{12B2}', '\u{12B5}'),
+    ('\u{12B8}', '\u{12BE}'),
+    ('\u{12C0}', '\u{12C0}'),
+    ('\u{12C2}', '\u{12C5}'),
+    ('\u{12C8}', '\u{12D6}'),
+    ('\u{12D8}', '\u{1310}'),
+    ('\u{1312}', '\u{1315}'),
+    ('\u{1318}', '\u{135A}'),
+    ('\u{1380}', '\u{138F}'),
+    ('\u{13A0}', '\u{13F5}'),
+    ('\u{13F8}', '\u{13FD}'),
+    ('\u{1401}', '\u{166C}'),
+    ('\u{166F}', '\u{167F}'),
+    ('\u{1681}', '\u{169A}'),
+    ('\u{16A0}', '\u{16EA}'),
+    ('\u{16EE}', '\u{16F8}'),
+    ('\u{1700}', '\u{1713}'),
+    ('\u{171F}', '\u{1733}'),
+    ('\u{1740}', '\u{1753}'),
+    ('\u{1760}', '\u{176C}'),
+    ('\u{176E}', '\u{1770}'),
+    ('\u{1772}', '\u{1773}'),
+    ('\u{1780}', '\u{17B3}'),
+    ('\u{17B6}', '\u{17C8}'),
+    ('\u{17D7}', '\u{17D7}'),
+    ('\u{17DC}', '\u{17DC}'),
+    ('\u{1820}', '\u{1878}'),
+    ('\u{1880}', '\u{18AA}'),
+    ('\u{18B0}', '\u{18F5}'),
+    ('\u{1900}', '\u{191E}'),
+    ('\u{1920}', '\u{192B}'),
+    ('\u{1930}', '\u{1938}'),
+    ('\u{1950}', '\u{196D}'),
+    ('\u{1970}', '\u{1974}'),
+    ('\u{1980}', '\u{19AB}'),
+    ('\u{19B0}', '\u{19C9}'),
+    ('\u{1A00}', '\u{1A1B}'),
+    ('\u{1A20}', '\u{1A5E}'),
+    ('\u{1A61}', '\u{1A74}'),
+    ('\u{1AA7}', '\u{1AA7}'),
+    ('\u{1ABF}', '\u{1AC0}'),
+    ('\u{1ACC}', '\u{1ACE}'),
+    ('\u{1B00}', '\u{1B33}'),
+    ('\u{1B35}', '\u{1B43}'),
+    ('\u{1B45}', '\u{1B4C}'),
+    ('\u{1B80}', '\u{1BA9}'),
+    ('\u{1BAC}', '\u{1BAF}'),
+    ('\u{1BBA}', '\u{1BE5}'),
+    ('\u{1BE7}', '\u{1BF1}'),
+    ('\u{1C00}', '\u{1C36}'),
+    ('\u{1C4D}', '\u{1C4F}'),
+    ('\u{1C5A}', '\u{1C7D}'),
+    ('\u{1C80}', '\u{1C88}'),
+    ('\u{1C90}', '\u{1CBA}'),
+    ('\u{1CBD}', '\u{1CBF}'),
+    ('\u{1CE9}', '\u{1CEC}'),
+    ('\u{1CEE}', '\u{1CF3}'),
+    ('\u{1CF5}', '\u{1CF6}'),
+    ('\u{1CFA}', '\u{1CFA}'),
+    ('\u{1D00}', '\u{1DBF}'),
+    ('\u{1DE7}', '\u{1DF4}'),
+    ('\u{1E00}', '\u{1F15}'),
+    ('\u{1F18}', '\u{1F1D}'),
+    ('\u{1F20}', '\u{1F45}'),
+    ('\u{1F48}', '\u{1F4D}'),
+    ('\u{1F50}', '\u{1F57}'),
+    ('\u{1F59}', '\u{1F59}'),
+    ('\u{1F5B}', '\u{1F5B}'),
+    ('\u{1F5D}', '\u{1F5D}'),
+    ('\u{1F5F}', '\u{1F7D}'),
+    ('\u{1F80}', '\u{1FB4}'),
+    ('\u{1FB6}', '\u{1FBC}'),
+    ('\u{1FBE}', '\u{1FBE}'),
+    ('\u{1FC2}', '\u{1FC4}'),
+    ('\u{1FC6}', '\u{1FCC}'),
+    ('\u{1FD0}', '\u{1FD3}'),
+    ('\u{1FD6}', '\u{1FDB}'),
+    ('\u{1FE0}', '\u{1FEC}'),
+    ('\u{1FF2}', '\u{1FF4}'),
+    ('\u{1FF6}', '\u{1FFC}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207F}', '\u{207F}'),
+    ('\u{2090}', '\u{209C}'),
+    ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'),
+    ('\u{210A}', '\u{2113}'),
+    ('\u{2115}', '\u{2115}'),
+    ('\u{2119}', '\u{211D}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{2128}', '\u{2128}'),
+    ('\u{212A}', '\u{212D}'),
+    ('\u{212F}', '\u{2139}'),
+    ('\u{213C}', '\u{213F}'),
+    ('\u{2145}', '\u{2149}'),
+    ('\u{214E}', '\u{214E}'),
+    ('\u{2160}', '\u{2188}'),
+    ('\u{24B6}', '\u{24E9}'),
+    ('\u{2C00}', '\u{2CE4}'),
+    ('\u{2CEB}', '\u{2CEE}'),
+    ('\u{2CF2}', '\u{2CF3}'),
+    ('\u{2D00}', '\u{2D25}'),
+    ('\u{2D27}', '\u{2D27}'),
+    ('\u{2D2D}', '\u{2D2D}'),
+    ('\u{2D30}', '\u{2D67}'),
+    ('\u{2D6F}', '\u{2D6F}'),
+    ('\u{2D80}', '\u{2D96}'),
+    ('\u{2DA0}', '\u{2DA6}'),
+    ('\u{2DA8}', '\u{2DAE}'),
+    ('\u{2DB0}', '\u{2DB6}'),
+    ('\u{2DB8}', '\u{2DBE}'),
+    ('\u{2DC0}', '\u{2DC6}'),
+    ('\u{2DC8}', '\u{2DCE}'),
+    ('\u{2DD0}', '\u{2DD6}'),
+    ('\u{2DD8}', '\u{2DDE}'),
+    ('\u{2DE0}', '\u{2DFF}'),
+    ('\u{2E2F}', '\u{2E2F}'),
+    ('\u{3005}', '\u{3007}'),
+    ('\u{3021}', '\u{3029}'),
+    ('\u{3031}', '\u{3035}'),
+    ('\u{3038}', '\u{303C}'),
+    ('\u{3041}', '\u{3096}'),
+    ('\u{309D}', '\u{309F}'),
+    ('\u{30A1}', '\u{30FA}'),
+    ('\u{30FC}', '\u{30FF}'),
+    ('\u{3105}', '\u{312F}'),
+    ('\u{3131}', '\u{318E}'),
+    ('\u{31A0}', '\u{31BF}'),
+    ('\u{31F0}', '\u{31FF}'),
+    ('\u{3400}', '\u{4DBF}'),
+    ('\u{4E00}', '\u{A48C}'),
+    ('\u{A4D0}', '\u{A4FD}'),
+    ('\u{A500}', '\u{A60C}'),
+    ('\u{A610}', '\u{A61F}'),
+    ('\u{A62A}', '\u{A62B}'),
+    ('\u{A640}', '\u{A66E}'),
+    ('\u{A674}', '\u{A67B}'),
+    ('\u{A67F}', '\u{A6EF}'),
+    ('\u{A717}', '\u{A71F}'),
+    ('\u{A722}', '\u{A788}'),
+    ('\u{A78B}', '\u{A7CA}'),
+    ('\u{A7D0}', '\u{A7D1}'),
+    ('\u{A7D3}', '\u{A7D3}'),
+    ('\u{A7D5}', '\u{A7D9}'),
+    ('\u{A7F2}', '\u{A805}'),
+    ('\u{A807}', '\u{A827}'),
+    ('\u{A840}', '\u{A873}'),
+    ('\u{A880}', '\u{A8C3}'),
+    ('\u{A8C5}', '\u{A8C5}'),
+    ('\u{A8F2}', '\u{A8F7}'),
+    ('\u{A8FB}', '\u{A8FB}'),
+    ('\u{A8FD}', '\u{A8FF}'),
+    ('\u{A90A}', '\u{A92A}'),
+    ('\u{A930}', '\u{A952}'),
+    ('\u{A960}', '\u{A97C}'),
+    ('\u{A980}', '\u{A9B2}'),
+    ('\u{A9B4}', '\u{A9BF}'),
+    ('\u{A9CF}', '\u{A9CF}'),
+    ('\u{A9E0}', '\u{A9EF}'),
+    ('\u{A9FA}', '\u{A9FE}'),
+    ('\u{AA00}', '\u{AA36}'),
+    ('\u{AA40}', '\u{AA4D}'),
+    ('\u{AA60}', '\u{AA76}'),
+    ('\u{AA7A}', '\u{AABE}'),
+    ('\u{AAC0}', '\u{AAC0}'),
+    ('\u{AAC2}', '\u{AAC2}'),
+    ('\u{AADB}', '\u{AADD}'),
+    ('\u{AAE0}', '\u{AAEF}'),
+    ('\u{AAF2}', '\u{AAF5}'),
+    ('\u{AB01}', '\u{AB06}'),
+    ('\u{AB09}', '\u{AB0E}'),
+    ('\u{AB11}', '\u{AB16}'),
+    ('\u{AB20}', '\u{AB26}'),
+    ('\u{AB28}', '\u{AB2E}'),
+    ('\u{AB30}', '\u{AB5A}'),
+    ('\u{AB5C}', '\u{AB69}'),
+    ('\u{AB70}', '\u{ABEA}'),
+    ('\u{AC00}', '\u{D7A3}'),
+    ('\u{D7B0}', '\u{D7C6}'),
+    ('\u{D7CB}', '\u{D7FB}'),
+    ('\u{F900}', '\u{FA6D}'),
+    ('\u{FA70}', '\u{FAD9}'),
+    ('\u{FB00}', '\u{FB06}'),
+    ('\u{FB13}', '\u{FB17}'),
+    ('\u{FB1D}', '\u{FB28}'),
+    ('\u{FB2A}', '\u{FB36}'),
+    ('\u{FB38}', '\u{FB3C}'),
+    ('\u{FB3E}', '\u{FB3E}'),
+    ('\u{FB40}', '\u{FB41}'),
+    ('\u{FB43}', '\u{FB44}'),
+    ('\u{FB46}', '\u{FBB1}'),
+    ('\u{FBD3}', '\u{FD3D}'),
+    ('\u{FD50}', '\u{FD8F}'),
+    ('\u{FD92}', '\u{FDC7}'),
+    ('\u{FDF0}', '\u{FDFB}'),
+    ('\u{FE70}', '\u{FE74}'),
+    ('\u{FE76}', '\u{FEFC}'),
+    ('\u{FF21}', '\u{FF3A}'),
+    ('\u{FF41}', '\u{FF5A}'),
+    ('\u{FF66}', '\u{FFBE}'),
+    ('\u{FFC2}', '\u{FFC7}'),
+    ('\u{FFCA}', '\u{FFCF}'),
+    ('\u{FFD2}', '\u{FFD7}'),
+    ('\u{FFDA}', '\u{FFDC}'),
+    ('\u{10000}', '\u{1000B}'),
+    ('\u{1000D}', '\u{10026}'),
+    ('\u{10028}', '\u{1003A}'),
+    ('\u{1003C}', '\u{1003D}'),
+    ('\u{1003F}', '\u{1004D}'),
+    ('\u{10050}', '\u{1005D}'),
+    ('\u{10080}', '\u{100FA}'),
+    ('\u{10140}', '\u{10174}'),
+    ('\u{10280}', '\u{1029C}'),
+    ('\u{102A0}', '\u{102D0}'),
+    ('\u{10300}', '\u{1031F}'),
+    ('\u{1032D}', '\u{1034A}'),
+    ('\u{10350}', '\u{1037A}'),
+    ('\u{10380}', '\u{1039D}'),
+    ('\u{103A0}', '\u{103C3}'),
+    ('\u{103C8}', '\u{103CF}'),
+    ('\u{103D1}', '\u{103D5}'),
+    ('\u{10400}', '\u{1049D}'),
+    ('\u{104B0}', '\u{104D3}'),
+    ('\u{104D8}', '\u{104FB}'),
+    ('\u{10500}', '\u{10527}'),
+    ('\u{10530}', '\u{10563}'),
+    ('\u{10570}', '\u{1057A}'),
+    ('\u{1057C}', '\u{1058A}'),
+    ('\u{1058C}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'),
+    ('\u{10597}', '\u{105A1}'),
+    ('\u{105A3}', '\u{105B1}'),
+    ('\u{105B3}', '\u{105B9}'),
+    ('\u{105BB}', '\u{105BC}'),
+    ('\u{10600}', '\u{10736}'),
+    ('\u{10740}', '\u{10755}'),
+    ('\u{10760}', '\u{10767}'),
+    ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107B0}'),
+    ('\u{107B2}', '\u{107BA}'),
+    ('\u{10800}', '\u{10805}'),
+    ('\u{10808}', '\u{10808}'),
+    ('\u{1080A}', '\u{10835}'),
+    ('\u{10837}', '\u{10838}'),
+    ('\u{1083C}', '\u{1083C}'),
+    ('\u{1083F}', '\u{10855}'),
+    ('\u{10860}', '\u{10876}'),
+    ('\u{10880}', '\u{1089E}'),
+    ('\u{108E0}', '\u{108F2}'),
+    ('\u{108F4}', '\u{108F5}'),
+    ('\u{10900}', '\u{10915}'),
+    ('\u{10920}', '\u{10939}'),
+    ('\u{10980}', '\u{109B7}'),
+    ('\u{109BE}', '\u{109BF}'),
+    ('\u{10A00}', '\u{10A03}'),
+    ('\u{10A05}', '\u{10A06}'),
+    ('\u{10A0C}', '\u{10A13}'),
+    ('\u{10A15}', '\u{10A17}'),
+    ('\u{10A19}', '\u{10A35}'),
+    ('\u{10A60}', '\u{10A7C}'),
+    ('\u{10A80}', '\u{10A9C}'),
+    ('\u{10AC0}', '\u{10AC7}'),
+    ('\u{10AC9}', '\u{10AE4}'),
+    ('\u{10B00}', '\u{10B35}'),
+    ('\u{10B40}', '\u{10B55}'),
+    ('\u{10B60}', '\u{10B72}'),
+    ('\u{10B80}', '\u{10B91}'),
+    ('\u{10C00}', '\u{10C48}'),
+    ('\u{10C80}', '\u{10CB2}'),
+    ('\u{10CC0}', '\u{10CF2}'),
+    ('\u{10D00}', '\u{10D27}'),
+    ('\u{10E80}', '\u{10EA9}'),
+    ('\u{10EAB}', '\u{10EAC}'),
+    ('\u{10EB0}', '\u{10EB1}'),
+    ('\u{10F00}', '\u{10F1C}'),
+    ('\u{10F27}', '\u{10F27}'),
+    ('\u{10F30}', '\u{10F45}'),
+    ('\u{10F70}', '\u{10F81}'),
+    ('\u{10FB0}', '\u{10FC4}'),
+    ('\u{10FE0}', '\u{10FF6}'),
+    ('\u{11000}', '\u{11045}'),
+    ('\u{11071}', '\u{11075}'),
+    ('\u{11082}', '\u{110B8}'),
+    ('\u{110C2}', '\u{110C2}'),
+    ('\u{110D0}', '\u{110E8}'),
+    ('\u{11100}', '\u{11132}'),
+    ('\u{11144}', '\u{11147}'),
+    ('\u{11150}', '\u{11172}'),
+    ('\u{11176}', '\u{11176}'),
+    ('\u{11180}', '\u{111BF}'),
+    ('\u{111C1}', '\u{111C4}'),
+    ('\u{111CE}', '\u{111CF}'),
+    ('\u{111DA}', '\u{111DA}'),
+    ('\u{111DC}', '\u{111DC}'),
+    ('\u{11200}', '\u{11211}'),
+    ('\u{11213}', '\u{11234}'),
+    ('\u{11237}', '\u{11237}'),
+    ('\u{1123E}', '\u{1123E}'),
+    ('\u{11280}', '\u{11286}'),
+    ('\u{11288}', '\u{11288}'),
+    ('\u{1128A}', '\u{1128D}'),
+    ('\u{1128F}', '\u{1129D}'),
+    ('\u{1129F}', '\u{112A8}'),
+    ('\u{112B0}', '\u{112E8}'),
+    ('\u{11300}', '\u{11303}'),
+    ('\u{11305}', '\u{1130C}'),
+    ('\u{1130F}', '\u{11310}'),
+    ('\u{11313}', '\u{11328}'),
+    ('\u{1132A}', '\u{11330}'),
+    ('\u{11332}', '\u{11333}'),
+    ('\u{11335}', '\u{11339}'),
+    ('\u{1133D}', '\u{11344}'),
+    ('\u{11347}', '\u{11348}'),
+    ('\u{1134B}', '\u{1134C}'),
+    ('\u{11350}', '\u{11350}'),
+    ('\u{11357}', '\u{11357}'),
+    ('\u{1135D}', '\u{11363}'),
+    ('\u{11400}', '\u{11441}'),
+    ('\u{11443}', '\u{11445}'),
+    ('\u{11447}', '\u{1144A}'),
+    ('\u{1145F}', '\u{11461}'),
+    ('\u{11480}', '\u{114C1}'),
+    ('\u{114C4}', '\u{114C5}'),
+    ('\u{114C7}', '\u{114C7}'),
+    ('\u{11580}', '\u{115B5}'),
+    ('\u{115B8}', '\u{115BE}'),
+    ('\u{115D8}', '\u{115DD}'),
+    ('\u{11600}', '\u{1163E}'),
+    ('\u{11640}', '\u{11640}'),
+    ('\u{11644}', '\u{11644}'),
+    ('\u{11680}', '\u{116B5}'),
+    ('\u{116B8}', '\u{116B8}'),
+    ('\u{11700}', '\u{1171A}'),
+    ('\u{1171D}', '\u{1172A}'),
+    ('\u{11740}', '\u{11746}'),
+    ('\u{11800}', '\u{11838}'),
+    ('\u{118A0}', '\u{118DF}'),
+    ('\u{118FF}', '\u{11906}'),
+    ('\u{11909}', '\u{11909}'),
+    ('\u{1190C}', '\u{11913}'),
+    ('\u{11915}', '\u{11916}'),
+    ('\u{11918}', '\u{11935}'),
+    ('\u{11937}', '\u{11938}'),
+    ('\u{1193B}', '\u{1193C}'),
+    ('\u{1193F}', '\u{11942}'),
+    ('\u{119A0}', '\u{119A7}'),
+    ('\u{119AA}', '\u{119D7}'),
+    ('\u{119DA}', '\u{119DF}'),
+    ('\u{119E1}', '\u{119E1}'),
+    ('\u{119E3}', '\u{119E4}'),
+    ('\u{11A00}', '\u{11A32}'),
+    ('\u{11A35}', '\u{11A3E}'),
+    ('\u{11A50}', '\u{11A97}'),
+    ('\u{11A9D}', '\u{11A9D}'),
+    ('\u{11AB0}', '\u{11AF8}'),
+    ('\u{11C00}', '\u{11C08}'),
+    ('\u{11C0A}', '\u{11C36}'),
+    ('\u{11C38}', '\u{11C3E}'),
+    ('\u{11C40}', '\u{11C40}'),
+    ('\u{11C72}', '\u{11C8F}'),
+    ('\u{11C92}', '\u{11CA7}'),
+    ('\u{11CA9}', '\u{11CB6}'),
+    ('\u{11D00}', '\u{11D06}'),
+    ('\u{11D08}', '\u{11D09}'),
+    ('\u{11D0B}', '\u{11D36}'),
+    ('\u{11D3A}', '\u{11D3A}'),
+    ('\u{11D3C}', '\u{11D3D}'),
+    ('\u{11D3F}', '\u{11D41}'),
+    ('\u{11D43}', '\u{11D43}'),
+    ('\u{11D46}', '\u{11D47}'),
+    ('\u{11D60}', '\u{11D65}'),
+    ('\u{11D67}', '\u{11D68}'),
+    ('\u{11D6A}', '\u{11D8E}'),
+    ('\u{11D90}', '\u{11D91}'),
+    ('\u{11D93}', '\u{11D96}'),
+    ('\u{11D98}', '\u{11D98}'),
+    ('\u{11EE0}', '\u{11EF6}'),
+    ('\u{11FB0}', '\u{11FB0}'),
+    ('\u{12000}', '\u{12399}'),
+    ('\u{12400}', '\u{1246E}'),
+    ('\u{12480}', '\u{12543}'),
+    ('\u{12F90}', '\u{12FF0}'),
+    ('\u{13000}', '\u{1342E}'),
+    ('\u{14400}', '\u{14646}'),
+    ('\u{16800}', '\u{16A38}'),
+    ('\u{16A40}', '\u{16A5E}'),
+    ('\u{16A70}', '\u{16ABE}'),
+    ('\u{16AD0}', '\u{16AED}'),
+    ('\u{16B00}', '\u{16B2F}'),
+    ('\u{16B40}', '\u{16B43}'),
+    ('\u{16B63}', '\u{16B77}'),
+    ('\u{16B7D}', '\u{16B8F}'),
+    ('\u{16E40}', '\u{16E7F}'),
+    ('\u{16F00}', '\u{16F4A}'),
+    ('\u{16F4F}', '\u{16F87}'),
+    ('\u{16F8F}', '\u{16F9F}'),
+    ('\u{16FE0}', '\u{16FE1}'),
+    ('\u{16FE3}', '\u{16FE3}'),
+    ('\u{16FF0}', '\u{16FF1}'),
+    ('\u{17000}', '\u{187F7}'),
+    ('\u{18800}', '\u{18CD5}'),
+    ('\u{18D00}', '\u{18D08}'),
+    ('\u{1AFF0}', '\u{1AFF3}'),
+    ('\u{1AFF5}', '\u{1AFFB}'),
+    ('\u{1AFFD}', '\u{1AFFE}'),
+    ('\u{1B000}', '\u{1B122}'),
+    ('\u{1B150}', '\u{1B152}'),
+    ('\u{1B164}', '\u{1B167}'),
+    ('\u{1B170}', '\u{1B2FB}'),
+    ('\u{1BC00}', '\u{1BC6A}'),
+    ('\u{1BC70}', '\u{1BC7C}'),
+    ('\u{1BC80}', '\u{1BC88}'),
+    ('\u{1BC90}', '\u{1BC99}'),
+    ('\u{1BC9E}', '\u{1BC9E}'),
+    ('\u{1D400}', '\u{1D454}'),
+    ('\u{1D456}', '\u{1D49C}'),
+    ('\u{1D49E}', '\u{1D49F}'),
+    ('\u{1D4A2}', '\u{1D4A2}'),
+    ('\u{1D4A5}', '\u{1D4A6}'),
+    ('\u{1D4A9}', '\u{1D4AC}'),
+    ('\u{1D4AE}', '\u{1D4B9}'),
+    ('\u{1D4BB}', '\u{1D4BB}'),
+    ('\u{1D4BD}', '\u{1D4C3}'),
+    ('\u{1D4C5}', '\u{1D505}'),
+    ('\u{1D507}', '\u{1D50A}'),
+    ('\u{1D50D}', '\u{1D514}'),
+    ('\u{1D516}', '\u{1D51C}'),
+    ('\u{1D51E}', '\u{1D539}'),
+    ('\u{1D53B}', '\u{1D53E}'),
+    ('\u{1D540}', '\u{1D544}'),
+    ('\u{1D546}', '\u{1D546}'),
+    ('\u{1D54A}', '\u{1D550}'),
+    ('\u{1D552}', '\u{1D6A5}'),
+    ('\u{1D6A8}', '\u{1D6C0}'),
+    ('\u{1D6C2}', '\u{1D6DA}'),
+    ('\u{1D6DC}', '\u{1D6FA}'),
+    ('\u{1D6FC}', '\u{1D714}'),
+    ('\u{1D716}', '\u{1D734}'),
+    ('\u{1D736}', '\u{1D74E}'),
+    ('\u{1D750}', '\u{1D76E}'),
+    ('\u{1D770}', '\u{1D788}'),
+    ('\u{1D78A}', '\u{1D7A8}'),
+    ('\u{1D7AA}', '\u{1D7C2}'),
+    ('\u{1D7C4}', '\u{1D7CB}'),
+    ('\u{1DF00}', '\u{1DF1E}'),
+    ('\u{1E000}', '\u{1E006}'),
+    ('\u{1E008}', '\u{1E018}'),
+    ('\u{1E01B}', '\u{1E021}'),
+    ('\u{1E023}', '\u{1E024}'),
+    ('\u{1E026}', '\u{1E02A}'),
+    ('\u{1E100}', '\u{1E12C}'),
+    ('\u{1E137}', '\u{1E13D}'),
+    ('\u{1E14E}', '\u{1E14E}'),
+    ('\u{1E290}', '\u{1E2AD}'),
+    ('\u{1E2C0}', '\u{1E2EB}'),
+    ('\u{1E7E0}', '\u{1E7E6}'),
+    ('\u{1E7E8}', '\u{1E7EB}'),
+    ('\u{1E7ED}', '\u{1E7EE}'),
+    ('\u{1E7F0}', '\u{1E7FE}'),
+    ('\u{1E800}', '\u{1E8C4}'),
+    ('\u{1E900}', '\u{1E943}'),
+    ('\u{1E947}', '\u{1E947}'),
+    ('\u{1E94B}', '\u{1E94B}'),
+    ('\u{1EE00}', '\u{1EE03}'),
+    ('\u{1EE05}', '\u{1EE1F}'),
+    ('\u{1EE21}', '\u{1EE22}'),
+    ('\u{1EE24}', '\u{1EE24}'),
+    ('\u{1EE27}', '\u{1EE27}'),
+    ('\u{1EE29}', '\u{1EE32}'),
+    ('\u{1EE34}', '\u{1EE37}'),
+    ('\u{1EE39}', '\u{1EE39}'),
+    ('\u{1EE3B}', '\u{1EE3B}'),
+    ('\u{1EE42}', '\u{1EE42}'),
+    ('\u{1EE47}', '\u{1EE47}'),
+    ('\u{1EE49}', '\u{1EE49}'),
+    ('\u{1EE4B}', '\u{1EE4B}'),
+    ('\u{1EE4D}', '\u{1EE4F}'),
+    ('\u{1EE51}', '\u{1EE52}'),
+    ('\u{1EE54}', '\u{1EE54}'),
+    ('\u{1EE57}', '\u{1EE57}'),
+    ('\u{1EE59}', '\u{1EE59}'),
+    ('\u{1EE5B}', '\u{1EE5B}'),
+    ('\u{1EE5D}', '\u{1EE5D}'),
+    ('\u{1EE5F}', '\u{1EE5F}'),
+    ('\u{1EE61}', '\u{1EE62}'),
+    ('\u{1EE64}', '\u{1EE64}'),
+    ('\u{1EE67}', '\u{1EE6A}'),
+    ('\u{1EE6C}', '\u{1EE72}'),
+    ('\u{1EE74}', '\u{1EE77}'),
+    ('\u{1EE79}', '\u{1EE7C}'),
+    ('\u{1EE7E}', '\u{1EE7E}'),
+    ('\u{1EE80}', '\u{1EE89}'),
+    ('\u{1EE8B}', '\u{1EE9B}'),
+    ('\u{1EEA1}', '\u{1EEA3}'),
+    ('\u{1EEA5}', '\u{1EEA9}'),
+    ('\u{1EEAB}', '\u{1EEBB}'),
+    ('\u{1F130}', '\u{1F149}'),
+    ('\u{1F150}', '\u{1F169}'),
+    ('\u{1F170}', '\u{1F189}'),
+    ('\u{20000}', '\u{2A6DF}'),
+    ('\u{2A700}', '\u{2B738}'),
+    ('\u{2B740}', '\u{2B81D}'),
+    ('\u{2B820}', '\u{2CEA1}'),
+    ('\u{2CEB0}', '\u{2EBE0}'),
+    ('\u{2F800}', '\u{2FA1D}'),
+    ('\u{30000}', '\u{3134A}'),
+];
+
+/// Ranges of chars with the `Dash` property, sorted by code point.
+pub(crate) static DASH: &[(char, char)] = &[
+    ('\u{2D}', '\u{2D}'),
+    ('\u{58A}', '\u{58A}'),
+    ('\u{5BE}', '\u{5BE}'),
+    ('\u{1400}', '\u{1400}'),
+    ('\u{1806}', '\u{1806}'),
+    ('\u{2010}', '\u{2015}'),
+    ('\u{2053}', '\u{2053}'),
+    ('\u{207B}', '\u{207B}'),
+    ('\u{208B}', '\u{208B}'),
+    ('\u{2212}', '\u{2212}'),
+    ('\u{2E17}', '\u{2E17}'),
+    ('\u{2E1A}', '\u{2E1A}'),
+    ('\u{2E3A}', '\u{2E3B}'),
+    ('\u{2E40}', '\u{2E40}'),
+    ('\u{2E5D}', '\u{2E5D}'),
+    ('\u{301C}', '\u{301C}'),
+    ('\u{3030}', '\u{3030}'),
+    ('\u{30A0}', '\u{30A0}'),
+    ('\u{FE31}', '\u{FE32}'),
+    ('\u{FE58}', '\u{FE58}'),
+    ('\u{FE63}', '\u{FE63}'),
+    ('\u{FF0D}', '\u{FF0D}'),
+    ('\u{10EAD}', '\u{10EAD}'),
+];
+
+/// Ranges of chars with the `Default_Ignorable_Code_Point` property, sorted by code point.
+pub(crate) static DEFAULT_IGNORABLE_CODE_POINT: &[(char, char)] = &[
+    ('\u{AD}', '\u{AD}'),
+    ('\u{34F}', '\u{34F}'),
+    ('\u{61C}', '\u{61C}'),
+    ('\u{115F}', '\u{1160}'),
+    ('\u{17B4}', '\u{17B5}'),
+    ('\u{180B}', '\u{180F}'),
+    ('\u{200B}', '\u{200F}'),
+    ('\u{202A}', '\u{202E}'),
+    ('\u{2060}', '\u{206F}'),
+    ('\u{3164}', '\u{3164}'),
+    ('\u{FE00}', '\u{FE0F}'),
+    ('\u{FEFF}', '\u{FEFF}'),
+    ('\u{FFA0}', '\u{FFA0}'),
+    ('\u{FFF0}', '\u{FFF8}'),
+    ('\u{1BCA0}', '\u{1BCA3}'),
+    ('\u{1D173}', '\u{1D17A}'),
+    ('\u{E0000}', '\u{E0FFF}'),
+];
+
+/// Ranges of chars with the `Emoji` property, sorted by code point.
+pub(crate) static EMOJI: &[(char, char)] = &[
+    ('\u{23}', '\u{23}'),
+    ('\u{2A}', '\u{2A}'),
+    ('\u{30}', '\u{39}'),
+    ('\u{A9}', '\u{A9}'),
+    ('\u{AE}', '\u{AE}'),
+    ('\u{203C}', '\u{203C}'),
+    ('\u{2049}', '\u{2049}'),
+    ('\u{2122}', '\u{2122}'),
+    ('\u{2139}', '\u{2139}'),
+    ('\u{2194}', '\u{2199}'),
+    ('\u{21A9}', '\u{21AA}'),
+    ('\u{231A}', '\u{231B}'),
+    ('\u{2328}', '\u{2328}'),
+    ('\u{23CF}', '\u{23CF}'),
+    ('\u{23E9}', '\u{23F3}'),
+    ('\u{23F8}', '\u{23FA}'),
+    ('\u{24C2}', '\u{24C2}'),
+    ('\u{25AA}', '\u{25AB}'),
+    ('\u{25B6}', '\u{25B6}'),
+    ('\u{25C0}', '\u{25C0}'),
+    ('\u{25FB}', '\u{25FE}'),
+    ('\u{2600}', '\u{2604}'),
+    ('\u{260E}', '\u{260E}'),
+    ('\u{2611}', '\u{2611}'),
+    ('\u{2614}', '\u{2615}'),
+    ('\u{2618}', '\u{2618}'),
+    ('\u{261D}', '\u{261D}'),
+    ('\u{2620}', '\u{2620}'),
+    ('\u{2622}', '\u{2623}'),
+    ('\u{2626}', '\u{2626}'),
+    ('\u{262A}', '\u{262A}'),
+    ('\u{262E}', '\u{262F}'),
+    ('\u{2638}', '\u{263A}'),
+    ('\u{2640}', '\u{2640}'),
+    ('\u{2642}', '\u{2642}'),
+    ('\u{2648}', '\u{2653}'),
+    ('\u{265F}', '\u{2660}'),
+    ('\u{2663}', '\u{2663}'),
+    ('\u{2665}', '\u{2666}'),
+    ('\u{2668}', '\u{2668}'),
+    ('\u{267B}', '\u{267B}'),
+    ('\u{267E}', '\u{267F}'),
+    ('\u{2692}', '\u{2697}'),
+    ('\u{2699}', '\u{2699}'),
+    ('\u{269B}', '\u{269C}'),
+    ('\u{26A0}', '\u{26A1}'),
+    ('\u{26A7}', '\u{26A7}'),
+    ('\u{26AA}', '\u{26AB}'),
+    ('\u{26B0}', '\u{26B1}'),
+    ('\u{26BD}', '\u{26BE}'),
+    ('\u{26C4}', '\u{26C5}'),
+    ('\u{26C8}', '\u{26C8}'),
+    ('\u{26CE}', '\u{26CF}'),
+    ('\u{26D1}', '\u{26D1}'),
+    ('\u{26D3}', '\u{26D4}'),
+    ('\u{26E9}', '\u{26EA}'),
+    ('\u{26F0}', '\u{26F5}'),
+    ('\u{26F7}', '\u{26FA}'),
+    ('\u{26FD}', '\u{26FD}'),
+    ('\u{2702}', '\u{2702}'),
+    ('\u{2705}', '\u{2705}'),
+    ('\u{2708}', '\u{270D}'),
+    ('\u{270F}', '\u{270F}'),
+    ('\u{2712}', '\u{2712}'),
+    ('\u{2714}', '\u{2714}'),
+    ('\u{2716}', '\u{2716}'),
+    ('\u{271D}', '\u{271D}'),
+    ('\u{2721}', '\u{2721}'),
+    ('\u{2728}', '\u{2728}'),
+    ('\u{2733}', '\u{2734}'),
+    ('\u{2744}', '\u{2744}'),
+    ('\u{2747}', '\u{2747}'),
+    ('\u{274C}', '\u{274C}'),
+    ('\u{274E}', '\u{274E}'),
+    ('\u{2753}', '\u{2755}'),
+    ('\u{2757}', '\u{2757}'),
+    ('\u{2763}', '\u{2764}'),
+    ('\u{2795}', '\u{2797}'),
+    ('\u{27A1}', '\u{27A1}'),
+    ('\u{27B0}', '\u{27B0}'),
+    ('\u{27BF}', '\u{27BF}'),
+    ('\u{2934}', '\u{2935}'),
+    ('\u{2B05}', '\u{2B07}'),
+    ('\u{2B1B}', '\u{2B1C}'),
+    ('\u{2B50}', '\u{2B50}'),
+    ('\u{2B55}', '\u{2B55}'),
+    ('\u{3030}', '\u{3030}'),
+    ('\u{303D}', '\u{303D}'),
+    ('\u{3297}', '\u{3297}'),
+    ('\u{3299}', '\u{3299}'),
+    ('\u{1F004}', '\u{1F004}'),
+    ('\u{1F0CF}', '\u{1F0CF}'),
+    ('\u{1F170}', '\u{1F171}'),
+    ('\u{1F17E}', '\u{1F17F}'),
+    ('\u{1F18E}', '\u{1F18E}'),
+    ('\u{1F191}', '\u{1F19A}'),
+    ('\u{1F1E6}', '\u{1F1FF}'),
+    ('\u{1F201}', '\u{1F202}'),
+    ('\u{1F21A}', '\u{1F21A}'),
+    ('\u{1F22F}', '\u{1F22F}'),
+    ('\u{1F232}', '\u{1F23A}'),
+    ('\u{1F250}', '\u{1F251}'),
+    ('\u{1F300}', '\u{1F321}'),
+    ('\u{1F324}', '\u{1F393}'),
+    ('\u{1F396}', '\u{1F397}'),
+    ('\u{1F399}', '\u{1F39B}'),
+    ('\u{1F39E}', '\u{1F3F0}'),
+    ('\u{1F3F3}', '\u{1F3F5}'),
+    ('\u{1F3F7}', '\u{1F4FD}'),
+    ('\u{1F4FF}', '\u{1F53D}'),
+    ('\u{1F549}', '\u{1F54E}'),
+    ('\u{1F550}', '\u{1F567}'),
+    ('\u{1F56F}', '\u{1F570}'),
+    ('\u{1F573}', '\u{1F57A}'),
+    ('\u{1F587}', '\u{1F587}'),
+    ('\u{1F58A}', '\u{1F58D}'),
+    ('\u{1F590}', '\u{1F590}'),
+    ('\u{1F595}', '\u{1F596}'),
+    ('\u{1F5A4}', '\u{1F5A5}'),
+    ('\u{1F5A8}', '\u{1F5A8}'),
+    ('\u{1F5B1}', '\u{1F5B2}'),
+    ('\u{1F5BC}', '\u{1F5BC}'),
+    ('\u{1F5C2}', '\u{1F5C4}'),
+    ('\u{1F5D1}', '\u{1F5D3}'),
+    ('\u{1F5DC}', '\u{1F5DE}'),
+    ('\u{1F5E1}', '\u{1F5E1}'),
+    ('\u{1F5E3}', '\u{1F5E3}'),
+    ('\u{1F5E8}', '\u{1F5E8}'),
+    ('\u{1F5EF}', '\u{1F5EF}'),
+    ('\u{1F5F3}', '\u{1F5F3}'),
+    ('\u{1F5FA}', '\u{1F64F}'),
+    ('\u{1F680}', '\u{1F6C5}'),
+    ('\u{1F6CB}', '\u{1F6D2}'),
+    ('\u{1F6D5}', '\u{1F6D7}'),
+    ('\u{1F6DD}', '\u{1F6E5}'),
+    ('\u{1F6E9}', '\u{1F6E9}'),
+    ('\u{1F6EB}', '\u{1F6EC}'),
+    ('\u{1F6F0}', '\u{1F6F0}'),
+    ('\u{1F6F3}', '\u{1F6FC}'),
+    ('\u{1F7E0}', '\u{1F7EB}'),
+    ('\u{1F7F0}', '\u{1F7F0}'),
+    ('\u{1F90C}', '\u{1F93A}'),
+    ('\u{1F93C}', '\u{1F945}'),
+    ('\u{1F947}', '\u{1F9FF}'),
+    ('\u{1FA70}', '\u{1FA74}'),
+    ('\u{1FA78}', '\u{1FA7C}'),
+    ('\u{1FA80}', '\u{1FA86}'),
+    ('\u{1FA90}', '\u{1FAAC}'),
+    ('\u{1FAB0}', '\u{1FABA}'),
+    ('\u{1FAC0}', '\u{1FAC5}'),
+    ('\u{1FAD0}', '\u{1FAD9}'),
+    ('\u{1FAE0}', '\u{1FAE7}'),
+    ('\u{1FAF0}', '\u{1FAF6}'),
+];
+
+/// Ranges of chars with the `Emoji_Component` property, sorted by code point.
+pub(crate) static EMOJI_COMPONENT: &[(char, char)] = &[
+    ('\u{23}', '\u{23}'),
+    ('\u{2A}', '\u{2A}'),
+    ('\u{30}', '\u{39}'),
+    ('\u{200D}', '\u{200D}'),
+    ('\u{20E3}', '\u{20E3}'),
+    ('\u{FE0F}', '\u{FE0F}'),
+    ('\u{1F1E6}', '\u{1F1FF}'),
+    ('\u{1F3FB}', '\u{1F3FF}'),
+    ('\u{1F9B0}', '\u{1F9B3}'),
+    ('\u{E0020}', '\u{E007F}'),
+];
+
+/// Ranges of chars with the `Emoji_Modifier` property, sorted by code point.
+pub(crate) static EMOJI_MODIFIER: &[(char, char)] = &[
+    ('\u{1F3FB}', '\u{1F3FF}'),
+];
+
+/// Ranges of chars with the `Emoji_Modifier_Base` property, sorted by code point.
+pub(crate) static EMOJI_MODIFIER_BASE: &[(char, char)] = &[
+    ('\u{261D}', '\u{261D}'),
+    ('\u{26F9}', '\u{26F9}'),
+    ('\u{270A}', '\u{270D}'),
+    ('\u{1F385}', '\u{1F385}'),
+    ('\u{1F3C2}', '\u{1F3C4}'),
+    ('\u{1F3C7}', '\u{1F3C7}'),
+    ('\u{1F3CA}', '\u{1F3CC}'),
+    ('\u{1F442}', '\u{1F443}'),
+    ('\u{1F446}', '\u{1F450}'),
+    ('\u{1F466}', '\u{1F478}'),
+    ('\u{1F47C}', '\u{1F47C}'),
+    ('\u{1F481}', '\u{1F483}'),
+    ('\u{1F485}', '\u{1F487}'),
+    ('\u{1F48F}', '\u{1F48F}'),
+    ('\u{1F491}', '\u{1F491}'),
+    ('\u{1F4AA}', '\u{1F4AA}'),
+    ('\u{1F574}', '\u{1F575}'),
+    ('\u{1F57A}', '\u{1F57A}'),
+    ('\u{1F590}', '\u{1F590}'),
+    ('\u{1F595}', '\u{1F596}'),
+    ('\u{1F645}', '\u{1F647}'),
+    ('\u{1F64B}', '\u{1F64F}'),
+    ('\u{1F6A3}', '\u{1F6A3}'),
+    ('\u{1F6B4}', '\u{1F6B6}'),
+    ('\u{1F6C0}', '\u{1F6C0}'),
+    ('\u{1F6CC}', '\u{1F6CC}'),
+    ('\u{1F90C}', '\u{1F90C}'),
+    ('\u{1F90F}', '\u{1F90F}'),
+    ('\u{1F918}', '\u{1F91F}'),
+    ('\u{1F926}', '\u{1F926}'),
+    ('\u{1F930}', '\u{1F939}'),
+    ('\u{1F93C}', '\u{1F93E}'),
+    ('\u{1F977}', '\u{1F977}'),
+    ('\u{1F9B5}', '\u{1F9B6}'),
+    ('\u{1F9B8}', '\u{1F9B9}'),
+    ('\u{1F9BB}', '\u{1F9BB}'),
+    ('\u{1F9CD}', '\u{1F9CF}'),
+    ('\u{1F9D1}', '\u{1F9DD}'),
+    ('\u{1FAC3}', '\u{1FAC5}'),
+    ('\u{1FAF0}', '\u{1FAF6}'),
+];
+
+/// Ranges of chars with the `Emoji_Presentation` property, sorted by code point.
+pub(crate) static EMOJI_PRESENTATION: &[(char, char)] = &[
+    ('\u{231A}', '\u{231B}'),
+    ('\u{23E9}', '\u{23EC}'),
+    ('\u{23F0}', '\u{23F0}'),
+    ('\u{23F3}', '\u{23F3}'),
+    ('\u{25FD}', '\u{25FE}'),
+    ('\u{2614}', '\u{2615}'),
+    ('\u{2648}', '\u{2653}'),
+    ('\u{267F}', '\u{267F}'),
+    ('\u{2693}', '\u{2693}'),
+    ('\u{26A1}', '\u{26A1}'),
+    ('\u{26AA}', '\u{26AB}'),
+    ('\u{26BD}', '\u{26BE}'),
+    ('\u{26C4}', '\u{26C5}'),
+    ('\u{26CE}', '\u{26CE}'),
+    ('\u{26D4}', '\u{26D4}'),
+    ('\u{26EA}', '\u{26EA}'),
+    ('\u{26F2}', '\u{26F3}'),
+    ('\u{26F5}', '\u{26F5}'),
+    ('\u{26FA}', '\u{26FA}'),
+    ('\u{26FD}', '\u{26FD}'),
+    ('\u{2705}', '\u{2705}'),
+    ('\u{270A}', '\u{270B}'),
+    ('\u{2728}', '\u{2728}'),
+    ('\u{274C}', '\u{274C}'),
+    ('\u{274E}', '\u{274E}'),
+    ('\u{2753}', '\u{2755}'),
+    ('\u{2757}', '\u{2757}'),
+    ('\u{2795}', '\u{2797}'),
+    ('\u{27B0}', '\u{27B0}'),
+    ('\u{27BF}', '\u{27BF}'),
+    ('\u{2B1B}', '\u{2B1C}'),
+    ('\u{2B50}', '\u{2B50}'),
+    ('\u{2B55}', '\u{2B55}'),
+    ('\u{1F004}', '\u{1F004}'),
+    ('\u{1F0CF}', '\u{1F0CF}'),
+    ('\u{1F18E}', '\u{1F18E}'),
+    ('\u{1F191}', '\u{1F19A}'),
+    ('\u{1F1E6}', '\u{1F1FF}'),
+    ('\u{1F201}', '\u{1F201}'),
+    ('\u{1F21A}', '\u{1F21A}'),
+    ('\u{1F22F}', '\u{1F22F}'),
+    ('\u{1F232}', '\u{1F236}'),
+    ('\u{1F238}', '\u{1F23A}'),
+    ('\u{1F250}', '\u{1F251}'),
+    ('\u{1F300}', '\u{1F320}'),
+    ('\u{1F32D}', '\u{1F335}'),
+    ('\u{1F337}', '\u{1F37C}'),
+    ('\u{1F37E}', '\u{1F393}'),
+    ('\u{1F3A0}', '\u{1F3CA}'),
+    ('\u{1F3CF}', '\u{1F3D3}'),
+    ('\u{1F3E0}', '\u{1F3F0}'),
+    ('\u{1F3F4}', '\u{1F3F4}'),
+    ('\u{1F3F8}', '\u{1F43E}'),
+    ('\u{1F440}', '\u{1F440}'),
+    ('\u{1F442}', '\u{1F4FC}'),
+    ('\u{1F4FF}', '\u{1F53D}'),
+    ('\u{1F54B}', '\u{1F54E}'),
+    ('\u{1F550}', '\u{1F567}'),
+    ('\u{1F57A}', '\u{1F57A}'),
+    ('\u{1F595}', '\u{1F596}'),
+    ('\u{1F5A4}', '\u{1F5A4}'),
+    ('\u{1F5FB}', '\u{1F64F}'),
+    ('\u{1F680}', '\u{1F6C5}'),
+    ('\u{1F6CC}', '\u{1F6CC}'),
+    ('\u{1F6D0}', '\u{1F6D2}'),
+    ('\u{1F6D5}', '\u{1F6D7}'),
+    ('\u{1F6DD}', '\u{1F6DF}'),
+    ('\u{1F6EB}', '\u{1F6EC}'),
+    ('\u{1F6F4}', '\u{1F6FC}'),
+    ('\u{1F7E0}', '\u{1F7EB}'),
+    ('\u{1F7F0}', '\u{1F7F0}'),
+    ('\u{1F90C}', '\u{1F93A}'),
+    ('\u{1F93C}', '\u{1F945}'),
+    ('\u{1F947}', '\u{1F9FF}'),
+    ('\u{1FA70}', '\u{1FA74}'),
+    ('\u{1FA78}', '\u{1FA7C}'),
+    ('\u{1FA80}', '\u{1FA86}'),
+    ('\u{1FA90}', '\u{1FAAC}'),
+    ('\u{1FAB0}', '\u{1FABA}'),
+    ('\u{1FAC0}', '\u{1FAC5}'),
+    ('\u{1FAD0}', '\u{1FAD9}'),
+    ('\u{1FAE0}', '\u{1FAE7}'),
+    ('\u{1FAF0}', '\u{1FAF6}'),
+];
+
+/// Ranges of chars with the `Extended_Pictographic` property, sorted by code point.
+pub(crate) static EXTENDED_PICTOGRAPHIC: &[(char, char)] = &[
+    ('\u{A9}', '\u{A9}'),
+    ('\u{AE}', '\u{AE}'),
+    ('\u{203C}', '\u{203C}'),
+    ('\u{2049}', '\u{2049}'),
+    ('\u{2122}', '\u{2122}'),
+    ('\u{2139}', '\u{2139}'),
+    ('\u{2194}', '\u{2199}'),
+    ('\u{21A9}', '\u{21AA}'),
+    ('\u{231A}', '\u{231B}'),
+    ('\u{2328}', '\u{2328}'),
+    ('\u{2388}', '\u{2388}'),
+    ('\u{23CF}', '\u{23CF}'),
+    ('\u{23E9}', '\u{23F3}'),
+    ('\u{23F8}', '\u{23FA}'),
+    ('\u{24C2}', '\u{24C2}'),
+    ('\u{25AA}', '\u{25AB}'),
+    ('\u{25B6}', '\u{25B6}'),
+    ('\u{25C0}', '\u{25C0}'),
+    ('\u{25FB}', '\u{25FE}'),
+    ('\u{2600}', '\u{2605}'),
+    ('\u{2607}', '\u{2612}'),
+    ('\u{2614}', '\u{2685}'),
+    ('\u{2690}', '\u{2705}'),
+    ('\u{2708}', '\u{2712}'),
+    ('\u{2714}', '\u{2714}'),
+    ('\u{2716}', '\u{2716}'),
+    ('\u{271D}', '\u{271D}'),
+    ('\u{2721}', '\u{2721}'),
+    ('\u{2728}', '\u{2728}'),
+    ('\u{2733}', '\u{2734}'),
+    ('\u{2744}', '\u{2744}'),
+    ('\u{2747}', '\u{2747}'),
+    ('\u{274C}', '\u{274C}'),
+    ('\u{274E}', '\u{274E}'),
+    ('\u{2753}', '\u{2755}'),
+    ('\u{2757}', '\u{2757}'),
+    ('\u{2763}', '\u{2767}'),
+    ('\u{2795}', '\u{2797}'),
+    ('\u{27A1}', '\u{27A1}'),
+    ('\u{27B0}', '\u{27B0}'),
+    ('\u{27BF}', '\u{27BF}'),
+    ('\u{2934}', '\u{2935}'),
+    ('\u{2B05}', '\u{2B07}'),
+    ('\u{2B1B}', '\u{2B1C}'),
+    ('\u{2B50}', '\u{2B50}'),
+    ('\u{2B55}', '\u{2B55}'),
+    ('\u{3030}', '\u{3030}'),
+    ('\u{303D}', '\u{303D}'),
+    ('\u{3297}', '\u{3297}'),
+    ('\u{3299}', '\u{3299}'),
+    ('\u{1F000}', '\u{1F0FF}'),
+    ('\u{1F10D}', '\u{1F10F}'),
+    ('\u{1F12F}', '\u{1F12F}'),
+    ('\u{1F16C}', '\u{1F171}'),
+    ('\u{1F17E}', '\u{1F17F}'),
+    ('\u{1F18E}', '\u{1F18E}'),
+    ('\u{1F191}', '\u{1F19A}'),
+    ('\u{1F1AD}', '\u{1F1E5}'),
+    ('\u{1F201}', '\u{1F20F}'),
+    ('\u{1F21A}', '\u{1F21A}'),
+    ('\u{1F22F}', '\u{1F22F}'),
+    ('\u{1F232}', '\u{1F23A}'),
+    ('\u{1F23C}', '\u{1F23F}'),
+    ('\u{1F249}', '\u{1F3FA}'),
+    ('\u{1F400}', '\u{1F53D}'),
+    ('\u{1F546}', '\u{1F64F}'),
+    ('\u{1F680}', '\u{1F6FF}'),
+    ('\u{1F774}', '\u{1F77F}'),
+    ('\u{1F7D5}', '\u{1F7FF}'),
+    ('\u{1F80C}', '\u{1F80F}'),
+    ('\u{1F848}', '\u{1F84F}'),
+    ('\u{1F85A}', '\u{1F85F}'),
+    ('\u{1F888}', '\u{1F88F}'),
+    ('\u{1F8AE}', '\u{1F8FF}'),
+    ('\u{1F90C}', '\u{1F93A}'),
+    ('\u{1F93C}', '\u{1F945}'),
+    ('\u{1F947}', '\u{1FAFF}'),
+    ('\u{1FC00}', '\u{1FFFD}'),
+];
+
+/// Ranges of chars with the `ID_Continue` property, sorted by code point.
+pub(crate) static ID_CONTINUE: &[(char, char)] = &[
+    ('\u{30}', '\u{39}'),
+    ('\u{41}', '\u{5A}'),
+    ('\u{5F}', '\u{5F}'),
+    ('\u{61}', '\u{7A}'),
+    ('\u{AA}', '\u{AA}'),
+    ('\u{B5}', '\u{B5}'),
+    ('\u{B7}', '\u{B7}'),
+    ('\u{BA}', '\u{BA}'),
+    ('\u{C0}', '\u{D6}'),
+    ('\u{D8}', '\u{F6}'),
+    ('\u{F8}', '\u{2C1}'),
+    ('\u{2C6}', '\u{2D1}'),
+    ('\u{2E0}', '\u{2E4}'),
+    ('\u{2EC}', '\u{2EC}'),
+    ('\u{2EE}', '\u{2EE}'),
+    ('\u{300}', '\u{374}'),
+    ('\u{376}', '\u{377}'),
+    ('\u{37A}', '\u{37D}'),
+    ('\u{37F}', '\u{37F}'),
+    ('\u{386}', '\u{38A}'),
+    ('\u{38C}', '\u{38C}'),
+    ('\u{38E}', '\u{3A1}'),
+    ('\u{3A3}', '\u{3F5}'),
+    ('\u{3F7}', '\u{481}'),
+    ('\u{483}', '\u{487}'),
+    ('\u{48A}', '\u{52F}'),
+    ('\u{531}', '\u{556}'),
+    ('\u{559}', '\u{559}'),
+    ('\u{560}', '\u{588}'),
+    ('\u{591}', '\u{5BD}'),
+    ('\u{5BF}', '\u{5BF}'),
+    ('\u{5C1}', '\u{5C2}'),
+    ('\u{5C4}', '\u{5C5}'),
+    ('\u{5C7}', '\u{5C7}'),
+    ('\u{5D0}', '\u{5EA}'),
+    ('\u{5EF}', '\u{5F2}'),
+    ('\u{610}', '\u{61A}'),
+    ('\u{620}', '\u{669}'),
+    ('\u{66E}', '\u{6D3}'),
+    ('\u{6D5}', '\u{6DC}'),
+    ('\u{6DF}', '\u{6E8}'),
+    ('\u{6EA}', '\u{6FC}'),
+    ('\u{6FF}', '\u{6FF}'),
+    ('\u{710}', '\u{74A}'),
+    ('\u{74D}', '\u{7B1}'),
+    ('\u{7C0}', '\u{7F5}'),
+    ('\u{7FA}', '\u{7FA}'),
+    ('\u{7FD}', '\u{7FD}'),
+    ('\u{800}', '\u{82D}'),
+    ('\u{840}', '\u{85B}'),
+    ('\u{860}', '\u{86A}'),
+    ('\u{870}', '\u{887}'),
+    ('\u{889}', '\u{88E}'),
+    ('\u{898}', '\u{8E1}'),
+    ('\u{8E3}', '\u{963}'),
+    ('\u{966}', '\u{96F}'),
+    ('\u{971}', '\u{983}'),
+    ('\u{985}', '\u{98C}'),
+    ('\u{98F}', '\u{990}'),
+    ('\u{993}', '\u{9A8}'),
+    ('\u{9AA}', '\u{9B0}'),
+    ('\u{9B2}', '\u{9B2}'),
+    ('\u{9B6}', '\u{9B9}'),
+    ('\u{9BC}', '\u{9C4}'),
+    ('\u{9C7}', '\u{9C8}'),
+    ('\u{9CB}', '\u{9CE}'),
+    ('\u{9D7}', '\u{9D7}'),
+    ('\u{9DC}', '\u{9DD}'),
+    ('\u{9DF}', '\u{9E3}'),
+    ('\u{9E6}', '\u{9F1}'),
+    ('\u{9FC}', '\u{9FC}'),
+    ('\u{9FE}', '\u{9FE}'),
+    ('\u{A01}', '\u{A03}'),
+    ('\u{A05}', '\u{A0A}'),
+    ('\u{A0F}', '\u{A10}'),
+    ('\u{A13}', '\u{A28}'),
+    ('\u{A2A}', '\u{A30}'),
+    ('\u{A32}', '\u{A33}'),
+    ('\u{A35}', '\u{A36}'),
+    ('\u{A38}', '\u{A39}'),
+    ('\u{A3C}', '\u{A3C}'),
+    ('\u{A3E}', '\u{A42}'),
+    ('\u{A47}', '\u{A48}'),
+    ('\u{A4B}', '\u{A4D}'),
+    ('\u{A51}', '\u{A51}'),
+    ('\u{A59}', '\u{A5C}'),
+    ('\u{A5E}', '\u{A5E}'),
+    ('\u{A66}', '\u{A75}'),
+    ('\u{A81}', '\u{A83}'),
+    ('\u{A85}', '\u{A8D}'),
+    ('\u{A8F}', '\u{A91}'),
+    ('\u{A93}', '\u{AA8}'),
+    ('\u{AAA}', '\u{AB0}'),
+    ('\u{AB2}', '\u{AB3}'),
+    ('\u{AB5}', '\u{AB9}'),
+    ('\u{ABC}', '\u{AC5}'),
+    ('\u{AC7}', '\u{AC9}'),
+    ('\u{ACB}', '\u{ACD}'),
+    ('\u{AD0}', '\u{AD0}'),
+    ('\u{AE0}', '\u{AE3}'),
+    ('\u{AE6}', '\u{AEF}'),
+    ('\u{AF9}', '\u{AFF}'),
+    ('\u{B01}', '\u{B03}'),
+    ('\u{B05}', '\u{B0C}'),
+    ('\u{B0F}', '\u{B10}'),
+    ('\u{B13}', '\u{B28}'),
+    ('\u{B2A}', '\u{B30}'),
+    ('\u{B32}', '\u{B33}'),
+    ('\u{B35}', '\u{B39}'),
+    ('\u{B3C}', '\u{B44}'),
+    ('\u{B47}', '\u{B48}'),
+    ('\u{B4B}', '\u{B4D}'),
+    ('\u{B55}', '\u{B57}'),
+    ('\u{B5C}', '\u{B5D}'),
+    ('\u{B5F}', '\u{B63}'),
+    ('\u{B66}', '\u{B6F}'),
+    ('\u{B71}', '\u{B71}'),
+    ('\u{B82}', '\u{B83}'),
+    ('\u{B85}', '\u{B8A}'),
+    ('\u{B8E}', '\u{B90}'),
+    ('\u{B92}', '\u{B95}'),
+    ('\u{B99}', '\u{B9A}'),
+    ('\u{B9C}', '\u{B9C}'),
+    ('\u{B9E}', '\u{B9F}'),
+    ('\u{BA3}', '\u{BA4}'),
+    ('\u{BA8}', '\u{BAA}'),
+    ('\u{BAE}', '\u{BB9}'),
+    ('\u{BBE}', '\u{BC2}'),
+    ('\u{BC6}', '\u{BC8}'),
+    ('\u{BCA}', '\u{BCD}'),
+    ('\u{BD0}', '\u{BD0}'),
+    ('\u{BD7}', '\u{BD7}'),
+    ('\u{BE6}', '\u{BEF}'),
+    ('\u{C00}', '\u{C0C}'),
+    ('\u{C0E}', '\u{C10}'),
+    ('\u{C12}', '\u{C28}'),
+    ('\u{C2A}', '\u{C39}'),
+    ('\u{C3C}', '\u{C44}'),
+    ('\u{C46}', '\u{C48}'),
+    ('\u{C4A}', '\u{C4D}'),
+    ('\u{C55}', '\u{C56}'),
+    ('\u{C58}', '\u{C5A}'),
+    ('\u{C5D}', '\u{C5D}'),
+    ('\u{C60}', '\u{C63}'),
+    ('\u{C66}', '\u{C6F}'),
+    ('\u{C80}', '\u{C83}'),
+    ('\u{C85}', '\u{C8C}'),
+    ('\u{C8E}', '\u{C90}'),
+    ('\u{C92}', '\u{CA8}'),
+    ('\u{CAA}', '\u{CB3}'),
+    ('\u{CB5}', '\u{CB9}'),
+    ('\u{CBC}', '\u{CC4}'),
+    ('\u{CC6}', '\u{CC8}'),
+    ('\u{CCA}', '\u{CCD}'),
+    ('\u{CD5}', '\u{CD6}'),
+    ('\u{CDD}', '\u{CDE}'),
+    ('\u{CE0}', '\u{CE3}'),
+    ('\u{CE6}', '\u{CEF}'),
+    ('\u{CF1}', '\u{CF2}'),
+    ('\u{D00}', '\u{D0C}'),
+    ('\u{D0E}', '\u{D10}'),
+    ('\u{D12}', '\u{D44}'),
+    ('\u{D46}', '\u{D48}'),
+    ('\u{D4A}', '\u{D4E}'),
+    ('\u{D54}', '\u{D57}'),
+    ('\u{D5F}', '\u{D63}'),
+    ('\u{D66}', '\u{D6F}'),
+    ('\u{D7A}', '\u{D7F}'),
+    ('\u{D81}', '\u{D83}'),
+    ('\u{D85}', '\u{D96}'),
+    ('\u{D9A}', '\u{DB1}'),
+    ('\u{DB3}', '\u{DBB}'),
+    ('\u{DBD}', '\u{DBD}'),
+    ('\u{DC0}', '\u{DC6}'),
+    ('\u{DCA}', '\u{DCA}'),
+    ('\u{DCF}', '\u{DD4}'),
+    ('\u{DD6}', '\u{DD6}'),
+    ('\u{DD8}', '\u{DDF}'),
+    ('\u{DE6}', '\u{DEF}'),
+    ('\u{DF2}', '\u{DF3}'),
+    ('\u{E01}', '\u{E3A}'),
+    ('\u{E40}', '\u{E4E}'),
+    ('\u{E50}', '\u{E59}'),
+    ('\u{E81}', '\u{E82}'),
+    ('\u{E84}', '\u{E84}'),
+    ('\u{E86}', '\u{E8A}'),
+    ('\u{E8C}', '\u{EA3}'),
+    ('\u{EA5}', '\u{EA5}'),
+    ('\u{EA7}', '\u{EBD}'),
+    ('\u{EC0}', '\u{EC4}'),
+    ('\u{EC6}', '\u{EC6}'),
+    ('\u{EC8}', '\u{ECD}'),
+    ('\u{ED0}', '\u{ED9}'),
+    ('\u{EDC}', '\u{EDF}'),
+    ('\u{F00}', '\u{F00}'),
+    ('\u{F18}', '\u{F19}'),
+    ('\u{F20}', '\u{F29}'),
+    ('\u{F35}', '\u{F35}'),
+    ('\u{F37}', '\u{F37}'),
+    ('\u{F39}', '\u{F39}'),
+    ('\u{F3E}', '\u{F47}'),
+    ('\u{F49}', '\u{F6C}'),
+    ('\u{F71}', '\u{F84}'),
+    ('\u{F86}', '\u{F97}'),
+    ('\u{F99}', '\u{FBC}'),
+    ('\u{FC6}', '\u{FC6}'),
+    ('\u{1000}', '\u{1049}'),
+    ('\u{1050}', '\u{109D}'),
+    ('\u{10A0}', '\u{10C5}'),
+    ('\u{10C7}', '\u{10C7}'),
+    ('\u{10CD}', '\u{10CD}'),
+    ('\u{10D0}', '\u{10FA}'),
+    ('\u{10FC}', '\u{1248}'),
+    ('\u{124A}', '\u{124D}'),
+    ('\u{1250}', '\u{1256}'),
+    ('\u{1258}', '\u{1258}'),
+    ('\u{125A}', '\u{125D}'),
+    ('\u{1260}', '\u{1288}'),
+    ('\u{128A}', '\u{128D}'),
+    ('\u{1290}', '\u{12B0}'),
+    ('\u{12B2}', '\u{12B5}'),
+    ('\u{12B8}', '\u{12BE}'),
+    ('\u{12C0}', '\u{12C0}'),
+    ('\u{12C2}', '\u{12C5}'),
+    ('\u{12C8}', '\u{12D6}'),
+    ('\u{12D8}', '\u{1310}'),
+    ('\u{1312}', '\u{1315}'),
+    ('\u{1318}', '\u{135A}'),
+    ('\u{135D}', '\u{135F}'),
+    ('\u{1369}', '\u{1371}'),
+    ('\u{1380}', '\u{138F}'),
+    ('\u{13A0}', '\u{13F5}'),
+    ('\u{13F8}', '\u{13FD}'),
+    ('\u{1401}', '\u{166C}'),
+    ('\u{166F}', '\u{167F}'),
+    ('\u{1681}', '\u{169A}'),
+    ('\u{16A0}', '\u{16EA}'),
+    ('\u{16EE}', '\u{16F8}'),
+    ('\u{1700}', '\u{1715}'),
+    ('\u{171F}', '\u{1734}'),
+    ('\u{1740}', '\u{1753}'),
+    ('\u{1760}', '\u{176C}'),
+    ('\u{176E}', '\u{1770}'),
+    ('\u{1772}', '\u{1773}'),
+    ('\u{1780}', '\u{17D3}'),
+    ('\u{17D7}', '\u{17D7}'),
+    ('\u{17DC}', '\u{17DD}'),
+    ('\u{17E0}', '\u{17E9}'),
+    ('\u{180B}', '\u{180D}'),
+    ('\u{180F}', '\u{1819}'),
+    ('\u{1820}', '\u{1878}'),
+    ('\u{1880}', '\u{18AA}'),
+    ('\u{18B0}', '\u{18F5}'),
+    ('\u{1900}', '\u{191E}'),
+    ('\u{1920}', '\u{192B}'),
+    ('\u{1930}', '\u{193B}'),
+    ('\u{1946}', '\u{196D}'),
+    ('\u{1970}', '\u{1974}'),
+    ('\u{1980}', '\u{19AB}'),
+    ('\u{19B0}', '\u{19C9}'),
+    ('\u{19D0}', '\u{19DA}'),
+    ('\u{1A00}', '\u{1A1B}'),
+    ('\u{1A20}', '\u{1A5E}'),
+    ('\u{1A60}', '\u{1A7C}'),
+    ('\u{1A7F}', '\u{1A89}'),
+    ('\u{1A90}', '\u{1A99}'),
+    ('\u{1AA7}', '\u{1AA7}'),
+    ('\u{1AB0}', '\u{1ABD}'),
+    ('\u{1ABF}', '\u{1ACE}'),
+    ('\u{1B00}', '\u{1B4C}'),
+    ('\u{1B50}', '\u{1B59}'),
+    ('\u{1B6B}', '\u{1B73}'),
+    ('\u{1B80}', '\u{1BF3}'),
+    ('\u{1C00}', '\u{1C37}'),
+    ('\u{1C40}', '\u{1C49}'),
+    ('\u{1C4D}', '\u{1C7D}'),
+    ('\u{1C80}', '\u{1C88}'),
+    ('\u{1C90}', '\u{1CBA}'),
+    ('\u{1CBD}', '\u{1CBF}'),
+    ('\u{1CD0}', '\u{1CD2}'),
+    ('\u{1CD4}', '\u{1CFA}'),
+    ('\u{1D00}', '\u{1F15}'),
+    ('\u{1F18}', '\u{1F1D}'),
+    ('\u{1F20}', '\u{1F45}'),
+    ('\u{1F48}', '\u{1F4D}'),
+    ('\u{1F50}', '\u{1F57}'),
+    ('\u{1F59}', '\u{1F59}'),
+    ('\u{1F5B}', '\u{1F5B}'),
+    ('\u{1F5D}', '\u{1F5D}'),
+    ('\u{1F5F}', '\u{1F7D}'),
+    ('\u{1F80}', '\u{1FB4}'),
+    ('\u{1FB6}', '\u{1FBC}'),
+    ('\u{1FBE}', '\u{1FBE}'),
+    ('\u{1FC2}', '\u{1FC4}'),
+    ('\u{1FC6}', '\u{1FCC}'),
+    ('\u{1FD0}', '\u{1FD3}'),
+    ('\u{1FD6}', '\u{1FDB}'),
+    ('\u{1FE0}', '\u{1FEC}'),
+    ('\u{1FF2}', '\u{1FF4}'),
+    ('\u{1FF6}', '\u{1FFC}'),
+    ('\u{203F}', '\u{2040}'),
+    ('\u{2054}', '\u{2054}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207F}', '\u{207F}'),
+    ('\u{2090}', '\u{209C}'),
+    ('\u{20D0}', '\u{20DC}'),
+    ('\u{20E1}', '\u{20E1}'),
+    ('\u{20E5}', '\u{20F0}'),
+    ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'),
+    ('\u{210A}', '\u{2113}'),
+    ('\u{2115}', '\u{2115}'),
+    ('\u{2118}', '\u{211D}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{2128}', '\u{2128}'),
+    ('\u{212A}', '\u{2139}'),
+    ('\u{213C}', '\u{213F}'),
+    ('\u{2145}', '\u{2149}'),
+    ('\u{214E}', '\u{214E}'),
+    ('\u{2160}', '\u{2188}'),
+    ('\u{2C00}', '\u{2CE4}'),
+    ('\u{2CEB}', '\u{2CF3}'),
+    ('\u{2D00}', '\u{2D25}'),
+    ('\u{2D27}', '\u{2D27}'),
+    ('\u{2D2D}', '\u{2D2D}'),
+    ('\u{2D30}', '\u{2D67}'),
+    ('\u{2D6F}', '\u{2D6F}'),
+    ('\u{2D7F}', '\u{2D96}'),
+    ('\u{2DA0}', '\u{2DA6}'),
+    ('\u{2DA8}', '\u{2DAE}'),
+    ('\u{2DB0}', '\u{2DB6}'),
+    ('\u{2DB8}', '\u{2DBE}'),
+    ('\u{2DC0}', '\u{2DC6}'),
+    ('\u{2DC8}', '\u{2DCE}'),
+    ('\u{2DD0}', '\u{2DD6}'),
+    ('\u{2DD8}', '\u{2DDE}'),
+    ('\u{2DE0}', '\u{2DFF}'),
+    ('\u{3005}', '\u{3007}'),
+    ('\u{3021}', '\u{302F}'),
+    ('\u{3031}', '\u{3035}'),
+    ('\u{3038}', '\u{303C}'),
+    ('\u{3041}', '\u{3096}'),
+    ('\u{3099}', '\u{309F}'),
+    ('\u{30A1}', '\u{30FA}'),
+    ('\u{30FC}', '\u{30FF}'),
+    ('\u{3105}', '\u{312F}'),
+    ('\u{3131}', '\u{318E}'),
+    ('\u{31A0}', '\u{31BF}'),
+    ('\u{31F0}', '\u{31FF}'),
+    ('\u{3400}', '\u{4DBF}'),
+    ('\u{4E00}', '\u{A48C}'),
+    ('\u{A4D0}', '\u{A4FD}'),
+    ('\u{A500}', '\u{A60C}'),
+    ('\u{A610}', '\u{A62B}'),
+    ('\u{A640}', '\u{A66F}'),
+    ('\u{A674}', '\u{A67D}'),
+    ('\u{A67F}', '\u{A6F1}'),
+    ('\u{A717}', '\u{A71F}'),
+    ('\u{A722}', '\u{A788}'),
+    ('\u{A78B}', '\u{A7CA}'),
+    ('\u{A7D0}', '\u{A7D1}'),
+    ('\u{A7D3}', '\u{A7D3}'),
+    ('\u{A7D5}', '\u{A7D9}'),
+    ('\u{A7F2}', '\u{A827}'),
+    ('\u{A82C}', '\u{A82C}'),
+    ('\u{A840}', '\u{A873}'),
+    ('\u{A880}', '\u{A8C5}'),
+    ('\u{A8D0}', '\u{A8D9}'),
+    ('\u{A8E0}', '\u{A8F7}'),
+    ('\u{A8FB}', '\u{A8FB}'),
+    ('\u{A8FD}', '\u{A92D}'),
+    ('\u{A930}', '\u{A953}'),
+    ('\u{A960}', '\u{A97C}'),
+    ('\u{A980}', '\u{A9C0}'),
+    ('\u{A9CF}', '\u{A9D9}'),
+    ('\u{A9E0}', '\u{A9FE}'),
+    ('\u{AA00}', '\u{AA36}'),
+    ('\u{AA40}', '\u{AA4D}'),
+    ('\u{AA50}', '\u{AA59}'),
+    ('\u{AA60}', '\u{AA76}'),
+    ('\u{AA7A}', '\u{AAC2}'),
+    ('\u{AADB}', '\u{AADD}'),
+    ('\u{AAE0}', '\u{AAEF}'),
+    ('\u{AAF2}', '\u{AAF6}'),
+    ('\u{AB01}', '\u{AB06}'),
+    ('\u{AB09}', '\u{AB0E}'),
+    ('\u{AB11}', '\u{AB16}'),
+    ('\u{AB20}', '\u{AB26}'),
+    ('\u{AB28}', '\u{AB2E}'),
+    ('\u{AB30}', '\u{AB5A}'),
+    ('\u{AB5C}', '\u{AB69}'),
+    ('\u{AB70}', '\u{ABEA}'),
+    ('\u{ABEC}', '\u{ABED}'),
+    ('\u{ABF0}', '\u{ABF9}'),
+    ('\u{AC00}', '\u{D7A3}'),
+    ('\u{D7B0}', '\u{D7C6}'),
+    ('\u{D7CB}', '\u{D7FB}'),
+    ('\u{F900}', '\u{FA6D}'),
+    ('\u{FA70}', '\u{FAD9}'),
+    ('\u{FB00}', '\u{FB06}'),
+    ('\u{FB13}', '\u{FB17}'),
+    ('\u{FB1D}', '\u{FB28}'),
+    ('\u{FB2A}', '\u{FB36}'),
+    ('\u{FB38}', '\u{FB3C}'),
+    ('\u{FB3E}', '\u{FB3E}'),
+    ('\u{FB40}', '\u{FB41}'),
+    ('\u{FB43}', '\u{FB44}'),
+    ('\u{FB46}', '\u{FBB1}'),
+    ('\u{FBD3}', '\u{FD3D}'),
+    ('\u{FD50}', '\u{FD8F}'),
+    ('\u{FD92}', '\u{FDC7}'),
+    ('\u{FDF0}', '\u{FDFB}'),
+    ('\u{FE00}', '\u{FE0F}'),
+    ('\u{FE20}', '\u{FE2F}'),
+    ('\u{FE33}', '\u{FE34}'),
+    ('\u{FE4D}', '\u{FE4F}'),
+    ('\u{FE70}', '\u{FE74}'),
+    ('\u{FE76}', '\u{FEFC}'),
+    ('\u{FF10}', '\u{FF19}'),
+    ('\u{FF21}', '\u{FF3A}'),
+    ('\u{FF3F}', '\u{FF3F}'),
+    ('\u{FF41}', '\u{FF5A}'),
+    ('\u{FF66}', '\u{FFBE}'),
+    ('\u{FFC2}', '\u{FFC7}'),
+    ('\u{FFCA}', '\u{FFCF}'),
+    ('\u{FFD2}', '\u{FFD7}'),
+    ('\u{FFDA}', '\u{FFDC}'),
+    ('\u{10000}', '\u{1000B}'),
+    ('\u{1000D}', '\u{10026}'),
+    ('\u{10028}', '\u{1003A}'),
+    ('\u{1003C}', '\u{1003D}'),
+    ('\u{1003F}', '\u{1004D}'),
+    ('\u{10050}', '\u{1005D}'),
+    ('\u{10080}', '\u{100FA}'),
+    ('\u{10140}', '\u{10174}'),
+    ('\u{101FD}', '\u{101FD}'),
+    ('\u{10280}', '\u{1029C}'),
+    ('\u{102A0}', '\u{102D0}'),
+    ('\u{102E0}', '\u{102E0}'),
+    ('\u{10300}', '\u{1031F}'),
+    ('\u{1032D}', '\u{1034A}'),
+    ('\u{10350}', '\u{1037A}'),
+    ('\u{10380}', '\u{1039D}'),
+    ('\u{103A0}', '\u{103C3}'),
+    ('\u{103C8}', '\u{103CF}'),
+    ('\u{103D1}', '\u{103D5}'),
+    ('\u{10400}', '\u{1049D}'),
+    ('\u{104A0}', '\u{104A9}'),
+    ('\u{104B0}', '\u{104D3}'),
+    ('\u{104D8}', '\u{104FB}'),
+    ('\u{10500}', '\u{10527}'),
+    ('\u{10530}', '\u{10563}'),
+    ('\u{10570}', '\u{1057A}'),
+    ('\u{1057C}', '\u{1058A}'),
+    ('\u{1058C}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'),
+    ('\u{10597}', '\u{105A1}'),
+    ('\u{105A3}', '\u{105B1}'),
+    ('\u{105B3}', '\u{105B9}'),
+    ('\u{105BB}', '\u{105BC}'),
+    ('\u{10600}', '\u{10736}'),
+    ('\u{10740}', '\u{10755}'),
+    ('\u{10760}', '\u{10767}'),
+    ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107B0}'),
+    ('\u{107B2}', '\u{107BA}'),
+    ('\u{10800}', '\u{10805}'),
+    ('\u{10808}', '\u{10808}'),
+    ('\u{1080A}', '\u{10835}'),
+    ('\u{10837}', '\u{10838}'),
+    ('\u{1083C}', '\u{1083C}'),
+    ('\u{1083F}', '\u{10855}'),
+    ('\u{10860}', '\u{10876}'),
+    ('\u{10880}', '\u{1089E}'),
+    ('\u{108E0}', '\u{108F2}'),
+    ('\u{108F4}', '\u{108F5}'),
+    ('\u{10900}', '\u{10915}'),
+    ('\u{10920}', '\u{10939}'),
+    ('\u{10980}', '\u{109B7}'),
+    ('\u{109BE}', '\u{109BF}'),
+    ('\u{10A00}', '\u{10A03}'),
+    ('\u{10A05}', '\u{10A06}'),
+    ('\u{10A0C}', '\u{10A13}'),
+    ('\u{10A15}', '\u{10A17}'),
+    ('\u{10A19}', '\u{10A35}'),
+    ('\u{10A38}', '\u{10A3A}'),
+    ('\u{10A3F}', '\u{10A3F}'),
+    ('\u{10A60}', '\u{10A7C}'),
+    ('\u{10A80}', '\u{10A9C}'),
+    ('\u{10AC0}', '\u{10AC7}'),
+    ('\u{10AC9}', '\u{10AE6}'),
+    ('\u{10B00}', '\u{10B35}'),
+    ('\u{10B40}', '\u{10B55}'),
+    ('\u{10B60}', '\u{10B72}'),
+    ('\u{10B80}', '\u{10B91}'),
+    ('\u{10C00}', '\u{10C48}'),
+    ('\u{10C80}', '\u{10CB2}'),
+    ('\u{10CC0}', '\u{10CF2}'),
+    ('\u{10D00}', '\u{10D27}'),
+    ('\u{10D30}', '\u{10D39}'),
+    ('\u{10E80}', '\u{10EA9}'),
+    ('\u{10EAB}', '\u{10EAC}'),
+    ('\u{10EB0}', '\u{10EB1}'),
+    ('\u{10F00}', '\u{10F1C}'),
+    ('\u{10F27}', '\u{10F27}'),
+    ('\u{10F30}', '\u{10F50}'),
+    ('\u{10F70}', '\u{10F85}'),
+    ('\u{10FB0}', '\u{10FC4}'),
+    ('\u{10FE0}', '\u{10FF6}'),
+    ('\u{11000}', '\u{11046}'),
+    ('\u{11066}', '\u{11075}'),
+    ('\u{1107F}', '\u{110BA}'),
+    ('\u{110C2}', '\u{110C2}'),
+    ('\u{110D0}', '\u{110E8}'),
+    ('\u{110F0}', '\u{110F9}'),
+    ('\u{11100}', '\u{11134}'),
+    ('\u{11136}', '\u{1113F}'),
+    ('\u{11144}', '\u{11147}'),
+    ('\u{11150}', '\u{11173}'),
+    ('\u{11176}', '\u{11176}'),
+    ('\u{11180}', '\u{111C4}'),
+    ('\u{111C9}', '\u{111CC}'),
+    ('\u{111CE}', '\u{111DA}'),
+    ('\u{111DC}', '\u{111DC}'),
+    ('\u{11200}', '\u{11211}'),
+    ('\u{11213}', '\u{11237}'),
+    ('\u{1123E}', '\u{1123E}'),
+    ('\u{11280}', '\u{11286}'),
+    ('\u{11288}', '\u{11288}'),
+    ('\u{1128A}', '\u{1128D}'),
+    ('\u{1128F}', '\u{1129D}'),
+    ('\u{1129F}', '\u{112A8}'),
+    ('\u{112B0}', '\u{112EA}'),
+    ('\u{112F0}', '\u{112F9}'),
+    ('\u{11300}', '\u{11303}'),
+    ('\u{11305}', '\u{1130C}'),
+    ('\u{1130F}', '\u{11310}'),
+    ('\u{11313}', '\u{11328}'),
+    ('\u{1132A}', '\u{11330}'),
+    ('\u{11332}', '\u{11333}'),
+    ('\u{11335}', '\u{11339}'),
+    ('\u{1133B}', '\u{11344}'),
+    ('\u{11347}', '\u{11348}'),
+    ('\u{1134B}', '\u{1134D}'),
+    ('\u{11350}', '\u{11350}'),
+    ('\u{11357}', '\u{11357}'),
+    ('\u{1135D}', '\u{11363}'),
+    ('\u{11366}', '\u{1136C}'),
+    ('\u{11370}', '\u{11374}'),
+    ('\u{11400}', '\u{1144A}'),
+    ('\u{11450}', '\u{11459}'),
+    ('\u{1145E}', '\u{11461}'),
+    ('\u{11480}', '\u{114C5}'),
+    ('\u{114C7}', '\u{114C7}'),
+    ('\u{114D0}', '\u{114D9}'),
+    ('\u{11580}', '\u{115B5}'),
+    ('\u{115B8}', '\u{115C0}'),
+    ('\u{115D8}', '\u{115DD}'),
+    ('\u{11600}', '\u{11640}'),
+    ('\u{11644}', '\u{11644}'),
+    ('\u{11650}', '\u{11659}'),
+    ('\u{11680}', '\u{116B8}'),
+    ('\u{116C0}', '\u{116C9}'),
+    ('\u{11700}', '\u{1171A}'),
+    ('\u{1171D}', '\u{1172B}'),
+    ('\u{11730}', '\u{11739}'),
+    ('\u{11740}', '\u{11746}'),
+    ('\u{11800}', '\u{1183A}'),
+    ('\u{118A0}', '\u{118E9}'),
+    ('\u{118FF}', '\u{11906}'),
+    ('\u{11909}', '\u{11909}'),
+    ('\u{1190C}', '\u{11913}'),
+    ('\u{11915}', '\u{11916}'),
+    ('\u{11918}', '\u{11935}'),
+    ('\u{11937}', '\u{11938}'),
+    ('\u{1193B}', '\u{11943}'),
+    ('\u{11950}', '\u{11959}'),
+    ('\u{119A0}', '\u{119A7}'),
+    ('\u{119AA}', '\u{119D7}'),
+    ('\u{119DA}', '\u{119E1}'),
+    ('\u{119E3}', '\u{119E4}'),
+    ('\u{11A00}', '\u{11A3E}'),
+    ('\u{11A47}', '\u{11A47}'),
+    ('\u{11A50}', '\u{11A99}'),
+    ('\u{11A9D}', '\u{11A9D}'),
+    ('\u{11AB0}', '\u{11AF8}'),
+    ('\u{11C00}', '\u{11C08}'),
+    ('\u{11C0A}', '\u{11C36}'),
+    ('\u{11C38}', '\u{11C40}'),
+    ('\u{11C50}', '\u{11C59}'),
+    ('\u{11C72}', '\u{11C8F}'),
+    ('\u{11C92}', '\u{11CA7}'),
+    ('\u{11CA9}', '\u{11CB6}'),
+    ('\u{11D00}', '\u{11D06}'),
+    ('\u{11D08}', '\u{11D09}'),
+    ('\u{11D0B}', '\u{11D36}'),
+    ('\u{11D3A}', '\u{11D3A}'),
+    ('\u{11D3C}', '\u{11D3D}'),
+    ('\u{11D3F}', '\u{11D47}'),
+    ('\u{11D50}', '\u{11D59}'),
+    ('\u{11D60}', '\u{11D65}'),
+    ('\u{11D67}', '\u{11D68}'),
+    ('\u{11D6A}', '\u{11D8E}'),
+    ('\u{11D90}', '\u{11D91}'),
+    ('\u{11D93}', '\u{11D98}'),
+    ('\u{11DA0}', '\u{11DA9}'),
+    ('\u{11EE0}', '\u{11EF6}'),
+    ('\u{11FB0}', '\u{11FB0}'),
+    ('\u{12000}', '\u{12399}'),
+    ('\u{12400}', '\u{1246E}'),
+    ('\u{12480}', '\u{12543}'),
+    ('\u{12F90}', '\u{12FF0}'),
+    ('\u{13000}', '\u{1342E}'),
+    ('\u{14400}', '\u{14646}'),
+    ('\u{16800}', '\u{16A38}'),
+    ('\u{16A40}', '\u{16A5E}'),
+    ('\u{16A60}', '\u{16A69}'),
+    ('\u{16A70}', '\u{16ABE}'),
+    ('\u{16AC0}', '\u{16AC9}'),
+    ('\u{16AD0}', '\u{16AED}'),
+    ('\u{16AF0}', '\u{16AF4}'),
+    ('\u{16B00}', '\u{16B36}'),
+    ('\u{16B40}', '\u{16B43}'),
+    ('\u{16B50}', '\u{16B59}'),
+    ('\u{16B63}', '\u{16B77}'),
+    ('\u{16B7D}', '\u{16B8F}'),
+    ('\u{16E40}', '\u{16E7F}'),
+    ('\u{16F00}', '\u{16F4A}'),
+    ('\u{16F4F}', '\u{16F87}'),
+    ('\u{16F8F}', '\u{16F9F}'),
+    ('\u{16FE0}', '\u{16FE1}'),
+    ('\u{16FE3}', '\u{16FE4}'),
+    ('\u{16FF0}', '\u{16FF1}'),
+    ('\u{17000}', '\u{187F7}'),
+    ('\u{18800}', '\u{18CD5}'),
+    ('\u{18D00}', '\u{18D08}'),
+    ('\u{1AFF0}', '\u{1AFF3}'),
+    ('\u{1AFF5}', '\u{1AFFB}'),
+    ('\u{1AFFD}', '\u{1AFFE}'),
+    ('\u{1B000}', '\u{1B122}'),
+    ('\u{1B150}', '\u{1B152}'),
+    ('\u{1B164}', '\u{1B167}'),
+    ('\u{1B170}', '\u{1B2FB}'),
+    ('\u{1BC00}', '\u{1BC6A}'),
+    ('\u{1BC70}', '\u{1BC7C}'),
+    ('\u{1BC80}', '\u{1BC88}'),
+    ('\u{1BC90}', '\u{1BC99}'),
+    ('\u{1BC9D}', '\u{1BC9E}'),
+    ('\u{1CF00}', '\u{1CF2D}'),
+    ('\u{1CF30}', '\u{1CF46}'),
+    ('\u{1D165}', '\u{1D169}'),
+    ('\u{1D16D}', '\u{1D172}'),
+    ('\u{1D17B}', '\u{1D182}'),
+    ('\u{1D185}', '\u{1D18B}'),
+    ('\u{1D1AA}', '\u{1D1AD}'),
+    ('\u{1D242}', '\u{1D244}'),
+    ('\u{1D400}', '\u{1D454}'),
+    ('\u{1D456}', '\u{1D49C}'),
+    ('\u{1D49E}', '\u{1D49F}'),
+    ('\u{1D4A2}', '\u{1D4A2}'),
+    ('\u{1D4A5}', '\u{1D4A6}'),
+    ('\u{1D4A9}', '\u{1D4AC}'),
+    ('\u{1D4AE}', '\u{1D4B9}'),
+    ('\u{1D4BB}', '\u{1D4BB}'),
+    ('\u{1D4BD}', '\u{1D4C3}'),
+    ('\u{1D4C5}', '\u{1D505}'),
+    ('\u{1D507}', '\u{1D50A}'),
+    ('\u{1D50D}', '\u{1D514}'),
+    ('\u{1D516}', '\u{1D51C}'),
+    ('\u{1D51E}', '\u{1D539}'),
+    ('\u{1D53B}', '\u{1D53E}'),
+    ('\u{1D540}', '\u{1D544}'),
+    ('\u{1D546}', '\u{1D546}'),
+    ('\u{1D54A}', '\u{1D550}'),
+    ('\u{1D552}', '\u{1D6A5}'),
+    ('\u{1D6A8}', '\u{1D6C0}'),
+    ('\u{1D6C2}', '\u{1D6DA}'),
+    ('\u{1D6DC}', '\u{1D6FA}'),
+    ('\u{1D6FC}', '\u{1D714}'),
+    ('\u{1D716}', '\u{1D734}'),
+    ('\u{1D736}', '\u{1D74E}'),
+    ('\u{1D750}', '\u{1D76E}'),
+    ('\u{1D770}', '\u{1D788}'),
+    ('\u{1D78A}', '\u{1D7A8}'),
+    ('\u{1D7AA}', '\u{1D7C2}'),
+    ('\u{1D7C4}', '\u{1D7CB}'),
+    ('\u{1D7CE}', '\u{1D7FF}'),
+    ('\u{1DA00}', '\u{1DA36}'),
+    ('\u{1DA3B}', '\u{1DA6C}'),
+    ('\u{1DA75}', '\u{1DA75}'),
+    ('\u{1DA84}', '\u{1DA84}'),
+    ('\u{1DA9B}', '\u{1DA9F}'),
+    ('\u{1DAA1}', '\u{1DAAF}'),
+    ('\u{1DF00}', '\u{1DF1E}'),
+    ('\u{1E000}', '\u{1E006}'),
+    ('\u{1E008}', '\u{1E018}'),
+    ('\u{1E01B}', '\u{1E021}'),
+    ('\u{1E023}', '\u{1E024}'),
+    ('\u{1E026}', '\u{1E02A}'),
+    ('\u{1E100}', '\u{1E12C}'),
+    ('\u{1E130}', '\u{1E13D}'),
+    ('\u{1E140}', '\u{1E149}'),
+    ('\u{1E14E}', '\u{1E14E}'),
+    ('\u{1E290}', '\u{1E2AE}'),
+    ('\u{1E2C0}', '\u{1E2F9}'),
+    ('\u{1E7E0}', '\u{1E7E6}'),
+    ('\u{1E7E8}', '\u{1E7EB}'),
+    ('\u{1E7ED}', '\u{1E7EE}'),
+    ('\u{1E7F0}', '\u{1E7FE}'),
+    ('\u{1E800}', '\u{1E8C4}'),
+    ('\u{1E8D0}', '\u{1E8D6}'),
+    ('\u{1E900}', '\u{1E94B}'),
+    ('\u{1E950}', '\u{1E959}'),
+    ('\u{1EE00}', '\u{1EE03}'),
+    ('\u{1EE05}', '\u{1EE1F}'),
+    ('\u{1EE21}', '\u{1EE22}'),
+    ('\u{1EE24}', '\u{1EE24}'),
+    ('\u{1EE27}', '\u{1EE27}'),
+    ('\u{1EE29}', '\u{1EE32}'),
+    ('\u{1EE34}', '\u{1EE37}'),
+    ('\u{1EE39}', '\u{1EE39}'),
+    ('\u{1EE3B}', '\u{1EE3B}'),
+    ('\u{1EE42}', '\u{1EE42}'),
+    ('\u{1EE47}', '\u{1EE47}'),
+    ('\u{1EE49}', '\u{1EE49}'),
+    ('\u{1EE4B}', '\u{1EE4B}'),
+    ('\u{1EE4D}', '\u{1EE4F}'),
+    ('\u{1EE51}', '\u{1EE52}'),
+    ('\u{1EE54}', '\u{1EE54}'),
+    ('\u{1EE57}', '\u{1EE57}'),
+    ('\u{1EE59}', '\u{1EE59}'),
+    ('\u{1EE5B}', '\u{1EE5B}'),
+    ('\u{1EE5D}', '\u{1EE5D}'),
+    ('\u{1EE5F}', '\u{1EE5F}'),
+    ('\u{1EE61}', '\u{1EE62}'),
+    ('\u{1EE64}', '\u{1EE64}'),
+    ('\u{1EE67}', '\u{1EE6A}'),
+    ('\u{1EE6C}', '\u{1EE72}'),
+    ('\u{1EE74}', '\u{1EE77}'),
+    ('\u{1EE79}', '\u{1EE7C}'),
+    ('\u{1EE7E}', '\u{1EE7E}'),
+    ('\u{1EE80}', '\u{1EE89}'),
+    ('\u{1EE8B}', '\u{1EE9B}'),
+    ('\u{1EEA1}', '\u{1EEA3}'),
+    ('\u{1EEA5}', '\u{1EEA9}'),
+    ('\u{1EEAB}', '\u{1EEBB}'),
+    ('\u{1FBF0}', '\u{1FBF9}'),
+    ('\u{20000}', '\u{2A6DF}'),
+    ('\u{2A700}', '\u{2B738}'),
+    ('\u{2B740}', '\u{2B81D}'),
+    ('\u{2B820}', '\u{2CEA1}'),
+    ('\u{2CEB0}', '\u{2EBE0}'),
+    ('\u{2F800}', '\u{2FA1D}'),
+    ('\u{30000}', '\u{3134A}'),
+    ('\u{E0100}', '\u{E01EF}'),
+];
+
+/// Ranges of chars with the `ID_Start` property, sorted by code point.
+pub(crate) static ID_START: &[(char, char)] = &[
+    ('\u{41}', '\u{5A}'),
+    ('\u{61}', '\u{7A}'),
+    ('\u{AA}', '\u{AA}'),
+    ('\u{B5}', '\u{B5}'),
+    ('\u{BA}', '\u{BA}'),
+    ('\u{C0}', '\u{D6}'),
+    ('\u{D8}', '\u{F6}'),
+    ('\u{F8}', '\u{2C1}'),
+    ('\u{2C6}', '\u{2D1}'),
+    ('\u{2E0}', '\u{2E4}'),
+    ('\u{2EC}', '\u{2EC}'),
+    ('\u{2EE}', '\u{2EE}'),
+    ('\u{370}', '\u{374}'),
+    ('\u{376}', '\u{377}'),
+    ('\u{37A}', '\u{37D}'),
+    ('\u{37F}', '\u{37F}'),
+    ('\u{386}', '\u{386}'),
+    ('\u{388}', '\u{38A}'),
+    ('\u{38C}', '\u{38C}'),
+    ('\u{38E}', '\u{3A1}'),
+    ('\u{3A3}', '\u{3F5}'),
+    ('\u{3F7}', '\u{481}'),
+    ('\u{48A}', '\u{52F}'),
+    ('\u{531}', '\u{556}'),
+    ('\u{559}', '\u{559}'),
+    ('\u{560}', '\u{588}'),
+    ('\u{5D0}', '\u{5EA}'),
+    ('\u{5EF}', '\u{5F2}'),
+    ('\u{620}', '\u{64A}'),
+    ('\u{66E}', '\u{66F}'),
+    ('\u{671}', '\u{6D3}'),
+    ('\u{6D5}', '\u{6D5}'),
+    ('\u{6E5}', '\u{6E6}'),
+    ('\u{6EE}', '\u{6EF}'),
+    ('\u{6FA}', '\u{6FC}'),
+    ('\u{6FF}', '\u{6FF}'),
+    ('\u{710}', '\u{710}'),
+    ('\u{712}', '\u{72F}'),
+    ('\u{74D}', '\u{7A5}'),
+    ('\u{7B1}', '\u{7B1}'),
+    ('\u{7CA}', '\u{7EA}'),
+    ('\u{7F4}', '\u{7F5}'),
+    ('\u{7FA}', '\u{7FA}'),
+    ('\u{800}', '\u{815}'),
+    ('\u{81A}', '\u{81A}'),
+    ('\u{824}', '\u{824}'),
+    ('\u{828}', '\u{828}'),
+    ('\u{840}', '\u{858}'),
+    ('\u{860}', '\u{86A}'),
+    ('\u{870}', '\u{887}'),
+    ('\u{889}', '\u{88E}'),
+    ('\u{8A0}', '\u{8C9}'),
+    ('\u{904}', '\u{939}'),
+    ('\u{93D}', '\u{93D}'),
+    ('\u{950}', '\u{950}'),
+    ('\u{958}', '\u{961}'),
+    ('\u{971}', '\u{980}'),
+    ('\u{985}', '\u{98C}'),
+    ('\u{98F}', '\u{990}'),
+    ('\u{993}', '\u{9A8}'),
+    ('\u{9AA}', '\u{9B0}'),
+    ('\u{9B2}', '\u{9B2}'),
+    ('\u{9B6}', '\u{9B9}'),
+    ('\u{9BD}', '\u{9BD}'),
+    ('\u{9CE}', '\u{9CE}'),
+    ('\u{9DC}', '\u{9DD}'),
+    ('\u{9DF}', '\u{9E1}'),
+    ('\u{9F0}', '\u{9F1}'),
+    ('\u{9FC}', '\u{9FC}'),
+    ('\u{A05}', '\u{A0A}'),
+    ('\u{A0F}', '\u{A10}'),
+    ('\u{A13}', '\u{A28}'),
+    ('\u{A2A}', '\u{A30}'),
+    ('\u{A32}', '\u{A33}'),
+    ('\u{A35}', '\u{A36}'),
+    ('\u{A38}', '\u{A39}'),
+    ('\u{A59}', '\u{A5C}'),
+    ('\u{A5E}', '\u{A5E}'),
+    ('\u{A72}', '\u{A74}'),
+    ('\u{A85}', '\u{A8D}'),
+    ('\u{A8F}', '\u{A91}'),
+    ('\u{A93}', '\u{AA8}'),
+    ('\u{AAA}', '\u{AB0}'),
+    ('\u{AB2}', '\u{AB3}'),
+    ('\u{AB5}', '\u{AB9}'),
+    ('\u{ABD}', '\u{ABD}'),
+    ('\u{AD0}', '\u{AD0}'),
+    ('\u{AE0}', '\u{AE1}'),
+    ('\u{AF9}', '\u{AF9}'),
+    ('\u{B05}', '\u{B0C}'),
+    ('\u{B0F}', '\u{B10}'),
+    ('\u{B13}', '\u{B28}'),
+    ('\u{B2A}', '\u{B30}'),
+    ('\u{B32}', '\u{B33}'),
+    ('\u{B35}', '\u{B39}'),
+    ('\u{B3D}', '\u{B3D}'),
+    ('\u{B5C}', '\u{B5D}'),
+    ('\u{B5F}', '\u{B61}'),
+    ('\u{B71}', '\u{B71}'),
+    ('\u{B83}', '\u{B83}'),
+    ('\u{B85}', '\u{B8A}'),
+    ('\u{B8E}', '\u{B90}'),
+    ('\u{B92}', '\u{B95}'),
+    ('\u{B99}', '\u{B9A}'),
+    ('\u{B9C}', '\u{B9C}'),
+    ('\u{B9E}', '\u{B9F}'),
+    ('\u{BA3}', '\u{BA4}'),
+    ('\u{BA8}', '\u{BAA}'),
+    ('\u{BAE}', '\u{BB9}'),
+    ('\u{BD0}', '\u{BD0}'),
+    ('\u{C05}', '\u{C0C}'),
+    ('\u{C0E}', '\u{C10}'),
+    ('\u{C12}', '\u{C28}'),
+    ('\u{C2A}', '\u{C39}'),
+    ('\u{C3D}', '\u{C3D}'),
+    ('\u{C58}', '\u{C5A}'),
+    ('\u{C5D}', '\u{C5D}'),
+    ('\u{C60}', '\u{C61}'),
+    ('\u{C80}', '\u{C80}'),
+    ('\u{C85}', '\u{C8C}'),
+    ('\u{C8E}', '\u{C90}'),
+    ('\u{C92}', '\u{CA8}'),
+    ('\u{CAA}', '\u{CB3}'),
+    ('\u{CB5}', '\u{CB9}'),
+    ('\u{CBD}', '\u{CBD}'),
+    ('\u{CDD}', '\u{CDE}'),
+    ('\u{CE0}', '\u{CE1}'),
+    ('\u{CF1}', '\u{CF2}'),
+    ('\u{D04}', '\u{D0C}'),
+    ('\u{D0E}', '\u{D10}'),
+    ('\u{D12}', '\u{D3A}'),
+    ('\u{D3D}', '\u{D3D}'),
+    ('\u{D4E}', '\u{D4E}'),
+    ('\u{D54}', '\u{D56}'),
+    ('\u{D5F}', '\u{D61}'),
+    ('\u{D7A}', '\u{D7F}'),
+    ('\u{D85}', '\u{D96}'),
+    ('\u{D9A}', '\u{DB1}'),
+    ('\u{DB3}', '\u{DBB}'),
+    ('\u{DBD}', '\u{DBD}'),
+    ('\u{DC0}', '\u{DC6}'),
+    ('\u{E01}', '\u{E30}'),
+    ('\u{E32}', '\u{E33}'),
+    ('\u{E40}', '\u{E46}'),
+    ('\u{E81}', '\u{E82}'),
+    ('\u{E84}', '\u{E84}'),
+    ('\u{E86}', '\u{E8A}'),
+    ('\u{E8C}', '\u{EA3}'),
+    ('\u{EA5}', '\u{EA5}'),
+    ('\u{EA7}', '\u{EB0}'),
+    ('\u{EB2}', '\u{EB3}'),
+    ('\u{EBD}', '\u{EBD}'),
+    ('\u{EC0}', '\u{EC4}'),
+    ('\u{EC6}', '\u{EC6}'),
+    ('\u{EDC}', '\u{EDF}'),
+    ('\u{F00}', '\u{F00}'),
+    ('\u{F40}', '\u{F47}'),
+    ('\u{F49}', '\u{F6C}'),
+    ('\u{F88}', '\u{F8C}'),
+    ('\u{1000}', '\u{102A}'),
+    ('\u{103F}', '\u{103F}'),
+    ('\u{1050}', '\u{1055}'),
+    ('\u{105A}', '\u{105D}'),
+    ('\u{1061}', '\u{1061}'),
+    ('\u{1065}', '\u{1066}'),
+    ('\u{106E}', '\u{1070}'),
+    ('\u{1075}', '\u{1081}'),
+    ('\u{108E}', '\u{108E}'),
+    ('\u{10A0}', '\u{10C5}'),
+    ('\u{10C7}', '\u{10C7}'),
+    ('\u{10CD}', '\u{10CD}'),
+    ('\u{10D0}', '\u{10FA}'),
+    ('\u{10FC}', '\u{1248}'),
+    ('\u{124A}', '\u{124D}'),
+    ('\u{1250}', '\u{1256}'),
+    ('\u{1258}', '\u{1258}'),
+    ('\u{125A}', '\u{125D}'),
+    ('\u{1260}', '\u{1288}'),
+    ('\u{128A}', '\u{128D}'),
+    ('\u{1290}', '\u{12B0}'),
+    ('\u{12B2}', '\u{12B5}'),
+    ('\u{12B8}', '\u{12BE}'),
+    ('\u{12C0}', '\u{12C0}'),
+    ('\u{12C2}', '\u{12C5}'),
+    ('\u{12C8}', '\u{12D6}'),
+    ('\u{12D8}', '\u{1310}'),
+    ('\u{1312}', '\u{1315}'),
+    ('\u{1318}', '\u{135A}'),
+    ('\u{1380}', '\u{138F}'),
+    ('\u{13A0}', '\u{13F5}'),
+    ('\u{13F8}', '\u{13FD}'),
+    ('\u{1401}', '\u{166C}'),
+    ('\u{166F}', '\u{167F}'),
+    ('\u{1681}', '\u{169A}'),
+    ('\u{16A0}', '\u{16EA}'),
+    ('\u{16EE}', '\u{16F8}'),
+    ('\u{1700}', '\u{1711}'),
+    ('\u{171F}', '\u{1731}'),
+    ('\u{1740}', '\u{1751}'),
+    ('\u{1760}', '\u{176C}'),
+    ('\u{176E}', '\u{1770}'),
+    ('\u{1780}', '\u{17B3}'),
+    ('\u{17D7}', '\u{17D7}'),
+    ('\u{17DC}', '\u{17DC}'),
+    ('\u{1820}', '\u{1878}'),
+    ('\u{1880}', '\u{18A8}'),
+    ('\u{18AA}', '\u{18AA}'),
+    ('\u{18B0}', '\u{18F5}'),
+    ('\u{1900}', '\u{191E}'),
+    ('\u{1950}', '\u{196D}'),
+    ('\u{1970}', '\u{1974}'),
+    ('\u{1980}', '\u{19AB}'),
+    ('\u{19B0}', '\u{19C9}'),
+    ('\u{1A00}', '\u{1A16}'),
+    ('\u{1A20}', '\u{1A54}'),
+    ('\u{1AA7}', '\u{1AA7}'),
+    ('\u{1B05}', '\u{1B33}'),
+    ('\u{1B45}', '\u{1B4C}'),
+    ('\u{1B83}', '\u{1BA0}'),
+    ('\u{1BAE}', '\u{1BAF}'),
+    ('\u{1BBA}', '\u{1BE5}'),
+    ('\u{1C00}', '\u{1C23}'),
+    ('\u{1C4D}', '\u{1C4F}'),
+    ('\u{1C5A}', '\u{1C7D}'),
+    ('\u{1C80}', '\u{1C88}'),
+    ('\u{1C90}', '\u{1CBA}'),
+    ('\u{1CBD}', '\u{1CBF}'),
+    ('\u{1CE9}', '\u{1CEC}'),
+    ('\u{1CEE}', '\u{1CF3}'),
+    ('\u{1CF5}', '\u{1CF6}'),
+    ('\u{1CFA}', '\u{1CFA}'),
+    ('\u{1D00}', '\u{1DBF}'),
+    ('\u{1E00}', '\u{1F15}'),
+    ('\u{1F18}', '\u{1F1D}'),
+    ('\u{1F20}', '\u{1F45}'),
+    ('\u{1F48}', '\u{1F4D}'),
+    ('\u{1F50}', '\u{1F57}'),
+    ('\u{1F59}', '\u{1F59}'),
+    ('\u{1F5B}', '\u{1F5B}'),
+    ('\u{1F5D}', '\u{1F5D}'),
+    ('\u{1F5F}', '\u{1F7D}'),
+    ('\u{1F80}', '\u{1FB4}'),
+    ('\u{1FB6}', '\u{1FBC}'),
+    ('\u{1FBE}', '\u{1FBE}'),
+    ('\u{1FC2}', '\u{1FC4}'),
+    ('\u{1FC6}', '\u{1FCC}'),
+    ('\u{1FD0}', '\u{1FD3}'),
+    ('\u{1FD6}', '\u{1FDB}'),
+    ('\u{1FE0}', '\u{1FEC}'),
+    ('\u{1FF2}', '\u{1FF4}'),
+    ('\u{1FF6}', '\u{1FFC}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207F}', '\u{207F}'),
+    ('\u{2090}', '\u{209C}'),
+    ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'),
+    ('\u{210A}', '\u{2113}'),
+    ('\u{2115}', '\u{2115}'),
+    ('\u{2118}', '\u{211D}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{2128}', '\u{2128}'),
+    ('\u{212A}', '\u{2139}'),
+    ('\u{213C}', '\u{213F}'),
+    ('\u{2145}', '\u{2149}'),
+    ('\u{214E}', '\u{214E}'),
+    ('\u{2160}', '\u{2188}'),
+    ('\u{2C00}', '\u{2CE4}'),
+    ('\u{2CEB}', '\u{2CEE}'),
+    ('\u{2CF2}', '\u{2CF3}'),
+    ('\u{2D00}', '\u{2D25}'),
+    ('\u{2D27}', '\u{2D27}'),
+    ('\u{2D2D}', '\u{2D2D}'),
+    ('\u{2D30}', '\u{2D67}'),
+    ('\u{2D6F}', '\u{2D6F}'),
+    ('\u{2D80}', '\u{2D96}'),
+    ('\u{2DA0}', '\u{2DA6}'),
+    ('\u{2DA8}', '\u{2DAE}'),
+    ('\u{2DB0}', '\u{2DB6}'),
+    ('\u{2DB8}', '\u{2DBE}'),
+    ('\u{2DC0}', '\u{2DC6}'),
+    ('\u{2DC8}', '\u{2DCE}'),
+    ('\u{2DD0}', '\u{2DD6}'),
+    ('\u{2DD8}', '\u{2DDE}'),
+    ('\u{3005}', '\u{3007}'),
+    ('\u{3021}', '\u{3029}'),
+    ('\u{3031}', '\u{3035}'),
+    ('\u{3038}', '\u{303C}'),
+    ('\u{3041}', '\u{3096}'),
+    ('\u{309B}', '\u{309F}'),
+    ('\u{30A1}', '\u{30FA}'),
+    ('\u{30FC}', '\u{30FF}'),
+    ('\u{3105}', '\u{312F}'),
+    ('\u{3131}', '\u{318E}'),
+    ('\u{31A0}', '\u{31BF}'),
+    ('\u{31F0}', '\u{31FF}'),
+    ('\u{3400}', '\u{4DBF}'),
+    ('\u{4E00}', '\u{A48C}'),
+    ('\u{A4D0}', '\u{A4FD}'),
+    ('\u{A500}', '\u{A60C}'),
+    ('\u{A610}', '\u{A61F}'),
+    ('\u{A62A}', '\u{A62B}'),
+    ('\u{A640}', '\u{A66E}'),
+    ('\u{A67F}', '\u{A69D}'),
+    ('\u{A6A0}', '\u{A6EF}'),
+    ('\u{A717}', '\u{A71F}'),
+    ('\u{A722}', '\u{A788}'),
+    ('\u{A78B}', '\u{A7CA}'),
+    ('\u{A7D0}', '\u{A7D1}'),
+    ('\u{A7D3}', '\u{A7D3}'),
+    ('\u{A7D5}', '\u{A7D9}'),
+    ('\u{A7F2}', '\u{A801}'),
+    ('\u{A803}', '\u{A805}'),
+    ('\u{A807}', '\u{A80A}'),
+    ('\u{A80C}', '\u{A822}'),
+    ('\u{A840}', '\u{A873}'),
+    ('\u{A882}', '\u{A8B3}'),
+    ('\u{A8F2}', '\u{A8F7}'),
+    ('\u{A8FB}', '\u{A8FB}'),
+    ('\u{A8FD}', '\u{A8FE}'),
+    ('\u{A90A}', '\u{A925}'),
+    ('\u{A930}', '\u{A946}'),
+    ('\u{A960}', '\u{A97C}'),
+    ('\u{A984}', '\u{A9B2}'),
+    ('\u{A9CF}', '\u{A9CF}'),
+    ('\u{A9E0}', '\u{A9E4}'),
+    ('\u{A9E6}', '\u{A9EF}'),
+    ('\u{A9FA}', '\u{A9FE}'),
+    ('\u{AA00}', '\u{AA28}'),
+    ('\u{AA40}', '\u{AA42}'),
+    ('\u{AA44}', '\u{AA4B}'),
+    ('\u{AA60}', '\u{AA76}'),
+    ('\u{AA7A}', '\u{AA7A}'),
+    ('\u{AA7E}', '\u{AAAF}'),
+    ('\u{AAB1}', '\u{AAB1}'),
+    ('\u{AAB5}', '\u{AAB6}'),
+    ('\u{AAB9}', '\u{AABD}'),
+    ('\u{AAC0}', '\u{AAC0}'),
+    ('\u{AAC2}', '\u{AAC2}'),
+    ('\u{AADB}', '\u{AADD}'),
+    ('\u{AAE0}', '\u{AAEA}'),
+    ('\u{AAF2}', '\u{AAF4}'),
+    ('\u{AB01}', '\u{AB06}'),
+    ('\u{AB09}', '\u{AB0E}'),
+    ('\u{AB11}', '\u{AB16}'),
+    ('\u{AB20}', '\u{AB26}'),
+    ('\u{AB28}', '\u{AB2E}'),
+    ('\u{AB30}', '\u{AB5A}'),
+    ('\u{AB5C}', '\u{AB69}'),
+    ('\u{AB70}', '\u{ABE2}'),
+    ('\u{AC00}', '\u{D7A3}'),
+    ('\u{D7B0}', '\u{D7C6}'),
+    ('\u{D7CB}', '\u{D7FB}'),
+    ('\u{F900}', '\u{FA6D}'),
+    ('\u{FA70}', '\u{FAD9}'),
+    ('\u{FB00}', '\u{FB06}'),
+    ('\u{FB13}', '\u{FB17}'),
+    ('\u{FB1D}', '\u{FB1D}'),
+    ('\u{FB1F}', '\u{FB28}'),
+    ('\u{FB2A}', '\u{FB36}'),
+    ('\u{FB38}', '\u{FB3C}'),
+    ('\u{FB3E}', '\u{FB3E}'),
+    ('\u{FB40}', '\u{FB41}'),
+    ('\u{FB43}', '\u{FB44}'),
+    ('\u{FB46}', '\u{FBB1}'),
+    ('\u{FBD3}', '\u{FD3D}'),
+    ('\u{FD50}', '\u{FD8F}'),
+    ('\u{FD92}', '\u{FDC7}'),
+    ('\u{FDF0}', '\u{FDFB}'),
+    ('\u{FE70}', '\u{FE74}'),
+    ('\u{FE76}', '\u{FEFC}'),
+    ('\u{FF21}', '\u{FF3A}'),
+    ('\u{FF41}', '\u{FF5A}'),
+    ('\u{FF66}', '\u{FFBE}'),
+    ('\u{FFC2}', '\u{FFC7}'),
+    ('\u{FFCA}', '\u{FFCF}'),
+    ('\u{FFD2}', '\u{FFD7}'),
+    ('\u{FFDA}', '\u{FFDC}'),
+    ('\u{10000}', '\u{1000B}'),
+    ('\u{1000D}', '\u{10026}'),
+    ('\u{10028}', '\u{1003A}'),
+    ('\u{1003C}', '\u{1003D}'),
+    ('\u{1003F}', '\u{1004D}'),
+    ('\u{10050}', '\u{1005D}'),
+    ('\u{10080}', '\u{100FA}'),
+    ('\u{10140}', '\u{10174}'),
+    ('\u{10280}', '\u{1029C}'),
+    ('\u{102A0}', '\u{102D0}'),
+    ('\u{10300}', '\u{1031F}'),
+    ('\u{1032D}', '\u{1034A}'),
+    ('\u{10350}', '\u{10375}'),
+    ('\u{10380}', '\u{1039D}'),
+    ('\u{103A0}', '\u{103C3}'),
+    ('\u{103C8}', '\u{103CF}'),
+    ('\u{103D1}', '\u{103D5}'),
+    ('\u{10400}', '\u{1049D}'),
+    ('\u{104B0}', '\u{104D3}'),
+    ('\u{104D8}', '\u{104FB}'),
+    ('\u{10500}', '\u{10527}'),
+    ('\u{10530}', '\u{10563}'),
+    ('\u{10570}', '\u{1057A}'),
+    ('\u{1057C}', '\u{1058A}'),
+    ('\u{1058C}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'),
+    ('\u{10597}', '\u{105A1}'),
+    ('\u{105A3}', '\u{105B1}'),
+    ('\u{105B3}', '\u{105B9}'),
+    ('\u{105BB}', '\u{105BC}'),
+    ('\u{10600}', '\u{10736}'),
+    ('\u{10740}', '\u{10755}'),
+    ('\u{10760}', '\u{10767}'),
+    ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107B0}'),
+    ('\u{107B2}', '\u{107BA}'),
+    ('\u{10800}', '\u{10805}'),
+    ('\u{10808}', '\u{10808}'),
+    ('\u{1080A}', '\u{10835}'),
+    ('\u{10837}', '\u{10838}'),
+    ('\u{1083C}', '\u{1083C}'),
+    ('\u{1083F}', '\u{10855}'),
+    ('\u{10860}', '\u{10876}'),
+    ('\u{10880}', '\u{1089E}'),
+    ('\u{108E0}', '\u{108F2}'),
+    ('\u{108F4}', '\u{108F5}'),
+    ('\u{10900}', '\u{10915}'),
+    ('\u{10920}', '\u{10939}'),
+    ('\u{10980}', '\u{109B7}'),
+    ('\u{109BE}', '\u{109BF}'),
+    ('\u{10A00}', '\u{10A00}'),
+    ('\u{10A10}', '\u{10A13}'),
+    ('\u{10A15}', '\u{10A17}'),
+    ('\u{10A19}', '\u{10A35}'),
+    ('\u{10A60}', '\u{10A7C}'),
+    ('\u{10A80}', '\u{10A9C}'),
+    ('\u{10AC0}', '\u{10AC7}'),
+    ('\u{10AC9}', '\u{10AE4}'),
+    ('\u{10B00}', '\u{10B35}'),
+    ('\u{10B40}', '\u{10B55}'),
+    ('\u{10B60}', '\u{10B72}'),
+    ('\u{10B80}', '\u{10B91}'),
+    ('\u{10C00}', '\u{10C48}'),
+    ('\u{10C80}', '\u{10CB2}'),
+    ('\u{10CC0}', '\u{10CF2}'),
+    ('\u{10D00}', '\u{10D23}'),
+    ('\u{10E80}', '\u{10EA9}'),
+    ('\u{10EB0}', '\u{10EB1}'),
+    ('\u{10F00}', '\u{10F1C}'),
+    ('\u{10F27}', '\u{10F27}'),
+    ('\u{10F30}', '\u{10F45}'),
+    ('\u{10F70}', '\u{10F81}'),
+    ('\u{10FB0}', '\u{10FC4}'),
+    ('\u{10FE0}', '\u{10FF6}'),
+    ('\u{11003}', '\u{11037}'),
+    ('\u{11071}', '\u{11072}'),
+    ('\u{11075}', '\u{11075}'),
+    ('\u{11083}', '\u{110AF}'),
+    ('\u{110D0}', '\u{110E8}'),
+    ('\u{11103}', '\u{11126}'),
+    ('\u{11144}', '\u{11144}'),
+    ('\u{11147}', '\u{11147}'),
+    ('\u{11150}', '\u{11172}'),
+    ('\u{11176}', '\u{11176}'),
+    ('\u{11183}', '\u{111B2}'),
+    ('\u{111C1}', '\u{111C4}'),
+    ('\u{111DA}', '\u{111DA}'),
+    ('\u{111DC}', '\u{111DC}'),
+    ('\u{11200}', '\u{11211}'),
+    ('\u{11213}', '\u{1122B}'),
+    ('\u{11280}', '\u{11286}'),
+    ('\u{11288}', '\u{11288}'),
+    ('\u{1128A}', '\u{1128D}'),
+    ('\u{1128F}', '\u{1129D}'),
+    ('\u{1129F}', '\u{112A8}'),
+    ('\u{112B0}', '\u{112DE}'),
+    ('\u{11305}', '\u{1130C}'),
+    ('\u{1130F}', '\u{11310}'),
+    ('\u{11313}', '\u{11328}'),
+    ('\u{1132A}', '\u{11330}'),
+    ('\u{11332}', '\u{11333}'),
+    ('\u{11335}', '\u{11339}'),
+    ('\u{1133D}', '\u{1133D}'),
+    ('\u{11350}', '\u{11350}'),
+    ('\u{1135D}', '\u{11361}'),
+    ('\u{11400}', '\u{11434}'),
+    ('\u{11447}', '\u{1144A}'),
+    ('\u{1145F}', '\u{11461}'),
+    ('\u{11480}', '\u{114AF}'),
+    ('\u{114C4}', '\u{114C5}'),
+    ('\u{114C7}', '\u{114C7}'),
+    ('\u{11580}', '\u{115AE}'),
+    ('\u{115D8}', '\u{115DB}'),
+    ('\u{11600}', '\u{1162F}'),
+    ('\u{11644}', '\u{11644}'),
+    ('\u{11680}', '\u{116AA}'),
+    ('\u{116B8}', '\u{116B8}'),
+    ('\u{11700}', '\u{1171A}'),
+    ('\u{11740}', '\u{11746}'),
+    ('\u{11800}', '\u{1182B}'),
+    ('\u{118A0}', '\u{118DF}'),
+    ('\u{118FF}', '\u{11906}'),
+    ('\u{11909}', '\u{11909}'),
+    ('\u{1190C}', '\u{11913}'),
+    ('\u{11915}', '\u{11916}'),
+    ('\u{11918}', '\u{1192F}'),
+    ('\u{1193F}', '\u{1193F}'),
+    ('\u{11941}', '\u{11941}'),
+    ('\u{119A0}', '\u{119A7}'),
+    ('\u{119AA}', '\u{119D0}'),
+    ('\u{119E1}', '\u{119E1}'),
+    ('\u{119E3}', '\u{119E3}'),
+    ('\u{11A00}', '\u{11A00}'),
+    ('\u{11A0B}', '\u{11A32}'),
+    ('\u{11A3A}', '\u{11A3A}'),
+    ('\u{11A50}', '\u{11A50}'),
+    ('\u{11A5C}', '\u{11A89}'),
+    ('\u{11A9D}', '\u{11A9D}'),
+    ('\u{11AB0}', '\u{11AF8}'),
+    ('\u{11C00}', '\u{11C08}'),
+    ('\u{11C0A}', '\u{11C2E}'),
+    ('\u{11C40}', '\u{11C40}'),
+    ('\u{11C72}', '\u{11C8F}'),
+    ('\u{11D00}', '\u{11D06}'),
+    ('\u{11D08}', '\u{11D09}'),
+    ('\u{11D0B}', '\u{11D30}'),
+    ('\u{11D46}', '\u{11D46}'),
+    ('\u{11D60}', '\u{11D65}'),
+    ('\u{11D67}', '\u{11D68}'),
+    ('\u{11D6A}', '\u{11D89}'),
+    ('\u{11D98}', '\u{11D98}'),
+    ('\u{11EE0}', '\u{11EF2}'),
+    ('\u{11FB0}', '\u{11FB0}'),
+    ('\u{12000}', '\u{12399}'),
+    ('\u{12400}', '\u{1246E}'),
+    ('\u{12480}', '\u{12543}'),
+    ('\u{12F90}', '\u{12FF0}'),
+    ('\u{13000}', '\u{1342E}'),
+    ('\u{14400}', '\u{14646}'),
+    ('\u{16800}', '\u{16A38}'),
+    ('\u{16A40}', '\u{16A5E}'),
+    ('\u{16A70}', '\u{16ABE}'),
+    ('\u{16AD0}', '\u{16AED}'),
+    ('\u{16B00}', '\u{16B2F}'),
+    ('\u{16B40}', '\u{16B43}'),
+    ('\u{16B63}', '\u{16B77}'),
+    ('\u{16B7D}', '\u{16B8F}'),
+    ('\u{16E40}', '\u{16E7F}'),
+    ('\u{16F00}', '\u{16F4A}'),
+    ('\u{16F50}', '\u{16F50}'),
+    ('\u{16F93}', '\u{16F9F}'),
+    ('\u{16FE0}', '\u{16FE1}'),
+    ('\u{16FE3}', '\u{16FE3}'),
+    ('\u{17000}', '\u{187F7}'),
+    ('\u{18800}', '\u{18CD5}'),
+    ('\u{18D00}', '\u{18D08}'),
+    ('\u{1AFF0}', '\u{1AFF3}'),
+    ('\u{1AFF5}', '\u{1AFFB}'),
+    ('\u{1AFFD}', '\u{1AFFE}'),
+    ('\u{1B000}', '\u{1B122}'),
+    ('\u{1B150}', '\u{1B152}'),
+    ('\u{1B164}', '\u{1B167}'),
+    ('\u{1B170}', '\u{1B2FB}'),
+    ('\u{1BC00}', '\u{1BC6A}'),
+    ('\u{1BC70}', '\u{1BC7C}'),
+    ('\u{1BC80}', '\u{1BC88}'),
+    ('\u{1BC90}', '\u{1BC99}'),
+    ('\u{1D400}', '\u{1D454}'),
+    ('\u{1D456}', '\u{1D49C}'),
+    ('\u{1D49E}', '\u{1D49F}'),
+    ('\u{1D4A2}', '\u{1D4A2}'),
+    ('\u{1D4A5}', '\u{1D4A6}'),
+    ('\u{1D4A9}', '\u{1D4AC}'),
+    ('\u{1D4AE}', '\u{1D4B9}'),
+    ('\u{1D4BB}', '\u{1D4BB}'),
+    ('\u{1D4BD}', '\u{1D4C3}'),
+    ('\u{1D4C5}', '\u{1D505}'),
+    ('\u{1D507}', '\u{1D50A}'),
+    ('\u{1D50D}', '\u{1D514}'),
+    ('\u{1D516}', '\u{1D51C}'),
+    ('\u{1D51E}', '\u{1D539}'),
+    ('\u{1D53B}', '\u{1D53E}'),
+    ('\u{1D540}', '\u{1D544}'),
+    ('\u{1D546}', '\u{1D546}'),
+    ('\u{1D54A}', '\u{1D550}'),
+    ('\u{1D552}', '\u{1D6A5}'),
+    ('\u{1D6A8}', '\u{1D6C0}'),
+    ('\u{1D6C2}', '\u{1D6DA}'),
+    ('\u{1D6DC}', '\u{1D6FA}'),
+    ('\u{1D6FC}', '\u{1D714}'),
+    ('\u{1D716}', '\u{1D734}'),
+    ('\u{1D736}', '\u{1D74E}'),
+    ('\u{1D750}', '\u{1D76E}'),
+    ('\u{1D770}', '\u{1D788}'),
+    ('\u{1D78A}', '\u{1D7A8}'),
+    ('\u{1D7AA}', '\u{1D7C2}'),
+    ('\u{1D7C4}', '\u{1D7CB}'),
+    ('\u{1DF00}', '\u{1DF1E}'),
+    ('\u{1E100}', '\u{1E12C}'),
+    ('\u{1E137}', '\u{1E13D}'),
+    ('\u{1E14E}', '\u{1E14E}'),
+    ('\u{1E290}', '\u{1E2AD}'),
+    ('\u{1E2C0}', '\u{1E2EB}'),
+    ('\u{1E7E0}', '\u{1E7E6}'),
+    ('\u{1E7E8}', '\u{1E7EB}'),
+    ('\u{1E7ED}', '\u{1E7EE}'),
+    ('\u{1E7F0}', '\u{1E7FE}'),
+    ('\u{1E800}', '\u{1E8C4}'),
+    ('\u{1E900}', '\u{1E943}'),
+    ('\u{1E94B}', '\u{1E94B}'),
+    ('\u{1EE00}', '\u{1EE03}'),
+    ('\u{1EE05}', '\u{1EE1F}'),
+    ('\u{1EE21}', '\u{1EE22}'),
+    ('\u{1EE24}', '\u{1EE24}'),
+    ('\u{1EE27}', '\u{1EE27}'),
+    ('\u{1EE29}', '\u{1EE32}'),
+    ('\u{1EE34}', '\u{1EE37}'),
+    ('\u{1EE39}', '\u{1EE39}'),
+    ('\u{1EE3B}', '\u{1EE3B}'),
+    ('\u{1EE42}', '\u{1EE42}'),
+    ('\u{1EE47}', '\u{1EE47}'),
+    ('\u{1EE49}', '\u{1EE49}'),
+    ('\u{1EE4B}', '\u{1EE4B}'),
+    ('\u{1EE4D}', '\u{1EE4F}'),
+    ('\u{1EE51}', '\u{1EE52}'),
+    ('\u{1EE54}', '\u{1EE54}'),
+    ('\u{1EE57}', '\u{1EE57}'),
+    ('\u{1EE59}', '\u{1EE59}'),
+    ('\u{1EE5B}', '\u{1EE5B}'),
+    ('\u{1EE5D}', '\u{1EE5D}'),
+    ('\u{1EE5F}', '\u{1EE5F}'),
+    ('\u{1EE61}', '\u{1EE62}'),
+    ('\u{1EE64}', '\u{1EE64}'),
+    ('\u{1EE67}', '\u{1EE6A}'),
+    ('\u{1EE6C}', '\u{1EE72}'),
+    ('\u{1EE74}', '\u{1EE77}'),
+    ('\u{1EE79}', '\u{1EE7C}'),
+    ('\u{1EE7E}', '\u{1EE7E}'),
+    ('\u{1EE80}', '\u{1EE89}'),
+    ('\u{1EE8B}', '\u{1EE9B}'),
+    ('\u{1EEA1}', '\u{1EEA3}'),
+    ('\u{1EEA5}', '\u{1EEA9}'),
+    ('\u{1EEAB}', '\u{1EEBB}'),
+    ('\u{20000}', '\u{2A6DF}'),
+    ('\u{2A700}', '\u{2B738}'),
+    ('\u{2B740}', '\u{2B81D}'),
+    ('\u{2B820}', '\u{2CEA1}'),
+    ('\u{2CEB0}', '\u{2EBE0}'),
+    ('\u{2F800}', '\u{2FA1D}'),
+    ('\u{30000}', '\u{3134A}'),
+];
+
+/// Ranges of chars with the `Ideographic` property, sorted by code point.
+pub(crate) static IDEOGRAPHIC: &[(char, char)] = &[
+    ('\u{3006}', '\u{3007}'),
+    ('\u{3021}', '\u{3029}'),
+    ('\u{3038}', '\u{303A}'),
+    ('\u{3400}', '\u{4DBF}'),
+    ('\u{4E00}', '\u{9FFF}'),
+    ('\u{F900}', '\u{FA6D}'),
+    ('\u{FA70}', '\u{FAD9}'),
+    ('\u{16FE4}', '\u{16FE4}'),
+    ('\u{17000}', '\u{187F7}'),
+    ('\u{18800}', '\u{18CD5}'),
+    ('\u{18D00}', '\u{18D08}'),
+    ('\u{1B170}', '\u{1B2FB}'),
+    ('\u{20000}', '\u{2A6DF}'),
+    ('\u{2A700}', '\u{2B738}'),
+    ('\u{2B740}', '\u{2B81D}'),
+    ('\u{2B820}', '\u{2CEA1}'),
+    ('\u{2CEB0}', '\u{2EBE0}'),
+    ('\u{2F800}', '\u{2FA1D}'),
+    ('\u{30000}', '\u{3134A}'),
+];
+
+/// Ranges of chars with the `Lowercase` property, sorted by code point.
+pub(crate) static LOWERCASE: &[(char, char)] = &[
+    ('\u{61}', '\u{7A}'),
+    ('\u{AA}', '\u{AA}'),
+    ('\u{B5}', '\u{B5}'),
+    ('\u{BA}', '\u{BA}'),
+    ('\u{DF}', '\u{F6}'),
+    ('\u{F8}', '\u{FF}'),
+    ('\u{101}', '\u{101}'),
+    ('\u{103}', '\u{103}'),
+    ('\u{105}', '\u{105}'),
+    ('\u{107}', '\u{107}'),
+    ('\u{109}', '\u{109}'),
+    ('\u{10B}', '\u{10B}'),
+    ('\u{10D}', '\u{10D}'),
+    ('\u{10F}', '\u{10F}'),
+    ('\u{111}', '\u{111}'),
+    ('\u{113}', '\u{113}'),
+    ('\u{115}', '\u{115}'),
+    ('\u{117}', '\u{117}'),
+    ('\u{119}', '\u{119}'),
+    ('\u{11B}', '\u{11B}'),
+    ('\u{11D}', '\u{11D}'),
+    ('\u{11F}', '\u{11F}'),
+    ('\u{121}', '\u{121}'),
+    ('\u{123}', '\u{123}'),
+    ('\u{125}', '\u{125}'),
+    ('\u{127}', '\u{127}'),
+    ('\u{129}', '\u{129}'),
+    ('\u{12B}', '\u{12B}'),
+    ('\u{12D}', '\u{12D}'),
+    ('\u{12F}', '\u{12F}'),
+    ('\u{131}', '\u{131}'),
+    ('\u{133}', '\u{133}'),
+    ('\u{135}', '\u{135}'),
+    ('\u{137}', '\u{138}'),
+    ('\u{13A}', '\u{13A}'),
+    ('\u{13C}', '\u{13C}'),
+    ('\u{13E}', '\u{13E}'),
+    ('\u{140}', '\u{140}'),
+    ('\u{142}', '\u{142}'),
+    ('\u{144}', '\u{144}'),
+    ('\u{146}', '\u{146}'),
+    ('\u{148}', '\u{149}'),
+    ('\u{14B}', '\u{14B}'),
+    ('\u{14D}', '\u{14D}'),
+    ('\u{14F}', '\u{14F}'),
+    ('\u{151}', '\u{151}'),
+    ('\u{153}', '\u{153}'),
+    ('\u{155}', '\u{155}'),
+    ('\u{157}', '\u{157}'),
+    ('\u{159}', '\u{159}'),
+    ('\u{15B}', '\u{15B}'),
+    ('\u{15D}', '\u{15D}'),
+    ('\u{15F}', '\u{15F}'),
+    ('\u{161}', '\u{161}'),
+    ('\u{163}', '\u{163}'),
+    ('\u{165}', '\u{165}'),
+    ('\u{167}', '\u{167}'),
+    ('\u{169}', '\u{169}'),
+    ('\u{16B}', '\u{16B}'),
+    ('\u{16D}', '\u{16D}'),
+    ('\u{16F}', '\u{16F}'),
+    ('\u{171}', '\u{171}'),
+    ('\u{173}', '\u{173}'),
+    ('\u{175}', '\u{175}'),
+    ('\u{177}', '\u{177}'),
+    ('\u{17A}', '\u{17A}'),
+    ('\u{17C}', '\u{17C}'),
+    ('\u{17E}', '\u{180}'),
+    ('\u{183}', '\u{183}'),
+    ('\u{185}', '\u{185}'),
+    ('\u{188}', '\u{188}'),
+    ('\u{18C}', '\u{18D}'),
+    ('\u{192}', '\u{192}'),
+    ('\u{195}', '\u{195}'),
+    ('\u{199}', '\u{19B}'),
+    ('\u{19E}', '\u{19E}'),
+    ('\u{1A1}', '\u{1A1}'),
+    ('\u{1A3}', '\u{1A3}'),
+    ('\u{1A5}', '\u{1A5}'),
+    ('\u{1A8}', '\u{1A8}'),
+    ('\u{1AA}', '\u{1AB}'),
+    ('\u{1AD}', '\u{1AD}'),
+    ('\u{1B0}', '\u{1B0}'),
+    ('\u{1B4}', '\u{1B4}'),
+    ('\u{1B6}', '\u{1B6}'),
+    ('\u{1B9}', '\u{1BA}'),
+    ('\u{1BD}', '\u{1BF}'),
+    ('\u{1C6}', '\u{1C6}'),
+    ('\u{1C9}', '\u{1C9}'),
+    ('\u{1CC}', '\u{1CC}'),
+    ('\u{1CE}', '\u{1CE}'),
+    ('\u{1D0}', '\u{1D0}'),
+    ('\u{1D2}', '\u{1D2}'),
+    ('\u{1D4}', '\u{1D4}'),
+    ('\u{1D6}', '\u{1D6}'),
+    ('\u{1D8}', '\u{1D8}'),
+    ('\u{1DA}', '\u{1DA}'),
+    ('\u{1DC}', '\u{1DD}'),
+    ('\u{1DF}', '\u{1DF}'),
+    ('\u{1E1}', '\u{1E1}'),
+    ('\u{1E3}', '\u{1E3}'),
+    ('\u{1E5}', '\u{1E5}'),
+    ('\u{1E7}', '\u{1E7}'),
+    ('\u{1E9}', '\u{1E9}'),
+    ('\u{1EB}', '\u{1EB}'),
+    ('\u{1ED}', '\u{1ED}'),
+    ('\u{1EF}', '\u{1F0}'),
+    ('\u{1F3}', '\u{1F3}'),
+    ('\u{1F5}', '\u{1F5}'),
+    ('\u{1F9}', '\u{1F9}'),
+    ('\u{1FB}', '\u{1FB}'),
+    ('\u{1FD}', '\u{1FD}'),
+    ('\u{1FF}', '\u{1FF}'),
+    ('\u{201}', '\u{201}'),
+    ('\u{203}', '\u{203}'),
+    ('\u{205}', '\u{205}'),
+    ('\u{207}', '\u{207}'),
+    ('\u{209}', '\u{209}'),
+    ('\u{20B}', '\u{20B}'),
+    ('\u{20D}', '\u{20D}'),
+    ('\u{20F}', '\u{20F}'),
+    ('\u{211}', '\u{211}'),
+    ('\u{213}', '\u{213}'),
+    ('\u{215}', '\u{215}'),
+    ('\u{217}', '\u{217}'),
+    ('\u{219}', '\u{219}'),
+    ('\u{21B}', '\u{21B}'),
+    ('\u{21D}', '\u{21D}'),
+    ('\u{21F}', '\u{21F}'),
+    ('\u{221}', '\u{221}'),
+    ('\u{223}', '\u{223}'),
+    ('\u{225}', '\u{225}'),
+    ('\u{227}', '\u{227}'),
+    ('\u{229}', '\u{229}'),
+    ('\u{22B}', '\u{22B}'),
+    ('\u{22D}', '\u{22D}'),
+    ('\u{22F}', '\u{22F}'),
+    ('\u{231}', '\u{231}'),
+    ('\u{233}', '\u{239}'),
+    ('\u{23C}', '\u{23C}'),
+    ('\u{23F}', '\u{240}'),
+    ('\u{242}', '\u{242}'),
+    ('\u{247}', '\u{247}'),
+    ('\u{249}', '\u{249}'),
+    ('\u{24B}', '\u{24B}'),
+    ('\u{24D}', '\u{24D}'),
+    ('\u{24F}', '\u{293}'),
+    ('\u{295}', '\u{2B8}'),
+    ('\u{2C0}', '\u{2C1}'),
+    ('\u{2E0}', '\u{2E4}'),
+    ('\u{345}', '\u{345}'),
+    ('\u{371}', '\u{371}'),
+    ('\u{373}', '\u{373}'),
+    ('\u{377}', '\u{377}'),
+    ('\u{37A}', '\u{37D}'),
+    ('\u{390}', '\u{390}'),
+    ('\u{3AC}', '\u{3CE}'),
+    ('\u{3D0}', '\u{3D1}'),
+    ('\u{3D5}', '\u{3D7}'),
+    ('\u{3D9}', '\u{3D9}'),
+    ('\u{3DB}', '\u{3DB}'),
+    ('\u{3DD}', '\u{3DD}'),
+    ('\u{3DF}', '\u{3DF}'),
+    ('\u{3E1}', '\u{3E1}'),
+    ('\u{3E3}', '\u{3E3}'),
+    ('\u{3E5}', '\u{3E5}'),
+    ('\u{3E7}', '\u{3E7}'),
+    ('\u{3E9}', '\u{3E9}'),
+    ('\u{3EB}', '\u{3EB}'),
+    ('\u{3ED}', '\u{3ED}'),
+    ('\u{3EF}', '\u{3F3}'),
+    ('\u{3F5}', '\u{3F5}'),
+    ('\u{3F8}', '\u{3F8}'),
+    ('\u{3FB}', '\u{3FC}'),
+    ('\u{430}', '\u{45F}'),
+    ('\u{461}', '\u{461}'),
+    ('\u{463}', '\u{463}'),
+    ('\u{465}', '\u{465}'),
+    ('\u{467}', '\u{467}'),
+    ('\u{469}', '\u{469}'),
+    ('\u{46B}', '\u{46B}'),
+    ('\u{46D}', '\u{46D}'),
+    ('\u{46F}', '\u{46F}'),
+    ('\u{471}', '\u{471}'),
+    ('\u{473}', '\u{473}'),
+    ('\u{475}', '\u{475}'),
+    ('\u{477}', '\u{477}'),
+    ('\u{479}', '\u{479}'),
+    ('\u{47B}', '\u{47B}'),
+    ('\u{47D}', '\u{47D}'),
+    ('\u{47F}', '\u{47F}'),
+    ('\u{481}', '\u{481}'),
+    ('\u{48B}', '\u{48B}'),
+    ('\u{48D}', '\u{48D}'),
+    ('\u{48F}', '\u{48F}'),
+    ('\u{491}', '\u{491}'),
+    ('\u{493}', '\u{493}'),
+    ('\u{495}', '\u{495}'),
+    ('\u{497}', '\u{497}'),
+    ('\u{499}', '\u{499}'),
+    ('\u{49B}', '\u{49B}'),
+    ('\u{49D}', '\u{49D}'),
+    ('\u{49F}', '\u{49F}'),
+    ('\u{4A1}', '\u{4A1}'),
+    ('\u{4A3}', '\u{4A3}'),
+    ('\u{4A5}', '\u{4A5}'),
+    ('\u{4A7}', '\u{4A7}'),
+    ('\u{4A9}', '\u{4A9}'),
+    ('\u{4AB}', '\u{4AB}'),
+    ('\u{4AD}', '\u{4AD}'),
+    ('\u{4AF}', '\u{4AF}'),
+    ('\u{4B1}', '\u{4B1}'),
+    ('\u{4B3}', '\u{4B3}'),
+    ('\u{4B5}', '\u{4B5}'),
+    ('\u{4B7}', '\u{4B7}'),
+    ('\u{4B9}', '\u{4B9}'),
+    ('\u{4BB}', '\u{4BB}'),
+    ('\u{4BD}', '\u{4BD}'),
+    ('\u{4BF}', '\u{4BF}'),
+    ('\u{4C2}', '\u{4C2}'),
+    ('\u{4C4}', '\u{4C4}'),
+    ('\u{4C6}', '\u{4C6}'),
+    ('\u{4C8}', '\u{4C8}'),
+    ('\u{4CA}', '\u{4CA}'),
+    ('\u{4CC}', '\u{4CC}'),
+    ('\u{4CE}', '\u{4CF}'),
+    ('\u{4D1}', '\u{4D1}'),
+    ('\u{4D3}', '\u{4D3}'),
+    ('\u{4D5}', '\u{4D5}'),
+    ('\u{4D7}', '\u{4D7}'),
+    ('\u{4D9}', '\u{4D9}'),
+    ('\u{4DB}', '\u{4DB}'),
+    ('\u{4DD}', '\u{4DD}'),
+    ('\u{4DF}', '\u{4DF}'),
+    ('\u{4E1}', '\u{4E1}'),
+    ('\u{4E3}', '\u{4E3}'),
+    ('\u{4E5}', '\u{4E5}'),
+    ('\u{4E7}', '\u{4E7}'),
+    ('\u{4E9}', '\u{4E9}'),
+    ('\u{4EB}', '\u{4EB}'),
+    ('\u{4ED}', '\u{4ED}'),
+    ('\u{4EF}', '\u{4EF}'),
+    ('\u{4F1}', '\u{4F1}'),
+    ('\u{4F3}', '\u{4F3}'),
+    ('\u{4F5}', '\u{4F5}'),
+    ('\u{4F7}', '\u{4F7}'),
+    ('\u{4F9}', '\u{4F9}'),
+    ('\u{4FB}', '\u{4FB}'),
+    ('\u{4FD}', '\u{4FD}'),
+    ('\u{4FF}', '\u{4FF}'),
+    ('\u{501}', '\u{501}'),
+    ('\u{503}', '\u{503}'),
+    ('\u{505}', '\u{505}'),
+    ('\u{507}', '\u{507}'),
+    ('\u{509}', '\u{509}'),
+    ('\u{50B}', '\u{50B}'),
+    ('\u{50D}', '\u{50D}'),
+    ('\u{50F}', '\u{50F}'),
+    ('\u{511}', '\u{511}'),
+    ('\u{513}', '\u{513}'),
+    ('\u{515}', '\u{515}'),
+    ('\u{517}', '\u{517}'),
+    ('\u{519}', '\u{519}'),
+    ('\u{51B}', '\u{51B}'),
+    ('\u{51D}', '\u{51D}'),
+    ('\u{51F}', '\u{51F}'),
+    ('\u{521}', '\u{521}'),
+    ('\u{523}', '\u{523}'),
+    ('\u{525}', '\u{525}'),
+    ('\u{527}', '\u{527}'),
+    ('\u{529}', '\u{529}'),
+    ('\u{52B}', '\u{52B}'),
+    ('\u{52D}', '\u{52D}'),
+    ('\u{52F}', '\u{52F}'),
+    ('\u{560}', '\u{588}'),
+    ('\u{10D0}', '\u{10FA}'),
+    ('\u{10FD}', '\u{10FF}'),
+    ('\u{13F8}', '\u{13FD}'),
+    ('\u{1C80}', '\u{1C88}'),
+    ('\u{1D00}', '\u{1DBF}'),
+    ('\u{1E01}', '\u{1E01}'),
+    ('\u{1E03}', '\u{1E03}'),
+    ('\u{1E05}', '\u{1E05}'),
+    ('\u{1E07}', '\u{1E07}'),
+    ('\u{1E09}', '\u{1E09}'),
+    ('\u{1E0B}', '\u{1E0B}'),
+    ('\u{1E0D}', '\u{1E0D}'),
+    ('\u{1E0F}', '\u{1E0F}'),
+    ('\u{1E11}', '\u{1E11}'),
+    ('\u{1E13}', '\u{1E13}'),
+    ('\u{1E15}', '\u{1E15}'),
+    ('\u{1E17}', '\u{1E17}'),
+    ('\u{1E19}', '\u{1E19}'),
+    ('\u{1E1B}', '\u{1E1B}'),
+    ('\u{1E1D}', '\u{1E1D}'),
+    ('\u{1E1F}', '\u{1E1F}'),
+    ('\u{1E21}', '\u{1E21}'),
+    ('\u{1E23}', '\u{1E23}'),
+    ('\u{1E25}', '\u{1E25}'),
+    ('\u{1E27}', '\u{1E27}'),
+    ('\u{1E29}', '\u{1E29}'),
+    ('\u{1E2B}', '\u{1E2B}'),
+    ('\u{1E2D}', '\u{1E2D}'),
+    ('\u{1E2F}', '\u{1E2F}'),
+    ('\u{1E31}', '\u{1E31}'),
+    ('\u{1E33}', '\u{1E33}'),
+    ('\u{1E35}', '\u{1E35}'),
+    ('\u{1E37}', '\u{1E37}'),
+    ('\u{1E39}', '\u{1E39}'),
+    ('\u{1E3B}', '\u{1E3B}'),
+    ('\u{1E3D}', '\u{1E3D}'),
+    ('\u{1E3F}', '\u{1E3F}'),
+    ('\u{1E41}', '\u{1E41}'),
+    ('\u{1E43}', '\u{1E43}'),
+    ('\u{1E45}', '\u{1E45}'),
+    ('\u{1E47}', '\u{1E47}'),
+    ('\u{1E49}', '\u{1E49}'),
+    ('\u{1E4B}', '\u{1E4B}'),
+    ('\u{1E4D}', '\u{1E4D}'),
+    ('\u{1E4F}', '\u{1E4F}'),
+    ('\u{1E51}', '\u{1E51}'),
+    ('\u{1E53}', '\u{1E53}'),
+    ('\u{1E55}', '\u{1E55}'),
+    ('\u{1E57}', '\u{1E57}'),
+    ('\u{1E59}', '\u{1E59}'),
+    ('\u{1E5B}', '\u{1E5B}'),
+    ('\u{1E5D}', '\u{1E5D}'),
+    ('\u{1E5F}', '\u{1E5F}'),
+    ('\u{1E61}', '\u{1E61}'),
+    ('\u{1E63}', '\u{1E63}'),
+    ('\u{1E65}', '\u{1E65}'),
+    ('\u{1E67}', '\u{1E67}'),
+    ('\u{1E69}', '\u{1E69}'),
+    ('\u{1E6B}', '\u{1E6B}'),
+    ('\u{1E6D}', '\u{1E6D}'),
+    ('\u{1E6F}', '\u{1E6F}'),
+    ('\u{1E71}', '\u{1E71}'),
+    ('\u{1E73}', '\u{1E73}'),
+    ('\u{1E75}', '\u{1E75}'),
+    ('\u{1E77}', '\u{1E77}'),
+    ('\u{1E79}', '\u{1E79}'),
+    ('\u{1E7B}', '\u{1E7B}'),
+    ('\u{1E7D}', '\u{1E7D}'),
+    ('\u{1E7F}', '\u{1E7F}'),
+    ('\u{1E81}', '\u{1E81}'),
+    ('\u{1E83}', '\u{1E83}'),
+    ('\u{1E85}', '\u{1E85}'),
+    ('\u{1E87}', '\u{1E87}'),
+    ('\u{1E89}', '\u{1E89}'),
+    ('\u{1E8B}', '\u{1E8B}'),
+    ('\u{1E8D}', '\u{1E8D}'),
+    ('\u{1E8F}', '\u{1E8F}'),
+    ('\u{1E91}', '\u{1E91}'),
+    ('\u{1E93}', '\u{1E93}'),
+    ('\u{1E95}', '\u{1E9D}'),
+    ('\u{1E9F}', '\u{1E9F}'),
+    ('\u{1EA1}', '\u{1EA1}'),
+    ('\u{1EA3}', '\u{1EA3}'),
+    ('\u{1EA5}', '\u{1EA5}'),
+    ('\u{1EA7}', '\u{1EA7}'),
+    ('\u{1EA9}', '\u{1EA9}'),
+    ('\u{1EAB}', '\u{1EAB}'),
+    ('\u{1EAD}', '\u{1EAD}'),
+    ('\u{1EAF}', '\u{1EAF}'),
+    ('\u{1EB1}', '\u{1EB1}'),
+    ('\u{1EB3}', '\u{1EB3}'),
+    ('\u{1EB5}', '\u{1EB5}'),
+    ('\u{1EB7}', '\u{1EB7}'),
+    ('\u{1EB9}', '\u{1EB9}'),
+    ('\u{1EBB}', '\u{1EBB}'),
+    ('\u{1EBD}', '\u{1EBD}'),
+    ('\u{1EBF}', '\u{1EBF}'),
+    ('\u{1EC1}', '\u{1EC1}'),
+    ('\u{1EC3}', '\u{1EC3}'),
+    ('\u{1EC5}', '\u{1EC5}'),
+    ('\u{1EC7}', '\u{1EC7}'),
+    ('\u{1EC9}', '\u{1EC9}'),
+    ('\u{1ECB}', '\u{1ECB}'),
+    ('\u{1ECD}', '\u{1ECD}'),
+    ('\u{1ECF}', '\u{1ECF}'),
+    ('\u{1ED1}', '\u{1ED1}'),
+    ('\u{1ED3}', '\u{1ED3}'),
+    ('\u{1ED5}', '\u{1ED5}'),
+    ('\u{1ED7}', '\u{1ED7}'),
+    ('\u{1ED9}', '\u{1ED9}'),
+    ('\u{1EDB}', '\u{1EDB}'),
+    ('\u{1EDD}', '\u{1EDD}'),
+    ('\u{1EDF}', '\u{1EDF}'),
+    ('\u{1EE1}', '\u{1EE1}'),
+    ('\u{1EE3}', '\u{1EE3}'),
+    ('\u{1EE5}', '\u{1EE5}'),
+    ('\u{1EE7}', '\u{1EE7}'),
+    ('\u{1EE9}', '\u{1EE9}'),
+    ('\u{1EEB}', '\u{1EEB}'),
+    ('\u{1EED}', '\u{1EED}'),
+    ('\u{1EEF}', '\u{1EEF}'),
+    ('\u{1EF1}', '\u{1EF1}'),
+    ('\u{1EF3}', '\u{1EF3}'),
+    ('\u{1EF5}', '\u{1EF5}'),
+    ('\u{1EF7}', '\u{1EF7}'),
+    ('\u{1EF9}', '\u{1EF9}'),
+    ('\u{1EFB}', '\u{1EFB}'),
+    ('\u{1EFD}', '\u{1EFD}'),
+    ('\u{1EFF}', '\u{1F07}'),
+    ('\u{1F10}', '\u{1F15}'),
+    ('\u{1F20}', '\u{1F27}'),
+    ('\u{1F30}', '\u{1F37}'),
+    ('\u{1F40}', '\u{1F45}'),
+    ('\u{1F50}', '\u{1F57}'),
+    ('\u{1F60}', '\u{1F67}'),
+    ('\u{1F70}', '\u{1F7D}'),
+    ('\u{1F80}', '\u{1F87}'),
+    ('\u{1F90}', '\u{1F97}'),
+    ('\u{1FA0}', '\u{1FA7}'),
+    ('\u{1FB0}', '\u{1FB4}'),
+    ('\u{1FB6}', '\u{1FB7}'),
+    ('\u{1FBE}', '\u{1FBE}'),
+    ('\u{1FC2}', '\u{1FC4}'),
+    ('\u{1FC6}', '\u{1FC7}'),
+    ('\u{1FD0}', '\u{1FD3}'),
+    ('\u{1FD6}', '\u{1FD7}'),
+    ('\u{1FE0}', '\u{1FE7}'),
+    ('\u{1FF2}', '\u{1FF4}'),
+    ('\u{1FF6}', '\u{1FF7}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207F}', '\u{207F}'),
+    ('\u{2090}', '\u{209C}'),
+    ('\u{210A}', '\u{210A}'),
+    ('\u{210E}', '\u{210F}'),
+    ('\u{2113}', '\u{2113}'),
+    ('\u{212F}', '\u{212F}'),
+    ('\u{2134}', '\u{2134}'),
+    ('\u{2139}', '\u{2139}'),
+    ('\u{213C}', '\u{213D}'),
+    ('\u{2146}', '\u{2149}'),
+    ('\u{214E}', '\u{214E}'),
+    ('\u{2170}', '\u{217F}'),
+    ('\u{2184}', '\u{2184}'),
+    ('\u{24D0}', '\u{24E9}'),
+    ('\u{2C30}', '\u{2C5F}'),
+    ('\u{2C61}', '\u{2C61}'),
+    ('\u{2C65}', '\u{2C66}'),
+    ('\u{2C68}', '\u{2C68}'),
+    ('\u{2C6A}', '\u{2C6A}'),
+    ('\u{2C6C}', '\u{2C6C}'),
+    ('\u{2C71}', '\u{2C71}'),
+    ('\u{2C73}', '\u{2C74}'),
+    ('\u{2C76}', '\u{2C7D}'),
+    ('\u{2C81}', '\u{2C81}'),
+    ('\u{2C83}', '\u{2C83}'),
+    ('\u{2C85}', '\u{2C85}'),
+    ('\u{2C87}', '\u{2C87}'),
+    ('\u{2C89}', '\u{2C89}'),
+    ('\u{2C8B}', '\u{2C8B}'),
+    ('\u{2C8D}', '\u{2C8D}'),
+    ('\u{2C8F}', '\u{2C8F}'),
+    ('\u{2C91}', '\u{2C91}'),
+    ('\u{2C93}', '\u{2C93}'),
+    ('\u{2C95}', '\u{2C95}'),
+    ('\u{2C97}', '\u{2C97}'),
+    ('\u{2C99}', '\u{2C99}'),
+    ('\u{2C9B}', '\u{2C9B}'),
+    ('\u{2C9D}', '\u{2C9D}'),
+    ('\u{2C9F}', '\u{2C9F}'),
+    ('\u{2CA1}', '\u{2CA1}'),
+    ('\u{2CA3}', '\u{2CA3}'),
+    ('\u{2CA5}', '\u{2CA5}'),
+    ('\u{2CA7}', '\u{2CA7}'),
+    ('\u{2CA9}', '\u{2CA9}'),
+    ('\u{2CAB}', '\u{2CAB}'),
+    ('\u{2CAD}', '\u{2CAD}'),
+    ('\u{2CAF}', '\u{2CAF}'),
+    ('\u{2CB1}', '\u{2CB1}'),
+    ('\u{2CB3}', '\u{2CB3}'),
+    ('\u{2CB5}', '\u{2CB5}'),
+    ('\u{2CB7}', '\u{2CB7}'),
+    ('\u{2CB9}', '\u{2CB9}'),
+    ('\u{2CBB}', '\u{2CBB}'),
+    ('\u{2CBD}', '\u{2CBD}'),
+    ('\u{2CBF}', '\u{2CBF}'),
+    ('\u{2CC1}', '\u{2CC1}'),
+    ('\u{2CC3}', '\u{2CC3}'),
+    ('\u{2CC5}', '\u{2CC5}'),
+    ('\u{2CC7}', '\u{2CC7}'),
+    ('\u{2CC9}', '\u{2CC9}'),
+    ('\u{2CCB}', '\u{2CCB}'),
+    ('\u{2CCD}', '\u{2CCD}'),
+    ('\u{2CCF}', '\u{2CCF}'),
+    ('\u{2CD1}', '\u{2CD1}'),
+    ('\u{2CD3}', '\u{2CD3}'),
+    ('\u{2CD5}', '\u{2CD5}'),
+    ('\u{2CD7}', '\u{2CD7}'),
+    ('\u{2CD9}', '\u{2CD9}'),
+    ('\u{2CDB}', '\u{2CDB}'),
+    ('\u{2CDD}', '\u{2CDD}'),
+    ('\u{2CDF}', '\u{2CDF}'),
+    ('\u{2CE1}', '\u{2CE1}'),
+    ('\u{2CE3}', '\u{2CE4}'),
+    ('\u{2CEC}', '\u{2CEC}'),
+    ('\u{2CEE}', '\u{2CEE}'),
+    ('\u{2CF3}', '\u{2CF3}'),
+    ('\u{2D00}', '\u{2D25}'),
+    ('\u{2D27}', '\u{2D27}'),
+    ('\u{2D2D}', '\u{2D2D}'),
+    ('\u{A641}', '\u{A641}'),
+    ('\u{A643}', '\u{A643}'),
+    ('\u{A645}', '\u{A645}'),
+    ('\u{A647}', '\u{A647}'),
+    ('\u{A649}', '\u{A649}'),
+    ('\u{A64B}', '\u{A64B}'),
+    ('\u{A64D}', '\u{A64D}'),
+    ('\u{A64F}', '\u{A64F}'),
+    ('\u{A651}', '\u{A651}'),
+    ('\u{A653}', '\u{A653}'),
+    ('\u{A655}', '\u{A655}'),
+    ('\u{A657}', '\u{A657}'),
+    ('\u{A659}', '\u{A659}'),
+    ('\u{A65B}', '\u{A65B}'),
+    ('\u{A65D}', '\u{A65D}'),
+    ('\u{A65F}', '\u{A65F}'),
+    ('\u{A661}', '\u{A661}'),
+    ('\u{A663}', '\u{A663}'),
+    ('\u{A665}', '\u{A665}'),
+    ('\u{A667}', '\u{A667}'),
+    ('\u{A669}', '\u{A669}'),
+    ('\u{A66B}', '\u{A66B}'),
+    ('\u{A66D}', '\u{A66D}'),
+    ('\u{A681}', '\u{A681}'),
+    ('\u{A683}', '\u{A683}'),
+    ('\u{A685}', '\u{A685}'),
+    ('\u{A687}', '\u{A687}'),
+    ('\u{A689}', '\u{A689}'),
+    ('\u{A68B}', '\u{A68B}'),
+    ('\u{A68D}', '\u{A68D}'),
+    ('\u{A68F}', '\u{A68F}'),
+    ('\u{A691}', '\u{A691}'),
+    ('\u{A693}', '\u{A693}'),
+    ('\u{A695}', '\u{A695}'),
+    ('\u{A697}', '\u{A697}'),
+    ('\u{A699}', '\u{A699}'),
+    ('\u{A69B}', '\u{A69D}'),
+    ('\u{A723}', '\u{A723}'),
+    ('\u{A725}', '\u{A725}'),
+    ('\u{A727}', '\u{A727}'),
+    ('\u{A729}', '\u{A729}'),
+    ('\u{A72B}', '\u{A72B}'),
+    ('\u{A72D}', '\u{A72D}'),
+    ('\u{A72F}', '\u{A731}'),
+    ('\u{A733}', '\u{A733}'),
+    ('\u{A735}', '\u{A735}'),
+    ('\u{A737}', '\u{A737}'),
+    ('\u{A739}', '\u{A739}'),
+    ('\u{A73B}', '\u{A73B}'),
+    ('\u{A73D}', '\u{A73D}'),
+    ('\u{A73F}', '\u{A73F}'),
+    ('\u{A741}', '\u{A741}'),
+    ('\u{A743}', '\u{A743}'),
+    ('\u{A745}', '\u{A745}'),
+    ('\u{A747}', '\u{A747}'),
+    ('\u{A749}', '\u{A749}'),
+    ('\u{A74B}', '\u{A74B}'),
+    ('\u{A74D}', '\u{A74D}'),
+    ('\u{A74F}', '\u{A74F}'),
+    ('\u{A751}', '\u{A751}'),
+    ('\u{A753}', '\u{A753}'),
+    ('\u{A755}', '\u{A755}'),
+    ('\u{A757}', '\u{A757}'),
+    ('\u{A759}', '\u{A759}'),
+    ('\u{A75B}', '\u{A75B}'),
+    ('\u{A75D}', '\u{A75D}'),
+    ('\u{A75F}', '\u{A75F}'),
+    ('\u{A761}', '\u{A761}'),
+    ('\u{A763}', '\u{A763}'),
+    ('\u{A765}', '\u{A765}'),
+    ('\u{A767}', '\u{A767}'),
+    ('\u{A769}', '\u{A769}'),
+    ('\u{A76B}', '\u{A76B}'),
+    ('\u{A76D}', '\u{A76D}'),
+    ('\u{A76F}', '\u{A778}'),
+    ('\u{A77A}', '\u{A77A}'),
+    ('\u{A77C}', '\u{A77C}'),
+    ('\u{A77F}', '\u{A77F}'),
+    ('\u{A781}', '\u{A781}'),
+    ('\u{A783}', '\u{A783}'),
+    ('\u{A785}', '\u{A785}'),
+    ('\u{A787}', '\u{A787}'),
+    ('\u{A78C}', '\u{A78C}'),
+    ('\u{A78E}', '\u{A78E}'),
+    ('\u{A791}', '\u{A791}'),
+    ('\u{A793}', '\u{A795}'),
+    ('\u{A797}', '\u{A797}'),
+    ('\u{A799}', '\u{A799}'),
+    ('\u{A79B}', '\u{A79B}'),
+    ('\u{A79D}', '\u{A79D}'),
+    ('\u{A79F}', '\u{A79F}'),
+    ('\u{A7A1}', '\u{A7A1}'),
+    ('\u{A7A3}', '\u{A7A3}'),
+    ('\u{A7A5}', '\u{A7A5}'),
+    ('\u{A7A7}', '\u{A7A7}'),
+    ('\u{A7A9}', '\u{A7A9}'),
+    ('\u{A7AF}', '\u{A7AF}'),
+    ('\u{A7B5}', '\u{A7B5}'),
+    ('\u{A7B7}', '\u{A7B7}'),
+    ('\u{A7B9}', '\u{A7B9}'),
+    ('\u{A7BB}', '\u{A7BB}'),
+    ('\u{A7BD}', '\u{A7BD}'),
+    ('\u{A7BF}', '\u{A7BF}'),
+    ('\u{A7C1}', '\u{A7C1}'),
+    ('\u{A7C3}', '\u{A7C3}'),
+    ('\u{A7C8}', '\u{A7C8}'),
+    ('\u{A7CA}', '\u{A7CA}'),
+    ('\u{A7D1}', '\u{A7D1}'),
+    ('\u{A7D3}', '\u{A7D3}'),
+    ('\u{A7D5}', '\u{A7D5}'),
+    ('\u{A7D7}', '\u{A7D7}'),
+    ('\u{A7D9}', '\u{A7D9}'),
+    ('\u{A7F6}', '\u{A7F6}'),
+    ('\u{A7F8}', '\u{A7FA}'),
+    ('\u{AB30}', '\u{AB5A}'),
+    ('\u{AB5C}', '\u{AB68}'),
+    ('\u{AB70}', '\u{ABBF}'),
+    ('\u{FB00}', '\u{FB06}'),
+    ('\u{FB13}', '\u{FB17}'),
+    ('\u{FF41}', '\u{FF5A}'),
+    ('\u{10428}', '\u{1044F}'),
+    ('\u{104D8}', '\u{104FB}'),
+    ('\u{10597}', '\u{105A1}'),
+    ('\u{105A3}', '\u{105B1}'),
+    ('\u{105B3}', '\u{105B9}'),
+    ('\u{105BB}', '\u{105BC}'),
+    ('\u{10780}', '\u{10780}'),
+    ('\u{10783}', '\u{10785}'),
+    ('\u{10787}', '\u{107B0}'),
+    ('\u{107B2}', '\u{107BA}'),
+    ('\u{10CC0}', '\u{10CF2}'),
+    ('\u{118C0}', '\u{118DF}'),
+    ('\u{16E60}', '\u{16E7F}'),
+    ('\u{1D41A}', '\u{1D433}'),
+    ('\u{1D44E}', '\u{1D454}'),
+    ('\u{1D456}', '\u{1D467}'),
+    ('\u{1D482}', '\u{1D49B}'),
+    ('\u{1D4B6}', '\u{1D4B9}'),
+    ('\u{1D4BB}', '\u{1D4BB}'),
+    ('\u{1D4BD}', '\u{1D4C3}'),
+    ('\u{1D4C5}', '\u{1D4CF}'),
+    ('\u{1D4EA}', '\u{1D503}'),
+    ('\u{1D51E}', '\u{1D537}'),
+    ('\u{1D552}', '\u{1D56B}'),
+    ('\u{1D586}', '\u{1D59F}'),
+    ('\u{1D5BA}', '\u{1D5D3}'),
+    ('\u{1D5EE}', '\u{1D607}'),
+    ('\u{1D622}', '\u{1D63B}'),
+    ('\u{1D656}', '\u{1D66F}'),
+    ('\u{1D68A}', '\u{1D6A5}'),
+    ('\u{1D6C2}', '\u{1D6DA}'),
+    ('\u{1D6DC}', '\u{1D6E1}'),
+    ('\u{1D6FC}', '\u{1D714}'),
+    ('\u{1D716}', '\u{1D71B}'),
+    ('\u{1D736}', '\u{1D74E}'),
+    ('\u{1D750}', '\u{1D755}'),
+    ('\u{1D770}', '\u{1D788}'),
+    ('\u{1D78A}', '\u{1D78F}'),
+    ('\u{1D7AA}', '\u{1D7C2}'),
+    ('\u{1D7C4}', '\u{1D7C9}'),
+    ('\u{1D7CB}', '\u{1D7CB}'),
+    ('\u{1DF00}', '\u{1DF09}'),
+    ('\u{1DF0B}', '\u{1DF1E}'),
+    ('\u{1E922}', '\u{1E943}'),
+];
+
+/// Ranges of chars with the `Math` property, sorted by code point.
+pub(crate) static MATH: &[(char, char)] = &[
+    ('\u{2B}', '\u{2B}'),
+    ('\u{3C}', '\u{3E}'),
+    ('\u{5E}', '\u{5E}'),
+    ('\u{7C}', '\u{7C}'),
+    ('\u{7E}', '\u{7E}'),
+    ('\u{AC}', '\u{AC}'),
+    ('\u{B1}', '\u{B1}'),
+    ('\u{D7}', '\u{D7}'),
+    ('\u{F7}', '\u{F7}'),
+    ('\u{3D0}', '\u{3D2}'),
+    ('\u{3D5}', '\u{3D5}'),
+    ('\u{3F0}', '\u{3F1}'),
+    ('\u{3F4}', '\u{3F6}'),
+    ('\u{606}', '\u{608}'),
+    ('\u{2016}', '\u{2016}'),
+    ('\u{2032}', '\u{2034}'),
+    ('\u{2040}', '\u{2040}'),
+    ('\u{2044}', '\u{2044}'),
+    ('\u{2052}', '\u{2052}'),
+    ('\u{2061}', '\u{2064}'),
+    ('\u{207A}', '\u{207E}'),
+    ('\u{208A}', '\u{208E}'),
+    ('\u{20D0}', '\u{20DC}'),
+    ('\u{20E1}', '\u{20E1}'),
+    ('\u{20E5}', '\u{20E6}'),
+    ('\u{20EB}', '\u{20EF}'),
+    ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'),
+    ('\u{210A}', '\u{2113}'),
+    ('\u{2115}', '\u{2115}'),
+    ('\u{2118}', '\u{211D}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2128}', '\u{2129}'),
+    ('\u{212C}', '\u{212D}'),
+    ('\u{212F}', '\u{2131}'),
+    ('\u{2133}', '\u{2138}'),
+    ('\u{213C}', '\u{2149}'),
+    ('\u{214B}', '\u{214B}'),
+    ('\u{2190}', '\u{21A7}'),
+    ('\u{21A9}', '\u{21AE}'),
+    ('\u{21B0}', '\u{21B1}'),
+    ('\u{21B6}', '\u{21B7}'),
+    ('\u{21BC}', '\u{21DB}'),
+    ('\u{21DD}', '\u{21DD}'),
+    ('\u{21E4}', '\u{21E5}'),
+    ('\u{21F4}', '\u{22FF}'),
+    ('\u{2308}', '\u{230B}'),
+    ('\u{2320}', '\u{2321}'),
+    ('\u{237C}', '\u{237C}'),
+    ('\u{239B}', '\u{23B5}'),
+    ('\u{23B7}', '\u{23B7}'),
+    ('\u{23D0}', '\u{23D0}'),
+    ('\u{23DC}', '\u{23E2}'),
+    ('\u{25A0}', '\u{25A1}'),
+    ('\u{25AE}', '\u{25B7}'),
+    ('\u{25BC}', '\u{25C1}'),
+    ('\u{25C6}', '\u{25C7}'),
+    ('\u{25CA}', '\u{25CB}'),
+    ('\u{25CF}', '\u{25D3}'),
+    ('\u{25E2}', '\u{25E2}'),
+    ('\u{25E4}', '\u{25E4}'),
+    ('\u{25E7}', '\u{25EC}'),
+    ('\u{25F8}', '\u{25FF}'),
+    ('\u{2605}', '\u{2606}'),
+    ('\u{2640}', '\u{2640}'),
+    ('\u{2642}', '\u{2642}'),
+    ('\u{2660}', '\u{2663}'),
+    ('\u{266D}', '\u{266F}'),
+    ('\u{27C0}', '\u{27FF}'),
+    ('\u{2900}', '\u{2AFF}'),
+    ('\u{2B30}', '\u{2B44}'),
+    ('\u{2B47}', '\u{2B4C}'),
+    ('\u{FB29}', '\u{FB29}'),
+    ('\u{FE61}', '\u{FE66}'),
+    ('\u{FE68}', '\u{FE68}'),
+    ('\u{FF0B}', '\u{FF0B}'),
+    ('\u{FF1C}', '\u{FF1E}'),
+    ('\u{FF3C}', '\u{FF3C}'),
+    ('\u{FF3E}', '\u{FF3E}'),
+    ('\u{FF5C}', '\u{FF5C}'),
+    ('\u{FF5E}', '\u{FF5E}'),
+    ('\u{FFE2}', '\u{FFE2}'),
+    ('\u{FFE9}', '\u{FFEC}'),
+    ('\u{1D400}', '\u{1D454}'),
+    ('\u{1D456}', '\u{1D49C}'),
+    ('\u{1D49E}', '\u{1D49F}'),
+    ('\u{1D4A2}', '\u{1D4A2}'),
+    ('\u{1D4A5}', '\u{1D4A6}'),
+    ('\u{1D4A9}', '\u{1D4AC}'),
+    ('\u{1D4AE}', '\u{1D4B9}'),
+    ('\u{1D4BB}', '\u{1D4BB}'),
+    ('\u{1D4BD}', '\u{1D4C3}'),
+    ('\u{1D4C5}', '\u{1D505}'),
+    ('\u{1D507}', '\u{1D50A}'),
+    ('\u{1D50D}', '\u{1D514}'),
+    ('\u{1D516}', '\u{1D51C}'),
+    ('\u{1D51E}', '\u{1D539}'),
+    ('\u{1D53B}', '\u{1D53E}'),
+    ('\u{1D540}', '\u{1D544}'),
+    ('\u{1D546}', '\u{1D546}'),
+    ('\u{1D54A}', '\u{1D550}'),
+    ('\u{1D552}', '\u{1D6A5}'),
+    ('\u{1D6A8}', '\u{1D7CB}'),
+    ('\u{1D7CE}', '\u{1D7FF}'),
+    ('\u{1EE00}', '\u{1EE03}'),
+    ('\u{1EE05}', '\u{1EE1F}'),
+    ('\u{1EE21}', '\u{1EE22}'),
+    ('\u{1EE24}', '\u{1EE24}'),
+    ('\u{1EE27}', '\u{1EE27}'),
+    ('\u{1EE29}', '\u{1EE32}'),
+    ('\u{1EE34}', '\u{1EE37}'),
+    ('\u{1EE39}', '\u{1EE39}'),
+    ('\u{1EE3B}', '\u{1EE3B}'),
+    ('\u{1EE42}', '\u{1EE42}'),
+    ('\u{1EE47}', '\u{1EE47}'),
+    ('\u{1EE49}', '\u{1EE49}'),
+    ('\u{1EE4B}', '\u{1EE4B}'),
+    ('\u{1EE4D}', '\u{1EE4F}'),
+    ('\u{1EE51}', '\u{1EE52}'),
+    ('\u{1EE54}', '\u{1EE54}'),
+    ('\u{1EE57}', '\u{1EE57}'),
+    ('\u{1EE59}', '\u{1EE59}'),
+    ('\u{1EE5B}', '\u{1EE5B}'),
+    ('\u{1EE5D}', '\u{1EE5D}'),
+    ('\u{1EE5F}', '\u{1EE5F}'),
+    ('\u{1EE61}', '\u{1EE62}'),
+    ('\u{1EE64}', '\u{1EE64}'),
+    ('\u{1EE67}', '\u{1EE6A}'),
+    ('\u{1EE6C}', '\u{1EE72}'),
+    ('\u{1EE74}', '\u{1EE77}'),
+    ('\u{1EE79}', '\u{1EE7C}'),
+    ('\u{1EE7E}', '\u{1EE7E}'),
+    ('\u{1EE80}', '\u{1EE89}'),
+    ('\u{1EE8B}', '\u{1EE9B}'),
+    ('\u{1EEA1}', '\u{1EEA3}'),
+    ('\u{1EEA5}', '\u{1EEA9}'),
+    ('\u{1EEAB}', '\u{1EEBB}'),
+    ('\u{1EEF0}', '\u{1EEF1}'),
+];
+
+/// Ranges of chars with the `Noncharacter_Code_Point` property, sorted by code point.
+pub(crate) static NONCHARACTER_CODE_POINT: &[(char, char)] = &[
+    ('\u{FDD0}', '\u{FDEF}'),
+    ('\u{FFFE}', '\u{FFFF}'),
+    ('\u{1FFFE}', '\u{1FFFF}'),
+    ('\u{2FFFE}', '\u{2FFFF}'),
+    ('\u{3FFFE}', '\u{3FFFF}'),
+    ('\u{4FFFE}', '\u{4FFFF}'),
+    ('\u{5FFFE}', '\u{5FFFF}'),
+    ('\u{6FFFE}', '\u{6FFFF}'),
+    ('\u{7FFFE}', '\u{7FFFF}'),
+    ('\u{8FFFE}', '\u{8FFFF}'),
+    ('\u{9FFFE}', '\u{9FFFF}'),
+    ('\u{AFFFE}', '\u{AFFFF}'),
+    ('\u{BFFFE}', '\u{BFFFF}'),
+    ('\u{CFFFE}', '\u{CFFFF}'),
+    ('\u{DFFFE}', '\u{DFFFF}'),
+    ('\u{EFFFE}', '\u{EFFFF}'),
+    ('\u{FFFFE}', '\u{FFFFF}'),
+    ('\u{10FFFE}', '\u{10FFFF}'),
+];
+
+/// Ranges of chars with the `Pattern_Syntax` property, sorted by code point.
+pub(crate) static PATTERN_SYNTAX: &[(char, char)] = &[
+    ('\u{21}', '\u{2F}'),
+    ('\u{3A}', '\u{40}'),
+    ('\u{5B}', '\u{5E}'),
+    ('\u{60}', '\u{60}'),
+    ('\u{7B}', '\u{7E}'),
+    ('\u{A1}', '\u{A7}'),
+    ('\u{A9}', '\u{A9}'),
+    ('\u{AB}', '\u{AC}'),
+    ('\u{AE}', '\u{AE}'),
+    ('\u{B0}', '\u{B1}'),
+    ('\u{B6}', '\u{B6}'),
+    ('\u{BB}', '\u{BB}'),
+    ('\u{BF}', '\u{BF}'),
+    ('\u{D7}', '\u{D7}'),
+    ('\u{F7}', '\u{F7}'),
+    ('\u{2010}', '\u{2027}'),
+    ('\u{2030}', '\u{203E}'),
+    ('\u{2041}', '\u{2053}'),
+    ('\u{2055}', '\u{205E}'),
+    ('\u{2190}', '\u{245F}'),
+    ('\u{2500}', '\u{2775}'),
+    ('\u{2794}', '\u{2BFF}'),
+    ('\u{2E00}', '\u{2E7F}'),
+    ('\u{3001}', '\u{3003}'),
+    ('\u{3008}', '\u{3020}'),
+    ('\u{3030}', '\u{3030}'),
+    ('\u{FD3E}', '\u{FD3F}'),
+    ('\u{FE45}', '\u{FE46}'),
+];
+
+/// Ranges of chars with the `Pattern_White_Space` property, sorted by code point.
+pub(crate) static PATTERN_WHITE_SPACE: &[(char, char)] = &[
+    ('\u{9}', '\u{D}'),
+    ('\u{20}', '\u{20}'),
+    ('\u{85}', '\u{85}'),
+    ('\u{200E}', '\u{200F}'),
+    ('\u{2028}', '\u{2029}'),
+];
+
+/// Ranges of chars with the `Quotation_Mark` property, sorted by code point.
+pub(crate) static QUOTATION_MARK: &[(char, char)] = &[
+    ('\u{22}', '\u{22}'),
+    ('\u{27}', '\u{27}'),
+    ('\u{AB}', '\u{AB}'),
+    ('\u{BB}', '\u{BB}'),
+    ('\u{2018}', '\u{201F}'),
+    ('\u{2039}', '\u{203A}'),
+    ('\u{2E42}', '\u{2E42}'),
+    ('\u{300C}', '\u{300F}'),
+    ('\u{301D}', '\u{301F}'),
+    ('\u{FE41}', '\u{FE44}'),
+    ('\u{FF02}', '\u{FF02}'),
+    ('\u{FF07}', '\u{FF07}'),
+    ('\u{FF62}', '\u{FF63}'),
+];
+
+/// Ranges of chars with the `Regional_Indicator` property, sorted by code point.
+pub(crate) static REGIONAL_INDICATOR: &[(char, char)] = &[
+    ('\u{1F1E6}', '\u{1F1FF}'),
+];
+
+/// Ranges of chars with the `Uppercase` property, sorted by code point.
+pub(crate) static UPPERCASE: &[(char, char)] = &[
+    ('\u{41}', '\u{5A}'),
+    ('\u{C0}', '\u{D6}'),
+    ('\u{D8}', '\u{DE}'),
+    ('\u{100}', '\u{100}'),
+    ('\u{102}', '\u{102}'),
+    ('\u{104}', '\u{104}'),
+    ('\u{106}', '\u{106}'),
+    ('\u{108}', '\u{108}'),
+    ('\u{10A}', '\u{10A}'),
+    ('\u{10C}', '\u{10C}'),
+    ('\u{10E}', '\u{10E}'),
+    ('\u{110}', '\u{110}'),
+    ('\u{112}', '\u{112}'),
+    ('\u{114}', '\u{114}'),
+    ('\u{116}', '\u{116}'),
+    ('\u{118}', '\u{118}'),
+    ('\u{11A}', '\u{11A}'),
+    ('\u{11C}', '\u{11C}'),
+    ('\u{11E}', '\u{11E}'),
+    ('\u{120}', '\u{120}'),
+    ('\u{122}', '\u{122}'),
+    ('\u{124}', '\u{124}'),
+    ('\u{126}', '\u{126}'),
+    ('\u{128}', '\u{128}'),
+    ('\u{12A}', '\u{12A}'),
+    ('\u{12C}', '\u{12C}'),
+    ('\u{12E}', '\u{12E}'),
+    ('\u{130}', '\u{130}'),
+    ('\u{132}', '\u{132}'),
+    ('\u{134}', '\u{134}'),
+    ('\u{136}', '\u{136}'),
+    ('\u{139}', '\u{139}'),
+    ('\u{13B}', '\u{13B}'),
+    ('\u{13D}', '\u{13D}'),
+    ('\u{13F}', '\u{13F}'),
+    ('\u{141}', '\u{141}'),
+    ('\u{143}', '\u{143}'),
+    ('\u{145}', '\u{145}'),
+    ('\u{147}', '\u{147}'),
+    ('\u{14A}', '\u{14A}'),
+    ('\u{14C}', '\u{14C}'),
+    ('\u{14E}', '\u{14E}'),
+    ('\u{150}', '\u{150}'),
+    ('\u{152}', '\u{152}'),
+    ('\u{154}', '\u{154}'),
+    ('\u{156}', '\u{156}'),
+    ('\u{158}', '\u{158}'),
+    ('\u{15A}', '\u{15A}'),
+    ('\u{15C}', '\u{15C}'),
+    ('\u{15E}', '\u{15E}'),
+    ('\u{160}', '\u{160}'),
+    ('\u{162}', '\u{162}'),
+    ('\u{164}', '\u{164}'),
+    ('\u{166}', '\u{166}'),
+    ('\u{168}', '\u{168}'),
+    ('\u{16A}', '\u{16A}'),
+    ('\u{16C}', '\u{16C}'),
+    ('\u{16E}', '\u{16E}'),
+    ('\u{170}', '\u{170}'),
+    ('\u{172}', '\u{172}'),
+    ('\u{174}', '\u{174}'),
+    ('\u{176}', '\u{176}'),
+    ('\u{178}', '\u{179}'),
+    ('\u{17B}', '\u{17B}'),
+    ('\u{17D}', '\u{17D}'),
+    ('\u{181}', '\u{182}'),
+    ('\u{184}', '\u{184}'),
+    ('\u{186}', '\u{187}'),
+    ('\u{189}', '\u{18B}'),
+    ('\u{18E}', '\u{191}'),
+    ('\u{193}', '\u{194}'),
+    ('\u{196}', '\u{198}'),
+    ('\u{19C}', '\u{19D}'),
+    ('\u{19F}', '\u{1A0}'),
+    ('\u{1A2}', '\u{1A2}'),
+    ('\u{1A4}', '\u{1A4}'),
+    ('\u{1A6}', '\u{1A7}'),
+    ('\u{1A9}', '\u{1A9}'),
+    ('\u{1AC}', '\u{1AC}'),
+    ('\u{1AE}', '\u{1AF}'),
+    ('\u{1B1}', '\u{1B3}'),
+    ('\u{1B5}', '\u{1B5}'),
+    ('\u{1B7}', '\u{1B8}'),
+    ('\u{1BC}', '\u{1BC}'),
+    ('\u{1C4}', '\u{1C4}'),
+    ('\u{1C7}', '\u{1C7}'),
+    ('\u{1CA}', '\u{1CA}'),
+    ('\u{1CD}', '\u{1CD}'),
+    ('\u{1CF}', '\u{1CF}'),
+    ('\u{1D1}', '\u{1D1}'),
+    ('\u{1D3}', '\u{1D3}'),
+    ('\u{1D5}', '\u{1D5}'),
+    ('\u{1D7}', '\u{1D7}'),
+    ('\u{1D9}', '\u{1D9}'),
+    ('\u{1DB}', '\u{1DB}'),
+    ('\u{1DE}', '\u{1DE}'),
+    ('\u{1E0}', '\u{1E0}'),
+    ('\u{1E2}', '\u{1E2}'),
+    ('\u{1E4}', '\u{1E4}'),
+    ('\u{1E6}', '\u{1E6}'),
+    ('\u{1E8}', '\u{1E8}'),
+    ('\u{1EA}', '\u{1EA}'),
+    ('\u{1EC}', '\u{1EC}'),
+    ('\u{1EE}', '\u{1EE}'),
+    ('\u{1F1}', '\u{1F1}'),
+    ('\u{1F4}', '\u{1F4}'),
+    ('\u{1F6}', '\u{1F8}'),
+    ('\u{1FA}', '\u{1FA}'),
+    ('\u{1FC}', '\u{1FC}'),
+    ('\u{1FE}', '\u{1FE}'),
+    ('\u{200}', '\u{200}'),
+    ('\u{202}', '\u{202}'),
+    ('\u{204}', '\u{204}'),
+    ('\u{206}', '\u{206}'),
+    ('\u{208}', '\u{208}'),
+    ('\u{20A}', '\u{20A}'),
+    ('\u{20C}', '\u{20C}'),
+    ('\u{20E}', '\u{20E}'),
+    ('\u{210}', '\u{210}'),
+    ('\u{212}', '\u{212}'),
+    ('\u{214}', '\u{214}'),
+    ('\u{216}', '\u{216}'),
+    ('\u{218}', '\u{218}'),
+    ('\u{21A}', '\u{21A}'),
+    ('\u{21C}', '\u{21C}'),
+    ('\u{21E}', '\u{21E}'),
+    ('\u{220}', '\u{220}'),
+    ('\u{222}', '\u{222}'),
+    ('\u{224}', '\u{224}'),
+    ('\u{226}', '\u{226}'),
+    ('\u{228}', '\u{228}'),
+    ('\u{22A}', '\u{22A}'),
+    ('\u{22C}', '\u{22C}'),
+    ('\u{22E}', '\u{22E}'),
+    ('\u{230}', '\u{230}'),
+    ('\u{232}', '\u{232}'),
+    ('\u{23A}', '\u{23B}'),
+    ('\u{23D}', '\u{23E}'),
+    ('\u{241}', '\u{241}'),
+    ('\u{243}', '\u{246}'),
+    ('\u{248}', '\u{248}'),
+    ('\u{24A}', '\u{24A}'),
+    ('\u{24C}', '\u{24C}'),
+    ('\u{24E}', '\u{24E}'),
+    ('\u{370}', '\u{370}'),
+    ('\u{372}', '\u{372}'),
+    ('\u{376}', '\u{376}'),
+    ('\u{37F}', '\u{37F}'),
+    ('\u{386}', '\u{386}'),
+    ('\u{388}', '\u{38A}'),
+    ('\u{38C}', '\u{38C}'),
+    ('\u{38E}', '\u{38F}'),
+    ('\u{391}', '\u{3A1}'),
+    ('\u{3A3}', '\u{3AB}'),
+    ('\u{3CF}', '\u{3CF}'),
+    ('\u{3D2}', '\u{3D4}'),
+    ('\u{3D8}', '\u{3D8}'),
+    ('\u{3DA}', '\u{3DA}'),
+    ('\u{3DC}', '\u{3DC}'),
+    ('\u{3DE}', '\u{3DE}'),
+    ('\u{3E0}', '\u{3E0}'),
+    ('\u{3E2}', '\u{3E2}'),
+    ('\u{3E4}', '\u{3E4}'),
+    ('\u{3E6}', '\u{3E6}'),
+    ('\u{3E8}', '\u{3E8}'),
+    ('\u{3EA}', '\u{3EA}'),
+    ('\u{3EC}', '\u{3EC}'),
+    ('\u{3EE}', '\u{3EE}'),
+    ('\u{3F4}', '\u{3F4}'),
+    ('\u{3F7}', '\u{3F7}'),
+    ('\u{3F9}', '\u{3FA}'),
+    ('\u{3FD}', '\u{42F}'),
+    ('\u{460}', '\u{460}'),
+    ('\u{462}', '\u{462}'),
+    ('\u{464}', '\u{464}'),
+    ('\u{466}', '\u{466}'),
+    ('\u{468}', '\u{468}'),
+    ('\u{46A}', '\u{46A}'),
+    ('\u{46C}', '\u{46C}'),
+    ('\u{46E}', '\u{46E}'),
+    ('\u{470}', '\u{470}'),
+    ('\u{472}', '\u{472}'),
+    ('\u{474}', '\u{474}'),
+    ('\u{476}', '\u{476}'),
+    ('\u{478}', '\u{478}'),
+    ('\u{47A}', '\u{47A}'),
+    ('\u{47C}', '\u{47C}'),
+    ('\u{47E}', '\u{47E}'),
+    ('\u{480}', '\u{480}'),
+    ('\u{48A}', '\u{48A}'),
+    ('\u{48C}', '\u{48C}'),
+    ('\u{48E}', '\u{48E}'),
+    ('\u{490}', '\u{490}'),
+    ('\u{492}', '\u{492}'),
+    ('\u{494}', '\u{494}'),
+    ('\u{496}', '\u{496}'),
+    ('\u{498}', '\u{498}'),
+    ('\u{49A}', '\u{49A}'),
+    ('\u{49C}', '\u{49C}'),
+    ('\u{49E}', '\u{49E}'),
+    ('\u{4A0}', '\u{4A0}'),
+    ('\u{4A2}', '\u{4A2}'),
+    ('\u{4A4}', '\u{4A4}'),
+    ('\u{4A6}', '\u{4A6}'),
+    ('\u{4A8}', '\u{4A8}'),
+    ('\u{4AA}', '\u{4AA}'),
+    ('\u{4AC}', '\u{4AC}'),
+    ('\u{4AE}', '\u{4AE}'),
+    ('\u{4B0}', '\u{4B0}'),
+    ('\u{4B2}', '\u{4B2}'),
+    ('\u{4B4}', '\u{4B4}'),
+    ('\u{4B6}', '\u{4B6}'),
+    ('\u{4B8}', '\u{4B8}'),
+    ('\u{4BA}', '\u{4BA}'),
+    ('\u{4BC}', '\u{4BC}'),
+    ('\u{4BE}', '\u{4BE}'),
+    ('\u{4C0}', '\u{4C1}'),
+    ('\u{4C3}', '\u{4C3}'),
+    ('\u{4C5}', '\u{4C5}'),
+    ('\u{4C7}', '\u{4C7}'),
+    ('\u{4C9}', '\u{4C9}'),
+    ('\u{4CB}', '\u{4CB}'),
+    ('\u{4CD}', '\u{4CD}'),
+    ('\u{4D0}', '\u{4D0}'),
+    ('\u{4D2}', '\u{4D2}'),
+    ('\u{4D4}', '\u{4D4}'),
+    ('\u{4D6}', '\u{4D6}'),
+    ('\u{4D8}', '\u{4D8}'),
+    ('\u{4DA}', '\u{4DA}'),
+    ('\u{4DC}', '\u{4DC}'),
+    ('\u{4DE}', '\u{4DE}'),
+    ('\u{4E0}', '\u{4E0}'),
+    ('\u{4E2}', '\u{4E2}'),
+    ('\u{4E4}', '\u{4E4}'),
+    ('\u{4E6}', '\u{4E6}'),
+    ('\u{4E8}', '\u{4E8}'),
+    ('\u{4EA}', '\u{4EA}'),
+    ('\u{4EC}', '\u{4EC}'),
+    ('\u{4EE}', '\u{4EE}'),
+    ('\u{4F0}', '\u{4F0}'),
+    ('\u{4F2}', '\u{4F2}'),
+    ('\u{4F4}', '\u{4F4}'),
+    ('\u{4F6}', '\u{4F6}'),
+    ('\u{4F8}', '\u{4F8}'),
+    ('\u{4FA}', '\u{4FA}'),
+    ('\u{4FC}', '\u{4FC}'),
+    ('\u{4FE}', '\u{4FE}'),
+    ('\u{500}', '\u{500}'),
+    ('\u{502}', '\u{502}'),
+    ('\u{504}', '\u{504}'),
+    ('\u{506}', '\u{506}'),
+    ('\u{508}', '\u{508}'),
+    ('\u{50A}', '\u{50A}'),
+    ('\u{50C}', '\u{50C}'),
+    ('\u{50E}', '\u{50E}'),
+    ('\u{510}', '\u{510}'),
+    ('\u{512}', '\u{512}'),
+    ('\u{514}', '\u{514}'),
+    ('\u{516}', '\u{516}'),
+    ('\u{518}', '\u{518}'),
+    ('\u{51A}', '\u{51A}'),
+    ('\u{51C}', '\u{51C}'),
+    ('\u{51E}', '\u{51E}'),
+    ('\u{520}', '\u{520}'),
+    ('\u{522}', '\u{522}'),
+    ('\u{524}', '\u{524}'),
+    ('\u{526}', '\u{526}'),
+    ('\u{528}', '\u{528}'),
+    ('\u{52A}', '\u{52A}'),
+    ('\u{52C}', '\u{52C}'),
+    ('\u{52E}', '\u{52E}'),
+    ('\u{531}', '\u{556}'),
+    ('\u{10A0}', '\u{10C5}'),
+    ('\u{10C7}', '\u{10C7}'),
+    ('\u{10CD}', '\u{10CD}'),
+    ('\u{13A0}', '\u{13F5}'),
+    ('\u{1C90}', '\u{1CBA}'),
+    ('\u{1CBD}', '\u{1CBF}'),
+    ('\u{1E00}', '\u{1E00}'),
+    ('\u{1E02}', '\u{1E02}'),
+    ('\u{1E04}', '\u{1E04}'),
+    ('\u{1E06}', '\u{1E06}'),
+    ('\u{1E08}', '\u{1E08}'),
+    ('\u{1E0A}', '\u{1E0A}'),
+    ('\u{1E0C}', '\u{1E0C}'),
+    ('\u{1E0E}', '\u{1E0E}'),
+    ('\u{1E10}', '\u{1E10}'),
+    ('\u{1E12}', '\u{1E12}'),
+    ('\u{1E14}', '\u{1E14}'),
+    ('\u{1E16}', '\u{1E16}'),
+    ('\u{1E18}', '\u{1E18}'),
+    ('\u{1E1A}', '\u{1E1A}'),
+    ('\u{1E1C}', '\u{1E1C}'),
+    ('\u{1E1E}', '\u{1E1E}'),
+    ('\u{1E20}', '\u{1E20}'),
+    ('\u{1E22}', '\u{1E22}'),
+    ('\u{1E24}', '\u{1E24}'),
+    ('\u{1E26}', '\u{1E26}'),
+    ('\u{1E28}', '\u{1E28}'),
+    ('\u{1E2A}', '\u{1E2A}'),
+    ('\u{1E2C}', '\u{1E2C}'),
+    ('\u{1E2E}', '\u{1E2E}'),
+    ('\u{1E30}', '\u{1E30}'),
+    ('\u{1E32}', '\u{1E32}'),
+    ('\u{1E34}', '\u{1E34}'),
+    ('\u{1E36}', '\u{1E36}'),
+    ('\u{1E38}', '\u{1E38}'),
+    ('\u{1E3A}', '\u{1E3A}'),
+    ('\u{1E3C}', '\u{1E3C}'),
+    ('\u{1E3E}', '\u{1E3E}'),
+    ('\u{1E40}', '\u{1E40}'),
+    ('\u{1E42}', '\u{1E42}'),
+    ('\u{1E44}', '\u{1E44}'),
+    ('\u{1E46}', '\u{1E46}'),
+    ('\u{1E48}', '\u{1E48}'),
+    ('\u{1E4A}', '\u{1E4A}'),
+    ('\u{1E4C}', '\u{1E4C}'),
+    ('\u{1E4E}', '\u{1E4E}'),
+    ('\u{1E50}', '\u{1E50}'),
+    ('\u{1E52}', '\u{1E52}'),
+    ('\u{1E54}', '\u{1E54}'),
+    ('\u{1E56}', '\u{1E56}'),
+    ('\u{1E58}', '\u{1E58}'),
+    ('\u{1E5A}', '\u{1E5A}'),
+    ('\u{1E5C}', '\u{1E5C}'),
+    ('\u{1E5E}', '\u{1E5E}'),
+    ('\u{1E60}', '\u{1E60}'),
+    ('\u{1E62}', '\u{1E62}'),
+    ('\u{1E64}', '\u{1E64}'),
+    ('\u{1E66}', '\u{1E66}'),
+    ('\u{1E68}', '\u{1E68}'),
+    ('\u{1E6A}', '\u{1E6A}'),
+    ('\u{1E6C}', '\u{1E6C}'),
+    ('\u{1E6E}', '\u{1E6E}'),
+    ('\u{1E70}', '\u{1E70}'),
+    ('\u{1E72}', '\u{1E72}'),
+    ('\u{1E74}', '\u{1E74}'),
+    ('\u{1E76}', '\u{1E76}'),
+    ('\u{1E78}', '\u{1E78}'),
+    ('\u{1E7A}', '\u{1E7A}'),
+    ('\u{1E7C}', '\u{1E7C}'),
+    ('\u{1E7E}', '\u{1E7E}'),
+    ('\u{1E80}', '\u{1E80}'),
+    ('\u{1E82}', '\u{1E82}'),
+    ('\u{1E84}', '\u{1E84}'),
+    ('\u{1E86}', '\u{1E86}'),
+    ('\u{1E88}', '\u{1E88}'),
+    ('\u{1E8A}', '\u{1E8A}'),
+    ('\u{1E8C}', '\u{1E8C}'),
+    ('\u{1E8E}', '\u{1E8E}'),
+    ('\u{1E90}', '\u{1E90}'),
+    ('\u{1E92}', '\u{1E92}'),
+    ('\u{1E94}', '\u{1E94}'),
+    ('\u{1E9E}', '\u{1E9E}'),
+    ('\u{1EA0}', '\u{1EA0}'),
+    ('\u{1EA2}', '\u{1EA2}'),
+    ('\u{1EA4}', '\u{1EA4}'),
+    ('\u{1EA6}', '\u{1EA6}'),
+    ('\u{1EA8}', '\u{1EA8}'),
+    ('\u{1EAA}', '\u{1EAA}'),
+    ('\u{1EAC}', '\u{1EAC}'),
+    ('\u{1EAE}', '\u{1EAE}'),
+    ('\u{1EB0}', '\u{1EB0}'),
+    ('\u{1EB2}', '\u{1EB2}'),
+    ('\u{1EB4}', '\u{1EB4}'),
+    ('\u{1EB6}', '\u{1EB6}'),
+    ('\u{1EB8}', '\u{1EB8}'),
+    ('\u{1EBA}', '\u{1EBA}'),
+    ('\u{1EBC}', '\u{1EBC}'),
+    ('\u{1EBE}', '\u{1EBE}'),
+    ('\u{1EC0}', '\u{1EC0}'),
+    ('\u{1EC2}', '\u{1EC2}'),
+    ('\u{1EC4}', '\u{1EC4}'),
+    ('\u{1EC6}', '\u{1EC6}'),
+    ('\u{1EC8}', '\u{1EC8}'),
+    ('\u{1ECA}', '\u{1ECA}'),
+    ('\u{1ECC}', '\u{1ECC}'),
+    ('\u{1ECE}', '\u{1ECE}'),
+    ('\u{1ED0}', '\u{1ED0}'),
+    ('\u{1ED2}', '\u{1ED2}'),
+    ('\u{1ED4}', '\u{1ED4}'),
+    ('\u{1ED6}', '\u{1ED6}'),
+    ('\u{1ED8}', '\u{1ED8}'),
+    ('\u{1EDA}', '\u{1EDA}'),
+    ('\u{1EDC}', '\u{1EDC}'),
+    ('\u{1EDE}', '\u{1EDE}'),
+    ('\u{1EE0}', '\u{1EE0}'),
+    ('\u{1EE2}', '\u{1EE2}'),
+    ('\u{1EE4}', '\u{1EE4}'),
+    ('\u{1EE6}', '\u{1EE6}'),
+    ('\u{1EE8}', '\u{1EE8}'),
+    ('\u{1EEA}', '\u{1EEA}'),
+    ('\u{1EEC}', '\u{1EEC}'),
+    ('\u{1EEE}', '\u{1EEE}'),
+    ('\u{1EF0}', '\u{1EF0}'),
+    ('\u{1EF2}', '\u{1EF2}'),
+    ('\u{1EF4}', '\u{1EF4}'),
+    ('\u{1EF6}', '\u{1EF6}'),
+    ('\u{1EF8}', '\u{1EF8}'),
+    ('\u{1EFA}', '\u{1EFA}'),
+    ('\u{1EFC}', '\u{1EFC}'),
+    ('\u{1EFE}', '\u{1EFE}'),
+    ('\u{1F08}', '\u{1F0F}'),
+    ('\u{1F18}', '\u{1F1D}'),
+    ('\u{1F28}', '\u{1F2F}'),
+    ('\u{1F38}', '\u{1F3F}'),
+    ('\u{1F48}', '\u{1F4D}'),
+    ('\u{1F59}', '\u{1F59}'),
+    ('\u{1F5B}', '\u{1F5B}'),
+    ('\u{1F5D}', '\u{1F5D}'),
+    ('\u{1F5F}', '\u{1F5F}'),
+    ('\u{1F68}', '\u{1F6F}'),
+    ('\u{1FB8}', '\u{1FBB}'),
+    ('\u{1FC8}', '\u{1FCB}'),
+    ('\u{1FD8}', '\u{1FDB}'),
+    ('\u{1FE8}', '\u{1FEC}'),
+    ('\u{1FF8}', '\u{1FFB}'),
+    ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'),
+    ('\u{210B}', '\u{210D}'),
+    ('\u{2110}', '\u{2112}'),
+    ('\u{2115}', '\u{2115}'),
+    ('\u{2119}', '\u{211D}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{2128}', '\u{2128}'),
+    ('\u{212A}', '\u{212D}'),
+    ('\u{2130}', '\u{2133}'),
+    ('\u{213E}', '\u{213F}'),
+    ('\u{2145}', '\u{2145}'),
+    ('\u{2160}', '\u{216F}'),
+    ('\u{2183}', '\u{2183}'),
+    ('\u{24B6}', '\u{24CF}'),
+    ('\u{2C00}', '\u{2C2F}'),
+    ('\u{2C60}', '\u{2C60}'),
+    ('\u{2C62}', '\u{2C64}'),
+    ('\u{2C67}', '\u{2C67}'),
+    ('\u{2C69}', '\u{2C69}'),
+    ('\u{2C6B}', '\u{2C6B}'),
+    ('\u{2C6D}', '\u{2C70}'),
+    ('\u{2C72}', '\u{2C72}'),
+    ('\u{2C75}', '\u{2C75}'),
+    ('\u{2C7E}', '\u{2C80}'),
+    ('\u{2C82}', '\u{2C82}'),
+    ('\u{2C84}', '\u{2C84}'),
+    ('\u{2C86}', '\u{2C86}'),
+    ('\u{2C88}', '\u{2C88}'),
+    ('\u{2C8A}', '\u{2C8A}'),
+    ('\u{2C8C}', '\u{2C8C}'),
+    ('\u{2C8E}', '\u{2C8E}'),
+    ('\u{2C90}', '\u{2C90}'),
+    ('\u{2C92}', '\u{2C92}'),
+    ('\u{2C94}', '\u{2C94}'),
+    ('\u{2C96}', '\u{2C96}'),
+    ('\u{2C98}', '\u{2C98}'),
+    ('\u{2C9A}', '\u{2C9A}'),
+    ('\u{2C9C}', '\u{2C9C}'),
+    ('\u{2C9E}', '\u{2C9E}'),
+    ('\u{2CA0}', '\u{2CA0}'),
+    ('\u{2CA2}', '\u{2CA2}'),
+    ('\u{2CA4}', '\u{2CA4}'),
+    ('\u{2CA6}', '\u{2CA6}'),
+    ('\u{2CA8}', '\u{2CA8}'),
+    ('\u{2CAA}', '\u{2CAA}'),
+    ('\u{2CAC}', '\u{2CAC}'),
+    ('\u{2CAE}', '\u{2CAE}'),
+    ('\u{2CB0}', '\u{2CB0}'),
+    ('\u{2CB2}', '\u{2CB2}'),
+    ('\u{2CB4}', '\u{2CB4}'),
+    ('\u{2CB6}', '\u{2CB6}'),
+    ('\u{2CB8}', '\u{2CB8}'),
+    ('\u{2CBA}', '\u{2CBA}'),
+    ('\u{2CBC}', '\u{2CBC}'),
+    ('\u{2CBE}', '\u{2CBE}'),
+    ('\u{2CC0}', '\u{2CC0}'),
+    ('\u{2CC2}', '\u{2CC2}'),
+    ('\u{2CC4}', '\u{2CC4}'),
+    ('\u{2CC6}', '\u{2CC6}'),
+    ('\u{2CC8}', '\u{2CC8}'),
+    ('\u{2CCA}', '\u{2CCA}'),
+    ('\u{2CCC}', '\u{2CCC}'),
+    ('\u{2CCE}', '\u{2CCE}'),
+    ('\u{2CD0}', '\u{2CD0}'),
+    ('\u{2CD2}', '\u{2CD2}'),
+    ('\u{2CD4}', '\u{2CD4}'),
+    ('\u{2CD6}', '\u{2CD6}'),
+    ('\u{2CD8}', '\u{2CD8}'),
+    ('\u{2CDA}', '\u{2CDA}'),
+    ('\u{2CDC}', '\u{2CDC}'),
+    ('\u{2CDE}', '\u{2CDE}'),
+    ('\u{2CE0}', '\u{2CE0}'),
+    ('\u{2CE2}', '\u{2CE2}'),
+    ('\u{2CEB}', '\u{2CEB}'),
+    ('\u{2CED}', '\u{2CED}'),
+    ('\u{2CF2}', '\u{2CF2}'),
+    ('\u{A640}', '\u{A640}'),
+    ('\u{A642}', '\u{A642}'),
+    ('\u{A644}', '\u{A644}'),
+    ('\u{A646}', '\u{A646}'),
+    ('\u{A648}', '\u{A648}'),
+    ('\u{A64A}', '\u{A64A}'),
+    ('\u{A64C}', '\u{A64C}'),
+    ('\u{A64E}', '\u{A64E}'),
+    ('\u{A650}', '\u{A650}'),
+    ('\u{A652}', '\u{A652}'),
+    ('\u{A654}', '\u{A654}'),
+    ('\u{A656}', '\u{A656}'),
+    ('\u{A658}', '\u{A658}'),
+    ('\u{A65A}', '\u{A65A}'),
+    ('\u{A65C}', '\u{A65C}'),
+    ('\u{A65E}', '\u{A65E}'),
+    ('\u{A660}', '\u{A660}'),
+    ('\u{A662}', '\u{A662}'),
+    ('\u{A664}', '\u{A664}'),
+    ('\u{A666}', '\u{A666}'),
+    ('\u{A668}', '\u{A668}'),
+    ('\u{A66A}', '\u{A66A}'),
+    ('\u{A66C}', '\u{A66C}'),
+    ('\u{A680}', '\u{A680}'),
+    ('\u{A682}', '\u{A682}'),
+    ('\u{A684}', '\u{A684}'),
+    ('\u{A686}', '\u{A686}'),
+    ('\u{A688}', '\u{A688}'),
+    ('\u{A68A}', '\u{A68A}'),
+    ('\u{A68C}', '\u{A68C}'),
+    ('\u{A68E}', '\u{A68E}'),
+    ('\u{A690}', '\u{A690}'),
+    ('\u{A692}', '\u{A692}'),
+    ('\u{A694}', '\u{A694}'),
+    ('\u{A696}', '\u{A696}'),
+    ('\u{A698}', '\u{A698}'),
+    ('\u{A69A}', '\u{A69A}'),
+    ('\u{A722}', '\u{A722}'),
+    ('\u{A724}', '\u{A724}'),
+    ('\u{A726}', '\u{A726}'),
+    ('\u{A728}', '\u{A728}'),
+    ('\u{A72A}', '\u{A72A}'),
+    ('\u{A72C}', '\u{A72C}'),
+    ('\u{A72E}', '\u{A72E}'),
+    ('\u{A732}', '\u{A732}'),
+    ('\u{A734}', '\u{A734}'),
+    ('\u{A736}', '\u{A736}'),
+    ('\u{A738}', '\u{A738}'),
+    ('\u{A73A}', '\u{A73A}'),
+    ('\u{A73C}', '\u{A73C}'),
+    ('\u{A73E}', '\u{A73E}'),
+    ('\u{A740}', '\u{A740}'),
+    ('\u{A742}', '\u{A742}'),
+    ('\u{A744}', '\u{A744}'),
+    ('\u{A746}', '\u{A746}'),
+    ('\u{A748}', '\u{A748}'),
+    ('\u{A74A}', '\u{A74A}'),
+    ('\u{A74C}', '\u{A74C}'),
+    ('\u{A74E}', '\u{A74E}'),
+    ('\u{A750}', '\u{A750}'),
+    ('\u{A752}', '\u{A752}'),
+    ('\u{A754}', '\u{A754}'),
+    ('\u{A756}', '\u{A756}'),
+    ('\u{A758}', '\u{A758}'),
+    ('\u{A75A}', '\u{A75A}'),
+    ('\u{A75C}', '\u{A75C}'),
+    ('\u{A75E}', '\u{A75E}'),
+    ('\u{A760}', '\u{A760}'),
+    ('\u{A762}', '\u{A762}'),
+    ('\u{A764}', '\u{A764}'),
+    ('\u{A766}', '\u{A766}'),
+    ('\u{A768}', '\u{A768}'),
+    ('\u{A76A}', '\u{A76A}'),
+    ('\u{A76C}', '\u{A76C}'),
+    ('\u{A76E}', '\u{A76E}'),
+    ('\u{A779}', '\u{A779}'),
+    ('\u{A77B}', '\u{A77B}'),
+    ('\u{A77D}', '\u{A77E}'),
+    ('\u{A780}', '\u{A780}'),
+    ('\u{A782}', '\u{A782}'),
+    ('\u{A784}', '\u{A784}'),
+    ('\u{A786}', '\u{A786}'),
+    ('\u{A78B}', '\u{A78B}'),
+    ('\u{A78D}', '\u{A78D}'),
+    ('\u{A790}', '\u{A790}'),
+    ('\u{A792}', '\u{A792}'),
+    ('\u{A796}', '\u{A796}'),
+    ('\u{A798}', '\u{A798}'),
+    ('\u{A79A}', '\u{A79A}'),
+    ('\u{A79C}', '\u{A79C}'),
+    ('\u{A79E}', '\u{A79E}'),
+    ('\u{A7A0}', '\u{A7A0}'),
+    ('\u{A7A2}', '\u{A7A2}'),
+    ('\u{A7A4}', '\u{A7A4}'),
+    ('\u{A7A6}', '\u{A7A6}'),
+    ('\u{A7A8}', '\u{A7A8}'),
+    ('\u{A7AA}', '\u{A7AE}'),
+    ('\u{A7B0}', '\u{A7B4}'),
+    ('\u{A7B6}', '\u{A7B6}'),
+    ('\u{A7B8}', '\u{A7B8}'),
+    ('\u{A7BA}', '\u{A7BA}'),
+    ('\u{A7BC}', '\u{A7BC}'),
+    ('\u{A7BE}', '\u{A7BE}'),
+    ('\u{A7C0}', '\u{A7C0}'),
+    ('\u{A7C2}', '\u{A7C2}'),
+    ('\u{A7C4}', '\u{A7C7}'),
+    ('\u{A7C9}', '\u{A7C9}'),
+    ('\u{A7D0}', '\u{A7D0}'),
+    ('\u{A7D6}', '\u{A7D6}'),
+    ('\u{A7D8}', '\u{A7D8}'),
+    ('\u{A7F5}', '\u{A7F5}'),
+    ('\u{FF21}', '\u{FF3A}'),
+    ('\u{10400}', '\u{10427}'),
+    ('\u{104B0}', '\u{104D3}'),
+    ('\u{10570}', '\u{1057A}'),
+    ('\u{1057C}', '\u{1058A}'),
+    ('\u{1058C}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'),
+    ('\u{10C80}', '\u{10CB2}'),
+    ('\u{118A0}', '\u{118BF}'),
+    ('\u{16E40}', '\u{16E5F}'),
+    ('\u{1D400}', '\u{1D419}'),
+    ('\u{1D434}', '\u{1D44D}'),
+    ('\u{1D468}', '\u{1D481}'),
+    ('\u{1D49C}', '\u{1D49C}'),
+    ('\u{1D49E}', '\u{1D49F}'),
+    ('\u{1D4A2}', '\u{1D4A2}'),
+    ('\u{1D4A5}', '\u{1D4A6}'),
+    ('\u{1D4A9}', '\u{1D4AC}'),
+    ('\u{1D4AE}', '\u{1D4B5}'),
+    ('\u{1D4D0}', '\u{1D4E9}'),
+    ('\u{1D504}', '\u{1D505}'),
+    ('\u{1D507}', '\u{1D50A}'),
+    ('\u{1D50D}', '\u{1D514}'),
+    ('\u{1D516}', '\u{1D51C}'),
+    ('\u{1D538}', '\u{1D539}'),
+    ('\u{1D53B}', '\u{1D53E}'),
+    ('\u{1D540}', '\u{1D544}'),
+    ('\u{1D546}', '\u{1D546}'),
+    ('\u{1D54A}', '\u{1D550}'),
+    ('\u{1D56C}', '\u{1D585}'),
+    ('\u{1D5A0}', '\u{1D5B9}'),
+    ('\u{1D5D4}', '\u{1D5ED}'),
+    ('\u{1D608}', '\u{1D621}'),
+    ('\u{1D63C}', '\u{1D655}'),
+    ('\u{1D670}', '\u{1D689}'),
+    ('\u{1D6A8}', '\u{1D6C0}'),
+    ('\u{1D6E2}', '\u{1D6FA}'),
+    ('\u{1D71C}', '\u{1D734}'),
+    ('\u{1D756}', '\u{1D76E}'),
+    ('\u{1D790}', '\u{1D7A8}'),
+    ('\u{1D7CA}', '\u{1D7CA}'),
+    ('\u{1E900}', '\u{1E921}'),
+    ('\u{1F130}', '\u{1F149}'),
+    ('\u{1F150}', '\u{1F169}'),
+    ('\u{1F170}', '\u{1F189}'),
+];
+
+/// Ranges of chars with the `Variation_Selector` property, sorted by code point.
+pub(crate) static VARIATION_SELECTOR: &[(char, char)] = &[
+    ('\u{180B}', '\u{180D}'),
+    ('\u{180F}', '\u{180F}'),
+    ('\u{FE00}', '\u{FE0F}'),
+    ('\u{E0100}', '\u{E01EF}'),
+];
+
+/// Ranges of chars with the `White_Space` property, sorted by code point.
+pub(crate) static WHITE_SPACE: &[(char, char)] = &[
+    ('\u{9}', '\u{D}'),
+    ('\u{20}', '\u{20}'),
+    ('\u{85}', '\u{85}'),
+    ('\u{A0}', '\u{A0}'),
+    ('\u{1680}', '\u{1680}'),
+    ('\u{2000}', '\u{200A}'),
+    ('\u{2028}', '\u{2029}'),
+    ('\u{202F}', '\u{202F}'),
+    ('\u{205F}', '\u{205F}'),
+    ('\u{3000}', '\u{3000}'),
+];
+
+/// Ranges of chars with the `XID_Continue` property, sorted by code point.
+pub(crate) static XID_CONTINUE: &[(char, char)] = &[
+    ('\u{30}', '\u{39}'),
+    ('\u{41}', '\u{5A}'),
+    ('\u{5F}', '\u{5F}'),
+    ('\u{61}', '\u{7A}'),
+    ('\u{AA}', '\u{AA}'),
+    ('\u{B5}', '\u{B5}'),
+    ('\u{B7}', '\u{B7}'),
+    ('\u{BA}', '\u{BA}'),
+    ('\u{C0}', '\u{D6}'),
+    ('\u{D8}', '\u{F6}'),
+    ('\u{F8}', '\u{2C1}'),
+    ('\u{2C6}', '\u{2D1}'),
+    ('\u{2E0}', '\u{2E4}'),
+    ('\u{2EC}', '\u{2EC}'),
+    ('\u{2EE}', '\u{2EE}'),
+    ('\u{300}', '\u{374}'),
+    ('\u{376}', '\u{377}'),
+    ('\u{37B}', '\u{37D}'),
+    ('\u{37F}', '\u{37F}'),
+    ('\u{386}', '\u{38A}'),
+    ('\u{38C}', '\u{38C}'),
+    ('\u{38E}', '\u{3A1}'),
+    ('\u{3A3}', '\u{3F5}'),
+    ('\u{3F7}', '\u{481}'),
+    ('\u{483}', '\u{487}'),
+    ('\u{48A}', '\u{52F}'),
+    ('\u{531}', '\u{556}'),
+    ('\u{559}', '\u{559}'),
+    ('\u{560}', '\u{588}'),
+    ('\u{591}', '\u{5BD}'),
+    ('\u{5BF}', '\u{5BF}'),
+    ('\u{5C1}', '\u{5C2}'),
+    ('\u{5C4}', '\u{5C5}'),
+    ('\u{5C7}', '\u{5C7}'),
+    ('\u{5D0}', '\u{5EA}'),
+    ('\u{5EF}', '\u{5F2}'),
+    ('\u{610}', '\u{61A}'),
+    ('\u{620}', '\u{669}'),
+    ('\u{66E}', '\u{6D3}'),
+    ('\u{6D5}', '\u{6DC}'),
+    ('\u{6DF}', '\u{6E8}'),
+    ('\u{6EA}', '\u{6FC}'),
+    ('\u{6FF}', '\u{6FF}'),
+    ('\u{710}', '\u{74A}'),
+    ('\u{74D}', '\u{7B1}'),
+    ('\u{7C0}', '\u{7F5}'),
+    ('\u{7FA}', '\u{7FA}'),
+    ('\u{7FD}', '\u{7FD}'),
+    ('\u{800}', '\u{82D}'),
+    ('\u{840}', '\u{85B}'),
+    ('\u{860}', '\u{86A}'),
+    ('\u{870}', '\u{887}'),
+    ('\u{889}', '\u{88E}'),
+    ('\u{898}', '\u{8E1}'),
+    ('\u{8E3}', '\u{963}'),
+    ('\u{966}', '\u{96F}'),
+    ('\u{971}', '\u{983}'),
+    ('\u{985}', '\u{98C}'),
+    ('\u{98F}', '\u{990}'),
+    ('\u{993}', '\u{9A8}'),
+    ('\u{9AA}', '\u{9B0}'),
+    ('\u{9B2}', '\u{9B2}'),
+    ('\u{9B6}', '\u{9B9}'),
+    ('\u{9BC}', '\u{9C4}'),
+    ('\u{9C7}', '\u{9C8}'),
+    ('\u{9CB}', '\u{9CE}'),
+    ('\u{9D7}', '\u{9D7}'),
+    ('\u{9DC}', '\u{9DD}'),
+    ('\u{9DF}', '\u{9E3}'),
+    ('\u{9E6}', '\u{9F1}'),
+    ('\u{9FC}', '\u{9FC}'),
+    ('\u{9FE}', '\u{9FE}'),
+    ('\u{A01}', '\u{A03}'),
+    ('\u{A05}', '\u{A0A}'),
+    ('\u{A0F}', '\u{A10}'),
+    ('\u{A13}', '\u{A28}'),
+    ('\u{A2A}', '\u{A30}'),
+    ('\u{A32}', '\u{A33}'),
+    ('\u{A35}', '\u{A36}'),
+    ('\u{A38}', '\u{A39}'),
+    ('\u{A3C}', '\u{A3C}'),
+    ('\u{A3E}', '\u{A42}'),
+    ('\u{A47}', '\u{A48}'),
+    ('\u{A4B}', '\u{A4D}'),
+    ('\u{A51}', '\u{A51}'),
+    ('\u{A59}', '\u{A5C}'),
+    ('\u{A5E}', '\u{A5E}'),
+    ('\u{A66}', '\u{A75}'),
+    ('\u{A81}', '\u{A83}'),
+    ('\u{A85}', '\u{A8D}'),
+    ('\u{A8F}', '\u{A91}'),
+    ('\u{A93}', '\u{AA8}'),
+    ('\u{AAA}', '\u{AB0}'),
+    ('\u{AB2}', '\u{AB3}'),
+    ('\u{AB5}', '\u{AB9}'),
+    ('\u{ABC}', '\u{AC5}'),
+    ('\u{AC7}', '\u{AC9}'),
+    ('\u{ACB}', '\u{ACD}'),
+    ('\u{AD0}', '\u{AD0}'),
+    ('\u{AE0}', '\u{AE3}'),
+    ('\u{AE6}', '\u{AEF}'),
+    ('\u{AF9}', '\u{AFF}'),
+    ('\u{B01}', '\u{B03}'),
+    ('\u{B05}', '\u{B0C}'),
+    ('\u{B0F}', '\u{B10}'),
+    ('\u{B13}', '\u{B28}'),
+    ('\u{B2A}', '\u{B30}'),
+    ('\u{B32}', '\u{B33}'),
+    ('\u{B35}', '\u{B39}'),
+    ('\u{B3C}', '\u{B44}'),
+    ('\u{B47}', '\u{B48}'),
+    ('\u{B4B}', '\u{B4D}'),
+    ('\u{B55}', '\u{B57}'),
+    ('\u{B5C}', '\u{B5D}'),
+    ('\u{B5F}', '\u{B63}'),
+    ('\u{B66}', '\u{B6F}'),
+    ('\u{B71}', '\u{B71}'),
+    ('\u{B82}', '\u{B83}'),
+    ('\u{B85}', '\u{B8A}'),
+    ('\u{B8E}', '\u{B90}'),
+    ('\u{B92}', '\u{B95}'),
+    ('\u{B99}', '\u{B9A}'),
+    ('\u{B9C}', '\u{B9C}'),
+    ('\u{B9E}', '\u{B9F}'),
+    ('\u{BA3}', '\u{BA4}'),
+    ('\u{BA8}', '\u{BAA}'),
+    ('\u{BAE}', '\u{BB9}'),
+    ('\u{BBE}', '\u{BC2}'),
+    ('\u{BC6}', '\u{BC8}'),
+    ('\u{BCA}', '\u{BCD}'),
+    ('\u{BD0}', '\u{BD0}'),
+    ('\u{BD7}', '\u{BD7}'),
+    ('\u{BE6}', '\u{BEF}'),
+    ('\u{C00}', '\u{C0C}'),
+    ('\u{C0E}', '\u{C10}'),
+    ('\u{C12}', '\u{C28}'),
+    ('\u{C2A}', '\u{C39}'),
+    ('\u{C3C}', '\u{C44}'),
+    ('\u{C46}', '\u{C48}'),
+    ('\u{C4A}', '\u{C4D}'),
+    ('\u{C55}', '\u{C56}'),
+    ('\u{C58}', '\u{C5A}'),
+    ('\u{C5D}', '\u{C5D}'),
+    ('\u{C60}', '\u{C63}'),
+    ('\u{C66}', '\u{C6F}'),
+    ('\u{C80}', '\u{C83}'),
+    ('\u{C85}', '\u{C8C}'),
+    ('\u{C8E}', '\u{C90}'),
+    ('\u{C92}', '\u{CA8}'),
+    ('\u{CAA}', '\u{CB3}'),
+    ('\u{CB5}', '\u{CB9}'),
+    ('\u{CBC}', '\u{CC4}'),
+    ('\u{CC6}', '\u{CC8}'),
+    ('\u{CCA}', '\u{CCD}'),
+    ('\u{CD5}', '\u{CD6}'),
+    ('\u{CDD}', '\u{CDE}'),
+    ('\u{CE0}', '\u{CE3}'),
+    ('\u{CE6}', '\u{CEF}'),
+    ('\u{CF1}', '\u{CF2}'),
+    ('\u{D00}', '\u{D0C}'),
+    ('\u{D0E}', '\u{D10}'),
+    ('\u{D12}', '\u{D44}'),
+    ('\u{D46}', '\u{D48}'),
+    ('\u{D4A}', '\u{D4E}'),
+    ('\u{D54}', '\u{D57}'),
+    ('\u{D5F}', '\u{D63}'),
+    ('\u{D66}', '\u{D6F}'),
+    ('\u{D7A}', '\u{D7F}'),
+    ('\u{D81}', '\u{D83}'),
+    ('\u{D85}', '\u{D96}'),
+    ('\u{D9A}', '\u{DB1}'),
+    ('\u{DB3}', '\u{DBB}'),
+    ('\u{DBD}', '\u{DBD}'),
+    ('\u{DC0}', '\u{DC6}'),
+    ('\u{DCA}', '\u{DCA}'),
+    ('\u{DCF}', '\u{DD4}'),
+    ('\u{DD6}', '\u{DD6}'),
+    ('\u{DD8}', '\u{DDF}'),
+    ('\u{DE6}', '\u{DEF}'),
+    ('\u{DF2}', '\u{DF3}'),
+    ('\u{E01}', '\u{E3A}'),
+    ('\u{E40}', '\u{E4E}'),
+    ('\u{E50}', '\u{E59}'),
+    ('\u{E81}', '\u{E82}'),
+    ('\u{E84}', '\u{E84}'),
+    ('\u{E86}', '\u{E8A}'),
+    ('\u{E8C}', '\u{EA3}'),
+    ('\u{EA5}', '\u{EA5}'),
+    ('\u{EA7}', '\u{EBD}'),
+    ('\u{EC0}', '\u{EC4}'),
+    ('\u{EC6}', '\u{EC6}'),
+    ('\u{EC8}', '\u{ECD}'),
+    ('\u{ED0}', '\u{ED9}'),
+    ('\u{EDC}', '\u{EDF}'),
+    ('\u{F00}', '\u{F00}'),
+    ('\u{F18}', '\u{F19}'),
+    ('\u{F20}', '\u{F29}'),
+    ('\u{F35}', '\u{F35}'),
+    ('\u{F37}', '\u{F37}'),
+    ('\u{F39}', '\u{F39}'),
+    ('\u{F3E}', '\u{F47}'),
+    ('\u{F49}', '\u{F6C}'),
+    ('\u{F71}', '\u{F84}'),
+    ('\u{F86}', '\u{F97}'),
+    ('\u{F99}', '\u{FBC}'),
+    ('\u{FC6}', '\u{FC6}'),
+    ('\u{1000}', '\u{1049}'),
+    ('\u{1050}', '\u{109D}'),
+    ('\u{10A0}', '\u{10C5}'),
+    ('\u{10C7}', '\u{10C7}'),
+    ('\u{10CD}', '\u{10CD}'),
+    ('\u{10D0}', '\u{10FA}'),
+    ('\u{10FC}', '\u{1248}'),
+    ('\u{124A}', '\u{124D}'),
+    ('\u{1250}', '\u{1256}'),
+    ('\u{1258}', '\u{1258}'),
+    ('\u{125A}', '\u{125D}'),
+    ('\u{1260}', '\u{1288}'),
+    ('\u{128A}', '\u{128D}'),
+    ('\u{1290}', '\u{12B0}'),
+    ('\u{12B2}', '\u{12B5}'),
+    ('\u{12B8}', '\u{12BE}'),
+    ('\u{12C0}', '\u{12C0}'),
+    ('\u{12C2}', '\u{12C5}'),
+    ('\u{12C8}', '\u{12D6}'),
+    ('\u{12D8}', '\u{1310}'),
+    ('\u{1312}', '\u{1315}'),
+    ('\u{1318}', '\u{135A}'),
+    ('\u{135D}', '\u{135F}'),
+    ('\u{1369}', '\u{1371}'),
+    ('\u{1380}', '\u{138F}'),
+    ('\u{13A0}', '\u{13F5}'),
+    ('\u{13F8}', '\u{13FD}'),
+    ('\u{1401}', '\u{166C}'),
+    ('\u{166F}', '\u{167F}'),
+    ('\u{1681}', '\u{169A}'),
+    ('\u{16A0}', '\u{16EA}'),
+    ('\u{16EE}', '\u{16F8}'),
+    ('\u{1700}', '\u{1715}'),
+    ('\u{171F}', '\u{1734}'),
+    ('\u{1740}', '\u{1753}'),
+    ('\u{1760}', '\u{176C}'),
+    ('\u{176E}', '\u{1770}'),
+    ('\u{1772}', '\u{1773}'),
+    ('\u{1780}', '\u{17D3}'),
+    ('\u{17D7}', '\u{17D7}'),
+    ('\u{17DC}', '\u{17DD}'),
+    ('\u{17E0}', '\u{17E9}'),
+    ('\u{180B}', '\u{180D}'),
+    ('\u{180F}', '\u{1819}'),
+    ('\u{1820}', '\u{1878}'),
+    ('\u{1880}', '\u{18AA}'),
+    ('\u{18B0}', '\u{18F5}'),
+    ('\u{1900}', '\u{191E}'),
+    ('\u{1920}', '\u{192B}'),
+    ('\u{1930}', '\u{193B}'),
+    ('\u{1946}', '\u{196D}'),
+    ('\u{1970}', '\u{1974}'),
+    ('\u{1980}', '\u{19AB}'),
+    ('\u{19B0}', '\u{19C9}'),
+    ('\u{19D0}', '\u{19DA}'),
+    ('\u{1A00}', '\u{1A1B}'),
+    ('\u{1A20}', '\u{1A5E}'),
+    ('\u{1A60}', '\u{1A7C}'),
+    ('\u{1A7F}', '\u{1A89}'),
+    ('\u{1A90}', '\u{1A99}'),
+    ('\u{1AA7}', '\u{1AA7}'),
+    ('\u{1AB0}', '\u{1ABD}'),
+    ('\u{1ABF}', '\u{1ACE}'),
+    ('\u{1B00}', '\u{1B4C}'),
+    ('\u{1B50}', '\u{1B59}'),
+    ('\u{1B6B}', '\u{1B73}'),
+    ('\u{1B80}', '\u{1BF3}'),
+    ('\u{1C00}', '\u{1C37}'),
+    ('\u{1C40}', '\u{1C49}'),
+    ('\u{1C4D}', '\u{1C7D}'),
+    ('\u{1C80}', '\u{1C88}'),
+    ('\u{1C90}', '\u{1CBA}'),
+    ('\u{1CBD}', '\u{1CBF}'),
+    ('\u{1CD0}', '\u{1CD2}'),
+    ('\u{1CD4}', '\u{1CFA}'),
+    ('\u{1D00}', '\u{1F15}'),
+    ('\u{1F18}', '\u{1F1D}'),
+    ('\u{1F20}', '\u{1F45}'),
+    ('\u{1F48}', '\u{1F4D}'),
+    ('\u{1F50}', '\u{1F57}'),
+    ('\u{1F59}', '\u{1F59}'),
+    ('\u{1F5B}', '\u{1F5B}'),
+    ('\u{1F5D}', '\u{1F5D}'),
+    ('\u{1F5F}', '\u{1F7D}'),
+    ('\u{1F80}', '\u{1FB4}'),
+    ('\u{1FB6}', '\u{1FBC}'),
+    ('\u{1FBE}', '\u{1FBE}'),
+    ('\u{1FC2}', '\u{1FC4}'),
+    ('\u{1FC6}', '\u{1FCC}'),
+    ('\u{1FD0}', '\u{1FD3}'),
+    ('\u{1FD6}', '\u{1FDB}'),
+    ('\u{1FE0}', '\u{1FEC}'),
+    ('\u{1FF2}', '\u{1FF4}'),
+    ('\u{1FF6}', '\u{1FFC}'),
+    ('\u{203F}', '\u{2040}'),
+    ('\u{2054}', '\u{2054}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207F}', '\u{207F}'),
+    ('\u{2090}', '\u{209C}'),
+    ('\u{20D0}', '\u{20DC}'),
+    ('\u{20E1}', '\u{20E1}'),
+    ('\u{20E5}', '\u{20F0}'),
+    ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'),
+    ('\u{210A}', '\u{2113}'),
+    ('\u{2115}', '\u{2115}'),
+    ('\u{2118}', '\u{211D}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{2128}', '\u{2128}'),
+    ('\u{212A}', '\u{2139}'),
+    ('\u{213C}', '\u{213F}'),
+    ('\u{2145}', '\u{2149}'),
+    ('\u{214E}', '\u{214E}'),
+    ('\u{2160}', '\u{2188}'),
+    ('\u{2C00}', '\u{2CE4}'),
+    ('\u{2CEB}', '\u{2CF3}'),
+    ('\u{2D00}', '\u{2D25}'),
+    ('\u{2D27}', '\u{2D27}'),
+    ('\u{2D2D}', '\u{2D2D}'),
+    ('\u{2D30}', '\u{2D67}'),
+    ('\u{2D6F}', '\u{2D6F}'),
+    ('\u{2D7F}', '\u{2D96}'),
+    ('\u{2DA0}', '\u{2DA6}'),
+    ('\u{2DA8}', '\u{2DAE}'),
+    ('\u{2DB0}', '\u{2DB6}'),
+    ('\u{2DB8}', '\u{2DBE}'),
+    ('\u{2DC0}', '\u{2DC6}'),
+    ('\u{2DC8}', '\u{2DCE}'),
+    ('\u{2DD0}', '\u{2DD6}'),
+    ('\u{2DD8}', '\u{2DDE}'),
+    ('\u{2DE0}', '\u{2DFF}'),
+    ('\u{3005}', '\u{3007}'),
+    ('\u{3021}', '\u{302F}'),
+    ('\u{3031}', '\u{3035}'),
+    ('\u{3038}', '\u{303C}'),
+    ('\u{3041}', '\u{3096}'),
+    ('\u{3099}', '\u{309A}'),
+    ('\u{309D}', '\u{309F}'),
+    ('\u{30A1}', '\u{30FA}'),
+    ('\u{30FC}', '\u{30FF}'),
+    ('\u{3105}', '\u{312F}'),
+    ('\u{3131}', '\u{318E}'),
+    ('\u{31A0}', '\u{31BF}'),
+    ('\u{31F0}', '\u{31FF}'),
+    ('\u{3400}', '\u{4DBF}'),
+    ('\u{4E00}', '\u{A48C}'),
+    ('\u{A4D0}', '\u{A4FD}'),
+    ('\u{A500}', '\u{A60C}'),
+    ('\u{A610}', '\u{A62B}'),
+    ('\u{A640}', '\u{A66F}'),
+    ('\u{A674}', '\u{A67D}'),
+    ('\u{A67F}', '\u{A6F1}'),
+    ('\u{A717}', '\u{A71F}'),
+    ('\u{A722}', '\u{A788}'),
+    ('\u{A78B}', '\u{A7CA}'),
+    ('\u{A7D0}', '\u{A7D1}'),
+    ('\u{A7D3}', '\u{A7D3}'),
+    ('\u{A7D5}', '\u{A7D9}'),
+    ('\u{A7F2}', '\u{A827}'),
+    ('\u{A82C}', '\u{A82C}'),
+    ('\u{A840}', '\u{A873}'),
+    ('\u{A880}', '\u{A8C5}'),
+    ('\u{A8D0}', '\u{A8D9}'),
+    ('\u{A8E0}', '\u{A8F7}'),
+    ('\u{A8FB}', '\u{A8FB}'),
+    ('\u{A8FD}', '\u{A92D}'),
+    ('\u{A930}', '\u{A953}'),
+    ('\u{A960}', '\u{A97C}'),
+    ('\u{A980}', '\u{A9C0}'),
+    ('\u{A9CF}', '\u{A9D9}'),
+    ('\u{A9E0}', '\u{A9FE}'),
+    ('\u{AA00}', '\u{AA36}'),
+    ('\u{AA40}', '\u{AA4D}'),
+    ('\u{AA50}', '\u{AA59}'),
+    ('\u{AA60}', '\u{AA76}'),
+    ('\u{AA7A}', '\u{AAC2}'),
+    ('\u{AADB}', '\u{AADD}'),
+    ('\u{AAE0}', '\u{AAEF}'),
+    ('\u{AAF2}', '\u{AAF6}'),
+    ('\u{AB01}', '\u{AB06}'),
+    ('\u{AB09}', '\u{AB0E}'),
+    ('\u{AB11}', '\u{AB16}'),
+    ('\u{AB20}', '\u{AB26}'),
+    ('\u{AB28}', '\u{AB2E}'),
+    ('\u{AB30}', '\u{AB5A}'),
+    ('\u{AB5C}', '\u{AB69}'),
+    ('\u{AB70}', '\u{ABEA}'),
+    ('\u{ABEC}', '\u{ABED}'),
+    ('\u{ABF0}', '\u{ABF9}'),
+    ('\u{AC00}', '\u{D7A3}'),
+    ('\u{D7B0}', '\u{D7C6}'),
+    ('\u{D7CB}', '\u{D7FB}'),
+    ('\u{F900}', '\u{FA6D}'),
+    ('\u{FA70}', '\u{FAD9}'),
+    ('\u{FB00}', '\u{FB06}'),
+    ('\u{FB13}', '\u{FB17}'),
+    ('\u{FB1D}', '\u{FB28}'),
+    ('\u{FB2A}', '\u{FB36}'),
+    ('\u{FB38}', '\u{FB3C}'),
+    ('\u{FB3E}', '\u{FB3E}'),
+    ('\u{FB40}', '\u{FB41}'),
+    ('\u{FB43}', '\u{FB44}'),
+    ('\u{FB46}', '\u{FBB1}'),
+    ('\u{FBD3}', '\u{FC5D}'),
+    ('\u{FC64}', '\u{FD3D}'),
+    ('\u{FD50}', '\u{FD8F}'),
+    ('\u{FD92}', '\u{FDC7}'),
+    ('\u{FDF0}', '\u{FDF9}'),
+    ('\u{FE00}', '\u{FE0F}'),
+    ('\u{FE20}', '\u{FE2F}'),
+    ('\u{FE33}', '\u{FE34}'),
+    ('\u{FE4D}', '\u{FE4F}'),
+    ('\u{FE71}', '\u{FE71}'),
+    ('\u{FE73}', '\u{FE73}'),
+    ('\u{FE77}', '\u{FE77}'),
+    ('\u{FE79}', '\u{FE79}'),
+    ('\u{FE7B}', '\u{FE7B}'),
+    ('\u{FE7D}', '\u{FE7D}'),
+    ('\u{FE7F}', '\u{FEFC}'),
+    ('\u{FF10}', '\u{FF19}'),
+    ('\u{FF21}', '\u{FF3A}'),
+    ('\u{FF3F}', '\u{FF3F}'),
+    ('\u{FF41}', '\u{FF5A}'),
+    ('\u{FF66}', '\u{FFBE}'),
+    ('\u{FFC2}', '\u{FFC7}'),
+    ('\u{FFCA}', '\u{FFCF}'),
+    ('\u{FFD2}', '\u{FFD7}'),
+    ('\u{FFDA}', '\u{FFDC}'),
+    ('\u{10000}', '\u{1000B}'),
+    ('\u{1000D}', '\u{10026}'),
+    ('\u{10028}', '\u{1003A}'),
+    ('\u{1003C}', '\u{1003D}'),
+    ('\u{1003F}', '\u{1004D}'),
+    ('\u{10050}', '\u{1005D}'),
+    ('\u{10080}', '\u{100FA}'),
+    ('\u{10140}', '\u{10174}'),
+    ('\u{101FD}', '\u{101FD}'),
+    ('\u{10280}', '\u{1029C}'),
+    ('\u{102A0}', '\u{102D0}'),
+    ('\u{102E0}', '\u{102E0}'),
+    ('\u{10300}', '\u{1031F}'),
+    ('\u{1032D}', '\u{1034A}'),
+    ('\u{10350}', '\u{1037A}'),
+    ('\u{10380}', '\u{1039D}'),
+    ('\u{103A0}', '\u{103C3}'),
+    ('\u{103C8}', '\u{103CF}'),
+    ('\u{103D1}', '\u{103D5}'),
+    ('\u{10400}', '\u{1049D}'),
+    ('\u{104A0}', '\u{104A9}'),
+    ('\u{104B0}', '\u{104D3}'),
+    ('\u{104D8}', '\u{104FB}'),
+    ('\u{10500}', '\u{10527}'),
+    ('\u{10530}', '\u{10563}'),
+    ('\u{10570}', '\u{1057A}'),
+    ('\u{1057C}', '\u{1058A}'),
+    ('\u{1058C}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'),
+    ('\u{10597}', '\u{105A1}'),
+    ('\u{105A3}', '\u{105B1}'),
+    ('\u{105B3}', '\u{105B9}'),
+    ('\u{105BB}', '\u{105BC}'),
+    ('\u{10600}', '\u{10736}'),
+    ('\u{10740}', '\u{10755}'),
+    ('\u{10760}', '\u{10767}'),
+    ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107B0}'),
+    ('\u{107B2}', '\u{107BA}'),
+    ('\u{10800}', '\u{10805}'),
+    ('\u{10808}', '\u{10808}'),
+    ('\u{1080A}', '\u{10835}'),
+    ('\u{10837}', '\u{10838}'),
+    ('\u{1083C}', '\u{1083C}'),
+    ('\u{1083F}', '\u{10855}'),
+    ('\u{10860}', '\u{10876}'),
+    ('\u{10880}', '\u{1089E}'),
+    ('\u{108E0}', '\u{108F2}'),
+    ('\u{108F4}', '\u{108F5}'),
+    ('\u{10900}', '\u{10915}'),
+    ('\u{10920}', '\u{10939}'),
+    ('\u{10980}', '\u{109B7}'),
+    ('\u{109BE}', '\u{109BF}'),
+    ('\u{10A00}', '\u{10A03}'),
+    ('\u{10A05}', '\u{10A06}'),
+    ('\u{10A0C}', '\u{10A13}'),
+    ('\u{10A15}', '\u{10A17}'),
+    ('\u{10A19}', '\u{10A35}'),
+    ('\u{10A38}', '\u{10A3A}'),
+    ('\u{10A3F}', '\u{10A3F}'),
+    ('\u{10A60}', '\u{10A7C}'),
+    ('\u{10A80}', '\u{10A9C}'),
+    ('\u{10AC0}', '\u{10AC7}'),
+    ('\u{10AC9}', '\u{10AE6}'),
+    ('\u{10B00}', '\u{10B35}'),
+    ('\u{10B40}', '\u{10B55}'),
+    ('\u{10B60}', '\u{10B72}'),
+    ('\u{10B80}', '\u{10B91}'),
+    ('\u{10C00}', '\u{10C48}'),
+    ('\u{10C80}', '\u{10CB2}'),
+    ('\u{10CC0}', '\u{10CF2}'),
+    ('\u{10D00}', '\u{10D27}'),
+    ('\u{10D30}', '\u{10D39}'),
+    ('\u{10E80}', '\u{10EA9}'),
+    ('\u{10EAB}', '\u{10EAC}'),
+    ('\u{10EB0}', '\u{10EB1}'),
+    ('\u{10F00}', '\u{10F1C}'),
+    ('\u{10F27}', '\u{10F27}'),
+    ('\u{10F30}', '\u{10F50}'),
+    ('\u{10F70}', '\u{10F85}'),
+    ('\u{10FB0}', '\u{10FC4}'),
+    ('\u{10FE0}', '\u{10FF6}'),
+    ('\u{11000}', '\u{11046}'),
+    ('\u{11066}', '\u{11075}'),
+    ('\u{1107F}', '\u{110BA}'),
+    ('\u{110C2}', '\u{110C2}'),
+    ('\u{110D0}', '\u{110E8}'),
+    ('\u{110F0}', '\u{110F9}'),
+    ('\u{11100}', '\u{11134}'),
+    ('\u{11136}', '\u{1113F}'),
+    ('\u{11144}', '\u{11147}'),
+    ('\u{11150}', '\u{11173}'),
+    ('\u{11176}', '\u{11176}'),
+    ('\u{11180}', '\u{111C4}'),
+    ('\u{111C9}', '\u{111CC}'),
+    ('\u{111CE}', '\u{111DA}'),
+    ('\u{111DC}', '\u{111DC}'),
+    ('\u{11200}', '\u{11211}'),
+    ('\u{11213}', '\u{11237}'),
+    ('\u{1123E}', '\u{1123E}'),
+    ('\u{11280}', '\u{11286}'),
+    ('\u{11288}', '\u{11288}'),
+    ('\u{1128A}', '\u{1128D}'),
+    ('\u{1128F}', '\u{1129D}'),
+    ('\u{1129F}', '\u{112A8}'),
+    ('\u{112B0}', '\u{112EA}'),
+    ('\u{112F0}', '\u{112F9}'),
+    ('\u{11300}', '\u{11303}'),
+    ('\u{11305}', '\u{1130C}'),
+    ('\u{1130F}', '\u{11310}'),
+    ('\u{11313}', '\u{11328}'),
+    ('\u{1132A}', '\u{11330}'),
+    ('\u{11332}', '\u{11333}'),
+    ('\u{11335}', '\u{11339}'),
+    ('\u{1133B}', '\u{11344}'),
+    ('\u{11347}', '\u{11348}'),
+    ('\u{1134B}', '\u{1134D}'),
+    ('\u{11350}', '\u{11350}'),
+    ('\u{11357}', '\u{11357}'),
+    ('\u{1135D}', '\u{11363}'),
+    ('\u{11366}', '\u{1136C}'),
+    ('\u{11370}', '\u{11374}'),
+    ('\u{11400}', '\u{1144A}'),
+    ('\u{11450}', '\u{11459}'),
+    ('\u{1145E}', '\u{11461}'),
+    ('\u{11480}', '\u{114C5}'),
+    ('\u{114C7}', '\u{114C7}'),
+    ('\u{114D0}', '\u{114D9}'),
+    ('\u{11580}', '\u{115B5}'),
+    ('\u{115B8}', '\u{115C0}'),
+    ('\u{115D8}', '\u{115DD}'),
+    ('\u{11600}', '\u{11640}'),
+    ('\u{11644}', '\u{11644}'),
+    ('\u{11650}', '\u{11659}'),
+    ('\u{11680}', '\u{116B8}'),
+    ('\u{116C0}', '\u{116C9}'),
+    ('\u{11700}', '\u{1171A}'),
+    ('\u{1171D}', '\u{1172B}'),
+    ('\u{11730}', '\u{11739}'),
+    ('\u{11740}', '\u{11746}'),
+    ('\u{11800}', '\u{1183A}'),
+    ('\u{118A0}', '\u{118E9}'),
+    ('\u{118FF}', '\u{11906}'),
+    ('\u{11909}', '\u{11909}'),
+    ('\u{1190C}', '\u{11913}'),
+    ('\u{11915}', '\u{11916}'),
+    ('\u{11918}', '\u{11935}'),
+    ('\u{11937}', '\u{11938}'),
+    ('\u{1193B}', '\u{11943}'),
+    ('\u{11950}', '\u{11959}'),
+    ('\u{119A0}', '\u{119A7}'),
+    ('\u{119AA}', '\u{119D7}'),
+    ('\u{119DA}', '\u{119E1}'),
+    ('\u{119E3}', '\u{119E4}'),
+    ('\u{11A00}', '\u{11A3E}'),
+    ('\u{11A47}', '\u{11A47}'),
+    ('\u{11A50}', '\u{11A99}'),
+    ('\u{11A9D}', '\u{11A9D}'),
+    ('\u{11AB0}', '\u{11AF8}'),
+    ('\u{11C00}', '\u{11C08}'),
+    ('\u{11C0A}', '\u{11C36}'),
+    ('\u{11C38}', '\u{11C40}'),
+    ('\u{11C50}', '\u{11C59}'),
+    ('\u{11C72}', '\u{11C8F}'),
+    ('\u{11C92}', '\u{11CA7}'),
+    ('\u{11CA9}', '\u{11CB6}'),
+    ('\u{11D00}', '\u{11D06}'),
+    ('\u{11D08}', '\u{11D09}'),
+    ('\u{11D0B}', '\u{11D36}'),
+    ('\u{11D3A}', '\u{11D3A}'),
+    ('\u{11D3C}', '\u{11D3D}'),
+    ('\u{11D3F}', '\u{11D47}'),
+    ('\u{11D50}', '\u{11D59}'),
+    ('\u{11D60}', '\u{11D65}'),
+    ('\u{11D67}', '\u{11D68}'),
+    ('\u{11D6A}', '\u{11D8E}'),
+    ('\u{11D90}', '\u{11D91}'),
+    ('\u{11D93}', '\u{11D98}'),
+    ('\u{11DA0}', '\u{11DA9}'),
+    ('\u{11EE0}', '\u{11EF6}'),
+    ('\u{11FB0}', '\u{11FB0}'),
+    ('\u{12000}', '\u{12399}'),
+    ('\u{12400}', '\u{1246E}'),
+    ('\u{12480}', '\u{12543}'),
+    ('\u{12F90}', '\u{12FF0}'),
+    ('\u{13000}', '\u{1342E}'),
+    ('\u{14400}', '\u{14646}'),
+    ('\u{16800}', '\u{16A38}'),
+    ('\u{16A40}', '\u{16A5E}'),
+    ('\u{16A60}', '\u{16A69}'),
+    ('\u{16A70}', '\u{16ABE}'),
+    ('\u{16AC0}', '\u{16AC9}'),
+    ('\u{16AD0}', '\u{16AED}'),
+    ('\u{16AF0}', '\u{16AF4}'),
+    ('\u{16B00}', '\u{16B36}'),
+    ('\u{16B40}', '\u{16B43}'),
+    ('\u{16B50}', '\u{16B59}'),
+    ('\u{16B63}', '\u{16B77}'),
+    ('\u{16B7D}', '\u{16B8F}'),
+    ('\u{16E40}', '\u{16E7F}'),
+    ('\u{16F00}', '\u{16F4A}'),
+    ('\u{16F4F}', '\u{16F87}'),
+    ('\u{16F8F}', '\u{16F9F}'),
+    ('\u{16FE0}', '\u{16FE1}'),
+    ('\u{16FE3}', '\u{16FE4}'),
+    ('\u{16FF0}', '\u{16FF1}'),
+    ('\u{17000}', '\u{187F7}'),
+    ('\u{18800}', '\u{18CD5}'),
+    ('\u{18D00}', '\u{18D08}'),
+    ('\u{1AFF0}', '\u{1AFF3}'),
+    ('\u{1AFF5}', '\u{1AFFB}'),
+    ('\u{1AFFD}', '\u{1AFFE}'),
+    ('\u{1B000}', '\u{1B122}'),
+    ('\u{1B150}', '\u{1B152}'),
+    ('\u{1B164}', '\u{1B167}'),
+    ('\u{1B170}', '\u{1B2FB}'),
+    ('\u{1BC00}', '\u{1BC6A}'),
+    ('\u{1BC70}', '\u{1BC7C}'),
+    ('\u{1BC80}', '\u{1BC88}'),
+    ('\u{1BC90}', '\u{1BC99}'),
+    ('\u{1BC9D}', '\u{1BC9E}'),
+    ('\u{1CF00}', '\u{1CF2D}'),
+    ('\u{1CF30}', '\u{1CF46}'),
+    ('\u{1D165}', '\u{1D169}'),
+    ('\u{1D16D}', '\u{1D172}'),
+    ('\u{1D17B}', '\u{1D182}'),
+    ('\u{1D185}', '\u{1D18B}'),
+    ('\u{1D1AA}', '\u{1D1AD}'),
+    ('\u{1D242}', '\u{1D244}'),
+    ('\u{1D400}', '\u{1D454}'),
+    ('\u{1D456}', '\u{1D49C}'),
+    ('\u{1D49E}', '\u{1D49F}'),
+    ('\u{1D4A2}', '\u{1D4A2}'),
+    ('\u{1D4A5}', '\u{1D4A6}'),
+    ('\u{1D4A9}', '\u{1D4AC}'),
+    ('\u{1D4AE}', '\u{1D4B9}'),
+    ('\u{1D4BB}', '\u{1D4BB}'),
+    ('\u{1D4BD}', '\u{1D4C3}'),
+    ('\u{1D4C5}', '\u{1D505}'),
+    ('\u{1D507}', '\u{1D50A}'),
+    ('\u{1D50D}', '\u{1D514}'),
+    ('\u{1D516}', '\u{1D51C}'),
+    ('\u{1D51E}', '\u{1D539}'),
+    ('\u{1D53B}', '\u{1D53E}'),
+    ('\u{1D540}', '\u{1D544}'),
+    ('\u{1D546}', '\u{1D546}'),
+    ('\u{1D54A}', '\u{1D550}'),
+    ('\u{1D552}', '\u{1D6A5}'),
+    ('\u{1D6A8}', '\u{1D6C0}'),
+    ('\u{1D6C2}', '\u{1D6DA}'),
+    ('\u{1D6DC}', '\u{1D6FA}'),
+    ('\u{1D6FC}', '\u{1D714}'),
+    ('\u{1D716}', '\u{1D734}'),
+    ('\u{1D736}', '\u{1D74E}'),
+    ('\u{1D750}', '\u{1D76E}'),
+    ('\u{1D770}', '\u{1D788}'),
+    ('\u{1D78A}', '\u{1D7A8}'),
+    ('\u{1D7AA}', '\u{1D7C2}'),
+    ('\u{1D7C4}', '\u{1D7CB}'),
+    ('\u{1D7CE}', '\u{1D7FF}'),
+    ('\u{1DA00}', '\u{1DA36}'),
+    ('\u{1DA3B}', '\u{1DA6C}'),
+    ('\u{1DA75}', '\u{1DA75}'),
+    ('\u{1DA84}', '\u{1DA84}'),
+    ('\u{1DA9B}', '\u{1DA9F}'),
+    ('\u{1DAA1}', '\u{1DAAF}'),
+    ('\u{1DF00}', '\u{1DF1E}'),
+    ('\u{1E000}', '\u{1E006}'),
+    ('\u{1E008}', '\u{1E018}'),
+    ('\u{1E01B}', '\u{1E021}'),
+    ('\u{1E023}', '\u{1E024}'),
+    ('\u{1E026}', '\u{1E02A}'),
+    ('\u{1E100}', '\u{1E12C}'),
+    ('\u{1E130}', '\u{1E13D}'),
+    ('\u{1E140}', '\u{1E149}'),
+    ('\u{1E14E}', '\u{1E14E}'),
+    ('\u{1E290}', '\u{1E2AE}'),
+    ('\u{1E2C0}', '\u{1E2F9}'),
+    ('\u{1E7E0}', '\u{1E7E6}'),
+    ('\u{1E7E8}', '\u{1E7EB}'),
+    ('\u{1E7ED}', '\u{1E7EE}'),
+    ('\u{1E7F0}', '\u{1E7FE}'),
+    ('\u{1E800}', '\u{1E8C4}'),
+    ('\u{1E8D0}', '\u{1E8D6}'),
+    ('\u{1E900}', '\u{1E94B}'),
+    ('\u{1E950}', '\u{1E959}'),
+    ('\u{1EE00}', '\u{1EE03}'),
+    ('\u{1EE05}', '\u{1EE1F}'),
+    ('\u{1EE21}', '\u{1EE22}'),
+    ('\u{1EE24}', '\u{1EE24}'),
+    ('\u{1EE27}', '\u{1EE27}'),
+    ('\u{1EE29}', '\u{1EE32}'),
+    ('\u{1EE34}', '\u{1EE37}'),
+    ('\u{1EE39}', '\u{1EE39}'),
+    ('\u{1EE3B}', '\u{1EE3B}'),
+    ('\u{1EE42}', '\u{1EE42}'),
+    ('\u{1EE47}', '\u{1EE47}'),
+    ('\u{1EE49}', '\u{1EE49}'),
+    ('\u{1EE4B}', '\u{1EE4B}'),
+    ('\u{1EE4D}', '\u{1EE4F}'),
+    ('\u{1EE51}', '\u{1EE52}'),
+    ('\u{1EE54}', '\u{1EE54}'),
+    ('\u{1EE57}', '\u{1EE57}'),
+    ('\u{1EE59}', '\u{1EE59}'),
+    ('\u{1EE5B}', '\u{1EE5B}'),
+    ('\u{1EE5D}', '\u{1EE5D}'),
+    ('\u{1EE5F}', '\u{1EE5F}'),
+    ('\u{1EE61}', '\u{1EE62}'),
+    ('\u{1EE64}', '\u{1EE64}'),
+    ('\u{1EE67}', '\u{1EE6A}'),
+    ('\u{1EE6C}', '\u{1EE72}'),
+    ('\u{1EE74}', '\u{1EE77}'),
+    ('\u{1EE79}', '\u{1EE7C}'),
+    ('\u{1EE7E}', '\u{1EE7E}'),
+    ('\u{1EE80}', '\u{1EE89}'),
+    ('\u{1EE8B}', '\u{1EE9B}'),
+    ('\u{1EEA1}', '\u{1EEA3}'),
+    ('\u{1EEA5}', '\u{1EEA9}'),
+    ('\u{1EEAB}', '\u{1EEBB}'),
+    ('\u{1FBF0}', '\u{1FBF9}'),
+    ('\u{20000}', '\u{2A6DF}'),
+    ('\u{2A700}', '\u{2B738}'),
+    ('\u{2B740}', '\u{2B81D}'),
+    ('\u{2B820}', '\u{2CEA1}'),
+    ('\u{2CEB0}', '\u{2EBE0}'),
+    ('\u{2F800}', '\u{2FA1D}'),
+    ('\u{30000}', '\u{3134A}'),
+    ('\u{E0100}', '\u{E01EF}'),
+];
+
+/// Ranges of chars with the `XID_Start` property, sorted by code point.
+pub(crate) static XID_START: &[(char, char)] = &[
+    ('\u{41}', '\u{5A}'),
+    ('\u{61}', '\u{7A}'),
+    ('\u{AA}', '\u{AA}'),
+    ('\u{B5}', '\u{B5}'),
+    ('\u{BA}', '\u{BA}'),
+    ('\u{C0}', '\u{D6}'),
+    ('\u{D8}', '\u{F6}'),
+    ('\u{F8}', '\u{2C1}'),
+    ('\u{2C6}', '\u{2D1}'),
+    ('\u{2E0}', '\u{2E4}'),
+    ('\u{2EC}', '\u{2EC}'),
+    ('\u{2EE}', '\u{2EE}'),
+    ('\u{370}', '\u{374}'),
+    ('\u{376}', '\u{377}'),
+    ('\u{37B}', '\u{37D}'),
+    ('\u{37F}', '\u{37F}'),
+    ('\u{386}', '\u{386}'),
+    ('\u{388}', '\u{38A}'),
+    ('\u{38C}', '\u{38C}'),
+    ('\u{38E}', '\u{3A1}'),
+    ('\u{3A3}', '\u{3F5}'),
+    ('\u{3F7}', '\u{481}'),
+    ('\u{48A}', '\u{52F}'),
+    ('\u{531}', '\u{556}'),
+    ('\u{559}', '\u{559}'),
+    ('\u{560}', '\u{588}'),
+    ('\u{5D0}', '\u{5EA}'),
+    ('\u{5EF}', '\u{5F2}'),
+    ('\u{620}', '\u{64A}'),
+    ('\u{66E}', '\u{66F}'),
+    ('\u{671}', '\u{6D3}'),
+    ('\u{6D5}', '\u{6D5}'),
+    ('\u{6E5}', '\u{6E6}'),
+    ('\u{6EE}', '\u{6EF}'),
+    ('\u{6FA}', '\u{6FC}'),
+    ('\u{6FF}', '\u{6FF}'),
+    ('\u{710}', '\u{710}'),
+    ('\u{712}', '\u{72F}'),
+    ('\u{74D}', '\u{7A5}'),
+    ('\u{7B1}', '\u{7B1}'),
+    ('\u{7CA}', '\u{7EA}'),
+    ('\u{7F4}', '\u{7F5}'),
+    ('\u{7FA}', '\u{7FA}'),
+    ('\u{800}', '\u{815}'),
+    ('\u{81A}', '\u{81A}'),
+    ('\u{824}', '\u{824}'),
+    ('\u{828}', '\u{828}'),
+    ('\u{840}', '\u{858}'),
+    ('\u{860}', '\u{86A}'),
+    ('\u{870}', '\u{887}'),
+    ('\u{889}', '\u{88E}'),
+    ('\u{8A0}', '\u{8C9}'),
+    ('\u{904}', '\u{939}'),
+    ('\u{93D}', '\u{93D}'),
+    ('\u{950}', '\u{950}'),
+    ('\u{958}', '\u{961}'),
+    ('\u{971}', '\u{980}'),
+    ('\u{985}', '\u{98C}'),
+    ('\u{98F}', '\u{990}'),
+    ('\u{993}', '\u{9A8}'),
+    ('\u{9AA}', '\u{9B0}'),
+    ('\u{9B2}', '\u{9B2}'),
+    ('\u{9B6}', '\u{9B9}'),
+    ('\u{9BD}', '\u{9BD}'),
+    ('\u{9CE}', '\u{9CE}'),
+    ('\u{9DC}', '\u{9DD}'),
+    ('\u{9DF}', '\u{9E1}'),
+    ('\u{9F0}', '\u{9F1}'),
+    ('\u{9FC}', '\u{9FC}'),
+    ('\u{A05}', '\u{A0A}'),
+    ('\u{A0F}', '\u{A10}'),
+    ('\u{A13}', '\u{A28}'),
+    ('\u{A2A}', '\u{A30}'),
+    ('\u{A32}', '\u{A33}'),
+    ('\u{A35}', '\u{A36}'),
+    ('\u{A38}', '\u{A39}'),
+    ('\u{A59}', '\u{A5C}'),
+    ('\u{A5E}', '\u{A5E}'),
+    ('\u{A72}', '\u{A74}'),
+    ('\u{A85}', '\u{A8D}'),
+    ('\u{A8F}', '\u{A91}'),
+    ('\u{A93}', '\u{AA8}'),
+    ('\u{AAA}', '\u{AB0}'),
+    ('\u{AB2}', '\u{AB3}'),
+    ('\u{AB5}', '\u{AB9}'),
+    ('\u{ABD}', '\u{ABD}'),
+    ('\u{AD0}', '\u{AD0}'),
+    ('\u{AE0}', '\u{AE1}'),
+    ('\u{AF9}', '\u{AF9}'),
+    ('\u{B05}', '\u{B0C}'),
+    ('\u{B0F}', '\u{B10}'),
+    ('\u{B13}', '\u{B28}'),
+    ('\u{B2A}', '\u{B30}'),
+    ('\u{B32}', '\u{B33}'),
+    ('\u{B35}', '\u{B39}'),
+    ('\u{B3D}', '\u{B3D}'),
+    ('\u{B5C}', '\u{B5D}'),
+    ('\u{B5F}', '\u{B61}'),
+    ('\u{B71}', '\u{B71}'),
+    ('\u{B83}', '\u{B83}'),
+    ('\u{B85}', '\u{B8A}'),
+    ('\u{B8E}', '\u{B90}'),
+    ('\u{B92}', '\u{B95}'),
+    ('\u{B99}', '\u{B9A}'),
+    ('\u{B9C}', '\u{B9C}'),
+    ('\u{B9E}', '\u{B9F}'),
+    ('\u{BA3}', '\u{BA4}'),
+    ('\u{BA8}', '\u{BAA}'),
+    ('\u{BAE}', '\u{BB9}'),
+    ('\u{BD0}', '\u{BD0}'),
+    ('\u{C05}', '\u{C0C}'),
+    ('\u{C0E}', '\u{C10}'),
+    ('\u{C12}', '\u{C28}'),
+    ('\u{C2A}', '\u{C39}'),
+    ('\u{C3D}', '\u{C3D}'),
+    ('\u{C58}', '\u{C5A}'),
+    ('\u{C5D}', '\u{C5D}'),
+    ('\u{C60}', '\u{C61}'),
+    ('\u{C80}', '\u{C80}'),
+    ('\u{C85}', '\u{C8C}'),
+    ('\u{C8E}', '\u{C90}'),
+    ('\u{C92}', '\u{CA8}'),
+    ('\u{CAA}', '\u{CB3}'),
+    ('\u{CB5}', '\u{CB9}'),
+    ('\u{CBD}', '\u{CBD}'),
+    ('\u{CDD}', '\u{CDE}'),
+    ('\u{CE0}', '\u{CE1}'),
+    ('\u{CF1}', '\u{CF2}'),
+    ('\u{D04}', '\u{D0C}'),
+    ('\u{D0E}', '\u{D10}'),
+    ('\u{D12}', '\u{D3A}'),
+    ('\u{D3D}', '\u{D3D}'),
+    ('\u{D4E}', '\u{D4E}'),
+    ('\u{D54}', '\u{D56}'),
+    ('\u{D5F}', '\u{D61}'),
+    ('\u{D7A}', '\u{D7F}'),
+    ('\u{D85}', '\u{D96}'),
+    ('\u{D9A}', '\u{DB1}'),
+    ('\u{DB3}', '\u{DBB}'),
+    ('\u{DBD}', '\u{DBD}'),
+    ('\u{DC0}', '\u{DC6}'),
+    ('\u{E01}', '\u{E30}'),
+    ('\u{E32}', '\u{E32}'),
+    ('\u{E40}', '\u{E46}'),
+    ('\u{E81}', '\u{E82}'),
+    ('\u{E84}', '\u{E84}'),
+    ('\u{E86}', '\u{E8A}'),
+    ('\u{E8C}', '\u{EA3}'),
+    ('\u{EA5}', '\u{EA5}'),
+    ('\u{EA7}', '\u{EB0}'),
+    ('\u{EB2}', '\u{EB2}'),
+    ('\u{EBD}', '\u{EBD}'),
+    ('\u{EC0}', '\u{EC4}'),
+    ('\u{EC6}', '\u{EC6}'),
+    ('\u{EDC}', '\u{EDF}'),
+    ('\u{F00}', '\u{F00}'),
+    ('\u{F40}', '\u{F47}'),
+    ('\u{F49}', '\u{F6C}'),
+    ('\u{F88}', '\u{F8C}'),
+    ('\u{1000}', '\u{102A}'),
+    ('\u{103F}', '\u{103F}'),
+    ('\u{1050}', '\u{1055}'),
+    ('\u{105A}', '\u{105D}'),
+    ('\u{1061}', '\u{1061}'),
+    ('\u{1065}', '\u{1066}'),
+    ('\u{106E}', '\u{1070}'),
+    ('\u{1075}', '\u{1081}'),
+    ('\u{108E}', '\u{108E}'),
+    ('\u{10A0}', '\u{10C5}'),
+    ('\u{10C7}', '\u{10C7}'),
+    ('\u{10CD}', '\u{10CD}'),
+    ('\u{10D0}', '\u{10FA}'),
+    ('\u{10FC}', '\u{1248}'),
+    ('\u{124A}', '\u{124D}'),
+    ('\u{1250}', '\u{1256}'),
+    ('\u{1258}', '\u{1258}'),
+    ('\u{125A}', '\u{125D}'),
+    ('\u{1260}', '\u{1288}'),
+    ('\u{128A}', '\u{128D}'),
+    ('\u{1290}', '\u{12B0}'),
+    ('\u{12B2}', '\u{12B5}'),
+    ('\u{12B8}', '\u{12BE}'),
+    ('\u{12C0}', '\u{12C0}'),
+    ('\u{12C2}', '\u{12C5}'),
+    ('\u{12C8}', '\u{12D6}'),
+    ('\u{12D8}', '\u{1310}'),
+    ('\u{1312}', '\u{1315}'),
+    ('\u{1318}', '\u{135A}'),
+    ('\u{1380}', '\u{138F}'),
+    ('\u{13A0}', '\u{13F5}'),
+    ('\u{13F8}', '\u{13FD}'),
+    ('\u{1401}', '\u{166C}'),
+    ('\u{166F}', '\u{167F}'),
+    ('\u{1681}', '\u{169A}'),
+    ('\u{16A0}', '\u{16EA}'),
+    ('\u{16EE}', '\u{16F8}'),
+    ('\u{1700}', '\u{1711}'),
+    ('\u{171F}', '\u{1731}'),
+    ('\u{1740}', '\u{1751}'),
+    ('\u{1760}', '\u{176C}'),
+    ('\u{176E}', '\u{1770}'),
+    ('\u{1780}', '\u{17B3}'),
+    ('\u{17D7}', '\u{17D7}'),
+    ('\u{17DC}', '\u{17DC}'),
+    ('\u{1820}', '\u{1878}'),
+    ('\u{1880}', '\u{18A8}'),
+    ('\u{18AA}', '\u{18AA}'),
+    ('\u{18B0}', '\u{18F5}'),
+    ('\u{1900}', '\u{191E}'),
+    ('\u{1950}', '\u{196D}'),
+    ('\u{1970}', '\u{1974}'),
+    ('\u{1980}', '\u{19AB}'),
+    ('\u{19B0}', '\u{19C9}'),
+    ('\u{1A00}', '\u{1A16}'),
+    ('\u{1A20}', '\u{1A54}'),
+    ('\u{1AA7}', '\u{1AA7}'),
+    ('\u{1B05}', '\u{1B33}'),
+    ('\u{1B45}', '\u{1B4C}'),
+    ('\u{1B83}', '\u{1BA0}'),
+    ('\u{1BAE}', '\u{1BAF}'),
+    ('\u{1BBA}', '\u{1BE5}'),
+    ('\u{1C00}', '\u{1C23}'),
+    ('\u{1C4D}', '\u{1C4F}'),
+    ('\u{1C5A}', '\u{1C7D}'),
+    ('\u{1C80}', '\u{1C88}'),
+    ('\u{1C90}', '\u{1CBA}'),
+    ('\u{1CBD}', '\u{1CBF}'),
+    ('\u{1CE9}', '\u{1CEC}'),
+    ('\u{1CEE}', '\u{1CF3}'),
+    ('\u{1CF5}', '\u{1CF6}'),
+    ('\u{1CFA}', '\u{1CFA}'),
+    ('\u{1D00}', '\u{1DBF}'),
+    ('\u{1E00}', '\u{1F15}'),
+    ('\u{1F18}', '\u{1F1D}'),
+    ('\u{1F20}', '\u{1F45}'),
+    ('\u{1F48}', '\u{1F4D}'),
+    ('\u{1F50}', '\u{1F57}'),
+    ('\u{1F59}', '\u{1F59}'),
+    ('\u{1F5B}', '\u{1F5B}'),
+    ('\u{1F5D}', '\u{1F5D}'),
+    ('\u{1F5F}', '\u{1F7D}'),
+    ('\u{1F80}', '\u{1FB4}'),
+    ('\u{1FB6}', '\u{1FBC}'),
+    ('\u{1FBE}', '\u{1FBE}'),
+    ('\u{1FC2}', '\u{1FC4}'),
+    ('\u{1FC6}', '\u{1FCC}'),
+    ('\u{1FD0}', '\u{1FD3}'),
+    ('\u{1FD6}', '\u{1FDB}'),
+    ('\u{1FE0}', '\u{1FEC}'),
+    ('\u{1FF2}', '\u{1FF4}'),
+    ('\u{1FF6}', '\u{1FFC}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207F}', '\u{207F}'),
+    ('\u{2090}', '\u{209C}'),
+    ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'),
+    ('\u{210A}', '\u{2113}'),
+    ('\u{2115}', '\u{2115}'),
+    ('\u{2118}', '\u{211D}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{2128}', '\u{2128}'),
+    ('\u{212A}', '\u{2139}'),
+    ('\u{213C}', '\u{213F}'),
+    ('\u{2145}', '\u{2149}'),
+    ('\u{214E}', '\u{214E}'),
+    ('\u{2160}', '\u{2188}'),
+    ('\u{2C00}', '\u{2CE4}'),
+    ('\u{2CEB}', '\u{2CEE}'),
+    ('\u{2CF2}', '\u{2CF3}'),
+    ('\u{2D00}', '\u{2D25}'),
+    ('\u{2D27}', '\u{2D27}'),
+    ('\u{2D2D}', '\u{2D2D}'),
+    ('\u{2D30}', '\u{2D67}'),
+    ('\u{2D6F}', '\u{2D6F}'),
+    ('\u{2D80}', '\u{2D96}'),
+    ('\u{2DA0}', '\u{2DA6}'),
+    ('\u{2DA8}', '\u{2DAE}'),
+    ('\u{2DB0}', '\u{2DB6}'),
+    ('\u{2DB8}', '\u{2DBE}'),
+    ('\u{2DC0}', '\u{2DC6}'),
+    ('\u{2DC8}', '\u{2DCE}'),
+    ('\u{2DD0}', '\u{2DD6}'),
+    ('\u{2DD8}', '\u{2DDE}'),
+    ('\u{3005}', '\u{3007}'),
+    ('\u{3021}', '\u{3029}'),
+    ('\u{3031}', '\u{3035}'),
+    ('\u{3038}', '\u{303C}'),
+    ('\u{3041}', '\u{3096}'),
+    ('\u{309D}', '\u{309F}'),
+    ('\u{30A1}', '\u{30FA}'),
+    ('\u{30FC}', '\u{30FF}'),
+    ('\u{3105}', '\u{312F}'),
+    ('\u{3131}', '\u{318E}'),
+    ('\u{31A0}', '\u{31BF}'),
+    ('\u{31F0}', '\u{31FF}'),
+    ('\u{3400}', '\u{4DBF}'),
+    ('\u{4E00}', '\u{A48C}'),
+    ('\u{A4D0}', '\u{A4FD}'),
+    ('\u{A500}', '\u{A60C}'),
+    ('\u{A610}', '\u{A61F}'),
+    ('\u{A62A}', '\u{A62B}'),
+    ('\u{A640}', '\u{A66E}'),
+    ('\u{A67F}', '\u{A69D}'),
+    ('\u{A6A0}', '\u{A6EF}'),
+    ('\u{A717}', '\u{A71F}'),
+    ('\u{A722}', '\u{A788}'),
+    ('\u{A78B}', '\u{A7CA}'),
+    ('\u{A7D0}', '\u{A7D1}'),
+    ('\u{A7D3}', '\u{A7D3}'),
+    ('\u{A7D5}', '\u{A7D9}'),
+    ('\u{A7F2}', '\u{A801}'),
+    ('\u{A803}', '\u{A805}'),
+    ('\u{A807}', '\u{A80A}'),
+    ('\u{A80C}', '\u{A822}'),
+    ('\u{A840}', '\u{A873}'),
+    ('\u{A882}', '\u{A8B3}'),
+    ('\u{A8F2}', '\u{A8F7}'),
+    ('\u{A8FB}', '\u{A8FB}'),
+    ('\u{A8FD}', '\u{A8FE}'),
+    ('\u{A90A}', '\u{A925}'),
+    ('\u{A930}', '\u{A946}'),
+    ('\u{A960}', '\u{A97C}'),
+    ('\u{A984}', '\u{A9B2}'),
+    ('\u{A9CF}', '\u{A9CF}'),
+    ('\u{A9E0}', '\u{A9E4}'),
+    ('\u{A9E6}', '\u{A9EF}'),
+    ('\u{A9FA}', '\u{A9FE}'),
+    ('\u{AA00}', '\u{AA28}'),
+    ('\u{AA40}', '\u{AA42}'),
+    ('\u{AA44}', '\u{AA4B}'),
+    ('\u{AA60}', '\u{AA76}'),
+    ('\u{AA7A}', '\u{AA7A}'),
+    ('\u{AA7E}', '\u{AAAF}'),
+    ('\u{AAB1}', '\u{AAB1}'),
+    ('\u{AAB5}', '\u{AAB6}'),
+    ('\u{AAB9}', '\u{AABD}'),
+    ('\u{AAC0}', '\u{AAC0}'),
+    ('\u{AAC2}', '\u{AAC2}'),
+    ('\u{AADB}', '\u{AADD}'),
+    ('\u{AAE0}', '\u{AAEA}'),
+    ('\u{AAF2}', '\u{AAF4}'),
+    ('\u{AB01}', '\u{AB06}'),
+    ('\u{AB09}', '\u{AB0E}'),
+    ('\u{AB11}', '\u{AB16}'),
+    ('\u{AB20}', '\u{AB26}'),
+    ('\u{AB28}', '\u{AB2E}'),
+    ('\u{AB30}', '\u{AB5A}'),
+    ('\u{AB5C}', '\u{AB69}'),
+    ('\u{AB70}', '\u{ABE2}'),
+    ('\u{AC00}', '\u{D7A3}'),
+    ('\u{D7B0}', '\u{D7C6}'),
+    ('\u{D7CB}', '\u{D7FB}'),
+    ('\u{F900}', '\u{FA6D}'),
+    ('\u{FA70}', '\u{FAD9}'),
+    ('\u{FB00}', '\u{FB06}'),
+    ('\u{FB13}', '\u{FB17}'),
+    ('\u{FB1D}', '\u{FB1D}'),
+    ('\u{FB1F}', '\u{FB28}'),
+    ('\u{FB2A}', '\u{FB36}'),
+    ('\u{FB38}', '\u{FB3C}'),
+    ('\u{FB3E}', '\u{FB3E}'),
+    ('\u{FB40}', '\u{FB41}'),
+    ('\u{FB43}', '\u{FB44}'),
+    ('\u{FB46}', '\u{FBB1}'),
+    ('\u{FBD3}', '\u{FC5D}'),
+    ('\u{FC64}', '\u{FD3D}'),
+    ('\u{FD50}', '\u{FD8F}'),
+    ('\u{FD92}', '\u{FDC7}'),
+    ('\u{FDF0}', '\u{FDF9}'),
+    ('\u{FE71}', '\u{FE71}'),
+    ('\u{FE73}', '\u{FE73}'),
+    ('\u{FE77}', '\u{FE77}'),
+    ('\u{FE79}', '\u{FE79}'),
+    ('\u{FE7B}', '\u{FE7B}'),
+    ('\u{FE7D}', '\u{FE7D}'),
+    ('\u{FE7F}', '\u{FEFC}'),
+    ('\u{FF21}', '\u{FF3A}'),
+    ('\u{FF41}', '\u{FF5A}'),
+    ('\u{FF66}', '\u{FF9D}'),
+    ('\u{FFA0}', '\u{FFBE}'),
+    ('\u{FFC2}', '\u{FFC7}'),
+    ('\u{FFCA}', '\u{FFCF}'),
+    ('\u{FFD2}', '\u{FFD7}'),
+    ('\u{FFDA}', '\u{FFDC}'),
+    ('\u{10000}', '\u{1000B}'),
+    ('\u{1000D}', '\u{10026}'),
+    ('\u{10028}', '\u{1003A}'),
+    ('\u{1003C}', '\u{1003D}'),
+    ('\u{1003F}', '\u{1004D}'),
+    ('\u{10050}', '\u{1005D}'),
+    ('\u{10080}', '\u{100FA}'),
+    ('\u{10140}', '\u{10174}'),
+    ('\u{10280}', '\u{1029C}'),
+    ('\u{102A0}', '\u{102D0}'),
+    ('\u{10300}', '\u{1031F}'),
+    ('\u{1032D}', '\u{1034A}'),
+    ('\u{10350}', '\u{10375}'),
+    ('\u{10380}', '\u{1039D}'),
+    ('\u{103A0}', '\u{103C3}'),
+    ('\u{103C8}', '\u{103CF}'),
+    ('\u{103D1}', '\u{103D5}'),
+    ('\u{10400}', '\u{1049D}'),
+    ('\u{104B0}', '\u{104D3}'),
+    ('\u{104D8}', '\u{104FB}'),
+    ('\u{10500}', '\u{10527}'),
+    ('\u{10530}', '\u{10563}'),
+    ('\u{10570}', '\u{1057A}'),
+    ('\u{1057C}', '\u{1058A}'),
+    ('\u{1058C}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'),
+    ('\u{10597}', '\u{105A1}'),
+    ('\u{105A3}', '\u{105B1}'),
+    ('\u{105B3}', '\u{105B9}'),
+    ('\u{105BB}', '\u{105BC}'),
+    ('\u{10600}', '\u{10736}'),
+    ('\u{10740}', '\u{10755}'),
+    ('\u{10760}', '\u{10767}'),
+    ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107B0}'),
+    ('\u{107B2}', '\u{107BA}'),
+    ('\u{10800}', '\u{10805}'),
+    ('\u{10808}', '\u{10808}'),
+    ('\u{1080A}', '\u{10835}'),
+    ('\u{10837}', '\u{10838}'),
+    ('\u{1083C}', '\u{1083C}'),
+    ('\u{1083F}', '\u{10855}'),
+    ('\u{10860}', '\u{10876}'),
+    ('\u{10880}', '\u{1089E}'),
+    ('\u{108E0}', '\u{108F2}'),
+    ('\u{108F4}', '\u{108F5}'),
+    ('\u{10900}', '\u{10915}'),
+    ('\u{10920}', '\u{10939}'),
+    ('\u{10980}', '\u{109B7}'),
+    ('\u{109BE}', '\u{109BF}'),
+    ('\u{10A00}', '\u{10A00}'),
+    ('\u{10A10}', '\u{10A13}'),
+    ('\u{10A15}', '\u{10A17}'),
+    ('\u{10A19}', '\u{10A35}'),
+    ('\u{10A60}', '\u{10A7C}'),
+    ('\u{10A80}', '\u{10A9C}'),
+    ('\u{10AC0}', '\u{10AC7}'),
+    ('\u{10AC9}', '\u{10AE4}'),
+    ('\u{10B00}', '\u{10B35}'),
+    ('\u{10B40}', '\u{10B55}'),
+    ('\u{10B60}', '\u{10B72}'),
+    ('\u{10B80}', '\u{10B91}'),
+    ('\u{10C00}', '\u{10C48}'),
+    ('\u{10C80}', '\u{10CB2}'),
+    ('\u{10CC0}', '\u{10CF2}'),
+    ('\u{10D00}', '\u{10D23}'),
+    ('\u{10E80}', '\u{10EA9}'),
+    ('\u{10EB0}', '\u{10EB1}'),
+    ('\u{10F00}', '\u{10F1C}'),
+    ('\u{10F27}', '\u{10F27}'),
+    ('\u{10F30}', '\u{10F45}'),
+    ('\u{10F70}', '\u{10F81}'),
+    ('\u{10FB0}', '\u{10FC4}'),
+    ('\u{10FE0}', '\u{10FF6}'),
+    ('\u{11003}', '\u{11037}'),
+    ('\u{11071}', '\u{11072}'),
+    ('\u{11075}', '\u{11075}'),
+    ('\u{11083}', '\u{110AF}'),
+    ('\u{110D0}', '\u{110E8}'),
+    ('\u{11103}', '\u{11126}'),
+    ('\u{11144}', '\u{11144}'),
+    ('\u{11147}', '\u{11147}'),
+    ('\u{11150}', '\u{11172}'),
+    ('\u{11176}', '\u{11176}'),
+    ('\u{11183}', '\u{111B2}'),
+    ('\u{111C1}', '\u{111C4}'),
+    ('\u{111DA}', '\u{111DA}'),
+    ('\u{111DC}', '\u{111DC}'),
+    ('\u{11200}', '\u{11211}'),
+    ('\u{11213}', '\u{1122B}'),
+    ('\u{11280}', '\u{11286}'),
+    ('\u{11288}', '\u{11288}'),
+    ('\u{1128A}', '\u{1128D}'),
+    ('\u{1128F}', '\u{1129D}'),
+    ('\u{1129F}', '\u{112A8}'),
+    ('\u{112B0}', '\u{112DE}'),
+    ('\u{11305}', '\u{1130C}'),
+    ('\u{1130F}', '\u{11310}'),
+    ('\u{11313}', '\u{11328}'),
+    ('\u{1132A}', '\u{11330}'),
+    ('\u{11332}', '\u{11333}'),
+    ('\u{11335}', '\u{11339}'),
+    ('\u{1133D}', '\u{1133D}'),
+    ('\u{11350}', '\u{11350}'),
+    ('\u{1135D}', '\u{11361}'),
+    ('\u{11400}', '\u{11434}'),
+    ('\u{11447}', '\u{1144A}'),
+    ('\u{1145F}', '\u{11461}'),
+    ('\u{11480}', '\u{114AF}'),
+    ('\u{114C4}', '\u{114C5}'),
+    ('\u{114C7}', '\u{114C7}'),
+    ('\u{11580}', '\u{115AE}'),
+    ('\u{115D8}', '\u{115DB}'),
+    ('\u{11600}', '\u{1162F}'),
+    ('\u{11644}', '\u{11644}'),
+    ('\u{11680}', '\u{116AA}'),
+    ('\u{116B8}', '\u{116B8}'),
+    ('\u{11700}', '\u{1171A}'),
+    ('\u{11740}', '\u{11746}'),
+    ('\u{11800}', '\u{1182B}'),
+    ('\u{118A0}', '\u{118DF}'),
+    ('\u{118FF}', '\u{11906}'),
+    ('\u{11909}', '\u{11909}'),
+    ('\u{1190C}', '\u{11913}'),
+    ('\u{11915}', '\u{11916}'),
+    ('\u{11918}', '\u{1192F}'),
+    ('\u{1193F}', '\u{1193F}'),
+    ('\u{11941}', '\u{11941}'),
+    ('\u{119A0}', '\u{119A7}'),
+    ('\u{119AA}', '\u{119D0}'),
+    ('\u{119E1}', '\u{119E1}'),
+    ('\u{119E3}', '\u{119E3}'),
+    ('\u{11A00}', '\u{11A00}'),
+    ('\u{11A0B}', '\u{11A32}'),
+    ('\u{11A3A}', '\u{11A3A}'),
+    ('\u{11A50}', '\u{11A50}'),
+    ('\u{11A5C}', '\u{11A89}'),
+    ('\u{11A9D}', '\u{11A9D}'),
+    ('\u{11AB0}', '\u{11AF8}'),
+    ('\u{11C00}', '\u{11C08}'),
+    ('\u{11C0A}', '\u{11C2E}'),
+    ('\u{11C40}', '\u{11C40}'),
+    ('\u{11C72}', '\u{11C8F}'),
+    ('\u{11D00}', '\u{11D06}'),
+    ('\u{11D08}', '\u{11D09}'),
+    ('\u{11D0B}', '\u{11D30}'),
+    ('\u{11D46}', '\u{11D46}'),
+    ('\u{11D60}', '\u{11D65}'),
+    ('\u{11D67}', '\u{11D68}'),
+    ('\u{11D6A}', '\u{11D89}'),
+    ('\u{11D98}', '\u{11D98}'),
+    ('\u{11EE0}', '\u{11EF2}'),
+    ('\u{11FB0}', '\u{11FB0}'),
+    ('\u{12000}', '\u{12399}'),
+    ('\u{12400}', '\u{1246E}'),
+    ('\u{12480}', '\u{12543}'),
+    ('\u{12F90}', '\u{12FF0}'),
+    ('\u{13000}', '\u{1342E}'),
+    ('\u{14400}', '\u{14646}'),
+    ('\u{16800}', '\u{16A38}'),
+    ('\u{16A40}', '\u{16A5E}'),
+    ('\u{16A70}', '\u{16ABE}'),
+    ('\u{16AD0}', '\u{16AED}'),
+    ('\u{16B00}', '\u{16B2F}'),
+    ('\u{16B40}', '\u{16B43}'),
+    ('\u{16B63}', '\u{16B77}'),
+    ('\u{16B7D}', '\u{16B8F}'),
+    ('\u{16E40}', '\u{16E7F}'),
+    ('\u{16F00}', '\u{16F4A}'),
+    ('\u{16F50}', '\u{16F50}'),
+    ('\u{16F93}', '\u{16F9F}'),
+    ('\u{16FE0}', '\u{16FE1}'),
+    ('\u{16FE3}', '\u{16FE3}'),
+    ('\u{17000}', '\u{187F7}'),
+    ('\u{18800}', '\u{18CD5}'),
+    ('\u{18D00}', '\u{18D08}'),
+    ('\u{1AFF0}', '\u{1AFF3}'),
+    ('\u{1AFF5}', '\u{1AFFB}'),
+    ('\u{1AFFD}', '\u{1AFFE}'),
+    ('\u{1B000}', '\u{1B122}'),
+    ('\u{1B150}', '\u{1B152}'),
+    ('\u{1B164}', '\u{1B167}'),
+    ('\u{1B170}', '\u{1B2FB}'),
+    ('\u{1BC00}', '\u{1BC6A}'),
+    ('\u{1BC70}', '\u{1BC7C}'),
+    ('\u{1BC80}', '\u{1BC88}'),
+    ('\u{1BC90}', '\u{1BC99}'),
+    ('\u{1D400}', '\u{1D454}'),
+    ('\u{1D456}', '\u{1D49C}'),
+    ('\u{1D49E}', '\u{1D49F}'),
+    ('\u{1D4A2}', '\u{1D4A2}'),
+    ('\u{1D4A5}', '\u{1D4A6}'),
+    ('\u{1D4A9}', '\u{1D4AC}'),
+    ('\u{1D4AE}', '\u{1D4B9}'),
+    ('\u{1D4BB}', '\u{1D4BB}'),
+    ('\u{1D4BD}', '\u{1D4C3}'),
+    ('\u{1D4C5}', '\u{1D505}'),
+    ('\u{1D507}', '\u{1D50A}'),
+    ('\u{1D50D}', '\u{1D514}'),
+    ('\u{1D516}', '\u{1D51C}'),
+    ('\u{1D51E}', '\u{1D539}'),
+    ('\u{1D53B}', '\u{1D53E}'),
+    ('\u{1D540}', '\u{1D544}'),
+    ('\u{1D546}', '\u{1D546}'),
+    ('\u{1D54A}', '\u{1D550}'),
+    ('\u{1D552}', '\u{1D6A5}'),
+    ('\u{1D6A8}', '\u{1D6C0}'),
+    ('\u{1D6C2}', '\u{1D6DA}'),
+    ('\u{1D6DC}', '\u{1D6FA}'),
+    ('\u{1D6FC}', '\u{1D714}'),
+    ('\u{1D716}', '\u{1D734}'),
+    ('\u{1D736}', '\u{1D74E}'),
+    ('\u{1D750}', '\u{1D76E}'),
+    ('\u{1D770}', '\u{1D788}'),
+    ('\u{1D78A}', '\u{1D7A8}'),
+    ('\u{1D7AA}', '\u{1D7C2}'),
+    ('\u{1D7C4}', '\u{1D7CB}'),
+    ('\u{1DF00}', '\u{1DF1E}'),
+    ('\u{1E100}', '\u{1E12C}'),
+    ('\u{1E137}', '\u{1E13D}'),
+    ('\u{1E14E}', '\u{1E14E}'),
+    ('\u{1E290}', '\u{1E2AD}'),
+    ('\u{1E2C0}', '\u{1E2EB}'),
+    ('\u{1E7E0}', '\u{1E7E6}'),
+    ('\u{1E7E8}', '\u{1E7EB}'),
+    ('\u{1E7ED}', '\u{1E7EE}'),
+    ('\u{1E7F0}', '\u{1E7FE}'),
+    ('\u{1E800}', '\u{1E8C4}'),
+    ('\u{1E900}', '\u{1E943}'),
+    ('\u{1E94B}', '\u{1E94B}'),
+    ('\u{1EE00}', '\u{1EE03}'),
+    ('\u{1EE05}', '\u{1EE1F}'),
+    ('\u{1EE21}', '\u{1EE22}'),
+    ('\u{1EE24}', '\u{1EE24}'),
+    ('\u{1EE27}', '\u{1EE27}'),
+    ('\u{1EE29}', '\u{1EE32}'),
+    ('\u{1EE34}', '\u{1EE37}'),
+    ('\u{1EE39}', '\u{1EE39}'),
+    ('\u{1EE3B}', '\u{1EE3B}'),
+    ('\u{1EE42}', '\u{1EE42}'),
+    ('\u{1EE47}', '\u{1EE47}'),
+    ('\u{1EE49}', '\u{1EE49}'),
+    ('\u{1EE4B}', '\u{1EE4B}'),
+    ('\u{1EE4D}', '\u{1EE4F}'),
+    ('\u{1EE51}', '\u{1EE52}'),
+    ('\u{1EE54}', '\u{1EE54}'),
+    ('\u{1EE57}', '\u{1EE57}'),
+    ('\u{1EE59}', '\u{1EE59}'),
+    ('\u{1EE5B}', '\u{1EE5B}'),
+    ('\u{1EE5D}', '\u{1EE5D}'),
+    ('\u{1EE5F}', '\u{1EE5F}'),
+    ('\u{1EE61}', '\u{1EE62}'),
+    ('\u{1EE64}', '\u{1EE64}'),
+    ('\u{1EE67}', '\u{1EE6A}'),
+    ('\u{1EE6C}', '\u{1EE72}'),
+    ('\u{1EE74}', '\u{1EE77}'),
+    ('\u{1EE79}', '\u{1EE7C}'),
+    ('\u{1EE7E}', '\u{1EE7E}'),
+    ('\u{1EE80}', '\u{1EE89}'),
+    ('\u{1EE8B}', '\u{1EE9B}'),
+    ('\u{1EEA1}', '\u{1EEA3}'),
+    ('\u{1EEA5}', '\u{1EEA9}'),
+    ('\u{1EEAB}', '\u{1EEBB}'),
+    ('\u{20000}', '\u{2A6DF}'),
+    ('\u{2A700}', '\u{2B738}'),
+    ('\u{2B740}', '\u{2B81D}'),
+    ('\u{2B820}', '\u{2CEA1}'),
+    ('\u{2CEB0}', '\u{2EBE0}'),
+    ('\u{2F800}', '\u{2FA1D}'),
+    ('\u{30000}', '\u{3134A}'),
+];
@@ -0,0 +1,10176 @@
+// This file is generated by `unicode_string_gen`. Do not edit it manually.
+
+/// The number of bits of the values of [`TRANSLITERATIONS`] used for the length.
+pub(crate) const LENGTH_BITS: u32 = 6;
+
+/// The distinct transliterations, concatenated.
+pub(crate) static TRANSLITERATION_TEXT: &str = "\
+    \x20!C/PS$?Y=|SS\"(c)a<<(r)-deg+-23'uP*,1o>>1/41/23/4?AAECEIDNOxUYThssaeceidn/ythGgHhIJijJjKkLl'nngN\
+    GOEoeRrSsTtWwZzbB6@FfhvOIoipYRSHshVZHzh5ts|||=DZDzdzLJLjljNJNjnjHVOUoudbqp^qlZmvdZtStCfNlslzWW]]\
+    `<>\\_:.+~X4=``//[--][__]s.\"';MKsPhKhPsphkhps&QStstSpspShCHchTitiTHr/S.IeIoDjGjYiTshKjDzhZhTsChSh\
+    chIuIashchiuiaieiodjgjyitshkjdzhksOtot*1000**100.000**1.000.000*JhjhR'r'G'g'Zh'zh'Z'z'K'k'N'n'Ng\
+    P'p'S's'T't'U'u'Kh'kh'TtsttsCh'ch'LhlhNhnhMhmhAeGhghXhxhZjzjSjsjTjtjLkhlkhRkhrkhNynyDchdchT`Ch`R\
+    hTs`P`K`t`ch`rhts`p`k`ewDramYYYoy#sn.sm.cbrt4rt%0%00Afw'y'dhanunin0789%'w'u'ytttthbh'hdycchddDtd\
+    dhrrGHhyyu+m{}yhmbslaaiiooeehheyoagb..<.-<-....kdLEptvbzlmvmpnrgykygronounenuueNaioNaunnnnnlllRR\
+    AUMkhhghhdddhyyLL /  // r`Rs1/2/3/4/ 1 - 1//16bbllG.E.O.yaOm+10++100++1000+121632486410100100080\
+    160240204060aaenngjnynyjnnddnd . amueuueBh.ao *  /// eiayhnhmgn$ -/  +/  X/  /XX/  /X/ , oooxxox\
+    .51.52.53.54.55.56.57.58.5-.5][[sshkss :X:  /O/  /o/  \\o\\  (O) xoxxxnnyn*r*l*e*pwywghwC`c`ggjjnb\
+    dgrnrNmNbgbnbdbsbsgbstbsbbssbsjbjbcbtbpbNbbNsgsnsdsrsmsbsbgsssscskckpbpNgdnsdlyaeeoyeoyewawaeyow\
+    eowewieua-oa-uya-oya-yoeo-oeo-ueo-euyeo-oyeo-uo-eoo-eo-yeo-oo-uyo-yayo-yaeyo-yeoyo-oyo-iu-au-aeu\
+    -eo-euu-yeu-uyu-ayu-eoyu-eyu-yeoyu-yeyu-uyu-ieu-ueu-euyi-ui-ai-yai-oi-ui-eui-UU-eoU-uU-iUUaeuyau\
+    yeoyaoyaoyaegslglmlbltlpglgsgnZnttllgslnldlthlmglmslbslbhrNplsslklQmgmlmsmssmZmcblngngNsNZhlhbgc\
+    hgkhahuhihaaheehehohoalalulilaaleelelolwahhahhuhhihhaahheehhehhohhwamamumimaameememomwaszaszuszi\
+    szaaszeeszeszoszwararuriraareererorwasasusisaaseesesoswashashushishaasheesheshoshwaqaquqiqaaqeeq\
+    eqok'oaqwaqwiqwaaqweeqweqhaqhuqhiqhaaqheeqheqhoqhwaqhwiqhwaaqhweeqhwebabubibaabeebebobwavavuviva\
+    aveevevovwatatutaateetetotwacacucicaaceececocwaxaxuxixaaxeexexwaxwixwaaxweexwenanuninaaneenenonw\
+    anyanyunyinyaanyeenyenyonywa'a'i'aa'ee'e'o'wakakukikaakeekekokoakwakwikwaakweekwekxakxukxikxaakx\
+    eekxekxokxwakxwikxwaakxweekxwewuwaaweewowoa`a`u`i`aa`ee`e`ozazuzizaazeezezozwazhazhuzhizhaazheez\
+    hezhozhwayaayeeyoadadudidaadeededodwaddadduddiddaaddeeddeddoddwajajujijaajeejejojwagagugigaageeg\
+    egogoagwagwigwaagweegweggagguggiggaaggeeggeggongwathathuthithaatheethethothwachachuchichaacheech\
+    echochwaphaphuphiphaapheephephophwatsatsutsitsaatseetsetsotswatzatzutzitzaatzeetzetzots'oafafufi\
+    faafeefefofwapapupipaapeepepopwaryamyafya:: 10+20+30+40+50+60+70+80+90+100+10,000+mwemwibwebwifw\
+    efwipwepwigvlvhnanahnvquaquequiquoquuquvsvdvdlatlatletlitlotlutlvtsvwvyvMVaaiwiiwoopaaipiipoopwi\
+    ipwopwoopwaataaitiitootwetwitwiitwotwootwaattettittottakaaikiikookwiikwokwookwkehkihkohkahcaaici\
+    icoocwecwicwiicwocwoocwaamaaimiimoomwiimwomwoomwaanaainiinoonwenwaalaailiiloolwelwilwiilwolwoolw\
+    aasaaisiisoosweswiswiiswoswooswaaswskwsWspwastwaskwascwashiishooshweshwishwiishwoshwooshwaayaaiy\
+    iiyooyweywiywiiywoywooywaywaaraairiiroorwaafaaifiifoofwaathiithoothwaatthetthitthotthatyetyityot\
+    yahiihoohkqaaiqiiqootlhetlhitlhotlhangaaingingiingongoongangaalhilhiilholhoolhalhaaghughogheghee\
+    ghighahwuhwohwehweehwihwattutteekhukhokhekheekhikhakkukkokkekkeekkikkakkjjujjojjejjeejjijjadludl\
+    odledleedlilhulhelheetlhutlheetleedzudzodzedzeedzidzattsuttsottsettseettsittsaqaingainnginngiinn\
+    gonngoonnganngaathwethwithwiithwothwooyracearior171819ukuuvryryylylyyuaa`++KR @  ... . : zrjyiy \
+    3  333 cyaaywaypoypaypwoytaykaykwaymaynoynaylaysoysayshoyshayshwoyyoyyayraynwinwiinwonwoorwerwir\
+    wiirworwootthwetthootthaatlwetloogoogwojoojwejntrauegwuioaiueilaelaew081.2.3.4.5.6.7.8.9.10.11.1\
+    2.13.14.15.88.1.2.3.4.6.7.8.9.10.11.12.13.14.15ngkncluelngw.ww.ch.sy**@@reuleu)klplflQ'Ts'OuavSs\
+    \"~'`'''~`'`~\"`--,,*>\n\n\n'''```!!!?***-[]-???!PP(][)./.''''(ECUCLCrFFmilPtsNSEURDrPfHRNSmTLManatRb\
+    LariBTCSoma/ca/sc/oc/uNo(P)RxSMTELtmozOhmMhoinformation source FAXPLA/S1/71/91/10 1/3  2/3  1/5 \
+    \x202/5  3/5  4/5  1/6  5/6  1/8  3/8  5/8  7/8  1/IIIIIIVVIVIIVIIIIXXIXIIiiiivviiviiiixxii(DD)((|)\
+    )|)))(((|)))0/3-+sqrtinfSSS:.-~~=<=>=()<~>~[><<<<>>>=<=>watch hourglass keyboard NAK][eject fast\
+    \x20forward rewind arrow double up arrow double down track next track previous play pause alarm clo\
+    ck stopwatch timer hourglass flowing sand pause button stop button record button power on sleep \
+    NULSOHSTXETXEOTENQACKBELBSHTLFVTCRSOSIDLEDC1DC2DC3DC4SYNETBCANEMSUBESCFSGSRSUSSPDELNL\\\\11131415(\
+    1)(2)(3)(4)(5)(6)(7)(8)(9)(10)(11)(12)(13)(14)(15)(16)(17)(18)(19)(20)16.17.18.19.20.(a)(b)(d)(e\
+    )(f)(g)(h)(i)(j)(k)(l)(m)(n)(o)(p)(q)(s)(t)(u)(v)(w)(x)(y)(z)cloud umbrella snowman comet 180tel\
+    ephone [ ][v][x]coffee shamrock skull crossbones radioactive biohazard orthodox cross ChR.star a\
+    nd crescent farsi hammer & sickle peace yin yang heaven DuiLiZhenXunKanGenearth wheel of dharma \
+    :(:)Mercuryfemale sign Earthmale sign JupiterSaturnUranusNeptunePlutoaries taurus gemini cancer \
+    leo virgo libra scorpius sagittarius capricorn aquarius pisces white king black knight chess paw\
+    n spades white heart white diamond clubs white spade hearts diamonds white club hotsprings/1\\/2\\\
+    /3\\/4\\/5\\/6\\/7\\recycling recycle recycled (pap) recycled (part pap) (inf) wheelchair YangYaoYinY\
+    aoTaiYangShaoYinShaoYangTaiYinwhite flag black flag hammer & pick anchor crossed swords medical \
+    symbol scales alembic gear staff of hermes atom fleur-de-lis \\>\\<warning zap doubled female sign\
+    \x20male sign with stroke transgender symbol vertical male sign with stroke male sign with stroke h\
+    orizontal white circle black circle o|ocoffin urnneuter 30150sesquiquadrate soccer baseball [key\
+    ] black draughts king partly sunny rain thunder cloud rain turned white shogi piece disabled car\
+    \x20ophiuchus pick car sliding helmet with cross circled crossing lanes chains no entry alternate o\
+    neway left way traffic ::black cross shield shinto shrine church castle lighthouse mountain beac\
+    h umbrella fountain golf ferry boat skier ice skate basketballer tent jp bank graveyard fuelpump\
+    \x20cup scissors white check mark airplane envelope fist hand v writing hand pencil pencil2 nib bla\
+    ck nib OKcheckmark star of david sparkles x negative squared cross mark heart exclamation heartb\
+    lack heart arrow right curly loop loop >/d7d27d37d237d47d347d57d257d357d2357d457d3457d67d167d267\
+    d1267d367d2367d467d1467d2467d12467d3467d23467d123467d567d1567d2567d12567d3567d23567d123567d4567d\
+    14567d124567d34567d234567d1234567d8d18d28d128d38d138d238d1238d48d148d248d1248d348d1348d2348d1234\
+    8d58d158d258d1258d358d1358d2358d12358d458d1458d2458d12458d3458d13458d23458d123458d68d168d268d126\
+    8d368d1368d2368d12368d468d1468d2468d12468d3468d13468d23468d123468d568d1568d2568d12568d3568d13568\
+    d23568d123568d4568d14568d24568d124568d34568d134568d234568d1234568d78d178d278d1278d378d1378d2378d\
+    12378d478d1478d2478d12478d3478d13478d23478d123478d578d1578d2578d12578d3578d13578d23578d123578d45\
+    78d14578d24578d124578d34578d134578d234578d1234578d678d1678d2678d12678d3678d13678d23678d123678d46\
+    78d14678d24678d124678d34678d134678d234678d1234678d5678d15678d25678d125678d35678d135678d235678d12\
+    35678d45678d145678d245678d1245678d345678d1345678d2345678d12345678arrow heading up arrow heading \
+    down |||{{}}+++SSSS(++)(xx)=++==~=====?<>?~<~>///black large square white large square o off Cup\
+    idoHadesZeusKronosApollonAdmetosVulcanusPoseidonN2D3ShtJuJoJesht900MR.PR.STRS.TR.KhR.CS./..\\q'ts\
+    'tcloamoaroasoashoaboatoachoanoanyoa'oazoadoad'oajoat'oach'oap'oapoangwengwich'ech'uch'ich'ach'o\
+    k'yek'yuk'yik'yak'yk'yokyekyukyikyakyohyehyuhyihyahyogyegyugyigyagyo(())---BingHanRenJiongJiDaoB\
+    uJieXiaoWangYaoXinShouPuWuRiYueDaiShiShuiHuoZhaoQiangNiuQuanYuPiMuZhuMiYangLaoRouJiuCaoHuYaJianJ\
+    iaoYanBeiZuCheChuoJinChangMenFuQingWeiYeFengFeiMaGuGuiNiaoLuMaiHuangMinQiChiLongGunPieJueErTouRu\
+    BaBaoBiFangXiSiYouKouTuZhiSuiDaNuZiMianCunShanChuanGongGanYinGeWenDouQianShuMaoPianXuanGuaWaShen\
+    gYongTianNeBoBaiHeXueFouLeiChenSheZhouSeChongXingZouShenBianZhuiXiangGaoBiaoHeiDing[JIS]> >> ] }\
+    \x20[()] X ]] )) ~ +20++30+Masupart alternation mark yorikotoEHAIEIAUANENANGENGERIUGNBUZIJIGUEEENNO\
+    OONNIRANNINNUNNIMNGGAINNAUNNAMOMONGINNNWGXGBXGSWHZZHZGHPHZWGSZWGHZTHZZPHPWGHZWHZZZHZHGSZSWZSTSGP\
+    DPZTNSZZSWGHXWGHZZZGPG(ga)(na)(da)(ra)(ma)(ba)(sa)(ja)(ca)(ka)(ta)(pa)(ha)(ju)(OJeon)(OHu)(1) (2\
+    ) (3) (4) (5) (6) (7) (8) (9) (10) (Yue) (Huo) (Shui) (Mu) (Jin) (Tu) (Ri) (Zhu) (You) (She) (Mi\
+    ng) (Te) (Cai) (Lao) (Dai) (Hu) (Xue) (Jian) (Qi) (Zi) (Xie) (Ji) (Xiu) Zheng5070PTE212223242526\
+    27282931333435ChamGoJuUiKIS (Mi) (Nan) (Nu) (Shi) (Yin) (Xiang) (Zheng) (Shang) (Zhong) (Xia) (Z\
+    uo) (Yi) (Zong) (Ye) 3637383941424344454647491M2M3M4M5M6M7M8M9M10M11M12MHgergeVLTDReiwaapartment\
+    alphaampereareinninginchwonescudoacreounceohmkai-ricaratcaloriegallongammagigaguineacurieguilder\
+    kilokilogramkilometerkilowattgramgram ton cruzeirokronecasekorunaco-opcyclecentimeshillingcentic\
+    entdozendesidollartonnanoknotheightspercentpartsbarrelpiasterpiculpicobuildingfaradfeetbushelfra\
+    nchectarepesopfennighertzpencepagebetapointvolthonpoundhallhornmicromilemachmarkmansionmicronmil\
+    limillibarmegamegatonmeteryardyuanliterlirarupeerubleremroentgenwatt0h1h2h3h4h5h6h7h8h9h10h11h12\
+    h13h14h15h16h17h18h19h20h21h22h23h24hHPAbaroVpcdmdm2dm3HeiseiSyouwaTaisyouMeijiInc.pAnAuAmAkAkBM\
+    BGBcalkcalpFnFmicroFaradmicrogramkgHzkHzMHzGHzTHzmicroliterfmnmummmcmkmmm^2cm^2m^2km^2mm^4cm^3m^\
+    3km^3m/sm/s^2PakPaMPaGParadrad/srad/s^2microsecondpVnVmicrovoltmVkVpWnWmicrowattmWkWMWkOhmMOhma.\
+    m.BqcccdC/kgCo.dBGyK.K.KMktloglxmolpHp.m.PPMPRSvWbV/mA/m1d2d3d4d5d6d7d8d9d10d11d12d13d14d15d16d1\
+    7d18d19d20d21d22d23d24d25d26d27d28d29d30d31dgalQiuZimKuaGauJapZaauManNouDungZiuHoHangJauXieLouDa\
+    uChouKeoiKeiJyuNuoFauDanCoGeoiXuXiongLiuLinPanCiNeiChengWunSauTaaiZhuoAoZuoHingDongSuQiongKuangN\
+    aoZatCimZeonSeoiZungDieYingBengGinZikKoekLanMiaoLuoChaiNimHunHuiRaoNaamCamTaiJunLueTangZhaiRanSa\
+    HonSeonKuiTaSyuDiaoMieJoekJingSinSekWongFuiJipCaiLiangGungDinMongBukDuTingLeonJukCungQinCatNanMi\
+    tHungQiaDianLangHuaDiRuanQieRuiDengZanPutZhanChuaGaaChuKitKuaiSaiXiaBieLungCoekHengGwaiLePeiSunX\
+    ianWaiQueJiaLaKeAiDeoiCinTengHuanCanShuaShuangGaiFanPangGuoFaPouSiuWoHouQuGaaiNieHongTunMeiLingL\
+    unTungPenChunDuoZeKuZaaiHaapGouXiuPoZyuCuKuoZhaGangCongHaamSaapLengRongPaoLyunCoengWengWanHaoJik\
+    TanZangHaauZoiBangGeiSukZongZhangLauLiaoKyutChanMengSikQiaoNangYunNgoiKaiHeoiTaoLaiBanKongDaamPe\
+    ngMauSouSuoTongHaanSaoAnChaSaanSoengCaaLianGingNenCuiLeoiNianNaiHuaiHaiLuanMangNingMingZuiJyunKa\
+    ngSamDeChuiTuiZaZheCikSongCenShutsuNiCuoTuoQunGiuBinTiaoKwaiSungDuanNgaakYuanDikRunCuanZingFongF\
+    aanHauShaMokSaamZinZhunKunChuangZaoPinBenAaWaaJiangGoJuanSanDaanCengDaaiLamZhongMouJeoiFenEnNinL\
+    iePaangGuangGipTeShunDeonCeOiGuaiLaamBatSoKengJungNaHukShaiCauHakDenTuanGengChuaiShaoNgaiSyunGwo\
+    ngBunCeonZeoiHoengCaapNuanGwokPiaoHaakKwanNgokTyunRengNaaiDoiPaiZaakZauAngHapBuiWingGuanShuoPunH\
+    enZoengMikKuanCiuGoiSeiZaiMoZaapNengGamShuaiTaapJyutKiuJoengSingCyuZipTimPingCangChaoJamPuiNongH\
+    ipFungBouDatSaatKaoZuanKenHuouSatHiuFatPatGwingCyunGikGimMaanZenTaamDaatLaapDangHamSangZengJanNg\
+    ouBaanCeoiSimDunKoengCaLoekCouHatSapBaakPokCukZaamAuRangZhuanNgauGaakBongRuoZitDoZekSengTinGatKa\
+    uDukMuiUngGaanZunFukGoengNiangNueGaapWaanKaMaaZamHinNungGaamMungFunSuanNgoZaanDaapLipSaaBaatTieN\
+    gitMatGwangShuanGapAanCaanBiuOnGyunShangZoXiaoChuTongRenDaYouShiKeWuWangDaChuDaGuoDaZhuangMingYi\
+    JiaRenGuiMeiZhongFuXiaoGuoJiJiWeiJiYi Ding Kao Qi Shang Xia Mo Zhang San Ji Bu Yu Mian Gai Chou \
+    Zhuan Qie Pi Shi Qiu Bing Ye Cong Dong Si Cheng Diu Liang You Yan Sang Gun Jiu Ge Ya Qiang Zhong\
+    \x20Jie Feng Guan Chuan Chan Lin Zhuo Zhu Ha Wan Dan Wei Jing Li Ju Pie Fu Nai Shime Zhe Me Zhi Wu \
+    Zha Hu Fa Le Ping Pang Qiao Guai Yin Mie Xi Xiang Hal Shu Twul Nang Jia Kel Ol Mai Luan Cal Ru X\
+    ue Sha Na Gan Sol El Cwul Chi Gui Jue Liao Ma Zheng Er Chu Yun Sui Gen Xie Tou Wang Kang Ta Jiao\
+    \x20Hai Heng Mu Ting Qin Bo Lian Duo Ren La Shen Ze Jin Pu Ba Reng Fo Lun Sya Cang Zi Xian Tuo Hong\
+    \x20Tong Qian Di Dai Ling Chao Chang Sa Men Yang Jian Yao Fen Fang Pei Diao Dun Wen Xin Ai Xiu Bei \
+    Tang Huo Hui Cui Che Xun Xuan Nu Gu Ni Ban Xu Zhou Qu Beng Dian Han Bi Shao Ci Zuo Ti Zhan He Sh\
+    e Kou Ning Yong Wa Ka Huai Lao Bai Ming Hen Quan Tiao Xing Kan Lai Kua Guang Mi An Lu Mou Cha Go\
+    ng Zai Ko Zhen Ce Kuai Chai Nong Hou Jiong Nan Hao Bian Tui Cu E Kuang Ku Jun Lang Zu Hun Pai Su\
+    \x20Bao Lia Xiao Biao Fei Zan Zong Tian Dao Tan Chui Peng Kong Juan Luo Song Leng Ben Cai Zhai Qing\
+    \x20Ying Nuan Chun Ruo Pian Wo Sheng Huang Duan Ou Za Sou Yuan Rong Gou Bang Shan Suo Bin Nuo Can L\
+    ei Zao Ao Cou Piao Man Zun Deng Fan Seng Jiang Min Sai Dang Tai Lan Meng Qiong Lie Du Chen Teng \
+    Long Rang Xiong Chong Zhao Dui Ke Tu Dou Mao Nei Liu Ppwun Shou Ran Gang Gua Lam Pan Mei Zhun Zh\
+    uen Tako Mok Kai Chuang Cun Hua Yue Bie Kui Shua Lou Cuo Pou Tuan Fou Cuan Keng Miao Weng Mwun N\
+    ay Tao Pao Da Nao Zang Suan Qia Nian Shuai Ang Que Zui Uk Shuang Gao Die Rui Po Mang A Tun Hang \
+    Shun Ne Pen M Tie Gem Tha Wai Pin Sasou Yo Geng Shuo Huan Mal Kes Nou Ken Chuo Pa Se Nie Guo Re \
+    Phos Sao Cis Ng Dia Cao Ga De Saai Sin MeCa Haai So LoNin Kun Qun Ri Lue Iri Akutsu Yama Zhui Ta\
+    y Gake Cen Gomi Ruan Pyeng Toride Ama Zeng Sho Mama Ten Zhuang Cay Uys Kwu En Zou Niu Kuo Rao Ni\
+    ang Shui Hwa Rou Bou Niao Nen Neng Kaka Sun Kuan Ceng Nata Yuri Cem Kura Yamashina Kewashii Kek \
+    Pwu Phas Tazuna Hei ZhuangKoc Sei Nagi Te Zen Koraeru Ki S Kyuu Zei T Hameru Zhua Shuan Zhuai Mu\
+    shiru Haba Zuan Chuai Sen Mye Lv Es Shai Hi Yian Noboru Eburi Moku Soma Tochi Sugi Waku Haze Mat\
+    su Hoy Sayng Kasei Masu Fumoto Shikimi Kunugi Momiji Soko Shide Myeng Tafu Hazou Katsura Muro Sa\
+    kaki Hashi Kashi Ori Hokuso Tara Run Zusa Nude Toan Tamo Kwi Em Tsuki Tsukusu Shitamizu Tsuchi N\
+    iou Pal Shibui Tani Suei Qiou Ratsu Yie Oki Hama Boku Hyeng GwaanN Oozutsu Tatsu Nung Tsumekanmu\
+    ri Ushihen Ikenie Kari Swu Ton Ei Chiisai Xiou Dekaguramu Deshiguramu Miriguramu Thon Hekutogura\
+    \x20Sarake Senchigura Tap Kesa Tatamu Nue Zhung Shaku Yai Aragane Iong Kaki Seki Utsubo Sey Souke K\
+    ago O Sik Sasara Yana Hata Kuji Shinshi Kume Nukamiso Ro Sukumo Kouji Kinu Wata Gei Sok Kase Yin\
+    gl Kasuri Nawa Odoshi Horo Sem Jung Un Kaakeru Yashinau Shikato Tsuraneru Obiyaakasu Noy Yadoru \
+    Hesaki Sori Yofune Susa Usagi Nuc Kutabireru Yaji Sonoko Hie Hagi Ebi Kamakiri Kamishimo Yuki En\
+    a Hitoe Chihaya Tasuki Yasashi Miu Segare Nerau Utsuke Shitsuke Yagate Suberu Sip Ip Totemo Kep \
+    Sako Appare Otoko Sakenomoto Ishiyumi Habaki Irori Ngaak Diou Kasugai Kazari Yari Yuru Phwung To\
+    mo Kohaze On Oroshi Shuu Eri Namazu Todo Kajika Bora Mate Gori Ugui Asari Subashiri Kazunoko Sha\
+    chi Dojou Sukesou Muroaji Haraka Z Hatahata Eso Kyou Shiira Mutsu Nio Yiao Shigi Chidori Toki Ik\
+    aruga Kakesu Isuka Kikuitadaki Tsugumi GonLoengBaaToMakitipietiexiepataxapuoxuouopopexbitbixbipb\
+    ietbiexbiebiepbatbaxbapbuoxbuobuopbotboxbopbexbepbutbuxbupburxburbytbyxbybypbyrxbyrpitpixpippiex\
+    piepieppatpaxpappuoxpuopuoppotpoxpopputpuxpuppurxpurpytpyxpypyppyrxpyrbbitbbixbbibbipbbietbbiexb\
+    biebbiepbbatbbaxbbabbapbbuoxbbuobbuopbbotbboxbbobbopbbexbbebbepbbutbbuxbbubbupbburxbburbbytbbyxb\
+    bybbypnbitnbixnbinbipnbiexnbienbiepnbatnbaxnbanbapnbotnboxnbonbopnbutnbuxnbunbupnburxnburnbytnby\
+    xnbynbypnbyrxnbyrhmithmixhmihmiphmiexhmiehmiephmathmaxhmahmaphmuoxhmuohmuophmothmoxhmohmophmuthm\
+    uxhmuhmuphmurxhmurhmyxhmyhmyphmyrxhmyrmitmixmipmiexmiemiepmatmaxmapmuotmuoxmuomuopmotmoxmopmexmu\
+    tmuxmupmurxmurmytmyxmymypfitfixfipfatfaxfapfoxfopfutfuxfupfurxfurfytfyxfyfypvitvixvipvietviexvie\
+    viepvatvaxvapvotvoxvopvexvepvutvuxvupvurxvurvytvyxvyvypvyrxvyrditdixdipdiexdiediepdatdaxdapduoxd\
+    uodotdoxdopdexdepdutduxdupdurxdurtittixtiptiextietieptattaxtaptuottuoxtuotuoptottoxtoptexteptutt\
+    uxtupturxturdditddixddipddiexddieddiepddatddaxddapdduoxdduodduopddotddoxddopddexddepddutdduxddup\
+    ddurxddurnditndixndindipndiexndiendatndaxndandapndotndoxndondopndexndendepndutnduxndundupndurxnd\
+    urhnithnixhnihniphniethniexhniehniephnathnaxhnaphnuoxhnuohnothnoxhnophnexhnehnephnutnitnixnipnie\
+    xnieniepnaxnapnuoxnuonuopnotnoxnopnexnepnutnuxnupnurxnurhlithlixhlihliphliexhliehliephlathlaxhla\
+    hlaphluoxhluohluophloxhlohlophlexhlehlephluthluxhluhluphlurxhlurhlythlyxhlyhlyphlyrxhlyrlitlixli\
+    plietliexlielieplatlaxlapluotluoxluoluoplotloxloplexleplutluxluplurxlurlytlyxlyplyrxlyrgitgixgip\
+    gietgiexgiegiepgatgaxgapguotguoxguoguopgotgoxgopgetgexgepgutguxgupgurxgurkitkixkipkiexkiekiepkat\
+    kaxkapkuoxkuokuopkotkoxkopketkexkepkutkuxkupkurxkurggitggixggiexggieggiepggatggaxggapgguotgguoxg\
+    guogguopggotggoxggopggetggexggepggutgguxggupggurxggurmgiexmgiemgatmgaxmgamgapmguoxmguomguopmgotm\
+    goxmgomgopmgexmgemgepmgutmguxmgumgupmgurxmgurhxithxixhxihxiphxiethxiexhxiehxiephxathxaxhxahxaphx\
+    uothxuoxhxuohxuophxothxoxhxohxophxexhxehxepngiexngiengiepngatngaxngapnguotnguoxnguongotngoxngopn\
+    gexngengephithiexhiehathaxhaphuothuoxhuohuophothoxhophexhepwatwaxwapwuoxwuowuopwoxwopwexwepzitzi\
+    xzipziexzieziepzatzaxzapzuoxzuozuopzotzoxzopzexzepzutzuxzupzurxzurzytzyxzyzypzyrxzyrcitcixcipcie\
+    tciexcieciepcatcaxcapcuoxcuocuopcotcoxcopcexcepcutcuxcupcurxcurcytcyxcypcyrxcyrzzitzzixzzizzipzz\
+    ietzziexzziezziepzzatzzaxzzazzapzzoxzzozzopzzexzzezzepzzuxzzuzzupzzurxzzurzzytzzyxzzyzzypzzyrxzz\
+    yrnzitnzixnzinzipnziexnzienziepnzatnzaxnzanzapnzuoxnzuonzoxnzopnzexnzenzuxnzunzupnzurxnzurnzytnz\
+    yxnzynzypnzyrxnzyrsitsixsipsiexsiesiepsatsaxsapsuoxsuosuopsotsoxsopsexsepsutsuxsupsurxsursytsyxs\
+    ypsyrxsyrssitssixssissipssiexssiessiepssatssaxssassapssotssoxssossopssexssessepssutssuxssussupss\
+    ytssyxssyssypssyrxssyrzhatzhaxzhapzhuoxzhuozhuopzhotzhoxzhopzhetzhexzhepzhutzhuxzhupzhurxzhurzhy\
+    tzhyxzhyzhypzhyrxzhyrchatchaxchapchuotchuoxchuochuopchotchoxchopchetchexchepchuxchupchurxchurchy\
+    tchyxchychypchyrxchyrrraxrrarruoxrruorrotrroxrrorroprretrrexrrerreprrutrruxrrurruprrurxrrurrrytr\
+    ryxrryrryprryrxrryrnratnraxnranrapnroxnronropnretnrexnrenrepnrutnruxnrunrupnrurxnrurnrytnryxnryn\
+    rypnryrxnryrshatshaxshapshuoxshuoshuopshotshoxshopshetshexshepshutshuxshupshurxshurshytshyxshysh\
+    ypshyrxshyrratraxrapruoxruoruoprotroxroprexreprutruxruprurxrurrytryxrypryrxryrjitjixjipjietjiexj\
+    iejiepjuotjuoxjuojuopjotjoxjopjutjuxjupjurxjurjytjyxjypjyrxjyrqitqixqipqietqiexqieqiepquotquoxqu\
+    opqotqoxqopqutquxqupqurxqurqytqyxqyqypqyrxqyrjjitjjixjjipjjietjjiexjjiejjiepjjuoxjjuojjuopjjotjj\
+    oxjjopjjutjjuxjjupjjurxjjurjjytjjyxjjyjjypnjitnjixnjinjipnjietnjiexnjienjiepnjuoxnjuonjotnjoxnjo\
+    njopnjuxnjunjupnjurxnjurnjytnjyxnjynjypnjyrxnjyrnyitnyixnyipnyietnyiexnyienyiepnyuoxnyuonyuopnyo\
+    tnyoxnyopnyutnyuxnyupxitxixxipxietxiexxiexiepxuoxxuoxotxoxxopxytxyxxyxypxyrxxyryityixyipyietyiex\
+    yieyiepyuotyuoxyuoyuopyotyoxyopyutyuxyupyurxyuryytyyxyypyyrxyyrQotNyipCypSsiGgopGepHxitLyrBbutMo\
+    pYoHxuoTatGaZUPCYTDdurBurGguoNyopOpJjutZotPytHmoYitVurShyVepNZUPJjyGotJjieShurCyCuopCipHxopShatZ\
+    URShopZzietNBIEzzh'mbekpemgbegbedhenjenggembikpimgbigbidhinggimbakpamgbagbadhanjanggambokpomgbog\
+    bodhonggombukpumgbugbudhungguNiiFaaTaaKungDooJongInDgLgMgOo100000001000000001000000000DwdwDzwdzw\
+    ZhwzhwCchTwtwTswtswTchtchHwhwShwshwriendaanjemsuunuenjueyo'yu'nshantekenngkwenpuefomlo'mbenrenme\
+    nTztz4,AvAyC.c.K.k.L.l.O.o.P.p.Q.q.R.r.V.v.VyTh.th.d.m.n.t.LlOeUeththwwM'y''sh'f''m'hshthpawngge\
+    adslkklttlppmdbkssbjjhqq@@@ppoaychhueaKonNuengb'eb'ub'ib'ab'b'ogaggagggagsganganjganhgadgalggalm\
+    galbgalsgaltgalpgalhgamgabgabsgasgassganggajgacgakgahgaegaeggaegggaegsgaengaenjgaenhgaedgaelgael\
+    ggaelmgaelbgaelsgaeltgaelpgaelhgaemgaebgaebsgaesgaessgaenggaejgaecgaekgaetgaepgaehgyaggyagggyags\
+    gyangyanjgyanhgyadgyalgyalggyalmgyalbgyalsgyaltgyalpgyalhgyamgyabgyabsgyasgyassgyanggyajgyacgyak\
+    gyatgyapgyahgyaegyaeggyaegggyaegsgyaengyaenjgyaenhgyaedgyaelgyaelggyaelmgyaelbgyaelsgyaeltgyaelp\
+    gyaelhgyaemgyaebgyaebsgyaesgyaessgyaenggyaejgyaecgyaekgyaetgyaepgyaehgeogeoggeogggeogsgeongeonjg\
+    eonhgeodgeolgeolggeolmgeolbgeolsgeoltgeolpgeolhgeomgeobgeobsgeosgeossgeonggeojgeocgeokgeotgeopge\
+    ohgeggegggegsgengenjgenhgedgelgelggelmgelbgelsgeltgelpgelhgemgebgebsgesgessgenggejgecgekgehgyeog\
+    yeoggyeogggyeogsgyeongyeonjgyeonhgyeodgyeolgyeolggyeolmgyeolbgyeolsgyeoltgyeolpgyeolhgyeomgyeobg\
+    yeobsgyeosgyeossgyeonggyeojgyeocgyeokgyeotgyeopgyeohgyeggyegggyegsgyengyenjgyenhgyedgyelgyelggye\
+    lmgyelbgyelsgyeltgyelpgyelhgyemgyebgyebsgyesgyessgyenggyejgyecgyekgyetgyepgyehgoggogggogsgongonj\
+    gonhgodgolgolggolmgolbgolsgoltgolpgolhgomgobgobsgosgossgonggojgocgokgohgwaggwagggwagsgwangwanjgw\
+    anhgwadgwalgwalggwalmgwalbgwalsgwaltgwalpgwalhgwamgwabgwabsgwasgwassgwanggwajgwacgwakgwatgwapgwa\
+    hgwaegwaeggwaegggwaegsgwaengwaenjgwaenhgwaedgwaelgwaelggwaelmgwaelbgwaelsgwaeltgwaelpgwaelhgwaem\
+    gwaebgwaebsgwaesgwaessgwaenggwaejgwaecgwaekgwaetgwaepgwaehgoegoeggoegggoegsgoengoenjgoenhgoedgoe\
+    lgoelggoelmgoelbgoelsgoeltgoelpgoelhgoemgoebgoebsgoesgoessgoenggoejgoecgoekgoetgoepgoehgyoggyogg\
+    gyogsgyongyonjgyonhgyodgyolgyolggyolmgyolbgyolsgyoltgyolpgyolhgyomgyobgyobsgyosgyossgyonggyojgyo\
+    cgyokgyotgyopgyohguggugggugsgungunjgunhgudgulgulggulmgulbgulsgultgulpgulhgumgubgubsgusgussgunggu\
+    jgucgukguhgweogweoggweogggweogsgweongweonjgweonhgweodgweolgweolggweolmgweolbgweolsgweoltgweolpgw\
+    eolhgweomgweobgweobsgweosgweossgweonggweojgweocgweokgweotgweopgweohgweggwegggwegsgwengwenjgwenhg\
+    wedgwelgwelggwelmgwelbgwelsgweltgwelpgwelhgwemgwebgwebsgwesgwessgwenggwejgwecgwekgwetgwepgwehgwi\
+    ggwigggwigsgwingwinjgwinhgwidgwilgwilggwilmgwilbgwilsgwiltgwilpgwilhgwimgwibgwibsgwisgwissgwingg\
+    wijgwicgwikgwitgwipgwihgyuggyugggyugsgyungyunjgyunhgyudgyulgyulggyulmgyulbgyulsgyultgyulpgyulhgy\
+    umgyubgyubsgyusgyussgyunggyujgyucgyukgyutgyupgyuhgeugeuggeugggeugsgeungeunjgeunhgeudgeulgeulggeu\
+    lmgeulbgeulsgeultgeulpgeulhgeumgeubgeubsgeusgeussgeunggeujgeucgeukgeutgeupgeuhgyiggyigggyigsgyin\
+    gyinjgyinhgyidgyilgyilggyilmgyilbgyilsgyiltgyilpgyilhgyimgyibgyibsgyisgyissgyinggyijgyicgyikgyit\
+    gyipgyihgiggigggigsginginjginhgidgilgilggilmgilbgilsgiltgilpgilhgimgibgibsgisgissginggijgicgikgi\
+    hggagggaggggagsggangganjgganhggadggalggalgggalmggalbggalsggaltggalpggalhggamggabggabsggasggassgg\
+    angggajggacggakggahggaeggaegggaeggggaegsggaenggaenjggaenhggaedggaelggaelgggaelmggaelbggaelsggael\
+    tggaelpggaelhggaemggaebggaebsggaesggaessggaengggaejggaecggaekggaetggaepggaehggyaggyagggyaggggyag\
+    sggyanggyanjggyanhggyadggyalggyalgggyalmggyalbggyalsggyaltggyalpggyalhggyamggyabggyabsggyasggyas\
+    sggyangggyajggyacggyakggyatggyapggyahggyaeggyaegggyaeggggyaegsggyaenggyaenjggyaenhggyaedggyaelgg\
+    yaelgggyaelmggyaelbggyaelsggyaeltggyaelpggyaelhggyaemggyaebggyaebsggyaesggyaessggyaengggyaejggya\
+    ecggyaekggyaetggyaepggyaehggeoggeogggeoggggeogsggeonggeonjggeonhggeodggeolggeolgggeolmggeolbggeo\
+    lsggeoltggeolpggeolhggeomggeobggeobsggeosggeossggeongggeojggeocggeokggeotggeopggeohggegggeggggeg\
+    sggenggenjggenhggedggelggelgggelmggelbggelsggeltggelpggelhggemggebggebsggesggessggengggejggecgge\
+    kggehggyeoggyeogggyeoggggyeogsggyeonggyeonjggyeonhggyeodggyeolggyeolgggyeolmggyeolbggyeolsggyeol\
+    tggyeolpggyeolhggyeomggyeobggyeobsggyeosggyeossggyeongggyeojggyeocggyeokggyeotggyeopggyeohggyegg\
+    yegggyeggggyegsggyenggyenjggyenhggyedggyelggyelgggyelmggyelbggyelsggyeltggyelpggyelhggyemggyebgg\
+    yebsggyesggyessggyengggyejggyecggyekggyetggyepggyehggogggoggggogsggonggonjggonhggodggolggolgggol\
+    mggolbggolsggoltggolpggolhggomggobggobsggosggossggongggojggocggokggohggwaggwagggwaggggwagsggwang\
+    gwanjggwanhggwadggwalggwalgggwalmggwalbggwalsggwaltggwalpggwalhggwamggwabggwabsggwasggwassggwang\
+    ggwajggwacggwakggwatggwapggwahggwaeggwaegggwaeggggwaegsggwaenggwaenjggwaenhggwaedggwaelggwaelggg\
+    waelmggwaelbggwaelsggwaeltggwaelpggwaelhggwaemggwaebggwaebsggwaesggwaessggwaengggwaejggwaecggwae\
+    kggwaetggwaepggwaehggoeggoegggoeggggoegsggoenggoenjggoenhggoedggoelggoelgggoelmggoelbggoelsggoel\
+    tggoelpggoelhggoemggoebggoebsggoesggoessggoengggoejggoecggoekggoetggoepggoehggyoggyogggyoggggyog\
+    sggyonggyonjggyonhggyodggyolggyolgggyolmggyolbggyolsggyoltggyolpggyolhggyomggyobggyobsggyosggyos\
+    sggyongggyojggyocggyokggyotggyopggyohgguggguggggugsggunggunjggunhggudggulggulgggulmggulbggulsggu\
+    ltggulpggulhggumggubggubsggusggussggungggujggucggukgguhggweoggweogggweoggggweogsggweonggweonjggw\
+    eonhggweodggweolggweolgggweolmggweolbggweolsggweoltggweolpggweolhggweomggweobggweobsggweosggweos\
+    sggweongggweojggweocggweokggweotggweopggweohggweggwegggweggggwegsggwenggwenjggwenhggwedggwelggwe\
+    lgggwelmggwelbggwelsggweltggwelpggwelhggwemggwebggwebsggwesggwessggwengggwejggwecggwekggwetggwep\
+    ggwehggwiggwigggwiggggwigsggwinggwinjggwinhggwidggwilggwilgggwilmggwilbggwilsggwiltggwilpggwilhg\
+    gwimggwibggwibsggwisggwissggwingggwijggwicggwikggwitggwipggwihggyuggyugggyuggggyugsggyunggyunjgg\
+    yunhggyudggyulggyulgggyulmggyulbggyulsggyultggyulpggyulhggyumggyubggyubsggyusggyussggyungggyujgg\
+    yucggyukggyutggyupggyuhggeuggeugggeuggggeugsggeunggeunjggeunhggeudggeulggeulgggeulmggeulbggeulsg\
+    geultggeulpggeulhggeumggeubggeubsggeusggeussggeungggeujggeucggeukggeutggeupggeuhggyiggyigggyiggg\
+    gyigsggyinggyinjggyinhggyidggyilggyilgggyilmggyilbggyilsggyiltggyilpggyilhggyimggyibggyibsggyisg\
+    gyissggyingggyijggyicggyikggyitggyipggyihggigggiggggigsggingginjgginhggidggilggilgggilmggilbggil\
+    sggiltggilpggilhggimggibggibsggisggissggingggijggicggikggipggihnagnaggnagsnannanjnanhnadnalnalgn\
+    almnalbnalsnaltnalpnalhnamnabnabsnasnassnangnajnacnaknatnaenaegnaeggnaegsnaennaenjnaenhnaednaeln\
+    aelgnaelmnaelbnaelsnaeltnaelpnaelhnaemnaebnaebsnaesnaessnaengnaejnaecnaeknaetnaepnaehnyagnyaggny\
+    agsnyannyanjnyanhnyadnyalnyalgnyalmnyalbnyalsnyaltnyalpnyalhnyamnyabnyabsnyasnyassnyangnyajnyacn\
+    yaknyatnyapnyahnyaenyaegnyaeggnyaegsnyaennyaenjnyaenhnyaednyaelnyaelgnyaelmnyaelbnyaelsnyaeltnya\
+    elpnyaelhnyaemnyaebnyaebsnyaesnyaessnyaengnyaejnyaecnyaeknyaetnyaepnyaehneoneogneoggneogsneonneo\
+    njneonhneodneolneolgneolmneolbneolsneoltneolpneolhneomneobneobsneosneossneongneojneocneokneotneo\
+    pneohnegneggnegsnennenjnenhnednelnelgnelmnelbnelsneltnelpnelhnemnebnebsnesnessnengnejnecneknetne\
+    hnyeonyeognyeoggnyeogsnyeonnyeonjnyeonhnyeodnyeolnyeolgnyeolmnyeolbnyeolsnyeoltnyeolpnyeolhnyeom\
+    nyeobnyeobsnyeosnyeossnyeongnyeojnyeocnyeoknyeotnyeopnyeohnyegnyeggnyegsnyennyenjnyenhnyednyelny\
+    elgnyelmnyelbnyelsnyeltnyelpnyelhnyemnyebnyebsnyesnyessnyengnyejnyecnyeknyetnyepnyehnognoggnogsn\
+    onnonjnonhnodnolnolgnolmnolbnolsnoltnolpnolhnomnobnobsnosnossnongnojnocnoknohnwagnwaggnwagsnwann\
+    wanjnwanhnwadnwalnwalgnwalmnwalbnwalsnwaltnwalpnwalhnwamnwabnwabsnwasnwassnwangnwajnwacnwaknwatn\
+    wapnwahnwaenwaegnwaeggnwaegsnwaennwaenjnwaenhnwaednwaelnwaelgnwaelmnwaelbnwaelsnwaeltnwaelpnwael\
+    hnwaemnwaebnwaebsnwaesnwaessnwaengnwaejnwaecnwaeknwaetnwaepnwaehnoenoegnoeggnoegsnoennoenjnoenhn\
+    oednoelnoelgnoelmnoelbnoelsnoeltnoelpnoelhnoemnoebnoebsnoesnoessnoengnoejnoecnoeknoetnoepnoehnyo\
+    gnyoggnyogsnyonnyonjnyonhnyodnyolnyolgnyolmnyolbnyolsnyoltnyolpnyolhnyomnyobnyobsnyosnyossnyongn\
+    yojnyocnyoknyohnugnuggnugsnunnunjnunhnudnulnulgnulmnulbnulsnultnulpnulhnumnubnubsnusnussnungnujn\
+    ucnuknuhnweonweognweoggnweogsnweonnweonjnweonhnweodnweolnweolgnweolmnweolbnweolsnweoltnweolpnweo\
+    lhnweomnweobnweobsnweosnweossnweongnweojnweocnweoknweotnweopnweohnwegnweggnwegsnwennwenjnwenhnwe\
+    dnwelnwelgnwelmnwelbnwelsnweltnwelpnwelhnwemnwebnwebsnwesnwessnwengnwejnwecnweknwetnwepnwehnwign\
+    wiggnwigsnwinnwinjnwinhnwidnwilnwilgnwilmnwilbnwilsnwiltnwilpnwilhnwimnwibnwibsnwisnwissnwingnwi\
+    jnwicnwiknwitnwipnwihnyugnyuggnyugsnyunnyunjnyunhnyudnyulnyulgnyulmnyulbnyulsnyultnyulpnyulhnyum\
+    nyubnyubsnyusnyussnyungnyujnyucnyuknyuhneuneugneuggneugsneunneunjneunhneudneulneulgneulmneulbneu\
+    lsneultneulpneulhneumneubneubsneusneussneungneujneucneukneutneupneuhnyignyiggnyigsnyinnyinjnyinh\
+    nyidnyilnyilgnyilmnyilbnyilsnyiltnyilpnyilhnyimnyibnyibsnyisnyissnyingnyijnyicnyiknyihnigniggnig\
+    sninninjninhnidnilnilgnilmnilbnilsniltnilpnilhnimnibnibsnisnissningnijnicniknihdagdaggdagsdandan\
+    jdanhdaddaldalgdalmdalbdalsdaltdalpdalhdamdabdabsdasdassdangdajdacdakdahdaedaegdaeggdaegsdaendae\
+    njdaenhdaeddaeldaelgdaelmdaelbdaelsdaeltdaelpdaelhdaemdaebdaebsdaesdaessdaengdaejdaecdaekdaetdae\
+    pdaehdyadyagdyaggdyagsdyandyanjdyanhdyaddyaldyalgdyalmdyalbdyalsdyaltdyalpdyalhdyamdyabdyabsdyas\
+    dyassdyangdyajdyacdyakdyatdyapdyahdyaedyaegdyaeggdyaegsdyaendyaenjdyaenhdyaeddyaeldyaelgdyaelmdy\
+    aelbdyaelsdyaeltdyaelpdyaelhdyaemdyaebdyaebsdyaesdyaessdyaengdyaejdyaecdyaekdyaetdyaepdyaehdeode\
+    ogdeoggdeogsdeondeonjdeonhdeoddeoldeolgdeolmdeolbdeolsdeoltdeolpdeolhdeomdeobdeobsdeosdeossdeong\
+    deojdeocdeokdeotdeopdeohdeggdegsdendenjdenhdeddeldelgdelmdelbdelsdeltdelpdelhdemdebdebsdesdessde\
+    ngdejdecdekdetdehdyeodyeogdyeoggdyeogsdyeondyeonjdyeonhdyeoddyeoldyeolgdyeolmdyeolbdyeolsdyeoltd\
+    yeolpdyeolhdyeomdyeobdyeobsdyeosdyeossdyeongdyeojdyeocdyeokdyeotdyeopdyeohdyedyegdyeggdyegsdyend\
+    yenjdyenhdyeddyeldyelgdyelmdyelbdyelsdyeltdyelpdyelhdyemdyebdyebsdyesdyessdyengdyejdyecdyekdyetd\
+    yepdyehdogdoggdogsdondonjdonhdoddoldolgdolmdolbdolsdoltdolpdolhdomdobdobsdosdossdongdojdocdokdoh\
+    dwagdwaggdwagsdwandwanjdwanhdwaddwaldwalgdwalmdwalbdwalsdwaltdwalpdwalhdwamdwabdwabsdwasdwassdwa\
+    ngdwajdwacdwakdwatdwapdwahdwaedwaegdwaeggdwaegsdwaendwaenjdwaenhdwaeddwaeldwaelgdwaelmdwaelbdwae\
+    lsdwaeltdwaelpdwaelhdwaemdwaebdwaebsdwaesdwaessdwaengdwaejdwaecdwaekdwaetdwaepdwaehdoedoegdoeggd\
+    oegsdoendoenjdoenhdoeddoeldoelgdoelmdoelbdoelsdoeltdoelpdoelhdoemdoebdoebsdoesdoessdoengdoejdoec\
+    doekdoetdoepdoehdyodyogdyoggdyogsdyondyonjdyonhdyoddyoldyolgdyolmdyolbdyolsdyoltdyolpdyolhdyomdy\
+    obdyobsdyosdyossdyongdyojdyocdyokdyotdyopdyohdugduggdugsdundunjdunhdudduldulgdulmdulbdulsdultdul\
+    pdulhdumdubdubsdusdussdungdujducdukduhdweodweogdweoggdweogsdweondweonjdweonhdweoddweoldweolgdweo\
+    lmdweolbdweolsdweoltdweolpdweolhdweomdweobdweobsdweosdweossdweongdweojdweocdweokdweotdweopdweohd\
+    wedwegdweggdwegsdwendwenjdwenhdweddweldwelgdwelmdwelbdwelsdweltdwelpdwelhdwemdwebdwebsdwesdwessd\
+    wengdwejdwecdwekdwetdwepdwehdwidwigdwiggdwigsdwindwinjdwinhdwiddwildwilgdwilmdwilbdwilsdwiltdwil\
+    pdwilhdwimdwibdwibsdwisdwissdwingdwijdwicdwikdwitdwipdwihdyudyugdyuggdyugsdyundyunjdyunhdyuddyul\
+    dyulgdyulmdyulbdyulsdyultdyulpdyulhdyumdyubdyubsdyusdyussdyungdyujdyucdyukdyutdyupdyuhdeudeugdeu\
+    ggdeugsdeundeunjdeunhdeuddeuldeulgdeulmdeulbdeulsdeultdeulpdeulhdeumdeubdeubsdeusdeussdeungdeujd\
+    eucdeukdeutdeupdeuhdyidyigdyiggdyigsdyindyinjdyinhdyiddyildyilgdyilmdyilbdyilsdyiltdyilpdyilhdyi\
+    mdyibdyibsdyisdyissdyingdyijdyicdyikdyitdyipdyihdigdiggdigsdindinjdinhdiddildilgdilmdilbdilsdilt\
+    dilpdilhdimdibdibsdisdissdingdijdicdikdihddagddaggddagsddanddanjddanhddadddalddalgddalmddalbddal\
+    sddaltddalpddalhddamddabddabsddasddassddangddajddacddakddahddaeddaegddaeggddaegsddaenddaenjddaen\
+    hddaedddaelddaelgddaelmddaelbddaelsddaeltddaelpddaelhddaemddaebddaebsddaesddaessddaengddaejddaec\
+    ddaekddaetddaepddaehddyaddyagddyaggddyagsddyanddyanjddyanhddyadddyalddyalgddyalmddyalbddyalsddya\
+    ltddyalpddyalhddyamddyabddyabsddyasddyassddyangddyajddyacddyakddyatddyapddyahddyaeddyaegddyaeggd\
+    dyaegsddyaenddyaenjddyaenhddyaedddyaelddyaelgddyaelmddyaelbddyaelsddyaeltddyaelpddyaelhddyaemddy\
+    aebddyaebsddyaesddyaessddyaengddyaejddyaecddyaekddyaetddyaepddyaehddeoddeogddeoggddeogsddeonddeo\
+    njddeonhddeodddeolddeolgddeolmddeolbddeolsddeoltddeolpddeolhddeomddeobddeobsddeosddeossddeongdde\
+    ojddeocddeokddeotddeopddeohddegddeggddegsddenddenjddenhddedddelddelgddelmddelbddelsddeltddelpdde\
+    lhddemddebddebsddesddessddengddejddecddekddetddehddyeoddyeogddyeoggddyeogsddyeonddyeonjddyeonhdd\
+    yeodddyeolddyeolgddyeolmddyeolbddyeolsddyeoltddyeolpddyeolhddyeomddyeobddyeobsddyeosddyeossddyeo\
+    ngddyeojddyeocddyeokddyeotddyeopddyeohddyeddyegddyeggddyegsddyenddyenjddyenhddyedddyelddyelgddye\
+    lmddyelbddyelsddyeltddyelpddyelhddyemddyebddyebsddyesddyessddyengddyejddyecddyekddyetddyepddyehd\
+    dogddoggddogsddonddonjddonhddodddolddolgddolmddolbddolsddoltddolpddolhddomddobddobsddosddossddon\
+    gddojddocddokddohddwagddwaggddwagsddwanddwanjddwanhddwadddwalddwalgddwalmddwalbddwalsddwaltddwal\
+    pddwalhddwamddwabddwabsddwasddwassddwangddwajddwacddwakddwatddwapddwahddwaeddwaegddwaeggddwaegsd\
+    dwaenddwaenjddwaenhddwaedddwaelddwaelgddwaelmddwaelbddwaelsddwaeltddwaelpddwaelhddwaemddwaebddwa\
+    ebsddwaesddwaessddwaengddwaejddwaecddwaekddwaetddwaepddwaehddoeddoegddoeggddoegsddoenddoenjddoen\
+    hddoedddoelddoelgddoelmddoelbddoelsddoeltddoelpddoelhddoemddoebddoebsddoesddoessddoengddoejddoec\
+    ddoekddoetddoepddoehddyoddyogddyoggddyogsddyonddyonjddyonhddyodddyolddyolgddyolmddyolbddyolsddyo\
+    ltddyolpddyolhddyomddyobddyobsddyosddyossddyongddyojddyocddyokddyotddyopddyohddugdduggddugsddund\
+    dunjddunhddudddulddulgddulmddulbddulsddultddulpddulhddumddubddubsddusddussddungddujdducddukdduhd\
+    dweoddweogddweoggddweogsddweonddweonjddweonhddweodddweolddweolgddweolmddweolbddweolsddweoltddweo\
+    lpddweolhddweomddweobddweobsddweosddweossddweongddweojddweocddweokddweotddweopddweohddweddwegddw\
+    eggddwegsddwenddwenjddwenhddwedddwelddwelgddwelmddwelbddwelsddweltddwelpddwelhddwemddwebddwebsdd\
+    wesddwessddwengddwejddwecddwekddwetddwepddwehddwiddwigddwiggddwigsddwinddwinjddwinhddwidddwilddw\
+    ilgddwilmddwilbddwilsddwiltddwilpddwilhddwimddwibddwibsddwisddwissddwingddwijddwicddwikddwitddwi\
+    pddwihddyuddyugddyuggddyugsddyunddyunjddyunhddyudddyulddyulgddyulmddyulbddyulsddyultddyulpddyulh\
+    ddyumddyubddyubsddyusddyussddyungddyujddyucddyukddyutddyupddyuhddeuddeugddeuggddeugsddeunddeunjd\
+    deunhddeudddeulddeulgddeulmddeulbddeulsddeultddeulpddeulhddeumddeubddeubsddeusddeussddeungddeujd\
+    deucddeukddeutddeupddeuhddyiddyigddyiggddyigsddyinddyinjddyinhddyidddyilddyilgddyilmddyilbddyils\
+    ddyiltddyilpddyilhddyimddyibddyibsddyisddyissddyingddyijddyicddyikddyitddyipddyihddigddiggddigsd\
+    dinddinjddinhddidddilddilgddilmddilbddilsddiltddilpddilhddimddibddibsddisddissddingddijddicddikd\
+    dihragraggragsranranjranhralralgralmralbralsraltralpralhramrabrabsrasrassrangrajracrakrahraeraeg\
+    raeggraegsraenraenjraenhraedraelraelgraelmraelbraelsraeltraelpraelhraemraebraebsraesraessraengra\
+    ejraecraekraetraepraehryagryaggryagsryanryanjryanhryadryalryalgryalmryalbryalsryaltryalpryalhrya\
+    mryabryabsryasryassryangryajryacryakryatryapryahryaeryaegryaeggryaegsryaenryaenjryaenhryaedryael\
+    ryaelgryaelmryaelbryaelsryaeltryaelpryaelhryaemryaebryaebsryaesryaessryaengryaejryaecryaekryaetr\
+    yaepryaehreoreogreoggreogsreonreonjreonhreodreolreolgreolmreolbreolsreoltreolpreolhreomreobreobs\
+    reosreossreongreojreocreokreotreopreohregreggregsrenjrenhredrelrelgrelmrelbrelsreltrelprelhrebre\
+    bsresressrengrejrecrekretrehryeoryeogryeoggryeogsryeonryeonjryeonhryeodryeolryeolgryeolmryeolbry\
+    eolsryeoltryeolpryeolhryeomryeobryeobsryeosryeossryeongryeojryeocryeokryeotryeopryeohryeryegryeg\
+    gryegsryenryenjryenhryedryelryelgryelmryelbryelsryeltryelpryelhryemryebryebsryesryessryengryejry\
+    ecryekryetryepryehrogroggrogsronronjronhrodrolrolgrolmrolbrolsroltrolprolhromrobrobsrosrossrongr\
+    ojrocrokrohrwagrwaggrwagsrwanrwanjrwanhrwadrwalrwalgrwalmrwalbrwalsrwaltrwalprwalhrwamrwabrwabsr\
+    wasrwassrwangrwajrwacrwakrwatrwaprwahrwaerwaegrwaeggrwaegsrwaenrwaenjrwaenhrwaedrwaelrwaelgrwael\
+    mrwaelbrwaelsrwaeltrwaelprwaelhrwaemrwaebrwaebsrwaesrwaessrwaengrwaejrwaecrwaekrwaetrwaeprwaehro\
+    eroegroeggroegsroenroenjroenhroedroelroelgroelmroelbroelsroeltroelproelhroemroebroebsroesroessro\
+    engroejroecroekroetroeproehryoryogryoggryogsryonryonjryonhryodryolryolgryolmryolbryolsryoltryolp\
+    ryolhryomryobryobsryosryossryongryojryocryokryotryopryohrugruggrugsrunrunjrunhrudrulrulgrulmrulb\
+    rulsrultrulprulhrumrubrubsrusrussrungrujrucrukruhrweorweogrweoggrweogsrweonrweonjrweonhrweodrweo\
+    lrweolgrweolmrweolbrweolsrweoltrweolprweolhrweomrweobrweobsrweosrweossrweongrweojrweocrweokrweot\
+    rweoprweohrwegrweggrwegsrwenrwenjrwenhrwedrwelrwelgrwelmrwelbrwelsrweltrwelprwelhrwemrwebrwebsrw\
+    esrwessrwengrwejrwecrwekrwetrweprwehrwigrwiggrwigsrwinrwinjrwinhrwidrwilrwilgrwilmrwilbrwilsrwil\
+    trwilprwilhrwimrwibrwibsrwisrwissrwingrwijrwicrwikrwitrwiprwihryuryugryuggryugsryunryunjryunhryu\
+    dryulryulgryulmryulbryulsryultryulpryulhryumryubryubsryusryussryungryujryucryukryutryupryuhreugr\
+    euggreugsreunreunjreunhreudreulreulgreulmreulbreulsreultreulpreulhreumreubreubsreusreussreungreu\
+    jreucreukreutreupreuhryiryigryiggryigsryinryinjryinhryidryilryilgryilmryilbryilsryiltryilpryilhr\
+    yimryibryibsryisryissryingryijryicryikryitryipryihrigriggrigsrinrinjrinhridrilrilgrilmrilbrilsri\
+    ltrilprilhrimribribsrisrissringrijricrikritriprihmagmaggmagsmanmanjmanhmadmalmalgmalmmalbmalsmal\
+    tmalpmalhmammabmabsmasmassmangmajmacmakmahmaemaegmaeggmaegsmaenmaenjmaenhmaedmaelmaelgmaelmmaelb\
+    maelsmaeltmaelpmaelhmaemmaebmaebsmaesmaessmaengmaejmaecmaekmaetmaepmaehmyagmyaggmyagsmyanmyanjmy\
+    anhmyadmyalmyalgmyalmmyalbmyalsmyaltmyalpmyalhmyammyabmyabsmyasmyassmyangmyajmyacmyakmyatmyapmya\
+    hmyaemyaegmyaeggmyaegsmyaenmyaenjmyaenhmyaedmyaelmyaelgmyaelmmyaelbmyaelsmyaeltmyaelpmyaelhmyaem\
+    myaebmyaebsmyaesmyaessmyaengmyaejmyaecmyaekmyaetmyaepmyaehmeomeogmeoggmeogsmeonmeonjmeonhmeodmeo\
+    lmeolgmeolmmeolbmeolsmeoltmeolpmeolhmeommeobmeobsmeosmeossmeongmeojmeocmeokmeotmeopmeohmegmeggme\
+    gsmenjmenhmedmelmelgmelmmelbmelsmeltmelpmelhmemmebmebsmesmessmengmejmecmekmetmepmehmyeomyeogmyeo\
+    ggmyeogsmyeonmyeonjmyeonhmyeodmyeolmyeolgmyeolmmyeolbmyeolsmyeoltmyeolpmyeolhmyeommyeobmyeobsmye\
+    osmyeossmyeongmyeojmyeocmyeokmyeotmyeopmyeohmyemyegmyeggmyegsmyenmyenjmyenhmyedmyelmyelgmyelmmye\
+    lbmyelsmyeltmyelpmyelhmyemmyebmyebsmyesmyessmyengmyejmyecmyekmyetmyepmyehmogmoggmogsmonmonjmonhm\
+    odmolgmolmmolbmolsmoltmolpmolhmommobmobsmosmossmongmojmocmokmohmwagmwaggmwagsmwanmwanjmwanhmwadm\
+    walmwalgmwalmmwalbmwalsmwaltmwalpmwalhmwammwabmwabsmwasmwassmwangmwajmwacmwakmwatmwapmwahmwaemwa\
+    egmwaeggmwaegsmwaenmwaenjmwaenhmwaedmwaelmwaelgmwaelmmwaelbmwaelsmwaeltmwaelpmwaelhmwaemmwaebmwa\
+    ebsmwaesmwaessmwaengmwaejmwaecmwaekmwaetmwaepmwaehmoemoegmoeggmoegsmoenmoenjmoenhmoedmoelmoelgmo\
+    elmmoelbmoelsmoeltmoelpmoelhmoemmoebmoebsmoesmoessmoengmoejmoecmoekmoetmoepmoehmyomyogmyoggmyogs\
+    myonmyonjmyonhmyodmyolmyolgmyolmmyolbmyolsmyoltmyolpmyolhmyommyobmyobsmyosmyossmyongmyojmyocmyok\
+    myotmyopmyohmugmuggmugsmunmunjmunhmudmulmulgmulmmulbmulsmultmulpmulhmummubmubsmusmussmungmujmucm\
+    ukmuhmweomweogmweoggmweogsmweonmweonjmweonhmweodmweolmweolgmweolmmweolbmweolsmweoltmweolpmweolhm\
+    weommweobmweobsmweosmweossmweongmweojmweocmweokmweotmweopmweohmwegmweggmwegsmwenmwenjmwenhmwedmw\
+    elmwelgmwelmmwelbmwelsmweltmwelpmwelhmwemmwebmwebsmwesmwessmwengmwejmwecmwekmwetmwepmwehmwigmwig\
+    gmwigsmwinmwinjmwinhmwidmwilmwilgmwilmmwilbmwilsmwiltmwilpmwilhmwimmwibmwibsmwismwissmwingmwijmw\
+    icmwikmwitmwipmwihmyumyugmyuggmyugsmyunmyunjmyunhmyudmyulmyulgmyulmmyulbmyulsmyultmyulpmyulhmyum\
+    myubmyubsmyusmyussmyungmyujmyucmyukmyutmyupmyuhmeumeugmeuggmeugsmeunmeunjmeunhmeudmeulmeulgmeulm\
+    meulbmeulsmeultmeulpmeulhmeummeubmeubsmeusmeussmeungmeujmeucmeukmeutmeupmeuhmyimyigmyiggmyigsmyi\
+    nmyinjmyinhmyidmyilmyilgmyilmmyilbmyilsmyiltmyilpmyilhmyimmyibmyibsmyismyissmyingmyijmyicmyikmyi\
+    tmyipmyihmigmiggmigsminminjminhmidmilgmilmmilbmilsmiltmilpmilhmimmibmibsmismissmingmijmicmikmihb\
+    agbaggbagsbanbanjbanhbadbalbalgbalmbalbbalsbaltbalpbalhbambabbabsbasbassbangbajbacbakbahbaebaegb\
+    aeggbaegsbaenbaenjbaenhbaedbaelbaelgbaelmbaelbbaelsbaeltbaelpbaelhbaembaebbaebsbaesbaessbaengbae\
+    jbaecbaekbaetbaepbaehbyabyagbyaggbyagsbyanbyanjbyanhbyadbyalbyalgbyalmbyalbbyalsbyaltbyalpbyalhb\
+    yambyabbyabsbyasbyassbyangbyajbyacbyakbyatbyapbyahbyaebyaegbyaeggbyaegsbyaenbyaenjbyaenhbyaedbya\
+    elbyaelgbyaelmbyaelbbyaelsbyaeltbyaelpbyaelhbyaembyaebbyaebsbyaesbyaessbyaengbyaejbyaecbyaekbyae\
+    tbyaepbyaehbeobeogbeoggbeogsbeonbeonjbeonhbeodbeolbeolgbeolmbeolbbeolsbeoltbeolpbeolhbeombeobbeo\
+    bsbeosbeossbeongbeojbeocbeokbeotbeopbeohbegbeggbegsbenbenjbenhbedbelbelgbelmbelbbelsbeltbelpbelh\
+    bembebbebsbesbessbengbejbecbekbetbehbyeobyeogbyeoggbyeogsbyeonbyeonjbyeonhbyeodbyeolbyeolgbyeolm\
+    byeolbbyeolsbyeoltbyeolpbyeolhbyeombyeobbyeobsbyeosbyeossbyeongbyeojbyeocbyeokbyeotbyeopbyeohbye\
+    byegbyeggbyegsbyenbyenjbyenhbyedbyelbyelgbyelmbyelbbyelsbyeltbyelpbyelhbyembyebbyebsbyesbyessbye\
+    ngbyejbyecbyekbyetbyepbyehbogboggbogsbonbonjbonhbodbolbolgbolmbolbbolsboltbolpbolhbombobbobsbosb\
+    ossbongbojbocbokbohbwagbwaggbwagsbwanbwanjbwanhbwadbwalbwalgbwalmbwalbbwalsbwaltbwalpbwalhbwambw\
+    abbwabsbwasbwassbwangbwajbwacbwakbwatbwapbwahbwaebwaegbwaeggbwaegsbwaenbwaenjbwaenhbwaedbwaelbwa\
+    elgbwaelmbwaelbbwaelsbwaeltbwaelpbwaelhbwaembwaebbwaebsbwaesbwaessbwaengbwaejbwaecbwaekbwaetbwae\
+    pbwaehboeboegboeggboegsboenboenjboenhboedboelboelgboelmboelbboelsboeltboelpboelhboemboebboebsboe\
+    sboessboengboejboecboekboetboepboehbyobyogbyoggbyogsbyonbyonjbyonhbyodbyolbyolgbyolmbyolbbyolsby\
+    oltbyolpbyolhbyombyobbyobsbyosbyossbyongbyojbyocbyokbyotbyopbyohbugbuggbugsbunbunjbunhbudbulbulg\
+    bulmbulbbulsbultbulpbulhbumbubbubsbusbussbungbujbucbukbuhbweobweogbweoggbweogsbweonbweonjbweonhb\
+    weodbweolbweolgbweolmbweolbbweolsbweoltbweolpbweolhbweombweobbweobsbweosbweossbweongbweojbweocbw\
+    eokbweotbweopbweohbwegbweggbwegsbwenbwenjbwenhbwedbwelbwelgbwelmbwelbbwelsbweltbwelpbwelhbwembwe\
+    bbwebsbwesbwessbwengbwejbwecbwekbwetbwepbwehbwigbwiggbwigsbwinbwinjbwinhbwidbwilbwilgbwilmbwilbb\
+    wilsbwiltbwilpbwilhbwimbwibbwibsbwisbwissbwingbwijbwicbwikbwitbwipbwihbyubyugbyuggbyugsbyunbyunj\
+    byunhbyudbyulbyulgbyulmbyulbbyulsbyultbyulpbyulhbyumbyubbyubsbyusbyussbyungbyujbyucbyukbyutbyupb\
+    yuhbeubeugbeuggbeugsbeunbeunjbeunhbeudbeulbeulgbeulmbeulbbeulsbeultbeulpbeulhbeumbeubbeubsbeusbe\
+    ussbeungbeujbeucbeukbeutbeupbeuhbyibyigbyiggbyigsbyinbyinjbyinhbyidbyilbyilgbyilmbyilbbyilsbyilt\
+    byilpbyilhbyimbyibbyibsbyisbyissbyingbyijbyicbyikbyitbyipbyihbigbiggbigsbinbinjbinhbidbilbilgbil\
+    mbilbbilsbiltbilpbilhbimbibbibsbisbissbingbijbicbikbihbbagbbaggbbagsbbanbbanjbbanhbbadbbalbbalgb\
+    balmbbalbbbalsbbaltbbalpbbalhbbambbabbbabsbbasbbassbbangbbajbbacbbakbbahbbaebbaegbbaeggbbaegsbba\
+    enbbaenjbbaenhbbaedbbaelbbaelgbbaelmbbaelbbbaelsbbaeltbbaelpbbaelhbbaembbaebbbaebsbbaesbbaessbba\
+    engbbaejbbaecbbaekbbaetbbaepbbaehbbyabbyagbbyaggbbyagsbbyanbbyanjbbyanhbbyadbbyalbbyalgbbyalmbby\
+    albbbyalsbbyaltbbyalpbbyalhbbyambbyabbbyabsbbyasbbyassbbyangbbyajbbyacbbyakbbyatbbyapbbyahbbyaeb\
+    byaegbbyaeggbbyaegsbbyaenbbyaenjbbyaenhbbyaedbbyaelbbyaelgbbyaelmbbyaelbbbyaelsbbyaeltbbyaelpbby\
+    aelhbbyaembbyaebbbyaebsbbyaesbbyaessbbyaengbbyaejbbyaecbbyaekbbyaetbbyaepbbyaehbbeobbeogbbeoggbb\
+    eogsbbeonbbeonjbbeonhbbeodbbeolbbeolgbbeolmbbeolbbbeolsbbeoltbbeolpbbeolhbbeombbeobbbeobsbbeosbb\
+    eossbbeongbbeojbbeocbbeokbbeotbbeopbbeohbbegbbeggbbegsbbenbbenjbbenhbbedbbelbbelgbbelmbbelbbbels\
+    bbeltbbelpbbelhbbembbebbbebsbbesbbessbbengbbejbbecbbekbbetbbehbbyeobbyeogbbyeoggbbyeogsbbyeonbby\
+    eonjbbyeonhbbyeodbbyeolbbyeolgbbyeolmbbyeolbbbyeolsbbyeoltbbyeolpbbyeolhbbyeombbyeobbbyeobsbbyeo\
+    sbbyeossbbyeongbbyeojbbyeocbbyeokbbyeotbbyeopbbyeohbbyebbyegbbyeggbbyegsbbyenbbyenjbbyenhbbyedbb\
+    yelbbyelgbbyelmbbyelbbbyelsbbyeltbbyelpbbyelhbbyembbyebbbyebsbbyesbbyessbbyengbbyejbbyecbbyekbby\
+    etbbyepbbyehbbogbboggbbogsbbonbbonjbbonhbbodbbolbbolgbbolmbbolbbbolsbboltbbolpbbolhbbombbobbbobs\
+    bbosbbossbbongbbojbbocbbokbbohbbwabbwagbbwaggbbwagsbbwanbbwanjbbwanhbbwadbbwalbbwalgbbwalmbbwalb\
+    bbwalsbbwaltbbwalpbbwalhbbwambbwabbbwabsbbwasbbwassbbwangbbwajbbwacbbwakbbwatbbwapbbwahbbwaebbwa\
+    egbbwaeggbbwaegsbbwaenbbwaenjbbwaenhbbwaedbbwaelbbwaelgbbwaelmbbwaelbbbwaelsbbwaeltbbwaelpbbwael\
+    hbbwaembbwaebbbwaebsbbwaesbbwaessbbwaengbbwaejbbwaecbbwaekbbwaetbbwaepbbwaehbboebboegbboeggbboeg\
+    sbboenbboenjbboenhbboedbboelbboelgbboelmbboelbbboelsbboeltbboelpbboelhbboembboebbboebsbboesbboes\
+    sbboengbboejbboecbboekbboetbboepbboehbbyobbyogbbyoggbbyogsbbyonbbyonjbbyonhbbyodbbyolbbyolgbbyol\
+    mbbyolbbbyolsbbyoltbbyolpbbyolhbbyombbyobbbyobsbbyosbbyossbbyongbbyojbbyocbbyokbbyotbbyopbbyohbb\
+    ugbbuggbbugsbbunbbunjbbunhbbudbbulbbulgbbulmbbulbbbulsbbultbbulpbbulhbbumbbubbbubsbbusbbussbbung\
+    bbujbbucbbukbbuhbbweobbweogbbweoggbbweogsbbweonbbweonjbbweonhbbweodbbweolbbweolgbbweolmbbweolbbb\
+    weolsbbweoltbbweolpbbweolhbbweombbweobbbweobsbbweosbbweossbbweongbbweojbbweocbbweokbbweotbbweopb\
+    bweohbbwebbwegbbweggbbwegsbbwenbbwenjbbwenhbbwedbbwelbbwelgbbwelmbbwelbbbwelsbbweltbbwelpbbwelhb\
+    bwembbwebbbwebsbbwesbbwessbbwengbbwejbbwecbbwekbbwetbbwepbbwehbbwibbwigbbwiggbbwigsbbwinbbwinjbb\
+    winhbbwidbbwilbbwilgbbwilmbbwilbbbwilsbbwiltbbwilpbbwilhbbwimbbwibbbwibsbbwisbbwissbbwingbbwijbb\
+    wicbbwikbbwitbbwipbbwihbbyubbyugbbyuggbbyugsbbyunbbyunjbbyunhbbyudbbyulbbyulgbbyulmbbyulbbbyulsb\
+    byultbbyulpbbyulhbbyumbbyubbbyubsbbyusbbyussbbyungbbyujbbyucbbyukbbyutbbyupbbyuhbbeubbeugbbeuggb\
+    beugsbbeunbbeunjbbeunhbbeudbbeulbbeulgbbeulmbbeulbbbeulsbbeultbbeulpbbeulhbbeumbbeubbbeubsbbeusb\
+    beussbbeungbbeujbbeucbbeukbbeutbbeupbbeuhbbyibbyigbbyiggbbyigsbbyinbbyinjbbyinhbbyidbbyilbbyilgb\
+    byilmbbyilbbbyilsbbyiltbbyilpbbyilhbbyimbbyibbbyibsbbyisbbyissbbyingbbyijbbyicbbyikbbyitbbyipbby\
+    ihbbigbbiggbbigsbbinbbinjbbinhbbidbbilbbilgbbilmbbilbbbilsbbiltbbilpbbilhbbimbbibbbibsbbisbbissb\
+    bingbbijbbicbbikbbihsagsaggsagssansanjsanhsadsalsalgsalmsalbsalssaltsalpsalhsamsabsabssassasssan\
+    gsajsacsaksahsaesaegsaeggsaegssaensaenjsaenhsaedsaelsaelgsaelmsaelbsaelssaeltsaelpsaelhsaemsaebs\
+    aebssaessaesssaengsaejsaecsaeksaetsaepsaehsyasyagsyaggsyagssyansyanjsyanhsyadsyalsyalgsyalmsyalb\
+    syalssyaltsyalpsyalhsyamsyabsyabssyassyasssyangsyajsyacsyaksyatsyapsyahsyaesyaegsyaeggsyaegssyae\
+    nsyaenjsyaenhsyaedsyaelsyaelgsyaelmsyaelbsyaelssyaeltsyaelpsyaelhsyaemsyaebsyaebssyaessyaesssyae\
+    ngsyaejsyaecsyaeksyaetsyaepsyaehseoseogseoggseogsseonseonjseonhseodseolseolgseolmseolbseolsseolt\
+    seolpseolhseomseobseobsseosseossseongseojseocseokseotseopseohsegseggsegssensenjsenhsedselselgsel\
+    mselbselsseltselpselhsemsebsebssessesssengsejsecseksetsehsyeosyeogsyeoggsyeogssyeonsyeonjsyeonhs\
+    yeodsyeolsyeolgsyeolmsyeolbsyeolssyeoltsyeolpsyeolhsyeomsyeobsyeobssyeossyeosssyeongsyeojsyeocsy\
+    eoksyeotsyeopsyeohsyesyegsyeggsyegssyensyenjsyenhsyedsyelsyelgsyelmsyelbsyelssyeltsyelpsyelhsyem\
+    syebsyebssyessyesssyengsyejsyecsyeksyetsyepsyehsogsoggsogssonsonjsonhsodsolsolgsolmsolbsolssolts\
+    olpsolhsomsobsobssossosssongsojsocsoksohswagswaggswagsswanswanjswanhswadswalswalgswalmswalbswals\
+    swaltswalpswalhswamswabswabsswasswassswangswajswacswakswatswapswahswaeswaegswaeggswaegsswaenswae\
+    njswaenhswaedswaelswaelgswaelmswaelbswaelsswaeltswaelpswaelhswaemswaebswaebsswaesswaessswaengswa\
+    ejswaecswaekswaetswaepswaehsoesoegsoeggsoegssoensoenjsoenhsoedsoelsoelgsoelmsoelbsoelssoeltsoelp\
+    soelhsoemsoebsoebssoessoesssoengsoejsoecsoeksoetsoepsoehsyosyogsyoggsyogssyonsyonjsyonhsyodsyols\
+    yolgsyolmsyolbsyolssyoltsyolpsyolhsyomsyobsyobssyossyosssyongsyojsyocsyoksyotsyopsyohsugsuggsugs\
+    sunsunjsunhsudsulsulgsulmsulbsulssultsulpsulhsumsubsubssussusssungsujsucsuksuhsweosweogsweoggswe\
+    ogssweonsweonjsweonhsweodsweolsweolgsweolmsweolbsweolssweoltsweolpsweolhsweomsweobsweobssweosswe\
+    osssweongsweojsweocsweoksweotsweopsweohswegsweggswegsswenswenjswenhswedswelswelgswelmswelbswelss\
+    weltswelpswelhswemswebswebsswesswessswengswejswecswekswetswepswehswigswiggswigsswinswinjswinhswi\
+    dswilswilgswilmswilbswilsswiltswilpswilhswimswibswibsswisswissswingswijswicswikswitswipswihsyusy\
+    ugsyuggsyugssyunsyunjsyunhsyudsyulsyulgsyulmsyulbsyulssyultsyulpsyulhsyumsyubsyubssyussyusssyung\
+    syujsyucsyuksyutsyupsyuhseuseugseuggseugsseunseunjseunhseudseulseulgseulmseulbseulsseultseulpseu\
+    lhseumseubseubsseusseussseungseujseucseukseutseupseuhsyisyigsyiggsyigssyinsyinjsyinhsyidsyilsyil\
+    gsyilmsyilbsyilssyiltsyilpsyilhsyimsyibsyibssyissyisssyingsyijsyicsyiksyitsyipsyihsigsiggsigssin\
+    sinjsinhsidsilsilgsilmsilbsilssiltsilpsilhsimsibsibssississsingsijsicsiksihssagssaggssagsssanssa\
+    njssanhssadssalssalgssalmssalbssalsssaltssalpssalhssamssabssabsssasssassssangssajssacssakssahssa\
+    essaegssaeggssaegsssaenssaenjssaenhssaedssaelssaelgssaelmssaelbssaelsssaeltssaelpssaelhssaemssae\
+    bssaebsssaesssaessssaengssaejssaecssaekssaetssaepssaehssyassyagssyaggssyagsssyanssyanjssyanhssya\
+    dssyalssyalgssyalmssyalbssyalsssyaltssyalpssyalhssyamssyabssyabsssyasssyassssyangssyajssyacssyak\
+    ssyatssyapssyahssyaessyaegssyaeggssyaegsssyaenssyaenjssyaenhssyaedssyaelssyaelgssyaelmssyaelbssy\
+    aelsssyaeltssyaelpssyaelhssyaemssyaebssyaebsssyaesssyaessssyaengssyaejssyaecssyaekssyaetssyaepss\
+    yaehsseosseogsseoggsseogssseonsseonjsseonhsseodsseolsseolgsseolmsseolbsseolssseoltsseolpsseolhss\
+    eomsseobsseobssseossseosssseongsseojsseocsseoksseotsseopsseohssegsseggssegsssenssenjssenhssedsse\
+    lsselgsselmsselbsselssseltsselpsselhssemssebssebsssesssessssengssejssecssekssetssehssyeossyeogss\
+    yeoggssyeogsssyeonssyeonjssyeonhssyeodssyeolssyeolgssyeolmssyeolbssyeolsssyeoltssyeolpssyeolhssy\
+    eomssyeobssyeobsssyeosssyeossssyeongssyeojssyeocssyeokssyeotssyeopssyeohssyessyegssyeggssyegsssy\
+    enssyenjssyenhssyedssyelssyelgssyelmssyelbssyelsssyeltssyelpssyelhssyemssyebssyebsssyesssyessssy\
+    engssyejssyecssyekssyetssyepssyehssogssoggssogsssonssonjssonhssodssolssolgssolmssolbssolsssoltss\
+    olpssolhssomssobssobsssosssossssongssojssocssokssohsswasswagsswaggsswagssswansswanjsswanhsswadss\
+    walsswalgsswalmsswalbsswalssswaltsswalpsswalhsswamsswabsswabssswassswasssswangsswajsswacsswakssw\
+    atsswapsswahsswaesswaegsswaeggsswaegssswaensswaenjsswaenhsswaedsswaelsswaelgsswaelmsswaelbsswael\
+    ssswaeltsswaelpsswaelhsswaemsswaebsswaebssswaessswaesssswaengsswaejsswaecsswaeksswaetsswaepsswae\
+    hssoessoegssoeggssoegsssoenssoenjssoenhssoedssoelssoelgssoelmssoelbssoelsssoeltssoelpssoelhssoem\
+    ssoebssoebsssoesssoessssoengssoejssoecssoekssoetssoepssoehssyossyogssyoggssyogsssyonssyonjssyonh\
+    ssyodssyolssyolgssyolmssyolbssyolsssyoltssyolpssyolhssyomssyobssyobsssyosssyossssyongssyojssyocs\
+    syokssyotssyopssyohssugssuggssugsssunssunjssunhssudssulssulgssulmssulbssulsssultssulpssulhssumss\
+    ubssubsssusssussssungssujssucssukssuhssweossweogssweoggssweogsssweonssweonjssweonhssweodssweolss\
+    weolgssweolmssweolbssweolsssweoltssweolpssweolhssweomssweobssweobsssweosssweossssweongssweojsswe\
+    ocssweokssweotssweopssweohsswesswegssweggsswegssswensswenjsswenhsswedsswelsswelgsswelmsswelbsswe\
+    lsssweltsswelpsswelhsswemsswebsswebssswessswesssswengsswejsswecssweksswetsswepsswehsswisswigsswi\
+    ggsswigssswinsswinjsswinhsswidsswilsswilgsswilmsswilbsswilssswiltsswilpsswilhsswimsswibsswibsssw\
+    issswisssswingsswijsswicsswiksswitsswipsswihssyussyugssyuggssyugsssyunssyunjssyunhssyudssyulssyu\
+    lgssyulmssyulbssyulsssyultssyulpssyulhssyumssyubssyubsssyusssyussssyungssyujssyucssyukssyutssyup\
+    ssyuhsseusseugsseuggsseugssseunsseunjsseunhsseudsseulsseulgsseulmsseulbsseulssseultsseulpsseulhs\
+    seumsseubsseubssseussseusssseungsseujsseucsseuksseutsseupsseuhssyissyigssyiggssyigsssyinssyinjss\
+    yinhssyidssyilssyilgssyilmssyilbssyilsssyiltssyilpssyilhssyimssyibssyibsssyisssyissssyingssyijss\
+    yicssyikssyitssyipssyihssigssiggssigsssinssinjssinhssidssilssilgssilmssilbssilsssiltssilpssilhss\
+    imssibssibsssisssissssingssijssicssikssihagaggagsanjanhadalalgalmalbalsaltalpalhababsasassangaja\
+    kahaegaeggaegsaenaenjaenhaedaelaelgaelmaelbaelsaeltaelpaelhaemaebaebsaesaessaengaejaecaekaetaepa\
+    ehyagyaggyagsyanyanjyanhyadyalyalgyalmyalbyalsyaltyalpyalhyamyabyabsyasyassyangyajyacyakyatyapya\
+    hyaegyaeggyaegsyaenyaenjyaenhyaedyaelyaelgyaelmyaelbyaelsyaeltyaelpyaelhyaemyaebyaebsyaesyaessya\
+    engyaejyaecyaekyaetyaepyaeheogeoggeogseoneonjeonheodeoleolgeolmeolbeolseolteolpeolheomeobeobseos\
+    eosseongeojeoceokeoteopeohegeggegsenjenhedelelgelmelbelseltelpelhemebebsesessengejeceketepehyeog\
+    yeoggyeogsyeonyeonjyeonhyeodyeolyeolgyeolmyeolbyeolsyeoltyeolpyeolhyeomyeobyeobsyeosyeossyeongye\
+    ojyeocyeokyeotyeopyeohyegyeggyegsyenyenjyenhyedyelyelgyelmyelbyelsyeltyelpyelhyemyebyebsyesyessy\
+    engyejyecyekyetyepyehogoggogsonjonhodololgolmolbolsoltolpolhomobobsosossongojocokohwagwaggwagswa\
+    nwanjwanhwadwalwalgwalmwalbwalswaltwalpwalhwamwabwabswaswasswangwajwacwakwahwaegwaeggwaegswaenwa\
+    enjwaenhwaedwaelwaelgwaelmwaelbwaelswaeltwaelpwaelhwaemwaebwaebswaeswaesswaengwaejwaecwaekwaetwa\
+    epwaehoegoeggoegsoenoenjoenhoedoeloelgoelmoelboelsoeltoelpoelhoemoeboebsoesoessoengoejoecoekoeto\
+    epoehyogyoggyogsyonyonjyonhyodyolyolgyolmyolbyolsyoltyolpyolhyomyobyobsyosyossyongyojyocyokyohug\
+    uggugsunjunhudululgulmulbulsultulpulhububsusussungujucutupuhweogweoggweogsweonweonjweonhweodweol\
+    weolgweolmweolbweolsweoltweolpweolhweomweobweobsweosweossweongweojweocweokweotweopweohwegweggweg\
+    swenwenjwenhwedwelwelgwelmwelbwelsweltwelpwelhwemwebwebsweswesswengwejwecwekwetwehwigwiggwigswin\
+    winjwinhwidwilwilgwilmwilbwilswiltwilpwilhwimwibwibswiswisswingwijwicwikwitwipwihyugyuggyugsyuny\
+    unjyunhyudyulyulgyulmyulbyulsyultyulpyulhyumyubyubsyusyussyungyujyucyukyuheugeuggeugseuneunjeunh\
+    eudeuleulgeulmeulbeulseulteulpeulheumeubeubseuseusseungeujeuceukeuteupeuhyigyiggyigsyinyinjyinhy\
+    idyilyilgyilmyilbyilsyiltyilpyilhyimyibyibsyisyissyingyijyicyikyihigiggigsinjinhidililgilmilbils\
+    iltilpilhimibibsisissingicikihjagjaggjagsjanjanjjanhjadjaljalgjalmjalbjalsjaltjalpjalhjamjabjabs\
+    jasjassjangjajjacjakjatjapjahjaejaegjaeggjaegsjaenjaenjjaenhjaedjaeljaelgjaelmjaelbjaelsjaeltjae\
+    lpjaelhjaemjaebjaebsjaesjaessjaengjaejjaecjaekjaetjaepjaehjyajyagjyaggjyagsjyanjyanjjyanhjyadjya\
+    ljyalgjyalmjyalbjyalsjyaltjyalpjyalhjyamjyabjyabsjyasjyassjyangjyajjyacjyakjyatjyapjyahjyaejyaeg\
+    jyaeggjyaegsjyaenjyaenjjyaenhjyaedjyaeljyaelgjyaelmjyaelbjyaelsjyaeltjyaelpjyaelhjyaemjyaebjyaeb\
+    sjyaesjyaessjyaengjyaejjyaecjyaekjyaetjyaepjyaehjeojeogjeoggjeogsjeonjeonjjeonhjeodjeoljeolgjeol\
+    mjeolbjeolsjeoltjeolpjeolhjeomjeobjeobsjeosjeossjeongjeojjeocjeokjeotjeopjeohjegjeggjegsjenjenjj\
+    enhjedjeljelgjelmjelbjelsjeltjelpjelhjemjebjebsjesjessjengjejjecjekjetjepjehjyeojyeogjyeoggjyeog\
+    sjyeonjyeonjjyeonhjyeodjyeoljyeolgjyeolmjyeolbjyeolsjyeoltjyeolpjyeolhjyeomjyeobjyeobsjyeosjyeos\
+    sjyeongjyeojjyeocjyeokjyeotjyeopjyeohjyejyegjyeggjyegsjyenjyenjjyenhjyedjyeljyelgjyelmjyelbjyels\
+    jyeltjyelpjyelhjyemjyebjyebsjyesjyessjyengjyejjyecjyekjyetjyepjyehjogjoggjogsjonjonjjonhjodjoljo\
+    lgjolmjolbjolsjoltjolpjolhjomjobjobsjosjossjongjojjocjokjohjwagjwaggjwagsjwanjwanjjwanhjwadjwalj\
+    walgjwalmjwalbjwalsjwaltjwalpjwalhjwamjwabjwabsjwasjwassjwangjwajjwacjwakjwatjwapjwahjwaejwaegjw\
+    aeggjwaegsjwaenjwaenjjwaenhjwaedjwaeljwaelgjwaelmjwaelbjwaelsjwaeltjwaelpjwaelhjwaemjwaebjwaebsj\
+    waesjwaessjwaengjwaejjwaecjwaekjwaetjwaepjwaehjoejoegjoeggjoegsjoenjoenjjoenhjoedjoeljoelgjoelmj\
+    oelbjoelsjoeltjoelpjoelhjoemjoebjoebsjoesjoessjoengjoejjoecjoekjoetjoepjoehjyojyogjyoggjyogsjyon\
+    jyonjjyonhjyodjyoljyolgjyolmjyolbjyolsjyoltjyolpjyolhjyomjyobjyobsjyosjyossjyongjyojjyocjyokjyot\
+    jyopjyohjugjuggjugsjunjunjjunhjudjuljulgjulmjulbjulsjultjulpjulhjumjubjubsjusjussjungjujjucjukju\
+    hjweojweogjweoggjweogsjweonjweonjjweonhjweodjweoljweolgjweolmjweolbjweolsjweoltjweolpjweolhjweom\
+    jweobjweobsjweosjweossjweongjweojjweocjweokjweotjweopjweohjwegjweggjwegsjwenjwenjjwenhjwedjweljw\
+    elgjwelmjwelbjwelsjweltjwelpjwelhjwemjwebjwebsjwesjwessjwengjwejjwecjwekjwetjwepjwehjwijwigjwigg\
+    jwigsjwinjwinjjwinhjwidjwiljwilgjwilmjwilbjwilsjwiltjwilpjwilhjwimjwibjwibsjwisjwissjwingjwijjwi\
+    cjwikjwitjwipjwihjyujyugjyuggjyugsjyunjyunjjyunhjyudjyuljyulgjyulmjyulbjyulsjyultjyulpjyulhjyumj\
+    yubjyubsjyusjyussjyungjyujjyucjyukjyutjyupjyuhjeujeugjeuggjeugsjeunjeunjjeunhjeudjeuljeulgjeulmj\
+    eulbjeulsjeultjeulpjeulhjeumjeubjeubsjeusjeussjeungjeujjeucjeukjeutjeupjeuhjyijyigjyiggjyigsjyin\
+    jyinjjyinhjyidjyiljyilgjyilmjyilbjyilsjyiltjyilpjyilhjyimjyibjyibsjyisjyissjyingjyijjyicjyikjyit\
+    jyipjyihjigjiggjigsjinjinjjinhjidjiljilgjilmjilbjilsjiltjilpjilhjimjibjibsjisjissjingjijjicjikji\
+    hjjagjjaggjjagsjjanjjanjjjanhjjadjjaljjalgjjalmjjalbjjalsjjaltjjalpjjalhjjamjjabjjabsjjasjjassjj\
+    angjjajjjacjjakjjatjjapjjahjjaejjaegjjaeggjjaegsjjaenjjaenjjjaenhjjaedjjaeljjaelgjjaelmjjaelbjja\
+    elsjjaeltjjaelpjjaelhjjaemjjaebjjaebsjjaesjjaessjjaengjjaejjjaecjjaekjjaetjjaepjjaehjjyajjyagjjy\
+    aggjjyagsjjyanjjyanjjjyanhjjyadjjyaljjyalgjjyalmjjyalbjjyalsjjyaltjjyalpjjyalhjjyamjjyabjjyabsjj\
+    yasjjyassjjyangjjyajjjyacjjyakjjyatjjyapjjyahjjyaejjyaegjjyaeggjjyaegsjjyaenjjyaenjjjyaenhjjyaed\
+    jjyaeljjyaelgjjyaelmjjyaelbjjyaelsjjyaeltjjyaelpjjyaelhjjyaemjjyaebjjyaebsjjyaesjjyaessjjyaengjj\
+    yaejjjyaecjjyaekjjyaetjjyaepjjyaehjjeojjeogjjeoggjjeogsjjeonjjeonjjjeonhjjeodjjeoljjeolgjjeolmjj\
+    eolbjjeolsjjeoltjjeolpjjeolhjjeomjjeobjjeobsjjeosjjeossjjeongjjeojjjeocjjeokjjeotjjeopjjeohjjegj\
+    jeggjjegsjjenjjenjjjenhjjedjjeljjelgjjelmjjelbjjelsjjeltjjelpjjelhjjemjjebjjebsjjesjjessjjengjje\
+    jjjecjjekjjetjjepjjehjjyeojjyeogjjyeoggjjyeogsjjyeonjjyeonjjjyeonhjjyeodjjyeoljjyeolgjjyeolmjjye\
+    olbjjyeolsjjyeoltjjyeolpjjyeolhjjyeomjjyeobjjyeobsjjyeosjjyeossjjyeongjjyeojjjyeocjjyeokjjyeotjj\
+    yeopjjyeohjjyejjyegjjyeggjjyegsjjyenjjyenjjjyenhjjyedjjyeljjyelgjjyelmjjyelbjjyelsjjyeltjjyelpjj\
+    yelhjjyemjjyebjjyebsjjyesjjyessjjyengjjyejjjyecjjyekjjyetjjyepjjyehjjogjjoggjjogsjjonjjonjjjonhj\
+    jodjjoljjolgjjolmjjolbjjolsjjoltjjolpjjolhjjomjjobjjobsjjosjjossjjongjjojjjocjjokjjohjjwajjwagjj\
+    waggjjwagsjjwanjjwanjjjwanhjjwadjjwaljjwalgjjwalmjjwalbjjwalsjjwaltjjwalpjjwalhjjwamjjwabjjwabsj\
+    jwasjjwassjjwangjjwajjjwacjjwakjjwatjjwapjjwahjjwaejjwaegjjwaeggjjwaegsjjwaenjjwaenjjjwaenhjjwae\
+    djjwaeljjwaelgjjwaelmjjwaelbjjwaelsjjwaeltjjwaelpjjwaelhjjwaemjjwaebjjwaebsjjwaesjjwaessjjwaengj\
+    jwaejjjwaecjjwaekjjwaetjjwaepjjwaehjjoejjoegjjoeggjjoegsjjoenjjoenjjjoenhjjoedjjoeljjoelgjjoelmj\
+    joelbjjoelsjjoeltjjoelpjjoelhjjoemjjoebjjoebsjjoesjjoessjjoengjjoejjjoecjjoekjjoetjjoepjjoehjjyo\
+    jjyogjjyoggjjyogsjjyonjjyonjjjyonhjjyodjjyoljjyolgjjyolmjjyolbjjyolsjjyoltjjyolpjjyolhjjyomjjyob\
+    jjyobsjjyosjjyossjjyongjjyojjjyocjjyokjjyotjjyopjjyohjjugjjuggjjugsjjunjjunjjjunhjjudjjuljjulgjj\
+    ulmjjulbjjulsjjultjjulpjjulhjjumjjubjjubsjjusjjussjjungjjujjjucjjukjjuhjjweojjweogjjweoggjjweogs\
+    jjweonjjweonjjjweonhjjweodjjweoljjweolgjjweolmjjweolbjjweolsjjweoltjjweolpjjweolhjjweomjjweobjjw\
+    eobsjjweosjjweossjjweongjjweojjjweocjjweokjjweotjjweopjjweohjjwejjwegjjweggjjwegsjjwenjjwenjjjwe\
+    nhjjwedjjweljjwelgjjwelmjjwelbjjwelsjjweltjjwelpjjwelhjjwemjjwebjjwebsjjwesjjwessjjwengjjwejjjwe\
+    cjjwekjjwetjjwepjjwehjjwijjwigjjwiggjjwigsjjwinjjwinjjjwinhjjwidjjwiljjwilgjjwilmjjwilbjjwilsjjw\
+    iltjjwilpjjwilhjjwimjjwibjjwibsjjwisjjwissjjwingjjwijjjwicjjwikjjwitjjwipjjwihjjyujjyugjjyuggjjy\
+    ugsjjyunjjyunjjjyunhjjyudjjyuljjyulgjjyulmjjyulbjjyulsjjyultjjyulpjjyulhjjyumjjyubjjyubsjjyusjjy\
+    ussjjyungjjyujjjyucjjyukjjyutjjyupjjyuhjjeujjeugjjeuggjjeugsjjeunjjeunjjjeunhjjeudjjeuljjeulgjje\
+    ulmjjeulbjjeulsjjeultjjeulpjjeulhjjeumjjeubjjeubsjjeusjjeussjjeungjjeujjjeucjjeukjjeutjjeupjjeuh\
+    jjyijjyigjjyiggjjyigsjjyinjjyinjjjyinhjjyidjjyiljjyilgjjyilmjjyilbjjyilsjjyiltjjyilpjjyilhjjyimj\
+    jyibjjyibsjjyisjjyissjjyingjjyijjjyicjjyikjjyitjjyipjjyihjjigjjiggjjigsjjinjjinjjjinhjjidjjiljji\
+    lgjjilmjjilbjjilsjjiltjjilpjjilhjjimjjibjjibsjjisjjissjjingjjijjjicjjikjjihcagcaggcagscancanjcan\
+    hcadcalgcalmcalbcalscaltcalpcalhcamcabcabscascasscangcajcaccakcahcaecaegcaeggcaegscaencaenjcaenh\
+    caedcaelcaelgcaelmcaelbcaelscaeltcaelpcaelhcaemcaebcaebscaescaesscaengcaejcaeccaekcaetcaepcaehcy\
+    acyagcyaggcyagscyancyanjcyanhcyadcyalcyalgcyalmcyalbcyalscyaltcyalpcyalhcyamcyabcyabscyascyasscy\
+    angcyajcyaccyakcyatcyapcyahcyaecyaegcyaeggcyaegscyaencyaenjcyaenhcyaedcyaelcyaelgcyaelmcyaelbcya\
+    elscyaeltcyaelpcyaelhcyaemcyaebcyaebscyaescyaesscyaengcyaejcyaeccyaekcyaetcyaepcyaehceoceogceogg\
+    ceogsceonceonjceonhceodceolceolgceolmceolbceolsceoltceolpceolhceomceobceobsceosceossceongceojceo\
+    cceokceotceopceohcegceggcegscencenjcenhcedcelcelgcelmcelbcelsceltcelpcelhcemcebcebscescesscengce\
+    jceccekcetcehcyeocyeogcyeoggcyeogscyeoncyeonjcyeonhcyeodcyeolcyeolgcyeolmcyeolbcyeolscyeoltcyeol\
+    pcyeolhcyeomcyeobcyeobscyeoscyeosscyeongcyeojcyeoccyeokcyeotcyeopcyeohcyecyegcyeggcyegscyencyenj\
+    cyenhcyedcyelcyelgcyelmcyelbcyelscyeltcyelpcyelhcyemcyebcyebscyescyesscyengcyejcyeccyekcyetcyepc\
+    yehcogcoggcogsconconjconhcodcolcolgcolmcolbcolscoltcolpcolhcomcobcobscoscosscongcojcoccokcohcwag\
+    cwaggcwagscwancwanjcwanhcwadcwalcwalgcwalmcwalbcwalscwaltcwalpcwalhcwamcwabcwabscwascwasscwangcw\
+    ajcwaccwakcwatcwapcwahcwaecwaegcwaeggcwaegscwaencwaenjcwaenhcwaedcwaelcwaelgcwaelmcwaelbcwaelscw\
+    aeltcwaelpcwaelhcwaemcwaebcwaebscwaescwaesscwaengcwaejcwaeccwaekcwaetcwaepcwaehcoecoegcoeggcoegs\
+    coencoenjcoenhcoedcoelcoelgcoelmcoelbcoelscoeltcoelpcoelhcoemcoebcoebscoescoesscoengcoejcoeccoek\
+    coetcoepcoehcyocyogcyoggcyogscyoncyonjcyonhcyodcyolcyolgcyolmcyolbcyolscyoltcyolpcyolhcyomcyobcy\
+    obscyoscyosscyongcyojcyoccyokcyotcyopcyohcugcuggcugscuncunjcunhcudculculgculmculbculscultculpcul\
+    hcumcubcubscuscusscungcujcuccukcuhcweocweogcweoggcweogscweoncweonjcweonhcweodcweolcweolgcweolmcw\
+    eolbcweolscweoltcweolpcweolhcweomcweobcweobscweoscweosscweongcweojcweoccweokcweotcweopcweohcwegc\
+    weggcwegscwencwenjcwenhcwedcwelcwelgcwelmcwelbcwelscweltcwelpcwelhcwemcwebcwebscwescwesscwengcwe\
+    jcweccwekcwetcwepcwehcwigcwiggcwigscwincwinjcwinhcwidcwilcwilgcwilmcwilbcwilscwiltcwilpcwilhcwim\
+    cwibcwibscwiscwisscwingcwijcwiccwikcwitcwipcwihcyucyugcyuggcyugscyuncyunjcyunhcyudcyulcyulgcyulm\
+    cyulbcyulscyultcyulpcyulhcyumcyubcyubscyuscyusscyungcyujcyuccyukcyutcyupcyuhceuceugceuggceugsceu\
+    nceunjceunhceudceulceulgceulmceulbceulsceultceulpceulhceumceubceubsceusceussceungceujceucceukceu\
+    tceupceuhcyicyigcyiggcyigscyincyinjcyinhcyidcyilcyilgcyilmcyilbcyilscyiltcyilpcyilhcyimcyibcyibs\
+    cyiscyisscyingcyijcyiccyikcyitcyipcyihcigciggcigscincinjcinhcidcilcilgcilmcilbcilsciltcilpcilhci\
+    mcibcibsciscisscingcijciccikcihkagkaggkagskankanjkanhkadkalkalgkalmkalbkalskaltkalpkalhkamkabkab\
+    skaskasskangkajkackakkaekaegkaeggkaegskaenkaenjkaenhkaedkaelkaelgkaelmkaelbkaelskaeltkaelpkaelhk\
+    aemkaebkaebskaeskaesskaengkaejkaeckaekkaetkaepkaehkyagkyaggkyagskyankyanjkyanhkyadkyalkyalgkyalm\
+    kyalbkyalskyaltkyalpkyalhkyamkyabkyabskyaskyasskyangkyajkyackyakkyatkyapkyahkyaekyaegkyaeggkyaeg\
+    skyaenkyaenjkyaenhkyaedkyaelkyaelgkyaelmkyaelbkyaelskyaeltkyaelpkyaelhkyaemkyaebkyaebskyaeskyaes\
+    skyaengkyaejkyaeckyaekkyaetkyaepkyaehkeokeogkeoggkeogskeonkeonjkeonhkeodkeolkeolgkeolmkeolbkeols\
+    keoltkeolpkeolhkeomkeobkeobskeoskeosskeongkeojkeockeokkeotkeopkeohkegkeggkegskenjkenhkedkelkelgk\
+    elmkelbkelskeltkelpkelhkemkebkebskeskesskengkejkeckekkyeokyeogkyeoggkyeogskyeonkyeonjkyeonhkyeod\
+    kyeolkyeolgkyeolmkyeolbkyeolskyeoltkyeolpkyeolhkyeomkyeobkyeobskyeoskyeosskyeongkyeojkyeockyeokk\
+    yeotkyeopkyeohkyegkyeggkyegskyenkyenjkyenhkyedkyelkyelgkyelmkyelbkyelskyeltkyelpkyelhkyemkyebkye\
+    bskyeskyesskyengkyejkyeckyekkyetkyepkyehkogkoggkogskonkonjkonhkodkolkolgkolmkolbkolskoltkolpkolh\
+    komkobkobskoskosskongkojkockokkwagkwaggkwagskwankwanjkwanhkwadkwalkwalgkwalmkwalbkwalskwaltkwalp\
+    kwalhkwamkwabkwabskwaskwasskwangkwajkwackwakkwatkwapkwahkwaekwaegkwaeggkwaegskwaenkwaenjkwaenhkw\
+    aedkwaelkwaelgkwaelmkwaelbkwaelskwaeltkwaelpkwaelhkwaemkwaebkwaebskwaeskwaesskwaengkwaejkwaeckwa\
+    ekkwaetkwaepkwaehkoekoegkoeggkoegskoenkoenjkoenhkoedkoelkoelgkoelmkoelbkoelskoeltkoelpkoelhkoemk\
+    oebkoebskoeskoesskoengkoejkoeckoekkoetkoepkoehkyogkyoggkyogskyonkyonjkyonhkyodkyolkyolgkyolmkyol\
+    bkyolskyoltkyolpkyolhkyomkyobkyobskyoskyosskyongkyojkyockyokkyotkyopkyohkugkuggkugskunkunjkunhku\
+    dkulkulgkulmkulbkulskultkulpkulhkumkubkubskuskusskungkujkuckukkuhkweokweogkweoggkweogskweonkweon\
+    jkweonhkweodkweolkweolgkweolmkweolbkweolskweoltkweolpkweolhkweomkweobkweobskweoskweosskweongkweo\
+    jkweockweokkweotkweopkweohkwegkweggkwegskwenkwenjkwenhkwedkwelkwelgkwelmkwelbkwelskweltkwelpkwel\
+    hkwemkwebkwebskweskwesskwengkwejkweckwekkwetkwepkwehkwigkwiggkwigskwinkwinjkwinhkwidkwilkwilgkwi\
+    lmkwilbkwilskwiltkwilpkwilhkwimkwibkwibskwiskwisskwingkwijkwickwikkwitkwipkwihkyugkyuggkyugskyun\
+    kyunjkyunhkyudkyulkyulgkyulmkyulbkyulskyultkyulpkyulhkyumkyubkyubskyuskyusskyungkyujkyuckyukkyut\
+    kyupkyuhkeukeugkeuggkeugskeunkeunjkeunhkeudkeulkeulgkeulmkeulbkeulskeultkeulpkeulhkeumkeubkeubsk\
+    euskeusskeungkeujkeuckeukkeutkeupkeuhkyigkyiggkyigskyinkyinjkyinhkyidkyilkyilgkyilmkyilbkyilskyi\
+    ltkyilpkyilhkyimkyibkyibskyiskyisskyingkyijkyickyikkyitkyipkyihkigkiggkigskinkinjkinhkidkilkilgk\
+    ilmkilbkilskiltkilpkilhkimkibkibskiskisskingkijkickiktagtaggtagstantanjtanhtadtaltalgtalmtalbtal\
+    stalttalptalhtamtabtabstastasstangtajtactaktahtaetaegtaeggtaegstaentaenjtaenhtaedtaeltaelgtaelmt\
+    aelbtaelstaelttaelptaelhtaemtaebtaebstaestaesstaengtaejtaectaektaettaeptaehtyagtyaggtyagstyantya\
+    njtyanhtyadtyaltyalgtyalmtyalbtyalstyalttyalptyalhtyamtyabtyabstyastyasstyangtyajtyactyaktyattya\
+    ptyahtyaetyaegtyaeggtyaegstyaentyaenjtyaenhtyaedtyaeltyaelgtyaelmtyaelbtyaelstyaelttyaelptyaelht\
+    yaemtyaebtyaebstyaestyaesstyaengtyaejtyaectyaektyaettyaeptyaehteoteogteoggteogsteonteonjteonhteo\
+    dteolteolgteolmteolbteolsteoltteolpteolhteomteobteobsteosteossteongteojteocteokteotteopteohtegte\
+    ggtegstentenjtenhtedteltelgtelmtelbtelstelttelptelhtemtebtebstestesstengtejtectektettehtyeotyeog\
+    tyeoggtyeogstyeontyeonjtyeonhtyeodtyeoltyeolgtyeolmtyeolbtyeolstyeolttyeolptyeolhtyeomtyeobtyeob\
+    styeostyeosstyeongtyeojtyeoctyeoktyeottyeoptyeohtyegtyeggtyegstyentyenjtyenhtyedtyeltyelgtyelmty\
+    elbtyelstyelttyelptyelhtyemtyebtyebstyestyesstyengtyejtyectyektyettyeptyehtogtoggtogstonjtonhtod\
+    toltolgtolmtolbtolstolttolptolhtomtobtobstostosstongtojtoctoktohtwagtwaggtwagstwantwanjtwanhtwad\
+    twaltwalgtwalmtwalbtwalstwalttwalptwalhtwamtwabtwabstwastwasstwangtwajtwactwaktwattwaptwahtwaetw\
+    aegtwaeggtwaegstwaentwaenjtwaenhtwaedtwaeltwaelgtwaelmtwaelbtwaelstwaelttwaelptwaelhtwaemtwaebtw\
+    aebstwaestwaesstwaengtwaejtwaectwaektwaettwaeptwaehtoetoegtoeggtoegstoentoenjtoenhtoedtoeltoelgt\
+    oelmtoelbtoelstoelttoelptoelhtoemtoebtoebstoestoesstoengtoejtoectoektoettoeptoehtyogtyoggtyogsty\
+    ontyonjtyonhtyodtyoltyolgtyolmtyolbtyolstyolttyolptyolhtyomtyobtyobstyostyosstyongtyojtyoctyokty\
+    ottyoptyohtugtuggtugstuntunjtunhtudtultulgtulmtulbtulstulttulptulhtumtubtubstustusstungtujtuctuk\
+    tuhtweotweogtweoggtweogstweontweonjtweonhtweodtweoltweolgtweolmtweolbtweolstweolttweolptweolhtwe\
+    omtweobtweobstweostweosstweongtweojtweoctweoktweottweoptweohtwegtweggtwegstwentwenjtwenhtwedtwel\
+    twelgtwelmtwelbtwelstwelttwelptwelhtwemtwebtwebstwestwesstwengtwejtwectwektwettweptwehtwigtwiggt\
+    wigstwintwinjtwinhtwidtwiltwilgtwilmtwilbtwilstwilttwilptwilhtwimtwibtwibstwistwisstwingtwijtwic\
+    twiktwittwiptwihtyutyugtyuggtyugstyuntyunjtyunhtyudtyultyulgtyulmtyulbtyulstyulttyulptyulhtyumty\
+    ubtyubstyustyusstyungtyujtyuctyuktyuttyuptyuhteuteugteuggteugsteunteunjteunhteudteulteulgteulmte\
+    ulbteulsteultteulpteulhteumteubteubsteusteussteungteujteucteukteutteupteuhtyigtyiggtyigstyintyin\
+    jtyinhtyidtyiltyilgtyilmtyilbtyilstyilttyilptyilhtyimtyibtyibstyistyisstyingtyijtyictyiktyittyip\
+    tyihtigtiggtigstintinjtinhtidtiltilgtilmtilbtilstilttilptilhtimtibtibstistisstingtijtictiktihpag\
+    paggpagspanpanjpanhpadpalpalgpalmpalbpalspaltpalppalhpampabpabspaspasspangpajpacpakpahpaepaegpae\
+    ggpaegspaenpaenjpaenhpaedpaelpaelgpaelmpaelbpaelspaeltpaelppaelhpaempaebpaebspaespaesspaengpaejp\
+    aecpaekpaetpaeppaehpyapyagpyaggpyagspyanpyanjpyanhpyadpyalpyalgpyalmpyalbpyalspyaltpyalppyalhpya\
+    mpyabpyabspyaspyasspyangpyajpyacpyakpyatpyappyahpyaepyaegpyaeggpyaegspyaenpyaenjpyaenhpyaedpyael\
+    pyaelgpyaelmpyaelbpyaelspyaeltpyaelppyaelhpyaempyaebpyaebspyaespyaesspyaengpyaejpyaecpyaekpyaetp\
+    yaeppyaehpeopeogpeoggpeogspeonpeonjpeonhpeodpeolpeolgpeolmpeolbpeolspeoltpeolppeolhpeompeobpeobs\
+    peospeosspeongpeojpeocpeokpeotpeoppeohpegpeggpegspenpenjpenhpedpelpelgpelmpelbpelspeltpelppelhpe\
+    mpebpebspespesspengpejpecpekpetpeppehpyeopyeogpyeoggpyeogspyeonpyeonjpyeonhpyeodpyeolpyeolgpyeol\
+    mpyeolbpyeolspyeoltpyeolppyeolhpyeompyeobpyeobspyeospyeosspyeongpyeojpyeocpyeokpyeotpyeoppyeohpy\
+    epyegpyeggpyegspyenpyenjpyenhpyedpyelpyelgpyelmpyelbpyelspyeltpyelppyelhpyempyebpyebspyespyesspy\
+    engpyejpyecpyekpyetpyeppyehpogpoggpogsponponjponhpodpolpolgpolmpolbpolspoltpolppolhpompobpobspos\
+    posspongpojpocpokpohpwagpwaggpwagspwanpwanjpwanhpwadpwalpwalgpwalmpwalbpwalspwaltpwalppwalhpwamp\
+    wabpwabspwaspwasspwangpwajpwacpwakpwatpwappwahpwaepwaegpwaeggpwaegspwaenpwaenjpwaenhpwaedpwaelpw\
+    aelgpwaelmpwaelbpwaelspwaeltpwaelppwaelhpwaempwaebpwaebspwaespwaesspwaengpwaejpwaecpwaekpwaetpwa\
+    eppwaehpoepoegpoeggpoegspoenpoenjpoenhpoedpoelpoelgpoelmpoelbpoelspoeltpoelppoelhpoempoebpoebspo\
+    espoesspoengpoejpoecpoekpoetpoeppoehpyopyogpyoggpyogspyonpyonjpyonhpyodpyolpyolgpyolmpyolbpyolsp\
+    yoltpyolppyolhpyompyobpyobspyospyosspyongpyojpyocpyokpyotpyoppyohpugpuggpugspunpunjpunhpudpulpul\
+    gpulmpulbpulspultpulppulhpumpubpubspuspusspungpujpucpukpuhpweopweogpweoggpweogspweonpweonjpweonh\
+    pweodpweolpweolgpweolmpweolbpweolspweoltpweolppweolhpweompweobpweobspweospweosspweongpweojpweocp\
+    weokpweotpweoppweohpwegpweggpwegspwenpwenjpwenhpwedpwelpwelgpwelmpwelbpwelspweltpwelppwelhpwempw\
+    ebpwebspwespwesspwengpwejpwecpwekpwetpweppwehpwigpwiggpwigspwinpwinjpwinhpwidpwilpwilgpwilmpwilb\
+    pwilspwiltpwilppwilhpwimpwibpwibspwispwisspwingpwijpwicpwikpwitpwippwihpyupyugpyuggpyugspyunpyun\
+    jpyunhpyudpyulpyulgpyulmpyulbpyulspyultpyulppyulhpyumpyubpyubspyuspyusspyungpyujpyucpyukpyutpyup\
+    pyuhpeupeugpeuggpeugspeunpeunjpeunhpeudpeulpeulgpeulmpeulbpeulspeultpeulppeulhpeumpeubpeubspeusp\
+    eusspeungpeujpeucpeukpeutpeuppeuhpyipyigpyiggpyigspyinpyinjpyinhpyidpyilpyilgpyilmpyilbpyilspyil\
+    tpyilppyilhpyimpyibpyibspyispyisspyingpyijpyicpyikpyitpyippyihpigpiggpigspinpinjpinhpidpilpilgpi\
+    lmpilbpilspiltpilppilhpimpibpibspispisspingpijpicpikpihhaghagghagshanhanjhanhhadhalhalghalmhalbh\
+    alshalthalphalhhamhabhabshashasshanghajhachakhahhaehaeghaegghaegshaenhaenjhaenhhaedhaelhaelghael\
+    mhaelbhaelshaelthaelphaelhhaemhaebhaebshaeshaesshaenghaejhaechaekhaethaephaehhyaghyagghyagshyanh\
+    yanjhyanhhyadhyalhyalghyalmhyalbhyalshyalthyalphyalhhyamhyabhyabshyashyasshyanghyajhyachyakhyath\
+    yaphyahhyaehyaeghyaegghyaegshyaenhyaenjhyaenhhyaedhyaelhyaelghyaelmhyaelbhyaelshyaelthyaelphyael\
+    hhyaemhyaebhyaebshyaeshyaesshyaenghyaejhyaechyaekhyaethyaephyaehheoheogheoggheogsheonheonjheonhh\
+    eodheolheolgheolmheolbheolsheoltheolpheolhheomheobheobsheosheossheongheojheocheokheotheopheohheg\
+    hegghegshenhenjhenhhedhelhelghelmhelbhelshelthelphelhhemhebhebsheshesshenghejhechekhethehhyeohye\
+    oghyeogghyeogshyeonhyeonjhyeonhhyeodhyeolhyeolghyeolmhyeolbhyeolshyeolthyeolphyeolhhyeomhyeobhye\
+    obshyeoshyeosshyeonghyeojhyeochyeokhyeothyeophyeohhyeghyegghyegshyenhyenjhyenhhyedhyelhyelghyelm\
+    hyelbhyelshyelthyelphyelhhyemhyebhyebshyeshyesshyenghyejhyechyekhyethyephyehhoghogghogshonjhonhh\
+    odholholgholmholbholsholtholpholhhomhobhobshoshosshonghojhochokhohhwaghwagghwagshwanhwanjhwanhhw\
+    adhwalhwalghwalmhwalbhwalshwalthwalphwalhhwamhwabhwabshwashwasshwanghwajhwachwakhwathwaphwahhwae\
+    hwaeghwaegghwaegshwaenhwaenjhwaenhhwaedhwaelhwaelghwaelmhwaelbhwaelshwaelthwaelphwaelhhwaemhwaeb\
+    hwaebshwaeshwaesshwaenghwaejhwaechwaekhwaethwaephwaehhoehoeghoegghoegshoenhoenjhoenhhoedhoelhoel\
+    ghoelmhoelbhoelshoelthoelphoelhhoemhoebhoebshoeshoesshoenghoejhoechoekhoethoephoehhyoghyogghyogs\
+    hyonhyonjhyonhhyodhyolhyolghyolmhyolbhyolshyolthyolphyolhhyomhyobhyobshyoshyosshyonghyojhyochyok\
+    hyothyophyohhughugghugshunhunjhunhhudhulhulghulmhulbhulshulthulphulhhumhubhubshushusshunghujhuch\
+    ukhuthuphuhhweohweoghweogghweogshweonhweonjhweonhhweodhweolhweolghweolmhweolbhweolshweolthweolph\
+    weolhhweomhweobhweobshweoshweosshweonghweojhweochweokhweothweophweohhweghwegghwegshwenhwenjhwenh\
+    hwedhwelhwelghwelmhwelbhwelshwelthwelphwelhhwemhwebhwebshweshwesshwenghwejhwechwekhwethwephwehhw\
+    ighwigghwigshwinhwinjhwinhhwidhwilhwilghwilmhwilbhwilshwilthwilphwilhhwimhwibhwibshwishwisshwing\
+    hwijhwichwikhwithwiphwihhyughyugghyugshyunhyunjhyunhhyudhyulhyulghyulmhyulbhyulshyulthyulphyulhh\
+    yumhyubhyubshyushyusshyunghyujhyuchyukhyuthyuphyuhheuheugheuggheugsheunheunjheunhheudheulheulghe\
+    ulmheulbheulsheultheulpheulhheumheubheubsheusheussheungheujheucheukheutheupheuhhyighyigghyigshyi\
+    nhyinjhyinhhyidhyilhyilghyilmhyilbhyilshyilthyilphyilhhyimhyibhyibshyishyisshyinghyijhyichyikhyi\
+    thyiphyihhighigghigshinhinjhinhhidhilhilghilmhilbhilshilthilphilhhimhibhibshishisshinghijhichikh\
+    iphihoyeoooiyoaeyoeouyeouiiyuaeeuaeueoeueeuoiyaoiyaeiyeoiyeioiiyoiyunlnchttbdsgdtlghllklmhlbdlbp\
+    lqhmnmnnmbsmjblpbmbsdbchssgssdszschzbzvngmnghjbjppKay Kayng Kol Kyun Kul Kum Nak Nam Nap Nayng N\
+    o Nok Non Nwu Nuk Num Twu Lak Lyeng Lo Lyul Pey Pyen Pwul Sak Sam Sayk Sep Sway Sim Yak Yek Yen \
+    Yel Yem Yep Yeng Yey Wun Yuk Yul Yung I Ik In Im Cek Cip Chek Chey Thak Thang Thayk Thong Pho Ph\
+    ok Hyen Hwak CocKiSenKakuffffifflvnmkhe'i'ai'ei'wi'ui'oi'ji'hi'mi'ybkhtkhtythjthmthyjmhjkhjkhmsk\
+    hdkhdhm`j`mghjghmfjfhfkhqhqmkkhnkhyjykhymun-in-a-u-i--ai'ri'zi'nbrbztnthrthzthnnzyzyni'khthhshms\
+    hh`yghykhyshjshkhshrdraantjmtkhmtmjtmhtmkhjmhsjhsjasmhsmjsmmshhmshjyshmkhshmmdkhmtmmtmy`jm`mm`ma\
+    ghmmghmyghmafkhmqmhqmmlhmlhyljjlkhmmhjmhmmhymjhmjmmkhjmkhmmjkhhmjhmmnhmnhanjmnmynmaymmbkhytjytja\
+    tkhytkhatmajmyjhajmaskhashhydhyljylmyyhyyjyymymmyqmynhy`mykmynjhmkhyljmkmmjhyhjymjyfmybhyskhy{Sa\
+    lla}{Qala}AllahAkbarMohammedSL`MRasul{Alayhi}{WaSallam}{Salla Llahu Alayhi WaSallam}{Jalla Jalal\
+    ahu}Rial {Bismillah Ar-Rahman Ar-Rahimi})]) i'l'/CW=90200300400500600700800200030004000500060007\
+    000800090001000020000300004000050000600007000080000900002/3GrIIS0102030405060709shcsraAMHAXSBGAw\
+    DhOyarorerur'riryuuBrHchEiHyHkKyHpHtHchchhcd'c'j'IjfnglzhqcRMTIMN1000002000003000004000005000006\
+    00000700000800000900000vhxvngvbashMbNcCsNdNkLyMpSzNtTyZsTpruscsnkzstprus1/3x'`d-'-wSrihrhuushris\
+    hriishrushruushrashraaspespispospa2160004320005/61/81/612b51525354555658596162636667686971727374\
+    7575b76787981828384858687899192949596979899101102103104105107108109110112114301302mSaxrXrdmniwqi\
+    zirySpsSpsimsiDHwtyXnmwinpwstXmnwmAatHHtpHrSnyrmiwDAtfnDsptsptymnDaHADsrDbamtsbqsAHzzmtzAbmAirwA\
+    bywnHAtSsAwsrwpbHsDmxpSwHmXnstiSdnfrzmAimAxspriwaqAbAAtywmwtnbtynHDbrxytAxdSrgmbAbaHiaqwrnDszAxn\
+    wSAmAwTAsnDwSmpAqSwaSAStywmzHsbksAqHfnDDXAnSmtxprsrqtiAmHnxtrnpSAzSnwdnwADHAsxtrswSmanDmbnrbdtiz\
+    idtzwHnmmtpzDiaHsbAdwAttAiwwDbspAtxAstAxtiAtmwprHwtaHwsxtkArzHtxniwnaAzxntinbqnbtSzpiptnxnniwtzp\
+    SnwtwHanfwaHaxrwwzqrswxAwtHtpkpsnTrnTrimntiAbwxxmHDtdSrtsxmtyxprSAtfSwtymDHwsxnbwtHnmnitsDAwxtms\
+    TdmDSndytmnxtsfsiATbanxSwtxwHqAawtwAsDamxrpAmsnxxwHDHDDpdpDzwnrwdrsqmAwrrtSmsqsiHDbAXrnmtsSmmrHq\
+    AtbiAgrgstpmnxAbHmtwbADArtHzmnxsfHmmxAtsTAsTAwwAsnTSnarqiTimDtmDmHwDaDwAHmsnsSridrbAsHbXnmiabxnt\
+    HnqtnwinirdimDAtzSibAzSStimiqnmAasAaprwDanDqdXkrV11dhaioqazvzvcvqvxezeqiquzuquxawzawcawqawxuizui\
+    cuiquixuexuezuecuequiuzuiucuiuquiuxmzmqmxhtteebeevaubauvuvevaibaivooboovawbawvuabuavoviavwbaabaa\
+    vntsplhnthCaumPuaVamRoobNeevRuavTasXyooHliZTHnubNqigXiabNtujTCMTTauLosMusCHLNNCCTCTCTCCPDCNTYeng\
+    LeeLorHerMouaThaoPhaKhangVangChueKwVueAtiuKpatiuAivahxianianguanuanguogyuiiongchhangwuzargwyrtso\
+    nrerparshontsewthwyphyvorhartewthwuthewchhewthonnyrtshwintsewrdzewdirkyrgewvondzyjwochhikirtshec\
+    hhwaghwamernartshentshwakwarbornkertshivarngyrgardzentswylerlwyvirswyverlonrirpewmyrchwechwonchw\
+    ocharchwychwenngornshwonbirrorlewghwennwyshanrarlwughorlwenkhwydzwalenkhewrewrsewmarlwonkewrngew\
+    rtwyj?kwytshyngwyrshwytsarkhankhwasiwjewlhwochhypharsarsharlhewzirlwyrwyakhonlwandzonlhwitsurtsi\
+    ndzarzorntshughirchhonchewlhwatshachonkwondzwykewchhenchenshwuchhudzwantshewchhwanshinkhwarchort\
+    swuchhyrnerkharlirtswenchwihwarchwanthwan?arnorchiwthanjwonchwudarmortsirkhinngwydewrtshwumirtha\
+    rthentserswewwyghurghardzwyrkorkarghewtshonchinngwirghanshwyrngontsyzewghwetshwewnwewchhwykhwelh\
+    wuwerchhekhordwukhwanchannirn?kornzhinnewngyzorchhwyrtsenchhwozhirchhwilhornpwyrthorntshwyshwenw\
+    yrjirlinhyrlwinphanphinvanhewlhonngurlhorvenjartsyrshwantshanzontwyrchhokhwitsuntsorndyrlanzhiwn\
+    wuzhwyshewtshwethwyrlordzyrjwujwewtsharphyrberlhyrtshwenchhwewswudzwotswyrshwewtshinvinlhwechhiw\
+    dewtsanghwanlhwyghyrchhanchhwenngirshenghwytwarkwyrtsorzwiphonghiwpirshordwewrzingwynornzhonzwet\
+    swishwyatshwizwytshokhwutwudwartyrzwyrzhwuzewrzhertswejwyrlhenjwykhenkwumewlhertswintshwanchhwor\
+    t?dwyrdzurchhwintshyrghonmornchirdwyphen?ugorchwewzhewhwyrzergwarzwanngwoghentswodzwewtewrtwonta\
+    rdzwitswarchhinj?nthinghornkhurzhwyrdzwewarghwyrghwi?atwewchharrornhwylwewgewrporntswanv?gwonzwe\
+    wlhanghinlhwyrchhweghwindziw75675775875976076176276376476576676776837838338138239239545945439045\
+    545245339638438545038938857391386773873793933949310611111311511665117118397398399401403279280281\
+    282283284285286287288289290291292293294295296402297298299303304305306307308309310404405406407408\
+    311312313314315316317318319411412409410413320321322323324325416417419420164165451166167168169170\
+    171172173175174176177178430179427428429432181182183431184185186187188189190457191192193194195196\
+    414197198199201415202203204205206207208209210220221222223224225226418227228229230231232233234235\
+    236237238239241242245250251253422356243248254358359244421246247249252255423256257258259260261425\
+    262263426264265266424267268269270326327328329330331332333334335458336337438439440338437339340341\
+    436342343344345346347348349350351352353354355357360361362433434435363364365366367368369370371372\
+    373374375376377119120121122123124125126127128129130131132133134135442443136137138139444140141441\
+    142143144145146147148149151152153154155156157158159161162163445211212456213214446216217218219215\
+    449447274448275276277278271272273phiutchyeliusiunjyuvaikaikhaukoutciouliangciangtshengthoetsoetc\
+    yufaihauloulioucioutchynfangvangkhangthengpiongkhuatchyatcietchievwetcinjiuciutchypailaitouiousu\
+    owhuownguwthangtciangnjiangtshiongtcingloekhuoetchitsiufwmaithaisaithaulauthuowuowkuwtcyntsangmi\
+    ongciongnjingkuaphoefoetshoetchyuphwtsaitsoupioumioutchiounjiounuowkuowngutsiangliongkuetsioutsh\
+    iuphaimautshautshiousioulangtshangsianglenglongtsiongsiongtshietcykhwsaungoutchiongtcyetciulwkau\
+    thouhoutsengtsietauvoetsuowtchiangtciongtshainguowpnfnjsmnsnmsjmssjsjnsstrtrswhkrsgrsskrowaouooh\
+    weiwowD.S.D.C.%%8GG88FF8##8va8vb15ma15mbNTPed.TianRenDiRenRenTianRenDiRenRenntxtxnqnpnpliuhaangu\
+    enk'wik'wewuiwviwvawvemuamuesiafuaheihuanggaangguanggeenggoogueiguanguambuumbeembooBhYhGb1L2Lcr2\
+    crhd1z2z3z4zmahjong 6z5z1m2m3m4m5m6m7m8m9m1s2s3s4s5s6s7s8s9s1p2p3p4p5p6p7p8p9p1b2b4b3b5b6b7b8b00\
+    AsJsQsAhQhAdTdJdQdKdblack joker Ac2c3c4c5c6c7c8c9cTcJcQcKc0.0,1,2,3,5,6,7,8,9,CC0ShareAlikeNonCo\
+    mmercial(A)(B)(C)(D)(E)(F)(G)(H)(I)(J)(K)(L)(M)(N)(O)(Q)(R)(S)(T)(U)(V)(W)(X)(Y)(Z)[S]CDWZcopyle\
+    ft SDPPVWCMCMDMRCreativeCommonsPublicDomainAttributionPXICPAab DJcl cool free id new ng ok sos u\
+    p vs 3D2ndScr2K4K8K5.17.122.260P120PHCHDRHi-ResLosslessSHVUHDVOD*M*hokakoko SA Noneu6307 u7981 u\
+    7a7a PassFullu6709 Monthu7533 u5272 Open[Ben][San][Er][An][Dian][Da][Dao][Sheng][Bai]ideograph a\
+    dvantage accept Shuangxicyclone foggy closed umbrella night with stars sunrise over mountains su\
+    nrise city sunset rainbow bridge at night ocean volcano milky way earth africa earth americas ea\
+    rth asia globe with meridians new moon waxing crescent moon first quarter moon moon full moon wa\
+    ning gibbous moon last quarter moon waning crescent moon crescent moon new moon with face first \
+    quarter moon with face last quarter moon with face full moon with face sun with face stars therm\
+    ometer white sun small cloud white sun cloud white sun rain cloud cloud rain cloud snow cloud li\
+    ghtning tornado fog wind blowing face hotdog taco burrito chestnut seedling evergreen tree decid\
+    uous tree palm tree cactus hot pepper tulip cherry blossom rose hibiscus sunflower blossom corn \
+    ear of rice herb four leaf clover maple leaf fallen leaf leaves mushroom tomato eggplant grapes \
+    melon watermelon orange lemon banana pineapple apple green apple pear peach cherries strawberry \
+    hamburger pizza meat on bone poultry leg rice cracker rice ball rice curry ramen spaghetti bread\
+    \x20fries sweet potato dango oden sushi fried shrimp fish cake icecream shaved ice ice cream doughn\
+    ut cookie chocolate bar candy lollipop custard honey pot cake bento stew fried egg fork and knif\
+    e tea sake wine glass cocktail tropical drink beer beers baby bottle fork knife plate champagne \
+    popcorn ribbon gift birthday jack o lantern christmas tree santa fireworks sparkler balloon tada\
+    \x20confetti ball tanabata tree crossed flags bamboo dolls flags wind chime rice scene school satch\
+    el mortar board military medal reminder ribbon microphone level slider control knobs film frames\
+    \x20ticket carousel horse ferris wheel roller coaster fishing pole and fish movie camera cinema hea\
+    dphones art tophat circus tent clapper performing arts video game dart slot machine 8ball game d\
+    ie bowling flower playing cards musical note notes saxophone guitar musical keyboard trumpet vio\
+    lin musical score running shirt with sash tennis ski basketball checkered flag snowboarder runne\
+    r surfer medal trophy horse racing football rugby football swimmer weightlifter golfer motorcycl\
+    e racecar cricket game volleyball field hockey ice hockey ping pong mountain snow camping beach \
+    construction site houses cityscape house abandoned classical building desert island park stadium\
+    \x20house house with garden office post office european post office hospital bank atm hotel love ho\
+    tel convenience store school department store factory lantern japanese castle european castle fl\
+    ag white rosette label badminton bow and arrow amphora rat mouse2 ox water buffalo cow2 tiger2 l\
+    eopard rabbit2 cat2 dragon crocodile whale2 snail snake racehorse ram goat sheep monkey rooster \
+    chicken dog2 pig2 boar elephant octopus shell bug ant bee beetle fish tropical fish blowfish tur\
+    tle hatching chick baby chick hatched chick bird penguin koala poodle dromedary camel camel dolp\
+    hin mouse cow tiger rabbit cat dragon face whale horse monkey face dog pig frog hamster wolf bea\
+    r panda face pig nose feet chipmunk eyes eye ear nose lips tongue point up 2 point down point le\
+    ft point right punch wave ok hand +1 -1 clap open hands crown womans hat eyeglasses necktie shir\
+    t jeans dress kimono bikini womans clothes purse handbag pouch shoe athletic shoe high heel sand\
+    al boot footprints bust in silhouette busts in silhouette boy girl man woman family couple two m\
+    en holding hands two women holding hands cop dancers bride with veil blond haired person man wit\
+    h gua pi mao man with turban older man older woman baby construction worker princess japanese og\
+    re japanese goblin ghost angel alien space invader imp skull tipping hand person guard dancer li\
+    pstick nail care massage haircut barber syringe pill kiss love letter ring gem couplekiss bouque\
+    t couple with heart wedding heartbeat broken heart two hearts sparkling heart heartpulse cupid b\
+    lue heart green heart yellow heart purple heart gift heart revolving hearts heart decoration dia\
+    mond shape with a dot inside bulb anger bomb zzz boom sweat drops droplet dash poop muscle dizzy\
+    \x20speech balloon thought balloon white flower 100 moneybag currency exchange credit card yen $$$e\
+    uro pound money with wings chart seat computer briefcase minidisc floppy disk cd dvd file folder\
+    \x20open file folder page with curl page facing up date calendar card index chart with upwards tren\
+    d chart with downwards trend bar chart clipboard pushpin round pushpin paperclip straight ruler \
+    triangular ruler bookmark tabs ledger notebook notebook with decorative cover closed book book g\
+    reen book blue book orange book books name badge scroll memo telephone receiver pager fax satell\
+    ite loudspeaker mega outbox tray inbox tray package email incoming envelope envelope with arrow \
+    mailbox closed mailbox mailbox with mail mailbox with no mail postbox postal horn newspaper ipho\
+    ne calling vibration mode mobile phone off no mobile phones signal strength camera camera flash \
+    video camera tv radio vhs projectorprayer beads twisted rightwards arrows repeat repeat one arro\
+    ws clockwise arrows counterclockwise low brightness high brightness mute speaker sound loud soun\
+    d battery electric plug mag mag right lock with ink pen closed lock with key key lock unlock bel\
+    l no bell bookmark link radio button back end soon top underage keycap ten capital abcd abcd 123\
+    4 symbols abc fire flashlight wrench hammer nut and bolt hocho gun microscope telescope crystal \
+    ball six pointed star beginner trident black square button white square button red circle blue c\
+    ircle large orange diamond large blue diamond small orange diamond small blue diamond small red \
+    triangle small red triangle down arrow up small arrow down small om symbol dove kaaba mosque syn\
+    agogue menorah bowl of hygieia clock1 clock2 clock3 clock4 clock5 clock6 clock7 clock8 clock9 cl\
+    ock10 clock11 clock12 clock130 clock230 clock330 clock430 clock530 clock630 clock730 clock830 cl\
+    ock930 clock1030 clock1130 clock1230 right speaker candle mantelpiece clock black skull and cros\
+    sbones no piracy hole man in suit detective dark sunglasses spider spider web joystick man danci\
+    ng pen envelope paperclips black pushpin pen paintbrush crayon ok hand sign hand splayed fu spoc\
+    k-hand desktop keyboard and mouse networked computers printer calculator two-button mouse comput\
+    er mouse trackball old pc document frame photo dividers card file box file cabinet wastebasket n\
+    otepad desktop window compression stocks dagger speaking head speech left @!anger right ballot b\
+    ox map mount fuji tokyo tower statue of liberty japan moyai grinning grin joy smiley smile sweat\
+    \x20smile laughing innocent smiling imp wink blush yum relieved heart eyes sunglasses smirk neutral\
+    \x20face expressionless unamused sweat pensive confused confounded kissing kissing heart kissing sm\
+    iling eyes kissing closed eyes stuck out tongue stuck out tongue winking eye stuck out tongue cl\
+    osed eyes disappointed worried angry rage cry persevere triumph disappointed relieved frowning a\
+    nguished fearful weary sleepy tired face grimacing sob open mouth hushed cold sweat scream aston\
+    ished flushed sleeping dizzy face no mouth mask smile cat joy cat smiley cat heart eyes cat smir\
+    k cat kissing cat pouting cat crying cat face scream cat slightly frowning face slight smile ups\
+    ide down roll eyes no good ok woman bow see no evil hear no evil speak no evil raising hand rais\
+    ed hands frowning person pouting face pray rocket helicopter steam locomotive railway car bullet\
+    train side bullettrain front train2 metro light rail station tram train bus oncoming bus trolley\
+    bus busstop minibus ambulance fire engine police car oncoming police car taxi oncoming taxi car \
+    oncoming automobile blue car truck articulated lorry tractor monorail mountain railway suspensio\
+    n railway mountain cableway aerial tramway ship rowboat speedboat traffic light vertical traffic\
+    \x20light construction rotating light triangular flag on post door no entry sign smoking no smoking\
+    \x20put litter in its place do not litter potable water non-potable water bike no bicycles bicyclis\
+    t mountain bicyclist walking no pedestrians children crossing mens womens restroom baby symbol t\
+    oilet wc shower bath bathtub passport control customs baggage claim left luggage girls couch sle\
+    eping bed shopping bags bellhop bed place of worship stop sign shopping cart pagoda hindu temple\
+    \x20hut elevator wireless playground slide wheel ring buoy tools shield oil motorway railway track \
+    motorboat military airplane flight departure flight arrival cruise ship scooter motor scooter ca\
+    noe sled flying saucer skateboard auto rickshaw pickup truck roller skate QEAFARSVAs4S4aaa-SSSVB\
+    dndrssozssorange circle yellow circle green circle purple circle brown circle red square blue sq\
+    uare orange square yellow square green square purple square brown square pinched fingers brown h\
+    eart pinching hand zipper mouth money mouth face face with thermometer nerd thinking face with h\
+    ead bandage robot hugs metal call me hand raised back of hand fist left fist right handshake cro\
+    ssed fingers love you gesture cowboy hat face clown face nauseated face rofl drooling face lying\
+    \x20face facepalm sneezing face raised eyebrow star struck zany face shushing face cursing face han\
+    d over mouth vomiting face exploding head pregnant woman breast feeding palms up together selfie\
+    \x20prince man in tuxedo mrs claus shrug cartwheeling juggling fencer wrestling water polo handball\
+    \x20diving mask wilted flower drum clinking glasses tumbler glass spoon goal net 1st place medal 2n\
+    d place medal 3rd place medal boxing glove martial arts uniform curling stone lacrosse softball \
+    flying disc croissant avocado cucumber bacon potato carrot baguette bread green salad shallow pa\
+    n of food stuffed flatbread egg milk glass peanuts kiwifruit pancakes dumpling fortune cookie ta\
+    keout box chopsticks bowl with spoon cup with straw coconut broccoli pie pretzel cut of meat san\
+    dwich canned food leafy green mango moon cake bagel smiling face with three hearts yawning face \
+    smiling face with tear partying face woozy face hot face cold face ninja disguised face face hol\
+    ding back tears pleading face sari lab coat goggles hiking boot flat shoe crab lion scorpion tur\
+    key unicorn eagle duck bat shark owl fox face butterfly deer gorilla lizard rhinoceros shrimp sq\
+    uid giraffe zebra hedgehog sauropod t-rex cricket kangaroo llama peacock hippopotamus parrot rac\
+    coon lobster mosquito microbe badger swan mammoth dodo sloth otter orangutan skunk flamingo oyst\
+    er beaver bison seal guide dog probing cane red hair white hair bone leg foot tooth superhero su\
+    pervillain safety vest ear with hearing aid motorized wheelchair manual wheelchair mechanical ar\
+    m mechanical leg cheese cupcake salt beverage box garlic onion falafel waffle butter mate ice cu\
+    be bubble tea troll standing person kneeling person deaf person monocle face adult child older a\
+    dult bearded person woman with headscarf sauna person climbing lotus position mage fairy vampire\
+    \x20merperson elf genie zombie brain orange heart billed cap scarf gloves coat socks red envelope f\
+    irecracker jigsaw test tube petri dish dna compass abacus fire extinguisher toolbox bricks magne\
+    t luggage lotion bottle thread yarn safety pin teddy bear broom basket roll of paper soap sponge\
+    \x20receipt nazar amulet NQNRNBballet shoes one piece swimsuit swim brief shorts thong sandal light\
+    \x20blue heart grey heart pink heart drop of blood adhesive bandage stethoscope x ray crutch yo yo \
+    kite parachute boomerang magic wand pinata nesting dolls maracas flute harp shovel ringed planet\
+    \x20chair razor axe diya lamp banjo military helmet accordion long drum coin carpentry saw screwdri\
+    ver ladder hook mirror window plunger sewing needle knot bucket mouse trap toothbrush headstone \
+    placard rock mirror ball identification card low battery hamsa folding hand fan hair pick khanda\
+    \x20fly worm cockroach potted plant wood feather lotus coral empty nest nest with eggs hyacinth jel\
+    lyfish wing leafless tree goose anatomical heart lungs people hugging pregnant man pregnant pers\
+    on person with crown fingerprint moose donkey blueberries bell pepper olive flatbread tamale fon\
+    due teapot pouring liquid beans jar ginger root pea pod root vegetable splatter melting face sal\
+    uting face face with open eyes and hand over mouth face with peeking eye face with diagonal mout\
+    h dotted line face biting lip bubbles shaking face face with bags under eyes hand with index fin\
+    ger and thumb crossed rightwards hand leftwards hand palm down hand palm up hand index pointing \
+    at the viewer heart hands leftwards pushing hand rightwards pushing hand KhenhCupKhaKheDuaReTrut\
+    XuoiLuonBikDuoiSanhGomNhamGiuaSuotXuyenChuoiWaangUlUtItChinTamVuThoGopVaiKepGiengNamQuenSonCoiTr\
+    umVoiVaBomTopNhongLapZokNghiNayXienTrayPhuongNguoiNhoThayVungPaaNapPhiaBayButGitXumCapChauBamGio\
+    ngNgheTroiTromThoiNguaNhauMaauRoVacNhocTruocQuanhVaoNhuiLonVoTronSipLinhLimXuongZeotLiengLeoNgat\
+    DayMaangRetLokKingFaaiMocRaChamDutChemDeoXeThaiTachThienChatGwaaiHoatRuaBuaTetRiuChomSiengNhanLo\
+    iKhuotGiupRuonBitChoThiaKhamWuiGiauHweMuoiNghinChucRuoiXungNhayNhuTrungNgheoRapRotTranhCutMotTro\
+    DucDanhTuouLukDieuGayNgoenMutKhenMeoEotPhinhNipMiengQuaiHoetTheRinNhaiPhomDiuThauHuytNhungThucTr\
+    eUcCaauDapXacHoiPhaoNhanhOcNucRenhChiengMiuThonToacUongBiengVumNgoamKeuNetZaatXapMepMunNganFaatP\
+    eThamSuaTreuMoiViengRiaChumBeNghenhBeoSucBopKiengNhinhEpSuongChutKakTapNaaKamSoekQuatXuaTucTenDu\
+    mHucPhucGiaiKhanKhacLayXamChiaMapKoBemKhuMaaiCayChiuGweChoiTrouLeuVongXangGheChacNaapChoacCopXui\
+    NgoanGuongNgonOangBepGianTuechPaaiNhoiKhoachMachXoZapThungNuotNatThinMonNacSyutRamThuaThepGiucNh\
+    aOamKikThueNgoangRumWeZaaSeuThenGiamToetNhunNhapXepHetNhipSaauSoeWetAmNguiSutNhaoLemNgayChoanNic\
+    hTuongOaiNeuChuyenUaKhoacRonKhayNonXaoCacDyutDoengKwaatPheuThotKhaoXongPhoMomReuEngLocRayKhoanTh\
+    omAakNhangPhanDoeThanhBocMuaNhomLaaiGheoTheoPhacLumRucHaLiemLomToeKhemTotLaaHumGionNheoLanhNgong\
+    ThemDemNguyenHoaiKhuyenXonNhepWokSacGiemNhotChuongTumSaaiNieuVachBetKokDiaGenhNuiTriengZhuaThong\
+    LuongNgachTretXayHocNutVeThoaiNeoVuonConEchDocChinhRanhTrauBonNhieuMuongGioiCanhNhonKechNuaKaaHa\
+    aZukCuoiGukCaaiNhatNoiNitTruRemKinTacGwikNhiHoenNheDepMayChoaiHoanKhiNhenTeoOatGhenhKekTsubiThuo\
+    cGwatNgoeXaiToiViaNocVomVonChonChopVotRungFaiXucTrangTraiPhuonVuaPhoiBauManhPhangTuoiKhuyaRokuKh\
+    o\
+";
+
+/// Ranges of consecutive chars that have a transliteration and the index of the
+/// transliteration of their first char in [`TRANSLITERATIONS`], sorted by code point.
+pub(crate) static TRANSLITERATION_RANGES: &[(char, char, u32)] = &[
+    ('\u{80}', '\u{377}', 0),
+    ('\u{37A}', '\u{37F}', 760),
+    ('\u{384}', '\u{38A}', 766),
+    ('\u{38C}', '\u{38C}', 773),
+    ('\u{38E}', '\u{3A1}', 774),
+    ('\u{3A3}', '\u{52F}', 794),
+    ('\u{531}', '\u{556}', 1191),
+    ('\u{559}', '\u{58A}', 1229),
+    ('\u{58D}', '\u{58F}', 1279),
+    ('\u{591}', '\u{5C7}', 1282),
+    ('\u{5D0}', '\u{5EA}', 1337),
+    ('\u{5EF}', '\u{5F4}', 1364),
+    ('\u{600}', '\u{70D}', 1370),
+    ('\u{70F}', '\u{74A}', 1640),
+    ('\u{74D}', '\u{7B1}', 1700),
+    ('\u{7C0}', '\u{7D0}', 1801),
+    ('\u{7D2}', '\u{7FA}', 1818),
+    ('\u{7FD}', '\u{82D}', 1859),
+    ('\u{830}', '\u{83E}', 1908),
+    ('\u{840}', '\u{85B}', 1923),
+    ('\u{85E}', '\u{85E}', 1951),
+    ('\u{860}', '\u{86A}', 1952),
+    ('\u{870}', '\u{88E}', 1963),
+    ('\u{890}', '\u{891}', 1994),
+    ('\u{89E}', '\u{8AC}', 1996),
+    ('\u{8AE}', '\u{8C8}', 2011),
+    ('\u{8D4}', '\u{983}', 2038),
+    ('\u{985}', '\u{98C}', 2214),
+    ('\u{98F}', '\u{990}', 2222),
+    ('\u{993}', '\u{9A8}', 2224),
+    ('\u{9AA}', '\u{9B0}', 2246),
+    ('\u{9B2}', '\u{9B2}', 2253),
+    ('\u{9B6}', '\u{9B9}', 2254),
+    ('\u{9BC}', '\u{9C4}', 2258),
+    ('\u{9C7}', '\u{9C8}', 2267),
+    ('\u{9CB}', '\u{9CE}', 2269),
+    ('\u{9D7}', '\u{9D7}', 2273),
+    ('\u{9DC}', '\u{9DD}', 2274),
+    ('\u{9DF}', '\u{9E3}', 2276),
+    ('\u{9E6}', '\u{9FE}', 2281),
+    ('\u{A01}', '\u{A03}', 2306),
+    ('\u{A05}', '\u{A0A}', 2309),
+    ('\u{A0F}', '\u{A10}', 2315),
+    ('\u{A13}', '\u{A28}', 2317),
+    ('\u{A2A}', '\u{A30}', 2339),
+    ('\u{A32}', '\u{A33}', 2346),
+    ('\u{A35}', '\u{A36}', 2348),
+    ('\u{A38}', '\u{A39}', 2350),
+    ('\u{A3C}', '\u{A3C}', 2352),
+    ('\u{A3E}', '\u{A42}', 2353),
+    ('\u{A47}', '\u{A48}', 2358),
+    ('\u{A4B}', '\u{A4D}', 2360),
+    ('\u{A51}', '\u{A51}', 2363),
+    ('\u{A59}', '\u{A5C}', 2364),
+    ('\u{A5E}', '\u{A5E}', 2368),
+    ('\u{A66}', '\u{A76}', 2369),
+    ('\u{A81}', '\u{A83}', 2386),
+    ('\u{A85}', '\u{A8D}', 2389),
+    ('\u{A8F}', '\u{A91}', 2398),
+    ('\u{A93}', '\u{AA8}', 2401),
+    ('\u{AAA}', '\u{AB0}', 2423),
+    ('\u{AB2}', '\u{AB3}', 2430),
+    ('\u{AB5}', '\u{AB9}', 2432),
+    ('\u{ABC}', '\u{AC5}', 2437),
+    ('\u{AC7}', '\u{AC9}', 2447),
+    ('\u{ACB}', '\u{ACD}', 2450),
+    ('\u{AD0}', '\u{AD0}', 2453),
+    ('\u{AE0}', '\u{AE3}', 2454),
+    ('\u{AE6}', '\u{AF1}', 2458),
+    ('\u{AF9}', '\u{AFF}', 2470),
+    ('\u{B01}', '\u{B03}', 2477),
+    ('\u{B05}', '\u{B0C}', 2480),
+    ('\u{B0F}', '\u{B10}', 2488),
+    ('\u{B13}', '\u{B28}', 2490),
+    ('\u{B2A}', '\u{B30}', 2512),
+    ('\u{B32}', '\u{B33}', 2519),
+    ('\u{B35}', '\u{B39}', 2521),
+    ('\u{B3C}', '\u{B44}', 2526),
+    ('\u{B47}', '\u{B48}', 2535),
+    ('\u{B4B}', '\u{B4D}', 2537),
+    ('\u{B56}', '\u{B57}', 2540),
+    ('\u{B5C}', '\u{B5D}', 2542),
+    ('\u{B5F}', '\u{B63}', 2544),
+    ('\u{B66}', '\u{B77}', 2549),
+    ('\u{B82}', '\u{B83}', 2567),
+    ('\u{B85}', '\u{B8A}', 2569),
+    ('\u{B8E}', '\u{B90}', 2575),
+    ('\u{B92}', '\u{B95}', 2578),
+    ('\u{B99}', '\u{B9A}', 2582),
+    ('\u{B9C}', '\u{B9C}', 2584),
+    ('\u{B9E}', '\u{B9F}', 2585),
+    ('\u{BA3}', '\u{BA4}', 2587),
+    ('\u{BA8}', '\u{BAA}', 2589),
+    ('\u{BAE}', '\u{BB9}', 2592),
+    ('\u{BBE}', '\u{BC2}', 2604),
+    ('\u{BC6}', '\u{BC8}', 2609),
+    ('\u{BCA}', '\u{BCD}', 2612),
+    ('\u{BD0}', '\u{BD0}', 2616),
+    ('\u{BD7}', '\u{BD7}', 2617),
+    ('\u{BE6}', '\u{BFA}', 2618),
+    ('\u{C00}', '\u{C0C}', 2639),
+    ('\u{C0E}', '\u{C10}', 2652),
+    ('\u{C12}', '\u{C28}', 2655),
+    ('\u{C2A}', '\u{C39}', 2678),
+    ('\u{C3C}', '\u{C44}', 2694),
+    ('\u{C46}', '\u{C48}', 2703),
+    ('\u{C4A}', '\u{C4D}', 2706),
+    ('\u{C55}', '\u{C56}', 2710),
+    ('\u{C58}', '\u{C5A}', 2712),
+    ('\u{C5D}', '\u{C5D}', 2715),
+    ('\u{C60}', '\u{C63}', 2716),
+    ('\u{C66}', '\u{C6F}', 2720),
+    ('\u{C77}', '\u{C8C}', 2730),
+    ('\u{C8E}', '\u{C90}', 2752),
+    ('\u{C92}', '\u{CA8}', 2755),
+    ('\u{CAA}', '\u{CB3}', 2778),
+    ('\u{CB5}', '\u{CB9}', 2788),
+    ('\u{CBC}', '\u{CC4}', 2793),
+    ('\u{CC6}', '\u{CC8}', 2802),
+    ('\u{CCA}', '\u{CCD}', 2805),
+    ('\u{CD5}', '\u{CD6}', 2809),
+    ('\u{CDD}', '\u{CDE}', 2811),
+    ('\u{CE0}', '\u{CE3}', 2813),
+    ('\u{CE6}', '\u{CEF}', 2817),
+    ('\u{CF1}', '\u{CF3}', 2827),
+    ('\u{D00}', '\u{D0C}', 2830),
+    ('\u{D0E}', '\u{D10}', 2843),
+    ('\u{D12}', '\u{D44}', 2846),
+    ('\u{D46}', '\u{D4F}', 2897),
+    ('\u{D54}', '\u{D63}', 2907),
+    ('\u{D66}', '\u{D7F}', 2923),
+    ('\u{D81}', '\u{D83}', 2949),
+    ('\u{D85}', '\u{D96}', 2952),
+    ('\u{D9A}', '\u{DB1}', 2970),
+    ('\u{DB3}', '\u{DBB}', 2994),
+    ('\u{DBD}', '\u{DBD}', 3003),
+    ('\u{DC0}', '\u{DC6}', 3004),
+    ('\u{DCA}', '\u{DCA}', 3011),
+    ('\u{DCF}', '\u{DD4}', 3012),
+    ('\u{DD6}', '\u{DD6}', 3018),
+    ('\u{DD8}', '\u{DDF}', 3019),
+    ('\u{DE6}', '\u{DEF}', 3027),
+    ('\u{DF2}', '\u{DF4}', 3037),
+    ('\u{E01}', '\u{E3A}', 3040),
+    ('\u{E3F}', '\u{E5B}', 3098),
+    ('\u{E81}', '\u{E82}', 3127),
+    ('\u{E84}', '\u{E84}', 3129),
+    ('\u{E86}', '\u{E8A}', 3130),
+    ('\u{E8C}', '\u{EA3}', 3135),
+    ('\u{EA5}', '\u{EA5}', 3159),
+    ('\u{EA7}', '\u{EB9}', 3160),
+    ('\u{EBB}', '\u{EBD}', 3179),
+    ('\u{EC0}', '\u{EC4}', 3182),
+    ('\u{EC6}', '\u{EC6}', 3187),
+    ('\u{EC8}', '\u{ECD}', 3188),
+    ('\u{ED0}', '\u{ED9}', 3194),
+    ('\u{EDC}', '\u{EDF}', 3204),
+    ('\u{F00}', '\u{F47}', 3208),
+    ('\u{F49}', '\u{F6C}', 3280),
+    ('\u{F71}', '\u{F97}', 3316),
+    ('\u{F99}', '\u{FBC}', 3355),
+    ('\u{FBE}', '\u{FCC}', 3391),
+    ('\u{FCE}', '\u{FDA}', 3406),
+    ('\u{1000}', '\u{10C5}', 3419),
+    ('\u{10C7}', '\u{10C7}', 3617),
+    ('\u{10CD}', '\u{10CD}', 3618),
+    ('\u{10D0}', '\u{1248}', 3619),
+    ('\u{124A}', '\u{124D}', 3996),
+    ('\u{1250}', '\u{1256}', 4000),
+    ('\u{1258}', '\u{1258}', 4007),
+    ('\u{125A}', '\u{125D}', 4008),
+    ('\u{1260}', '\u{1288}', 4012),
+    ('\u{128A}', '\u{128D}', 4053),
+    ('\u{1290}', '\u{12B0}', 4057),
+    ('\u{12B2}', '\u{12B5}', 4090),
+    ('\u{12B8}', '\u{12BE}', 4094),
+    ('\u{12C0}', '\u{12C0}', 4101),
+    ('\u{12C2}', '\u{12C5}', 4102),
+    ('\u{12C8}', '\u{12D6}', 4106),
+    ('\u{12D8}', '\u{1310}', 4121),
+    ('\u{1312}', '\u{1315}', 4178),
+    ('\u{1318}', '\u{135A}', 4182),
+    ('\u{135D}', '\u{137C}', 4249),
+    ('\u{1380}', '\u{138F}', 4281),
+    ('\u{13A0}', '\u{13F5}', 4297),
+    ('\u{13F8}', '\u{13FD}', 4383),
+    ('\u{1400}', '\u{169C}', 4389),
+    ('\u{16A0}', '\u{16F8}', 5058),
+    ('\u{1700}', '\u{1714}', 5147),
+    ('\u{171F}', '\u{1736}', 5168),
+    ('\u{1740}', '\u{1753}', 5192),
+    ('\u{1760}', '\u{176C}', 5212),
+    ('\u{176E}', '\u{1770}', 5225),
+    ('\u{1772}', '\u{1773}', 5228),
+    ('\u{1780}', '\u{17DD}', 5230),
+    ('\u{17E0}', '\u{17E9}', 5324),
+    ('\u{17F0}', '\u{17F9}', 5334),
+    ('\u{1800}', '\u{180E}', 5344),
+    ('\u{1810}', '\u{1819}', 5359),
+    ('\u{1820}', '\u{1878}', 5369),
+    ('\u{1880}', '\u{18AA}', 5458),
+    ('\u{18B0}', '\u{18F5}', 5501),
+    ('\u{1901}', '\u{191E}', 5571),
+    ('\u{1920}', '\u{192B}', 5601),
+    ('\u{1930}', '\u{193B}', 5613),
+    ('\u{1940}', '\u{1940}', 5625),
+    ('\u{1944}', '\u{196D}', 5626),
+    ('\u{1982}', '\u{19AB}', 5668),
+    ('\u{19B0}', '\u{19C7}', 5710),
+    ('\u{19D0}', '\u{19DA}', 5734),
+    ('\u{19DE}', '\u{1A14}', 5745),
+    ('\u{1A16}', '\u{1A1B}', 5800),
+    ('\u{1A1E}', '\u{1A4A}', 5806),
+    ('\u{1A4C}', '\u{1A5E}', 5851),
+    ('\u{1A60}', '\u{1A7C}', 5870),
+    ('\u{1A7F}', '\u{1A89}', 5899),
+    ('\u{1A90}', '\u{1A99}', 5910),
+    ('\u{1AA0}', '\u{1AAD}', 5920),
+    ('\u{1AB0}', '\u{1AC0}', 5934),
+    ('\u{1ACC}', '\u{1ACE}', 5951),
+    ('\u{1B00}', '\u{1B4C}', 5954),
+    ('\u{1B50}', '\u{1B7E}', 6031),
+    ('\u{1B80}', '\u{1BBF}', 6078),
+    ('\u{1BC2}', '\u{1BF3}', 6142),
+    ('\u{1BFC}', '\u{1C22}', 6192),
+    ('\u{1C24}', '\u{1C37}', 6231),
+    ('\u{1C3B}', '\u{1C49}', 6251),
+    ('\u{1C4D}', '\u{1C78}', 6266),
+    ('\u{1C7A}', '\u{1C7A}', 6310),
+    ('\u{1C7D}', '\u{1C88}', 6311),
+    ('\u{1C90}', '\u{1CBA}', 6323),
+    ('\u{1CBD}', '\u{1CC7}', 6366),
+    ('\u{1CD0}', '\u{1CF9}', 6377),
+    ('\u{1D00}', '\u{1DF9}', 6419),
+    ('\u{1DFB}', '\u{1F15}', 6669),
+    ('\u{1F18}', '\u{1F1D}', 6952),
+    ('\u{1F20}', '\u{1F45}', 6958),
+    ('\u{1F48}', '\u{1F4D}', 6996),
+    ('\u{1F50}', '\u{1F57}', 7002),
+    ('\u{1F59}', '\u{1F59}', 7010),
+    ('\u{1F5B}', '\u{1F5B}', 7011),
+    ('\u{1F5D}', '\u{1F5D}', 7012),
+    ('\u{1F5F}', '\u{1F7D}', 7013),
+    ('\u{1F80}', '\u{1FB4}', 7044),
+    ('\u{1FB6}', '\u{1FC4}', 7097),
+    ('\u{1FC6}', '\u{1FD3}', 7112),
+    ('\u{1FD6}', '\u{1FDB}', 7126),
+    ('\u{1FDD}', '\u{1FEF}', 7132),
+    ('\u{1FF2}', '\u{1FF4}', 7151),
+    ('\u{1FF6}', '\u{1FFE}', 7154),
+    ('\u{2000}', '\u{2064}', 7163),
+    ('\u{2066}', '\u{208E}', 7264),
+    ('\u{2090}', '\u{209C}', 7305),
+    ('\u{20A0}', '\u{20C0}', 7318),
+    ('\u{20D0}', '\u{20F0}', 7351),
+    ('\u{2100}', '\u{218B}', 7384),
+    ('\u{2190}', '\u{2234}', 7524),
+    ('\u{2236}', '\u{2236}', 7689),
+    ('\u{2238}', '\u{2338}', 7690),
+    ('\u{233A}', '\u{235F}', 7947),
+    ('\u{2361}', '\u{2426}', 7985),
+    ('\u{2440}', '\u{244A}', 8183),
+    ('\u{2460}', '\u{2981}', 8194),
+    ('\u{2983}', '\u{2998}', 9508),
+    ('\u{299A}', '\u{2AF5}', 9530),
+    ('\u{2AF7}', '\u{2B73}', 9878),
+    ('\u{2B76}', '\u{2B95}', 10003),
+    ('\u{2B97}', '\u{2CF3}', 10035),
+    ('\u{2CF9}', '\u{2D25}', 10384),
+    ('\u{2D27}', '\u{2D27}', 10429),
+    ('\u{2D2D}', '\u{2D2D}', 10430),
+    ('\u{2D30}', '\u{2D67}', 10431),
+    ('\u{2D6F}', '\u{2D70}', 10487),
+    ('\u{2D7F}', '\u{2D96}', 10489),
+    ('\u{2DA0}', '\u{2DA6}', 10513),
+    ('\u{2DA8}', '\u{2DAE}', 10520),
+    ('\u{2DB0}', '\u{2DB6}', 10527),
+    ('\u{2DB8}', '\u{2DBE}', 10534),
+    ('\u{2DC0}', '\u{2DC6}', 10541),
+    ('\u{2DC8}', '\u{2DCE}', 10548),
+    ('\u{2DD0}', '\u{2DD6}', 10555),
+    ('\u{2DD8}', '\u{2DDE}', 10562),
+    ('\u{2DE0}', '\u{2E5D}', 10569),
+    ('\u{2E80}', '\u{2E99}', 10695),
+    ('\u{2E9B}', '\u{2EF3}', 10721),
+    ('\u{2F00}', '\u{2FD5}', 10810),
+    ('\u{2FF0}', '\u{2FFB}', 11024),
+    ('\u{3000}', '\u{303F}', 11036),
+    ('\u{3041}', '\u{3096}', 11100),
+    ('\u{3099}', '\u{30FF}', 11186),
+    ('\u{3105}', '\u{312F}', 11289),
+    ('\u{3131}', '\u{318E}', 11332),
+    ('\u{3190}', '\u{31E3}', 11426),
+    ('\u{31F0}', '\u{321E}', 11510),
+    ('\u{3220}', '\u{3401}', 11557),
+    ('\u{3403}', '\u{3426}', 12039),
+    ('\u{3428}', '\u{34AC}', 12075),
+    ('\u{34AE}', '\u{3583}', 12208),
+    ('\u{3585}', '\u{3632}', 12422),
+    ('\u{3634}', '\u{363A}', 12596),
+    ('\u{363C}', '\u{36B2}', 12603),
+    ('\u{36B4}', '\u{36BB}', 12722),
+    ('\u{36BD}', '\u{3800}', 12730),
+    ('\u{3802}', '\u{3894}', 13054),
+    ('\u{3896}', '\u{3950}', 13201),
+    ('\u{3952}', '\u{39DC}', 13388),
+    ('\u{39DE}', '\u{39FE}', 13527),
+    ('\u{3A00}', '\u{3A62}', 13560),
+    ('\u{3A64}', '\u{3A64}', 13659),
+    ('\u{3A66}', '\u{3AB7}', 13660),
+    ('\u{3AB9}', '\u{3AF3}', 13742),
+    ('\u{3AF5}', '\u{3C06}', 13801),
+    ('\u{3C08}', '\u{3C98}', 14075),
+    ('\u{3C9A}', '\u{3CA2}', 14220),
+    ('\u{3CA4}', '\u{3CEF}', 14229),
+    ('\u{3CF1}', '\u{3D09}', 14305),
+    ('\u{3D0B}', '\u{3D58}', 14330),
+    ('\u{3D5A}', '\u{3D9B}', 14408),
+    ('\u{3D9D}', '\u{3DA7}', 14474),
+    ('\u{3DA9}', '\u{3DD0}', 14485),
+    ('\u{3DD2}', '\u{3E66}', 14525),
+    ('\u{3E68}', '\u{3F4F}', 14674),
+    ('\u{3F51}', '\u{3FC7}', 14906),
+    ('\u{3FC9}', '\u{3FF1}', 15025),
+    ('\u{3FF3}', '\u{4097}', 15066),
+    ('\u{4099}', '\u{4280}', 15231),
+    ('\u{4282}', '\u{4294}', 15719),
+    ('\u{4296}', '\u{42B7}', 15738),
+    ('\u{42B9}', '\u{42DC}', 15772),
+    ('\u{42DE}', '\u{436F}', 15808),
+    ('\u{4371}', '\u{4461}', 15954),
+    ('\u{4463}', '\u{4479}', 16195),
+    ('\u{447B}', '\u{44E9}', 16218),
+    ('\u{44EB}', '\u{4616}', 16329),
+    ('\u{4618}', '\u{462E}', 16629),
+    ('\u{4630}', '\u{4756}', 16652),
+    ('\u{4758}', '\u{47F0}', 16947),
+    ('\u{47F2}', '\u{488D}', 17100),
+    ('\u{488F}', '\u{495F}', 17256),
+    ('\u{4961}', '\u{4973}', 17465),
+    ('\u{4975}', '\u{49E2}', 17484),
+    ('\u{49E4}', '\u{49F0}', 17594),
+    ('\u{49F2}', '\u{4A08}', 17607),
+    ('\u{4A0A}', '\u{4A14}', 17630),
+    ('\u{4A16}', '\u{4A26}', 17641),
+    ('\u{4A28}', '\u{4A9F}', 17658),
+    ('\u{4AA1}', '\u{4AEE}', 17778),
+    ('\u{4AF0}', '\u{4B19}', 17856),
+    ('\u{4B1B}', '\u{4B28}', 17898),
+    ('\u{4B2A}', '\u{4B8B}', 17912),
+    ('\u{4B8D}', '\u{4CAA}', 18010),
+    ('\u{4CAC}', '\u{4D77}', 18296),
+    ('\u{4D79}', '\u{4DAE}', 18500),
+    ('\u{4DB0}', '\u{4DB6}', 18554),
+    ('\u{4DB8}', '\u{4DB9}', 18561),
+    ('\u{4DC0}', '\u{9FB0}', 18563),
+    ('\u{9FB2}', '\u{9FB2}', 39540),
+    ('\u{9FB5}', '\u{9FB5}', 39541),
+    ('\u{9FBB}', '\u{9FBB}', 39542),
+    ('\u{9FC1}', '\u{9FC1}', 39543),
+    ('\u{9FC3}', '\u{9FC4}', 39544),
+    ('\u{9FC7}', '\u{9FD0}', 39546),
+    ('\u{9FD4}', '\u{9FD5}', 39556),
+    ('\u{9FEB}', '\u{9FED}', 39558),
+    ('\u{9FF0}', '\u{9FFC}', 39561),
+    ('\u{A000}', '\u{A48C}', 39574),
+    ('\u{A490}', '\u{A4C6}', 40739),
+    ('\u{A4D0}', '\u{A62B}', 40794),
+    ('\u{A640}', '\u{A6F7}', 41142),
+    ('\u{A700}', '\u{A7CA}', 41326),
+    ('\u{A7D0}', '\u{A7D1}', 41529),
+    ('\u{A7D3}', '\u{A7D3}', 41531),
+    ('\u{A7D5}', '\u{A7D9}', 41532),
+    ('\u{A7F2}', '\u{A82B}', 41537),
+    ('\u{A830}', '\u{A839}', 41595),
+    ('\u{A840}', '\u{A877}', 41605),
+    ('\u{A880}', '\u{A8C5}', 41661),
+    ('\u{A8CE}', '\u{A8D9}', 41731),
+    ('\u{A8E0}', '\u{A8F4}', 41743),
+    ('\u{A8F7}', '\u{A953}', 41764),
+    ('\u{A95F}', '\u{A97C}', 41857),
+    ('\u{A980}', '\u{A9CD}', 41887),
+    ('\u{A9CF}', '\u{A9D9}', 41965),
+    ('\u{A9DE}', '\u{A9FE}', 41976),
+    ('\u{AA00}', '\u{AA36}', 42009),
+    ('\u{AA40}', '\u{AA4D}', 42064),
+    ('\u{AA50}', '\u{AA59}', 42078),
+    ('\u{AA5C}', '\u{AAAD}', 42088),
+    ('\u{AAB0}', '\u{AABF}', 42170),
+    ('\u{AAC1}', '\u{AAC1}', 42186),
+    ('\u{AADB}', '\u{AAF6}', 42187),
+    ('\u{AB01}', '\u{AB06}', 42215),
+    ('\u{AB09}', '\u{AB0E}', 42221),
+    ('\u{AB11}', '\u{AB16}', 42227),
+    ('\u{AB20}', '\u{AB26}', 42233),
+    ('\u{AB28}', '\u{AB2E}', 42240),
+    ('\u{AB30}', '\u{AB6B}', 42247),
+    ('\u{AB70}', '\u{ABED}', 42307),
+    ('\u{ABF0}', '\u{ABF9}', 42433),
+    ('\u{AC00}', '\u{D7A3}', 42443),
+    ('\u{D7B0}', '\u{D7C6}', 53615),
+    ('\u{D7CB}', '\u{D7FB}', 53638),
+    ('\u{F900}', '\u{FA0D}', 53687),
+    ('\u{FA0F}', '\u{FA12}', 53957),
+    ('\u{FA15}', '\u{FA1E}', 53961),
+    ('\u{FA20}', '\u{FA22}', 53971),
+    ('\u{FA24}', '\u{FA26}', 53974),
+    ('\u{FA2A}', '\u{FA6D}', 53977),
+    ('\u{FA70}', '\u{FAD9}', 54045),
+    ('\u{FB00}', '\u{FB06}', 54151),
+    ('\u{FB13}', '\u{FB17}', 54158),
+    ('\u{FB1D}', '\u{FB36}', 54163),
+    ('\u{FB38}', '\u{FB3C}', 54189),
+    ('\u{FB3E}', '\u{FB3E}', 54194),
+    ('\u{FB40}', '\u{FB41}', 54195),
+    ('\u{FB43}', '\u{FB44}', 54197),
+    ('\u{FB46}', '\u{FBC2}', 54199),
+    ('\u{FBD3}', '\u{FD8F}', 54324),
+    ('\u{FD92}', '\u{FDC7}', 54769),
+    ('\u{FDCF}', '\u{FDCF}', 54823),
+    ('\u{FDF0}', '\u{FE19}', 54824),
+    ('\u{FE20}', '\u{FE6B}', 54866),
+    ('\u{FE70}', '\u{FE74}', 54942),
+    ('\u{FE76}', '\u{FEFC}', 54947),
+    ('\u{FEFF}', '\u{FEFF}', 55082),
+    ('\u{FF01}', '\u{FFBE}', 55083),
+    ('\u{FFC2}', '\u{FFC7}', 55273),
+    ('\u{FFCA}', '\u{FFCF}', 55279),
+    ('\u{FFD2}', '\u{FFD7}', 55285),
+    ('\u{FFDA}', '\u{FFDC}', 55291),
+    ('\u{FFE0}', '\u{FFE6}', 55294),
+    ('\u{FFE8}', '\u{FFEE}', 55301),
+    ('\u{FFF9}', '\u{FFFE}', 55308),
+    ('\u{10000}', '\u{1000B}', 55314),
+    ('\u{1000D}', '\u{10026}', 55326),
+    ('\u{10028}', '\u{1003A}', 55352),
+    ('\u{1003C}', '\u{1003D}', 55371),
+    ('\u{1003F}', '\u{1003F}', 55373),
+    ('\u{10100}', '\u{10102}', 55374),
+    ('\u{10107}', '\u{10133}', 55377),
+    ('\u{10137}', '\u{1018E}', 55422),
+    ('\u{10190}', '\u{1019C}', 55510),
+    ('\u{101A0}', '\u{101A0}', 55523),
+    ('\u{101D0}', '\u{101FD}', 55524),
+    ('\u{10280}', '\u{1029C}', 55570),
+    ('\u{102A0}', '\u{102D0}', 55599),
+    ('\u{102E0}', '\u{102FB}', 55648),
+    ('\u{10300}', '\u{10323}', 55676),
+    ('\u{1032D}', '\u{1034A}', 55712),
+    ('\u{10350}', '\u{1037A}', 55742),
+    ('\u{10380}', '\u{1039D}', 55785),
+    ('\u{1039F}', '\u{103C3}', 55815),
+    ('\u{103CA}', '\u{103CB}', 55852),
+    ('\u{103CE}', '\u{103D5}', 55854),
+    ('\u{10400}', '\u{1049D}', 55862),
+    ('\u{104A0}', '\u{104A9}', 56020),
+    ('\u{104B0}', '\u{104D3}', 56030),
+    ('\u{104D8}', '\u{104FB}', 56066),
+    ('\u{10500}', '\u{10527}', 56102),
+    ('\u{10530}', '\u{10563}', 56142),
+    ('\u{1056F}', '\u{1057A}', 56194),
+    ('\u{1057C}', '\u{1058A}', 56206),
+    ('\u{1058C}', '\u{10592}', 56221),
+    ('\u{10594}', '\u{10595}', 56228),
+    ('\u{10597}', '\u{105A1}', 56230),
+    ('\u{105A3}', '\u{105B1}', 56241),
+    ('\u{105B3}', '\u{105B9}', 56256),
+    ('\u{105BB}', '\u{105BC}', 56263),
+    ('\u{10780}', '\u{10785}', 56265),
+    ('\u{10787}', '\u{107B0}', 56271),
+    ('\u{107B2}', '\u{107BA}', 56313),
+    ('\u{10800}', '\u{10805}', 56322),
+    ('\u{10808}', '\u{10808}', 56328),
+    ('\u{1080A}', '\u{10835}', 56329),
+    ('\u{10837}', '\u{10838}', 56373),
+    ('\u{1083C}', '\u{1083C}', 56375),
+    ('\u{1083F}', '\u{10855}', 56376),
+    ('\u{10857}', '\u{1089E}', 56399),
+    ('\u{108A7}', '\u{108AF}', 56471),
+    ('\u{108E0}', '\u{108F2}', 56480),
+    ('\u{108F4}', '\u{108F5}', 56499),
+    ('\u{108FB}', '\u{1091B}', 56501),
+    ('\u{1091F}', '\u{10939}', 56534),
+    ('\u{1093F}', '\u{1093F}', 56561),
+    ('\u{10980}', '\u{109B7}', 56562),
+    ('\u{109BC}', '\u{109CF}', 56618),
+    ('\u{109D2}', '\u{10A03}', 56638),
+    ('\u{10A05}', '\u{10A06}', 56688),
+    ('\u{10A0C}', '\u{10A13}', 56690),
+    ('\u{10A15}', '\u{10A17}', 56698),
+    ('\u{10A19}', '\u{10A35}', 56701),
+    ('\u{10A38}', '\u{10A3A}', 56730),
+    ('\u{10A3F}', '\u{10A48}', 56733),
+    ('\u{10A50}', '\u{10A58}', 56743),
+    ('\u{10A60}', '\u{10A9F}', 56752),
+    ('\u{10AC0}', '\u{10AE6}', 56816),
+    ('\u{10AEB}', '\u{10AF6}', 56855),
+    ('\u{10B00}', '\u{10B35}', 56867),
+    ('\u{10B39}', '\u{10B55}', 56921),
+    ('\u{10B58}', '\u{10B72}', 56950),
+    ('\u{10B78}', '\u{10B91}', 56977),
+    ('\u{10B99}', '\u{10B9C}', 57003),
+    ('\u{10BA9}', '\u{10BAF}', 57007),
+    ('\u{10C00}', '\u{10C48}', 57014),
+    ('\u{10C80}', '\u{10CB2}', 57087),
+    ('\u{10CC0}', '\u{10CF2}', 57138),
+    ('\u{10CFA}', '\u{10CFF}', 57189),
+    ('\u{10D01}', '\u{10D21}', 57195),
+    ('\u{10D23}', '\u{10D23}', 57228),
+    ('\u{10D30}', '\u{10D39}', 57229),
+    ('\u{10E60}', '\u{10E7E}', 57239),
+    ('\u{10E80}', '\u{10EA9}', 57270),
+    ('\u{10EAD}', '\u{10EAD}', 57312),
+    ('\u{10EB0}', '\u{10EB1}', 57313),
+    ('\u{10F00}', '\u{10F27}', 57315),
+    ('\u{10F30}', '\u{10F45}', 57355),
+    ('\u{10F51}', '\u{10F59}', 57377),
+    ('\u{10F70}', '\u{10F81}', 57386),
+    ('\u{10F86}', '\u{10F88}', 57404),
+    ('\u{10FB0}', '\u{10FCB}', 57407),
+    ('\u{10FE0}', '\u{10FF6}', 57435),
+    ('\u{11000}', '\u{1104D}', 57458),
+    ('\u{11052}', '\u{1106F}', 57536),
+    ('\u{11071}', '\u{11075}', 57566),
+    ('\u{1107F}', '\u{110C2}', 57571),
+    ('\u{110CD}', '\u{110CD}', 57639),
+    ('\u{110D0}', '\u{110E8}', 57640),
+    ('\u{110F0}', '\u{110F9}', 57665),
+    ('\u{11100}', '\u{11134}', 57675),
+    ('\u{11136}', '\u{11147}', 57728),
+    ('\u{11150}', '\u{11176}', 57746),
+    ('\u{11180}', '\u{111D9}', 57785),
+    ('\u{111DB}', '\u{111DF}', 57875),
+    ('\u{111E1}', '\u{111F4}', 57880),
+    ('\u{11200}', '\u{11211}', 57900),
+    ('\u{11213}', '\u{11241}', 57918),
+    ('\u{11280}', '\u{11286}', 57965),
+    ('\u{11288}', '\u{11288}', 57972),
+    ('\u{1128A}', '\u{1128D}', 57973),
+    ('\u{1128F}', '\u{1129D}', 57977),
+    ('\u{1129F}', '\u{112A9}', 57992),
+    ('\u{112B0}', '\u{112EA}', 58003),
+    ('\u{112F0}', '\u{112F9}', 58062),
+    ('\u{11300}', '\u{11303}', 58072),
+    ('\u{11305}', '\u{1130C}', 58076),
+    ('\u{1130F}', '\u{11310}', 58084),
+    ('\u{11313}', '\u{11328}', 58086),
+    ('\u{1132A}', '\u{11330}', 58108),
+    ('\u{11332}', '\u{11333}', 58115),
+    ('\u{11335}', '\u{11339}', 58117),
+    ('\u{1133B}', '\u{11344}', 58122),
+    ('\u{11347}', '\u{11348}', 58132),
+    ('\u{1134B}', '\u{1134D}', 58134),
+    ('\u{11350}', '\u{11350}', 58137),
+    ('\u{11357}', '\u{11357}', 58138),
+    ('\u{1135D}', '\u{11363}', 58139),
+    ('\u{11366}', '\u{1136C}', 58146),
+    ('\u{11370}', '\u{11374}', 58153),
+    ('\u{11400}', '\u{1145B}', 58158),
+    ('\u{1145D}', '\u{11461}', 58250),
+    ('\u{11480}', '\u{114C7}', 58255),
+    ('\u{114D0}', '\u{114D9}', 58327),
+    ('\u{11580}', '\u{115B5}', 58337),
+    ('\u{115B8}', '\u{115DD}', 58391),
+    ('\u{11600}', '\u{11644}', 58429),
+    ('\u{11650}', '\u{11659}', 58498),
+    ('\u{11660}', '\u{1166C}', 58508),
+    ('\u{11680}', '\u{116B9}', 58521),
+    ('\u{116C0}', '\u{116C9}', 58579),
+    ('\u{11700}', '\u{11711}', 58589),
+    ('\u{11713}', '\u{1171A}', 58607),
+    ('\u{1171D}', '\u{1172B}', 58615),
+    ('\u{11730}', '\u{11746}', 58630),
+    ('\u{11800}', '\u{11838}', 58653),
+    ('\u{1183A}', '\u{1183B}', 58710),
+    ('\u{118A0}', '\u{118F2}', 58712),
+    ('\u{118FF}', '\u{11906}', 58795),
+    ('\u{11909}', '\u{11909}', 58803),
+    ('\u{1190C}', '\u{11913}', 58804),
+    ('\u{11915}', '\u{11916}', 58812),
+    ('\u{11918}', '\u{11935}', 58814),
+    ('\u{11937}', '\u{11938}', 58844),
+    ('\u{1193B}', '\u{1193C}', 58846),
+    ('\u{1193F}', '\u{11946}', 58848),
+    ('\u{11950}', '\u{11959}', 58856),
+    ('\u{119A0}', '\u{119A7}', 58866),
+    ('\u{119AA}', '\u{119D7}', 58874),
+    ('\u{119DA}', '\u{119DF}', 58920),
+    ('\u{119E1}', '\u{119E4}', 58926),
+    ('\u{11A01}', '\u{11A47}', 58930),
+    ('\u{11A51}', '\u{11AA2}', 59001),
+    ('\u{11AB0}', '\u{11AE4}', 59083),
+    ('\u{11AE7}', '\u{11AE7}', 59136),
+    ('\u{11AEA}', '\u{11AEA}', 59137),
+    ('\u{11AF0}', '\u{11AF0}', 59138),
+    ('\u{11AF5}', '\u{11AF5}', 59139),
+    ('\u{11AF8}', '\u{11AF8}', 59140),
+    ('\u{11B00}', '\u{11B09}', 59141),
+    ('\u{11C00}', '\u{11C08}', 59151),
+    ('\u{11C0A}', '\u{11C36}', 59160),
+    ('\u{11C38}', '\u{11C45}', 59205),
+    ('\u{11C50}', '\u{11C6C}', 59219),
+    ('\u{11C70}', '\u{11C8E}', 59248),
+    ('\u{11C92}', '\u{11CA7}', 59279),
+    ('\u{11CA9}', '\u{11CB6}', 59301),
+    ('\u{11D00}', '\u{11D06}', 59315),
+    ('\u{11D08}', '\u{11D09}', 59322),
+    ('\u{11D0B}', '\u{11D36}', 59324),
+    ('\u{11D3A}', '\u{11D3A}', 59368),
+    ('\u{11D3C}', '\u{11D3D}', 59369),
+    ('\u{11D3F}', '\u{11D47}', 59371),
+    ('\u{11D50}', '\u{11D59}', 59380),
+    ('\u{11D60}', '\u{11D65}', 59390),
+    ('\u{11D67}', '\u{11D68}', 59396),
+    ('\u{11D6A}', '\u{11D8E}', 59398),
+    ('\u{11D90}', '\u{11D91}', 59435),
+    ('\u{11D93}', '\u{11D96}', 59437),
+    ('\u{11D98}', '\u{11D98}', 59441),
+    ('\u{11DA0}', '\u{11DA9}', 59442),
+    ('\u{11EE0}', '\u{11EF0}', 59452),
+    ('\u{11EF2}', '\u{11EF8}', 59469),
+    ('\u{11F00}', '\u{11F10}', 59476),
+    ('\u{11F12}', '\u{11F3A}', 59493),
+    ('\u{11F3E}', '\u{11F40}', 59534),
+    ('\u{11F43}', '\u{11F48}', 59537),
+    ('\u{11F4C}', '\u{11F59}', 59543),
+    ('\u{11FB0}', '\u{11FB0}', 59557),
+    ('\u{11FC0}', '\u{11FF1}', 59558),
+    ('\u{11FFF}', '\u{11FFF}', 59608),
+    ('\u{12038}', '\u{12038}', 59609),
+    ('\u{12400}', '\u{1246E}', 59610),
+    ('\u{12470}', '\u{12474}', 59721),
+    ('\u{12F90}', '\u{12FF2}', 59726),
+    ('\u{1300E}', '\u{1300E}', 59825),
+    ('\u{13012}', '\u{13012}', 59826),
+    ('\u{13014}', '\u{13014}', 59827),
+    ('\u{13019}', '\u{13019}', 59828),
+    ('\u{13026}', '\u{13026}', 59829),
+    ('\u{1302B}', '\u{1302B}', 59830),
+    ('\u{13038}', '\u{13038}', 59831),
+    ('\u{1303B}', '\u{1303C}', 59832),
+    ('\u{13052}', '\u{13052}', 59834),
+    ('\u{1305F}', '\u{13060}', 59835),
+    ('\u{13062}', '\u{13064}', 59837),
+    ('\u{13066}', '\u{13066}', 59840),
+    ('\u{13068}', '\u{13068}', 59841),
+    ('\u{13076}', '\u{13079}', 59842),
+    ('\u{1307F}', '\u{13080}', 59846),
+    ('\u{13089}', '\u{13089}', 59848),
+    ('\u{1308B}', '\u{1308B}', 59849),
+    ('\u{1308E}', '\u{1308F}', 59850),
+    ('\u{13091}', '\u{13091}', 59852),
+    ('\u{13093}', '\u{13093}', 59853),
+    ('\u{1309A}', '\u{1309A}', 59854),
+    ('\u{1309D}', '\u{1309D}', 59855),
+    ('\u{130A6}', '\u{130A7}', 59856),
+    ('\u{130AD}', '\u{130AD}', 59858),
+    ('\u{130B8}', '\u{130B8}', 59859),
+    ('\u{130BE}', '\u{130BE}', 59860),
+    ('\u{130C0}', '\u{130C3}', 59861),
+    ('\u{130D7}', '\u{130D7}', 59865),
+    ('\u{130E5}', '\u{130E5}', 59866),
+    ('\u{130EC}', '\u{130EE}', 59867),
+    ('\u{130F9}', '\u{130F9}', 59870),
+    ('\u{13102}', '\u{13103}', 59871),
+    ('\u{1310A}', '\u{1310B}', 59873),
+    ('\u{1310F}', '\u{1310F}', 59875),
+    ('\u{13111}', '\u{13111}', 59876),
+    ('\u{13113}', '\u{13114}', 59877),
+    ('\u{13116}', '\u{13117}', 59879),
+    ('\u{13119}', '\u{1311A}', 59881),
+    ('\u{1311D}', '\u{1311F}', 59883),
+    ('\u{13121}', '\u{13125}', 59886),
+    ('\u{1312A}', '\u{1312B}', 59891),
+    ('\u{1312D}', '\u{1312D}', 59893),
+    ('\u{1312F}', '\u{1312F}', 59894),
+    ('\u{13132}', '\u{13132}', 59895),
+    ('\u{1313F}', '\u{13140}', 59896),
+    ('\u{13142}', '\u{13142}', 59898),
+    ('\u{13150}', '\u{13150}', 59899),
+    ('\u{13152}', '\u{13154}', 59900),
+    ('\u{13158}', '\u{1315A}', 59903),
+    ('\u{1315C}', '\u{1315C}', 59906),
+    ('\u{1315F}', '\u{13161}', 59907),
+    ('\u{13164}', '\u{13164}', 59910),
+    ('\u{13167}', '\u{13168}', 59911),
+    ('\u{1316A}', '\u{1316A}', 59913),
+    ('\u{1316C}', '\u{13171}', 59914),
+    ('\u{13173}', '\u{13173}', 59920),
+    ('\u{13176}', '\u{13177}', 59921),
+    ('\u{1317E}', '\u{1317E}', 59923),
+    ('\u{13180}', '\u{13182}', 59924),
+    ('\u{13184}', '\u{13184}', 59927),
+    ('\u{13188}', '\u{1318C}', 59928),
+    ('\u{1318E}', '\u{1318E}', 59933),
+    ('\u{13190}', '\u{13191}', 59934),
+    ('\u{13193}', '\u{13193}', 59936),
+    ('\u{13195}', '\u{13195}', 59937),
+    ('\u{1319B}', '\u{1319B}', 59938),
+    ('\u{1319D}', '\u{131A0}', 59939),
+    ('\u{131A3}', '\u{131A4}', 59943),
+    ('\u{131AB}', '\u{131AB}', 59945),
+    ('\u{131AD}', '\u{131AD}', 59946),
+    ('\u{131B0}', '\u{131B1}', 59947),
+    ('\u{131B3}', '\u{131B3}', 59949),
+    ('\u{131B5}', '\u{131B5}', 59950),
+    ('\u{131B7}', '\u{131B8}', 59951),
+    ('\u{131BB}', '\u{131BB}', 59953),
+    ('\u{131C5}', '\u{131C5}', 59954),
+    ('\u{131C9}', '\u{131C9}', 59955),
+    ('\u{131CB}', '\u{131CD}', 59956),
+    ('\u{131CF}', '\u{131D0}', 59959),
+    ('\u{131D2}', '\u{131D4}', 59961),
+    ('\u{131D7}', '\u{131D7}', 59964),
+    ('\u{131DB}', '\u{131DC}', 59965),
+    ('\u{131E3}', '\u{131E3}', 59967),
+    ('\u{131E5}', '\u{131E5}', 59968),
+    ('\u{131E9}', '\u{131E9}', 59969),
+    ('\u{131EF}', '\u{131EF}', 59970),
+    ('\u{131F2}', '\u{131F3}', 59971),
+    ('\u{131F6}', '\u{131F7}', 59973),
+    ('\u{131F9}', '\u{131F9}', 59975),
+    ('\u{131FC}', '\u{131FE}', 59976),
+    ('\u{13200}', '\u{13200}', 59979),
+    ('\u{13204}', '\u{13204}', 59980),
+    ('\u{13208}', '\u{13209}', 59981),
+    ('\u{1320B}', '\u{1320F}', 59983),
+    ('\u{13216}', '\u{13217}', 59988),
+    ('\u{13219}', '\u{13219}', 59990),
+    ('\u{1321D}', '\u{1321E}', 59991),
+    ('\u{13250}', '\u{13250}', 59993),
+    ('\u{13254}', '\u{13254}', 59994),
+    ('\u{13257}', '\u{13257}', 59995),
+    ('\u{13265}', '\u{13265}', 59996),
+    ('\u{13269}', '\u{13269}', 59997),
+    ('\u{1326C}', '\u{1326C}', 59998),
+    ('\u{13272}', '\u{13272}', 59999),
+    ('\u{13276}', '\u{13276}', 60000),
+    ('\u{1327A}', '\u{1327B}', 60001),
+    ('\u{1327D}', '\u{1327D}', 60003),
+    ('\u{13283}', '\u{13285}', 60004),
+    ('\u{1328B}', '\u{1328B}', 60007),
+    ('\u{1328F}', '\u{1328F}', 60008),
+    ('\u{13292}', '\u{13292}', 60009),
+    ('\u{13294}', '\u{13294}', 60010),
+    ('\u{13296}', '\u{13297}', 60011),
+    ('\u{1329A}', '\u{1329A}', 60013),
+    ('\u{132A0}', '\u{132A2}', 60014),
+    ('\u{132A4}', '\u{132A4}', 60017),
+    ('\u{132A8}', '\u{132AA}', 60018),
+    ('\u{132AD}', '\u{132AD}', 60021),
+    ('\u{132AF}', '\u{132AF}', 60022),
+    ('\u{132B5}', '\u{132B6}', 60023),
+    ('\u{132B8}', '\u{132BA}', 60025),
+    ('\u{132BD}', '\u{132BD}', 60028),
+    ('\u{132C0}', '\u{132C2}', 60029),
+    ('\u{132C9}', '\u{132C9}', 60032),
+    ('\u{132D1}', '\u{132D1}', 60033),
+    ('\u{132D4}', '\u{132D4}', 60034),
+    ('\u{132D7}', '\u{132D7}', 60035),
+    ('\u{132D9}', '\u{132DE}', 60036),
+    ('\u{132E3}', '\u{132E3}', 60042),
+    ('\u{132E7}', '\u{132E9}', 60043),
+    ('\u{132EB}', '\u{132ED}', 60046),
+    ('\u{132EF}', '\u{132EF}', 60049),
+    ('\u{132F2}', '\u{132F2}', 60050),
+    ('\u{132F4}', '\u{132F5}', 60051),
+    ('\u{132F7}', '\u{132FA}', 60053),
+    ('\u{132FD}', '\u{13305}', 60057),
+    ('\u{13309}', '\u{13309}', 60066),
+    ('\u{1330D}', '\u{1330D}', 60067),
+    ('\u{13312}', '\u{13312}', 60068),
+    ('\u{13314}', '\u{13315}', 60069),
+    ('\u{13317}', '\u{13319}', 60071),
+    ('\u{1331D}', '\u{1331F}', 60074),
+    ('\u{13321}', '\u{13322}', 60077),
+    ('\u{13324}', '\u{13325}', 60079),
+    ('\u{13328}', '\u{13329}', 60081),
+    ('\u{1332B}', '\u{1332B}', 60083),
+    ('\u{13330}', '\u{13330}', 60084),
+    ('\u{13333}', '\u{13333}', 60085),
+    ('\u{13338}', '\u{13338}', 60086),
+    ('\u{1333E}', '\u{1333F}', 60087),
+    ('\u{13341}', '\u{13341}', 60089),
+    ('\u{13343}', '\u{13345}', 60090),
+    ('\u{13349}', '\u{1334B}', 60093),
+    ('\u{1334D}', '\u{1334D}', 60096),
+    ('\u{1334F}', '\u{1334F}', 60097),
+    ('\u{13351}', '\u{13351}', 60098),
+    ('\u{13355}', '\u{13356}', 60099),
+    ('\u{13358}', '\u{13359}', 60101),
+    ('\u{1335B}', '\u{1335B}', 60103),
+    ('\u{1335D}', '\u{1335D}', 60104),
+    ('\u{1336C}', '\u{1336C}', 60105),
+    ('\u{1336E}', '\u{13372}', 60106),
+    ('\u{1337C}', '\u{1337C}', 60111),
+    ('\u{1337F}', '\u{1337F}', 60112),
+    ('\u{13381}', '\u{13381}', 60113),
+    ('\u{13385}', '\u{13386}', 60114),
+    ('\u{13394}', '\u{13394}', 60116),
+    ('\u{13397}', '\u{13397}', 60117),
+    ('\u{13399}', '\u{13399}', 60118),
+    ('\u{1339B}', '\u{1339B}', 60119),
+    ('\u{1339D}', '\u{1339D}', 60120),
+    ('\u{1339F}', '\u{1339F}', 60121),
+    ('\u{133A1}', '\u{133A1}', 60122),
+    ('\u{133A3}', '\u{133A4}', 60123),
+    ('\u{133A9}', '\u{133A9}', 60125),
+    ('\u{133B0}', '\u{133B1}', 60126),
+    ('\u{133B8}', '\u{133B8}', 60128),
+    ('\u{133BA}', '\u{133BA}', 60129),
+    ('\u{133BC}', '\u{133BC}', 60130),
+    ('\u{133BF}', '\u{133BF}', 60131),
+    ('\u{133C3}', '\u{133C3}', 60132),
+    ('\u{133C7}', '\u{133C7}', 60133),
+    ('\u{133CA}', '\u{133CA}', 60134),
+    ('\u{133CC}', '\u{133CC}', 60135),
+    ('\u{133CE}', '\u{133CF}', 60136),
+    ('\u{133D9}', '\u{133D9}', 60138),
+    ('\u{133DB}', '\u{133DB}', 60139),
+    ('\u{133DE}', '\u{133DE}', 60140),
+    ('\u{133E0}', '\u{133E1}', 60141),
+    ('\u{133E3}', '\u{133E3}', 60143),
+    ('\u{133ED}', '\u{133ED}', 60144),
+    ('\u{133F2}', '\u{133F2}', 60145),
+    ('\u{133F6}', '\u{133F6}', 60146),
+    ('\u{1340D}', '\u{1340D}', 60147),
+    ('\u{13411}', '\u{13411}', 60148),
+    ('\u{13416}', '\u{13416}', 60149),
+    ('\u{13419}', '\u{13419}', 60150),
+    ('\u{1341B}', '\u{1341B}', 60151),
+    ('\u{1341D}', '\u{1341D}', 60152),
+    ('\u{1341F}', '\u{1341F}', 60153),
+    ('\u{13422}', '\u{13423}', 60154),
+    ('\u{13429}', '\u{1342A}', 60156),
+    ('\u{1342C}', '\u{1342C}', 60158),
+    ('\u{1342F}', '\u{1342F}', 60159),
+    ('\u{13431}', '\u{13431}', 60160),
+    ('\u{13436}', '\u{13438}', 60161),
+    ('\u{16A40}', '\u{16A5E}', 60164),
+    ('\u{16A60}', '\u{16A69}', 60195),
+    ('\u{16A6E}', '\u{16ABE}', 60205),
+    ('\u{16AC0}', '\u{16AC9}', 60286),
+    ('\u{16AD0}', '\u{16AED}', 60296),
+    ('\u{16AF0}', '\u{16AF5}', 60326),
+    ('\u{16B00}', '\u{16B45}', 60332),
+    ('\u{16B50}', '\u{16B59}', 60402),
+    ('\u{16B5B}', '\u{16B61}', 60412),
+    ('\u{16B63}', '\u{16B77}', 60419),
+    ('\u{16B7D}', '\u{16B8F}', 60440),
+    ('\u{16E40}', '\u{16E9A}', 60459),
+    ('\u{16F00}', '\u{16F42}', 60550),
+    ('\u{16F45}', '\u{16F4A}', 60617),
+    ('\u{16F4F}', '\u{16F87}', 60623),
+    ('\u{16F8F}', '\u{16F9F}', 60680),
+    ('\u{16FE0}', '\u{16FE3}', 60697),
+    ('\u{17000}', '\u{187F7}', 60701),
+    ('\u{18AF3}', '\u{18CD5}', 66837),
+    ('\u{18D00}', '\u{18D08}', 67320),
+    ('\u{1B000}', '\u{1B122}', 67329),
+    ('\u{1B132}', '\u{1B132}', 67620),
+    ('\u{1B150}', '\u{1B152}', 67621),
+    ('\u{1B155}', '\u{1B155}', 67624),
+    ('\u{1B164}', '\u{1B167}', 67625),
+    ('\u{1B170}', '\u{1B2FB}', 67629),
+    ('\u{1BC00}', '\u{1BC6A}', 68025),
+    ('\u{1BC70}', '\u{1BC7C}', 68132),
+    ('\u{1BC80}', '\u{1BC88}', 68145),
+    ('\u{1BC90}', '\u{1BC99}', 68154),
+    ('\u{1BC9C}', '\u{1BCA3}', 68164),
+    ('\u{1CCD6}', '\u{1CCF9}', 68172),
+    ('\u{1CF50}', '\u{1CFC3}', 68208),
+    ('\u{1D000}', '\u{1D0F5}', 68324),
+    ('\u{1D100}', '\u{1D107}', 68570),
+    ('\u{1D109}', '\u{1D126}', 68578),
+    ('\u{1D129}', '\u{1D1EA}', 68608),
+    ('\u{1D200}', '\u{1D245}', 68802),
+    ('\u{1D2C0}', '\u{1D2D3}', 68872),
+    ('\u{1D2E0}', '\u{1D2F3}', 68892),
+    ('\u{1D300}', '\u{1D356}', 68912),
+    ('\u{1D360}', '\u{1D378}', 68999),
+    ('\u{1D400}', '\u{1D454}', 69024),
+    ('\u{1D456}', '\u{1D49C}', 69109),
+    ('\u{1D49E}', '\u{1D49F}', 69180),
+    ('\u{1D4A2}', '\u{1D4A2}', 69182),
+    ('\u{1D4A5}', '\u{1D4A6}', 69183),
+    ('\u{1D4A9}', '\u{1D4AC}', 69185),
+    ('\u{1D4AE}', '\u{1D4B9}', 69189),
+    ('\u{1D4BB}', '\u{1D4BB}', 69201),
+    ('\u{1D4BD}', '\u{1D4C3}', 69202),
+    ('\u{1D4C5}', '\u{1D505}', 69209),
+    ('\u{1D507}', '\u{1D50A}', 69274),
+    ('\u{1D50D}', '\u{1D514}', 69278),
+    ('\u{1D516}', '\u{1D51C}', 69286),
+    ('\u{1D51E}', '\u{1D539}', 69293),
+    ('\u{1D53B}', '\u{1D53E}', 69321),
+    ('\u{1D540}', '\u{1D544}', 69325),
+    ('\u{1D546}', '\u{1D546}', 69330),
+    ('\u{1D54A}', '\u{1D550}', 69331),
+    ('\u{1D552}', '\u{1D6A5}', 69338),
+    ('\u{1D6A8}', '\u{1D7CB}', 69678),
+    ('\u{1D7CE}', '\u{1D7FF}', 69970),
+    ('\u{1DA00}', '\u{1DA36}', 70020),
+    ('\u{1DA3B}', '\u{1DA6C}', 70075),
+    ('\u{1DA75}', '\u{1DA75}', 70125),
+    ('\u{1DA84}', '\u{1DA84}', 70126),
+    ('\u{1DA87}', '\u{1DA8B}', 70127),
+    ('\u{1DA9B}', '\u{1DA9F}', 70132),
+    ('\u{1DAA1}', '\u{1DAAF}', 70137),
+    ('\u{1DF00}', '\u{1DF1E}', 70152),
+    ('\u{1DF25}', '\u{1DF2A}', 70183),
+    ('\u{1E000}', '\u{1E006}', 70189),
+    ('\u{1E008}', '\u{1E018}', 70196),
+    ('\u{1E01B}', '\u{1E021}', 70213),
+    ('\u{1E023}', '\u{1E024}', 70220),
+    ('\u{1E026}', '\u{1E02A}', 70222),
+    ('\u{1E030}', '\u{1E06D}', 70227),
+    ('\u{1E08F}', '\u{1E08F}', 70289),
+    ('\u{1E100}', '\u{1E12C}', 70290),
+    ('\u{1E130}', '\u{1E136}', 70335),
+    ('\u{1E13C}', '\u{1E13C}', 70342),
+    ('\u{1E140}', '\u{1E149}', 70343),
+    ('\u{1E14E}', '\u{1E14F}', 70353),
+    ('\u{1E290}', '\u{1E2AD}', 70355),
+    ('\u{1E2C0}', '\u{1E2EB}', 70385),
+    ('\u{1E2F0}', '\u{1E2F9}', 70429),
+    ('\u{1E2FF}', '\u{1E2FF}', 70439),
+    ('\u{1E4D0}', '\u{1E4EA}', 70440),
+    ('\u{1E4F0}', '\u{1E4F9}', 70467),
+    ('\u{1E7E0}', '\u{1E7E6}', 70477),
+    ('\u{1E7E8}', '\u{1E7EB}', 70484),
+    ('\u{1E7ED}', '\u{1E7EE}', 70488),
+    ('\u{1E7F0}', '\u{1E7FE}', 70490),
+    ('\u{1E800}', '\u{1E8C4}', 70505),
+    ('\u{1E8C7}', '\u{1E8D6}', 70702),
+    ('\u{1E900}', '\u{1E94B}', 70718),
+    ('\u{1E950}', '\u{1E959}', 70794),
+    ('\u{1E95E}', '\u{1E95F}', 70804),
+    ('\u{1EC71}', '\u{1ECB4}', 70806),
+    ('\u{1ED01}', '\u{1ED3D}', 70874),
+    ('\u{1EE00}', '\u{1EE03}', 70935),
+    ('\u{1EE05}', '\u{1EE1F}', 70939),
+    ('\u{1EE21}', '\u{1EE22}', 70966),
+    ('\u{1EE24}', '\u{1EE24}', 70968),
+    ('\u{1EE27}', '\u{1EE27}', 70969),
+    ('\u{1EE29}', '\u{1EE32}', 70970),
+    ('\u{1EE34}', '\u{1EE37}', 70980),
+    ('\u{1EE39}', '\u{1EE39}', 70984),
+    ('\u{1EE3B}', '\u{1EE3B}', 70985),
+    ('\u{1EE42}', '\u{1EE42}', 70986),
+    ('\u{1EE47}', '\u{1EE47}', 70987),
+    ('\u{1EE49}', '\u{1EE49}', 70988),
+    ('\u{1EE4B}', '\u{1EE4B}', 70989),
+    ('\u{1EE4D}', '\u{1EE4F}', 70990),
+    ('\u{1EE51}', '\u{1EE52}', 70993),
+    ('\u{1EE54}', '\u{1EE54}', 70995),
+    ('\u{1EE57}', '\u{1EE57}', 70996),
+    ('\u{1EE59}', '\u{1EE59}', 70997),
+    ('\u{1EE5B}', '\u{1EE5B}', 70998),
+    ('\u{1EE5D}', '\u{1EE5D}', 70999),
+    ('\u{1EE5F}', '\u{1EE5F}', 71000),
+    ('\u{1EE61}', '\u{1EE62}', 71001),
+    ('\u{1EE64}', '\u{1EE64}', 71003),
+    ('\u{1EE67}', '\u{1EE6A}', 71004),
+    ('\u{1EE6C}', '\u{1EE72}', 71008),
+    ('\u{1EE74}', '\u{1EE77}', 71015),
+    ('\u{1EE79}', '\u{1EE7C}', 71019),
+    ('\u{1EE7E}', '\u{1EE7E}', 71023),
+    ('\u{1EE80}', '\u{1EE89}', 71024),
+    ('\u{1EE8B}', '\u{1EE9B}', 71034),
+    ('\u{1EEA1}', '\u{1EEA3}', 71051),
+    ('\u{1EEA5}', '\u{1EEA9}', 71054),
+    ('\u{1EEAB}', '\u{1EEBB}', 71059),
+    ('\u{1EEF0}', '\u{1EEF1}', 71076),
+    ('\u{1F000}', '\u{1F02B}', 71078),
+    ('\u{1F030}', '\u{1F093}', 71122),
+    ('\u{1F0A0}', '\u{1F0AE}', 71222),
+    ('\u{1F0B1}', '\u{1F0BF}', 71237),
+    ('\u{1F0C1}', '\u{1F0CF}', 71252),
+    ('\u{1F0D1}', '\u{1F0F5}', 71267),
+    ('\u{1F100}', '\u{1F1AD}', 71304),
+    ('\u{1F1E6}', '\u{1F202}', 71478),
+    ('\u{1F210}', '\u{1F23B}', 71507),
+    ('\u{1F240}', '\u{1F248}', 71551),
+    ('\u{1F250}', '\u{1F251}', 71560),
+    ('\u{1F260}', '\u{1F265}', 71562),
+    ('\u{1F300}', '\u{1F6D7}', 71568),
+    ('\u{1F6DC}', '\u{1F6EC}', 72552),
+    ('\u{1F6F0}', '\u{1F6FC}', 72569),
+    ('\u{1F700}', '\u{1F776}', 72582),
+    ('\u{1F77B}', '\u{1F7D9}', 72701),
+    ('\u{1F7E0}', '\u{1F7EB}', 72796),
+    ('\u{1F7F0}', '\u{1F7F0}', 72808),
+    ('\u{1F800}', '\u{1F80B}', 72809),
+    ('\u{1F810}', '\u{1F847}', 72821),
+    ('\u{1F850}', '\u{1F859}', 72877),
+    ('\u{1F860}', '\u{1F887}', 72887),
+    ('\u{1F890}', '\u{1F8AD}', 72927),
+    ('\u{1F8B0}', '\u{1F8B1}', 72957),
+    ('\u{1F900}', '\u{1F9B0}', 72959),
+    ('\u{1F9B3}', '\u{1FA53}', 73136),
+    ('\u{1FA60}', '\u{1FA6D}', 73297),
+    ('\u{1FA70}', '\u{1FA7C}', 73311),
+    ('\u{1FA80}', '\u{1FA89}', 73324),
+    ('\u{1FA8F}', '\u{1FAC6}', 73334),
+    ('\u{1FACE}', '\u{1FADC}', 73390),
+    ('\u{1FADF}', '\u{1FAE9}', 73405),
+    ('\u{1FAF0}', '\u{1FAF8}', 73416),
+    ('\u{1FB00}', '\u{1FB92}', 73425),
+    ('\u{1FB94}', '\u{1FBCA}', 73572),
+    ('\u{1FBF0}', '\u{1FBF9}', 73627),
+    ('\u{20000}', '\u{20001}', 73637),
+    ('\u{20003}', '\u{20003}', 73639),
+    ('\u{20005}', '\u{20005}', 73640),
+    ('\u{20009}', '\u{2000A}', 73641),
+    ('\u{2000D}', '\u{2000D}', 73643),
+    ('\u{20013}', '\u{20014}', 73644),
+    ('\u{20016}', '\u{20017}', 73646),
+    ('\u{2001B}', '\u{2001B}', 73648),
+    ('\u{2001D}', '\u{2001D}', 73649),
+    ('\u{20021}', '\u{20022}', 73650),
+    ('\u{20026}', '\u{20028}', 73652),
+    ('\u{2002A}', '\u{2002B}', 73655),
+    ('\u{20031}', '\u{20034}', 73657),
+    ('\u{20037}', '\u{20039}', 73661),
+    ('\u{2003C}', '\u{20042}', 73664),
+    ('\u{20044}', '\u{20044}', 73671),
+    ('\u{20046}', '\u{20046}', 73672),
+    ('\u{20049}', '\u{20049}', 73673),
+    ('\u{2004E}', '\u{2004E}', 73674),
+    ('\u{20051}', '\u{20051}', 73675),
+    ('\u{20054}', '\u{20054}', 73676),
+    ('\u{20057}', '\u{20057}', 73677),
+    ('\u{20059}', '\u{2005A}', 73678),
+    ('\u{2005D}', '\u{20060}', 73680),
+    ('\u{20065}', '\u{20065}', 73684),
+    ('\u{20068}', '\u{20068}', 73685),
+    ('\u{2006A}', '\u{2006A}', 73686),
+    ('\u{2006D}', '\u{2006D}', 73687),
+    ('\u{20075}', '\u{20075}', 73688),
+    ('\u{20077}', '\u{2007B}', 73689),
+    ('\u{20084}', '\u{20084}', 73694),
+    ('\u{20086}', '\u{20088}', 73695),
+    ('\u{2008E}', '\u{2008E}', 73698),
+    ('\u{20091}', '\u{20091}', 73699),
+    ('\u{20094}', '\u{20094}', 73700),
+    ('\u{2009D}', '\u{2009D}', 73701),
+    ('\u{2009F}', '\u{2009F}', 73702),
+    ('\u{200A2}', '\u{200A2}', 73703),
+    ('\u{200A4}', '\u{200A5}', 73704),
+    ('\u{200A7}', '\u{200A7}', 73706),
+    ('\u{200AA}', '\u{200AB}', 73707),
+    ('\u{200B8}', '\u{200B8}', 73709),
+    ('\u{200C5}', '\u{200C5}', 73710),
+    ('\u{200CA}', '\u{200CA}', 73711),
+    ('\u{200CC}', '\u{200CD}', 73712),
+    ('\u{200D1}', '\u{200D1}', 73714),
+    ('\u{200D3}', '\u{200D4}', 73715),
+    ('\u{200D6}', '\u{200D7}', 73717),
+    ('\u{200DD}', '\u{200DD}', 73719),
+    ('\u{200E3}', '\u{200E3}', 73720),
+    ('\u{200E9}', '\u{200E9}', 73721),
+    ('\u{200EB}', '\u{200EB}', 73722),
+    ('\u{200EE}', '\u{200EF}', 73723),
+    ('\u{200F6}', '\u{200F7}', 73725),
+    ('\u{200FA}', '\u{200FA}', 73727),
+    ('\u{20100}', '\u{20100}', 73728),
+    ('\u{20105}', '\u{20105}', 73729),
+    ('\u{20109}', '\u{20109}', 73730),
+    ('\u{2010C}', '\u{2010D}', 73731),
+    ('\u{2010F}', '\u{20112}', 73733),
+    ('\u{20116}', '\u{20116}', 73737),
+    ('\u{20118}', '\u{20118}', 73738),
+    ('\u{2011A}', '\u{2011A}', 73739),
+    ('\u{2011C}', '\u{2011C}', 73740),
+    ('\u{2011E}', '\u{20120}', 73741),
+    ('\u{20123}', '\u{20123}', 73744),
+    ('\u{20126}', '\u{20127}', 73745),
+    ('\u{20129}', '\u{20129}', 73747),
+    ('\u{2012C}', '\u{2012C}', 73748),
+    ('\u{2012E}', '\u{2012F}', 73749),
+    ('\u{20131}', '\u{20131}', 73751),
+    ('\u{20133}', '\u{20133}', 73752),
+    ('\u{20136}', '\u{20136}', 73753),
+    ('\u{2013A}', '\u{2013D}', 73754),
+    ('\u{2013F}', '\u{2013F}', 73758),
+    ('\u{20142}', '\u{20142}', 73759),
+    ('\u{2014C}', '\u{2014E}', 73760),
+    ('\u{20150}', '\u{20150}', 73763),
+    ('\u{20152}', '\u{20152}', 73764),
+    ('\u{20157}', '\u{20157}', 73765),
+    ('\u{20159}', '\u{2015A}', 73766),
+    ('\u{2015C}', '\u{2015C}', 73768),
+    ('\u{20164}', '\u{20164}', 73769),
+    ('\u{2016C}', '\u{2016C}', 73770),
+    ('\u{20171}', '\u{20171}', 73771),
+    ('\u{20173}', '\u{20173}', 73772),
+    ('\u{20179}', '\u{20179}', 73773),
+    ('\u{2017B}', '\u{2017C}', 73774),
+    ('\u{2018C}', '\u{2018D}', 73776),
+    ('\u{20190}', '\u{20190}', 73778),
+    ('\u{20199}', '\u{20199}', 73779),
+    ('\u{2019B}', '\u{2019B}', 73780),
+    ('\u{2019F}', '\u{2019F}', 73781),
+    ('\u{201A3}', '\u{201A3}', 73782),
+    ('\u{201A9}', '\u{201A9}', 73783),
+    ('\u{201AB}', '\u{201AB}', 73784),
+    ('\u{201AE}', '\u{201AE}', 73785),
+    ('\u{201B1}', '\u{201B3}', 73786),
+    ('\u{201B5}', '\u{201B6}', 73789),
+    ('\u{201BF}', '\u{201BF}', 73791),
+    ('\u{201C1}', '\u{201C1}', 73792),
+    ('\u{201C6}', '\u{201C6}', 73793),
+    ('\u{201CB}', '\u{201CB}', 73794),
+    ('\u{201CD}', '\u{201CD}', 73795),
+    ('\u{201CF}', '\u{201D0}', 73796),
+    ('\u{201D2}', '\u{201D2}', 73798),
+    ('\u{201D4}', '\u{201D8}', 73799),
+    ('\u{201EF}', '\u{201EF}', 73804),
+    ('\u{201F1}', '\u{201F3}', 73805),
+    ('\u{201F5}', '\u{201F5}', 73808),
+    ('\u{201F7}', '\u{201F7}', 73809),
+    ('\u{201F9}', '\u{201F9}', 73810),
+    ('\u{201FC}', '\u{201FD}', 73811),
+    ('\u{20201}', '\u{20201}', 73813),
+    ('\u{20204}', '\u{20205}', 73814),
+    ('\u{2020A}', '\u{2020C}', 73816),
+    ('\u{20214}', '\u{20214}', 73819),
+    ('\u{20224}', '\u{20225}', 73820),
+    ('\u{20228}', '\u{20228}', 73822),
+    ('\u{2022C}', '\u{2022D}', 73823),
+    ('\u{20230}', '\u{20231}', 73825),
+    ('\u{20235}', '\u{20236}', 73827),
+    ('\u{20238}', '\u{2023A}', 73829),
+    ('\u{20242}', '\u{20242}', 73832),
+    ('\u{20257}', '\u{20257}', 73833),
+    ('\u{2025B}', '\u{20260}', 73834),
+    ('\u{20262}', '\u{20264}', 73840),
+    ('\u{20267}', '\u{20267}', 73843),
+    ('\u{20269}', '\u{2026A}', 73844),
+    ('\u{2026C}', '\u{2026C}', 73846),
+    ('\u{20271}', '\u{20271}', 73847),
+    ('\u{20274}', '\u{20275}', 73848),
+    ('\u{20289}', '\u{20289}', 73850),
+    ('\u{2028C}', '\u{2028C}', 73851),
+    ('\u{2028E}', '\u{2028F}', 73852),
+    ('\u{20292}', '\u{20292}', 73854),
+    ('\u{20294}', '\u{20294}', 73855),
+    ('\u{20299}', '\u{202A1}', 73856),
+    ('\u{202A3}', '\u{202A7}', 73865),
+    ('\u{202A9}', '\u{202AD}', 73870),
+    ('\u{202B0}', '\u{202B0}', 73875),
+    ('\u{202B2}', '\u{202B2}', 73876),
+    ('\u{202B6}', '\u{202B7}', 73877),
+    ('\u{202BE}', '\u{202C0}', 73879),
+    ('\u{202C6}', '\u{202C6}', 73882),
+    ('\u{202D6}', '\u{202D7}', 73883),
+    ('\u{202DC}', '\u{202DF}', 73885),
+    ('\u{202E1}', '\u{202E2}', 73889),
+    ('\u{202E4}', '\u{202E7}', 73891),
+    ('\u{202E9}', '\u{202E9}', 73895),
+    ('\u{202EC}', '\u{202ED}', 73896),
+    ('\u{202EF}', '\u{202EF}', 73898),
+    ('\u{202F2}', '\u{202F2}', 73899),
+    ('\u{202F5}', '\u{202F7}', 73900),
+    ('\u{202FA}', '\u{202FB}', 73903),
+    ('\u{20302}', '\u{20302}', 73905),
+    ('\u{2030A}', '\u{2030A}', 73906),
+    ('\u{20315}', '\u{20316}', 73907),
+    ('\u{2031A}', '\u{2031A}', 73909),
+    ('\u{2031E}', '\u{20320}', 73910),
+    ('\u{20325}', '\u{20328}', 73913),
+    ('\u{2032A}', '\u{2032A}', 73917),
+    ('\u{2032D}', '\u{20332}', 73918),
+    ('\u{20334}', '\u{20334}', 73924),
+    ('\u{2033D}', '\u{2033D}', 73925),
+    ('\u{20341}', '\u{20341}', 73926),
+    ('\u{20343}', '\u{20347}', 73927),
+    ('\u{20357}', '\u{20357}', 73932),
+    ('\u{2035A}', '\u{2035C}', 73933),
+    ('\u{20363}', '\u{20366}', 73936),
+    ('\u{20368}', '\u{2036B}', 73940),
+    ('\u{2036D}', '\u{2036D}', 73944),
+    ('\u{2036F}', '\u{2036F}', 73945),
+    ('\u{20372}', '\u{20373}', 73946),
+    ('\u{20375}', '\u{20375}', 73948),
+    ('\u{20379}', '\u{20379}', 73949),
+    ('\u{2037D}', '\u{2037E}', 73950),
+    ('\u{20380}', '\u{20380}', 73952),
+    ('\u{2038A}', '\u{2038A}', 73953),
+    ('\u{20399}', '\u{20399}', 73954),
+    ('\u{203A0}', '\u{203A0}', 73955),
+    ('\u{203A7}', '\u{203AC}', 73956),
+    ('\u{203AE}', '\u{203AE}', 73962),
+    ('\u{203B0}', '\u{203B0}', 73963),
+    ('\u{203B3}', '\u{203B8}', 73964),
+    ('\u{203BB}', '\u{203BB}', 73970),
+    ('\u{203BF}', '\u{203C0}', 73971),
+    ('\u{203C3}', '\u{203C3}', 73973),
+    ('\u{203C7}', '\u{203C9}', 73974),
+    ('\u{203CB}', '\u{203CB}', 73977),
+    ('\u{203D3}', '\u{203D3}', 73978),
+    ('\u{203D5}', '\u{203D6}', 73979),
+    ('\u{203DA}', '\u{203DB}', 73981),
+    ('\u{203DF}', '\u{203E2}', 73983),
+    ('\u{203E4}', '\u{203E9}', 73987),
+    ('\u{203EB}', '\u{203EB}', 73993),
+    ('\u{203ED}', '\u{203EE}', 73994),
+    ('\u{203F0}', '\u{203F0}', 73996),
+    ('\u{203F2}', '\u{203F3}', 73997),
+    ('\u{203F5}', '\u{203F5}', 73999),
+    ('\u{203FC}', '\u{203FC}', 74000),
+    ('\u{203FF}', '\u{20401}', 74001),
+    ('\u{20405}', '\u{20405}', 74004),
+    ('\u{20408}', '\u{2040D}', 74005),
+    ('\u{20411}', '\u{20411}', 74011),
+    ('\u{20413}', '\u{20414}', 74012),
+    ('\u{2041E}', '\u{2041F}', 74014),
+    ('\u{20421}', '\u{20422}', 74016),
+    ('\u{20427}', '\u{20427}', 74018),
+    ('\u{20429}', '\u{20429}', 74019),
+    ('\u{2042C}', '\u{2042C}', 74020),
+    ('\u{20435}', '\u{20435}', 74021),
+    ('\u{20438}', '\u{20438}', 74022),
+    ('\u{2043A}', '\u{2043F}', 74023),
+    ('\u{20443}', '\u{20446}', 74029),
+    ('\u{20448}', '\u{20449}', 74033),
+    ('\u{2044B}', '\u{2044B}', 74035),
+    ('\u{20450}', '\u{20451}', 74036),
+    ('\u{20455}', '\u{2045B}', 74038),
+    ('\u{2045E}', '\u{2045F}', 74045),
+    ('\u{20461}', '\u{20462}', 74047),
+    ('\u{20465}', '\u{20465}', 74049),
+    ('\u{2046A}', '\u{2046A}', 74050),
+    ('\u{2046C}', '\u{2046C}', 74051),
+    ('\u{2046F}', '\u{20472}', 74052),
+    ('\u{20474}', '\u{20474}', 74056),
+    ('\u{20479}', '\u{20479}', 74057),
+    ('\u{20484}', '\u{20484}', 74058),
+    ('\u{20487}', '\u{20487}', 74059),
+    ('\u{2048E}', '\u{2048E}', 74060),
+    ('\u{20491}', '\u{20492}', 74061),
+    ('\u{20499}', '\u{20499}', 74063),
+    ('\u{2049C}', '\u{2049D}', 74064),
+    ('\u{204A2}', '\u{204A3}', 74066),
+    ('\u{204A5}', '\u{204A6}', 74068),
+    ('\u{204AC}', '\u{204AC}', 74070),
+    ('\u{204AF}', '\u{204B0}', 74071),
+    ('\u{204B2}', '\u{204B2}', 74073),
+    ('\u{204B5}', '\u{204B5}', 74074),
+    ('\u{204B7}', '\u{204B8}', 74075),
+    ('\u{204BE}', '\u{204C1}', 74077),
+    ('\u{204C5}', '\u{204C5}', 74081),
+    ('\u{204C7}', '\u{204C7}', 74082),
+    ('\u{204CA}', '\u{204CC}', 74083),
+    ('\u{204CE}', '\u{204D1}', 74086),
+    ('\u{204D4}', '\u{204D4}', 74090),
+    ('\u{204D6}', '\u{204D7}', 74091),
+    ('\u{204D9}', '\u{204D9}', 74093),
+    ('\u{204DC}', '\u{204DE}', 74094),
+    ('\u{204E2}', '\u{204E4}', 74097),
+    ('\u{204E8}', '\u{204EE}', 74100),
+    ('\u{204F6}', '\u{204FF}', 74107),
+    ('\u{20502}', '\u{20503}', 74117),
+    ('\u{20509}', '\u{2050B}', 74119),
+    ('\u{2050D}', '\u{2050E}', 74122),
+    ('\u{20511}', '\u{20511}', 74124),
+    ('\u{20515}', '\u{20515}', 74125),
+    ('\u{20520}', '\u{20520}', 74126),
+    ('\u{20526}', '\u{20526}', 74127),
+    ('\u{20528}', '\u{20528}', 74128),
+    ('\u{2052D}', '\u{2052F}', 74129),
+    ('\u{20532}', '\u{20533}', 74132),
+    ('\u{20536}', '\u{20536}', 74134),
+    ('\u{2053A}', '\u{2053C}', 74135),
+    ('\u{20541}', '\u{20541}', 74138),
+    ('\u{20544}', '\u{20544}', 74139),
+    ('\u{20547}', '\u{20547}', 74140),
+    ('\u{2054A}', '\u{2054A}', 74141),
+    ('\u{2054C}', '\u{2054C}', 74142),
+    ('\u{20555}', '\u{20556}', 74143),
+    ('\u{2055F}', '\u{20560}', 74145),
+    ('\u{20563}', '\u{20563}', 74147),
+    ('\u{20566}', '\u{20567}', 74148),
+    ('\u{2056D}', '\u{2056D}', 74150),
+    ('\u{20573}', '\u{20574}', 74151),
+    ('\u{20578}', '\u{20578}', 74153),
+    ('\u{2057B}', '\u{2057B}', 74154),
+    ('\u{2057F}', '\u{2057F}', 74155),
+    ('\u{20582}', '\u{20582}', 74156),
+    ('\u{20584}', '\u{20584}', 74157),
+    ('\u{20586}', '\u{20586}', 74158),
+    ('\u{20588}', '\u{20588}', 74159),
+    ('\u{2058B}', '\u{2058B}', 74160),
+    ('\u{2058E}', '\u{2058E}', 74161),
+    ('\u{20593}', '\u{20593}', 74162),
+    ('\u{20598}', '\u{20598}', 74163),
+    ('\u{2059B}', '\u{2059B}', 74164),
+    ('\u{2059D}', '\u{2059D}', 74165),
+    ('\u{2059F}', '\u{2059F}', 74166),
+    ('\u{205A4}', '\u{205A5}', 74167),
+    ('\u{205A8}', '\u{205A8}', 74169),
+    ('\u{205AB}', '\u{205AD}', 74170),
+    ('\u{205AF}', '\u{205B0}', 74173),
+    ('\u{205B3}', '\u{205B3}', 74175),
+    ('\u{205B6}', '\u{205B7}', 74176),
+    ('\u{205B9}', '\u{205B9}', 74178),
+    ('\u{205BE}', '\u{205BF}', 74179),
+    ('\u{205C2}', '\u{205C3}', 74181),
+    ('\u{205C6}', '\u{205C6}', 74183),
+    ('\u{205C8}', '\u{205CC}', 74184),
+    ('\u{205CF}', '\u{205CF}', 74189),
+    ('\u{205D5}', '\u{205D5}', 74190),
+    ('\u{205D8}', '\u{205D8}', 74191),
+    ('\u{205DA}', '\u{205DB}', 74192),
+    ('\u{205DD}', '\u{205DD}', 74194),
+    ('\u{205DF}', '\u{205E0}', 74195),
+    ('\u{205E3}', '\u{205E9}', 74197),
+    ('\u{205EB}', '\u{205EB}', 74204),
+    ('\u{205EF}', '\u{205F0}', 74205),
+    ('\u{205F3}', '\u{205F6}', 74207),
+    ('\u{205F8}', '\u{205FF}', 74211),
+    ('\u{20601}', '\u{20603}', 74219),
+    ('\u{20605}', '\u{20606}', 74222),
+    ('\u{20608}', '\u{2060C}', 74224),
+    ('\u{2060E}', '\u{2060E}', 74229),
+    ('\u{20610}', '\u{20611}', 74230),
+    ('\u{20615}', '\u{20616}', 74232),
+    ('\u{20619}', '\u{2061A}', 74234),
+    ('\u{2061D}', '\u{20620}', 74236),
+    ('\u{20622}', '\u{20623}', 74240),
+    ('\u{20625}', '\u{20625}', 74242),
+    ('\u{20627}', '\u{20627}', 74243),
+    ('\u{2062A}', '\u{2062A}', 74244),
+    ('\u{20630}', '\u{20631}', 74245),
+    ('\u{20633}', '\u{20633}', 74247),
+    ('\u{2063A}', '\u{2063B}', 74248),
+    ('\u{20641}', '\u{20642}', 74250),
+    ('\u{20645}', '\u{20646}', 74252),
+    ('\u{2064E}', '\u{2064E}', 74254),
+    ('\u{20654}', '\u{20656}', 74255),
+    ('\u{2065E}', '\u{2065E}', 74258),
+    ('\u{20663}', '\u{20664}', 74259),
+    ('\u{20666}', '\u{20666}', 74261),
+    ('\u{2066C}', '\u{2066C}', 74262),
+    ('\u{20676}', '\u{20676}', 74263),
+    ('\u{2067C}', '\u{2067D}', 74264),
+    ('\u{20683}', '\u{20686}', 74266),
+    ('\u{20689}', '\u{2068A}', 74270),
+    ('\u{20690}', '\u{20690}', 74272),
+    ('\u{20693}', '\u{20694}', 74273),
+    ('\u{2069C}', '\u{2069C}', 74275),
+    ('\u{206A0}', '\u{206A0}', 74276),
+    ('\u{206A2}', '\u{206A2}', 74277),
+    ('\u{206A5}', '\u{206A5}', 74278),
+    ('\u{206A7}', '\u{206A8}', 74279),
+    ('\u{206AD}', '\u{206AF}', 74281),
+    ('\u{206B1}', '\u{206B1}', 74284),
+    ('\u{206B3}', '\u{206B5}', 74285),
+    ('\u{206B9}', '\u{206BD}', 74288),
+    ('\u{206C0}', '\u{206C0}', 74293),
+    ('\u{206C3}', '\u{206C3}', 74294),
+    ('\u{206C5}', '\u{206C6}', 74295),
+    ('\u{206CA}', '\u{206CC}', 74297),
+    ('\u{206CE}', '\u{206CE}', 74300),
+    ('\u{206D1}', '\u{206D6}', 74301),
+    ('\u{206D8}', '\u{206DA}', 74307),
+    ('\u{206E1}', '\u{206E1}', 74310),
+    ('\u{206E3}', '\u{206E6}', 74311),
+    ('\u{206E9}', '\u{206EE}', 74315),
+    ('\u{206F1}', '\u{206F1}', 74321),
+    ('\u{206F3}', '\u{206F3}', 74322),
+    ('\u{206F5}', '\u{206F6}', 74323),
+    ('\u{206F8}', '\u{206F8}', 74325),
+    ('\u{20701}', '\u{20701}', 74326),
+    ('\u{20703}', '\u{20705}', 74327),
+    ('\u{20708}', '\u{20709}', 74330),
+    ('\u{2070B}', '\u{2070B}', 74332),
+    ('\u{2070E}', '\u{20711}', 74333),
+    ('\u{20716}', '\u{20719}', 74337),
+    ('\u{20724}', '\u{20724}', 74341),
+    ('\u{2072C}', '\u{2072F}', 74342),
+    ('\u{20731}', '\u{20735}', 74346),
+    ('\u{20739}', '\u{20739}', 74351),
+    ('\u{2073C}', '\u{2073C}', 74352),
+    ('\u{2073E}', '\u{2073E}', 74353),
+    ('\u{20744}', '\u{20744}', 74354),
+    ('\u{20747}', '\u{20747}', 74355),
+    ('\u{20750}', '\u{20751}', 74356),
+    ('\u{20753}', '\u{20758}', 74358),
+    ('\u{2075A}', '\u{2075B}', 74364),
+    ('\u{2075D}', '\u{2075F}', 74366),
+    ('\u{20762}', '\u{20762}', 74369),
+    ('\u{20764}', '\u{20764}', 74370),
+    ('\u{20768}', '\u{20768}', 74371),
+    ('\u{2076A}', '\u{2076D}', 74372),
+    ('\u{20772}', '\u{20773}', 74376),
+    ('\u{20779}', '\u{2077D}', 74378),
+    ('\u{2077F}', '\u{20780}', 74383),
+    ('\u{20783}', '\u{20784}', 74385),
+    ('\u{20786}', '\u{20786}', 74387),
+    ('\u{20788}', '\u{2078A}', 74388),
+    ('\u{20795}', '\u{20797}', 74391),
+    ('\u{20799}', '\u{20799}', 74394),
+    ('\u{2079B}', '\u{2079B}', 74395),
+    ('\u{2079E}', '\u{207A2}', 74396),
+    ('\u{207A4}', '\u{207A4}', 74401),
+    ('\u{207A7}', '\u{207A7}', 74402),
+    ('\u{207A9}', '\u{207A9}', 74403),
+    ('\u{207AC}', '\u{207B1}', 74404),
+    ('\u{207B3}', '\u{207B3}', 74410),
+    ('\u{207B5}', '\u{207B6}', 74411),
+    ('\u{207B8}', '\u{207BA}', 74413),
+    ('\u{207BE}', '\u{207C0}', 74416),
+    ('\u{207C2}', '\u{207C3}', 74419),
+    ('\u{207C5}', '\u{207C5}', 74421),
+    ('\u{207C8}', '\u{207CD}', 74422),
+    ('\u{207D3}', '\u{207D3}', 74428),
+    ('\u{207D7}', '\u{207D7}', 74429),
+    ('\u{207E3}', '\u{207EA}', 74430),
+    ('\u{207F0}', '\u{207F0}', 74438),
+    ('\u{207F2}', '\u{207F2}', 74439),
+    ('\u{207F6}', '\u{207F6}', 74440),
+    ('\u{207F8}', '\u{207FC}', 74441),
+    ('\u{20801}', '\u{20801}', 74446),
+    ('\u{20803}', '\u{20804}', 74447),
+    ('\u{20809}', '\u{2080B}', 74449),
+    ('\u{2080D}', '\u{20811}', 74452),
+    ('\u{20814}', '\u{20814}', 74457),
+    ('\u{20817}', '\u{20817}', 74458),
+    ('\u{20819}', '\u{2081A}', 74459),
+    ('\u{2081C}', '\u{2081D}', 74461),
+    ('\u{20820}', '\u{20820}', 74463),
+    ('\u{20822}', '\u{20822}', 74464),
+    ('\u{20829}', '\u{2082A}', 74465),
+    ('\u{2082C}', '\u{2082D}', 74467),
+    ('\u{2082F}', '\u{2082F}', 74469),
+    ('\u{20833}', '\u{20833}', 74470),
+    ('\u{20835}', '\u{20837}', 74471),
+    ('\u{20839}', '\u{20839}', 74474),
+    ('\u{20840}', '\u{20840}', 74475),
+    ('\u{20842}', '\u{20842}', 74476),
+    ('\u{20844}', '\u{20844}', 74477),
+    ('\u{2084A}', '\u{2084A}', 74478),
+    ('\u{2084E}', '\u{2084F}', 74479),
+    ('\u{20851}', '\u{20852}', 74481),
+    ('\u{20854}', '\u{20854}', 74483),
+    ('\u{2085A}', '\u{2085E}', 74484),
+    ('\u{20860}', '\u{20861}', 74489),
+    ('\u{20863}', '\u{20865}', 74491),
+    ('\u{20867}', '\u{20867}', 74494),
+    ('\u{2086D}', '\u{2086E}', 74495),
+    ('\u{20871}', '\u{20871}', 74497),
+    ('\u{20873}', '\u{20873}', 74498),
+    ('\u{20876}', '\u{20878}', 74499),
+    ('\u{2087A}', '\u{2087B}', 74502),
+    ('\u{20883}', '\u{20883}', 74504),
+    ('\u{20886}', '\u{20887}', 74505),
+    ('\u{2088C}', '\u{2088D}', 74507),
+    ('\u{20891}', '\u{20891}', 74509),
+    ('\u{20893}', '\u{20895}', 74510),
+    ('\u{20899}', '\u{2089B}', 74513),
+    ('\u{2089D}', '\u{208A5}', 74516),
+    ('\u{208A9}', '\u{208AA}', 74525),
+    ('\u{208AC}', '\u{208AC}', 74527),
+    ('\u{208B1}', '\u{208B5}', 74528),
+    ('\u{208B9}', '\u{208B9}', 74533),
+    ('\u{208C2}', '\u{208C2}', 74534),
+    ('\u{208C4}', '\u{208C4}', 74535),
+    ('\u{208C7}', '\u{208C7}', 74536),
+    ('\u{208C9}', '\u{208CA}', 74537),
+    ('\u{208D0}', '\u{208D2}', 74539),
+    ('\u{208D5}', '\u{208D6}', 74542),
+    ('\u{208D8}', '\u{208D8}', 74544),
+    ('\u{208DB}', '\u{208DB}', 74545),
+    ('\u{208E0}', '\u{208E0}', 74546),
+    ('\u{208EA}', '\u{208EC}', 74547),
+    ('\u{208F0}', '\u{208F0}', 74550),
+    ('\u{208F2}', '\u{208F3}', 74551),
+    ('\u{208F6}', '\u{208F6}', 74553),
+    ('\u{208FA}', '\u{208FA}', 74554),
+    ('\u{208FE}', '\u{20900}', 74555),
+    ('\u{20904}', '\u{20904}', 74558),
+    ('\u{20906}', '\u{20906}', 74559),
+    ('\u{2090A}', '\u{2090A}', 74560),
+    ('\u{2090C}', '\u{2090F}', 74561),
+    ('\u{20915}', '\u{20918}', 74565),
+    ('\u{2091D}', '\u{2091D}', 74569),
+    ('\u{20923}', '\u{20923}', 74570),
+    ('\u{20926}', '\u{20927}', 74571),
+    ('\u{20929}', '\u{20929}', 74573),
+    ('\u{2092B}', '\u{2092B}', 74574),
+    ('\u{2092E}', '\u{2092E}', 74575),
+    ('\u{20930}', '\u{20930}', 74576),
+    ('\u{20934}', '\u{20934}', 74577),
+    ('\u{20939}', '\u{2093A}', 74578),
+    ('\u{2093C}', '\u{2093C}', 74580),
+    ('\u{2093E}', '\u{2093E}', 74581),
+    ('\u{20947}', '\u{20948}', 74582),
+    ('\u{2094D}', '\u{2094E}', 74584),
+    ('\u{20950}', '\u{20951}', 74586),
+    ('\u{20954}', '\u{20956}', 74588),
+    ('\u{20958}', '\u{20959}', 74591),
+    ('\u{2095C}', '\u{2095E}', 74593),
+    ('\u{20962}', '\u{20962}', 74596),
+    ('\u{20964}', '\u{20964}', 74597),
+    ('\u{20966}', '\u{20966}', 74598),
+    ('\u{20968}', '\u{20968}', 74599),
+    ('\u{20970}', '\u{20970}', 74600),
+    ('\u{20974}', '\u{20974}', 74601),
+    ('\u{20979}', '\u{20979}', 74602),
+    ('\u{2097F}', '\u{2097F}', 74603),
+    ('\u{20983}', '\u{20984}', 74604),
+    ('\u{20988}', '\u{20988}', 74606),
+    ('\u{2098A}', '\u{2098C}', 74607),
+    ('\u{2098E}', '\u{20990}', 74610),
+    ('\u{20992}', '\u{20992}', 74613),
+    ('\u{20997}', '\u{20997}', 74614),
+    ('\u{2099C}', '\u{2099C}', 74615),
+    ('\u{209A7}', '\u{209AC}', 74616),
+    ('\u{209AF}', '\u{209AF}', 74622),
+    ('\u{209B2}', '\u{209B3}', 74623),
+    ('\u{209B9}', '\u{209BB}', 74625),
+    ('\u{209BF}', '\u{209C0}', 74628),
+    ('\u{209C5}', '\u{209C7}', 74630),
+    ('\u{209CC}', '\u{209CD}', 74633),
+    ('\u{209D2}', '\u{209D2}', 74635),
+    ('\u{209D5}', '\u{209D6}', 74636),
+    ('\u{209D9}', '\u{209DB}', 74638),
+    ('\u{209DF}', '\u{209E0}', 74641),
+    ('\u{209E4}', '\u{209E4}', 74643),
+    ('\u{209E7}', '\u{209E7}', 74644),
+    ('\u{209E9}', '\u{209EB}', 74645),
+    ('\u{209F0}', '\u{209F0}', 74648),
+    ('\u{209F4}', '\u{209F5}', 74649),
+    ('\u{209FF}', '\u{209FF}', 74651),
+    ('\u{20A03}', '\u{20A03}', 74652),
+    ('\u{20A06}', '\u{20A06}', 74653),
+    ('\u{20A0A}', '\u{20A0A}', 74654),
+    ('\u{20A0C}', '\u{20A0E}', 74655),
+    ('\u{20A11}', '\u{20A11}', 74658),
+    ('\u{20A15}', '\u{20A15}', 74659),
+    ('\u{20A18}', '\u{20A18}', 74660),
+    ('\u{20A1A}', '\u{20A1A}', 74661),
+    ('\u{20A1E}', '\u{20A1E}', 74662),
+    ('\u{20A20}', '\u{20A22}', 74663),
+    ('\u{20A25}', '\u{20A26}', 74666),
+    ('\u{20A2A}', '\u{20A2A}', 74668),
+    ('\u{20A2D}', '\u{20A2D}', 74669),
+    ('\u{20A32}', '\u{20A32}', 74670),
+    ('\u{20A35}', '\u{20A35}', 74671),
+    ('\u{20A38}', '\u{20A38}', 74672),
+    ('\u{20A3B}', '\u{20A3B}', 74673),
+    ('\u{20A3E}', '\u{20A3F}', 74674),
+    ('\u{20A42}', '\u{20A42}', 74676),
+    ('\u{20A44}', '\u{20A44}', 74677),
+    ('\u{20A46}', '\u{20A46}', 74678),
+    ('\u{20A48}', '\u{20A49}', 74679),
+    ('\u{20A4F}', '\u{20A50}', 74681),
+    ('\u{20A54}', '\u{20A55}', 74683),
+    ('\u{20A57}', '\u{20A58}', 74685),
+    ('\u{20A60}', '\u{20A60}', 74687),
+    ('\u{20A65}', '\u{20A65}', 74688),
+    ('\u{20A67}', '\u{20A67}', 74689),
+    ('\u{20A6A}', '\u{20A6B}', 74690),
+    ('\u{20A6F}', '\u{20A6F}', 74692),
+    ('\u{20A75}', '\u{20A75}', 74693),
+    ('\u{20A77}', '\u{20A77}', 74694),
+    ('\u{20A7A}', '\u{20A7A}', 74695),
+    ('\u{20A7F}', '\u{20A7F}', 74696),
+    ('\u{20A82}', '\u{20A84}', 74697),
+    ('\u{20A87}', '\u{20A87}', 74700),
+    ('\u{20A8A}', '\u{20A8A}', 74701),
+    ('\u{20A90}', '\u{20A92}', 74702),
+    ('\u{20A97}', '\u{20A97}', 74705),
+    ('\u{20A99}', '\u{20A9A}', 74706),
+    ('\u{20A9F}', '\u{20A9F}', 74708),
+    ('\u{20AA3}', '\u{20AA3}', 74709),
+    ('\u{20AA7}', '\u{20AA7}', 74710),
+    ('\u{20AAE}', '\u{20AAE}', 74711),
+    ('\u{20AB4}', '\u{20AB4}', 74712),
+    ('\u{20AB6}', '\u{20AB8}', 74713),
+    ('\u{20ABA}', '\u{20ABB}', 74716),
+    ('\u{20AC2}', '\u{20AC6}', 74718),
+    ('\u{20AC9}', '\u{20AC9}', 74723),
+    ('\u{20ACC}', '\u{20ACC}', 74724),
+    ('\u{20ACF}', '\u{20ACF}', 74725),
+    ('\u{20AD3}', '\u{20AD3}', 74726),
+    ('\u{20AD8}', '\u{20AD8}', 74727),
+    ('\u{20ADB}', '\u{20ADD}', 74728),
+    ('\u{20AE3}', '\u{20AE4}', 74731),
+    ('\u{20AE8}', '\u{20AE8}', 74733),
+    ('\u{20AED}', '\u{20AEE}', 74734),
+    ('\u{20AF6}', '\u{20AFA}', 74736),
+    ('\u{20AFE}', '\u{20AFE}', 74741),
+    ('\u{20B03}', '\u{20B03}', 74742),
+    ('\u{20B06}', '\u{20B06}', 74743),
+    ('\u{20B08}', '\u{20B09}', 74744),
+    ('\u{20B0B}', '\u{20B0B}', 74746),
+    ('\u{20B0D}', '\u{20B0D}', 74747),
+    ('\u{20B12}', '\u{20B13}', 74748),
+    ('\u{20B15}', '\u{20B16}', 74750),
+    ('\u{20B19}', '\u{20B19}', 74752),
+    ('\u{20B1B}', '\u{20B1B}', 74753),
+    ('\u{20B1D}', '\u{20B1D}', 74754),
+    ('\u{20B20}', '\u{20B20}', 74755),
+    ('\u{20B22}', '\u{20B24}', 74756),
+    ('\u{20B2A}', '\u{20B2A}', 74759),
+    ('\u{20B2C}', '\u{20B2C}', 74760),
+    ('\u{20B2E}', '\u{20B2E}', 74761),
+    ('\u{20B36}', '\u{20B36}', 74762),
+    ('\u{20B3E}', '\u{20B3E}', 74763),
+    ('\u{20B48}', '\u{20B49}', 74764),
+    ('\u{20B4B}', '\u{20B4B}', 74766),
+    ('\u{20B57}', '\u{20B57}', 74767),
+    ('\u{20B64}', '\u{20B65}', 74768),
+    ('\u{20B6F}', '\u{20B70}', 74770),
+    ('\u{20B74}', '\u{20B74}', 74772),
+    ('\u{20B79}', '\u{20B79}', 74773),
+    ('\u{20B7F}', '\u{20B7F}', 74774),
+    ('\u{20B83}', '\u{20B83}', 74775),
+    ('\u{20B86}', '\u{20B86}', 74776),
+    ('\u{20B8A}', '\u{20B8A}', 74777),
+    ('\u{20B8C}', '\u{20B8C}', 74778),
+    ('\u{20B8F}', '\u{20B8F}', 74779),
+    ('\u{20B91}', '\u{20B91}', 74780),
+    ('\u{20B99}', '\u{20B99}', 74781),
+    ('\u{20B9C}', '\u{20B9C}', 74782),
+    ('\u{20B9F}', '\u{20BA1}', 74783),
+    ('\u{20BA8}', '\u{20BA9}', 74786),
+    ('\u{20BAB}', '\u{20BAB}', 74788),
+    ('\u{20BAD}', '\u{20BAD}', 74789),
+    ('\u{20BAF}', '\u{20BB0}', 74790),
+    ('\u{20BB3}', '\u{20BB7}', 74792),
+    ('\u{20BBD}', '\u{20BC0}', 74797),
+    ('\u{20BC2}', '\u{20BC9}', 74801),
+    ('\u{20BCB}', '\u{20BCB}', 74809),
+    ('\u{20BCD}', '\u{20BCD}', 74810),
+    ('\u{20BD0}', '\u{20BD1}', 74811),
+    ('\u{20BD3}', '\u{20BD4}', 74813),
+    ('\u{20BD7}', '\u{20BD9}', 74815),
+    ('\u{20BDC}', '\u{20BDC}', 74818),
+    ('\u{20BDF}', '\u{20BE0}', 74819),
+    ('\u{20BE6}', '\u{20BE6}', 74821),
+    ('\u{20BE9}', '\u{20BEB}', 74822),
+    ('\u{20BED}', '\u{20BF4}', 74825),
+    ('\u{20BF7}', '\u{20BF7}', 74833),
+    ('\u{20BF9}', '\u{20BF9}', 74834),
+    ('\u{20BFB}', '\u{20C05}', 74835),
+    ('\u{20C07}', '\u{20C09}', 74846),
+    ('\u{20C0B}', '\u{20C0D}', 74849),
+    ('\u{20C0F}', '\u{20C11}', 74852),
+    ('\u{20C13}', '\u{20C13}', 74855),
+    ('\u{20C15}', '\u{20C16}', 74856),
+    ('\u{20C19}', '\u{20C1C}', 74858),
+    ('\u{20C20}', '\u{20C20}', 74862),
+    ('\u{20C2D}', '\u{20C2D}', 74863),
+    ('\u{20C32}', '\u{20C34}', 74864),
+    ('\u{20C37}', '\u{20C37}', 74867),
+    ('\u{20C39}', '\u{20C3E}', 74868),
+    ('\u{20C40}', '\u{20C4D}', 74874),
+    ('\u{20C50}', '\u{20C51}', 74888),
+    ('\u{20C53}', '\u{20C54}', 74890),
+    ('\u{20C57}', '\u{20C5E}', 74892),
+    ('\u{20C60}', '\u{20C61}', 74900),
+    ('\u{20C64}', '\u{20C65}', 74902),
+    ('\u{20C67}', '\u{20C68}', 74904),
+    ('\u{20C6B}', '\u{20C6B}', 74906),
+    ('\u{20C6F}', '\u{20C6F}', 74907),
+    ('\u{20C72}', '\u{20C73}', 74908),
+    ('\u{20C76}', '\u{20C77}', 74910),
+    ('\u{20C7C}', '\u{20C7C}', 74912),
+    ('\u{20C8A}', '\u{20C8F}', 74913),
+    ('\u{20C91}', '\u{20C94}', 74919),
+    ('\u{20C96}', '\u{20C96}', 74923),
+    ('\u{20C99}', '\u{20C9C}', 74924),
+    ('\u{20C9F}', '\u{20CA8}', 74928),
+    ('\u{20CAE}', '\u{20CAE}', 74938),
+    ('\u{20CB1}', '\u{20CB1}', 74939),
+    ('\u{20CB4}', '\u{20CB5}', 74940),
+    ('\u{20CB7}', '\u{20CB7}', 74942),
+    ('\u{20CB9}', '\u{20CBB}', 74943),
+    ('\u{20CBD}', '\u{20CC3}', 74946),
+    ('\u{20CC7}', '\u{20CC7}', 74953),
+    ('\u{20CCA}', '\u{20CCC}', 74954),
+    ('\u{20CCE}', '\u{20CDA}', 74957),
+    ('\u{20CDD}', '\u{20CDE}', 74970),
+    ('\u{20CE8}', '\u{20CE8}', 74972),
+    ('\u{20CEC}', '\u{20CED}', 74973),
+    ('\u{20CF3}', '\u{20CF4}', 74975),
+    ('\u{20CF6}', '\u{20CF6}', 74977),
+    ('\u{20CF9}', '\u{20CFA}', 74978),
+    ('\u{20CFC}', '\u{20CFD}', 74980),
+    ('\u{20CFF}', '\u{20CFF}', 74982),
+    ('\u{20D02}', '\u{20D02}', 74983),
+    ('\u{20D04}', '\u{20D04}', 74984),
+    ('\u{20D07}', '\u{20D07}', 74985),
+    ('\u{20D09}', '\u{20D1E}', 74986),
+    ('\u{20D20}', '\u{20D26}', 75008),
+    ('\u{20D28}', '\u{20D29}', 75015),
+    ('\u{20D2B}', '\u{20D2B}', 75017),
+    ('\u{20D2D}', '\u{20D2D}', 75018),
+    ('\u{20D2F}', '\u{20D33}', 75019),
+    ('\u{20D35}', '\u{20D35}', 75024),
+    ('\u{20D38}', '\u{20D3C}', 75025),
+    ('\u{20D3E}', '\u{20D3E}', 75030),
+    ('\u{20D46}', '\u{20D49}', 75031),
+    ('\u{20D4B}', '\u{20D50}', 75035),
+    ('\u{20D58}', '\u{20D58}', 75041),
+    ('\u{20D5A}', '\u{20D5A}', 75042),
+    ('\u{20D60}', '\u{20D60}', 75043),
+    ('\u{20D63}', '\u{20D63}', 75044),
+    ('\u{20D66}', '\u{20D69}', 75045),
+    ('\u{20D6B}', '\u{20D6F}', 75049),
+    ('\u{20D71}', '\u{20D71}', 75054),
+    ('\u{20D73}', '\u{20D74}', 75055),
+    ('\u{20D77}', '\u{20DA8}', 75057),
+    ('\u{20DAB}', '\u{20DAC}', 75107),
+    ('\u{20DAE}', '\u{20DB2}', 75109),
+    ('\u{20DB7}', '\u{20DB9}', 75114),
+    ('\u{20DBB}', '\u{20DBB}', 75117),
+    ('\u{20DBE}', '\u{20DC5}', 75118),
+    ('\u{20DC7}', '\u{20DD3}', 75126),
+    ('\u{20DD6}', '\u{20DD6}', 75139),
+    ('\u{20DD9}', '\u{20DD9}', 75140),
+    ('\u{20DDE}', '\u{20DDF}', 75141),
+    ('\u{20DE2}', '\u{20DE2}', 75143),
+    ('\u{20DE4}', '\u{20DE6}', 75144),
+    ('\u{20DF8}', '\u{20DF8}', 75147),
+    ('\u{20DFA}', '\u{20DFB}', 75148),
+    ('\u{20DFF}', '\u{20DFF}', 75150),
+    ('\u{20E01}', '\u{20E02}', 75151),
+    ('\u{20E04}', '\u{20E04}', 75153),
+    ('\u{20E09}', '\u{20E0A}', 75154),
+    ('\u{20E0D}', '\u{20E2C}', 75156),
+    ('\u{20E2E}', '\u{20E2E}', 75188),
+    ('\u{20E31}', '\u{20E34}', 75189),
+    ('\u{20E38}', '\u{20E3A}', 75193),
+    ('\u{20E3C}', '\u{20E3E}', 75196),
+    ('\u{20E40}', '\u{20E41}', 75199),
+    ('\u{20E43}', '\u{20E46}', 75201),
+    ('\u{20E48}', '\u{20E48}', 75205),
+    ('\u{20E4A}', '\u{20E4E}', 75206),
+    ('\u{20E50}', '\u{20E51}', 75211),
+    ('\u{20E53}', '\u{20E58}', 75213),
+    ('\u{20E5B}', '\u{20E5B}', 75219),
+    ('\u{20E5D}', '\u{20E5D}', 75220),
+    ('\u{20E5F}', '\u{20E60}', 75221),
+    ('\u{20E64}', '\u{20E66}', 75223),
+    ('\u{20E6D}', '\u{20E6D}', 75226),
+    ('\u{20E6F}', '\u{20E74}', 75227),
+    ('\u{20E77}', '\u{20E7E}', 75233),
+    ('\u{20E87}', '\u{20E87}', 75241),
+    ('\u{20E8C}', '\u{20E8C}', 75242),
+    ('\u{20E90}', '\u{20E90}', 75243),
+    ('\u{20E92}', '\u{20E92}', 75244),
+    ('\u{20E94}', '\u{20E9A}', 75245),
+    ('\u{20E9D}', '\u{20E9D}', 75252),
+    ('\u{20E9F}', '\u{20EA0}', 75253),
+    ('\u{20EA2}', '\u{20EA3}', 75255),
+    ('\u{20EA5}', '\u{20EA9}', 75257),
+    ('\u{20EAB}', '\u{20ECB}', 75262),
+    ('\u{20ECD}', '\u{20ECD}', 75295),
+    ('\u{20ECF}', '\u{20ED0}', 75296),
+    ('\u{20ED2}', '\u{20ED3}', 75298),
+    ('\u{20ED7}', '\u{20EDA}', 75300),
+    ('\u{20EDC}', '\u{20EE1}', 75304),
+    ('\u{20EE4}', '\u{20EE8}', 75310),
+    ('\u{20EEA}', '\u{20EEC}', 75315),
+    ('\u{20EF1}', '\u{20EF1}', 75318),
+    ('\u{20EF4}', '\u{20EF5}', 75319),
+    ('\u{20EF7}', '\u{20EFD}', 75321),
+    ('\u{20F15}', '\u{20F18}', 75328),
+    ('\u{20F1D}', '\u{20F22}', 75332),
+    ('\u{20F24}', '\u{20F24}', 75338),
+    ('\u{20F26}', '\u{20F26}', 75339),
+    ('\u{20F29}', '\u{20F2A}', 75340),
+    ('\u{20F2C}', '\u{20F30}', 75342),
+    ('\u{20F32}', '\u{20F5E}', 75347),
+    ('\u{20F61}', '\u{20F61}', 75392),
+    ('\u{20F63}', '\u{20F64}', 75393),
+    ('\u{20F66}', '\u{20F66}', 75395),
+    ('\u{20F68}', '\u{20F6C}', 75396),
+    ('\u{20F6E}', '\u{20F6E}', 75401),
+    ('\u{20F70}', '\u{20F72}', 75402),
+    ('\u{20F75}', '\u{20F77}', 75405),
+    ('\u{20F79}', '\u{20F79}', 75408),
+    ('\u{20F7B}', '\u{20F7C}', 75409),
+    ('\u{20F7E}', '\u{20F80}', 75411),
+    ('\u{20F86}', '\u{20F87}', 75414),
+    ('\u{20F89}', '\u{20F89}', 75416),
+    ('\u{20F8B}', '\u{20F8D}', 75417),
+    ('\u{20F8F}', '\u{20F90}', 75420),
+    ('\u{20F92}', '\u{20F96}', 75422),
+    ('\u{20F9B}', '\u{20F9B}', 75427),
+    ('\u{20FA2}', '\u{20FA6}', 75428),
+    ('\u{20FA8}', '\u{20FA9}', 75433),
+    ('\u{20FAB}', '\u{20FAE}', 75435),
+    ('\u{20FB2}', '\u{20FB2}', 75439),
+    ('\u{20FB4}', '\u{20FC4}', 75440),
+    ('\u{20FC6}', '\u{20FC9}', 75457),
+    ('\u{20FCB}', '\u{20FCB}', 75461),
+    ('\u{20FCF}', '\u{20FCF}', 75462),
+    ('\u{20FD1}', '\u{20FD5}', 75463),
+    ('\u{20FD7}', '\u{20FD8}', 75468),
+    ('\u{20FDA}', '\u{20FE5}', 75470),
+    ('\u{20FEA}', '\u{20FF9}', 75482),
+    ('\u{20FFF}', '\u{20FFF}', 75498),
+    ('\u{21002}', '\u{21002}', 75499),
+    ('\u{21007}', '\u{21007}', 75500),
+    ('\u{21011}', '\u{21011}', 75501),
+    ('\u{21014}', '\u{21014}', 75502),
+    ('\u{21016}', '\u{21019}', 75503),
+    ('\u{2101D}', '\u{2101E}', 75507),
+    ('\u{21020}', '\u{21037}', 75509),
+    ('\u{21039}', '\u{2103A}', 75533),
+    ('\u{2103D}', '\u{21041}', 75535),
+    ('\u{21046}', '\u{2104F}', 75540),
+    ('\u{21051}', '\u{21051}', 75550),
+    ('\u{21053}', '\u{21055}', 75551),
+    ('\u{21059}', '\u{2105E}', 75554),
+    ('\u{21064}', '\u{21064}', 75560),
+    ('\u{21067}', '\u{21068}', 75561),
+    ('\u{2106A}', '\u{2106A}', 75563),
+    ('\u{2106E}', '\u{21071}', 75564),
+    ('\u{21073}', '\u{2108A}', 75568),
+    ('\u{2108C}', '\u{2108F}', 75592),
+    ('\u{21091}', '\u{21093}', 75596),
+    ('\u{21095}', '\u{2109B}', 75599),
+    ('\u{2109D}', '\u{2109D}', 75606),
+    ('\u{2109F}', '\u{210A1}', 75607),
+    ('\u{210A3}', '\u{210A3}', 75610),
+    ('\u{210A9}', '\u{210AB}', 75611),
+    ('\u{210AD}', '\u{210B5}', 75614),
+    ('\u{210B7}', '\u{210B7}', 75623),
+    ('\u{210B9}', '\u{210B9}', 75624),
+    ('\u{210BF}', '\u{210BF}', 75625),
+    ('\u{210C1}', '\u{210DD}', 75626),
+    ('\u{210E1}', '\u{210E2}', 75655),
+    ('\u{210E4}', '\u{210E4}', 75657),
+    ('\u{210E6}', '\u{210E7}', 75658),
+    ('\u{210E9}', '\u{210E9}', 75660),
+    ('\u{210F3}', '\u{210F4}', 75661),
+    ('\u{210F6}', '\u{21101}', 75663),
+    ('\u{21107}', '\u{21107}', 75675),
+    ('\u{2110D}', '\u{21116}', 75676),
+    ('\u{2111F}', '\u{2111F}', 75686),
+    ('\u{21121}', '\u{21135}', 75687),
+    ('\u{21137}', '\u{21137}', 75708),
+    ('\u{21139}', '\u{21139}', 75709),
+    ('\u{2113B}', '\u{2113B}', 75710),
+    ('\u{2113D}', '\u{2113E}', 75711),
+    ('\u{21142}', '\u{21153}', 75713),
+    ('\u{21155}', '\u{21156}', 75731),
+    ('\u{21158}', '\u{21159}', 75733),
+    ('\u{21160}', '\u{21162}', 75735),
+    ('\u{21165}', '\u{21165}', 75738),
+    ('\u{21167}', '\u{2116D}', 75739),
+    ('\u{2116F}', '\u{2116F}', 75746),
+    ('\u{21171}', '\u{21171}', 75747),
+    ('\u{21173}', '\u{21173}', 75748),
+    ('\u{21175}', '\u{21177}', 75749),
+    ('\u{21179}', '\u{2117B}', 75752),
+    ('\u{21180}', '\u{21182}', 75755),
+    ('\u{21184}', '\u{21188}', 75758),
+    ('\u{2118C}', '\u{2118D}', 75763),
+    ('\u{2118F}', '\u{2118F}', 75765),
+    ('\u{21191}', '\u{21191}', 75766),
+    ('\u{21195}', '\u{2119A}', 75767),
+    ('\u{2119E}', '\u{2119E}', 75773),
+    ('\u{211A3}', '\u{211A6}', 75774),
+    ('\u{211A9}', '\u{211AA}', 75778),
+    ('\u{211AE}', '\u{211B2}', 75780),
+    ('\u{211B8}', '\u{211B8}', 75785),
+    ('\u{211BD}', '\u{211BD}', 75786),
+    ('\u{211C0}', '\u{211C0}', 75787),
+    ('\u{211C2}', '\u{211C2}', 75788),
+    ('\u{211C4}', '\u{211C4}', 75789),
+    ('\u{211C8}', '\u{211C8}', 75790),
+    ('\u{211D1}', '\u{211D3}', 75791),
+    ('\u{211D6}', '\u{211D6}', 75794),
+    ('\u{211D9}', '\u{211DA}', 75795),
+    ('\u{211DC}', '\u{211E0}', 75797),
+    ('\u{211E4}', '\u{211E4}', 75802),
+    ('\u{211E6}', '\u{211E7}', 75803),
+    ('\u{211E9}', '\u{211E9}', 75805),
+    ('\u{211EC}', '\u{211F1}', 75806),
+    ('\u{211F3}', '\u{211F3}', 75812),
+    ('\u{211F5}', '\u{211F8}', 75813),
+    ('\u{211FA}', '\u{211FC}', 75817),
+    ('\u{211FF}', '\u{21200}', 75820),
+    ('\u{21205}', '\u{21206}', 75822),
+    ('\u{21208}', '\u{2120A}', 75824),
+    ('\u{2120D}', '\u{21210}', 75827),
+    ('\u{21212}', '\u{21213}', 75831),
+    ('\u{21217}', '\u{21217}', 75833),
+    ('\u{21219}', '\u{21219}', 75834),
+    ('\u{2121B}', '\u{2121B}', 75835),
+    ('\u{2121E}', '\u{2121E}', 75836),
+    ('\u{21220}', '\u{21221}', 75837),
+    ('\u{21223}', '\u{21223}', 75839),
+    ('\u{21226}', '\u{21229}', 75840),
+    ('\u{2122E}', '\u{21230}', 75844),
+    ('\u{21232}', '\u{21232}', 75847),
+    ('\u{21234}', '\u{21236}', 75848),
+    ('\u{2123A}', '\u{2123A}', 75851),
+    ('\u{2123C}', '\u{2123C}', 75852),
+    ('\u{2123F}', '\u{2123F}', 75853),
+    ('\u{21244}', '\u{21246}', 75854),
+    ('\u{21249}', '\u{21249}', 75857),
+    ('\u{2124E}', '\u{21253}', 75858),
+    ('\u{21255}', '\u{21257}', 75864),
+    ('\u{2125A}', '\u{2125B}', 75867),
+    ('\u{2125F}', '\u{2125F}', 75869),
+    ('\u{21266}', '\u{21266}', 75870),
+    ('\u{21269}', '\u{2126A}', 75871),
+    ('\u{2126D}', '\u{2126D}', 75873),
+    ('\u{21274}', '\u{21274}', 75874),
+    ('\u{21277}', '\u{21278}', 75875),
+    ('\u{2127A}', '\u{2127A}', 75877),
+    ('\u{2127C}', '\u{2127F}', 75878),
+    ('\u{21281}', '\u{21281}', 75882),
+    ('\u{21284}', '\u{21285}', 75883),
+    ('\u{21289}', '\u{21289}', 75885),
+    ('\u{2128D}', '\u{2128D}', 75886),
+    ('\u{21291}', '\u{21291}', 75887),
+    ('\u{2129B}', '\u{2129B}', 75888),
+    ('\u{2129D}', '\u{212A1}', 75889),
+    ('\u{212A3}', '\u{212A3}', 75894),
+    ('\u{212A7}', '\u{212A9}', 75895),
+    ('\u{212AB}', '\u{212AB}', 75898),
+    ('\u{212AD}', '\u{212AE}', 75899),
+    ('\u{212B0}', '\u{212B3}', 75901),
+    ('\u{212B6}', '\u{212B6}', 75905),
+    ('\u{212B8}', '\u{212B9}', 75906),
+    ('\u{212BB}', '\u{212BC}', 75908),
+    ('\u{212C0}', '\u{212C3}', 75910),
+    ('\u{212D7}', '\u{212D7}', 75914),
+    ('\u{212D9}', '\u{212DA}', 75915),
+    ('\u{212DF}', '\u{212DF}', 75917),
+    ('\u{212E3}', '\u{212EA}', 75918),
+    ('\u{212EC}', '\u{212ED}', 75926),
+    ('\u{212EF}', '\u{212EF}', 75928),
+    ('\u{212FE}', '\u{21300}', 75929),
+    ('\u{21302}', '\u{21305}', 75932),
+    ('\u{21314}', '\u{21314}', 75936),
+    ('\u{2131A}', '\u{2131A}', 75937),
+    ('\u{2131C}', '\u{2131C}', 75938),
+    ('\u{2131E}', '\u{2131E}', 75939),
+    ('\u{21320}', '\u{21324}', 75940),
+    ('\u{21326}', '\u{21326}', 75945),
+    ('\u{21329}', '\u{2132A}', 75946),
+    ('\u{2132D}', '\u{2132D}', 75948),
+    ('\u{21332}', '\u{21332}', 75949),
+    ('\u{21334}', '\u{21334}', 75950),
+    ('\u{21336}', '\u{21336}', 75951),
+    ('\u{2133A}', '\u{2133A}', 75952),
+    ('\u{2133D}', '\u{2133D}', 75953),
+    ('\u{2133F}', '\u{2133F}', 75954),
+    ('\u{2134C}', '\u{2134D}', 75955),
+    ('\u{21350}', '\u{21350}', 75957),
+    ('\u{21352}', '\u{21352}', 75958),
+    ('\u{21358}', '\u{2135F}', 75959),
+    ('\u{21361}', '\u{21366}', 75967),
+    ('\u{21368}', '\u{21368}', 75973),
+    ('\u{2136A}', '\u{2136B}', 75974),
+    ('\u{2136E}', '\u{2136E}', 75976),
+    ('\u{21372}', '\u{21372}', 75977),
+    ('\u{21375}', '\u{21377}', 75978),
+    ('\u{2137B}', '\u{2137B}', 75981),
+    ('\u{21389}', '\u{21389}', 75982),
+    ('\u{2138D}', '\u{2138E}', 75983),
+    ('\u{21391}', '\u{21392}', 75985),
+    ('\u{21394}', '\u{21394}', 75987),
+    ('\u{21398}', '\u{21398}', 75988),
+    ('\u{2139A}', '\u{213A6}', 75989),
+    ('\u{213AB}', '\u{213AE}', 76002),
+    ('\u{213B3}', '\u{213B4}', 76006),
+    ('\u{213BA}', '\u{213BB}', 76008),
+    ('\u{213BE}', '\u{213C1}', 76010),
+    ('\u{213C5}', '\u{213C6}', 76014),
+    ('\u{213D8}', '\u{213D8}', 76016),
+    ('\u{213DA}', '\u{213DB}', 76017),
+    ('\u{213DE}', '\u{213E7}', 76019),
+    ('\u{213E9}', '\u{213EA}', 76029),
+    ('\u{213ED}', '\u{213EF}', 76031),
+    ('\u{213F5}', '\u{213F5}', 76034),
+    ('\u{213FC}', '\u{213FE}', 76035),
+    ('\u{2140B}', '\u{2140C}', 76038),
+    ('\u{2140F}', '\u{2140F}', 76040),
+    ('\u{21412}', '\u{21414}', 76041),
+    ('\u{21416}', '\u{2141A}', 76044),
+    ('\u{2141D}', '\u{2141E}', 76049),
+    ('\u{21420}', '\u{21420}', 76051),
+    ('\u{21424}', '\u{21425}', 76052),
+    ('\u{21428}', '\u{21428}', 76054),
+    ('\u{2142E}', '\u{2142F}', 76055),
+    ('\u{2143F}', '\u{21440}', 76057),
+    ('\u{21444}', '\u{21444}', 76059),
+    ('\u{21446}', '\u{21446}', 76060),
+    ('\u{21448}', '\u{21448}', 76061),
+    ('\u{2144D}', '\u{2144E}', 76062),
+    ('\u{21452}', '\u{21458}', 76064),
+    ('\u{2145A}', '\u{2145A}', 76071),
+    ('\u{2145D}', '\u{2145F}', 76072),
+    ('\u{21463}', '\u{21463}', 76075),
+    ('\u{21469}', '\u{2146A}', 76076),
+    ('\u{2146D}', '\u{2146D}', 76078),
+    ('\u{2146F}', '\u{21476}', 76079),
+    ('\u{2147B}', '\u{2147B}', 76087),
+    ('\u{2147D}', '\u{2147E}', 76088),
+    ('\u{21481}', '\u{21481}', 76090),
+    ('\u{21483}', '\u{21484}', 76091),
+    ('\u{2148A}', '\u{2148A}', 76093),
+    ('\u{2148C}', '\u{2148D}', 76094),
+    ('\u{2148F}', '\u{2148F}', 76096),
+    ('\u{21492}', '\u{21493}', 76097),
+    ('\u{21496}', '\u{21498}', 76099),
+    ('\u{214A2}', '\u{214A2}', 76102),
+    ('\u{214A7}', '\u{214A7}', 76103),
+    ('\u{214AA}', '\u{214AF}', 76104),
+    ('\u{214B1}', '\u{214B1}', 76110),
+    ('\u{214B3}', '\u{214B3}', 76111),
+    ('\u{214B6}', '\u{214B6}', 76112),
+    ('\u{214BB}', '\u{214BB}', 76113),
+    ('\u{214BE}', '\u{214BE}', 76114),
+    ('\u{214C1}', '\u{214C9}', 76115),
+    ('\u{214CB}', '\u{214CB}', 76124),
+    ('\u{214CD}', '\u{214CF}', 76125),
+    ('\u{214D2}', '\u{214D4}', 76128),
+    ('\u{214D6}', '\u{214D6}', 76131),
+    ('\u{214D8}', '\u{214D8}', 76132),
+    ('\u{214DE}', '\u{214E3}', 76133),
+    ('\u{214E5}', '\u{214E6}', 76139),
+    ('\u{214E8}', '\u{214E8}', 76141),
+    ('\u{214ED}', '\u{214F0}', 76142),
+    ('\u{214F7}', '\u{214F7}', 76146),
+    ('\u{214FD}', '\u{214FF}', 76147),
+    ('\u{21503}', '\u{21503}', 76150),
+    ('\u{21507}', '\u{21507}', 76151),
+    ('\u{2150D}', '\u{2150D}', 76152),
+    ('\u{21511}', '\u{21512}', 76153),
+    ('\u{21514}', '\u{21516}', 76155),
+    ('\u{2151B}', '\u{2151C}', 76158),
+    ('\u{2151E}', '\u{2151E}', 76160),
+    ('\u{21523}', '\u{21523}', 76161),
+    ('\u{21528}', '\u{21528}', 76162),
+    ('\u{2152A}', '\u{2152A}', 76163),
+    ('\u{21531}', '\u{21531}', 76164),
+    ('\u{21534}', '\u{21534}', 76165),
+    ('\u{2153F}', '\u{2153F}', 76166),
+    ('\u{21541}', '\u{21541}', 76167),
+    ('\u{21549}', '\u{21549}', 76168),
+    ('\u{2154F}', '\u{21550}', 76169),
+    ('\u{21552}', '\u{21552}', 76171),
+    ('\u{21556}', '\u{21557}', 76172),
+    ('\u{21562}', '\u{21562}', 76174),
+    ('\u{21567}', '\u{21567}', 76175),
+    ('\u{2156A}', '\u{2156A}', 76176),
+    ('\u{2156E}', '\u{2156E}', 76177),
+    ('\u{21570}', '\u{21570}', 76178),
+    ('\u{21575}', '\u{21575}', 76179),
+    ('\u{21577}', '\u{21577}', 76180),
+    ('\u{21582}', '\u{21582}', 76181),
+    ('\u{21589}', '\u{21589}', 76182),
+    ('\u{2158C}', '\u{2158C}', 76183),
+    ('\u{2158E}', '\u{2158E}', 76184),
+    ('\u{21590}', '\u{21594}', 76185),
+    ('\u{21596}', '\u{21596}', 76190),
+    ('\u{2159B}', '\u{2159B}', 76191),
+    ('\u{2159D}', '\u{2159E}', 76192),
+    ('\u{215A1}', '\u{215A4}', 76194),
+    ('\u{215A7}', '\u{215A7}', 76198),
+    ('\u{215AA}', '\u{215AC}', 76199),
+    ('\u{215AF}', '\u{215AF}', 76202),
+    ('\u{215B1}', '\u{215B3}', 76203),
+    ('\u{215B5}', '\u{215B5}', 76206),
+    ('\u{215BA}', '\u{215BC}', 76207),
+    ('\u{215BE}', '\u{215BE}', 76210),
+    ('\u{215C1}', '\u{215C2}', 76211),
+    ('\u{215C4}', '\u{215C6}', 76213),
+    ('\u{215C8}', '\u{215CB}', 76216),
+    ('\u{215CD}', '\u{215D1}', 76220),
+    ('\u{215D7}', '\u{215D7}', 76225),
+    ('\u{215DE}', '\u{215DE}', 76226),
+    ('\u{215E2}', '\u{215E2}', 76227),
+    ('\u{215E4}', '\u{215E6}', 76228),
+    ('\u{215F2}', '\u{215F3}', 76231),
+    ('\u{215F5}', '\u{215F9}', 76233),
+    ('\u{215FC}', '\u{215FC}', 76238),
+    ('\u{2160A}', '\u{2160A}', 76239),
+    ('\u{2160D}', '\u{2160D}', 76240),
+    ('\u{2160F}', '\u{21610}', 76241),
+    ('\u{21613}', '\u{21613}', 76243),
+    ('\u{21619}', '\u{21619}', 76244),
+    ('\u{2161B}', '\u{2161B}', 76245),
+    ('\u{2161D}', '\u{2161E}', 76246),
+    ('\u{21621}', '\u{21621}', 76248),
+    ('\u{21627}', '\u{21627}', 76249),
+    ('\u{2162A}', '\u{2162A}', 76250),
+    ('\u{2162D}', '\u{21630}', 76251),
+    ('\u{21634}', '\u{21634}', 76255),
+    ('\u{2163E}', '\u{2163E}', 76256),
+    ('\u{21640}', '\u{21640}', 76257),
+    ('\u{21645}', '\u{21645}', 76258),
+    ('\u{2164B}', '\u{2164B}', 76259),
+    ('\u{2164E}', '\u{2164E}', 76260),
+    ('\u{21650}', '\u{21653}', 76261),
+    ('\u{21658}', '\u{21658}', 76265),
+    ('\u{2165B}', '\u{2165B}', 76266),
+    ('\u{21661}', '\u{21661}', 76267),
+    ('\u{21663}', '\u{21663}', 76268),
+    ('\u{21666}', '\u{21667}', 76269),
+    ('\u{2166A}', '\u{2166A}', 76271),
+    ('\u{2166C}', '\u{2166D}', 76272),
+    ('\u{21674}', '\u{21676}', 76274),
+    ('\u{21681}', '\u{21681}', 76277),
+    ('\u{21683}', '\u{21685}', 76278),
+    ('\u{21687}', '\u{21688}', 76281),
+    ('\u{2168A}', '\u{2168A}', 76283),
+    ('\u{2168C}', '\u{2168C}', 76284),
+    ('\u{2168E}', '\u{2168E}', 76285),
+    ('\u{21691}', '\u{21692}', 76286),
+    ('\u{21694}', '\u{21694}', 76288),
+    ('\u{21697}', '\u{21697}', 76289),
+    ('\u{21699}', '\u{2169D}', 76290),
+    ('\u{2169F}', '\u{216A2}', 76295),
+    ('\u{216A6}', '\u{216A6}', 76299),
+    ('\u{216A8}', '\u{216A8}', 76300),
+    ('\u{216AD}', '\u{216AE}', 76301),
+    ('\u{216B7}', '\u{216BD}', 76303),
+    ('\u{216C0}', '\u{216C2}', 76310),
+    ('\u{216CC}', '\u{216CC}', 76313),
+    ('\u{216D3}', '\u{216D5}', 76314),
+    ('\u{216D7}', '\u{216D9}', 76317),
+    ('\u{216DC}', '\u{216E1}', 76320),
+    ('\u{216E4}', '\u{216E4}', 76326),
+    ('\u{216E6}', '\u{216E6}', 76327),
+    ('\u{216E8}', '\u{216E8}', 76328),
+    ('\u{216F5}', '\u{216F6}', 76329),
+    ('\u{216FA}', '\u{216FD}', 76331),
+    ('\u{21700}', '\u{21703}', 76335),
+    ('\u{21709}', '\u{2170B}', 76339),
+    ('\u{2170D}', '\u{2170D}', 76342),
+    ('\u{21710}', '\u{21710}', 76343),
+    ('\u{2171A}', '\u{2171A}', 76344),
+    ('\u{21722}', '\u{21722}', 76345),
+    ('\u{21724}', '\u{21728}', 76346),
+    ('\u{2172B}', '\u{2172C}', 76351),
+    ('\u{2172F}', '\u{2172F}', 76353),
+    ('\u{21731}', '\u{21733}', 76354),
+    ('\u{21735}', '\u{21735}', 76357),
+    ('\u{2173A}', '\u{2173C}', 76358),
+    ('\u{21743}', '\u{21743}', 76361),
+    ('\u{21748}', '\u{2174B}', 76362),
+    ('\u{2174D}', '\u{2174D}', 76366),
+    ('\u{21750}', '\u{21750}', 76367),
+    ('\u{21752}', '\u{21757}', 76368),
+    ('\u{21759}', '\u{2175D}', 76374),
+    ('\u{2175F}', '\u{21760}', 76379),
+    ('\u{21764}', '\u{21764}', 76381),
+    ('\u{21766}', '\u{21767}', 76382),
+    ('\u{21769}', '\u{21769}', 76384),
+    ('\u{2176B}', '\u{21771}', 76385),
+    ('\u{21773}', '\u{21774}', 76392),
+    ('\u{2178B}', '\u{2178B}', 76394),
+    ('\u{21795}', '\u{2179A}', 76395),
+    ('\u{2179C}', '\u{2179C}', 76401),
+    ('\u{2179E}', '\u{217A0}', 76402),
+    ('\u{217A2}', '\u{217A3}', 76405),
+    ('\u{217A5}', '\u{217A7}', 76407),
+    ('\u{217AA}', '\u{217AB}', 76410),
+    ('\u{217AD}', '\u{217AD}', 76412),
+    ('\u{217AF}', '\u{217B5}', 76413),
+    ('\u{217BE}', '\u{217BE}', 76420),
+    ('\u{217C3}', '\u{217C3}', 76421),
+    ('\u{217C7}', '\u{217C7}', 76422),
+    ('\u{217CD}', '\u{217CD}', 76423),
+    ('\u{217D1}', '\u{217D1}', 76424),
+    ('\u{217D3}', '\u{217D3}', 76425),
+    ('\u{217D5}', '\u{217D5}', 76426),
+    ('\u{217D9}', '\u{217DC}', 76427),
+    ('\u{217DE}', '\u{217E0}', 76431),
+    ('\u{217E2}', '\u{217E5}', 76434),
+    ('\u{217E8}', '\u{217E9}', 76438),
+    ('\u{217EB}', '\u{217ED}', 76440),
+    ('\u{217EF}', '\u{217EF}', 76443),
+    ('\u{217F3}', '\u{217F3}', 76444),
+    ('\u{217F5}', '\u{217F5}', 76445),
+    ('\u{217F9}', '\u{217FC}', 76446),
+    ('\u{21806}', '\u{21806}', 76450),
+    ('\u{2180A}', '\u{2180C}', 76451),
+    ('\u{2180F}', '\u{2180F}', 76454),
+    ('\u{21812}', '\u{21812}', 76455),
+    ('\u{21816}', '\u{2181A}', 76456),
+    ('\u{2181C}', '\u{2181C}', 76461),
+    ('\u{2181E}', '\u{21820}', 76462),
+    ('\u{21823}', '\u{21823}', 76465),
+    ('\u{21825}', '\u{21825}', 76466),
+    ('\u{21828}', '\u{2182A}', 76467),
+    ('\u{2182D}', '\u{2182D}', 76470),
+    ('\u{21839}', '\u{2183C}', 76471),
+    ('\u{2183F}', '\u{21840}', 76475),
+    ('\u{21842}', '\u{21842}', 76477),
+    ('\u{21844}', '\u{2184A}', 76478),
+    ('\u{2184E}', '\u{21852}', 76485),
+    ('\u{21854}', '\u{21857}', 76490),
+    ('\u{2185E}', '\u{2185E}', 76494),
+    ('\u{21861}', '\u{21867}', 76495),
+    ('\u{21877}', '\u{21877}', 76502),
+    ('\u{2187A}', '\u{2187B}', 76503),
+    ('\u{21880}', '\u{21880}', 76505),
+    ('\u{21883}', '\u{21885}', 76506),
+    ('\u{21888}', '\u{21888}', 76509),
+    ('\u{2188A}', '\u{2188A}', 76510),
+    ('\u{21890}', '\u{21890}', 76511),
+    ('\u{21892}', '\u{21892}', 76512),
+    ('\u{21895}', '\u{21896}', 76513),
+    ('\u{21898}', '\u{21898}', 76515),
+    ('\u{2189A}', '\u{2189A}', 76516),
+    ('\u{2189C}', '\u{2189C}', 76517),
+    ('\u{2189E}', '\u{218A2}', 76518),
+    ('\u{218B1}', '\u{218B1}', 76523),
+    ('\u{218B3}', '\u{218B3}', 76524),
+    ('\u{218B9}', '\u{218B9}', 76525),
+    ('\u{218BB}', '\u{218BE}', 76526),
+    ('\u{218C0}', '\u{218C2}', 76530),
+    ('\u{218C4}', '\u{218C4}', 76533),
+    ('\u{218C8}', '\u{218C8}', 76534),
+    ('\u{218CB}', '\u{218CB}', 76535),
+    ('\u{218D4}', '\u{218D9}', 76536),
+    ('\u{218E0}', '\u{218E0}', 76542),
+    ('\u{218E2}', '\u{218E2}', 76543),
+    ('\u{218E7}', '\u{218E8}', 76544),
+    ('\u{218EA}', '\u{218EA}', 76546),
+    ('\u{218EC}', '\u{218EC}', 76547),
+    ('\u{218EE}', '\u{218EF}', 76548),
+    ('\u{218F9}', '\u{218FB}', 76550),
+    ('\u{218FD}', '\u{218FE}', 76553),
+    ('\u{21903}', '\u{21905}', 76555),
+    ('\u{2190A}', '\u{2190C}', 76558),
+    ('\u{2190E}', '\u{2190E}', 76561),
+    ('\u{21910}', '\u{21915}', 76562),
+    ('\u{21917}', '\u{21917}', 76568),
+    ('\u{21919}', '\u{21919}', 76569),
+    ('\u{2191B}', '\u{2191C}', 76570),
+    ('\u{2191E}', '\u{21920}', 76572),
+    ('\u{21922}', '\u{21922}', 76575),
+    ('\u{21927}', '\u{21927}', 76576),
+    ('\u{2192B}', '\u{2192B}', 76577),
+    ('\u{21936}', '\u{21936}', 76578),
+    ('\u{2193B}', '\u{2193B}', 76579),
+    ('\u{2193D}', '\u{2193D}', 76580),
+    ('\u{2193F}', '\u{2193F}', 76581),
+    ('\u{21941}', '\u{21941}', 76582),
+    ('\u{21944}', '\u{21944}', 76583),
+    ('\u{21946}', '\u{21946}', 76584),
+    ('\u{21948}', '\u{21949}', 76585),
+    ('\u{2194B}', '\u{2194E}', 76587),
+    ('\u{21958}', '\u{2195A}', 76591),
+    ('\u{2195E}', '\u{2195E}', 76594),
+    ('\u{21964}', '\u{2196A}', 76595),
+    ('\u{21974}', '\u{21976}', 76602),
+    ('\u{21978}', '\u{21978}', 76605),
+    ('\u{2197C}', '\u{2197D}', 76606),
+    ('\u{2197F}', '\u{21986}', 76608),
+    ('\u{21988}', '\u{21988}', 76616),
+    ('\u{2198A}', '\u{2198A}', 76617),
+    ('\u{2198D}', '\u{2198E}', 76618),
+    ('\u{21994}', '\u{21996}', 76620),
+    ('\u{2199B}', '\u{2199D}', 76623),
+    ('\u{2199F}', '\u{2199F}', 76626),
+    ('\u{219A7}', '\u{219A8}', 76627),
+    ('\u{219AA}', '\u{219AB}', 76629),
+    ('\u{219AE}', '\u{219AE}', 76631),
+    ('\u{219B3}', '\u{219B3}', 76632),
+    ('\u{219B6}', '\u{219B7}', 76633),
+    ('\u{219BB}', '\u{219BC}', 76635),
+    ('\u{219CC}', '\u{219CD}', 76637),
+    ('\u{219D2}', '\u{219D2}', 76639),
+    ('\u{219D4}', '\u{219D7}', 76640),
+    ('\u{219D9}', '\u{219D9}', 76644),
+    ('\u{219DB}', '\u{219DB}', 76645),
+    ('\u{219E3}', '\u{219E3}', 76646),
+    ('\u{219E5}', '\u{219E6}', 76647),
+    ('\u{219E8}', '\u{219E9}', 76649),
+    ('\u{219ED}', '\u{219ED}', 76651),
+    ('\u{219F2}', '\u{219F3}', 76652),
+    ('\u{219F9}', '\u{219F9}', 76654),
+    ('\u{219FC}', '\u{219FD}', 76655),
+    ('\u{21A00}', '\u{21A00}', 76657),
+    ('\u{21A04}', '\u{21A05}', 76658),
+    ('\u{21A07}', '\u{21A07}', 76660),
+    ('\u{21A0C}', '\u{21A0C}', 76661),
+    ('\u{21A16}', '\u{21A17}', 76662),
+    ('\u{21A1B}', '\u{21A1B}', 76664),
+    ('\u{21A22}', '\u{21A23}', 76665),
+    ('\u{21A27}', '\u{21A27}', 76667),
+    ('\u{21A29}', '\u{21A29}', 76668),
+    ('\u{21A2D}', '\u{21A2D}', 76669),
+    ('\u{21A32}', '\u{21A35}', 76670),
+    ('\u{21A38}', '\u{21A3B}', 76674),
+    ('\u{21A3D}', '\u{21A3D}', 76678),
+    ('\u{21A3F}', '\u{21A3F}', 76679),
+    ('\u{21A42}', '\u{21A47}', 76680),
+    ('\u{21A4B}', '\u{21A4B}', 76686),
+    ('\u{21A58}', '\u{21A58}', 76687),
+    ('\u{21A5A}', '\u{21A5A}', 76688),
+    ('\u{21A61}', '\u{21A61}', 76689),
+    ('\u{21A63}', '\u{21A65}', 76690),
+    ('\u{21A69}', '\u{21A69}', 76693),
+    ('\u{21A76}', '\u{21A76}', 76694),
+    ('\u{21A78}', '\u{21A78}', 76695),
+    ('\u{21A7D}', '\u{21A7E}', 76696),
+    ('\u{21A81}', '\u{21A81}', 76698),
+    ('\u{21A85}', '\u{21A85}', 76699),
+    ('\u{21A87}', '\u{21A87}', 76700),
+    ('\u{21A91}', '\u{21A92}', 76701),
+    ('\u{21A97}', '\u{21A97}', 76703),
+    ('\u{21A99}', '\u{21A99}', 76704),
+    ('\u{21A9C}', '\u{21A9C}', 76705),
+    ('\u{21A9E}', '\u{21A9E}', 76706),
+    ('\u{21AA0}', '\u{21AA0}', 76707),
+    ('\u{21AA6}', '\u{21AA6}', 76708),
+    ('\u{21AB0}', '\u{21AB1}', 76709),
+    ('\u{21AB3}', '\u{21AB3}', 76711),
+    ('\u{21AB5}', '\u{21AB5}', 76712),
+    ('\u{21ABA}', '\u{21ABB}', 76713),
+    ('\u{21AC0}', '\u{21AC3}', 76715),
+    ('\u{21AD0}', '\u{21AD0}', 76719),
+    ('\u{21ADF}', '\u{21ADF}', 76720),
+    ('\u{21AE1}', '\u{21AE1}', 76721),
+    ('\u{21AE5}', '\u{21AE8}', 76722),
+    ('\u{21AEB}', '\u{21AEC}', 76726),
+    ('\u{21AEF}', '\u{21AEF}', 76728),
+    ('\u{21AF5}', '\u{21AF6}', 76729),
+    ('\u{21AF8}', '\u{21AFA}', 76731),
+    ('\u{21AFD}', '\u{21AFD}', 76734),
+    ('\u{21B01}', '\u{21B02}', 76735),
+    ('\u{21B04}', '\u{21B04}', 76737),
+    ('\u{21B06}', '\u{21B0E}', 76738),
+    ('\u{21B10}', '\u{21B10}', 76747),
+    ('\u{21B13}', '\u{21B13}', 76748),
+    ('\u{21B15}', '\u{21B19}', 76749),
+    ('\u{21B1C}', '\u{21B1C}', 76754),
+    ('\u{21B1E}', '\u{21B1E}', 76755),
+    ('\u{21B25}', '\u{21B25}', 76756),
+    ('\u{21B2B}', '\u{21B2B}', 76757),
+    ('\u{21B2F}', '\u{21B2F}', 76758),
+    ('\u{21B32}', '\u{21B33}', 76759),
+    ('\u{21B35}', '\u{21B38}', 76761),
+    ('\u{21B3C}', '\u{21B3C}', 76765),
+    ('\u{21B42}', '\u{21B42}', 76766),
+    ('\u{21B44}', '\u{21B44}', 76767),
+    ('\u{21B47}', '\u{21B47}', 76768),
+    ('\u{21B49}', '\u{21B49}', 76769),
+    ('\u{21B4B}', '\u{21B4B}', 76770),
+    ('\u{21B50}', '\u{21B50}', 76771),
+    ('\u{21B55}', '\u{21B55}', 76772),
+    ('\u{21B58}', '\u{21B58}', 76773),
+    ('\u{21B5B}', '\u{21B5C}', 76774),
+    ('\u{21B5E}', '\u{21B5E}', 76776),
+    ('\u{21B62}', '\u{21B63}', 76777),
+    ('\u{21B65}', '\u{21B67}', 76779),
+    ('\u{21B6C}', '\u{21B6E}', 76782),
+    ('\u{21B70}', '\u{21B74}', 76785),
+    ('\u{21B77}', '\u{21B78}', 76790),
+    ('\u{21B7A}', '\u{21B7B}', 76792),
+    ('\u{21B7D}', '\u{21B7F}', 76794),
+    ('\u{21B82}', '\u{21B82}', 76797),
+    ('\u{21B84}', '\u{21B8E}', 76798),
+    ('\u{21B92}', '\u{21B94}', 76809),
+    ('\u{21B97}', '\u{21B9C}', 76812),
+    ('\u{21B9E}', '\u{21B9E}', 76818),
+    ('\u{21BA0}', '\u{21BA0}', 76819),
+    ('\u{21BA3}', '\u{21BA6}', 76820),
+    ('\u{21BA8}', '\u{21BB0}', 76824),
+    ('\u{21BB2}', '\u{21BB3}', 76833),
+    ('\u{21BB5}', '\u{21BBB}', 76835),
+    ('\u{21BBD}', '\u{21BC2}', 76842),
+    ('\u{21BC4}', '\u{21BC4}', 76848),
+    ('\u{21BC7}', '\u{21BC9}', 76849),
+    ('\u{21BCB}', '\u{21BCB}', 76852),
+    ('\u{21BCE}', '\u{21BD2}', 76853),
+    ('\u{21BD8}', '\u{21BD9}', 76858),
+    ('\u{21BE2}', '\u{21BE2}', 76860),
+    ('\u{21BE5}', '\u{21BE5}', 76861),
+    ('\u{21BE8}', '\u{21BE9}', 76862),
+    ('\u{21BEB}', '\u{21BEB}', 76864),
+    ('\u{21BF0}', '\u{21BF1}', 76865),
+    ('\u{21BF3}', '\u{21BF5}', 76867),
+    ('\u{21BF7}', '\u{21BF7}', 76870),
+    ('\u{21BFD}', '\u{21BFD}', 76871),
+    ('\u{21BFF}', '\u{21C00}', 76872),
+    ('\u{21C05}', '\u{21C06}', 76874),
+    ('\u{21C0B}', '\u{21C0C}', 76876),
+    ('\u{21C0E}', '\u{21C12}', 76878),
+    ('\u{21C16}', '\u{21C16}', 76883),
+    ('\u{21C1A}', '\u{21C1A}', 76884),
+    ('\u{21C1D}', '\u{21C1E}', 76885),
+    ('\u{21C20}', '\u{21C20}', 76887),
+    ('\u{21C22}', '\u{21C22}', 76888),
+    ('\u{21C25}', '\u{21C25}', 76889),
+    ('\u{21C28}', '\u{21C28}', 76890),
+    ('\u{21C2A}', '\u{21C2B}', 76891),
+    ('\u{21C2F}', '\u{21C2F}', 76893),
+    ('\u{21C32}', '\u{21C32}', 76894),
+    ('\u{21C38}', '\u{21C39}', 76895),
+    ('\u{21C3D}', '\u{21C3F}', 76897),
+    ('\u{21C41}', '\u{21C41}', 76900),
+    ('\u{21C45}', '\u{21C47}', 76901),
+    ('\u{21C49}', '\u{21C4A}', 76904),
+    ('\u{21C4C}', '\u{21C4C}', 76906),
+    ('\u{21C50}', '\u{21C51}', 76907),
+    ('\u{21C54}', '\u{21C54}', 76909),
+    ('\u{21C56}', '\u{21C56}', 76910),
+    ('\u{21C5C}', '\u{21C5C}', 76911),
+    ('\u{21C63}', '\u{21C63}', 76912),
+    ('\u{21C69}', '\u{21C69}', 76913),
+    ('\u{21C6F}', '\u{21C71}', 76914),
+    ('\u{21C73}', '\u{21C73}', 76917),
+    ('\u{21C76}', '\u{21C78}', 76918),
+    ('\u{21C7A}', '\u{21C7A}', 76921),
+    ('\u{21C7C}', '\u{21C7E}', 76922),
+    ('\u{21C80}', '\u{21C80}', 76925),
+    ('\u{21C88}', '\u{21C88}', 76926),
+    ('\u{21C8D}', '\u{21C8D}', 76927),
+    ('\u{21C95}', '\u{21C95}', 76928),
+    ('\u{21C97}', '\u{21C97}', 76929),
+    ('\u{21C9A}', '\u{21C9A}', 76930),
+    ('\u{21CA2}', '\u{21CA5}', 76931),
+    ('\u{21CAA}', '\u{21CAE}', 76935),
+    ('\u{21CB0}', '\u{21CB0}', 76940),
+    ('\u{21CBC}', '\u{21CBC}', 76941),
+    ('\u{21CBF}', '\u{21CC4}', 76942),
+    ('\u{21CC6}', '\u{21CC6}', 76948),
+    ('\u{21CCE}', '\u{21CCF}', 76949),
+    ('\u{21CD1}', '\u{21CD2}', 76951),
+    ('\u{21CD8}', '\u{21CD8}', 76953),
+    ('\u{21CDA}', '\u{21CDE}', 76954),
+    ('\u{21CE2}', '\u{21CE5}', 76959),
+    ('\u{21CE9}', '\u{21CEA}', 76963),
+    ('\u{21CED}', '\u{21CEE}', 76965),
+    ('\u{21CF0}', '\u{21CF0}', 76967),
+    ('\u{21CF3}', '\u{21CF6}', 76968),
+    ('\u{21CF8}', '\u{21CFA}', 76972),
+    ('\u{21CFD}', '\u{21CFE}', 76975),
+    ('\u{21D05}', '\u{21D06}', 76977),
+    ('\u{21D09}', '\u{21D09}', 76979),
+    ('\u{21D0E}', '\u{21D0E}', 76980),
+    ('\u{21D14}', '\u{21D14}', 76981),
+    ('\u{21D22}', '\u{21D22}', 76982),
+    ('\u{21D2D}', '\u{21D2D}', 76983),
+    ('\u{21D2F}', '\u{21D2F}', 76984),
+    ('\u{21D31}', '\u{21D32}', 76985),
+    ('\u{21D40}', '\u{21D41}', 76987),
+    ('\u{21D46}', '\u{21D46}', 76989),
+    ('\u{21D49}', '\u{21D49}', 76990),
+    ('\u{21D4C}', '\u{21D4C}', 76991),
+    ('\u{21D51}', '\u{21D53}', 76992),
+    ('\u{21D55}', '\u{21D5E}', 76995),
+    ('\u{21D6C}', '\u{21D6C}', 77005),
+    ('\u{21D76}', '\u{21D77}', 77006),
+    ('\u{21D7A}', '\u{21D7B}', 77008),
+    ('\u{21D80}', '\u{21D84}', 77010),
+    ('\u{21D86}', '\u{21D86}', 77015),
+    ('\u{21D88}', '\u{21D89}', 77016),
+    ('\u{21D8B}', '\u{21D8C}', 77018),
+    ('\u{21D8E}', '\u{21D91}', 77020),
+    ('\u{21DA2}', '\u{21DA6}', 77024),
+    ('\u{21DA8}', '\u{21DA8}', 77029),
+    ('\u{21DAA}', '\u{21DAB}', 77030),
+    ('\u{21DAD}', '\u{21DAD}', 77032),
+    ('\u{21DAF}', '\u{21DAF}', 77033),
+    ('\u{21DB1}', '\u{21DB2}', 77034),
+    ('\u{21DB4}', '\u{21DB4}', 77036),
+    ('\u{21DB6}', '\u{21DB6}', 77037),
+    ('\u{21DBA}', '\u{21DBA}', 77038),
+    ('\u{21DC8}', '\u{21DC8}', 77039),
+    ('\u{21DCA}', '\u{21DCB}', 77040),
+    ('\u{21DCD}', '\u{21DD1}', 77042),
+    ('\u{21DD3}', '\u{21DD3}', 77047),
+    ('\u{21DD5}', '\u{21DD8}', 77048),
+    ('\u{21DDB}', '\u{21DDE}', 77052),
+    ('\u{21DE0}', '\u{21DE2}', 77056),
+    ('\u{21DE4}', '\u{21DE5}', 77059),
+    ('\u{21DE8}', '\u{21DE8}', 77061),
+    ('\u{21DEA}', '\u{21DEB}', 77062),
+    ('\u{21DF8}', '\u{21DFD}', 77064),
+    ('\u{21DFF}', '\u{21DFF}', 77070),
+    ('\u{21E02}', '\u{21E03}', 77071),
+    ('\u{21E07}', '\u{21E09}', 77073),
+    ('\u{21E0C}', '\u{21E0C}', 77076),
+    ('\u{21E0E}', '\u{21E0F}', 77077),
+    ('\u{21E11}', '\u{21E11}', 77079),
+    ('\u{21E14}', '\u{21E15}', 77080),
+    ('\u{21E17}', '\u{21E18}', 77082),
+    ('\u{21E1B}', '\u{21E1C}', 77084),
+    ('\u{21E1E}', '\u{21E1F}', 77086),
+    ('\u{21E21}', '\u{21E21}', 77088),
+    ('\u{21E23}', '\u{21E26}', 77089),
+    ('\u{21E28}', '\u{21E28}', 77093),
+    ('\u{21E2A}', '\u{21E2A}', 77094),
+    ('\u{21E37}', '\u{21E37}', 77095),
+    ('\u{21E3D}', '\u{21E3D}', 77096),
+    ('\u{21E44}', '\u{21E44}', 77097),
+    ('\u{21E47}', '\u{21E47}', 77098),
+    ('\u{21E49}', '\u{21E49}', 77099),
+    ('\u{21E4E}', '\u{21E4E}', 77100),
+    ('\u{21E53}', '\u{21E53}', 77101),
+    ('\u{21E55}', '\u{21E56}', 77102),
+    ('\u{21E58}', '\u{21E59}', 77104),
+    ('\u{21E5E}', '\u{21E63}', 77106),
+    ('\u{21E65}', '\u{21E65}', 77112),
+    ('\u{21E68}', '\u{21E6A}', 77113),
+    ('\u{21E6C}', '\u{21E6D}', 77116),
+    ('\u{21E6F}', '\u{21E70}', 77118),
+    ('\u{21E72}', '\u{21E72}', 77120),
+    ('\u{21E75}', '\u{21E79}', 77121),
+    ('\u{21E7C}', '\u{21E7C}', 77126),
+    ('\u{21E7E}', '\u{21E7E}', 77127),
+    ('\u{21E83}', '\u{21E83}', 77128),
+    ('\u{21E87}', '\u{21E87}', 77129),
+    ('\u{21E89}', '\u{21E89}', 77130),
+    ('\u{21E90}', '\u{21E98}', 77131),
+    ('\u{21E9B}', '\u{21E9C}', 77140),
+    ('\u{21E9F}', '\u{21E9F}', 77142),
+    ('\u{21EA1}', '\u{21EA1}', 77143),
+    ('\u{21EA4}', '\u{21EA8}', 77144),
+    ('\u{21EAB}', '\u{21EAB}', 77149),
+    ('\u{21EAD}', '\u{21EAF}', 77150),
+    ('\u{21EB4}', '\u{21EB5}', 77153),
+    ('\u{21EB7}', '\u{21EB8}', 77155),
+    ('\u{21EBA}', '\u{21EBA}', 77157),
+    ('\u{21EBD}', '\u{21EBD}', 77158),
+    ('\u{21EC8}', '\u{21EC8}', 77159),
+    ('\u{21ECC}', '\u{21ECC}', 77160),
+    ('\u{21ECE}', '\u{21ECE}', 77161),
+    ('\u{21ED0}', '\u{21ED1}', 77162),
+    ('\u{21ED3}', '\u{21EDA}', 77164),
+    ('\u{21EDD}', '\u{21EE4}', 77172),
+    ('\u{21EE7}', '\u{21EF1}', 77180),
+    ('\u{21F01}', '\u{21F01}', 77191),
+    ('\u{21F03}', '\u{21F04}', 77192),
+    ('\u{21F07}', '\u{21F08}', 77194),
+    ('\u{21F0A}', '\u{21F0A}', 77196),
+    ('\u{21F0E}', '\u{21F0F}', 77197),
+    ('\u{21F13}', '\u{21F13}', 77199),
+    ('\u{21F15}', '\u{21F18}', 77200),
+    ('\u{21F1A}', '\u{21F1C}', 77204),
+    ('\u{21F2C}', '\u{21F2C}', 77207),
+    ('\u{21F31}', '\u{21F31}', 77208),
+    ('\u{21F38}', '\u{21F39}', 77209),
+    ('\u{21F3B}', '\u{21F3F}', 77211),
+    ('\u{21F41}', '\u{21F42}', 77216),
+    ('\u{21F45}', '\u{21F46}', 77218),
+    ('\u{21F49}', '\u{21F4A}', 77220),
+    ('\u{21F56}', '\u{21F57}', 77222),
+    ('\u{21F5B}', '\u{21F5D}', 77224),
+    ('\u{21F60}', '\u{21F60}', 77227),
+    ('\u{21F62}', '\u{21F63}', 77228),
+    ('\u{21F67}', '\u{21F67}', 77230),
+    ('\u{21F6A}', '\u{21F6B}', 77231),
+    ('\u{21F71}', '\u{21F74}', 77233),
+    ('\u{21F76}', '\u{21F76}', 77237),
+    ('\u{21F7B}', '\u{21F7C}', 77238),
+    ('\u{21F7E}', '\u{21F7E}', 77240),
+    ('\u{21F82}', '\u{21F82}', 77241),
+    ('\u{21F85}', '\u{21F85}', 77242),
+    ('\u{21F87}', '\u{21F87}', 77243),
+    ('\u{21F89}', '\u{21F89}', 77244),
+    ('\u{21F8B}', '\u{21F8C}', 77245),
+    ('\u{21F92}', '\u{21F93}', 77247),
+    ('\u{21F95}', '\u{21F96}', 77249),
+    ('\u{21F99}', '\u{21F99}', 77251),
+    ('\u{21F9B}', '\u{21F9C}', 77252),
+    ('\u{21F9E}', '\u{21F9F}', 77254),
+    ('\u{21FA1}', '\u{21FA1}', 77256),
+    ('\u{21FA5}', '\u{21FA6}', 77257),
+    ('\u{21FA8}', '\u{21FA8}', 77259),
+    ('\u{21FAB}', '\u{21FAE}', 77260),
+    ('\u{21FB0}', '\u{21FB2}', 77264),
+    ('\u{21FB5}', '\u{21FB5}', 77267),
+    ('\u{21FBB}', '\u{21FBE}', 77268),
+    ('\u{21FC2}', '\u{21FC2}', 77272),
+    ('\u{21FC4}', '\u{21FC5}', 77273),
+    ('\u{21FC9}', '\u{21FCB}', 77275),
+    ('\u{21FCE}', '\u{21FCF}', 77278),
+    ('\u{21FD3}', '\u{21FD3}', 77280),
+    ('\u{21FD6}', '\u{21FD7}', 77281),
+    ('\u{21FD9}', '\u{21FD9}', 77283),
+    ('\u{21FDB}', '\u{21FDB}', 77284),
+    ('\u{21FDD}', '\u{21FDD}', 77285),
+    ('\u{21FE0}', '\u{21FE1}', 77286),
+    ('\u{21FE4}', '\u{21FE5}', 77288),
+    ('\u{21FE7}', '\u{21FE9}', 77290),
+    ('\u{21FEF}', '\u{21FF0}', 77293),
+    ('\u{21FFA}', '\u{21FFA}', 77295),
+    ('\u{21FFC}', '\u{21FFC}', 77296),
+    ('\u{21FFE}', '\u{21FFF}', 77297),
+    ('\u{22001}', '\u{22001}', 77299),
+    ('\u{2200A}', '\u{2200D}', 77300),
+    ('\u{22015}', '\u{22019}', 77304),
+    ('\u{2201C}', '\u{2201C}', 77309),
+    ('\u{22021}', '\u{22021}', 77310),
+    ('\u{22025}', '\u{22028}', 77311),
+    ('\u{2202A}', '\u{2202E}', 77315),
+    ('\u{22031}', '\u{22032}', 77320),
+    ('\u{22035}', '\u{22035}', 77322),
+    ('\u{2203C}', '\u{2203C}', 77323),
+    ('\u{22040}', '\u{22042}', 77324),
+    ('\u{22045}', '\u{22045}', 77327),
+    ('\u{22049}', '\u{2204B}', 77328),
+    ('\u{2204D}', '\u{2204D}', 77331),
+    ('\u{2204F}', '\u{2204F}', 77332),
+    ('\u{22051}', '\u{22053}', 77333),
+    ('\u{22055}', '\u{22058}', 77336),
+    ('\u{2205D}', '\u{2205E}', 77340),
+    ('\u{22060}', '\u{22060}', 77342),
+    ('\u{22062}', '\u{22062}', 77343),
+    ('\u{22064}', '\u{22064}', 77344),
+    ('\u{22067}', '\u{22067}', 77345),
+    ('\u{22069}', '\u{2206A}', 77346),
+    ('\u{2206C}', '\u{2206C}', 77348),
+    ('\u{2206E}', '\u{2206E}', 77349),
+    ('\u{22071}', '\u{22071}', 77350),
+    ('\u{22076}', '\u{22079}', 77351),
+    ('\u{2207B}', '\u{22081}', 77355),
+    ('\u{22083}', '\u{22084}', 77362),
+    ('\u{22086}', '\u{22086}', 77364),
+    ('\u{2208A}', '\u{2208A}', 77365),
+    ('\u{2208C}', '\u{22092}', 77366),
+    ('\u{22094}', '\u{22095}', 77373),
+    ('\u{22097}', '\u{22098}', 77375),
+    ('\u{2209A}', '\u{2209A}', 77377),
+    ('\u{2209C}', '\u{2209D}', 77378),
+    ('\u{220A4}', '\u{220A4}', 77380),
+    ('\u{220A8}', '\u{220A8}', 77381),
+    ('\u{220B0}', '\u{220B1}', 77382),
+    ('\u{220B6}', '\u{220B6}', 77384),
+    ('\u{220B9}', '\u{220BA}', 77385),
+    ('\u{220BC}', '\u{220BC}', 77387),
+    ('\u{220C0}', '\u{220C0}', 77388),
+    ('\u{220C7}', '\u{220CA}', 77389),
+    ('\u{220CD}', '\u{220D0}', 77393),
+    ('\u{220D5}', '\u{220D5}', 77397),
+    ('\u{220D7}', '\u{220D8}', 77398),
+    ('\u{220DC}', '\u{220DC}', 77400),
+    ('\u{220E2}', '\u{220E2}', 77401),
+    ('\u{220E5}', '\u{220E6}', 77402),
+    ('\u{220E9}', '\u{220E9}', 77404),
+    ('\u{220EC}', '\u{220F4}', 77405),
+    ('\u{220F8}', '\u{220F8}', 77414),
+    ('\u{220FA}', '\u{220FA}', 77415),
+    ('\u{220FC}', '\u{220FC}', 77416),
+    ('\u{22100}', '\u{22100}', 77417),
+    ('\u{22102}', '\u{22105}', 77418),
+    ('\u{22107}', '\u{22107}', 77422),
+    ('\u{2210A}', '\u{22110}', 77423),
+    ('\u{22113}', '\u{22114}', 77430),
+    ('\u{22117}', '\u{22117}', 77432),
+    ('\u{2211C}', '\u{2211D}', 77433),
+    ('\u{2211F}', '\u{22124}', 77435),
+    ('\u{22126}', '\u{22127}', 77441),
+    ('\u{22129}', '\u{2212A}', 77443),
+    ('\u{2212F}', '\u{2212F}', 77445),
+    ('\u{22131}', '\u{22131}', 77446),
+    ('\u{22133}', '\u{22133}', 77447),
+    ('\u{22135}', '\u{2213A}', 77448),
+    ('\u{2213C}', '\u{2213D}', 77454),
+    ('\u{2213F}', '\u{22141}', 77456),
+    ('\u{22144}', '\u{22146}', 77459),
+    ('\u{22148}', '\u{2214B}', 77462),
+    ('\u{2214E}', '\u{2214F}', 77466),
+    ('\u{22151}', '\u{22152}', 77468),
+    ('\u{22155}', '\u{22157}', 77470),
+    ('\u{2215A}', '\u{22161}', 77473),
+    ('\u{22164}', '\u{22165}', 77481),
+    ('\u{22167}', '\u{22167}', 77483),
+    ('\u{22169}', '\u{22170}', 77484),
+    ('\u{22173}', '\u{22173}', 77492),
+    ('\u{22179}', '\u{2217A}', 77493),
+    ('\u{2217C}', '\u{2217C}', 77495),
+    ('\u{2217E}', '\u{2217E}', 77496),
+    ('\u{22180}', '\u{22182}', 77497),
+    ('\u{22189}', '\u{22189}', 77500),
+    ('\u{22195}', '\u{22195}', 77501),
+    ('\u{22198}', '\u{22198}', 77502),
+    ('\u{2219C}', '\u{2219C}', 77503),
+    ('\u{2219E}', '\u{221A1}', 77504),
+    ('\u{221A3}', '\u{221A3}', 77508),
+    ('\u{221A5}', '\u{221AB}', 77509),
+    ('\u{221AD}', '\u{221AD}', 77516),
+    ('\u{221AF}', '\u{221B0}', 77517),
+    ('\u{221B4}', '\u{221B4}', 77519),
+    ('\u{221B6}', '\u{221B8}', 77520),
+    ('\u{221BA}', '\u{221BB}', 77523),
+    ('\u{221BD}', '\u{221BD}', 77525),
+    ('\u{221C1}', '\u{221C3}', 77526),
+    ('\u{221C7}', '\u{221C9}', 77529),
+    ('\u{221D5}', '\u{221D5}', 77532),
+    ('\u{221D7}', '\u{221DB}', 77533),
+    ('\u{221DE}', '\u{221DF}', 77538),
+    ('\u{221E1}', '\u{221E2}', 77540),
+    ('\u{221E4}', '\u{221E8}', 77542),
+    ('\u{221EA}', '\u{221EB}', 77547),
+    ('\u{221F0}', '\u{221F8}', 77549),
+    ('\u{22202}', '\u{22202}', 77558),
+    ('\u{22204}', '\u{22204}', 77559),
+    ('\u{22206}', '\u{22209}', 77560),
+    ('\u{2220B}', '\u{2220B}', 77564),
+    ('\u{22213}', '\u{22213}', 77565),
+    ('\u{22215}', '\u{22216}', 77566),
+    ('\u{22218}', '\u{2221D}', 77568),
+    ('\u{22220}', '\u{22222}', 77574),
+    ('\u{22224}', '\u{22224}', 77577),
+    ('\u{2222F}', '\u{2222F}', 77578),
+    ('\u{22231}', '\u{22232}', 77579),
+    ('\u{22234}', '\u{22236}', 77581),
+    ('\u{22238}', '\u{22239}', 77584),
+    ('\u{2223B}', '\u{2223E}', 77586),
+    ('\u{22241}', '\u{22243}', 77590),
+    ('\u{22245}', '\u{22246}', 77593),
+    ('\u{22251}', '\u{22251}', 77595),
+    ('\u{22253}', '\u{22253}', 77596),
+    ('\u{22255}', '\u{22255}', 77597),
+    ('\u{22257}', '\u{22258}', 77598),
+    ('\u{2225A}', '\u{2225A}', 77600),
+    ('\u{2225C}', '\u{2225E}', 77601),
+    ('\u{22262}', '\u{22262}', 77604),
+    ('\u{22264}', '\u{22269}', 77605),
+    ('\u{2226D}', '\u{2226D}', 77611),
+    ('\u{22275}', '\u{22275}', 77612),
+    ('\u{2227A}', '\u{2227A}', 77613),
+    ('\u{2227D}', '\u{22281}', 77614),
+    ('\u{22284}', '\u{22285}', 77619),
+    ('\u{22287}', '\u{22287}', 77621),
+    ('\u{2228D}', '\u{2228D}', 77622),
+    ('\u{2228F}', '\u{2228F}', 77623),
+    ('\u{22291}', '\u{22293}', 77624),
+    ('\u{22295}', '\u{2229C}', 77627),
+    ('\u{222A7}', '\u{222A7}', 77635),
+    ('\u{222A9}', '\u{222A9}', 77636),
+    ('\u{222AB}', '\u{222AB}', 77637),
+    ('\u{222AE}', '\u{222AF}', 77638),
+    ('\u{222B1}', '\u{222B2}', 77640),
+    ('\u{222BB}', '\u{222BB}', 77642),
+    ('\u{222C2}', '\u{222C8}', 77643),
+    ('\u{222D2}', '\u{222D2}', 77650),
+    ('\u{222D4}', '\u{222D4}', 77651),
+    ('\u{222DA}', '\u{222DA}', 77652),
+    ('\u{222DD}', '\u{222DD}', 77653),
+];
+
+/// The offset of each transliteration in [`TRANSLITERATION_TEXT`], shifted left by
+/// [`LENGTH_BITS`], and its length.
+pub(crate) static TRANSLITERATIONS: &[u32] = &[
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 65, 130, 258,
+    386, 514, 641, 706, 833, 899, 1089, 1154, 65, 0, 1283, 1473,
+    1539, 1730, 1857, 1921, 1985, 2049, 2113, 2177, 2241, 2305, 2369, 2434,
+    2563, 2755, 2947, 3137, 3201, 3201, 3201, 3201, 3201, 3201, 3266, 3393,
+    3457, 3457, 3457, 3457, 3521, 3521, 3521, 3521, 3585, 3649, 3713, 3713,
+    3713, 3713, 3713, 3777, 3713, 3841, 3841, 3841, 3841, 3905, 3970, 4098,
+    1089, 1089, 1089, 1089, 1089, 1089, 4226, 4353, 4417, 4417, 4417, 4417,
+    4481, 4481, 4481, 4481, 4545, 4609, 2369, 2369, 2369, 2369, 2369, 4673,
+    2369, 2049, 2049, 2049, 2049, 4737, 4802, 4737, 3201, 1089, 3201, 1089,
+    3201, 1089, 3393, 4353, 3393, 4353, 3393, 4353, 3393, 4353, 3585, 4545,
+    3585, 4545, 3457, 4417, 3457, 4417, 3457, 4417, 3457, 4417, 3457, 4417,
+    4929, 4993, 4929, 4993, 4929, 4993, 4929, 4993, 5057, 5121, 5057, 5121,
+    3521, 4481, 3521, 4481, 3521, 4481, 3521, 4481, 3521, 4481, 5186, 5314,
+    5441, 5505, 5569, 5633, 5633, 5697, 5761, 5697, 5761, 5697, 5761, 5697,
+    5761, 5697, 5761, 3649, 4609, 3649, 4609, 3649, 4609, 5826, 5954, 6082,
+    3713, 2369, 3713, 2369, 3713, 2369, 6210, 6338, 6465, 6529, 6465, 6529,
+    6465, 6529, 6593, 6657, 6593, 6657, 6593, 6657, 6593, 6657, 6721, 6785,
+    6721, 6785, 6721, 6785, 3841, 2049, 3841, 2049, 3841, 2049, 3841, 2049,
+    3841, 2049, 3841, 2049, 6849, 6913, 3905, 4737, 3905, 6977, 7041, 6977,
+    7041, 6977, 7041, 6657, 7105, 7169, 7169, 7105, 7233, 7233, 3713, 3393,
+    4353, 3585, 3585, 3585, 4545, 4545, 1921, 7297, 3457, 7361, 7425, 4929,
+    4929, 7490, 3521, 3521, 5569, 5633, 5761, 5761, 6849, 3649, 4609, 3713,
+    3713, 2369, 7618, 7746, 2113, 7873, 7938, 1857, 1857, 8066, 8194, 6785,
+    6721, 6785, 6721, 3841, 2049, 3905, 8321, 3905, 4737, 6977, 7041, 8386,
+    8386, 8514, 8514, 1857, 8641, 8641, 8706, 6913, 641, 8834, 8962, 65,
+    9090, 9218, 9346, 9474, 9602, 9730, 9858, 9986, 10114, 3201, 1089, 3521,
+    4481, 3713, 2369, 3841, 2049, 3841, 2049, 3841, 2049, 3841, 2049, 3841,
+    2049, 7297, 3201, 1089, 3201, 1089, 3266, 4226, 4929, 4993, 4929, 4993,
+    5569, 5633, 3713, 2369, 3713, 2369, 8386, 8514, 5505, 9090, 9218, 9346,
+    4929, 4993, 10242, 6849, 3649, 4609, 3201, 1089, 3266, 4226, 3713, 2369,
+    3201, 1089, 3201, 1089, 3457, 4417, 3457, 4417, 3521, 4481, 3521, 4481,
+    3713, 2369, 3713, 2369, 6465, 6529, 6465, 6529, 3841, 2049, 3841, 2049,
+    6593, 6657, 6721, 6785, 3905, 4737, 5057, 5121, 3649, 4545, 10370, 10498,
+    6977, 7041, 3201, 1089, 3457, 4417, 3713, 2369, 3713, 2369, 3713, 2369,
+    3713, 2369, 3905, 4737, 5761, 4609, 6785, 5505, 10626, 10754, 3201, 3393,
+    4353, 5697, 6721, 6657, 7041, 1985, 1985, 7169, 3841, 10881, 3457, 4417,
+    5441, 5505, 10945, 10945, 6465, 6529, 3905, 4737, 1089, 1089, 1089, 7105,
+    2369, 4353, 4545, 4545, 4417, 7297, 7297, 4417, 4417, 4417, 4417, 5505,
+    4993, 4993, 4993, 4993, 2049, 3905, 5121, 5121, 4481, 4481, 3521, 5761,
+    5761, 5761, 11010, 6849, 6849, 11137, 4609, 4609, 4609, 2369, 6210, 3713,
+    7361, 6529, 6529, 6529, 6529, 6529, 6529, 6529, 6465, 6465, 6657, 6593,
+    5505, 6593, 6593, 6785, 6785, 2049, 3841, 11201, 10881, 6913, 4737, 3905,
+    7041, 7041, 6977, 6977, 3137, 3137, 3137, 3393, 7297, 7169, 3457, 4929,
+    5057, 5505, 5633, 5697, 10945, 3137, 3137, 9346, 11266, 9346, 8706, 11394,
+    11522, 11650, 11778, 11906, 12034, 12162, 5121, 5121, 5121, 5121, 5505, 6529,
+    6529, 6529, 6529, 6913, 4737, 1985, 833, 12289, 1985, 12289, 12289, 1985,
+    3137, 3137, 12353, 12417, 10881, 8321, 10881, 8321, 1985, 1473, 4673, 12481,
+    2241, 12545, 12481, 4673, 12609, 12673, 12289, 1985, 10881, 8321, 12737, 1473,
+    8321, 12673, 7297, 2241, 12801, 833, 6465, 12865, 4929, 5761, 6657, 3777,
+    3137, 8641, 12929, 1921, 1857, 2305, 4673, 12481, 8321, 12993, 833, 8321,
+    10881, 12353, 12417, 2369, 12289, 13058, 13186, 12801, 12609, 13314, 13442, 13570,
+    13698, 12545, 12545, 12353, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 1089, 4417, 4481, 2369, 2049,
+    4353, 4545, 5121, 11137, 6529, 6785, 11201, 3777, 5057, 5121, 6593, 6657,
+    1985, 2241, 6849, 6913, 4481, 6657, 13826, 13826, 3137, 5441, 1985, 13954,
+    3201, 14081, 3457, 3457, 3521, 3713, 3841, 3713, 3521, 3201, 7169, 4929,
+    3585, 3457, 6977, 3457, 3970, 3521, 5569, 5697, 14145, 3649, 14210, 3713,
+    2113, 6465, 6593, 6721, 3841, 14338, 14466, 14594, 3713, 3521, 3841, 1089,
+    4417, 4417, 4481, 2049, 1089, 7105, 4993, 4545, 4417, 7041, 4417, 4802,
+    4481, 5633, 5761, 11137, 4609, 3777, 2369, 7873, 6529, 6657, 6657, 6785,
+    2049, 14722, 14850, 14978, 2369, 4481, 2049, 2369, 2049, 2369, 15105, 7105,
+    4802, 3841, 3841, 3841, 14722, 7873, 15105, 15169, 10945, 15234, 15362, 6849,
+    6913, 15169, 10945, 15490, 15618, 15746, 8194, 7361, 7425, 14466, 14850, 5057,
+    5121, 4929, 4993, 15874, 16002, 16130, 16258, 5633, 6529, 4353, 5505, 16386,
+    4417, 4417, 15746, 8194, 6657, 6593, 6657, 16514, 6593, 16642, 16642, 16770,
+    16898, 17026, 17154, 3457, 9218, 3521, 17282, 5441, 9602, 9986, 17411, 17602,
+    3521, 3841, 17731, 3201, 7169, 8321, 4929, 3585, 3457, 17922, 6977, 3521,
+    3521, 5569, 5697, 14145, 3649, 3713, 2113, 6465, 6593, 6721, 3841, 7361,
+    14466, 18050, 18178, 15746, 18308, 1985, 3905, 1985, 3457, 18562, 18690, 1089,
+    7105, 11201, 4993, 4545, 4417, 8514, 7041, 4481, 4481, 5633, 5761, 11137,
+    4609, 2369, 7873, 6529, 6657, 6785, 2049, 7425, 14850, 8706, 16002, 8194,
+    18820, 1985, 4737, 1985, 4417, 19074, 19202, 19330, 19458, 19586, 19714, 19330,
+    9346, 4481, 19842, 5505, 9730, 10114, 19971, 20162, 4481, 2049, 20291, 3713,
+    2369, 3457, 4417, 16770, 19330, 3457, 4417, 16770, 19330, 3713, 2369, 16898,
+    19458, 14210, 20482, 14594, 14978, 7361, 7425, 3905, 4737, 3905, 4737, 2049,
+    2049, 3713, 2369, 3713, 2369, 20610, 20738, 15169, 10945, 20870, 12673, 0,
+    0, 0, 12673, 21257, 21835, 22530, 22658, 833, 833, 22786, 22914, 23042,
+    23170, 23042, 23170, 23042, 23170, 23299, 23491, 23682, 23810, 23938, 24066, 23938,
+    24066, 23938, 24066, 23938, 24066, 24194, 24322, 24450, 5954, 24578, 24706, 14466,
+    14850, 24834, 24962, 25090, 25218, 3841, 2049, 25346, 25474, 25603, 25795, 25987,
+    26179, 26371, 26563, 26371, 26563, 5057, 5121, 18178, 16002, 26371, 26563, 12289,
+    17922, 8514, 23938, 24066, 26754, 26882, 24194, 24322, 27010, 27138, 18178, 16002,
+    27266, 27394, 5121, 1089, 1089, 3201, 1089, 27522, 4226, 16770, 19330, 7297,
+    7297, 7297, 7297, 17922, 8514, 6977, 7041, 9218, 9346, 3521, 4481, 3521,
+    4481, 3713, 2369, 3713, 2369, 3713, 2369, 3457, 4417, 3841, 2049, 3841,
+    2049, 3841, 2049, 18178, 16002, 27650, 27778, 3905, 4737, 27906, 28034, 12865,
+    3777, 5057, 5121, 3585, 4545, 17026, 19586, 28162, 28290, 9218, 9346, 9602,
+    9730, 9986, 10114, 28418, 28546, 28674, 28802, 3201, 1089, 5697, 5761, 28931,
+    29123, 29315, 29507, 3201, 1089, 15169, 10945, 6849, 6913, 15169, 10945, 5697,
+    5761, 3649, 4609, 14338, 14722, 1985, 1985, 29698, 29826, 17731, 20291, 29955,
+    30147, 5697, 5761, 3201, 7169, 4929, 3585, 3457, 6977, 3457, 3457, 30338,
+    17922, 3521, 5697, 14466, 18050, 5569, 5057, 9218, 27650, 18178, 14145, 3905,
+    3649, 15746, 3713, 30467, 2113, 5441, 30658, 6593, 8321, 6721, 6465, 30787,
+    6849, 30978, 31106, 3713, 7361, 12353, 1985, 4673, 65, 2241, 3137, 12673,
+    1089, 1089, 7105, 4993, 4545, 4417, 7041, 4417, 4417, 31234, 8514, 4481,
+    5761, 14850, 8706, 5633, 5121, 9346, 27778, 16002, 11137, 4737, 4609, 8194,
+    2369, 31363, 7873, 5505, 31554, 6657, 11201, 6785, 6529, 31683, 6913, 31874,
+    32002, 2369, 7425, 32130, 5121, 12673, 1473, 2177, 2177, 32260, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 7297, 4417, 1089, 2369, 4481, 4417, 4417,
+    1089, 1089, 2369, 2369, 2049, 1985, 0, 1473, 0, 641, 5121, 0,
+    12609, 12289, 12289, 10881, 2369, 1985, 7105, 4993, 4545, 5121, 11201, 7041,
+    14850, 6785, 4737, 5633, 5633, 5761, 11137, 11137, 4609, 4609, 6657, 12289,
+    7873, 7873, 8706, 8706, 10945, 6529, 8194, 6785, 32515, 8321, 32706, 4481,
+    1985, 833, 32833, 32899, 10881, 32833, 33091, 32833, 33284, 33539, 6529, 33730,
+    33859, 34050, 2241, 4673, 10881, 2177, 0, 0, 0, 0, 0, 0,
+    4481, 0, 0, 0, 0, 14081, 0, 12673, 12673, 3137, 4737, 1985,
+    1089, 1985, 34178, 1985, 34306, 0, 7105, 7297, 6785, 4802, 5505, 5057,
+    14850, 4545, 34434, 6529, 7041, 6657, 8194, 6593, 3585, 6721, 6977, 12289,
+    4929, 4993, 4993, 4737, 4481, 4481, 0, 7425, 10945, 5633, 5761, 11137,
+    4609, 5121, 6913, 12801, 4737, 34562, 34690, 34818, 1089, 2049, 4481, 6849,
+    0, 1089, 1985, 1985, 4481, 0, 4417, 4417, 2369, 4417, 4417, 2369,
+    1089, 25474, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137,
+    35201, 12673, 2241, 2177, 7105, 10945, 1089, 1985, 1985, 1985, 4417, 1985,
+    35266, 35394, 35522, 35650, 35779, 7105, 6785, 6721, 7873, 4802, 35970, 36098,
+    5057, 29826, 36226, 5057, 16002, 36355, 36546, 3585, 3585, 36674, 34434, 36803,
+    4545, 3585, 3585, 36994, 6465, 6465, 6465, 6465, 6465, 6465, 5505, 6465,
+    6593, 6593, 6593, 6593, 6593, 6721, 37122, 7361, 7361, 7361, 11201, 7425,
+    14722, 15169, 15169, 14850, 5633, 5569, 5569, 5954, 5569, 4993, 4929, 3649,
+    4929, 4929, 4929, 5697, 5697, 5697, 5697, 3649, 3649, 3649, 3649, 3649,
+    5121, 18178, 37250, 5121, 5057, 7297, 6849, 6338, 6338, 2049, 37378, 37378,
+    6849, 11201, 4737, 3905, 3905, 6849, 4417, 29826, 4737, 34306, 12673, 4226,
+    0, 0, 0, 0, 0, 0, 0, 7297, 32833, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 10881, 0, 0, 0, 0,
+    4545, 6529, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137,
+    15746, 3585, 27650, 15105, 37506, 5121, 13186, 4673, 2241, 65, 65, 1473,
+    2241, 2241, 14081, 3137, 12801, 37633, 37697, 2177, 12673, 1985, 1985, 7105,
+    4993, 4993, 4545, 4545, 5121, 6913, 7041, 5057, 6785, 6785, 4737, 37762,
+    5633, 5761, 11137, 4609, 6657, 6657, 12289, 7873, 7873, 6593, 10945, 6529,
+    8194, 6785, 35970, 27778, 34434, 1089, 1089, 1089, 3201, 3201, 3201, 4417,
+    4417, 4417, 3457, 4481, 4481, 2049, 2049, 2049, 2369, 0, 12289, 1985,
+    0, 0, 12865, 15169, 7297, 7297, 641, 12737, 8514, 14850, 14722, 35522,
+    7873, 7873, 4353, 29826, 7873, 4353, 5954, 4353, 36546, 4545, 6529, 6657,
+    5954, 5954, 4993, 7873, 11201, 4993, 4993, 5633, 7105, 37890, 4609, 4609,
+    4609, 38018, 6529, 7041, 6657, 5505, 4353, 6657, 7041, 5505, 1089, 38146,
+    4481, 38274, 4737, 2369, 38402, 4417, 38530, 16002, 6657, 6657, 4993, 5121,
+    8194, 4609, 6529, 7105, 5697, 5633, 1985, 11201, 11137, 7425, 34434, 4802,
+    5761, 4993, 29826, 6657, 4545, 7041, 6785, 4737, 7873, 5505, 16002, 35650,
+    38658, 14850, 4802, 7041, 8194, 6657, 4545, 6785, 7041, 12289, 27778, 10945,
+    6913, 1089, 38146, 4481, 38530, 2049, 38402, 4417, 38786, 2369, 38914, 0,
+    4609, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 1089,
+    4417, 4481, 4417, 2049, 2369, 2369, 4609, 7105, 7873, 6785, 5505, 4353,
+    4545, 6529, 36994, 6657, 39042, 7425, 5633, 5761, 4609, 11137, 29826, 4609,
+    5121, 6913, 4737, 29826, 5505, 4353, 6529, 0, 0, 0, 0, 0,
+    0, 0, 4609, 0, 1985, 12289, 2369, 2177, 2241, 65, 1473, 12673,
+    3585, 6721, 1985, 7105, 4993, 4545, 5121, 6913, 7041, 5121, 6785, 4737,
+    5633, 5761, 11137, 4609, 6657, 12289, 7425, 6657, 10945, 6529, 8194, 6785,
+    12289, 12289, 0, 0, 4737, 4737, 4417, 4417, 1089, 1089, 1089, 1089,
+    1089, 1089, 1089, 1089, 2049, 2049, 4481, 4481, 4481, 2369, 0, 0,
+    14081, 12545, 39170, 39298, 12353, 3137, 12673, 39426, 39554, 1473, 12993, 641,
+    4673, 12673, 39683, 1089, 7105, 4993, 4545, 5121, 2049, 7041, 5121, 6785,
+    4481, 5633, 5761, 11137, 4609, 6657, 12289, 7873, 6657, 10945, 6529, 8194,
+    6785, 4545, 39874, 12289, 1985, 0, 0, 2241, 4609, 5505, 4609, 6785,
+    4609, 4609, 35970, 6529, 5761, 5761, 6657, 1985, 1985, 1985, 1985, 1985,
+    1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985,
+    1985, 1985, 1985, 2049, 4737, 4737, 12673, 12673, 10114, 4353, 4802, 4802,
+    4993, 12673, 40002, 40130, 1089, 1089, 7105, 7105, 4353, 6785, 40258, 39042,
+    40386, 40514, 4737, 29826, 6529, 6913, 4737, 9346, 8706, 14850, 2049, 7041,
+    4609, 5633, 10945, 37890, 40642, 35650, 40770, 29826, 7425, 10945, 4609, 14722,
+    4802, 4802, 16002, 14850, 40898, 41026, 4609, 4609, 5761, 41154, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    32833, 4417, 2369, 10498, 4417, 41282, 41411, 41602, 0, 0, 0, 0,
+    0, 0, 1089, 2049, 4481, 2049, 4417, 1089, 4481, 2369, 2049, 4417,
+    4417, 34690, 41282, 2369, 2369, 0, 11137, 3649, 3649, 5057, 1089, 1089,
+    38146, 4481, 38274, 2049, 41730, 6465, 5697, 41858, 4417, 4417, 41986, 42114,
+    2369, 2369, 42242, 5633, 14850, 4993, 27778, 5954, 4353, 16002, 5505, 22658,
+    29826, 35650, 35779, 36546, 36803, 42370, 6785, 4802, 4545, 34434, 4609, 42499,
+    7873, 14722, 7105, 35970, 11137, 4737, 6529, 36994, 5761, 5761, 42691, 11201,
+    8194, 4098, 6657, 5121, 2369, 2369, 1985, 1985, 38146, 4481, 38274, 2049,
+    41730, 6465, 42882, 41858, 4417, 4417, 41986, 42114, 2369, 2369, 42242, 0,
+    4417, 1089, 43011, 1985, 1985, 12289, 1985, 4417, 2049, 2049, 10945, 43203,
+    43395, 7041, 43588, 31554, 7425, 43842, 42882, 43970, 5697, 43970, 44099, 44292,
+    34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 12673, 1985,
+    1089, 2369, 2369, 1089, 2049, 2049, 4545, 8514, 4737, 4993, 5505, 1985,
+    4545, 7105, 2177, 3649, 3649, 5057, 1089, 38146, 4481, 38274, 2049, 41730,
+    6465, 42882, 4417, 41986, 2369, 42242, 5633, 14850, 4993, 27778, 5954, 4353,
+    16002, 5505, 22658, 29826, 35650, 35779, 36546, 36803, 42370, 6785, 4802, 4545,
+    34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737, 6529, 5761, 8194, 4098,
+    6657, 5121, 1985, 1985, 38146, 4481, 38274, 2049, 41730, 6465, 42882, 4417,
+    41986, 2369, 42242, 0, 6785, 12737, 36994, 31554, 43842, 42882, 43970, 5697,
+    43970, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 22914,
+    44546, 44674, 44674, 44802, 44930, 45058, 45186, 45319, 45763, 10881, 4993, 11137,
+    12673, 1985, 11137, 3649, 5121, 1089, 38146, 4481, 38274, 2049, 41730, 38530,
+    41986, 38402, 42242, 5633, 14850, 4993, 27778, 5954, 4353, 16002, 5505, 22658,
+    29826, 35650, 35779, 36546, 36803, 42370, 6785, 4802, 4545, 34434, 4609, 7873,
+    14722, 7105, 45954, 11137, 4737, 6529, 5761, 46082, 11201, 8194, 6657, 5121,
+    1985, 38146, 4481, 38274, 2049, 41730, 38530, 41986, 38402, 42242, 0, 0,
+    43203, 43395, 7041, 36994, 7425, 34945, 2305, 1857, 1921, 12929, 8641, 7233,
+    35009, 35073, 35137, 3649, 5057, 0, 0, 46214, 4737, 12673, 3649, 3649,
+    5057, 1089, 38146, 4481, 38274, 2049, 41730, 6465, 5761, 41858, 4417, 41986,
+    42114, 2369, 42242, 5633, 14850, 4993, 27778, 5954, 4353, 16002, 5505, 22658,
+    29826, 35650, 35779, 36546, 36803, 42370, 6785, 4802, 4545, 34434, 4609, 7873,
+    14722, 7105, 35970, 11137, 46594, 6529, 5761, 46082, 11201, 8194, 4098, 6657,
+    5121, 1985, 1985, 38146, 4481, 38274, 2049, 41730, 6465, 42882, 41858, 4417,
+    41986, 42114, 2369, 42242, 0, 43011, 42882, 5761, 5761, 5761, 34945, 2305,
+    1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 12673, 6465, 8514, 0,
+    0, 0, 1985, 1985, 1985, 3649, 3649, 5057, 1089, 38146, 4481, 38274,
+    2049, 41730, 6465, 5697, 4417, 41986, 2369, 42242, 5633, 14850, 4993, 27778,
+    5954, 4353, 16002, 5505, 22658, 29826, 35650, 35779, 36546, 36803, 42370, 6785,
+    4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737, 6529, 5761,
+    46082, 11201, 8194, 4098, 6657, 5121, 1985, 1985, 38146, 4481, 38274, 2049,
+    41730, 6465, 6529, 4417, 41986, 2369, 42242, 0, 12737, 12737, 36994, 31554,
+    43842, 42882, 43970, 5761, 5761, 34945, 2305, 1857, 1921, 12929, 8641, 7233,
+    35009, 35073, 35137, 10881, 6913, 2305, 1857, 1921, 2305, 1857, 1921, 3649,
+    5057, 1089, 38146, 4481, 38274, 2049, 41730, 4417, 38530, 41986, 2369, 38402,
+    42242, 5633, 5954, 4353, 5505, 29826, 35650, 42370, 6785, 4609, 42499, 7873,
+    11137, 4737, 6529, 36994, 5761, 46082, 42691, 11201, 6657, 4098, 6657, 5121,
+    38146, 4481, 38274, 2049, 41730, 4417, 38530, 41986, 2369, 38402, 42242, 0,
+    46722, 12737, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137,
+    46852, 47109, 47430, 3649, 14145, 8321, 1473, 12737, 1473, 44674, 32833, 11137,
+    3649, 3649, 5057, 11137, 1089, 38146, 4481, 38274, 2049, 41730, 6465, 5697,
+    4417, 38530, 41986, 2369, 38402, 42242, 5633, 14850, 4993, 27778, 5954, 4353,
+    16002, 5505, 22658, 29826, 35650, 35779, 36546, 36803, 42370, 6785, 4802, 4545,
+    34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737, 6529, 36994, 5761, 46082,
+    5761, 11201, 8194, 4098, 6657, 5121, 1985, 1985, 38146, 4481, 38274, 2049,
+    41730, 6465, 42882, 4417, 38530, 41986, 2369, 38402, 42242, 0, 12737, 12737,
+    4353, 7041, 6529, 4609, 42882, 43970, 5761, 5761, 34945, 2305, 1857, 1921,
+    12929, 8641, 7233, 35009, 35073, 35137, 2177, 34945, 2305, 1857, 1921, 2305,
+    1857, 1921, 6785, 11137, 11137, 3649, 5057, 2177, 1089, 38146, 4481, 38274,
+    2049, 41730, 6465, 5697, 4417, 38530, 41986, 2369, 38402, 42242, 5633, 14850,
+    4993, 27778, 5954, 4353, 16002, 5505, 22658, 29826, 35650, 35779, 36546, 36803,
+    42370, 6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737,
+    6529, 36994, 5761, 46082, 11201, 8194, 4098, 6657, 5121, 1985, 1985, 38146,
+    4481, 38274, 2049, 41730, 6465, 42882, 4417, 38530, 41986, 2369, 38402, 42242,
+    0, 12737, 12737, 4609, 42691, 42882, 43970, 5761, 5761, 34945, 2305, 1857,
+    1921, 12929, 8641, 7233, 35009, 35073, 35137, 5121, 5121, 11137, 11137, 11137,
+    3649, 5057, 11137, 1089, 38146, 4481, 38274, 2049, 41730, 6465, 5697, 4417,
+    38530, 41986, 2369, 38402, 42242, 5633, 14850, 4993, 27778, 5954, 4353, 16002,
+    5505, 22658, 29826, 35650, 35779, 36546, 36803, 42370, 6785, 4802, 4545, 34434,
+    4609, 4609, 7873, 14722, 7105, 35970, 11137, 4737, 6529, 36994, 5761, 46082,
+    42691, 11201, 8194, 4098, 6657, 5121, 6785, 0, 0, 1985, 38146, 4481,
+    38274, 2049, 41730, 6465, 6529, 4417, 38530, 41986, 0, 2369, 38402, 42242,
+    0, 6529, 7873, 11137, 4737, 5761, 12737, 1857, 12929, 47810, 47938, 48066,
+    48194, 48322, 4481, 42882, 43970, 5761, 5761, 34945, 2305, 1857, 1921, 12929,
+    8641, 7233, 35009, 35073, 35137, 48450, 48579, 48772, 49026, 49155, 49347, 49538,
+    49666, 49794, 4545, 4609, 4609, 6529, 5761, 5761, 5633, 11137, 3649, 5057,
+    1089, 38146, 4226, 49923, 4481, 38274, 2049, 41730, 6465, 42882, 5697, 43970,
+    4417, 38530, 41986, 2369, 38402, 42242, 5633, 14850, 4993, 27778, 5954, 50115,
+    4353, 16002, 5505, 22658, 29826, 50307, 50499, 35650, 35779, 36546, 36803, 42370,
+    50692, 6785, 4802, 4545, 34434, 4609, 50946, 7873, 14722, 7105, 35970, 11137,
+    37890, 4737, 6529, 5761, 11201, 8194, 4098, 6657, 5121, 46082, 7425, 0,
+    38146, 4226, 49923, 4481, 38274, 2049, 41730, 6465, 4417, 38530, 41986, 2369,
+    38402, 42242, 5697, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073,
+    35137, 42882, 43970, 51075, 5633, 14850, 14850, 14850, 14850, 14850, 5954, 36355,
+    16002, 16002, 16002, 16002, 4737, 4545, 6785, 4802, 4802, 4802, 4609, 4545,
+    6785, 4802, 4802, 4802, 4609, 7105, 7873, 14722, 7425, 14722, 7425, 14722,
+    11137, 4737, 6529, 6465, 5761, 5697, 6913, 6657, 6657, 6657, 5121, 5761,
+    12289, 5121, 12801, 1089, 1089, 38146, 51266, 4481, 38274, 51394, 51523, 2049,
+    41730, 1985, 51715, 4417, 4226, 2369, 41986, 41986, 51906, 12737, 0, 0,
+    0, 0, 0, 0, 14145, 0, 52035, 34945, 2305, 1857, 1921, 12929,
+    8641, 7233, 35009, 35073, 35137, 44292, 52229, 5633, 14850, 14850, 27778, 5954,
+    16002, 16002, 6657, 22658, 29826, 4609, 6785, 4802, 4545, 34434, 4609, 4545,
+    5121, 4802, 4802, 34434, 4609, 7105, 7873, 14722, 7425, 14722, 7425, 35970,
+    11137, 4737, 6529, 5761, 6913, 6657, 6657, 6657, 5121, 5761, 12289, 5121,
+    12801, 1089, 1089, 38146, 51266, 4481, 38274, 4737, 43842, 2049, 41730, 2369,
+    5761, 29826, 4417, 52546, 2369, 52674, 41986, 12737, 0, 0, 0, 0,
+    0, 14145, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137,
+    52802, 52930, 4993, 53058, 43011, 7297, 7297, 7297, 7297, 32833, 53185, 35201,
+    44292, 52035, 2177, 1473, 44099, 44099, 44292, 53252, 53508, 53764, 54022, 54405,
+    54722, 2177, 2177, 2177, 0, 0, 2369, 38402, 54851, 3777, 55042, 55170,
+    34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 55298, 55427,
+    55619, 55811, 56003, 56195, 56387, 56579, 56771, 56963, 12737, 2177, 10881, 12545,
+    2177, 12801, 12353, 57153, 57218, 12162, 0, 0, 5633, 14850, 4993, 27778,
+    5954, 4353, 16002, 5505, 29826, 35650, 35779, 36546, 36803, 42370, 6785, 4802,
+    4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 8706, 19971, 9346, 20291,
+    6913, 8514, 7041, 1985, 4737, 6529, 5761, 8194, 57347, 6657, 5121, 1089,
+    57539, 6529, 10945, 6529, 38146, 4481, 38274, 2049, 41730, 6465, 42882, 5697,
+    43970, 4417, 38530, 2369, 38402, 14145, 5057, 4481, 38274, 11137, 11137, 0,
+    12289, 0, 0, 5121, 5121, 0, 0, 5121, 5121, 5121, 5121, 5633,
+    14850, 4993, 27778, 5954, 4353, 16002, 5505, 29826, 35650, 35779, 36546, 36803,
+    42370, 6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 8706,
+    19971, 9346, 20291, 6913, 8514, 7041, 1985, 4737, 6529, 5761, 8194, 4098,
+    6657, 5121, 1089, 57539, 6913, 4737, 6529, 12865, 57733, 58053, 58373, 58693,
+    59013, 2177, 2177, 0, 2177, 2177, 2177, 2177, 2177, 2177, 59330, 59459,
+    2177, 2177, 12545, 7297, 32833, 12737, 12737, 12737, 12737, 12801, 12801, 5633,
+    14850, 4993, 27778, 5954, 4353, 16002, 5505, 22658, 29826, 59651, 35650, 35779,
+    36546, 36803, 42370, 35650, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970,
+    11137, 4737, 6529, 5761, 6913, 6657, 5121, 46082, 1089, 1985, 4481, 38274,
+    2049, 41730, 4417, 4417, 2369, 42242, 1089, 38146, 4481, 38274, 2049, 41730,
+    4417, 41986, 4481, 51906, 4417, 3649, 1985, 12609, 0, 0, 4737, 6529,
+    11201, 5121, 4098, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073,
+    35137, 44099, 44292, 59842, 59970, 60098, 60226, 8194, 4098, 6465, 42882, 5697,
+    43970, 6465, 42882, 5697, 43970, 4609, 22658, 7105, 7105, 4609, 11137, 5761,
+    8194, 2369, 1, 1, 6657, 60354, 2369, 2049, 1, 1, 1, 1,
+    1, 4609, 60482, 60611, 4481, 2369, 2049, 4417, 5633, 14850, 4993, 4353,
+    7041, 4609, 4545, 4609, 14722, 7425, 7105, 3777, 5121, 11201, 1089, 4417,
+    4417, 41986, 1, 1, 1, 1, 1, 1, 0, 7425, 1, 34945,
+    2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 1, 1, 1089,
+    41986, 2305, 65, 3201, 7169, 4929, 3585, 3457, 8321, 6977, 30338, 3521,
+    5569, 5697, 14145, 3649, 3713, 2113, 17922, 6465, 6593, 6721, 3841, 30978,
+    31106, 23042, 15169, 15746, 30467, 60802, 23682, 3393, 18178, 12865, 5441, 5057,
+    3457, 3905, 6849, 27906, 6210, 3457, 27522, 1089, 7105, 4993, 4545, 4417,
+    11201, 7041, 31234, 4481, 5633, 5761, 11137, 4609, 2369, 7873, 8514, 6529,
+    6657, 6785, 2049, 31874, 32002, 23170, 10945, 8194, 31363, 60930, 23810, 4353,
+    16002, 3777, 5505, 5121, 4417, 4737, 6913, 28034, 6338, 7425, 4417, 12289,
+    4993, 12289, 44292, 4609, 4226, 12289, 6913, 4993, 61058, 4609, 4545, 36546,
+    6529, 11137, 7105, 45954, 6657, 4098, 0, 5505, 61186, 4353, 5633, 6785,
+    7873, 5121, 5954, 42370, 50946, 61314, 61442, 61570, 36994, 31554, 61698, 37890,
+    61826, 61954, 62082, 62210, 62338, 62467, 62659, 62851, 63043, 63235, 63426, 63554,
+    63682, 63810, 63938, 64067, 64258, 64386, 64514, 64642, 64770, 64898, 65027, 65219,
+    6657, 28546, 65410, 65538, 15362, 15618, 8194, 6657, 4098, 6657, 4098, 6977,
+    4993, 4545, 11137, 7105, 6657, 6977, 0, 5505, 4353, 6785, 7873, 3649,
+    5505, 5505, 61186, 5505, 61186, 65666, 16002, 16002, 16002, 65794, 65922, 38658,
+    15169, 66050, 66178, 10114, 27138, 66306, 0, 0, 1089, 4226, 46594, 66435,
+    66626, 4417, 66755, 66946, 2369, 67074, 67203, 6338, 67394, 2049, 67523, 67714,
+    67842, 37378, 67970, 19842, 4481, 68099, 68291, 68484, 68741, 69060, 69316, 69573,
+    69893, 70213, 70532, 70787, 70980, 71235, 71427, 71621, 71942, 72326, 72708, 72964,
+    73219, 73412, 73671, 74116, 74371, 74564, 74821, 75140, 75398, 75781, 76100, 76356,
+    76612, 76869, 77188, 77443, 77636, 77891, 78083, 78276, 78531, 3841, 78724, 78979,
+    79171, 79362, 79491, 79683, 79877, 80195, 80388, 4993, 61058, 80642, 4609, 10114,
+    27138, 4545, 5761, 80770, 80898, 81026, 11778, 81154, 81282, 26882, 11137, 7105,
+    62338, 6657, 4098, 5954, 5505, 4353, 5633, 6785, 7873, 5121, 81410, 81539,
+    5954, 50946, 66178, 81730, 81858, 61442, 81986, 82115, 82306, 82434, 82563, 46082,
+    82755, 82947, 83139, 83331, 83523, 83715, 11010, 83906, 84034, 84162, 84290, 37890,
+    84418, 84547, 84738, 84866, 27394, 61826, 84994, 63810, 14722, 65922, 64258, 64514,
+    38018, 64898, 6977, 4993, 4098, 85124, 14850, 3649, 85378, 85506, 65794, 65922,
+    52802, 85634, 52930, 85762, 15169, 53058, 39042, 85891, 86082, 27778, 42370, 86210,
+    86338, 86466, 86595, 86787, 86978, 87106, 87235, 87426, 87554, 87682, 87811, 88003,
+    88194, 88322, 88451, 88643, 88835, 89027, 89220, 89476, 89731, 89923, 90116, 90370,
+    90498, 90626, 90755, 90947, 91138, 91266, 91395, 91587, 91779, 91971, 92164, 92420,
+    92675, 92867, 93060, 93314, 93442, 93570, 93699, 93891, 94082, 94210, 94339, 94530,
+    94658, 94786, 94915, 95107, 95298, 95426, 95555, 95747, 95939, 96131, 96324, 96580,
+    96835, 97027, 97220, 97474, 97602, 97730, 97859, 98051, 98242, 98370, 98500, 98755,
+    98947, 99140, 99396, 99651, 99843, 100035, 100227, 100420, 100676, 100931, 101123, 101316,
+    101572, 101829, 102149, 102468, 102722, 102850, 102978, 103107, 103299, 103490, 103618, 103747,
+    103938, 104066, 104194, 104323, 104515, 104706, 104834, 104963, 105154, 105282, 16258, 105411,
+    105603, 105794, 105922, 106051, 106242, 106370, 106498, 106627, 106819, 107010, 107138, 107267,
+    107458, 107586, 107714, 107843, 108035, 108226, 59330, 87235, 108355, 108547, 108740, 108996,
+    109251, 109442, 109570, 109698, 109827, 110019, 110210, 110338, 110467, 110659, 110851, 111043,
+    111236, 111492, 111747, 111939, 112132, 112386, 35394, 112514, 112643, 112835, 113026, 113154,
+    113283, 113474, 113602, 113730, 113859, 114051, 114242, 114370, 114499, 114691, 114883, 115076,
+    115332, 115587, 115779, 115971, 116163, 116356, 116612, 116867, 117059, 117252, 117508, 117765,
+    118085, 118404, 67074, 118658, 67842, 118787, 118979, 67714, 119170, 119299, 119490, 119618,
+    119746, 119875, 120067, 120258, 120386, 120514, 120642, 120770, 120899, 121091, 121282, 121410,
+    121539, 121731, 121923, 122115, 122308, 122564, 122819, 123011, 123204, 46594, 37378, 19842,
+    123459, 123651, 66946, 67394, 123843, 124034, 124162, 124290, 124419, 124611, 124802, 124930,
+    125059, 125251, 125443, 125635, 125828, 126084, 126339, 126531, 126724, 126978, 127106, 127234,
+    127363, 127555, 127746, 127874, 128003, 128194, 128322, 128450, 128579, 128771, 128962, 129090,
+    129219, 129411, 129603, 129796, 130052, 130307, 130499, 130691, 130883, 131076, 131332, 131587,
+    131779, 131972, 132227, 132419, 132611, 132804, 133060, 133315, 133507, 133700, 133955, 134147,
+    134339, 134532, 134788, 135043, 135235, 135428, 135683, 135875, 136067, 136260, 136516, 136771,
+    136963, 137156, 137411, 137603, 137795, 137988, 138244, 138499, 138691, 138884, 139139, 139331,
+    139523, 139716, 139972, 140227, 140419, 140613, 140930, 141058, 141186, 141315, 141507, 141698,
+    141826, 141955, 142146, 142274, 142402, 142531, 142723, 142914, 143042, 143171, 143363, 143555,
+    143747, 0, 0, 0, 12737, 1, 12673, 2241, 14081, 12609, 143939, 3137,
+    13186, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 144131, 144323,
+    144515, 144707, 144899, 145091, 145283, 145475, 145667, 145860, 146119, 146563, 146755, 146563,
+    146755, 146947, 147139, 146947, 147139, 147331, 147523, 147331, 147523, 147715, 147907, 147715,
+    147907, 1089, 4417, 4481, 2369, 2049, 11201, 128194, 113474, 128962, 128450, 129090,
+    128322, 148098, 86210, 86978, 86466, 87106, 86338, 7490, 87426, 88194, 87682, 88322,
+    87554, 148226, 90370, 91138, 90626, 91266, 90498, 109442, 148355, 148547, 110210, 109698,
+    110338, 109570, 148738, 148867, 149059, 149251, 149443, 149635, 149827, 94530, 6657, 95298,
+    94786, 95426, 94658, 150018, 124034, 105154, 124802, 105794, 124290, 16258, 124930, 124162,
+    150146, 150275, 150467, 150659, 150851, 151043, 151235, 151427, 137411, 138499, 137795, 138691,
+    137603, 151619, 67074, 67714, 67842, 119170, 118658, 151810, 46594, 66946, 19842, 67394,
+    37378, 151938, 152066, 66946, 19842, 67394, 37378, 151938, 40514, 1473, 4417, 152195,
+    4481, 38274, 2369, 38402, 38402, 38530, 4481, 1089, 38146, 67714, 67714, 67842,
+    67842, 152387, 152387, 119170, 119170, 152579, 152579, 152579, 67074, 67074, 118787, 118787,
+    118787, 41986, 6913, 1985, 6785, 5633, 8194, 6657, 4609, 6913, 4609, 5121,
+    6913, 4353, 3137, 5761, 41602, 34818, 41282, 34562, 142914, 152772, 142402, 153027,
+    143042, 153219, 153219, 86787, 86466, 142146, 142531, 147715, 147715, 147907, 147907, 153412,
+    153412, 153667, 153667, 153860, 153860, 143171, 143171, 154116, 154116, 154116, 7873, 7873,
+    5121, 105794, 154372, 16258, 154627, 105922, 154819, 154819, 124611, 124290, 105154, 105411,
+    155011, 155011, 155203, 155203, 155396, 155396, 155651, 155651, 155844, 155844, 106051, 106051,
+    156100, 156100, 156100, 6785, 156355, 156547, 156739, 156931, 114242, 157124, 113730, 157379,
+    114370, 157571, 157571, 113474, 113859, 115587, 115587, 114883, 114883, 157764, 157764, 158019,
+    158019, 158212, 158212, 114691, 114691, 115076, 115076, 115076, 5633, 158466, 158595, 158787,
+    158979, 159171, 107010, 159364, 106498, 159619, 107138, 159811, 159811, 106242, 106627, 160003,
+    160003, 160195, 160195, 160388, 160388, 160643, 160643, 160836, 160836, 107267, 107267, 161092,
+    161092, 161092, 4353, 4802, 91138, 161348, 90626, 161603, 91266, 161795, 161795, 90370,
+    90755, 146563, 146563, 146755, 146755, 161988, 161988, 162243, 162243, 162436, 162436, 91395,
+    91395, 162692, 162692, 162692, 11137, 11137, 27394, 11137, 11137, 110210, 162948, 109698,
+    163203, 110338, 163395, 163395, 109442, 109827, 163587, 163587, 110467, 110467, 163780, 163780,
+    163780, 4609, 5954, 27138, 88194, 164036, 87682, 164291, 88322, 164483, 164483, 87426,
+    87811, 164675, 164675, 164867, 164867, 165060, 165060, 165315, 165315, 165508, 165508, 88451,
+    88451, 165764, 165764, 5761, 5761, 5761, 95298, 166020, 94786, 166275, 95426, 166467,
+    166467, 94530, 94915, 166659, 166659, 166851, 166851, 167044, 167044, 167299, 167299, 167492,
+    167492, 95555, 95555, 167748, 167748, 167748, 6657, 6657, 168002, 6657, 65538, 168131,
+    168322, 168452, 168708, 168964, 169220, 96835, 96131, 169476, 97027, 169732, 95747, 96324,
+    169988, 169988, 170244, 170244, 170501, 170501, 170820, 170820, 171077, 171077, 97220, 97220,
+    171397, 171397, 8194, 66946, 171716, 19842, 171971, 67394, 172163, 172163, 46594, 123459,
+    172355, 172355, 172547, 172547, 172740, 172740, 172995, 172995, 173188, 173188, 173443, 173443,
+    173636, 173636, 173636, 4737, 4737, 4737, 19842, 94082, 94082, 88194, 173892, 93570,
+    174147, 94210, 174339, 88322, 93314, 93699, 87426, 174532, 174532, 6529, 6529, 6529,
+    141698, 174788, 141186, 175043, 141826, 175235, 140930, 141315, 175428, 175428, 7425, 133315,
+    133315, 132611, 132611, 175684, 175684, 133507, 175940, 132227, 132804, 176197, 176197, 4802,
+    176516, 176772, 177028, 177284, 35779, 177539, 177731, 177923, 178115, 86978, 86466, 178307,
+    87106, 178499, 86210, 86595, 5121, 5121, 178690, 178820, 97730, 179075, 98370, 179267,
+    97474, 97859, 10945, 179460, 179716, 179972, 180228, 94082, 93570, 94210, 93314, 180485,
+    180803, 180996, 181251, 181444, 181699, 181892, 5954, 50115, 96835, 96131, 97027, 95747,
+    133315, 132611, 133507, 132227, 4802, 182147, 182340, 182595, 182788, 183043, 183236, 26882,
+    133315, 132611, 175684, 133507, 175940, 132227, 132804, 4802, 7105, 4417, 4481, 2369,
+    1089, 67714, 67842, 119170, 67074, 110210, 109698, 110338, 109442, 114242, 113730, 114370,
+    113474, 86978, 86466, 87106, 86210, 183491, 183683, 183875, 184068, 184323, 184515, 93442,
+    94210, 94082, 93891, 93570, 93314, 118658, 119170, 67714, 118979, 67842, 67074, 184707,
+    184899, 185091, 185284, 185539, 185731, 132419, 133507, 133315, 133060, 132611, 132227, 185923,
+    156739, 156355, 186116, 156547, 156931, 142274, 143042, 142914, 142723, 142402, 142146, 7873,
+    128322, 129090, 128962, 128771, 128450, 128194, 186371, 186563, 186755, 186948, 187203, 187395,
+    187587, 187779, 187971, 188164, 188419, 188611, 188802, 109570, 110338, 110210, 110019, 109698,
+    109442, 90498, 91266, 91138, 90947, 90626, 90370, 37378, 67394, 66946, 123651, 19842,
+    46594, 127106, 127106, 127874, 127746, 127555, 127234, 127234, 126978, 188931, 189123, 189315,
+    189508, 189763, 189955, 87554, 88322, 88194, 88003, 87682, 87426, 190147, 190339, 190531,
+    190724, 190979, 150275, 191171, 182595, 191363, 191556, 182147, 183043, 191812, 179972, 179460,
+    192069, 179716, 180228, 151235, 151043, 150659, 192388, 150851, 150467, 120642, 121410, 121282,
+    121091, 120770, 120514, 7041, 7041, 192643, 192835, 193027, 193220, 193475, 193667, 94658,
+    95426, 95298, 95107, 94786, 94530, 95939, 97027, 96835, 96580, 96131, 95747, 8194,
+    137603, 138691, 138499, 138244, 137795, 137411, 134147, 135235, 135043, 134788, 134339, 133955,
+    193860, 194116, 194372, 194629, 194948, 195204, 12865, 12673, 195459, 195652, 195908, 196165,
+    196484, 196741, 197060, 197317, 197636, 197892, 198149, 198468, 198725, 133700, 176197, 4802,
+    6913, 1, 7105, 5761, 7425, 6657, 4609, 5121, 4545, 6785, 4353, 10945,
+    11137, 4993, 5954, 7041, 6529, 1089, 2369, 2049, 4417, 4481, 16002, 4802,
+    14722, 7873, 3777, 7873, 12353, 12417, 7425, 11201, 2049, 199042, 4737, 6913,
+    4802, 4802, 1089, 2369, 199170, 4226, 2369, 2369, 2369, 6338, 41282, 6529,
+    5633, 4353, 5633, 4993, 5954, 4993, 4993, 6913, 5121, 5121, 5121, 5121,
+    4609, 4609, 4609, 4481, 4417, 5505, 4993, 4226, 1089, 66626, 7873, 7041,
+    6657, 6657, 6657, 4353, 7041, 6785, 6785, 4545, 7105, 7105, 7873, 7873,
+    4417, 11137, 11137, 11137, 5761, 5761, 5954, 5954, 4545, 2369, 199299, 199491,
+    97602, 97602, 97602, 6657, 199042, 199042, 199042, 10945, 3777, 12673, 12609, 12737,
+    199682, 199810, 199938, 5633, 8194, 38402, 2369, 4481, 4417, 1089, 4226, 1089,
+    4481, 2049, 5633, 4993, 5954, 6785, 4545, 4609, 7873, 7105, 11137, 4737,
+    6529, 5761, 6913, 6657, 5121, 4481, 2049, 0, 6529, 1089, 4481, 2049,
+    5633, 4993, 5954, 6785, 4545, 4609, 7873, 7105, 11137, 4737, 6529, 5761,
+    6913, 6657, 5121, 4481, 2049, 0, 2241, 12673, 1089, 4481, 2049, 5633,
+    4993, 5954, 6785, 4545, 4609, 7873, 7105, 11137, 4737, 6529, 5761, 6913,
+    6657, 5121, 4481, 2049, 1089, 4481, 2049, 5633, 4993, 5954, 6785, 4545,
+    4609, 7873, 7105, 11137, 4737, 5761, 6913, 6657, 4481, 2049, 5633, 14850,
+    4993, 27778, 5954, 4353, 16002, 5505, 22658, 29826, 6785, 35779, 4545, 36803,
+    42370, 6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737,
+    6529, 5761, 11201, 8194, 4098, 6657, 5121, 5761, 10945, 1089, 38146, 4481,
+    38274, 2049, 200066, 41730, 200195, 200386, 200515, 200706, 200835, 4417, 41986, 38402,
+    38402, 42242, 1089, 38146, 38146, 4481, 38274, 4737, 43842, 2049, 41730, 201026,
+    6338, 46594, 19330, 4417, 4226, 41986, 38402, 42242, 14145, 5057, 201154, 0,
+    0, 1089, 6529, 0, 65, 0, 1089, 0, 0, 0, 12673, 44292,
+    12609, 12737, 201282, 52035, 52229, 201410, 1985, 0, 34945, 2305, 1857, 1921,
+    12929, 8641, 7233, 35009, 35073, 35137, 34945, 2305, 1857, 1921, 12929, 8641,
+    7233, 35009, 35073, 35137, 201539, 201733, 54722, 202050, 202178, 44292, 1473, 1473,
+    54722, 202050, 1473, 0, 0, 0, 1, 34945, 2305, 1857, 1921, 12929,
+    8641, 7233, 35009, 35073, 35137, 1089, 4417, 4481, 2369, 2049, 3713, 3841,
+    38530, 4609, 5954, 7105, 7873, 10945, 4993, 11137, 5761, 6657, 8194, 6785,
+    4545, 16002, 5505, 4737, 6529, 6913, 7425, 5633, 187395, 8706, 7041, 5121,
+    202306, 26882, 8514, 16002, 1473, 4417, 4481, 2369, 2049, 3713, 3841, 5954,
+    7105, 7873, 10945, 4993, 11137, 6785, 4545, 16002, 5505, 8706, 4737, 6913,
+    5633, 4993, 5121, 202434, 29826, 9346, 4417, 4481, 202562, 3841, 2049, 5954,
+    5633, 4993, 5121, 7873, 8194, 6785, 4545, 5505, 7425, 4993, 5121, 8706,
+    7041, 6529, 16002, 8514, 4481, 5633, 6529, 7425, 8514, 3777, 11137, 5057,
+    12865, 6849, 14145, 202691, 202885, 1089, 4481, 5633, 5954, 4353, 35650, 35779,
+    36546, 42370, 6785, 4545, 7873, 14722, 4098, 8514, 7041, 1089, 6785, 8514,
+    27778, 5954, 4353, 22658, 156931, 36803, 6785, 34434, 4098, 203202, 8514, 7041,
+    2049, 4737, 35970, 1985, 26882, 32706, 52674, 203331, 203523, 203715, 203907, 204100,
+    204355, 204547, 204740, 204995, 205187, 205379, 205571, 205763, 205955, 206148, 206404, 206661,
+    206979, 207171, 207363, 207555, 207555, 207748, 207748, 208003, 208003, 208196, 208196, 208451,
+    208643, 208836, 209091, 209284, 94339, 7873, 6785, 5633, 4353, 11137, 4609, 6657,
+    8194, 6913, 6913, 4737, 1985, 208451, 164483, 87811, 197636, 133700, 209541, 209861,
+    210181, 210500, 210756, 169988, 169732, 211011, 211203, 211011, 128579, 129411, 211395, 211587,
+    5761, 6529, 6657, 5633, 14850, 4993, 27778, 4609, 4353, 16002, 5505, 22658,
+    4609, 6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737,
+    6529, 5761, 11201, 6657, 6657, 6657, 5121, 211778, 211906, 1089, 4481, 2049,
+    4417, 41986, 2369, 42242, 4417, 2369, 4737, 6529, 11201, 5633, 4609, 11137,
+    6785, 4609, 7873, 11137, 6529, 5761, 5121, 1985, 0, 88322, 65, 3137,
+    34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 4993, 5121,
+    5954, 7041, 6657, 4737, 4545, 6785, 5761, 7105, 7873, 11137, 7425, 6913,
+    38658, 1985, 5633, 4353, 4609, 38146, 4481, 4417, 4226, 2049, 2369, 38914,
+    51394, 6338, 212035, 41986, 4993, 5633, 5954, 4993, 5633, 5954, 7041, 6657,
+    4737, 7041, 6657, 4737, 4545, 6785, 4609, 4545, 6785, 4609, 7105, 7873,
+    11137, 7105, 7873, 11137, 7425, 6913, 5761, 7425, 6913, 5761, 5121, 36546,
+    45954, 5121, 36546, 45954, 212226, 158466, 212226, 158466, 168002, 168002, 1985, 38146,
+    4481, 2049, 2049, 4417, 4226, 2369, 38914, 51394, 41986, 152195, 212354, 7746,
+    212483, 212675, 38274, 6913, 5954, 4609, 11137, 5633, 6785, 7873, 34945, 2305,
+    1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 2305, 212867, 213060, 213314,
+    213442, 213570, 213698, 213826, 213954, 214082, 214210, 214338, 214466, 214595, 214787, 214979,
+    215171, 215363, 215555, 215746, 215874, 216002, 216130, 216258, 55298, 216386, 216514, 216642,
+    216770, 216899, 217091, 217283, 217475, 217667, 217859, 5633, 4993, 5954, 218051, 7873,
+    7105, 11137, 40642, 6785, 4545, 4609, 40770, 4353, 5505, 29826, 218242, 4737,
+    6529, 5761, 6913, 6657, 5121, 4481, 2049, 4417, 2369, 4417, 12481, 2177,
+    5633, 14850, 14850, 5633, 14850, 14850, 5954, 16002, 6657, 16002, 6657, 6657,
+    29826, 6785, 4802, 4545, 4802, 4609, 6785, 4802, 6785, 4802, 4609, 7105,
+    7873, 14722, 7425, 7873, 7425, 14722, 11137, 29826, 4737, 5121, 218371, 5761,
+    218371, 6913, 6657, 6657, 6657, 5121, 5761, 5121, 4481, 4481, 2049, 2049,
+    4417, 2369, 212867, 4802, 6529, 5761, 218563, 5954, 5954, 7873, 4802, 11137,
+    7105, 6657, 0, 1089, 1089, 1089, 1089, 4481, 4481, 51394, 51394, 2049,
+    2049, 2369, 2369, 32706, 4417, 4226, 2369, 41986, 41986, 2369, 11137, 0,
+    0, 0, 0, 0, 0, 1473, 0, 12673, 34945, 2305, 1857, 1921,
+    12929, 8641, 7233, 35009, 35073, 35137, 34945, 2305, 1857, 1921, 12929, 8641,
+    7233, 35009, 35073, 35137, 218754, 218883, 13826, 2177, 2177, 2177, 10881, 1473,
+    2241, 12673, 14081, 12545, 7297, 219075, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 6913, 6913, 4993,
+    6529, 6785, 11137, 5954, 5954, 6529, 5121, 1089, 1089, 4481, 4481, 2049,
+    2049, 94082, 94210, 88194, 88322, 4417, 41986, 2369, 42242, 5633, 14850, 4993,
+    27778, 5954, 4353, 16002, 5505, 22658, 29826, 6785, 4802, 4545, 34434, 4609,
+    6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737, 6529,
+    5761, 6913, 6657, 6657, 6657, 5121, 1985, 1089, 4481, 4481, 2049, 2049,
+    94082, 94210, 88194, 88322, 4417, 41986, 2369, 42242, 4417, 2369, 1, 10945,
+    3777, 8706, 7425, 11201, 7041, 219266, 50307, 34945, 2305, 1857, 1921, 12929,
+    8641, 7233, 35009, 35073, 35137, 2177, 7297, 12673, 12545, 2241, 12673, 1473,
+    1089, 4417, 2049, 5954, 6529, 4481, 4417, 2049, 41986, 5121, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 10881, 10881, 10881, 10881, 10881,
+    10881, 10881, 10881, 10881, 219394, 219522, 5954, 6529, 5121, 1089, 4481, 2049,
+    4417, 2369, 4417, 67970, 5633, 10945, 4993, 5954, 4353, 5505, 7041, 29826,
+    6785, 4545, 4609, 7873, 7425, 11201, 7105, 11137, 4737, 6529, 5761, 6913,
+    6657, 3777, 5121, 4737, 6529, 5761, 4481, 2049, 4417, 2369, 4417, 67970,
+    1, 0, 11137, 6913, 14850, 219266, 34945, 2305, 1857, 1921, 12929, 8641,
+    7233, 35009, 35073, 35137, 1985, 219651, 219843, 35970, 5633, 11137, 5121, 5121,
+    5121, 7105, 7105, 7873, 7873, 4609, 4609, 6913, 6913, 6913, 4993, 4993,
+    5505, 4545, 6529, 6529, 11137, 11137, 6785, 6785, 6657, 6657, 6657, 4737,
+    4737, 5954, 5761, 5761, 29826, 4353, 50946, 37890, 4481, 2049, 1985, 4417,
+    4417, 4417, 4481, 4481, 2369, 2369, 2049, 2049, 5954, 5121, 1, 1,
+    2177, 12737, 641, 220033, 5633, 220098, 14850, 4993, 81410, 5954, 4353, 16002,
+    5505, 29826, 6785, 4802, 4545, 4609, 7873, 220226, 14722, 7425, 220354, 7105,
+    84994, 11137, 84290, 8706, 19971, 7041, 4737, 6529, 5761, 5121, 85634, 11201,
+    6657, 8194, 6913, 4737, 6529, 1089, 4481, 2369, 2369, 2049, 2049, 4417,
+    5633, 11137, 5761, 4609, 7873, 6529, 6785, 5954, 5954, 0, 1985, 2241,
+    12673, 12673, 12673, 12673, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009,
+    35073, 35137, 6785, 4802, 4545, 34945, 2305, 1857, 1921, 12929, 8641, 7233,
+    35009, 35073, 35137, 1089, 6785, 4993, 11137, 5761, 1089, 5633, 5505, 11137,
+    6913, 4481, 6657, 5121, 4609, 6529, 2049, 4353, 4545, 4609, 4737, 4417,
+    7873, 4545, 4609, 6529, 2369, 6785, 7105, 4609, 5121, 11137, 11137, 1985,
+    2241, 12673, 11201, 4545, 2369, 6657, 6785, 6785, 1985, 4417, 2049, 3201,
+    7169, 4929, 3585, 3457, 8321, 6977, 6721, 3521, 23938, 5697, 14145, 3649,
+    3713, 24578, 17922, 6465, 6593, 25090, 3841, 2113, 5569, 27650, 220482, 15746,
+    18178, 18050, 9218, 220611, 26371, 14466, 5441, 5057, 3457, 3905, 6849, 12865,
+    3713, 7361, 3457, 12289, 4929, 12289, 27522, 12289, 6849, 12673, 2177, 14081,
+    2241, 5697, 5569, 3585, 7169, 0, 0, 0, 833, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 5121,
+    5121, 5121, 5121, 5121, 5121, 5121, 11137, 11137, 11137, 11137, 11137, 11137,
+    11137, 11137, 11137, 5121, 5121, 0, 5121, 5121, 1, 0, 0, 1089,
+    4226, 4226, 7105, 4353, 4545, 4545, 4417, 4417, 4481, 5505, 5633, 5761,
+    11137, 4609, 2369, 2369, 2369, 2369, 2369, 6338, 10498, 2369, 2369, 7873,
+    6529, 6529, 6785, 2049, 2049, 2049, 6913, 11201, 6913, 7041, 8514, 12289,
+    12289, 4993, 5761, 7873, 6529, 14978, 5761, 3201, 27522, 7169, 7169, 3585,
+    3457, 3457, 4929, 5057, 3521, 5441, 5569, 5697, 14145, 3649, 3649, 3713,
+    220802, 2113, 6465, 6721, 3841, 6849, 1089, 1089, 1089, 4226, 7105, 4545,
+    4417, 4417, 4417, 4417, 4993, 4481, 5633, 11137, 5954, 2369, 2369, 2369,
+    2369, 7873, 6785, 2049, 2049, 6913, 11201, 12289, 11201, 4993, 4545, 7425,
+    16002, 4481, 6529, 2049, 11201, 11201, 4993, 6529, 7425, 16002, 51394, 7105,
+    4545, 7425, 11137, 4609, 7873, 6529, 6529, 6657, 6785, 7041, 4993, 4609,
+    4993, 4802, 4481, 4481, 7873, 2049, 2049, 7105, 4545, 7425, 4993, 5633,
+    5761, 11137, 4609, 7873, 6529, 6657, 8194, 11201, 3777, 7041, 1089, 1089,
+    4545, 4417, 4417, 4417, 4417, 4481, 2369, 8194, 2049, 8514, 1089, 4353,
+    4353, 4545, 4417, 7425, 5505, 4993, 4737, 4481, 4481, 4481, 4481, 5505,
+    5761, 5761, 5761, 11137, 6913, 4609, 4609, 4609, 2369, 7425, 6657, 8194,
+    6785, 2049, 2049, 2049, 11201, 1089, 7041, 7041, 7041, 8514, 4802, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 6529, 0, 0,
+    0, 0, 0, 0, 0, 0, 1089, 4226, 51906, 220930, 4353, 4545,
+    4545, 4993, 4993, 5633, 5761, 5761, 11137, 4609, 4609, 6529, 6529, 6657,
+    6657, 7041, 1089, 7105, 7105, 4417, 7425, 5761, 2369, 7873, 8194, 2049,
+    6913, 1089, 2369, 2049, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 3201, 1089, 7169, 7105, 7169, 7105, 7169, 7105, 3393, 4353,
+    3585, 4545, 3585, 4545, 3585, 4545, 3585, 4545, 3585, 4545, 3457, 4417,
+    3457, 4417, 3457, 4417, 3457, 4417, 3457, 4417, 7361, 7425, 4929, 4993,
+    5057, 5121, 5057, 5121, 5057, 5121, 5057, 5121, 5057, 5121, 3521, 4481,
+    3521, 4481, 5569, 5633, 5569, 5633, 5569, 5633, 5697, 5761, 5697, 5761,
+    5697, 5761, 5697, 5761, 14145, 11137, 14145, 11137, 14145, 11137, 3649, 4609,
+    3649, 4609, 3649, 4609, 3649, 4609, 3713, 2369, 3713, 2369, 3713, 2369,
+    3713, 2369, 2113, 7873, 2113, 7873, 6465, 6529, 6465, 6529, 6465, 6529,
+    6465, 6529, 6593, 6657, 6593, 6657, 6593, 6657, 6593, 6657, 6593, 6657,
+    6721, 6785, 6721, 6785, 6721, 6785, 6721, 6785, 3841, 2049, 3841, 2049,
+    3841, 2049, 3841, 2049, 3841, 2049, 8321, 11201, 8321, 11201, 6849, 6913,
+    6849, 6913, 6849, 6913, 6849, 6913, 6849, 6913, 12865, 3777, 12865, 3777,
+    3905, 4737, 6977, 7041, 6977, 7041, 6977, 7041, 5121, 6785, 6913, 4737,
+    1089, 6657, 6657, 6657, 221058, 4545, 3201, 1089, 3201, 1089, 3201, 1089,
+    3201, 1089, 3201, 1089, 3201, 1089, 3201, 1089, 3201, 1089, 3201, 1089,
+    3201, 1089, 3201, 1089, 3201, 1089, 3457, 4417, 3457, 4417, 3457, 4417,
+    3457, 4417, 3457, 4417, 3457, 4417, 3457, 4417, 3457, 4417, 3521, 4481,
+    3521, 4481, 3713, 2369, 3713, 2369, 3713, 2369, 3713, 2369, 3713, 2369,
+    3713, 2369, 3713, 2369, 3713, 2369, 3713, 2369, 3713, 2369, 3713, 2369,
+    3713, 2369, 3841, 2049, 3841, 2049, 3841, 2049, 3841, 2049, 3841, 2049,
+    3841, 2049, 3841, 2049, 3905, 4737, 3905, 4737, 3905, 4737, 3905, 4737,
+    43970, 46082, 8321, 11201, 3905, 4737, 1089, 1089, 1089, 1089, 1089, 1089,
+    1089, 1089, 3201, 3201, 3201, 3201, 3201, 3201, 3201, 3201, 4417, 4417,
+    4417, 4417, 4417, 4417, 3457, 3457, 3457, 3457, 3457, 3457, 4417, 4417,
+    4417, 4417, 4417, 4417, 4417, 4417, 3457, 3457, 3457, 3457, 3457, 3457,
+    3457, 3457, 4481, 4481, 4481, 4481, 4481, 4481, 4481, 4481, 3521, 3521,
+    3521, 3521, 3521, 3521, 3521, 3521, 2369, 2369, 2369, 2369, 2369, 2369,
+    3713, 3713, 3713, 3713, 3713, 3713, 2049, 2049, 2049, 2049, 2049, 2049,
+    2049, 2049, 3841, 3841, 3841, 3841, 2369, 2369, 2369, 2369, 2369, 2369,
+    2369, 2369, 3713, 3713, 3713, 3713, 3713, 3713, 3713, 3713, 1089, 1089,
+    4417, 4417, 4417, 4417, 4481, 4481, 2369, 2369, 2049, 2049, 2369, 2369,
+    1089, 1089, 1089, 1089, 1089, 1089, 1089, 1089, 3201, 3201, 3201, 3201,
+    3201, 3201, 3201, 3201, 4417, 4417, 4417, 4417, 4417, 4417, 4417, 4417,
+    3457, 3457, 3457, 3457, 3457, 3457, 3457, 3457, 2369, 2369, 2369, 2369,
+    2369, 2369, 2369, 2369, 3713, 3713, 3713, 3713, 3713, 3713, 3713, 3713,
+    1089, 1089, 1089, 1089, 1089, 1089, 1089, 3201, 3201, 3201, 3201, 3201,
+    1985, 4481, 1985, 12801, 221186, 4417, 4417, 4417, 4417, 4417, 3457, 3457,
+    3457, 3457, 3457, 221314, 221442, 221570, 4481, 4481, 4481, 4481, 4481, 4481,
+    3521, 3521, 3521, 3521, 221698, 221698, 221826, 2049, 2049, 2049, 2049, 6465,
+    6465, 2049, 2049, 3841, 3841, 3841, 3841, 6465, 221954, 13954, 12289, 2369,
+    2369, 2369, 2369, 2369, 3713, 3713, 3713, 3713, 3713, 1985, 12289, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0,
+    0, 0, 0, 1473, 1473, 1473, 1473, 222082, 222082, 8834, 12545, 1985,
+    1985, 2241, 1985, 833, 833, 222210, 833, 12737, 201282, 2177, 222338, 12673,
+    39170, 39683, 12673, 222465, 222530, 0, 0, 0, 0, 0, 1, 33730,
+    33859, 1985, 221442, 222659, 12289, 13058, 222851, 10881, 12353, 12417, 2177, 223042,
+    223170, 1473, 12545, 1473, 10881, 223299, 222082, 4673, 223490, 223618, 223746, 223874,
+    223170, 35009, 224002, 224130, 224258, 2177, 14081, 12545, 219394, 224387, 12801, 12545,
+    2177, 12545, 224580, 12545, 2177, 12609, 12545, 12737, 12545, 12545, 1, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 34945, 4481, 0, 0, 12929, 8641, 7233, 35009, 35073, 35137,
+    12737, 1473, 12993, 224833, 220033, 4609, 34945, 2305, 1857, 1921, 12929, 8641,
+    7233, 35009, 35073, 35137, 12737, 1473, 12993, 224833, 220033, 1089, 4417, 2369,
+    3777, 4417, 5121, 5633, 5761, 11137, 4609, 7873, 6657, 6785, 224899, 225090,
+    225218, 225346, 5697, 225475, 3649, 225667, 44674, 6849, 225858, 3585, 225987, 5569,
+    6721, 226178, 226306, 2113, 4929, 3201, 226435, 3393, 81154, 226626, 6721, 44674,
+    226754, 14145, 226885, 227202, 227332, 227587, 227779, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 227971, 228163, 3393, 3393, 225090, 228355, 228547, 3457,
+    4993, 7361, 4993, 5057, 5057, 5057, 5121, 5121, 3521, 3521, 5697, 5761,
+    32833, 3649, 228738, 228867, 7873, 2113, 15169, 6465, 6465, 6465, 229058, 6465,
+    229186, 229315, 229506, 8321, 6977, 229634, 229763, 229955, 6977, 4481, 5569, 3201,
+    7169, 3393, 4417, 4417, 3457, 7361, 7361, 14145, 2369, 1089, 7105, 4993,
+    4545, 230163, 15169, 231363, 7873, 4993, 4929, 2113, 6593, 4929, 5697, 5697,
+    3905, 3585, 4545, 4417, 4481, 5505, 231554, 15105, 2113, 231683, 7425, 15746,
+    231875, 232067, 232260, 232517, 232837, 233157, 233477, 233797, 234117, 234437, 234757, 235077,
+    235397, 235717, 236037, 236355, 3521, 236546, 236675, 236866, 8321, 236994, 237123, 237316,
+    237570, 12865, 237698, 237827, 5697, 3393, 3585, 14145, 4481, 38274, 238019, 238210,
+    11201, 104194, 238339, 238532, 238786, 3777, 107714, 238915, 5761, 4353, 4545, 11137,
+    239106, 239234, 239365, 220033, 4353, 7233, 5697, 239684, 239943, 240387, 1857, 1921,
+    1473, 641, 1473, 641, 1473, 641, 12481, 4673, 12481, 4673, 1473, 1473,
+    12801, 12801, 1473, 641, 1473, 641, 1473, 1473, 1473, 641, 1473, 641,
+    641, 1473, 1473, 1473, 1473, 1473, 1473, 641, 641, 641, 641, 641,
+    641, 641, 10881, 8321, 12481, 12993, 8321, 10881, 1473, 1473, 641, 641,
+    1473, 1473, 641, 641, 12993, 641, 12993, 12993, 641, 12993, 641, 12993,
+    12993, 12993, 12993, 12993, 12993, 641, 12993, 641, 12993, 641, 12481, 4673,
+    12481, 4673, 12993, 12993, 12801, 12801, 641, 641, 1473, 641, 1473, 641,
+    1473, 1473, 1473, 641, 1473, 641, 641, 641, 641, 641, 641, 641,
+    1473, 12481, 12481, 641, 12417, 641, 12417, 12353, 12417, 1473, 12353, 12417,
+    1473, 12353, 12417, 1473, 8321, 3393, 4545, 3457, 3457, 34945, 10881, 3585,
+    3457, 3457, 3457, 3457, 3457, 3457, 32833, 2113, 3841, 6593, 1473, 240578,
+    12737, 4673, 12481, 2177, 2177, 2177, 240708, 33284, 33539, 12801, 240963, 5697,
+    12353, 12353, 12353, 641, 641, 8834, 8834, 10881, 11201, 10881, 11201, 6593,
+    6593, 241155, 6593, 706, 241155, 6593, 6593, 6593, 241346, 12609, 12545, 1473,
+    1473, 12801, 12801, 12801, 12801, 12801, 12801, 12801, 241474, 12801, 12801, 241602,
+    12993, 12993, 12801, 12801, 12801, 12801, 241602, 12993, 12993, 12993, 12993, 12993,
+    12993, 12993, 12993, 12993, 12993, 12993, 12993, 12993, 12993, 12993, 12993, 12993,
+    12993, 12993, 12993, 12993, 12993, 12993, 241730, 241858, 241730, 241858, 12353, 12417,
+    1154, 2434, 241986, 12993, 12353, 12417, 12353, 12417, 242114, 242242, 12353, 12417,
+    12993, 12993, 12993, 12993, 12353, 12417, 12353, 12417, 12353, 12417, 12353, 12417,
+    12353, 12417, 12353, 12417, 12353, 12417, 12353, 12417, 12353, 12417, 11201, 11201,
+    11201, 242369, 57153, 242369, 57153, 10881, 11201, 12737, 1473, 3777, 4673, 12673,
+    2369, 2177, 12993, 1473, 12737, 1473, 3777, 12673, 12737, 12737, 12737, 12737,
+    12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12353, 12417,
+    12353, 12417, 12353, 12417, 1473, 1473, 1473, 12737, 6721, 11201, 10881, 11201,
+    12353, 4673, 10881, 11201, 10881, 11201, 2177, 2177, 2177, 2177, 242434, 242434,
+    242434, 12417, 12353, 241602, 11201, 10881, 12353, 12417, 10881, 11201, 12737, 32833,
+    12353, 12417, 242563, 242755, 12993, 12993, 242946, 243074, 12353, 12417, 12353, 12417,
+    242369, 57153, 242369, 57153, 12353, 12417, 12353, 12417, 12353, 12417, 12353, 12417,
+    641, 1473, 4673, 12481, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737,
+    12737, 12737, 12737, 12737, 12737, 3457, 34945, 12801, 10881, 10881, 11201, 10881,
+    10881, 12801, 242369, 57153, 242369, 57153, 12737, 12737, 12737, 12737, 1473, 2177,
+    2177, 2177, 2177, 2177, 12737, 32833, 32833, 1473, 243206, 243594, 12737, 12737,
+    12737, 12737, 224833, 220033, 224833, 220033, 10881, 2177, 12417, 3777, 244233, 12353,
+    12417, 12353, 32833, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    3521, 32833, 12993, 2177, 2177, 2177, 2177, 2177, 4673, 12481, 4673, 12481,
+    12353, 12417, 12353, 12417, 12353, 12417, 34945, 2177, 2177, 11201, 2177, 2177,
+    10881, 10881, 2177, 2177, 10881, 2177, 2177, 11201, 11201, 1985, 2177, 2177,
+    2177, 2177, 2177, 1985, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    12801, 12417, 2241, 2177, 34945, 641, 14081, 12993, 3137, 11201, 10881, 4481,
+    6529, 2369, 1089, 4417, 4481, 2369, 1089, 244803, 12737, 2177, 2177, 641,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 32833, 12673, 12353,
+    12417, 32833, 32833, 224833, 224833, 224833, 220033, 220033, 220033, 242369, 242369, 242369,
+    57153, 57153, 57153, 37633, 37633, 37633, 641, 37697, 37697, 37697, 641, 1473,
+    37633, 37633, 6593, 6593, 242369, 57153, 244994, 4673, 641, 641, 1473, 1473,
+    1473, 1473, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737,
+    12737, 12737, 12737, 12737, 12737, 1985, 12353, 245126, 641, 1473, 1473, 1473,
+    1473, 1473, 1473, 1921, 12929, 8641, 12737, 1473, 224833, 220033, 37633, 37697,
+    242369, 57153, 32833, 32833, 1473, 32833, 2177, 2177, 3457, 245517, 246343, 246800,
+    247826, 248971, 249679, 250635, 251340, 252106, 252742, 253143, 12353, 12417, 10881, 11201,
+    254605, 255436, 256206, 257094, 257094, 257475, 257670, 12353, 258051, 258243, 258435, 258627,
+    258819, 259011, 259203, 259395, 259586, 259714, 259842, 259970, 225346, 260098, 260226, 260354,
+    260483, 260675, 260867, 261059, 261251, 244803, 261443, 261635, 261827, 262018, 262147, 262339,
+    262530, 262658, 262786, 262914, 263042, 263171, 7105, 12545, 263362, 4673, 3137, 12353,
+    12417, 57153, 2177, 2177, 2177, 0, 14081, 12353, 12993, 263490, 2305, 1857,
+    1921, 12929, 8641, 7233, 35009, 35073, 35137, 48450, 263618, 47810, 263746, 263874,
+    264002, 47938, 199682, 199810, 199938, 49538, 264131, 264323, 264515, 264707, 264899, 265091,
+    265283, 265475, 265667, 265860, 266116, 266372, 266628, 266884, 267140, 267396, 267652, 267908,
+    268164, 268420, 213442, 213570, 213698, 213826, 213954, 214082, 214210, 214338, 214466, 214595,
+    214787, 214979, 215171, 215363, 215555, 268675, 268867, 269059, 269251, 269443, 269635, 269827,
+    899, 270019, 270211, 270403, 270595, 270787, 270979, 271171, 271363, 271555, 271747, 271939,
+    272131, 272323, 272515, 1283, 272707, 272899, 273091, 273283, 273475, 273667, 273859, 274051,
+    3201, 7169, 3393, 3585, 3457, 7361, 4929, 5057, 3521, 5441, 5569, 5697,
+    14145, 3649, 3713, 2113, 15169, 6465, 6593, 6721, 3841, 8321, 6849, 12865,
+    3905, 6977, 1089, 7105, 4353, 4545, 4417, 7425, 4993, 5121, 4481, 5505,
+    5633, 5761, 11137, 4609, 2369, 7873, 10945, 6529, 6657, 6785, 2049, 11201,
+    6913, 3777, 4737, 7041, 34945, 263618, 47810, 263746, 263874, 264002, 47938, 199682,
+    199810, 199938, 49538, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137,
+    48450, 34945, 1473, 1473, 641, 641, 1473, 1473, 641, 641, 1473, 1473,
+    641, 641, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737,
+    12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737,
+    12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737,
+    12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737,
+    12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737,
+    12737, 12737, 12737, 12737, 12737, 12737, 1473, 1473, 641, 641, 1473, 641,
+    12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737,
+    12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737,
+    12737, 12737, 12737, 12737, 12737, 12737, 12737, 4673, 12481, 12865, 1473, 641,
+    1473, 641, 1473, 641, 1473, 641, 1473, 641, 1473, 641, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 1473, 641, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    10881, 10881, 10881, 10881, 12417, 12417, 12417, 12417, 12417, 12417, 8321, 8321,
+    8321, 8321, 12353, 12353, 12353, 12353, 12353, 12353, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    224833, 220033, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    4673, 12481, 4673, 12481, 2177, 32833, 32833, 32833, 32833, 32833, 10881, 10881,
+    10881, 3713, 32833, 32833, 32833, 32833, 3713, 3713, 3713, 3713, 4673, 12481,
+    12481, 32833, 32833, 32833, 32833, 4673, 2177, 274246, 274633, 275208, 275718, 2177,
+    2177, 12353, 2177, 2177, 2177, 2177, 34945, 276099, 276298, 276298, 276931, 277123,
+    277315, 12865, 274633, 277511, 6849, 7169, 277961, 219394, 12353, 12417, 12353, 10881,
+    12417, 11201, 278545, 223042, 279628, 280394, 12737, 12737, 281039, 281988, 12737, 12737,
+    282258, 283398, 2177, 283792, 284806, 285193, 285767, 286211, 286402, 286532, 286787, 286979,
+    287171, 287366, 287760, 288770, 288898, 288898, 2177, 220033, 224833, 289031, 289484, 290245,
+    290570, 291207, 291654, 292038, 292423, 292869, 293190, 293575, 294023, 294471, 294916, 295174,
+    295558, 295945, 296524, 297290, 297929, 298503, 298955, 15169, 6465, 7169, 3649, 2113,
+    5633, 10945, 6529, 7105, 299661, 300491, 301191, 301644, 302414, 303302, 303692, 304455,
+    304905, 305483, 306186, 32833, 32833, 32833, 32833, 7105, 32833, 32833, 12737, 12737,
+    2177, 306819, 307011, 307203, 307395, 307587, 307779, 307971, 308170, 308808, 309327, 310292,
+    311558, 311947, 2305, 1857, 1921, 12929, 8641, 7233, 2177, 2177, 2177, 2177,
+    312647, 313094, 313479, 313927, 314376, 314886, 315275, 315979, 316686, 317575, 318031, 318991,
+    319943, 320392, 2177, 320901, 321232, 322245, 322573, 2177, 323394, 323522, 323656, 324164,
+    324436, 2177, 2177, 2177, 325718, 327123, 328351, 330337, 332429, 333261, 2369, 38402,
+    334083, 71235, 334279, 334723, 334919, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    335362, 335491, 335695, 336647, 337097, 337670, 14145, 5569, 11137, 338068, 275208, 339341,
+    340165, 275208, 340499, 341721, 7105, 32833, 12865, 343309, 344138, 344773, 345100, 345874,
+    347031, 348487, 348937, 349538, 2177, 2177, 2177, 2177, 351682, 11201, 2177, 12865,
+    12481, 2177, 2177, 2177, 292038, 2177, 2177, 2177, 2177, 2177, 351827, 353038,
+    353927, 354375, 241346, 2177, 2177, 354827, 355529, 356111, 357065, 357637, 357958, 358341,
+    32833, 358662, 359050, 359693, 360517, 360840, 361354, 361993, 362564, 2177, 12353, 362825,
+    362825, 362825, 362825, 363409, 7297, 7297, 364489, 365065, 365637, 365957, 366274, 366413,
+    367239, 367688, 367239, 368196, 368458, 369090, 369226, 12865, 3777, 3777, 3777, 12737,
+    12737, 12737, 12737, 12737, 12737, 12737, 12737, 369870, 2177, 2177, 2177, 2177,
+    2177, 2177, 370761, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 371330, 2177, 371484, 32833, 32833, 32833, 32833, 3137, 3137, 65,
+    2177, 65, 641, 641, 641, 1985, 1985, 833, 833, 1985, 833, 2113,
+    65, 373266, 374405, 374732, 2177, 2177, 224833, 220033, 224833, 220033, 12353, 12417,
+    12353, 12417, 12353, 12417, 224833, 220033, 37633, 37697, 2305, 1857, 1921, 12929,
+    8641, 7233, 35009, 35073, 35137, 48450, 2305, 1857, 1921, 12929, 8641, 7233,
+    35009, 35073, 35137, 48450, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073,
+    35137, 48450, 12417, 12737, 1473, 4673, 12481, 12417, 4673, 12417, 12417, 12417,
+    12417, 12417, 12417, 375500, 12417, 12417, 12417, 12417, 12417, 12417, 12417, 12417,
+    12417, 12417, 12417, 12417, 12417, 12417, 376267, 12417, 12417, 12417, 12481, 12417,
+    4673, 12481, 12417, 4673, 12417, 12417, 12417, 12417, 12417, 376965, 10881, 10881,
+    6721, 12353, 12417, 37633, 37697, 11201, 323522, 377282, 641, 4673, 220033, 12481,
+    10881, 11201, 32833, 10881, 6913, 12417, 12353, 2177, 2177, 2177, 6721, 6721,
+    32833, 12737, 1473, 1473, 1473, 12737, 32833, 32833, 32833, 32833, 32833, 32833,
+    242369, 57153, 12353, 12417, 1154, 2434, 242369, 57153, 224833, 220033, 10881, 11201,
+    12353, 12417, 12417, 12353, 12417, 1473, 12353, 12417, 1473, 12353, 12417, 12353,
+    12417, 12801, 1, 1089, 2241, 7105, 1985, 5633, 14081, 5761, 7297, 4353,
+    4481, 7425, 4673, 11137, 6657, 7873, 833, 4417, 12609, 5121, 35137, 2369,
+    65, 6529, 10881, 4545, 5505, 4993, 12417, 4609, 6785, 10945, 2241, 2177,
+    8641, 12353, 1473, 2049, 3137, 11201, 12673, 35201, 242369, 53185, 12737, 3777,
+    65, 15105, 14081, 12609, 12673, 12481, 34945, 7041, 35009, 224833, 12545, 3137,
+    6913, 57153, 32833, 4737, 220033, 12993, 377410, 3201, 377539, 7169, 377731, 5569,
+    377924, 5697, 378179, 3393, 3521, 7361, 378372, 14145, 6593, 2113, 378627, 3457,
+    378820, 5057, 379076, 3713, 379333, 6465, 379652, 3585, 5441, 4929, 379909, 3649,
+    6721, 15169, 380227, 380420, 380676, 380933, 381252, 3841, 381509, 8321, 381828, 382085,
+    382405, 382726, 383109, 12865, 383430, 383815, 384260, 384517, 384837, 385158, 385541, 6977,
+    385862, 386247, 386693, 387014, 6849, 387399, 387846, 3905, 388231, 388680, 389186, 389315,
+    389507, 389700, 389955, 390148, 390404, 390661, 390979, 391172, 391428, 391685, 392004, 392261,
+    392581, 392902, 393283, 393476, 393732, 393989, 394308, 394565, 394885, 395206, 395588, 395845,
+    396165, 396486, 396869, 397190, 397574, 397959, 398403, 398596, 398852, 399109, 399428, 399685,
+    400005, 400326, 400708, 400965, 401285, 401606, 401989, 402310, 402694, 403079, 403524, 403781,
+    404101, 404422, 404805, 405126, 405510, 405895, 406341, 406662, 407046, 407431, 407878, 408263,
+    408711, 409160, 409667, 409860, 410116, 410373, 410692, 410949, 411269, 411590, 411972, 412229,
+    412549, 412870, 413253, 413574, 413958, 414343, 414788, 415045, 415365, 415686, 416069, 416390,
+    416774, 417159, 417605, 417926, 418310, 418695, 419142, 419527, 419975, 420424, 420932, 421189,
+    421509, 421830, 422213, 422534, 422918, 423303, 423749, 424070, 424454, 424839, 425286, 425671,
+    426119, 426568, 427077, 427398, 427782, 428167, 428614, 428999, 429447, 429896, 430406, 430791,
+    431239, 431688, 432199, 432648, 433160, 433673, 12417, 12417, 12353, 12417, 1473, 12417,
+    12353, 12417, 11201, 10881, 10881, 11201, 12353, 12417, 12353, 12417, 12417, 12417,
+    10881, 11201, 12417, 12417, 12417, 12417, 12417, 12353, 12417, 12353, 12417, 12353,
+    12417, 12353, 12417, 12481, 4673, 12481, 4673, 12481, 4673, 12865, 12865, 12865,
+    12865, 12865, 12865, 12865, 12865, 12865, 12865, 12865, 12865, 12417, 434257, 435347,
+    12353, 12417, 12353, 12417, 12353, 12417, 1473, 12737, 12353, 12417, 12353, 12417,
+    12993, 12993, 12993, 12417, 12353, 12417, 1473, 10881, 1473, 1473, 641, 641,
+    1473, 641, 1473, 641, 12353, 12417, 10881, 11201, 12353, 12417, 10881, 11201,
+    12353, 12417, 10881, 11201, 12353, 12417, 10881, 11201, 12353, 10881, 12417, 11201,
+    12993, 12993, 12993, 12993, 12353, 12353, 12417, 12417, 641, 641, 12417, 12993,
+    12801, 12353, 12417, 12801, 12353, 12353, 12417, 12417, 12353, 12353, 12417, 12353,
+    11201, 10881, 436547, 2177, 37633, 37697, 224833, 220033, 224833, 220033, 12353, 12417,
+    242369, 57153, 242369, 57153, 242369, 57153, 12353, 12417, 12353, 12417, 12417, 12353,
+    242369, 57153, 641, 12417, 10881, 10881, 12353, 12353, 12417, 10881, 12417, 12417,
+    12353, 12417, 10881, 10881, 10881, 10881, 10881, 10881, 10881, 10881, 10881, 10881,
+    34945, 34945, 34945, 34945, 34945, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 12353, 12417, 2177, 2177, 4673, 12481, 2177, 2369,
+    32833, 32833, 10881, 10881, 10881, 10881, 32833, 12353, 12417, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 37633, 37697, 436738, 436866, 2177, 2177, 2177, 2177,
+    32833, 12353, 3457, 32833, 32833, 32833, 32833, 32833, 10881, 10881, 32833, 32833,
+    2177, 2177, 12737, 12737, 12737, 12737, 12737, 12737, 12417, 12481, 4673, 12481,
+    4673, 12481, 201282, 436995, 12353, 12417, 12737, 1473, 2177, 12737, 3777, 12673,
+    12737, 10881, 11201, 14145, 6849, 12865, 6593, 6593, 437188, 6593, 6593, 6593,
+    6593, 6593, 6593, 6593, 6593, 6593, 6593, 6593, 6593, 6593, 6593, 6593,
+    6593, 242434, 12353, 14081, 2434, 641, 12737, 12737, 12737, 12737, 12737, 12737,
+    12737, 1473, 1473, 1473, 1473, 437442, 437570, 3777, 3777, 3777, 3777, 2177,
+    437698, 437826, 3777, 3777, 4673, 12737, 1473, 3777, 1473, 1473, 14081, 3841,
+    10881, 11201, 11201, 10881, 10881, 11201, 12865, 34945, 12865, 34945, 6913, 11137,
+    11201, 10881, 10881, 11201, 2177, 10881, 11201, 10881, 11201, 14145, 6849, 11201,
+    10881, 12865, 10881, 11201, 10881, 11201, 10881, 10881, 10881, 11201, 11201, 11201,
+    12353, 12417, 12993, 12993, 32833, 32833, 12801, 12801, 12801, 12993, 12993, 12993,
+    12993, 437954, 438082, 438210, 12993, 438338, 438467, 12993, 12993, 12353, 12417, 438658,
+    438786, 241730, 241858, 241730, 241858, 241730, 241858, 241730, 241858, 12353, 12417, 12353,
+    12417, 12353, 12417, 12993, 12993, 12353, 12417, 12801, 12801, 12993, 12993, 12993,
+    12993, 242946, 243074, 242946, 243074, 242946, 243074, 242946, 243074, 438914, 439042, 12353,
+    12417, 1154, 2434, 1154, 242434, 242434, 12353, 12417, 12353, 12417, 12353, 12417,
+    12353, 12417, 12993, 12353, 12417, 12353, 12417, 12353, 12417, 12353, 12417, 12353,
+    12417, 12353, 12417, 1154, 2434, 12353, 12417, 12353, 12417, 12353, 12417, 12353,
+    12417, 12353, 12417, 12353, 12417, 12353, 12417, 12353, 12417, 242369, 57153, 12353,
+    12417, 12353, 12417, 1473, 1473, 12353, 12417, 242434, 242434, 12737, 12737, 12737,
+    12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737,
+    12737, 12737, 12737, 12737, 1473, 1473, 641, 641, 641, 641, 8834, 8834,
+    436547, 436547, 242563, 242755, 241730, 241858, 439171, 436547, 13186, 641, 641, 4673,
+    12481, 12481, 4673, 1473, 241730, 10881, 11201, 4673, 12481, 12481, 4673, 1473,
+    641, 11201, 10881, 11201, 10881, 1473, 1473, 12481, 12481, 641, 641, 1473,
+    1473, 32833, 439379, 440595, 1473, 1473, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 12353,
+    12353, 12353, 12353, 12353, 12353, 12353, 12353, 12353, 12353, 12353, 12353, 12353,
+    12353, 12353, 12353, 12353, 12353, 12353, 12417, 12417, 12353, 12417, 12417, 12417,
+    12353, 12353, 12353, 12417, 11201, 4673, 12481, 2177, 2177, 2177, 2177, 2177,
+    441794, 2177, 2177, 441924, 2177, 4673, 12481, 4673, 12481, 12417, 12417, 12353,
+    10881, 12417, 11201, 1473, 641, 12481, 4673, 12481, 4673, 12353, 10881, 12417,
+    11201, 12417, 12353, 12353, 10881, 12417, 11201, 12481, 4673, 12481, 4673, 12353,
+    10881, 12417, 11201, 1473, 641, 1473, 641, 1473, 641, 12353, 10881, 12417,
+    11201, 12353, 10881, 12417, 11201, 12353, 10881, 12417, 11201, 12353, 12417, 12353,
+    12417, 12353, 12417, 3201, 12353, 10881, 12417, 11201, 12353, 10881, 12417, 11201,
+    12353, 12417, 12353, 12417, 10881, 10881, 11201, 11201, 12353, 12417, 12353, 12417,
+    10881, 10881, 11201, 11201, 12353, 12417, 12353, 12417, 10881, 10881, 11201, 11201,
+    10881, 10881, 2177, 2177, 2177, 3777, 3777, 3777, 2177, 2177, 2177, 2177,
+    2177, 10881, 11201, 12353, 12417, 292423, 10881, 11201, 2177, 2177, 2177, 2177,
+    12737, 3137, 12737, 292869, 292869, 292869, 292869, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 442182, 442565, 442884, 443142, 443527, 443975, 444424, 444936,
+    2177, 2177, 2177, 2177, 12353, 10881, 12417, 11201, 2177, 2177, 2177, 8321,
+    3649, 15169, 445442, 48579, 445570, 2177, 2177, 2177, 2177, 2177, 2177, 32833,
+    3201, 7169, 8321, 4929, 3585, 3457, 17922, 9218, 6977, 3521, 5441, 3521,
+    4929, 5569, 5697, 14145, 3649, 3713, 2113, 6465, 6593, 6721, 3841, 7361,
+    5057, 3713, 2113, 445699, 3393, 18178, 15746, 3841, 3521, 3457, 5057, 445890,
+    3457, 3457, 446018, 446146, 3713, 446018, 3970, 3841, 3521, 3201, 14145, 18178,
+    1089, 7105, 11201, 4993, 4545, 4417, 8514, 9346, 7041, 4481, 5505, 4481,
+    4993, 5633, 5761, 11137, 4609, 2369, 7873, 6529, 6657, 6785, 2049, 7425,
+    5121, 2369, 7873, 446275, 4353, 16002, 8194, 2049, 4481, 4417, 5121, 127106,
+    4417, 4417, 127874, 127746, 2369, 127874, 4802, 2049, 4481, 1089, 11137, 16002,
+    5697, 5761, 5697, 2113, 6465, 1089, 6785, 5057, 5121, 5569, 5633, 6977,
+    7041, 3201, 14145, 3201, 3201, 11201, 6849, 6913, 11201, 5057, 5121, 2369,
+    4417, 6529, 2369, 4417, 5505, 8321, 6593, 6977, 3201, 1089, 7169, 7105,
+    4929, 4993, 3585, 4545, 3457, 4417, 7233, 7233, 6977, 7041, 3457, 4417,
+    3970, 4802, 3521, 4481, 5569, 5633, 5697, 5761, 14145, 11137, 3649, 4609,
+    14210, 20482, 3713, 2369, 2113, 7873, 6465, 6529, 6593, 6657, 6721, 6785,
+    3841, 2049, 14338, 14722, 14466, 14850, 14594, 14978, 3713, 2369, 1985, 1985,
+    12289, 12289, 3457, 4417, 5569, 5633, 3649, 4609, 3649, 4609, 3713, 2369,
+    446467, 446467, 15746, 8194, 15746, 8194, 15746, 8194, 12865, 3777, 5057, 5121,
+    5057, 5121, 5057, 5121, 5057, 5121, 5057, 5121, 5057, 5121, 5441, 5505,
+    17026, 19586, 3393, 4353, 3393, 4353, 24450, 5954, 29698, 29826, 6849, 6913,
+    15105, 446659, 446851, 447045, 447363, 447556, 447811, 15746, 8194, 5441, 5505, 4609,
+    0, 0, 12865, 3777, 263490, 448002, 448130, 13186, 55298, 12673, 1985, 1089,
+    7105, 4993, 4545, 4417, 11201, 7041, 6785, 4481, 24066, 5761, 11137, 4609,
+    2369, 24706, 8514, 6529, 6657, 25218, 2049, 7873, 5633, 27778, 448258, 8194,
+    16002, 8706, 9346, 448387, 26563, 14850, 5505, 5121, 4417, 4737, 6913, 3777,
+    2369, 4417, 4226, 1089, 7105, 7105, 4993, 4993, 19586, 19586, 4545, 4545,
+    4545, 4545, 4417, 7425, 5633, 5633, 5633, 5121, 5121, 5121, 5121, 1985,
+    3777, 3777, 10945, 10945, 4481, 5505, 5505, 5505, 5761, 11137, 4609, 53058,
+    5954, 7873, 2049, 6529, 6529, 27778, 27778, 27778, 6657, 6657, 4353, 6785,
+    6785, 448578, 6785, 11201, 6913, 4737, 7041, 7041, 7041, 4417, 2369, 6913,
+    1, 0, 448707, 448899, 449091, 449283, 449476, 449731, 449923, 450116, 450371, 450564,
+    450819, 451011, 451203, 451396, 451651, 451844, 452101, 452420, 452675, 452868, 453124, 452868,
+    453124, 96835, 95939, 96131, 95747, 96835, 8194, 97027, 135043, 134147, 134339, 133955,
+    135043, 16002, 135235, 122819, 121923, 122115, 121731, 122819, 8514, 123011, 453380, 453636,
+    453892, 454148, 453380, 26563, 454404, 454660, 454916, 455172, 455428, 454660, 455683, 455876,
+    456131, 456323, 456515, 456707, 456131, 41026, 456899, 457091, 457283, 457475, 457667, 457091,
+    37250, 457859, 458051, 458243, 458435, 458627, 458051, 40898, 458819, 7105, 11201, 4993,
+    4545, 8514, 7041, 5633, 5761, 11137, 4609, 2369, 7873, 6529, 6657, 6785,
+    14850, 8706, 16002, 8194, 18820, 7425, 15362, 1089, 4417, 19586, 2049, 4417,
+    37378, 19202, 4417, 2369, 19458, 12737, 12737, 12737, 12737, 12737, 12737, 12737,
+    12737, 12737, 12737, 12737, 2177, 12481, 4673, 32833, 12545, 12545, 12545, 2241,
+    4673, 4673, 4673, 12417, 12993, 223170, 4673, 1473, 12801, 12481, 4673, 12801,
+    12801, 242369, 57153, 242369, 57153, 242369, 57153, 224833, 220033, 459010, 459138, 12545,
+    12545, 12545, 12737, 3137, 1985, 2177, 1473, 2241, 1473, 2241, 14081, 12737,
+    12737, 12737, 6593, 222082, 459267, 12673, 12545, 641, 3393, 12993, 2241, 833,
+    1473, 12545, 12673, 12545, 12673, 12673, 14081, 4673, 436995, 2241, 2113, 14081,
+    4673, 12737, 12737, 15105, 65, 3137, 242369, 57153, 242369, 57153, 224833, 220033,
+    224833, 220033, 1473, 459460, 459715, 17282, 17282, 17282, 459907, 460101, 460418, 460547,
+    460547, 460738, 460867, 461060, 461060, 461316, 461316, 461316, 461316, 460418, 461571, 460418,
+    460418, 461763, 461763, 461956, 462210, 462338, 462466, 462595, 462787, 462338, 462979, 463172,
+    463172, 463427, 463620, 463620, 463877, 464195, 464388, 464642, 464770, 464898, 462979, 462979,
+    465027, 465218, 465218, 461316, 461316, 461316, 461316, 461316, 465348, 465348, 465348, 465603,
+    464642, 464642, 465795, 465987, 466179, 466179, 466179, 466370, 17282, 466498, 466498, 466628,
+    466884, 466884, 467139, 467331, 467522, 467651, 467844, 467844, 467844, 17282, 468099, 468293,
+    468293, 468293, 468611, 468802, 468802, 464642, 468932, 469187, 469378, 469508, 469763, 462979,
+    462979, 462979, 462979, 461956, 469954, 470082, 470211, 464642, 470404, 470658, 470787, 470981,
+    471299, 471490, 471490, 471619, 471619, 471812, 471812, 470211, 470211, 470211, 17282, 472067,
+    465027, 472259, 17282, 472451, 472642, 472771, 459907, 472642, 472962, 473090, 460101, 465218,
+    459460, 460418, 286979, 460547, 286402, 473219, 473410, 473540, 473794, 462979, 460738, 460867,
+    459715, 473922, 474051, 474243, 469187, 474434, 462979, 474563, 474755, 473794, 474946, 475074,
+    475202, 475332, 475587, 461060, 461316, 462979, 467651, 475780, 476037, 476356, 460418, 468099,
+    476611, 461571, 467139, 476803, 476356, 17282, 476356, 460418, 475780, 471619, 461763, 476994,
+    466370, 461956, 474563, 462210, 477123, 477315, 468099, 473540, 462338, 462466, 462595, 462595,
+    464898, 477508, 474563, 462787, 477763, 462338, 473410, 477955, 462979, 471490, 463172, 463427,
+    463620, 468802, 461571, 463877, 478148, 466498, 464195, 464388, 478404, 464642, 478659, 478850,
+    476611, 478981, 479300, 479556, 464770, 479810, 479938, 480067, 464770, 471299, 464898, 477955,
+    462979, 462979, 462979, 465795, 480258, 480387, 286402, 465027, 465218, 465218, 480579, 461316,
+    465348, 464642, 465603, 472642, 480771, 472642, 464642, 465795, 480964, 475202, 474563, 465987,
+    481219, 476037, 481412, 287171, 481666, 466179, 466370, 481797, 480387, 482116, 17282, 466498,
+    466628, 466884, 467139, 470082, 477315, 462979, 474563, 467331, 471619, 482371, 467522, 482564,
+    467651, 461763, 480964, 467844, 17282, 474051, 482820, 286402, 468099, 468293, 468611, 468802,
+    286402, 483076, 464642, 468932, 469763, 475332, 476994, 469187, 465987, 476803, 469378, 469508,
+    469763, 462979, 461956, 483333, 469954, 470082, 483651, 483844, 477315, 468293, 286402, 470211,
+    464642, 470404, 470658, 470658, 470787, 469954, 470981, 477763, 484099, 474563, 471299, 484292,
+    470082, 477763, 473410, 471490, 471619, 471812, 470211, 462595, 12737, 12737, 12737, 12737,
+    12737, 12737, 12737, 12737, 12737, 12737, 12737, 12737, 1, 54722, 202050, 833,
+    484549, 833, 4673, 34945, 12353, 484866, 1154, 484995, 242369, 485186, 37633, 485314,
+    485442, 485571, 7297, 485762, 242369, 485186, 57218, 485891, 459010, 486083, 57218, 485891,
+    486274, 13058, 221442, 222210, 7297, 2305, 1857, 1921, 12929, 8641, 7233, 35009,
+    35073, 35137, 0, 0, 0, 0, 0, 0, 12801, 12737, 12737, 12737,
+    12737, 12481, 7297, 44292, 46852, 486404, 486660, 1473, 486916, 487190, 12801, 32833,
+    1089, 1089, 4481, 4481, 2049, 2049, 4417, 4417, 2369, 2369, 113474, 128194,
+    113730, 128450, 113602, 128322, 114242, 128962, 114370, 129090, 94530, 120514, 94786, 120770,
+    94658, 120642, 95298, 121282, 95426, 121410, 105154, 124034, 16258, 124290, 105282, 105282,
+    124162, 105794, 124802, 105922, 124930, 109442, 109698, 109570, 110210, 110338, 86210, 102722,
+    142146, 86466, 102978, 142402, 86338, 102850, 142274, 86978, 103490, 142914, 87106, 103618,
+    143042, 90370, 90626, 90498, 91138, 91266, 46594, 46594, 37378, 37378, 67394, 67394,
+    93314, 93570, 93442, 94082, 94210, 67074, 67074, 67842, 67714, 119170, 4609, 104066,
+    113474, 114242, 1985, 12289, 1985, 12289, 833, 833, 488580, 12993, 1089, 1089,
+    4481, 4481, 2049, 2049, 4417, 4417, 2369, 2369, 113474, 128194, 113730, 128450,
+    113602, 128322, 114242, 128962, 114370, 129090, 94530, 120514, 94786, 120770, 94658, 120642,
+    95298, 121282, 95426, 121410, 105154, 124034, 16258, 124290, 105282, 105282, 124162, 105794,
+    124802, 105922, 124930, 109442, 109698, 109570, 110210, 110338, 86210, 102722, 142146, 86466,
+    102978, 142402, 86338, 102850, 142274, 86978, 103490, 142914, 87106, 103618, 143042, 90370,
+    90626, 90498, 91138, 91266, 46594, 46594, 37378, 37378, 67394, 67394, 93314, 93570,
+    93442, 94082, 94210, 67074, 67074, 67842, 67714, 119170, 4609, 104066, 113474, 114242,
+    103938, 104194, 104706, 104834, 1473, 0, 833, 833, 488836, 7169, 2113, 14145,
+    7361, 3585, 6721, 3649, 5697, 4929, 5569, 5057, 5441, 15169, 12865, 8386,
+    15874, 8066, 6465, 6977, 3393, 6593, 3201, 3713, 3457, 489090, 489218, 489346,
+    489474, 10370, 489602, 489730, 489859, 490051, 490242, 3521, 3841, 490370, 8321, 6082,
+    490498, 4481, 4417, 4609, 4993, 61058, 80642, 4609, 10114, 27138, 4545, 36546,
+    6529, 80770, 80898, 81026, 11778, 81154, 81282, 31554, 11137, 7105, 45954, 62338,
+    6657, 4098, 0, 5505, 61186, 4353, 5633, 6785, 7873, 5121, 1089, 4226,
+    46594, 66435, 66626, 4417, 66755, 66946, 2369, 67074, 67203, 6338, 67394, 2049,
+    67523, 67714, 67842, 37378, 67970, 19842, 4481, 0, 42370, 50946, 66178, 81730,
+    82115, 82434, 83139, 11010, 84034, 37890, 84418, 84738, 61826, 61954, 62210, 62467,
+    62659, 63426, 63682, 63938, 64067, 64258, 64386, 64514, 64898, 28546, 6977, 0,
+    3649, 85378, 85506, 65922, 38658, 15169, 71621, 71942, 72964, 75398, 75781, 76356,
+    3841, 79171, 1473, 94082, 2305, 1857, 1921, 12929, 3201, 7169, 3393, 3521,
+    236546, 236675, 236866, 1089, 7105, 4353, 490626, 490754, 490882, 491010, 491138, 491267,
+    491458, 491587, 491778, 491907, 492099, 492291, 492482, 492611, 492804, 493060, 493314, 493442,
+    493571, 493764, 2113, 6721, 5569, 5057, 11201, 66306, 4737, 5633, 212226, 158466,
+    67970, 1089, 6721, 494018, 494146, 494275, 494466, 494595, 494787, 494978, 495108, 495364,
+    495619, 495812, 496068, 496323, 496516, 3649, 5057, 6593, 2113, 263042, 3585, 496770,
+    496898, 497026, 497155, 497346, 497474, 497602, 497730, 497858, 497987, 498179, 498372, 498629,
+    498946, 15169, 113602, 96131, 94658, 105922, 109570, 86210, 86466, 141058, 86978, 87106,
+    90498, 93314, 93570, 93442, 94082, 94210, 270595, 271939, 270019, 1283, 271747, 269827,
+    272707, 241986, 271171, 899, 271363, 272899, 272323, 270787, 499076, 499332, 499588, 499844,
+    500100, 500356, 500612, 269635, 500868, 501124, 501380, 501636, 501892, 502148, 502404, 502663,
+    503109, 503428, 503684, 503940, 504196, 504452, 504708, 504964, 505220, 505476, 505733, 506054,
+    506438, 506823, 507269, 507590, 507973, 508293, 508614, 508998, 509382, 509767, 510213, 510534,
+    508614, 510918, 511302, 511685, 512006, 512391, 512837, 513157, 513478, 513861, 514182, 1154,
+    2434, 477123, 474051, 477123, 514565, 48450, 49538, 335362, 49666, 514882, 49794, 515010,
+    49026, 515139, 515330, 515458, 515586, 515714, 515842, 515970, 516098, 516226, 516354, 335362,
+    516482, 48066, 516610, 516738, 516866, 270595, 271939, 270019, 1283, 271747, 269827, 272707,
+    241986, 271171, 899, 271363, 272899, 272323, 270787, 499076, 499332, 499588, 499844, 500100,
+    500356, 500612, 269635, 500868, 501124, 501380, 501636, 501892, 502148, 516998, 517380, 3841,
+    517636, 503428, 503684, 503940, 504196, 504452, 504708, 504964, 505220, 505476, 505733, 506054,
+    506438, 506823, 507269, 507590, 507973, 508293, 508614, 508998, 509382, 509767, 510213, 510534,
+    508614, 510918, 517893, 518214, 518597, 518918, 508998, 519302, 508614, 519688, 514182, 513478,
+    520200, 520712, 521224, 521734, 522118, 508998, 522501, 522823, 512006, 512391, 512837, 513157,
+    513478, 523269, 523586, 523714, 523842, 523970, 49666, 524098, 524226, 524354, 524482, 524610,
+    524738, 524866, 48194, 524994, 514882, 525122, 525250, 525378, 525506, 525634, 525762, 525890,
+    526018, 526146, 526275, 526467, 526659, 526850, 526979, 527170, 527299, 1089, 4481, 2049,
+    2049, 2369, 113474, 113730, 113602, 114242, 114370, 94530, 94786, 94658, 95298, 95426,
+    105154, 16258, 105282, 105794, 105922, 109442, 109698, 109570, 110210, 110338, 86210, 86466,
+    86338, 86978, 87106, 90370, 90626, 90498, 91138, 91266, 46594, 37378, 67394, 93314,
+    93570, 93442, 94082, 94210, 67074, 67842, 67714, 119170, 527493, 527817, 528389, 528710,
+    529091, 529286, 529668, 529923, 530118, 530500, 530757, 531075, 531270, 531653, 531975, 532422,
+    532805, 533124, 533382, 533765, 534087, 534532, 534792, 535305, 535880, 536388, 536649, 537224,
+    537733, 538052, 538310, 538693, 539013, 539335, 539784, 540293, 540612, 540869, 541188, 541446,
+    541827, 542020, 542276, 542535, 542983, 543429, 543750, 544135, 544581, 544900, 545160, 545669,
+    545988, 546246, 546629, 546951, 547396, 547655, 548101, 548421, 548740, 548996, 549253, 549572,
+    549827, 550021, 550340, 550596, 550853, 551172, 551428, 551684, 551943, 552390, 552773, 553096,
+    553604, 553863, 554309, 554628, 554628, 554884, 555141, 555460, 555717, 556037, 556355, 556552,
+    557060, 557314, 557442, 557570, 557698, 557826, 557954, 558082, 558210, 558338, 558466, 558595,
+    558787, 558979, 559171, 559363, 559555, 559747, 559939, 560131, 560323, 560515, 560707, 560899,
+    561091, 561283, 561475, 124034, 489474, 561667, 561858, 561986, 562114, 562243, 562435, 490370,
+    562630, 563014, 563399, 563845, 564164, 564418, 564546, 564674, 564802, 564930, 565058, 565186,
+    565314, 565443, 565636, 565890, 566018, 566154, 566793, 84162, 567362, 567490, 567619, 567811,
+    568003, 568195, 568394, 84290, 66306, 220098, 569026, 569154, 569282, 569410, 569538, 569666,
+    569796, 570052, 570307, 570500, 570756, 571012, 571267, 571460, 571715, 571909, 572226, 572355,
+    572547, 572739, 572931, 573125, 573447, 14978, 66178, 573899, 84418, 574594, 574722, 574857,
+    575426, 575554, 152066, 575682, 575810, 575945, 576514, 576642, 576770, 576900, 577156, 577412,
+    577666, 577794, 577922, 578052, 578307, 578498, 578626, 86210, 494978, 34818, 578756, 579010,
+    579138, 80898, 82306, 579267, 579458, 37890, 225475, 579587, 579778, 579908, 580163, 580354,
+    64642, 580482, 580610, 580739, 580931, 581122, 581250, 581378, 581506, 581634, 581762, 581890,
+    582018, 582146, 582275, 582467, 582659, 582851, 583043, 583235, 583427, 583619, 583811, 584003,
+    584195, 584387, 584579, 584771, 584963, 585155, 585347, 585539, 585731, 585923, 586115, 586307,
+    586499, 586691, 479556, 586883, 587075, 462338, 476803, 587267, 587459, 484292, 473922, 472771,
+    17282, 587652, 587907, 24450, 588099, 588292, 588547, 588738, 588868, 589123, 589315, 589507,
+    470787, 589699, 24450, 589123, 589892, 590148, 590403, 469763, 590595, 590787, 590979, 468802,
+    591171, 591362, 591492, 591746, 482116, 473922, 591877, 592195, 592387, 483333, 479300, 461763,
+    286532, 462787, 462338, 592579, 472962, 592770, 469954, 477508, 17282, 476803, 592899, 593093,
+    469508, 593411, 593603, 593796, 594052, 473540, 594306, 462338, 594435, 594628, 481412, 594884,
+    595138, 17282, 595269, 595589, 480771, 595907, 465027, 477763, 596099, 596291, 596484, 591746,
+    596740, 596996, 482564, 460867, 597251, 590787, 595138, 17282, 471812, 597444, 597700, 597955,
+    598147, 598340, 598595, 598788, 17282, 286402, 460418, 464642, 599043, 599236, 599491, 470082,
+    467331, 599683, 591746, 599875, 600067, 600260, 481412, 600515, 459715, 473794, 600707, 461571,
+    599875, 600899, 469954, 601091, 601284, 461571, 463620, 601540, 464642, 594052, 472642, 601795,
+    471490, 471619, 462338, 459715, 601284, 481666, 473922, 595269, 480771, 601986, 602115, 602308,
+    602563, 462210, 602754, 477763, 465348, 220802, 600707, 602883, 475332, 476803, 603076, 464642,
+    603331, 600899, 470404, 589315, 474051, 603524, 603780, 467651, 469508, 480771, 286402, 604035,
+    599043, 604227, 460418, 604420, 604675, 604867, 464388, 598147, 605059, 605253, 470082, 477955,
+    605572, 478659, 474755, 605827, 606020, 477955, 587907, 464388, 462979, 286402, 606275, 461316,
+    474243, 606466, 286532, 606596, 606852, 607107, 459460, 463427, 594884, 476356, 593093, 607300,
+    607555, 460101, 470658, 482116, 607747, 607939, 589315, 608131, 473410, 460867, 595138, 608324,
+    476356, 605572, 474051, 482116, 608579, 464770, 608772, 468802, 599043, 608579, 608579, 601284,
+    480067, 476611, 592770, 478404, 609028, 468802, 468802, 481219, 603076, 286402, 609283, 472771,
+    478148, 609474, 609604, 3457, 609859, 17282, 594052, 610051, 466628, 476356, 471619, 481797,
+    473794, 602883, 601091, 610244, 592387, 472451, 595138, 461060, 610499, 610691, 469763, 465027,
+    610884, 466628, 482371, 611140, 589315, 286402, 596291, 471619, 473794, 466628, 611395, 460418,
+    611395, 469763, 611587, 597700, 460867, 611779, 473090, 605253, 611972, 612227, 612419, 612611,
+    472451, 480771, 461763, 480067, 465348, 470658, 467331, 3457, 470658, 612804, 613060, 467651,
+    590787, 478404, 613316, 464642, 613572, 470211, 17282, 478404, 476356, 589507, 16130, 613826,
+    462979, 613955, 614147, 461571, 614340, 482371, 614595, 614787, 476803, 473794, 474563, 614979,
+    466370, 615170, 17282, 615298, 468802, 607555, 615426, 615556, 615298, 611587, 589315, 611587,
+    469187, 615811, 616004, 616260, 595138, 474051, 616515, 600899, 463620, 591746, 462979, 473922,
+    616708, 602563, 616966, 480258, 617347, 467139, 586691, 482564, 609283, 473794, 617539, 617732,
+    591171, 473540, 476356, 594306, 468802, 479810, 480387, 474051, 609283, 608324, 480964, 617987,
+    3649, 609283, 286402, 618178, 461060, 618307, 608324, 473922, 618499, 618690, 613826, 592387,
+    17282, 618819, 591746, 619010, 472642, 589507, 590403, 286787, 619140, 593603, 589699, 619140,
+    619395, 469187, 589315, 16130, 619588, 619843, 619395, 619395, 476803, 286532, 611395, 468802,
+    470082, 603780, 620035, 614595, 461956, 590787, 469378, 471490, 472771, 459715, 600899, 594884,
+    599683, 470658, 445890, 463427, 620228, 593603, 479556, 620483, 620676, 620676, 446146, 590595,
+    590595, 587907, 476994, 467139, 462979, 480387, 620931, 621124, 464195, 621379, 621570, 3457,
+    589315, 474051, 3457, 478981, 477123, 621698, 466370, 476994, 612419, 587907, 601091, 460418,
+    618819, 474563, 621828, 611395, 614595, 622084, 480067, 615426, 483076, 477508, 622339, 591171,
+    467331, 479938, 611587, 286402, 461060, 622531, 622339, 622722, 622851, 592770, 603780, 619588,
+    16130, 623042, 623171, 465603, 474563, 589315, 473794, 613955, 609859, 623363, 473794, 623556,
+    621570, 623812, 460418, 463427, 602754, 467139, 591746, 622722, 612227, 624068, 614595, 624324,
+    617987, 469378, 483333, 480387, 480258, 594435, 17282, 592770, 446146, 624580, 614340, 600707,
+    624836, 17282, 474563, 473794, 614340, 445890, 460418, 459715, 477315, 625091, 286402, 596996,
+    598595, 612227, 459715, 467139, 619010, 625284, 467139, 459715, 286979, 471619, 619395, 463427,
+    625541, 473410, 612419, 625860, 478404, 626115, 474051, 607555, 591746, 619395, 473410, 626307,
+    603780, 594306, 594306, 626499, 625541, 286532, 626691, 445890, 594435, 460738, 460867, 615426,
+    626884, 592770, 618178, 627140, 627395, 590595, 619395, 592195, 620035, 286211, 627588, 473410,
+    473219, 461316, 611587, 612419, 479556, 468293, 627843, 628035, 621379, 469187, 468802, 621379,
+    464642, 469378, 602563, 469187, 611972, 590595, 469187, 461571, 471812, 482116, 460738, 471619,
+    589315, 619395, 609028, 17282, 628228, 587907, 628485, 612419, 472067, 589315, 628803, 460418,
+    628996, 17282, 460418, 476803, 629252, 474946, 17282, 589315, 626307, 479300, 286979, 629508,
+    600707, 601284, 474563, 473219, 629764, 602563, 629508, 480771, 630019, 473794, 484099, 473794,
+    630212, 630468, 630723, 475202, 471812, 468802, 628228, 630916, 470082, 631171, 603076, 609283,
+    602563, 631364, 483651, 631619, 620228, 475780, 631811, 619395, 468802, 483651, 609859, 632003,
+    614979, 632196, 473794, 464642, 483076, 482564, 467844, 461060, 460418, 475074, 461060, 17282,
+    464642, 17282, 467139, 482564, 601795, 626307, 601986, 600899, 474051, 632452, 461763, 613955,
+    586691, 629508, 460738, 594884, 473922, 472642, 473922, 477955, 630723, 460418, 630212, 591877,
+    625091, 611587, 632708, 590787, 460867, 17282, 472642, 621379, 628228, 627140, 465987, 621379,
+    592899, 632963, 609859, 470658, 586691, 633155, 616515, 477315, 473794, 469508, 17282, 633347,
+    609859, 622722, 461763, 633540, 461763, 474051, 467331, 471812, 593411, 633796, 626115, 593603,
+    630723, 286402, 602754, 598595, 587907, 463877, 481412, 467139, 473794, 470658, 473794, 634051,
+    617539, 617539, 469187, 618178, 17282, 595907, 593093, 626691, 460418, 477763, 478148, 634242,
+    587075, 634371, 634564, 614340, 474563, 634821, 635139, 469508, 635332, 286787, 591746, 465218,
+    599875, 464898, 479300, 610884, 17282, 588099, 601284, 473794, 630723, 477763, 468802, 17282,
+    474946, 635588, 635332, 466179, 616515, 445890, 470658, 595138, 635843, 594306, 634242, 477508,
+    624068, 636035, 623812, 636228, 601795, 636484, 470787, 461763, 462595, 636739, 594306, 482564,
+    469954, 632452, 594884, 598595, 473794, 462595, 474563, 625860, 636932, 629764, 470404, 626115,
+    465218, 619395, 619010, 610499, 635332, 474563, 475202, 637187, 591746, 626307, 478404, 474563,
+    475332, 621124, 622339, 484099, 621124, 637380, 465027, 461956, 628996, 465987, 589315, 484292,
+    460867, 624836, 637636, 591492, 615298, 461571, 637892, 17282, 609028, 479300, 476803, 467139,
+    595138, 630019, 592387, 466498, 477955, 638148, 638403, 464642, 17282, 622339, 465218, 600899,
+    477123, 638596, 638852, 608772, 471812, 462338, 482116, 636035, 630212, 475332, 629764, 607555,
+    639107, 626115, 639298, 615426, 600515, 482820, 588099, 635332, 468099, 464642, 639428, 594435,
+    479938, 599875, 461571, 639683, 460418, 634242, 599043, 460418, 469187, 479938, 639874, 591746,
+    636484, 630723, 638596, 473090, 640003, 445890, 469187, 589315, 471490, 17282, 589315, 592770,
+    586691, 606466, 470404, 471490, 460418, 639683, 640195, 640388, 608772, 465603, 610884, 475202,
+    617539, 476803, 640643, 460418, 599875, 475202, 598595, 595907, 445890, 607555, 462787, 640838,
+    460867, 591746, 623812, 479300, 477315, 471619, 472771, 471299, 470981, 474755, 615298, 467522,
+    626307, 593093, 480387, 641218, 471619, 635332, 634242, 464898, 473922, 483333, 465348, 609283,
+    641347, 586691, 465603, 468802, 286211, 637636, 609028, 641539, 459715, 637636, 479938, 641731,
+    471490, 459715, 641923, 471812, 642115, 642308, 621570, 471490, 610499, 465218, 613955, 610884,
+    483333, 623556, 642564, 471490, 642820, 470658, 600515, 630723, 3457, 643076, 471299, 469187,
+    464388, 633155, 471299, 474434, 643333, 638148, 461571, 472451, 286402, 611972, 623556, 643652,
+    474946, 622339, 465603, 589507, 477508, 594306, 483844, 479300, 637636, 460547, 594306, 643907,
+    473794, 468802, 591171, 465987, 644099, 633540, 619010, 3457, 471490, 460418, 460418, 609283,
+    466884, 638403, 483844, 629764, 480067, 469187, 17282, 594306, 464642, 626307, 286211, 618690,
+    641218, 644292, 644548, 286402, 470658, 470404, 636932, 286402, 634821, 470658, 469508, 465218,
+    464642, 644804, 445890, 645060, 645315, 610884, 632708, 17282, 611395, 460418, 473410, 587459,
+    459907, 470981, 617539, 476994, 621698, 460867, 645507, 484099, 473922, 633540, 643652, 475202,
+    473410, 587075, 286402, 470981, 286787, 590787, 644804, 640003, 477123, 614340, 608579, 469378,
+    477955, 645699, 645892, 477763, 646147, 630212, 646340, 646595, 462338, 597444, 646790, 16130,
+    635332, 473410, 622339, 637636, 589315, 469508, 589507, 647171, 514565, 611587, 587907, 471812,
+    604867, 476803, 647363, 514565, 466628, 637380, 619395, 17282, 627843, 460418, 460418, 601540,
+    464642, 465987, 616260, 474563, 615170, 620228, 474563, 647555, 623363, 445890, 591171, 628996,
+    17282, 463620, 614340, 471619, 592770, 471619, 467139, 609028, 477315, 471812, 629508, 468099,
+    639683, 634371, 615426, 471619, 647746, 597444, 640003, 472771, 647875, 639683, 634371, 461571,
+    628228, 592579, 630212, 635332, 607555, 470658, 467139, 638852, 595138, 17282, 629508, 460101,
+    648069, 480067, 603780, 627395, 594884, 648386, 648516, 459715, 609474, 462338, 634564, 619588,
+    472771, 471619, 603076, 473410, 592899, 286787, 470658, 612227, 589315, 473410, 596740, 473410,
+    648771, 614340, 610051, 612611, 472642, 648516, 645315, 286532, 467331, 3457, 464642, 619010,
+    610499, 465218, 17282, 473922, 605572, 587907, 648964, 475780, 600707, 464898, 603780, 482820,
+    624836, 649220, 616515, 484292, 649476, 593796, 462338, 590148, 609474, 633540, 602754, 482116,
+    640388, 621379, 473794, 631619, 596996, 16130, 475780, 466628, 474563, 469187, 476803, 470082,
+    649731, 616260, 649925, 471490, 628228, 600260, 589315, 589315, 621570, 469187, 650243, 604420,
+    602754, 610884, 637892, 650436, 603780, 461763, 17282, 459907, 477763, 634371, 594052, 606020,
+    475332, 460418, 473540, 613955, 615426, 617539, 594306, 607555, 608579, 461060, 650691, 476611,
+    630212, 476994, 633540, 629508, 474051, 483651, 647555, 468802, 611587, 465027, 593796, 481412,
+    650882, 588868, 651011, 472451, 481797, 634371, 632196, 651203, 286402, 464642, 651397, 464642,
+    637187, 286402, 618819, 476356, 615298, 643652, 639298, 599875, 641923, 651717, 460101, 594435,
+    468802, 609859, 467331, 467651, 592770, 637636, 459715, 473794, 586691, 470981, 620676, 652035,
+    589892, 648771, 467139, 474563, 639298, 652226, 468611, 620228, 461956, 639683, 616515, 597251,
+    467651, 632708, 17282, 445890, 460418, 631811, 479556, 643652, 605059, 471490, 464642, 635332,
+    623812, 587907, 652035, 596996, 464642, 460418, 469187, 465218, 474755, 589315, 591746, 471619,
+    586691, 599875, 635588, 464642, 609859, 652356, 463172, 621379, 589507, 652612, 617732, 600707,
+    481412, 476803, 634051, 469763, 480964, 643652, 17282, 599683, 481666, 469378, 471299, 650691,
+    480258, 640195, 476803, 652866, 641218, 594306, 469508, 635332, 468293, 629508, 469954, 597251,
+    466370, 470658, 652994, 17282, 609283, 623363, 466370, 3457, 463427, 614147, 641218, 614340,
+    286402, 614340, 467139, 471812, 468611, 468099, 460418, 590595, 482820, 464642, 463427, 598788,
+    589892, 470787, 615556, 613826, 460867, 469187, 17282, 478404, 473794, 616515, 598595, 476803,
+    589315, 639874, 599043, 620228, 477508, 463427, 466628, 618690, 627395, 627395, 476994, 465027,
+    597251, 479300, 460418, 465348, 472962, 473794, 616966, 464642, 17282, 477508, 460418, 619010,
+    479556, 461956, 477508, 464898, 468099, 477955, 476803, 617347, 622722, 478404, 477955, 473540,
+    466498, 623556, 640388, 599875, 464642, 478659, 653124, 592195, 3457, 475202, 475202, 473410,
+    478850, 653380, 651203, 653635, 611972, 620035, 637187, 476803, 465027, 481797, 614340, 478404,
+    653826, 586691, 613955, 470211, 472642, 476356, 595269, 466370, 465603, 286402, 480964, 648771,
+    594052, 618690, 618307, 653956, 619843, 632708, 652226, 602754, 594052, 483844, 470082, 466370,
+    459460, 474563, 594884, 286211, 481412, 592899, 592387, 622722, 460418, 471299, 469187, 467651,
+    622339, 627588, 472962, 626691, 460738, 628228, 602563, 465603, 459715, 597444, 474563, 460867,
+    482116, 589315, 286787, 475780, 477508, 589315, 595138, 637187, 465218, 599683, 464770, 654212,
+    599875, 654466, 640388, 647555, 589892, 460867, 470981, 598595, 638596, 466370, 477315, 463427,
+    472067, 461571, 652866, 470211, 466628, 466628, 460547, 468099, 469954, 599875, 475332, 616515,
+    601091, 464770, 465348, 445890, 445890, 614787, 654595, 477508, 654788, 655043, 465987, 463427,
+    630723, 474946, 478404, 461060, 469763, 652866, 469378, 655235, 655427, 624324, 607555, 599875,
+    619843, 620228, 463877, 473794, 641218, 612227, 629764, 655620, 598595, 626307, 592770, 601540,
+    594306, 599043, 603331, 468802, 589315, 479938, 599875, 468932, 589315, 596099, 484099, 479938,
+    477508, 622722, 466884, 472451, 646595, 640388, 445890, 3457, 619395, 477508, 597251, 597251,
+    613955, 471490, 474563, 471490, 483076, 621698, 464642, 607555, 621698, 480258, 468802, 655876,
+    609474, 614340, 470211, 480258, 641731, 459715, 633540, 479938, 475780, 473410, 470658, 469378,
+    641218, 656133, 648771, 603076, 470658, 472771, 635332, 615298, 648771, 286532, 656133, 635332,
+    477955, 652612, 477508, 631171, 656452, 461060, 473410, 623363, 476803, 473794, 475780, 595138,
+    601986, 610051, 467844, 470658, 620228, 634371, 621828, 616260, 589699, 589699, 614979, 632003,
+    466370, 477315, 589507, 445890, 648516, 615298, 633347, 599043, 640003, 484292, 643076, 465027,
+    467139, 617732, 634371, 460418, 590595, 590595, 656708, 17282, 646147, 656964, 474051, 599875,
+    461571, 461571, 474563, 476356, 471490, 287171, 657221, 475202, 618819, 465218, 468802, 466370,
+    651717, 626691, 609474, 472771, 467139, 657539, 588292, 619010, 587907, 468293, 638148, 631619,
+    473219, 634242, 657732, 596996, 614340, 657732, 473922, 592770, 477955, 609028, 607939, 467331,
+    480964, 469763, 481412, 460418, 460867, 477763, 630019, 646595, 597251, 470658, 634821, 657988,
+    658245, 658564, 464642, 600707, 629508, 587907, 471299, 616260, 477123, 658820, 616260, 618819,
+    603780, 479938, 614340, 286402, 468099, 659076, 637636, 659332, 626307, 465348, 631171, 614340,
+    595138, 469187, 467651, 473794, 468099, 649220, 480258, 650691, 654788, 620228, 484099, 286211,
+    471490, 462210, 462595, 479938, 659588, 599875, 597251, 467139, 445890, 466884, 607939, 651203,
+    464642, 16130, 479556, 462338, 619588, 461060, 626307, 659844, 642308, 514565, 601284, 470981,
+    468802, 660100, 660356, 619843, 612227, 660612, 466884, 476356, 461763, 645892, 660868, 643652,
+    472451, 609283, 630019, 627588, 650243, 607747, 623556, 469187, 649476, 620035, 473922, 482820,
+    470658, 619010, 661123, 638148, 476994, 640003, 470658, 661315, 624836, 586691, 651203, 476356,
+    614340, 473794, 461763, 587907, 470404, 468802, 661508, 661763, 589315, 651203, 468802, 641347,
+    594052, 473090, 594435, 640003, 638403, 480258, 460418, 627140, 466628, 605827, 644804, 600260,
+    474434, 614340, 467139, 601284, 602754, 609474, 472451, 661955, 459715, 461060, 445890, 469187,
+    627588, 483076, 619395, 479556, 636739, 662147, 632963, 474051, 475332, 646147, 662339, 636739,
+    478981, 634371, 467139, 287171, 481797, 609604, 614979, 607555, 477955, 3457, 286402, 471619,
+    626884, 480258, 460867, 636484, 662532, 662788, 618819, 617347, 607300, 647555, 633347, 462338,
+    460418, 473794, 595269, 480258, 625860, 614340, 460867, 599683, 464770, 482564, 589892, 286532,
+    593603, 610884, 663044, 460418, 640388, 474563, 647555, 633155, 604035, 663299, 609028, 473410,
+    478404, 613955, 462787, 471490, 474563, 464770, 629508, 473410, 595138, 463427, 663491, 460101,
+    476037, 648069, 635843, 470082, 473540, 663685, 664003, 602754, 636035, 473794, 639298, 614340,
+    664196, 640003, 602754, 466370, 636035, 470658, 648516, 470658, 477508, 625091, 286532, 617539,
+    286402, 466179, 471490, 664451, 628035, 16130, 620228, 619010, 635332, 470658, 477763, 476356,
+    640003, 625091, 468099, 468932, 654212, 588292, 628228, 462210, 468099, 483844, 466628, 472067,
+    664643, 642115, 647171, 651203, 286402, 599043, 482564, 475332, 466628, 609474, 467331, 635332,
+    596484, 614340, 647363, 614787, 471812, 638403, 622339, 472451, 475780, 480387, 653380, 589315,
+    484099, 598595, 471490, 17282, 590787, 286402, 462595, 605827, 17282, 471619, 460418, 588868,
+    589315, 653956, 475202, 480258, 473794, 619010, 637187, 612419, 637187, 470211, 629508, 286787,
+    591746, 482564, 474243, 612419, 645507, 464642, 466498, 618307, 467522, 474051, 475202, 635332,
+    614340, 612419, 17282, 645507, 467139, 466884, 473794, 471619, 462979, 638852, 476803, 484099,
+    17282, 473794, 481666, 468099, 469378, 474051, 614787, 469378, 637380, 646595, 514565, 644548,
+    596740, 588738, 620228, 589315, 613572, 636035, 622531, 634242, 622531, 616515, 476037, 623363,
+    664835, 17282, 464770, 621698, 478981, 609028, 639683, 473794, 620228, 471490, 618690, 635332,
+    606466, 468611, 598595, 469187, 643076, 611972, 615426, 665027, 599875, 17282, 665218, 475202,
+    650691, 632708, 665348, 473410, 286402, 470658, 599043, 637187, 286532, 617347, 614787, 286532,
+    632196, 593093, 465987, 472451, 460418, 620228, 588292, 656452, 614787, 610051, 467844, 665604,
+    474563, 589507, 625091, 665859, 596740, 473219, 624836, 614340, 480771, 461060, 468802, 619010,
+    645507, 474563, 626691, 624836, 595138, 597444, 477955, 636739, 482820, 666053, 601284, 459715,
+    634051, 624836, 666372, 610244, 462210, 466884, 626691, 666372, 601795, 637892, 651203, 597251,
+    597251, 649925, 618499, 470658, 591171, 473794, 470211, 460418, 641218, 17282, 636484, 464642,
+    461316, 617987, 621570, 467139, 636035, 614340, 466884, 472771, 468802, 613955, 666628, 474051,
+    586691, 466498, 460738, 482820, 462979, 623363, 17282, 482820, 589123, 286211, 598595, 17282,
+    599236, 481797, 478404, 591746, 464642, 622531, 619588, 607300, 608324, 602754, 617987, 666883,
+    589507, 628035, 471812, 589315, 467651, 466628, 626691, 464770, 610499, 478404, 614340, 470404,
+    648771, 667077, 593796, 655235, 465218, 460418, 588099, 466370, 609283, 461316, 474051, 621570,
+    473410, 465218, 463877, 589315, 617539, 17282, 626691, 480771, 479300, 618499, 468099, 481219,
+    476803, 460418, 595138, 470082, 667396, 636739, 461316, 475332, 595138, 17282, 654788, 473794,
+    460418, 599043, 474051, 477955, 623363, 474755, 474563, 482820, 286402, 472771, 645315, 624068,
+    658245, 607300, 460418, 667651, 630212, 662788, 473794, 286532, 479300, 619395, 600899, 589315,
+    461571, 589315, 474563, 665604, 639107, 473922, 471812, 480964, 465218, 614787, 591171, 475780,
+    470787, 667843, 602883, 595138, 589315, 479938, 484292, 467522, 644804, 477763, 481219, 459715,
+    626691, 483651, 668035, 470082, 649731, 654466, 465218, 286787, 468611, 466628, 636035, 472451,
+    480258, 469763, 462979, 467651, 482564, 475074, 668228, 587907, 468802, 604035, 600260, 17282,
+    589892, 620035, 621698, 473219, 480771, 615298, 645507, 473410, 474755, 476994, 464770, 17282,
+    614340, 641218, 597444, 465027, 621124, 469508, 591746, 659332, 462338, 628996, 668484, 482371,
+    594435, 482820, 461571, 616260, 661315, 622531, 481666, 480771, 468932, 461060, 466884, 617987,
+    604420, 650243, 467139, 480387, 465027, 613316, 597444, 473794, 616515, 650436, 635332, 614340,
+    616260, 476803, 603780, 635332, 475780, 668484, 467331, 466628, 477763, 617539, 608772, 650436,
+    473090, 464642, 603780, 630468, 480771, 17282, 462787, 587267, 629508, 668740, 476611, 468099,
+    635843, 668995, 669187, 628996, 665218, 474051, 618499, 592195, 459715, 657539, 479300, 468099,
+    471619, 459907, 669380, 657732, 669635, 619588, 479556, 669828, 615426, 478659, 483844, 479938,
+    595269, 654212, 477763, 639428, 599875, 668740, 468802, 599875, 3457, 469187, 650691, 626691,
+    661763, 620483, 480258, 479300, 599875, 464642, 628228, 467139, 586691, 463620, 460101, 600707,
+    646147, 606596, 670083, 626499, 670275, 645315, 639683, 592387, 460101, 623363, 482116, 466370,
+    644548, 591746, 638596, 669828, 484099, 636035, 468932, 665218, 669828, 647171, 597700, 471619,
+    608324, 588292, 467139, 476994, 665218, 467331, 648516, 597251, 463620, 670468, 462338, 467139,
+    604035, 472451, 614340, 600707, 459715, 596740, 608772, 460418, 460867, 670723, 670916, 588547,
+    589315, 631811, 617539, 463427, 473794, 619395, 465218, 601795, 644292, 476803, 465218, 602115,
+    472451, 619010, 633540, 626115, 640003, 286402, 656452, 632196, 614340, 640003, 474563, 642308,
+    477763, 467331, 469378, 478148, 629508, 466370, 671171, 465987, 634242, 621124, 477508, 467331,
+    473090, 650691, 615298, 641539, 641539, 594435, 620228, 604227, 470211, 467139, 462979, 618819,
+    651203, 645507, 473922, 669828, 467331, 459907, 606466, 479938, 605253, 477508, 469763, 460418,
+    628228, 599875, 480258, 286402, 643652, 462595, 622531, 629508, 609474, 480771, 468099, 481797,
+    473922, 462210, 461571, 648069, 616260, 616260, 631619, 472962, 625860, 597444, 600067, 476803,
+    462979, 476803, 472451, 619843, 478404, 614979, 649925, 609859, 465027, 603076, 474051, 594628,
+    634564, 17282, 462979, 17282, 665218, 671364, 657221, 614787, 461060, 462338, 655876, 597444,
+    606596, 462979, 641218, 655876, 602754, 618690, 445890, 629508, 659332, 594052, 466370, 595907,
+    467139, 622339, 464642, 618819, 632003, 473922, 471619, 466370, 465348, 625860, 614340, 647363,
+    624836, 589507, 465603, 475780, 461060, 621570, 637187, 617539, 459715, 629508, 610884, 671619,
+    602754, 465027, 669380, 459715, 464642, 594052, 474051, 286402, 463427, 473794, 614340, 629508,
+    635332, 671811, 473922, 465987, 462210, 586691, 476356, 475202, 464642, 612804, 473922, 660612,
+    464195, 620035, 473090, 465987, 589699, 591746, 668228, 482820, 477955, 672003, 672195, 613955,
+    672389, 17282, 464642, 613572, 668228, 619010, 473219, 599875, 638596, 672708, 645315, 460738,
+    637636, 615170, 474434, 462338, 286402, 620228, 657732, 460418, 600899, 482371, 621379, 472451,
+    462787, 467331, 639107, 607107, 617732, 460418, 607300, 615170, 642115, 474755, 474434, 480387,
+    473922, 639107, 672963, 589123, 590595, 621379, 638596, 673155, 474755, 473410, 474434, 481666,
+    616515, 474434, 475332, 468099, 470658, 480771, 673348, 610884, 473410, 460418, 673603, 478404,
+    286402, 652612, 673796, 474755, 479300, 477763, 674052, 460418, 3457, 674308, 627843, 657221,
+    603780, 595269, 599043, 286532, 619843, 470082, 464642, 480771, 479938, 592899, 478148, 635332,
+    601284, 635332, 477123, 674564, 286402, 606596, 478850, 481412, 623556, 482116, 661955, 617539,
+    632708, 479938, 641539, 477763, 17282, 479938, 609859, 472771, 476356, 633540, 459715, 593093,
+    460867, 616260, 482116, 608772, 599236, 594884, 464770, 609604, 651203, 478981, 220802, 609474,
+    464642, 476037, 624836, 638852, 601284, 623812, 659332, 646790, 470658, 633540, 514565, 286402,
+    601986, 592579, 473922, 674564, 674564, 466370, 17282, 614340, 589315, 599043, 592195, 674819,
+    626691, 476611, 626691, 675012, 648771, 650436, 474051, 607939, 605827, 623556, 600899, 471619,
+    622339, 626115, 286402, 592195, 651203, 612419, 467331, 634242, 464642, 445890, 465795, 286787,
+    475202, 641347, 616515, 675268, 479300, 468802, 609604, 667396, 473794, 477763, 466884, 466884,
+    591746, 628485, 628228, 627843, 463172, 480964, 617539, 460418, 474563, 675523, 470082, 462338,
+    636035, 609859, 477763, 637187, 641539, 606466, 475202, 601795, 464898, 468802, 620228, 460418,
+    622531, 478404, 636739, 466498, 460867, 286402, 474946, 472962, 643652, 470658, 482564, 286402,
+    605253, 655876, 461763, 589315, 607555, 609859, 467651, 474051, 460738, 595589, 614787, 615426,
+    607555, 463877, 611587, 613955, 623171, 17282, 653124, 478981, 478148, 619140, 481412, 470981,
+    599875, 466370, 467331, 675716, 475202, 623363, 460418, 470082, 473794, 483651, 599236, 469954,
+    465027, 639683, 483076, 614340, 609028, 675972, 671811, 644548, 474563, 615426, 614340, 617987,
+    473794, 639683, 616515, 634051, 614340, 460867, 650691, 641731, 676228, 461571, 460547, 614979,
+    480771, 467139, 470658, 639683, 597444, 464770, 599043, 286402, 612611, 658245, 477955, 480067,
+    470981, 589699, 461571, 480258, 621124, 480258, 637892, 589892, 286402, 601284, 616260, 473410,
+    473090, 467651, 465348, 474946, 594306, 480387, 475202, 474946, 601795, 627588, 641347, 626115,
+    602754, 473219, 476611, 467139, 473794, 465027, 466498, 617539, 474051, 634242, 639683, 629764,
+    481219, 468099, 470082, 460418, 630212, 466884, 467139, 473794, 286979, 475332, 478404, 475780,
+    618690, 477508, 616260, 459907, 286532, 479556, 472451, 589315, 471490, 661955, 620035, 470082,
+    467331, 631619, 617539, 445890, 629508, 652356, 473410, 477955, 663044, 470082, 619588, 609283,
+    599043, 588868, 614979, 464388, 617347, 470981, 460738, 470082, 469508, 464898, 615426, 597444,
+    652356, 605253, 460867, 471619, 460867, 589892, 668228, 480964, 467139, 606466, 609474, 462787,
+    605253, 614340, 483844, 482116, 629764, 469378, 465218, 471490, 471490, 618690, 589315, 464642,
+    608579, 593093, 461571, 597444, 465348, 460418, 628228, 478404, 471299, 589507, 631171, 461571,
+    467139, 614147, 470211, 470981, 597444, 478981, 634371, 635332, 676483, 478404, 476037, 467651,
+    641218, 619010, 598788, 463427, 464642, 610884, 466370, 649220, 483844, 477508, 473794, 648069,
+    474243, 470787, 637636, 610884, 482820, 460418, 472451, 630468, 473410, 462979, 663044, 665218,
+    651203, 603331, 665218, 473794, 629508, 619010, 466884, 463427, 614340, 591746, 464195, 633540,
+    618819, 464642, 589123, 481797, 479938, 670916, 603076, 594052, 460418, 608579, 642564, 482116,
+    599875, 462979, 621698, 286211, 461571, 464642, 627588, 460867, 640003, 614979, 462979, 609474,
+    594884, 592770, 468802, 471299, 286532, 286532, 640195, 467139, 630212, 588868, 476356, 630212,
+    601091, 653124, 615170, 610051, 618178, 641347, 467139, 476356, 460867, 653124, 617987, 633347,
+    618690, 514565, 619395, 603076, 631811, 602754, 636035, 466498, 472067, 606020, 635588, 609474,
+    590403, 475332, 460867, 471299, 445890, 464642, 286532, 463620, 623363, 482116, 670275, 632003,
+    480258, 622339, 619588, 465603, 462338, 479938, 653956, 470658, 623042, 635332, 17282, 630212,
+    477763, 634564, 478404, 468099, 607555, 599875, 595138, 646790, 676675, 471812, 676869, 595907,
+    626691, 591171, 469187, 476611, 474946, 286402, 677186, 614340, 592579, 615170, 465027, 470404,
+    636932, 597444, 614340, 598595, 665218, 473090, 606275, 470211, 473410, 468802, 463427, 17282,
+    592195, 465348, 476803, 648516, 463427, 593093, 477315, 3457, 475202, 467139, 483076, 623363,
+    471490, 464642, 464388, 463427, 619395, 470981, 445890, 481219, 590595, 638596, 632708, 638148,
+    466179, 589507, 286402, 646790, 665027, 636035, 475780, 591171, 471490, 628035, 631811, 620228,
+    628996, 660612, 464642, 17282, 603076, 471490, 17282, 636484, 468802, 466628, 466498, 473540,
+    610051, 614340, 666628, 631811, 473410, 462979, 622722, 636484, 474563, 631619, 479556, 479556,
+    472962, 17282, 651203, 634242, 480258, 595269, 286402, 470211, 475202, 595138, 643652, 466498,
+    634371, 626115, 648516, 606596, 474051, 599875, 466628, 610051, 637636, 445890, 475202, 445890,
+    634242, 474755, 631811, 599683, 464388, 468293, 621379, 632196, 479810, 616515, 16130, 591746,
+    465987, 470981, 471490, 460867, 477955, 467139, 658245, 474563, 639683, 677316, 615426, 617732,
+    668484, 601284, 650882, 599683, 471490, 611587, 633347, 594052, 588099, 474434, 482564, 589507,
+    483844, 286402, 587907, 461763, 640643, 470981, 620035, 483651, 635332, 460547, 610884, 475202,
+    677571, 669828, 474563, 473090, 636035, 586691, 631171, 471812, 614340, 469763, 617987, 593093,
+    465987, 3457, 481797, 462595, 619588, 461571, 466498, 461571, 633540, 623363, 474051, 480387,
+    461571, 615298, 616260, 609028, 462595, 480964, 672708, 672708, 482564, 618690, 637892, 638148,
+    619588, 646790, 630723, 478404, 468099, 594052, 464642, 626691, 638852, 595269, 613572, 593093,
+    465987, 480387, 514565, 481797, 592579, 630212, 615298, 619010, 598595, 17282, 624836, 473922,
+    477508, 473922, 677763, 618178, 677955, 629764, 609283, 678148, 657221, 637187, 630212, 611587,
+    614787, 286211, 286402, 473090, 460867, 591746, 599043, 596740, 630723, 649925, 466370, 476803,
+    678403, 474563, 477508, 586883, 476611, 466628, 465027, 465027, 621698, 619395, 610051, 621570,
+    661955, 474563, 476356, 17282, 471619, 460418, 465027, 465603, 459907, 624836, 514565, 654466,
+    652866, 646147, 612227, 17282, 472451, 612611, 593093, 600899, 477315, 469187, 17282, 640003,
+    467139, 678595, 648771, 620483, 668228, 463620, 459715, 464642, 462787, 463620, 469763, 645507,
+    620228, 602754, 619010, 637636, 469378, 473219, 470211, 478659, 607939, 476994, 611395, 462979,
+    615298, 633347, 592770, 481412, 600707, 611972, 607555, 591746, 606466, 652866, 616260, 623812,
+    612227, 514565, 477508, 468099, 628228, 469187, 633796, 673155, 473794, 654466, 462210, 633155,
+    445890, 286532, 656452, 631619, 632003, 602754, 477508, 625860, 624836, 599043, 466370, 633155,
+    649925, 462210, 603331, 468099, 656452, 465218, 477763, 620228, 480771, 648069, 624580, 474563,
+    603076, 678788, 648771, 470082, 617539, 620035, 474755, 466628, 601284, 589315, 621698, 462338,
+    617539, 599043, 616515, 649220, 620228, 17282, 623812, 630723, 629764, 464642, 474563, 17282,
+    591171, 463427, 469187, 626691, 481666, 589315, 633155, 640388, 477508, 592195, 17282, 679042,
+    480771, 286402, 469763, 651203, 592387, 614340, 461060, 220802, 465218, 614340, 679172, 679429,
+    616966, 467139, 482820, 620228, 619588, 471490, 628996, 632003, 473410, 466370, 466370, 652866,
+    613955, 595269, 638148, 465987, 460738, 620035, 648771, 469187, 628228, 467331, 286402, 464388,
+    639107, 599683, 483333, 644548, 462979, 597444, 607939, 470981, 465987, 467139, 615556, 601986,
+    655620, 589315, 640003, 468611, 473794, 587907, 663685, 470981, 626691, 461060, 469378, 473410,
+    599043, 617539, 286402, 636035, 611140, 460547, 609474, 595589, 611587, 614340, 629508, 465218,
+    477508, 586691, 286532, 475202, 615811, 466370, 476611, 471619, 653124, 464898, 479938, 609283,
+    655876, 461571, 477955, 461316, 679748, 462338, 626499, 472962, 480387, 514565, 471299, 648069,
+    3713, 610884, 594435, 462595, 651203, 602883, 481412, 473410, 459907, 464642, 597955, 467844,
+    472642, 17282, 465218, 468932, 461316, 460418, 460738, 602883, 612611, 617539, 462595, 286402,
+    617539, 619010, 468802, 472642, 3457, 514565, 479556, 464642, 468099, 471490, 445890, 631811,
+    467651, 467331, 464195, 17282, 591746, 650243, 286787, 468802, 655043, 651011, 606596, 597700,
+    623363, 469187, 615298, 461571, 220802, 461060, 655876, 601284, 470211, 599875, 602754, 476356,
+    461571, 474946, 471490, 468099, 601091, 465218, 465218, 466628, 470658, 617539, 220802, 465218,
+    460867, 468802, 612611, 470981, 595138, 461571, 619395, 468099, 635332, 479938, 466628, 16130,
+    620228, 670916, 462979, 476803, 460547, 589892, 677186, 603331, 467139, 598595, 481797, 466884,
+    616966, 464388, 619395, 599043, 617539, 462979, 599043, 465027, 475202, 589892, 648516, 460101,
+    472642, 17282, 610051, 605059, 459907, 468802, 598595, 474755, 464642, 474051, 608772, 620228,
+    465027, 602754, 668228, 601540, 466884, 639428, 460738, 474243, 475587, 638596, 459715, 459715,
+    650243, 466370, 476356, 609474, 468802, 478404, 465218, 620035, 609028, 470082, 463620, 602754,
+    464642, 628228, 286402, 470658, 462338, 480771, 460418, 286402, 286402, 628228, 622722, 465348,
+    478850, 641539, 632708, 628228, 463620, 470211, 591746, 636739, 614787, 469187, 514565, 594884,
+    469187, 479938, 646147, 616260, 478404, 610499, 286402, 467139, 470981, 480387, 466370, 473219,
+    601795, 461060, 622722, 628996, 481412, 17282, 591746, 599043, 670723, 611587, 468802, 654466,
+    459715, 668740, 470658, 610884, 602754, 468802, 619588, 675268, 630212, 595138, 647363, 662788,
+    589507, 599683, 611587, 621570, 472642, 472642, 609604, 471490, 473922, 445890, 680004, 467139,
+    627588, 469378, 475202, 479810, 646790, 473090, 466179, 16130, 459715, 594435, 473090, 640003,
+    478850, 655876, 473410, 472642, 465027, 462338, 477123, 474563, 481412, 470658, 477123, 472067,
+    586691, 615170, 665027, 633155, 475332, 609474, 471490, 466179, 659332, 635332, 462979, 471812,
+    595138, 471490, 643652, 469508, 591746, 472451, 609474, 478148, 662788, 464195, 459907, 286532,
+    617347, 464770, 626691, 668740, 621124, 480258, 679429, 665218, 612611, 471490, 462979, 473410,
+    472451, 473922, 673796, 478659, 654466, 599875, 473794, 472642, 622531, 650243, 622851, 473794,
+    474563, 644099, 445890, 597251, 640003, 656452, 629764, 473410, 459715, 464642, 614340, 617732,
+    665604, 616515, 460738, 680260, 471490, 460418, 594052, 470658, 600899, 614340, 473794, 605059,
+    477123, 474563, 475202, 646595, 623812, 479556, 611587, 609474, 621124, 586691, 640003, 623363,
+    465795, 642115, 460418, 473794, 465027, 472451, 476994, 460418, 474946, 480964, 633347, 680515,
+    483333, 470981, 471490, 465027, 614147, 599236, 625860, 615298, 670723, 470082, 617347, 617539,
+    623812, 466179, 474563, 629508, 480771, 622531, 601540, 640003, 464642, 470211, 476356, 610499,
+    591171, 463427, 633155, 626691, 470082, 473794, 587907, 621379, 594306, 464770, 462338, 615426,
+    629764, 464770, 629764, 465348, 474563, 479938, 597444, 469187, 679172, 598595, 467139, 629508,
+    464388, 286532, 462210, 477315, 600707, 469763, 477763, 680707, 674564, 641347, 626691, 479556,
+    471619, 602754, 614979, 652356, 470981, 628996, 635139, 480964, 468099, 3457, 622339, 468802,
+    621379, 470082, 3457, 597700, 631619, 609474, 680898, 609474, 460738, 626115, 463620, 620483,
+    471490, 464898, 477508, 597955, 628228, 633155, 474051, 481412, 602754, 681027, 595138, 460738,
+    473794, 648069, 466179, 468802, 616004, 467651, 468802, 469763, 462338, 473794, 465348, 638148,
+    617732, 637636, 681220, 629764, 466179, 642308, 631171, 480067, 461060, 461763, 471490, 596740,
+    623812, 656452, 616260, 464195, 461060, 480964, 591171, 469508, 476803, 661955, 601795, 462466,
+    587907, 617539, 619010, 462979, 480258, 482820, 462787, 665218, 610244, 476356, 638596, 595589,
+    644548, 634371, 621379, 474051, 626307, 681475, 478659, 480387, 480771, 468099, 471490, 619010,
+    461316, 17282, 628996, 681667, 674564, 467139, 17282, 476803, 471490, 640003, 473794, 17282,
+    469378, 462338, 474563, 474563, 459715, 467844, 468802, 621124, 668228, 611972, 589892, 627140,
+    641539, 595269, 623812, 483651, 587075, 619010, 619010, 474563, 629764, 286402, 481412, 602754,
+    474563, 470082, 605253, 466370, 615170, 608772, 592770, 597444, 475202, 471490, 594052, 634371,
+    477955, 606466, 476803, 599236, 610051, 663491, 609604, 468802, 631811, 482116, 466628, 17282,
+    620035, 681859, 637636, 460418, 633347, 459715, 596740, 286402, 475202, 467522, 461571, 476994,
+    286402, 471490, 476356, 286402, 459460, 633347, 622339, 682051, 595138, 589892, 466628, 589315,
+    467331, 591746, 603780, 462210, 620228, 483333, 594435, 603076, 621124, 468932, 607939, 601540,
+    470658, 17282, 656452, 464642, 609283, 286402, 572226, 618499, 682243, 286402, 682435, 640195,
+    616966, 653826, 17282, 637892, 473922, 621698, 468802, 17282, 610244, 601795, 652866, 682628,
+    16130, 607555, 483844, 474755, 469187, 676675, 481666, 615426, 471490, 682883, 664196, 469763,
+    638596, 476803, 644548, 634051, 477315, 599875, 589315, 621570, 626691, 601284, 474563, 17282,
+    468802, 3457, 683075, 600899, 614979, 634371, 614340, 587907, 656964, 473410, 620228, 460867,
+    602563, 614979, 676228, 593093, 609028, 482116, 469763, 470658, 623363, 480258, 460418, 641218,
+    597444, 461060, 616004, 465603, 621570, 602563, 683269, 477508, 445890, 659332, 617539, 472771,
+    592387, 465218, 594052, 589315, 466370, 465218, 460867, 639874, 623812, 286402, 601795, 465027,
+    476803, 459715, 671811, 17282, 637380, 462595, 601795, 620228, 683589, 464642, 683907, 631364,
+    17282, 683907, 17282, 477508, 612419, 611587, 476803, 465218, 473794, 654466, 286979, 467522,
+    612419, 467139, 474434, 16130, 462338, 633347, 476803, 481797, 481412, 637636, 643652, 475074,
+    598788, 647171, 626115, 286402, 619010, 654466, 462979, 473410, 475202, 627588, 684100, 648516,
+    483333, 602563, 661315, 595589, 286787, 623363, 461571, 646595, 599875, 473794, 3457, 465348,
+    642308, 474051, 472451, 286402, 638596, 286402, 593093, 460418, 466370, 610884, 468802, 468293,
+    662788, 445890, 629764, 468293, 626691, 650243, 482116, 286402, 467139, 633155, 462979, 17282,
+    459460, 623812, 618819, 626115, 609474, 460418, 476994, 459715, 479938, 622531, 592195, 616515,
+    616515, 17282, 478404, 467139, 647171, 459715, 479300, 628228, 669828, 638852, 464642, 471490,
+    640003, 469954, 669828, 684356, 616966, 468099, 662788, 462210, 592387, 674564, 606596, 648069,
+    615170, 17282, 479300, 592770, 467139, 460867, 286787, 469187, 614340, 637892, 468802, 476994,
+    586883, 665218, 465027, 636739, 614340, 477123, 286402, 616515, 603331, 466628, 641218, 599236,
+    626115, 591746, 475074, 470787, 638403, 286979, 684610, 588868, 624068, 464642, 469187, 465027,
+    627843, 476611, 17282, 684739, 603076, 468802, 473410, 465027, 475202, 477763, 612419, 641218,
+    667077, 466884, 286787, 481797, 588099, 624836, 474563, 675012, 475780, 464642, 684931, 468099,
+    596996, 470658, 459715, 612611, 17282, 638403, 610884, 464642, 626115, 641218, 662788, 472451,
+    597700, 616515, 596996, 621379, 471490, 461571, 602563, 609604, 618819, 286787, 589315, 656964,
+    602563, 469763, 589315, 479938, 615298, 636035, 591746, 480067, 220802, 628228, 627588, 16130,
+    611587, 471619, 470404, 662788, 469508, 589315, 610244, 469187, 472451, 602563, 675268, 601986,
+    621379, 620228, 629764, 617539, 617987, 629764, 471812, 590403, 597444, 685123, 662788, 623042,
+    286402, 606466, 615811, 483844, 477508, 473794, 685316, 639298, 639298, 614340, 635332, 592770,
+    656452, 589315, 462979, 469187, 600515, 600515, 480258, 474051, 470658, 631811, 597444, 478981,
+    648516, 471490, 466628, 630723, 622339, 471490, 623812, 592387, 460418, 470787, 646790, 636484,
+    642115, 286402, 620228, 623556, 593093, 478404, 614340, 466370, 473410, 467522, 462787, 462787,
+    599683, 612227, 467651, 16130, 590595, 590787, 474563, 592195, 469763, 466884, 662788, 473794,
+    592387, 478404, 660612, 631619, 464770, 461763, 475780, 474563, 478850, 472771, 479556, 17282,
+    589315, 464770, 461571, 461571, 475074, 626307, 651011, 476803, 617539, 607939, 461571, 626115,
+    643652, 612419, 481412, 643652, 462979, 475332, 473794, 460418, 631619, 469763, 480387, 641218,
+    592770, 465218, 482820, 685572, 654466, 464642, 3457, 474563, 459907, 591746, 601091, 599875,
+    286787, 595907, 459715, 614979, 477315, 609283, 474434, 668228, 623042, 473794, 640388, 465218,
+    461763, 462338, 595269, 628485, 631619, 482116, 465987, 445890, 599683, 16130, 587907, 467139,
+    460418, 461956, 480771, 626115, 467651, 616515, 460867, 474051, 599875, 623363, 595138, 476994,
+    595907, 473794, 618819, 286211, 471619, 469187, 640003, 472067, 668740, 471619, 647171, 599875,
+    637380, 628996, 465603, 641539, 599875, 462338, 594306, 481219, 474755, 470787, 626691, 461763,
+    603780, 634242, 602754, 629508, 469187, 655620, 460418, 480964, 467651, 464642, 614340, 461763,
+    608324, 648964, 602308, 595907, 684356, 467139, 586691, 648069, 640388, 600899, 628996, 445890,
+    618819, 587907, 651203, 666628, 611587, 471619, 483333, 607555, 620035, 477763, 599236, 471619,
+    470082, 464642, 476803, 602883, 592195, 465603, 477763, 640003, 616966, 599875, 672003, 604675,
+    3457, 685828, 645507, 628228, 472451, 600899, 655620, 589507, 469187, 481797, 465027, 651203,
+    686083, 640003, 463620, 17282, 611587, 641218, 479938, 686276, 17282, 626307, 466498, 616260,
+    587907, 587907, 619010, 465603, 626307, 649925, 471299, 614340, 286532, 477763, 594435, 465027,
+    622339, 478404, 17282, 474563, 589315, 468099, 616515, 665027, 460738, 605253, 474563, 460418,
+    626115, 662788, 445890, 603780, 615426, 468802, 470211, 618819, 467139, 609604, 474563, 483844,
+    17282, 633347, 597251, 470211, 478981, 286787, 480964, 481219, 468932, 625541, 473922, 621124,
+    619588, 594884, 593093, 469187, 472962, 477763, 605059, 460418, 639874, 471490, 467139, 468802,
+    464642, 468802, 622722, 474563, 626691, 594435, 467651, 619010, 474051, 480258, 618819, 470211,
+    3457, 648069, 630723, 472771, 475587, 474434, 468802, 594435, 466370, 686531, 479938, 463620,
+    472451, 601284, 472451, 468802, 470981, 621124, 479300, 639428, 633347, 471619, 477508, 605059,
+    461060, 587907, 616515, 471490, 466628, 473410, 460418, 474563, 465027, 619010, 610884, 460418,
+    482820, 686724, 286402, 286402, 462595, 464388, 593093, 468802, 634371, 601284, 462979, 588868,
+    609859, 471490, 479938, 654466, 472771, 611587, 623042, 462595, 474563, 480964, 611587, 473410,
+    629764, 473090, 479556, 471299, 651203, 469508, 593093, 586691, 642308, 468802, 623171, 466628,
+    607300, 678595, 286532, 586691, 594435, 471619, 602563, 651203, 467331, 606466, 462338, 653826,
+    594052, 470658, 601284, 665027, 611587, 605253, 479556, 646595, 468293, 472451, 474434, 616260,
+    469763, 473410, 686980, 612419, 618690, 460418, 619010, 602563, 466370, 586691, 474755, 605059,
+    475202, 586691, 464770, 617732, 478850, 461571, 624836, 286787, 623042, 597251, 471619, 641347,
+    629764, 478404, 621379, 612611, 640003, 611587, 629508, 470211, 643076, 482371, 610244, 631811,
+    616004, 462595, 464388, 465027, 620228, 480964, 286532, 468802, 481219, 642308, 587075, 615426,
+    587907, 595269, 477763, 637187, 475780, 614595, 610884, 471812, 465987, 286402, 627843, 621124,
+    624836, 462595, 472451, 638852, 617539, 471490, 619588, 468802, 470658, 619588, 641539, 471299,
+    479556, 648516, 471490, 514565, 468932, 476356, 479556, 609028, 477955, 476803, 470658, 643652,
+    445890, 464770, 588868, 589315, 482820, 599683, 465027, 624836, 675012, 462338, 634371, 653956,
+    475780, 632708, 587907, 622531, 596996, 623812, 653956, 679429, 629508, 473922, 481797, 474755,
+    467331, 631171, 608131, 474563, 469187, 471299, 620228, 670916, 619395, 620228, 471490, 462595,
+    687235, 17282, 473794, 480964, 654212, 624836, 480964, 669380, 474051, 460418, 479938, 473540,
+    589699, 623042, 609474, 466884, 464642, 480258, 591746, 464642, 619010, 593603, 480067, 655876,
+    460101, 613572, 466498, 477763, 474051, 640388, 469378, 668484, 461571, 477763, 467139, 666053,
+    628996, 623812, 464642, 479938, 474755, 615811, 467139, 480771, 592387, 16130, 606466, 462595,
+    460418, 615811, 630723, 680260, 648964, 445890, 445890, 611587, 480964, 476356, 483333, 614340,
+    634242, 470211, 464642, 480771, 481666, 474434, 480964, 482116, 586691, 588868, 687427, 674564,
+    605059, 609474, 467139, 475202, 605572, 597444, 629508, 613572, 286402, 633347, 469954, 469954,
+    622339, 601284, 613955, 589507, 471490, 641347, 474434, 3457, 616515, 460867, 17282, 460418,
+    674564, 472451, 473410, 480771, 17282, 621124, 621124, 622722, 286402, 665027, 600707, 622722,
+    623042, 445890, 591746, 617539, 473922, 591746, 472642, 463427, 465027, 601795, 618178, 648516,
+    459715, 605253, 474563, 465218, 464642, 603524, 640643, 620035, 476803, 475332, 474434, 602563,
+    593603, 484099, 465218, 624836, 464642, 463877, 465218, 445890, 464770, 468099, 461316, 460418,
+    629764, 466628, 480387, 473219, 476611, 629508, 286402, 286402, 586691, 676675, 597444, 630723,
+    480964, 474563, 601795, 687620, 601091, 631171, 470211, 462595, 599875, 464770, 634371, 621379,
+    629508, 645507, 462979, 481219, 482116, 597444, 462979, 471619, 469378, 459715, 469763, 469378,
+    467139, 670916, 633155, 468099, 621379, 614340, 662788, 631619, 609859, 629508, 459715, 629764,
+    462595, 623042, 477508, 468099, 475780, 464898, 643652, 625541, 632708, 514565, 474563, 621124,
+    464642, 650243, 626115, 648069, 471490, 595138, 472259, 479556, 664196, 623042, 474755, 591362,
+    460867, 466628, 594306, 466884, 469378, 469378, 471812, 647171, 473219, 635332, 676228, 616260,
+    470658, 469187, 614340, 687875, 479938, 514565, 465027, 467331, 629764, 589315, 220802, 474051,
+    461060, 286402, 623363, 465218, 688068, 469378, 650243, 688323, 622722, 589315, 631811, 590595,
+    680260, 475780, 594052, 614595, 475780, 472451, 460418, 460867, 664835, 470404, 594306, 611587,
+    462338, 662788, 589315, 606596, 480387, 674564, 610884, 626691, 632708, 589315, 591746, 614340,
+    473922, 587075, 514565, 462338, 463427, 644099, 477123, 606466, 616260, 623171, 468802, 656133,
+    614340, 607555, 609859, 598595, 587907, 466498, 597444, 614787, 588868, 621124, 474563, 587267,
+    469187, 467139, 483333, 17282, 641218, 514565, 656133, 590595, 462979, 484292, 475202, 472451,
+    591746, 643652, 596996, 688517, 591746, 460547, 479556, 476994, 17282, 619588, 17282, 612227,
+    286402, 621698, 614340, 474755, 473794, 478404, 669828, 659076, 609474, 631811, 481412, 636484,
+    593093, 466628, 473410, 679429, 620228, 626307, 627588, 601284, 471619, 469954, 614340, 688837,
+    479300, 619010, 462210, 599875, 469187, 17282, 469378, 665859, 467651, 626307, 642115, 473922,
+    614340, 629508, 599683, 459715, 592770, 474563, 471490, 602563, 465795, 470082, 597444, 591877,
+    689155, 466370, 636035, 602883, 614787, 609474, 462338, 586691, 689347, 467139, 628996, 473410,
+    634821, 642115, 643652, 683907, 473219, 597444, 619588, 592770, 608579, 16130, 464642, 480771,
+    473219, 460418, 468802, 614340, 640643, 466370, 481666, 597700, 468932, 464642, 478850, 615426,
+    459715, 591171, 476994, 609474, 463427, 617732, 483076, 620228, 470787, 470787, 635332, 461060,
+    480387, 286532, 622722, 468802, 588099, 473794, 286211, 591171, 630723, 614340, 476803, 477763,
+    286211, 597700, 466370, 469763, 469763, 639874, 467331, 469763, 614340, 462979, 475332, 610884,
+    610884, 610884, 599875, 468802, 626115, 665218, 630212, 628996, 596291, 603331, 466370, 619588,
+    464642, 471490, 621379, 661955, 687427, 473090, 609474, 478404, 609474, 473410, 481412, 625091,
+    687875, 17282, 606596, 614979, 474563, 474434, 589315, 591171, 642308, 589315, 468293, 643652,
+    662788, 605253, 597700, 627843, 470658, 460418, 478404, 477763, 606466, 633155, 466370, 630723,
+    629508, 627588, 624836, 3457, 625860, 473090, 469508, 464642, 640003, 650691, 662788, 460738,
+    476994, 676675, 470981, 606466, 16130, 479938, 477508, 651203, 471812, 469187, 610884, 598595,
+    474755, 654466, 473410, 641539, 465027, 597251, 460738, 445890, 622722, 612419, 469187, 622722,
+    474946, 617539, 629508, 466370, 639874, 665027, 665027, 665027, 665027, 617539, 589315, 619588,
+    471619, 473219, 476803, 611395, 603780, 479938, 609604, 589892, 597444, 17282, 617347, 646595,
+    630723, 286532, 466498, 445890, 618819, 471299, 480067, 476994, 482820, 594052, 626307, 286532,
+    478981, 287171, 473410, 621379, 621124, 611140, 648771, 593093, 601795, 480964, 477955, 613955,
+    469187, 464770, 468802, 594052, 471490, 592387, 17282, 468611, 462338, 471490, 597251, 480964,
+    612419, 480258, 675012, 478659, 618819, 594306, 468802, 630212, 599683, 464770, 467139, 473922,
+    473794, 638148, 602563, 476994, 618819, 594306, 648771, 616966, 589507, 286532, 599875, 629508,
+    592387, 654466, 459715, 606466, 468099, 475332, 617539, 3457, 668740, 619588, 619588, 464642,
+    480387, 625091, 473410, 668740, 474051, 17282, 480387, 601986, 591746, 286402, 286402, 643652,
+    286211, 463427, 645507, 624580, 618307, 466370, 617987, 460738, 610051, 469187, 633155, 634242,
+    464642, 483333, 613316, 465348, 461060, 461571, 473410, 592770, 613316, 631619, 592195, 469763,
+    465027, 472771, 473794, 610499, 17282, 477315, 643652, 465987, 479938, 16130, 597444, 472771,
+    17282, 636484, 656452, 647555, 622339, 632003, 665218, 617347, 650882, 481219, 689540, 474563,
+    465348, 466628, 643652, 463172, 16130, 469187, 286787, 474563, 17282, 459907, 462979, 466370,
+    479810, 469378, 466628, 474755, 597444, 473219, 466370, 466370, 469378, 642115, 465348, 635332,
+    473794, 650882, 286211, 610499, 465027, 597444, 597444, 468099, 646790, 591171, 592195, 611972,
+    17282, 469378, 466628, 650882, 637892, 592770, 477508, 480387, 479938, 465218, 463172, 665218,
+    605253, 471490, 471490, 461956, 468802, 479938, 597700, 612611, 17282, 469187, 616260, 617539,
+    471490, 477955, 468802, 661955, 661955, 468802, 471490, 641731, 641539, 17282, 479938, 478148,
+    473090, 590148, 478404, 666628, 613955, 464642, 471619, 470658, 17282, 286402, 470404, 473794,
+    462338, 672389, 480771, 462210, 594052, 638403, 594052, 468293, 634242, 472642, 464642, 624580,
+    468802, 623363, 599683, 621124, 633155, 473410, 473410, 623363, 640388, 480258, 286402, 641923,
+    459715, 665027, 470082, 593093, 589507, 665218, 465218, 470787, 594306, 640003, 465027, 470981,
+    617539, 610244, 633540, 689795, 606466, 618690, 469187, 460418, 471619, 592387, 483844, 471812,
+    466628, 619395, 599043, 482564, 689986, 478659, 619395, 17282, 621698, 626115, 478850, 608579,
+    479938, 670723, 620228, 476611, 478659, 637187, 595589, 613316, 602563, 621570, 606596, 609028,
+    473410, 616260, 622722, 461571, 626115, 16130, 474755, 587075, 286211, 594306, 466628, 665218,
+    602563, 611972, 634242, 469954, 468932, 630212, 618819, 670723, 626307, 621379, 614340, 636739,
+    633347, 460867, 464770, 572226, 640388, 468293, 619395, 587907, 640388, 592770, 614340, 623171,
+    622339, 609474, 618307, 642308, 467522, 618690, 469763, 605059, 632708, 612227, 633155, 465795,
+    471490, 641347, 592579, 479938, 587907, 628228, 592770, 602563, 460418, 598595, 633155, 629764,
+    475332, 592579, 470658, 670916, 465987, 592195, 17282, 477123, 286402, 286402, 675268, 465027,
+    599683, 482564, 471619, 482116, 461316, 594884, 463427, 464770, 466370, 620035, 467651, 620035,
+    668740, 445890, 588099, 460418, 17282, 472962, 620228, 466498, 484292, 471490, 475202, 606020,
+    627588, 476356, 621570, 460867, 464642, 607555, 467331, 473090, 641539, 465348, 630212, 474051,
+    474563, 460867, 665218, 478981, 475780, 471490, 475780, 465218, 476356, 17282, 655876, 655876,
+    472771, 468802, 480387, 469378, 606596, 642308, 650243, 592195, 616515, 286402, 477763, 470658,
+    463427, 641347, 661315, 592195, 445890, 610884, 445890, 514565, 467522, 614340, 474563, 657988,
+    690116, 615170, 606020, 689795, 615170, 591746, 655876, 3457, 464898, 649925, 16130, 643652,
+    610884, 655876, 625860, 609028, 464642, 633155, 623363, 637187, 609283, 610884, 468293, 589507,
+    629508, 474563, 469187, 478404, 647171, 471299, 470211, 595138, 678595, 684739, 473922, 621379,
+    640643, 664196, 616004, 592899, 465603, 470658, 17282, 589315, 467139, 468932, 462210, 589892,
+    614340, 662788, 460867, 631811, 629764, 469378, 623812, 286402, 476803, 621124, 586691, 616004,
+    464642, 661763, 587267, 462787, 606466, 619588, 473922, 473794, 471490, 592770, 643652, 460418,
+    630723, 473540, 476356, 588868, 286532, 614787, 638596, 464195, 460867, 464770, 476611, 478404,
+    478981, 462979, 630212, 592770, 597251, 479938, 603076, 626115, 592770, 474563, 480067, 462338,
+    473219, 591171, 473090, 633540, 690116, 476356, 465987, 470211, 592770, 474051, 643652, 465603,
+    445890, 468802, 619395, 3457, 3457, 482116, 286979, 467139, 474434, 618307, 597700, 638148,
+    463172, 467139, 471490, 643652, 612611, 620228, 478404, 618819, 470981, 461571, 648516, 602563,
+    3457, 460418, 665218, 481797, 473219, 462338, 286532, 591746, 602754, 471619, 473794, 623812,
+    469954, 474243, 467139, 616515, 679042, 480258, 610244, 601795, 633540, 464642, 483333, 595907,
+    652356, 650691, 462210, 620228, 594306, 616260, 17282, 616260, 629764, 597444, 480771, 467139,
+    473219, 597251, 620228, 462979, 466884, 651203, 603780, 445890, 16130, 464770, 623556, 461060,
+    614595, 656133, 609474, 616260, 461571, 286402, 465218, 466370, 478981, 614979, 476803, 469187,
+    638596, 659332, 470658, 620228, 17282, 605059, 475780, 466370, 477763, 641539, 665218, 609283,
+    687875, 459460, 632708, 599683, 468802, 617987, 460738, 286402, 629508, 464770, 641347, 629764,
+    633347, 463877, 474563, 595589, 473410, 594306, 629764, 614340, 621698, 472771, 655620, 469187,
+    614340, 626691, 655620, 465603, 629508, 641218, 641218, 286402, 594884, 445890, 477508, 479938,
+    654788, 623363, 631619, 477508, 669380, 17282, 603780, 476611, 609474, 466628, 620035, 474946,
+    466628, 464642, 589315, 665027, 637636, 286402, 472067, 286787, 602754, 640003, 465348, 655620,
+    690373, 473794, 630212, 469187, 597444, 611140, 619010, 478850, 474563, 606596, 470082, 690373,
+    677186, 468802, 687875, 602754, 602754, 594052, 459715, 668228, 480258, 483076, 481412, 479938,
+    592195, 475074, 473794, 625091, 609474, 480258, 16130, 614595, 16130, 471490, 460418, 471619,
+    473090, 468099, 615298, 286402, 445890, 619010, 615170, 470082, 608579, 471490, 614340, 466628,
+    462979, 466628, 615426, 609283, 623363, 621570, 461571, 610884, 460418, 634371, 467139, 466628,
+    604420, 467139, 466884, 633540, 607939, 462595, 676228, 471619, 639298, 665218, 690690, 477508,
+    646595, 646340, 629764, 591746, 640388, 462979, 473410, 690823, 470658, 600707, 464770, 691271,
+    691717, 477508, 464642, 474755, 470082, 592387, 662788, 692037, 473410, 479938, 468802, 692358,
+    692741, 17282, 693061, 286979, 286402, 614340, 613316, 676675, 693384, 468099, 693894, 694278,
+    602563, 466628, 589315, 614147, 17282, 653124, 622339, 636035, 478981, 646595, 603780, 476994,
+    484292, 286532, 287171, 466628, 694662, 469508, 470658, 286787, 286211, 616260, 460867, 695047,
+    695495, 695940, 696197, 696515, 696709, 697028, 697283, 697478, 697860, 459715, 698115, 698310,
+    698692, 697478, 697860, 698947, 699139, 699331, 699525, 699844, 700101, 700101, 700422, 700804,
+    701059, 701252, 701252, 701508, 701765, 702083, 702277, 702597, 702915, 703110, 703492, 701508,
+    703750, 703492, 704132, 703750, 704388, 701765, 704645, 704964, 705220, 705475, 705667, 705862,
+    706246, 698947, 706628, 706885, 707205, 707526, 707909, 708228, 708485, 708804, 709059, 709252,
+    709508, 709764, 708804, 710021, 710339, 710531, 710724, 710979, 696515, 696515, 711172, 711430,
+    705220, 705220, 711812, 712067, 696515, 17282, 712260, 712515, 712708, 712963, 713155, 713347,
+    706246, 713541, 713861, 714181, 712963, 714501, 703110, 703110, 696515, 714820, 466498, 715076,
+    705220, 697283, 702083, 715331, 715526, 699844, 703492, 715908, 466370, 716164, 716421, 701252,
+    698947, 716741, 717060, 717316, 701252, 477955, 717571, 717764, 718021, 718340, 718595, 718788,
+    704388, 710979, 719044, 719299, 719492, 719748, 720003, 720197, 640003, 719492, 720516, 720772,
+    719492, 718021, 708228, 696515, 721028, 721285, 721603, 699331, 721798, 701252, 701252, 722179,
+    722372, 699331, 699331, 699331, 722628, 712963, 697283, 712515, 710021, 702915, 722884, 723140,
+    723140, 705667, 723396, 705667, 697283, 705667, 698947, 723652, 723909, 724229, 724547, 724741,
+    725060, 696515, 707909, 725317, 725635, 469378, 715526, 710021, 725829, 703750, 715526, 710021,
+    702083, 726148, 726403, 704132, 723396, 709508, 726597, 726916, 709764, 727172, 727172, 698947,
+    727427, 723909, 696515, 727621, 727172, 713347, 696709, 727939, 728132, 728387, 700101, 728579,
+    698310, 728132, 706628, 701765, 728773, 702277, 729091, 698692, 729284, 729540, 729797, 730115,
+    701252, 724547, 698310, 710979, 730309, 730309, 730628, 730885, 731205, 727172, 731525, 719492,
+    696515, 731843, 732036, 732293, 696515, 732613, 732934, 733315, 468293, 696515, 725635, 733508,
+    727172, 717060, 732613, 733765, 731525, 706246, 701059, 709252, 712515, 734085, 717060, 734404,
+    706885, 729797, 727172, 723909, 734660, 731843, 734917, 706246, 697283, 735236, 699331, 735493,
+    735812, 736068, 696515, 736324, 724229, 696515, 698947, 736579, 712515, 698947, 710979, 713155,
+    736772, 728132, 737028, 709508, 710979, 737285, 706246, 704132, 737604, 737860, 699331, 738116,
+    707526, 698692, 709764, 707526, 738372, 705667, 730309, 697478, 732934, 729284, 729797, 738628,
+    736324, 709764, 708804, 621570, 738885, 739203, 726403, 739395, 739587, 739587, 723396, 739780,
+    740035, 732293, 740229, 727621, 740547, 702915, 740741, 702915, 717060, 701059, 696515, 702915,
+    736579, 721798, 741061, 741380, 717764, 709508, 708804, 699139, 740547, 741635, 741829, 742147,
+    709764, 731843, 708804, 742340, 704132, 733765, 742595, 742789, 743107, 741635, 730628, 743300,
+    699331, 696515, 729091, 742340, 743556, 743813, 731205, 739587, 738885, 740547, 744133, 744451,
+    731525, 462979, 744643, 473219, 735236, 744837, 743107, 745156, 715526, 705475, 733765, 745412,
+    713155, 745669, 717060, 722179, 701765, 698947, 745988, 737604, 720772, 746245, 746565, 724741,
+    742147, 696515, 701252, 746885, 727621, 730628, 747204, 712260, 699844, 747460, 696515, 720516,
+    747716, 747974, 710339, 714820, 701252, 748355, 708804, 740035, 704132, 748547, 748739, 748932,
+    722179, 729284, 731205, 749188, 720516, 738628, 749445, 740229, 696515, 718595, 734085, 697860,
+    717060, 749764, 748739, 750019, 724741, 750213, 750531, 714181, 750725, 751045, 743813, 751365,
+    728132, 712515, 751684, 751942, 703110, 750213, 742340, 700101, 726148, 748739, 710531, 716164,
+    725829, 727621, 730628, 726403, 752324, 752580, 752837, 753156, 699331, 715331, 753411, 753602,
+    701508, 740035, 753734, 754115, 712515, 754308, 696515, 710979, 754565, 754883, 714181, 710339,
+    744133, 755076, 710021, 730309, 698692, 755332, 755587, 710979, 715331, 710339, 710979, 713541,
+    755780, 699331, 702915, 697860, 736324, 736772, 699331, 742595, 738372, 700101, 474563, 704388,
+    756036, 710339, 747460, 473922, 734085, 736772, 710979, 743107, 710531, 756293, 755332, 734085,
+    756613, 722372, 756932, 706885, 705667, 748547, 737028, 699331, 736324, 741635, 734085, 732934,
+    720516, 701765, 757188, 734404, 738116, 756036, 709252, 747460, 729797, 757445, 705475, 707205,
+    737028, 757765, 716164, 716164, 733508, 758084, 758340, 721028, 758597, 746885, 758917, 737285,
+    751684, 696515, 697283, 742595, 719492, 710021, 706628, 722884, 732934, 706628, 734917, 712260,
+    759237, 759557, 757445, 710531, 731525, 739587, 729284, 708485, 709764, 759876, 760133, 760453,
+    755076, 702597, 730115, 760772, 712515, 710531, 711172, 761028, 734085, 761285, 702083, 712260,
+    719044, 761605, 637892, 761925, 703110, 734085, 704388, 762245, 706246, 762565, 717060, 706628,
+    709764, 699331, 701765, 762884, 742595, 709764, 763141, 704388, 706885, 737285, 763459, 753602,
+    723396, 738372, 763654, 747204, 731843, 742340, 749188, 725829, 737028, 702083, 764038, 734404,
+    742789, 700101, 704388, 704132, 734085, 740035, 712708, 742147, 710979, 741635, 712260, 757445,
+    699525, 698947, 696515, 723396, 738628, 702915, 764421, 750531, 750213, 764739, 723652, 723652,
+    737028, 764931, 748739, 706628, 709764, 734660, 732934, 720772, 765124, 712260, 755587, 697860,
+    710979, 765381, 765701, 710339, 718595, 722628, 766020, 721603, 766277, 741061, 737285, 752580,
+    706628, 715331, 766597, 731525, 721028, 729797, 722372, 698692, 737028, 756293, 744133, 734404,
+    758340, 766916, 733765, 713155, 701765, 717060, 732036, 749764, 737285, 470082, 767172, 722372,
+    767428, 767684, 767940, 738116, 744133, 768196, 757445, 758917, 760133, 768451, 707526, 699331,
+    761285, 768644, 697478, 705862, 710021, 720516, 719044, 741380, 698310, 761605, 704388, 731843,
+    715331, 748739, 737028, 768901, 728132, 726597, 748739, 769220, 731525, 730309, 758340, 761925,
+    702597, 700422, 715526, 766597, 714181, 751942, 753156, 769476, 728387, 715331, 745156, 732934,
+    747974, 721285, 697283, 769733, 707909, 709764, 698947, 770052, 737860, 707526, 734085, 709508,
+    724741, 705220, 770309, 734660, 730309, 721028, 753602, 724741, 734085, 731205, 708228, 726403,
+    739395, 614340, 755587, 730309, 770630, 771012, 702083, 728132, 717060, 714181, 701059, 706885,
+    740229, 736579, 771268, 696515, 754308, 751365, 707909, 696515, 771525, 710021, 738885, 750725,
+    734085, 722372, 709508, 724741, 719044, 749764, 616515, 767172, 748547, 718595, 771844, 700101,
+    751045, 772100, 739587, 728132, 731525, 772357, 712515, 743813, 772678, 739587, 732934, 773060,
+    767940, 748739, 753734, 755780, 773315, 756613, 757188, 712260, 702915, 704132, 752580, 773509,
+    773509, 710339, 773829, 709764, 774149, 722372, 707909, 774469, 716164, 737860, 710339, 759876,
+    757188, 767428, 737285, 704388, 767940, 716741, 722179, 712515, 722628, 757188, 765381, 774790,
+    775174, 775557, 774790, 730309, 747974, 775876, 776131, 775876, 699525, 776323, 732934, 722179,
+    775876, 722179, 736324, 776323, 702915, 704388, 704388, 701252, 701252, 771525, 731525, 776516,
+    734660, 776772, 727621, 776516, 745412, 710021, 710339, 764038, 718595, 723909, 777028, 746245,
+    703750, 699331, 728579, 749445, 777284, 715331, 459715, 772100, 749445, 757765, 707205, 746885,
+    701765, 697283, 710531, 741061, 730115, 777542, 733765, 734085, 777925, 698947, 696515, 698947,
+    707909, 751942, 776772, 778244, 777028, 765381, 776772, 778501, 778244, 750531, 751942, 750531,
+    749764, 778820, 751942, 776772, 740229, 748932, 766020, 740035, 699525, 748355, 765701, 714820,
+    723396, 747204, 754308, 751365, 696515, 748355, 701252, 707205, 772357, 706246, 710531, 765381,
+    745669, 743556, 779076, 710979, 723396, 748355, 701765, 702597, 771844, 778501, 706885, 701765,
+    712963, 775174, 721028, 712963, 753734, 702083, 760453, 779332, 710979, 771012, 702597, 730309,
+    773060, 697860, 734085, 710021, 716164, 779588, 776323, 697283, 739395, 779845, 760133, 710021,
+    703750, 761605, 735493, 732293, 702597, 719492, 734085, 714820, 768644, 696515, 710339, 729797,
+    745669, 780166, 738116, 702915, 726916, 728132, 708228, 708228, 743813, 715331, 773315, 698947,
+    770052, 770052, 770052, 706885, 710531, 722372, 780549, 706885, 780868, 742147, 710979, 706885,
+    713541, 706885, 781124, 764038, 781124, 719492, 769733, 713541, 740547, 774790, 750725, 776323,
+    768451, 722372, 698947, 771525, 741380, 741380, 768196, 758084, 735493, 758084, 727172, 727172,
+    781383, 734660, 700804, 696515, 698947, 747204, 731525, 781828, 722372, 736068, 698947, 709508,
+    746885, 782084, 709252, 721028, 710339, 782340, 773060, 777284, 727939, 778501, 781383, 710979,
+    722372, 740547, 710531, 766597, 771012, 732293, 706246, 779332, 782596, 706628, 706628, 755780,
+    710339, 766597, 782596, 707909, 710021, 778820, 723140, 758084, 781383, 782852, 754115, 726916,
+    722179, 712260, 783109, 746245, 749188, 742147, 776131, 706628, 720772, 742147, 720772, 781124,
+    726916, 698947, 742595, 710021, 783428, 723140, 727939, 765381, 783684, 718788, 776131, 727427,
+    731525, 749188, 781383, 778820, 734085, 783684, 710339, 742595, 756932, 783940, 707909, 697283,
+    781383, 730115, 778501, 709252, 726403, 698947, 726916, 761605, 704388, 708485, 734085, 698947,
+    726403, 704388, 710531, 737604, 763654, 734085, 726916, 764421, 712515, 778820, 710979, 763654,
+    734085, 705475, 712708, 781124, 781383, 759557, 707909, 784197, 748739, 710339, 784516, 766597,
+    768901, 743556, 724741, 778820, 714181, 721028, 782084, 712708, 708485, 726597, 710531, 701059,
+    777284, 720772, 724741, 720772, 734085, 734085, 737285, 737604, 698947, 734085, 696515, 734085,
+    712260, 707909, 784773, 698115, 710339, 708804, 710339, 705667, 746245, 739780, 749445, 717060,
+    712515, 717764, 773060, 728132, 785093, 723396, 712260, 702597, 708804, 739203, 706628, 740547,
+    741829, 696515, 708804, 785413, 710339, 710021, 745156, 745156, 759557, 743556, 733765, 744451,
+    756293, 748932, 753734, 706628, 773060, 743107, 701252, 776131, 710021, 752580, 726403, 771012,
+    720516, 754565, 744133, 744133, 699525, 776131, 738628, 759557, 761605, 748739, 783940, 772357,
+    747460, 713347, 781124, 699525, 702597, 740035, 740035, 747204, 712515, 696515, 738628, 785733,
+    763654, 745156, 725635, 748739, 768901, 701252, 698947, 726148, 705862, 724741, 746245, 733765,
+    696515, 721028, 770052, 759557, 731205, 710531, 709508, 723396, 717764, 738628, 738628, 748739,
+    710339, 738372, 774469, 746245, 755780, 741829, 722628, 705220, 755780, 766020, 712515, 722628,
+    786053, 786372, 699844, 699844, 755780, 702277, 17282, 774790, 758917, 710531, 786628, 705475,
+    728387, 748547, 786884, 710979, 749445, 787139, 705220, 772678, 741635, 782084, 737028, 787332,
+    720516, 734917, 705220, 696515, 764931, 770630, 724229, 770630, 753734, 712963, 697860, 740547,
+    752837, 720772, 700804, 787589, 753734, 756932, 712963, 723652, 720772, 720772, 737860, 709508,
+    720772, 726597, 726597, 787909, 773315, 705220, 740547, 715331, 701059, 740547, 696515, 788228,
+    704388, 752837, 739587, 740547, 701252, 736324, 731525, 788485, 733315, 754883, 763654, 712515,
+    737860, 739780, 701252, 715331, 709252, 782084, 723396, 709252, 737028, 754883, 708485, 723396,
+    709508, 717764, 752324, 709508, 698947, 726403, 788806, 699139, 753734, 752837, 699139, 742789,
+    788228, 748739, 704132, 748739, 715331, 778820, 763459, 723396, 706628, 706628, 709764, 789188,
+    772678, 712260, 776772, 714820, 709764, 741829, 698947, 789444, 718021, 701252, 759557, 723396,
+    740035, 728132, 789444, 712515, 698947, 753602, 761605, 715331, 648771, 741380, 742789, 753602,
+    725829, 710339, 711812, 741380, 710339, 705667, 705667, 704388, 743300, 712260, 712708, 713861,
+    466498, 743107, 705667, 712260, 750531, 713861, 742595, 710339, 743300, 751684, 725829, 789700,
+    783684, 756932, 765381, 750531, 765381, 715526, 704388, 710339, 721028, 719044, 741061, 722372,
+    705220, 726148, 768451, 720772, 704388, 702915, 710339, 732934, 772100, 710339, 704388, 704388,
+    765381, 702915, 749445, 708228, 701508, 740547, 740547, 789955, 767940, 773315, 730309, 700422,
+    698692, 767684, 767684, 767684, 767684, 736579, 732036, 704132, 749188, 698947, 704132, 790151,
+    770052, 777925, 714501, 728579, 713155, 762884, 701252, 716164, 708485, 740547, 777925, 752837,
+    740035, 717060, 779332, 765124, 790596, 709764, 765124, 790852, 791108, 702277, 743556, 739395,
+    710531, 732293, 778820, 786628, 743556, 712260, 724741, 775557, 728579, 696709, 776131, 771844,
+    720516, 701252, 704132, 701508, 791363, 723396, 752580, 702915, 758340, 720516, 713347, 735493,
+    698947, 628996, 730885, 715076, 740035, 791557, 720516, 705475, 738885, 734404, 730115, 743107,
+    698947, 735493, 781828, 731205, 745669, 751684, 710339, 776323, 715526, 712708, 697860, 702083,
+    748739, 791874, 721603, 764739, 718788, 696515, 754308, 700101, 708228, 792004, 714820, 756932,
+    741635, 726148, 726148, 706628, 699139, 784516, 728579, 735812, 734660, 753602, 741380, 725829,
+    792261, 792581, 697283, 730885, 712260, 727621, 712515, 712515, 732613, 792899, 718788, 715331,
+    758597, 776516, 736068, 751684, 764739, 712515, 790596, 705667, 754308, 748739, 753602, 705475,
+    779588, 736579, 697283, 703110, 712515, 790596, 710979, 724741, 730885, 720516, 763654, 792899,
+    792004, 710979, 696515, 732036, 764739, 710339, 745412, 765381, 750725, 477123, 705862, 712515,
+    753602, 701252, 746245, 793092, 736068, 739587, 793346, 732293, 778244, 704132, 731843, 740229,
+    701252, 740229, 793476, 715331, 696515, 697283, 713541, 730115, 739395, 730115, 709764, 740035,
+    743107, 787332, 697860, 735236, 696515, 756293, 727621, 712963, 745669, 787139, 740547, 710531,
+    793732, 764931, 730628, 726916, 783940, 786884, 741635, 710979, 733765, 743107, 712708, 743107,
+    725060, 705220, 744133, 710979, 789444, 740229, 744451, 744643, 739395, 744643, 742340, 699139,
+    774149, 702597, 743813, 793988, 702915, 730309, 737604, 697283, 722179, 753602, 747974, 712708,
+    715331, 696515, 773060, 730115, 715076, 748355, 712260, 734404, 698947, 740229, 705475, 788806,
+    757188, 756293, 776131, 737860, 747716, 744837, 786628, 730309, 753602, 738885, 736772, 794244,
+    704388, 745156, 696515, 736579, 794500, 727621, 731205, 730885, 774790, 720516, 744451, 709059,
+    749764, 699331, 731843, 755332, 715526, 736579, 745988, 753734, 705667, 787139, 756293, 741635,
+    782340, 636484, 782084, 794758, 750725, 726916, 650691, 698947, 751365, 748932, 795139, 752580,
+    765381, 774149, 783940, 791557, 705475, 753602, 720516, 741829, 710339, 719299, 754883, 743107,
+    754115, 756293, 730309, 745156, 726403, 711812, 712708, 703750, 728579, 715076, 713347, 722884,
+    784516, 699139, 741380, 725317, 795333, 795653, 705475, 704132, 704388, 739395, 739395, 745412,
+    741380, 766916, 762565, 696515, 736579, 717060, 776323, 730309, 795973, 710339, 715331, 737285,
+    742340, 701508, 738372, 712515, 768196, 705667, 776516, 697283, 731843, 726148, 721603, 796292,
+    730885, 776516, 796548, 745156, 703750, 766916, 768196, 795973, 754565, 719044, 698947, 742340,
+    763459, 706885, 714820, 712963, 697283, 777925, 709764, 783109, 732934, 722179, 710339, 705862,
+    748547, 706628, 795139, 788485, 699331, 757765, 747460, 719044, 715331, 730628, 712963, 736579,
+    740229, 796804, 797060, 708485, 708485, 697478, 731843, 725317, 772100, 791874, 756293, 715526,
+    792004, 712515, 736068, 738116, 719044, 712963, 697283, 697283, 786628, 709508, 709508, 702083,
+    730115, 741635, 738116, 797317, 743107, 705667, 697283, 711812, 735236, 703750, 730309, 701059,
+    719044, 727427, 727939, 761605, 778820, 797635, 711812, 797827, 700422, 798020, 798276, 759876,
+    704388, 731843, 746245, 758340, 726403, 696709, 754565, 756293, 445890, 737285, 720516, 742595,
+    748547, 705220, 709508, 776131, 744133, 709764, 752324, 766597, 699331, 711812, 727427, 706628,
+    751684, 741380, 790852, 740229, 751045, 794244, 798531, 699331, 714820, 757188, 734404, 763459,
+    699525, 712963, 722628, 707526, 737860, 795973, 795973, 715331, 743107, 698947, 782852, 706246,
+    709764, 719044, 740035, 764038, 773315, 798020, 738885, 703750, 699331, 704645, 720516, 714181,
+    704388, 709508, 793092, 767684, 710339, 795139, 712708, 709764, 785413, 761925, 793092, 798725,
+    782852, 715331, 699331, 706628, 783428, 754115, 799044, 737604, 742595, 734404, 743107, 791874,
+    736772, 705862, 797827, 744133, 755587, 730885, 723396, 696515, 766916, 721603, 749188, 725060,
+    776131, 724547, 704645, 757765, 718595, 765124, 744451, 698947, 713861, 712515, 730309, 701252,
+    705475, 730115, 706628, 759876, 785733, 744451, 702915, 720516, 752580, 766916, 717060, 725060,
+    766916, 726148, 798020, 743107, 799300, 771268, 799555, 705475, 719299, 799748, 736579, 463877,
+    731205, 741635, 768451, 768451, 726597, 738116, 711812, 698115, 765124, 765124, 758340, 731843,
+    697283, 724741, 775174, 724741, 781124, 758340, 698692, 800004, 717060, 736579, 756293, 768901,
+    783428, 800259, 739395, 756293, 712963, 737860, 798276, 764739, 730309, 727939, 732934, 740035,
+    791363, 800451, 721603, 721603, 712963, 767940, 773315, 800259, 737285, 702083, 740741, 761925,
+    800645, 724741, 748355, 756293, 782084, 717764, 778244, 742340, 758917, 745156, 756293, 698947,
+    708804, 732613, 782852, 789700, 756293, 702915, 752580, 710979, 721285, 714181, 715331, 736772,
+    758340, 758340, 698115, 738628, 753602, 769476, 770052, 720516, 737860, 757188, 781383, 753411,
+    709508, 699331, 792004, 703110, 724741, 702083, 715331, 697283, 752580, 726597, 740035, 769733,
+    737860, 714820, 728387, 721028, 726148, 738628, 798020, 748739, 702915, 704388, 761925, 787139,
+    709508, 699331, 740229, 728132, 751365, 782340, 737860, 697283, 753602, 768196, 696515, 701252,
+    724741, 765381, 736579, 744133, 721028, 750725, 699331, 793092, 758084, 705475, 736324, 735812,
+    771525, 800964, 771268, 701059, 701059, 714820, 789700, 743813, 731843, 772100, 724547, 737604,
+    718595, 752580, 697860, 705667, 726916, 715331, 700101, 698947, 728132, 752580, 742595, 732934,
+    286787, 801218, 801347, 742595, 748739, 737860, 726403, 704132, 798020, 714820, 712963, 698115,
+    764038, 711812, 710339, 777284, 801541, 716741, 756293, 698115, 704388, 710339, 748739, 774149,
+    710979, 709508, 773509, 794500, 701059, 715526, 737604, 698115, 715331, 726916, 754115, 704388,
+    707909, 761925, 774469, 741061, 727427, 724547, 756293, 724741, 797317, 795973, 737604, 700422,
+    798020, 756293, 801347, 710339, 707909, 751045, 710339, 696515, 759876, 716741, 757188, 755587,
+    715331, 801859, 734085, 764931, 708804, 772100, 798020, 716741, 598595, 802050, 709764, 737860,
+    714820, 701508, 702915, 802180, 734085, 737860, 736324, 714820, 752324, 784197, 784197, 735812,
+    724229, 765381, 751942, 763141, 722628, 702277, 712963, 737860, 765381, 704132, 798276, 802436,
+    702277, 709764, 776323, 709764, 729284, 798276, 802692, 802947, 732293, 739395, 798276, 771844,
+    798276, 776323, 704132, 798276, 714820, 755076, 728387, 699331, 741380, 765381, 729284, 746245,
+    699331, 761605, 798276, 707526, 709764, 765381, 746245, 754115, 710979, 765381, 765381, 753602,
+    776323, 776323, 776323, 784197, 803140, 737860, 696515, 765381, 718021, 718021, 776323, 705667,
+    776323, 725829, 763654, 728387, 748739, 803396, 705667, 749764, 709764, 705475, 699331, 712515,
+    720772, 701059, 696515, 731843, 731525, 731525, 750213, 708485, 771525, 788228, 803655, 804101,
+    753734, 732934, 697283, 798020, 698115, 698947, 717060, 712260, 712260, 739780, 738628, 723652,
+    726148, 734660, 754308, 785093, 792004, 734917, 734660, 760772, 758340, 747204, 701059, 742340,
+    785093, 741635, 746885, 731843, 710021, 698947, 750725, 731843, 710021, 734085, 758340, 710339,
+    728579, 712515, 734660, 804421, 791363, 779332, 737285, 802436, 740547, 758340, 712260, 730628,
+    719492, 713541, 741061, 778820, 739587, 771844, 701059, 751942, 733765, 729091, 768451, 777284,
+    701508, 725635, 776131, 766020, 718788, 728579, 720516, 738372, 732293, 708804, 710979, 712963,
+    712260, 758597, 727427, 774149, 774149, 748739, 768451, 804740, 786884, 471299, 746885, 702597,
+    698947, 776131, 748739, 742147, 720516, 767940, 699844, 714820, 751684, 775876, 775557, 710979,
+    747974, 734404, 726916, 726916, 720772, 749188, 733765, 714820, 713155, 766020, 765381, 790852,
+    723396, 797060, 751942, 777925, 753602, 709059, 741061, 730885, 712515, 747716, 474946, 786628,
+    771525, 781124, 804997, 787332, 748547, 746885, 730309, 795973, 766277, 735236, 728579, 696515,
+    714820, 741380, 740035, 758597, 805316, 795333, 736579, 758917, 734917, 789444, 744133, 738628,
+    717060, 731843, 717764, 754565, 738885, 703110, 704388, 728132, 711812, 767940, 773060, 699139,
+    703110, 805573, 699139, 701252, 738628, 798276, 751942, 702083, 788485, 731843, 699331, 699139,
+    705667, 759557, 722884, 701059, 703110, 709252, 710531, 729284, 721798, 759237, 775174, 702597,
+    732036, 758340, 748547, 761028, 716164, 740741, 747204, 712260, 726916, 696515, 712260, 696515,
+    735236, 698947, 779845, 697283, 799044, 710531, 739587, 754115, 776131, 737285, 802436, 739587,
+    734085, 775876, 728132, 778501, 699331, 753602, 758917, 739395, 776323, 760453, 473540, 705667,
+    731525, 646595, 748547, 482564, 726916, 787332, 776323, 703110, 714820, 755076, 741635, 726597,
+    798276, 790852, 700422, 751684, 755780, 755780, 699331, 731843, 776772, 706628, 805893, 753602,
+    795333, 747204, 757445, 699331, 764038, 753602, 734404, 704388, 755780, 698947, 779588, 732934,
+    773315, 730628, 714820, 706885, 706246, 706628, 750213, 706885, 778501, 707526, 734085, 806214,
+    806599, 715526, 764038, 760453, 764421, 626115, 738885, 698947, 698947, 750725, 761925, 724547,
+    703110, 744133, 781124, 755587, 755587, 701252, 748355, 724547, 785733, 703110, 776323, 737285,
+    789444, 706246, 710339, 758917, 766277, 771268, 787589, 775876, 757765, 712515, 703110, 738628,
+    705475, 750213, 736579, 749445, 704388, 747204, 757765, 765381, 736068, 723396, 777284, 807044,
+    754565, 732934, 758917, 740741, 773509, 753411, 748739, 764739, 731525, 779588, 698115, 700422,
+    790151, 716164, 783428, 720516, 769220, 756613, 710021, 697283, 716164, 731843, 698310, 747204,
+    744133, 741061, 773509, 712260, 715331, 798276, 705862, 728132, 731843, 697478, 725635, 738116,
+    704388, 724547, 807301, 697283, 705862, 703750, 469187, 804421, 714181, 807301, 740035, 766597,
+    766597, 728579, 728387, 750725, 702597, 770052, 789444, 698115, 735812, 735812, 735812, 731843,
+    763654, 726916, 726916, 758340, 769733, 712515, 734660, 764038, 758340, 787139, 702083, 807620,
+    807877, 699331, 705862, 698947, 714181, 797060, 696515, 701059, 741635, 741061, 770630, 702083,
+    744133, 726403, 758340, 772100, 710531, 744837, 771525, 774469, 731525, 738628, 772100, 715331,
+    743107, 736579, 705667, 758084, 752580, 805893, 807877, 767940, 753734, 748739, 704388, 758340,
+    709764, 744837, 774149, 774149, 791108, 710339, 708228, 774469, 808196, 738628, 704388, 767940,
+    728579, 626115, 701252, 727172, 648771, 808455, 808455, 763654, 696515, 717764, 776131, 708804,
+    808455, 712963, 712963, 802436, 696515, 712963, 740035, 802436, 777925, 791557, 769476, 777925,
+    696515, 712260, 739395, 722372, 770630, 706885, 737028, 808900, 752837, 722884, 802692, 732293,
+    710979, 742340, 697860, 774790, 589315, 787332, 697860, 782852, 715331, 794244, 765381, 776772,
+    755587, 726916, 726916, 702083, 761605, 809156, 766020, 766020, 697283, 772357, 772357, 714820,
+    737604, 773509, 787139, 727939, 757765, 771844, 710979, 714501, 734404, 733765, 792261, 790596,
+    701252, 760772, 771844, 723652, 704388, 741635, 696515, 747716, 717060, 726916, 809412, 753734,
+    722628, 717060, 797635, 809667, 726597, 795973, 731843, 704388, 786884, 746245, 697283, 711172,
+    706885, 723396, 734660, 741061, 464388, 782852, 809860, 795973, 697283, 781124, 712708, 760772,
+    696515, 770630, 786628, 787589, 760772, 715331, 715526, 756932, 735493, 738628, 785093, 741061,
+    768451, 743300, 785733, 779332, 768451, 712515, 768451, 770630, 726597, 726916, 722628, 770630,
+    701252, 734660, 737604, 741635, 726597, 726916, 739203, 739203, 696709, 711172, 731525, 734085,
+    724547, 705220, 752324, 749188, 752580, 730309, 770052, 698947, 795653, 718595, 756932, 723909,
+    730885, 808455, 710979, 721603, 709508, 727172, 710979, 710021, 704388, 723396, 736068, 706246,
+    797635, 773315, 698947, 785093, 706246, 734404, 728132, 722628, 785413, 735236, 701252, 782340,
+    808455, 810116, 704388, 719299, 736324, 734660, 741635, 699331, 730628, 706885, 765381, 734917,
+    712515, 699331, 720772, 773315, 728579, 739587, 740229, 708485, 775557, 787139, 711172, 765381,
+    723652, 738885, 712260, 753602, 779588, 698115, 697283, 741635, 727621, 700804, 753602, 743107,
+    740035, 713155, 721798, 771012, 739780, 725635, 710979, 732293, 730115, 730115, 701252, 778244,
+    766597, 733765, 769220, 706628, 739395, 702915, 746885, 709764, 730115, 710531, 766597, 794500,
+    727172, 734404, 731205, 770630, 716164, 698947, 699844, 697478, 810372, 759557, 724741, 766020,
+    725635, 734085, 734085, 696515, 788485, 712260, 698947, 698947, 730309, 725317, 747974, 754308,
+    747716, 704388, 745669, 773060, 735236, 704388, 704132, 704388, 749188, 727621, 714820, 720516,
+    720772, 746245, 730115, 760133, 709764, 730885, 744451, 783428, 705667, 810628, 724741, 718021,
+    713541, 730309, 741829, 710339, 703110, 756293, 791557, 710979, 766916, 712515, 709764, 776131,
+    747460, 797317, 696709, 810886, 746885, 752324, 699331, 767428, 735236, 777028, 759557, 727621,
+    712260, 741380, 731843, 808455, 753602, 794500, 753156, 741380, 699525, 712515, 704388, 712515,
+    715331, 704388, 699331, 702915, 699331, 744451, 286402, 730309, 710531, 740547, 811269, 697283,
+    730309, 804421, 702597, 732934, 748739, 736579, 753602, 753602, 783428, 699525, 702277, 783940,
+    710531, 791363, 761028, 696709, 709252, 756613, 756293, 716164, 697283, 737860, 710979, 753602,
+    763459, 758340, 756932, 709764, 706628, 757765, 739587, 746245, 710021, 755076, 710021, 731525,
+    741061, 746885, 712963, 744451, 747460, 741635, 714820, 700101, 797317, 710979, 710021, 729284,
+    704388, 772100, 802436, 714820, 705667, 710531, 710339, 741061, 730309, 811588, 782084, 761925,
+    707909, 727621, 725829, 771525, 734404, 712515, 752324, 762884, 717060, 723652, 740035, 699331,
+    709764, 742595, 811844, 779588, 709508, 805893, 726148, 737860, 712515, 731525, 762565, 776772,
+    710979, 706628, 764421, 715331, 706246, 779588, 764038, 699525, 748547, 761925, 738885, 706628,
+    709764, 779588, 765381, 750213, 701508, 742595, 723396, 730628, 726597, 776772, 778244, 702915,
+    763141, 709764, 744451, 705220, 712963, 768451, 460867, 812100, 740035, 723652, 720772, 809860,
+    734404, 701059, 715331, 765381, 761925, 765701, 718595, 720516, 777284, 779588, 779332, 768451,
+    721603, 766020, 782852, 726148, 717060, 799044, 750213, 765381, 749188, 744133, 745669, 761925,
+    698947, 755587, 812357, 730309, 786628, 713861, 754565, 787332, 755780, 736579, 701059, 794500,
+    696515, 768901, 699331, 767940, 738885, 769220, 696515, 698310, 724229, 744133, 739587, 710339,
+    731843, 720772, 704388, 728132, 700422, 732934, 750531, 741380, 812676, 745156, 698115, 711812,
+    712963, 712963, 768451, 812676, 705862, 721603, 710724, 739395, 712515, 724741, 730628, 742789,
+    776772, 730309, 730309, 698115, 721285, 726597, 782084, 720772, 769733, 712260, 740035, 696515,
+    782084, 715331, 737860, 810628, 715331, 704388, 707909, 724741, 779588, 770052, 770052, 730309,
+    696515, 709764, 724741, 710979, 701252, 741635, 766597, 722884, 705862, 726597, 795973, 736324,
+    812357, 702597, 696515, 767684, 736579, 810886, 812933, 721603, 746565, 700101, 728132, 742147,
+    699331, 794500, 744133, 740035, 711172, 704388, 771844, 761925, 767684, 812357, 763459, 761925,
+    699525, 813253, 721603, 727621, 746885, 739587, 773315, 777284, 765381, 772100, 704388, 790151,
+    732293, 724741, 810886, 772100, 730309, 761925, 790151, 788806, 746245, 748355, 710339, 718021,
+    704388, 708804, 772100, 730115, 706628, 721028, 721028, 759237, 722628, 730115, 730115, 781828,
+    813572, 710979, 737028, 730115, 756293, 736324, 772357, 702915, 771844, 755780, 698947, 739395,
+    739203, 718788, 474051, 700422, 725060, 718021, 813572, 744837, 715076, 702277, 731525, 716164,
+    707909, 705667, 730115, 739587, 710979, 730115, 710339, 718788, 726403, 718595, 747460, 798020,
+    798020, 761925, 718021, 699525, 708804, 765701, 724547, 720772, 761285, 772678, 699331, 777925,
+    748547, 776323, 760133, 709252, 811844, 734404, 730885, 696515, 710021, 779845, 748355, 708804,
+    771525, 730885, 757445, 707205, 740229, 696709, 709252, 696515, 755780, 701252, 701252, 775174,
+    727621, 776131, 738885, 701252, 704132, 795973, 696515, 746565, 701252, 730309, 749445, 703110,
+    802692, 749445, 756293, 749764, 712708, 755780, 725060, 704388, 756293, 717060, 727621, 773509,
+    765701, 764038, 748355, 743556, 813829, 767172, 755587, 761028, 757188, 698947, 765381, 698947,
+    714820, 748355, 743556, 761605, 789444, 750213, 734085, 710979, 743813, 701765, 795973, 779588,
+    726148, 741380, 699331, 701252, 743813, 726148, 743813, 712260, 699331, 755780, 813829, 743813,
+    726148, 698115, 749188, 710531, 778820, 726148, 712963, 712515, 721285, 701252, 708804, 761285,
+    727621, 709764, 723396, 813829, 737860, 721285, 754308, 795973, 696515, 696515, 755780, 726148,
+    775174, 755780, 706885, 781828, 775876, 702915, 738628, 758084, 748739, 775876, 777925, 791363,
+    706885, 700422, 710979, 743300, 776131, 770630, 770630, 700422, 709764, 769476, 738628, 716164,
+    775876, 758084, 756293, 698947, 741829, 722179, 722179, 722179, 800259, 734085, 716164, 773509,
+    697478, 697478, 698115, 800259, 732934, 721285, 730309, 730309, 646595, 723909, 723909, 704132,
+    721285, 721285, 734404, 791557, 723909, 723909, 723909, 800259, 734404, 726916, 782852, 706246,
+    705220, 719492, 739395, 719492, 753156, 719492, 719492, 701252, 714820, 720516, 697028, 739587,
+    728132, 709764, 812357, 710531, 701059, 814149, 715331, 741635, 710531, 706628, 757765, 740547,
+    742595, 706628, 712515, 735493, 701252, 701252, 713541, 698947, 723396, 773509, 715331, 739587,
+    742789, 715331, 469187, 769220, 753602, 783428, 713541, 742595, 756932, 716164, 723396, 776323,
+    748739, 748739, 715331, 814149, 748739, 710531, 723396, 710531, 721028, 721285, 721028, 716164,
+    715331, 738372, 792004, 739587, 766597, 478850, 730309, 710339, 718788, 814469, 599875, 774149,
+    696515, 697283, 727172, 712515, 741380, 727621, 699331, 722372, 722884, 697283, 459907, 782340,
+    739780, 734404, 789188, 705667, 712515, 706628, 753602, 698947, 731525, 734660, 765381, 697283,
+    805316, 731525, 697283, 749188, 706628, 740547, 778501, 730309, 768451, 772100, 758084, 728579,
+    742340, 742340, 733765, 710531, 778501, 776131, 766020, 718788, 737028, 710339, 746565, 710531,
+    704388, 710979, 736772, 717060, 732293, 730628, 735236, 704132, 732036, 753734, 782340, 740547,
+    712963, 791363, 771012, 748547, 746565, 732293, 720516, 814789, 702597, 815108, 782852, 736772,
+    776772, 731205, 718788, 696515, 815365, 743107, 776131, 759876, 753602, 710979, 738628, 790852,
+    748739, 748547, 722179, 699844, 746245, 731205, 696515, 725635, 701252, 748547, 709764, 712963,
+    712260, 748355, 710339, 698947, 731205, 709764, 704132, 704645, 697860, 710339, 734404, 724741,
+    721798, 718021, 724741, 753602, 753602, 699331, 702083, 699139, 714181, 802692, 706885, 706885,
+    787332, 710339, 704132, 730309, 730885, 758084, 727621, 703110, 776323, 795333, 754308, 752580,
+    697860, 714820, 699331, 754565, 747204, 745156, 747460, 730309, 789444, 759237, 775174, 775174,
+    724547, 708228, 782084, 710531, 747460, 697283, 771012, 802436, 802436, 754883, 739395, 738116,
+    705667, 705667, 778501, 729284, 729284, 760453, 721028, 726916, 721798, 798276, 714820, 702597,
+    741380, 721798, 709764, 734404, 701059, 704388, 760133, 706628, 740741, 754883, 721028, 702597,
+    742789, 739395, 714820, 475202, 727939, 764038, 699331, 709764, 733765, 706885, 701508, 735812,
+    742595, 696515, 712260, 701252, 749764, 734404, 753602, 708804, 747204, 748739, 704388, 779588,
+    719492, 698947, 698947, 795973, 725829, 763654, 779588, 731525, 712515, 699331, 757445, 772100,
+    721028, 704388, 704388, 709764, 757445, 749188, 722884, 765701, 815690, 726148, 699331, 816329,
+    783428, 776323, 775876, 715331, 785733, 729797, 771525, 730885, 706628, 736579, 777284, 712515,
+    760133, 714181, 730115, 709764, 740741, 741061, 783684, 731525, 744133, 798020, 783684, 698947,
+    462979, 786628, 760133, 757445, 770630, 721285, 724229, 707909, 790852, 805316, 696709, 776323,
+    783428, 698310, 742789, 742789, 768451, 800004, 740547, 705862, 789700, 789700, 758084, 758084,
+    715331, 699331, 726403, 774149, 715526, 814149, 726403, 726148, 724741, 704388, 745156, 742789,
+    708228, 721285, 721285, 728132, 769733, 726916, 769476, 724741, 720772, 734404, 714181, 734404,
+    721028, 742789, 696515, 718788, 787332, 702083, 702083, 696515, 753602, 730309, 698947, 723396,
+    776131, 715331, 731843, 768451, 789700, 469187, 739587, 765701, 758084, 732293, 764931, 699331,
+    782340, 714820, 472962, 706628, 710339, 722884, 774149, 774149, 741061, 761925, 715331, 710531,
+    707909, 761925, 782852, 704388, 709764, 787332, 746245, 732613, 784773, 718021, 741061, 741061,
+    619395, 704388, 704388, 704388, 787332, 704388, 707526, 720772, 707526, 740229, 764038, 710021,
+    738628, 732613, 732613, 773060, 749445, 742340, 714181, 710531, 749445, 816900, 712515, 817156,
+    817156, 751045, 701508, 701508, 698947, 696515, 702915, 728579, 712260, 775557, 715526, 696515,
+    728132, 738628, 759557, 817413, 738628, 728132, 710979, 764931, 741635, 701252, 699139, 696709,
+    788806, 770052, 798020, 701252, 734660, 797635, 712260, 715331, 712963, 709508, 709764, 698310,
+    737285, 732036, 721603, 735236, 797635, 793476, 710979, 726597, 712260, 740229, 726403, 712260,
+    731843, 698115, 696515, 696515, 713541, 788228, 759557, 718595, 788806, 732036, 721798, 811269,
+    714181, 750213, 701252, 802692, 715331, 766277, 732036, 720772, 700101, 713541, 698310, 719044,
+    709252, 732036, 709764, 732934, 719044, 697283, 727939, 798276, 776772, 773315, 751684, 721798,
+    740035, 748355, 709764, 763459, 710979, 696515, 766277, 713541, 817735, 749445, 779332, 764038,
+    758084, 748355, 717060, 773829, 737860, 706246, 766597, 769220, 725635, 756613, 798276, 727939,
+    725635, 766277, 698310, 751942, 707909, 710979, 712260, 712963, 770052, 781383, 741635, 818180,
+    628485, 748355, 714181, 707909, 734660, 772357, 766277, 700101, 715076, 722372, 706628, 730309,
+    772100, 719492, 713541, 788485, 731525, 701765, 701765, 746885, 719492, 734404, 795973, 704132,
+    704132, 698947, 704388, 701059, 725829, 727939, 747974, 808455, 698115, 761605, 741635, 726148,
+    735812, 781383, 720772, 705667, 745412, 706628, 740035, 748739, 712515, 818438, 754115, 761925,
+    731843, 786884, 741061, 705667, 785413, 795333, 742147, 710979, 731205, 713861, 756932, 715526,
+    696515, 712260, 746565, 712260, 736772, 773315, 742340, 756293, 776323, 720772, 754115, 713861,
+    725829, 704132, 699139, 696709, 703110, 747460, 737028, 698947, 748547, 716164, 724229, 744133,
+    730628, 760133, 716164, 761605, 699331, 699331, 785413, 765124, 750531, 715526, 756932, 705220,
+    743107, 737860, 777284, 719044, 726597, 754565, 765124, 734085, 783940, 761605, 705220, 705220,
+    726148, 768451, 810372, 783428, 714820, 721285, 732036, 748739, 696515, 722372, 707909, 776323,
+    702915, 736324, 785413, 732934, 712515, 756932, 747974, 818820, 750725, 741635, 705862, 723396,
+    708228, 708228, 721285, 748739, 460418, 761925, 730309, 725829, 744133, 710339, 725829, 714820,
+    738628, 704388, 725829, 731843, 791363, 734085, 737860, 711172, 737860, 749445, 788485, 781124,
+    752837, 696515, 697283, 751365, 734660, 710531, 704388, 696515, 787589, 741635, 696515, 696515,
+    722179, 698692, 701252, 722179, 701252, 701252, 749445, 735493, 714820, 712963, 710979, 730885,
+    712515, 753156, 720516, 770630, 728579, 727621, 731843, 698310, 721028, 786628, 710979, 731843,
+    748355, 730309, 712963, 732613, 739203, 710021, 750213, 696515, 748355, 746245, 709252, 741829,
+    762884, 738885, 710021, 735812, 698310, 770630, 705862, 758917, 709508, 705862, 741635, 741635,
+    743300, 709508, 734085, 766020, 819076, 713155, 741635, 743556, 819333, 782596, 756293, 709508,
+    810372, 705862, 730885, 748355, 810372, 709252, 721028, 698947, 698947, 720772, 771525, 748739,
+    748739, 784197, 737860, 712260, 737860, 737860, 696515, 696515, 696515, 696515, 737604, 737604,
+    766597, 746885, 736068, 731205, 704388, 704388, 699331, 720516, 761028, 756613, 735493, 767172,
+    758917, 744133, 768901, 698310, 761925, 720516, 720516, 708485, 730628, 698947, 713861, 706246,
+    696515, 723909, 738372, 741635, 720516, 732293, 710979, 723909, 721798, 753411, 723909, 710021,
+    732036, 715331, 738628, 745988, 733765, 744837, 748739, 751684, 744451, 703110, 712260, 740035,
+    710021, 776323, 702277, 474563, 747460, 702277, 800451, 755332, 715331, 594884, 697283, 732934,
+    712260, 702277, 740229, 747460, 699331, 723396, 706628, 734085, 720516, 717060, 752837, 764038,
+    710979, 738628, 709764, 713861, 734404, 709764, 715331, 721798, 768901, 720516, 800451, 721798,
+    721798, 782596, 800451, 775174, 738372, 724741, 709764, 724741, 737860, 779588, 774149, 715526,
+    755780, 740547, 736324, 716164, 741635, 696515, 713347, 727172, 758084, 696709, 699844, 698947,
+    727172, 727172, 707909, 758340, 819651, 819651, 719492, 697283, 701252, 781828, 712260, 723909,
+    791557, 715331, 770052, 761925, 757765, 771012, 771012, 706246, 775174, 712515, 698947, 712515,
+    715331, 702083, 704132, 709252, 702277, 706246, 750725, 699331, 752837, 712260, 697283, 738116,
+    773509, 771844, 792004, 731525, 788485, 755076, 774790, 810116, 723909, 730309, 736324, 724229,
+    712963, 781124, 734660, 744837, 771844, 760133, 712515, 764739, 732934, 781383, 710531, 696515,
+    755780, 732613, 771012, 735236, 742340, 819844, 733765, 743556, 739780, 739203, 787332, 721798,
+    797635, 699139, 793476, 739395, 712963, 710531, 787139, 726597, 702915, 700101, 731843, 732036,
+    696515, 776323, 704132, 710979, 698947, 758917, 746885, 765381, 739587, 714501, 710979, 715331,
+    741635, 704132, 700804, 738885, 702277, 701765, 764038, 740035, 722372, 701059, 715331, 715331,
+    758340, 820104, 757445, 775876, 665218, 820611, 696515, 720516, 727172, 738628, 701252, 715331,
+    745156, 725317, 753734, 725635, 712260, 723396, 726597, 746565, 764038, 790852, 752580, 759237,
+    720772, 725317, 715331, 756293, 716164, 820802, 747716, 701508, 733765, 737860, 737860, 720516,
+    717060, 696515, 774790, 714501, 708228, 737860, 730115, 740035, 720516, 715526, 739203, 745988,
+    809667, 776131, 731205, 757765, 749445, 746245, 715331, 788228, 782340, 758917, 797060, 800451,
+    737860, 753602, 820933, 731205, 704388, 781124, 750531, 787332, 722628, 791557, 744133, 744133,
+    765381, 701059, 802436, 714181, 782340, 699331, 699331, 706628, 715331, 711812, 708228, 742595,
+    741380, 752580, 700804, 742595, 699139, 696515, 731525, 737860, 715331, 737028, 769220, 696515,
+    725317, 760133, 746245, 703110, 737860, 712515, 712515, 704132, 710339, 703750, 795973, 702277,
+    696515, 782340, 710339, 802180, 787332, 753602, 789444, 738885, 731525, 712515, 771012, 702277,
+    756932, 737028, 726916, 738116, 732934, 733508, 710339, 698947, 707205, 707205, 746885, 758084,
+    697283, 759237, 757765, 729284, 715331, 747204, 802436, 739587, 761605, 700101, 735812, 798276,
+    707909, 703750, 709252, 765381, 728132, 698947, 708228, 699331, 737604, 743107, 746245, 758340,
+    742595, 742595, 798020, 723909, 797317, 699139, 755076, 715331, 737285, 736324, 709764, 737860,
+    753602, 791108, 757445, 734085, 744133, 741061, 710531, 767684, 703110, 800451, 737028, 700804,
+    767684, 709508, 707205, 726916, 787332, 722628, 715526, 804421, 790852, 764038, 762565, 772678,
+    798531, 746885, 750531, 752837, 755076, 757445, 742595, 714181, 700101, 737028, 738885, 709764,
+    705475, 731525, 709764, 699331, 699331, 741635, 738885, 795973, 771012, 741635, 696515, 699525,
+    744133, 781124, 771525, 714820, 753602, 773509, 748932, 776131, 776131, 699331, 736579, 700804,
+    704388, 767428, 719492, 722628, 757445, 771268, 760453, 734660, 597444, 782852, 782852, 789444,
+    749445, 722628, 755587, 755587, 697283, 734404, 760133, 764038, 698947, 739395, 710531, 781383,
+    739587, 723396, 781124, 721798, 744133, 800004, 813572, 727621, 726403, 781124, 765381, 723396,
+    755076, 744133, 733765, 710339, 799044, 786628, 714820, 742147, 740035, 731525, 771844, 764038,
+    722628, 727621, 745669, 476356, 743300, 702277, 768901, 698115, 725635, 798276, 720516, 767684,
+    767684, 767684, 738116, 771012, 819651, 698310, 731205, 768451, 790151, 769220, 707205, 789444,
+    768196, 705220, 737860, 781124, 726597, 764739, 760133, 728132, 714820, 748739, 697478, 709764,
+    784197, 769220, 731525, 743300, 744133, 761605, 724229, 731843, 712260, 783428, 759557, 697283,
+    697283, 699331, 713541, 721285, 702277, 704132, 775174, 712260, 731205, 703110, 697283, 740547,
+    758917, 737028, 782596, 762565, 724741, 807301, 720516, 726597, 713541, 782852, 737860, 714181,
+    703110, 714820, 714820, 715331, 715331, 709508, 758340, 726916, 775876, 775876, 755587, 721028,
+    750531, 756293, 770052, 734660, 745156, 745156, 775174, 741380, 697283, 730309, 771012, 710021,
+    721285, 712515, 767684, 721028, 753411, 730309, 758340, 763654, 701059, 696515, 722372, 730309,
+    787332, 709508, 758340, 710021, 760133, 741380, 724741, 794244, 795973, 702597, 726148, 726148,
+    740547, 800004, 797060, 723396, 761925, 768451, 776772, 696515, 708228, 797827, 754308, 744837,
+    733508, 772100, 736579, 708228, 704388, 778820, 697860, 720516, 699331, 714820, 732036, 772357,
+    736579, 772357, 775876, 697283, 698115, 772100, 733508, 700101, 712260, 767428, 767428, 704388,
+    733765, 726403, 712260, 753734, 753734, 704132, 710979, 777284, 715076, 703110, 599875, 707909,
+    772357, 772100, 744837, 738885, 774469, 707909, 698947, 710531, 795973, 743300, 696515, 726597,
+    752324, 748355, 737285, 721028, 778501, 778501, 778501, 705475, 782340, 712515, 734085, 740035,
+    716164, 765701, 715331, 703110, 763459, 706628, 705475, 734085, 705862, 737604, 705862, 742789,
+    702597, 697283, 717060, 790852, 821252, 717060, 698947, 701252, 747204, 698947, 782852, 699844,
+    769733, 742789, 781383, 705475, 734085, 706628, 699331, 734085, 704388, 748739, 715331, 742789,
+    715331, 715331, 797317, 732036, 740547, 712963, 712963, 712963, 753602, 701252, 710339, 776772,
+    712963, 710339, 734917, 766916, 752837, 741061, 751942, 697478, 696515, 696515, 766597, 712963,
+    756932, 704388, 777925, 821506, 761028, 712708, 701508, 713347, 699139, 728579, 787139, 728773,
+    710979, 821639, 749764, 730628, 698310, 735493, 724229, 699331, 754115, 741380, 727621, 749188,
+    696515, 739395, 743556, 712515, 730628, 731525, 712260, 727172, 810372, 733508, 799044, 733765,
+    810116, 739780, 738372, 810628, 715331, 731525, 739780, 717060, 699331, 710979, 768451, 715331,
+    701059, 712260, 730115, 753602, 735812, 775557, 703110, 698947, 704388, 753734, 752837, 732613,
+    710531, 736068, 712963, 782340, 721028, 728579, 726148, 750213, 721798, 722628, 709252, 739203,
+    696515, 716164, 822085, 783940, 723652, 776516, 724229, 711812, 783940, 710979, 786884, 728579,
+    768451, 727939, 784197, 743556, 729284, 705862, 630723, 712963, 755780, 701765, 712260, 758917,
+    758340, 728387, 701059, 771844, 734404, 750213, 712708, 733765, 755780, 743107, 739587, 696515,
+    731843, 720516, 701059, 764931, 698115, 698115, 727621, 705667, 700101, 740547, 771012, 722372,
+    717060, 710979, 742789, 708804, 709508, 751045, 725635, 788485, 727427, 710979, 786884, 739780,
+    755332, 732293, 719299, 714501, 731525, 710531, 730628, 728579, 730628, 730628, 768451, 710531,
+    708485, 779332, 775557, 745412, 745412, 731843, 739587, 710531, 810372, 774149, 734085, 608579,
+    744133, 772100, 743813, 726403, 727939, 731525, 745988, 778820, 701252, 706628, 721798, 802180,
+    749445, 749445, 746245, 822406, 781828, 757188, 697028, 720516, 723396, 750531, 737860, 794500,
+    822790, 701252, 719299, 726403, 720516, 778820, 712260, 810372, 726916, 726916, 712260, 700804,
+    748547, 751365, 750213, 705475, 724741, 754115, 702597, 718595, 746565, 773060, 712708, 748739,
+    790852, 744451, 721028, 823176, 710531, 712260, 718021, 705667, 822085, 724547, 723396, 787332,
+    771525, 724741, 721798, 698947, 737860, 738628, 754115, 736579, 730628, 767428, 783684, 726403,
+    795333, 742595, 750213, 703110, 766916, 766916, 785093, 779588, 774149, 710531, 758917, 734085,
+    696515, 725829, 766597, 767428, 709252, 723396, 749188, 706885, 724741, 712515, 754308, 705220,
+    731205, 802436, 737604, 776323, 708485, 783940, 713347, 728579, 741380, 741829, 798020, 759557,
+    727939, 760133, 702083, 721028, 699139, 795973, 699139, 769476, 696515, 761285, 748739, 765124,
+    730628, 745156, 813572, 766277, 734085, 795973, 758084, 469187, 709252, 726148, 758917, 743300,
+    773060, 771012, 733508, 710979, 745412, 710531, 758084, 763459, 736579, 759557, 782340, 757445,
+    773509, 758597, 706628, 776323, 760772, 719299, 788485, 767428, 754883, 763459, 715331, 730309,
+    703110, 741061, 799044, 729284, 761605, 778501, 726916, 777925, 735493, 783940, 731843, 698310,
+    704964, 698947, 786628, 788228, 697283, 755332, 716164, 731525, 732293, 696515, 705667, 721028,
+    721798, 703750, 778820, 696515, 737604, 766597, 721798, 803140, 761028, 758340, 738372, 701765,
+    706628, 742595, 759237, 753156, 704388, 783684, 809860, 710531, 757765, 731525, 797060, 745412,
+    777925, 706628, 748739, 798276, 823685, 460867, 712260, 709508, 791557, 730309, 799044, 707205,
+    758917, 765381, 767428, 734085, 750213, 705220, 734085, 699331, 704388, 782852, 752324, 730885,
+    811844, 701059, 709764, 771268, 809860, 738885, 785413, 742595, 798020, 749188, 701252, 757445,
+    750213, 696515, 792581, 725317, 752837, 733765, 795973, 704388, 824005, 748547, 740035, 705667,
+    763459, 776131, 824326, 698947, 742595, 727427, 727427, 703110, 781124, 705220, 705220, 776323,
+    706628, 737860, 795333, 775174, 795653, 743300, 723396, 765381, 731525, 702083, 749188, 712708,
+    737028, 734404, 469187, 597700, 772100, 736068, 726148, 707909, 705475, 783428, 757445, 795333,
+    724741, 766020, 726148, 744133, 789444, 700101, 720516, 742789, 813572, 813572, 726403, 722372,
+    765701, 740741, 783684, 799044, 776131, 734404, 758084, 712260, 739203, 723396, 734085, 765124,
+    701508, 790596, 730309, 795653, 704645, 728132, 715076, 753602, 758597, 767428, 766597, 724547,
+    706628, 737285, 779332, 739780, 787139, 710339, 786628, 712963, 712260, 744451, 697860, 731525,
+    736068, 705862, 757765, 750213, 753602, 715331, 767428, 746245, 749188, 712708, 705475, 712515,
+    809667, 743300, 724229, 743300, 716164, 745412, 734404, 767172, 765124, 758340, 733315, 707909,
+    766916, 721285, 775174, 781383, 798276, 701765, 706885, 788806, 731843, 697283, 765124, 761285,
+    726597, 737285, 720516, 707205, 748739, 759876, 783428, 757445, 699844, 712963, 712708, 781383,
+    737285, 782084, 738116, 711172, 698115, 770630, 720772, 761925, 712260, 768451, 712260, 798020,
+    769220, 766597, 743556, 716164, 766916, 784197, 724741, 698115, 698115, 711812, 730309, 785093,
+    768901, 770630, 714820, 766020, 731525, 803140, 698947, 761925, 721028, 710724, 710724, 745156,
+    735812, 730309, 805893, 782852, 757188, 696515, 738628, 703110, 703110, 733315, 787332, 725317,
+    702915, 731525, 764038, 787139, 769476, 788485, 708228, 721798, 737860, 808455, 724741, 698947,
+    800004, 709508, 709508, 738372, 726403, 738372, 721028, 756293, 721285, 760772, 710979, 714181,
+    726403, 783684, 708485, 700422, 730628, 728387, 726148, 735812, 788485, 609283, 723396, 748739,
+    724741, 784773, 724547, 741380, 714181, 822085, 734085, 719492, 744133, 767940, 810372, 748739,
+    766597, 708485, 727939, 728387, 797317, 698947, 771525, 766916, 800004, 761605, 710021, 795973,
+    706628, 726148, 750725, 709508, 715331, 705475, 701059, 726403, 768451, 710531, 702083, 3457,
+    791557, 765124, 748355, 698947, 771844, 708485, 758084, 746885, 772100, 801347, 710531, 702083,
+    718595, 702083, 702083, 739587, 712963, 698947, 767172, 743813, 705475, 712260, 706628, 810372,
+    698115, 734085, 723396, 773060, 758340, 745412, 765124, 748739, 803140, 810628, 712260, 779332,
+    733765, 767940, 733315, 716164, 757188, 788485, 730309, 754308, 737604, 710339, 727427, 741380,
+    761925, 748739, 774149, 731525, 731525, 757188, 731525, 772100, 698692, 761925, 779588, 774469,
+    707909, 625860, 784773, 715331, 743300, 759876, 754308, 748355, 710339, 757188, 718021, 758340,
+    824005, 710339, 741061, 744451, 771525, 724741, 721028, 772100, 710339, 716741, 712260, 720772,
+    720772, 697283, 736324, 728387, 722884, 777925, 697028, 704132, 699844, 696515, 749445, 719492,
+    739780, 734917, 721798, 726403, 741061, 743556, 771012, 712515, 739395, 743107, 750531, 756293,
+    748355, 722372, 705475, 731843, 740035, 724741, 771012, 773509, 705220, 750213, 726916, 699331,
+    720516, 768451, 745412, 740035, 724741, 726916, 726597, 798020, 741635, 732934, 741061, 726916,
+    696515, 719492, 698692, 776131, 704388, 735812, 697283, 776516, 756293, 726916, 724741, 710021,
+    733765, 697860, 771012, 716164, 736579, 714181, 736579, 721798, 731843, 750213, 710979, 716164,
+    721285, 740547, 774790, 715331, 724741, 824708, 724741, 708485, 696515, 726597, 741635, 710339,
+    756293, 756293, 736068, 718788, 697283, 697283, 761285, 767172, 721028, 761285, 609028, 756932,
+    739780, 739780, 772100, 699331, 772100, 709764, 776516, 763654, 721285, 717060, 712963, 723396,
+    717060, 699331, 750213, 724741, 763459, 723652, 722372, 728132, 720516, 714820, 710979, 705862,
+    742789, 740547, 708485, 742789, 764421, 708485, 702915, 736324, 708485, 708485, 726148, 708228,
+    708485, 722372, 764421, 708804, 734917, 723396, 792261, 699331, 701252, 735236, 704132, 824964,
+    713861, 697283, 742789, 776772, 825219, 735236, 701059, 777284, 710979, 734917, 738885, 710021,
+    710021, 739587, 754883, 775557, 696515, 777284, 741829, 734085, 825411, 696515, 697283, 712260,
+    770052, 768901, 770052, 742789, 714501, 722884, 699331, 712515, 698947, 698947, 698947, 737604,
+    802947, 709508, 705220, 712260, 768196, 723396, 746565, 738628, 740035, 740035, 740035, 719492,
+    741380, 771844, 731843, 740035, 707909, 701252, 753734, 733765, 701252, 723909, 771012, 771012,
+    792004, 762565, 712515, 722628, 737028, 789188, 727939, 739780, 706628, 802436, 763654, 712963,
+    734917, 752580, 720772, 732934, 738885, 745669, 755076, 734660, 726148, 712963, 696515, 715331,
+    736324, 704388, 727939, 734917, 758340, 727621, 710531, 733765, 757188, 701765, 746885, 761925,
+    738885, 735236, 750213, 732293, 762565, 752580, 779588, 742340, 698115, 752837, 740035, 755076,
+    775557, 757445, 701252, 701252, 699331, 756932, 790852, 776772, 739587, 732934, 736068, 702597,
+    736579, 701765, 789188, 740229, 774149, 730309, 753734, 746565, 732613, 701252, 764038, 764038,
+    738885, 782852, 740035, 724741, 728132, 712260, 728132, 697478, 731205, 730885, 704388, 699844,
+    715526, 825605, 756293, 702083, 722628, 737860, 741380, 741380, 754308, 709252, 730309, 802436,
+    740229, 715331, 703110, 763654, 699139, 711812, 711812, 712515, 741380, 737860, 752580, 773509,
+    709252, 757765, 708485, 789700, 740229, 728387, 710021, 715331, 766597, 696515, 715331, 761605,
+    697283, 710021, 720772, 750213, 696515, 712260, 748547, 709252, 708228, 703750, 732934, 723909,
+    756293, 757188, 825923, 738885, 738885, 696515, 697860, 722628, 737860, 710979, 771012, 782852,
+    743107, 761925, 773315, 709764, 716164, 761605, 776772, 752324, 734085, 762245, 748547, 733765,
+    762565, 734404, 766916, 728132, 745669, 724741, 781124, 790596, 785733, 732934, 697283, 752580,
+    704388, 710339, 736579, 698947, 720772, 733508, 757188, 723396, 752580, 725635, 698115, 702277,
+    739587, 698310, 737860, 755780, 741380, 738885, 707526, 721285, 730309, 709508, 710021, 710724,
+    708228, 792004, 715331, 696515, 698947, 764038, 771844, 702083, 702083, 710339, 758340, 731205,
+    756293, 756932, 726148, 775557, 752580, 696515, 715526, 746885, 824708, 724741, 755780, 710021,
+    826117, 736579, 702083, 718595, 716164, 772357, 738628, 734404, 728387, 710339, 773509, 753734,
+    790852, 628996, 704388, 737604, 748739, 715331, 765701, 774149, 716741, 759876, 718021, 825605,
+    737285, 704388, 722372, 782340, 782340, 740547, 696515, 795333, 702083, 712963, 743107, 716164,
+    800004, 800004, 826439, 769220, 814149, 814149, 742595, 789700, 767684, 740035, 737860, 714820,
+    700804, 734660, 701059, 782340, 704132, 805893, 758917, 739780, 710979, 732293, 756932, 740547,
+    16130, 739203, 746565, 795653, 750213, 754565, 754565, 759557, 745669, 764038, 723909, 792004,
+    775557, 698947, 697283, 761925, 757445, 723909, 731205, 754565, 465603, 772357, 774149, 725635,
+    769733, 709764, 698115, 760772, 712708, 708804, 716164, 464898, 708804, 727172, 728579, 791363,
+    726916, 726916, 758084, 710339, 701508, 698947, 705220, 741635, 736772, 725829, 742147, 719044,
+    826886, 764931, 746245, 731525, 699331, 719492, 712515, 749188, 766597, 738628, 770052, 712515,
+    730115, 710339, 746885, 761028, 781828, 727172, 741829, 730628, 731843, 698310, 791557, 720516,
+    696515, 739395, 749445, 773315, 696515, 697283, 716164, 778501, 746565, 827269, 827589, 827910,
+    747460, 828293, 791557, 733765, 721603, 785413, 702915, 765381, 792261, 756932, 737028, 706628,
+    702597, 790596, 734404, 730309, 722372, 802692, 797635, 716164, 782084, 736324, 700101, 708804,
+    700101, 760133, 739780, 760133, 698947, 782340, 728132, 766020, 698947, 776772, 701059, 741635,
+    723909, 789188, 734917, 734660, 696515, 710979, 752324, 715331, 712963, 705667, 776516, 738628,
+    750213, 734404, 708228, 791108, 753602, 779588, 775557, 798276, 712260, 702277, 722628, 828613,
+    776516, 716164, 768196, 609474, 710339, 828933, 734085, 703110, 829254, 705862, 706885, 752324,
+    756293, 730309, 754115, 713541, 696515, 715331, 712260, 714501, 756293, 717060, 717060, 766020,
+    710979, 698115, 696515, 702083, 702083, 701252, 798020, 741635, 726916, 696515, 732293, 701765,
+    739587, 727427, 743107, 779332, 770052, 706246, 732036, 742147, 733765, 710979, 726403, 748932,
+    719492, 697283, 778244, 811844, 776772, 775557, 760133, 711812, 697860, 704132, 727621, 710531,
+    730628, 742340, 752324, 743813, 744133, 731843, 712260, 712708, 749188, 709508, 739395, 728387,
+    705220, 768451, 710979, 734085, 726403, 726916, 776131, 711172, 708804, 741635, 777284, 751045,
+    712708, 702915, 708804, 735236, 701252, 714501, 749188, 734404, 721028, 705220, 701252, 712260,
+    777284, 779588, 829636, 765701, 712708, 647171, 756613, 742789, 706628, 774149, 702597, 748739,
+    829894, 710339, 772100, 744133, 716164, 738628, 822406, 697283, 750213, 697283, 710339, 696515,
+    715526, 750213, 710339, 755587, 778820, 747204, 701765, 727172, 756293, 726403, 727172, 701765,
+    730115, 700101, 696515, 706628, 740035, 708804, 734085, 789700, 722179, 722179, 704132, 713155,
+    749445, 697028, 745156, 742789, 710339, 714820, 733765, 743107, 723140, 712260, 720516, 705475,
+    749764, 718021, 710979, 706628, 792261, 720772, 786628, 747974, 709764, 753734, 718595, 748547,
+    748547, 759557, 696515, 708485, 754115, 712260, 772678, 731205, 704645, 704645, 795973, 706628,
+    705220, 718788, 726916, 804421, 699331, 757188, 830278, 761925, 830661, 592195, 742789, 705667,
+    787332, 750213, 771525, 697283, 714181, 782084, 750725, 770630, 808455, 738628, 766916, 719044,
+    750213, 737028, 725829, 778820, 710021, 726403, 760772, 710979, 791108, 731205, 721028, 715331,
+    754565, 777284, 706885, 697283, 736068, 754308, 719492, 753411, 703750, 701508, 725829, 704132,
+    779588, 766277, 774149, 758917, 808455, 731843, 738885, 776323, 768196, 768451, 739395, 741635,
+    731843, 741380, 730115, 712260, 727172, 737028, 795333, 734085, 795973, 709252, 767428, 717060,
+    746565, 698947, 756293, 748739, 795973, 741829, 805316, 734660, 760133, 772357, 712515, 710339,
+    710339, 776516, 805316, 761925, 766916, 710531, 742595, 706628, 802436, 708485, 716164, 707909,
+    770052, 709764, 710021, 710339, 701765, 830983, 831432, 786628, 712260, 747460, 726597, 734085,
+    708485, 732293, 710339, 697283, 701765, 779845, 702277, 731525, 699525, 697283, 697283, 761028,
+    704964, 707909, 819651, 756932, 755332, 766277, 783940, 755076, 757445, 703110, 768196, 698947,
+    710339, 758917, 699331, 699331, 739395, 755076, 702597, 737285, 778501, 723909, 731843, 715331,
+    770052, 703110, 742789, 697283, 765381, 704388, 699331, 746245, 696515, 824708, 727172, 758597,
+    760453, 697283, 708485, 710979, 776131, 747460, 809860, 809860, 708485, 707205, 734660, 734660,
+    773509, 772678, 798020, 709252, 798276, 748739, 752580, 706628, 696515, 700101, 710531, 710531,
+    703110, 742340, 703750, 705862, 712260, 804421, 705667, 710531, 737028, 724741, 708485, 730115,
+    767172, 758917, 696709, 722372, 732934, 831943, 832391, 734085, 720772, 715331, 773315, 731525,
+    831943, 832837, 833158, 759876, 712260, 797060, 833542, 833925, 655876, 758917, 742789, 17282,
+    730628, 824708, 726916, 702083, 784516, 709764, 709764, 764421, 717060, 757445, 734085, 696515,
+    727621, 715331, 704388, 704388, 707526, 742789, 762565, 699331, 743107, 712708, 763459, 763141,
+    741635, 734404, 737604, 740035, 762884, 733765, 727427, 704388, 760772, 755076, 782852, 706628,
+    782852, 702915, 706885, 723396, 730628, 712260, 734085, 725635, 776772, 722372, 712963, 712963,
+    726597, 760453, 725829, 752324, 699331, 704132, 779588, 760133, 738885, 738885, 761925, 750213,
+    763141, 702083, 698947, 706628, 702083, 722372, 792581, 699331, 768644, 709764, 779588, 731843,
+    698947, 706628, 781124, 701508, 761925, 811844, 725317, 783428, 713347, 834246, 834632, 794500,
+    835141, 699844, 758340, 772100, 722628, 699331, 773509, 748739, 710531, 835463, 611587, 701059,
+    723396, 717060, 696515, 742789, 710979, 711172, 748355, 754565, 765701, 739395, 734085, 710531,
+    724547, 734404, 750213, 766277, 719044, 765381, 730115, 745669, 755587, 717060, 734404, 706628,
+    764038, 719492, 756932, 712708, 731525, 721603, 813572, 765381, 723396, 765701, 701252, 712260,
+    738116, 722628, 725829, 777284, 765701, 737285, 789444, 761285, 702915, 763654, 724547, 776131,
+    715331, 739395, 697283, 697028, 790596, 813572, 779332, 786628, 705475, 738628, 741061, 796804,
+    698947, 795653, 766020, 758597, 705862, 749188, 731525, 744837, 779588, 740035, 778501, 790596,
+    708485, 730628, 835910, 733765, 741061, 717060, 734085, 789700, 836294, 836676, 767172, 708804,
+    675012, 715331, 697283, 726597, 737860, 744133, 731525, 798276, 699844, 699844, 784197, 782084,
+    753411, 824708, 738116, 740741, 704132, 712963, 770630, 712963, 795973, 782852, 696515, 798020,
+    790596, 724229, 720772, 720772, 800004, 769220, 728132, 731843, 808455, 713347, 754565, 773509,
+    702277, 710339, 736772, 761605, 790151, 770052, 731205, 707205, 698947, 766916, 767940, 748739,
+    703750, 748355, 783428, 732613, 755587, 776131, 716164, 737285, 756613, 748739, 705220, 716164,
+    712708, 716164, 698310, 733508, 698115, 812357, 733765, 746565, 758917, 708804, 719044, 715331,
+    746245, 725317, 734085, 702277, 460418, 836935, 705862, 837381, 761925, 722179, 736324, 712260,
+    714181, 789700, 702277, 728387, 716164, 782084, 782852, 750213, 769476, 782340, 742789, 715331,
+    738628, 741061, 713155, 719492, 698115, 712515, 714181, 787332, 708228, 777284, 714181, 730309,
+    837700, 770052, 742789, 730628, 745156, 722628, 792581, 753156, 703110, 737285, 772357, 710531,
+    703110, 755587, 721028, 721028, 758340, 737860, 698947, 767428, 715526, 730628, 743813, 791108,
+    708804, 781383, 807301, 734660, 772678, 778244, 725317, 805316, 739395, 777284, 745156, 790596,
+    722372, 837957, 838277, 801347, 698692, 698947, 776516, 777925, 748739, 466628, 611587, 765381,
+    724547, 716164, 770630, 758340, 708228, 751365, 714820, 715331, 722884, 766597, 789700, 738885,
+    703110, 719492, 710531, 789700, 696515, 726148, 728387, 704388, 767940, 706885, 737860, 771525,
+    698947, 722884, 726403, 741635, 696709, 722372, 822085, 750725, 698947, 706628, 717060, 761605,
+    711812, 734085, 705862, 758084, 696515, 756613, 760133, 743300, 708228, 831943, 749188, 772357,
+    714820, 786628, 771844, 699525, 697283, 838597, 767172, 737604, 698947, 731525, 748355, 743813,
+    696515, 790596, 734085, 714820, 722179, 761605, 704388, 697283, 748355, 775557, 720772, 762565,
+    698947, 782852, 791363, 769733, 722372, 476994, 699525, 704132, 712260, 747974, 731525, 767940,
+    767940, 733315, 748739, 710339, 784773, 748739, 715076, 737860, 764739, 748739, 706628, 790596,
+    773315, 765381, 710339, 756932, 708485, 765124, 726597, 838917, 722372, 468932, 708804, 748739,
+    704388, 710339, 708804, 773509, 706628, 753602, 755587, 744837, 798020, 699331, 774149, 747460,
+    466884, 730309, 839236, 710531, 756293, 732293, 761925, 734085, 714820, 704132, 761925, 715526,
+    751365, 726403, 707909, 772100, 710531, 790151, 743300, 709764, 702277, 746245, 740547, 729797,
+    465987, 699331, 759876, 710339, 757188, 718021, 771525, 721028, 839491, 772100, 772100, 708804,
+    767940, 710339, 728579, 716741, 699331, 732293, 839686, 731525, 742147, 795973, 736324, 699331,
+    699331, 731525, 764739, 740035, 732613, 722372, 720516, 781124, 696515, 721028, 715331, 740035,
+    697860, 699331, 750725, 754565, 813829, 795653, 715331, 736579, 696515, 697283, 712963, 720516,
+    726148, 813829, 747204, 813829, 747204, 707526, 719044, 778820, 714820, 736324, 723396, 699331,
+    731525, 756293, 696515, 705475, 712515, 758340, 728132, 764739, 712963, 742595, 795973, 740035,
+    793092, 715331, 756293, 740035, 715331, 824708, 726597, 722372, 696515, 747204, 699331, 797317,
+    795973, 712260, 721798, 742147, 699139, 712515, 697283, 699139, 699139, 794244, 710531, 731525,
+    720516, 797827, 720516, 797827, 706246, 722884, 722884, 710339, 783684, 699331, 710339, 720772,
+    732036, 732036, 702915, 734085, 711812, 698115, 698115, 734404, 698115, 753411, 733765, 757765,
+    763654, 732036, 697478, 740035, 738628, 716164, 767684, 721028, 768901, 788228, 701508, 755587,
+    761605, 722628, 726597, 696515, 784516, 712260, 702083, 767684, 755076, 709508, 698947, 702083,
+    750213, 722628, 736068, 700101, 767172, 742595, 728132, 697478, 714820, 735493, 753411, 737860,
+    784773, 696515, 709508, 773315, 770630, 726597, 767172, 773315, 840072, 734085, 716164, 764739,
+    764421, 708804, 714820, 761605, 696515, 719044, 789444, 776131, 734404, 754308, 741061, 737860,
+    737860, 739395, 789444, 698947, 696515, 764739, 737860, 764421, 696515, 756293, 712515, 707205,
+    725635, 779588, 779588, 736579, 742340, 773315, 699331, 741635, 741635, 741635, 701059, 701059,
+    741635, 707909, 776772, 626307, 605059, 728387, 823176, 717060, 742789, 771268, 725635, 730628,
+    738628, 722179, 765701, 730309, 710531, 725635, 752580, 701508, 776516, 823176, 758340, 735236,
+    710531, 726916, 738116, 741635, 698692, 648771, 776772, 722884, 699331, 699331, 730628, 743107,
+    734085, 724547, 698692, 748739, 725635, 710339, 731205, 765701, 732934, 728387, 759876, 742789,
+    799044, 742789, 772357, 759876, 740547, 790852, 701252, 731843, 771012, 721028, 791557, 697283,
+    710724, 711172, 697283, 758084, 730309, 707526, 734660, 802947, 777028, 642115, 710979, 727621,
+    702597, 761605, 697283, 714820, 715331, 725060, 733765, 748547, 705667, 776131, 761605, 705667,
+    702597, 709508, 748739, 761605, 733765, 722628, 722628, 811269, 698692, 721798, 701765, 744133,
+    771525, 840586, 713347, 739587, 792004, 770052, 720772, 725829, 712260, 701508, 767172, 727939,
+    699525, 784773, 737860, 735493, 696515, 749188, 708485, 707526, 709252, 770052, 732036, 715331,
+    730628, 791557, 701508, 697283, 766597, 755332, 741380, 731525, 712515, 712515, 738628, 702915,
+    718595, 749445, 770630, 720516, 712515, 841223, 465987, 737285, 712260, 720516, 731525, 748355,
+    699331, 723909, 761605, 710021, 791108, 754308, 730885, 771844, 746245, 698947, 752837, 752837,
+    719492, 736068, 706246, 734917, 774790, 721028, 792261, 841669, 697283, 734660, 740035, 740035,
+    726148, 696515, 763459, 722628, 765381, 792261, 704388, 773509, 773509, 709508, 704132, 735812,
+    712963, 737604, 700804, 725635, 811844, 779588, 724547, 699525, 712515, 775174, 757765, 741635,
+    719044, 712260, 735236, 779332, 804421, 764931, 766020, 777284, 779588, 727939, 706885, 764739,
+    710339, 729284, 729797, 706885, 709764, 712963, 698115, 779588, 716164, 710531, 757188, 730628,
+    730628, 730628, 743107, 710339, 748355, 696515, 713155, 756932, 704132, 757765, 712260, 775557,
+    739395, 742789, 704388, 702915, 753734, 751942, 710531, 723396, 701508, 696515, 717060, 706246,
+    746245, 726403, 737860, 748355, 760772, 708485, 722372, 713347, 704132, 739395, 730885, 719492,
+    713155, 776772, 702915, 712963, 713541, 742147, 770052, 720516, 755587, 743813, 703110, 732293,
+    786884, 726403, 697283, 702915, 739587, 710531, 782340, 708804, 763654, 767940, 738885, 718788,
+    710979, 779332, 771012, 771844, 733765, 698947, 744133, 707205, 740741, 718788, 774149, 748739,
+    591171, 726403, 723396, 791363, 727939, 710021, 714820, 740229, 698947, 696515, 737860, 737860,
+    789700, 703110, 714820, 709764, 751684, 734085, 733765, 773060, 702915, 698947, 722179, 746885,
+    710979, 733315, 766916, 712260, 714820, 712515, 715331, 697028, 708804, 770630, 759876, 599043,
+    748547, 702597, 696515, 748932, 767940, 696515, 748355, 746245, 728132, 698115, 709764, 756293,
+    723396, 730885, 740035, 795653, 753734, 786628, 700804, 710531, 722179, 740229, 718595, 713541,
+    738628, 774790, 712260, 747974, 795973, 745669, 737604, 744451, 788228, 755332, 712515, 740547,
+    777284, 696515, 717060, 710021, 731525, 770630, 724741, 703110, 701252, 708485, 750531, 841988,
+    750725, 698947, 777284, 707909, 755076, 712963, 751365, 738628, 728132, 773060, 701508, 709764,
+    711812, 754308, 741380, 766277, 791557, 708485, 704132, 715331, 726403, 776516, 709252, 730885,
+    696515, 728387, 761925, 772100, 752580, 754565, 741380, 710339, 795333, 710979, 712515, 726597,
+    762565, 706885, 696515, 699331, 731205, 745156, 725060, 728132, 717060, 775174, 785733, 779588,
+    722884, 703110, 735236, 730309, 727621, 776323, 802436, 794500, 798020, 741380, 710021, 756293,
+    743300, 788485, 776323, 744133, 756293, 730309, 725829, 753602, 755587, 792004, 759557, 805316,
+    742595, 710339, 811269, 702915, 767940, 811269, 786628, 773315, 745156, 747460, 726597, 709764,
+    763459, 722628, 795973, 731843, 613316, 837700, 734085, 698310, 797827, 710979, 707205, 746885,
+    777925, 822406, 705667, 797317, 698310, 702083, 759237, 763459, 741380, 730628, 702597, 743107,
+    763459, 710531, 719492, 703750, 755076, 724547, 708485, 741061, 700804, 800451, 759557, 730115,
+    715331, 734404, 697283, 739395, 798276, 741380, 708228, 737285, 740229, 758917, 752580, 732934,
+    716164, 697283, 734917, 720516, 748739, 787332, 710531, 786628, 702277, 767940, 712260, 758917,
+    756932, 760133, 757765, 701059, 709508, 699331, 739587, 699331, 748739, 719492, 748355, 710021,
+    732293, 729284, 714820, 738116, 740547, 744837, 699331, 788485, 727621, 768901, 762565, 744451,
+    765381, 747460, 755076, 761605, 704388, 731525, 757765, 785413, 712260, 714820, 748355, 760772,
+    765381, 736068, 798531, 756932, 761605, 765381, 776131, 698947, 743300, 765381, 842247, 748739,
+    730115, 773315, 17282, 734085, 771012, 701059, 842693, 699331, 765381, 727621, 727621, 811844,
+    795973, 708804, 734085, 762245, 699331, 701508, 725829, 740547, 773315, 706885, 712708, 726403,
+    763459, 763459, 731843, 709764, 736068, 718595, 723396, 750531, 709764, 705475, 778501, 704388,
+    730885, 738885, 748355, 776131, 776772, 761925, 704388, 704132, 730885, 785413, 746885, 779588,
+    749764, 755076, 711172, 782852, 701252, 753602, 755332, 779588, 726597, 697283, 697283, 779588,
+    757765, 768644, 709764, 767684, 784197, 699525, 737860, 698115, 740035, 698947, 793092, 734085,
+    734085, 712963, 706885, 715526, 696515, 714820, 742789, 701252, 706628, 703110, 764038, 758340,
+    699331, 741635, 771012, 701252, 776323, 763654, 744133, 740547, 706246, 843013, 705220, 724741,
+    843333, 714820, 737285, 774149, 737604, 765381, 752324, 739780, 704132, 746245, 758597, 703750,
+    707909, 704388, 762565, 798020, 730115, 709252, 701252, 769220, 761925, 843654, 782852, 469508,
+    734085, 740035, 748739, 720772, 699844, 479938, 597444, 791363, 728132, 720772, 737285, 765381,
+    766916, 765381, 726597, 734404, 772357, 779845, 763654, 776131, 771844, 787139, 744451, 777284,
+    766020, 799044, 745669, 712708, 701252, 696515, 729284, 721603, 728387, 709764, 710339, 761028,
+    712515, 715331, 736068, 705862, 727939, 701252, 755587, 696515, 750213, 765124, 722628, 736772,
+    714820, 765701, 755076, 755587, 755587, 739587, 724547, 701252, 718595, 709764, 779332, 722372,
+    722372, 713861, 785733, 729797, 715076, 743107, 741061, 752580, 764038, 715331, 730115, 731843,
+    712260, 761925, 710979, 706628, 782084, 705475, 730115, 786628, 773829, 722884, 741635, 724741,
+    737860, 704964, 714820, 790596, 774149, 712260, 704388, 743300, 769220, 761925, 762565, 748739,
+    772100, 718021, 461571, 767172, 758340, 699331, 765124, 712963, 741635, 756613, 712260, 770630,
+    743556, 727621, 697478, 731843, 748355, 768451, 748739, 712963, 712963, 704132, 707909, 770052,
+    744133, 704964, 769220, 761605, 699331, 768901, 698947, 705667, 724741, 697283, 715331, 698947,
+    748739, 748739, 774149, 728132, 798276, 702277, 783428, 712260, 699844, 705862, 710339, 704388,
+    800004, 724741, 702277, 802692, 784197, 764739, 773829, 702083, 715331, 748355, 737285, 698115,
+    697478, 741380, 726597, 772100, 744451, 710339, 731525, 706885, 738885, 696515, 769220, 730115,
+    791557, 724229, 767940, 758917, 716164, 698310, 698310, 775174, 740035, 795973, 810372, 734085,
+    704388, 781383, 721285, 738116, 742595, 733765, 770630, 702277, 761925, 730885, 738628, 716164,
+    707205, 761925, 756293, 628228, 646595, 740035, 726597, 712260, 709764, 701059, 721028, 724741,
+    791363, 771525, 737860, 706628, 712515, 797635, 698947, 779332, 720772, 756293, 731525, 731525,
+    715331, 748739, 715331, 738885, 735812, 764038, 771012, 837700, 755587, 721285, 750213, 706246,
+    696515, 731843, 709252, 709508, 758340, 732613, 738628, 782852, 844036, 741829, 776323, 708804,
+    698692, 818180, 741635, 766597, 707909, 707909, 716164, 731205, 728387, 708228, 709764, 797827,
+    797827, 703110, 751942, 703110, 782084, 724741, 745156, 738372, 719492, 781828, 725317, 702915,
+    716164, 758917, 741380, 722628, 777284, 730885, 710979, 752580, 743107, 730309, 734085, 766597,
+    715331, 844292, 470658, 772100, 637892, 699331, 708228, 771012, 768196, 771525, 709252, 727939,
+    723396, 699331, 710339, 701252, 718788, 732293, 769220, 730115, 744133, 750725, 767684, 726597,
+    741061, 702083, 768451, 795973, 750213, 707909, 769220, 709508, 709508, 696515, 722884, 701059,
+    710531, 724547, 726148, 698947, 708485, 726597, 751365, 798276, 728132, 734660, 797827, 698947,
+    722884, 737860, 722372, 724547, 760133, 696709, 481666, 708804, 747460, 767172, 726597, 748355,
+    701252, 716164, 748355, 743813, 761925, 761925, 772357, 728132, 697283, 701059, 698947, 752580,
+    718595, 789700, 763459, 786628, 714820, 714820, 775876, 742147, 737604, 710021, 772100, 754308,
+    736579, 728387, 708485, 709764, 767172, 739395, 731525, 746885, 844549, 810372, 756932, 668484,
+    844869, 734085, 709764, 759876, 757188, 748739, 710339, 704132, 733765, 748739, 702915, 706628,
+    761925, 773315, 723909, 737860, 723396, 779332, 727621, 756613, 707909, 698115, 777284, 734085,
+    728387, 797827, 703110, 739395, 767172, 737604, 730309, 748739, 726148, 741380, 761925, 744133,
+    710339, 710021, 756293, 761925, 722884, 709764, 723396, 744837, 752580, 708804, 774149, 747460,
+    775876, 770052, 712963, 747460, 477763, 620228, 761925, 748355, 698947, 726597, 734085, 761925,
+    734660, 708228, 696515, 734085, 782340, 707909, 732036, 774469, 734085, 772100, 770052, 790151,
+    765381, 708485, 706885, 743300, 767940, 772100, 702277, 740547, 744133, 731525, 713155, 707205,
+    789444, 704388, 752580, 845190, 733315, 757188, 718021, 704388, 710339, 748355, 766597, 758340,
+    771525, 724741, 707909, 597444, 752580, 728579, 708804, 772100, 772100, 716741, 709252, 718021,
+    738628, 730309, 704388, 719492, 704388, 699331, 737604, 702915, 715076, 747974, 769733, 737860,
+    756293, 756293, 712963, 730885, 732293, 768196, 700422, 705220, 712708, 723396, 720516, 708485,
+    749764, 749764, 767684, 733765, 697283, 706246, 734660, 810116, 751942, 736068, 791363, 696515,
+    748739, 758597, 701059, 781124, 779332, 704388, 781124, 713861, 725635, 732613, 721285, 720772,
+    724229, 792004, 747974, 736324, 712260, 747974, 747974, 709764, 705862, 482820, 787139, 697860,
+    721798, 708804, 776131, 775557, 710979, 728579, 726916, 726916, 732293, 708485, 738885, 710531,
+    758340, 786884, 751942, 786884, 771844, 771844, 701765, 733765, 731205, 741380, 708804, 712708,
+    741061, 709764, 701252, 726597, 720516, 764038, 481412, 712963, 795653, 772100, 710021, 724741,
+    740035, 746885, 746245, 773060, 795973, 733765, 756293, 736772, 730309, 714820, 712515, 740229,
+    734404, 701252, 709764, 731205, 718788, 749764, 781124, 730885, 759876, 697860, 708804, 738885,
+    721798, 791363, 704388, 737860, 747974, 711812, 737860, 697028, 445890, 770052, 741829, 702083,
+    737860, 845573, 737285, 728132, 798531, 651203, 715331, 710979, 751942, 738372, 728387, 710021,
+    708485, 725829, 709252, 725060, 758917, 754565, 766597, 712963, 706885, 720516, 765701, 712963,
+    715331, 716164, 743107, 738628, 754115, 721028, 756293, 715331, 704388, 741380, 808455, 754308,
+    731843, 723396, 698947, 712515, 467139, 470658, 741380, 704388, 795973, 733508, 710531, 700101,
+    737028, 734660, 708228, 802436, 755076, 792004, 715331, 738116, 712515, 730885, 710531, 710979,
+    763459, 724741, 702277, 706885, 713541, 772678, 762884, 715331, 772678, 736324, 708485, 704388,
+    704388, 696515, 721028, 699331, 778501, 778244, 701059, 739395, 623556, 763654, 732934, 741829,
+    591877, 636484, 655876, 469187, 773509, 743107, 782852, 706246, 764421, 697860, 737860, 706885,
+    726597, 738885, 746885, 764038, 724741, 734085, 741635, 761925, 708804, 709764, 784197, 757765,
+    715331, 762245, 762245, 707909, 704388, 751942, 751942, 699331, 779588, 719044, 709764, 702083,
+    736324, 772678, 811844, 779588, 795973, 740035, 775557, 709764, 770052, 701508, 722884, 733765,
+    773060, 708804, 706628, 790596, 778820, 755780, 712963, 722628, 697860, 462979, 605253, 752837,
+    766020, 753156, 737285, 732613, 766597, 845890, 726403, 764038, 723396, 715331, 712515, 715331,
+    722628, 743107, 743107, 715331, 722628, 774790, 711172, 766597, 772678, 734404, 738628, 748355,
+    726597, 761925, 736068, 765701, 846024, 467139, 705862, 777284, 715331, 741635, 756613, 757445,
+    748739, 734085, 777925, 696515, 783428, 706885, 722884, 696515, 731205, 721028, 757445, 722628,
+    712963, 696515, 712260, 768451, 709764, 721285, 741380, 764739, 798531, 751942, 769220, 646595,
+    697478, 784773, 807301, 734085, 715331, 715331, 715331, 696515, 756293, 720516, 764038, 707909,
+    702083, 731525, 778244, 704388, 730309, 714181, 769476, 769733, 735812, 727621, 724741, 734660,
+    702915, 721285, 699331, 708228, 731205, 741829, 734660, 770052, 704388, 738628, 772100, 779588,
+    737285, 696515, 710021, 733508, 603780, 466884, 761925, 699331, 696515, 718788, 772100, 771844,
+    768196, 767684, 722884, 715331, 789444, 702277, 726597, 737860, 708804, 723396, 732293, 709764,
+    696515, 723396, 775557, 737860, 846534, 846917, 772100, 718595, 730309, 697028, 738628, 728132,
+    700101, 700101, 734404, 743107, 772100, 756613, 765701, 710339, 698115, 755780, 762884, 748739,
+    727427, 768451, 738628, 753734, 795653, 628996, 710339, 748739, 721028, 721285, 704388, 715331,
+    723396, 774149, 702083, 616515, 774469, 782340, 772100, 702277, 721028, 731205, 707205, 445890,
+    738372, 748355, 737285, 772100, 708804, 598595, 732293, 784773, 699331, 822085, 847245, 797635,
+    721798, 786884, 703110, 765381, 736579, 709764, 459715, 721028, 721028, 710979, 702083, 728579,
+    790852, 702083, 734404, 754883, 790151, 722179, 705862, 781383, 705475, 787589, 790852, 705862,
+    744133, 705862, 763141, 739780, 779332, 741829, 734085, 755332, 773315, 781383, 723652, 712708,
+    752837, 790852, 766277, 726403, 781383, 704132, 474051, 773315, 705667, 703110, 810116, 848072,
+    794500, 705220, 748932, 730628, 725635, 745156, 727172, 791557, 734917, 776772, 725635, 778501,
+    712515, 704388, 705475, 737028, 702915, 734085, 739395, 704132, 705475, 763654, 725635, 731843,
+    731525, 746245, 746245, 730115, 819651, 715331, 791557, 785093, 731525, 712515, 739395, 715331,
+    710339, 710339, 783940, 698947, 778501, 712260, 760772, 746245, 837700, 773315, 710531, 717060,
+    734085, 706885, 763141, 776131, 710531, 697028, 722372, 715331, 737028, 759876, 706628, 721603,
+    698692, 709764, 710339, 735812, 731205, 630212, 770630, 848583, 710339, 773315, 773060, 701059,
+    768901, 755780, 715331, 700101, 709764, 782852, 700101, 746245, 770052, 728579, 770052, 701508,
+    698947, 761028, 797317, 748547, 706628, 808455, 747974, 721603, 704132, 724229, 726403, 751684,
+    705667, 714820, 795973, 808455, 722628, 753734, 810116, 731843, 761605, 706246, 725635, 737028,
+    701059, 710531, 739587, 763654, 786884, 697860, 730628, 712963, 732293, 756932, 701059, 739587,
+    768451, 704132, 766020, 782340, 710531, 709508, 791363, 739395, 730309, 743813, 795973, 745988,
+    724741, 743107, 775557, 698947, 738628, 766597, 724547, 765701, 777925, 731205, 745156, 773315,
+    697860, 701252, 782084, 721798, 699331, 813572, 699331, 741635, 791557, 715331, 759557, 710339,
+    697860, 714820, 787909, 754565, 737028, 712260, 704388, 719044, 710339, 741380, 730309, 710021,
+    755332, 756932, 734404, 728579, 697283, 739587, 756613, 714820, 747460, 715331, 734085, 705862,
+    802436, 704388, 798276, 757445, 748355, 732934, 696515, 712260, 721798, 705667, 772357, 761028,
+    753411, 743300, 849029, 805316, 759876, 712963, 757445, 698947, 709764, 706885, 763459, 765381,
+    746885, 708804, 776772, 709764, 765381, 730309, 784197, 705667, 787332, 723396, 717060, 751684,
+    752837, 704132, 704132, 779588, 712708, 734404, 813572, 726403, 745669, 782084, 765381, 765124,
+    721603, 765381, 732036, 699331, 701252, 752580, 463877, 696515, 750213, 781383, 752580, 769220,
+    710021, 770630, 725635, 698310, 707909, 768451, 768451, 752580, 738116, 734660, 721028, 741635,
+    741635, 764038, 728387, 708228, 699331, 731205, 734404, 721285, 795653, 756293, 849348, 849604,
+    715331, 705475, 759557, 773315, 737860, 750725, 730309, 723396, 724547, 730309, 738628, 743813,
+    794500, 737604, 796804, 772357, 773060, 787332, 747974, 777925, 748739, 724547, 730309, 748355,
+    774469, 795973, 787332, 759876, 730309, 697283, 721028, 738885, 785413, 730115, 748739, 748739,
+    699331, 755587, 723909, 701508, 800259, 696709, 713347, 728579, 698947, 730885, 731843, 746245,
+    719492, 705220, 699331, 698947, 699331, 733765, 721603, 749445, 712515, 710979, 736068, 706628,
+    705667, 734660, 752837, 740741, 782340, 721028, 722628, 721028, 709252, 734085, 779588, 709508,
+    701059, 709764, 795973, 730309, 705862, 732293, 732036, 696515, 748547, 713541, 741061, 710979,
+    738885, 715331, 726403, 742147, 766020, 717060, 741829, 791363, 742147, 776131, 778244, 763654,
+    727621, 696515, 754883, 717060, 771012, 766597, 777284, 741635, 750213, 750213, 721028, 713155,
+    774149, 728132, 724741, 734085, 710339, 747974, 730309, 740229, 749445, 704388, 736772, 733765,
+    740035, 759876, 755587, 708804, 726148, 797060, 738628, 755780, 722179, 715526, 734404, 697860,
+    725317, 720772, 775174, 740035, 739780, 735236, 465603, 771525, 849859, 755076, 736068, 753602,
+    703110, 742595, 712515, 712515, 703110, 754308, 779588, 737028, 725829, 730309, 797317, 741380,
+    738885, 704388, 701508, 746245, 754565, 710339, 736772, 710979, 777284, 702083, 715331, 732293,
+    710339, 728132, 726597, 766916, 850056, 469508, 709252, 741061, 794500, 742789, 738116, 771012,
+    699331, 710531, 773509, 747460, 736068, 763654, 709764, 741061, 722372, 708485, 735236, 703110,
+    712963, 697283, 753602, 802436, 732934, 697283, 740741, 709252, 748739, 702277, 707205, 704388,
+    735493, 737028, 708228, 726148, 701059, 797635, 789444, 708485, 726148, 713155, 468099, 772678,
+    773315, 706628, 755076, 699331, 776772, 779588, 762565, 738885, 742595, 746885, 732036, 811844,
+    771012, 750213, 709764, 805893, 795973, 706628, 707526, 734085, 700422, 733765, 726597, 746245,
+    697860, 764421, 765381, 702083, 787332, 712963, 761925, 699331, 764038, 791108, 797827, 777284,
+    701252, 765701, 766916, 734404, 736068, 712515, 728132, 728132, 761925, 721603, 786628, 777284,
+    737285, 710339, 754565, 720772, 750213, 705862, 783684, 721028, 775557, 734404, 736579, 767172,
+    776323, 732934, 802436, 700422, 702277, 728132, 696515, 738116, 702277, 697283, 710339, 761925,
+    766916, 701508, 738885, 768451, 726597, 769220, 698310, 714820, 469378, 761925, 712260, 748739,
+    712515, 769733, 850565, 807301, 738628, 740547, 771525, 708228, 721285, 772678, 755587, 764038,
+    720772, 728387, 710021, 770052, 728132, 777284, 698947, 599875, 710021, 736579, 741635, 767684,
+    740547, 768196, 771525, 724741, 704964, 758340, 737860, 795973, 797827, 722884, 757765, 611587,
+    699331, 728132, 748739, 767172, 777925, 736068, 789700, 772100, 715331, 698947, 738885, 805893,
+    737604, 699844, 767940, 773315, 710339, 712260, 811844, 710339, 757188, 772678, 711812, 720772,
+    722884, 727427, 774149, 748739, 710339, 757188, 772100, 761925, 748355, 715526, 715331, 707205,
+    758084, 757188, 795973, 778820, 726403, 790852, 755780, 712963, 712260, 768901, 739780, 774469,
+    710339, 744451, 850891, 770630, 731525, 770052, 793092, 734917, 709508, 785733, 764739, 851596,
+    852363, 853061, 712963, 732293, 696515, 713541, 742147, 853387, 759557, 732934, 720516, 854087,
+    771525, 772357, 783940, 804421, 713541, 752837, 740229, 750213, 854539, 742147, 761925, 697283,
+    730309, 783428, 731843, 764739, 772357, 700422, 758917, 708228, 807301, 712515, 701059, 709508,
+    785733, 761925, 704388, 719492, 732036, 727621, 757765, 757765, 741380, 732934, 763654, 761605,
+    763654, 707909, 707909, 791108, 763654, 755587, 824708, 744133, 788806, 748739, 710979, 744133,
+    740741, 706885, 743813, 757765, 704132, 717060, 727621, 712708, 741061, 710979, 752324, 741061,
+    713541, 725829, 782084, 725829, 746245, 730115, 772357, 741635, 697283, 777284, 738628, 777284,
+    732934, 725635, 722628, 770052, 710979, 795333, 757765, 706628, 706628, 746245, 709764, 710979,
+    757765, 725635, 855236, 779332, 770630, 744451, 787139, 752324, 777284, 760772, 750213, 722372,
+    725635, 725635, 750531, 805316, 699844, 741635, 787139, 712260, 803140, 697283, 803140, 779332,
+    855493, 770052, 782084, 699331, 699331, 725635, 754308, 696515, 777284, 699331, 790852, 700101,
+    782084, 771525, 797317, 698947, 709252, 770630, 763654, 732934, 784197, 767940, 698947, 749188,
+    777284, 855815, 784197, 708228, 770630, 770630, 700101, 726403, 790852, 790852, 701059, 798020,
+    709508, 716164, 716164, 712260, 696515, 781383, 711172, 696709, 741635, 706628, 721285, 749445,
+    705475, 705220, 740229, 697860, 766597, 740035, 856260, 710339, 733765, 773509, 704132, 728579,
+    706628, 721028, 712260, 697860, 738116, 741635, 696515, 710339, 757445, 781383, 706885, 708804,
+    786884, 701059, 719492, 776131, 742147, 723396, 697283, 709508, 750213, 713155, 712260, 773829,
+    710531, 698947, 756932, 740547, 741061, 717060, 730309, 749188, 701765, 739587, 721798, 744133,
+    710021, 746245, 775174, 731205, 696515, 781124, 709764, 737860, 726916, 733765, 720516, 712260,
+    745988, 705667, 779588, 776516, 710021, 756293, 731205, 776323, 791557, 701059, 756293, 787909,
+    728387, 710339, 712260, 783684, 726916, 712515, 719044, 745156, 777925, 795973, 730309, 696515,
+    758917, 698310, 707205, 758340, 756932, 721603, 708228, 720516, 698947, 741061, 748547, 720516,
+    741635, 737028, 771012, 739395, 775876, 753602, 709764, 699331, 738116, 705667, 708804, 753411,
+    709508, 727621, 856518, 698947, 699331, 751684, 706885, 727427, 733765, 727621, 776323, 699331,
+    778820, 736068, 795973, 754115, 717060, 714820, 696515, 748739, 799044, 721028, 720516, 715331,
+    707205, 696515, 736068, 698947, 781383, 739780, 767940, 777284, 751045, 777925, 856260, 741061,
+    787139, 710724, 758340, 698310, 756613, 727621, 753411, 759876, 696515, 757445, 700101, 698310,
+    761285, 765124, 766916, 789444, 735493, 783428, 748739, 698115, 728132, 714820, 761925, 764038,
+    710979, 721285, 774149, 714181, 777284, 745156, 730309, 756932, 709508, 714820, 743107, 704388,
+    739780, 730309, 707205, 714501, 751365, 699331, 709764, 696515, 744133, 701059, 767940, 710339,
+    716164, 709508, 708228, 741061, 708228, 747460, 710724, 698947, 720516, 733765, 730309, 706628,
+    721798, 801218, 710339, 737604, 747460, 856902, 741061, 730309, 761925, 714820, 740547, 744133,
+    758340, 741061, 759876, 718021, 718021, 726403, 479938, 720772, 791363, 713155, 769733, 713155,
+    745412, 745412, 700804, 741635, 768196, 768196, 776772, 800451, 797635, 706628, 764038, 720772,
+    742147, 732293, 790596, 698115, 698947, 724741, 758917, 790596, 736579, 753602, 752580, 741380,
+    741635, 709252, 700101, 731525, 715331, 736579, 751942, 752580, 764038, 752580, 727939, 738116,
+    752580, 756293, 702083, 791363, 752580, 724741, 736579, 746885, 764038, 710339, 768901, 743107,
+    724741, 701059, 719492, 786884, 740229, 754308, 701508, 781828, 789444, 712708, 739395, 754308,
+    754308, 740229, 712708, 739395, 742789, 773315, 771012, 697283, 761925, 699331, 737028, 775557,
+    706246, 793092, 743107, 761925, 743107, 696515, 726403, 709252, 743107, 789188, 742789, 704388,
+    734085, 743107, 699331, 782852, 770052, 699844, 758084, 779332, 710979, 701508, 763654, 758084,
+    748739, 742789, 772357, 710339, 728132, 740035, 734085, 779332, 707205, 748547, 748739, 716164,
+    740229, 771525, 748547, 739395, 710339, 725635, 703110, 719492, 740035, 791557, 791557, 712260,
+    697283, 805893, 757765, 715526, 735812, 736324, 715331, 779332, 706885, 735812, 771012, 745669,
+    763654, 701252, 722628, 699525, 779332, 734917, 785413, 709508, 779588, 776772, 747204, 730309,
+    764739, 701252, 733765, 721798, 734404, 727621, 737604, 787139, 750213, 753734, 710531, 727621,
+    720516, 763654, 779588, 698115, 708804, 750213, 750213, 699525, 731843, 765381, 790852, 696515,
+    730115, 730115, 732613, 712708, 738885, 701765, 748355, 774149, 722884, 702597, 748355, 790852,
+    696515, 722179, 745669, 738885, 720516, 753734, 759557, 748932, 750213, 746565, 733765, 704388,
+    698115, 706246, 717764, 775557, 721798, 779588, 754308, 741829, 741380, 795973, 731843, 703110,
+    783684, 759557, 753602, 709252, 730309, 715331, 802436, 747460, 734085, 766597, 757765, 755076,
+    709252, 732293, 701252, 772678, 773060, 857284, 710021, 721798, 710339, 747460, 722884, 759557,
+    811269, 722884, 773315, 741635, 741635, 725635, 755076, 739587, 748739, 696515, 706628, 761028,
+    740229, 699331, 755076, 721603, 697860, 746885, 715331, 704964, 761028, 762565, 734085, 779588,
+    773315, 751684, 738885, 742595, 782852, 790596, 791108, 748932, 740035, 713155, 736068, 785413,
+    700101, 782852, 748355, 785733, 743556, 771525, 773509, 776131, 765124, 697860, 772678, 776772,
+    745669, 769220, 811269, 727939, 698310, 696515, 735493, 764739, 698115, 792581, 702277, 783428,
+    720516, 769220, 768901, 703110, 698947, 772357, 626115, 837700, 710724, 715331, 714181, 728387,
+    708804, 769733, 727621, 792581, 721285, 738372, 730309, 747204, 702083, 740035, 731205, 748932,
+    708228, 782852, 730309, 702083, 736579, 737860, 742789, 734085, 739395, 775557, 740547, 709764,
+    700101, 799044, 743813, 738628, 734404, 737604, 772357, 699525, 767172, 699525, 710339, 753734,
+    721028, 738885, 699525, 737604, 748739, 772357, 774149, 707205, 769220, 715331, 722372, 737285,
+    747204, 708804, 776772, 728132, 708228, 699331, 795653, 750531, 721028, 701252, 696515, 727621,
+    712260, 751684, 727621, 761925, 710531, 740229, 724741, 783684, 764421, 736579, 724741, 807301,
+    737604, 745412, 701252, 696709, 697283, 698947, 730115, 719492, 712515, 730628, 754115, 705862,
+    715331, 770052, 753734, 771525, 721603, 719044, 709508, 721028, 710339, 710979, 771012, 767428,
+    737604, 724229, 712260, 697283, 747204, 706628, 734660, 753602, 705667, 701059, 711812, 704388,
+    722884, 700422, 738372, 735812, 779332, 704388, 468099, 706885, 713155, 698115, 712708, 740547,
+    699331, 759876, 730628, 730628, 731843, 761285, 750213, 736579, 756932, 725635, 708804, 710339,
+    752837, 739203, 713541, 758917, 732293, 786884, 713347, 791363, 726403, 791363, 727621, 764931,
+    767428, 710339, 774149, 731205, 479300, 710339, 857544, 722372, 785093, 746245, 708804, 753734,
+    737604, 753602, 787332, 717060, 748739, 709764, 736579, 759876, 797060, 746885, 704388, 731205,
+    758917, 715331, 465603, 730885, 795653, 697860, 714181, 468932, 709764, 714181, 17282, 785093,
+    756293, 789444, 707909, 754565, 730885, 699331, 756293, 697860, 791557, 774149, 858053, 738372,
+    738372, 753602, 777284, 761925, 791557, 789444, 704388, 719044, 802436, 699331, 471619, 858373,
+    748739, 773509, 734085, 856260, 760133, 708485, 785093, 758917, 704388, 804421, 759237, 814149,
+    697283, 757445, 761605, 708228, 754308, 726403, 696709, 771012, 735493, 734085, 743107, 748739,
+    736579, 722884, 789444, 732293, 737028, 714820, 775876, 712515, 697283, 729284, 709252, 741061,
+    778501, 735236, 697283, 773509, 805893, 704388, 790852, 696709, 773315, 730628, 706628, 761925,
+    752837, 776131, 741635, 709764, 795653, 750213, 764421, 697860, 771525, 742595, 787332, 758917,
+    734085, 731843, 758340, 749188, 858693, 697283, 676675, 706885, 738885, 789444, 789444, 721603,
+    749445, 788485, 755587, 753602, 742147, 777284, 702915, 737285, 766277, 782084, 701059, 709764,
+    704645, 767940, 783684, 750213, 697860, 697283, 726597, 779332, 709764, 722628, 775876, 711812,
+    776131, 727427, 679429, 761605, 704964, 700422, 707909, 697283, 768451, 758917, 748739, 748739,
+    747204, 705862, 773509, 714820, 767940, 756613, 697283, 698115, 697283, 738116, 757445, 761605,
+    797317, 620483, 698947, 766597, 745156, 740547, 807301, 769733, 734085, 715331, 708228, 696709,
+    741061, 764038, 779332, 764931, 714181, 731843, 710339, 842693, 724741, 473794, 698310, 714181,
+    735812, 730309, 699331, 804421, 743107, 737604, 761285, 767940, 776131, 722372, 698947, 789444,
+    771525, 696515, 770630, 701059, 701059, 699331, 794500, 697283, 736579, 781124, 734085, 699331,
+    805893, 772357, 786884, 742147, 479938, 465348, 715076, 801347, 730309, 753734, 767940, 767940,
+    712260, 710339, 710339, 770052, 789444, 786884, 761925, 710339, 774149, 774149, 698115, 726403,
+    790151, 707205, 772100, 757188, 704388, 701252, 701252, 710339, 728773, 743300, 782340, 702915,
+    697283, 724547, 721603, 723396, 730309, 730309, 712260, 697283, 712260, 740741, 775876, 706246,
+    459907, 696515, 701252, 704132, 712260, 746565, 710979, 710979, 748355, 754883, 712260, 787909,
+    779588, 742340, 740547, 712963, 708804, 727621, 722884, 742147, 751045, 748355, 748739, 699331,
+    715526, 712515, 746565, 768901, 708804, 720772, 697860, 712260, 698947, 790596, 750213, 790596,
+    811269, 728132, 773509, 699844, 802436, 731843, 758084, 737604, 786628, 697283, 739395, 707205,
+    789700, 732293, 748739, 701765, 728132, 758084, 712260, 748739, 766597, 737028, 711812, 737860,
+    704132, 715331, 714820, 730115, 737604, 750213, 710979, 765381, 712515, 730309, 733765, 742595,
+    696515, 779588, 702915, 731843, 467331, 708485, 750213, 744133, 698947, 790596, 737285, 702915,
+    721603, 724547, 468802, 738885, 697283, 699331, 715331, 698947, 702915, 707909, 758340, 750725,
+    722884, 710339, 751365, 739587, 758084, 710339, 774469, 782340, 742595, 757188, 767940, 811844,
+    699331, 699331, 720516, 723396, 726148, 743107, 776323, 736772, 702915, 727172, 776323, 730115,
+    749188, 719492, 696515, 730309, 701765, 788485, 701508, 701508, 775174, 734660, 752580, 722628,
+    776131, 785413, 712260, 795333, 741635, 712260, 699331, 748355, 754115, 739780, 701059, 739587,
+    710339, 704132, 754883, 701059, 728579, 732293, 698115, 703110, 788485, 726148, 733765, 742340,
+    712260, 712260, 716164, 710531, 730115, 737604, 698947, 703110, 731205, 712260, 737604, 743107,
+    714820, 730115, 712260, 706628, 727172, 773315, 696515, 708804, 737860, 751365, 710979, 715331,
+    697028, 754565, 710979, 727939, 811269, 748739, 802436, 719492, 795333, 742595, 703110, 776323,
+    741829, 811269, 705667, 729284, 748739, 739395, 742340, 727172, 779845, 766277, 745412, 698947,
+    712260, 712260, 802436, 760453, 758917, 776131, 701765, 700101, 754883, 699331, 755587, 803140,
+    483333, 696515, 715331, 752837, 698947, 710979, 741635, 767428, 706628, 706246, 757445, 740035,
+    703110, 758084, 736068, 726597, 730115, 699331, 698947, 740035, 750213, 712260, 758084, 717060,
+    698947, 790596, 790596, 739395, 765701, 722884, 704132, 698947, 724229, 725635, 766597, 733508,
+    712260, 698947, 748739, 755587, 698947, 761925, 736068, 701508, 797827, 480258, 696515, 764038,
+    700804, 698947, 722884, 756293, 728387, 724741, 708485, 731205, 771268, 748739, 722884, 751365,
+    797827, 737860, 774469, 767428, 699331, 767172, 698947, 753156, 736068, 703110, 737604, 749445,
+    748739, 756613, 481666, 774469, 708485, 710339, 757188, 718788, 744451, 705220, 772678, 715331,
+    772678, 759237, 699331, 824708, 710021, 734404, 707526, 779845, 776323, 745156, 700804, 761285,
+    734404, 752837, 755780, 734404, 701765, 744451, 708804, 724741, 714181, 735493, 712515, 720772,
+    734404, 712260, 781383, 734404, 746565, 724741, 781383, 751942, 756293, 703110, 743556, 784773,
+    763459, 709508, 754115, 776131, 804421, 740035, 755587, 707205, 782852, 776516, 594052, 714820,
+    763459, 744451, 705667, 699331, 710531, 772678, 734404, 734404, 746565, 732613, 699331, 757765,
+    735493, 710531, 721285, 715331, 712515, 782852, 781383, 775557, 664196, 813829, 774149, 703110,
+    738116, 768901, 768196, 784773, 714181, 772678, 776516, 768196, 774149, 700804, 710339, 722372,
+    701252, 784516, 731525, 722372, 730885, 697283, 731525, 749445, 701252, 716164, 785413, 710531,
+    742789, 708804, 732293, 774149, 701765, 710021, 710021, 698310, 696515, 702915, 754308, 730885,
+    731205, 760133, 710021, 735493, 696515, 716164, 710021, 740547, 706628, 713541, 764421, 741829,
+    700422, 814149, 769733, 738116, 744837, 710021, 747204, 710021, 708804, 708804, 713347, 758917,
+    699331, 720516, 719492, 791557, 708804, 859015, 773315, 698947, 756293, 728579, 787909, 698947,
+    750213, 775557, 813572, 705667, 804421, 765381, 712963, 778501, 756293, 805316, 701059, 741635,
+    734085, 696515, 702597, 766597, 763654, 697860, 731843, 708804, 719299, 720516, 739395, 710339,
+    700804, 771012, 755780, 746565, 702915, 710979, 750531, 760772, 735236, 787139, 730115, 731843,
+    732293, 727939, 739203, 710979, 766020, 770052, 717060, 705475, 770052, 701252, 776772, 791363,
+    859460, 734085, 772678, 774149, 859718, 752837, 759876, 720772, 740547, 720516, 714820, 734404,
+    730309, 741635, 772678, 778820, 769733, 724741, 728132, 746245, 813572, 718595, 713155, 753734,
+    708804, 731205, 698947, 787139, 746885, 750531, 706246, 743556, 747460, 741635, 825605, 771525,
+    721798, 750531, 710979, 722628, 776323, 797635, 710339, 754565, 710531, 707205, 734085, 741380,
+    731205, 697860, 712260, 703110, 787909, 701252, 708804, 742340, 756293, 741829, 725829, 750531,
+    704388, 790596, 750725, 719492, 700101, 860101, 778501, 722628, 860418, 731525, 756293, 734085,
+    728387, 747460, 809860, 741635, 741635, 741635, 705475, 720516, 714501, 699331, 734085, 775557,
+    739395, 720516, 721798, 710021, 719044, 740229, 748739, 726403, 698947, 708228, 787909, 754308,
+    710979, 712708, 739395, 759237, 731525, 746245, 754308, 758597, 707205, 765381, 750531, 710531,
+    726403, 727939, 700804, 730628, 759876, 709508, 756293, 762884, 734085, 738885, 752837, 813572,
+    715526, 730309, 713541, 750213, 763654, 712963, 701252, 708804, 782340, 762565, 748739, 712515,
+    702597, 756293, 698947, 706628, 764038, 746885, 779588, 770052, 758597, 700422, 763141, 706885,
+    708804, 730885, 700804, 751684, 701508, 785413, 731525, 470082, 782852, 860548, 783428, 722628,
+    743107, 737285, 782340, 700101, 790596, 756932, 762884, 721798, 766020, 798020, 731525, 756293,
+    784773, 749445, 713861, 773315, 710339, 741635, 708485, 722372, 825605, 720516, 708804, 705862,
+    774149, 772100, 734085, 699139, 710339, 737860, 741635, 731843, 702277, 704388, 758917, 824708,
+    700422, 755332, 768901, 776516, 699331, 715076, 700422, 727939, 715331, 798276, 696515, 712963,
+    707909, 743556, 753411, 713541, 700101, 698947, 720772, 755587, 783428, 712708, 748739, 788485,
+    766916, 784773, 860807, 766916, 713347, 764421, 861253, 756293, 726403, 748355, 702915, 771525,
+    721285, 709508, 741061, 710979, 734085, 771012, 782852, 732036, 714181, 769733, 764038, 813572,
+    745156, 757188, 756293, 773315, 701252, 757188, 471490, 755332, 861573, 755332, 719492, 710531,
+    773315, 748739, 704388, 726403, 771525, 771268, 776131, 774149, 731525, 726597, 726403, 740229,
+    747460, 462979, 772100, 782852, 699331, 782340, 752580, 750213, 771844, 742595, 748355, 700101,
+    698947, 17282, 861573, 773829, 700422, 740229, 770052, 765124, 740229, 861893, 708485, 773829,
+    748739, 748739, 734085, 730628, 761925, 699331, 747460, 774149, 862216, 726597, 772100, 731525,
+    782340, 706246, 740547, 726597, 752837, 764421, 824005, 710339, 702915, 759876, 761925, 782340,
+    708485, 740035, 748355, 731843, 770052, 727621, 711812, 727621, 739203, 723396, 767940, 730309,
+    730115, 739587, 781828, 628485, 731525, 862725, 741635, 739780, 712515, 719044, 724229, 811844,
+    734660, 741635, 738116, 476803, 710339, 720516, 863049, 863619, 728579, 710339, 719492, 710531,
+    791363, 698115, 753411, 788485, 740229, 710339, 755587, 746565, 710339, 697283, 755587, 730885,
+    731205, 730115, 750531, 782340, 740229, 708228, 808455, 745412, 465603, 734660, 698947, 619010,
+    863815, 703750, 730309, 710979, 703750, 767684, 795333, 710339, 782340, 748739, 710531, 697283,
+    738116, 745412, 698310, 708228, 757445, 710021, 798276, 864262, 698692, 698692, 737285, 752837,
+    811844, 699525, 751684, 740035, 757445, 712963, 734085, 757188, 742147, 710339, 723396, 710979,
+    739587, 737028, 739395, 736772, 790596, 737285, 701508, 863815, 800004, 808455, 737285, 748355,
+    698692, 734660, 768196, 724229, 770630, 698115, 698692, 698692, 767428, 715331, 703750, 770630,
+    750725, 726403, 795973, 477763, 757445, 730309, 767428, 784197, 798020, 710339, 742340, 731843,
+    798020, 746565, 772100, 748355, 724741, 705220, 715331, 749445, 721798, 705220, 704132, 698947,
+    749188, 740229, 738628, 782340, 730885, 699331, 743107, 709252, 727172, 736068, 736068, 701508,
+    719299, 730115, 723652, 810116, 784516, 706628, 716164, 762565, 701059, 714820, 719044, 730885,
+    712260, 698947, 734660, 722628, 727172, 709508, 728132, 755587, 734917, 766916, 738116, 705220,
+    712708, 864645, 728132, 710979, 712260, 742147, 730115, 700101, 730885, 712708, 767940, 715331,
+    710979, 723396, 727621, 737028, 708804, 740547, 732293, 708804, 741829, 719492, 733765, 710979,
+    730628, 750213, 732036, 708485, 701252, 706246, 730309, 754883, 751942, 739780, 710531, 698115,
+    716164, 789700, 864965, 710021, 727172, 725317, 723396, 706628, 708804, 700101, 778820, 745412,
+    721028, 753734, 712963, 742147, 795333, 795333, 786628, 723396, 754115, 724741, 746245, 699844,
+    759876, 738885, 701765, 730309, 710979, 865284, 731205, 765701, 746565, 714820, 767940, 723396,
+    746245, 740035, 729284, 790852, 731205, 702915, 770630, 715526, 737860, 721028, 712260, 734085,
+    759557, 720516, 699525, 750213, 748739, 703110, 701508, 716164, 766277, 731205, 756293, 709252,
+    726148, 795333, 736772, 742595, 736772, 723396, 730885, 715331, 710979, 725829, 722884, 775876,
+    802436, 710979, 710021, 712963, 712260, 704388, 751942, 706885, 698947, 865540, 865797, 757445,
+    708228, 726916, 710339, 748739, 703750, 700101, 746245, 741829, 697283, 697283, 779845, 697283,
+    709252, 731525, 730309, 777925, 709764, 697283, 786628, 709252, 778501, 723909, 740741, 804421,
+    761028, 798276, 738116, 729284, 777284, 697283, 742789, 737028, 797317, 732293, 699525, 697283,
+    700804, 758340, 757445, 704964, 809860, 696515, 730115, 746885, 703750, 728132, 756932, 791108,
+    771012, 699331, 757445, 770052, 748739, 740035, 866118, 698310, 866503, 740035, 715526, 734085,
+    776131, 730309, 805893, 699525, 697283, 764421, 706246, 731843, 771012, 785413, 765381, 723396,
+    755780, 702915, 701508, 752837, 795973, 795333, 702277, 699525, 709764, 710979, 709764, 699331,
+    766020, 785413, 723396, 726597, 757445, 752837, 722628, 714820, 742595, 778820, 712260, 722628,
+    703110, 707909, 732036, 697860, 765381, 757445, 740035, 866949, 867271, 795333, 824708, 761925,
+    728132, 696515, 804421, 739587, 766277, 739395, 779332, 740229, 734085, 783684, 746245, 790151,
+    722628, 697860, 788806, 715331, 765701, 786628, 710979, 722628, 750213, 790596, 718595, 712963,
+    749764, 773829, 730309, 755587, 750213, 757445, 786628, 867717, 761028, 741635, 706885, 753411,
+    710339, 766916, 714820, 715331, 757445, 767940, 700422, 731525, 769220, 712260, 748739, 698115,
+    768901, 726597, 748355, 738885, 757445, 698947, 766597, 722884, 770052, 788806, 740741, 696515,
+    799044, 748932, 740229, 705862, 755076, 868036, 715331, 868293, 736772, 778244, 738885, 737860,
+    714181, 807301, 742340, 712260, 766597, 698692, 708228, 699331, 770052, 721285, 797317, 769476,
+    734085, 810628, 707909, 791108, 736772, 737860, 782084, 824005, 715331, 705862, 868611, 787139,
+    763654, 737860, 715331, 797827, 734085, 770630, 795973, 768196, 702277, 706628, 724741, 726403,
+    707909, 696515, 787332, 722884, 696515, 825605, 740035, 698947, 767172, 731525, 772100, 728387,
+    738628, 824005, 697283, 758917, 710339, 698115, 767940, 723396, 824005, 753734, 704132, 740035,
+    767940, 730309, 707909, 743556, 748739, 707909, 761925, 761028, 715526, 730309, 789700, 824005,
+    759876, 715331, 758084, 772100, 767940, 726597, 702915, 705220, 699331, 730885, 740229, 730309,
+    743107, 782340, 698947, 709252, 753734, 698947, 727172, 709764, 722628, 730885, 762565, 701059,
+    719044, 778501, 719299, 727172, 757445, 729284, 734660, 712260, 736068, 734917, 708804, 714820,
+    810116, 716164, 730309, 719492, 723396, 710979, 726597, 754883, 727621, 715331, 712260, 706246,
+    740229, 739780, 710979, 708485, 741829, 696515, 710021, 732036, 766277, 765701, 706628, 754115,
+    810628, 790852, 725317, 737860, 865284, 738885, 770630, 759876, 721028, 724741, 731205, 795333,
+    756293, 759557, 736772, 715331, 722884, 786628, 698947, 742595, 698947, 740035, 732293, 597444,
+    740035, 697283, 756932, 797317, 698310, 704964, 763654, 709764, 699525, 777925, 740741, 700101,
+    786628, 777284, 746245, 757445, 742789, 709252, 748739, 804421, 730115, 776131, 715526, 734085,
+    699525, 772100, 742595, 785413, 697283, 722628, 737860, 702915, 726916, 764421, 752837, 730309,
+    766020, 804421, 795973, 731843, 748739, 752837, 771012, 765381, 728132, 710979, 718595, 750213,
+    706885, 788806, 790596, 707909, 710339, 696515, 734085, 767172, 768901, 769220, 767940, 761925,
+    766916, 748932, 799044, 723396, 721285, 766597, 807301, 770630, 731525, 768196, 795973, 724741,
+    824005, 784516, 723396, 778501, 784516, 789444, 784516, 868808, 726403, 713541, 751684, 463620,
+    778501, 761925, 761925, 761605, 697860, 707205, 769476, 758340, 732934, 697283, 785733, 761925,
+    767940, 758340, 748739, 707205, 723909, 723909, 778501, 723909, 741380, 599043, 759876, 710979,
+    748355, 713155, 739395, 708804, 710531, 776772, 739395, 771012, 778501, 728579, 778820, 742595,
+    759557, 710979, 708228, 704388, 775557, 789700, 778820, 708485, 699331, 712260, 748547, 713155,
+    752324, 716164, 702915, 701059, 721603, 777284, 728579, 713155, 710339, 732613, 709764, 741635,
+    698947, 807301, 731205, 777284, 698947, 759557, 748355, 775557, 759876, 701059, 698947, 698947,
+    718021, 733765, 715076, 705862, 724547, 779588, 733765, 704132, 704132, 734660, 728579, 790596,
+    733765, 739395, 705862, 787589, 790596, 732293, 696515, 708804, 731843, 736772, 731525, 696515,
+    730309, 765701, 802692, 802692, 731525, 795973, 789700, 730309, 696515, 869321, 705862, 730309,
+    699331, 795333, 706628, 737285, 765381, 715331, 770052, 766597, 734660, 766597, 726597, 767940,
+    795333, 796804, 705862, 707909, 699331, 749445, 696515, 775174, 785733, 734660, 730885, 720516,
+    720516, 738116, 710979, 697860, 793092, 696515, 727427, 696515, 701059, 732293, 777284, 712260,
+    740547, 715331, 723396, 715526, 715331, 715331, 697283, 714181, 737860, 737860, 756293, 797827,
+    730885, 770630, 731843, 738116, 756932, 786628, 719044, 720516, 708804, 734085, 738885, 701252,
+    763141, 757445, 709252, 737860, 751684, 743107, 743107, 741380, 768451, 768901, 696515, 726597,
+    740547, 594306, 708228, 793092, 714181, 768451, 770052, 696515, 737860, 738885, 758084, 734404,
+    745156, 465603, 697028, 776772, 711812, 697283, 766020, 766020, 766020, 790852, 790852, 722179,
+    783109, 805893, 722179, 711172, 700422, 767940, 725829, 730115, 795333, 732613, 752580, 722628,
+    797635, 701059, 720516, 702915, 722372, 717060, 710531, 743107, 722372, 745156, 729284, 698947,
+    737285, 764739, 783428, 796804, 766020, 713861, 727939, 783428, 698947, 745156, 737604, 704132,
+    698115, 744837, 722179, 711812, 725829, 702083, 787139, 760133, 726148, 722628, 720516, 709508,
+    709508, 730885, 795333, 712260, 592579, 798020, 709508, 750213, 738372, 732293, 721798, 704132,
+    744451, 721285, 774149, 712260, 743813, 746565, 722179, 705667, 790852, 778820, 591746, 726597,
+    752580, 763654, 773060, 794500, 710021, 710531, 741635, 731843, 798276, 736068, 740035, 713541,
+    702277, 869896, 641218, 725829, 699331, 702277, 782852, 870410, 782852, 702277, 726597, 785733,
+    782852, 726597, 726597, 702277, 768451, 763654, 760133, 725829, 782852, 798020, 712260, 709508,
+    743813, 700804, 698947, 725829, 725829, 774149, 699331, 699331, 775557, 702915, 755587, 696515,
+    755587, 702915, 775557, 775557, 811844, 696515, 713347, 698947, 701508, 797060, 800004, 705475,
+    731843, 795973, 764038, 696515, 727172, 756293, 718595, 740229, 765381, 773315, 778501, 765701,
+    719492, 749188, 763459, 732934, 739395, 712260, 741380, 710979, 756932, 734660, 735236, 713861,
+    734085, 734917, 779845, 704132, 719299, 792261, 797060, 778244, 749445, 699331, 736068, 734404,
+    728132, 701059, 731525, 715331, 715331, 756932, 797060, 710021, 771844, 727621, 706246, 698310,
+    723396, 727621, 709764, 740229, 790852, 709508, 756932, 728579, 726403, 740547, 757765, 737028,
+    778820, 771844, 730115, 754115, 712260, 739587, 713541, 730115, 710979, 713861, 750213, 730309,
+    742340, 735236, 717060, 763654, 712260, 755780, 725635, 740547, 712963, 776131, 696515, 714820,
+    740035, 733765, 774149, 702597, 744643, 748739, 710021, 739203, 704388, 713861, 747716, 696515,
+    747974, 699844, 705475, 702597, 712260, 756293, 774790, 774790, 722179, 753602, 746885, 763141,
+    812933, 730115, 720772, 703110, 746565, 712260, 738116, 779588, 723396, 738116, 723396, 698115,
+    717764, 698947, 871051, 651011, 750725, 733315, 787589, 697283, 787332, 748355, 751365, 718021,
+    709252, 726403, 736068, 707205, 701508, 724741, 710021, 811844, 725317, 783684, 773060, 766597,
+    725829, 779588, 762565, 727621, 723396, 800451, 789700, 753411, 736772, 736324, 730628, 786884,
+    703110, 777028, 710979, 776516, 730628, 812357, 871748, 701059, 739395, 778820, 710339, 726597,
+    698310, 738116, 706628, 703750, 740229, 701059, 756613, 729284, 763141, 798276, 782852, 758597,
+    709508, 757765, 777028, 710021, 706628, 727427, 696515, 748547, 727172, 727621, 797317, 710979,
+    710979, 710531, 756932, 705862, 709252, 702597, 701059, 798276, 757445, 696709, 712515, 779588,
+    805893, 700422, 712260, 768644, 778820, 764739, 731843, 748547, 746885, 787332, 699331, 707526,
+    752324, 722628, 706246, 811844, 753602, 771268, 776323, 734404, 734085, 709764, 724741, 699331,
+    717060, 764421, 741635, 732934, 710979, 730309, 739587, 699525, 744451, 773829, 753156, 766277,
+    731525, 748739, 744451, 765124, 737285, 755587, 804421, 705475, 696515, 726403, 721285, 698947,
+    701059, 723396, 790596, 748739, 767172, 764739, 732934, 748739, 798276, 713861, 824326, 768901,
+    770630, 710979, 737285, 698115, 715331, 700422, 748739, 724741, 761925, 748739, 712260, 837381,
+    762565, 726597, 731205, 758917, 739587, 712708, 721285, 738116, 720772, 756293, 773829, 770052,
+    712260, 724741, 766597, 712515, 738116, 837700, 715526, 722884, 734660, 761925, 758340, 822085,
+    709508, 750725, 751365, 792004, 726597, 741635, 744133, 721028, 722372, 696515, 759557, 727427,
+    726597, 799044, 792004, 739395, 697283, 738116, 767172, 738628, 718595, 737604, 787589, 730309,
+    756613, 746885, 813829, 727427, 704388, 748739, 737604, 787589, 759876, 740547, 787589, 718021,
+    739587, 787589, 773509, 731525, 763459, 747974, 787589, 708228, 747974, 730115, 724741, 798020,
+    700101, 698947, 790596, 700101, 699525, 798020, 712260, 712260, 705475, 734085, 790852, 712260,
+    736772, 771844, 750213, 705220, 730309, 699331, 749188, 734404, 699331, 775174, 715331, 715331,
+    705220, 699331, 699331, 746885, 710531, 705220, 736324, 743300, 743300, 872007, 705220, 701252,
+    758340, 716164, 701252, 757765, 709508, 728387, 728387, 707205, 782084, 758340, 707526, 792581,
+    697860, 712515, 740229, 758084, 778501, 766597, 696515, 617539, 797635, 771844, 770052, 739780,
+    707526, 792261, 734917, 739780, 789444, 872455, 706246, 734085, 729797, 732293, 708804, 727939,
+    726916, 726403, 730309, 705475, 707526, 717060, 748739, 730885, 713861, 715331, 587075, 710979,
+    768196, 706885, 710339, 741829, 699331, 754565, 725829, 464642, 709764, 726403, 772357, 788485,
+    710531, 764038, 777925, 757445, 752837, 776772, 790852, 460547, 766277, 749188, 696515, 799044,
+    729797, 800004, 783428, 732036, 872901, 734404, 731205, 873223, 771525, 758340, 748739, 696515,
+    706628, 734085, 737604, 772357, 697283, 748739, 748739, 707909, 790151, 723140, 703750, 734085,
+    734085, 797827, 704388, 710979, 713541, 704388, 704388, 800004, 800004, 696515, 713347, 725829,
+    701508, 736579, 711172, 746565, 724741, 706628, 758917, 709252, 696515, 751045, 699525, 715076,
+    719492, 731525, 699331, 699331, 795653, 772678, 776323, 697860, 697283, 791557, 730115, 737860,
+    722884, 712260, 715526, 741635, 710979, 792004, 709764, 712515, 712260, 697283, 766597, 736068,
+    731525, 727172, 784516, 743556, 706628, 748739, 740035, 698947, 726148, 697283, 765381, 734660,
+    728579, 791108, 736324, 698947, 782084, 782084, 734917, 712515, 721028, 766020, 712260, 722628,
+    726148, 768451, 722372, 776772, 705667, 756932, 728773, 792261, 702277, 714820, 704132, 752837,
+    696515, 873669, 709764, 710339, 701059, 753602, 730309, 732934, 729797, 772357, 755587, 696515,
+    765381, 778244, 732293, 771844, 746565, 731843, 785413, 772678, 710339, 744133, 776131, 725635,
+    735236, 755780, 766020, 771012, 696515, 696515, 710531, 701059, 762884, 754115, 708804, 739587,
+    726403, 701765, 766597, 701508, 734404, 730309, 760772, 730885, 761925, 712708, 702597, 710531,
+    790852, 798020, 719492, 712963, 713541, 779588, 710979, 763654, 739395, 741635, 709764, 710979,
+    708485, 776772, 770052, 700804, 776772, 776772, 728579, 730115, 698115, 730115, 731843, 720516,
+    698947, 710021, 774149, 623812, 812357, 643652, 718788, 761925, 772678, 705475, 745669, 710339,
+    765701, 714820, 723140, 731525, 751045, 773509, 699331, 736772, 730115, 773060, 712515, 698947,
+    782852, 750531, 775174, 742147, 766020, 747974, 791557, 720516, 724741, 724741, 710979, 699331,
+    708804, 730115, 770630, 737860, 714820, 749188, 713155, 765701, 718595, 775174, 791557, 731205,
+    706246, 477508, 708804, 738628, 795973, 747716, 746245, 699844, 787139, 710021, 746885, 746245,
+    800004, 710021, 722179, 748547, 777925, 720516, 727172, 734085, 742595, 764038, 713541, 710339,
+    728132, 745156, 716164, 808455, 787139, 717060, 810628, 741635, 727939, 714181, 737860, 697283,
+    771525, 475202, 765701, 755076, 761925, 759876, 761925, 738628, 728132, 813572, 714820, 717764,
+    730885, 740229, 734404, 773315, 709764, 722372, 776516, 710979, 727172, 714820, 743107, 741635,
+    699139, 722628, 731843, 776323, 722884, 722884, 703110, 773509, 712515, 782596, 715331, 795333,
+    710339, 710979, 708804, 698115, 710339, 808455, 698947, 726916, 701508, 719044, 766916, 773509,
+    706885, 710531, 779588, 772357, 746885, 710021, 738372, 736324, 754308, 704388, 725829, 735493,
+    783684, 709252, 741380, 704132, 783684, 717060, 723909, 704132, 810116, 741829, 730309, 754565,
+    710979, 753602, 698115, 736068, 706628, 752324, 725635, 747204, 747460, 726597, 701252, 763459,
+    873990, 726597, 737604, 704132, 761925, 761925, 874372, 762565, 791557, 791557, 742147, 709252,
+    710021, 731843, 740547, 702597, 734085, 809860, 739395, 727427, 748739, 710531, 709764, 754308,
+    798020, 802436, 743107, 728387, 730115, 790596, 798276, 710979, 729284, 732934, 700101, 760133,
+    758597, 742789, 733508, 761028, 728579, 710339, 776323, 726403, 741380, 755780, 726148, 759557,
+    715331, 726148, 731843, 706628, 728387, 771525, 728132, 775557, 771844, 795333, 782084, 739395,
+    732293, 756932, 728132, 748547, 723909, 740741, 740229, 704388, 710531, 734085, 708228, 758340,
+    716164, 757765, 758084, 712963, 697283, 743107, 738116, 786628, 762565, 737028, 732934, 795973,
+    756932, 747460, 697283, 772357, 713541, 709764, 709508, 719044, 795973, 704388, 696515, 746565,
+    697283, 709252, 750531, 711172, 874635, 730628, 705220, 793476, 759876, 473410, 17282, 772357,
+    479938, 875333, 484292, 761925, 761925, 761925, 756293, 733315, 701508, 776131, 715526, 709252,
+    699331, 699331, 710979, 726597, 738885, 765381, 752324, 727939, 763459, 762565, 756293, 699331,
+    763141, 776772, 748547, 753602, 759876, 761925, 737604, 778820, 770630, 699525, 742340, 742340,
+    710531, 755780, 811844, 715331, 723396, 748547, 740547, 734085, 710979, 748739, 710021, 793092,
+    706885, 730885, 730885, 751684, 704388, 776323, 708804, 730115, 715526, 727621, 705475, 706628,
+    710021, 748355, 764038, 727621, 728387, 699844, 702597, 740229, 731525, 709764, 726403, 709764,
+    797635, 698947, 712963, 787589, 717060, 764421, 734404, 754308, 702277, 746245, 709764, 730309,
+    782852, 725829, 755076, 715331, 701252, 697283, 772100, 757445, 734404, 765381, 779588, 722628,
+    716164, 731843, 700422, 707205, 863815, 718788, 707909, 781124, 782852, 609283, 770630, 783428,
+    709764, 755332, 474051, 765124, 714820, 701252, 762565, 701252, 722628, 750213, 754565, 739203,
+    772357, 743107, 789444, 787909, 765381, 710339, 710531, 715331, 713861, 722372, 740035, 776323,
+    777284, 763459, 750213, 731525, 754883, 791363, 783684, 765381, 722372, 699331, 750725, 779332,
+    728387, 728387, 719299, 795653, 715331, 734660, 722628, 721798, 734085, 698947, 762884, 729797,
+    809667, 748355, 752580, 813572, 750213, 745669, 765124, 740035, 777284, 715331, 739395, 754565,
+    765701, 785733, 699844, 783684, 701252, 737285, 759876, 718595, 766916, 730309, 737028, 734404,
+    720772, 741635, 757445, 704964, 764931, 736772, 750531, 725060, 772100, 591171, 698947, 710339,
+    767684, 754565, 699331, 620228, 761925, 698115, 735493, 746565, 776772, 731205, 708804, 758917,
+    748547, 726597, 702277, 715331, 713541, 701508, 728132, 762565, 706628, 709764, 753156, 800004,
+    699331, 696515, 698947, 721285, 741635, 748739, 755587, 699139, 698310, 759876, 770630, 769220,
+    704388, 732293, 698947, 768901, 704964, 741380, 731843, 755587, 748739, 743300, 697478, 731843,
+    715076, 738628, 769220, 726403, 731843, 783684, 711812, 824708, 738885, 709764, 712963, 768451,
+    748355, 783428, 753411, 706246, 761028, 791363, 770630, 748355, 702277, 812357, 737860, 754308,
+    714820, 734085, 704388, 716164, 714820, 782852, 773509, 712963, 719044, 743556, 731525, 721603,
+    787589, 875655, 705862, 776516, 726597, 708228, 743556, 736579, 741635, 710339, 709764, 698947,
+    738628, 763654, 770052, 772357, 764739, 707909, 741061, 738628, 724741, 791108, 791108, 767940,
+    699331, 714181, 722372, 782084, 734085, 717764, 722628, 755780, 704132, 740547, 748739, 810628,
+    737860, 753602, 773829, 756932, 721028, 789700, 713155, 718595, 734660, 782852, 792581, 791108,
+    705667, 740035, 710979, 721028, 771525, 712515, 731205, 702915, 756293, 715331, 774149, 722628,
+    656452, 697283, 734085, 722628, 813572, 732293, 699331, 697860, 744133, 698947, 730885, 702915,
+    751365, 767940, 738885, 722628, 699331, 715331, 752580, 726403, 752580, 736579, 709764, 737860,
+    709764, 698947, 742147, 715526, 718021, 715076, 696515, 760453, 770630, 767684, 727621, 705862,
+    726597, 776131, 765381, 787139, 742595, 737285, 723396, 741635, 742789, 813572, 726597, 770052,
+    696709, 706628, 739395, 723396, 716164, 734085, 697028, 730885, 733315, 736324, 738628, 734404,
+    876100, 765124, 716164, 738628, 775876, 794500, 709764, 812933, 700101, 717764, 718595, 768901,
+    771844, 697283, 768196, 773509, 750213, 722179, 739587, 761925, 790596, 702277, 756293, 697283,
+    713155, 734085, 740035, 782852, 706628, 752837, 735493, 748355, 772100, 728132, 729797, 785413,
+    772678, 700804, 730309, 628996, 764739, 730309, 755587, 748739, 696515, 740035, 723396, 710339,
+    696515, 727427, 767940, 756293, 731843, 712260, 737028, 773829, 734404, 698115, 795973, 768901,
+    770052, 765124, 758340, 753156, 772678, 714181, 709764, 777284, 737860, 220802, 790596, 722628,
+    473219, 710339, 716164, 722372, 736579, 708228, 768196, 738885, 773509, 747460, 737604, 730628,
+    712515, 791108, 791108, 697283, 725317, 748739, 755587, 753156, 791557, 722628, 794500, 699331,
+    738628, 698947, 751942, 730309, 698115, 876357, 755587, 751942, 632708, 798020, 726403, 774469,
+    696515, 730309, 699331, 710531, 726597, 726597, 714820, 705862, 761925, 774149, 731205, 709764,
+    782340, 732293, 740547, 734404, 770052, 748355, 772100, 782852, 772100, 698947, 771525, 834632,
+    767940, 767940, 782084, 706885, 712260, 709764, 782852, 742789, 744837, 710339, 698947, 748355,
+    767940, 744837, 759876, 698947, 782852, 748739, 734085, 698692, 616004, 767940, 746245, 756293,
+    696515, 718021, 733508, 782596, 712963, 712963, 748739, 856260, 748739, 702915, 756293, 731525,
+    722372, 712963, 740035, 783684, 710979, 740035, 740035, 748739, 712963, 699331, 752580, 724741,
+    710531, 798276, 755780, 704388, 742789, 742789, 782852, 739780, 715331, 716164, 775174, 701508,
+    735493, 698947, 701508, 703110, 701252, 612419, 731843, 711812, 743300, 699331, 719492, 730115,
+    730885, 737860, 772357, 705475, 722884, 697860, 751045, 701252, 696515, 721603, 715526, 734917,
+    753602, 797635, 720516, 731525, 736068, 736068, 791108, 766277, 741635, 782340, 782340, 754308,
+    697283, 778244, 714820, 697283, 757765, 765381, 721028, 737860, 726148, 697283, 706246, 705667,
+    742147, 725635, 723909, 734660, 734660, 792261, 749445, 768196, 710979, 778244, 706628, 710979,
+    720516, 776516, 768901, 730309, 739587, 819651, 701508, 704132, 712708, 713541, 720516, 704132,
+    743107, 741380, 710531, 710339, 710979, 778244, 712708, 766020, 701059, 726403, 730309, 708804,
+    735493, 782596, 701765, 739395, 778244, 740547, 743300, 793476, 732293, 739395, 709508, 739395,
+    761925, 710339, 703110, 740547, 748932, 705475, 742147, 737860, 737860, 791557, 710979, 733765,
+    744451, 773060, 708804, 696515, 730309, 810372, 724741, 710339, 696515, 713541, 698947, 709059,
+    743300, 696515, 723909, 698115, 772678, 700804, 720772, 749445, 712260, 769220, 876676, 712260,
+    717060, 810628, 702915, 697283, 746885, 773060, 701508, 741829, 744133, 717060, 811269, 738372,
+    745412, 753602, 741380, 716164, 738885, 706885, 727621, 750213, 710979, 730309, 711812, 712515,
+    710979, 710339, 754565, 741635, 722372, 765381, 704132, 706628, 709508, 704388, 725829, 741061,
+    811269, 737860, 778820, 712260, 760133, 756932, 710531, 748355, 697283, 697283, 699331, 754308,
+    712708, 772357, 705862, 702915, 715331, 729284, 710339, 790852, 746565, 786628, 802436, 719492,
+    741380, 699331, 766277, 756932, 701059, 709764, 735812, 696515, 765381, 755587, 746245, 731525,
+    791108, 739587, 761605, 709764, 703750, 798276, 709252, 702597, 753602, 739780, 731843, 723909,
+    767684, 733765, 761925, 798276, 707909, 484292, 727427, 776131, 698947, 743107, 725829, 717764,
+    740035, 699525, 699331, 706628, 701252, 738885, 764038, 704388, 752837, 811844, 709764, 710979,
+    765381, 779588, 709764, 710979, 805893, 723396, 704132, 701508, 776772, 697860, 761925, 701252,
+    775174, 737285, 708804, 757445, 742595, 710979, 765381, 737860, 772357, 727427, 773315, 712963,
+    701508, 790852, 710339, 778820, 722628, 710531, 752324, 783428, 802692, 765701, 761925, 770630,
+    632003, 754565, 713861, 702915, 715331, 742147, 715331, 765381, 785733, 726597, 765124, 739780,
+    765701, 765701, 698947, 712515, 701508, 741380, 726148, 696515, 741635, 782084, 737285, 696515,
+    773315, 711172, 743107, 712963, 737860, 721603, 745669, 696515, 736068, 761925, 773829, 699331,
+    729797, 801859, 876676, 769220, 642308, 697478, 711812, 800004, 720516, 731843, 768451, 748739,
+    709764, 712260, 737285, 773509, 768901, 740547, 701059, 699331, 734085, 759876, 783428, 726148,
+    706246, 714820, 770630, 788806, 736068, 724741, 709252, 712260, 711812, 721603, 721603, 798276,
+    777284, 776772, 715331, 702277, 710339, 769220, 756293, 876937, 698310, 791557, 715526, 698115,
+    789700, 702915, 701508, 819651, 712260, 758917, 758917, 724741, 740547, 782596, 721285, 779332,
+    720772, 715331, 698947, 700422, 764038, 756932, 745156, 721028, 721028, 737860, 714820, 707909,
+    724741, 766597, 810628, 756293, 748932, 775174, 738628, 702915, 611587, 703110, 771525, 710339,
+    723396, 766597, 696515, 710021, 787139, 707909, 697283, 742147, 715526, 743300, 759876, 726148,
+    761925, 751045, 710339, 727939, 738885, 726597, 708804, 727939, 723396, 791557, 723396, 697283,
+    765701, 734085, 772357, 752580, 805893, 737604, 708485, 706628, 767172, 743107, 715076, 770052,
+    767940, 706628, 727427, 748355, 710339, 762565, 710339, 701508, 798020, 748739, 773315, 756293,
+    708804, 774149, 710339, 774149, 706885, 702083, 740741, 697478, 739395, 759557, 761925, 477763,
+    715331, 767684, 740547, 746245, 773315, 767684, 769220, 721028, 706628, 708804, 712708, 723396,
+    764038, 810116, 735236, 739203, 736324, 706246, 698115, 722179, 776131, 715076, 715331, 746885,
+    704388, 747204, 765381, 619010, 732293, 738885, 716164, 730309, 731205, 774149, 706628, 730309,
+    705667, 712963, 709764, 758084, 775174, 709764, 758084, 779845, 725317, 740547, 696515, 696515,
+    699139, 719492, 699331, 756613, 749188, 696515, 766597, 773509, 710979, 704964, 734660, 788806,
+    706628, 719299, 706246, 709508, 802947, 706246, 706246, 723396, 697283, 723396, 778244, 712260,
+    727172, 726148, 728132, 754308, 765381, 779588, 751045, 768451, 812357, 737860, 778244, 717060,
+    730628, 732293, 732036, 755780, 786884, 734404, 742340, 741635, 741829, 758340, 710531, 743107,
+    716164, 736772, 750213, 696515, 797635, 726403, 731843, 744451, 710979, 704964, 712260, 712260,
+    778244, 779332, 696515, 776772, 730628, 719299, 743556, 730309, 707909, 740547, 737028, 704964,
+    715331, 792899, 726403, 867717, 710979, 696515, 720516, 754115, 727172, 770630, 717060, 781828,
+    698115, 706628, 722179, 759876, 718595, 708804, 720772, 714820, 761028, 773060, 877514, 878149,
+    808455, 771525, 591746, 802436, 797060, 812357, 716164, 717060, 802436, 703110, 710339, 759557,
+    727621, 783940, 705475, 696515, 699331, 750213, 777284, 701508, 802692, 698947, 696515, 699139,
+    808455, 811269, 719044, 802692, 710339, 726597, 726597, 754115, 734085, 784516, 707909, 741635,
+    704964, 786628, 765381, 732293, 720516, 732934, 700101, 726916, 756613, 703750, 732934, 735236,
+    735236, 756932, 765381, 759876, 798276, 704388, 773315, 715331, 712260, 710531, 697283, 698947,
+    712260, 778820, 797060, 738372, 742595, 742595, 710979, 775174, 723396, 752837, 790852, 802436,
+    764421, 736772, 736772, 743107, 765381, 755780, 755780, 710979, 699331, 784197, 704388, 737860,
+    737028, 722372, 748739, 878468, 878726, 722628, 787139, 766020, 787139, 744837, 765701, 765381,
+    718595, 711172, 751942, 766916, 739780, 792004, 720516, 704645, 812357, 761925, 706628, 731525,
+    744837, 754115, 726597, 755780, 710339, 711812, 701252, 748739, 696515, 790852, 723396, 730309,
+    709764, 756613, 800004, 698947, 770630, 824708, 755780, 715526, 879112, 728387, 734085, 700422,
+    734085, 789700, 698947, 709508, 764931, 770052, 726403, 715526, 736324, 782596, 810628, 769220,
+    772100, 768451, 726916, 720772, 800004, 722884, 751365, 707909, 726597, 741635, 728132, 771525,
+    716164, 758340, 741635, 772100, 728387, 718595, 712260, 286211, 716164, 744451, 701252, 745412,
+    723396, 726403, 773509, 747460, 774149, 715331, 730309, 772100, 711812, 732036, 879623, 757188,
+    701252, 734085, 779332, 696515, 778244, 705667, 715331, 715331, 734404, 706885, 758340, 468802,
+    756613, 710979, 728579, 743107, 698947, 698947, 734085, 707205, 752837, 704388, 720772, 721028,
+    763141, 776772, 748355, 748355, 715076, 701252, 702915, 742789, 759876, 721028, 748355, 746565,
+    726597, 734404, 712260, 754308, 715331, 766597, 709764, 715331, 757765, 699331, 772100, 753602,
+    773315, 726148, 713861, 698947, 745669, 761925, 766020, 740547, 742789, 728132, 707205, 769733,
+    734085, 759876, 740547, 734085, 709764, 721028, 740547, 759876, 772100, 727621, 731843, 707205,
+    734085, 707205, 704388, 720772, 748355, 701252, 742789, 772100, 721028, 698947, 715331, 731843,
+    757765, 699331, 766020, 728132, 740547, 724741, 705220, 728132, 753411, 721028, 712260, 732613,
+    698947, 739395, 709508, 789700, 731843, 697478, 782084, 746245, 705475, 720516, 706628, 720772,
+    749445, 730885, 706628, 755076, 701508, 746885, 755587, 739587, 698947, 748739, 712260, 712708,
+    741635, 746885, 712963, 697478, 749445, 712260, 718788, 722372, 715331, 696515, 748739, 721028,
+    715331, 704388, 715331, 704388, 704388, 696709, 710979, 701508, 701508, 724741, 730885, 698947,
+    770052, 738628, 735493, 730885, 749188, 786628, 740035, 706628, 696515, 727172, 738628, 714820,
+    766597, 697283, 730628, 698947, 738628, 714820, 753602, 734660, 705667, 734404, 760133, 727621,
+    714820, 736324, 721028, 756293, 792899, 773509, 704132, 712260, 774790, 734917, 736324, 732613,
+    743300, 730309, 719044, 792004, 740035, 696515, 696515, 755587, 720516, 743107, 727621, 743107,
+    740035, 750213, 708804, 721798, 766020, 730115, 730115, 742789, 739395, 710979, 746245, 790852,
+    732293, 731843, 733765, 710339, 787332, 779332, 740229, 719492, 696515, 710531, 768451, 712708,
+    730628, 696515, 740547, 775557, 713541, 741635, 774790, 740547, 728579, 787139, 754883, 786628,
+    708804, 742147, 711812, 744133, 740035, 738628, 696515, 764038, 743107, 701252, 749188, 724741,
+    701252, 745988, 749188, 766020, 720772, 746245, 737860, 706628, 782084, 699844, 715526, 709764,
+    727621, 700101, 731205, 748355, 742789, 745669, 753602, 737860, 704388, 774790, 778820, 722179,
+    740741, 746565, 720516, 767940, 708804, 753734, 747716, 712515, 699331, 773829, 698947, 712260,
+    727172, 755587, 754565, 753602, 753734, 753602, 701252, 725829, 709508, 726403, 707909, 704132,
+    725317, 714181, 726148, 783109, 748547, 699331, 756293, 703110, 706628, 730309, 712515, 712515,
+    790596, 760133, 728387, 737860, 710021, 795653, 750213, 795653, 773315, 880072, 732934, 811269,
+    706628, 776131, 740547, 702277, 756293, 722884, 723909, 738885, 756932, 720516, 724547, 696515,
+    719299, 714820, 735493, 701059, 797317, 707909, 773509, 779845, 698947, 697283, 758340, 804421,
+    709764, 710531, 761605, 734085, 721798, 727939, 809860, 731525, 708485, 703750, 734085, 708804,
+    752580, 729284, 727621, 756613, 744837, 763141, 699331, 790852, 740035, 763141, 701252, 738885,
+    701252, 755076, 782084, 753602, 706246, 731843, 723396, 710979, 728387, 725829, 734085, 697283,
+    699331, 730115, 707526, 715331, 737860, 714820, 748547, 730309, 752324, 773509, 706885, 708804,
+    733765, 704388, 725317, 738885, 705475, 767428, 697283, 748932, 702083, 709764, 482116, 773829,
+    809860, 766597, 734085, 726403, 754115, 764038, 737604, 705475, 761925, 748355, 756293, 748355,
+    715331, 705862, 773509, 856260, 742595, 755587, 766277, 720516, 731525, 701252, 770630, 765381,
+    723396, 718788, 786628, 734404, 734404, 470658, 699331, 756613, 702277, 761605, 710339, 698115,
+    698115, 697478, 711812, 880580, 734085, 727939, 706628, 726597, 783428, 767684, 764739, 707205,
+    715331, 708485, 768451, 768451, 728132, 711812, 696515, 712963, 770630, 769220, 732613, 741380,
+    782084, 707909, 740035, 807301, 797827, 715331, 743300, 775876, 721798, 787332, 772100, 753602,
+    761925, 721028, 698947, 769476, 724741, 726403, 737860, 700422, 725635, 819844, 712708, 701252,
+    714181, 758340, 819844, 728387, 763654, 738885, 768196, 758340, 771525, 722884, 731525, 698947,
+    724741, 710021, 726597, 796804, 696515, 736579, 742789, 701059, 737860, 782084, 696515, 696515,
+    766597, 774469, 796804, 731525, 804421, 724547, 712963, 740229, 752580, 702083, 761925, 734085,
+    699331, 734085, 737860, 773315, 711812, 738885, 757188, 767940, 727621, 709764, 707909, 710339,
+    696515, 752837, 711812, 704388, 753602, 700101, 709764, 700101, 734404, 707909, 774469, 714820,
+    772100, 773509, 737604, 711812, 795973, 757188, 696515, 771525, 742789, 704388, 773315, 704388,
+    698947, 696709, 710979, 727172, 698947, 706628, 730885, 786628, 774469, 766597, 697283, 730628,
+    738628, 696515, 738628, 698947, 727172, 770630, 737860, 764739, 710531, 705667, 792899, 740035,
+    753602, 729284, 774790, 760133, 706885, 743300, 734917, 721028, 721798, 739395, 743107, 713541,
+    754883, 701252, 774790, 712708, 755587, 750213, 731843, 809860, 742147, 740547, 775557, 741635,
+    696515, 696515, 753734, 767940, 701252, 778820, 701252, 706628, 737860, 703110, 708804, 727621,
+    782084, 709508, 766020, 746245, 720772, 738628, 696515, 721798, 699844, 715526, 749188, 755076,
+    740035, 740229, 706628, 712515, 699331, 714181, 712515, 790596, 704132, 737860, 753734, 795653,
+    760133, 736579, 761605, 708804, 809860, 767428, 773315, 708485, 756932, 776131, 709764, 699331,
+    811269, 727621, 735493, 707909, 703750, 779845, 722884, 758340, 727621, 696515, 748932, 773509,
+    790852, 764038, 734085, 723396, 856260, 702083, 709764, 753602, 699331, 738885, 707909, 730115,
+    748547, 704388, 731843, 748355, 763141, 740035, 698115, 771525, 755587, 723396, 734404, 766277,
+    701252, 731525, 748355, 728132, 769220, 711812, 734085, 880580, 758340, 819844, 714181, 772100,
+    728387, 721028, 704388, 731525, 742789, 773509, 739395, 731525, 730885, 697860, 721028, 730885,
+    741380, 730885, 715331, 715331, 737604, 721285, 741380, 773315, 774149, 776516, 770630, 697283,
+    701252, 710339, 769733, 709252, 741635, 716164, 730309, 706885, 712260, 712260, 704388, 704388,
+    701252, 722372, 737860, 792004, 696515, 792004, 696515, 734085, 728579, 751684, 753602, 753411,
+    715526, 795973, 734085, 797060, 699844, 740547, 710979, 715331, 767172, 752580, 699331, 708804,
+    717060, 650691, 715331, 726403, 736068, 795973, 767172, 731843, 757445, 734660, 696515, 712260,
+    755780, 751045, 741380, 701059, 719299, 701059, 766020, 719299, 704132, 735493, 698115, 702915,
+    736772, 795973, 802436, 743107, 743107, 698115, 741380, 776772, 710339, 739587, 741635, 699331,
+    717060, 784197, 776772, 701059, 715331, 753602, 710531, 698115, 722372, 758340, 795973, 721028,
+    737028, 750213, 765381, 710979, 761028, 749445, 819651, 696515, 792261, 709252, 794500, 737604,
+    770052, 758340, 707205, 727939, 712260, 722179, 708804, 701252, 741635, 730115, 722179, 720772,
+    763141, 752837, 717764, 732036, 763654, 753734, 756932, 793476, 696515, 720516, 776772, 743107,
+    741635, 748739, 727172, 737860, 699844, 763141, 730115, 717060, 740035, 821252, 724741, 699844,
+    787589, 734085, 761925, 738628, 750213, 743300, 767172, 767172, 701508, 743300, 707526, 787589,
+    740229, 747460, 757188, 702915, 773509, 697478, 757765, 735236, 795333, 730309, 717764, 734085,
+    722884, 710979, 758340, 702277, 702277, 712260, 698947, 698310, 773315, 728132, 774790, 792581,
+    722628, 755780, 749764, 747460, 706885, 729797, 698947, 763654, 736579, 700422, 710979, 766020,
+    771268, 727939, 721285, 709764, 745412, 773509, 700422, 712260, 804421, 756613, 722628, 807301,
+    758340, 757188, 704388, 462210, 766597, 709252, 761925, 728132, 719492, 730309, 787589, 741635,
+    773315, 716164, 704388, 690373, 738885, 774149, 719492, 787589, 737028, 750213, 710979, 765381,
+    749445, 761028, 727939, 730309, 745412, 698310, 737604, 712260, 770052, 758340, 794500, 752837,
+    766020, 708804, 707205, 722179, 734085, 741635, 701252, 793476, 720772, 753734, 732036, 776772,
+    756932, 743107, 696515, 821252, 712260, 717060, 737860, 730115, 727172, 748739, 787589, 730115,
+    699844, 728132, 701508, 750213, 747460, 743300, 710979, 773315, 698947, 716164, 697478, 702915,
+    741635, 740229, 795333, 735236, 758340, 747460, 706885, 804421, 710979, 700422, 771268, 727939,
+    704388, 757188, 722628, 807301, 766597, 761925, 719492, 720516, 715331, 743300, 752324, 774790,
+    715331, 703110, 743107, 703110, 711812, 697860, 737285, 809860, 809860, 710339, 705220, 710979,
+    775557, 719492, 697283, 766597, 772678, 726148, 730309, 742147, 721028, 726148, 720516, 742147,
+    773509, 773509, 790852, 710531, 732613, 731843, 797827, 742789, 708804, 782340, 740547, 706628,
+    720516, 722372, 778820, 718788, 742147, 746565, 726916, 773060, 719492, 766916, 753411, 715331,
+    775557, 755587, 714820, 710531, 734085, 789444, 737285, 797317, 738116, 748739, 740547, 771525,
+    701508, 730115, 742595, 740547, 720516, 764038, 714181, 714181, 734404, 768196, 742595, 472642,
+    757188, 757188, 754883, 797635, 755780, 754115, 776131, 735812, 721028, 710979, 773509, 734085,
+    734917, 712260, 733315, 782340, 797635, 697283, 782340, 705862, 730628, 771844, 696515, 788485,
+    732293, 779588, 728579, 790852, 754115, 730628, 717060, 742147, 786884, 788228, 708804, 710531,
+    790852, 712260, 710979, 779332, 710531, 766597, 726403, 739587, 710531, 710339, 723140, 696515,
+    698947, 732036, 730309, 724741, 726916, 708804, 700422, 747716, 822790, 720772, 772678, 782852,
+    715526, 720516, 748739, 740741, 712260, 717060, 746565, 761028, 734085, 724547, 714181, 741635,
+    730309, 726916, 698947, 710531, 698947, 716164, 776323, 722372, 710021, 798020, 756293, 726403,
+    720516, 802692, 748932, 716164, 754565, 744133, 724741, 700101, 714181, 650243, 724547, 734085,
+    697283, 763459, 709764, 708485, 706628, 698947, 798020, 710531, 710531, 729284, 748739, 760453,
+    744837, 710531, 720516, 709252, 746245, 742595, 726403, 754883, 700804, 698947, 753411, 757445,
+    761028, 757445, 758917, 712260, 721798, 741061, 712260, 699331, 726916, 735812, 762565, 744133,
+    706246, 731843, 711812, 773509, 824326, 734085, 778820, 737285, 710531, 710979, 754883, 790852,
+    763141, 811844, 767428, 742595, 749188, 753156, 734085, 758084, 783684, 715331, 724547, 705862,
+    742789, 741061, 742595, 698947, 798020, 769220, 777284, 742789, 741635, 775174, 748739, 721285,
+    753411, 737285, 732036, 766916, 715331, 782852, 698947, 712260, 705862, 731843, 769220, 757445,
+    726597, 740741, 768196, 788485, 782596, 753156, 710531, 769733, 814149, 730309, 770052, 722372,
+    706246, 735812, 726403, 753411, 754883, 721028, 721028, 708228, 724547, 714181, 714181, 728387,
+    721285, 735812, 784773, 753734, 768196, 724547, 741635, 741635, 708804, 710531, 722372, 714181,
+    735812, 700101, 698947, 712515, 782340, 788485, 708228, 773060, 712260, 710339, 712260, 707909,
+    722372, 764421, 709764, 774149, 708228, 730309, 709764, 824005, 772100, 723396, 774469, 723396,
+    798020, 724547, 740547, 706628, 784773, 824005, 715331, 782852, 721028, 708228, 727621, 749445,
+    709508, 880839, 740547, 742595, 726916, 726916, 749445, 754565, 881286, 759876, 736579, 698947,
+    710531, 737285, 881671, 465603, 704388, 882121, 724229, 740547, 783428, 745156, 730628, 712260,
+    882695, 742595, 758084, 882695, 699331, 738372, 705667, 720772, 754308, 709764, 782340, 736324,
+    731843, 738885, 770052, 727172, 766597, 705862, 716164, 792004, 773509, 732036, 753602, 719299,
+    697283, 776772, 805893, 727172, 770052, 700422, 730885, 712963, 740547, 764038, 731843, 732293,
+    732036, 768451, 750213, 770052, 753734, 789188, 758917, 737028, 739395, 754115, 786884, 708804,
+    765701, 753602, 728579, 740229, 712260, 734404, 776131, 696515, 761605, 701252, 713541, 722179,
+    772678, 710531, 724741, 747974, 748739, 781124, 746245, 740229, 749764, 712260, 743300, 703750,
+    699331, 741829, 704132, 795973, 722628, 711812, 709252, 710979, 761605, 740229, 739587, 732293,
+    711812, 742789, 703750, 730115, 737860, 723909, 797317, 798276, 747204, 696515, 758917, 731525,
+    704964, 788485, 763141, 707205, 737028, 729284, 755332, 703750, 805893, 811844, 698947, 733765,
+    730309, 707526, 768644, 802692, 705475, 704132, 730885, 716164, 710979, 730115, 710979, 736068,
+    760772, 742789, 699331, 736068, 786628, 739395, 750213, 697860, 765381, 748739, 705220, 732613,
+    700422, 709764, 755076, 872901, 738372, 724741, 742789, 728387, 745156, 734660, 770052, 708228,
+    705475, 797827, 747204, 795973, 696515, 698947, 775876, 722179, 699331, 730309, 730885, 767940,
+    735236, 710339, 710339, 748739, 708228, 738372, 705667, 720772, 738885, 731843, 727172, 700422,
+    753602, 729284, 805893, 730885, 754115, 776131, 748739, 740229, 712260, 696515, 712963, 750213,
+    710339, 734404, 761605, 701252, 749764, 712260, 724741, 740229, 746245, 748739, 724741, 711812,
+    710979, 703750, 788485, 737028, 737860, 704964, 723909, 703750, 797317, 730115, 768644, 710979,
+    698947, 736068, 716164, 735236, 765381, 697860, 742789, 748739, 738372, 708228, 736324, 739395,
+    742147, 742147, 701059, 789700, 752837, 727427, 727427, 742147, 718788, 739780, 752837, 752837,
+    752837, 480387, 752837, 739780, 742147, 752837, 752837, 773509, 718595, 751365, 751365, 750213,
+    797317, 797317, 883143, 728773, 752837, 752837, 883588, 883843, 721285, 787139, 707909, 719492,
+    731525, 699331, 699331, 697283, 738628, 696515, 798276, 717764, 697283, 764931, 723909, 717060,
+    779845, 761925, 742595, 722628, 728132, 792261, 705667, 770052, 712515, 787139, 753602, 795973,
+    711812, 884039, 728132, 765381, 709764, 726597, 720516, 738372, 739587, 746565, 712260, 696515,
+    751942, 717060, 773509, 732036, 722179, 731843, 791363, 723909, 790852, 727939, 786628, 716164,
+    730628, 884484, 710021, 737860, 731205, 704132, 748355, 740741, 698947, 711172, 696515, 706628,
+    804421, 773060, 738628, 753156, 760133, 778820, 786628, 713861, 751684, 739587, 735812, 751942,
+    738885, 738628, 699139, 704132, 756293, 701508, 723652, 708804, 701508, 731843, 731843, 776323,
+    710021, 742595, 776516, 696515, 711812, 731205, 747974, 712515, 701252, 703110, 755587, 768196,
+    802692, 706885, 726597, 766916, 737860, 710339, 884741, 747460, 760772, 783684, 721028, 740741,
+    795973, 732036, 748739, 704132, 740229, 728132, 699331, 797317, 782852, 709764, 742595, 696515,
+    787139, 765381, 759876, 741635, 767428, 699331, 771525, 722884, 735812, 722884, 704388, 707526,
+    720516, 742595, 699331, 701252, 750213, 704132, 722628, 753602, 752837, 798276, 753602, 697860,
+    764038, 701508, 758084, 787139, 709764, 885063, 696515, 766020, 734404, 722372, 777284, 738628,
+    724547, 731843, 720516, 765381, 755587, 724547, 731525, 469954, 734404, 707205, 698310, 768451,
+    701252, 750531, 720516, 755587, 768196, 711812, 735812, 731843, 783428, 720516, 783684, 708228,
+    769476, 810628, 731525, 738885, 699331, 696515, 712515, 721285, 710531, 701252, 741635, 734404,
+    717764, 723396, 722884, 795973, 742789, 773829, 722179, 785413, 752837, 752837, 727427, 710339,
+    765381, 734404, 759876, 710339, 696515, 725829, 769733, 697283, 744133, 766597, 741380, 699331,
+    791557, 718595, 772678, 473794, 753734, 710979, 724229, 767172, 734917, 746885, 719299, 736324,
+    727621, 766277, 765381, 781828, 737604, 723396, 766277, 712515, 710531, 704132, 741380, 771844,
+    701508, 741635, 735236, 701765, 741829, 737028, 744451, 731843, 809860, 702083, 708228, 753734,
+    720772, 708804, 701252, 754115, 699331, 699844, 705475, 715331, 712260, 698947, 738628, 751684,
+    746885, 724741, 715331, 720772, 767428, 754565, 717060, 750725, 721798, 885510, 722628, 704388,
+    703110, 776516, 720516, 748739, 710979, 712515, 710979, 790596, 752580, 754565, 717060, 795333,
+    754308, 761925, 726403, 715331, 737028, 710339, 722628, 699139, 756293, 697283, 701059, 761605,
+    798276, 740229, 758340, 809860, 713541, 747460, 739587, 773509, 704132, 699139, 715526, 709508,
+    710531, 744133, 714181, 696515, 773315, 704388, 779588, 762884, 737028, 753602, 699331, 759557,
+    699331, 722628, 751684, 782852, 715526, 715526, 765124, 737285, 745669, 715331, 718595, 722372,
+    730115, 809860, 710531, 712515, 715526, 722628, 752580, 744133, 741635, 698115, 732613, 710979,
+    721285, 714820, 700422, 712963, 714181, 704388, 698310, 770052, 714181, 740035, 769733, 741635,
+    736324, 741635, 814149, 709764, 721798, 776772, 766597, 708228, 791363, 709508, 772357, 702083,
+    800004, 750725, 706885, 772357, 809860, 753734, 726597, 757188, 707909, 704132, 697283, 704388,
+    707909, 757188, 732293, 795973, 715331, 706885, 757188, 710339, 704132, 696709, 701508, 708485,
+    735236, 740229, 696515, 792261, 699331, 705220, 704388, 789700, 776772, 709508, 740035, 723652,
+    750213, 734660, 885899, 468802, 722628, 771844, 757765, 788228, 730628, 742340, 741380, 739395,
+    755587, 791363, 700101, 749764, 745669, 759876, 797317, 700101, 704132, 731205, 712260, 730309,
+    770630, 703110, 714820, 776323, 756293, 779588, 754115, 787909, 767940, 728387, 789700, 725060,
+    704388, 715331, 810886, 709764, 748739, 772100, 704388, 786628, 735236, 742789, 762565, 758340,
+    789700, 797317, 753411, 802436, 742595, 699525, 773315, 712963, 740035, 746885, 758340, 705220,
+    762565, 722628, 791363, 776131, 765124, 748355, 746245, 700101, 783684, 722628, 744133, 789188,
+    712708, 725060, 737285, 770630, 768901, 766597, 699331, 710339, 768196, 745156, 696515, 770630,
+    728387, 724741, 715331, 758340, 791363, 751365, 696515, 710339, 710531, 724741, 696515, 810886,
+    718595, 738628, 700101, 704388, 732293, 748355, 748355, 810886, 736324, 724741, 715331, 748355,
+    704388, 752837, 761028, 701252, 704132, 701252, 701252, 710339, 706246, 702083, 703750, 710339,
+    728132, 728132, 701508, 696515, 735493, 758084, 775557, 696709, 791363, 701508, 743107, 710979,
+    750213, 712260, 728579, 718021, 710979, 711172, 735493, 766597, 714181, 743556, 707526, 730115,
+    770052, 699331, 782084, 741380, 749445, 697283, 791557, 802947, 731843, 702915, 715331, 696515,
+    751045, 701252, 776323, 715331, 739203, 731525, 886601, 734085, 701059, 702083, 714820, 728579,
+    734917, 773509, 746885, 723652, 782340, 704388, 710979, 701059, 719299, 736324, 753602, 721028,
+    735812, 766020, 714820, 731525, 739780, 698947, 727172, 732613, 810116, 734660, 722628, 698947,
+    726148, 701059, 798276, 730885, 714820, 754308, 701252, 696515, 706246, 798020, 699844, 802947,
+    737604, 771844, 724229, 887175, 887622, 888006, 607555, 726916, 730115, 739587, 776323, 701252,
+    771012, 739395, 753602, 732293, 701765, 696515, 739395, 728579, 701059, 699331, 702915, 742340,
+    699139, 704132, 741061, 717060, 750213, 701252, 701252, 793476, 710531, 742789, 701252, 743300,
+    738885, 775557, 755780, 743107, 741635, 763654, 722372, 701252, 726403, 708804, 720516, 764931,
+    791363, 731205, 731525, 710979, 761285, 777284, 731525, 710979, 710339, 782340, 701059, 733765,
+    739780, 726403, 706628, 766020, 716164, 721798, 725635, 739587, 798020, 731843, 717060, 725635,
+    709508, 727621, 696515, 702915, 753734, 744643, 737028, 734085, 731205, 746885, 730885, 724741,
+    720516, 722179, 705475, 701765, 701252, 748932, 717060, 714820, 754308, 740229, 775174, 697478,
+    731205, 698115, 767940, 698947, 699331, 740035, 727172, 769476, 712260, 772678, 766597, 720516,
+    730309, 746885, 746245, 701059, 793476, 708804, 751684, 745669, 747716, 734404, 730309, 730309,
+    736772, 754308, 749188, 745156, 698947, 701059, 718595, 748355, 696515, 714820, 747974, 748547,
+    888389, 704132, 797827, 697028, 731525, 718021, 888712, 736579, 735493, 741380, 791108, 701252,
+    785093, 701508, 756293, 711812, 736772, 787589, 742595, 783684, 778820, 749445, 706246, 776516,
+    748739, 779588, 754565, 709252, 736324, 722628, 737028, 712515, 755587, 699331, 707909, 725829,
+    726403, 741380, 717060, 730885, 784773, 706885, 707909, 709252, 712260, 702915, 738885, 712515,
+    712515, 746565, 749445, 708485, 803140, 746885, 731525, 727621, 741380, 803140, 723396, 722372,
+    721798, 710531, 730309, 793476, 791557, 728387, 710339, 779332, 791108, 703110, 790596, 710339,
+    819651, 806214, 708804, 286532, 776323, 777284, 789700, 710531, 732934, 765381, 734085, 778501,
+    735493, 786628, 732934, 729284, 747716, 732293, 737028, 748739, 710339, 705862, 783940, 759557,
+    771012, 789700, 758917, 748547, 701059, 730309, 705667, 804421, 767940, 791874, 759237, 724547,
+    802436, 773315, 709764, 758597, 730115, 721798, 760772, 798020, 702277, 802692, 758340, 696709,
+    697283, 731525, 708485, 697283, 699331, 728132, 707205, 776772, 732934, 757765, 715331, 726597,
+    786628, 739395, 783684, 716164, 750213, 748739, 772357, 748739, 782084, 756613, 800259, 747460,
+    797060, 889223, 699139, 711172, 709252, 757188, 466370, 800451, 730309, 478148, 737604, 703750,
+    618178, 733508, 781124, 761925, 731843, 726597, 798276, 730309, 773315, 776323, 709764, 702277,
+    710979, 811844, 698947, 753602, 811844, 773509, 742595, 712708, 730885, 733765, 764421, 697860,
+    699331, 785093, 746885, 764038, 709764, 710979, 775557, 749188, 700804, 743300, 730885, 782852,
+    757765, 748932, 714181, 714181, 751684, 723652, 702277, 795973, 702083, 771012, 734085, 764421,
+    734085, 760133, 782852, 712963, 738885, 726916, 706628, 750213, 752837, 706246, 730115, 736772,
+    702083, 779588, 755332, 736579, 706628, 477508, 779588, 797317, 724547, 766277, 697860, 726597,
+    766916, 715331, 777284, 754883, 702083, 796804, 785733, 765701, 737285, 766916, 705862, 705475,
+    795653, 758597, 726403, 779332, 733315, 741635, 704645, 778501, 730115, 712515, 761925, 764038,
+    746565, 777284, 781124, 813572, 719044, 765124, 709252, 752580, 750213, 750213, 759876, 696515,
+    765381, 737285, 798020, 715331, 717060, 705475, 721603, 759557, 888712, 887175, 766916, 612419,
+    469508, 477123, 719299, 748739, 766916, 764739, 754883, 784197, 736772, 707205, 738885, 726597,
+    777925, 768451, 769220, 698115, 759876, 741635, 709764, 777284, 731843, 714181, 702277, 696515,
+    748739, 768451, 785093, 705862, 738116, 697283, 732934, 737285, 769220, 744133, 707909, 706885,
+    710021, 756613, 716164, 783428, 736772, 702277, 774149, 757188, 734085, 800004, 710339, 697860,
+    715331, 724229, 616966, 740741, 628485, 477508, 721798, 748739, 782084, 698947, 728387, 737860,
+    705862, 791363, 708228, 766916, 736772, 698692, 703110, 782852, 702915, 777284, 787332, 725317,
+    710724, 722884, 770052, 714181, 746245, 733765, 737285, 715526, 721028, 724741, 769476, 721285,
+    706628, 745156, 775876, 758340, 757188, 698947, 734085, 706246, 769733, 705667, 761925, 775876,
+    721028, 796804, 742595, 728387, 793476, 617539, 593093, 696709, 766597, 781124, 734085, 756932,
+    722884, 748739, 759557, 737860, 699331, 726597, 708485, 714181, 731525, 708485, 767940, 741635,
+    793476, 795973, 702083, 726916, 798276, 771525, 710531, 734660, 787139, 737028, 696515, 736579,
+    757445, 738628, 735493, 708804, 725317, 804421, 698947, 798020, 724547, 737604, 761605, 767172,
+    761925, 782852, 743813, 740035, 734085, 734085, 889669, 749188, 712260, 715076, 710339, 767940,
+    698947, 824005, 753734, 697478, 758917, 727427, 773315, 795653, 797317, 748739, 756613, 755780,
+    748739, 614340, 664196, 774149, 753602, 748739, 736324, 734085, 772100, 726403, 734085, 734404,
+    707909, 715526, 734085, 715331, 707205, 729797, 798020, 767940, 784773, 740547, 779332, 759876,
+    824005, 718021, 768196, 798020, 721028, 737285, 716164, 772100, 728132, 701508, 696515, 750213,
+    696709, 775557, 791363, 735493, 776323, 731525, 707526, 766597, 698947, 770052, 735493, 733508,
+    739203, 715331, 751045, 746885, 699844, 699139, 771844, 710531, 735812, 732613, 706246, 719299,
+    737028, 778501, 739780, 731525, 734404, 726148, 754308, 712515, 766020, 724229, 734917, 737604,
+    723652, 810116, 728579, 699331, 731525, 721798, 731525, 739395, 726403, 753602, 791363, 699139,
+    728579, 782340, 824005, 725635, 709508, 717060, 741061, 704132, 793476, 726403, 732293, 795653,
+    731525, 777284, 755780, 701252, 738885, 743300, 741635, 739587, 701059, 726916, 746885, 697028,
+    745156, 722179, 791557, 705667, 704132, 703110, 717060, 702083, 787332, 712260, 771525, 731205,
+    748739, 735493, 714820, 781124, 712708, 708804, 730309, 725829, 703492, 730309, 782084, 746245,
+    719044, 717060, 734404, 705475, 745669, 721798, 797827, 724741, 696515, 707909, 775174, 737285,
+    748547, 714820, 718595, 708804, 745156, 728387, 712515, 747460, 819651, 726597, 785093, 756293,
+    766916, 710339, 721798, 722372, 798276, 790596, 793476, 736772, 783684, 803140, 706885, 736324,
+    777284, 781124, 734085, 791108, 742595, 754565, 731525, 710531, 791874, 705862, 726916, 757765,
+    783684, 776772, 760772, 697283, 800451, 747716, 802436, 732934, 715331, 739395, 759876, 758597,
+    804421, 728132, 712260, 730309, 759557, 737604, 783940, 758340, 696709, 734085, 710531, 772357,
+    730115, 700804, 761925, 781124, 705862, 760133, 753602, 749188, 714181, 706246, 764421, 765124,
+    764038, 795973, 736579, 773315, 779588, 783428, 730115, 756932, 779588, 698115, 750213, 726403,
+    705475, 798020, 737285, 759557, 798020, 719299, 777284, 752580, 766277, 696515, 717060, 767172,
+    765701, 756613, 737285, 769220, 759876, 740741, 744133, 710021, 731843, 754883, 738885, 777284,
+    758340, 721028, 721285, 728387, 748739, 775876, 772100, 728387, 784773, 705862, 769733, 737604,
+    767940, 795973, 708485, 726597, 696515, 749188, 756613, 727427, 707909, 715526, 732934, 732934,
+    705220, 768451, 790852, 740547, 721285, 748355, 732934, 733508, 721603, 822406, 766597, 737604,
+    733508, 704388, 741635, 741380, 741635, 698692, 781124, 724229, 740741, 730885, 837700, 698692,
+    730309, 730309, 734085, 771012, 697860, 889989, 776516, 712708, 787332, 734085, 758917, 697860,
+    732293, 752837, 741635, 837700, 743107, 707205, 705475, 705475, 713155, 722372, 730885, 720772,
+    771012, 797827, 802436, 754565, 748739, 725829, 719044, 710531, 782340, 782340, 707909, 740547,
+    708228, 732934, 825605, 802436, 704388, 771012, 704388, 753602, 755076, 699331, 736068, 715526,
+    755780, 715526, 740547, 734404, 736068, 739780, 748547, 709764, 714820, 810372, 789444, 772100,
+    773315, 464388, 890311, 757765, 798020, 724547, 781124, 743107, 789444, 781383, 707205, 776516,
+    697283, 782852, 737285, 707205, 768901, 747204, 715331, 737860, 707909, 701059, 771525, 795973,
+    724547, 736068, 602754, 733508, 822406, 766597, 704388, 741380, 741635, 736068, 781383, 837700,
+    709764, 730309, 730885, 734085, 771012, 724229, 733508, 712708, 787332, 720772, 736068, 724547,
+    771012, 748739, 781124, 713155, 705475, 743107, 802436, 705220, 782340, 754565, 773315, 699331,
+    704388, 732934, 715331, 736068, 755076, 704388, 753602, 707909, 772100, 740547, 737860, 810372,
+    789444, 705475, 757765, 724547, 789444, 747204, 795973, 710979, 710979, 713347, 775876, 736324,
+    731525, 712515, 696515, 730628, 714820, 733765, 776516, 753602, 763654, 739780, 735236, 785093,
+    722628, 805893, 712260, 701059, 710021, 734917, 733765, 714820, 750213, 706628, 703110, 753602,
+    740547, 731843, 754883, 742340, 741061, 732293, 791874, 730628, 730628, 791363, 701765, 710979,
+    698947, 748739, 774149, 773509, 746885, 726916, 783428, 698115, 770630, 716164, 726916, 730309,
+    722179, 720772, 699331, 699844, 766597, 738628, 714181, 746885, 762565, 710979, 741635, 697860,
+    766597, 763654, 712260, 728387, 776516, 765381, 750213, 722372, 730309, 723652, 798020, 722628,
+    730309, 735236, 735236, 809860, 696515, 775876, 729284, 714820, 710531, 758597, 773509, 701059,
+    732293, 786628, 730309, 748739, 763654, 730309, 714820, 708804, 733765, 728773, 766597, 775174,
+    704388, 714820, 699331, 742595, 699331, 774149, 709764, 709764, 798020, 775876, 722884, 748547,
+    764038, 706628, 722884, 714820, 699844, 704388, 737860, 705475, 722628, 712515, 709764, 736579,
+    715331, 737285, 698947, 698310, 758084, 768451, 715331, 714820, 601986, 810628, 708228, 753156,
+    769733, 701059, 722884, 722884, 699331, 730309, 734660, 739587, 722179, 698947, 758084, 715331,
+    714820, 753602, 737860, 774149, 715331, 710339, 710339, 710339, 804421, 743107, 712260, 779845,
+    754308, 752324, 696515, 789444, 704388, 731525, 705667, 774790, 705667, 698947, 739395, 795973,
+    712260, 766020, 754308, 742147, 744133, 710531, 722372, 712963, 764931, 759876, 699331, 700101,
+    735493, 722884, 741380, 737604, 790151, 707205, 722372, 764931, 744133, 698947, 715331, 700101,
+    777284, 710339, 752324, 718788, 764931, 698947, 698947, 699331, 699331, 718788, 719299, 784516,
+    797827, 725635, 736068, 734660, 713861, 722628, 710339, 710339, 789188, 732293, 767940, 748547,
+    755780, 772357, 741061, 771525, 746885, 712515, 775557, 740035, 698947, 725635, 773509, 756293,
+    712708, 725829, 750213, 735236, 779588, 732293, 697283, 700101, 737604, 719044, 756932, 785733,
+    742789, 714820, 739587, 700101, 792004, 708228, 620228, 702597, 761925, 712515, 732293, 790151,
+    732293, 697860, 730885, 714820, 698115, 717764, 722628, 777284, 772357, 767172, 712515, 709764,
+    737604, 714820, 715331, 696515, 736579, 709508, 769733, 730309, 699331, 748739, 774149, 732036,
+    698947, 713861, 733765, 728579, 701059, 709764, 469508, 715331, 698947, 717764, 772357, 772357,
+    767940, 710339, 737604, 736579, 756932, 732036, 774149, 732293, 736579, 706885, 710339, 755780,
+    480258, 743107, 743107, 701765, 761605, 761605, 710021, 757765, 750213, 710021, 703110, 761605,
+    710021, 710021, 741061, 710021, 757765, 756932, 756932, 697028, 748355, 699525, 699525, 786884,
+    702083, 757765, 737860, 702083, 705475, 696709, 749188, 734085, 727172, 731843, 773315, 712515,
+    727172, 726148, 728132, 718788, 810116, 728579, 714820, 733315, 719299, 698115, 754883, 787139,
+    739780, 696515, 734404, 786628, 730628, 717060, 730885, 786884, 733765, 890757, 714820, 717060,
+    786628, 698947, 723396, 748547, 748547, 745988, 749445, 891079, 787139, 714181, 725829, 709252,
+    761925, 722884, 746565, 714181, 738885, 759237, 740741, 724547, 698310, 701765, 810372, 710531,
+    727427, 723396, 811844, 766277, 696515, 701508, 701508, 743107, 756293, 725635, 710531, 734085,
+    752837, 731843, 734085, 891523, 786628, 766020, 724547, 737028, 723396, 779332, 705475, 741635,
+    810372, 737285, 783428, 720772, 714181, 718788, 698947, 734085, 770630, 707909, 787139, 737604,
+    730309, 731525, 773315, 744451, 734085, 772100, 709764, 727172, 710979, 779588, 759557, 705475,
+    709764, 714181, 741380, 732934, 623171, 811844, 738628, 743300, 709764, 705475, 737028, 786628,
+    766020, 722628, 483651, 741635, 709764, 737860, 773315, 744451, 773315, 709764, 727172, 710979,
+    741380, 709764, 722628, 786628, 705220, 705220, 730309, 723396, 730309, 698947, 714820, 764931,
+    722628, 741829, 713347, 758917, 725317, 761925, 722628, 758917, 714820, 714820, 715526, 712963,
+    702083, 696709, 761605, 779332, 715526, 792581, 741380, 740035, 696515, 740035, 739395, 760133,
+    782852, 697283, 792261, 699331, 709252, 739780, 735812, 731843, 709508, 779332, 791363, 732293,
+    750531, 710021, 767940, 743107, 714181, 753602, 753602, 709764, 706628, 778820, 727621, 696515,
+    727621, 725060, 775876, 763141, 713541, 767940, 710979, 717060, 723652, 737860, 782852, 717060,
+    713347, 757765, 703110, 761925, 754308, 712963, 741380, 710021, 753156, 753156, 794500, 747460,
+    753156, 730115, 730115, 758597, 696709, 747460, 704388, 741380, 734085, 776131, 738116, 751942,
+    726148, 696515, 771268, 742595, 753602, 753602, 704388, 755076, 747204, 744133, 700422, 704388,
+    730309, 736324, 696515, 765381, 704645, 741061, 741061, 770630, 754115, 767940, 721285, 768901,
+    696515, 769220, 697283, 810628, 752580, 714181, 739395, 738628, 731525, 737860, 742789, 718595,
+    730885, 767172, 730309, 794500, 748739, 772100, 798020, 746245, 702083, 696709, 761605, 741380,
+    715526, 792581, 740035, 740035, 709252, 739395, 735812, 697283, 739780, 760133, 792261, 699331,
+    748739, 732293, 791363, 710021, 706628, 717060, 757765, 741380, 761925, 751942, 725060, 696515,
+    794500, 737860, 753156, 741380, 761925, 761925, 776131, 742595, 744133, 753602, 700422, 704388,
+    753602, 798020, 769220, 741061, 704645, 752580, 767940, 742789, 718595, 794500, 746245, 706885,
+    756613, 891719, 710979, 697860, 742789, 756613, 733315, 728579, 771844, 773060, 778820, 738885,
+    741829, 710531, 741635, 702915, 709764, 733765, 734404, 765124, 781124, 799044, 770052, 777284,
+    715331, 721285, 768901, 768901, 777284, 756613, 756613, 756613, 721285, 483844, 797827, 706885,
+    756613, 706885, 733765, 742789, 756613, 733315, 710531, 702915, 765124, 734404, 777284, 768901,
+    756613, 756613, 756932, 770052, 756932, 756932, 701252, 701252, 767684, 698947, 696709, 702915,
+    730628, 742789, 813572, 715526, 792004, 727172, 699331, 759557, 720516, 714820, 770052, 770052,
+    813572, 714820, 708804, 696515, 761285, 741635, 706628, 786628, 777284, 742147, 793476, 702915,
+    755780, 701252, 726916, 725060, 727172, 757765, 724741, 717060, 701765, 734404, 731205, 742147,
+    715526, 733765, 733765, 722179, 704388, 713347, 696515, 767684, 726403, 777028, 753602, 699139,
+    754308, 776516, 755587, 699331, 701252, 734404, 755076, 798276, 701252, 734085, 804421, 701765,
+    730309, 699139, 702083, 758340, 756932, 698310, 709764, 707205, 753602, 762245, 755076, 712963,
+    764038, 793476, 737860, 734085, 751684, 743107, 746885, 734660, 709764, 739395, 749188, 760133,
+    737285, 726403, 790596, 715331, 782852, 777284, 765124, 786628, 702083, 722628, 698115, 737285,
+    769220, 741635, 699331, 736772, 728132, 698692, 782852, 700422, 766597, 720516, 709508, 696515,
+    698947, 810628, 703110, 744133, 786628, 737860, 715526, 742789, 734660, 725060, 772357, 704388,
+    698115, 707909, 715526, 759876, 824005, 716741, 701252, 696709, 698947, 730628, 746885, 792004,
+    715331, 727172, 699331, 720516, 770052, 714820, 734085, 701252, 755780, 702915, 726916, 696515,
+    722179, 810628, 715526, 717060, 713347, 724741, 696515, 701765, 726403, 776516, 753602, 699331,
+    777028, 754308, 798276, 755076, 730309, 707205, 749188, 782852, 739395, 765124, 707909, 702083,
+    698115, 726403, 777284, 736772, 728132, 769220, 698692, 700422, 716741, 777925, 782852, 798276,
+    715526, 734660, 728579, 739587, 741635, 726403, 776323, 741380, 756932, 734085, 748547, 736579,
+    710979, 730309, 736068, 736324, 734660, 767172, 746885, 721603, 699331, 706885, 741380, 731843,
+    730628, 730628, 720516, 738628, 708804, 712260, 735236, 736324, 802947, 733315, 714820, 736068,
+    712260, 709508, 748739, 704132, 726403, 755780, 750725, 730628, 696515, 740547, 477123, 740547,
+    751942, 726403, 775557, 765381, 758917, 740229, 710531, 708804, 739203, 710531, 701059, 787589,
+    717060, 732293, 750213, 771844, 710979, 733765, 701252, 741635, 730628, 730628, 702915, 777284,
+    721603, 763141, 786628, 712260, 765701, 773829, 702597, 738628, 746245, 727621, 751942, 722179,
+    725060, 726403, 708804, 714820, 759876, 892165, 709508, 723396, 777284, 710531, 760133, 726148,
+    791557, 703750, 741380, 776323, 738885, 753156, 754308, 753602, 703110, 736324, 736579, 748739,
+    804421, 740229, 743300, 763141, 802436, 786628, 747460, 757445, 776131, 697283, 697283, 704388,
+    756932, 799044, 704388, 706628, 734404, 712515, 763141, 702277, 763141, 731525, 756932, 764038,
+    734085, 737604, 699331, 742595, 746245, 697860, 757445, 782852, 811844, 702915, 778820, 730628,
+    782852, 765124, 731525, 703110, 712260, 777284, 713861, 773829, 715331, 800004, 773315, 704388,
+    765381, 809860, 799044, 766597, 710339, 712260, 790151, 748739, 715331, 759876, 698310, 698115,
+    768451, 767684, 768901, 702277, 740547, 741635, 712260, 699331, 740035, 782084, 726403, 755587,
+    756293, 708228, 707909, 735812, 777284, 730628, 807301, 758340, 724741, 793476, 704388, 759876,
+    742789, 710021, 696515, 702083, 730628, 767172, 809860, 704388, 758917, 748739, 773829, 715526,
+    698947, 790151, 710531, 715331, 795973, 710339, 756613, 721603, 699331, 730628, 738628, 720516,
+    740547, 802947, 726403, 748739, 787589, 701252, 702915, 710979, 710531, 809860, 708804, 730628,
+    739203, 717060, 696515, 771844, 756293, 721603, 714820, 724741, 782084, 759876, 725060, 763141,
+    756613, 710339, 703110, 704388, 736324, 726148, 754308, 697283, 697283, 776131, 804421, 757445,
+    755587, 767684, 763141, 712260, 782852, 799044, 712515, 768451, 777284, 731525, 766597, 768901,
+    759876, 702277, 707909, 809860, 698947, 790151, 715526, 739395, 709764, 709764, 709764, 699331,
+    719492, 696515, 789188, 723652, 723396, 755780, 741635, 720516, 742595, 731843, 754115, 725060,
+    714181, 766020, 747716, 705475, 753156, 795333, 763141, 741635, 776131, 744643, 699331, 722884,
+    783428, 726403, 756293, 713861, 726403, 742147, 813829, 767172, 698115, 721285, 783428, 787332,
+    773315, 787589, 722884, 742595, 767172, 813829, 748739, 790596, 790596, 714181, 697028, 714181,
+    745156, 768196, 756613, 802436, 802436, 742595, 734917, 736772, 778244, 776772, 709508, 802436,
+    767172, 713155, 746565, 758917, 730115, 713155, 778244, 742595, 786884, 701059, 776772, 710979,
+    722179, 765701, 740547, 749445, 736772, 778820, 698947, 758917, 822085, 741829, 719044, 742595,
+    710339, 767172, 757445, 742595, 758917, 760133, 721798, 746245, 757445, 792581, 734085, 726916,
+    712963, 727427, 705220, 697283, 726597, 750213, 767172, 758917, 698115, 698692, 769220, 769220,
+    770309, 740035, 773060, 731525, 731525, 751365, 795973, 750725, 743813, 767172, 773060, 774469,
+    776516, 776516, 787332, 730885, 715331, 776516, 741380, 776516, 776516, 705220, 732934, 699331,
+    699331, 710339, 759557, 710979, 731525, 720772, 757445, 777284, 720772, 697478, 699331, 720772,
+    779588, 698947, 697283, 706628, 782852, 755076, 728579, 791363, 779588, 740035, 704388, 756293,
+    703750, 699331, 753156, 697283, 723909, 703750, 709764, 734085, 720516, 768901, 741635, 698115,
+    698947, 740035, 700101, 704388, 742789, 699331, 758084, 727172, 698947, 892484, 749445, 730628,
+    735493, 698947, 740035, 753602, 753602, 719044, 792261, 792004, 698115, 706628, 727621, 770052,
+    765381, 741635, 748739, 736068, 712963, 748739, 764931, 734917, 734660, 719299, 704132, 892743,
+    893189, 743107, 697860, 740547, 741380, 701059, 732293, 730628, 726403, 701508, 713541, 710979,
+    741635, 698947, 709764, 710531, 735493, 726403, 704132, 704964, 701059, 788485, 746885, 771844,
+    755780, 710979, 712708, 710531, 739395, 893511, 731205, 462787, 724547, 706628, 716164, 751684,
+    715526, 722179, 748547, 709764, 746565, 708804, 714820, 773060, 759876, 731205, 696515, 697283,
+    701765, 709764, 724741, 699139, 720772, 730309, 705475, 737860, 893957, 894277, 697028, 894597,
+    726916, 754308, 742595, 769220, 756293, 764931, 719044, 726148, 699331, 777028, 711812, 704964,
+    795333, 755587, 712515, 701508, 725829, 710979, 709252, 704132, 710339, 719044, 719044, 790596,
+    772357, 894917, 895238, 895626, 896265, 744133, 739587, 730115, 697283, 761605, 715526, 777028,
+    762565, 698947, 735493, 700804, 739395, 740229, 702597, 747460, 756932, 739587, 696515, 802436,
+    748739, 705220, 732934, 710021, 729284, 732293, 809860, 710339, 772357, 757445, 712260, 788485,
+    896839, 897286, 897672, 701252, 727621, 755076, 701252, 751684, 746885, 708804, 727427, 757445,
+    698947, 752837, 752837, 795973, 746245, 727939, 709764, 709764, 699331, 802692, 811844, 790852,
+    764038, 726597, 704388, 701508, 701508, 734085, 741635, 753602, 733765, 710979, 771268, 734085,
+    697860, 730628, 712963, 898184, 762884, 898695, 736068, 734085, 752580, 712515, 734917, 799044,
+    777284, 721603, 701252, 701252, 714820, 899138, 773829, 724547, 734404, 705475, 765701, 731525,
+    697283, 736068, 762884, 899273, 726597, 768451, 713347, 737860, 771012, 698947, 746565, 740547,
+    734085, 799044, 769220, 715331, 701508, 756613, 698947, 698947, 708804, 770630, 701508, 700422,
+    744133, 698310, 724229, 718788, 782596, 721028, 740547, 715526, 726403, 724741, 738628, 755587,
+    764038, 769476, 766597, 766597, 770052, 721028, 708228, 738628, 785413, 715331, 899844, 900101,
+    734660, 707205, 751684, 750725, 821252, 799044, 742789, 719492, 720772, 763654, 710339, 732934,
+    899273, 900423, 900870, 718595, 698947, 740035, 737604, 900423, 710339, 773060, 710339, 715076,
+    750213, 715526, 753602, 748739, 707205, 710339, 730309, 699331, 758084, 698947, 704132, 792004,
+    748739, 734917, 728579, 743107, 726403, 713541, 788485, 748739, 704132, 712708, 710979, 726403,
+    755780, 751684, 701059, 771844, 720772, 706628, 697028, 709764, 722179, 731205, 727939, 751684,
+    750725, 698947, 724741, 730309, 764931, 715526, 738628, 795333, 710339, 726597, 734085, 710339,
+    701252, 746565, 704964, 719044, 709252, 754308, 698947, 744133, 761605, 732293, 697283, 809860,
+    756932, 802436, 732934, 739395, 739587, 788485, 735493, 710021, 727621, 701252, 730115, 734660,
+    790852, 741635, 732934, 701252, 736068, 709764, 771268, 753602, 701508, 710979, 764038, 746245,
+    770630, 752837, 799044, 768451, 697283, 724547, 714820, 734404, 734917, 734085, 713347, 756613,
+    718788, 782596, 769220, 771012, 744133, 709764, 715331, 721028, 766597, 708228, 769476, 737604,
+    719492, 710339, 742789, 707205, 812357, 696515, 710979, 710339, 705220, 699139, 704388, 710979,
+    735493, 698947, 706885, 901252, 719492, 701252, 706885, 745669, 755780, 765381, 712260, 712963,
+    726148, 710979, 734660, 736068, 734085, 701252, 699331, 784516, 901509, 721028, 721028, 701059,
+    795973, 750213, 755780, 704388, 705667, 721798, 734917, 706885, 736068, 764739, 819651, 717060,
+    739203, 732293, 715076, 710979, 730628, 736068, 710339, 752837, 712260, 705475, 765381, 730115,
+    740547, 756293, 712260, 709508, 710531, 704132, 739395, 706246, 699331, 733765, 765701, 705667,
+    793476, 699331, 901830, 761925, 804421, 712515, 722179, 778820, 736579, 712260, 704388, 725317,
+    724741, 698947, 773060, 708804, 727172, 696515, 730885, 759876, 718595, 748932, 705475, 727172,
+    724741, 736772, 740229, 712260, 759876, 902216, 902725, 808196, 718021, 717060, 698947, 699331,
+    795973, 730628, 699139, 712515, 759557, 699331, 726403, 738628, 738628, 741635, 715331, 754308,
+    710531, 776323, 710021, 742595, 753602, 753602, 753734, 712963, 712515, 727621, 747460, 903048,
+    903559, 748739, 713541, 716164, 710979, 748547, 775557, 758917, 726148, 731525, 737028, 735493,
+    748739, 789444, 734085, 710531, 776323, 705667, 765381, 697283, 710339, 702083, 804421, 759237,
+    804421, 802436, 763654, 697283, 710021, 696515, 696515, 710021, 730115, 747460, 702597, 697283,
+    762565, 795333, 710531, 740547, 904006, 904396, 698947, 716164, 597444, 720516, 785413, 811844,
+    748547, 701508, 742595, 712963, 742595, 753602, 706628, 776772, 710979, 762565, 776323, 704388,
+    743107, 765381, 763141, 722628, 779588, 712963, 761925, 735812, 725635, 710531, 905160, 729797,
+    734917, 739395, 761925, 765381, 738885, 785733, 701252, 743107, 722372, 737285, 697860, 762884,
+    777284, 698947, 739395, 734085, 779845, 741380, 730115, 730115, 739587, 734404, 704388, 698947,
+    710339, 757765, 743556, 742595, 742595, 739587, 776323, 721603, 724741, 790596, 757765, 773509,
+    710339, 700422, 711812, 768451, 734404, 696515, 764739, 720516, 712260, 721285, 765701, 783428,
+    741635, 790151, 708485, 699331, 712515, 721028, 714820, 746245, 702915, 724741, 696515, 782084,
+    741635, 761925, 755587, 764038, 770052, 724741, 721285, 704388, 697028, 705220, 730309, 730309,
+    776323, 717764, 769476, 699331, 761925, 748739, 784197, 730309, 718788, 696515, 701059, 716164,
+    759876, 697283, 696515, 698947, 711812, 699331, 742789, 702083, 733765, 701059, 743813, 737604,
+    748355, 761925, 772357, 731843, 782340, 699331, 767940, 755780, 748739, 743107, 774149, 790151,
+    782340, 761925, 707205, 740547, 710339, 718021, 812357, 705220, 698947, 765381, 745669, 701252,
+    764739, 705667, 729797, 755780, 750213, 739395, 702597, 748739, 705667, 756293, 733765, 732293,
+    712260, 740547, 765381, 718788, 730628, 702915, 712260, 722179, 778820, 736772, 725317, 740229,
+    705475, 718021, 730885, 712515, 726403, 710339, 759557, 712963, 753602, 699331, 730309, 742595,
+    712515, 789444, 785413, 748547, 802436, 737028, 758917, 731525, 762565, 795333, 765381, 755587,
+    712963, 743107, 753602, 739395, 701508, 730115, 779588, 725635, 739587, 734404, 785733, 777284,
+    698947, 739587, 734085, 743107, 696515, 761925, 711812, 721285, 721285, 724741, 705220, 699331,
+    748739, 738885, 742789, 761925, 737604, 772357, 707205, 790151, 748739, 728132, 732293, 734085,
+    730309, 783684, 734085, 734085, 704388, 783684, 748739, 704132, 753411, 698947, 756613, 753411,
+    756613, 708804, 754308, 708804, 734085, 748355, 748355, 712515, 777284, 773509, 754308, 708228,
+    739587, 697283, 748739, 705220, 754308, 710021, 710339, 715526, 704388, 717060, 748355, 710339,
+    743300, 698310, 708228, 710021, 698947, 732293, 704388, 753411, 717764, 717764, 705475, 732613,
+    710979, 699525, 699525, 710979, 786884, 740547, 740547, 748932, 710979, 730309, 747460, 740547,
+    699525, 471619, 706885, 710979, 740547, 699525, 721603, 698115, 698115, 737860, 721603, 809860,
+    812676, 734660, 764038, 764038, 728132, 747974, 757765, 723652, 725317, 715331, 753734, 725317,
+    716164, 710339, 788485, 720516, 818180, 818180, 696515, 731525, 709508, 715331, 784197, 698115,
+    698115, 731525, 732036, 722372, 704132, 741061, 696515, 697860, 704388, 740547, 779588, 704388,
+    710021, 699331, 710339, 771525, 773315, 767684, 714820, 748547, 704388, 758340, 748547, 750213,
+    732036, 767684, 696515, 779588, 709508, 704388, 773315, 748739, 712260, 734660, 710979, 710979,
+    771012, 771012, 765381, 753411, 740547, 732613, 744451, 708804, 712260, 791557, 768451, 782596,
+    730628, 741635, 765381, 732613, 730628, 696709, 748355, 711172, 696709, 730115, 739395, 739395,
+    702597, 734660, 786628, 765381, 701059, 732934, 790596, 697283, 765381, 737285, 773829, 716164,
+    716164, 734660, 756932, 736068, 728579, 735493, 730628, 731205, 740547, 763654, 701252, 704132,
+    701252, 725829, 712515, 788485, 710021, 755076, 710531, 704388, 776323, 742595, 715331, 730309,
+    704388, 767940, 741635, 734404, 701508, 741380, 712515, 712515, 751684, 715331, 705475, 712708,
+    736772, 785733, 712708, 751365, 716741, 697283, 761285, 698947, 730115, 698947, 698947, 697283,
+    698947, 720516, 773509, 773509, 743107, 705667, 797060, 723396, 786884, 783684, 701252, 730115,
+    720516, 788485, 710531, 746565, 732293, 732293, 722372, 746245, 723396, 797060, 798020, 705220,
+    734404, 797317, 802436, 699331, 722372, 696515, 739587, 783684, 809860, 740547, 812676, 730309,
+    764739, 753602, 763459, 696515, 797317, 809860, 741061, 722372, 728132, 705667, 720516, 773509,
+    743107, 797060, 710531, 732293, 786884, 746565, 730115, 797060, 699331, 797317, 740547, 763459,
+    774149, 713861, 749445, 713861, 704388, 774149, 774149, 749445, 747204, 724547, 732293, 724547,
+    774149, 749445, 747204, 720772, 701508, 782596, 720772, 782340, 758597, 743107, 721028, 723396,
+    699331, 634821, 602883, 657988, 476611, 675523, 905667, 606020, 460418, 626499, 650243, 475202,
+    468802, 593603, 625284, 608324, 475780, 905861, 626884, 652866, 462338, 475202, 484099, 905861,
+    623556, 602754, 470787, 661763, 476994, 591171, 594306, 479556, 641218, 602115, 906179, 589507,
+    906370, 588292, 475202, 905861, 668995, 906370, 688323, 906499, 670083, 611395, 906690, 238786,
+    4481, 906818, 906947, 907139, 19330, 907331, 907522, 907650, 1089, 907778, 907907, 908098,
+    908227, 20738, 55170, 2369, 908418, 908546, 4417, 118658, 908675, 908867, 102978, 909059,
+    909252, 909508, 909763, 909956, 910211, 910403, 102722, 910595, 910788, 911043, 911236, 911491,
+    911683, 103618, 911875, 912067, 103490, 912259, 912451, 912643, 102850, 912835, 913028, 913283,
+    913475, 913667, 913858, 913987, 914180, 914435, 914627, 914819, 142402, 915011, 915204, 915459,
+    915652, 915907, 916099, 142146, 916291, 916484, 916739, 916932, 917187, 917379, 143042, 917571,
+    917763, 917955, 142274, 918147, 918340, 918595, 918787, 918979, 919170, 919299, 919492, 919747,
+    919940, 920196, 920451, 920644, 920901, 921221, 921540, 921797, 922116, 922372, 922627, 922820,
+    923077, 923396, 923653, 923972, 924228, 924483, 924676, 924932, 925187, 925380, 925636, 925892,
+    926147, 926340, 926597, 926916, 927172, 927428, 927683, 927876, 928132, 928388, 928643, 928836,
+    929093, 929412, 929669, 929988, 930244, 930499, 930692, 930948, 931204, 931459, 931652, 931908,
+    932164, 932419, 932612, 932869, 933188, 933444, 933700, 933955, 934148, 934405, 934724, 934980,
+    935236, 935491, 935684, 935941, 936260, 936517, 936836, 937092, 937347, 937540, 937797, 938116,
+    938373, 938692, 938948, 939203, 939396, 939652, 939908, 940163, 940356, 940613, 940932, 941188,
+    941443, 941636, 941893, 942212, 942467, 942659, 90626, 942851, 943044, 943299, 943492, 943747,
+    943939, 90370, 944131, 944324, 944580, 944835, 945028, 945283, 945475, 91266, 945667, 945859,
+    91138, 946051, 946243, 90498, 946435, 946628, 946883, 947075, 947267, 947458, 947587, 947779,
+    947971, 141186, 948163, 948355, 948547, 140930, 948739, 948931, 141826, 949123, 949315, 949507,
+    141058, 949699, 949892, 950147, 950339, 950531, 950722, 950851, 951043, 951235, 104194, 951427,
+    951620, 951876, 952131, 952324, 952579, 952771, 103938, 952963, 953155, 953347, 104834, 953539,
+    953731, 953923, 954115, 954307, 104066, 954499, 954692, 954947, 955139, 955331, 955522, 955651,
+    955844, 956099, 956291, 956483, 124290, 956675, 956868, 957123, 957316, 957571, 957763, 124034,
+    957955, 958148, 958403, 958595, 958787, 124930, 958979, 959171, 124802, 959363, 959555, 959747,
+    124162, 959939, 960132, 960387, 960579, 960771, 16258, 960963, 961156, 961411, 961604, 961859,
+    962051, 105154, 962243, 962436, 962692, 962947, 963140, 963395, 963587, 105922, 963779, 963971,
+    105794, 964163, 964355, 964547, 105282, 964739, 964932, 965187, 965380, 965636, 125635, 965892,
+    966149, 966468, 966725, 967044, 967300, 125251, 967556, 967813, 968132, 968389, 968708, 968964,
+    126531, 969220, 969476, 126339, 969732, 969988, 970244, 125443, 970500, 970757, 971076, 971332,
+    971588, 971843, 972036, 972293, 972612, 972868, 973124, 973379, 973572, 973828, 974084, 974339,
+    974532, 974788, 975043, 975236, 975492, 975748, 976003, 976196, 976453, 976772, 977028, 977284,
+    977539, 977732, 977989, 978309, 978628, 978885, 979204, 979460, 148355, 979716, 979973, 980292,
+    980548, 980804, 981060, 981316, 981571, 981764, 982020, 982275, 982467, 109698, 982659, 982852,
+    983107, 983300, 983555, 109442, 983747, 983940, 984195, 984388, 984643, 984835, 110338, 985027,
+    985219, 110210, 985411, 985603, 985795, 109570, 985987, 986180, 986435, 986628, 986884, 987139,
+    987332, 987589, 987908, 988165, 988484, 988740, 988995, 989188, 989445, 989764, 990021, 990340,
+    990595, 990788, 991044, 991299, 991492, 991748, 992004, 992259, 992452, 992709, 993028, 993284,
+    993540, 993795, 993988, 994245, 994564, 994819, 995011, 87682, 995203, 995396, 995652, 995907,
+    996100, 996355, 996547, 87426, 996739, 996932, 997188, 997443, 997636, 997891, 998083, 88322,
+    998275, 998467, 88194, 998659, 998851, 999043, 87554, 999235, 999428, 999683, 999875, 1000067,
+    200706, 1000259, 1000452, 1000707, 1000899, 1001091, 128450, 1001283, 1001476, 1001732, 1001987, 1002180,
+    1002435, 1002627, 128194, 1002819, 1003012, 1003268, 1003523, 1003716, 1003971, 1004163, 129090, 1004355,
+    1004547, 1004739, 128962, 1004931, 1005123, 1005315, 128322, 1005507, 1005700, 1005955, 1006147, 1006339,
+    113730, 1006531, 1006724, 1006979, 1007172, 1007427, 1007619, 113474, 1007811, 1008004, 1008259, 1008452,
+    1008707, 1008899, 114370, 1009091, 1009283, 1009475, 114242, 1009667, 1009859, 1010051, 113602, 1010243,
+    1010436, 1010691, 1010884, 1011140, 130883, 1011397, 1011716, 1011973, 1012292, 1012548, 130499, 1012804,
+    1013061, 1013381, 1013700, 1013957, 1014276, 1014532, 131779, 1014788, 1015044, 1015300, 131587, 1015556,
+    1015812, 1016068, 130691, 1016324, 1016581, 1016900, 1017157, 1017476, 1017732, 1017988, 1018243, 1018436,
+    1018693, 1019012, 1019269, 1019588, 1019844, 1020099, 1020292, 1020548, 1020803, 1020996, 1021252, 1021508,
+    1021763, 1021956, 1022213, 1022532, 1022788, 1023044, 1023299, 1023492, 1023749, 1024069, 1024388, 1024645,
+    1024964, 1025220, 1025475, 1025668, 1025925, 1026245, 1026564, 1026821, 1027140, 1027396, 1027651, 1027844,
+    1028100, 1028355, 1028548, 1028805, 1029124, 1029381, 1029700, 1029956, 181699, 1030212, 1030469, 1030789,
+    1031108, 1031364, 1031620, 181251, 1031876, 1032132, 1032387, 1032580, 1032835, 1033028, 1033283, 1033475,
+    1033667, 86210, 1033859, 1034052, 1034308, 1034563, 1034756, 1035011, 1035203, 87106, 1035395, 1035587,
+    86978, 1035779, 1035971, 1036163, 67074, 1036355, 1036548, 1036803, 1036996, 1037251, 119170, 1037443,
+    1037635, 67714, 1037827, 1038019, 1038211, 120770, 1038403, 1038596, 1038851, 1039044, 1039299, 1039491,
+    120514, 1039683, 1039876, 1040131, 1040324, 1040579, 1040771, 121410, 1040963, 1041155, 121282, 1041347,
+    1041539, 1041731, 120642, 1041923, 1042116, 1042371, 1042563, 1042755, 1042946, 1043075, 1043268, 1043523,
+    1043715, 1043907, 106498, 1044099, 1044292, 1044548, 1044803, 1044996, 1045251, 1045443, 106242, 1045635,
+    1045828, 1046083, 1046276, 1046531, 1046723, 107138, 1046915, 1047107, 107010, 1047299, 1047491, 1047683,
+    106370, 1047875, 1048068, 1048323, 1048515, 1048707, 203202, 1048899, 1049092, 1049347, 1049540, 1049796,
+    1050051, 1050244, 1050501, 1050821, 1051140, 1051397, 1051716, 1051972, 1052227, 1052420, 1052676, 1052931,
+    1053124, 1053380, 1053635, 1053828, 1054084, 1054339, 1054532, 1054789, 1055108, 1055364, 1055620, 1055875,
+    1056068, 1056325, 1056644, 1056900, 1057156, 1057411, 1057604, 1057861, 1058180, 1058437, 1058756, 1059012,
+    1059267, 1059460, 1059717, 1060036, 1060292, 1060548, 1060804, 1061059, 1061252, 1061507, 1061700, 1061957,
+    1062276, 1062532, 1062788, 1063043, 1063236, 1063493, 1063812, 1064067, 1064259, 94786, 1064451, 1064644,
+    1064899, 1065092, 1065347, 1065539, 94530, 1065731, 1065924, 1066179, 1066372, 1066627, 1066819, 95426,
+    1067011, 1067203, 95298, 1067395, 1067587, 1067779, 94658, 1067971, 1068164, 1068419, 1068611, 1068803,
+    219266, 1068995, 1069188, 1069443, 1069636, 1069892, 1070147, 1070340, 1070597, 1070916, 1071173, 1071492,
+    1071748, 1072003, 1072196, 1072452, 1072708, 1072963, 1073156, 1073412, 1073667, 1073860, 1074116, 1074372,
+    1074627, 1074820, 1075076, 1075332, 1075587, 1075780, 1076037, 1076356, 1076612, 1076868, 121731, 1077124,
+    1077381, 1077700, 1077957, 1078276, 1078532, 123011, 1078788, 1079044, 1079300, 122819, 1079556, 1079812,
+    1080068, 121923, 1080324, 1080581, 1080900, 1081156, 1081412, 1081667, 1081860, 1082117, 1082436, 1082692,
+    1082948, 133955, 1083204, 1083461, 1083781, 1084100, 1084357, 1084676, 1084932, 135235, 1085188, 1085444,
+    1085700, 135043, 1085956, 1086212, 134147, 1086468, 1086725, 1087044, 1087300, 1087556, 1087811, 1088004,
+    1088261, 1088580, 1088836, 1089091, 1089285, 1089604, 1089860, 1090116, 1090371, 1090564, 1090820, 1091076,
+    1091331, 1091524, 1091780, 1092036, 1092291, 1092484, 1092741, 1093060, 1093316, 1093572, 1093827, 1094020,
+    1094277, 1094596, 1094852, 1095108, 1095363, 1095556, 1095812, 1096067, 1096260, 1096516, 1096772, 1097027,
+    1097220, 1097476, 1097732, 1097987, 1098180, 1098437, 1098756, 1099012, 1099268, 1099523, 1099716, 1099973,
+    1100292, 1100548, 1100804, 95747, 1101060, 1101317, 1101636, 1101893, 1102212, 1102468, 97027, 1102724,
+    1102980, 1103236, 96835, 1103492, 1103748, 1104004, 95939, 1104260, 1104517, 1104836, 1105092, 1105348,
+    1105603, 1105796, 1106053, 1106372, 1106627, 1106819, 93314, 1107011, 1107204, 1107459, 1107652, 1107907,
+    1108099, 94210, 1108291, 1108483, 94082, 1108675, 1108867, 1109059, 93442, 1109251, 1109444, 1109699,
+    1109891, 1110083, 200386, 1110275, 1110468, 1110723, 1110915, 1111107, 127234, 1111299, 1111492, 1111748,
+    1112003, 1112196, 1112452, 1112708, 1112963, 1113156, 1113411, 1113603, 127874, 1113795, 1113987, 1114179,
+    127106, 1114371, 1114564, 1114819, 1115011, 1115203, 202434, 1115395, 1115588, 1115843, 1116035, 1116227,
+    97730, 1116419, 1116612, 1116868, 1117123, 1117316, 1117572, 1117828, 149443, 1118084, 1118339, 1118531,
+    98370, 1118723, 1118915, 1119107, 97602, 1119299, 1119492, 1119747, 1119939, 1120131, 1120322, 1120451,
+    1120644, 1120899, 1121092, 1121348, 189763, 1121604, 1121861, 1122181, 1122500, 1122757, 1123077, 1123396,
+    1123653, 1123972, 1124228, 189123, 1124484, 1124740, 1124996, 188931, 1125252, 1125509, 1125828, 1126084,
+    1126340, 1126595, 1126788, 1127044, 1127300, 1127555, 1127748, 1128005, 1128325, 1128644, 1128901, 1129221,
+    1129540, 1129796, 1130052, 1130307, 1130500, 1130756, 1131011, 1131204, 1131461, 1131780, 1132036, 1132292,
+    1132547, 1132740, 1132997, 1133316, 1133572, 1133828, 111043, 1134084, 1134341, 1134661, 1134980, 1135237,
+    1135557, 1135876, 1136133, 1136452, 1136708, 111939, 1136964, 1137220, 1137476, 110851, 1137732, 1137987,
+    1138179, 107714, 1138371, 1138564, 1138820, 1139075, 1139268, 1139524, 1139779, 1139971, 1140163, 59330,
+    1140355, 1140547, 1140739, 1140930, 1141059, 1141252, 1141507, 1141699, 1141891, 19842, 1142083, 1142276,
+    1142532, 1142787, 1142980, 1143236, 1143492, 1143747, 1143940, 1144195, 1144387, 67394, 1144579, 1144771,
+    1144963, 37378, 1145155, 1145348, 1145603, 1145795, 1145987, 43842, 1146179, 1146372, 1146627, 1146819,
+    286402, 611779, 1147012, 1147267, 1147459, 1147652, 1147907, 465218, 1148100, 1148355, 1148548, 1148803,
+    1148994, 610691, 1149124, 1149379, 1149570, 1149699, 1149891, 1150084, 1150339, 1150532, 1150788, 474434,
+    1151042, 1151172, 1151427, 1151619, 1151811, 1152003, 1152195, 1152387, 1152579, 639874, 446018, 1152772,
+    1153027, 1153219, 1153412, 618690, 606466, 1153668, 651203, 1153922, 1154052, 1154307, 1154500, 1154756,
+    1155011, 1155204, 467651, 1155461, 1155780, 615298, 45954, 7105, 7873, 36546, 4545, 6785,
+    61058, 4993, 5633, 36994, 8514, 16002, 1156034, 7041, 4353, 11137, 4609, 5761,
+    6657, 6529, 4098, 5954, 5121, 5121, 1156162, 7425, 6913, 8194, 4737, 4417,
+    1089, 41986, 52546, 52546, 4481, 2369, 2049, 2049, 4417, 4417, 5761, 10945,
+    39170, 3777, 6529, 6785, 2241, 12673, 4417, 4417, 86978, 67714, 67714, 142914,
+    103490, 103490, 1156291, 1156483, 1156676, 1156931, 141698, 104706, 105794, 133315, 124802, 1157123,
+    88194, 94082, 124802, 975043, 95298, 96835, 121282, 122819, 135043, 127746, 1157315, 66946,
+    114242, 1157508, 128962, 91138, 110210, 111747, 4481, 4481, 86466, 86466, 67842, 67842,
+    142402, 102978, 102978, 1157763, 1157955, 1158148, 1158403, 141186, 104194, 16258, 132611, 124290,
+    1158595, 87682, 93570, 124290, 971843, 94786, 96131, 120770, 122115, 134339, 127234, 1127555,
+    19842, 113730, 1158788, 128450, 90626, 109698, 111043, 1089, 1089, 181699, 86210, 86210,
+    67074, 67074, 142146, 102722, 102722, 1159043, 1159235, 1159235, 1159428, 1159683, 140930, 103938,
+    105154, 132227, 124034, 1159875, 87426, 93314, 124034, 973379, 94530, 95747, 120514, 121731,
+    133955, 126978, 1160067, 46594, 113474, 113474, 1160260, 128194, 90370, 109442, 110659, 2369,
+    2369, 87106, 119170, 119170, 143042, 103618, 103618, 1160515, 1160707, 1160900, 1161155, 141826,
+    104834, 105922, 133507, 124930, 1161347, 88322, 94210, 124930, 974339, 95426, 97027, 121410,
+    123011, 135235, 127874, 1130307, 67394, 114370, 1161540, 129090, 91266, 110338, 111939, 2049,
+    2049, 86338, 86338, 118658, 118658, 142274, 102850, 102850, 1161795, 1161987, 1162180, 1162435,
+    141058, 104066, 105282, 132419, 124162, 1162627, 87554, 93442, 124162, 976003, 94658, 95939,
+    120642, 121923, 134147, 127106, 1131011, 37378, 113602, 1162820, 128322, 90498, 109570, 110851,
+    2369, 2369, 181251, 87106, 87106, 119170, 119170, 143042, 103618, 103618, 1160515, 1160707,
+    1160900, 1161155, 1161155, 141826, 104834, 105922, 133507, 124930, 1161347, 88322, 94210, 124930,
+    974339, 95426, 97027, 121410, 123011, 135235, 127874, 1130307, 67394, 114370, 1161540, 129090,
+    91266, 110338, 111939, 4417, 4417, 1032387, 86978, 86978, 67714, 67714, 142914, 103490,
+    103490, 1156291, 1156483, 1156483, 1156676, 1156931, 1156931, 141698, 104706, 105794, 133315, 124802,
+    1157123, 88194, 94082, 124802, 975043, 95298, 96835, 121282, 122819, 135043, 127746, 1157315,
+    66946, 114242, 1157508, 1157508, 128962, 128962, 91138, 110210, 111747, 5954, 5121, 2241,
+    12673, 3137, 140930, 113474, 95426, 469508, 653956, 606596, 1163075, 627588, 1163267, 1163459,
+    674564, 594884, 1163652, 633540, 1163907, 1164100, 34945, 2305, 1857, 1921, 12929, 8641,
+    7233, 35009, 35073, 35137, 90370, 124930, 6977, 7041, 9218, 9346, 9218, 9346,
+    3521, 4481, 17026, 19586, 3841, 2049, 3713, 2369, 1985, 1985, 3905, 4737,
+    16770, 19330, 652994, 7746, 18690, 19202, 3457, 4417, 3713, 2369, 16770, 19330,
+    1164354, 34818, 18050, 8706, 1164482, 61442, 1164610, 80770, 1164738, 84162, 3713, 2369,
+    1164866, 38402, 1164866, 38402, 3713, 12673, 1165000, 1165513, 1166090, 2177, 19330, 4481,
+    4481, 2049, 1985, 4737, 1985, 2369, 1985, 1985, 1985, 1985, 1166722, 1166850,
+    1166979, 1167171, 1167363, 1167555, 1167747, 36355, 9218, 9346, 6721, 6785, 1167938, 1168066,
+    1168195, 1168387, 18050, 8706, 1168579, 1168771, 1168962, 1169090, 1169219, 1169411, 1164866, 38402,
+    3713, 2369, 1985, 1985, 7425, 19330, 1089, 113474, 2049, 113602, 4417, 94082,
+    105794, 2369, 111043, 4481, 87426, 142146, 174147, 1169603, 88003, 90947, 105411, 1169796,
+    1170052, 11137, 1170307, 90498, 169476, 94786, 1104004, 1067779, 456131, 1009283, 1170499, 109570,
+    1170692, 1170947, 95939, 1171139, 46594, 1171332, 1010051, 917955, 1157315, 1171587, 142274, 118658,
+    142914, 141698, 93442, 87554, 90626, 109698, 1109059, 94082, 1171779, 1171974, 1160260, 181699,
+    97027, 1172355, 141058, 1172547, 67074, 109442, 87682, 142402, 1172739, 114370, 1172932, 1173187,
+    1173379, 90370, 16258, 113730, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073,
+    35137, 34945, 1985, 12289, 2177, 12673, 12545, 2241, 14081, 3137, 2305, 1857,
+    1921, 12929, 8641, 7233, 35009, 35073, 8641, 12929, 1921, 1857, 2305, 8641,
+    12929, 1921, 1857, 2305, 8641, 12929, 1921, 1857, 2305, 2305, 1857, 1921,
+    12929, 10881, 11201, 65, 10881, 10881, 1473, 12545, 1985, 1985, 12289, 12289,
+    5057, 5121, 1173570, 1173698, 1921, 1921, 12929, 12929, 1173826, 1173826, 7425, 6657,
+    647746, 38146, 594306, 51906, 679042, 42242, 1173954, 220930, 1173954, 220930, 1174082, 52674,
+    1174210, 1174338, 1174466, 1174594, 1174466, 1174594, 1174466, 1174594, 5697, 5761, 1174722, 1174850,
+    1174978, 1175106, 3713, 2369, 1164866, 38402, 1175234, 1175362, 1175234, 1175362, 1175234, 1175362,
+    1175490, 1175618, 1175490, 1175618, 6465, 6529, 1175746, 1175874, 1176002, 1176130, 1176258, 955522,
+    6977, 7041, 1176387, 1176579, 1176387, 1176579, 8321, 11201, 15105, 15105, 16642, 13826,
+    1985, 1985, 1985, 1176770, 1174850, 1176898, 1177026, 1175874, 1175874, 1177154, 13826, 3585,
+    4545, 7361, 7425, 4929, 24450, 5954, 1177282, 46082, 6465, 6529, 6593, 6657,
+    6721, 6785, 10881, 12609, 12993, 1985, 1985, 3905, 5761, 1473, 3649, 4609,
+    3393, 4353, 4353, 5121, 7169, 7105, 7361, 7425, 27522, 4226, 1177410, 6338,
+    1177538, 51394, 4929, 4993, 5569, 5633, 3649, 4609, 6465, 6529, 6593, 6657,
+    5057, 3457, 4929, 5697, 3521, 10945, 5569, 6721, 5441, 12865, 7169, 7105,
+    3713, 2369, 3841, 2049, 3201, 1089, 3521, 4481, 3841, 2049, 3713, 2369,
+    6849, 6913, 3393, 6593, 6977, 3585, 4545, 6593, 6657, 4929, 4993, 1177668,
+    1177922, 6593, 6657, 6593, 6657, 3393, 7361, 15169, 5057, 5121, 3521, 5057,
+    6338, 6913, 7361, 2113, 14145, 3521, 1178050, 1089, 4481, 4481, 2049, 4417,
+    2369, 0, 5633, 14850, 4993, 27778, 5954, 4353, 16002, 5505, 22658, 6785,
+    4802, 4545, 34434, 6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970,
+    11137, 6529, 5761, 6529, 6657, 5121, 1089, 4481, 2049, 4417, 2369, 2305,
+    1857, 1921, 12929, 2305, 1857, 1921, 2305, 1857, 1921, 4673, 1473, 44674,
+    1985, 5633, 14850, 4993, 5954, 4353, 16002, 5505, 29826, 6785, 4802, 4545,
+    4609, 7873, 14722, 7105, 11137, 8706, 19971, 9346, 6913, 8514, 7041, 1473,
+    4737, 6529, 5761, 8194, 6657, 5121, 1985, 4481, 2049, 4417, 2369, 10945,
+    3777, 7425, 61058, 38530, 6913, 4737, 35650, 35779, 36546, 42370, 1178179, 1178371,
+    1156162, 1178563, 6529, 6529, 10881, 7297, 219522, 4673, 13186, 11137, 5121, 1089,
+    1089, 4481, 4481, 2049, 2049, 6529, 6529, 5761, 5761, 4417, 4417, 41986,
+    2369, 2369, 42242, 5633, 14850, 4993, 27778, 4609, 4353, 16002, 5505, 22658,
+    4609, 6785, 4802, 4545, 34434, 4609, 6785, 4802, 4545, 34434, 4609, 7873,
+    14722, 7105, 35970, 11137, 4737, 6529, 5761, 11201, 6657, 6657, 6657, 5121,
+    5761, 5121, 1089, 4481, 4481, 2049, 2049, 6529, 6529, 5761, 5761, 4417,
+    4417, 41986, 2369, 2369, 42242, 0, 11137, 2241, 12673, 34945, 2305, 1857,
+    1921, 12929, 8641, 7233, 35009, 35073, 35137, 34945, 2305, 1857, 1921, 12929,
+    8641, 7233, 35009, 35073, 35137, 1089, 2049, 5633, 4609, 7873, 6529, 11201,
+    1985, 11137, 11137, 569410, 1178754, 1473, 1473, 10881, 12801, 2177, 46722, 1089,
+    1089, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 5633,
+    178690, 4993, 5954, 6657, 1178882, 7041, 29826, 6785, 1179010, 4609, 7873, 1179138,
+    11137, 4545, 7105, 6529, 4737, 5761, 6913, 4802, 5121, 11201, 5505, 1089,
+    6338, 4481, 2369, 51394, 4226, 2049, 4417, 1179266, 0, 0, 0, 1985,
+    2241, 5633, 4993, 5954, 6785, 4545, 4609, 7873, 7105, 11137, 4353, 5505,
+    29826, 6657, 6529, 5761, 4737, 6913, 5121, 37890, 1179395, 50946, 50499, 1985,
+    4481, 2049, 4417, 41986, 2369, 42242, 67970, 1179586, 5954, 4609, 6529, 5121,
+    1, 2177, 562114, 10626, 1179714, 19586, 80770, 1179843, 82434, 1180035, 80898, 81026,
+    1180227, 148226, 11778, 9730, 83906, 84162, 1180418, 84418, 62659, 1180546, 35970, 1180675,
+    5761, 5121, 1180867, 35650, 14722, 1178882, 1181058, 11137, 5954, 6529, 5121, 1089,
+    4481, 4481, 4481, 2049, 94082, 88194, 88322, 4417, 41986, 2369, 5633, 10945,
+    14850, 4993, 27778, 5954, 4353, 16002, 5505, 50307, 22658, 29826, 6785, 4802,
+    4545, 34434, 4609, 6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970,
+    11137, 4737, 6529, 6529, 5761, 6913, 6657, 6657, 6657, 5121, 1985, 1089,
+    2369, 4481, 4481, 2049, 2049, 4417, 41986, 4417, 94082, 4737, 6529, 1,
+    12353, 12417, 7297, 219522, 1181187, 2177, 12545, 2241, 12673, 641, 8834, 37633,
+    37697, 1473, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137,
+    10881, 12801, 27778, 16002, 22658, 4609, 35970, 0, 1473, 4609, 7425, 4993,
+    27778, 5505, 22658, 4545, 34434, 4609, 34945, 2305, 1857, 1921, 12929, 8641,
+    7233, 35009, 35073, 35137, 5761, 4545, 34434, 7105, 35970, 1089, 4481, 2049,
+    4417, 41986, 2369, 5633, 14850, 4993, 27778, 5954, 5954, 4353, 16002, 5505,
+    22658, 29826, 29826, 10114, 6785, 4802, 4545, 34434, 4609, 4609, 50946, 7873,
+    1181378, 14722, 7105, 35970, 11137, 11137, 37890, 4737, 6529, 5761, 6913, 3777,
+    6657, 5121, 1089, 4481, 4481, 52546, 2049, 4417, 2369, 41986, 42242, 2049,
+    4737, 6529, 5761, 6913, 5633, 4993, 5954, 5954, 4353, 6785, 4609, 7873,
+    4737, 6529, 5761, 3777, 11137, 5121, 34945, 2305, 1857, 1921, 12929, 8641,
+    7233, 35009, 35073, 35137, 2177, 2241, 14081, 12673, 4993, 4353, 16002, 5505,
+    22658, 4609, 6785, 4802, 4545, 34434, 34434, 4609, 6657, 5121, 5761, 7425,
+    1473, 3777, 7041, 6529, 1181507, 5826, 52930, 65, 2305, 1857, 6529, 1,
+    0, 1, 16002, 8194, 5633, 5633, 14850, 14850, 3777, 3777, 4993, 4993,
+    5954, 5954, 16002, 16002, 1181699, 1181699, 6657, 6657, 29826, 29826, 4545, 4545,
+    6785, 6785, 4802, 4802, 4609, 4609, 7105, 7105, 7873, 7873, 14722, 14722,
+    7425, 7425, 11137, 11137, 4737, 4737, 6529, 6529, 5761, 5761, 11201, 11201,
+    5121, 5121, 1089, 38146, 4481, 51394, 2049, 4226, 2369, 38914, 19202, 1181891,
+    201026, 212035, 41986, 34562, 51266, 0, 0, 1182083, 1182277, 1473, 12353, 12417,
+    4417, 2369, 16002, 4609, 6785, 4802, 4545, 34434, 4609, 6657, 6657, 4481,
+    2049, 41986, 42242, 42242, 2241, 3137, 2177, 1473, 1473, 5121, 0, 132419,
+    132611, 132227, 133315, 4802, 133507, 1162627, 1158595, 1159875, 1157123, 34434, 1161347, 192643,
+    193475, 193667, 193027, 9346, 192835, 453380, 453636, 453892, 454148, 453380, 26563, 454404,
+    1182595, 1182787, 1182979, 1183171, 1182595, 1183362, 1183491, 1089, 4226, 4417, 4417, 4417,
+    7425, 4993, 5761, 5761, 5761, 11137, 4609, 5954, 2369, 2369, 2369, 6338,
+    6338, 6338, 38402, 38402, 6529, 6529, 6529, 36994, 6529, 36994, 6529, 6529,
+    8194, 2049, 2049, 212354, 212354, 2049, 3777, 3777, 3777, 3777, 3777, 3777,
+    3777, 4737, 2177, 5121, 5761, 5761, 2049, 19202, 19330, 6338, 908098, 1089,
+    2369, 9346, 8706, 6529, 6913, 12353, 12417, 1089, 4417, 4481, 2369, 2049,
+    11201, 128194, 113474, 128962, 128450, 129090, 128322, 148098, 86210, 86978, 86466, 87106,
+    86338, 7490, 87426, 88194, 87682, 88322, 87554, 148226, 90370, 91138, 90626, 91266,
+    90498, 109442, 148355, 148547, 110210, 109698, 110338, 109570, 148738, 148867, 149059, 149251,
+    149443, 149635, 149827, 94530, 6657, 95298, 94786, 95426, 94658, 150018, 124034, 105154,
+    124802, 105794, 124290, 16258, 124930, 124162, 150146, 150275, 150467, 150659, 150851, 151043,
+    151235, 151427, 137411, 138499, 137795, 138691, 137603, 151619, 67074, 67714, 67842, 119170,
+    118658, 151810, 46594, 5633, 6657, 5761, 11137, 7873, 4609, 4353, 6785, 14850,
+    4609, 4802, 6913, 4737, 5121, 2049, 4481, 14722, 1089, 4993, 22658, 6529,
+    7105, 5505, 4545, 27778, 34434, 35970, 5633, 5761, 11137, 7873, 4609, 6785,
+    4609, 4481, 2369, 4481, 1089, 4417, 10498, 2049, 52546, 11137, 12673, 1,
+    0, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 128194,
+    1183683, 1183876, 1184132, 1184387, 1184580, 1184836, 1185091, 586499, 1185284, 1185540, 1185796, 1186052,
+    1186308, 1186564, 1186820, 1187075, 1187267, 1187460, 1187715, 1187908, 1188164, 1188419, 1188611, 1188803,
+    1002435, 1002819, 1188995, 1189187, 1189380, 1189637, 1189957, 1190276, 1190533, 1190853, 1191172, 1191428,
+    1191685, 1192005, 1192325, 1192645, 1192965, 1193285, 1193605, 1193924, 1194180, 1194437, 1194756, 1195013,
+    1195333, 1195652, 1195908, 1196164, 1196420, 1196676, 1196932, 458627, 1197188, 1197445, 1197765, 1198084,
+    1198341, 1198661, 1198980, 1199236, 1199493, 1199813, 1200133, 1200453, 1200773, 1201093, 1201413, 1201732,
+    1201988, 1202245, 1202564, 1202821, 1203141, 1203460, 1203716, 1203972, 1204228, 1204484, 1204740, 1204996,
+    1205253, 1205574, 1205958, 1206341, 1206662, 1207046, 1207429, 1207749, 1208070, 1208454, 1208838, 1209222,
+    1209606, 1209990, 1210374, 1210757, 1211077, 1211398, 1211781, 1212102, 1212486, 1212869, 1213189, 1213509,
+    1213829, 1214149, 1214469, 1214787, 1214980, 1215237, 1215557, 1215876, 1216133, 1216453, 1216772, 1217028,
+    1217285, 1217605, 1217925, 1218245, 1218565, 1218885, 1219205, 1219524, 1219780, 1220037, 1220356, 1220613,
+    1220933, 1221252, 1221508, 1221764, 1222020, 1222276, 1222532, 128962, 1222787, 1222980, 1223236, 1223491,
+    1223684, 1223940, 1224195, 1224387, 1224580, 1224836, 1225092, 1225348, 1225604, 1225860, 1226116, 1226371,
+    1226563, 1226756, 1227011, 1227204, 1227460, 1227715, 1227907, 1228099, 1004547, 1004931, 1228291, 1228484,
+    1228741, 1229062, 1229446, 1229829, 1230150, 1230534, 1230917, 1231237, 1231558, 1231942, 1232326, 1232710,
+    1233094, 1233478, 1233862, 1234245, 1234565, 1234886, 1235269, 1235590, 1235974, 1236357, 1236677, 1236997,
+    1237317, 1237637, 1237957, 458051, 1238276, 1238533, 1238853, 1239172, 1239429, 1239749, 1240068, 1240324,
+    1240581, 1240901, 1241221, 1241541, 1241861, 1242181, 1242501, 1242820, 1243076, 1243333, 1243652, 1243909,
+    1244229, 1244548, 1244804, 1245060, 1245316, 1245572, 1245828, 129090, 1246083, 1246276, 1246532, 1246787,
+    1246980, 1247236, 1247491, 1247683, 1247876, 1248132, 1248388, 1248644, 1248900, 1249156, 1249412, 1249667,
+    1249859, 1250052, 1250307, 1250500, 1250756, 1251011, 1251203, 1251395, 1003971, 1004355, 1251587, 129411,
+    1251780, 1252037, 1252357, 1252676, 1252933, 1253253, 1253572, 1253828, 1254085, 1254405, 1254725, 1255045,
+    1255365, 1255685, 1256005, 1256324, 1256580, 1256837, 1257156, 1257413, 1257733, 1258052, 1258308, 1258564,
+    1258820, 1259076, 1259332, 1259588, 1259845, 1260166, 1260550, 1260933, 1261254, 1261638, 1262021, 1262341,
+    1262662, 1263046, 1263430, 1263814, 1264198, 1264582, 1264966, 1265349, 1265669, 1265990, 1266373, 1266694,
+    1267078, 1267461, 1267781, 1268101, 1268421, 1268741, 1269061, 1269379, 1269572, 1269829, 1270149, 1270468,
+    1270725, 1271045, 1271364, 1271620, 1271877, 1272197, 1272517, 1272837, 1273157, 1273477, 1273797, 1274116,
+    1274372, 1274629, 1274948, 1275205, 1275525, 1275844, 1276100, 1276356, 1276612, 1276868, 1277124, 458819,
+    1277380, 1277637, 1277957, 1278276, 1278533, 1278853, 1279172, 1279428, 1279685, 1280005, 1280325, 1280645,
+    1280965, 1281285, 1281605, 1281924, 1282180, 1282437, 1282756, 1283013, 1283333, 1283652, 1283908, 1284164,
+    1284420, 1284676, 1284932, 128322, 1285187, 1285380, 1285636, 1285891, 1286084, 1286340, 1286595, 1286787,
+    1286980, 1287236, 1287492, 1287748, 1288004, 1288260, 1288516, 1288771, 1288963, 1289156, 1289411, 1289604,
+    1289860, 1290115, 1290307, 1290499, 1005123, 1005507, 1290691, 1290884, 1291141, 1291462, 1291846, 1292229,
+    1292550, 1292934, 1293317, 1293637, 1293958, 1294342, 1294726, 1295110, 1295494, 1295878, 1296262, 1296645,
+    1296965, 1297286, 1297669, 1297990, 1298374, 1298757, 1299077, 1299397, 1299717, 1300037, 1300357, 130307,
+    1300676, 1300933, 1301253, 1301572, 1301829, 1302149, 1302468, 1302724, 1302981, 1303301, 1303621, 1303941,
+    1304261, 1304581, 1304901, 1305220, 1305476, 1305733, 1306052, 1306309, 1306629, 1306948, 1307204, 1307460,
+    1307716, 1307972, 1308228, 129603, 1308484, 1308741, 1309061, 1309380, 1309637, 1309957, 1310276, 1310532,
+    1310789, 1311109, 1311429, 1311749, 1312069, 1312389, 1312709, 1313028, 1313284, 1313541, 1313860, 1314117,
+    1314437, 1314756, 1315012, 1315268, 1315524, 1315780, 1316036, 458243, 1316292, 1316549, 1316869, 1317188,
+    1317445, 1317765, 1318084, 1318340, 1318597, 1318917, 1319237, 1319557, 1319877, 1320197, 1320517, 1320836,
+    1321092, 1321349, 1321668, 1321925, 1322245, 1322564, 1322820, 1323076, 1323332, 1323588, 1323844, 1324099,
+    1324292, 1324549, 1324869, 1325188, 1325445, 1325765, 1326084, 1326340, 1326597, 1326917, 1327237, 1327557,
+    1327877, 1328197, 1328517, 1328836, 1329092, 1329349, 1329668, 1329925, 1330245, 1330564, 1330820, 1331076,
+    1331332, 1331588, 1331844, 458435, 1332100, 1332357, 1332677, 1332996, 1333253, 1333573, 1333892, 1334148,
+    1334405, 1334725, 1335045, 1335365, 1335685, 1336005, 1336325, 1336644, 1336900, 1337157, 1337476, 1337733,
+    1338053, 1338372, 1338628, 1338884, 1339140, 1339396, 1339652, 128450, 1339907, 1340100, 1340356, 1340611,
+    1340804, 1341060, 1341315, 1341507, 1341700, 1341956, 1342212, 1342468, 1342724, 1342980, 1343236, 1343491,
+    1343683, 1343876, 1344131, 1344324, 1344580, 1344835, 1345027, 1345219, 1000899, 1001283, 1345411, 130499,
+    1345604, 1345861, 1346181, 1346500, 1346757, 1347077, 1347396, 1347652, 1347909, 1348229, 1348549, 1348869,
+    1349189, 1349509, 1349829, 1350148, 1350404, 1350661, 1350980, 1351237, 1351557, 1351876, 1352132, 1352388,
+    1012292, 1012804, 1352644, 1352900, 1353157, 1353478, 1353862, 1354245, 1354566, 1354950, 1355333, 1355653,
+    1355974, 1356358, 1356742, 1357126, 1357510, 1357894, 1358278, 1358661, 1358981, 1359302, 1359685, 1360006,
+    1360390, 1360773, 1361093, 1361413, 1361733, 1362053, 1362373, 1362692, 1362949, 1363270, 1363654, 1364037,
+    1364358, 1364742, 1365125, 1365445, 1365766, 1366150, 1366534, 1366918, 1367302, 1367686, 1368070, 1368453,
+    1368773, 1369094, 1369477, 1369798, 1370182, 1370565, 1370885, 1371205, 1371525, 1371845, 1372165, 1372485,
+    1372806, 1373191, 1373639, 1374086, 1374471, 1374919, 1375366, 1375750, 1376135, 1376583, 1377031, 1377479,
+    1377927, 1378375, 1378823, 1379270, 1379654, 1380039, 1380486, 1380871, 1381319, 1381766, 1382150, 1382534,
+    1382918, 1383302, 1383686, 1384068, 1384325, 1384646, 1385030, 1385413, 1385734, 1386118, 1386501, 1386821,
+    1387142, 1387526, 1387910, 1388294, 1388678, 1389062, 1389446, 1389829, 1390149, 1390470, 1390853, 1391174,
+    1391558, 1391941, 1392261, 1392581, 1392901, 1393221, 1393541, 131587, 1393860, 1394117, 1394437, 1394756,
+    1395013, 1395333, 1395652, 1395908, 1396165, 1396485, 1396805, 1397125, 1397445, 1397765, 1398085, 1398404,
+    1398660, 1398917, 1399236, 1399493, 1399813, 1400132, 1400388, 1400644, 1015044, 1015556, 1400900, 1401157,
+    1401478, 1401863, 1402311, 1402758, 1403143, 1403591, 1404038, 1404422, 1404807, 1405255, 1405703, 1406151,
+    1406599, 1407047, 1407495, 1407942, 1408326, 1408711, 1409158, 1409543, 1409991, 1410438, 1410822, 1411206,
+    1411590, 1411974, 1412358, 1412740, 1412997, 1413318, 1413702, 1414085, 1414406, 1414790, 1415173, 1415493,
+    1415814, 1416198, 1416582, 1416966, 1417350, 1417734, 1418118, 1418501, 1418821, 1419142, 1419525, 1419846,
+    1420230, 1420613, 1420933, 1421253, 1421573, 1421893, 1422213, 131779, 1422532, 1422789, 1423109, 1423428,
+    1423685, 1424005, 1424324, 1424580, 1424837, 1425157, 1425477, 1425797, 1426117, 1426437, 1426757, 1427076,
+    1427332, 1427589, 1427908, 1428165, 1428485, 1428804, 1429060, 1429316, 1014276, 1014788, 1429572, 1429828,
+    1430085, 1430406, 1430790, 1431173, 1431494, 1431878, 1432261, 1432581, 1432902, 1433286, 1433670, 1434054,
+    1434438, 1434822, 1435206, 1435589, 1435909, 1436230, 1436613, 1436934, 1437318, 1437701, 1438021, 1438341,
+    1438661, 1438981, 1439301, 1439621, 1439942, 1440327, 1440775, 1441222, 1441607, 1442055, 1442502, 1442886,
+    1443271, 1443719, 1444167, 1444615, 1445063, 1445511, 1445959, 1446406, 1446790, 1447175, 1447622, 1448007,
+    1448455, 1448902, 1449286, 1449670, 1450054, 1450438, 1450822, 1451204, 1451461, 1451782, 1452166, 1452549,
+    1452870, 1453254, 1453637, 1453957, 1454278, 1454662, 1455046, 1455430, 1455814, 1456198, 1456582, 1456965,
+    1457285, 1457606, 1457989, 1458310, 1458694, 1459077, 1459397, 1459717, 1460037, 1460357, 1460677, 1460996,
+    1461253, 1461574, 1461958, 1462341, 1462662, 1463046, 1463429, 1463749, 1464070, 1464454, 1464838, 1465222,
+    1465606, 1465990, 1466374, 1466757, 1467077, 1467398, 1467781, 1468102, 1468486, 1468869, 1469189, 1469509,
+    1469829, 1470149, 1470469, 130691, 1470788, 1471045, 1471365, 1471684, 1471941, 1472261, 1472580, 1472836,
+    1473093, 1473413, 1473733, 1474053, 1474373, 1474693, 1475013, 1475332, 1475588, 1475845, 1476164, 1476421,
+    1476741, 1477060, 1477316, 1477572, 1015812, 1016324, 1477828, 1478085, 1478406, 1478791, 1479239, 1479686,
+    1480071, 1480519, 1480966, 1481350, 1481735, 1482183, 1482631, 1483079, 1483527, 1483975, 1484423, 1484870,
+    1485254, 1485639, 1486086, 1486471, 1486919, 1487366, 1487750, 1488134, 1488518, 1488902, 1489286, 1489668,
+    1489925, 1490246, 1490630, 1491013, 1491334, 1491718, 1492101, 1492421, 1492742, 1493126, 1493510, 1493894,
+    1494278, 1494662, 1495046, 1495429, 1495749, 1496070, 1496453, 1496774, 1497158, 1497541, 1497861, 1498181,
+    1498501, 1498821, 1499141, 1499460, 1499717, 1500038, 1500422, 1500805, 1501126, 1501510, 1501893, 1502213,
+    1502534, 1502918, 1503302, 1503686, 1504070, 1504454, 1504838, 1505221, 1505541, 1505862, 1506245, 1506566,
+    1506950, 1507333, 1507653, 1507973, 1508293, 1508613, 1508933, 1509252, 1509509, 1509830, 1510214, 1510597,
+    1510918, 1511302, 1511685, 1512005, 1512326, 1512710, 1513094, 1513478, 1513862, 1514246, 1514630, 1515013,
+    1515333, 1515654, 1516037, 1516358, 1516742, 1517125, 1517445, 1517765, 1518085, 1518405, 1518725, 1519044,
+    1519301, 1519622, 1520006, 1520389, 1520710, 1521094, 1521477, 1521797, 1522118, 1522502, 1522886, 1523270,
+    1523654, 1524038, 1524422, 1524805, 1525125, 1525446, 1525829, 1526150, 1526534, 1526917, 1527237, 1527557,
+    1527877, 1528197, 1528517, 1528836, 1529093, 1529414, 1529798, 1530181, 1530502, 1530886, 1531269, 1531589,
+    1531910, 1532294, 1532678, 1533062, 1533446, 1533830, 1534214, 1534597, 1534917, 1535238, 1535621, 1535942,
+    1536326, 1536709, 1537029, 1537349, 1537669, 1537989, 1538309, 130883, 1538628, 1538885, 1539205, 1539524,
+    1539781, 1540101, 1540420, 1540676, 1540933, 1541253, 1541573, 1541893, 1542213, 1542533, 1542853, 1543172,
+    1543428, 1543685, 1544004, 1544261, 1544581, 1544900, 1545156, 1545412, 1010884, 1545668, 1545924, 109442,
+    1546179, 1546372, 1546628, 1546883, 1547076, 1547332, 1547587, 1547779, 1547972, 1548228, 1548484, 1548740,
+    1548996, 1549252, 1549508, 1549763, 1549955, 1550148, 1550403, 1550596, 1550852, 1551107, 1551299, 1551491,
+    1551683, 983747, 148547, 1551875, 1552068, 1552325, 1552645, 1552964, 1553221, 1553541, 1553860, 1554116,
+    1554373, 1554693, 1555013, 1555333, 1555653, 1555973, 1556293, 1556612, 1556868, 1557125, 1557444, 1557701,
+    1558021, 1558340, 1558596, 1558852, 1559108, 1559364, 1559620, 110659, 1559876, 1560133, 1560453, 1560772,
+    1561029, 1561349, 1561668, 1561924, 1562181, 1562501, 1562821, 1563141, 1563461, 1563781, 1564101, 1564420,
+    1564676, 1564933, 1565252, 1565509, 1565829, 1566148, 1566404, 1566660, 1566916, 1567172, 1567428, 1567684,
+    1567941, 1568262, 1568646, 1569029, 1569350, 1569734, 1570117, 1570437, 1570758, 1571142, 1571526, 1571910,
+    1572294, 1572678, 1573062, 1573445, 1573765, 1574086, 1574469, 1574790, 1575174, 1575557, 1575877, 1576197,
+    1576517, 1576837, 1577157, 1577475, 1577668, 1577925, 1578245, 1578564, 1578821, 1579141, 1579460, 1579716,
+    1579973, 1580293, 1580613, 1580933, 1581253, 1581573, 1581893, 1582212, 1582468, 1582725, 1583044, 1583301,
+    1583621, 1583940, 1584196, 1584452, 1584708, 1584964, 1585220, 110210, 1585475, 1585668, 1585924, 1586179,
+    1586372, 1586628, 1586883, 1587075, 1587268, 1587524, 1587780, 1588036, 1588292, 1588548, 1588804, 1589059,
+    1589251, 1589444, 1589699, 1589892, 1590148, 1590403, 1590595, 1590787, 1590979, 985411, 1591171, 1591364,
+    1591621, 1591942, 1592326, 1592709, 1593030, 1593414, 1593797, 1594117, 1594438, 1594822, 1595206, 1595590,
+    1595974, 1596358, 1596742, 1597125, 1597445, 1597766, 1598149, 1598470, 1598854, 1599237, 1599557, 1599877,
+    1600197, 1600517, 1600837, 111747, 1601156, 1601413, 1601733, 1602052, 1602309, 1602629, 1602948, 1603204,
+    1603461, 1603781, 1604101, 1604421, 1604741, 1605061, 1605381, 1605700, 1605956, 1606213, 1606532, 1606789,
+    1607109, 1607428, 1607684, 1607940, 1608196, 1608452, 1608708, 110338, 1608963, 1609156, 1609412, 1609667,
+    1609860, 1610116, 1610371, 1610563, 1610756, 1611012, 1611268, 1611524, 1611780, 1612036, 1612292, 1612547,
+    1612739, 1612932, 1613187, 1613380, 1613636, 1613891, 1614083, 1614275, 984643, 985027, 1614467, 110467,
+    1614660, 1614917, 1615237, 1615556, 1615813, 1616133, 1616452, 1616708, 1616965, 1617285, 1617605, 1617925,
+    1618245, 1618565, 1618885, 1619204, 1619460, 1619717, 1620036, 1620293, 1620613, 1620932, 1621188, 1621444,
+    1621700, 1621956, 1622212, 1622468, 1622725, 1623046, 1623430, 1623813, 1624134, 1624518, 1624901, 1625221,
+    1625542, 1625926, 1626310, 1626694, 1627078, 1627462, 1627846, 1628229, 1628549, 1628870, 1629253, 1629574,
+    1629958, 1630341, 1630661, 1630981, 1631301, 1631621, 1631941, 1632259, 1632452, 1632709, 1633029, 1633348,
+    1633605, 1633925, 1634244, 1634500, 1634757, 1635077, 1635397, 1635717, 1636037, 1636357, 1636677, 1636996,
+    1637252, 1637509, 1637828, 1638085, 1638405, 1638724, 1638980, 1639236, 1639492, 1639748, 1640004, 111939,
+    1640260, 1640517, 1640837, 1641156, 1641413, 1641733, 1642052, 1642308, 1642565, 1642885, 1643205, 1643525,
+    1643845, 1644165, 1644485, 1644804, 1645060, 1645317, 1645636, 1645893, 1646213, 1646532, 1646788, 1647044,
+    1136452, 1136964, 1647300, 109570, 1647555, 1647748, 1648004, 1648259, 1648452, 1648708, 1648963, 1649155,
+    1649348, 1649604, 1649860, 1650116, 1650372, 1650628, 1650884, 1651139, 1651331, 1651524, 1651779, 1651972,
+    1652228, 1652483, 1652675, 1652867, 985603, 985987, 1653059, 1653252, 1653509, 1653830, 1654214, 1654597,
+    1654918, 1655302, 1655685, 1656005, 1656326, 1656710, 1657094, 1657478, 1657862, 1658246, 1658630, 1659013,
+    1659333, 1659654, 1660037, 1660358, 1660742, 1661125, 1661445, 1661765, 1662085, 1662405, 1662725, 163587,
+    1663044, 1663301, 1663621, 1663940, 1664197, 1664517, 1664836, 1665092, 1665349, 1665669, 1665989, 1666309,
+    1666629, 1666949, 1667269, 1667588, 1667844, 1668101, 1668420, 1668677, 1668997, 1669316, 1669572, 1669828,
+    1670084, 1670340, 1670596, 207555, 1670852, 1671109, 1671429, 1671748, 1672005, 1672325, 1672644, 1672900,
+    1673157, 1673477, 1673797, 1674117, 1674437, 1674757, 1675077, 1675396, 1675652, 1675909, 1676228, 1676485,
+    1676805, 1677124, 1677380, 1677636, 1677892, 1678148, 1678404, 110851, 1678660, 1678917, 1679237, 1679556,
+    1679813, 1680133, 1680452, 1680708, 1680965, 1681285, 1681605, 1681925, 1682245, 1682565, 1682885, 1683204,
+    1683460, 1683717, 1684036, 1684293, 1684613, 1684932, 1685188, 1685444, 1137220, 1137732, 1685700, 1685955,
+    1686148, 1686405, 1686725, 1687044, 1687301, 1687621, 1687940, 1688196, 1688453, 1688773, 1689093, 1689413,
+    1689733, 1690053, 1690373, 1690692, 1690948, 1691205, 1691524, 1691781, 1692101, 1692420, 1692676, 1692932,
+    1693188, 1693444, 1693700, 111043, 1693956, 1694213, 1694533, 1694852, 1695109, 1695429, 1695748, 1696004,
+    1696261, 1696581, 1696901, 1697221, 1697541, 1697861, 1698181, 1698500, 1698756, 1699013, 1699332, 1699589,
+    1699909, 1700228, 1700484, 1700740, 1133572, 1134084, 1700996, 109698, 1701251, 1701444, 1701700, 1701955,
+    1702148, 1702404, 1702659, 1702851, 1703044, 1703300, 1703556, 1703812, 1704068, 1704324, 1704580, 1704835,
+    1705027, 1705220, 1705475, 1705668, 1705924, 1706179, 1706371, 1706563, 982275, 982659, 1706755, 124034,
+    1706947, 1707140, 1707396, 1707651, 1707844, 1708100, 1708355, 1708547, 1708740, 1708996, 1709252, 1709508,
+    1709764, 1710020, 1710276, 1710531, 1710723, 1710916, 1711171, 1711364, 1711620, 1711875, 1712067, 1712259,
+    957571, 957955, 1712451, 1712643, 1712836, 1713093, 1713413, 1713732, 1713989, 1714309, 1714628, 1714884,
+    1715141, 1715461, 1715781, 1716101, 1716421, 1716741, 1717061, 1717380, 1717636, 1717893, 1718212, 1718469,
+    1718789, 1719108, 1719364, 1719620, 1719876, 1720132, 1720388, 1720643, 1720836, 1721093, 1721413, 1721732,
+    1721989, 1722309, 1722628, 1722884, 1723141, 1723461, 1723781, 1724101, 1724421, 1724741, 1725061, 1725380,
+    1725636, 1725893, 1726212, 1726469, 1726789, 1727108, 1727364, 1727620, 1727876, 1728132, 1728388, 1728644,
+    1728901, 1729222, 1729606, 1729989, 1730310, 1730694, 1731077, 1731397, 1731718, 1732102, 1732486, 1732870,
+    1733254, 1733638, 1734022, 1734405, 1734725, 1735046, 1735429, 1735750, 1736134, 1736517, 1736837, 1737157,
+    1737477, 1737797, 1738117, 1738435, 1738628, 1738885, 1739205, 1739524, 1739781, 1740101, 1740420, 1740676,
+    1740933, 1741253, 1741573, 1741893, 1742213, 1742533, 1742853, 1743172, 1743428, 1743685, 1744004, 1744261,
+    1744581, 1744900, 1745156, 1745412, 1745668, 1745924, 1746180, 124802, 1539, 1746436, 1746692, 1746947,
+    1747140, 1747396, 1747651, 1747843, 1748036, 1748292, 1748548, 1748804, 1749060, 1749316, 1749572, 1749827,
+    1750019, 1750212, 1750467, 1750660, 1750916, 1751171, 1751363, 1751555, 1751747, 959363, 1751939, 1752132,
+    1752389, 1752710, 1753094, 1753477, 1753798, 1754182, 1754565, 1754885, 1755206, 1755590, 1755974, 1756358,
+    1756742, 1757126, 1757510, 1757893, 1758213, 1758534, 1758917, 1759238, 1759622, 1760005, 1760325, 1760645,
+    1760965, 1761285, 1761605, 1761923, 1762116, 1762373, 1762693, 1763012, 1763269, 1763589, 1763908, 1764164,
+    1764421, 1764741, 1765061, 1765381, 1765701, 1766021, 1766341, 1766660, 1766916, 1767173, 1767492, 1767749,
+    1768069, 1768388, 1768644, 1768900, 1769156, 1769412, 1769668, 124930, 1769923, 1770116, 1770372, 1770627,
+    1770820, 1771076, 1771331, 1771523, 1771716, 1771972, 1772228, 1772484, 1772740, 1772996, 1773252, 1773507,
+    1773699, 1773892, 1774147, 1774340, 1774596, 1774851, 1775043, 1775235, 958595, 958979, 1775427, 125059,
+    1775620, 1775877, 1776197, 1776516, 1776773, 1777093, 1777412, 1777668, 1777925, 1778245, 1778565, 1778885,
+    1779205, 1779525, 1779845, 1780164, 1780420, 1780677, 1780996, 1781253, 1781573, 1781892, 1782148, 1782404,
+    1782660, 1782916, 1783172, 1783428, 1783685, 1784006, 1784390, 1784773, 1785094, 1785478, 1785861, 1786181,
+    1786502, 1786886, 1787270, 1787654, 1788038, 1788422, 1788806, 1789189, 1789509, 1789830, 1790213, 1790534,
+    1790918, 1791301, 1791621, 1791941, 1792261, 1792581, 1792901, 1793219, 1793412, 1793669, 1793989, 1794308,
+    1794565, 1794885, 1795204, 1795460, 1795717, 1796037, 1796357, 1796677, 1796997, 1797317, 1797637, 1797956,
+    1798212, 1798469, 1798788, 1799045, 1799365, 1799684, 1799940, 1800196, 1800452, 1800708, 1800964, 1801219,
+    1801412, 1801669, 1801989, 1802308, 1802565, 1802885, 1803204, 1803460, 1803717, 1804037, 1804357, 1804677,
+    1804997, 1805317, 1805637, 1805956, 1806212, 1806469, 1806788, 1807045, 1807365, 1807684, 1807940, 1808196,
+    1808452, 1808708, 1808964, 124162, 1809219, 1809412, 1809668, 1809923, 1810116, 1810372, 1810627, 1810819,
+    1811012, 1811268, 1811524, 1811780, 1812036, 1812292, 1812548, 1812803, 1812995, 1813188, 1813443, 1813636,
+    1813892, 1814147, 1814339, 1814531, 959555, 959939, 1814723, 1814916, 1815173, 1815494, 1815878, 1816261,
+    1816582, 1816966, 1817349, 1817669, 1817990, 1818374, 1818758, 1819142, 1819526, 1819910, 1820294, 1820677,
+    1820997, 1821318, 1821701, 1822022, 1822406, 1822789, 1823109, 1823429, 1823749, 1824069, 1824389, 1824707,
+    1824900, 1825157, 1825477, 1825796, 1826053, 1826373, 1826692, 1826948, 1827205, 1827525, 1827845, 1828165,
+    1828485, 1828805, 1829125, 1829444, 1829700, 1829957, 1830276, 1830533, 1830853, 1831172, 1831428, 1831684,
+    1831940, 1832196, 1832452, 1832707, 1832900, 1833157, 1833477, 1833796, 1834053, 1834373, 1834692, 1834948,
+    1835205, 1835525, 1835845, 1836165, 1836485, 1836805, 1837125, 1837444, 1837700, 1837957, 1838276, 1838533,
+    1838853, 1839172, 1839428, 1839684, 1839940, 1840196, 1840452, 1840707, 1840900, 1841157, 1841477, 1841796,
+    1842053, 1842373, 1842692, 1842948, 1843205, 1843525, 1843845, 1844165, 1844485, 1844805, 1845125, 1845444,
+    1845700, 1845957, 1846276, 1846533, 1846853, 1847172, 1847428, 1847684, 1847940, 1848196, 1848452, 1848707,
+    1848900, 1849157, 1849477, 1849796, 1850053, 1850373, 1850692, 1850948, 1851205, 1851525, 1851845, 1852165,
+    1852485, 1852805, 1853125, 1853444, 1853700, 1853957, 1854276, 1854533, 1854853, 1855172, 1855428, 1855684,
+    1855940, 1856196, 1856452, 1856707, 1856900, 1857157, 1857477, 1857796, 1858053, 1858373, 1858692, 1858948,
+    1859205, 1859525, 1859845, 1860165, 1860485, 1860805, 1861125, 1861444, 1861700, 1861957, 1862276, 1862533,
+    1862853, 1863172, 1863428, 1863684, 1863940, 1864196, 1864452, 124290, 1864707, 1864900, 1865156, 1865411,
+    1865604, 1865860, 1866115, 1866307, 1866500, 1866756, 1867012, 1867268, 1867524, 1867780, 1868036, 1868291,
+    1868483, 1868676, 1868931, 1869124, 1869380, 1869635, 1869827, 1870019, 956291, 956675, 1870211, 125251,
+    1870404, 1870661, 1870981, 1871300, 1871557, 1871877, 1872196, 1872452, 1872709, 1873029, 1873349, 1873669,
+    1873989, 1874309, 1874629, 1874948, 1875204, 1875461, 1875780, 1876037, 1876357, 1876676, 1876932, 1877188,
+    967044, 967556, 1877444, 1877700, 1877957, 1878278, 1878662, 1879045, 1879366, 1879750, 1880133, 1880453,
+    1880774, 1881158, 1881542, 1881926, 1882310, 1882694, 1883078, 1883461, 1883781, 1884102, 1884485, 1884806,
+    1885190, 1885573, 1885893, 1886213, 1886533, 1886853, 1887173, 1887492, 1887749, 1888070, 1888454, 1888837,
+    1889158, 1889542, 1889925, 1890245, 1890566, 1890950, 1891334, 1891718, 1892102, 1892486, 1892870, 1893253,
+    1893573, 1893894, 1894277, 1894598, 1894982, 1895365, 1895685, 1896005, 1896325, 1896645, 1896965, 1897285,
+    1897606, 1897991, 1898439, 1898886, 1899271, 1899719, 1900166, 1900550, 1900935, 1901383, 1901831, 1902279,
+    1902727, 1903175, 1903623, 1904070, 1904454, 1904839, 1905286, 1905671, 1906119, 1906566, 1906950, 1907334,
+    1907718, 1908102, 1908486, 1908868, 1909125, 1909446, 1909830, 1910213, 1910534, 1910918, 1911301, 1911621,
+    1911942, 1912326, 1912710, 1913094, 1913478, 1913862, 1914246, 1914629, 1914949, 1915270, 1915653, 1915974,
+    1916358, 1916741, 1917061, 1917381, 1917701, 1918021, 1918341, 126339, 1918660, 1918917, 1919237, 1919556,
+    1919813, 1920133, 1920452, 1920708, 1920965, 1921285, 1921605, 1921925, 1922245, 1922565, 1922885, 1923204,
+    1923460, 1923717, 1924036, 1924293, 1924613, 1924932, 1925188, 1925444, 1925700, 969732, 1925956, 1926213,
+    1926534, 1926919, 1927367, 1927814, 1928199, 1928647, 1929094, 1929478, 1929863, 1930311, 1930759, 1931207,
+    1931655, 1932103, 1932551, 1932998, 1933382, 1933767, 1934214, 1934599, 1935047, 1935494, 1935878, 1936262,
+    1936646, 1937030, 1937414, 1937796, 1938053, 1938374, 1938758, 1939141, 1939462, 1939846, 1940229, 1940549,
+    1940870, 1941254, 1941638, 1942022, 1942406, 1942790, 1943174, 1943557, 1943877, 1944198, 1944581, 1944902,
+    1945286, 1945669, 1945989, 1946309, 1946629, 1946949, 1947269, 126531, 1947588, 1947845, 1948165, 1948484,
+    1948741, 1949061, 1949380, 1949636, 1949893, 1950213, 1950533, 1950853, 1951173, 1951493, 1951813, 1952132,
+    1952388, 1952645, 1952964, 1953221, 1953541, 1953860, 1954116, 1954372, 968708, 969220, 1954628, 126724,
+    1954885, 1955206, 1955590, 1955973, 1956294, 1956678, 1957061, 1957381, 1957702, 1958086, 1958470, 1958854,
+    1959238, 1959622, 1960006, 1960389, 1960709, 1961030, 1961413, 1961734, 1962118, 1962501, 1962821, 1963141,
+    1963461, 1963781, 1964101, 1964421, 1964742, 1965127, 1965575, 1966022, 1966407, 1966855, 1967302, 1967686,
+    1968071, 1968519, 1968967, 1969415, 1969863, 1970311, 1970759, 1971206, 1971590, 1971975, 1972422, 1972807,
+    1973255, 1973702, 1974086, 1974470, 1974854, 1975238, 1975622, 1976004, 1976261, 1976582, 1976966, 1977349,
+    1977670, 1978054, 1978437, 1978757, 1979078, 1979462, 1979846, 1980230, 1980614, 1980998, 1981382, 1981765,
+    1982085, 1982406, 1982789, 1983110, 1983494, 1983877, 1984197, 1984517, 1984837, 1985157, 1985477, 1985796,
+    1986053, 1986374, 1986758, 1987141, 1987462, 1987846, 1988229, 1988549, 1988870, 1989254, 1989638, 1990022,
+    1990406, 1990790, 1991174, 1991557, 1991877, 1992198, 1992581, 1992902, 1993286, 1993669, 1993989, 1994309,
+    1994629, 1994949, 1995269, 125443, 1995588, 1995845, 1996165, 1996484, 1996741, 1997061, 1997380, 1997636,
+    1997893, 1998213, 1998533, 1998853, 1999173, 1999493, 1999813, 2000132, 2000388, 2000645, 2000964, 2001221,
+    2001541, 2001860, 2002116, 2002372, 969988, 970500, 2002628, 2002885, 2003206, 2003591, 2004039, 2004486,
+    2004871, 2005319, 2005766, 2006150, 2006535, 2006983, 2007431, 2007879, 2008327, 2008775, 2009223, 2009670,
+    2010054, 2010439, 2010886, 2011271, 2011719, 2012166, 2012550, 2012934, 2013318, 2013702, 2014086, 2014468,
+    2014725, 2015046, 2015430, 2015813, 2016134, 2016518, 2016901, 2017221, 2017542, 2017926, 2018310, 2018694,
+    2019078, 2019462, 2019846, 2020229, 2020549, 2020870, 2021253, 2021574, 2021958, 2022341, 2022661, 2022981,
+    2023301, 2023621, 2023941, 2024260, 2024517, 2024838, 2025222, 2025605, 2025926, 2026310, 2026693, 2027013,
+    2027334, 2027718, 2028102, 2028486, 2028870, 2029254, 2029638, 2030021, 2030341, 2030662, 2031045, 2031366,
+    2031750, 2032133, 2032453, 2032773, 2033093, 2033413, 2033733, 2034052, 2034309, 2034630, 2035014, 2035397,
+    2035718, 2036102, 2036485, 2036805, 2037126, 2037510, 2037894, 2038278, 2038662, 2039046, 2039430, 2039813,
+    2040133, 2040454, 2040837, 2041158, 2041542, 2041925, 2042245, 2042565, 2042885, 2043205, 2043525, 2043844,
+    2044101, 2044422, 2044806, 2045189, 2045510, 2045894, 2046277, 2046597, 2046918, 2047302, 2047686, 2048070,
+    2048454, 2048838, 2049222, 2049605, 2049925, 2050246, 2050629, 2050950, 2051334, 2051717, 2052037, 2052357,
+    2052677, 2052997, 2053317, 2053636, 2053893, 2054214, 2054598, 2054981, 2055302, 2055686, 2056069, 2056389,
+    2056710, 2057094, 2057478, 2057862, 2058246, 2058630, 2059014, 2059397, 2059717, 2060038, 2060421, 2060742,
+    2061126, 2061509, 2061829, 2062149, 2062469, 2062789, 2063109, 125635, 2063428, 2063685, 2064005, 2064324,
+    2064581, 2064901, 2065220, 2065476, 2065733, 2066053, 2066373, 2066693, 2067013, 2067333, 2067653, 2067972,
+    2068228, 2068485, 2068804, 2069061, 2069381, 2069700, 2069956, 2070212, 965380, 965892, 2070468, 93314,
+    2070723, 2070916, 2071172, 2071427, 2071620, 2071876, 572931, 2072131, 2072324, 2072580, 2072836, 2073092,
+    2073348, 2073604, 2073860, 2074115, 2074307, 2074500, 2074755, 2074948, 2075204, 2075459, 2075651, 2075843,
+    1106627, 1107011, 2076035, 2076227, 2076420, 2076677, 2076997, 2077316, 2077573, 2077893, 2078212, 2078468,
+    2078725, 2079045, 2079365, 2079685, 2080005, 2080325, 2080645, 2080964, 2081220, 2081477, 2081796, 2082053,
+    2082373, 2082692, 2082948, 2083204, 2083460, 2083716, 2083972, 143363, 2084228, 2084485, 2084805, 2085124,
+    2085381, 2085701, 2086020, 2086276, 2086533, 2086853, 2087173, 2087493, 2087813, 2088133, 2088453, 2088772,
+    2089028, 2089285, 2089604, 2089861, 2090181, 2090500, 2090756, 2091012, 2091268, 2091524, 2091780, 2092036,
+    2092293, 2092614, 2092998, 2093381, 2093702, 2094086, 2094469, 2094789, 2095110, 2095494, 2095878, 2096262,
+    2096646, 2097030, 2097414, 2097797, 2098117, 2098438, 2098821, 2099142, 2099526, 2099909, 2100229, 2100549,
+    2100869, 2101189, 2101509, 2101827, 2102020, 2102277, 2102597, 2102916, 2103173, 2103493, 2103812, 2104068,
+    2104325, 2104645, 2104965, 2105285, 2105605, 2105925, 2106245, 2106564, 2106820, 2107077, 2107396, 2107653,
+    2107973, 2108292, 2108548, 2108804, 2109060, 2109316, 2109572, 94082, 2109827, 2110020, 2110276, 1173187,
+    2110532, 2110788, 2111043, 2111235, 2111428, 2111684, 2111940, 2112196, 2112452, 2112708, 2112964, 556355,
+    2113219, 2113412, 2113667, 2113860, 2114116, 2114371, 2114563, 2114755, 2114947, 1108675, 2115139, 2115332,
+    2115589, 2115910, 2116294, 2116677, 2116998, 2117382, 2117765, 2118085, 2118406, 2118790, 2119174, 2119558,
+    2119942, 2120326, 2120710, 2121093, 2121413, 2121734, 2122117, 2122438, 2122822, 2123205, 2123525, 2123845,
+    2124165, 2124485, 2124805, 2125123, 2125316, 2125573, 2125893, 2126212, 2126469, 2126789, 2127108, 2127364,
+    2127621, 2127941, 2128261, 2128581, 2128901, 2129221, 2129541, 2129860, 2130116, 2130373, 2130692, 2130949,
+    2131269, 2131588, 2131844, 2132100, 2132356, 2132612, 2132868, 94210, 2133123, 2133316, 2133572, 2133827,
+    2134020, 2134276, 2134531, 2134723, 2134916, 2135172, 2135428, 2135684, 2135940, 2136196, 2136452, 2136707,
+    2136899, 2137092, 2137347, 2137540, 2137796, 2138051, 2138243, 2138435, 1107907, 1108291, 2138627, 94339,
+    2138820, 2139077, 2139397, 2139716, 2139973, 2140293, 2140612, 2140868, 2141125, 2141445, 2141765, 2142085,
+    2142405, 2142725, 2143045, 2143364, 2143620, 2143877, 2144196, 2144453, 2144773, 2145092, 2145348, 2145604,
+    2145860, 2146116, 2146372, 2146628, 2146885, 2147206, 2147590, 2147973, 2148294, 2148678, 2149061, 2149381,
+    2149702, 2150086, 2150470, 2150854, 2151238, 2151622, 2152006, 2152389, 2152709, 2153030, 2153413, 2153734,
+    2154118, 2154501, 2154821, 2155141, 2155461, 2155781, 2156101, 2156419, 2156612, 2156869, 2157189, 2157508,
+    2157765, 2158085, 2158404, 2158660, 2158917, 2159237, 2159557, 2159877, 2160197, 2160517, 2160837, 2161156,
+    2161412, 2161669, 2161988, 2162245, 2162565, 2162884, 2163140, 2163396, 2163652, 2163908, 2164164, 2164419,
+    2164612, 2164869, 2165189, 2165508, 2165765, 2166085, 2166404, 2166660, 2166917, 2167237, 2167557, 2167877,
+    2168197, 2168517, 2168837, 2169156, 2169412, 2169669, 2169988, 2170245, 2170565, 2170884, 2171140, 2171396,
+    2171652, 2171908, 2172164, 93442, 2172419, 2172612, 2172868, 2173123, 2173316, 2173572, 2173827, 2174019,
+    2174212, 2174468, 2174724, 2174980, 2175236, 2175492, 2175748, 2176003, 2176195, 2176388, 2176643, 2176836,
+    2177092, 2177347, 2177539, 2177731, 1108867, 1109251, 2177923, 2178116, 2178373, 2178694, 2179078, 2179461,
+    2179782, 2180166, 2180549, 2180869, 2181190, 2181574, 2181958, 2182342, 2182726, 2183110, 2183494, 2183877,
+    2184197, 2184518, 2184901, 2185222, 2185606, 2185989, 2186309, 2186629, 2186949, 2187269, 2187589, 208451,
+    2187908, 2188165, 2188485, 2188804, 2189061, 2189381, 2189700, 2189956, 2190213, 2190533, 2190853, 2191173,
+    2191493, 2191813, 2192133, 2192452, 2192708, 2192965, 2193284, 2193541, 2193861, 2194180, 2194436, 2194692,
+    2194948, 2195204, 2195460, 208643, 2195716, 2195973, 2196293, 2196612, 2196869, 2197189, 2197508, 2197764,
+    2198021, 2198341, 2198661, 2198981, 2199301, 2199621, 2199941, 2200260, 2200516, 2200773, 2201092, 2201349,
+    2201669, 2201988, 2202244, 2202500, 2202756, 2203012, 2203268, 2203523, 2203716, 2203973, 2204293, 2204612,
+    2204869, 2205189, 2205508, 2205764, 2206021, 2206341, 2206661, 2206981, 2207301, 2207621, 2207941, 2208260,
+    2208516, 2208773, 2209092, 2209349, 2209669, 2209988, 2210244, 2210500, 2210756, 2211012, 2211268, 219651,
+    2211524, 2211781, 2212101, 2212420, 2212677, 2212997, 2213316, 2213572, 2213829, 2214149, 2214469, 2214789,
+    2215109, 2215429, 2215749, 2216068, 2216324, 2216581, 2216900, 2217157, 2217477, 2217796, 2218052, 2218308,
+    2218564, 2218820, 2219076, 2219331, 2219524, 2219781, 2220101, 2220420, 2220677, 2220997, 2221316, 2221572,
+    2221829, 2222149, 2222469, 2222789, 2223109, 2223429, 2223749, 2224068, 2224324, 2224581, 2224900, 2225157,
+    2225477, 2225796, 2226052, 2226308, 2226564, 2226820, 2227076, 93570, 2227331, 2227524, 2227780, 2228035,
+    2228228, 2228484, 2228739, 2228931, 2229124, 2229380, 2229636, 2229892, 2230148, 2230404, 2230660, 2230915,
+    2231107, 2231300, 2231555, 2231748, 2232004, 2232259, 2232451, 2232643, 2232835, 2233027, 2233219, 90370,
+    2233411, 2233604, 2233860, 2234115, 2234308, 2234564, 2234819, 2235011, 2235204, 2235460, 2235716, 2235972,
+    2236228, 2236484, 2236740, 2236995, 2237187, 2237380, 2237635, 2237828, 2238084, 2238339, 2238531, 2238723,
+    943747, 944131, 2238915, 2239107, 2239300, 2239557, 2239877, 2240196, 2240453, 2240773, 2241092, 2241348,
+    2241605, 2241925, 2242245, 2242565, 2242885, 2243205, 2243525, 2243844, 2244100, 2244357, 2244676, 2244933,
+    2245253, 2245572, 2245828, 2246084, 2246340, 2246596, 2246852, 143555, 2247108, 2247365, 2247685, 2248004,
+    2248261, 2248581, 2248900, 2249156, 2249413, 2249733, 2250053, 2250373, 2250693, 2251013, 2251333, 2251652,
+    2251908, 2252165, 2252484, 2252741, 2253061, 2253380, 2253636, 2253892, 2254148, 2254404, 2254660, 2254916,
+    2255173, 2255494, 2255878, 2256261, 2256582, 2256966, 2257349, 2257669, 2257990, 2258374, 2258758, 2259142,
+    2259526, 2259910, 2260294, 2260677, 2260997, 2261318, 2261701, 2262022, 2262406, 2262789, 2263109, 2263429,
+    2263749, 2264069, 2264389, 2264707, 2264900, 2265157, 2265477, 2265796, 2266053, 2266373, 2266692, 2266948,
+    2267205, 2267525, 2267845, 2268165, 2268485, 2268805, 2269125, 2269444, 2269700, 2269957, 2270276, 2270533,
+    2270853, 2271172, 2271428, 2271684, 2271940, 2272196, 2272452, 91138, 2272707, 2272900, 2273156, 1173379,
+    2273412, 2273668, 2273923, 2274115, 2274308, 2274564, 2274820, 2275076, 2275332, 2275588, 2275844, 2276099,
+    2276291, 2276484, 2276739, 2276932, 2277188, 2277443, 2277635, 2277827, 2278019, 2278211, 2278403, 2278596,
+    2278853, 2279174, 2279558, 2279941, 2280262, 2280646, 2281029, 2281349, 2281670, 2282054, 2282438, 2282822,
+    2283206, 2283590, 2283974, 2284357, 2284677, 2284998, 2285381, 2285702, 2286086, 2286469, 2286789, 2287109,
+    2287429, 2287749, 2288069, 2288387, 2288580, 2288837, 2289157, 2289476, 2289733, 2290053, 2290372, 2290628,
+    2290885, 2291205, 2291525, 2291845, 2292165, 2292485, 2292805, 2293124, 2293380, 2293637, 2293956, 2294213,
+    2294533, 2294852, 2295108, 2295364, 2295620, 2295876, 2296132, 91266, 2296387, 2296580, 2296836, 2297091,
+    2297284, 2297540, 2297795, 579587, 2297988, 2298244, 2298500, 2298756, 2299012, 2299268, 2299524, 2299779,
+    2299971, 2300164, 2300419, 2300612, 2300868, 2301123, 2301315, 2301507, 945283, 945667, 2301699, 91395,
+    2301892, 2302149, 2302469, 2302788, 2303045, 2303365, 2303684, 2303940, 2304197, 2304517, 2304837, 2305157,
+    2305477, 2305797, 2306117, 2306436, 2306692, 2306949, 2307268, 2307525, 2307845, 2308164, 2308420, 2308676,
+    2308932, 2309188, 2309444, 2309700, 2309957, 2310278, 2310662, 2311045, 2311366, 2311750, 2312133, 2312453,
+    2312774, 2313158, 2313542, 2313926, 2314310, 2314694, 2315078, 2315461, 2315781, 2316102, 2316485, 2316806,
+    2317190, 2317573, 2317893, 2318213, 2318533, 2318853, 2319173, 2319491, 2319684, 2319941, 2320261, 2320580,
+    2320837, 2321157, 2321476, 2321732, 2321989, 2322309, 2322629, 2322949, 2323269, 2323589, 2323909, 2324228,
+    2324484, 2324741, 2325060, 2325317, 2325637, 2325956, 2326212, 2326468, 2326724, 2326980, 2327236, 2327491,
+    2327684, 2327941, 2328261, 2328580, 2328837, 2329157, 2329476, 2329732, 2329989, 2330309, 2330629, 2330949,
+    2331269, 2331589, 2331909, 2332228, 2332484, 2332741, 2333060, 2333317, 2333637, 2333956, 2334212, 2334468,
+    2334724, 2334980, 2335236, 90498, 2335491, 2335684, 2335940, 2336195, 2336388, 2336644, 2336899, 2337091,
+    2337284, 2337540, 2337796, 2338052, 2338308, 2338564, 2338820, 2339075, 2339267, 2339460, 2339715, 2339908,
+    2340164, 2340419, 2340611, 2340803, 946051, 946435, 2340995, 2341188, 2341445, 2341766, 2342150, 2342533,
+    2342854, 2343238, 2343621, 2343941, 2344262, 2344646, 2345030, 2345414, 2345798, 2346182, 2346566, 2346949,
+    2347269, 2347590, 2347973, 2348294, 2348678, 2349061, 2349381, 2349701, 2350021, 2350341, 2350661, 146563,
+    2350980, 2351237, 2351557, 2351876, 2352133, 2352453, 2352772, 2353028, 2353285, 2353605, 2353925, 2354245,
+    2354565, 2354885, 2355205, 2355524, 2355780, 2356037, 2356356, 2356613, 2356933, 2357252, 2357508, 2357764,
+    2358020, 2358276, 2358532, 146755, 2358788, 2359045, 2359365, 2359684, 2359941, 2360261, 2360580, 2360836,
+    2361093, 2361413, 2361733, 2362053, 2362373, 2362693, 2363013, 2363332, 2363588, 2363845, 2364164, 2364421,
+    2364741, 2365060, 2365316, 2365572, 2365828, 2366084, 2366340, 2366595, 2366788, 2367045, 2367365, 2367684,
+    2367941, 2368261, 2368580, 2368836, 2369093, 2369413, 2369733, 2370053, 2370373, 2370693, 2371013, 2371332,
+    2371588, 2371845, 2372164, 2372421, 2372741, 2373060, 2373316, 2373572, 2373828, 2374084, 2374340, 2374595,
+    2374788, 2375045, 2375365, 2375684, 2375941, 2376261, 2376580, 2376836, 2377093, 2377413, 2377733, 2378053,
+    2378373, 2378693, 2379013, 2379332, 2379588, 2379845, 2380164, 2380421, 2380741, 2381060, 2381316, 2381572,
+    2381828, 2382084, 2382340, 2382595, 2382788, 2383045, 2383365, 2383684, 2383941, 2384261, 2384580, 2384836,
+    2385093, 2385413, 2385733, 2386053, 2386373, 2386693, 2387013, 2387332, 2387588, 2387845, 2388164, 2388421,
+    2388741, 2389060, 2389316, 2389572, 2389828, 2390084, 2390340, 90626, 2390595, 2390788, 2391044, 2391299,
+    2391492, 2391748, 2392003, 225475, 2392196, 2392452, 2392708, 2392964, 2393220, 2393476, 2393732, 2393987,
+    2394179, 2394372, 2394627, 2394820, 2395076, 2395331, 2395523, 2395715, 942467, 942851, 2395907, 102722,
+    2396099, 2396292, 2396548, 2396803, 2396996, 2397252, 2397507, 2397699, 2397892, 2398148, 2398404, 2398660,
+    2398916, 2399172, 2399428, 2399683, 2399875, 2400068, 2400323, 2400516, 2400772, 2401027, 2401219, 2401411,
+    910211, 910595, 2401603, 2401795, 2401988, 2402245, 2402565, 2402884, 2403141, 2403461, 2403780, 2404036,
+    2404293, 2404613, 2404933, 2405253, 2405573, 2405893, 2406213, 2406532, 2406788, 2407045, 2407364, 2407621,
+    2407941, 2408260, 2408516, 2408772, 2409028, 2409284, 2409540, 2409795, 2409988, 2410245, 2410565, 2410884,
+    2411141, 2411461, 2411780, 2412036, 2412293, 2412613, 2412933, 2413253, 2413573, 2413893, 2414213, 2414532,
+    2414788, 2415045, 2415364, 2415621, 2415941, 2416260, 2416516, 2416772, 2417028, 2417284, 2417540, 2417796,
+    2418053, 2418374, 2418758, 2419141, 2419462, 2419846, 2420229, 2420549, 2420870, 2421254, 2421638, 2422022,
+    2422406, 2422790, 2423174, 2423557, 2423877, 2424198, 2424581, 2424902, 2425286, 2425669, 2425989, 2426309,
+    2426629, 2426949, 2427269, 2427587, 2427780, 2428037, 2428357, 2428676, 2428933, 2429253, 2429572, 2429828,
+    2430085, 2430405, 2430725, 2431045, 2431365, 2431685, 2432005, 2432324, 2432580, 2432837, 2433156, 2433413,
+    2433733, 2434052, 2434308, 2434564, 2434820, 2435076, 2435332, 103490, 2435587, 2435780, 2436036, 2436291,
+    2436484, 2436740, 2436995, 2437187, 2437380, 2437636, 2437892, 2438148, 2438404, 2438660, 2438916, 2439171,
+    2439363, 2439556, 2439811, 2440004, 2440260, 2440515, 2440707, 2440899, 2441091, 912259, 2441283, 2441476,
+    2441733, 2442054, 2442438, 2442821, 2443142, 2443526, 2443909, 2444229, 2444550, 2444934, 2445318, 2445702,
+    2446086, 2446470, 2446854, 2447237, 2447557, 2447878, 2448261, 2448582, 2448966, 2449349, 2449669, 2449989,
+    2450309, 2450629, 2450949, 2451267, 2451460, 2451717, 2452037, 2452356, 2452613, 2452933, 2453252, 2453508,
+    2453765, 2454085, 2454405, 2454725, 2455045, 2455365, 2455685, 2456004, 2456260, 2456517, 2456836, 2457093,
+    2457413, 2457732, 2457988, 2458244, 2458500, 2458756, 2459012, 103618, 2459267, 2459460, 2459716, 2459971,
+    2460164, 2460420, 2460675, 2460867, 2461060, 2461316, 2461572, 2461828, 2462084, 2462340, 2462596, 2462851,
+    2463043, 2463236, 2463491, 2463684, 2463940, 2464195, 2464387, 2464579, 911491, 911875, 2464771, 103747,
+    2464964, 2465221, 2465541, 2465860, 2466117, 2466437, 2466756, 2467012, 2467269, 2467589, 2467909, 2468229,
+    2468549, 2468869, 2469189, 2469508, 2469764, 2470021, 2470340, 2470597, 2470917, 2471236, 2471492, 2471748,
+    2472004, 2472260, 2472516, 2472772, 2473029, 2473350, 2473734, 2474117, 2474438, 2474822, 2475205, 2475525,
+    2475846, 2476230, 2476614, 2476998, 2477382, 2477766, 2478150, 2478533, 2478853, 2479174, 2479557, 2479878,
+    2480262, 2480645, 2480965, 2481285, 2481605, 2481925, 2482245, 2482563, 2482756, 2483013, 2483333, 2483652,
+    2483909, 2484229, 2484548, 2484804, 2485061, 2485381, 2485701, 2486021, 2486341, 2486661, 2486981, 2487300,
+    2487556, 2487813, 2488132, 2488389, 2488709, 2489028, 2489284, 2489540, 2489796, 2490052, 2490308, 2490563,
+    2490756, 2491013, 2491333, 2491652, 2491909, 2492229, 2492548, 2492804, 2493061, 2493381, 2493701, 2494021,
+    2494341, 2494661, 2494981, 2495300, 2495556, 2495813, 2496132, 2496389, 2496709, 2497028, 2497284, 2497540,
+    2497796, 2498052, 2498308, 102850, 2498563, 2498756, 2499012, 2499267, 2499460, 2499716, 2499971, 2500163,
+    2500356, 2500612, 2500868, 2501124, 2501380, 2501636, 2501892, 2502147, 2502339, 2502532, 2502787, 2502980,
+    2503236, 2503491, 2503683, 2503875, 912451, 912835, 2504067, 2504260, 2504517, 2504838, 2505222, 2505605,
+    2505926, 2506310, 2506693, 2507013, 2507334, 2507718, 2508102, 2508486, 2508870, 2509254, 2509638, 2510021,
+    2510341, 2510662, 2511045, 2511366, 2511750, 2512133, 2512453, 2512773, 2513093, 2513413, 2513733, 146947,
+    2514052, 2514309, 2514629, 2514948, 2515205, 2515525, 2515844, 2516100, 2516357, 2516677, 2516997, 2517317,
+    2517637, 2517957, 2518277, 2518596, 2518852, 2519109, 2519428, 2519685, 2520005, 2520324, 2520580, 2520836,
+    2521092, 2521348, 2521604, 147139, 2521860, 2522117, 2522437, 2522756, 2523013, 2523333, 2523652, 2523908,
+    2524165, 2524485, 2524805, 2525125, 2525445, 2525765, 2526085, 2526404, 2526660, 2526917, 2527236, 2527493,
+    2527813, 2528132, 2528388, 2528644, 2528900, 2529156, 2529412, 2529667, 2529860, 2530117, 2530437, 2530756,
+    2531013, 2531333, 2531652, 2531908, 2532165, 2532485, 2532805, 2533125, 2533445, 2533765, 2534085, 2534404,
+    2534660, 2534917, 2535236, 2535493, 2535813, 2536132, 2536388, 2536644, 2536900, 2537156, 2537412, 2537667,
+    2537860, 2538117, 2538437, 2538756, 2539013, 2539333, 2539652, 2539908, 2540165, 2540485, 2540805, 2541125,
+    2541445, 2541765, 2542085, 2542404, 2542660, 2542917, 2543236, 2543493, 2543813, 2544132, 2544388, 2544644,
+    2544900, 2545156, 2545412, 2545667, 2545860, 2546117, 2546437, 2546756, 2547013, 2547333, 2547652, 2547908,
+    2548165, 2548485, 2548805, 2549125, 2549445, 2549765, 2550085, 2550404, 2550660, 2550917, 2551236, 2551493,
+    2551813, 2552132, 2552388, 2552644, 2552900, 2553156, 2553412, 102978, 2553667, 2553860, 2554116, 2554371,
+    2554564, 2554820, 2555075, 2555267, 2555460, 2555716, 2555972, 2556228, 2556484, 2556740, 2556996, 2557251,
+    2557443, 2557636, 2557891, 2558084, 2558340, 2558595, 2558787, 2558979, 908675, 909059, 2559171, 922627,
+    2559364, 2559621, 2559941, 2560260, 2560517, 2560837, 2561156, 2561412, 2561669, 2561989, 2562309, 2562629,
+    2562949, 2563269, 2563589, 2563908, 2564164, 2564421, 2564740, 2564997, 2565317, 2565636, 2565892, 2566148,
+    922116, 922820, 2566404, 2566660, 2566917, 2567238, 2567622, 2568005, 2568326, 2568710, 2569093, 2569413,
+    2569734, 2570118, 2570502, 2570886, 2571270, 2571654, 2572038, 2572421, 2572741, 2573062, 2573445, 2573766,
+    2574150, 2574533, 2574853, 2575173, 2575493, 2575813, 2576133, 2576452, 2576709, 2577030, 2577414, 2577797,
+    2578118, 2578502, 2578885, 2579205, 2579526, 2579910, 2580294, 2580678, 2581062, 2581446, 2581830, 2582213,
+    2582533, 2582854, 2583237, 2583558, 2583942, 2584325, 2584645, 2584965, 2585285, 2585605, 2585925, 2586245,
+    2586566, 2586951, 2587399, 2587846, 2588231, 2588679, 2589126, 2589510, 2589895, 2590343, 2590791, 2591239,
+    2591687, 2592135, 2592583, 2593030, 2593414, 2593799, 2594246, 2594631, 2595079, 2595526, 2595910, 2596294,
+    2596678, 2597062, 2597446, 2597828, 2598085, 2598406, 2598790, 2599173, 2599494, 2599878, 2600261, 2600581,
+    2600902, 2601286, 2601670, 2602054, 2602438, 2602822, 2603206, 2603589, 2603909, 2604230, 2604613, 2604934,
+    2605318, 2605701, 2606021, 2606341, 2606661, 2606981, 2607301, 925187, 2607620, 2607877, 2608197, 2608516,
+    2608773, 2609093, 2609412, 2609668, 2609925, 2610245, 2610565, 2610885, 2611205, 2611525, 2611845, 2612164,
+    2612420, 2612677, 2612996, 2613253, 2613573, 2613892, 2614148, 2614404, 2614660, 925380, 2614916, 2615173,
+    2615494, 2615879, 2616327, 2616774, 2617159, 2617607, 2618054, 2618438, 2618823, 2619271, 2619719, 2620167,
+    2620615, 2621063, 2621511, 2621958, 2622342, 2622727, 2623174, 2623559, 2624007, 2624454, 2624838, 2625222,
+    2625606, 2625990, 2626374, 2626756, 2627013, 2627334, 2627718, 2628101, 2628422, 2628806, 2629189, 2629509,
+    2629830, 2630214, 2630598, 2630982, 2631366, 2631750, 2632134, 2632517, 2632837, 2633158, 2633541, 2633862,
+    2634246, 2634629, 2634949, 2635269, 2635589, 2635909, 2636229, 924483, 2636548, 2636805, 2637125, 2637444,
+    2637701, 2638021, 2638340, 2638596, 2638853, 2639173, 2639493, 2639813, 2640133, 2640453, 2640773, 2641092,
+    2641348, 2641605, 2641924, 2642181, 2642501, 2642820, 2643076, 2643332, 923972, 924676, 2643588, 2643844,
+    2644101, 2644422, 2644806, 2645189, 2645510, 2645894, 2646277, 2646597, 2646918, 2647302, 2647686, 2648070,
+    2648454, 2648838, 2649222, 2649605, 2649925, 2650246, 2650629, 2650950, 2651334, 2651717, 2652037, 2652357,
+    2652677, 2652997, 2653317, 2653637, 2653958, 2654343, 2654791, 2655238, 2655623, 2656071, 2656518, 2656902,
+    2657287, 2657735, 2658183, 2658631, 2659079, 2659527, 2659975, 2660422, 2660806, 2661191, 2661638, 2662023,
+    2662471, 2662918, 2663302, 2663686, 2664070, 2664454, 2664838, 2665220, 2665477, 2665798, 2666182, 2666565,
+    2666886, 2667270, 2667653, 2667973, 2668294, 2668678, 2669062, 2669446, 2669830, 2670214, 2670598, 2670981,
+    2671301, 2671622, 2672005, 2672326, 2672710, 2673093, 2673413, 2673733, 2674053, 2674373, 2674693, 2675012,
+    2675269, 2675590, 2675974, 2676357, 2676678, 2677062, 2677445, 2677765, 2678086, 2678470, 2678854, 2679238,
+    2679622, 2680006, 2680390, 2680773, 2681093, 2681414, 2681797, 2682118, 2682502, 2682885, 2683205, 2683525,
+    2683845, 2684165, 2684485, 926147, 2684804, 2685061, 2685381, 2685700, 2685957, 2686277, 2686596, 2686852,
+    2687109, 2687429, 2687749, 2688069, 2688389, 2688709, 2689029, 2689348, 2689604, 2689861, 2690180, 2690437,
+    2690757, 2691076, 2691332, 2691588, 925636, 926340, 2691844, 2692101, 2692422, 2692807, 2693255, 2693702,
+    2694087, 2694535, 2694982, 2695366, 2695751, 2696199, 2696647, 2697095, 2697543, 2697991, 2698439, 2698886,
+    2699270, 2699655, 2700102, 2700487, 2700935, 2701382, 2701766, 2702150, 2702534, 2702918, 2703302, 2703684,
+    2703941, 2704262, 2704646, 2705029, 2705350, 2705734, 2706117, 2706437, 2706758, 2707142, 2707526, 2707910,
+    2708294, 2708678, 2709062, 2709445, 2709765, 2710086, 2710469, 2710790, 2711174, 2711557, 2711877, 2712197,
+    2712517, 2712837, 2713157, 2713476, 2713733, 2714054, 2714438, 2714821, 2715142, 2715526, 2715909, 2716229,
+    2716550, 2716934, 2717318, 2717702, 2718086, 2718470, 2718854, 2719237, 2719557, 2719878, 2720261, 2720582,
+    2720966, 2721349, 2721669, 2721989, 2722309, 2722629, 2722949, 2723268, 2723525, 2723846, 2724230, 2724613,
+    2724934, 2725318, 2725701, 2726021, 2726342, 2726726, 2727110, 2727494, 2727878, 2728262, 2728646, 2729029,
+    2729349, 2729670, 2730053, 2730374, 2730758, 2731141, 2731461, 2731781, 2732101, 2732421, 2732741, 2733060,
+    2733317, 2733638, 2734022, 2734405, 2734726, 2735110, 2735493, 2735813, 2736134, 2736518, 2736902, 2737286,
+    2737670, 2738054, 2738438, 2738821, 2739141, 2739462, 2739845, 2740166, 2740550, 2740933, 2741253, 2741573,
+    2741893, 2742213, 2742533, 2742852, 2743109, 2743430, 2743814, 2744197, 2744518, 2744902, 2745285, 2745605,
+    2745926, 2746310, 2746694, 2747078, 2747462, 2747846, 2748230, 2748613, 2748933, 2749254, 2749637, 2749958,
+    2750342, 2750725, 2751045, 2751365, 2751685, 2752005, 2752325, 920451, 2752644, 2752901, 2753221, 2753540,
+    2753797, 2754117, 2754436, 2754692, 2754949, 2755269, 2755589, 2755909, 2756229, 2756549, 2756869, 2757188,
+    2757444, 2757701, 2758020, 2758277, 2758597, 2758916, 2759172, 2759428, 919940, 920644, 2759684, 94530,
+    2759939, 2760132, 2760388, 2760643, 2760836, 2761092, 2761347, 2761539, 2761732, 2761988, 2762244, 2762500,
+    2762756, 2763012, 2763268, 2763523, 2763715, 2763908, 2764163, 2764356, 2764612, 2764867, 2765059, 2765251,
+    1065347, 1065731, 2765443, 2765635, 2765828, 2766085, 2766405, 2766724, 2766981, 2767301, 2767620, 2767876,
+    2768133, 2768453, 2768773, 2769093, 2769413, 2769733, 2770053, 2770372, 2770628, 2770885, 2771204, 2771461,
+    2771781, 2772100, 2772356, 2772612, 2772868, 2773124, 2773380, 2773635, 2773828, 2774085, 2774405, 2774724,
+    2774981, 2775301, 2775620, 2775876, 2776133, 2776453, 2776773, 2777093, 2777413, 2777733, 2778053, 2778372,
+    2778628, 2778885, 2779204, 2779461, 2779781, 2780100, 2780356, 2780612, 2780868, 2781124, 2781380, 2781636,
+    2781893, 2782214, 2782598, 2782981, 2783302, 2783686, 2784069, 2784389, 2784710, 2785094, 2785478, 2785862,
+    2786246, 2786630, 2787014, 2787397, 2787717, 2788038, 2788421, 2788742, 2789126, 2789509, 2789829, 2790149,
+    2790469, 2790789, 2791109, 2791427, 2791620, 2791877, 2792197, 2792516, 2792773, 2793093, 2793412, 2793668,
+    2793925, 2794245, 2794565, 2794885, 2795205, 2795525, 2795845, 2796164, 2796420, 2796677, 2796996, 2797253,
+    2797573, 2797892, 2798148, 2798404, 2798660, 2798916, 2799172, 95298, 2799427, 2799620, 2799876, 2800131,
+    2800324, 2800580, 2800835, 2801027, 2801220, 2801476, 2801732, 2801988, 2802244, 2802500, 2802756, 2803011,
+    2803203, 2803396, 2803651, 2803844, 2804100, 2804355, 2804547, 2804739, 2804931, 1067395, 2805123, 2805316,
+    2805573, 2805894, 2806278, 2806661, 2806982, 2807366, 2807749, 2808069, 2808390, 2808774, 2809158, 2809542,
+    2809926, 2810310, 2810694, 2811077, 2811397, 2811718, 2812101, 2812422, 2812806, 2813189, 2813509, 2813829,
+    2814149, 2814469, 2814789, 2815107, 2815300, 2815557, 2815877, 2816196, 2816453, 2816773, 2817092, 2817348,
+    2817605, 2817925, 2818245, 2818565, 2818885, 2819205, 2819525, 2819844, 2820100, 2820357, 2820676, 2820933,
+    2821253, 2821572, 2821828, 2822084, 2822340, 2822596, 2822852, 95426, 2823107, 2823300, 2823556, 2823811,
+    2824004, 2824260, 2824515, 2824707, 2824900, 2825156, 2825412, 2825668, 2825924, 2826180, 2826436, 2826691,
+    2826883, 2827076, 2827331, 2827524, 2827780, 2828035, 2828227, 2828419, 1066627, 1067011, 2828611, 95555,
+    2828804, 2829061, 2829381, 2829700, 2829957, 2830277, 2830596, 2830852, 2831109, 2831429, 2831749, 2832069,
+    2832389, 2832709, 2833029, 2833348, 2833604, 2833861, 2834180, 2834437, 2834757, 2835076, 2835332, 2835588,
+    2835844, 2836100, 2836356, 2836612, 2836869, 2837190, 2837574, 2837957, 2838278, 2838662, 2839045, 2839365,
+    2839686, 2840070, 2840454, 2840838, 2841222, 2841606, 2841990, 2842373, 2842693, 2843014, 2843397, 2843718,
+    2844102, 2844485, 2844805, 2845125, 2845445, 2845765, 2846085, 2846403, 2846596, 2846853, 2847173, 2847492,
+    2847749, 2848069, 2848388, 2848644, 2848901, 2849221, 2849541, 2849861, 2850181, 2850501, 2850821, 2851140,
+    2851396, 2851653, 2851972, 2852229, 2852549, 2852868, 2853124, 2853380, 2853636, 2853892, 2854148, 2854403,
+    2854596, 2854853, 2855173, 2855492, 2855749, 2856069, 2856388, 2856644, 2856901, 2857221, 2857541, 2857861,
+    2858181, 2858501, 2858821, 2859140, 2859396, 2859653, 2859972, 2860229, 2860549, 2860868, 2861124, 2861380,
+    2861636, 2861892, 2862148, 94658, 2862403, 2862596, 2862852, 2863107, 2863300, 2863556, 2863811, 2864003,
+    2864196, 2864452, 2864708, 2864964, 2865220, 2865476, 2865732, 2865987, 2866179, 2866372, 2866627, 2866820,
+    2867076, 2867331, 2867523, 2867715, 1067587, 1067971, 2867907, 2868100, 2868357, 2868678, 2869062, 2869445,
+    2869766, 2870150, 2870533, 2870853, 2871174, 2871558, 2871942, 2872326, 2872710, 2873094, 2873478, 2873861,
+    2874181, 2874502, 2874885, 2875206, 2875590, 2875973, 2876293, 2876613, 2876933, 2877253, 2877573, 166659,
+    2877892, 2878149, 2878469, 2878788, 2879045, 2879365, 2879684, 2879940, 2880197, 2880517, 2880837, 2881157,
+    2881477, 2881797, 2882117, 2882436, 2882692, 2882949, 2883268, 2883525, 2883845, 2884164, 2884420, 2884676,
+    2884932, 2885188, 2885444, 166851, 2885700, 2885957, 2886277, 2886596, 2886853, 2887173, 2887492, 2887748,
+    2888005, 2888325, 2888645, 2888965, 2889285, 2889605, 2889925, 2890244, 2890500, 2890757, 2891076, 2891333,
+    2891653, 2891972, 2892228, 2892484, 2892740, 2892996, 2893252, 2893507, 2893700, 2893957, 2894277, 2894596,
+    2894853, 2895173, 2895492, 2895748, 2896005, 2896325, 2896645, 2896965, 2897285, 2897605, 2897925, 2898244,
+    2898500, 2898757, 2899076, 2899333, 2899653, 2899972, 2900228, 2900484, 2900740, 2900996, 2901252, 2901507,
+    2901700, 2901957, 2902277, 2902596, 2902853, 2903173, 2903492, 2903748, 2904005, 2904325, 2904645, 2904965,
+    2905285, 2905605, 2905925, 2906244, 2906500, 2906757, 2907076, 2907333, 2907653, 2907972, 2908228, 2908484,
+    2908740, 2908996, 2909252, 2909507, 2909700, 2909957, 2910277, 2910596, 2910853, 2911173, 2911492, 2911748,
+    2912005, 2912325, 2912645, 2912965, 2913285, 2913605, 2913925, 2914244, 2914500, 2914757, 2915076, 2915333,
+    2915653, 2915972, 2916228, 2916484, 2916740, 2916996, 2917252, 94786, 2917507, 2917700, 2917956, 2918211,
+    2918404, 2918660, 2918915, 2919107, 2919300, 2919556, 2919812, 2920068, 2920324, 2920580, 2920836, 2921091,
+    2921283, 2921476, 2921731, 2921924, 2922180, 2922435, 2922627, 2922819, 1064067, 1064451, 2923011, 1072003,
+    2923204, 2923461, 2923781, 2924100, 2924357, 2924677, 2924996, 2925252, 2925509, 2925829, 2926149, 2926469,
+    2926789, 2927109, 2927429, 2927748, 2928004, 2928261, 2928580, 2928837, 2929157, 2929476, 2929732, 2929988,
+    1071492, 1072196, 2930244, 2930500, 2930757, 2931078, 2931462, 2931845, 2932166, 2932550, 2932933, 2933253,
+    2933574, 2933958, 2934342, 2934726, 2935110, 2935494, 2935878, 2936261, 2936581, 2936902, 2937285, 2937606,
+    2937990, 2938373, 2938693, 2939013, 2939333, 2939653, 2939973, 2940292, 2940549, 2940870, 2941254, 2941637,
+    2941958, 2942342, 2942725, 2943045, 2943366, 2943750, 2944134, 2944518, 2944902, 2945286, 2945670, 2946053,
+    2946373, 2946694, 2947077, 2947398, 2947782, 2948165, 2948485, 2948805, 2949125, 2949445, 2949765, 2950085,
+    2950406, 2950791, 2951239, 2951686, 2952071, 2952519, 2952966, 2953350, 2953735, 2954183, 2954631, 2955079,
+    2955527, 2955975, 2956423, 2956870, 2957254, 2957639, 2958086, 2958471, 2958919, 2959366, 2959750, 2960134,
+    2960518, 2960902, 2961286, 2961668, 2961925, 2962246, 2962630, 2963013, 2963334, 2963718, 2964101, 2964421,
+    2964742, 2965126, 2965510, 2965894, 2966278, 2966662, 2967046, 2967429, 2967749, 2968070, 2968453, 2968774,
+    2969158, 2969541, 2969861, 2970181, 2970501, 2970821, 2971141, 1073667, 2971460, 2971717, 2972037, 2972356,
+    2972613, 2972933, 2973252, 2973508, 2973765, 2974085, 2974405, 2974725, 2975045, 2975365, 2975685, 2976004,
+    2976260, 2976517, 2976836, 2977093, 2977413, 2977732, 2977988, 2978244, 2978500, 1073860, 2978756, 2979013,
+    2979334, 2979719, 2980167, 2980614, 2980999, 2981447, 2981894, 2982278, 2982663, 2983111, 2983559, 2984007,
+    2984455, 2984903, 2985351, 2985798, 2986182, 2986567, 2987014, 2987399, 2987847, 2988294, 2988678, 2989062,
+    2989446, 2989830, 2990214, 2990596, 2990853, 2991174, 2991558, 2991941, 2992262, 2992646, 2993029, 2993349,
+    2993670, 2994054, 2994438, 2994822, 2995206, 2995590, 2995974, 2996357, 2996677, 2996998, 2997381, 2997702,
+    2998086, 2998469, 2998789, 2999109, 2999429, 2999749, 3000069, 1072963, 3000388, 3000645, 3000965, 3001284,
+    3001541, 3001861, 3002180, 3002436, 3002693, 3003013, 3003333, 3003653, 3003973, 3004293, 3004613, 3004932,
+    3005188, 3005445, 3005764, 3006021, 3006341, 3006660, 3006916, 3007172, 1072452, 1073156, 3007428, 3007684,
+    3007941, 3008262, 3008646, 3009029, 3009350, 3009734, 3010117, 3010437, 3010758, 3011142, 3011526, 3011910,
+    3012294, 3012678, 3013062, 3013445, 3013765, 3014086, 3014469, 3014790, 3015174, 3015557, 3015877, 3016197,
+    3016517, 3016837, 3017157, 3017477, 3017798, 3018183, 3018631, 3019078, 3019463, 3019911, 3020358, 3020742,
+    3021127, 3021575, 3022023, 3022471, 3022919, 3023367, 3023815, 3024262, 3024646, 3025031, 3025478, 3025863,
+    3026311, 3026758, 3027142, 3027526, 3027910, 3028294, 3028678, 3029060, 3029317, 3029638, 3030022, 3030405,
+    3030726, 3031110, 3031493, 3031813, 3032134, 3032518, 3032902, 3033286, 3033670, 3034054, 3034438, 3034821,
+    3035141, 3035462, 3035845, 3036166, 3036550, 3036933, 3037253, 3037573, 3037893, 3038213, 3038533, 3038852,
+    3039109, 3039430, 3039814, 3040197, 3040518, 3040902, 3041285, 3041605, 3041926, 3042310, 3042694, 3043078,
+    3043462, 3043846, 3044230, 3044613, 3044933, 3045254, 3045637, 3045958, 3046342, 3046725, 3047045, 3047365,
+    3047685, 3048005, 3048325, 1074627, 3048644, 3048901, 3049221, 3049540, 3049797, 3050117, 3050436, 3050692,
+    3050949, 3051269, 3051589, 3051909, 3052229, 3052549, 3052869, 3053188, 3053444, 3053701, 3054020, 3054277,
+    3054597, 3054916, 3055172, 3055428, 1074116, 1074820, 3055684, 3055941, 3056262, 3056647, 3057095, 3057542,
+    3057927, 3058375, 3058822, 3059206, 3059591, 3060039, 3060487, 3060935, 3061383, 3061831, 3062279, 3062726,
+    3063110, 3063495, 3063942, 3064327, 3064775, 3065222, 3065606, 3065990, 3066374, 3066758, 3067142, 3067524,
+    3067781, 3068102, 3068486, 3068869, 3069190, 3069574, 3069957, 3070277, 3070598, 3070982, 3071366, 3071750,
+    3072134, 3072518, 3072902, 3073285, 3073605, 3073926, 3074309, 3074630, 3075014, 3075397, 3075717, 3076037,
+    3076357, 3076677, 3076997, 3077316, 3077573, 3077894, 3078278, 3078661, 3078982, 3079366, 3079749, 3080069,
+    3080390, 3080774, 3081158, 3081542, 3081926, 3082310, 3082694, 3083077, 3083397, 3083718, 3084101, 3084422,
+    3084806, 3085189, 3085509, 3085829, 3086149, 3086469, 3086789, 3087108, 3087365, 3087686, 3088070, 3088453,
+    3088774, 3089158, 3089541, 3089861, 3090182, 3090566, 3090950, 3091334, 3091718, 3092102, 3092486, 3092869,
+    3093189, 3093510, 3093893, 3094214, 3094598, 3094981, 3095301, 3095621, 3095941, 3096261, 3096581, 3096900,
+    3097157, 3097478, 3097862, 3098245, 3098566, 3098950, 3099333, 3099653, 3099974, 3100358, 3100742, 3101126,
+    3101510, 3101894, 3102278, 3102661, 3102981, 3103302, 3103685, 3104006, 3104390, 3104773, 3105093, 3105413,
+    3105733, 3106053, 3106373, 3106692, 3106949, 3107270, 3107654, 3108037, 3108358, 3108742, 3109125, 3109445,
+    3109766, 3110150, 3110534, 3110918, 3111302, 3111686, 3112070, 3112453, 3112773, 3113094, 3113477, 3113798,
+    3114182, 3114565, 3114885, 3115205, 3115525, 3115845, 3116165, 1070147, 3116484, 3116741, 3117061, 3117380,
+    3117637, 3117957, 3118276, 3118532, 3118789, 3119109, 3119429, 3119749, 3120069, 3120389, 3120709, 3121028,
+    3121284, 3121541, 3121860, 3122117, 3122437, 3122756, 3123012, 3123268, 1069636, 1070340, 3123524, 1089,
+    3123778, 3123907, 3124099, 34562, 3124291, 3124483, 3124674, 3124802, 3124931, 3125123, 3125315, 3125507,
+    3125699, 3125891, 3126083, 51266, 3126274, 3126403, 3126594, 3126723, 3126915, 3127106, 199170, 3127234,
+    907522, 907778, 3127362, 4226, 3127491, 3127684, 3127940, 3128195, 3128388, 3128644, 3128899, 3129091,
+    3129284, 3129540, 3129796, 3130052, 3130308, 3130564, 3130820, 3131075, 3131267, 3131460, 3131715, 3131908,
+    3132164, 3132419, 3132611, 3132803, 3132995, 3133187, 3133379, 46594, 3133571, 3133764, 3134020, 3134275,
+    3134468, 3134724, 3134979, 3135171, 3135364, 3135620, 3135876, 3136132, 3136388, 3136644, 3136900, 3137155,
+    3137347, 3137540, 3137795, 3137988, 3138244, 3138499, 3138691, 3138883, 3139075, 3139267, 3139459, 66435,
+    3139652, 3139909, 3140229, 3140548, 3140805, 3141125, 3141444, 3141700, 3141957, 3142277, 3142597, 3142917,
+    3143237, 3143557, 3143877, 3144196, 3144452, 3144709, 3145028, 3145285, 3145605, 3145924, 3146180, 3146436,
+    3146692, 3146948, 3147204, 66626, 3147459, 3147652, 3147908, 3148163, 3148356, 3148612, 3148867, 3149059,
+    3149252, 3149508, 3149764, 3150020, 3150276, 3150532, 3150788, 3151043, 3151235, 3151428, 3151683, 3151876,
+    3152132, 3152387, 3152579, 3152771, 3152963, 3153155, 3153347, 4417, 3153538, 3153667, 3153859, 41602,
+    3154051, 3154243, 3154434, 3154562, 3154691, 3154883, 3155075, 3155267, 3155459, 3155651, 3155843, 3156034,
+    3156162, 3156291, 3156482, 3156611, 3156803, 3156994, 3157122, 3157250, 3157378, 3157506, 3157634, 66755,
+    3157764, 3158021, 3158341, 3158660, 3158917, 3159237, 3159556, 3159812, 3160069, 3160389, 3160709, 3161029,
+    3161349, 3161669, 3161989, 3162308, 3162564, 3162821, 3163140, 3163397, 3163717, 3164036, 3164292, 3164548,
+    3164804, 3165060, 3165316, 66946, 3165571, 3165764, 3166020, 3166275, 3166468, 3166724, 3166979, 3167171,
+    3167364, 3167620, 3167876, 3168132, 3168388, 3168644, 3168900, 3169155, 3169347, 3169540, 3169795, 3169988,
+    3170244, 3170499, 3170691, 3170883, 3171075, 3171267, 3171459, 2369, 3171650, 3171779, 3171971, 41282,
+    3172163, 3172355, 3172546, 3172674, 3172803, 3172995, 3173187, 3173379, 3173571, 3173763, 3173955, 3174146,
+    3174274, 3174403, 3174594, 3174723, 3174915, 3175106, 3175234, 3175362, 20738, 908418, 3175490, 67074,
+    3175619, 3175812, 3176068, 3176323, 3176516, 3176772, 3177027, 3177219, 3177412, 3177668, 3177924, 3178180,
+    3178436, 3178692, 3178948, 3179203, 3179395, 3179588, 3179843, 3180036, 3180292, 3180547, 3180739, 3180931,
+    1035971, 1036355, 3181123, 67203, 3181316, 3181573, 3181893, 3182212, 3182469, 3182789, 3183108, 3183364,
+    3183621, 3183941, 3184261, 3184581, 3184901, 3185221, 3185541, 3185860, 3186116, 3186373, 3186692, 3186949,
+    3187269, 3187588, 3187844, 3188100, 3188356, 3188612, 3188868, 6338, 3189123, 3189316, 3189572, 3189827,
+    3190020, 3190276, 3190531, 3190723, 3190916, 3191172, 3191428, 3191684, 3191940, 3192196, 3192452, 3192707,
+    3192899, 3193092, 3193347, 3193540, 3193796, 3194051, 3194243, 3194435, 3194627, 3194819, 3195011, 67394,
+    3195203, 3195396, 3195652, 3195907, 3196100, 3196356, 3196611, 3196803, 3196996, 3197252, 3197508, 3197764,
+    3198020, 3198276, 3198532, 3198787, 3198979, 3199172, 3199427, 3199620, 3199876, 3200131, 3200323, 3200515,
+    1144195, 1144579, 3200707, 2049, 3200898, 3201027, 3201219, 34690, 3201411, 3201603, 3201794, 3201922,
+    3202051, 3202243, 3202435, 3202627, 3202819, 3203011, 3203203, 569282, 3203394, 3203523, 3203714, 3203843,
+    3204035, 3204226, 3204354, 200066, 3204482, 3204610, 3204738, 67523, 3204868, 3205125, 3205445, 3205764,
+    3206021, 3206341, 3206660, 3206916, 3207173, 3207493, 3207813, 3208133, 3208453, 3208773, 3209093, 3209412,
+    3209668, 3209925, 3210244, 3210501, 3210821, 3211140, 3211396, 3211652, 3211908, 3212164, 3212420, 67714,
+    3212675, 3212868, 3213124, 3213379, 3213572, 3213828, 3214083, 3214275, 3214468, 3214724, 3214980, 3215236,
+    3215492, 3215748, 3216004, 3216259, 3216451, 3216644, 3216899, 3217092, 3217348, 3217603, 3217795, 3217987,
+    3218179, 1037827, 3218371, 67842, 3218563, 3218756, 3219012, 3219267, 3219460, 3219716, 3219971, 3220163,
+    3220356, 3220612, 3220868, 3221124, 3221380, 3221636, 3221892, 3222147, 3222339, 3222532, 3222787, 3222980,
+    3223236, 3223491, 3223683, 3223875, 3224067, 3224259, 3224451, 37378, 3224643, 3224836, 3225092, 3225347,
+    3225540, 3225796, 3226051, 3226243, 3226436, 3226692, 3226948, 3227204, 3227460, 3227716, 3227972, 3228227,
+    3228419, 3228612, 3228867, 3229060, 3229316, 3229571, 3229763, 3229955, 1144771, 1145155, 3230147, 67970,
+    3230339, 3230532, 3230788, 3231043, 3231236, 3231492, 3231747, 3231939, 3232132, 3232388, 3232644, 3232900,
+    3233156, 3233412, 3233668, 3233923, 3234115, 3234308, 3234563, 3234756, 3235012, 3235267, 3235459, 3235651,
+    3235843, 3236035, 3236227, 19842, 3236419, 3236612, 3236868, 3237123, 3237316, 3237572, 3237827, 3238019,
+    3238212, 3238468, 3238724, 3238980, 3239236, 3239492, 3239748, 3240003, 3240195, 3240388, 3240643, 3240836,
+    3241092, 3241347, 3241539, 3241731, 1141699, 1142083, 3241923, 4481, 3242114, 3242243, 3242435, 34818,
+    3242627, 3242819, 3243010, 3243138, 3243267, 3243459, 3243651, 3243843, 3244035, 3244227, 3244419, 3244610,
+    3244738, 3244867, 3245058, 3245187, 3245379, 5314, 3245570, 3245698, 906690, 906818, 3245826, 126978,
+    3245955, 3246148, 3246404, 3246659, 3246852, 3247108, 3247363, 3247555, 3247748, 3248004, 3248260, 3248516,
+    3248772, 3249028, 3249284, 3249539, 3249731, 3249924, 3250179, 3250372, 3250628, 3250883, 3251075, 3251267,
+    3251459, 3251651, 3251843, 3252035, 3252228, 3252485, 3252805, 3253124, 3253381, 3253701, 3254020, 3254276,
+    3254533, 3254853, 3255173, 3255493, 3255813, 3256133, 3256453, 3256772, 3257028, 3257285, 3257604, 3257861,
+    3258181, 3258500, 3258756, 3259012, 3259268, 3259524, 3259780, 3260035, 3260228, 3260485, 3260805, 3261124,
+    3261381, 3261701, 3262020, 3262276, 3262533, 3262853, 3263173, 3263493, 3263813, 3264133, 3264453, 3264772,
+    3265028, 3265285, 3265604, 3265861, 3266181, 3266500, 3266756, 3267012, 3267268, 3267524, 3267780, 3268036,
+    3268293, 3268614, 3268998, 3269381, 3269702, 3270086, 3270469, 3270789, 3271110, 3271494, 3271878, 3272262,
+    3272646, 3273030, 3273414, 3273797, 3274117, 3274438, 3274821, 3275142, 3275526, 3275909, 3276229, 3276549,
+    3276869, 3277189, 3277509, 3277827, 3278020, 3278277, 3278597, 3278916, 3279173, 3279493, 3279812, 3280068,
+    3280325, 3280645, 3280965, 3281285, 3281605, 3281925, 3282245, 3282564, 3282820, 3283077, 3283396, 3283653,
+    3283973, 3284292, 3284548, 3284804, 3285060, 3285316, 3285572, 127746, 3285827, 3286020, 3286276, 3286531,
+    3286724, 3286980, 3287235, 3287427, 3287620, 3287876, 3288132, 3288388, 3288644, 3288900, 3289156, 3289411,
+    3289603, 3289796, 3290051, 3290244, 3290500, 3290755, 3290947, 3291139, 3291331, 3291523, 3291715, 3291908,
+    3292165, 3292486, 3292870, 3293253, 3293574, 3293958, 3294341, 3294661, 3294982, 3295366, 3295750, 3296134,
+    3296518, 3296902, 3297286, 3297669, 3297989, 3298310, 3298693, 3299014, 3299398, 3299781, 3300101, 3300421,
+    3300741, 3301061, 3301381, 3301699, 3301892, 3302149, 3302469, 3302788, 3303045, 3303365, 3303684, 3303940,
+    3304197, 3304517, 3304837, 3305157, 3305477, 3305797, 3306117, 3306436, 3306692, 3306949, 3307268, 3307525,
+    3307845, 3308164, 3308420, 3308676, 3308932, 3309188, 3309444, 127874, 3309699, 3309892, 3310148, 3310403,
+    3310596, 3310852, 3311107, 3311299, 3311492, 3311748, 3312004, 3312260, 3312516, 3312772, 3313028, 3313283,
+    3313475, 3313668, 3313923, 3314116, 3314372, 3314627, 3314819, 3315011, 1113411, 1113795, 3315203, 128003,
+    3315396, 3315653, 3315973, 3316292, 3316549, 3316869, 3317188, 3317444, 3317701, 3318021, 3318341, 3318661,
+    3318981, 3319301, 3319621, 3319940, 3320196, 3320453, 3320772, 3321029, 3321349, 3321668, 3321924, 3322180,
+    3322436, 3322692, 3322948, 3323204, 3323461, 3323782, 3324166, 3324549, 3324870, 3325254, 3325637, 3325957,
+    3326278, 3326662, 3327046, 3327430, 3327814, 3328198, 3328582, 3328965, 3329285, 3329606, 3329989, 3330310,
+    3330694, 3331077, 3331397, 3331717, 3332037, 3332357, 3332677, 3332995, 3333188, 3333445, 3333765, 3334084,
+    3334341, 3334661, 3334980, 3335236, 3335493, 3335813, 3336133, 3336453, 3336773, 3337093, 3337413, 3337732,
+    3337988, 3338245, 3338564, 3338821, 3339141, 3339460, 3339716, 3339972, 3340228, 3340484, 3340740, 3340995,
+    3341188, 3341445, 3341765, 3342084, 3342341, 3342661, 3342980, 3343236, 3343493, 3343813, 3344133, 3344453,
+    3344773, 3345093, 3345413, 3345732, 3345988, 3346245, 3346564, 3346821, 3347141, 3347460, 3347716, 3347972,
+    3348228, 3348484, 3348740, 127106, 3348995, 3349188, 3349444, 3349699, 3349892, 3350148, 3350403, 3350595,
+    3350788, 3351044, 3351300, 3351556, 3351812, 3352068, 3352324, 3352579, 3352771, 3352964, 3353219, 3353412,
+    3353668, 3353923, 3354115, 3354307, 1113987, 1114371, 3354499, 3354692, 3354949, 3355270, 3355654, 3356037,
+    3356358, 3356742, 3357125, 3357445, 3357766, 3358150, 3358534, 3358918, 3359302, 3359686, 3360070, 3360453,
+    3360773, 3361094, 3361477, 3361798, 3362182, 3362565, 3362885, 3363205, 3363525, 3363845, 3364165, 211587,
+    3364484, 3364741, 3365061, 3365380, 3365637, 3365957, 3366276, 3366532, 3366789, 3367109, 3367429, 3367749,
+    3368069, 3368389, 3368709, 3369028, 3369284, 3369541, 3369860, 3370117, 3370437, 3370756, 3371012, 3371268,
+    3371524, 3371780, 3372036, 3372291, 3372484, 3372741, 3373061, 3373380, 3373637, 3373957, 3374276, 3374532,
+    3374789, 3375109, 3375429, 3375749, 3376069, 3376389, 3376709, 3377028, 3377284, 3377541, 3377860, 3378117,
+    3378437, 3378756, 3379012, 3379268, 3379524, 3379780, 3380036, 3380291, 3380484, 3380741, 3381061, 3381380,
+    3381637, 3381957, 3382276, 3382532, 3382789, 3383109, 3383429, 3383749, 3384069, 3384389, 3384709, 3385028,
+    3385284, 3385541, 3385860, 3386117, 3386437, 3386756, 3387012, 3387268, 3387524, 3387780, 3388036, 3388291,
+    3388484, 3388741, 3389061, 3389380, 3389637, 3389957, 3390276, 3390532, 3390789, 3391109, 3391429, 3391749,
+    3392069, 3392389, 3392709, 3393028, 3393284, 3393541, 3393860, 3394117, 3394437, 3394756, 3395012, 3395268,
+    3395524, 3395780, 3396036, 3396291, 3396484, 3396741, 3397061, 3397380, 3397637, 3397957, 3398276, 3398532,
+    3398789, 3399109, 3399429, 3399749, 3400069, 3400389, 3400709, 3401028, 3401284, 3401541, 3401860, 3402117,
+    3402437, 3402756, 3403012, 3403268, 3403524, 3403780, 3404036, 127234, 3404291, 3404484, 3404740, 3404995,
+    3405188, 3405444, 3405699, 3405891, 3406084, 3406340, 3406596, 3406852, 3407108, 3407364, 3407620, 3407875,
+    3408067, 3408260, 3408515, 3408708, 3408964, 3409219, 3409411, 3409603, 1110915, 1111299, 3409795, 189955,
+    3409988, 3410245, 3410565, 3410884, 3411141, 3411461, 3411780, 3412036, 3412293, 3412613, 3412933, 3413253,
+    3413573, 3413893, 3414213, 3414532, 3414788, 3415045, 3415364, 3415621, 3415941, 3416260, 3416516, 3416772,
+    3417028, 3417284, 3417540, 3417796, 3418053, 3418374, 3418758, 3419141, 3419462, 3419846, 3420229, 3420549,
+    3420870, 3421254, 3421638, 3422022, 3422406, 3422790, 3423174, 3423557, 3423877, 3424198, 3424581, 3424902,
+    3425286, 3425669, 3425989, 3426309, 3426629, 3426949, 3427269, 3427588, 3427845, 3428166, 3428550, 3428933,
+    3429254, 3429638, 3430021, 3430341, 3430662, 3431046, 3431430, 3431814, 3432198, 3432582, 3432966, 3433349,
+    3433669, 3433990, 3434373, 3434694, 3435078, 3435461, 3435781, 3436101, 3436421, 3436741, 3437061, 3437381,
+    3437702, 3438087, 3438535, 3438982, 3439367, 3439815, 3440262, 3440646, 3441031, 3441479, 3441927, 3442375,
+    3442823, 3443271, 3443719, 3444166, 3444550, 3444935, 3445382, 3445767, 3446215, 3446662, 3447046, 3447430,
+    3447814, 3448198, 3448582, 3448964, 3449221, 3449542, 3449926, 3450309, 3450630, 3451014, 3451397, 3451717,
+    3452038, 3452422, 3452806, 3453190, 3453574, 3453958, 3454342, 3454725, 3455045, 3455366, 3455749, 3456070,
+    3456454, 3456837, 3457157, 3457477, 3457797, 3458117, 3458437, 189315, 3458756, 3459013, 3459333, 3459652,
+    3459909, 3460229, 3460548, 3460804, 3461061, 3461381, 3461701, 3462021, 3462341, 3462661, 3462981, 3463300,
+    3463556, 3463813, 3464132, 3464389, 3464709, 3465028, 3465284, 3465540, 3465796, 3466052, 3466308, 3466565,
+    3466886, 3467271, 3467719, 3468166, 3468551, 3468999, 3469446, 3469830, 3470215, 3470663, 3471111, 3471559,
+    3472007, 3472455, 3472903, 3473350, 3473734, 3474119, 3474566, 3474951, 3475399, 3475846, 3476230, 3476614,
+    3476998, 3477382, 3477766, 3478148, 3478405, 3478726, 3479110, 3479493, 3479814, 3480198, 3480581, 3480901,
+    3481222, 3481606, 3481990, 3482374, 3482758, 3483142, 3483526, 3483909, 3484229, 3484550, 3484933, 3485254,
+    3485638, 3486021, 3486341, 3486661, 3486981, 3487301, 3487621, 189123, 3487940, 3488197, 3488517, 3488836,
+    3489093, 3489413, 3489732, 3489988, 3490245, 3490565, 3490885, 3491205, 3491525, 3491845, 3492165, 3492484,
+    3492740, 3492997, 3493316, 3493573, 3493893, 3494212, 3494468, 3494724, 1123972, 1124484, 3494980, 3495236,
+    3495493, 3495814, 3496198, 3496581, 3496902, 3497286, 3497669, 3497989, 3498310, 3498694, 3499078, 3499462,
+    3499846, 3500230, 3500614, 3500997, 3501317, 3501638, 3502021, 3502342, 3502726, 3503109, 3503429, 3503749,
+    3504069, 3504389, 3504709, 3505029, 3505350, 3505735, 3506183, 3506630, 3507015, 3507463, 3507910, 3508294,
+    3508679, 3509127, 3509575, 3510023, 3510471, 3510919, 3511367, 3511814, 3512198, 3512583, 3513030, 3513415,
+    3513863, 3514310, 3514694, 3515078, 3515462, 3515846, 3516230, 3516612, 3516869, 3517190, 3517574, 3517957,
+    3518278, 3518662, 3519045, 3519365, 3519686, 3520070, 3520454, 3520838, 3521222, 3521606, 3521990, 3522373,
+    3522693, 3523014, 3523397, 3523718, 3524102, 3524485, 3524805, 3525125, 3525445, 3525765, 3526085, 3526404,
+    3526661, 3526982, 3527366, 3527749, 3528070, 3528454, 3528837, 3529157, 3529478, 3529862, 3530246, 3530630,
+    3531014, 3531398, 3531782, 3532165, 3532485, 3532806, 3533189, 3533510, 3533894, 3534277, 3534597, 3534917,
+    3535237, 3535557, 3535877, 188931, 3536196, 3536453, 3536773, 3537092, 3537349, 3537669, 3537988, 3538244,
+    3538501, 3538821, 3539141, 3539461, 3539781, 3540101, 3540421, 3540740, 3540996, 3541253, 3541572, 3541829,
+    3542149, 3542468, 3542724, 3542980, 1124740, 1125252, 3543236, 3543493, 3543814, 3544199, 3544647, 3545094,
+    3545479, 3545927, 3546374, 3546758, 3547143, 3547591, 3548039, 3548487, 3548935, 3549383, 3549831, 3550278,
+    3550662, 3551047, 3551494, 3551879, 3552327, 3552774, 3553158, 3553542, 3553926, 3554310, 3554694, 3555076,
+    3555333, 3555654, 3556038, 3556421, 3556742, 3557126, 3557509, 3557829, 3558150, 3558534, 3558918, 3559302,
+    3559686, 3560070, 3560454, 3560837, 3561157, 3561478, 3561861, 3562182, 3562566, 3562949, 3563269, 3563589,
+    3563909, 3564229, 3564549, 3564868, 3565125, 3565446, 3565830, 3566213, 3566534, 3566918, 3567301, 3567621,
+    3567942, 3568326, 3568710, 3569094, 3569478, 3569862, 3570246, 3570629, 3570949, 3571270, 3571653, 3571974,
+    3572358, 3572741, 3573061, 3573381, 3573701, 3574021, 3574341, 3574660, 3574917, 3575238, 3575622, 3576005,
+    3576326, 3576710, 3577093, 3577413, 3577734, 3578118, 3578502, 3578886, 3579270, 3579654, 3580038, 3580421,
+    3580741, 3581062, 3581445, 3581766, 3582150, 3582533, 3582853, 3583173, 3583493, 3583813, 3584133, 3584452,
+    3584709, 3585030, 3585414, 3585797, 3586118, 3586502, 3586885, 3587205, 3587526, 3587910, 3588294, 3588678,
+    3589062, 3589446, 3589830, 3590213, 3590533, 3590854, 3591237, 3591558, 3591942, 3592325, 3592645, 3592965,
+    3593285, 3593605, 3593925, 3594244, 3594501, 3594822, 3595206, 3595589, 3595910, 3596294, 3596677, 3596997,
+    3597318, 3597702, 3598086, 3598470, 3598854, 3599238, 3599622, 3600005, 3600325, 3600646, 3601029, 3601350,
+    3601734, 3602117, 3602437, 3602757, 3603077, 3603397, 3603717, 189763, 3604036, 3604293, 3604613, 3604932,
+    3605189, 3605509, 3605828, 3606084, 3606341, 3606661, 3606981, 3607301, 3607621, 3607941, 3608261, 3608580,
+    3608836, 3609093, 3609412, 3609669, 3609989, 3610308, 3610564, 3610820, 1121092, 1121604, 3611076, 106242,
+    3611331, 3611524, 3611780, 3612035, 3612228, 3612484, 3612739, 565443, 3612932, 3613188, 3613444, 3613700,
+    3613956, 3614212, 3614468, 3614723, 3614915, 3615108, 3615363, 3615556, 3615812, 3616067, 3616259, 3616451,
+    1045251, 1045635, 3616643, 3616835, 3617028, 3617285, 3617605, 3617924, 3618181, 3618501, 3618820, 3619076,
+    3619333, 3619653, 3619973, 3620293, 3620613, 3620933, 3621253, 3621572, 3621828, 3622085, 3622404, 3622661,
+    3622981, 3623300, 3623556, 3623812, 3624068, 3624324, 3624580, 3624835, 3625028, 3625285, 3625605, 3625924,
+    3626181, 3626501, 3626820, 3627076, 3627333, 3627653, 3627973, 3628293, 3628613, 3628933, 3629253, 3629572,
+    3629828, 3630085, 3630404, 3630661, 3630981, 3631300, 3631556, 3631812, 3632068, 3632324, 3632580, 3632836,
+    3633093, 3633414, 3633798, 3634181, 3634502, 3634886, 3635269, 3635589, 3635910, 3636294, 3636678, 3637062,
+    3637446, 3637830, 3638214, 3638597, 3638917, 3639238, 3639621, 3639942, 3640326, 3640709, 3641029, 3641349,
+    3641669, 3641989, 3642309, 3642627, 3642820, 3643077, 3643397, 3643716, 3643973, 3644293, 3644612, 3644868,
+    3645125, 3645445, 3645765, 3646085, 3646405, 3646725, 3647045, 3647364, 3647620, 3647877, 3648196, 3648453,
+    3648773, 3649092, 3649348, 3649604, 3649860, 3650116, 3650372, 107010, 3650627, 3650820, 3651076, 3651331,
+    3651524, 3651780, 3652035, 3652227, 3652420, 3652676, 3652932, 3653188, 3653444, 3653700, 3653956, 3654211,
+    3654403, 3654596, 3654851, 3655044, 3655300, 3655555, 3655747, 3655939, 3656131, 1047299, 3656323, 3656516,
+    3656773, 3657094, 3657478, 3657861, 3658182, 3658566, 3658949, 3659269, 3659590, 3659974, 3660358, 3660742,
+    3661126, 3661510, 3661894, 3662277, 3662597, 3662918, 3663301, 3663622, 3664006, 3664389, 3664709, 3665029,
+    3665349, 3665669, 3665989, 3666307, 3666500, 3666757, 3667077, 3667396, 3667653, 3667973, 3668292, 3668548,
+    3668805, 3669125, 3669445, 3669765, 3670085, 3670405, 3670725, 3671044, 3671300, 3671557, 3671876, 3672133,
+    3672453, 3672772, 3673028, 3673284, 3673540, 3673796, 3674052, 107138, 3674307, 3674500, 3674756, 3675011,
+    3675204, 3675460, 3675715, 3675907, 3676100, 3676356, 3676612, 3676868, 3677124, 3677380, 3677636, 3677891,
+    3678083, 3678276, 3678531, 3678724, 3678980, 3679235, 3679427, 3679619, 1046531, 1046915, 3679811, 107267,
+    3680004, 3680261, 3680581, 3680900, 3681157, 3681477, 3681796, 3682052, 3682309, 3682629, 3682949, 3683269,
+    3683589, 3683909, 3684229, 3684548, 3684804, 3685061, 3685380, 3685637, 3685957, 3686276, 3686532, 3686788,
+    3687044, 3687300, 3687556, 3687812, 3688069, 3688390, 3688774, 3689157, 3689478, 3689862, 3690245, 3690565,
+    3690886, 3691270, 3691654, 3692038, 3692422, 3692806, 3693190, 3693573, 3693893, 3694214, 3694597, 3694918,
+    3695302, 3695685, 3696005, 3696325, 3696645, 3696965, 3697285, 3697603, 3697796, 3698053, 3698373, 3698692,
+    3698949, 3699269, 3699588, 3699844, 3700101, 3700421, 3700741, 3701061, 3701381, 3701701, 3702021, 3702340,
+    3702596, 3702853, 3703172, 3703429, 3703749, 3704068, 3704324, 3704580, 3704836, 3705092, 3705348, 3705603,
+    3705796, 3706053, 3706373, 3706692, 3706949, 3707269, 3707588, 3707844, 3708101, 3708421, 3708741, 3709061,
+    3709381, 3709701, 3710021, 3710340, 3710596, 3710853, 3711172, 3711429, 3711749, 3712068, 3712324, 3712580,
+    3712836, 3713092, 3713348, 106370, 3713603, 3713796, 3714052, 3714307, 3714500, 3714756, 3715011, 3715203,
+    3715396, 3715652, 3715908, 3716164, 3716420, 3716676, 3716932, 3717187, 3717379, 3717572, 3717827, 3718020,
+    3718276, 3718531, 3718723, 3718915, 1047491, 1047875, 3719107, 3719300, 3719557, 3719878, 3720262, 3720645,
+    3720966, 3721350, 3721733, 3722053, 3722374, 3722758, 3723142, 3723526, 3723910, 3724294, 3724678, 3725061,
+    3725381, 3725702, 3726085, 3726406, 3726790, 3727173, 3727493, 3727813, 3728133, 3728453, 3728773, 160003,
+    3729092, 3729349, 3729669, 3729988, 3730245, 3730565, 3730884, 3731140, 3731397, 3731717, 3732037, 3732357,
+    3732677, 3732997, 3733317, 3733636, 3733892, 3734149, 3734468, 3734725, 3735045, 3735364, 3735620, 3735876,
+    3736132, 3736388, 3736644, 160195, 3736900, 3737157, 3737477, 3737796, 3738053, 3738373, 3738692, 3738948,
+    3739205, 3739525, 3739845, 3740165, 3740485, 3740805, 3741125, 3741444, 3741700, 3741957, 3742276, 3742533,
+    3742853, 3743172, 3743428, 3743684, 3743940, 3744196, 3744452, 3744707, 3744900, 3745157, 3745477, 3745796,
+    3746053, 3746373, 3746692, 3746948, 3747205, 3747525, 3747845, 3748165, 3748485, 3748805, 3749125, 3749444,
+    3749700, 3749957, 3750276, 3750533, 3750853, 3751172, 3751428, 3751684, 3751940, 3752196, 3752452, 3752707,
+    3752900, 3753157, 3753477, 3753796, 3754053, 3754373, 3754692, 3754948, 3755205, 3755525, 3755845, 3756165,
+    3756485, 3756805, 3757125, 3757444, 3757700, 3757957, 3758276, 3758533, 3758853, 3759172, 3759428, 3759684,
+    3759940, 3760196, 3760452, 3760707, 3760900, 3761157, 3761477, 3761796, 3762053, 3762373, 3762692, 3762948,
+    3763205, 3763525, 3763845, 3764165, 3764485, 3764805, 3765125, 3765444, 3765700, 3765957, 3766276, 3766533,
+    3766853, 3767172, 3767428, 3767684, 3767940, 3768196, 3768452, 106498, 3768707, 3768900, 3769156, 3769411,
+    3769604, 3769860, 3770115, 3770307, 3770500, 3770756, 3771012, 3771268, 3771524, 3771780, 3772036, 3772291,
+    3772483, 3772676, 3772931, 3773124, 3773380, 3773635, 3773827, 3774019, 1043715, 1044099, 3774211, 113474,
+    3774403, 3774596, 3774852, 3775107, 3775300, 3775556, 3775811, 3776003, 3776196, 3776452, 3776708, 3776964,
+    3777220, 3777476, 3777732, 3777987, 3778179, 3778372, 3778627, 3778820, 3779076, 3779331, 3779523, 3779715,
+    1007427, 1007811, 159171, 3779907, 3780100, 3780357, 3780677, 3780996, 3781253, 3781573, 3781892, 3782148,
+    3782405, 3782725, 3783045, 3783365, 3783685, 3784005, 3784325, 3784644, 3784900, 3785157, 3785476, 3785733,
+    3786053, 3786372, 3786628, 3786884, 3787140, 3787396, 3787652, 456707, 3787908, 3788165, 3788485, 3788804,
+    3789061, 3789381, 3789700, 3789956, 3790213, 3790533, 3790853, 3791173, 3791493, 3791813, 3792133, 3792452,
+    3792708, 3792965, 3793284, 3793541, 3793861, 3794180, 3794436, 3794692, 3794948, 3795204, 3795460, 3795716,
+    3795973, 3796294, 3796678, 3797061, 3797382, 3797766, 3798149, 3798469, 3798790, 3799174, 3799558, 3799942,
+    3800326, 3800710, 3801094, 3801477, 3801797, 3802118, 3802501, 3802822, 3803206, 3803589, 3803909, 3804229,
+    3804549, 3804869, 3805189, 3805507, 3805700, 3805957, 3806277, 3806596, 3806853, 3807173, 3807492, 3807748,
+    3808005, 3808325, 3808645, 3808965, 3809285, 3809605, 3809925, 3810244, 3810500, 3810757, 3811076, 3811333,
+    3811653, 3811972, 3812228, 3812484, 3812740, 3812996, 3813252, 114242, 3813507, 3813700, 3813956, 1171779,
+    3814212, 3814468, 3814723, 3814915, 3815108, 3815364, 3815620, 3815876, 3816132, 3816388, 3816644, 3816899,
+    3817091, 3817284, 3817539, 3817732, 3817988, 3818243, 3818435, 3818627, 1009283, 1009667, 158595, 3818820,
+    3819077, 3819398, 3819782, 3820165, 3820486, 3820870, 3821253, 3821573, 3821894, 3822278, 3822662, 3823046,
+    3823430, 3823814, 3824198, 3824581, 3824901, 3825222, 3825605, 3825926, 3826310, 3826693, 3827013, 3827333,
+    3827653, 3827973, 3828293, 456131, 3828612, 3828869, 3829189, 3829508, 3829765, 3830085, 3830404, 3830660,
+    3830917, 3831237, 3831557, 3831877, 3832197, 3832517, 3832837, 3833156, 3833412, 3833669, 3833988, 3834245,
+    3834565, 3834884, 3835140, 3835396, 3835652, 3835908, 3836164, 114370, 3836419, 3836612, 3836868, 3837123,
+    3837316, 3837572, 3837827, 3838019, 3838212, 3838468, 3838724, 3838980, 3839236, 3839492, 3839748, 3840003,
+    3840195, 3840388, 3840643, 3840836, 3841092, 3841347, 3841539, 3841731, 1008707, 1009091, 158979, 114691,
+    3841924, 3842181, 3842501, 3842820, 3843077, 3843397, 3843716, 3843972, 3844229, 3844549, 3844869, 3845189,
+    3845509, 3845829, 3846149, 3846468, 3846724, 3846981, 3847300, 3847557, 3847877, 3848196, 3848452, 3848708,
+    3848964, 3849220, 3849476, 3849732, 3849989, 3850310, 3850694, 3851077, 3851398, 3851782, 3852165, 3852485,
+    3852806, 3853190, 3853574, 3853958, 3854342, 3854726, 3855110, 3855493, 3855813, 3856134, 3856517, 3856838,
+    3857222, 3857605, 3857925, 3858245, 3858565, 3858885, 3859205, 3859523, 3859716, 3859973, 3860293, 3860612,
+    3860869, 3861189, 3861508, 3861764, 3862021, 3862341, 3862661, 3862981, 3863301, 3863621, 3863941, 3864260,
+    3864516, 3864773, 3865092, 3865349, 3865669, 3865988, 3866244, 3866500, 3866756, 3867012, 3867268, 456899,
+    3867524, 3867781, 3868101, 3868420, 3868677, 3868997, 3869316, 3869572, 3869829, 3870149, 3870469, 3870789,
+    3871109, 3871429, 3871749, 3872068, 3872324, 3872581, 3872900, 3873157, 3873477, 3873796, 3874052, 3874308,
+    3874564, 3874820, 3875076, 113602, 3875331, 3875524, 3875780, 3876035, 3876228, 3876484, 3876739, 3876931,
+    3877124, 3877380, 3877636, 3877892, 3878148, 3878404, 3878660, 3878915, 3879107, 3879300, 3879555, 3879748,
+    3880004, 3880259, 3880451, 3880643, 1009859, 1010243, 3880835, 3881028, 3881285, 3881606, 3881990, 3882373,
+    3882694, 3883078, 3883461, 3883781, 3884102, 3884486, 3884870, 3885254, 3885638, 3886022, 3886406, 3886789,
+    3887109, 3887430, 3887813, 3888134, 3888518, 3888901, 3889221, 3889541, 3889861, 3890181, 3890501, 115587,
+    3890820, 3891077, 3891397, 3891716, 3891973, 3892293, 3892612, 3892868, 3893125, 3893445, 3893765, 3894085,
+    3894405, 3894725, 3895045, 3895364, 3895620, 3895877, 3896196, 3896453, 3896773, 3897092, 3897348, 3897604,
+    3897860, 3898116, 3898372, 114883, 3898628, 3898885, 3899205, 3899524, 3899781, 3900101, 3900420, 3900676,
+    3900933, 3901253, 3901573, 3901893, 3902213, 3902533, 3902853, 3903172, 3903428, 3903685, 3904004, 3904261,
+    3904581, 3904900, 3905156, 3905412, 3905668, 3905924, 3906180, 456323, 3906436, 3906693, 3907013, 3907332,
+    3907589, 3907909, 3908228, 3908484, 3908741, 3909061, 3909381, 3909701, 3910021, 3910341, 3910661, 3910980,
+    3911236, 3911493, 3911812, 3912069, 3912389, 3912708, 3912964, 3913220, 3913476, 3913732, 3913988, 3914243,
+    3914436, 3914693, 3915013, 3915332, 3915589, 3915909, 3916228, 3916484, 3916741, 3917061, 3917381, 3917701,
+    3918021, 3918341, 3918661, 3918980, 3919236, 3919493, 3919812, 3920069, 3920389, 3920708, 3920964, 3921220,
+    3921476, 3921732, 3921988, 456515, 3922244, 3922501, 3922821, 3923140, 3923397, 3923717, 3924036, 3924292,
+    3924549, 3924869, 3925189, 3925509, 3925829, 3926149, 3926469, 3926788, 3927044, 3927301, 3927620, 3927877,
+    3928197, 3928516, 3928772, 3929028, 3929284, 3929540, 3929796, 113730, 3930051, 3930244, 3930500, 3930755,
+    3930948, 3931204, 3931459, 3931651, 3931844, 3932100, 3932356, 3932612, 3932868, 3933124, 3933380, 3933635,
+    3933827, 3934020, 3934275, 3934468, 3934724, 3934979, 3935171, 3935363, 1006147, 1006531, 158787, 105154,
+    3935555, 3935748, 3936004, 3936259, 3936452, 3936708, 3936963, 3937155, 3937348, 3937604, 3937860, 3938116,
+    3938372, 3938628, 3938884, 3939139, 3939331, 3939524, 3939779, 3939972, 3940228, 3940483, 3940675, 3940867,
+    961859, 962243, 3941059, 3941251, 3941444, 3941701, 3942021, 3942340, 3942597, 3942917, 3943236, 3943492,
+    3943749, 3944069, 3944389, 3944709, 3945029, 3945349, 3945669, 3945988, 3946244, 3946501, 3946820, 3947077,
+    3947397, 3947716, 3947972, 3948228, 3948484, 3948740, 3948996, 178115, 3949252, 3949509, 3949829, 3950148,
+    3950405, 3950725, 3951044, 3951300, 3951557, 3951877, 3952197, 3952517, 3952837, 3953157, 3953477, 3953796,
+    3954052, 3954309, 3954628, 3954885, 3955205, 3955524, 3955780, 3956036, 3956292, 3956548, 3956804, 3957060,
+    3957317, 3957638, 3958022, 3958405, 3958726, 3959110, 3959493, 3959813, 3960134, 3960518, 3960902, 3961286,
+    3961670, 3962054, 3962438, 3962821, 3963141, 3963462, 3963845, 3964166, 3964550, 3964933, 3965253, 3965573,
+    3965893, 3966213, 3966533, 3966851, 3967044, 3967301, 3967621, 3967940, 3968197, 3968517, 3968836, 3969092,
+    3969349, 3969669, 3969989, 3970309, 3970629, 3970949, 3971269, 3971588, 3971844, 3972101, 3972420, 3972677,
+    3972997, 3973316, 3973572, 3973828, 3974084, 3974340, 3974596, 105794, 3974851, 3975044, 3975300, 3975555,
+    3975748, 3976004, 3976259, 3976451, 3976644, 3976900, 3977156, 3977412, 3977668, 3977924, 3978180, 3978435,
+    3978627, 3978820, 3979075, 3979268, 3979524, 3979779, 3979971, 3980163, 3980355, 964163, 3980547, 3980740,
+    3980997, 3981318, 3981702, 3982085, 3982406, 3982790, 3983173, 3983493, 3983814, 3984198, 3984582, 3984966,
+    3985350, 3985734, 3986118, 3986501, 3986821, 3987142, 3987525, 3987846, 3988230, 3988613, 3988933, 3989253,
+    3989573, 3989893, 3990213, 177539, 3990532, 3990789, 3991109, 3991428, 3991685, 3992005, 3992324, 3992580,
+    3992837, 3993157, 3993477, 3993797, 3994117, 3994437, 3994757, 3995076, 3995332, 3995589, 3995908, 3996165,
+    3996485, 3996804, 3997060, 3997316, 3997572, 3997828, 3998084, 105922, 3998339, 3998532, 3998788, 541827,
+    3999044, 3999300, 3999555, 3999747, 3999940, 4000196, 4000452, 4000708, 4000964, 4001220, 4001476, 4001731,
+    4001923, 4002116, 4002371, 4002564, 4002820, 4003075, 4003267, 4003459, 963395, 963779, 4003651, 106051,
+    4003844, 4004101, 4004421, 4004740, 4004997, 4005317, 4005636, 4005892, 4006149, 4006469, 4006789, 4007109,
+    4007429, 4007749, 4008069, 4008388, 4008644, 4008901, 4009220, 4009477, 4009797, 4010116, 4010372, 4010628,
+    4010884, 4011140, 4011396, 4011652, 4011909, 4012230, 4012614, 4012997, 4013318, 4013702, 4014085, 4014405,
+    4014726, 4015110, 4015494, 4015878, 4016262, 4016646, 4017030, 4017413, 4017733, 4018054, 4018437, 4018758,
+    4019142, 4019525, 4019845, 4020165, 4020485, 4020805, 4021125, 4021443, 4021636, 4021893, 4022213, 4022532,
+    4022789, 4023109, 4023428, 4023684, 4023941, 4024261, 4024581, 4024901, 4025221, 4025541, 4025861, 4026180,
+    4026436, 4026693, 4027012, 4027269, 4027589, 4027908, 4028164, 4028420, 4028676, 4028932, 4029188, 177923,
+    4029444, 4029701, 4030021, 4030340, 4030597, 4030917, 4031236, 4031492, 4031749, 4032069, 4032389, 4032709,
+    4033029, 4033349, 4033669, 4033988, 4034244, 4034501, 4034820, 4035077, 4035397, 4035716, 4035972, 4036228,
+    4036484, 4036740, 4036996, 105282, 4037251, 4037444, 4037700, 4037955, 4038148, 4038404, 4038659, 4038851,
+    4039044, 4039300, 4039556, 4039812, 4040068, 4040324, 4040580, 4040835, 4041027, 4041220, 4041475, 4041668,
+    4041924, 4042179, 4042371, 4042563, 964355, 964739, 4042755, 4042948, 4043205, 4043526, 4043910, 4044293,
+    4044614, 4044998, 4045381, 4045701, 4046022, 4046406, 4046790, 4047174, 4047558, 4047942, 4048326, 4048709,
+    4049029, 4049350, 4049733, 4050054, 4050438, 4050821, 4051141, 4051461, 4051781, 4052101, 4052421, 155011,
+    4052740, 4052997, 4053317, 4053636, 4053893, 4054213, 4054532, 4054788, 4055045, 4055365, 4055685, 4056005,
+    4056325, 4056645, 4056965, 4057284, 4057540, 4057797, 4058116, 4058373, 4058693, 4059012, 4059268, 4059524,
+    4059780, 4060036, 4060292, 155203, 4060548, 4060805, 4061125, 4061444, 4061701, 4062021, 4062340, 4062596,
+    4062853, 4063173, 4063493, 4063813, 4064133, 4064453, 4064773, 4065092, 4065348, 4065605, 4065924, 4066181,
+    4066501, 4066820, 4067076, 4067332, 4067588, 4067844, 4068100, 4068355, 4068548, 4068805, 4069125, 4069444,
+    4069701, 4070021, 4070340, 4070596, 4070853, 4071173, 4071493, 4071813, 4072133, 4072453, 4072773, 4073092,
+    4073348, 4073605, 4073924, 4074181, 4074501, 4074820, 4075076, 4075332, 4075588, 4075844, 4076100, 4076355,
+    4076548, 4076805, 4077125, 4077444, 4077701, 4078021, 4078340, 4078596, 4078853, 4079173, 4079493, 4079813,
+    4080133, 4080453, 4080773, 4081092, 4081348, 4081605, 4081924, 4082181, 4082501, 4082820, 4083076, 4083332,
+    4083588, 4083844, 4084100, 177731, 4084356, 4084613, 4084933, 4085252, 4085509, 4085829, 4086148, 4086404,
+    4086661, 4086981, 4087301, 4087621, 4087941, 4088261, 4088581, 4088900, 4089156, 4089413, 4089732, 4089989,
+    4090309, 4090628, 4090884, 4091140, 4091396, 4091652, 4091908, 16258, 4092163, 4092356, 4092612, 4092867,
+    4093060, 4093316, 4093571, 4093763, 4093956, 4094212, 4094468, 4094724, 4094980, 4095236, 4095492, 4095747,
+    4095939, 4096132, 4096387, 4096580, 4096836, 4097091, 4097283, 4097475, 960579, 960963, 4097667, 142146,
+    4097859, 4098052, 4098308, 4098563, 4098756, 4099012, 4099267, 4099459, 4099652, 4099908, 4100164, 4100420,
+    4100676, 4100932, 4101188, 4101443, 4101635, 4101828, 4102083, 4102276, 4102532, 4102787, 4102979, 4103171,
+    915907, 916291, 4103363, 4103555, 4103748, 4104005, 4104325, 4104644, 4104901, 4105221, 4105540, 4105796,
+    4106053, 4106373, 4106693, 4107013, 4107333, 4107653, 4107973, 4108292, 4108548, 4108805, 4109124, 4109381,
+    4109701, 4110020, 4110276, 4110532, 4110788, 4111044, 4111300, 4111555, 4111748, 4112005, 4112325, 4112644,
+    4112901, 4113221, 4113540, 4113796, 4114053, 4114373, 4114693, 4115013, 4115333, 4115653, 4115973, 4116292,
+    4116548, 4116805, 4117124, 4117381, 4117701, 4118020, 4118276, 4118532, 4118788, 4119044, 4119300, 4119556,
+    4119813, 4120134, 4120518, 4120901, 4121222, 4121606, 4121989, 4122309, 4122630, 4123014, 4123398, 4123782,
+    4124166, 4124550, 4124934, 4125317, 4125637, 4125958, 4126341, 4126662, 4127046, 4127429, 4127749, 4128069,
+    4128389, 4128709, 4129029, 4129347, 4129540, 4129797, 4130117, 4130436, 4130693, 4131013, 4131332, 4131588,
+    4131845, 4132165, 4132485, 4132805, 4133125, 4133445, 4133765, 4134084, 4134340, 4134597, 4134916, 4135173,
+    4135493, 4135812, 4136068, 4136324, 4136580, 4136836, 4137092, 142914, 4137347, 4137540, 4137796, 4138051,
+    4138244, 4138500, 4138755, 4138947, 4139140, 4139396, 4139652, 4139908, 4140164, 4140420, 4140676, 4140931,
+    4141123, 4141316, 4141571, 4141764, 4142020, 4142275, 4142467, 4142659, 4142851, 4143043, 4143235, 4143428,
+    4143685, 4144006, 4144390, 4144773, 4145094, 4145478, 4145861, 4146181, 4146502, 4146886, 4147270, 4147654,
+    4148038, 4148422, 4148806, 4149189, 4149509, 4149830, 4150213, 4150534, 4150918, 4151301, 4151621, 4151941,
+    4152261, 4152581, 4152901, 4153219, 4153412, 4153669, 4153989, 4154308, 4154565, 4154885, 4155204, 4155460,
+    4155717, 4156037, 4156357, 4156677, 4156997, 4157317, 4157637, 4157956, 4158212, 4158469, 4158788, 4159045,
+    4159365, 4159684, 4159940, 4160196, 4160452, 4160708, 4160964, 143042, 4161219, 4161412, 4161668, 4161923,
+    4162116, 4162372, 4162627, 4162819, 4163012, 4163268, 4163524, 4163780, 4164036, 4164292, 4164548, 4164803,
+    4164995, 4165188, 4165443, 4165636, 4165892, 4166147, 4166339, 4166531, 917187, 917571, 4166723, 143171,
+    4166916, 4167173, 4167493, 4167812, 4168069, 4168389, 4168708, 4168964, 4169221, 4169541, 4169861, 4170181,
+    4170501, 4170821, 4171141, 4171460, 4171716, 4171973, 4172292, 4172549, 4172869, 4173188, 4173444, 4173700,
+    4173956, 4174212, 4174468, 4174724, 4174981, 4175302, 4175686, 4176069, 4176390, 4176774, 4177157, 4177477,
+    4177798, 4178182, 4178566, 4178950, 4179334, 4179718, 4180102, 4180485, 4180805, 4181126, 4181509, 4181830,
+    4182214, 4182597, 4182917, 4183237, 4183557, 4183877, 4184197, 4184515, 4184708, 4184965, 4185285, 4185604,
+    4185861, 4186181, 4186500, 4186756, 4187013, 4187333, 4187653, 4187973, 4188293, 4188613, 4188933, 4189252,
+    4189508, 4189765, 4190084, 4190341, 4190661, 4190980, 4191236, 4191492, 4191748, 4192004, 4192260, 4192515,
+    4192708, 4192965, 4193285, 4193604, 4193861, 4194181, 4194500, 4194756, 4195013, 4195333, 4195653, 4195973,
+    4196293, 4196613, 4196933, 4197252, 4197508, 4197765, 4198084, 4198341, 4198661, 4198980, 4199236, 4199492,
+    4199748, 4200004, 4200260, 142274, 4200515, 4200708, 4200964, 4201219, 4201412, 4201668, 4201923, 4202115,
+    4202308, 4202564, 4202820, 4203076, 4203332, 4203588, 4203844, 4204099, 4204291, 4204484, 4204739, 4204932,
+    4205188, 4205443, 4205635, 4205827, 917763, 918147, 4206019, 4206212, 4206469, 4206790, 4207174, 4207557,
+    4207878, 4208262, 4208645, 4208965, 4209286, 4209670, 4210054, 4210438, 4210822, 4211206, 4211590, 4211973,
+    4212293, 4212614, 4212997, 4213318, 4213702, 4214085, 4214405, 4214725, 4215045, 4215365, 4215685, 147715,
+    4216004, 4216261, 4216581, 4216900, 4217157, 4217477, 4217796, 4218052, 4218309, 4218629, 4218949, 4219269,
+    4219589, 4219909, 4220229, 4220548, 4220804, 4221061, 4221380, 4221637, 4221957, 4222276, 4222532, 4222788,
+    4223044, 4223300, 4223556, 147907, 4223812, 4224069, 4224389, 4224708, 4224965, 4225285, 4225604, 4225860,
+    4226117, 4226437, 4226757, 4227077, 4227397, 4227717, 4228037, 4228356, 4228612, 4228869, 4229188, 4229445,
+    4229765, 4230084, 4230340, 4230596, 4230852, 4231108, 4231364, 4231619, 4231812, 4232069, 4232389, 4232708,
+    4232965, 4233285, 4233604, 4233860, 4234117, 4234437, 4234757, 4235077, 4235397, 4235717, 4236037, 4236356,
+    4236612, 4236869, 4237188, 4237445, 4237765, 4238084, 4238340, 4238596, 4238852, 4239108, 4239364, 4239619,
+    4239812, 4240069, 4240389, 4240708, 4240965, 4241285, 4241604, 4241860, 4242117, 4242437, 4242757, 4243077,
+    4243397, 4243717, 4244037, 4244356, 4244612, 4244869, 4245188, 4245445, 4245765, 4246084, 4246340, 4246596,
+    4246852, 4247108, 4247364, 4247619, 4247812, 4248069, 4248389, 4248708, 4248965, 4249285, 4249604, 4249860,
+    4250117, 4250437, 4250757, 4251077, 4251397, 4251717, 4252037, 4252356, 4252612, 4252869, 4253188, 4253445,
+    4253765, 4254084, 4254340, 4254596, 4254852, 4255108, 4255364, 142402, 4255619, 4255812, 4256068, 4256323,
+    4256516, 4256772, 4257027, 4257219, 4257412, 4257668, 4257924, 4258180, 4258436, 4258692, 4258948, 4259203,
+    4259395, 4259588, 4259843, 4260036, 4260292, 4260547, 4260739, 4260931, 914627, 915011, 4261123, 86210,
+    4261315, 4261508, 4261764, 4262019, 4262212, 4262468, 4262723, 4262915, 4263108, 4263364, 4263620, 4263876,
+    4264132, 4264388, 4264644, 4264899, 4265091, 4265284, 4265539, 4265732, 4265988, 4266243, 4266435, 4266627,
+    1033475, 1033859, 4266819, 4267011, 4267204, 4267461, 4267781, 4268100, 4268357, 4268677, 4268996, 4269252,
+    4269509, 4269829, 4270149, 4270469, 4270789, 4271109, 4271429, 4271748, 4272004, 4272261, 4272580, 4272837,
+    4273157, 4273476, 4273732, 4273988, 4274244, 4274500, 4274756, 457667, 4275012, 4275269, 4275589, 4275908,
+    4276165, 4276485, 4276804, 4277060, 4277317, 4277637, 4277957, 4278277, 4278597, 4278917, 4279237, 4279556,
+    4279812, 4280069, 4280388, 4280645, 4280965, 4281284, 4281540, 4281796, 4282052, 4282308, 4282564, 4282820,
+    4283077, 4283398, 4283782, 4284165, 4284486, 4284870, 4285253, 4285573, 4285894, 4286278, 4286662, 4287046,
+    4287430, 4287814, 4288198, 4288581, 4288901, 4289222, 4289605, 4289926, 4290310, 4290693, 4291013, 4291333,
+    4291653, 4291973, 4292293, 4292611, 4292804, 4293061, 4293381, 4293700, 4293957, 4294277, 4294596, 4294852,
+    4295109, 4295429, 4295749, 4296069, 4296389, 4296709, 4297029, 4297348, 4297604, 4297861, 4298180, 4298437,
+    4298757, 4299076, 4299332, 4299588, 4299844, 4300100, 4300356, 86978, 4300611, 4300804, 4301060, 4301315,
+    4301508, 4301764, 4302019, 4302211, 4302404, 4302660, 4302916, 4303172, 4303428, 4303684, 4303940, 4304195,
+    4304387, 4304580, 4304835, 4305028, 4305284, 4305539, 4305731, 4305923, 4306115, 1035779, 4306307, 4306500,
+    4306757, 4307078, 4307462, 4307845, 4308166, 4308550, 4308933, 4309253, 4309574, 4309958, 4310342, 4310726,
+    4311110, 4311494, 4311878, 4312261, 4312581, 4312902, 4313285, 4313606, 4313990, 4314373, 4314693, 4315013,
+    4315333, 4315653, 4315973, 457091, 4316292, 4316549, 4316869, 4317188, 4317445, 4317765, 4318084, 4318340,
+    4318597, 4318917, 4319237, 4319557, 4319877, 4320197, 4320517, 4320836, 4321092, 4321349, 4321668, 4321925,
+    4322245, 4322564, 4322820, 4323076, 4323332, 4323588, 4323844, 87106, 4324099, 4324292, 4324548, 549827,
+    4324804, 4325060, 4325315, 4325507, 4325700, 4325956, 4326212, 4326468, 4326724, 4326980, 4327236, 4327491,
+    4327683, 4327876, 4328131, 4328324, 4328580, 4328835, 4329027, 4329219, 1035011, 1035395, 4329411, 185731,
+    4329604, 4329861, 4330181, 4330500, 4330757, 4331077, 4331396, 4331652, 4331909, 4332229, 4332549, 4332869,
+    4333189, 4333509, 4333829, 4334148, 4334404, 4334661, 4334980, 4335237, 4335557, 4335876, 4336132, 4336388,
+    4336644, 4336900, 4337156, 4337412, 4337669, 4337990, 4338374, 4338757, 4339078, 4339462, 4339845, 4340165,
+    4340486, 4340870, 4341254, 4341638, 4342022, 4342406, 4342790, 4343173, 4343493, 4343814, 4344197, 4344518,
+    4344902, 4345285, 4345605, 4345925, 4346245, 4346565, 4346885, 4347203, 4347396, 4347653, 4347973, 4348292,
+    4348549, 4348869, 4349188, 4349444, 4349701, 4350021, 4350341, 4350661, 4350981, 4351301, 4351621, 4351940,
+    4352196, 4352453, 4352772, 4353029, 4353349, 4353668, 4353924, 4354180, 4354436, 4354692, 4354948, 457859,
+    4355204, 4355461, 4355781, 4356100, 4356357, 4356677, 4356996, 4357252, 4357509, 4357829, 4358149, 4358469,
+    4358789, 4359109, 4359429, 4359748, 4360004, 4360261, 4360580, 4360837, 4361157, 4361476, 4361732, 4361988,
+    4362244, 4362500, 4362756, 86338, 4363011, 4363204, 4363460, 4363715, 4363908, 4364164, 4364419, 4364611,
+    4364804, 4365060, 4365316, 4365572, 4365828, 4366084, 4366340, 4366595, 4366787, 4366980, 4367235, 4367428,
+    4367684, 4367939, 4368131, 4368323, 4368515, 4368707, 4368899, 4369092, 4369349, 4369670, 4370054, 4370437,
+    4370758, 4371142, 4371525, 4371845, 4372166, 4372550, 4372934, 4373318, 4373702, 4374086, 4374470, 4374853,
+    4375173, 4375494, 4375877, 4376198, 4376582, 4376965, 4377285, 4377605, 4377925, 4378245, 4378565, 185091,
+    4378884, 4379141, 4379461, 4379780, 4380037, 4380357, 4380676, 4380932, 4381189, 4381509, 4381829, 4382149,
+    4382469, 4382789, 4383109, 4383428, 4383684, 4383941, 4384260, 4384517, 4384837, 4385156, 4385412, 4385668,
+    4385924, 4386180, 4386436, 185539, 4386692, 4386949, 4387269, 4387588, 4387845, 4388165, 4388484, 4388740,
+    4388997, 4389317, 4389637, 4389957, 4390277, 4390597, 4390917, 4391236, 4391492, 4391749, 4392068, 4392325,
+    4392645, 4392964, 4393220, 4393476, 4393732, 4393988, 4394244, 457283, 4394500, 4394757, 4395077, 4395396,
+    4395653, 4395973, 4396292, 4396548, 4396805, 4397125, 4397445, 4397765, 4398085, 4398405, 4398725, 4399044,
+    4399300, 4399557, 4399876, 4400133, 4400453, 4400772, 4401028, 4401284, 4401540, 4401796, 4402052, 4402307,
+    4402500, 4402757, 4403077, 4403396, 4403653, 4403973, 4404292, 4404548, 4404805, 4405125, 4405445, 4405765,
+    4406085, 4406405, 4406725, 4407044, 4407300, 4407557, 4407876, 4408133, 4408453, 4408772, 4409028, 4409284,
+    4409540, 4409796, 4410052, 457475, 4410308, 4410565, 4410885, 4411204, 4411461, 4411781, 4412100, 4412356,
+    4412613, 4412933, 4413253, 4413573, 4413893, 4414213, 4414533, 4414852, 4415108, 4415365, 4415684, 4415941,
+    4416261, 4416580, 4416836, 4417092, 4417348, 4417604, 4417860, 86466, 4418115, 4418308, 4418564, 4418819,
+    4419012, 4419268, 4419523, 4419715, 4419908, 4420164, 4420420, 4420676, 4420932, 4421188, 4421444, 4421699,
+    4421891, 4422084, 4422339, 4422532, 4422788, 4423043, 4423235, 4423427, 1032835, 4423619, 4423811, 4424004,
+    4424259, 123843, 4424452, 4424708, 4424964, 4425219, 4425412, 1143747, 4425667, 4425860, 4426115, 4426307,
+    4426500, 4426756, 4427012, 4427267, 4427459, 4427651, 4427843, 38274, 38914, 6338, 4428034, 4428163,
+    35650, 4428355, 10626, 1179714, 4428547, 19586, 30147, 4428738, 1179843, 4428867, 4429059, 4429251,
+    4429443, 4429635, 218563, 4429827, 26882, 4430018, 4430147, 569410, 4430339, 4430530, 62210, 4430659,
+    4430850, 1181378, 4430979, 63426, 4431171, 64770, 150018, 4431363, 4431555, 4431746, 28546, 4431875,
+    15362, 8194, 4432066, 4432194, 4432323, 4432515, 4432706, 4432835, 61186, 14978, 40130, 4433028,
+    4433286, 776131, 750019, 4433668, 818820, 839236, 839236, 4433925, 4434244, 4434500, 719299, 719299,
+    719299, 727427, 719299, 719299, 719299, 719299, 719299, 4434756, 4434756, 4434756, 4434756, 4434756,
+    4434756, 4434756, 752324, 752324, 752324, 752324, 752324, 752324, 4435012, 4435012, 4435012, 4435012,
+    4435268, 4435268, 4435268, 716741, 716741, 716741, 716741, 716741, 786372, 4435526, 4435907, 4435907,
+    4435907, 4435907, 4435907, 4435907, 4435907, 4435907, 4435907, 4435907, 4435907, 4435907, 4436100, 4436100,
+    4436100, 4436100, 4436100, 4436100, 4436356, 751365, 751365, 751365, 751365, 871748, 871748, 871748,
+    871748, 4436612, 4436612, 4436612, 4436612, 4436612, 4436612, 4436612, 4436612, 4436868, 4436868, 4437124,
+    846917, 846917, 846917, 846917, 846917, 4437380, 727427, 4437636, 4437636, 772100, 4437894, 4438275,
+    4438469, 710339, 4438788, 793092, 4439045, 817156, 4439365, 701059, 4439684, 4439684, 4439940, 4440197,
+    829894, 4440516, 859460, 4440773, 800964, 4441092, 883588, 705667, 4441348, 4441348, 733765, 733765,
+    733765, 733765, 733765, 733765, 733765, 733765, 702083, 702083, 702083, 702083, 702083, 702083,
+    702083, 702083, 702083, 702083, 702083, 4441604, 4441604, 4441604, 4441604, 4441860, 4441860, 4441860,
+    4441860, 4441860, 4441860, 4441860, 4441860, 4441860, 4441860, 4441860, 4441860, 4441860, 4441860, 4442116,
+    4442116, 4442116, 4442116, 4442116, 4442116, 4442372, 4442372, 4442372, 4442372, 4442372, 4442628, 4442885,
+    4442885, 4442885, 4442885, 4442885, 4442885, 4442885, 4442885, 4442885, 4442885, 4442885, 4442885, 4442885,
+    4443204, 4443204, 4443204, 4443204, 860418, 795139, 795139, 795139, 795139, 795139, 795139, 795139,
+    795139, 795139, 795139, 744133, 4443460, 736068, 699331, 699331, 699331, 699331, 699331, 699331,
+    699331, 699331, 699331, 699331, 4443716, 4443716, 4443716, 722628, 722628, 722628, 722628, 4443972,
+    4443972, 4443972, 4443972, 4444229, 4444546, 4444546, 4444546, 4444546, 4444546, 4444546, 4444546, 4444546,
+    4444546, 4444546, 4444546, 4444546, 4444546, 4444546, 4444675, 4444675, 4444867, 4444867, 4444867, 4444867,
+    4444867, 4444867, 4444867, 4445059, 4445059, 4445059, 883843, 883843, 883843, 729797, 4445252, 742147,
+    4445508, 749188, 4445765, 4446085, 4446405, 4446405, 4446726, 4447110, 4447494, 4447876, 4448133, 792261,
+    792261, 4448453, 4448773, 712515, 737604, 4449091, 706246, 4449282, 761605, 715331, 708804, 696515,
+    710339, 727621, 715526, 710979, 710021, 710021, 699331, 876357, 4449411, 708804, 689155, 696515,
+    773315, 770052, 702915, 707205, 4449604, 630468, 469378, 462338, 681220, 475332, 475332, 607555,
+    467331, 480258, 626691, 471490, 668228, 665218, 649220, 467651, 599875, 631171, 675268, 593093,
+    471299, 460418, 477763, 620035, 637187, 465027, 459715, 465027, 463620, 594435, 467331, 481219,
+    474563, 471490, 474051, 467522, 465027, 463427, 286532, 470082, 474434, 460867, 635332, 468099,
+    617539, 477763, 640003, 589892, 466179, 466179, 640003, 480258, 462979, 469378, 468099, 642115,
+    675268, 467844, 17282, 607939, 483333, 647363, 599875, 484099, 662788, 459460, 595589, 464388,
+    482116, 481797, 460418, 479300, 656452, 480258, 631619, 599875, 478850, 649925, 650691, 467139,
+    647555, 473410, 592770, 594306, 17282, 605059, 461571, 461316, 482564, 464642, 675268, 594306,
+    593093, 462787, 464642, 633155, 459460, 594306, 468932, 609028, 461316, 628485, 462787, 645507,
+    592195, 476803, 475202, 459715, 603780, 465027, 630212, 472451, 617539, 465027, 286532, 592770,
+    609283, 662788, 477123, 17282, 478981, 474563, 648516, 640003, 479556, 642308, 460867, 480771,
+    631619, 635332, 668228, 640003, 470981, 609283, 630723, 463877, 468802, 462979, 603076, 465027,
+    468932, 469378, 590787, 464642, 468099, 482820, 675268, 477763, 471619, 633155, 482116, 465027,
+    607939, 603780, 480067, 483333, 3457, 647363, 286532, 470211, 0, 0, 0, 480258,
+    589315, 460867, 0, 477508, 597700, 3457, 617732, 4449858, 141186, 220354, 4449987, 4450179,
+    15362, 15362, 4430018, 91138, 90626, 4450370, 4450499, 19842, 0, 52674, 12289, 1985,
+    4545, 5121, 5633, 5761, 11137, 11137, 6785, 12737, 8194, 6657, 8194, 6657,
+    1089, 1089, 1985, 7105, 4993, 4545, 5121, 11201, 7041, 6785, 4737, 5633,
+    5633, 5761, 5761, 4609, 4609, 7873, 7873, 8706, 8706, 6529, 8194, 6785,
+    104834, 7105, 5633, 7873, 5761, 1985, 1985, 7105, 7105, 7105, 7105, 7873,
+    7873, 7873, 7873, 35970, 35970, 35970, 35970, 4802, 4802, 4802, 4802, 4802,
+    4802, 4802, 4802, 6785, 6785, 6785, 6785, 11201, 11201, 11201, 11201, 14722,
+    14722, 14722, 14722, 5505, 5505, 5505, 5505, 4609, 4609, 4609, 4609, 16002,
+    16002, 16002, 16002, 16002, 16002, 16002, 16002, 34434, 34434, 34434, 34434, 34434,
+    34434, 4545, 4545, 8514, 8514, 6529, 6529, 5633, 5633, 5633, 5633, 4993,
+    4993, 4993, 4993, 4993, 4993, 4993, 4993, 4609, 4609, 4609, 4609, 4609,
+    4609, 4609, 4609, 4609, 4609, 4417, 4417, 5121, 5121, 5121, 5121, 5121,
+    5121, 5121, 5121, 4417, 4417, 4450690, 4450690, 12673, 12673, 12673, 12673, 12673,
+    12673, 12673, 12673, 12673, 12673, 12673, 12673, 12673, 12673, 6785, 6785, 1985,
+    5954, 5954, 5954, 5954, 2049, 2049, 2369, 2369, 2049, 2049, 2049, 11201,
+    11201, 2369, 2369, 2049, 2049, 4417, 4417, 4417, 4417, 1089, 1089, 4450819,
+    4450819, 4451011, 4451011, 4451203, 4451203, 4451395, 4451395, 4451587, 4451587, 4451395, 4451395, 4451011,
+    4451011, 4451011, 4450819, 4450819, 4450819, 4737, 4737, 4737, 4737, 4451779, 4451971, 4452163,
+    4450819, 4452355, 63426, 35970, 4452547, 4430850, 102722, 913858, 28802, 4802, 4452739, 229506,
+    105154, 4452930, 4453059, 4453251, 132227, 4453443, 22658, 4453634, 4453762, 52930, 4453891, 43203,
+    4454083, 28546, 8194, 4454275, 64770, 8194, 64770, 19586, 34434, 4454467, 562114, 4802,
+    229506, 4454659, 4454850, 4454978, 4455107, 4455299, 4455490, 4455618, 4455747, 569026, 140930, 950722,
+    4455938, 4456066, 97474, 1120322, 113474, 20162, 14850, 4456195, 220098, 569666, 113474, 41026,
+    9730, 26882, 29123, 80898, 87426, 200706, 4430530, 27394, 4450499, 569410, 90370, 947458,
+    10114, 27138, 4456387, 569154, 109442, 29826, 4453762, 52930, 86210, 37250, 4456578, 37762,
+    4456707, 4456898, 46594, 43842, 1159875, 93314, 38146, 4457027, 4457219, 4457410, 4457538, 4457666,
+    4457794, 4457923, 4458115, 4452163, 4458307, 4450819, 4452355, 4458498, 4458626, 4430850, 62082, 102722,
+    913858, 211906, 1173698, 229506, 4458754, 105154, 4452930, 4458883, 4459075, 4453251, 4459267, 132227,
+    4453443, 140930, 950722, 97474, 1120322, 113474, 220098, 569666, 113474, 41026, 80898, 87426,
+    200706, 90370, 569410, 40770, 4459458, 569154, 42370, 109442, 29826, 38146, 199042, 4459586,
+    4456898, 4459714, 46594, 43842, 4451779, 4451971, 4459844, 4452163, 4451971, 63426, 35970, 4452547,
+    4430850, 35970, 28802, 4802, 4452739, 229506, 4802, 4453251, 22658, 4453634, 4453762, 52930,
+    4453891, 4454083, 28546, 8194, 4454275, 64770, 8194, 4454275, 64770, 19586, 34434, 4454467,
+    562114, 4802, 4454659, 4454850, 4454978, 4455107, 4455299, 4455490, 4455618, 4455747, 569026, 4455938,
+    4456066, 20162, 14850, 4456195, 220098, 569666, 9730, 26882, 29123, 80898, 26882, 4430530,
+    27394, 4450499, 569410, 10114, 27138, 4456387, 569154, 27138, 4453762, 52930, 86210, 4456578,
+    37762, 4456707, 4456898, 37762, 4452163, 4451971, 4430850, 35970, 229506, 4802, 4453251, 4460099,
+    64770, 8194, 4460291, 4460483, 220098, 569666, 80898, 569154, 27138, 4456898, 37762, 4457410,
+    4457538, 4457666, 105154, 4452930, 119490, 4460674, 184515, 4460803, 94530, 219266, 95747, 1105603,
+    86210, 37250, 126978, 202434, 187395, 4460995, 94530, 219266, 124034, 36226, 4461187, 4460483,
+    4461380, 4460291, 4461635, 64642, 64642, 4461826, 105154, 4452930, 119490, 4460674, 184515, 4460803,
+    94530, 219266, 95747, 1105603, 86210, 37250, 126978, 202434, 187395, 4460995, 94530, 219266,
+    124034, 36226, 4461187, 4460483, 4461380, 4460291, 4461635, 64642, 64642, 4461826, 4461187, 4460483,
+    4461380, 4460291, 8194, 4460483, 229506, 28546, 8194, 4454275, 4461187, 4460483, 4461380, 229506,
+    4454659, 4461955, 4461955, 224833, 220033, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 4462147, 4453059, 4453059,
+    4453251, 4462340, 4462595, 4462787, 4462980, 4463235, 4463235, 941443, 937347, 4461187, 4463427, 4463619,
+    4463811, 4463811, 4464003, 4464195, 4464195, 4460483, 4460483, 4464195, 4464388, 4464388, 4464644, 4464901,
+    4464901, 4465220, 4465220, 1159875, 4465476, 4465476, 4462787, 4462787, 4465731, 4465923, 4466115, 4466307,
+    4466307, 4466499, 4466692, 4466948, 4467204, 4467460, 4467460, 4467715, 4467907, 4468099, 4468291, 183043,
+    4468483, 4468483, 4468676, 4468676, 4429251, 4429251, 4468931, 4469123, 4469315, 4469507, 4469699, 4469892,
+    4470148, 4470404, 4470659, 4470851, 4471043, 4471235, 4471427, 4471427, 1160067, 4471619, 4471811, 4472003,
+    4472003, 4472196, 4472451, 4472643, 4472836, 4473092, 4465923, 4473347, 4473539, 4473731, 4473923, 4474116,
+    1105603, 4474372, 4474627, 4474819, 4475011, 4475203, 4475395, 4475587, 4475779, 4475971, 4476163, 4467715,
+    4468099, 4476355, 4476547, 4476739, 4476932, 4477187, 4477379, 4477187, 4476739, 4477571, 4477763, 4477955,
+    4478147, 4478339, 4477379, 4466115, 4464195, 4478532, 1132547, 2177, 4478791, 4479238, 4479621, 4479941,
+    4480264, 4480772, 4481029, 4481352, 4481866, 4478791, 4482525, 4484368, 4485381, 4485727, 2177, 2177,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 2241, 2241, 12673, 12609, 14081, 65, 3137, 485442,
+    4487682, 39683, 0, 0, 0, 12801, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 39170, 222082, 1473, 12545, 12545, 224833,
+    4487810, 37633, 485314, 242369, 485186, 485442, 485571, 1154, 484995, 12353, 484866, 242369,
+    485186, 37633, 37697, 1985, 1985, 242369, 57153, 1473, 1473, 1473, 1473, 12545,
+    12545, 12545, 2241, 2241, 12673, 0, 14081, 12609, 3137, 65, 1473, 224833,
+    220033, 37633, 37697, 37633, 37697, 32833, 15105, 2177, 12737, 1473, 12353, 12417,
+    12993, 0, 12481, 53185, 35201, 7297, 34562, 34562, 34690, 12545, 34818, 1089,
+    1089, 2049, 2049, 4481, 4481, 1473, 1473, 12673, 12673, 1985, 1089, 1089,
+    1985, 1985, 25474, 25474, 1985, 1985, 4487938, 4487938, 4487938, 4487938, 1089, 1089,
+    7105, 7105, 7105, 7105, 5121, 5121, 6785, 6785, 6785, 6785, 4802, 4802,
+    4802, 4802, 5505, 5505, 5505, 5505, 5121, 5121, 5121, 5121, 14850, 14850,
+    14850, 14850, 4545, 4545, 34434, 34434, 6529, 6529, 7041, 7041, 6657, 6657,
+    6657, 6657, 8194, 8194, 8194, 8194, 6657, 6657, 6657, 6657, 4545, 4545,
+    4545, 4545, 6785, 6785, 6785, 6785, 34434, 34434, 34434, 34434, 12289, 12289,
+    12289, 12289, 27778, 27778, 27778, 27778, 7425, 7425, 7425, 7425, 10945, 10945,
+    10945, 10945, 5633, 5633, 5633, 5633, 5761, 5761, 5761, 5761, 11137, 11137,
+    11137, 11137, 4609, 4609, 4609, 4609, 5121, 5121, 5121, 5121, 6913, 6913,
+    1089, 1089, 4737, 4737, 4737, 4737, 87426, 87426, 4488066, 4488066, 4488066, 4488066,
+    87426, 87426, 0, 65, 833, 32833, 53185, 35201, 15105, 1985, 224833, 220033,
+    2177, 12737, 2241, 1473, 12673, 4673, 34945, 2305, 1857, 1921, 12929, 8641,
+    7233, 35009, 35073, 35137, 12609, 14081, 12353, 12993, 12417, 3137, 7297, 3201,
+    7169, 3393, 3585, 3457, 7361, 4929, 5057, 3521, 5441, 5569, 5697, 14145,
+    3649, 3713, 2113, 15169, 6465, 6593, 6721, 3841, 8321, 6849, 12865, 3905,
+    6977, 242369, 12481, 57153, 10881, 12545, 12289, 1089, 7105, 4353, 4545, 4417,
+    7425, 4993, 5121, 4481, 5505, 5633, 5761, 11137, 4609, 2369, 7873, 10945,
+    6529, 6657, 6785, 2049, 11201, 6913, 3777, 4737, 7041, 37633, 641, 37697,
+    12801, 224833, 220033, 12673, 242369, 57153, 2241, 2177, 119170, 1089, 4481, 2049,
+    4417, 2369, 46594, 37378, 67394, 105282, 12737, 1089, 4481, 2049, 4417, 2369,
+    113474, 113730, 113602, 114242, 114370, 94530, 94786, 94658, 95298, 95426, 105154, 16258,
+    105282, 105794, 105922, 109442, 109698, 109570, 110210, 110338, 86210, 86466, 86338, 86978,
+    87106, 90370, 90626, 90498, 91138, 91266, 46594, 37378, 67394, 93314, 93570, 93442,
+    94082, 94210, 67074, 4609, 12609, 14081, 0, 4993, 61058, 80642, 4609, 10114,
+    27138, 4545, 36546, 6529, 80770, 80898, 81026, 11778, 81154, 81282, 31554, 11137,
+    7105, 45954, 62338, 6657, 4098, 0, 5505, 61186, 4353, 5633, 6785, 7873,
+    5121, 1089, 4226, 46594, 66435, 66626, 4417, 66755, 66946, 2369, 67074, 67203,
+    6338, 67394, 2049, 67523, 67714, 67842, 37378, 67970, 19842, 4481, 4488194, 258,
+    65, 1473, 641, 514, 4488322, 641, 1473, 641, 1473, 641, 32833, 3713,
+    37633, 641, 37697, 3137, 3137, 0, 1089, 4417, 4481, 2369, 2049, 124034,
+    124802, 124290, 124930, 124162, 126978, 127746, 127874, 127106, 113474, 114242, 113730, 114370,
+    113602, 90370, 91138, 90626, 91266, 90498, 109442, 110210, 109698, 110338, 109570, 142146,
+    142914, 142402, 143042, 142274, 97474, 98242, 97730, 98370, 93314, 94082, 93570, 94210,
+    93442, 94530, 95298, 94786, 95426, 94658, 105154, 105794, 16258, 105922, 105282, 67074,
+    67714, 67842, 119170, 120514, 121282, 121410, 2241, 12673, 2177, 2305, 1857, 1921,
+    12929, 8641, 7233, 35009, 35073, 35137, 48450, 49538, 335362, 49666, 514882, 49794,
+    515010, 49026, 4488450, 48579, 4488579, 4488771, 4488963, 4489155, 4489347, 4489539, 4489731, 446467,
+    48772, 4489924, 4490180, 4490436, 4490692, 4490948, 4491204, 4491460, 4491716, 4491973, 4492293, 4492613,
+    4492933, 4493253, 4493573, 4493893, 4494213, 4494533, 2177, 2177, 32833, 2177, 2177, 2177,
+    2177, 2177, 2177, 2563, 2755, 2305, 8641, 514882, 4489155, 4490692, 4493253, 8641,
+    48450, 514882, 48579, 4489155, 48772, 4490692, 8641, 48450, 514882, 48579, 4489155, 48772,
+    4491973, 4493253, 48450, 2305, 2305, 2305, 1857, 1857, 1857, 1857, 8641, 48450,
+    48450, 48450, 48450, 48450, 335362, 514882, 514882, 514882, 514882, 48579, 4488771, 4489155,
+    4489155, 4489155, 4489155, 4489155, 48772, 4490692, 8641, 514882, 2755, 2755, 4494851, 2947,
+    4737, 6785, 12353, 2305, 1857, 1921, 12929, 8641, 11137, 5569, 5761, 648386,
+    3777, 1089, 1089, 4495042, 6785, 34945, 2563, 4737, 3521, 228738, 12993, 1473,
+    6593, 6593, 6593, 459138, 12865, 8321, 4495171, 236546, 641, 12417, 2177, 447363,
+    4495362, 4495490, 4495618, 4495746, 4495874, 4496002, 4496130, 213314, 4496258, 48450, 263618, 47810,
+    263746, 263874, 264002, 47938, 199682, 199810, 199938, 49538, 515330, 515458, 515586, 515714,
+    515842, 515970, 516098, 516226, 516354, 335362, 516482, 48066, 516610, 516738, 516866, 523586,
+    523714, 523842, 523970, 49666, 524098, 524226, 524354, 524482, 524610, 524738, 1089, 4417,
+    7105, 7105, 4993, 4545, 4481, 6913, 7041, 4802, 4737, 5633, 10945, 5761,
+    11137, 4609, 11137, 4609, 2049, 7873, 5633, 6529, 6657, 6785, 6785, 1089,
+    4417, 5121, 3777, 1089, 7873, 4545, 5761, 4737, 6529, 5761, 1089, 10945,
+    7105, 11137, 2369, 4545, 6785, 8194, 8194, 6657, 3137, 2049, 4609, 5633,
+    4609, 6785, 7873, 6657, 4481, 4417, 4737, 5633, 5633, 4545, 6913, 4993,
+    4993, 7041, 7041, 5954, 5505, 3137, 6785, 4737, 6529, 7105, 7105, 7105,
+    7105, 5761, 4417, 4737, 5633, 2305, 1857, 1921, 12929, 8641, 7233, 35009,
+    35073, 35137, 48450, 49538, 335362, 49666, 514882, 49794, 515010, 49026, 4488450, 48579,
+    4488579, 4488771, 4488963, 4489155, 4489347, 4489539, 4489731, 446467, 1089, 7105, 4353, 4545,
+    4417, 11201, 7041, 5121, 4802, 4481, 5633, 5761, 11137, 4609, 6657, 2369,
+    7873, 6657, 10945, 6529, 6657, 6785, 2049, 3777, 14722, 16002, 7425, 6529,
+    4353, 4481, 2049, 6657, 3521, 8321, 12865, 5697, 5505, 25218, 25218, 1089,
+    7105, 4993, 4545, 4417, 10945, 7041, 5121, 4802, 4481, 5633, 5761, 11137,
+    4609, 5505, 2049, 7873, 4488450, 6529, 6657, 6785, 6913, 7425, 3777, 1169090,
+    2369, 446467, 1089, 7105, 4993, 4545, 4417, 8514, 20291, 7041, 9346, 4481,
+    5633, 5761, 11137, 4609, 2369, 7873, 6529, 6657, 6785, 2049, 4353, 8194,
+    4496387, 4737, 4737, 2369, 2369, 7425, 5121, 4353, 11201, 221442, 1985, 4417,
+    19330, 127106, 126978, 19202, 1089, 4545, 7041, 4609, 6657, 1089, 7105, 4993,
+    5121, 4545, 5121, 6913, 7041, 5121, 6785, 4737, 5633, 8194, 5761, 11137,
+    4545, 4609, 7041, 6657, 12289, 7873, 6657, 10945, 6529, 6785, 4993, 6785,
+    4481, 2049, 6657, 641, 1089, 4481, 2049, 113474, 113602, 128194, 128322, 107458,
+    106242, 126978, 127234, 105154, 105282, 124034, 124290, 124162, 132227, 142146, 102722, 140930,
+    109442, 109570, 90370, 90626, 90498, 46594, 103938, 104194, 93314, 93442, 87426, 94530,
+    120514, 95747, 4496579, 86210, 4496772, 4497026, 4497154, 490626, 12545, 2305, 1857, 48450,
+    49538, 48579, 3457, 3201, 3201, 3713, 3713, 3841, 3521, 3457, 3201, 3713,
+    3841, 3841, 3521, 4497282, 6849, 3905, 5057, 2113, 7169, 6721, 3585, 18178,
+    5441, 5569, 4929, 7361, 8321, 3970, 4497410, 6593, 6977, 15746, 17922, 6465,
+    5697, 14145, 3649, 24450, 4497538, 464642, 4417, 1089, 1089, 2369, 2369, 2049,
+    4481, 4417, 1089, 2369, 2049, 2049, 4481, 1179266, 6913, 4737, 5121, 7873,
+    7105, 6785, 4545, 16002, 5505, 5633, 4993, 7425, 11201, 4802, 34434, 6657,
+    7041, 8194, 8514, 6529, 5761, 11137, 4609, 5954, 32706, 37378, 7873, 6785,
+    5633, 7425, 4802, 6657, 8194, 16002, 4737, 5954, 7105, 4545, 4993, 11201,
+    34434, 7041, 8514, 5505, 6913, 5121, 5761, 11137, 4481, 4417, 1089, 1985,
+    2369, 2049, 42242, 38146, 6529, 4609, 38274, 52546, 41986, 2049, 10498, 41730,
+    7746, 38402, 4497666, 4497794, 4497922, 4498050, 4498178, 4498306, 4487938, 4498435, 1985, 7105,
+    6785, 5505, 3777, 14850, 4545, 6529, 6657, 8194, 34434, 4353, 4993, 7425,
+    10945, 5633, 5761, 11137, 4609, 6913, 5121, 4737, 1089, 4417, 4481, 2369,
+    2049, 38146, 38530, 38402, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009,
+    35073, 35137, 3201, 615426, 615426, 3457, 4498626, 18178, 4498755, 3457, 4498946, 5057,
+    4499074, 3521, 5569, 4499202, 4499330, 5697, 14145, 3649, 3713, 652994, 2113, 4499458,
+    6593, 15746, 6721, 4499586, 3393, 4499714, 18178, 3585, 3841, 6849, 12865, 4929,
+    6977, 17922, 1089, 41986, 41986, 4417, 4458498, 16002, 4499843, 4417, 52546, 5121,
+    37250, 4481, 5633, 178690, 41026, 5761, 11137, 4609, 2369, 7746, 7873, 1179138,
+    6657, 8194, 6785, 1179010, 4353, 4500034, 16002, 4545, 2049, 6913, 3777, 4993,
+    7041, 8514, 1089, 7105, 4353, 16002, 4545, 50946, 34434, 4417, 4417, 7425,
+    4993, 19714, 5121, 4481, 5505, 5633, 5761, 46082, 11137, 4609, 4609, 10114,
+    2369, 7873, 10945, 6529, 36994, 6657, 8194, 6785, 4802, 2049, 11201, 3777,
+    4737, 7041, 8514, 27778, 27778, 14850, 1089, 7105, 4993, 4545, 4417, 7041,
+    4417, 7041, 6785, 4353, 4737, 7041, 4481, 12289, 5761, 24322, 3777, 4500162,
+    4353, 5505, 5633, 4488066, 5121, 3777, 1089, 4353, 4353, 4500290, 11137, 10945,
+    4609, 4500418, 6657, 5505, 2369, 25218, 7425, 5505, 4353, 7873, 4993, 6529,
+    6657, 11201, 6785, 6657, 2049, 4500290, 4353, 6913, 7873, 5633, 2177, 3201,
+    7169, 7169, 3393, 18178, 3585, 4497410, 3457, 3457, 7361, 4929, 5057, 5057,
+    3521, 4500546, 5441, 5569, 5697, 1177282, 14145, 3649, 9986, 3713, 2113, 15169,
+    6465, 6593, 15746, 6721, 3970, 3841, 8321, 12865, 3905, 6977, 1089, 7105,
+    7105, 4353, 16002, 4545, 34434, 4417, 4417, 7425, 4993, 5121, 5121, 4481,
+    5314, 5505, 5633, 5761, 46082, 11137, 4609, 10114, 2369, 7873, 10945, 6529,
+    6657, 8194, 6785, 4802, 2049, 11201, 3777, 4737, 7041, 38146, 12609, 1473,
+    4226, 7105, 7105, 9346, 9346, 9346, 20291, 4545, 4545, 4545, 4417, 2369,
+    4500675, 2049, 4993, 4993, 4993, 5121, 5121, 5121, 5505, 11778, 11906, 5761,
+    5761, 5761, 4500867, 4500867, 4737, 4737, 2369, 6338, 2049, 10945, 6529, 6529,
+    6529, 6529, 6529, 448578, 8706, 8706, 19971, 6785, 11201, 4737, 1985, 12289,
+    561986, 4353, 3777, 4501058, 10945, 6657, 1089, 4417, 4481, 2369, 2049, 126978,
+    127874, 113474, 114242, 113730, 114370, 113602, 87426, 88194, 87682, 88322, 87554, 90370,
+    91138, 90626, 91266, 90498, 109442, 110210, 109698, 110338, 109570, 142146, 142914, 142402,
+    143042, 142274, 93314, 94082, 93570, 94210, 93442, 94530, 95298, 94786, 95426, 94658,
+    105154, 105794, 16258, 105922, 105282, 67074, 67714, 67842, 119170, 107458, 108226, 120514,
+    121410, 1985, 7105, 4993, 4545, 5121, 6913, 7041, 5121, 6785, 4737, 5633,
+    5761, 11137, 4609, 6657, 12289, 7873, 6657, 10945, 6529, 8194, 6785, 12737,
+    2305, 1857, 1921, 48450, 49538, 48579, 48772, 4491973, 1985, 7105, 4993, 4545,
+    5121, 6913, 7041, 5121, 6785, 4737, 5633, 5761, 11137, 4609, 4609, 6657,
+    12289, 7873, 6657, 10945, 6529, 8194, 6785, 12353, 12417, 2305, 1857, 1921,
+    12929, 8641, 48450, 49538, 1985, 1985, 7105, 7105, 4993, 4545, 5121, 5121,
+    6913, 7041, 5121, 6785, 4737, 4737, 5633, 5633, 5761, 5761, 11137, 11137,
+    4609, 4609, 6657, 12289, 7873, 6657, 10945, 6529, 8194, 8194, 6785, 2305,
+    1857, 1921, 12929, 12929, 8641, 48450, 49538, 48579, 1985, 7105, 4993, 4545,
+    5121, 6913, 7041, 5121, 6785, 4737, 5633, 5761, 11137, 4609, 6657, 12289,
+    7873, 6657, 10945, 8194, 6785, 2305, 8641, 48450, 49538, 48579, 1985, 7105,
+    4993, 4545, 5121, 6913, 7041, 5121, 6785, 4737, 5633, 5761, 11137, 4609,
+    6657, 12289, 7873, 6657, 10945, 6529, 8194, 6785, 2305, 48450, 49538, 48579,
+    1857, 1921, 1, 1089, 7105, 4993, 4545, 4417, 11201, 4481, 4737, 5633,
+    5761, 11137, 4609, 2369, 6529, 6657, 6785, 2049, 7425, 10945, 6657, 6785,
+    1089, 4417, 5761, 4609, 4353, 10881, 1089, 4417, 4481, 2369, 4737, 6913,
+    7105, 7105, 7873, 11137, 4609, 4609, 110210, 110210, 6529, 6529, 5761, 3777,
+    5121, 6657, 6657, 95298, 5633, 10945, 6785, 6785, 105794, 105794, 105922, 4545,
+    2177, 12737, 1089, 4417, 4481, 2369, 4737, 6913, 7105, 7873, 11137, 4609,
+    110210, 6529, 5761, 3777, 5121, 6657, 6657, 95298, 5633, 10945, 6785, 105794,
+    105922, 4545, 263618, 55298, 4501187, 4501379, 2305, 1857, 1921, 12929, 8641, 7233,
+    35009, 35073, 35137, 48450, 49538, 335362, 49666, 514882, 49794, 515010, 48579, 4488579,
+    4488771, 4488963, 4489155, 4489347, 4489539, 4489731, 446467, 48772, 4489924, 4490180, 4490436, 4490692,
+    4490948, 4491204, 4491460, 4491716, 4491973, 4492293, 4492613, 4492933, 4493253, 4493573, 4493893, 4494213,
+    4494533, 4501574, 4501958, 4502342, 4502726, 4503110, 4503494, 4503878, 4504262, 4504646, 2305, 1857,
+    1921, 12929, 8641, 7233, 35009, 35073, 35137, 48450, 1089, 4481, 2049, 6529,
+    4417, 2369, 0, 0, 11137, 5121, 5633, 14850, 4993, 27778, 4353, 16002,
+    5505, 4609, 6785, 4802, 4545, 34434, 4609, 6785, 4802, 4545, 34434, 4609,
+    7873, 14722, 7105, 35970, 11137, 4737, 6529, 5761, 11201, 6657, 6657, 6657,
+    7041, 5121, 5633, 4802, 6785, 4505026, 1985, 1985, 0, 0, 2305, 1857,
+    1921, 12929, 48450, 49538, 48579, 48772, 55298, 2241, 12673, 2177, 32833, 12737,
+    7297, 2241, 12673, 12801, 5121, 5761, 5121, 11137, 10945, 6913, 6657, 6529,
+    7105, 6785, 6657, 5633, 4609, 5121, 6657, 6657, 7425, 1985, 12289, 4545,
+    4993, 4545, 4993, 6785, 7041, 4545, 4737, 6785, 7041, 641, 514882, 32833,
+    5121, 5761, 5121, 11137, 10945, 6913, 6657, 6529, 7105, 6785, 6657, 5633,
+    4609, 5121, 6657, 6657, 7425, 1985, 12289, 4545, 4993, 4545, 4993, 6785,
+    7041, 4545, 4737, 6785, 7041, 641, 48450, 49538, 1985, 7105, 35970, 4993,
+    27778, 4545, 5121, 6913, 6913, 7041, 8514, 5505, 22658, 5121, 6785, 4737,
+    5633, 5633, 14850, 5761, 34434, 4802, 11137, 4609, 6657, 12289, 12289, 7873,
+    7425, 4353, 10945, 10945, 4455938, 6529, 8194, 8194, 6785, 0, 0, 2305,
+    8641, 48450, 49538, 48579, 12737, 32833, 14081, 2241, 12673, 12545, 2177, 1089,
+    1089, 1089, 1089, 1089, 1089, 4417, 4417, 4417, 4417, 2369, 2369, 4481,
+    4481, 2049, 2049, 5633, 3777, 3777, 4505154, 4993, 4993, 27778, 4353, 5505,
+    6785, 4802, 4545, 34434, 6785, 7873, 7425, 7105, 35970, 5954, 5954, 4505283,
+    4609, 4609, 4609, 11137, 11137, 4737, 4737, 11201, 6529, 5761, 6657, 7041,
+    8194, 8514, 8194, 8194, 5121, 12673, 12545, 14081, 12673, 12673, 2177, 2177,
+    1985, 7105, 4993, 4545, 5121, 6913, 7041, 5121, 6785, 4737, 5633, 5761,
+    11137, 4609, 6657, 12289, 7873, 6657, 10945, 6529, 8194, 6785, 2305, 1857,
+    1921, 12929, 48450, 49538, 48579, 48772, 1985, 7105, 4993, 4545, 5121, 6913,
+    7041, 5121, 6785, 4737, 5633, 5761, 11137, 4609, 6657, 7873, 6657, 8194,
+    6785, 2305, 1857, 1921, 12929, 48450, 49538, 48579, 48772, 1985, 7105, 4993,
+    4545, 5121, 6913, 7041, 5121, 4737, 5633, 5761, 11137, 4609, 6657, 7873,
+    6657, 8194, 6785, 2177, 2177, 12737, 12737, 2305, 1857, 1921, 12929, 48450,
+    49538, 48579, 1089, 1089, 1089, 4481, 4481, 4417, 2369, 2369, 2369, 7105,
+    7105, 7105, 7105, 4993, 4993, 4993, 4993, 4545, 4545, 4545, 7041, 7041,
+    4737, 4737, 4737, 4737, 5633, 5633, 5633, 5633, 5761, 5761, 5761, 81154,
+    11137, 4609, 4609, 4609, 81858, 81858, 218242, 218242, 29826, 29826, 5954, 5954,
+    5954, 7873, 7873, 4353, 4353, 4353, 10945, 10945, 10945, 10945, 10945, 10945,
+    6529, 6529, 6529, 6657, 6657, 8194, 8194, 8194, 8194, 6785, 6785, 6785,
+    6785, 20738, 4505476, 3201, 3201, 7169, 4505730, 3393, 4505858, 4505986, 3585, 4506114,
+    3457, 3457, 3457, 7361, 4929, 578626, 5057, 3521, 3521, 5441, 5569, 5569,
+    4506242, 5697, 4506370, 14145, 3649, 29698, 3713, 3713, 3713, 3713, 3713, 2113,
+    4506498, 6465, 6465, 6593, 4506626, 6721, 4506754, 4506882, 18178, 3841, 3841, 3841,
+    3841, 8321, 6977, 4507010, 4507141, 6593, 1089, 1089, 7105, 37890, 4353, 218242,
+    4507458, 4545, 50946, 4417, 4417, 4417, 7425, 4993, 40898, 5121, 4481, 4481,
+    5505, 5633, 5633, 4507586, 5761, 200706, 11137, 4609, 29826, 2369, 2369, 2369,
+    2369, 2369, 7873, 40642, 6529, 6529, 6657, 4431746, 6785, 81858, 4452930, 16002,
+    2049, 2049, 2049, 2049, 11201, 7041, 4507714, 4507845, 6657, 2305, 8641, 48450,
+    514882, 48579, 48772, 7105, 7873, 6785, 6785, 5505, 16002, 5121, 14850, 7425,
+    4545, 4545, 6529, 6529, 7041, 6657, 8194, 5633, 4993, 5761, 11137, 4609,
+    6913, 2049, 4737, 4481, 5954, 29826, 11201, 1089, 4481, 2049, 4417, 2369,
+    11137, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 2305,
+    1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 48450, 49538, 335362, 49666,
+    514882, 49794, 515010, 49026, 4488450, 48579, 4488579, 4488771, 4488963, 4489155, 4489347, 4489539,
+    4489731, 446467, 2755, 2563, 4508163, 4494851, 1089, 7105, 7873, 24706, 25218, 6657,
+    4353, 16002, 26563, 1156162, 3777, 4545, 7041, 6529, 22914, 7041, 5505, 6657,
+    8194, 6657, 4545, 6785, 23810, 1985, 4508354, 7425, 11201, 11201, 10945, 5633,
+    24066, 4993, 5761, 11137, 4609, 2049, 6913, 2369, 4417, 5121, 4737, 4417,
+    1473, 46082, 4417, 1985, 1985, 7105, 7105, 4993, 5121, 5121, 6913, 7041,
+    5121, 4737, 5633, 5761, 11137, 4609, 4609, 4609, 6657, 12289, 12289, 7873,
+    6657, 6657, 6657, 6529, 8194, 6785, 6785, 6785, 2305, 1857, 1921, 12929,
+    8641, 48450, 49538, 335362, 48579, 55298, 4508482, 1985, 7105, 4993, 5121, 6913,
+    7041, 5121, 4737, 5633, 5761, 11137, 4609, 6657, 12289, 7873, 6657, 6529,
+    8194, 6785, 7425, 5761, 8194, 2305, 48450, 49538, 48579, 2241, 12673, 2177,
+    219394, 12353, 1985, 7105, 4993, 6913, 7041, 3777, 4737, 5633, 4545, 11137,
+    4609, 6657, 7873, 4353, 6529, 8194, 6785, 5761, 2241, 12673, 14081, 1985,
+    4508610, 7105, 4993, 4545, 5121, 6913, 4508738, 7041, 5121, 4737, 5633, 5761,
+    11137, 4609, 6657, 12289, 7873, 6529, 8194, 6785, 2305, 1857, 1921, 12929,
+    48450, 49538, 48579, 1985, 7105, 4993, 4545, 5121, 6913, 7041, 5121, 6785,
+    4737, 5633, 5761, 11137, 4609, 6657, 12289, 7873, 6657, 10945, 6529, 8194,
+    6785, 1042946, 11137, 11137, 5121, 5121, 5121, 1089, 1089, 4481, 4481, 2049,
+    2049, 6529, 6529, 5761, 5761, 4417, 41986, 2369, 42242, 5633, 14850, 4993,
+    27778, 4609, 4353, 16002, 5505, 22658, 4609, 6785, 4802, 4545, 34434, 4609,
+    6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737, 6529,
+    5761, 11201, 6657, 6657, 6657, 5121, 5761, 5761, 6529, 4609, 1089, 1089,
+    4481, 4481, 2049, 2049, 6529, 6529, 5761, 5761, 4417, 41986, 2369, 42242,
+    0, 2241, 12673, 12673, 12545, 1473, 12737, 2177, 2305, 1857, 1921, 12929,
+    8641, 7233, 35009, 35073, 35137, 48450, 49538, 335362, 49666, 514882, 49794, 515010,
+    49026, 4488450, 48579, 48772, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009,
+    35073, 35137, 4417, 2369, 4417, 2369, 5761, 2177, 11137, 11137, 5121, 1089,
+    1089, 4481, 4481, 2049, 2049, 4417, 41986, 2369, 42242, 5633, 14850, 4993,
+    27778, 4609, 4353, 16002, 5505, 22658, 4609, 6785, 4802, 4545, 6529, 34434,
+    31554, 4609, 6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137,
+    4737, 6529, 5761, 11201, 6657, 6657, 6657, 5121, 1089, 4481, 4481, 2049,
+    2049, 4417, 41986, 2369, 42242, 0, 1985, 12673, 32833, 32833, 2241, 12673,
+    2241, 12673, 6529, 32833, 6657, 6785, 7105, 4353, 4545, 4993, 11137, 4609,
+    5761, 4609, 11201, 7873, 4737, 6529, 5121, 5633, 5505, 4609, 1089, 4417,
+    4481, 2049, 2369, 4417, 3777, 34945, 2305, 1857, 1921, 12929, 8641, 7233,
+    35009, 35073, 35137, 11137, 11137, 5121, 1089, 4481, 2049, 4417, 5633, 14850,
+    4993, 27778, 4609, 4353, 16002, 5505, 22658, 4609, 6785, 4802, 4545, 34434,
+    4609, 6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737,
+    4737, 6529, 5761, 6913, 6657, 5121, 1089, 4481, 4481, 2049, 2049, 4417,
+    41986, 2369, 42242, 7746, 2369, 42242, 0, 0, 34945, 2305, 1857, 1921,
+    12929, 8641, 7233, 35009, 35073, 35137, 2177, 2241, 12673, 3137, 26882, 1089,
+    52546, 11201, 1089, 4481, 2049, 4417, 2369, 5633, 14850, 4993, 27778, 4353,
+    16002, 5505, 22658, 4609, 6785, 4802, 4545, 34434, 4609, 6785, 4802, 4545,
+    34434, 4609, 7873, 14722, 7105, 35970, 11137, 6529, 5761, 11201, 6657, 5121,
+    6529, 1985, 12673, 2177, 4508867, 11137, 11137, 5121, 1089, 1089, 4481, 4481,
+    2049, 2049, 6529, 6529, 5761, 5761, 4417, 41986, 2369, 42242, 5633, 14850,
+    4993, 27778, 4609, 4353, 16002, 5505, 22658, 4609, 6785, 4802, 4545, 34434,
+    4609, 6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737,
+    6529, 5761, 5761, 11201, 6657, 6657, 6657, 5121, 1089, 4481, 4481, 2049,
+    2049, 6529, 6529, 5761, 5761, 4417, 41986, 2369, 42242, 1, 1985, 5121,
+    5121, 46722, 2241, 12673, 12673, 1, 12545, 1985, 0, 0, 2177, 4417,
+    11137, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 2177,
+    1473, 1473, 12801, 10881, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073,
+    35137, 48450, 49538, 335362, 49666, 514882, 49794, 515010, 49026, 4488450, 48579, 48772,
+    1089, 1089, 4481, 2049, 4417, 41986, 2369, 42242, 5633, 14850, 4993, 4993,
+    27778, 4609, 4353, 16002, 5505, 5505, 4609, 6785, 4802, 4545, 34434, 4609,
+    6785, 4802, 4545, 4545, 34434, 4609, 7873, 14722, 7105, 7105, 35970, 11137,
+    4737, 6529, 5761, 11201, 6657, 5121, 6529, 1089, 4481, 4481, 2049, 4417,
+    41986, 2369, 42242, 11137, 1, 1985, 0, 2241, 12673, 1, 2241, 12673,
+    12673, 0, 10945, 4481, 6529, 1089, 4481, 2049, 4417, 5633, 14850, 4993,
+    27778, 4353, 16002, 5505, 5505, 4609, 6785, 4802, 4545, 4545, 34434, 4609,
+    6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737, 6529,
+    5761, 11201, 6657, 5121, 6529, 31554, 12673, 1089, 1089, 4481, 4481, 2049,
+    2049, 4417, 41986, 2369, 42242, 5633, 14850, 4993, 4993, 27778, 4609, 4353,
+    16002, 5505, 5505, 22658, 4609, 6785, 4802, 4545, 4545, 6529, 34434, 4609,
+    6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 7105, 35970, 11137, 4737,
+    6529, 5761, 11201, 6657, 6657, 5121, 11137, 1089, 4481, 4481, 2049, 2049,
+    4417, 41986, 2369, 42242, 1985, 0, 34945, 2305, 1857, 1921, 12929, 8641,
+    7233, 35009, 35073, 35137, 11137, 11137, 11137, 5121, 1089, 1089, 4481, 4481,
+    2049, 2049, 6529, 5761, 4417, 41986, 2369, 42242, 5633, 14850, 4993, 27778,
+    4609, 4353, 16002, 5505, 22658, 4609, 6785, 4802, 4545, 34434, 4609, 6785,
+    4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737, 6529, 5761,
+    5761, 11201, 4545, 4545, 4545, 5121, 1985, 1985, 1985, 1089, 4481, 4481,
+    2049, 2049, 6529, 6529, 4417, 41986, 2369, 42242, 1, 46722, 2049, 12289,
+    11137, 11137, 6529, 5761, 5761, 5761, 34945, 2305, 1857, 1921, 12929, 8641,
+    7233, 1089, 5633, 4609, 11201, 7873, 1089, 1089, 4481, 4481, 2049, 2049,
+    6529, 6529, 5761, 5761, 4417, 41986, 2369, 42242, 5633, 14850, 4993, 27778,
+    4609, 52802, 4353, 16002, 5505, 22658, 4609, 52802, 6785, 4802, 4545, 34434,
+    4609, 6785, 4802, 4545, 34434, 4609, 52802, 7873, 14722, 7105, 35970, 11137,
+    52930, 4737, 6529, 4509058, 5761, 85634, 11201, 6657, 6657, 6657, 5121, 1089,
+    4481, 4481, 2049, 2049, 6529, 6529, 5761, 5761, 4417, 41986, 2369, 42242,
+    0, 11137, 11137, 5121, 1985, 1985, 11137, 46722, 2177, 2241, 12673, 2241,
+    1473, 12673, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137,
+    12673, 1473, 10881, 1985, 11137, 5121, 5121, 2177, 1089, 1089, 4481, 4481,
+    2049, 2049, 6529, 6529, 5761, 5761, 4417, 41986, 2369, 42242, 5633, 14850,
+    4993, 27778, 4609, 4353, 16002, 5505, 22658, 4609, 6785, 4802, 4545, 34434,
+    4609, 6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737,
+    6529, 5761, 11201, 6657, 6657, 6657, 5121, 1089, 4481, 4481, 2049, 2049,
+    6529, 6529, 5761, 5761, 4417, 4417, 41986, 2369, 2369, 42242, 11137, 11137,
+    5121, 0, 1985, 1985, 11137, 12673, 46722, 34945, 2305, 1857, 1921, 12929,
+    8641, 7233, 35009, 35073, 35137, 1089, 1089, 4481, 4481, 2049, 2049, 6529,
+    6529, 5761, 5761, 4417, 41986, 2369, 42242, 5633, 14850, 4993, 27778, 4609,
+    4353, 16002, 5505, 22658, 4609, 6785, 4802, 4545, 34434, 4609, 6785, 4802,
+    4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737, 6529, 5761, 11201,
+    6657, 6657, 6657, 5121, 1089, 4481, 4481, 2049, 2049, 6529, 6529, 4417,
+    41986, 2369, 42242, 11137, 11137, 5121, 0, 1985, 2177, 2241, 12673, 1473,
+    1473, 1473, 1473, 1473, 32833, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 4481, 4481, 4481, 2049, 2049,
+    2049, 1089, 1089, 4481, 4481, 2049, 2049, 6529, 6529, 5761, 5761, 4417,
+    41986, 2369, 42242, 5633, 14850, 4993, 27778, 4609, 4353, 16002, 5505, 22658,
+    4609, 6785, 4802, 4545, 34434, 4609, 6785, 4802, 4545, 34434, 4609, 7873,
+    14722, 7105, 35970, 11137, 4737, 6529, 5761, 11201, 6657, 6657, 6657, 5121,
+    5761, 1089, 4481, 4481, 2049, 2049, 6529, 6529, 5761, 5761, 4417, 41986,
+    2369, 42242, 11137, 5121, 0, 1985, 2241, 12673, 12673, 2177, 34945, 2305,
+    1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 7297, 7297, 7297, 7297,
+    7297, 7297, 7297, 7297, 7297, 7297, 7297, 7297, 7297, 1089, 1089, 4481,
+    4481, 2049, 2049, 4417, 41986, 2369, 42242, 5633, 6657, 4993, 27778, 4609,
+    4353, 16002, 5505, 22658, 4609, 6785, 4802, 4545, 34434, 4609, 6785, 4802,
+    4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737, 6529, 5761, 11201,
+    6657, 6657, 5121, 6529, 11137, 5121, 1089, 4481, 4481, 2049, 2049, 4417,
+    41986, 2369, 42242, 1, 1985, 14850, 12673, 34945, 2305, 1857, 1921, 12929,
+    8641, 7233, 35009, 35073, 35137, 5633, 14850, 5954, 4609, 6785, 6785, 7873,
+    14722, 7105, 11137, 5505, 16002, 4802, 6529, 5761, 6657, 29826, 5121, 4545,
+    34434, 4993, 4993, 27778, 35970, 22658, 7105, 5761, 6529, 6529, 1089, 1089,
+    4481, 4481, 2049, 2049, 4417, 220930, 2369, 41986, 11137, 0, 34945, 2305,
+    1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 48450, 49538, 2241, 12673,
+    2177, 65, 4353, 6785, 4802, 4545, 34434, 4609, 5761, 1089, 1089, 4481,
+    4481, 2049, 2049, 4417, 41986, 2369, 42242, 5633, 14850, 4993, 27778, 4609,
+    4353, 16002, 5505, 22658, 4609, 6785, 4802, 4545, 34434, 4609, 6785, 4802,
+    4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737, 6529, 5761, 11201,
+    6657, 6657, 6657, 5121, 6529, 1089, 4481, 4481, 2049, 2049, 6529, 6529,
+    4417, 41986, 2369, 42242, 11137, 5121, 1985, 12673, 14145, 3201, 3521, 3841,
+    1985, 3905, 3521, 3841, 3457, 3713, 3649, 4929, 5569, 3649, 5441, 3393,
+    3649, 3585, 6721, 3649, 3585, 6721, 14145, 7169, 2113, 5057, 5697, 6465,
+    6465, 6593, 6593, 8321, 11137, 1089, 4481, 2049, 1985, 4737, 4481, 2049,
+    4417, 2369, 4609, 4993, 5633, 4609, 5505, 4353, 4609, 4545, 6785, 4609,
+    4545, 6785, 11137, 7105, 7873, 5121, 5761, 6529, 6529, 6657, 6657, 11201,
+    34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 48450, 49538,
+    335362, 49666, 514882, 49794, 515010, 49026, 4488450, 46722, 1089, 1089, 4481, 4481,
+    2049, 2049, 4417, 2369, 5633, 14850, 4993, 27778, 4609, 4353, 16002, 5505,
+    4609, 6785, 4545, 34434, 4609, 6785, 4802, 4545, 34434, 4609, 7873, 14722,
+    7105, 35970, 11137, 1985, 4737, 6529, 5761, 11201, 6657, 6657, 6657, 5121,
+    5761, 7041, 1089, 4481, 4481, 2049, 2049, 4417, 41986, 2369, 11137, 11137,
+    4609, 4737, 6529, 6529, 1985, 12673, 1473, 2177, 34945, 2305, 1857, 1921,
+    12929, 8641, 7233, 35009, 35073, 35137, 1089, 1089, 4481, 4481, 2049, 2049,
+    6529, 6529, 4417, 41986, 2369, 42242, 5633, 14850, 4993, 27778, 4609, 4353,
+    16002, 5505, 22658, 4609, 6785, 4802, 4545, 34434, 4609, 6785, 4802, 4545,
+    34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737, 6529, 5761, 11201, 6657,
+    6657, 6657, 5121, 5761, 6529, 1089, 4481, 4481, 2049, 2049, 6529, 6529,
+    4417, 41986, 2369, 42242, 11137, 5121, 1985, 2177, 12801, 4417, 4481, 2049,
+    2049, 4417, 2369, 2369, 41986, 42242, 4481, 0, 5633, 14850, 4993, 27778,
+    5954, 4353, 16002, 5505, 29826, 6785, 4802, 4545, 34434, 4609, 6785, 4802,
+    4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 8706, 19971, 9346, 20291,
+    8514, 7041, 1985, 4737, 6529, 5761, 11201, 8194, 6657, 6657, 5121, 20482,
+    0, 0, 11137, 11137, 11137, 11137, 5121, 6529, 4737, 6529, 5761, 11201,
+    7297, 32833, 1473, 2241, 12673, 12545, 7297, 32833, 0, 4481, 2049, 2049,
+    4417, 2369, 2369, 41986, 42242, 6529, 5761, 0, 5633, 14850, 4993, 27778,
+    5954, 4353, 16002, 5505, 22658, 29826, 6785, 4802, 4545, 34434, 4609, 6785,
+    4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 8706, 19971, 9346,
+    8514, 7041, 1985, 4737, 6529, 5761, 11201, 8194, 6657, 6657, 5121, 20482,
+    5121, 5121, 6529, 5761, 8194, 6657, 4993, 5633, 5954, 4545, 4609, 7105,
+    11137, 6529, 5761, 8194, 6657, 1985, 11137, 5121, 0, 0, 1473, 2241,
+    12673, 12289, 7297, 7297, 7297, 32833, 32833, 86466, 178307, 86338, 4509187, 86210,
+    86595, 4509380, 4509637, 4509956, 4510213, 4510532, 4510789, 4511107, 4511299, 4511491, 4511683, 7873,
+    5633, 5761, 11137, 4545, 7041, 11201, 5954, 5121, 4993, 14850, 6657, 7105,
+    4353, 6785, 4802, 4609, 14722, 6529, 7425, 16002, 1089, 4417, 4481, 2369,
+    2049, 201026, 19202, 7873, 5633, 6785, 11137, 4609, 5761, 6913, 5954, 4737,
+    1985, 1985, 1985, 1985, 1985, 32833, 32833, 8641, 7233, 35073, 35137, 8641,
+    35137, 35137, 34945, 1089, 1089, 4481, 4481, 2049, 2049, 6529, 6529, 5761,
+    4417, 41986, 2369, 42242, 5633, 14850, 4993, 27778, 4609, 4353, 16002, 5505,
+    22658, 4609, 6785, 4802, 4545, 34434, 4609, 6785, 4802, 4545, 34434, 4609,
+    7873, 14722, 7105, 35970, 11137, 4737, 6529, 5761, 11201, 6657, 6657, 6657,
+    5121, 1089, 4481, 4481, 2049, 2049, 6529, 6529, 5761, 4417, 41986, 2369,
+    42242, 11137, 11137, 5121, 0, 1985, 2241, 12673, 1, 1473, 10881, 34945,
+    2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 2305, 1857, 1921,
+    12929, 8641, 7233, 35009, 35073, 35137, 48450, 49538, 335362, 49666, 514882, 49794,
+    515010, 49026, 4488450, 2177, 7297, 2241, 5633, 14850, 4993, 5954, 4353, 16002,
+    5505, 29826, 6785, 4802, 4545, 4609, 7873, 14722, 7105, 11137, 8706, 19971,
+    9346, 6913, 8514, 7041, 1985, 4737, 6529, 5761, 8194, 6657, 5121, 5633,
+    14850, 4993, 5954, 4353, 16002, 5505, 29826, 6785, 4802, 4545, 4609, 7873,
+    14722, 7105, 11137, 8706, 19971, 9346, 6913, 8514, 7041, 4737, 6529, 5761,
+    8194, 6657, 5121, 0, 0, 4481, 2049, 4417, 2369, 11137, 11137, 1089,
+    1089, 4481, 4481, 2049, 2049, 4417, 41986, 2369, 42242, 5633, 14850, 4993,
+    27778, 4609, 4353, 16002, 5505, 22658, 4609, 6785, 4802, 4545, 34434, 4609,
+    6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737, 6529,
+    5761, 11201, 6657, 6657, 6657, 5121, 5761, 20482, 211778, 211906, 1089, 4481,
+    4481, 2049, 2049, 6529, 4417, 41986, 2369, 42242, 11137, 5121, 1985, 1985,
+    0, 0, 6529, 6529, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009,
+    35073, 35137, 1089, 1089, 4481, 4481, 2049, 2049, 4417, 41986, 2369, 42242,
+    4737, 11201, 7105, 35970, 11137, 5633, 14850, 6785, 4802, 5761, 4993, 27778,
+    4545, 34434, 4609, 4353, 16002, 6785, 4802, 5761, 5505, 22658, 4545, 34434,
+    4609, 7873, 14722, 5121, 6529, 6657, 1089, 4481, 4481, 2049, 2049, 4417,
+    41986, 2369, 42242, 11137, 5121, 46722, 34945, 2305, 1857, 1921, 12929, 8641,
+    7233, 35009, 35073, 35137, 5633, 4993, 5954, 7873, 7105, 11137, 6785, 4545,
+    4609, 4353, 5505, 29826, 4737, 6529, 5761, 6913, 6657, 1473, 4481, 2049,
+    4417, 2369, 2241, 12673, 11137, 5954, 6529, 5121, 1089, 1089, 4481, 4481,
+    2049, 2049, 94082, 94210, 88194, 88322, 4417, 41986, 2369, 5633, 14850, 4993,
+    27778, 5954, 4353, 16002, 5505, 22658, 29826, 6785, 4802, 4545, 34434, 4609,
+    6785, 4802, 4545, 34434, 4609, 7873, 14722, 7105, 35970, 11137, 4737, 6529,
+    5761, 6913, 6657, 6657, 6657, 5121, 50307, 1089, 1089, 4481, 4481, 2049,
+    2049, 94082, 4417, 41986, 4417, 2241, 12673, 32833, 32833, 2177, 1473, 2177,
+    2177, 7297, 7297, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073,
+    35137, 37762, 2305, 1857, 12929, 8641, 35073, 48450, 47810, 264002, 47938, 49538,
+    49538, 48066, 49666, 48194, 49794, 48322, 49026, 49155, 49155, 49347, 4673, 3649,
+    3393, 3201, 3841, 14145, 5569, 2113, 14145, 5569, 2113, 2113, 8321, 2113,
+    5569, 8321, 3649, 2113, 3649, 3841, 8321, 32833, 3649, 6593, 2113, 3201,
+    8321, 14145, 14145, 8321, 12801, 6785, 1857, 1921, 12929, 8641, 7233, 35009,
+    35073, 35137, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 12929, 8641, 7233,
+    35009, 35073, 35137, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137,
+    2305, 1857, 1921, 12929, 8641, 1857, 1921, 1921, 12929, 8641, 7233, 35009,
+    35073, 35137, 2305, 1857, 1921, 1921, 12929, 8641, 4511878, 4512262, 2305, 1857,
+    1921, 1921, 12929, 8641, 1921, 1921, 12929, 12929, 12929, 12929, 7233, 35009,
+    35009, 35009, 35073, 35073, 35137, 35137, 35137, 35137, 1857, 1921, 12929, 8641,
+    7233, 2305, 1857, 1921, 12929, 12929, 8641, 8641, 1857, 1921, 2305, 1857,
+    4508163, 4494851, 4512643, 4508163, 4494851, 4512835, 2563, 4513027, 2563, 2563, 2755, 4508163,
+    4494851, 49666, 514882, 12929, 8641, 7233, 35009, 35073, 35137, 641, 12545, 12481,
+    12481, 12481, 2305, 1857, 12929, 8641, 7233, 35009, 35073, 35137, 48450, 263618,
+    47810, 4513219, 263746, 264002, 199682, 199938, 515330, 515586, 515714, 515842, 515970, 516098,
+    516226, 516354, 335362, 516610, 516738, 516866, 523586, 523714, 523842, 523970, 49666, 524098,
+    524482, 524738, 524866, 524994, 514882, 4513410, 4513538, 4513666, 4513794, 4513922, 4514050, 4514178,
+    4514306, 49794, 4514434, 4514562, 4514690, 48322, 4514818, 4514946, 4515074, 4515202, 515010, 4515330,
+    4515458, 4515586, 4515714, 4515842, 4515971, 4516162, 4516290, 4516418, 49026, 4516546, 4516674, 4516802,
+    4516930, 4517058, 4517186, 4517314, 215746, 4517442, 4488450, 4517570, 4517698, 4517826, 4517954, 4518082,
+    4518210, 4518338, 4518466, 48579, 4518595, 4518787, 4518979, 4519171, 4519363, 4519555, 4519747, 4519939,
+    4520131, 4520323, 4520515, 4520707, 4520899, 4521091, 4521282, 4521411, 64642, 4521604, 4521859, 4522051,
+    4522243, 4522436, 4522691, 4522885, 4523204, 4523460, 4523715, 4523907, 4524100, 4524354, 4524482, 4524610,
+    4524739, 4498306, 4524931, 4525124, 4525379, 6529, 4525571, 4525764, 4526019, 564930, 4526211, 1089,
+    4526403, 4545, 4526595, 4526786, 4526915, 7105, 3126274, 3179395, 4527107, 4527300, 4527555, 4527747,
+    4527938, 4528067, 4528258, 4528387, 4528579, 4528771, 4528962, 10626, 4529090, 66178, 4529219, 579778,
+    4529411, 4529603, 4529794, 4529923, 4530114, 84418, 12865, 64514, 3244738, 4530243, 4530435, 4530628,
+    4497282, 4530883, 4531075, 4531267, 3201, 4531458, 4531587, 4531779, 4531972, 11137, 569410, 4532226,
+    4532354, 4532484, 4532738, 4532867, 4533058, 4533186, 4533316, 4533570, 4533698, 4533827, 39042, 4534018,
+    564418, 4534146, 4534275, 6913, 1177922, 4534467, 4534658, 4534787, 4534979, 4535171, 40770, 4535362,
+    4535491, 4535684, 4535939, 4536131, 4536323, 569666, 4536515, 7425, 3585, 4536706, 34818, 3124674,
+    4536834, 4458626, 4536964, 4537219, 908675, 4537412, 4537667, 4537858, 4537986, 4538115, 211906, 4538306,
+    4538435, 4538627, 4538819, 4539010, 4481, 3905, 38274, 4539139, 64770, 42370, 168002, 4539331,
+    4539523, 4539715, 4539907, 4540099, 226178, 4540290, 40130, 4540419, 4540610, 4540741, 4541059, 4541251,
+    4541443, 4541636, 4541890, 4542018, 4542147, 4542340, 4542596, 1166722, 4542851, 107458, 10945, 4543043,
+    4609, 4543234, 6593, 226626, 3243010, 4543362, 5121, 4543491, 4543682, 4543812, 4544067, 4544258,
+    4544387, 4544579, 4544770, 4544900, 7041, 4432066, 4545155, 4545348, 4545603, 4545795, 4545987, 4546180,
+    4546434, 4546564, 4546819, 4547011, 4547203, 4547395, 15362, 4547586, 7873, 4547716, 4547972, 4548227,
+    4548418, 4548548, 4548803, 62210, 36546, 4548996, 4549251, 4549442, 4549570, 4549699, 4549892, 4550149,
+    4550468, 4550723, 4550916, 4551171, 4551363, 4551555, 4551747, 4551940, 4552196, 4552451, 4552642, 4552771,
+    1173570, 4552965, 4553284, 6657, 4553538, 4553667, 4553858, 4553987, 4554179, 4554370, 4554499, 4554691,
+    4554883, 4555075, 4555267, 1180418, 4555459, 4555652, 4555906, 4556035, 4556226, 4556354, 4556483, 4556675,
+    4556866, 4556995, 4557188, 4557443, 4557634, 67074, 64386, 4557762, 4557891, 4558082, 4558211, 4558403,
+    569154, 564802, 4558594, 906690, 4558724, 85762, 229506, 4558979, 4559171, 4559363, 4559555, 4559746,
+    4559875, 4560067, 4560258, 4560387, 4560579, 16258, 4560771, 4560962, 4561092, 4561347, 4561540, 4561794,
+    4561923, 221058, 4562114, 4562243, 6721, 4562435, 4562627, 4562818, 4562946, 4563074, 4563202, 5057,
+    4563331, 61314, 5633, 4563523, 4563715, 4563907, 4564099, 4564290, 4564419, 4564611, 4993, 567490,
+    4564803, 90626, 4564996, 4565250, 4565379, 6785, 4565571, 4565764, 4566018, 4430018, 4566147, 4566340,
+    4737, 6849, 4566595, 3777, 4499458, 4566786, 4566915, 3244610, 14145, 4567106, 4567235, 4567427,
+    4567618, 4567746, 4567875, 4568068, 2177, 12737, 224833, 220033, 6785, 5954, 4737, 11137,
+    7105, 4545, 1089, 14722, 14850, 5121, 4481, 4353, 5633, 5761, 11137, 4609,
+    7873, 2369, 2369, 6529, 8194, 4802, 4417, 6913, 4417, 5633, 5761, 5761,
+    4568323, 2369, 3980163, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073,
+    35137, 2241, 12673, 229634, 3175234, 4568514, 55170, 4568642, 199170, 4533570, 907650, 4568770,
+    4568898, 4569026, 4569154, 4569282, 3157122, 4569410, 908546, 4540290, 3245570, 4569538, 238786, 4569666,
+    3204354, 4569794, 4569922, 4570051, 4570243, 4570435, 4570627, 4570819, 4571011, 4571203, 4571395, 5954,
+    4571587, 4571779, 4570627, 4571971, 4571779, 4572163, 4571587, 4572356, 4572612, 4572868, 4573124, 4573378,
+    84866, 4573506, 4573634, 5633, 14850, 4993, 5954, 6657, 4737, 6913, 7873, 29826,
+    14722, 7105, 11137, 4609, 5121, 5761, 1179010, 6785, 4545, 6529, 27138, 8194,
+    4353, 8706, 27778, 4573763, 4802, 3777, 7425, 34434, 16002, 7041, 34945, 2305,
+    1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 4609, 5633, 6657, 7425,
+    37890, 29826, 4993, 4545, 4548418, 5505, 4554370, 6913, 7041, 39042, 50946, 4353,
+    1169090, 6785, 7105, 11201, 5121, 7873, 5761, 1089, 2369, 2369, 2049, 4417,
+    4417, 4481, 0, 0, 0, 0, 0, 12673, 4573955, 4574147, 3244738, 238210,
+    4574339, 4574531, 3203394, 4574722, 3156162, 4574850, 4574979, 4575171, 4575363, 4575555, 4575747, 4575939,
+    4576131, 4576323, 3174274, 4576514, 4564611, 4576643, 3126274, 220930, 4576834, 151810, 4576963, 4577155,
+    11201, 4577347, 5761, 5121, 4428034, 6529, 4507586, 4455938, 4737, 85634, 11137, 16002,
+    4428163, 52802, 4577539, 4577731, 4609, 1985, 3777, 4353, 11137, 4545, 5505, 11201,
+    0, 6657, 4993, 3137, 65, 2241, 15105, 35201, 12737, 1473, 2177, 4673,
+    12801, 12289, 1473, 1473, 10881, 7297, 34945, 2305, 1857, 1921, 12929, 8641,
+    7233, 35009, 35073, 35137, 4577924, 4578179, 4578371, 4578564, 4578820, 4579076, 4579331, 1985,
+    4579524, 4579779, 4579779, 4579970, 4580100, 4580356, 4580612, 4580868, 1173954, 4581122, 4581250, 4581379,
+    4581571, 4581763, 4581957, 4582275, 4582466, 4582595, 4582787, 4582979, 593093, 4583172, 4583427, 4583619,
+    591877, 632196, 4583811, 4584004, 4584260, 468293, 4584515, 4584709, 588868, 4585028, 473540, 465348,
+    4585284, 4585538, 4585667, 14145, 6593, 8321, 6849, 4585860, 6977, 4586114, 2113, 6721,
+    4929, 7361, 3521, 5569, 3201, 5441, 3457, 7169, 3393, 3841, 464642, 5697,
+    15169, 5057, 24450, 12865, 3585, 3713, 3649, 6465, 3713, 615426, 3905, 11137,
+    6657, 11201, 6913, 4586244, 7041, 4548418, 7873, 6785, 4993, 7425, 4481, 5633,
+    1089, 5505, 4417, 7105, 4353, 2049, 37378, 5761, 10945, 5121, 5954, 3777,
+    4545, 2369, 4609, 6529, 2369, 41986, 4737, 34945, 2305, 1857, 1921, 12929,
+    8641, 7233, 35009, 35073, 35137, 48450, 263618, 47810, 263746, 263874, 264002, 47938,
+    199682, 199810, 199938, 2305, 1857, 1921, 2241, 12673, 4586500, 65, 7105, 7105,
+    7105, 84994, 11137, 11137, 11137, 7425, 11201, 11201, 4545, 4545, 4545, 4545,
+    4461826, 4461826, 4609, 4609, 4609, 4609, 40770, 40770, 5761, 5761, 85634, 85634,
+    66306, 66306, 190979, 190979, 4993, 4993, 4993, 27778, 27778, 1179395, 1179395, 1179395,
+    5121, 4586754, 4586754, 4586754, 5505, 5505, 109698, 109698, 8514, 8514, 8514, 8514,
+    5505, 8194, 3777, 6529, 6529, 7041, 7041, 7041, 6657, 6529, 6529, 4737,
+    4737, 4737, 19842, 19842, 6913, 84994, 8194, 5505, 4545, 7041, 4461826, 12289,
+    4609, 1985, 12289, 1985, 1089, 42242, 3127362, 34562, 3126915, 2369, 38914, 119170,
+    6913, 4417, 41602, 3156803, 67970, 4481, 19202, 4586883, 4587076, 19458, 19330, 38274,
+    19074, 3245379, 2049, 201026, 4587331, 4587524, 41730, 212675, 3204035, 37378, 19842, 4417,
+    4417, 37378, 37378, 4481, 4481, 41986, 52546, 51906, 10498, 4609, 5954, 4587779,
+    4587971, 3171650, 4497922, 6913, 3242114, 1179586, 4588164, 212354, 3777, 7105, 4545, 6657,
+    3777, 7105, 5761, 6785, 6657, 5633, 7425, 7105, 3777, 5761, 6785, 6657,
+    7425, 1473, 1473, 2241, 1473, 124930, 94530, 4588420, 97027, 4588676, 4588931, 4589124,
+    1246787, 4589380, 114242, 4588420, 136963, 4588420, 187203, 4589635, 4589827, 127746, 88194, 4590020,
+    96835, 4588420, 4588931, 88322, 4590276, 4590532, 4590787, 97027, 187203, 4588676, 4590979, 105922,
+    109698, 4590787, 4588420, 97027, 4591171, 4588676, 4591363, 3137, 124802, 4591556, 124802, 87682,
+    136963, 4591812, 4591556, 4591812, 4590532, 4468291, 136963, 4592069, 1088580, 3137, 91138, 4592388,
+    186371, 105794, 120770, 4592643, 4592838, 113474, 4591363, 4593221, 87682, 114370, 104706, 4593540,
+    113602, 91266, 87426, 103490, 200706, 106051, 136771, 102722, 105154, 4593795, 102850, 127234,
+    114370, 91266, 200706, 200706, 4452930, 105154, 105154, 124930, 91138, 4589635, 4593987, 4593987,
+    87426, 4590979, 4594179, 4588420, 113602, 4594371, 136067, 183043, 4594563, 4594563, 135235, 103490,
+    4591363, 102978, 4092867, 4591812, 219266, 4592388, 4594755, 4594948, 4460995, 3137, 4417, 91138,
+    1089, 200706, 4417, 136771, 187395, 94786, 132227, 4595203, 191363, 103490, 4595396, 105154,
+    105154, 114370, 4460995, 95939, 4595653, 4595972, 4596227, 121282, 115587, 1081667, 104194, 128003,
+    4596419, 4497794, 182147, 4596613, 2049, 4596933, 4597252, 4597252, 4591363, 94530, 91138, 4597508,
+    4460995, 4597763, 135683, 4417, 913858, 4597956, 127234, 91138, 4598211, 549827, 919747, 124290,
+    104194, 102722, 110210, 4498306, 191363, 135235, 1109699, 4598404, 128322, 4497666, 135683, 4593987,
+    4598659, 87682, 200706, 4598852, 1088580, 1088580, 135683, 1110723, 155203, 102978, 4468291, 4468291,
+    1824707, 4417, 4417, 91266, 105282, 4599108, 135683, 4460995, 4599363, 4599555, 4599747, 4497666,
+    87426, 4599939, 128962, 136963, 4600131, 4600131, 181699, 4497922, 114370, 3137, 4591363, 200706,
+    4591363, 4593221, 122115, 90498, 102850, 2369, 3286531, 110210, 4600323, 87682, 102850, 41026,
+    41026, 110210, 142274, 96131, 4600515, 90626, 41026, 36226, 88194, 2369, 128962, 4600515,
+    4600707, 87682, 2049, 3310403, 36226, 86978, 102850, 3286531, 4600899, 186371, 142274, 90626,
+    4460995, 37250, 94530, 200706, 29826, 41026, 4598211, 29826, 122115, 3137, 198468, 4601092,
+    4601349, 124290, 4601668, 4601924, 3137, 170820, 120770, 120770, 122115, 4602180, 4601668, 87682,
+    4602437, 134339, 4602757, 170820, 40898, 4603077, 96835, 127234, 135043, 4602437, 135428, 4602180,
+    4603395, 104706, 3930755, 955522, 4588676, 4588676, 41026, 200706, 114370, 4603587, 191363, 127746,
+    200706, 87554, 102978, 109570, 4603779, 4598852, 4594563, 128194, 91138, 4590787, 142914, 3137,
+    90370, 155203, 4603973, 4592069, 947458, 4588420, 142914, 102850, 4604291, 87426, 4604484, 95298,
+    87554, 1109699, 2049, 4604739, 4604931, 87426, 32130, 1746947, 3975555, 3975555, 1609667, 182147,
+    90626, 4605124, 4605380, 4605636, 87426, 87426, 91138, 4605892, 110210, 109442, 87682, 120770,
+    91138, 127234, 91138, 103490, 4606147, 4606340, 103490, 4468291, 4606596, 1005955, 4605636, 4606851,
+    1109699, 4607043, 105282, 2049, 128322, 91138, 4607236, 115587, 4607492, 4593540, 4607749, 124162,
+    41026, 4605380, 3975555, 3975555, 128962, 88194, 91138, 4608067, 4608258, 4608387, 1089, 4608580,
+    4608580, 105922, 4591363, 110338, 193027, 2049, 2760643, 136771, 142274, 142274, 3137, 3891716,
+    4608837, 128450, 114370, 113474, 4609156, 102850, 4603779, 4595396, 134147, 88194, 128194, 4600323,
+    91138, 113474, 124290, 4604739, 114242, 4460995, 182147, 192643, 94530, 4609412, 1110723, 91138,
+    90626, 102850, 2049, 4605636, 4417, 4609668, 4609924, 124290, 4610179, 91138, 4590787, 4417,
+    1000707, 4599747, 4610371, 4610564, 4608580, 87426, 132227, 193475, 4417, 87426, 87426, 4604739,
+    87426, 102850, 4417, 4607236, 1089, 32130, 4610820, 128962, 4611076, 4590787, 4604484, 4611331,
+    185731, 90626, 4610820, 4611524, 185731, 4609924, 4609924, 133315, 4596227, 87554, 4611780, 104706,
+    88194, 124290, 86210, 95747, 95747, 200706, 87426, 1088580, 4612035, 4611780, 4612228, 1105603,
+    105794, 4612483, 4612483, 4612676, 4460995, 102978, 4606147, 113474, 114370, 138884, 3137, 87426,
+    4460995, 90370, 4600323, 124290, 124290, 4612932, 113474, 1110723, 124034, 90370, 181699, 87554,
+    186563, 4613188, 4613444, 135043, 113474, 1042946, 4609668, 87554, 1586179, 1252676, 192643, 1770627,
+    124802, 200706, 104066, 113474, 94658, 29826, 88194, 4595396, 87426, 3246659, 109698, 122115,
+    4613700, 124802, 137411, 3137, 124802, 947458, 193475, 4607492, 88194, 113474, 122115, 3137,
+    124290, 4387588, 134147, 128322, 128322, 113474, 4613956, 4614212, 105794, 193475, 4614468, 4614724,
+    4607492, 95939, 127746, 183043, 96835, 88322, 2369, 1089, 164675, 1089, 4609924, 4614980,
+    4614980, 4596419, 4604739, 4609924, 2369, 4603587, 202434, 219266, 4607749, 4615237, 94786, 109442,
+    4615556, 142274, 142402, 4615812, 4616068, 4596933, 4481, 184323, 105794, 126978, 137411, 142402,
+    137411, 137411, 127746, 211587, 4616324, 142274, 97220, 103490, 4616580, 4616324, 4616836, 3137,
+    136067, 4615556, 1088580, 4596613, 90626, 4610820, 124290, 132419, 122115, 193027, 96131, 183491,
+    1089, 134147, 4617091, 97220, 136067, 4610820, 130307, 3137, 4596613, 1088580, 129090, 4617285,
+    186755, 130307, 4617604, 4610820, 113474, 4610820, 4617860, 4592069, 114691, 4610820, 104194, 947458,
+    4618116, 549827, 102850, 95747, 4595396, 184515, 219266, 4611524, 4603587, 183491, 109442, 4616836,
+    946883, 3975555, 124290, 110338, 94530, 185539, 110338, 88322, 137795, 109570, 114883, 1770627,
+    109698, 1043523, 4618373, 142146, 947458, 102722, 1105603, 2369, 191363, 183875, 4387588, 109698,
+    4618693, 121923, 126978, 128450, 95426, 128962, 86210, 200706, 113602, 133955, 947458, 36226,
+    4619014, 4452930, 549827, 4600323, 109442, 113602, 95298, 87426, 187395, 87554, 192835, 3137,
+    4602437, 95298, 4618116, 191171, 4606147, 110338, 165315, 86210, 97027, 124034, 913858, 4593987,
+    4619396, 1087811, 124802, 170820, 94786, 94658, 4604931, 4619653, 183491, 4588676, 126978, 4589635,
+    4619972, 110210, 4620228, 4620485, 4591556, 4618116, 1746947, 114242, 1043523, 197892, 132227, 113474,
+    166851, 4619653, 955522, 955522, 4604291, 137411, 4604931, 4620803, 4597763, 126978, 109442, 4608387,
+    4620996, 1043523, 4599747, 191171, 3137, 4621251, 4599747, 119170, 4591363, 136963, 124290, 170244,
+    4608837, 128962, 124290, 87682, 3842820, 3137, 94658, 4621445, 137795, 90370, 110338, 114883,
+    4613188, 4610820, 1043523, 121923, 4498050, 86210, 124290, 4621764, 4037955, 4600323, 2760643, 169988,
+    41026, 946883, 4620803, 4604291, 86210, 109442, 4622020, 124290, 4498050, 138884, 4622277, 4619972,
+    3137, 4622597, 4622915, 4622915, 128322, 4604291, 191171, 1586179, 4622020, 4623107, 95298, 109442,
+    4623300, 109442, 4623556, 128322, 128322, 3137, 142146, 136963, 4498050, 4623812, 4138051, 4599555,
+    4624068, 4624323, 113602, 4624515, 104194, 142274, 2436291, 103938, 103938, 104194, 3837123, 4620803,
+    143042, 110210, 4620803, 104194, 4497666, 200706, 36226, 4590532, 4138051, 183491, 104194, 163587,
+    32130, 110210, 4593987, 4593987, 4590020, 3837123, 37250, 94658, 4460995, 211587, 4624708, 142146,
+    94658, 4624964, 4460995, 103938, 4606596, 4595203, 4593987, 4593987, 104194, 142146, 104194, 4588931,
+    41026, 142146, 41282, 4625220, 36226, 4624964, 94658, 143042, 102722, 124034, 4460803, 191171,
+    87682, 1042371, 37250, 4596613, 29826, 87554, 4600323, 4625476, 3137, 143042, 1042946, 87426,
+    109698, 4593795, 135428, 4598852, 4598852, 4589827, 4593221, 133955, 126978, 104706, 4625733, 104706,
+    4603779, 133955, 4600515, 133955, 113730, 956099, 4605124, 104194, 956099, 119170, 41026, 4589635,
+    956099, 3310403, 187203, 114883, 91138, 41026, 946883, 124034, 4607492, 127746, 113602, 187203,
+    113602, 102850, 135683, 4626051, 113602, 113602, 135683, 947458, 4626244, 4497922, 113602, 4626500,
+    4161923, 91138, 90626, 187395, 187395, 104066, 113474, 3137, 4626756, 1115843, 90626, 104066,
+    104066, 1087811, 4626756, 211587, 187395, 16258, 104066, 3837123, 133955, 133955, 102850, 103938,
+    3837123, 4627012, 90626, 90626, 143042, 90626, 4625220, 90626, 4417, 4625220, 4627266, 4330500,
+    124290, 4588676, 4627396, 4627652, 94786, 3137, 3137, 102722, 1105603, 4595396, 110338, 124162,
+    87682, 135235, 4600515, 4589635, 4608258, 4627652, 4627909, 103490, 3219267, 142914, 121410, 2049,
+    88322, 4627652, 113602, 4627396, 4589635, 132227, 87554, 102722, 3137, 4627396, 913858, 104194,
+    87554, 4628227, 90370, 4595396, 4138051, 105794, 87682, 136771, 4628419, 127106, 90370, 136963,
+    40898, 90370, 2049, 4589635, 1115843, 4604291, 4589635, 87426, 87426, 4589635, 16258, 16258,
+    95747, 4138051, 103490, 135235, 182595, 103490, 4607043, 4138051, 184515, 103938, 114242, 919170,
+    191171, 549827, 96131, 184515, 4599939, 4627652, 142914, 134339, 191171, 186563, 124162, 4603587,
+    4598211, 184515, 4628612, 1087811, 134339, 1087811, 1087811, 1087811, 87554, 104706, 4608387, 87554,
+    110210, 96131, 96131, 4612676, 104706, 41026, 97220, 4606147, 114242, 4590787, 109698, 913858,
+    135043, 109570, 87682, 87426, 219266, 95298, 34562, 2391299, 4737, 128003, 4628869, 2886596,
+    453124, 127234, 41282, 956099, 4629188, 134147, 40898, 4452930, 90370, 4594563, 91138, 181699,
+    4612676, 114242, 87426, 219266, 132227, 4603779, 4624515, 97220, 124290, 947458, 127234, 4629445,
+    95298, 166659, 4629764, 4598659, 1043523, 128322, 4593221, 4606340, 104194, 4600515, 4600515, 3137,
+    4595972, 4595972, 135043, 4630021, 105922, 4630340, 128450, 3899524, 913858, 192643, 4604291, 36226,
+    1005955, 4595396, 113474, 4592069, 109442, 4612932, 4630595, 4630787, 105922, 4630980, 34562, 109570,
+    184515, 110210, 187395, 4588676, 87426, 4631238, 192643, 109442, 87426, 87426, 1106372, 185731,
+    4631620, 3286531, 87426, 3137, 4590020, 200706, 4616836, 41282, 129090, 4630980, 4737, 90626,
+    4588420, 4630340, 164675, 202434, 186755, 183875, 183875, 913858, 2049, 128003, 2391299, 109442,
+    208003, 114242, 4616836, 96835, 102978, 1005955, 41282, 109442, 184515, 102722, 109570, 103938,
+    4594179, 200706, 4460995, 187395, 187203, 4600515, 170244, 134339, 186371, 4631877, 114691, 128003,
+    4604739, 200706, 95747, 4618693, 1010691, 4632196, 132419, 90626, 87554, 87554, 90626, 219266,
+    128322, 452868, 113474, 88194, 41026, 4632452, 41026, 4612676, 183875, 3137, 95747, 4604931,
+    127234, 4460803, 4632707, 192643, 4632900, 4595396, 4594371, 128194, 127106, 4301315, 919170, 90370,
+    4604291, 4633156, 104706, 134147, 4608837, 124802, 4600515, 955522, 127746, 127746, 4590020, 4633411,
+    184515, 90626, 2436291, 2886596, 135875, 4600131, 164675, 90370, 3137, 109442, 105794, 103618,
+    109442, 4590787, 4497922, 88322, 4633605, 109442, 164675, 955522, 4620996, 453124, 88322, 4618693,
+    4600515, 103938, 94786, 137795, 134339, 102722, 4617091, 95426, 2049, 4633924, 4615237, 4616580,
+    2459971, 120770, 4630340, 183875, 4604931, 109570, 113474, 955522, 3137, 191171, 4634179, 4593221,
+    95747, 91266, 4594948, 4590787, 129603, 4605380, 4617091, 4600899, 4634370, 94658, 200706, 16258,
+    4606147, 114883, 193475, 4596227, 94658, 1087811, 183875, 115587, 124162, 104194, 4607749, 4630340,
+    193475, 109570, 200706, 4618693, 4588931, 4498050, 113602, 4634500, 4616324, 102722, 4497922, 87682,
+    4497666, 4630980, 4605636, 4460995, 4612228, 2369, 134339, 128322, 4589380, 4600515, 91138, 87554,
+    87554, 109570, 4590020, 3137, 4599363, 4634756, 4599363, 4635011, 1088580, 41026, 4460995, 109698,
+    124290, 133315, 4590787, 114370, 4589635, 4635203, 4590787, 90370, 105794, 103938, 4590787, 192835,
+    4452930, 186563, 4635395, 4460995, 4616068, 97220, 102978, 4453443, 4633411, 88322, 4604931, 91138,
+    1109699, 1105603, 4635590, 4594948, 88322, 182147, 184323, 4635590, 1105603, 4635972, 128003, 184515,
+    452868, 4615237, 105154, 4588676, 186563, 87554, 124034, 113602, 110210, 4598852, 192643, 4636229,
+    3137, 207555, 166851, 128962, 187395, 135683, 133507, 1105603, 4599939, 40898, 87426, 114242,
+    208003, 102850, 87554, 124034, 182595, 86210, 109442, 29826, 2918211, 208003, 94530, 94530,
+    184323, 105154, 110210, 135043, 113474, 452868, 105154, 110210, 4636548, 105282, 4636805, 4603077,
+    4481, 4600515, 87682, 4637125, 109442, 4607492, 109570, 4615237, 1089, 4612228, 118658, 191171,
+    87426, 4607492, 102850, 4599939, 124162, 4588676, 113474, 113602, 142274, 88194, 4417, 4453443,
+    4453443, 1105603, 1105603, 91266, 118658, 87554, 169988, 2918211, 128322, 124930, 452868, 113602,
+    105794, 129090, 88194, 4637444, 4637444, 32130, 113602, 113602, 4630340, 4417, 4606147, 4606147,
+    4417, 4606147, 4417, 4630340, 183043, 4637701, 4417, 88194, 4417, 183043, 4606147, 133315,
+    4599555, 4599555, 122115, 4590787, 113474, 122115, 135683, 113730, 4624708, 4604931, 4616324, 4604931,
+    135043, 4605636, 202434, 187203, 4605636, 4638021, 187203, 29826, 109698, 103938, 97220, 200706,
+    109442, 109442, 202434, 103938, 105154, 4617091, 4600515, 4605636, 109442, 105154, 128322, 4617091,
+    134147, 4629445, 4630340, 4625733, 113474, 4603077, 4608837, 4608837, 4638341, 115587, 913858, 115587,
+    86338, 4625733, 4608837, 86338, 4625733, 4608837, 4630340, 4625733, 4625733, 128194, 87554, 183043,
+    87554, 4589635, 4617285, 4617285, 4638659, 4611780, 4611780, 127746, 4638851, 127746, 4592643, 4611780,
+    87554, 95555, 132227, 4592643, 104706, 102722, 4588676, 4606147, 124162, 90498, 187203, 40898,
+    550596, 4609668, 4609668, 91138, 124930, 128322, 4590532, 4608387, 143042, 102850, 947458, 4625476,
+    4639043, 1106372, 4639235, 86210, 4630787, 103938, 4627652, 3246659, 4613188, 41026, 4605636, 4606851,
+    184323, 94786, 184323, 87426, 4639428, 4330500, 86210, 4639684, 124034, 184323, 3930755, 4639940,
+    200706, 169988, 187395, 4605636, 128962, 126978, 4597252, 4640195, 86210, 4256323, 124802, 3404995,
+    4596227, 4497922, 4640387, 3775107, 1089, 4590020, 4604739, 4631877, 4612035, 41026, 41026, 4600515,
+    41026, 4604739, 133955, 4635972, 4600515, 4600515, 4640580, 2049, 135235, 2049, 29826, 3891716,
+    2049, 135235, 124162, 95426, 41026, 124930, 87554, 87554, 88194, 184515, 2049, 4640836,
+    134339, 94786, 4618373, 549827, 95747, 88194, 124930, 186371, 105282, 4616836, 87682, 4641092,
+    124034, 4624708, 198468, 4630787, 90498, 142274, 193475, 947458, 3842820, 186371, 29826, 135683,
+    135683, 4595396, 1043523, 105282, 947458, 4593540, 4589635, 102978, 4641347, 4605124, 4641539, 4628869,
+    88194, 88194, 183043, 4641732, 113602, 202434, 913858, 121923, 4610820, 4636805, 4641989, 137795,
+    1105603, 4642309, 124162, 187203, 4603779, 158019, 102978, 4617860, 200706, 4604931, 124162, 4598211,
+    4596933, 170244, 186755, 95747, 113474, 453124, 124034, 192835, 4604739, 96835, 4637125, 4642627,
+    4600323, 121923, 102850, 41026, 102722, 186371, 4606147, 193027, 113602, 4606147, 4589635, 36226,
+    104194, 183491, 41026, 87554, 4600323, 4589827, 193667, 187203, 87554, 4596419, 4590979, 4594179,
+    4605124, 124162, 105282, 955522, 104066, 4589635, 4619396, 4600323, 3137, 110210, 87426, 193475,
+    135683, 104066, 4628869, 2369, 124034, 4642820, 3137, 186755, 114370, 4590276, 127874, 2369,
+    105922, 2369, 104066, 191363, 128322, 4642627, 4737, 133955, 4630340, 3137, 94530, 4603587,
+    947458, 947458, 142146, 187395, 95747, 126978, 114242, 135235, 4600323, 103490, 135043, 127874,
+    3137, 2369, 128962, 4643076, 4594179, 4643332, 4628869, 155203, 87554, 4617091, 114242, 164867,
+    91138, 947458, 87426, 4603779, 94786, 88322, 124034, 87554, 200706, 4643588, 97027, 193475,
+    3246659, 186371, 88194, 104194, 103618, 128322, 182147, 3137, 4633411, 4606147, 1110723, 4481,
+    4595396, 4595396, 88322, 1110723, 4138051, 2829700, 3137, 133507, 4642627, 4630787, 103938, 166851,
+    128194, 95747, 124034, 186371, 124162, 184515, 126978, 4460803, 41026, 1109699, 87554, 186755,
+    110338, 4604291, 4643845, 219266, 4634179, 4644163, 4608837, 124802, 128322, 94786, 87426, 4644355,
+    124034, 4600515, 946883, 94786, 4616068, 105922, 4613188, 3775107, 4606340, 4600515, 104706, 95298,
+    142146, 4607043, 4600131, 947458, 109442, 965187, 183875, 133955, 4617091, 128962, 104066, 109442,
+    4608387, 124290, 136963, 3975555, 4632900, 4589635, 193475, 87554, 36226, 1109699, 4622277, 128322,
+    4643076, 102978, 94786, 142274, 4641092, 4644548, 113474, 104194, 4644803, 137795, 164675, 4644996,
+    4604291, 95747, 136771, 4387588, 4645252, 1110723, 155011, 128962, 202434, 4498050, 128962, 124034,
+    114370, 4301315, 143042, 2396803, 4460803, 4629445, 88194, 1010691, 124034, 41026, 109698, 4497922,
+    134339, 4606147, 104194, 124290, 86338, 110210, 87682, 124930, 4617860, 4596227, 1109699, 110210,
+    3842820, 4630595, 3137, 4640580, 41026, 109570, 102722, 4616580, 4645509, 4604739, 198468, 128962,
+    4628612, 200706, 4460995, 4640580, 4630595, 947458, 4644996, 4606147, 104194, 198468, 4498050, 4616836,
+    4606147, 113602, 4481, 142402, 3936259, 114370, 133955, 3837123, 136067, 143042, 4645829, 4646147,
+    102722, 4646340, 183875, 87682, 143042, 127106, 4604931, 128450, 200706, 4615556, 105794, 2234115,
+    4600515, 4613444, 93314, 128450, 4634179, 164675, 104834, 4597763, 4643076, 4613444, 919170, 4588676,
+    4588676, 4460995, 1089, 4498306, 919170, 113474, 110210, 919170, 136067, 124290, 4624515, 113474,
+    136067, 4616836, 102850, 4600515, 105282, 113474, 40898, 143042, 16258, 192835, 4600899, 3246659,
+    4588420, 103938, 105282, 4460995, 114370, 124162, 4628419, 913858, 102722, 136963, 132227, 4627652,
+    142402, 41026, 914435, 133315, 191171, 102978, 1089, 207555, 97027, 124930, 913858, 4590787,
+    103618, 193475, 124930, 16258, 41026, 4646595, 90626, 102978, 4646788, 4603587, 113730, 1089,
+    4647045, 132611, 133315, 124930, 96131, 1089, 142146, 1081667, 136963, 36226, 193475, 87682,
+    4626244, 88194, 113474, 137603, 91266, 88322, 181699, 198468, 4595396, 193475, 193475, 4647364,
+    3137, 4497666, 102978, 3137, 193475, 3137, 142402, 87682, 90626, 113474, 90626, 4590787,
+    132227, 4588676, 109570, 3137, 4497666, 36226, 41282, 4590276, 87682, 114242, 113474, 105282,
+    102978, 102978, 955522, 105922, 193475, 90370, 199042, 199042, 193475, 127874, 185539, 4590276,
+    97027, 4606147, 4460995, 170820, 127234, 87426, 95298, 3137, 88194, 97220, 124802, 96835,
+    1609667, 94786, 4634500, 4647619, 200706, 4647619, 4602437, 110338, 95555, 95555, 37250, 37250,
+    4589124, 4641989, 109698, 4609156, 4617091, 137411, 137411, 104066, 127746, 4256323, 109698, 4627652,
+    94786, 2049, 4600515, 192643, 181699, 4600515, 4647812, 4648070, 4648454, 4648835, 4596419, 121410,
+    2049, 3137, 2049, 88194, 4621764, 947458, 104194, 193475, 182147, 87426, 124034, 128450,
+    4626051, 4649028, 137411, 142402, 4468291, 4593540, 40898, 129603, 4615812, 109442, 4628869, 128194,
+    132227, 4589635, 4590979, 3310403, 4460995, 87426, 4737, 4600515, 90626, 121282, 124802, 3891716,
+    4592838, 4589635, 4468291, 4468291, 129603, 198468, 4588676, 4616068, 4630340, 182595, 87426, 90626,
+    4609668, 90626, 947458, 4604739, 87554, 4452930, 124162, 4600323, 128450, 105282, 104194, 4590020,
+    121282, 4616324, 4591812, 110338, 4617091, 132419, 91138, 4649285, 4600131, 124802, 4453443, 4593540,
+    4597956, 919170, 4630340, 4649605, 136963, 133315, 132227, 102978, 4635972, 104834, 4649925, 4417,
+    124802, 114883, 193475, 103618, 95298, 4650243, 103490, 40898, 4468291, 110210, 137795, 4604739,
+    4468291, 4588420, 87682, 40898, 4650436, 96835, 4650693, 124290, 109442, 199042, 91138, 200706,
+    4651011, 128450, 181699, 94658, 91138, 105282, 103490, 4611331, 4593987, 113474, 136771, 4617091,
+    133507, 90498, 4651204, 105794, 3137, 1746947, 124034, 90626, 4616324, 4600323, 134339, 4138051,
+    4624708, 4598211, 128450, 102978, 90370, 132419, 181699, 1105603, 134147, 127106, 4651461, 4607749,
+    4593987, 109698, 3219267, 4604291, 193475, 4635590, 4606147, 4628869, 947458, 103490, 1088580, 110210,
+    113474, 134339, 183491, 4651780, 103938, 109442, 2918211, 88322, 4468291, 87682, 4590020, 186755,
+    1000707, 4452930, 4452930, 4596227, 4452930, 4453443, 124802, 128962, 947458, 110210, 128322, 128322,
+    2369, 4623107, 86338, 181251, 181251, 4611780, 120642, 4608067, 4590787, 87682, 4642309, 120770,
+    1110723, 4608387, 124162, 128322, 87426, 91138, 87554, 127234, 137603, 138499, 4600515, 183491,
+    4620996, 4594755, 4604931, 4606147, 4417, 4417, 88194, 1010691, 129603, 87426, 193027, 4256323,
+    87682, 4612676, 4460995, 87554, 87426, 4588676, 88322, 4625220, 136067, 1089, 1088580, 40898,
+    142146, 87106, 184323, 193475, 185091, 4593795, 186755, 4613956, 4603779, 4591812, 103490, 4650436,
+    110210, 2829700, 3137, 4604739, 4652036, 4468291, 86338, 4608837, 36226, 95426, 4652293, 4596227,
+    95426, 4460995, 94786, 4628869, 4646340, 95426, 127746, 4608387, 947458, 955522, 41282, 193667,
+    207555, 88194, 4598852, 109442, 4596227, 3137, 105922, 4453443, 32130, 128322, 120642, 136771,
+    183491, 4611524, 185731, 4600515, 192643, 90626, 919170, 4644548, 87682, 109698, 104194, 4639684,
+    91138, 4644548, 109442, 2049, 4138051, 4603779, 4589635, 3137, 128450, 3137, 4652036, 105154,
+    4612228, 4599939, 104194, 105922, 4589635, 110338, 3837123, 114242, 4643076, 102978, 135683, 4468291,
+    181699, 87554, 87554, 4616324, 4652614, 104834, 200706, 1746947, 128962, 191171, 91266, 137795,
+    1081667, 114242, 138499, 36226, 109442, 135683, 4612035, 40898, 87682, 4589635, 103618, 142914,
+    136963, 133507, 4652036, 124290, 4417, 186755, 4599939, 4603779, 187395, 132419, 4611331, 109698,
+    4604931, 121282, 4652996, 4592388, 202434, 192643, 4643845, 4453443, 90626, 4608258, 135875, 41602,
+    87426, 32130, 4468291, 4653252, 4616324, 4603395, 109698, 4593987, 4653508, 4604291, 185731, 4607749,
+    3310403, 142914, 133955, 4600515, 94530, 4635011, 103618, 142146, 192643, 102722, 191363, 4628419,
+    1110723, 193475, 4619396, 191363, 4608258, 4468291, 124802, 87682, 128322, 192835, 4599108, 913858,
+    4608387, 4603587, 142146, 41026, 183491, 1109699, 122819, 113474, 1088580, 87426, 109570, 16258,
+    16258, 4600323, 4608387, 105922, 4468291, 4460803, 4460803, 88194, 104194, 211587, 4589827, 86978,
+    4653764, 4654020, 102978, 4596227, 3137, 124930, 4641092, 129603, 4650243, 102850, 94786, 4654276,
+    947458, 191171, 955522, 4591363, 4597956, 128322, 113602, 4597252, 32130, 90626, 193667, 192643,
+    4598852, 110338, 135875, 124162, 110338, 4624708, 4138051, 102978, 135043, 102722, 104706, 97220,
+    95939, 102978, 103618, 114370, 90626, 4630595, 4640195, 182595, 947458, 96131, 4616324, 114242,
+    166659, 114691, 207555, 104194, 3372291, 4635011, 913858, 4652614, 4481, 4620996, 4481, 3137,
+    4601668, 87426, 4619972, 120770, 94786, 186371, 128450, 114370, 132419, 132419, 135235, 4599939,
+    102850, 947458, 4654531, 102978, 4460995, 4654020, 947458, 109698, 94786, 114242, 4619653, 113602,
+    4616068, 4600323, 102978, 4600323, 4627909, 96131, 946883, 124162, 4623812, 4623107, 4630980, 2049,
+    183491, 183491, 211203, 193667, 4654724, 134339, 134339, 142146, 95939, 91138, 4138051, 4617860,
+    87682, 3837123, 36226, 90626, 128450, 128450, 913858, 4654980, 104194, 4651011, 135043, 4655235,
+    36226, 4604739, 192835, 184323, 4607043, 3975555, 913283, 113602, 87426, 4623107, 67842, 105154,
+    4654724, 4616836, 4635011, 3137, 95939, 95298, 103490, 913858, 4615237, 109698, 96131, 947458,
+    197892, 94658, 109570, 102722, 4655428, 4481, 133507, 3219267, 4589124, 3137, 200706, 4643332,
+    87682, 32130, 3137, 193475, 4654724, 4589124, 29826, 913858, 142146, 109570, 202434, 4628612,
+    4604291, 4655685, 1115843, 3137, 549827, 133955, 191171, 1707651, 182147, 4589827, 4609668, 186755,
+    4595203, 90370, 4595203, 124802, 124802, 4481, 4653508, 4592838, 127746, 200706, 186755, 200706,
+    114883, 4604931, 1746947, 4598211, 94786, 138884, 90370, 136067, 87426, 104194, 94786, 4653508,
+    4630980, 4656003, 128322, 4590020, 4606147, 219266, 1087811, 124034, 4625733, 183043, 4596227, 104194,
+    104194, 88322, 4618116, 29826, 4600515, 114370, 4600515, 103490, 4612035, 102722, 2554371, 4656195,
+    919170, 95939, 124802, 4640387, 955522, 919170, 113730, 182595, 4644163, 124802, 126978, 4644163,
+    4608387, 127234, 121923, 124034, 142914, 4606147, 4623812, 114691, 4656388, 109442, 4606147, 4604739,
+    4628227, 199042, 3137, 87554, 4634179, 4606147, 913283, 95426, 549827, 3975555, 202434, 105154,
+    128962, 102978, 1087811, 4417, 1087811, 1770627, 127746, 96131, 128962, 87554, 95939, 4460995,
+    183875, 88194, 88194, 4460995, 919170, 137411, 3137, 219266, 2878788, 126978, 198468, 102978,
+    88194, 4609668, 4649285, 4648454, 198468, 142274, 4589827, 4648454, 36226, 166851, 104194, 94786,
+    142402, 142402, 36226, 4656644, 41282, 4616068, 128194, 132419, 132419, 41282, 4589635, 129090,
+    4634179, 142914, 561667, 919747, 88194, 4627012, 124290, 102850, 4656899, 102850, 4656899, 136963,
+    4657092, 4654724, 4589635, 87682, 4593795, 109442, 87682, 136963, 4617091, 4481, 193475, 1115843,
+    128962, 132419, 132419, 947458, 200706, 4627012, 947458, 170244, 913858, 170244, 170244, 102978,
+    4616324, 3137, 4640580, 4657349, 4657669, 87682, 913858, 4640580, 102850, 132419, 947458, 134339,
+    182147, 187203, 87682, 94658, 4610820, 200706, 87682, 109698, 97220, 94786, 94530, 128962,
+    1005955, 90370, 183491, 4637444, 4657987, 919170, 95426, 4630595, 102850, 142914, 4589635, 4634179,
+    4608387, 4645252, 4645252, 4641347, 1109699, 113474, 4648835, 947458, 88194, 104194, 200706, 4609668,
+    103490, 4597956, 4609668, 4608067, 913858, 4605124, 4658180, 4616068, 192835, 88194, 88194, 4658436,
+    88194, 88322, 4656195, 128322, 4608387, 191363, 191363, 103490, 4588676, 4658436, 88194, 4656195,
+    128322, 41026, 191363, 4606147, 4460995, 1109699, 4658691, 105282, 88322, 4600131, 104706, 4591556,
+    105794, 132611, 4653508, 87554, 4604291, 186371, 1087811, 90370, 3891716, 4098563, 87554, 4658884,
+    126978, 126978, 4607043, 87554, 4600131, 4591556, 3310403, 184899, 4608580, 130307, 128962, 183043,
+    4640580, 128003, 4640580, 4609412, 4609412, 4609412, 4610371, 2049, 91138, 91138, 4659139, 2459971,
+    88322, 32130, 3137, 97027, 184515, 2049, 4643332, 200706, 193027, 4600899, 142402, 109442,
+    67842, 96131, 113474, 128194, 121282, 87426, 4609924, 165315, 4641092, 32130, 119170, 4611780,
+    105922, 105922, 95747, 956099, 4468291, 4595203, 128322, 3137, 4468291, 128322, 128322, 3137,
+    4659332, 4605636, 4589380, 4616836, 182147, 2369, 4616836, 109698, 67842, 2369, 4453443, 94786,
+    4453443, 4453443, 182147, 453124, 87682, 87682, 191363, 4453443, 4453443, 132419, 132419, 133955,
+    142274, 133507, 4615556, 128962, 202434, 4262019, 1109699, 4659588, 135043, 4652036, 1010691, 192643,
+    947458, 87554, 3372291, 4644355, 3137, 127746, 4659588, 109442, 104194, 135043, 182147, 41026,
+    91138, 87554, 163587, 4481, 94658, 919170, 4645252, 3975555, 4651011, 4644355, 4616068, 124162,
+    4589635, 104194, 87682, 124162, 41026, 87554, 4659844, 91138, 109442, 4609924, 4594563, 4651011,
+    4653508, 88322, 133955, 4640836, 4606147, 4615237, 4652614, 4652036, 955522, 4628612, 127874, 4453443,
+    87554, 90370, 4646147, 4600131, 109442, 103490, 4651011, 41602, 103618, 4640836, 137795, 4618693,
+    1069443, 1109699, 3372291, 115587, 115587, 41026, 41026, 4604739, 919170, 181699, 4418819, 87554,
+    4417, 4453443, 138499, 104194, 91138, 4612035, 4609924, 3246659, 913858, 113474, 955522, 4660100,
+    4660356, 115587, 4615237, 4604931, 4660612, 87554, 87682, 4615556, 4588676, 4452930, 87554, 95939,
+    132227, 4599108, 4497922, 2886596, 91138, 91138, 91138, 1109699, 102978, 4593987, 104194, 182595,
+    192643, 4604739, 133315, 4616324, 128962, 105794, 95426, 94786, 4626500, 4604739, 102978, 4588676,
+    4611524, 128003, 142914, 4588676, 3137, 453124, 113474, 113474, 113474, 182147, 2369, 183043,
+    4641347, 102978, 170244, 127746, 4596227, 4651780, 127106, 113474, 90626, 126978, 2369, 4612035,
+    4647812, 4633411, 4497922, 2049, 2049, 3137, 132227, 4660868, 1105603, 4610820, 4616836, 4594948,
+    4618116, 132227, 4618116, 132227, 133955, 4594179, 4600323, 4588420, 113474, 95426, 113474, 4654724,
+    4654724, 199042, 113730, 4661123, 164675, 1770627, 136771, 182147, 3842820, 4642627, 541827, 541827,
+    137411, 4627652, 4661316, 113474, 4600515, 87426, 142146, 104706, 142914, 4625220, 4630787, 142914,
+    4601349, 4661571, 4630787, 4600515, 142146, 3975555, 128194, 102850, 956099, 32130, 4661763, 121731,
+    104194, 219266, 219266, 4600899, 113730, 134147, 4648835, 124802, 1252676, 1252676, 4603587, 211587,
+    4661956, 4589635, 87554, 3137, 87426, 138884, 110338, 90370, 91138, 4606340, 88322, 104706,
+    113602, 4594371, 186371, 200706, 104194, 87554, 4604931, 4653252, 4597956, 4600515, 1770627, 90498,
+    3310403, 4632707, 87554, 4653252, 102850, 110210, 2369, 113730, 4600323, 4626244, 913858, 4662213,
+    4603587, 133955, 103618, 102850, 87554, 4612228, 4600323, 4590020, 4662534, 4643076, 135043, 2049,
+    4615556, 95298, 132419, 105922, 124802, 1105603, 4600323, 211587, 128962, 104706, 114370, 124290,
+    1087811, 16258, 4595203, 4603587, 110338, 193667, 137603, 86978, 96835, 86978, 947458, 103938,
+    95747, 4460803, 87554, 102722, 102978, 87554, 90626, 137795, 134339, 947458, 134147, 4595972,
+    124290, 124290, 1043523, 110210, 4624708, 3137, 102978, 4661123, 87554, 90626, 4601668, 3137,
+    102978, 102722, 34562, 133955, 135235, 135235, 4661123, 110210, 4599939, 202434, 87554, 4588676,
+    102850, 192643, 86338, 4620803, 90626, 87554, 4617091, 113474, 3372291, 4624323, 132611, 105922,
+    4603779, 3137, 183491, 128194, 95747, 110210, 135235, 105154, 186563, 86210, 96131, 1105603,
+    1105603, 121410, 96131, 86338, 4609156, 1105603, 1105603, 4417, 4640580, 4631238, 193667, 96131,
+    127746, 123011, 1105603, 136067, 136067, 4452930, 96835, 105154, 3137, 16258, 127746, 4628419,
+    102850, 185539, 136067, 3137, 155011, 4658691, 3137, 4631238, 155651, 114242, 164867, 87682,
+    114242, 124290, 91138, 87682, 4629764, 4601092, 4597763, 4616324, 4646595, 124162, 124162, 913858,
+    124162, 41602, 41602, 124162, 29826, 4662918, 3930755, 4594371, 4595203, 4663298, 3286531, 1340611,
+    124162, 4663428, 4452930, 913858, 182147, 32130, 4663684, 90370, 105794, 109698, 3137, 88194,
+    90370, 2049, 165315, 169988, 104194, 109698, 4663942, 4612676, 124802, 124290, 3286531, 87554,
+    4593221, 104834, 137603, 4608387, 164867, 199042, 1115843, 94658, 4663428, 199042, 4605636, 128194,
+    4646340, 86210, 4664325, 128194, 4605636, 4605636, 4664644, 110210, 128194, 185731, 4598852, 106051,
+    4452930, 4092867, 105282, 4646595, 3137, 4608837, 88194, 4497666, 88194, 4660868, 955522, 955522,
+    4646595, 541827, 110338, 1042371, 4644355, 4604291, 4590020, 4650436, 4650436, 132419, 219266, 4599939,
+    914435, 182147, 4616324, 124290, 2049, 4617604, 4607043, 1246787, 167299, 219266, 4606340, 113730,
+    4599939, 2369, 1246787, 113602, 4387588, 219266, 4664900, 4004740, 1770627, 4658691, 4617604, 3137,
+    4617604, 4650436, 1770627, 87554, 129090, 1770627, 4626756, 4620803, 135043, 4661123, 110210, 947458,
+    947458, 4589635, 4595203, 124930, 4588676, 96835, 4589635, 947458, 947458, 41602, 113730, 88194,
+    105794, 95298, 103490, 36226, 4603587, 1087811, 4301315, 102722, 127746, 947458, 4644355, 124162,
+    4602757, 4640580, 136771, 4629764, 4651011, 4609924, 135043, 136771, 124802, 4639043, 4301315, 104706,
+    4603587, 90370, 94530, 183043, 124802, 133315, 4594948, 128450, 4594948, 4606596, 4661123, 4610820,
+    41602, 4640580, 102722, 3137, 155203, 4616068, 113730, 137411, 126978, 32130, 1042371, 95555,
+    3137, 192643, 541827, 105282, 4605124, 105282, 126978, 143042, 96131, 919170, 103490, 1109699,
+    4654724, 4634179, 95747, 1109699, 913858, 4616836, 3310403, 104194, 183491, 4600323, 4609156, 87682,
+    103938, 133507, 95426, 32130, 1042371, 4657987, 4640387, 94786, 86210, 142146, 4604739, 4598211,
+    142146, 102978, 124802, 124802, 114691, 3899524, 4644355, 95747, 95747, 142274, 87554, 124930,
+    4611780, 4608580, 102850, 4603779, 113474, 102850, 1109699, 3316292, 41282, 127106, 95747, 4600515,
+    95939, 4616068, 3310403, 110338, 4617091, 136771, 4656003, 128322, 4418819, 4481, 182595, 4618693,
+    4609924, 4609156, 3137, 4656003, 96835, 4600515, 4656003, 4635011, 4590979, 1082436, 1089, 88194,
+    1042371, 4618693, 4593221, 4599108, 4138051, 133507, 113602, 87106, 132419, 184515, 102850, 103938,
+    133955, 4653252, 102722, 104066, 4599939, 129603, 104066, 102722, 4653252, 4665156, 4604291, 4590020,
+    90626, 109442, 166659, 4606851, 4616836, 113474, 94530, 947458, 143042, 3842820, 182147, 186371,
+    1042946, 4643076, 88322, 88322, 1746947, 4654724, 183491, 41282, 4665156, 184323, 88322, 3837123,
+    4617860, 4605636, 142914, 1043523, 4652036, 4594371, 113474, 192643, 4617091, 453124, 184515, 124290,
+    94658, 2396803, 192643, 192643, 105154, 87682, 4593540, 102850, 4598211, 88194, 549827, 4640836,
+    184515, 164867, 4594755, 192643, 200706, 186371, 4606851, 1701955, 110210, 4468291, 4460803, 4628612,
+    102850, 200706, 1087811, 4604931, 4588420, 87682, 16258, 91138, 4613188, 41602, 4665411, 4665411,
+    124290, 4594563, 124802, 4654724, 4606596, 913858, 3837123, 4597956, 200706, 40898, 4606596, 4617091,
+    142146, 104194, 114691, 4626500, 4630980, 87554, 4600131, 4617091, 4600323, 4632900, 1005955, 549827,
+    4609924, 105154, 16258, 4630980, 163587, 4597956, 4606340, 109442, 4665604, 102850, 1707651, 128962,
+    4598852, 1707651, 4590787, 4665858, 4497666, 200706, 96131, 1010691, 3837123, 164867, 113602, 4611331,
+    192643, 192643, 114242, 113602, 4620996, 200706, 4604739, 4610371, 102978, 90626, 4606851, 104706,
+    16258, 4600131, 129603, 114883, 4599747, 4613444, 91266, 110338, 200706, 102978, 4600515, 4665987,
+    86210, 104706, 4603779, 110210, 109570, 4666181, 4666500, 29826, 103938, 1109699, 4597508, 95426,
+    193475, 183491, 94530, 135235, 109442, 4600899, 4330500, 109442, 95298, 4628612, 110210, 1042946,
+    103618, 3137, 110210, 4608580, 3137, 102850, 186371, 86338, 127234, 102978, 4625733, 103490,
+    137603, 103618, 103618, 4600515, 4600515, 4624708, 4590787, 114242, 36226, 186371, 4737, 96835,
+    4607492, 186755, 183491, 4600323, 103618, 135043, 95747, 4603779, 4625733, 4666181, 4666756, 4632196,
+    4468291, 453124, 118658, 181699, 91138, 4608580, 186371, 86210, 2396803, 4594948, 193475, 124290,
+    90498, 4666181, 4650243, 1042371, 4596227, 4623812, 955522, 90370, 4630595, 3891716, 135043, 94530,
+    4608387, 4608387, 947458, 919170, 124290, 114242, 91138, 97027, 4598852, 4597508, 184515, 88194,
+    127746, 104194, 41282, 109570, 113602, 4623812, 90370, 104194, 124802, 4663428, 133507, 186371,
+    128322, 128322, 128322, 110210, 4644548, 135875, 4644548, 200706, 128962, 4624708, 109442, 946883,
+    142274, 4644548, 142274, 3246659, 138499, 135875, 4624708, 128962, 4625733, 136771, 103618, 4643076,
+    200706, 4625733, 4627266, 4667011, 2369, 114883, 36226, 132227, 4606147, 36226, 87554, 91138,
+    91138, 3137, 4667204, 4604739, 1042946, 184515, 115587, 4468291, 90370, 4616068, 3137, 187395,
+    187395, 4667460, 113602, 87426, 1110723, 4667460, 187395, 187395, 4628612, 109698, 4597956, 4628869,
+    110338, 4595203, 193027, 1089, 1089, 947458, 135875, 4609668, 4737, 1105603, 4667716, 182147,
+    3137, 4599555, 4599555, 4453443, 913858, 102850, 103618, 4603587, 102850, 4589635, 102850, 913858,
+    120514, 4608387, 128003, 4608387, 1042946, 192643, 110210, 4653508, 124930, 1089, 120514, 4591812,
+    4667972, 4592069, 105794, 105794, 4632900, 102850, 4634500, 1042946, 110210, 105794, 4653508, 1042946,
+    193475, 164867, 113474, 120514, 4651011, 4653508, 138499, 4594563, 4614212, 4453443, 4614212, 4660868,
+    170244, 170244, 170244, 4667716, 114883, 95298, 4645252, 4604291, 87682, 41282, 32130, 4630595,
+    4138051, 88322, 124290, 3137, 120770, 4625220, 4460803, 87426, 103490, 103490, 4616068, 96131,
+    1707651, 88322, 105154, 4630595, 919747, 4590020, 142146, 88322, 4597956, 4588931, 4598852, 88194,
+    4651011, 4600323, 4668228, 4616068, 87682, 187395, 4590020, 4262019, 187395, 103490, 96131, 193027,
+    955522, 2369, 164675, 208003, 103618, 97027, 103618, 94658, 121731, 91138, 3137, 104194,
+    4624964, 1171779, 110210, 134339, 4603779, 124034, 110210, 113474, 955522, 4659844, 4606147, 955522,
+    4659844, 4616836, 104194, 104194, 4589635, 4639043, 4625220, 4737, 192643, 16258, 110338, 142146,
+    142146, 4595396, 90626, 4593540, 109442, 36226, 124034, 4588931, 36226, 114242, 200706, 4616836,
+    4604739, 4604739, 104194, 200706, 3176323, 124162, 104194, 4603779, 104194, 104834, 124162, 200706,
+    129090, 4652036, 4636229, 128003, 4608387, 2369, 186563, 200706, 129090, 4668485, 4668485, 104706,
+    4617091, 88194, 1110723, 167299, 109570, 88194, 955522, 947458, 34818, 87426, 2369, 4468291,
+    87426, 134147, 41026, 128450, 87682, 120770, 182147, 136963, 90626, 2369, 4606147, 87682,
+    4460803, 105794, 102722, 128450, 127106, 3137, 102722, 142146, 4138051, 4138051, 219266, 124162,
+    4616836, 4664644, 135043, 124162, 88451, 110338, 97220, 2049, 4616068, 4588420, 95298, 1089,
+    186371, 4590787, 95298, 3975555, 1089, 137603, 137603, 4589635, 137603, 4606147, 137603, 4460995,
+    4650436, 135428, 4626500, 200706, 200706, 4597956, 2369, 146947, 90370, 4620803, 4061444, 102722,
+    88451, 186755, 181699, 4656388, 105794, 105154, 4600515, 87426, 181699, 94530, 124162, 166659,
+    16258, 3137, 105794, 88194, 4629445, 102850, 121282, 4606147, 4613700, 135875, 2459971, 87426,
+    4614468, 4621764, 4642627, 913858, 120770, 4620803, 4604739, 1746947, 113602, 124290, 4604931, 4620803,
+    4597956, 4658691, 95298, 4614724, 124034, 4604739, 4613700, 3137, 114883, 181699, 135043, 105154,
+    134147, 4453443, 105154, 4643845, 4594563, 183491, 106051, 4597956, 4597956, 88451, 88451, 4668804,
+    4620803, 87682, 4620803, 1746947, 4629764, 4669060, 3137, 2369, 102850, 4600131, 4605380, 88194,
+    913858, 29826, 113474, 88194, 88194, 87554, 3137, 4664644, 119170, 4596613, 113474, 4600515,
+    200706, 3137, 128450, 183491, 183043, 561667, 128194, 4608580, 202434, 4608580, 128962, 128962,
+    109442, 4608580, 4589635, 187203, 4659844, 186755, 4595396, 105154, 113474, 4590020, 3137, 193027,
+    4595396, 16258, 90626, 105154, 3975555, 96835, 87682, 134339, 193475, 115587, 115587, 4625733,
+    102978, 95426, 109698, 94658, 4669315, 136067, 4600899, 102978, 4618693, 115587, 128962, 193475,
+    96835, 4643076, 96835, 128322, 118658, 4604931, 4589635, 4654276, 102850, 105154, 87682, 88194,
+    96835, 4669508, 118658, 109442, 136771, 4590020, 183491, 4599747, 4590979, 193667, 4593540, 87554,
+    87554, 4606147, 4640195, 90626, 4138051, 102978, 102978, 102978, 4460995, 561667, 132419, 132419,
+    88322, 128962, 136963, 3891716, 41282, 170244, 109698, 103618, 29826, 4669765, 166851, 124034,
+    109698, 4670085, 142146, 104194, 90370, 104194, 4610820, 104194, 4658436, 134147, 1105603, 127874,
+    90498, 128962, 110338, 127234, 109698, 4598211, 1105603, 87554, 4604739, 1087811, 110338, 4605124,
+    4604739, 90626, 4625733, 104194, 136771, 104834, 110338, 105922, 4604739, 4497666, 4600899, 193027,
+    4604739, 102978, 170820, 104194, 3775107, 4609156, 124802, 40898, 198468, 198468, 170820, 91138,
+    200706, 114242, 4612676, 4606147, 187203, 4591171, 94530, 191171, 124802, 986435, 4603779, 105154,
+    2049, 124802, 2049, 3286531, 913858, 3286531, 91138, 913858, 91266, 128194, 109698, 1110723,
+    128450, 4603587, 41282, 200706, 956099, 90370, 2878788, 124162, 3137, 4626756, 90626, 4598852,
+    192643, 4604931, 193667, 4616068, 4593540, 4593540, 102722, 102722, 4497666, 1043523, 102978, 125059,
+    4658180, 41282, 947458, 91266, 102722, 125059, 4606147, 4637444, 41282, 1043523, 133315, 125059,
+    1776516, 4658180, 109442, 109442, 142274, 4597956, 142274, 1824707, 142274, 4599747, 126978, 126978,
+    126978, 4616836, 4603779, 208003, 128450, 4604739, 549827, 136771, 4624708, 181699, 4645252, 4594179,
+    182147, 193475, 4589635, 4641732, 4654020, 4644163, 137603, 4641732, 182147, 133507, 193475, 104706,
+    137603, 1081667, 4606340, 109698, 95298, 1609667, 143042, 103618, 4590020, 4656388, 114242, 4606851,
+    143042, 4610371, 143042, 4611076, 114242, 114242, 110338, 3837123, 87426, 4670403, 4608258, 142146,
+    4607492, 4138051, 947458, 104194, 192835, 187203, 4498306, 87554, 4667716, 121282, 87554, 105922,
+    95426, 132419, 4608258, 132419, 4610371, 4591812, 132419, 1105603, 135683, 94530, 4649285, 4669315,
+    155203, 135683, 135683, 135683, 103618, 132419, 132419, 29826, 109570, 102722, 113474, 4604291,
+    29826, 197636, 4670596, 113474, 102722, 1005955, 3372291, 135875, 4636805, 135875, 2760643, 185731,
+    185539, 110338, 3310403, 3310403, 4633411, 1087811, 2049, 3842820, 110210, 184515, 114691, 40898,
+    40898, 88194, 87106, 186755, 192643, 114370, 4600515, 192643, 2436291, 4604739, 4606596, 4588931,
+    4606596, 947458, 136067, 4608580, 947458, 3246659, 4651011, 121731, 88322, 4616836, 124930, 4659139,
+    133955, 4608580, 4588420, 4497666, 41602, 103618, 4608580, 87554, 129603, 41602, 103618, 129603,
+    3137, 103618, 129603, 4590020, 121923, 1010691, 184515, 4590020, 110210, 4497922, 4670853, 2049,
+    4600515, 4664644, 121282, 113474, 110338, 2049, 158019, 4594179, 182147, 4653252, 181699, 96131,
+    4671172, 187203, 95426, 3837123, 4612676, 4600515, 3310403, 4671429, 4607492, 4664644, 4633411, 200706,
+    164867, 142402, 142274, 4630595, 142914, 4665604, 164867, 120642, 120642, 36226, 103490, 1042371,
+    16258, 87426, 4636805, 199042, 4604739, 955522, 4601668, 187395, 4617285, 4590787, 193475, 4666500,
+    104194, 4379780, 96131, 4597956, 87554, 105922, 4468291, 136067, 136067, 200706, 4453443, 561667,
+    136067, 87426, 1088580, 91266, 4601924, 113730, 4667204, 87426, 4667204, 4612035, 187395, 4591363,
+    4604739, 136067, 136067, 2369, 137795, 4589635, 4606147, 4671748, 4605636, 134339, 134147, 4672003,
+    128322, 2436291, 128322, 142274, 134147, 919170, 2369, 2369, 4630595, 4625476, 4417, 4417,
+    4616324, 102978, 4625476, 4417, 90370, 86466, 4262019, 86338, 4643332, 86210, 166851, 4591171,
+    166851, 4588420, 4588420, 4588420, 128962, 87682, 104194, 4672197, 105794, 136067, 104194, 104194,
+    87426, 104194, 104194, 128450, 3137, 1105603, 136067, 3137, 104194, 4599747, 3137, 4661123,
+    4161923, 202434, 182595, 193475, 202434, 4648454, 4648454, 4648835, 4592069, 135043, 121731, 4648454,
+    87554, 4606340, 4606340, 4593540, 4468291, 87682, 4606340, 104706, 96131, 105154, 94786, 109442,
+    95747, 94786, 110338, 105154, 4605636, 4452930, 105922, 956099, 103938, 182147, 166659, 4595396,
+    113730, 114883, 4604291, 142402, 4628419, 124930, 4601092, 191171, 104194, 113474, 121282, 4608387,
+    4604291, 105154, 124162, 124290, 1105603, 4453443, 4606340, 4661316, 41026, 124802, 166659, 115587,
+    41026, 16258, 3137, 95426, 4600515, 103938, 4636229, 1010691, 219266, 113474, 184515, 192643,
+    4452930, 4672516, 135875, 136067, 95298, 4647364, 4599747, 3975555, 134339, 1109699, 95747, 16258,
+    103490, 4591363, 4654724, 143042, 1089, 138691, 186755, 3891716, 164867, 1000707, 124162, 3975555,
+    1089, 200706, 4379780, 110210, 86210, 4628419, 1087811, 110210, 96131, 955522, 4606851, 4626756,
+    95747, 4605124, 4672770, 4672900, 124290, 1105603, 4630595, 4669315, 1109699, 4592069, 166851, 138499,
+    113474, 4590787, 87554, 4604291, 3137, 4615812, 128450, 87426, 87426, 114691, 103938, 4614724,
+    104066, 87426, 4636805, 142402, 16258, 90370, 4589635, 4497922, 105154, 4631238, 4615556, 87554,
+    4452930, 113602, 4616836, 4609156, 200706, 4655235, 4653252, 453124, 109442, 4667716, 1087811, 128450,
+    4590787, 4645509, 104066, 104066, 142402, 87426, 4590787, 1087811, 187203, 103618, 129090, 4598659,
+    4600515, 4608387, 4651011, 182595, 3137, 4598404, 4590787, 4594755, 4628419, 1043523, 136067, 88322,
+    4589635, 4665156, 4601092, 113474, 41026, 4452930, 4603587, 182595, 3137, 124162, 41026, 4590787,
+    4481, 113474, 186371, 186371, 4668804, 127746, 182147, 105794, 105154, 4589635, 4606596, 41026,
+    4673157, 133955, 4604739, 87426, 4598404, 96131, 90370, 3137, 4605636, 95298, 91138, 4642627,
+    947458, 4590979, 4590979, 4605636, 95298, 4417, 4618116, 4606851, 4606851, 118658, 88322, 4605636,
+    3775107, 4655685, 120770, 4633411, 2049, 4655685, 169988, 4616324, 4637125, 4637125, 4453443, 138499,
+    4672770, 4673476, 120770, 124290, 124290, 4603587, 120770, 124162, 4600323, 124162, 124162, 86210,
+    87554, 4643076, 1081667, 155011, 4597956, 183491, 88322, 1081667, 4595396, 103618, 155203, 4641092,
+    128194, 88322, 211587, 88322, 4589827, 155011, 105922, 88322, 4098563, 4098563, 155203, 103490,
+    102722, 133315, 4592069, 121923, 4656644, 3137, 91138, 91138, 124034, 87682, 4590787, 132419,
+    4633411, 136771, 192643, 124162, 4630787, 110210, 4630787, 4630787, 4630787, 4630787, 105154, 4659139,
+    105922, 105154, 105922, 4673731, 4627266, 955522, 191171, 4596419, 164675, 4596419, 4619653, 4609156,
+    164867, 164867, 200706, 200706, 200706, 1109699, 4460995, 200706, 200706, 127106, 4611780, 1043523,
+    4600323, 4611780, 94786, 166851, 1109699, 4649028, 4651461, 1088580, 955522, 947458, 1109699, 3837123,
+    4594563, 128322, 1109699, 1109699, 4669508, 193475, 1087811, 16258, 127746, 184515, 1109699, 193027,
+    102722, 103938, 2049, 88194, 3137, 4603587, 90498, 103490, 135043, 128322, 124290, 1109699,
+    4612676, 4652036, 4625220, 4608837, 4602757, 3137, 4652036, 128322, 4654020, 4652036, 103938, 4608837,
+    184515, 919170, 88322, 34562, 104194, 104066, 102978, 202434, 4620228, 88322, 1042371, 3137,
+    183043, 4663428, 4663428, 919170, 4591556, 127746, 2049, 1707651, 102978, 181699, 110210, 110210,
+    4621764, 109570, 110210, 110210, 1701955, 4363715, 110210, 110210, 202434, 4670085, 110210, 549827,
+    4594563, 110210, 110210, 4609924, 132227, 113602, 113602, 4600515, 128322, 110210, 4663428, 4598852,
+    87554, 102978, 4598852, 4598211, 186371, 2369, 142146, 91138, 4673924, 124162, 4608387, 4658436,
+    95298, 2369, 913858, 187203, 186563, 1609667, 1609667, 4640580, 4637125, 193475, 1043523, 127746,
+    127746, 3936259, 3936259, 3936259, 4628612, 1087811, 104194, 4460995, 4641539, 4658691, 4592069, 110210,
+    4674180, 200706, 2049, 113602, 110338, 200706, 95426, 104706, 113602, 4630595, 4630595, 110210,
+    114242, 128194, 103490, 193475, 4659844, 4619972, 4659844, 913858, 103938, 2049, 87426, 3137,
+    124034, 94786, 94786, 4628612, 4607749, 102978, 102978, 37250, 86466, 135875, 95939, 113602,
+    113602, 4598852, 4645252, 135875, 4674436, 105154, 4592069, 128322, 128322, 128322, 128322, 4620803,
+    184515, 94786, 4616068, 4607749, 103938, 4460995, 104194, 4606147, 4606147, 4606147, 186755, 947458,
+    4635203, 947458, 128450, 104706, 947458, 947458, 947458, 947458, 87682, 97220, 138691, 947458,
+    202434, 202434, 110338, 110338, 4590020, 114883, 3137, 4593987, 102978, 4593987, 3137, 128450,
+    4593987, 186755, 4669765, 105154, 184515, 4600323, 114370, 4593987, 913858, 109570, 187395, 186371,
+    1110723, 4596933, 121923, 3137, 128450, 4674693, 4658436, 4598404, 90370, 4614724, 4468291, 109570,
+    4595653, 4640195, 3137, 4640836, 129603, 4604291, 126978, 4628869, 104194, 4613188, 4597956, 4614724,
+    4595653, 4606147, 2071427, 1110723, 128962, 4612035, 191171, 4620228, 4616836, 124802, 4675010, 102850,
+    129603, 124034, 4660100, 186755, 143042, 4624323, 102850, 124802, 102850, 4630340, 87554, 4590979,
+    110210, 184515, 1010691, 184515, 124162, 4641732, 202434, 3137, 4654531, 947458, 134147, 4604931,
+    87426, 127106, 127106, 127106, 4646147, 4651011, 4651011, 110338, 4602437, 4595203, 4604739, 4604739,
+    29826, 104194, 104194, 104194, 4612676, 4661316, 121410, 191363, 191363, 96131, 97027, 135235,
+    128450, 170244, 114242, 87426, 170244, 4665156, 114242, 170244, 91138, 95939, 124290, 4649285,
+    118658, 96835, 4599747, 4599747, 4632196, 126978, 954947, 87682, 87682, 94530, 127746, 193027,
+    4617091, 96835, 193027, 113474, 120514, 143042, 120514, 128450, 4737, 202434, 4737, 4636229,
+    113474, 1042946, 41026, 541827, 102978, 202434, 104194, 91266, 184515, 114242, 4611524, 95426,
+    128450, 4675140, 2823811, 104834, 4599747, 128450, 41026, 41026, 199042, 4613444, 91138, 4658884,
+    946883, 4669315, 95298, 102978, 4737, 4603973, 113602, 955522, 1110723, 4600899, 1171779, 164867,
+    4600515, 41026, 191171, 182147, 138691, 103938, 182595, 142402, 142402, 4591363, 41026, 41026,
+    120770, 41026, 3137, 4675396, 4593540, 29826, 4468291, 1082436, 182595, 3837123, 4654531, 102722,
+    96131, 947458, 182147, 95298, 129603, 2823811, 4594948, 219266, 97027, 541827, 4603779, 4603779,
+    103490, 4603779, 103490, 127874, 104706, 4628227, 192643, 4600515, 87554, 88322, 4417, 4417,
+    4611780, 90498, 4592069, 549827, 88194, 94530, 104194, 4675652, 124162, 41026, 4675652, 105282,
+    104834, 105282, 199042, 199042, 1770627, 4589635, 4460995, 3310403, 167299, 2823811, 2823811, 2823811,
+    103938, 91138, 4600323, 165315, 4600323, 182147, 919170, 919170, 182147, 4650436, 87554, 3137,
+    182147, 200706, 200706, 127746, 128962, 4645829, 87554, 102722, 3137, 41282, 186755, 193475,
+    200706, 4675908, 104834, 114370, 200706, 200706, 200706, 200706, 4604484, 200706, 114370, 200706,
+    200706, 193475, 4599939, 4661316, 124290, 4599555, 4599555, 36226, 91138, 103490, 103490, 103490,
+    87554, 3137, 4594563, 2878788, 128322, 113474, 4599108, 113474, 187395, 4606596, 4644803, 4635011,
+    109442, 4658691, 4592069, 4607749, 103490, 4604291, 4609156, 1082436, 1082436, 1105603, 87682, 110210,
+    3975555, 120770, 137603, 91266, 104706, 3137, 127234, 4596227, 95747, 1109699, 1109699, 95298,
+    4606147, 114691, 41026, 102978, 4603779, 4599747, 95298, 186755, 128322, 186755, 1184387, 128322,
+    87426, 128322, 105922, 1088580, 1110723, 4646595, 4606596, 103490, 124290, 3137, 87554, 87554,
+    128962, 4600899, 137411, 4598852, 193475, 4629445, 87682, 41282, 4643076, 127874, 136067, 4611076,
+    4639684, 4654724, 4497666, 88194, 4596419, 193667, 142146, 95939, 4594948, 4607749, 4595203, 124162,
+    90370, 4600515, 134147, 114370, 4630980, 103490, 4626756, 138499, 913858, 4611524, 4611524, 4597252,
+    4646340, 86210, 4597252, 4676165, 4661571, 4676485, 133507, 4497922, 114242, 114242, 4640836, 4640836,
+    4481, 128962, 4453443, 95939, 132227, 90370, 95939, 132227, 102978, 4608580, 4613188, 1110723,
+    135683, 136963, 186371, 114242, 3137, 136067, 4453443, 4641539, 137795, 4599747, 4610820, 87426,
+    170244, 4641539, 3137, 4626051, 4616836, 4606147, 134147, 4599747, 127106, 183875, 4657092, 4617860,
+    4617860, 4617860, 110210, 32130, 32130, 32130, 4613188, 4654020, 4615237, 1010691, 3137, 4638659,
+    4609156, 4453443, 4646147, 4610820, 1586179, 1609667, 4595396, 202434, 103618, 121410, 3137, 3310403,
+    3137, 88194, 1043523, 4604931, 103618, 1340611, 186563, 4633411, 947458, 128194, 4417, 133315,
+    87426, 187395, 4599747, 104066, 158019, 158019, 158019, 87682, 87682, 109442, 87682, 4594563,
+    3310403, 200706, 87426, 102978, 102978, 3936259, 88194, 4460995, 4635972, 1082436, 4668804, 113474,
+    87554, 95939, 3930755, 1105603, 104706, 4611076, 4640195, 4606851, 4599939, 32130, 128962, 103490,
+    4599939, 4622597, 4616836, 4588420, 4616836, 136963, 4613188, 4613188, 4613188, 4600323, 4600323, 4600323,
+    4611780, 122819, 87554, 105794, 4674180, 4676805, 4653764, 128450, 127234, 87682, 104194, 197636,
+    67074, 103938, 193667, 135875, 186755, 4138051, 36226, 36226, 36226, 94786, 1042946, 913858,
+    104706, 88194, 102978, 4588676, 110338, 4460803, 4632900, 4632900, 88322, 1105603, 4592069, 4677124,
+    4588931, 4609412, 122115, 4625733, 102978, 4639235, 4262019, 185539, 4635972, 182147, 4660868, 113730,
+    4634500, 4654980, 110210, 36226, 1082436, 4613188, 2369, 914435, 186755, 192643, 4468291, 104194,
+    124162, 4648835, 913858, 913858, 4656388, 4656388, 3975555, 4590020, 184515, 104194, 3137, 3137,
+    3137, 3137, 3137, 3137, 3137, 3137, 3137, 3137, 3137, 4677379, 4677571, 4677763,
+    4677955, 4678147, 4678339, 4678531, 4678723, 4678915, 4679107, 4679299, 4679491, 4679683, 4679875, 2305,
+    1857, 4680067, 4680259, 4680451, 1921, 4680643, 12929, 8641, 7233, 4680835, 35009, 35073,
+    35137, 48450, 263618, 47810, 263746, 263874, 4681027, 264002, 4681219, 47938, 199682, 4681411,
+    199810, 199938, 49538, 515330, 515458, 4681603, 515586, 515714, 515842, 515970, 516098, 516226,
+    516354, 335362, 516482, 48066, 516610, 516738, 516866, 523586, 523714, 523842, 523970, 4681795,
+    4681987, 4682179, 4682371, 4682563, 49666, 524098, 524226, 524354, 3137, 4682755, 524610, 524738,
+    524866, 48194, 524994, 514882, 4513410, 4513538, 4682947, 4513666, 4513794, 4513922, 4683139, 4514050,
+    4683330, 4514178, 4514306, 49794, 4514434, 4514562, 4514690, 48322, 4683459, 4683651, 4514818, 4514946,
+    4515074, 4515202, 515010, 4515330, 4515458, 4515586, 4515714, 4515842, 4516162, 4683842, 4516290, 4516418,
+    49026, 4683971, 3137, 3137, 4684163, 4516546, 4516674, 4516802, 4516930, 4684355, 4517058, 4517186,
+    4684547, 4517314, 215746, 4517442, 4488450, 4517570, 4517698, 4684738, 4519363, 4684867, 4519555, 4519747,
+    4519939, 4520131, 4685059, 4520323, 4685251, 4520515, 4685443, 4685635, 4685826, 4685955, 4686147, 4518338,
+    4517826, 4518466, 48579, 4518595, 4518787, 4518979, 4686339, 4686531, 4686723, 4519171, 4517954, 4518082,
+    4518210, 524482, 4488963, 4686915, 4687107, 4687299, 4687491, 4687683, 4687875, 4688067, 4688259, 4688451,
+    4688643, 4688835, 4689027, 4689219, 4689411, 4689603, 4689795, 4689987, 4690179, 4690371, 4690563, 4690755,
+    4690947, 4691139, 4691331, 4488771, 4520707, 4520899, 4691523, 4691715, 4691907, 4692099, 4692291, 4692483,
+    4692675, 4692867, 4693059, 4693251, 4693443, 4693635, 4693827, 4694019, 4694211, 4694403, 4694595, 4694787,
+    4694979, 4695171, 4695363, 3137, 4695555, 4695747, 4695939, 4696131, 4696323, 4696515, 4696707, 4696899,
+    4697091, 4697283, 4697475, 4697667, 4697859, 4698051, 4698243, 4698435, 4698627, 4698819, 4699011, 4699203,
+    4699395, 4699587, 4699779, 4699971, 4700163, 4700355, 4700547, 4700739, 4700931, 4701123, 4701315, 4701507,
+    4701699, 3137, 4701891, 276099, 4702083, 3137, 4702275, 4702467, 3137, 4702659, 4702851, 4703043,
+    4703235, 4703427, 4703619, 4703811, 4704003, 3137, 4704195, 4704387, 4704579, 4704771, 4704963, 4705155,
+    4705347, 4705539, 4705731, 4705923, 4706115, 4706307, 4706499, 4706691, 4706883, 4488579, 4707075, 4707267,
+    4707459, 4707651, 4707843, 4708035, 4708227, 4708419, 4708611, 4708803, 4708995, 4709187, 4709379, 4709571,
+    4709763, 4709955, 4710147, 4710339, 4710531, 4710723, 4710915, 4711107, 4711299, 4711491, 4711683, 4711875,
+    4712067, 4712259, 3137, 4712451, 4712643, 4712835, 4713027, 4713219, 4713411, 4713603, 4713795, 4713987,
+    4714179, 49347, 4714371, 4714563, 4714755, 4714947, 4715139, 4715331, 4715523, 4715715, 4715907, 4716099,
+    4716291, 4716483, 4716675, 4716867, 4717059, 4717251, 4717443, 4717635, 4717827, 4718019, 4718211, 4718403,
+    4718595, 4718787, 3137, 4718979, 4719171, 4719363, 4719555, 4719747, 4719939, 4720131, 4720323, 4720515,
+    4720707, 4720899, 4721091, 4721283, 4721475, 4721667, 4721859, 4722051, 4722243, 4722435, 4722627, 4722819,
+    4723011, 4723203, 4723395, 4723587, 4723779, 4723971, 4724163, 4724355, 4724547, 4724739, 4724931, 4725123,
+    4725315, 4725507, 4725699, 4725891, 4726083, 4726275, 4726467, 4726659, 4726851, 4727043, 4727235, 4727427,
+    4727619, 4727811, 4728003, 4728195, 4728387, 4728579, 4728771, 4728963, 4729155, 4729347, 4729539, 4729731,
+    4729923, 4730115, 4730307, 4730499, 4730691, 4730883, 4731075, 4731267, 4731459, 4731651, 4731843, 3137,
+    4732035, 4732227, 4732419, 4732611, 4732803, 4732995, 4733187, 4733379, 4733571, 4733763, 4733955, 3137,
+    4734147, 4734339, 4734531, 4734723, 4734915, 4735107, 4735299, 4735491, 4735683, 4735875, 4736067, 4736259,
+    4736451, 4736643, 4736835, 4737027, 4737219, 4737411, 4737603, 4737795, 4737987, 4738179, 4738371, 335491,
+    4738563, 4738755, 4738947, 4739139, 4739331, 4739523, 4739715, 4739907, 4740099, 49155, 4740291, 4740483,
+    4740675, 4740867, 4741059, 4741251, 4741443, 4741635, 4741827, 4742019, 4742211, 4742403, 4742595, 4742787,
+    4742979, 4743171, 4743363, 4743555, 4743747, 4743939, 4744131, 4744323, 4744515, 4744707, 4744899, 4745091,
+    128194, 105154, 183491, 102850, 96131, 88194, 115587, 90626, 88194, 4417, 4417, 1089,
+    1089, 1089, 1089, 4481, 4481, 4481, 4481, 2049, 2049, 2049, 2049, 2049,
+    4417, 4417, 4417, 4417, 4417, 2369, 2369, 2369, 113474, 113474, 113474, 113474,
+    113474, 113474, 113474, 113474, 113474, 113474, 113474, 113474, 113730, 113730, 113730, 113730,
+    113730, 113730, 113730, 113730, 113602, 113602, 113602, 113602, 113602, 113602, 113602, 114242,
+    114242, 114242, 114242, 114242, 114242, 114370, 114370, 114370, 114370, 94530, 94530, 94530,
+    94530, 94530, 94530, 94530, 94530, 96131, 96131, 96131, 96131, 96131, 96131, 94658,
+    94658, 94658, 94658, 94658, 94658, 94658, 94658, 95298, 95298, 95298, 95298, 95298,
+    95426, 95426, 95426, 95426, 95426, 95426, 95426, 105154, 105154, 105154, 105154, 134339,
+    134339, 134339, 134339, 134339, 134339, 134339, 137603, 137603, 137603, 137603, 137603, 105794,
+    105794, 105794, 105794, 105794, 105794, 105794, 105794, 105794, 105922, 105922, 105922, 105922,
+    105922, 105922, 105922, 109442, 109442, 109442, 109442, 109442, 109442, 109442, 109442, 109442,
+    109698, 109698, 109698, 109698, 109698, 109698, 109698, 109698, 109570, 109570, 109570, 110210,
+    110210, 110210, 110210, 110210, 110210, 110210, 110338, 110338, 110338, 110338, 110338, 86210,
+    86210, 86210, 86210, 86210, 86210, 86210, 86210, 86210, 86210, 86210, 86466, 86466,
+    86466, 86466, 86466, 86466, 86466, 141058, 141058, 141058, 86978, 86978, 86978, 86978,
+    86978, 86978, 86978, 87106, 87106, 87106, 87106, 87106, 87106, 87106, 87106, 90370,
+    90370, 90370, 90370, 90370, 90370, 90370, 90626, 90626, 90626, 90626, 90626, 90626,
+    90626, 90498, 90498, 90498, 90498, 91138, 91138, 91138, 91266, 91266, 91266, 91266,
+    91266, 91266, 46594, 46594, 46594, 46594, 46594, 46594, 37378, 37378, 37378, 37378,
+    67394, 67394, 67394, 67394, 67394, 67394, 93314, 93314, 93314, 93314, 93570, 93570,
+    93570, 93570, 93570, 93570, 93570, 93442, 93442, 93442, 93442, 93442, 93442, 94082,
+    94082, 94082, 94082, 94210, 94210, 94210, 94210, 94210, 94210, 67074, 67074, 67074,
+    67074, 67074, 4481, 4481, 4481, 4481, 4481, 4417, 4417, 4417, 4417, 2369,
+    2369, 2369, 2369, 2369, 2369, 2369, 4609, 4609, 118658, 19842, 66946, 118658,
+    114370, 4481, 4417, 2369, 114370, 4481, 4417, 2369, 4609, 4481, 109442, 4616068,
+    19330, 4184515, 166659, 4745284, 2049, 3624835, 961411, 1128644, 4021443, 4745541, 147331, 4745859,
+    4746051, 132419, 4746244, 4746499, 4746691, 4746884, 4747139, 4747333, 4747653, 4747973, 4748294, 2827780,
+    90370, 140930, 87426, 4748676, 4748932, 147331, 4660356, 16258, 106498, 141058, 141058, 141058,
+    4749188, 4749188, 4749443, 4749635, 4749827, 4750020, 4747333, 4750276, 4750533, 4750852, 4751108, 2764612,
+    4751365, 4751685, 1590148, 4752005, 4588164, 103938, 4752324, 4752581, 983107, 995907, 4752900, 4753157,
+    1128644, 1128644, 19330, 19330, 146563, 4753475, 4753475, 155011, 4660356, 166659, 166659, 4753667,
+    106498, 4481, 4745859, 4753860, 4754115, 90498, 37378, 4754308, 1132547, 4737, 4754563, 4754563,
+    4754755, 41986, 4749635, 4754947, 4750020, 4755139, 4590276, 4590276, 4755332, 4755588, 4755588, 4755844,
+    2238084, 4756101, 3779076, 4747653, 4756422, 4756806, 4142020, 2804100, 4752005, 4757191, 4757637, 142146,
+    87426, 1064899, 4752900, 19330, 4184515, 4757955, 4748932, 2846403, 3859523, 4758149, 66946, 147715,
+    147715, 4758468, 4758724, 141058, 104066, 186371, 86338, 60354, 4758978, 168002, 158466, 1169090,
+    4759107, 4759300, 4759555, 4759555, 4759748, 4760003, 4760003, 4565250, 4747139, 4750276, 4603779, 4606851,
+    4760197, 4760515, 4760707, 4760900, 4459714, 4751108, 4761157, 2764612, 4751365, 4756422, 4587076, 3979524,
+    1590148, 4752005, 4761477, 4761797, 4761797, 4762117, 5954, 137411, 94530, 4762435, 4762435, 3624835,
+    1044803, 4762628, 4762883, 4757955, 4763077, 4763077, 6338, 4745541, 147331, 4660356, 109698, 4597956,
+    4597956, 94786, 141058, 3126915, 4763397, 3744707, 4763715, 4749443, 4763908, 4749827, 4764164, 4764420,
+    4764676, 4764934, 4765317, 4755139, 4765636, 4765892, 4755588, 4102532, 4766147, 4766342, 4766342, 4747973,
+    4587076, 4766725, 4757191, 4761797, 3773380, 87426, 3624835, 4184515, 4748676, 1632259, 4763077, 4767043,
+    4767237, 4767043, 66946, 147715, 4753475, 132611, 137795, 137795, 4753667, 4753667, 4767557, 142274,
+    137603, 113602, 4749188, 4746244, 4754308, 60354, 1169090, 4767876, 4759107, 4754755, 4759555, 4568323,
+    4768131, 4768325, 4749635, 4747139, 10498, 4767043, 4768646, 4769028, 4747333, 4750276, 4760197, 4750852,
+    4751108, 4769284, 4769542, 4751365, 4751365, 4747653, 4769925, 4747973, 4747973, 1590148, 4770244, 1613636,
+    4770500, 4766725, 4770758, 4771141, 4757637, 4757637, 3773380, 3245379, 5954, 103938, 94530, 995907,
+    4771461, 1064899, 19330, 2319491, 4757955, 4745541, 3666307, 142402, 16258, 4753667, 4758724, 142274,
+    141058, 132419, 86338, 4771779, 203202, 60354, 4771971, 4754755, 4759555, 4772163, 4764164, 4772356,
+    4767237, 4765317, 4750276, 4755139, 4459714, 3940228, 4769284, 3779076, 4265988, 4265988, 4769925, 4756422,
+    4587076, 2277188, 4770758, 4771141, 4772615, 142146, 4771461, 1064899, 4748932, 3859523, 4773060, 4773060,
+    147715, 166659, 16258, 4481, 4773316, 4754115, 137603, 4614724, 113602, 4773570, 4759107, 4773699,
+    4773892, 4774147, 4102532, 4750852, 3940228, 4769284, 2277188, 3979524, 4774341, 3773380, 3245379, 137411,
+    4774660, 1044803, 2319491, 87682, 137795, 4753667, 4481, 90498, 87554, 86338, 3744707, 1169090,
+    4774915, 4769028, 4603779, 4618693, 4459714, 4002820, 19330, 4775107, 4660356, 4481, 105282, 87554,
+    4749827, 4764164, 4775301, 2238084, 4756422, 4775623, 4776070, 4774660, 2319491, 113602, 3744707, 60354,
+    4766342, 4776453, 4776773, 1613636, 141058, 113602, 4750852, 4773060, 141186, 5121, 3777, 7873,
+    6785, 7425, 5633, 5761, 7105, 4545, 11201, 4993, 6529, 4777090, 1179714, 4777218,
+    569666, 4556866, 4802, 34434, 34434, 188802, 5505, 85634, 26882, 31554, 11137, 4609,
+    5505, 6657, 4430018, 569154, 4453634, 28546, 11137, 5954, 8514, 5505, 7041, 6657,
+    84418, 66178, 4777346, 4098, 4777475, 4777667, 4777859, 4778051, 16002, 211778, 4778243, 15362,
+    4778435, 15618, 4530883, 8706, 4778627, 6913, 4778818, 4533698, 64386, 64770, 4778947, 4779139,
+    65538, 4779331, 1089, 4779522, 38914, 2369, 4779651, 4481, 4417, 19330, 4481, 212354,
+    38530, 3157634, 4481, 38530, 4481, 66946, 2049, 67970, 4554370, 34690, 2049, 2049,
+    3204738, 2049, 4779843, 4779522, 10498, 67074, 119170, 67842, 4780035, 4780227, 2049, 2369,
+    4481, 1089, 34562, 51266, 41602, 34562, 41282, 11137, 1985, 1985, 1985, 1985,
+    1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 12289,
+    12289, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 12289, 12289, 1985, 1985,
+    1985, 1985, 1985, 1985, 12737, 6529, 1473, 12673, 0, 0, 0, 0,
+    3201, 7169, 3393, 3585, 3457, 7361, 4929, 5057, 3521, 5441, 5569, 5697,
+    14145, 3649, 3713, 2113, 15169, 6465, 6593, 6721, 3841, 8321, 6849, 12865,
+    3905, 6977, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473, 1473,
+    1473, 1473, 641, 641, 641, 641, 641, 641, 641, 641, 4780420, 4780676,
+    6593, 2177, 4673, 35201, 4780930, 10881, 10881, 1985, 13186, 37633, 242369, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 4929, 4781058, 4781186, 3393, 7361,
+    4781314, 4781442, 3649, 3649, 1473, 4781570, 45954, 7105, 7105, 32833, 32833, 32833,
+    32833, 32833, 7105, 524482, 515458, 4781699, 4781891, 4782084, 4782340, 1473, 1473, 1473,
+    1473, 1473, 1473, 1473, 1473, 1473, 12673, 12673, 12673, 12673, 12673, 12673,
+    12673, 12673, 12673, 12673, 12673, 12673, 12673, 12673, 12673, 12673, 12673, 12673,
+    12673, 12673, 12673, 12673, 12673, 12673, 12673, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 1, 1, 0, 0, 0, 4673, 13186, 439171,
+    12673, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12801, 12801,
+    0, 0, 0, 0, 0, 0, 0, 6529, 6657, 7041, 7873, 11137,
+    7425, 12353, 12417, 2177, 2177, 211906, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 259714, 4782594, 6721,
+    2177, 0, 0, 0, 0, 4782724, 2177, 10881, 4673, 12481, 2177, 2177,
+    2177, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    1473, 1473, 1473, 1473, 1473, 1473, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 3393, 7361, 7105, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 3393, 15105, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 7105, 32833, 7105, 2305, 1857, 1921, 12929, 8641, 7233,
+    35009, 35073, 35137, 48450, 263618, 47810, 263746, 263874, 264002, 47938, 199682, 199810,
+    199938, 49538, 515330, 515458, 515586, 515714, 514882, 4513410, 4513538, 4513666, 4513794, 2305,
+    1857, 12929, 8641, 35009, 35073, 263618, 47810, 263746, 263874, 199682, 199810, 199938,
+    515586, 515714, 515842, 515970, 516098, 516354, 335362, 48066, 523586, 523714, 523842, 523970,
+    49666, 524226, 524354, 524610, 524866, 48194, 524994, 514882, 4513410, 4513538, 4513666, 4513794,
+    1921, 12929, 8641, 1473, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009,
+    35073, 35137, 48450, 263618, 47810, 263746, 263874, 264002, 47938, 199682, 199810, 199938,
+    34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 48450, 263618,
+    47810, 263746, 263874, 264002, 47938, 199682, 199810, 199938, 459907, 4782983, 4783429, 4783751,
+    4784197, 4784518, 649925, 481412, 614340, 614340, 656452, 286402, 690373, 476611, 477763, 614340,
+    634371, 633540, 675268, 610051, 474946, 466884, 609604, 473794, 623812, 468099, 462979, 476994,
+    17282, 613826, 514565, 462338, 462979, 655876, 643076, 17282, 818438, 649925, 465218, 607555,
+    635332, 463877, 474755, 478981, 445890, 618178, 597444, 597444, 464642, 647171, 474946, 623171,
+    477123, 286402, 631619, 601284, 468293, 606466, 479300, 646595, 466628, 468099, 461956, 473794,
+    445890, 460418, 462979, 17282, 462979, 480964, 592899, 619010, 599875, 629764, 595269, 476994,
+    474563, 466628, 593093, 474563, 462979, 445890, 286787, 648069, 607939, 607555, 465348, 2305,
+    1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 2305, 1857, 1921, 12929,
+    8641, 7233, 35009, 35073, 35137, 2305, 1857, 1921, 12929, 8641, 2305, 8641,
+    3201, 7169, 3393, 3585, 3457, 7361, 4929, 5057, 3521, 5441, 5569, 5697,
+    14145, 3649, 3713, 2113, 15169, 6465, 6593, 6721, 3841, 8321, 6849, 12865,
+    3905, 6977, 1089, 7105, 4353, 4545, 4417, 7425, 4993, 5121, 4481, 5505,
+    5633, 5761, 11137, 4609, 2369, 7873, 10945, 6529, 6657, 6785, 2049, 11201,
+    6913, 3777, 4737, 7041, 3201, 7169, 3393, 3585, 3457, 7361, 4929, 5057,
+    3521, 5441, 5569, 5697, 14145, 3649, 3713, 2113, 15169, 6465, 6593, 6721,
+    3841, 8321, 6849, 12865, 3905, 6977, 1089, 7105, 4353, 4545, 4417, 7425,
+    4993, 4481, 5505, 5633, 5761, 11137, 4609, 2369, 7873, 10945, 6529, 6657,
+    6785, 2049, 11201, 6913, 3777, 4737, 7041, 3201, 7169, 3393, 3585, 3457,
+    7361, 4929, 5057, 3521, 5441, 5569, 5697, 14145, 3649, 3713, 2113, 15169,
+    6465, 6593, 6721, 3841, 8321, 6849, 12865, 3905, 6977, 1089, 7105, 4353,
+    4545, 4417, 7425, 4993, 5121, 4481, 5505, 5633, 5761, 11137, 4609, 2369,
+    7873, 10945, 6529, 6657, 6785, 2049, 11201, 6913, 3777, 4737, 7041, 3201,
+    3393, 3585, 4929, 5441, 5569, 3649, 3713, 2113, 15169, 6593, 6721, 3841,
+    8321, 6849, 12865, 3905, 6977, 1089, 7105, 4353, 4545, 7425, 5121, 4481,
+    5505, 5633, 5761, 11137, 4609, 7873, 10945, 6529, 6657, 6785, 2049, 11201,
+    6913, 3777, 4737, 7041, 3201, 7169, 3393, 3585, 3457, 7361, 4929, 5057,
+    3521, 5441, 5569, 5697, 14145, 3649, 3713, 2113, 15169, 6465, 6593, 6721,
+    3841, 8321, 6849, 12865, 3905, 6977, 1089, 7105, 4353, 4545, 4417, 7425,
+    4993, 5121, 4481, 5505, 5633, 5761, 11137, 4609, 2369, 7873, 10945, 6529,
+    6657, 6785, 2049, 11201, 6913, 3777, 4737, 7041, 3201, 7169, 3585, 3457,
+    7361, 4929, 5441, 5569, 5697, 14145, 3649, 3713, 2113, 15169, 6593, 6721,
+    3841, 8321, 6849, 12865, 3905, 1089, 7105, 4353, 4545, 4417, 7425, 4993,
+    5121, 4481, 5505, 5633, 5761, 11137, 4609, 2369, 7873, 10945, 6529, 6657,
+    6785, 2049, 11201, 6913, 3777, 4737, 7041, 3201, 7169, 3585, 3457, 7361,
+    4929, 3521, 5441, 5569, 5697, 14145, 3713, 6593, 6721, 3841, 8321, 6849,
+    12865, 3905, 1089, 7105, 4353, 4545, 4417, 7425, 4993, 5121, 4481, 5505,
+    5633, 5761, 11137, 4609, 2369, 7873, 10945, 6529, 6657, 6785, 2049, 11201,
+    6913, 3777, 4737, 7041, 3201, 7169, 3393, 3585, 3457, 7361, 4929, 5057,
+    3521, 5441, 5569, 5697, 14145, 3649, 3713, 2113, 15169, 6465, 6593, 6721,
+    3841, 8321, 6849, 12865, 3905, 6977, 1089, 7105, 4353, 4545, 4417, 7425,
+    4993, 5121, 4481, 5505, 5633, 5761, 11137, 4609, 2369, 7873, 10945, 6529,
+    6657, 6785, 2049, 11201, 6913, 3777, 4737, 7041, 3201, 7169, 3393, 3585,
+    3457, 7361, 4929, 5057, 3521, 5441, 5569, 5697, 14145, 3649, 3713, 2113,
+    15169, 6465, 6593, 6721, 3841, 8321, 6849, 12865, 3905, 6977, 1089, 7105,
+    4353, 4545, 4417, 7425, 4993, 5121, 4481, 5505, 5633, 5761, 11137, 4609,
+    2369, 7873, 10945, 6529, 6657, 6785, 2049, 11201, 6913, 3777, 4737, 7041,
+    3201, 7169, 3393, 3585, 3457, 7361, 4929, 5057, 3521, 5441, 5569, 5697,
+    14145, 3649, 3713, 2113, 15169, 6465, 6593, 6721, 3841, 8321, 6849, 12865,
+    3905, 6977, 1089, 7105, 4353, 4545, 4417, 7425, 4993, 5121, 4481, 5505,
+    5633, 5761, 11137, 4609, 2369, 7873, 10945, 6529, 6657, 6785, 2049, 11201,
+    6913, 3777, 4737, 7041, 3201, 7169, 3393, 3585, 3457, 7361, 4929, 5057,
+    3521, 5441, 5569, 5697, 14145, 3649, 3713, 2113, 15169, 6465, 6593, 6721,
+    3841, 8321, 6849, 12865, 3905, 6977, 1089, 7105, 4353, 4545, 4417, 7425,
+    4993, 5121, 4481, 5505, 5633, 5761, 11137, 4609, 2369, 7873, 10945, 6529,
+    6657, 6785, 2049, 11201, 6913, 3777, 4737, 7041, 3201, 7169, 3393, 3585,
+    3457, 7361, 4929, 5057, 3521, 5441, 5569, 5697, 14145, 3649, 3713, 2113,
+    15169, 6465, 6593, 6721, 3841, 8321, 6849, 12865, 3905, 6977, 1089, 7105,
+    4353, 4545, 4417, 7425, 4993, 5121, 4481, 5505, 5633, 5761, 11137, 4609,
+    2369, 7873, 10945, 6529, 6657, 6785, 2049, 11201, 6913, 3777, 4737, 7041,
+    3201, 7169, 3393, 3585, 3457, 7361, 4929, 5057, 3521, 5441, 5569, 5697,
+    14145, 3649, 3713, 2113, 15169, 6465, 6593, 6721, 3841, 8321, 6849, 12865,
+    3905, 6977, 1089, 7105, 4353, 4545, 4417, 7425, 4993, 5121, 4481, 5505,
+    5633, 5761, 11137, 4609, 2369, 7873, 10945, 6529, 6657, 6785, 2049, 11201,
+    6913, 3777, 4737, 7041, 4481, 5505, 3201, 7169, 4929, 3585, 3457, 6977,
+    3457, 3970, 3521, 5569, 5697, 14145, 3649, 12865, 3713, 2113, 6465, 3970,
+    6593, 6721, 3905, 14338, 18178, 14594, 3713, 3585, 1089, 7105, 4993, 4545,
+    4417, 7041, 4417, 4802, 4481, 5633, 5761, 11137, 4609, 3777, 2369, 7873,
+    6529, 6657, 6657, 6785, 4737, 14722, 16002, 14978, 2369, 4545, 4417, 4802,
+    5633, 14722, 6529, 7873, 3201, 7169, 4929, 3585, 3457, 6977, 3457, 3970,
+    3521, 5569, 5697, 14145, 3649, 12865, 3713, 2113, 6465, 3970, 6593, 6721,
+    3905, 14338, 18178, 14594, 3713, 3585, 1089, 7105, 4993, 4545, 4417, 7041,
+    4417, 4802, 4481, 5633, 5761, 11137, 4609, 3777, 2369, 7873, 6529, 6657,
+    6657, 6785, 4737, 14722, 16002, 14978, 2369, 4545, 4417, 4802, 5633, 14722,
+    6529, 7873, 3201, 7169, 4929, 3585, 3457, 6977, 3457, 3970, 3521, 5569,
+    5697, 14145, 3649, 12865, 3713, 2113, 6465, 3970, 6593, 6721, 3905, 14338,
+    18178, 14594, 3713, 3585, 1089, 7105, 4993, 4545, 4417, 7041, 4417, 4802,
+    4481, 5633, 5761, 11137, 4609, 3777, 2369, 7873, 6529, 6657, 6657, 6785,
+    4737, 14722, 16002, 14978, 2369, 4545, 4417, 4802, 5633, 14722, 6529, 7873,
+    3201, 7169, 4929, 3585, 3457, 6977, 3457, 3970, 3521, 5569, 5697, 14145,
+    3649, 12865, 3713, 2113, 6465, 3970, 6593, 6721, 3905, 14338, 18178, 14594,
+    3713, 3585, 1089, 7105, 4993, 4545, 4417, 7041, 4417, 4802, 4481, 5633,
+    5761, 11137, 4609, 3777, 2369, 7873, 6529, 6657, 6657, 6785, 4737, 14722,
+    16002, 14978, 2369, 4545, 4417, 4802, 5633, 14722, 6529, 7873, 3201, 7169,
+    4929, 3585, 3457, 6977, 3457, 3970, 3521, 5569, 5697, 14145, 3649, 12865,
+    3713, 2113, 6465, 3970, 6593, 6721, 3905, 14338, 18178, 14594, 3713, 3585,
+    1089, 7105, 4993, 4545, 4417, 7041, 4417, 4802, 4481, 5633, 5761, 11137,
+    4609, 3777, 2369, 7873, 6529, 6657, 6657, 6785, 4737, 14722, 16002, 14978,
+    2369, 4545, 4417, 4802, 5633, 14722, 6529, 7873, 6849, 6913, 34945, 2305,
+    1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 34945, 2305, 1857, 1921,
+    12929, 8641, 7233, 35009, 35073, 35137, 34945, 2305, 1857, 1921, 12929, 8641,
+    7233, 35009, 35073, 35137, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009,
+    35073, 35137, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12545,
+    12545, 12545, 12545, 12545, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    4500675, 4993, 4993, 5633, 5761, 4500867, 4737, 5954, 6529, 6785, 10945, 4501058,
+    4501058, 6785, 3777, 10945, 5633, 5761, 20291, 5761, 5954, 6529, 6529, 19971,
+    8514, 20291, 4481, 2369, 19971, 4353, 6657, 4545, 5761, 4609, 6529, 6657,
+    6785, 1089, 7105, 11201, 4993, 4545, 4417, 8514, 7041, 4481, 5505, 4481,
+    4993, 5633, 5761, 11137, 4609, 2369, 7873, 6529, 6657, 6785, 2049, 7425,
+    5121, 446275, 4353, 16002, 8194, 2049, 4481, 4417, 127106, 4417, 127874, 127746,
+    2369, 127874, 4802, 1089, 7105, 11201, 4993, 4545, 4417, 8514, 7041, 4481,
+    5633, 5761, 11137, 2369, 7873, 6529, 6657, 6785, 2049, 7425, 14850, 8706,
+    16002, 8194, 4737, 4417, 37378, 9346, 1089, 4481, 5505, 2369, 2049, 5121,
+    1089, 7105, 11201, 4993, 4545, 4417, 8514, 7041, 4481, 5633, 5761, 2369,
+    7873, 6657, 2049, 7425, 14850, 8706, 16002, 8194, 1985, 4737, 4993, 4481,
+    9346, 20291, 6657, 4737, 2049, 4481, 11137, 8706, 81858, 6785, 5121, 4609,
+    3777, 4507586, 4353, 5761, 6657, 7041, 218242, 4577347, 5633, 4545, 29826, 40770,
+    11201, 4784899, 4785090, 7425, 6529, 10945, 4737, 4785218, 7873, 1140930, 4785346, 66306,
+    4785475, 1985, 84290, 220226, 4993, 6529, 1089, 38146, 4481, 2049, 2369, 38402,
+    4417, 38530, 6913, 7105, 11137, 5505, 11201, 6657, 4993, 4545, 1473, 34945,
+    2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 53185, 7297, 7873,
+    7105, 6785, 4545, 5633, 4993, 11137, 4609, 5954, 6657, 16002, 4737, 6913,
+    5505, 5121, 6529, 5761, 4481, 3245826, 19074, 4785667, 2049, 4417, 3157634, 66626,
+    3153347, 2369, 4226, 3133379, 1089, 38146, 1089, 7105, 4353, 4545, 4993, 4737,
+    14722, 5761, 4609, 7873, 6785, 4802, 7425, 6657, 8194, 5505, 7041, 6913,
+    11201, 5633, 2369, 42242, 6529, 11137, 14850, 5121, 4417, 4481, 5954, 2049,
+    26882, 8706, 211906, 3174915, 4785860, 3126915, 3245379, 41282, 41602, 4461955, 29826, 4786115,
+    3777, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 44674,
+    2369, 7873, 5761, 4737, 11137, 1089, 5505, 7105, 4609, 5121, 4481, 6657,
+    4545, 6785, 5121, 2049, 4353, 4545, 5633, 6529, 4417, 4609, 4993, 11137,
+    4609, 6785, 5761, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073,
+    35137, 457091, 457283, 457475, 457667, 457091, 37250, 457859, 185091, 185539, 185091, 185539,
+    146755, 146563, 4786308, 4786564, 4786308, 147139, 146947, 114883, 115587, 114883, 129603, 130307,
+    129603, 147523, 147331, 147907, 147715, 113730, 113474, 113602, 114242, 114242, 114370, 114370,
+    4762435, 67842, 67074, 118658, 67714, 67714, 119170, 119170, 4786819, 4780035, 4787011, 4787203,
+    4787395, 90626, 90370, 90498, 91138, 91266, 4787587, 4787779, 102978, 102722, 102850, 103490,
+    103490, 103618, 103618, 4481, 1089, 2049, 4417, 4417, 2369, 2369, 52546, 4481,
+    4481, 1089, 4417, 94786, 94530, 94658, 95298, 95298, 95426, 95426, 4787971, 87682,
+    87426, 87554, 88194, 88194, 88322, 88322, 88003, 124290, 124034, 124162, 124802, 124930,
+    124930, 16258, 105154, 105282, 105794, 105794, 105922, 105922, 127234, 126978, 127106, 127746,
+    127746, 127874, 127874, 211395, 19842, 46594, 37378, 66946, 66946, 67394, 67394, 141186,
+    140930, 141058, 141698, 141698, 141826, 141826, 4788163, 140930, 109698, 109442, 109570, 110210,
+    110338, 86466, 86210, 86338, 86978, 86978, 87106, 87106, 4788355, 4774147, 86466, 86210,
+    86338, 86978, 87106, 4788547, 1158788, 1160260, 1162820, 1157508, 1157508, 1161540, 1161540, 4788741,
+    4789061, 4789381, 4789701, 4789701, 128450, 128194, 128322, 128962, 4790020, 4790275, 1032387, 181251,
+    4790468, 142402, 142146, 142274, 142914, 142914, 143042, 143042, 1157763, 1159043, 1161795, 1156291,
+    1156291, 1156291, 1160515, 1160515, 4790724, 4790980, 4791236, 4791236, 1157955, 1159235, 1161987, 1156483,
+    1156483, 1160707, 1160707, 1158403, 1159683, 1162435, 1156931, 1156931, 1161155, 1161155, 93314, 971843,
+    973379, 976003, 975043, 975043, 974339, 974339, 1160067, 1131011, 1157315, 1130307, 104194, 103938,
+    104066, 104706, 104706, 104834, 104834, 111043, 110659, 110851, 111747, 111939, 2305, 1857,
+    1921, 12929, 8641, 7233, 35009, 35073, 35137, 0, 0, 0, 0, 0,
+    0, 0, 3201, 3585, 5697, 14145, 7169, 6593, 2113, 4791490, 6465, 3457,
+    7361, 3521, 3713, 4497410, 4791618, 6849, 3649, 5569, 3905, 3841, 5441, 3393,
+    5057, 15169, 4929, 29698, 6721, 27010, 8321, 12865, 4791746, 6977, 4586114, 15746,
+    1089, 4545, 5761, 11137, 7105, 6657, 7873, 35970, 6529, 4417, 7425, 4481,
+    2369, 34434, 37762, 6913, 4609, 5633, 4737, 2049, 5505, 4353, 5121, 10945,
+    4993, 29826, 6785, 27138, 11201, 3777, 39042, 7041, 4548418, 8194, 0, 0,
+    0, 1985, 0, 0, 0, 1985, 34945, 2305, 1857, 1921, 12929, 8641,
+    7233, 35009, 35073, 35137, 65, 3137, 2305, 1857, 1921, 12929, 8641, 7233,
+    35009, 35073, 35137, 48450, 49538, 335362, 49666, 514882, 49794, 515010, 49026, 4488450,
+    48579, 4488579, 4488771, 4488963, 4489155, 4489347, 4489539, 4489731, 446467, 48772, 4489924, 4490180,
+    4490436, 4490692, 4490948, 4491204, 4491460, 4491716, 4491973, 4492293, 4492613, 4492933, 4493253, 4493573,
+    4493893, 4494213, 4494533, 4791874, 4792002, 5697, 4792130, 4792259, 2305, 1857, 1921, 12929,
+    8641, 7233, 35009, 35073, 35137, 1473, 2563, 2755, 2947, 44674, 2305, 1857,
+    4491973, 5697, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 48450,
+    49538, 335362, 49666, 514882, 49794, 515010, 49026, 4488450, 48579, 4488579, 4488771, 4488963,
+    4489155, 4489347, 4489539, 4489731, 446467, 48772, 4489924, 4490180, 4490436, 4490692, 4490948, 4491204,
+    4491460, 4491716, 4491973, 4492293, 4492613, 4492933, 4493253, 4493573, 4493893, 4494213, 4494533, 2177,
+    1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137, 48450, 4488963, 4489347, 4489924,
+    4491973, 2755, 4513027, 1089, 7105, 5505, 4545, 6913, 7041, 5121, 6785, 4737,
+    5633, 5761, 11137, 4609, 6657, 12289, 7425, 6657, 10945, 6529, 8194, 6785,
+    4802, 14850, 34434, 4545, 34434, 27778, 7105, 4609, 7425, 10945, 7105, 5505,
+    5121, 5121, 4737, 5633, 5761, 11137, 4609, 6657, 12289, 7425, 6657, 10945,
+    8194, 6785, 4802, 14850, 4545, 27778, 5505, 5121, 4737, 5761, 4609, 6657,
+    12289, 6657, 10945, 8194, 14850, 4545, 27778, 4609, 10945, 7105, 5505, 5121,
+    5121, 6785, 4737, 5633, 11137, 4609, 6657, 12289, 7425, 6657, 10945, 8194,
+    6785, 4802, 14850, 4545, 34434, 27778, 7105, 7425, 1089, 7105, 5505, 4545,
+    5121, 6913, 7041, 5121, 6785, 4737, 5761, 11137, 4609, 6657, 12289, 7425,
+    6657, 10945, 6529, 8194, 6785, 4802, 14850, 34434, 4545, 34434, 27778, 7105,
+    5505, 4545, 6913, 7041, 5121, 6785, 4737, 5761, 11137, 4609, 6657, 12289,
+    7425, 6657, 10945, 6529, 8194, 6785, 4802, 14850, 34434, 4545, 34434, 27778,
+    27394, 4792450, 4792578, 4792706, 4792834, 4792962, 4793096, 4793602, 4793730, 4793858, 4793986, 4794114,
+    4794242, 4794370, 4794498, 4794626, 4794754, 4794882, 4795010, 4795138, 4795266, 4795394, 4795522, 4795650,
+    4795778, 4795906, 4796034, 4796162, 4796290, 4796418, 4796546, 4796674, 4796802, 4796930, 4797058, 4797186,
+    4797314, 4797442, 4797570, 4797698, 4797826, 4797954, 4798082, 4798210, 5505, 1473, 1473, 4798338,
+    4495362, 4495490, 4495618, 4495746, 4495874, 4496002, 48450, 263618, 47810, 263746, 263874, 264002,
+    47938, 49538, 515330, 515458, 515586, 515714, 515842, 515970, 335362, 516482, 48066, 516610,
+    516738, 516866, 523586, 49666, 524098, 524226, 524354, 524482, 524610, 524738, 514882, 4513410,
+    4513538, 4513666, 4513794, 4513922, 4514050, 49794, 4514434, 4514562, 4514690, 48322, 4685826, 4514818,
+    1473, 4798338, 4495362, 4495490, 4495618, 4495746, 4495874, 4496002, 48450, 263618, 47810, 263746,
+    263874, 264002, 47938, 49538, 515330, 515458, 515586, 515714, 515842, 515970, 335362, 516482,
+    48066, 516610, 516738, 516866, 523586, 49666, 524098, 524226, 524354, 524482, 524610, 524738,
+    514882, 4513410, 4513538, 4513666, 4513794, 4513922, 4514050, 49794, 4514434, 4514562, 4514690, 48322,
+    4685826, 4514818, 1473, 4798466, 4795138, 4795266, 4795394, 4795522, 4795650, 4795778, 4795906, 4796034,
+    18050, 4798594, 85378, 4798722, 14210, 4798850, 557570, 557698, 557826, 557954, 558082, 558210,
+    558338, 558466, 3970, 22530, 27010, 4798978, 14466, 34945, 4799106, 581250, 581378, 581506,
+    581634, 581762, 581890, 582018, 582146, 4799234, 4799362, 4506114, 4799490, 4799618, 4799756, 4800514,
+    4800642, 4800770, 4800898, 4801026, 4801154, 4801282, 4801410, 4801538, 4801666, 4801794, 4505858, 4801922,
+    4802050, 34945, 34945, 2305, 1857, 1921, 12929, 8641, 7233, 35009, 35073, 35137,
+    48450, 263618, 47810, 263746, 263874, 264002, 47938, 199682, 199810, 199938, 49538, 515330,
+    4802178, 4802306, 4802434, 4802562, 4802690, 1173826, 4802818, 4802946, 4803074, 4803202, 4803330, 34945,
+    34945, 4803459, 4803658, 4804301, 4805123, 4805315, 4805507, 4805699, 4805891, 4806083, 4806275, 4806467,
+    4806659, 4806851, 4807043, 4807235, 4807427, 4807619, 4807811, 228867, 4808003, 4808195, 4808387, 4808579,
+    4808771, 4808963, 4809155, 4809347, 4809539, 4809731, 4809923, 3393, 6465, 4810114, 4810242, 4810377,
+    3201, 7169, 3393, 3585, 3457, 7361, 4929, 5057, 3521, 5441, 5569, 5697,
+    14145, 3649, 3713, 2113, 15169, 6465, 6593, 6721, 3841, 8321, 6849, 12865,
+    3905, 6977, 10242, 152066, 4810946, 706, 4811075, 4811266, 3201, 7169, 3393, 3585,
+    3457, 7361, 4929, 5057, 3521, 5441, 5569, 5697, 14145, 3649, 3713, 2113,
+    15169, 6465, 6593, 6721, 3841, 8321, 6849, 12865, 3905, 6977, 4811394, 4811522,
+    4811650, 4811791, 4812748, 4813515, 3201, 7169, 3393, 3585, 3457, 7361, 4929, 5057,
+    3521, 5441, 5569, 5697, 14145, 3649, 3713, 2113, 15169, 6465, 6593, 6721,
+    3841, 8321, 6849, 12865, 3905, 6977, 4814210, 4814338, 4814466, 4538306, 4814595, 4811266,
+    4814786, 4814915, 4815109, 4815429, 4815747, 4815940, 4816195, 4816387, 4816580, 4816835, 4817027, 4817218,
+    4817350, 4817730, 4817858, 4817986, 4818115, 4818307, 4818500, 4818755, 4818948, 4545, 4819202, 4819331,
+    4819526, 4819912, 4820419, 4820611, 4820803, 4820995, 3201, 7169, 3393, 3585, 3457, 7361,
+    4929, 5057, 3521, 5441, 5569, 5697, 14145, 3649, 3713, 2113, 15169, 6465,
+    6593, 6721, 3841, 8321, 6849, 12865, 3905, 6977, 4821188, 4821445, 4821763, 461956,
+    475202, 616966, 124802, 472642, 621379, 460867, 479556, 466884, 597444, 4821956, 628996, 477508,
+    618819, 665027, 461763, 611587, 649925, 478981, 617539, 478981, 639428, 467139, 472771, 460738,
+    17282, 648771, 474051, 594435, 649925, 474051, 4822214, 482371, 474946, 4822598, 4822982, 4823364,
+    4823620, 4823878, 4824261, 4824582, 4824966, 4825348, 613955, 4825605, 4825925, 4826244, 4826500, 4826758,
+    4827140, 4827397, 4827719, 4828165, 4828500, 4829767, 468802, 470658, 461956, 473794, 4830216, 605059,
+    4830728, 4831238, 4831632, 4832657, 4833751, 4835208, 4835724, 4835724, 4836488, 4837008, 4838022, 4838408,
+    4838922, 4839565, 4840399, 4841355, 4842069, 4843401, 4843989, 4845331, 4846533, 4846858, 4847508, 4848786,
+    4849941, 4851278, 4852179, 4853405, 4855260, 4857044, 4858318, 2177, 4859206, 4859596, 2177, 2177,
+    4860374, 4861776, 4862805, 4864139, 4864843, 4865552, 4866568, 4867076, 4867346, 4868487, 4868933, 4869256,
+    4869769, 4870345, 4870927, 4871887, 4872842, 4873479, 4873931, 4874630, 4875023, 4875973, 4876297, 4876874,
+    4877512, 4878021, 4878348, 4879109, 4879441, 4880523, 4881228, 4881991, 4882441, 4883015, 4883465, 4884039,
+    4884486, 4884875, 4885575, 4886022, 4886407, 4886858, 4887494, 4887884, 4888645, 4888966, 4889353, 4889931,
+    4890634, 4891270, 4891661, 4892492, 4893261, 4894090, 4894725, 4895046, 4895430, 4895818, 4896454, 4896838,
+    4897229, 4898054, 4898437, 4898758, 4899149, 4899978, 4900617, 4901195, 4901898, 4902537, 4903111, 4903566,
+    4904454, 4904841, 4905416, 4905930, 4906565, 4906886, 4907269, 4907594, 4908239, 4909188, 4909445, 4909771,
+    4910473, 4911055, 4912005, 4912326, 4912716, 4913489, 4914570, 4915208, 4915719, 4916165, 4916489, 4917071,
+    4918031, 4918982, 4919370, 4920009, 4920584, 4921093, 4921422, 4922318, 4923214, 4924103, 4924550, 4924934,
+    4925323, 4926027, 4926735, 4927693, 2177, 2177, 4928527, 4929488, 2177, 4930507, 4931213, 4932046,
+    32833, 32833, 4932940, 4933703, 4934159, 4935117, 4935951, 4936918, 4930507, 4938317, 4939143, 4939595,
+    4940292, 4940551, 4941004, 4933703, 4941768, 4942288, 4943307, 4944005, 4944333, 4945158, 4945545, 4946120,
+    4946645, 4947981, 4948806, 4949194, 4949831, 4950289, 4951368, 4951879, 4952334, 4953240, 4954759, 4955204,
+    4955467, 4956175, 4957132, 4957895, 4958343, 4958790, 4959175, 4959629, 4960457, 4961039, 4961992, 4962509,
+    4963335, 4963787, 4964488, 4965005, 4965835, 4966541, 4967371, 4968074, 4968718, 4969608, 4970118, 4970514,
+    4971655, 4972106, 4972752, 4973779, 4974983, 4975431, 4975877, 4976200, 4976710, 4977106, 4978247, 4978700,
+    4979477, 4980809, 4981381, 4981700, 4981958, 4982347, 4983058, 4984199, 4984657, 4985736, 4986248, 4986768,
+    4987792, 2177, 2177, 4988811, 315979, 4989512, 2177, 4990022, 4990410, 4991054, 4991944, 0,
+    0, 0, 0, 0, 4992452, 4992711, 4993155, 4993358, 4994245, 4994567, 4995016, 4995528,
+    4996037, 4996359, 4996810, 4997447, 4997894, 4998278, 4998666, 4999300, 4999557, 4999878, 5000263, 5000712,
+    5001224, 5001733, 5002053, 5002373, 5002697, 5003272, 5003782, 5004164, 5004420, 5004676, 5004935, 5005381,
+    5005710, 5006601, 5007175, 5007631, 5008587, 5009294, 5010181, 5010504, 5011014, 5011399, 5011856, 5012870,
+    5013256, 5013766, 5014148, 5014406, 5014791, 5015236, 5015500, 5016262, 5016646, 5017036, 5017796, 5018052,
+    5018309, 5018632, 5019141, 5019461, 5019787, 5020489, 5021061, 5021385, 5021957, 5022276, 5022532, 5022789,
+    5023109, 5023431, 5023883, 5024587, 5025291, 5025996, 5026758, 5027141, 5027464, 5027971, 5028163, 5028357,
+    5028683, 5029382, 5029771, 5030475, 5031176, 5031686, 5032070, 5032454, 5032839, 5033287, 5033743, 5034694,
+    5035080, 5035590, 5035973, 5036302, 5037194, 5037831, 5038277, 5038603, 5039315, 5040532, 5041796, 5042053,
+    5042372, 5042630, 5043015, 5043463, 5043926, 5045336, 5046852, 5047112, 5047632, 5048660, 5049940, 5051216,
+    5052234, 5052876, 5053637, 5053972, 5055241, 5055822, 5056720, 5057734, 5058118, 5058502, 5058894, 5059780,
+    5060038, 5060436, 5061702, 5062087, 5062537, 5063114, 5063752, 5064264, 5064775, 5065224, 5065733, 5066053,
+    5066380, 5067141, 5067460, 5067723, 5068424, 5068946, 5070088, 5070602, 5071245, 5072075, 5072784, 5073803,
+    5074502, 5074891, 5075596, 5076365, 5077197, 5078027, 5078737, 5079825, 5080928, 5082949, 5083270, 5083653,
+    5083972, 5084229, 5084556, 5085320, 5085829, 5086149, 5086471, 5086918, 5087311, 5088272, 5089293, 5090116,
+    5090377, 5090962, 53185, 5092108, 5092868, 5093123, 5093317, 5093638, 5094033, 5095110, 5095493, 5095817,
+    5096394, 5097033, 5097612, 5098371, 5098564, 5098828, 5099601, 5100687, 5101647, 5102597, 5102921, 5103499,
+    5104217, 5105819, 5107530, 5108170, 5108808, 5109326, 5110218, 5110863, 5111825, 5112910, 5113799, 5114249,
+    5114847, 5116812, 5117573, 5117899, 5118602, 5119244, 5120006, 5120395, 5121095, 5121541, 5121875, 5123078,
+    5123460, 5123722, 5124364, 5125125, 5125452, 5126219, 5126920, 5127430, 5127826, 5128980, 5130255, 5131208,
+    5131730, 5132885, 5134216, 5134732, 5135498, 5136135, 5136584, 5137103, 5138065, 5139153, 5140240, 5141255,
+    5141709, 5142541, 5143363, 5143558, 5143940, 5144201, 2177, 5144781, 5145626, 5147271, 5147723, 5148433,
+    5149528, 5151055, 5152016, 5153029, 5153352, 5153862, 5154251, 5154952, 5155470, 5156356, 5156618, 5157266,
+    5158421, 5159748, 5160005, 5160327, 5160773, 5161096, 5161609, 5162181, 5162509, 5163333, 5163652, 257475,
+    5163909, 5164228, 5164489, 5165067, 5165773, 5166597, 5166917, 5167240, 5167748, 5168005, 5168331, 5169031,
+    5169479, 5169933, 5170758, 5171140, 5171403, 5172106, 5172749, 5173585, 5174665, 5175240, 5175764, 5177044,
+    5178315, 5179020, 5179797, 5181139, 5182357, 5183699, 5184915, 5186136, 5187663, 5188625, 2177, 2177,
+    12737, 12737, 12737, 2177, 2177, 14145, 12737, 12737, 12737, 5189706, 5190341, 5190662,
+    5191047, 5191498, 5192136, 5192656, 5193671, 5194119, 5194567, 5195015, 5195463, 5195911, 5196359, 5196807,
+    5197255, 5197704, 5198216, 5198728, 5199241, 5199817, 5200393, 5200969, 5201545, 5202121, 5202697, 5203273,
+    5203849, 5204426, 5205066, 5205706, 5206350, 2177, 2177, 7297, 7297, 2177, 5117573, 5207239,
+    5207698, 5208859, 5210570, 5211205, 5211532, 5212298, 5212944, 5213959, 5214411, 5215113, 5215692, 276298,
+    7297, 7297, 7297, 7297, 7297, 7297, 7297, 7297, 7297, 7297, 5216461, 5217291,
+    5217998, 367239, 5218884, 5218884, 5219147, 5219847, 366413, 5220301, 5221133, 2177, 10881, 11201,
+    8321, 5221955, 5222155, 11201, 12353, 12417, 12353, 12417, 12353, 12417, 10881, 11201,
+    10881, 11201, 10881, 11201, 374732, 5222856, 5223379, 5224596, 5225864, 5226379, 2177, 2177,
+    2177, 2177, 2177, 2177, 5227089, 5228175, 5229130, 5229767, 2177, 2177, 2177, 2177,
+    2177, 32833, 32833, 5230217, 5230796, 32833, 32833, 32833, 32833, 32833, 5231561, 5232142,
+    5233037, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 5233868, 5234632, 5102921, 5235151, 2177, 2177, 2177, 2177, 12865, 4544770, 647746,
+    5236108, 5159748, 5135498, 32833, 5236871, 5237319, 5023109, 5237774, 11201, 10881, 12417, 12353,
+    5238668, 7297, 7297, 7297, 7297, 7297, 5239426, 5239564, 2177, 2177, 2177, 5240331,
+    3777, 3777, 3777, 3777, 11201, 11201, 5241028, 5241291, 5241996, 5242770, 5243910, 5244294,
+    5244681, 5245253, 5245572, 5245831, 5246278, 5246668, 5247433, 5248009, 5248588, 5249349, 5249670, 5250052,
+    5250313, 5250891, 5251595, 5252294, 5252685, 5253519, 5254473, 5255046, 5255432, 5255945, 5256523, 5257224,
+    5257742, 5258645, 5259988, 5261265, 5262365, 5264221, 5266061, 5266888, 5267398, 5267781, 5268100, 5268362,
+    5269000, 5269526, 5270921, 5271498, 5272136, 5272646, 5273031, 5273483, 5274186, 5274820, 5275083, 5275783,
+    5276235, 5276935, 5277387, 5278088, 5278601, 5279179, 5279881, 5280453, 5280778, 5281416, 5281931, 5282639,
+    5283594, 5284236, 5285004, 5285776, 5286795, 5287511, 5288973, 5289804, 5290570, 5291208, 5291721, 5292292,
+    5292556, 5293325, 5294158, 5295053, 5295885, 5296720, 5297741, 5298565, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 32833, 32833, 32833, 32833,
+    1473, 641, 1473, 641, 15105, 15105, 15105, 15105, 15105, 15105, 833, 833,
+    833, 223170, 223170, 223170, 4673, 12481, 4673, 12481, 5298887, 5299339, 5300049, 5301132,
+    5301905, 5302994, 5304135, 5304582, 5304971, 5305672, 5306181, 5306502, 5306884, 5307149, 5307979, 5308680,
+    5309192, 5309706, 5310348, 5311115, 5311828, 5313093, 5313422, 5314308, 5314580, 5315849, 5316422, 5316818,
+    5317960, 5318473, 5319057, 5320147, 5321362, 5322511, 5323461, 5323784, 5324298, 5324942, 5325847, 5327309,
+    5328143, 5329112, 5330629, 5330958, 5331848, 5332363, 5333080, 5334606, 5335502, 5336402, 5337541, 5337868,
+    5338634, 5339283, 5340488, 5341007, 5341970, 5343109, 5343431, 5343881, 5344460, 5345223, 5345667, 5345863,
+    5346309, 5346632, 5347153, 5348232, 5348750, 5349645, 10881, 12865, 4481, 11137, 5350470, 5350854,
+    5351245, 5352078, 5352968, 5353476, 5353745, 5354826, 5355470, 10881, 5356359, 5356813, 5357636, 5357897,
+    5358473, 5359057, 5360134, 5360522, 5361158, 5361543, 5361988, 5362249, 5362830, 5363722, 5364370, 10881,
+    364489, 364489, 364489, 5365521, 5366607, 5123722, 32833, 32833, 5367564, 5368328, 5368846, 5369734,
+    5370117, 5370446, 5371339, 5372046, 5372941, 5373773, 5374594, 3201, 7361, 3457, 6849, 5374722,
+    5374850, 5374850, 5374978, 5374978, 12737, 12737, 12737, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 6657, 4609, 4609, 11201, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 5375109, 5375109, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 3457, 2177, 2177,
+    2177, 5375427, 241155, 5375620, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 6721,
+    6721, 6721, 6721, 6721, 3201, 565186, 5375874, 6465, 5121, 4609, 5376002, 11137,
+    5376132, 5376388, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 12353, 10881,
+    12417, 11201, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 12737, 12737, 12737, 12737, 12737,
+    12737, 12737, 3777, 3777, 3777, 3777, 3777, 3777, 3777, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 12737, 12737, 12737, 12737, 12737, 2177,
+    2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177,
+    2177, 2177, 2177, 2177, 5376654, 5377550, 5378445, 5379278, 5380173, 5381003, 5381708, 5382478,
+    5383374, 5384269, 5385102, 5385997, 12993, 12353, 10881, 12417, 11201, 12353, 10881, 12417,
+    11201, 12353, 10881, 12417, 11201, 12353, 10881, 12417, 11201, 12353, 10881, 12417,
+    11201, 12353, 10881, 12417, 11201, 12353, 10881, 12417, 11201, 12353, 10881, 12417,
+    11201, 12353, 10881, 12417, 11201, 12353, 10881, 12417, 11201, 12353, 10881, 12417,
+    11201, 12353, 10881, 12417, 11201, 12353, 10881, 12417, 11201, 12353, 10881, 12417,
+    11201, 12353, 10881, 12417, 11201, 12353, 10881, 12417, 11201, 12353, 10881, 12417,
+    11201, 12353, 10881, 12417, 11201, 12481, 4673, 12481, 4673, 1473, 641, 12353,
+    10881, 12417, 11201, 12481, 4673, 12481, 4673, 12353, 10881, 12417, 11201, 12481,
+    4673, 12481, 4673, 12353, 10881, 12417, 11201, 12481, 4673, 12481, 4673, 12353,
+    10881, 12417, 11201, 12481, 4673, 12481, 4673, 12353, 10881, 12417, 11201, 12481,
+    4673, 12481, 4673, 12353, 10881, 12417, 11201, 12353, 10881, 12417, 11201, 12353,
+    10881, 12417, 11201, 1473, 1473, 1473, 1473, 12353, 12417, 12353, 12417, 12353,
+    12417, 12353, 12417, 12353, 12417, 12353, 12417, 1473, 1473, 12481, 4673, 12737,
+    12737, 12737, 2177, 2177, 2177, 2177, 2177, 10881, 10881, 10881, 10881, 5386832,
+    301644, 5387852, 5388622, 5389517, 5390353, 5391446, 5392837, 5393161, 5393751, 5395206, 5395589, 5395910,
+    5396301, 5397140, 5398410, 5399051, 5399754, 5400400, 5401425, 5402512, 5403531, 5404239, 5405189, 5405518,
+    5406411, 5407113, 5407694, 5408591, 5409548, 5410314, 5410958, 5411853, 5412688, 5413710, 5414607, 5415567,
+    5416527, 5417490, 5418631, 5419079, 5419534, 5420426, 5421062, 5421453, 5422281, 5422855, 12737, 5423306,
+    5423947, 5424649, 5425228, 5425998, 5426885, 5427217, 5428302, 5429190, 5429577, 12737, 5430160, 5431184,
+    5432208, 5433229, 5434069, 5435406, 5436297, 5436873, 5437452, 5438218, 5438856, 5439369, 5439942, 5440327,
+    5440775, 5441231, 5442188, 5442964, 5444242, 5445380, 5445643, 5446344, 5446858, 5447497, 5448073, 5448655,
+    5449612, 5450379, 5451088, 5452111, 5453064, 5453577, 5454148, 5454408, 5454924, 5455689, 5456268, 5457036,
+    5457798, 5458186, 5458822, 5459231, 5461197, 5462039, 5463502, 5464395, 5465097, 5465674, 5466310, 5466703,
+    5467672, 5469198, 5470085, 5470409, 5470984, 5471500, 5472266, 5472901, 5473221, 5473545, 5474119, 5474568,
+    5475078, 5475461, 5475780, 5476038, 5476420, 5476681, 5477258, 5477893, 5478216, 5478727, 5479179, 5479879,
+    5480326, 5480712, 5481222, 5481609, 5482185, 5482758, 5483144, 5483657, 5484230, 5484616, 5485133, 5485959,
+    5486408, 5486920, 5487433, 5488008, 5488519, 5488965, 5489288, 5489797, 5490118, 5490502, 5490890, 5491526,
+    5491913, 5492487, 5492935, 5493382, 5493765, 5494090, 5494733, 5495561, 5496139, 5496837, 5497156, 5497413,
+    5497734, 5498122, 5498765, 5499596, 5500373, 5501717, 5503058, 5504207, 5505167, 5506119, 5506568, 5507077,
+    5507405, 5508231, 5508678, 5509064, 5509575, 5510023, 5510469, 5510793, 5511371, 5512070, 5512464, 5513488,
+    5514508, 5515277, 5516102, 5516486, 5516876, 5517647, 5518613, 5519949, 5520777, 5521359, 5522309, 5522630,
+    5523016, 5523530, 5524164, 5524422, 5524807, 5525254, 5525645, 5526475, 5527174, 5527559, 5528005, 5528326,
+    5528717, 5529548, 5530311, 5530762, 5531403, 5532100, 5532360, 5532871, 5533330, 5534472, 5534983, 5535431,
+    5535880, 5536398, 5537287, 5537733, 5538059, 5538763, 5539462, 5539847, 5540302, 5541189, 5541511, 5541960,
+    5542477, 5569, 15169, 6465, 7169, 3649, 2113, 3649, 4609, 3649, 5569, 15169,
+    6465, 7169, 3649, 2113, 5633, 10945, 6529, 7105, 4609, 7873, 5569, 15169,
+    6465, 7169, 3649, 2113, 3649, 4609, 3649, 5569, 15169, 6465, 7169, 3649,
+    2113, 5633, 10945, 6529, 7105, 4609, 7873, 5569, 15169, 6465, 7169, 3649,
+    2113, 3649, 4609, 3649, 5569, 15169, 6465, 7169, 3649, 2113, 5633, 10945,
+    6529, 7105, 4609, 7873, 5569, 15169, 6465, 7169, 3649, 2113, 3649, 4609,
+    3649, 3457, 4417, 3457, 3457, 4417, 3457, 5543298, 5543426, 5543554, 4785218, 40770,
+    61314, 4929, 3201, 3457, 5057, 6465, 3393, 6593, 4993, 1089, 4417, 5121,
+    6529, 4353, 6657, 5543693, 5544531, 5545739, 5546439, 5546893, 5547729, 5548811, 5549515, 5550222,
+    5551121, 5552204, 5552966, 5553351, 5553798, 5554181, 5554506, 5555146, 5555787, 5556487, 5556942, 5557832,
+    5558342, 5558725, 5559047, 5559502, 5560390, 5560774, 5561156, 5561418, 5562054, 5562448, 5563466, 5564106,
+    5564741, 5565070, 5565964, 5566727, 5567173, 5567495, 5567943, 5568392, 5568910, 5569797, 5570119, 5570571,
+    5571275, 5571978, 5572616, 5573125, 5573452, 5574228, 5575500, 5576262, 5576657, 5577738, 5578375, 5578820,
+    5579077, 5004935, 5579402, 5580045, 5580869, 5581192, 5581702, 5582086, 5582475, 5583183, 5584137, 5584714,
+    5585349, 5585678, 5586566, 5586961, 5588038, 5588431, 5589389, 5590224, 5591250, 5592396, 5593158, 5593543,
+    5593996, 5594764, 5595526, 5595914, 5596551, 5596999, 5597447, 5597903, 5598854, 5599236, 5599500, 5600264,
+    5600783, 5601737, 5602317, 5603150, 5604072, 5606614, 5608025, 5609617, 5610699, 5611400, 5611917, 5612762,
+    5614441, 5617040, 5618063, 5619023, 5619981, 5620829, 5622668, 5623447, 5624920, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 641, 641, 641, 641, 641, 641, 1473, 1473, 1473, 1473, 1473,
+    1473, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833, 32833,
+    32833, 32833, 32833, 32833, 32833, 32833, 12865, 12865, 4673, 12481, 4673, 12481,
+    12865, 12865, 12865, 12865, 12865, 12865, 12865, 12865, 12865, 12865, 12865, 12865,
+    12865, 12865, 12865, 1473, 10881, 11201, 12417, 12353, 12353, 12353, 12417, 11201,
+    10881, 242369, 57153, 32833, 57153, 32833, 32833, 32833, 12865, 12417, 12417, 12417,
+    3137, 12737, 12737, 12737, 12737, 12737, 10881, 34945, 2305, 1857, 1921, 12929,
+    8641, 7233, 35009, 35073, 35137, 480258, 471490, 609859, 637187, 586691, 466179, 462979,
+    473922, 472451, 4584709, 5626437, 464642, 632196, 681475, 475202, 482116, 473090, 5626755, 5626947,
+    5627139, 650243, 5627331, 5627522, 5627652, 460418, 469378, 600899, 477508, 470658, 662339, 5627908,
+    661123, 477315, 5627331, 5628164, 5628419, 611587, 592387, 5628612, 5628868, 462979, 634371, 636035,
+    5629123, 662339, 5629123, 609859, 1149570, 607300, 5629316, 471490, 5629572, 629508, 5629828, 5629572,
+    5630085, 5630405, 616260, 17282, 594435, 460867, 1174082, 482371, 475202, 639874, 468099, 661315,
+    286211, 623812, 482564, 647555, 647555, 470981, 470787, 476803, 472067, 5630725, 645892, 667077,
+    626115, 465987, 5631042, 5631170, 5631298, 5631428, 482564, 5631683, 5631170, 625284, 5631170, 465987,
+    5631874, 469378, 594884, 472451, 460867, 603076, 613826, 472451, 639428, 620228, 667396, 606596,
+    5632003, 472642, 472642, 472642, 287171, 5632195, 5632387, 637187, 446018, 466498, 632003, 17282,
+    5632579, 5629316, 5632773, 649731, 5633091, 5632387, 469187, 460867, 17282, 688323, 688323, 688323,
+    688323, 597251, 905861, 471490, 613826, 473794, 473219, 589315, 5633284, 628485, 5633284, 688323,
+    479300, 5633539, 591746, 597251, 591171, 469187, 478659, 617539, 5633731, 665218, 473794, 467139,
+    5633924, 641218, 591171, 462338, 675523, 667077, 591171, 5634179, 631619, 464642, 476356, 650243,
+    5634370, 5634499, 587075, 611587, 619010, 665218, 589123, 462979, 476611, 478981, 635588, 609283,
+    5634691, 641539, 684356, 461956, 619395, 5634885, 590595, 5635203, 630723, 478659, 5635395, 461060,
+    465603, 591171, 633347, 637636, 17282, 652226, 589123, 473410, 602754, 599043, 667396, 5632387,
+    5635588, 5635843, 5636036, 5636292, 473794, 599683, 474946, 445890, 606466, 604867, 634242, 5636550,
+    590595, 484292, 620035, 601795, 615426, 464642, 466628, 471490, 460418, 656708, 5636933, 5637251,
+    5637444, 596996, 471299, 617539, 5637700, 481412, 474563, 649925, 595907, 459460, 679429, 477763,
+    286787, 472451, 477508, 478659, 474434, 597444, 474563, 602563, 656964, 654212, 480964, 635332,
+    466498, 617987, 598788, 481219, 464642, 473922, 633155, 474563, 620035, 464898, 609859, 468802,
+    445890, 467331, 473410, 633347, 477508, 638148, 629508, 634051, 460418, 648771, 5637955, 476356,
+    595269, 624836, 633155, 633155, 461571, 462338, 5638147, 654466, 5638340, 589892, 666053, 640003,
+    286402, 617347, 474755, 610884, 818438, 468802, 587907, 460418, 477315, 906499, 480771, 689540,
+    599875, 466628, 467139, 474563, 5638595, 5638787, 5634499, 663491, 620035, 461571, 609474, 17282,
+    689155, 612611, 619010, 17282, 465348, 623363, 645507, 468099, 5638979, 5639171, 631811, 635588,
+    5638979, 5639363, 5638595, 470658, 5639556, 674564, 472451, 471490, 464642, 5632387, 656708, 665027,
+    601986, 481666, 676675, 460867, 615298, 462595, 466628, 461571, 905667, 590595, 614340, 461060,
+    630212, 464642, 619010, 5639811, 1149570, 614340, 599043, 651717, 656708, 588292, 593093, 646790,
+    17282, 514565, 628228, 286211, 601540, 5632003, 5640005, 669828, 611395, 469763, 17282, 629764,
+    5640324, 482820, 460867, 477763, 628996, 473410, 595138, 609474, 603780, 589507, 5640580, 592195,
+    467331, 477123, 629764, 629508, 661123, 460547, 661123, 647363, 466628, 592387, 470211, 471490,
+    619588, 460418, 589315, 514565, 629508, 5640836, 5641092, 471812, 461571, 629508, 5641348, 630916,
+    604035, 608772, 481797, 592899, 592899, 601540, 482820, 629508, 5640324, 655043, 461060, 5641604,
+    623042, 461763, 603780, 477508, 468932, 470082, 462338, 5641860, 468802, 638596, 675523, 476611,
+    643652, 459460, 626115, 588868, 679172, 5642114, 5642243, 5642436, 470404, 615811, 635332, 681475,
+    600067, 617539, 609474, 5642693, 679172, 4585028, 675012, 599875, 17282, 614340, 676675, 632452,
+    5643013, 5642114, 606020, 598595, 468802, 591877, 605253, 631619, 460418, 460867, 623363, 462979,
+    5643331, 5643524, 471490, 482820, 598595, 598595, 592387, 474563, 473410, 478981, 5643779, 5643970,
+    5644100, 5644355, 478981, 672708, 607555, 483844, 473794, 648516, 459460, 460418, 473794, 607555,
+    637187, 620483, 462595, 5644548, 635332, 5631683, 5629123, 632003, 613316, 5644803, 471490, 477508,
+    514565, 477955, 623812, 654466, 650243, 606596, 628228, 460101, 463620, 636484, 593093, 608579,
+    464642, 466884, 463620, 609474, 465987, 474755, 461571, 461316, 628996, 633540, 629764, 5644997,
+    474051, 5645316, 473922, 5638147, 589507, 5645573, 476803, 5638595, 481797, 606020, 476611, 465987,
+    5645891, 5646084, 5646339, 688323, 607555, 460101, 589315, 688323, 624836, 480258, 675523, 631619,
+    586691, 589315, 603780, 636484, 603780, 460418, 596484, 479556, 636035, 597251, 468932, 682243,
+    5646533, 620483, 4585028, 668228, 668228, 652866, 597251, 589507, 593411, 464388, 5644100, 635332,
+    459715, 617732, 601284, 623812, 5646851, 17282, 478404, 633347, 592195, 616966, 482564, 460738,
+    633155, 594884, 607555, 482564, 629508, 619588, 669380, 606596, 648069, 599875, 629508, 484099,
+    473794, 474563, 5647043, 665218, 631811, 286402, 286402, 636932, 480258, 466884, 467139, 477763,
+    462979, 669828, 286532, 474051, 633347, 462338, 468293, 623812, 677955, 445890, 477763, 465987,
+    469187, 617732, 463427, 5638595, 460867, 5647236, 647171, 220802, 478659, 5647492, 626307, 286402,
+    474563, 614340, 460738, 468293, 5647747, 630723, 480258, 631619, 483844, 5647938, 603076, 472642,
+    465987, 609474, 17282, 646595, 640003, 623171, 481412, 445890, 475780, 645507, 603076, 632003,
+    460418, 649925, 17282, 474243, 462338, 476994, 473090, 5648068, 622339, 614340, 478659, 592195,
+    471619, 653124, 476037, 286402, 623042, 616708, 473410, 5648323, 5648516, 459460, 286402, 465987,
+    642308, 621379, 603780, 467139, 464388, 651203, 615298, 287171, 286532, 650691, 17282, 465987,
+    591746, 466884, 470658, 465987, 589892, 614340, 5648771, 611972, 286211, 599043, 473794, 607555,
+    460738, 608579, 591171, 4555075, 599875, 466179, 464770, 466498, 597700, 617987, 478659, 445890,
+    608579, 472451, 286402, 473219, 609283, 466884, 5648962, 5649092, 475587, 5649348, 5648323, 608579,
+    623363, 608579, 640003, 634371, 597444, 467139, 481797, 471619, 626115, 801218, 633155, 632708,
+    286979, 643652, 619140, 665218, 5648068, 665218, 589892, 633347, 474434, 640003, 16130, 462338,
+    474946, 286402, 634371, 624836, 476356, 614787, 286402, 5649605, 631619, 466179, 5649924, 1153219,
+    5639811, 286402, 465218, 471619, 472067, 589507, 646790, 633347, 466884, 468099, 5650181, 618178,
+    601540, 5648771, 5648323, 598595, 468099, 636035, 5648771, 649220, 682883, 463620, 472259, 610884,
+    473794, 461571, 468802, 481797, 636035, 478659, 460418, 5649605, 5650500, 481666, 610884, 620228,
+    481666, 469378, 445890, 5642243, 474434, 681667, 5650755, 472962, 621570, 616260, 614340, 477508,
+    463620, 5650947, 607300, 616515, 613826, 623171, 286402, 465795, 5651139, 606466, 651203, 5651331,
+    5648962, 597444, 286402, 606466, 620228, 5642693, 626115, 596291, 5651524, 597251, 465987, 286402,
+    621698, 653956, 286532, 480258, 473410, 464770, 588868, 601795, 5651781, 594052, 286211, 17282,
+    623556, 5652100, 615298, 17282, 665218, 5652355, 616515, 655876, 615298, 462979, 601795, 620228,
+    597700, 643076, 471299, 648516, 595907, 475202, 5652549, 628228, 601284, 612419, 459715, 601091,
+    477508, 5642436, 665218, 220802, 626307, 623363, 648516, 623812, 4578371, 5652868, 5652868, 286402,
+    623363, 474051, 608772, 472451, 467331, 461571, 472259, 470658, 468099, 631171, 481666, 465348,
+    468099, 615298, 5653124, 629508, 636484, 626115, 470658, 630723, 461571, 473219, 600899, 478404,
+    481412, 5647747, 602563, 619010, 656452, 614147, 606466, 611972, 625091, 5653379, 473219, 468802,
+    465987, 601795, 445890, 5653571, 595269, 5653571, 481412, 609283, 473219, 17282, 801218, 17282,
+    17282, 477955, 592770, 609604, 5653764, 5638595, 592770, 459715, 623812, 473794, 464388, 642308,
+    603076, 459715, 469378, 5654020, 3457, 469187, 668484, 603076, 5654275, 3457, 609474, 686276,
+    464388, 3457, 220802, 478404, 462338, 5654468, 17282, 650243, 466370, 459715, 679042, 462979,
+    601986, 5654723, 473410, 459715, 603780, 473794, 607555, 641347, 592770, 632003, 286211, 473794,
+    474563, 637380, 5654916, 466370, 460418, 653124, 476611, 617732, 5655173, 5655492, 5655748, 612804,
+    5656004, 465027, 473410, 5644100, 464642, 5656260, 5656515, 471490, 480258, 611587, 656452, 471619,
+    479938, 660612, 474051, 636739, 612227, 599875, 642308, 632003, 591746, 474051, 471619, 613316,
+    614595, 589315, 472451, 474755, 468932, 679429, 606020, 460418, 473410, 473794, 594628, 460418,
+    5656709, 600899, 628996, 474051, 5657029, 445890, 462595, 627588, 464770, 621570, 17282, 609474,
+    609859, 633347, 592770, 465027, 462595, 466884, 648771, 462979, 801218, 17282, 612419, 643652,
+    617987, 615298, 636035, 17282, 672708, 286402, 608772, 473794, 473410, 482820, 620035, 286402,
+    633155, 5644803, 592195, 470211, 615298, 17282, 473794, 476803, 615298, 481219, 618690, 464770,
+    591362, 462595, 619588, 5657347, 286402, 468802, 589507, 472451, 614340, 5646339, 5646339, 608772,
+    286402, 5657539, 474434, 466628, 480067, 609474, 628485, 464642, 606852, 286211, 616515, 474434,
+    626691, 460418, 471490, 475780, 636484, 609474, 479938, 662788, 473410, 5657733, 482116, 596484,
+    5658051, 286532, 479938, 479938, 601986, 665218, 468802, 5658243, 631619, 627588, 626499, 483844,
+    473794, 460867, 468099, 477508, 473922, 603780, 471619, 603780, 5658435, 474755, 623363, 286402,
+    594052, 482820, 619843, 473410, 469763, 639298, 465027, 650436, 445890, 17282, 466498, 471619,
+    478659, 474563, 660612, 5645891, 474051, 479938, 460418, 647363, 597444, 465348, 637636, 476994,
+    460418, 471812, 3649, 601986, 476037, 592770, 462338, 459907, 5658627, 590595, 462466, 462787,
+    460418, 17282, 601795, 463427, 478659, 640003, 464770, 639874, 632003, 460867, 618819, 614340,
+    599875, 605059, 623363, 462787, 476994, 464770, 685123, 478148, 462979, 605253, 462595, 466370,
+    482820, 660612, 660612, 684356, 5658820, 475202, 5659076, 5659331, 5659524, 5659779, 5659973, 5629316,
+    17282, 474563, 468099, 625860, 668740, 586691, 5660291, 465027, 623363, 622722, 634242, 480258,
+    611587, 467139, 5660484, 462979, 466370, 3457, 590148, 5660739, 5660931, 5661125, 462979, 589507,
+    641539, 462787, 614595, 622722, 624836, 445890, 479938, 5631683, 670275, 5661443, 5661637, 5661956,
+    674564, 5662212, 5662467, 5662659, 481666, 5642114, 5662852, 5663108, 464642, 477315, 470211, 461956,
+    633347, 641218, 481412, 471812, 459460, 682883, 469378, 601795, 620228, 601986, 480771, 3457,
+    649925, 460418, 3457, 594435, 654466, 630723, 5643970, 638148, 627140, 589315, 638403, 477763,
+    5663363, 618178, 459907, 627588, 459715, 619588, 17282, 17282, 615298, 17282, 599875, 514565,
+    476611, 5663556, 626307, 589699, 5663812, 5637251, 631811, 5664069, 5664388, 5664643, 603780, 476994,
+    588099, 609859, 597251, 460418, 17282, 17282, 468802, 663044, 663044, 479300, 671171, 609283,
+    619588, 480258, 480258, 477508, 608579, 473922, 652866, 627588, 5664834, 5652355, 467331, 590148,
+    5664964, 5665219, 5665411, 5656515, 5637444, 605059, 590979, 472451, 5665603, 603780, 615298, 615426,
+    589507, 474434, 646790, 598595, 640388, 593093, 469187, 475074, 465987, 642115, 5659973, 5642114,
+    5665796, 5666053, 5652100, 5666370, 637636, 5666499, 228738, 5666692, 5646339, 5666950, 5667331, 5634179,
+    680898, 5667524, 479938, 5667780, 605572, 615298, 5668036, 632003, 5668293, 461060, 478981, 618819,
+    5668611, 5668805, 465027, 662788, 460418, 460418, 473794, 481219, 220802, 466370, 602754, 461060,
+    647171, 479938, 471490, 478850, 641539, 460547, 654466, 632452, 5669123, 682243, 619588, 5669315,
+    460418, 5669508, 470082, 5669763, 5669955, 5670147, 5670340, 601540, 466498, 462338, 286532, 639298,
+    480258, 661955, 464770, 481666, 650691, 478659, 460418, 622722, 638148, 478404, 459715, 623556,
+    473090, 628228, 629764, 5670596, 463427, 5670850, 5670980, 5656515, 5671235, 5668805, 1149570, 5671428,
+    5671683, 5671877, 481666, 627588, 4584515, 5672195, 616515, 5672388, 5672642, 5661125, 5672774, 3841,
+    670275, 5673155, 5661956, 5641604, 604420, 5673347, 671619, 5673539, 3201, 5673733, 665027, 5671235,
+    5674053, 5674370, 5664643, 5640580, 606466, 470787, 5674501, 1163652, 5660739, 608772, 473794, 474946,
+    630468, 484099, 5674820, 603076, 599043, 615298, 17282, 472451, 480258, 460418, 480258, 619395,
+    644099, 477508, 462787, 656452, 615298, 465027, 462979, 470658, 614979, 478148, 618819, 460418,
+    602754, 589892, 618690, 603780, 622722, 601540, 461763, 482820, 591746, 470082, 460867, 614340,
+    5665796, 5658820, 5661637, 3457, 479938, 659332, 639874, 661315, 474434, 597444, 5675075, 5675267,
+    620035, 5675459, 476994, 5675651, 5675844, 670083, 5676100, 464195, 591171, 620228, 5676355, 5676547,
+    640388, 655043, 5676739, 5676739, 624324, 5676931, 480067, 473410, 5677123, 5677316, 619588, 5677572,
+    615298, 5677828, 5678084, 5678339, 459907, 5642243, 4581379, 5678531, 483333, 590787, 476994, 479938,
+    589315, 286532, 464642, 641218, 5671683, 5678724, 5678979, 286787, 478850, 661955, 459715, 619588,
+    591171, 590787, 466179, 460418, 665604, 479300, 461060, 611140, 461571, 476994, 601284, 473219,
+    629508, 5649924, 591746, 637187, 589892, 466628, 594435, 469187, 474946, 464770, 5679170, 5672642,
+    5679299, 5679491, 611587, 5638979, 464388, 686531, 5675651, 598595, 5679684, 684931, 5672195, 5679939,
+    5680132, 484099, 5680387, 616260, 473794, 620931, 592195, 464898, 603331, 609028, 639683, 632003,
+    476994, 621698, 612804, 614979, 479938, 5680132, 5680580, 5659524, 462466, 5680836, 5681091, 480258,
+    680260, 5662467, 5681284, 5681540, 608324, 629508, 5681795, 677763, 5681988, 5682244, 5682501, 668035,
+    462466, 614787, 5682819, 5683011, 5629828, 5639556, 5683205, 680898, 5683525, 5683844, 459715, 5684100,
+    5684355, 5684548, 462787, 689986, 5676100, 5684805, 5650947, 616260, 467522, 599043, 5668805, 5641092,
+    286402, 5645316, 480258, 665218, 463172, 482564, 638852, 471619, 620228, 599043, 467139, 463620,
+    611140, 470082, 607555, 626691, 650691, 474434, 620228, 609028, 637636, 663491, 475202, 668035,
+    647746, 480067, 589699, 5671683, 5685124, 598595, 610499, 462338, 286402, 3201, 601091, 474563,
+    589892, 648069, 466628, 673348, 620483, 17282, 690373, 5638787, 656708, 588738, 638852, 5685380,
+    616515, 5685638, 5658820, 651011, 5637444, 650882, 476611, 591171, 460418, 689795, 5686020, 640388,
+    601795, 679748, 5686274, 591171, 5686403, 5670980, 5686597, 5686916, 5687171, 5687364, 5670980, 5687619,
+    5687811, 5688004, 5688259, 5662852, 674819, 686531, 612419, 665604, 5683844, 5688452, 5688708, 5688964,
+    5652100, 588868, 5689219, 481797, 5689411, 17282, 651011, 602115, 599875, 623363, 5689603, 459715,
+    476803, 473410, 634242, 612419, 641218, 639298, 609474, 466628, 592579, 464642, 656133, 639874,
+    634371, 640003, 481666, 620931, 470082, 640003, 286402, 477315, 589892, 638403, 622722, 480258,
+    481219, 471812, 5689219, 5671428, 5689796, 661123, 471490, 5690054, 477763, 5690435, 468099, 620228,
+    480067, 638852, 615170, 591746, 468099, 5690626, 476037, 462595, 627588, 5690755, 635588, 5643331,
+    5690947, 5686916, 5691140, 5691396, 5691652, 5691908, 5692164, 588868, 634051, 473922, 5692419, 5671683,
+    679748, 470787, 589315, 465987, 460418, 462595, 466628, 459715, 5692611, 601986, 599875, 630212,
+    481666, 638403, 5692804, 470658, 609283, 611587, 475780, 618690, 460418, 594052, 614340, 17282,
+    617987, 602563, 611395, 5693060, 5693315, 5693507, 5693698, 5693828, 5694083, 5694276, 670275, 5694531,
+    5694724, 5677828, 5694981, 5694276, 5646084, 5629316, 630916, 5652355, 594884, 481412, 470658, 479938,
+    462979, 597444, 621698, 5633091, 667843, 467331, 5695300, 5695557, 616515, 606466, 5665796, 689986,
+    5695875, 479938, 5647043, 5634179, 5639811, 639683, 655043, 5696067, 5696262, 5641092, 626307, 664643,
+    464388, 5696642, 5696773, 5683844, 5697091, 474563, 589315, 469378, 3457, 470658, 459715, 469378,
+    599043, 467844, 617539, 474563, 597444, 477123, 478850, 615426, 464642, 5693698, 609283, 651203,
+    603780, 639874, 5697284, 602115, 5697539, 618690, 588868, 5697731, 5697923, 626884, 286211, 460418,
+    618690, 5698116, 460418, 473794, 610884, 655620, 476611, 680707, 5698373, 5698693, 680898, 5683844,
+    588547, 5699012, 634371, 5699268, 653826, 5699524, 5699780, 686531, 599683, 5700035, 5700227, 5700419,
+    5700227, 606275, 5662467, 464642, 461763, 5700611, 5700803, 651203, 632003, 474563, 462979, 465603,
+    631811, 469187, 625091, 471619, 597444, 477315, 477315, 473219, 609859, 477763, 474563, 651203,
+    621570, 632708, 640003, 679748, 679172, 601795, 674564, 5700995, 5640580, 592387, 5666053, 5701189,
+    5701508, 220802, 624580, 589315, 460418, 631811, 597444, 649220, 480067, 5701763, 611395, 5627139,
+    5644100, 5701957, 5648516, 5702276, 5702531, 591171, 5702725, 5703043, 479938, 5671428, 605572, 613826,
+    5703235, 5703428, 5660291, 459907, 630916, 5665411, 620483, 471812, 473794, 5703684, 592387, 631364,
+    470211, 482116, 676228, 604227, 286402, 592770, 480964, 5703940, 674564, 5704196, 5637251, 5704452,
+    5699268, 615170, 5704707, 468932, 5704899, 665859, 674819, 682435, 466628, 460547, 466628, 468932,
+    589315, 597444, 5705090, 640003, 481219, 465218, 616260, 5705220, 5705475, 674564, 5697539, 5677828,
+    5705667, 5705860, 599683, 5686597, 459907, 626499, 5706115, 623042, 472962, 601986, 463427, 17282,
+    609474, 637380, 17282, 5693315, 616515, 479938, 617732, 626691, 3457, 626884, 623812, 5706307,
+    674564, 657539, 5706499, 5706692, 5706948, 5640836, 631811, 5707204, 5707461, 5641604, 601540, 473794,
+    637636, 615170, 630723, 5681540, 3457, 665859, 597251, 607939, 5707780, 5708035, 5708230, 5703940,
+    5708612, 662788, 689986, 616260, 5708870, 462979, 466628, 5694724, 610884, 460418, 616260, 688517,
+    5641604, 5709251, 5709444, 626115, 599043, 5709699, 5709891, 5669123, 5709891, 477315, 5629316, 635332,
+    5640836, 615170, 663491, 5710084, 470082, 5654468, 619395, 607939, 465987, 462595, 461571, 646790,
+    616515, 286402, 676675, 607939, 607939, 462466, 462595, 474051, 476803, 617987, 674564, 286532,
+    465218, 597251, 286532, 5702531, 587075, 459715, 640388, 480258, 460418, 640003, 459460, 469187,
+    472771, 474434, 623556, 589507, 464388, 599683, 679429, 614787, 619588, 674564, 480258, 600707,
+    653124, 464642, 5710340, 466498, 626115, 641731, 472451, 220802, 5710598, 464388, 474563, 620228,
+    462338, 461763, 474946, 643652, 643652, 665218, 474051, 684356, 462338, 628485, 5710598, 478404,
+    600067, 472067, 464642, 5710598, 612419, 482820, 474051, 476803, 478404, 474051, 480771, 5644100,
+    606596, 286532, 665027, 1149570, 615170, 614787, 445890, 460418, 621124, 474946, 619843, 615426,
+    602115, 623812, 475202, 470981, 17282, 818438, 686531, 473219, 471619, 462466, 470658, 460867,
+    462979, 670916, 667396, 5700803, 5710979, 5647747, 17282, 650691, 650691, 665218, 477763, 5711172,
+    594306, 464770, 668228, 622722, 614979, 481412, 586691, 474051, 626691, 607107, 624836, 465218,
+    638596, 681475, 5639556, 5653571, 5628868, 17282, 624836, 476803, 651203, 595269, 5700995, 5711428,
+    635843, 5652355, 5633731, 599875, 460418, 483651, 642564, 596484, 607747, 5711684, 474051, 634371,
+    639298, 476803, 464642, 467331, 479938, 5653379, 802050, 5711939, 617539, 5712131, 652866, 672003,
+    630212, 634371, 461763, 471619, 5646339, 5712323, 674819, 647171, 602563, 469763, 602754, 653124,
+    621379, 470211, 474563, 675972, 637636, 4555075, 5712516, 629508, 595907, 466370, 631619, 467651,
+    5637700, 649731, 5676931, 5670147, 5712771, 5707780, 5683011, 17282, 648386, 647555, 619395, 601540,
+    616260, 606466, 471490, 652866, 639428, 474946, 596484, 474563, 655876, 625860, 606466, 471619,
+    612227, 634242, 623171, 618690, 597444, 478148, 657539, 5633091, 5633731, 5712966, 588868, 5632773,
+    665218, 630916, 5639556, 801218, 630916, 630916, 623363, 5713348, 5713605, 595138, 641218, 605572,
+    465027, 629508, 597700, 641218, 474563, 599875, 471812, 667077, 612419, 474563, 473794, 648069,
+    5676931, 619588, 5713925, 469954, 5686274, 5714245, 5714564, 5714819, 662339, 286211, 468802, 466884,
+    668740, 480067, 651203, 594306, 5665219, 627843, 647171, 611587, 641539, 626307, 638852, 476803,
+    614340, 5715011, 5715203, 670275, 5641348, 468802, 612611, 602563, 609859, 601986, 446146, 5715394,
+    480258, 474946, 469378, 628485, 605253, 286211, 465603, 286787, 665859, 5672388, 659844, 639107,
+    661123, 5715525, 474563, 621698, 598595, 474755, 618690, 621698, 5715843, 607747, 5633731, 466628,
+    5716036, 5716291, 5629123, 5716291, 655427, 5681284, 648069, 483076, 616966, 464642, 601986, 464642,
+    598595, 464642, 477508, 445890, 651203, 477763, 614340, 617347, 5670147, 460547, 600707, 479556,
+    5716483, 655043, 5681795, 5711428, 5674501, 629764, 609474, 475332, 667077, 5649924, 599875, 480067,
+    588292, 5652355, 5637700, 5693698, 5692419, 5653571, 5681795, 621379, 5628612, 651203, 5632387, 648771,
+    631811, 476803, 598595, 466884, 463427, 5716675, 5716869, 5632003, 5717188, 5648516, 617987, 480964,
+    610884, 626115, 465218, 630916, 5672388, 602563, 621379, 617539, 5700995, 476803, 607939, 480771,
+    5639556, 5705475, 635843, 598595, 476356, 647555, 606596, 461571, 461316, 460867, 622531, 477763,
+    469187, 464642, 5717444, 610884, 661955, 675012, 589892, 587075, 445890, 637187, 475332, 588868,
+    589892, 620228, 628228, 480258, 594628, 642564, 646595, 649925, 463620, 597251, 622339, 630723,
+    591171, 590787, 594628, 459460, 601795, 629508, 5703428, 624836, 476803, 629508, 474563, 653124,
+    590787, 482564, 595138, 5717699, 618690, 471619, 5708035, 603331, 474563, 5628868, 471490, 622339,
+    612227, 5629123, 5715394, 589507, 475202, 5717893, 5717893, 649731, 674564, 5710979, 614340, 5718213,
+    465795, 632708, 473794, 587075, 470211, 621124, 460867, 460867, 473794, 621698, 5718532, 470082,
+    623363, 617539, 589315, 617539, 616260, 470404, 473794, 645315, 593796, 623042, 472067, 632003,
+    473794, 608579, 637636, 640003, 688323, 5643779, 648516, 612611, 628228, 612611, 464388, 473794,
+    466884, 464388, 474563, 479556, 631171, 648771, 475202, 623556, 460867, 653826, 5718213, 612611,
+    477315, 638403, 5718788, 5719044, 467139, 473410, 5709251, 5719300, 611972, 467139, 469187, 616260,
+    626307, 615170, 476356, 617539, 629764, 480771, 609474, 5643779, 459460, 616260, 591362, 478850,
+    472451, 906370, 906370, 637187, 471619, 473090, 465987, 609474, 684739, 628485, 638596, 474946,
+    462979, 626307, 633540, 688323, 460418, 469378, 648964, 617347, 593796, 473410, 464770, 461571,
+    609474, 616515, 647363, 462595, 609859, 464770, 5719555, 459460, 5719747, 641539, 589315, 589123,
+    662532, 607107, 469378, 617539, 478659, 466370, 472962, 601795, 480579, 470981, 596099, 602883,
+    472962, 477955, 609474, 286211, 599875, 473794, 622531, 601795, 17282, 640003, 460418, 483651,
+    474051, 462210, 588868, 645892, 5654275, 5650947, 611587, 623042, 640003, 470404, 609859, 634371,
+    470404, 474755, 5632579, 5634370, 228738, 659844, 634371, 593093, 461571, 606466, 461316, 636484,
+    465218, 628803, 588099, 473794, 461571, 629508, 670083, 650243, 460418, 462787, 5703684, 617539,
+    475202, 638596, 616515, 5643970, 609474, 680260, 589315, 603331, 653956, 623042, 478981, 592579,
+    466370, 615298, 614340, 618819, 595269, 628228, 468802, 636739, 641218, 621698, 592770, 636228,
+    668228, 613572, 668228, 593411, 635843, 638596, 604675, 476994, 618819, 615426, 462979, 667396,
+    603780, 459460, 683269, 622531, 623812, 466884, 623363, 461060, 635332, 619010, 475780, 589315,
+    476356, 603331, 599236, 650882, 477315, 648386, 475202, 5719939, 675523, 604675, 474243, 642308,
+    462979, 464770, 675012, 662788, 626307, 474563, 465348, 633540, 473410, 665218, 468802, 5720131,
+    633540, 5720324, 463877, 600707, 597955, 5670147, 5688004, 474563, 662532, 482371, 633155, 470404,
+    648516, 465348, 470981, 5645891, 471299, 605059, 597700, 665218, 668740, 470658, 656452, 460738,
+    675268, 473922, 638403, 462595, 610499, 637380, 602308, 635588, 623812, 5720579, 627843, 619010,
+    661123, 609474, 620228, 598788, 620035, 679429, 633796, 587907, 626115, 674564, 643652, 593603,
+    445890, 599875, 471490, 630723, 587907, 665218, 603780, 591362, 618690, 659844, 590595, 659332,
+    468099, 461571, 228738, 5643970, 474755, 665859, 471619, 641218, 633155, 464195, 477763, 659332,
+    459715, 632003, 634821, 611395, 685828, 5720772, 461571, 592899, 470787, 5720324, 462979, 643652,
+    605059, 460867, 589315, 614595, 4449411, 467139, 461060, 682628, 633796, 616515, 611140, 589315,
+    286402, 617539, 5633731, 625541, 591492, 5671683, 5671683, 5719939, 465027, 654466, 679429, 460418,
+    602563, 5718532, 5627522, 599043, 670083, 608579, 626115, 625284, 477763, 593093, 17282, 475202,
+    626307, 466884, 627140, 599875, 5721028, 461060, 592770, 618819, 5639556, 1149570, 460418, 5721283,
+    5627331, 641218, 5703684, 641218, 16130, 602308, 5721475, 5716291, 445890, 638148, 801218, 286402,
+    649925, 471812, 591746, 611587, 595269, 468802, 653826, 642115, 470082, 460418, 471490, 473794,
+    610244, 472642, 5670147, 477763, 633540, 461060, 464770, 5657539, 591171, 460418, 5649924, 5701957,
+    461060, 5633731, 623812, 642115, 624836, 613572, 475332, 475332, 477763, 461060, 473219, 478850,
+    625091, 468802, 617347, 466370, 613316, 465027, 653124, 470211, 5629572, 5633731, 462787, 642115,
+    470981, 634371, 612419, 445890, 461571, 5629572, 650691, 647171, 469508, 445890, 464642, 5706115,
+    599683, 607300, 460867, 591877, 637892, 636739, 611587, 641923, 5721667, 588099, 478981, 464642,
+    616260, 655876, 626115, 611395, 641539, 630212, 637892, 476803, 614979, 633347, 460867, 473794,
+    625860, 637636, 465348, 461571, 637636, 220802, 634242, 589507, 611140, 3457, 475202, 3457,
+    634242, 5639171, 463427, 649220, 5653571, 591877, 460418, 594435, 471490, 514565, 591362, 460418,
+    471490, 648516, 637892, 5721859, 481666, 634051, 480258, 624836, 607555, 5722051, 598595, 445890,
+    286402, 462979, 612804, 641218, 614340, 468802, 472962, 591877, 470211, 460418, 629764, 468802,
+    5644997, 612227, 464642, 466884, 629764, 471812, 463877, 465218, 17282, 471812, 459715, 641218,
+    465603, 681220, 592387, 464642, 590787, 462338, 482820, 482820, 478404, 466628, 600515, 5722243,
+    482820, 623556, 639298, 672708, 679429, 632003, 624836, 688837, 5658051, 614979, 599875, 5687619,
+    610884, 480067, 651203, 589315, 657539, 5674820, 5672642, 466628, 461956, 622531, 5674820, 5627652,
+    670723, 5722436, 662788, 637380, 5722691, 5719044, 5722436, 588099, 468293, 5722436, 5722884, 5723139,
+    5687619, 5674820, 5637251, 5687619, 5723331, 5723523, 5672642, 5674820, 605253, 20610, 5723717, 5723523,
+    5724036, 665218, 636739, 472962, 5721475, 622531, 474563, 5723523, 5672642, 5672642, 5672642, 466179,
+    5723523, 5672642, 5724291, 5724484, 5687619, 5719044, 5687619, 5724739, 286402, 5632003, 5723523, 5710598,
+    5710598, 663491, 5719044, 606020, 663491, 598595, 5719044, 5706948, 629508, 5724931, 461316, 604420,
+    286402, 462338, 625091, 474051, 476611, 606020, 634242, 622531, 463172, 610051, 632003, 474051,
+    463427, 599875, 594435, 461060, 475332, 1149570, 643652, 479938, 668740, 639683, 479938, 1149570,
+    642308, 654466, 466370, 619395, 599875, 589507, 16130, 5725125, 630212, 630212, 649925, 609474,
+    592387, 464388, 679429, 480771, 589315, 459907, 674564, 606466, 636484, 462979, 614340, 5725443,
+    474563, 615426, 592770, 462210, 462979, 619010, 477763, 608772, 461060, 463172, 616260, 17282,
+    648516, 474563, 5725637, 463620, 591746, 5725957, 471812, 660356, 465027, 633347, 648386, 597251,
+    623556, 619010, 615298, 466370, 445890, 468932, 5715394, 459460, 16130, 472451, 586691, 615298,
+    648069, 5634370, 675012, 630723, 5632387, 5726276, 620035, 464770, 619010, 465218, 16130, 5726532,
+    5726787, 631171, 5720324, 473410, 619010, 5634370, 642308, 611587, 5715394, 445890, 473794, 5658051,
+    5720324, 5657539, 592387, 680898, 680898, 680898, 5726979, 5643970, 5628612, 471619, 460418, 623042,
+    5720324, 470658, 623042, 623042, 286402, 5634179, 5727171, 5727363, 472451, 465027, 470658, 5727555,
+    619395, 464388, 5719044, 466498, 3457, 466370, 656708, 637636, 656708, 475202, 462338, 634371,
+    607555, 460867, 619588, 591171, 650882, 621570, 466370, 661955, 460867, 468802, 479300, 596996,
+    469508, 464898, 481666, 623812, 623042, 638852, 5712771, 676675, 461571, 615426, 473219, 622722,
+    462979, 617539, 445890, 464770, 469187, 621698, 609859, 476611, 595589, 474755, 597700, 614979,
+    466498, 5670340, 286979, 619395, 482116, 473794, 592387, 621379, 629508, 476356, 669187, 462979,
+    477315, 286211, 648069, 464642, 470658, 650882, 592770, 470082, 469187, 467651, 616260, 612611,
+    459715, 639683, 654466, 471490, 472771, 643652, 461316, 462338, 483651, 653956, 17282, 667396,
+    461060, 468099, 470211, 466498, 474755, 640388, 594052, 474434, 614340, 5648771, 621570, 286402,
+    465027, 460867, 5634179, 16130, 589315, 595269, 466498, 445890, 476803, 474563, 286979, 475202,
+    615298, 619395, 463877, 626115, 621570, 445890, 475202, 590403, 598147, 466498, 592387, 471490,
+    599875, 471490, 465348, 474755, 471490, 470211, 5725125, 5651524, 5635588, 5725125, 607555, 3457,
+    594435, 621570, 471490, 460418, 641539, 597251, 599875, 477955, 591746, 618819, 467139, 483333,
+    623812, 466370, 634242, 459460, 625541, 621379, 480771, 465027, 597251, 474051, 471490, 462979,
+    286787, 474051, 286979, 630212, 463877, 620931, 464388, 597444, 653380, 5707461, 605827, 645507,
+    614595, 631619, 619588, 464770, 461571, 474434, 599236, 612419, 471490, 595269, 468099, 286532,
+    465027, 473794, 625860, 649925, 5634179, 679172, 474755, 5727747, 5727940, 615298, 623171, 638852,
+    668740, 473410, 665218, 465027, 459715, 464642, 17282, 469954, 471490, 472067, 587907, 628996,
+    592387, 467522, 480771, 466370, 646790, 471490, 480771, 471619, 622722, 597251, 5700227, 5728196,
+    480771, 17282, 682628, 608772, 596484, 676675, 483651, 466370, 461060, 1149570, 632708, 482564,
+    469187, 5728451, 5728644, 286211, 668740, 476803, 611972, 621698, 638403, 470082, 630723, 474563,
+    460418, 593093, 589315, 614340, 638403, 634242, 626307, 622722, 609474, 469378, 595907, 5728899,
+    5697539, 460867, 627588, 598595, 668484, 473410, 610884, 471490, 5705475, 595907, 470658, 595589,
+    665218, 480771, 625091, 286402, 649220, 674564, 480771, 3457, 597700, 472451, 484099, 484099,
+    684931, 478404, 619395, 637187, 661123, 5716675, 5705475, 614340, 466628, 465218, 619395, 630916,
+    668484, 640388, 675268, 17282, 481797, 668484, 5707461, 480771, 590787, 286402, 286402, 599043,
+    601284, 619395, 619395, 460418, 480771, 630468, 592387, 620228, 614340, 464642, 665027, 464388,
+    651203, 464642, 470981, 595907, 286787, 445890, 463427, 17282, 473794, 481666, 466884, 479300,
+    462979, 603780, 626115, 469378, 465987, 476356, 599875, 4585028, 5671235, 5644100, 675012, 472642,
+    627588, 5643779, 5654468, 639107, 906370, 5643779, 459715, 468802, 468802, 594052, 460418, 602308,
+    602308, 5729091, 5729285, 5634370, 627588, 5729604, 471490, 462979, 603076, 613955, 614340, 648771,
+    468293, 462595, 476356, 462338, 650691, 629508, 592899, 472451, 463620, 477508, 594306, 461316,
+    649925, 5729861, 470981, 460738, 465027, 473410, 668740, 514565, 468802, 474243, 594435, 478404,
+    468802, 461571, 5646339, 479938, 606466, 467331, 589315, 462979, 17282, 619588, 636035, 17282,
+    679429, 613060, 5730179, 471619, 622722, 476803, 5657733, 643652, 460101, 477955, 477508, 17282,
+    462338, 480771, 471812, 5653379, 5730179, 467331, 463427, 623812, 632196, 602754, 459715, 477508,
+    474563, 481666, 477508, 617987, 472067, 466628, 649925, 475332, 470211, 462979, 650243, 3457,
+    473090, 615170, 481412, 460418, 620035, 647171, 5653571, 5730372, 623363, 17282, 622339, 470211,
+    597444, 654788, 480258, 627588, 665218, 629764, 462338, 462787, 460101, 459715, 5730627, 633540,
+    474243, 286402, 474563, 599875, 610499, 603076, 623042, 462787, 628803, 587907, 474563, 587075,
+    483333, 609283, 628996, 636035, 630212, 466884, 591746, 472642, 641539, 626691, 474563, 5632387,
+    5676100, 5730820, 595907, 477955, 609474, 649220, 466884, 635332, 645507, 591171, 474755, 635332,
+    617987, 483844, 612804, 592770, 608772, 470658, 641218, 467139, 598595, 617347, 611587, 468611,
+    473410, 467522, 599875, 629508, 631811, 614340, 650691, 480258, 5644803, 461571, 610884, 592899,
+    599043, 643652, 5721859, 665604, 459907, 5731077, 635843, 476994, 466628, 668228, 631811, 651011,
+    612611, 5633091, 466628, 5723523, 5723523, 459460, 5723523, 5731396, 635843, 465218, 466370, 603076,
+    474051, 461571, 597700, 480964, 460418, 461571, 473922, 845573, 473922, 662788, 467139, 661123,
+    471619, 645507, 467139, 17282, 17282, 467651, 459715, 470981, 5731653, 4449411, 463172, 474755,
+    459907, 626691, 474563, 617539, 469508, 626691, 480258, 465218, 464770, 460738, 654466, 479556,
+    473090, 17282, 467139, 642308, 461571, 482564, 615298, 633540, 478404, 474051, 480067, 599236,
+    5731972, 612419, 470658, 646595, 626884, 586691, 623042, 638403, 589507, 612419, 482564, 5652355,
+    462210, 603780, 463877, 17282, 619395, 286211, 460867, 474755, 610884, 677571, 597700, 662788,
+    481219, 468099, 609474, 591171, 636739, 588099, 460418, 467139, 588099, 606466, 469187, 478148,
+    466370, 614979, 469378, 600899, 598595, 615170, 476803, 639683, 595907, 467522, 5732227, 3841,
+    469954, 473922, 474563, 599875, 483076, 599875, 611587, 467651, 5730179, 622531, 598595, 623812,
+    482564, 665218, 17282, 461571, 473794, 638403, 459460, 464642, 470658, 5727555, 639683, 469187,
+    650691, 482564, 628996, 477763, 591171, 648516, 464642, 461763, 461571, 595138, 463427, 477508,
+    469954, 631171,
+];
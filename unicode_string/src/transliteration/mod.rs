@@ -0,0 +1,65 @@
+mod transliterator;
+
+pub use self::transliterator::*;
+
+use crate::tables::transliteration::{
+    LENGTH_BITS, TRANSLITERATIONS, TRANSLITERATION_RANGES, TRANSLITERATION_TEXT,
+};
+use crate::ucd::compare_range;
+use crate::unicode_str;
+
+/// Approximates `s` with ASCII characters, for example to build slugs or search keys from
+/// text written in any script.
+///
+/// Accented Latin letters lose their accents, other alphabets like Cyrillic and Greek are
+/// romanized letter by letter, and Chinese characters are replaced with their pinyin reading
+/// without tones. Symbols are replaced with ASCII look-alikes or descriptions, and chars with
+/// no known transliteration with `?`. This is a lossy approximation that ignores the context
+/// and the language of the text: Japanese kanji are read as Chinese, and Arabic vowels that
+/// are not written are not added. Use a [`Transliterator`] to change how chars are
+/// transliterated.
+///
+/// The transliterations are those of the [deunicode](https://crates.io/crates/deunicode)
+/// crate.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::{to_ascii_approx, ustr};
+///
+/// assert_eq!(to_ascii_approx(ustr!("Crème brûlée")), "Creme brulee");
+/// assert_eq!(to_ascii_approx(ustr!("Straße")), "Strasse");
+/// assert_eq!(to_ascii_approx(ustr!("Москва")), "Moskva");
+/// assert_eq!(to_ascii_approx(ustr!("Αθήνα")), "Athena");
+/// assert_eq!(to_ascii_approx(ustr!("北京市")), "Bei Jing Shi");
+/// assert_eq!(to_ascii_approx(ustr!("دمشق")), "dmshq");
+/// assert_eq!(to_ascii_approx(ustr!("½ €")), "1/2 EUR");
+/// ```
+pub fn to_ascii_approx(s: &unicode_str) -> String {
+    Transliterator::new().transliterate(s).text
+}
+
+/// Returns the ASCII transliteration of `c` from the bundled table, or `None` if there is no
+/// known transliteration. ASCII chars are their own transliteration.
+pub(crate) fn transliterate_char(c: char) -> Option<&'static str> {
+    if c.is_ascii() {
+        let index = c as usize;
+        return Some(&ASCII[index..index + 1]);
+    }
+    let index = TRANSLITERATION_RANGES
+        .binary_search_by(|&(start, end, _)| compare_range(start, end, c))
+        .ok()?;
+    let (start, _, first) = TRANSLITERATION_RANGES[index];
+    let packed = TRANSLITERATIONS[(first + (c as u32 - start as u32)) as usize];
+    let offset = (packed >> LENGTH_BITS) as usize;
+    let length = (packed & ((1 << LENGTH_BITS) - 1)) as usize;
+    Some(&TRANSLITERATION_TEXT[offset..offset + length])
+}
+
+/// All the ASCII chars in order, to return the transliteration of ASCII chars as a `&str`.
+static ASCII: &str = "\
+    \x00\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0A\x0B\x0C\x0D\x0E\x0F\
+    \x10\x11\x12\x13\x14\x15\x16\x17\x18\x19\x1A\x1B\x1C\x1D\x1E\x1F\
+    \x20!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~\x7F";
@@ -0,0 +1,237 @@
+use std::ops::Range;
+
+use crate::transliteration::transliterate_char;
+use crate::unicode_str;
+
+/// Transliterates text to ASCII like [`to_ascii_approx`](crate::to_ascii_approx), with custom
+/// rules that take precedence over the bundled table.
+///
+/// Rules replace a sequence of one or more chars, so they can override the transliteration of
+/// a single char, like the German `ä` that is usually written `ae`, or of a whole word. When
+/// several rules match at the same position, the longest one is used.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::{Transliterator, ustr};
+///
+/// let german = Transliterator::new()
+///     .rule(ustr!("ä"), "ae")
+///     .rule(ustr!("ö"), "oe")
+///     .rule(ustr!("ü"), "ue");
+///
+/// assert_eq!(german.transliterate(ustr!("Müller")).text, "Mueller");
+/// assert_eq!(german.transliterate(ustr!("Ørsted")).text, "Orsted");
+/// ```
+///
+/// Mapping the transliteration back to the original text:
+///
+/// ```
+/// use unicode_string::{Transliterator, ustr};
+///
+/// let s = ustr!("Жуков");
+/// let result = Transliterator::new().transliterate(s);
+/// assert_eq!(result.text, "Zhukov");
+/// assert_eq!(result.source_ranges, [0..1, 0..1, 1..2, 2..3, 3..4, 4..5]);
+///
+/// // "ukov" matches a search key, so highlight the matching chars of the original.
+/// let start = result.text.find("ukov").unwrap();
+/// assert_eq!(result.source_range(start..start + 4), 1..5);
+/// assert_eq!(&s[1..5], ustr!("уков"));
+/// ```
+///
+/// The chars produced by a rule come from all the chars the rule replaces:
+///
+/// ```
+/// use unicode_string::{Transliterator, ustr};
+///
+/// let dutch = Transliterator::new().rule(ustr!("ij"), "y");
+/// let result = dutch.transliterate(ustr!("xijz"));
+/// assert_eq!(result.text, "xyz");
+/// assert_eq!(result.source_range(1..2), 1..3);
+/// assert_eq!(result.source_range(2..2), 3..3);
+///
+/// let result = dutch.transliterate(ustr!("xij"));
+/// assert_eq!(result.source_range(2..2), 3..3);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Transliterator {
+    /// The rules, sorted by decreasing length of the replaced chars.
+    rules: Vec<(Vec<char>, String)>,
+    unknown: String,
+}
+
+impl Default for Transliterator {
+    fn default() -> Self {
+        Transliterator::new()
+    }
+}
+
+impl Transliterator {
+    /// Creates a transliterator that uses the bundled table and replaces the chars that have
+    /// no known transliteration with `?`.
+    pub fn new() -> Transliterator {
+        Transliterator {
+            rules: Vec::new(),
+            unknown: String::from("?"),
+        }
+    }
+
+    /// Adds a rule that replaces `from` with `to`, replacing any previous rule for `from`.
+    ///
+    /// `to` does not need to be ASCII, so a rule can also keep chars unchanged. Rules with an
+    /// empty `from` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::{Transliterator, ustr};
+    ///
+    /// let transliterator = Transliterator::new()
+    ///     .rule(ustr!("€"), "euros")
+    ///     .rule(ustr!("&"), "and")
+    ///     .rule(ustr!("東京"), "Tokyo");
+    ///
+    /// let result = transliterator.transliterate(ustr!("東京 & 京都: 5€"));
+    /// assert_eq!(result.text, "Tokyo and Jing Du: 5euros");
+    /// ```
+    #[must_use]
+    pub fn rule(mut self, from: &unicode_str, to: &str) -> Self {
+        let from = from.chars();
+        if from.is_empty() {
+            return self;
+        }
+        self.rules.retain(|(rule, _)| rule != from);
+        let index = self
+            .rules
+            .partition_point(|(rule, _)| rule.len() >= from.len());
+        self.rules.insert(index, (from.to_vec(), to.to_owned()));
+        self
+    }
+
+    /// Sets the replacement of the chars that have no known transliteration and no rule.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::{Transliterator, ustr};
+    ///
+    /// let s = ustr!("a\u{E000}b");
+    /// assert_eq!(Transliterator::new().transliterate(s).text, "a?b");
+    /// assert_eq!(Transliterator::new().unknown("").transliterate(s).text, "ab");
+    /// ```
+    #[must_use]
+    pub fn unknown(mut self, replacement: &str) -> Self {
+        self.unknown = replacement.to_owned();
+        self
+    }
+
+    /// Transliterates `s`, returning the text and the range of chars of `s` each char of the
+    /// text comes from.
+    ///
+    /// The readings of ideographs are separated by spaces, and the space after the last
+    /// reading of a word is removed when it is followed by a space, closing punctuation or the
+    /// end of the text.
+    pub fn transliterate(&self, s: &unicode_str) -> Transliteration {
+        let chars = s.chars();
+        let mut result = Transliteration {
+            text: String::with_capacity(chars.len()),
+            source_ranges: Vec::with_capacity(chars.len()),
+            source_len: chars.len(),
+        };
+        // Set when the last non-empty replacement is a reading that ends with a separating
+        // space, which is removed if the next replacement does not start a new word.
+        let mut separated = false;
+        let mut index = 0;
+        while index < chars.len() {
+            let (replacement, length, from_table) = match self.find_rule(&chars[index..]) {
+                Some((rule, replacement)) => (replacement, rule.len(), false),
+                None => match transliterate_char(chars[index]) {
+                    Some(replacement) => (replacement, 1, true),
+                    None => (self.unknown.as_str(), 1, false),
+                },
+            };
+            if let Some(first) = replacement.chars().next() {
+                if separated && ends_word(first) {
+                    result.pop_separator();
+                }
+                for c in replacement.chars() {
+                    result.text.push(c);
+                    result.source_ranges.push(index..index + length);
+                }
+                separated = from_table && replacement.len() > 1 && replacement.ends_with(' ');
+            }
+            index += length;
+        }
+        if separated {
+            result.pop_separator();
+        }
+        result
+    }
+
+    /// Returns the longest rule that matches the start of `chars`.
+    fn find_rule(&self, chars: &[char]) -> Option<(&[char], &str)> {
+        self.rules
+            .iter()
+            .find(|(rule, _)| chars.starts_with(rule))
+            .map(|(rule, replacement)| (rule.as_slice(), replacement.as_str()))
+    }
+}
+
+/// Checks if a separating space is redundant before `c`.
+#[inline]
+fn ends_word(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '.' | ',' | ';' | ':' | '!' | '?' | ')' | ']' | '}')
+}
+
+/// The result of [`Transliterator::transliterate`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Transliteration {
+    /// The transliterated text.
+    pub text: String,
+    /// For each char of [`text`](Transliteration::text), the range of chars of the original
+    /// string it comes from, which spans all the chars replaced by a rule. The ranges are
+    /// sorted, and a range that is replaced with several chars appears several times.
+    pub source_ranges: Vec<Range<usize>>,
+    /// The number of chars of the original string.
+    source_len: usize,
+}
+
+impl Transliteration {
+    /// Returns the range of chars of the original string that the chars in `range` of the
+    /// transliterated text come from.
+    ///
+    /// The range is in chars, which are also bytes when the text is ASCII. An empty range is
+    /// mapped to an empty range at the corresponding position of the original string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of the bounds of the text.
+    pub fn source_range(&self, range: Range<usize>) -> Range<usize> {
+        let ranges = &self.source_ranges;
+        assert!(
+            range.start <= range.end && range.end <= ranges.len(),
+            "range {:?} out of bounds of a text of {} chars",
+            range,
+            ranges.len()
+        );
+        if range.is_empty() {
+            let start = ranges
+                .get(range.start)
+                .map_or(self.source_len, |source| source.start);
+            return start..start;
+        }
+        ranges[range.start].start..ranges[range.end - 1].end
+    }
+
+    fn pop_separator(&mut self) {
+        self.text.pop();
+        self.source_ranges.pop();
+    }
+}
@@ -0,0 +1,81 @@
+use std::ops::RangeInclusive;
+
+use crate::tables::properties::BLOCKS;
+use crate::ucd::compare_range;
+
+/// A named range of code points (the `Block` property).
+///
+/// Blocks are contiguous ranges of code points that were allocated together, and their names
+/// only give a rough idea of what the chars in them are: [scripts](crate::ucd::Script) are
+/// usually more useful.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::ucd;
+///
+/// let block = ucd::block('é').unwrap();
+/// assert_eq!(block.name(), "Latin-1 Supplement");
+/// assert_eq!(block.range(), '\u{80}'..='\u{FF}');
+/// assert!(block.contains('ÿ'));
+///
+/// assert_eq!(ucd::block('\u{2FE0}'), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Block {
+    start: char,
+    end: char,
+    name: &'static str,
+}
+
+impl Block {
+    /// Returns the name of the block, e.g. `"Basic Latin"`.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the code points of the block.
+    #[inline]
+    pub fn range(&self) -> RangeInclusive<char> {
+        self.start..=self.end
+    }
+
+    /// Checks if `c` is in the block.
+    #[inline]
+    pub fn contains(&self, c: char) -> bool {
+        self.start <= c && c <= self.end
+    }
+
+    /// Returns an iterator over every block, in code point order.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ucd::Block;
+    ///
+    /// let first = Block::all().next().unwrap();
+    /// assert_eq!(first.name(), "Basic Latin");
+    /// ```
+    pub fn all() -> impl Iterator<Item = Block> {
+        BLOCKS
+            .iter()
+            .map(|&(start, end, name)| Block { start, end, name })
+    }
+}
+
+/// Returns the block containing `c`, or `None` if `c` is not in a block.
+///
+/// See [`Block`] for an example.
+#[inline]
+pub fn block(c: char) -> Option<Block> {
+    let index = BLOCKS
+        .binary_search_by(|&(start, end, _)| compare_range(start, end, c))
+        .ok()?;
+    let (start, end, name) = BLOCKS[index];
+    Some(Block { start, end, name })
+}
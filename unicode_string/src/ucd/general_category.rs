@@ -0,0 +1,293 @@
+use crate::tables::properties::GENERAL_CATEGORY;
+use crate::ucd::{loose_eq, range_value};
+
+/// The general category of a char (the `General_Category` property).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::ucd::{self, GeneralCategory};
+///
+/// assert_eq!(ucd::general_category('a'), GeneralCategory::LowercaseLetter);
+/// assert_eq!(ucd::general_category('٣'), GeneralCategory::DecimalNumber);
+/// assert_eq!(ucd::general_category('\u{378}'), GeneralCategory::Unassigned);
+///
+/// assert_eq!(GeneralCategory::DecimalNumber.short_name(), "Nd");
+/// assert!(GeneralCategory::DecimalNumber.is_number());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GeneralCategory {
+    /// An uppercase letter (`Lu`).
+    UppercaseLetter,
+    /// A lowercase letter (`Ll`).
+    LowercaseLetter,
+    /// A digraph whose first part is uppercase (`Lt`).
+    TitlecaseLetter,
+    /// A modifier letter (`Lm`).
+    ModifierLetter,
+    /// Any other letter, including syllables and ideographs (`Lo`).
+    OtherLetter,
+    /// A nonspacing combining mark (`Mn`).
+    NonspacingMark,
+    /// A spacing combining mark (`Mc`).
+    SpacingMark,
+    /// An enclosing combining mark (`Me`).
+    EnclosingMark,
+    /// A decimal digit (`Nd`).
+    DecimalNumber,
+    /// A letterlike numeric character (`Nl`).
+    LetterNumber,
+    /// Any other numeric character (`No`).
+    OtherNumber,
+    /// A connecting punctuation mark, like an underscore (`Pc`).
+    ConnectorPunctuation,
+    /// A dash or hyphen punctuation mark (`Pd`).
+    DashPunctuation,
+    /// An opening punctuation mark of a pair (`Ps`).
+    OpenPunctuation,
+    /// A closing punctuation mark of a pair (`Pe`).
+    ClosePunctuation,
+    /// An initial quotation mark (`Pi`).
+    InitialPunctuation,
+    /// A final quotation mark (`Pf`).
+    FinalPunctuation,
+    /// Any other punctuation mark (`Po`).
+    OtherPunctuation,
+    /// A symbol of mathematical use (`Sm`).
+    MathSymbol,
+    /// A currency sign (`Sc`).
+    CurrencySymbol,
+    /// A non-letterlike modifier symbol (`Sk`).
+    ModifierSymbol,
+    /// Any other symbol (`So`).
+    OtherSymbol,
+    /// A space character of non-zero width (`Zs`).
+    SpaceSeparator,
+    /// U+2028 LINE SEPARATOR (`Zl`).
+    LineSeparator,
+    /// U+2029 PARAGRAPH SEPARATOR (`Zp`).
+    ParagraphSeparator,
+    /// A C0 or C1 control code (`Cc`).
+    Control,
+    /// A format control character (`Cf`).
+    Format,
+    /// A surrogate code point (`Cs`). No char has this category.
+    Surrogate,
+    /// A private-use character (`Co`).
+    PrivateUse,
+    /// A reserved unassigned code point or a noncharacter (`Cn`).
+    Unassigned,
+}
+
+/// The long and short name of every general category, in the order of the variants.
+static NAMES: &[(&str, &str)] = &[
+    ("Uppercase_Letter", "Lu"),
+    ("Lowercase_Letter", "Ll"),
+    ("Titlecase_Letter", "Lt"),
+    ("Modifier_Letter", "Lm"),
+    ("Other_Letter", "Lo"),
+    ("Nonspacing_Mark", "Mn"),
+    ("Spacing_Mark", "Mc"),
+    ("Enclosing_Mark", "Me"),
+    ("Decimal_Number", "Nd"),
+    ("Letter_Number", "Nl"),
+    ("Other_Number", "No"),
+    ("Connector_Punctuation", "Pc"),
+    ("Dash_Punctuation", "Pd"),
+    ("Open_Punctuation", "Ps"),
+    ("Close_Punctuation", "Pe"),
+    ("Initial_Punctuation", "Pi"),
+    ("Final_Punctuation", "Pf"),
+    ("Other_Punctuation", "Po"),
+    ("Math_Symbol", "Sm"),
+    ("Currency_Symbol", "Sc"),
+    ("Modifier_Symbol", "Sk"),
+    ("Other_Symbol", "So"),
+    ("Space_Separator", "Zs"),
+    ("Line_Separator", "Zl"),
+    ("Paragraph_Separator", "Zp"),
+    ("Control", "Cc"),
+    ("Format", "Cf"),
+    ("Surrogate", "Cs"),
+    ("Private_Use", "Co"),
+    ("Unassigned", "Cn"),
+];
+
+/// Every general category, in the order of the variants.
+static ALL: &[GeneralCategory] = &[
+    GeneralCategory::UppercaseLetter,
+    GeneralCategory::LowercaseLetter,
+    GeneralCategory::TitlecaseLetter,
+    GeneralCategory::ModifierLetter,
+    GeneralCategory::OtherLetter,
+    GeneralCategory::NonspacingMark,
+    GeneralCategory::SpacingMark,
+    GeneralCategory::EnclosingMark,
+    GeneralCategory::DecimalNumber,
+    GeneralCategory::LetterNumber,
+    GeneralCategory::OtherNumber,
+    GeneralCategory::ConnectorPunctuation,
+    GeneralCategory::DashPunctuation,
+    GeneralCategory::OpenPunctuation,
+    GeneralCategory::ClosePunctuation,
+    GeneralCategory::InitialPunctuation,
+    GeneralCategory::FinalPunctuation,
+    GeneralCategory::OtherPunctuation,
+    GeneralCategory::MathSymbol,
+    GeneralCategory::CurrencySymbol,
+    GeneralCategory::ModifierSymbol,
+    GeneralCategory::OtherSymbol,
+    GeneralCategory::SpaceSeparator,
+    GeneralCategory::LineSeparator,
+    GeneralCategory::ParagraphSeparator,
+    GeneralCategory::Control,
+    GeneralCategory::Format,
+    GeneralCategory::Surrogate,
+    GeneralCategory::PrivateUse,
+    GeneralCategory::Unassigned,
+];
+
+impl GeneralCategory {
+    /// Returns the long name of the category, e.g. `"Uppercase_Letter"`.
+    #[inline]
+    pub fn name(self) -> &'static str {
+        NAMES[self as usize].0
+    }
+
+    /// Returns the abbreviated name of the category, e.g. `"Lu"`.
+    #[inline]
+    pub fn short_name(self) -> &'static str {
+        NAMES[self as usize].1
+    }
+
+    /// Finds a category by its long or short name. Names are matched loosely: case,
+    /// whitespace, underscores and hyphens are ignored.
+    ///
+    /// The names of the groups of categories, like `L` or `Letter`, are not accepted.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ucd::GeneralCategory;
+    ///
+    /// assert_eq!(GeneralCategory::from_name("Lu"), Some(GeneralCategory::UppercaseLetter));
+    /// assert_eq!(GeneralCategory::from_name("uppercase letter"), Some(GeneralCategory::UppercaseLetter));
+    /// assert_eq!(GeneralCategory::from_name("L"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<GeneralCategory> {
+        NAMES
+            .iter()
+            .position(|&(long, short)| loose_eq(name, long) || loose_eq(name, short))
+            .map(|index| ALL[index])
+    }
+
+    /// Checks if this is a letter category (`L`: `Lu`, `Ll`, `Lt`, `Lm` or `Lo`).
+    #[inline]
+    pub const fn is_letter(self) -> bool {
+        matches!(
+            self,
+            GeneralCategory::UppercaseLetter
+                | GeneralCategory::LowercaseLetter
+                | GeneralCategory::TitlecaseLetter
+                | GeneralCategory::ModifierLetter
+                | GeneralCategory::OtherLetter
+        )
+    }
+
+    /// Checks if this is a cased letter category (`LC`: `Lu`, `Ll` or `Lt`).
+    #[inline]
+    pub const fn is_cased_letter(self) -> bool {
+        matches!(
+            self,
+            GeneralCategory::UppercaseLetter
+                | GeneralCategory::LowercaseLetter
+                | GeneralCategory::TitlecaseLetter
+        )
+    }
+
+    /// Checks if this is a mark category (`M`: `Mn`, `Mc` or `Me`).
+    #[inline]
+    pub const fn is_mark(self) -> bool {
+        matches!(
+            self,
+            GeneralCategory::NonspacingMark
+                | GeneralCategory::SpacingMark
+                | GeneralCategory::EnclosingMark
+        )
+    }
+
+    /// Checks if this is a number category (`N`: `Nd`, `Nl` or `No`).
+    #[inline]
+    pub const fn is_number(self) -> bool {
+        matches!(
+            self,
+            GeneralCategory::DecimalNumber
+                | GeneralCategory::LetterNumber
+                | GeneralCategory::OtherNumber
+        )
+    }
+
+    /// Checks if this is a punctuation category (`P`: `Pc`, `Pd`, `Ps`, `Pe`, `Pi`, `Pf` or
+    /// `Po`).
+    #[inline]
+    pub const fn is_punctuation(self) -> bool {
+        matches!(
+            self,
+            GeneralCategory::ConnectorPunctuation
+                | GeneralCategory::DashPunctuation
+                | GeneralCategory::OpenPunctuation
+                | GeneralCategory::ClosePunctuation
+                | GeneralCategory::InitialPunctuation
+                | GeneralCategory::FinalPunctuation
+                | GeneralCategory::OtherPunctuation
+        )
+    }
+
+    /// Checks if this is a symbol category (`S`: `Sm`, `Sc`, `Sk` or `So`).
+    #[inline]
+    pub const fn is_symbol(self) -> bool {
+        matches!(
+            self,
+            GeneralCategory::MathSymbol
+                | GeneralCategory::CurrencySymbol
+                | GeneralCategory::ModifierSymbol
+                | GeneralCategory::OtherSymbol
+        )
+    }
+
+    /// Checks if this is a separator category (`Z`: `Zs`, `Zl` or `Zp`).
+    #[inline]
+    pub const fn is_separator(self) -> bool {
+        matches!(
+            self,
+            GeneralCategory::SpaceSeparator
+                | GeneralCategory::LineSeparator
+                | GeneralCategory::ParagraphSeparator
+        )
+    }
+
+    /// Checks if this is one of the other categories (`C`: `Cc`, `Cf`, `Cs`, `Co` or `Cn`).
+    #[inline]
+    pub const fn is_other(self) -> bool {
+        matches!(
+            self,
+            GeneralCategory::Control
+                | GeneralCategory::Format
+                | GeneralCategory::Surrogate
+                | GeneralCategory::PrivateUse
+                | GeneralCategory::Unassigned
+        )
+    }
+}
+
+/// Returns the general category of `c`.
+///
+/// See [`GeneralCategory`] for an example.
+#[inline]
+pub fn general_category(c: char) -> GeneralCategory {
+    range_value(GENERAL_CATEGORY, c).unwrap_or(GeneralCategory::Unassigned)
+}
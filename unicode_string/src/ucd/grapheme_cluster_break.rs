@@ -0,0 +1,59 @@
+use crate::tables::properties::GRAPHEME_CLUSTER_BREAK;
+use crate::ucd::range_value;
+
+/// The role of a char in the segmentation of text into grapheme clusters (the
+/// `Grapheme_Cluster_Break` property), as defined by
+/// [UAX #29](https://www.unicode.org/reports/tr29/).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::ucd::{self, GraphemeClusterBreak};
+///
+/// assert_eq!(ucd::grapheme_cluster_break('\r'), GraphemeClusterBreak::CR);
+/// assert_eq!(ucd::grapheme_cluster_break('\u{301}'), GraphemeClusterBreak::Extend);
+/// assert_eq!(ucd::grapheme_cluster_break('한'), GraphemeClusterBreak::LVT);
+/// assert_eq!(ucd::grapheme_cluster_break('a'), GraphemeClusterBreak::Other);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GraphemeClusterBreak {
+    /// Any other char (`XX`).
+    Other,
+    /// U+000D CARRIAGE RETURN (`CR`).
+    CR,
+    /// U+000A LINE FEED (`LF`).
+    LF,
+    /// Control and format chars that always break, like U+0000 NULL (`CN`).
+    Control,
+    /// Combining marks and other chars that extend the previous char (`EX`).
+    Extend,
+    /// U+200D ZERO WIDTH JOINER (`ZWJ`).
+    ZWJ,
+    /// The regional indicators, which form flags in pairs (`RI`).
+    RegionalIndicator,
+    /// Chars that attach to the following char, like some Arabic number signs (`PP`).
+    Prepend,
+    /// Spacing combining marks, which extend the previous char (`SM`).
+    SpacingMark,
+    /// Hangul leading consonants (`L`).
+    L,
+    /// Hangul vowels (`V`).
+    V,
+    /// Hangul trailing consonants (`T`).
+    T,
+    /// Hangul syllables made of a leading consonant and a vowel (`LV`).
+    LV,
+    /// Hangul syllables made of a leading consonant, a vowel and a trailing consonant
+    /// (`LVT`).
+    LVT,
+}
+
+/// Returns the grapheme cluster break property of `c`.
+///
+/// See [`GraphemeClusterBreak`] for an example.
+#[inline]
+pub fn grapheme_cluster_break(c: char) -> GraphemeClusterBreak {
+    range_value(GRAPHEME_CLUSTER_BREAK, c).unwrap_or(GraphemeClusterBreak::Other)
+}
@@ -0,0 +1,77 @@
+use std::iter::FusedIterator;
+
+use crate::ucd::{grapheme_cluster_break, is_extended_pictographic, GraphemeClusterBreak};
+use crate::unicode_str;
+
+/// An iterator over the extended grapheme clusters of a string.
+///
+/// This struct is created by the [`graphemes`] method on [`unicode_str`]. See its
+/// documentation for more.
+///
+/// [`graphemes`]: unicode_str::graphemes
+#[derive(Clone, Debug)]
+pub struct Graphemes<'a> {
+    rest: &'a unicode_str,
+}
+
+impl<'a> Graphemes<'a> {
+    #[inline]
+    pub(crate) fn new(s: &'a unicode_str) -> Graphemes<'a> {
+        Graphemes { rest: s }
+    }
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a unicode_str;
+
+    fn next(&mut self) -> Option<&'a unicode_str> {
+        let chars = self.rest.chars();
+        if chars.is_empty() {
+            return None;
+        }
+        let len = first_grapheme_len(chars);
+        self.rest = unicode_str::from_chars(&chars[len..]);
+        Some(unicode_str::from_chars(&chars[..len]))
+    }
+}
+
+impl FusedIterator for Graphemes<'_> {}
+
+/// Returns the number of chars of the first extended grapheme cluster of `chars`, which must
+/// not be empty, following the rules of UAX #29.
+pub(crate) fn first_grapheme_len(chars: &[char]) -> usize {
+    use GraphemeClusterBreak::*;
+
+    let mut previous = grapheme_cluster_break(chars[0]);
+    // The number of regional indicators right before the current position.
+    let mut regional_indicators = usize::from(previous == RegionalIndicator);
+    // Whether the chars before the current position are an extended pictographic char
+    // followed by any number of `Extend` chars (GB11).
+    let mut pictographic = is_extended_pictographic(chars[0]);
+    // Whether they are such a sequence followed by a ZWJ.
+    let mut pictographic_zwj = false;
+    for (index, &c) in chars.iter().enumerate().skip(1) {
+        let current = grapheme_cluster_break(c);
+        let joined = match (previous, current) {
+            (CR, LF) => true,
+            (Control | CR | LF, _) | (_, Control | CR | LF) => false,
+            (L, L | V | LV | LVT) | (LV | V, V | T) | (LVT | T, T) => true,
+            (_, Extend | ZWJ | SpacingMark) | (Prepend, _) => true,
+            (ZWJ, _) => pictographic_zwj && is_extended_pictographic(c),
+            (RegionalIndicator, RegionalIndicator) => regional_indicators % 2 == 1,
+            _ => false,
+        };
+        if !joined {
+            return index;
+        }
+        pictographic_zwj = pictographic && current == ZWJ;
+        pictographic = is_extended_pictographic(c) || (pictographic && current == Extend);
+        regional_indicators = if current == RegionalIndicator {
+            regional_indicators + 1
+        } else {
+            0
+        };
+        previous = current;
+    }
+    chars.len()
+}
@@ -0,0 +1,41 @@
+use crate::tables::properties::JOINING_TYPE;
+use crate::ucd::range_value;
+
+/// How a char joins with its neighbors in cursive scripts like Arabic and Syriac (the
+/// `Joining_Type` property).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::ucd::{self, JoiningType};
+///
+/// assert_eq!(ucd::joining_type('ب'), JoiningType::DualJoining);
+/// assert_eq!(ucd::joining_type('ا'), JoiningType::RightJoining);
+/// assert_eq!(ucd::joining_type('\u{64E}'), JoiningType::Transparent);
+/// assert_eq!(ucd::joining_type('a'), JoiningType::NonJoining);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum JoiningType {
+    /// The char does not join with its neighbors (`U`).
+    NonJoining,
+    /// The char forces its neighbors to join with it, like U+200D ZERO WIDTH JOINER (`C`).
+    JoinCausing,
+    /// The char joins with the chars on both sides (`D`).
+    DualJoining,
+    /// The char only joins with the char on its left (`L`).
+    LeftJoining,
+    /// The char only joins with the char on its right (`R`).
+    RightJoining,
+    /// The char does not affect joining, like most combining marks (`T`).
+    Transparent,
+}
+
+/// Returns the joining type of `c`.
+///
+/// See [`JoiningType`] for an example.
+#[inline]
+pub fn joining_type(c: char) -> JoiningType {
+    range_value(JOINING_TYPE, c).unwrap_or(JoiningType::NonJoining)
+}
@@ -0,0 +1,92 @@
+//! Properties of chars from the Unicode Character Database.
+//!
+//! The tables are generated by `unicode_string_gen` from the files of the Unicode Character
+//! Database, and [`UNICODE_VERSION`] is the version they were built from.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use unicode_string::ucd::{self, GeneralCategory, Script};
+//!
+//! assert_eq!(ucd::general_category('A'), GeneralCategory::UppercaseLetter);
+//! assert_eq!(ucd::script('Ж'), Script::Cyrillic);
+//! assert_eq!(ucd::block('Ж').unwrap().name(), "Cyrillic");
+//! assert_eq!(ucd::age('€'), Some((2, 1)));
+//! assert!(ucd::is_emoji('🦀'));
+//! assert_eq!(ucd::name('🦀').as_deref(), Some("CRAB"));
+//! assert_eq!(ucd::lookup("CRAB"), Some('🦀'));
+//! ```
+
+mod block;
+mod general_category;
+mod grapheme_cluster_break;
+mod graphemes;
+mod joining_type;
+mod names;
+mod properties;
+mod script;
+mod script_runs;
+pub(crate) mod script_set;
+
+pub use self::block::*;
+pub use self::general_category::*;
+pub use self::grapheme_cluster_break::*;
+pub use self::graphemes::*;
+pub use self::joining_type::*;
+pub use self::names::*;
+pub use self::properties::*;
+pub use self::script::*;
+pub use self::script_runs::*;
+
+use std::cmp::Ordering;
+
+/// The version of the Unicode Character Database the property tables were built from.
+pub const UNICODE_VERSION: (u8, u8, u8) = crate::tables::properties::UNICODE_VERSION;
+
+/// Finds the range containing `c` in a table of sorted, non-overlapping ranges and returns its
+/// value.
+#[inline]
+pub(crate) fn range_value<T: Copy>(table: &[(char, char, T)], c: char) -> Option<T> {
+    table
+        .binary_search_by(|&(start, end, _)| compare_range(start, end, c))
+        .ok()
+        .map(|index| table[index].2)
+}
+
+/// Checks if `c` is in one of the ranges of a table of sorted, non-overlapping ranges.
+#[inline]
+pub(crate) fn in_ranges(table: &[(char, char)], c: char) -> bool {
+    table
+        .binary_search_by(|&(start, end)| compare_range(start, end, c))
+        .is_ok()
+}
+
+/// Orders the range `start..=end` relative to `c`, for binary searches in range tables.
+#[inline]
+pub(crate) fn compare_range(start: char, end: char, c: char) -> Ordering {
+    if end < c {
+        Ordering::Less
+    } else if start > c {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Compares property names and values using the loose matching rule of UAX #44 (UAX44-LM3):
+/// case, whitespace, underscores and hyphens are ignored, as is an initial `is`.
+pub(crate) fn loose_eq(a: &str, b: &str) -> bool {
+    fn normalize(name: &str) -> impl Iterator<Item = char> + '_ {
+        let name = name.trim_start();
+        let name = match name.get(..2) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("is") => &name[2..],
+            _ => name,
+        };
+        name.chars()
+            .filter(|&c| !c.is_whitespace() && c != '_' && c != '-')
+            .map(|c| c.to_ascii_lowercase())
+    }
+    normalize(a).eq(normalize(b))
+}
@@ -0,0 +1,325 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+use crate::normalization::{HANGUL_N_COUNT, HANGUL_S_BASE, HANGUL_S_COUNT, HANGUL_T_COUNT};
+use crate::tables::names::{NAMES, NAMES_BY_NAME, NAME_ALIASES, NAME_PREFIXES, NAME_TEXT};
+use crate::ucd::range_value;
+
+/// The prefix of the names of Hangul syllables (rule NR1 of UAX #44).
+const HANGUL_SYLLABLE_PREFIX: &str = "HANGUL SYLLABLE ";
+
+/// The short names of the leading consonants, vowels and trailing consonants (`Jamo.txt`).
+const JAMO_L: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+const JAMO_V: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE",
+    "WI", "YU", "EU", "YI", "I",
+];
+const JAMO_T: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+/// Returns the name of `c` (the `Name` property), or `None` if `c` has no name.
+///
+/// Control codes, private-use chars, noncharacters and unassigned code points have no name,
+/// but control codes have [aliases](name_aliases). The names of CJK ideographs and Hangul
+/// syllables are derived from the code point, so they are returned as owned strings.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::ucd;
+///
+/// assert_eq!(ucd::name('α').as_deref(), Some("GREEK SMALL LETTER ALPHA"));
+/// assert_eq!(ucd::name('\u{200B}').as_deref(), Some("ZERO WIDTH SPACE"));
+/// assert_eq!(ucd::name('中').as_deref(), Some("CJK UNIFIED IDEOGRAPH-4E2D"));
+/// assert_eq!(ucd::name('한').as_deref(), Some("HANGUL SYLLABLE HAN"));
+/// assert_eq!(ucd::name('\n'), None);
+/// ```
+pub fn name(c: char) -> Option<Cow<'static, str>> {
+    let code = c as u32;
+    if (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&code) {
+        let index = code - HANGUL_S_BASE;
+        let leading = JAMO_L[(index / HANGUL_N_COUNT) as usize];
+        let vowel = JAMO_V[(index % HANGUL_N_COUNT / HANGUL_T_COUNT) as usize];
+        let trailing = JAMO_T[(index % HANGUL_T_COUNT) as usize];
+        return Some(Cow::Owned(format!(
+            "{}{}{}{}",
+            HANGUL_SYLLABLE_PREFIX, leading, vowel, trailing
+        )));
+    }
+    if let Some(prefix) = range_value(NAME_PREFIXES, c) {
+        return Some(Cow::Owned(format!("{}{:04X}", prefix, code)));
+    }
+    let index = NAMES.binary_search_by_key(&c, |&(code, _)| code).ok()?;
+    let (start, end) = name_range(index);
+    Some(Cow::Borrowed(&NAME_TEXT[start..end]))
+}
+
+/// Returns the aliases of the name of `c` (the `Name_Alias` property): corrections of
+/// misspelled names, the names of control codes, and alternate names and abbreviations.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::ucd;
+///
+/// let aliases: Vec<_> = ucd::name_aliases('\u{FEFF}').collect();
+/// assert_eq!(aliases, ["BYTE ORDER MARK", "BOM", "ZWNBSP"]);
+///
+/// let aliases: Vec<_> = ucd::name_aliases('\0').collect();
+/// assert_eq!(aliases, ["NULL", "NUL"]);
+/// ```
+pub fn name_aliases(c: char) -> impl Iterator<Item = &'static str> {
+    let start = NAME_ALIASES.partition_point(|&(code, _)| code < c);
+    NAME_ALIASES[start..]
+        .iter()
+        .take_while(move |&&(code, _)| code == c)
+        .map(|&(_, alias)| alias)
+}
+
+/// Finds the char with the given name or name alias.
+///
+/// Case is ignored, and underscores and runs of spaces are treated as a single space. The names
+/// derived from the code point, like those of CJK ideographs and Hangul syllables, are
+/// recognized too.
+///
+/// This is a `const fn`, so names can be resolved at compile time. The
+/// [`ustr!`](crate::ustr) macro uses it for its `\N{...}` escapes.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::ucd;
+///
+/// assert_eq!(ucd::lookup("GREEK SMALL LETTER ALPHA"), Some('α'));
+/// assert_eq!(ucd::lookup("greek_small_letter_alpha"), Some('α'));
+/// assert_eq!(ucd::lookup("CJK UNIFIED IDEOGRAPH-4E2D"), Some('中'));
+/// assert_eq!(ucd::lookup("HANGUL SYLLABLE HAN"), Some('한'));
+/// assert_eq!(ucd::lookup("BYTE ORDER MARK"), Some('\u{FEFF}'));
+/// assert_eq!(ucd::lookup("NOT A CHARACTER NAME"), None);
+///
+/// const ZWJ: char = match ucd::lookup("ZERO WIDTH JOINER") {
+///     Some(c) => c,
+///     None => panic!(),
+/// };
+/// assert_eq!(ZWJ, '\u{200D}');
+/// ```
+///
+/// Naming invisible chars in `ustr!`:
+///
+/// ```
+/// use unicode_string::ustr;
+///
+/// let s = ustr!(r"soft\N{SOFT HYPHEN}ware");
+/// assert_eq!(s.len(), 9);
+/// assert_eq!(s.chars()[4], '\u{AD}');
+/// ```
+pub const fn lookup(name: &str) -> Option<char> {
+    let query = trim_separators(name.as_bytes());
+    if query.is_empty() {
+        return None;
+    }
+
+    // Names stored in the table.
+    let text = NAME_TEXT.as_bytes();
+    let (mut low, mut high) = (0, NAMES_BY_NAME.len());
+    while low < high {
+        let middle = low + (high - low) / 2;
+        let index = NAMES_BY_NAME[middle] as usize;
+        let (start, end) = name_range(index);
+        match compare(query, text, start, end) {
+            Ordering::Less => high = middle,
+            Ordering::Greater => low = middle + 1,
+            Ordering::Equal => return Some(NAMES[index].0),
+        }
+    }
+
+    // Names derived from the code point.
+    let mut index = 0;
+    while index < NAME_PREFIXES.len() {
+        let (first, last, prefix) = NAME_PREFIXES[index];
+        if let Some(rest) = strip_prefix(query, 0, prefix.as_bytes()) {
+            if let Some(code) = parse_code_point(query, rest) {
+                if first as u32 <= code && code <= last as u32 {
+                    return char::from_u32(code);
+                }
+            }
+        }
+        index += 1;
+    }
+    if let Some(rest) = strip_prefix(query, 0, HANGUL_SYLLABLE_PREFIX.as_bytes()) {
+        if let Some(c) = hangul_syllable(query, rest) {
+            return Some(c);
+        }
+    }
+
+    // Aliases.
+    let mut index = 0;
+    while index < NAME_ALIASES.len() {
+        let (c, alias) = NAME_ALIASES[index];
+        let alias = alias.as_bytes();
+        if let Ordering::Equal = compare(query, alias, 0, alias.len()) {
+            return Some(c);
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Returns the start and end offset of the name of `NAMES[index]` in `NAME_TEXT`.
+#[inline]
+const fn name_range(index: usize) -> (usize, usize) {
+    let start = NAMES[index].1 as usize;
+    let end = if index + 1 < NAMES.len() {
+        NAMES[index + 1].1 as usize
+    } else {
+        NAME_TEXT.len()
+    };
+    (start, end)
+}
+
+#[inline]
+const fn is_separator(b: u8) -> bool {
+    matches!(b, b' ' | b'_' | b'\t' | b'\n' | b'\r')
+}
+
+const fn trim_separators(mut bytes: &[u8]) -> &[u8] {
+    while let [first, rest @ ..] = bytes {
+        if !is_separator(*first) {
+            break;
+        }
+        bytes = rest;
+    }
+    while let [rest @ .., last] = bytes {
+        if !is_separator(*last) {
+            break;
+        }
+        bytes = rest;
+    }
+    bytes
+}
+
+/// Returns the byte of a trimmed query at `index`, uppercased and with a run of separators
+/// replaced by a single space, and the index of the next byte.
+#[inline]
+const fn next_byte(query: &[u8], mut index: usize) -> Option<(u8, usize)> {
+    if index >= query.len() {
+        return None;
+    }
+    if !is_separator(query[index]) {
+        return Some((query[index].to_ascii_uppercase(), index + 1));
+    }
+    while index < query.len() && is_separator(query[index]) {
+        index += 1;
+    }
+    Some((b' ', index))
+}
+
+/// Compares a trimmed query with the name `text[start..end]`.
+const fn compare(query: &[u8], text: &[u8], start: usize, end: usize) -> Ordering {
+    let (mut index, mut position) = (0, start);
+    loop {
+        let Some((byte, next)) = next_byte(query, index) else {
+            return if position == end {
+                Ordering::Equal
+            } else {
+                Ordering::Less
+            };
+        };
+        if position == end {
+            return Ordering::Greater;
+        }
+        if byte != text[position] {
+            return if byte < text[position] {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+        index = next;
+        position += 1;
+    }
+}
+
+/// Matches `prefix` against a trimmed query starting at `index`, returning the index after it.
+const fn strip_prefix(query: &[u8], mut index: usize, prefix: &[u8]) -> Option<usize> {
+    let mut position = 0;
+    while position < prefix.len() {
+        match next_byte(query, index) {
+            Some((byte, next)) if byte == prefix[position] => index = next,
+            _ => return None,
+        }
+        position += 1;
+    }
+    Some(index)
+}
+
+/// Parses the hexadecimal code point at the end of a derived name, which has at least four
+/// digits and no other leading zeros.
+const fn parse_code_point(query: &[u8], mut index: usize) -> Option<u32> {
+    let (mut code, mut digits) = (0u32, 0);
+    while let Some((byte, next)) = next_byte(query, index) {
+        let digit = match byte {
+            b'0'..=b'9' => byte - b'0',
+            b'A'..=b'F' => byte - b'A' + 10,
+            _ => return None,
+        };
+        if digits == 6 {
+            return None;
+        }
+        code = code << 4 | digit as u32;
+        digits += 1;
+        index = next;
+    }
+    let expected = if code > 0xFFFF {
+        (32 - code.leading_zeros()).div_ceil(4)
+    } else {
+        4
+    };
+    if digits == expected {
+        Some(code)
+    } else {
+        None
+    }
+}
+
+/// Parses the jamo short names of a Hangul syllable name starting at `index`.
+const fn hangul_syllable(query: &[u8], index: usize) -> Option<char> {
+    let mut leading = 0;
+    while leading < JAMO_L.len() {
+        if let Some(index) = strip_prefix(query, index, JAMO_L[leading].as_bytes()) {
+            let mut vowel = 0;
+            while vowel < JAMO_V.len() {
+                if let Some(index) = strip_prefix(query, index, JAMO_V[vowel].as_bytes()) {
+                    let mut trailing = 0;
+                    while trailing < JAMO_T.len() {
+                        if let Some(index) = strip_prefix(query, index, JAMO_T[trailing].as_bytes())
+                        {
+                            if next_byte(query, index).is_none() {
+                                let code = HANGUL_S_BASE
+                                    + leading as u32 * HANGUL_N_COUNT
+                                    + vowel as u32 * HANGUL_T_COUNT
+                                    + trailing as u32;
+                                return char::from_u32(code);
+                            }
+                        }
+                        trailing += 1;
+                    }
+                }
+                vowel += 1;
+            }
+        }
+        leading += 1;
+    }
+    None
+}
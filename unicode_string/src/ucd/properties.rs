@@ -0,0 +1,132 @@
+use crate::tables::properties::*;
+use crate::ucd::{in_ranges, range_value};
+
+/// Returns the version of Unicode that assigned `c` (the `Age` property) as a major and minor
+/// version, or `None` if `c` is unassigned.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::ucd;
+///
+/// assert_eq!(ucd::age('a'), Some((1, 1)));
+/// assert_eq!(ucd::age('€'), Some((2, 1)));
+/// assert_eq!(ucd::age('🦀'), Some((8, 0)));
+/// assert_eq!(ucd::age('\u{378}'), None);
+/// ```
+#[inline]
+pub fn age(c: char) -> Option<(u8, u8)> {
+    range_value(AGE, c)
+}
+
+macro_rules! binary_properties {
+    ($($(#[$attr:meta])* $name:ident => $table:ident;)*) => {
+        $(
+            $(#[$attr])*
+            #[inline]
+            pub fn $name(c: char) -> bool {
+                in_ranges($table, c)
+            }
+        )*
+    };
+}
+
+binary_properties! {
+    /// Checks if `c` is alphabetic (the `Alphabetic` property): letters, letter numbers and the
+    /// marks and other chars used like letters.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ucd;
+    ///
+    /// assert!(ucd::is_alphabetic('a'));
+    /// assert!(ucd::is_alphabetic('Ⅻ'));
+    /// assert!(!ucd::is_alphabetic('1'));
+    /// ```
+    is_alphabetic => ALPHABETIC;
+    /// Checks if `c` is a dash or hyphen (the `Dash` property).
+    is_dash => DASH;
+    /// Checks if `c` is ignored by default when rendering (the `Default_Ignorable_Code_Point`
+    /// property), like U+200B ZERO WIDTH SPACE or the variation selectors.
+    is_default_ignorable_code_point => DEFAULT_IGNORABLE_CODE_POINT;
+    /// Checks if `c` can be displayed as an emoji (the `Emoji` property).
+    ///
+    /// Note that this includes the ASCII digits, `#` and `*`, which are only displayed as
+    /// emoji in keycap sequences.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ucd;
+    ///
+    /// assert!(ucd::is_emoji('🦀'));
+    /// assert!(ucd::is_emoji('©'));
+    /// assert!(!ucd::is_emoji('a'));
+    /// ```
+    is_emoji => EMOJI;
+    /// Checks if `c` is used in emoji sequences, e.g. as a skin tone modifier or a zero width
+    /// joiner (the `Emoji_Component` property).
+    is_emoji_component => EMOJI_COMPONENT;
+    /// Checks if `c` is a skin tone modifier (the `Emoji_Modifier` property).
+    is_emoji_modifier => EMOJI_MODIFIER;
+    /// Checks if `c` can be followed by a skin tone modifier (the `Emoji_Modifier_Base`
+    /// property).
+    is_emoji_modifier_base => EMOJI_MODIFIER_BASE;
+    /// Checks if `c` is displayed as an emoji by default (the `Emoji_Presentation` property).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ucd;
+    ///
+    /// assert!(ucd::is_emoji_presentation('🦀'));
+    /// assert!(!ucd::is_emoji_presentation('©'));
+    /// ```
+    is_emoji_presentation => EMOJI_PRESENTATION;
+    /// Checks if `c` is a pictographic symbol or reserved for one (the `Extended_Pictographic`
+    /// property).
+    is_extended_pictographic => EXTENDED_PICTOGRAPHIC;
+    /// Checks if `c` can continue an identifier (the `ID_Continue` property).
+    is_id_continue => ID_CONTINUE;
+    /// Checks if `c` can start an identifier (the `ID_Start` property).
+    is_id_start => ID_START;
+    /// Checks if `c` is an ideograph (the `Ideographic` property).
+    is_ideographic => IDEOGRAPHIC;
+    /// Checks if `c` is lowercase (the `Lowercase` property).
+    is_lowercase => LOWERCASE;
+    /// Checks if `c` is a mathematical symbol or letter (the `Math` property).
+    is_math => MATH;
+    /// Checks if `c` is a noncharacter, a code point permanently reserved for internal use (the
+    /// `Noncharacter_Code_Point` property).
+    is_noncharacter_code_point => NONCHARACTER_CODE_POINT;
+    /// Checks if `c` is reserved for syntax in patterns (the `Pattern_Syntax` property).
+    is_pattern_syntax => PATTERN_SYNTAX;
+    /// Checks if `c` is whitespace in patterns (the `Pattern_White_Space` property).
+    is_pattern_white_space => PATTERN_WHITE_SPACE;
+    /// Checks if `c` is a quotation mark (the `Quotation_Mark` property).
+    is_quotation_mark => QUOTATION_MARK;
+    /// Checks if `c` is one of the regional indicator symbols used in flag emoji (the
+    /// `Regional_Indicator` property).
+    is_regional_indicator => REGIONAL_INDICATOR;
+    /// Checks if `c` is uppercase (the `Uppercase` property).
+    is_uppercase => UPPERCASE;
+    /// Checks if `c` is a variation selector (the `Variation_Selector` property).
+    is_variation_selector => VARIATION_SELECTOR;
+    /// Checks if `c` is whitespace (the `White_Space` property).
+    is_white_space => WHITE_SPACE;
+    /// Checks if `c` can continue an identifier, with the closure under NFKC normalization
+    /// (the `XID_Continue` property).
+    is_xid_continue => XID_CONTINUE;
+    /// Checks if `c` can start an identifier, with the closure under NFKC normalization (the
+    /// `XID_Start` property).
+    is_xid_start => XID_START;
+}
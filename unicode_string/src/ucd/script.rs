@@ -0,0 +1,84 @@
+pub use crate::tables::scripts::Script;
+
+use crate::tables::scripts::{SCRIPT, SCRIPTS, SCRIPT_EXTENSIONS, SCRIPT_NAMES};
+use crate::ucd::{loose_eq, range_value};
+
+impl Script {
+    /// Returns the long name of the script, e.g. `"Old_Italic"`.
+    #[inline]
+    pub fn name(self) -> &'static str {
+        SCRIPT_NAMES[self as usize].0
+    }
+
+    /// Returns the four-letter ISO 15924 code of the script, e.g. `"Ital"`.
+    #[inline]
+    pub fn short_name(self) -> &'static str {
+        SCRIPT_NAMES[self as usize].1
+    }
+
+    /// Finds a script by its long name or its ISO 15924 code. Names are matched loosely: case,
+    /// whitespace, underscores and hyphens are ignored.
+    pub fn from_name(name: &str) -> Option<Script> {
+        SCRIPT_NAMES
+            .iter()
+            .position(|&(long, short)| loose_eq(name, long) || loose_eq(name, short))
+            .map(|index| SCRIPTS[index])
+    }
+
+    /// Returns a slice containing only this script.
+    #[inline]
+    pub(crate) fn as_slice(self) -> &'static [Script] {
+        let index = self as usize;
+        &SCRIPTS[index..=index]
+    }
+}
+
+/// Returns the script of `c`.
+///
+/// Chars shared by several scripts have the script [`Common`](Script::Common) or
+/// [`Inherited`](Script::Inherited), even if they are only used by a few of them. Use
+/// [`script_extensions`] to find out which scripts these chars are used with.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::ucd::{self, Script};
+///
+/// assert_eq!(ucd::script('a'), Script::Latin);
+/// assert_eq!(ucd::script('α'), Script::Greek);
+/// assert_eq!(ucd::script('漢'), Script::Han);
+/// assert_eq!(ucd::script('1'), Script::Common);
+/// assert_eq!(ucd::script('\u{301}'), Script::Inherited);
+/// assert_eq!(ucd::script('\u{378}'), Script::Unknown);
+/// ```
+#[inline]
+pub fn script(c: char) -> Script {
+    range_value(SCRIPT, c).unwrap_or(Script::Unknown)
+}
+
+/// Returns the scripts `c` is used with (the `Script_Extensions` property).
+///
+/// For most chars this is just their [`script`], but some chars with the script
+/// [`Common`](Script::Common) or [`Inherited`](Script::Inherited) are only used with a few
+/// scripts, and some chars of a specific script are also used with others.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::ucd::{self, Script};
+///
+/// assert_eq!(ucd::script_extensions('a'), [Script::Latin]);
+/// assert_eq!(ucd::script_extensions('!'), [Script::Common]);
+///
+/// // KATAKANA-HIRAGANA PROLONGED SOUND MARK
+/// assert_eq!(ucd::script('ー'), Script::Common);
+/// assert_eq!(ucd::script_extensions('ー'), [Script::Hiragana, Script::Katakana]);
+/// ```
+#[inline]
+pub fn script_extensions(c: char) -> &'static [Script] {
+    range_value(SCRIPT_EXTENSIONS, c).unwrap_or_else(|| script(c).as_slice())
+}
@@ -0,0 +1,75 @@
+use std::iter::FusedIterator;
+
+use crate::ucd::script_set::ScriptSet;
+use crate::ucd::{script, script_extensions, Script};
+use crate::unicode_str;
+
+/// Checks if the script extensions of `c` are just `Common` or `Inherited`, i.e. if `c` can be
+/// used with any script.
+#[inline]
+pub(crate) fn is_shared(scripts: &[Script]) -> bool {
+    matches!(scripts, [Script::Common | Script::Inherited])
+}
+
+/// An iterator over the runs of a string that are written in a single script.
+///
+/// This struct is created by the [`script_runs`] method on [`unicode_str`]. See its
+/// documentation for more.
+///
+/// [`script_runs`]: unicode_str::script_runs
+#[derive(Clone, Debug)]
+pub struct ScriptRuns<'a> {
+    rest: &'a unicode_str,
+}
+
+impl<'a> ScriptRuns<'a> {
+    #[inline]
+    pub(crate) fn new(s: &'a unicode_str) -> ScriptRuns<'a> {
+        ScriptRuns { rest: s }
+    }
+}
+
+impl<'a> Iterator for ScriptRuns<'a> {
+    type Item = (Script, &'a unicode_str);
+
+    fn next(&mut self) -> Option<(Script, &'a unicode_str)> {
+        let chars = self.rest.chars();
+        let first = *chars.first()?;
+
+        // The scripts the run can be in, or `None` while it only contains shared chars.
+        let mut candidates: Option<ScriptSet> = None;
+        let mut len = 0;
+        for &c in chars {
+            let scripts = script_extensions(c);
+            if !is_shared(scripts) {
+                let set = ScriptSet::new(scripts);
+                match candidates {
+                    None => candidates = Some(set),
+                    Some(current) => {
+                        let intersection = current.intersection(set);
+                        if intersection.is_empty() {
+                            break;
+                        }
+                        candidates = Some(intersection);
+                    }
+                }
+            }
+            len += 1;
+        }
+
+        let run = unicode_str::from_chars(&chars[..len]);
+        self.rest = unicode_str::from_chars(&chars[len..]);
+        let script = candidates
+            .and_then(|candidates| candidates.single())
+            .unwrap_or_else(|| {
+                run.chars()
+                    .iter()
+                    .map(|&c| script(c))
+                    .find(|&script| !matches!(script, Script::Common | Script::Inherited))
+                    .unwrap_or_else(|| script(first))
+            });
+        Some((script, run))
+    }
+}
+
+impl FusedIterator for ScriptRuns<'_> {}
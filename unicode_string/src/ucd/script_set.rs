@@ -0,0 +1,54 @@
+use crate::tables::scripts::{SCRIPTS, SCRIPT_COUNT};
+use crate::ucd::Script;
+
+/// A set of scripts, stored as a bitset indexed by the discriminant of [`Script`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ScriptSet([u64; SCRIPT_COUNT.div_ceil(64)]);
+
+impl ScriptSet {
+    pub(crate) fn new(scripts: &[Script]) -> ScriptSet {
+        let mut set = ScriptSet([0; SCRIPT_COUNT.div_ceil(64)]);
+        for &script in scripts {
+            set.0[script as usize / 64] |= 1 << (script as usize % 64);
+        }
+        set
+    }
+
+    /// Returns the set of all scripts.
+    pub(crate) fn all() -> ScriptSet {
+        ScriptSet::new(SCRIPTS)
+    }
+
+    pub(crate) fn intersection(self, other: ScriptSet) -> ScriptSet {
+        let mut set = self;
+        for (word, other) in set.0.iter_mut().zip(other.0) {
+            *word &= other;
+        }
+        set
+    }
+
+    pub(crate) fn contains(&self, script: Script) -> bool {
+        self.0[script as usize / 64] & 1 << (script as usize % 64) != 0
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    /// Returns the script if the set contains exactly one.
+    pub(crate) fn single(&self) -> Option<Script> {
+        let count: u32 = self.0.iter().map(|word| word.count_ones()).sum();
+        if count != 1 {
+            return None;
+        }
+        self.iter().next()
+    }
+
+    /// Returns an iterator over the scripts of the set, in the order of [`Script`].
+    pub(crate) fn iter(&self) -> impl Iterator<Item = Script> + '_ {
+        SCRIPTS
+            .iter()
+            .copied()
+            .filter(move |&script| self.contains(script))
+    }
+}
@@ -0,0 +1,77 @@
+use std::cmp::Ordering;
+
+use crate::case::CaseFold;
+use crate::{unicode_str, UnicodeString};
+
+impl unicode_str {
+    /// Returns the full case folding of this string slice, as a new [`UnicodeString`].
+    ///
+    /// Case folding maps strings that differ only in case to the same string, and is the
+    /// recommended way of doing case-insensitive matching. This uses the full folding from
+    /// `CaseFolding.txt`, so a single char may fold to several chars (e.g. `ß` folds to `ss`).
+    /// The result is not meant to be displayed: it is neither lowercase nor uppercase text.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ustr;
+    ///
+    /// assert_eq!(ustr!("hello"), ustr!("Hello").case_fold());
+    /// assert_eq!(ustr!("strasse"), ustr!("Straße").case_fold());
+    /// assert_eq!(ustr!("σοφοσ"), ustr!("ΣΟΦΟΣ").case_fold());
+    /// ```
+    #[must_use = "this returns the case folded string as a new UnicodeString, \
+                  without modifying the original"]
+    pub fn case_fold(&self) -> UnicodeString {
+        UnicodeString {
+            vec: CaseFold::new(self).collect(),
+        }
+    }
+
+    /// Checks that two strings are equal after full case folding.
+    ///
+    /// Both strings are folded on the fly, so this does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ustr;
+    ///
+    /// assert!(ustr!("Ferris").eq_ignore_case(ustr!("FERRIS")));
+    /// assert!(ustr!("Maße").eq_ignore_case(ustr!("MASSE")));
+    /// assert!(!ustr!("Ferris").eq_ignore_case(ustr!("Ferri")));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn eq_ignore_case(&self, other: &unicode_str) -> bool {
+        CaseFold::new(self).eq(CaseFold::new(other))
+    }
+
+    /// Compares two strings after full case folding.
+    ///
+    /// Like the [`Ord`] implementation of `unicode_str`, the folded strings are compared by
+    /// code point, which is not necessarily alphabetical order. Both strings are folded on the
+    /// fly, so this does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use unicode_string::ustr;
+    ///
+    /// assert_eq!(Ordering::Equal, ustr!("Straße").cmp_ignore_case(ustr!("STRASSE")));
+    /// assert_eq!(Ordering::Less, ustr!("apple").cmp_ignore_case(ustr!("Banana")));
+    /// assert_eq!(Ordering::Greater, ustr!("apple").cmp(ustr!("Banana")));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cmp_ignore_case(&self, other: &unicode_str) -> Ordering {
+        CaseFold::new(self).cmp(CaseFold::new(other))
+    }
+}
@@ -1,9 +1,32 @@
-use crate::{unicode_str};
+use std::hash::{Hash, Hasher};
 
+use crate::{search, unicode_str};
+
+/// Checks if two strings have the same chars, comparing many chars at once with AVX2 or SSE2
+/// when available.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::{ustr, UnicodeString};
+///
+/// assert_eq!(ustr!("Grüße"), ustr!("Grüße"));
+/// assert_ne!(ustr!("Grüße"), ustr!("Grüsse"));
+///
+/// // Strings longer than a vector, which differ in their last char.
+/// let a = UnicodeString::from_string(&"ü".repeat(100));
+/// let b = UnicodeString::from_string(&("ü".repeat(99) + "u"));
+/// assert_eq!(a, a.clone());
+/// assert_ne!(a, b);
+/// assert_ne!(a[..99], b[..]);
+/// assert_eq!(a[..99], b[..99]);
+/// ```
 impl PartialEq for unicode_str {
     #[inline]
     fn eq(&self, other: &unicode_str) -> bool {
-        self.chars == other.chars
+        search::eq(&self.chars, &other.chars)
     }
     #[inline]
     fn ne(&self, other: &unicode_str) -> bool {
@@ -13,13 +36,21 @@ impl PartialEq for unicode_str {
 
 impl Eq for unicode_str {}
 
+impl Hash for unicode_str {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.chars.hash(state)
+    }
+}
+
 /// Implements comparison operations on strings.
 ///
 /// Strings are compared [lexicographically](Ord#lexicographical-comparison) by their byte values. This compares Unicode code
 /// points based on their positions in the code charts. This is not necessarily the same as
-/// "alphabetical" order, which varies by language and locale. Comparing strings according to
-/// culturally-accepted standards requires locale-specific data that is outside the scope of
-/// the `str` type.
+/// "alphabetical" order, which varies by language and locale. Use a [`Collator`] to compare
+/// strings according to the Unicode Collation Algorithm and culturally-accepted standards.
+///
+/// [`Collator`]: crate::Collator
 impl PartialOrd for unicode_str {
     #[inline]
     fn partial_cmp(&self, other: &unicode_str) -> Option<std::cmp::Ordering> {
@@ -33,4 +64,3 @@ impl Ord for unicode_str {
         self.chars.cmp(&other.chars)
     }
 }
-
@@ -0,0 +1,217 @@
+use std::fmt::{self, Write};
+use std::iter::{Copied, Enumerate, FlatMap, FusedIterator};
+use std::{char, slice, str};
+
+use crate::unicode_str;
+
+impl unicode_str {
+    /// Returns an iterator over the chars of the string escaped like [`str::escape_debug`].
+    ///
+    /// Quotes, backslashes and `\t`, `\r` and `\n` are escaped with a backslash, and the other
+    /// chars that are not printable with `\u{NNNN}`. Grapheme extenders, like combining marks,
+    /// are only escaped at the start of the string, where they have nothing to combine with.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ustr;
+    ///
+    /// let s = ustr!("\u{301}e\u{301} \"\u{200B}\"\n");
+    /// assert_eq!(s.escape_debug().to_string(), "\\u{301}e\u{301} \\\"\\u{200b}\\\"\\n");
+    /// ```
+    pub fn escape_debug(&self) -> EscapeDebug<'_> {
+        EscapeDebug {
+            inner: self
+                .chars()
+                .iter()
+                .copied()
+                .enumerate()
+                .flat_map(EscapedChar::debug as fn(_) -> _),
+        }
+    }
+
+    /// Returns an iterator over the chars of the string escaped like [`str::escape_default`].
+    ///
+    /// Printable ASCII is kept, except for quotes and backslashes which are escaped with a
+    /// backslash like `\t`, `\r` and `\n`, and all the other chars are escaped with
+    /// `\u{NNNN}`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ustr;
+    ///
+    /// let s = ustr!("'né'\t");
+    /// assert_eq!(s.escape_default().to_string(), "\\'n\\u{e9}\\'\\t");
+    /// ```
+    pub fn escape_default(&self) -> EscapeDefault<'_> {
+        EscapeDefault {
+            inner: self
+                .chars()
+                .iter()
+                .copied()
+                .flat_map(char::escape_default as fn(_) -> _),
+        }
+    }
+
+    /// Returns an iterator over the chars of the string all escaped with `\u{NNNN}`, like
+    /// [`str::escape_unicode`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ustr;
+    ///
+    /// assert_eq!(ustr!("né").escape_unicode().to_string(), "\\u{6e}\\u{e9}");
+    /// ```
+    pub fn escape_unicode(&self) -> EscapeUnicode<'_> {
+        EscapeUnicode {
+            inner: self
+                .chars()
+                .iter()
+                .copied()
+                .flat_map(char::escape_unicode as fn(_) -> _),
+        }
+    }
+}
+
+/// Writes the string quoted and escaped like a `str`, or with the alternate flag (`{:#?}`), as
+/// the list of its code points.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::ustr;
+///
+/// let s = ustr!("e\u{301} \t");
+/// assert_eq!(format!("{:?}", s), "\"e\\u{301} \\t\"");
+/// assert_eq!(format!("{:#?}", s), "U+0065 U+0301 U+0020 U+0009");
+/// ```
+impl fmt::Debug for unicode_str {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            for (index, &c) in self.chars().iter().enumerate() {
+                if index > 0 {
+                    f.write_char(' ')?;
+                }
+                write!(f, "U+{:04X}", u32::from(c))?;
+            }
+            return Ok(());
+        }
+        f.write_char('"')?;
+        for &c in self.chars() {
+            // Unlike a char, a string is quoted with double quotes only.
+            if c == '\'' {
+                f.write_char(c)?;
+            } else {
+                write!(f, "{}", c.escape_debug())?;
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+/// The escape of a single char, which is at most `\u{10FFFF}`.
+#[derive(Clone, Debug)]
+struct EscapedChar {
+    chars: [char; 10],
+    start: u8,
+    end: u8,
+}
+
+impl EscapedChar {
+    fn new(escape: impl Iterator<Item = char>) -> EscapedChar {
+        let mut escaped = EscapedChar {
+            chars: ['\0'; 10],
+            start: 0,
+            end: 0,
+        };
+        for c in escape {
+            escaped.chars[usize::from(escaped.end)] = c;
+            escaped.end += 1;
+        }
+        escaped
+    }
+
+    /// Escapes the char at the given index of a string as [`str::escape_debug`] does, which
+    /// unlike [`char::escape_debug`] keeps the grapheme extenders after the start.
+    fn debug((index, c): (usize, char)) -> EscapedChar {
+        if index == 0 {
+            return EscapedChar::new(c.escape_debug());
+        }
+        let mut buffer = [0; 5];
+        buffer[0] = b' ';
+        let len = c.encode_utf8(&mut buffer[1..]).len();
+        let s = str::from_utf8(&buffer[..1 + len]).unwrap();
+        EscapedChar::new(s.escape_debug().skip(1))
+    }
+}
+
+impl Iterator for EscapedChar {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        Some(self.chars[usize::from(self.start - 1)])
+    }
+}
+
+macro_rules! escape_iterator {
+    ($(#[$attr:meta])* $name:ident, $inner:ty) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug)]
+        pub struct $name<'a> {
+            inner: $inner,
+        }
+
+        impl Iterator for $name<'_> {
+            type Item = char;
+
+            #[inline]
+            fn next(&mut self) -> Option<char> {
+                self.inner.next()
+            }
+        }
+
+        impl FusedIterator for $name<'_> {}
+
+        impl fmt::Display for $name<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.clone().try_for_each(|c| f.write_char(c))
+            }
+        }
+    };
+}
+
+escape_iterator!(
+    /// An iterator over the chars of a string escaped for debugging, created by
+    /// [`unicode_str::escape_debug`].
+    EscapeDebug,
+    FlatMap<Enumerate<Copied<slice::Iter<'a, char>>>, EscapedChar, fn((usize, char)) -> EscapedChar>
+);
+
+escape_iterator!(
+    /// An iterator over the chars of a string escaped like Rust literals, created by
+    /// [`unicode_str::escape_default`].
+    EscapeDefault,
+    FlatMap<Copied<slice::Iter<'a, char>>, char::EscapeDefault, fn(char) -> char::EscapeDefault>
+);
+
+escape_iterator!(
+    /// An iterator over the chars of a string all escaped with `\u{NNNN}`, created by
+    /// [`unicode_str::escape_unicode`].
+    EscapeUnicode,
+    FlatMap<Copied<slice::Iter<'a, char>>, char::EscapeUnicode, fn(char) -> char::EscapeUnicode>
+);
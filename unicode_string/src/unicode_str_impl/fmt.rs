@@ -0,0 +1,71 @@
+use std::fmt::{self, Alignment};
+
+use crate::unicode_str;
+
+/// Writes the string like `str`, padded to the width and truncated to the precision of the
+/// formatter, both counted in chars.
+///
+/// The chars are encoded into the formatter in chunks through a buffer on the stack, so no
+/// `String` is allocated.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::ustr;
+///
+/// let s = ustr!("Grüße");
+/// assert_eq!(format!("{}", s), "Grüße");
+/// assert_eq!(format!("[{:>8}]", s), "[   Grüße]");
+/// assert_eq!(format!("[{:*^9.3}]", s), "[***Grü***]");
+/// ```
+impl fmt::Display for unicode_str {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let chars = match f.precision() {
+            Some(precision) if precision < self.len() => &self.chars()[..precision],
+            _ => self.chars(),
+        };
+        let padding = match f.width() {
+            Some(width) if width > chars.len() => width - chars.len(),
+            _ => return write_chars(chars, f),
+        };
+        // Like `str`, the text is aligned to the left by default.
+        let (before, after) = match f.align() {
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+            Some(Alignment::Left) | None => (0, padding),
+        };
+        let fill = f.fill();
+        write_fill(fill, before, f)?;
+        write_chars(chars, f)?;
+        write_fill(fill, after, f)
+    }
+}
+
+/// Writes the chars to the formatter encoded in UTF-8 through a buffer on the stack.
+fn write_chars(chars: &[char], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut buffer = [0; 256];
+    let mut len = 0;
+    for &c in chars {
+        if len + c.len_utf8() > buffer.len() {
+            f.write_str(buffer_str(&buffer[..len]))?;
+            len = 0;
+        }
+        len += c.encode_utf8(&mut buffer[len..]).len();
+    }
+    f.write_str(buffer_str(&buffer[..len]))
+}
+
+fn write_fill(fill: char, count: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut buffer = [0; 4];
+    let fill = fill.encode_utf8(&mut buffer);
+    (0..count).try_for_each(|_| f.write_str(fill))
+}
+
+/// Views a buffer made of whole chars encoded by [`char::encode_utf8`] as a `str`.
+#[inline]
+fn buffer_str(buffer: &[u8]) -> &str {
+    // SAFETY: the buffer only ever contains whole chars encoded in UTF-8.
+    unsafe { std::str::from_utf8_unchecked(buffer) }
+}
@@ -0,0 +1,41 @@
+use std::io::{self, Write};
+
+use crate::unicode_str;
+
+impl unicode_str {
+    /// Writes the string to `writer` encoded in UTF-8, without allocating a `String`.
+    ///
+    /// The string is encoded in chunks through a buffer on the stack, so `writer` does not
+    /// need to be buffered. See [`UnicodeWriter`](crate::io::UnicodeWriter) for other
+    /// encodings, and [`UnicodeStrReader`](crate::io::UnicodeStrReader) to read the encoded
+    /// bytes instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of `writer`, other than
+    /// [`Interrupted`](io::ErrorKind::Interrupted).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ustr;
+    ///
+    /// let mut bytes = Vec::new();
+    /// ustr!("Grüße").write_utf8_to(&mut bytes).unwrap();
+    /// assert_eq!(bytes, "Grüße".as_bytes());
+    /// ```
+    pub fn write_utf8_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        let mut buffer = [0; 1024];
+        let mut len = 0;
+        for &c in self.chars() {
+            if len + c.len_utf8() > buffer.len() {
+                writer.write_all(&buffer[..len])?;
+                len = 0;
+            }
+            len += c.encode_utf8(&mut buffer[len..]).len();
+        }
+        writer.write_all(&buffer[..len])
+    }
+}
@@ -1,5 +1,14 @@
 mod unicode_str_impl;
+mod case;
 mod cmp;
+mod escape;
+mod fmt;
 mod index;
+mod io;
+mod natural;
+mod scripts;
+mod search;
+mod segmentation;
 
+pub use self::escape::{EscapeDebug, EscapeDefault, EscapeUnicode};
 pub use self::unicode_str_impl::*;
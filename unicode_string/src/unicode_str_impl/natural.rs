@@ -0,0 +1,30 @@
+use std::cmp::Ordering;
+
+use crate::natural::NaturalOrder;
+use crate::unicode_str;
+
+impl unicode_str {
+    /// Compares two strings in natural order, comparing runs of decimal digits by their
+    /// numeric value.
+    ///
+    /// This is a shorthand for `NaturalOrder::new().compare(self, other)`. Use a
+    /// [`NaturalOrder`] to ignore case or leading zeros.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use unicode_string::ustr;
+    ///
+    /// assert_eq!(Ordering::Less, ustr!("file10").cmp(ustr!("file2")));
+    /// assert_eq!(Ordering::Greater, ustr!("file10").natural_cmp(ustr!("file2")));
+    /// assert_eq!(Ordering::Less, ustr!("file").natural_cmp(ustr!("file0")));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn natural_cmp(&self, other: &unicode_str) -> Ordering {
+        NaturalOrder::new().compare(self, other)
+    }
+}
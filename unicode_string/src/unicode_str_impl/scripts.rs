@@ -0,0 +1,68 @@
+use crate::ucd::{is_shared, script_extensions, Script, ScriptRuns};
+use crate::unicode_str;
+
+impl unicode_str {
+    /// Returns an iterator over the runs of the string that are written in a single script,
+    /// along with their script.
+    ///
+    /// Chars shared by several scripts, like spaces, punctuation and combining marks, are
+    /// added to the run they appear in, or to the following run at the start of the string.
+    /// Chars used by only a few scripts (their
+    /// [script extensions](crate::ucd::script_extensions)) continue a run if it is in one of
+    /// these scripts. A run made only of shared chars has their script, usually
+    /// [`Common`](Script::Common).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ucd::Script;
+    /// use unicode_string::ustr;
+    ///
+    /// let runs: Vec<_> = ustr!("Hello, мир! 東京").script_runs().collect();
+    /// assert_eq!(
+    ///     runs,
+    ///     [
+    ///         (Script::Latin, ustr!("Hello, ")),
+    ///         (Script::Cyrillic, ustr!("мир! ")),
+    ///         (Script::Han, ustr!("東京")),
+    ///     ]
+    /// );
+    ///
+    /// let runs: Vec<_> = ustr!("123").script_runs().collect();
+    /// assert_eq!(runs, [(Script::Common, ustr!("123"))]);
+    /// ```
+    #[inline]
+    pub fn script_runs(&self) -> ScriptRuns<'_> {
+        ScriptRuns::new(self)
+    }
+
+    /// Checks if the string only uses the given scripts.
+    ///
+    /// Chars shared by all scripts (whose [script extensions](crate::ucd::script_extensions)
+    /// are [`Common`](Script::Common) or [`Inherited`](Script::Inherited)) are always allowed.
+    /// Other chars are allowed if one of their script extensions is in `scripts`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ucd::Script;
+    /// use unicode_string::ustr;
+    ///
+    /// assert!(ustr!("Hello, world!").uses_only_scripts(&[Script::Latin]));
+    /// assert!(ustr!("123 - 456").uses_only_scripts(&[]));
+    ///
+    /// // The second letter is CYRILLIC SMALL LETTER A.
+    /// assert!(!ustr!("pаypal").uses_only_scripts(&[Script::Latin]));
+    /// assert!(ustr!("pаypal").uses_only_scripts(&[Script::Latin, Script::Cyrillic]));
+    /// ```
+    pub fn uses_only_scripts(&self, scripts: &[Script]) -> bool {
+        self.chars().iter().all(|&c| {
+            let extensions = script_extensions(c);
+            is_shared(extensions) || extensions.iter().any(|script| scripts.contains(script))
+        })
+    }
+}
@@ -0,0 +1,155 @@
+use std::slice;
+
+use crate::case::CaseFold;
+use crate::search;
+use crate::unicode_str;
+
+impl unicode_str {
+    /// Returns the char index of the first occurrence of `pat` in this string slice.
+    ///
+    /// Returns [`None`] if the pattern doesn't match. An empty pattern matches at index `0`.
+    /// The returned index can be used directly to slice the string.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ustr;
+    ///
+    /// let s = ustr!("Löwe 老虎 Léopard");
+    ///
+    /// assert_eq!(s.find(ustr!("L")), Some(0));
+    /// assert_eq!(s.find(ustr!("老虎")), Some(5));
+    /// assert_eq!(s.find(ustr!("pard")), Some(11));
+    /// assert_eq!(s.find(ustr!("Tiger")), None);
+    /// ```
+    ///
+    /// The search takes time linear in the length of the string, even for periodic patterns
+    /// whose first and last chars are found almost everywhere:
+    ///
+    /// ```
+    /// use unicode_string::UnicodeString;
+    ///
+    /// let a = "a".repeat(10_000);
+    /// let haystack = UnicodeString::from_string(&(a.clone() + "b"));
+    /// let needle = UnicodeString::from_string(&("a".repeat(100) + "b"));
+    /// assert_eq!(haystack.find(&needle), Some(9_900));
+    ///
+    /// let needle = UnicodeString::from_string(&("a".repeat(50) + "b" + &"a".repeat(49)));
+    /// let haystack = UnicodeString::from_string(&(a.clone() + "b" + &"a".repeat(49)));
+    /// assert_eq!(haystack.find(&needle), Some(9_950));
+    /// let haystack = UnicodeString::from_string(&a);
+    /// assert_eq!(haystack.find(&needle), None);
+    /// ```
+    ///
+    /// Matches are found up to the very end of the string, whatever its length:
+    ///
+    /// ```
+    /// use unicode_string::ustr;
+    ///
+    /// let s = ustr!("abcdefghijklm");
+    /// assert_eq!(s.find(ustr!("m")), Some(12));
+    /// assert_eq!(s.find(ustr!("lm")), Some(11));
+    /// assert_eq!(s.find(ustr!("jklm")), Some(9));
+    /// assert_eq!(s.find(ustr!("lmn")), None);
+    /// assert_eq!(s[..11].find(ustr!("k")), Some(10));
+    /// assert_eq!(s[..7].find(ustr!("efg")), Some(4));
+    /// ```
+    #[must_use]
+    pub fn find(&self, pat: &unicode_str) -> Option<usize> {
+        search::find(&self.chars, pat.chars())
+    }
+
+    /// Returns `true` if the given pattern is a substring of this string slice.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ustr;
+    ///
+    /// let bananas = ustr!("bananas");
+    ///
+    /// assert!(bananas.contains(ustr!("nana")));
+    /// assert!(!bananas.contains(ustr!("apples")));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, pat: &unicode_str) -> bool {
+        self.find(pat).is_some()
+    }
+
+    /// Returns the char index of the first case-insensitive occurrence of `pat` in this string
+    /// slice.
+    ///
+    /// Both strings are compared by their full case folding (see [`case_fold`]). Since a
+    /// single char may fold to several chars, a match only counts if it starts and ends on
+    /// char boundaries of this string: `"s"` is not found in `"ß"`, but `"ss"` is. The
+    /// returned index refers to this string, not its folded form.
+    ///
+    /// [`case_fold`]: unicode_str::case_fold
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ustr;
+    ///
+    /// let s = ustr!("Die Straße ist lang");
+    ///
+    /// assert_eq!(s.find_ignore_case(ustr!("STRASSE")), Some(4));
+    /// assert_eq!(s.find_ignore_case(ustr!("IST")), Some(11));
+    /// assert_eq!(s.find_ignore_case(ustr!("stras")), None);
+    /// ```
+    #[must_use]
+    pub fn find_ignore_case(&self, pat: &unicode_str) -> Option<usize> {
+        let needle: Vec<char> = CaseFold::new(pat).collect();
+        if needle.is_empty() {
+            return Some(0);
+        }
+
+        // `boundaries[i]` is the index in `folded` at which the folding of `self[i]` starts.
+        let mut folded = Vec::with_capacity(self.len());
+        let mut boundaries = Vec::with_capacity(self.len() + 1);
+        for c in self.chars() {
+            boundaries.push(folded.len());
+            folded.extend(CaseFold::new(unicode_str::from_chars(slice::from_ref(c))));
+        }
+        boundaries.push(folded.len());
+
+        boundaries[..self.len()]
+            .iter()
+            .position(|&start| {
+                folded[start..].starts_with(&needle)
+                    && boundaries.binary_search(&(start + needle.len())).is_ok()
+            })
+    }
+
+    /// Returns `true` if the given pattern is a case-insensitive substring of this string
+    /// slice.
+    ///
+    /// See [`find_ignore_case`] for how the strings are compared.
+    ///
+    /// [`find_ignore_case`]: unicode_str::find_ignore_case
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ustr;
+    ///
+    /// let s = ustr!("ΟΔΥΣΣΕΥΣ");
+    ///
+    /// assert!(s.contains_ignore_case(ustr!("Οδυσσευς")));
+    /// assert!(!s.contains_ignore_case(ustr!("Αχιλλευς")));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains_ignore_case(&self, pat: &unicode_str) -> bool {
+        self.find_ignore_case(pat).is_some()
+    }
+}
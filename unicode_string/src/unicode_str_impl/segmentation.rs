@@ -0,0 +1,30 @@
+use crate::ucd::Graphemes;
+use crate::unicode_str;
+
+impl unicode_str {
+    /// Returns an iterator over the extended grapheme clusters of the string, the
+    /// user-perceived characters defined by [UAX #29](https://www.unicode.org/reports/tr29/).
+    ///
+    /// A grapheme cluster is a base char with the marks that combine with it, a Hangul
+    /// syllable made of jamo, an emoji sequence or a flag, or a `\r\n` pair.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::ustr;
+    ///
+    /// let s = ustr!("e\u{301}🇫🇷👩‍🔬\r\n");
+    /// let graphemes: Vec<_> = s.graphemes().collect();
+    /// assert_eq!(
+    ///     graphemes,
+    ///     [ustr!("e\u{301}"), ustr!("🇫🇷"), ustr!("👩‍🔬"), ustr!("\r\n")]
+    /// );
+    /// assert_eq!(s.len(), 9);
+    /// ```
+    #[inline]
+    pub fn graphemes(&self) -> Graphemes<'_> {
+        Graphemes::new(self)
+    }
+}
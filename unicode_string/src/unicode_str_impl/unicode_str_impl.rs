@@ -1,4 +1,3 @@
-use std::mem;
 use crate::UnicodeString;
 
 #[repr(C)]
@@ -76,12 +75,22 @@ impl unicode_str {
     ///
     /// assert_eq!(ustr!("💖"), sparkle_heart);
     /// ```
+    ///
+    /// It is a `const fn`, so it can build strings in `const` and `static` items:
+    ///
+    /// ```
+    /// use unicode_string::{unicode_str, ustr};
+    ///
+    /// static GREETING: &unicode_str = unicode_str::from_chars(&['h', 'é']);
+    ///
+    /// assert_eq!(GREETING, ustr!("hé"));
+    /// ```
     #[inline]
     #[must_use]
     pub const fn from_chars(v: &[char]) -> &unicode_str {
-        // SAFETY: the caller must guarantee that the bytes `v` are valid UTF-8.
-        // Also relies on `&u32str` and `&[char]` having the same layout.
-        unsafe { mem::transmute(v) }
+        // SAFETY: every slice of chars is a valid `unicode_str`, and `unicode_str` and `[char]`
+        // have the same layout.
+        unsafe { &*(v as *const [char] as *const unicode_str) }
     }
 
     /// Converts a slice of bytes to a string slice without checking
@@ -119,6 +128,13 @@ impl AsRef<[char]> for unicode_str {
     }
 }
 
+impl AsRef<unicode_str> for unicode_str {
+    #[inline]
+    fn as_ref(&self) -> &unicode_str {
+        self
+    }
+}
+
 impl const Default for &unicode_str {
     /// Creates an empty str
     #[inline]
@@ -142,14 +158,3 @@ impl ToOwned for unicode_str {
     }
 }
 
-impl std::fmt::Debug for unicode_str {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", &String::from_iter(self.chars().iter()))
-    }
-}
-
-impl std::fmt::Display for unicode_str {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        f.pad(&String::from_iter(self.chars().iter()))
-    }
-}
@@ -1,5 +1,6 @@
 use crate::{unicode_str, UnicodeString};
 use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
 
 impl PartialEq for UnicodeString {
     #[inline]
@@ -12,6 +13,13 @@ impl PartialEq for UnicodeString {
     }
 }
 
+impl Hash for UnicodeString {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
 macro_rules! impl_eq {
     ($lhs:ty, $rhs: ty) => {
         #[allow(unused_lifetimes)]
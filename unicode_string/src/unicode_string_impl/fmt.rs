@@ -0,0 +1,240 @@
+use std::fmt::{self, Write};
+
+use crate::{unicode_str, UnicodeString};
+
+/// Creates a `UnicodeString` using interpolation of runtime expressions, like [`format!`].
+///
+/// The arguments are written straight into the `UnicodeString`, without going through an
+/// intermediate `String`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::{uformat, ustr};
+///
+/// let name = "Zoë";
+/// assert_eq!(uformat!("Hello, {}!", name), ustr!("Hello, Zoë!"));
+/// assert_eq!(uformat!("{:>4}|{:<4}|", 7, 'é'), ustr!("   7|é   |"));
+/// ```
+#[macro_export]
+macro_rules! uformat {
+    ($($arg:tt)*) => {
+        $crate::format(::std::format_args!($($arg)*))
+    };
+}
+
+/// Writes formatted data into a [`fmt::Write`] sink, like [`write!`], without having to import
+/// the trait.
+///
+/// Returns the [`fmt::Result`] of the sink.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::{ustr, uwrite, UnicodeString};
+///
+/// let mut s = UnicodeString::new();
+/// uwrite!(s, "{}+{}", 1, 2).unwrap();
+/// uwrite!(&mut s, "={}", 3).unwrap();
+/// assert_eq!(s, ustr!("1+2=3"));
+/// ```
+#[macro_export]
+macro_rules! uwrite {
+    ($dst:expr, $($arg:tt)*) => {
+        ::std::fmt::Write::write_fmt(&mut $dst, ::std::format_args!($($arg)*))
+    };
+}
+
+/// Writes formatted data into a [`fmt::Write`] sink followed by a newline, like [`writeln!`],
+/// without having to import the trait.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::{ustr, uwriteln, UnicodeString};
+///
+/// let mut s = UnicodeString::new();
+/// uwriteln!(s, "{}", "first").unwrap();
+/// uwriteln!(s).unwrap();
+/// assert_eq!(s, ustr!("first\n\n"));
+/// ```
+#[macro_export]
+macro_rules! uwriteln {
+    ($dst:expr $(,)?) => {
+        $crate::uwrite!($dst, "\n")
+    };
+    ($dst:expr, $fmt:literal $($arg:tt)*) => {
+        $crate::uwrite!($dst, ::std::concat!($fmt, "\n") $($arg)*)
+    };
+}
+
+/// Takes an [`Arguments`](fmt::Arguments) struct and returns the resulting formatted
+/// `UnicodeString`, like [`std::fmt::format`].
+///
+/// This is the function behind [`uformat!`](crate::uformat).
+///
+/// # Panics
+///
+/// Panics if a formatting trait implementation returns an error, like [`format!`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::{format, ustr};
+///
+/// assert_eq!(format(format_args!("{}-{}", 1, 2)), ustr!("1-2"));
+/// ```
+pub fn format(args: fmt::Arguments<'_>) -> UnicodeString {
+    // Literals without arguments do not need to go through the formatting machinery.
+    if let Some(s) = args.as_str() {
+        return UnicodeString::from_string(s);
+    }
+    let mut output = UnicodeString::new();
+    output
+        .write_fmt(args)
+        .expect("a formatting trait implementation returned an error");
+    output
+}
+
+/// A trait for converting a value to a `UnicodeString`, like [`ToString`].
+///
+/// It is implemented for every type that implements [`Display`](fmt::Display), and the text is
+/// written straight into the `UnicodeString`. Strings, chars and integers are converted
+/// without going through the formatting machinery at all.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::{ustr, ToUnicodeString};
+///
+/// assert_eq!("né".to_ustring(), ustr!("né"));
+/// assert_eq!('é'.to_ustring(), ustr!("é"));
+/// assert_eq!((-42).to_ustring(), ustr!("-42"));
+/// assert_eq!(1.5.to_ustring(), ustr!("1.5"));
+/// ```
+pub trait ToUnicodeString {
+    /// Converts the given value to a `UnicodeString`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`Display`](fmt::Display) implementation of the value returns an error,
+    /// like [`ToString::to_string`].
+    fn to_ustring(&self) -> UnicodeString;
+}
+
+impl<T: fmt::Display + ?Sized> ToUnicodeString for T {
+    #[inline]
+    fn to_ustring(&self) -> UnicodeString {
+        SpecToUnicodeString::spec_to_ustring(self)
+    }
+}
+
+/// The specialization of [`ToUnicodeString`], kept private so that the fast paths are an
+/// implementation detail.
+trait SpecToUnicodeString {
+    fn spec_to_ustring(&self) -> UnicodeString;
+}
+
+impl<T: fmt::Display + ?Sized> SpecToUnicodeString for T {
+    #[inline]
+    default fn spec_to_ustring(&self) -> UnicodeString {
+        let mut output = UnicodeString::new();
+        write!(output, "{}", self).expect("a Display implementation returned an error");
+        output
+    }
+}
+
+impl SpecToUnicodeString for str {
+    #[inline]
+    fn spec_to_ustring(&self) -> UnicodeString {
+        UnicodeString::from_string(self)
+    }
+}
+
+impl SpecToUnicodeString for String {
+    #[inline]
+    fn spec_to_ustring(&self) -> UnicodeString {
+        UnicodeString::from_string(self)
+    }
+}
+
+impl SpecToUnicodeString for char {
+    #[inline]
+    fn spec_to_ustring(&self) -> UnicodeString {
+        UnicodeString { vec: vec![*self] }
+    }
+}
+
+impl SpecToUnicodeString for unicode_str {
+    #[inline]
+    fn spec_to_ustring(&self) -> UnicodeString {
+        self.to_owned()
+    }
+}
+
+impl SpecToUnicodeString for UnicodeString {
+    #[inline]
+    fn spec_to_ustring(&self) -> UnicodeString {
+        self.clone()
+    }
+}
+
+/// Writes the decimal digits of an integer at the end of `chars`, returning their start.
+macro_rules! write_digits {
+    ($n:expr, $chars:expr) => {{
+        let mut n = $n;
+        let mut start = $chars.len();
+        loop {
+            start -= 1;
+            $chars[start] = char::from(b'0' + (n % 10) as u8);
+            n /= 10;
+            if n == 0 {
+                break start;
+            }
+        }
+    }};
+}
+
+macro_rules! integer_to_ustring {
+    (unsigned: $($t:ty),*; signed: $($s:ty),*) => {
+        $(
+            impl SpecToUnicodeString for $t {
+                #[inline]
+                fn spec_to_ustring(&self) -> UnicodeString {
+                    let mut chars = ['\0'; 39];
+                    let start = write_digits!(*self, chars);
+                    UnicodeString { vec: chars[start..].to_vec() }
+                }
+            }
+        )*
+        $(
+            impl SpecToUnicodeString for $s {
+                #[inline]
+                fn spec_to_ustring(&self) -> UnicodeString {
+                    let mut chars = ['\0'; 40];
+                    let mut start = write_digits!(self.unsigned_abs(), chars);
+                    if *self < 0 {
+                        start -= 1;
+                        chars[start] = '-';
+                    }
+                    UnicodeString { vec: chars[start..].to_vec() }
+                }
+            }
+        )*
+    };
+}
+
+integer_to_ustring!(
+    unsigned: u8, u16, u32, u64, u128, usize;
+    signed: i8, i16, i32, i64, i128, isize
+);
@@ -1,7 +1,9 @@
+mod fmt;
 mod from_utf8_error;
 mod unicode_string;
 mod cmp;
 mod index;
 
+pub use self::fmt::{format, ToUnicodeString};
 pub use self::from_utf8_error::*;
 pub use self::unicode_string::*;
@@ -1,8 +1,8 @@
-use std::borrow::{Borrow, BorrowMut};
-use std::{ops, str};
+use std::borrow::{Borrow, BorrowMut, Cow};
+use std::{fmt, ops, str};
 use crate::{FromUtf8Error, unicode_str};
 
-#[derive(Debug, PartialOrd, Eq, Ord)]
+#[derive(PartialOrd, Eq, Ord)]
 pub struct UnicodeString {
     pub(crate) vec: Vec<char>,
 }
@@ -230,6 +230,63 @@ impl UnicodeString {
     pub fn push(&mut self, ch: char) {
         self.vec.push(ch)
     }
+
+    /// Converts this `UnicodeString` into a <code>[Box]<[unicode_str]></code>.
+    ///
+    /// This will drop any excess capacity.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use unicode_string::{ustr, UnicodeString};
+    ///
+    /// let s = UnicodeString::from_string("hello");
+    ///
+    /// let b = s.into_boxed_unicode_str();
+    /// assert_eq!(&*b, ustr!("hello"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_boxed_unicode_str(self) -> Box<unicode_str> {
+        let chars = Box::into_raw(self.vec.into_boxed_slice());
+        // SAFETY: `unicode_str` and `[char]` have the same layout, and the pointer comes
+        // from a box of the same allocation.
+        unsafe { Box::from_raw(chars as *mut unicode_str) }
+    }
+}
+
+impl From<UnicodeString> for Box<unicode_str> {
+    #[inline]
+    fn from(s: UnicodeString) -> Box<unicode_str> {
+        s.into_boxed_unicode_str()
+    }
+}
+
+impl From<Box<unicode_str>> for UnicodeString {
+    #[inline]
+    fn from(s: Box<unicode_str>) -> UnicodeString {
+        let chars = Box::into_raw(s) as *mut [char];
+        // SAFETY: `unicode_str` and `[char]` have the same layout.
+        UnicodeString {
+            vec: unsafe { Box::from_raw(chars) }.into_vec(),
+        }
+    }
+}
+
+impl<'a> From<UnicodeString> for Cow<'a, unicode_str> {
+    #[inline]
+    fn from(s: UnicodeString) -> Cow<'a, unicode_str> {
+        Cow::Owned(s)
+    }
+}
+
+impl AsRef<unicode_str> for UnicodeString {
+    #[inline]
+    fn as_ref(&self) -> &unicode_str {
+        self
+    }
 }
 
 impl Borrow<unicode_str> for UnicodeString {
@@ -259,6 +316,49 @@ impl Clone for UnicodeString {
 }
 
 
+impl fmt::Debug for UnicodeString {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl fmt::Display for UnicodeString {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+/// Appends the written text to the string, so that it can be the target of [`write!`] and
+/// [`uwrite!`](crate::uwrite).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::fmt::Write;
+/// use unicode_string::{ustr, UnicodeString};
+///
+/// let mut s = UnicodeString::new();
+/// write!(s, "{}-{}", 'a', 1).unwrap();
+/// assert_eq!(s, ustr!("a-1"));
+/// ```
+impl fmt::Write for UnicodeString {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.vec.extend(s.chars());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.vec.push(c);
+        Ok(())
+    }
+}
+
 impl ops::Deref for UnicodeString {
     type Target = unicode_str;
 
@@ -0,0 +1,228 @@
+//! Runs the conformance tests of the Unicode Bidirectional Algorithm, `BidiTest.txt` and
+//! `BidiCharacterTest.txt`, through `BidiInfo`, checking the resolved levels and the visual
+//! order of every case.
+//!
+//! The files are not part of the repository. They must be those of the version of the
+//! tables, [`UNICODE_VERSION`], downloaded into `tests/data`:
+//!
+//! ```text
+//! cd unicode_string
+//! curl --create-dirs -o tests/data/BidiTest.txt \
+//!     https://www.unicode.org/Public/14.0.0/ucd/BidiTest.txt
+//! curl --create-dirs -o tests/data/BidiCharacterTest.txt \
+//!     https://www.unicode.org/Public/14.0.0/ucd/BidiCharacterTest.txt
+//! cargo test --test bidi -- --ignored
+//! ```
+
+use std::fs;
+use std::path::Path;
+
+use unicode_string::ucd::UNICODE_VERSION;
+use unicode_string::{unicode_str, BidiInfo, Level};
+
+/// The number of failures that are printed in full.
+const MAX_REPORTED: usize = 20;
+
+/// Reads a file of `tests/data`, checking that it is the one of `UNICODE_VERSION`.
+fn read_test_file(name: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/data")
+        .join(name);
+    let data = fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("{}: {}, see tests/bidi.rs", path.display(), error));
+    let (major, minor, update) = UNICODE_VERSION;
+    let header = format!(
+        "# {}-{}.{}.{}.txt",
+        name.trim_end_matches(".txt"),
+        major,
+        minor,
+        update
+    );
+    assert!(
+        data.starts_with(&header),
+        "{} is not the file of Unicode {}.{}.{}",
+        path.display(),
+        major,
+        minor,
+        update
+    );
+    data
+}
+
+/// Parses a list of levels, where `x` marks the chars removed by rule X9.
+fn parse_levels(levels: &str) -> Vec<Option<u8>> {
+    levels
+        .split_whitespace()
+        .map(|level| match level {
+            "x" => None,
+            level => Some(level.parse().unwrap()),
+        })
+        .collect()
+}
+
+fn parse_order(order: &str) -> Vec<usize> {
+    order
+        .split_whitespace()
+        .map(|index| index.parse().unwrap())
+        .collect()
+}
+
+/// Returns a char of the bidi class with the given short name.
+fn char_of_class(class: &str) -> char {
+    match class {
+        "L" => 'a',
+        "R" => '\u{05D0}',
+        "AL" => '\u{0627}',
+        "EN" => '0',
+        "ES" => '+',
+        "ET" => '#',
+        "AN" => '\u{0660}',
+        "CS" => ',',
+        "NSM" => '\u{0300}',
+        "BN" => '\u{00AD}',
+        "B" => '\u{2029}',
+        "S" => '\t',
+        "WS" => ' ',
+        "ON" => '!',
+        "LRE" => '\u{202A}',
+        "LRO" => '\u{202D}',
+        "RLE" => '\u{202B}',
+        "RLO" => '\u{202E}',
+        "PDF" => '\u{202C}',
+        "LRI" => '\u{2066}',
+        "RLI" => '\u{2067}',
+        "FSI" => '\u{2068}',
+        "PDI" => '\u{2069}',
+        class => panic!("unknown bidi class {}", class),
+    }
+}
+
+/// Runs the algorithm on a single line of text, and returns the levels and the visual order
+/// of its chars, leaving out the chars whose expected level is `x`.
+fn resolve(
+    text: &[char],
+    paragraph_level: Option<Level>,
+    expected_levels: &[Option<u8>],
+) -> (Level, Vec<Option<u8>>, Vec<usize>) {
+    let bidi = BidiInfo::new(unicode_str::from_chars(text), paragraph_level);
+    let line = 0..text.len();
+    let levels = bidi
+        .line_levels(line.clone())
+        .into_iter()
+        .zip(expected_levels)
+        .map(|(level, expected)| expected.and(Some(level.number())))
+        .collect();
+    let order = bidi
+        .reordered_indices(line)
+        .into_iter()
+        .filter(|&index| expected_levels[index].is_some())
+        .collect();
+    (bidi.paragraphs()[0].level, levels, order)
+}
+
+fn check_failures(file: &str, cases: usize, failures: &[String]) {
+    assert!(
+        failures.is_empty(),
+        "{} of the {} cases of {} failed:\n{}",
+        failures.len(),
+        cases,
+        file,
+        failures[..failures.len().min(MAX_REPORTED)].join("\n")
+    );
+}
+
+#[test]
+#[ignore = "needs the conformance files in tests/data, see tests/bidi.rs"]
+fn bidi_test() {
+    let data = read_test_file("BidiTest.txt");
+    let mut expected_levels = Vec::new();
+    let mut expected_order = Vec::new();
+    let mut cases = 0;
+    let mut failures = Vec::new();
+
+    for (number, line) in data.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if let Some(levels) = line.strip_prefix("@Levels:") {
+            expected_levels = parse_levels(levels);
+            continue;
+        }
+        if let Some(order) = line.strip_prefix("@Reorder:") {
+            expected_order = parse_order(order);
+            continue;
+        }
+        if line.is_empty() || line.starts_with('@') {
+            continue;
+        }
+
+        let (input, bitset) = line.split_once(';').unwrap();
+        let text: Vec<char> = input.split_whitespace().map(char_of_class).collect();
+        let bitset = u8::from_str_radix(bitset.trim(), 16).unwrap();
+        for (bit, paragraph_level) in [(1, None), (2, Some(Level::LTR)), (4, Some(Level::RTL))] {
+            if bitset & bit == 0 {
+                continue;
+            }
+            cases += 1;
+            let (_, levels, order) = resolve(&text, paragraph_level, &expected_levels);
+            if levels != expected_levels || order != expected_order {
+                failures.push(format!(
+                    "line {}: {} with paragraph level {:?}: levels {:?}, order {:?}",
+                    number + 1,
+                    input,
+                    paragraph_level,
+                    levels,
+                    order
+                ));
+            }
+        }
+    }
+
+    check_failures("BidiTest.txt", cases, &failures);
+}
+
+#[test]
+#[ignore = "needs the conformance files in tests/data, see tests/bidi.rs"]
+fn bidi_character_test() {
+    let data = read_test_file("BidiCharacterTest.txt");
+    let mut cases = 0;
+    let mut failures = Vec::new();
+
+    for (number, line) in data.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(';').collect();
+        let text: Vec<char> = fields[0]
+            .split_whitespace()
+            .map(|c| char::from_u32(u32::from_str_radix(c, 16).unwrap()).unwrap())
+            .collect();
+        let paragraph_level = match fields[1] {
+            "0" => Some(Level::LTR),
+            "1" => Some(Level::RTL),
+            _ => None,
+        };
+        let expected_paragraph_level = Level::new(fields[2].parse().unwrap()).unwrap();
+        let expected_levels = parse_levels(fields[3]);
+        let expected_order = parse_order(fields[4]);
+
+        cases += 1;
+        let (resolved_paragraph_level, levels, order) =
+            resolve(&text, paragraph_level, &expected_levels);
+        if resolved_paragraph_level != expected_paragraph_level
+            || levels != expected_levels
+            || order != expected_order
+        {
+            failures.push(format!(
+                "line {}: {}: paragraph level {}, levels {:?}, order {:?}",
+                number + 1,
+                fields[0],
+                resolved_paragraph_level.number(),
+                levels,
+                order
+            ));
+        }
+    }
+
+    check_failures("BidiCharacterTest.txt", cases, &failures);
+}
@@ -0,0 +1,81 @@
+//! Checks that the letters the tailorings add between the letters of the DUCET have primary
+//! weights of their own, which no char of the root collation order uses.
+
+use std::collections::HashSet;
+
+use unicode_string::{Collator, Strength, Tailoring, UnicodeString};
+
+fn primary_weights(collator: &Collator, s: &str) -> Vec<u16> {
+    collator.sort_key(&UnicodeString::from_string(s))
+}
+
+#[test]
+fn tailored_letters_have_free_primary_weights() {
+    let root = Collator::new().strength(Strength::Primary);
+    let used = (0..=0x10FFFF)
+        .filter_map(char::from_u32)
+        .flat_map(|c| primary_weights(&root, &c.to_string()))
+        .collect::<HashSet<_>>();
+
+    let spanish = Collator::with_tailoring(Tailoring::Spanish).strength(Strength::Primary);
+    let swedish = Collator::with_tailoring(Tailoring::Swedish).strength(Strength::Primary);
+    for (collator, letter) in [
+        (&spanish, "ñ"),
+        (&swedish, "å"),
+        (&swedish, "ä"),
+        (&swedish, "ö"),
+    ] {
+        let weights = primary_weights(collator, letter);
+        assert_eq!(weights.len(), 1, "{}", letter);
+        assert!(
+            !used.contains(&weights[0]),
+            "the primary weight of {} is taken",
+            letter
+        );
+    }
+}
+
+#[test]
+fn tailored_letters_sort_between_their_neighbours() {
+    let spanish = Collator::with_tailoring(Tailoring::Spanish).strength(Strength::Primary);
+    let order = ["n", "ñ", "o"].map(|s| primary_weights(&spanish, s));
+    assert!(
+        order.windows(2).all(|pair| pair[0] < pair[1]),
+        "{:X?}",
+        order
+    );
+
+    // The Swedish letters follow `z` and all its variants, but come before the other letters
+    // of the Latin script.
+    let swedish = Collator::with_tailoring(Tailoring::Swedish).strength(Strength::Primary);
+    let order = ["z", "ʒ", "å", "ä", "ö", "ǀ"].map(|s| primary_weights(&swedish, s));
+    assert!(
+        order.windows(2).all(|pair| pair[0] < pair[1]),
+        "{:X?}",
+        order
+    );
+}
+
+#[test]
+fn only_traditional_swedish_sorts_w_as_v() {
+    let swedish = Collator::with_tailoring(Tailoring::Swedish).strength(Strength::Primary);
+    assert!(primary_weights(&swedish, "v") < primary_weights(&swedish, "w"));
+
+    let traditional =
+        Collator::with_tailoring(Tailoring::SwedishTraditional).strength(Strength::Primary);
+    assert_eq!(
+        primary_weights(&traditional, "v"),
+        primary_weights(&traditional, "w")
+    );
+
+    // &v<<<ᵛ<<w<<<W puts `ᵛ` between `v` and `V`, and `w` after all of them.
+    let traditional = Collator::with_tailoring(Tailoring::SwedishTraditional);
+    let order = ["v", "ᵛ", "V", "w", "W"].map(UnicodeString::from_string);
+    assert!(
+        order
+            .windows(2)
+            .all(|pair| traditional.compare(&pair[0], &pair[1]).is_lt()),
+        "{:?}",
+        order
+    );
+}
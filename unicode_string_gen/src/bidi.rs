@@ -0,0 +1,56 @@
+use std::path::Path;
+
+use crate::ucd::{parse_code_point, read_lines, read_property_values};
+use crate::writer::TableWriter;
+
+/// Generates `bidi.rs` from `DerivedBidiClass.txt` and `BidiBrackets.txt`.
+///
+/// Ranges of `L`, the default class, are left out of the class table.
+pub fn generate(data: &Path, out: &Path) {
+    let mut writer = TableWriter::new();
+    writer.push("\nuse crate::bidi::BidiClass;\n");
+
+    writer.push("\n/// Ranges of chars whose bidi class is not `L`, sorted by code point.\n");
+    writer.push("pub(crate) static BIDI_CLASS: &[(char, char, BidiClass)] = &[\n");
+    for (start, end, class) in read_property_values(&data.join("ucd/DerivedBidiClass.txt")) {
+        if class == "L" {
+            continue;
+        }
+        writer.push("    (");
+        writer.push_char(start);
+        writer.push(", ");
+        writer.push_char(end);
+        writer.push(&format!(", BidiClass::{}),\n", class));
+    }
+    writer.push("];\n");
+
+    let mut brackets = read_lines(&data.join("ucd/BidiBrackets.txt"))
+        .into_iter()
+        .map(|line| {
+            let opening = match line.field(2) {
+                "o" => true,
+                "c" => false,
+                other => panic!("invalid bidi paired bracket type {:?}", other),
+            };
+            (
+                parse_code_point(line.field(0)),
+                parse_code_point(line.field(1)),
+                opening,
+            )
+        })
+        .collect::<Vec<_>>();
+    brackets.sort();
+
+    writer.push("\n/// Paired brackets: the bracket, its pair and whether it is an opening bracket, sorted\n");
+    writer.push("/// by code point.\n");
+    writer.push("pub(crate) static BIDI_PAIRED_BRACKETS: &[(char, char, bool)] = &[\n");
+    for (code, pair, opening) in brackets {
+        writer.push("    (");
+        writer.push_char(code);
+        writer.push(", ");
+        writer.push_char(pair);
+        writer.push(&format!(", {}),\n", opening));
+    }
+    writer.push("];\n");
+    writer.write(&out.join("bidi.rs"));
+}
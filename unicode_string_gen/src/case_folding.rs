@@ -0,0 +1,50 @@
+use std::path::Path;
+
+use crate::ucd::{parse_code_point, parse_code_points, read_lines};
+use crate::writer::TableWriter;
+
+/// Generates `case_folding.rs` from `CaseFolding.txt`.
+///
+/// The common (`C`) and full (`F`) mappings make up the full case folding, and the common and
+/// simple (`S`) mappings the simple case folding. Code points that fold to themselves are left
+/// out. ASCII is left out as well, since it is folded without consulting the tables.
+pub fn generate(data: &Path, out: &Path) {
+    let lines = read_lines(&data.join("ucd/CaseFolding.txt"));
+    let mappings = |statuses: &[&str]| {
+        let mut mappings = lines
+            .iter()
+            .filter(|line| statuses.contains(&line.field(1)))
+            .map(|line| (parse_code_point(line.field(0)), parse_code_points(line.field(2))))
+            .filter(|(code, _)| *code > 0x7F)
+            .collect::<Vec<_>>();
+        mappings.sort_by_key(|(code, _)| *code);
+        mappings
+    };
+    let full = mappings(&["C", "F"]);
+    let simple = mappings(&["C", "S"]);
+
+    let mut writer = TableWriter::new();
+    writer.push("\n/// Full case folding (statuses `C` and `F` of `CaseFolding.txt`), sorted by code point.\n");
+    writer.push("pub(crate) static FULL_CASE_FOLDING: &[(char, &[char])] = &[\n");
+    for (code, mapping) in &full {
+        writer.push("    (");
+        writer.push_char(*code);
+        writer.push(", ");
+        writer.push_char_slice(mapping);
+        writer.push("),\n");
+    }
+    writer.push("];\n");
+
+    writer.push("\n/// Simple case folding (statuses `C` and `S` of `CaseFolding.txt`), sorted by code point.\n");
+    writer.push("pub(crate) static SIMPLE_CASE_FOLDING: &[(char, char)] = &[\n");
+    for (code, mapping) in &simple {
+        assert_eq!(mapping.len(), 1, "simple case folding of {:04X} is not a single char", code);
+        writer.push("    (");
+        writer.push_char(*code);
+        writer.push(", ");
+        writer.push_char(mapping[0]);
+        writer.push("),\n");
+    }
+    writer.push("];\n");
+    writer.write(&out.join("case_folding.rs"));
+}
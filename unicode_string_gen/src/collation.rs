@@ -0,0 +1,207 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use crate::normalization::canonical_decompositions;
+use crate::ucd::{parse_code_point, parse_code_points, parse_range, read_property_ranges, read_unicode_data};
+use crate::writer::TableWriter;
+
+/// The number of unused primary weights the tailorings need right before U+01C0 LATIN LETTER
+/// DENTAL CLICK.
+const DENTAL_CLICK_GAP: u32 = 3;
+
+/// Packs a collation element into a `u32`: the primary weight in the upper 16 bits, then 9 bits
+/// of secondary weight, 5 bits of tertiary weight, and the variable flag in the lowest bit.
+fn pack(variable: bool, primary: u32, secondary: u32, tertiary: u32) -> u32 {
+    assert!(primary <= 0xFFFF && secondary <= 0x1FF && tertiary <= 0x1F);
+    primary << 16 | secondary << 7 | tertiary << 2 | variable as u32
+}
+
+/// Parses collation elements such as `[.1FA2.0020.0002][*0209.0020.0002]`.
+fn parse_elements(value: &str) -> Vec<u32> {
+    value
+        .split(']')
+        .map(str::trim)
+        .filter(|element| !element.is_empty())
+        .map(|element| {
+            let element = element.strip_prefix('[').expect("invalid collation element");
+            let variable = element.starts_with('*');
+            let weights = element[1..]
+                .split('.')
+                .map(parse_code_point)
+                .collect::<Vec<_>>();
+            pack(variable, weights[0], weights[1], weights[2])
+        })
+        .collect()
+}
+
+/// Generates `collation.rs` from the Default Unicode Collation Element Table (`allkeys.txt`).
+pub fn generate(data: &Path, out: &Path) {
+    let path = data.join("uca/allkeys.txt");
+    let contents = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+
+    let decompositions = canonical_decompositions(&read_unicode_data(data));
+
+    let mut version = None;
+    let mut implicit_weights = Vec::new();
+    let mut singles = Vec::new();
+    let mut contractions = Vec::new();
+    for line in contents.lines() {
+        let line = match line.find('#') {
+            Some(index) => &line[..index],
+            None => line,
+        }
+        .trim();
+        if let Some(value) = line.strip_prefix("@version") {
+            version = Some(value.trim().split('.').map(|part| part.parse::<u8>().unwrap()).collect::<Vec<_>>());
+        } else if let Some(value) = line.strip_prefix("@implicitweights") {
+            let (range, base) = value.split_once(';').expect("invalid @implicitweights");
+            let (start, end) = parse_range(range.trim());
+            implicit_weights.push((start, end, parse_code_point(base)));
+        } else if let Some((key, elements)) = line.split_once(';') {
+            let key = parse_code_points(key);
+            let elements = parse_elements(elements);
+            if key.len() == 1 {
+                // Input is normalized to NFD before it is collated, so chars with a canonical
+                // decomposition are never looked up.
+                if !decompositions.contains_key(&key[0]) {
+                    singles.push((key[0], elements));
+                }
+            } else {
+                contractions.push((key, elements));
+            }
+        }
+    }
+    singles.sort();
+    contractions.sort();
+    let version = version.expect("missing @version");
+
+    // The tailorings are built on the primary weights of the Latin letters, and put new
+    // letters in gaps of the DUCET, which must still be free.
+    let primary_of = |code: u32| {
+        let (_, values) = singles.iter().find(|(key, _)| *key == code).expect("missing letter");
+        values[0] >> 16
+    };
+    let latin_primaries = (u32::from('a')..=u32::from('z')).map(primary_of).collect::<Vec<_>>();
+    let dental_click_primary = primary_of(0x01C0);
+    let used_primaries = singles
+        .iter()
+        .map(|(_, values)| values)
+        .chain(contractions.iter().map(|(_, values)| values))
+        .flatten()
+        .map(|element| element >> 16)
+        .collect::<HashSet<_>>();
+    let gaps = [
+        // `ñ` in Spanish.
+        (latin_primaries[13] + 1, 1),
+        // `å`, `ä` and `ö` in Swedish.
+        (dental_click_primary - DENTAL_CLICK_GAP, DENTAL_CLICK_GAP),
+    ];
+    for (start, len) in gaps {
+        assert!(
+            (start..start + len).all(|primary| !used_primaries.contains(&primary)),
+            "the primary weights {:04X} to {:04X} used by the tailorings are taken",
+            start,
+            start + len - 1
+        );
+    }
+
+    let mut elements = Vec::new();
+    let mut push_elements = |values: &[u32]| {
+        assert!(values.len() < 32);
+        let index = (elements.len() as u32) << 5 | values.len() as u32;
+        elements.extend_from_slice(values);
+        index
+    };
+    let singles = singles
+        .iter()
+        .map(|(code, values)| (*code, push_elements(values)))
+        .collect::<Vec<_>>();
+    let contractions = contractions
+        .iter()
+        .map(|(key, values)| (key, push_elements(values)))
+        .collect::<Vec<_>>();
+    let max_contraction = contractions.iter().map(|(key, _)| key.len()).max().unwrap_or(1);
+
+    let mut writer = TableWriter::new();
+    writer.push(&format!(
+        "\n/// The version of the Default Unicode Collation Element Table these tables were built from.\n\
+         pub(crate) const DUCET_VERSION: (u8, u8, u8) = ({}, {}, {});\n",
+        version[0], version[1], version[2]
+    ));
+    writer.push(&format!(
+        "\n/// The length of the longest contraction in [`CONTRACTIONS`].\n\
+         pub(crate) const MAX_CONTRACTION_LEN: usize = {};\n",
+        max_contraction
+    ));
+    writer.push("\n/// The primary weights of the letters `a` to `z`.\n");
+    writer.push("pub(crate) const LATIN_PRIMARIES: [u16; 26] = [\n");
+    for chunk in latin_primaries.chunks(8) {
+        writer.push("   ");
+        for primary in chunk {
+            writer.push(&format!(" 0x{:04X},", primary));
+        }
+        writer.push("\n");
+    }
+    writer.push("];\n");
+    writer.push(&format!(
+        "\n/// The primary weight of U+01C0 LATIN LETTER DENTAL CLICK, the first letter after the\n\
+         /// Latin letters and their variants. The {} primary weights before it are unused.\n\
+         pub(crate) const DENTAL_CLICK_PRIMARY: u16 = 0x{:04X};\n",
+        DENTAL_CLICK_GAP, dental_click_primary
+    ));
+
+    writer.push("\n/// Collation elements, packed as `primary << 16 | secondary << 7 | tertiary << 2 | variable`.\n");
+    writer.push("pub(crate) static COLLATION_ELEMENTS: &[u32] = &[\n");
+    for chunk in elements.chunks(8) {
+        writer.push("   ");
+        for element in chunk {
+            writer.push(&format!(" 0x{:08X},", element));
+        }
+        writer.push("\n");
+    }
+    writer.push("];\n");
+
+    writer.push("\n/// Collation elements of single chars, as `(char, start << 5 | len)` indices into\n");
+    writer.push("/// [`COLLATION_ELEMENTS`], sorted by char. Chars with a canonical decomposition are left out.\n");
+    writer.push("pub(crate) static SINGLES: &[(char, u32)] = &[\n");
+    for (code, index) in &singles {
+        writer.push("    (");
+        writer.push_char(*code);
+        writer.push(&format!(", 0x{:X}),\n", index));
+    }
+    writer.push("];\n");
+
+    writer.push("\n/// Collation elements of contractions, indexed like [`SINGLES`] and sorted by key.\n");
+    writer.push("pub(crate) static CONTRACTIONS: &[(&[char], u32)] = &[\n");
+    for (key, index) in &contractions {
+        writer.push("    (");
+        writer.push_char_slice(key);
+        writer.push(&format!(", 0x{:X}),\n", index));
+    }
+    writer.push("];\n");
+
+    writer.push("\n/// Ranges of code points with their own implicit weight base (`@implicitweights`).\n");
+    writer.push("pub(crate) static IMPLICIT_WEIGHTS: &[(char, char, u16)] = &[\n");
+    for (start, end, base) in &implicit_weights {
+        writer.push("    (");
+        writer.push_char(*start);
+        writer.push(", ");
+        writer.push_char(*end);
+        writer.push(&format!(", 0x{:04X}),\n", base));
+    }
+    writer.push("];\n");
+
+    writer.push("\n/// Ranges of chars with the `Unified_Ideograph` property.\n");
+    writer.push("pub(crate) static UNIFIED_IDEOGRAPH: &[(char, char)] = &[\n");
+    for (start, end) in read_property_ranges(&data.join("ucd/PropList.txt"), "Unified_Ideograph") {
+        writer.push("    (");
+        writer.push_char(start);
+        writer.push(", ");
+        writer.push_char(end);
+        writer.push("),\n");
+    }
+    writer.push("];\n");
+    writer.write(&out.join("collation.rs"));
+}
@@ -0,0 +1,42 @@
+use std::path::Path;
+
+use crate::ucd::{parse_code_point, parse_code_points, read_lines};
+use crate::writer::TableWriter;
+
+/// Generates `confusables.rs` from the `confusables.txt` file of UTS #39.
+///
+/// Every line maps a char to its prototype, the sequence of chars it is visually confusable
+/// with. The prototypes are already fully mapped, so the table only needs to be applied once.
+pub fn generate(data: &Path, out: &Path) {
+    let mut confusables: Vec<(u32, Vec<u32>)> = read_lines(&data.join("uts39/confusables.txt"))
+        .into_iter()
+        .map(|line| (parse_code_point(line.field(0)), parse_code_points(line.field(1))))
+        .collect();
+    confusables.sort();
+    assert!(
+        confusables.windows(2).all(|pair| pair[0].0 != pair[1].0),
+        "confusables.txt maps a char more than once"
+    );
+    for (_, prototype) in &confusables {
+        assert!(
+            prototype
+                .iter()
+                .all(|c| confusables.binary_search_by_key(c, |&(code, _)| code).is_err()),
+            "the prototype {:04X?} is not fully mapped",
+            prototype
+        );
+    }
+
+    let mut writer = TableWriter::new();
+    writer.push("\n/// The prototype of every confusable char, sorted by code point.\n");
+    writer.push("pub(crate) static CONFUSABLES: &[(char, &[char])] = &[\n");
+    for (code, prototype) in confusables {
+        writer.push("    (");
+        writer.push_char(code);
+        writer.push(", ");
+        writer.push_char_slice(&prototype);
+        writer.push("),\n");
+    }
+    writer.push("];\n");
+    writer.write(&out.join("confusables.rs"));
+}
@@ -0,0 +1,40 @@
+use std::path::Path;
+
+use crate::ucd::read_unicode_data;
+use crate::writer::TableWriter;
+
+/// Generates `decimal_digits.rs` from `UnicodeData.txt`.
+///
+/// Every decimal digit (`Nd`) is part of a contiguous run of ten chars with the values 0 to 9,
+/// so only the zero of each run is stored.
+pub fn generate(data: &Path, out: &Path) {
+    let digits: Vec<(u32, u8)> = read_unicode_data(data)
+        .into_iter()
+        .filter_map(|entry| Some((entry.start, entry.decimal_digit?)))
+        .collect();
+
+    let zeros: Vec<u32> = digits
+        .iter()
+        .filter(|&&(_, value)| value == 0)
+        .map(|&(code, _)| code)
+        .collect();
+    for (index, &(code, value)) in digits.iter().enumerate() {
+        let zero = code - u32::from(value);
+        assert!(
+            zeros.contains(&zero) && digits[index - usize::from(value)].0 == zero,
+            "U+{:04X} is not part of a contiguous run of decimal digits",
+            code
+        );
+    }
+
+    let mut writer = TableWriter::new();
+    writer.push("\n/// The zero of every run of ten decimal digits, sorted by code point.\n");
+    writer.push("pub(crate) static DECIMAL_DIGIT_ZEROS: &[char] = &[\n");
+    for &zero in &zeros {
+        writer.push("    ");
+        writer.push_char(zero);
+        writer.push(",\n");
+    }
+    writer.push("];\n");
+    writer.write(&out.join("decimal_digits.rs"));
+}
@@ -0,0 +1,271 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::writer::TableWriter;
+
+/// The single-byte encodings, as the name of their index and of their table.
+const SINGLE_BYTE: &[(&str, &str)] = &[
+    ("ibm866", "IBM866"),
+    ("iso-8859-2", "ISO_8859_2"),
+    ("iso-8859-3", "ISO_8859_3"),
+    ("iso-8859-4", "ISO_8859_4"),
+    ("iso-8859-5", "ISO_8859_5"),
+    ("iso-8859-6", "ISO_8859_6"),
+    ("iso-8859-7", "ISO_8859_7"),
+    ("iso-8859-8", "ISO_8859_8"),
+    ("iso-8859-10", "ISO_8859_10"),
+    ("iso-8859-13", "ISO_8859_13"),
+    ("iso-8859-14", "ISO_8859_14"),
+    ("iso-8859-15", "ISO_8859_15"),
+    ("iso-8859-16", "ISO_8859_16"),
+    ("koi8-r", "KOI8_R"),
+    ("koi8-u", "KOI8_U"),
+    ("macintosh", "MACINTOSH"),
+    ("windows-874", "WINDOWS_874"),
+    ("windows-1250", "WINDOWS_1250"),
+    ("windows-1251", "WINDOWS_1251"),
+    ("windows-1252", "WINDOWS_1252"),
+    ("windows-1253", "WINDOWS_1253"),
+    ("windows-1254", "WINDOWS_1254"),
+    ("windows-1255", "WINDOWS_1255"),
+    ("windows-1256", "WINDOWS_1256"),
+    ("windows-1257", "WINDOWS_1257"),
+    ("windows-1258", "WINDOWS_1258"),
+    ("x-mac-cyrillic", "X_MAC_CYRILLIC"),
+];
+
+/// The pointers of index jis0208 that Shift_JIS does not encode to: the NEC selected IBM
+/// extensions, which are duplicates of the IBM extensions.
+const SHIFT_JIS_EXCLUDED: std::ops::RangeInclusive<u32> = 8272..=8835;
+
+/// The pointers of index Big5 that Big5 does not encode to: the HKSCS extensions below the
+/// original Big5 range.
+const BIG5_EXCLUDED: std::ops::Range<u32> = 0..(0xA1 - 0x81) * 157;
+
+/// The code points that Big5 encodes to their last pointer rather than their first.
+const BIG5_LAST_POINTER: &[u32] = &[0x2550, 0x255E, 0x2561, 0x256A, 0x5341, 0x5345];
+
+/// Generates the `encoding_*.rs` tables from the indexes of the WHATWG Encoding Standard, one
+/// table per family of encodings, so each family can be left out of the build.
+///
+/// The indexes map pointers to code points: a decoding table is indexed by pointer, with 0 for
+/// the pointers that are not mapped, since no index maps a pointer to U+0000, and an encoding
+/// table lists the pointer that each code point encodes to, sorted by code point.
+pub fn generate(data: &Path, out: &Path) {
+    let data = data.join("encoding");
+
+    let mut writer = TableWriter::new();
+    for &(name, table) in SINGLE_BYTE {
+        let index = read_index(&data, name);
+        assert!(
+            index.keys().all(|&pointer| pointer < 128),
+            "index {} has a pointer above 127",
+            name
+        );
+        writer.push(&format!(
+            "\n/// The chars of the bytes 0x80 to 0xFF in {}, or 0 if a byte is not mapped.\n",
+            name
+        ));
+        push_decoding_table(&mut writer, table, "[u16; 128]", &index, 128);
+    }
+    writer.write(&out.join("encoding_single_byte.rs"));
+
+    let mut writer = TableWriter::new();
+    let jis0208 = read_index(&data, "jis0208");
+    writer.push("\n/// Index jis0208, or 0 if a pointer is not mapped.\n");
+    push_decoding_table(&mut writer, "JIS0208", "&[u16]", &jis0208, 0);
+    writer.push(
+        "\n/// The first pointer of every code point of index jis0208, sorted by code point.\n",
+    );
+    let euc_jp = first_pointers(&jis0208, |_| true);
+    push_encoding_table(&mut writer, "JIS0208_POINTERS", &euc_jp);
+    let shift_jis: Vec<(u32, u32)> =
+        first_pointers(&jis0208, |pointer| !SHIFT_JIS_EXCLUDED.contains(&pointer))
+            .into_iter()
+            .filter(|pair| euc_jp.binary_search(pair).is_err())
+            .collect();
+    assert_eq!(
+        shift_jis.len(),
+        euc_jp
+            .iter()
+            .filter(|(_, pointer)| SHIFT_JIS_EXCLUDED.contains(pointer))
+            .count(),
+        "Shift_JIS cannot encode a code point of the NEC selected IBM extensions"
+    );
+    writer.push("\n/// The pointer that Shift_JIS encodes a code point to, sorted by code point, for the code\n");
+    writer.push("/// points whose first pointer is one of the NEC selected IBM extensions.\n");
+    push_encoding_table(&mut writer, "SHIFT_JIS_POINTERS", &shift_jis);
+    let jis0212 = read_index(&data, "jis0212");
+    writer.push("\n/// Index jis0212, or 0 if a pointer is not mapped.\n");
+    push_decoding_table(&mut writer, "JIS0212", "&[u16]", &jis0212, 0);
+    let katakana = read_index(&data, "iso-2022-jp-katakana");
+    writer.push("\n/// The fullwidth katakana that ISO-2022-JP encodes for U+FF61 to U+FF9F.\n");
+    push_decoding_table(
+        &mut writer,
+        "ISO_2022_JP_KATAKANA",
+        "[u16; 63]",
+        &katakana,
+        63,
+    );
+    writer.write(&out.join("encoding_japanese.rs"));
+
+    let mut writer = TableWriter::new();
+    let gb18030 = read_index(&data, "gb18030");
+    writer.push("\n/// Index gb18030, or 0 if a pointer is not mapped.\n");
+    push_decoding_table(&mut writer, "GB18030", "&[u16]", &gb18030, 0);
+    writer.push(
+        "\n/// The first pointer of every code point of index gb18030, sorted by code point.\n",
+    );
+    push_encoding_table(
+        &mut writer,
+        "GB18030_POINTERS",
+        &first_pointers(&gb18030, |_| true),
+    );
+    let ranges = read_index(&data, "gb18030-ranges");
+    assert!(
+        ranges
+            .values()
+            .collect::<Vec<_>>()
+            .windows(2)
+            .all(|pair| pair[0] < pair[1]),
+        "index gb18030 ranges is not sorted by code point"
+    );
+    writer.push("\n/// Index gb18030 ranges: the first pointer and code point of every range of four-byte\n");
+    writer.push("/// sequences, sorted by pointer and by code point.\n");
+    writer.push("pub(crate) static GB18030_RANGES: &[(u16, u16)] = &[\n");
+    for (pointer, code) in ranges {
+        writer.push(&format!("    ({}, 0x{:04X}),\n", pointer, code));
+    }
+    writer.push("];\n");
+    writer.write(&out.join("encoding_chinese_simplified.rs"));
+
+    let mut writer = TableWriter::new();
+    let big5 = read_index(&data, "big5");
+    writer.push("\n/// Index Big5, or 0 if a pointer is not mapped.\n");
+    push_decoding_table(&mut writer, "BIG5", "&[u32]", &big5, 0);
+    let mut pointers = first_pointers(&big5, |pointer| !BIG5_EXCLUDED.contains(&pointer));
+    for (code, pointer) in &mut pointers {
+        if BIG5_LAST_POINTER.contains(code) {
+            *pointer = *big5
+                .iter()
+                .rev()
+                .find(|&(_, &other)| other == *code)
+                .unwrap()
+                .0;
+        }
+    }
+    writer.push("\n/// The pointer that Big5 encodes every code point of index Big5 to, sorted by code point.\n");
+    push_encoding_table(&mut writer, "BIG5_POINTERS", &pointers);
+    writer.write(&out.join("encoding_chinese_traditional.rs"));
+
+    let mut writer = TableWriter::new();
+    let euc_kr = read_index(&data, "euc-kr");
+    writer.push("\n/// Index EUC-KR, or 0 if a pointer is not mapped.\n");
+    push_decoding_table(&mut writer, "EUC_KR", "&[u16]", &euc_kr, 0);
+    writer.push(
+        "\n/// The first pointer of every code point of index EUC-KR, sorted by code point.\n",
+    );
+    push_encoding_table(
+        &mut writer,
+        "EUC_KR_POINTERS",
+        &first_pointers(&euc_kr, |_| true),
+    );
+    writer.write(&out.join("encoding_korean.rs"));
+}
+
+/// Reads `index-<name>.txt`, which lists a pointer and a code point on every line.
+fn read_index(data: &Path, name: &str) -> BTreeMap<u32, u32> {
+    let path = data.join(format!("index-{}.txt", name));
+    let contents = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+    let mut index = BTreeMap::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let (pointer, code) = (fields.next().unwrap(), fields.next().unwrap_or(""));
+        let pointer = pointer
+            .parse()
+            .unwrap_or_else(|e| panic!("invalid pointer {:?} in {}: {}", pointer, name, e));
+        let code = u32::from_str_radix(code.trim_start_matches("0x"), 16)
+            .unwrap_or_else(|e| panic!("invalid code point {:?} in {}: {}", code, name, e));
+        assert!(
+            code != 0,
+            "index {} maps pointer {} to U+0000",
+            name,
+            pointer
+        );
+        assert!(
+            index.insert(pointer, code).is_none(),
+            "index {} maps pointer {} twice",
+            name,
+            pointer
+        );
+    }
+    index
+}
+
+/// Returns the first pointer of every code point of `index` whose pointers are `allowed`,
+/// sorted by code point.
+fn first_pointers(index: &BTreeMap<u32, u32>, allowed: impl Fn(u32) -> bool) -> Vec<(u32, u32)> {
+    let mut pointers = BTreeMap::new();
+    for (&pointer, &code) in index {
+        if allowed(pointer) {
+            pointers.entry(code).or_insert(pointer);
+        }
+    }
+    pointers.into_iter().collect()
+}
+
+/// Writes `index` as a table of code points indexed by pointer, with at least `len` entries.
+fn push_decoding_table(
+    writer: &mut TableWriter,
+    name: &str,
+    ty: &str,
+    index: &BTreeMap<u32, u32>,
+    len: u32,
+) {
+    let len = index
+        .keys()
+        .next_back()
+        .map_or(len, |&last| len.max(last + 1));
+    let narrow = !ty.contains("u32");
+    let reference = if ty.starts_with('&') { "&" } else { "" };
+    writer.push(&format!(
+        "pub(crate) static {}: {} = {}[\n",
+        name, ty, reference
+    ));
+    for row in (0..len).collect::<Vec<_>>().chunks(12) {
+        writer.push("   ");
+        for pointer in row {
+            let code = index.get(pointer).copied().unwrap_or(0);
+            assert!(
+                !narrow || code <= 0xFFFF,
+                "{} maps pointer {} outside the BMP",
+                name,
+                pointer
+            );
+            writer.push(&format!(" 0x{:04X},", code));
+        }
+        writer.push("\n");
+    }
+    writer.push("];\n");
+}
+
+/// Writes the pairs of a code point and a pointer of an encoding table.
+fn push_encoding_table(writer: &mut TableWriter, name: &str, pointers: &[(u32, u32)]) {
+    let wide = pointers.iter().any(|&(code, _)| code > 0xFFFF);
+    let ty = if wide { "(u32, u16)" } else { "(u16, u16)" };
+    writer.push(&format!("pub(crate) static {}: &[{}] = &[\n", name, ty));
+    for row in pointers.chunks(6) {
+        writer.push("   ");
+        for &(code, pointer) in row {
+            writer.push(&format!(" (0x{:04X}, {}),", code, pointer));
+        }
+        writer.push("\n");
+    }
+    writer.push("];\n");
+}
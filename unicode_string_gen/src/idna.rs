@@ -0,0 +1,76 @@
+use std::path::Path;
+
+use crate::ucd::{is_surrogate_range, parse_code_points, parse_range, read_lines};
+use crate::writer::TableWriter;
+
+/// The statuses of `IdnaMappingTable.txt`, the corresponding `Mapping` variants, and whether the
+/// status has a mapping.
+const STATUSES: &[(&str, &str, bool)] = &[
+    ("valid", "Valid", false),
+    ("ignored", "Ignored", false),
+    ("mapped", "Mapped", true),
+    ("deviation", "Deviation", true),
+    ("disallowed", "Disallowed", false),
+    ("disallowed_STD3_valid", "DisallowedStd3Valid", false),
+    ("disallowed_STD3_mapped", "DisallowedStd3Mapped", true),
+];
+
+/// Generates `idna.rs` from the `IdnaMappingTable.txt` file of UTS #46.
+///
+/// Adjacent ranges with the same status and mapping are merged, since the IDNA2008 status in
+/// the third field is not used.
+pub fn generate(data: &Path, out: &Path) {
+    let mut ranges: Vec<(u32, u32, &str, bool, Vec<u32>)> = Vec::new();
+    for line in read_lines(&data.join("idna/IdnaMappingTable.txt")) {
+        let (start, end) = parse_range(line.field(0));
+        if is_surrogate_range(start, end) {
+            continue;
+        }
+        let &(_, variant, has_mapping) = STATUSES
+            .iter()
+            .find(|&&(status, _, _)| status == line.field(1))
+            .unwrap_or_else(|| panic!("unknown IDNA status {:?}", line.field(1)));
+        let mapping = parse_code_points(line.field(2));
+        assert!(
+            has_mapping || mapping.is_empty(),
+            "U+{:04X} has a mapping but its status is {}",
+            start,
+            line.field(1)
+        );
+        match ranges.last_mut() {
+            Some((_, last_end, last_variant, _, last_mapping))
+                if *last_end + 1 == start
+                    && *last_variant == variant
+                    && *last_mapping == mapping =>
+            {
+                *last_end = end;
+            }
+            _ => ranges.push((start, end, variant, has_mapping, mapping)),
+        }
+    }
+    assert!(
+        ranges.windows(2).all(|pair| pair[0].1 < pair[1].0),
+        "IdnaMappingTable.txt is not sorted by code point"
+    );
+
+    let mut writer = TableWriter::new();
+    writer.push("\nuse crate::idna::Mapping;\n");
+    writer.push("\n/// The IDNA mapping of every char, sorted by code point. All the chars of a range have the\n");
+    writer.push("/// same mapping.\n");
+    writer.push("pub(crate) static IDNA_MAPPING: &[(char, char, Mapping)] = &[\n");
+    for (start, end, variant, has_mapping, mapping) in ranges {
+        writer.push("    (");
+        writer.push_char(start);
+        writer.push(", ");
+        writer.push_char(end);
+        writer.push(&format!(", Mapping::{}", variant));
+        if has_mapping {
+            writer.push("(");
+            writer.push_char_slice(&mapping);
+            writer.push(")");
+        }
+        writer.push("),\n");
+    }
+    writer.push("];\n");
+    writer.write(&out.join("idna.rs"));
+}
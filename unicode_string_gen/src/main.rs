@@ -0,0 +1,40 @@
+//! Generates the Unicode data tables in `unicode_string/src/tables` from the vendored
+//! Unicode Character Database files and encoding indexes in `data/`.
+//!
+//! Run with `cargo run -p unicode_string_gen` after updating the files in `data/`.
+
+mod bidi;
+mod case_folding;
+mod collation;
+mod confusables;
+mod decimal_digits;
+mod encoding;
+mod idna;
+mod names;
+mod normalization;
+mod properties;
+mod scripts;
+mod transliteration;
+mod ucd;
+mod writer;
+
+use std::path::Path;
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let data = root.join("data");
+    let out = root.join("../unicode_string/src/tables");
+
+    bidi::generate(&data, &out);
+    case_folding::generate(&data, &out);
+    collation::generate(&data, &out);
+    confusables::generate(&data, &out);
+    decimal_digits::generate(&data, &out);
+    encoding::generate(&data, &out);
+    idna::generate(&data, &out);
+    names::generate(&data, &out);
+    normalization::generate(&data, &out);
+    properties::generate(&data, &out);
+    scripts::generate(&data, &out);
+    transliteration::generate(&data, &out);
+}
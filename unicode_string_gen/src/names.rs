@@ -0,0 +1,130 @@
+use std::path::Path;
+
+use crate::ucd::{is_surrogate_range, parse_code_point, read_lines};
+use crate::writer::TableWriter;
+
+/// The prefixes of the names of the ranges listed with `<..., First>` and `<..., Last>` lines in
+/// `UnicodeData.txt` that have a name derived from the code point (rule NR2 of UAX #44).
+/// Hangul syllables (rule NR1) are named by `unicode_string` itself, and the other ranges have
+/// no names.
+const RANGE_PREFIXES: &[(&str, &str)] = &[
+    ("CJK Ideograph", "CJK UNIFIED IDEOGRAPH-"),
+    ("Tangut Ideograph", "TANGUT IDEOGRAPH-"),
+];
+
+/// Generates `names.rs` from `UnicodeData.txt` and `NameAliases.txt`.
+///
+/// Names made of a prefix and the code point in hexadecimal, like those of the CJK
+/// compatibility ideographs, are stored as ranges sharing the prefix. The other names are
+/// concatenated into a single string.
+pub fn generate(data: &Path, out: &Path) {
+    let mut names: Vec<(u32, String)> = Vec::new();
+    let mut prefixes: Vec<(u32, u32, String)> = Vec::new();
+    let mut range_start = None;
+    for line in read_lines(&data.join("ucd/UnicodeData.txt")) {
+        let code = parse_code_point(line.field(0));
+        let name = line.field(1);
+        if let Some(range) = name.strip_suffix(", First>") {
+            range_start = Some((code, range.trim_start_matches('<').to_owned()));
+            continue;
+        }
+        if name.ends_with(", Last>") {
+            let (start, range) = range_start.take().expect("range without a start");
+            if is_surrogate_range(start, code) {
+                continue;
+            }
+            if let Some(&(_, prefix)) = RANGE_PREFIXES
+                .iter()
+                .find(|&&(range_name, _)| range.starts_with(range_name))
+            {
+                prefixes.push((start, code, prefix.to_owned()));
+            }
+            continue;
+        }
+        if name.starts_with('<') {
+            continue;
+        }
+        match name.strip_suffix(&format!("-{:04X}", code)) {
+            Some(prefix) => {
+                let prefix = format!("{}-", prefix);
+                match prefixes.last_mut() {
+                    Some((_, end, last)) if *end + 1 == code && *last == prefix => *end = code,
+                    _ => prefixes.push((code, code, prefix)),
+                }
+            }
+            None => names.push((code, name.to_owned())),
+        }
+    }
+    prefixes.sort();
+
+    let mut writer = TableWriter::new();
+    writer.push("\n/// The names that are not derived from the code point, concatenated in code point order.\n");
+    writer.push("pub(crate) static NAME_TEXT: &str = \"\\\n");
+    for (_, name) in &names {
+        assert!(
+            name.bytes()
+                .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b' ' || b == b'-'),
+            "unexpected character in name {:?}",
+            name
+        );
+        writer.push(&format!("    {}\\\n", name));
+    }
+    writer.push("\";\n");
+
+    writer.push("\n/// The chars whose name is in [`NAME_TEXT`] and the offset of their name, sorted by code\n");
+    writer.push("/// point. Each name ends where the next one starts.\n");
+    writer.push("pub(crate) static NAMES: &[(char, u32)] = &[\n");
+    let mut offset = 0;
+    for (code, name) in &names {
+        writer.push("    (");
+        writer.push_char(*code);
+        writer.push(&format!(", {}),\n", offset));
+        offset += name.len();
+    }
+    writer.push("];\n");
+
+    let mut by_name = (0..names.len()).collect::<Vec<_>>();
+    by_name.sort_by(|&a, &b| names[a].1.cmp(&names[b].1));
+    assert!(
+        names.len() <= usize::from(u16::MAX),
+        "too many names for u16 indices"
+    );
+    writer.push("\n/// Indices into [`NAMES`], sorted by name.\n");
+    writer.push("pub(crate) static NAMES_BY_NAME: &[u16] = &[\n");
+    for chunk in by_name.chunks(16) {
+        let indices = chunk.iter().map(usize::to_string).collect::<Vec<_>>();
+        writer.push(&format!("    {},\n", indices.join(", ")));
+    }
+    writer.push("];\n");
+
+    writer.push("\n/// Ranges of chars whose name is a prefix followed by their code point in hexadecimal,\n");
+    writer.push("/// sorted by code point.\n");
+    writer.push("pub(crate) static NAME_PREFIXES: &[(char, char, &str)] = &[\n");
+    for (start, end, prefix) in prefixes {
+        writer.push("    (");
+        writer.push_char(start);
+        writer.push(", ");
+        writer.push_char(end);
+        writer.push(&format!(", {:?}),\n", prefix));
+    }
+    writer.push("];\n");
+
+    let aliases = read_lines(&data.join("ucd/NameAliases.txt"))
+        .into_iter()
+        .map(|line| (parse_code_point(line.field(0)), line.field(1).to_owned()))
+        .collect::<Vec<_>>();
+    assert!(
+        aliases.windows(2).all(|pair| pair[0].0 <= pair[1].0),
+        "NameAliases.txt is not sorted by code point"
+    );
+    writer
+        .push("\n/// Name aliases, sorted by code point and in the order of `NameAliases.txt`.\n");
+    writer.push("pub(crate) static NAME_ALIASES: &[(char, &str)] = &[\n");
+    for (code, alias) in aliases {
+        writer.push("    (");
+        writer.push_char(code);
+        writer.push(&format!(", {:?}),\n", alias));
+    }
+    writer.push("];\n");
+    writer.write(&out.join("names.rs"));
+}
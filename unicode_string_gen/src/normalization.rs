@@ -0,0 +1,115 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::ucd::{parse_code_point, read_lines, read_unicode_data, UnicodeDataEntry};
+use crate::writer::TableWriter;
+
+/// Returns the full (recursively applied) canonical decompositions, excluding Hangul syllables,
+/// which are decomposed algorithmically.
+pub fn canonical_decompositions(entries: &[UnicodeDataEntry]) -> BTreeMap<u32, Vec<u32>> {
+    let single: BTreeMap<u32, Vec<u32>> = entries
+        .iter()
+        .filter_map(|entry| Some((entry.start, entry.canonical_decomposition()?)))
+        .collect();
+
+    fn decompose(code: u32, single: &BTreeMap<u32, Vec<u32>>, out: &mut Vec<u32>) {
+        match single.get(&code) {
+            Some(mapping) => mapping.iter().for_each(|&c| decompose(c, single, out)),
+            None => out.push(code),
+        }
+    }
+
+    single
+        .keys()
+        .map(|&code| {
+            let mut full = Vec::new();
+            decompose(code, &single, &mut full);
+            (code, full)
+        })
+        .collect()
+}
+
+/// Returns the primary composites: the chars whose canonical decomposition is a pair of chars
+/// and that are not excluded from composition, keyed by that pair. Hangul syllables are
+/// composed algorithmically and are not included.
+///
+/// A char is excluded if it is listed in `CompositionExclusions.txt`, or if it is itself a
+/// non-starter or its decomposition starts with one.
+fn canonical_compositions(data: &Path, entries: &[UnicodeDataEntry]) -> BTreeMap<(u32, u32), u32> {
+    let exclusions: Vec<u32> = read_lines(&data.join("ucd/CompositionExclusions.txt"))
+        .iter()
+        .map(|line| parse_code_point(line.field(0)))
+        .collect();
+    let combining_class = |code: u32| {
+        entries
+            .iter()
+            .find(|entry| entry.start <= code && code <= entry.end)
+            .map_or(0, |entry| entry.combining_class)
+    };
+    entries
+        .iter()
+        .filter_map(|entry| match entry.canonical_decomposition()?.as_slice() {
+            &[first, second]
+                if !exclusions.contains(&entry.start)
+                    && entry.combining_class == 0
+                    && combining_class(first) == 0 =>
+            {
+                Some(((first, second), entry.start))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Generates `normalization.rs` from `UnicodeData.txt` and `CompositionExclusions.txt`.
+pub fn generate(data: &Path, out: &Path) {
+    let entries = read_unicode_data(data);
+
+    let mut combining_classes: Vec<(u32, u32, u8)> = Vec::new();
+    for entry in entries.iter().filter(|entry| entry.combining_class != 0) {
+        match combining_classes.last_mut() {
+            Some((_, end, class)) if *end + 1 == entry.start && *class == entry.combining_class => {
+                *end = entry.end;
+            }
+            _ => combining_classes.push((entry.start, entry.end, entry.combining_class)),
+        }
+    }
+
+    let mut writer = TableWriter::new();
+    writer.push("\n/// Ranges of chars with a non-zero canonical combining class, sorted by code point.\n");
+    writer.push("pub(crate) static CANONICAL_COMBINING_CLASS: &[(char, char, u8)] = &[\n");
+    for (start, end, class) in &combining_classes {
+        writer.push("    (");
+        writer.push_char(*start);
+        writer.push(", ");
+        writer.push_char(*end);
+        writer.push(&format!(", {}),\n", class));
+    }
+    writer.push("];\n");
+
+    writer.push("\n/// Full canonical decompositions, sorted by code point. Hangul syllables are not included.\n");
+    writer.push("pub(crate) static CANONICAL_DECOMPOSITION: &[(char, &[char])] = &[\n");
+    for (code, mapping) in canonical_decompositions(&entries) {
+        writer.push("    (");
+        writer.push_char(code);
+        writer.push(", ");
+        writer.push_char_slice(&mapping);
+        writer.push("),\n");
+    }
+    writer.push("];\n");
+
+    writer.push("\n/// Primary composites and the pair of chars they are composed of, sorted by pair.\n");
+    writer.push("/// Hangul syllables are not included.\n");
+    writer.push("pub(crate) static CANONICAL_COMPOSITION: &[(char, char, char)] = &[\n");
+    for ((first, second), composite) in canonical_compositions(data, &entries) {
+        writer.push("    (");
+        writer.push_char(first);
+        writer.push(", ");
+        writer.push_char(second);
+        writer.push(", ");
+        writer.push_char(composite);
+        writer.push("),\n");
+    }
+    writer.push("];\n");
+    writer.write(&out.join("normalization.rs"));
+}
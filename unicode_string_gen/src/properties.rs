@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::ucd::{
+    is_surrogate_range, parse_range, read_lines, read_property_ranges, read_property_values,
+    read_unicode_data, read_value_aliases, read_version,
+};
+use crate::writer::TableWriter;
+
+/// The binary properties that get a table: the file they are read from, the property and the
+/// name of the table.
+const BINARY_PROPERTIES: &[(&str, &str, &str)] = &[
+    ("DerivedCoreProperties.txt", "Alphabetic", "ALPHABETIC"),
+    ("PropList.txt", "Dash", "DASH"),
+    (
+        "DerivedCoreProperties.txt",
+        "Default_Ignorable_Code_Point",
+        "DEFAULT_IGNORABLE_CODE_POINT",
+    ),
+    ("emoji-data.txt", "Emoji", "EMOJI"),
+    ("emoji-data.txt", "Emoji_Component", "EMOJI_COMPONENT"),
+    ("emoji-data.txt", "Emoji_Modifier", "EMOJI_MODIFIER"),
+    (
+        "emoji-data.txt",
+        "Emoji_Modifier_Base",
+        "EMOJI_MODIFIER_BASE",
+    ),
+    ("emoji-data.txt", "Emoji_Presentation", "EMOJI_PRESENTATION"),
+    (
+        "emoji-data.txt",
+        "Extended_Pictographic",
+        "EXTENDED_PICTOGRAPHIC",
+    ),
+    ("DerivedCoreProperties.txt", "ID_Continue", "ID_CONTINUE"),
+    ("DerivedCoreProperties.txt", "ID_Start", "ID_START"),
+    ("PropList.txt", "Ideographic", "IDEOGRAPHIC"),
+    ("DerivedCoreProperties.txt", "Lowercase", "LOWERCASE"),
+    ("DerivedCoreProperties.txt", "Math", "MATH"),
+    (
+        "PropList.txt",
+        "Noncharacter_Code_Point",
+        "NONCHARACTER_CODE_POINT",
+    ),
+    ("PropList.txt", "Pattern_Syntax", "PATTERN_SYNTAX"),
+    ("PropList.txt", "Pattern_White_Space", "PATTERN_WHITE_SPACE"),
+    ("PropList.txt", "Quotation_Mark", "QUOTATION_MARK"),
+    ("PropList.txt", "Regional_Indicator", "REGIONAL_INDICATOR"),
+    ("DerivedCoreProperties.txt", "Uppercase", "UPPERCASE"),
+    ("PropList.txt", "Variation_Selector", "VARIATION_SELECTOR"),
+    ("PropList.txt", "White_Space", "WHITE_SPACE"),
+    ("DerivedCoreProperties.txt", "XID_Continue", "XID_CONTINUE"),
+    ("DerivedCoreProperties.txt", "XID_Start", "XID_START"),
+];
+
+/// The files whose version header must match the version of the generated tables.
+const VERSIONED_FILES: &[&str] = &[
+    "Blocks.txt",
+    "DerivedAge.txt",
+    "DerivedCoreProperties.txt",
+    "DerivedJoiningType.txt",
+    "GraphemeBreakProperty.txt",
+    "PropList.txt",
+    "PropertyValueAliases.txt",
+    "ScriptExtensions.txt",
+    "Scripts.txt",
+    "emoji-data.txt",
+];
+
+/// The short names of the joining types listed in `DerivedJoiningType.txt` and the
+/// corresponding `JoiningType` variants.
+const JOINING_TYPES: &[(&str, &str)] = &[
+    ("C", "JoinCausing"),
+    ("D", "DualJoining"),
+    ("L", "LeftJoining"),
+    ("R", "RightJoining"),
+    ("T", "Transparent"),
+];
+
+/// The values listed in `GraphemeBreakProperty.txt` and the corresponding
+/// `GraphemeClusterBreak` variants.
+const GRAPHEME_CLUSTER_BREAKS: &[(&str, &str)] = &[
+    ("CR", "CR"),
+    ("Control", "Control"),
+    ("Extend", "Extend"),
+    ("L", "L"),
+    ("LF", "LF"),
+    ("LV", "LV"),
+    ("LVT", "LVT"),
+    ("Prepend", "Prepend"),
+    ("Regional_Indicator", "RegionalIndicator"),
+    ("SpacingMark", "SpacingMark"),
+    ("T", "T"),
+    ("V", "V"),
+    ("ZWJ", "ZWJ"),
+];
+
+/// Generates `properties.rs` from `UnicodeData.txt`, `DerivedAge.txt`, `Blocks.txt`,
+/// `DerivedJoiningType.txt`, `GraphemeBreakProperty.txt` and the binary property files.
+///
+/// Unassigned code points (`Cn`) are left out of the general category table and the age
+/// table, and surrogates are left out of every table.
+pub fn generate(data: &Path, out: &Path) {
+    let ucd = data.join("ucd");
+    let version = read_version(&ucd.join("DerivedAge.txt"));
+    for file in VERSIONED_FILES {
+        assert_eq!(
+            read_version(&ucd.join(file)),
+            version,
+            "{} is not from Unicode {:?}",
+            file,
+            version
+        );
+    }
+
+    let mut writer = TableWriter::new();
+    writer.push("\nuse crate::ucd::{GeneralCategory, GraphemeClusterBreak, JoiningType};\n");
+
+    writer.push(
+        "\n/// The version of the Unicode Character Database these tables were built from.\n",
+    );
+    writer.push(&format!(
+        "pub(crate) const UNICODE_VERSION: (u8, u8, u8) = ({}, {}, {});\n",
+        version.0, version.1, version.2
+    ));
+
+    let categories: HashMap<String, String> = read_value_aliases(data, "gc")
+        .into_iter()
+        .map(|(short, long)| (short, long.replace('_', "")))
+        .collect();
+    let mut general_categories: Vec<(u32, u32, String)> = Vec::new();
+    for entry in read_unicode_data(data) {
+        match general_categories.last_mut() {
+            Some((_, end, category))
+                if *end + 1 == entry.start && *category == entry.general_category =>
+            {
+                *end = entry.end;
+            }
+            _ => general_categories.push((entry.start, entry.end, entry.general_category)),
+        }
+    }
+    writer
+        .push("\n/// Ranges of assigned chars and their general category, sorted by code point.\n");
+    writer.push("pub(crate) static GENERAL_CATEGORY: &[(char, char, GeneralCategory)] = &[\n");
+    for (start, end, category) in general_categories {
+        if is_surrogate_range(start, end) {
+            continue;
+        }
+        let variant = categories
+            .get(&category)
+            .unwrap_or_else(|| panic!("unknown general category {:?}", category));
+        writer.push("    (");
+        writer.push_char(start);
+        writer.push(", ");
+        writer.push_char(end);
+        writer.push(&format!(", GeneralCategory::{}),\n", variant));
+    }
+    writer.push("];\n");
+
+    writer.push("\n/// Ranges of assigned chars and the version that assigned them, sorted by code point.\n");
+    writer.push("pub(crate) static AGE: &[(char, char, (u8, u8))] = &[\n");
+    for (start, end, age) in read_property_values(&ucd.join("DerivedAge.txt")) {
+        if is_surrogate_range(start, end) {
+            continue;
+        }
+        let (major, minor) = age.split_once('.').expect("invalid age");
+        writer.push("    (");
+        writer.push_char(start);
+        writer.push(", ");
+        writer.push_char(end);
+        writer.push(&format!(", ({}, {})),\n", major, minor));
+    }
+    writer.push("];\n");
+
+    writer.push("\n/// Ranges of chars that are not non-joining and their joining type, sorted by code point.\n");
+    writer.push("pub(crate) static JOINING_TYPE: &[(char, char, JoiningType)] = &[\n");
+    for (start, end, value) in read_property_values(&ucd.join("DerivedJoiningType.txt")) {
+        assert!(!is_surrogate_range(start, end));
+        let variant = JOINING_TYPES
+            .iter()
+            .find(|&&(short, _)| short == value)
+            .unwrap_or_else(|| panic!("unknown joining type {:?}", value))
+            .1;
+        writer.push("    (");
+        writer.push_char(start);
+        writer.push(", ");
+        writer.push_char(end);
+        writer.push(&format!(", JoiningType::{}),\n", variant));
+    }
+    writer.push("];\n");
+
+    writer.push("\n/// Ranges of chars whose grapheme cluster break is not `Other` and their value, sorted by\n/// code point.\n");
+    writer.push(
+        "pub(crate) static GRAPHEME_CLUSTER_BREAK: &[(char, char, GraphemeClusterBreak)] = &[\n",
+    );
+    for (start, end, value) in read_property_values(&ucd.join("GraphemeBreakProperty.txt")) {
+        if is_surrogate_range(start, end) {
+            continue;
+        }
+        let variant = GRAPHEME_CLUSTER_BREAKS
+            .iter()
+            .find(|&&(name, _)| name == value)
+            .unwrap_or_else(|| panic!("unknown grapheme cluster break {:?}", value))
+            .1;
+        writer.push("    (");
+        writer.push_char(start);
+        writer.push(", ");
+        writer.push_char(end);
+        writer.push(&format!(", GraphemeClusterBreak::{}),\n", variant));
+    }
+    writer.push("];\n");
+
+    let mut blocks = read_lines(&ucd.join("Blocks.txt"))
+        .into_iter()
+        .map(|line| {
+            let (start, end) = parse_range(line.field(0));
+            (start, end, line.field(1).to_owned())
+        })
+        .collect::<Vec<_>>();
+    blocks.sort();
+    writer.push("\n/// The range and name of every block, sorted by code point.\n");
+    writer.push("pub(crate) static BLOCKS: &[(char, char, &str)] = &[\n");
+    for (start, end, name) in blocks {
+        if is_surrogate_range(start, end) {
+            continue;
+        }
+        writer.push("    (");
+        writer.push_char(start);
+        writer.push(", ");
+        writer.push_char(end);
+        writer.push(&format!(", {:?}),\n", name));
+    }
+    writer.push("];\n");
+
+    for &(file, property, table) in BINARY_PROPERTIES {
+        let ranges = merge_ranges(read_property_ranges(&ucd.join(file), property));
+        assert!(!ranges.is_empty(), "{} has no {} ranges", file, property);
+        writer.push(&format!(
+            "\n/// Ranges of chars with the `{}` property, sorted by code point.\n",
+            property
+        ));
+        writer.push(&format!(
+            "pub(crate) static {}: &[(char, char)] = &[\n",
+            table
+        ));
+        for (start, end) in ranges {
+            assert!(!is_surrogate_range(start, end));
+            writer.push("    (");
+            writer.push_char(start);
+            writer.push(", ");
+            writer.push_char(end);
+            writer.push("),\n");
+        }
+        writer.push("];\n");
+    }
+    writer.write(&out.join("properties.rs"));
+}
+
+/// Merges adjacent ranges of a sorted list of ranges.
+fn merge_ranges(ranges: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some((_, last_end)) if *last_end + 1 == start => *last_end = end,
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
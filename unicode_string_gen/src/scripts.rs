@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::ucd::{parse_range, read_lines, read_property_values, read_value_aliases};
+use crate::writer::TableWriter;
+
+/// Generates `scripts.rs` from `PropertyValueAliases.txt`, `Scripts.txt` and
+/// `ScriptExtensions.txt`.
+///
+/// The `Script` enum has a variant for every script, named after its long name without
+/// underscores and ordered by that name. Ranges of `Unknown`, the default script, are left out
+/// of the script table, and only chars whose script extensions differ from their script are
+/// listed in the extensions table.
+pub fn generate(data: &Path, out: &Path) {
+    let scripts = read_value_aliases(data, "sc");
+    let variants: HashMap<&str, String> = scripts
+        .iter()
+        .flat_map(|(short, long)| {
+            let variant = long.replace('_', "");
+            [(short.as_str(), variant.clone()), (long.as_str(), variant)]
+        })
+        .collect();
+    let variant = |name: &str| -> &str {
+        variants
+            .get(name)
+            .unwrap_or_else(|| panic!("unknown script {:?}", name))
+    };
+
+    let mut writer = TableWriter::new();
+    writer.push(
+        r#"
+/// A writing system (the `Script` property of a char).
+///
+/// Chars used with several scripts, like punctuation and digits, have the script
+/// [`Common`](Script::Common), and combining marks used with several scripts have the script
+/// [`Inherited`](Script::Inherited). Unassigned code points have the script
+/// [`Unknown`](Script::Unknown). See [`script`](crate::ucd::script) and
+/// [`script_extensions`](crate::ucd::script_extensions).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use unicode_string::ucd::{self, Script};
+///
+/// assert_eq!(ucd::script('a'), Script::Latin);
+/// assert_eq!(Script::Latin.name(), "Latin");
+/// assert_eq!(Script::OldItalic.name(), "Old_Italic");
+/// assert_eq!(Script::OldItalic.short_name(), "Ital");
+/// assert_eq!(Script::from_name("cyrl"), Some(Script::Cyrillic));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Script {
+"#,
+    );
+    for (short, long) in &scripts {
+        writer.push(&format!(
+            "    /// {} (`{}`).\n",
+            long.replace('_', " "),
+            short
+        ));
+        writer.push(&format!("    {},\n", variant(long)));
+    }
+    writer.push("}\n");
+
+    writer.push(&format!(
+        "\n/// The number of variants of [`Script`].\npub(crate) const SCRIPT_COUNT: usize = {};\n",
+        scripts.len()
+    ));
+
+    writer.push("\n/// Every script, indexed by `Script as usize`.\n");
+    writer.push("pub(crate) static SCRIPTS: &[Script] = &[\n");
+    for (_, long) in &scripts {
+        writer.push(&format!("    Script::{},\n", variant(long)));
+    }
+    writer.push("];\n");
+
+    writer.push("\n/// The long and short name of every script, indexed by `Script as usize`.\n");
+    writer.push("pub(crate) static SCRIPT_NAMES: &[(&str, &str)] = &[\n");
+    for (short, long) in &scripts {
+        writer.push(&format!("    ({:?}, {:?}),\n", long, short));
+    }
+    writer.push("];\n");
+
+    writer.push("\n/// Ranges of chars whose script is not `Unknown`, sorted by code point.\n");
+    writer.push("pub(crate) static SCRIPT: &[(char, char, Script)] = &[\n");
+    for (start, end, script) in read_property_values(&data.join("ucd/Scripts.txt")) {
+        writer.push("    (");
+        writer.push_char(start);
+        writer.push(", ");
+        writer.push_char(end);
+        writer.push(&format!(", Script::{}),\n", variant(&script)));
+    }
+    writer.push("];\n");
+
+    let mut extensions = read_lines(&data.join("ucd/ScriptExtensions.txt"))
+        .into_iter()
+        .map(|line| {
+            let (start, end) = parse_range(line.field(0));
+            let scripts = line
+                .field(1)
+                .split_whitespace()
+                .map(|script| format!("Script::{}", variant(script)))
+                .collect::<Vec<_>>();
+            (start, end, scripts.join(", "))
+        })
+        .collect::<Vec<_>>();
+    extensions.sort();
+
+    writer.push(
+        "\n/// Ranges of chars whose script extensions differ from their script, sorted by code\n",
+    );
+    writer.push("/// point.\n");
+    writer.push("pub(crate) static SCRIPT_EXTENSIONS: &[(char, char, &[Script])] = &[\n");
+    for (start, end, scripts) in extensions {
+        writer.push("    (");
+        writer.push_char(start);
+        writer.push(", ");
+        writer.push_char(end);
+        writer.push(&format!(", &[{}]),\n", scripts));
+    }
+    writer.push("];\n");
+    writer.write(&out.join("scripts.rs"));
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::ucd::parse_code_point;
+use crate::writer::TableWriter;
+
+/// The number of bits of a packed transliteration used for its length.
+const LENGTH_BITS: u32 = 6;
+
+/// Generates `transliteration.rs` from `Transliterations.txt`.
+///
+/// Many chars share a transliteration (most ideographs share their reading with others), so
+/// every distinct transliteration is stored once in a single string, and each char is mapped
+/// to the offset and length of its transliteration packed into a `u32`. The chars are grouped
+/// into ranges of consecutive code points that index into the packed values.
+pub fn generate(data: &Path, out: &Path) {
+    let entries = read_transliterations(&data.join("translit/Transliterations.txt"));
+    assert!(
+        entries.windows(2).all(|pair| pair[0].0 < pair[1].0),
+        "Transliterations.txt is not sorted by code point"
+    );
+
+    let mut text = String::new();
+    let mut offsets: HashMap<&str, usize> = HashMap::new();
+    let mut packed = Vec::new();
+    for (_, replacement) in &entries {
+        let offset = *offsets.entry(replacement).or_insert_with(|| {
+            text.push_str(replacement);
+            text.len() - replacement.len()
+        });
+        assert!(
+            replacement.len() < 1 << LENGTH_BITS && offset < 1 << (32 - LENGTH_BITS),
+            "the transliteration {:?} does not fit in the packed format",
+            replacement
+        );
+        packed.push((offset as u32) << LENGTH_BITS | replacement.len() as u32);
+    }
+
+    let mut ranges: Vec<(u32, u32, usize)> = Vec::new();
+    for (index, &(code, _)) in entries.iter().enumerate() {
+        match ranges.last_mut() {
+            Some((_, end, _)) if *end + 1 == code => *end = code,
+            _ => ranges.push((code, code, index)),
+        }
+    }
+
+    let mut writer = TableWriter::new();
+    writer.push(&format!(
+        "\n/// The number of bits of the values of [`TRANSLITERATIONS`] used for the length.\npub(crate) const LENGTH_BITS: u32 = {};\n",
+        LENGTH_BITS
+    ));
+    writer.push("\n/// The distinct transliterations, concatenated.\n");
+    writer.push("pub(crate) static TRANSLITERATION_TEXT: &str = \"\\\n");
+    let chars = text.chars().collect::<Vec<_>>();
+    for line in chars.chunks(96) {
+        writer.push("    ");
+        for (index, &c) in line.iter().enumerate() {
+            match c {
+                ' ' if index == 0 => writer.push("\\x20"),
+                '"' => writer.push("\\\""),
+                '\\' => writer.push("\\\\"),
+                '\n' => writer.push("\\n"),
+                c => {
+                    assert!(
+                        c.is_ascii_graphic() || c == ' ',
+                        "unexpected character {:?} in a transliteration",
+                        c
+                    );
+                    writer.push(c.encode_utf8(&mut [0; 4]));
+                }
+            }
+        }
+        writer.push("\\\n");
+    }
+    writer.push("\";\n");
+
+    writer.push(
+        "\n/// Ranges of consecutive chars that have a transliteration and the index of the\n",
+    );
+    writer.push(
+        "/// transliteration of their first char in [`TRANSLITERATIONS`], sorted by code point.\n",
+    );
+    writer.push("pub(crate) static TRANSLITERATION_RANGES: &[(char, char, u32)] = &[\n");
+    for (start, end, index) in ranges {
+        writer.push("    (");
+        writer.push_char(start);
+        writer.push(", ");
+        writer.push_char(end);
+        writer.push(&format!(", {}),\n", index));
+    }
+    writer.push("];\n");
+
+    writer.push(
+        "\n/// The offset of each transliteration in [`TRANSLITERATION_TEXT`], shifted left by\n",
+    );
+    writer.push("/// [`LENGTH_BITS`], and its length.\n");
+    writer.push("pub(crate) static TRANSLITERATIONS: &[u32] = &[\n");
+    for chunk in packed.chunks(12) {
+        let values = chunk.iter().map(u32::to_string).collect::<Vec<_>>();
+        writer.push(&format!("    {},\n", values.join(", ")));
+    }
+    writer.push("];\n");
+    writer.write(&out.join("transliteration.rs"));
+}
+
+/// Reads the `<code point>; "<replacement>"` lines of `Transliterations.txt`.
+///
+/// The replacements can contain `#` and `;`, so the file cannot be read with
+/// [`read_lines`](crate::ucd::read_lines).
+fn read_transliterations(path: &Path) -> Vec<(u32, String)> {
+    let contents = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (code, replacement) = line
+                .split_once(';')
+                .unwrap_or_else(|| panic!("invalid line {:?}", line));
+            let quoted = replacement.trim();
+            let replacement = quoted
+                .strip_prefix('"')
+                .and_then(|quoted| quoted.strip_suffix('"'))
+                .unwrap_or_else(|| panic!("unquoted replacement in {:?}", line));
+            (parse_code_point(code), unescape(replacement))
+        })
+        .collect()
+}
+
+fn unescape(value: &str) -> String {
+    let mut result = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some(c @ ('"' | '\\')) => result.push(c),
+            other => panic!("invalid escape {:?} in {:?}", other, value),
+        }
+    }
+    result
+}
@@ -0,0 +1,184 @@
+use std::fs;
+use std::path::Path;
+
+/// A single data line of a Unicode Character Database file, split on `;` with the trailing
+/// comment removed and every field trimmed.
+pub struct Line {
+    pub fields: Vec<String>,
+}
+
+impl Line {
+    /// Returns the field at `index`, or an empty string if the line is shorter than that.
+    pub fn field(&self, index: usize) -> &str {
+        self.fields.get(index).map(String::as_str).unwrap_or("")
+    }
+}
+
+/// Reads a file in the usual UCD format (`field; field; ... # comment`), skipping empty lines
+/// and comments.
+pub fn read_lines(path: &Path) -> Vec<Line> {
+    let contents = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+    contents
+        .lines()
+        .filter_map(|line| {
+            let line = match line.find('#') {
+                Some(index) => &line[..index],
+                None => line,
+            };
+            if line.trim().is_empty() {
+                return None;
+            }
+            Some(Line {
+                fields: line.split(';').map(|field| field.trim().to_owned()).collect(),
+            })
+        })
+        .collect()
+}
+
+/// Parses a single hexadecimal code point, e.g. `00DF`.
+pub fn parse_code_point(value: &str) -> u32 {
+    u32::from_str_radix(value.trim(), 16)
+        .unwrap_or_else(|e| panic!("invalid code point {:?}: {}", value, e))
+}
+
+/// Parses a space separated sequence of hexadecimal code points, e.g. `0073 0073`.
+pub fn parse_code_points(value: &str) -> Vec<u32> {
+    value.split_whitespace().map(parse_code_point).collect()
+}
+
+/// Parses a code point or a code point range, e.g. `0041` or `0041..005A`.
+pub fn parse_range(value: &str) -> (u32, u32) {
+    match value.split_once("..") {
+        Some((start, end)) => (parse_code_point(start), parse_code_point(end)),
+        None => {
+            let code = parse_code_point(value);
+            (code, code)
+        }
+    }
+}
+
+/// Checks if `start..=end` only contains surrogate code points, which are not valid chars and
+/// are left out of the tables. Panics if the range only partially overlaps the surrogates.
+pub fn is_surrogate_range(start: u32, end: u32) -> bool {
+    let surrogates = 0xD800..=0xDFFF;
+    let inside = surrogates.contains(&start) && surrogates.contains(&end);
+    assert!(
+        inside || end < 0xD800 || start > 0xDFFF,
+        "the range {:04X}..{:04X} contains surrogates and other code points",
+        start,
+        end
+    );
+    inside
+}
+
+/// Reads a file listing property values for code point ranges (e.g. `PropList.txt`,
+/// `Scripts.txt`) and returns the ranges that have the property `value`.
+pub fn read_property_ranges(path: &Path, value: &str) -> Vec<(u32, u32)> {
+    let mut ranges = read_lines(path)
+        .into_iter()
+        .filter(|line| line.field(1) == value)
+        .map(|line| parse_range(line.field(0)))
+        .collect::<Vec<_>>();
+    ranges.sort();
+    ranges
+}
+
+/// Reads a file mapping code point ranges to property values (e.g. `DerivedBidiClass.txt`)
+/// and returns the ranges sorted by code point, with adjacent ranges of the same value merged.
+pub fn read_property_values(path: &Path) -> Vec<(u32, u32, String)> {
+    let mut entries = read_lines(path)
+        .into_iter()
+        .map(|line| {
+            let (start, end) = parse_range(line.field(0));
+            (start, end, line.field(1).to_owned())
+        })
+        .collect::<Vec<_>>();
+    entries.sort();
+
+    let mut merged: Vec<(u32, u32, String)> = Vec::with_capacity(entries.len());
+    for (start, end, value) in entries {
+        match merged.last_mut() {
+            Some((_, last_end, last_value)) if *last_end + 1 == start && *last_value == value => {
+                *last_end = end;
+            }
+            _ => merged.push((start, end, value)),
+        }
+    }
+    merged
+}
+
+/// Reads the Unicode version from the first line of a UCD file, e.g. `# Scripts-14.0.0.txt`.
+pub fn read_version(path: &Path) -> (u8, u8, u8) {
+    let contents = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+    let version = contents
+        .lines()
+        .next()
+        .and_then(|line| line.strip_suffix(".txt"))
+        .and_then(|line| line.rsplit_once('-'))
+        .map(|(_, version)| version)
+        .unwrap_or_else(|| panic!("{} does not start with a version header", path.display()));
+    let parts = version
+        .split('.')
+        .map(|part| part.parse().expect("invalid version number"))
+        .collect::<Vec<u8>>();
+    match parts[..] {
+        [major, minor, update] => (major, minor, update),
+        _ => panic!("invalid version {:?} in {}", version, path.display()),
+    }
+}
+
+/// Reads the short and long names of the values of `property` (e.g. `sc`) from
+/// `PropertyValueAliases.txt`, in the order of the file.
+pub fn read_value_aliases(data: &Path, property: &str) -> Vec<(String, String)> {
+    read_lines(&data.join("ucd/PropertyValueAliases.txt"))
+        .into_iter()
+        .filter(|line| line.field(0) == property)
+        .map(|line| (line.field(1).to_owned(), line.field(2).to_owned()))
+        .collect()
+}
+
+/// An entry of `UnicodeData.txt`. Ranges given by `<..., First>` and `<..., Last>` lines are
+/// kept as a single entry.
+pub struct UnicodeDataEntry {
+    pub start: u32,
+    pub end: u32,
+    pub general_category: String,
+    pub combining_class: u8,
+    pub decomposition: String,
+    pub decimal_digit: Option<u8>,
+}
+
+impl UnicodeDataEntry {
+    /// Returns the canonical decomposition mapping, if this entry has one.
+    pub fn canonical_decomposition(&self) -> Option<Vec<u32>> {
+        if self.decomposition.is_empty() || self.decomposition.starts_with('<') {
+            None
+        } else {
+            Some(parse_code_points(&self.decomposition))
+        }
+    }
+}
+
+/// Reads `UnicodeData.txt`.
+pub fn read_unicode_data(data: &Path) -> Vec<UnicodeDataEntry> {
+    let mut entries: Vec<UnicodeDataEntry> = Vec::new();
+    for line in read_lines(&data.join("ucd/UnicodeData.txt")) {
+        let code = parse_code_point(line.field(0));
+        let name = line.field(1);
+        if name.ends_with(", Last>") {
+            entries.last_mut().expect("range without a start").end = code;
+            continue;
+        }
+        entries.push(UnicodeDataEntry {
+            start: code,
+            end: code,
+            general_category: line.field(2).to_owned(),
+            combining_class: line.field(3).parse().expect("invalid combining class"),
+            decomposition: line.field(5).to_owned(),
+            decimal_digit: line.field(6).parse().ok(),
+        });
+    }
+    entries
+}
@@ -0,0 +1,56 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// Accumulates the source of one generated table module.
+pub struct TableWriter {
+    source: String,
+}
+
+impl Default for TableWriter {
+    fn default() -> Self {
+        TableWriter::new()
+    }
+}
+
+impl TableWriter {
+    pub fn new() -> TableWriter {
+        TableWriter {
+            source: String::from(
+                "// This file is generated by `unicode_string_gen`. Do not edit it manually.\n",
+            ),
+        }
+    }
+
+    /// Appends raw source code.
+    pub fn push(&mut self, code: &str) {
+        self.source.push_str(code);
+    }
+
+    /// Writes `value` as a char literal.
+    pub fn push_char(&mut self, value: u32) {
+        let _ = write!(self.source, "'\\u{{{:X}}}'", value);
+    }
+
+    /// Writes `values` as a slice of char literals.
+    pub fn push_char_slice(&mut self, values: &[u32]) {
+        self.push("&[");
+        for (index, &value) in values.iter().enumerate() {
+            if index > 0 {
+                self.push(", ");
+            }
+            self.push_char(value);
+        }
+        self.push("]");
+    }
+
+    /// Writes the module to `path`, only touching the file if its contents changed.
+    pub fn write(self, path: &Path) {
+        if fs::read_to_string(path).ok().as_deref() == Some(self.source.as_str()) {
+            return;
+        }
+        fs::write(path, self.source)
+            .unwrap_or_else(|e| panic!("failed to write {}: {}", path.display(), e));
+        println!("wrote {}", path.display());
+    }
+}
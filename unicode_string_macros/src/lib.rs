@@ -1,29 +1,178 @@
 #![feature(proc_macro_quote)]
 
-use proc_macro::{quote, Delimiter, Group, Literal, Punct, Spacing, TokenStream, TokenTree};
-use syn::{parse_macro_input, LitStr};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
 
+use proc_macro::{
+    quote, Delimiter, Group, Literal, Punct, Spacing, Span, TokenStream, TokenTree,
+};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Lit, LitStr, Token};
+
+/// Creates a `&'static unicode_str` from a string literal.
+///
+/// Chars can be written by name with `\N{NAME}`, using their Unicode name or a name alias,
+/// which makes invisible and confusable chars readable in source. Rust does not accept `\N` in
+/// ordinary string literals, so either escape the backslash (`"\\N{NAME}"`) or use a raw
+/// string (`r"\N{NAME}"`). Names are resolved at compile time, and unknown names are compile
+/// errors. A literal `\N{` can be written as `\N{REVERSE SOLIDUS}N{`.
+///
+/// The expansion is a constant expression, so it can initialize `const` and `static` items.
 #[proc_macro]
 pub fn ustr(input: TokenStream) -> TokenStream {
     let result = parse_macro_input!(input as LitStr);
-    let chars = result
-        .value()
-        .chars()
+    match parse_chars(&result.value()) {
+        Ok(chars) => unicode_str_expr(chars),
+        Err(message) => syn::Error::new(result.span(), message)
+            .to_compile_error()
+            .into(),
+    }
+}
+
+/// Concatenates literals into a `&'static unicode_str`, like `concat!`.
+///
+/// Strings, which accept `\N{NAME}` escapes like in `ustr!`, and chars are inserted as is, and
+/// integers, floats and booleans as they are written.
+#[proc_macro]
+pub fn uconcat(input: TokenStream) -> TokenStream {
+    let literals = parse_macro_input!(input with Punctuated::<Lit, Token![,]>::parse_terminated);
+    let mut chars = Vec::new();
+    for literal in literals {
+        match &literal {
+            Lit::Str(s) => match parse_chars(&s.value()) {
+                Ok(parsed) => chars.extend(parsed),
+                Err(message) => {
+                    return syn::Error::new(s.span(), message)
+                        .to_compile_error()
+                        .into()
+                }
+            },
+            Lit::Char(c) => chars.push(StringChar::Char(c.value())),
+            Lit::Int(i) => chars.extend(i.base10_digits().chars().map(StringChar::Char)),
+            Lit::Float(f) => chars.extend(f.base10_digits().chars().map(StringChar::Char)),
+            Lit::Bool(b) => chars.extend(b.value.to_string().chars().map(StringChar::Char)),
+            _ => {
+                return syn::Error::new(literal.span(), "cannot concatenate this literal")
+                    .to_compile_error()
+                    .into()
+            }
+        }
+    }
+    unicode_str_expr(chars)
+}
+
+/// Creates a `&'static unicode_str` from the contents of a UTF-8 file, like `include_str!`.
+///
+/// The path is relative to the file in which the macro is called. The file is read at compile
+/// time and the crate is rebuilt when it changes.
+#[proc_macro]
+pub fn include_ustr(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+    let relative = PathBuf::from(path.value());
+    let base = Span::call_site()
+        .local_file()
+        .and_then(|file| file.parent().map(Path::to_path_buf))
+        .or_else(|| env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from))
+        .unwrap_or_default();
+    let mut full_path = base.join(relative);
+    // The path is made absolute for `include_bytes!` below, which resolves relative paths
+    // from the calling file rather than from the current directory.
+    let contents = match fs::canonicalize(&full_path)
+        .and_then(|absolute| Ok((fs::read_to_string(&absolute)?, absolute)))
+    {
+        Ok((contents, absolute)) => {
+            full_path = absolute;
+            contents
+        }
+        Err(error) => {
+            let message = format!("couldn't read `{}`: {}", full_path.display(), error);
+            return syn::Error::new(path.span(), message)
+                .to_compile_error()
+                .into();
+        }
+    };
+    let chars = contents.chars().map(StringChar::Char).collect();
+    // Including the bytes too makes the compiler track the file, which a proc macro can't do.
+    let tracked: TokenStream = format!(
+        "const _: &[u8] = ::std::include_bytes!({:?});",
+        full_path.display().to_string()
+    )
+    .parse()
+    .expect("the generated item is valid Rust");
+    let chars = unicode_str_expr(chars);
+    quote! {
+        {
+            $tracked
+            $chars
+        }
+    }
+}
+
+/// Emits a block that evaluates to a `&'static unicode_str` made of `chars`, through
+/// `unicode_str::from_chars` so that it can initialize `const` and `static` items.
+fn unicode_str_expr(chars: Vec<StringChar>) -> TokenStream {
+    let chars = chars
         .into_iter()
         .flat_map(|c| {
-            [
-                TokenTree::Literal(Literal::character(c)),
-                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
-            ]
+            let c = match c {
+                StringChar::Char(c) => TokenTree::Literal(Literal::character(c)).into(),
+                StringChar::Named(name) => named_char(&name),
+            };
+            [c, TokenTree::Punct(Punct::new(',', Spacing::Alone)).into()]
         })
+        .collect::<TokenStream>();
+    let chars_array: TokenStream = [TokenTree::Group(Group::new(Delimiter::Bracket, chars))]
+        .into_iter()
         .collect();
-    let chars_array = TokenTree::Group(Group::new(Delimiter::Bracket, chars));
-    let params: TokenStream = [chars_array].into_iter().collect();
-    let expanded = quote! {
-        unsafe {
-            let result: &::unicode_string::unicode_str = ::std::mem::transmute(($params).as_slice());
-            result
+    quote! {
+        {
+            const CHARS: &[::std::primitive::char] = &$chars_array;
+            ::unicode_string::unicode_str::from_chars(CHARS)
         }
-    };
-    expanded.into()
+    }
+}
+
+/// A char of the string passed to `ustr!` or `uconcat!`.
+enum StringChar {
+    Char(char),
+    /// A char written as `\N{NAME}`.
+    Named(String),
+}
+
+/// Splits the value of the string literal into chars, extracting the `\N{NAME}` escapes.
+fn parse_chars(value: &str) -> Result<Vec<StringChar>, String> {
+    let mut chars = Vec::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("\\N{") {
+        chars.extend(rest[..start].chars().map(StringChar::Char));
+        let escape = &rest[start + 3..];
+        let end = escape
+            .find('}')
+            .ok_or_else(|| format!("unterminated `\\N{{` escape in {:?}", value))?;
+        let name = &escape[..end];
+        if name.trim().is_empty() {
+            return Err("empty `\\N{}` escape".to_owned());
+        }
+        chars.push(StringChar::Named(name.to_owned()));
+        rest = &escape[end + 1..];
+    }
+    chars.extend(rest.chars().map(StringChar::Char));
+    Ok(chars)
+}
+
+/// Emits an inline const block that resolves `name` with `unicode_string::ucd::lookup` and
+/// fails to compile if there is no char with that name.
+fn named_char(name: &str) -> TokenStream {
+    let message = format!("unknown character name in `\\N{{{}}}`", name)
+        .replace('{', "{{")
+        .replace('}', "}}");
+    format!(
+        "(const {{ match ::unicode_string::ucd::lookup({:?}) {{ \
+            ::std::option::Option::Some(c) => c, \
+            ::std::option::Option::None => ::std::panic!({:?}), \
+        }} }})",
+        name, message
+    )
+    .parse()
+    .expect("the generated block is valid Rust")
 }